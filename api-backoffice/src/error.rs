@@ -294,6 +294,7 @@ impl From<analytics::AnalyticsError> for AppError {
             E::UnknownKpiKey(_)
             | E::UnknownReportKind(_)
             | E::InvalidTimeWindow(_)
+            | E::InvalidStoreSelection(_)
             | E::InvalidWidgetKind(_)
             | E::InvalidWidgetConfig(_) => (
                 StatusCode::BAD_REQUEST,
@@ -1,8 +1,15 @@
 use std::net::SocketAddr;
 
+use std::sync::Arc;
+
 use axum::Router;
+use common::TaxIdPolicy;
+use notifications::{
+    DefaultNotificationAdapterRegistry, HttpWebhookAdapter, PgWebhookDeliveryAttemptRepository,
+    SignatureAlgorithm, WebhookSigningConfig,
+};
 
-use crate::config::AppConfig;
+use crate::config::{AppConfig, WebhooksConfig};
 use crate::cors::build_cors_layer;
 use crate::database::init_pool;
 use crate::jobs;
@@ -12,7 +19,29 @@ use crate::state::AppState;
 pub async fn build() -> (SocketAddr, Router) {
     let config = AppConfig::from_env();
     let pool = init_pool(&config.database).await;
-    let app_state = AppState::from_pool(pool, config.jwt_secret.clone());
+    let mut app_state = AppState::from_pool(
+        pool.clone(),
+        config.jwt_secret.clone(),
+        &config.mfa.encryption_key,
+    )
+    .with_mfa_policy(config.mfa.policy())
+    .with_shift_reopen_window(chrono::Duration::minutes(
+        config.sales.shift_reopen_window_minutes,
+    ))
+    .with_shift_variance_threshold(config.sales.shift_variance_threshold)
+    .with_restock_cost_basis(config.sales.restock_cost_basis)
+    .with_transfer_approval_policy(config.inventory.transfer_approval_policy())
+    .with_adjustment_approval_policy(config.inventory.adjustment_approval_policy())
+    .with_reservation_priority_policy(config.inventory.reservation_priority_policy())
+    .with_purchase_approval_policy(config.purchasing.approval_policy())
+    .with_receipt_match_policy(config.purchasing.receipt_match_policy())
+    .with_stock_posting_batch_size(config.inventory.stock_posting_batch_size)
+    .with_max_category_depth(config.inventory.max_category_depth)
+    .with_document_numbering(config.document_numbering.service())
+    .with_tax_id_policy(TaxIdPolicy::new(&config.tax_id_default_country));
+    if let Some(registry) = webhook_registry(&config.webhooks, pool) {
+        app_state = app_state.with_notification_registry(Arc::new(registry));
+    }
 
     let app = build_router(app_state.clone(), &config).layer(build_cors_layer(&config));
 
@@ -21,3 +50,26 @@ pub async fn build() -> (SocketAddr, Router) {
     let addr: SocketAddr = "0.0.0.0:8000".parse().expect("invalid bind address");
     (addr, app)
 }
+
+/// Registry with the signed HTTP webhook adapter, when a secret is configured.
+fn webhook_registry(
+    config: &WebhooksConfig,
+    pool: sqlx::PgPool,
+) -> Option<DefaultNotificationAdapterRegistry> {
+    let secret = config.signing_secret.clone()?;
+    let algorithm: SignatureAlgorithm = config
+        .signature_algorithm
+        .parse()
+        .expect("WEBHOOK_SIGNATURE_ALGORITHM must be hmac-sha256 or hmac-sha512");
+
+    let signing = WebhookSigningConfig {
+        secret,
+        algorithm,
+        tolerance_secs: config.tolerance_secs,
+        timeout_secs: config.timeout_secs,
+    };
+    let attempts = Arc::new(PgWebhookDeliveryAttemptRepository::new(pool));
+    let adapter = HttpWebhookAdapter::new(signing, attempts);
+
+    Some(DefaultNotificationAdapterRegistry::new().with_webhook(Arc::new(adapter)))
+}
@@ -1,6 +1,21 @@
 use std::env;
 use std::str::FromStr;
 
+use common::{DocumentNumberFormat, DocumentNumberService, DocumentType};
+use identity::{MfaPolicy, PermissionCode, StoreId};
+use inventory::application::helpers::{
+    DEFAULT_MAX_CATEGORY_DEPTH, DEFAULT_STOCK_POSTING_BATCH_SIZE,
+};
+use inventory::{
+    AdjustmentApprovalLimit, AdjustmentApprovalPolicy, AdjustmentReason, ReservationPriorityPolicy,
+    TransferApprovalPolicy,
+};
+use notifications::WebhookRetryPolicy;
+use purchasing::{PurchaseApprovalPolicy, ReceiptMatchPolicy};
+use rust_decimal::Decimal;
+use sales::CartTtlPolicy;
+use uuid::Uuid;
+
 pub struct AppConfig {
     pub database: DatabaseConfig,
     pub jwt_secret: String,
@@ -8,11 +23,42 @@ pub struct AppConfig {
     /// (e.g. backoffice-api requesting an impersonation token). Both binaries
     /// must be configured with the same value.
     pub internal_service_secret: String,
+    pub mfa: MfaConfig,
     pub cors_allowed_origins: Option<String>,
     pub image_storage: ImageStorageConfig,
+    pub webhooks: WebhooksConfig,
+    pub sales: SalesConfig,
+    pub inventory: InventoryConfig,
+    pub purchasing: PurchasingConfig,
+    pub document_numbering: DocumentNumberingConfig,
+    /// Country whose tax id format applies to customers and vendors that
+    /// don't name their own.
+    pub tax_id_default_country: String,
     pub jobs: JobsConfig,
 }
 
+/// TOTP multi-factor authentication.
+pub struct MfaConfig {
+    /// Key encrypting stored TOTP secrets. Changing it makes every
+    /// enrollment unreadable, so enrolled users must enroll again.
+    pub encryption_key: String,
+    /// Issuer shown by authenticator apps.
+    pub issuer: String,
+    /// Wrong codes in a row before the second factor is locked.
+    pub max_failed_attempts: i32,
+    pub lockout_minutes: i64,
+}
+
+impl MfaConfig {
+    pub fn policy(&self) -> MfaPolicy {
+        MfaPolicy::new(
+            self.issuer.clone(),
+            self.max_failed_attempts,
+            chrono::Duration::minutes(self.lockout_minutes),
+        )
+    }
+}
+
 pub struct DatabaseConfig {
     pub url: String,
     pub max_connections: u32,
@@ -27,16 +73,262 @@ pub struct ImageStorageConfig {
     pub root: String,
 }
 
+/// Outbound webhook signing. Deliveries stay log-only without a secret.
+pub struct WebhooksConfig {
+    pub signing_secret: Option<String>,
+    pub signature_algorithm: String,
+    pub tolerance_secs: i64,
+    pub timeout_secs: u64,
+}
+
+pub struct SalesConfig {
+    /// Minutes after a close during which a manager may reopen a shift.
+    pub shift_reopen_window_minutes: i64,
+    /// Closing-count variance allowed without a reason.
+    pub shift_variance_threshold: Decimal,
+    /// Unit cost recorded when a credit note restocks returned items.
+    pub restock_cost_basis: sales::RestockCostBasis,
+}
+
+pub struct InventoryConfig {
+    /// Transfers worth more than this need approval; `None` disables the rule.
+    pub transfer_approval_value_threshold: Option<Decimal>,
+    /// `region=store_id,store_id;region=store_id` - transfers between stores
+    /// of different regions need approval.
+    pub transfer_approval_store_regions: String,
+    /// Rows written per statement when posting goods receipts to stock.
+    pub stock_posting_batch_size: usize,
+    /// `max_value,max_quantity` under which negative adjustments are
+    /// approved on creation; either side may be empty. Empty disables the
+    /// default limit.
+    pub adjustment_auto_approve_limit: String,
+    /// `store_id=max_value,max_quantity;...` - per-store limits replacing the
+    /// default one.
+    pub adjustment_auto_approve_store_limits: String,
+    /// `reason=max_value,max_quantity;...` - per-reason limits applied on top
+    /// of the store or default limit.
+    pub adjustment_auto_approve_reason_limits: String,
+    /// Comma-separated reasons that always need approval, besides theft.
+    pub adjustment_always_approve_reasons: String,
+    /// `reference_type=priority,...` - reservation priorities, on top of the
+    /// defaults (orders and checkouts above carts).
+    pub reservation_priorities: String,
+    /// Comma-separated reference types whose reservations are never bumped,
+    /// besides orders and checkouts.
+    pub reservation_protected_types: String,
+    /// Maximum number of levels in the category tree, roots included.
+    pub max_category_depth: usize,
+}
+
+impl InventoryConfig {
+    /// Builds the transfer approval policy. Malformed store ids are skipped.
+    pub fn transfer_approval_policy(&self) -> TransferApprovalPolicy {
+        let mut policy = TransferApprovalPolicy::disabled();
+        if let Some(threshold) = self.transfer_approval_value_threshold {
+            policy = policy.with_value_threshold(threshold);
+        }
+        for group in self.transfer_approval_store_regions.split(';') {
+            let Some((region, stores)) = group.split_once('=') else {
+                continue;
+            };
+            let region = region.trim();
+            if region.is_empty() {
+                continue;
+            }
+            for store in stores.split(',') {
+                if let Ok(id) = Uuid::parse_str(store.trim()) {
+                    policy = policy.with_store_region(StoreId::from_uuid(id), region);
+                }
+            }
+        }
+        policy
+    }
+
+    /// Builds the adjustment auto-approval policy. Malformed entries are
+    /// skipped.
+    pub fn adjustment_approval_policy(&self) -> AdjustmentApprovalPolicy {
+        let mut policy = AdjustmentApprovalPolicy::disabled();
+        if let Some(limit) = parse_adjustment_limit(&self.adjustment_auto_approve_limit) {
+            policy = policy.with_default_limit(limit);
+        }
+        for (key, limit) in parse_keyed_limits(&self.adjustment_auto_approve_store_limits) {
+            if let Ok(id) = Uuid::parse_str(key) {
+                policy = policy.with_store_limit(StoreId::from_uuid(id), limit);
+            }
+        }
+        for (key, limit) in parse_keyed_limits(&self.adjustment_auto_approve_reason_limits) {
+            if let Ok(reason) = AdjustmentReason::from_str(key) {
+                policy = policy.with_reason_limit(reason, limit);
+            }
+        }
+        for reason in self.adjustment_always_approve_reasons.split(',') {
+            if let Ok(reason) = AdjustmentReason::from_str(reason.trim()) {
+                policy = policy.with_always_approve(reason);
+            }
+        }
+        policy
+    }
+
+    /// Builds the reservation priority policy. Malformed entries are skipped.
+    pub fn reservation_priority_policy(&self) -> ReservationPriorityPolicy {
+        let mut policy = ReservationPriorityPolicy::default();
+        for entry in self.reservation_priorities.split(',') {
+            let Some((reference_type, priority)) = entry.split_once('=') else {
+                continue;
+            };
+            let reference_type = reference_type.trim();
+            if let (false, Ok(priority)) = (reference_type.is_empty(), priority.trim().parse()) {
+                policy = policy.with_priority(reference_type, priority);
+            }
+        }
+        for reference_type in self.reservation_protected_types.split(',') {
+            let reference_type = reference_type.trim();
+            if !reference_type.is_empty() {
+                policy = policy.with_protected(reference_type);
+            }
+        }
+        policy
+    }
+}
+
+pub struct PurchasingConfig {
+    /// `permission=limit;...` - spend limit per approval tier; an empty
+    /// limit means unlimited. Empty disables spend limits.
+    pub approval_tiers: String,
+    /// Purchase orders worth more than this need two approvers; `None`
+    /// disables dual approval.
+    pub dual_approval_threshold: Option<Decimal>,
+    /// Percentage a goods receipt may go over the ordered quantity; `None`
+    /// skips the quantity match.
+    pub receipt_quantity_tolerance_percent: Option<Decimal>,
+    /// Percentage a receipt unit cost may differ from the ordered cost;
+    /// `None` skips the cost match.
+    pub receipt_cost_tolerance_percent: Option<Decimal>,
+    /// Whether users with goods_receipts:override_match may confirm
+    /// mismatched receipts.
+    pub receipt_match_allow_override: bool,
+}
+
+impl PurchasingConfig {
+    /// Builds the purchase order approval policy. Malformed entries are
+    /// skipped.
+    pub fn approval_policy(&self) -> PurchaseApprovalPolicy {
+        let mut policy = PurchaseApprovalPolicy::disabled();
+        for entry in self.approval_tiers.split(';') {
+            let Some((permission, limit)) = entry.split_once('=') else {
+                continue;
+            };
+            let Ok(permission) = PermissionCode::new(permission.trim()) else {
+                continue;
+            };
+            let limit = limit.trim();
+            if limit.is_empty() {
+                policy = policy.with_tier(permission, None);
+            } else if let Ok(limit) = Decimal::from_str(limit) {
+                policy = policy.with_tier(permission, Some(limit));
+            }
+        }
+        if let Some(threshold) = self.dual_approval_threshold {
+            policy = policy.with_dual_approval_threshold(threshold);
+        }
+        policy
+    }
+
+    /// Builds the goods receipt match policy
+    pub fn receipt_match_policy(&self) -> ReceiptMatchPolicy {
+        let mut policy =
+            ReceiptMatchPolicy::disabled().with_override_allowed(self.receipt_match_allow_override);
+        if let Some(percent) = self.receipt_quantity_tolerance_percent {
+            policy = policy.with_quantity_tolerance(percent);
+        }
+        if let Some(percent) = self.receipt_cost_tolerance_percent {
+            policy = policy.with_cost_tolerance(percent);
+        }
+        policy
+    }
+}
+
+/// Formats of sequential document numbers (purchase orders, receipts,
+/// adjustments, transfers, credit notes).
+pub struct DocumentNumberingConfig {
+    /// `document_type=PREFIX:padding;...` - overrides of the default
+    /// formats, e.g. `purchase_order=OC:6`.
+    pub formats: String,
+}
+
+impl DocumentNumberingConfig {
+    /// Builds the document number service. Malformed entries are skipped.
+    pub fn service(&self) -> DocumentNumberService {
+        let mut service = DocumentNumberService::new();
+        for entry in self.formats.split(';') {
+            let Some((document_type, format)) = entry.split_once('=') else {
+                continue;
+            };
+            let Some((prefix, padding)) = format.split_once(':') else {
+                continue;
+            };
+            let prefix = prefix.trim();
+            if let (Ok(document_type), Ok(padding), false) = (
+                DocumentType::from_str(document_type.trim()),
+                padding.trim().parse::<usize>(),
+                prefix.is_empty(),
+            ) {
+                service =
+                    service.with_format(document_type, DocumentNumberFormat::new(prefix, padding));
+            }
+        }
+        service
+    }
+}
+
+/// Parses `max_value,max_quantity`; `None` if neither side is a number.
+fn parse_adjustment_limit(raw: &str) -> Option<AdjustmentApprovalLimit> {
+    let (value, quantity) = raw.split_once(',').unwrap_or((raw, ""));
+    let limit = AdjustmentApprovalLimit {
+        max_value: Decimal::from_str(value.trim()).ok(),
+        max_quantity: Decimal::from_str(quantity.trim()).ok(),
+    };
+    (limit.max_value.is_some() || limit.max_quantity.is_some()).then_some(limit)
+}
+
+/// Parses `key=max_value,max_quantity;...`.
+fn parse_keyed_limits(raw: &str) -> Vec<(&str, AdjustmentApprovalLimit)> {
+    raw.split(';')
+        .filter_map(|entry| {
+            let (key, limit) = entry.split_once('=')?;
+            Some((key.trim(), parse_adjustment_limit(limit)?))
+        })
+        .collect()
+}
+
 pub struct JobsConfig {
     pub reservation_expiry_interval: u64,
     pub cart_cleanup_interval: u64,
+    pub cart_cleanup_batch_size: i64,
+    pub cart_guest_ttl_hours: i64,
+    pub cart_customer_ttl_hours: i64,
+    pub pickup_expiry_interval: u64,
+    pub pickup_expiry_batch_size: i64,
     pub event_dispatch_interval: u64,
     pub event_dispatch_batch_size: i64,
+    pub outbox_drain_interval: u64,
+    pub outbox_drain_batch_size: i64,
+    pub webhook_dispatch_interval: u64,
+    pub webhook_dispatch_batch_size: i64,
+    pub webhook_max_attempts: i32,
+    pub webhook_retry_base_delay_secs: i64,
+    pub webhook_timeout_secs: u64,
     pub notification_retry_interval: u64,
     pub notification_retry_batch_size: i64,
     pub analytics_recompute_interval: u64,
     pub demand_planning_interval: u64,
     pub subscription_billing_interval: u64,
+    pub auto_markdown_interval: u64,
+    pub composite_cost_refresh_interval: u64,
+    pub composite_cost_auto_adjust: bool,
+    pub audit_archive_interval: u64,
+    pub audit_retention_days: i64,
+    pub audit_archive_batch_size: i64,
 }
 
 impl AppConfig {
@@ -52,6 +344,13 @@ impl AppConfig {
             },
             internal_service_secret: env::var("INTERNAL_SERVICE_SECRET")
                 .expect("INTERNAL_SERVICE_SECRET must be set"),
+            mfa: MfaConfig {
+                encryption_key: env::var("MFA_ENCRYPTION_KEY")
+                    .expect("MFA_ENCRYPTION_KEY must be set"),
+                issuer: env::var("MFA_ISSUER").unwrap_or_else(|_| "POS".to_string()),
+                max_failed_attempts: env_or("MFA_MAX_FAILED_ATTEMPTS", 5),
+                lockout_minutes: env_or("MFA_LOCKOUT_MINUTES", 15),
+            },
             jwt_secret: env::var("JWT_SECRET")
                 .expect("JWT_SECRET environment variable must be set"),
             cors_allowed_origins: env::var("CORS_ALLOWED_ORIGINS")
@@ -62,16 +361,128 @@ impl AppConfig {
                     .unwrap_or_else(|_| "/uploads".to_string()),
                 root: env::var("IMAGE_STORAGE_ROOT").unwrap_or_else(|_| "./uploads".to_string()),
             },
+            webhooks: WebhooksConfig {
+                signing_secret: env::var("WEBHOOK_SIGNING_SECRET")
+                    .ok()
+                    .filter(|s| !s.is_empty()),
+                signature_algorithm: env::var("WEBHOOK_SIGNATURE_ALGORITHM")
+                    .unwrap_or_else(|_| "hmac-sha256".to_string()),
+                tolerance_secs: env_or("WEBHOOK_TOLERANCE_SECS", 300),
+                timeout_secs: env_or("WEBHOOK_TIMEOUT_SECS", 10),
+            },
+            sales: SalesConfig {
+                shift_reopen_window_minutes: env_or(
+                    "SHIFT_REOPEN_WINDOW_MINUTES",
+                    sales::DEFAULT_SHIFT_REOPEN_WINDOW_MINUTES,
+                ),
+                shift_variance_threshold: env_or(
+                    "SHIFT_VARIANCE_THRESHOLD",
+                    sales::DEFAULT_SHIFT_VARIANCE_THRESHOLD,
+                ),
+                restock_cost_basis: env_or(
+                    "CREDIT_NOTE_RESTOCK_COST_BASIS",
+                    sales::RestockCostBasis::default(),
+                ),
+            },
+            inventory: InventoryConfig {
+                transfer_approval_value_threshold: Some(env_or(
+                    "TRANSFER_APPROVAL_VALUE_THRESHOLD",
+                    Decimal::ZERO,
+                ))
+                .filter(|threshold| *threshold > Decimal::ZERO),
+                transfer_approval_store_regions: env::var("TRANSFER_APPROVAL_STORE_REGIONS")
+                    .unwrap_or_default(),
+                stock_posting_batch_size: env_or(
+                    "STOCK_POSTING_BATCH_SIZE",
+                    DEFAULT_STOCK_POSTING_BATCH_SIZE,
+                )
+                .max(1),
+                adjustment_auto_approve_limit: env::var("ADJUSTMENT_AUTO_APPROVE_LIMIT")
+                    .unwrap_or_default(),
+                adjustment_auto_approve_store_limits: env::var(
+                    "ADJUSTMENT_AUTO_APPROVE_STORE_LIMITS",
+                )
+                .unwrap_or_default(),
+                adjustment_auto_approve_reason_limits: env::var(
+                    "ADJUSTMENT_AUTO_APPROVE_REASON_LIMITS",
+                )
+                .unwrap_or_default(),
+                adjustment_always_approve_reasons: env::var("ADJUSTMENT_ALWAYS_APPROVE_REASONS")
+                    .unwrap_or_default(),
+                reservation_priorities: env::var("RESERVATION_PRIORITIES").unwrap_or_default(),
+                reservation_protected_types: env::var("RESERVATION_PROTECTED_TYPES")
+                    .unwrap_or_default(),
+                max_category_depth: env_or("CATEGORY_MAX_DEPTH", DEFAULT_MAX_CATEGORY_DEPTH).max(1),
+            },
+            purchasing: PurchasingConfig {
+                approval_tiers: env::var("PURCHASE_APPROVAL_TIERS").unwrap_or_default(),
+                dual_approval_threshold: Some(env_or(
+                    "PURCHASE_DUAL_APPROVAL_THRESHOLD",
+                    Decimal::ZERO,
+                ))
+                .filter(|threshold| *threshold > Decimal::ZERO),
+                receipt_quantity_tolerance_percent: env::var(
+                    "RECEIPT_MATCH_QUANTITY_TOLERANCE_PERCENT",
+                )
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .filter(|percent: &Decimal| *percent >= Decimal::ZERO),
+                receipt_cost_tolerance_percent: env::var("RECEIPT_MATCH_COST_TOLERANCE_PERCENT")
+                    .ok()
+                    .and_then(|v| v.parse().ok())
+                    .filter(|percent: &Decimal| *percent >= Decimal::ZERO),
+                receipt_match_allow_override: env_or("RECEIPT_MATCH_ALLOW_OVERRIDE", true),
+            },
+            document_numbering: DocumentNumberingConfig {
+                formats: env::var("DOCUMENT_NUMBER_FORMATS").unwrap_or_default(),
+            },
+            tax_id_default_country: env::var("TAX_ID_DEFAULT_COUNTRY")
+                .ok()
+                .filter(|s| !s.is_empty())
+                .unwrap_or_else(|| common::tax_id::DEFAULT_TAX_ID_COUNTRY.to_string()),
             jobs: JobsConfig {
                 reservation_expiry_interval: env_or("RESERVATION_EXPIRY_INTERVAL_SECS", 300),
                 cart_cleanup_interval: env_or("CART_CLEANUP_INTERVAL_SECS", 900),
+                cart_cleanup_batch_size: env_or("CART_CLEANUP_BATCH_SIZE", 500),
+                cart_guest_ttl_hours: env_or(
+                    "CART_GUEST_TTL_HOURS",
+                    CartTtlPolicy::DEFAULT_GUEST_TTL_HOURS,
+                ),
+                cart_customer_ttl_hours: env_or(
+                    "CART_CUSTOMER_TTL_HOURS",
+                    CartTtlPolicy::DEFAULT_CUSTOMER_TTL_HOURS,
+                ),
+                pickup_expiry_interval: env_or("PICKUP_EXPIRY_INTERVAL_SECS", 3600),
+                pickup_expiry_batch_size: env_or("PICKUP_EXPIRY_BATCH_SIZE", 200),
                 event_dispatch_interval: env_or("EVENT_DISPATCH_INTERVAL_SECS", 5),
                 event_dispatch_batch_size: env_or("EVENT_DISPATCH_BATCH_SIZE", 100),
+                outbox_drain_interval: env_or("OUTBOX_DRAIN_INTERVAL_SECS", 5),
+                outbox_drain_batch_size: env_or("OUTBOX_DRAIN_BATCH_SIZE", 500),
+                webhook_dispatch_interval: env_or("WEBHOOK_DISPATCH_INTERVAL_SECS", 10),
+                webhook_dispatch_batch_size: env_or("WEBHOOK_DISPATCH_BATCH_SIZE", 100),
+                webhook_max_attempts: env_or(
+                    "WEBHOOK_MAX_ATTEMPTS",
+                    WebhookRetryPolicy::DEFAULT_MAX_ATTEMPTS,
+                ),
+                webhook_retry_base_delay_secs: env_or(
+                    "WEBHOOK_RETRY_BASE_DELAY_SECS",
+                    WebhookRetryPolicy::DEFAULT_BASE_DELAY_SECS,
+                ),
+                webhook_timeout_secs: env_or("WEBHOOK_TIMEOUT_SECS", 10),
                 notification_retry_interval: env_or("NOTIFICATION_RETRY_INTERVAL_SECS", 60),
                 notification_retry_batch_size: env_or("NOTIFICATION_RETRY_BATCH_SIZE", 50),
                 analytics_recompute_interval: env_or("ANALYTICS_RECOMPUTE_INTERVAL_SECS", 1800),
                 demand_planning_interval: env_or("DEMAND_PLANNING_RECOMPUTE_INTERVAL_SECS", 86_400),
                 subscription_billing_interval: env_or("SUBSCRIPTION_BILLING_INTERVAL_SECS", 3600),
+                auto_markdown_interval: env_or("AUTO_MARKDOWN_INTERVAL_SECS", 86_400),
+                composite_cost_refresh_interval: env_or(
+                    "COMPOSITE_COST_REFRESH_INTERVAL_SECS",
+                    86_400,
+                ),
+                composite_cost_auto_adjust: env_or("COMPOSITE_COST_AUTO_ADJUST", false),
+                audit_archive_interval: env_or("AUDIT_ARCHIVE_INTERVAL_SECS", 86_400),
+                audit_retention_days: env_or("AUDIT_RETENTION_DAYS", 365),
+                audit_archive_batch_size: env_or("AUDIT_ARCHIVE_BATCH_SIZE", 5000),
             },
         }
     }
@@ -83,3 +494,164 @@ fn env_or<T: FromStr>(name: &str, default: T) -> T {
         .and_then(|v| v.parse().ok())
         .unwrap_or(default)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_transfer_approval_policy_parses_regions() {
+        let (north, north2, south) = (StoreId::new(), StoreId::new(), StoreId::new());
+        let config = InventoryConfig {
+            transfer_approval_value_threshold: Some(Decimal::new(5000, 0)),
+            transfer_approval_store_regions: format!(
+                "north={}, {};south={};broken;=ignored",
+                north.into_uuid(),
+                north2.into_uuid(),
+                south.into_uuid()
+            ),
+            stock_posting_batch_size: DEFAULT_STOCK_POSTING_BATCH_SIZE,
+            adjustment_auto_approve_limit: String::new(),
+            adjustment_auto_approve_store_limits: String::new(),
+            adjustment_auto_approve_reason_limits: String::new(),
+            adjustment_always_approve_reasons: String::new(),
+            reservation_priorities: String::new(),
+            reservation_protected_types: String::new(),
+            max_category_depth: DEFAULT_MAX_CATEGORY_DEPTH,
+        };
+
+        let policy = config.transfer_approval_policy();
+        assert_eq!(policy.value_threshold(), Some(Decimal::new(5000, 0)));
+        assert_eq!(policy.region_of(north), Some("north"));
+        assert_eq!(policy.region_of(north2), Some("north"));
+        assert_eq!(policy.region_of(south), Some("south"));
+    }
+
+    #[test]
+    fn test_transfer_approval_policy_empty_is_disabled() {
+        let config = InventoryConfig {
+            transfer_approval_value_threshold: None,
+            transfer_approval_store_regions: String::new(),
+            stock_posting_batch_size: DEFAULT_STOCK_POSTING_BATCH_SIZE,
+            adjustment_auto_approve_limit: String::new(),
+            adjustment_auto_approve_store_limits: String::new(),
+            adjustment_auto_approve_reason_limits: String::new(),
+            adjustment_always_approve_reasons: String::new(),
+            reservation_priorities: String::new(),
+            reservation_protected_types: String::new(),
+            max_category_depth: DEFAULT_MAX_CATEGORY_DEPTH,
+        };
+        assert_eq!(
+            config.transfer_approval_policy(),
+            TransferApprovalPolicy::disabled()
+        );
+    }
+
+    #[test]
+    fn test_adjustment_approval_policy_parses_limits() {
+        let store = StoreId::new();
+        let config = InventoryConfig {
+            transfer_approval_value_threshold: None,
+            transfer_approval_store_regions: String::new(),
+            stock_posting_batch_size: DEFAULT_STOCK_POSTING_BATCH_SIZE,
+            adjustment_auto_approve_limit: "500,".to_string(),
+            adjustment_auto_approve_store_limits: format!("{}=,10;broken;x=1", store.into_uuid()),
+            adjustment_auto_approve_reason_limits: "expired=100,5;bogus=1,1".to_string(),
+            adjustment_always_approve_reasons: "loss, nope".to_string(),
+            reservation_priorities: String::new(),
+            reservation_protected_types: String::new(),
+            max_category_depth: DEFAULT_MAX_CATEGORY_DEPTH,
+        };
+
+        let expected = AdjustmentApprovalPolicy::disabled()
+            .with_default_limit(AdjustmentApprovalLimit {
+                max_value: Some(Decimal::new(500, 0)),
+                max_quantity: None,
+            })
+            .with_store_limit(
+                store,
+                AdjustmentApprovalLimit {
+                    max_value: None,
+                    max_quantity: Some(Decimal::new(10, 0)),
+                },
+            )
+            .with_reason_limit(
+                AdjustmentReason::Expiration,
+                AdjustmentApprovalLimit {
+                    max_value: Some(Decimal::new(100, 0)),
+                    max_quantity: Some(Decimal::new(5, 0)),
+                },
+            )
+            .with_always_approve(AdjustmentReason::Loss);
+        assert_eq!(config.adjustment_approval_policy(), expected);
+    }
+
+    #[test]
+    fn test_reservation_priority_policy_parses_overrides() {
+        let config = InventoryConfig {
+            transfer_approval_value_threshold: None,
+            transfer_approval_store_regions: String::new(),
+            stock_posting_batch_size: DEFAULT_STOCK_POSTING_BATCH_SIZE,
+            adjustment_auto_approve_limit: String::new(),
+            adjustment_auto_approve_store_limits: String::new(),
+            adjustment_auto_approve_reason_limits: String::new(),
+            adjustment_always_approve_reasons: String::new(),
+            reservation_priorities: "quote=10, cart=-5,broken,=3,x=y".to_string(),
+            reservation_protected_types: "quote, ".to_string(),
+            max_category_depth: DEFAULT_MAX_CATEGORY_DEPTH,
+        };
+
+        let expected = ReservationPriorityPolicy::default()
+            .with_priority("quote", 10)
+            .with_priority("cart", -5)
+            .with_protected("quote");
+        assert_eq!(config.reservation_priority_policy(), expected);
+    }
+
+    #[test]
+    fn test_purchase_approval_policy_parses_tiers() {
+        let config = PurchasingConfig {
+            approval_tiers:
+                "purchase_orders:approve=1000; purchase_orders:approve_tier2=;broken=5;x:y=z"
+                    .to_string(),
+            dual_approval_threshold: Some(Decimal::new(5000, 0)),
+            receipt_quantity_tolerance_percent: None,
+            receipt_cost_tolerance_percent: None,
+            receipt_match_allow_override: true,
+        };
+
+        let expected = PurchaseApprovalPolicy::disabled()
+            .with_tier(
+                PermissionCode::new("purchase_orders:approve").unwrap(),
+                Some(Decimal::new(1000, 0)),
+            )
+            .with_tier(
+                PermissionCode::new("purchase_orders:approve_tier2").unwrap(),
+                None,
+            )
+            .with_dual_approval_threshold(Decimal::new(5000, 0));
+        assert_eq!(config.approval_policy(), expected);
+    }
+
+    #[test]
+    fn test_document_numbering_parses_formats() {
+        let config = DocumentNumberingConfig {
+            formats: "purchase_order=OC:6; credit_note = NC:4;invoice=F:8;transfer=T;x=:3"
+                .to_string(),
+        };
+
+        let service = config.service();
+        assert_eq!(
+            service.format_of(DocumentType::PurchaseOrder),
+            DocumentNumberFormat::new("OC", 6)
+        );
+        assert_eq!(
+            service.format_of(DocumentType::CreditNote),
+            DocumentNumberFormat::new("NC", 4)
+        );
+        assert_eq!(
+            service.format_of(DocumentType::StockTransfer),
+            DocumentNumberFormat::new("TRF", 6)
+        );
+    }
+}
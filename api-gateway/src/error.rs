@@ -18,6 +18,7 @@ use fiscal::FiscalError;
 use identity::{AuthError, ErrorResponse, IdentityError};
 use inventory::InventoryError;
 use loyalty::LoyaltyError;
+use notifications::NotificationsError;
 use payments::PaymentsError;
 use pos_core::CoreError;
 use purchasing::PurchasingError;
@@ -45,6 +46,7 @@ use tenancy::TenancyError;
 /// | AccountDisabled | 401 | ACCOUNT_DISABLED |
 /// | TokenExpired | 401 | TOKEN_EXPIRED |
 /// | InvalidToken | 401 | INVALID_TOKEN |
+/// | RefreshTokenReused | 401 | TOKEN_REUSED |
 /// | PasswordTooShort | 400 | VALIDATION_ERROR |
 /// | InvalidEmailFormat | 400 | VALIDATION_ERROR |
 /// | InvalidUsernameFormat | 400 | VALIDATION_ERROR |
@@ -103,6 +105,10 @@ impl From<AuthError> for AppError {
             }
             AuthError::TokenExpired => (StatusCode::UNAUTHORIZED, ErrorResponse::token_expired()),
             AuthError::InvalidToken => (StatusCode::UNAUTHORIZED, ErrorResponse::invalid_token()),
+            AuthError::RefreshTokenReused => (
+                StatusCode::UNAUTHORIZED,
+                ErrorResponse::new("TOKEN_REUSED", err.to_string()),
+            ),
 
             // 400 Bad Request - Validation errors
             AuthError::PasswordTooShort => (
@@ -121,7 +127,7 @@ impl From<AuthError> for AppError {
             ),
             AuthError::InvalidName(msg) => (
                 StatusCode::BAD_REQUEST,
-                ErrorResponse::validation_error(msg),
+                ErrorResponse::validation_error(msg.clone()),
             ),
 
             // 409 Conflict - Duplicate resources
@@ -135,6 +141,24 @@ impl From<AuthError> for AppError {
             // 404 Not Found
             AuthError::StoreNotFound => (StatusCode::NOT_FOUND, ErrorResponse::store_not_found()),
 
+            // Multi-factor authentication
+            AuthError::InvalidMfaCode => (
+                StatusCode::UNAUTHORIZED,
+                ErrorResponse::new("INVALID_MFA_CODE", "Invalid verification code"),
+            ),
+            AuthError::MfaLocked => (
+                StatusCode::TOO_MANY_REQUESTS,
+                ErrorResponse::new("MFA_LOCKED", err.to_string()),
+            ),
+            AuthError::MfaNotEnrolled => (
+                StatusCode::CONFLICT,
+                ErrorResponse::new("MFA_NOT_ENROLLED", err.to_string()),
+            ),
+            AuthError::MfaAlreadyEnabled => (
+                StatusCode::CONFLICT,
+                ErrorResponse::new("MFA_ALREADY_ENABLED", err.to_string()),
+            ),
+
             // 500 Internal Server Error - Don't expose internal details
             AuthError::Internal(_) => (
                 StatusCode::INTERNAL_SERVER_ERROR,
@@ -166,6 +190,14 @@ impl From<IdentityError> for AppError {
                 StatusCode::BAD_REQUEST,
                 ErrorResponse::validation_error("Invalid username format"),
             ),
+            IdentityError::InvalidStoreSettings(msg) => (
+                StatusCode::BAD_REQUEST,
+                ErrorResponse::new("INVALID_STORE_SETTINGS", msg.clone()),
+            ),
+            IdentityError::InvalidAuditRetention(msg) | IdentityError::InvalidAuditQuery(msg) => (
+                StatusCode::BAD_REQUEST,
+                ErrorResponse::validation_error(msg.clone()),
+            ),
 
             // 409 Conflict - Duplicate resources
             IdentityError::DuplicatePermission(name) => (
@@ -209,6 +241,10 @@ impl From<IdentityError> for AppError {
                 StatusCode::NOT_FOUND,
                 ErrorResponse::new("USER_NOT_IN_STORE", "User is not a member of this store"),
             ),
+            IdentityError::LegalHoldNotFound(_, _) => (
+                StatusCode::NOT_FOUND,
+                ErrorResponse::new("LEGAL_HOLD_NOT_FOUND", "No legal hold on this record"),
+            ),
 
             // 403 Forbidden - Protected resources
             IdentityError::ProtectedRoleCannotBeDeleted => (
@@ -230,6 +266,10 @@ impl From<IdentityError> for AppError {
                 StatusCode::INTERNAL_SERVER_ERROR,
                 ErrorResponse::internal_error(),
             ),
+            IdentityError::MfaSecretCipher => (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                ErrorResponse::internal_error(),
+            ),
             IdentityError::Database(_) => (
                 StatusCode::INTERNAL_SERVER_ERROR,
                 ErrorResponse::internal_error(),
@@ -284,6 +324,14 @@ impl From<CoreError> for AppError {
                 StatusCode::BAD_REQUEST,
                 ErrorResponse::validation_error("Invalid CAI range: start must be <= end"),
             ),
+            CoreError::InvalidCaiThreshold(msg) => (
+                StatusCode::BAD_REQUEST,
+                ErrorResponse::validation_error(format!("Invalid CAI warning threshold: {}", msg)),
+            ),
+            CoreError::InvalidPageCursor(_) => (
+                StatusCode::BAD_REQUEST,
+                ErrorResponse::new("INVALID_PAGE_CURSOR", err.to_string()),
+            ),
             CoreError::NoCaiAssigned(id) => (
                 StatusCode::BAD_REQUEST,
                 ErrorResponse::new(
@@ -360,6 +408,26 @@ impl From<InventoryError> for AppError {
                     format!("Parent category not found: {}", id),
                 ),
             ),
+            InventoryError::CategoryCycle => (
+                StatusCode::BAD_REQUEST,
+                ErrorResponse::new(
+                    "CATEGORY_CYCLE",
+                    "A category cannot be placed under itself or one of its descendants",
+                ),
+            ),
+            InventoryError::CategoryDepthExceeded(max) => (
+                StatusCode::BAD_REQUEST,
+                ErrorResponse::new(
+                    "CATEGORY_DEPTH_EXCEEDED",
+                    format!("Category tree cannot be deeper than {} levels", max),
+                ),
+            ),
+            InventoryError::InvalidCategoryOrder => (
+                StatusCode::BAD_REQUEST,
+                ErrorResponse::validation_error(
+                    "Reorder must list every sibling category exactly once",
+                ),
+            ),
             InventoryError::ProductNotFound(id) => (
                 StatusCode::NOT_FOUND,
                 ErrorResponse::new("PRODUCT_NOT_FOUND", format!("Product not found: {}", id)),
@@ -408,6 +476,13 @@ impl From<InventoryError> for AppError {
                 StatusCode::NOT_FOUND,
                 ErrorResponse::new("TRANSFER_NOT_FOUND", format!("Transfer not found: {}", id)),
             ),
+            InventoryError::RepriceBatchNotFound(id) => (
+                StatusCode::NOT_FOUND,
+                ErrorResponse::new(
+                    "REPRICE_BATCH_NOT_FOUND",
+                    format!("Reprice batch not found: {}", id),
+                ),
+            ),
 
             // -----------------------------------------------------------------
             // 409 Conflict - Duplicate resources and version conflicts
@@ -430,6 +505,14 @@ impl From<InventoryError> for AppError {
                     format!("Barcode '{}' already exists", barcode),
                 ),
             ),
+            InventoryError::InvalidExportColumn(msg) => (
+                StatusCode::BAD_REQUEST,
+                ErrorResponse::new("VALIDATION_ERROR", msg),
+            ),
+            InventoryError::InvalidImportRow(msg) => (
+                StatusCode::BAD_REQUEST,
+                ErrorResponse::new("VALIDATION_ERROR", msg),
+            ),
             InventoryError::ActiveRecipeExists => (
                 StatusCode::CONFLICT,
                 ErrorResponse::new(
@@ -444,6 +527,16 @@ impl From<InventoryError> for AppError {
                     "Record was modified by another process, please retry",
                 ),
             ),
+            InventoryError::ConcurrencyExhausted { attempts } => (
+                StatusCode::CONFLICT,
+                ErrorResponse::new(
+                    "CONCURRENCY_EXHAUSTED",
+                    format!(
+                        "Stock is under heavy contention; update gave up after {} attempts",
+                        attempts
+                    ),
+                ),
+            ),
             InventoryError::StockAlreadyExists {
                 store_id,
                 product_id,
@@ -470,10 +563,28 @@ impl From<InventoryError> for AppError {
             // -----------------------------------------------------------------
             // 400 Bad Request - Validation and business rule violations
             // -----------------------------------------------------------------
+            InventoryError::EmptySearchQuery => (
+                StatusCode::BAD_REQUEST,
+                ErrorResponse::validation_error("Search query cannot be empty"),
+            ),
             InventoryError::VariantsNotEnabled => (
                 StatusCode::BAD_REQUEST,
                 ErrorResponse::validation_error("Product does not have variants enabled"),
             ),
+            InventoryError::InvalidVariantAxes(msg) => (
+                StatusCode::BAD_REQUEST,
+                ErrorResponse::new("INVALID_VARIANT_AXES", msg.clone()),
+            ),
+            InventoryError::TooManyVariantCombinations(requested, max) => (
+                StatusCode::BAD_REQUEST,
+                ErrorResponse::new(
+                    "TOO_MANY_VARIANT_COMBINATIONS",
+                    format!(
+                        "{} combinations requested; at most {} can be generated at once",
+                        requested, max
+                    ),
+                ),
+            ),
             InventoryError::InsufficientStock => (
                 StatusCode::BAD_REQUEST,
                 ErrorResponse::new("INSUFFICIENT_STOCK", "Insufficient stock available"),
@@ -501,6 +612,17 @@ impl From<InventoryError> for AppError {
                     "Invalid reservation status transition",
                 ),
             ),
+            InventoryError::InvalidAutoExtend => (
+                StatusCode::BAD_REQUEST,
+                ErrorResponse::validation_error("auto_extend_seconds must be between 1 and 86400"),
+            ),
+            InventoryError::ReservationNotAutoExtending => (
+                StatusCode::BAD_REQUEST,
+                ErrorResponse::new(
+                    "RESERVATION_NOT_AUTO_EXTENDING",
+                    "Reservation was created without auto_extend_seconds",
+                ),
+            ),
             InventoryError::IngredientInUse => (
                 StatusCode::BAD_REQUEST,
                 ErrorResponse::new(
@@ -554,6 +676,129 @@ impl From<InventoryError> for AppError {
                 StatusCode::BAD_REQUEST,
                 ErrorResponse::validation_error("Transfer has no items"),
             ),
+            InventoryError::TransferApprovalRequired => (
+                StatusCode::CONFLICT,
+                ErrorResponse::new(
+                    "TRANSFER_APPROVAL_REQUIRED",
+                    "Transfer requires approval before shipping",
+                ),
+            ),
+            InventoryError::IngredientUnavailable(name) => (
+                StatusCode::BAD_REQUEST,
+                ErrorResponse::new(
+                    "INGREDIENT_UNAVAILABLE",
+                    format!("Ingredient unavailable: {}", name),
+                ),
+            ),
+            InventoryError::InvalidReceivedQuantity(item_id) => (
+                StatusCode::BAD_REQUEST,
+                ErrorResponse::new(
+                    "INVALID_RECEIVED_QUANTITY",
+                    format!(
+                        "Received quantity for transfer item {} must be between zero and the quantity shipped",
+                        item_id
+                    ),
+                ),
+            ),
+            InventoryError::EmptyRepriceBatch => (
+                StatusCode::BAD_REQUEST,
+                ErrorResponse::validation_error("Reprice batch has no items"),
+            ),
+            InventoryError::NegativeCost => (
+                StatusCode::BAD_REQUEST,
+                ErrorResponse::validation_error("Cost cannot be negative"),
+            ),
+            InventoryError::InvalidMarkupRule(msg) => (
+                StatusCode::BAD_REQUEST,
+                ErrorResponse::new(
+                    "INVALID_MARKUP_RULE",
+                    format!("Invalid markup rule: {}", msg),
+                ),
+            ),
+            InventoryError::InvalidPriceAdjustment(msg) => (
+                StatusCode::BAD_REQUEST,
+                ErrorResponse::new(
+                    "INVALID_PRICE_ADJUSTMENT",
+                    format!("Invalid price adjustment: {}", msg),
+                ),
+            ),
+            InventoryError::InvalidReorderLevel(msg) => (
+                StatusCode::BAD_REQUEST,
+                ErrorResponse::new(
+                    "INVALID_REORDER_LEVEL",
+                    format!("Invalid reorder level: {}", msg),
+                ),
+            ),
+            InventoryError::InvalidProductVendor(msg) => (
+                StatusCode::BAD_REQUEST,
+                ErrorResponse::new(
+                    "INVALID_PRODUCT_VENDOR",
+                    format!("Invalid product vendor: {}", msg),
+                ),
+            ),
+            InventoryError::ValuationSnapshotNotFound(store_id, period_end) => (
+                StatusCode::NOT_FOUND,
+                ErrorResponse::new(
+                    "VALUATION_SNAPSHOT_NOT_FOUND",
+                    format!(
+                        "Valuation snapshot not found for store {} and period ending {}",
+                        store_id, period_end
+                    ),
+                ),
+            ),
+            InventoryError::ValuationPeriodNotEnded(period_end) => (
+                StatusCode::BAD_REQUEST,
+                ErrorResponse::validation_error(format!(
+                    "Cannot snapshot the period ending {} before it has ended",
+                    period_end
+                )),
+            ),
+            InventoryError::PeriodNotFound(id) => (
+                StatusCode::NOT_FOUND,
+                ErrorResponse::new(
+                    "PERIOD_NOT_FOUND",
+                    format!("Inventory period not found: {}", id),
+                ),
+            ),
+            InventoryError::InvalidPeriodRange => (
+                StatusCode::BAD_REQUEST,
+                ErrorResponse::validation_error("Period start must not be after its end"),
+            ),
+            InventoryError::PeriodNotEnded(period_end) => (
+                StatusCode::BAD_REQUEST,
+                ErrorResponse::validation_error(format!(
+                    "Cannot close the period ending {} before it has ended",
+                    period_end
+                )),
+            ),
+            InventoryError::PeriodOverlap(start, end) => (
+                StatusCode::CONFLICT,
+                ErrorResponse::new(
+                    "PERIOD_OVERLAP",
+                    format!("Period overlaps the period from {} to {}", start, end),
+                ),
+            ),
+            InventoryError::PeriodAlreadyClosed(id) => (
+                StatusCode::CONFLICT,
+                ErrorResponse::new(
+                    "PERIOD_ALREADY_CLOSED",
+                    format!("Inventory period {} is already closed", id),
+                ),
+            ),
+            InventoryError::PeriodNotClosed(id) => (
+                StatusCode::CONFLICT,
+                ErrorResponse::new(
+                    "PERIOD_NOT_CLOSED",
+                    format!("Inventory period {} is not closed", id),
+                ),
+            ),
+            InventoryError::PeriodClosed(start, end) => (
+                StatusCode::CONFLICT,
+                ErrorResponse::new(
+                    "PERIOD_CLOSED",
+                    format!("The period from {} to {} is closed", start, end),
+                ),
+            ),
             InventoryError::InvalidStatusTransition => (
                 StatusCode::BAD_REQUEST,
                 ErrorResponse::new("INVALID_STATUS_TRANSITION", "Invalid status transition"),
@@ -568,14 +813,58 @@ impl From<InventoryError> for AppError {
                 StatusCode::BAD_REQUEST,
                 ErrorResponse::validation_error("Invalid barcode: maximum 100 characters"),
             ),
+            InventoryError::InvalidEan13CheckDigit(barcode) => (
+                StatusCode::BAD_REQUEST,
+                ErrorResponse::new(
+                    "INVALID_EAN13_CHECK_DIGIT",
+                    format!("Invalid EAN-13 barcode '{}': wrong check digit", barcode),
+                ),
+            ),
+            InventoryError::InvalidBarcodePrefix(msg) => (
+                StatusCode::BAD_REQUEST,
+                ErrorResponse::new("INVALID_BARCODE_PREFIX", msg.clone()),
+            ),
+            InventoryError::BarcodeSequenceExhausted(prefix) => (
+                StatusCode::CONFLICT,
+                ErrorResponse::new(
+                    "BARCODE_SEQUENCE_EXHAUSTED",
+                    format!("No barcodes left under prefix '{}'", prefix),
+                ),
+            ),
             InventoryError::InvalidUnitOfMeasure => (
                 StatusCode::BAD_REQUEST,
                 ErrorResponse::validation_error("Invalid unit of measure"),
             ),
+            InventoryError::IncompatibleUnits { from, to } => (
+                StatusCode::BAD_REQUEST,
+                ErrorResponse::new(
+                    "INCOMPATIBLE_UNITS",
+                    format!("Cannot convert {} to {}", from, to),
+                ),
+            ),
+            InventoryError::InvalidUnitConversion(msg) => (
+                StatusCode::BAD_REQUEST,
+                ErrorResponse::new(
+                    "INVALID_UNIT_CONVERSION",
+                    format!("Invalid unit conversion: {}", msg),
+                ),
+            ),
             InventoryError::InvalidMovementType => (
                 StatusCode::BAD_REQUEST,
                 ErrorResponse::validation_error("Invalid movement type"),
             ),
+            InventoryError::InvalidPageCursor(_) => (
+                StatusCode::BAD_REQUEST,
+                ErrorResponse::new("INVALID_PAGE_CURSOR", err.to_string()),
+            ),
+            InventoryError::InvalidTaxCategory => (
+                StatusCode::BAD_REQUEST,
+                ErrorResponse::validation_error("Invalid tax category"),
+            ),
+            InventoryError::InvalidCostingMethod => (
+                StatusCode::BAD_REQUEST,
+                ErrorResponse::validation_error("Invalid costing method"),
+            ),
             InventoryError::InvalidAdjustmentType => (
                 StatusCode::BAD_REQUEST,
                 ErrorResponse::validation_error("Invalid adjustment type"),
@@ -592,6 +881,50 @@ impl From<InventoryError> for AppError {
                 StatusCode::BAD_REQUEST,
                 ErrorResponse::validation_error("Invalid reservation status"),
             ),
+            InventoryError::InvalidRepriceBatchStatus => (
+                StatusCode::BAD_REQUEST,
+                ErrorResponse::validation_error("Invalid reprice batch status"),
+            ),
+            InventoryError::InvalidPeriodStatus => (
+                StatusCode::BAD_REQUEST,
+                ErrorResponse::validation_error("Invalid period status"),
+            ),
+            InventoryError::InvalidSerialStatus => (
+                StatusCode::BAD_REQUEST,
+                ErrorResponse::validation_error("Invalid serial status"),
+            ),
+            InventoryError::InvalidSerialNumber => (
+                StatusCode::BAD_REQUEST,
+                ErrorResponse::validation_error("Invalid serial number"),
+            ),
+            InventoryError::ProductNotSerialized(id) => (
+                StatusCode::BAD_REQUEST,
+                ErrorResponse::new(
+                    "PRODUCT_NOT_SERIALIZED",
+                    format!("Product is not serial-tracked: {}", id),
+                ),
+            ),
+            InventoryError::SerialNotFound(serial) => (
+                StatusCode::NOT_FOUND,
+                ErrorResponse::new(
+                    "SERIAL_NOT_FOUND",
+                    format!("Serial number not found: {}", serial),
+                ),
+            ),
+            InventoryError::DuplicateSerial(serial) => (
+                StatusCode::CONFLICT,
+                ErrorResponse::new(
+                    "DUPLICATE_SERIAL",
+                    format!("Serial number '{}' is already registered", serial),
+                ),
+            ),
+            InventoryError::SerialNotAvailable(serial) => (
+                StatusCode::CONFLICT,
+                ErrorResponse::new(
+                    "SERIAL_NOT_AVAILABLE",
+                    format!("Serial number '{}' is not available", serial),
+                ),
+            ),
             InventoryError::InvalidAdjustmentStatus => (
                 StatusCode::BAD_REQUEST,
                 ErrorResponse::validation_error("Invalid adjustment status"),
@@ -618,6 +951,14 @@ impl From<InventoryError> for AppError {
                 StatusCode::BAD_REQUEST,
                 ErrorResponse::new("INVALID_OPERATION", msg),
             ),
+            InventoryError::Forbidden { .. } => (
+                StatusCode::FORBIDDEN,
+                ErrorResponse::new("FORBIDDEN", err.to_string()),
+            ),
+            InventoryError::StoreAccessDenied(_) => (
+                StatusCode::FORBIDDEN,
+                ErrorResponse::new("STORE_ACCESS_DENIED", err.to_string()),
+            ),
             InventoryError::NotImplemented => (
                 StatusCode::INTERNAL_SERVER_ERROR,
                 ErrorResponse::new("NOT_IMPLEMENTED", "Feature not yet implemented"),
@@ -710,6 +1051,13 @@ impl From<PurchasingError> for AppError {
                     format!("Receipt number '{}' already exists", number),
                 ),
             ),
+            PurchasingError::SerialAlreadyRegistered(serial) => (
+                StatusCode::CONFLICT,
+                ErrorResponse::new(
+                    "DUPLICATE_SERIAL",
+                    format!("Serial number '{}' is already registered", serial),
+                ),
+            ),
 
             // -----------------------------------------------------------------
             // 400 Bad Request - Business rule violations
@@ -736,6 +1084,26 @@ impl From<PurchasingError> for AppError {
                     "User cannot approve their own purchase order",
                 ),
             ),
+            PurchasingError::DuplicateApprover => (
+                StatusCode::BAD_REQUEST,
+                ErrorResponse::new(
+                    "DUPLICATE_APPROVER",
+                    "Purchase order needs a second, different approver",
+                ),
+            ),
+            PurchasingError::ApprovalLimitExceeded {
+                total,
+                required_tier,
+            } => (
+                StatusCode::FORBIDDEN,
+                ErrorResponse::new(
+                    "APPROVAL_LIMIT_EXCEEDED",
+                    format!(
+                        "Purchase order total {} exceeds approval limit; requires {}",
+                        total, required_tier
+                    ),
+                ),
+            ),
             PurchasingError::OrderAlreadyCancelled => (
                 StatusCode::BAD_REQUEST,
                 ErrorResponse::new(
@@ -764,6 +1132,10 @@ impl From<PurchasingError> for AppError {
                     "Cannot cancel: purchase order has received goods",
                 ),
             ),
+            PurchasingError::CancellationReasonRequired => (
+                StatusCode::BAD_REQUEST,
+                ErrorResponse::validation_error("Cancellation reason is required"),
+            ),
             PurchasingError::InvalidQuantityOrdered => (
                 StatusCode::BAD_REQUEST,
                 ErrorResponse::validation_error("Quantity ordered must be positive"),
@@ -772,6 +1144,10 @@ impl From<PurchasingError> for AppError {
                 StatusCode::BAD_REQUEST,
                 ErrorResponse::validation_error("Unit cost must be non-negative"),
             ),
+            PurchasingError::InvalidPackPricing(msg) => (
+                StatusCode::BAD_REQUEST,
+                ErrorResponse::validation_error(format!("Invalid pack pricing: {}", msg)),
+            ),
             PurchasingError::ExceedsOrderedQuantity => (
                 StatusCode::BAD_REQUEST,
                 ErrorResponse::new(
@@ -779,6 +1155,20 @@ impl From<PurchasingError> for AppError {
                     "Cannot receive more than ordered quantity",
                 ),
             ),
+            PurchasingError::ReceiptMatchFailed(msg) => (
+                StatusCode::BAD_REQUEST,
+                ErrorResponse::new(
+                    "RECEIPT_MATCH_FAILED",
+                    format!("Goods receipt doesn't match the purchase order: {}", msg),
+                ),
+            ),
+            PurchasingError::PeriodClosed(start, end) => (
+                StatusCode::CONFLICT,
+                ErrorResponse::new(
+                    "PERIOD_CLOSED",
+                    format!("The period from {} to {} is closed", start, end),
+                ),
+            ),
             PurchasingError::ReceiptNotEditable => (
                 StatusCode::BAD_REQUEST,
                 ErrorResponse::new(
@@ -808,6 +1198,38 @@ impl From<PurchasingError> for AppError {
                 StatusCode::BAD_REQUEST,
                 ErrorResponse::validation_error("Quantity received must be positive"),
             ),
+            PurchasingError::SerialNumbersRequired(id) => (
+                StatusCode::BAD_REQUEST,
+                ErrorResponse::new(
+                    "SERIAL_NUMBERS_REQUIRED",
+                    format!("Serial numbers required for serial-tracked product: {}", id),
+                ),
+            ),
+            PurchasingError::InvalidSerialNumber => (
+                StatusCode::BAD_REQUEST,
+                ErrorResponse::validation_error("Invalid serial number"),
+            ),
+            PurchasingError::SerialCountMismatch => (
+                StatusCode::BAD_REQUEST,
+                ErrorResponse::new(
+                    "SERIAL_COUNT_MISMATCH",
+                    "Number of serial numbers must equal the quantity received",
+                ),
+            ),
+            PurchasingError::DuplicateSerialNumber(serial) => (
+                StatusCode::BAD_REQUEST,
+                ErrorResponse::new(
+                    "DUPLICATE_SERIAL_NUMBER",
+                    format!("Serial number '{}' is listed more than once", serial),
+                ),
+            ),
+            PurchasingError::ProductNotSerialized(id) => (
+                StatusCode::BAD_REQUEST,
+                ErrorResponse::new(
+                    "PRODUCT_NOT_SERIALIZED",
+                    format!("Product is not serial-tracked: {}", id),
+                ),
+            ),
             PurchasingError::InvalidStatusTransition => (
                 StatusCode::BAD_REQUEST,
                 ErrorResponse::new("INVALID_STATUS_TRANSITION", "Invalid status transition"),
@@ -818,10 +1240,25 @@ impl From<PurchasingError> for AppError {
                     "Invalid currency code: must be 3 uppercase letters (ISO 4217)",
                 ),
             ),
+            PurchasingError::InvalidTaxId(msg) => (
+                StatusCode::BAD_REQUEST,
+                ErrorResponse::new("INVALID_TAX_ID", msg),
+            ),
             PurchasingError::InvalidUnitOfMeasure => (
                 StatusCode::BAD_REQUEST,
                 ErrorResponse::validation_error("Invalid unit of measure"),
             ),
+            PurchasingError::InvalidPageCursor(_) => (
+                StatusCode::BAD_REQUEST,
+                ErrorResponse::new("INVALID_PAGE_CURSOR", err.to_string()),
+            ),
+            PurchasingError::IncompatibleUnits { from, to } => (
+                StatusCode::BAD_REQUEST,
+                ErrorResponse::new(
+                    "INCOMPATIBLE_UNITS",
+                    format!("Cannot convert {} to {}", from, to),
+                ),
+            ),
             PurchasingError::InvalidPurchaseOrderStatus => (
                 StatusCode::BAD_REQUEST,
                 ErrorResponse::validation_error("Invalid purchase order status"),
@@ -830,6 +1267,21 @@ impl From<PurchasingError> for AppError {
                 StatusCode::BAD_REQUEST,
                 ErrorResponse::validation_error("Invalid goods receipt status"),
             ),
+            PurchasingError::InvalidReceiptMatchStatus => (
+                StatusCode::BAD_REQUEST,
+                ErrorResponse::validation_error("Invalid receipt match status"),
+            ),
+            PurchasingError::ExpectedDeliveryDateInPast => (
+                StatusCode::BAD_REQUEST,
+                ErrorResponse::new(
+                    "EXPECTED_DELIVERY_DATE_IN_PAST",
+                    "Expected delivery date cannot be in the past",
+                ),
+            ),
+            PurchasingError::InvalidDateRange => (
+                StatusCode::BAD_REQUEST,
+                ErrorResponse::validation_error("from_date must not be after to_date"),
+            ),
 
             // -----------------------------------------------------------------
             // 500 Internal Server Error - Database and system errors
@@ -838,10 +1290,18 @@ impl From<PurchasingError> for AppError {
                 StatusCode::INTERNAL_SERVER_ERROR,
                 ErrorResponse::new("AUDIT_ERROR", "Failed to record audit entry"),
             ),
-            PurchasingError::Database(_) => (
+            PurchasingError::Inventory(_) | PurchasingError::Database(_) => (
                 StatusCode::INTERNAL_SERVER_ERROR,
                 ErrorResponse::internal_error(),
             ),
+            PurchasingError::Forbidden { .. } => (
+                StatusCode::FORBIDDEN,
+                ErrorResponse::new("FORBIDDEN", err.to_string()),
+            ),
+            PurchasingError::StoreAccessDenied(_) => (
+                StatusCode::FORBIDDEN,
+                ErrorResponse::new("STORE_ACCESS_DENIED", err.to_string()),
+            ),
             PurchasingError::NotImplemented => (
                 StatusCode::INTERNAL_SERVER_ERROR,
                 ErrorResponse::new("NOT_IMPLEMENTED", "Feature not yet implemented"),
@@ -866,6 +1326,13 @@ impl From<SalesError> for AppError {
                 StatusCode::NOT_FOUND,
                 ErrorResponse::new("CUSTOMER_NOT_FOUND", format!("Customer not found: {}", id)),
             ),
+            SalesError::CustomerCodeNotFound(code) => (
+                StatusCode::NOT_FOUND,
+                ErrorResponse::new(
+                    "CUSTOMER_NOT_FOUND",
+                    format!("Customer not found with code '{}'", code),
+                ),
+            ),
             SalesError::ShiftNotFound(id) => (
                 StatusCode::NOT_FOUND,
                 ErrorResponse::new("SHIFT_NOT_FOUND", format!("Shift not found: {}", id)),
@@ -907,6 +1374,27 @@ impl From<SalesError> for AppError {
                 StatusCode::NOT_FOUND,
                 ErrorResponse::new("PRODUCT_NOT_FOUND", format!("Product not found: {}", id)),
             ),
+            SalesError::BarcodeNotFound(barcode) => (
+                StatusCode::NOT_FOUND,
+                ErrorResponse::new(
+                    "BARCODE_NOT_FOUND",
+                    format!("No product found for barcode '{}'", barcode),
+                ),
+            ),
+            SalesError::AmbiguousBarcode {
+                barcode,
+                candidates,
+            } => (
+                StatusCode::CONFLICT,
+                ErrorResponse::new(
+                    "AMBIGUOUS_BARCODE",
+                    format!(
+                        "Barcode '{}' matches several items: {}",
+                        barcode,
+                        candidates.join(", ")
+                    ),
+                ),
+            ),
             SalesError::StoreNotFound(id) => (
                 StatusCode::NOT_FOUND,
                 ErrorResponse::new("STORE_NOT_FOUND", format!("Store not found: {}", id)),
@@ -968,6 +1456,10 @@ impl From<SalesError> for AppError {
                 StatusCode::CONFLICT,
                 ErrorResponse::new("SALE_ALREADY_VOIDED", "Sale has already been voided"),
             ),
+            SalesError::SaleNotParked => (
+                StatusCode::CONFLICT,
+                ErrorResponse::new("SALE_NOT_PARKED", "Sale is not parked"),
+            ),
             SalesError::PaymentAlreadyRefunded => (
                 StatusCode::CONFLICT,
                 ErrorResponse::new(
@@ -989,6 +1481,13 @@ impl From<SalesError> for AppError {
                     "Credit note has already been cancelled",
                 ),
             ),
+            SalesError::CreditNoteNotApproved => (
+                StatusCode::CONFLICT,
+                ErrorResponse::new(
+                    "CREDIT_NOTE_NOT_APPROVED",
+                    "Credit note has not been approved",
+                ),
+            ),
             SalesError::CreditNoteAlreadyApplied => (
                 StatusCode::CONFLICT,
                 ErrorResponse::new(
@@ -1000,6 +1499,27 @@ impl From<SalesError> for AppError {
                 StatusCode::CONFLICT,
                 ErrorResponse::new("SHIFT_ALREADY_CLOSED", "Shift is already closed"),
             ),
+            SalesError::ShiftNotClosed => (
+                StatusCode::CONFLICT,
+                ErrorResponse::new("SHIFT_NOT_CLOSED", "Shift is not closed"),
+            ),
+            SalesError::ShiftFinalized => (
+                StatusCode::CONFLICT,
+                ErrorResponse::new(
+                    "SHIFT_FINALIZED",
+                    "Shift has been finalized and cannot be reopened",
+                ),
+            ),
+            SalesError::ShiftReopenWindowExpired(minutes) => (
+                StatusCode::CONFLICT,
+                ErrorResponse::new(
+                    "SHIFT_REOPEN_WINDOW_EXPIRED",
+                    format!(
+                        "Shift was closed more than {} minutes ago and can no longer be reopened",
+                        minutes
+                    ),
+                ),
+            ),
             // -----------------------------------------------------------------
             // 400 Bad Request - Validation and business rule violations
             // -----------------------------------------------------------------
@@ -1010,6 +1530,41 @@ impl From<SalesError> for AppError {
                     format!("Customer is not active: {}", id),
                 ),
             ),
+            SalesError::CustomerCreditNotAllowed(id) => (
+                StatusCode::BAD_REQUEST,
+                ErrorResponse::new(
+                    "CUSTOMER_CREDIT_NOT_ALLOWED",
+                    format!("Customer is not allowed to buy on account: {}", id),
+                ),
+            ),
+            SalesError::CreditLimitExceeded(available) => (
+                StatusCode::BAD_REQUEST,
+                ErrorResponse::new(
+                    "CREDIT_LIMIT_EXCEEDED",
+                    format!("Credit limit exceeded: {} available", available),
+                ),
+            ),
+            SalesError::OnAccountRequiresCustomer => (
+                StatusCode::BAD_REQUEST,
+                ErrorResponse::new(
+                    "ON_ACCOUNT_REQUIRES_CUSTOMER",
+                    "On-account payments require a customer",
+                ),
+            ),
+            SalesError::StoreCreditRequiresCustomer => (
+                StatusCode::BAD_REQUEST,
+                ErrorResponse::new(
+                    "STORE_CREDIT_REQUIRES_CUSTOMER",
+                    "Store credit requires a customer",
+                ),
+            ),
+            SalesError::InsufficientStoreCredit(available) => (
+                StatusCode::BAD_REQUEST,
+                ErrorResponse::new(
+                    "INSUFFICIENT_STORE_CREDIT",
+                    format!("Insufficient store credit: {} available", available),
+                ),
+            ),
             SalesError::TerminalNotActive(id) => (
                 StatusCode::BAD_REQUEST,
                 ErrorResponse::new(
@@ -1028,6 +1583,23 @@ impl From<SalesError> for AppError {
                     "Opening balance must be non-negative",
                 ),
             ),
+            SalesError::ShiftReopenReasonRequired => (
+                StatusCode::BAD_REQUEST,
+                ErrorResponse::new(
+                    "SHIFT_REOPEN_REASON_REQUIRED",
+                    "A reason is required to reopen a shift",
+                ),
+            ),
+            SalesError::ShiftVarianceReasonRequired(variance) => (
+                StatusCode::UNPROCESSABLE_ENTITY,
+                ErrorResponse::new(
+                    "SHIFT_VARIANCE_REASON_REQUIRED",
+                    format!(
+                        "Closing count is off by {}; a variance reason is required",
+                        variance
+                    ),
+                ),
+            ),
             SalesError::SaleNotEditable => (
                 StatusCode::BAD_REQUEST,
                 ErrorResponse::new(
@@ -1043,6 +1615,13 @@ impl From<SalesError> for AppError {
                 StatusCode::BAD_REQUEST,
                 ErrorResponse::new("SALE_NOT_FULLY_PAID", "Sale is not fully paid"),
             ),
+            SalesError::PeriodClosed(start, end) => (
+                StatusCode::CONFLICT,
+                ErrorResponse::new(
+                    "PERIOD_CLOSED",
+                    format!("The period from {} to {} is closed", start, end),
+                ),
+            ),
             SalesError::PosRequiresOpenShift => (
                 StatusCode::BAD_REQUEST,
                 ErrorResponse::new("POS_REQUIRES_OPEN_SHIFT", "POS sale requires an open shift"),
@@ -1055,6 +1634,24 @@ impl From<SalesError> for AppError {
                 StatusCode::BAD_REQUEST,
                 ErrorResponse::new("POS_REQUIRES_CASHIER", "POS sale requires a cashier"),
             ),
+            SalesError::QuickSaleProductRequired => (
+                StatusCode::BAD_REQUEST,
+                ErrorResponse::validation_error("Quick sale requires a barcode or a product id"),
+            ),
+            SalesError::QuickSaleNotSupported(reason) => (
+                StatusCode::UNPROCESSABLE_ENTITY,
+                ErrorResponse::new(
+                    "QUICK_SALE_NOT_SUPPORTED",
+                    format!("Quick sale not available: {}", reason),
+                ),
+            ),
+            SalesError::InvoiceNumberNotAllowed => (
+                StatusCode::BAD_REQUEST,
+                ErrorResponse::new(
+                    "INVOICE_NUMBER_NOT_ALLOWED",
+                    "Quick sale invoice numbers are drawn from the terminal's CAI range",
+                ),
+            ),
             SalesError::InvalidQuantity => (
                 StatusCode::BAD_REQUEST,
                 ErrorResponse::new("INVALID_QUANTITY", "Quantity must be positive"),
@@ -1070,6 +1667,73 @@ impl From<SalesError> for AppError {
                     format!("Insufficient stock for product: {}", id),
                 ),
             ),
+            SalesError::StockConflict => (
+                StatusCode::CONFLICT,
+                ErrorResponse::new(
+                    "VERSION_CONFLICT",
+                    "Stock was modified by another process, retry the sale",
+                ),
+            ),
+            SalesError::IngredientUnavailable(name) => (
+                StatusCode::BAD_REQUEST,
+                ErrorResponse::new(
+                    "INGREDIENT_UNAVAILABLE",
+                    format!("Ingredient unavailable: {}", name),
+                ),
+            ),
+            SalesError::SerialNumbersRequired(id) => (
+                StatusCode::BAD_REQUEST,
+                ErrorResponse::new(
+                    "SERIAL_NUMBERS_REQUIRED",
+                    format!("Serial numbers required for serial-tracked product: {}", id),
+                ),
+            ),
+            SalesError::InvalidSerialNumber => (
+                StatusCode::BAD_REQUEST,
+                ErrorResponse::validation_error("Invalid serial number"),
+            ),
+            SalesError::SerialCountMismatch => (
+                StatusCode::BAD_REQUEST,
+                ErrorResponse::new(
+                    "SERIAL_COUNT_MISMATCH",
+                    "Number of serial numbers must equal the quantity",
+                ),
+            ),
+            SalesError::DuplicateSerialNumber(serial) => (
+                StatusCode::BAD_REQUEST,
+                ErrorResponse::new(
+                    "DUPLICATE_SERIAL_NUMBER",
+                    format!("Serial number '{}' is listed more than once", serial),
+                ),
+            ),
+            SalesError::SerialNotAvailable(serial) => (
+                StatusCode::CONFLICT,
+                ErrorResponse::new(
+                    "SERIAL_NOT_AVAILABLE",
+                    format!("Serial number '{}' is not available for sale", serial),
+                ),
+            ),
+            SalesError::ProductNotSerialized(id) => (
+                StatusCode::BAD_REQUEST,
+                ErrorResponse::new(
+                    "PRODUCT_NOT_SERIALIZED",
+                    format!("Product is not serial-tracked: {}", id),
+                ),
+            ),
+            SalesError::SerialNotOnSaleLine(serial) => (
+                StatusCode::BAD_REQUEST,
+                ErrorResponse::new(
+                    "SERIAL_NOT_ON_SALE_LINE",
+                    format!("Serial number '{}' was not sold on this sale line", serial),
+                ),
+            ),
+            SalesError::SerialSaleNotFound(serial) => (
+                StatusCode::NOT_FOUND,
+                ErrorResponse::new(
+                    "SERIAL_SALE_NOT_FOUND",
+                    format!("No sale found for serial number '{}'", serial),
+                ),
+            ),
             SalesError::InvalidPaymentAmount => (
                 StatusCode::BAD_REQUEST,
                 ErrorResponse::new("INVALID_PAYMENT_AMOUNT", "Payment amount must be positive"),
@@ -1081,6 +1745,13 @@ impl From<SalesError> for AppError {
                     "Payment exceeds remaining balance",
                 ),
             ),
+            SalesError::NoPaymentAllocations => (
+                StatusCode::BAD_REQUEST,
+                ErrorResponse::new(
+                    "NO_PAYMENT_ALLOCATIONS",
+                    "Payment requires at least one allocation",
+                ),
+            ),
             SalesError::CashRequiresAmountTendered => (
                 StatusCode::BAD_REQUEST,
                 ErrorResponse::new(
@@ -1135,6 +1806,20 @@ impl From<SalesError> for AppError {
                     "Cannot create return for incomplete sale",
                 ),
             ),
+            SalesError::ReceiptlessReturnNotAuthorized => (
+                StatusCode::FORBIDDEN,
+                ErrorResponse::new(
+                    "RECEIPTLESS_RETURN_NOT_AUTHORIZED",
+                    "Returns without a receipt require a manager override",
+                ),
+            ),
+            SalesError::InvalidReturnLine => (
+                StatusCode::BAD_REQUEST,
+                ErrorResponse::new(
+                    "INVALID_RETURN_LINE",
+                    "Return lines must name an original sale line, or a product on returns without a receipt",
+                ),
+            ),
             SalesError::CannotCancelShippedOrder => (
                 StatusCode::BAD_REQUEST,
                 ErrorResponse::new(
@@ -1154,11 +1839,25 @@ impl From<SalesError> for AppError {
                 StatusCode::BAD_REQUEST,
                 ErrorResponse::new("ORDER_NOT_SHIPPED", "Order has not been shipped"),
             ),
-            SalesError::NoValidCai(id) => (
+            SalesError::NoValidCai(id) => (
+                StatusCode::BAD_REQUEST,
+                ErrorResponse::new(
+                    "NO_VALID_CAI",
+                    format!("No valid CAI available for terminal: {}", id),
+                ),
+            ),
+            SalesError::CaiExpired(id) => (
+                StatusCode::BAD_REQUEST,
+                ErrorResponse::new(
+                    "CAI_EXPIRED",
+                    format!("CAI has expired for terminal: {}", id),
+                ),
+            ),
+            SalesError::CaiRangeExhausted(id) => (
                 StatusCode::BAD_REQUEST,
                 ErrorResponse::new(
-                    "NO_VALID_CAI",
-                    format!("No valid CAI available for terminal: {}", id),
+                    "CAI_RANGE_EXHAUSTED",
+                    format!("CAI range exhausted for terminal: {}", id),
                 ),
             ),
             SalesError::ReservationFailed => (
@@ -1197,6 +1896,10 @@ impl From<SalesError> for AppError {
                 StatusCode::BAD_REQUEST,
                 ErrorResponse::new("INVALID_UNIT_OF_MEASURE", "Invalid unit of measure"),
             ),
+            SalesError::InvalidPageCursor(_) => (
+                StatusCode::BAD_REQUEST,
+                ErrorResponse::new("INVALID_PAGE_CURSOR", err.to_string()),
+            ),
             SalesError::InvalidSaleStatus => (
                 StatusCode::BAD_REQUEST,
                 ErrorResponse::new("INVALID_SALE_STATUS", "Invalid sale status"),
@@ -1221,10 +1924,40 @@ impl From<SalesError> for AppError {
                 StatusCode::BAD_REQUEST,
                 ErrorResponse::new("INVALID_CUSTOMER_TYPE", "Invalid customer type"),
             ),
+            SalesError::InvalidTaxId(msg) => (
+                StatusCode::BAD_REQUEST,
+                ErrorResponse::new("INVALID_TAX_ID", msg),
+            ),
+            SalesError::InvalidCustomerCodeFormat(msg) => (
+                StatusCode::BAD_REQUEST,
+                ErrorResponse::new(
+                    "INVALID_CUSTOMER_CODE_FORMAT",
+                    format!("Invalid customer code format: {}", msg),
+                ),
+            ),
+            SalesError::InvalidStatementPeriod => (
+                StatusCode::BAD_REQUEST,
+                ErrorResponse::new(
+                    "INVALID_STATEMENT_PERIOD",
+                    "Statement period start must not be after its end",
+                ),
+            ),
+            SalesError::InvalidCreditLimit => (
+                StatusCode::BAD_REQUEST,
+                ErrorResponse::new("INVALID_CREDIT_LIMIT", "Credit limit must be non-negative"),
+            ),
+            SalesError::InvalidAccountEntryType => (
+                StatusCode::BAD_REQUEST,
+                ErrorResponse::new("INVALID_ACCOUNT_ENTRY_TYPE", "Invalid account entry type"),
+            ),
             SalesError::InvalidReturnReason => (
                 StatusCode::BAD_REQUEST,
                 ErrorResponse::new("INVALID_RETURN_REASON", "Invalid return reason"),
             ),
+            SalesError::InvalidRestockCostBasis => (
+                StatusCode::BAD_REQUEST,
+                ErrorResponse::new("INVALID_RESTOCK_COST_BASIS", "Invalid restock cost basis"),
+            ),
             SalesError::InvalidCreditNoteStatus => (
                 StatusCode::BAD_REQUEST,
                 ErrorResponse::new("INVALID_CREDIT_NOTE_STATUS", "Invalid credit note status"),
@@ -1236,6 +1969,13 @@ impl From<SalesError> for AppError {
                     "Discount percentage must be between 0 and 100",
                 ),
             ),
+            SalesError::InvalidDiscountAmount => (
+                StatusCode::BAD_REQUEST,
+                ErrorResponse::new(
+                    "INVALID_DISCOUNT_AMOUNT",
+                    "Allocated discount must be between zero and the line subtotal",
+                ),
+            ),
             SalesError::InvalidTaxRate => (
                 StatusCode::BAD_REQUEST,
                 ErrorResponse::new("INVALID_TAX_RATE", "Tax rate must be non-negative"),
@@ -1251,6 +1991,14 @@ impl From<SalesError> for AppError {
                 StatusCode::INTERNAL_SERVER_ERROR,
                 ErrorResponse::internal_error(),
             ),
+            SalesError::Forbidden { .. } => (
+                StatusCode::FORBIDDEN,
+                ErrorResponse::new("FORBIDDEN", err.to_string()),
+            ),
+            SalesError::StoreAccessDenied(_) => (
+                StatusCode::FORBIDDEN,
+                ErrorResponse::new("STORE_ACCESS_DENIED", err.to_string()),
+            ),
             SalesError::NotImplemented => (
                 StatusCode::INTERNAL_SERVER_ERROR,
                 ErrorResponse::new("NOT_IMPLEMENTED", "Feature not yet implemented"),
@@ -1294,6 +2042,290 @@ impl From<SalesError> for AppError {
                 StatusCode::BAD_REQUEST,
                 ErrorResponse::new("INVALID_PROMOTION_STATUS", "Invalid promotion status"),
             ),
+
+            // Markdown errors
+            SalesError::MarkdownNotFound(id) => (
+                StatusCode::NOT_FOUND,
+                ErrorResponse::new("MARKDOWN_NOT_FOUND", format!("Markdown not found: {}", id)),
+            ),
+            SalesError::InvalidMarkdownRule(msg) => (
+                StatusCode::BAD_REQUEST,
+                ErrorResponse::new("INVALID_MARKDOWN_RULE", msg.clone()),
+            ),
+            SalesError::MarkdownNotReversible => (
+                StatusCode::CONFLICT,
+                ErrorResponse::new(
+                    "MARKDOWN_NOT_REVERSIBLE",
+                    "Markdown is not active and cannot be reversed",
+                ),
+            ),
+            SalesError::InvalidMarkdownReason => (
+                StatusCode::BAD_REQUEST,
+                ErrorResponse::new("INVALID_MARKDOWN_REASON", "Invalid markdown reason"),
+            ),
+            SalesError::InvalidMarkdownStatus => (
+                StatusCode::BAD_REQUEST,
+                ErrorResponse::new("INVALID_MARKDOWN_STATUS", "Invalid markdown status"),
+            ),
+            SalesError::InvalidGiftCardStatus => (
+                StatusCode::BAD_REQUEST,
+                ErrorResponse::new("INVALID_GIFT_CARD_STATUS", "Invalid gift card status"),
+            ),
+            SalesError::InvalidLayawayStatus => (
+                StatusCode::BAD_REQUEST,
+                ErrorResponse::new("INVALID_LAYAWAY_STATUS", "Invalid layaway status"),
+            ),
+
+            // Price tier errors
+            SalesError::InvalidPriceTier(msg) => (
+                StatusCode::BAD_REQUEST,
+                ErrorResponse::new("INVALID_PRICE_TIER", msg.clone()),
+            ),
+
+            // Tax rule errors
+            SalesError::InvalidTaxRule(msg) => (
+                StatusCode::BAD_REQUEST,
+                ErrorResponse::new("INVALID_TAX_RULE", msg.clone()),
+            ),
+
+            // Price list errors
+            SalesError::PriceListNotFound(_) => (
+                StatusCode::NOT_FOUND,
+                ErrorResponse::new("PRICE_LIST_NOT_FOUND", "Price list not found"),
+            ),
+            SalesError::InvalidPriceList(msg) => (
+                StatusCode::BAD_REQUEST,
+                ErrorResponse::new("INVALID_PRICE_LIST", msg.clone()),
+            ),
+            SalesError::PriceListAlreadyAssigned(target) => (
+                StatusCode::CONFLICT,
+                ErrorResponse::new(
+                    "PRICE_LIST_ALREADY_ASSIGNED",
+                    format!(
+                        "A price list is already assigned to '{}' at this store",
+                        target
+                    ),
+                ),
+            ),
+
+            // Gift card errors
+            SalesError::GiftCardNotFound(code) => (
+                StatusCode::NOT_FOUND,
+                ErrorResponse::new(
+                    "GIFT_CARD_NOT_FOUND",
+                    format!("Gift card {} not found", code),
+                ),
+            ),
+            SalesError::DuplicateGiftCardCode(code) => (
+                StatusCode::CONFLICT,
+                ErrorResponse::new(
+                    "DUPLICATE_GIFT_CARD_CODE",
+                    format!("Gift card code '{}' already exists", code),
+                ),
+            ),
+            SalesError::InvalidGiftCardCode => (
+                StatusCode::BAD_REQUEST,
+                ErrorResponse::new(
+                    "INVALID_GIFT_CARD_CODE",
+                    "Gift card code must be 6-32 letters, digits or dashes",
+                ),
+            ),
+            SalesError::InvalidGiftCardAmount => (
+                StatusCode::BAD_REQUEST,
+                ErrorResponse::new(
+                    "INVALID_GIFT_CARD_AMOUNT",
+                    "Gift card amount must be positive",
+                ),
+            ),
+            SalesError::GiftCardNotActive => (
+                StatusCode::CONFLICT,
+                ErrorResponse::new("GIFT_CARD_NOT_ACTIVE", "Gift card is not active"),
+            ),
+            SalesError::GiftCardDepleted => (
+                StatusCode::CONFLICT,
+                ErrorResponse::new("GIFT_CARD_DEPLETED", "Gift card has no balance left"),
+            ),
+            SalesError::InsufficientGiftCardBalance => (
+                StatusCode::CONFLICT,
+                ErrorResponse::new(
+                    "INSUFFICIENT_GIFT_CARD_BALANCE",
+                    "Insufficient gift card balance",
+                ),
+            ),
+            SalesError::GiftCardCodeRequired => (
+                StatusCode::BAD_REQUEST,
+                ErrorResponse::new(
+                    "GIFT_CARD_CODE_REQUIRED",
+                    "Gift card payment requires a gift card code",
+                ),
+            ),
+            SalesError::GiftCardCurrencyMismatch => (
+                StatusCode::BAD_REQUEST,
+                ErrorResponse::new(
+                    "GIFT_CARD_CURRENCY_MISMATCH",
+                    "Gift card currency does not match the sale currency",
+                ),
+            ),
+            SalesError::GiftCardProductRequired => (
+                StatusCode::BAD_REQUEST,
+                ErrorResponse::new(
+                    "GIFT_CARD_PRODUCT_REQUIRED",
+                    "Selling a gift card on a sale requires a gift card product",
+                ),
+            ),
+
+            // Layaway errors
+            SalesError::LayawayNotFound(sale_id) => (
+                StatusCode::NOT_FOUND,
+                ErrorResponse::new(
+                    "LAYAWAY_NOT_FOUND",
+                    format!("Layaway not found for sale: {}", sale_id),
+                ),
+            ),
+            SalesError::InvalidLayawayTerms(msg) => (
+                StatusCode::BAD_REQUEST,
+                ErrorResponse::new("INVALID_LAYAWAY_TERMS", msg.clone()),
+            ),
+            SalesError::LayawayRequiresCustomer => (
+                StatusCode::BAD_REQUEST,
+                ErrorResponse::new(
+                    "LAYAWAY_REQUIRES_CUSTOMER",
+                    "A layaway sale requires a customer",
+                ),
+            ),
+            SalesError::InvalidRestockingFee => (
+                StatusCode::BAD_REQUEST,
+                ErrorResponse::new(
+                    "INVALID_RESTOCKING_FEE",
+                    "Restocking fee must be between zero and the amount paid",
+                ),
+            ),
+            SalesError::SaleOnLayaway => (
+                StatusCode::CONFLICT,
+                ErrorResponse::new(
+                    "SALE_ON_LAYAWAY",
+                    "Sale is on layaway; cancel the layaway instead",
+                ),
+            ),
+
+            // Exchange rate errors
+            SalesError::InvalidExchangeRate => (
+                StatusCode::BAD_REQUEST,
+                ErrorResponse::new(
+                    "INVALID_EXCHANGE_RATE",
+                    "Exchange rate must be greater than zero",
+                ),
+            ),
+            SalesError::ExchangeRateNotFound(currency) => (
+                StatusCode::UNPROCESSABLE_ENTITY,
+                ErrorResponse::new(
+                    "EXCHANGE_RATE_NOT_FOUND",
+                    format!("No exchange rate recorded for {}", currency),
+                ),
+            ),
+            SalesError::InvalidWeightBarcodeFormat(msg) => (
+                StatusCode::BAD_REQUEST,
+                ErrorResponse::new(
+                    "INVALID_WEIGHT_BARCODE_FORMAT",
+                    format!("Invalid weight barcode format: {}", msg),
+                ),
+            ),
+            SalesError::WeightBarcodePrefixConflict(prefix) => (
+                StatusCode::CONFLICT,
+                ErrorResponse::new(
+                    "WEIGHT_BARCODE_PREFIX_CONFLICT",
+                    format!(
+                        "A weight barcode format already uses a prefix overlapping '{}'",
+                        prefix
+                    ),
+                ),
+            ),
+            SalesError::WeightBarcodeFormatNotFound(id) => (
+                StatusCode::NOT_FOUND,
+                ErrorResponse::new(
+                    "WEIGHT_BARCODE_FORMAT_NOT_FOUND",
+                    format!("Weight barcode format not found: {}", id),
+                ),
+            ),
+            SalesError::ScaleLabelUnpriced(id) => (
+                StatusCode::UNPROCESSABLE_ENTITY,
+                ErrorResponse::new(
+                    "SCALE_LABEL_UNPRICED",
+                    format!(
+                        "Product {} has no unit price to derive the weighed quantity from",
+                        id
+                    ),
+                ),
+            ),
+
+            // Pickup errors
+            SalesError::OrderNotReadyForPickup => (
+                StatusCode::BAD_REQUEST,
+                ErrorResponse::new(
+                    "ORDER_NOT_READY_FOR_PICKUP",
+                    "Order is not ready for pickup",
+                ),
+            ),
+            SalesError::PickupNotFound(id) => (
+                StatusCode::NOT_FOUND,
+                ErrorResponse::new(
+                    "PICKUP_NOT_FOUND",
+                    format!("Pickup not found for order: {}", id),
+                ),
+            ),
+            SalesError::InvalidPickupCode => (
+                StatusCode::BAD_REQUEST,
+                ErrorResponse::new("INVALID_PICKUP_CODE", "Invalid pickup code"),
+            ),
+            SalesError::PickupNotOpen => (
+                StatusCode::CONFLICT,
+                ErrorResponse::new("PICKUP_NOT_OPEN", "Pickup is no longer open"),
+            ),
+            SalesError::InvalidIdempotencyKey => (
+                StatusCode::BAD_REQUEST,
+                ErrorResponse::validation_error(
+                    "idempotency_key must be between 1 and 255 characters",
+                ),
+            ),
+            SalesError::IdempotencyKeyReused(key) => (
+                StatusCode::CONFLICT,
+                ErrorResponse::new(
+                    "IDEMPOTENCY_KEY_REUSED",
+                    format!(
+                        "Idempotency key '{}' was already used for a different request",
+                        key
+                    ),
+                ),
+            ),
+            SalesError::IdempotencyKeyRequiresTerminal => (
+                StatusCode::BAD_REQUEST,
+                ErrorResponse::new(
+                    "IDEMPOTENCY_KEY_REQUIRES_TERMINAL",
+                    "Idempotency keys require a sale rung up at a terminal",
+                ),
+            ),
+            SalesError::InvalidIdempotentOperation => (
+                StatusCode::BAD_REQUEST,
+                ErrorResponse::validation_error("Invalid idempotent operation"),
+            ),
+            SalesError::InvalidPickupStatus => (
+                StatusCode::BAD_REQUEST,
+                ErrorResponse::new("INVALID_PICKUP_STATUS", "Invalid pickup status"),
+            ),
+            SalesError::InvoiceIssueFailed(msg) => (
+                StatusCode::UNPROCESSABLE_ENTITY,
+                ErrorResponse::new(
+                    "INVOICE_ISSUE_FAILED",
+                    format!("Failed to issue invoice: {}", msg),
+                ),
+            ),
+            SalesError::NotificationFailed(msg) => (
+                StatusCode::BAD_GATEWAY,
+                ErrorResponse::new(
+                    "NOTIFICATION_FAILED",
+                    format!("Failed to notify customer: {}", msg),
+                ),
+            ),
         };
 
         AppError::new(status, response)
@@ -1432,6 +2464,10 @@ impl From<FiscalError> for AppError {
                 StatusCode::BAD_REQUEST,
                 ErrorResponse::validation_error("Invalid tax applies to value"),
             ),
+            FiscalError::InvalidPageCursor(_) => (
+                StatusCode::BAD_REQUEST,
+                ErrorResponse::new("INVALID_PAGE_CURSOR", err.to_string()),
+            ),
             FiscalError::OriginalInvoiceRequired => (
                 StatusCode::BAD_REQUEST,
                 ErrorResponse::new(
@@ -1603,6 +2639,10 @@ impl From<PaymentsError> for AppError {
                 StatusCode::BAD_REQUEST,
                 ErrorResponse::validation_error("Invalid payout status"),
             ),
+            PaymentsError::InvalidPageCursor(_) => (
+                StatusCode::BAD_REQUEST,
+                ErrorResponse::new("INVALID_PAGE_CURSOR", err.to_string()),
+            ),
             PaymentsError::UnsupportedPaymentMethod => (
                 StatusCode::BAD_REQUEST,
                 ErrorResponse::new(
@@ -1737,6 +2777,34 @@ impl From<ShippingError> for AppError {
                 StatusCode::CONFLICT,
                 ErrorResponse::new("DRIVER_BUSY", "Driver is busy with another shipment"),
             ),
+            ShippingError::OrderNotAwaitingFulfillment(id) => (
+                StatusCode::CONFLICT,
+                ErrorResponse::new(
+                    "ORDER_NOT_AWAITING_FULFILLMENT",
+                    format!("Order is not awaiting fulfillment: {}", id),
+                ),
+            ),
+            ShippingError::OrderAlreadyClaimed(id) => (
+                StatusCode::CONFLICT,
+                ErrorResponse::new(
+                    "ORDER_ALREADY_CLAIMED",
+                    format!("Order already claimed by another picker: {}", id),
+                ),
+            ),
+            ShippingError::OrderOnHold(id) => (
+                StatusCode::CONFLICT,
+                ErrorResponse::new("ORDER_ON_HOLD", format!("Order is on hold: {}", id)),
+            ),
+            ShippingError::PickVersionConflict(id) => (
+                StatusCode::CONFLICT,
+                ErrorResponse::new(
+                    "PICK_VERSION_CONFLICT",
+                    format!(
+                        "Order {} was changed by someone else; reload the queue and retry",
+                        id
+                    ),
+                ),
+            ),
             ShippingError::NoMatchingZone => (
                 StatusCode::BAD_REQUEST,
                 ErrorResponse::new(
@@ -2063,6 +3131,7 @@ impl From<AnalyticsError> for AppError {
             AnalyticsError::UnknownKpiKey(_)
             | AnalyticsError::UnknownReportKind(_)
             | AnalyticsError::InvalidTimeWindow(_)
+            | AnalyticsError::InvalidStoreSelection(_)
             | AnalyticsError::InvalidWidgetKind(_)
             | AnalyticsError::InvalidWidgetConfig(_) => (
                 StatusCode::BAD_REQUEST,
@@ -2117,36 +3186,82 @@ impl From<AccountingError> for AppError {
             AccountingError::DuplicateAccountCode(code) => (
                 StatusCode::CONFLICT,
                 ErrorResponse::new(
-                    "DUPLICATE_ACCOUNT_CODE",
-                    format!("Account code already in use: {}", code),
+                    "DUPLICATE_ACCOUNT_CODE",
+                    format!("Account code already in use: {}", code),
+                ),
+            ),
+            AccountingError::PeriodClosed(_) | AccountingError::PeriodNotOpen => (
+                StatusCode::CONFLICT,
+                ErrorResponse::new("PERIOD_NOT_OPEN", err.to_string()),
+            ),
+            AccountingError::InvalidStatusTransition { .. } => (
+                StatusCode::CONFLICT,
+                ErrorResponse::new("INVALID_STATUS_TRANSITION", err.to_string()),
+            ),
+
+            // 400 — validation
+            AccountingError::InvalidPeriodRange
+            | AccountingError::NotEnoughLines
+            | AccountingError::Unbalanced { .. }
+            | AccountingError::InvalidLineAmounts
+            | AccountingError::NegativeAmount
+            | AccountingError::InvalidAccountType(_)
+            | AccountingError::InvalidPeriodStatus(_)
+            | AccountingError::InvalidJournalEntryStatus(_) => (
+                StatusCode::BAD_REQUEST,
+                ErrorResponse::validation_error(err.to_string()),
+            ),
+
+            // 500
+            AccountingError::Database(_)
+            | AccountingError::Serialization(_)
+            | AccountingError::Subscriber(_) => (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                ErrorResponse::internal_error(),
+            ),
+        };
+
+        AppError::new(status, response)
+    }
+}
+
+// =============================================================================
+// From<NotificationsError> Implementation
+// =============================================================================
+
+impl From<NotificationsError> for AppError {
+    fn from(err: NotificationsError) -> Self {
+        let (status, response) = match &err {
+            // 404
+            NotificationsError::NotFound(id) => (
+                StatusCode::NOT_FOUND,
+                ErrorResponse::new(
+                    "NOTIFICATION_NOT_FOUND",
+                    format!("Notification not found: {}", id),
                 ),
             ),
-            AccountingError::PeriodClosed(_) | AccountingError::PeriodNotOpen => (
-                StatusCode::CONFLICT,
-                ErrorResponse::new("PERIOD_NOT_OPEN", err.to_string()),
-            ),
-            AccountingError::InvalidStatusTransition { .. } => (
-                StatusCode::CONFLICT,
-                ErrorResponse::new("INVALID_STATUS_TRANSITION", err.to_string()),
+            NotificationsError::WebhookNotFound(id) => (
+                StatusCode::NOT_FOUND,
+                ErrorResponse::new("WEBHOOK_NOT_FOUND", format!("Webhook not found: {}", id)),
             ),
 
             // 400 — validation
-            AccountingError::InvalidPeriodRange
-            | AccountingError::NotEnoughLines
-            | AccountingError::Unbalanced { .. }
-            | AccountingError::InvalidLineAmounts
-            | AccountingError::NegativeAmount
-            | AccountingError::InvalidAccountType(_)
-            | AccountingError::InvalidPeriodStatus(_)
-            | AccountingError::InvalidJournalEntryStatus(_) => (
+            NotificationsError::InvalidWebhookUrl(_)
+            | NotificationsError::WebhookSecretTooShort(_)
+            | NotificationsError::NoWebhookEventTypes
+            | NotificationsError::UnsupportedChannel(_)
+            | NotificationsError::InvalidChannel(_)
+            | NotificationsError::InvalidStatus(_)
+            | NotificationsError::InvalidSignatureAlgorithm(_) => (
                 StatusCode::BAD_REQUEST,
                 ErrorResponse::validation_error(err.to_string()),
             ),
 
             // 500
-            AccountingError::Database(_)
-            | AccountingError::Serialization(_)
-            | AccountingError::Subscriber(_) => (
+            NotificationsError::AdapterNotConfigured(_)
+            | NotificationsError::InvalidWebhookSignature(_)
+            | NotificationsError::Adapter(_)
+            | NotificationsError::Database(_) => (
                 StatusCode::INTERNAL_SERVER_ERROR,
                 ErrorResponse::internal_error(),
             ),
@@ -2207,6 +3322,8 @@ impl From<DemandPlanningError> for AppError {
             | DemandPlanningError::InvalidSuggestionStatus(_)
             | DemandPlanningError::InvalidAbcClass(_)
             | DemandPlanningError::DismissReasonRequired
+            | DemandPlanningError::InvalidVelocityWindows
+            | DemandPlanningError::InvalidForecastHorizon { .. }
             | DemandPlanningError::ForecastingFailed(_) => (
                 StatusCode::BAD_REQUEST,
                 ErrorResponse::validation_error(err.to_string()),
@@ -2367,6 +3484,10 @@ impl From<LoyaltyError> for AppError {
                 StatusCode::CONFLICT,
                 ErrorResponse::new("ALREADY_ENROLLED", err.to_string()),
             ),
+            LoyaltyError::LoyaltyDisabled(_) => (
+                StatusCode::CONFLICT,
+                ErrorResponse::new("LOYALTY_DISABLED", err.to_string()),
+            ),
             LoyaltyError::InsufficientPoints { .. } => (
                 StatusCode::CONFLICT,
                 ErrorResponse::new("INSUFFICIENT_POINTS", err.to_string()),
@@ -2888,6 +4009,21 @@ mod tests {
         assert_eq!(app_error.response().error_code, "INVALID_CREDENTIALS");
     }
 
+    #[test]
+    fn test_auth_error_mfa_errors_map() {
+        let app_error: AppError = AuthError::InvalidMfaCode.into();
+        assert_eq!(app_error.status(), StatusCode::UNAUTHORIZED);
+        assert_eq!(app_error.response().error_code, "INVALID_MFA_CODE");
+
+        let app_error: AppError = AuthError::MfaLocked.into();
+        assert_eq!(app_error.status(), StatusCode::TOO_MANY_REQUESTS);
+        assert_eq!(app_error.response().error_code, "MFA_LOCKED");
+
+        let app_error: AppError = AuthError::MfaAlreadyEnabled.into();
+        assert_eq!(app_error.status(), StatusCode::CONFLICT);
+        assert_eq!(app_error.response().error_code, "MFA_ALREADY_ENABLED");
+    }
+
     #[test]
     fn test_auth_error_account_disabled_maps_to_401() {
         let app_error: AppError = AuthError::AccountDisabled.into();
@@ -2909,6 +4045,13 @@ mod tests {
         assert_eq!(app_error.response().error_code, "INVALID_TOKEN");
     }
 
+    #[test]
+    fn test_auth_error_refresh_token_reused_maps_to_401() {
+        let app_error: AppError = AuthError::RefreshTokenReused.into();
+        assert_eq!(app_error.status(), StatusCode::UNAUTHORIZED);
+        assert_eq!(app_error.response().error_code, "TOKEN_REUSED");
+    }
+
     #[test]
     fn test_auth_error_password_too_short_maps_to_400() {
         let app_error: AppError = AuthError::PasswordTooShort.into();
@@ -3046,6 +4189,154 @@ mod tests {
         assert_eq!(app_error.response().error_code, "TRANSFER_NOT_FOUND");
     }
 
+    #[test]
+    fn test_inventory_error_store_access_denied_maps_to_403() {
+        let app_error: AppError = InventoryError::StoreAccessDenied(Uuid::nil()).into();
+        assert_eq!(app_error.status(), StatusCode::FORBIDDEN);
+        assert_eq!(app_error.response().error_code, "STORE_ACCESS_DENIED");
+    }
+
+    #[test]
+    fn test_inventory_error_invalid_markup_rule_maps_to_400() {
+        let app_error: AppError =
+            InventoryError::InvalidMarkupRule("unknown markup type".to_string()).into();
+        assert_eq!(app_error.status(), StatusCode::BAD_REQUEST);
+        assert_eq!(app_error.response().error_code, "INVALID_MARKUP_RULE");
+    }
+
+    #[test]
+    fn test_inventory_error_invalid_price_adjustment_maps_to_400() {
+        let app_error: AppError =
+            InventoryError::InvalidPriceAdjustment("unknown adjustment type".to_string()).into();
+        assert_eq!(app_error.status(), StatusCode::BAD_REQUEST);
+        assert_eq!(app_error.response().error_code, "INVALID_PRICE_ADJUSTMENT");
+    }
+
+    #[test]
+    fn test_inventory_error_invalid_reorder_level_maps_to_400() {
+        let app_error: AppError =
+            InventoryError::InvalidReorderLevel("reorder_quantity must be positive".to_string())
+                .into();
+        assert_eq!(app_error.status(), StatusCode::BAD_REQUEST);
+        assert_eq!(app_error.response().error_code, "INVALID_REORDER_LEVEL");
+    }
+
+    #[test]
+    fn test_inventory_error_invalid_product_vendor_maps_to_400() {
+        let app_error: AppError =
+            InventoryError::InvalidProductVendor("vendor not found".to_string()).into();
+        assert_eq!(app_error.status(), StatusCode::BAD_REQUEST);
+        assert_eq!(app_error.response().error_code, "INVALID_PRODUCT_VENDOR");
+    }
+
+    #[test]
+    fn test_inventory_error_valuation_snapshot_not_found_maps_to_404() {
+        let app_error: AppError = InventoryError::ValuationSnapshotNotFound(
+            Uuid::nil(),
+            chrono::NaiveDate::from_ymd_opt(2026, 9, 30).unwrap(),
+        )
+        .into();
+        assert_eq!(app_error.status(), StatusCode::NOT_FOUND);
+        assert_eq!(
+            app_error.response().error_code,
+            "VALUATION_SNAPSHOT_NOT_FOUND"
+        );
+    }
+
+    #[test]
+    fn test_inventory_error_valuation_period_not_ended_maps_to_400() {
+        let app_error: AppError = InventoryError::ValuationPeriodNotEnded(
+            chrono::NaiveDate::from_ymd_opt(2026, 9, 30).unwrap(),
+        )
+        .into();
+        assert_eq!(app_error.status(), StatusCode::BAD_REQUEST);
+        assert_eq!(app_error.response().error_code, "VALIDATION_ERROR");
+    }
+
+    #[test]
+    fn test_inventory_error_period_closed_maps_to_409() {
+        let app_error: AppError = InventoryError::PeriodClosed(
+            chrono::NaiveDate::from_ymd_opt(2026, 9, 1).unwrap(),
+            chrono::NaiveDate::from_ymd_opt(2026, 9, 30).unwrap(),
+        )
+        .into();
+        assert_eq!(app_error.status(), StatusCode::CONFLICT);
+        assert_eq!(app_error.response().error_code, "PERIOD_CLOSED");
+        assert_eq!(
+            app_error.response().message,
+            "The period from 2026-09-01 to 2026-09-30 is closed"
+        );
+    }
+
+    #[test]
+    fn test_inventory_error_period_not_found_maps_to_404() {
+        let app_error: AppError = InventoryError::PeriodNotFound(Uuid::nil()).into();
+        assert_eq!(app_error.status(), StatusCode::NOT_FOUND);
+        assert_eq!(app_error.response().error_code, "PERIOD_NOT_FOUND");
+    }
+
+    #[test]
+    fn test_inventory_error_invalid_period_range_maps_to_400() {
+        let app_error: AppError = InventoryError::InvalidPeriodRange.into();
+        assert_eq!(app_error.status(), StatusCode::BAD_REQUEST);
+        assert_eq!(app_error.response().error_code, "VALIDATION_ERROR");
+    }
+
+    #[test]
+    fn test_inventory_error_period_not_ended_maps_to_400() {
+        let app_error: AppError =
+            InventoryError::PeriodNotEnded(chrono::NaiveDate::from_ymd_opt(2026, 9, 30).unwrap())
+                .into();
+        assert_eq!(app_error.status(), StatusCode::BAD_REQUEST);
+        assert_eq!(app_error.response().error_code, "VALIDATION_ERROR");
+    }
+
+    #[test]
+    fn test_inventory_error_period_overlap_maps_to_409() {
+        let app_error: AppError = InventoryError::PeriodOverlap(
+            chrono::NaiveDate::from_ymd_opt(2026, 9, 1).unwrap(),
+            chrono::NaiveDate::from_ymd_opt(2026, 9, 30).unwrap(),
+        )
+        .into();
+        assert_eq!(app_error.status(), StatusCode::CONFLICT);
+        assert_eq!(app_error.response().error_code, "PERIOD_OVERLAP");
+    }
+
+    #[test]
+    fn test_inventory_error_period_already_closed_maps_to_409() {
+        let app_error: AppError = InventoryError::PeriodAlreadyClosed(Uuid::nil()).into();
+        assert_eq!(app_error.status(), StatusCode::CONFLICT);
+        assert_eq!(app_error.response().error_code, "PERIOD_ALREADY_CLOSED");
+    }
+
+    #[test]
+    fn test_inventory_error_period_not_closed_maps_to_409() {
+        let app_error: AppError = InventoryError::PeriodNotClosed(Uuid::nil()).into();
+        assert_eq!(app_error.status(), StatusCode::CONFLICT);
+        assert_eq!(app_error.response().error_code, "PERIOD_NOT_CLOSED");
+    }
+
+    #[test]
+    fn test_inventory_error_invalid_period_status_maps_to_400() {
+        let app_error: AppError = InventoryError::InvalidPeriodStatus.into();
+        assert_eq!(app_error.status(), StatusCode::BAD_REQUEST);
+        assert_eq!(app_error.response().error_code, "VALIDATION_ERROR");
+    }
+
+    #[test]
+    fn test_sales_and_purchasing_period_closed_map_to_409() {
+        let start = chrono::NaiveDate::from_ymd_opt(2026, 9, 1).unwrap();
+        let end = chrono::NaiveDate::from_ymd_opt(2026, 9, 30).unwrap();
+
+        let app_error: AppError = SalesError::PeriodClosed(start, end).into();
+        assert_eq!(app_error.status(), StatusCode::CONFLICT);
+        assert_eq!(app_error.response().error_code, "PERIOD_CLOSED");
+
+        let app_error: AppError = PurchasingError::PeriodClosed(start, end).into();
+        assert_eq!(app_error.status(), StatusCode::CONFLICT);
+        assert_eq!(app_error.response().error_code, "PERIOD_CLOSED");
+    }
+
     #[test]
     fn test_inventory_error_duplicate_sku_maps_to_409() {
         let app_error: AppError = InventoryError::DuplicateSku("SKU-001".to_string()).into();
@@ -3074,6 +4365,13 @@ mod tests {
         assert_eq!(app_error.response().error_code, "INSUFFICIENT_STOCK");
     }
 
+    #[test]
+    fn test_inventory_error_concurrency_exhausted_maps_to_409() {
+        let app_error: AppError = InventoryError::ConcurrencyExhausted { attempts: 3 }.into();
+        assert_eq!(app_error.status(), StatusCode::CONFLICT);
+        assert_eq!(app_error.response().error_code, "CONCURRENCY_EXHAUSTED");
+    }
+
     #[test]
     fn test_inventory_error_same_store_transfer_maps_to_400() {
         let app_error: AppError = InventoryError::SameStoreTransfer.into();
@@ -3081,6 +4379,59 @@ mod tests {
         assert_eq!(app_error.response().error_code, "SAME_STORE_TRANSFER");
     }
 
+    #[test]
+    fn test_inventory_error_transfer_approval_required_maps_to_409() {
+        let app_error: AppError = InventoryError::TransferApprovalRequired.into();
+        assert_eq!(app_error.status(), StatusCode::CONFLICT);
+        assert_eq!(
+            app_error.response().error_code,
+            "TRANSFER_APPROVAL_REQUIRED"
+        );
+    }
+
+    #[test]
+    fn test_inventory_error_invalid_received_quantity_maps_to_400() {
+        let app_error: AppError = InventoryError::InvalidReceivedQuantity(Uuid::nil()).into();
+        assert_eq!(app_error.status(), StatusCode::BAD_REQUEST);
+        assert_eq!(app_error.response().error_code, "INVALID_RECEIVED_QUANTITY");
+    }
+
+    #[test]
+    fn test_inventory_error_incompatible_units_maps_to_400() {
+        let app_error: AppError = InventoryError::IncompatibleUnits {
+            from: "liter".to_string(),
+            to: "kg".to_string(),
+        }
+        .into();
+        assert_eq!(app_error.status(), StatusCode::BAD_REQUEST);
+        assert_eq!(app_error.response().error_code, "INCOMPATIBLE_UNITS");
+    }
+
+    #[test]
+    fn test_inventory_error_invalid_unit_conversion_maps_to_400() {
+        let app_error: AppError =
+            InventoryError::InvalidUnitConversion("factor must be positive".to_string()).into();
+        assert_eq!(app_error.status(), StatusCode::BAD_REQUEST);
+        assert_eq!(app_error.response().error_code, "INVALID_UNIT_CONVERSION");
+    }
+
+    #[test]
+    fn test_inventory_error_ingredient_unavailable_maps_to_400() {
+        let app_error: AppError =
+            InventoryError::IngredientUnavailable("Burger bun".to_string()).into();
+        assert_eq!(app_error.status(), StatusCode::BAD_REQUEST);
+        assert_eq!(app_error.response().error_code, "INGREDIENT_UNAVAILABLE");
+        assert!(app_error.response().message.contains("Burger bun"));
+    }
+
+    #[test]
+    fn test_inventory_error_invalid_ean13_check_digit_maps_to_400() {
+        let app_error: AppError =
+            InventoryError::InvalidEan13CheckDigit("4006381333932".to_string()).into();
+        assert_eq!(app_error.status(), StatusCode::BAD_REQUEST);
+        assert_eq!(app_error.response().error_code, "INVALID_EAN13_CHECK_DIGIT");
+    }
+
     #[test]
     fn test_inventory_error_invalid_status_transition_maps_to_400() {
         let app_error: AppError = InventoryError::InvalidStatusTransition.into();
@@ -3088,6 +4439,48 @@ mod tests {
         assert_eq!(app_error.response().error_code, "INVALID_STATUS_TRANSITION");
     }
 
+    #[test]
+    fn test_inventory_error_reservation_not_auto_extending_maps_to_400() {
+        let app_error: AppError = InventoryError::ReservationNotAutoExtending.into();
+        assert_eq!(app_error.status(), StatusCode::BAD_REQUEST);
+        assert_eq!(
+            app_error.response().error_code,
+            "RESERVATION_NOT_AUTO_EXTENDING"
+        );
+    }
+
+    #[test]
+    fn test_inventory_error_category_cycle_maps_to_400() {
+        let app_error: AppError = InventoryError::CategoryCycle.into();
+        assert_eq!(app_error.status(), StatusCode::BAD_REQUEST);
+        assert_eq!(app_error.response().error_code, "CATEGORY_CYCLE");
+    }
+
+    #[test]
+    fn test_inventory_error_category_depth_exceeded_maps_to_400() {
+        let app_error: AppError = InventoryError::CategoryDepthExceeded(5).into();
+        assert_eq!(app_error.status(), StatusCode::BAD_REQUEST);
+        assert_eq!(app_error.response().error_code, "CATEGORY_DEPTH_EXCEEDED");
+        assert_eq!(
+            app_error.response().message,
+            "Category tree cannot be deeper than 5 levels"
+        );
+    }
+
+    #[test]
+    fn test_inventory_error_invalid_category_order_maps_to_400() {
+        let app_error: AppError = InventoryError::InvalidCategoryOrder.into();
+        assert_eq!(app_error.status(), StatusCode::BAD_REQUEST);
+        assert_eq!(app_error.response().error_code, "VALIDATION_ERROR");
+    }
+
+    #[test]
+    fn test_inventory_error_empty_search_query_maps_to_400() {
+        let app_error: AppError = InventoryError::EmptySearchQuery.into();
+        assert_eq!(app_error.status(), StatusCode::BAD_REQUEST);
+        assert_eq!(app_error.response().message, "Search query cannot be empty");
+    }
+
     #[test]
     fn test_inventory_error_database_maps_to_500() {
         let db_error = sqlx::Error::RowNotFound;
@@ -3097,4 +4490,240 @@ mod tests {
         // Internal details should not be exposed
         assert_eq!(app_error.response().message, "Internal error");
     }
+
+    #[test]
+    fn test_invalid_tax_id_maps_to_400_with_expected_format() {
+        let message = common::TaxIdPolicy::default()
+            .parse("12345", None)
+            .unwrap_err()
+            .to_string();
+        let app_error: AppError = SalesError::InvalidTaxId(message.clone()).into();
+        assert_eq!(app_error.status(), StatusCode::BAD_REQUEST);
+        assert_eq!(app_error.response().error_code, "INVALID_TAX_ID");
+        assert_eq!(app_error.response().message, message);
+
+        let app_error: AppError = PurchasingError::InvalidTaxId(message).into();
+        assert_eq!(app_error.status(), StatusCode::BAD_REQUEST);
+        assert_eq!(app_error.response().error_code, "INVALID_TAX_ID");
+    }
+
+    #[test]
+    fn test_sales_idempotency_key_reused_maps_to_409() {
+        let app_error: AppError = SalesError::IdempotencyKeyReused("retry-1".to_string()).into();
+        assert_eq!(app_error.status(), StatusCode::CONFLICT);
+        assert_eq!(app_error.response().error_code, "IDEMPOTENCY_KEY_REUSED");
+    }
+
+    #[test]
+    fn test_ambiguous_barcode_maps_to_409_listing_candidates() {
+        let app_error: AppError = SalesError::AmbiguousBarcode {
+            barcode: "7501234567890".to_string(),
+            candidates: vec!["SKU-A".to_string(), "SKU-B".to_string()],
+        }
+        .into();
+        assert_eq!(app_error.status(), StatusCode::CONFLICT);
+        assert_eq!(app_error.response().error_code, "AMBIGUOUS_BARCODE");
+        assert!(app_error.response().message.contains("SKU-A, SKU-B"));
+    }
+
+    #[test]
+    fn test_quick_sale_fallback_maps_to_422() {
+        let app_error: AppError =
+            SalesError::QuickSaleNotSupported("product is serial-tracked".to_string()).into();
+        assert_eq!(app_error.status(), StatusCode::UNPROCESSABLE_ENTITY);
+        assert_eq!(app_error.response().error_code, "QUICK_SALE_NOT_SUPPORTED");
+
+        let app_error: AppError = SalesError::StockConflict.into();
+        assert_eq!(app_error.status(), StatusCode::CONFLICT);
+
+        let app_error: AppError = SalesError::InvoiceNumberNotAllowed.into();
+        assert_eq!(app_error.status(), StatusCode::BAD_REQUEST);
+        assert_eq!(
+            app_error.response().error_code,
+            "INVOICE_NUMBER_NOT_ALLOWED"
+        );
+    }
+
+    #[test]
+    fn test_goods_receipt_serial_errors() {
+        let app_error: AppError = PurchasingError::SerialCountMismatch.into();
+        assert_eq!(app_error.status(), StatusCode::BAD_REQUEST);
+        assert_eq!(app_error.response().error_code, "SERIAL_COUNT_MISMATCH");
+
+        let app_error: AppError = PurchasingError::SerialAlreadyRegistered("SN-1".into()).into();
+        assert_eq!(app_error.status(), StatusCode::CONFLICT);
+        assert_eq!(app_error.response().error_code, "DUPLICATE_SERIAL");
+    }
+
+    #[test]
+    fn test_purchasing_error_incompatible_units_maps_to_400() {
+        let app_error: AppError = PurchasingError::IncompatibleUnits {
+            from: "case".to_string(),
+            to: "unit".to_string(),
+        }
+        .into();
+        assert_eq!(app_error.status(), StatusCode::BAD_REQUEST);
+        assert_eq!(app_error.response().error_code, "INCOMPATIBLE_UNITS");
+        assert_eq!(app_error.response().message, "Cannot convert case to unit");
+    }
+
+    #[test]
+    fn test_purchasing_error_invalid_pack_pricing_maps_to_400() {
+        let app_error: AppError =
+            PurchasingError::InvalidPackPricing("pack_size must be positive".to_string()).into();
+        assert_eq!(app_error.status(), StatusCode::BAD_REQUEST);
+        assert_eq!(
+            app_error.response().message,
+            "Invalid pack pricing: pack_size must be positive"
+        );
+    }
+
+    #[test]
+    fn test_purchasing_error_forbidden_maps_to_403() {
+        let app_error: AppError = PurchasingError::Forbidden {
+            missing: vec!["purchase_orders:approve".to_string()],
+        }
+        .into();
+        assert_eq!(app_error.status(), StatusCode::FORBIDDEN);
+        assert_eq!(app_error.response().error_code, "FORBIDDEN");
+        assert_eq!(
+            app_error.response().message,
+            "Missing required permissions: purchase_orders:approve"
+        );
+    }
+
+    #[test]
+    fn test_purchasing_error_store_access_denied_maps_to_403() {
+        let store_id = Uuid::nil();
+        let app_error: AppError = PurchasingError::StoreAccessDenied(store_id).into();
+        assert_eq!(app_error.status(), StatusCode::FORBIDDEN);
+        assert_eq!(app_error.response().error_code, "STORE_ACCESS_DENIED");
+    }
+
+    #[test]
+    fn test_purchasing_error_approval_limit_exceeded_maps_to_403() {
+        let app_error: AppError = PurchasingError::ApprovalLimitExceeded {
+            total: rust_decimal::Decimal::new(5000, 0),
+            required_tier: "purchase_orders:approve_tier2".to_string(),
+        }
+        .into();
+        assert_eq!(app_error.status(), StatusCode::FORBIDDEN);
+        assert_eq!(app_error.response().error_code, "APPROVAL_LIMIT_EXCEEDED");
+        assert_eq!(
+            app_error.response().message,
+            "Purchase order total 5000 exceeds approval limit; requires purchase_orders:approve_tier2"
+        );
+    }
+
+    #[test]
+    fn test_purchasing_error_duplicate_approver_maps_to_400() {
+        let app_error: AppError = PurchasingError::DuplicateApprover.into();
+        assert_eq!(app_error.status(), StatusCode::BAD_REQUEST);
+        assert_eq!(app_error.response().error_code, "DUPLICATE_APPROVER");
+    }
+
+    #[test]
+    fn test_purchasing_error_receipt_match_failed_maps_to_400() {
+        let app_error: AppError =
+            PurchasingError::ReceiptMatchFailed("product x: 2 over ordered quantity".to_string())
+                .into();
+        assert_eq!(app_error.status(), StatusCode::BAD_REQUEST);
+        assert_eq!(app_error.response().error_code, "RECEIPT_MATCH_FAILED");
+        assert_eq!(
+            app_error.response().message,
+            "Goods receipt doesn't match the purchase order: product x: 2 over ordered quantity"
+        );
+    }
+
+    #[test]
+    fn test_purchasing_error_invalid_receipt_match_status_maps_to_400() {
+        let app_error: AppError = PurchasingError::InvalidReceiptMatchStatus.into();
+        assert_eq!(app_error.status(), StatusCode::BAD_REQUEST);
+        assert_eq!(app_error.response().message, "Invalid receipt match status");
+    }
+
+    #[test]
+    fn test_purchasing_error_invalid_date_range_maps_to_400() {
+        let app_error: AppError = PurchasingError::InvalidDateRange.into();
+        assert_eq!(app_error.status(), StatusCode::BAD_REQUEST);
+        assert_eq!(
+            app_error.response().message,
+            "from_date must not be after to_date"
+        );
+    }
+
+    #[test]
+    fn test_purchasing_error_expected_delivery_date_in_past_maps_to_400() {
+        let app_error: AppError = PurchasingError::ExpectedDeliveryDateInPast.into();
+        assert_eq!(app_error.status(), StatusCode::BAD_REQUEST);
+        assert_eq!(
+            app_error.response().error_code,
+            "EXPECTED_DELIVERY_DATE_IN_PAST"
+        );
+    }
+
+    #[test]
+    fn test_notifications_error_webhook_not_found_maps_to_404() {
+        let app_error: AppError = NotificationsError::WebhookNotFound(Uuid::nil()).into();
+        assert_eq!(app_error.status(), StatusCode::NOT_FOUND);
+        assert_eq!(app_error.response().error_code, "WEBHOOK_NOT_FOUND");
+    }
+
+    #[test]
+    fn test_notifications_error_short_webhook_secret_maps_to_400() {
+        let app_error: AppError = NotificationsError::WebhookSecretTooShort(16).into();
+        assert_eq!(app_error.status(), StatusCode::BAD_REQUEST);
+        assert_eq!(app_error.response().error_code, "VALIDATION_ERROR");
+    }
+
+    #[test]
+    fn test_sales_error_invalid_page_cursor_maps_to_400() {
+        let app_error: AppError =
+            SalesError::from(common::InvalidPageCursor("page-2".to_string())).into();
+        assert_eq!(app_error.status(), StatusCode::BAD_REQUEST);
+        assert_eq!(app_error.response().error_code, "INVALID_PAGE_CURSOR");
+    }
+
+    #[test]
+    fn test_inventory_error_invalid_import_row_maps_to_400() {
+        let app_error: AppError =
+            InventoryError::InvalidImportRow("name is required".to_string()).into();
+        assert_eq!(app_error.status(), StatusCode::BAD_REQUEST);
+        assert_eq!(app_error.response().error_code, "VALIDATION_ERROR");
+    }
+
+    #[test]
+    fn test_inventory_error_invalid_export_column_maps_to_400() {
+        let app_error: AppError = InventoryError::InvalidExportColumn("weight".to_string()).into();
+        assert_eq!(app_error.status(), StatusCode::BAD_REQUEST);
+        assert_eq!(app_error.response().error_code, "VALIDATION_ERROR");
+    }
+
+    #[test]
+    fn test_sales_gift_card_errors_map_to_status_codes() {
+        let not_found: AppError = SalesError::GiftCardNotFound("GC-0001".to_string()).into();
+        assert_eq!(not_found.status(), StatusCode::NOT_FOUND);
+        assert_eq!(not_found.response().error_code, "GIFT_CARD_NOT_FOUND");
+
+        let duplicate: AppError = SalesError::DuplicateGiftCardCode("GC-0001".to_string()).into();
+        assert_eq!(duplicate.status(), StatusCode::CONFLICT);
+
+        let depleted: AppError = SalesError::GiftCardDepleted.into();
+        assert_eq!(depleted.status(), StatusCode::CONFLICT);
+        assert_eq!(depleted.response().error_code, "GIFT_CARD_DEPLETED");
+    }
+
+    #[test]
+    fn test_sales_layaway_errors_map_to_status_codes() {
+        let not_found: AppError = SalesError::LayawayNotFound(Uuid::nil()).into();
+        assert_eq!(not_found.status(), StatusCode::NOT_FOUND);
+        assert_eq!(not_found.response().error_code, "LAYAWAY_NOT_FOUND");
+
+        let fee: AppError = SalesError::InvalidRestockingFee.into();
+        assert_eq!(fee.status(), StatusCode::BAD_REQUEST);
+
+        let on_layaway: AppError = SalesError::SaleOnLayaway.into();
+        assert_eq!(on_layaway.status(), StatusCode::CONFLICT);
+        assert_eq!(on_layaway.response().error_code, "SALE_ON_LAYAWAY");
+    }
 }
@@ -0,0 +1,242 @@
+// CSV export boundary
+//
+// Every data export (customer lists, sales, ...) is rendered through
+// `CsvExport` so the same compliance rules apply whichever report triggers it:
+//
+// - Columns are tagged with a `ColumnKind`; email and phone columns are
+//   masked unless the user is a super admin or holds `sales:read_customer_pii`.
+// - Each export writes one `audit_log` row (action `exported`) with the
+//   report, store, row count and whether PII was masked. The audit write is
+//   fail-closed: if it fails, the file is not returned.
+
+use std::sync::Arc;
+
+use axum::{
+    http::{StatusCode, header},
+    response::{IntoResponse, Response},
+};
+use chrono::Utc;
+use identity::{AuditAction, AuditEntry, AuditRepository, PgAuditRepository, UserContext};
+use uuid::{NoContext, Timestamp, Uuid};
+
+use crate::error::AppError;
+
+/// Permission required to see unmasked customer email/phone in exports.
+pub const PII_PERMISSION: &str = "sales:read_customer_pii";
+
+/// Page size used when an export walks a paginated list use case.
+pub const EXPORT_PAGE_SIZE: i64 = 100;
+
+/// Upper bound on rows in a single export.
+pub const MAX_EXPORT_ROWS: usize = 50_000;
+
+/// Audit entity type for export entries.
+const EXPORT_ENTITY_TYPE: &str = "data_export";
+
+/// How a column is treated when PII must be masked.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ColumnKind {
+    Plain,
+    Email,
+    Phone,
+}
+
+/// Returns true if the user may see unmasked PII in exports.
+pub fn can_view_pii(ctx: &UserContext) -> bool {
+    ctx.is_super_admin() || ctx.has_permission(PII_PERMISSION)
+}
+
+/// An in-memory CSV export: a header row plus data rows.
+pub struct CsvExport {
+    report: &'static str,
+    columns: Vec<(&'static str, ColumnKind)>,
+    rows: Vec<Vec<String>>,
+}
+
+impl CsvExport {
+    /// Creates an empty export for `report` (also used as the file name prefix).
+    pub fn new(report: &'static str, columns: Vec<(&'static str, ColumnKind)>) -> Self {
+        Self {
+            report,
+            columns,
+            rows: Vec::new(),
+        }
+    }
+
+    /// Appends a row; cells must follow the column order.
+    pub fn push_row(&mut self, row: Vec<String>) {
+        debug_assert_eq!(row.len(), self.columns.len());
+        self.rows.push(row);
+    }
+
+    pub fn row_count(&self) -> usize {
+        self.rows.len()
+    }
+
+    /// Renders the CSV, masking PII columns when `mask_pii` is set.
+    pub fn render(&self, mask_pii: bool) -> String {
+        let mut out = String::new();
+        let header: Vec<String> = self.columns.iter().map(|(n, _)| csv_field(n)).collect();
+        out.push_str(&header.join(","));
+        out.push_str("\r\n");
+
+        for row in &self.rows {
+            let cells: Vec<String> = row
+                .iter()
+                .zip(&self.columns)
+                .map(|(value, (_, kind))| {
+                    let value = match (mask_pii, kind) {
+                        (true, ColumnKind::Email) => mask_email(value),
+                        (true, ColumnKind::Phone) => mask_phone(value),
+                        _ => value.clone(),
+                    };
+                    csv_field(&value)
+                })
+                .collect();
+            out.push_str(&cells.join(","));
+            out.push_str("\r\n");
+        }
+        out
+    }
+
+    /// Audits the export for `ctx` and returns it as a CSV download.
+    pub async fn into_response(
+        self,
+        audit_repo: Arc<PgAuditRepository>,
+        ctx: &UserContext,
+        store_id: Option<Uuid>,
+    ) -> Result<Response, Response> {
+        let mask_pii = !can_view_pii(ctx);
+        let has_pii_columns = self.columns.iter().any(|(_, k)| *k != ColumnKind::Plain);
+
+        let entry = AuditEntry::create(
+            EXPORT_ENTITY_TYPE.to_string(),
+            Uuid::new_v7(Timestamp::now(NoContext)),
+            AuditAction::Exported,
+            None,
+            Some(serde_json::json!({
+                "report": self.report,
+                "store_id": store_id,
+                "row_count": self.row_count(),
+                "pii_masked": mask_pii && has_pii_columns,
+            })),
+            *ctx.user_id(),
+        );
+        audit_repo
+            .save(&entry)
+            .await
+            .map_err(|e| AppError::from(e).into_response())?;
+
+        let filename = format!("{}-{}.csv", self.report, Utc::now().format("%Y%m%d%H%M%S"));
+        Ok((
+            StatusCode::OK,
+            [
+                (header::CONTENT_TYPE, "text/csv; charset=utf-8".to_string()),
+                (
+                    header::CONTENT_DISPOSITION,
+                    format!("attachment; filename=\"{}\"", filename),
+                ),
+            ],
+            self.render(mask_pii),
+        )
+            .into_response())
+    }
+}
+
+/// Masks an email, keeping the first character of the local part and the domain:
+/// `john.doe@example.com` -> `j***@example.com`.
+pub fn mask_email(email: &str) -> String {
+    match email.split_once('@') {
+        Some((local, domain)) if !local.is_empty() => {
+            let first: String = local.chars().take(1).collect();
+            format!("{}***@{}", first, domain)
+        }
+        _ if email.is_empty() => String::new(),
+        _ => "***".to_string(),
+    }
+}
+
+/// Masks a phone number, keeping only the last four digits:
+/// `+504 9988-7766` -> `****7766`. Numbers that short are fully masked.
+pub fn mask_phone(phone: &str) -> String {
+    let digits: Vec<char> = phone.chars().filter(|c| c.is_ascii_digit()).collect();
+    if digits.is_empty() {
+        return String::new();
+    }
+    if digits.len() <= 4 {
+        return "****".to_string();
+    }
+    let visible: String = digits[digits.len().saturating_sub(4)..].iter().collect();
+    format!("****{}", visible)
+}
+
+/// Quotes a CSV field when it contains a delimiter, quote or line break.
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+// =============================================================================
+// Tests
+// =============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn customer_export() -> CsvExport {
+        let mut export = CsvExport::new(
+            "customers",
+            vec![
+                ("name", ColumnKind::Plain),
+                ("email", ColumnKind::Email),
+                ("phone", ColumnKind::Phone),
+            ],
+        );
+        export.push_row(vec![
+            "Doe, John".to_string(),
+            "john.doe@example.com".to_string(),
+            "+504 9988-7766".to_string(),
+        ]);
+        export
+    }
+
+    #[test]
+    fn mask_email_keeps_first_char_and_domain() {
+        assert_eq!(mask_email("john.doe@example.com"), "j***@example.com");
+        assert_eq!(mask_email("not-an-email"), "***");
+        assert_eq!(mask_email(""), "");
+    }
+
+    #[test]
+    fn mask_phone_keeps_last_four_digits() {
+        assert_eq!(mask_phone("+504 9988-7766"), "****7766");
+        assert_eq!(mask_phone("1234"), "****");
+        assert_eq!(mask_phone(""), "");
+    }
+
+    #[test]
+    fn render_masks_pii_columns_only_when_requested() {
+        let export = customer_export();
+
+        let masked = export.render(true);
+        assert_eq!(
+            masked,
+            "name,email,phone\r\n\"Doe, John\",j***@example.com,****7766\r\n"
+        );
+
+        let unmasked = export.render(false);
+        assert!(unmasked.contains("john.doe@example.com"));
+        assert!(unmasked.contains("+504 9988-7766"));
+    }
+
+    #[test]
+    fn csv_field_escapes_quotes_and_line_breaks() {
+        assert_eq!(csv_field("plain"), "plain");
+        assert_eq!(csv_field("say \"hi\""), "\"say \"\"hi\"\"\"");
+        assert_eq!(csv_field("a\nb"), "\"a\nb\"");
+    }
+}
@@ -7,4 +7,7 @@ pub use dashboards::{
     list_dashboards_handler, remove_widget_handler,
 };
 pub use kpis::get_kpi_snapshot_handler;
-pub use reports::run_report_handler;
+pub use reports::{
+    daily_sales_summary_handler, product_sales_ranking_handler, run_report_handler,
+    terminal_sales_report_handler,
+};
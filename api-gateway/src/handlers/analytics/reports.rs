@@ -1,12 +1,22 @@
 //! POST /api/v1/analytics/reports/run — execute a registered report.
+//! GET  /api/v1/analytics/reports/terminals — per-terminal sales for a store.
+//! GET  /api/v1/analytics/reports/daily-sales — daily sales summary per store.
+//! GET  /api/v1/analytics/reports/product-ranking — best sellers / slow movers.
 
 use axum::{
     Json,
-    extract::State,
+    extract::{Query, State},
     response::{IntoResponse, Response},
 };
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::Deserialize;
+use uuid::Uuid;
 
-use analytics::{ReportRows, RunReportCommand, RunReportUseCase};
+use analytics::{
+    AnalyticsError, DailySalesSummaryRow, GetDailySalesSummaryUseCase,
+    GetProductSalesRankingUseCase, GetTerminalSalesReportUseCase, ProductSalesRankingQuery,
+    ProductSalesRankingResponse, ReportRows, RunReportCommand, RunReportUseCase, TerminalSalesRow,
+};
 
 use crate::error::AppError;
 use crate::extractors::CurrentUser;
@@ -32,3 +42,88 @@ pub async fn run_report_handler(
 
     Ok(Json(rows))
 }
+
+#[derive(Debug, Deserialize)]
+pub struct TerminalSalesQuery {
+    pub store_id: Uuid,
+    pub from: DateTime<Utc>,
+    pub to: DateTime<Utc>,
+}
+
+pub async fn terminal_sales_report_handler(
+    State(state): State<AppState>,
+    CurrentUser(ctx): CurrentUser,
+    Query(params): Query<TerminalSalesQuery>,
+) -> Result<Json<Vec<TerminalSalesRow>>, Response> {
+    require_permission(&ctx, "reports:analytics")?;
+    verify_store_in_org(state.pool(), &ctx, params.store_id).await?;
+
+    let use_case = GetTerminalSalesReportUseCase::new(state.analytics_query_repo());
+    let rows = use_case
+        .execute(params.store_id, params.from, params.to)
+        .await
+        .map_err(|e| AppError::from(e).into_response())?;
+
+    Ok(Json(rows))
+}
+
+#[derive(Debug, Deserialize)]
+pub struct DailySalesSummaryQuery {
+    /// Comma-separated store ids
+    pub store_ids: String,
+    pub from: NaiveDate,
+    pub to: NaiveDate,
+}
+
+pub async fn daily_sales_summary_handler(
+    State(state): State<AppState>,
+    CurrentUser(ctx): CurrentUser,
+    Query(params): Query<DailySalesSummaryQuery>,
+) -> Result<Json<Vec<DailySalesSummaryRow>>, Response> {
+    require_permission(&ctx, "reports:analytics")?;
+
+    let store_ids = params
+        .store_ids
+        .split(',')
+        .filter(|s| !s.trim().is_empty())
+        .map(|s| s.trim().parse::<Uuid>())
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|_| {
+            AppError::from(AnalyticsError::InvalidStoreSelection(
+                "store_ids must be comma-separated UUIDs".to_string(),
+            ))
+            .into_response()
+        })?;
+    for store_id in &store_ids {
+        verify_store_in_org(state.pool(), &ctx, *store_id).await?;
+    }
+
+    let use_case = GetDailySalesSummaryUseCase::new(state.analytics_query_repo());
+    let rows = use_case
+        .execute(&store_ids, params.from, params.to)
+        .await
+        .map_err(|e| AppError::from(e).into_response())?;
+
+    Ok(Json(rows))
+}
+
+pub async fn product_sales_ranking_handler(
+    State(state): State<AppState>,
+    CurrentUser(ctx): CurrentUser,
+    Query(mut query): Query<ProductSalesRankingQuery>,
+) -> Result<Json<ProductSalesRankingResponse>, Response> {
+    require_permission(&ctx, "reports:analytics")?;
+    if let Some(store_id) = query.store_id {
+        verify_store_in_org(state.pool(), &ctx, store_id).await?;
+    } else if !ctx.is_super_admin() {
+        query.user_store_ids = Some(ctx.accessible_store_ids().to_vec());
+    }
+
+    let use_case = GetProductSalesRankingUseCase::new(state.analytics_query_repo());
+    let response = use_case
+        .execute(query)
+        .await
+        .map_err(|e| AppError::from(e).into_response())?;
+
+    Ok(Json(response))
+}
@@ -3,17 +3,39 @@
 // These handlers implement the REST endpoints for user authentication:
 // - POST /api/v1/auth/register - Ecommerce user registration
 // - POST /api/v1/auth/login - Unified login (email or username)
-// - POST /api/v1/auth/refresh - Token refresh
+// - POST /api/v1/auth/refresh - Token refresh (rotates the refresh token)
+// - POST /api/v1/auth/sessions/revoke - Log out everywhere (authenticated)
+// - POST /api/v1/auth/password/forgot - Email a password reset token
+// - POST /api/v1/auth/password/reset - Set a new password with a reset token
+// - POST /api/v1/auth/mfa/verify - Second step of a login with MFA
+// - POST /api/v1/auth/mfa/enroll - Start TOTP enrollment (authenticated)
+// - POST /api/v1/auth/mfa/confirm - Enable TOTP with a first code (authenticated)
+// - POST /api/v1/auth/mfa/disable - Turn MFA off (authenticated)
+// - POST /api/v1/auth/mfa/users/{user_id}/disable - Admin override (super_admin)
 
-use axum::{Json, extract::State, http::StatusCode};
+use axum::{
+    Json,
+    extract::{Path, State},
+    http::StatusCode,
+    response::{IntoResponse, Response},
+};
+use uuid::Uuid;
 
 use identity::{
-    LoginCommand, LoginResponse, LoginUseCase, RefreshCommand, RefreshTokenUseCase,
-    RegisterEcommerceCommand, RegisterResponse, RegisterUserUseCase,
+    AdminDisableMfaUseCase, AuthError, ConfirmMfaCommand, ConfirmMfaUseCase, DisableMfaCommand,
+    DisableMfaUseCase, EnrollMfaUseCase, ErrorResponse, LoginCommand, LoginOutcome, LoginResponse,
+    LoginUseCase, MfaEnrollmentResponse, MfaRecoveryCodesResponse, PasswordResetIssued,
+    RefreshCommand, RefreshTokenUseCase, RegisterEcommerceCommand, RegisterResponse,
+    RegisterUserUseCase, RequestPasswordResetCommand, RequestPasswordResetUseCase,
+    ResetPasswordCommand, ResetPasswordUseCase, RevokeAllSessionsUseCase, UserContext, UserId,
+    VerifyMfaCommand, VerifyMfaLoginUseCase,
 };
 
+use notifications::{NotificationChannel, SendNotificationCommand, SendNotificationUseCase};
+
 use crate::error::AppError;
-use crate::extractors::JsonBody;
+use crate::extractors::{CurrentUser, JsonBody};
+use crate::middleware::permission::require_super_admin;
 use crate::state::AppState;
 
 // =============================================================================
@@ -64,7 +86,9 @@ pub async fn register_handler(
 /// Handler for POST /api/v1/auth/login
 ///
 /// Authenticates a user with email or username and password.
-/// Returns JWT access and refresh tokens on success.
+/// Returns JWT access and refresh tokens on success. When the user has MFA
+/// enabled, returns `{"mfa_required": true, "mfa_token": ..., "expires_in": ...}`
+/// instead; the token is exchanged at `/mfa/verify` together with a code.
 ///
 /// # Request Body
 ///
@@ -77,7 +101,7 @@ pub async fn register_handler(
 ///
 /// # Response
 ///
-/// - 200 OK: Login successful, returns tokens
+/// - 200 OK: Login successful, returns tokens or an MFA challenge
 /// - 400 Bad Request: Malformed request body
 /// - 401 Unauthorized: Invalid credentials or account disabled
 /// - 500 Internal Server Error: Unexpected error
@@ -89,8 +113,13 @@ pub async fn register_handler(
 pub async fn login_handler(
     State(state): State<AppState>,
     JsonBody(command): JsonBody<LoginCommand>,
-) -> Result<Json<LoginResponse>, AppError> {
-    let use_case = LoginUseCase::new(state.user_repo(), state.token_service());
+) -> Result<Json<LoginOutcome>, AppError> {
+    let use_case = LoginUseCase::new(
+        state.user_repo(),
+        state.token_service(),
+        state.user_mfa_repo(),
+        state.refresh_token_repo(),
+    );
 
     let response = use_case.execute(command).await?;
 
@@ -104,7 +133,9 @@ pub async fn login_handler(
 /// Handler for POST /api/v1/auth/refresh
 ///
 /// Refreshes an access token using a valid refresh token.
-/// Returns a new access token (refresh token remains the same).
+/// Returns a new access token and a rotated refresh token; the presented
+/// refresh token cannot be used again. Presenting an already rotated token
+/// revokes every token descended from the same login.
 ///
 /// # Request Body
 ///
@@ -118,7 +149,7 @@ pub async fn login_handler(
 ///
 /// - 200 OK: Token refreshed successfully
 /// - 400 Bad Request: Malformed request body
-/// - 401 Unauthorized: Invalid or expired refresh token
+/// - 401 Unauthorized: Invalid, expired, revoked or reused refresh token
 /// - 500 Internal Server Error: Unexpected error
 ///
 /// # Requirements
@@ -131,9 +162,310 @@ pub async fn refresh_handler(
     State(state): State<AppState>,
     JsonBody(command): JsonBody<RefreshCommand>,
 ) -> Result<Json<LoginResponse>, AppError> {
-    let use_case = RefreshTokenUseCase::new(state.user_repo(), state.token_service());
+    let use_case = RefreshTokenUseCase::new(
+        state.user_repo(),
+        state.token_service(),
+        state.refresh_token_repo(),
+    );
+
+    let response = use_case
+        .execute(command.refresh_token)
+        .await
+        .inspect_err(|e| {
+            if matches!(e, AuthError::RefreshTokenReused) {
+                tracing::warn!("Refresh token reuse detected; token family revoked");
+            }
+        })?;
+
+    Ok(Json(response))
+}
+
+/// Handler for POST /api/v1/auth/sessions/revoke
+///
+/// Revokes every refresh token of the current user, logging out all of
+/// their sessions once the access tokens in flight expire.
+///
+/// # Response
+///
+/// - 204 No Content: Sessions revoked
+/// - 401 Unauthorized: Not authenticated
+/// - 403 Forbidden: Called with an impersonation token
+pub async fn revoke_all_sessions_handler(
+    State(state): State<AppState>,
+    CurrentUser(ctx): CurrentUser,
+) -> Result<StatusCode, AppError> {
+    reject_impersonation(
+        &ctx,
+        "Sessions cannot be revoked while impersonating a user",
+    )?;
+
+    let use_case = RevokeAllSessionsUseCase::new(state.refresh_token_repo());
+
+    use_case.execute(*ctx.user_id()).await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+// =============================================================================
+// Password Reset Handlers
+// =============================================================================
+
+/// Handler for POST /api/v1/auth/password/forgot
+///
+/// Emails a single-use password reset token to the account with this email.
+/// Answers the same whether or not such an account exists, so it cannot be
+/// used to find out who is registered.
+///
+/// # Request Body
+///
+/// ```json
+/// {
+///   "email": "user@example.com"
+/// }
+/// ```
+///
+/// # Response
+///
+/// - 202 Accepted: Request taken; a token was emailed if the account exists
+/// - 400 Bad Request: Malformed request body or email
+pub async fn forgot_password_handler(
+    State(state): State<AppState>,
+    JsonBody(command): JsonBody<RequestPasswordResetCommand>,
+) -> Result<StatusCode, AppError> {
+    let use_case = RequestPasswordResetUseCase::new(state.user_repo(), state.password_reset_repo());
+
+    if let Some(issued) = use_case.execute(command).await? {
+        // A delivery failure must not change the answer
+        if let Err(e) = send_password_reset_email(&state, &issued).await {
+            tracing::warn!(user_id = %issued.user_id, "Failed to send password reset email: {}", e);
+        }
+    }
+
+    Ok(StatusCode::ACCEPTED)
+}
+
+/// Handler for POST /api/v1/auth/password/reset
+///
+/// Sets a new password with a token from `/password/forgot`. Every session
+/// of the user is signed out.
+///
+/// # Request Body
+///
+/// ```json
+/// {
+///   "token": "9f86d081884c7d65...",
+///   "new_password": "newsecurepass123"
+/// }
+/// ```
+///
+/// # Response
+///
+/// - 204 No Content: Password changed
+/// - 400 Bad Request: Malformed request body or password too short
+/// - 401 Unauthorized: Invalid, used or expired token, or account disabled
+pub async fn reset_password_handler(
+    State(state): State<AppState>,
+    JsonBody(command): JsonBody<ResetPasswordCommand>,
+) -> Result<StatusCode, AppError> {
+    let use_case = ResetPasswordUseCase::new(
+        state.user_repo(),
+        state.password_reset_repo(),
+        state.refresh_token_repo(),
+    );
+
+    use_case.execute(command).await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+async fn send_password_reset_email(
+    state: &AppState,
+    issued: &PasswordResetIssued,
+) -> Result<(), notifications::NotificationsError> {
+    let command = SendNotificationCommand {
+        channel: NotificationChannel::Email,
+        recipient: issued.email.clone(),
+        subject: Some("Reset your password".to_string()),
+        body: format!(
+            "Use this token to reset your password: {}\n\nIt expires at {}. If you did not request a reset, you can ignore this email.",
+            issued.token,
+            issued.expires_at.format("%Y-%m-%d %H:%M UTC"),
+        ),
+        metadata: serde_json::json!({
+            "event": "identity.password_reset_requested",
+            "user_id": issued.user_id,
+        }),
+    };
+
+    SendNotificationUseCase::new(state.notification_repo(), state.notification_registry())
+        .execute(command)
+        .await
+        .map(|_| ())
+}
+
+// =============================================================================
+// MFA Handlers
+// =============================================================================
+
+/// Handler for POST /api/v1/auth/mfa/verify
+///
+/// Completes a login that returned an MFA challenge. Accepts the current
+/// authenticator code or one of the recovery codes.
+///
+/// # Request Body
+///
+/// ```json
+/// {
+///   "mfa_token": "eyJhbGciOiJIUzI1NiIs...",
+///   "code": "123456"
+/// }
+/// ```
+///
+/// # Response
+///
+/// - 200 OK: Second factor accepted, returns tokens
+/// - 401 Unauthorized: Invalid or expired MFA token, or wrong code
+/// - 429 Too Many Requests: Too many failed codes, the second factor is locked
+pub async fn verify_mfa_handler(
+    State(state): State<AppState>,
+    JsonBody(command): JsonBody<VerifyMfaCommand>,
+) -> Result<Json<LoginResponse>, AppError> {
+    let use_case = VerifyMfaLoginUseCase::new(
+        state.user_repo(),
+        state.token_service(),
+        state.user_mfa_repo(),
+        state.refresh_token_repo(),
+        state.mfa_policy(),
+    );
+
+    let response = use_case.execute(command).await?;
 
-    let response = use_case.execute(command.refresh_token).await?;
+    Ok(Json(response))
+}
+
+/// Handler for POST /api/v1/auth/mfa/enroll
+///
+/// Starts (or restarts) TOTP enrollment for the current user and returns the
+/// secret and the `otpauth://` URI to show as a QR code. MFA is not enforced
+/// until the enrollment is confirmed.
+///
+/// # Response
+///
+/// - 200 OK: Enrollment started
+/// - 403 Forbidden: Called with an impersonation token
+/// - 409 Conflict: MFA is already enabled
+pub async fn enroll_mfa_handler(
+    State(state): State<AppState>,
+    CurrentUser(ctx): CurrentUser,
+) -> Result<Json<MfaEnrollmentResponse>, AppError> {
+    reject_impersonation(&ctx, MFA_IMPERSONATION_MESSAGE)?;
+
+    let use_case =
+        EnrollMfaUseCase::new(state.user_repo(), state.user_mfa_repo(), state.mfa_policy());
+
+    let response = use_case.execute(*ctx.user_id()).await?;
 
     Ok(Json(response))
 }
+
+/// Handler for POST /api/v1/auth/mfa/confirm
+///
+/// Enables MFA once the user enters a code from the newly enrolled
+/// authenticator. Returns the recovery codes, which are only shown once.
+///
+/// # Response
+///
+/// - 200 OK: MFA enabled, returns recovery codes
+/// - 401 Unauthorized: Wrong code
+/// - 403 Forbidden: Called with an impersonation token
+/// - 409 Conflict: No pending enrollment, or MFA is already enabled
+pub async fn confirm_mfa_handler(
+    State(state): State<AppState>,
+    CurrentUser(ctx): CurrentUser,
+    JsonBody(command): JsonBody<ConfirmMfaCommand>,
+) -> Result<Json<MfaRecoveryCodesResponse>, AppError> {
+    reject_impersonation(&ctx, MFA_IMPERSONATION_MESSAGE)?;
+
+    let use_case = ConfirmMfaUseCase::new(state.user_mfa_repo(), state.mfa_policy());
+
+    let response = use_case.execute(*ctx.user_id(), command).await?;
+
+    Ok(Json(response))
+}
+
+/// Handler for POST /api/v1/auth/mfa/disable
+///
+/// Turns MFA off for the current user. Requires the password and a current
+/// code or recovery code.
+///
+/// # Response
+///
+/// - 204 No Content: MFA disabled
+/// - 401 Unauthorized: Wrong password or code
+/// - 403 Forbidden: Called with an impersonation token
+/// - 409 Conflict: MFA is not enabled
+pub async fn disable_mfa_handler(
+    State(state): State<AppState>,
+    CurrentUser(ctx): CurrentUser,
+    JsonBody(command): JsonBody<DisableMfaCommand>,
+) -> Result<StatusCode, AppError> {
+    reject_impersonation(&ctx, MFA_IMPERSONATION_MESSAGE)?;
+
+    let use_case =
+        DisableMfaUseCase::new(state.user_repo(), state.user_mfa_repo(), state.mfa_policy());
+
+    use_case.execute(*ctx.user_id(), command).await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// Handler for POST /api/v1/auth/mfa/users/{user_id}/disable
+///
+/// Administrator override for a user who lost both the authenticator and
+/// the recovery codes: removes their MFA enrollment without a code. Each
+/// use is recorded in the audit log. Administrators turn off their own MFA
+/// through `/mfa/disable` like everyone else.
+///
+/// # Response
+///
+/// - 204 No Content: MFA removed
+/// - 403 Forbidden: Not super_admin, impersonating, or targeting oneself
+/// - 409 Conflict: The user has no MFA enrollment
+pub async fn admin_disable_mfa_handler(
+    State(state): State<AppState>,
+    CurrentUser(ctx): CurrentUser,
+    Path(user_id): Path<Uuid>,
+) -> Result<StatusCode, Response> {
+    require_super_admin(&ctx)?;
+    reject_impersonation(&ctx, MFA_IMPERSONATION_MESSAGE).map_err(IntoResponse::into_response)?;
+    if ctx.user_id().into_uuid() == user_id {
+        return Err(AppError::new(
+            StatusCode::FORBIDDEN,
+            ErrorResponse::new("FORBIDDEN", "Use /mfa/disable to turn off your own MFA"),
+        )
+        .into_response());
+    }
+
+    let use_case = AdminDisableMfaUseCase::new(state.user_mfa_repo(), state.audit_repo());
+
+    use_case
+        .execute(UserId::from_uuid(user_id), *ctx.user_id())
+        .await
+        .map_err(|e| AppError::from(e).into_response())?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+const MFA_IMPERSONATION_MESSAGE: &str = "MFA settings cannot be changed while impersonating a user";
+
+/// Second-factor settings and sessions belong to the user; an operator acting
+/// through an impersonation token must not change them.
+fn reject_impersonation(ctx: &UserContext, message: &str) -> Result<(), AppError> {
+    if ctx.actor_id().is_some() {
+        return Err(AppError::new(
+            StatusCode::FORBIDDEN,
+            ErrorResponse::new("FORBIDDEN", message),
+        ));
+    }
+    Ok(())
+}
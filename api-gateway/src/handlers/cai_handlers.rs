@@ -5,10 +5,11 @@
 // - GET /terminals/:id/cai/status - Get CAI status
 // - POST /terminals/:id/cai/next-number - Get next invoice number
 // - GET /terminals/:id/cai/history - Get CAI history
+// - GET /terminals/cai-near-exhaustion - List CAI ranges close to running out
 
 use axum::{
     Json,
-    extract::{Path, State},
+    extract::{Path, Query, State},
     http::StatusCode,
     response::{IntoResponse, Response},
 };
@@ -16,8 +17,9 @@ use chrono::NaiveDate;
 use uuid::Uuid;
 
 use pos_core::{
-    AssignCaiCommand, AssignCaiUseCase, CaiHistoryItemResponse, CaiStatusResponse,
-    GetCaiStatusUseCase, GetNextInvoiceNumberUseCase, GetTerminalDetailUseCase, ListResponse,
+    AssignCaiCommand, AssignCaiUseCase, CaiHistoryItemResponse, CaiNearExhaustionResponse,
+    CaiStatusResponse, GetCaiStatusUseCase, GetNextInvoiceNumberUseCase, GetTerminalDetailUseCase,
+    ListCaisNearExhaustionQuery, ListCaisNearExhaustionUseCase, ListResponse,
     NextInvoiceNumberResponse, TerminalId,
 };
 
@@ -155,7 +157,7 @@ pub struct AssignCaiRequest {
 /// - 404 Not Found: Terminal doesn't exist
 /// - 500 Internal Server Error: Unexpected error
 ///
-/// - Return current number, remaining, and expiration date
+/// - Return current number, remaining, expiration date and days until it
 /// - Include warning if CAI expires within 30 days
 pub async fn get_cai_status_handler(
     State(state): State<AppState>,
@@ -248,3 +250,44 @@ pub async fn get_cai_history_handler(
 
     Ok(Json(ListResponse::new(terminal_detail.cai_history)))
 }
+
+// =============================================================================
+// List CAIs Near Exhaustion Handler
+// =============================================================================
+
+/// Handler for GET /terminals/cai-near-exhaustion
+///
+/// Lists the current CAI ranges of active terminals that are running out of
+/// numbers or about to expire, with the terminal each belongs to.
+///
+/// # Query Parameters
+///
+/// - `remaining_percent`: Flag ranges with less than this share left (default 10)
+/// - `expiry_days`: Flag ranges expiring within this many days (default 30)
+///
+/// # Response
+///
+/// - 200 OK: At-risk CAI ranges, most urgent first
+/// - 400 Bad Request: Threshold out of range
+/// - 401 Unauthorized: Missing or invalid token
+/// - 500 Internal Server Error: Unexpected error
+///
+/// - Non-super-admin users only see terminals of their assigned stores
+pub async fn list_cais_near_exhaustion_handler(
+    State(state): State<AppState>,
+    CurrentUser(ctx): CurrentUser,
+    Query(mut query): Query<ListCaisNearExhaustionQuery>,
+) -> Result<Json<ListResponse<CaiNearExhaustionResponse>>, Response> {
+    if !ctx.is_super_admin() {
+        query.user_store_ids = Some(ctx.accessible_store_ids().to_vec());
+    }
+
+    let use_case = ListCaisNearExhaustionUseCase::new(state.terminal_repo());
+
+    let response = use_case
+        .execute(query)
+        .await
+        .map_err(|e| AppError::from(e).into_response())?;
+
+    Ok(Json(ListResponse::new(response)))
+}
@@ -1,4 +1,4 @@
-//! Forecast read endpoints.
+//! Forecast and sales-velocity read endpoints.
 
 use axum::{
     Json,
@@ -8,7 +8,11 @@ use axum::{
 use serde::Deserialize;
 use uuid::Uuid;
 
-use demand_planning::{DemandForecastResponse, GetForecastUseCase};
+use demand_planning::{
+    DemandForecastResponse, GetForecastUseCase, GetProductAvailabilityForecastUseCase,
+    GetProductSalesVelocityUseCase, ProductAvailabilityForecastResponse, SalesVelocityResponse,
+    VelocityWindows,
+};
 
 use crate::error::AppError;
 use crate::extractors::CurrentUser;
@@ -38,3 +42,83 @@ pub async fn get_forecast_handler(
         forecasts.iter().map(DemandForecastResponse::from).collect(),
     ))
 }
+
+#[derive(Debug, Deserialize)]
+pub struct SalesVelocityQuery {
+    /// Omit to aggregate across every store.
+    pub store_id: Option<Uuid>,
+    /// Omit to list every variant that sold in the long window.
+    pub variant_id: Option<Uuid>,
+    pub short_days: Option<i64>,
+    pub medium_days: Option<i64>,
+    pub long_days: Option<i64>,
+}
+
+pub async fn get_sales_velocity_handler(
+    State(state): State<AppState>,
+    CurrentUser(ctx): CurrentUser,
+    Query(params): Query<SalesVelocityQuery>,
+) -> Result<Json<Vec<SalesVelocityResponse>>, Response> {
+    require_permission(&ctx, "demand_planning:read_forecast")?;
+    if let Some(sid) = params.store_id {
+        verify_store_in_org(state.pool(), &ctx, sid).await?;
+    }
+    let defaults = VelocityWindows::default();
+    let windows = VelocityWindows {
+        short_days: params.short_days.unwrap_or(defaults.short_days),
+        medium_days: params.medium_days.unwrap_or(defaults.medium_days),
+        long_days: params.long_days.unwrap_or(defaults.long_days),
+    };
+    let use_case = GetProductSalesVelocityUseCase::new(state.sales_history_repo());
+    let velocities = use_case
+        .execute(params.store_id, params.variant_id, windows)
+        .await
+        .map_err(|e| AppError::from(e).into_response())?;
+    Ok(Json(
+        velocities.iter().map(SalesVelocityResponse::from).collect(),
+    ))
+}
+
+#[derive(Debug, Deserialize)]
+pub struct AvailabilityForecastQuery {
+    pub store_id: Uuid,
+    /// Days to project, 90 by default.
+    pub horizon_days: Option<i64>,
+    pub short_days: Option<i64>,
+    /// Window whose rate is used as daily demand.
+    pub medium_days: Option<i64>,
+    pub long_days: Option<i64>,
+}
+
+/// Projected stock-out date and days of cover for one variant at a store,
+/// counting open purchase orders on their expected delivery dates.
+pub async fn get_availability_forecast_handler(
+    State(state): State<AppState>,
+    CurrentUser(ctx): CurrentUser,
+    Path(variant_id): Path<Uuid>,
+    Query(params): Query<AvailabilityForecastQuery>,
+) -> Result<Json<ProductAvailabilityForecastResponse>, Response> {
+    require_permission(&ctx, "demand_planning:read_forecast")?;
+    verify_store_in_org(state.pool(), &ctx, params.store_id).await?;
+    let defaults = VelocityWindows::default();
+    let windows = VelocityWindows {
+        short_days: params.short_days.unwrap_or(defaults.short_days),
+        medium_days: params.medium_days.unwrap_or(defaults.medium_days),
+        long_days: params.long_days.unwrap_or(defaults.long_days),
+    };
+    let use_case = GetProductAvailabilityForecastUseCase::new(
+        state.sales_history_repo(),
+        state.stock_snapshot_repo(),
+        state.incoming_supply_repo(),
+    );
+    let forecast = use_case
+        .execute(
+            variant_id,
+            params.store_id,
+            windows,
+            params.horizon_days.unwrap_or(90),
+        )
+        .await
+        .map_err(|e| AppError::from(e).into_response())?;
+    Ok(Json(ProductAvailabilityForecastResponse::from(&forecast)))
+}
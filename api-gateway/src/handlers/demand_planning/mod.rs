@@ -4,8 +4,11 @@ pub mod policies;
 pub mod suggestions;
 
 pub use abc::list_abc_handler;
-pub use forecasts::get_forecast_handler;
+pub use forecasts::{
+    get_availability_forecast_handler, get_forecast_handler, get_sales_velocity_handler,
+};
 pub use policies::{list_reorder_policies_handler, upsert_reorder_policy_handler};
 pub use suggestions::{
-    approve_suggestion_handler, dismiss_suggestion_handler, list_replenishment_suggestions_handler,
+    approve_suggestion_handler, dismiss_suggestion_handler, generate_replenishment_orders_handler,
+    list_replenishment_suggestions_handler,
 };
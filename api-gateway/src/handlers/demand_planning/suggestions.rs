@@ -1,4 +1,5 @@
-//! Replenishment suggestion endpoints (list + approve + dismiss).
+//! Replenishment suggestion endpoints (list + approve + dismiss), plus draft
+//! purchase orders generated from a store's low-stock alerts.
 
 use std::sync::Arc;
 
@@ -12,9 +13,12 @@ use uuid::Uuid;
 
 use demand_planning::{
     ApproveSuggestionCommand, ApproveSuggestionUseCase, DismissSuggestionCommand,
-    DismissSuggestionUseCase, ListReplenishmentSuggestionsUseCase, ReplenishmentSuggestionResponse,
-    SuggestionId, SuggestionStatus,
+    DismissSuggestionUseCase, GenerateReplenishmentOrdersCommand,
+    GenerateReplenishmentOrdersUseCase, ListReplenishmentSuggestionsUseCase,
+    ReplenishmentOrdersResponse, ReplenishmentSuggestionResponse, SuggestionId, SuggestionStatus,
 };
+use identity::StoreId;
+use inventory::GetLowStockAlertsUseCase;
 use purchasing::CreatePurchaseOrderUseCase;
 
 use crate::error::AppError;
@@ -85,3 +89,31 @@ pub async fn dismiss_suggestion_handler(
         .map_err(|e| AppError::from(e).into_response())?;
     Ok(Json(ReplenishmentSuggestionResponse::from(&suggestion)))
 }
+
+pub async fn generate_replenishment_orders_handler(
+    State(state): State<AppState>,
+    CurrentUser(ctx): CurrentUser,
+    Path(store_id): Path<Uuid>,
+    Json(cmd): Json<GenerateReplenishmentOrdersCommand>,
+) -> Result<Json<ReplenishmentOrdersResponse>, Response> {
+    require_permission(&ctx, "demand_planning:approve_suggestion")?;
+    verify_store_in_org(state.pool(), &ctx, store_id).await?;
+
+    let create_po = Arc::new(CreatePurchaseOrderUseCase::new(
+        state.purchase_order_repo(),
+        state.vendor_repo(),
+    ));
+    let low_stock = Arc::new(GetLowStockAlertsUseCase::new(state.stock_repo()));
+    let use_case = GenerateReplenishmentOrdersUseCase::new(
+        state.reorder_policy_repo(),
+        low_stock,
+        state.product_repo(),
+        state.goods_receipt_repo(),
+        create_po,
+    );
+    let response = use_case
+        .execute(StoreId::from_uuid(store_id), *ctx.user_id().as_uuid(), cmd)
+        .await
+        .map_err(|e| AppError::from(e).into_response())?;
+    Ok(Json(response))
+}
@@ -14,8 +14,8 @@ use crate::middleware::org_scope::verify_store_in_org;
 use crate::middleware::permission::require_permission;
 use crate::state::AppState;
 use fiscal::{
-    CalculateTaxCommand, GenerateInvoiceCommand, InvoiceResponse, ListInvoicesQuery,
-    TaxCalculationResponse, VoidInvoiceCommand,
+    CalculateTaxCommand, GenerateInvoiceCommand, InvoiceResponse, InvoiceSequenceAuditQuery,
+    InvoiceSequenceAuditResponse, ListInvoicesQuery, TaxCalculationResponse, VoidInvoiceCommand,
 };
 
 pub async fn generate_invoice_handler(
@@ -79,6 +79,27 @@ pub async fn list_invoices_handler(
     Ok(Json(response))
 }
 
+pub async fn invoice_sequence_audit_handler(
+    State(state): State<AppState>,
+    CurrentUser(ctx): CurrentUser,
+    Query(query): Query<InvoiceSequenceAuditQuery>,
+) -> Result<Json<InvoiceSequenceAuditResponse>, Response> {
+    require_permission(&ctx, "invoices:read")?;
+    verify_store_in_org(state.pool(), &ctx, query.store_id).await?;
+
+    let use_case = fiscal::GetInvoiceSequenceAuditUseCase::new(
+        state.invoice_repo(),
+        state.fiscal_sequence_repo(),
+    );
+
+    let response = use_case
+        .execute(query)
+        .await
+        .map_err(|e| AppError::from(e).into_response())?;
+
+    Ok(Json(response))
+}
+
 pub async fn void_invoice_handler(
     State(state): State<AppState>,
     CurrentUser(ctx): CurrentUser,
@@ -107,7 +128,11 @@ pub async fn calculate_tax_handler(
     require_permission(&ctx, "invoices:read")?;
     verify_store_in_org(state.pool(), &ctx, command.store_id).await?;
 
-    let use_case = fiscal::CalculateTaxUseCase::new(state.tax_rate_repo());
+    let use_case = fiscal::CalculateTaxUseCase::new(
+        state.tax_rate_repo(),
+        state.product_repo(),
+        state.category_repo(),
+    );
 
     let response = use_case
         .execute(command)
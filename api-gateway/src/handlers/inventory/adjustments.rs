@@ -31,6 +31,7 @@ use crate::error::AppError;
 use crate::extractors::{CurrentUser, JsonBody};
 use crate::middleware::org_scope::verify_store_in_org;
 use crate::middleware::permission::require_permission;
+use crate::middleware::store_settings::load_store_settings;
 use crate::state::AppState;
 
 use super::products::{default_page, default_page_size};
@@ -48,6 +49,8 @@ pub struct ListAdjustmentsQueryParams {
     /// Number of items per page (default: 20, max: 100)
     #[serde(default = "default_page_size")]
     pub page_size: i64,
+    /// Keyset cursor from a previous page's `next_cursor` (overrides `page`)
+    pub after_cursor: Option<String>,
     /// Filter by store ID
     pub store_id: Option<Uuid>,
     /// Filter by status (draft, pending_approval, approved, rejected, applied)
@@ -61,6 +64,7 @@ impl From<ListAdjustmentsQueryParams> for ListAdjustmentsQuery {
             status: params.status,
             page: params.page,
             page_size: params.page_size,
+            after_cursor: params.after_cursor,
         }
     }
 }
@@ -113,7 +117,8 @@ pub async fn create_adjustment_handler(
     require_permission(&ctx, "adjustments:create")?;
     verify_store_in_org(state.pool(), &ctx, command.store_id).await?;
 
-    let use_case = CreateAdjustmentUseCase::new(state.adjustment_repo());
+    let use_case =
+        CreateAdjustmentUseCase::new(state.adjustment_repo(), state.adjustment_approval_policy());
 
     let actor_id = *ctx.user_id();
     let response = use_case
@@ -158,7 +163,7 @@ pub async fn list_adjustments_handler(
 
     let query: ListAdjustmentsQuery = params.into();
     let response = use_case
-        .execute(query)
+        .execute(query, &ctx)
         .await
         .map_err(|e| AppError::from(e).into_response())?;
 
@@ -182,7 +187,8 @@ pub async fn list_adjustments_handler(
 /// - 200 OK: Adjustment details with items
 /// - 401 Unauthorized: Missing or invalid token
 /// - 403 Forbidden: User lacks inventory:adjustments:read permission
-/// - 404 Not Found: Adjustment doesn't exist
+/// - 404 Not Found: Adjustment doesn't exist, or the user is not assigned to
+///   its store
 pub async fn get_adjustment_handler(
     State(state): State<AppState>,
     CurrentUser(ctx): CurrentUser,
@@ -193,7 +199,7 @@ pub async fn get_adjustment_handler(
     let use_case = GetAdjustmentUseCase::new(state.adjustment_repo());
 
     let response = use_case
-        .execute(id)
+        .execute(id, &ctx)
         .await
         .map_err(|e| AppError::from(e).into_response())?;
 
@@ -279,10 +285,9 @@ pub async fn approve_adjustment_handler(
         approve: true,
         notes: body.and_then(|b| b.notes),
     };
-    let approver_id = *ctx.user_id();
 
     let response = use_case
-        .execute(command, approver_id)
+        .execute(command, &ctx)
         .await
         .map_err(|e| AppError::from(e).into_response())?;
 
@@ -331,10 +336,9 @@ pub async fn reject_adjustment_handler(
         approve: false,
         notes: body.and_then(|b| b.notes),
     };
-    let approver_id = *ctx.user_id();
 
     let response = use_case
-        .execute(command, approver_id)
+        .execute(command, &ctx)
         .await
         .map_err(|e| AppError::from(e).into_response())?;
 
@@ -360,6 +364,8 @@ pub async fn reject_adjustment_handler(
 /// - 401 Unauthorized: Missing or invalid token
 /// - 403 Forbidden: User lacks adjustments:apply permission
 /// - 404 Not Found: Adjustment doesn't exist
+/// - 409 Conflict: The adjustment is dated in a closed period and the user
+///   lacks inventory:reopen_period (`PERIOD_CLOSED`)
 pub async fn apply_adjustment_handler(
     State(state): State<AppState>,
     CurrentUser(ctx): CurrentUser,
@@ -367,17 +373,28 @@ pub async fn apply_adjustment_handler(
 ) -> Result<Json<AdjustmentDetailResponse>, Response> {
     require_permission(&ctx, "adjustments:apply")?;
 
+    // Whether the adjustment may take stock below zero is the store's call
+    let adjustment = GetAdjustmentUseCase::new(state.adjustment_repo())
+        .execute(id, &ctx)
+        .await
+        .map_err(|e| AppError::from(e).into_response())?;
+    let settings = load_store_settings(&state, adjustment.store_id).await?;
+
     let use_case = ApplyAdjustmentUseCase::new(
         state.adjustment_repo(),
         state.stock_repo(),
         state.movement_repo(),
+    )
+    .with_period_lock(
+        state.inventory_period_repo(),
+        ctx.has_permission("inventory:reopen_period"),
     );
 
     let command = ApplyAdjustmentCommand { adjustment_id: id };
     let actor_id = *ctx.user_id();
 
     let response = use_case
-        .execute(command, actor_id)
+        .execute(command, actor_id, &settings)
         .await
         .map_err(|e| AppError::from(e).into_response())?;
 
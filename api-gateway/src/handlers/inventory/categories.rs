@@ -7,6 +7,11 @@
 // - GET /api/v1/categories/{id}/children - Get child categories
 // - PUT /api/v1/categories/{id} - Update a category
 // - DELETE /api/v1/categories/{id} - Delete (deactivate) a category
+// - PUT /api/v1/categories/{id}/move - Move a category under another parent
+// - PUT /api/v1/categories/reorder - Set the order of sibling categories
+// - GET /api/v1/categories/{id}/barcode-prefix - Get the prefix for generated barcodes
+// - PUT /api/v1/categories/{id}/barcode-prefix - Set the prefix for generated barcodes
+// - POST /api/v1/categories/{id}/bulk-price-update - Adjust prices of the category's products
 
 use axum::{
     Json,
@@ -18,9 +23,13 @@ use serde::Deserialize;
 use uuid::Uuid;
 
 use inventory::{
-    CategoryResponse, CategoryTreeResponse, CreateCategoryCommand, CreateCategoryUseCase,
-    DeleteCategoryUseCase, GetCategoryUseCase, ListCategoriesUseCase, ListResponse,
-    UpdateCategoryCommand, UpdateCategoryUseCase,
+    BulkUpdatePricesCommand, BulkUpdatePricesResponse, BulkUpdatePricesUseCase,
+    CategoryBarcodePrefixResponse, CategoryResponse, CategoryTreeResponse,
+    ConfigureCategoryBarcodePrefixCommand, ConfigureCategoryBarcodePrefixUseCase,
+    CreateCategoryCommand, CreateCategoryUseCase, DeleteCategoryUseCase, GetCategoryUseCase,
+    ListCategoriesUseCase, ListResponse, MoveCategoryCommand, MoveCategoryUseCase,
+    ReorderCategoriesCommand, ReorderCategoriesUseCase, UpdateCategoryCommand,
+    UpdateCategoryUseCase,
 };
 
 use crate::error::AppError;
@@ -50,7 +59,8 @@ pub async fn create_category_handler(
 ) -> Result<(StatusCode, Json<CategoryResponse>), Response> {
     require_permission(&ctx, "categories:create")?;
 
-    let use_case = CreateCategoryUseCase::new(state.category_repo());
+    let use_case = CreateCategoryUseCase::new(state.category_repo())
+        .with_max_depth(state.max_category_depth());
 
     let response = use_case
         .execute(command)
@@ -136,7 +146,8 @@ pub async fn update_category_handler(
 ) -> Result<Json<CategoryResponse>, Response> {
     require_permission(&ctx, "categories:update")?;
 
-    let use_case = UpdateCategoryUseCase::new(state.category_repo());
+    let use_case = UpdateCategoryUseCase::new(state.category_repo())
+        .with_max_depth(state.max_category_depth());
 
     let response = use_case
         .execute(id, command)
@@ -146,6 +157,50 @@ pub async fn update_category_handler(
     Ok(Json(response))
 }
 
+/// Handler for PUT /api/v1/categories/{id}/move
+///
+/// Moves the category under `parent_id` (or to the root when null). The
+/// category's subtree moves with it; products keep their category.
+pub async fn move_category_handler(
+    State(state): State<AppState>,
+    CurrentUser(ctx): CurrentUser,
+    Path(id): Path<Uuid>,
+    JsonBody(command): JsonBody<MoveCategoryCommand>,
+) -> Result<Json<CategoryResponse>, Response> {
+    require_permission(&ctx, "categories:update")?;
+
+    let use_case =
+        MoveCategoryUseCase::new(state.category_repo()).with_max_depth(state.max_category_depth());
+
+    let response = use_case
+        .execute(id, command)
+        .await
+        .map_err(|e| AppError::from(e).into_response())?;
+
+    Ok(Json(response))
+}
+
+/// Handler for PUT /api/v1/categories/reorder
+///
+/// `category_ids` must list every child of `parent_id` (or every root
+/// category when null) exactly once, in the desired order.
+pub async fn reorder_categories_handler(
+    State(state): State<AppState>,
+    CurrentUser(ctx): CurrentUser,
+    JsonBody(command): JsonBody<ReorderCategoriesCommand>,
+) -> Result<Json<ListResponse<CategoryResponse>>, Response> {
+    require_permission(&ctx, "categories:update")?;
+
+    let use_case = ReorderCategoriesUseCase::new(state.category_repo());
+
+    let response = use_case
+        .execute(command)
+        .await
+        .map_err(|e| AppError::from(e).into_response())?;
+
+    Ok(Json(ListResponse::new(response)))
+}
+
 /// Handler for DELETE /api/v1/categories/{id}
 pub async fn delete_category_handler(
     State(state): State<AppState>,
@@ -163,3 +218,91 @@ pub async fn delete_category_handler(
 
     Ok(StatusCode::NO_CONTENT)
 }
+
+/// Handler for GET /api/v1/categories/{id}/barcode-prefix
+pub async fn get_category_barcode_prefix_handler(
+    State(state): State<AppState>,
+    CurrentUser(ctx): CurrentUser,
+    Path(id): Path<Uuid>,
+) -> Result<Json<CategoryBarcodePrefixResponse>, Response> {
+    require_permission(&ctx, "categories:read")?;
+
+    let use_case = ConfigureCategoryBarcodePrefixUseCase::new(
+        state.category_repo(),
+        state.barcode_sequence_repo(),
+    );
+
+    let response = use_case
+        .get(id)
+        .await
+        .map_err(|e| AppError::from(e).into_response())?;
+
+    Ok(Json(response))
+}
+
+/// Handler for PUT /api/v1/categories/{id}/barcode-prefix
+///
+/// Products and variants created in the category without a barcode get an
+/// EAN-13 built from this prefix. A null prefix turns generation off.
+pub async fn configure_category_barcode_prefix_handler(
+    State(state): State<AppState>,
+    CurrentUser(ctx): CurrentUser,
+    Path(id): Path<Uuid>,
+    JsonBody(mut command): JsonBody<ConfigureCategoryBarcodePrefixCommand>,
+) -> Result<Json<CategoryBarcodePrefixResponse>, Response> {
+    require_permission(&ctx, "categories:update")?;
+
+    command.category_id = id;
+
+    let use_case = ConfigureCategoryBarcodePrefixUseCase::new(
+        state.category_repo(),
+        state.barcode_sequence_repo(),
+    );
+
+    let response = use_case
+        .execute(command)
+        .await
+        .map_err(|e| AppError::from(e).into_response())?;
+
+    Ok(Json(response))
+}
+
+/// Handler for POST /api/v1/categories/{id}/bulk-price-update
+///
+/// Applies a percentage or fixed-amount change to the base and/or cost price
+/// of every product in the category (and its subcategories when
+/// `include_subcategories` is set). Products whose new price would be
+/// negative are left unchanged and listed in `skipped_skus`.
+///
+/// ```json
+/// {
+///   "adjustment_type": "percentage",
+///   "value": 10,
+///   "include_subcategories": true,
+///   "apply_to_base_price": true,
+///   "apply_to_cost_price": false
+/// }
+/// ```
+pub async fn bulk_update_prices_handler(
+    State(state): State<AppState>,
+    CurrentUser(ctx): CurrentUser,
+    Path(id): Path<Uuid>,
+    JsonBody(mut command): JsonBody<BulkUpdatePricesCommand>,
+) -> Result<Json<BulkUpdatePricesResponse>, Response> {
+    require_permission(&ctx, "products:update")?;
+
+    command.category_id = id;
+
+    let use_case = BulkUpdatePricesUseCase::new(
+        state.product_repo(),
+        state.category_repo(),
+        state.price_update_repo(),
+    );
+
+    let response = use_case
+        .execute(command, *ctx.user_id())
+        .await
+        .map_err(|e| AppError::from(e).into_response())?;
+
+    Ok(Json(response))
+}
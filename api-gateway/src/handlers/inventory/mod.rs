@@ -7,13 +7,17 @@
 // - stock: Inventory stock operations
 // - reservations: Inventory reservation operations
 // - adjustments: Stock adjustment operations
+// - periods: Closing and reopening inventory periods
 // - reports: Stock history and inventory reports
+// - reprices: Batch reprices from vendor cost changes and price history
 
 pub mod adjustments;
 pub mod categories;
+pub mod periods;
 pub mod products;
 pub mod recipes;
 pub mod reports;
+pub mod reprices;
 pub mod reservations;
 pub mod stock;
 pub mod transfers;
@@ -22,9 +26,11 @@ pub mod variants;
 // Re-export all handlers for easy access
 pub use adjustments::*;
 pub use categories::*;
+pub use periods::*;
 pub use products::*;
 pub use recipes::*;
 pub use reports::*;
+pub use reprices::*;
 pub use reservations::*;
 pub use stock::*;
 pub use transfers::*;
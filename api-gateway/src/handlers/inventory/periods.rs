@@ -0,0 +1,134 @@
+// Inventory period HTTP handlers for the API Gateway
+//
+// These handlers implement the REST endpoints for closing inventory periods:
+// - GET /api/inventory/periods - List the periods of a store
+// - POST /api/inventory/periods/close - Close a period of a store
+// - PUT /api/inventory/periods/{id}/reopen - Reopen a closed period
+
+use axum::{
+    Json,
+    extract::{Path, Query, State},
+    response::{IntoResponse, Response},
+};
+use serde::Deserialize;
+use uuid::Uuid;
+
+use inventory::{
+    ClosePeriodCommand, ClosePeriodUseCase, InventoryError, InventoryPeriodId,
+    InventoryPeriodRepository, InventoryPeriodResponse, ListPeriodsUseCase, ReopenPeriodUseCase,
+};
+
+use crate::error::AppError;
+use crate::extractors::{CurrentUser, JsonBody};
+use crate::middleware::org_scope::verify_store_in_org;
+use crate::middleware::permission::require_permission;
+use crate::state::AppState;
+
+/// Query parameters for listing periods
+#[derive(Debug, Deserialize)]
+pub struct ListPeriodsQueryParams {
+    /// Store whose periods are listed
+    pub store_id: Uuid,
+}
+
+/// Handler for GET /api/inventory/periods
+///
+/// # Query Parameters
+///
+/// - `store_id`: Store whose periods are listed, latest first
+///
+/// # Response
+///
+/// - 200 OK: The store's periods
+/// - 401 Unauthorized: Missing or invalid token
+/// - 403 Forbidden: User lacks inventory:read permission
+pub async fn list_periods_handler(
+    State(state): State<AppState>,
+    CurrentUser(ctx): CurrentUser,
+    Query(params): Query<ListPeriodsQueryParams>,
+) -> Result<Json<Vec<InventoryPeriodResponse>>, Response> {
+    require_permission(&ctx, "inventory:read")?;
+    verify_store_in_org(state.pool(), &ctx, params.store_id).await?;
+
+    let use_case = ListPeriodsUseCase::new(state.inventory_period_repo());
+
+    let response = use_case
+        .execute(params.store_id)
+        .await
+        .map_err(|e| AppError::from(e).into_response())?;
+
+    Ok(Json(response))
+}
+
+/// Handler for POST /api/inventory/periods/close
+///
+/// Closes a store's inventory period. Stock updates, adjustment applies,
+/// goods receipts and sales dated in it (by their document date) are then
+/// rejected unless the user has inventory:reopen_period. Closing the range
+/// of a reopened period closes that period again.
+///
+/// # Request Body
+///
+/// ```json
+/// { "store_id": "...", "period_start": "2026-09-01", "period_end": "2026-09-30" }
+/// ```
+///
+/// # Response
+///
+/// - 200 OK: The closed period
+/// - 400 Bad Request: The range is inverted or hasn't ended yet
+/// - 401 Unauthorized: Missing or invalid token
+/// - 403 Forbidden: User lacks inventory:close_period permission
+/// - 409 Conflict: The range overlaps another period, or the period is
+///   already closed
+pub async fn close_period_handler(
+    State(state): State<AppState>,
+    CurrentUser(ctx): CurrentUser,
+    JsonBody(command): JsonBody<ClosePeriodCommand>,
+) -> Result<Json<InventoryPeriodResponse>, Response> {
+    require_permission(&ctx, "inventory:close_period")?;
+    verify_store_in_org(state.pool(), &ctx, command.store_id).await?;
+
+    let use_case = ClosePeriodUseCase::new(state.inventory_period_repo());
+
+    let response = use_case
+        .execute(command, *ctx.user_id())
+        .await
+        .map_err(|e| AppError::from(e).into_response())?;
+
+    Ok(Json(response))
+}
+
+/// Handler for PUT /api/inventory/periods/{id}/reopen
+///
+/// # Response
+///
+/// - 200 OK: The reopened period
+/// - 401 Unauthorized: Missing or invalid token
+/// - 403 Forbidden: User lacks inventory:reopen_period permission
+/// - 404 Not Found: Period doesn't exist
+/// - 409 Conflict: The period isn't closed
+pub async fn reopen_period_handler(
+    State(state): State<AppState>,
+    CurrentUser(ctx): CurrentUser,
+    Path(id): Path<Uuid>,
+) -> Result<Json<InventoryPeriodResponse>, Response> {
+    require_permission(&ctx, "inventory:reopen_period")?;
+
+    let period = state
+        .inventory_period_repo()
+        .find_by_id(InventoryPeriodId::from_uuid(id))
+        .await
+        .map_err(|e| AppError::from(e).into_response())?
+        .ok_or_else(|| AppError::from(InventoryError::PeriodNotFound(id)).into_response())?;
+    verify_store_in_org(state.pool(), &ctx, period.store_id().into_uuid()).await?;
+
+    let use_case = ReopenPeriodUseCase::new(state.inventory_period_repo());
+
+    let response = use_case
+        .execute(id, *ctx.user_id())
+        .await
+        .map_err(|e| AppError::from(e).into_response())?;
+
+    Ok(Json(response))
+}
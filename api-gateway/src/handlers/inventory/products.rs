@@ -2,10 +2,15 @@
 //
 // These handlers implement the REST endpoints for product management:
 // - POST /api/products - Create a new product
+// - POST /api/products/import - Create or update products from a catalog file
+// - GET /api/products/export - Export products as CSV in chosen columns
 // - GET /api/products - List products with pagination
+// - GET /api/products/search - Search products by relevance
 // - GET /api/products/{id} - Get product details
 // - PUT /api/products/{id} - Update product
 // - DELETE /api/products/{id} - Soft delete product
+// - PUT /api/products/{id}/vendors - Link a vendor to a product
+// - PUT /api/products/{id}/unit-conversions - Define the product's unit conversions
 
 use axum::{
     Json,
@@ -17,13 +22,19 @@ use serde::Deserialize;
 use uuid::Uuid;
 
 use inventory::{
-    CreateProductCommand, CreateProductUseCase, DeleteProductUseCase, GetProductUseCase,
-    ListProductsQuery, ListProductsUseCase, PaginatedResponse, ProductDetailResponse,
-    ProductResponse, UpdateProductCommand, UpdateProductUseCase,
+    CreateProductCommand, CreateProductUseCase, DeleteProductUseCase, ExportProductsQuery,
+    ExportProductsUseCase, GetProductUseCase, ImportProductsCommand, ImportProductsResponse,
+    ImportProductsUseCase, ListProductsQuery, ListProductsUseCase, PaginatedResponse,
+    ProductDetailResponse, ProductExportColumn, ProductResponse, ProductSearchResultResponse,
+    ProductVendorResponse, SearchProductsQuery, SearchProductsUseCase,
+    SetProductUnitConversionsCommand, SetProductUnitConversionsUseCase, SetProductVendorCommand,
+    SetProductVendorUseCase, UnitConversionResponse, UpdateProductCommand, UpdateProductUseCase,
 };
 
 use crate::error::AppError;
+use crate::export::{ColumnKind, CsvExport, MAX_EXPORT_ROWS};
 use crate::extractors::{CurrentUser, JsonBody};
+use crate::middleware::org_scope::verify_store_in_org;
 use crate::middleware::permission::require_permission;
 use crate::state::AppState;
 
@@ -40,6 +51,8 @@ pub struct ListProductsQueryParams {
     /// Number of items per page (default: 20, max: 100)
     #[serde(default = "default_page_size")]
     pub page_size: i64,
+    /// Keyset cursor from a previous page's `next_cursor` (overrides `page`)
+    pub after_cursor: Option<String>,
     /// Filter by category ID
     pub category_id: Option<Uuid>,
     /// Filter by active status
@@ -64,10 +77,60 @@ impl From<ListProductsQueryParams> for ListProductsQuery {
             search: params.search,
             page: params.page,
             page_size: params.page_size,
+            after_cursor: params.after_cursor,
         }
     }
 }
 
+/// Query parameters for searching products (HTTP API layer)
+#[derive(Debug, Deserialize)]
+pub struct SearchProductsQueryParams {
+    /// Search text
+    pub q: String,
+    /// Filter by category ID
+    pub category_id: Option<Uuid>,
+    /// Only active products (default: true)
+    #[serde(default = "default_active_only")]
+    pub active_only: bool,
+    /// Page number (1-indexed, default: 1)
+    #[serde(default = "default_page")]
+    pub page: i64,
+    /// Number of items per page (default: 20, max: 100)
+    #[serde(default = "default_page_size")]
+    pub page_size: i64,
+}
+
+fn default_active_only() -> bool {
+    true
+}
+
+impl From<SearchProductsQueryParams> for SearchProductsQuery {
+    fn from(params: SearchProductsQueryParams) -> Self {
+        SearchProductsQuery {
+            q: params.q,
+            category_id: params.category_id,
+            active_only: params.active_only,
+            page: params.page,
+            page_size: params.page_size,
+        }
+    }
+}
+
+/// Query parameters for exporting products (HTTP API layer)
+#[derive(Debug, Deserialize)]
+pub struct ExportProductsQueryParams {
+    /// Filter by category ID
+    pub category_id: Option<Uuid>,
+    /// Filter by active status
+    pub is_active: Option<bool>,
+    /// Search term for name/description
+    pub search: Option<String>,
+    /// Comma-separated columns in output order, e.g. `sku,name,base_price`
+    pub columns: Option<String>,
+    /// Store whose stock fills the `stock` and `available_stock` columns
+    pub store_id: Option<Uuid>,
+}
+
 // =============================================================================
 // Create Product Handler
 // =============================================================================
@@ -84,7 +147,7 @@ impl From<ListProductsQueryParams> for ListProductsQuery {
 ///   "unit_of_measure": "unit",
 ///   "base_price": 99.99,
 ///   "cost_price": 50.00,
-///   "barcode": "1234567890123",
+///   "barcode": "1234567890128",
 ///   "category_id": "uuid",
 ///   "is_trackable": true
 /// }
@@ -107,6 +170,7 @@ pub async fn create_product_handler(
     let use_case = CreateProductUseCase::new(
         state.product_repo(),
         state.category_repo(),
+        state.barcode_sequence_repo(),
         state.audit_repo(),
     );
 
@@ -119,6 +183,136 @@ pub async fn create_product_handler(
     Ok((StatusCode::CREATED, Json(response)))
 }
 
+// =============================================================================
+// Import Products Handler
+// =============================================================================
+
+/// Handler for POST /api/products/import
+///
+/// Creates or updates products from parsed catalog rows. Rows are matched to
+/// existing products by SKU; categories missing from a row's `category_path`
+/// ("Beverages/Sodas") are created. Invalid rows are reported per row and
+/// skipped; the rest are saved together.
+///
+/// # Request Body
+///
+/// ```json
+/// {
+///   "rows": [
+///     {
+///       "name": "Cola 355ml",
+///       "sku": "COLA-355",
+///       "barcode": "1234567890128",
+///       "category_path": "Beverages/Sodas",
+///       "base_price": 1.50,
+///       "cost_price": 0.80,
+///       "unit_of_measure": "unit"
+///     }
+///   ]
+/// }
+/// ```
+///
+/// # Response
+///
+/// - 200 OK: Per-row results with created/updated/failed totals
+/// - 401 Unauthorized: Missing or invalid token
+/// - 403 Forbidden: User lacks products:create permission
+pub async fn import_products_handler(
+    State(state): State<AppState>,
+    CurrentUser(ctx): CurrentUser,
+    JsonBody(command): JsonBody<ImportProductsCommand>,
+) -> Result<Json<ImportProductsResponse>, Response> {
+    require_permission(&ctx, "products:create")?;
+    require_permission(&ctx, "products:update")?;
+
+    let use_case = ImportProductsUseCase::new(
+        state.product_repo(),
+        state.category_repo(),
+        state.product_import_repo(),
+    );
+
+    let response = use_case
+        .execute(command, *ctx.user_id())
+        .await
+        .map_err(|e| AppError::from(e).into_response())?;
+
+    Ok(Json(response))
+}
+
+// =============================================================================
+// Export Products Handler
+// =============================================================================
+
+/// Handler for GET /api/products/export
+///
+/// Exports the products matching the filters as CSV. Without `columns` the
+/// file has the columns a catalog import reads. Every export is audited.
+///
+/// # Response
+///
+/// - 200 OK: CSV file
+/// - 400 Bad Request: Unknown column, or stock columns without `store_id`
+/// - 401 Unauthorized: Missing or invalid token
+/// - 403 Forbidden: User lacks products:export permission
+pub async fn export_products_handler(
+    State(state): State<AppState>,
+    CurrentUser(ctx): CurrentUser,
+    Query(params): Query<ExportProductsQueryParams>,
+) -> Result<Response, Response> {
+    require_permission(&ctx, "products:export")?;
+    if let Some(store_id) = params.store_id {
+        verify_store_in_org(state.pool(), &ctx, store_id).await?;
+    }
+
+    let columns = params
+        .columns
+        .as_deref()
+        .unwrap_or_default()
+        .split(',')
+        .filter(|c| !c.trim().is_empty())
+        .map(str::parse::<ProductExportColumn>)
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|e| AppError::from(e).into_response())?;
+
+    let query = ExportProductsQuery {
+        filter: ListProductsQuery {
+            category_id: params.category_id,
+            is_active: params.is_active,
+            search: params.search,
+            ..Default::default()
+        },
+        columns,
+        store_id: params.store_id,
+        limit: Some(MAX_EXPORT_ROWS),
+    };
+    let header = if query.columns.is_empty() {
+        ProductExportColumn::DEFAULT.to_vec()
+    } else {
+        query.columns.clone()
+    };
+    let mut export = CsvExport::new(
+        "products",
+        header
+            .iter()
+            .map(|c| (c.as_str(), ColumnKind::Plain))
+            .collect(),
+    );
+
+    let use_case = ExportProductsUseCase::new(
+        state.product_repo(),
+        state.category_repo(),
+        state.stock_repo(),
+    );
+    use_case
+        .execute(query, |row| export.push_row(row.into_values()))
+        .await
+        .map_err(|e| AppError::from(e).into_response())?;
+
+    export
+        .into_response(state.audit_repo(), &ctx, params.store_id)
+        .await
+}
+
 // =============================================================================
 // List Products Handler
 // =============================================================================
@@ -155,6 +349,43 @@ pub async fn list_products_handler(
     Ok(Json(response))
 }
 
+// =============================================================================
+// Search Products Handler
+// =============================================================================
+
+/// Handler for GET /api/products/search
+///
+/// Searches products by name, SKU, barcode and description, best matches
+/// first. Misspelled names and partial SKUs still match.
+///
+/// # Query Parameters
+///
+/// - `q`: Search text
+/// - `category_id` (optional): Filter by category
+/// - `active_only` (optional): Only active products (default: true)
+/// - `page` (optional): Page number (1-based, default: 1)
+/// - `page_size` (optional): Items per page (default: 20, max: 100)
+///
+/// # Response
+///
+/// - 200 OK: Paginated products, each with its `relevance_score`
+/// - 400 Bad Request: Empty search text
+/// - 401 Unauthorized: Missing or invalid token
+pub async fn search_products_handler(
+    State(state): State<AppState>,
+    CurrentUser(_ctx): CurrentUser,
+    Query(params): Query<SearchProductsQueryParams>,
+) -> Result<Json<PaginatedResponse<ProductSearchResultResponse>>, Response> {
+    let use_case = SearchProductsUseCase::new(state.product_repo());
+
+    let response = use_case
+        .execute(params.into())
+        .await
+        .map_err(|e| AppError::from(e).into_response())?;
+
+    Ok(Json(response))
+}
+
 // =============================================================================
 // Get Product Handler
 // =============================================================================
@@ -177,7 +408,7 @@ pub async fn get_product_handler(
     CurrentUser(_ctx): CurrentUser,
     Path(id): Path<Uuid>,
 ) -> Result<Json<ProductDetailResponse>, Response> {
-    let use_case = GetProductUseCase::new(state.product_repo());
+    let use_case = GetProductUseCase::new(state.product_repo(), state.category_repo());
 
     let response = use_case
         .execute(id)
@@ -207,6 +438,8 @@ pub async fn get_product_handler(
 /// {
 ///   "name": "New Name",
 ///   "base_price": 149.99,
+///   "markup_type": "margin",
+///   "markup_percent": 40,
 ///   "is_active": true
 /// }
 /// ```
@@ -277,3 +510,109 @@ pub async fn delete_product_handler(
 
     Ok(StatusCode::NO_CONTENT)
 }
+
+// =============================================================================
+// Set Product Vendor Handler
+// =============================================================================
+
+/// Handler for PUT /api/products/{id}/vendors
+///
+/// Adds or updates a vendor that supplies the product. A product can have
+/// several vendors but exactly one preferred: the first vendor linked becomes
+/// preferred, and marking another one as preferred unmarks the previous one.
+///
+/// # Path Parameters
+///
+/// - `id`: Product UUID
+///
+/// # Request Body
+///
+/// ```json
+/// {
+///   "vendor_id": "uuid",
+///   "vendor_sku": "ACME-4411",       // Optional
+///   "last_purchase_price": 12.50,    // Optional
+///   "is_preferred": true             // Optional, defaults to false
+/// }
+/// ```
+///
+/// # Response
+///
+/// - 200 OK: Vendor linked to the product
+/// - 400 Bad Request: Negative price, SKU too long, unknown vendor, or the
+///   preferred vendor would be unmarked
+/// - 401 Unauthorized: Missing or invalid token
+/// - 403 Forbidden: User lacks products:update permission
+/// - 404 Not Found: Product doesn't exist
+pub async fn set_product_vendor_handler(
+    State(state): State<AppState>,
+    CurrentUser(ctx): CurrentUser,
+    Path(id): Path<Uuid>,
+    JsonBody(mut command): JsonBody<SetProductVendorCommand>,
+) -> Result<Json<ProductVendorResponse>, Response> {
+    require_permission(&ctx, "products:update")?;
+
+    command.product_id = id;
+
+    let use_case = SetProductVendorUseCase::new(state.product_repo());
+
+    let response = use_case
+        .execute(command)
+        .await
+        .map_err(|e| AppError::from(e).into_response())?;
+
+    Ok(Json(response))
+}
+
+// =============================================================================
+// Set Product Unit Conversions Handler
+// =============================================================================
+
+/// Handler for PUT /api/products/{id}/unit-conversions
+///
+/// Replaces the product's unit conversions, used where its packaging varies:
+/// goods received in cases are converted to the product's stocking unit with
+/// them. Units with standard factors (kg and g, liter and ml) convert on
+/// their own and can't be redefined.
+///
+/// # Path Parameters
+///
+/// - `id`: Product UUID
+///
+/// # Request Body
+///
+/// ```json
+/// {
+///   "conversions": [
+///     { "from_unit": "case", "to_unit": "unit", "factor": 24 }
+///   ]
+/// }
+/// ```
+///
+/// # Response
+///
+/// - 200 OK: Conversions now defined for the product
+/// - 400 Bad Request: Unknown unit, non-positive factor, repeated pair, or
+///   units that already convert with standard factors
+/// - 401 Unauthorized: Missing or invalid token
+/// - 403 Forbidden: User lacks products:update permission
+/// - 404 Not Found: Product doesn't exist
+pub async fn set_product_unit_conversions_handler(
+    State(state): State<AppState>,
+    CurrentUser(ctx): CurrentUser,
+    Path(id): Path<Uuid>,
+    JsonBody(mut command): JsonBody<SetProductUnitConversionsCommand>,
+) -> Result<Json<Vec<UnitConversionResponse>>, Response> {
+    require_permission(&ctx, "products:update")?;
+
+    command.product_id = id;
+
+    let use_case = SetProductUnitConversionsUseCase::new(state.product_repo());
+
+    let response = use_case
+        .execute(command)
+        .await
+        .map_err(|e| AppError::from(e).into_response())?;
+
+    Ok(Json(response))
+}
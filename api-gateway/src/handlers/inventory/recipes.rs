@@ -7,6 +7,7 @@
 // - GET /api/products/{product_id}/recipe - Get active recipe for product
 // - PUT /api/recipes/{id} - Update recipe
 // - POST /api/recipes/{recipe_id}/calculate-cost - Calculate recipe cost
+// - POST /api/recipes/refresh-costs - Recompute all composite costs
 
 use axum::{
     Json,
@@ -20,7 +21,8 @@ use uuid::Uuid;
 use inventory::{
     CalculateRecipeCostUseCase, CreateRecipeCommand, CreateRecipeUseCase, GetProductRecipeUseCase,
     GetRecipeUseCase, ListRecipesQuery, ListRecipesUseCase, PaginatedResponse, RecipeCostResult,
-    RecipeDetailResponse, RecipeResponse, UpdateRecipeCommand, UpdateRecipeUseCase,
+    RecipeDetailResponse, RecipeResponse, RefreshCompositeCostsResponse,
+    RefreshCompositeCostsUseCase, UpdateRecipeCommand, UpdateRecipeUseCase,
 };
 
 use crate::error::AppError;
@@ -43,6 +45,8 @@ pub struct ListRecipesQueryParams {
     /// Number of items per page (default: 20, max: 100)
     #[serde(default = "default_page_size")]
     pub page_size: i64,
+    /// Keyset cursor from a previous page's `next_cursor` (overrides `page`)
+    pub after_cursor: Option<String>,
     /// Filter by active status
     pub is_active: Option<bool>,
     /// Search term for name/description
@@ -56,6 +60,7 @@ impl From<ListRecipesQueryParams> for ListRecipesQuery {
             search: params.search,
             page: params.page,
             page_size: params.page_size,
+            after_cursor: params.after_cursor,
         }
     }
 }
@@ -67,6 +72,9 @@ pub struct RecipeCostResponse {
     pub total_ingredient_cost: rust_decimal::Decimal,
     pub cost_per_unit: rust_decimal::Decimal,
     pub yield_quantity: rust_decimal::Decimal,
+    pub yield_unit: String,
+    pub base_unit: String,
+    pub cost_per_base_unit: rust_decimal::Decimal,
     pub ingredient_count: usize,
     pub ingredients_with_cost: usize,
 }
@@ -78,6 +86,9 @@ impl From<RecipeCostResult> for RecipeCostResponse {
             total_ingredient_cost: result.total_ingredient_cost,
             cost_per_unit: result.cost_per_unit,
             yield_quantity: result.yield_quantity,
+            yield_unit: result.yield_unit.to_string(),
+            base_unit: result.base_unit.to_string(),
+            cost_per_base_unit: result.cost_per_base_unit,
             ingredient_count: result.ingredient_count,
             ingredients_with_cost: result.ingredients_with_cost,
         }
@@ -99,6 +110,7 @@ impl From<RecipeCostResult> for RecipeCostResponse {
 ///   "product_id": "uuid",
 ///   "name": "Recipe Name",
 ///   "yield_quantity": 10,
+///   "yield_unit": "unit",
 ///   "ingredients": [
 ///     {
 ///       "ingredient_product_id": "uuid",
@@ -298,6 +310,7 @@ pub async fn update_recipe_handler(
 /// # Response
 ///
 /// - 200 OK: Recipe cost breakdown
+/// - 400 Bad Request: An ingredient's unit can't be converted to its product's unit
 /// - 401 Unauthorized: Missing or invalid token
 /// - 404 Not Found: Recipe doesn't exist
 pub async fn calculate_recipe_cost_handler(
@@ -305,7 +318,7 @@ pub async fn calculate_recipe_cost_handler(
     CurrentUser(_ctx): CurrentUser,
     Path(recipe_id): Path<Uuid>,
 ) -> Result<Json<RecipeCostResponse>, Response> {
-    let use_case = CalculateRecipeCostUseCase::new(state.recipe_repo());
+    let use_case = CalculateRecipeCostUseCase::new(state.recipe_repo(), state.product_repo());
 
     let result = use_case
         .execute(recipe_id)
@@ -314,3 +327,39 @@ pub async fn calculate_recipe_cost_handler(
 
     Ok(Json(RecipeCostResponse::from(result)))
 }
+
+// =============================================================================
+// Refresh Composite Costs Handler
+// =============================================================================
+
+/// Request body for refreshing composite costs
+#[derive(Debug, Default, Deserialize)]
+pub struct RefreshCompositeCostsRequest {
+    /// Write recomputed costs to each changed composite's cost_price
+    #[serde(default)]
+    pub auto_adjust: bool,
+}
+
+/// Handler for POST /api/recipes/refresh-costs
+///
+/// Recomputes the cost of every active recipe-costed composite from current
+/// ingredient weighted-average costs, in dependency order. Reports changed
+/// costs, margin erosion and ingredient cycles.
+///
+/// Requires `recipes:update`.
+pub async fn refresh_composite_costs_handler(
+    State(state): State<AppState>,
+    CurrentUser(ctx): CurrentUser,
+    JsonBody(request): JsonBody<RefreshCompositeCostsRequest>,
+) -> Result<Json<RefreshCompositeCostsResponse>, Response> {
+    require_permission(&ctx, "recipes:update")?;
+
+    let use_case = RefreshCompositeCostsUseCase::new(state.recipe_repo());
+
+    let response = use_case
+        .execute(request.auto_adjust)
+        .await
+        .map_err(|e| AppError::from(e).into_response())?;
+
+    Ok(Json(response))
+}
@@ -4,27 +4,45 @@
 // - GET /api/inventory/stock/{stock_id}/history - Get stock history (movements)
 // - GET /api/products/{product_id}/stock-history - Get product stock history across stores
 // - GET /api/reports/inventory/valuation - Get inventory valuation report
+// - POST /api/reports/inventory/valuation/snapshots - Record a period-end valuation snapshot
+// - GET /api/reports/inventory/valuation/snapshots/{store_id}/{period_end} - Get a valuation snapshot
+// - GET /api/reports/inventory/in-transit - Get stock in transit between stores
+// - GET /api/reports/inventory/in-transit/transfers - Get in-transit transfers by destination
+// - GET /api/reports/inventory/category-rollup - Get stock value rolled up by category
 // - GET /api/reports/inventory/low-stock - Get low stock report
+// - GET /api/reports/inventory/expiring - Get lots expiring within a window
+// - GET /api/reports/inventory/aging - Get on-hand stock bucketed by age
 // - GET /api/reports/inventory/movements - Get movements report
+// - GET /api/reports/inventory/reservation-conversion - Get reservation hold-to-sale conversion
 
 use axum::{
     Json,
     extract::{Path, Query, State},
+    http::StatusCode,
     response::{IntoResponse, Response},
 };
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, NaiveDate, Utc};
 use serde::Deserialize;
 use uuid::Uuid;
 
+use identity::StoreRepository;
 use inventory::{
-    GetLowStockReportUseCase, GetMovementsReportUseCase, GetStockHistoryUseCase,
-    GetValuationReportUseCase, LowStockReportQuery, LowStockReportResponse, MovementResponse,
-    MovementsReportQuery, PaginatedResponse, StockHistoryQuery, StockHistoryResponse,
-    ValuationReportQuery, ValuationReportResponse,
+    CategoryRollupQuery, CategoryRollupReportResponse, CreateValuationSnapshotCommand,
+    CreateValuationSnapshotUseCase, DEFAULT_EXPIRY_WINDOW_DAYS, DEFAULT_TRANSIT_OVERDUE_DAYS,
+    ExpiringStockReportQuery, ExpiringStockReportResponse, GetExpiringStockReportUseCase,
+    GetInTransitReportUseCase, GetInTransitStockUseCase, GetInventoryAgingReportUseCase,
+    GetInventoryCategoryRollupUseCase, GetLowStockReportUseCase, GetMovementsReportUseCase,
+    GetReservationConversionReportUseCase, GetStockHistoryUseCase, GetValuationReportUseCase,
+    GetValuationSnapshotUseCase, InTransitReportQuery, InTransitReportResponse,
+    InTransitStockResponse, InventoryAgingReportQuery, InventoryAgingReportResponse,
+    LowStockReportQuery, LowStockReportResponse, MovementResponse, MovementsReportQuery,
+    PaginatedResponse, ReservationConversionQuery, ReservationConversionReportResponse,
+    StockHistoryQuery, StockHistoryResponse, ValuationReportQuery, ValuationReportResponse,
+    ValuationSnapshotResponse,
 };
 
 use crate::error::AppError;
-use crate::extractors::CurrentUser;
+use crate::extractors::{CurrentUser, JsonBody};
 use crate::middleware::org_scope::verify_store_in_org;
 use crate::middleware::permission::require_permission;
 use crate::state::AppState;
@@ -59,6 +77,38 @@ pub struct ValuationReportQueryParams {
     pub currency: Option<String>,
 }
 
+/// Query parameters for in-transit stock report (HTTP API layer)
+#[derive(Debug, Deserialize)]
+pub struct InTransitStockQueryParams {
+    /// Only transfers from or to this store
+    pub store_id: Option<Uuid>,
+}
+
+/// Query parameters for in-transit transfers report (HTTP API layer)
+#[derive(Debug, Deserialize)]
+pub struct InTransitReportQueryParams {
+    /// Only transfers from or to this store
+    pub store_id: Option<Uuid>,
+    /// Days in transit after which a transfer is flagged overdue (default: 7)
+    #[serde(default = "default_transit_overdue_days")]
+    pub overdue_after_days: i64,
+}
+
+fn default_transit_overdue_days() -> i64 {
+    DEFAULT_TRANSIT_OVERDUE_DAYS
+}
+
+/// Query parameters for category rollup report (HTTP API layer)
+#[derive(Debug, Deserialize)]
+pub struct CategoryRollupQueryParams {
+    /// Filter by store ID
+    pub store_id: Option<Uuid>,
+    /// Drill down into one category's subtree
+    pub category_id: Option<Uuid>,
+    /// Currency for the report (defaults to HNL)
+    pub currency: Option<String>,
+}
+
 /// Query parameters for low stock report (HTTP API layer)
 #[derive(Debug, Deserialize)]
 pub struct LowStockReportQueryParams {
@@ -73,6 +123,20 @@ fn default_include_zero() -> bool {
     true
 }
 
+/// Query parameters for expiring stock report (HTTP API layer)
+#[derive(Debug, Deserialize)]
+pub struct ExpiringStockReportQueryParams {
+    /// Filter by store ID
+    pub store_id: Option<Uuid>,
+    /// Days ahead to look for expiring lots (default: 30)
+    #[serde(default = "default_expiry_window_days")]
+    pub days: i64,
+}
+
+fn default_expiry_window_days() -> i64 {
+    DEFAULT_EXPIRY_WINDOW_DAYS
+}
+
 /// Query parameters for movements report (HTTP API layer)
 #[derive(Debug, Deserialize)]
 pub struct MovementsReportQueryParams {
@@ -94,6 +158,20 @@ pub struct MovementsReportQueryParams {
     pub page_size: i64,
 }
 
+/// Query parameters for reservation conversion report (HTTP API layer)
+#[derive(Debug, Deserialize)]
+pub struct ReservationConversionQueryParams {
+    /// Store whose reservations are reported
+    pub store_id: Uuid,
+    /// Include reservations created from this date (inclusive)
+    pub from_date: DateTime<Utc>,
+    /// Include reservations created up to this date (inclusive)
+    pub to_date: DateTime<Utc>,
+    /// Also bucket the counts by creation day (default: false)
+    #[serde(default)]
+    pub group_by_day: bool,
+}
+
 // =============================================================================
 // Get Stock History Handler
 // =============================================================================
@@ -155,7 +233,8 @@ pub async fn get_stock_history_handler(
 
 /// Handler for GET /api/reports/inventory/valuation
 ///
-/// Generates an inventory valuation report with stock values.
+/// Generates an inventory valuation report with stock values, including
+/// stock in transit attributed to its owner store.
 ///
 /// # Query Parameters
 ///
@@ -181,6 +260,7 @@ pub async fn get_valuation_report_handler(
         state.stock_repo(),
         state.movement_repo(),
         state.product_repo(),
+        state.transfer_repo(),
     );
 
     let query = ValuationReportQuery {
@@ -188,6 +268,229 @@ pub async fn get_valuation_report_handler(
         currency: params.currency,
     };
 
+    let settings = state
+        .store_repo()
+        .find_all_settings()
+        .await
+        .map_err(|e| AppError::from(e).into_response())?;
+
+    let response = use_case
+        .execute(query, &settings)
+        .await
+        .map_err(|e| AppError::from(e).into_response())?;
+
+    Ok(Json(response))
+}
+
+// =============================================================================
+// Valuation Snapshot Handlers
+// =============================================================================
+
+/// Handler for POST /api/reports/inventory/valuation/snapshots
+///
+/// Freezes the valuation of a store's stock on hand at a period end.
+/// Recording the same store and period again returns the existing snapshot.
+/// Closing the period itself is done through /api/inventory/periods/close.
+///
+/// # Request Body
+///
+/// ```json
+/// { "store_id": "...", "period_end": "2026-09-30", "currency": "HNL" }
+/// ```
+///
+/// # Response
+///
+/// - 201 Created: Snapshot recorded
+/// - 200 OK: The period already had a snapshot, which is returned
+/// - 400 Bad Request: The period hasn't ended yet
+/// - 401 Unauthorized: Missing or invalid token
+/// - 403 Forbidden: User lacks inventory:close_period permission
+pub async fn create_valuation_snapshot_handler(
+    State(state): State<AppState>,
+    CurrentUser(ctx): CurrentUser,
+    JsonBody(command): JsonBody<CreateValuationSnapshotCommand>,
+) -> Result<(StatusCode, Json<ValuationSnapshotResponse>), Response> {
+    require_permission(&ctx, "inventory:close_period")?;
+    verify_store_in_org(state.pool(), &ctx, command.store_id).await?;
+
+    let use_case = CreateValuationSnapshotUseCase::new(
+        state.stock_repo(),
+        state.movement_repo(),
+        state.product_repo(),
+        state.valuation_snapshot_repo(),
+    );
+
+    let (response, created) = use_case
+        .execute(command, *ctx.user_id())
+        .await
+        .map_err(|e| AppError::from(e).into_response())?;
+
+    let status = if created {
+        StatusCode::CREATED
+    } else {
+        StatusCode::OK
+    };
+    Ok((status, Json(response)))
+}
+
+/// Handler for GET /api/reports/inventory/valuation/snapshots/{store_id}/{period_end}
+///
+/// # Response
+///
+/// - 200 OK: The snapshot with its valued stock lines
+/// - 401 Unauthorized: Missing or invalid token
+/// - 403 Forbidden: User lacks reports:inventory permission
+/// - 404 Not Found: No snapshot for the store and period end
+pub async fn get_valuation_snapshot_handler(
+    State(state): State<AppState>,
+    CurrentUser(ctx): CurrentUser,
+    Path((store_id, period_end)): Path<(Uuid, NaiveDate)>,
+) -> Result<Json<ValuationSnapshotResponse>, Response> {
+    require_permission(&ctx, "reports:inventory")?;
+    verify_store_in_org(state.pool(), &ctx, store_id).await?;
+
+    let use_case = GetValuationSnapshotUseCase::new(state.valuation_snapshot_repo());
+
+    let response = use_case
+        .execute(store_id, period_end)
+        .await
+        .map_err(|e| AppError::from(e).into_response())?;
+
+    Ok(Json(response))
+}
+
+// =============================================================================
+// Get In-Transit Stock Report Handler
+// =============================================================================
+
+/// Handler for GET /api/reports/inventory/in-transit
+///
+/// Lists stock shipped on transfers but not yet received, with per-store
+/// outbound/inbound quantities and the in-transit value each store owns.
+///
+/// # Query Parameters
+///
+/// - `store_id` (optional): Only transfers from or to this store
+///
+/// # Response
+///
+/// - 200 OK: In-transit lines and per-store totals
+/// - 401 Unauthorized: Missing or invalid token
+/// - 403 Forbidden: User lacks reports:inventory permission
+pub async fn get_in_transit_stock_handler(
+    State(state): State<AppState>,
+    CurrentUser(ctx): CurrentUser,
+    Query(params): Query<InTransitStockQueryParams>,
+) -> Result<Json<InTransitStockResponse>, Response> {
+    require_permission(&ctx, "reports:inventory")?;
+    if let Some(sid) = params.store_id {
+        verify_store_in_org(state.pool(), &ctx, sid).await?;
+    }
+
+    let settings = state
+        .store_repo()
+        .find_all_settings()
+        .await
+        .map_err(|e| AppError::from(e).into_response())?;
+
+    let use_case = GetInTransitStockUseCase::new(state.transfer_repo());
+
+    let response = use_case
+        .execute(params.store_id, &settings)
+        .await
+        .map_err(|e| AppError::from(e).into_response())?;
+
+    Ok(Json(response))
+}
+
+// =============================================================================
+// Get In-Transit Transfers Report Handler
+// =============================================================================
+
+/// Handler for GET /api/reports/inventory/in-transit/transfers
+///
+/// Lists shipped transfers not yet received, grouped by destination store,
+/// each valued at the cost of the goods when they shipped and with the days
+/// it has been in transit.
+///
+/// # Query Parameters
+///
+/// - `store_id` (optional): Only transfers from or to this store
+/// - `overdue_after_days` (optional): Days in transit before a transfer is
+///   flagged overdue (default: 7)
+///
+/// # Response
+///
+/// - 200 OK: In-transit transfers per destination with value totals
+/// - 400 Bad Request: Negative `overdue_after_days`
+/// - 401 Unauthorized: Missing or invalid token
+/// - 403 Forbidden: User lacks reports:inventory permission
+pub async fn get_in_transit_report_handler(
+    State(state): State<AppState>,
+    CurrentUser(ctx): CurrentUser,
+    Query(params): Query<InTransitReportQueryParams>,
+) -> Result<Json<InTransitReportResponse>, Response> {
+    require_permission(&ctx, "reports:inventory")?;
+    if let Some(sid) = params.store_id {
+        verify_store_in_org(state.pool(), &ctx, sid).await?;
+    }
+
+    let use_case = GetInTransitReportUseCase::new(state.transfer_repo());
+
+    let query = InTransitReportQuery {
+        store_id: params.store_id,
+        overdue_after_days: params.overdue_after_days,
+    };
+
+    let response = use_case
+        .execute(query)
+        .await
+        .map_err(|e| AppError::from(e).into_response())?;
+
+    Ok(Json(response))
+}
+
+// =============================================================================
+// Get Category Rollup Report Handler
+// =============================================================================
+
+/// Handler for GET /api/reports/inventory/category-rollup
+///
+/// Returns the category tree with on-hand quantity and valuation per node,
+/// both for products directly in the category and for the whole subtree.
+/// Values are omitted unless the user also has reports:inventory_valuation.
+///
+/// # Query Parameters
+///
+/// - `store_id` (optional): Filter by store
+/// - `category_id` (optional): Drill down into one category's subtree
+/// - `currency` (optional): Currency for the report (default: HNL)
+///
+/// # Response
+///
+/// - 200 OK: Category rollup tree
+/// - 401 Unauthorized: Missing or invalid token
+/// - 403 Forbidden: User lacks reports:inventory permission
+/// - 404 Not Found: Drill-down category does not exist
+pub async fn get_category_rollup_report_handler(
+    State(state): State<AppState>,
+    CurrentUser(ctx): CurrentUser,
+    Query(params): Query<CategoryRollupQueryParams>,
+) -> Result<Json<CategoryRollupReportResponse>, Response> {
+    require_permission(&ctx, "reports:inventory")?;
+    if let Some(sid) = params.store_id {
+        verify_store_in_org(state.pool(), &ctx, sid).await?;
+    }
+
+    let use_case = GetInventoryCategoryRollupUseCase::new(state.category_repo());
+
+    let query = CategoryRollupQuery {
+        store_id: params.store_id,
+        category_id: params.category_id,
+        currency: params.currency,
+        include_costs: ctx.is_super_admin() || ctx.has_permission("reports:inventory_valuation"),
+    };
+
     let response = use_case
         .execute(query)
         .await
@@ -239,6 +542,102 @@ pub async fn get_low_stock_report_handler(
     Ok(Json(response))
 }
 
+// =============================================================================
+// Get Expiring Stock Report Handler
+// =============================================================================
+
+/// Handler for GET /api/reports/inventory/expiring
+///
+/// Lists lots with units left that expire within the next `days` days,
+/// including lots already expired, earliest expiry first. Sales and other
+/// outgoing movements draw lots down earliest expiry first (FEFO).
+///
+/// # Query Parameters
+///
+/// - `store_id` (optional): Filter by store
+/// - `days` (optional): Days ahead to look (default: 30)
+///
+/// # Response
+///
+/// - 200 OK: Expiring lots with remaining quantity and days until expiry
+/// - 400 Bad Request: Negative `days`
+/// - 401 Unauthorized: Missing or invalid token
+/// - 403 Forbidden: User lacks reports:inventory permission
+pub async fn get_expiring_stock_report_handler(
+    State(state): State<AppState>,
+    CurrentUser(ctx): CurrentUser,
+    Query(params): Query<ExpiringStockReportQueryParams>,
+) -> Result<Json<ExpiringStockReportResponse>, Response> {
+    require_permission(&ctx, "reports:inventory")?;
+    if let Some(sid) = params.store_id {
+        verify_store_in_org(state.pool(), &ctx, sid).await?;
+    }
+
+    let use_case = GetExpiringStockReportUseCase::new(state.lot_repo(), state.product_repo());
+
+    let query = ExpiringStockReportQuery {
+        store_id: params.store_id,
+        days: params.days,
+    };
+
+    let response = use_case
+        .execute(query)
+        .await
+        .map_err(|e| AppError::from(e).into_response())?;
+
+    Ok(Json(response))
+}
+
+// =============================================================================
+// Get Inventory Aging Report Handler
+// =============================================================================
+
+/// Handler for GET /api/reports/inventory/aging
+///
+/// Buckets on-hand stock into 0-30, 31-60, 61-90 and 90+ days since receipt,
+/// matching the quantity on hand against the most recent receipts on the
+/// kardex (FIFO). Stock the kardex cannot account for is reported with
+/// unknown age. Stock with the most value over 90 days old comes first.
+///
+/// # Query Parameters
+///
+/// - `store_id` (optional): Filter by store
+/// - `currency` (optional): Currency for the report (default: HNL)
+///
+/// # Response
+///
+/// - 200 OK: Aging report with quantity and value per bucket
+/// - 401 Unauthorized: Missing or invalid token
+/// - 403 Forbidden: User lacks reports:inventory permission
+pub async fn get_inventory_aging_report_handler(
+    State(state): State<AppState>,
+    CurrentUser(ctx): CurrentUser,
+    Query(params): Query<ValuationReportQueryParams>,
+) -> Result<Json<InventoryAgingReportResponse>, Response> {
+    require_permission(&ctx, "reports:inventory")?;
+    if let Some(sid) = params.store_id {
+        verify_store_in_org(state.pool(), &ctx, sid).await?;
+    }
+
+    let use_case = GetInventoryAgingReportUseCase::new(
+        state.stock_repo(),
+        state.movement_repo(),
+        state.product_repo(),
+    );
+
+    let query = InventoryAgingReportQuery {
+        store_id: params.store_id,
+        currency: params.currency,
+    };
+
+    let response = use_case
+        .execute(query)
+        .await
+        .map_err(|e| AppError::from(e).into_response())?;
+
+    Ok(Json(response))
+}
+
 // =============================================================================
 // Get Movements Report Handler
 // =============================================================================
@@ -291,3 +690,51 @@ pub async fn get_movements_report_handler(
 
     Ok(Json(response))
 }
+
+// =============================================================================
+// Get Reservation Conversion Report Handler
+// =============================================================================
+
+/// Handler for GET /api/reports/inventory/reservation-conversion
+///
+/// Counts the reservations created in a period by outcome (confirmed,
+/// expired, cancelled, still pending) with the hold-to-sale conversion rate,
+/// broken down by reference type and optionally by day.
+///
+/// # Query Parameters
+///
+/// - `store_id`: Store to report on
+/// - `from_date`: Include reservations created from this date
+/// - `to_date`: Include reservations created up to this date
+/// - `group_by_day` (optional): Bucket the counts by creation day (default: false)
+///
+/// # Response
+///
+/// - 200 OK: Reservation conversion report
+/// - 400 Bad Request: from_date is after to_date
+/// - 401 Unauthorized: Missing or invalid token
+/// - 403 Forbidden: User lacks reports:inventory permission
+pub async fn get_reservation_conversion_report_handler(
+    State(state): State<AppState>,
+    CurrentUser(ctx): CurrentUser,
+    Query(params): Query<ReservationConversionQueryParams>,
+) -> Result<Json<ReservationConversionReportResponse>, Response> {
+    require_permission(&ctx, "reports:inventory")?;
+    verify_store_in_org(state.pool(), &ctx, params.store_id).await?;
+
+    let use_case = GetReservationConversionReportUseCase::new(state.reservation_repo());
+
+    let query = ReservationConversionQuery {
+        store_id: params.store_id,
+        from_date: params.from_date,
+        to_date: params.to_date,
+        group_by_day: params.group_by_day,
+    };
+
+    let response = use_case
+        .execute(query)
+        .await
+        .map_err(|e| AppError::from(e).into_response())?;
+
+    Ok(Json(response))
+}
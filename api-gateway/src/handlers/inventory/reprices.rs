@@ -0,0 +1,226 @@
+// =============================================================================
+// Reprice Handlers
+// =============================================================================
+//
+// These handlers implement the REST endpoints for batch reprices from vendor
+// cost changes:
+// - POST /api/v1/products/reprices - Preview new prices for a cost update
+// - GET /api/v1/products/reprices - List batches
+// - GET /api/v1/products/reprices/{id} - Get a batch with its lines
+// - PUT /api/v1/products/reprices/{id}/approve - Apply the new costs and prices
+// - PUT /api/v1/products/reprices/{id}/reject - Discard the preview
+// - GET /api/v1/products/{product_id}/price-history - Product price history
+
+use axum::{
+    Json,
+    extract::{Path, Query, State},
+    http::StatusCode,
+    response::{IntoResponse, Response},
+};
+use serde::Deserialize;
+use uuid::Uuid;
+
+use inventory::{
+    ApproveRepriceCommand, ApproveRepriceUseCase, GetPriceHistoryUseCase, GetRepriceBatchUseCase,
+    ListRepriceBatchesUseCase, PreviewRepriceCommand, PreviewRepriceUseCase, PriceChangeResponse,
+    RepriceBatchResponse,
+};
+use purchasing::GetVendorUseCase;
+
+use crate::error::AppError;
+use crate::extractors::{CurrentUser, JsonBody};
+use crate::middleware::permission::require_permission;
+use crate::state::AppState;
+
+/// Query parameters for listing reprice batches
+#[derive(Debug, Deserialize)]
+pub struct ListRepriceBatchesQueryParams {
+    /// Filter by status (pending, applied, rejected)
+    pub status: Option<String>,
+}
+
+// =============================================================================
+// Preview Reprice Handler
+// =============================================================================
+
+/// Handler for POST /api/v1/products/reprices
+///
+/// Previews the selling prices that follow a vendor cost update. Each
+/// product's price is recomputed with its markup rule; manually priced
+/// products are listed with `excluded: true` and keep their price. The
+/// preview is saved as a pending batch and nothing changes until approval.
+///
+/// # Request Body
+///
+/// ```json
+/// {
+///   "vendor_id": "uuid",
+///   "reason": "2026 price list",
+///   "items": [
+///     { "product_id": "uuid", "new_cost": 12.50 }
+///   ]
+/// }
+/// ```
+///
+/// # Response
+///
+/// - 201 Created: Pending batch with previewed lines
+/// - 400 Bad Request: No items, negative cost or duplicate product
+/// - 403 Forbidden: User lacks products:update permission
+/// - 404 Not Found: Vendor or product doesn't exist
+pub async fn preview_reprice_handler(
+    State(state): State<AppState>,
+    CurrentUser(ctx): CurrentUser,
+    JsonBody(command): JsonBody<PreviewRepriceCommand>,
+) -> Result<(StatusCode, Json<RepriceBatchResponse>), Response> {
+    require_permission(&ctx, "products:update")?;
+
+    if let Some(vendor_id) = command.vendor_id {
+        GetVendorUseCase::new(state.vendor_repo())
+            .execute(vendor_id)
+            .await
+            .map_err(|e| AppError::from(e).into_response())?;
+    }
+
+    let use_case = PreviewRepriceUseCase::new(state.product_repo(), state.reprice_batch_repo());
+
+    let response = use_case
+        .execute(command, *ctx.user_id())
+        .await
+        .map_err(|e| AppError::from(e).into_response())?;
+
+    Ok((StatusCode::CREATED, Json(response)))
+}
+
+// =============================================================================
+// List / Get Reprice Handlers
+// =============================================================================
+
+/// Handler for GET /api/v1/products/reprices
+///
+/// Lists batch reprices, newest first.
+///
+/// # Query Parameters
+///
+/// - `status`: Optional status filter (pending, applied, rejected)
+pub async fn list_reprice_batches_handler(
+    State(state): State<AppState>,
+    CurrentUser(ctx): CurrentUser,
+    Query(params): Query<ListRepriceBatchesQueryParams>,
+) -> Result<Json<Vec<RepriceBatchResponse>>, Response> {
+    require_permission(&ctx, "products:read")?;
+
+    let use_case = ListRepriceBatchesUseCase::new(state.reprice_batch_repo());
+
+    let response = use_case
+        .execute(params.status.as_deref())
+        .await
+        .map_err(|e| AppError::from(e).into_response())?;
+
+    Ok(Json(response))
+}
+
+/// Handler for GET /api/v1/products/reprices/{id}
+///
+/// Returns a batch with its previewed lines.
+pub async fn get_reprice_batch_handler(
+    State(state): State<AppState>,
+    CurrentUser(ctx): CurrentUser,
+    Path(id): Path<Uuid>,
+) -> Result<Json<RepriceBatchResponse>, Response> {
+    require_permission(&ctx, "products:read")?;
+
+    let use_case = GetRepriceBatchUseCase::new(state.reprice_batch_repo());
+
+    let response = use_case
+        .execute(id)
+        .await
+        .map_err(|e| AppError::from(e).into_response())?;
+
+    Ok(Json(response))
+}
+
+// =============================================================================
+// Approve / Reject Reprice Handlers
+// =============================================================================
+
+/// Handler for PUT /api/v1/products/reprices/{id}/approve
+///
+/// Applies a pending batch: writes the new costs, the new prices of products
+/// with a markup rule, and one price-history record per product.
+///
+/// # Response
+///
+/// - 200 OK: Batch applied
+/// - 400 Bad Request: Batch is not pending
+/// - 403 Forbidden: User lacks products:reprice permission
+/// - 404 Not Found: Batch doesn't exist
+pub async fn approve_reprice_handler(
+    state: State<AppState>,
+    user: CurrentUser,
+    id: Path<Uuid>,
+) -> Result<Json<RepriceBatchResponse>, Response> {
+    decide_reprice(state, user, id, true).await
+}
+
+/// Handler for PUT /api/v1/products/reprices/{id}/reject
+///
+/// Discards a pending batch without changing any product.
+pub async fn reject_reprice_handler(
+    state: State<AppState>,
+    user: CurrentUser,
+    id: Path<Uuid>,
+) -> Result<Json<RepriceBatchResponse>, Response> {
+    decide_reprice(state, user, id, false).await
+}
+
+async fn decide_reprice(
+    State(state): State<AppState>,
+    CurrentUser(ctx): CurrentUser,
+    Path(id): Path<Uuid>,
+    approve: bool,
+) -> Result<Json<RepriceBatchResponse>, Response> {
+    require_permission(&ctx, "products:reprice")?;
+
+    let use_case = ApproveRepriceUseCase::new(
+        state.product_repo(),
+        state.reprice_batch_repo(),
+        state.price_history_repo(),
+    );
+
+    let command = ApproveRepriceCommand {
+        batch_id: id,
+        approve,
+    };
+
+    let response = use_case
+        .execute(command, *ctx.user_id())
+        .await
+        .map_err(|e| AppError::from(e).into_response())?;
+
+    Ok(Json(response))
+}
+
+// =============================================================================
+// Price History Handler
+// =============================================================================
+
+/// Handler for GET /api/v1/products/{product_id}/price-history
+///
+/// Lists the cost and price changes of a product, newest first.
+pub async fn get_product_price_history_handler(
+    State(state): State<AppState>,
+    CurrentUser(ctx): CurrentUser,
+    Path(product_id): Path<Uuid>,
+) -> Result<Json<Vec<PriceChangeResponse>>, Response> {
+    require_permission(&ctx, "products:read")?;
+
+    let use_case = GetPriceHistoryUseCase::new(state.product_repo(), state.price_history_repo());
+
+    let response = use_case
+        .execute(product_id)
+        .await
+        .map_err(|e| AppError::from(e).into_response())?;
+
+    Ok(Json(response))
+}
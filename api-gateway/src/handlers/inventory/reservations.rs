@@ -7,6 +7,7 @@
 // - GET /api/inventory/reservations - List reservations with pagination
 // - PUT /api/inventory/reservations/{id}/confirm - Confirm a reservation
 // - PUT /api/inventory/reservations/{id}/cancel - Cancel a reservation
+// - PUT /api/inventory/reservations/{id}/touch - Extend an auto-extending reservation
 // - POST /api/inventory/reservations/expire - Expire all expired reservations
 
 use axum::{
@@ -23,7 +24,8 @@ use inventory::{
     CancelReservationCommand, CancelReservationUseCase, ConfirmReservationCommand,
     ConfirmReservationUseCase, CreateReservationCommand, CreateReservationUseCase,
     ExpireReservationsResult, ExpireReservationsUseCase, ListReservationsQuery,
-    ListReservationsUseCase, PaginatedResponse, ReservationResponse,
+    ListReservationsUseCase, PaginatedResponse, ReservationResponse, TouchReservationCommand,
+    TouchReservationUseCase,
 };
 
 use crate::error::AppError;
@@ -46,6 +48,8 @@ pub struct ListReservationsQueryParams {
     /// Number of items per page (default: 20, max: 100)
     #[serde(default = "default_page_size")]
     pub page_size: i64,
+    /// Keyset cursor from a previous page's `next_cursor` (overrides `page`)
+    pub after_cursor: Option<String>,
     /// Filter by stock ID
     pub stock_id: Option<Uuid>,
     /// Filter by status (pending, confirmed, cancelled, expired)
@@ -62,6 +66,7 @@ impl From<ListReservationsQueryParams> for ListReservationsQuery {
             reference_type: params.reference_type,
             page: params.page,
             page_size: params.page_size,
+            after_cursor: params.after_cursor,
         }
     }
 }
@@ -102,7 +107,8 @@ impl From<ExpireReservationsResult> for ExpireReservationsResponse {
 ///   "reference_type": "cart",
 ///   "reference_id": "uuid",
 ///   "quantity": 10,
-///   "expires_at": "2024-12-31T23:59:59Z"
+///   "expires_at": "2024-12-31T23:59:59Z",
+///   "auto_extend_seconds": 900
 /// }
 /// ```
 ///
@@ -130,7 +136,11 @@ pub async fn create_reservation_handler(
         .into_response());
     }
 
-    let use_case = CreateReservationUseCase::new(state.stock_repo(), state.reservation_repo());
+    let use_case = CreateReservationUseCase::new(
+        state.stock_repo(),
+        state.reservation_repo(),
+        state.reservation_priority_policy(),
+    );
 
     let response = use_case
         .execute(command)
@@ -154,7 +164,7 @@ pub async fn create_reservation_handler(
 ///
 /// # Response
 ///
-/// - 200 OK: Paginated list of reservations
+/// - 200 OK: Paginated list of reservations, limited to the user's stores
 /// - 401 Unauthorized: Missing or invalid token
 /// - 403 Forbidden: User lacks inventory:read permission
 pub async fn list_reservations_handler(
@@ -168,7 +178,7 @@ pub async fn list_reservations_handler(
 
     let query: ListReservationsQuery = params.into();
     let response = use_case
-        .execute(query)
+        .execute(query, &ctx)
         .await
         .map_err(|e| AppError::from(e).into_response())?;
 
@@ -258,6 +268,53 @@ pub async fn cancel_reservation_handler(
     Ok(Json(response))
 }
 
+/// Handler for PUT /api/inventory/reservations/{id}/touch
+///
+/// Keeps a reservation created with `auto_extend_seconds` alive while its
+/// holder is active (e.g. a shopper editing their cart): the expiry becomes
+/// at least that many seconds from now.
+///
+/// # Path Parameters
+///
+/// - `id`: Reservation UUID
+///
+/// # Response
+///
+/// - 200 OK: Reservation with its new expiry
+/// - 400 Bad Request: Reservation already expired, was confirmed or cancelled,
+///   or doesn't auto-extend
+/// - 401 Unauthorized: Missing or invalid token
+/// - 403 Forbidden: User lacks cart:add or sales:create permission
+/// - 404 Not Found: Reservation doesn't exist
+pub async fn touch_reservation_handler(
+    State(state): State<AppState>,
+    CurrentUser(ctx): CurrentUser,
+    Path(id): Path<Uuid>,
+) -> Result<Json<ReservationResponse>, Response> {
+    // Check for cart:add OR sales:create permission
+    let has_cart_add = ctx.has_permission("cart:add");
+    let has_sales_create = ctx.has_permission("sales:create");
+
+    if !has_cart_add && !has_sales_create {
+        return Err(AppError::new(
+            StatusCode::FORBIDDEN,
+            ErrorResponse::new("FORBIDDEN", "Requires cart:add or sales:create permission"),
+        )
+        .into_response());
+    }
+
+    let use_case = TouchReservationUseCase::new(state.reservation_repo());
+
+    let command = TouchReservationCommand { reservation_id: id };
+
+    let response = use_case
+        .execute(command)
+        .await
+        .map_err(|e| AppError::from(e).into_response())?;
+
+    Ok(Json(response))
+}
+
 /// Handler for POST /api/inventory/reservations/expire
 ///
 /// Batch process to expire all pending reservations past their expiration time.
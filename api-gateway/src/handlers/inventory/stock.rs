@@ -6,6 +6,8 @@
 // - GET /api/inventory/stock - List stock with pagination
 // - GET /api/inventory/stock/{stock_id} - Get stock details
 // - PUT /api/inventory/stock/{stock_id}/levels - Update stock level thresholds
+// - POST /api/inventory/stock/recalculate-cost - Replay the kardex to correct average cost
+// - POST /api/inventory/serials - Register serial numbers of serial-tracked units
 // - GET /api/stores/{store_id}/inventory - Get store inventory
 // - GET /api/stores/{store_id}/low-stock - Get low stock alerts
 // - GET /api/products/{product_id}/stock - Get product stock across stores
@@ -20,10 +22,12 @@ use serde::Deserialize;
 use uuid::Uuid;
 
 use inventory::{
-    BulkInitializeStockCommand, BulkInitializeStockResult, BulkInitializeStockUseCase,
-    GetLowStockAlertsUseCase, GetProductStockUseCase, GetStockUseCase, GetStoreInventoryUseCase,
-    InitializeStockCommand, InitializeStockUseCase, ListResponse, ListStockQuery, ListStockUseCase,
-    PaginatedResponse, StockDetailResponse, StockResponse, UpdateStockLevelsCommand,
+    AverageCostRecalculationResponse, BulkInitializeStockCommand, BulkInitializeStockResult,
+    BulkInitializeStockUseCase, GetLowStockAlertsUseCase, GetProductStockUseCase, GetStockUseCase,
+    GetStoreInventoryUseCase, InitializeStockCommand, InitializeStockUseCase, ListResponse,
+    ListStockQuery, ListStockUseCase, PaginatedResponse, ProductSerialResponse,
+    RecalculateAverageCostCommand, RecalculateAverageCostUseCase, RegisterSerialsCommand,
+    RegisterSerialsUseCase, StockDetailResponse, StockResponse, UpdateStockLevelsCommand,
     UpdateStockLevelsUseCase,
 };
 
@@ -48,6 +52,8 @@ pub struct ListStockQueryParams {
     /// Number of items per page (default: 20, max: 100)
     #[serde(default = "default_page_size")]
     pub page_size: i64,
+    /// Keyset cursor from a previous page's `next_cursor` (overrides `page`)
+    pub after_cursor: Option<String>,
     /// Filter by store ID
     pub store_id: Option<Uuid>,
     /// Filter by product ID
@@ -65,6 +71,7 @@ impl From<ListStockQueryParams> for ListStockQuery {
             low_stock: params.low_stock,
             page: params.page,
             page_size: params.page_size,
+            after_cursor: params.after_cursor,
         }
     }
 }
@@ -89,7 +96,8 @@ impl From<ListStockQueryParams> for ListStockQuery {
 ///
 /// - 200 OK: Paginated list of stock records
 /// - 401 Unauthorized: Missing or invalid token
-/// - 403 Forbidden: User lacks inventory:read permission
+/// - 403 Forbidden: User lacks inventory:read permission, or is not assigned
+///   to the requested store
 pub async fn list_stock_handler(
     State(state): State<AppState>,
     CurrentUser(ctx): CurrentUser,
@@ -104,7 +112,7 @@ pub async fn list_stock_handler(
 
     let query: ListStockQuery = params.into();
     let response = use_case
-        .execute(query)
+        .execute(query, &ctx)
         .await
         .map_err(|e| AppError::from(e).into_response())?;
 
@@ -162,7 +170,8 @@ pub async fn get_stock_handler(
 ///
 /// - 200 OK: List of stock records for the store
 /// - 401 Unauthorized: Missing or invalid token
-/// - 403 Forbidden: User lacks inventory:read permission
+/// - 403 Forbidden: User lacks inventory:read permission, or is not assigned
+///   to the store
 pub async fn get_store_inventory_handler(
     State(state): State<AppState>,
     CurrentUser(ctx): CurrentUser,
@@ -174,7 +183,7 @@ pub async fn get_store_inventory_handler(
     let use_case = GetStoreInventoryUseCase::new(state.stock_repo());
 
     let response = use_case
-        .execute(store_id)
+        .execute(store_id, &ctx)
         .await
         .map_err(|e| AppError::from(e).into_response())?;
 
@@ -333,8 +342,8 @@ pub async fn bulk_initialize_stock_handler(
 
 /// Handler for PUT /api/inventory/stock/{stock_id}/levels
 ///
-/// Updates the min/max stock level thresholds for a stock record.
-/// Does not change the actual quantity.
+/// Updates the min/max stock level thresholds and the store's reorder point
+/// and reorder quantity for a stock record. Does not change the actual quantity.
 ///
 /// # Path Parameters
 ///
@@ -347,6 +356,8 @@ pub async fn bulk_initialize_stock_handler(
 ///     "stock_id": "uuid",
 ///     "min_stock_level": 20,
 ///     "max_stock_level": 500,
+///     "reorder_point": 50,       // Optional, falls back to min_stock_level
+///     "reorder_quantity": 200,   // Optional
 ///     "expected_version": 1
 /// }
 /// ```
@@ -354,6 +365,7 @@ pub async fn bulk_initialize_stock_handler(
 /// # Response
 ///
 /// - 200 OK: Stock levels updated
+/// - 400 Bad Request: Negative reorder point or non-positive reorder quantity
 /// - 401 Unauthorized: Missing or invalid token
 /// - 403 Forbidden: User lacks inventory:write permission
 /// - 404 Not Found: Stock record doesn't exist
@@ -379,14 +391,64 @@ pub async fn update_stock_levels_handler(
     Ok(Json(response))
 }
 
+// =============================================================================
+// Recalculate Average Cost Handler
+// =============================================================================
+
+/// Handler for POST /api/inventory/stock/recalculate-cost
+///
+/// Replays every movement of a product's stock in a store, oldest first, and
+/// rewrites the running weighted-average cost on each movement and on the
+/// stock record. Used after a bad movement in the kardex has been corrected.
+///
+/// # Request Body
+///
+/// ```json
+/// {
+///     "store_id": "uuid",
+///     "product_id": "uuid"
+/// }
+/// ```
+///
+/// # Response
+///
+/// - 200 OK: Movements replayed/rewritten and the old and new average cost
+/// - 401 Unauthorized: Missing or invalid token
+/// - 403 Forbidden: User lacks inventory:write permission
+/// - 404 Not Found: The product has no stock in the store
+/// - 409 Conflict: Movements kept being posted during the replay
+pub async fn recalculate_average_cost_handler(
+    State(state): State<AppState>,
+    CurrentUser(ctx): CurrentUser,
+    JsonBody(command): JsonBody<RecalculateAverageCostCommand>,
+) -> Result<Json<AverageCostRecalculationResponse>, Response> {
+    require_permission(&ctx, "inventory:write")?;
+    verify_store_in_org(state.pool(), &ctx, command.store_id).await?;
+
+    let use_case = RecalculateAverageCostUseCase::new(
+        state.stock_repo(),
+        state.movement_repo(),
+        state.audit_repo(),
+    );
+
+    let response = use_case
+        .execute(command, *ctx.user_id())
+        .await
+        .map_err(|e| AppError::from(e).into_response())?;
+
+    Ok(Json(response))
+}
+
 // =============================================================================
 // Get Low Stock Alerts Handler
 // =============================================================================
 
 /// Handler for GET /api/stores/{store_id}/low-stock
 ///
-/// Gets all products with low stock levels for a specific store.
-/// Useful for generating reorder alerts and notifications.
+/// Gets all products whose available stock is at or below the store's reorder
+/// point (min_stock_level when none is set), each with a
+/// `suggested_reorder_quantity`. Useful for generating reorder alerts and
+/// notifications.
 ///
 /// # Path Parameters
 ///
@@ -414,3 +476,39 @@ pub async fn get_low_stock_alerts_handler(
 
     Ok(Json(response))
 }
+
+// =============================================================================
+// Register Serials Handler
+// =============================================================================
+
+/// Handler for POST /api/inventory/serials
+///
+/// Registers the serial numbers of units of a serial-tracked product held
+/// at a store, so they can be captured on sales.
+///
+/// # Request Body
+///
+/// ```json
+/// {
+///     "product_id": "uuid",
+///     "store_id": "uuid",
+///     "serial_numbers": ["SN-0001", "SN-0002"]
+/// }
+/// ```
+pub async fn register_serials_handler(
+    State(state): State<AppState>,
+    CurrentUser(ctx): CurrentUser,
+    JsonBody(command): JsonBody<RegisterSerialsCommand>,
+) -> Result<(StatusCode, Json<Vec<ProductSerialResponse>>), Response> {
+    require_permission(&ctx, "inventory:write")?;
+    verify_store_in_org(state.pool(), &ctx, command.store_id).await?;
+
+    let use_case = RegisterSerialsUseCase::new(state.product_repo(), state.product_serial_repo());
+
+    let response = use_case
+        .execute(command)
+        .await
+        .map_err(|e| AppError::from(e).into_response())?;
+
+    Ok((StatusCode::CREATED, Json(response)))
+}
@@ -5,6 +5,7 @@
 // - GET /api/v1/transfers - List transfers
 // - GET /api/v1/transfers/{id} - Get transfer details
 // - PUT /api/v1/transfers/{id}/submit - Submit for processing
+// - PUT /api/v1/transfers/{id}/approve - Approve or reject a transfer awaiting approval
 // - PUT /api/v1/transfers/{id}/ship - Ship the transfer
 // - PUT /api/v1/transfers/{id}/receive - Receive the transfer
 // - PUT /api/v1/transfers/{id}/cancel - Cancel the transfer
@@ -19,10 +20,10 @@ use serde::Deserialize;
 use uuid::Uuid;
 
 use inventory::{
-    CancelTransferUseCase, CreateTransferCommand, CreateTransferUseCase, GetTransferUseCase,
-    ListResponse, ListTransfersQuery, ListTransfersUseCase, ReceiveTransferCommand,
-    ReceiveTransferUseCase, ShipTransferCommand, ShipTransferUseCase, SubmitTransferUseCase,
-    TransferDetailResponse, TransferResponse,
+    ApproveTransferCommand, ApproveTransferUseCase, CancelTransferUseCase, CreateTransferCommand,
+    CreateTransferUseCase, GetTransferUseCase, ListResponse, ListTransfersQuery,
+    ListTransfersUseCase, ReceiveTransferCommand, ReceiveTransferUseCase, ShipTransferCommand,
+    ShipTransferUseCase, SubmitTransferUseCase, TransferDetailResponse, TransferResponse,
 };
 
 use crate::error::AppError;
@@ -88,7 +89,7 @@ pub async fn list_transfers_handler(
 
     let query: ListTransfersQuery = params.into();
     let response = use_case
-        .execute(query)
+        .execute(query, &ctx)
         .await
         .map_err(|e| AppError::from(e).into_response())?;
 
@@ -106,7 +107,7 @@ pub async fn get_transfer_handler(
     let use_case = GetTransferUseCase::new(state.transfer_repo());
 
     let response = use_case
-        .execute(id)
+        .execute(id, &ctx)
         .await
         .map_err(|e| AppError::from(e).into_response())?;
 
@@ -121,7 +122,8 @@ pub async fn submit_transfer_handler(
 ) -> Result<Json<TransferDetailResponse>, Response> {
     require_permission(&ctx, "transfers:create")?;
 
-    let use_case = SubmitTransferUseCase::new(state.transfer_repo());
+    let use_case =
+        SubmitTransferUseCase::new(state.transfer_repo(), state.transfer_approval_policy());
 
     let response = use_case
         .execute(id)
@@ -131,6 +133,30 @@ pub async fn submit_transfer_handler(
     Ok(Json(response))
 }
 
+/// Handler for PUT /api/v1/transfers/{id}/approve
+///
+/// Rejecting (`approve: false`) cancels the transfer.
+pub async fn approve_transfer_handler(
+    State(state): State<AppState>,
+    CurrentUser(ctx): CurrentUser,
+    Path(id): Path<Uuid>,
+    Json(mut command): Json<ApproveTransferCommand>,
+) -> Result<Json<TransferDetailResponse>, Response> {
+    require_permission(&ctx, "transfers:approve")?;
+
+    command.transfer_id = id;
+
+    let use_case = ApproveTransferUseCase::new(state.transfer_repo());
+
+    let actor_id = *ctx.user_id();
+    let response = use_case
+        .execute(command, actor_id)
+        .await
+        .map_err(|e| AppError::from(e).into_response())?;
+
+    Ok(Json(response))
+}
+
 /// Handler for PUT /api/v1/transfers/{id}/ship
 pub async fn ship_transfer_handler(
     State(state): State<AppState>,
@@ -172,6 +198,7 @@ pub async fn receive_transfer_handler(
         state.transfer_repo(),
         state.stock_repo(),
         state.movement_repo(),
+        state.adjustment_repo(),
     );
 
     let actor_id = *ctx.user_id();
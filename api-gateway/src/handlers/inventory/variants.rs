@@ -2,6 +2,7 @@
 //
 // These handlers implement the REST endpoints for product variant management:
 // - POST /api/products/{product_id}/variants - Create a new variant
+// - POST /api/products/{product_id}/variants/generate - Generate variants from attribute axes
 // - GET /api/products/{product_id}/variants - List variants
 // - GET /api/products/{product_id}/variants/{variant_id} - Get variant details
 // - PUT /api/products/{product_id}/variants/{variant_id} - Update variant
@@ -16,8 +17,9 @@ use axum::{
 use uuid::Uuid;
 
 use inventory::{
-    CreateVariantCommand, CreateVariantUseCase, DeleteVariantUseCase, GetVariantUseCase,
-    ListResponse, ListVariantsUseCase, UpdateVariantCommand, UpdateVariantUseCase, VariantResponse,
+    CreateVariantCommand, CreateVariantUseCase, DeleteVariantUseCase, GenerateVariantsCommand,
+    GenerateVariantsResult, GenerateVariantsUseCase, GetVariantUseCase, ListResponse,
+    ListVariantsUseCase, UpdateVariantCommand, UpdateVariantUseCase, VariantResponse,
 };
 
 use crate::error::AppError;
@@ -45,7 +47,7 @@ use crate::state::AppState;
 ///   "variant_attributes": { "color": "red", "size": "L" },
 ///   "price": 34.99,
 ///   "cost_price": 15.00,
-///   "barcode": "1234567890123"
+///   "barcode": "1234567890128"
 /// }
 /// ```
 ///
@@ -68,7 +70,58 @@ pub async fn create_variant_handler(
     // Set the product_id from the path parameter
     command.product_id = product_id;
 
-    let use_case = CreateVariantUseCase::new(state.product_repo());
+    let use_case = CreateVariantUseCase::new(state.product_repo(), state.barcode_sequence_repo());
+
+    let response = use_case
+        .execute(command)
+        .await
+        .map_err(|e| AppError::from(e).into_response())?;
+
+    Ok((StatusCode::CREATED, Json(response)))
+}
+
+// =============================================================================
+// Generate Variants Handler
+// =============================================================================
+
+/// Handler for POST /api/products/{product_id}/variants/generate
+///
+/// Creates one variant per combination of the given attribute axes, with
+/// SKUs joining the product SKU and the values' codes (e.g. `...-M-RED`).
+/// Combinations that already exist on the product are skipped.
+///
+/// # Request Body
+///
+/// ```json
+/// {
+///   "axes": [
+///     { "name": "size", "values": ["S", "M", "L", "Extra Large"], "codes": { "Extra Large": "XL" } },
+///     { "name": "color", "values": ["Red", "Blue"] }
+///   ],
+///   "overrides": [
+///     { "attributes": { "size": "L", "color": "Blue" }, "price": 34.99 }
+///   ]
+/// }
+/// ```
+///
+/// # Response
+///
+/// - 201 Created: Created variants and skipped duplicates
+/// - 400 Bad Request: Invalid axes, too many combinations, or variants not enabled
+/// - 401 Unauthorized: Missing or invalid token
+/// - 403 Forbidden: User lacks products:create permission
+/// - 404 Not Found: Product doesn't exist
+pub async fn generate_variants_handler(
+    State(state): State<AppState>,
+    CurrentUser(ctx): CurrentUser,
+    Path(product_id): Path<Uuid>,
+    Json(mut command): Json<GenerateVariantsCommand>,
+) -> Result<(StatusCode, Json<GenerateVariantsResult>), Response> {
+    require_permission(&ctx, "products:create")?;
+
+    command.product_id = product_id;
+
+    let use_case = GenerateVariantsUseCase::new(state.product_repo());
 
     let response = use_case
         .execute(command)
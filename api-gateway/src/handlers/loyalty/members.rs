@@ -8,17 +8,20 @@ use axum::{
 use serde::Deserialize;
 use uuid::Uuid;
 
+use identity::StoreSettings;
 use loyalty::{
     AdjustPointsCommand, AdjustPointsUseCase, EarnPointsCommand, EarnPointsUseCase,
     EnrollMemberCommand, EnrollMemberUseCase, GetLoyaltyMemberUseCase, GetMemberLedgerUseCase,
-    ListLoyaltyMembersUseCase, LoyaltyMemberId, LoyaltyMemberResponse, LoyaltyProgramId,
-    PointsLedgerEntryResponse, RedeemRewardCommand, RedeemRewardUseCase, RewardRedemptionResponse,
+    ListLoyaltyMembersUseCase, LoyaltyError, LoyaltyMemberId, LoyaltyMemberResponse,
+    LoyaltyProgramId, PointsLedgerEntryResponse, RedeemRewardCommand, RedeemRewardUseCase,
+    RewardRedemptionResponse,
 };
 
 use crate::error::AppError;
 use crate::extractors::CurrentUser;
 use crate::middleware::org_scope::require_feature;
 use crate::middleware::permission::require_permission;
+use crate::middleware::store_settings::load_store_settings;
 use crate::state::AppState;
 
 #[derive(Debug, Deserialize)]
@@ -112,6 +115,7 @@ pub async fn earn_points_handler(
     // Earn is admin-driven in v1; subscriber takes over once publishers ship.
     require_permission(&ctx, "loyalty:adjust_points")?;
     require_feature(state.pool(), &ctx, "loyalty").await?;
+    let settings = member_store_settings(&state, id).await?;
     let use_case = EarnPointsUseCase::new(
         state.loyalty_program_repo(),
         state.loyalty_member_repo(),
@@ -123,6 +127,7 @@ pub async fn earn_points_handler(
             LoyaltyMemberId::from_uuid(id),
             Some(*ctx.user_id().as_uuid()),
             cmd,
+            &settings,
         )
         .await
         .map_err(|e| AppError::from(e).into_response())?;
@@ -171,6 +176,7 @@ pub async fn redeem_reward_handler(
 ) -> Result<Json<RewardRedemptionResponse>, Response> {
     require_permission(&ctx, "loyalty:redeem_reward")?;
     require_feature(state.pool(), &ctx, "loyalty").await?;
+    let settings = member_store_settings(&state, id).await?;
     let use_case = RedeemRewardUseCase::new(
         state.loyalty_member_repo(),
         state.reward_repo(),
@@ -182,8 +188,32 @@ pub async fn redeem_reward_handler(
             LoyaltyMemberId::from_uuid(id),
             Some(*ctx.user_id().as_uuid()),
             cmd,
+            &settings,
         )
         .await
         .map_err(|e| AppError::from(e).into_response())?;
     Ok(Json(RewardRedemptionResponse::from(&redemption)))
 }
+
+/// Settings of the store that runs the member's program
+async fn member_store_settings(
+    state: &AppState,
+    member_id: Uuid,
+) -> Result<StoreSettings, Response> {
+    let member = GetLoyaltyMemberUseCase::new(state.loyalty_member_repo())
+        .execute(LoyaltyMemberId::from_uuid(member_id))
+        .await
+        .map_err(|e| AppError::from(e).into_response())?;
+    let program = state
+        .loyalty_program_repo()
+        .find_by_id(member.program_id())
+        .await
+        .map_err(|e| AppError::from(e).into_response())?
+        .ok_or_else(|| {
+            AppError::from(LoyaltyError::ProgramNotFound(
+                member.program_id().into_uuid(),
+            ))
+            .into_response()
+        })?;
+    load_store_settings(state, program.store_id()).await
+}
@@ -12,6 +12,7 @@ pub mod fiscal;
 pub mod internal;
 pub mod inventory;
 pub mod loyalty;
+pub mod notifications;
 pub mod payments;
 pub mod purchasing;
 pub mod restaurant;
@@ -0,0 +1,6 @@
+pub mod webhooks;
+
+pub use webhooks::{
+    list_failed_webhook_deliveries_handler, list_webhooks_handler, register_webhook_handler,
+    unregister_webhook_handler,
+};
@@ -0,0 +1,109 @@
+//! Outbound event webhook endpoints.
+
+use axum::{
+    Json,
+    extract::{Path, Query, State},
+    response::{IntoResponse, Response},
+};
+use serde::Deserialize;
+use uuid::Uuid;
+
+use notifications::{
+    ListFailedWebhookDeliveriesUseCase, ListWebhooksUseCase, NotificationsError,
+    RegisterWebhookCommand, RegisterWebhookUseCase, UnregisterWebhookUseCase,
+    WebhookDeliveryResponse, WebhookId, WebhookRepository, WebhookResponse,
+};
+
+use crate::error::AppError;
+use crate::extractors::CurrentUser;
+use crate::middleware::org_scope::verify_store_in_org;
+use crate::middleware::permission::require_permission;
+use crate::state::AppState;
+
+#[derive(Debug, Deserialize)]
+pub struct ListWebhooksQuery {
+    pub store_id: Uuid,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ListFailedDeliveriesQuery {
+    pub store_id: Uuid,
+    pub webhook_id: Option<Uuid>,
+    pub limit: Option<i64>,
+}
+
+pub async fn list_webhooks_handler(
+    State(state): State<AppState>,
+    CurrentUser(ctx): CurrentUser,
+    Query(params): Query<ListWebhooksQuery>,
+) -> Result<Json<Vec<WebhookResponse>>, Response> {
+    require_permission(&ctx, "webhooks:manage")?;
+    verify_store_in_org(state.pool(), &ctx, params.store_id).await?;
+
+    let use_case = ListWebhooksUseCase::new(state.webhook_repo());
+    let webhooks = use_case
+        .execute(params.store_id)
+        .await
+        .map_err(|e| AppError::from(e).into_response())?;
+    Ok(Json(webhooks))
+}
+
+pub async fn register_webhook_handler(
+    State(state): State<AppState>,
+    CurrentUser(ctx): CurrentUser,
+    Json(cmd): Json<RegisterWebhookCommand>,
+) -> Result<Json<WebhookResponse>, Response> {
+    require_permission(&ctx, "webhooks:manage")?;
+    verify_store_in_org(state.pool(), &ctx, cmd.store_id).await?;
+
+    let use_case = RegisterWebhookUseCase::new(state.webhook_repo());
+    let webhook = use_case
+        .execute(cmd, *ctx.user_id())
+        .await
+        .map_err(|e| AppError::from(e).into_response())?;
+    Ok(Json(webhook))
+}
+
+pub async fn unregister_webhook_handler(
+    State(state): State<AppState>,
+    CurrentUser(ctx): CurrentUser,
+    Path(id): Path<Uuid>,
+) -> Result<Json<WebhookResponse>, Response> {
+    require_permission(&ctx, "webhooks:manage")?;
+
+    let webhook = state
+        .webhook_repo()
+        .find_by_id(WebhookId::from_uuid(id))
+        .await
+        .map_err(|e| AppError::from(e).into_response())?
+        .ok_or_else(|| AppError::from(NotificationsError::WebhookNotFound(id)).into_response())?;
+    verify_store_in_org(state.pool(), &ctx, webhook.store_id().into_uuid()).await?;
+
+    let use_case = UnregisterWebhookUseCase::new(state.webhook_repo());
+    let webhook = use_case
+        .execute(id)
+        .await
+        .map_err(|e| AppError::from(e).into_response())?;
+    Ok(Json(webhook))
+}
+
+/// Deliveries that exhausted their retries (dead letters), newest first.
+pub async fn list_failed_webhook_deliveries_handler(
+    State(state): State<AppState>,
+    CurrentUser(ctx): CurrentUser,
+    Query(params): Query<ListFailedDeliveriesQuery>,
+) -> Result<Json<Vec<WebhookDeliveryResponse>>, Response> {
+    require_permission(&ctx, "webhooks:manage")?;
+    verify_store_in_org(state.pool(), &ctx, params.store_id).await?;
+
+    let use_case = ListFailedWebhookDeliveriesUseCase::new(state.webhook_delivery_repo());
+    let deliveries = use_case
+        .execute(
+            params.store_id,
+            params.webhook_id,
+            params.limit.unwrap_or(100),
+        )
+        .await
+        .map_err(|e| AppError::from(e).into_response())?;
+    Ok(Json(deliveries))
+}
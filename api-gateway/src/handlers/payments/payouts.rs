@@ -20,6 +20,8 @@ pub struct ListPayoutsQuery {
     pub store_id: Uuid,
     pub page: Option<i64>,
     pub page_size: Option<i64>,
+    /// `next_cursor` of a previous page; when set, `page` is ignored
+    pub after_cursor: Option<String>,
 }
 
 pub async fn list_payouts_handler(
@@ -32,7 +34,12 @@ pub async fn list_payouts_handler(
 
     let use_case = ListPayoutsUseCase::new(state.payout_repo());
     let response = use_case
-        .execute(query.store_id, query.page, query.page_size)
+        .execute(
+            query.store_id,
+            query.page,
+            query.page_size,
+            query.after_cursor.as_deref(),
+        )
         .await
         .map_err(|e| AppError::from(e).into_response())?;
 
@@ -18,9 +18,16 @@ use axum::{
 use serde::Deserialize;
 use uuid::Uuid;
 
+use inventory::application::helpers::{
+    InboundPostingContext, InboundStockLine, ensure_period_open, plan_inbound_posting,
+};
 use inventory::{
-    Currency, InventoryMovement, InventoryMovementRepository, InventoryStock,
-    InventoryStockRepository, MovementType, PaginatedResponse,
+    Currency, InventoryStockRepository, PaginatedResponse, PgInventoryLotRepository,
+    PgInventoryMovementRepository, PgInventoryStockRepository, PgProductSerialRepository,
+    ProductId, VariantId,
+};
+use purchasing::application::utils::{
+    inventory_error, match_receipt, plan_receipt_lots, plan_receipt_serials, plan_stocking_lines,
 };
 use purchasing::{
     CancelGoodsReceiptUseCase, CreateGoodsReceiptCommand, CreateGoodsReceiptUseCase,
@@ -56,6 +63,8 @@ pub struct ListGoodsReceiptsQueryParams {
     /// Number of items per page (default: 20, max: 100)
     #[serde(default = "default_page_size")]
     pub page_size: i64,
+    /// Keyset cursor from a previous page's `next_cursor` (overrides `page`)
+    pub after_cursor: Option<String>,
     /// Filter by store ID
     pub store_id: Option<Uuid>,
     /// Filter by purchase order ID
@@ -72,6 +81,7 @@ impl From<ListGoodsReceiptsQueryParams> for ListGoodsReceiptsQuery {
             status: params.status,
             page: params.page,
             page_size: params.page_size,
+            after_cursor: params.after_cursor,
         }
     }
 }
@@ -92,6 +102,7 @@ impl From<ListGoodsReceiptsQueryParams> for ListGoodsReceiptsQuery {
 ///   "store_id": "uuid",
 ///   "receipt_date": "2024-01-22",
 ///   "notes": "optional notes",
+///   "split_backorder": false,
 ///   "items": [
 ///     {
 ///       "purchase_order_item_id": "uuid",
@@ -101,7 +112,8 @@ impl From<ListGoodsReceiptsQueryParams> for ListGoodsReceiptsQuery {
 ///       "unit_cost": 15.00,
 ///       "lot_number": "LOT001 (optional)",
 ///       "expiry_date": "2025-01-22 (optional)",
-///       "notes": "optional item notes"
+///       "notes": "optional item notes",
+///       "serial_numbers": ["SN-001", "..."] (serial-tracked products, one per unit)
 ///     }
 ///   ]
 /// }
@@ -110,7 +122,7 @@ impl From<ListGoodsReceiptsQueryParams> for ListGoodsReceiptsQuery {
 /// # Response
 ///
 /// - 201 Created: Goods receipt successfully created
-/// - 400 Bad Request: Validation error
+/// - 400 Bad Request: Validation error, or a serial is blank or repeated
 /// - 401 Unauthorized: Missing or invalid token
 /// - 403 Forbidden: User lacks goods_receipts:create permission
 /// - 404 Not Found: Purchase order not found or not approved
@@ -154,7 +166,8 @@ pub async fn create_goods_receipt_handler(
 ///
 /// - 200 OK: Paginated list of goods receipts
 /// - 401 Unauthorized: Missing or invalid token
-/// - 403 Forbidden: User lacks goods_receipts:read permission
+/// - 403 Forbidden: User lacks goods_receipts:read permission, or is not
+///   assigned to the requested store
 pub async fn list_goods_receipts_handler(
     State(state): State<AppState>,
     CurrentUser(ctx): CurrentUser,
@@ -169,7 +182,7 @@ pub async fn list_goods_receipts_handler(
 
     let query: ListGoodsReceiptsQuery = params.into();
     let response = use_case
-        .execute(query)
+        .execute(query, &ctx)
         .await
         .map_err(|e| AppError::from(e).into_response())?;
 
@@ -193,7 +206,8 @@ pub async fn list_goods_receipts_handler(
 /// - 200 OK: Goods receipt details with items
 /// - 401 Unauthorized: Missing or invalid token
 /// - 403 Forbidden: User lacks goods_receipts:read permission
-/// - 404 Not Found: Goods receipt doesn't exist
+/// - 404 Not Found: Goods receipt doesn't exist or belongs to a store the
+///   user is not assigned to
 pub async fn get_goods_receipt_handler(
     State(state): State<AppState>,
     CurrentUser(ctx): CurrentUser,
@@ -204,7 +218,7 @@ pub async fn get_goods_receipt_handler(
     let use_case = GetGoodsReceiptUseCase::new(state.goods_receipt_repo());
 
     let response = use_case
-        .execute(id)
+        .execute(id, &ctx)
         .await
         .map_err(|e| AppError::from(e).into_response())?;
 
@@ -217,7 +231,25 @@ pub async fn get_goods_receipt_handler(
 
 /// Handler for PUT /api/v1/goods-receipts/{id}/confirm
 ///
-/// Confirms a draft goods receipt and updates inventory.
+/// Confirms a draft goods receipt and posts its lines to inventory stock.
+/// If the receipt was created with `split_backorder`, quantities still pending
+/// on the order move into a new draft backorder (`backorder_id` in the
+/// response). Lines of serial-tracked products must carry one serial per
+/// received unit, which is registered as available at the receipt's store.
+/// Lines with a lot number or expiry date add their quantity to that lot.
+/// Quantities ordered in another unit (e.g. cases) are converted to the
+/// product's stocking unit with standard factors or the product's unit
+/// conversions, and the unit cost is spread accordingly.
+/// With `RECEIPT_MATCH_*` tolerances set, received quantities and unit costs
+/// are matched against the purchase order first: a mismatch blocks the
+/// confirmation unless overrides are allowed and the user has
+/// goods_receipts:override_match, in which case the variances are recorded
+/// (`match_status` and the lines' `quantity_variance`/`cost_variance`).
+/// Receipts dated in a closed inventory period of the store need
+/// inventory:reopen_period.
+/// The receipt, purchase order, backorder, serial, lot, stock and kardex writes
+/// share one transaction, with stock and movements written in batches of
+/// `STOCK_POSTING_BATCH_SIZE` rows.
 ///
 /// # Path Parameters
 ///
@@ -226,10 +258,15 @@ pub async fn get_goods_receipt_handler(
 /// # Response
 ///
 /// - 200 OK: Goods receipt successfully confirmed
-/// - 400 Bad Request: Goods receipt is not in draft status
+/// - 400 Bad Request: Goods receipt is not in draft status, serials are
+///   missing or don't match the quantity received, a line's unit doesn't
+///   convert to its product's stocking unit, or the receipt doesn't match
+///   its purchase order (`RECEIPT_MATCH_FAILED`)
 /// - 401 Unauthorized: Missing or invalid token
 /// - 403 Forbidden: User lacks goods_receipts:confirm permission
 /// - 404 Not Found: Goods receipt doesn't exist
+/// - 409 Conflict: Stock was modified concurrently, a serial is already
+///   registered, or the receipt date is in a closed period (`PERIOD_CLOSED`)
 pub async fn confirm_goods_receipt_handler(
     State(state): State<AppState>,
     CurrentUser(ctx): CurrentUser,
@@ -248,11 +285,26 @@ pub async fn confirm_goods_receipt_handler(
         .map_err(|e| AppError::from(e).into_response())?
         .ok_or_else(|| AppError::from(PurchasingError::GoodsReceiptNotFound(id)).into_response())?;
 
+    // Stock is received as of the receipt date, which may be in a closed period
+    ensure_period_open(
+        state.inventory_period_repo().as_ref(),
+        receipt.store_id(),
+        receipt.receipt_date(),
+        ctx.has_permission("inventory:reopen_period"),
+    )
+    .await
+    .map_err(|e| AppError::from(e).into_response())?;
+
     // Domain logic: confirm receipt
     receipt
         .confirm(actor_id)
         .map_err(|e| AppError::from(e).into_response())?;
 
+    // Units of serial-tracked products to register at the receipt's store
+    let serials = plan_receipt_serials(state.product_repo().as_ref(), &receipt)
+        .await
+        .map_err(|e| AppError::from(e).into_response())?;
+
     // Read purchase order
     let mut order = state
         .purchase_order_repo()
@@ -266,6 +318,21 @@ pub async fn confirm_goods_receipt_handler(
             .into_response()
         })?;
 
+    // Match quantities and costs before the receipt is added to the order
+    match_receipt(
+        &state.receipt_match_policy(),
+        &mut receipt,
+        &order,
+        actor_id,
+        ctx.has_permission("goods_receipts:override_match"),
+    )
+    .map_err(|e| AppError::from(e).into_response())?;
+
+    // Received quantities in the units the products are stocked in
+    let stocking_lines = plan_stocking_lines(state.product_repo().as_ref(), &receipt, &order)
+        .await
+        .map_err(|e| AppError::from(e).into_response())?;
+
     // Domain logic: update received quantities
     for receipt_item in receipt.items() {
         for order_item in order.items_mut() {
@@ -276,23 +343,92 @@ pub async fn confirm_goods_receipt_handler(
         }
     }
 
-    if order.all_items_received() {
-        order
-            .receive_complete(actor_id, receipt.receipt_date())
-            .map_err(|e| AppError::from(e).into_response())?;
-    } else if order.has_received_items() {
-        order
-            .receive_partial(actor_id)
-            .map_err(|e| AppError::from(e).into_response())?;
-    }
-
-    // All writes in a single transaction
+    // All writes in a single transaction, the backorder number included so
+    // it is given back if the confirmation fails
+    let batch_size = state.stock_posting_batch_size();
     let mut tx = state
         .pool()
         .begin()
         .await
         .map_err(|e| AppError::from(PurchasingError::from(e)).into_response())?;
 
+    // Split pending quantities into a backorder when the receipt asks for it
+    let backorder = if receipt.split_backorder() && !order.all_items_received() {
+        let order_number = state
+            .purchase_order_repo()
+            .generate_order_number_in_tx(&mut tx, order.store_id())
+            .await
+            .map_err(|e| AppError::from(e).into_response())?;
+        order
+            .split_backorder(order_number, actor_id, receipt.receipt_date())
+            .map_err(|e| AppError::from(e).into_response())?
+    } else {
+        None
+    };
+
+    // A split has already marked the order as received
+    if backorder.is_none() {
+        if order.all_items_received() {
+            order
+                .receive_complete(actor_id, receipt.receipt_date())
+                .map_err(|e| AppError::from(e).into_response())?;
+        } else if order.has_received_items() {
+            order
+                .receive_partial(actor_id, receipt.receipt_date())
+                .map_err(|e| AppError::from(e).into_response())?;
+        }
+    }
+
+    // Plan the stock posting: lines are folded per stock record so each
+    // record is written once and the kardex balances stay sequential
+    let receipt_store_id = receipt.store_id();
+    let stock_repo = state.stock_repo();
+
+    let product_ids: Vec<ProductId> = receipt
+        .items()
+        .iter()
+        .filter(|i| i.variant_id().is_none())
+        .map(|i| i.product_id())
+        .collect();
+    let variant_ids: Vec<VariantId> = receipt
+        .items()
+        .iter()
+        .filter_map(|i| i.variant_id())
+        .collect();
+
+    let mut existing = stock_repo
+        .find_by_store_and_products(receipt_store_id, &product_ids)
+        .await
+        .map_err(|e| AppError::from(e).into_response())?;
+    existing.extend(
+        stock_repo
+            .find_by_store_and_variants(receipt_store_id, &variant_ids)
+            .await
+            .map_err(|e| AppError::from(e).into_response())?,
+    );
+
+    let lines: Vec<InboundStockLine> = receipt
+        .items()
+        .iter()
+        .zip(&stocking_lines)
+        .map(|(item, line)| InboundStockLine {
+            product_id: item.product_id(),
+            variant_id: item.variant_id(),
+            quantity: line.quantity,
+            unit_cost: Some(line.unit_cost),
+        })
+        .collect();
+    let context = InboundPostingContext {
+        store_id: receipt_store_id,
+        movement_reason: Some("Goods receipt confirmed".to_string()),
+        reference_type: Some("goods_receipt".to_string()),
+        reference_id: Some(receipt.id().into_uuid()),
+        actor_id,
+        currency: Currency::hnl(),
+    };
+    let plan = plan_inbound_posting(&context, existing, &lines)
+        .map_err(|e| AppError::from(e).into_response())?;
+
     PgGoodsReceiptRepository::update_in_tx(&mut tx, &receipt)
         .await
         .map_err(|e| AppError::from(e).into_response())?;
@@ -301,84 +437,43 @@ pub async fn confirm_goods_receipt_handler(
         .await
         .map_err(|e| AppError::from(e).into_response())?;
 
-    tx.commit()
-        .await
-        .map_err(|e| AppError::from(PurchasingError::from(e)).into_response())?;
+    if let Some(backorder) = &backorder {
+        PgPurchaseOrderRepository::save_in_tx(&mut tx, backorder)
+            .await
+            .map_err(|e| AppError::from(e).into_response())?;
+    }
 
-    // Update inventory stock for each receipt item
-    let receipt_store_id = receipt.store_id();
-    let receipt_uuid = receipt.id().into_uuid();
-    let stock_repo = state.stock_repo();
-    let movement_repo = state.movement_repo();
+    PgProductSerialRepository::save_batch_in_tx(&mut tx, &serials)
+        .await
+        .map_err(|e| AppError::from(inventory_error(e)).into_response())?;
 
-    for receipt_item in receipt.items() {
-        let product_id = receipt_item.product_id();
-        let variant_id = receipt_item.variant_id();
-        let quantity = receipt_item.quantity_received();
-        let unit_cost = Some(receipt_item.unit_cost());
-
-        // Find or create stock record at the store
-        let existing = if let Some(vid) = variant_id {
-            stock_repo
-                .find_by_store_and_variant(receipt_store_id, vid)
-                .await
-                .map_err(|e| AppError::from(e).into_response())?
-        } else {
-            stock_repo
-                .find_by_store_and_product(receipt_store_id, product_id)
-                .await
-                .map_err(|e| AppError::from(e).into_response())?
-        };
-
-        let mut stock = match existing {
-            Some(s) => s,
-            None => {
-                let new_stock = if let Some(vid) = variant_id {
-                    InventoryStock::create_for_variant(receipt_store_id, vid)
-                        .map_err(|e| AppError::from(e).into_response())?
-                } else {
-                    InventoryStock::create_for_product(receipt_store_id, product_id)
-                        .map_err(|e| AppError::from(e).into_response())?
-                };
-                stock_repo
-                    .save(&new_stock)
-                    .await
-                    .map_err(|e| AppError::from(e).into_response())?;
-                new_stock
-            }
-        };
+    PgInventoryLotRepository::receive_batch_in_tx(
+        &mut tx,
+        &plan_receipt_lots(&receipt, &stocking_lines),
+    )
+    .await
+    .map_err(|e| AppError::from(e).into_response())?;
 
-        let expected_version = stock.version();
-        stock
-            .adjust_quantity(quantity)
+    for chunk in plan.new_stocks.chunks(batch_size) {
+        PgInventoryStockRepository::save_batch_in_tx(&mut tx, chunk)
+            .await
             .map_err(|e| AppError::from(e).into_response())?;
-        stock.increment_version();
-
-        stock_repo
-            .update_with_version(&stock, expected_version)
+    }
+    for chunk in plan.updated_stocks.chunks(batch_size) {
+        PgInventoryStockRepository::update_quantities_with_version_in_tx(&mut tx, chunk)
             .await
             .map_err(|e| AppError::from(e).into_response())?;
-
-        // Create inventory movement for goods receipt
-        let movement = InventoryMovement::create(
-            stock.id(),
-            MovementType::In,
-            Some("Goods receipt confirmed".to_string()),
-            quantity,
-            unit_cost,
-            Currency::hnl(),
-            stock.quantity(),
-            Some("goods_receipt".to_string()),
-            Some(receipt_uuid),
-            actor_id,
-            None,
-        );
-        movement_repo
-            .save(&movement)
+    }
+    for chunk in plan.movements.chunks(batch_size) {
+        PgInventoryMovementRepository::save_batch_in_tx(&mut tx, chunk)
             .await
             .map_err(|e| AppError::from(e).into_response())?;
     }
 
+    tx.commit()
+        .await
+        .map_err(|e| AppError::from(PurchasingError::from(e)).into_response())?;
+
     // Build response
     let items: Vec<GoodsReceiptItemResponse> = receipt
         .items()
@@ -394,6 +489,9 @@ pub async fn confirm_goods_receipt_handler(
             lot_number: item.lot_number().map(|s| s.to_string()),
             expiry_date: item.expiry_date(),
             notes: item.notes().map(|s| s.to_string()),
+            serial_numbers: item.serial_numbers().to_vec(),
+            quantity_variance: item.quantity_variance(),
+            cost_variance: item.cost_variance(),
         })
         .collect();
 
@@ -405,9 +503,13 @@ pub async fn confirm_goods_receipt_handler(
         receipt_date: receipt.receipt_date(),
         status: receipt.status().to_string(),
         notes: receipt.notes().map(|s| s.to_string()),
+        split_backorder: receipt.split_backorder(),
+        backorder_id: backorder.map(|b| b.id().into_uuid()),
         received_by_id: receipt.received_by_id().into_uuid(),
         confirmed_by_id: receipt.confirmed_by_id().map(|id| id.into_uuid()),
         confirmed_at: receipt.confirmed_at(),
+        match_status: receipt.match_status().map(|s| s.to_string()),
+        match_overridden_by_id: receipt.match_overridden_by_id().map(|id| id.into_uuid()),
         items,
         created_at: receipt.created_at(),
         updated_at: receipt.updated_at(),
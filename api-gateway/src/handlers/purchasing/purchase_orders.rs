@@ -6,10 +6,12 @@
 // - POST /api/v1/purchase-orders - Create a purchase order
 // - GET /api/v1/purchase-orders - List purchase orders with pagination
 // - GET /api/v1/purchase-orders/{id} - Get purchase order details
+// - GET /api/v1/purchase-orders/price-history - Last N prices paid for a product
 // - PUT /api/v1/purchase-orders/{id}/submit - Submit for approval
 // - PUT /api/v1/purchase-orders/{id}/approve - Approve purchase order
 // - PUT /api/v1/purchase-orders/{id}/reject - Reject purchase order
 // - PUT /api/v1/purchase-orders/{id}/cancel - Cancel purchase order
+// - POST /api/v1/purchase-orders/bulk-cancel - Cancel stale draft/submitted orders
 // - PUT /api/v1/purchase-orders/{id}/close - Close purchase order
 
 use axum::{
@@ -18,17 +20,20 @@ use axum::{
     http::StatusCode,
     response::{IntoResponse, Response},
 };
+use rust_decimal::Decimal;
 use serde::Deserialize;
 use uuid::Uuid;
 
 use inventory::PaginatedResponse;
 use purchasing::{
-    ApprovePurchaseOrderUseCase, CancelOrderCommand, CancelPurchaseOrderUseCase,
+    ApprovePurchaseOrderUseCase, BulkCancelPurchaseOrdersCommand, BulkCancelPurchaseOrdersResponse,
+    BulkCancelPurchaseOrdersUseCase, CancelOrderCommand, CancelPurchaseOrderUseCase,
     ClosePurchaseOrderUseCase, CreatePurchaseOrderCommand, CreatePurchaseOrderUseCase,
-    GetPurchaseOrderUseCase, ListPurchaseOrdersQuery, ListPurchaseOrdersUseCase,
-    PurchaseOrderDetailResponse, PurchaseOrderResponse, RejectOrderCommand,
-    RejectPurchaseOrderUseCase, SubmitPurchaseOrderUseCase, UpdatePurchaseOrderCommand,
-    UpdatePurchaseOrderUseCase,
+    GetPurchaseOrderUseCase, GetRecentPurchaseCostsUseCase, ListPurchaseOrdersQuery,
+    ListPurchaseOrdersUseCase, PurchaseOrderDetailResponse, PurchaseOrderResponse,
+    RecentPurchaseCostsQuery, RecentPurchaseCostsResponse, RejectOrderCommand,
+    RejectPurchaseOrderUseCase, SubmitOrderCommand, SubmitPurchaseOrderUseCase,
+    UpdatePurchaseOrderCommand, UpdatePurchaseOrderUseCase,
 };
 
 use crate::error::AppError;
@@ -58,6 +63,8 @@ pub struct ListPurchaseOrdersQueryParams {
     /// Number of items per page (default: 20, max: 100)
     #[serde(default = "default_page_size")]
     pub page_size: i64,
+    /// Keyset cursor from a previous page's `next_cursor` (overrides `page`)
+    pub after_cursor: Option<String>,
     /// Filter by store ID
     pub store_id: Option<Uuid>,
     /// Filter by vendor ID
@@ -66,6 +73,9 @@ pub struct ListPurchaseOrdersQueryParams {
     pub status: Option<String>,
     /// Search by order number
     pub search: Option<String>,
+    /// Only orders past their expected delivery date and not fully received
+    #[serde(default)]
+    pub overdue: bool,
 }
 
 impl From<ListPurchaseOrdersQueryParams> for ListPurchaseOrdersQuery {
@@ -75,8 +85,35 @@ impl From<ListPurchaseOrdersQueryParams> for ListPurchaseOrdersQuery {
             vendor_id: params.vendor_id,
             status: params.status,
             search: params.search,
+            overdue: params.overdue,
             page: params.page,
             page_size: params.page_size,
+            after_cursor: params.after_cursor,
+        }
+    }
+}
+
+/// Query parameters for the price history lookup (HTTP API layer)
+#[derive(Debug, Deserialize)]
+pub struct PriceHistoryQueryParams {
+    pub product_id: Uuid,
+    pub variant_id: Option<Uuid>,
+    /// Store the purchase order is for
+    pub store_id: Uuid,
+    /// Number of receipts (default: 5, max: 50)
+    pub limit: Option<i64>,
+    /// Quoted unit cost to compare against the weighted average
+    pub quoted_unit_cost: Option<Decimal>,
+}
+
+impl From<PriceHistoryQueryParams> for RecentPurchaseCostsQuery {
+    fn from(params: PriceHistoryQueryParams) -> Self {
+        RecentPurchaseCostsQuery {
+            product_id: params.product_id,
+            variant_id: params.variant_id,
+            store_id: params.store_id,
+            limit: params.limit,
+            quoted_unit_cost: params.quoted_unit_cost,
         }
     }
 }
@@ -87,7 +124,9 @@ impl From<ListPurchaseOrdersQueryParams> for ListPurchaseOrdersQuery {
 
 /// Handler for POST /api/v1/purchase-orders
 ///
-/// Creates a new purchase order in draft status.
+/// Creates a new purchase order in draft status. Lines quoted per vendor
+/// pack give `pack_size` and `pack_price` instead of `unit_cost`: their
+/// quantities count packs and the unit cost is derived from the pack price.
 ///
 /// # Request Body
 ///
@@ -110,6 +149,14 @@ impl From<ListPurchaseOrdersQueryParams> for ListPurchaseOrdersQuery {
 ///       "unit_cost": 15.00,
 ///       "discount_percent": 0,
 ///       "tax_percent": 15
+///     },
+///     {
+///       "product_id": "uuid",
+///       "description": "Soda, case of 24",
+///       "quantity_ordered": 5,
+///       "unit_of_measure": "unit",
+///       "pack_size": 24,
+///       "pack_price": 60.00
 ///     }
 ///   ]
 /// }
@@ -158,12 +205,15 @@ pub async fn create_purchase_order_handler(
 /// - `vendor_id` (optional): Filter by vendor
 /// - `status` (optional): Filter by status
 /// - `search` (optional): Search by order number
+/// - `overdue` (optional): Only orders past their expected delivery date and
+///   not fully received
 ///
 /// # Response
 ///
 /// - 200 OK: Paginated list of purchase orders
 /// - 401 Unauthorized: Missing or invalid token
-/// - 403 Forbidden: User lacks purchase_orders:read permission
+/// - 403 Forbidden: User lacks purchase_orders:read permission, or is not
+///   assigned to the requested store
 pub async fn list_purchase_orders_handler(
     State(state): State<AppState>,
     CurrentUser(ctx): CurrentUser,
@@ -178,7 +228,50 @@ pub async fn list_purchase_orders_handler(
 
     let query: ListPurchaseOrdersQuery = params.into();
     let response = use_case
-        .execute(query)
+        .execute(query, &ctx)
+        .await
+        .map_err(|e| AppError::from(e).into_response())?;
+
+    Ok(Json(response))
+}
+
+// =============================================================================
+// Price History Handler
+// =============================================================================
+
+/// Handler for GET /api/v1/purchase-orders/price-history
+///
+/// Returns the last N confirmed goods-receipt unit costs for a product across
+/// the stores of the organization, with vendor and date, plus the weighted
+/// average and min/max over the window. Used when adding a purchase order
+/// line to catch price spikes before ordering.
+///
+/// # Query Parameters
+///
+/// - `product_id`: Product UUID
+/// - `variant_id` (optional): Restrict to one variant
+/// - `store_id`: Store the purchase order is for
+/// - `limit` (optional): Number of receipts (default: 5, max: 50)
+/// - `quoted_unit_cost` (optional): Quoted cost; the response includes its
+///   variance against the weighted average
+///
+/// # Response
+///
+/// - 200 OK: Recent costs and statistics
+/// - 401 Unauthorized: Missing or invalid token
+/// - 403 Forbidden: User lacks purchase_orders:read permission
+pub async fn get_price_history_handler(
+    State(state): State<AppState>,
+    CurrentUser(ctx): CurrentUser,
+    Query(params): Query<PriceHistoryQueryParams>,
+) -> Result<Json<RecentPurchaseCostsResponse>, Response> {
+    require_permission(&ctx, "purchase_orders:read")?;
+    verify_store_in_org(state.pool(), &ctx, params.store_id).await?;
+
+    let use_case = GetRecentPurchaseCostsUseCase::new(state.goods_receipt_repo());
+
+    let response = use_case
+        .execute(params.into())
         .await
         .map_err(|e| AppError::from(e).into_response())?;
 
@@ -202,7 +295,8 @@ pub async fn list_purchase_orders_handler(
 /// - 200 OK: Purchase order details with items
 /// - 401 Unauthorized: Missing or invalid token
 /// - 403 Forbidden: User lacks purchase_orders:read permission
-/// - 404 Not Found: Purchase order doesn't exist
+/// - 404 Not Found: Purchase order doesn't exist or belongs to a store the
+///   user is not assigned to
 pub async fn get_purchase_order_handler(
     State(state): State<AppState>,
     CurrentUser(ctx): CurrentUser,
@@ -213,7 +307,7 @@ pub async fn get_purchase_order_handler(
     let use_case = GetPurchaseOrderUseCase::new(state.purchase_order_repo());
 
     let response = use_case
-        .execute(id)
+        .execute(id, &ctx)
         .await
         .map_err(|e| AppError::from(e).into_response())?;
 
@@ -232,10 +326,19 @@ pub async fn get_purchase_order_handler(
 ///
 /// - `id`: Purchase Order UUID
 ///
+/// # Request Body (optional)
+///
+/// ```json
+/// {
+///   "expected_delivery_date": "2024-02-15"
+/// }
+/// ```
+///
 /// # Response
 ///
 /// - 200 OK: Purchase order successfully submitted
-/// - 400 Bad Request: Purchase order is not in draft status or has no items
+/// - 400 Bad Request: Purchase order is not in draft status, has no items or
+///   its expected delivery date is in the past
 /// - 401 Unauthorized: Missing or invalid token
 /// - 403 Forbidden: User lacks purchase_orders:submit permission
 /// - 404 Not Found: Purchase order doesn't exist
@@ -243,14 +346,16 @@ pub async fn submit_purchase_order_handler(
     State(state): State<AppState>,
     CurrentUser(ctx): CurrentUser,
     Path(id): Path<Uuid>,
+    command: Option<Json<SubmitOrderCommand>>,
 ) -> Result<Json<PurchaseOrderDetailResponse>, Response> {
     require_permission(&ctx, "purchase_orders:submit")?;
 
     let use_case = SubmitPurchaseOrderUseCase::new(state.purchase_order_repo());
 
     let actor_id = *ctx.user_id();
+    let command = command.map(|j| j.0).unwrap_or_default();
     let response = use_case
-        .execute(id, actor_id)
+        .execute(id, actor_id, command)
         .await
         .map_err(|e| AppError::from(e).into_response())?;
 
@@ -265,16 +370,23 @@ pub async fn submit_purchase_order_handler(
 ///
 /// Approves a submitted purchase order.
 ///
+/// With `PURCHASE_APPROVAL_TIERS` set, the order total must be within the
+/// approver's spend limit. Orders above `PURCHASE_DUAL_APPROVAL_THRESHOLD`
+/// stay submitted after the first approval and are approved by a second,
+/// different user.
+///
 /// # Path Parameters
 ///
 /// - `id`: Purchase Order UUID
 ///
 /// # Response
 ///
-/// - 200 OK: Purchase order successfully approved
-/// - 400 Bad Request: Purchase order is not in submitted status or self-approval attempted
+/// - 200 OK: Purchase order approved, or its first approval recorded
+/// - 400 Bad Request: Purchase order is not in submitted status, self-approval
+///   attempted or the first approver approved again
 /// - 401 Unauthorized: Missing or invalid token
-/// - 403 Forbidden: User lacks purchase_orders:approve permission
+/// - 403 Forbidden: User lacks purchase_orders:approve permission, or the
+///   total exceeds their limit (`APPROVAL_LIMIT_EXCEEDED` names the tier required)
 /// - 404 Not Found: Purchase order doesn't exist
 pub async fn approve_purchase_order_handler(
     State(state): State<AppState>,
@@ -283,11 +395,11 @@ pub async fn approve_purchase_order_handler(
 ) -> Result<Json<PurchaseOrderDetailResponse>, Response> {
     require_permission(&ctx, "purchase_orders:approve")?;
 
-    let use_case = ApprovePurchaseOrderUseCase::new(state.purchase_order_repo());
+    let use_case = ApprovePurchaseOrderUseCase::new(state.purchase_order_repo())
+        .with_approval_policy(state.purchase_approval_policy());
 
-    let actor_id = *ctx.user_id();
     let response = use_case
-        .execute(id, actor_id)
+        .execute(id, &ctx)
         .await
         .map_err(|e| AppError::from(e).into_response())?;
 
@@ -386,6 +498,54 @@ pub async fn cancel_purchase_order_handler(
     Ok(Json(response))
 }
 
+/// Handler for POST /api/v1/purchase-orders/bulk-cancel
+///
+/// Cancels every draft or submitted purchase order of a store that hasn't
+/// been updated in `older_than_days`, optionally only one status or vendor.
+/// Orders with a goods receipt are skipped. With `dry_run` nothing is
+/// cancelled and the orders that would be are returned.
+///
+/// # Request Body
+///
+/// ```json
+/// {
+///   "store_id": "uuid",
+///   "status": "draft",
+///   "older_than_days": 90,
+///   "vendor_id": "uuid",
+///   "reason": "Abandoned",
+///   "dry_run": true
+/// }
+/// ```
+///
+/// # Response
+///
+/// - 200 OK: Per-order results
+/// - 400 Bad Request: Missing reason or a status that can't be cancelled
+/// - 401 Unauthorized: Missing or invalid token
+/// - 403 Forbidden: User lacks purchase_orders:cancel permission
+pub async fn bulk_cancel_purchase_orders_handler(
+    State(state): State<AppState>,
+    CurrentUser(ctx): CurrentUser,
+    JsonBody(command): JsonBody<BulkCancelPurchaseOrdersCommand>,
+) -> Result<Json<BulkCancelPurchaseOrdersResponse>, Response> {
+    require_permission(&ctx, "purchase_orders:cancel")?;
+    verify_store_in_org(state.pool(), &ctx, command.store_id).await?;
+
+    let use_case = BulkCancelPurchaseOrdersUseCase::new(
+        state.purchase_order_repo(),
+        state.goods_receipt_repo(),
+    );
+
+    let actor_id = *ctx.user_id();
+    let response = use_case
+        .execute(command, actor_id)
+        .await
+        .map_err(|e| AppError::from(e).into_response())?;
+
+    Ok(Json(response))
+}
+
 // =============================================================================
 // Close Purchase Order Handler
 // =============================================================================
@@ -5,6 +5,7 @@
 // These handlers implement the REST endpoints for vendor management:
 // - POST /api/v1/vendors - Create a vendor
 // - GET /api/v1/vendors - List vendors with pagination
+// - GET /api/v1/vendors/performance - Rank vendors by delivery performance
 // - GET /api/v1/vendors/{id} - Get vendor details
 // - PUT /api/v1/vendors/{id} - Update vendor
 // - PUT /api/v1/vendors/{id}/activate - Activate a vendor
@@ -16,18 +17,20 @@ use axum::{
     http::StatusCode,
     response::{IntoResponse, Response},
 };
+use chrono::NaiveDate;
 use serde::Deserialize;
 use uuid::Uuid;
 
 use inventory::PaginatedResponse;
 use purchasing::{
-    CreateVendorCommand, CreateVendorUseCase, GetVendorUseCase, ListVendorsQuery,
-    ListVendorsUseCase, ToggleVendorStatusUseCase, UpdateVendorCommand, UpdateVendorUseCase,
-    VendorResponse,
+    CreateVendorCommand, CreateVendorUseCase, GetVendorPerformanceUseCase, GetVendorUseCase,
+    ListVendorsQuery, ListVendorsUseCase, ToggleVendorStatusUseCase, UpdateVendorCommand,
+    UpdateVendorUseCase, VendorPerformanceQuery, VendorPerformanceResponse, VendorResponse,
 };
 
 use crate::error::AppError;
 use crate::extractors::{CurrentUser, JsonBody};
+use crate::middleware::org_scope::verify_store_in_org;
 use crate::middleware::permission::require_permission;
 use crate::state::AppState;
 
@@ -56,6 +59,8 @@ pub struct ListVendorsQueryParams {
     pub is_active: Option<bool>,
     /// Search by name or code
     pub search: Option<String>,
+    /// Keyset cursor from a previous page's `next_cursor` (overrides `page`)
+    pub after_cursor: Option<String>,
 }
 
 impl From<ListVendorsQueryParams> for ListVendorsQuery {
@@ -65,6 +70,28 @@ impl From<ListVendorsQueryParams> for ListVendorsQuery {
             search: params.search,
             page: params.page,
             page_size: params.page_size,
+            after_cursor: params.after_cursor,
+        }
+    }
+}
+
+/// Query parameters for the vendor performance report (HTTP API layer)
+#[derive(Debug, Deserialize)]
+pub struct VendorPerformanceQueryParams {
+    /// First order date included
+    pub from_date: NaiveDate,
+    /// Last order date included
+    pub to_date: NaiveDate,
+    /// Restrict to orders of one store
+    pub store_id: Option<Uuid>,
+}
+
+impl From<VendorPerformanceQueryParams> for VendorPerformanceQuery {
+    fn from(params: VendorPerformanceQueryParams) -> Self {
+        VendorPerformanceQuery {
+            store_id: params.store_id,
+            from_date: params.from_date,
+            to_date: params.to_date,
         }
     }
 }
@@ -83,7 +110,7 @@ impl From<ListVendorsQueryParams> for ListVendorsQuery {
 /// {
 ///   "name": "Vendor Name",
 ///   "legal_name": "Vendor Legal Name Inc.",
-///   "tax_id": "RTN12345678",
+///   "tax_id": "0801-1990-123456",
 ///   "email": "vendor@example.com",
 ///   "phone": "+504 1234-5678",
 ///   "address": "123 Main St",
@@ -110,7 +137,7 @@ pub async fn create_vendor_handler(
 ) -> Result<(StatusCode, Json<VendorResponse>), Response> {
     require_permission(&ctx, "vendors:create")?;
 
-    let use_case = CreateVendorUseCase::new(state.vendor_repo());
+    let use_case = CreateVendorUseCase::new(state.vendor_repo(), state.tax_id_policy());
 
     let response = use_case
         .execute(command)
@@ -213,7 +240,8 @@ pub async fn get_vendor_handler(
 /// {
 ///   "name": "New Name",
 ///   "legal_name": "New Legal Name",
-///   "tax_id": "NewRTN123",
+///   "tax_id": "0801-1990-654321",
+///   "tax_id_country": "HN",
 ///   "email": "newemail@example.com",
 ///   "phone": "+504 9999-9999",
 ///   "address": "New Address",
@@ -239,10 +267,14 @@ pub async fn update_vendor_handler(
 ) -> Result<Json<VendorResponse>, Response> {
     require_permission(&ctx, "vendors:update")?;
 
-    let use_case = UpdateVendorUseCase::new(state.vendor_repo());
+    let use_case = UpdateVendorUseCase::new(
+        state.vendor_repo(),
+        state.audit_repo(),
+        state.tax_id_policy(),
+    );
 
     let response = use_case
-        .execute(id, command)
+        .execute(id, command, *ctx.user_id())
         .await
         .map_err(|e| AppError::from(e).into_response())?;
 
@@ -318,3 +350,44 @@ pub async fn deactivate_vendor_handler(
 
     Ok(Json(response))
 }
+
+// =============================================================================
+// Vendor Performance Handler
+// =============================================================================
+
+/// Handler for GET /api/v1/vendors/performance
+///
+/// Ranks vendors by on-time delivery rate, fill rate and average lead time
+/// over the approved purchase orders placed in the date range.
+///
+/// # Query Parameters
+///
+/// - `from_date`: First order date included (YYYY-MM-DD)
+/// - `to_date`: Last order date included (YYYY-MM-DD)
+/// - `store_id` (optional): Restrict to orders of one store
+///
+/// # Response
+///
+/// - 200 OK: Ranked vendor metrics
+/// - 400 Bad Request: `from_date` is after `to_date`
+/// - 401 Unauthorized: Missing or invalid token
+/// - 403 Forbidden: User lacks vendors:read permission
+pub async fn get_vendor_performance_handler(
+    State(state): State<AppState>,
+    CurrentUser(ctx): CurrentUser,
+    Query(params): Query<VendorPerformanceQueryParams>,
+) -> Result<Json<VendorPerformanceResponse>, Response> {
+    require_permission(&ctx, "vendors:read")?;
+    if let Some(sid) = params.store_id {
+        verify_store_in_org(state.pool(), &ctx, sid).await?;
+    }
+
+    let use_case = GetVendorPerformanceUseCase::new(state.purchase_order_repo());
+
+    let response = use_case
+        .execute(params.into())
+        .await
+        .map_err(|e| AppError::from(e).into_response())?;
+
+    Ok(Json(response))
+}
@@ -58,7 +58,13 @@ pub async fn add_cart_item_handler(
 ) -> Result<(StatusCode, Json<CartResponse>), Response> {
     require_permission(&ctx, "sales:manage_cart")?;
 
-    let use_case = sales::AddCartItemUseCase::new(state.cart_repo());
+    let use_case = sales::AddCartItemUseCase::new(
+        state.cart_repo(),
+        state.customer_repo(),
+        state.product_repo(),
+        state.price_tier_repo(),
+        state.price_list_repo(),
+    );
 
     let mut cmd = command;
     cmd.cart_id = cart_id;
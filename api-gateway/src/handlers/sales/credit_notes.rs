@@ -6,6 +6,9 @@ use axum::{
     http::StatusCode,
     response::{IntoResponse, Response},
 };
+use std::collections::HashMap;
+
+use rust_decimal::Decimal;
 use uuid::Uuid;
 
 use crate::error::AppError;
@@ -13,9 +16,15 @@ use crate::extractors::{CurrentUser, JsonBody};
 use crate::middleware::org_scope::verify_store_in_org;
 use crate::middleware::permission::require_permission;
 use crate::state::AppState;
+use inventory::{
+    Currency, InventoryMovement, InventoryMovementRepository, InventoryStockRepository,
+    MovementType, ProductSerial, ProductSerialRepository, SerialStatus,
+};
 use sales::{
     AddCreditNoteItemCommand, ApplyCreditNoteCommand, CancelCreditNoteCommand,
-    CreateCreditNoteCommand, CreditNoteListResponse, CreditNoteResponse, ListCreditNotesQuery,
+    CreateCreditNoteCommand, CreditNoteDocumentResponse, CreditNoteListResponse,
+    CreditNoteRepository, CreditNoteResponse, ListCreditNotesQuery, RestockCostBasis,
+    SaleRepository,
 };
 
 pub async fn create_credit_note_handler(
@@ -26,10 +35,20 @@ pub async fn create_credit_note_handler(
     require_permission(&ctx, "sales:manage_credit_note")?;
     verify_store_in_org(state.pool(), &ctx, command.store_id).await?;
 
-    let use_case = sales::CreateCreditNoteUseCase::new(state.credit_note_repo());
+    let use_case = sales::CreateCreditNoteUseCase::new(
+        state.credit_note_repo(),
+        state.sale_repo(),
+        state.customer_repo(),
+        state.product_repo(),
+    );
+
+    // Returns without a receipt need a manager's override
+    let mut cmd = command;
+    cmd.manager_override =
+        ctx.is_super_admin() || ctx.has_permission("sales:return_without_receipt");
 
     let response = use_case
-        .execute(command, *ctx.user_id())
+        .execute(cmd, *ctx.user_id())
         .await
         .map_err(|e| AppError::from(e).into_response())?;
 
@@ -44,7 +63,11 @@ pub async fn add_credit_note_item_handler(
 ) -> Result<(StatusCode, Json<CreditNoteResponse>), Response> {
     require_permission(&ctx, "sales:manage_credit_note")?;
 
-    let use_case = sales::AddCreditNoteItemUseCase::new(state.credit_note_repo());
+    let use_case = sales::AddCreditNoteItemUseCase::new(
+        state.credit_note_repo(),
+        state.sale_repo(),
+        state.product_repo(),
+    );
 
     let mut cmd = command;
     cmd.credit_note_id = credit_note_id;
@@ -101,7 +124,7 @@ pub async fn approve_credit_note_handler(
     let use_case = sales::ApproveCreditNoteUseCase::new(state.credit_note_repo());
 
     let response = use_case
-        .execute(credit_note_id, *ctx.user_id())
+        .execute(credit_note_id, &ctx)
         .await
         .map_err(|e| AppError::from(e).into_response())?;
 
@@ -116,17 +139,28 @@ pub async fn apply_credit_note_handler(
 ) -> Result<Json<CreditNoteResponse>, Response> {
     require_permission(&ctx, "sales:manage_credit_note")?;
 
-    let use_case = sales::ApplyCreditNoteUseCase::new(state.credit_note_repo());
+    let returned_units = returned_serial_units(&state, credit_note_id).await?;
+
+    let use_case = sales::ApplyCreditNoteUseCase::new(
+        state.credit_note_repo(),
+        state.sale_repo(),
+        state.customer_repo(),
+    );
 
     let refund_method = body
         .get("refund_method")
         .and_then(|v| v.as_str())
-        .unwrap_or("cash")
-        .to_string();
+        .map(str::to_string);
+
+    let refund_to_store_credit = body
+        .get("refund_to_store_credit")
+        .and_then(|v| v.as_bool())
+        .unwrap_or(false);
 
     let cmd = ApplyCreditNoteCommand {
         credit_note_id,
         refund_method,
+        refund_to_store_credit,
     };
 
     let response = use_case
@@ -134,9 +168,162 @@ pub async fn apply_credit_note_handler(
         .await
         .map_err(|e| AppError::from(e).into_response())?;
 
+    let serial_repo = state.product_serial_repo();
+    for (mut unit, restock) in returned_units {
+        unit.take_back(credit_note_id, restock)
+            .map_err(|e| AppError::from(e).into_response())?;
+        serial_repo
+            .update(&unit, SerialStatus::Sold)
+            .await
+            .map_err(|e| AppError::from(e).into_response())?;
+    }
+
+    restock_credit_note_items(&state, &response, *ctx.user_id()).await?;
+
     Ok(Json(response))
 }
 
+/// Loads the sold units named on a credit note's items, checked before the
+/// refund is applied so a serial that was already returned is rejected
+/// up front. Each unit is paired with its item's restock flag.
+async fn returned_serial_units(
+    state: &AppState,
+    credit_note_id: Uuid,
+) -> Result<Vec<(ProductSerial, bool)>, Response> {
+    let Some(credit_note) = state
+        .credit_note_repo()
+        .find_by_id_with_items(sales::CreditNoteId::from_uuid(credit_note_id))
+        .await
+        .map_err(|e| AppError::from(e).into_response())?
+    else {
+        return Ok(Vec::new());
+    };
+
+    let serial_repo = state.product_serial_repo();
+    let mut units = Vec::new();
+    for item in credit_note.items() {
+        for serial_number in item.serial_numbers() {
+            let unit = serial_repo
+                .find_by_product_and_number(item.product_id(), serial_number)
+                .await
+                .map_err(|e| AppError::from(e).into_response())?
+                .filter(|u| u.status() == SerialStatus::Sold)
+                .ok_or_else(|| {
+                    AppError::from(inventory::InventoryError::SerialNotAvailable(
+                        serial_number.clone(),
+                    ))
+                    .into_response()
+                })?;
+            units.push((unit, item.restock()));
+        }
+    }
+    Ok(units)
+}
+
+/// Puts items flagged for restock back into the credit note's store.
+///
+/// The inbound movement's unit cost follows the configured
+/// [`RestockCostBasis`] (`CREDIT_NOTE_RESTOCK_COST_BASIS`), using the cost
+/// captured on the original sale line or the stock's current weighted
+/// average. Returns without a receipt have no sale cost and restock at the
+/// weighted average. Products without a stock record at the store are
+/// skipped, as on sale completion.
+async fn restock_credit_note_items(
+    state: &AppState,
+    credit_note: &CreditNoteResponse,
+    actor_id: identity::UserId,
+) -> Result<(), Response> {
+    if !credit_note.items.iter().any(|item| item.restock) {
+        return Ok(());
+    }
+
+    let cost_basis = state.restock_cost_basis();
+    let sale = match credit_note.original_sale_id {
+        Some(sale_id) => state
+            .sale_repo()
+            .find_by_id_with_details(sales::SaleId::from_uuid(sale_id))
+            .await
+            .map_err(|e| AppError::from(e).into_response())?,
+        None => None,
+    };
+    let sale_costs: HashMap<Uuid, Decimal> = sale
+        .iter()
+        .flat_map(|sale| sale.items())
+        .map(|item| (item.id().into_uuid(), item.unit_cost()))
+        .collect();
+
+    let stock_repo = state.stock_repo();
+    let movement_repo = state.movement_repo();
+    let store_id = identity::StoreId::from_uuid(credit_note.store_id);
+
+    for item in credit_note.items.iter().filter(|item| item.restock) {
+        let existing = if let Some(vid) = item.variant_id {
+            stock_repo
+                .find_by_store_and_variant(store_id, inventory::VariantId::from_uuid(vid))
+                .await
+                .map_err(|e| AppError::from(e).into_response())?
+        } else {
+            stock_repo
+                .find_by_store_and_product(
+                    store_id,
+                    inventory::ProductId::from_uuid(item.product_id),
+                )
+                .await
+                .map_err(|e| AppError::from(e).into_response())?
+        };
+
+        let Some(mut stock) = existing else {
+            continue;
+        };
+
+        let sale_cost = item
+            .original_sale_item_id
+            .and_then(|id| sale_costs.get(&id).copied())
+            .unwrap_or(Decimal::ZERO);
+        let weighted_average =
+            if cost_basis == RestockCostBasis::OriginalSaleCost && sale_cost > Decimal::ZERO {
+                None
+            } else {
+                movement_repo
+                    .calculate_weighted_average_cost(stock.id())
+                    .await
+                    .map_err(|e| AppError::from(e).into_response())?
+            };
+        let unit_cost = cost_basis.resolve(sale_cost, weighted_average);
+
+        let expected_version = stock.version();
+        stock
+            .adjust_quantity(item.return_quantity)
+            .map_err(|e| AppError::from(e).into_response())?;
+        stock.increment_version();
+
+        stock_repo
+            .update_with_version(&stock, expected_version)
+            .await
+            .map_err(|e| AppError::from(e).into_response())?;
+
+        let movement = InventoryMovement::create(
+            stock.id(),
+            MovementType::In,
+            Some("Credit note return".to_string()),
+            item.return_quantity,
+            unit_cost,
+            Currency::hnl(),
+            stock.quantity(),
+            Some("credit_note".to_string()),
+            Some(credit_note.id),
+            actor_id,
+            Some(format!("Restock cost basis: {}", cost_basis)),
+        );
+        movement_repo
+            .save(&movement)
+            .await
+            .map_err(|e| AppError::from(e).into_response())?;
+    }
+
+    Ok(())
+}
+
 pub async fn cancel_credit_note_handler(
     State(state): State<AppState>,
     CurrentUser(ctx): CurrentUser,
@@ -175,6 +362,26 @@ pub async fn get_credit_note_handler(
 
     let use_case = sales::GetCreditNoteUseCase::new(state.credit_note_repo());
 
+    let response = use_case
+        .execute(credit_note_id, &ctx)
+        .await
+        .map_err(|e| AppError::from(e).into_response())?;
+
+    Ok(Json(response))
+}
+
+/// Returns the printable document of an approved or applied credit note,
+/// issuing and numbering it on the first request
+pub async fn get_credit_note_document_handler(
+    State(state): State<AppState>,
+    CurrentUser(ctx): CurrentUser,
+    Path(credit_note_id): Path<Uuid>,
+) -> Result<Json<CreditNoteDocumentResponse>, Response> {
+    require_permission(&ctx, "sales:read_credit_note")?;
+
+    let use_case =
+        sales::GenerateCreditNoteDocumentUseCase::new(state.credit_note_repo(), state.sale_repo());
+
     let response = use_case
         .execute(credit_note_id)
         .await
@@ -196,7 +403,7 @@ pub async fn list_credit_notes_handler(
     let use_case = sales::ListCreditNotesUseCase::new(state.credit_note_repo());
 
     let response = use_case
-        .execute(query)
+        .execute(query, &ctx)
         .await
         .map_err(|e| AppError::from(e).into_response())?;
 
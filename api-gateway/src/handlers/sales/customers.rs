@@ -6,16 +6,20 @@ use axum::{
     http::StatusCode,
     response::{IntoResponse, Response},
 };
+use serde::Deserialize;
 use uuid::Uuid;
 
 use crate::error::AppError;
+use crate::export::{ColumnKind, CsvExport, EXPORT_PAGE_SIZE, MAX_EXPORT_ROWS};
 use crate::extractors::{CurrentUser, JsonBody};
 use crate::middleware::org_scope::verify_store_in_org;
 use crate::middleware::permission::require_permission;
 use crate::state::AppState;
 use sales::{
-    CreateCustomerCommand, CustomerListResponse, CustomerResponse, ListCustomersQuery,
-    UpdateCustomerCommand,
+    ConfigureCustomerCodeFormatCommand, CreateCustomerCommand, CustomerAccountEntryResponse,
+    CustomerCodeFormatResponse, CustomerCreditBalanceResponse, CustomerListResponse,
+    CustomerResponse, CustomerStatementQuery, CustomerStatementResponse, GetCustomerByCodeQuery,
+    ListCustomersQuery, RecordAccountPaymentCommand, UpdateCustomerCommand,
 };
 
 pub async fn create_customer_handler(
@@ -26,7 +30,7 @@ pub async fn create_customer_handler(
     require_permission(&ctx, "sales:create_customer")?;
     verify_store_in_org(state.pool(), &ctx, command.store_id).await?;
 
-    let use_case = sales::CreateCustomerUseCase::new(state.customer_repo());
+    let use_case = sales::CreateCustomerUseCase::new(state.customer_repo(), state.tax_id_policy());
 
     let response = use_case
         .execute(command)
@@ -53,6 +57,24 @@ pub async fn get_customer_handler(
     Ok(Json(response))
 }
 
+pub async fn get_customer_by_code_handler(
+    State(state): State<AppState>,
+    CurrentUser(ctx): CurrentUser,
+    Query(query): Query<GetCustomerByCodeQuery>,
+) -> Result<Json<CustomerResponse>, Response> {
+    require_permission(&ctx, "sales:read_customer")?;
+    verify_store_in_org(state.pool(), &ctx, query.store_id).await?;
+
+    let use_case = sales::GetCustomerByCodeUseCase::new(state.customer_repo());
+
+    let response = use_case
+        .execute(query)
+        .await
+        .map_err(|e| AppError::from(e).into_response())?;
+
+    Ok(Json(response))
+}
+
 pub async fn list_customers_handler(
     State(state): State<AppState>,
     CurrentUser(ctx): CurrentUser,
@@ -73,6 +95,92 @@ pub async fn list_customers_handler(
     Ok(Json(response))
 }
 
+/// Filters for a customer CSV export (store is required)
+#[derive(Debug, Deserialize)]
+pub struct ExportCustomersQuery {
+    pub store_id: Uuid,
+    pub search: Option<String>,
+    pub customer_type: Option<String>,
+    pub is_active: Option<bool>,
+}
+
+/// Exports a store's customers as CSV. Email and phone are masked unless the
+/// user holds `sales:read_customer_pii`; every export is audited.
+pub async fn export_customers_handler(
+    State(state): State<AppState>,
+    CurrentUser(ctx): CurrentUser,
+    Query(query): Query<ExportCustomersQuery>,
+) -> Result<Response, Response> {
+    require_permission(&ctx, "sales:export_customer")?;
+    verify_store_in_org(state.pool(), &ctx, query.store_id).await?;
+
+    let use_case = sales::ListCustomersUseCase::new(state.customer_repo());
+
+    let mut export = CsvExport::new(
+        "customers",
+        vec![
+            ("code", ColumnKind::Plain),
+            ("customer_type", ColumnKind::Plain),
+            ("first_name", ColumnKind::Plain),
+            ("last_name", ColumnKind::Plain),
+            ("company_name", ColumnKind::Plain),
+            ("email", ColumnKind::Email),
+            ("phone", ColumnKind::Phone),
+            ("is_active", ColumnKind::Plain),
+            ("total_purchases", ColumnKind::Plain),
+            ("purchase_count", ColumnKind::Plain),
+            ("last_purchase_at", ColumnKind::Plain),
+            ("created_at", ColumnKind::Plain),
+        ],
+    );
+
+    // Follow cursors rather than page numbers so customers added while the
+    // export runs don't shift rows between pages
+    let mut after_cursor = None;
+    loop {
+        let result = use_case
+            .execute(ListCustomersQuery {
+                store_id: Some(query.store_id),
+                search: query.search.clone(),
+                customer_type: query.customer_type.clone(),
+                is_active: query.is_active,
+                page: None,
+                page_size: Some(EXPORT_PAGE_SIZE),
+                after_cursor: after_cursor.take(),
+            })
+            .await
+            .map_err(|e| AppError::from(e).into_response())?;
+
+        for c in result.items {
+            export.push_row(vec![
+                c.code,
+                c.customer_type,
+                c.first_name,
+                c.last_name,
+                c.company_name.unwrap_or_default(),
+                c.email.unwrap_or_default(),
+                c.phone.unwrap_or_default(),
+                c.is_active.to_string(),
+                c.total_purchases.to_string(),
+                c.purchase_count.to_string(),
+                c.last_purchase_at
+                    .map(|d| d.to_rfc3339())
+                    .unwrap_or_default(),
+                c.created_at.to_rfc3339(),
+            ]);
+        }
+
+        match result.next_cursor {
+            Some(next) if export.row_count() < MAX_EXPORT_ROWS => after_cursor = Some(next),
+            _ => break,
+        }
+    }
+
+    export
+        .into_response(state.audit_repo(), &ctx, Some(query.store_id))
+        .await
+}
+
 pub async fn update_customer_handler(
     State(state): State<AppState>,
     CurrentUser(ctx): CurrentUser,
@@ -81,13 +189,17 @@ pub async fn update_customer_handler(
 ) -> Result<Json<CustomerResponse>, Response> {
     require_permission(&ctx, "sales:update_customer")?;
 
-    let use_case = sales::UpdateCustomerUseCase::new(state.customer_repo());
+    let use_case = sales::UpdateCustomerUseCase::new(
+        state.customer_repo(),
+        state.audit_repo(),
+        state.tax_id_policy(),
+    );
 
     let mut cmd = command;
     cmd.customer_id = id;
 
     let response = use_case
-        .execute(cmd)
+        .execute(cmd, *ctx.user_id())
         .await
         .map_err(|e| AppError::from(e).into_response())?;
 
@@ -127,3 +239,99 @@ pub async fn deactivate_customer_handler(
 
     Ok(Json(response))
 }
+
+pub async fn get_customer_statement_handler(
+    State(state): State<AppState>,
+    CurrentUser(ctx): CurrentUser,
+    Path(id): Path<Uuid>,
+    Query(query): Query<CustomerStatementQuery>,
+) -> Result<Json<CustomerStatementResponse>, Response> {
+    require_permission(&ctx, "sales:read_customer")?;
+
+    let use_case = sales::GetCustomerStatementUseCase::new(state.customer_repo());
+
+    let response = use_case
+        .execute(id, query)
+        .await
+        .map_err(|e| AppError::from(e).into_response())?;
+
+    Ok(Json(response))
+}
+
+pub async fn get_customer_credit_balance_handler(
+    State(state): State<AppState>,
+    CurrentUser(ctx): CurrentUser,
+    Path(id): Path<Uuid>,
+) -> Result<Json<CustomerCreditBalanceResponse>, Response> {
+    require_permission(&ctx, "sales:read_customer")?;
+
+    let use_case = sales::GetCustomerCreditBalanceUseCase::new(state.customer_repo());
+
+    let response = use_case
+        .execute(id)
+        .await
+        .map_err(|e| AppError::from(e).into_response())?;
+
+    Ok(Json(response))
+}
+
+pub async fn record_account_payment_handler(
+    State(state): State<AppState>,
+    CurrentUser(ctx): CurrentUser,
+    Path(id): Path<Uuid>,
+    JsonBody(command): JsonBody<RecordAccountPaymentCommand>,
+) -> Result<(StatusCode, Json<CustomerAccountEntryResponse>), Response> {
+    require_permission(&ctx, "sales:process_payment")?;
+
+    let use_case = sales::RecordAccountPaymentUseCase::new(state.customer_repo());
+
+    let mut cmd = command;
+    cmd.customer_id = id;
+
+    let response = use_case
+        .execute(cmd, *ctx.user_id())
+        .await
+        .map_err(|e| AppError::from(e).into_response())?;
+
+    Ok((StatusCode::CREATED, Json(response)))
+}
+
+pub async fn get_customer_code_format_handler(
+    State(state): State<AppState>,
+    CurrentUser(ctx): CurrentUser,
+    Path(store_id): Path<Uuid>,
+) -> Result<Json<CustomerCodeFormatResponse>, Response> {
+    require_permission(&ctx, "sales:read_customer")?;
+    verify_store_in_org(state.pool(), &ctx, store_id).await?;
+
+    let use_case = sales::ConfigureCustomerCodeFormatUseCase::new(state.customer_repo());
+
+    let response = use_case
+        .get(store_id)
+        .await
+        .map_err(|e| AppError::from(e).into_response())?;
+
+    Ok(Json(response))
+}
+
+pub async fn configure_customer_code_format_handler(
+    State(state): State<AppState>,
+    CurrentUser(ctx): CurrentUser,
+    Path(store_id): Path<Uuid>,
+    JsonBody(command): JsonBody<ConfigureCustomerCodeFormatCommand>,
+) -> Result<Json<CustomerCodeFormatResponse>, Response> {
+    require_permission(&ctx, "sales:update_customer")?;
+    verify_store_in_org(state.pool(), &ctx, store_id).await?;
+
+    let use_case = sales::ConfigureCustomerCodeFormatUseCase::new(state.customer_repo());
+
+    let mut cmd = command;
+    cmd.store_id = store_id;
+
+    let response = use_case
+        .execute(cmd)
+        .await
+        .map_err(|e| AppError::from(e).into_response())?;
+
+    Ok(Json(response))
+}
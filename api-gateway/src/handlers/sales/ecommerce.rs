@@ -6,22 +6,41 @@
 // - PUT /api/v1/orders/{id}/ship - Ship order
 // - PUT /api/v1/orders/{id}/deliver - Mark order delivered
 // - PUT /api/v1/orders/{id}/cancel - Cancel order
+// - GET /api/v1/orders/{id}/fulfillment - Payment, allocation and shipment status
+// - PUT /api/v1/orders/{id}/ready-for-pickup - Hold order at the store for pickup
+// - GET /api/v1/orders/{id}/pickup - Pickup code and hold
+// - POST /api/v1/orders/{id}/pickup - Hand order over against its pickup code
+// - GET /api/v1/orders/pick-queue - Paid orders waiting to be picked
+// - PUT /api/v1/orders/{id}/claim - Claim an order for picking
+// - PUT /api/v1/orders/{id}/release - Return a claimed order to the queue
+// - PUT /api/v1/orders/{id}/hold - Take an order out of the queue
+// - PUT /api/v1/orders/{id}/resume - Put a held order back in the queue
 
 use axum::{
     Json,
-    extract::{Path, State},
+    extract::{Path, Query, State},
     response::{IntoResponse, Response},
 };
 use uuid::Uuid;
 
 use sales::{
-    CancelOrderUseCase, DeliverOrderUseCase, MarkOrderPaidUseCase, ProcessOrderUseCase,
-    SaleDetailResponse, ShipOrderUseCase,
+    CancelOrderUseCase, CompletePickupCommand, CompletePickupUseCase, DeliverOrderUseCase,
+    GetOrderPickupUseCase, MarkOrderPaidUseCase, MarkOrderReadyForPickupUseCase,
+    OrderPickupResponse, PickupCompletedResponse, ProcessOrderUseCase, ReadyForPickupResponse,
+    SaleDetailResponse, SaleId, SaleRepository, SalesError, ShipOrderUseCase,
+};
+use shipping::{
+    ClaimOrderPickUseCase, GetOrderFulfillmentStatusUseCase, HoldOrderPickUseCase,
+    ListOrdersAwaitingFulfillmentUseCase, OrderFulfillmentResponse, OrderPickCommand,
+    OrderPickResponse, PickQueueQuery, PickQueueResponse, ReleaseOrderPickUseCase,
+    ResumeOrderPickUseCase,
 };
 
 use crate::error::AppError;
-use crate::extractors::CurrentUser;
+use crate::extractors::{CurrentUser, JsonBody};
+use crate::middleware::org_scope::verify_store_in_org;
 use crate::middleware::permission::require_permission;
+use crate::middleware::store_settings::load_store_settings;
 use crate::state::AppState;
 
 /// Handler for PUT /api/v1/orders/{id}/mark-paid
@@ -113,3 +132,278 @@ pub async fn cancel_order_handler(
 
     Ok(Json(response))
 }
+
+/// Handler for GET /api/v1/orders/{id}/fulfillment
+///
+/// Staff with shipments:read can look up any order in their organization.
+/// Customers with orders:view_own only see orders linked to their account.
+pub async fn get_order_fulfillment_handler(
+    State(state): State<AppState>,
+    CurrentUser(ctx): CurrentUser,
+    Path(id): Path<Uuid>,
+) -> Result<Json<OrderFulfillmentResponse>, Response> {
+    let staff = ctx.is_super_admin() || ctx.has_permission("shipments:read");
+    if !staff {
+        require_permission(&ctx, "orders:view_own")?;
+    }
+
+    let use_case = GetOrderFulfillmentStatusUseCase::new(
+        state.order_fulfillment_repo(),
+        state.shipment_repo(),
+        state.shipment_event_repo(),
+    );
+
+    let response = use_case
+        .execute(id, (!staff).then(|| *ctx.user_id()))
+        .await
+        .map_err(|e| AppError::from(e).into_response())?;
+
+    if staff {
+        verify_store_in_org(state.pool(), &ctx, response.store_id).await?;
+    }
+
+    Ok(Json(response))
+}
+
+/// Looks up the store an order belongs to
+async fn order_store_id(state: &AppState, id: Uuid) -> Result<Uuid, Response> {
+    state
+        .sale_repo()
+        .find_by_id(SaleId::from_uuid(id))
+        .await
+        .map_err(|e| AppError::from(e).into_response())?
+        .map(|sale| sale.store_id().into_uuid())
+        .ok_or_else(|| AppError::from(SalesError::SaleNotFound(id)).into_response())
+}
+
+/// Handler for PUT /api/v1/orders/{id}/ready-for-pickup
+///
+/// Holds the order for the store's `pickup_hold_days` and sends the customer
+/// the pickup code.
+pub async fn mark_order_ready_for_pickup_handler(
+    State(state): State<AppState>,
+    CurrentUser(ctx): CurrentUser,
+    Path(id): Path<Uuid>,
+) -> Result<Json<ReadyForPickupResponse>, Response> {
+    require_permission(&ctx, "orders:process")?;
+    let store_id = order_store_id(&state, id).await?;
+    verify_store_in_org(state.pool(), &ctx, store_id).await?;
+    let settings = load_store_settings(&state, store_id).await?;
+
+    let use_case = MarkOrderReadyForPickupUseCase::new(
+        state.sale_repo(),
+        state.order_pickup_repo(),
+        state.customer_repo(),
+        state.reservation_repo(),
+        state.order_notifier(),
+    );
+
+    let response = use_case
+        .execute(id, settings.pickup_hold_days())
+        .await
+        .map_err(|e| AppError::from(e).into_response())?;
+
+    Ok(Json(response))
+}
+
+/// Handler for GET /api/v1/orders/{id}/pickup
+pub async fn get_order_pickup_handler(
+    State(state): State<AppState>,
+    CurrentUser(ctx): CurrentUser,
+    Path(id): Path<Uuid>,
+) -> Result<Json<OrderPickupResponse>, Response> {
+    require_permission(&ctx, "orders:process")?;
+
+    let use_case = GetOrderPickupUseCase::new(state.order_pickup_repo());
+
+    let response = use_case
+        .execute(id)
+        .await
+        .map_err(|e| AppError::from(e).into_response())?;
+
+    verify_store_in_org(state.pool(), &ctx, response.store_id).await?;
+
+    Ok(Json(response))
+}
+
+/// Handler for POST /api/v1/orders/{id}/pickup
+///
+/// # Request Body
+///
+/// ```json
+/// {
+///   "code": "7KQ4MX"
+/// }
+/// ```
+pub async fn complete_pickup_handler(
+    State(state): State<AppState>,
+    CurrentUser(ctx): CurrentUser,
+    Path(id): Path<Uuid>,
+    JsonBody(command): JsonBody<CompletePickupCommand>,
+) -> Result<Json<PickupCompletedResponse>, Response> {
+    require_permission(&ctx, "orders:deliver")?;
+    let store_id = order_store_id(&state, id).await?;
+    verify_store_in_org(state.pool(), &ctx, store_id).await?;
+
+    let use_case = CompletePickupUseCase::new(
+        state.sale_repo(),
+        state.order_pickup_repo(),
+        state.reservation_repo(),
+        state.stock_repo(),
+        state.movement_repo(),
+        state.order_invoice_issuer(),
+    );
+
+    let response = use_case
+        .execute(id, command, *ctx.user_id())
+        .await
+        .map_err(|e| AppError::from(e).into_response())?;
+
+    Ok(Json(response))
+}
+
+// =============================================================================
+// Pick Queue Handlers
+// =============================================================================
+
+/// Handler for GET /api/v1/orders/pick-queue
+///
+/// Paid online orders a store still has to pick, oldest payment first, with
+/// their lines, allocation stores and bin locations.
+///
+/// # Query Parameters
+///
+/// - `store_id`: Warehouse store (required)
+/// - `page`, `page_size`: Pagination (default 1 / 50, max 200)
+pub async fn list_pick_queue_handler(
+    State(state): State<AppState>,
+    CurrentUser(ctx): CurrentUser,
+    Query(query): Query<PickQueueQuery>,
+) -> Result<Json<PickQueueResponse>, Response> {
+    require_permission(&ctx, "orders:process")?;
+    verify_store_in_org(state.pool(), &ctx, query.store_id).await?;
+
+    let use_case = ListOrdersAwaitingFulfillmentUseCase::new(state.order_fulfillment_repo());
+
+    let response = use_case
+        .execute(query)
+        .await
+        .map_err(|e| AppError::from(e).into_response())?;
+
+    Ok(Json(response))
+}
+
+/// Handler for PUT /api/v1/orders/{id}/claim
+///
+/// Assigns the order to the current user. `expected_version` is the
+/// `version` read from the queue; if another picker claimed the order first
+/// the request fails with 409 PICK_VERSION_CONFLICT.
+///
+/// # Request Body
+///
+/// ```json
+/// {
+///   "expected_version": 0
+/// }
+/// ```
+pub async fn claim_order_pick_handler(
+    State(state): State<AppState>,
+    CurrentUser(ctx): CurrentUser,
+    Path(id): Path<Uuid>,
+    JsonBody(mut command): JsonBody<OrderPickCommand>,
+) -> Result<Json<OrderPickResponse>, Response> {
+    require_permission(&ctx, "orders:process")?;
+    let store_id = order_store_id(&state, id).await?;
+    verify_store_in_org(state.pool(), &ctx, store_id).await?;
+    command.sale_id = id;
+
+    let use_case = ClaimOrderPickUseCase::new(state.order_pick_deps());
+
+    let response = use_case
+        .execute(command, *ctx.user_id())
+        .await
+        .map_err(|e| AppError::from(e).into_response())?;
+
+    Ok(Json(response))
+}
+
+/// Handler for PUT /api/v1/orders/{id}/release
+///
+/// Returns a claimed order to the queue for any picker.
+pub async fn release_order_pick_handler(
+    State(state): State<AppState>,
+    CurrentUser(ctx): CurrentUser,
+    Path(id): Path<Uuid>,
+    JsonBody(mut command): JsonBody<OrderPickCommand>,
+) -> Result<Json<OrderPickResponse>, Response> {
+    require_permission(&ctx, "orders:process")?;
+    let store_id = order_store_id(&state, id).await?;
+    verify_store_in_org(state.pool(), &ctx, store_id).await?;
+    command.sale_id = id;
+
+    let use_case = ReleaseOrderPickUseCase::new(state.order_pick_deps());
+
+    let response = use_case
+        .execute(command)
+        .await
+        .map_err(|e| AppError::from(e).into_response())?;
+
+    Ok(Json(response))
+}
+
+/// Handler for PUT /api/v1/orders/{id}/hold
+///
+/// Takes the order out of the pick queue, dropping any claim.
+///
+/// # Request Body
+///
+/// ```json
+/// {
+///   "expected_version": 2,
+///   "reason": "Address needs confirmation"
+/// }
+/// ```
+pub async fn hold_order_pick_handler(
+    State(state): State<AppState>,
+    CurrentUser(ctx): CurrentUser,
+    Path(id): Path<Uuid>,
+    JsonBody(mut command): JsonBody<OrderPickCommand>,
+) -> Result<Json<OrderPickResponse>, Response> {
+    require_permission(&ctx, "orders:process")?;
+    let store_id = order_store_id(&state, id).await?;
+    verify_store_in_org(state.pool(), &ctx, store_id).await?;
+    command.sale_id = id;
+
+    let use_case = HoldOrderPickUseCase::new(state.order_pick_deps());
+
+    let response = use_case
+        .execute(command)
+        .await
+        .map_err(|e| AppError::from(e).into_response())?;
+
+    Ok(Json(response))
+}
+
+/// Handler for PUT /api/v1/orders/{id}/resume
+///
+/// Puts a held order back in the pick queue.
+pub async fn resume_order_pick_handler(
+    State(state): State<AppState>,
+    CurrentUser(ctx): CurrentUser,
+    Path(id): Path<Uuid>,
+    JsonBody(mut command): JsonBody<OrderPickCommand>,
+) -> Result<Json<OrderPickResponse>, Response> {
+    require_permission(&ctx, "orders:process")?;
+    let store_id = order_store_id(&state, id).await?;
+    verify_store_in_org(state.pool(), &ctx, store_id).await?;
+    command.sale_id = id;
+
+    let use_case = ResumeOrderPickUseCase::new(state.order_pick_deps());
+
+    let response = use_case
+        .execute(command)
+        .await
+        .map_err(|e| AppError::from(e).into_response())?;
+
+    Ok(Json(response))
+}
@@ -0,0 +1,70 @@
+// Exchange Rate Handlers
+//
+// REST endpoints for the rates stores take foreign currencies at:
+// - GET /api/v1/exchange-rates - List a store's rate history
+// - POST /api/v1/exchange-rates - Record a rate, effective now
+
+use axum::{
+    Json,
+    extract::{Query, State},
+    http::StatusCode,
+    response::{IntoResponse, Response},
+};
+use serde::Deserialize;
+use uuid::Uuid;
+
+use sales::{
+    ExchangeRateResponse, ListExchangeRatesUseCase, RecordExchangeRateCommand,
+    RecordExchangeRateUseCase,
+};
+
+use crate::error::AppError;
+use crate::extractors::{CurrentUser, JsonBody};
+use crate::middleware::org_scope::verify_store_in_org;
+use crate::middleware::permission::require_permission;
+use crate::state::AppState;
+
+#[derive(Debug, Deserialize)]
+pub struct ListExchangeRatesQueryParams {
+    pub store_id: Uuid,
+    pub currency: Option<String>,
+}
+
+/// Handler for GET /api/v1/exchange-rates
+pub async fn list_exchange_rates_handler(
+    State(state): State<AppState>,
+    CurrentUser(ctx): CurrentUser,
+    Query(params): Query<ListExchangeRatesQueryParams>,
+) -> Result<Json<Vec<ExchangeRateResponse>>, Response> {
+    require_permission(&ctx, "sales:read")?;
+    verify_store_in_org(state.pool(), &ctx, params.store_id).await?;
+
+    let use_case = ListExchangeRatesUseCase::new(state.exchange_rate_repo());
+
+    let response = use_case
+        .execute(params.store_id, params.currency)
+        .await
+        .map_err(|e| AppError::from(e).into_response())?;
+
+    Ok(Json(response))
+}
+
+/// Handler for POST /api/v1/exchange-rates
+pub async fn record_exchange_rate_handler(
+    State(state): State<AppState>,
+    CurrentUser(ctx): CurrentUser,
+    JsonBody(command): JsonBody<RecordExchangeRateCommand>,
+) -> Result<(StatusCode, Json<ExchangeRateResponse>), Response> {
+    require_permission(&ctx, "sales:manage_exchange_rates")?;
+    verify_store_in_org(state.pool(), &ctx, command.store_id).await?;
+
+    let use_case = RecordExchangeRateUseCase::new(state.exchange_rate_repo());
+
+    let actor_id = *ctx.user_id();
+    let response = use_case
+        .execute(command, actor_id)
+        .await
+        .map_err(|e| AppError::from(e).into_response())?;
+
+    Ok((StatusCode::CREATED, Json(response)))
+}
@@ -0,0 +1,66 @@
+// Gift Card Handlers
+//
+// REST endpoints for gift cards sold and redeemed at the POS:
+// - POST /api/v1/gift-cards - Issue a card, on its own or as a line of a sale
+// - GET /api/v1/gift-cards/{code}/balance - Check a card's balance
+
+use axum::{
+    Json,
+    extract::{Path, State},
+    http::StatusCode,
+    response::{IntoResponse, Response},
+};
+
+use sales::{
+    GetGiftCardBalanceUseCase, GiftCardBalanceResponse, GiftCardResponse, IssueGiftCardCommand,
+    IssueGiftCardUseCase,
+};
+
+use crate::error::AppError;
+use crate::extractors::{CurrentUser, JsonBody};
+use crate::middleware::org_scope::verify_store_in_org;
+use crate::middleware::permission::require_permission;
+use crate::state::AppState;
+
+/// Handler for POST /api/v1/gift-cards
+pub async fn issue_gift_card_handler(
+    State(state): State<AppState>,
+    CurrentUser(ctx): CurrentUser,
+    JsonBody(command): JsonBody<IssueGiftCardCommand>,
+) -> Result<(StatusCode, Json<GiftCardResponse>), Response> {
+    require_permission(&ctx, "gift_cards:issue")?;
+    verify_store_in_org(state.pool(), &ctx, command.store_id).await?;
+
+    let use_case = IssueGiftCardUseCase::new(
+        state.gift_card_repo(),
+        state.sale_repo(),
+        state.product_repo(),
+    );
+
+    let actor_id = *ctx.user_id();
+    let response = use_case
+        .execute(command, actor_id)
+        .await
+        .map_err(|e| AppError::from(e).into_response())?;
+
+    Ok((StatusCode::CREATED, Json(response)))
+}
+
+/// Handler for GET /api/v1/gift-cards/{code}/balance
+pub async fn get_gift_card_balance_handler(
+    State(state): State<AppState>,
+    CurrentUser(ctx): CurrentUser,
+    Path(code): Path<String>,
+) -> Result<Json<GiftCardBalanceResponse>, Response> {
+    require_permission(&ctx, "gift_cards:read")?;
+
+    let use_case = GetGiftCardBalanceUseCase::new(state.gift_card_repo());
+
+    let response = use_case
+        .execute(&code)
+        .await
+        .map_err(|e| AppError::from(e).into_response())?;
+    verify_store_in_org(state.pool(), &ctx, response.store_id).await?;
+
+    Ok(Json(response))
+}
@@ -0,0 +1,70 @@
+// Layaway Handlers
+//
+// REST endpoints for sales paid off in scheduled installments:
+// - GET /api/v1/sales/{id}/layaway - Check what is paid and still due
+// - POST /api/v1/sales/{id}/layaway/cancel - Cancel the layaway, releasing its stock
+
+use axum::{
+    Json,
+    extract::{Path, State},
+    response::{IntoResponse, Response},
+};
+use uuid::Uuid;
+
+use sales::{
+    CancelLayawayCommand, CancelLayawayUseCase, GetLayawayBalanceUseCase, LayawayBalanceResponse,
+    LayawayResponse,
+};
+
+use crate::error::AppError;
+use crate::extractors::{CurrentUser, JsonBody};
+use crate::middleware::org_scope::verify_store_in_org;
+use crate::middleware::permission::require_permission;
+use crate::state::AppState;
+
+/// Handler for GET /api/v1/sales/{id}/layaway
+pub async fn get_layaway_balance_handler(
+    State(state): State<AppState>,
+    CurrentUser(ctx): CurrentUser,
+    Path(sale_id): Path<Uuid>,
+) -> Result<Json<LayawayBalanceResponse>, Response> {
+    require_permission(&ctx, "sales:read")?;
+
+    let use_case = GetLayawayBalanceUseCase::new(state.sale_repo(), state.layaway_repo());
+
+    let response = use_case
+        .execute(sale_id)
+        .await
+        .map_err(|e| AppError::from(e).into_response())?;
+    verify_store_in_org(state.pool(), &ctx, response.store_id).await?;
+
+    Ok(Json(response))
+}
+
+/// Handler for POST /api/v1/sales/{id}/layaway/cancel
+///
+/// Voids the sale and releases the stock held for it; the restocking fee
+/// is kept from what the customer paid.
+pub async fn cancel_layaway_handler(
+    State(state): State<AppState>,
+    CurrentUser(ctx): CurrentUser,
+    Path(sale_id): Path<Uuid>,
+    JsonBody(command): JsonBody<CancelLayawayCommand>,
+) -> Result<Json<LayawayResponse>, Response> {
+    require_permission(&ctx, "sales:void")?;
+
+    let use_case = CancelLayawayUseCase::new(
+        state.sale_repo(),
+        state.customer_repo(),
+        state.layaway_repo(),
+        state.reservation_repo(),
+        state.stock_repo(),
+    );
+
+    let response = use_case
+        .execute(sale_id, command, *ctx.user_id())
+        .await
+        .map_err(|e| AppError::from(e).into_response())?;
+
+    Ok(Json(response))
+}
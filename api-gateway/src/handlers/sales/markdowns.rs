@@ -0,0 +1,214 @@
+// Markdown Handlers
+//
+// REST endpoints for automatic markdowns of aging / near-expiry stock:
+// - GET /api/v1/markdowns/rules - List markdown rules
+// - PUT /api/v1/markdowns/rules - Create or update the rule for a store/category
+// - GET /api/v1/markdowns - List markdowns (the markdown log)
+// - POST /api/v1/markdowns/run - Apply automatic markdowns now
+// - POST /api/v1/markdowns/{id}/reverse - Reverse an active markdown
+
+use axum::{
+    Json,
+    extract::{Path, Query, State},
+    response::{IntoResponse, Response},
+};
+use identity::StoreRepository;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use sales::{
+    ApplyAutoMarkdownsResult, ApplyAutoMarkdownsUseCase, ListMarkdownRulesUseCase,
+    ListMarkdownsQuery, ListMarkdownsUseCase, MarkdownResponse, MarkdownRuleResponse,
+    ReverseMarkdownCommand, ReverseMarkdownUseCase, UpsertMarkdownRuleCommand,
+    UpsertMarkdownRuleUseCase,
+};
+
+use crate::error::AppError;
+use crate::extractors::{CurrentUser, JsonBody};
+use crate::middleware::org_scope::verify_store_in_org;
+use crate::middleware::permission::require_permission;
+use crate::middleware::store_settings::load_store_settings;
+use crate::state::AppState;
+
+fn default_page() -> i64 {
+    1
+}
+
+fn default_page_size() -> i64 {
+    20
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ListMarkdownRulesQueryParams {
+    pub store_id: Option<Uuid>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ListMarkdownsQueryParams {
+    #[serde(default = "default_page")]
+    pub page: i64,
+    #[serde(default = "default_page_size")]
+    pub page_size: i64,
+    pub store_id: Option<Uuid>,
+    pub product_id: Option<Uuid>,
+    pub status: Option<String>,
+    /// `next_cursor` of a previous page; when set, `page` is ignored
+    pub after_cursor: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct PaginatedMarkdownResponse {
+    pub items: Vec<MarkdownResponse>,
+    pub page: i64,
+    pub page_size: i64,
+    pub total_items: i64,
+    pub total_pages: i64,
+    /// Pass back as `after_cursor` for the next page; `None` on the last page
+    pub next_cursor: Option<String>,
+}
+
+/// Body for the run endpoint; omit `store_id` to run every store's rules
+#[derive(Debug, Default, Deserialize)]
+pub struct RunMarkdownsBody {
+    pub store_id: Option<Uuid>,
+}
+
+/// Handler for GET /api/v1/markdowns/rules
+pub async fn list_markdown_rules_handler(
+    State(state): State<AppState>,
+    CurrentUser(ctx): CurrentUser,
+    Query(params): Query<ListMarkdownRulesQueryParams>,
+) -> Result<Json<Vec<MarkdownRuleResponse>>, Response> {
+    require_permission(&ctx, "promotions:read")?;
+    if let Some(sid) = params.store_id {
+        verify_store_in_org(state.pool(), &ctx, sid).await?;
+    }
+
+    let use_case = ListMarkdownRulesUseCase::new(state.markdown_repo());
+
+    let response = use_case
+        .execute(params.store_id)
+        .await
+        .map_err(|e| AppError::from(e).into_response())?;
+
+    Ok(Json(response))
+}
+
+/// Handler for PUT /api/v1/markdowns/rules
+pub async fn upsert_markdown_rule_handler(
+    State(state): State<AppState>,
+    CurrentUser(ctx): CurrentUser,
+    JsonBody(command): JsonBody<UpsertMarkdownRuleCommand>,
+) -> Result<Json<MarkdownRuleResponse>, Response> {
+    require_permission(&ctx, "promotions:update")?;
+    verify_store_in_org(state.pool(), &ctx, command.store_id).await?;
+
+    let use_case = UpsertMarkdownRuleUseCase::new(state.markdown_repo());
+
+    let actor_id = *ctx.user_id();
+    let response = use_case
+        .execute(command, actor_id)
+        .await
+        .map_err(|e| AppError::from(e).into_response())?;
+
+    Ok(Json(response))
+}
+
+/// Handler for GET /api/v1/markdowns
+pub async fn list_markdowns_handler(
+    State(state): State<AppState>,
+    CurrentUser(ctx): CurrentUser,
+    Query(params): Query<ListMarkdownsQueryParams>,
+) -> Result<Json<PaginatedMarkdownResponse>, Response> {
+    require_permission(&ctx, "promotions:read")?;
+    if let Some(sid) = params.store_id {
+        verify_store_in_org(state.pool(), &ctx, sid).await?;
+    }
+
+    let use_case = ListMarkdownsUseCase::new(state.markdown_repo());
+
+    let query = ListMarkdownsQuery {
+        store_id: params.store_id,
+        product_id: params.product_id,
+        status: params.status,
+        page: params.page,
+        page_size: params.page_size,
+        after_cursor: params.after_cursor.clone(),
+    };
+
+    let (items, total_items, next_cursor) = use_case
+        .execute(query)
+        .await
+        .map_err(|e| AppError::from(e).into_response())?;
+
+    let total_pages = if total_items == 0 {
+        1
+    } else {
+        (total_items + params.page_size - 1) / params.page_size
+    };
+
+    // A cursor page has no meaningful number
+    let page = if params.after_cursor.is_some() {
+        1
+    } else {
+        params.page
+    };
+
+    Ok(Json(PaginatedMarkdownResponse {
+        items,
+        page,
+        page_size: params.page_size,
+        total_items,
+        total_pages,
+        next_cursor,
+    }))
+}
+
+/// Handler for POST /api/v1/markdowns/run
+pub async fn run_markdowns_handler(
+    State(state): State<AppState>,
+    CurrentUser(ctx): CurrentUser,
+    JsonBody(body): JsonBody<RunMarkdownsBody>,
+) -> Result<Json<ApplyAutoMarkdownsResult>, Response> {
+    require_permission(&ctx, "promotions:update")?;
+    let settings = match body.store_id {
+        Some(sid) => {
+            verify_store_in_org(state.pool(), &ctx, sid).await?;
+            vec![load_store_settings(&state, sid).await?]
+        }
+        None => state
+            .store_repo()
+            .find_all_settings()
+            .await
+            .map_err(|e| AppError::from(e).into_response())?,
+    };
+
+    let use_case = ApplyAutoMarkdownsUseCase::new(state.markdown_repo(), state.promotion_repo());
+
+    let response = use_case
+        .execute(body.store_id, &settings)
+        .await
+        .map_err(|e| AppError::from(e).into_response())?;
+
+    Ok(Json(response))
+}
+
+/// Handler for POST /api/v1/markdowns/{id}/reverse
+pub async fn reverse_markdown_handler(
+    State(state): State<AppState>,
+    CurrentUser(ctx): CurrentUser,
+    Path(id): Path<Uuid>,
+    JsonBody(command): JsonBody<ReverseMarkdownCommand>,
+) -> Result<Json<MarkdownResponse>, Response> {
+    require_permission(&ctx, "promotions:update")?;
+
+    let use_case = ReverseMarkdownUseCase::new(state.markdown_repo(), state.promotion_repo());
+
+    let actor_id = *ctx.user_id();
+    let response = use_case
+        .execute(id, command, actor_id)
+        .await
+        .map_err(|e| AppError::from(e).into_response())?;
+
+    Ok(Json(response))
+}
@@ -4,20 +4,44 @@
 // - customers: Customer CRUD and status management
 // - shifts: Cashier shift lifecycle operations
 // - pos: Point-of-Sale transaction operations
+// - exchange_rates: Store rates for foreign currency tenders
+// - gift_cards: Gift cards sold and redeemed at the POS
+// - layaways: Sales paid off in scheduled installments
+// - markdowns: Automatic markdown rules and log
+// - price_lists: Customer-type price lists
+// - price_tiers: Customer-type pricing
+// - tax_rules: Tax rates per product, category and store
+// - weight_barcodes: Layouts of scale-printed weight and price labels
 
 pub mod cart;
 pub mod credit_notes;
 pub mod customers;
 pub mod ecommerce;
+pub mod exchange_rates;
+pub mod gift_cards;
+pub mod layaways;
+pub mod markdowns;
 pub mod pos;
+pub mod price_lists;
+pub mod price_tiers;
 pub mod promotions;
 pub mod shifts;
+pub mod tax_rules;
+pub mod weight_barcodes;
 
 // Re-export all handlers for easy access
 pub use cart::*;
 pub use credit_notes::*;
 pub use customers::*;
 pub use ecommerce::*;
+pub use exchange_rates::*;
+pub use gift_cards::*;
+pub use layaways::*;
+pub use markdowns::*;
 pub use pos::*;
+pub use price_lists::*;
+pub use price_tiers::*;
 pub use promotions::*;
 pub use shifts::*;
+pub use tax_rules::*;
+pub use weight_barcodes::*;
@@ -11,18 +11,29 @@ use serde::Deserialize;
 use uuid::Uuid;
 
 use crate::error::AppError;
+use crate::export::{ColumnKind, CsvExport, EXPORT_PAGE_SIZE, MAX_EXPORT_ROWS};
 use crate::extractors::{CurrentUser, JsonBody};
 use crate::middleware::org_scope::verify_store_in_org;
 use crate::middleware::permission::require_permission;
+use crate::middleware::store_settings::load_store_settings;
 use crate::state::AppState;
+use chrono::Utc;
+use identity::UserContext;
 use inventory::{
-    Currency, InventoryMovement, InventoryMovementRepository, InventoryStockRepository,
-    MovementType,
+    Currency, InventoryError, InventoryMovement, InventoryMovementRepository,
+    InventoryStockRepository, MovementType, RecipeConsumption, ResolveRecipeConsumptionUseCase,
 };
 use sales::{
-    AddSaleItemCommand, ApplyDiscountCommand, CreatePosSaleCommand, ListSalesQuery, Payment,
-    PaymentMethod, PgSaleRepository, PgShiftRepository, ProcessPaymentCommand, SaleDetailResponse,
-    SaleId, SaleListResponse, SaleRepository, ShiftRepository, VoidSaleCommand,
+    AddSaleItemCommand, ApplyDiscountCommand, BarcodeLookupQuery, BarcodeLookupResponse,
+    CashierShift, CreatePosSaleCommand, CustomerAccountEntry, CustomerId, CustomerRepository,
+    GiftCardRedemption, IdempotentOperation, Layaway, ListSalesQuery, Payment, PaymentMethod,
+    PgCustomerRepository, PgGiftCardRepository, PgLayawayRepository, PgSaleIdempotencyRepository,
+    PgSaleRepository, PgShiftRepository, ProcessPaymentCommand, QuickSaleCommand, Sale,
+    SaleDetailResponse, SaleId, SaleIdempotencyKey, SaleListResponse, SaleRepository,
+    SerialSaleResponse, SetSaleCurrencyCommand, ShiftRepository, VoidSaleCommand,
+    apply_layaway_payment, gift_card_tenders, idempotency_key_for, is_idempotent_replay,
+    prepare_layaway_payment, release_sale_reservations, reserve_sale_stock, tender_payments,
+    tender_rates,
 };
 
 /// Extended request for adding a sale item.
@@ -38,6 +49,9 @@ pub struct AddSaleItemRequest {
     pub unit_cost: Decimal,
     pub tax_rate: Decimal,
     pub unit_of_measure: String,
+    /// Serials of the units sold, required for serial-tracked products
+    #[serde(default)]
+    pub serial_numbers: Vec<String>,
     pub notes: Option<String>,
 }
 
@@ -57,7 +71,11 @@ pub async fn create_pos_sale_handler(
     require_permission(&ctx, "sales:create")?;
     verify_store_in_org(state.pool(), &ctx, command.store_id).await?;
 
-    let use_case = sales::CreatePosSaleUseCase::new(state.sale_repo(), state.shift_repo());
+    let use_case = sales::CreatePosSaleUseCase::new(
+        state.sale_repo(),
+        state.shift_repo(),
+        state.layaway_repo(),
+    );
 
     let response = use_case
         .execute(command, *ctx.user_id())
@@ -75,7 +93,25 @@ pub async fn add_sale_item_handler(
 ) -> Result<(StatusCode, Json<SaleDetailResponse>), Response> {
     require_permission(&ctx, "sales:create")?;
 
-    let use_case = sales::AddSaleItemUseCase::new(state.sale_repo());
+    let sale = state
+        .sale_repo()
+        .find_by_id(SaleId::from_uuid(sale_id))
+        .await
+        .map_err(|e| AppError::from(e).into_response())?
+        .ok_or_else(|| AppError::from(sales::SalesError::SaleNotFound(sale_id)).into_response())?;
+    let settings = load_store_settings(&state, sale.store_id().into_uuid()).await?;
+
+    let use_case = sales::AddSaleItemUseCase::new(
+        state.sale_repo(),
+        state.customer_repo(),
+        state.product_repo(),
+        state.product_serial_repo(),
+        state.price_tier_repo(),
+        state.price_list_repo(),
+        state.recipe_repo(),
+        state.stock_repo(),
+    )
+    .with_allow_negative_stock(settings.allow_negative_stock());
 
     let command = AddSaleItemCommand {
         sale_id,
@@ -83,6 +119,7 @@ pub async fn add_sale_item_handler(
         variant_id: req.variant_id,
         quantity: req.quantity,
         unit_price: req.unit_price,
+        serial_numbers: req.serial_numbers,
         notes: req.notes,
     };
 
@@ -96,7 +133,6 @@ pub async fn add_sale_item_handler(
             command,
             req.sku,
             req.description,
-            req.unit_price.unwrap_or(req.unit_cost),
             req.unit_cost,
             req.tax_rate,
             uom,
@@ -179,33 +215,7 @@ pub async fn process_payment_handler(
 
     command.sale_id = sale_id;
 
-    // Idempotency check: if key provided, look for existing payment
-    if let Some(ref key) = command.idempotency_key
-        && let Some(existing_payment) = state
-            .sale_repo()
-            .find_payment_by_idempotency_key(key)
-            .await
-            .map_err(|e| AppError::from(e).into_response())?
-    {
-        let sale = state
-            .sale_repo()
-            .find_by_id_with_details(existing_payment.sale_id())
-            .await
-            .map_err(|e| AppError::from(e).into_response())?
-            .ok_or_else(|| {
-                AppError::from(sales::SalesError::SaleNotFound(
-                    existing_payment.sale_id().into_uuid(),
-                ))
-                .into_response()
-            })?;
-        return Ok((StatusCode::CREATED, Json(SaleDetailResponse::from(sale))));
-    }
-
     let sale_id_vo = SaleId::from_uuid(command.sale_id);
-    let payment_method: PaymentMethod = command
-        .payment_method
-        .parse()
-        .map_err(|_| AppError::from(sales::SalesError::InvalidPaymentMethod).into_response())?;
 
     let mut sale = state
         .sale_repo()
@@ -216,37 +226,140 @@ pub async fn process_payment_handler(
             AppError::from(sales::SalesError::SaleNotFound(command.sale_id)).into_response()
         })?;
 
-    if !sale.is_editable() {
+    // Keys are kept per terminal for 24 hours; a retry of a payment the
+    // terminal already sent returns the sale as it stands
+    let idempotency = command
+        .idempotency_key
+        .as_deref()
+        .map(|key| idempotency_key_for(&sale, key, IdempotentOperation::Payment))
+        .transpose()
+        .map_err(|e| AppError::from(e).into_response())?;
+    if let Some(record) = &idempotency
+        && is_idempotent_replay(state.sale_idempotency_repo().as_ref(), record)
+            .await
+            .map_err(|e| AppError::from(e).into_response())?
+    {
+        return Ok((StatusCode::CREATED, Json(SaleDetailResponse::from(sale))));
+    }
+
+    if !sale.accepts_payments() {
         return Err(AppError::from(sales::SalesError::SaleNotEditable).into_response());
     }
+    // A layaway's deposit puts its sale on layaway
+    let mut layaway = prepare_layaway_payment(state.layaway_repo().as_ref(), &mut sale)
+        .await
+        .map_err(|e| AppError::from(e).into_response())?;
 
-    // Create the payment
-    let mut payment = if payment_method == PaymentMethod::Cash {
-        let tendered = command.amount_tendered.unwrap_or(command.amount);
-        Payment::create_cash(
-            sale_id_vo,
-            command.amount,
-            sale.currency().clone(),
-            tendered,
-        )
-        .map_err(|e| AppError::from(e).into_response())?
-    } else {
-        Payment::create(
-            sale_id_vo,
-            payment_method,
-            command.amount,
-            sale.currency().clone(),
-        )
-        .map_err(|e| AppError::from(e).into_response())?
-    };
+    // Create one payment per tender, converting foreign tenders at the
+    // store's current rate
+    let rates = tender_rates(
+        state.exchange_rate_repo().as_ref(),
+        &sale,
+        &command.allocations,
+    )
+    .await
+    .map_err(|e| AppError::from(e).into_response())?;
+    let mut payments = tender_payments(&sale, &command.allocations, &rates)
+        .map_err(|e| AppError::from(e).into_response())?;
+    // Gift card tenders beyond the card's balance only spend the balance
+    let gift_cards = gift_card_tenders(
+        state.gift_card_repo().as_ref(),
+        &sale,
+        &command.allocations,
+        &mut payments,
+    )
+    .await
+    .map_err(|e| AppError::from(e).into_response())?;
+    for payment in &mut payments {
+        payment.set_notes(command.notes.clone());
+    }
 
-    payment.set_reference_number(command.reference.clone());
-    payment.set_notes(command.notes.clone());
-    payment.set_idempotency_key(command.idempotency_key.clone());
+    // On-account tenders charge the customer's balance. The credit limit is
+    // checked here for a clear error and re-checked atomically when posting.
+    let mut account_entries = Vec::new();
+    for payment in payments
+        .iter()
+        .filter(|p| p.payment_method().is_on_account())
+    {
+        let customer_id = sale.customer_id().ok_or_else(|| {
+            AppError::from(sales::SalesError::OnAccountRequiresCustomer).into_response()
+        })?;
+        let customer = state
+            .customer_repo()
+            .find_by_id(customer_id)
+            .await
+            .map_err(|e| AppError::from(e).into_response())?
+            .ok_or_else(|| {
+                AppError::from(sales::SalesError::CustomerNotFound(customer_id.into_uuid()))
+                    .into_response()
+            })?;
+        customer
+            .ensure_can_charge(payment.base_amount())
+            .map_err(|e| AppError::from(e).into_response())?;
+        account_entries.push(
+            CustomerAccountEntry::charge(
+                customer.id(),
+                sale.store_id(),
+                payment.base_amount(),
+                sale.id().into_uuid(),
+                sale.sale_number().to_string(),
+                Some(*ctx.user_id()),
+            )
+            .map_err(|e| AppError::from(e).into_response())?,
+        );
+    }
+
+    // Store credit tenders spend the customer's store credit. The balance is
+    // checked here for a clear error and re-checked atomically when debiting.
+    let mut store_credit_spends = Vec::new();
+    for payment in payments
+        .iter()
+        .filter(|p| p.payment_method() == PaymentMethod::StoreCredit)
+    {
+        let customer_id = sale.customer_id().ok_or_else(|| {
+            AppError::from(sales::SalesError::StoreCreditRequiresCustomer).into_response()
+        })?;
+        let customer = state
+            .customer_repo()
+            .find_by_id(customer_id)
+            .await
+            .map_err(|e| AppError::from(e).into_response())?
+            .ok_or_else(|| {
+                AppError::from(sales::SalesError::CustomerNotFound(customer_id.into_uuid()))
+                    .into_response()
+            })?;
+        customer
+            .ensure_can_spend_store_credit(payment.base_amount())
+            .map_err(|e| AppError::from(e).into_response())?;
+        store_credit_spends.push((customer.id(), payment.base_amount()));
+    }
 
-    sale.add_payment(payment.clone())
+    // Partial tenders leave the sale partially paid
+    let paid_before = sale.amount_paid();
+    sale.add_payments(payments.clone())
         .map_err(|e| AppError::from(e).into_response())?;
 
+    // Schedule what a layaway deposit leaves due, holding the stock until
+    // the layaway is due, or apply an installment
+    let mut holds_stock = false;
+    if let Some(layaway) = &mut layaway {
+        let paid = sale.amount_paid() - paid_before;
+        let hold_until = apply_layaway_payment(layaway, &sale, paid, Utc::now().date_naive())
+            .map_err(|e| AppError::from(e).into_response())?;
+        if let Some(hold_until) = hold_until {
+            reserve_sale_stock(
+                &state.reservation_repo(),
+                &state.stock_repo(),
+                &state.reservation_priority_policy(),
+                &sale,
+                hold_until,
+            )
+            .await
+            .map_err(|e| AppError::from(e).into_response())?;
+            holds_stock = true;
+        }
+    }
+
     // Find shift before transaction to minimize TX duration
     let shift_update = if let Some(shift_id) = sale.shift_id() {
         state
@@ -255,49 +368,111 @@ pub async fn process_payment_handler(
             .await
             .map_err(|e| AppError::from(e).into_response())?
             .map(|mut shift| {
-                let result = match payment_method {
-                    PaymentMethod::Cash => shift.record_cash_sale(command.amount),
-                    PaymentMethod::CreditCard | PaymentMethod::DebitCard => {
-                        shift.record_card_sale(command.amount)
-                    }
-                    _ => shift.record_other_sale(command.amount),
-                };
-                result.map(|()| shift)
+                for payment in &payments {
+                    match payment.payment_method() {
+                        PaymentMethod::Cash => shift.record_cash_sale(payment.base_amount()),
+                        PaymentMethod::CreditCard | PaymentMethod::DebitCard => {
+                            shift.record_card_sale(payment.base_amount())
+                        }
+                        _ => shift.record_other_sale(payment.base_amount()),
+                    }?;
+                }
+                Ok(shift)
             })
             .transpose()
-            .map_err(|e| AppError::from(e).into_response())?
+            .map_err(|e: sales::SalesError| AppError::from(e).into_response())?
     } else {
         None
     };
 
-    // All writes in a single transaction
-    let mut tx = state
-        .pool()
-        .begin()
-        .await
-        .map_err(|e| AppError::from(sales::SalesError::from(e)).into_response())?;
+    // All writes in a single transaction; a layaway deposit that fails to
+    // save gives back the stock it reserved
+    let written = save_payment(
+        &state,
+        &sale,
+        &payments,
+        &mut account_entries,
+        &store_credit_spends,
+        &gift_cards,
+        layaway.as_ref(),
+        shift_update.as_ref(),
+        idempotency.as_ref(),
+    )
+    .await;
+    if let Err(e) = written {
+        if holds_stock {
+            let _ = release_held_stock(&state, sale_id).await;
+        }
+        return Err(AppError::from(e).into_response());
+    }
 
-    PgSaleRepository::save_payment_in_tx(&mut tx, &payment)
-        .await
-        .map_err(|e| AppError::from(e).into_response())?;
+    // Paying off a layaway completes its sale and takes its stock
+    if layaway.is_some() && sale.amount_due() <= Decimal::ZERO {
+        let invoice_number = command
+            .invoice_number
+            .unwrap_or_else(|| sale.sale_number().to_string());
+        let response =
+            complete_and_deduct_stock(&state, &ctx, sale_id, invoice_number, None).await?;
+        return Ok((StatusCode::CREATED, Json(response)));
+    }
 
-    PgSaleRepository::update_in_tx(&mut tx, &sale)
-        .await
-        .map_err(|e| AppError::from(e).into_response())?;
+    Ok((StatusCode::CREATED, Json(SaleDetailResponse::from(sale))))
+}
 
-    if let Some(shift) = &shift_update {
-        PgShiftRepository::update_in_tx(&mut tx, shift)
-            .await
-            .map_err(|e| AppError::from(e).into_response())?;
+/// Writes a payment taken on a sale in one transaction: customer charges,
+/// gift card redemptions, the payments, the sale and its layaway, shift
+/// totals and the idempotency record
+#[allow(clippy::too_many_arguments)]
+async fn save_payment(
+    state: &AppState,
+    sale: &Sale,
+    payments: &[Payment],
+    account_entries: &mut [CustomerAccountEntry],
+    store_credit_spends: &[(CustomerId, Decimal)],
+    gift_cards: &[GiftCardRedemption],
+    layaway: Option<&Layaway>,
+    shift: Option<&CashierShift>,
+    idempotency: Option<&SaleIdempotencyKey>,
+) -> Result<(), sales::SalesError> {
+    let mut tx = state.pool().begin().await?;
+
+    for entry in account_entries.iter_mut() {
+        PgCustomerRepository::post_account_entry_in_tx(&mut tx, entry).await?;
+    }
+    for (customer_id, amount) in store_credit_spends {
+        PgCustomerRepository::adjust_store_credit_in_tx(&mut tx, *customer_id, -*amount).await?;
+    }
+    // Locks each card's row, so a concurrent payment can't spend it twice
+    for (card_id, amount) in gift_cards {
+        PgGiftCardRepository::redeem_in_tx(&mut tx, *card_id, *amount).await?;
     }
 
-    tx.commit()
-        .await
-        .map_err(|e| AppError::from(sales::SalesError::from(e)).into_response())?;
+    for payment in payments {
+        PgSaleRepository::save_payment_in_tx(&mut tx, payment).await?;
+    }
 
-    Ok((StatusCode::CREATED, Json(SaleDetailResponse::from(sale))))
+    PgSaleRepository::update_in_tx(&mut tx, sale).await?;
+
+    if let Some(layaway) = layaway {
+        PgLayawayRepository::update_in_tx(&mut tx, layaway).await?;
+    }
+
+    if let Some(shift) = shift {
+        PgShiftRepository::update_in_tx(&mut tx, shift).await?;
+    }
+
+    if let Some(record) = idempotency {
+        PgSaleIdempotencyRepository::save_in_tx(&mut tx, record).await?;
+    }
+
+    tx.commit().await?;
+    Ok(())
 }
 
+/// Completes a POS sale and deducts its stock. A retry carrying an
+/// `idempotency_key` the sale's terminal already completed it with (within
+/// 24 hours) returns the sale without deducting stock again. Sales opened in
+/// a closed inventory period need inventory:reopen_period.
 pub async fn complete_sale_handler(
     State(state): State<AppState>,
     CurrentUser(ctx): CurrentUser,
@@ -311,24 +486,108 @@ pub async fn complete_sale_handler(
         .and_then(|v| v.as_str())
         .unwrap_or("")
         .to_string();
+    let idempotency_key = body
+        .get("idempotency_key")
+        .and_then(|v| v.as_str())
+        .map(str::to_string);
 
-    let use_case = sales::CompleteSaleUseCase::new(state.sale_repo());
+    // Idempotent replay: the stock was deducted by the first request
+    if let Some(key) = &idempotency_key {
+        let sale = state
+            .sale_repo()
+            .find_by_id_with_details(SaleId::from_uuid(sale_id))
+            .await
+            .map_err(|e| AppError::from(e).into_response())?
+            .ok_or_else(|| {
+                AppError::from(sales::SalesError::SaleNotFound(sale_id)).into_response()
+            })?;
+        let record = idempotency_key_for(&sale, key, IdempotentOperation::Complete)
+            .map_err(|e| AppError::from(e).into_response())?;
+        if is_idempotent_replay(state.sale_idempotency_repo().as_ref(), &record)
+            .await
+            .map_err(|e| AppError::from(e).into_response())?
+        {
+            return Ok(Json(SaleDetailResponse::from(sale)));
+        }
+    }
+
+    let response =
+        complete_and_deduct_stock(&state, &ctx, sale_id, invoice_number, idempotency_key).await?;
+
+    Ok(Json(response))
+}
+
+/// Completes a sale, then deducts the stock of its lines, drawing composite
+/// lines from their recipe ingredients
+async fn complete_and_deduct_stock(
+    state: &AppState,
+    ctx: &UserContext,
+    sale_id: Uuid,
+    invoice_number: String,
+    idempotency_key: Option<String>,
+) -> Result<SaleDetailResponse, Response> {
+    let use_case = sales::CompleteSaleUseCase::new(
+        state.sale_repo(),
+        state.sale_idempotency_repo(),
+        state.exchange_rate_repo(),
+        state.product_repo(),
+        state.product_serial_repo(),
+    )
+    .with_period_lock(
+        state.inventory_period_repo(),
+        ctx.has_permission("inventory:reopen_period"),
+    )
+    .with_gift_cards(state.gift_card_repo())
+    .with_layaways(state.layaway_repo())
+    .with_tax_rules(
+        state.customer_repo(),
+        state.tax_rule_repo(),
+        state.category_repo(),
+    )
+    .with_cai_numbering();
 
     let response = use_case
-        .execute(sale_id, invoice_number)
+        .execute(sale_id, invoice_number, idempotency_key)
         .await
         .map_err(|e| AppError::from(e).into_response())?;
 
+    // Stock held for a resumed or layaway sale is deducted below, so drop
+    // the hold
+    release_held_stock(state, sale_id).await?;
+
     // Deduct inventory stock for each sale item
     let stock_repo = state.stock_repo();
     let movement_repo = state.movement_repo();
     let actor_id = *ctx.user_id();
     let store_id = identity::StoreId::from_uuid(response.store_id);
+    let settings = load_store_settings(state, response.store_id).await?;
 
     for item in &response.items {
         let product_id = inventory::ProductId::from_uuid(item.product_id);
         let variant_id = item.variant_id.map(inventory::VariantId::from_uuid);
 
+        // Composite products consume their ingredients instead
+        let consumption = ResolveRecipeConsumptionUseCase::new(
+            state.recipe_repo(),
+            state.stock_repo(),
+            state.product_repo(),
+        )
+        .with_allow_negative_stock(settings.allow_negative_stock())
+        .execute(store_id, product_id, variant_id, item.quantity)
+        .await
+        .map_err(|e| AppError::from(e).into_response())?;
+        if let Some(consumption) = consumption {
+            deduct_recipe_ingredients(
+                state,
+                &consumption,
+                sale_id,
+                actor_id,
+                settings.allow_negative_stock(),
+            )
+            .await?;
+            continue;
+        }
+
         // Find stock record
         let existing = if let Some(vid) = variant_id {
             stock_repo
@@ -345,7 +604,7 @@ pub async fn complete_sale_handler(
         if let Some(mut stock) = existing {
             let expected_version = stock.version();
             stock
-                .adjust_quantity(-item.quantity)
+                .adjust_quantity_with_policy(-item.quantity, settings.allow_negative_stock())
                 .map_err(|e| AppError::from(e).into_response())?;
             stock.increment_version();
 
@@ -357,7 +616,7 @@ pub async fn complete_sale_handler(
             // Create sale movement
             let movement = InventoryMovement::create(
                 stock.id(),
-                MovementType::Out,
+                MovementType::for_sale(stock.quantity()),
                 Some("Sale completed".to_string()),
                 -item.quantity,
                 Some(item.unit_price),
@@ -376,7 +635,107 @@ pub async fn complete_sale_handler(
         // If no stock record exists, skip (product may not be trackable)
     }
 
-    Ok(Json(response))
+    Ok(response)
+}
+
+/// Takes a composite sale line's ingredients off the store's stock, one
+/// movement per ingredient or substitute drawn
+async fn deduct_recipe_ingredients(
+    state: &AppState,
+    consumption: &RecipeConsumption,
+    sale_id: Uuid,
+    actor_id: identity::UserId,
+    allow_negative_stock: bool,
+) -> Result<(), Response> {
+    let stock_repo = state.stock_repo();
+    let movement_repo = state.movement_repo();
+
+    for draw in &consumption.draws {
+        let mut stock = stock_repo
+            .find_by_id(draw.stock_id)
+            .await
+            .map_err(|e| AppError::from(e).into_response())?
+            .ok_or_else(|| {
+                AppError::from(InventoryError::StockNotFound(draw.stock_id.into_uuid()))
+                    .into_response()
+            })?;
+        let expected_version = stock.version();
+        stock
+            .adjust_quantity_with_policy(-draw.quantity, allow_negative_stock)
+            .map_err(|e| AppError::from(e).into_response())?;
+        stock.increment_version();
+
+        stock_repo
+            .update_with_version(&stock, expected_version)
+            .await
+            .map_err(|e| AppError::from(e).into_response())?;
+
+        let reason = match draw.substitute_id {
+            Some(_) => "Recipe ingredient substitute",
+            None => "Recipe ingredient",
+        };
+        let movement = InventoryMovement::create(
+            stock.id(),
+            MovementType::for_sale(stock.quantity()),
+            Some(reason.to_string()),
+            -draw.quantity,
+            None,
+            Currency::hnl(),
+            stock.quantity(),
+            Some("sale".to_string()),
+            Some(sale_id),
+            actor_id,
+            None,
+        );
+        movement_repo
+            .save(&movement)
+            .await
+            .map_err(|e| AppError::from(e).into_response())?;
+    }
+    Ok(())
+}
+
+/// Rings up a single scanned item paid in cash: creates, pays, completes
+/// and deducts stock in one transaction. Needs every permission of the
+/// regular flow it replaces.
+pub async fn quick_sale_handler(
+    State(state): State<AppState>,
+    CurrentUser(ctx): CurrentUser,
+    JsonBody(command): JsonBody<QuickSaleCommand>,
+) -> Result<(StatusCode, Json<SaleDetailResponse>), Response> {
+    require_permission(&ctx, "sales:create")?;
+    require_permission(&ctx, "sales:process_payment")?;
+    require_permission(&ctx, "sales:complete")?;
+    verify_store_in_org(state.pool(), &ctx, command.store_id).await?;
+
+    let settings = load_store_settings(&state, command.store_id).await?;
+    let use_case = sales::QuickSaleUseCase::new(
+        state.sale_repo(),
+        state.shift_repo(),
+        state.quick_sale_repo(),
+        state.product_repo(),
+        state.stock_repo(),
+        state.price_tier_repo(),
+        state.price_list_repo(),
+        state.recipe_repo(),
+        state.sale_idempotency_repo(),
+    )
+    .with_period_lock(
+        state.inventory_period_repo(),
+        ctx.has_permission("inventory:reopen_period"),
+    )
+    .with_tax_rules(
+        state.customer_repo(),
+        state.tax_rule_repo(),
+        state.category_repo(),
+    );
+
+    let response = use_case
+        .execute(command, *ctx.user_id(), settings.allow_negative_stock())
+        .await
+        .map_err(|e| AppError::from(e).into_response())?;
+
+    Ok((StatusCode::CREATED, Json(response)))
 }
 
 pub async fn void_sale_handler(
@@ -390,13 +749,102 @@ pub async fn void_sale_handler(
     let mut cmd = command;
     cmd.sale_id = sale_id;
 
-    let use_case = sales::VoidSaleUseCase::new(state.sale_repo());
+    let use_case = sales::VoidSaleUseCase::new(state.sale_repo(), state.customer_repo());
 
     let response = use_case
         .execute(cmd, *ctx.user_id())
         .await
         .map_err(|e| AppError::from(e).into_response())?;
 
+    release_held_stock(&state, sale_id).await?;
+
+    Ok(Json(response))
+}
+
+/// Releases the stock still reserved for a sale that was completed or voided
+async fn release_held_stock(state: &AppState, sale_id: Uuid) -> Result<(), Response> {
+    let sale = state
+        .sale_repo()
+        .find_by_id_with_details(SaleId::from_uuid(sale_id))
+        .await
+        .map_err(|e| AppError::from(e).into_response())?
+        .ok_or_else(|| AppError::from(sales::SalesError::SaleNotFound(sale_id)).into_response())?;
+
+    release_sale_reservations(
+        state.reservation_repo().as_ref(),
+        state.stock_repo().as_ref(),
+        &sale,
+    )
+    .await
+    .map_err(|_| AppError::from(sales::SalesError::ReservationCancelFailed).into_response())?;
+    Ok(())
+}
+
+/// Parks a sale so the cashier can ring up another one. The sale keeps its
+/// items; the stock reserved for it is released.
+pub async fn park_sale_handler(
+    State(state): State<AppState>,
+    CurrentUser(ctx): CurrentUser,
+    Path(sale_id): Path<Uuid>,
+) -> Result<Json<SaleDetailResponse>, Response> {
+    require_permission(&ctx, "sales:create")?;
+
+    let use_case = sales::ParkSaleUseCase::new(
+        state.sale_repo(),
+        state.reservation_repo(),
+        state.stock_repo(),
+    );
+
+    let response = use_case
+        .execute(sale_id)
+        .await
+        .map_err(|e| AppError::from(e).into_response())?;
+
+    Ok(Json(response))
+}
+
+/// Quotes an open sale in another currency at the store's current rate
+pub async fn set_sale_currency_handler(
+    State(state): State<AppState>,
+    CurrentUser(ctx): CurrentUser,
+    Path(sale_id): Path<Uuid>,
+    JsonBody(mut command): JsonBody<SetSaleCurrencyCommand>,
+) -> Result<Json<SaleDetailResponse>, Response> {
+    require_permission(&ctx, "sales:update")?;
+
+    let use_case =
+        sales::SetSaleCurrencyUseCase::new(state.sale_repo(), state.exchange_rate_repo());
+
+    command.sale_id = sale_id;
+    let response = use_case
+        .execute(command)
+        .await
+        .map_err(|e| AppError::from(e).into_response())?;
+
+    Ok(Json(response))
+}
+
+/// Resumes a parked sale, reserving its stock again. Fails without
+/// reserving anything when a line is out of stock.
+pub async fn resume_sale_handler(
+    State(state): State<AppState>,
+    CurrentUser(ctx): CurrentUser,
+    Path(sale_id): Path<Uuid>,
+) -> Result<Json<SaleDetailResponse>, Response> {
+    require_permission(&ctx, "sales:create")?;
+
+    let use_case = sales::ResumeSaleUseCase::new(
+        state.sale_repo(),
+        state.reservation_repo(),
+        state.stock_repo(),
+        state.reservation_priority_policy(),
+    );
+
+    let response = use_case
+        .execute(sale_id)
+        .await
+        .map_err(|e| AppError::from(e).into_response())?;
+
     Ok(Json(response))
 }
 
@@ -410,13 +858,169 @@ pub async fn get_sale_handler(
     let use_case = sales::GetSaleUseCase::new(state.sale_repo());
 
     let response = use_case
-        .execute(id)
+        .execute(id, &ctx)
+        .await
+        .map_err(|e| AppError::from(e).into_response())?;
+
+    Ok(Json(response))
+}
+
+/// Resolves a scanned barcode to the product or variant to ring up, with
+/// its price and the stock at the terminal's store.
+pub async fn lookup_barcode_handler(
+    State(state): State<AppState>,
+    CurrentUser(ctx): CurrentUser,
+    Path(barcode): Path<String>,
+    Query(query): Query<BarcodeLookupQuery>,
+) -> Result<Json<BarcodeLookupResponse>, Response> {
+    require_permission(&ctx, "sales:create")?;
+
+    let use_case = sales::FindProductByBarcodeUseCase::new(
+        state.product_repo(),
+        state.stock_repo(),
+        state.terminal_repo(),
+        state.weight_barcode_format_repo(),
+    );
+
+    let response = use_case
+        .execute(&barcode, query.terminal_id)
         .await
         .map_err(|e| AppError::from(e).into_response())?;
+    verify_store_in_org(state.pool(), &ctx, response.store_id).await?;
 
     Ok(Json(response))
 }
 
+/// Finds the sale a serialized unit was sold on, for warranty claims.
+/// Sales of stores outside the caller's organization are left out.
+pub async fn lookup_serial_sale_handler(
+    State(state): State<AppState>,
+    CurrentUser(ctx): CurrentUser,
+    Path(serial_number): Path<String>,
+) -> Result<Json<Vec<SerialSaleResponse>>, Response> {
+    require_permission(&ctx, "sales:read")?;
+
+    let use_case =
+        sales::LookupSerialSaleUseCase::new(state.sale_repo(), state.product_serial_repo());
+
+    let results = use_case
+        .execute(&serial_number)
+        .await
+        .map_err(|e| AppError::from(e).into_response())?;
+
+    let mut visible = Vec::with_capacity(results.len());
+    for result in results {
+        if verify_store_in_org(state.pool(), &ctx, result.store_id)
+            .await
+            .is_ok()
+        {
+            visible.push(result);
+        }
+    }
+    if visible.is_empty() {
+        return Err(
+            AppError::from(sales::SalesError::SerialSaleNotFound(serial_number)).into_response(),
+        );
+    }
+
+    Ok(Json(visible))
+}
+
+/// Filters for a sales CSV export (store is required)
+#[derive(Debug, Deserialize)]
+pub struct ExportSalesQuery {
+    pub store_id: Uuid,
+    pub terminal_id: Option<Uuid>,
+    pub sale_type: Option<String>,
+    pub status: Option<String>,
+    pub date_from: Option<String>,
+    pub date_to: Option<String>,
+}
+
+/// Exports a store's sales as CSV; every export is audited.
+pub async fn export_sales_handler(
+    State(state): State<AppState>,
+    CurrentUser(ctx): CurrentUser,
+    Query(query): Query<ExportSalesQuery>,
+) -> Result<Response, Response> {
+    require_permission(&ctx, "sales:export")?;
+    verify_store_in_org(state.pool(), &ctx, query.store_id).await?;
+
+    let use_case = sales::ListSalesUseCase::new(state.sale_repo());
+
+    let mut export = CsvExport::new(
+        "sales",
+        vec![
+            ("sale_number", ColumnKind::Plain),
+            ("created_at", ColumnKind::Plain),
+            ("sale_type", ColumnKind::Plain),
+            ("status", ColumnKind::Plain),
+            ("terminal_id", ColumnKind::Plain),
+            ("cashier_id", ColumnKind::Plain),
+            ("customer_id", ColumnKind::Plain),
+            ("invoice_number", ColumnKind::Plain),
+            ("item_count", ColumnKind::Plain),
+            ("subtotal", ColumnKind::Plain),
+            ("discount_amount", ColumnKind::Plain),
+            ("tax_amount", ColumnKind::Plain),
+            ("total", ColumnKind::Plain),
+            ("currency", ColumnKind::Plain),
+        ],
+    );
+
+    // Follow cursors rather than page numbers so sales completed while the
+    // export runs don't shift rows between pages
+    let mut after_cursor = None;
+    loop {
+        let result = use_case
+            .execute(
+                ListSalesQuery {
+                    store_id: Some(query.store_id),
+                    terminal_id: query.terminal_id,
+                    sale_type: query.sale_type.clone(),
+                    status: query.status.clone(),
+                    date_from: query.date_from.clone(),
+                    date_to: query.date_to.clone(),
+                    page_size: Some(EXPORT_PAGE_SIZE),
+                    after_cursor: after_cursor.take(),
+                    ..Default::default()
+                },
+                &ctx,
+            )
+            .await
+            .map_err(|e| AppError::from(e).into_response())?;
+
+        let uuid_cell = |id: Option<Uuid>| id.map(|id| id.to_string()).unwrap_or_default();
+        for s in result.items {
+            export.push_row(vec![
+                s.sale_number,
+                s.created_at.to_rfc3339(),
+                s.sale_type,
+                s.status,
+                uuid_cell(s.terminal_id),
+                uuid_cell(s.cashier_id),
+                uuid_cell(s.customer_id),
+                s.invoice_number.unwrap_or_default(),
+                s.item_count.to_string(),
+                s.subtotal.to_string(),
+                s.discount_amount.to_string(),
+                s.tax_amount.to_string(),
+                s.total.to_string(),
+                s.currency,
+            ]);
+        }
+
+        match result.next_cursor {
+            Some(next) if export.row_count() < MAX_EXPORT_ROWS => after_cursor = Some(next),
+            _ => break,
+        }
+    }
+
+    export
+        .into_response(state.audit_repo(), &ctx, Some(query.store_id))
+        .await
+}
+
 pub async fn list_sales_handler(
     State(state): State<AppState>,
     CurrentUser(ctx): CurrentUser,
@@ -430,7 +1034,7 @@ pub async fn list_sales_handler(
     let use_case = sales::ListSalesUseCase::new(state.sale_repo());
 
     let response = use_case
-        .execute(query)
+        .execute(query, &ctx)
         .await
         .map_err(|e| AppError::from(e).into_response())?;
 
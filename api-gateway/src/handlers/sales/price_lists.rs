@@ -0,0 +1,99 @@
+// Price List Handlers
+//
+// REST endpoints for customer-type price lists:
+// - GET /api/v1/price-lists - List a store's price lists
+// - POST /api/v1/price-lists - Create a price list
+// - PUT /api/v1/price-lists/{id}/entries - Set a product or variant price
+
+use axum::{
+    Json,
+    extract::{Path, Query, State},
+    http::StatusCode,
+    response::{IntoResponse, Response},
+};
+use serde::Deserialize;
+use uuid::Uuid;
+
+use sales::{
+    CreatePriceListCommand, CreatePriceListUseCase, ListPriceListsUseCase, PriceListEntryResponse,
+    PriceListId, PriceListRepository, PriceListResponse, SetPriceListEntryCommand,
+    SetPriceListEntryUseCase,
+};
+
+use crate::error::AppError;
+use crate::extractors::{CurrentUser, JsonBody};
+use crate::middleware::org_scope::verify_store_in_org;
+use crate::middleware::permission::require_permission;
+use crate::state::AppState;
+
+#[derive(Debug, Deserialize)]
+pub struct ListPriceListsQueryParams {
+    pub store_id: Uuid,
+}
+
+/// Handler for GET /api/v1/price-lists
+pub async fn list_price_lists_handler(
+    State(state): State<AppState>,
+    CurrentUser(ctx): CurrentUser,
+    Query(params): Query<ListPriceListsQueryParams>,
+) -> Result<Json<Vec<PriceListResponse>>, Response> {
+    require_permission(&ctx, "promotions:read")?;
+    verify_store_in_org(state.pool(), &ctx, params.store_id).await?;
+
+    let use_case = ListPriceListsUseCase::new(state.price_list_repo());
+
+    let response = use_case
+        .execute(Some(params.store_id))
+        .await
+        .map_err(|e| AppError::from(e).into_response())?;
+
+    Ok(Json(response))
+}
+
+/// Handler for POST /api/v1/price-lists
+pub async fn create_price_list_handler(
+    State(state): State<AppState>,
+    CurrentUser(ctx): CurrentUser,
+    JsonBody(command): JsonBody<CreatePriceListCommand>,
+) -> Result<(StatusCode, Json<PriceListResponse>), Response> {
+    require_permission(&ctx, "promotions:create")?;
+    verify_store_in_org(state.pool(), &ctx, command.store_id).await?;
+
+    let use_case = CreatePriceListUseCase::new(state.price_list_repo());
+
+    let actor_id = *ctx.user_id();
+    let response = use_case
+        .execute(command, actor_id)
+        .await
+        .map_err(|e| AppError::from(e).into_response())?;
+
+    Ok((StatusCode::CREATED, Json(response)))
+}
+
+/// Handler for PUT /api/v1/price-lists/{id}/entries
+pub async fn set_price_list_entry_handler(
+    State(state): State<AppState>,
+    CurrentUser(ctx): CurrentUser,
+    Path(id): Path<Uuid>,
+    JsonBody(mut command): JsonBody<SetPriceListEntryCommand>,
+) -> Result<Json<PriceListEntryResponse>, Response> {
+    require_permission(&ctx, "promotions:update")?;
+
+    let list = state
+        .price_list_repo()
+        .find_by_id(PriceListId::from_uuid(id))
+        .await
+        .map_err(|e| AppError::from(e).into_response())?
+        .ok_or_else(|| AppError::from(sales::SalesError::PriceListNotFound(id)).into_response())?;
+    verify_store_in_org(state.pool(), &ctx, list.store_id()).await?;
+
+    let use_case = SetPriceListEntryUseCase::new(state.price_list_repo(), state.product_repo());
+
+    command.price_list_id = id;
+    let response = use_case
+        .execute(command)
+        .await
+        .map_err(|e| AppError::from(e).into_response())?;
+
+    Ok(Json(response))
+}
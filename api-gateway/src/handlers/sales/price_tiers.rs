@@ -0,0 +1,68 @@
+// Price Tier Handlers
+//
+// REST endpoints for customer-type pricing:
+// - GET /api/v1/price-tiers - List a store's price tiers
+// - PUT /api/v1/price-tiers - Create or update the tier for a customer type
+//   and product/category
+
+use axum::{
+    Json,
+    extract::{Query, State},
+    response::{IntoResponse, Response},
+};
+use serde::Deserialize;
+use uuid::Uuid;
+
+use sales::{
+    ListPriceTiersUseCase, PriceTierResponse, UpsertPriceTierCommand, UpsertPriceTierUseCase,
+};
+
+use crate::error::AppError;
+use crate::extractors::{CurrentUser, JsonBody};
+use crate::middleware::org_scope::verify_store_in_org;
+use crate::middleware::permission::require_permission;
+use crate::state::AppState;
+
+#[derive(Debug, Deserialize)]
+pub struct ListPriceTiersQueryParams {
+    pub store_id: Uuid,
+}
+
+/// Handler for GET /api/v1/price-tiers
+pub async fn list_price_tiers_handler(
+    State(state): State<AppState>,
+    CurrentUser(ctx): CurrentUser,
+    Query(params): Query<ListPriceTiersQueryParams>,
+) -> Result<Json<Vec<PriceTierResponse>>, Response> {
+    require_permission(&ctx, "promotions:read")?;
+    verify_store_in_org(state.pool(), &ctx, params.store_id).await?;
+
+    let use_case = ListPriceTiersUseCase::new(state.price_tier_repo());
+
+    let response = use_case
+        .execute(Some(params.store_id))
+        .await
+        .map_err(|e| AppError::from(e).into_response())?;
+
+    Ok(Json(response))
+}
+
+/// Handler for PUT /api/v1/price-tiers
+pub async fn upsert_price_tier_handler(
+    State(state): State<AppState>,
+    CurrentUser(ctx): CurrentUser,
+    JsonBody(command): JsonBody<UpsertPriceTierCommand>,
+) -> Result<Json<PriceTierResponse>, Response> {
+    require_permission(&ctx, "promotions:update")?;
+    verify_store_in_org(state.pool(), &ctx, command.store_id).await?;
+
+    let use_case = UpsertPriceTierUseCase::new(state.price_tier_repo());
+
+    let actor_id = *ctx.user_id();
+    let response = use_case
+        .execute(command, actor_id)
+        .await
+        .map_err(|e| AppError::from(e).into_response())?;
+
+    Ok(Json(response))
+}
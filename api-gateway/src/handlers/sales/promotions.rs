@@ -7,6 +7,7 @@
 // - PUT /api/v1/promotions/{id} - Update promotion
 // - POST /api/v1/promotions/{id}/deactivate - Deactivate promotion
 // - POST /api/v1/sales/{sale_id}/apply-promotion - Apply promotion to sale
+// - POST /api/v1/sales/{sale_id}/evaluate-promotions - Evaluate promotions against sale
 
 use axum::{
     Json,
@@ -19,7 +20,8 @@ use uuid::Uuid;
 
 use sales::{
     ApplyPromotionUseCase, CreatePromotionCommand, CreatePromotionUseCase,
-    DeactivatePromotionUseCase, GetPromotionUseCase, ListPromotionsQuery, ListPromotionsUseCase,
+    DeactivatePromotionUseCase, EvaluatePromotionsCommand, EvaluatePromotionsUseCase,
+    GetPromotionUseCase, ListPromotionsQuery, ListPromotionsUseCase, PromotionEvaluationResponse,
     PromotionResponse, SaleDetailResponse, UpdatePromotionCommand, UpdatePromotionUseCase,
 };
 
@@ -46,6 +48,8 @@ pub struct ListPromotionsQueryParams {
     pub status: Option<String>,
     pub store_id: Option<Uuid>,
     pub search: Option<String>,
+    /// `next_cursor` of a previous page; when set, `page` is ignored
+    pub after_cursor: Option<String>,
 }
 
 use serde::Serialize;
@@ -57,6 +61,8 @@ pub struct PaginatedPromotionResponse {
     pub page_size: i64,
     pub total_items: i64,
     pub total_pages: i64,
+    /// Pass back as `after_cursor` for the next page; `None` on the last page
+    pub next_cursor: Option<String>,
 }
 
 /// Handler for POST /api/v1/promotions
@@ -100,9 +106,10 @@ pub async fn list_promotions_handler(
         search: params.search,
         page: params.page,
         page_size: params.page_size,
+        after_cursor: params.after_cursor.clone(),
     };
 
-    let (items, total_items) = use_case
+    let (items, total_items, next_cursor) = use_case
         .execute(query)
         .await
         .map_err(|e| AppError::from(e).into_response())?;
@@ -113,12 +120,20 @@ pub async fn list_promotions_handler(
         (total_items + params.page_size - 1) / params.page_size
     };
 
+    // A cursor page has no meaningful number
+    let page = if params.after_cursor.is_some() {
+        1
+    } else {
+        params.page
+    };
+
     Ok(Json(PaginatedPromotionResponse {
         items,
-        page: params.page,
+        page,
         page_size: params.page_size,
         total_items,
         total_pages,
+        next_cursor,
     }))
 }
 
@@ -204,3 +219,27 @@ pub async fn apply_promotion_handler(
 
     Ok(Json(response))
 }
+
+/// Handler for POST /api/v1/sales/{sale_id}/evaluate-promotions
+pub async fn evaluate_promotions_handler(
+    State(state): State<AppState>,
+    CurrentUser(ctx): CurrentUser,
+    Path(sale_id): Path<Uuid>,
+    JsonBody(mut command): JsonBody<EvaluatePromotionsCommand>,
+) -> Result<Json<PromotionEvaluationResponse>, Response> {
+    require_permission(&ctx, "promotions:apply")?;
+
+    let use_case = EvaluatePromotionsUseCase::new(
+        state.promotion_repo(),
+        state.sale_repo(),
+        state.product_repo(),
+    );
+
+    command.sale_id = sale_id;
+    let response = use_case
+        .execute(command)
+        .await
+        .map_err(|e| AppError::from(e).into_response())?;
+
+    Ok(Json(response))
+}
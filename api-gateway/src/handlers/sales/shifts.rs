@@ -14,8 +14,8 @@ use crate::middleware::org_scope::verify_store_in_org;
 use crate::middleware::permission::require_permission;
 use crate::state::AppState;
 use sales::{
-    CashMovementCommand, CloseShiftCommand, ListShiftsQuery, OpenShiftCommand, ShiftListResponse,
-    ShiftReportResponse, ShiftResponse,
+    CashMovementCommand, CloseShiftCommand, ListShiftsQuery, OpenShiftCommand, ReopenShiftCommand,
+    SalesError, ShiftId, ShiftListResponse, ShiftReportResponse, ShiftRepository, ShiftResponse,
 };
 
 pub async fn open_shift_handler(
@@ -44,7 +44,37 @@ pub async fn close_shift_handler(
 ) -> Result<Json<ShiftResponse>, Response> {
     require_permission(&ctx, "sales:manage_shift")?;
 
-    let use_case = sales::CloseShiftUseCase::new(state.shift_repo());
+    let use_case =
+        sales::CloseShiftUseCase::new(state.shift_repo(), state.shift_variance_threshold());
+
+    let mut cmd = command;
+    cmd.shift_id = id;
+
+    let response = use_case
+        .execute(cmd, *ctx.user_id())
+        .await
+        .map_err(|e| AppError::from(e).into_response())?;
+
+    Ok(Json(response))
+}
+
+pub async fn reopen_shift_handler(
+    State(state): State<AppState>,
+    CurrentUser(ctx): CurrentUser,
+    Path(id): Path<Uuid>,
+    JsonBody(command): JsonBody<ReopenShiftCommand>,
+) -> Result<Json<ShiftResponse>, Response> {
+    require_permission(&ctx, "sales:reopen_shift")?;
+
+    let shift = state
+        .shift_repo()
+        .find_by_id(ShiftId::from_uuid(id))
+        .await
+        .map_err(|e| AppError::from(e).into_response())?
+        .ok_or_else(|| AppError::from(SalesError::ShiftNotFound(id)).into_response())?;
+    verify_store_in_org(state.pool(), &ctx, shift.store_id().into_uuid()).await?;
+
+    let use_case = sales::ReopenShiftUseCase::new(state.shift_repo(), state.shift_reopen_window());
 
     let mut cmd = command;
     cmd.shift_id = id;
@@ -67,7 +97,7 @@ pub async fn get_current_shift_handler(
     let use_case = sales::GetCurrentShiftUseCase::new(state.shift_repo());
 
     let response = use_case
-        .execute(terminal_id)
+        .execute(terminal_id, &ctx)
         .await
         .map_err(|e| AppError::from(e).into_response())?;
 
@@ -84,7 +114,24 @@ pub async fn get_shift_report_handler(
     let use_case = sales::GetShiftReportUseCase::new(state.shift_repo());
 
     let response = use_case
-        .execute(id)
+        .execute(id, &ctx)
+        .await
+        .map_err(|e| AppError::from(e).into_response())?;
+
+    Ok(Json(response))
+}
+
+pub async fn get_shift_snapshot_handler(
+    State(state): State<AppState>,
+    CurrentUser(ctx): CurrentUser,
+    Path(id): Path<Uuid>,
+) -> Result<Json<ShiftReportResponse>, Response> {
+    require_permission(&ctx, "sales:read_shift")?;
+
+    let use_case = sales::GetShiftSnapshotUseCase::new(state.shift_repo());
+
+    let response = use_case
+        .execute(id, &ctx)
         .await
         .map_err(|e| AppError::from(e).into_response())?;
 
@@ -104,7 +151,7 @@ pub async fn list_shifts_handler(
     let use_case = sales::ListShiftsUseCase::new(state.shift_repo());
 
     let response = use_case
-        .execute(query)
+        .execute(query, &ctx)
         .await
         .map_err(|e| AppError::from(e).into_response())?;
 
@@ -0,0 +1,94 @@
+// Tax Rule Handlers
+//
+// REST endpoints for the tax stores charge:
+// - GET /api/v1/tax-rules - List a store's tax rules
+// - PUT /api/v1/tax-rules - Create or update the rule for a product, a
+//   category or the whole store
+// - POST /api/v1/sales/{id}/tax - Tax a sale's lines under its store's rules
+
+use axum::{
+    Json,
+    extract::{Path, Query, State},
+    response::{IntoResponse, Response},
+};
+use serde::Deserialize;
+use uuid::Uuid;
+
+use sales::{
+    CalculateTaxUseCase, ListTaxRulesUseCase, SaleDetailResponse, TaxRuleResponse,
+    UpsertTaxRuleCommand, UpsertTaxRuleUseCase,
+};
+
+use crate::error::AppError;
+use crate::extractors::{CurrentUser, JsonBody};
+use crate::middleware::org_scope::verify_store_in_org;
+use crate::middleware::permission::require_permission;
+use crate::state::AppState;
+
+#[derive(Debug, Deserialize)]
+pub struct ListTaxRulesQueryParams {
+    pub store_id: Uuid,
+}
+
+/// Handler for GET /api/v1/tax-rules
+pub async fn list_tax_rules_handler(
+    State(state): State<AppState>,
+    CurrentUser(ctx): CurrentUser,
+    Query(params): Query<ListTaxRulesQueryParams>,
+) -> Result<Json<Vec<TaxRuleResponse>>, Response> {
+    require_permission(&ctx, "tax_rates:read")?;
+    verify_store_in_org(state.pool(), &ctx, params.store_id).await?;
+
+    let use_case = ListTaxRulesUseCase::new(state.tax_rule_repo());
+
+    let response = use_case
+        .execute(Some(params.store_id))
+        .await
+        .map_err(|e| AppError::from(e).into_response())?;
+
+    Ok(Json(response))
+}
+
+/// Handler for PUT /api/v1/tax-rules
+pub async fn upsert_tax_rule_handler(
+    State(state): State<AppState>,
+    CurrentUser(ctx): CurrentUser,
+    JsonBody(command): JsonBody<UpsertTaxRuleCommand>,
+) -> Result<Json<TaxRuleResponse>, Response> {
+    require_permission(&ctx, "tax_rates:update")?;
+    verify_store_in_org(state.pool(), &ctx, command.store_id).await?;
+
+    let use_case = UpsertTaxRuleUseCase::new(state.tax_rule_repo());
+
+    let actor_id = *ctx.user_id();
+    let response = use_case
+        .execute(command, actor_id)
+        .await
+        .map_err(|e| AppError::from(e).into_response())?;
+
+    Ok(Json(response))
+}
+
+/// Handler for POST /api/v1/sales/{id}/tax
+pub async fn calculate_sale_tax_handler(
+    State(state): State<AppState>,
+    CurrentUser(ctx): CurrentUser,
+    Path(sale_id): Path<Uuid>,
+) -> Result<Json<SaleDetailResponse>, Response> {
+    require_permission(&ctx, "sales:create")?;
+
+    let use_case = CalculateTaxUseCase::new(
+        state.sale_repo(),
+        state.customer_repo(),
+        state.tax_rule_repo(),
+        state.product_repo(),
+        state.category_repo(),
+    );
+
+    let response = use_case
+        .execute(sale_id)
+        .await
+        .map_err(|e| AppError::from(e).into_response())?;
+
+    Ok(Json(response))
+}
@@ -0,0 +1,90 @@
+// Weight Barcode Format Handlers
+//
+// REST endpoints for the layouts of scale-printed weight and price labels:
+// - GET /api/v1/weight-barcode-formats - List a store's formats
+// - POST /api/v1/weight-barcode-formats - Register a format
+// - DELETE /api/v1/weight-barcode-formats/{id} - Remove a format
+
+use axum::{
+    Json,
+    extract::{Path, Query, State},
+    http::StatusCode,
+    response::{IntoResponse, Response},
+};
+use serde::Deserialize;
+use uuid::Uuid;
+
+use sales::{
+    CreateWeightBarcodeFormatCommand, CreateWeightBarcodeFormatUseCase,
+    DeleteWeightBarcodeFormatUseCase, ListWeightBarcodeFormatsUseCase, WeightBarcodeFormatResponse,
+};
+
+use crate::error::AppError;
+use crate::extractors::{CurrentUser, JsonBody};
+use crate::middleware::org_scope::verify_store_in_org;
+use crate::middleware::permission::require_permission;
+use crate::state::AppState;
+
+#[derive(Debug, Deserialize)]
+pub struct WeightBarcodeFormatStoreParams {
+    pub store_id: Uuid,
+}
+
+/// Handler for GET /api/v1/weight-barcode-formats
+pub async fn list_weight_barcode_formats_handler(
+    State(state): State<AppState>,
+    CurrentUser(ctx): CurrentUser,
+    Query(params): Query<WeightBarcodeFormatStoreParams>,
+) -> Result<Json<Vec<WeightBarcodeFormatResponse>>, Response> {
+    require_permission(&ctx, "sales:read")?;
+    verify_store_in_org(state.pool(), &ctx, params.store_id).await?;
+
+    let use_case = ListWeightBarcodeFormatsUseCase::new(state.weight_barcode_format_repo());
+
+    let response = use_case
+        .execute(params.store_id)
+        .await
+        .map_err(|e| AppError::from(e).into_response())?;
+
+    Ok(Json(response))
+}
+
+/// Handler for POST /api/v1/weight-barcode-formats
+pub async fn create_weight_barcode_format_handler(
+    State(state): State<AppState>,
+    CurrentUser(ctx): CurrentUser,
+    JsonBody(command): JsonBody<CreateWeightBarcodeFormatCommand>,
+) -> Result<(StatusCode, Json<WeightBarcodeFormatResponse>), Response> {
+    require_permission(&ctx, "sales:manage_barcode_formats")?;
+    verify_store_in_org(state.pool(), &ctx, command.store_id).await?;
+
+    let use_case = CreateWeightBarcodeFormatUseCase::new(state.weight_barcode_format_repo());
+
+    let actor_id = *ctx.user_id();
+    let response = use_case
+        .execute(command, actor_id)
+        .await
+        .map_err(|e| AppError::from(e).into_response())?;
+
+    Ok((StatusCode::CREATED, Json(response)))
+}
+
+/// Handler for DELETE /api/v1/weight-barcode-formats/{id}
+pub async fn delete_weight_barcode_format_handler(
+    State(state): State<AppState>,
+    CurrentUser(ctx): CurrentUser,
+    Path(id): Path<Uuid>,
+    Query(params): Query<WeightBarcodeFormatStoreParams>,
+) -> Result<StatusCode, Response> {
+    require_permission(&ctx, "sales:manage_barcode_formats")?;
+    verify_store_in_org(state.pool(), &ctx, params.store_id).await?;
+
+    let use_case = DeleteWeightBarcodeFormatUseCase::new(state.weight_barcode_format_repo());
+
+    use_case
+        .execute(params.store_id, id)
+        .await
+        .map_err(|e| AppError::from(e).into_response())?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
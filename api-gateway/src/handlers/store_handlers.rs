@@ -7,6 +7,8 @@
 // - PUT /stores/:id - Update store
 // - POST /stores/:id/activate - Activate store (requires super_admin)
 // - POST /stores/:id/deactivate - Deactivate store (requires super_admin)
+// - GET /stores/:id/settings - Get store settings
+// - PUT /stores/:id/settings - Update store settings
 
 use axum::{
     Json,
@@ -18,8 +20,10 @@ use chrono::{DateTime, Utc};
 use uuid::Uuid;
 
 use identity::{
-    CreateStoreCommand, CreateStoreUseCase, StoreId, UpdateStoreCommand, UpdateStoreUseCase,
+    CreateStoreCommand, CreateStoreUseCase, GetStoreSettingsUseCase, StoreId, StoreSettings,
+    UpdateStoreCommand, UpdateStoreSettingsCommand, UpdateStoreSettingsUseCase, UpdateStoreUseCase,
 };
+use inventory::TransferRepository;
 use pos_core::{
     GetStoreDetailUseCase, ListStoresQuery, ListStoresUseCase, PaginatedStoresResponse,
     SetStoreActiveUseCaseExtended, StoreDetailResponse,
@@ -28,7 +32,7 @@ use pos_core::{
 use crate::error::AppError;
 use crate::extractors::{CurrentUser, JsonBody};
 use crate::middleware::org_scope::verify_store_in_org;
-use crate::middleware::permission::require_super_admin;
+use crate::middleware::permission::{require_permission, require_super_admin};
 use crate::state::AppState;
 
 // =============================================================================
@@ -61,6 +65,34 @@ impl From<identity::Store> for StoreResponse {
     }
 }
 
+/// Response DTO for store settings
+#[derive(Debug, serde::Serialize)]
+pub struct StoreSettingsResponse {
+    pub store_id: Uuid,
+    pub loyalty_enabled: bool,
+    pub auto_markdowns_enabled: bool,
+    pub allow_negative_stock: bool,
+    pub pickup_hold_days: i32,
+    pub transit_owned_by_destination: bool,
+    pub updated_by_id: Option<Uuid>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl From<StoreSettings> for StoreSettingsResponse {
+    fn from(settings: StoreSettings) -> Self {
+        Self {
+            store_id: settings.store_id().into_uuid(),
+            loyalty_enabled: settings.loyalty_enabled(),
+            auto_markdowns_enabled: settings.auto_markdowns_enabled(),
+            allow_negative_stock: settings.allow_negative_stock(),
+            pickup_hold_days: settings.pickup_hold_days(),
+            transit_owned_by_destination: settings.transit_owned_by_destination(),
+            updated_by_id: settings.updated_by_id().map(|id| id.into_uuid()),
+            updated_at: settings.updated_at(),
+        }
+    }
+}
+
 // =============================================================================
 // Create Store Handler
 // =============================================================================
@@ -332,3 +364,94 @@ pub async fn deactivate_store_handler(
 
     Ok(Json(StoreResponse::from(store)))
 }
+
+// =============================================================================
+// Store Settings Handlers
+// =============================================================================
+
+/// Handler for GET /stores/:id/settings
+///
+/// Gets the store's settings. Stores that were never configured return the
+/// defaults.
+///
+/// # Response
+///
+/// - 200 OK: Store settings
+/// - 401 Unauthorized: Missing or invalid token
+/// - 403 Forbidden: User lacks stores:read permission
+/// - 404 Not Found: Store doesn't exist
+pub async fn get_store_settings_handler(
+    State(state): State<AppState>,
+    CurrentUser(ctx): CurrentUser,
+    Path(id): Path<Uuid>,
+) -> Result<Json<StoreSettingsResponse>, Response> {
+    require_permission(&ctx, "stores:read")?;
+    verify_store_in_org(state.pool(), &ctx, id).await?;
+
+    let use_case = GetStoreSettingsUseCase::new(state.store_repo());
+
+    let settings = use_case
+        .execute(StoreId::from_uuid(id))
+        .await
+        .map_err(|e| AppError::from(e).into_response())?;
+
+    Ok(Json(StoreSettingsResponse::from(settings)))
+}
+
+/// Handler for PUT /stores/:id/settings
+///
+/// Changes the store's settings. Takes effect on the next request.
+///
+/// # Request Body
+///
+/// ```json
+/// {
+///   "loyalty_enabled": true,
+///   "auto_markdowns_enabled": false,
+///   "allow_negative_stock": false,
+///   "pickup_hold_days": 7,
+///   "transit_owned_by_destination": false
+/// }
+/// ```
+///
+/// All fields are optional - only provided fields will be updated.
+///
+/// # Response
+///
+/// - 200 OK: Settings successfully updated
+/// - 400 Bad Request: No settings provided, unknown field, or negative stock
+///   enabled while transfers to or from the store are in transit
+/// - 401 Unauthorized: Missing or invalid token
+/// - 403 Forbidden: User lacks stores:update permission
+/// - 404 Not Found: Store doesn't exist
+pub async fn update_store_settings_handler(
+    State(state): State<AppState>,
+    CurrentUser(ctx): CurrentUser,
+    Path(id): Path<Uuid>,
+    JsonBody(command): JsonBody<UpdateStoreSettingsCommand>,
+) -> Result<Json<StoreSettingsResponse>, Response> {
+    require_permission(&ctx, "stores:update")?;
+    verify_store_in_org(state.pool(), &ctx, id).await?;
+
+    let transfers_in_transit = if command.allow_negative_stock == Some(true) {
+        state
+            .transfer_repo()
+            .find_in_transit()
+            .await
+            .map_err(|e| AppError::from(e).into_response())?
+            .iter()
+            .any(|t| *t.from_store_id().as_uuid() == id || *t.to_store_id().as_uuid() == id)
+    } else {
+        false
+    };
+
+    let use_case = UpdateStoreSettingsUseCase::new(state.store_repo(), state.audit_repo())
+        .with_transfers_in_transit(transfers_in_transit);
+
+    let settings = use_case
+        .execute(StoreId::from_uuid(id), command, *ctx.user_id())
+        .await
+        .map_err(|e| AppError::from(e).into_response())?;
+
+    Ok(Json(StoreSettingsResponse::from(settings)))
+}
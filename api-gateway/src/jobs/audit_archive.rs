@@ -0,0 +1,39 @@
+use std::sync::Arc;
+use std::time::Duration;
+
+use identity::{ArchiveAuditUseCase, PgAuditRepository};
+
+/// Spawns a background task that periodically moves audit entries older
+/// than the retention period to `audit_log_archive`, skipping records under
+/// a legal hold.
+pub fn spawn(
+    audit_repo: Arc<PgAuditRepository>,
+    interval_secs: u64,
+    retention_days: i64,
+    batch_size: i64,
+) {
+    let use_case = ArchiveAuditUseCase::new(audit_repo);
+
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(Duration::from_secs(interval_secs));
+        // First tick completes immediately; skip it to avoid running on startup
+        interval.tick().await;
+
+        loop {
+            interval.tick().await;
+            match use_case.execute(retention_days, batch_size).await {
+                Ok(result) => {
+                    if result.archived > 0 {
+                        println!(
+                            "[audit-archive] archived={}, batches={}, retention_days={}",
+                            result.archived, result.batches, retention_days
+                        );
+                    }
+                }
+                Err(e) => {
+                    eprintln!("[audit-archive] error: {}", e);
+                }
+            }
+        }
+    });
+}
@@ -0,0 +1,55 @@
+//! Periodic automatic markdowns for aging / near-expiry stock.
+//!
+//! Runs `ApplyAutoMarkdownsUseCase` across every store with an active
+//! markdown rule, except stores whose settings turn auto markdowns off.
+//! Settings are re-read on every run. Default interval is 24 hours; `AUTO_MARKDOWN_INTERVAL_SECS`
+//! overrides it. Re-running is harmless: products that already carry an
+//! active markdown are skipped.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use identity::{PgStoreRepository, StoreRepository};
+use sales::{ApplyAutoMarkdownsUseCase, PgMarkdownRepository, PgPromotionRepository};
+
+pub fn spawn(
+    markdown_repo: Arc<PgMarkdownRepository>,
+    promotion_repo: Arc<PgPromotionRepository>,
+    store_repo: Arc<PgStoreRepository>,
+    interval_secs: u64,
+) {
+    let use_case = ApplyAutoMarkdownsUseCase::new(markdown_repo, promotion_repo);
+
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(Duration::from_secs(interval_secs));
+        // First tick completes immediately; skip it to avoid running on startup
+        interval.tick().await;
+
+        loop {
+            interval.tick().await;
+            let settings = match store_repo.find_all_settings().await {
+                Ok(settings) => settings,
+                Err(e) => {
+                    eprintln!("[auto-markdown] failed to load store settings: {}", e);
+                    continue;
+                }
+            };
+            match use_case.execute(None, &settings).await {
+                Ok(result) => {
+                    if !result.created.is_empty() || result.expired > 0 {
+                        println!(
+                            "[auto-markdown] rules={} created={} expired={} skipped={}",
+                            result.rules_evaluated,
+                            result.created.len(),
+                            result.expired,
+                            result.skipped_already_marked_down
+                        );
+                    }
+                }
+                Err(e) => {
+                    eprintln!("[auto-markdown] error: {}", e);
+                }
+            }
+        }
+    });
+}
@@ -1,10 +1,21 @@
 use std::sync::Arc;
 use std::time::Duration;
 
-use sales::{CartRepository, PgCartRepository};
+use inventory::{PgInventoryStockRepository, PgReservationRepository};
+use sales::{CartTtlPolicy, CleanupCartsUseCase, PgCartRepository};
+
+/// Spawns a background task that periodically deletes expired or abandoned
+/// carts and releases the stock they reserved.
+pub fn spawn(
+    cart_repo: Arc<PgCartRepository>,
+    reservation_repo: Arc<PgReservationRepository>,
+    stock_repo: Arc<PgInventoryStockRepository>,
+    policy: CartTtlPolicy,
+    interval_secs: u64,
+    batch_size: i64,
+) {
+    let use_case = CleanupCartsUseCase::new(cart_repo, reservation_repo, stock_repo, policy);
 
-/// Spawns a background task that periodically deletes expired carts.
-pub fn spawn(cart_repo: Arc<PgCartRepository>, interval_secs: u64) {
     tokio::spawn(async move {
         let mut interval = tokio::time::interval(Duration::from_secs(interval_secs));
         // First tick completes immediately; skip it to avoid running on startup
@@ -12,11 +23,23 @@ pub fn spawn(cart_repo: Arc<PgCartRepository>, interval_secs: u64) {
 
         loop {
             interval.tick().await;
-            let before = chrono::Utc::now();
-            match cart_repo.delete_expired(before).await {
-                Ok(count) => {
-                    if count > 0 {
-                        println!("[cart-cleanup] deleted {} expired carts", count);
+            match use_case.execute(batch_size).await {
+                Ok(result) => {
+                    if result.carts_deleted > 0
+                        || result.skipped_in_checkout > 0
+                        || result.failed > 0
+                    {
+                        println!(
+                            "[cart-cleanup] deleted={}, skipped_in_checkout={}, reservations_released={}, quantity_released={}, failed={}",
+                            result.carts_deleted,
+                            result.skipped_in_checkout,
+                            result.reservations_released,
+                            result.quantity_released,
+                            result.failed
+                        );
+                    }
+                    for error in &result.errors {
+                        eprintln!("[cart-cleanup] {}", error);
                     }
                 }
                 Err(e) => {
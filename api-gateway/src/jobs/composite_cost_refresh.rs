@@ -0,0 +1,42 @@
+//! Periodic composite (recipe/bundle) cost refresh.
+//!
+//! Runs `RefreshCompositeCostsUseCase` over every active recipe-costed
+//! composite. Default interval is 24 hours; `COMPOSITE_COST_REFRESH_INTERVAL_SECS`
+//! overrides it. Costs are only reported unless `COMPOSITE_COST_AUTO_ADJUST`
+//! is true, in which case changed costs are written to cost_price.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use inventory::{PgRecipeRepository, RefreshCompositeCostsUseCase};
+
+pub fn spawn(recipe_repo: Arc<PgRecipeRepository>, interval_secs: u64, auto_adjust: bool) {
+    let use_case = RefreshCompositeCostsUseCase::new(recipe_repo);
+
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(Duration::from_secs(interval_secs));
+        // First tick completes immediately; skip it to avoid running on startup
+        interval.tick().await;
+
+        loop {
+            interval.tick().await;
+            match use_case.execute(auto_adjust).await {
+                Ok(result) => {
+                    if !result.changed.is_empty() || !result.cycle_recipe_ids.is_empty() {
+                        println!(
+                            "[composite-cost] evaluated={} changed={} margin_eroded={} cycles={} adjusted={}",
+                            result.composites_evaluated,
+                            result.changed.len(),
+                            result.margin_eroded.len(),
+                            result.cycle_recipe_ids.len(),
+                            result.auto_adjust
+                        );
+                    }
+                }
+                Err(e) => {
+                    eprintln!("[composite-cost] error: {}", e);
+                }
+            }
+        }
+    });
+}
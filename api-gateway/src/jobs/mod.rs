@@ -1,10 +1,21 @@
 pub mod analytics_recompute;
+pub mod audit_archive;
+pub mod auto_markdown;
 pub mod cart_cleanup;
+pub mod composite_cost_refresh;
 pub mod demand_planning_recompute;
 pub mod event_dispatcher;
 pub mod notification_dispatcher;
+pub mod outbox_drain;
+pub mod pickup_expiry;
 pub mod reservation_expiry;
 pub mod subscription_billing;
+pub mod webhook_dispatcher;
+
+use std::sync::Arc;
+
+use notifications::{WebhookEventPublisher, WebhookRetryPolicy};
+use sales::CartTtlPolicy;
 
 use crate::config::JobsConfig;
 use crate::state::AppState;
@@ -15,13 +26,49 @@ pub fn spawn_all(state: &AppState, config: &JobsConfig) {
         state.stock_repo(),
         config.reservation_expiry_interval,
     );
-    cart_cleanup::spawn(state.cart_repo(), config.cart_cleanup_interval);
+    cart_cleanup::spawn(
+        state.cart_repo(),
+        state.reservation_repo(),
+        state.stock_repo(),
+        CartTtlPolicy::from_hours(config.cart_guest_ttl_hours, config.cart_customer_ttl_hours),
+        config.cart_cleanup_interval,
+        config.cart_cleanup_batch_size,
+    );
+    pickup_expiry::spawn(
+        state.sale_repo(),
+        state.order_pickup_repo(),
+        state.reservation_repo(),
+        state.stock_repo(),
+        config.pickup_expiry_interval,
+        config.pickup_expiry_batch_size,
+    );
     event_dispatcher::spawn(
         state.outbox_repo(),
         state.subscriber_registry(),
         config.event_dispatch_interval,
         config.event_dispatch_batch_size,
     );
+    outbox_drain::spawn(
+        state.outbox_repo(),
+        Arc::new(WebhookEventPublisher::new(
+            state.webhook_repo(),
+            state.webhook_delivery_repo(),
+        )),
+        config.outbox_drain_interval,
+        config.outbox_drain_batch_size,
+    );
+    webhook_dispatcher::spawn(
+        state.webhook_repo(),
+        state.webhook_delivery_repo(),
+        WebhookRetryPolicy::new(
+            config.webhook_max_attempts,
+            config.webhook_retry_base_delay_secs,
+            WebhookRetryPolicy::DEFAULT_MAX_DELAY_SECS,
+        ),
+        config.webhook_timeout_secs,
+        config.webhook_dispatch_interval,
+        config.webhook_dispatch_batch_size,
+    );
     notification_dispatcher::spawn(
         state.notification_repo(),
         state.notification_registry(),
@@ -51,4 +98,21 @@ pub fn spawn_all(state: &AppState, config: &JobsConfig) {
         state.subscription_payment_gateway(),
         config.subscription_billing_interval,
     );
+    auto_markdown::spawn(
+        state.markdown_repo(),
+        state.promotion_repo(),
+        state.store_repo(),
+        config.auto_markdown_interval,
+    );
+    composite_cost_refresh::spawn(
+        state.recipe_repo(),
+        config.composite_cost_refresh_interval,
+        config.composite_cost_auto_adjust,
+    );
+    audit_archive::spawn(
+        state.audit_repo(),
+        config.audit_archive_interval,
+        config.audit_retention_days,
+        config.audit_archive_batch_size,
+    );
 }
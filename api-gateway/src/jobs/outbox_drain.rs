@@ -0,0 +1,34 @@
+use std::sync::Arc;
+use std::time::Duration;
+
+use events::{DrainOutboxUseCase, EventPublisher, OutboxRepository};
+
+/// Spawns a background task that periodically publishes unpublished outbox
+/// events, in order, to the external change feed.
+pub fn spawn(
+    outbox_repo: Arc<dyn OutboxRepository>,
+    publisher: Arc<dyn EventPublisher>,
+    interval_secs: u64,
+    batch_size: i64,
+) {
+    let use_case = DrainOutboxUseCase::new(outbox_repo, publisher);
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(Duration::from_secs(interval_secs));
+        // First tick completes immediately; skip it to avoid running on startup
+        interval.tick().await;
+
+        loop {
+            interval.tick().await;
+            match use_case.execute(batch_size).await {
+                Ok(count) => {
+                    if count > 0 {
+                        println!("[outbox-drain] published {} events", count);
+                    }
+                }
+                Err(e) => {
+                    eprintln!("[outbox-drain] error: {}", e);
+                }
+            }
+        }
+    });
+}
@@ -0,0 +1,48 @@
+use std::sync::Arc;
+use std::time::Duration;
+
+use inventory::{PgInventoryStockRepository, PgReservationRepository};
+use sales::{ExpirePickupsUseCase, PgOrderPickupRepository, PgSaleRepository};
+
+/// Spawns a background task that periodically cancels pickup orders nobody
+/// collected before the end of their hold and releases the stock they
+/// reserved.
+pub fn spawn(
+    sale_repo: Arc<PgSaleRepository>,
+    pickup_repo: Arc<PgOrderPickupRepository>,
+    reservation_repo: Arc<PgReservationRepository>,
+    stock_repo: Arc<PgInventoryStockRepository>,
+    interval_secs: u64,
+    batch_size: i64,
+) {
+    let use_case = ExpirePickupsUseCase::new(sale_repo, pickup_repo, reservation_repo, stock_repo);
+
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(Duration::from_secs(interval_secs));
+        // First tick completes immediately; skip it to avoid running on startup
+        interval.tick().await;
+
+        loop {
+            interval.tick().await;
+            match use_case.execute(batch_size).await {
+                Ok(result) => {
+                    if result.orders_cancelled > 0 || result.failed > 0 {
+                        println!(
+                            "[pickup-expiry] orders_cancelled={}, reservations_released={}, quantity_released={}, failed={}",
+                            result.orders_cancelled,
+                            result.reservations_released,
+                            result.quantity_released,
+                            result.failed
+                        );
+                    }
+                    for error in &result.errors {
+                        eprintln!("[pickup-expiry] {}", error);
+                    }
+                }
+                Err(e) => {
+                    eprintln!("[pickup-expiry] error: {}", e);
+                }
+            }
+        }
+    });
+}
@@ -0,0 +1,44 @@
+use std::sync::Arc;
+use std::time::Duration;
+
+use notifications::{
+    DispatchWebhooksUseCase, HttpWebhookClient, WebhookDeliveryRepository, WebhookRepository,
+    WebhookRetryPolicy,
+};
+
+/// Spawns a background task that periodically POSTs due event webhook
+/// deliveries, retrying failures with exponential backoff.
+pub fn spawn(
+    webhook_repo: Arc<dyn WebhookRepository>,
+    delivery_repo: Arc<dyn WebhookDeliveryRepository>,
+    policy: WebhookRetryPolicy,
+    timeout_secs: u64,
+    interval_secs: u64,
+    batch_size: i64,
+) {
+    let use_case = DispatchWebhooksUseCase::new(
+        webhook_repo,
+        delivery_repo,
+        Arc::new(HttpWebhookClient::new(timeout_secs)),
+    )
+    .with_retry_policy(policy);
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(Duration::from_secs(interval_secs));
+        // First tick completes immediately; skip it to avoid running on startup
+        interval.tick().await;
+
+        loop {
+            interval.tick().await;
+            match use_case.execute(batch_size).await {
+                Ok(count) => {
+                    if count > 0 {
+                        println!("[webhook-dispatcher] attempted {} deliveries", count);
+                    }
+                }
+                Err(e) => {
+                    eprintln!("[webhook-dispatcher] error: {}", e);
+                }
+            }
+        }
+    });
+}
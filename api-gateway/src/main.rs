@@ -6,10 +6,12 @@ mod config;
 mod cors;
 mod database;
 pub mod error;
+pub mod export;
 pub mod extractors;
 mod handlers;
 mod jobs;
 pub mod middleware;
+mod order_gateways;
 mod router;
 mod routes;
 mod state;
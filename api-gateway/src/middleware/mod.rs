@@ -7,9 +7,11 @@ pub mod auth;
 pub mod org_scope;
 pub mod permission;
 pub mod rate_limit;
+pub mod store_settings;
 
 pub use auth::auth_middleware;
 pub use org_scope::{require_feature, require_org_match, verify_store_in_org};
 pub use permission::{
     require_all_permissions, require_any_permission, require_permission, require_super_admin,
 };
+pub use store_settings::load_store_settings;
@@ -0,0 +1,24 @@
+//! Per-store settings lookup for handlers.
+//!
+//! Handlers load the settings of the store they act on once per request and
+//! pass them into the use cases that branch on store policy. There is no
+//! cache: settings are read fresh, so a change applies to the next request.
+
+use axum::response::{IntoResponse, Response};
+use identity::{GetStoreSettingsUseCase, StoreId, StoreSettings};
+use uuid::Uuid;
+
+use crate::error::AppError;
+use crate::state::AppState;
+
+/// Loads a store's settings, falling back to the defaults for stores that
+/// were never configured.
+pub async fn load_store_settings(
+    state: &AppState,
+    store_id: Uuid,
+) -> Result<StoreSettings, Response> {
+    GetStoreSettingsUseCase::new(state.store_repo())
+        .execute(StoreId::from_uuid(store_id))
+        .await
+        .map_err(|e| AppError::from(e).into_response())
+}
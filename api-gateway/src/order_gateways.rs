@@ -0,0 +1,171 @@
+// Outbound gateways for the sales order workflow
+//
+// `sales` defines `OrderNotifier` and `OrderInvoiceIssuer` but cannot depend
+// on `notifications` or `fiscal` (fiscal already depends on sales). The
+// concrete adapters live here, where every module is in reach:
+//
+// - `NotificationOrderNotifier` sends through `SendNotificationUseCase`, by
+//   email when the customer has one and by SMS otherwise.
+// - `FiscalOrderInvoiceIssuer` runs `GenerateInvoiceUseCase`, returning the
+//   existing invoice when the order was already billed.
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use fiscal::{
+    FiscalSequenceRepository, GenerateInvoiceCommand, GenerateInvoiceUseCase, InvoiceRepository,
+    TaxRateRepository,
+};
+use notifications::{
+    NotificationAdapterRegistry, NotificationChannel, NotificationRepository,
+    SendNotificationCommand, SendNotificationUseCase,
+};
+use pos_core::TerminalRepository;
+use sales::{
+    Customer, CustomerRepository, OrderInvoiceIssuer, OrderNotifier, OrderPickup, Sale,
+    SaleRepository, SalesError,
+};
+
+use crate::state::AppState;
+
+/// Customer name printed on invoices for orders without a known customer
+const ANONYMOUS_CUSTOMER_NAME: &str = "Consumidor Final";
+
+/// Sends order updates to customers through the notifications module
+pub struct NotificationOrderNotifier {
+    notification_repo: Arc<dyn NotificationRepository>,
+    notification_registry: Arc<dyn NotificationAdapterRegistry>,
+}
+
+impl NotificationOrderNotifier {
+    pub fn new(
+        notification_repo: Arc<dyn NotificationRepository>,
+        notification_registry: Arc<dyn NotificationAdapterRegistry>,
+    ) -> Self {
+        Self {
+            notification_repo,
+            notification_registry,
+        }
+    }
+}
+
+#[async_trait]
+impl OrderNotifier for NotificationOrderNotifier {
+    async fn notify_ready_for_pickup(
+        &self,
+        customer: &Customer,
+        sale: &Sale,
+        pickup: &OrderPickup,
+    ) -> Result<(), SalesError> {
+        let (channel, recipient) = match (customer.email(), customer.phone()) {
+            (Some(email), _) => (NotificationChannel::Email, email),
+            (None, Some(phone)) => (NotificationChannel::Sms, phone),
+            (None, None) => {
+                return Err(SalesError::NotificationFailed(
+                    "customer has no email or phone".to_string(),
+                ));
+            }
+        };
+
+        let body = format!(
+            "Hi {}, your order {} is ready for pickup. Show code {} at the store before {}.",
+            customer.display_name(),
+            sale.sale_number(),
+            pickup.pickup_code(),
+            pickup.hold_until().format("%Y-%m-%d %H:%M UTC"),
+        );
+        let command = SendNotificationCommand {
+            channel,
+            recipient: recipient.to_string(),
+            subject: Some(format!("Order {} is ready for pickup", sale.sale_number())),
+            body,
+            metadata: serde_json::json!({
+                "event": "order.ready_for_pickup",
+                "sale_id": sale.id().into_uuid(),
+                "store_id": sale.store_id().into_uuid(),
+            }),
+        };
+
+        SendNotificationUseCase::new(
+            self.notification_repo.clone(),
+            self.notification_registry.clone(),
+        )
+        .execute(command)
+        .await
+        .map(|_| ())
+        .map_err(|e| SalesError::NotificationFailed(e.to_string()))
+    }
+}
+
+/// Issues fiscal invoices for online orders through the fiscal module
+pub struct FiscalOrderInvoiceIssuer {
+    invoice_repo: Arc<dyn InvoiceRepository>,
+    fiscal_sequence_repo: Arc<dyn FiscalSequenceRepository>,
+    tax_rate_repo: Arc<dyn TaxRateRepository>,
+    sale_repo: Arc<dyn SaleRepository>,
+    terminal_repo: Arc<dyn TerminalRepository>,
+    customer_repo: Arc<dyn CustomerRepository>,
+}
+
+impl FiscalOrderInvoiceIssuer {
+    pub fn new(state: &AppState) -> Self {
+        Self {
+            invoice_repo: state.invoice_repo(),
+            fiscal_sequence_repo: state.fiscal_sequence_repo(),
+            tax_rate_repo: state.tax_rate_repo(),
+            sale_repo: state.sale_repo(),
+            terminal_repo: state.terminal_repo(),
+            customer_repo: state.customer_repo(),
+        }
+    }
+}
+
+#[async_trait]
+impl OrderInvoiceIssuer for FiscalOrderInvoiceIssuer {
+    async fn issue_invoice(&self, sale: &Sale) -> Result<Uuid, SalesError> {
+        if let Some(invoice) = self
+            .invoice_repo
+            .find_by_sale_id(sale.id())
+            .await
+            .map_err(|e| SalesError::InvoiceIssueFailed(e.to_string()))?
+        {
+            return Ok(invoice.id().into_uuid());
+        }
+
+        let customer = match sale.customer_id() {
+            Some(customer_id) => self.customer_repo.find_by_id(customer_id).await?,
+            None => None,
+        };
+        let command = GenerateInvoiceCommand {
+            sale_id: sale.id().into_uuid(),
+            store_id: sale.store_id().into_uuid(),
+            terminal_id: None,
+            customer_name: customer
+                .as_ref()
+                .map(|c| c.display_name())
+                .unwrap_or_else(|| ANONYMOUS_CUSTOMER_NAME.to_string()),
+            customer_rtn: customer
+                .as_ref()
+                .and_then(|c| c.tax_id().map(str::to_string)),
+            customer_address: customer
+                .as_ref()
+                .and_then(|c| c.billing_address().line1.clone()),
+            invoice_type: "standard".to_string(),
+        };
+
+        let invoice = GenerateInvoiceUseCase::new(
+            self.invoice_repo.clone(),
+            self.fiscal_sequence_repo.clone(),
+            self.tax_rate_repo.clone(),
+            self.sale_repo.clone(),
+            self.terminal_repo.clone(),
+        )
+        .execute(command)
+        .await
+        .map_err(|e| SalesError::InvoiceIssueFailed(e.to_string()))?;
+
+        Ok(invoice.id)
+    }
+}
@@ -12,20 +12,22 @@ use crate::routes::{
     catalog_listings_router, catalog_public_router, catalog_reviews_router,
     catalog_storage_providers_router, catalog_wishlist_router, categories_router,
     credit_notes_router, customers_router, delivery_providers_router, delivery_webhooks_router,
-    drivers_router, forecasts_router, goods_receipts_router, inventory_router, invoices_router,
-    kds_stream_router, kds_tickets_router, loyalty_members_router, loyalty_programs_router,
-    loyalty_rewards_router, loyalty_tiers_router, orders_router, organization_subscription_router,
-    payment_gateways_router, payouts_router, pos_sales_router, products_router, promotions_router,
-    public_booking_router, public_service_orders_router, public_subscription_plans_router,
-    public_tenancy_router, public_tracking_router, purchase_orders_router, recipes_router,
-    reorder_policies_router, replenishment_suggestions_router, reports_router,
-    restaurant_modifier_groups_router, restaurant_product_modifiers_router,
-    restaurant_stations_router, restaurant_tables_router, service_orders_assets_router,
-    service_orders_router, shifts_router, shipments_router, shipping_calculate_router,
-    shipping_methods_router, shipping_rates_router, shipping_zones_router, store_router,
-    store_terminals_router, subscription_plans_router, tax_rates_router,
-    tenancy_organizations_router, terminals_router, transactions_router, transfers_router,
-    vendors_router, webhooks_router,
+    drivers_router, event_webhooks_router, exchange_rates_router, forecasts_router,
+    gift_cards_router, goods_receipts_router, inventory_router, invoices_router, kds_stream_router,
+    kds_tickets_router, loyalty_members_router, loyalty_programs_router, loyalty_rewards_router,
+    loyalty_tiers_router, markdowns_router, orders_router, organization_subscription_router,
+    payment_gateways_router, payouts_router, pos_sales_router, price_lists_router,
+    price_tiers_router, products_router, promotions_router, public_booking_router,
+    public_service_orders_router, public_subscription_plans_router, public_tenancy_router,
+    public_tracking_router, purchase_orders_router, recipes_router, reorder_policies_router,
+    replenishment_suggestions_router, reports_router, restaurant_modifier_groups_router,
+    restaurant_product_modifiers_router, restaurant_stations_router, restaurant_tables_router,
+    service_orders_assets_router, service_orders_router, shifts_router, shipments_router,
+    shipping_calculate_router, shipping_methods_router, shipping_rates_router,
+    shipping_zones_router, store_router, store_terminals_router, subscription_plans_router,
+    tax_rates_router, tax_rules_router, tenancy_organizations_router, terminals_router,
+    transactions_router, transfers_router, vendors_router, webhooks_router,
+    weight_barcode_formats_router,
 };
 use crate::state::AppState;
 
@@ -40,7 +42,7 @@ pub fn build_router(app_state: AppState, config: &AppConfig) -> Router {
 
     Router::new()
         .route("/health", get(health_check_simple))
-        .nest("/api/v1/auth", auth_router())
+        .nest("/api/v1/auth", auth_router(app_state.clone()))
         .nest("/api/v1/stores", store_router(app_state.clone()))
         .nest(
             "/api/v1/stores/{store_id}/terminals",
@@ -66,6 +68,19 @@ pub fn build_router(app_state: AppState, config: &AppConfig) -> Router {
         .nest("/api/v1/shifts", shifts_router(app_state.clone()))
         .nest("/api/v1/orders", orders_router(app_state.clone()))
         .nest("/api/v1/promotions", promotions_router(app_state.clone()))
+        .nest("/api/v1/markdowns", markdowns_router(app_state.clone()))
+        .nest("/api/v1/price-lists", price_lists_router(app_state.clone()))
+        .nest(
+            "/api/v1/exchange-rates",
+            exchange_rates_router(app_state.clone()),
+        )
+        .nest("/api/v1/gift-cards", gift_cards_router(app_state.clone()))
+        .nest(
+            "/api/v1/weight-barcode-formats",
+            weight_barcode_formats_router(app_state.clone()),
+        )
+        .nest("/api/v1/price-tiers", price_tiers_router(app_state.clone()))
+        .nest("/api/v1/tax-rules", tax_rules_router(app_state.clone()))
         .nest("/api/v1/sales", pos_sales_router(app_state.clone()))
         .nest("/api/v1/carts", cart_router(app_state.clone()))
         .nest(
@@ -135,6 +150,11 @@ pub fn build_router(app_state: AppState, config: &AppConfig) -> Router {
         .nest("/api/v1/analytics", analytics_router(app_state.clone()))
         // Accounting
         .nest("/api/v1/accounting", accounting_router(app_state.clone()))
+        // Notifications
+        .nest(
+            "/api/v1/event-webhooks",
+            event_webhooks_router(app_state.clone()),
+        )
         // Demand planning
         .nest("/api/v1/forecasts", forecasts_router(app_state.clone()))
         .nest(
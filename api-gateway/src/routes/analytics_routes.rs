@@ -11,8 +11,10 @@ use axum::{
 };
 
 use crate::handlers::analytics::{
-    add_widget_handler, create_dashboard_handler, get_dashboard_overview_handler,
-    get_kpi_snapshot_handler, list_dashboards_handler, remove_widget_handler, run_report_handler,
+    add_widget_handler, create_dashboard_handler, daily_sales_summary_handler,
+    get_dashboard_overview_handler, get_kpi_snapshot_handler, list_dashboards_handler,
+    product_sales_ranking_handler, remove_widget_handler, run_report_handler,
+    terminal_sales_report_handler,
 };
 use crate::middleware::auth_middleware;
 use crate::state::AppState;
@@ -32,5 +34,11 @@ pub fn analytics_router(state: AppState) -> Router<AppState> {
         .route("/widgets/{id}", delete(remove_widget_handler))
         .route("/kpis/{kpi_key}", get(get_kpi_snapshot_handler))
         .route("/reports/run", post(run_report_handler))
+        .route("/reports/terminals", get(terminal_sales_report_handler))
+        .route("/reports/daily-sales", get(daily_sales_summary_handler))
+        .route(
+            "/reports/product-ranking",
+            get(product_sales_ranking_handler),
+        )
         .layer(middleware::from_fn_with_state(state, auth_middleware))
 }
@@ -3,25 +3,54 @@
 // This module defines the authentication router that groups all auth endpoints
 // under /api/v1/auth prefix.
 
-use axum::{Router, routing::post};
+use axum::{Router, middleware, routing::post};
 
-use crate::handlers::{login_handler, refresh_handler, register_handler};
+use crate::handlers::{
+    admin_disable_mfa_handler, confirm_mfa_handler, disable_mfa_handler, enroll_mfa_handler,
+    forgot_password_handler, login_handler, refresh_handler, register_handler,
+    reset_password_handler, revoke_all_sessions_handler, verify_mfa_handler,
+};
+use crate::middleware::auth_middleware;
 use crate::middleware::rate_limit::auth_rate_limit_layer;
 use crate::state::AppState;
 
 /// Creates the authentication router with all auth endpoints.
 ///
-/// Rate limiting is applied to login and register to prevent brute-force attacks.
+/// Rate limiting is applied to all routes to prevent brute-force attacks.
+/// The MFA management and session routes additionally require authentication.
 ///
 /// # Routes
 ///
 /// - `POST /register` - Register a new ecommerce user (Requirement 5.1)
 /// - `POST /login` - Unified login with email or username (Requirement 5.3)
-/// - `POST /refresh` - Refresh access token (Requirement 5.4)
-pub fn auth_router() -> Router<AppState> {
+/// - `POST /refresh` - Refresh access token and rotate the refresh token (Requirement 5.4)
+/// - `POST /password/forgot` - Email a password reset token
+/// - `POST /password/reset` - Set a new password with a reset token
+/// - `POST /mfa/verify` - Complete a login with a TOTP or recovery code
+/// - `POST /mfa/enroll` - Start TOTP enrollment (authenticated)
+/// - `POST /mfa/confirm` - Enable TOTP and get recovery codes (authenticated)
+/// - `POST /mfa/disable` - Turn MFA off (authenticated)
+/// - `POST /mfa/users/{user_id}/disable` - Remove another user's MFA without a code (super_admin)
+/// - `POST /sessions/revoke` - Revoke all refresh tokens of the user (authenticated)
+pub fn auth_router(state: AppState) -> Router<AppState> {
+    let authenticated = Router::new()
+        .route("/mfa/enroll", post(enroll_mfa_handler))
+        .route("/mfa/confirm", post(confirm_mfa_handler))
+        .route("/mfa/disable", post(disable_mfa_handler))
+        .route(
+            "/mfa/users/{user_id}/disable",
+            post(admin_disable_mfa_handler),
+        )
+        .route("/sessions/revoke", post(revoke_all_sessions_handler))
+        .layer(middleware::from_fn_with_state(state, auth_middleware));
+
     Router::new()
         .route("/register", post(register_handler))
         .route("/login", post(login_handler))
         .route("/refresh", post(refresh_handler))
+        .route("/password/forgot", post(forgot_password_handler))
+        .route("/password/reset", post(reset_password_handler))
+        .route("/mfa/verify", post(verify_mfa_handler))
+        .merge(authenticated)
         .layer(auth_rate_limit_layer())
 }
@@ -1,4 +1,4 @@
-// Demand planning routes: forecasts, reorder policies, replenishment
+// Demand planning routes: forecasts, sales velocity, reorder policies, replenishment
 // suggestions, ABC classification.
 
 use axum::{
@@ -7,8 +7,9 @@ use axum::{
 };
 
 use crate::handlers::demand_planning::{
-    approve_suggestion_handler, dismiss_suggestion_handler, get_forecast_handler, list_abc_handler,
-    list_reorder_policies_handler, list_replenishment_suggestions_handler,
+    approve_suggestion_handler, dismiss_suggestion_handler, generate_replenishment_orders_handler,
+    get_availability_forecast_handler, get_forecast_handler, get_sales_velocity_handler,
+    list_abc_handler, list_reorder_policies_handler, list_replenishment_suggestions_handler,
     upsert_reorder_policy_handler,
 };
 use crate::middleware::auth_middleware;
@@ -17,6 +18,11 @@ use crate::state::AppState;
 pub fn forecasts_router(state: AppState) -> Router<AppState> {
     Router::new()
         .route("/products/{variant_id}", get(get_forecast_handler))
+        .route(
+            "/products/{variant_id}/availability",
+            get(get_availability_forecast_handler),
+        )
+        .route("/velocity", get(get_sales_velocity_handler))
         .layer(middleware::from_fn_with_state(state, auth_middleware))
 }
 
@@ -34,6 +40,10 @@ pub fn replenishment_suggestions_router(state: AppState) -> Router<AppState> {
         .route("/", get(list_replenishment_suggestions_handler))
         .route("/{id}/approve", post(approve_suggestion_handler))
         .route("/{id}/dismiss", post(dismiss_suggestion_handler))
+        .route(
+            "/stores/{store_id}/orders",
+            post(generate_replenishment_orders_handler),
+        )
         .layer(middleware::from_fn_with_state(state, auth_middleware))
 }
 
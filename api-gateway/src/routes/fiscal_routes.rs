@@ -10,8 +10,9 @@ use axum::{
 
 use crate::handlers::fiscal::{
     calculate_tax_handler, create_tax_rate_handler, delete_tax_rate_handler,
-    generate_invoice_handler, get_invoice_handler, get_tax_rate_handler, list_invoices_handler,
-    list_tax_rates_handler, update_tax_rate_handler, void_invoice_handler,
+    generate_invoice_handler, get_invoice_handler, get_tax_rate_handler,
+    invoice_sequence_audit_handler, list_invoices_handler, list_tax_rates_handler,
+    update_tax_rate_handler, void_invoice_handler,
 };
 use crate::middleware::auth_middleware;
 use crate::state::AppState;
@@ -21,6 +22,7 @@ use crate::state::AppState;
 /// # Routes
 /// - `POST /` - Generate invoice
 /// - `GET /` - List invoices
+/// - `GET /sequence-audit` - Audit issued numbers of a terminal's fiscal sequence
 /// - `GET /{id}` - Get invoice
 /// - `PUT /{id}/void` - Void invoice
 /// - `POST /calculate-tax` - Calculate tax
@@ -30,6 +32,7 @@ pub fn invoices_router(state: AppState) -> Router<AppState> {
             "/",
             post(generate_invoice_handler).get(list_invoices_handler),
         )
+        .route("/sequence-audit", get(invoice_sequence_audit_handler))
         .route("/{id}", get(get_invoice_handler))
         .route("/{id}/void", put(void_invoice_handler))
         .route("/calculate-tax", post(calculate_tax_handler))
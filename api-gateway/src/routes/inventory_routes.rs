@@ -13,23 +13,34 @@ use axum::{
 };
 
 use crate::handlers::{
-    apply_adjustment_handler, approve_adjustment_handler, bulk_initialize_stock_handler,
+    apply_adjustment_handler, approve_adjustment_handler, approve_reprice_handler,
+    approve_transfer_handler, bulk_initialize_stock_handler, bulk_update_prices_handler,
     calculate_recipe_cost_handler, cancel_reservation_handler, cancel_transfer_handler,
-    confirm_reservation_handler, create_adjustment_handler, create_category_handler,
-    create_product_handler, create_recipe_handler, create_reservation_handler,
-    create_transfer_handler, create_variant_handler, delete_category_handler,
+    close_period_handler, configure_category_barcode_prefix_handler, confirm_reservation_handler,
+    create_adjustment_handler, create_category_handler, create_product_handler,
+    create_recipe_handler, create_reservation_handler, create_transfer_handler,
+    create_valuation_snapshot_handler, create_variant_handler, delete_category_handler,
     delete_product_handler, delete_variant_handler, expire_reservations_handler,
-    get_adjustment_handler, get_category_children_handler, get_category_handler,
-    get_low_stock_report_handler, get_movements_report_handler, get_product_handler,
-    get_product_recipe_handler, get_product_stock_handler, get_recipe_handler, get_stock_handler,
-    get_stock_history_handler, get_transfer_handler, get_valuation_report_handler,
-    get_variant_handler, initialize_stock_handler, list_adjustments_handler,
-    list_categories_handler, list_products_handler, list_recipes_handler,
-    list_reservations_handler, list_stock_handler, list_transfers_handler, list_variants_handler,
-    receive_transfer_handler, reject_adjustment_handler, ship_transfer_handler,
-    submit_adjustment_handler, submit_transfer_handler, update_category_handler,
-    update_product_handler, update_recipe_handler, update_stock_levels_handler,
-    update_variant_handler,
+    export_products_handler, generate_variants_handler, get_adjustment_handler,
+    get_category_barcode_prefix_handler, get_category_children_handler, get_category_handler,
+    get_category_rollup_report_handler, get_expiring_stock_report_handler,
+    get_in_transit_report_handler, get_in_transit_stock_handler,
+    get_inventory_aging_report_handler, get_low_stock_report_handler, get_movements_report_handler,
+    get_product_handler, get_product_price_history_handler, get_product_recipe_handler,
+    get_product_stock_handler, get_recipe_handler, get_reprice_batch_handler,
+    get_reservation_conversion_report_handler, get_stock_handler, get_stock_history_handler,
+    get_transfer_handler, get_valuation_report_handler, get_valuation_snapshot_handler,
+    get_variant_handler, import_products_handler, initialize_stock_handler,
+    list_adjustments_handler, list_categories_handler, list_periods_handler, list_products_handler,
+    list_recipes_handler, list_reprice_batches_handler, list_reservations_handler,
+    list_stock_handler, list_transfers_handler, list_variants_handler, move_category_handler,
+    preview_reprice_handler, recalculate_average_cost_handler, receive_transfer_handler,
+    refresh_composite_costs_handler, register_serials_handler, reject_adjustment_handler,
+    reject_reprice_handler, reopen_period_handler, reorder_categories_handler,
+    search_products_handler, set_product_unit_conversions_handler, set_product_vendor_handler,
+    ship_transfer_handler, submit_adjustment_handler, submit_transfer_handler,
+    touch_reservation_handler, update_category_handler, update_product_handler,
+    update_recipe_handler, update_stock_levels_handler, update_variant_handler,
 };
 use crate::middleware::auth_middleware;
 use crate::state::AppState;
@@ -43,16 +54,26 @@ use crate::state::AppState;
 ///
 /// - `POST /` - Create a new product (requires products:create)
 /// - `GET /` - List products with pagination and filters
+/// - `GET /search` - Search products by relevance, tolerating typos
 /// - `GET /{id}` - Get product details with variants
 /// - `PUT /{id}` - Update product (requires products:update)
 /// - `DELETE /{id}` - Soft delete product (requires products:delete)
+/// - `PUT /{id}/vendors` - Link a vendor to the product (requires products:update)
+/// - `PUT /{id}/unit-conversions` - Replace the product's unit conversions (requires products:update)
 /// - `POST /{product_id}/variants` - Create variant (requires products:create)
+/// - `POST /{product_id}/variants/generate` - Generate variants from attribute axes (requires products:create)
 /// - `GET /{product_id}/variants` - List variants
 /// - `GET /{product_id}/variants/{variant_id}` - Get variant details
 /// - `PUT /{product_id}/variants/{variant_id}` - Update variant (requires products:update)
 /// - `DELETE /{product_id}/variants/{variant_id}` - Delete variant (requires products:delete)
 /// - `GET /{product_id}/recipe` - Get active recipe for product
 /// - `GET /{product_id}/stock` - Get product stock across all stores
+/// - `GET /{product_id}/price-history` - Get cost and price history (requires products:read)
+/// - `POST /reprices` - Preview prices for a vendor cost update (requires products:update)
+/// - `GET /reprices` - List reprice batches (requires products:read)
+/// - `GET /reprices/{id}` - Get a reprice batch (requires products:read)
+/// - `PUT /reprices/{id}/approve` - Apply a reprice batch (requires products:reprice)
+/// - `PUT /reprices/{id}/reject` - Reject a reprice batch (requires products:reprice)
 ///
 /// # Usage
 ///
@@ -65,6 +86,9 @@ pub fn products_router(state: AppState) -> Router<AppState> {
     Router::new()
         // Collection routes
         .route("/", post(create_product_handler).get(list_products_handler))
+        .route("/search", get(search_products_handler))
+        .route("/import", post(import_products_handler))
+        .route("/export", get(export_products_handler))
         // Individual product routes
         .route(
             "/{id}",
@@ -72,11 +96,20 @@ pub fn products_router(state: AppState) -> Router<AppState> {
                 .put(update_product_handler)
                 .delete(delete_product_handler),
         )
+        .route("/{id}/vendors", put(set_product_vendor_handler))
+        .route(
+            "/{id}/unit-conversions",
+            put(set_product_unit_conversions_handler),
+        )
         // Variant collection routes
         .route(
             "/{product_id}/variants",
             post(create_variant_handler).get(list_variants_handler),
         )
+        .route(
+            "/{product_id}/variants/generate",
+            post(generate_variants_handler),
+        )
         // Individual variant routes
         .route(
             "/{product_id}/variants/{variant_id}",
@@ -88,6 +121,19 @@ pub fn products_router(state: AppState) -> Router<AppState> {
         .route("/{product_id}/recipe", get(get_product_recipe_handler))
         // Product stock route
         .route("/{product_id}/stock", get(get_product_stock_handler))
+        // Product price history route
+        .route(
+            "/{product_id}/price-history",
+            get(get_product_price_history_handler),
+        )
+        // Batch reprice routes
+        .route(
+            "/reprices",
+            post(preview_reprice_handler).get(list_reprice_batches_handler),
+        )
+        .route("/reprices/{id}", get(get_reprice_batch_handler))
+        .route("/reprices/{id}/approve", put(approve_reprice_handler))
+        .route("/reprices/{id}/reject", put(reject_reprice_handler))
         // Apply authentication middleware to all routes
         .layer(middleware::from_fn_with_state(state, auth_middleware))
 }
@@ -104,6 +150,7 @@ pub fn products_router(state: AppState) -> Router<AppState> {
 /// - `GET /{id}` - Get recipe details with ingredients
 /// - `PUT /{id}` - Update recipe (requires recipes:update)
 /// - `POST /{recipe_id}/calculate-cost` - Calculate recipe cost
+/// - `POST /refresh-costs` - Recompute all composite costs (requires recipes:update)
 ///
 /// # Usage
 ///
@@ -119,6 +166,7 @@ pub fn recipes_router(state: AppState) -> Router<AppState> {
         // Individual recipe routes
         .route("/{id}", get(get_recipe_handler).put(update_recipe_handler))
         // Recipe cost calculation
+        .route("/refresh-costs", post(refresh_composite_costs_handler))
         .route(
             "/{recipe_id}/calculate-cost",
             post(calculate_recipe_cost_handler),
@@ -143,12 +191,15 @@ pub fn recipes_router(state: AppState) -> Router<AppState> {
 /// - `GET /stock/{stock_id}` - Get stock details
 /// - `PUT /stock/{stock_id}/levels` - Update stock level thresholds (requires inventory:write)
 /// - `GET /stock/{stock_id}/history` - Get stock movement history (requires inventory:read)
+/// - `POST /stock/recalculate-cost` - Replay the kardex to correct average cost (requires inventory:write)
+/// - `POST /serials` - Register serial numbers of serial-tracked units (requires inventory:write)
 ///
 /// ## Reservation Routes
 /// - `POST /reservations` - Create a reservation (requires cart:add or sales:create)
 /// - `GET /reservations` - List reservations (requires inventory:read)
 /// - `PUT /reservations/{id}/confirm` - Confirm a reservation (requires sales:create)
 /// - `PUT /reservations/{id}/cancel` - Cancel a reservation (requires cart:remove or sales:void)
+/// - `PUT /reservations/{id}/touch` - Extend an auto-extending reservation (requires cart:add or sales:create)
 /// - `POST /reservations/expire` - Expire all expired reservations (requires organization:admin)
 ///
 /// ## Adjustment Routes
@@ -160,6 +211,11 @@ pub fn recipes_router(state: AppState) -> Router<AppState> {
 /// - `PUT /adjustments/{id}/reject` - Reject adjustment (requires inventory:adjustments:approve)
 /// - `POST /adjustments/{id}/apply` - Apply to stock (requires inventory:adjustments:apply)
 ///
+/// ## Period Routes
+/// - `GET /periods` - List a store's periods (requires inventory:read)
+/// - `POST /periods/close` - Close a period (requires inventory:close_period)
+/// - `PUT /periods/{id}/reopen` - Reopen a closed period (requires inventory:reopen_period)
+///
 /// # Usage
 ///
 /// ```rust,ignore
@@ -176,12 +232,19 @@ pub fn inventory_router(state: AppState) -> Router<AppState> {
         )
         // Bulk stock initialization
         .route("/stock/bulk", post(bulk_initialize_stock_handler))
+        // Weighted-average cost correction
+        .route(
+            "/stock/recalculate-cost",
+            post(recalculate_average_cost_handler),
+        )
         // Individual stock routes
         .route("/stock/{stock_id}", get(get_stock_handler))
         // Stock levels update
         .route("/stock/{stock_id}/levels", put(update_stock_levels_handler))
         // Stock history route
         .route("/stock/{stock_id}/history", get(get_stock_history_handler))
+        // Serial number registration
+        .route("/serials", post(register_serials_handler))
         // Reservation collection routes
         .route(
             "/reservations",
@@ -193,6 +256,7 @@ pub fn inventory_router(state: AppState) -> Router<AppState> {
             put(confirm_reservation_handler),
         )
         .route("/reservations/{id}/cancel", put(cancel_reservation_handler))
+        .route("/reservations/{id}/touch", put(touch_reservation_handler))
         // Reservation batch operations
         .route("/reservations/expire", post(expire_reservations_handler))
         // Adjustment collection routes
@@ -207,6 +271,10 @@ pub fn inventory_router(state: AppState) -> Router<AppState> {
         .route("/adjustments/{id}/approve", put(approve_adjustment_handler))
         .route("/adjustments/{id}/reject", put(reject_adjustment_handler))
         .route("/adjustments/{id}/apply", post(apply_adjustment_handler))
+        // Period close routes
+        .route("/periods", get(list_periods_handler))
+        .route("/periods/close", post(close_period_handler))
+        .route("/periods/{id}/reopen", put(reopen_period_handler))
         // Apply authentication middleware to all routes
         .layer(middleware::from_fn_with_state(state, auth_middleware))
 }
@@ -219,14 +287,48 @@ pub fn inventory_router(state: AppState) -> Router<AppState> {
 /// # Routes
 ///
 /// - `GET /inventory/valuation` - Get inventory valuation report (requires reports:inventory)
+/// - `POST /inventory/valuation/snapshots` - Record a period-end valuation snapshot (requires inventory:close_period)
+/// - `GET /inventory/valuation/snapshots/{store_id}/{period_end}` - Get a valuation snapshot (requires reports:inventory)
+/// - `GET /inventory/in-transit` - Get stock in transit between stores (requires reports:inventory)
+/// - `GET /inventory/in-transit/transfers` - Get in-transit transfers by destination (requires reports:inventory)
+/// - `GET /inventory/category-rollup` - Get stock value rolled up by category (requires reports:inventory)
 /// - `GET /inventory/low-stock` - Get low stock report (requires reports:inventory)
+/// - `GET /inventory/expiring` - Get lots expiring within a window (requires reports:inventory)
+/// - `GET /inventory/aging` - Get on-hand stock bucketed by age (requires reports:inventory)
 /// - `GET /inventory/movements` - Get movements report (requires reports:inventory)
+/// - `GET /inventory/reservation-conversion` - Get reservation hold-to-sale conversion (requires reports:inventory)
 pub fn reports_router(state: AppState) -> Router<AppState> {
     Router::new()
         // Inventory reports
         .route("/inventory/valuation", get(get_valuation_report_handler))
+        .route(
+            "/inventory/valuation/snapshots",
+            post(create_valuation_snapshot_handler),
+        )
+        .route(
+            "/inventory/valuation/snapshots/{store_id}/{period_end}",
+            get(get_valuation_snapshot_handler),
+        )
+        .route("/inventory/in-transit", get(get_in_transit_stock_handler))
+        .route(
+            "/inventory/in-transit/transfers",
+            get(get_in_transit_report_handler),
+        )
+        .route(
+            "/inventory/category-rollup",
+            get(get_category_rollup_report_handler),
+        )
         .route("/inventory/low-stock", get(get_low_stock_report_handler))
+        .route(
+            "/inventory/expiring",
+            get(get_expiring_stock_report_handler),
+        )
+        .route("/inventory/aging", get(get_inventory_aging_report_handler))
         .route("/inventory/movements", get(get_movements_report_handler))
+        .route(
+            "/inventory/reservation-conversion",
+            get(get_reservation_conversion_report_handler),
+        )
         // Apply authentication middleware to all routes
         .layer(middleware::from_fn_with_state(state, auth_middleware))
 }
@@ -239,6 +341,7 @@ pub fn reports_router(state: AppState) -> Router<AppState> {
 /// - `GET /` - List transfers (requires transfers:read)
 /// - `GET /{id}` - Get transfer details (requires transfers:read)
 /// - `PUT /{id}/submit` - Submit for processing (requires transfers:create)
+/// - `PUT /{id}/approve` - Approve or reject a pending approval (requires transfers:approve)
 /// - `PUT /{id}/ship` - Ship the transfer (requires transfers:ship)
 /// - `PUT /{id}/receive` - Receive the transfer (requires transfers:receive)
 /// - `PUT /{id}/cancel` - Cancel the transfer (requires transfers:create)
@@ -250,6 +353,7 @@ pub fn transfers_router(state: AppState) -> Router<AppState> {
         )
         .route("/{id}", get(get_transfer_handler))
         .route("/{id}/submit", put(submit_transfer_handler))
+        .route("/{id}/approve", put(approve_transfer_handler))
         .route("/{id}/ship", put(ship_transfer_handler))
         .route("/{id}/receive", put(receive_transfer_handler))
         .route("/{id}/cancel", put(cancel_transfer_handler))
@@ -266,6 +370,11 @@ pub fn transfers_router(state: AppState) -> Router<AppState> {
 /// - `GET /{id}/children` - Get child categories (requires categories:read)
 /// - `PUT /{id}` - Update category (requires categories:update)
 /// - `DELETE /{id}` - Soft delete category (requires categories:delete)
+/// - `PUT /reorder` - Set the order of sibling categories (requires categories:update)
+/// - `PUT /{id}/move` - Move a category under another parent (requires categories:update)
+/// - `GET /{id}/barcode-prefix` - Get the generated barcode prefix (requires categories:read)
+/// - `PUT /{id}/barcode-prefix` - Set the generated barcode prefix (requires categories:update)
+/// - `POST /{id}/bulk-price-update` - Adjust prices of the category's products (requires products:update)
 pub fn categories_router(state: AppState) -> Router<AppState> {
     Router::new()
         .route(
@@ -278,6 +387,13 @@ pub fn categories_router(state: AppState) -> Router<AppState> {
                 .put(update_category_handler)
                 .delete(delete_category_handler),
         )
+        .route("/reorder", put(reorder_categories_handler))
         .route("/{id}/children", get(get_category_children_handler))
+        .route("/{id}/move", put(move_category_handler))
+        .route(
+            "/{id}/barcode-prefix",
+            get(get_category_barcode_prefix_handler).put(configure_category_barcode_prefix_handler),
+        )
+        .route("/{id}/bulk-price-update", post(bulk_update_prices_handler))
         .layer(middleware::from_fn_with_state(state, auth_middleware))
 }
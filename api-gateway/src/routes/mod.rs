@@ -11,6 +11,7 @@ pub mod fiscal_routes;
 pub mod internal_routes;
 pub mod inventory_routes;
 pub mod loyalty_routes;
+pub mod notifications_routes;
 pub mod payments_routes;
 pub mod purchasing_routes;
 pub mod restaurant_routes;
@@ -50,6 +51,7 @@ pub use inventory_routes::{
 pub use loyalty_routes::{
     loyalty_members_router, loyalty_programs_router, loyalty_rewards_router, loyalty_tiers_router,
 };
+pub use notifications_routes::event_webhooks_router;
 pub use payments_routes::{
     payment_gateways_router, payouts_router, transactions_router, webhooks_router,
 };
@@ -59,8 +61,9 @@ pub use restaurant_routes::{
     restaurant_product_modifiers_router, restaurant_stations_router, restaurant_tables_router,
 };
 pub use sales_routes::{
-    cart_router, credit_notes_router, customers_router, orders_router, pos_sales_router,
-    promotions_router, shifts_router,
+    cart_router, credit_notes_router, customers_router, exchange_rates_router, gift_cards_router,
+    markdowns_router, orders_router, pos_sales_router, price_lists_router, price_tiers_router,
+    promotions_router, shifts_router, tax_rules_router, weight_barcode_formats_router,
 };
 pub use service_orders_routes::{
     public_service_orders_router, service_orders_assets_router, service_orders_router,
@@ -0,0 +1,27 @@
+// Notifications routes: outbound event webhooks.
+
+use axum::{
+    Router, middleware,
+    routing::{delete, get},
+};
+
+use crate::handlers::notifications::{
+    list_failed_webhook_deliveries_handler, list_webhooks_handler, register_webhook_handler,
+    unregister_webhook_handler,
+};
+use crate::middleware::auth_middleware;
+use crate::state::AppState;
+
+pub fn event_webhooks_router(state: AppState) -> Router<AppState> {
+    Router::new()
+        .route(
+            "/",
+            get(list_webhooks_handler).post(register_webhook_handler),
+        )
+        .route(
+            "/failed-deliveries",
+            get(list_failed_webhook_deliveries_handler),
+        )
+        .route("/{id}", delete(unregister_webhook_handler))
+        .layer(middleware::from_fn_with_state(state, auth_middleware))
+}
@@ -13,11 +13,12 @@ use axum::{
 };
 
 use crate::handlers::{
-    activate_vendor_handler, approve_purchase_order_handler, cancel_goods_receipt_handler,
-    cancel_purchase_order_handler, close_purchase_order_handler, confirm_goods_receipt_handler,
-    create_goods_receipt_handler, create_purchase_order_handler, create_vendor_handler,
-    deactivate_vendor_handler, get_goods_receipt_handler, get_purchase_order_handler,
-    get_vendor_handler, list_goods_receipts_handler, list_purchase_orders_handler,
+    activate_vendor_handler, approve_purchase_order_handler, bulk_cancel_purchase_orders_handler,
+    cancel_goods_receipt_handler, cancel_purchase_order_handler, close_purchase_order_handler,
+    confirm_goods_receipt_handler, create_goods_receipt_handler, create_purchase_order_handler,
+    create_vendor_handler, deactivate_vendor_handler, get_goods_receipt_handler,
+    get_price_history_handler, get_purchase_order_handler, get_vendor_handler,
+    get_vendor_performance_handler, list_goods_receipts_handler, list_purchase_orders_handler,
     list_vendors_handler, reject_purchase_order_handler, submit_purchase_order_handler,
     update_purchase_order_handler, update_vendor_handler,
 };
@@ -33,6 +34,7 @@ use crate::state::AppState;
 ///
 /// - `POST /` - Create a new vendor (requires vendors:create)
 /// - `GET /` - List vendors with pagination and filters (requires vendors:read)
+/// - `GET /performance` - Rank vendors by delivery performance (requires vendors:read)
 /// - `GET /{id}` - Get vendor details (requires vendors:read)
 /// - `PUT /{id}` - Update vendor (requires vendors:update)
 /// - `PUT /{id}/activate` - Activate vendor (requires vendors:update)
@@ -49,6 +51,7 @@ pub fn vendors_router(state: AppState) -> Router<AppState> {
     Router::new()
         // Collection routes
         .route("/", post(create_vendor_handler).get(list_vendors_handler))
+        .route("/performance", get(get_vendor_performance_handler))
         // Individual vendor routes
         .route("/{id}", get(get_vendor_handler).put(update_vendor_handler))
         // Vendor status routes
@@ -67,6 +70,8 @@ pub fn vendors_router(state: AppState) -> Router<AppState> {
 ///
 /// - `POST /` - Create a new purchase order (requires purchase_orders:create)
 /// - `GET /` - List purchase orders with pagination and filters (requires purchase_orders:read)
+/// - `GET /price-history` - Last N prices paid for a product (requires purchase_orders:read)
+/// - `POST /bulk-cancel` - Cancel stale draft/submitted orders (requires purchase_orders:cancel)
 /// - `GET /{id}` - Get purchase order details with items (requires purchase_orders:read)
 /// - `PUT /{id}/submit` - Submit for approval (requires purchase_orders:submit)
 /// - `PUT /{id}/approve` - Approve purchase order (requires purchase_orders:approve)
@@ -88,6 +93,8 @@ pub fn purchase_orders_router(state: AppState) -> Router<AppState> {
             "/",
             post(create_purchase_order_handler).get(list_purchase_orders_handler),
         )
+        .route("/price-history", get(get_price_history_handler))
+        .route("/bulk-cancel", post(bulk_cancel_purchase_orders_handler))
         // Individual purchase order routes
         .route(
             "/{id}",
@@ -6,6 +6,7 @@
 // Customers: /api/v1/customers
 // Shifts: /api/v1/shifts
 // POS Sales: /api/v1/sales
+// Gift Cards: /api/v1/gift-cards
 
 use axum::{
     Router, middleware,
@@ -15,18 +16,36 @@ use axum::{
 use crate::handlers::{
     activate_customer_handler, add_cart_item_handler, add_credit_note_item_handler,
     add_sale_item_handler, apply_credit_note_handler, apply_discount_handler,
-    apply_promotion_handler, approve_credit_note_handler, cancel_credit_note_handler,
-    cancel_order_handler, cash_in_handler, cash_out_handler, clear_cart_handler,
-    close_shift_handler, complete_sale_handler, create_cart_handler, create_credit_note_handler,
-    create_customer_handler, create_pos_sale_handler, create_promotion_handler,
-    deactivate_customer_handler, deactivate_promotion_handler, deliver_order_handler,
-    get_cart_handler, get_credit_note_handler, get_current_shift_handler, get_customer_handler,
-    get_promotion_handler, get_sale_handler, get_shift_report_handler, list_credit_notes_handler,
-    list_customers_handler, list_promotions_handler, list_sales_handler, list_shifts_handler,
-    mark_order_paid_handler, open_shift_handler, process_order_handler, process_payment_handler,
+    apply_promotion_handler, approve_credit_note_handler, calculate_sale_tax_handler,
+    cancel_credit_note_handler, cancel_layaway_handler, cancel_order_handler, cash_in_handler,
+    cash_out_handler, claim_order_pick_handler, clear_cart_handler, close_shift_handler,
+    complete_pickup_handler, complete_sale_handler, configure_customer_code_format_handler,
+    create_cart_handler, create_credit_note_handler, create_customer_handler,
+    create_pos_sale_handler, create_price_list_handler, create_promotion_handler,
+    create_weight_barcode_format_handler, deactivate_customer_handler,
+    deactivate_promotion_handler, delete_weight_barcode_format_handler, deliver_order_handler,
+    evaluate_promotions_handler, export_customers_handler, export_sales_handler, get_cart_handler,
+    get_credit_note_document_handler, get_credit_note_handler, get_current_shift_handler,
+    get_customer_by_code_handler, get_customer_code_format_handler,
+    get_customer_credit_balance_handler, get_customer_handler, get_customer_statement_handler,
+    get_gift_card_balance_handler, get_layaway_balance_handler, get_order_fulfillment_handler,
+    get_order_pickup_handler, get_promotion_handler, get_sale_handler, get_shift_report_handler,
+    get_shift_snapshot_handler, hold_order_pick_handler, issue_gift_card_handler,
+    list_credit_notes_handler, list_customers_handler, list_exchange_rates_handler,
+    list_markdown_rules_handler, list_markdowns_handler, list_pick_queue_handler,
+    list_price_lists_handler, list_price_tiers_handler, list_promotions_handler,
+    list_sales_handler, list_shifts_handler, list_tax_rules_handler,
+    list_weight_barcode_formats_handler, lookup_barcode_handler, lookup_serial_sale_handler,
+    mark_order_paid_handler, mark_order_ready_for_pickup_handler, open_shift_handler,
+    park_sale_handler, process_order_handler, process_payment_handler, quick_sale_handler,
+    record_account_payment_handler, record_exchange_rate_handler, release_order_pick_handler,
     remove_cart_item_handler, remove_credit_note_item_handler, remove_sale_item_handler,
+    reopen_shift_handler, resume_order_pick_handler, resume_sale_handler, reverse_markdown_handler,
+    run_markdowns_handler, set_price_list_entry_handler, set_sale_currency_handler,
     ship_order_handler, submit_credit_note_handler, update_cart_item_handler,
-    update_customer_handler, update_promotion_handler, update_sale_item_handler, void_sale_handler,
+    update_customer_handler, update_promotion_handler, update_sale_item_handler,
+    upsert_markdown_rule_handler, upsert_price_tier_handler, upsert_tax_rule_handler,
+    void_sale_handler,
 };
 use crate::middleware::auth_middleware;
 use crate::state::AppState;
@@ -36,22 +55,44 @@ use crate::state::AppState;
 /// # Routes
 /// - `POST /` - Create customer
 /// - `GET /` - List customers
+/// - `GET /by-code` - Get customer by code (`?store_id=&code=`)
+/// - `GET /export` - Export customers as CSV (`?store_id=`; PII masked without `sales:read_customer_pii`)
+/// - `GET /code-format/{store_id}` - Get the store's customer code format
+/// - `PUT /code-format/{store_id}` - Configure the store's customer code format
 /// - `GET /{id}` - Get customer
 /// - `PUT /{id}` - Update customer
 /// - `PUT /{id}/activate` - Activate customer
 /// - `PUT /{id}/deactivate` - Deactivate customer
+/// - `GET /{id}/statement` - Account statement for a period (`?from=&to=`)
+/// - `GET /{id}/store-credit` - Store credit balance
+/// - `POST /{id}/account/payments` - Record a payment against the account balance
 pub fn customers_router(state: AppState) -> Router<AppState> {
     Router::new()
         .route(
             "/",
             post(create_customer_handler).get(list_customers_handler),
         )
+        .route("/by-code", get(get_customer_by_code_handler))
+        .route("/export", get(export_customers_handler))
+        .route(
+            "/code-format/{store_id}",
+            get(get_customer_code_format_handler).put(configure_customer_code_format_handler),
+        )
         .route(
             "/{id}",
             get(get_customer_handler).put(update_customer_handler),
         )
         .route("/{id}/activate", put(activate_customer_handler))
         .route("/{id}/deactivate", put(deactivate_customer_handler))
+        .route("/{id}/statement", get(get_customer_statement_handler))
+        .route(
+            "/{id}/store-credit",
+            get(get_customer_credit_balance_handler),
+        )
+        .route(
+            "/{id}/account/payments",
+            post(record_account_payment_handler),
+        )
         .layer(middleware::from_fn_with_state(state, auth_middleware))
 }
 
@@ -62,7 +103,9 @@ pub fn customers_router(state: AppState) -> Router<AppState> {
 /// - `GET /` - List shifts
 /// - `GET /current/{terminal_id}` - Get current open shift for terminal
 /// - `GET /{id}/report` - Get shift report
+/// - `GET /{id}/snapshot` - Get a running-totals snapshot (X-report) of an open shift
 /// - `PUT /{id}/close` - Close shift
+/// - `PUT /{id}/reopen` - Reopen a recently closed shift (manager)
 /// - `POST /{id}/cash-in` - Record cash in
 /// - `POST /{id}/cash-out` - Record cash out
 pub fn shifts_router(state: AppState) -> Router<AppState> {
@@ -70,7 +113,9 @@ pub fn shifts_router(state: AppState) -> Router<AppState> {
         .route("/", post(open_shift_handler).get(list_shifts_handler))
         .route("/current/{terminal_id}", get(get_current_shift_handler))
         .route("/{id}/report", get(get_shift_report_handler))
+        .route("/{id}/snapshot", get(get_shift_snapshot_handler))
         .route("/{id}/close", put(close_shift_handler))
+        .route("/{id}/reopen", put(reopen_shift_handler))
         .route("/{id}/cash-in", post(cash_in_handler))
         .route("/{id}/cash-out", post(cash_out_handler))
         .layer(middleware::from_fn_with_state(state, auth_middleware))
@@ -80,18 +125,34 @@ pub fn shifts_router(state: AppState) -> Router<AppState> {
 ///
 /// # Routes
 /// - `POST /` - Create POS sale
-/// - `GET /` - List sales
+/// - `GET /` - List sales (`?status=parked&terminal_id=` lists a terminal's parked tickets)
+/// - `GET /export` - Export sales as CSV (`?store_id=`)
+/// - `POST /quick` - Single-item cash sale in one call
+/// - `GET /barcodes/{barcode}` - Resolve a scanned barcode (`?terminal_id=`)
+/// - `GET /serials/{serial_number}` - Find the sale of a serialized unit
 /// - `GET /{id}` - Get sale details
 /// - `POST /{id}/items` - Add item to sale
 /// - `PUT /{id}/items/{item_id}` - Update sale item
 /// - `DELETE /{id}/items/{item_id}` - Remove sale item
 /// - `POST /{id}/discount` - Apply discount
+/// - `POST /{id}/tax` - Tax the lines under the store's tax rules
+/// - `PUT /{id}/currency` - Quote the sale in another currency
 /// - `POST /{id}/payment` - Process payment
 /// - `PUT /{id}/complete` - Complete sale
 /// - `PUT /{id}/void` - Void sale
+/// - `PUT /{id}/park` - Park sale, releasing its reserved stock
+/// - `PUT /{id}/resume` - Resume a parked sale, reserving its stock again
+/// - `GET /{id}/layaway` - Layaway balance and installment schedule
+/// - `POST /{id}/layaway/cancel` - Cancel a layaway, releasing its stock
+/// - `POST /{id}/apply-promotion` - Apply a promotion by code
+/// - `POST /{id}/evaluate-promotions` - Evaluate promotions, optionally applying the best combination
 pub fn pos_sales_router(state: AppState) -> Router<AppState> {
     Router::new()
         .route("/", post(create_pos_sale_handler).get(list_sales_handler))
+        .route("/export", get(export_sales_handler))
+        .route("/quick", post(quick_sale_handler))
+        .route("/barcodes/{barcode}", get(lookup_barcode_handler))
+        .route("/serials/{serial_number}", get(lookup_serial_sale_handler))
         .route("/{id}", get(get_sale_handler))
         .route("/{id}/items", post(add_sale_item_handler))
         .route(
@@ -99,10 +160,20 @@ pub fn pos_sales_router(state: AppState) -> Router<AppState> {
             put(update_sale_item_handler).delete(remove_sale_item_handler),
         )
         .route("/{id}/discount", post(apply_discount_handler))
+        .route("/{id}/tax", post(calculate_sale_tax_handler))
+        .route("/{id}/currency", put(set_sale_currency_handler))
         .route("/{id}/payment", post(process_payment_handler))
         .route("/{id}/complete", put(complete_sale_handler))
         .route("/{id}/void", put(void_sale_handler))
+        .route("/{id}/park", put(park_sale_handler))
+        .route("/{id}/resume", put(resume_sale_handler))
+        .route("/{id}/layaway", get(get_layaway_balance_handler))
+        .route("/{id}/layaway/cancel", post(cancel_layaway_handler))
         .route("/{id}/apply-promotion", post(apply_promotion_handler))
+        .route(
+            "/{id}/evaluate-promotions",
+            post(evaluate_promotions_handler),
+        )
         .layer(middleware::from_fn_with_state(state, auth_middleware))
 }
 
@@ -136,6 +207,7 @@ pub fn cart_router(state: AppState) -> Router<AppState> {
 /// - `POST /` - Create credit note
 /// - `GET /` - List credit notes
 /// - `GET /{id}` - Get credit note
+/// - `GET /{id}/document` - Get the printable credit note document
 /// - `POST /{id}/items` - Add item to credit note
 /// - `DELETE /{id}/items/{item_id}` - Remove item from credit note
 /// - `PUT /{id}/submit` - Submit credit note for approval
@@ -149,6 +221,7 @@ pub fn credit_notes_router(state: AppState) -> Router<AppState> {
             post(create_credit_note_handler).get(list_credit_notes_handler),
         )
         .route("/{id}", get(get_credit_note_handler))
+        .route("/{id}/document", get(get_credit_note_document_handler))
         .route("/{id}/items", post(add_credit_note_item_handler))
         .route(
             "/{id}/items/{item_id}",
@@ -183,6 +256,117 @@ pub fn promotions_router(state: AppState) -> Router<AppState> {
         .layer(middleware::from_fn_with_state(state, auth_middleware))
 }
 
+/// Creates the markdowns router for automatic markdowns of aging stock.
+///
+/// # Routes
+/// - `GET /rules` - List markdown rules (requires promotions:read)
+/// - `PUT /rules` - Create or update a store/category rule (requires promotions:update)
+/// - `GET /` - List markdowns (requires promotions:read)
+/// - `POST /run` - Apply automatic markdowns now (requires promotions:update)
+/// - `POST /{id}/reverse` - Reverse an active markdown (requires promotions:update)
+pub fn markdowns_router(state: AppState) -> Router<AppState> {
+    Router::new()
+        .route(
+            "/rules",
+            get(list_markdown_rules_handler).put(upsert_markdown_rule_handler),
+        )
+        .route("/", get(list_markdowns_handler))
+        .route("/run", post(run_markdowns_handler))
+        .route("/{id}/reverse", post(reverse_markdown_handler))
+        .layer(middleware::from_fn_with_state(state, auth_middleware))
+}
+
+/// Creates the exchange rates router for foreign currency tenders.
+///
+/// # Routes
+/// - `GET /` - List a store's rate history (`?store_id=&currency=`)
+/// - `POST /` - Record a rate, effective now (requires
+///   sales:manage_exchange_rates)
+pub fn exchange_rates_router(state: AppState) -> Router<AppState> {
+    Router::new()
+        .route(
+            "/",
+            get(list_exchange_rates_handler).post(record_exchange_rate_handler),
+        )
+        .layer(middleware::from_fn_with_state(state, auth_middleware))
+}
+
+/// Creates the gift cards router.
+///
+/// # Routes
+/// - `POST /` - Issue a card, on its own or sold on a sale (requires
+///   gift_cards:issue)
+/// - `GET /{code}/balance` - Check a card's balance (requires gift_cards:read)
+pub fn gift_cards_router(state: AppState) -> Router<AppState> {
+    Router::new()
+        .route("/", post(issue_gift_card_handler))
+        .route("/{code}/balance", get(get_gift_card_balance_handler))
+        .layer(middleware::from_fn_with_state(state, auth_middleware))
+}
+
+/// Creates the weight barcode formats router for scale-printed labels.
+///
+/// # Routes
+/// - `GET /` - List a store's formats (`?store_id=`)
+/// - `POST /` - Register a format (requires sales:manage_barcode_formats)
+/// - `DELETE /{id}` - Remove a format (`?store_id=`, requires
+///   sales:manage_barcode_formats)
+pub fn weight_barcode_formats_router(state: AppState) -> Router<AppState> {
+    Router::new()
+        .route(
+            "/",
+            get(list_weight_barcode_formats_handler).post(create_weight_barcode_format_handler),
+        )
+        .route("/{id}", delete(delete_weight_barcode_format_handler))
+        .layer(middleware::from_fn_with_state(state, auth_middleware))
+}
+
+/// Creates the price lists router for customer-type price lists.
+///
+/// # Routes
+/// - `GET /` - List a store's price lists (requires promotions:read)
+/// - `POST /` - Create a price list (requires promotions:create)
+/// - `PUT /{id}/entries` - Set a product or variant price on a list
+///   (requires promotions:update)
+pub fn price_lists_router(state: AppState) -> Router<AppState> {
+    Router::new()
+        .route(
+            "/",
+            get(list_price_lists_handler).post(create_price_list_handler),
+        )
+        .route("/{id}/entries", put(set_price_list_entry_handler))
+        .layer(middleware::from_fn_with_state(state, auth_middleware))
+}
+
+/// Creates the price tiers router for customer-type pricing.
+///
+/// # Routes
+/// - `GET /` - List a store's price tiers (requires promotions:read)
+/// - `PUT /` - Create or update a tier (requires promotions:update)
+pub fn price_tiers_router(state: AppState) -> Router<AppState> {
+    Router::new()
+        .route(
+            "/",
+            get(list_price_tiers_handler).put(upsert_price_tier_handler),
+        )
+        .layer(middleware::from_fn_with_state(state, auth_middleware))
+}
+
+/// Creates the tax rules router for per-product, per-category and
+/// store-wide tax.
+///
+/// # Routes
+/// - `GET /` - List a store's tax rules (requires tax_rates:read)
+/// - `PUT /` - Create or update a rule (requires tax_rates:update)
+pub fn tax_rules_router(state: AppState) -> Router<AppState> {
+    Router::new()
+        .route(
+            "/",
+            get(list_tax_rules_handler).put(upsert_tax_rule_handler),
+        )
+        .layer(middleware::from_fn_with_state(state, auth_middleware))
+}
+
 /// Creates the e-commerce orders router for order workflow transitions.
 ///
 /// # Routes
@@ -191,6 +375,15 @@ pub fn promotions_router(state: AppState) -> Router<AppState> {
 /// - `PUT /{id}/ship` - Ship order (requires orders:ship)
 /// - `PUT /{id}/deliver` - Mark delivered (requires orders:deliver)
 /// - `PUT /{id}/cancel` - Cancel order (requires orders:cancel)
+/// - `GET /{id}/fulfillment` - Fulfillment status (requires shipments:read, or orders:view_own for own orders)
+/// - `PUT /{id}/ready-for-pickup` - Hold for pickup and notify the customer (requires orders:process)
+/// - `GET /{id}/pickup` - Pickup code and hold (requires orders:process)
+/// - `POST /{id}/pickup` - Hand over against the pickup code (requires orders:deliver)
+/// - `GET /pick-queue` - Paid orders waiting to be picked at a store (requires orders:process)
+/// - `PUT /{id}/claim` - Claim an order for picking (requires orders:process)
+/// - `PUT /{id}/release` - Return a claimed order to the queue (requires orders:process)
+/// - `PUT /{id}/hold` - Take an order out of the queue (requires orders:process)
+/// - `PUT /{id}/resume` - Put a held order back in the queue (requires orders:process)
 pub fn orders_router(state: AppState) -> Router<AppState> {
     Router::new()
         .route("/{id}/mark-paid", put(mark_order_paid_handler))
@@ -198,5 +391,19 @@ pub fn orders_router(state: AppState) -> Router<AppState> {
         .route("/{id}/ship", put(ship_order_handler))
         .route("/{id}/deliver", put(deliver_order_handler))
         .route("/{id}/cancel", put(cancel_order_handler))
+        .route("/{id}/fulfillment", get(get_order_fulfillment_handler))
+        .route(
+            "/{id}/ready-for-pickup",
+            put(mark_order_ready_for_pickup_handler),
+        )
+        .route(
+            "/{id}/pickup",
+            get(get_order_pickup_handler).post(complete_pickup_handler),
+        )
+        .route("/pick-queue", get(list_pick_queue_handler))
+        .route("/{id}/claim", put(claim_order_pick_handler))
+        .route("/{id}/release", put(release_order_pick_handler))
+        .route("/{id}/hold", put(hold_order_pick_handler))
+        .route("/{id}/resume", put(resume_order_pick_handler))
         .layer(middleware::from_fn_with_state(state, auth_middleware))
 }
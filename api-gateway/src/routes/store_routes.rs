@@ -11,7 +11,8 @@ use axum::{
 use crate::handlers::{
     activate_store_handler, create_store_handler, deactivate_store_handler,
     get_low_stock_alerts_handler, get_store_handler, get_store_inventory_handler,
-    list_stores_handler, update_store_handler,
+    get_store_settings_handler, list_stores_handler, update_store_handler,
+    update_store_settings_handler,
 };
 use crate::middleware::auth_middleware;
 use crate::state::AppState;
@@ -29,6 +30,8 @@ use crate::state::AppState;
 /// - `PUT /:id` - Update store details - Requirement 1.2
 /// - `POST /:id/activate` - Activate store (requires super_admin) - Requirement 1.4, 1.5
 /// - `POST /:id/deactivate` - Deactivate store and cascade to terminals (requires super_admin) - Requirement 1.3, 1.5
+/// - `GET /:id/settings` - Get store settings (requires stores:read)
+/// - `PUT /:id/settings` - Update store settings (requires stores:update)
 /// - `GET /:id/inventory` - Get store inventory (requires inventory:read)
 /// - `GET /:id/low-stock` - Get low stock alerts for store (requires inventory:read)
 ///
@@ -48,6 +51,11 @@ pub fn store_router(state: AppState) -> Router<AppState> {
         // Store activation routes
         .route("/{id}/activate", post(activate_store_handler))
         .route("/{id}/deactivate", post(deactivate_store_handler))
+        // Store settings routes
+        .route(
+            "/{id}/settings",
+            get(get_store_settings_handler).put(update_store_settings_handler),
+        )
         // Store inventory route
         .route("/{id}/inventory", get(get_store_inventory_handler))
         // Store low stock alerts route
@@ -11,8 +11,8 @@ use axum::{
 use crate::handlers::{
     activate_terminal_handler, assign_cai_handler, create_terminal_handler,
     deactivate_terminal_handler, get_cai_history_handler, get_cai_status_handler,
-    get_next_invoice_number_handler, get_terminal_handler, list_terminals_handler,
-    update_terminal_handler,
+    get_next_invoice_number_handler, get_terminal_handler, list_cais_near_exhaustion_handler,
+    list_terminals_handler, update_terminal_handler,
 };
 use crate::middleware::auth_middleware;
 use crate::state::AppState;
@@ -58,6 +58,7 @@ pub fn store_terminals_router(state: AppState) -> Router<AppState> {
 /// - `GET /:id/cai/status` - Get CAI status with expiration warning - Requirement 3.4, 3.5
 /// - `POST /:id/cai/next-number` - Get next invoice number atomically - Requirement 3.1
 /// - `GET /:id/cai/history` - Get complete CAI history - Requirement 4.4
+/// - `GET /cai-near-exhaustion` - List CAI ranges running out or close to expiry
 ///
 /// # Usage
 ///
@@ -84,6 +85,10 @@ pub fn terminals_router(state: AppState) -> Router<AppState> {
             post(get_next_invoice_number_handler),
         )
         .route("/{id}/cai/history", get(get_cai_history_handler))
+        .route(
+            "/cai-near-exhaustion",
+            get(list_cais_near_exhaustion_handler),
+        )
         // Apply authentication middleware to all routes
         .layer(middleware::from_fn_with_state(state, auth_middleware))
 }
@@ -32,20 +32,31 @@ use catalog::{
     PgProductImageRepository, PgProductListingRepository, PgProductReviewRepository,
     PgWishlistRepository,
 };
+use common::{DocumentNumberService, TaxIdPolicy};
 use demand_planning::{
     AbcClassificationRepository, DemandForecastRepository, DemandPlanningEventSubscriber,
-    PgAbcClassificationRepository, PgDemandForecastRepository, PgReorderPolicyRepository,
-    PgReplenishmentSuggestionRepository, PgSalesHistoryRepository, PgStockSnapshotRepository,
-    ReorderPolicyRepository, ReplenishmentSuggestionRepository, SalesHistoryRepository,
-    StockSnapshotRepository,
+    IncomingSupplyRepository, PgAbcClassificationRepository, PgDemandForecastRepository,
+    PgIncomingSupplyRepository, PgReorderPolicyRepository, PgReplenishmentSuggestionRepository,
+    PgSalesHistoryRepository, PgStockSnapshotRepository, ReorderPolicyRepository,
+    ReplenishmentSuggestionRepository, SalesHistoryRepository, StockSnapshotRepository,
 };
 use events::{OutboxRepository, PgOutboxRepository, SubscriberRegistry};
 use fiscal::{PgFiscalSequenceRepository, PgInvoiceRepository, PgTaxRateRepository};
-use identity::{JwtTokenService, PgAuditRepository, PgStoreRepository, PgUserRepository};
+use identity::{
+    JwtTokenService, MfaPolicy, MfaSecretCipher, PgAuditRepository, PgPasswordResetTokenRepository,
+    PgRefreshTokenRepository, PgStoreRepository, PgUserMfaRepository, PgUserRepository,
+};
+use inventory::application::helpers::{
+    DEFAULT_MAX_CATEGORY_DEPTH, DEFAULT_STOCK_POSTING_BATCH_SIZE,
+};
 use inventory::{
-    PgAdjustmentRepository, PgCategoryRepository, PgInventoryMovementRepository,
-    PgInventoryStockRepository, PgProductRepository, PgRecipeRepository, PgReservationRepository,
-    PgTransferRepository,
+    AdjustmentApprovalPolicy, PgAdjustmentRepository, PgBarcodeSequenceRepository,
+    PgCategoryRepository, PgInventoryLotRepository, PgInventoryMovementRepository,
+    PgInventoryPeriodRepository, PgInventoryStockRepository, PgPriceHistoryRepository,
+    PgPriceUpdateRepository, PgProductImportRepository, PgProductRepository,
+    PgProductSerialRepository, PgRecipeRepository, PgRepriceBatchRepository,
+    PgReservationRepository, PgTransferRepository, PgValuationSnapshotRepository,
+    ReservationPriorityPolicy, TransferApprovalPolicy,
 };
 use loyalty::{
     LoyaltyEventSubscriber, LoyaltyMemberRepository, LoyaltyProgramRepository,
@@ -55,13 +66,17 @@ use loyalty::{
 };
 use notifications::{
     DefaultNotificationAdapterRegistry, NotificationAdapterRegistry, PgNotificationRepository,
+    PgWebhookDeliveryRepository, PgWebhookRepository,
 };
 use payments::{
     DefaultGatewayAdapterRegistry, GatewayAdapterRegistry, PgPaymentGatewayRepository,
     PgPayoutRepository, PgTransactionRepository,
 };
 use pos_core::PgTerminalRepository;
-use purchasing::{PgGoodsReceiptRepository, PgPurchaseOrderRepository, PgVendorRepository};
+use purchasing::{
+    PgGoodsReceiptRepository, PgPurchaseOrderRepository, PgVendorRepository,
+    PurchaseApprovalPolicy, ReceiptMatchPolicy,
+};
 use restaurant_operations::{
     KdsBroadcaster, KdsTicketItemRepository, KdsTicketRepository, KitchenStationRepository,
     MenuModifierRepository, PgKdsTicketItemRepository, PgKdsTicketRepository,
@@ -69,8 +84,11 @@ use restaurant_operations::{
     RestaurantOperationsEventSubscriber, RestaurantTableRepository, TokioBroadcastKdsBroadcaster,
 };
 use sales::{
-    PgCartRepository, PgCreditNoteRepository, PgCustomerRepository, PgPromotionRepository,
-    PgSaleRepository, PgShiftRepository,
+    OrderInvoiceIssuer, OrderNotifier, PgCartRepository, PgCreditNoteRepository,
+    PgCustomerRepository, PgExchangeRateRepository, PgGiftCardRepository, PgLayawayRepository,
+    PgMarkdownRepository, PgOrderPickupRepository, PgPriceListRepository, PgPriceTierRepository,
+    PgPromotionRepository, PgQuickSaleRepository, PgSaleIdempotencyRepository, PgSaleRepository,
+    PgShiftRepository, PgTaxRuleRepository, PgWeightBarcodeFormatRepository,
 };
 use service_orders::{
     AssetRepository as ServiceAssetRepository, DiagnosticRepository as ServiceDiagnosticRepository,
@@ -81,8 +99,9 @@ use service_orders::{
     ServiceOrderItemRepository, ServiceOrderRepository, ServiceOrdersEventSubscriber,
 };
 use shipping::{
-    DefaultDeliveryProviderRegistry, DeliveryProviderRegistry, PgDeliveryProviderRepository,
-    PgDriverRepository, PgShipmentRepository, PgShipmentTrackingEventRepository,
+    DefaultDeliveryProviderRegistry, DeliveryProviderRegistry, OrderPickDeps,
+    PgDeliveryProviderRepository, PgDriverRepository, PgOrderFulfillmentRepository,
+    PgOrderPickRepository, PgShipmentRepository, PgShipmentTrackingEventRepository,
     PgShippingMethodRepository, PgShippingRateRepository, PgShippingZoneRepository, ShipmentDeps,
 };
 use sqlx::PgPool;
@@ -102,6 +121,8 @@ use tenancy::{
     PgOrganizationPlanRepository, PgOrganizationRepository, TenancyEventSubscriber,
 };
 
+use crate::order_gateways::{FiscalOrderInvoiceIssuer, NotificationOrderNotifier};
+
 /// Application state shared across all HTTP handlers.
 ///
 /// This struct holds Arc-wrapped instances of repositories and services
@@ -126,6 +147,14 @@ pub struct AppState {
     audit_repo: Arc<PgAuditRepository>,
     /// Token service for JWT generation and validation
     token_service: Arc<JwtTokenService>,
+    /// TOTP enrollment repository (secrets encrypted at rest)
+    user_mfa_repo: Arc<PgUserMfaRepository>,
+    /// Refresh token store for rotation and reuse detection
+    refresh_token_repo: Arc<PgRefreshTokenRepository>,
+    /// Password reset token store (hashed, single-use)
+    password_reset_repo: Arc<PgPasswordResetTokenRepository>,
+    /// TOTP issuer and failed-attempt lockout
+    mfa_policy: MfaPolicy,
     // -------------------------------------------------------------------------
     // Inventory repositories
     // -------------------------------------------------------------------------
@@ -133,10 +162,18 @@ pub struct AppState {
     product_repo: Arc<PgProductRepository>,
     /// Category repository for product category operations
     category_repo: Arc<PgCategoryRepository>,
+    /// Maximum number of levels in the category tree
+    max_category_depth: usize,
+    /// Barcode sequence repository for generated EAN-13 barcodes
+    barcode_sequence_repo: Arc<PgBarcodeSequenceRepository>,
     /// Inventory stock repository for stock management
     stock_repo: Arc<PgInventoryStockRepository>,
     /// Reservation repository for stock reservations
     reservation_repo: Arc<PgReservationRepository>,
+    /// Serial repository for serial-tracked units
+    product_serial_repo: Arc<PgProductSerialRepository>,
+    /// Lot repository for lot/expiry tracking
+    lot_repo: Arc<PgInventoryLotRepository>,
     /// Movement repository for inventory movement history (stock ledger)
     movement_repo: Arc<PgInventoryMovementRepository>,
     /// Recipe repository for recipe/BOM management
@@ -145,6 +182,30 @@ pub struct AppState {
     adjustment_repo: Arc<PgAdjustmentRepository>,
     /// Transfer repository for inter-store transfers
     transfer_repo: Arc<PgTransferRepository>,
+    /// Reprice batch repository for vendor cost reprices
+    reprice_batch_repo: Arc<PgRepriceBatchRepository>,
+    /// Price history repository for product cost and price changes
+    price_history_repo: Arc<PgPriceHistoryRepository>,
+    /// Price update repository for transactional bulk price updates
+    price_update_repo: Arc<PgPriceUpdateRepository>,
+    /// Product import repository for transactional catalog imports
+    product_import_repo: Arc<PgProductImportRepository>,
+    /// Valuation snapshot repository for period-end inventory closes
+    valuation_snapshot_repo: Arc<PgValuationSnapshotRepository>,
+    /// Inventory period repository for open and closed periods per store
+    inventory_period_repo: Arc<PgInventoryPeriodRepository>,
+    /// Which transfers must be approved before they ship
+    transfer_approval_policy: TransferApprovalPolicy,
+    /// Which negative adjustments are approved without review
+    adjustment_approval_policy: AdjustmentApprovalPolicy,
+    /// Which reservations may bump others when stock is short
+    reservation_priority_policy: ReservationPriorityPolicy,
+    /// Spend limits and dual approval for purchase orders
+    purchase_approval_policy: PurchaseApprovalPolicy,
+    /// Tolerances goods receipts must meet against their purchase orders
+    receipt_match_policy: ReceiptMatchPolicy,
+    /// Per-country tax id validation for customers and vendors
+    tax_id_policy: TaxIdPolicy,
     // -------------------------------------------------------------------------
     // Purchasing repositories
     // -------------------------------------------------------------------------
@@ -154,6 +215,8 @@ pub struct AppState {
     purchase_order_repo: Arc<PgPurchaseOrderRepository>,
     /// Goods receipt repository for goods receipt management
     goods_receipt_repo: Arc<PgGoodsReceiptRepository>,
+    /// Rows written per statement when posting goods receipts to stock
+    stock_posting_batch_size: usize,
     // -------------------------------------------------------------------------
     // Sales repositories
     // -------------------------------------------------------------------------
@@ -161,14 +224,42 @@ pub struct AppState {
     customer_repo: Arc<PgCustomerRepository>,
     /// Sale repository for sale transaction operations
     sale_repo: Arc<PgSaleRepository>,
+    /// Terminal-scoped idempotency keys for retried sale requests
+    sale_idempotency_repo: Arc<PgSaleIdempotencyRepository>,
     /// Shift repository for cashier shift management
     shift_repo: Arc<PgShiftRepository>,
+    /// Single-transaction writer for quick sales
+    quick_sale_repo: Arc<PgQuickSaleRepository>,
+    /// How long after a close a manager may still reopen a shift
+    shift_reopen_window: chrono::Duration,
+    /// Closing-count variance a shift may show without a reason
+    shift_variance_threshold: rust_decimal::Decimal,
+    /// Unit cost basis for credit note restock movements
+    restock_cost_basis: sales::RestockCostBasis,
     /// Cart repository for e-commerce cart management
     cart_repo: Arc<PgCartRepository>,
     /// Credit note repository for returns management
     credit_note_repo: Arc<PgCreditNoteRepository>,
     /// Promotion repository for discount/coupon management
     promotion_repo: Arc<PgPromotionRepository>,
+    /// Markdown repository for automatic markdown rules and log
+    markdown_repo: Arc<PgMarkdownRepository>,
+    /// Order pickup repository for buy-online-pickup-in-store orders
+    order_pickup_repo: Arc<PgOrderPickupRepository>,
+    /// Price tier repository for customer-type pricing
+    price_tier_repo: Arc<PgPriceTierRepository>,
+    /// Price list repository for customer-type price lists
+    price_list_repo: Arc<PgPriceListRepository>,
+    /// Exchange rate repository for foreign currency tenders
+    exchange_rate_repo: Arc<PgExchangeRateRepository>,
+    /// Gift card repository for issuing and redeeming gift cards
+    gift_card_repo: Arc<PgGiftCardRepository>,
+    /// Layaway repository for installment schedules of layaway sales
+    layaway_repo: Arc<PgLayawayRepository>,
+    /// Tax rule repository for per-product, per-category and store-wide tax
+    tax_rule_repo: Arc<PgTaxRuleRepository>,
+    /// Weight barcode format repository for scale-printed labels
+    weight_barcode_format_repo: Arc<PgWeightBarcodeFormatRepository>,
     // -------------------------------------------------------------------------
     // Fiscal repositories
     // -------------------------------------------------------------------------
@@ -200,9 +291,12 @@ pub struct AppState {
     delivery_provider_repo: Arc<PgDeliveryProviderRepository>,
     shipment_repo: Arc<PgShipmentRepository>,
     shipment_event_repo: Arc<PgShipmentTrackingEventRepository>,
+    order_fulfillment_repo: Arc<PgOrderFulfillmentRepository>,
     delivery_registry: Arc<dyn DeliveryProviderRegistry>,
     /// Pre-built ShipmentDeps to avoid rewiring on every handler invocation.
     shipment_deps: Arc<ShipmentDeps>,
+    /// Pre-built OrderPickDeps for the warehouse pick queue.
+    order_pick_deps: Arc<OrderPickDeps>,
     // -------------------------------------------------------------------------
     // Catalog repositories + image adapter registry
     // -------------------------------------------------------------------------
@@ -222,6 +316,8 @@ pub struct AppState {
     // -------------------------------------------------------------------------
     notification_repo: Arc<PgNotificationRepository>,
     notification_registry: Arc<dyn NotificationAdapterRegistry>,
+    webhook_repo: Arc<PgWebhookRepository>,
+    webhook_delivery_repo: Arc<PgWebhookDeliveryRepository>,
     // -------------------------------------------------------------------------
     // Analytics (KPI snapshots, dashboards/widgets, cross-module queries)
     // -------------------------------------------------------------------------
@@ -245,6 +341,7 @@ pub struct AppState {
     abc_classification_repo: Arc<dyn AbcClassificationRepository>,
     sales_history_repo: Arc<dyn SalesHistoryRepository>,
     stock_snapshot_repo: Arc<dyn StockSnapshotRepository>,
+    incoming_supply_repo: Arc<dyn IncomingSupplyRepository>,
     // -------------------------------------------------------------------------
     // Cash management (bank accounts, bank transactions, deposits, reconciliations)
     // -------------------------------------------------------------------------
@@ -320,14 +417,26 @@ impl AppState {
     /// * `terminal_repo` - Terminal repository implementation
     /// * `audit_repo` - Audit repository implementation
     /// * `token_service` - Token service implementation
+    /// * `user_mfa_repo` - TOTP enrollment repository implementation
+    /// * `refresh_token_repo` - Refresh token repository implementation
+    /// * `password_reset_repo` - Password reset token repository implementation
     /// * `product_repo` - Product repository implementation
     /// * `category_repo` - Category repository implementation
+    /// * `barcode_sequence_repo` - Barcode sequence repository implementation
     /// * `stock_repo` - Inventory stock repository implementation
     /// * `reservation_repo` - Reservation repository implementation
+    /// * `product_serial_repo` - Product serial repository implementation
+    /// * `lot_repo` - Inventory lot repository implementation
     /// * `movement_repo` - Inventory movement repository implementation
     /// * `recipe_repo` - Recipe repository implementation
     /// * `adjustment_repo` - Adjustment repository implementation
     /// * `transfer_repo` - Transfer repository implementation
+    /// * `reprice_batch_repo` - Reprice batch repository implementation
+    /// * `price_history_repo` - Price history repository implementation
+    /// * `price_update_repo` - Bulk price update repository implementation
+    /// * `product_import_repo` - Product import repository implementation
+    /// * `valuation_snapshot_repo` - Valuation snapshot repository implementation
+    /// * `inventory_period_repo` - Inventory period repository implementation
     /// * `vendor_repo` - Vendor repository implementation
     /// * `purchase_order_repo` - Purchase order repository implementation
     /// * `goods_receipt_repo` - Goods receipt repository implementation
@@ -339,23 +448,46 @@ impl AppState {
         terminal_repo: Arc<PgTerminalRepository>,
         audit_repo: Arc<PgAuditRepository>,
         token_service: Arc<JwtTokenService>,
+        user_mfa_repo: Arc<PgUserMfaRepository>,
+        refresh_token_repo: Arc<PgRefreshTokenRepository>,
+        password_reset_repo: Arc<PgPasswordResetTokenRepository>,
         product_repo: Arc<PgProductRepository>,
         category_repo: Arc<PgCategoryRepository>,
+        barcode_sequence_repo: Arc<PgBarcodeSequenceRepository>,
         stock_repo: Arc<PgInventoryStockRepository>,
         reservation_repo: Arc<PgReservationRepository>,
+        product_serial_repo: Arc<PgProductSerialRepository>,
+        lot_repo: Arc<PgInventoryLotRepository>,
         movement_repo: Arc<PgInventoryMovementRepository>,
         recipe_repo: Arc<PgRecipeRepository>,
         adjustment_repo: Arc<PgAdjustmentRepository>,
         transfer_repo: Arc<PgTransferRepository>,
+        reprice_batch_repo: Arc<PgRepriceBatchRepository>,
+        price_history_repo: Arc<PgPriceHistoryRepository>,
+        price_update_repo: Arc<PgPriceUpdateRepository>,
+        product_import_repo: Arc<PgProductImportRepository>,
+        valuation_snapshot_repo: Arc<PgValuationSnapshotRepository>,
+        inventory_period_repo: Arc<PgInventoryPeriodRepository>,
         vendor_repo: Arc<PgVendorRepository>,
         purchase_order_repo: Arc<PgPurchaseOrderRepository>,
         goods_receipt_repo: Arc<PgGoodsReceiptRepository>,
         customer_repo: Arc<PgCustomerRepository>,
         sale_repo: Arc<PgSaleRepository>,
+        sale_idempotency_repo: Arc<PgSaleIdempotencyRepository>,
         shift_repo: Arc<PgShiftRepository>,
+        quick_sale_repo: Arc<PgQuickSaleRepository>,
         cart_repo: Arc<PgCartRepository>,
         credit_note_repo: Arc<PgCreditNoteRepository>,
         promotion_repo: Arc<PgPromotionRepository>,
+        markdown_repo: Arc<PgMarkdownRepository>,
+        order_pickup_repo: Arc<PgOrderPickupRepository>,
+        price_tier_repo: Arc<PgPriceTierRepository>,
+        price_list_repo: Arc<PgPriceListRepository>,
+        exchange_rate_repo: Arc<PgExchangeRateRepository>,
+        gift_card_repo: Arc<PgGiftCardRepository>,
+        layaway_repo: Arc<PgLayawayRepository>,
+        tax_rule_repo: Arc<PgTaxRuleRepository>,
+        weight_barcode_format_repo: Arc<PgWeightBarcodeFormatRepository>,
         invoice_repo: Arc<PgInvoiceRepository>,
         tax_rate_repo: Arc<PgTaxRateRepository>,
         fiscal_sequence_repo: Arc<PgFiscalSequenceRepository>,
@@ -370,8 +502,10 @@ impl AppState {
         delivery_provider_repo: Arc<PgDeliveryProviderRepository>,
         shipment_repo: Arc<PgShipmentRepository>,
         shipment_event_repo: Arc<PgShipmentTrackingEventRepository>,
+        order_fulfillment_repo: Arc<PgOrderFulfillmentRepository>,
         delivery_registry: Arc<dyn DeliveryProviderRegistry>,
         shipment_deps: Arc<ShipmentDeps>,
+        order_pick_deps: Arc<OrderPickDeps>,
         listing_repo: Arc<PgProductListingRepository>,
         image_repo: Arc<PgProductImageRepository>,
         review_repo: Arc<PgProductReviewRepository>,
@@ -382,6 +516,8 @@ impl AppState {
         subscriber_registry: SubscriberRegistry,
         notification_repo: Arc<PgNotificationRepository>,
         notification_registry: Arc<dyn NotificationAdapterRegistry>,
+        webhook_repo: Arc<PgWebhookRepository>,
+        webhook_delivery_repo: Arc<PgWebhookDeliveryRepository>,
         kpi_snapshot_repo: Arc<dyn KpiSnapshotRepository>,
         dashboard_repo: Arc<dyn DashboardRepository>,
         widget_repo: Arc<dyn WidgetRepository>,
@@ -396,6 +532,7 @@ impl AppState {
         abc_classification_repo: Arc<dyn AbcClassificationRepository>,
         sales_history_repo: Arc<dyn SalesHistoryRepository>,
         stock_snapshot_repo: Arc<dyn StockSnapshotRepository>,
+        incoming_supply_repo: Arc<dyn IncomingSupplyRepository>,
         bank_account_repo: Arc<dyn BankAccountRepository>,
         bank_transaction_repo: Arc<dyn BankTransactionRepository>,
         cash_deposit_repo: Arc<dyn CashDepositRepository>,
@@ -441,23 +578,60 @@ impl AppState {
             terminal_repo,
             audit_repo,
             token_service,
+            user_mfa_repo,
+            refresh_token_repo,
+            password_reset_repo,
+            mfa_policy: MfaPolicy::default(),
             product_repo,
             category_repo,
+            max_category_depth: DEFAULT_MAX_CATEGORY_DEPTH,
+            barcode_sequence_repo,
             stock_repo,
             reservation_repo,
+            product_serial_repo,
+            lot_repo,
             movement_repo,
             recipe_repo,
             adjustment_repo,
             transfer_repo,
+            reprice_batch_repo,
+            price_history_repo,
+            price_update_repo,
+            product_import_repo,
+            valuation_snapshot_repo,
+            inventory_period_repo,
+            transfer_approval_policy: TransferApprovalPolicy::disabled(),
+            adjustment_approval_policy: AdjustmentApprovalPolicy::disabled(),
+            reservation_priority_policy: ReservationPriorityPolicy::default(),
+            purchase_approval_policy: PurchaseApprovalPolicy::disabled(),
+            receipt_match_policy: ReceiptMatchPolicy::disabled(),
+            tax_id_policy: TaxIdPolicy::default(),
             vendor_repo,
             purchase_order_repo,
             goods_receipt_repo,
+            stock_posting_batch_size: DEFAULT_STOCK_POSTING_BATCH_SIZE,
             customer_repo,
             sale_repo,
+            sale_idempotency_repo,
             shift_repo,
+            quick_sale_repo,
+            shift_reopen_window: chrono::Duration::minutes(
+                sales::DEFAULT_SHIFT_REOPEN_WINDOW_MINUTES,
+            ),
+            shift_variance_threshold: sales::DEFAULT_SHIFT_VARIANCE_THRESHOLD,
+            restock_cost_basis: sales::RestockCostBasis::default(),
             cart_repo,
             credit_note_repo,
             promotion_repo,
+            markdown_repo,
+            order_pickup_repo,
+            price_tier_repo,
+            price_list_repo,
+            exchange_rate_repo,
+            gift_card_repo,
+            layaway_repo,
+            tax_rule_repo,
+            weight_barcode_format_repo,
             invoice_repo,
             tax_rate_repo,
             fiscal_sequence_repo,
@@ -472,8 +646,10 @@ impl AppState {
             delivery_provider_repo,
             shipment_repo,
             shipment_event_repo,
+            order_fulfillment_repo,
             delivery_registry,
             shipment_deps,
+            order_pick_deps,
             listing_repo,
             image_repo,
             review_repo,
@@ -484,6 +660,8 @@ impl AppState {
             subscriber_registry,
             notification_repo,
             notification_registry,
+            webhook_repo,
+            webhook_delivery_repo,
             kpi_snapshot_repo,
             dashboard_repo,
             widget_repo,
@@ -498,6 +676,7 @@ impl AppState {
             abc_classification_repo,
             sales_history_repo,
             stock_snapshot_repo,
+            incoming_supply_repo,
             bank_account_repo,
             bank_transaction_repo,
             cash_deposit_repo,
@@ -547,13 +726,21 @@ impl AppState {
     ///
     /// * `pool` - PostgreSQL connection pool
     /// * `jwt_secret` - Secret key for JWT signing (should be at least 32 bytes)
-    pub fn from_pool(pool: PgPool, jwt_secret: String) -> Self {
+    /// * `mfa_encryption_key` - Key encrypting stored TOTP secrets
+    pub fn from_pool(pool: PgPool, jwt_secret: String, mfa_encryption_key: &str) -> Self {
         let pool_arc = Arc::new(pool.clone());
 
         // Identity repositories
         let user_repo = Arc::new(PgUserRepository::new((*pool_arc).clone()));
         let store_repo = Arc::new(PgStoreRepository::new((*pool_arc).clone()));
         let audit_repo = Arc::new(PgAuditRepository::new((*pool_arc).clone()));
+        let user_mfa_repo = Arc::new(PgUserMfaRepository::new(
+            (*pool_arc).clone(),
+            MfaSecretCipher::new(mfa_encryption_key),
+        ));
+        let refresh_token_repo = Arc::new(PgRefreshTokenRepository::new((*pool_arc).clone()));
+        let password_reset_repo =
+            Arc::new(PgPasswordResetTokenRepository::new((*pool_arc).clone()));
 
         // Core repositories
         let terminal_repo = Arc::new(PgTerminalRepository::new(pool_arc.clone()));
@@ -561,12 +748,22 @@ impl AppState {
         // Inventory repositories
         let product_repo = Arc::new(PgProductRepository::new((*pool_arc).clone()));
         let category_repo = Arc::new(PgCategoryRepository::new((*pool_arc).clone()));
+        let barcode_sequence_repo = Arc::new(PgBarcodeSequenceRepository::new((*pool_arc).clone()));
         let stock_repo = Arc::new(PgInventoryStockRepository::new((*pool_arc).clone()));
         let reservation_repo = Arc::new(PgReservationRepository::new((*pool_arc).clone()));
+        let product_serial_repo = Arc::new(PgProductSerialRepository::new((*pool_arc).clone()));
+        let lot_repo = Arc::new(PgInventoryLotRepository::new((*pool_arc).clone()));
         let movement_repo = Arc::new(PgInventoryMovementRepository::new((*pool_arc).clone()));
         let recipe_repo = Arc::new(PgRecipeRepository::new((*pool_arc).clone()));
         let adjustment_repo = Arc::new(PgAdjustmentRepository::new((*pool_arc).clone()));
         let transfer_repo = Arc::new(PgTransferRepository::new((*pool_arc).clone()));
+        let reprice_batch_repo = Arc::new(PgRepriceBatchRepository::new((*pool_arc).clone()));
+        let price_history_repo = Arc::new(PgPriceHistoryRepository::new((*pool_arc).clone()));
+        let price_update_repo = Arc::new(PgPriceUpdateRepository::new((*pool_arc).clone()));
+        let product_import_repo = Arc::new(PgProductImportRepository::new((*pool_arc).clone()));
+        let valuation_snapshot_repo =
+            Arc::new(PgValuationSnapshotRepository::new((*pool_arc).clone()));
+        let inventory_period_repo = Arc::new(PgInventoryPeriodRepository::new((*pool_arc).clone()));
 
         // Purchasing repositories
         let vendor_repo = Arc::new(PgVendorRepository::new((*pool_arc).clone()));
@@ -576,10 +773,22 @@ impl AppState {
         // Sales repositories
         let customer_repo = Arc::new(PgCustomerRepository::new((*pool_arc).clone()));
         let sale_repo = Arc::new(PgSaleRepository::new((*pool_arc).clone()));
+        let sale_idempotency_repo = Arc::new(PgSaleIdempotencyRepository::new((*pool_arc).clone()));
         let shift_repo = Arc::new(PgShiftRepository::new((*pool_arc).clone()));
+        let quick_sale_repo = Arc::new(PgQuickSaleRepository::new((*pool_arc).clone()));
         let cart_repo = Arc::new(PgCartRepository::new((*pool_arc).clone()));
         let credit_note_repo = Arc::new(PgCreditNoteRepository::new((*pool_arc).clone()));
         let promotion_repo = Arc::new(PgPromotionRepository::new((*pool_arc).clone()));
+        let markdown_repo = Arc::new(PgMarkdownRepository::new((*pool_arc).clone()));
+        let order_pickup_repo = Arc::new(PgOrderPickupRepository::new((*pool_arc).clone()));
+        let price_tier_repo = Arc::new(PgPriceTierRepository::new((*pool_arc).clone()));
+        let price_list_repo = Arc::new(PgPriceListRepository::new((*pool_arc).clone()));
+        let exchange_rate_repo = Arc::new(PgExchangeRateRepository::new((*pool_arc).clone()));
+        let gift_card_repo = Arc::new(PgGiftCardRepository::new((*pool_arc).clone()));
+        let layaway_repo = Arc::new(PgLayawayRepository::new((*pool_arc).clone()));
+        let tax_rule_repo = Arc::new(PgTaxRuleRepository::new((*pool_arc).clone()));
+        let weight_barcode_format_repo =
+            Arc::new(PgWeightBarcodeFormatRepository::new((*pool_arc).clone()));
 
         // Fiscal repositories
         let invoice_repo = Arc::new(PgInvoiceRepository::new((*pool_arc).clone()));
@@ -605,6 +814,8 @@ impl AppState {
         let shipment_repo = Arc::new(PgShipmentRepository::new((*pool_arc).clone()));
         let shipment_event_repo =
             Arc::new(PgShipmentTrackingEventRepository::new((*pool_arc).clone()));
+        let order_fulfillment_repo =
+            Arc::new(PgOrderFulfillmentRepository::new((*pool_arc).clone()));
         let delivery_registry: Arc<dyn DeliveryProviderRegistry> =
             Arc::new(DefaultDeliveryProviderRegistry::new());
         let shipment_deps = Arc::new(ShipmentDeps {
@@ -616,6 +827,10 @@ impl AppState {
             provider_registry: delivery_registry.clone(),
             transaction_repo: transaction_repo.clone(),
         });
+        let order_pick_deps = Arc::new(OrderPickDeps {
+            fulfillment_repo: order_fulfillment_repo.clone(),
+            pick_repo: Arc::new(PgOrderPickRepository::new((*pool_arc).clone())),
+        });
 
         // Catalog repositories + image storage registry
         let listing_repo = Arc::new(PgProductListingRepository::new((*pool_arc).clone()));
@@ -644,6 +859,8 @@ impl AppState {
         let notification_repo = Arc::new(PgNotificationRepository::new((*pool_arc).clone()));
         let notification_registry: Arc<dyn NotificationAdapterRegistry> =
             Arc::new(DefaultNotificationAdapterRegistry::new());
+        let webhook_repo = Arc::new(PgWebhookRepository::new((*pool_arc).clone()));
+        let webhook_delivery_repo = Arc::new(PgWebhookDeliveryRepository::new((*pool_arc).clone()));
 
         // Analytics repositories + register its outbox subscriber.
         let kpi_snapshot_repo: Arc<dyn KpiSnapshotRepository> =
@@ -681,6 +898,8 @@ impl AppState {
             Arc::new(PgSalesHistoryRepository::new((*pool_arc).clone()));
         let stock_snapshot_repo: Arc<dyn StockSnapshotRepository> =
             Arc::new(PgStockSnapshotRepository::new((*pool_arc).clone()));
+        let incoming_supply_repo: Arc<dyn IncomingSupplyRepository> =
+            Arc::new(PgIncomingSupplyRepository::new((*pool_arc).clone()));
         subscriber_registry.register(Arc::new(DemandPlanningEventSubscriber::new()));
 
         // Cash management repositories + register its outbox subscriber.
@@ -803,23 +1022,60 @@ impl AppState {
             terminal_repo,
             audit_repo,
             token_service,
+            user_mfa_repo,
+            refresh_token_repo,
+            password_reset_repo,
+            mfa_policy: MfaPolicy::default(),
             product_repo,
             category_repo,
+            max_category_depth: DEFAULT_MAX_CATEGORY_DEPTH,
+            barcode_sequence_repo,
             stock_repo,
             reservation_repo,
+            product_serial_repo,
+            lot_repo,
             movement_repo,
             recipe_repo,
             adjustment_repo,
             transfer_repo,
+            reprice_batch_repo,
+            price_history_repo,
+            price_update_repo,
+            product_import_repo,
+            valuation_snapshot_repo,
+            inventory_period_repo,
+            transfer_approval_policy: TransferApprovalPolicy::disabled(),
+            adjustment_approval_policy: AdjustmentApprovalPolicy::disabled(),
+            reservation_priority_policy: ReservationPriorityPolicy::default(),
+            purchase_approval_policy: PurchaseApprovalPolicy::disabled(),
+            receipt_match_policy: ReceiptMatchPolicy::disabled(),
+            tax_id_policy: TaxIdPolicy::default(),
             vendor_repo,
             purchase_order_repo,
             goods_receipt_repo,
+            stock_posting_batch_size: DEFAULT_STOCK_POSTING_BATCH_SIZE,
             customer_repo,
             sale_repo,
+            sale_idempotency_repo,
             shift_repo,
+            quick_sale_repo,
+            shift_reopen_window: chrono::Duration::minutes(
+                sales::DEFAULT_SHIFT_REOPEN_WINDOW_MINUTES,
+            ),
+            shift_variance_threshold: sales::DEFAULT_SHIFT_VARIANCE_THRESHOLD,
+            restock_cost_basis: sales::RestockCostBasis::default(),
             cart_repo,
             credit_note_repo,
             promotion_repo,
+            markdown_repo,
+            order_pickup_repo,
+            price_tier_repo,
+            price_list_repo,
+            exchange_rate_repo,
+            gift_card_repo,
+            layaway_repo,
+            tax_rule_repo,
+            weight_barcode_format_repo,
             invoice_repo,
             tax_rate_repo,
             fiscal_sequence_repo,
@@ -834,8 +1090,10 @@ impl AppState {
             delivery_provider_repo,
             shipment_repo,
             shipment_event_repo,
+            order_fulfillment_repo,
             delivery_registry,
             shipment_deps,
+            order_pick_deps,
             listing_repo,
             image_repo,
             review_repo,
@@ -846,6 +1104,8 @@ impl AppState {
             subscriber_registry,
             notification_repo,
             notification_registry,
+            webhook_repo,
+            webhook_delivery_repo,
             kpi_snapshot_repo,
             dashboard_repo,
             widget_repo,
@@ -860,6 +1120,7 @@ impl AppState {
             abc_classification_repo,
             sales_history_repo,
             stock_snapshot_repo,
+            incoming_supply_repo,
             bank_account_repo,
             bank_transaction_repo,
             cash_deposit_repo,
@@ -930,6 +1191,31 @@ impl AppState {
         self.token_service.clone()
     }
 
+    /// Returns a reference to the TOTP enrollment repository.
+    pub fn user_mfa_repo(&self) -> Arc<PgUserMfaRepository> {
+        self.user_mfa_repo.clone()
+    }
+
+    /// Returns a reference to the refresh token repository.
+    pub fn refresh_token_repo(&self) -> Arc<PgRefreshTokenRepository> {
+        self.refresh_token_repo.clone()
+    }
+
+    /// Returns a reference to the password reset token repository.
+    pub fn password_reset_repo(&self) -> Arc<PgPasswordResetTokenRepository> {
+        self.password_reset_repo.clone()
+    }
+
+    pub fn mfa_policy(&self) -> MfaPolicy {
+        self.mfa_policy.clone()
+    }
+
+    /// Overrides the MFA policy (see `MFA_*`).
+    pub fn with_mfa_policy(mut self, policy: MfaPolicy) -> Self {
+        self.mfa_policy = policy;
+        self
+    }
+
     // -------------------------------------------------------------------------
     // Inventory repository accessors
     // -------------------------------------------------------------------------
@@ -944,6 +1230,21 @@ impl AppState {
         self.category_repo.clone()
     }
 
+    pub fn max_category_depth(&self) -> usize {
+        self.max_category_depth
+    }
+
+    /// Overrides the category tree depth limit (see `CATEGORY_MAX_DEPTH`).
+    pub fn with_max_category_depth(mut self, max_depth: usize) -> Self {
+        self.max_category_depth = max_depth;
+        self
+    }
+
+    /// Returns a reference to the barcode sequence repository.
+    pub fn barcode_sequence_repo(&self) -> Arc<PgBarcodeSequenceRepository> {
+        self.barcode_sequence_repo.clone()
+    }
+
     /// Returns a reference to the inventory stock repository.
     pub fn stock_repo(&self) -> Arc<PgInventoryStockRepository> {
         self.stock_repo.clone()
@@ -954,6 +1255,16 @@ impl AppState {
         self.reservation_repo.clone()
     }
 
+    /// Returns a reference to the product serial repository.
+    pub fn product_serial_repo(&self) -> Arc<PgProductSerialRepository> {
+        self.product_serial_repo.clone()
+    }
+
+    /// Returns a reference to the inventory lot repository.
+    pub fn lot_repo(&self) -> Arc<PgInventoryLotRepository> {
+        self.lot_repo.clone()
+    }
+
     /// Returns a reference to the inventory movement repository.
     pub fn movement_repo(&self) -> Arc<PgInventoryMovementRepository> {
         self.movement_repo.clone()
@@ -974,6 +1285,96 @@ impl AppState {
         self.transfer_repo.clone()
     }
 
+    /// Returns a reference to the reprice batch repository.
+    pub fn reprice_batch_repo(&self) -> Arc<PgRepriceBatchRepository> {
+        self.reprice_batch_repo.clone()
+    }
+
+    /// Returns a reference to the price history repository.
+    pub fn price_history_repo(&self) -> Arc<PgPriceHistoryRepository> {
+        self.price_history_repo.clone()
+    }
+
+    /// Returns a reference to the bulk price update repository.
+    pub fn price_update_repo(&self) -> Arc<PgPriceUpdateRepository> {
+        self.price_update_repo.clone()
+    }
+
+    /// Returns a reference to the product import repository.
+    pub fn product_import_repo(&self) -> Arc<PgProductImportRepository> {
+        self.product_import_repo.clone()
+    }
+
+    /// Returns a reference to the valuation snapshot repository.
+    pub fn valuation_snapshot_repo(&self) -> Arc<PgValuationSnapshotRepository> {
+        self.valuation_snapshot_repo.clone()
+    }
+
+    /// Returns a reference to the inventory period repository.
+    pub fn inventory_period_repo(&self) -> Arc<PgInventoryPeriodRepository> {
+        self.inventory_period_repo.clone()
+    }
+
+    pub fn transfer_approval_policy(&self) -> TransferApprovalPolicy {
+        self.transfer_approval_policy.clone()
+    }
+
+    /// Overrides the transfer approval policy (see `TRANSFER_APPROVAL_*`).
+    pub fn with_transfer_approval_policy(mut self, policy: TransferApprovalPolicy) -> Self {
+        self.transfer_approval_policy = policy;
+        self
+    }
+
+    pub fn adjustment_approval_policy(&self) -> AdjustmentApprovalPolicy {
+        self.adjustment_approval_policy.clone()
+    }
+
+    /// Overrides the adjustment auto-approval policy (see `ADJUSTMENT_AUTO_APPROVE_*`).
+    pub fn with_adjustment_approval_policy(mut self, policy: AdjustmentApprovalPolicy) -> Self {
+        self.adjustment_approval_policy = policy;
+        self
+    }
+
+    pub fn purchase_approval_policy(&self) -> PurchaseApprovalPolicy {
+        self.purchase_approval_policy.clone()
+    }
+
+    /// Overrides the purchase order approval policy (see `PURCHASE_APPROVAL_TIERS`).
+    pub fn with_purchase_approval_policy(mut self, policy: PurchaseApprovalPolicy) -> Self {
+        self.purchase_approval_policy = policy;
+        self
+    }
+
+    pub fn receipt_match_policy(&self) -> ReceiptMatchPolicy {
+        self.receipt_match_policy.clone()
+    }
+
+    /// Overrides the goods receipt match policy (see `RECEIPT_MATCH_*`).
+    pub fn with_receipt_match_policy(mut self, policy: ReceiptMatchPolicy) -> Self {
+        self.receipt_match_policy = policy;
+        self
+    }
+
+    pub fn reservation_priority_policy(&self) -> ReservationPriorityPolicy {
+        self.reservation_priority_policy.clone()
+    }
+
+    /// Overrides the reservation priority policy (see `RESERVATION_PRIORITIES`).
+    pub fn with_reservation_priority_policy(mut self, policy: ReservationPriorityPolicy) -> Self {
+        self.reservation_priority_policy = policy;
+        self
+    }
+
+    pub fn tax_id_policy(&self) -> TaxIdPolicy {
+        self.tax_id_policy.clone()
+    }
+
+    /// Overrides the tax id policy (see `TAX_ID_DEFAULT_COUNTRY`).
+    pub fn with_tax_id_policy(mut self, policy: TaxIdPolicy) -> Self {
+        self.tax_id_policy = policy;
+        self
+    }
+
     // -------------------------------------------------------------------------
     // Purchasing repository accessors
     // -------------------------------------------------------------------------
@@ -993,6 +1394,35 @@ impl AppState {
         self.goods_receipt_repo.clone()
     }
 
+    pub fn stock_posting_batch_size(&self) -> usize {
+        self.stock_posting_batch_size
+    }
+
+    /// Overrides the formats of sequential document numbers (see
+    /// `DOCUMENT_NUMBER_FORMATS`) in every repository that hands them out.
+    pub fn with_document_numbering(mut self, numbering: DocumentNumberService) -> Self {
+        let pool = self.pool.clone();
+        self.adjustment_repo =
+            Arc::new(PgAdjustmentRepository::new(pool.clone()).with_numbering(numbering.clone()));
+        self.transfer_repo =
+            Arc::new(PgTransferRepository::new(pool.clone()).with_numbering(numbering.clone()));
+        self.purchase_order_repo = Arc::new(
+            PgPurchaseOrderRepository::new(pool.clone()).with_numbering(numbering.clone()),
+        );
+        self.goods_receipt_repo =
+            Arc::new(PgGoodsReceiptRepository::new(pool.clone()).with_numbering(numbering.clone()));
+        self.credit_note_repo =
+            Arc::new(PgCreditNoteRepository::new(pool).with_numbering(numbering));
+        self
+    }
+
+    /// Overrides the goods receipt posting batch size (see
+    /// `STOCK_POSTING_BATCH_SIZE`).
+    pub fn with_stock_posting_batch_size(mut self, batch_size: usize) -> Self {
+        self.stock_posting_batch_size = batch_size;
+        self
+    }
+
     // -------------------------------------------------------------------------
     // Sales repository accessors
     // -------------------------------------------------------------------------
@@ -1007,11 +1437,51 @@ impl AppState {
         self.sale_repo.clone()
     }
 
+    /// Returns a reference to the sale idempotency key repository.
+    pub fn sale_idempotency_repo(&self) -> Arc<PgSaleIdempotencyRepository> {
+        self.sale_idempotency_repo.clone()
+    }
+
+    pub fn quick_sale_repo(&self) -> Arc<PgQuickSaleRepository> {
+        self.quick_sale_repo.clone()
+    }
+
     /// Returns a reference to the shift repository.
     pub fn shift_repo(&self) -> Arc<PgShiftRepository> {
         self.shift_repo.clone()
     }
 
+    pub fn shift_reopen_window(&self) -> chrono::Duration {
+        self.shift_reopen_window
+    }
+
+    /// Overrides the shift reopen window (see `SHIFT_REOPEN_WINDOW_MINUTES`).
+    pub fn with_shift_reopen_window(mut self, window: chrono::Duration) -> Self {
+        self.shift_reopen_window = window;
+        self
+    }
+
+    pub fn shift_variance_threshold(&self) -> rust_decimal::Decimal {
+        self.shift_variance_threshold
+    }
+
+    /// Overrides the shift variance threshold (see `SHIFT_VARIANCE_THRESHOLD`).
+    pub fn with_shift_variance_threshold(mut self, threshold: rust_decimal::Decimal) -> Self {
+        self.shift_variance_threshold = threshold;
+        self
+    }
+
+    pub fn restock_cost_basis(&self) -> sales::RestockCostBasis {
+        self.restock_cost_basis
+    }
+
+    /// Overrides the credit note restock cost basis (see
+    /// `CREDIT_NOTE_RESTOCK_COST_BASIS`).
+    pub fn with_restock_cost_basis(mut self, basis: sales::RestockCostBasis) -> Self {
+        self.restock_cost_basis = basis;
+        self
+    }
+
     /// Returns a reference to the cart repository.
     pub fn cart_repo(&self) -> Arc<PgCartRepository> {
         self.cart_repo.clone()
@@ -1027,6 +1497,65 @@ impl AppState {
         self.promotion_repo.clone()
     }
 
+    /// Returns a reference to the markdown repository.
+    pub fn markdown_repo(&self) -> Arc<PgMarkdownRepository> {
+        self.markdown_repo.clone()
+    }
+
+    /// Returns a reference to the order pickup repository.
+    pub fn order_pickup_repo(&self) -> Arc<PgOrderPickupRepository> {
+        self.order_pickup_repo.clone()
+    }
+
+    /// Returns a reference to the price tier repository.
+    pub fn price_tier_repo(&self) -> Arc<PgPriceTierRepository> {
+        self.price_tier_repo.clone()
+    }
+
+    /// Returns a reference to the price list repository.
+    pub fn price_list_repo(&self) -> Arc<PgPriceListRepository> {
+        self.price_list_repo.clone()
+    }
+
+    /// Returns a reference to the exchange rate repository.
+    pub fn exchange_rate_repo(&self) -> Arc<PgExchangeRateRepository> {
+        self.exchange_rate_repo.clone()
+    }
+
+    /// Returns a reference to the gift card repository.
+    pub fn gift_card_repo(&self) -> Arc<PgGiftCardRepository> {
+        self.gift_card_repo.clone()
+    }
+
+    /// Returns a reference to the layaway repository.
+    pub fn layaway_repo(&self) -> Arc<PgLayawayRepository> {
+        self.layaway_repo.clone()
+    }
+
+    /// Returns a reference to the tax rule repository.
+    pub fn tax_rule_repo(&self) -> Arc<PgTaxRuleRepository> {
+        self.tax_rule_repo.clone()
+    }
+
+    /// Returns a reference to the weight barcode format repository.
+    pub fn weight_barcode_format_repo(&self) -> Arc<PgWeightBarcodeFormatRepository> {
+        self.weight_barcode_format_repo.clone()
+    }
+
+    /// Notifier for order updates, sent through the current notification
+    /// adapter registry.
+    pub fn order_notifier(&self) -> Arc<dyn OrderNotifier> {
+        Arc::new(NotificationOrderNotifier::new(
+            self.notification_repo(),
+            self.notification_registry(),
+        ))
+    }
+
+    /// Issues fiscal invoices for online orders.
+    pub fn order_invoice_issuer(&self) -> Arc<dyn OrderInvoiceIssuer> {
+        Arc::new(FiscalOrderInvoiceIssuer::new(self))
+    }
+
     // -------------------------------------------------------------------------
     // Fiscal repository accessors
     // -------------------------------------------------------------------------
@@ -1095,12 +1624,18 @@ impl AppState {
     pub fn shipment_event_repo(&self) -> Arc<PgShipmentTrackingEventRepository> {
         self.shipment_event_repo.clone()
     }
+    pub fn order_fulfillment_repo(&self) -> Arc<PgOrderFulfillmentRepository> {
+        self.order_fulfillment_repo.clone()
+    }
     pub fn delivery_registry(&self) -> Arc<dyn DeliveryProviderRegistry> {
         self.delivery_registry.clone()
     }
     pub fn shipment_deps(&self) -> Arc<ShipmentDeps> {
         self.shipment_deps.clone()
     }
+    pub fn order_pick_deps(&self) -> Arc<OrderPickDeps> {
+        self.order_pick_deps.clone()
+    }
 
     // -------------------------------------------------------------------------
     // Catalog accessors
@@ -1147,6 +1682,22 @@ impl AppState {
         self.notification_registry.clone()
     }
 
+    pub fn webhook_repo(&self) -> Arc<PgWebhookRepository> {
+        self.webhook_repo.clone()
+    }
+    pub fn webhook_delivery_repo(&self) -> Arc<PgWebhookDeliveryRepository> {
+        self.webhook_delivery_repo.clone()
+    }
+
+    /// Swaps the notification adapter registry (e.g. to enable signed webhooks).
+    pub fn with_notification_registry(
+        mut self,
+        registry: Arc<dyn NotificationAdapterRegistry>,
+    ) -> Self {
+        self.notification_registry = registry;
+        self
+    }
+
     // -------------------------------------------------------------------------
     // Analytics accessors
     // -------------------------------------------------------------------------
@@ -1203,6 +1754,9 @@ impl AppState {
     pub fn stock_snapshot_repo(&self) -> Arc<dyn StockSnapshotRepository> {
         self.stock_snapshot_repo.clone()
     }
+    pub fn incoming_supply_repo(&self) -> Arc<dyn IncomingSupplyRepository> {
+        self.incoming_supply_repo.clone()
+    }
 
     // -------------------------------------------------------------------------
     // Cash management accessors
@@ -0,0 +1,59 @@
+-- Automatic markdowns for aging / near-expiry stock.
+--
+-- `markdown_rules` is the per-store, per-category opt-in: a store only gets
+-- automatic markdowns for categories that have an active rule. Each rule can
+-- trigger on proximity to expiry (from confirmed goods-receipt lots), on idle
+-- stock (no sales for N days), or both.
+--
+-- `markdowns` is the log: one row per product marked down, with the reason,
+-- the time-bounded promotion that carries the discount, and reversal details.
+
+CREATE TABLE IF NOT EXISTS markdown_rules (
+    id UUID PRIMARY KEY,
+    store_id UUID NOT NULL REFERENCES stores(id),
+    category_id UUID NOT NULL REFERENCES product_categories(id) ON DELETE CASCADE,
+    expiry_window_days INTEGER,
+    idle_days INTEGER,
+    discount_percent DECIMAL(5,2) NOT NULL,
+    duration_days INTEGER NOT NULL,
+    is_active BOOLEAN NOT NULL DEFAULT TRUE,
+    created_by_id UUID NOT NULL REFERENCES users(id),
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+
+    CONSTRAINT markdown_rules_store_category_unique UNIQUE (store_id, category_id),
+    CONSTRAINT markdown_rules_trigger_required CHECK (expiry_window_days IS NOT NULL OR idle_days IS NOT NULL),
+    CONSTRAINT markdown_rules_expiry_window_positive CHECK (expiry_window_days IS NULL OR expiry_window_days > 0),
+    CONSTRAINT markdown_rules_idle_days_positive CHECK (idle_days IS NULL OR idle_days > 0),
+    CONSTRAINT markdown_rules_discount_range CHECK (discount_percent > 0 AND discount_percent < 100),
+    CONSTRAINT markdown_rules_duration_positive CHECK (duration_days > 0)
+);
+
+CREATE INDEX idx_markdown_rules_store_id ON markdown_rules(store_id);
+CREATE INDEX idx_markdown_rules_active ON markdown_rules(is_active) WHERE is_active = TRUE;
+
+CREATE TABLE IF NOT EXISTS markdowns (
+    id UUID PRIMARY KEY,
+    rule_id UUID NOT NULL REFERENCES markdown_rules(id) ON DELETE CASCADE,
+    store_id UUID NOT NULL REFERENCES stores(id),
+    product_id UUID NOT NULL REFERENCES products(id) ON DELETE CASCADE,
+    promotion_id UUID NOT NULL REFERENCES promotions(id),
+    reason VARCHAR(20) NOT NULL,
+    reason_detail TEXT NOT NULL,
+    discount_percent DECIMAL(5,2) NOT NULL,
+    starts_at TIMESTAMPTZ NOT NULL,
+    ends_at TIMESTAMPTZ NOT NULL,
+    status VARCHAR(20) NOT NULL DEFAULT 'active',
+    reversed_by_id UUID REFERENCES users(id),
+    reversed_at TIMESTAMPTZ,
+    reversal_reason TEXT,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+
+    CONSTRAINT markdowns_reason_check CHECK (reason IN ('near_expiry', 'idle_stock')),
+    CONSTRAINT markdowns_status_check CHECK (status IN ('active', 'expired', 'reversed'))
+);
+
+CREATE INDEX idx_markdowns_store_id ON markdowns(store_id);
+CREATE INDEX idx_markdowns_product_id ON markdowns(product_id);
+CREATE INDEX idx_markdowns_status ON markdowns(status);
+CREATE INDEX idx_markdowns_active_product ON markdowns(store_id, product_id) WHERE status = 'active';
@@ -0,0 +1,27 @@
+-- Per-store sequence for human-friendly customer codes ("CUS-00042").
+--
+-- `last_value` is bumped with a single UPSERT ... RETURNING, so concurrent
+-- customer creates never receive the same number. `prefix` and `padding`
+-- are configurable per store.
+
+CREATE TABLE IF NOT EXISTS customer_code_sequences (
+    store_id UUID PRIMARY KEY REFERENCES stores(id) ON DELETE CASCADE,
+    prefix VARCHAR(20) NOT NULL DEFAULT 'CUS-',
+    padding SMALLINT NOT NULL DEFAULT 5,
+    last_value BIGINT NOT NULL DEFAULT 0,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+
+    CONSTRAINT customer_code_sequences_padding_range CHECK (padding BETWEEN 1 AND 12),
+    CONSTRAINT customer_code_sequences_last_value_positive CHECK (last_value >= 0)
+);
+
+-- One-time backfill: start each store's sequence after the highest numeric
+-- code already issued with the default prefix (codes were previously derived
+-- from COUNT(*), which could collide under concurrency).
+INSERT INTO customer_code_sequences (store_id, last_value)
+SELECT store_id,
+       COALESCE(MAX(SUBSTRING(code FROM '^CUS-([0-9]{1,18})$')::BIGINT), 0)
+FROM customers
+GROUP BY store_id
+ON CONFLICT (store_id) DO NOTHING;
@@ -0,0 +1,20 @@
+-- Outbound webhook delivery attempts: one row per HTTP POST of a `webhook`
+-- channel notification. The notification id doubles as the delivery id that
+-- receivers dedupe on; `signed_timestamp` is the per-attempt timestamp that
+-- was covered by the signature. See modules/notifications (HttpWebhookAdapter).
+
+CREATE TABLE IF NOT EXISTS webhook_delivery_attempts (
+    id                  UUID PRIMARY KEY,
+    notification_id     UUID NOT NULL REFERENCES notifications(id) ON DELETE CASCADE,
+    attempt_number      INTEGER NOT NULL,
+    url                 TEXT NOT NULL,
+    signature_algorithm VARCHAR(20) NOT NULL,
+    signed_timestamp    BIGINT NOT NULL,
+    response_status     INTEGER,                 -- NULL when no response (timeout, DNS, TLS)
+    error               TEXT,
+    duration_ms         BIGINT NOT NULL,
+    attempted_at        TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX IF NOT EXISTS idx_webhook_delivery_attempts_notification
+    ON webhook_delivery_attempts (notification_id, attempted_at);
@@ -0,0 +1,30 @@
+-- Audit trail for cashier shifts reopened by a manager after a premature
+-- close. Each row keeps the closing snapshot that was cleared on reopen, so
+-- the original count can be reconstructed. See modules/sales (ReopenShiftUseCase).
+
+CREATE TABLE IF NOT EXISTS cashier_shift_reopens (
+    id                       UUID PRIMARY KEY,
+    shift_id                 UUID NOT NULL REFERENCES cashier_shifts(id) ON DELETE CASCADE,
+    reopened_by_id           UUID NOT NULL REFERENCES users(id),
+    reason                   TEXT NOT NULL,
+    previous_closed_at       TIMESTAMPTZ NOT NULL,
+    previous_closing_balance DECIMAL(15,4),
+    previous_closing_notes   TEXT,
+    reopened_at              TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX IF NOT EXISTS idx_cashier_shift_reopens_shift
+    ON cashier_shift_reopens (shift_id, reopened_at);
+
+-- Permission backup; seed/src/data.rs is the source of truth.
+INSERT INTO permissions (id, code, description) VALUES
+    (gen_random_uuid(), 'sales:reopen_shift', 'Reopen a recently closed cashier shift')
+ON CONFLICT (code) DO NOTHING;
+
+INSERT INTO role_permissions (role_id, permission_id)
+SELECT r.id, p.id
+FROM roles r
+CROSS JOIN permissions p
+WHERE r.name IN ('super_admin', 'store_admin', 'store_manager')
+  AND p.code = 'sales:reopen_shift'
+ON CONFLICT DO NOTHING;
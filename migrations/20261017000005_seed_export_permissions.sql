@@ -0,0 +1,30 @@
+-- Export permissions, separate from read: exporting customer lists or sales
+-- is a distinct right, and unmasked customer email/phone in exports requires
+-- sales:read_customer_pii. Every export is recorded in audit_log
+-- (action = 'exported').
+--
+-- IMPORTANT: this is a backup. The seed binary
+-- (seed/src/data.rs::PERMISSIONS + ROLE_PERMISSIONS) is the actual source
+-- of truth, since it runs after migrations.
+
+INSERT INTO permissions (id, code, description) VALUES
+    (gen_random_uuid(), 'sales:export',            'Export sales transactions'),
+    (gen_random_uuid(), 'sales:export_customer',   'Export customer lists'),
+    (gen_random_uuid(), 'sales:read_customer_pii', 'View unmasked customer email and phone in exports')
+ON CONFLICT (code) DO NOTHING;
+
+INSERT INTO role_permissions (role_id, permission_id)
+SELECT r.id, p.id
+FROM roles r
+CROSS JOIN permissions p
+WHERE r.name IN ('super_admin', 'store_admin')
+  AND p.code IN ('sales:export', 'sales:export_customer', 'sales:read_customer_pii')
+ON CONFLICT DO NOTHING;
+
+INSERT INTO role_permissions (role_id, permission_id)
+SELECT r.id, p.id
+FROM roles r
+CROSS JOIN permissions p
+WHERE r.name = 'store_manager'
+  AND p.code IN ('sales:export', 'sales:export_customer')
+ON CONFLICT DO NOTHING;
@@ -0,0 +1,36 @@
+-- Approval gate for stock transfers. Transfers above the configured value
+-- threshold (TRANSFER_APPROVAL_VALUE_THRESHOLD) or between stores in
+-- different regions (TRANSFER_APPROVAL_STORE_REGIONS) are submitted as
+-- 'pending_approval' and must be approved before they can ship. Rejecting
+-- a transfer cancels it.
+
+ALTER TABLE stock_transfers
+    ADD COLUMN IF NOT EXISTS requires_approval BOOLEAN NOT NULL DEFAULT false,
+    ADD COLUMN IF NOT EXISTS approval_reason TEXT,
+    ADD COLUMN IF NOT EXISTS approved_by_id UUID NULL REFERENCES users(id) ON DELETE RESTRICT,
+    ADD COLUMN IF NOT EXISTS approved_at TIMESTAMPTZ,
+    ADD COLUMN IF NOT EXISTS approval_notes TEXT;
+
+ALTER TABLE stock_transfers DROP CONSTRAINT IF EXISTS stock_transfers_status_check;
+ALTER TABLE stock_transfers ADD CONSTRAINT stock_transfers_status_check CHECK (
+    status IN ('draft', 'pending_approval', 'approved', 'pending', 'in_transit', 'completed', 'cancelled')
+);
+
+CREATE INDEX IF NOT EXISTS idx_stock_transfers_pending_approval ON stock_transfers(status)
+    WHERE status = 'pending_approval';
+
+-- IMPORTANT: this is a backup. The seed binary
+-- (seed/src/data.rs::PERMISSIONS + ROLE_PERMISSIONS) is the actual source
+-- of truth, since it runs after migrations.
+
+INSERT INTO permissions (id, code, description) VALUES
+    (gen_random_uuid(), 'transfers:approve', 'Approve or reject high-value and cross-region transfers')
+ON CONFLICT (code) DO NOTHING;
+
+INSERT INTO role_permissions (role_id, permission_id)
+SELECT r.id, p.id
+FROM roles r
+CROSS JOIN permissions p
+WHERE r.name IN ('super_admin', 'store_admin', 'store_manager')
+  AND p.code = 'transfers:approve'
+ON CONFLICT DO NOTHING;
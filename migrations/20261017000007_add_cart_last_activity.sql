@@ -0,0 +1,11 @@
+-- Track the last mutation of a cart so the abandoned-cart cleanup can apply
+-- inactivity TTLs (CART_GUEST_TTL_HOURS / CART_CUSTOMER_TTL_HOURS) instead of
+-- relying on expires_at alone.
+
+ALTER TABLE carts
+    ADD COLUMN IF NOT EXISTS last_activity_at TIMESTAMPTZ NOT NULL DEFAULT NOW();
+
+UPDATE carts SET last_activity_at = updated_at;
+
+CREATE INDEX IF NOT EXISTS idx_carts_last_activity ON carts(last_activity_at)
+    WHERE converted_to_sale = FALSE;
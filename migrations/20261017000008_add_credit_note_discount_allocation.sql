@@ -0,0 +1,10 @@
+-- Sale discounts allocated to returned lines. Credit note items now refund
+-- the line's share of the original sale discount (sale-level discounts are
+-- distributed across lines by value), so total = subtotal - discount + tax
+-- and returning every line of a sale reconciles exactly with the sale total.
+
+ALTER TABLE credit_note_items
+    ADD COLUMN IF NOT EXISTS discount_amount DECIMAL(15,4) NOT NULL DEFAULT 0;
+
+ALTER TABLE credit_notes
+    ADD COLUMN IF NOT EXISTS discount_amount DECIMAL(15,4) NOT NULL DEFAULT 0;
@@ -0,0 +1,14 @@
+-- Tax category inheritance. A category's tax category applies to every
+-- product below it in the tree; a product's own tax category overrides it.
+-- NULL means "not defined here": categories defer to their parent and
+-- products to their category. Values match the fiscal tax types.
+
+ALTER TABLE product_categories
+    ADD COLUMN IF NOT EXISTS tax_category VARCHAR(20) NULL
+        CONSTRAINT product_categories_tax_category_check
+        CHECK (tax_category IN ('isv15', 'isv18', 'exempt'));
+
+ALTER TABLE products
+    ADD COLUMN IF NOT EXISTS tax_category VARCHAR(20) NULL
+        CONSTRAINT products_tax_category_check
+        CHECK (tax_category IN ('isv15', 'isv18', 'exempt'));
@@ -0,0 +1,31 @@
+-- Customer accounts: customers with a credit limit may buy on account
+-- (payment method 'on_account'). account_balance is what they owe; it only
+-- changes by posting a customer_account_entries row in the same statement,
+-- so the ledger always explains the balance. A credit limit of 0 means the
+-- customer can't buy on account.
+
+ALTER TABLE customers
+    ADD COLUMN IF NOT EXISTS credit_limit DECIMAL(15,4) NOT NULL DEFAULT 0
+        CONSTRAINT customers_credit_limit_check CHECK (credit_limit >= 0),
+    ADD COLUMN IF NOT EXISTS account_balance DECIMAL(15,4) NOT NULL DEFAULT 0;
+
+CREATE TABLE IF NOT EXISTS customer_account_entries (
+    id UUID PRIMARY KEY,
+    customer_id UUID NOT NULL REFERENCES customers(id),
+    store_id UUID NOT NULL REFERENCES stores(id),
+    -- sale (charge), sale_void, payment, credit_note (credits)
+    entry_type VARCHAR(20) NOT NULL
+        CONSTRAINT customer_account_entries_type_check
+        CHECK (entry_type IN ('sale', 'sale_void', 'payment', 'credit_note')),
+    -- Positive for charges, negative for credits
+    amount DECIMAL(15,4) NOT NULL,
+    balance_after DECIMAL(15,4) NOT NULL,
+    reference_id UUID,
+    reference_number VARCHAR(100),
+    notes TEXT,
+    created_by_id UUID REFERENCES users(id),
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX IF NOT EXISTS idx_customer_account_entries_customer
+    ON customer_account_entries (customer_id, created_at);
@@ -0,0 +1,23 @@
+-- Per-store settings: behavior switches read on every request, so changing
+-- one takes effect without a redeploy. A store without a row uses the
+-- defaults below, which match the behavior before settings existed.
+
+CREATE TABLE IF NOT EXISTS store_settings (
+    store_id UUID PRIMARY KEY REFERENCES stores(id) ON DELETE CASCADE,
+    loyalty_enabled BOOLEAN NOT NULL DEFAULT TRUE,
+    auto_markdowns_enabled BOOLEAN NOT NULL DEFAULT TRUE,
+    allow_negative_stock BOOLEAN NOT NULL DEFAULT FALSE,
+    updated_by_id UUID REFERENCES users(id),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+-- Stores that allow negative stock may sell past zero, so the floor moves
+-- from the table to the application. Reservations still can't exceed what
+-- is on hand.
+ALTER TABLE inventory_stock
+    DROP CONSTRAINT IF EXISTS inventory_stock_quantity_check,
+    DROP CONSTRAINT IF EXISTS inventory_stock_reserved_check;
+
+ALTER TABLE inventory_stock
+    ADD CONSTRAINT inventory_stock_reserved_check
+        CHECK (reserved_quantity <= GREATEST(quantity, 0));
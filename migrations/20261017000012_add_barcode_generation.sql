@@ -0,0 +1,31 @@
+-- Automatic EAN-13 barcodes for products and variants created without one.
+--
+-- A category may configure a numeric prefix (typically a GS1 company
+-- prefix). Each prefix owns a sequence whose `last_value` is bumped with a
+-- single UPSERT ... RETURNING, so concurrent creates never receive the same
+-- number. Categories that share a prefix share its sequence.
+
+CREATE TABLE IF NOT EXISTS category_barcode_prefixes (
+    category_id UUID PRIMARY KEY REFERENCES product_categories(id) ON DELETE CASCADE,
+    prefix VARCHAR(9) NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+
+    CONSTRAINT category_barcode_prefixes_digits CHECK (prefix ~ '^[0-9]{3,9}$')
+);
+
+CREATE TABLE IF NOT EXISTS barcode_sequences (
+    prefix VARCHAR(9) PRIMARY KEY,
+    last_value BIGINT NOT NULL DEFAULT 0,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+
+    CONSTRAINT barcode_sequences_last_value_positive CHECK (last_value >= 0)
+);
+
+-- Whether the barcode was generated rather than entered by hand
+ALTER TABLE products
+    ADD COLUMN IF NOT EXISTS barcode_generated BOOLEAN NOT NULL DEFAULT FALSE;
+
+ALTER TABLE product_variants
+    ADD COLUMN IF NOT EXISTS barcode_generated BOOLEAN NOT NULL DEFAULT FALSE;
@@ -0,0 +1,33 @@
+-- Buy online, pick up in store.
+--
+-- An order marked ready for pickup gets one row here holding the code the
+-- customer presents at the counter and the end of the hold. Stock stays
+-- reserved until hand-over; orders nobody collects by `hold_until` are
+-- cancelled and their reservations released.
+
+CREATE TABLE IF NOT EXISTS order_pickups (
+    sale_id UUID PRIMARY KEY REFERENCES sales(id) ON DELETE CASCADE,
+    store_id UUID NOT NULL REFERENCES stores(id),
+    pickup_code VARCHAR(12) NOT NULL,
+    status VARCHAR(20) NOT NULL DEFAULT 'waiting',
+    ready_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    hold_until TIMESTAMPTZ NOT NULL,
+    picked_up_at TIMESTAMPTZ,
+    picked_up_by_id UUID REFERENCES users(id),
+    closed_at TIMESTAMPTZ,
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+
+    CONSTRAINT order_pickups_status_check
+        CHECK (status IN ('waiting', 'picked_up', 'expired', 'cancelled')),
+    CONSTRAINT order_pickups_hold_check CHECK (hold_until > ready_at)
+);
+
+-- The expiry job scans waiting pickups by end of hold
+CREATE INDEX IF NOT EXISTS idx_order_pickups_waiting_hold
+    ON order_pickups (hold_until)
+    WHERE status = 'waiting';
+
+-- Days a store holds a pickup order before cancelling it as a no-show
+ALTER TABLE store_settings
+    ADD COLUMN IF NOT EXISTS pickup_hold_days INTEGER NOT NULL DEFAULT 7
+        CHECK (pickup_hold_days BETWEEN 1 AND 90);
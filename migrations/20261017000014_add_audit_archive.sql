@@ -0,0 +1,35 @@
+-- Audit retention.
+--
+-- A scheduled job moves audit_log entries older than AUDIT_RETENTION_DAYS
+-- into audit_log_archive, keeping the hot table small. Archived rows keep
+-- their id and timestamps so they stay queryable.
+--
+-- Records listed in audit_legal_holds are never archived, whatever the age
+-- of their entries; releasing the hold makes them eligible again.
+
+CREATE TABLE IF NOT EXISTS audit_log_archive (
+    id UUID PRIMARY KEY,
+    entity_type VARCHAR(100) NOT NULL,
+    entity_id UUID NOT NULL,
+    action VARCHAR(50) NOT NULL,
+    old_value JSONB,
+    new_value JSONB,
+    actor_id UUID NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL,
+    archived_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX IF NOT EXISTS idx_audit_log_archive_entity
+    ON audit_log_archive (entity_type, entity_id);
+CREATE INDEX IF NOT EXISTS idx_audit_log_archive_created_at
+    ON audit_log_archive (created_at);
+
+CREATE TABLE IF NOT EXISTS audit_legal_holds (
+    entity_type VARCHAR(100) NOT NULL,
+    entity_id UUID NOT NULL,
+    reason TEXT NOT NULL,
+    placed_by_id UUID NOT NULL REFERENCES users(id),
+    placed_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+
+    PRIMARY KEY (entity_type, entity_id)
+);
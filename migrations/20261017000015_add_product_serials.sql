@@ -0,0 +1,39 @@
+-- Serial number tracking.
+--
+-- Products flagged is_serialized have each unit registered in
+-- product_serials. Sale lines of such products must capture one serial per
+-- unit (sale_items.serial_numbers); completing the sale marks the units
+-- sold against the line. Credit notes record which serials came back
+-- (credit_note_items.serial_numbers): restocked units become available
+-- again, the others are kept as returned.
+
+ALTER TABLE products
+    ADD COLUMN IF NOT EXISTS is_serialized BOOLEAN NOT NULL DEFAULT FALSE;
+
+CREATE TABLE IF NOT EXISTS product_serials (
+    id UUID PRIMARY KEY,
+    product_id UUID NOT NULL REFERENCES products(id) ON DELETE CASCADE,
+    variant_id UUID REFERENCES product_variants(id) ON DELETE CASCADE,
+    store_id UUID NOT NULL REFERENCES stores(id),
+    serial_number VARCHAR(100) NOT NULL,
+    status VARCHAR(20) NOT NULL DEFAULT 'available'
+        CHECK (status IN ('available', 'sold', 'returned')),
+    sale_id UUID REFERENCES sales(id),
+    sale_item_id UUID,
+    sold_at TIMESTAMPTZ,
+    credit_note_id UUID REFERENCES credit_notes(id),
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    CONSTRAINT uq_product_serials_product_serial UNIQUE (product_id, serial_number)
+);
+
+CREATE INDEX IF NOT EXISTS idx_product_serials_serial_number
+    ON product_serials (serial_number);
+CREATE INDEX IF NOT EXISTS idx_product_serials_product_store_status
+    ON product_serials (product_id, store_id, status);
+
+ALTER TABLE sale_items
+    ADD COLUMN IF NOT EXISTS serial_numbers TEXT[] NOT NULL DEFAULT '{}';
+
+ALTER TABLE credit_note_items
+    ADD COLUMN IF NOT EXISTS serial_numbers TEXT[] NOT NULL DEFAULT '{}';
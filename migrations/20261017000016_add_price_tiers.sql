@@ -0,0 +1,36 @@
+-- Price tiers by customer type.
+--
+-- `price_tiers` sets what a customer type (individual, business) pays at a
+-- store, either for one product (fixed price or percentage off) or for a
+-- whole category (percentage off). Product tiers win over category tiers;
+-- products without a tier sell at their base price.
+--
+-- Sale lines record the tier their unit price was resolved from.
+
+CREATE TABLE IF NOT EXISTS price_tiers (
+    id UUID PRIMARY KEY,
+    store_id UUID NOT NULL REFERENCES stores(id),
+    customer_type VARCHAR(20) NOT NULL,
+    product_id UUID REFERENCES products(id) ON DELETE CASCADE,
+    category_id UUID REFERENCES product_categories(id) ON DELETE CASCADE,
+    price DECIMAL(12,2),
+    discount_percent DECIMAL(5,2),
+    is_active BOOLEAN NOT NULL DEFAULT TRUE,
+    created_by_id UUID NOT NULL REFERENCES users(id),
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+
+    CONSTRAINT price_tiers_one_target CHECK ((product_id IS NULL) <> (category_id IS NULL)),
+    CONSTRAINT price_tiers_one_price CHECK ((price IS NULL) <> (discount_percent IS NULL)),
+    CONSTRAINT price_tiers_category_percent CHECK (category_id IS NULL OR price IS NULL),
+    CONSTRAINT price_tiers_price_non_negative CHECK (price IS NULL OR price >= 0),
+    CONSTRAINT price_tiers_discount_range CHECK (discount_percent IS NULL OR (discount_percent > 0 AND discount_percent < 100))
+);
+
+CREATE UNIQUE INDEX IF NOT EXISTS uq_price_tiers_product
+    ON price_tiers(store_id, customer_type, product_id) WHERE product_id IS NOT NULL;
+CREATE UNIQUE INDEX IF NOT EXISTS uq_price_tiers_category
+    ON price_tiers(store_id, customer_type, category_id) WHERE category_id IS NOT NULL;
+
+ALTER TABLE sale_items
+    ADD COLUMN IF NOT EXISTS price_tier_id UUID REFERENCES price_tiers(id) ON DELETE SET NULL;
@@ -0,0 +1,13 @@
+-- Ownership of stock in transit between stores.
+--
+-- A shipped transfer's stock has left the source's on-hand but not reached
+-- the destination's. Valuation reports count it toward the source store,
+-- unless the source store sets transit_owned_by_destination.
+
+ALTER TABLE store_settings
+    ADD COLUMN IF NOT EXISTS transit_owned_by_destination BOOLEAN NOT NULL DEFAULT FALSE;
+
+-- The in-transit report reads shipped, unreceived transfers
+CREATE INDEX IF NOT EXISTS idx_stock_transfers_in_transit
+    ON stock_transfers (shipped_date)
+    WHERE status = 'in_transit';
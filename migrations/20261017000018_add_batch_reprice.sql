@@ -0,0 +1,76 @@
+-- Batch reprice from vendor cost changes.
+--
+-- Products may carry a markup rule (markup_type/markup_percent) that derives
+-- the selling price from cost: 'markup' (% over cost), 'margin' (% of price)
+-- or 'keystone' (twice the cost). Products without one are priced manually.
+--
+-- A vendor cost update is previewed as a pending reprice_batches row whose
+-- lines hold each product's old/new cost and price; manually priced products
+-- get a NULL new_price and keep their price. Approving the batch writes the
+-- new costs and prices to products and records each change in
+-- product_price_history.
+
+ALTER TABLE products
+    ADD COLUMN IF NOT EXISTS markup_type VARCHAR(20)
+        CHECK (markup_type IN ('markup', 'margin', 'keystone')),
+    ADD COLUMN IF NOT EXISTS markup_percent NUMERIC(7, 4);
+
+CREATE TABLE IF NOT EXISTS reprice_batches (
+    id UUID PRIMARY KEY,
+    vendor_id UUID REFERENCES vendors(id),
+    reason TEXT,
+    status VARCHAR(20) NOT NULL DEFAULT 'pending'
+        CHECK (status IN ('pending', 'applied', 'rejected')),
+    created_by_id UUID NOT NULL REFERENCES users(id),
+    decided_by_id UUID REFERENCES users(id),
+    decided_at TIMESTAMPTZ,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX IF NOT EXISTS idx_reprice_batches_status_created
+    ON reprice_batches (status, created_at DESC);
+
+CREATE TABLE IF NOT EXISTS reprice_batch_lines (
+    batch_id UUID NOT NULL REFERENCES reprice_batches(id) ON DELETE CASCADE,
+    product_id UUID NOT NULL REFERENCES products(id) ON DELETE CASCADE,
+    product_name VARCHAR(255) NOT NULL,
+    markup_type VARCHAR(20),
+    markup_percent NUMERIC(7, 4),
+    old_cost NUMERIC(20, 4) NOT NULL,
+    new_cost NUMERIC(20, 4) NOT NULL CHECK (new_cost >= 0),
+    old_price NUMERIC(20, 4) NOT NULL,
+    new_price NUMERIC(20, 4),
+    PRIMARY KEY (batch_id, product_id)
+);
+
+CREATE TABLE IF NOT EXISTS product_price_history (
+    id UUID PRIMARY KEY,
+    product_id UUID NOT NULL REFERENCES products(id) ON DELETE CASCADE,
+    old_cost NUMERIC(20, 4) NOT NULL,
+    new_cost NUMERIC(20, 4) NOT NULL,
+    old_price NUMERIC(20, 4) NOT NULL,
+    new_price NUMERIC(20, 4) NOT NULL,
+    reprice_batch_id UUID REFERENCES reprice_batches(id),
+    changed_by_id UUID NOT NULL REFERENCES users(id),
+    changed_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX IF NOT EXISTS idx_product_price_history_product
+    ON product_price_history (product_id, changed_at DESC);
+
+-- Permission to approve batch reprices
+INSERT INTO permissions (id, code, description) VALUES
+    (gen_random_uuid(), 'products:reprice', 'Approve batch reprices from vendor cost changes')
+ON CONFLICT (code) DO NOTHING;
+
+INSERT INTO role_permissions (role_id, permission_id)
+SELECT r.id, p.id
+FROM roles r
+CROSS JOIN permissions p
+WHERE r.name IN ('super_admin', 'store_admin')
+  AND p.code = 'products:reprice'
+  AND NOT EXISTS (
+    SELECT 1 FROM role_permissions rp
+    WHERE rp.role_id = r.id AND rp.permission_id = p.id
+  );
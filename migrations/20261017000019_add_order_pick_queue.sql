@@ -0,0 +1,30 @@
+-- Warehouse pick queue for paid e-commerce orders.
+--
+-- Paid online orders that have not left the store are listed to pickers by
+-- payment time and shipping method. An order gets a row in order_picks the
+-- first time it is claimed or put on hold; orders on hold are left out of
+-- the queue. `version` drives optimistic locking: a claim only succeeds if
+-- the version still matches the one the picker read (0 = no row yet), so
+-- two pickers can never take the same order.
+
+CREATE TABLE IF NOT EXISTS order_picks (
+    sale_id UUID PRIMARY KEY REFERENCES sales(id) ON DELETE CASCADE,
+    store_id UUID NOT NULL REFERENCES stores(id),
+    picker_id UUID REFERENCES users(id),
+    claimed_at TIMESTAMPTZ,
+    on_hold BOOLEAN NOT NULL DEFAULT false,
+    hold_reason TEXT,
+    version INTEGER NOT NULL DEFAULT 1,
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+
+    CONSTRAINT order_picks_claim_check
+        CHECK ((picker_id IS NULL) = (claimed_at IS NULL))
+);
+
+CREATE INDEX IF NOT EXISTS idx_order_picks_picker
+    ON order_picks (picker_id)
+    WHERE picker_id IS NOT NULL;
+
+-- Bin or shelf a stock row is picked from, when the store records one
+ALTER TABLE inventory_stock
+    ADD COLUMN IF NOT EXISTS bin_location VARCHAR(50);
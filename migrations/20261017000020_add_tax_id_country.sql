@@ -0,0 +1,11 @@
+-- Country whose format a customer's or vendor's tax id was validated against.
+--
+-- NULL means the configured default country (TAX_ID_DEFAULT_COUNTRY); rows
+-- written before per-country validation keep NULL. Tax ids are stored
+-- without separators from now on.
+
+ALTER TABLE customers ADD COLUMN IF NOT EXISTS tax_id_country CHAR(2);
+ALTER TABLE vendors ADD COLUMN IF NOT EXISTS tax_id_country CHAR(2);
+
+COMMENT ON COLUMN customers.tax_id_country IS 'ISO 3166-1 alpha-2 country of tax_id';
+COMMENT ON COLUMN vendors.tax_id_country IS 'ISO 3166-1 alpha-2 country of tax_id';
@@ -0,0 +1,11 @@
+-- Auto-approval of small negative stock adjustments.
+--
+-- Adjustments within the configured limits (ADJUSTMENT_AUTO_APPROVE_*) are
+-- approved when they are created, skipping submission and review; theft
+-- adjustments always go through approval. Such adjustments have no approver
+-- and are flagged here.
+
+ALTER TABLE stock_adjustments
+    ADD COLUMN IF NOT EXISTS auto_approved BOOLEAN NOT NULL DEFAULT FALSE;
+
+COMMENT ON COLUMN stock_adjustments.auto_approved IS 'Approved on creation under the auto-approval limits';
@@ -0,0 +1,11 @@
+-- Per-line allocation of sale-level discounts.
+--
+-- A sale-level discount is split across lines by their value after line
+-- discounts, and each line's tax is charged on what remains, so tax-exempt
+-- lines absorb their share of the discount. The share is stored per line;
+-- tax_amount and total on the line already reflect it.
+
+ALTER TABLE sale_items
+    ADD COLUMN IF NOT EXISTS order_discount_amount DECIMAL(15,4) NOT NULL DEFAULT 0;
+
+COMMENT ON COLUMN sale_items.order_discount_amount IS 'Share of the sale-level discount allocated to this line';
@@ -0,0 +1,13 @@
+-- Migration: Add priority and bump tracking to inventory reservations
+-- A higher-priority reservation may expire lower-priority pending holds when
+-- stock is short; bumped holds record when and why.
+
+ALTER TABLE inventory_reservations
+    ADD COLUMN IF NOT EXISTS priority INTEGER NOT NULL DEFAULT 0,
+    ADD COLUMN IF NOT EXISTS bumped_at TIMESTAMPTZ,
+    ADD COLUMN IF NOT EXISTS bump_reason VARCHAR(255);
+
+-- Candidates for bumping: pending holds on a stock record by priority
+CREATE INDEX IF NOT EXISTS idx_inventory_reservations_stock_priority
+    ON inventory_reservations(stock_id, priority)
+    WHERE status = 'pending';
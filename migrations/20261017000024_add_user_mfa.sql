@@ -0,0 +1,18 @@
+-- Migration: Add TOTP multi-factor authentication enrollments
+-- One row per enrolled user. The secret is AES-256-GCM encrypted by the
+-- application (nonce || ciphertext); recovery codes are SHA-256 hashes and
+-- are removed as they are used. enabled_at stays NULL until the user
+-- confirms the enrollment with a code, and only enabled rows are enforced at
+-- login. failed_attempts / locked_until throttle second-factor attempts.
+
+CREATE TABLE IF NOT EXISTS user_mfa (
+    user_id UUID PRIMARY KEY REFERENCES users(id) ON DELETE CASCADE,
+    secret_ciphertext BYTEA NOT NULL,
+    enabled_at TIMESTAMPTZ,
+    recovery_code_hashes TEXT[] NOT NULL DEFAULT '{}',
+    failed_attempts INTEGER NOT NULL DEFAULT 0,
+    locked_until TIMESTAMPTZ,
+    last_used_step BIGINT,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
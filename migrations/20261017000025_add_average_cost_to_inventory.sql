@@ -0,0 +1,11 @@
+-- Migration: Store running weighted-average cost on the kardex
+-- average_cost on a movement is the stock record's weighted-average unit cost
+-- right after that movement; on inventory_stock it is the current one. Both
+-- are written when the kardex is replayed to correct costs and stay NULL
+-- until then.
+
+ALTER TABLE inventory_movements
+    ADD COLUMN IF NOT EXISTS average_cost NUMERIC(20, 4) NULL;
+
+ALTER TABLE inventory_stock
+    ADD COLUMN IF NOT EXISTS average_cost NUMERIC(20, 4) NULL;
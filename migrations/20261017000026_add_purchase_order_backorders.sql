@@ -0,0 +1,13 @@
+-- Migration: Backorders for partial goods receipts
+-- A goods receipt created with split_backorder moves the quantities still
+-- pending on its purchase order into a new draft order when confirmed. The
+-- new order points back to the original through parent_order_id.
+
+ALTER TABLE purchase_orders
+    ADD COLUMN IF NOT EXISTS parent_order_id UUID NULL REFERENCES purchase_orders(id) ON DELETE SET NULL;
+
+CREATE INDEX IF NOT EXISTS idx_purchase_orders_parent_order_id ON purchase_orders(parent_order_id)
+    WHERE parent_order_id IS NOT NULL;
+
+ALTER TABLE goods_receipts
+    ADD COLUMN IF NOT EXISTS split_backorder BOOLEAN NOT NULL DEFAULT FALSE;
@@ -0,0 +1,25 @@
+-- Migration: FIFO/LIFO cost layers
+-- Each product category picks how its stock is costed. Weighted average keeps
+-- using the kardex as before; for FIFO and LIFO every costed receipt opens a
+-- layer and outgoing movements consume layers oldest-first (FIFO) or
+-- newest-first (LIFO). Valuation reads the units left in the layers.
+
+ALTER TABLE product_categories
+    ADD COLUMN IF NOT EXISTS costing_method VARCHAR(20) NOT NULL DEFAULT 'weighted_average'
+        CONSTRAINT product_categories_costing_method_check
+        CHECK (costing_method IN ('weighted_average', 'fifo', 'lifo'));
+
+CREATE TABLE IF NOT EXISTS inventory_cost_layers (
+    id UUID PRIMARY KEY,
+    stock_id UUID NOT NULL REFERENCES inventory_stock(id) ON DELETE CASCADE,
+    movement_id UUID NOT NULL UNIQUE REFERENCES inventory_movements(id) ON DELETE CASCADE,
+    unit_cost NUMERIC(20, 4) NOT NULL,
+    original_quantity NUMERIC(20, 4) NOT NULL CHECK (original_quantity > 0),
+    remaining_quantity NUMERIC(20, 4) NOT NULL CHECK (remaining_quantity >= 0),
+    received_at TIMESTAMPTZ NOT NULL,
+    CONSTRAINT inventory_cost_layers_remaining_check CHECK (remaining_quantity <= original_quantity)
+);
+
+-- Open layers of a stock record in receipt order
+CREATE INDEX IF NOT EXISTS idx_inventory_cost_layers_open ON inventory_cost_layers(stock_id, received_at, id)
+    WHERE remaining_quantity > 0;
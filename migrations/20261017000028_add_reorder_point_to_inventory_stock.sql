@@ -0,0 +1,11 @@
+-- Migration: Store-specific reorder points
+-- A stock row may carry its own reorder point and order size. Low-stock
+-- alerts compare available stock against reorder_point, falling back to
+-- min_stock_level when it is NULL; reorder_quantity, when set, is the
+-- suggested quantity to order.
+
+ALTER TABLE inventory_stock
+    ADD COLUMN IF NOT EXISTS reorder_point NUMERIC(20, 4) NULL
+        CONSTRAINT inventory_stock_reorder_point_check CHECK (reorder_point >= 0),
+    ADD COLUMN IF NOT EXISTS reorder_quantity NUMERIC(20, 4) NULL
+        CONSTRAINT inventory_stock_reorder_quantity_check CHECK (reorder_quantity > 0);
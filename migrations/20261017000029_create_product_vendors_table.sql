@@ -0,0 +1,22 @@
+-- Migration: Product vendors
+-- Vendors that supply a product, with the vendor's own SKU and the last price
+-- paid. A product may have several vendors; the use case keeps exactly one of
+-- them marked preferred, and the partial unique index guarantees at most one.
+
+CREATE TABLE IF NOT EXISTS product_vendors (
+    product_id UUID NOT NULL REFERENCES products(id) ON DELETE CASCADE,
+    vendor_id UUID NOT NULL REFERENCES vendors(id) ON DELETE CASCADE,
+    vendor_sku VARCHAR(100),
+    last_purchase_price NUMERIC(20, 4) CHECK (last_purchase_price >= 0),
+    is_preferred BOOLEAN NOT NULL DEFAULT FALSE,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (product_id, vendor_id)
+);
+
+-- At most one preferred vendor per product
+CREATE UNIQUE INDEX IF NOT EXISTS idx_product_vendors_preferred ON product_vendors(product_id)
+    WHERE is_preferred;
+
+-- Products supplied by a vendor
+CREATE INDEX IF NOT EXISTS idx_product_vendors_vendor ON product_vendors(vendor_id);
@@ -0,0 +1,7 @@
+-- Migration: Serial numbers on goods receipt lines
+-- Lines of serial-tracked products (products.is_serialized) carry one serial
+-- per received unit. Confirming the receipt registers them in
+-- product_serials as available at the receipt's store.
+
+ALTER TABLE goods_receipt_items
+    ADD COLUMN IF NOT EXISTS serial_numbers TEXT[] NOT NULL DEFAULT '{}';
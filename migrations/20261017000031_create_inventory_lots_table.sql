@@ -0,0 +1,27 @@
+-- Migration: Lot/batch and expiration tracking
+-- Confirming a goods receipt adds the received quantity of every line with a
+-- lot number or expiry date to its lot, keyed by product, variant, store,
+-- lot number and expiry date. Stock leaving the store comes off the lots of
+-- its stock record earliest-expiry first (FEFO); depleted lots keep their
+-- history with remaining_quantity = 0.
+
+CREATE TABLE IF NOT EXISTS inventory_lots (
+    id UUID PRIMARY KEY,
+    product_id UUID NOT NULL REFERENCES products(id) ON DELETE CASCADE,
+    variant_id UUID REFERENCES product_variants(id) ON DELETE CASCADE,
+    store_id UUID NOT NULL REFERENCES stores(id),
+    lot_number VARCHAR(100),
+    expiry_date DATE,
+    received_quantity NUMERIC(20, 4) NOT NULL CHECK (received_quantity > 0),
+    remaining_quantity NUMERIC(20, 4) NOT NULL CHECK (remaining_quantity >= 0),
+    received_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    CONSTRAINT inventory_lots_identity_check CHECK (lot_number IS NOT NULL OR expiry_date IS NOT NULL),
+    CONSTRAINT inventory_lots_remaining_check CHECK (remaining_quantity <= received_quantity),
+    CONSTRAINT uq_inventory_lots_key UNIQUE NULLS NOT DISTINCT
+        (product_id, variant_id, store_id, lot_number, expiry_date)
+);
+
+-- Open lots of a store by expiry, for FEFO consumption and expiry reports
+CREATE INDEX IF NOT EXISTS idx_inventory_lots_open ON inventory_lots(store_id, expiry_date)
+    WHERE remaining_quantity > 0;
@@ -0,0 +1,7 @@
+-- Migration: Add an auto-extend interval to inventory reservations
+-- Touching a reservation while its holder is active (e.g. a shopper editing
+-- their cart) pushes its expiry out by this many seconds.
+
+ALTER TABLE inventory_reservations
+    ADD COLUMN IF NOT EXISTS auto_extend_seconds BIGINT
+        CHECK (auto_extend_seconds > 0);
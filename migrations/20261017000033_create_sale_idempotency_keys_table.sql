@@ -0,0 +1,19 @@
+-- Migration: Create sale_idempotency_keys table
+-- Terminals retrying a sale completion or payment send the same key; a key
+-- that already produced the request returns the original sale instead of
+-- completing or charging it again. Keys are scoped per terminal and kept
+-- for 24 hours.
+
+CREATE TABLE IF NOT EXISTS sale_idempotency_keys (
+    terminal_id UUID NOT NULL REFERENCES terminals(id) ON DELETE CASCADE,
+    idempotency_key VARCHAR(255) NOT NULL,
+    operation VARCHAR(20) NOT NULL CHECK (operation IN ('complete', 'payment')),
+    sale_id UUID NOT NULL REFERENCES sales(id) ON DELETE CASCADE,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    expires_at TIMESTAMPTZ NOT NULL,
+    PRIMARY KEY (terminal_id, idempotency_key)
+);
+
+-- Purging expired keys
+CREATE INDEX IF NOT EXISTS idx_sale_idempotency_keys_expires_at
+    ON sale_idempotency_keys(expires_at);
@@ -0,0 +1,12 @@
+-- Migration: Create customer_credit_balances table
+-- Store credit a customer holds from credit notes refunded to store credit,
+-- spendable as a store_credit payment. Rows are created on the first credit;
+-- spending only goes through while the balance covers it.
+
+CREATE TABLE IF NOT EXISTS customer_credit_balances (
+    customer_id UUID PRIMARY KEY REFERENCES customers(id) ON DELETE CASCADE,
+    balance DECIMAL(15,4) NOT NULL DEFAULT 0,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    CONSTRAINT customer_credit_balances_balance_check CHECK (balance >= 0)
+);
@@ -0,0 +1,46 @@
+-- Migration: Create price_lists and price_list_entries tables
+-- A price list sets the unit price of products (or single variants) for the
+-- customer types assigned to it at a store. Each customer type has at most
+-- one active list per store; the store's default (retail) list prices
+-- walk-in and guest sales and customer types without a list of their own.
+-- Products without an entry fall through to price tiers and base prices.
+--
+-- Sale lines record the list their unit price was resolved from.
+
+CREATE TABLE IF NOT EXISTS price_lists (
+    id UUID PRIMARY KEY,
+    store_id UUID NOT NULL REFERENCES stores(id),
+    name VARCHAR(100) NOT NULL,
+    customer_types TEXT[] NOT NULL DEFAULT '{}',
+    is_default BOOLEAN NOT NULL DEFAULT FALSE,
+    is_active BOOLEAN NOT NULL DEFAULT TRUE,
+    created_by_id UUID NOT NULL REFERENCES users(id),
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+
+    CONSTRAINT price_lists_assigned CHECK (is_default OR cardinality(customer_types) > 0)
+);
+
+CREATE INDEX IF NOT EXISTS idx_price_lists_store ON price_lists(store_id);
+CREATE UNIQUE INDEX IF NOT EXISTS uq_price_lists_default
+    ON price_lists(store_id) WHERE is_default AND is_active;
+
+CREATE TABLE IF NOT EXISTS price_list_entries (
+    id UUID PRIMARY KEY,
+    price_list_id UUID NOT NULL REFERENCES price_lists(id) ON DELETE CASCADE,
+    product_id UUID NOT NULL REFERENCES products(id) ON DELETE CASCADE,
+    variant_id UUID REFERENCES product_variants(id) ON DELETE CASCADE,
+    price DECIMAL(12,2) NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+
+    CONSTRAINT price_list_entries_price_non_negative CHECK (price >= 0)
+);
+
+CREATE UNIQUE INDEX IF NOT EXISTS uq_price_list_entries_product
+    ON price_list_entries(price_list_id, product_id) WHERE variant_id IS NULL;
+CREATE UNIQUE INDEX IF NOT EXISTS uq_price_list_entries_variant
+    ON price_list_entries(price_list_id, variant_id) WHERE variant_id IS NOT NULL;
+
+ALTER TABLE sale_items
+    ADD COLUMN IF NOT EXISTS price_list_id UUID REFERENCES price_lists(id) ON DELETE SET NULL;
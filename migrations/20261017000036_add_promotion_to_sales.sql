@@ -0,0 +1,10 @@
+-- Migration: Record promotions applied to sales
+-- Evaluated promotions are applied as fixed discounts on sale lines or on
+-- the sale total. The granting promotion is recorded next to the discount so
+-- receipts show what was applied; discounts given by hand leave it NULL.
+
+ALTER TABLE sales
+    ADD COLUMN IF NOT EXISTS promotion_id UUID REFERENCES promotions(id) ON DELETE SET NULL;
+
+ALTER TABLE sale_items
+    ADD COLUMN IF NOT EXISTS promotion_id UUID REFERENCES promotions(id) ON DELETE SET NULL;
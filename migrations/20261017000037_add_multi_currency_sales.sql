@@ -0,0 +1,49 @@
+-- Migration: Multi-currency sales with captured exchange rates
+-- Stores record the rate they take each foreign currency at, in units of
+-- the base currency per unit of the foreign one. Rates are never edited: a
+-- new row takes effect from its effective_at, so the history stays
+-- available for reprinting old sales.
+--
+-- Sales keep their totals in the base currency (sales.currency) and record
+-- the currency they were quoted in with the rate captured at completion.
+-- Payments are recorded in the tender currency with the rate converting
+-- them to the base currency.
+
+CREATE TABLE IF NOT EXISTS exchange_rates (
+    id UUID PRIMARY KEY,
+    store_id UUID NOT NULL REFERENCES stores(id),
+    currency VARCHAR(3) NOT NULL,
+    rate DECIMAL(18,8) NOT NULL,
+    effective_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    created_by_id UUID NOT NULL REFERENCES users(id),
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+
+    CONSTRAINT exchange_rates_rate_positive CHECK (rate > 0)
+);
+
+CREATE INDEX IF NOT EXISTS idx_exchange_rates_store_currency
+    ON exchange_rates(store_id, currency, effective_at DESC);
+
+ALTER TABLE sales
+    ADD COLUMN IF NOT EXISTS transaction_currency VARCHAR(3),
+    ADD COLUMN IF NOT EXISTS exchange_rate DECIMAL(18,8) NOT NULL DEFAULT 1;
+
+UPDATE sales SET transaction_currency = currency WHERE transaction_currency IS NULL;
+
+ALTER TABLE sales ALTER COLUMN transaction_currency SET NOT NULL;
+
+ALTER TABLE payments
+    ADD COLUMN IF NOT EXISTS exchange_rate DECIMAL(18,8) NOT NULL DEFAULT 1;
+
+-- Permission backup; seed/src/data.rs is the source of truth.
+INSERT INTO permissions (id, code, description) VALUES
+    (gen_random_uuid(), 'sales:manage_exchange_rates', 'Record currency exchange rates')
+ON CONFLICT (code) DO NOTHING;
+
+INSERT INTO role_permissions (role_id, permission_id)
+SELECT r.id, p.id
+FROM roles r
+CROSS JOIN permissions p
+WHERE r.name IN ('super_admin', 'store_admin', 'store_manager')
+  AND p.code = 'sales:manage_exchange_rates'
+ON CONFLICT DO NOTHING;
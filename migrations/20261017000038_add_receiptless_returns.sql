@@ -0,0 +1,33 @@
+-- Migration: Returns without a receipt
+-- A manager can approve a return when the customer has no receipt. Such
+-- credit notes reference no sale and name the customer whose store credit
+-- is refunded; their lines are priced from the catalog and reference no
+-- sale line.
+
+ALTER TABLE credit_notes ALTER COLUMN original_sale_id DROP NOT NULL;
+
+ALTER TABLE credit_notes
+    ADD COLUMN IF NOT EXISTS customer_id UUID REFERENCES customers(id);
+
+ALTER TABLE credit_notes
+    ADD CONSTRAINT credit_notes_sale_or_customer
+    CHECK (original_sale_id IS NOT NULL OR customer_id IS NOT NULL);
+
+CREATE INDEX IF NOT EXISTS idx_credit_notes_receiptless
+    ON credit_notes(store_id, created_at)
+    WHERE original_sale_id IS NULL;
+
+ALTER TABLE credit_note_items ALTER COLUMN original_sale_item_id DROP NOT NULL;
+
+-- Permission backup; seed/src/data.rs is the source of truth.
+INSERT INTO permissions (id, code, description) VALUES
+    (gen_random_uuid(), 'sales:return_without_receipt', 'Approve returns without a receipt')
+ON CONFLICT (code) DO NOTHING;
+
+INSERT INTO role_permissions (role_id, permission_id)
+SELECT r.id, p.id
+FROM roles r
+CROSS JOIN permissions p
+WHERE r.name IN ('super_admin', 'store_admin', 'store_manager')
+  AND p.code = 'sales:return_without_receipt'
+ON CONFLICT DO NOTHING;
@@ -0,0 +1,16 @@
+-- Migration: Cash-drawer reconciliation variance on shift close
+-- Closing a shift records the counted card and other-tender totals next to
+-- the counted cash, and the variance of the counted cash against the
+-- expected drawer. Counts off by more than the configured threshold
+-- (SHIFT_VARIANCE_THRESHOLD) carry a reason.
+
+ALTER TABLE cashier_shifts
+    ADD COLUMN IF NOT EXISTS card_count DECIMAL(15,4),
+    ADD COLUMN IF NOT EXISTS other_count DECIMAL(15,4),
+    ADD COLUMN IF NOT EXISTS variance DECIMAL(15,4),
+    ADD COLUMN IF NOT EXISTS variance_reason TEXT;
+
+-- Shifts closed before this migration
+UPDATE cashier_shifts
+SET variance = closing_balance - expected_balance
+WHERE closing_balance IS NOT NULL AND variance IS NULL;
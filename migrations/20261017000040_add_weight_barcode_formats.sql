@@ -0,0 +1,41 @@
+-- Migration: Weight-embedded barcodes for deli/produce scales
+-- Scales print EAN-13 labels carrying the item code and the weight or price
+-- of the weighed item. Each store registers the layouts its scales use:
+-- the prefix that marks a scale label, where the embedded value sits and
+-- what it is divided by. The weighed product carries the label's item
+-- barcode, with the embedded value zeroed and the check digit recomputed.
+
+CREATE TABLE IF NOT EXISTS weight_barcode_formats (
+    id UUID PRIMARY KEY,
+    store_id UUID NOT NULL REFERENCES stores(id),
+    prefix VARCHAR(3) NOT NULL,
+    value_start SMALLINT NOT NULL,
+    value_length SMALLINT NOT NULL,
+    value_kind VARCHAR(10) NOT NULL,
+    divisor INTEGER NOT NULL,
+    created_by_id UUID NOT NULL REFERENCES users(id),
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+
+    CONSTRAINT weight_barcode_formats_store_prefix_unique UNIQUE (store_id, prefix),
+    CONSTRAINT weight_barcode_formats_prefix_digits CHECK (prefix ~ '^[0-9]{1,3}$'),
+    CONSTRAINT weight_barcode_formats_value_layout CHECK (
+        value_start > char_length(prefix)
+        AND value_length BETWEEN 1 AND 6
+        AND value_start + value_length <= 12
+    ),
+    CONSTRAINT weight_barcode_formats_value_kind CHECK (value_kind IN ('weight', 'price')),
+    CONSTRAINT weight_barcode_formats_divisor_positive CHECK (divisor > 0)
+);
+
+-- Permission backup; seed/src/data.rs is the source of truth.
+INSERT INTO permissions (id, code, description) VALUES
+    (gen_random_uuid(), 'sales:manage_barcode_formats', 'Configure scale-printed barcode formats')
+ON CONFLICT (code) DO NOTHING;
+
+INSERT INTO role_permissions (role_id, permission_id)
+SELECT r.id, p.id
+FROM roles r
+CROSS JOIN permissions p
+WHERE r.name IN ('super_admin', 'store_admin', 'store_manager')
+  AND p.code = 'sales:manage_barcode_formats'
+ON CONFLICT DO NOTHING;
@@ -0,0 +1,18 @@
+-- Migration: Record short transfer receipts
+-- Transfers received short of what was shipped end in
+-- 'received_with_discrepancy', and the shortfall is written off at the
+-- source store with an 'in_transit_loss' adjustment.
+
+ALTER TABLE stock_transfers ALTER COLUMN status TYPE VARCHAR(30);
+
+ALTER TABLE stock_transfers DROP CONSTRAINT IF EXISTS stock_transfers_status_check;
+ALTER TABLE stock_transfers ADD CONSTRAINT stock_transfers_status_check CHECK (
+    status IN ('draft', 'pending_approval', 'approved', 'pending', 'in_transit', 'completed',
+               'received_with_discrepancy', 'cancelled')
+);
+
+ALTER TABLE stock_adjustments DROP CONSTRAINT IF EXISTS stock_adjustments_reason_check;
+ALTER TABLE stock_adjustments ADD CONSTRAINT stock_adjustments_reason_check CHECK (
+    adjustment_reason IN ('damage', 'theft', 'loss', 'found', 'correction', 'expiration',
+                          'in_transit_loss')
+);
@@ -0,0 +1,9 @@
+-- Migration: Add backorder movement type
+-- Sales that take stock below zero, in stores allowing negative stock, are
+-- recorded as 'backorder' movements instead of 'out'.
+
+ALTER TABLE inventory_movements DROP CONSTRAINT IF EXISTS inventory_movements_type_check;
+ALTER TABLE inventory_movements ADD CONSTRAINT inventory_movements_type_check CHECK (
+    movement_type IN ('in', 'out', 'adjustment', 'transfer_out', 'transfer_in', 'reservation',
+                      'release', 'backorder')
+);
@@ -0,0 +1,6 @@
+-- Migration: Add recipe yield unit
+-- A batch yields yield_quantity of yield_unit (e.g. 2 liters of sauce);
+-- existing recipes yield counted units.
+
+ALTER TABLE product_recipes
+    ADD COLUMN IF NOT EXISTS yield_unit VARCHAR(20) NOT NULL DEFAULT 'unit';
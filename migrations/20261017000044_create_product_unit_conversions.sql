@@ -0,0 +1,13 @@
+-- Migration: Product unit conversions
+-- Per-product conversion factors for packaging that varies between products,
+-- e.g. one case of a product holds 24 units. One from_unit equals factor
+-- to_unit; conversions are read both ways, so each pair is stored once.
+
+CREATE TABLE IF NOT EXISTS product_unit_conversions (
+    product_id UUID NOT NULL REFERENCES products(id) ON DELETE CASCADE,
+    from_unit VARCHAR(20) NOT NULL,
+    to_unit VARCHAR(20) NOT NULL,
+    factor NUMERIC(20, 6) NOT NULL CHECK (factor > 0),
+    PRIMARY KEY (product_id, from_unit, to_unit),
+    CHECK (from_unit <> to_unit)
+);
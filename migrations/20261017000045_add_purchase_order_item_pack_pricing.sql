@@ -0,0 +1,13 @@
+-- Migration: Purchase order item pack pricing
+-- Lines quoted per vendor pack keep the pack size and price for printing;
+-- their quantities count packs and unit_cost holds the derived cost of one
+-- stocking unit.
+
+ALTER TABLE purchase_order_items
+    ADD COLUMN IF NOT EXISTS pack_size DECIMAL(18, 4),
+    ADD COLUMN IF NOT EXISTS pack_price DECIMAL(18, 4);
+
+ALTER TABLE purchase_order_items
+    ADD CONSTRAINT po_items_pack_size_positive CHECK (pack_size IS NULL OR pack_size > 0),
+    ADD CONSTRAINT po_items_pack_price_non_negative CHECK (pack_price IS NULL OR pack_price >= 0),
+    ADD CONSTRAINT po_items_pack_pricing_complete CHECK ((pack_size IS NULL) = (pack_price IS NULL));
@@ -0,0 +1,26 @@
+-- Migration: Purchase order approval tiers
+-- Orders above the configured dual approval threshold need two distinct
+-- approvers; the first one is recorded here while the order stays submitted.
+-- Spend limits per tier are configured on the gateway and keyed by the
+-- permissions seeded below.
+
+ALTER TABLE purchase_orders
+    ADD COLUMN IF NOT EXISTS first_approved_by_id UUID REFERENCES users(id),
+    ADD COLUMN IF NOT EXISTS first_approved_at TIMESTAMPTZ;
+
+-- Approval tiers above the base purchase_orders:approve permission
+INSERT INTO permissions (id, code, description) VALUES
+    (gen_random_uuid(), 'purchase_orders:approve_tier2', 'Approve purchase orders up to the tier 2 spend limit'),
+    (gen_random_uuid(), 'purchase_orders:approve_tier3', 'Approve purchase orders up to the tier 3 spend limit')
+ON CONFLICT (code) DO NOTHING;
+
+INSERT INTO role_permissions (role_id, permission_id)
+SELECT r.id, p.id
+FROM roles r
+CROSS JOIN permissions p
+WHERE ((r.name IN ('super_admin', 'store_admin') AND p.code = 'purchase_orders:approve_tier2')
+    OR (r.name = 'super_admin' AND p.code = 'purchase_orders:approve_tier3'))
+  AND NOT EXISTS (
+    SELECT 1 FROM role_permissions rp
+    WHERE rp.role_id = r.id AND rp.permission_id = p.id
+  );
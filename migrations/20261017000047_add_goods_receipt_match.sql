@@ -0,0 +1,30 @@
+-- Migration: Goods receipt match against the purchase order
+-- Receipts confirmed under a match policy record the outcome ('matched', or
+-- 'overridden' when a user accepted quantities or costs outside tolerance)
+-- and, per line, the quantity received past the order and the unit cost
+-- difference. Receipts confirmed without a policy leave them NULL.
+
+ALTER TABLE goods_receipts
+    ADD COLUMN IF NOT EXISTS match_status VARCHAR(20)
+        CHECK (match_status IN ('matched', 'overridden')),
+    ADD COLUMN IF NOT EXISTS match_overridden_by_id UUID REFERENCES users(id);
+
+ALTER TABLE goods_receipt_items
+    ADD COLUMN IF NOT EXISTS quantity_variance DECIMAL(18, 4),
+    ADD COLUMN IF NOT EXISTS cost_variance DECIMAL(18, 4);
+
+-- Permission to confirm receipts outside the match tolerance
+INSERT INTO permissions (id, code, description) VALUES
+    (gen_random_uuid(), 'goods_receipts:override_match', 'Confirm goods receipts that do not match their purchase order')
+ON CONFLICT (code) DO NOTHING;
+
+INSERT INTO role_permissions (role_id, permission_id)
+SELECT r.id, p.id
+FROM roles r
+CROSS JOIN permissions p
+WHERE r.name IN ('super_admin', 'store_admin')
+  AND p.code = 'goods_receipts:override_match'
+  AND NOT EXISTS (
+    SELECT 1 FROM role_permissions rp
+    WHERE rp.role_id = r.id AND rp.permission_id = p.id
+  );
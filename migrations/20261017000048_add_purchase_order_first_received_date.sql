@@ -0,0 +1,24 @@
+-- Migration: Purchase order lead-time tracking
+-- first_received_date is the receipt date of the first goods receipt
+-- confirmed against the order; together with expected_delivery_date and
+-- received_date it gives lead time and lateness per order.
+
+ALTER TABLE purchase_orders
+    ADD COLUMN IF NOT EXISTS first_received_date DATE;
+
+-- Backfill from receipts already confirmed
+UPDATE purchase_orders po
+SET first_received_date = gr.first_receipt_date
+FROM (
+    SELECT purchase_order_id, MIN(receipt_date) AS first_receipt_date
+    FROM goods_receipts
+    WHERE status = 'confirmed'
+    GROUP BY purchase_order_id
+) gr
+WHERE gr.purchase_order_id = po.id
+  AND po.first_received_date IS NULL;
+
+-- Overdue lookups: expected date passed and not fully received
+CREATE INDEX IF NOT EXISTS idx_purchase_orders_overdue
+    ON purchase_orders (expected_delivery_date)
+    WHERE status IN ('approved', 'partially_received');
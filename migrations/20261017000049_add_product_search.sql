@@ -0,0 +1,23 @@
+-- Migration: Product catalog search
+-- search_vector indexes name, SKU and barcode (weight A), brand (B) and
+-- description (C) for full-text search. The 'simple' configuration skips
+-- stemming so mixed Spanish/English names and codes match as typed.
+-- Trigram indexes back typo-tolerant name matches and partial SKU matches.
+
+CREATE EXTENSION IF NOT EXISTS pg_trgm;
+
+ALTER TABLE products
+    ADD COLUMN IF NOT EXISTS search_vector tsvector
+        GENERATED ALWAYS AS (
+            setweight(to_tsvector('simple'::regconfig, coalesce(name, '')), 'A') ||
+            setweight(to_tsvector('simple'::regconfig, sku || ' ' || coalesce(barcode, '')), 'A') ||
+            setweight(to_tsvector('simple'::regconfig, coalesce(brand, '')), 'B') ||
+            setweight(to_tsvector('simple'::regconfig, coalesce(description, '')), 'C')
+        ) STORED;
+
+CREATE INDEX IF NOT EXISTS idx_products_search_vector
+    ON products USING gin (search_vector);
+CREATE INDEX IF NOT EXISTS idx_products_name_trgm
+    ON products USING gin (name gin_trgm_ops);
+CREATE INDEX IF NOT EXISTS idx_products_sku_trgm
+    ON products USING gin (sku gin_trgm_ops);
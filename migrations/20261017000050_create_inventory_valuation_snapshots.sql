@@ -0,0 +1,50 @@
+-- Migration: Period-end inventory valuation snapshots
+-- One immutable snapshot per store and period end, holding each stock
+-- record's quantity and value when the period was closed. Lines copy the
+-- product names and SKUs and carry no foreign keys to the catalog, so later
+-- catalog changes or deletions leave the snapshot untouched.
+-- The latest period end of a store closes its stock through that date:
+-- goods receipts dated on or before it need inventory:post_to_closed_period.
+
+CREATE TABLE IF NOT EXISTS inventory_valuation_snapshots (
+    id UUID PRIMARY KEY,
+    store_id UUID NOT NULL REFERENCES stores(id),
+    period_end DATE NOT NULL,
+    currency VARCHAR(3) NOT NULL,
+    total_value NUMERIC(20, 4) NOT NULL,
+    created_by_id UUID NOT NULL REFERENCES users(id),
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    CONSTRAINT inventory_valuation_snapshots_store_period_unique UNIQUE (store_id, period_end)
+);
+
+CREATE TABLE IF NOT EXISTS inventory_valuation_snapshot_lines (
+    snapshot_id UUID NOT NULL REFERENCES inventory_valuation_snapshots(id) ON DELETE CASCADE,
+    stock_id UUID NOT NULL,
+    product_id UUID,
+    variant_id UUID,
+    product_name VARCHAR(255),
+    variant_name VARCHAR(255),
+    sku VARCHAR(100),
+    quantity NUMERIC(20, 4) NOT NULL,
+    costing_method VARCHAR(20) NOT NULL,
+    unit_cost NUMERIC(20, 4) NOT NULL,
+    total_value NUMERIC(20, 4) NOT NULL,
+    PRIMARY KEY (snapshot_id, stock_id)
+);
+
+-- Permissions to close a period and to post stock into a closed one
+INSERT INTO permissions (id, code, description) VALUES
+    (gen_random_uuid(), 'inventory:close_period', 'Record period-end inventory valuation snapshots'),
+    (gen_random_uuid(), 'inventory:post_to_closed_period', 'Post stock dated in a closed inventory period')
+ON CONFLICT (code) DO NOTHING;
+
+INSERT INTO role_permissions (role_id, permission_id)
+SELECT r.id, p.id
+FROM roles r
+CROSS JOIN permissions p
+WHERE r.name IN ('super_admin', 'store_admin')
+  AND p.code IN ('inventory:close_period', 'inventory:post_to_closed_period')
+  AND NOT EXISTS (
+    SELECT 1 FROM role_permissions rp
+    WHERE rp.role_id = r.id AND rp.permission_id = p.id
+  );
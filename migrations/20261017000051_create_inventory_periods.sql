@@ -0,0 +1,54 @@
+-- Migration: Inventory periods with open/closed status per store
+-- A closed period rejects stock updates, adjustment applies, goods receipts
+-- and sales whose document date falls in it, unless the user holds
+-- inventory:reopen_period. Periods of a store never overlap; the use case
+-- checks ranges, the unique key guards concurrent closes of the same range.
+-- Closing is no longer tied to valuation snapshots, so the override
+-- permission inventory:post_to_closed_period is replaced.
+
+CREATE TABLE IF NOT EXISTS inventory_periods (
+    id UUID PRIMARY KEY,
+    store_id UUID NOT NULL REFERENCES stores(id),
+    period_start DATE NOT NULL,
+    period_end DATE NOT NULL,
+    status VARCHAR(20) NOT NULL DEFAULT 'open',
+    closed_by_id UUID REFERENCES users(id),
+    closed_at TIMESTAMPTZ,
+    reopened_by_id UUID REFERENCES users(id),
+    reopened_at TIMESTAMPTZ,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    CONSTRAINT inventory_periods_range_check CHECK (period_start <= period_end),
+    CONSTRAINT inventory_periods_status_check CHECK (status IN ('open', 'closed')),
+    CONSTRAINT inventory_periods_store_start_unique UNIQUE (store_id, period_start)
+);
+
+CREATE INDEX IF NOT EXISTS idx_inventory_periods_store_range
+    ON inventory_periods (store_id, period_start, period_end);
+
+-- Permission to reopen closed periods and post into them
+INSERT INTO permissions (id, code, description) VALUES
+    (gen_random_uuid(), 'inventory:reopen_period', 'Reopen closed inventory periods and post into them')
+ON CONFLICT (code) DO NOTHING;
+
+UPDATE permissions
+SET description = 'Close inventory periods and record valuation snapshots'
+WHERE code = 'inventory:close_period';
+
+INSERT INTO role_permissions (role_id, permission_id)
+SELECT r.id, p.id
+FROM roles r
+CROSS JOIN permissions p
+WHERE r.name IN ('super_admin', 'store_admin')
+  AND p.code = 'inventory:reopen_period'
+  AND NOT EXISTS (
+    SELECT 1 FROM role_permissions rp
+    WHERE rp.role_id = r.id AND rp.permission_id = p.id
+  );
+
+DELETE FROM role_permissions
+WHERE permission_id IN (
+    SELECT id FROM permissions WHERE code = 'inventory:post_to_closed_period'
+);
+
+DELETE FROM permissions WHERE code = 'inventory:post_to_closed_period';
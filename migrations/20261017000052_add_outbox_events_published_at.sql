@@ -0,0 +1,14 @@
+-- Migration: Track external publication of outbox events
+-- The drain worker publishes outbox events to systems outside the API in
+-- occurrence order and stamps published_at once they were accepted. This is
+-- tracked apart from the in-process dispatch status.
+
+ALTER TABLE outbox_events ADD COLUMN IF NOT EXISTS published_at TIMESTAMPTZ;
+
+-- Events written before the change feed existed are not replayed to it
+UPDATE outbox_events SET published_at = created_at WHERE published_at IS NULL;
+
+-- Hot path: the drain worker fetches the oldest unpublished events
+CREATE INDEX IF NOT EXISTS idx_outbox_events_unpublished
+    ON outbox_events (occurred_at, id)
+    WHERE published_at IS NULL;
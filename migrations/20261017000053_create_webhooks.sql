@@ -0,0 +1,67 @@
+-- Migration: Per-store webhooks for outbox events
+-- A store registers a URL, a signing secret and the event types it wants.
+-- The outbox drain turns each matching event into one webhook_deliveries row
+-- per webhook; the dispatcher POSTs it signed (HMAC-SHA256), retries with
+-- exponential backoff and moves it to dead_letter after the last attempt.
+
+CREATE TABLE IF NOT EXISTS webhooks (
+    id UUID PRIMARY KEY,
+    store_id UUID NOT NULL REFERENCES stores(id),
+    url TEXT NOT NULL,
+    secret TEXT NOT NULL,
+    event_types TEXT[] NOT NULL,
+    is_active BOOLEAN NOT NULL DEFAULT TRUE,
+    created_by_id UUID NOT NULL REFERENCES users(id),
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    CONSTRAINT webhooks_event_types_check CHECK (cardinality(event_types) > 0)
+);
+
+CREATE INDEX IF NOT EXISTS idx_webhooks_store_active
+    ON webhooks (store_id)
+    WHERE is_active;
+
+CREATE TABLE IF NOT EXISTS webhook_deliveries (
+    id UUID PRIMARY KEY,
+    webhook_id UUID NOT NULL REFERENCES webhooks(id),
+    outbox_event_id UUID NOT NULL REFERENCES outbox_events(id),
+    event_type VARCHAR(128) NOT NULL,
+    payload JSONB NOT NULL,
+    status VARCHAR(20) NOT NULL DEFAULT 'pending',
+    attempts INTEGER NOT NULL DEFAULT 0,
+    next_attempt_at TIMESTAMPTZ NOT NULL,
+    last_response_status INTEGER,
+    last_error TEXT,
+    delivered_at TIMESTAMPTZ,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    CONSTRAINT webhook_deliveries_status_check
+        CHECK (status IN ('pending', 'delivered', 'dead_letter')),
+    -- Re-draining an outbox event never delivers it twice to one webhook
+    CONSTRAINT webhook_deliveries_webhook_event_unique UNIQUE (webhook_id, outbox_event_id)
+);
+
+-- Hot path: the dispatcher fetches pending deliveries that are due
+CREATE INDEX IF NOT EXISTS idx_webhook_deliveries_due
+    ON webhook_deliveries (next_attempt_at)
+    WHERE status = 'pending';
+
+CREATE INDEX IF NOT EXISTS idx_webhook_deliveries_dead_letter
+    ON webhook_deliveries (webhook_id, updated_at)
+    WHERE status = 'dead_letter';
+
+-- Permission to manage a store's webhooks and read their dead letters
+INSERT INTO permissions (id, code, description) VALUES
+    (gen_random_uuid(), 'webhooks:manage', 'Register and unregister event webhooks and review failed deliveries')
+ON CONFLICT (code) DO NOTHING;
+
+INSERT INTO role_permissions (role_id, permission_id)
+SELECT r.id, p.id
+FROM roles r
+CROSS JOIN permissions p
+WHERE r.name IN ('super_admin', 'store_admin')
+  AND p.code = 'webhooks:manage'
+  AND NOT EXISTS (
+    SELECT 1 FROM role_permissions rp
+    WHERE rp.role_id = r.id AND rp.permission_id = p.id
+  );
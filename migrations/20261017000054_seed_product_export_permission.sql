@@ -0,0 +1,20 @@
+-- Migration: Product catalog export permission
+-- Exporting the catalog (with costs and, optionally, stock) is a separate
+-- right from reading products, like sales:export. Each export is recorded in
+-- audit_log (action = 'exported').
+--
+-- IMPORTANT: this is a backup. The seed binary
+-- (seed/src/data.rs::PERMISSIONS + ROLE_PERMISSIONS) is the actual source
+-- of truth, since it runs after migrations.
+
+INSERT INTO permissions (id, code, description) VALUES
+    (gen_random_uuid(), 'products:export', 'Export the product catalog')
+ON CONFLICT (code) DO NOTHING;
+
+INSERT INTO role_permissions (role_id, permission_id)
+SELECT r.id, p.id
+FROM roles r
+CROSS JOIN permissions p
+WHERE r.name IN ('super_admin', 'store_admin', 'store_manager')
+  AND p.code = 'products:export'
+ON CONFLICT DO NOTHING;
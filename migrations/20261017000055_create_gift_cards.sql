@@ -0,0 +1,46 @@
+-- Migration: Gift cards
+-- Stored-value cards sold at the POS and accepted as a payment method.
+-- A card sold as a sale line stays 'pending' until that sale is completed;
+-- a card issued directly is 'active' at once. Redemptions lock the card's
+-- row (SELECT ... FOR UPDATE) while drawing the balance down, so concurrent
+-- tenders on the same card can't spend more than it holds.
+
+CREATE TABLE IF NOT EXISTS gift_cards (
+    id UUID PRIMARY KEY,
+    store_id UUID NOT NULL REFERENCES stores(id),
+    code VARCHAR(32) NOT NULL,
+    currency VARCHAR(3) NOT NULL,
+    initial_balance NUMERIC(20, 4) NOT NULL,
+    current_balance NUMERIC(20, 4) NOT NULL,
+    status VARCHAR(20) NOT NULL DEFAULT 'active',
+    sale_id UUID REFERENCES sales(id) ON DELETE SET NULL,
+    sale_item_id UUID REFERENCES sale_items(id) ON DELETE SET NULL,
+    issued_by_id UUID NOT NULL REFERENCES users(id),
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+
+    CONSTRAINT gift_cards_code_unique UNIQUE (code),
+    CONSTRAINT gift_cards_status_check
+        CHECK (status IN ('pending', 'active', 'depleted')),
+    CONSTRAINT gift_cards_initial_balance_check CHECK (initial_balance > 0),
+    CONSTRAINT gift_cards_balance_check
+        CHECK (current_balance >= 0 AND current_balance <= initial_balance)
+);
+
+-- Completing a sale activates the cards sold on it
+CREATE INDEX IF NOT EXISTS idx_gift_cards_sale
+    ON gift_cards (sale_id)
+    WHERE sale_id IS NOT NULL;
+
+INSERT INTO permissions (id, code, description) VALUES
+    (gen_random_uuid(), 'gift_cards:issue', 'Issue and sell gift cards'),
+    (gen_random_uuid(), 'gift_cards:read', 'Check gift card balances')
+ON CONFLICT (code) DO NOTHING;
+
+INSERT INTO role_permissions (role_id, permission_id)
+SELECT r.id, p.id
+FROM roles r
+CROSS JOIN permissions p
+WHERE r.name IN ('super_admin', 'store_admin', 'store_manager', 'cashier')
+  AND p.code IN ('gift_cards:issue', 'gift_cards:read')
+ON CONFLICT DO NOTHING;
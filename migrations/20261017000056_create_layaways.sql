@@ -0,0 +1,43 @@
+-- Migration: Layaway sales
+-- A POS sale can be put on layaway: the customer pays a deposit, the
+-- sale's stock is reserved, and the rest is paid in scheduled installments.
+-- The sale completes (and its stock is consumed) once it is paid in full;
+-- cancelling releases the reservations and may keep a restocking fee.
+
+CREATE TABLE IF NOT EXISTS layaways (
+    id UUID PRIMARY KEY,
+    sale_id UUID NOT NULL REFERENCES sales(id) ON DELETE CASCADE,
+    store_id UUID NOT NULL REFERENCES stores(id),
+    status VARCHAR(20) NOT NULL DEFAULT 'open',
+    installment_count INTEGER NOT NULL,
+    interval_days INTEGER NOT NULL,
+    restocking_fee NUMERIC(20, 4) NOT NULL DEFAULT 0,
+    refund_amount NUMERIC(20, 4) NOT NULL DEFAULT 0,
+    cancel_reason TEXT,
+    created_by_id UUID NOT NULL REFERENCES users(id),
+    activated_at TIMESTAMPTZ,
+    completed_at TIMESTAMPTZ,
+    cancelled_at TIMESTAMPTZ,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+
+    CONSTRAINT layaways_sale_unique UNIQUE (sale_id),
+    CONSTRAINT layaways_status_check
+        CHECK (status IN ('open', 'active', 'completed', 'cancelled')),
+    CONSTRAINT layaways_installment_count_check CHECK (installment_count BETWEEN 1 AND 52),
+    CONSTRAINT layaways_interval_days_check CHECK (interval_days BETWEEN 1 AND 90),
+    CONSTRAINT layaways_restocking_fee_check CHECK (restocking_fee >= 0)
+);
+
+CREATE TABLE IF NOT EXISTS layaway_installments (
+    layaway_id UUID NOT NULL REFERENCES layaways(id) ON DELETE CASCADE,
+    number INTEGER NOT NULL,
+    due_date DATE NOT NULL,
+    amount NUMERIC(20, 4) NOT NULL,
+    amount_paid NUMERIC(20, 4) NOT NULL DEFAULT 0,
+
+    PRIMARY KEY (layaway_id, number),
+    CONSTRAINT layaway_installments_amount_check CHECK (amount > 0),
+    CONSTRAINT layaway_installments_paid_check
+        CHECK (amount_paid >= 0 AND amount_paid <= amount)
+);
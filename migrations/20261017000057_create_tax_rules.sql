@@ -0,0 +1,42 @@
+-- Migration: Tax rules per product, category and store
+-- `tax_rules` sets the rate a store charges under a jurisdiction, either for
+-- one product, a whole category, or everything it sells. Product rules win
+-- over category rules, which win over the store-wide rule; lines no rule
+-- covers keep the rate they were rung up with.
+--
+-- Tax-inclusive rules back the tax out of the shelf price instead of adding
+-- it on top. Customers of the listed types are exempt from the rule's tax.
+--
+-- Sale lines record the rule, jurisdiction and pricing their tax came from,
+-- so the sale's tax breakdown survives later changes to the rules.
+
+CREATE TABLE IF NOT EXISTS tax_rules (
+    id UUID PRIMARY KEY,
+    store_id UUID NOT NULL REFERENCES stores(id),
+    jurisdiction VARCHAR(50) NOT NULL,
+    product_id UUID REFERENCES products(id) ON DELETE CASCADE,
+    category_id UUID REFERENCES product_categories(id) ON DELETE CASCADE,
+    rate DECIMAL(5,2) NOT NULL,
+    tax_inclusive BOOLEAN NOT NULL DEFAULT FALSE,
+    exempt_customer_types TEXT[] NOT NULL DEFAULT '{}',
+    is_active BOOLEAN NOT NULL DEFAULT TRUE,
+    created_by_id UUID NOT NULL REFERENCES users(id),
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+
+    CONSTRAINT tax_rules_one_target CHECK (product_id IS NULL OR category_id IS NULL),
+    CONSTRAINT tax_rules_rate_range CHECK (rate >= 0 AND rate <= 100)
+);
+
+CREATE UNIQUE INDEX IF NOT EXISTS uq_tax_rules_product
+    ON tax_rules(store_id, product_id) WHERE product_id IS NOT NULL;
+CREATE UNIQUE INDEX IF NOT EXISTS uq_tax_rules_category
+    ON tax_rules(store_id, category_id) WHERE category_id IS NOT NULL;
+CREATE UNIQUE INDEX IF NOT EXISTS uq_tax_rules_store
+    ON tax_rules(store_id) WHERE product_id IS NULL AND category_id IS NULL;
+
+ALTER TABLE sale_items
+    ADD COLUMN IF NOT EXISTS tax_inclusive BOOLEAN NOT NULL DEFAULT FALSE,
+    ADD COLUMN IF NOT EXISTS tax_exempt BOOLEAN NOT NULL DEFAULT FALSE,
+    ADD COLUMN IF NOT EXISTS tax_rule_id UUID REFERENCES tax_rules(id) ON DELETE SET NULL,
+    ADD COLUMN IF NOT EXISTS tax_jurisdiction VARCHAR(50);
@@ -0,0 +1,12 @@
+-- Migration: CAI invoice numbering for completed sales
+-- A POS sale draws its invoice number from the active CAI range of its
+-- terminal when it is completed. `cai_number` records the CAI that
+-- authorized the number, which stays with the sale after the terminal is
+-- given a new range.
+
+ALTER TABLE sales
+    ADD COLUMN IF NOT EXISTS cai_number VARCHAR(50);
+
+-- A number is never handed out twice under the same CAI
+CREATE UNIQUE INDEX IF NOT EXISTS uq_sales_cai_invoice_number
+    ON sales(cai_number, invoice_number) WHERE cai_number IS NOT NULL;
@@ -0,0 +1,23 @@
+-- Migration: Printable credit-note documents
+-- An approved or applied credit note gets a document the first time it is
+-- printed. The document is stored as issued so reprints match the original,
+-- and is numbered from its store's credit-note sequence.
+--
+-- `credit_note_document_sequences` holds the last number handed out per
+-- store. Drawing a number locks the store's row until the document is
+-- saved, so numbers are unique and gapless.
+
+CREATE TABLE IF NOT EXISTS credit_note_document_sequences (
+    store_id UUID PRIMARY KEY REFERENCES stores(id),
+    last_number BIGINT NOT NULL
+);
+
+CREATE TABLE IF NOT EXISTS credit_note_documents (
+    credit_note_id UUID PRIMARY KEY REFERENCES credit_notes(id),
+    store_id UUID NOT NULL REFERENCES stores(id),
+    document_number VARCHAR(20) NOT NULL,
+    document JSONB NOT NULL,
+    issued_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+
+    CONSTRAINT uq_credit_note_documents_number UNIQUE (store_id, document_number)
+);
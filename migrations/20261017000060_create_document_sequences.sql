@@ -0,0 +1,38 @@
+-- Migration: Create document_sequences
+-- One gapless counter per document type, scope and year. scope_id is the
+-- store the sequence belongs to, or the nil UUID for types numbered
+-- globally (stock transfers). Numbers are drawn by upserting the row inside
+-- the transaction that inserts the document, so the row lock serializes
+-- concurrent drawers and a rollback gives the number back.
+
+CREATE TABLE IF NOT EXISTS document_sequences (
+    document_type VARCHAR(30) NOT NULL,
+    scope_id UUID NOT NULL,
+    year INTEGER NOT NULL,
+    last_number BIGINT NOT NULL CHECK (last_number >= 0),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+
+    PRIMARY KEY (document_type, scope_id, year)
+);
+
+-- Purchase orders and goods receipts already used PREFIX-YYYY-NNNNN per
+-- store; continue after the highest number issued so far.
+INSERT INTO document_sequences (document_type, scope_id, year, last_number)
+SELECT 'purchase_order',
+       store_id,
+       SUBSTRING(order_number FROM '^PO-(\d{4})-\d+$')::INTEGER,
+       MAX(SUBSTRING(order_number FROM '^PO-\d{4}-(\d+)$')::BIGINT)
+FROM purchase_orders
+WHERE order_number ~ '^PO-\d{4}-\d+$'
+GROUP BY store_id, SUBSTRING(order_number FROM '^PO-(\d{4})-\d+$')
+ON CONFLICT DO NOTHING;
+
+INSERT INTO document_sequences (document_type, scope_id, year, last_number)
+SELECT 'goods_receipt',
+       store_id,
+       SUBSTRING(receipt_number FROM '^GR-(\d{4})-\d+$')::INTEGER,
+       MAX(SUBSTRING(receipt_number FROM '^GR-\d{4}-(\d+)$')::BIGINT)
+FROM goods_receipts
+WHERE receipt_number ~ '^GR-\d{4}-\d+$'
+GROUP BY store_id, SUBSTRING(receipt_number FROM '^GR-(\d{4})-\d+$')
+ON CONFLICT DO NOTHING;
@@ -0,0 +1,11 @@
+-- Field-level change capture for audit entries.
+--
+-- Updates record the fields that differ between the old and new state as a
+-- JSON array of {field, old_value, new_value}; sensitive fields are stored
+-- redacted. Entries written before this migration have no changes.
+
+ALTER TABLE audit_log
+    ADD COLUMN IF NOT EXISTS changes JSONB NOT NULL DEFAULT '[]'::jsonb;
+
+ALTER TABLE audit_log_archive
+    ADD COLUMN IF NOT EXISTS changes JSONB NOT NULL DEFAULT '[]'::jsonb;
@@ -0,0 +1,23 @@
+-- Migration: Create refresh_tokens
+-- Server-side record of issued refresh tokens. Each login starts a family;
+-- each refresh marks the presented token rotated (pointing at its
+-- replacement) and issues the next token of the family. Presenting a
+-- rotated token again is treated as theft: the whole family is revoked.
+--
+-- Refresh tokens issued before this migration carry no token id and are
+-- rejected, so users sign in again once.
+
+CREATE TABLE IF NOT EXISTS refresh_tokens (
+    id UUID PRIMARY KEY,
+    user_id UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    family_id UUID NOT NULL,
+    expires_at TIMESTAMPTZ NOT NULL,
+    rotated_at TIMESTAMPTZ,
+    replaced_by UUID,
+    revoked_at TIMESTAMPTZ,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX IF NOT EXISTS idx_refresh_tokens_family ON refresh_tokens (family_id);
+CREATE INDEX IF NOT EXISTS idx_refresh_tokens_user_live
+    ON refresh_tokens (user_id) WHERE revoked_at IS NULL;
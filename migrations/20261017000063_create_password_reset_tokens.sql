@@ -0,0 +1,17 @@
+-- Migration: Create password_reset_tokens
+-- Single-use, time-limited tokens emailed to users who forgot their
+-- password. Only the SHA-256 hash of the token is stored. used_at is also
+-- set when a newer reset is requested or the password is reset, so at most
+-- one link per user works at a time.
+
+CREATE TABLE IF NOT EXISTS password_reset_tokens (
+    id UUID PRIMARY KEY,
+    user_id UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    token_hash VARCHAR(64) NOT NULL UNIQUE,
+    expires_at TIMESTAMPTZ NOT NULL,
+    used_at TIMESTAMPTZ,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX IF NOT EXISTS idx_password_reset_tokens_user_unused
+    ON password_reset_tokens (user_id) WHERE used_at IS NULL;
@@ -0,0 +1,12 @@
+-- Migration: Scope quick sale idempotency keys per terminal
+-- Quick sales record their keys in sale_idempotency_keys like completions
+-- and payments, so keys are per terminal and expire after 24 hours.
+-- Payments no longer carry a key; the global unique index that made the
+-- same key on two terminals collide is dropped.
+
+DROP INDEX IF EXISTS idx_payments_idempotency_key;
+
+ALTER TABLE sale_idempotency_keys DROP CONSTRAINT IF EXISTS sale_idempotency_keys_operation_check;
+ALTER TABLE sale_idempotency_keys ADD CONSTRAINT sale_idempotency_keys_operation_check CHECK (
+    operation IN ('complete', 'payment', 'quick_sale')
+);
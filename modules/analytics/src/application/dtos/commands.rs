@@ -1,8 +1,11 @@
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use serde_json::Value as JsonValue;
 use uuid::Uuid;
 
-use crate::domain::value_objects::{KpiKey, ReportKind, TimeWindow, WidgetKind};
+use crate::domain::value_objects::{
+    KpiKey, RankingDirection, RankingMetric, ReportKind, TimeWindow, WidgetKind,
+};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CreateDashboardCommand {
@@ -35,3 +38,32 @@ pub struct RunReportCommand {
 fn default_limit() -> i64 {
     100
 }
+
+/// Query for a page of the product sales ranking.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProductSalesRankingQuery {
+    pub from: DateTime<Utc>,
+    pub to: DateTime<Utc>,
+    pub store_id: Option<Uuid>,
+    pub category_id: Option<Uuid>,
+    #[serde(default)]
+    pub rank_by: RankingMetric,
+    #[serde(default)]
+    pub direction: RankingDirection,
+    #[serde(default = "default_page")]
+    pub page: i64,
+    #[serde(default = "default_page_size")]
+    pub page_size: i64,
+    /// Stores the caller may see when `store_id` is not given; set by the
+    /// handler for non-super-admins
+    #[serde(skip)]
+    pub user_store_ids: Option<Vec<Uuid>>,
+}
+
+fn default_page() -> i64 {
+    1
+}
+
+fn default_page_size() -> i64 {
+    20
+}
@@ -1,8 +1,10 @@
 mod commands;
 mod responses;
 
-pub use commands::{AddWidgetCommand, CreateDashboardCommand, RunReportCommand};
+pub use commands::{
+    AddWidgetCommand, CreateDashboardCommand, ProductSalesRankingQuery, RunReportCommand,
+};
 pub use responses::{
-    DashboardOverviewResponse, DashboardResponse, KpiSnapshotResponse, WidgetOverviewResponse,
-    WidgetResponse,
+    DashboardOverviewResponse, DashboardResponse, KpiSnapshotResponse, ProductSalesRankingResponse,
+    WidgetOverviewResponse, WidgetResponse,
 };
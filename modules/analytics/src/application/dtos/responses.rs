@@ -4,7 +4,7 @@ use serde::{Deserialize, Serialize};
 use serde_json::Value as JsonValue;
 use uuid::Uuid;
 
-use crate::domain::entities::{Dashboard, KpiSnapshot, Widget};
+use crate::domain::entities::{Dashboard, KpiSnapshot, ProductSalesRankingRow, Widget};
 use crate::domain::value_objects::{KpiKey, TimeWindow, WidgetKind};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -99,3 +99,13 @@ pub struct DashboardOverviewResponse {
     pub dashboard: DashboardResponse,
     pub widgets: Vec<WidgetOverviewResponse>,
 }
+
+/// One page of the product sales ranking.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProductSalesRankingResponse {
+    pub items: Vec<ProductSalesRankingRow>,
+    pub page: i64,
+    pub page_size: i64,
+    pub total_items: i64,
+    pub total_pages: i64,
+}
@@ -0,0 +1,62 @@
+//! GetDailySalesSummaryUseCase — one row per day per store with sales totals,
+//! sale-type and payment breakdowns, and returns.
+
+use std::sync::Arc;
+
+use chrono::NaiveDate;
+use uuid::Uuid;
+
+use crate::AnalyticsError;
+use crate::domain::entities::DailySalesSummaryRow;
+use crate::domain::repositories::AnalyticsQueryRepository;
+
+/// Longest range, in days, a single summary may span.
+const MAX_RANGE_DAYS: i64 = 366;
+
+pub struct GetDailySalesSummaryUseCase {
+    queries: Arc<dyn AnalyticsQueryRepository>,
+}
+
+impl GetDailySalesSummaryUseCase {
+    pub fn new(queries: Arc<dyn AnalyticsQueryRepository>) -> Self {
+        Self { queries }
+    }
+
+    /// Summarizes `from..=to` for every store in `store_ids`. Store-days
+    /// without activity are returned zeroed.
+    pub async fn execute(
+        &self,
+        store_ids: &[Uuid],
+        from: NaiveDate,
+        to: NaiveDate,
+    ) -> Result<Vec<DailySalesSummaryRow>, AnalyticsError> {
+        if store_ids.is_empty() {
+            return Err(AnalyticsError::InvalidStoreSelection(
+                "at least one store is required".to_string(),
+            ));
+        }
+        if from > to {
+            return Err(AnalyticsError::InvalidTimeWindow(
+                "'from' must not be later than 'to'".to_string(),
+            ));
+        }
+        if (to - from).num_days() >= MAX_RANGE_DAYS {
+            return Err(AnalyticsError::InvalidTimeWindow(format!(
+                "range cannot exceed {} days",
+                MAX_RANGE_DAYS
+            )));
+        }
+
+        let mut store_ids = store_ids.to_vec();
+        store_ids.sort_unstable();
+        store_ids.dedup();
+
+        let rows = self
+            .queries
+            .daily_sales_summary(&store_ids, from, to)
+            .await?;
+        Ok(DailySalesSummaryRow::fill_missing_days(
+            rows, &store_ids, from, to,
+        ))
+    }
+}
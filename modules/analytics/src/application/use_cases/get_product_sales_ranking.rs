@@ -0,0 +1,63 @@
+//! GetProductSalesRankingUseCase — best sellers or slowest movers by units
+//! or revenue, with stock on hand to spot markdown candidates.
+
+use std::sync::Arc;
+
+use crate::AnalyticsError;
+use crate::application::dtos::{ProductSalesRankingQuery, ProductSalesRankingResponse};
+use crate::domain::entities::ProductSalesRankingRow;
+use crate::domain::repositories::{AnalyticsQueryRepository, ProductRankingFilters};
+use crate::domain::value_objects::RankingDirection;
+
+const MAX_PAGE_SIZE: i64 = 100;
+
+pub struct GetProductSalesRankingUseCase {
+    queries: Arc<dyn AnalyticsQueryRepository>,
+}
+
+impl GetProductSalesRankingUseCase {
+    pub fn new(queries: Arc<dyn AnalyticsQueryRepository>) -> Self {
+        Self { queries }
+    }
+
+    pub async fn execute(
+        &self,
+        query: ProductSalesRankingQuery,
+    ) -> Result<ProductSalesRankingResponse, AnalyticsError> {
+        if query.from >= query.to {
+            return Err(AnalyticsError::InvalidTimeWindow(
+                "'from' must be earlier than 'to'".to_string(),
+            ));
+        }
+
+        let page = query.page.max(1);
+        let page_size = query.page_size.clamp(1, MAX_PAGE_SIZE);
+        let store_ids = match query.store_id {
+            Some(store_id) => Some(vec![store_id]),
+            None => query.user_store_ids,
+        };
+
+        let filters = ProductRankingFilters {
+            store_ids,
+            category_id: query.category_id,
+            from: query.from,
+            to: query.to,
+            rank_by: query.rank_by,
+            direction: query.direction,
+            limit: page_size,
+            offset: (page - 1) * page_size,
+        };
+        let (mut items, total_items) = self.queries.product_sales_ranking(&filters).await?;
+        if query.direction == RankingDirection::SlowMovers {
+            ProductSalesRankingRow::flag_markdown_candidates(&mut items);
+        }
+
+        Ok(ProductSalesRankingResponse {
+            items,
+            page,
+            page_size,
+            total_items,
+            total_pages: (total_items + page_size - 1) / page_size,
+        })
+    }
+}
@@ -0,0 +1,38 @@
+//! GetTerminalSalesReportUseCase — per-terminal sales, ticket and payment mix
+//! for one store, with idle-hour hints.
+
+use std::sync::Arc;
+
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use crate::AnalyticsError;
+use crate::domain::entities::TerminalSalesRow;
+use crate::domain::repositories::AnalyticsQueryRepository;
+
+pub struct GetTerminalSalesReportUseCase {
+    queries: Arc<dyn AnalyticsQueryRepository>,
+}
+
+impl GetTerminalSalesReportUseCase {
+    pub fn new(queries: Arc<dyn AnalyticsQueryRepository>) -> Self {
+        Self { queries }
+    }
+
+    pub async fn execute(
+        &self,
+        store_id: Uuid,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+    ) -> Result<Vec<TerminalSalesRow>, AnalyticsError> {
+        if from >= to {
+            return Err(AnalyticsError::InvalidTimeWindow(
+                "'from' must be earlier than 'to'".to_string(),
+            ));
+        }
+
+        let mut rows = self.queries.terminal_sales(store_id, from, to).await?;
+        TerminalSalesRow::fill_idle_hours(&mut rows);
+        Ok(rows)
+    }
+}
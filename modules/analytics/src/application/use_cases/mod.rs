@@ -1,7 +1,10 @@
 mod add_widget;
 mod create_dashboard;
+mod get_daily_sales_summary;
 mod get_dashboard_overview;
 mod get_kpi_snapshot;
+mod get_product_sales_ranking;
+mod get_terminal_sales_report;
 mod list_dashboards;
 mod recompute_kpi_snapshots;
 mod remove_widget;
@@ -9,8 +12,11 @@ mod run_report;
 
 pub use add_widget::AddWidgetUseCase;
 pub use create_dashboard::CreateDashboardUseCase;
+pub use get_daily_sales_summary::GetDailySalesSummaryUseCase;
 pub use get_dashboard_overview::GetDashboardOverviewUseCase;
 pub use get_kpi_snapshot::GetKpiSnapshotUseCase;
+pub use get_product_sales_ranking::GetProductSalesRankingUseCase;
+pub use get_terminal_sales_report::GetTerminalSalesReportUseCase;
 pub use list_dashboards::ListDashboardsUseCase;
 pub use recompute_kpi_snapshots::RecomputeKpiSnapshotsUseCase;
 pub use remove_widget::RemoveWidgetUseCase;
@@ -6,6 +6,7 @@ mod widget;
 pub use dashboard::Dashboard;
 pub use kpi_snapshot::KpiSnapshot;
 pub use report_row::{
-    CashierPerformanceRow, DeadStockRow, PeakHourRow, ProductProfitabilityRow, ReportRows,
+    CashierPerformanceRow, DailySalesSummaryRow, DeadStockRow, PaymentMixEntry, PeakHourRow,
+    ProductProfitabilityRow, ProductSalesRankingRow, ReportRows, SaleTypeTotals, TerminalSalesRow,
 };
 pub use widget::Widget;
@@ -1,7 +1,9 @@
 //! Report rows — typed result shapes returned by the analytics query repository
 //! for each registered `ReportKind`.
 
-use chrono::{DateTime, Utc};
+use std::collections::{BTreeMap, BTreeSet};
+
+use chrono::{DateTime, NaiveDate, Utc};
 use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
@@ -42,6 +44,163 @@ pub struct CashierPerformanceRow {
     pub average_ticket: Decimal,
 }
 
+/// A product's position in a sales ranking, with its stock on hand.
+///
+/// `rank` is 1-based across the whole ranking, not the page. A slow mover is
+/// a `markdown_candidate` when more stock is on hand than sold in the range.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProductSalesRankingRow {
+    pub rank: i64,
+    pub product_id: Uuid,
+    pub sku: String,
+    pub product_name: String,
+    pub category_id: Option<Uuid>,
+    pub units_sold: Decimal,
+    pub revenue: Decimal,
+    pub quantity_on_hand: Decimal,
+    pub markdown_candidate: bool,
+}
+
+impl ProductSalesRankingRow {
+    /// Flags slow movers holding more stock than they sold.
+    pub fn flag_markdown_candidates(rows: &mut [ProductSalesRankingRow]) {
+        for row in rows.iter_mut() {
+            row.markdown_candidate = row.quantity_on_hand > row.units_sold;
+        }
+    }
+}
+
+/// Net amount collected with one payment method at a terminal.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PaymentMixEntry {
+    pub payment_method: String,
+    pub payment_count: i64,
+    pub amount: Decimal,
+}
+
+/// Per-terminal sales summary for a store over a date range.
+///
+/// `active_hours` are the hours of day (0..23) in which the terminal closed at
+/// least one sale; `idle_hours` are the hours in which the store sold on other
+/// terminals but this one sat unused — a hint for staffing or consolidating
+/// registers.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TerminalSalesRow {
+    pub terminal_id: Uuid,
+    pub terminal_code: String,
+    pub terminal_name: String,
+    pub sales_count: i64,
+    pub revenue: Decimal,
+    pub average_ticket: Decimal,
+    pub payment_mix: Vec<PaymentMixEntry>,
+    pub active_hours: Vec<i32>,
+    pub idle_hours: Vec<i32>,
+}
+
+impl TerminalSalesRow {
+    /// Fills `idle_hours` for every row from the store-wide active hours.
+    pub fn fill_idle_hours(rows: &mut [TerminalSalesRow]) {
+        let store_hours: BTreeSet<i32> = rows
+            .iter()
+            .flat_map(|r| r.active_hours.iter().copied())
+            .collect();
+
+        for row in rows.iter_mut() {
+            row.idle_hours = store_hours
+                .iter()
+                .copied()
+                .filter(|h| !row.active_hours.contains(h))
+                .collect();
+        }
+    }
+}
+
+/// Totals of one sale type (`pos` or `online`) within a daily summary row.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SaleTypeTotals {
+    pub sale_type: String,
+    pub sales_count: i64,
+    pub gross_sales: Decimal,
+    pub discounts: Decimal,
+    pub tax: Decimal,
+    pub net_sales: Decimal,
+}
+
+/// One store's completed sales for one day, POS and online combined.
+///
+/// `gross_sales` is the sum of subtotals before discounts and `net_sales` the
+/// sum of sale totals; both are the sums of the matching `by_sale_type`
+/// entries. Returns are credit notes applied that day, regardless of when the
+/// original sale was made. Days are calendar days in UTC.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DailySalesSummaryRow {
+    pub date: NaiveDate,
+    pub store_id: Uuid,
+    pub sales_count: i64,
+    pub gross_sales: Decimal,
+    pub discounts: Decimal,
+    pub tax: Decimal,
+    pub net_sales: Decimal,
+    pub returns_count: i64,
+    pub returns_amount: Decimal,
+    pub by_sale_type: Vec<SaleTypeTotals>,
+    pub payment_mix: Vec<PaymentMixEntry>,
+}
+
+impl DailySalesSummaryRow {
+    /// Zeroed summary for a store-day without activity.
+    pub fn empty(date: NaiveDate, store_id: Uuid) -> Self {
+        Self {
+            date,
+            store_id,
+            sales_count: 0,
+            gross_sales: Decimal::ZERO,
+            discounts: Decimal::ZERO,
+            tax: Decimal::ZERO,
+            net_sales: Decimal::ZERO,
+            returns_count: 0,
+            returns_amount: Decimal::ZERO,
+            by_sale_type: Vec::new(),
+            payment_mix: Vec::new(),
+        }
+    }
+
+    /// Adds one sale type's totals to the breakdown and the day's totals.
+    pub fn add_sale_type(&mut self, totals: SaleTypeTotals) {
+        self.sales_count += totals.sales_count;
+        self.gross_sales += totals.gross_sales;
+        self.discounts += totals.discounts;
+        self.tax += totals.tax;
+        self.net_sales += totals.net_sales;
+        self.by_sale_type.push(totals);
+    }
+
+    /// Returns one row per day in `from..=to` per store, in that order,
+    /// taking the matching row from `rows` or a zeroed one, so dashboards
+    /// chart a continuous series.
+    pub fn fill_missing_days(
+        rows: Vec<DailySalesSummaryRow>,
+        store_ids: &[Uuid],
+        from: NaiveDate,
+        to: NaiveDate,
+    ) -> Vec<DailySalesSummaryRow> {
+        let mut by_key: BTreeMap<(NaiveDate, Uuid), DailySalesSummaryRow> = rows
+            .into_iter()
+            .map(|r| ((r.date, r.store_id), r))
+            .collect();
+
+        from.iter_days()
+            .take_while(|d| *d <= to)
+            .flat_map(|date| store_ids.iter().map(move |sid| (date, *sid)))
+            .map(|(date, sid)| {
+                by_key
+                    .remove(&(date, sid))
+                    .unwrap_or_else(|| Self::empty(date, sid))
+            })
+            .collect()
+    }
+}
+
 /// Polymorphic envelope returned by `RunReportUseCase`.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(tag = "kind", rename_all = "snake_case")]
@@ -51,3 +210,118 @@ pub enum ReportRows {
     DeadStock(Vec<DeadStockRow>),
     CashierPerformance(Vec<CashierPerformanceRow>),
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn terminal(code: &str, active_hours: Vec<i32>) -> TerminalSalesRow {
+        TerminalSalesRow {
+            terminal_id: Uuid::now_v7(),
+            terminal_code: code.to_string(),
+            terminal_name: code.to_string(),
+            sales_count: active_hours.len() as i64,
+            revenue: Decimal::ZERO,
+            average_ticket: Decimal::ZERO,
+            payment_mix: Vec::new(),
+            active_hours,
+            idle_hours: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_fill_idle_hours_uses_store_wide_hours() {
+        let mut rows = vec![
+            terminal("T1", vec![9, 10, 11, 17]),
+            terminal("T2", vec![10, 11]),
+            terminal("T3", vec![]),
+        ];
+
+        TerminalSalesRow::fill_idle_hours(&mut rows);
+
+        assert!(rows[0].idle_hours.is_empty());
+        assert_eq!(rows[1].idle_hours, vec![9, 17]);
+        assert_eq!(rows[2].idle_hours, vec![9, 10, 11, 17]);
+    }
+
+    fn ranked(units_sold: i64, quantity_on_hand: i64) -> ProductSalesRankingRow {
+        ProductSalesRankingRow {
+            rank: 1,
+            product_id: Uuid::now_v7(),
+            sku: "SKU".to_string(),
+            product_name: "Product".to_string(),
+            category_id: None,
+            units_sold: Decimal::from(units_sold),
+            revenue: Decimal::ZERO,
+            quantity_on_hand: Decimal::from(quantity_on_hand),
+            markdown_candidate: false,
+        }
+    }
+
+    #[test]
+    fn test_flag_markdown_candidates_when_stock_exceeds_sales() {
+        let mut rows = vec![ranked(0, 40), ranked(5, 5), ranked(10, 2), ranked(0, 0)];
+
+        ProductSalesRankingRow::flag_markdown_candidates(&mut rows);
+
+        assert_eq!(
+            rows.iter()
+                .map(|r| r.markdown_candidate)
+                .collect::<Vec<_>>(),
+            [true, false, false, false]
+        );
+    }
+
+    fn sale_type(sale_type: &str, count: i64, subtotal: Decimal) -> SaleTypeTotals {
+        SaleTypeTotals {
+            sale_type: sale_type.to_string(),
+            sales_count: count,
+            gross_sales: subtotal,
+            discounts: Decimal::ONE,
+            tax: Decimal::TWO,
+            net_sales: subtotal + Decimal::ONE,
+        }
+    }
+
+    #[test]
+    fn test_add_sale_type_accumulates_totals() {
+        let date = NaiveDate::from_ymd_opt(2026, 10, 1).unwrap();
+        let mut row = DailySalesSummaryRow::empty(date, Uuid::now_v7());
+
+        row.add_sale_type(sale_type("pos", 3, Decimal::from(100)));
+        row.add_sale_type(sale_type("online", 2, Decimal::from(50)));
+
+        assert_eq!(row.sales_count, 5);
+        assert_eq!(row.gross_sales, Decimal::from(150));
+        assert_eq!(row.discounts, Decimal::TWO);
+        assert_eq!(row.tax, Decimal::from(4));
+        assert_eq!(row.net_sales, Decimal::from(152));
+        assert_eq!(row.by_sale_type.len(), 2);
+    }
+
+    #[test]
+    fn test_fill_missing_days_returns_every_store_day_in_order() {
+        let (a, b) = (Uuid::now_v7(), Uuid::now_v7());
+        let day = |d| NaiveDate::from_ymd_opt(2026, 10, d).unwrap();
+        let mut active = DailySalesSummaryRow::empty(day(2), b);
+        active.add_sale_type(sale_type("pos", 1, Decimal::TEN));
+
+        let rows = DailySalesSummaryRow::fill_missing_days(vec![active], &[a, b], day(1), day(3));
+
+        assert_eq!(
+            rows.iter()
+                .map(|r| (r.date, r.store_id))
+                .collect::<Vec<_>>(),
+            [
+                (day(1), a),
+                (day(1), b),
+                (day(2), a),
+                (day(2), b),
+                (day(3), a),
+                (day(3), b)
+            ]
+        );
+        assert_eq!(rows[3].sales_count, 1);
+        assert_eq!(rows.iter().filter(|r| r.sales_count == 0).count(), 5);
+    }
+}
@@ -5,14 +5,16 @@
 //! code, so analytics stays decoupled from internal refactors there.
 
 use async_trait::async_trait;
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, NaiveDate, Utc};
 use rust_decimal::Decimal;
 use uuid::Uuid;
 
 use crate::AnalyticsError;
 use crate::domain::entities::{
-    CashierPerformanceRow, DeadStockRow, PeakHourRow, ProductProfitabilityRow,
+    CashierPerformanceRow, DailySalesSummaryRow, DeadStockRow, PeakHourRow,
+    ProductProfitabilityRow, ProductSalesRankingRow, TerminalSalesRow,
 };
+use crate::domain::value_objects::{RankingDirection, RankingMetric};
 
 /// Filters applied to all reports.
 #[derive(Debug, Clone)]
@@ -23,6 +25,20 @@ pub struct ReportFilters {
     pub limit: i64,
 }
 
+/// Scope and ordering of a product sales ranking page.
+#[derive(Debug, Clone)]
+pub struct ProductRankingFilters {
+    /// `None` ranks across all stores
+    pub store_ids: Option<Vec<Uuid>>,
+    pub category_id: Option<Uuid>,
+    pub from: DateTime<Utc>,
+    pub to: DateTime<Utc>,
+    pub rank_by: RankingMetric,
+    pub direction: RankingDirection,
+    pub limit: i64,
+    pub offset: i64,
+}
+
 #[async_trait]
 pub trait AnalyticsQueryRepository: Send + Sync {
     // ---- Aggregates used by RecomputeKpiSnapshotsUseCase --------------------
@@ -66,4 +82,32 @@ pub trait AnalyticsQueryRepository: Send + Sync {
         &self,
         filters: &ReportFilters,
     ) -> Result<Vec<CashierPerformanceRow>, AnalyticsError>;
+
+    /// Per-terminal sales for one store, sorted by revenue descending.
+    /// Terminals without sales in the range are included with zeroed totals;
+    /// `idle_hours` is left empty for the caller to fill.
+    async fn terminal_sales(
+        &self,
+        store_id: Uuid,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+    ) -> Result<Vec<TerminalSalesRow>, AnalyticsError>;
+
+    /// Completed sales and applied returns per store and UTC day within
+    /// `from..=to`. Only store-days with activity are returned, sorted by
+    /// date then store.
+    async fn daily_sales_summary(
+        &self,
+        store_ids: &[Uuid],
+        from: NaiveDate,
+        to: NaiveDate,
+    ) -> Result<Vec<DailySalesSummaryRow>, AnalyticsError>;
+
+    /// One page of products ranked by `rank_by`, ties broken by SKU, with
+    /// the total number of ranked products. `rank` is filled in;
+    /// `markdown_candidate` is left false for the caller to set.
+    async fn product_sales_ranking(
+        &self,
+        filters: &ProductRankingFilters,
+    ) -> Result<(Vec<ProductSalesRankingRow>, i64), AnalyticsError>;
 }
@@ -3,7 +3,9 @@ mod dashboard_repository;
 mod kpi_snapshot_repository;
 mod widget_repository;
 
-pub use analytics_query_repository::{AnalyticsQueryRepository, ReportFilters};
+pub use analytics_query_repository::{
+    AnalyticsQueryRepository, ProductRankingFilters, ReportFilters,
+};
 pub use dashboard_repository::DashboardRepository;
 pub use kpi_snapshot_repository::KpiSnapshotRepository;
 pub use widget_repository::WidgetRepository;
@@ -1,11 +1,13 @@
 mod ids;
 mod kpi_key;
+mod product_ranking;
 mod report_kind;
 mod time_window;
 mod widget_kind;
 
 pub use ids::{DashboardId, KpiSnapshotId, WidgetId};
 pub use kpi_key::KpiKey;
+pub use product_ranking::{RankingDirection, RankingMetric};
 pub use report_kind::ReportKind;
 pub use time_window::TimeWindow;
 pub use widget_kind::WidgetKind;
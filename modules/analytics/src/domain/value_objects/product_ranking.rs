@@ -0,0 +1,24 @@
+//! Product ranking options — which measure products are ranked by and from
+//! which end of the ranking.
+
+use serde::{Deserialize, Serialize};
+
+/// Measure a product sales ranking is ordered by.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RankingMetric {
+    #[default]
+    UnitsSold,
+    Revenue,
+}
+
+/// End of the ranking to read from.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RankingDirection {
+    /// Best sellers first; only products that sold in the range.
+    #[default]
+    TopSellers,
+    /// Slowest movers first, including carried products that did not sell.
+    SlowMovers,
+}
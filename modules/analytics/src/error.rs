@@ -21,6 +21,9 @@ pub enum AnalyticsError {
     #[error("Invalid time window: {0}")]
     InvalidTimeWindow(String),
 
+    #[error("Invalid store selection: {0}")]
+    InvalidStoreSelection(String),
+
     #[error("Invalid widget kind: {0}")]
     InvalidWidgetKind(String),
 
@@ -5,17 +5,21 @@
 //! and use `completed_at` for time-window bounds — voided/draft sales are
 //! intentionally excluded.
 
+use std::collections::BTreeMap;
+
 use async_trait::async_trait;
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, Days, NaiveDate, NaiveTime, Utc};
 use rust_decimal::Decimal;
 use sqlx::PgPool;
 use uuid::Uuid;
 
 use crate::AnalyticsError;
 use crate::domain::entities::{
-    CashierPerformanceRow, DeadStockRow, PeakHourRow, ProductProfitabilityRow,
+    CashierPerformanceRow, DailySalesSummaryRow, DeadStockRow, PaymentMixEntry, PeakHourRow,
+    ProductProfitabilityRow, ProductSalesRankingRow, SaleTypeTotals, TerminalSalesRow,
 };
-use crate::domain::repositories::{AnalyticsQueryRepository, ReportFilters};
+use crate::domain::repositories::{AnalyticsQueryRepository, ProductRankingFilters, ReportFilters};
+use crate::domain::value_objects::{RankingDirection, RankingMetric};
 
 pub struct PgAnalyticsQueryRepository {
     pool: PgPool,
@@ -235,6 +239,317 @@ impl AnalyticsQueryRepository for PgAnalyticsQueryRepository {
         .await?;
         Ok(rows.into_iter().map(CashierPerformanceRow::from).collect())
     }
+
+    async fn terminal_sales(
+        &self,
+        store_id: Uuid,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+    ) -> Result<Vec<TerminalSalesRow>, AnalyticsError> {
+        // One row per (terminal, payment method); terminal totals repeat on
+        // each row and are folded below. Refunds are netted out of the mix.
+        let rows = sqlx::query_as::<_, TerminalSalesRowDb>(
+            r#"
+            WITH scoped AS (
+                SELECT s.id, s.terminal_id, s.total,
+                       EXTRACT(HOUR FROM s.completed_at)::INT AS hour_of_day
+                FROM sales s
+                WHERE s.status = 'completed'
+                  AND s.store_id = $1
+                  AND s.terminal_id IS NOT NULL
+                  AND s.completed_at >= $2
+                  AND s.completed_at < $3
+            ),
+            per_terminal AS (
+                SELECT terminal_id,
+                       COUNT(*)::BIGINT                                        AS sales_count,
+                       COALESCE(SUM(total), 0)::NUMERIC                        AS revenue,
+                       COALESCE(AVG(total), 0)::NUMERIC                        AS average_ticket,
+                       ARRAY_AGG(DISTINCT hour_of_day ORDER BY hour_of_day)    AS active_hours
+                FROM scoped
+                GROUP BY terminal_id
+            ),
+            mix AS (
+                SELECT sc.terminal_id,
+                       p.payment_method,
+                       COUNT(*)::BIGINT                                        AS payment_count,
+                       COALESCE(SUM(ROUND((p.amount - p.refunded_amount) * p.exchange_rate, 2)), 0)::NUMERIC AS amount
+                FROM scoped sc
+                JOIN payments p ON p.sale_id = sc.id
+                WHERE p.status IN ('completed', 'partially_refunded', 'refunded')
+                GROUP BY sc.terminal_id, p.payment_method
+            )
+            SELECT
+                t.id                                                          AS terminal_id,
+                t.code::TEXT                                                  AS terminal_code,
+                t.name::TEXT                                                  AS terminal_name,
+                COALESCE(pt.sales_count, 0)::BIGINT                           AS sales_count,
+                COALESCE(pt.revenue, 0)::NUMERIC                              AS revenue,
+                COALESCE(pt.average_ticket, 0)::NUMERIC                       AS average_ticket,
+                COALESCE(pt.active_hours, ARRAY[]::INT[])                     AS active_hours,
+                m.payment_method::TEXT                                        AS payment_method,
+                m.payment_count,
+                m.amount                                                      AS payment_amount
+            FROM terminals t
+            LEFT JOIN per_terminal pt ON pt.terminal_id = t.id
+            LEFT JOIN mix m ON m.terminal_id = t.id
+            WHERE t.store_id = $1
+            ORDER BY revenue DESC, t.code, payment_amount DESC NULLS LAST
+            "#,
+        )
+        .bind(store_id)
+        .bind(from)
+        .bind(to)
+        .fetch_all(&self.pool)
+        .await?;
+
+        let mut out: Vec<TerminalSalesRow> = Vec::new();
+        for r in rows {
+            let entry = match (r.payment_method, r.payment_count, r.payment_amount) {
+                (Some(payment_method), Some(payment_count), Some(amount)) => {
+                    Some(PaymentMixEntry {
+                        payment_method,
+                        payment_count,
+                        amount,
+                    })
+                }
+                _ => None,
+            };
+
+            match out.last_mut() {
+                Some(last) if last.terminal_id == r.terminal_id => {
+                    last.payment_mix.extend(entry);
+                }
+                _ => out.push(TerminalSalesRow {
+                    terminal_id: r.terminal_id,
+                    terminal_code: r.terminal_code,
+                    terminal_name: r.terminal_name,
+                    sales_count: r.sales_count,
+                    revenue: r.revenue,
+                    average_ticket: r.average_ticket,
+                    payment_mix: entry.into_iter().collect(),
+                    active_hours: r.active_hours,
+                    idle_hours: Vec::new(),
+                }),
+            }
+        }
+        Ok(out)
+    }
+
+    async fn daily_sales_summary(
+        &self,
+        store_ids: &[Uuid],
+        from: NaiveDate,
+        to: NaiveDate,
+    ) -> Result<Vec<DailySalesSummaryRow>, AnalyticsError> {
+        let start = from.and_time(NaiveTime::MIN).and_utc();
+        let end = (to + Days::new(1)).and_time(NaiveTime::MIN).and_utc();
+
+        let totals = sqlx::query_as::<_, DailySaleTypeRowDb>(
+            r#"
+            SELECT (s.completed_at AT TIME ZONE 'UTC')::DATE                  AS day,
+                   s.store_id,
+                   s.sale_type::TEXT                                          AS sale_type,
+                   COUNT(*)::BIGINT                                           AS sales_count,
+                   COALESCE(SUM(s.subtotal), 0)::NUMERIC                      AS gross_sales,
+                   COALESCE(SUM(s.discount_amount), 0)::NUMERIC               AS discounts,
+                   COALESCE(SUM(s.tax_amount), 0)::NUMERIC                    AS tax,
+                   COALESCE(SUM(s.total), 0)::NUMERIC                         AS net_sales
+            FROM sales s
+            WHERE s.status = 'completed'
+              AND s.store_id = ANY($1)
+              AND s.completed_at >= $2
+              AND s.completed_at < $3
+            GROUP BY 1, 2, 3
+            ORDER BY 1, 2, 3
+            "#,
+        )
+        .bind(store_ids)
+        .bind(start)
+        .bind(end)
+        .fetch_all(&self.pool)
+        .await?;
+
+        // Refunds are netted out of the mix, as in the terminal report.
+        let mix = sqlx::query_as::<_, DailyPaymentMixRowDb>(
+            r#"
+            SELECT (s.completed_at AT TIME ZONE 'UTC')::DATE                  AS day,
+                   s.store_id,
+                   p.payment_method::TEXT                                     AS payment_method,
+                   COUNT(*)::BIGINT                                           AS payment_count,
+                   COALESCE(SUM(ROUND((p.amount - p.refunded_amount) * p.exchange_rate, 2)), 0)::NUMERIC AS amount
+            FROM sales s
+            JOIN payments p ON p.sale_id = s.id
+            WHERE s.status = 'completed'
+              AND s.store_id = ANY($1)
+              AND s.completed_at >= $2
+              AND s.completed_at < $3
+              AND p.status IN ('completed', 'partially_refunded', 'refunded')
+            GROUP BY 1, 2, 3
+            ORDER BY 1, 2, amount DESC
+            "#,
+        )
+        .bind(store_ids)
+        .bind(start)
+        .bind(end)
+        .fetch_all(&self.pool)
+        .await?;
+
+        let returns = sqlx::query_as::<_, DailyReturnsRowDb>(
+            r#"
+            SELECT (cn.applied_at AT TIME ZONE 'UTC')::DATE                   AS day,
+                   cn.store_id,
+                   COUNT(*)::BIGINT                                           AS returns_count,
+                   COALESCE(SUM(cn.refunded_amount), 0)::NUMERIC              AS returns_amount
+            FROM credit_notes cn
+            WHERE cn.status = 'applied'
+              AND cn.store_id = ANY($1)
+              AND cn.applied_at >= $2
+              AND cn.applied_at < $3
+            GROUP BY 1, 2
+            "#,
+        )
+        .bind(store_ids)
+        .bind(start)
+        .bind(end)
+        .fetch_all(&self.pool)
+        .await?;
+
+        let mut out: BTreeMap<(NaiveDate, Uuid), DailySalesSummaryRow> = BTreeMap::new();
+        for r in totals {
+            out.entry((r.day, r.store_id))
+                .or_insert_with(|| DailySalesSummaryRow::empty(r.day, r.store_id))
+                .add_sale_type(SaleTypeTotals {
+                    sale_type: r.sale_type,
+                    sales_count: r.sales_count,
+                    gross_sales: r.gross_sales,
+                    discounts: r.discounts,
+                    tax: r.tax,
+                    net_sales: r.net_sales,
+                });
+        }
+        for r in mix {
+            out.entry((r.day, r.store_id))
+                .or_insert_with(|| DailySalesSummaryRow::empty(r.day, r.store_id))
+                .payment_mix
+                .push(PaymentMixEntry {
+                    payment_method: r.payment_method,
+                    payment_count: r.payment_count,
+                    amount: r.amount,
+                });
+        }
+        for r in returns {
+            let row = out
+                .entry((r.day, r.store_id))
+                .or_insert_with(|| DailySalesSummaryRow::empty(r.day, r.store_id));
+            row.returns_count = r.returns_count;
+            row.returns_amount = r.returns_amount;
+        }
+        Ok(out.into_values().collect())
+    }
+
+    async fn product_sales_ranking(
+        &self,
+        f: &ProductRankingFilters,
+    ) -> Result<(Vec<ProductSalesRankingRow>, i64), AnalyticsError> {
+        let metric = match f.rank_by {
+            RankingMetric::UnitsSold => "units_sold",
+            RankingMetric::Revenue => "revenue",
+        };
+        let order = match f.direction {
+            RankingDirection::TopSellers => "DESC",
+            RankingDirection::SlowMovers => "ASC",
+        };
+
+        // Top sellers rank only products that sold; slow movers also rank
+        // active products stocked in scope that did not sell at all. Stock
+        // held as variants counts towards the parent product.
+        let sql = format!(
+            r#"
+            WITH sold AS (
+                SELECT si.product_id,
+                       SUM(si.quantity)                                       AS units_sold,
+                       SUM(si.total)                                          AS revenue
+                FROM sale_items si
+                JOIN sales s ON s.id = si.sale_id
+                WHERE s.status = 'completed'
+                  AND ($1::uuid[] IS NULL OR s.store_id = ANY($1))
+                  AND s.completed_at >= $2
+                  AND s.completed_at < $3
+                GROUP BY si.product_id
+            ),
+            on_hand AS (
+                SELECT COALESCE(inv.product_id, pv.product_id)                AS product_id,
+                       SUM(inv.quantity)                                      AS quantity
+                FROM inventory_stock inv
+                LEFT JOIN product_variants pv ON pv.id = inv.variant_id
+                WHERE ($1::uuid[] IS NULL OR inv.store_id = ANY($1))
+                GROUP BY 1
+            )
+            SELECT
+                p.id                                                          AS product_id,
+                p.sku::TEXT                                                   AS sku,
+                p.name::TEXT                                                  AS product_name,
+                p.category_id,
+                COALESCE(sd.units_sold, 0)::NUMERIC                           AS units_sold,
+                COALESCE(sd.revenue, 0)::NUMERIC                              AS revenue,
+                COALESCE(oh.quantity, 0)::NUMERIC                             AS quantity_on_hand,
+                COUNT(*) OVER ()::BIGINT                                      AS total_count
+            FROM products p
+            LEFT JOIN sold sd ON sd.product_id = p.id
+            LEFT JOIN on_hand oh ON oh.product_id = p.id
+            WHERE ($4::uuid IS NULL OR p.category_id = $4)
+              AND (sd.product_id IS NOT NULL
+                   OR ($5 AND p.is_active AND oh.product_id IS NOT NULL))
+            ORDER BY {metric} {order}, p.sku
+            LIMIT $6 OFFSET $7
+            "#
+        );
+
+        let rows = sqlx::query_as::<_, ProductSalesRankingRowDb>(&sql)
+            .bind(f.store_ids.as_deref())
+            .bind(f.from)
+            .bind(f.to)
+            .bind(f.category_id)
+            .bind(f.direction == RankingDirection::SlowMovers)
+            .bind(f.limit)
+            .bind(f.offset)
+            .fetch_all(&self.pool)
+            .await?;
+
+        let total = match rows.first() {
+            Some(r) => r.total_count,
+            None if f.offset > 0 => {
+                // Past the last page: the window count is unavailable, so
+                // count separately to keep the total accurate.
+                return self
+                    .product_sales_ranking(&ProductRankingFilters {
+                        offset: 0,
+                        limit: 1,
+                        ..f.clone()
+                    })
+                    .await
+                    .map(|(_, total)| (Vec::new(), total));
+            }
+            None => 0,
+        };
+        let items = rows
+            .into_iter()
+            .enumerate()
+            .map(|(idx, r)| ProductSalesRankingRow {
+                rank: f.offset + idx as i64 + 1,
+                product_id: r.product_id,
+                sku: r.sku,
+                product_name: r.product_name,
+                category_id: r.category_id,
+                units_sold: r.units_sold,
+                revenue: r.revenue,
+                quantity_on_hand: r.quantity_on_hand,
+                markdown_candidate: false,
+            })
+            .collect();
+        Ok((items, total))
+    }
 }
 
 // -----------------------------------------------------------------------------
@@ -324,3 +639,58 @@ impl From<CashierPerformanceRowDb> for CashierPerformanceRow {
         }
     }
 }
+
+#[derive(sqlx::FromRow)]
+struct TerminalSalesRowDb {
+    terminal_id: Uuid,
+    terminal_code: String,
+    terminal_name: String,
+    sales_count: i64,
+    revenue: Decimal,
+    average_ticket: Decimal,
+    active_hours: Vec<i32>,
+    payment_method: Option<String>,
+    payment_count: Option<i64>,
+    payment_amount: Option<Decimal>,
+}
+
+#[derive(sqlx::FromRow)]
+struct DailySaleTypeRowDb {
+    day: NaiveDate,
+    store_id: Uuid,
+    sale_type: String,
+    sales_count: i64,
+    gross_sales: Decimal,
+    discounts: Decimal,
+    tax: Decimal,
+    net_sales: Decimal,
+}
+
+#[derive(sqlx::FromRow)]
+struct DailyPaymentMixRowDb {
+    day: NaiveDate,
+    store_id: Uuid,
+    payment_method: String,
+    payment_count: i64,
+    amount: Decimal,
+}
+
+#[derive(sqlx::FromRow)]
+struct DailyReturnsRowDb {
+    day: NaiveDate,
+    store_id: Uuid,
+    returns_count: i64,
+    returns_amount: Decimal,
+}
+
+#[derive(sqlx::FromRow)]
+struct ProductSalesRankingRowDb {
+    product_id: Uuid,
+    sku: String,
+    product_name: String,
+    category_id: Option<Uuid>,
+    units_sold: Decimal,
+    revenue: Decimal,
+    quantity_on_hand: Decimal,
+    total_count: i64,
+}
@@ -21,26 +21,31 @@ pub use error::AnalyticsError;
 
 // Domain
 pub use domain::entities::{
-    CashierPerformanceRow, Dashboard, DeadStockRow, KpiSnapshot, PeakHourRow,
-    ProductProfitabilityRow, ReportRows, Widget,
+    CashierPerformanceRow, DailySalesSummaryRow, Dashboard, DeadStockRow, KpiSnapshot,
+    PaymentMixEntry, PeakHourRow, ProductProfitabilityRow, ProductSalesRankingRow, ReportRows,
+    SaleTypeTotals, TerminalSalesRow, Widget,
 };
 pub use domain::repositories::{
-    AnalyticsQueryRepository, DashboardRepository, KpiSnapshotRepository, ReportFilters,
-    WidgetRepository,
+    AnalyticsQueryRepository, DashboardRepository, KpiSnapshotRepository, ProductRankingFilters,
+    ReportFilters, WidgetRepository,
 };
 pub use domain::value_objects::{
-    DashboardId, KpiKey, KpiSnapshotId, ReportKind, TimeWindow, WidgetId, WidgetKind,
+    DashboardId, KpiKey, KpiSnapshotId, RankingDirection, RankingMetric, ReportKind, TimeWindow,
+    WidgetId, WidgetKind,
 };
 
 // Application
 pub use application::dtos::{
     AddWidgetCommand, CreateDashboardCommand, DashboardOverviewResponse, DashboardResponse,
-    KpiSnapshotResponse, RunReportCommand, WidgetOverviewResponse, WidgetResponse,
+    KpiSnapshotResponse, ProductSalesRankingQuery, ProductSalesRankingResponse, RunReportCommand,
+    WidgetOverviewResponse, WidgetResponse,
 };
 pub use application::subscriber::AnalyticsEventSubscriber;
 pub use application::use_cases::{
-    AddWidgetUseCase, CreateDashboardUseCase, GetDashboardOverviewUseCase, GetKpiSnapshotUseCase,
-    ListDashboardsUseCase, RecomputeKpiSnapshotsUseCase, RemoveWidgetUseCase, RunReportUseCase,
+    AddWidgetUseCase, CreateDashboardUseCase, GetDailySalesSummaryUseCase,
+    GetDashboardOverviewUseCase, GetKpiSnapshotUseCase, GetProductSalesRankingUseCase,
+    GetTerminalSalesReportUseCase, ListDashboardsUseCase, RecomputeKpiSnapshotsUseCase,
+    RemoveWidgetUseCase, RunReportUseCase,
 };
 
 // Infrastructure
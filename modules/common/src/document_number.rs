@@ -0,0 +1,226 @@
+//! Gapless sequential numbers for business documents (`PO-2026-00001`).
+//!
+//! Each document type counts per scope (usually a store) and per calendar
+//! year in one row of `document_sequences`. Drawing a number upserts that
+//! row, which locks it until the surrounding transaction ends: concurrent
+//! drawers of the same sequence wait for each other, and a transaction that
+//! rolls back hands its number back. Draw inside the transaction that
+//! inserts the document so the two commit together and no number is lost.
+
+use std::collections::HashMap;
+use std::fmt;
+use std::str::FromStr;
+
+use chrono::{Datelike, Utc};
+use sqlx::PgConnection;
+use uuid::Uuid;
+
+/// Documents numbered through [`DocumentNumberService`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DocumentType {
+    PurchaseOrder,
+    GoodsReceipt,
+    StockAdjustment,
+    StockTransfer,
+    CreditNote,
+}
+
+impl DocumentType {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            DocumentType::PurchaseOrder => "purchase_order",
+            DocumentType::GoodsReceipt => "goods_receipt",
+            DocumentType::StockAdjustment => "stock_adjustment",
+            DocumentType::StockTransfer => "stock_transfer",
+            DocumentType::CreditNote => "credit_note",
+        }
+    }
+
+    fn default_format(&self) -> DocumentNumberFormat {
+        let (prefix, padding) = match self {
+            DocumentType::PurchaseOrder => ("PO", 5),
+            DocumentType::GoodsReceipt => ("GR", 5),
+            DocumentType::StockAdjustment => ("ADJ", 6),
+            DocumentType::StockTransfer => ("TRF", 6),
+            DocumentType::CreditNote => ("CN", 5),
+        };
+        DocumentNumberFormat::new(prefix, padding)
+    }
+}
+
+impl fmt::Display for DocumentType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// The given name is not a numbered document type.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnknownDocumentType(pub String);
+
+impl fmt::Display for UnknownDocumentType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Unknown document type: {}", self.0)
+    }
+}
+
+impl std::error::Error for UnknownDocumentType {}
+
+impl FromStr for DocumentType {
+    type Err = UnknownDocumentType;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "purchase_order" => Ok(DocumentType::PurchaseOrder),
+            "goods_receipt" => Ok(DocumentType::GoodsReceipt),
+            "stock_adjustment" => Ok(DocumentType::StockAdjustment),
+            "stock_transfer" => Ok(DocumentType::StockTransfer),
+            "credit_note" => Ok(DocumentType::CreditNote),
+            other => Err(UnknownDocumentType(other.to_string())),
+        }
+    }
+}
+
+/// How a document type's numbers are written: `{prefix}-{year}-{sequence}`,
+/// the sequence zero-padded to `padding` digits
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DocumentNumberFormat {
+    pub prefix: String,
+    pub padding: usize,
+}
+
+impl DocumentNumberFormat {
+    pub fn new(prefix: impl Into<String>, padding: usize) -> Self {
+        Self {
+            prefix: prefix.into(),
+            padding,
+        }
+    }
+
+    pub fn format(&self, year: i32, sequence: i64) -> String {
+        format!(
+            "{}-{}-{:0width$}",
+            self.prefix,
+            year,
+            sequence,
+            width = self.padding
+        )
+    }
+}
+
+/// Hands out document numbers from `document_sequences`, formatted per
+/// document type.
+#[derive(Debug, Clone, Default)]
+pub struct DocumentNumberService {
+    formats: HashMap<DocumentType, DocumentNumberFormat>,
+}
+
+impl DocumentNumberService {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Overrides the format of one document type
+    pub fn with_format(
+        mut self,
+        document_type: DocumentType,
+        format: DocumentNumberFormat,
+    ) -> Self {
+        self.formats.insert(document_type, format);
+        self
+    }
+
+    pub fn format_of(&self, document_type: DocumentType) -> DocumentNumberFormat {
+        self.formats
+            .get(&document_type)
+            .cloned()
+            .unwrap_or_else(|| document_type.default_format())
+    }
+
+    /// Draws the next number of `document_type` for the current year.
+    ///
+    /// `scope_id` is the store the sequence belongs to; `None` numbers the
+    /// type globally. Pass the connection of the transaction that inserts
+    /// the document: the sequence row stays locked until it ends.
+    pub async fn next_number(
+        &self,
+        conn: &mut PgConnection,
+        document_type: DocumentType,
+        scope_id: Option<Uuid>,
+    ) -> Result<String, sqlx::Error> {
+        let year = Utc::now().year();
+        let sequence: i64 = sqlx::query_scalar(
+            r#"
+            INSERT INTO document_sequences (document_type, scope_id, year, last_number)
+            VALUES ($1, $2, $3, 1)
+            ON CONFLICT (document_type, scope_id, year)
+            DO UPDATE SET last_number = document_sequences.last_number + 1,
+                          updated_at = NOW()
+            RETURNING last_number
+            "#,
+        )
+        .bind(document_type.as_str())
+        .bind(scope_id.unwrap_or(Uuid::nil()))
+        .bind(year)
+        .fetch_one(conn)
+        .await?;
+
+        Ok(self.format_of(document_type).format(year, sequence))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_formats() {
+        let service = DocumentNumberService::new();
+
+        assert_eq!(
+            service
+                .format_of(DocumentType::PurchaseOrder)
+                .format(2024, 1),
+            "PO-2024-00001"
+        );
+        assert_eq!(
+            service
+                .format_of(DocumentType::StockTransfer)
+                .format(2026, 42),
+            "TRF-2026-000042"
+        );
+    }
+
+    #[test]
+    fn test_with_format_overrides_one_type() {
+        let service = DocumentNumberService::new().with_format(
+            DocumentType::CreditNote,
+            DocumentNumberFormat::new("NCR", 3),
+        );
+
+        assert_eq!(
+            service.format_of(DocumentType::CreditNote).format(2026, 7),
+            "NCR-2026-007"
+        );
+        assert_eq!(
+            service
+                .format_of(DocumentType::GoodsReceipt)
+                .format(2026, 7),
+            "GR-2026-00007"
+        );
+    }
+
+    #[test]
+    fn test_document_type_round_trips_through_str() {
+        for document_type in [
+            DocumentType::PurchaseOrder,
+            DocumentType::GoodsReceipt,
+            DocumentType::StockAdjustment,
+            DocumentType::StockTransfer,
+            DocumentType::CreditNote,
+        ] {
+            assert_eq!(document_type.as_str().parse(), Ok(document_type));
+        }
+        assert!("invoice".parse::<DocumentType>().is_err());
+    }
+}
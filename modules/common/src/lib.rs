@@ -1,7 +1,15 @@
 // Tipos compartidos: Money, Errors, validators, etc.
 
 pub mod auth;
+pub mod document_number;
 pub mod health;
+pub mod pagination;
+pub mod tax_id;
 
 pub use auth::{ActorClaim, BackofficeClaims, TokenAudience};
+pub use document_number::{
+    DocumentNumberFormat, DocumentNumberService, DocumentType, UnknownDocumentType,
+};
 pub use health::{HealthCheckUseCase, HealthState, HealthStatus};
+pub use pagination::{InvalidPageCursor, PageCursor};
+pub use tax_id::{TaxId, TaxIdError, TaxIdPolicy, TaxIdRule};
@@ -0,0 +1,100 @@
+//! Keyset (cursor) pagination for list queries.
+//!
+//! Entity ids are UUID v7, so ordering by id is creation order. A
+//! [`PageCursor`] wraps the id of the last row a client has seen; the next
+//! page is the rows with a smaller id (lists are newest first). Unlike
+//! offset paging, rows inserted while a client scrolls do not shift the
+//! pages it has not read yet.
+
+use std::fmt;
+
+use uuid::Uuid;
+
+/// Opaque continuation token handed out as `next_cursor` and accepted back
+/// as `after_cursor`. Clients must not parse it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PageCursor(Uuid);
+
+/// The `after_cursor` a client sent is not a cursor this API issued.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InvalidPageCursor(pub String);
+
+impl fmt::Display for InvalidPageCursor {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Invalid page cursor: {}", self.0)
+    }
+}
+
+impl std::error::Error for InvalidPageCursor {}
+
+impl PageCursor {
+    pub fn new(id: Uuid) -> Self {
+        Self(id)
+    }
+
+    /// Id of the last row of the previous page
+    pub fn id(&self) -> Uuid {
+        self.0
+    }
+
+    pub fn encode(&self) -> String {
+        self.0.simple().to_string()
+    }
+
+    pub fn decode(token: &str) -> Result<Self, InvalidPageCursor> {
+        let token = token.trim();
+        if token.len() != 32 {
+            return Err(InvalidPageCursor(token.to_string()));
+        }
+        Uuid::try_parse(token)
+            .map(Self)
+            .map_err(|_| InvalidPageCursor(token.to_string()))
+    }
+
+    /// Decodes an optional `after_cursor` query parameter.
+    pub fn decode_opt(token: Option<&str>) -> Result<Option<Self>, InvalidPageCursor> {
+        token
+            .filter(|t| !t.trim().is_empty())
+            .map(Self::decode)
+            .transpose()
+    }
+
+    /// Cursor for the page after one that returned `returned` rows ending at
+    /// `last_id`. A short page is the last one, so it gets no cursor.
+    pub fn next(last_id: Option<Uuid>, returned: usize, page_size: i64) -> Option<String> {
+        if page_size <= 0 || (returned as i64) < page_size {
+            return None;
+        }
+        last_id.map(|id| Self(id).encode())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cursor_round_trips() {
+        let id = Uuid::now_v7();
+        let token = PageCursor::new(id).encode();
+        assert_eq!(PageCursor::decode(&token).unwrap().id(), id);
+    }
+
+    #[test]
+    fn test_decode_rejects_garbage_and_hyphenated_ids() {
+        assert!(PageCursor::decode("not-a-cursor").is_err());
+        assert!(PageCursor::decode(&Uuid::now_v7().to_string()).is_err());
+        assert_eq!(PageCursor::decode_opt(Some("  ")), Ok(None));
+    }
+
+    #[test]
+    fn test_next_only_for_full_pages() {
+        let id = Uuid::now_v7();
+        assert_eq!(PageCursor::next(Some(id), 19, 20), None);
+        assert_eq!(
+            PageCursor::next(Some(id), 20, 20),
+            Some(PageCursor::new(id).encode())
+        );
+        assert_eq!(PageCursor::next(None, 0, 0), None);
+    }
+}
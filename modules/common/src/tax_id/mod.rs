@@ -0,0 +1,210 @@
+//! Tax ids (RTN, NIT, RUC...) of customers and vendors.
+//!
+//! Each country's format is checked by a [`TaxIdRule`]; a [`TaxIdPolicy`]
+//! maps ISO country codes to rules and names the country used when a record
+//! does not give its own. Countries without a rule fall back to a loose
+//! alphanumeric check.
+
+mod rules;
+
+pub use rules::{AlphanumericRule, DigitsRule, GuatemalaNitRule, NicaraguaRucRule, TaxIdRule};
+
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::Arc;
+
+use serde::{Deserialize, Serialize};
+
+/// Country used when no default is configured
+pub const DEFAULT_TAX_ID_COUNTRY: &str = "HN";
+
+/// A validated tax id, stored without separators.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TaxId {
+    country: String,
+    value: String,
+}
+
+impl TaxId {
+    /// ISO 3166-1 alpha-2 code of the country the id was validated for
+    pub fn country(&self) -> &str {
+        &self.country
+    }
+
+    pub fn value(&self) -> &str {
+        &self.value
+    }
+}
+
+impl fmt::Display for TaxId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.value)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TaxIdError {
+    /// The country code is not two ASCII letters
+    InvalidCountry(String),
+    /// The id does not match the country's format
+    InvalidFormat { country: String, expected: String },
+}
+
+impl fmt::Display for TaxIdError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TaxIdError::InvalidCountry(code) => {
+                write!(
+                    f,
+                    "Invalid tax id country '{}': expected an ISO code like HN",
+                    code
+                )
+            }
+            TaxIdError::InvalidFormat { country, expected } => {
+                write!(f, "Invalid {} tax id: expected {}", country, expected)
+            }
+        }
+    }
+}
+
+impl std::error::Error for TaxIdError {}
+
+/// Per-country tax id rules plus the default country.
+#[derive(Clone)]
+pub struct TaxIdPolicy {
+    default_country: String,
+    rules: HashMap<String, Arc<dyn TaxIdRule>>,
+}
+
+impl TaxIdPolicy {
+    /// Policy with the built-in Central American rules
+    pub fn new(default_country: &str) -> Self {
+        let policy = Self {
+            default_country: default_country.trim().to_ascii_uppercase(),
+            rules: HashMap::new(),
+        };
+        policy
+            .with_rule(
+                "HN",
+                DigitsRule::new(
+                    &[13, 14],
+                    "13-digit identity number or 14-digit RTN, e.g. 0801-1990-123456",
+                ),
+            )
+            .with_rule(
+                "SV",
+                DigitsRule::new(&[9, 14], "9-digit DUI or 14-digit NIT"),
+            )
+            .with_rule(
+                "CR",
+                DigitsRule::new(&[9, 10, 11, 12], "9 to 12 digits, e.g. 3-101-123456"),
+            )
+            .with_rule("GT", GuatemalaNitRule)
+            .with_rule("NI", NicaraguaRucRule)
+    }
+
+    /// Registers or replaces the rule of a country
+    pub fn with_rule(mut self, country: &str, rule: impl TaxIdRule + 'static) -> Self {
+        self.rules
+            .insert(country.to_ascii_uppercase(), Arc::new(rule));
+        self
+    }
+
+    pub fn default_country(&self) -> &str {
+        &self.default_country
+    }
+
+    /// Validates `raw` against the rule of `country`, or of the default
+    /// country when the record gives none.
+    pub fn parse(&self, raw: &str, country: Option<&str>) -> Result<TaxId, TaxIdError> {
+        let country = match country.map(str::trim).filter(|c| !c.is_empty()) {
+            Some(code) if code.len() == 2 && code.chars().all(|c| c.is_ascii_alphabetic()) => {
+                code.to_ascii_uppercase()
+            }
+            Some(code) => return Err(TaxIdError::InvalidCountry(code.to_string())),
+            None => self.default_country.clone(),
+        };
+
+        let compact: String = raw
+            .chars()
+            .filter(|c| !matches!(c, ' ' | '-' | '.' | '/'))
+            .map(|c| c.to_ascii_uppercase())
+            .collect();
+        let rule: &dyn TaxIdRule = match self.rules.get(&country) {
+            Some(rule) => rule.as_ref(),
+            None => &AlphanumericRule,
+        };
+
+        match rule.normalize(&compact) {
+            Some(value) => Ok(TaxId { country, value }),
+            None => Err(TaxIdError::InvalidFormat {
+                country,
+                expected: rule.expected_format().to_string(),
+            }),
+        }
+    }
+}
+
+impl Default for TaxIdPolicy {
+    fn default() -> Self {
+        Self::new(DEFAULT_TAX_ID_COUNTRY)
+    }
+}
+
+impl fmt::Debug for TaxIdPolicy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut countries: Vec<&String> = self.rules.keys().collect();
+        countries.sort();
+        f.debug_struct("TaxIdPolicy")
+            .field("default_country", &self.default_country)
+            .field("countries", &countries)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_with_default_country_and_strips_separators() {
+        let tax_id = TaxIdPolicy::default()
+            .parse("0801-1990-123456", None)
+            .unwrap();
+        assert_eq!(tax_id.country(), "HN");
+        assert_eq!(tax_id.value(), "08011990123456");
+    }
+
+    #[test]
+    fn record_country_overrides_default() {
+        let policy = TaxIdPolicy::default();
+        assert!(policy.parse("1234567-9", None).is_err());
+
+        let tax_id = policy.parse("1234567-9", Some("gt")).unwrap();
+        assert_eq!(tax_id.country(), "GT");
+        assert_eq!(tax_id.value(), "12345679");
+    }
+
+    #[test]
+    fn rejection_names_expected_format() {
+        let err = TaxIdPolicy::default().parse("12345", None).unwrap_err();
+        assert!(matches!(&err, TaxIdError::InvalidFormat { country, .. } if country == "HN"));
+        assert!(err.to_string().contains("14-digit RTN"));
+
+        assert_eq!(
+            TaxIdPolicy::default().parse("0801199012345", Some("Honduras")),
+            Err(TaxIdError::InvalidCountry("Honduras".to_string()))
+        );
+    }
+
+    #[test]
+    fn unknown_country_uses_fallback_and_rules_are_pluggable() {
+        let policy = TaxIdPolicy::new("us");
+        assert_eq!(policy.default_country(), "US");
+        assert!(policy.parse("12-3456789", None).is_ok());
+
+        let policy = policy.with_rule("US", DigitsRule::new(&[9], "9-digit EIN"));
+        assert!(policy.parse("12-3456789", None).is_ok());
+        assert!(policy.parse("12-34567", None).is_err());
+    }
+}
@@ -0,0 +1,134 @@
+/// Format check for the tax ids of one country.
+///
+/// Rules receive the id with spaces, hyphens and dots already removed and
+/// letters uppercased, and return the form to store, or `None` if the id is
+/// malformed.
+pub trait TaxIdRule: Send + Sync {
+    /// Human-readable format, shown when an id is rejected
+    fn expected_format(&self) -> &str;
+
+    fn normalize(&self, compact: &str) -> Option<String>;
+}
+
+/// Ids made only of digits, with one of the given lengths.
+pub struct DigitsRule {
+    lengths: &'static [usize],
+    format: &'static str,
+}
+
+impl DigitsRule {
+    pub const fn new(lengths: &'static [usize], format: &'static str) -> Self {
+        Self { lengths, format }
+    }
+}
+
+impl TaxIdRule for DigitsRule {
+    fn expected_format(&self) -> &str {
+        self.format
+    }
+
+    fn normalize(&self, compact: &str) -> Option<String> {
+        let valid =
+            compact.chars().all(|c| c.is_ascii_digit()) && self.lengths.contains(&compact.len());
+        valid.then(|| compact.to_string())
+    }
+}
+
+/// Guatemalan NIT: digits followed by a modulo-11 check digit, where a
+/// remainder of 10 is written as `K`.
+pub struct GuatemalaNitRule;
+
+impl TaxIdRule for GuatemalaNitRule {
+    fn expected_format(&self) -> &str {
+        "2 to 12 digits followed by a check digit (0-9 or K), e.g. 1234567-9"
+    }
+
+    fn normalize(&self, compact: &str) -> Option<String> {
+        if !(3..=13).contains(&compact.len()) {
+            return None;
+        }
+        let (body, check) = compact.split_at(compact.len() - 1);
+        if !body.chars().all(|c| c.is_ascii_digit()) {
+            return None;
+        }
+
+        let weighted: u32 = body
+            .chars()
+            .rev()
+            .zip(2..)
+            .map(|(c, weight)| c.to_digit(10).unwrap_or(0) * weight)
+            .sum();
+        let expected = match (11 - weighted % 11) % 11 {
+            10 => 'K',
+            digit => char::from_digit(digit, 10)?,
+        };
+        (check.starts_with(expected)).then(|| compact.to_string())
+    }
+}
+
+/// Nicaraguan RUC: the 13 digits of the cédula followed by a letter.
+pub struct NicaraguaRucRule;
+
+impl TaxIdRule for NicaraguaRucRule {
+    fn expected_format(&self) -> &str {
+        "13 digits followed by a letter, e.g. 001-010190-0001A"
+    }
+
+    fn normalize(&self, compact: &str) -> Option<String> {
+        let (digits, letter) = compact.split_at(compact.len().checked_sub(1)?);
+        let valid = digits.len() == 13
+            && digits.chars().all(|c| c.is_ascii_digit())
+            && letter.chars().all(|c| c.is_ascii_uppercase());
+        valid.then(|| compact.to_string())
+    }
+}
+
+/// Fallback for countries without a registered rule: 5 to 20 letters or
+/// digits.
+pub struct AlphanumericRule;
+
+impl TaxIdRule for AlphanumericRule {
+    fn expected_format(&self) -> &str {
+        "5 to 20 letters or digits"
+    }
+
+    fn normalize(&self, compact: &str) -> Option<String> {
+        let valid =
+            (5..=20).contains(&compact.len()) && compact.chars().all(|c| c.is_ascii_alphanumeric());
+        valid.then(|| compact.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn digits_rule_checks_length_and_digits() {
+        let rule = DigitsRule::new(&[13, 14], "13 or 14 digits");
+        assert_eq!(
+            rule.normalize("08011990123456"),
+            Some("08011990123456".to_string())
+        );
+        assert!(rule.normalize("0801199012345").is_some());
+        assert!(rule.normalize("080119901234").is_none());
+        assert!(rule.normalize("0801199012345A").is_none());
+    }
+
+    #[test]
+    fn guatemala_nit_verifies_check_digit() {
+        // 1234567: 7*2 + 6*3 + 5*4 + 4*5 + 3*6 + 2*7 + 1*8 = 112, 11 - 112 % 11 = 9
+        assert!(GuatemalaNitRule.normalize("12345679").is_some());
+        assert!(GuatemalaNitRule.normalize("12345678").is_none());
+        // 06: 6*2 + 0*3 = 12, 11 - 12 % 11 = 10, written as K
+        assert!(GuatemalaNitRule.normalize("06K").is_some());
+        assert!(GuatemalaNitRule.normalize("060").is_none());
+    }
+
+    #[test]
+    fn nicaragua_ruc_needs_trailing_letter() {
+        assert!(NicaraguaRucRule.normalize("0010101900001A").is_some());
+        assert!(NicaraguaRucRule.normalize("00101019000011").is_none());
+        assert!(NicaraguaRucRule.normalize("").is_none());
+    }
+}
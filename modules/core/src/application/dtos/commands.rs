@@ -55,6 +55,8 @@ pub struct ListStoresQuery {
     pub page: Option<u32>,
     /// Number of items per page (defaults to 20, max 100)
     pub page_size: Option<u32>,
+    /// `next_cursor` of a previous page; when set, `page` is ignored
+    pub after_cursor: Option<String>,
     /// Restrict results to these store IDs (set by access control, not by the client).
     #[serde(skip)]
     pub user_store_ids: Option<Vec<Uuid>>,
@@ -69,4 +71,18 @@ pub struct ListTerminalsQuery {
     pub page: Option<u32>,
     /// Number of items per page (defaults to 20, max 100)
     pub page_size: Option<u32>,
+    /// `next_cursor` of a previous page; when set, `page` is ignored
+    pub after_cursor: Option<String>,
+}
+
+/// Query parameters for listing CAI ranges close to running out
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct ListCaisNearExhaustionQuery {
+    /// Flag ranges with less than this percentage of numbers left (defaults to 10)
+    pub remaining_percent: Option<i64>,
+    /// Flag ranges expiring within this many days (defaults to 30)
+    pub expiry_days: Option<i64>,
+    /// Restrict results to these store IDs (set by access control, not by the client).
+    #[serde(skip)]
+    pub user_store_ids: Option<Vec<Uuid>>,
 }
@@ -85,8 +85,12 @@ pub struct CaiStatusResponse {
     pub current_number: i64,
     /// Number of remaining invoices in the range
     pub remaining: i64,
+    /// Share of the range still unused, as a whole percentage
+    pub remaining_percent: i64,
     /// Expiration date of the CAI
     pub expiration_date: NaiveDate,
+    /// Days left until the CAI expires (negative once expired)
+    pub days_until_expiry: i64,
     /// Whether the range is exhausted
     pub is_exhausted: bool,
     /// Warning message if CAI expires within 30 days
@@ -108,7 +112,9 @@ impl From<&CaiRange> for CaiStatusResponse {
             cai_number: cai.cai_number().as_str().to_string(),
             current_number: cai.current_number(),
             remaining: cai.remaining(),
+            remaining_percent: cai.remaining_percent(),
             expiration_date: cai.expiration_date(),
+            days_until_expiry: cai.days_until_expiry(),
             is_exhausted: cai.is_exhausted_flag(),
             expiration_warning,
         }
@@ -121,6 +127,36 @@ impl From<CaiRange> for CaiStatusResponse {
     }
 }
 
+/// Response DTO for an active CAI range that is running out of numbers or
+/// close to expiring
+#[derive(Debug, Clone, Serialize)]
+pub struct CaiNearExhaustionResponse {
+    /// Terminal the range belongs to
+    pub terminal_id: Uuid,
+    /// Store the terminal belongs to
+    pub store_id: Uuid,
+    /// Code of the terminal within its store
+    pub terminal_code: String,
+    /// Human-readable name of the terminal
+    pub terminal_name: String,
+    /// The CAI number from the fiscal authority
+    pub cai_number: String,
+    /// Last invoice number of the range
+    pub range_end: i64,
+    /// Number of remaining invoices in the range
+    pub remaining: i64,
+    /// Share of the range still unused, as a whole percentage
+    pub remaining_percent: i64,
+    /// Expiration date of the CAI
+    pub expiration_date: NaiveDate,
+    /// Days left until the CAI expires
+    pub days_until_expiry: i64,
+    /// Whether the range is below the remaining-percentage threshold
+    pub near_exhaustion: bool,
+    /// Whether the CAI expires within the days threshold
+    pub near_expiry: bool,
+}
+
 /// Response DTO for next invoice number operation
 #[derive(Debug, Clone, Serialize)]
 pub struct NextInvoiceNumberResponse {
@@ -187,6 +223,8 @@ pub struct PaginatedStoresResponse {
     pub page_size: u32,
     /// Total number of pages
     pub total_pages: u32,
+    /// Pass back as `after_cursor` for the next page; `None` on the last page
+    pub next_cursor: Option<String>,
 }
 
 /// Paginated response for terminal listing
@@ -202,6 +240,8 @@ pub struct PaginatedTerminalsResponse {
     pub page_size: u32,
     /// Total number of pages
     pub total_pages: u32,
+    /// Pass back as `after_cursor` for the next page; `None` on the last page
+    pub next_cursor: Option<String>,
 }
 
 /// Response DTO for terminal detail with CAI history
@@ -1,6 +1,6 @@
 // GetCaiStatusUseCase - Gets the current CAI status for a terminal
 //
-// - Return current CAI status
+// - Return current CAI status, remaining count and days until expiry
 // - Include warning if CAI expires within 30 days
 
 use std::sync::Arc;
@@ -14,8 +14,8 @@ use crate::error::CoreError;
 ///
 /// This use case retrieves the current CAI status including:
 /// - Current invoice number
-/// - Remaining invoices in the range
-/// - Expiration date
+/// - Remaining invoices in the range, also as a percentage
+/// - Expiration date and days until it
 /// - Whether the range is exhausted
 /// - Warning if CAI expires within 30 days
 pub struct GetCaiStatusUseCase<T>
@@ -0,0 +1,317 @@
+// ListCaisNearExhaustionUseCase - Lists CAI ranges that need replacing soon
+//
+// - Flag active ranges below a remaining-percentage threshold
+// - Flag active ranges expiring within a number of days
+// - Include the terminal each flagged range belongs to
+
+use std::sync::Arc;
+
+use identity::StoreId;
+
+use crate::domain::repositories::TerminalRepository;
+use crate::error::CoreError;
+use crate::{CaiNearExhaustionResponse, ListCaisNearExhaustionQuery};
+
+/// Default remaining-percentage below which a range is flagged
+const DEFAULT_REMAINING_PERCENT: i64 = 10;
+
+/// Default number of days before expiry at which a range is flagged
+const DEFAULT_EXPIRY_DAYS: i64 = 30;
+
+/// Use case for listing CAI ranges close to running out
+///
+/// Looks at the current CAI range of every active terminal and flags those
+/// with less than `remaining_percent` of their numbers left or expiring
+/// within `expiry_days`, so new ranges can be requested from the tax
+/// authority before sales get blocked. Results are ordered by urgency:
+/// fewest days to expiry first, then fewest numbers left.
+pub struct ListCaisNearExhaustionUseCase<T>
+where
+    T: TerminalRepository,
+{
+    terminal_repo: Arc<T>,
+}
+
+impl<T> ListCaisNearExhaustionUseCase<T>
+where
+    T: TerminalRepository,
+{
+    /// Creates a new instance of ListCaisNearExhaustionUseCase
+    pub fn new(terminal_repo: Arc<T>) -> Self {
+        Self { terminal_repo }
+    }
+
+    /// Executes the use case to list the at-risk CAI ranges
+    ///
+    /// # Arguments
+    /// * `query` - Thresholds and store restriction
+    ///
+    /// # Returns
+    /// * `Ok(Vec<CaiNearExhaustionResponse>)` - The flagged ranges (may be empty)
+    /// * `Err(CoreError::InvalidCaiThreshold)` - If a threshold is out of range
+    pub async fn execute(
+        &self,
+        query: ListCaisNearExhaustionQuery,
+    ) -> Result<Vec<CaiNearExhaustionResponse>, CoreError> {
+        let remaining_percent = query.remaining_percent.unwrap_or(DEFAULT_REMAINING_PERCENT);
+        if !(0..=100).contains(&remaining_percent) {
+            return Err(CoreError::InvalidCaiThreshold(
+                "remaining_percent must be between 0 and 100".to_string(),
+            ));
+        }
+        let expiry_days = query.expiry_days.unwrap_or(DEFAULT_EXPIRY_DAYS);
+        if expiry_days < 0 {
+            return Err(CoreError::InvalidCaiThreshold(
+                "expiry_days must not be negative".to_string(),
+            ));
+        }
+
+        let store_ids: Option<Vec<StoreId>> = query
+            .user_store_ids
+            .map(|ids| ids.into_iter().map(StoreId::from_uuid).collect());
+        let terminals = self
+            .terminal_repo
+            .find_active_with_cai(store_ids.as_deref())
+            .await?;
+
+        let mut flagged: Vec<CaiNearExhaustionResponse> = terminals
+            .iter()
+            .filter_map(|terminal| {
+                let cai = terminal.current_cai()?;
+                let near_exhaustion = cai.remaining_percent() < remaining_percent;
+                let near_expiry = cai.expires_within_days(expiry_days);
+                (near_exhaustion || near_expiry).then(|| CaiNearExhaustionResponse {
+                    terminal_id: terminal.id().into_uuid(),
+                    store_id: terminal.store_id().into_uuid(),
+                    terminal_code: terminal.code().as_str().to_string(),
+                    terminal_name: terminal.name().to_string(),
+                    cai_number: cai.cai_number().as_str().to_string(),
+                    range_end: cai.range_end(),
+                    remaining: cai.remaining(),
+                    remaining_percent: cai.remaining_percent(),
+                    expiration_date: cai.expiration_date(),
+                    days_until_expiry: cai.days_until_expiry(),
+                    near_exhaustion,
+                    near_expiry,
+                })
+            })
+            .collect();
+
+        flagged.sort_by_key(|r| (r.days_until_expiry, r.remaining));
+        Ok(flagged)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use async_trait::async_trait;
+    use chrono::{Duration, Utc};
+    use uuid::{NoContext, Timestamp, Uuid};
+
+    use crate::domain::entities::{CaiRange, Terminal};
+    use crate::domain::value_objects::{CaiNumber, TerminalCode, TerminalId};
+
+    struct MockTerminalRepository {
+        terminals: Vec<Terminal>,
+    }
+
+    #[async_trait]
+    impl TerminalRepository for MockTerminalRepository {
+        async fn save(&self, _terminal: &Terminal) -> Result<(), CoreError> {
+            unimplemented!()
+        }
+
+        async fn find_by_id(&self, _id: TerminalId) -> Result<Option<Terminal>, CoreError> {
+            unimplemented!()
+        }
+
+        async fn find_by_store(&self, _store_id: StoreId) -> Result<Vec<Terminal>, CoreError> {
+            unimplemented!()
+        }
+
+        async fn find_by_code(
+            &self,
+            _store_id: StoreId,
+            _code: &TerminalCode,
+        ) -> Result<Option<Terminal>, CoreError> {
+            unimplemented!()
+        }
+
+        async fn update(&self, _terminal: &Terminal) -> Result<(), CoreError> {
+            unimplemented!()
+        }
+
+        async fn save_cai_range(
+            &self,
+            _terminal_id: TerminalId,
+            _cai: &CaiRange,
+        ) -> Result<(), CoreError> {
+            unimplemented!()
+        }
+
+        async fn get_cai_history(
+            &self,
+            _terminal_id: TerminalId,
+        ) -> Result<Vec<CaiRange>, CoreError> {
+            unimplemented!()
+        }
+
+        async fn increment_and_get_invoice_number(
+            &self,
+            _terminal_id: TerminalId,
+        ) -> Result<i64, CoreError> {
+            unimplemented!()
+        }
+
+        async fn find_active_with_cai(
+            &self,
+            store_ids: Option<&[StoreId]>,
+        ) -> Result<Vec<Terminal>, CoreError> {
+            Ok(self
+                .terminals
+                .iter()
+                .filter(|t| t.current_cai().is_some())
+                .filter(|t| store_ids.is_none_or(|ids| ids.contains(&t.store_id())))
+                .cloned()
+                .collect())
+        }
+
+        async fn count_active_by_store(&self, _store_id: StoreId) -> Result<i64, CoreError> {
+            unimplemented!()
+        }
+
+        async fn deactivate_by_store(&self, _store_id: StoreId) -> Result<(), CoreError> {
+            unimplemented!()
+        }
+    }
+
+    /// A terminal whose CAI covers 1..=100 with `current` as the next number,
+    /// expiring `expires_in_days` from today
+    fn terminal(code: &str, current: i64, expires_in_days: i64) -> Terminal {
+        let mut terminal = Terminal::create(
+            StoreId::new(),
+            TerminalCode::new(code).unwrap(),
+            format!("Terminal {}", code),
+        );
+        terminal.set_cai(CaiRange::new(
+            Uuid::new_v7(Timestamp::now(NoContext)),
+            CaiNumber::new(&format!("CAI-{}", code)).unwrap(),
+            1,
+            100,
+            current,
+            Utc::now().date_naive() + Duration::days(expires_in_days),
+            false,
+            Utc::now(),
+        ));
+        terminal
+    }
+
+    async fn list(
+        terminals: Vec<Terminal>,
+        remaining_percent: Option<i64>,
+        expiry_days: Option<i64>,
+    ) -> Vec<CaiNearExhaustionResponse> {
+        let use_case =
+            ListCaisNearExhaustionUseCase::new(Arc::new(MockTerminalRepository { terminals }));
+        use_case
+            .execute(ListCaisNearExhaustionQuery {
+                remaining_percent,
+                expiry_days,
+                user_store_ids: None,
+            })
+            .await
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_flags_exhausted_range() {
+        let flagged = list(vec![terminal("T-001", 101, 365)], None, None).await;
+
+        assert_eq!(flagged.len(), 1);
+        assert_eq!(flagged[0].remaining, 0);
+        assert_eq!(flagged[0].remaining_percent, 0);
+        assert!(flagged[0].near_exhaustion);
+        assert!(!flagged[0].near_expiry);
+    }
+
+    #[tokio::test]
+    async fn test_flags_range_expiring_today() {
+        let flagged = list(vec![terminal("T-001", 1, 0)], None, Some(0)).await;
+
+        assert_eq!(flagged.len(), 1);
+        assert_eq!(flagged[0].days_until_expiry, 0);
+        assert!(flagged[0].near_expiry);
+        assert!(!flagged[0].near_exhaustion);
+    }
+
+    #[tokio::test]
+    async fn test_flags_already_expired_range() {
+        let flagged = list(vec![terminal("T-001", 1, -3)], None, Some(0)).await;
+
+        assert_eq!(flagged.len(), 1);
+        assert_eq!(flagged[0].days_until_expiry, -3);
+        assert!(flagged[0].near_expiry);
+    }
+
+    #[tokio::test]
+    async fn test_remaining_percent_threshold_is_exclusive() {
+        // 10 of 100 left is exactly the threshold; 9 of 100 is below it
+        let at_threshold = terminal("T-001", 91, 365);
+        let below_threshold = terminal("T-002", 92, 365);
+
+        let flagged = list(vec![at_threshold, below_threshold], Some(10), Some(30)).await;
+
+        assert_eq!(flagged.len(), 1);
+        assert_eq!(flagged[0].terminal_code, "T-002");
+        assert_eq!(flagged[0].remaining_percent, 9);
+    }
+
+    #[tokio::test]
+    async fn test_expiry_days_threshold_is_inclusive() {
+        let on_boundary = terminal("T-001", 1, 30);
+        let past_boundary = terminal("T-002", 1, 31);
+
+        let flagged = list(vec![on_boundary, past_boundary], Some(10), Some(30)).await;
+
+        assert_eq!(flagged.len(), 1);
+        assert_eq!(flagged[0].terminal_code, "T-001");
+    }
+
+    #[tokio::test]
+    async fn test_orders_by_days_until_expiry_then_remaining() {
+        let later = terminal("T-001", 95, 20);
+        let sooner_more_left = terminal("T-002", 50, 5);
+        let sooner_less_left = terminal("T-003", 99, 5);
+
+        let flagged = list(vec![later, sooner_more_left, sooner_less_left], None, None).await;
+
+        let codes: Vec<&str> = flagged.iter().map(|r| r.terminal_code.as_str()).collect();
+        assert_eq!(codes, vec!["T-003", "T-002", "T-001"]);
+    }
+
+    #[tokio::test]
+    async fn test_rejects_out_of_range_thresholds() {
+        let use_case = ListCaisNearExhaustionUseCase::new(Arc::new(MockTerminalRepository {
+            terminals: vec![],
+        }));
+
+        let result = use_case
+            .execute(ListCaisNearExhaustionQuery {
+                remaining_percent: Some(101),
+                expiry_days: None,
+                user_store_ids: None,
+            })
+            .await;
+        assert!(matches!(result, Err(CoreError::InvalidCaiThreshold(_))));
+
+        let result = use_case
+            .execute(ListCaisNearExhaustionQuery {
+                remaining_percent: None,
+                expiry_days: Some(-1),
+                user_store_ids: None,
+            })
+            .await;
+        assert!(matches!(result, Err(CoreError::InvalidCaiThreshold(_))));
+    }
+}
@@ -5,7 +5,8 @@
 
 use std::sync::Arc;
 
-use identity::StoreRepository;
+use common::PageCursor;
+use identity::{StoreId, StoreRepository};
 
 use crate::application::dtos::{ListStoresQuery, PaginatedStoresResponse, StoreListItemResponse};
 use crate::error::CoreError;
@@ -52,6 +53,9 @@ where
             .page_size
             .unwrap_or(DEFAULT_PAGE_SIZE)
             .clamp(1, MAX_PAGE_SIZE);
+        let after = PageCursor::decode_opt(query.after_cursor.as_deref())?;
+        // A cursor page has no meaningful number
+        let page = if after.is_some() { 1 } else { page };
 
         // Delegate filtering and pagination to the repository
         let (stores, total) = self
@@ -62,11 +66,17 @@ where
                 page as i64,
                 page_size as i64,
                 query.user_store_ids.as_deref(),
+                after.map(|c| StoreId::from_uuid(c.id())),
             )
             .await
             .map_err(|e| CoreError::Database(sqlx::Error::Protocol(e.to_string())))?;
 
         let total_pages = ((total as f64) / (page_size as f64)).ceil() as u32;
+        let next_cursor = PageCursor::next(
+            stores.last().map(|s| s.id().into_uuid()),
+            stores.len(),
+            page_size as i64,
+        );
 
         let items: Vec<StoreListItemResponse> = stores
             .into_iter()
@@ -87,6 +97,7 @@ where
             page,
             page_size,
             total_pages,
+            next_cursor,
         })
     }
 }
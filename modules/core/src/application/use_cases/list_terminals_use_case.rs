@@ -4,6 +4,7 @@
 
 use std::sync::Arc;
 
+use common::PageCursor;
 use identity::StoreId;
 
 use crate::application::dtos::{ListTerminalsQuery, PaginatedTerminalsResponse, TerminalResponse};
@@ -49,13 +50,16 @@ where
         store_id: StoreId,
         query: ListTerminalsQuery,
     ) -> Result<PaginatedTerminalsResponse, CoreError> {
-        // 1. Fetch all terminals for the store
+        let after = PageCursor::decode_opt(query.after_cursor.as_deref())?;
+
+        // 1. Fetch all terminals for the store, ordered by code
         let all_terminals = self.terminal_repo.find_by_store(store_id).await?;
 
-        // 2. Apply filters
+        // 2. Apply filters, keeping each terminal's place in the full list
         let filtered_terminals: Vec<_> = all_terminals
-            .into_iter()
-            .filter(|terminal| {
+            .iter()
+            .enumerate()
+            .filter(|(_, terminal)| {
                 // Filter by is_active if specified
                 if let Some(is_active) = query.is_active
                     && terminal.is_active() != is_active
@@ -66,9 +70,22 @@ where
             })
             .collect();
 
+        // With a cursor, the page starts right after the cursor terminal's
+        // place in the store's list, whatever the filters
+        let cursor_position = after.map(|c| {
+            all_terminals
+                .iter()
+                .position(|t| t.id().into_uuid() == c.id())
+                .map_or(all_terminals.len(), |index| index + 1)
+        });
+
         // 3. Calculate pagination
         let total = filtered_terminals.len() as i64;
-        let page = query.page.unwrap_or(1).max(1);
+        // A cursor page has no meaningful number
+        let page = match after {
+            Some(_) => 1,
+            None => query.page.unwrap_or(1).max(1),
+        };
         let page_size = query
             .page_size
             .unwrap_or(DEFAULT_PAGE_SIZE)
@@ -80,11 +97,28 @@ where
         };
 
         // 4. Apply pagination and convert to response DTOs
-        let skip = ((page - 1) * page_size) as usize;
-        let items: Vec<TerminalResponse> = filtered_terminals
+        let page_terminals: Vec<_> = match cursor_position {
+            Some(position) => filtered_terminals
+                .into_iter()
+                .skip_while(|(index, _)| *index < position)
+                .map(|(_, terminal)| terminal)
+                .take(page_size as usize)
+                .collect(),
+            None => filtered_terminals
+                .into_iter()
+                .map(|(_, terminal)| terminal)
+                .skip(((page - 1) * page_size) as usize)
+                .take(page_size as usize)
+                .collect(),
+        };
+        let next_cursor = PageCursor::next(
+            page_terminals.last().map(|t| t.id().into_uuid()),
+            page_terminals.len(),
+            page_size as i64,
+        );
+        let items: Vec<TerminalResponse> = page_terminals
             .into_iter()
-            .skip(skip)
-            .take(page_size as usize)
+            .cloned()
             .map(TerminalResponse::from)
             .collect();
 
@@ -94,6 +128,7 @@ where
             page,
             page_size,
             total_pages,
+            next_cursor,
         })
     }
 }
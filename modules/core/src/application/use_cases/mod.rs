@@ -6,6 +6,7 @@ mod get_cai_status_use_case;
 mod get_next_invoice_number_use_case;
 mod get_store_detail_use_case;
 mod get_terminal_detail_use_case;
+mod list_cais_near_exhaustion_use_case;
 mod list_stores_use_case;
 mod list_terminals_use_case;
 mod set_store_active_use_case;
@@ -18,6 +19,7 @@ pub use get_cai_status_use_case::GetCaiStatusUseCase;
 pub use get_next_invoice_number_use_case::GetNextInvoiceNumberUseCase;
 pub use get_store_detail_use_case::GetStoreDetailUseCase;
 pub use get_terminal_detail_use_case::GetTerminalDetailUseCase;
+pub use list_cais_near_exhaustion_use_case::ListCaisNearExhaustionUseCase;
 pub use list_stores_use_case::ListStoresUseCase;
 pub use list_terminals_use_case::ListTerminalsUseCase;
 pub use set_store_active_use_case::SetStoreActiveUseCaseExtended;
@@ -57,6 +57,22 @@ impl CaiRange {
         }
     }
 
+    /// Returns the number of invoice numbers the range was authorized for
+    pub fn size(&self) -> i64 {
+        self.range_end - self.range_start + 1
+    }
+
+    /// Returns the share of the range still unused, as a whole percentage
+    /// rounded down
+    pub fn remaining_percent(&self) -> i64 {
+        self.remaining() * 100 / self.size()
+    }
+
+    /// Returns the days left until the CAI expires; negative once expired
+    pub fn days_until_expiry(&self) -> i64 {
+        (self.expiration_date - Utc::now().date_naive()).num_days()
+    }
+
     /// Checks if the CAI has expired based on current date
     pub fn is_expired(&self) -> bool {
         Utc::now().date_naive() > self.expiration_date
@@ -131,3 +147,66 @@ impl CaiRange {
         self.current_number - 1 // Return the number that was used
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Duration;
+
+    fn range(start: i64, end: i64, current: i64, expires_in_days: i64) -> CaiRange {
+        CaiRange::new(
+            Uuid::new_v7(uuid::Timestamp::now(uuid::NoContext)),
+            CaiNumber::new("CAI-TEST-0001").unwrap(),
+            start,
+            end,
+            current,
+            Utc::now().date_naive() + Duration::days(expires_in_days),
+            false,
+            Utc::now(),
+        )
+    }
+
+    #[test]
+    fn test_size_counts_both_ends_of_the_range() {
+        assert_eq!(range(1, 100, 1, 30).size(), 100);
+        assert_eq!(range(501, 1000, 501, 30).size(), 500);
+        assert_eq!(range(7, 7, 7, 30).size(), 1);
+    }
+
+    #[test]
+    fn test_remaining_percent_of_a_fresh_range_is_100() {
+        assert_eq!(range(1, 100, 1, 30).remaining_percent(), 100);
+    }
+
+    #[test]
+    fn test_remaining_percent_rounds_down() {
+        // 91..=100 left: 10 of 100
+        assert_eq!(range(1, 100, 91, 30).remaining_percent(), 10);
+        // 92..=100 left: 9 of 100
+        assert_eq!(range(1, 100, 92, 30).remaining_percent(), 9);
+        // 1 of 3 left is 33.3%
+        assert_eq!(range(1, 3, 3, 30).remaining_percent(), 33);
+    }
+
+    #[test]
+    fn test_remaining_percent_of_an_exhausted_range_is_0() {
+        let cai = range(1, 100, 101, 30);
+        assert_eq!(cai.remaining(), 0);
+        assert_eq!(cai.remaining_percent(), 0);
+    }
+
+    #[test]
+    fn test_days_until_expiry() {
+        assert_eq!(range(1, 100, 1, 30).days_until_expiry(), 30);
+        assert_eq!(range(1, 100, 1, 0).days_until_expiry(), 0);
+        assert_eq!(range(1, 100, 1, -5).days_until_expiry(), -5);
+    }
+
+    #[test]
+    fn test_range_expiring_today_is_not_yet_expired() {
+        let cai = range(1, 100, 1, 0);
+        assert!(!cai.is_expired());
+        assert!(cai.expires_within_days(0));
+        assert!(range(1, 100, 1, -1).is_expired());
+    }
+}
@@ -123,6 +123,19 @@ pub trait TerminalRepository: Send + Sync {
         terminal_id: TerminalId,
     ) -> Result<i64, CoreError>;
 
+    /// Finds the active terminals that have a current CAI range assigned
+    ///
+    /// # Arguments
+    /// * `store_ids` - Restricts the search to these stores; `None` searches all stores
+    ///
+    /// # Returns
+    /// * `Ok(Vec<Terminal>)` - Terminals with their current CAI (may be empty)
+    /// * `Err(CoreError)` - If there was a database error
+    async fn find_active_with_cai(
+        &self,
+        store_ids: Option<&[StoreId]>,
+    ) -> Result<Vec<Terminal>, CoreError>;
+
     /// Counts the number of active terminals for a store
     ///
     /// # Arguments
@@ -42,9 +42,15 @@ pub enum CoreError {
     #[error("Invalid CAI range: start must be <= end")]
     InvalidCaiRange,
 
+    #[error("Invalid CAI warning threshold: {0}")]
+    InvalidCaiThreshold(String),
+
     #[error("Unauthorized: requires super_admin role")]
     Unauthorized,
 
+    #[error(transparent)]
+    InvalidPageCursor(#[from] common::InvalidPageCursor),
+
     #[error("Database error: {0}")]
     Database(#[from] sqlx::Error),
 }
@@ -353,6 +353,53 @@ impl TerminalRepository for PgTerminalRepository {
         Ok(invoice_number)
     }
 
+    async fn find_active_with_cai(
+        &self,
+        store_ids: Option<&[StoreId]>,
+    ) -> Result<Vec<Terminal>, CoreError> {
+        let store_ids: Option<Vec<Uuid>> =
+            store_ids.map(|ids| ids.iter().map(|id| id.into_uuid()).collect());
+
+        let rows = sqlx::query_as::<_, TerminalRow>(
+            r#"
+            SELECT t.id, t.store_id, t.code, t.name, t.is_active, t.created_at, t.updated_at
+            FROM terminals t
+            WHERE t.is_active = TRUE
+              AND ($1::uuid[] IS NULL OR t.store_id = ANY($1))
+              AND EXISTS (
+                  SELECT 1 FROM cai_ranges c
+                  WHERE c.terminal_id = t.id
+                    AND c.is_exhausted = FALSE
+                    AND c.expiration_date >= CURRENT_DATE
+              )
+            ORDER BY t.store_id, t.code
+            "#,
+        )
+        .bind(store_ids)
+        .fetch_all(self.pool.as_ref())
+        .await?;
+
+        let mut terminals = Vec::with_capacity(rows.len());
+        for row in rows {
+            let terminal_id = TerminalId::from_uuid(row.id);
+            let cai = self.get_current_cai(terminal_id).await?;
+            let terminal_code = TerminalCode::new(&row.code)?;
+
+            terminals.push(Terminal::reconstitute(
+                terminal_id,
+                StoreId::from_uuid(row.store_id),
+                terminal_code,
+                row.name,
+                row.is_active,
+                cai,
+                row.created_at,
+                row.updated_at,
+            ));
+        }
+
+        Ok(terminals)
+    }
+
     async fn count_active_by_store(&self, store_id: StoreId) -> Result<i64, CoreError> {
         let count = sqlx::query_scalar::<_, i64>(
             r#"
@@ -43,8 +43,9 @@ pub use application::dtos::*;
 // -----------------------------------------------------------------------------
 pub use application::use_cases::{
     AssignCaiUseCase, CreateTerminalUseCase, GetCaiStatusUseCase, GetNextInvoiceNumberUseCase,
-    GetStoreDetailUseCase, GetTerminalDetailUseCase, ListStoresUseCase, ListTerminalsUseCase,
-    SetStoreActiveUseCaseExtended, SetTerminalActiveUseCase, UpdateTerminalUseCase,
+    GetStoreDetailUseCase, GetTerminalDetailUseCase, ListCaisNearExhaustionUseCase,
+    ListStoresUseCase, ListTerminalsUseCase, SetStoreActiveUseCaseExtended,
+    SetTerminalActiveUseCase, UpdateTerminalUseCase,
 };
 
 // -----------------------------------------------------------------------------
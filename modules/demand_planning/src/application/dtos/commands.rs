@@ -31,6 +31,14 @@ pub struct ApproveSuggestionCommand {
     pub product_id: Uuid,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GenerateReplenishmentOrdersCommand {
+    /// Date the generated POs will use as `order_date` (YYYY-MM-DD).
+    pub order_date: String,
+    /// Optional expected delivery date for every generated PO (YYYY-MM-DD).
+    pub expected_delivery_date: Option<String>,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct DismissSuggestionCommand {
     pub reason: String,
@@ -2,9 +2,11 @@ mod commands;
 mod responses;
 
 pub use commands::{
-    ApproveSuggestionCommand, DismissSuggestionCommand, UpsertReorderPolicyCommand,
+    ApproveSuggestionCommand, DismissSuggestionCommand, GenerateReplenishmentOrdersCommand,
+    UpsertReorderPolicyCommand,
 };
 pub use responses::{
-    AbcClassificationResponse, DemandForecastResponse, ReorderPolicyResponse,
-    ReplenishmentSuggestionResponse,
+    AbcClassificationResponse, DemandForecastResponse, ProductAvailabilityForecastResponse,
+    ReorderPolicyResponse, ReplenishmentOrdersResponse, ReplenishmentSuggestionResponse,
+    SalesVelocityResponse, UnassignedReplenishmentItem, VelocityWindowResponse,
 };
@@ -1,12 +1,16 @@
 use chrono::{DateTime, NaiveDate, Utc};
+use purchasing::PurchaseOrderDetailResponse;
 use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
 use crate::domain::entities::{
-    AbcClassification, DemandForecast, ReorderPolicy, ReplenishmentSuggestion,
+    AbcClassification, AvailabilityForecast, DemandForecast, ProjectedStockPoint, ReorderPolicy,
+    ReplenishmentSuggestion, SalesVelocity,
+};
+use crate::domain::value_objects::{
+    AbcClass, ForecastMethod, ForecastPeriod, SuggestionStatus, VelocityTrend,
 };
-use crate::domain::value_objects::{AbcClass, ForecastMethod, ForecastPeriod, SuggestionStatus};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct DemandForecastResponse {
@@ -141,3 +145,101 @@ impl From<&AbcClassification> for AbcClassificationResponse {
         }
     }
 }
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct VelocityWindowResponse {
+    pub days: i64,
+    pub units: Decimal,
+    pub units_per_day: Decimal,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SalesVelocityResponse {
+    pub product_variant_id: Uuid,
+    /// `None` when the figures aggregate every store.
+    pub store_id: Option<Uuid>,
+    pub short: VelocityWindowResponse,
+    pub medium: VelocityWindowResponse,
+    pub long: VelocityWindowResponse,
+    pub trend: VelocityTrend,
+}
+
+impl From<&SalesVelocity> for SalesVelocityResponse {
+    fn from(v: &SalesVelocity) -> Self {
+        let w = v.windows();
+        Self {
+            product_variant_id: v.product_variant_id(),
+            store_id: v.store_id(),
+            short: VelocityWindowResponse {
+                days: w.short_days,
+                units: v.short_units(),
+                units_per_day: v.short_per_day(),
+            },
+            medium: VelocityWindowResponse {
+                days: w.medium_days,
+                units: v.medium_units(),
+                units_per_day: v.medium_per_day(),
+            },
+            long: VelocityWindowResponse {
+                days: w.long_days,
+                units: v.long_units(),
+                units_per_day: v.long_per_day(),
+            },
+            trend: v.trend(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProductAvailabilityForecastResponse {
+    pub product_variant_id: Uuid,
+    pub store_id: Uuid,
+    pub as_of: NaiveDate,
+    pub available_qty: Decimal,
+    pub daily_demand: Decimal,
+    pub horizon_days: i64,
+    /// `None` when stock lasts the whole horizon.
+    pub stockout_date: Option<NaiveDate>,
+    pub days_of_cover: Option<Decimal>,
+    pub next_delivery_date: Option<NaiveDate>,
+    pub stocks_out_before_next_delivery: bool,
+    pub projection: Vec<ProjectedStockPoint>,
+}
+
+impl From<&AvailabilityForecast> for ProductAvailabilityForecastResponse {
+    fn from(f: &AvailabilityForecast) -> Self {
+        Self {
+            product_variant_id: f.product_variant_id(),
+            store_id: f.store_id(),
+            as_of: f.as_of(),
+            available_qty: f.available_qty(),
+            daily_demand: f.daily_demand(),
+            horizon_days: f.horizon_days(),
+            stockout_date: f.stockout_date(),
+            days_of_cover: f.days_of_cover(),
+            next_delivery_date: f.next_delivery_date(),
+            stocks_out_before_next_delivery: f.stocks_out_before_next_delivery(),
+            projection: f.points().to_vec(),
+        }
+    }
+}
+
+/// Draft purchase orders created from a store's low-stock alerts.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReplenishmentOrdersResponse {
+    pub store_id: Uuid,
+    /// One draft PO per preferred vendor.
+    pub purchase_orders: Vec<PurchaseOrderDetailResponse>,
+    /// Low-stock items with no preferred vendor; they still need ordering.
+    pub unassigned: Vec<UnassignedReplenishmentItem>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UnassignedReplenishmentItem {
+    pub stock_id: Uuid,
+    pub product_id: Option<Uuid>,
+    pub variant_id: Option<Uuid>,
+    pub available_quantity: Decimal,
+    pub reorder_point: Decimal,
+    pub suggested_quantity: Decimal,
+}
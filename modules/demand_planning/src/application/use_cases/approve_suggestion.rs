@@ -81,6 +81,8 @@ impl ApproveSuggestionUseCase {
             quantity_ordered: suggestion.recommended_qty(),
             unit_of_measure: cmd.unit_of_measure,
             unit_cost: cmd.unit_cost,
+            pack_size: None,
+            pack_price: None,
             discount_percent: rust_decimal::Decimal::ZERO,
             tax_percent: rust_decimal::Decimal::ZERO,
             notes: None,
@@ -0,0 +1,960 @@
+//! GenerateReplenishmentOrdersUseCase — turns a store's low-stock alerts into
+//! draft Purchase Orders, one per preferred vendor.
+//!
+//! For every stock record below its reorder point (as reported by
+//! `inventory::GetLowStockAlertsUseCase`) the suggested reorder quantity is
+//! ordered from the product's preferred vendor (see
+//! `inventory::SetProductVendorUseCase`), falling back to the
+//! `preferred_vendor_id` of the active reorder policy for that
+//! (variant, store). Lines are priced at the last unit cost received from
+//! that vendor, falling back to the catalog cost when the vendor has never
+//! delivered the item.
+//!
+//! Items without a preferred vendor are returned as `unassigned` so a buyer
+//! can order them by hand. The POs of a run are created in one transaction,
+//! so a failure leaves none of them behind. Like `ApproveSuggestionUseCase`,
+//! the POs are left in `draft`; submission and approval remain the buyer's
+//! responsibility.
+
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use identity::{StoreId, UserId};
+use inventory::{
+    GetLowStockAlertsUseCase, InventoryStockRepository, Product, ProductId, ProductRepository,
+    ProductVariant, VariantId,
+};
+use purchasing::{
+    CreatePurchaseOrderCommand, CreatePurchaseOrderItemCommand, CreatePurchaseOrderUseCase,
+    GoodsReceiptRepository, PurchaseOrderRepository, VendorRepository,
+};
+use rust_decimal::Decimal;
+use uuid::Uuid;
+
+use crate::DemandPlanningError;
+use crate::application::dtos::{
+    GenerateReplenishmentOrdersCommand, ReplenishmentOrdersResponse, UnassignedReplenishmentItem,
+};
+use crate::domain::repositories::ReorderPolicyRepository;
+
+/// Receipts scanned when looking for the preferred vendor's last cost
+const RECENT_COSTS_WINDOW: i64 = 50;
+
+pub struct GenerateReplenishmentOrdersUseCase<S, P, V>
+where
+    S: InventoryStockRepository,
+    P: PurchaseOrderRepository,
+    V: VendorRepository,
+{
+    policies: Arc<dyn ReorderPolicyRepository>,
+    low_stock: Arc<GetLowStockAlertsUseCase<S>>,
+    products: Arc<dyn ProductRepository>,
+    receipts: Arc<dyn GoodsReceiptRepository>,
+    create_po: Arc<CreatePurchaseOrderUseCase<P, V>>,
+}
+
+impl<S, P, V> GenerateReplenishmentOrdersUseCase<S, P, V>
+where
+    S: InventoryStockRepository,
+    P: PurchaseOrderRepository,
+    V: VendorRepository,
+{
+    pub fn new(
+        policies: Arc<dyn ReorderPolicyRepository>,
+        low_stock: Arc<GetLowStockAlertsUseCase<S>>,
+        products: Arc<dyn ProductRepository>,
+        receipts: Arc<dyn GoodsReceiptRepository>,
+        create_po: Arc<CreatePurchaseOrderUseCase<P, V>>,
+    ) -> Self {
+        Self {
+            policies,
+            low_stock,
+            products,
+            receipts,
+            create_po,
+        }
+    }
+
+    pub async fn execute(
+        &self,
+        store_id: StoreId,
+        actor_id: Uuid,
+        cmd: GenerateReplenishmentOrdersCommand,
+    ) -> Result<ReplenishmentOrdersResponse, DemandPlanningError> {
+        let alerts = self
+            .low_stock
+            .execute(store_id.into_uuid())
+            .await
+            .map_err(|e| DemandPlanningError::Subscriber(format!("inventory: {}", e)))?;
+
+        let mut by_vendor: BTreeMap<Uuid, Vec<CreatePurchaseOrderItemCommand>> = BTreeMap::new();
+        let mut unassigned = Vec::new();
+
+        for stock in alerts.items {
+            let quantity = stock.suggested_reorder_quantity.unwrap_or(Decimal::ZERO);
+            if quantity <= Decimal::ZERO {
+                continue;
+            }
+
+            // Reorder policies key variantless products by their product_id
+            // (see `ApproveSuggestionUseCase`).
+            let Some(product_variant_id) = stock.variant_id.or(stock.product_id) else {
+                continue;
+            };
+            let (product, variant) = self
+                .stock_product(stock.product_id, stock.variant_id)
+                .await?;
+            // The product's own preferred vendor wins over the policy's
+            let product_vendor = self
+                .products
+                .find_preferred_vendor(product.id())
+                .await
+                .map_err(|e| DemandPlanningError::Subscriber(format!("inventory: {}", e)))?
+                .map(|v| v.vendor_id());
+            let policy_vendor = self
+                .policies
+                .find_by_variant_store(product_variant_id, store_id.into_uuid())
+                .await?
+                .filter(|p| p.is_active())
+                .and_then(|p| p.preferred_vendor_id());
+
+            let Some(vendor_id) = product_vendor.or(policy_vendor) else {
+                unassigned.push(UnassignedReplenishmentItem {
+                    stock_id: stock.id,
+                    product_id: stock.product_id,
+                    variant_id: stock.variant_id,
+                    available_quantity: stock.available_quantity,
+                    reorder_point: stock.reorder_point.unwrap_or(stock.min_stock_level),
+                    suggested_quantity: quantity,
+                });
+                continue;
+            };
+
+            let item = self
+                .order_item(&product, variant.as_ref(), quantity, store_id, vendor_id)
+                .await?;
+            by_vendor.entry(vendor_id).or_default().push(item);
+        }
+
+        let po_cmds = by_vendor
+            .into_iter()
+            .map(|(vendor_id, items)| CreatePurchaseOrderCommand {
+                store_id: store_id.into_uuid(),
+                vendor_id,
+                order_date: cmd.order_date.clone(),
+                expected_delivery_date: cmd.expected_delivery_date.clone(),
+                currency: None,
+                payment_terms_days: None,
+                notes: Some("Auto-generated from low-stock alerts".into()),
+                items,
+            })
+            .collect();
+        let purchase_orders = self
+            .create_po
+            .execute_many(po_cmds, UserId::from_uuid(actor_id))
+            .await
+            .map_err(|e| DemandPlanningError::Subscriber(format!("purchasing: {}", e)))?;
+
+        Ok(ReplenishmentOrdersResponse {
+            store_id: store_id.into_uuid(),
+            purchase_orders,
+            unassigned,
+        })
+    }
+
+    /// Loads the product (and variant, if any) a stock record is kept for
+    async fn stock_product(
+        &self,
+        product_id: Option<Uuid>,
+        variant_id: Option<Uuid>,
+    ) -> Result<(Product, Option<ProductVariant>), DemandPlanningError> {
+        let inventory_err = |e: inventory::InventoryError| {
+            DemandPlanningError::Subscriber(format!("inventory: {}", e))
+        };
+
+        // Variant stock rows don't carry the product; PO items need both.
+        let variant = match variant_id {
+            Some(id) => Some(
+                self.products
+                    .find_variant_by_id(VariantId::from_uuid(id))
+                    .await
+                    .map_err(inventory_err)?
+                    .ok_or_else(|| {
+                        DemandPlanningError::Subscriber(format!(
+                            "inventory: variant {} not found",
+                            id
+                        ))
+                    })?,
+            ),
+            None => None,
+        };
+        let product_id = match (&variant, product_id) {
+            (Some(v), _) => v.product_id(),
+            (None, Some(id)) => ProductId::from_uuid(id),
+            (None, None) => {
+                return Err(DemandPlanningError::Subscriber(
+                    "inventory: stock record has neither product nor variant".into(),
+                ));
+            }
+        };
+        let product = self
+            .products
+            .find_by_id(product_id)
+            .await
+            .map_err(inventory_err)?
+            .ok_or_else(|| {
+                DemandPlanningError::Subscriber(format!(
+                    "inventory: product {} not found",
+                    product_id.into_uuid()
+                ))
+            })?;
+
+        Ok((product, variant))
+    }
+
+    /// Builds the PO line for a stock record, priced at the vendor's last
+    /// received unit cost or, failing that, the catalog cost.
+    async fn order_item(
+        &self,
+        product: &Product,
+        variant: Option<&ProductVariant>,
+        quantity: Decimal,
+        store_id: StoreId,
+        vendor_id: Uuid,
+    ) -> Result<CreatePurchaseOrderItemCommand, DemandPlanningError> {
+        let product_id = product.id();
+        let variant_id = variant.map(|v| v.id());
+        let last_vendor_cost = self
+            .receipts
+            .find_recent_unit_costs(product_id, variant_id, store_id, RECENT_COSTS_WINDOW)
+            .await
+            .map_err(|e| DemandPlanningError::Subscriber(format!("purchasing: {}", e)))?
+            .into_iter()
+            .find(|c| c.vendor_id.into_uuid() == vendor_id)
+            .map(|c| c.unit_cost);
+        let catalog_cost = match variant {
+            Some(v) => v.effective_cost(product.cost_price()),
+            None => product.cost_price(),
+        };
+
+        let description = match variant {
+            Some(v) => format!("{} - {}", product.name(), v.name()),
+            None => product.name().to_string(),
+        };
+
+        Ok(CreatePurchaseOrderItemCommand {
+            product_id: product_id.into_uuid(),
+            variant_id: variant_id.map(|id| id.into_uuid()),
+            description,
+            quantity_ordered: quantity,
+            unit_of_measure: product.unit_of_measure().to_string(),
+            unit_cost: last_vendor_cost.unwrap_or(catalog_cost),
+            pack_size: None,
+            pack_price: None,
+            discount_percent: Decimal::ZERO,
+            tax_percent: Decimal::ZERO,
+            notes: None,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+    use std::sync::Mutex;
+
+    use async_trait::async_trait;
+    use chrono::NaiveDate;
+    use inventory::{
+        Barcode, CategoryId, Currency, InventoryError, InventoryStock, Product, ProductVariant,
+        ProductVendor, Sku, StockId, UnitConversion, UnitOfMeasure,
+    };
+    use purchasing::{
+        GoodsReceipt, GoodsReceiptFilter, GoodsReceiptId, PurchaseOrder, PurchaseOrderFilter,
+        PurchaseOrderId, PurchaseOrderItem, PurchaseOrderItemId, PurchasingError, ReceivedUnitCost,
+        StalePurchaseOrderFilter, Vendor, VendorFilter, VendorId, VendorOrderDelivery,
+    };
+    use rust_decimal_macros::dec;
+
+    use crate::domain::entities::ReorderPolicy;
+    use crate::domain::value_objects::ReorderPolicyId;
+
+    struct MockPolicyRepository {
+        policies: Vec<ReorderPolicy>,
+    }
+
+    #[async_trait]
+    impl ReorderPolicyRepository for MockPolicyRepository {
+        async fn save(&self, _policy: &ReorderPolicy) -> Result<(), DemandPlanningError> {
+            unimplemented!()
+        }
+        async fn update(&self, _policy: &ReorderPolicy) -> Result<(), DemandPlanningError> {
+            unimplemented!()
+        }
+        async fn find_by_id(
+            &self,
+            _id: ReorderPolicyId,
+        ) -> Result<Option<ReorderPolicy>, DemandPlanningError> {
+            unimplemented!()
+        }
+        async fn find_by_variant_store(
+            &self,
+            product_variant_id: Uuid,
+            store_id: Uuid,
+        ) -> Result<Option<ReorderPolicy>, DemandPlanningError> {
+            Ok(self
+                .policies
+                .iter()
+                .find(|p| p.product_variant_id() == product_variant_id && p.store_id() == store_id)
+                .cloned())
+        }
+        async fn list_active(
+            &self,
+            _store_id: Option<Uuid>,
+        ) -> Result<Vec<ReorderPolicy>, DemandPlanningError> {
+            unimplemented!()
+        }
+    }
+
+    /// Every stock record is reported as low stock
+    struct MockStockRepository {
+        stocks: Vec<InventoryStock>,
+    }
+
+    #[async_trait]
+    impl InventoryStockRepository for MockStockRepository {
+        async fn save(&self, _stock: &InventoryStock) -> Result<(), InventoryError> {
+            unimplemented!()
+        }
+        async fn find_by_id(&self, _id: StockId) -> Result<Option<InventoryStock>, InventoryError> {
+            unimplemented!()
+        }
+        async fn find_by_store_and_product(
+            &self,
+            _store_id: StoreId,
+            _product_id: ProductId,
+        ) -> Result<Option<InventoryStock>, InventoryError> {
+            unimplemented!()
+        }
+        async fn find_by_store_and_variant(
+            &self,
+            _store_id: StoreId,
+            _variant_id: VariantId,
+        ) -> Result<Option<InventoryStock>, InventoryError> {
+            unimplemented!()
+        }
+        async fn update_with_version(
+            &self,
+            _stock: &InventoryStock,
+            _expected_version: i32,
+        ) -> Result<(), InventoryError> {
+            unimplemented!()
+        }
+        async fn find_low_stock(
+            &self,
+            store_id: StoreId,
+        ) -> Result<Vec<InventoryStock>, InventoryError> {
+            Ok(self
+                .stocks
+                .iter()
+                .filter(|s| s.store_id() == store_id)
+                .cloned()
+                .collect())
+        }
+        async fn find_by_store(
+            &self,
+            _store_id: StoreId,
+        ) -> Result<Vec<InventoryStock>, InventoryError> {
+            unimplemented!()
+        }
+        async fn find_paginated(
+            &self,
+            _store_ids: Option<&[StoreId]>,
+            _product_id: Option<ProductId>,
+            _low_stock_only: bool,
+            _after_id: Option<Uuid>,
+            _page: i64,
+            _page_size: i64,
+        ) -> Result<(Vec<InventoryStock>, i64), InventoryError> {
+            unimplemented!()
+        }
+        async fn find_by_product(
+            &self,
+            _product_id: ProductId,
+        ) -> Result<Vec<InventoryStock>, InventoryError> {
+            unimplemented!()
+        }
+        async fn find_all(&self) -> Result<Vec<InventoryStock>, InventoryError> {
+            unimplemented!()
+        }
+        async fn find_all_low_stock(&self) -> Result<Vec<InventoryStock>, InventoryError> {
+            unimplemented!()
+        }
+        async fn find_low_stock_by_store(
+            &self,
+            _store_id: StoreId,
+        ) -> Result<Vec<InventoryStock>, InventoryError> {
+            unimplemented!()
+        }
+        async fn find_by_store_and_products(
+            &self,
+            _store_id: StoreId,
+            _product_ids: &[ProductId],
+        ) -> Result<Vec<InventoryStock>, InventoryError> {
+            unimplemented!()
+        }
+        async fn find_by_store_and_variants(
+            &self,
+            _store_id: StoreId,
+            _variant_ids: &[VariantId],
+        ) -> Result<Vec<InventoryStock>, InventoryError> {
+            unimplemented!()
+        }
+    }
+
+    struct MockProductRepository {
+        products: HashMap<ProductId, Product>,
+        preferred_vendors: HashMap<ProductId, Uuid>,
+    }
+
+    #[async_trait]
+    impl ProductRepository for MockProductRepository {
+        async fn save(&self, _product: &Product) -> Result<(), InventoryError> {
+            unimplemented!()
+        }
+        async fn find_by_id(&self, id: ProductId) -> Result<Option<Product>, InventoryError> {
+            Ok(self.products.get(&id).cloned())
+        }
+        async fn find_by_sku(&self, _sku: &Sku) -> Result<Option<Product>, InventoryError> {
+            unimplemented!()
+        }
+        async fn find_by_barcode(
+            &self,
+            _barcode: &Barcode,
+        ) -> Result<Option<Product>, InventoryError> {
+            unimplemented!()
+        }
+        async fn update(&self, _product: &Product) -> Result<(), InventoryError> {
+            unimplemented!()
+        }
+        async fn delete(&self, _id: ProductId) -> Result<(), InventoryError> {
+            unimplemented!()
+        }
+        async fn find_active(&self) -> Result<Vec<Product>, InventoryError> {
+            unimplemented!()
+        }
+        async fn find_by_category(
+            &self,
+            _category_id: CategoryId,
+        ) -> Result<Vec<Product>, InventoryError> {
+            unimplemented!()
+        }
+        async fn find_paginated(
+            &self,
+            _category_id: Option<CategoryId>,
+            _is_active: Option<bool>,
+            _search: Option<&str>,
+            _after_id: Option<Uuid>,
+            _page: i64,
+            _page_size: i64,
+        ) -> Result<(Vec<Product>, i64), InventoryError> {
+            unimplemented!()
+        }
+        async fn count_filtered(
+            &self,
+            _category_id: Option<CategoryId>,
+            _is_active: Option<bool>,
+            _search: Option<&str>,
+        ) -> Result<i64, InventoryError> {
+            unimplemented!()
+        }
+        async fn search(
+            &self,
+            _term: &str,
+            _category_id: Option<CategoryId>,
+            _active_only: bool,
+            _page: i64,
+            _page_size: i64,
+        ) -> Result<(Vec<(Product, f64)>, i64), InventoryError> {
+            unimplemented!()
+        }
+        async fn save_variant(&self, _variant: &ProductVariant) -> Result<(), InventoryError> {
+            unimplemented!()
+        }
+        async fn find_variant_by_id(
+            &self,
+            _id: VariantId,
+        ) -> Result<Option<ProductVariant>, InventoryError> {
+            unimplemented!()
+        }
+        async fn find_variant_by_sku(
+            &self,
+            _sku: &Sku,
+        ) -> Result<Option<ProductVariant>, InventoryError> {
+            unimplemented!()
+        }
+        async fn find_variant_by_barcode(
+            &self,
+            _barcode: &Barcode,
+        ) -> Result<Option<ProductVariant>, InventoryError> {
+            unimplemented!()
+        }
+        async fn find_variants_by_product(
+            &self,
+            _product_id: ProductId,
+        ) -> Result<Vec<ProductVariant>, InventoryError> {
+            unimplemented!()
+        }
+        async fn update_variant(&self, _variant: &ProductVariant) -> Result<(), InventoryError> {
+            unimplemented!()
+        }
+        async fn delete_variant(&self, _id: VariantId) -> Result<(), InventoryError> {
+            unimplemented!()
+        }
+        async fn count_variants(&self, _product_id: ProductId) -> Result<u32, InventoryError> {
+            unimplemented!()
+        }
+        async fn save_product_vendors(
+            &self,
+            _vendors: &[ProductVendor],
+        ) -> Result<(), InventoryError> {
+            unimplemented!()
+        }
+        async fn find_product_vendors(
+            &self,
+            _product_id: ProductId,
+        ) -> Result<Vec<ProductVendor>, InventoryError> {
+            unimplemented!()
+        }
+        async fn find_preferred_vendor(
+            &self,
+            product_id: ProductId,
+        ) -> Result<Option<ProductVendor>, InventoryError> {
+            Ok(self.preferred_vendors.get(&product_id).map(|&vendor_id| {
+                let mut link = ProductVendor::create(product_id, vendor_id);
+                link.set_preferred(true);
+                link
+            }))
+        }
+        async fn replace_unit_conversions(
+            &self,
+            _product_id: ProductId,
+            _conversions: &[UnitConversion],
+        ) -> Result<(), InventoryError> {
+            unimplemented!()
+        }
+        async fn find_unit_conversions(
+            &self,
+            _product_id: ProductId,
+        ) -> Result<Vec<UnitConversion>, InventoryError> {
+            unimplemented!()
+        }
+    }
+
+    /// No vendor has delivered anything yet
+    struct MockReceiptRepository;
+
+    #[async_trait]
+    impl GoodsReceiptRepository for MockReceiptRepository {
+        async fn save(&self, _receipt: &GoodsReceipt) -> Result<(), PurchasingError> {
+            unimplemented!()
+        }
+        async fn find_by_id(
+            &self,
+            _id: GoodsReceiptId,
+        ) -> Result<Option<GoodsReceipt>, PurchasingError> {
+            unimplemented!()
+        }
+        async fn find_by_id_with_items(
+            &self,
+            _id: GoodsReceiptId,
+        ) -> Result<Option<GoodsReceipt>, PurchasingError> {
+            unimplemented!()
+        }
+        async fn update(&self, _receipt: &GoodsReceipt) -> Result<(), PurchasingError> {
+            unimplemented!()
+        }
+        async fn find_by_purchase_order(
+            &self,
+            _order_id: PurchaseOrderId,
+        ) -> Result<Vec<GoodsReceipt>, PurchasingError> {
+            unimplemented!()
+        }
+        async fn find_orders_with_receipts(
+            &self,
+            _order_ids: &[PurchaseOrderId],
+        ) -> Result<Vec<PurchaseOrderId>, PurchasingError> {
+            unimplemented!()
+        }
+        async fn find_paginated(
+            &self,
+            _filter: GoodsReceiptFilter,
+            _page: i64,
+            _page_size: i64,
+        ) -> Result<(Vec<GoodsReceipt>, i64), PurchasingError> {
+            unimplemented!()
+        }
+        async fn find_recent_unit_costs(
+            &self,
+            _product_id: ProductId,
+            _variant_id: Option<VariantId>,
+            _store_id: StoreId,
+            _limit: i64,
+        ) -> Result<Vec<ReceivedUnitCost>, PurchasingError> {
+            Ok(vec![])
+        }
+        async fn generate_receipt_number(
+            &self,
+            _store_id: StoreId,
+        ) -> Result<String, PurchasingError> {
+            unimplemented!()
+        }
+    }
+
+    #[derive(Default)]
+    struct MockOrderRepository {
+        saved: Mutex<Vec<PurchaseOrder>>,
+    }
+
+    #[async_trait]
+    impl PurchaseOrderRepository for MockOrderRepository {
+        async fn save(&self, _order: &PurchaseOrder) -> Result<(), PurchasingError> {
+            unimplemented!()
+        }
+        async fn save_many(&self, orders: &[PurchaseOrder]) -> Result<(), PurchasingError> {
+            self.saved.lock().unwrap().extend(orders.iter().cloned());
+            Ok(())
+        }
+        async fn find_by_id(
+            &self,
+            _id: PurchaseOrderId,
+        ) -> Result<Option<PurchaseOrder>, PurchasingError> {
+            unimplemented!()
+        }
+        async fn find_by_id_with_items(
+            &self,
+            _id: PurchaseOrderId,
+        ) -> Result<Option<PurchaseOrder>, PurchasingError> {
+            unimplemented!()
+        }
+        async fn find_by_order_number(
+            &self,
+            _store_id: StoreId,
+            _order_number: &str,
+        ) -> Result<Option<PurchaseOrder>, PurchasingError> {
+            unimplemented!()
+        }
+        async fn update(&self, _order: &PurchaseOrder) -> Result<(), PurchasingError> {
+            unimplemented!()
+        }
+        async fn find_paginated(
+            &self,
+            _filter: PurchaseOrderFilter,
+            _page: i64,
+            _page_size: i64,
+        ) -> Result<(Vec<PurchaseOrder>, i64), PurchasingError> {
+            unimplemented!()
+        }
+        async fn find_stale(
+            &self,
+            _filter: &StalePurchaseOrderFilter,
+        ) -> Result<Vec<PurchaseOrder>, PurchasingError> {
+            unimplemented!()
+        }
+        async fn cancel_many(&self, _orders: &[PurchaseOrder]) -> Result<(), PurchasingError> {
+            unimplemented!()
+        }
+        async fn find_vendor_deliveries(
+            &self,
+            _store_id: Option<StoreId>,
+            _from: NaiveDate,
+            _to: NaiveDate,
+        ) -> Result<Vec<VendorOrderDelivery>, PurchasingError> {
+            unimplemented!()
+        }
+        async fn generate_order_number(
+            &self,
+            _store_id: StoreId,
+        ) -> Result<String, PurchasingError> {
+            Ok("PO-2026-00001".to_string())
+        }
+        async fn save_item(&self, _item: &PurchaseOrderItem) -> Result<(), PurchasingError> {
+            unimplemented!()
+        }
+        async fn update_item(&self, _item: &PurchaseOrderItem) -> Result<(), PurchasingError> {
+            unimplemented!()
+        }
+        async fn delete_item(&self, _item_id: PurchaseOrderItemId) -> Result<(), PurchasingError> {
+            unimplemented!()
+        }
+        async fn find_items_by_order(
+            &self,
+            _order_id: PurchaseOrderId,
+        ) -> Result<Vec<PurchaseOrderItem>, PurchasingError> {
+            unimplemented!()
+        }
+        async fn find_item_by_id(
+            &self,
+            _item_id: PurchaseOrderItemId,
+        ) -> Result<Option<PurchaseOrderItem>, PurchasingError> {
+            unimplemented!()
+        }
+    }
+
+    struct MockVendorRepository {
+        vendors: HashMap<VendorId, Vendor>,
+    }
+
+    #[async_trait]
+    impl VendorRepository for MockVendorRepository {
+        async fn save(&self, _vendor: &Vendor) -> Result<(), PurchasingError> {
+            unimplemented!()
+        }
+        async fn find_by_id(&self, id: VendorId) -> Result<Option<Vendor>, PurchasingError> {
+            Ok(self.vendors.get(&id).cloned())
+        }
+        async fn find_by_code(&self, _code: &str) -> Result<Option<Vendor>, PurchasingError> {
+            unimplemented!()
+        }
+        async fn update(&self, _vendor: &Vendor) -> Result<(), PurchasingError> {
+            unimplemented!()
+        }
+        async fn find_paginated(
+            &self,
+            _filter: VendorFilter,
+            _page: i64,
+            _page_size: i64,
+        ) -> Result<(Vec<Vendor>, i64), PurchasingError> {
+            unimplemented!()
+        }
+        async fn count(&self, _filter: VendorFilter) -> Result<i64, PurchasingError> {
+            unimplemented!()
+        }
+        async fn exists_by_code(&self, _code: &str) -> Result<bool, PurchasingError> {
+            unimplemented!()
+        }
+        async fn exists_by_tax_id(&self, _tax_id: &str) -> Result<bool, PurchasingError> {
+            unimplemented!()
+        }
+        async fn exists_by_code_excluding(
+            &self,
+            _code: &str,
+            _exclude_id: VendorId,
+        ) -> Result<bool, PurchasingError> {
+            unimplemented!()
+        }
+        async fn exists_by_tax_id_excluding(
+            &self,
+            _tax_id: &str,
+            _exclude_id: VendorId,
+        ) -> Result<bool, PurchasingError> {
+            unimplemented!()
+        }
+        async fn count_by_code_prefix(&self, _prefix: &str) -> Result<i64, PurchasingError> {
+            unimplemented!()
+        }
+    }
+
+    type UseCase = GenerateReplenishmentOrdersUseCase<
+        MockStockRepository,
+        MockOrderRepository,
+        MockVendorRepository,
+    >;
+
+    /// A store's low-stock products, their reorder policies and preferred
+    /// vendors, and the vendors those point to
+    struct Fixture {
+        store_id: StoreId,
+        stocks: Vec<InventoryStock>,
+        products: HashMap<ProductId, Product>,
+        preferred_vendors: HashMap<ProductId, Uuid>,
+        policies: Vec<ReorderPolicy>,
+        vendors: HashMap<VendorId, Vendor>,
+        orders: Arc<MockOrderRepository>,
+    }
+
+    impl Fixture {
+        fn new() -> Self {
+            Self {
+                store_id: StoreId::new(),
+                stocks: Vec::new(),
+                products: HashMap::new(),
+                preferred_vendors: HashMap::new(),
+                policies: Vec::new(),
+                vendors: HashMap::new(),
+                orders: Arc::new(MockOrderRepository::default()),
+            }
+        }
+
+        fn vendor(&mut self) -> Uuid {
+            let vendor = Vendor::create(
+                "VEND-001".to_string(),
+                "Vendor".to_string(),
+                "Vendor S.A.".to_string(),
+                "08011999123456".to_string(),
+                Currency::new("HNL").unwrap(),
+            );
+            let id = vendor.id();
+            self.vendors.insert(id, vendor);
+            id.into_uuid()
+        }
+
+        /// Adds a product 10 units short whose reorder policy prefers
+        /// `vendor_id`
+        fn low_stock_product(&mut self, vendor_id: Option<Uuid>) -> ProductId {
+            let product = Product::create("Coffee".to_string(), UnitOfMeasure::Unit, None);
+            let product_id = product.id();
+            self.products.insert(product_id, product);
+
+            let mut stock = InventoryStock::create_for_product(self.store_id, product_id).unwrap();
+            stock.set_reorder_quantity(Some(dec!(10)));
+            self.stocks.push(stock);
+
+            let policy = ReorderPolicy::create(
+                product_id.into_uuid(),
+                self.store_id.into_uuid(),
+                dec!(5),
+                dec!(20),
+                7,
+                dec!(2),
+                7,
+                vendor_id,
+            )
+            .unwrap();
+            self.policies.push(policy);
+            product_id
+        }
+
+        fn use_case(&self) -> UseCase {
+            let low_stock = GetLowStockAlertsUseCase::new(Arc::new(MockStockRepository {
+                stocks: self.stocks.clone(),
+            }));
+            let create_po = CreatePurchaseOrderUseCase::new(
+                self.orders.clone(),
+                Arc::new(MockVendorRepository {
+                    vendors: self.vendors.clone(),
+                }),
+            );
+            GenerateReplenishmentOrdersUseCase::new(
+                Arc::new(MockPolicyRepository {
+                    policies: self.policies.clone(),
+                }),
+                Arc::new(low_stock),
+                Arc::new(MockProductRepository {
+                    products: self.products.clone(),
+                    preferred_vendors: self.preferred_vendors.clone(),
+                }),
+                Arc::new(MockReceiptRepository),
+                Arc::new(create_po),
+            )
+        }
+
+        async fn run(&self) -> Result<ReplenishmentOrdersResponse, DemandPlanningError> {
+            self.use_case()
+                .execute(
+                    self.store_id,
+                    UserId::new().into_uuid(),
+                    GenerateReplenishmentOrdersCommand {
+                        order_date: "2026-10-18".to_string(),
+                        expected_delivery_date: None,
+                    },
+                )
+                .await
+        }
+    }
+
+    #[tokio::test]
+    async fn test_groups_lines_into_one_po_per_vendor() {
+        let mut fixture = Fixture::new();
+        let (vendor_a, vendor_b) = (fixture.vendor(), fixture.vendor());
+        fixture.low_stock_product(Some(vendor_a));
+        fixture.low_stock_product(Some(vendor_a));
+        fixture.low_stock_product(Some(vendor_b));
+
+        let response = fixture.run().await.unwrap();
+
+        assert_eq!(response.purchase_orders.len(), 2);
+        let lines_for = |vendor_id: Uuid| {
+            response
+                .purchase_orders
+                .iter()
+                .find(|po| po.vendor_id == vendor_id)
+                .map(|po| po.items.len())
+        };
+        assert_eq!(lines_for(vendor_a), Some(2));
+        assert_eq!(lines_for(vendor_b), Some(1));
+        assert_eq!(fixture.orders.saved.lock().unwrap().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_product_without_vendor_is_unassigned() {
+        let mut fixture = Fixture::new();
+        let vendor_id = fixture.vendor();
+        fixture.low_stock_product(Some(vendor_id));
+        let unassigned = fixture.low_stock_product(None);
+
+        let response = fixture.run().await.unwrap();
+
+        assert_eq!(response.purchase_orders.len(), 1);
+        assert_eq!(response.purchase_orders[0].items.len(), 1);
+        assert_eq!(response.unassigned.len(), 1);
+        assert_eq!(
+            response.unassigned[0].product_id,
+            Some(unassigned.into_uuid())
+        );
+        assert_eq!(response.unassigned[0].suggested_quantity, dec!(10));
+    }
+
+    #[tokio::test]
+    async fn test_failing_po_leaves_no_po_behind() {
+        let mut fixture = Fixture::new();
+        let (active, inactive) = (fixture.vendor(), fixture.vendor());
+        fixture
+            .vendors
+            .get_mut(&VendorId::from_uuid(inactive))
+            .unwrap()
+            .deactivate();
+        fixture.low_stock_product(Some(active));
+        fixture.low_stock_product(Some(inactive));
+
+        let result = fixture.run().await;
+
+        assert!(matches!(result, Err(DemandPlanningError::Subscriber(_))));
+        assert!(fixture.orders.saved.lock().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_product_preferred_vendor_overrides_policy_vendor() {
+        let mut fixture = Fixture::new();
+        let (policy_vendor, product_vendor) = (fixture.vendor(), fixture.vendor());
+        let product_id = fixture.low_stock_product(Some(policy_vendor));
+        fixture.low_stock_product(Some(policy_vendor));
+        fixture.preferred_vendors.insert(product_id, product_vendor);
+
+        let response = fixture.run().await.unwrap();
+
+        assert_eq!(response.purchase_orders.len(), 2);
+        let po = response
+            .purchase_orders
+            .iter()
+            .find(|po| po.vendor_id == product_vendor)
+            .unwrap();
+        assert_eq!(po.items.len(), 1);
+        assert_eq!(po.items[0].product_id, product_id.into_uuid());
+    }
+
+    #[tokio::test]
+    async fn test_product_preferred_vendor_assigns_product_without_policy_vendor() {
+        let mut fixture = Fixture::new();
+        let vendor_id = fixture.vendor();
+        let product_id = fixture.low_stock_product(None);
+        fixture.preferred_vendors.insert(product_id, vendor_id);
+
+        let response = fixture.run().await.unwrap();
+
+        assert!(response.unassigned.is_empty());
+        assert_eq!(response.purchase_orders.len(), 1);
+        assert_eq!(response.purchase_orders[0].vendor_id, vendor_id);
+    }
+}
@@ -0,0 +1,104 @@
+//! GetProductAvailabilityForecastUseCase — when will a variant stock out at a
+//! store? Projects today's available stock forward using the medium-window
+//! sales velocity as daily demand and adding open purchase-order quantities on
+//! their expected delivery dates.
+
+use std::sync::Arc;
+
+use chrono::{Duration, Utc};
+use rust_decimal::Decimal;
+use uuid::Uuid;
+
+use crate::DemandPlanningError;
+use crate::domain::entities::{AvailabilityForecast, SalesVelocity, VelocityWindows};
+use crate::domain::repositories::{
+    IncomingSupplyRepository, SalesHistoryRepository, StockSnapshotRepository,
+};
+
+/// Longest projection accepted, in days.
+pub const MAX_AVAILABILITY_HORIZON_DAYS: i64 = 365;
+
+pub struct GetProductAvailabilityForecastUseCase {
+    history: Arc<dyn SalesHistoryRepository>,
+    stock: Arc<dyn StockSnapshotRepository>,
+    supply: Arc<dyn IncomingSupplyRepository>,
+}
+
+impl GetProductAvailabilityForecastUseCase {
+    pub fn new(
+        history: Arc<dyn SalesHistoryRepository>,
+        stock: Arc<dyn StockSnapshotRepository>,
+        supply: Arc<dyn IncomingSupplyRepository>,
+    ) -> Self {
+        Self {
+            history,
+            stock,
+            supply,
+        }
+    }
+
+    /// Projection starts today. A variant without a stock row at the store
+    /// starts from zero.
+    pub async fn execute(
+        &self,
+        product_variant_id: Uuid,
+        store_id: Uuid,
+        windows: VelocityWindows,
+        horizon_days: i64,
+    ) -> Result<AvailabilityForecast, DemandPlanningError> {
+        if !(1..=MAX_AVAILABILITY_HORIZON_DAYS).contains(&horizon_days) {
+            return Err(DemandPlanningError::InvalidForecastHorizon {
+                max: MAX_AVAILABILITY_HORIZON_DAYS,
+            });
+        }
+        let windows =
+            VelocityWindows::new(windows.short_days, windows.medium_days, windows.long_days)?;
+        let today = Utc::now().date_naive();
+
+        let daily_demand = self
+            .history
+            .units_sold_by_window(
+                Some(store_id),
+                Some(product_variant_id),
+                today + Duration::days(1),
+                windows,
+            )
+            .await?
+            .into_iter()
+            .find(|r| r.product_variant_id == product_variant_id)
+            .map(|r| {
+                SalesVelocity::new(
+                    r.product_variant_id,
+                    Some(store_id),
+                    windows,
+                    r.short_units,
+                    r.medium_units,
+                    r.long_units,
+                )
+                .medium_per_day()
+            })
+            .unwrap_or(Decimal::ZERO);
+
+        let available = self
+            .stock
+            .snapshot(product_variant_id, store_id)
+            .await?
+            .map(|s| s.available())
+            .unwrap_or(Decimal::ZERO);
+
+        let receipts = self
+            .supply
+            .open_receipts(product_variant_id, store_id)
+            .await?;
+
+        Ok(AvailabilityForecast::project(
+            product_variant_id,
+            store_id,
+            today,
+            available,
+            daily_demand,
+            horizon_days,
+            &receipts,
+        ))
+    }
+}
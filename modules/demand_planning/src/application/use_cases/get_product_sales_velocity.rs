@@ -0,0 +1,56 @@
+//! GetProductSalesVelocityUseCase — units/day over short/medium/long trailing
+//! windows for one variant or every active variant, per store or aggregated.
+//! Single source of velocity for replenishment and markdown/clearance so both
+//! agree on what "fast" and "slow" mean.
+
+use std::sync::Arc;
+
+use chrono::{Duration, Utc};
+use uuid::Uuid;
+
+use crate::DemandPlanningError;
+use crate::domain::entities::{SalesVelocity, VelocityWindows};
+use crate::domain::repositories::SalesHistoryRepository;
+
+pub struct GetProductSalesVelocityUseCase {
+    history: Arc<dyn SalesHistoryRepository>,
+}
+
+impl GetProductSalesVelocityUseCase {
+    pub fn new(history: Arc<dyn SalesHistoryRepository>) -> Self {
+        Self { history }
+    }
+
+    /// Windows end today (inclusive). `store_id = None` aggregates across
+    /// stores; `product_variant_id = None` returns every variant that moved in
+    /// the long window.
+    pub async fn execute(
+        &self,
+        store_id: Option<Uuid>,
+        product_variant_id: Option<Uuid>,
+        windows: VelocityWindows,
+    ) -> Result<Vec<SalesVelocity>, DemandPlanningError> {
+        let windows =
+            VelocityWindows::new(windows.short_days, windows.medium_days, windows.long_days)?;
+        let as_of = Utc::now().date_naive() + Duration::days(1);
+
+        let rows = self
+            .history
+            .units_sold_by_window(store_id, product_variant_id, as_of, windows)
+            .await?;
+
+        Ok(rows
+            .into_iter()
+            .map(|r| {
+                SalesVelocity::new(
+                    r.product_variant_id,
+                    store_id,
+                    windows,
+                    r.short_units,
+                    r.medium_units,
+                    r.long_units,
+                )
+            })
+            .collect())
+    }
+}
@@ -1,8 +1,11 @@
 mod approve_suggestion;
 mod classify_abc;
 mod dismiss_suggestion;
+mod generate_replenishment_orders;
 mod generate_replenishment_suggestions;
 mod get_forecast;
+mod get_product_availability_forecast;
+mod get_product_sales_velocity;
 mod list_abc_classifications;
 mod list_reorder_policies;
 mod list_replenishment_suggestions;
@@ -12,8 +15,13 @@ mod upsert_reorder_policy;
 pub use approve_suggestion::ApproveSuggestionUseCase;
 pub use classify_abc::ClassifyAbcUseCase;
 pub use dismiss_suggestion::DismissSuggestionUseCase;
+pub use generate_replenishment_orders::GenerateReplenishmentOrdersUseCase;
 pub use generate_replenishment_suggestions::GenerateReplenishmentSuggestionsUseCase;
 pub use get_forecast::GetForecastUseCase;
+pub use get_product_availability_forecast::{
+    GetProductAvailabilityForecastUseCase, MAX_AVAILABILITY_HORIZON_DAYS,
+};
+pub use get_product_sales_velocity::GetProductSalesVelocityUseCase;
 pub use list_abc_classifications::ListAbcClassificationsUseCase;
 pub use list_reorder_policies::ListReorderPoliciesUseCase;
 pub use list_replenishment_suggestions::ListReplenishmentSuggestionsUseCase;
@@ -0,0 +1,223 @@
+//! AvailabilityForecast — available stock of a variant at one store projected
+//! forward day by day: each day adds the open purchase-order quantity due that
+//! day and subtracts the projected daily demand (a sales velocity).
+//!
+//! Computed on the fly; nothing is persisted. Receipts count from the start of
+//! their day, so a delivery due on the day stock would run out saves it.
+
+use chrono::{Duration, NaiveDate};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::domain::repositories::IncomingReceipt;
+
+/// Projected stock at the end of one day.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ProjectedStockPoint {
+    pub date: NaiveDate,
+    pub receipts: Decimal,
+    pub demand: Decimal,
+    pub projected_qty: Decimal,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AvailabilityForecast {
+    product_variant_id: Uuid,
+    store_id: Uuid,
+    as_of: NaiveDate,
+    available_qty: Decimal,
+    daily_demand: Decimal,
+    horizon_days: i64,
+    stockout_date: Option<NaiveDate>,
+    days_of_cover: Option<Decimal>,
+    next_delivery_date: Option<NaiveDate>,
+    points: Vec<ProjectedStockPoint>,
+}
+
+impl AvailabilityForecast {
+    /// Projects `available_qty` from `as_of` (day 0) over `horizon_days`.
+    /// Receipts due before `as_of` are late and count as arriving on day 0;
+    /// receipts past the horizon are ignored. The projection stops on the
+    /// stock-out day.
+    pub fn project(
+        product_variant_id: Uuid,
+        store_id: Uuid,
+        as_of: NaiveDate,
+        available_qty: Decimal,
+        daily_demand: Decimal,
+        horizon_days: i64,
+        receipts: &[IncomingReceipt],
+    ) -> Self {
+        let daily_demand = daily_demand.max(Decimal::ZERO);
+        let next_delivery_date = receipts
+            .iter()
+            .filter(|r| r.quantity > Decimal::ZERO)
+            .map(|r| r.expected_date.max(as_of))
+            .min();
+
+        let mut points = Vec::new();
+        let mut stockout_date = None;
+        let mut days_of_cover = None;
+        let mut stock = available_qty;
+        for day in 0..horizon_days {
+            let date = as_of + Duration::days(day);
+            let arriving: Decimal = receipts
+                .iter()
+                .filter(|r| r.expected_date.max(as_of) == date)
+                .map(|r| r.quantity)
+                .sum();
+            let opening = stock + arriving;
+            stock = opening - daily_demand;
+            points.push(ProjectedStockPoint {
+                date,
+                receipts: arriving,
+                demand: daily_demand,
+                projected_qty: stock,
+            });
+
+            if stock <= Decimal::ZERO && daily_demand > Decimal::ZERO {
+                // Fraction of the stock-out day the opening stock still covers.
+                let partial = (opening.max(Decimal::ZERO) / daily_demand).min(Decimal::ONE);
+                stockout_date = Some(date);
+                days_of_cover = Some((Decimal::from(day) + partial).round_dp(2));
+                break;
+            }
+        }
+
+        Self {
+            product_variant_id,
+            store_id,
+            as_of,
+            available_qty,
+            daily_demand,
+            horizon_days,
+            stockout_date,
+            days_of_cover,
+            next_delivery_date,
+            points,
+        }
+    }
+
+    pub fn product_variant_id(&self) -> Uuid {
+        self.product_variant_id
+    }
+    pub fn store_id(&self) -> Uuid {
+        self.store_id
+    }
+    pub fn as_of(&self) -> NaiveDate {
+        self.as_of
+    }
+    pub fn available_qty(&self) -> Decimal {
+        self.available_qty
+    }
+    pub fn daily_demand(&self) -> Decimal {
+        self.daily_demand
+    }
+    pub fn horizon_days(&self) -> i64 {
+        self.horizon_days
+    }
+    /// First day projected stock reaches zero; `None` if it lasts the horizon.
+    pub fn stockout_date(&self) -> Option<NaiveDate> {
+        self.stockout_date
+    }
+    /// Days until the stock-out, counting receipts; `None` if stock lasts the
+    /// horizon.
+    pub fn days_of_cover(&self) -> Option<Decimal> {
+        self.days_of_cover
+    }
+    pub fn next_delivery_date(&self) -> Option<NaiveDate> {
+        self.next_delivery_date
+    }
+    pub fn points(&self) -> &[ProjectedStockPoint] {
+        &self.points
+    }
+
+    /// True when the product runs out before the next open purchase order is
+    /// due, or runs out with no purchase order on the way.
+    pub fn stocks_out_before_next_delivery(&self) -> bool {
+        match (self.stockout_date, self.next_delivery_date) {
+            (Some(stockout), Some(delivery)) => stockout < delivery,
+            (Some(_), None) => true,
+            (None, _) => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    fn day(d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2026, 3, d).unwrap()
+    }
+
+    fn receipt(date: NaiveDate, quantity: Decimal) -> IncomingReceipt {
+        IncomingReceipt {
+            purchase_order_id: Uuid::nil(),
+            expected_date: date,
+            quantity,
+        }
+    }
+
+    fn project(
+        available: Decimal,
+        demand: Decimal,
+        receipts: &[IncomingReceipt],
+    ) -> AvailabilityForecast {
+        AvailabilityForecast::project(
+            Uuid::nil(),
+            Uuid::nil(),
+            day(1),
+            available,
+            demand,
+            30,
+            receipts,
+        )
+    }
+
+    #[test]
+    fn stock_out_without_deliveries() {
+        // 10 units at 4/day: 6 left after day 0, 2 after day 1, out on day 2.
+        let f = project(dec!(10), dec!(4), &[]);
+        assert_eq!(f.stockout_date(), Some(day(3)));
+        assert_eq!(f.days_of_cover(), Some(dec!(2.5)));
+        assert_eq!(f.points().len(), 3);
+        assert!(f.stocks_out_before_next_delivery());
+    }
+
+    #[test]
+    fn delivery_on_time_pushes_stock_out_back() {
+        // Out on day 2 alone; 20 units arriving that morning last 5 more days.
+        let f = project(dec!(10), dec!(4), &[receipt(day(3), dec!(20))]);
+        assert_eq!(f.next_delivery_date(), Some(day(3)));
+        assert_eq!(f.stockout_date(), Some(day(8)));
+        assert_eq!(f.days_of_cover(), Some(dec!(7.5)));
+        assert!(!f.stocks_out_before_next_delivery());
+    }
+
+    #[test]
+    fn late_delivery_is_flagged() {
+        let f = project(dec!(10), dec!(4), &[receipt(day(10), dec!(100))]);
+        assert_eq!(f.stockout_date(), Some(day(3)));
+        assert!(f.stocks_out_before_next_delivery());
+    }
+
+    #[test]
+    fn overdue_receipts_arrive_today() {
+        let f = project(dec!(0), dec!(4), &[receipt(NaiveDate::MIN, dec!(8))]);
+        assert_eq!(f.next_delivery_date(), Some(day(1)));
+        assert_eq!(f.points()[0].receipts, dec!(8));
+        assert_eq!(f.stockout_date(), Some(day(2)));
+    }
+
+    #[test]
+    fn no_demand_never_stocks_out() {
+        let f = project(dec!(0), dec!(0), &[]);
+        assert_eq!(f.stockout_date(), None);
+        assert_eq!(f.days_of_cover(), None);
+        assert_eq!(f.points().len(), 30);
+        assert!(!f.stocks_out_before_next_delivery());
+    }
+}
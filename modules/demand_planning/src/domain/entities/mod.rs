@@ -1,11 +1,15 @@
 mod abc_classification;
+mod availability_forecast;
 mod demand_forecast;
 mod reorder_policy;
 mod replenishment_suggestion;
+mod sales_velocity;
 mod series_point;
 
 pub use abc_classification::AbcClassification;
+pub use availability_forecast::{AvailabilityForecast, ProjectedStockPoint};
 pub use demand_forecast::DemandForecast;
 pub use reorder_policy::ReorderPolicy;
 pub use replenishment_suggestion::ReplenishmentSuggestion;
+pub use sales_velocity::{SalesVelocity, VelocityWindows};
 pub use series_point::SeriesPoint;
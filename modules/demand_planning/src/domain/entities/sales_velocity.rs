@@ -0,0 +1,209 @@
+//! SalesVelocity — units/day sold for a variant over three trailing windows
+//! (short / medium / long, 7/30/90 days by default), net of returns.
+//!
+//! Computed on the fly from `SalesHistoryRepository::units_sold_by_window`;
+//! nothing is persisted. Shared by replenishment (how fast will stock run out)
+//! and markdown/clearance (what isn't moving), so both read the same numbers.
+
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::DemandPlanningError;
+use crate::domain::value_objects::VelocityTrend;
+
+/// Short-vs-long rate ratio beyond which the trend stops being "steady".
+/// ±15% keeps weekly noise from flipping the label back and forth.
+const TREND_TOLERANCE: Decimal = Decimal::from_parts(15, 0, 0, false, 2);
+
+/// Trailing windows, in days, ending at the evaluation date.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct VelocityWindows {
+    pub short_days: i64,
+    pub medium_days: i64,
+    pub long_days: i64,
+}
+
+impl Default for VelocityWindows {
+    fn default() -> Self {
+        Self {
+            short_days: 7,
+            medium_days: 30,
+            long_days: 90,
+        }
+    }
+}
+
+impl VelocityWindows {
+    /// Windows must be positive and strictly nested (short < medium < long)
+    /// so the long window's query range covers the other two.
+    pub fn new(
+        short_days: i64,
+        medium_days: i64,
+        long_days: i64,
+    ) -> Result<Self, DemandPlanningError> {
+        if short_days <= 0 || short_days >= medium_days || medium_days >= long_days {
+            return Err(DemandPlanningError::InvalidVelocityWindows);
+        }
+        Ok(Self {
+            short_days,
+            medium_days,
+            long_days,
+        })
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SalesVelocity {
+    product_variant_id: Uuid,
+    store_id: Option<Uuid>,
+    windows: VelocityWindows,
+    short_units: Decimal,
+    medium_units: Decimal,
+    long_units: Decimal,
+}
+
+impl SalesVelocity {
+    /// `store_id = None` means the figures aggregate every store.
+    pub fn new(
+        product_variant_id: Uuid,
+        store_id: Option<Uuid>,
+        windows: VelocityWindows,
+        short_units: Decimal,
+        medium_units: Decimal,
+        long_units: Decimal,
+    ) -> Self {
+        Self {
+            product_variant_id,
+            store_id,
+            windows,
+            short_units,
+            medium_units,
+            long_units,
+        }
+    }
+
+    pub fn product_variant_id(&self) -> Uuid {
+        self.product_variant_id
+    }
+    pub fn store_id(&self) -> Option<Uuid> {
+        self.store_id
+    }
+    pub fn windows(&self) -> VelocityWindows {
+        self.windows
+    }
+    pub fn short_units(&self) -> Decimal {
+        self.short_units
+    }
+    pub fn medium_units(&self) -> Decimal {
+        self.medium_units
+    }
+    pub fn long_units(&self) -> Decimal {
+        self.long_units
+    }
+
+    pub fn short_per_day(&self) -> Decimal {
+        per_day(self.short_units, self.windows.short_days)
+    }
+    pub fn medium_per_day(&self) -> Decimal {
+        per_day(self.medium_units, self.windows.medium_days)
+    }
+    pub fn long_per_day(&self) -> Decimal {
+        per_day(self.long_units, self.windows.long_days)
+    }
+
+    /// Compares the short window's rate against the long window's. A variant
+    /// with no long-window sales but some recent ones is accelerating; one
+    /// that sold nothing in either is steady (at zero).
+    pub fn trend(&self) -> VelocityTrend {
+        let short = self.short_per_day();
+        let long = self.long_per_day();
+        if long <= Decimal::ZERO {
+            return if short > Decimal::ZERO {
+                VelocityTrend::Accelerating
+            } else {
+                VelocityTrend::Steady
+            };
+        }
+        let ratio = short / long;
+        if ratio > Decimal::ONE + TREND_TOLERANCE {
+            VelocityTrend::Accelerating
+        } else if ratio < Decimal::ONE - TREND_TOLERANCE {
+            VelocityTrend::Decelerating
+        } else {
+            VelocityTrend::Steady
+        }
+    }
+}
+
+fn per_day(units: Decimal, days: i64) -> Decimal {
+    if days <= 0 {
+        return Decimal::ZERO;
+    }
+    (units / Decimal::from(days)).round_dp(4)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+    use uuid::{NoContext, Timestamp};
+
+    fn build(short: Decimal, medium: Decimal, long: Decimal) -> SalesVelocity {
+        SalesVelocity::new(
+            Uuid::new_v7(Timestamp::now(NoContext)),
+            None,
+            VelocityWindows::default(),
+            short,
+            medium,
+            long,
+        )
+    }
+
+    #[test]
+    fn windows_must_be_nested() {
+        assert!(VelocityWindows::new(7, 30, 90).is_ok());
+        assert!(VelocityWindows::new(0, 30, 90).is_err());
+        assert!(VelocityWindows::new(30, 30, 90).is_err());
+        assert!(VelocityWindows::new(7, 90, 30).is_err());
+    }
+
+    #[test]
+    fn per_day_rates_divide_by_window_length() {
+        let v = build(dec!(14), dec!(30), dec!(180));
+        assert_eq!(v.short_per_day(), dec!(2));
+        assert_eq!(v.medium_per_day(), dec!(1));
+        assert_eq!(v.long_per_day(), dec!(2));
+    }
+
+    #[test]
+    fn trend_compares_short_against_long() {
+        // 3/day recently vs 1/day over 90 days.
+        assert_eq!(
+            build(dec!(21), dec!(40), dec!(90)).trend(),
+            VelocityTrend::Accelerating
+        );
+        // 0.5/day recently vs 1/day over 90 days.
+        assert_eq!(
+            build(dec!(3.5), dec!(25), dec!(90)).trend(),
+            VelocityTrend::Decelerating
+        );
+        // Within tolerance.
+        assert_eq!(
+            build(dec!(7.5), dec!(30), dec!(90)).trend(),
+            VelocityTrend::Steady
+        );
+    }
+
+    #[test]
+    fn trend_handles_empty_history() {
+        assert_eq!(
+            build(dec!(0), dec!(0), dec!(0)).trend(),
+            VelocityTrend::Steady
+        );
+        assert_eq!(
+            build(dec!(2), dec!(2), dec!(0)).trend(),
+            VelocityTrend::Accelerating
+        );
+    }
+}
@@ -0,0 +1,30 @@
+//! Read-only projection over `purchase_orders` / `purchase_order_items` so the
+//! availability forecast can add open purchase-order quantities on their
+//! expected delivery dates.
+
+use async_trait::async_trait;
+use chrono::NaiveDate;
+use rust_decimal::Decimal;
+use uuid::Uuid;
+
+use crate::DemandPlanningError;
+
+#[derive(Debug, Clone, Copy)]
+pub struct IncomingReceipt {
+    pub purchase_order_id: Uuid,
+    pub expected_date: NaiveDate,
+    /// Ordered minus already received
+    pub quantity: Decimal,
+}
+
+#[async_trait]
+pub trait IncomingSupplyRepository: Send + Sync {
+    /// Quantities still to be received for a (variant, store) tuple on
+    /// submitted, approved or partially received purchase orders that have an
+    /// expected delivery date.
+    async fn open_receipts(
+        &self,
+        product_variant_id: Uuid,
+        store_id: Uuid,
+    ) -> Result<Vec<IncomingReceipt>, DemandPlanningError>;
+}
@@ -1,5 +1,6 @@
 mod abc_classification_repository;
 mod demand_forecast_repository;
+mod incoming_supply_repository;
 mod reorder_policy_repository;
 mod replenishment_suggestion_repository;
 mod sales_history_repository;
@@ -7,7 +8,8 @@ mod stock_snapshot_repository;
 
 pub use abc_classification_repository::AbcClassificationRepository;
 pub use demand_forecast_repository::DemandForecastRepository;
+pub use incoming_supply_repository::{IncomingReceipt, IncomingSupplyRepository};
 pub use reorder_policy_repository::ReorderPolicyRepository;
 pub use replenishment_suggestion_repository::ReplenishmentSuggestionRepository;
-pub use sales_history_repository::{RevenueRow, SalesHistoryRepository};
+pub use sales_history_repository::{RevenueRow, SalesHistoryRepository, VelocityRow};
 pub use stock_snapshot_repository::{StockSnapshot, StockSnapshotRepository};
@@ -7,7 +7,7 @@ use rust_decimal::Decimal;
 use uuid::Uuid;
 
 use crate::DemandPlanningError;
-use crate::domain::entities::SeriesPoint;
+use crate::domain::entities::{SeriesPoint, VelocityWindows};
 use crate::domain::value_objects::ForecastPeriod;
 
 #[async_trait]
@@ -38,6 +38,19 @@ pub trait SalesHistoryRepository: Send + Sync {
         from: NaiveDate,
         to: NaiveDate,
     ) -> Result<Vec<RevenueRow>, DemandPlanningError>;
+
+    /// Net units sold per variant over each trailing window ending at `as_of`
+    /// (exclusive), in one grouped pass. Voided sales are ignored and applied
+    /// credit notes are subtracted on the day they were applied. `store_id =
+    /// None` aggregates across stores; `product_variant_id = None` returns
+    /// every variant with activity in the long window.
+    async fn units_sold_by_window(
+        &self,
+        store_id: Option<Uuid>,
+        product_variant_id: Option<Uuid>,
+        as_of: NaiveDate,
+        windows: VelocityWindows,
+    ) -> Result<Vec<VelocityRow>, DemandPlanningError>;
 }
 
 #[derive(Debug, Clone)]
@@ -46,3 +59,11 @@ pub struct RevenueRow {
     pub store_id: Uuid,
     pub revenue: Decimal,
 }
+
+#[derive(Debug, Clone)]
+pub struct VelocityRow {
+    pub product_variant_id: Uuid,
+    pub short_units: Decimal,
+    pub medium_units: Decimal,
+    pub long_units: Decimal,
+}
@@ -3,9 +3,11 @@ mod forecast_method;
 mod forecast_period;
 mod ids;
 mod suggestion_status;
+mod velocity_trend;
 
 pub use abc_class::AbcClass;
 pub use forecast_method::ForecastMethod;
 pub use forecast_period::ForecastPeriod;
 pub use ids::{AbcClassificationId, ForecastId, ReorderPolicyId, SuggestionId};
 pub use suggestion_status::SuggestionStatus;
+pub use velocity_trend::VelocityTrend;
@@ -0,0 +1,25 @@
+//! VelocityTrend — direction of a variant's sales rate, read by comparing the
+//! short window's units/day against the long window's.
+
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum VelocityTrend {
+    Accelerating,
+    Steady,
+    Decelerating,
+}
+
+impl fmt::Display for VelocityTrend {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let s = match self {
+            VelocityTrend::Accelerating => "accelerating",
+            VelocityTrend::Steady => "steady",
+            VelocityTrend::Decelerating => "decelerating",
+        };
+        f.write_str(s)
+    }
+}
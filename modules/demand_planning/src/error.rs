@@ -48,6 +48,12 @@ pub enum DemandPlanningError {
     #[error("Forecasting failed: {0}")]
     ForecastingFailed(String),
 
+    #[error("Velocity windows must be positive and strictly increasing (short < medium < long)")]
+    InvalidVelocityWindows,
+
+    #[error("Forecast horizon must be between 1 and {max} days")]
+    InvalidForecastHorizon { max: i64 },
+
     #[error("Dismiss reason is required")]
     DismissReasonRequired,
 
@@ -1,5 +1,6 @@
 mod pg_abc_classification_repository;
 mod pg_demand_forecast_repository;
+mod pg_incoming_supply_repository;
 mod pg_reorder_policy_repository;
 mod pg_replenishment_suggestion_repository;
 mod pg_sales_history_repository;
@@ -7,6 +8,7 @@ mod pg_stock_snapshot_repository;
 
 pub use pg_abc_classification_repository::PgAbcClassificationRepository;
 pub use pg_demand_forecast_repository::PgDemandForecastRepository;
+pub use pg_incoming_supply_repository::PgIncomingSupplyRepository;
 pub use pg_reorder_policy_repository::PgReorderPolicyRepository;
 pub use pg_replenishment_suggestion_repository::PgReplenishmentSuggestionRepository;
 pub use pg_sales_history_repository::PgSalesHistoryRepository;
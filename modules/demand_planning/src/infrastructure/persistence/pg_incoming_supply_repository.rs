@@ -0,0 +1,59 @@
+use async_trait::async_trait;
+use chrono::NaiveDate;
+use rust_decimal::Decimal;
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::DemandPlanningError;
+use crate::domain::repositories::{IncomingReceipt, IncomingSupplyRepository};
+
+pub struct PgIncomingSupplyRepository {
+    pool: PgPool,
+}
+
+impl PgIncomingSupplyRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl IncomingSupplyRepository for PgIncomingSupplyRepository {
+    async fn open_receipts(
+        &self,
+        product_variant_id: Uuid,
+        store_id: Uuid,
+    ) -> Result<Vec<IncomingReceipt>, DemandPlanningError> {
+        // Same (product_id, NULL) / (NULL, variant_id) convention as
+        // inventory_stock — match either by COALESCE.
+        let rows: Vec<(Uuid, NaiveDate, Decimal)> = sqlx::query_as(
+            r#"
+            SELECT po.id, po.expected_delivery_date,
+                   SUM(poi.quantity_ordered - poi.quantity_received)
+            FROM purchase_orders po
+            JOIN purchase_order_items poi ON poi.purchase_order_id = po.id
+            WHERE po.store_id = $1
+              AND COALESCE(poi.variant_id, poi.product_id) = $2
+              AND po.status IN ('submitted', 'approved', 'partially_received')
+              AND po.expected_delivery_date IS NOT NULL
+              AND poi.quantity_received < poi.quantity_ordered
+            GROUP BY po.id, po.expected_delivery_date
+            ORDER BY po.expected_delivery_date
+            "#,
+        )
+        .bind(store_id)
+        .bind(product_variant_id)
+        .fetch_all(&self.pool)
+        .await?;
+        Ok(rows
+            .into_iter()
+            .map(
+                |(purchase_order_id, expected_date, quantity)| IncomingReceipt {
+                    purchase_order_id,
+                    expected_date,
+                    quantity,
+                },
+            )
+            .collect())
+    }
+}
@@ -1,6 +1,7 @@
 //! PostgreSQL projection over `sales` + `sale_items`. Aggregates units sold by
 //! day/week/month, returns the (variant, store) pairs that have completed
-//! sales in a window, and totals revenue for ABC classification.
+//! sales in a window, totals revenue for ABC classification, and computes
+//! trailing-window sales velocity net of applied credit notes.
 //!
 //! Variants without an explicit `variant_id` (i.e. the simple-product case)
 //! still need replenishment, so the queries fall back to `product_id` cast as
@@ -9,14 +10,14 @@
 //! the same convention so its outputs line up with reorder policies and stock.
 
 use async_trait::async_trait;
-use chrono::NaiveDate;
+use chrono::{Duration, NaiveDate};
 use rust_decimal::Decimal;
 use sqlx::PgPool;
 use uuid::Uuid;
 
 use crate::DemandPlanningError;
-use crate::domain::entities::SeriesPoint;
-use crate::domain::repositories::{RevenueRow, SalesHistoryRepository};
+use crate::domain::entities::{SeriesPoint, VelocityWindows};
+use crate::domain::repositories::{RevenueRow, SalesHistoryRepository, VelocityRow};
 use crate::domain::value_objects::ForecastPeriod;
 
 pub struct PgSalesHistoryRepository {
@@ -155,4 +156,74 @@ impl SalesHistoryRepository for PgSalesHistoryRepository {
             })
             .collect())
     }
+
+    async fn units_sold_by_window(
+        &self,
+        store_id: Option<Uuid>,
+        product_variant_id: Option<Uuid>,
+        as_of: NaiveDate,
+        windows: VelocityWindows,
+    ) -> Result<Vec<VelocityRow>, DemandPlanningError> {
+        let short_from = as_of - Duration::days(windows.short_days);
+        let medium_from = as_of - Duration::days(windows.medium_days);
+        let long_from = as_of - Duration::days(windows.long_days);
+
+        // Fully returned sales flip to `returned` but their credit notes are
+        // still subtracted below, so both statuses count as sold here.
+        let rows: Vec<(Uuid, Decimal, Decimal, Decimal)> = sqlx::query_as(
+            r#"
+            WITH movements AS (
+                SELECT COALESCE(si.variant_id, si.product_id) AS variant_id,
+                       s.store_id,
+                       s.completed_at AS occurred_at,
+                       si.quantity AS units
+                FROM sales s
+                JOIN sale_items si ON si.sale_id = s.id
+                WHERE s.status IN ('completed', 'returned')
+                  AND s.completed_at IS NOT NULL
+                  AND s.completed_at >= $1
+                  AND s.completed_at < $2
+                UNION ALL
+                SELECT COALESCE(cni.variant_id, cni.product_id) AS variant_id,
+                       cn.store_id,
+                       cn.applied_at AS occurred_at,
+                       -cni.return_quantity AS units
+                FROM credit_notes cn
+                JOIN credit_note_items cni ON cni.credit_note_id = cn.id
+                WHERE cn.status = 'applied'
+                  AND cn.applied_at IS NOT NULL
+                  AND cn.applied_at >= $1
+                  AND cn.applied_at < $2
+            )
+            SELECT variant_id,
+                   COALESCE(SUM(units) FILTER (WHERE occurred_at >= $3), 0)::NUMERIC AS short_units,
+                   COALESCE(SUM(units) FILTER (WHERE occurred_at >= $4), 0)::NUMERIC AS medium_units,
+                   COALESCE(SUM(units), 0)::NUMERIC AS long_units
+            FROM movements
+            WHERE ($5::UUID IS NULL OR store_id = $5)
+              AND ($6::UUID IS NULL OR variant_id = $6)
+            GROUP BY variant_id
+            ORDER BY variant_id
+            "#,
+        )
+        .bind(long_from)
+        .bind(as_of)
+        .bind(short_from)
+        .bind(medium_from)
+        .bind(store_id)
+        .bind(product_variant_id)
+        .fetch_all(&self.pool)
+        .await?;
+        Ok(rows
+            .into_iter()
+            .map(
+                |(variant_id, short_units, medium_units, long_units)| VelocityRow {
+                    product_variant_id: variant_id,
+                    short_units,
+                    medium_units,
+                    long_units,
+                },
+            )
+            .collect())
+    }
 }
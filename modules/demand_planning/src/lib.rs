@@ -6,10 +6,13 @@
 //!   `AbcClassification`. Optimistic locking on `ReorderPolicy::version`.
 //! - **Application**: pure forecasting math (`forecasting/` — moving average,
 //!   exponential smoothing, Holt-Winters, outlier filter), use cases for
-//!   recompute/generate/approve/dismiss/classify, and a
-//!   `DemandPlanningEventSubscriber` that observes inventory and sales events.
+//!   recompute/generate/approve/dismiss/classify, draft purchase orders from
+//!   low-stock alerts, sales velocity, and a
+//!   `DemandPlanningEventSubscriber` that observes inventory and sales events,
+//!   and a day-by-day availability forecast that combines velocity with open
+//!   purchase orders.
 //! - **Infrastructure**: `Pg*Repository` implementations and read-only
-//!   projections over `sales` / `inventory_stock`.
+//!   projections over `sales` / `inventory_stock` / `purchase_orders`.
 //!
 //! Forecasting runs entirely in Rust with `statrs`; there are no external AI
 //! adapters in v1 or v2 by design (cost decision — see `docs/roadmap-modulos.md`).
@@ -26,34 +29,39 @@ pub use error::DemandPlanningError;
 
 // Domain
 pub use domain::entities::{
-    AbcClassification, DemandForecast, ReorderPolicy, ReplenishmentSuggestion, SeriesPoint,
+    AbcClassification, AvailabilityForecast, DemandForecast, ProjectedStockPoint, ReorderPolicy,
+    ReplenishmentSuggestion, SalesVelocity, SeriesPoint, VelocityWindows,
 };
 pub use domain::repositories::{
-    AbcClassificationRepository, DemandForecastRepository, ReorderPolicyRepository,
-    ReplenishmentSuggestionRepository, RevenueRow, SalesHistoryRepository, StockSnapshot,
-    StockSnapshotRepository,
+    AbcClassificationRepository, DemandForecastRepository, IncomingReceipt,
+    IncomingSupplyRepository, ReorderPolicyRepository, ReplenishmentSuggestionRepository,
+    RevenueRow, SalesHistoryRepository, StockSnapshot, StockSnapshotRepository, VelocityRow,
 };
 pub use domain::value_objects::{
     AbcClass, AbcClassificationId, ForecastId, ForecastMethod, ForecastPeriod, ReorderPolicyId,
-    SuggestionId, SuggestionStatus,
+    SuggestionId, SuggestionStatus, VelocityTrend,
 };
 
 // Application
 pub use application::dtos::{
     AbcClassificationResponse, ApproveSuggestionCommand, DemandForecastResponse,
-    DismissSuggestionCommand, ReorderPolicyResponse, ReplenishmentSuggestionResponse,
-    UpsertReorderPolicyCommand,
+    DismissSuggestionCommand, GenerateReplenishmentOrdersCommand,
+    ProductAvailabilityForecastResponse, ReorderPolicyResponse, ReplenishmentOrdersResponse,
+    ReplenishmentSuggestionResponse, SalesVelocityResponse, UnassignedReplenishmentItem,
+    UpsertReorderPolicyCommand, VelocityWindowResponse,
 };
 pub use application::subscriber::DemandPlanningEventSubscriber;
 pub use application::use_cases::{
     ApproveSuggestionUseCase, ClassifyAbcUseCase, DismissSuggestionUseCase,
-    GenerateReplenishmentSuggestionsUseCase, GetForecastUseCase, ListAbcClassificationsUseCase,
-    ListReorderPoliciesUseCase, ListReplenishmentSuggestionsUseCase, RecomputeForecastUseCase,
-    UpsertReorderPolicyUseCase,
+    GenerateReplenishmentOrdersUseCase, GenerateReplenishmentSuggestionsUseCase,
+    GetForecastUseCase, GetProductAvailabilityForecastUseCase, GetProductSalesVelocityUseCase,
+    ListAbcClassificationsUseCase, ListReorderPoliciesUseCase, ListReplenishmentSuggestionsUseCase,
+    RecomputeForecastUseCase, UpsertReorderPolicyUseCase,
 };
 
 // Infrastructure
 pub use infrastructure::persistence::{
-    PgAbcClassificationRepository, PgDemandForecastRepository, PgReorderPolicyRepository,
-    PgReplenishmentSuggestionRepository, PgSalesHistoryRepository, PgStockSnapshotRepository,
+    PgAbcClassificationRepository, PgDemandForecastRepository, PgIncomingSupplyRepository,
+    PgReorderPolicyRepository, PgReplenishmentSuggestionRepository, PgSalesHistoryRepository,
+    PgStockSnapshotRepository,
 };
@@ -1,5 +1,6 @@
 //! Application layer for the events module.
 
 pub mod dtos;
+pub mod publisher;
 pub mod subscriber;
 pub mod use_cases;
@@ -0,0 +1,20 @@
+//! Publisher abstraction for the external change feed.
+//!
+//! Where `EventSubscriber` delivers events to in-process modules, an
+//! `EventPublisher` hands them to systems outside this process (a message
+//! broker, a webhook, a log shipper). The drain worker calls it in outbox
+//! order and only marks an event published once `publish` returned `Ok`, so
+//! a publisher must tolerate receiving the same event twice.
+
+use async_trait::async_trait;
+
+use crate::EventsError;
+use crate::domain::entities::OutboxEvent;
+
+#[async_trait]
+pub trait EventPublisher: Send + Sync {
+    /// Stable name used in logs and error messages.
+    fn name(&self) -> &'static str;
+
+    async fn publish(&self, event: &OutboxEvent) -> Result<(), EventsError>;
+}
@@ -0,0 +1,197 @@
+//! Drain use case — publishes unpublished outbox events to external systems.
+//!
+//! Run periodically by a background worker (`api-gateway::jobs::outbox_drain`).
+//! Events are handed to the [`EventPublisher`] oldest first. The batch stops
+//! at the first publish failure so later events never overtake an earlier
+//! one; the failed event and everything after it are retried on the next
+//! tick. Events are marked published only after the publisher accepted them,
+//! which gives at-least-once delivery.
+
+use std::sync::Arc;
+
+use crate::EventsError;
+use crate::application::publisher::EventPublisher;
+use crate::domain::repositories::OutboxRepository;
+
+pub struct DrainOutboxUseCase {
+    repo: Arc<dyn OutboxRepository>,
+    publisher: Arc<dyn EventPublisher>,
+}
+
+impl DrainOutboxUseCase {
+    pub fn new(repo: Arc<dyn OutboxRepository>, publisher: Arc<dyn EventPublisher>) -> Self {
+        Self { repo, publisher }
+    }
+
+    /// Publish up to `batch_size` unpublished events. Returns how many events
+    /// were published and marked.
+    pub async fn execute(&self, batch_size: i64) -> Result<usize, EventsError> {
+        let events = self.repo.fetch_unpublished(batch_size).await?;
+        let mut published = Vec::with_capacity(events.len());
+
+        for event in &events {
+            if let Err(err) = self.publisher.publish(event).await {
+                tracing::error!(
+                    event_id = %event.id().into_uuid(),
+                    event_type = event.event_type(),
+                    publisher = self.publisher.name(),
+                    error = %err,
+                    "publish failed; draining resumes from this event"
+                );
+                break;
+            }
+            published.push(event.id());
+        }
+
+        self.repo.mark_published(&published).await?;
+        Ok(published.len())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use async_trait::async_trait;
+    use serde_json::json;
+    use sqlx::{Postgres, Transaction};
+    use std::sync::Mutex;
+
+    use crate::domain::entities::OutboxEvent;
+    use crate::domain::value_objects::OutboxEventId;
+
+    struct MockOutboxRepository {
+        events: Mutex<Vec<OutboxEvent>>,
+    }
+
+    #[async_trait]
+    impl OutboxRepository for MockOutboxRepository {
+        async fn enqueue_in_tx(
+            &self,
+            _tx: &mut Transaction<'_, Postgres>,
+            _event: &OutboxEvent,
+        ) -> Result<(), EventsError> {
+            unimplemented!()
+        }
+
+        async fn fetch_pending(&self, _batch_size: i64) -> Result<Vec<OutboxEvent>, EventsError> {
+            unimplemented!()
+        }
+
+        async fn update_after_dispatch(&self, _event: &OutboxEvent) -> Result<(), EventsError> {
+            unimplemented!()
+        }
+
+        async fn fetch_unpublished(
+            &self,
+            batch_size: i64,
+        ) -> Result<Vec<OutboxEvent>, EventsError> {
+            Ok(self
+                .events
+                .lock()
+                .unwrap()
+                .iter()
+                .filter(|e| !e.is_published())
+                .take(batch_size as usize)
+                .cloned()
+                .collect())
+        }
+
+        async fn mark_published(&self, ids: &[OutboxEventId]) -> Result<(), EventsError> {
+            for event in self.events.lock().unwrap().iter_mut() {
+                if ids.contains(&event.id()) {
+                    event.mark_published();
+                }
+            }
+            Ok(())
+        }
+
+        async fn find_by_id(&self, _id: OutboxEventId) -> Result<Option<OutboxEvent>, EventsError> {
+            unimplemented!()
+        }
+    }
+
+    /// Records what it published; fails on event types listed in `failing`.
+    struct MockPublisher {
+        published: Mutex<Vec<String>>,
+        failing: Mutex<Vec<&'static str>>,
+    }
+
+    #[async_trait]
+    impl EventPublisher for MockPublisher {
+        fn name(&self) -> &'static str {
+            "mock"
+        }
+
+        async fn publish(&self, event: &OutboxEvent) -> Result<(), EventsError> {
+            if self.failing.lock().unwrap().contains(&event.event_type()) {
+                return Err(EventsError::SubscriberFailed("broker down".into()));
+            }
+            self.published
+                .lock()
+                .unwrap()
+                .push(event.aggregate_id().to_string());
+            Ok(())
+        }
+    }
+
+    fn setup(
+        event_types: &[&str],
+        failing: Vec<&'static str>,
+    ) -> (
+        Arc<MockOutboxRepository>,
+        Arc<MockPublisher>,
+        DrainOutboxUseCase,
+    ) {
+        let events = event_types
+            .iter()
+            .enumerate()
+            .map(|(i, t)| OutboxEvent::create("stock", i.to_string(), *t, json!({})))
+            .collect();
+        let repo = Arc::new(MockOutboxRepository {
+            events: Mutex::new(events),
+        });
+        let publisher = Arc::new(MockPublisher {
+            published: Mutex::new(vec![]),
+            failing: Mutex::new(failing),
+        });
+        let use_case = DrainOutboxUseCase::new(repo.clone(), publisher.clone());
+        (repo, publisher, use_case)
+    }
+
+    #[tokio::test]
+    async fn publishes_in_order_and_marks_published() {
+        let (repo, publisher, use_case) = setup(
+            &["stock.updated", "sale.completed", "stock.updated"],
+            vec![],
+        );
+
+        assert_eq!(use_case.execute(2).await.unwrap(), 2);
+        assert_eq!(use_case.execute(10).await.unwrap(), 1);
+        assert_eq!(use_case.execute(10).await.unwrap(), 0);
+
+        assert_eq!(*publisher.published.lock().unwrap(), vec!["0", "1", "2"]);
+        assert!(repo.events.lock().unwrap().iter().all(|e| e.is_published()));
+    }
+
+    #[tokio::test]
+    async fn stops_at_first_failure_and_resumes_from_it() {
+        let (repo, publisher, use_case) = setup(
+            &["stock.updated", "sale.completed", "stock.updated"],
+            vec!["sale.completed"],
+        );
+
+        assert_eq!(use_case.execute(10).await.unwrap(), 1);
+        let published: Vec<bool> = repo
+            .events
+            .lock()
+            .unwrap()
+            .iter()
+            .map(|e| e.is_published())
+            .collect();
+        assert_eq!(published, vec![true, false, false]);
+
+        publisher.failing.lock().unwrap().clear();
+        assert_eq!(use_case.execute(10).await.unwrap(), 2);
+        assert_eq!(*publisher.published.lock().unwrap(), vec!["0", "1", "2"]);
+    }
+}
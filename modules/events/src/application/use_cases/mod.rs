@@ -1,7 +1,9 @@
 //! Use cases for the events module.
 
 mod dispatch_pending_events;
+mod drain_outbox;
 mod publish_event;
 
 pub use dispatch_pending_events::DispatchPendingEventsUseCase;
+pub use drain_outbox::DrainOutboxUseCase;
 pub use publish_event::PublishEventUseCase;
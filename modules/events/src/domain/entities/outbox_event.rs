@@ -1,6 +1,8 @@
 //! OutboxEvent entity — a domain event persisted in the same transaction as
 //! the aggregate that produced it. The dispatcher worker picks it up and
-//! delivers it to local subscribers (analytics, notifications, accounting).
+//! delivers it to local subscribers (analytics, notifications, accounting);
+//! independently, the drain worker publishes it to external systems and
+//! stamps `published_at`.
 
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
@@ -23,6 +25,7 @@ pub struct OutboxEvent {
     last_error: Option<String>,
     occurred_at: DateTime<Utc>,
     processed_at: Option<DateTime<Utc>>,
+    published_at: Option<DateTime<Utc>>,
     created_at: DateTime<Utc>,
 }
 
@@ -45,6 +48,7 @@ impl OutboxEvent {
             last_error: None,
             occurred_at: now,
             processed_at: None,
+            published_at: None,
             created_at: now,
         }
     }
@@ -61,6 +65,7 @@ impl OutboxEvent {
         last_error: Option<String>,
         occurred_at: DateTime<Utc>,
         processed_at: Option<DateTime<Utc>>,
+        published_at: Option<DateTime<Utc>>,
         created_at: DateTime<Utc>,
     ) -> Self {
         Self {
@@ -74,6 +79,7 @@ impl OutboxEvent {
             last_error,
             occurred_at,
             processed_at,
+            published_at,
             created_at,
         }
     }
@@ -92,6 +98,14 @@ impl OutboxEvent {
         }
     }
 
+    pub fn mark_published(&mut self) {
+        self.published_at = Some(Utc::now());
+    }
+
+    pub fn is_published(&self) -> bool {
+        self.published_at.is_some()
+    }
+
     // Getters
     pub fn id(&self) -> OutboxEventId {
         self.id
@@ -123,6 +137,9 @@ impl OutboxEvent {
     pub fn processed_at(&self) -> Option<DateTime<Utc>> {
         self.processed_at
     }
+    pub fn published_at(&self) -> Option<DateTime<Utc>> {
+        self.published_at
+    }
     pub fn created_at(&self) -> DateTime<Utc> {
         self.created_at
     }
@@ -139,6 +156,15 @@ mod tests {
         assert_eq!(evt.status(), EventStatus::Pending);
         assert_eq!(evt.attempts(), 0);
         assert!(evt.processed_at().is_none());
+        assert!(!evt.is_published());
+    }
+
+    #[test]
+    fn publishing_is_independent_of_dispatch_status() {
+        let mut evt = OutboxEvent::create("stock", "abc", "stock.updated", json!({}));
+        evt.mark_published();
+        assert!(evt.published_at().is_some());
+        assert_eq!(evt.status(), EventStatus::Pending);
     }
 
     #[test]
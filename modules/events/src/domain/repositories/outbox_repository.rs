@@ -7,6 +7,9 @@
 //!     pattern.
 //!   * `update_after_dispatch` is called by the dispatcher worker after it has
 //!     fanned out an event to local subscribers, to mark it processed/failed.
+//!   * `mark_published` is called by the drain worker once events reached the
+//!     external publisher. It is tracked apart from the dispatch status so the
+//!     two consumers never hold each other back.
 
 use async_trait::async_trait;
 use sqlx::{Postgres, Transaction};
@@ -30,5 +33,12 @@ pub trait OutboxRepository: Send + Sync {
     /// Persist an updated event (status / attempts / processed_at / last_error).
     async fn update_after_dispatch(&self, event: &OutboxEvent) -> Result<(), EventsError>;
 
+    /// Pull the next batch of events not yet published to external systems,
+    /// oldest first (`occurred_at`, then `id`).
+    async fn fetch_unpublished(&self, batch_size: i64) -> Result<Vec<OutboxEvent>, EventsError>;
+
+    /// Stamp `published_at` on the given events.
+    async fn mark_published(&self, ids: &[OutboxEventId]) -> Result<(), EventsError>;
+
     async fn find_by_id(&self, id: OutboxEventId) -> Result<Option<OutboxEvent>, EventsError>;
 }
@@ -1,3 +1,4 @@
 //! Infrastructure layer for the events module.
 
 pub mod persistence;
+pub mod publishers;
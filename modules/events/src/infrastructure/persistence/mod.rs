@@ -2,4 +2,4 @@
 
 mod pg_outbox_repository;
 
-pub use pg_outbox_repository::PgOutboxRepository;
+pub use pg_outbox_repository::{PgOutboxRepository, enqueue_outbox_event_in_tx};
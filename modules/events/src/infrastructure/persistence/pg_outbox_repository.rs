@@ -25,29 +25,7 @@ impl OutboxRepository for PgOutboxRepository {
         tx: &mut Transaction<'_, Postgres>,
         event: &OutboxEvent,
     ) -> Result<(), EventsError> {
-        sqlx::query(
-            r#"
-            INSERT INTO outbox_events (
-                id, aggregate_type, aggregate_id, event_type, payload,
-                status, attempts, last_error,
-                occurred_at, processed_at, created_at
-            )
-            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11)
-            "#,
-        )
-        .bind(event.id().into_uuid())
-        .bind(event.aggregate_type())
-        .bind(event.aggregate_id())
-        .bind(event.event_type())
-        .bind(event.payload())
-        .bind(event.status().to_string())
-        .bind(event.attempts())
-        .bind(event.last_error())
-        .bind(event.occurred_at())
-        .bind(event.processed_at())
-        .bind(event.created_at())
-        .execute(&mut **tx)
-        .await?;
+        enqueue_outbox_event_in_tx(tx, event).await?;
         Ok(())
     }
 
@@ -56,7 +34,7 @@ impl OutboxRepository for PgOutboxRepository {
             r#"
             SELECT id, aggregate_type, aggregate_id, event_type, payload,
                    status, attempts, last_error,
-                   occurred_at, processed_at, created_at
+                   occurred_at, processed_at, published_at, created_at
             FROM outbox_events
             WHERE status = 'pending'
             ORDER BY occurred_at ASC
@@ -95,12 +73,49 @@ impl OutboxRepository for PgOutboxRepository {
         Ok(())
     }
 
+    async fn fetch_unpublished(&self, batch_size: i64) -> Result<Vec<OutboxEvent>, EventsError> {
+        let rows = sqlx::query_as::<_, OutboxEventRow>(
+            r#"
+            SELECT id, aggregate_type, aggregate_id, event_type, payload,
+                   status, attempts, last_error,
+                   occurred_at, processed_at, published_at, created_at
+            FROM outbox_events
+            WHERE published_at IS NULL
+            ORDER BY occurred_at ASC, id ASC
+            LIMIT $1
+            "#,
+        )
+        .bind(batch_size)
+        .fetch_all(&self.pool)
+        .await?;
+
+        rows.into_iter().map(OutboxEvent::try_from).collect()
+    }
+
+    async fn mark_published(&self, ids: &[OutboxEventId]) -> Result<(), EventsError> {
+        if ids.is_empty() {
+            return Ok(());
+        }
+        let ids: Vec<uuid::Uuid> = ids.iter().map(|id| id.into_uuid()).collect();
+        sqlx::query(
+            r#"
+            UPDATE outbox_events
+            SET published_at = NOW()
+            WHERE id = ANY($1) AND published_at IS NULL
+            "#,
+        )
+        .bind(&ids)
+        .execute(&self.pool)
+        .await?;
+        Ok(())
+    }
+
     async fn find_by_id(&self, id: OutboxEventId) -> Result<Option<OutboxEvent>, EventsError> {
         let row = sqlx::query_as::<_, OutboxEventRow>(
             r#"
             SELECT id, aggregate_type, aggregate_id, event_type, payload,
                    status, attempts, last_error,
-                   occurred_at, processed_at, created_at
+                   occurred_at, processed_at, published_at, created_at
             FROM outbox_events
             WHERE id = $1
             "#,
@@ -113,6 +128,41 @@ impl OutboxRepository for PgOutboxRepository {
     }
 }
 
+/// Persist a new event inside an open transaction.
+///
+/// Free-standing so the persistence layer of other modules can write the
+/// event next to the aggregate change without holding an outbox repository.
+pub async fn enqueue_outbox_event_in_tx(
+    tx: &mut Transaction<'_, Postgres>,
+    event: &OutboxEvent,
+) -> Result<(), sqlx::Error> {
+    sqlx::query(
+        r#"
+        INSERT INTO outbox_events (
+            id, aggregate_type, aggregate_id, event_type, payload,
+            status, attempts, last_error,
+            occurred_at, processed_at, published_at, created_at
+        )
+        VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12)
+        "#,
+    )
+    .bind(event.id().into_uuid())
+    .bind(event.aggregate_type())
+    .bind(event.aggregate_id())
+    .bind(event.event_type())
+    .bind(event.payload())
+    .bind(event.status().to_string())
+    .bind(event.attempts())
+    .bind(event.last_error())
+    .bind(event.occurred_at())
+    .bind(event.processed_at())
+    .bind(event.published_at())
+    .bind(event.created_at())
+    .execute(&mut **tx)
+    .await?;
+    Ok(())
+}
+
 #[derive(sqlx::FromRow)]
 struct OutboxEventRow {
     id: uuid::Uuid,
@@ -125,6 +175,7 @@ struct OutboxEventRow {
     last_error: Option<String>,
     occurred_at: chrono::DateTime<chrono::Utc>,
     processed_at: Option<chrono::DateTime<chrono::Utc>>,
+    published_at: Option<chrono::DateTime<chrono::Utc>>,
     created_at: chrono::DateTime<chrono::Utc>,
 }
 
@@ -144,6 +195,7 @@ impl TryFrom<OutboxEventRow> for OutboxEvent {
             row.last_error,
             row.occurred_at,
             row.processed_at,
+            row.published_at,
             row.created_at,
         ))
     }
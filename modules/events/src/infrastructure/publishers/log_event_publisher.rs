@@ -0,0 +1,39 @@
+//! LogEventPublisher — writes each event as one structured `tracing` record
+//! under the `outbox` target. Lets a log shipper forward the change feed
+//! until a broker-backed publisher is configured.
+
+use async_trait::async_trait;
+
+use crate::EventsError;
+use crate::application::publisher::EventPublisher;
+use crate::domain::entities::OutboxEvent;
+
+#[derive(Debug, Clone, Default)]
+pub struct LogEventPublisher;
+
+impl LogEventPublisher {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+#[async_trait]
+impl EventPublisher for LogEventPublisher {
+    fn name(&self) -> &'static str {
+        "log"
+    }
+
+    async fn publish(&self, event: &OutboxEvent) -> Result<(), EventsError> {
+        tracing::info!(
+            target: "outbox",
+            event_id = %event.id().into_uuid(),
+            event_type = event.event_type(),
+            aggregate_type = event.aggregate_type(),
+            aggregate_id = event.aggregate_id(),
+            occurred_at = %event.occurred_at(),
+            payload = %event.payload(),
+            "outbox event published"
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,5 @@
+//! External change-feed publishers.
+
+mod log_event_publisher;
+
+pub use log_event_publisher::LogEventPublisher;
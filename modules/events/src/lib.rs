@@ -15,14 +15,22 @@
 //! re-attempted until [`MAX_DELIVERY_ATTEMPTS`] is reached, after which it
 //! is marked `failed` and requires manual review.
 //!
+//! Separately, [`DrainOutboxUseCase`] feeds the same events, in outbox order,
+//! to an [`EventPublisher`] for systems outside this process and stamps
+//! `published_at` once they were accepted. The persistence layers of
+//! inventory, sales and purchasing write `stock.updated`, `sale.completed`
+//! and `purchase_order.status_changed` through
+//! [`enqueue_outbox_event_in_tx`].
+//!
 //! ## Architecture
 //!
 //! Hexagonal/clean architecture with three layers:
 //!
 //! - **Domain**: `OutboxEvent` entity, `OutboxRepository` trait
 //! - **Application**: `PublishEventUseCase`, `DispatchPendingEventsUseCase`,
-//!   `EventSubscriber` trait, `SubscriberRegistry`
-//! - **Infrastructure**: `PgOutboxRepository`
+//!   `DrainOutboxUseCase`, `EventSubscriber` and `EventPublisher` traits,
+//!   `SubscriberRegistry`
+//! - **Infrastructure**: `PgOutboxRepository`, `LogEventPublisher`
 
 pub mod application;
 pub mod domain;
@@ -38,8 +46,12 @@ pub use domain::repositories::OutboxRepository;
 pub use domain::value_objects::{EventStatus, OutboxEventId};
 
 // Application
+pub use application::publisher::EventPublisher;
 pub use application::subscriber::{EventSubscriber, SubscriberRegistry};
-pub use application::use_cases::{DispatchPendingEventsUseCase, PublishEventUseCase};
+pub use application::use_cases::{
+    DispatchPendingEventsUseCase, DrainOutboxUseCase, PublishEventUseCase,
+};
 
 // Infrastructure
-pub use infrastructure::persistence::PgOutboxRepository;
+pub use infrastructure::persistence::{PgOutboxRepository, enqueue_outbox_event_in_tx};
+pub use infrastructure::publishers::LogEventPublisher;
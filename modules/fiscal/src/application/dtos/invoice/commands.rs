@@ -59,6 +59,8 @@ pub struct ListInvoicesQuery {
     pub search: Option<String>,
     pub page: Option<i64>,
     pub page_size: Option<i64>,
+    /// `next_cursor` of a previous page; when set, `page` is ignored
+    pub after_cursor: Option<String>,
 }
 
 /// Command to generate a fiscal report for a date range
@@ -68,3 +70,13 @@ pub struct FiscalReportCommand {
     pub date_from: String,
     pub date_to: String,
 }
+
+/// Query for auditing the numbers issued from a terminal's fiscal sequence.
+///
+/// When `cai_range_id` is omitted the terminal's active sequence is audited.
+#[derive(Debug, Deserialize)]
+pub struct InvoiceSequenceAuditQuery {
+    pub store_id: Uuid,
+    pub terminal_id: Uuid,
+    pub cai_range_id: Option<Uuid>,
+}
@@ -5,7 +5,8 @@ use rust_decimal::Decimal;
 use serde::Serialize;
 use uuid::Uuid;
 
-use crate::domain::entities::{Invoice, InvoiceLine};
+use crate::domain::entities::{FiscalSequence, Invoice, InvoiceLine};
+use crate::domain::value_objects::{InvoiceSequenceAudit, SequenceGap, VoidedSequenceNumber};
 
 /// Full response for an invoice with all details
 #[derive(Debug, Serialize)]
@@ -213,6 +214,59 @@ pub struct FiscalReportResponse {
     pub total_tax: Decimal,
 }
 
+/// Compliance report of the numbers issued from a fiscal sequence.
+///
+/// `gaps` lists issued numbers with no invoice at all; numbers whose invoice
+/// or sale was voided were legitimately issued and appear in `voided` instead.
+#[derive(Debug, Serialize)]
+pub struct InvoiceSequenceAuditResponse {
+    pub fiscal_sequence_id: Uuid,
+    pub store_id: Uuid,
+    pub terminal_id: Uuid,
+    pub cai_range_id: Uuid,
+    pub prefix: String,
+    pub range_start: i64,
+    pub range_end: i64,
+    pub is_active: bool,
+    pub first_number: Option<i64>,
+    pub last_number: Option<i64>,
+    pub issued_count: i64,
+    pub active_count: i64,
+    pub voided_count: i64,
+    pub missing_count: i64,
+    pub gaps: Vec<SequenceGap>,
+    pub voided: Vec<VoidedSequenceNumber>,
+    pub duplicates: Vec<i64>,
+    pub unrecognized: Vec<String>,
+    pub is_clean: bool,
+}
+
+impl InvoiceSequenceAuditResponse {
+    pub fn new(seq: &FiscalSequence, audit: InvoiceSequenceAudit) -> Self {
+        Self {
+            fiscal_sequence_id: seq.id().into_uuid(),
+            store_id: seq.store_id().into_uuid(),
+            terminal_id: seq.terminal_id().into_uuid(),
+            cai_range_id: seq.cai_range_id(),
+            prefix: seq.prefix().to_string(),
+            range_start: seq.range_start(),
+            range_end: seq.range_end(),
+            is_active: seq.is_active(),
+            first_number: audit.first_number(),
+            last_number: audit.last_number(),
+            issued_count: audit.issued_count(),
+            active_count: audit.active_count(),
+            voided_count: audit.voided().len() as i64,
+            missing_count: audit.missing_count(),
+            gaps: audit.gaps().to_vec(),
+            voided: audit.voided().to_vec(),
+            duplicates: audit.duplicates().to_vec(),
+            unrecognized: audit.unrecognized().to_vec(),
+            is_clean: audit.is_clean(),
+        }
+    }
+}
+
 /// Paginated response for invoice list
 #[derive(Debug, Serialize)]
 pub struct InvoiceListResponse {
@@ -221,4 +275,6 @@ pub struct InvoiceListResponse {
     pub page: i64,
     pub page_size: i64,
     pub total_pages: i64,
+    /// Pass back as `after_cursor` for the next page; `None` on the last page
+    pub next_cursor: Option<String>,
 }
@@ -12,15 +12,47 @@ use crate::application::dtos::{
 use crate::domain::repositories::TaxRateRepository;
 use crate::domain::value_objects::TaxType;
 use identity::StoreId;
+use inventory::application::helpers::resolve_effective_tax_category;
+use inventory::{CategoryRepository, InventoryError, ProductId, ProductRepository, TaxCategory};
 
 /// Use case for calculating taxes on a set of items
 pub struct CalculateTaxUseCase {
     tax_rate_repo: Arc<dyn TaxRateRepository>,
+    product_repo: Arc<dyn ProductRepository>,
+    category_repo: Arc<dyn CategoryRepository>,
 }
 
 impl CalculateTaxUseCase {
-    pub fn new(tax_rate_repo: Arc<dyn TaxRateRepository>) -> Self {
-        Self { tax_rate_repo }
+    pub fn new(
+        tax_rate_repo: Arc<dyn TaxRateRepository>,
+        product_repo: Arc<dyn ProductRepository>,
+        category_repo: Arc<dyn CategoryRepository>,
+    ) -> Self {
+        Self {
+            tax_rate_repo,
+            product_repo,
+            category_repo,
+        }
+    }
+
+    /// Resolves the tax category configured for a product, either on the
+    /// product itself or inherited from the nearest category defining one
+    async fn resolve_tax_category(
+        &self,
+        product_id: uuid::Uuid,
+    ) -> Result<Option<TaxCategory>, FiscalError> {
+        let product = self
+            .product_repo
+            .find_by_id(ProductId::from_uuid(product_id))
+            .await
+            .map_err(inventory_error)?;
+        let Some(product) = product else {
+            return Ok(None);
+        };
+        let effective = resolve_effective_tax_category(self.category_repo.as_ref(), &product)
+            .await
+            .map_err(inventory_error)?;
+        Ok(effective.map(|e| e.tax_category))
     }
 
     pub async fn execute(
@@ -70,20 +102,20 @@ impl CalculateTaxUseCase {
                     total: line_subtotal,
                 });
             } else {
-                // Determine tax type based on category or default to ISV 15%
-                // For now, check if there's a specific 18% rate for the category
-                let (tax_type, rate) = if let Some(_category_id) = item.category_id {
-                    // Check if this category has a special 18% rate
-                    let has_18_rate = tax_rates
-                        .iter()
-                        .any(|tr| tr.tax_type() == TaxType::Isv18 && tr.is_active());
-                    if has_18_rate {
+                // Use the product's configured tax category; otherwise fall
+                // back to the store's rates for the item's category
+                let (tax_type, rate) = match self.resolve_tax_category(item.product_id).await? {
+                    Some(TaxCategory::Isv15) => (TaxType::Isv15, rate_15),
+                    Some(TaxCategory::Isv18) => (TaxType::Isv18, rate_18),
+                    Some(TaxCategory::Exempt) => (TaxType::Exempt, Decimal::ZERO),
+                    None if item.category_id.is_some()
+                        && tax_rates
+                            .iter()
+                            .any(|tr| tr.tax_type() == TaxType::Isv18 && tr.is_active()) =>
+                    {
                         (TaxType::Isv18, rate_18)
-                    } else {
-                        (TaxType::Isv15, rate_15)
                     }
-                } else {
-                    (TaxType::Isv15, rate_15)
+                    None => (TaxType::Isv15, rate_15),
                 };
 
                 let tax_amount = line_subtotal * rate / hundred;
@@ -97,7 +129,7 @@ impl CalculateTaxUseCase {
                         total_taxable_18 += line_subtotal;
                         total_tax_18 += tax_amount;
                     }
-                    TaxType::Exempt => {}
+                    TaxType::Exempt => total_exempt += line_subtotal,
                 }
 
                 result_items.push(TaxCalculationResultItem {
@@ -127,3 +159,10 @@ impl CalculateTaxUseCase {
         })
     }
 }
+
+fn inventory_error(err: InventoryError) -> FiscalError {
+    match err {
+        InventoryError::Database(e) => FiscalError::Database(e),
+        _ => FiscalError::InvalidTaxType,
+    }
+}
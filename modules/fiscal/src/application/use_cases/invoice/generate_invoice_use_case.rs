@@ -2,7 +2,8 @@
 //!
 //! Supports both POS and eCommerce workflows:
 //! - POS: Sale must be `Completed`. `terminal_id` is required.
-//! - eCommerce: Order must be `Paid`, `Processing`, `Shipped`, or `Delivered`.
+//! - eCommerce: Order must be `Paid`, `Processing`, `Shipped`, `Delivered`,
+//!   `ReadyForPickup` or `PickedUp`.
 //!   `terminal_id` is optional — auto-resolved from the store's first active terminal.
 
 use std::str::FromStr;
@@ -81,8 +82,10 @@ impl GenerateInvoiceUseCase {
         // ── 4. Validate sale is in a billable state ─────────────────────
         //
         // POS:       SaleStatus::Completed
-        // eCommerce: order_status in {Paid, Processing, Shipped, Delivered}
-        //            (invoice is emitted at payment time, before delivery)
+        // eCommerce: order_status in {Paid, Processing, Shipped, Delivered,
+        //            ReadyForPickup, PickedUp}
+        //            (invoice is emitted at payment time, before delivery,
+        //            or at the counter for pickup orders)
         let is_billable = match sale.sale_type() {
             SaleType::Pos => sale.status() == SaleStatus::Completed,
             SaleType::Online => {
@@ -93,6 +96,8 @@ impl GenerateInvoiceUseCase {
                             | OrderStatus::Processing
                             | OrderStatus::Shipped
                             | OrderStatus::Delivered
+                            | OrderStatus::ReadyForPickup
+                            | OrderStatus::PickedUp
                     )
                 } else {
                     false
@@ -0,0 +1,68 @@
+//! Get invoice sequence audit use case
+
+use std::sync::Arc;
+
+use crate::FiscalError;
+use crate::application::dtos::{InvoiceSequenceAuditQuery, InvoiceSequenceAuditResponse};
+use crate::domain::repositories::{FiscalSequenceRepository, InvoiceRepository};
+use crate::domain::value_objects::InvoiceSequenceAudit;
+use identity::StoreId;
+use pos_core::TerminalId;
+
+/// Use case for auditing the numbers issued from a terminal's fiscal sequence.
+///
+/// Reports gaps (issued numbers with no invoice) separately from numbers
+/// consumed by voided invoices or voided sales.
+pub struct GetInvoiceSequenceAuditUseCase {
+    invoice_repo: Arc<dyn InvoiceRepository>,
+    fiscal_sequence_repo: Arc<dyn FiscalSequenceRepository>,
+}
+
+impl GetInvoiceSequenceAuditUseCase {
+    pub fn new(
+        invoice_repo: Arc<dyn InvoiceRepository>,
+        fiscal_sequence_repo: Arc<dyn FiscalSequenceRepository>,
+    ) -> Self {
+        Self {
+            invoice_repo,
+            fiscal_sequence_repo,
+        }
+    }
+
+    pub async fn execute(
+        &self,
+        query: InvoiceSequenceAuditQuery,
+    ) -> Result<InvoiceSequenceAuditResponse, FiscalError> {
+        let store_id = StoreId::from_uuid(query.store_id);
+        let terminal_id = TerminalId::from_uuid(query.terminal_id);
+
+        let sequence = match query.cai_range_id {
+            Some(cai_range_id) => {
+                self.fiscal_sequence_repo
+                    .find_by_terminal_and_cai_range(terminal_id, cai_range_id)
+                    .await?
+            }
+            None => {
+                self.fiscal_sequence_repo
+                    .find_active(store_id, terminal_id)
+                    .await?
+            }
+        }
+        .filter(|seq| seq.store_id() == store_id)
+        .ok_or(FiscalError::FiscalSequenceNotFound)?;
+
+        let entries = self
+            .invoice_repo
+            .find_sequence_entries(terminal_id, sequence.cai_range_id())
+            .await?;
+
+        let audit = InvoiceSequenceAudit::build(
+            sequence.prefix(),
+            sequence.range_start(),
+            sequence.current_number(),
+            entries,
+        );
+
+        Ok(InvoiceSequenceAuditResponse::new(&sequence, audit))
+    }
+}
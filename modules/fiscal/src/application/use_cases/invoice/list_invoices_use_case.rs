@@ -4,11 +4,12 @@ use std::str::FromStr;
 use std::sync::Arc;
 
 use chrono::NaiveDate;
+use common::PageCursor;
 
 use crate::FiscalError;
 use crate::application::dtos::{InvoiceListResponse, InvoiceSummaryResponse, ListInvoicesQuery};
 use crate::domain::repositories::{InvoiceFilter, InvoiceRepository};
-use crate::domain::value_objects::{InvoiceStatus, InvoiceType};
+use crate::domain::value_objects::{InvoiceId, InvoiceStatus, InvoiceType};
 use identity::StoreId;
 use pos_core::TerminalId;
 
@@ -28,6 +29,9 @@ impl ListInvoicesUseCase {
     ) -> Result<InvoiceListResponse, FiscalError> {
         let page = query.page.unwrap_or(1).max(1);
         let page_size = query.page_size.unwrap_or(20).clamp(1, 100);
+        let after = PageCursor::decode_opt(query.after_cursor.as_deref())?;
+        // A cursor page has no meaningful number
+        let page = if after.is_some() { 1 } else { page };
 
         let date_from = query.date_from.as_ref().and_then(|s| {
             NaiveDate::parse_from_str(s, "%Y-%m-%d")
@@ -56,6 +60,7 @@ impl ListInvoicesUseCase {
             date_from,
             date_to,
             search: query.search,
+            after_id: after.map(|c| InvoiceId::from_uuid(c.id())),
         };
 
         let (invoices, total) = self
@@ -64,6 +69,11 @@ impl ListInvoicesUseCase {
             .await?;
 
         let total_pages = (total as f64 / page_size as f64).ceil() as i64;
+        let next_cursor = PageCursor::next(
+            invoices.last().map(|i| i.id().into_uuid()),
+            invoices.len(),
+            page_size,
+        );
 
         Ok(InvoiceListResponse {
             items: invoices.iter().map(InvoiceSummaryResponse::from).collect(),
@@ -71,6 +81,7 @@ impl ListInvoicesUseCase {
             page,
             page_size,
             total_pages,
+            next_cursor,
         })
     }
 }
@@ -3,6 +3,7 @@
 mod calculate_tax_use_case;
 mod fiscal_report_use_case;
 mod generate_invoice_use_case;
+mod get_invoice_sequence_audit_use_case;
 mod get_invoice_use_case;
 mod list_invoices_use_case;
 mod void_invoice_use_case;
@@ -10,6 +11,7 @@ mod void_invoice_use_case;
 pub use calculate_tax_use_case::CalculateTaxUseCase;
 pub use fiscal_report_use_case::FiscalReportUseCase;
 pub use generate_invoice_use_case::GenerateInvoiceUseCase;
+pub use get_invoice_sequence_audit_use_case::GetInvoiceSequenceAuditUseCase;
 pub use get_invoice_use_case::GetInvoiceUseCase;
 pub use list_invoices_use_case::ListInvoicesUseCase;
 pub use void_invoice_use_case::VoidInvoiceUseCase;
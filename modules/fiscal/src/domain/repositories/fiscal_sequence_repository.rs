@@ -36,6 +36,13 @@ pub trait FiscalSequenceRepository: Send + Sync {
         terminal_id: TerminalId,
     ) -> Result<Option<FiscalSequence>, FiscalError>;
 
+    /// Finds the fiscal sequence of a terminal for a CAI range, active or not
+    async fn find_by_terminal_and_cai_range(
+        &self,
+        terminal_id: TerminalId,
+        cai_range_id: uuid::Uuid,
+    ) -> Result<Option<FiscalSequence>, FiscalError>;
+
     /// Atomically increments the sequence and returns the formatted number
     async fn increment_and_get(&self, id: FiscalSequenceId) -> Result<String, FiscalError>;
 
@@ -5,7 +5,7 @@ use chrono::{DateTime, Utc};
 
 use crate::FiscalError;
 use crate::domain::entities::{Invoice, InvoiceLine};
-use crate::domain::value_objects::{InvoiceId, InvoiceStatus, InvoiceType};
+use crate::domain::value_objects::{InvoiceId, InvoiceSequenceEntry, InvoiceStatus, InvoiceType};
 use identity::StoreId;
 use pos_core::TerminalId;
 use sales::SaleId;
@@ -21,6 +21,9 @@ pub struct InvoiceFilter {
     pub date_from: Option<DateTime<Utc>>,
     pub date_to: Option<DateTime<Utc>>,
     pub search: Option<String>,
+    /// Keyset cursor: only invoices with a smaller id, newest id first. The
+    /// page offset is ignored when set.
+    pub after_id: Option<InvoiceId>,
 }
 
 /// Repository trait for Invoice persistence
@@ -64,4 +67,12 @@ pub trait InvoiceRepository: Send + Sync {
         &self,
         invoice_id: InvoiceId,
     ) -> Result<Vec<InvoiceLine>, FiscalError>;
+
+    /// Lists the invoices numbered from a terminal's sequence for a CAI range,
+    /// including whether the sale behind each invoice was voided
+    async fn find_sequence_entries(
+        &self,
+        terminal_id: TerminalId,
+        cai_range_id: uuid::Uuid,
+    ) -> Result<Vec<InvoiceSequenceEntry>, FiscalError>;
 }
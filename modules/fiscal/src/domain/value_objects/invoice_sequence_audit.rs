@@ -0,0 +1,331 @@
+//! InvoiceSequenceAudit value object - reconciles a fiscal sequence against its invoices
+
+use std::collections::BTreeMap;
+
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+
+use crate::domain::value_objects::{InvoiceId, InvoiceStatus};
+use sales::SaleId;
+
+/// An invoice found for the audited sequence
+#[derive(Debug, Clone)]
+pub struct InvoiceSequenceEntry {
+    pub invoice_id: InvoiceId,
+    pub invoice_number: String,
+    pub sale_id: SaleId,
+    pub status: InvoiceStatus,
+    /// True if the sale behind the invoice was voided
+    pub sale_voided: bool,
+    pub void_reason: Option<String>,
+    pub voided_at: Option<DateTime<Utc>>,
+}
+
+impl InvoiceSequenceEntry {
+    /// Returns true if the number was issued but later nullified
+    pub fn is_voided(&self) -> bool {
+        self.status.is_final() || self.sale_voided
+    }
+}
+
+/// A run of consecutive sequence numbers with no invoice
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct SequenceGap {
+    pub from: i64,
+    pub to: i64,
+    pub count: i64,
+}
+
+/// A number that was issued and then voided, with the invoice behind it
+#[derive(Debug, Clone, Serialize)]
+pub struct VoidedSequenceNumber {
+    pub sequence_number: i64,
+    pub invoice_id: InvoiceId,
+    pub invoice_number: String,
+    pub sale_id: SaleId,
+    pub status: InvoiceStatus,
+    pub sale_voided: bool,
+    pub void_reason: Option<String>,
+    pub voided_at: Option<DateTime<Utc>>,
+}
+
+/// Reconciliation of the numbers consumed from a fiscal sequence.
+///
+/// Every number from `range_start` up to the sequence's current number was
+/// handed out. Each one is either backed by an active invoice, backed by a
+/// voided invoice (or an invoice whose sale was voided), or missing: no
+/// invoice carries it. Only missing numbers are gaps; voided numbers were
+/// legitimately issued and are reported separately.
+#[derive(Debug, Clone)]
+pub struct InvoiceSequenceAudit {
+    first_number: Option<i64>,
+    last_number: Option<i64>,
+    issued_count: i64,
+    active_count: i64,
+    voided: Vec<VoidedSequenceNumber>,
+    gaps: Vec<SequenceGap>,
+    missing_count: i64,
+    duplicates: Vec<i64>,
+    unrecognized: Vec<String>,
+}
+
+impl InvoiceSequenceAudit {
+    /// Audits the numbers `range_start..=issued_through` of a sequence using
+    /// `prefix`, against the invoices recorded for it.
+    ///
+    /// Invoice numbers that don't carry the prefix or fall outside the issued
+    /// range are listed as unrecognized; numbers carried by more than one
+    /// invoice are listed as duplicates.
+    pub fn build(
+        prefix: &str,
+        range_start: i64,
+        issued_through: i64,
+        entries: Vec<InvoiceSequenceEntry>,
+    ) -> Self {
+        let mut by_number: BTreeMap<i64, Vec<InvoiceSequenceEntry>> = BTreeMap::new();
+        let mut unrecognized = Vec::new();
+
+        for entry in entries {
+            match parse_sequence_number(prefix, &entry.invoice_number) {
+                Some(n) if (range_start..=issued_through).contains(&n) => {
+                    by_number.entry(n).or_default().push(entry)
+                }
+                _ => unrecognized.push(entry.invoice_number),
+            }
+        }
+
+        let mut active_count = 0;
+        let mut voided = Vec::new();
+        let mut duplicates = Vec::new();
+
+        for (&number, invoices) in &by_number {
+            if invoices.len() > 1 {
+                duplicates.push(number);
+            }
+            // A number counts as active if any invoice carrying it is active
+            match invoices.iter().find(|e| !e.is_voided()) {
+                Some(_) => active_count += 1,
+                None => {
+                    let e = &invoices[0];
+                    voided.push(VoidedSequenceNumber {
+                        sequence_number: number,
+                        invoice_id: e.invoice_id,
+                        invoice_number: e.invoice_number.clone(),
+                        sale_id: e.sale_id,
+                        status: e.status,
+                        sale_voided: e.sale_voided,
+                        void_reason: e.void_reason.clone(),
+                        voided_at: e.voided_at,
+                    });
+                }
+            }
+        }
+
+        let mut gaps = Vec::new();
+        let mut expected = range_start;
+        for &number in by_number.keys() {
+            if number > expected {
+                gaps.push(SequenceGap {
+                    from: expected,
+                    to: number - 1,
+                    count: number - expected,
+                });
+            }
+            expected = number + 1;
+        }
+        if issued_through >= expected {
+            gaps.push(SequenceGap {
+                from: expected,
+                to: issued_through,
+                count: issued_through - expected + 1,
+            });
+        }
+
+        let issued_count = (issued_through - range_start + 1).max(0);
+        let (first_number, last_number) = if issued_count > 0 {
+            (Some(range_start), Some(issued_through))
+        } else {
+            (None, None)
+        };
+
+        Self {
+            first_number,
+            last_number,
+            issued_count,
+            active_count,
+            voided,
+            missing_count: gaps.iter().map(|g| g.count).sum(),
+            gaps,
+            duplicates,
+            unrecognized,
+        }
+    }
+
+    /// First number issued from the sequence, if any
+    pub fn first_number(&self) -> Option<i64> {
+        self.first_number
+    }
+
+    /// Last number issued from the sequence, if any
+    pub fn last_number(&self) -> Option<i64> {
+        self.last_number
+    }
+
+    /// Count of numbers handed out by the sequence
+    pub fn issued_count(&self) -> i64 {
+        self.issued_count
+    }
+
+    /// Count of numbers backed by an active invoice
+    pub fn active_count(&self) -> i64 {
+        self.active_count
+    }
+
+    /// Numbers issued and then voided
+    pub fn voided(&self) -> &[VoidedSequenceNumber] {
+        &self.voided
+    }
+
+    /// Runs of issued numbers with no invoice
+    pub fn gaps(&self) -> &[SequenceGap] {
+        &self.gaps
+    }
+
+    /// Count of issued numbers with no invoice
+    pub fn missing_count(&self) -> i64 {
+        self.missing_count
+    }
+
+    /// Numbers carried by more than one invoice
+    pub fn duplicates(&self) -> &[i64] {
+        &self.duplicates
+    }
+
+    /// Invoice numbers that don't belong to the audited range
+    pub fn unrecognized(&self) -> &[String] {
+        &self.unrecognized
+    }
+
+    /// Returns true if every issued number is accounted for exactly once
+    pub fn is_clean(&self) -> bool {
+        self.gaps.is_empty() && self.duplicates.is_empty() && self.unrecognized.is_empty()
+    }
+}
+
+/// Extracts the sequence number from a `{prefix}{number:08}` invoice number
+fn parse_sequence_number(prefix: &str, invoice_number: &str) -> Option<i64> {
+    let digits = invoice_number.strip_prefix(prefix)?;
+    if digits.is_empty() || !digits.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    digits.parse().ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PREFIX: &str = "001-001-01-";
+
+    fn entry(number: i64, status: InvoiceStatus, sale_voided: bool) -> InvoiceSequenceEntry {
+        InvoiceSequenceEntry {
+            invoice_id: InvoiceId::new(),
+            invoice_number: format!("{}{:08}", PREFIX, number),
+            sale_id: SaleId::new(),
+            status,
+            sale_voided,
+            void_reason: None,
+            voided_at: None,
+        }
+    }
+
+    #[test]
+    fn test_contiguous_sequence_is_clean() {
+        let entries = (1..=5)
+            .map(|n| entry(n, InvoiceStatus::Emitted, false))
+            .collect();
+        let audit = InvoiceSequenceAudit::build(PREFIX, 1, 5, entries);
+
+        assert!(audit.is_clean());
+        assert_eq!(audit.first_number(), Some(1));
+        assert_eq!(audit.last_number(), Some(5));
+        assert_eq!(audit.issued_count(), 5);
+        assert_eq!(audit.active_count(), 5);
+        assert_eq!(audit.missing_count(), 0);
+    }
+
+    #[test]
+    fn test_voided_numbers_are_not_gaps() {
+        let entries = vec![
+            entry(10, InvoiceStatus::Emitted, false),
+            entry(11, InvoiceStatus::Voided, false),
+            entry(12, InvoiceStatus::Emitted, true),
+            entry(13, InvoiceStatus::Emitted, false),
+        ];
+        let audit = InvoiceSequenceAudit::build(PREFIX, 10, 13, entries);
+
+        assert!(audit.gaps().is_empty());
+        assert_eq!(audit.active_count(), 2);
+        let voided: Vec<i64> = audit.voided().iter().map(|v| v.sequence_number).collect();
+        assert_eq!(voided, vec![11, 12]);
+        assert!(audit.voided()[1].sale_voided);
+    }
+
+    #[test]
+    fn test_missing_numbers_are_grouped_into_gaps() {
+        let entries = vec![
+            entry(1, InvoiceStatus::Emitted, false),
+            entry(4, InvoiceStatus::Voided, false),
+            entry(5, InvoiceStatus::Emitted, false),
+        ];
+        let audit = InvoiceSequenceAudit::build(PREFIX, 1, 8, entries);
+
+        assert_eq!(
+            audit.gaps(),
+            &[
+                SequenceGap {
+                    from: 2,
+                    to: 3,
+                    count: 2
+                },
+                SequenceGap {
+                    from: 6,
+                    to: 8,
+                    count: 3
+                },
+            ]
+        );
+        assert_eq!(audit.missing_count(), 5);
+        assert_eq!(audit.voided().len(), 1);
+        assert!(!audit.is_clean());
+    }
+
+    #[test]
+    fn test_duplicates_and_unrecognized_numbers() {
+        let mut foreign = entry(2, InvoiceStatus::Emitted, false);
+        foreign.invoice_number = "002-001-01-00000002".to_string();
+        let entries = vec![
+            entry(1, InvoiceStatus::Emitted, false),
+            entry(1, InvoiceStatus::Emitted, false),
+            entry(2, InvoiceStatus::Emitted, false),
+            entry(9, InvoiceStatus::Emitted, false),
+            foreign,
+        ];
+        let audit = InvoiceSequenceAudit::build(PREFIX, 1, 2, entries);
+
+        assert_eq!(audit.duplicates(), &[1]);
+        assert_eq!(audit.unrecognized().len(), 2);
+        assert_eq!(audit.active_count(), 2);
+        assert!(audit.gaps().is_empty());
+    }
+
+    #[test]
+    fn test_unused_sequence() {
+        let audit = InvoiceSequenceAudit::build(PREFIX, 1, 0, Vec::new());
+
+        assert_eq!(audit.issued_count(), 0);
+        assert_eq!(audit.first_number(), None);
+        assert!(audit.gaps().is_empty());
+        assert!(audit.is_clean());
+    }
+}
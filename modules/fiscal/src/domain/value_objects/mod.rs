@@ -15,6 +15,9 @@ mod invoice_type;
 mod tax_applies_to;
 mod tax_type;
 
+// Audit value objects
+mod invoice_sequence_audit;
+
 // Re-exports - IDs
 pub use fiscal_sequence_id::FiscalSequenceId;
 pub use invoice_id::InvoiceId;
@@ -26,3 +29,8 @@ pub use invoice_status::InvoiceStatus;
 pub use invoice_type::InvoiceType;
 pub use tax_applies_to::TaxAppliesTo;
 pub use tax_type::TaxType;
+
+// Re-exports - Audit
+pub use invoice_sequence_audit::{
+    InvoiceSequenceAudit, InvoiceSequenceEntry, SequenceGap, VoidedSequenceNumber,
+};
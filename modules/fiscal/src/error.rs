@@ -120,6 +120,10 @@ pub enum FiscalError {
     #[error("Invalid tax applies-to scope")]
     InvalidTaxAppliesTo,
 
+    /// The `after_cursor` of a list query was not issued by this API.
+    #[error(transparent)]
+    InvalidPageCursor(#[from] common::InvalidPageCursor),
+
     // -------------------------------------------------------------------------
     // Database and system errors
     // -------------------------------------------------------------------------
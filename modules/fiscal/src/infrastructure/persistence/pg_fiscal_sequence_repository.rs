@@ -74,6 +74,32 @@ impl FiscalSequenceRepository for PgFiscalSequenceRepository {
         }
     }
 
+    async fn find_by_terminal_and_cai_range(
+        &self,
+        terminal_id: TerminalId,
+        cai_range_id: uuid::Uuid,
+    ) -> Result<Option<FiscalSequence>, FiscalError> {
+        let row = sqlx::query_as::<_, FiscalSequenceRow>(
+            r#"
+            SELECT id, store_id, terminal_id, cai_range_id, prefix, current_number,
+                   range_start, range_end, is_active, created_at, updated_at
+            FROM fiscal_sequences
+            WHERE terminal_id = $1 AND cai_range_id = $2
+            ORDER BY created_at DESC
+            LIMIT 1
+            "#,
+        )
+        .bind(terminal_id.into_uuid())
+        .bind(cai_range_id)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        match row {
+            Some(r) => Ok(Some(r.try_into()?)),
+            None => Ok(None),
+        }
+    }
+
     async fn increment_and_get(&self, id: FiscalSequenceId) -> Result<String, FiscalError> {
         let row = sqlx::query_as::<_, IncrementResultRow>(
             r#"
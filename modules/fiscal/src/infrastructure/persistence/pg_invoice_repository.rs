@@ -6,7 +6,9 @@ use sqlx::PgPool;
 use crate::FiscalError;
 use crate::domain::entities::{Invoice, InvoiceLine};
 use crate::domain::repositories::{InvoiceFilter, InvoiceRepository};
-use crate::domain::value_objects::{InvoiceId, InvoiceLineId, InvoiceStatus, InvoiceType, TaxType};
+use crate::domain::value_objects::{
+    InvoiceId, InvoiceLineId, InvoiceSequenceEntry, InvoiceStatus, InvoiceType, TaxType,
+};
 use identity::{StoreId, UserId};
 use inventory::Currency;
 use pos_core::TerminalId;
@@ -254,7 +256,11 @@ impl InvoiceRepository for PgInvoiceRepository {
         page: i64,
         page_size: i64,
     ) -> Result<(Vec<Invoice>, i64), FiscalError> {
-        let offset = (page - 1) * page_size;
+        let offset = if filter.after_id.is_some() {
+            0
+        } else {
+            (page - 1) * page_size
+        };
 
         let mut count_query = String::from("SELECT COUNT(*) FROM invoices WHERE 1=1");
         let mut param_idx = 1;
@@ -369,11 +375,12 @@ impl InvoiceRepository for PgInvoiceRepository {
             ));
             param_idx += 1;
         }
-        data_query.push_str(&format!(
-            " ORDER BY created_at DESC LIMIT ${} OFFSET ${}",
-            param_idx,
-            param_idx + 1
-        ));
+        if filter.after_id.is_some() {
+            data_query.push_str(&format!(" AND id < ${}", param_idx));
+            param_idx += 1;
+        }
+        data_query.push_str(" ORDER BY id DESC");
+        data_query.push_str(&format!(" LIMIT ${} OFFSET ${}", param_idx, param_idx + 1));
 
         let mut data_builder = sqlx::query_as::<_, InvoiceRow>(&data_query);
         if let Some(store_id) = filter.store_id {
@@ -400,6 +407,9 @@ impl InvoiceRepository for PgInvoiceRepository {
         if let Some(ref search) = filter.search {
             data_builder = data_builder.bind(format!("%{}%", search));
         }
+        if let Some(after_id) = filter.after_id {
+            data_builder = data_builder.bind(after_id.into_uuid());
+        }
         data_builder = data_builder.bind(page_size).bind(offset);
 
         let rows = data_builder.fetch_all(&self.pool).await?;
@@ -453,6 +463,29 @@ impl InvoiceRepository for PgInvoiceRepository {
     ) -> Result<Vec<InvoiceLine>, FiscalError> {
         self.load_lines(invoice_id).await
     }
+
+    async fn find_sequence_entries(
+        &self,
+        terminal_id: TerminalId,
+        cai_range_id: uuid::Uuid,
+    ) -> Result<Vec<InvoiceSequenceEntry>, FiscalError> {
+        let rows = sqlx::query_as::<_, InvoiceSequenceEntryRow>(
+            r#"
+            SELECT i.id, i.invoice_number, i.sale_id, i.status, i.void_reason, i.voided_at,
+                   COALESCE(s.status = 'voided', false) AS sale_voided
+            FROM invoices i
+            LEFT JOIN sales s ON s.id = i.sale_id
+            WHERE i.terminal_id = $1 AND i.cai_range_id = $2
+            ORDER BY i.invoice_number
+            "#,
+        )
+        .bind(terminal_id.into_uuid())
+        .bind(cai_range_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        rows.into_iter().map(|r| r.try_into()).collect()
+    }
 }
 
 // =============================================================================
@@ -548,6 +581,33 @@ impl InvoiceRow {
     }
 }
 
+#[derive(sqlx::FromRow)]
+struct InvoiceSequenceEntryRow {
+    id: uuid::Uuid,
+    invoice_number: String,
+    sale_id: uuid::Uuid,
+    status: String,
+    void_reason: Option<String>,
+    voided_at: Option<chrono::DateTime<chrono::Utc>>,
+    sale_voided: bool,
+}
+
+impl TryFrom<InvoiceSequenceEntryRow> for InvoiceSequenceEntry {
+    type Error = FiscalError;
+
+    fn try_from(row: InvoiceSequenceEntryRow) -> Result<Self, Self::Error> {
+        Ok(InvoiceSequenceEntry {
+            invoice_id: InvoiceId::from_uuid(row.id),
+            invoice_number: row.invoice_number,
+            sale_id: SaleId::from_uuid(row.sale_id),
+            status: row.status.parse::<InvoiceStatus>()?,
+            sale_voided: row.sale_voided,
+            void_reason: row.void_reason,
+            voided_at: row.voided_at,
+        })
+    }
+}
+
 #[derive(sqlx::FromRow)]
 struct InvoiceLineRow {
     id: uuid::Uuid,
@@ -54,6 +54,12 @@ pub use domain::value_objects::InvoiceType;
 pub use domain::value_objects::TaxAppliesTo;
 pub use domain::value_objects::TaxType;
 
+// Audit value objects
+pub use domain::value_objects::InvoiceSequenceAudit;
+pub use domain::value_objects::InvoiceSequenceEntry;
+pub use domain::value_objects::SequenceGap;
+pub use domain::value_objects::VoidedSequenceNumber;
+
 // -----------------------------------------------------------------------------
 // Domain Layer - Entities
 // -----------------------------------------------------------------------------
@@ -92,6 +98,8 @@ pub use application::dtos::GenerateInvoiceCommand;
 pub use application::dtos::InvoiceLineResponse;
 pub use application::dtos::InvoiceListResponse;
 pub use application::dtos::InvoiceResponse;
+pub use application::dtos::InvoiceSequenceAuditQuery;
+pub use application::dtos::InvoiceSequenceAuditResponse;
 pub use application::dtos::InvoiceSummaryResponse;
 pub use application::dtos::ListInvoicesQuery;
 pub use application::dtos::TaxCalculationItem;
@@ -112,6 +120,7 @@ pub use application::dtos::UpdateTaxRateCommand;
 pub use application::use_cases::CalculateTaxUseCase;
 pub use application::use_cases::FiscalReportUseCase;
 pub use application::use_cases::GenerateInvoiceUseCase;
+pub use application::use_cases::GetInvoiceSequenceAuditUseCase;
 pub use application::use_cases::GetInvoiceUseCase;
 pub use application::use_cases::ListInvoicesUseCase;
 pub use application::use_cases::VoidInvoiceUseCase;
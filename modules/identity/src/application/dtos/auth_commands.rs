@@ -74,6 +74,62 @@ pub struct RefreshCommand {
     pub refresh_token: String,
 }
 
+// =============================================================================
+// Multi-Factor Authentication Commands
+// =============================================================================
+
+/// Command to complete a login with the second factor.
+///
+/// Exactly one of `code` and `recovery_code` is expected; `code` wins when
+/// both are sent.
+#[derive(Debug, Clone, Deserialize)]
+pub struct VerifyMfaCommand {
+    /// The MFA token returned by the password step
+    pub mfa_token: String,
+    /// Current code from the authenticator app
+    pub code: Option<String>,
+    /// One of the single-use recovery codes
+    pub recovery_code: Option<String>,
+}
+
+/// Command to enable a pending enrollment with a code from the authenticator.
+#[derive(Debug, Clone, Deserialize)]
+pub struct ConfirmMfaCommand {
+    /// Current code from the authenticator app
+    pub code: String,
+}
+
+/// Command to turn MFA off, re-authenticating with both factors.
+#[derive(Debug, Clone, Deserialize)]
+pub struct DisableMfaCommand {
+    /// The user's current password
+    pub password: String,
+    /// Current code from the authenticator app
+    pub code: Option<String>,
+    /// One of the single-use recovery codes
+    pub recovery_code: Option<String>,
+}
+
+// =============================================================================
+// Password Reset Commands
+// =============================================================================
+
+/// Command to request a password reset link for an email address.
+#[derive(Debug, Clone, Deserialize)]
+pub struct RequestPasswordResetCommand {
+    /// Email address of the account
+    pub email: String,
+}
+
+/// Command to set a new password with a reset token.
+#[derive(Debug, Clone, Deserialize)]
+pub struct ResetPasswordCommand {
+    /// The raw token from the reset link
+    pub token: String,
+    /// New plain text password (minimum 8 characters)
+    pub new_password: String,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -83,6 +83,74 @@ impl LoginResponse {
     }
 }
 
+// =============================================================================
+// Multi-Factor Authentication Responses
+// =============================================================================
+
+/// Response returned by the password step of a login when the user has MFA
+/// enabled. The MFA token is exchanged for a `LoginResponse` once the second
+/// factor passes.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct MfaChallengeResponse {
+    /// Always true; lets clients tell this response from `LoginResponse`
+    pub mfa_required: bool,
+    /// Short-lived token to send with the second factor
+    pub mfa_token: String,
+    /// MFA token lifetime in seconds
+    pub expires_in: i64,
+}
+
+impl MfaChallengeResponse {
+    /// Creates a new MfaChallengeResponse.
+    pub fn new(mfa_token: String, expires_in: i64) -> Self {
+        Self {
+            mfa_required: true,
+            mfa_token,
+            expires_in,
+        }
+    }
+}
+
+/// Result of the password step of a login: tokens, or a second-factor
+/// challenge for users with MFA enabled.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(untagged)]
+pub enum LoginOutcome {
+    Authenticated(LoginResponse),
+    MfaRequired(MfaChallengeResponse),
+}
+
+/// Response returned when starting a TOTP enrollment.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct MfaEnrollmentResponse {
+    /// Base32 secret, for typing into an authenticator app
+    pub secret: String,
+    /// `otpauth://` URI, for rendering as a QR code
+    pub provisioning_uri: String,
+}
+
+/// Response returned when MFA is enabled: the recovery codes, shown once.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct MfaRecoveryCodesResponse {
+    pub recovery_codes: Vec<String>,
+}
+
+// =============================================================================
+// Password Reset
+// =============================================================================
+
+/// A password reset token issued for an existing account.
+///
+/// Holds the raw token, which must only be delivered to `email`; it is
+/// deliberately not serializable so it cannot end up in an HTTP response.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PasswordResetIssued {
+    pub user_id: Uuid,
+    pub email: String,
+    pub token: String,
+    pub expires_at: DateTime<Utc>,
+}
+
 // =============================================================================
 // Error Response
 // =============================================================================
@@ -231,6 +299,25 @@ mod tests {
         assert_eq!(response, deserialized);
     }
 
+    #[test]
+    fn test_login_outcome_serializes_untagged() {
+        let tokens = LoginOutcome::Authenticated(LoginResponse::new(
+            "access".to_string(),
+            "refresh".to_string(),
+            900,
+        ));
+        let json = serde_json::to_value(&tokens).unwrap();
+        assert_eq!(json["access_token"], "access");
+        assert!(json.get("mfa_required").is_none());
+
+        let challenge =
+            LoginOutcome::MfaRequired(MfaChallengeResponse::new("mfa".to_string(), 300));
+        let json = serde_json::to_value(&challenge).unwrap();
+        assert_eq!(json["mfa_required"], true);
+        assert_eq!(json["mfa_token"], "mfa");
+        assert_eq!(json["expires_in"], 300);
+    }
+
     #[test]
     fn test_error_response_serialize() {
         let response = ErrorResponse::new("INVALID_CREDENTIALS", "Invalid credentials");
@@ -4,6 +4,7 @@
 // They use primitive types (String, Uuid, bool) rather than domain value objects
 // to keep the application boundary clean and allow validation in use cases.
 
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
@@ -100,6 +101,20 @@ pub struct UpdateStoreCommand {
     pub is_ecommerce: Option<bool>,
 }
 
+/// Command to change a store's settings
+///
+/// Only provided fields are changed. Unknown fields are rejected so a typo
+/// can't silently leave a setting untouched.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct UpdateStoreSettingsCommand {
+    pub loyalty_enabled: Option<bool>,
+    pub auto_markdowns_enabled: Option<bool>,
+    pub allow_negative_stock: Option<bool>,
+    pub pickup_hold_days: Option<i32>,
+    pub transit_owned_by_destination: Option<bool>,
+}
+
 // =============================================================================
 // Role Assignment Commands
 // =============================================================================
@@ -191,3 +206,37 @@ pub struct SetStoreActiveCommand {
     /// Whether the store should be active
     pub is_active: bool,
 }
+
+// =============================================================================
+// Audit Commands
+// =============================================================================
+
+/// Command to place a legal hold on a record's audit trail
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PlaceAuditLegalHoldCommand {
+    /// Audited entity type (e.g., "user", "store")
+    pub entity_type: String,
+    /// Audited entity id
+    pub entity_id: Uuid,
+    /// Why the trail must be preserved (case number, request, ...)
+    pub reason: String,
+}
+
+/// Query for listing the audit trail
+///
+/// All filters are optional. `action` uses the stored form (e.g.
+/// "role_assigned"); `from` is inclusive and `to` exclusive.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ListAuditEntriesQuery {
+    pub actor_id: Option<Uuid>,
+    pub entity_type: Option<String>,
+    pub entity_id: Option<Uuid>,
+    pub action: Option<String>,
+    pub from: Option<DateTime<Utc>>,
+    pub to: Option<DateTime<Utc>>,
+    /// Also search entries already moved to the archive
+    #[serde(default)]
+    pub include_archived: bool,
+    pub page: Option<i64>,
+    pub page_size: Option<i64>,
+}
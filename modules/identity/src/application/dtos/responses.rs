@@ -1,6 +1,10 @@
 // Response DTOs for identity operations
 
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::domain::entities::{AuditEntry, FieldChange};
 
 // =============================================================================
 // List Response (Simple)
@@ -22,3 +26,66 @@ impl<T> ListResponse<T> {
         Self { items, total }
     }
 }
+
+// =============================================================================
+// Audit Archive Result
+// =============================================================================
+
+/// Outcome of one audit archival run
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ArchiveAuditResult {
+    /// Entries created before this instant were eligible
+    pub cutoff: Option<chrono::DateTime<chrono::Utc>>,
+    /// Entries moved to the archive table
+    pub archived: u64,
+    /// Batches executed
+    pub batches: u32,
+}
+
+// =============================================================================
+// Audit Trail
+// =============================================================================
+
+/// One audit entry with its before/after snapshots
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AuditEntryResponse {
+    pub id: Uuid,
+    pub entity_type: String,
+    pub entity_id: Uuid,
+    pub action: String,
+    pub actor_id: Uuid,
+    /// State before the change (`None` for creations)
+    pub old_value: Option<serde_json::Value>,
+    /// State after the change (`None` for deletions)
+    pub new_value: Option<serde_json::Value>,
+    /// Fields changed by an update; empty for other actions
+    pub changes: Vec<FieldChange>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl From<&AuditEntry> for AuditEntryResponse {
+    fn from(entry: &AuditEntry) -> Self {
+        Self {
+            id: entry.id(),
+            entity_type: entry.entity_type().to_string(),
+            entity_id: entry.entity_id(),
+            action: entry.action().to_string(),
+            actor_id: entry.actor_id().into_uuid(),
+            old_value: entry.old_value().cloned(),
+            new_value: entry.new_value().cloned(),
+            changes: entry.changes().to_vec(),
+            created_at: entry.created_at(),
+        }
+    }
+}
+
+/// One page of the audit trail, oldest entry first
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AuditTrailResponse {
+    pub items: Vec<AuditEntryResponse>,
+    /// Current page number (1-indexed)
+    pub page: i64,
+    pub page_size: i64,
+    pub total_items: i64,
+    pub total_pages: i64,
+}
@@ -0,0 +1,697 @@
+// Audit use cases - Trail queries, retention, archival and legal holds for the audit log
+
+use std::sync::Arc;
+
+use chrono::{Duration, Utc};
+use uuid::Uuid;
+
+use crate::application::dtos::{
+    ArchiveAuditResult, AuditEntryResponse, AuditTrailResponse, ListAuditEntriesQuery,
+    PlaceAuditLegalHoldCommand,
+};
+use crate::domain::entities::{AuditEntry, AuditLegalHold};
+use crate::domain::repositories::{AuditArchiveRepository, AuditEntryFilter, AuditRepository};
+use crate::domain::value_objects::UserId;
+use crate::error::IdentityError;
+
+/// Shortest retention the archiver accepts, so a misconfiguration cannot
+/// empty the hot table of recent history
+pub const MIN_AUDIT_RETENTION_DAYS: i64 = 30;
+
+/// Upper bound on batches per run, so one run cannot hold the job forever
+pub const MAX_AUDIT_ARCHIVE_BATCHES: u32 = 100;
+
+/// Page size of the audit trail when none is requested
+pub const DEFAULT_AUDIT_PAGE_SIZE: i64 = 50;
+
+/// Largest page of the audit trail a single request may ask for
+pub const MAX_AUDIT_PAGE_SIZE: i64 = 200;
+
+// =============================================================================
+// ListAuditEntriesUseCase
+// =============================================================================
+
+/// Use case for reading the audit trail, e.g. for compliance reviews
+///
+/// Entries are returned oldest first with their before/after snapshots.
+/// Filtering by `entity_type` and `entity_id` with `include_archived` gives
+/// the full history of a single record.
+pub struct ListAuditEntriesUseCase<A>
+where
+    A: AuditRepository,
+{
+    audit_repo: Arc<A>,
+}
+
+impl<A> ListAuditEntriesUseCase<A>
+where
+    A: AuditRepository,
+{
+    /// Creates a new instance of ListAuditEntriesUseCase
+    pub fn new(audit_repo: Arc<A>) -> Self {
+        Self { audit_repo }
+    }
+
+    /// Lists one page of the entries matching the query
+    ///
+    /// # Errors
+    /// - `IdentityError::InvalidAuditQuery` if the action is unknown or
+    ///   `from` is not before `to`
+    pub async fn execute(
+        &self,
+        query: ListAuditEntriesQuery,
+    ) -> Result<AuditTrailResponse, IdentityError> {
+        let action = query
+            .action
+            .as_deref()
+            .map(str::trim)
+            .filter(|a| !a.is_empty())
+            .map(|a| a.parse())
+            .transpose()
+            .map_err(IdentityError::InvalidAuditQuery)?;
+
+        if let (Some(from), Some(to)) = (query.from, query.to)
+            && from >= to
+        {
+            return Err(IdentityError::InvalidAuditQuery(
+                "from must be before to".to_string(),
+            ));
+        }
+
+        let filter = AuditEntryFilter {
+            actor_id: query.actor_id.map(UserId::from_uuid),
+            entity_type: query
+                .entity_type
+                .map(|t| t.trim().to_string())
+                .filter(|t| !t.is_empty()),
+            entity_id: query.entity_id,
+            action,
+            from: query.from,
+            to: query.to,
+            include_archived: query.include_archived,
+        };
+        let page = query.page.unwrap_or(1).max(1);
+        let page_size = query
+            .page_size
+            .unwrap_or(DEFAULT_AUDIT_PAGE_SIZE)
+            .clamp(1, MAX_AUDIT_PAGE_SIZE);
+
+        let (entries, total_items) = self
+            .audit_repo
+            .find_paginated(&filter, page, page_size)
+            .await?;
+
+        Ok(AuditTrailResponse {
+            items: entries.iter().map(AuditEntryResponse::from).collect(),
+            page,
+            page_size,
+            total_items,
+            total_pages: (total_items + page_size - 1) / page_size,
+        })
+    }
+}
+
+// =============================================================================
+// ArchiveAuditUseCase
+// =============================================================================
+
+/// Use case for moving audit entries past the retention period to the archive
+///
+/// Runs as a scheduled job. Entries are moved in batches of `batch_size`
+/// until fewer than a full batch is left (or `MAX_AUDIT_ARCHIVE_BATCHES` is
+/// reached); records under a legal hold are never moved.
+pub struct ArchiveAuditUseCase<A>
+where
+    A: AuditArchiveRepository,
+{
+    archive_repo: Arc<A>,
+}
+
+impl<A> ArchiveAuditUseCase<A>
+where
+    A: AuditArchiveRepository,
+{
+    /// Creates a new instance of ArchiveAuditUseCase
+    pub fn new(archive_repo: Arc<A>) -> Self {
+        Self { archive_repo }
+    }
+
+    /// Archives entries older than `retention_days` days
+    ///
+    /// # Errors
+    /// - `IdentityError::InvalidAuditRetention` if the retention is below
+    ///   `MIN_AUDIT_RETENTION_DAYS` or the batch size is not positive
+    pub async fn execute(
+        &self,
+        retention_days: i64,
+        batch_size: i64,
+    ) -> Result<ArchiveAuditResult, IdentityError> {
+        if retention_days < MIN_AUDIT_RETENTION_DAYS {
+            return Err(IdentityError::InvalidAuditRetention(format!(
+                "retention must be at least {} days",
+                MIN_AUDIT_RETENTION_DAYS
+            )));
+        }
+        if batch_size <= 0 {
+            return Err(IdentityError::InvalidAuditRetention(
+                "batch size must be positive".to_string(),
+            ));
+        }
+
+        let cutoff = Utc::now() - Duration::days(retention_days);
+        let mut result = ArchiveAuditResult {
+            cutoff: Some(cutoff),
+            ..Default::default()
+        };
+
+        while result.batches < MAX_AUDIT_ARCHIVE_BATCHES {
+            let moved = self.archive_repo.archive_before(cutoff, batch_size).await?;
+            result.batches += 1;
+            result.archived += moved;
+            if moved < batch_size as u64 {
+                break;
+            }
+        }
+
+        Ok(result)
+    }
+}
+
+// =============================================================================
+// PlaceAuditLegalHoldUseCase
+// =============================================================================
+
+/// Use case for placing a legal hold on a record's audit trail
+pub struct PlaceAuditLegalHoldUseCase<H, A>
+where
+    H: AuditArchiveRepository,
+    A: AuditRepository,
+{
+    archive_repo: Arc<H>,
+    audit_repo: Arc<A>,
+}
+
+impl<H, A> PlaceAuditLegalHoldUseCase<H, A>
+where
+    H: AuditArchiveRepository,
+    A: AuditRepository,
+{
+    /// Creates a new instance of PlaceAuditLegalHoldUseCase
+    pub fn new(archive_repo: Arc<H>, audit_repo: Arc<A>) -> Self {
+        Self {
+            archive_repo,
+            audit_repo,
+        }
+    }
+
+    /// Places (or re-places) the hold and records it in the audit log
+    pub async fn execute(
+        &self,
+        command: PlaceAuditLegalHoldCommand,
+        actor_id: UserId,
+    ) -> Result<AuditLegalHold, IdentityError> {
+        let entity_type = command.entity_type.trim().to_string();
+        let reason = command.reason.trim().to_string();
+        if entity_type.is_empty() || reason.is_empty() {
+            return Err(IdentityError::InvalidAuditRetention(
+                "entity_type and reason are required for a legal hold".to_string(),
+            ));
+        }
+
+        let hold = AuditLegalHold::create(entity_type, command.entity_id, reason, actor_id);
+        self.archive_repo.place_legal_hold(&hold).await?;
+
+        let audit_entry =
+            AuditEntry::for_create("audit_legal_hold", hold.entity_id(), &hold, actor_id);
+        self.audit_repo.save(&audit_entry).await?;
+
+        Ok(hold)
+    }
+}
+
+// =============================================================================
+// ReleaseAuditLegalHoldUseCase
+// =============================================================================
+
+/// Use case for releasing a legal hold
+///
+/// The record's entries become eligible for archival on the next run.
+pub struct ReleaseAuditLegalHoldUseCase<H, A>
+where
+    H: AuditArchiveRepository,
+    A: AuditRepository,
+{
+    archive_repo: Arc<H>,
+    audit_repo: Arc<A>,
+}
+
+impl<H, A> ReleaseAuditLegalHoldUseCase<H, A>
+where
+    H: AuditArchiveRepository,
+    A: AuditRepository,
+{
+    /// Creates a new instance of ReleaseAuditLegalHoldUseCase
+    pub fn new(archive_repo: Arc<H>, audit_repo: Arc<A>) -> Self {
+        Self {
+            archive_repo,
+            audit_repo,
+        }
+    }
+
+    /// Releases the hold and records it in the audit log
+    ///
+    /// # Errors
+    /// - `IdentityError::LegalHoldNotFound` if the record is not on hold
+    pub async fn execute(
+        &self,
+        entity_type: &str,
+        entity_id: Uuid,
+        actor_id: UserId,
+    ) -> Result<(), IdentityError> {
+        if !self
+            .archive_repo
+            .release_legal_hold(entity_type, entity_id)
+            .await?
+        {
+            return Err(IdentityError::LegalHoldNotFound(
+                entity_type.to_string(),
+                entity_id,
+            ));
+        }
+
+        let audit_entry = AuditEntry::for_delete(
+            "audit_legal_hold",
+            entity_id,
+            &serde_json::json!({ "entity_type": entity_type, "entity_id": entity_id }),
+            actor_id,
+        );
+        self.audit_repo.save(&audit_entry).await?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use async_trait::async_trait;
+    use chrono::DateTime;
+    use std::sync::Mutex;
+    use uuid::{NoContext, Timestamp};
+
+    use crate::domain::entities::AuditAction;
+
+    fn new_uuid() -> Uuid {
+        Uuid::new_v7(Timestamp::now(NoContext))
+    }
+
+    // In-memory audit store: hot entries, archive and holds
+    struct MockAuditStore {
+        entries: Mutex<Vec<AuditEntry>>,
+        archived: Mutex<Vec<AuditEntry>>,
+        holds: Mutex<Vec<AuditLegalHold>>,
+    }
+
+    impl MockAuditStore {
+        fn new() -> Self {
+            Self {
+                entries: Mutex::new(Vec::new()),
+                archived: Mutex::new(Vec::new()),
+                holds: Mutex::new(Vec::new()),
+            }
+        }
+
+        fn insert_aged(&self, entity_type: &str, entity_id: Uuid, age_days: i64) {
+            self.entries.lock().unwrap().push(AuditEntry::new(
+                new_uuid(),
+                entity_type.to_string(),
+                entity_id,
+                AuditAction::Updated,
+                None,
+                None,
+                UserId::new(),
+                Utc::now() - Duration::days(age_days),
+            ));
+        }
+    }
+
+    #[async_trait]
+    impl AuditRepository for MockAuditStore {
+        async fn save(&self, entry: &AuditEntry) -> Result<(), IdentityError> {
+            self.entries.lock().unwrap().push(entry.clone());
+            Ok(())
+        }
+
+        async fn find_by_entity(
+            &self,
+            _entity_type: &str,
+            _entity_id: Uuid,
+        ) -> Result<Vec<AuditEntry>, IdentityError> {
+            unimplemented!()
+        }
+
+        async fn find_by_date_range(
+            &self,
+            _from: DateTime<Utc>,
+            _to: DateTime<Utc>,
+        ) -> Result<Vec<AuditEntry>, IdentityError> {
+            unimplemented!()
+        }
+
+        async fn find_paginated(
+            &self,
+            filter: &AuditEntryFilter,
+            page: i64,
+            page_size: i64,
+        ) -> Result<(Vec<AuditEntry>, i64), IdentityError> {
+            let mut matching: Vec<AuditEntry> = self.entries.lock().unwrap().clone();
+            if filter.include_archived {
+                matching.extend(self.archived.lock().unwrap().iter().cloned());
+            }
+            matching.retain(|e| {
+                filter.actor_id.is_none_or(|a| *e.actor_id() == a)
+                    && filter
+                        .entity_type
+                        .as_deref()
+                        .is_none_or(|t| e.entity_type() == t)
+                    && filter.entity_id.is_none_or(|id| e.entity_id() == id)
+                    && filter.action.as_ref().is_none_or(|a| e.action() == a)
+                    && filter.from.is_none_or(|from| e.created_at() >= from)
+                    && filter.to.is_none_or(|to| e.created_at() < to)
+            });
+            matching.sort_by_key(|e| (e.created_at(), e.id()));
+
+            let total = matching.len() as i64;
+            let page_entries = matching
+                .into_iter()
+                .skip(((page - 1) * page_size) as usize)
+                .take(page_size as usize)
+                .collect();
+            Ok((page_entries, total))
+        }
+    }
+
+    #[async_trait]
+    impl AuditArchiveRepository for MockAuditStore {
+        async fn archive_before(
+            &self,
+            cutoff: DateTime<Utc>,
+            limit: i64,
+        ) -> Result<u64, IdentityError> {
+            let holds = self.holds.lock().unwrap();
+            let mut entries = self.entries.lock().unwrap();
+            let mut archived = self.archived.lock().unwrap();
+
+            let mut moved = 0;
+            let mut kept = Vec::new();
+            for entry in entries.drain(..) {
+                let held = holds
+                    .iter()
+                    .any(|h| h.covers(entry.entity_type(), entry.entity_id()));
+                if moved < limit && entry.created_at() < cutoff && !held {
+                    archived.push(entry);
+                    moved += 1;
+                } else {
+                    kept.push(entry);
+                }
+            }
+            *entries = kept;
+            Ok(moved as u64)
+        }
+
+        async fn find_archived_by_entity(
+            &self,
+            _entity_type: &str,
+            _entity_id: Uuid,
+        ) -> Result<Vec<AuditEntry>, IdentityError> {
+            unimplemented!()
+        }
+
+        async fn place_legal_hold(&self, hold: &AuditLegalHold) -> Result<(), IdentityError> {
+            let mut holds = self.holds.lock().unwrap();
+            holds.retain(|h| !h.covers(hold.entity_type(), hold.entity_id()));
+            holds.push(hold.clone());
+            Ok(())
+        }
+
+        async fn release_legal_hold(
+            &self,
+            entity_type: &str,
+            entity_id: Uuid,
+        ) -> Result<bool, IdentityError> {
+            let mut holds = self.holds.lock().unwrap();
+            let before = holds.len();
+            holds.retain(|h| !h.covers(entity_type, entity_id));
+            Ok(holds.len() < before)
+        }
+
+        async fn find_legal_holds(&self) -> Result<Vec<AuditLegalHold>, IdentityError> {
+            Ok(self.holds.lock().unwrap().clone())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_archive_moves_only_entries_past_retention() {
+        let store = Arc::new(MockAuditStore::new());
+        let entity_id = new_uuid();
+        store.insert_aged("user", entity_id, 400);
+        store.insert_aged("user", entity_id, 200);
+        store.insert_aged("user", entity_id, 10);
+
+        let use_case = ArchiveAuditUseCase::new(store.clone());
+        let result = use_case.execute(365, 100).await.unwrap();
+
+        assert_eq!(result.archived, 1);
+        assert_eq!(result.batches, 1);
+        assert_eq!(store.entries.lock().unwrap().len(), 2);
+        assert_eq!(store.archived.lock().unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_archive_runs_batches_until_drained() {
+        let store = Arc::new(MockAuditStore::new());
+        for _ in 0..5 {
+            store.insert_aged("role", new_uuid(), 100);
+        }
+
+        let use_case = ArchiveAuditUseCase::new(store.clone());
+        let result = use_case.execute(30, 2).await.unwrap();
+
+        assert_eq!(result.archived, 5);
+        assert_eq!(result.batches, 3);
+        assert!(store.entries.lock().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_archive_never_moves_entries_under_legal_hold() {
+        let store = Arc::new(MockAuditStore::new());
+        let held_id = new_uuid();
+        let free_id = new_uuid();
+        store.insert_aged("user", held_id, 400);
+        store.insert_aged("user", free_id, 400);
+
+        PlaceAuditLegalHoldUseCase::new(store.clone(), store.clone())
+            .execute(
+                PlaceAuditLegalHoldCommand {
+                    entity_type: "user".to_string(),
+                    entity_id: held_id,
+                    reason: "Litigation".to_string(),
+                },
+                UserId::new(),
+            )
+            .await
+            .unwrap();
+
+        let result = ArchiveAuditUseCase::new(store.clone())
+            .execute(365, 100)
+            .await
+            .unwrap();
+
+        assert_eq!(result.archived, 1);
+        let archived = store.archived.lock().unwrap();
+        assert_eq!(archived.len(), 1);
+        assert_eq!(archived[0].entity_id(), free_id);
+        assert!(
+            store
+                .entries
+                .lock()
+                .unwrap()
+                .iter()
+                .any(|e| e.entity_id() == held_id)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_released_hold_makes_entries_archivable() {
+        let store = Arc::new(MockAuditStore::new());
+        let entity_id = new_uuid();
+        store.insert_aged("store", entity_id, 400);
+        store
+            .place_legal_hold(&AuditLegalHold::create(
+                "store".to_string(),
+                entity_id,
+                "Tax inspection".to_string(),
+                UserId::new(),
+            ))
+            .await
+            .unwrap();
+
+        ReleaseAuditLegalHoldUseCase::new(store.clone(), store.clone())
+            .execute("store", entity_id, UserId::new())
+            .await
+            .unwrap();
+        let result = ArchiveAuditUseCase::new(store.clone())
+            .execute(365, 100)
+            .await
+            .unwrap();
+
+        assert_eq!(result.archived, 1);
+    }
+
+    #[tokio::test]
+    async fn test_archive_rejects_short_retention() {
+        let store = Arc::new(MockAuditStore::new());
+        let result = ArchiveAuditUseCase::new(store)
+            .execute(MIN_AUDIT_RETENTION_DAYS - 1, 100)
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(IdentityError::InvalidAuditRetention(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_release_without_hold_fails() {
+        let store = Arc::new(MockAuditStore::new());
+        let result = ReleaseAuditLegalHoldUseCase::new(store.clone(), store)
+            .execute("user", new_uuid(), UserId::new())
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(IdentityError::LegalHoldNotFound(_, _))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_list_returns_full_history_of_a_record_oldest_first() {
+        let store = Arc::new(MockAuditStore::new());
+        let entity_id = new_uuid();
+        store.insert_aged("user", entity_id, 400);
+        store.insert_aged("user", entity_id, 5);
+        store.insert_aged("user", new_uuid(), 3);
+        ArchiveAuditUseCase::new(store.clone())
+            .execute(365, 100)
+            .await
+            .unwrap();
+
+        let use_case = ListAuditEntriesUseCase::new(store.clone());
+        let query = ListAuditEntriesQuery {
+            entity_type: Some("user".to_string()),
+            entity_id: Some(entity_id),
+            ..Default::default()
+        };
+
+        let hot_only = use_case.execute(query.clone()).await.unwrap();
+        assert_eq!(hot_only.total_items, 1);
+
+        let full = use_case
+            .execute(ListAuditEntriesQuery {
+                include_archived: true,
+                ..query
+            })
+            .await
+            .unwrap();
+        assert_eq!(full.total_items, 2);
+        assert!(full.items[0].created_at < full.items[1].created_at);
+        assert!(full.items.iter().all(|e| e.entity_id == entity_id));
+    }
+
+    #[tokio::test]
+    async fn test_list_filters_by_actor_and_action_with_snapshots() {
+        let store = Arc::new(MockAuditStore::new());
+        let actor = UserId::new();
+        let entity_id = new_uuid();
+        let before = serde_json::json!({ "name": "Old" });
+        let after = serde_json::json!({ "name": "New" });
+        store
+            .save(&AuditEntry::for_update(
+                "store", entity_id, &before, &after, actor,
+            ))
+            .await
+            .unwrap();
+        store
+            .save(&AuditEntry::for_create("store", entity_id, &after, actor))
+            .await
+            .unwrap();
+        store.insert_aged("store", entity_id, 1);
+
+        let result = ListAuditEntriesUseCase::new(store)
+            .execute(ListAuditEntriesQuery {
+                actor_id: Some(actor.into_uuid()),
+                action: Some("updated".to_string()),
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(result.total_items, 1);
+        assert_eq!(result.items[0].action, "updated");
+        assert_eq!(result.items[0].old_value, Some(before));
+        assert_eq!(result.items[0].new_value, Some(after));
+    }
+
+    #[tokio::test]
+    async fn test_list_paginates_and_clamps_page_size() {
+        let store = Arc::new(MockAuditStore::new());
+        for age in (1..=5).rev() {
+            store.insert_aged("role", new_uuid(), age);
+        }
+        let use_case = ListAuditEntriesUseCase::new(store);
+
+        let second = use_case
+            .execute(ListAuditEntriesQuery {
+                page: Some(2),
+                page_size: Some(2),
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+        assert_eq!(second.items.len(), 2);
+        assert_eq!(second.total_items, 5);
+        assert_eq!(second.total_pages, 3);
+
+        let clamped = use_case
+            .execute(ListAuditEntriesQuery {
+                page_size: Some(10_000),
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+        assert_eq!(clamped.page_size, MAX_AUDIT_PAGE_SIZE);
+    }
+
+    #[tokio::test]
+    async fn test_list_rejects_unknown_action_and_inverted_range() {
+        let use_case = ListAuditEntriesUseCase::new(Arc::new(MockAuditStore::new()));
+
+        let unknown = use_case
+            .execute(ListAuditEntriesQuery {
+                action: Some("renamed".to_string()),
+                ..Default::default()
+            })
+            .await;
+        assert!(matches!(unknown, Err(IdentityError::InvalidAuditQuery(_))));
+
+        let now = Utc::now();
+        let inverted = use_case
+            .execute(ListAuditEntriesQuery {
+                from: Some(now),
+                to: Some(now - Duration::days(1)),
+                ..Default::default()
+            })
+            .await;
+        assert!(matches!(inverted, Err(IdentityError::InvalidAuditQuery(_))));
+    }
+}
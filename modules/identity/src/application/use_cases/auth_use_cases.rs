@@ -7,14 +7,21 @@ use argon2::{
     password_hash::{PasswordHash, PasswordHasher, PasswordVerifier, SaltString, rand_core::OsRng},
 };
 
+use chrono::Utc;
+
 use crate::application::dtos::{
-    LoginCommand, LoginResponse, RegisterEcommerceCommand, RegisterResponse,
+    LoginCommand, LoginOutcome, LoginResponse, MfaChallengeResponse, RegisterEcommerceCommand,
+    RegisterResponse, VerifyMfaCommand,
 };
 use crate::application::validators::{validate_name, validate_password};
-use crate::domain::auth::{AuthError, LoginIdentifier, TokenService};
-use crate::domain::entities::{AuditEntry, User};
-use crate::domain::repositories::{AuditRepository, UserRepository};
-use crate::domain::value_objects::{Email, Username};
+use crate::domain::auth::{
+    AuthError, LoginIdentifier, MFA_TOKEN_LIFETIME_SECS, MfaPolicy, TokenService,
+};
+use crate::domain::entities::{AuditEntry, MfaFactor, RefreshToken, User};
+use crate::domain::repositories::{
+    AuditRepository, RefreshTokenRepository, UserMfaRepository, UserRepository,
+};
+use crate::domain::value_objects::{Email, UserId, Username};
 
 // =============================================================================
 // RegisterUserUseCase (Ecommerce Registration)
@@ -166,27 +173,42 @@ where
 /// - Identifier can be either email or username
 /// - System auto-detects the format
 /// - Returns JWT tokens on success
+/// - Users with MFA enabled get an MFA token instead, to be exchanged for
+///   JWT tokens by `VerifyMfaLoginUseCase`
 ///
 /// Requirements: 3.1
-pub struct LoginUseCase<U, T>
+pub struct LoginUseCase<U, T, M, R>
 where
     U: UserRepository,
     T: TokenService,
+    M: UserMfaRepository,
+    R: RefreshTokenRepository,
 {
     user_repo: Arc<U>,
     token_service: Arc<T>,
+    mfa_repo: Arc<M>,
+    refresh_repo: Arc<R>,
 }
 
-impl<U, T> LoginUseCase<U, T>
+impl<U, T, M, R> LoginUseCase<U, T, M, R>
 where
     U: UserRepository,
     T: TokenService,
+    M: UserMfaRepository,
+    R: RefreshTokenRepository,
 {
     /// Creates a new instance of LoginUseCase
-    pub fn new(user_repo: Arc<U>, token_service: Arc<T>) -> Self {
+    pub fn new(
+        user_repo: Arc<U>,
+        token_service: Arc<T>,
+        mfa_repo: Arc<M>,
+        refresh_repo: Arc<R>,
+    ) -> Self {
         Self {
             user_repo,
             token_service,
+            mfa_repo,
+            refresh_repo,
         }
     }
 
@@ -196,7 +218,9 @@ where
     /// * `command` - The login command containing identifier and password
     ///
     /// # Returns
-    /// `LoginResponse` with access and refresh tokens
+    /// `LoginOutcome::Authenticated` with access and refresh tokens, or
+    /// `LoginOutcome::MfaRequired` with an MFA token if the user has MFA
+    /// enabled
     ///
     /// # Errors
     /// * `AuthError::InvalidCredentials` - If user not found or password incorrect (Requirement 3.6)
@@ -207,7 +231,7 @@ where
     /// The error message for invalid credentials is intentionally vague to prevent
     /// user enumeration attacks. Both "user not found" and "wrong password" return
     /// the same error message.
-    pub async fn execute(&self, command: LoginCommand) -> Result<LoginResponse, AuthError> {
+    pub async fn execute(&self, command: LoginCommand) -> Result<LoginOutcome, AuthError> {
         // Parse identifier to determine if it's email or username (Requirement 3.2)
         let identifier = LoginIdentifier::parse(&command.identifier);
 
@@ -239,30 +263,128 @@ where
             return Err(AuthError::AccountDisabled);
         }
 
-        // Load all store permissions for embedding in token
-        let store_permissions = self
-            .user_repo
-            .get_all_store_permissions(*user.id())
+        // Tokens are only issued once the second factor passes
+        let mfa = self
+            .mfa_repo
+            .find_by_user_id(*user.id())
             .await
             .map_err(|e| AuthError::Internal(e.to_string()))?;
+        if mfa.is_some_and(|m| m.is_enabled()) {
+            let mfa_token = self.token_service.generate_mfa_token(*user.id())?;
+            return Ok(LoginOutcome::MfaRequired(MfaChallengeResponse::new(
+                mfa_token,
+                MFA_TOKEN_LIFETIME_SECS,
+            )));
+        }
 
-        // Generate JWT tokens (Requirement 3.8)
-        let access_token = self
-            .token_service
-            .generate_access_token(&user, &store_permissions)?;
-        let refresh_token = self.token_service.generate_refresh_token(*user.id())?;
+        let response = issue_login_tokens(
+            self.user_repo.as_ref(),
+            self.token_service.as_ref(),
+            self.refresh_repo.as_ref(),
+            &mut user,
+        )
+        .await?;
+        Ok(LoginOutcome::Authenticated(response))
+    }
+}
 
-        // Record the successful login. This is an UPDATE of an existing user —
-        // never save() (an INSERT), which would collide with the unique email.
-        user.record_login();
-        self.user_repo
-            .update(&user)
+// =============================================================================
+// VerifyMfaLoginUseCase
+// =============================================================================
+
+/// Use case for the second step of a login for users with MFA enabled.
+///
+/// Exchanges the MFA token from the password step plus a TOTP code or a
+/// recovery code for JWT tokens. Failed attempts count towards the user's
+/// MFA lockout.
+pub struct VerifyMfaLoginUseCase<U, T, M, R>
+where
+    U: UserRepository,
+    T: TokenService,
+    M: UserMfaRepository,
+    R: RefreshTokenRepository,
+{
+    user_repo: Arc<U>,
+    token_service: Arc<T>,
+    mfa_repo: Arc<M>,
+    refresh_repo: Arc<R>,
+    policy: MfaPolicy,
+}
+
+impl<U, T, M, R> VerifyMfaLoginUseCase<U, T, M, R>
+where
+    U: UserRepository,
+    T: TokenService,
+    M: UserMfaRepository,
+    R: RefreshTokenRepository,
+{
+    /// Creates a new instance of VerifyMfaLoginUseCase
+    pub fn new(
+        user_repo: Arc<U>,
+        token_service: Arc<T>,
+        mfa_repo: Arc<M>,
+        refresh_repo: Arc<R>,
+        policy: MfaPolicy,
+    ) -> Self {
+        Self {
+            user_repo,
+            token_service,
+            mfa_repo,
+            refresh_repo,
+            policy,
+        }
+    }
+
+    /// Executes the use case to complete a login.
+    ///
+    /// # Returns
+    /// `LoginResponse` with access and refresh tokens
+    ///
+    /// # Errors
+    /// * `AuthError::TokenExpired` / `AuthError::InvalidToken` - If the MFA token is not valid
+    /// * `AuthError::InvalidCredentials` - If the user no longer exists
+    /// * `AuthError::AccountDisabled` - If the user account is inactive
+    /// * `AuthError::MfaNotEnrolled` - If the user no longer has MFA enabled
+    /// * `AuthError::InvalidMfaCode` - If the code or recovery code is wrong
+    /// * `AuthError::MfaLocked` - After too many failed attempts
+    /// * `AuthError::Internal` - On internal errors
+    pub async fn execute(&self, command: VerifyMfaCommand) -> Result<LoginResponse, AuthError> {
+        let user_id = self.token_service.validate_mfa_token(&command.mfa_token)?;
+        let factor = mfa_factor(command.code, command.recovery_code)?;
+
+        let mut user = self
+            .user_repo
+            .find_by_id(user_id)
             .await
-            .map_err(|e| AuthError::Internal(e.to_string()))?;
+            .map_err(|e| AuthError::Internal(e.to_string()))?
+            .ok_or(AuthError::InvalidCredentials)?;
+        if !user.is_active() {
+            return Err(AuthError::AccountDisabled);
+        }
 
-        // Return login response with tokens
-        // expires_in is 900 seconds (15 minutes) as per Requirement 4.2
-        Ok(LoginResponse::new(access_token, refresh_token, 900))
+        let mut mfa = self
+            .mfa_repo
+            .find_by_user_id(user_id)
+            .await
+            .map_err(|e| AuthError::Internal(e.to_string()))?
+            .filter(|m| m.is_enabled())
+            .ok_or(AuthError::MfaNotEnrolled)?;
+
+        // Persist the attempt whether it passed or not, so failures add up
+        let verified = mfa.verify(&factor, &self.policy, Utc::now());
+        self.mfa_repo
+            .save(&mfa)
+            .await
+            .map_err(|e| AuthError::Internal(e.to_string()))?;
+        verified?;
+
+        issue_login_tokens(
+            self.user_repo.as_ref(),
+            self.token_service.as_ref(),
+            self.refresh_repo.as_ref(),
+            &mut user,
+        )
+        .await
     }
 }
 
@@ -273,44 +395,56 @@ where
 /// Use case for refreshing an access token using a refresh token.
 ///
 /// This handles token refresh where:
-/// - The refresh token is validated
+/// - The refresh token is validated and looked up in the token store
 /// - The user is verified to exist and be active
-/// - A new access token is generated
+/// - The refresh token is rotated: it is marked used and a new one of the
+///   same family is issued together with the new access token
+///
+/// Presenting a refresh token that was already rotated means a copy of it
+/// is in someone else's hands; the whole family is revoked, logging out
+/// both the thief and the legitimate session.
 ///
 /// Requirements: 4.5
-pub struct RefreshTokenUseCase<U, T>
+pub struct RefreshTokenUseCase<U, T, R>
 where
     U: UserRepository,
     T: TokenService,
+    R: RefreshTokenRepository,
 {
     user_repo: Arc<U>,
     token_service: Arc<T>,
+    refresh_repo: Arc<R>,
 }
 
-impl<U, T> RefreshTokenUseCase<U, T>
+impl<U, T, R> RefreshTokenUseCase<U, T, R>
 where
     U: UserRepository,
     T: TokenService,
+    R: RefreshTokenRepository,
 {
     /// Creates a new instance of RefreshTokenUseCase
-    pub fn new(user_repo: Arc<U>, token_service: Arc<T>) -> Self {
+    pub fn new(user_repo: Arc<U>, token_service: Arc<T>, refresh_repo: Arc<R>) -> Self {
         Self {
             user_repo,
             token_service,
+            refresh_repo,
         }
     }
 
     /// Executes the use case to refresh an access token.
     ///
     /// # Arguments
-    /// * `refresh_token` - The refresh token obtained from login
+    /// * `refresh_token` - The refresh token obtained from login or the
+    ///   previous refresh
     ///
     /// # Returns
-    /// `LoginResponse` with new access token (refresh token remains the same)
+    /// `LoginResponse` with a new access token and a new refresh token; the
+    /// presented refresh token can no longer be used
     ///
     /// # Errors
     /// * `AuthError::TokenExpired` - If the refresh token has expired (Requirement 4.6)
-    /// * `AuthError::InvalidToken` - If the refresh token is malformed or invalid (Requirement 4.6)
+    /// * `AuthError::InvalidToken` - If the refresh token is malformed, unknown or revoked (Requirement 4.6)
+    /// * `AuthError::RefreshTokenReused` - If the refresh token was already rotated
     /// * `AuthError::InvalidCredentials` - If the user no longer exists
     /// * `AuthError::AccountDisabled` - If the user account is inactive
     /// * `AuthError::Internal` - On internal errors
@@ -320,13 +454,31 @@ where
     /// - Requirement 4.5: Valid refresh token SHALL generate new access token
     /// - Requirement 4.6: Expired/invalid refresh token SHALL return appropriate error
     pub async fn execute(&self, refresh_token: String) -> Result<LoginResponse, AuthError> {
-        // Validate refresh token and extract user_id (Requirement 4.6)
-        let user_id = self.token_service.validate_refresh_token(&refresh_token)?;
+        // Validate refresh token and extract its ids (Requirement 4.6)
+        let subject = self.token_service.validate_refresh_token(&refresh_token)?;
+
+        let stored = self
+            .refresh_repo
+            .find_by_id(subject.token_id)
+            .await
+            .map_err(|e| AuthError::Internal(e.to_string()))?
+            .filter(|t| t.user_id() == subject.user_id && t.family_id() == subject.family_id)
+            .ok_or(AuthError::InvalidToken)?;
+        if stored.is_revoked() {
+            return Err(AuthError::InvalidToken);
+        }
+        if stored.is_rotated() {
+            return Err(self.revoke_reused_family(&stored).await);
+        }
+        let now = Utc::now();
+        if stored.is_expired(now) {
+            return Err(AuthError::TokenExpired);
+        }
 
         // Find user by ID
         let user = self
             .user_repo
-            .find_by_id(user_id)
+            .find_by_id(subject.user_id)
             .await
             .map_err(|e| AuthError::Internal(e.to_string()))?
             .ok_or(AuthError::InvalidCredentials)?;
@@ -336,6 +488,18 @@ where
             return Err(AuthError::AccountDisabled);
         }
 
+        // Rotate before issuing anything; losing a race against another
+        // refresh with the same token counts as reuse
+        let successor = stored.successor(self.token_service.refresh_token_lifetime());
+        let rotated = self
+            .refresh_repo
+            .rotate(stored.id(), &successor, now)
+            .await
+            .map_err(|e| AuthError::Internal(e.to_string()))?;
+        if !rotated {
+            return Err(self.revoke_reused_family(&stored).await);
+        }
+
         // Re-load store permissions for the new token
         let store_permissions = self
             .user_repo
@@ -347,21 +511,120 @@ where
         let access_token = self
             .token_service
             .generate_access_token(&user, &store_permissions)?;
+        let refresh_token = self.token_service.generate_refresh_token(&successor)?;
 
-        // Return response with new access token but same refresh token
         // expires_in is 900 seconds (15 minutes) as per Requirement 4.2
         Ok(LoginResponse::new(access_token, refresh_token, 900))
     }
+
+    /// Revokes the family of a reused token, returning the error to report
+    async fn revoke_reused_family(&self, token: &RefreshToken) -> AuthError {
+        match self
+            .refresh_repo
+            .revoke_family(token.family_id(), Utc::now())
+            .await
+        {
+            Ok(_) => AuthError::RefreshTokenReused,
+            Err(e) => AuthError::Internal(e.to_string()),
+        }
+    }
+}
+
+// =============================================================================
+// RevokeAllSessionsUseCase
+// =============================================================================
+
+/// Use case for signing a user out everywhere
+///
+/// Revokes every refresh token of the user, so no session can be extended.
+/// Access tokens already issued stay valid until they expire (15 minutes).
+pub struct RevokeAllSessionsUseCase<R>
+where
+    R: RefreshTokenRepository,
+{
+    refresh_repo: Arc<R>,
+}
+
+impl<R> RevokeAllSessionsUseCase<R>
+where
+    R: RefreshTokenRepository,
+{
+    /// Creates a new instance of RevokeAllSessionsUseCase
+    pub fn new(refresh_repo: Arc<R>) -> Self {
+        Self { refresh_repo }
+    }
+
+    /// Revokes the user's refresh tokens, returning how many were live
+    pub async fn execute(&self, user_id: UserId) -> Result<u64, AuthError> {
+        self.refresh_repo
+            .revoke_all_for_user(user_id, Utc::now())
+            .await
+            .map_err(|e| AuthError::Internal(e.to_string()))
+    }
 }
 
 // =============================================================================
 // Helper Functions
 // =============================================================================
 
+/// Issues JWT tokens to an authenticated user and records the login.
+///
+/// Each login starts a new refresh token family.
+async fn issue_login_tokens<U, T, R>(
+    user_repo: &U,
+    token_service: &T,
+    refresh_repo: &R,
+    user: &mut User,
+) -> Result<LoginResponse, AuthError>
+where
+    U: UserRepository,
+    T: TokenService,
+    R: RefreshTokenRepository,
+{
+    // Load all store permissions for embedding in token
+    let store_permissions = user_repo
+        .get_all_store_permissions(*user.id())
+        .await
+        .map_err(|e| AuthError::Internal(e.to_string()))?;
+
+    // Generate JWT tokens (Requirement 3.8)
+    let access_token = token_service.generate_access_token(user, &store_permissions)?;
+    let stored = RefreshToken::issue(*user.id(), token_service.refresh_token_lifetime());
+    refresh_repo
+        .save(&stored)
+        .await
+        .map_err(|e| AuthError::Internal(e.to_string()))?;
+    let refresh_token = token_service.generate_refresh_token(&stored)?;
+
+    // Record the successful login. This is an UPDATE of an existing user —
+    // never save() (an INSERT), which would collide with the unique email.
+    user.record_login();
+    user_repo
+        .update(user)
+        .await
+        .map_err(|e| AuthError::Internal(e.to_string()))?;
+
+    // Return login response with tokens
+    // expires_in is 900 seconds (15 minutes) as per Requirement 4.2
+    Ok(LoginResponse::new(access_token, refresh_token, 900))
+}
+
+/// Second factor sent as either a TOTP code or a recovery code
+pub(super) fn mfa_factor(
+    code: Option<String>,
+    recovery_code: Option<String>,
+) -> Result<MfaFactor, AuthError> {
+    match (code, recovery_code) {
+        (Some(code), _) => Ok(MfaFactor::Totp(code)),
+        (None, Some(recovery_code)) => Ok(MfaFactor::RecoveryCode(recovery_code)),
+        (None, None) => Err(AuthError::InvalidMfaCode),
+    }
+}
+
 /// Verifies a password against a stored Argon2 hash.
 ///
 /// Requirements: 3.5
-fn verify_password(password: &str, hash: &str) -> Result<bool, AuthError> {
+pub(super) fn verify_password(password: &str, hash: &str) -> Result<bool, AuthError> {
     let parsed_hash = PasswordHash::new(hash)
         .map_err(|e| AuthError::Internal(format!("Invalid password hash format: {}", e)))?;
 
@@ -435,7 +698,7 @@ fn generate_unique_username(email: &Email) -> Username {
 /// Hashes a password using Argon2.
 ///
 /// Requirements: 1.5
-fn hash_password(password: &str) -> Result<String, AuthError> {
+pub(super) fn hash_password(password: &str) -> Result<String, AuthError> {
     let salt = SaltString::generate(&mut OsRng);
     let argon2 = Argon2::default();
 
@@ -458,8 +721,10 @@ mod tests {
     use std::sync::Mutex;
     use uuid::Uuid;
 
-    use crate::domain::entities::{AuditAction, Permission, Role, Store};
+    use crate::domain::auth::{RefreshTokenSubject, TotpSecret};
+    use crate::domain::entities::{AuditAction, Permission, Role, Store, UserMfa};
     use crate::domain::value_objects::{RoleId, StoreId, UserId};
+    use crate::error::IdentityError;
 
     // =========================================================================
     // Mock Repositories
@@ -650,6 +915,144 @@ mod tests {
                 .cloned()
                 .collect())
         }
+
+        async fn find_paginated(
+            &self,
+            _filter: &crate::domain::repositories::AuditEntryFilter,
+            _page: i64,
+            _page_size: i64,
+        ) -> Result<(Vec<AuditEntry>, i64), crate::error::IdentityError> {
+            unimplemented!()
+        }
+    }
+
+    struct MockUserMfaRepository {
+        enrollments: Mutex<HashMap<UserId, UserMfa>>,
+    }
+
+    impl MockUserMfaRepository {
+        fn new() -> Self {
+            Self {
+                enrollments: Mutex::new(HashMap::new()),
+            }
+        }
+
+        fn with_mfa(self, mfa: UserMfa) -> Self {
+            self.enrollments.lock().unwrap().insert(mfa.user_id(), mfa);
+            self
+        }
+
+        fn get(&self, user_id: UserId) -> Option<UserMfa> {
+            self.enrollments.lock().unwrap().get(&user_id).cloned()
+        }
+    }
+
+    #[async_trait]
+    impl UserMfaRepository for MockUserMfaRepository {
+        async fn find_by_user_id(
+            &self,
+            user_id: UserId,
+        ) -> Result<Option<UserMfa>, crate::error::IdentityError> {
+            Ok(self.get(user_id))
+        }
+
+        async fn save(&self, mfa: &UserMfa) -> Result<(), crate::error::IdentityError> {
+            self.enrollments
+                .lock()
+                .unwrap()
+                .insert(mfa.user_id(), mfa.clone());
+            Ok(())
+        }
+
+        async fn delete(&self, user_id: UserId) -> Result<(), crate::error::IdentityError> {
+            self.enrollments.lock().unwrap().remove(&user_id);
+            Ok(())
+        }
+    }
+
+    // =========================================================================
+    // Mock RefreshTokenRepository
+    // =========================================================================
+
+    struct MockRefreshTokenRepository {
+        tokens: Mutex<Vec<RefreshToken>>,
+    }
+
+    impl MockRefreshTokenRepository {
+        fn new() -> Self {
+            Self {
+                tokens: Mutex::new(Vec::new()),
+            }
+        }
+
+        fn get(&self, id: Uuid) -> Option<RefreshToken> {
+            self.tokens
+                .lock()
+                .unwrap()
+                .iter()
+                .find(|t| t.id() == id)
+                .cloned()
+        }
+    }
+
+    #[async_trait]
+    impl RefreshTokenRepository for MockRefreshTokenRepository {
+        async fn save(&self, token: &RefreshToken) -> Result<(), IdentityError> {
+            self.tokens.lock().unwrap().push(token.clone());
+            Ok(())
+        }
+
+        async fn find_by_id(&self, id: Uuid) -> Result<Option<RefreshToken>, IdentityError> {
+            Ok(self.get(id))
+        }
+
+        async fn rotate(
+            &self,
+            current: Uuid,
+            successor: &RefreshToken,
+            now: DateTime<Utc>,
+        ) -> Result<bool, IdentityError> {
+            let mut tokens = self.tokens.lock().unwrap();
+            let Some(token) = tokens
+                .iter_mut()
+                .find(|t| t.id() == current && !t.is_rotated() && !t.is_revoked())
+            else {
+                return Ok(false);
+            };
+            token.rotate(successor.id(), now);
+            tokens.push(successor.clone());
+            Ok(true)
+        }
+
+        async fn revoke_family(
+            &self,
+            family_id: Uuid,
+            now: DateTime<Utc>,
+        ) -> Result<u64, IdentityError> {
+            let mut revoked = 0;
+            for token in self.tokens.lock().unwrap().iter_mut() {
+                if token.family_id() == family_id && !token.is_revoked() {
+                    token.revoke(now);
+                    revoked += 1;
+                }
+            }
+            Ok(revoked)
+        }
+
+        async fn revoke_all_for_user(
+            &self,
+            user_id: UserId,
+            now: DateTime<Utc>,
+        ) -> Result<u64, IdentityError> {
+            let mut revoked = 0;
+            for token in self.tokens.lock().unwrap().iter_mut() {
+                if token.user_id() == user_id && !token.is_revoked() {
+                    token.revoke(now);
+                    revoked += 1;
+                }
+            }
+            Ok(revoked)
+        }
     }
 
     // =========================================================================
@@ -667,8 +1070,12 @@ mod tests {
             Ok(format!("access_token_for_{}", user.id().as_uuid()))
         }
 
-        fn generate_refresh_token(&self, user_id: UserId) -> Result<String, AuthError> {
-            Ok(format!("refresh_token_for_{}", user_id.as_uuid()))
+        fn generate_refresh_token(&self, token: &RefreshToken) -> Result<String, AuthError> {
+            Ok(format!("refresh_token_for_{}", token.user_id().as_uuid()))
+        }
+
+        fn refresh_token_lifetime(&self) -> chrono::Duration {
+            chrono::Duration::days(7)
         }
 
         fn validate_access_token(
@@ -678,10 +1085,22 @@ mod tests {
             unimplemented!("Not needed for login tests")
         }
 
-        fn validate_refresh_token(&self, _token: &str) -> Result<UserId, AuthError> {
+        fn validate_refresh_token(&self, _token: &str) -> Result<RefreshTokenSubject, AuthError> {
             unimplemented!("Not needed for login tests")
         }
 
+        fn generate_mfa_token(&self, user_id: UserId) -> Result<String, AuthError> {
+            Ok(format!("mfa_token_for_{}", user_id.as_uuid()))
+        }
+
+        fn validate_mfa_token(&self, token: &str) -> Result<UserId, AuthError> {
+            token
+                .strip_prefix("mfa_token_for_")
+                .and_then(|id| Uuid::parse_str(id).ok())
+                .map(UserId::from_uuid)
+                .ok_or(AuthError::InvalidToken)
+        }
+
         fn issue_impersonation_token(
             &self,
             _user: &User,
@@ -694,9 +1113,9 @@ mod tests {
     }
 
     /// Mock TokenService that supports refresh token validation for RefreshTokenUseCase tests
+    ///
+    /// Refresh tokens are encoded as `refresh:{user_id}:{token_id}:{family_id}`.
     struct MockTokenServiceWithRefresh {
-        /// Map of refresh tokens to user IDs
-        valid_tokens: Mutex<HashMap<String, UserId>>,
         /// Tokens that should return TokenExpired error
         expired_tokens: Mutex<Vec<String>>,
     }
@@ -704,19 +1123,10 @@ mod tests {
     impl MockTokenServiceWithRefresh {
         fn new() -> Self {
             Self {
-                valid_tokens: Mutex::new(HashMap::new()),
                 expired_tokens: Mutex::new(Vec::new()),
             }
         }
 
-        fn with_valid_token(self, token: &str, user_id: UserId) -> Self {
-            self.valid_tokens
-                .lock()
-                .unwrap()
-                .insert(token.to_string(), user_id);
-            self
-        }
-
         fn with_expired_token(self, token: &str) -> Self {
             self.expired_tokens.lock().unwrap().push(token.to_string());
             self
@@ -732,8 +1142,17 @@ mod tests {
             Ok(format!("new_access_token_for_{}", user.id().as_uuid()))
         }
 
-        fn generate_refresh_token(&self, user_id: UserId) -> Result<String, AuthError> {
-            Ok(format!("refresh_token_for_{}", user_id.as_uuid()))
+        fn generate_refresh_token(&self, token: &RefreshToken) -> Result<String, AuthError> {
+            Ok(format!(
+                "refresh:{}:{}:{}",
+                token.user_id().as_uuid(),
+                token.id(),
+                token.family_id()
+            ))
+        }
+
+        fn refresh_token_lifetime(&self) -> chrono::Duration {
+            chrono::Duration::days(7)
         }
 
         fn validate_access_token(
@@ -743,7 +1162,7 @@ mod tests {
             unimplemented!("Not needed for refresh tests")
         }
 
-        fn validate_refresh_token(&self, token: &str) -> Result<UserId, AuthError> {
+        fn validate_refresh_token(&self, token: &str) -> Result<RefreshTokenSubject, AuthError> {
             // Check if token is in expired list
             if self
                 .expired_tokens
@@ -754,13 +1173,28 @@ mod tests {
                 return Err(AuthError::TokenExpired);
             }
 
-            // Check if token is valid
-            self.valid_tokens
-                .lock()
-                .unwrap()
-                .get(token)
-                .copied()
-                .ok_or(AuthError::InvalidToken)
+            let ids: Vec<Uuid> = token
+                .strip_prefix("refresh:")
+                .ok_or(AuthError::InvalidToken)?
+                .split(':')
+                .map(|id| Uuid::parse_str(id).map_err(|_| AuthError::InvalidToken))
+                .collect::<Result<_, _>>()?;
+            let [user_id, token_id, family_id] = ids[..] else {
+                return Err(AuthError::InvalidToken);
+            };
+            Ok(RefreshTokenSubject {
+                user_id: UserId::from_uuid(user_id),
+                token_id,
+                family_id,
+            })
+        }
+
+        fn generate_mfa_token(&self, _user_id: UserId) -> Result<String, AuthError> {
+            unimplemented!("Not needed for refresh tests")
+        }
+
+        fn validate_mfa_token(&self, _token: &str) -> Result<UserId, AuthError> {
+            unimplemented!("Not needed for refresh tests")
         }
 
         fn issue_impersonation_token(
@@ -938,7 +1372,12 @@ mod tests {
         let user = create_user_with_password("testuser", "test@example.com", "password123", true);
         let user_repo = Arc::new(MockUserRepository::new().with_user(user));
         let token_service = Arc::new(MockTokenService);
-        let use_case = LoginUseCase::new(user_repo, token_service);
+        let use_case = LoginUseCase::new(
+            user_repo,
+            token_service,
+            Arc::new(MockUserMfaRepository::new()),
+            Arc::new(MockRefreshTokenRepository::new()),
+        );
 
         let command = LoginCommand {
             identifier: "test@example.com".to_string(),
@@ -948,7 +1387,9 @@ mod tests {
         let result = use_case.execute(command).await;
 
         assert!(result.is_ok());
-        let response = result.unwrap();
+        let LoginOutcome::Authenticated(response) = result.unwrap() else {
+            panic!("expected tokens");
+        };
         assert!(response.access_token.starts_with("access_token_for_"));
         assert!(response.refresh_token.starts_with("refresh_token_for_"));
         assert_eq!(response.token_type, "Bearer");
@@ -960,7 +1401,12 @@ mod tests {
         let user = create_user_with_password("testuser", "test@example.com", "password123", true);
         let user_repo = Arc::new(MockUserRepository::new().with_user(user));
         let token_service = Arc::new(MockTokenService);
-        let use_case = LoginUseCase::new(user_repo, token_service);
+        let use_case = LoginUseCase::new(
+            user_repo,
+            token_service,
+            Arc::new(MockUserMfaRepository::new()),
+            Arc::new(MockRefreshTokenRepository::new()),
+        );
 
         let command = LoginCommand {
             identifier: "testuser".to_string(),
@@ -970,7 +1416,9 @@ mod tests {
         let result = use_case.execute(command).await;
 
         assert!(result.is_ok());
-        let response = result.unwrap();
+        let LoginOutcome::Authenticated(response) = result.unwrap() else {
+            panic!("expected tokens");
+        };
         assert!(response.access_token.starts_with("access_token_for_"));
         assert!(response.refresh_token.starts_with("refresh_token_for_"));
     }
@@ -981,7 +1429,12 @@ mod tests {
         assert!(user.last_login_at().is_none(), "fresh user has no login");
         let user_repo = Arc::new(MockUserRepository::new().with_user(user));
         let token_service = Arc::new(MockTokenService);
-        let use_case = LoginUseCase::new(user_repo.clone(), token_service);
+        let use_case = LoginUseCase::new(
+            user_repo.clone(),
+            token_service,
+            Arc::new(MockUserMfaRepository::new()),
+            Arc::new(MockRefreshTokenRepository::new()),
+        );
 
         use_case
             .execute(LoginCommand {
@@ -1006,7 +1459,12 @@ mod tests {
     async fn test_login_user_not_found() {
         let user_repo = Arc::new(MockUserRepository::new());
         let token_service = Arc::new(MockTokenService);
-        let use_case = LoginUseCase::new(user_repo, token_service);
+        let use_case = LoginUseCase::new(
+            user_repo,
+            token_service,
+            Arc::new(MockUserMfaRepository::new()),
+            Arc::new(MockRefreshTokenRepository::new()),
+        );
 
         let command = LoginCommand {
             identifier: "nonexistent@example.com".to_string(),
@@ -1024,7 +1482,12 @@ mod tests {
         let user = create_user_with_password("testuser", "test@example.com", "password123", true);
         let user_repo = Arc::new(MockUserRepository::new().with_user(user));
         let token_service = Arc::new(MockTokenService);
-        let use_case = LoginUseCase::new(user_repo, token_service);
+        let use_case = LoginUseCase::new(
+            user_repo,
+            token_service,
+            Arc::new(MockUserMfaRepository::new()),
+            Arc::new(MockRefreshTokenRepository::new()),
+        );
 
         let command = LoginCommand {
             identifier: "test@example.com".to_string(),
@@ -1042,7 +1505,12 @@ mod tests {
         let user = create_user_with_password("testuser", "test@example.com", "password123", false);
         let user_repo = Arc::new(MockUserRepository::new().with_user(user));
         let token_service = Arc::new(MockTokenService);
-        let use_case = LoginUseCase::new(user_repo, token_service);
+        let use_case = LoginUseCase::new(
+            user_repo,
+            token_service,
+            Arc::new(MockUserMfaRepository::new()),
+            Arc::new(MockRefreshTokenRepository::new()),
+        );
 
         let command = LoginCommand {
             identifier: "test@example.com".to_string(),
@@ -1060,7 +1528,12 @@ mod tests {
         let user = create_user_with_password("testuser", "user@domain.com", "password123", true);
         let user_repo = Arc::new(MockUserRepository::new().with_user(user));
         let token_service = Arc::new(MockTokenService);
-        let use_case = LoginUseCase::new(user_repo, token_service);
+        let use_case = LoginUseCase::new(
+            user_repo,
+            token_service,
+            Arc::new(MockUserMfaRepository::new()),
+            Arc::new(MockRefreshTokenRepository::new()),
+        );
 
         // Using email format should find user by email
         let command = LoginCommand {
@@ -1078,7 +1551,12 @@ mod tests {
         let user = create_user_with_password("john_doe", "john@example.com", "password123", true);
         let user_repo = Arc::new(MockUserRepository::new().with_user(user));
         let token_service = Arc::new(MockTokenService);
-        let use_case = LoginUseCase::new(user_repo, token_service);
+        let use_case = LoginUseCase::new(
+            user_repo,
+            token_service,
+            Arc::new(MockUserMfaRepository::new()),
+            Arc::new(MockRefreshTokenRepository::new()),
+        );
 
         // Using username format should find user by username
         let command = LoginCommand {
@@ -1090,6 +1568,214 @@ mod tests {
         assert!(result.is_ok());
     }
 
+    // =========================================================================
+    // MFA Login Tests
+    // =========================================================================
+
+    /// User with MFA enabled, with its enrollment and recovery codes
+    fn create_user_with_mfa() -> (User, UserMfa, Vec<String>) {
+        let user = create_user_with_password("admin", "admin@example.com", "password123", true);
+        let mut mfa = UserMfa::enroll(*user.id());
+        let codes = mfa.enable();
+        (user, mfa, codes)
+    }
+
+    async fn mfa_challenge<U, M, R>(use_case: &LoginUseCase<U, MockTokenService, M, R>) -> String
+    where
+        U: UserRepository,
+        M: UserMfaRepository,
+        R: RefreshTokenRepository,
+    {
+        let outcome = use_case
+            .execute(LoginCommand {
+                identifier: "admin@example.com".to_string(),
+                password: "password123".to_string(),
+            })
+            .await
+            .unwrap();
+        let LoginOutcome::MfaRequired(challenge) = outcome else {
+            panic!("expected an MFA challenge");
+        };
+        assert!(challenge.mfa_required);
+        assert_eq!(challenge.expires_in, MFA_TOKEN_LIFETIME_SECS);
+        challenge.mfa_token
+    }
+
+    #[tokio::test]
+    async fn test_login_with_mfa_issues_tokens_only_after_second_factor() {
+        let (user, mfa, _) = create_user_with_mfa();
+        let secret = mfa.secret().clone();
+        let user_repo = Arc::new(MockUserRepository::new().with_user(user));
+        let mfa_repo = Arc::new(MockUserMfaRepository::new().with_mfa(mfa));
+        let token_service = Arc::new(MockTokenService);
+        let refresh_repo = Arc::new(MockRefreshTokenRepository::new());
+        let login = LoginUseCase::new(
+            user_repo.clone(),
+            token_service.clone(),
+            mfa_repo.clone(),
+            refresh_repo.clone(),
+        );
+
+        let mfa_token = mfa_challenge(&login).await;
+        let persisted = user_repo
+            .find_by_email(&Email::new("admin@example.com").unwrap())
+            .await
+            .unwrap()
+            .unwrap();
+        assert!(
+            persisted.last_login_at().is_none(),
+            "password alone is not a login"
+        );
+
+        let verify = VerifyMfaLoginUseCase::new(
+            user_repo,
+            token_service,
+            mfa_repo,
+            refresh_repo,
+            MfaPolicy::default(),
+        );
+        let response = verify
+            .execute(VerifyMfaCommand {
+                mfa_token,
+                code: Some(secret.code_at(TotpSecret::step_at(Utc::now()))),
+                recovery_code: None,
+            })
+            .await
+            .unwrap();
+        assert!(response.access_token.starts_with("access_token_for_"));
+        assert!(response.refresh_token.starts_with("refresh_token_for_"));
+    }
+
+    #[tokio::test]
+    async fn test_login_ignores_pending_mfa_enrollment() {
+        let user = create_user_with_password("admin", "admin@example.com", "password123", true);
+        let mfa = UserMfa::enroll(*user.id());
+        let user_repo = Arc::new(MockUserRepository::new().with_user(user));
+        let mfa_repo = Arc::new(MockUserMfaRepository::new().with_mfa(mfa));
+        let use_case = LoginUseCase::new(
+            user_repo,
+            Arc::new(MockTokenService),
+            mfa_repo,
+            Arc::new(MockRefreshTokenRepository::new()),
+        );
+
+        let outcome = use_case
+            .execute(LoginCommand {
+                identifier: "admin".to_string(),
+                password: "password123".to_string(),
+            })
+            .await
+            .unwrap();
+        assert!(matches!(outcome, LoginOutcome::Authenticated(_)));
+    }
+
+    #[tokio::test]
+    async fn test_verify_mfa_login_with_recovery_code_consumes_it() {
+        let (user, mfa, codes) = create_user_with_mfa();
+        let user_id = *user.id();
+        let user_repo = Arc::new(MockUserRepository::new().with_user(user));
+        let mfa_repo = Arc::new(MockUserMfaRepository::new().with_mfa(mfa));
+        let token_service = Arc::new(MockTokenService);
+        let refresh_repo = Arc::new(MockRefreshTokenRepository::new());
+        let login = LoginUseCase::new(
+            user_repo.clone(),
+            token_service.clone(),
+            mfa_repo.clone(),
+            refresh_repo.clone(),
+        );
+        let verify = VerifyMfaLoginUseCase::new(
+            user_repo,
+            token_service,
+            mfa_repo.clone(),
+            refresh_repo,
+            MfaPolicy::default(),
+        );
+
+        let command = VerifyMfaCommand {
+            mfa_token: mfa_challenge(&login).await,
+            code: None,
+            recovery_code: Some(codes[0].clone()),
+        };
+        assert!(verify.execute(command.clone()).await.is_ok());
+        assert_eq!(
+            mfa_repo.get(user_id).unwrap().recovery_code_hashes().len(),
+            codes.len() - 1
+        );
+        assert!(matches!(
+            verify.execute(command).await,
+            Err(AuthError::InvalidMfaCode)
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_verify_mfa_login_locks_after_failed_attempts() {
+        let (user, mfa, codes) = create_user_with_mfa();
+        let user_id = *user.id();
+        let user_repo = Arc::new(MockUserRepository::new().with_user(user));
+        let mfa_repo = Arc::new(MockUserMfaRepository::new().with_mfa(mfa));
+        let token_service = Arc::new(MockTokenService);
+        let refresh_repo = Arc::new(MockRefreshTokenRepository::new());
+        let login = LoginUseCase::new(
+            user_repo.clone(),
+            token_service.clone(),
+            mfa_repo.clone(),
+            refresh_repo.clone(),
+        );
+        let verify = VerifyMfaLoginUseCase::new(
+            user_repo,
+            token_service,
+            mfa_repo.clone(),
+            refresh_repo,
+            MfaPolicy::new("POS", 2, chrono::Duration::minutes(15)),
+        );
+        let mfa_token = mfa_challenge(&login).await;
+
+        for _ in 0..2 {
+            let result = verify
+                .execute(VerifyMfaCommand {
+                    mfa_token: mfa_token.clone(),
+                    code: Some("000000".to_string()),
+                    recovery_code: None,
+                })
+                .await;
+            assert!(matches!(result, Err(AuthError::InvalidMfaCode)));
+        }
+        assert!(mfa_repo.get(user_id).unwrap().is_locked(Utc::now()));
+
+        let result = verify
+            .execute(VerifyMfaCommand {
+                mfa_token,
+                code: None,
+                recovery_code: Some(codes[0].clone()),
+            })
+            .await;
+        assert!(matches!(result, Err(AuthError::MfaLocked)));
+    }
+
+    #[tokio::test]
+    async fn test_verify_mfa_login_rejects_other_tokens() {
+        let (user, mfa, codes) = create_user_with_mfa();
+        let refresh_token = format!("refresh_token_for_{}", user.id().as_uuid());
+        let user_repo = Arc::new(MockUserRepository::new().with_user(user));
+        let mfa_repo = Arc::new(MockUserMfaRepository::new().with_mfa(mfa));
+        let verify = VerifyMfaLoginUseCase::new(
+            user_repo,
+            Arc::new(MockTokenService),
+            mfa_repo,
+            Arc::new(MockRefreshTokenRepository::new()),
+            MfaPolicy::default(),
+        );
+
+        let result = verify
+            .execute(VerifyMfaCommand {
+                mfa_token: refresh_token,
+                code: None,
+                recovery_code: Some(codes[0].clone()),
+            })
+            .await;
+        assert!(matches!(result, Err(AuthError::InvalidToken)));
+    }
+
     // =========================================================================
     // Username Generation Tests
     // =========================================================================
@@ -1136,28 +1822,132 @@ mod tests {
     // RefreshTokenUseCase Tests
     // =========================================================================
 
+    /// Stores a fresh refresh token for the user and returns it with its encoded form
+    async fn stored_refresh_token(
+        repo: &MockRefreshTokenRepository,
+        token_service: &MockTokenServiceWithRefresh,
+        user_id: UserId,
+    ) -> (RefreshToken, String) {
+        let token = RefreshToken::issue(user_id, chrono::Duration::days(7));
+        repo.save(&token).await.unwrap();
+        let encoded = token_service.generate_refresh_token(&token).unwrap();
+        (token, encoded)
+    }
+
     #[tokio::test]
     async fn test_refresh_token_success() {
         // Create a user
         let user = create_user_with_password("testuser", "test@example.com", "password123", true);
         let user_id = *user.id();
         let user_repo = Arc::new(MockUserRepository::new().with_user(user));
+        let token_service = Arc::new(MockTokenServiceWithRefresh::new());
+        let refresh_repo = Arc::new(MockRefreshTokenRepository::new());
+        let (stored, encoded) = stored_refresh_token(&refresh_repo, &token_service, user_id).await;
 
-        // Create token service with valid refresh token
-        let token_service = Arc::new(
-            MockTokenServiceWithRefresh::new().with_valid_token("valid_refresh_token", user_id),
-        );
-
-        let use_case = RefreshTokenUseCase::new(user_repo, token_service);
+        let use_case = RefreshTokenUseCase::new(user_repo, token_service, refresh_repo.clone());
 
-        let result = use_case.execute("valid_refresh_token".to_string()).await;
+        let result = use_case.execute(encoded.clone()).await;
 
         assert!(result.is_ok());
         let response = result.unwrap();
         assert!(response.access_token.starts_with("new_access_token_for_"));
-        assert_eq!(response.refresh_token, "valid_refresh_token");
+        assert_ne!(response.refresh_token, encoded);
         assert_eq!(response.token_type, "Bearer");
         assert_eq!(response.expires_in, 900);
+
+        // The presented token is rotated into a successor of the same family
+        let old = refresh_repo.get(stored.id()).unwrap();
+        assert!(old.is_rotated());
+        let successor = refresh_repo.get(old.replaced_by().unwrap()).unwrap();
+        assert_eq!(successor.family_id(), stored.family_id());
+        assert!(!successor.is_rotated());
+    }
+
+    #[tokio::test]
+    async fn test_refresh_token_successor_can_be_refreshed() {
+        let user = create_user_with_password("testuser", "test@example.com", "password123", true);
+        let user_id = *user.id();
+        let user_repo = Arc::new(MockUserRepository::new().with_user(user));
+        let token_service = Arc::new(MockTokenServiceWithRefresh::new());
+        let refresh_repo = Arc::new(MockRefreshTokenRepository::new());
+        let (_, encoded) = stored_refresh_token(&refresh_repo, &token_service, user_id).await;
+
+        let use_case = RefreshTokenUseCase::new(user_repo, token_service, refresh_repo);
+
+        let first = use_case.execute(encoded).await.unwrap();
+        let second = use_case.execute(first.refresh_token.clone()).await;
+
+        assert!(second.is_ok());
+        assert_ne!(second.unwrap().refresh_token, first.refresh_token);
+    }
+
+    #[tokio::test]
+    async fn test_refresh_token_reuse_revokes_family() {
+        let user = create_user_with_password("testuser", "test@example.com", "password123", true);
+        let user_id = *user.id();
+        let user_repo = Arc::new(MockUserRepository::new().with_user(user));
+        let token_service = Arc::new(MockTokenServiceWithRefresh::new());
+        let refresh_repo = Arc::new(MockRefreshTokenRepository::new());
+        let (stored, encoded) = stored_refresh_token(&refresh_repo, &token_service, user_id).await;
+
+        let use_case = RefreshTokenUseCase::new(user_repo, token_service, refresh_repo.clone());
+
+        let rotated = use_case.execute(encoded.clone()).await.unwrap();
+
+        // Replaying the already rotated token is detected as reuse
+        let result = use_case.execute(encoded).await;
+        assert!(matches!(result, Err(AuthError::RefreshTokenReused)));
+
+        // The successor issued before the replay is revoked with its family
+        let successor_id = refresh_repo
+            .get(stored.id())
+            .unwrap()
+            .replaced_by()
+            .unwrap();
+        assert!(refresh_repo.get(successor_id).unwrap().is_revoked());
+        let result = use_case.execute(rotated.refresh_token).await;
+        assert!(matches!(result, Err(AuthError::InvalidToken)));
+    }
+
+    #[tokio::test]
+    async fn test_refresh_token_revoked() {
+        let user = create_user_with_password("testuser", "test@example.com", "password123", true);
+        let user_id = *user.id();
+        let user_repo = Arc::new(MockUserRepository::new().with_user(user));
+        let token_service = Arc::new(MockTokenServiceWithRefresh::new());
+        let refresh_repo = Arc::new(MockRefreshTokenRepository::new());
+        let (_, encoded) = stored_refresh_token(&refresh_repo, &token_service, user_id).await;
+        refresh_repo
+            .revoke_all_for_user(user_id, Utc::now())
+            .await
+            .unwrap();
+
+        let use_case = RefreshTokenUseCase::new(user_repo, token_service, refresh_repo);
+
+        let result = use_case.execute(encoded).await;
+
+        assert!(matches!(result, Err(AuthError::InvalidToken)));
+    }
+
+    #[tokio::test]
+    async fn test_refresh_token_unknown_token_id() {
+        // Well-formed token whose id was never stored
+        let user = create_user_with_password("testuser", "test@example.com", "password123", true);
+        let user_id = *user.id();
+        let user_repo = Arc::new(MockUserRepository::new().with_user(user));
+        let token_service = Arc::new(MockTokenServiceWithRefresh::new());
+        let unsaved = RefreshToken::issue(user_id, chrono::Duration::days(7));
+        let encoded = token_service.generate_refresh_token(&unsaved).unwrap();
+
+        let use_case = RefreshTokenUseCase::new(
+            user_repo,
+            token_service,
+            Arc::new(MockRefreshTokenRepository::new()),
+        );
+
+        let result = use_case.execute(encoded).await;
+
+        assert!(matches!(result, Err(AuthError::InvalidToken)));
     }
 
     #[tokio::test]
@@ -1166,7 +1956,11 @@ mod tests {
         let token_service =
             Arc::new(MockTokenServiceWithRefresh::new().with_expired_token("expired_token"));
 
-        let use_case = RefreshTokenUseCase::new(user_repo, token_service);
+        let use_case = RefreshTokenUseCase::new(
+            user_repo,
+            token_service,
+            Arc::new(MockRefreshTokenRepository::new()),
+        );
 
         let result = use_case.execute("expired_token".to_string()).await;
 
@@ -1178,7 +1972,11 @@ mod tests {
         let user_repo = Arc::new(MockUserRepository::new());
         let token_service = Arc::new(MockTokenServiceWithRefresh::new());
 
-        let use_case = RefreshTokenUseCase::new(user_repo, token_service);
+        let use_case = RefreshTokenUseCase::new(
+            user_repo,
+            token_service,
+            Arc::new(MockRefreshTokenRepository::new()),
+        );
 
         let result = use_case.execute("invalid_token".to_string()).await;
 
@@ -1190,12 +1988,13 @@ mod tests {
         // Token is valid but user no longer exists
         let user_id = UserId::new();
         let user_repo = Arc::new(MockUserRepository::new());
-        let token_service =
-            Arc::new(MockTokenServiceWithRefresh::new().with_valid_token("valid_token", user_id));
+        let token_service = Arc::new(MockTokenServiceWithRefresh::new());
+        let refresh_repo = Arc::new(MockRefreshTokenRepository::new());
+        let (_, encoded) = stored_refresh_token(&refresh_repo, &token_service, user_id).await;
 
-        let use_case = RefreshTokenUseCase::new(user_repo, token_service);
+        let use_case = RefreshTokenUseCase::new(user_repo, token_service, refresh_repo);
 
-        let result = use_case.execute("valid_token".to_string()).await;
+        let result = use_case.execute(encoded).await;
 
         // Should return InvalidCredentials when user doesn't exist
         assert!(matches!(result, Err(AuthError::InvalidCredentials)));
@@ -1207,17 +2006,41 @@ mod tests {
         let user = create_user_with_password("testuser", "test@example.com", "password123", false);
         let user_id = *user.id();
         let user_repo = Arc::new(MockUserRepository::new().with_user(user));
+        let token_service = Arc::new(MockTokenServiceWithRefresh::new());
+        let refresh_repo = Arc::new(MockRefreshTokenRepository::new());
+        let (_, encoded) = stored_refresh_token(&refresh_repo, &token_service, user_id).await;
 
-        let token_service =
-            Arc::new(MockTokenServiceWithRefresh::new().with_valid_token("valid_token", user_id));
-
-        let use_case = RefreshTokenUseCase::new(user_repo, token_service);
+        let use_case = RefreshTokenUseCase::new(user_repo, token_service, refresh_repo);
 
-        let result = use_case.execute("valid_token".to_string()).await;
+        let result = use_case.execute(encoded).await;
 
         assert!(matches!(result, Err(AuthError::AccountDisabled)));
     }
 
+    // =========================================================================
+    // RevokeAllSessionsUseCase Tests
+    // =========================================================================
+
+    #[tokio::test]
+    async fn test_revoke_all_sessions_revokes_only_user_tokens() {
+        let user_id = UserId::new();
+        let other_user = UserId::new();
+        let token_service = MockTokenServiceWithRefresh::new();
+        let refresh_repo = Arc::new(MockRefreshTokenRepository::new());
+        let (first, _) = stored_refresh_token(&refresh_repo, &token_service, user_id).await;
+        let (second, _) = stored_refresh_token(&refresh_repo, &token_service, user_id).await;
+        let (other, _) = stored_refresh_token(&refresh_repo, &token_service, other_user).await;
+
+        let use_case = RevokeAllSessionsUseCase::new(refresh_repo.clone());
+
+        let revoked = use_case.execute(user_id).await.unwrap();
+
+        assert_eq!(revoked, 2);
+        assert!(refresh_repo.get(first.id()).unwrap().is_revoked());
+        assert!(refresh_repo.get(second.id()).unwrap().is_revoked());
+        assert!(!refresh_repo.get(other.id()).unwrap().is_revoked());
+    }
+
     // =========================================================================
     // Property-Based Tests for Uniqueness Constraints
     // =========================================================================
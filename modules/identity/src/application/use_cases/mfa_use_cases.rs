@@ -0,0 +1,771 @@
+// Multi-factor authentication use cases - TOTP enrollment and removal
+//
+// The login side (challenge and second-factor verification) lives with the
+// other login use cases in auth_use_cases.
+
+use std::sync::Arc;
+
+use chrono::Utc;
+
+use super::auth_use_cases::{mfa_factor, verify_password};
+use crate::application::dtos::{
+    ConfirmMfaCommand, DisableMfaCommand, MfaEnrollmentResponse, MfaRecoveryCodesResponse,
+};
+use crate::domain::auth::{AuthError, MfaPolicy};
+use crate::domain::entities::{AuditAction, AuditEntry, MfaFactor, UserMfa};
+use crate::domain::repositories::{AuditRepository, UserMfaRepository, UserRepository};
+use crate::domain::value_objects::UserId;
+
+// =============================================================================
+// EnrollMfaUseCase
+// =============================================================================
+
+/// Use case for starting a TOTP enrollment.
+///
+/// Generates a new secret and returns it with the provisioning URI for the
+/// authenticator app. The enrollment stays pending, and is not enforced at
+/// login, until confirmed with a code. Enrolling again replaces a pending
+/// enrollment.
+pub struct EnrollMfaUseCase<U, M>
+where
+    U: UserRepository,
+    M: UserMfaRepository,
+{
+    user_repo: Arc<U>,
+    mfa_repo: Arc<M>,
+    policy: MfaPolicy,
+}
+
+impl<U, M> EnrollMfaUseCase<U, M>
+where
+    U: UserRepository,
+    M: UserMfaRepository,
+{
+    /// Creates a new instance of EnrollMfaUseCase
+    pub fn new(user_repo: Arc<U>, mfa_repo: Arc<M>, policy: MfaPolicy) -> Self {
+        Self {
+            user_repo,
+            mfa_repo,
+            policy,
+        }
+    }
+
+    /// Executes the use case for the authenticated user.
+    ///
+    /// # Errors
+    /// * `AuthError::InvalidCredentials` - If the user no longer exists
+    /// * `AuthError::MfaAlreadyEnabled` - If MFA is already enabled
+    /// * `AuthError::Internal` - On internal errors
+    pub async fn execute(&self, user_id: UserId) -> Result<MfaEnrollmentResponse, AuthError> {
+        let user = self
+            .user_repo
+            .find_by_id(user_id)
+            .await
+            .map_err(|e| AuthError::Internal(e.to_string()))?
+            .ok_or(AuthError::InvalidCredentials)?;
+
+        let existing = self
+            .mfa_repo
+            .find_by_user_id(user_id)
+            .await
+            .map_err(|e| AuthError::Internal(e.to_string()))?;
+        if existing.is_some_and(|m| m.is_enabled()) {
+            return Err(AuthError::MfaAlreadyEnabled);
+        }
+
+        let mfa = UserMfa::enroll(user_id);
+        self.mfa_repo
+            .save(&mfa)
+            .await
+            .map_err(|e| AuthError::Internal(e.to_string()))?;
+
+        Ok(MfaEnrollmentResponse {
+            secret: mfa.secret().to_base32(),
+            provisioning_uri: mfa
+                .secret()
+                .provisioning_uri(self.policy.issuer(), user.email().as_str()),
+        })
+    }
+}
+
+// =============================================================================
+// ConfirmMfaUseCase
+// =============================================================================
+
+/// Use case for enabling a pending enrollment.
+///
+/// The user proves the authenticator works by entering its current code.
+/// Enabling issues the recovery codes, which are returned only here.
+pub struct ConfirmMfaUseCase<M>
+where
+    M: UserMfaRepository,
+{
+    mfa_repo: Arc<M>,
+    policy: MfaPolicy,
+}
+
+impl<M> ConfirmMfaUseCase<M>
+where
+    M: UserMfaRepository,
+{
+    /// Creates a new instance of ConfirmMfaUseCase
+    pub fn new(mfa_repo: Arc<M>, policy: MfaPolicy) -> Self {
+        Self { mfa_repo, policy }
+    }
+
+    /// Executes the use case for the authenticated user.
+    ///
+    /// # Errors
+    /// * `AuthError::MfaNotEnrolled` - If there is no pending enrollment
+    /// * `AuthError::MfaAlreadyEnabled` - If MFA is already enabled
+    /// * `AuthError::InvalidMfaCode` - If the code is wrong
+    /// * `AuthError::MfaLocked` - After too many failed attempts
+    /// * `AuthError::Internal` - On internal errors
+    pub async fn execute(
+        &self,
+        user_id: UserId,
+        command: ConfirmMfaCommand,
+    ) -> Result<MfaRecoveryCodesResponse, AuthError> {
+        let mut mfa = self
+            .mfa_repo
+            .find_by_user_id(user_id)
+            .await
+            .map_err(|e| AuthError::Internal(e.to_string()))?
+            .ok_or(AuthError::MfaNotEnrolled)?;
+        if mfa.is_enabled() {
+            return Err(AuthError::MfaAlreadyEnabled);
+        }
+
+        let verified = mfa.verify(&MfaFactor::Totp(command.code), &self.policy, Utc::now());
+        let recovery_codes = match verified {
+            Ok(()) => mfa.enable(),
+            Err(_) => Vec::new(),
+        };
+        self.mfa_repo
+            .save(&mfa)
+            .await
+            .map_err(|e| AuthError::Internal(e.to_string()))?;
+        verified?;
+
+        Ok(MfaRecoveryCodesResponse { recovery_codes })
+    }
+}
+
+// =============================================================================
+// DisableMfaUseCase
+// =============================================================================
+
+/// Use case for turning MFA off.
+///
+/// Being logged in is not enough: the user re-authenticates with the
+/// password and a TOTP or recovery code, so a stolen session cannot remove
+/// the second factor.
+pub struct DisableMfaUseCase<U, M>
+where
+    U: UserRepository,
+    M: UserMfaRepository,
+{
+    user_repo: Arc<U>,
+    mfa_repo: Arc<M>,
+    policy: MfaPolicy,
+}
+
+impl<U, M> DisableMfaUseCase<U, M>
+where
+    U: UserRepository,
+    M: UserMfaRepository,
+{
+    /// Creates a new instance of DisableMfaUseCase
+    pub fn new(user_repo: Arc<U>, mfa_repo: Arc<M>, policy: MfaPolicy) -> Self {
+        Self {
+            user_repo,
+            mfa_repo,
+            policy,
+        }
+    }
+
+    /// Executes the use case for the authenticated user.
+    ///
+    /// # Errors
+    /// * `AuthError::InvalidCredentials` - If the password is wrong
+    /// * `AuthError::MfaNotEnrolled` - If MFA is not enabled
+    /// * `AuthError::InvalidMfaCode` - If the code or recovery code is wrong
+    /// * `AuthError::MfaLocked` - After too many failed attempts
+    /// * `AuthError::Internal` - On internal errors
+    pub async fn execute(
+        &self,
+        user_id: UserId,
+        command: DisableMfaCommand,
+    ) -> Result<(), AuthError> {
+        let factor = mfa_factor(command.code, command.recovery_code)?;
+
+        let user = self
+            .user_repo
+            .find_by_id(user_id)
+            .await
+            .map_err(|e| AuthError::Internal(e.to_string()))?
+            .ok_or(AuthError::InvalidCredentials)?;
+        if !verify_password(&command.password, user.password_hash())? {
+            return Err(AuthError::InvalidCredentials);
+        }
+
+        let mut mfa = self
+            .mfa_repo
+            .find_by_user_id(user_id)
+            .await
+            .map_err(|e| AuthError::Internal(e.to_string()))?
+            .filter(|m| m.is_enabled())
+            .ok_or(AuthError::MfaNotEnrolled)?;
+
+        if let Err(e) = mfa.verify(&factor, &self.policy, Utc::now()) {
+            self.mfa_repo
+                .save(&mfa)
+                .await
+                .map_err(|e| AuthError::Internal(e.to_string()))?;
+            return Err(e);
+        }
+
+        self.mfa_repo
+            .delete(user_id)
+            .await
+            .map_err(|e| AuthError::Internal(e.to_string()))
+    }
+}
+
+// =============================================================================
+// AdminDisableMfaUseCase
+// =============================================================================
+
+/// Use case for an administrator turning off another user's MFA.
+///
+/// The override for a user who lost both the authenticator and the
+/// recovery codes: no code is needed, so each use is recorded in the audit
+/// log under the administrator's id. The user can enroll again afterwards.
+/// The audit entry is written first; if it can't be, MFA stays on.
+pub struct AdminDisableMfaUseCase<M, A>
+where
+    M: UserMfaRepository,
+    A: AuditRepository,
+{
+    mfa_repo: Arc<M>,
+    audit_repo: Arc<A>,
+}
+
+impl<M, A> AdminDisableMfaUseCase<M, A>
+where
+    M: UserMfaRepository,
+    A: AuditRepository,
+{
+    /// Creates a new instance of AdminDisableMfaUseCase
+    pub fn new(mfa_repo: Arc<M>, audit_repo: Arc<A>) -> Self {
+        Self {
+            mfa_repo,
+            audit_repo,
+        }
+    }
+
+    /// Removes the enrollment of `user_id`, pending or enabled.
+    ///
+    /// # Errors
+    /// * `AuthError::MfaNotEnrolled` - If the user has no enrollment
+    /// * `AuthError::Internal` - On internal errors
+    pub async fn execute(&self, user_id: UserId, actor_id: UserId) -> Result<(), AuthError> {
+        let mfa = self
+            .mfa_repo
+            .find_by_user_id(user_id)
+            .await
+            .map_err(|e| AuthError::Internal(e.to_string()))?
+            .ok_or(AuthError::MfaNotEnrolled)?;
+
+        let entry = AuditEntry::create(
+            "user_mfa".to_string(),
+            user_id.into_uuid(),
+            AuditAction::Deleted,
+            Some(serde_json::json!({
+                "enabled": mfa.is_enabled(),
+                "admin_override": true,
+            })),
+            None,
+            actor_id,
+        );
+        // Fail closed: an override that can't be recorded doesn't happen
+        self.audit_repo
+            .save(&entry)
+            .await
+            .map_err(|e| AuthError::Internal(e.to_string()))?;
+
+        self.mfa_repo
+            .delete(user_id)
+            .await
+            .map_err(|e| AuthError::Internal(e.to_string()))
+    }
+}
+
+// =============================================================================
+// Tests
+// =============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use argon2::{
+        Argon2,
+        password_hash::{PasswordHasher, SaltString, rand_core::OsRng},
+    };
+    use async_trait::async_trait;
+    use std::collections::HashMap;
+    use std::sync::Mutex;
+
+    use chrono::DateTime;
+    use uuid::Uuid;
+
+    use crate::domain::auth::TotpSecret;
+    use crate::domain::entities::{Permission, Role, Store, User};
+    use crate::domain::repositories::AuditEntryFilter;
+    use crate::domain::value_objects::{Email, RoleId, StoreId, Username};
+
+    // =========================================================================
+    // Mock Repositories
+    // =========================================================================
+
+    struct MockUserRepository {
+        users: Mutex<HashMap<UserId, User>>,
+    }
+
+    impl MockUserRepository {
+        fn new() -> Self {
+            Self {
+                users: Mutex::new(HashMap::new()),
+            }
+        }
+
+        fn with_user(self, user: User) -> Self {
+            self.users.lock().unwrap().insert(*user.id(), user);
+            self
+        }
+    }
+
+    #[async_trait]
+    impl UserRepository for MockUserRepository {
+        async fn save(&self, user: &User) -> Result<(), crate::error::IdentityError> {
+            let mut users = self.users.lock().unwrap();
+            users.insert(*user.id(), user.clone());
+            Ok(())
+        }
+
+        async fn find_by_id(
+            &self,
+            id: UserId,
+        ) -> Result<Option<User>, crate::error::IdentityError> {
+            let users = self.users.lock().unwrap();
+            Ok(users.get(&id).cloned())
+        }
+
+        async fn find_by_email(
+            &self,
+            email: &Email,
+        ) -> Result<Option<User>, crate::error::IdentityError> {
+            let users = self.users.lock().unwrap();
+            Ok(users.values().find(|u| u.email() == email).cloned())
+        }
+
+        async fn find_by_username(
+            &self,
+            username: &Username,
+        ) -> Result<Option<User>, crate::error::IdentityError> {
+            let users = self.users.lock().unwrap();
+            Ok(users.values().find(|u| u.username() == username).cloned())
+        }
+
+        async fn update(&self, user: &User) -> Result<(), crate::error::IdentityError> {
+            let mut users = self.users.lock().unwrap();
+            users.insert(*user.id(), user.clone());
+            Ok(())
+        }
+
+        async fn assign_role(
+            &self,
+            _user_id: UserId,
+            _role_id: RoleId,
+            _store_id: StoreId,
+        ) -> Result<(), crate::error::IdentityError> {
+            Ok(())
+        }
+
+        async fn remove_role(
+            &self,
+            _user_id: UserId,
+            _role_id: RoleId,
+            _store_id: StoreId,
+        ) -> Result<(), crate::error::IdentityError> {
+            Ok(())
+        }
+
+        async fn get_roles_for_store(
+            &self,
+            _user_id: UserId,
+            _store_id: StoreId,
+        ) -> Result<Vec<Role>, crate::error::IdentityError> {
+            Ok(vec![])
+        }
+
+        async fn get_permissions_for_store(
+            &self,
+            _user_id: UserId,
+            _store_id: StoreId,
+        ) -> Result<Vec<Permission>, crate::error::IdentityError> {
+            Ok(vec![])
+        }
+
+        async fn remove_role_from_all_users(
+            &self,
+            _role_id: RoleId,
+        ) -> Result<(), crate::error::IdentityError> {
+            Ok(())
+        }
+
+        async fn add_to_store(
+            &self,
+            _user_id: UserId,
+            _store_id: StoreId,
+        ) -> Result<(), crate::error::IdentityError> {
+            Ok(())
+        }
+
+        async fn remove_from_store(
+            &self,
+            _user_id: UserId,
+            _store_id: StoreId,
+        ) -> Result<(), crate::error::IdentityError> {
+            Ok(())
+        }
+
+        async fn get_stores(
+            &self,
+            _user_id: UserId,
+        ) -> Result<Vec<Store>, crate::error::IdentityError> {
+            Ok(vec![])
+        }
+
+        async fn is_member_of_store(
+            &self,
+            _user_id: UserId,
+            _store_id: StoreId,
+        ) -> Result<bool, crate::error::IdentityError> {
+            Ok(false)
+        }
+
+        async fn get_all_store_permissions(
+            &self,
+            _user_id: UserId,
+        ) -> Result<std::collections::HashMap<String, Vec<String>>, crate::error::IdentityError>
+        {
+            Ok(std::collections::HashMap::new())
+        }
+    }
+
+    struct MockUserMfaRepository {
+        enrollments: Mutex<HashMap<UserId, UserMfa>>,
+    }
+
+    impl MockUserMfaRepository {
+        fn new() -> Self {
+            Self {
+                enrollments: Mutex::new(HashMap::new()),
+            }
+        }
+
+        fn with_mfa(self, mfa: UserMfa) -> Self {
+            self.enrollments.lock().unwrap().insert(mfa.user_id(), mfa);
+            self
+        }
+
+        fn get(&self, user_id: UserId) -> Option<UserMfa> {
+            self.enrollments.lock().unwrap().get(&user_id).cloned()
+        }
+    }
+
+    #[async_trait]
+    impl UserMfaRepository for MockUserMfaRepository {
+        async fn find_by_user_id(
+            &self,
+            user_id: UserId,
+        ) -> Result<Option<UserMfa>, crate::error::IdentityError> {
+            Ok(self.get(user_id))
+        }
+
+        async fn save(&self, mfa: &UserMfa) -> Result<(), crate::error::IdentityError> {
+            self.enrollments
+                .lock()
+                .unwrap()
+                .insert(mfa.user_id(), mfa.clone());
+            Ok(())
+        }
+
+        async fn delete(&self, user_id: UserId) -> Result<(), crate::error::IdentityError> {
+            self.enrollments.lock().unwrap().remove(&user_id);
+            Ok(())
+        }
+    }
+
+    struct MockAuditRepository {
+        entries: Mutex<Vec<AuditEntry>>,
+        fail: bool,
+    }
+
+    impl MockAuditRepository {
+        fn new() -> Self {
+            Self {
+                entries: Mutex::new(Vec::new()),
+                fail: false,
+            }
+        }
+
+        fn failing() -> Self {
+            Self {
+                fail: true,
+                ..Self::new()
+            }
+        }
+
+        fn get_entries(&self) -> Vec<AuditEntry> {
+            self.entries.lock().unwrap().clone()
+        }
+    }
+
+    #[async_trait]
+    impl AuditRepository for MockAuditRepository {
+        async fn save(&self, entry: &AuditEntry) -> Result<(), crate::error::IdentityError> {
+            if self.fail {
+                return Err(crate::error::IdentityError::AuditError(
+                    "audit store unavailable".to_string(),
+                ));
+            }
+            self.entries.lock().unwrap().push(entry.clone());
+            Ok(())
+        }
+
+        async fn find_by_entity(
+            &self,
+            _entity_type: &str,
+            _entity_id: Uuid,
+        ) -> Result<Vec<AuditEntry>, crate::error::IdentityError> {
+            unimplemented!("Not needed for MFA tests")
+        }
+
+        async fn find_by_date_range(
+            &self,
+            _from: DateTime<Utc>,
+            _to: DateTime<Utc>,
+        ) -> Result<Vec<AuditEntry>, crate::error::IdentityError> {
+            unimplemented!("Not needed for MFA tests")
+        }
+
+        async fn find_paginated(
+            &self,
+            _filter: &AuditEntryFilter,
+            _page: i64,
+            _page_size: i64,
+        ) -> Result<(Vec<AuditEntry>, i64), crate::error::IdentityError> {
+            unimplemented!("Not needed for MFA tests")
+        }
+    }
+
+    // =========================================================================
+    // Helpers
+    // =========================================================================
+
+    fn create_user(password: &str) -> User {
+        let salt = SaltString::generate(&mut OsRng);
+        let password_hash = Argon2::default()
+            .hash_password(password.as_bytes(), &salt)
+            .unwrap()
+            .to_string();
+        User::create(
+            Username::new("admin").unwrap(),
+            Email::new("admin@example.com").unwrap(),
+            "Ada".to_string(),
+            "Admin".to_string(),
+            password_hash,
+        )
+    }
+
+    fn current_code(secret: &TotpSecret) -> String {
+        secret.code_at(TotpSecret::step_at(Utc::now()))
+    }
+
+    // =========================================================================
+    // Enrollment Tests
+    // =========================================================================
+
+    #[tokio::test]
+    async fn test_enroll_then_confirm_enables_mfa_with_recovery_codes() {
+        let user = create_user("password123");
+        let user_id = *user.id();
+        let user_repo = Arc::new(MockUserRepository::new().with_user(user));
+        let mfa_repo = Arc::new(MockUserMfaRepository::new());
+
+        let enrollment = EnrollMfaUseCase::new(user_repo, mfa_repo.clone(), MfaPolicy::default())
+            .execute(user_id)
+            .await
+            .unwrap();
+        assert!(
+            enrollment
+                .provisioning_uri
+                .starts_with("otpauth://totp/POS:admin%40example.com?secret=")
+        );
+        let pending = mfa_repo.get(user_id).unwrap();
+        assert!(!pending.is_enabled());
+        assert_eq!(pending.secret().to_base32(), enrollment.secret);
+
+        let confirm = ConfirmMfaUseCase::new(mfa_repo.clone(), MfaPolicy::default());
+        let wrong = confirm
+            .execute(
+                user_id,
+                ConfirmMfaCommand {
+                    code: "000000".to_string(),
+                },
+            )
+            .await;
+        assert!(matches!(wrong, Err(AuthError::InvalidMfaCode)));
+        assert_eq!(mfa_repo.get(user_id).unwrap().failed_attempts(), 1);
+
+        let response = confirm
+            .execute(
+                user_id,
+                ConfirmMfaCommand {
+                    code: current_code(pending.secret()),
+                },
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.recovery_codes.len(), 10);
+        assert!(mfa_repo.get(user_id).unwrap().is_enabled());
+    }
+
+    #[tokio::test]
+    async fn test_enroll_refuses_when_already_enabled() {
+        let user = create_user("password123");
+        let mut mfa = UserMfa::enroll(*user.id());
+        mfa.enable();
+        let user_id = *user.id();
+        let user_repo = Arc::new(MockUserRepository::new().with_user(user));
+        let mfa_repo = Arc::new(MockUserMfaRepository::new().with_mfa(mfa));
+
+        let result = EnrollMfaUseCase::new(user_repo, mfa_repo, MfaPolicy::default())
+            .execute(user_id)
+            .await;
+        assert!(matches!(result, Err(AuthError::MfaAlreadyEnabled)));
+    }
+
+    // =========================================================================
+    // Disable Tests
+    // =========================================================================
+
+    #[tokio::test]
+    async fn test_disable_requires_password_and_second_factor() {
+        let user = create_user("password123");
+        let mut mfa = UserMfa::enroll(*user.id());
+        mfa.enable();
+        let secret = mfa.secret().clone();
+        let user_id = *user.id();
+        let user_repo = Arc::new(MockUserRepository::new().with_user(user));
+        let mfa_repo = Arc::new(MockUserMfaRepository::new().with_mfa(mfa));
+        let use_case = DisableMfaUseCase::new(user_repo, mfa_repo.clone(), MfaPolicy::default());
+
+        let wrong_password = use_case
+            .execute(
+                user_id,
+                DisableMfaCommand {
+                    password: "wrongpassword".to_string(),
+                    code: Some(current_code(&secret)),
+                    recovery_code: None,
+                },
+            )
+            .await;
+        assert!(matches!(wrong_password, Err(AuthError::InvalidCredentials)));
+
+        let wrong_code = use_case
+            .execute(
+                user_id,
+                DisableMfaCommand {
+                    password: "password123".to_string(),
+                    code: Some("000000".to_string()),
+                    recovery_code: None,
+                },
+            )
+            .await;
+        assert!(matches!(wrong_code, Err(AuthError::InvalidMfaCode)));
+        assert_eq!(mfa_repo.get(user_id).unwrap().failed_attempts(), 1);
+
+        use_case
+            .execute(
+                user_id,
+                DisableMfaCommand {
+                    password: "password123".to_string(),
+                    code: Some(current_code(&secret)),
+                    recovery_code: None,
+                },
+            )
+            .await
+            .unwrap();
+        assert!(mfa_repo.get(user_id).is_none());
+    }
+
+    // =========================================================================
+    // Admin Override Tests
+    // =========================================================================
+
+    #[tokio::test]
+    async fn test_admin_disable_removes_enrollment_and_records_audit() {
+        let user = create_user("password123");
+        let user_id = *user.id();
+        let admin_id = UserId::new();
+        let mut mfa = UserMfa::enroll(user_id);
+        mfa.enable();
+        let mfa_repo = Arc::new(MockUserMfaRepository::new().with_mfa(mfa));
+        let audit_repo = Arc::new(MockAuditRepository::new());
+
+        AdminDisableMfaUseCase::new(mfa_repo.clone(), audit_repo.clone())
+            .execute(user_id, admin_id)
+            .await
+            .unwrap();
+
+        assert!(mfa_repo.get(user_id).is_none());
+        let entries = audit_repo.get_entries();
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].entity_type(), "user_mfa");
+        assert_eq!(entries[0].entity_id(), user_id.into_uuid());
+        assert_eq!(*entries[0].action(), AuditAction::Deleted);
+        assert_eq!(*entries[0].actor_id(), admin_id);
+    }
+
+    #[tokio::test]
+    async fn test_admin_disable_keeps_enrollment_when_audit_fails() {
+        let user_id = UserId::new();
+        let mut mfa = UserMfa::enroll(user_id);
+        mfa.enable();
+        let mfa_repo = Arc::new(MockUserMfaRepository::new().with_mfa(mfa));
+
+        let result =
+            AdminDisableMfaUseCase::new(mfa_repo.clone(), Arc::new(MockAuditRepository::failing()))
+                .execute(user_id, UserId::new())
+                .await;
+
+        assert!(matches!(result, Err(AuthError::Internal(_))));
+        assert!(mfa_repo.get(user_id).unwrap().is_enabled());
+    }
+
+    #[tokio::test]
+    async fn test_admin_disable_without_enrollment() {
+        let audit_repo = Arc::new(MockAuditRepository::new());
+
+        let result =
+            AdminDisableMfaUseCase::new(Arc::new(MockUserMfaRepository::new()), audit_repo.clone())
+                .execute(UserId::new(), UserId::new())
+                .await;
+
+        assert!(matches!(result, Err(AuthError::MfaNotEnrolled)));
+        assert!(audit_repo.get_entries().is_empty());
+    }
+}
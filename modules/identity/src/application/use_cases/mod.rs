@@ -1,14 +1,20 @@
 // Use cases - Application business logic orchestration
 
+mod audit_use_cases;
 mod auth_use_cases;
 mod build_user_context;
+mod mfa_use_cases;
+mod password_reset_use_cases;
 mod permission_use_cases;
 mod role_use_cases;
 mod store_use_cases;
 mod user_use_cases;
 
+pub use audit_use_cases::*;
 pub use auth_use_cases::*;
 pub use build_user_context::*;
+pub use mfa_use_cases::*;
+pub use password_reset_use_cases::*;
 pub use permission_use_cases::*;
 pub use role_use_cases::*;
 pub use store_use_cases::*;
@@ -0,0 +1,688 @@
+// Password reset use cases - forgotten password recovery by email
+//
+// Requesting a reset issues a single-use, time-limited token whose raw value
+// is emailed by the caller; resetting with it sets a new password and signs
+// the user out everywhere.
+
+use std::sync::Arc;
+
+use chrono::{Duration, Utc};
+
+use super::auth_use_cases::hash_password;
+use crate::application::dtos::{
+    PasswordResetIssued, RequestPasswordResetCommand, ResetPasswordCommand,
+};
+use crate::application::validators::validate_password;
+use crate::domain::auth::AuthError;
+use crate::domain::entities::{PASSWORD_RESET_TOKEN_LIFETIME_MINUTES, PasswordResetToken};
+use crate::domain::repositories::{
+    PasswordResetTokenRepository, RefreshTokenRepository, UserRepository,
+};
+use crate::domain::value_objects::Email;
+
+// =============================================================================
+// RequestPasswordResetUseCase
+// =============================================================================
+
+/// Use case for requesting a password reset link.
+///
+/// Issues a new token for an active account and invalidates any earlier,
+/// unused one. The outcome must not tell the requester whether the account
+/// exists: callers answer the same way whether a token was issued or not,
+/// and only deliver the token to the account's email.
+pub struct RequestPasswordResetUseCase<U, P>
+where
+    U: UserRepository,
+    P: PasswordResetTokenRepository,
+{
+    user_repo: Arc<U>,
+    reset_repo: Arc<P>,
+}
+
+impl<U, P> RequestPasswordResetUseCase<U, P>
+where
+    U: UserRepository,
+    P: PasswordResetTokenRepository,
+{
+    /// Creates a new instance of RequestPasswordResetUseCase
+    pub fn new(user_repo: Arc<U>, reset_repo: Arc<P>) -> Self {
+        Self {
+            user_repo,
+            reset_repo,
+        }
+    }
+
+    /// Executes the use case.
+    ///
+    /// # Returns
+    /// The issued token to email, or `None` when there is no active account
+    /// for the email
+    ///
+    /// # Errors
+    /// * `AuthError::InvalidEmailFormat` - If the email is malformed
+    /// * `AuthError::Internal` - On internal errors
+    pub async fn execute(
+        &self,
+        command: RequestPasswordResetCommand,
+    ) -> Result<Option<PasswordResetIssued>, AuthError> {
+        let email = Email::new(&command.email).map_err(|_| AuthError::InvalidEmailFormat)?;
+
+        let user = match self
+            .user_repo
+            .find_by_email(&email)
+            .await
+            .map_err(|e| AuthError::Internal(e.to_string()))?
+        {
+            Some(user) if user.is_active() => user,
+            _ => return Ok(None),
+        };
+
+        // Only the latest link works
+        let user_id = *user.id();
+        self.reset_repo
+            .invalidate_all_for_user(user_id, Utc::now())
+            .await
+            .map_err(|e| AuthError::Internal(e.to_string()))?;
+
+        let (token, raw) = PasswordResetToken::issue(
+            user_id,
+            Duration::minutes(PASSWORD_RESET_TOKEN_LIFETIME_MINUTES),
+        );
+        self.reset_repo
+            .save(&token)
+            .await
+            .map_err(|e| AuthError::Internal(e.to_string()))?;
+
+        Ok(Some(PasswordResetIssued {
+            user_id: user_id.into_uuid(),
+            email: user.email().as_str().to_string(),
+            token: raw,
+            expires_at: token.expires_at(),
+        }))
+    }
+}
+
+// =============================================================================
+// ResetPasswordUseCase
+// =============================================================================
+
+/// Use case for setting a new password with a reset token.
+///
+/// The token is consumed before the password changes, so two concurrent
+/// resets with the same link cannot both succeed. On success every other
+/// reset token of the user is invalidated and all refresh token families
+/// are revoked, signing the user out of every session.
+pub struct ResetPasswordUseCase<U, P, R>
+where
+    U: UserRepository,
+    P: PasswordResetTokenRepository,
+    R: RefreshTokenRepository,
+{
+    user_repo: Arc<U>,
+    reset_repo: Arc<P>,
+    refresh_repo: Arc<R>,
+}
+
+impl<U, P, R> ResetPasswordUseCase<U, P, R>
+where
+    U: UserRepository,
+    P: PasswordResetTokenRepository,
+    R: RefreshTokenRepository,
+{
+    /// Creates a new instance of ResetPasswordUseCase
+    pub fn new(user_repo: Arc<U>, reset_repo: Arc<P>, refresh_repo: Arc<R>) -> Self {
+        Self {
+            user_repo,
+            reset_repo,
+            refresh_repo,
+        }
+    }
+
+    /// Executes the use case.
+    ///
+    /// # Errors
+    /// * `AuthError::PasswordTooShort` - If the new password is too short;
+    ///   the token stays usable
+    /// * `AuthError::InvalidToken` - If the token is unknown or already used
+    /// * `AuthError::TokenExpired` - If the token has expired
+    /// * `AuthError::AccountDisabled` - If the account was deactivated
+    /// * `AuthError::Internal` - On internal errors
+    pub async fn execute(&self, command: ResetPasswordCommand) -> Result<(), AuthError> {
+        validate_password(&command.new_password)?;
+
+        let token = self
+            .reset_repo
+            .find_by_hash(&PasswordResetToken::hash(&command.token))
+            .await
+            .map_err(|e| AuthError::Internal(e.to_string()))?
+            .filter(|t| !t.is_used())
+            .ok_or(AuthError::InvalidToken)?;
+        let now = Utc::now();
+        if token.is_expired(now) {
+            return Err(AuthError::TokenExpired);
+        }
+
+        let mut user = self
+            .user_repo
+            .find_by_id(token.user_id())
+            .await
+            .map_err(|e| AuthError::Internal(e.to_string()))?
+            .ok_or(AuthError::InvalidToken)?;
+        if !user.is_active() {
+            return Err(AuthError::AccountDisabled);
+        }
+
+        let consumed = self
+            .reset_repo
+            .mark_used(token.id(), now)
+            .await
+            .map_err(|e| AuthError::Internal(e.to_string()))?;
+        if !consumed {
+            return Err(AuthError::InvalidToken);
+        }
+
+        user.set_password_hash(hash_password(&command.new_password)?);
+        self.user_repo
+            .update(&user)
+            .await
+            .map_err(|e| AuthError::Internal(e.to_string()))?;
+
+        self.reset_repo
+            .invalidate_all_for_user(token.user_id(), now)
+            .await
+            .map_err(|e| AuthError::Internal(e.to_string()))?;
+        self.refresh_repo
+            .revoke_all_for_user(token.user_id(), now)
+            .await
+            .map_err(|e| AuthError::Internal(e.to_string()))?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use async_trait::async_trait;
+    use chrono::DateTime;
+    use std::collections::HashMap;
+    use std::sync::Mutex;
+    use uuid::Uuid;
+
+    use crate::application::use_cases::auth_use_cases::verify_password;
+    use crate::domain::entities::{Permission, RefreshToken, Role, Store, User};
+    use crate::domain::value_objects::{RoleId, StoreId, UserId, Username};
+    use crate::error::IdentityError;
+
+    // =========================================================================
+    // Mock Repositories
+    // =========================================================================
+
+    struct MockUserRepository {
+        users: Mutex<HashMap<UserId, User>>,
+    }
+
+    impl MockUserRepository {
+        fn new() -> Self {
+            Self {
+                users: Mutex::new(HashMap::new()),
+            }
+        }
+
+        fn with_user(self, user: User) -> Self {
+            self.users.lock().unwrap().insert(*user.id(), user);
+            self
+        }
+    }
+
+    #[async_trait]
+    impl UserRepository for MockUserRepository {
+        async fn save(&self, user: &User) -> Result<(), crate::error::IdentityError> {
+            let mut users = self.users.lock().unwrap();
+            users.insert(*user.id(), user.clone());
+            Ok(())
+        }
+
+        async fn find_by_id(
+            &self,
+            id: UserId,
+        ) -> Result<Option<User>, crate::error::IdentityError> {
+            let users = self.users.lock().unwrap();
+            Ok(users.get(&id).cloned())
+        }
+
+        async fn find_by_email(
+            &self,
+            email: &Email,
+        ) -> Result<Option<User>, crate::error::IdentityError> {
+            let users = self.users.lock().unwrap();
+            Ok(users.values().find(|u| u.email() == email).cloned())
+        }
+
+        async fn find_by_username(
+            &self,
+            username: &Username,
+        ) -> Result<Option<User>, crate::error::IdentityError> {
+            let users = self.users.lock().unwrap();
+            Ok(users.values().find(|u| u.username() == username).cloned())
+        }
+
+        async fn update(&self, user: &User) -> Result<(), crate::error::IdentityError> {
+            let mut users = self.users.lock().unwrap();
+            users.insert(*user.id(), user.clone());
+            Ok(())
+        }
+
+        async fn assign_role(
+            &self,
+            _user_id: UserId,
+            _role_id: RoleId,
+            _store_id: StoreId,
+        ) -> Result<(), crate::error::IdentityError> {
+            Ok(())
+        }
+
+        async fn remove_role(
+            &self,
+            _user_id: UserId,
+            _role_id: RoleId,
+            _store_id: StoreId,
+        ) -> Result<(), crate::error::IdentityError> {
+            Ok(())
+        }
+
+        async fn get_roles_for_store(
+            &self,
+            _user_id: UserId,
+            _store_id: StoreId,
+        ) -> Result<Vec<Role>, crate::error::IdentityError> {
+            Ok(vec![])
+        }
+
+        async fn get_permissions_for_store(
+            &self,
+            _user_id: UserId,
+            _store_id: StoreId,
+        ) -> Result<Vec<Permission>, crate::error::IdentityError> {
+            Ok(vec![])
+        }
+
+        async fn remove_role_from_all_users(
+            &self,
+            _role_id: RoleId,
+        ) -> Result<(), crate::error::IdentityError> {
+            Ok(())
+        }
+
+        async fn add_to_store(
+            &self,
+            _user_id: UserId,
+            _store_id: StoreId,
+        ) -> Result<(), crate::error::IdentityError> {
+            Ok(())
+        }
+
+        async fn remove_from_store(
+            &self,
+            _user_id: UserId,
+            _store_id: StoreId,
+        ) -> Result<(), crate::error::IdentityError> {
+            Ok(())
+        }
+
+        async fn get_stores(
+            &self,
+            _user_id: UserId,
+        ) -> Result<Vec<Store>, crate::error::IdentityError> {
+            Ok(vec![])
+        }
+
+        async fn is_member_of_store(
+            &self,
+            _user_id: UserId,
+            _store_id: StoreId,
+        ) -> Result<bool, crate::error::IdentityError> {
+            Ok(false)
+        }
+
+        async fn get_all_store_permissions(
+            &self,
+            _user_id: UserId,
+        ) -> Result<std::collections::HashMap<String, Vec<String>>, crate::error::IdentityError>
+        {
+            Ok(std::collections::HashMap::new())
+        }
+    }
+
+    struct MockPasswordResetTokenRepository {
+        tokens: Mutex<Vec<PasswordResetToken>>,
+    }
+
+    impl MockPasswordResetTokenRepository {
+        fn new() -> Self {
+            Self {
+                tokens: Mutex::new(Vec::new()),
+            }
+        }
+
+        fn with_token(self, token: PasswordResetToken) -> Self {
+            self.tokens.lock().unwrap().push(token);
+            self
+        }
+
+        fn for_user(&self, user_id: UserId) -> Vec<PasswordResetToken> {
+            self.tokens
+                .lock()
+                .unwrap()
+                .iter()
+                .filter(|t| t.user_id() == user_id)
+                .cloned()
+                .collect()
+        }
+    }
+
+    #[async_trait]
+    impl PasswordResetTokenRepository for MockPasswordResetTokenRepository {
+        async fn save(&self, token: &PasswordResetToken) -> Result<(), IdentityError> {
+            self.tokens.lock().unwrap().push(token.clone());
+            Ok(())
+        }
+
+        async fn find_by_hash(
+            &self,
+            token_hash: &str,
+        ) -> Result<Option<PasswordResetToken>, IdentityError> {
+            Ok(self
+                .tokens
+                .lock()
+                .unwrap()
+                .iter()
+                .find(|t| t.token_hash() == token_hash)
+                .cloned())
+        }
+
+        async fn mark_used(&self, id: Uuid, now: DateTime<Utc>) -> Result<bool, IdentityError> {
+            let mut tokens = self.tokens.lock().unwrap();
+            match tokens.iter_mut().find(|t| t.id() == id && !t.is_used()) {
+                Some(token) => {
+                    token.mark_used(now);
+                    Ok(true)
+                }
+                None => Ok(false),
+            }
+        }
+
+        async fn invalidate_all_for_user(
+            &self,
+            user_id: UserId,
+            now: DateTime<Utc>,
+        ) -> Result<u64, IdentityError> {
+            let mut invalidated = 0;
+            for token in self.tokens.lock().unwrap().iter_mut() {
+                if token.user_id() == user_id && !token.is_used() {
+                    token.mark_used(now);
+                    invalidated += 1;
+                }
+            }
+            Ok(invalidated)
+        }
+    }
+
+    struct MockRefreshTokenRepository {
+        tokens: Mutex<Vec<RefreshToken>>,
+    }
+
+    impl MockRefreshTokenRepository {
+        fn new() -> Self {
+            Self {
+                tokens: Mutex::new(Vec::new()),
+            }
+        }
+
+        fn with_token(self, token: RefreshToken) -> Self {
+            self.tokens.lock().unwrap().push(token);
+            self
+        }
+
+        fn all_revoked(&self) -> bool {
+            self.tokens.lock().unwrap().iter().all(|t| t.is_revoked())
+        }
+    }
+
+    #[async_trait]
+    impl RefreshTokenRepository for MockRefreshTokenRepository {
+        async fn save(&self, token: &RefreshToken) -> Result<(), IdentityError> {
+            self.tokens.lock().unwrap().push(token.clone());
+            Ok(())
+        }
+
+        async fn find_by_id(&self, id: Uuid) -> Result<Option<RefreshToken>, IdentityError> {
+            Ok(self
+                .tokens
+                .lock()
+                .unwrap()
+                .iter()
+                .find(|t| t.id() == id)
+                .cloned())
+        }
+
+        async fn rotate(
+            &self,
+            _current: Uuid,
+            _successor: &RefreshToken,
+            _now: DateTime<Utc>,
+        ) -> Result<bool, IdentityError> {
+            unimplemented!("Not needed for password reset tests")
+        }
+
+        async fn revoke_family(
+            &self,
+            _family_id: Uuid,
+            _now: DateTime<Utc>,
+        ) -> Result<u64, IdentityError> {
+            unimplemented!("Not needed for password reset tests")
+        }
+
+        async fn revoke_all_for_user(
+            &self,
+            user_id: UserId,
+            now: DateTime<Utc>,
+        ) -> Result<u64, IdentityError> {
+            let mut revoked = 0;
+            for token in self.tokens.lock().unwrap().iter_mut() {
+                if token.user_id() == user_id && !token.is_revoked() {
+                    token.revoke(now);
+                    revoked += 1;
+                }
+            }
+            Ok(revoked)
+        }
+    }
+
+    // =========================================================================
+    // Helpers
+    // =========================================================================
+
+    fn create_user(active: bool) -> User {
+        let mut user = User::create(
+            Username::new("ada").unwrap(),
+            Email::new("ada@example.com").unwrap(),
+            "Ada".to_string(),
+            "Lovelace".to_string(),
+            hash_password("old-password").unwrap(),
+        );
+        if !active {
+            user.deactivate();
+        }
+        user
+    }
+
+    fn reset_command(token: &str, new_password: &str) -> ResetPasswordCommand {
+        ResetPasswordCommand {
+            token: token.to_string(),
+            new_password: new_password.to_string(),
+        }
+    }
+
+    // =========================================================================
+    // RequestPasswordResetUseCase Tests
+    // =========================================================================
+
+    #[tokio::test]
+    async fn test_request_issues_token_stored_hashed() {
+        let user = create_user(true);
+        let user_id = *user.id();
+        let user_repo = Arc::new(MockUserRepository::new().with_user(user));
+        let reset_repo = Arc::new(MockPasswordResetTokenRepository::new());
+
+        let issued = RequestPasswordResetUseCase::new(user_repo, reset_repo.clone())
+            .execute(RequestPasswordResetCommand {
+                email: "ada@example.com".to_string(),
+            })
+            .await
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(issued.email, "ada@example.com");
+        let stored = reset_repo.for_user(user_id);
+        assert_eq!(stored.len(), 1);
+        assert_ne!(stored[0].token_hash(), issued.token);
+        assert_eq!(
+            stored[0].token_hash(),
+            PasswordResetToken::hash(&issued.token)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_request_for_unknown_or_inactive_account_issues_nothing() {
+        let user_repo = Arc::new(MockUserRepository::new().with_user(create_user(false)));
+        let reset_repo = Arc::new(MockPasswordResetTokenRepository::new());
+        let use_case = RequestPasswordResetUseCase::new(user_repo, reset_repo.clone());
+
+        for email in ["nobody@example.com", "ada@example.com"] {
+            let result = use_case
+                .execute(RequestPasswordResetCommand {
+                    email: email.to_string(),
+                })
+                .await;
+            assert!(matches!(result, Ok(None)));
+        }
+        assert!(reset_repo.tokens.lock().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_new_request_invalidates_previous_token() {
+        let user = create_user(true);
+        let user_repo = Arc::new(MockUserRepository::new().with_user(user));
+        let reset_repo = Arc::new(MockPasswordResetTokenRepository::new());
+        let refresh_repo = Arc::new(MockRefreshTokenRepository::new());
+        let request = RequestPasswordResetUseCase::new(user_repo.clone(), reset_repo.clone());
+        let command = RequestPasswordResetCommand {
+            email: "ada@example.com".to_string(),
+        };
+
+        let first = request.execute(command.clone()).await.unwrap().unwrap();
+        let second = request.execute(command).await.unwrap().unwrap();
+
+        let reset = ResetPasswordUseCase::new(user_repo, reset_repo, refresh_repo);
+        let result = reset
+            .execute(reset_command(&first.token, "new-password"))
+            .await;
+        assert!(matches!(result, Err(AuthError::InvalidToken)));
+        assert!(
+            reset
+                .execute(reset_command(&second.token, "new-password"))
+                .await
+                .is_ok()
+        );
+    }
+
+    // =========================================================================
+    // ResetPasswordUseCase Tests
+    // =========================================================================
+
+    #[tokio::test]
+    async fn test_reset_updates_password_and_revokes_sessions() {
+        let user = create_user(true);
+        let user_id = *user.id();
+        let (token, raw) = PasswordResetToken::issue(user_id, Duration::minutes(60));
+        let user_repo = Arc::new(MockUserRepository::new().with_user(user));
+        let reset_repo = Arc::new(MockPasswordResetTokenRepository::new().with_token(token));
+        let refresh_repo = Arc::new(
+            MockRefreshTokenRepository::new()
+                .with_token(RefreshToken::issue(user_id, Duration::days(7)))
+                .with_token(RefreshToken::issue(user_id, Duration::days(7))),
+        );
+        let use_case =
+            ResetPasswordUseCase::new(user_repo.clone(), reset_repo.clone(), refresh_repo.clone());
+
+        use_case
+            .execute(reset_command(&raw, "new-password"))
+            .await
+            .unwrap();
+
+        let user = user_repo.find_by_id(user_id).await.unwrap().unwrap();
+        assert!(verify_password("new-password", user.password_hash()).unwrap());
+        assert!(!verify_password("old-password", user.password_hash()).unwrap());
+        assert!(refresh_repo.all_revoked());
+
+        // Single use
+        let again = use_case
+            .execute(reset_command(&raw, "other-password"))
+            .await;
+        assert!(matches!(again, Err(AuthError::InvalidToken)));
+    }
+
+    #[tokio::test]
+    async fn test_reset_rejects_short_password_without_consuming_token() {
+        let user = create_user(true);
+        let (token, raw) = PasswordResetToken::issue(*user.id(), Duration::minutes(60));
+        let user_repo = Arc::new(MockUserRepository::new().with_user(user));
+        let reset_repo = Arc::new(MockPasswordResetTokenRepository::new().with_token(token));
+        let use_case = ResetPasswordUseCase::new(
+            user_repo,
+            reset_repo,
+            Arc::new(MockRefreshTokenRepository::new()),
+        );
+
+        let short = use_case.execute(reset_command(&raw, "short")).await;
+        assert!(matches!(short, Err(AuthError::PasswordTooShort)));
+        assert!(
+            use_case
+                .execute(reset_command(&raw, "long-enough"))
+                .await
+                .is_ok()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_reset_rejects_expired_token() {
+        let user = create_user(true);
+        let (token, raw) = PasswordResetToken::issue(*user.id(), Duration::minutes(-1));
+        let user_repo = Arc::new(MockUserRepository::new().with_user(user));
+        let reset_repo = Arc::new(MockPasswordResetTokenRepository::new().with_token(token));
+
+        let result = ResetPasswordUseCase::new(
+            user_repo,
+            reset_repo,
+            Arc::new(MockRefreshTokenRepository::new()),
+        )
+        .execute(reset_command(&raw, "new-password"))
+        .await;
+
+        assert!(matches!(result, Err(AuthError::TokenExpired)));
+    }
+
+    #[tokio::test]
+    async fn test_reset_rejects_unknown_token() {
+        let result = ResetPasswordUseCase::new(
+            Arc::new(MockUserRepository::new()),
+            Arc::new(MockPasswordResetTokenRepository::new()),
+            Arc::new(MockRefreshTokenRepository::new()),
+        )
+        .execute(reset_command("not-a-token", "new-password"))
+        .await;
+
+        assert!(matches!(result, Err(AuthError::InvalidToken)));
+    }
+}
@@ -326,6 +326,15 @@ mod tests {
                 .cloned()
                 .collect())
         }
+
+        async fn find_paginated(
+            &self,
+            _filter: &crate::domain::repositories::AuditEntryFilter,
+            _page: i64,
+            _page_size: i64,
+        ) -> Result<(Vec<AuditEntry>, i64), IdentityError> {
+            unimplemented!()
+        }
     }
 
     // Mock RoleRepository for testing
@@ -2,8 +2,12 @@
 
 use std::sync::Arc;
 
-use crate::application::dtos::{CreateStoreCommand, UpdateStoreCommand};
-use crate::domain::entities::{AuditAction, AuditEntry, Store};
+use crate::application::dtos::{
+    CreateStoreCommand, UpdateStoreCommand, UpdateStoreSettingsCommand,
+};
+use crate::domain::entities::{
+    AuditAction, AuditEntry, MAX_PICKUP_HOLD_DAYS, Store, StoreSettings,
+};
 use crate::domain::repositories::{AuditRepository, StoreRepository, UserRepository};
 use crate::domain::value_objects::{StoreId, UserId};
 use crate::error::IdentityError;
@@ -157,6 +161,166 @@ where
     }
 }
 
+// =============================================================================
+// GetStoreSettingsUseCase
+// =============================================================================
+
+/// Use case for reading a store's settings
+///
+/// Stores whose settings were never changed get the defaults.
+pub struct GetStoreSettingsUseCase<S>
+where
+    S: StoreRepository,
+{
+    store_repo: Arc<S>,
+}
+
+impl<S> GetStoreSettingsUseCase<S>
+where
+    S: StoreRepository,
+{
+    /// Creates a new instance of GetStoreSettingsUseCase
+    pub fn new(store_repo: Arc<S>) -> Self {
+        Self { store_repo }
+    }
+
+    /// Executes the use case to read a store's settings
+    ///
+    /// # Errors
+    /// * `IdentityError::StoreNotFound` - If store doesn't exist
+    pub async fn execute(&self, store_id: StoreId) -> Result<StoreSettings, IdentityError> {
+        if let Some(settings) = self.store_repo.find_settings(store_id).await? {
+            return Ok(settings);
+        }
+
+        // No saved row: only fall back to defaults for a store that exists
+        self.store_repo
+            .find_by_id(store_id)
+            .await?
+            .ok_or(IdentityError::StoreNotFound(store_id.into_uuid()))?;
+
+        Ok(StoreSettings::defaults(store_id))
+    }
+}
+
+// =============================================================================
+// UpdateStoreSettingsUseCase
+// =============================================================================
+
+/// Use case for changing a store's settings
+///
+/// Applies the provided fields on top of the current settings and creates an
+/// audit entry.
+///
+/// Negative stock can't be turned on while transfers to or from the store are
+/// still in transit: their receipt would be reconciled against stock that may
+/// already have been sold below zero. The caller tells the use case whether
+/// any are, since transfers belong to inventory.
+pub struct UpdateStoreSettingsUseCase<S, A>
+where
+    S: StoreRepository,
+    A: AuditRepository,
+{
+    store_repo: Arc<S>,
+    audit_repo: Arc<A>,
+    transfers_in_transit: bool,
+}
+
+impl<S, A> UpdateStoreSettingsUseCase<S, A>
+where
+    S: StoreRepository,
+    A: AuditRepository,
+{
+    /// Creates a new instance of UpdateStoreSettingsUseCase
+    pub fn new(store_repo: Arc<S>, audit_repo: Arc<A>) -> Self {
+        Self {
+            store_repo,
+            audit_repo,
+            transfers_in_transit: false,
+        }
+    }
+
+    /// Sets whether the store has transfers shipped but not yet received
+    pub fn with_transfers_in_transit(mut self, in_transit: bool) -> Self {
+        self.transfers_in_transit = in_transit;
+        self
+    }
+
+    /// Executes the use case to change a store's settings
+    ///
+    /// # Errors
+    /// * `IdentityError::StoreNotFound` - If store doesn't exist
+    /// * `IdentityError::InvalidStoreSettings` - If the command changes nothing,
+    ///   the pickup hold is out of range or negative stock is turned on while
+    ///   transfers are in transit
+    pub async fn execute(
+        &self,
+        store_id: StoreId,
+        command: UpdateStoreSettingsCommand,
+        actor_id: UserId,
+    ) -> Result<StoreSettings, IdentityError> {
+        if command.loyalty_enabled.is_none()
+            && command.auto_markdowns_enabled.is_none()
+            && command.allow_negative_stock.is_none()
+            && command.pickup_hold_days.is_none()
+            && command.transit_owned_by_destination.is_none()
+        {
+            return Err(IdentityError::InvalidStoreSettings(
+                "at least one setting must be provided".to_string(),
+            ));
+        }
+        if let Some(days) = command.pickup_hold_days
+            && !(1..=MAX_PICKUP_HOLD_DAYS).contains(&days)
+        {
+            return Err(IdentityError::InvalidStoreSettings(format!(
+                "pickup_hold_days must be between 1 and {}",
+                MAX_PICKUP_HOLD_DAYS
+            )));
+        }
+
+        let mut settings = GetStoreSettingsUseCase::new(self.store_repo.clone())
+            .execute(store_id)
+            .await?;
+        let old_settings = settings.clone();
+
+        if let Some(enabled) = command.loyalty_enabled {
+            settings.set_loyalty_enabled(enabled);
+        }
+        if let Some(enabled) = command.auto_markdowns_enabled {
+            settings.set_auto_markdowns_enabled(enabled);
+        }
+        if let Some(allow) = command.allow_negative_stock {
+            if allow && !settings.allow_negative_stock() && self.transfers_in_transit {
+                return Err(IdentityError::InvalidStoreSettings(
+                    "allow_negative_stock cannot be enabled while transfers are in transit"
+                        .to_string(),
+                ));
+            }
+            settings.set_allow_negative_stock(allow);
+        }
+        if let Some(days) = command.pickup_hold_days {
+            settings.set_pickup_hold_days(days);
+        }
+        if let Some(by_destination) = command.transit_owned_by_destination {
+            settings.set_transit_owned_by_destination(by_destination);
+        }
+        settings.touch(actor_id);
+
+        self.store_repo.save_settings(&settings).await?;
+
+        let audit_entry = AuditEntry::for_update(
+            "store_settings",
+            store_id.into_uuid(),
+            &old_settings,
+            &settings,
+            actor_id,
+        );
+        self.audit_repo.save(&audit_entry).await?;
+
+        Ok(settings)
+    }
+}
+
 // =============================================================================
 // SetStoreActiveUseCase
 // =============================================================================
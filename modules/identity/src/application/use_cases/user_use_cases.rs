@@ -504,7 +504,7 @@ mod tests {
     use std::sync::Mutex;
     use uuid::Uuid;
 
-    use crate::domain::entities::{Permission, Role, Store};
+    use crate::domain::entities::{Permission, Role, Store, StoreSettings};
 
     // Mock UserRepository for testing
     struct MockUserRepository {
@@ -704,6 +704,15 @@ mod tests {
                 .cloned()
                 .collect())
         }
+
+        async fn find_paginated(
+            &self,
+            _filter: &crate::domain::repositories::AuditEntryFilter,
+            _page: i64,
+            _page_size: i64,
+        ) -> Result<(Vec<AuditEntry>, i64), IdentityError> {
+            unimplemented!()
+        }
     }
 
     // Mock RoleRepository for testing
@@ -848,9 +857,25 @@ mod tests {
             _page: i64,
             _page_size: i64,
             _user_store_ids: Option<&[uuid::Uuid]>,
+            _after_id: Option<StoreId>,
         ) -> Result<(Vec<Store>, i64), IdentityError> {
             unimplemented!()
         }
+
+        async fn find_settings(
+            &self,
+            _store_id: StoreId,
+        ) -> Result<Option<StoreSettings>, IdentityError> {
+            unimplemented!()
+        }
+
+        async fn find_all_settings(&self) -> Result<Vec<StoreSettings>, IdentityError> {
+            unimplemented!()
+        }
+
+        async fn save_settings(&self, _settings: &StoreSettings) -> Result<(), IdentityError> {
+            unimplemented!()
+        }
     }
 
     // =============================================================================
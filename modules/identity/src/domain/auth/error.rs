@@ -28,6 +28,11 @@ pub enum AuthError {
     #[error("Invalid token")]
     InvalidToken,
 
+    /// A refresh token was presented again after it had been rotated, so it
+    /// may have been stolen; every token of its family was revoked
+    #[error("Refresh token reuse detected")]
+    RefreshTokenReused,
+
     /// Password does not meet minimum length requirement (8 characters)
     #[error("Password too short: minimum 8 characters required")]
     PasswordTooShort,
@@ -56,6 +61,23 @@ pub enum AuthError {
     #[error("Invalid name: {0}")]
     InvalidName(String),
 
+    /// Second-factor code (TOTP or recovery code) is wrong, expired or
+    /// already used
+    #[error("Invalid verification code")]
+    InvalidMfaCode,
+
+    /// Too many failed second-factor attempts; the factor is locked for a while
+    #[error("Too many failed verification attempts, try again later")]
+    MfaLocked,
+
+    /// The user has no enrollment in the state the operation needs
+    #[error("Multi-factor authentication is not enrolled")]
+    MfaNotEnrolled,
+
+    /// The user already has multi-factor authentication enabled
+    #[error("Multi-factor authentication is already enabled")]
+    MfaAlreadyEnabled,
+
     /// Internal error - wraps unexpected failures
     #[error("Internal error: {0}")]
     Internal(String),
@@ -119,6 +141,15 @@ mod tests {
         assert_eq!(err.to_string(), "Store not found or inactive");
     }
 
+    #[test]
+    fn test_mfa_locked_message() {
+        let err = AuthError::MfaLocked;
+        assert_eq!(
+            err.to_string(),
+            "Too many failed verification attempts, try again later"
+        );
+    }
+
     #[test]
     fn test_error_equality() {
         assert_eq!(AuthError::InvalidCredentials, AuthError::InvalidCredentials);
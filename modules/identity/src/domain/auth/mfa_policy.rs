@@ -0,0 +1,44 @@
+// MfaPolicy - settings of multi-factor authentication
+
+use chrono::Duration;
+
+/// How TOTP enrollment is labelled and how failed second-factor attempts are
+/// throttled.
+///
+/// After `max_failed_attempts` wrong codes in a row the user's second factor
+/// is locked for `lockout`, whichever endpoint the attempts came through.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MfaPolicy {
+    issuer: String,
+    max_failed_attempts: i32,
+    lockout: Duration,
+}
+
+impl MfaPolicy {
+    pub fn new(issuer: impl Into<String>, max_failed_attempts: i32, lockout: Duration) -> Self {
+        Self {
+            issuer: issuer.into(),
+            max_failed_attempts: max_failed_attempts.max(1),
+            lockout,
+        }
+    }
+
+    /// Issuer shown by authenticator apps next to the account
+    pub fn issuer(&self) -> &str {
+        &self.issuer
+    }
+
+    pub fn max_failed_attempts(&self) -> i32 {
+        self.max_failed_attempts
+    }
+
+    pub fn lockout(&self) -> Duration {
+        self.lockout
+    }
+}
+
+impl Default for MfaPolicy {
+    fn default() -> Self {
+        Self::new("POS", 5, Duration::minutes(15))
+    }
+}
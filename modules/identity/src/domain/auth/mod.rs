@@ -4,10 +4,14 @@
 
 mod error;
 mod login_identifier;
+mod mfa_policy;
 mod token_claims;
 mod token_service;
+mod totp_secret;
 
 pub use error::AuthError;
 pub use login_identifier::LoginIdentifier;
-pub use token_claims::TokenClaims;
-pub use token_service::TokenService;
+pub use mfa_policy::MfaPolicy;
+pub use token_claims::{RefreshTokenSubject, TokenClaims};
+pub use token_service::{MFA_TOKEN_LIFETIME_SECS, TokenService};
+pub use totp_secret::{TOTP_DIGITS, TOTP_PERIOD_SECS, TotpSecret};
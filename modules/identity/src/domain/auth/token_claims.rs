@@ -8,6 +8,8 @@ use common::{ActorClaim, TokenAudience};
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
+use crate::domain::value_objects::UserId;
+
 /// Claims contained in JWT access token.
 ///
 /// These claims are embedded in the JWT payload and contain
@@ -128,6 +130,18 @@ impl TokenClaims {
     }
 }
 
+/// What a valid refresh token identifies: its user and the server-side
+/// `RefreshToken` record it was issued as, which decides whether it may
+/// still be used
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RefreshTokenSubject {
+    pub user_id: UserId,
+    /// Id of the stored token (the JWT `jti`)
+    pub token_id: Uuid,
+    /// Rotation chain the token belongs to
+    pub family_id: Uuid,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
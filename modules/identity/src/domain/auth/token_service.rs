@@ -6,12 +6,17 @@
 
 use std::collections::HashMap;
 
+use chrono::Duration;
 use uuid::Uuid;
 
-use crate::domain::auth::{AuthError, TokenClaims};
-use crate::domain::entities::User;
+use crate::domain::auth::{AuthError, RefreshTokenSubject, TokenClaims};
+use crate::domain::entities::{RefreshToken, User};
 use crate::domain::value_objects::UserId;
 
+/// Lifetime in seconds of the MFA token issued between the password and the
+/// second factor
+pub const MFA_TOKEN_LIFETIME_SECS: i64 = 300;
+
 /// Token service port for authentication token operations.
 ///
 /// This trait defines the hexagonal port for token management.
@@ -22,7 +27,11 @@ use crate::domain::value_objects::UserId;
 /// - **Access Token**: Short-lived token (15 min) containing user claims
 ///   for authenticating API requests.
 /// - **Refresh Token**: Long-lived token (7 days) used to obtain new
-///   access tokens without re-authentication.
+///   access tokens without re-authentication. Each one encodes a stored
+///   `RefreshToken` and is single-use: refreshing rotates it.
+/// - **MFA Token**: Short-lived token (5 min) proving the password was
+///   checked, exchanged for access and refresh tokens once the second factor
+///   passes. It grants no API access by itself.
 ///
 pub trait TokenService: Send + Sync {
     /// Generates an access token for the given user.
@@ -45,21 +54,24 @@ pub trait TokenService: Send + Sync {
         store_permissions: &HashMap<String, Vec<String>>,
     ) -> Result<String, AuthError>;
 
-    /// Generates a refresh token for the given user.
+    /// Encodes a stored refresh token for the client.
     ///
-    /// The refresh token has a longer expiration time (typically 7 days)
-    /// and is used to obtain new access tokens without re-authentication.
+    /// The token carries the user, the stored token's id and its family,
+    /// and expires at the stored token's `expires_at`.
     ///
     /// # Arguments
     ///
-    /// * `user_id` - The user ID to embed in the refresh token
+    /// * `token` - The refresh token record issued for the user
     ///
     /// # Returns
     ///
     /// * `Ok(String)` - The encoded refresh token
     /// * `Err(AuthError)` - If token generation fails
     ///
-    fn generate_refresh_token(&self, user_id: UserId) -> Result<String, AuthError>;
+    fn generate_refresh_token(&self, token: &RefreshToken) -> Result<String, AuthError>;
+
+    /// Lifetime of newly issued refresh tokens (typically 7 days)
+    fn refresh_token_lifetime(&self) -> Duration;
 
     /// Validates and decodes an access token.
     ///
@@ -78,10 +90,11 @@ pub trait TokenService: Send + Sync {
     ///
     fn validate_access_token(&self, token: &str) -> Result<TokenClaims, AuthError>;
 
-    /// Validates a refresh token and extracts the user ID.
+    /// Validates a refresh token and extracts what it identifies.
     ///
-    /// Verifies the refresh token signature and expiration, then
-    /// returns the embedded user ID for generating a new access token.
+    /// Verifies the refresh token signature and expiration. Whether the
+    /// stored token was since rotated or revoked is checked by the caller
+    /// against the `RefreshTokenRepository`.
     ///
     /// # Arguments
     ///
@@ -89,11 +102,30 @@ pub trait TokenService: Send + Sync {
     ///
     /// # Returns
     ///
+    /// * `Ok(RefreshTokenSubject)` - The user and stored token ids if valid
+    /// * `Err(AuthError::TokenExpired)` - If the token has expired
+    /// * `Err(AuthError::InvalidToken)` - If the token is malformed, has an
+    ///   invalid signature or carries no token id (issued before rotation)
+    ///
+    fn validate_refresh_token(&self, token: &str) -> Result<RefreshTokenSubject, AuthError>;
+
+    /// Generates an MFA token for a user whose password was verified and who
+    /// still has to present a second factor.
+    ///
+    /// The token expires after [`MFA_TOKEN_LIFETIME_SECS`] and is accepted
+    /// neither as an access token nor as a refresh token.
+    fn generate_mfa_token(&self, user_id: UserId) -> Result<String, AuthError>;
+
+    /// Validates an MFA token and extracts the user ID.
+    ///
+    /// # Returns
+    ///
     /// * `Ok(UserId)` - The user ID from the token if valid
     /// * `Err(AuthError::TokenExpired)` - If the token has expired
-    /// * `Err(AuthError::InvalidToken)` - If the token is malformed or has invalid signature
+    /// * `Err(AuthError::InvalidToken)` - If the token is malformed, has an
+    ///   invalid signature or is not an MFA token
     ///
-    fn validate_refresh_token(&self, token: &str) -> Result<UserId, AuthError>;
+    fn validate_mfa_token(&self, token: &str) -> Result<UserId, AuthError>;
 
     /// Mints a short-lived (15 min) impersonation token for `user`: a full
     /// tenant access token (same claims as [`generate_access_token`], so the
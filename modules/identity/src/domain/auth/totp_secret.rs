@@ -0,0 +1,193 @@
+// TotpSecret - shared secret of a time-based one-time password (RFC 6238)
+//
+// Generates the secret at enrollment, renders it for authenticator apps and
+// checks the 6-digit codes they produce.
+
+use std::fmt;
+
+use base32::Alphabet;
+use chrono::{DateTime, Utc};
+use hmac::{Hmac, Mac};
+use rand::RngCore;
+use rand::rngs::OsRng;
+use sha1::Sha1;
+
+/// Length of a generated secret in bytes (160 bits, as RFC 4226 recommends)
+const SECRET_LEN: usize = 20;
+
+/// Digits of a code
+pub const TOTP_DIGITS: u32 = 6;
+
+/// Seconds each code stays current
+pub const TOTP_PERIOD_SECS: i64 = 30;
+
+/// Steps before and after the current one still accepted, to absorb clock
+/// drift between the server and the user's device
+const ALLOWED_SKEW_STEPS: i64 = 1;
+
+const BASE32: Alphabet = Alphabet::Rfc4648 { padding: false };
+
+/// TOTP shared secret (HMAC-SHA1, 6 digits, 30 second steps).
+///
+/// The raw bytes never appear in `Debug` output; persistence stores them
+/// encrypted.
+#[derive(Clone, PartialEq, Eq)]
+pub struct TotpSecret(Vec<u8>);
+
+impl TotpSecret {
+    /// Generates a new random secret
+    pub fn generate() -> Self {
+        let mut bytes = vec![0u8; SECRET_LEN];
+        OsRng.fill_bytes(&mut bytes);
+        Self(bytes)
+    }
+
+    /// Wraps secret bytes loaded from persistence
+    pub fn from_bytes(bytes: Vec<u8>) -> Self {
+        Self(bytes)
+    }
+
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.0
+    }
+
+    /// Base32 form that users type into an authenticator app
+    pub fn to_base32(&self) -> String {
+        base32::encode(BASE32, &self.0)
+    }
+
+    /// `otpauth://` URI that authenticator apps read from a QR code
+    pub fn provisioning_uri(&self, issuer: &str, account: &str) -> String {
+        format!(
+            "otpauth://totp/{}:{}?secret={}&issuer={}&algorithm=SHA1&digits={}&period={}",
+            percent_encode(issuer),
+            percent_encode(account),
+            self.to_base32(),
+            percent_encode(issuer),
+            TOTP_DIGITS,
+            TOTP_PERIOD_SECS,
+        )
+    }
+
+    /// Time step `at` falls in
+    pub fn step_at(at: DateTime<Utc>) -> i64 {
+        at.timestamp().div_euclid(TOTP_PERIOD_SECS)
+    }
+
+    /// Code for time step `step`
+    pub fn code_at(&self, step: i64) -> String {
+        let mut mac = Hmac::<Sha1>::new_from_slice(&self.0).expect("any key length");
+        mac.update(&step.to_be_bytes());
+        let digest = mac.finalize().into_bytes();
+
+        // Dynamic truncation (RFC 4226 section 5.3)
+        let offset = (digest[digest.len() - 1] & 0x0f) as usize;
+        let binary = u32::from_be_bytes([
+            digest[offset] & 0x7f,
+            digest[offset + 1],
+            digest[offset + 2],
+            digest[offset + 3],
+        ]);
+        format!(
+            "{:0width$}",
+            binary % 10u32.pow(TOTP_DIGITS),
+            width = TOTP_DIGITS as usize
+        )
+    }
+
+    /// Checks `code` against the steps around `now`.
+    ///
+    /// Returns the matching step, or `None` if no step matches. Steps at or
+    /// before `last_used_step` are rejected so a code cannot be replayed.
+    pub fn verify(
+        &self,
+        code: &str,
+        now: DateTime<Utc>,
+        last_used_step: Option<i64>,
+    ) -> Option<i64> {
+        let code = code.trim();
+        if code.len() != TOTP_DIGITS as usize || !code.chars().all(|c| c.is_ascii_digit()) {
+            return None;
+        }
+
+        let current = Self::step_at(now);
+        (current - ALLOWED_SKEW_STEPS..=current + ALLOWED_SKEW_STEPS)
+            .filter(|step| last_used_step.is_none_or(|last| *step > last))
+            .find(|step| constant_time_eq(self.code_at(*step).as_bytes(), code.as_bytes()))
+    }
+}
+
+impl fmt::Debug for TotpSecret {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("TotpSecret(..)")
+    }
+}
+
+/// Compares two byte strings without stopping at the first difference
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    a.len() == b.len() && a.iter().zip(b).fold(0u8, |acc, (x, y)| acc | (x ^ y)) == 0
+}
+
+/// Percent-encodes everything but RFC 3986 unreserved characters
+fn percent_encode(value: &str) -> String {
+    value
+        .bytes()
+        .map(|b| match b {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' => {
+                (b as char).to_string()
+            }
+            _ => format!("%{:02X}", b),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn rfc_secret() -> TotpSecret {
+        TotpSecret::from_bytes(b"12345678901234567890".to_vec())
+    }
+
+    #[test]
+    fn test_code_matches_rfc_6238_vectors() {
+        // RFC 6238 appendix B, SHA1, last 6 of the 8 published digits
+        let secret = rfc_secret();
+        assert_eq!(secret.code_at(59 / 30), "287082");
+        assert_eq!(secret.code_at(1111111109 / 30), "081804");
+        assert_eq!(secret.code_at(1234567890 / 30), "005924");
+    }
+
+    #[test]
+    fn test_verify_accepts_adjacent_steps_and_rejects_replay() {
+        let secret = rfc_secret();
+        let now = Utc.timestamp_opt(1111111109, 0).unwrap();
+        let step = TotpSecret::step_at(now);
+
+        assert_eq!(secret.verify("081804", now, None), Some(step));
+        assert_eq!(
+            secret.verify(&secret.code_at(step - 1), now, None),
+            Some(step - 1)
+        );
+        assert_eq!(secret.verify(&secret.code_at(step + 2), now, None), None);
+        assert_eq!(secret.verify("081804", now, Some(step)), None);
+        assert_eq!(secret.verify("08180", now, None), None);
+    }
+
+    #[test]
+    fn test_provisioning_uri() {
+        let secret = rfc_secret();
+        assert_eq!(secret.to_base32(), "GEZDGNBVGY3TQOJQGEZDGNBVGY3TQOJQ");
+        assert_eq!(
+            secret.provisioning_uri("POS Admin", "ana@example.com"),
+            "otpauth://totp/POS%20Admin:ana%40example.com?secret=GEZDGNBVGY3TQOJQGEZDGNBVGY3TQOJQ\
+             &issuer=POS%20Admin&algorithm=SHA1&digits=6&period=30"
+        );
+    }
+
+    #[test]
+    fn test_debug_hides_secret() {
+        assert_eq!(format!("{:?}", rfc_secret()), "TotpSecret(..)");
+    }
+}
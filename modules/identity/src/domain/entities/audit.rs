@@ -27,6 +27,8 @@ pub enum AuditAction {
     UserAddedToStore,
     /// User was removed from a store
     UserRemovedFromStore,
+    /// Data was exported (CSV/report download)
+    Exported,
 }
 
 impl std::fmt::Display for AuditAction {
@@ -41,13 +43,146 @@ impl std::fmt::Display for AuditAction {
             AuditAction::RoleUnassigned => write!(f, "role_unassigned"),
             AuditAction::UserAddedToStore => write!(f, "user_added_to_store"),
             AuditAction::UserRemovedFromStore => write!(f, "user_removed_from_store"),
+            AuditAction::Exported => write!(f, "exported"),
         }
     }
 }
 
+impl std::str::FromStr for AuditAction {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "created" => Ok(AuditAction::Created),
+            "updated" => Ok(AuditAction::Updated),
+            "deleted" => Ok(AuditAction::Deleted),
+            "permission_added" => Ok(AuditAction::PermissionAdded),
+            "permission_removed" => Ok(AuditAction::PermissionRemoved),
+            "role_assigned" => Ok(AuditAction::RoleAssigned),
+            "role_unassigned" => Ok(AuditAction::RoleUnassigned),
+            "user_added_to_store" => Ok(AuditAction::UserAddedToStore),
+            "user_removed_from_store" => Ok(AuditAction::UserRemovedFromStore),
+            "exported" => Ok(AuditAction::Exported),
+            other => Err(format!("unknown audit action '{}'", other)),
+        }
+    }
+}
+
+/// Placeholder written instead of the value of a sensitive field
+pub const REDACTED_AUDIT_VALUE: &str = "[REDACTED]";
+
+/// Returns true for fields whose values must never reach the audit log
+/// (password hashes, MFA secrets, API keys, tokens)
+pub fn is_sensitive_audit_field(field: &str) -> bool {
+    let field = field.to_ascii_lowercase();
+    field.contains("password")
+        || field.contains("secret")
+        || field.contains("api_key")
+        || field.contains("token")
+        || field.ends_with("_hash")
+        || field.ends_with("_hashes")
+}
+
+/// One field that differs between the old and new state of an entity
+///
+/// Nested fields are named by their dotted path (e.g. `"settings.currency"`);
+/// a field missing on one side has a `null` value there.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FieldChange {
+    pub field: String,
+    pub old_value: serde_json::Value,
+    pub new_value: serde_json::Value,
+}
+
+/// Serializes an entity for the audit log, redacting sensitive fields
+fn audit_snapshot<T: Serialize>(value: &T) -> serde_json::Value {
+    let mut snapshot = serde_json::to_value(value).unwrap_or(serde_json::Value::Null);
+    redact(&mut snapshot);
+    snapshot
+}
+
+/// Serializes an entity as is, for diffing before redaction
+fn raw_snapshot<T: Serialize>(value: &T) -> serde_json::Value {
+    serde_json::to_value(value).unwrap_or(serde_json::Value::Null)
+}
+
+fn redact(value: &mut serde_json::Value) {
+    match value {
+        serde_json::Value::Object(map) => {
+            for (key, field) in map.iter_mut() {
+                if is_sensitive_audit_field(key) {
+                    *field = serde_json::Value::String(REDACTED_AUDIT_VALUE.to_string());
+                } else {
+                    redact(field);
+                }
+            }
+        }
+        serde_json::Value::Array(items) => items.iter_mut().for_each(redact),
+        _ => {}
+    }
+}
+
+/// Computes the fields that differ between two snapshots, in field order
+///
+/// Objects are compared field by field; any other value (arrays included)
+/// is compared as a whole. Sensitive fields are diffed on their real values
+/// and then redacted, so a changed secret is recorded as changed without
+/// revealing either value.
+pub fn diff_snapshots(old: &serde_json::Value, new: &serde_json::Value) -> Vec<FieldChange> {
+    let mut changes = Vec::new();
+    diff_into(&mut changes, "", old, new);
+    for change in &mut changes {
+        if change.field.split('.').any(is_sensitive_audit_field) {
+            let redacted = serde_json::Value::String(REDACTED_AUDIT_VALUE.to_string());
+            change.old_value = redacted.clone();
+            change.new_value = redacted;
+        } else {
+            redact(&mut change.old_value);
+            redact(&mut change.new_value);
+        }
+    }
+    changes
+}
+
+fn diff_into(
+    changes: &mut Vec<FieldChange>,
+    path: &str,
+    old: &serde_json::Value,
+    new: &serde_json::Value,
+) {
+    use serde_json::Value;
+
+    match (old, new) {
+        (Value::Object(old_map), Value::Object(new_map)) => {
+            let removed = old_map.keys().filter(|k| !new_map.contains_key(*k));
+            for key in new_map.keys().chain(removed) {
+                let field = if path.is_empty() {
+                    key.clone()
+                } else {
+                    format!("{}.{}", path, key)
+                };
+                diff_into(
+                    changes,
+                    &field,
+                    old_map.get(key).unwrap_or(&Value::Null),
+                    new_map.get(key).unwrap_or(&Value::Null),
+                );
+            }
+        }
+        _ if old != new => changes.push(FieldChange {
+            field: path.to_string(),
+            old_value: old.clone(),
+            new_value: new.clone(),
+        }),
+        _ => {}
+    }
+}
+
 /// Audit entry recording a change in the identity module
 ///
 /// Captures who made what change, when, and the before/after state.
+/// Updates also carry the field-level `changes` between the two states.
+/// Sensitive fields are redacted in snapshots and changes alike.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AuditEntry {
     id: Uuid,
@@ -56,6 +191,8 @@ pub struct AuditEntry {
     action: AuditAction,
     old_value: Option<serde_json::Value>,
     new_value: Option<serde_json::Value>,
+    #[serde(default)]
+    changes: Vec<FieldChange>,
     actor_id: UserId,
     created_at: DateTime<Utc>,
 }
@@ -80,11 +217,18 @@ impl AuditEntry {
             action,
             old_value,
             new_value,
+            changes: Vec::new(),
             actor_id,
             created_at,
         }
     }
 
+    /// Sets the field-level changes (used when loading stored entries)
+    pub fn with_changes(mut self, changes: Vec<FieldChange>) -> Self {
+        self.changes = changes;
+        self
+    }
+
     /// Creates a new AuditEntry with auto-generated ID and current timestamp
     pub fn create(
         entity_type: String,
@@ -101,6 +245,7 @@ impl AuditEntry {
             action,
             old_value,
             new_value,
+            changes: Vec::new(),
             actor_id,
             created_at: Utc::now(),
         }
@@ -118,12 +263,13 @@ impl AuditEntry {
             entity_id,
             AuditAction::Created,
             None,
-            Some(serde_json::to_value(new_value).unwrap_or(serde_json::Value::Null)),
+            Some(audit_snapshot(new_value)),
             actor_id,
         )
     }
 
-    /// Creates an audit entry for entity update
+    /// Creates an audit entry for entity update, recording only the fields
+    /// that changed between `old_value` and `new_value`
     pub fn for_update<T: Serialize>(
         entity_type: &str,
         entity_id: Uuid,
@@ -131,14 +277,20 @@ impl AuditEntry {
         new_value: &T,
         actor_id: UserId,
     ) -> Self {
+        let mut old_value = raw_snapshot(old_value);
+        let mut new_value = raw_snapshot(new_value);
+        let changes = diff_snapshots(&old_value, &new_value);
+        redact(&mut old_value);
+        redact(&mut new_value);
         Self::create(
             entity_type.to_string(),
             entity_id,
             AuditAction::Updated,
-            Some(serde_json::to_value(old_value).unwrap_or(serde_json::Value::Null)),
-            Some(serde_json::to_value(new_value).unwrap_or(serde_json::Value::Null)),
+            Some(old_value),
+            Some(new_value),
             actor_id,
         )
+        .with_changes(changes)
     }
 
     /// Creates an audit entry for entity deletion
@@ -152,7 +304,7 @@ impl AuditEntry {
             entity_type.to_string(),
             entity_id,
             AuditAction::Deleted,
-            Some(serde_json::to_value(old_value).unwrap_or(serde_json::Value::Null)),
+            Some(audit_snapshot(old_value)),
             None,
             actor_id,
         )
@@ -184,6 +336,10 @@ impl AuditEntry {
         self.new_value.as_ref()
     }
 
+    pub fn changes(&self) -> &[FieldChange] {
+        &self.changes
+    }
+
     pub fn actor_id(&self) -> &UserId {
         &self.actor_id
     }
@@ -241,6 +397,20 @@ mod tests {
             format!("{}", AuditAction::UserRemovedFromStore),
             "user_removed_from_store"
         );
+        assert_eq!(format!("{}", AuditAction::Exported), "exported");
+    }
+
+    #[test]
+    fn test_audit_action_parses_its_display_form() {
+        for action in [
+            AuditAction::Created,
+            AuditAction::PermissionRemoved,
+            AuditAction::UserRemovedFromStore,
+            AuditAction::Exported,
+        ] {
+            assert_eq!(action.to_string().parse::<AuditAction>(), Ok(action));
+        }
+        assert!("renamed".parse::<AuditAction>().is_err());
     }
 
     #[test]
@@ -312,6 +482,89 @@ mod tests {
         assert_eq!(new_val["name"], "New");
     }
 
+    #[test]
+    fn test_for_update_records_only_changed_fields() {
+        let old_entity = TestEntity {
+            name: "Same".to_string(),
+            value: 1,
+        };
+        let new_entity = TestEntity {
+            name: "Same".to_string(),
+            value: 2,
+        };
+
+        let entry = AuditEntry::for_update(
+            "test_entity",
+            new_uuid(),
+            &old_entity,
+            &new_entity,
+            UserId::new(),
+        );
+
+        assert_eq!(
+            entry.changes(),
+            [FieldChange {
+                field: "value".to_string(),
+                old_value: serde_json::json!(1),
+                new_value: serde_json::json!(2),
+            }]
+        );
+    }
+
+    #[test]
+    fn test_diff_names_nested_fields_by_path() {
+        let old =
+            serde_json::json!({ "settings": { "currency": "HNL", "tax": 15 }, "tags": ["a"] });
+        let new = serde_json::json!({ "settings": { "currency": "USD", "tax": 15 }, "tags": ["a", "b"], "notes": "x" });
+
+        let fields: Vec<_> = diff_snapshots(&old, &new)
+            .into_iter()
+            .map(|c| c.field)
+            .collect();
+
+        assert_eq!(fields, ["notes", "settings.currency", "tags"]);
+    }
+
+    #[test]
+    fn test_sensitive_fields_are_redacted_in_snapshots_and_changes() {
+        #[derive(Serialize)]
+        struct Account {
+            username: String,
+            password_hash: String,
+        }
+        let old_account = Account {
+            username: "jdoe".to_string(),
+            password_hash: "$argon2id$old".to_string(),
+        };
+        let new_account = Account {
+            username: "jdoe".to_string(),
+            password_hash: "$argon2id$new".to_string(),
+        };
+
+        let entry = AuditEntry::for_update(
+            "user",
+            new_uuid(),
+            &old_account,
+            &new_account,
+            UserId::new(),
+        );
+
+        let serialized = serde_json::to_string(&entry).unwrap();
+        assert!(!serialized.contains("$argon2id"));
+        assert_eq!(
+            entry.new_value().unwrap()["password_hash"],
+            REDACTED_AUDIT_VALUE
+        );
+        assert_eq!(
+            entry.changes(),
+            [FieldChange {
+                field: "password_hash".to_string(),
+                old_value: REDACTED_AUDIT_VALUE.into(),
+                new_value: REDACTED_AUDIT_VALUE.into(),
+            }]
+        );
+    }
+
     #[test]
     fn test_audit_entry_for_delete() {
         let actor_id = UserId::new();
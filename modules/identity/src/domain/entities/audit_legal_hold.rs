@@ -0,0 +1,104 @@
+// AuditLegalHold entity - keeps a record's audit trail out of archival
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::domain::value_objects::UserId;
+
+/// Legal hold on the audit trail of a single record
+///
+/// While a hold exists, no audit entry for `(entity_type, entity_id)` is
+/// moved out of the hot audit table, whatever its age. Releasing the hold
+/// makes the entries eligible for archival again on the next run.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AuditLegalHold {
+    entity_type: String,
+    entity_id: Uuid,
+    reason: String,
+    placed_by_id: UserId,
+    placed_at: DateTime<Utc>,
+}
+
+impl AuditLegalHold {
+    /// Places a new hold, timestamped now
+    pub fn create(
+        entity_type: String,
+        entity_id: Uuid,
+        reason: String,
+        placed_by_id: UserId,
+    ) -> Self {
+        Self {
+            entity_type,
+            entity_id,
+            reason,
+            placed_by_id,
+            placed_at: Utc::now(),
+        }
+    }
+
+    /// Reconstitutes a hold from persistence
+    pub fn reconstitute(
+        entity_type: String,
+        entity_id: Uuid,
+        reason: String,
+        placed_by_id: UserId,
+        placed_at: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            entity_type,
+            entity_id,
+            reason,
+            placed_by_id,
+            placed_at,
+        }
+    }
+
+    /// Returns true if this hold covers the given audit entry's record
+    pub fn covers(&self, entity_type: &str, entity_id: Uuid) -> bool {
+        self.entity_type == entity_type && self.entity_id == entity_id
+    }
+
+    // Getters
+
+    pub fn entity_type(&self) -> &str {
+        &self.entity_type
+    }
+
+    pub fn entity_id(&self) -> Uuid {
+        self.entity_id
+    }
+
+    pub fn reason(&self) -> &str {
+        &self.reason
+    }
+
+    pub fn placed_by_id(&self) -> &UserId {
+        &self.placed_by_id
+    }
+
+    pub fn placed_at(&self) -> DateTime<Utc> {
+        self.placed_at
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use uuid::{NoContext, Timestamp};
+
+    #[test]
+    fn test_legal_hold_covers_only_its_record() {
+        let entity_id = Uuid::new_v7(Timestamp::now(NoContext));
+        let hold = AuditLegalHold::create(
+            "user".to_string(),
+            entity_id,
+            "Litigation 2026-114".to_string(),
+            UserId::new(),
+        );
+
+        assert!(hold.covers("user", entity_id));
+        assert!(!hold.covers("role", entity_id));
+        assert!(!hold.covers("user", Uuid::new_v7(Timestamp::now(NoContext))));
+    }
+}
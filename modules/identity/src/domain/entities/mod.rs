@@ -1,13 +1,23 @@
 // Domain entities
 
 mod audit;
+mod audit_legal_hold;
+mod password_reset_token;
 mod permission;
+mod refresh_token;
 mod role;
 mod store;
+mod store_settings;
 mod user;
+mod user_mfa;
 
 pub use audit::*;
+pub use audit_legal_hold::*;
+pub use password_reset_token::*;
 pub use permission::*;
+pub use refresh_token::*;
 pub use role::*;
 pub use store::*;
+pub use store_settings::*;
 pub use user::*;
+pub use user_mfa::*;
@@ -0,0 +1,147 @@
+// PasswordResetToken entity - single-use, time-limited password reset grant
+
+use chrono::{DateTime, Duration, Utc};
+use rand::RngCore;
+use rand::rngs::OsRng;
+use sha2::{Digest, Sha256};
+use uuid::{NoContext, Timestamp, Uuid};
+
+use crate::domain::value_objects::UserId;
+
+/// How long an emailed reset link stays valid
+pub const PASSWORD_RESET_TOKEN_LIFETIME_MINUTES: i64 = 60;
+
+/// Random bytes in a raw reset token (hex encoded, 64 characters)
+const RESET_TOKEN_BYTES: usize = 32;
+
+/// A password reset token as stored by the server
+///
+/// Only the SHA-256 hash of the token is kept; the raw value is handed out
+/// once, to be emailed to the user. A token can be used once and stops
+/// working when it expires or when a newer reset is requested.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PasswordResetToken {
+    id: Uuid,
+    user_id: UserId,
+    token_hash: String,
+    expires_at: DateTime<Utc>,
+    used_at: Option<DateTime<Utc>>,
+    created_at: DateTime<Utc>,
+}
+
+impl PasswordResetToken {
+    /// Issues a new token for the user
+    ///
+    /// Returns the token together with its raw value; only the hash is kept.
+    pub fn issue(user_id: UserId, lifetime: Duration) -> (Self, String) {
+        let mut bytes = [0u8; RESET_TOKEN_BYTES];
+        OsRng.fill_bytes(&mut bytes);
+        let raw = hex::encode(bytes);
+
+        let now = Utc::now();
+        let token = Self {
+            id: Uuid::new_v7(Timestamp::now(NoContext)),
+            user_id,
+            token_hash: Self::hash(&raw),
+            expires_at: now + lifetime,
+            used_at: None,
+            created_at: now,
+        };
+        (token, raw)
+    }
+
+    /// Reconstitutes a token from persistence
+    pub fn reconstitute(
+        id: Uuid,
+        user_id: UserId,
+        token_hash: String,
+        expires_at: DateTime<Utc>,
+        used_at: Option<DateTime<Utc>>,
+        created_at: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            id,
+            user_id,
+            token_hash,
+            expires_at,
+            used_at,
+            created_at,
+        }
+    }
+
+    /// Hex SHA-256 of a raw token, as stored and looked up
+    pub fn hash(raw: &str) -> String {
+        hex::encode(Sha256::digest(raw.trim().as_bytes()))
+    }
+
+    /// Marks the token as used (or invalidated); keeps the first time
+    pub fn mark_used(&mut self, now: DateTime<Utc>) {
+        self.used_at.get_or_insert(now);
+    }
+
+    pub fn is_used(&self) -> bool {
+        self.used_at.is_some()
+    }
+
+    pub fn is_expired(&self, now: DateTime<Utc>) -> bool {
+        self.expires_at <= now
+    }
+
+    // Getters
+
+    pub fn id(&self) -> Uuid {
+        self.id
+    }
+
+    pub fn user_id(&self) -> UserId {
+        self.user_id
+    }
+
+    pub fn token_hash(&self) -> &str {
+        &self.token_hash
+    }
+
+    pub fn expires_at(&self) -> DateTime<Utc> {
+        self.expires_at
+    }
+
+    pub fn used_at(&self) -> Option<DateTime<Utc>> {
+        self.used_at
+    }
+
+    pub fn created_at(&self) -> DateTime<Utc> {
+        self.created_at
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_issue_keeps_only_the_hash() {
+        let (token, raw) = PasswordResetToken::issue(UserId::new(), Duration::minutes(60));
+
+        assert_eq!(raw.len(), RESET_TOKEN_BYTES * 2);
+        assert_ne!(token.token_hash(), raw);
+        assert_eq!(token.token_hash(), PasswordResetToken::hash(&raw));
+        assert!(!token.is_used());
+    }
+
+    #[test]
+    fn test_issued_tokens_are_unique() {
+        let user_id = UserId::new();
+        let (_, a) = PasswordResetToken::issue(user_id, Duration::minutes(60));
+        let (_, b) = PasswordResetToken::issue(user_id, Duration::minutes(60));
+
+        assert_ne!(a, b);
+    }
+
+    #[test]
+    fn test_is_expired() {
+        let (token, _) = PasswordResetToken::issue(UserId::new(), Duration::minutes(5));
+
+        assert!(!token.is_expired(Utc::now()));
+        assert!(token.is_expired(Utc::now() + Duration::minutes(5)));
+    }
+}
@@ -0,0 +1,166 @@
+// RefreshToken entity - one issued refresh token in a rotation chain
+
+use chrono::{DateTime, Duration, Utc};
+use uuid::{NoContext, Timestamp, Uuid};
+
+use crate::domain::value_objects::UserId;
+
+/// A refresh token as tracked by the server
+///
+/// Every login starts a new family; each refresh rotates the presented
+/// token (marking it used and pointing at its replacement) and issues the
+/// next token of the same family. A token can be used once: presenting a
+/// rotated token again means it leaked, and the whole family is revoked.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RefreshToken {
+    id: Uuid,
+    user_id: UserId,
+    family_id: Uuid,
+    expires_at: DateTime<Utc>,
+    rotated_at: Option<DateTime<Utc>>,
+    replaced_by: Option<Uuid>,
+    revoked_at: Option<DateTime<Utc>>,
+    created_at: DateTime<Utc>,
+}
+
+impl RefreshToken {
+    /// Issues the first token of a new family, at login
+    pub fn issue(user_id: UserId, lifetime: Duration) -> Self {
+        Self::in_family(user_id, Uuid::new_v7(Timestamp::now(NoContext)), lifetime)
+    }
+
+    /// Issues the token that replaces this one, in the same family
+    pub fn successor(&self, lifetime: Duration) -> Self {
+        Self::in_family(self.user_id, self.family_id, lifetime)
+    }
+
+    fn in_family(user_id: UserId, family_id: Uuid, lifetime: Duration) -> Self {
+        let now = Utc::now();
+        Self {
+            id: Uuid::new_v7(Timestamp::now(NoContext)),
+            user_id,
+            family_id,
+            expires_at: now + lifetime,
+            rotated_at: None,
+            replaced_by: None,
+            revoked_at: None,
+            created_at: now,
+        }
+    }
+
+    /// Reconstitutes a token from persistence
+    #[allow(clippy::too_many_arguments)]
+    pub fn reconstitute(
+        id: Uuid,
+        user_id: UserId,
+        family_id: Uuid,
+        expires_at: DateTime<Utc>,
+        rotated_at: Option<DateTime<Utc>>,
+        replaced_by: Option<Uuid>,
+        revoked_at: Option<DateTime<Utc>>,
+        created_at: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            id,
+            user_id,
+            family_id,
+            expires_at,
+            rotated_at,
+            replaced_by,
+            revoked_at,
+            created_at,
+        }
+    }
+
+    /// Marks the token as exchanged for `successor_id`
+    pub fn rotate(&mut self, successor_id: Uuid, now: DateTime<Utc>) {
+        self.rotated_at = Some(now);
+        self.replaced_by = Some(successor_id);
+    }
+
+    /// Revokes the token; revoking twice keeps the first revocation time
+    pub fn revoke(&mut self, now: DateTime<Utc>) {
+        self.revoked_at.get_or_insert(now);
+    }
+
+    /// Returns true once the token has been exchanged for its successor
+    pub fn is_rotated(&self) -> bool {
+        self.rotated_at.is_some()
+    }
+
+    pub fn is_revoked(&self) -> bool {
+        self.revoked_at.is_some()
+    }
+
+    pub fn is_expired(&self, now: DateTime<Utc>) -> bool {
+        self.expires_at <= now
+    }
+
+    // Getters
+
+    pub fn id(&self) -> Uuid {
+        self.id
+    }
+
+    pub fn user_id(&self) -> UserId {
+        self.user_id
+    }
+
+    pub fn family_id(&self) -> Uuid {
+        self.family_id
+    }
+
+    pub fn expires_at(&self) -> DateTime<Utc> {
+        self.expires_at
+    }
+
+    pub fn rotated_at(&self) -> Option<DateTime<Utc>> {
+        self.rotated_at
+    }
+
+    pub fn replaced_by(&self) -> Option<Uuid> {
+        self.replaced_by
+    }
+
+    pub fn revoked_at(&self) -> Option<DateTime<Utc>> {
+        self.revoked_at
+    }
+
+    pub fn created_at(&self) -> DateTime<Utc> {
+        self.created_at
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_successor_stays_in_family() {
+        let first = RefreshToken::issue(UserId::new(), Duration::days(7));
+        let second = first.successor(Duration::days(7));
+
+        assert_eq!(second.family_id(), first.family_id());
+        assert_eq!(second.user_id(), first.user_id());
+        assert_ne!(second.id(), first.id());
+        assert!(!second.is_rotated());
+        assert!(!second.is_revoked());
+    }
+
+    #[test]
+    fn test_each_login_starts_a_new_family() {
+        let user_id = UserId::new();
+        let a = RefreshToken::issue(user_id, Duration::days(7));
+        let b = RefreshToken::issue(user_id, Duration::days(7));
+
+        assert_ne!(a.family_id(), b.family_id());
+    }
+
+    #[test]
+    fn test_is_expired() {
+        let token = RefreshToken::issue(UserId::new(), Duration::minutes(5));
+
+        assert!(!token.is_expired(Utc::now()));
+        assert!(token.is_expired(Utc::now() + Duration::minutes(5)));
+    }
+}
@@ -0,0 +1,168 @@
+// StoreSettings entity - per-store policy flags read by the other modules
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::domain::value_objects::{StoreId, UserId};
+
+/// Days a pickup order is held before it is cancelled as a no-show
+pub const DEFAULT_PICKUP_HOLD_DAYS: i32 = 7;
+
+/// Longest hold a store may configure for pickup orders
+pub const MAX_PICKUP_HOLD_DAYS: i32 = 90;
+
+/// Per-store behavior switches
+///
+/// Stores without a saved row behave as `StoreSettings::defaults`, which keep
+/// the behavior the system had before settings existed. Settings are read from
+/// the database on every request, so a change takes effect immediately.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StoreSettings {
+    store_id: StoreId,
+    loyalty_enabled: bool,
+    auto_markdowns_enabled: bool,
+    allow_negative_stock: bool,
+    pickup_hold_days: i32,
+    transit_owned_by_destination: bool,
+    updated_by_id: Option<UserId>,
+    updated_at: DateTime<Utc>,
+}
+
+impl StoreSettings {
+    /// Creates a StoreSettings with all fields specified
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        store_id: StoreId,
+        loyalty_enabled: bool,
+        auto_markdowns_enabled: bool,
+        allow_negative_stock: bool,
+        pickup_hold_days: i32,
+        transit_owned_by_destination: bool,
+        updated_by_id: Option<UserId>,
+        updated_at: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            store_id,
+            loyalty_enabled,
+            auto_markdowns_enabled,
+            allow_negative_stock,
+            pickup_hold_days,
+            transit_owned_by_destination,
+            updated_by_id,
+            updated_at,
+        }
+    }
+
+    /// Settings for a store that has never been configured
+    pub fn defaults(store_id: StoreId) -> Self {
+        Self {
+            store_id,
+            loyalty_enabled: true,
+            auto_markdowns_enabled: true,
+            allow_negative_stock: false,
+            pickup_hold_days: DEFAULT_PICKUP_HOLD_DAYS,
+            transit_owned_by_destination: false,
+            updated_by_id: None,
+            updated_at: Utc::now(),
+        }
+    }
+
+    // Getters
+
+    pub fn store_id(&self) -> StoreId {
+        self.store_id
+    }
+
+    /// Whether customers earn and redeem loyalty points in this store
+    pub fn loyalty_enabled(&self) -> bool {
+        self.loyalty_enabled
+    }
+
+    /// Whether the scheduled markdown job evaluates this store's rules
+    pub fn auto_markdowns_enabled(&self) -> bool {
+        self.auto_markdowns_enabled
+    }
+
+    /// Whether sales and adjustments may take stock below zero
+    pub fn allow_negative_stock(&self) -> bool {
+        self.allow_negative_stock
+    }
+
+    /// Days a pickup order stays ready before it is cancelled as a no-show
+    pub fn pickup_hold_days(&self) -> i32 {
+        self.pickup_hold_days
+    }
+
+    /// Whether stock shipped from this store counts toward the destination
+    /// store while in transit, instead of staying with this store
+    pub fn transit_owned_by_destination(&self) -> bool {
+        self.transit_owned_by_destination
+    }
+
+    pub fn updated_by_id(&self) -> Option<UserId> {
+        self.updated_by_id
+    }
+
+    pub fn updated_at(&self) -> DateTime<Utc> {
+        self.updated_at
+    }
+
+    // Setters / Mutators
+
+    pub fn set_loyalty_enabled(&mut self, enabled: bool) {
+        self.loyalty_enabled = enabled;
+    }
+
+    pub fn set_auto_markdowns_enabled(&mut self, enabled: bool) {
+        self.auto_markdowns_enabled = enabled;
+    }
+
+    pub fn set_allow_negative_stock(&mut self, allow: bool) {
+        self.allow_negative_stock = allow;
+    }
+
+    pub fn set_pickup_hold_days(&mut self, days: i32) {
+        self.pickup_hold_days = days;
+    }
+
+    pub fn set_transit_owned_by_destination(&mut self, by_destination: bool) {
+        self.transit_owned_by_destination = by_destination;
+    }
+
+    /// Records who changed the settings and when
+    pub fn touch(&mut self, actor_id: UserId) {
+        self.updated_by_id = Some(actor_id);
+        self.updated_at = Utc::now();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_defaults_keep_existing_behavior() {
+        let store_id = StoreId::new();
+        let settings = StoreSettings::defaults(store_id);
+
+        assert_eq!(settings.store_id(), store_id);
+        assert!(settings.loyalty_enabled());
+        assert!(settings.auto_markdowns_enabled());
+        assert!(!settings.allow_negative_stock());
+        assert_eq!(settings.pickup_hold_days(), DEFAULT_PICKUP_HOLD_DAYS);
+        assert!(!settings.transit_owned_by_destination());
+        assert!(settings.updated_by_id().is_none());
+    }
+
+    #[test]
+    fn test_touch_records_actor() {
+        let mut settings = StoreSettings::defaults(StoreId::new());
+        let actor_id = UserId::new();
+
+        settings.set_allow_negative_stock(true);
+        settings.touch(actor_id);
+
+        assert!(settings.allow_negative_stock());
+        assert_eq!(settings.updated_by_id(), Some(actor_id));
+    }
+}
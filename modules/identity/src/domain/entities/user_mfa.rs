@@ -0,0 +1,311 @@
+// UserMfa entity - a user's TOTP second factor and recovery codes
+
+use chrono::{DateTime, Utc};
+use rand::Rng;
+use rand::rngs::OsRng;
+use sha2::{Digest, Sha256};
+
+use crate::domain::auth::{AuthError, MfaPolicy, TotpSecret};
+use crate::domain::value_objects::UserId;
+
+/// Recovery codes handed out when MFA is enabled
+pub const RECOVERY_CODE_COUNT: usize = 10;
+
+/// Characters of a recovery code, without look-alikes (0/o, 1/l/i)
+const RECOVERY_CODE_ALPHABET: &[u8] = b"abcdefghjkmnpqrstuvwxyz23456789";
+
+/// Second factor presented to complete a login or re-authenticate
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MfaFactor {
+    /// Current code from the authenticator app
+    Totp(String),
+    /// One of the single-use recovery codes
+    RecoveryCode(String),
+}
+
+/// TOTP enrollment of a user
+///
+/// Starts pending at enrollment and is enabled once the user proves the
+/// authenticator works by entering a code. Only enabled enrollments are
+/// enforced at login. Recovery codes are kept as SHA-256 hashes and each
+/// one is removed when used. Consecutive failed attempts lock the second
+/// factor for the policy's lockout period.
+#[derive(Debug, Clone)]
+pub struct UserMfa {
+    user_id: UserId,
+    secret: TotpSecret,
+    enabled_at: Option<DateTime<Utc>>,
+    recovery_code_hashes: Vec<String>,
+    failed_attempts: i32,
+    locked_until: Option<DateTime<Utc>>,
+    last_used_step: Option<i64>,
+    created_at: DateTime<Utc>,
+    updated_at: DateTime<Utc>,
+}
+
+impl UserMfa {
+    /// Starts a pending enrollment with a fresh secret
+    pub fn enroll(user_id: UserId) -> Self {
+        let now = Utc::now();
+        Self {
+            user_id,
+            secret: TotpSecret::generate(),
+            enabled_at: None,
+            recovery_code_hashes: Vec::new(),
+            failed_attempts: 0,
+            locked_until: None,
+            last_used_step: None,
+            created_at: now,
+            updated_at: now,
+        }
+    }
+
+    /// Reconstitutes an enrollment from persistence
+    #[allow(clippy::too_many_arguments)]
+    pub fn reconstitute(
+        user_id: UserId,
+        secret: TotpSecret,
+        enabled_at: Option<DateTime<Utc>>,
+        recovery_code_hashes: Vec<String>,
+        failed_attempts: i32,
+        locked_until: Option<DateTime<Utc>>,
+        last_used_step: Option<i64>,
+        created_at: DateTime<Utc>,
+        updated_at: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            user_id,
+            secret,
+            enabled_at,
+            recovery_code_hashes,
+            failed_attempts,
+            locked_until,
+            last_used_step,
+            created_at,
+            updated_at,
+        }
+    }
+
+    /// Enables the enrollment and replaces the recovery codes.
+    ///
+    /// Returns the new plain recovery codes; only their hashes are kept.
+    pub fn enable(&mut self) -> Vec<String> {
+        let codes: Vec<String> = (0..RECOVERY_CODE_COUNT)
+            .map(|_| generate_recovery_code())
+            .collect();
+        self.recovery_code_hashes = codes.iter().map(|c| hash_recovery_code(c)).collect();
+        self.enabled_at = Some(Utc::now());
+        self.touch();
+        codes
+    }
+
+    /// Checks a second factor, recording the attempt.
+    ///
+    /// A successful TOTP code cannot be used again; a successful recovery
+    /// code is consumed. A failure counts towards the lockout, and reaching
+    /// the policy's limit locks the second factor. The caller must persist
+    /// the enrollment whatever the outcome.
+    ///
+    /// # Errors
+    /// * `AuthError::MfaLocked` - While locked, without checking the factor
+    /// * `AuthError::InvalidMfaCode` - If the factor does not match
+    pub fn verify(
+        &mut self,
+        factor: &MfaFactor,
+        policy: &MfaPolicy,
+        now: DateTime<Utc>,
+    ) -> Result<(), AuthError> {
+        if self.is_locked(now) {
+            return Err(AuthError::MfaLocked);
+        }
+
+        let matched = match factor {
+            MfaFactor::Totp(code) => match self.secret.verify(code, now, self.last_used_step) {
+                Some(step) => {
+                    self.last_used_step = Some(step);
+                    true
+                }
+                None => false,
+            },
+            MfaFactor::RecoveryCode(code) => {
+                let hash = hash_recovery_code(code);
+                let before = self.recovery_code_hashes.len();
+                self.recovery_code_hashes.retain(|h| *h != hash);
+                self.recovery_code_hashes.len() < before
+            }
+        };
+        self.touch();
+
+        if matched {
+            self.failed_attempts = 0;
+            self.locked_until = None;
+            return Ok(());
+        }
+
+        self.failed_attempts += 1;
+        if self.failed_attempts >= policy.max_failed_attempts() {
+            self.failed_attempts = 0;
+            self.locked_until = Some(now + policy.lockout());
+        }
+        Err(AuthError::InvalidMfaCode)
+    }
+
+    /// Returns true while failed attempts keep the second factor locked
+    pub fn is_locked(&self, now: DateTime<Utc>) -> bool {
+        self.locked_until.is_some_and(|until| until > now)
+    }
+
+    fn touch(&mut self) {
+        self.updated_at = Utc::now();
+    }
+
+    // =========================================================================
+    // Getters
+    // =========================================================================
+
+    pub fn user_id(&self) -> UserId {
+        self.user_id
+    }
+
+    pub fn secret(&self) -> &TotpSecret {
+        &self.secret
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.enabled_at.is_some()
+    }
+
+    pub fn enabled_at(&self) -> Option<DateTime<Utc>> {
+        self.enabled_at
+    }
+
+    pub fn recovery_code_hashes(&self) -> &[String] {
+        &self.recovery_code_hashes
+    }
+
+    pub fn failed_attempts(&self) -> i32 {
+        self.failed_attempts
+    }
+
+    pub fn locked_until(&self) -> Option<DateTime<Utc>> {
+        self.locked_until
+    }
+
+    pub fn last_used_step(&self) -> Option<i64> {
+        self.last_used_step
+    }
+
+    pub fn created_at(&self) -> DateTime<Utc> {
+        self.created_at
+    }
+
+    pub fn updated_at(&self) -> DateTime<Utc> {
+        self.updated_at
+    }
+}
+
+/// Random recovery code formatted as `xxxxx-xxxxx`
+fn generate_recovery_code() -> String {
+    let mut rng = OsRng;
+    let chars: String = (0..10)
+        .map(|_| RECOVERY_CODE_ALPHABET[rng.gen_range(0..RECOVERY_CODE_ALPHABET.len())] as char)
+        .collect();
+    format!("{}-{}", &chars[..5], &chars[5..])
+}
+
+/// Hex SHA-256 of a recovery code, ignoring case, dashes and spaces
+fn hash_recovery_code(code: &str) -> String {
+    let normalized: String = code
+        .chars()
+        .filter(|c| !c.is_whitespace() && *c != '-')
+        .flat_map(char::to_lowercase)
+        .collect();
+    hex::encode(Sha256::digest(normalized.as_bytes()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Duration;
+
+    fn enabled_mfa() -> (UserMfa, Vec<String>) {
+        let mut mfa = UserMfa::enroll(UserId::new());
+        let codes = mfa.enable();
+        (mfa, codes)
+    }
+
+    #[test]
+    fn test_enroll_starts_pending() {
+        let mfa = UserMfa::enroll(UserId::new());
+        assert!(!mfa.is_enabled());
+        assert!(mfa.recovery_code_hashes().is_empty());
+    }
+
+    #[test]
+    fn test_enable_issues_hashed_recovery_codes() {
+        let (mfa, codes) = enabled_mfa();
+        assert!(mfa.is_enabled());
+        assert_eq!(codes.len(), RECOVERY_CODE_COUNT);
+        assert!(
+            codes
+                .iter()
+                .all(|c| c.len() == 11 && c.as_bytes()[5] == b'-')
+        );
+        assert!(!mfa.recovery_code_hashes().contains(&codes[0]));
+    }
+
+    #[test]
+    fn test_totp_code_cannot_be_replayed() {
+        let (mut mfa, _) = enabled_mfa();
+        let policy = MfaPolicy::default();
+        let now = Utc::now();
+        let code = mfa.secret().code_at(TotpSecret::step_at(now));
+
+        assert!(
+            mfa.verify(&MfaFactor::Totp(code.clone()), &policy, now)
+                .is_ok()
+        );
+        assert_eq!(
+            mfa.verify(&MfaFactor::Totp(code), &policy, now),
+            Err(AuthError::InvalidMfaCode)
+        );
+    }
+
+    #[test]
+    fn test_recovery_code_is_single_use() {
+        let (mut mfa, codes) = enabled_mfa();
+        let policy = MfaPolicy::default();
+        let now = Utc::now();
+        let code = codes[3].to_uppercase().replace('-', " ");
+
+        assert!(
+            mfa.verify(&MfaFactor::RecoveryCode(code.clone()), &policy, now)
+                .is_ok()
+        );
+        assert_eq!(mfa.recovery_code_hashes().len(), RECOVERY_CODE_COUNT - 1);
+        assert_eq!(
+            mfa.verify(&MfaFactor::RecoveryCode(code), &policy, now),
+            Err(AuthError::InvalidMfaCode)
+        );
+    }
+
+    #[test]
+    fn test_failed_attempts_lock_the_second_factor() {
+        let (mut mfa, codes) = enabled_mfa();
+        let policy = MfaPolicy::new("POS", 3, Duration::minutes(10));
+        let now = Utc::now();
+        let wrong = MfaFactor::Totp("000000".to_string());
+
+        for _ in 0..3 {
+            let _ = mfa.verify(&wrong, &policy, now);
+        }
+        assert!(mfa.is_locked(now));
+
+        // Even a valid factor is refused until the lockout ends
+        let valid = MfaFactor::RecoveryCode(codes[0].clone());
+        assert_eq!(mfa.verify(&valid, &policy, now), Err(AuthError::MfaLocked));
+        let later = now + Duration::minutes(11);
+        assert!(mfa.verify(&valid, &policy, later).is_ok());
+        assert_eq!(mfa.failed_attempts(), 0);
+    }
+}
@@ -0,0 +1,43 @@
+// AuditArchiveRepository trait - interface for audit retention and legal holds
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use crate::domain::entities::{AuditEntry, AuditLegalHold};
+use crate::error::IdentityError;
+
+/// Repository trait for moving old audit entries out of the hot table
+///
+/// Archived entries keep their id and timestamps and stay queryable through
+/// `find_archived_by_entity`; the hot table only holds the retention window.
+#[async_trait]
+pub trait AuditArchiveRepository: Send + Sync {
+    /// Moves up to `limit` entries created before `cutoff` to the archive
+    ///
+    /// Entries of records under a legal hold are skipped. The copy and the
+    /// delete happen atomically, so an entry is never lost or duplicated.
+    /// Returns how many entries were moved.
+    async fn archive_before(&self, cutoff: DateTime<Utc>, limit: i64)
+    -> Result<u64, IdentityError>;
+
+    /// Finds archived audit entries for a specific entity (newest first)
+    async fn find_archived_by_entity(
+        &self,
+        entity_type: &str,
+        entity_id: Uuid,
+    ) -> Result<Vec<AuditEntry>, IdentityError>;
+
+    /// Places a legal hold, replacing the reason of an existing one
+    async fn place_legal_hold(&self, hold: &AuditLegalHold) -> Result<(), IdentityError>;
+
+    /// Releases the hold on a record, returning false if there was none
+    async fn release_legal_hold(
+        &self,
+        entity_type: &str,
+        entity_id: Uuid,
+    ) -> Result<bool, IdentityError>;
+
+    /// Lists all active legal holds (newest first)
+    async fn find_legal_holds(&self) -> Result<Vec<AuditLegalHold>, IdentityError>;
+}
@@ -4,9 +4,25 @@ use async_trait::async_trait;
 use chrono::{DateTime, Utc};
 use uuid::Uuid;
 
-use crate::domain::entities::AuditEntry;
+use crate::domain::entities::{AuditAction, AuditEntry};
+use crate::domain::value_objects::UserId;
 use crate::error::IdentityError;
 
+/// Filters for querying the audit trail; `None` fields match everything
+#[derive(Debug, Clone, Default)]
+pub struct AuditEntryFilter {
+    pub actor_id: Option<UserId>,
+    pub entity_type: Option<String>,
+    pub entity_id: Option<Uuid>,
+    pub action: Option<AuditAction>,
+    /// Inclusive lower bound on `created_at`
+    pub from: Option<DateTime<Utc>>,
+    /// Exclusive upper bound on `created_at`
+    pub to: Option<DateTime<Utc>>,
+    /// Also search entries moved to the archive table
+    pub include_archived: bool,
+}
+
 /// Repository trait for AuditEntry persistence
 ///
 /// Defines the contract for storing and querying audit logs.
@@ -37,4 +53,15 @@ pub trait AuditRepository: Send + Sync {
         from: DateTime<Utc>,
         to: DateTime<Utc>,
     ) -> Result<Vec<AuditEntry>, IdentityError>;
+
+    /// Finds one page of audit entries matching the filter
+    /// Returns (entries, total_count)
+    ///
+    /// Entries are ordered chronologically (oldest first), ties broken by id.
+    async fn find_paginated(
+        &self,
+        filter: &AuditEntryFilter,
+        page: i64,
+        page_size: i64,
+    ) -> Result<(Vec<AuditEntry>, i64), IdentityError>;
 }
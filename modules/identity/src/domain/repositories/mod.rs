@@ -1,13 +1,21 @@
 // Repository traits - interfaces for data persistence
 
+mod audit_archive_repository;
 mod audit_repository;
+mod password_reset_token_repository;
 mod permission_repository;
+mod refresh_token_repository;
 mod role_repository;
 mod store_repository;
+mod user_mfa_repository;
 mod user_repository;
 
+pub use audit_archive_repository::*;
 pub use audit_repository::*;
+pub use password_reset_token_repository::*;
 pub use permission_repository::*;
+pub use refresh_token_repository::*;
 pub use role_repository::*;
 pub use store_repository::*;
+pub use user_mfa_repository::*;
 pub use user_repository::*;
@@ -0,0 +1,36 @@
+// PasswordResetTokenRepository trait - interface for password reset token persistence
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use crate::domain::entities::PasswordResetToken;
+use crate::domain::value_objects::UserId;
+use crate::error::IdentityError;
+
+/// Repository trait for password reset tokens
+#[async_trait]
+pub trait PasswordResetTokenRepository: Send + Sync {
+    /// Inserts a newly issued token
+    async fn save(&self, token: &PasswordResetToken) -> Result<(), IdentityError>;
+
+    /// Finds a token by the hash of its raw value
+    async fn find_by_hash(
+        &self,
+        token_hash: &str,
+    ) -> Result<Option<PasswordResetToken>, IdentityError>;
+
+    /// Marks a token as used
+    ///
+    /// Returns false if the token was already used - e.g. by a concurrent
+    /// reset with the same link.
+    async fn mark_used(&self, id: Uuid, now: DateTime<Utc>) -> Result<bool, IdentityError>;
+
+    /// Invalidates every unused token of a user, returning how many were
+    /// invalidated
+    async fn invalidate_all_for_user(
+        &self,
+        user_id: UserId,
+        now: DateTime<Utc>,
+    ) -> Result<u64, IdentityError>;
+}
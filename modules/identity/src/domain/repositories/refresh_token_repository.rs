@@ -0,0 +1,45 @@
+// RefreshTokenRepository trait - interface for refresh token persistence
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use crate::domain::entities::RefreshToken;
+use crate::domain::value_objects::UserId;
+use crate::error::IdentityError;
+
+/// Repository trait for issued refresh tokens
+#[async_trait]
+pub trait RefreshTokenRepository: Send + Sync {
+    /// Inserts a newly issued token
+    async fn save(&self, token: &RefreshToken) -> Result<(), IdentityError>;
+
+    /// Finds a token by its id (the `jti` of the JWT)
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<RefreshToken>, IdentityError>;
+
+    /// Marks `current` as rotated into `successor` and inserts `successor`,
+    /// atomically
+    ///
+    /// Returns false, inserting nothing, if `current` was already rotated or
+    /// revoked - e.g. by a concurrent refresh with the same token.
+    async fn rotate(
+        &self,
+        current: Uuid,
+        successor: &RefreshToken,
+        now: DateTime<Utc>,
+    ) -> Result<bool, IdentityError>;
+
+    /// Revokes every live token of a family, returning how many were revoked
+    async fn revoke_family(
+        &self,
+        family_id: Uuid,
+        now: DateTime<Utc>,
+    ) -> Result<u64, IdentityError>;
+
+    /// Revokes every live token of a user, returning how many were revoked
+    async fn revoke_all_for_user(
+        &self,
+        user_id: UserId,
+        now: DateTime<Utc>,
+    ) -> Result<u64, IdentityError>;
+}
@@ -2,7 +2,7 @@
 
 use async_trait::async_trait;
 
-use crate::domain::entities::{Store, User};
+use crate::domain::entities::{Store, StoreSettings, User};
 use crate::domain::value_objects::StoreId;
 use crate::error::IdentityError;
 
@@ -43,6 +43,8 @@ pub trait StoreRepository: Send + Sync {
     /// Returns (stores, total_count)
     ///
     /// If `user_store_ids` is `Some`, results are restricted to those store IDs.
+    /// If `after_id` is `Some`, only stores sorted after that one by name are
+    /// returned and the page offset is ignored.
     async fn find_paginated(
         &self,
         is_active: Option<bool>,
@@ -50,5 +52,20 @@ pub trait StoreRepository: Send + Sync {
         page: i64,
         page_size: i64,
         user_store_ids: Option<&[uuid::Uuid]>,
+        after_id: Option<StoreId>,
     ) -> Result<(Vec<Store>, i64), IdentityError>;
+
+    /// Finds the saved settings of a store
+    ///
+    /// Returns `None` if the store's settings were never changed.
+    async fn find_settings(
+        &self,
+        store_id: StoreId,
+    ) -> Result<Option<StoreSettings>, IdentityError>;
+
+    /// Returns the saved settings of every store
+    async fn find_all_settings(&self) -> Result<Vec<StoreSettings>, IdentityError>;
+
+    /// Inserts or replaces a store's settings
+    async fn save_settings(&self, settings: &StoreSettings) -> Result<(), IdentityError>;
 }
@@ -0,0 +1,23 @@
+// UserMfaRepository trait - interface for multi-factor enrollment persistence
+
+use async_trait::async_trait;
+
+use crate::domain::entities::UserMfa;
+use crate::domain::value_objects::UserId;
+use crate::error::IdentityError;
+
+/// Repository trait for users' TOTP enrollments
+///
+/// A user has at most one enrollment. Implementations must store the TOTP
+/// secret encrypted.
+#[async_trait]
+pub trait UserMfaRepository: Send + Sync {
+    /// Finds the enrollment of a user, pending or enabled
+    async fn find_by_user_id(&self, user_id: UserId) -> Result<Option<UserMfa>, IdentityError>;
+
+    /// Inserts the enrollment or replaces the user's existing one
+    async fn save(&self, mfa: &UserMfa) -> Result<(), IdentityError>;
+
+    /// Removes the user's enrollment, turning MFA off
+    async fn delete(&self, user_id: UserId) -> Result<(), IdentityError>;
+}
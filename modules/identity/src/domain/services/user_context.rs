@@ -18,6 +18,19 @@ pub enum PermissionCheckResult {
     Denied { missing: Vec<String> },
 }
 
+/// Stores a store-scoped query may read, resolved by `UserContext::store_scope`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum StoreScope {
+    /// No restriction - a super admin listing every store
+    All,
+    /// Only these stores: the requested one, or every store assigned to the
+    /// user when none was requested
+    Stores(Vec<Uuid>),
+    /// The requested store is not assigned to the user. The use case decides
+    /// whether to reject the request or answer as if the store had no data.
+    Denied(Uuid),
+}
+
 /// Immutable context for the current user session.
 ///
 /// Contains the user_id, store_id, and a deduplicated set of permissions
@@ -219,6 +232,41 @@ impl UserContext {
             PermissionCheckResult::Denied { missing }
         }
     }
+
+    /// Authorizes an operation requiring all of `permissions`.
+    ///
+    /// Same as `check_permissions`, except that a super admin is granted
+    /// everything - the rule the gateway's permission guards apply. Use
+    /// cases that mutate sensitive data call this themselves, so they stay
+    /// protected when invoked without going through the gateway.
+    pub fn authorize(&self, permissions: &[&str]) -> PermissionCheckResult {
+        if self.is_super_admin {
+            return PermissionCheckResult::Granted;
+        }
+        self.check_permissions(permissions)
+    }
+
+    /// Returns true if the user may read or act on the given store's data.
+    ///
+    /// Super admins can access every store; everyone else only the stores
+    /// assigned to them.
+    pub fn can_access_store(&self, store_id: Uuid) -> bool {
+        self.is_super_admin || self.accessible_store_ids.contains(&store_id)
+    }
+
+    /// Intersects a query's requested store with the user's assigned stores.
+    ///
+    /// Super admins are never scoped. For everyone else, no requested store
+    /// means all of their assigned stores, and a requested store they are
+    /// not assigned to comes back as `StoreScope::Denied`.
+    pub fn store_scope(&self, requested: Option<Uuid>) -> StoreScope {
+        match requested {
+            Some(store_id) if self.can_access_store(store_id) => StoreScope::Stores(vec![store_id]),
+            Some(store_id) => StoreScope::Denied(store_id),
+            None if self.is_super_admin => StoreScope::All,
+            None => StoreScope::Stores(self.accessible_store_ids.clone()),
+        }
+    }
 }
 
 #[cfg(test)]
@@ -405,4 +453,99 @@ mod tests {
             PermissionCheckResult::Granted => panic!("Expected Denied result"),
         }
     }
+
+    #[test]
+    fn test_authorize_grants_super_admin_without_permissions() {
+        let ctx = UserContext::new(
+            UserId::new(),
+            StoreId::new(),
+            HashSet::new(),
+            vec![],
+            true,
+            None,
+            None,
+        );
+
+        assert_eq!(
+            ctx.authorize(&["purchase_orders:approve"]),
+            PermissionCheckResult::Granted
+        );
+    }
+
+    #[test]
+    fn test_authorize_reports_missing_permissions() {
+        let ctx = create_test_context(&["sales:create"]);
+
+        assert_eq!(
+            ctx.authorize(&["sales:create"]),
+            PermissionCheckResult::Granted
+        );
+        assert_eq!(
+            ctx.authorize(&["sales:create", "sales:approve_credit_note"]),
+            PermissionCheckResult::Denied {
+                missing: vec!["sales:approve_credit_note".to_string()]
+            }
+        );
+    }
+
+    #[test]
+    fn test_can_access_store_only_for_assigned_stores() {
+        let assigned = Uuid::new_v7(Timestamp::now(NoContext));
+        let other = Uuid::new_v7(Timestamp::now(NoContext));
+        let ctx = UserContext::new(
+            UserId::new(),
+            StoreId::new(),
+            HashSet::new(),
+            vec![assigned],
+            false,
+            None,
+            None,
+        );
+
+        assert!(ctx.can_access_store(assigned));
+        assert!(!ctx.can_access_store(other));
+    }
+
+    #[test]
+    fn test_store_scope_intersects_requested_store() {
+        let assigned = Uuid::new_v7(Timestamp::now(NoContext));
+        let other = Uuid::new_v7(Timestamp::now(NoContext));
+        let ctx = UserContext::new(
+            UserId::new(),
+            StoreId::new(),
+            HashSet::new(),
+            vec![assigned],
+            false,
+            None,
+            None,
+        );
+
+        assert_eq!(
+            ctx.store_scope(Some(assigned)),
+            StoreScope::Stores(vec![assigned])
+        );
+        assert_eq!(ctx.store_scope(Some(other)), StoreScope::Denied(other));
+        assert_eq!(ctx.store_scope(None), StoreScope::Stores(vec![assigned]));
+    }
+
+    #[test]
+    fn test_store_scope_bypassed_for_super_admin() {
+        let store = Uuid::new_v7(Timestamp::now(NoContext));
+        let ctx = UserContext::new(
+            UserId::new(),
+            StoreId::new(),
+            HashSet::new(),
+            vec![],
+            true,
+            None,
+            None,
+        );
+
+        assert!(ctx.can_access_store(store));
+        assert_eq!(
+            ctx.store_scope(Some(store)),
+            StoreScope::Stores(vec![store])
+        );
+        assert_eq!(ctx.store_scope(None), StoreScope::All);
+    }
 }
@@ -47,15 +47,30 @@ pub enum IdentityError {
     #[error("Store is inactive: {0}")]
     StoreInactive(Uuid),
 
+    #[error("Invalid store settings: {0}")]
+    InvalidStoreSettings(String),
+
     #[error("User is not a member of store: {0}")]
     UserNotInStore(Uuid),
 
+    #[error("Invalid audit retention: {0}")]
+    InvalidAuditRetention(String),
+
+    #[error("No legal hold on {0} {1}")]
+    LegalHoldNotFound(String, Uuid),
+
+    #[error("Invalid audit query: {0}")]
+    InvalidAuditQuery(String),
+
     #[error("Audit error: {0}")]
     AuditError(String),
 
     #[error("Password hash error: {0}")]
     PasswordHashError(String),
 
+    #[error("MFA secret could not be encrypted or decrypted")]
+    MfaSecretCipher,
+
     #[error("Database error: {0}")]
     Database(#[from] sqlx::Error),
 
@@ -11,8 +11,10 @@ use uuid::Uuid;
 
 use std::collections::HashMap;
 
-use crate::domain::auth::{AuthError, TokenClaims, TokenService};
-use crate::domain::entities::User;
+use crate::domain::auth::{
+    AuthError, MFA_TOKEN_LIFETIME_SECS, RefreshTokenSubject, TokenClaims, TokenService,
+};
+use crate::domain::entities::{RefreshToken, User};
 use crate::domain::value_objects::UserId;
 
 /// JWT implementation of the TokenService trait.
@@ -70,6 +72,13 @@ struct RefreshTokenClaims {
     iat: i64,
     /// Token type marker to distinguish from access tokens
     token_type: String,
+    /// Id of the stored refresh token; absent on MFA tokens and on refresh
+    /// tokens issued before rotation, which are no longer accepted
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    jti: Option<Uuid>,
+    /// Rotation family of the stored refresh token
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    fam: Option<Uuid>,
 }
 
 impl JwtTokenService {
@@ -183,20 +192,19 @@ impl TokenService for JwtTokenService {
         .map_err(|e| AuthError::Internal(format!("Failed to generate access token: {}", e)))
     }
 
-    /// Generates a refresh token for the given user ID.
+    /// Encodes a stored refresh token.
     ///
-    /// The refresh token contains only the user_id and expires after the
-    /// configured refresh_token_duration (default: 7 days).
+    /// The token contains the user_id, the stored token's id (`jti`) and
+    /// family (`fam`), and expires with the stored token.
     ///
-    fn generate_refresh_token(&self, user_id: UserId) -> Result<String, AuthError> {
-        let now = Utc::now();
-        let exp = now + self.refresh_token_duration;
-
+    fn generate_refresh_token(&self, token: &RefreshToken) -> Result<String, AuthError> {
         let claims = RefreshTokenClaims {
-            sub: user_id.into_uuid(),
-            exp: exp.timestamp(),
-            iat: now.timestamp(),
+            sub: token.user_id().into_uuid(),
+            exp: token.expires_at().timestamp(),
+            iat: token.created_at().timestamp(),
             token_type: "refresh".to_string(),
+            jti: Some(token.id()),
+            fam: Some(token.family_id()),
         };
 
         encode(
@@ -207,6 +215,10 @@ impl TokenService for JwtTokenService {
         .map_err(|e| AuthError::Internal(format!("Failed to generate refresh token: {}", e)))
     }
 
+    fn refresh_token_lifetime(&self) -> Duration {
+        self.refresh_token_duration
+    }
+
     /// Validates and decodes an access token.
     ///
     /// Verifies the token signature using HS256, checks expiration, and
@@ -235,11 +247,11 @@ impl TokenService for JwtTokenService {
         Ok(claims)
     }
 
-    /// Validates a refresh token and extracts the user ID.
+    /// Validates a refresh token and extracts the user and stored token ids.
     ///
-    /// Verifies the token signature and expiration, then returns the user_id
-    /// for generating a new access token.
-    fn validate_refresh_token(&self, token: &str) -> Result<UserId, AuthError> {
+    /// Verifies the token signature and expiration. Tokens without a token
+    /// id predate rotation and are rejected.
+    fn validate_refresh_token(&self, token: &str) -> Result<RefreshTokenSubject, AuthError> {
         let validation = Validation::default();
 
         let token_data = decode::<RefreshTokenClaims>(
@@ -253,7 +265,56 @@ impl TokenService for JwtTokenService {
         })?;
 
         // Verify this is actually a refresh token
-        if token_data.claims.token_type != "refresh" {
+        let claims = token_data.claims;
+        if claims.token_type != "refresh" {
+            return Err(AuthError::InvalidToken);
+        }
+        let (Some(token_id), Some(family_id)) = (claims.jti, claims.fam) else {
+            return Err(AuthError::InvalidToken);
+        };
+
+        Ok(RefreshTokenSubject {
+            user_id: UserId::from_uuid(claims.sub),
+            token_id,
+            family_id,
+        })
+    }
+
+    fn generate_mfa_token(&self, user_id: UserId) -> Result<String, AuthError> {
+        let now = Utc::now();
+        let exp = now + Duration::seconds(MFA_TOKEN_LIFETIME_SECS);
+
+        // Same shape as a refresh token; the token_type keeps either from
+        // being accepted as the other.
+        let claims = RefreshTokenClaims {
+            sub: user_id.into_uuid(),
+            exp: exp.timestamp(),
+            iat: now.timestamp(),
+            token_type: "mfa".to_string(),
+            jti: None,
+            fam: None,
+        };
+
+        encode(
+            &Header::default(),
+            &claims,
+            &EncodingKey::from_secret(self.secret.as_bytes()),
+        )
+        .map_err(|e| AuthError::Internal(format!("Failed to generate MFA token: {}", e)))
+    }
+
+    fn validate_mfa_token(&self, token: &str) -> Result<UserId, AuthError> {
+        let token_data = decode::<RefreshTokenClaims>(
+            token,
+            &DecodingKey::from_secret(self.secret.as_bytes()),
+            &Validation::default(),
+        )
+        .map_err(|e| match e.kind() {
+            jsonwebtoken::errors::ErrorKind::ExpiredSignature => AuthError::TokenExpired,
+            _ => AuthError::InvalidToken,
+        })?;
+
+        if token_data.claims.token_type != "mfa" {
             return Err(AuthError::InvalidToken);
         }
 
@@ -361,9 +422,9 @@ mod tests {
     #[test]
     fn test_generate_refresh_token_success() {
         let service = create_service();
-        let user_id = UserId::new();
+        let stored = RefreshToken::issue(UserId::new(), service.refresh_token_lifetime());
 
-        let token = service.generate_refresh_token(user_id);
+        let token = service.generate_refresh_token(&stored);
 
         assert!(token.is_ok());
         let token = token.unwrap();
@@ -426,22 +487,50 @@ mod tests {
     #[test]
     fn test_validate_refresh_token_success() {
         let service = create_service();
-        let user_id = UserId::new();
+        let stored = RefreshToken::issue(UserId::new(), service.refresh_token_lifetime());
 
-        let token = service.generate_refresh_token(user_id).unwrap();
-        let result = service.validate_refresh_token(&token);
+        let token = service.generate_refresh_token(&stored).unwrap();
+        let subject = service.validate_refresh_token(&token).unwrap();
 
-        assert!(result.is_ok());
-        assert_eq!(result.unwrap().as_uuid(), user_id.as_uuid());
+        assert_eq!(subject.user_id, stored.user_id());
+        assert_eq!(subject.token_id, stored.id());
+        assert_eq!(subject.family_id, stored.family_id());
+    }
+
+    #[test]
+    fn test_validate_refresh_token_rejects_tokens_without_token_id() {
+        let service = create_service();
+        let now = Utc::now();
+
+        // Shape of refresh tokens issued before rotation was introduced
+        let claims = RefreshTokenClaims {
+            sub: UserId::new().into_uuid(),
+            exp: (now + Duration::days(1)).timestamp(),
+            iat: now.timestamp(),
+            token_type: "refresh".to_string(),
+            jti: None,
+            fam: None,
+        };
+        let token = encode(
+            &Header::default(),
+            &claims,
+            &EncodingKey::from_secret("test-secret-key-at-least-32-bytes-long".as_bytes()),
+        )
+        .unwrap();
+
+        assert!(matches!(
+            service.validate_refresh_token(&token),
+            Err(AuthError::InvalidToken)
+        ));
     }
 
     #[test]
     fn test_validate_refresh_token_invalid_signature() {
         let service = create_service();
         let other_service = JwtTokenService::new("different-secret-key-also-32-bytes".to_string());
-        let user_id = UserId::new();
+        let stored = RefreshToken::issue(UserId::new(), Duration::days(7));
 
-        let token = other_service.generate_refresh_token(user_id).unwrap();
+        let token = other_service.generate_refresh_token(&stored).unwrap();
         let result = service.validate_refresh_token(&token);
 
         assert!(matches!(result, Err(AuthError::InvalidToken)));
@@ -464,6 +553,31 @@ mod tests {
         assert!(matches!(result, Err(AuthError::InvalidToken)));
     }
 
+    #[test]
+    fn test_mfa_token_is_only_accepted_as_mfa_token() {
+        let service = create_service();
+        let user_id = UserId::new();
+
+        let mfa_token = service.generate_mfa_token(user_id).unwrap();
+        assert_eq!(service.validate_mfa_token(&mfa_token).unwrap(), user_id);
+        assert!(matches!(
+            service.validate_refresh_token(&mfa_token),
+            Err(AuthError::InvalidToken)
+        ));
+        assert!(matches!(
+            service.validate_access_token(&mfa_token),
+            Err(AuthError::InvalidToken)
+        ));
+
+        let refresh_token = service
+            .generate_refresh_token(&RefreshToken::issue(user_id, Duration::days(7)))
+            .unwrap();
+        assert!(matches!(
+            service.validate_mfa_token(&refresh_token),
+            Err(AuthError::InvalidToken)
+        ));
+    }
+
     #[test]
     fn test_access_token_contains_correct_claims() {
         let service = create_service();
@@ -534,6 +648,8 @@ mod tests {
             exp: past.timestamp(), // Already expired
             iat: (past - Duration::days(7)).timestamp(),
             token_type: "refresh".to_string(),
+            jti: Some(Uuid::now_v7()),
+            fam: Some(Uuid::now_v7()),
         };
 
         // Manually encode the expired token
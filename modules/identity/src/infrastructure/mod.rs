@@ -1,6 +1,8 @@
 // Infrastructure layer - External implementations (database, JWT, etc.)
 
 pub mod jwt_token_service;
+pub mod mfa_secret_cipher;
 pub mod persistence;
 
 pub use jwt_token_service::JwtTokenService;
+pub use mfa_secret_cipher::MfaSecretCipher;
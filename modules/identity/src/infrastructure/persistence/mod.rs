@@ -1,13 +1,19 @@
 // PostgreSQL repository implementations
 
 mod pg_audit_repository;
+mod pg_password_reset_token_repository;
 mod pg_permission_repository;
+mod pg_refresh_token_repository;
 mod pg_role_repository;
 mod pg_store_repository;
+mod pg_user_mfa_repository;
 mod pg_user_repository;
 
 pub use pg_audit_repository::*;
+pub use pg_password_reset_token_repository::*;
 pub use pg_permission_repository::*;
+pub use pg_refresh_token_repository::*;
 pub use pg_role_repository::*;
 pub use pg_store_repository::*;
+pub use pg_user_mfa_repository::*;
 pub use pg_user_repository::*;
@@ -5,8 +5,8 @@ use chrono::{DateTime, Utc};
 use sqlx::PgPool;
 use uuid::Uuid;
 
-use crate::domain::entities::{AuditAction, AuditEntry};
-use crate::domain::repositories::AuditRepository;
+use crate::domain::entities::{AuditAction, AuditEntry, AuditLegalHold, FieldChange};
+use crate::domain::repositories::{AuditArchiveRepository, AuditEntryFilter, AuditRepository};
 use crate::domain::value_objects::UserId;
 use crate::error::IdentityError;
 
@@ -27,8 +27,8 @@ impl AuditRepository for PgAuditRepository {
     async fn save(&self, entry: &AuditEntry) -> Result<(), IdentityError> {
         sqlx::query(
             r#"
-            INSERT INTO audit_log (id, entity_type, entity_id, action, old_value, new_value, actor_id, created_at)
-            VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
+            INSERT INTO audit_log (id, entity_type, entity_id, action, old_value, new_value, changes, actor_id, created_at)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
             "#,
         )
         .bind(entry.id())
@@ -37,6 +37,7 @@ impl AuditRepository for PgAuditRepository {
         .bind(entry.action().to_string())
         .bind(entry.old_value())
         .bind(entry.new_value())
+        .bind(sqlx::types::Json(entry.changes()))
         .bind(entry.actor_id().as_uuid())
         .bind(entry.created_at())
         .execute(&self.pool)
@@ -52,7 +53,7 @@ impl AuditRepository for PgAuditRepository {
     ) -> Result<Vec<AuditEntry>, IdentityError> {
         let rows = sqlx::query_as::<_, AuditRow>(
             r#"
-            SELECT id, entity_type, entity_id, action, old_value, new_value, actor_id, created_at
+            SELECT id, entity_type, entity_id, action, old_value, new_value, changes, actor_id, created_at
             FROM audit_log
             WHERE entity_type = $1 AND entity_id = $2
             ORDER BY created_at DESC
@@ -73,7 +74,7 @@ impl AuditRepository for PgAuditRepository {
     ) -> Result<Vec<AuditEntry>, IdentityError> {
         let rows = sqlx::query_as::<_, AuditRow>(
             r#"
-            SELECT id, entity_type, entity_id, action, old_value, new_value, actor_id, created_at
+            SELECT id, entity_type, entity_id, action, old_value, new_value, changes, actor_id, created_at
             FROM audit_log
             WHERE created_at >= $1 AND created_at < $2
             ORDER BY created_at DESC
@@ -86,6 +87,178 @@ impl AuditRepository for PgAuditRepository {
 
         Ok(rows.into_iter().map(|r| r.into()).collect())
     }
+
+    async fn find_paginated(
+        &self,
+        filter: &AuditEntryFilter,
+        page: i64,
+        page_size: i64,
+    ) -> Result<(Vec<AuditEntry>, i64), IdentityError> {
+        let offset = (page - 1) * page_size;
+
+        // Archived entries keep their columns, so the archive is searched
+        // through the same filters with a UNION ALL when asked for.
+        let rows = sqlx::query_as::<_, AuditPageRow>(
+            r#"
+            WITH trail AS (
+                SELECT id, entity_type, entity_id, action, old_value, new_value, changes, actor_id, created_at
+                FROM audit_log
+                UNION ALL
+                SELECT id, entity_type, entity_id, action, old_value, new_value, changes, actor_id, created_at
+                FROM audit_log_archive
+                WHERE $7
+            )
+            SELECT id, entity_type, entity_id, action, old_value, new_value, changes, actor_id, created_at,
+                   COUNT(*) OVER() AS total_count
+            FROM trail
+            WHERE ($1::uuid IS NULL OR actor_id = $1)
+              AND ($2::text IS NULL OR entity_type = $2)
+              AND ($3::uuid IS NULL OR entity_id = $3)
+              AND ($4::text IS NULL OR action = $4)
+              AND ($5::timestamptz IS NULL OR created_at >= $5)
+              AND ($6::timestamptz IS NULL OR created_at < $6)
+            ORDER BY created_at, id
+            LIMIT $8 OFFSET $9
+            "#,
+        )
+        .bind(filter.actor_id.map(|a| a.into_uuid()))
+        .bind(filter.entity_type.as_deref())
+        .bind(filter.entity_id)
+        .bind(filter.action.as_ref().map(|a| a.to_string()))
+        .bind(filter.from)
+        .bind(filter.to)
+        .bind(filter.include_archived)
+        .bind(page_size)
+        .bind(offset)
+        .fetch_all(&self.pool)
+        .await?;
+
+        if rows.is_empty() && page > 1 {
+            // Past the last page: the window count is unavailable, fetch it
+            // from the first page instead
+            let (_, total) = self.find_paginated(filter, 1, 1).await?;
+            return Ok((Vec::new(), total));
+        }
+
+        let total = rows.first().map(|r| r.total_count).unwrap_or(0);
+        let entries = rows.into_iter().map(|r| r.entry.into()).collect();
+        Ok((entries, total))
+    }
+}
+
+#[async_trait]
+impl AuditArchiveRepository for PgAuditRepository {
+    async fn archive_before(
+        &self,
+        cutoff: DateTime<Utc>,
+        limit: i64,
+    ) -> Result<u64, IdentityError> {
+        // A single statement: the DELETE ... RETURNING feeds the INSERT, so
+        // the move is atomic without an explicit transaction. SKIP LOCKED
+        // lets two overlapping runs split the work instead of blocking.
+        let result = sqlx::query(
+            r#"
+            WITH moved AS (
+                DELETE FROM audit_log
+                WHERE id IN (
+                    SELECT a.id
+                    FROM audit_log a
+                    WHERE a.created_at < $1
+                      AND NOT EXISTS (
+                          SELECT 1 FROM audit_legal_holds h
+                          WHERE h.entity_type = a.entity_type
+                            AND h.entity_id = a.entity_id
+                      )
+                    ORDER BY a.created_at
+                    LIMIT $2
+                    FOR UPDATE SKIP LOCKED
+                )
+                RETURNING id, entity_type, entity_id, action, old_value, new_value, changes, actor_id, created_at
+            )
+            INSERT INTO audit_log_archive (id, entity_type, entity_id, action, old_value, new_value, changes, actor_id, created_at)
+            SELECT id, entity_type, entity_id, action, old_value, new_value, changes, actor_id, created_at
+            FROM moved
+            "#,
+        )
+        .bind(cutoff)
+        .bind(limit)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(result.rows_affected())
+    }
+
+    async fn find_archived_by_entity(
+        &self,
+        entity_type: &str,
+        entity_id: Uuid,
+    ) -> Result<Vec<AuditEntry>, IdentityError> {
+        let rows = sqlx::query_as::<_, AuditRow>(
+            r#"
+            SELECT id, entity_type, entity_id, action, old_value, new_value, changes, actor_id, created_at
+            FROM audit_log_archive
+            WHERE entity_type = $1 AND entity_id = $2
+            ORDER BY created_at DESC
+            "#,
+        )
+        .bind(entity_type)
+        .bind(entity_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows.into_iter().map(|r| r.into()).collect())
+    }
+
+    async fn place_legal_hold(&self, hold: &AuditLegalHold) -> Result<(), IdentityError> {
+        sqlx::query(
+            r#"
+            INSERT INTO audit_legal_holds (entity_type, entity_id, reason, placed_by_id, placed_at)
+            VALUES ($1, $2, $3, $4, $5)
+            ON CONFLICT (entity_type, entity_id) DO UPDATE SET
+                reason = EXCLUDED.reason,
+                placed_by_id = EXCLUDED.placed_by_id,
+                placed_at = EXCLUDED.placed_at
+            "#,
+        )
+        .bind(hold.entity_type())
+        .bind(hold.entity_id())
+        .bind(hold.reason())
+        .bind(hold.placed_by_id().as_uuid())
+        .bind(hold.placed_at())
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    async fn release_legal_hold(
+        &self,
+        entity_type: &str,
+        entity_id: Uuid,
+    ) -> Result<bool, IdentityError> {
+        let result =
+            sqlx::query("DELETE FROM audit_legal_holds WHERE entity_type = $1 AND entity_id = $2")
+                .bind(entity_type)
+                .bind(entity_id)
+                .execute(&self.pool)
+                .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    async fn find_legal_holds(&self) -> Result<Vec<AuditLegalHold>, IdentityError> {
+        let rows = sqlx::query_as::<_, LegalHoldRow>(
+            r#"
+            SELECT entity_type, entity_id, reason, placed_by_id, placed_at
+            FROM audit_legal_holds
+            ORDER BY placed_at DESC
+            "#,
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows.into_iter().map(|r| r.into()).collect())
+    }
 }
 
 // Transactional methods
@@ -97,8 +270,8 @@ impl PgAuditRepository {
     ) -> Result<(), IdentityError> {
         sqlx::query(
             r#"
-            INSERT INTO audit_log (id, entity_type, entity_id, action, old_value, new_value, actor_id, created_at)
-            VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
+            INSERT INTO audit_log (id, entity_type, entity_id, action, old_value, new_value, changes, actor_id, created_at)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
             "#,
         )
         .bind(entry.id())
@@ -107,6 +280,7 @@ impl PgAuditRepository {
         .bind(entry.action().to_string())
         .bind(entry.old_value())
         .bind(entry.new_value())
+        .bind(sqlx::types::Json(entry.changes()))
         .bind(entry.actor_id().as_uuid())
         .bind(entry.created_at())
         .execute(&mut **tx)
@@ -125,10 +299,19 @@ struct AuditRow {
     action: String,
     old_value: Option<serde_json::Value>,
     new_value: Option<serde_json::Value>,
+    changes: sqlx::types::Json<Vec<FieldChange>>,
     actor_id: Uuid,
     created_at: DateTime<Utc>,
 }
 
+/// Audit row with the total match count of a paginated query
+#[derive(sqlx::FromRow)]
+struct AuditPageRow {
+    #[sqlx(flatten)]
+    entry: AuditRow,
+    total_count: i64,
+}
+
 impl From<AuditRow> for AuditEntry {
     fn from(row: AuditRow) -> Self {
         let action = parse_audit_action(&row.action);
@@ -142,22 +325,34 @@ impl From<AuditRow> for AuditEntry {
             UserId::from_uuid(row.actor_id),
             row.created_at,
         )
+        .with_changes(row.changes.0)
+    }
+}
+
+/// Internal row type for mapping legal hold database results
+#[derive(sqlx::FromRow)]
+struct LegalHoldRow {
+    entity_type: String,
+    entity_id: Uuid,
+    reason: String,
+    placed_by_id: Uuid,
+    placed_at: DateTime<Utc>,
+}
+
+impl From<LegalHoldRow> for AuditLegalHold {
+    fn from(row: LegalHoldRow) -> Self {
+        AuditLegalHold::reconstitute(
+            row.entity_type,
+            row.entity_id,
+            row.reason,
+            UserId::from_uuid(row.placed_by_id),
+            row.placed_at,
+        )
     }
 }
 
 /// Parses an action string into an AuditAction enum
 fn parse_audit_action(action: &str) -> AuditAction {
-    match action {
-        "created" => AuditAction::Created,
-        "updated" => AuditAction::Updated,
-        "deleted" => AuditAction::Deleted,
-        "permission_added" => AuditAction::PermissionAdded,
-        "permission_removed" => AuditAction::PermissionRemoved,
-        "role_assigned" => AuditAction::RoleAssigned,
-        "role_unassigned" => AuditAction::RoleUnassigned,
-        "user_added_to_store" => AuditAction::UserAddedToStore,
-        "user_removed_from_store" => AuditAction::UserRemovedFromStore,
-        // Default to Created for unknown actions (shouldn't happen in practice)
-        _ => AuditAction::Created,
-    }
+    // Default to Created for unknown actions (shouldn't happen in practice)
+    action.parse().unwrap_or(AuditAction::Created)
 }
@@ -0,0 +1,115 @@
+// PostgreSQL PasswordResetTokenRepository implementation
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::domain::entities::PasswordResetToken;
+use crate::domain::repositories::PasswordResetTokenRepository;
+use crate::domain::value_objects::UserId;
+use crate::error::IdentityError;
+
+/// PostgreSQL implementation of PasswordResetTokenRepository
+pub struct PgPasswordResetTokenRepository {
+    pool: PgPool,
+}
+
+impl PgPasswordResetTokenRepository {
+    /// Creates a new PgPasswordResetTokenRepository with the given connection pool
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl PasswordResetTokenRepository for PgPasswordResetTokenRepository {
+    async fn save(&self, token: &PasswordResetToken) -> Result<(), IdentityError> {
+        sqlx::query(
+            r#"
+            INSERT INTO password_reset_tokens (id, user_id, token_hash, expires_at, used_at, created_at)
+            VALUES ($1, $2, $3, $4, $5, $6)
+            "#,
+        )
+        .bind(token.id())
+        .bind(token.user_id().as_uuid())
+        .bind(token.token_hash())
+        .bind(token.expires_at())
+        .bind(token.used_at())
+        .bind(token.created_at())
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    async fn find_by_hash(
+        &self,
+        token_hash: &str,
+    ) -> Result<Option<PasswordResetToken>, IdentityError> {
+        let row = sqlx::query_as::<_, PasswordResetTokenRow>(
+            r#"
+            SELECT id, user_id, token_hash, expires_at, used_at, created_at
+            FROM password_reset_tokens
+            WHERE token_hash = $1
+            "#,
+        )
+        .bind(token_hash)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(row.map(|r| r.into()))
+    }
+
+    async fn mark_used(&self, id: Uuid, now: DateTime<Utc>) -> Result<bool, IdentityError> {
+        let result = sqlx::query(
+            "UPDATE password_reset_tokens SET used_at = $2 WHERE id = $1 AND used_at IS NULL",
+        )
+        .bind(id)
+        .bind(now)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    async fn invalidate_all_for_user(
+        &self,
+        user_id: UserId,
+        now: DateTime<Utc>,
+    ) -> Result<u64, IdentityError> {
+        let result = sqlx::query(
+            "UPDATE password_reset_tokens SET used_at = $2 WHERE user_id = $1 AND used_at IS NULL",
+        )
+        .bind(user_id.as_uuid())
+        .bind(now)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(result.rows_affected())
+    }
+}
+
+/// Internal row type for mapping password reset token database results
+#[derive(sqlx::FromRow)]
+struct PasswordResetTokenRow {
+    id: Uuid,
+    user_id: Uuid,
+    token_hash: String,
+    expires_at: DateTime<Utc>,
+    used_at: Option<DateTime<Utc>>,
+    created_at: DateTime<Utc>,
+}
+
+impl From<PasswordResetTokenRow> for PasswordResetToken {
+    fn from(row: PasswordResetTokenRow) -> Self {
+        PasswordResetToken::reconstitute(
+            row.id,
+            UserId::from_uuid(row.user_id),
+            row.token_hash,
+            row.expires_at,
+            row.used_at,
+            row.created_at,
+        )
+    }
+}
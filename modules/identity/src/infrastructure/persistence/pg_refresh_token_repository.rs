@@ -0,0 +1,164 @@
+// PostgreSQL RefreshTokenRepository implementation
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::domain::entities::RefreshToken;
+use crate::domain::repositories::RefreshTokenRepository;
+use crate::domain::value_objects::UserId;
+use crate::error::IdentityError;
+
+/// PostgreSQL implementation of RefreshTokenRepository
+pub struct PgRefreshTokenRepository {
+    pool: PgPool,
+}
+
+impl PgRefreshTokenRepository {
+    /// Creates a new PgRefreshTokenRepository with the given connection pool
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    async fn insert<'e, E>(executor: E, token: &RefreshToken) -> Result<(), IdentityError>
+    where
+        E: sqlx::PgExecutor<'e>,
+    {
+        sqlx::query(
+            r#"
+            INSERT INTO refresh_tokens (id, user_id, family_id, expires_at, rotated_at,
+                                        replaced_by, revoked_at, created_at)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
+            "#,
+        )
+        .bind(token.id())
+        .bind(token.user_id().as_uuid())
+        .bind(token.family_id())
+        .bind(token.expires_at())
+        .bind(token.rotated_at())
+        .bind(token.replaced_by())
+        .bind(token.revoked_at())
+        .bind(token.created_at())
+        .execute(executor)
+        .await?;
+
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl RefreshTokenRepository for PgRefreshTokenRepository {
+    async fn save(&self, token: &RefreshToken) -> Result<(), IdentityError> {
+        Self::insert(&self.pool, token).await
+    }
+
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<RefreshToken>, IdentityError> {
+        let row = sqlx::query_as::<_, RefreshTokenRow>(
+            r#"
+            SELECT id, user_id, family_id, expires_at, rotated_at, replaced_by, revoked_at, created_at
+            FROM refresh_tokens
+            WHERE id = $1
+            "#,
+        )
+        .bind(id)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(row.map(|r| r.into()))
+    }
+
+    async fn rotate(
+        &self,
+        current: Uuid,
+        successor: &RefreshToken,
+        now: DateTime<Utc>,
+    ) -> Result<bool, IdentityError> {
+        let mut tx = self.pool.begin().await?;
+
+        // The state check in the WHERE clause makes the first of two
+        // concurrent refreshes win; the loser sees zero rows and is
+        // treated as a reuse.
+        let result = sqlx::query(
+            r#"
+            UPDATE refresh_tokens
+            SET rotated_at = $2, replaced_by = $3
+            WHERE id = $1 AND rotated_at IS NULL AND revoked_at IS NULL
+            "#,
+        )
+        .bind(current)
+        .bind(now)
+        .bind(successor.id())
+        .execute(&mut *tx)
+        .await?;
+
+        if result.rows_affected() == 0 {
+            return Ok(false);
+        }
+
+        Self::insert(&mut *tx, successor).await?;
+        tx.commit().await?;
+
+        Ok(true)
+    }
+
+    async fn revoke_family(
+        &self,
+        family_id: Uuid,
+        now: DateTime<Utc>,
+    ) -> Result<u64, IdentityError> {
+        let result = sqlx::query(
+            "UPDATE refresh_tokens SET revoked_at = $2 WHERE family_id = $1 AND revoked_at IS NULL",
+        )
+        .bind(family_id)
+        .bind(now)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(result.rows_affected())
+    }
+
+    async fn revoke_all_for_user(
+        &self,
+        user_id: UserId,
+        now: DateTime<Utc>,
+    ) -> Result<u64, IdentityError> {
+        let result = sqlx::query(
+            "UPDATE refresh_tokens SET revoked_at = $2 WHERE user_id = $1 AND revoked_at IS NULL",
+        )
+        .bind(user_id.as_uuid())
+        .bind(now)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(result.rows_affected())
+    }
+}
+
+/// Internal row type for mapping refresh token database results
+#[derive(sqlx::FromRow)]
+struct RefreshTokenRow {
+    id: Uuid,
+    user_id: Uuid,
+    family_id: Uuid,
+    expires_at: DateTime<Utc>,
+    rotated_at: Option<DateTime<Utc>>,
+    replaced_by: Option<Uuid>,
+    revoked_at: Option<DateTime<Utc>>,
+    created_at: DateTime<Utc>,
+}
+
+impl From<RefreshTokenRow> for RefreshToken {
+    fn from(row: RefreshTokenRow) -> Self {
+        RefreshToken::reconstitute(
+            row.id,
+            UserId::from_uuid(row.user_id),
+            row.family_id,
+            row.expires_at,
+            row.rotated_at,
+            row.replaced_by,
+            row.revoked_at,
+            row.created_at,
+        )
+    }
+}
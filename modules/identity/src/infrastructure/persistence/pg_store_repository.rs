@@ -3,7 +3,7 @@
 use async_trait::async_trait;
 use sqlx::PgPool;
 
-use crate::domain::entities::{Store, User};
+use crate::domain::entities::{Store, StoreSettings, User};
 use crate::domain::repositories::StoreRepository;
 use crate::domain::value_objects::{Email, StoreId, UserId, Username};
 use crate::error::IdentityError;
@@ -117,8 +117,13 @@ impl StoreRepository for PgStoreRepository {
         page: i64,
         page_size: i64,
         user_store_ids: Option<&[uuid::Uuid]>,
+        after_id: Option<StoreId>,
     ) -> Result<(Vec<Store>, i64), IdentityError> {
-        let offset = (page - 1) * page_size;
+        let offset = if after_id.is_some() {
+            0
+        } else {
+            (page - 1) * page_size
+        };
 
         let (count,): (i64,) = sqlx::query_as(
             r#"
@@ -142,7 +147,8 @@ impl StoreRepository for PgStoreRepository {
             WHERE ($1::bool IS NULL OR is_active = $1)
               AND ($2::bool IS NULL OR is_ecommerce = $2)
               AND ($3::uuid[] IS NULL OR id = ANY($3))
-            ORDER BY name
+              AND ($6::uuid IS NULL OR (name, id) > (SELECT name, id FROM stores WHERE id = $6))
+            ORDER BY name, id
             LIMIT $4 OFFSET $5
             "#,
         )
@@ -151,6 +157,7 @@ impl StoreRepository for PgStoreRepository {
         .bind(user_store_ids)
         .bind(page_size)
         .bind(offset)
+        .bind(after_id.map(|id| id.into_uuid()))
         .fetch_all(&self.pool)
         .await?;
 
@@ -174,6 +181,71 @@ impl StoreRepository for PgStoreRepository {
 
         rows.into_iter().map(|r| r.try_into()).collect()
     }
+
+    async fn find_settings(
+        &self,
+        store_id: StoreId,
+    ) -> Result<Option<StoreSettings>, IdentityError> {
+        let row = sqlx::query_as::<_, StoreSettingsRow>(
+            r#"
+            SELECT store_id, loyalty_enabled, auto_markdowns_enabled, allow_negative_stock,
+                   pickup_hold_days, transit_owned_by_destination, updated_by_id, updated_at
+            FROM store_settings
+            WHERE store_id = $1
+            "#,
+        )
+        .bind(store_id.as_uuid())
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(row.map(|r| r.into()))
+    }
+
+    async fn find_all_settings(&self) -> Result<Vec<StoreSettings>, IdentityError> {
+        let rows = sqlx::query_as::<_, StoreSettingsRow>(
+            r#"
+            SELECT store_id, loyalty_enabled, auto_markdowns_enabled, allow_negative_stock,
+                   pickup_hold_days, transit_owned_by_destination, updated_by_id, updated_at
+            FROM store_settings
+            "#,
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows.into_iter().map(|r| r.into()).collect())
+    }
+
+    async fn save_settings(&self, settings: &StoreSettings) -> Result<(), IdentityError> {
+        sqlx::query(
+            r#"
+            INSERT INTO store_settings (store_id, loyalty_enabled, auto_markdowns_enabled,
+                                        allow_negative_stock, pickup_hold_days,
+                                        transit_owned_by_destination, updated_by_id,
+                                        updated_at)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
+            ON CONFLICT (store_id) DO UPDATE
+            SET loyalty_enabled = EXCLUDED.loyalty_enabled,
+                auto_markdowns_enabled = EXCLUDED.auto_markdowns_enabled,
+                allow_negative_stock = EXCLUDED.allow_negative_stock,
+                pickup_hold_days = EXCLUDED.pickup_hold_days,
+                transit_owned_by_destination = EXCLUDED.transit_owned_by_destination,
+                updated_by_id = EXCLUDED.updated_by_id,
+                updated_at = EXCLUDED.updated_at
+            "#,
+        )
+        .bind(settings.store_id().as_uuid())
+        .bind(settings.loyalty_enabled())
+        .bind(settings.auto_markdowns_enabled())
+        .bind(settings.allow_negative_stock())
+        .bind(settings.pickup_hold_days())
+        .bind(settings.transit_owned_by_destination())
+        .bind(settings.updated_by_id().map(|id| id.into_uuid()))
+        .bind(settings.updated_at())
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
 }
 
 /// Internal row type for mapping store database results
@@ -202,6 +274,34 @@ impl From<StoreRow> for Store {
     }
 }
 
+/// Internal row type for mapping store settings database results
+#[derive(sqlx::FromRow)]
+struct StoreSettingsRow {
+    store_id: uuid::Uuid,
+    loyalty_enabled: bool,
+    auto_markdowns_enabled: bool,
+    allow_negative_stock: bool,
+    pickup_hold_days: i32,
+    transit_owned_by_destination: bool,
+    updated_by_id: Option<uuid::Uuid>,
+    updated_at: chrono::DateTime<chrono::Utc>,
+}
+
+impl From<StoreSettingsRow> for StoreSettings {
+    fn from(row: StoreSettingsRow) -> Self {
+        StoreSettings::new(
+            StoreId::from_uuid(row.store_id),
+            row.loyalty_enabled,
+            row.auto_markdowns_enabled,
+            row.allow_negative_stock,
+            row.pickup_hold_days,
+            row.transit_owned_by_destination,
+            row.updated_by_id.map(UserId::from_uuid),
+            row.updated_at,
+        )
+    }
+}
+
 /// Internal row type for mapping user database results
 #[derive(sqlx::FromRow)]
 struct UserRow {
@@ -0,0 +1,120 @@
+// PostgreSQL UserMfaRepository implementation
+
+use async_trait::async_trait;
+use sqlx::PgPool;
+
+use crate::domain::auth::TotpSecret;
+use crate::domain::entities::UserMfa;
+use crate::domain::repositories::UserMfaRepository;
+use crate::domain::value_objects::UserId;
+use crate::error::IdentityError;
+use crate::infrastructure::MfaSecretCipher;
+
+/// PostgreSQL implementation of UserMfaRepository
+///
+/// TOTP secrets are encrypted with the given cipher before they reach the
+/// database and decrypted on load.
+pub struct PgUserMfaRepository {
+    pool: PgPool,
+    cipher: MfaSecretCipher,
+}
+
+impl PgUserMfaRepository {
+    /// Creates a new PgUserMfaRepository with the given connection pool
+    pub fn new(pool: PgPool, cipher: MfaSecretCipher) -> Self {
+        Self { pool, cipher }
+    }
+}
+
+#[async_trait]
+impl UserMfaRepository for PgUserMfaRepository {
+    async fn find_by_user_id(&self, user_id: UserId) -> Result<Option<UserMfa>, IdentityError> {
+        let row = sqlx::query_as::<_, UserMfaRow>(
+            r#"
+            SELECT user_id, secret_ciphertext, enabled_at, recovery_code_hashes,
+                   failed_attempts, locked_until, last_used_step, created_at, updated_at
+            FROM user_mfa
+            WHERE user_id = $1
+            "#,
+        )
+        .bind(user_id.as_uuid())
+        .fetch_optional(&self.pool)
+        .await?;
+
+        row.map(|r| r.into_entity(&self.cipher)).transpose()
+    }
+
+    async fn save(&self, mfa: &UserMfa) -> Result<(), IdentityError> {
+        let secret_ciphertext = self.cipher.encrypt(mfa.secret().as_bytes())?;
+
+        sqlx::query(
+            r#"
+            INSERT INTO user_mfa (user_id, secret_ciphertext, enabled_at, recovery_code_hashes,
+                                  failed_attempts, locked_until, last_used_step, created_at, updated_at)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
+            ON CONFLICT (user_id) DO UPDATE
+            SET secret_ciphertext = EXCLUDED.secret_ciphertext,
+                enabled_at = EXCLUDED.enabled_at,
+                recovery_code_hashes = EXCLUDED.recovery_code_hashes,
+                failed_attempts = EXCLUDED.failed_attempts,
+                locked_until = EXCLUDED.locked_until,
+                last_used_step = EXCLUDED.last_used_step,
+                created_at = EXCLUDED.created_at,
+                updated_at = EXCLUDED.updated_at
+            "#,
+        )
+        .bind(mfa.user_id().as_uuid())
+        .bind(secret_ciphertext)
+        .bind(mfa.enabled_at())
+        .bind(mfa.recovery_code_hashes())
+        .bind(mfa.failed_attempts())
+        .bind(mfa.locked_until())
+        .bind(mfa.last_used_step())
+        .bind(mfa.created_at())
+        .bind(mfa.updated_at())
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    async fn delete(&self, user_id: UserId) -> Result<(), IdentityError> {
+        sqlx::query("DELETE FROM user_mfa WHERE user_id = $1")
+            .bind(user_id.as_uuid())
+            .execute(&self.pool)
+            .await?;
+
+        Ok(())
+    }
+}
+
+/// Internal row type for mapping user_mfa database results
+#[derive(sqlx::FromRow)]
+struct UserMfaRow {
+    user_id: uuid::Uuid,
+    secret_ciphertext: Vec<u8>,
+    enabled_at: Option<chrono::DateTime<chrono::Utc>>,
+    recovery_code_hashes: Vec<String>,
+    failed_attempts: i32,
+    locked_until: Option<chrono::DateTime<chrono::Utc>>,
+    last_used_step: Option<i64>,
+    created_at: chrono::DateTime<chrono::Utc>,
+    updated_at: chrono::DateTime<chrono::Utc>,
+}
+
+impl UserMfaRow {
+    fn into_entity(self, cipher: &MfaSecretCipher) -> Result<UserMfa, IdentityError> {
+        let secret = TotpSecret::from_bytes(cipher.decrypt(&self.secret_ciphertext)?);
+        Ok(UserMfa::reconstitute(
+            UserId::from_uuid(self.user_id),
+            secret,
+            self.enabled_at,
+            self.recovery_code_hashes,
+            self.failed_attempts,
+            self.locked_until,
+            self.last_used_step,
+            self.created_at,
+            self.updated_at,
+        ))
+    }
+}
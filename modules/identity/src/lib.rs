@@ -21,7 +21,11 @@ pub use error::IdentityError;
 // -----------------------------------------------------------------------------
 // Domain Layer - Entities
 // -----------------------------------------------------------------------------
-pub use domain::entities::{AuditAction, AuditEntry, Permission, Role, Store, User};
+pub use domain::entities::{
+    AuditAction, AuditEntry, AuditLegalHold, FieldChange, MfaFactor,
+    PASSWORD_RESET_TOKEN_LIFETIME_MINUTES, PasswordResetToken, Permission, RefreshToken, Role,
+    Store, StoreSettings, User, UserMfa,
+};
 
 // -----------------------------------------------------------------------------
 // Domain Layer - Value Objects
@@ -34,18 +38,23 @@ pub use domain::value_objects::{
 // Domain Layer - Repository Traits
 // -----------------------------------------------------------------------------
 pub use domain::repositories::{
-    AuditRepository, PermissionRepository, RoleRepository, StoreRepository, UserRepository,
+    AuditArchiveRepository, AuditEntryFilter, AuditRepository, PasswordResetTokenRepository,
+    PermissionRepository, RefreshTokenRepository, RoleRepository, StoreRepository,
+    UserMfaRepository, UserRepository,
 };
 
 // -----------------------------------------------------------------------------
 // Domain Layer - Services (UserContext)
 // -----------------------------------------------------------------------------
-pub use domain::services::{PermissionCheckResult, UserContext};
+pub use domain::services::{PermissionCheckResult, StoreScope, UserContext};
 
 // -----------------------------------------------------------------------------
 // Domain Layer - Authentication
 // -----------------------------------------------------------------------------
-pub use domain::auth::{AuthError, LoginIdentifier, TokenClaims, TokenService};
+pub use domain::auth::{
+    AuthError, LoginIdentifier, MFA_TOKEN_LIFETIME_SECS, MfaPolicy, RefreshTokenSubject,
+    TokenClaims, TokenService, TotpSecret,
+};
 
 // -----------------------------------------------------------------------------
 // Application Layer - Use Cases
@@ -55,10 +64,15 @@ pub use application::use_cases::{
     AddPermissionToRoleUseCase,
     // Store use cases
     AddUserToStoreUseCase,
+    AdminDisableMfaUseCase,
+    // Audit use cases
+    ArchiveAuditUseCase,
     // User use cases
     AssignRoleUseCase,
     // UserContext use case
     BuildUserContextUseCase,
+    // MFA use cases
+    ConfirmMfaUseCase,
     // Permission use cases
     CreatePermissionUseCase,
     CreateRoleUseCase,
@@ -66,18 +80,30 @@ pub use application::use_cases::{
     CreateUserUseCase,
     DeletePermissionUseCase,
     DeleteRoleUseCase,
+    DisableMfaUseCase,
+    EnrollMfaUseCase,
+    GetStoreSettingsUseCase,
+    ListAuditEntriesUseCase,
     ListPermissionsUseCase,
     // Auth use cases
     LoginUseCase,
+    PlaceAuditLegalHoldUseCase,
     RefreshTokenUseCase,
     RegisterUserUseCase,
+    ReleaseAuditLegalHoldUseCase,
     RemovePermissionFromRoleUseCase,
     RemoveRoleUseCase,
     RemoveUserFromStoreUseCase,
+    // Password reset use cases
+    RequestPasswordResetUseCase,
+    ResetPasswordUseCase,
+    RevokeAllSessionsUseCase,
     SetStoreActiveUseCase,
     SetUserActiveUseCase,
+    UpdateStoreSettingsUseCase,
     UpdateStoreUseCase,
     UpdateUserUseCase,
+    VerifyMfaLoginUseCase,
 };
 
 // -----------------------------------------------------------------------------
@@ -86,23 +112,36 @@ pub use application::use_cases::{
 pub use application::dtos::{
     AddUserToStoreCommand,
     AssignRoleCommand,
+    ConfirmMfaCommand,
     CreatePermissionCommand,
     CreateRoleCommand,
     CreateStoreCommand,
     CreateUserCommand,
+    DisableMfaCommand,
+    ListAuditEntriesQuery,
     // Auth commands
     LoginCommand,
+    PlaceAuditLegalHoldCommand,
     RefreshCommand,
     RegisterEcommerceCommand,
     RegisterPosCommand,
+    // Password reset commands
+    RequestPasswordResetCommand,
+    ResetPasswordCommand,
     UpdateStoreCommand,
+    UpdateStoreSettingsCommand,
     UpdateUserCommand,
+    VerifyMfaCommand,
 };
 
 // -----------------------------------------------------------------------------
 // Application Layer - DTOs (Responses)
 // -----------------------------------------------------------------------------
-pub use application::dtos::{ErrorResponse, ListResponse, LoginResponse, RegisterResponse};
+pub use application::dtos::{
+    ArchiveAuditResult, AuditEntryResponse, AuditTrailResponse, ErrorResponse, ListResponse,
+    LoginOutcome, LoginResponse, MfaChallengeResponse, MfaEnrollmentResponse,
+    MfaRecoveryCodesResponse, PasswordResetIssued, RegisterResponse,
+};
 
 // -----------------------------------------------------------------------------
 // Application Layer - Validators
@@ -115,11 +154,12 @@ pub use application::validators::{
 // Infrastructure Layer - PostgreSQL Repositories
 // -----------------------------------------------------------------------------
 pub use infrastructure::persistence::{
-    PgAuditRepository, PgPermissionRepository, PgRoleRepository, PgStoreRepository,
+    PgAuditRepository, PgPasswordResetTokenRepository, PgPermissionRepository,
+    PgRefreshTokenRepository, PgRoleRepository, PgStoreRepository, PgUserMfaRepository,
     PgUserRepository,
 };
 
 // -----------------------------------------------------------------------------
 // Infrastructure Layer - JWT Token Service
 // -----------------------------------------------------------------------------
-pub use infrastructure::JwtTokenService;
+pub use infrastructure::{JwtTokenService, MfaSecretCipher};
@@ -4,7 +4,9 @@
 // They use primitive types (String, Uuid, Decimal) rather than domain value objects
 // to keep the application boundary clean and allow validation in use cases.
 
-use chrono::{DateTime, Utc};
+use std::collections::HashMap;
+
+use chrono::{DateTime, NaiveDate, Utc};
 use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 use serde_json::Value as JsonValue;
@@ -30,6 +32,14 @@ pub struct CreateCategoryCommand {
     /// Sort order within the same level (default: 0)
     #[serde(default)]
     pub sort_order: i32,
+    /// Optional tax category ("isv15", "isv18", "exempt") inherited by the
+    /// products below this category
+    #[serde(default)]
+    pub tax_category: Option<String>,
+    /// Optional costing method ("weighted_average", "fifo", "lifo");
+    /// defaults to weighted average
+    #[serde(default)]
+    pub costing_method: Option<String>,
 }
 
 /// Command to update an existing category
@@ -49,6 +59,41 @@ pub struct UpdateCategoryCommand {
     pub sort_order: Option<i32>,
     /// New active status (if changing)
     pub is_active: Option<bool>,
+    /// New tax category (if changing); Some(None) inherits from the parent
+    #[serde(default)]
+    pub tax_category: Option<Option<String>>,
+    /// New costing method (if changing)
+    #[serde(default)]
+    pub costing_method: Option<String>,
+}
+
+/// Command to move a category, with its subtree, under another parent
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MoveCategoryCommand {
+    /// New parent category ID; null makes it a root category
+    pub parent_id: Option<Uuid>,
+    /// Sort order among the new siblings; defaults to after the last one
+    #[serde(default)]
+    pub sort_order: Option<i32>,
+}
+
+/// Command to set the order of the categories sharing a parent
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReorderCategoriesCommand {
+    /// Parent whose children are reordered; null reorders the root categories
+    pub parent_id: Option<Uuid>,
+    /// Every sibling category ID, in the new order
+    pub category_ids: Vec<Uuid>,
+}
+
+/// Command to set the prefix of a category's generated barcodes
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ConfigureCategoryBarcodePrefixCommand {
+    /// Category ID (set from the URL path)
+    #[serde(default)]
+    pub category_id: Uuid,
+    /// 3 to 9 digit prefix; null turns generation off
+    pub prefix: Option<String>,
 }
 
 // =============================================================================
@@ -93,6 +138,19 @@ pub struct CreateProductCommand {
     /// Whether tax is included in price
     #[serde(default)]
     pub tax_included: bool,
+    /// Optional tax category overriding the one inherited from the category
+    #[serde(default)]
+    pub tax_category: Option<String>,
+    /// Whether each unit sold must be recorded by serial number
+    #[serde(default)]
+    pub is_serialized: bool,
+    /// Optional rule pricing the product from its cost ("markup", "margin" or
+    /// "keystone"); products without one keep a manual price
+    #[serde(default)]
+    pub markup_type: Option<String>,
+    /// Percent for the markup or margin rule
+    #[serde(default)]
+    pub markup_percent: Option<Decimal>,
     /// Flexible product attributes (JSONB)
     pub attributes: Option<JsonValue>,
 }
@@ -132,12 +190,102 @@ pub struct UpdateProductCommand {
     pub tax_rate: Option<Decimal>,
     /// New tax_included flag (if changing)
     pub tax_included: Option<bool>,
+    /// New tax category override (if changing); Some(None) inherits from the category
+    #[serde(default)]
+    pub tax_category: Option<Option<String>>,
+    /// New serial-number tracking flag (if changing)
+    pub is_serialized: Option<bool>,
+    /// New markup rule type (if changing); Some(None) switches to a manual price
+    #[serde(default)]
+    pub markup_type: Option<Option<String>>,
+    /// Percent for the new markup rule
+    #[serde(default)]
+    pub markup_percent: Option<Decimal>,
     /// New attributes (if changing)
     pub attributes: Option<JsonValue>,
     /// New active status (if changing)
     pub is_active: Option<bool>,
 }
 
+/// Command to adjust the prices of every product in a category
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BulkUpdatePricesCommand {
+    /// Category ID (set from the URL path)
+    #[serde(default)]
+    pub category_id: Uuid,
+    /// Also update products in all subcategories
+    #[serde(default)]
+    pub include_subcategories: bool,
+    /// "percentage" or "fixed_amount"
+    pub adjustment_type: String,
+    /// Percent (10 = +10%) or amount to add; negative values lower prices
+    pub value: Decimal,
+    /// Adjust base prices (default: true)
+    #[serde(default = "default_true")]
+    pub apply_to_base_price: bool,
+    /// Adjust cost prices (default: false)
+    #[serde(default)]
+    pub apply_to_cost_price: bool,
+}
+
+/// One parsed row of a product catalog import
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ImportProductRow {
+    pub name: String,
+    /// Matches an existing product to update; otherwise the new product's SKU
+    pub sku: String,
+    pub barcode: Option<String>,
+    /// Category names from the root down, separated by `/`
+    /// (e.g. "Beverages/Sodas"); missing categories are created
+    pub category_path: Option<String>,
+    pub base_price: Decimal,
+    pub cost_price: Decimal,
+    /// Unit of measure (unit, kg, g, l, ml, ...)
+    pub unit_of_measure: String,
+}
+
+/// Command to create or update products from an imported catalog
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ImportProductsCommand {
+    pub rows: Vec<ImportProductRow>,
+}
+
+/// Command to link a vendor to a product, optionally as its preferred vendor
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SetProductVendorCommand {
+    /// Product ID (set from the path)
+    #[serde(default)]
+    pub product_id: Uuid,
+    /// Vendor that supplies the product
+    pub vendor_id: Uuid,
+    /// Vendor's own code for the product (max 100 characters)
+    pub vendor_sku: Option<String>,
+    /// Last price paid to this vendor
+    pub last_purchase_price: Option<Decimal>,
+    /// Make this the product's preferred vendor (default: false).
+    /// The first vendor linked to a product is always preferred.
+    #[serde(default)]
+    pub is_preferred: bool,
+}
+
+/// Command to replace the unit conversions defined for a product
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SetProductUnitConversionsCommand {
+    /// Product ID (set from the path)
+    #[serde(default)]
+    pub product_id: Uuid,
+    /// Conversions for the product; an empty list removes them all
+    pub conversions: Vec<UnitConversionInput>,
+}
+
+/// One unit conversion: one `from_unit` equals `factor` `to_unit`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UnitConversionInput {
+    pub from_unit: String,
+    pub to_unit: String,
+    pub factor: Decimal,
+}
+
 // =============================================================================
 // Variant Commands
 // =============================================================================
@@ -160,6 +308,43 @@ pub struct CreateVariantCommand {
     pub barcode: Option<String>,
 }
 
+/// Command to generate variants from attribute axes (cartesian product)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GenerateVariantsCommand {
+    /// Parent product ID (set from URL path, not from request body)
+    #[serde(default)]
+    pub product_id: Uuid,
+    /// Attribute axes, e.g. size: [S, M, L] and color: [Red, Blue]
+    pub axes: Vec<VariantAxis>,
+    /// Optional price/cost overrides for specific combinations
+    #[serde(default)]
+    pub overrides: Vec<VariantCombinationOverride>,
+}
+
+/// One attribute axis and its values
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct VariantAxis {
+    /// Attribute name (becomes a key in variant_attributes)
+    pub name: String,
+    /// Attribute values, in display order
+    pub values: Vec<String>,
+    /// Optional value -> SKU code, for values whose derived code (uppercased
+    /// letters and digits) is too long or clashes with another value's
+    #[serde(default)]
+    pub codes: HashMap<String, String>,
+}
+
+/// Price/cost override for the combination matching `attributes`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct VariantCombinationOverride {
+    /// Axis name -> value; must name every axis
+    pub attributes: HashMap<String, String>,
+    /// Optional price override
+    pub price: Option<Decimal>,
+    /// Optional cost override
+    pub cost_price: Option<Decimal>,
+}
+
 /// Command to update a product variant
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct UpdateVariantCommand {
@@ -221,8 +406,15 @@ pub struct UpdateStockCommand {
     pub reference_id: Option<Uuid>,
     /// Optional notes
     pub notes: Option<String>,
-    /// Expected version for optimistic locking
-    pub expected_version: i32,
+    /// Version the caller last read. When set, the update is refused if the
+    /// stock has changed since; when empty, the delta is applied to the
+    /// latest version, retrying version conflicts.
+    #[serde(default)]
+    pub expected_version: Option<i32>,
+    /// Document date of the movement, checked against closed periods
+    /// (defaults to today)
+    #[serde(default)]
+    pub effective_date: Option<NaiveDate>,
 }
 
 /// Command to update stock level thresholds (min/max)
@@ -234,10 +426,25 @@ pub struct UpdateStockLevelsCommand {
     pub min_stock_level: Decimal,
     /// Maximum stock level (optional)
     pub max_stock_level: Option<Decimal>,
+    /// Store-specific reorder point; falls back to min_stock_level when absent
+    #[serde(default)]
+    pub reorder_point: Option<Decimal>,
+    /// Quantity to order when stock reaches the reorder point (optional)
+    #[serde(default)]
+    pub reorder_quantity: Option<Decimal>,
     /// Expected version for optimistic locking
     pub expected_version: i32,
 }
 
+/// Command to replay a stock record's kardex and rewrite its average cost
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RecalculateAverageCostCommand {
+    /// Store holding the stock
+    pub store_id: Uuid,
+    /// Product whose stock is recalculated
+    pub product_id: Uuid,
+}
+
 /// Command to initialize stock for multiple products at once
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct BulkInitializeStockCommand {
@@ -264,6 +471,23 @@ pub struct BulkInitializeStockItem {
     pub max_stock_level: Option<Decimal>,
 }
 
+// =============================================================================
+// Serial Number Commands
+// =============================================================================
+
+/// Command to register serialized units in stock at a store
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RegisterSerialsCommand {
+    /// Serialized product the units belong to
+    pub product_id: Uuid,
+    /// Variant of the product, if any
+    pub variant_id: Option<Uuid>,
+    /// Store holding the units
+    pub store_id: Uuid,
+    /// Serial numbers, one per unit
+    pub serial_numbers: Vec<String>,
+}
+
 // =============================================================================
 // Reservation Commands
 // =============================================================================
@@ -281,6 +505,10 @@ pub struct CreateReservationCommand {
     pub quantity: Decimal,
     /// Expiration timestamp (must be in future)
     pub expires_at: DateTime<Utc>,
+    /// Seconds each touch pushes the expiry out by while the holder is
+    /// active; touching is disabled when omitted
+    #[serde(default)]
+    pub auto_extend_seconds: Option<i64>,
 }
 
 /// Command to confirm a reservation
@@ -297,6 +525,13 @@ pub struct CancelReservationCommand {
     pub reservation_id: Uuid,
 }
 
+/// Command to touch a reservation, extending its expiry
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TouchReservationCommand {
+    /// Reservation ID to touch
+    pub reservation_id: Uuid,
+}
+
 // =============================================================================
 // Recipe Commands
 // =============================================================================
@@ -317,6 +552,10 @@ pub struct CreateRecipeCommand {
     pub version: i32,
     /// Quantity produced by this recipe
     pub yield_quantity: Decimal,
+    /// Unit the yield is measured in: "unit", "kg", "lb", "liter", "oz"
+    /// (unit if omitted)
+    #[serde(default)]
+    pub yield_unit: Option<String>,
     /// Preparation time in minutes
     pub preparation_time_minutes: Option<i32>,
     /// Whether to calculate cost from ingredients
@@ -345,6 +584,9 @@ pub struct UpdateRecipeCommand {
     pub version: Option<i32>,
     /// New yield quantity (if changing)
     pub yield_quantity: Option<Decimal>,
+    /// New yield unit (if changing)
+    #[serde(default)]
+    pub yield_unit: Option<String>,
     /// New preparation time (if changing)
     pub preparation_time_minutes: Option<i32>,
     /// New calculate_cost flag (if changing)
@@ -500,6 +742,17 @@ pub struct TransferItemCommand {
     pub notes: Option<String>,
 }
 
+/// Command to approve or reject a transfer awaiting approval
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ApproveTransferCommand {
+    /// Transfer ID to approve/reject
+    pub transfer_id: Uuid,
+    /// Whether to approve (true) or reject (false)
+    pub approve: bool,
+    /// Optional reason for the decision
+    pub notes: Option<String>,
+}
+
 /// Command to ship a transfer
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ShipTransferCommand {
@@ -537,3 +790,70 @@ pub struct ReceiveTransferItemCommand {
     /// Actual quantity received (may differ from shipped due to damage/loss)
     pub quantity_received: Decimal,
 }
+
+// =============================================================================
+// Reprice Commands
+// =============================================================================
+
+/// Command to preview selling prices after a vendor cost update
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PreviewRepriceCommand {
+    /// Vendor whose costs changed
+    pub vendor_id: Option<Uuid>,
+    /// Optional note on the cost update
+    pub reason: Option<String>,
+    /// New unit costs of the affected products
+    pub items: Vec<RepriceCostItemCommand>,
+}
+
+/// New unit cost of one product
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RepriceCostItemCommand {
+    /// Product whose cost changed
+    pub product_id: Uuid,
+    /// New unit cost
+    pub new_cost: Decimal,
+}
+
+/// Command to approve (apply) or reject a previewed batch reprice
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ApproveRepriceCommand {
+    /// Batch to decide on
+    pub batch_id: Uuid,
+    /// Whether to apply (true) or reject (false) the new prices
+    pub approve: bool,
+}
+
+// =============================================================================
+// Valuation Snapshot Commands
+// =============================================================================
+
+/// Command to record the period-end valuation snapshot of a store
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreateValuationSnapshotCommand {
+    /// Store to snapshot
+    pub store_id: Uuid,
+    /// Last day of the period being closed; must be before today
+    pub period_end: NaiveDate,
+    /// Currency for the snapshot (defaults to HNL)
+    #[serde(default)]
+    pub currency: Option<String>,
+}
+
+// =============================================================================
+// Period Close Commands
+// =============================================================================
+
+/// Command to close an inventory period of a store
+///
+/// Closing a range that matches an existing (reopened) period closes that
+/// period again; any other overlap with an existing period is rejected.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ClosePeriodCommand {
+    /// Store whose period is closed
+    pub store_id: Uuid,
+    /// First day of the period
+    pub period_start: NaiveDate,
+    /// Last day of the period; must be before today
+    pub period_end: NaiveDate,
+}
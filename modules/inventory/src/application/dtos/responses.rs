@@ -3,12 +3,19 @@
 // These DTOs represent the output data returned from use cases in the inventory module.
 // They are designed for API responses and include all necessary information for clients.
 
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, NaiveDate, Utc};
+use common::PageCursor;
 use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 use serde_json::Value as JsonValue;
 use uuid::Uuid;
 
+use crate::domain::entities::{
+    InventoryPeriod, ProductPriceChange, ProductSerial, ProductVendor, RepriceBatch,
+    ValuationSnapshot,
+};
+use crate::domain::value_objects::{EffectiveTaxCategory, TaxCategorySource, UnitConversion};
+
 // =============================================================================
 // Category Responses
 // =============================================================================
@@ -24,10 +31,19 @@ pub struct CategoryResponse {
     pub icon: Option<String>,
     pub sort_order: i32,
     pub is_active: bool,
+    pub tax_category: Option<String>,
+    pub costing_method: String,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
 }
 
+/// Barcode prefix configured for a category
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CategoryBarcodePrefixResponse {
+    pub category_id: Uuid,
+    pub prefix: Option<String>,
+}
+
 /// Response for hierarchical category tree
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CategoryTreeResponse {
@@ -54,6 +70,7 @@ pub struct ProductResponse {
     pub id: Uuid,
     pub sku: String,
     pub barcode: Option<String>,
+    pub barcode_generated: bool,
     pub name: String,
     pub description: Option<String>,
     pub category_id: Option<Uuid>,
@@ -67,17 +84,31 @@ pub struct ProductResponse {
     pub has_variants: bool,
     pub tax_rate: Decimal,
     pub tax_included: bool,
+    pub tax_category: Option<String>,
+    pub is_serialized: bool,
+    pub markup_type: Option<String>,
+    pub markup_percent: Option<Decimal>,
     pub is_active: bool,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
 }
 
+/// Product search hit: the product summary plus how well it matched
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProductSearchResultResponse {
+    #[serde(flatten)]
+    pub product: ProductResponse,
+    /// Higher is a better match; only comparable within one search
+    pub relevance_score: f64,
+}
+
 /// Response for product with full details including variants
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ProductDetailResponse {
     pub id: Uuid,
     pub sku: String,
     pub barcode: Option<String>,
+    pub barcode_generated: bool,
     pub name: String,
     pub description: Option<String>,
     pub category_id: Option<Uuid>,
@@ -92,13 +123,168 @@ pub struct ProductDetailResponse {
     pub has_variants: bool,
     pub tax_rate: Decimal,
     pub tax_included: bool,
+    /// Tax category set on the product itself (overrides its categories)
+    pub tax_category: Option<String>,
+    pub is_serialized: bool,
+    pub markup_type: Option<String>,
+    pub markup_percent: Option<Decimal>,
+    /// Tax category that applies after inheritance from the category tree
+    pub effective_tax_category: Option<EffectiveTaxCategoryResponse>,
     pub attributes: JsonValue,
     pub is_active: bool,
     pub variants: Vec<VariantResponse>,
+    /// Vendor that normally supplies the product
+    pub preferred_vendor: Option<ProductVendorResponse>,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
 }
 
+/// Resolved tax category of a product and where it was defined
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EffectiveTaxCategoryResponse {
+    pub tax_category: String,
+    /// "product" when overridden on the product, "category" when inherited
+    pub source: String,
+    /// Category the tax category was inherited from
+    pub source_category_id: Option<Uuid>,
+}
+
+impl From<EffectiveTaxCategory> for EffectiveTaxCategoryResponse {
+    fn from(effective: EffectiveTaxCategory) -> Self {
+        let (source, source_category_id) = match effective.source {
+            TaxCategorySource::Product => ("product", None),
+            TaxCategorySource::Category(id) => ("category", Some(id.into_uuid())),
+        };
+        Self {
+            tax_category: effective.tax_category.to_string(),
+            source: source.to_string(),
+            source_category_id,
+        }
+    }
+}
+
+/// Result of a bulk price update over a category
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BulkUpdatePricesResponse {
+    pub category_id: Uuid,
+    /// Categories whose products were considered
+    pub categories_included: i64,
+    pub products_updated: i64,
+    /// SKUs left unchanged because a new price would be negative
+    pub skipped_skus: Vec<String>,
+}
+
+/// What a catalog import did with one row
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ImportRowOutcome {
+    Created,
+    Updated,
+    Failed,
+}
+
+/// Result of one row of a catalog import
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ImportProductRowResult {
+    /// Index of the row in the request (0-based)
+    pub row: usize,
+    pub sku: String,
+    pub outcome: ImportRowOutcome,
+    /// Created or updated product
+    pub product_id: Option<Uuid>,
+    /// Why the row was rejected
+    pub error: Option<String>,
+}
+
+/// Result of a product catalog import
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ImportProductsResponse {
+    pub created: i64,
+    pub updated: i64,
+    pub failed: i64,
+    /// Categories created from row category paths
+    pub categories_created: i64,
+    pub rows: Vec<ImportProductRowResult>,
+}
+
+/// One product of a catalog export: a value for each requested column, in
+/// the requested order. Serializes as a JSON object keyed by column name.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProductExportRow {
+    pub cells: Vec<(&'static str, String)>,
+}
+
+impl ProductExportRow {
+    /// Value of the named column, if it was requested
+    pub fn get(&self, column: &str) -> Option<&str> {
+        self.cells
+            .iter()
+            .find(|(name, _)| *name == column)
+            .map(|(_, value)| value.as_str())
+    }
+
+    /// Values in column order, for CSV
+    pub fn into_values(self) -> Vec<String> {
+        self.cells.into_iter().map(|(_, value)| value).collect()
+    }
+}
+
+impl Serialize for ProductExportRow {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::SerializeMap;
+
+        let mut map = serializer.serialize_map(Some(self.cells.len()))?;
+        for (name, value) in &self.cells {
+            map.serialize_entry(name, value)?;
+        }
+        map.end()
+    }
+}
+
+/// Response for a vendor that supplies a product
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProductVendorResponse {
+    pub product_id: Uuid,
+    pub vendor_id: Uuid,
+    pub vendor_sku: Option<String>,
+    pub last_purchase_price: Option<Decimal>,
+    pub is_preferred: bool,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl From<&ProductVendor> for ProductVendorResponse {
+    fn from(vendor: &ProductVendor) -> Self {
+        Self {
+            product_id: vendor.product_id().into_uuid(),
+            vendor_id: vendor.vendor_id(),
+            vendor_sku: vendor.vendor_sku().map(|s| s.to_string()),
+            last_purchase_price: vendor.last_purchase_price(),
+            is_preferred: vendor.is_preferred(),
+            created_at: vendor.created_at(),
+            updated_at: vendor.updated_at(),
+        }
+    }
+}
+
+/// Response for a unit conversion: one `from_unit` equals `factor` `to_unit`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UnitConversionResponse {
+    pub from_unit: String,
+    pub to_unit: String,
+    pub factor: Decimal,
+}
+
+impl From<&UnitConversion> for UnitConversionResponse {
+    fn from(conversion: &UnitConversion) -> Self {
+        Self {
+            from_unit: conversion.from_unit().to_string(),
+            to_unit: conversion.to_unit().to_string(),
+            factor: conversion.factor(),
+        }
+    }
+}
+
 // =============================================================================
 // Variant Responses
 // =============================================================================
@@ -110,6 +296,7 @@ pub struct VariantResponse {
     pub product_id: Uuid,
     pub sku: String,
     pub barcode: Option<String>,
+    pub barcode_generated: bool,
     pub name: String,
     pub variant_attributes: JsonValue,
     pub price: Option<Decimal>,
@@ -138,6 +325,10 @@ pub struct StockResponse {
     pub version: i32,
     pub min_stock_level: Decimal,
     pub max_stock_level: Option<Decimal>,
+    pub reorder_point: Option<Decimal>,
+    pub reorder_quantity: Option<Decimal>,
+    /// Quantity to order, present only for low-stock records
+    pub suggested_reorder_quantity: Option<Decimal>,
     pub is_low_stock: bool,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
@@ -164,6 +355,64 @@ pub struct StockDetailResponse {
     pub updated_at: DateTime<Utc>,
 }
 
+/// Response for a weighted-average cost recalculation
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AverageCostRecalculationResponse {
+    pub stock_id: Uuid,
+    pub store_id: Uuid,
+    pub product_id: Uuid,
+    /// Movements replayed from the kardex
+    pub movements_replayed: i64,
+    /// Movements whose stored average cost changed
+    pub movements_rewritten: i64,
+    /// Average cost before the recalculation
+    pub previous_average_cost: Option<Decimal>,
+    /// Average cost after replaying every movement
+    pub average_cost: Option<Decimal>,
+    /// `average_cost - previous_average_cost`, a missing cost counting as zero
+    pub cost_delta: Decimal,
+}
+
+// =============================================================================
+// Serial Number Responses
+// =============================================================================
+
+/// Response for a serialized unit
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProductSerialResponse {
+    pub id: Uuid,
+    pub product_id: Uuid,
+    pub variant_id: Option<Uuid>,
+    pub store_id: Uuid,
+    pub serial_number: String,
+    pub status: String,
+    pub sale_id: Option<Uuid>,
+    pub sale_item_id: Option<Uuid>,
+    pub sold_at: Option<DateTime<Utc>>,
+    pub credit_note_id: Option<Uuid>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl From<&ProductSerial> for ProductSerialResponse {
+    fn from(serial: &ProductSerial) -> Self {
+        Self {
+            id: serial.id().into_uuid(),
+            product_id: serial.product_id().into_uuid(),
+            variant_id: serial.variant_id().map(|v| v.into_uuid()),
+            store_id: serial.store_id().into_uuid(),
+            serial_number: serial.serial_number().to_string(),
+            status: serial.status().to_string(),
+            sale_id: serial.sale_id(),
+            sale_item_id: serial.sale_item_id(),
+            sold_at: serial.sold_at(),
+            credit_note_id: serial.credit_note_id(),
+            created_at: serial.created_at(),
+            updated_at: serial.updated_at(),
+        }
+    }
+}
+
 // =============================================================================
 // Reservation Responses
 // =============================================================================
@@ -177,7 +426,13 @@ pub struct ReservationResponse {
     pub reference_id: Uuid,
     pub quantity: Decimal,
     pub status: String,
+    pub priority: i32,
     pub expires_at: DateTime<Utc>,
+    /// Seconds each touch extends the reservation by, if enabled
+    pub auto_extend_seconds: Option<i64>,
+    /// Set when a higher-priority reservation took over this one's stock
+    pub bumped_at: Option<DateTime<Utc>>,
+    pub bump_reason: Option<String>,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
 }
@@ -236,6 +491,7 @@ pub struct RecipeResponse {
     pub description: Option<String>,
     pub version: i32,
     pub yield_quantity: Decimal,
+    pub yield_unit: String,
     pub is_active: bool,
     pub preparation_time_minutes: Option<i32>,
     pub calculate_cost_from_ingredients: bool,
@@ -256,6 +512,7 @@ pub struct RecipeDetailResponse {
     pub description: Option<String>,
     pub version: i32,
     pub yield_quantity: Decimal,
+    pub yield_unit: String,
     pub is_active: bool,
     pub preparation_time_minutes: Option<i32>,
     pub calculate_cost_from_ingredients: bool,
@@ -305,6 +562,39 @@ pub struct IngredientSubstituteResponse {
     pub created_at: DateTime<Utc>,
 }
 
+/// Recomputed cost of one composite (recipe-costed) product or variant
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CompositeCostChangeResponse {
+    pub recipe_id: Uuid,
+    pub recipe_name: String,
+    pub product_id: Option<Uuid>,
+    pub variant_id: Option<Uuid>,
+    pub previous_cost: Decimal,
+    pub new_cost: Decimal,
+    /// new_cost - previous_cost
+    pub change: Decimal,
+    pub price: Decimal,
+    /// The new cost exceeds the selling price
+    pub margin_eroded: bool,
+    /// Ingredients with neither a weighted-average nor an estimated cost
+    pub ingredients_without_cost: usize,
+    /// cost_price was updated to new_cost
+    pub adjusted: bool,
+}
+
+/// Result of a composite cost refresh run
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RefreshCompositeCostsResponse {
+    pub composites_evaluated: usize,
+    /// Composites whose recomputed cost differs from the stored cost_price
+    pub changed: Vec<CompositeCostChangeResponse>,
+    /// Recipe IDs whose recomputed cost exceeds their price (changed or not)
+    pub margin_eroded: Vec<Uuid>,
+    /// Recipe IDs skipped because they are in, or depend on, an ingredient cycle
+    pub cycle_recipe_ids: Vec<Uuid>,
+    pub auto_adjust: bool,
+}
+
 // =============================================================================
 // Adjustment Responses
 // =============================================================================
@@ -321,6 +611,7 @@ pub struct AdjustmentResponse {
     pub created_by_id: Uuid,
     pub approved_by_id: Option<Uuid>,
     pub approved_at: Option<DateTime<Utc>>,
+    pub auto_approved: bool,
     pub applied_at: Option<DateTime<Utc>>,
     pub notes: Option<String>,
     pub item_count: i32,
@@ -340,6 +631,7 @@ pub struct AdjustmentDetailResponse {
     pub created_by_id: Uuid,
     pub approved_by_id: Option<Uuid>,
     pub approved_at: Option<DateTime<Utc>>,
+    pub auto_approved: bool,
     pub applied_at: Option<DateTime<Utc>>,
     pub notes: Option<String>,
     pub attachments: Option<JsonValue>,
@@ -384,6 +676,11 @@ pub struct TransferResponse {
     pub notes: Option<String>,
     pub shipping_method: Option<String>,
     pub tracking_number: Option<String>,
+    pub requires_approval: bool,
+    pub approval_reason: Option<String>,
+    pub approved_by_id: Option<Uuid>,
+    pub approved_at: Option<DateTime<Utc>>,
+    pub approval_notes: Option<String>,
     pub item_count: i32,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
@@ -406,6 +703,11 @@ pub struct TransferDetailResponse {
     pub notes: Option<String>,
     pub shipping_method: Option<String>,
     pub tracking_number: Option<String>,
+    pub requires_approval: bool,
+    pub approval_reason: Option<String>,
+    pub approved_by_id: Option<Uuid>,
+    pub approved_at: Option<DateTime<Utc>>,
+    pub approval_notes: Option<String>,
     pub items: Vec<TransferItemResponse>,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
@@ -423,11 +725,115 @@ pub struct TransferItemResponse {
     pub quantity_requested: Decimal,
     pub quantity_shipped: Option<Decimal>,
     pub quantity_received: Option<Decimal>,
+    /// Shipped minus received, once the transfer has been received
+    pub quantity_discrepancy: Option<Decimal>,
     pub unit_cost: Option<Decimal>,
     pub notes: Option<String>,
     pub created_at: DateTime<Utc>,
 }
 
+// =============================================================================
+// Reprice Responses
+// =============================================================================
+
+/// Response for a batch reprice with its previewed lines
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RepriceBatchResponse {
+    pub id: Uuid,
+    pub vendor_id: Option<Uuid>,
+    pub reason: Option<String>,
+    pub status: String,
+    pub lines: Vec<RepriceLineResponse>,
+    /// Lines whose price follows the new cost
+    pub repriced_count: usize,
+    /// Manually priced lines that keep their price
+    pub excluded_count: usize,
+    pub created_by_id: Uuid,
+    pub decided_by_id: Option<Uuid>,
+    pub decided_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// Previewed cost and price change of one product
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RepriceLineResponse {
+    pub product_id: Uuid,
+    pub product_name: String,
+    pub markup_type: Option<String>,
+    pub markup_percent: Option<Decimal>,
+    pub old_cost: Decimal,
+    pub new_cost: Decimal,
+    pub old_price: Decimal,
+    pub new_price: Option<Decimal>,
+    /// True for manually priced products, which keep their price
+    pub excluded: bool,
+}
+
+impl From<&RepriceBatch> for RepriceBatchResponse {
+    fn from(batch: &RepriceBatch) -> Self {
+        let excluded_count = batch.lines().iter().filter(|l| l.is_excluded()).count();
+        Self {
+            id: batch.id().into_uuid(),
+            vendor_id: batch.vendor_id(),
+            reason: batch.reason().map(|r| r.to_string()),
+            status: batch.status().to_string(),
+            lines: batch
+                .lines()
+                .iter()
+                .map(|line| RepriceLineResponse {
+                    product_id: line.product_id().into_uuid(),
+                    product_name: line.product_name().to_string(),
+                    markup_type: line.markup_rule().map(|r| r.markup_type().to_string()),
+                    markup_percent: line.markup_rule().and_then(|r| r.percent()),
+                    old_cost: line.old_cost(),
+                    new_cost: line.new_cost(),
+                    old_price: line.old_price(),
+                    new_price: line.new_price(),
+                    excluded: line.is_excluded(),
+                })
+                .collect(),
+            repriced_count: batch.lines().len() - excluded_count,
+            excluded_count,
+            created_by_id: batch.created_by_id().into_uuid(),
+            decided_by_id: batch.decided_by_id().map(|id| id.into_uuid()),
+            decided_at: batch.decided_at(),
+            created_at: batch.created_at(),
+            updated_at: batch.updated_at(),
+        }
+    }
+}
+
+/// Response for a product price history record
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PriceChangeResponse {
+    pub id: Uuid,
+    pub product_id: Uuid,
+    pub old_cost: Decimal,
+    pub new_cost: Decimal,
+    pub old_price: Decimal,
+    pub new_price: Decimal,
+    pub reprice_batch_id: Option<Uuid>,
+    pub changed_by_id: Uuid,
+    pub changed_at: DateTime<Utc>,
+}
+
+impl From<&ProductPriceChange> for PriceChangeResponse {
+    fn from(change: &ProductPriceChange) -> Self {
+        Self {
+            id: change.id().into_uuid(),
+            product_id: change.product_id().into_uuid(),
+            old_cost: change.old_cost(),
+            new_cost: change.new_cost(),
+            old_price: change.old_price(),
+            new_price: change.new_price(),
+            reprice_batch_id: change.reprice_batch_id().map(|id| id.into_uuid()),
+            changed_by_id: change.changed_by_id().into_uuid(),
+            changed_at: change.changed_at(),
+        }
+    }
+}
+
 // =============================================================================
 // List Response (Simple)
 // =============================================================================
@@ -470,6 +876,9 @@ pub struct PaginatedResponse<T> {
     pub has_next: bool,
     /// Whether there is a previous page
     pub has_previous: bool,
+    /// Cursor for the next page in keyset mode (pass back as `after_cursor`).
+    /// Only set by lists that support it, and only when the page was full.
+    pub next_cursor: Option<String>,
 }
 
 impl<T> PaginatedResponse<T> {
@@ -488,8 +897,36 @@ impl<T> PaginatedResponse<T> {
             total_pages,
             has_next: page < total_pages,
             has_previous: page > 1,
+            next_cursor: None,
         }
     }
+
+    /// Page of a list that supports keyset paging. With an `after` cursor
+    /// the page number is meaningless, so it is reported as 1 and clients
+    /// follow `next_cursor`. Offset pages get a `next_cursor` too, so a client
+    /// can switch to cursors after the first page. `last_id` is the id of the
+    /// last row returned.
+    pub fn keyset(
+        items: Vec<T>,
+        after: Option<PageCursor>,
+        page: i64,
+        page_size: i64,
+        total_items: i64,
+        last_id: Option<Uuid>,
+    ) -> Self {
+        let next_cursor = PageCursor::next(last_id, items.len(), page_size);
+        let mut response = match after {
+            Some(_) => {
+                let mut response = Self::new(items, 1, page_size, total_items);
+                response.has_next = next_cursor.is_some();
+                response.has_previous = true;
+                response
+            }
+            None => Self::new(items, page, page_size, total_items),
+        };
+        response.next_cursor = next_cursor;
+        response
+    }
 }
 
 // =============================================================================
@@ -507,18 +944,269 @@ pub struct ValuationItemResponse {
     pub variant_name: Option<String>,
     pub sku: Option<String>,
     pub quantity: Decimal,
+    /// Costing method of the product's category ("weighted_average", "fifo", "lifo")
+    pub costing_method: String,
+    /// Weighted average cost, or total_value / quantity under FIFO/LIFO
     pub unit_cost: Decimal,
     pub total_value: Decimal,
     pub currency: String,
 }
 
+/// Stock of one transfer line that has shipped but not been received.
+///
+/// `owner_store_id` is the store the stock counts toward while in transit:
+/// the source store, unless its settings attribute transit stock to the
+/// destination.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct InTransitItemResponse {
+    pub transfer_id: Uuid,
+    pub transfer_number: String,
+    pub from_store_id: Uuid,
+    pub to_store_id: Uuid,
+    pub owner_store_id: Uuid,
+    pub product_id: Option<Uuid>,
+    pub variant_id: Option<Uuid>,
+    pub quantity: Decimal,
+    pub unit_cost: Decimal,
+    pub total_value: Decimal,
+    pub shipped_date: Option<DateTime<Utc>>,
+}
+
+/// In-transit totals for one store
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct InTransitStoreTotalResponse {
+    pub store_id: Uuid,
+    /// Quantity shipped from this store and not yet received
+    pub outbound_quantity: Decimal,
+    /// Quantity on its way to this store
+    pub inbound_quantity: Decimal,
+    /// Value of the in-transit stock attributed to this store
+    pub owned_value: Decimal,
+}
+
+/// Response for the in-transit stock report
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct InTransitStockResponse {
+    pub items: Vec<InTransitItemResponse>,
+    pub stores: Vec<InTransitStoreTotalResponse>,
+    pub total_quantity: Decimal,
+    pub total_value: Decimal,
+    pub generated_at: DateTime<Utc>,
+}
+
+/// A shipped transfer that has not been received yet
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct InTransitTransferResponse {
+    pub transfer_id: Uuid,
+    pub transfer_number: String,
+    pub from_store_id: Uuid,
+    pub to_store_id: Uuid,
+    pub shipped_date: Option<DateTime<Utc>>,
+    pub days_in_transit: i64,
+    /// In transit for longer than the report's threshold
+    pub is_overdue: bool,
+    pub shipping_method: Option<String>,
+    pub tracking_number: Option<String>,
+    pub total_items: i64,
+    pub total_quantity: Decimal,
+    /// Cost of the goods when they were shipped
+    pub total_value: Decimal,
+}
+
+/// In-transit transfers headed to one store
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct InTransitDestinationResponse {
+    pub to_store_id: Uuid,
+    pub transfers: Vec<InTransitTransferResponse>,
+    pub total_transfers: i64,
+    pub overdue_transfers: i64,
+    pub total_value: Decimal,
+}
+
+/// Response for the in-transit transfers report
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct InTransitReportResponse {
+    pub destinations: Vec<InTransitDestinationResponse>,
+    pub total_transfers: i64,
+    pub overdue_transfers: i64,
+    pub total_value: Decimal,
+    pub overdue_after_days: i64,
+    pub generated_at: DateTime<Utc>,
+}
+
 /// Response for inventory valuation report
+///
+/// `total_value` is on-hand stock plus stock in transit attributed to the
+/// reported store(s), so multi-day transfers do not show as a loss.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ValuationReportResponse {
+    pub items: Vec<ValuationItemResponse>,
+    pub in_transit_items: Vec<InTransitItemResponse>,
+    pub total_items: i64,
+    pub on_hand_value: Decimal,
+    pub in_transit_value: Decimal,
+    pub total_value: Decimal,
+    pub currency: String,
+    pub generated_at: DateTime<Utc>,
+}
+
+/// Period-end valuation snapshot of a store
+///
+/// `items` are the store's stock records as valued when the snapshot was
+/// recorded; they never change afterwards.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ValuationSnapshotResponse {
+    pub id: Uuid,
+    pub store_id: Uuid,
+    pub period_end: NaiveDate,
     pub items: Vec<ValuationItemResponse>,
     pub total_items: i64,
     pub total_value: Decimal,
     pub currency: String,
+    pub created_by_id: Uuid,
+    pub created_at: DateTime<Utc>,
+}
+
+impl From<&ValuationSnapshot> for ValuationSnapshotResponse {
+    fn from(snapshot: &ValuationSnapshot) -> Self {
+        let store_id = snapshot.store_id().into_uuid();
+        let items: Vec<ValuationItemResponse> = snapshot
+            .lines()
+            .iter()
+            .map(|line| ValuationItemResponse {
+                stock_id: line.stock_id.into_uuid(),
+                store_id,
+                product_id: line.product_id.map(|id| id.into_uuid()),
+                variant_id: line.variant_id.map(|id| id.into_uuid()),
+                product_name: line.product_name.clone(),
+                variant_name: line.variant_name.clone(),
+                sku: line.sku.clone(),
+                quantity: line.quantity,
+                costing_method: line.costing_method.clone(),
+                unit_cost: line.unit_cost,
+                total_value: line.total_value,
+                currency: snapshot.currency().to_string(),
+            })
+            .collect();
+
+        Self {
+            id: snapshot.id().into_uuid(),
+            store_id,
+            period_end: snapshot.period_end(),
+            total_items: items.len() as i64,
+            items,
+            total_value: snapshot.total_value(),
+            currency: snapshot.currency().to_string(),
+            created_by_id: snapshot.created_by_id().into_uuid(),
+            created_at: snapshot.created_at(),
+        }
+    }
+}
+
+/// Response DTO for an inventory period
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct InventoryPeriodResponse {
+    pub id: Uuid,
+    pub store_id: Uuid,
+    pub period_start: NaiveDate,
+    pub period_end: NaiveDate,
+    pub status: String,
+    pub closed_by_id: Option<Uuid>,
+    pub closed_at: Option<DateTime<Utc>>,
+    pub reopened_by_id: Option<Uuid>,
+    pub reopened_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl From<&InventoryPeriod> for InventoryPeriodResponse {
+    fn from(period: &InventoryPeriod) -> Self {
+        Self {
+            id: period.id().into_uuid(),
+            store_id: period.store_id().into_uuid(),
+            period_start: period.period_start(),
+            period_end: period.period_end(),
+            status: period.status().to_string(),
+            closed_by_id: period.closed_by_id().map(|id| id.into_uuid()),
+            closed_at: period.closed_at(),
+            reopened_by_id: period.reopened_by_id().map(|id| id.into_uuid()),
+            reopened_at: period.reopened_at(),
+            created_at: period.created_at(),
+            updated_at: period.updated_at(),
+        }
+    }
+}
+
+/// One node of the inventory-by-category rollup tree.
+///
+/// `own_*` covers products assigned directly to the category; `rolled_up_*`
+/// adds the whole subtree. Values are None when the caller may not see costs.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CategoryRollupNode {
+    pub category_id: Option<Uuid>,
+    pub parent_id: Option<Uuid>,
+    pub name: String,
+    pub own_quantity: Decimal,
+    pub own_value: Option<Decimal>,
+    pub rolled_up_quantity: Decimal,
+    pub rolled_up_value: Option<Decimal>,
+    pub children: Vec<CategoryRollupNode>,
+}
+
+/// Response for the inventory-by-category rollup report
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CategoryRollupReportResponse {
+    pub store_id: Option<Uuid>,
+    pub nodes: Vec<CategoryRollupNode>,
+    pub total_quantity: Decimal,
+    pub total_value: Option<Decimal>,
+    pub costs_visible: bool,
+    pub currency: String,
+    pub generated_at: DateTime<Utc>,
+}
+
+/// Lifecycle counts for a group of reservations.
+///
+/// `conversion_rate` is the percentage of resolved reservations (confirmed,
+/// expired or cancelled) that were confirmed; pending holds are excluded
+/// because their outcome is not known yet.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ReservationConversionStats {
+    pub created: i64,
+    pub confirmed: i64,
+    pub expired: i64,
+    pub cancelled: i64,
+    pub pending: i64,
+    pub quantity_reserved: Decimal,
+    pub quantity_converted: Decimal,
+    pub conversion_rate: Option<Decimal>,
+}
+
+/// Conversion of the reservations held for one reference type (e.g. cart, order)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReservationChannelConversion {
+    pub reference_type: String,
+    #[serde(flatten)]
+    pub stats: ReservationConversionStats,
+}
+
+/// Conversion of the reservations created on one day (UTC)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReservationDayConversion {
+    pub day: NaiveDate,
+    #[serde(flatten)]
+    pub stats: ReservationConversionStats,
+}
+
+/// Response for the reservation conversion (hold-to-sale) report
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReservationConversionReportResponse {
+    pub store_id: Uuid,
+    pub from_date: DateTime<Utc>,
+    pub to_date: DateTime<Utc>,
+    pub totals: ReservationConversionStats,
+    pub by_channel: Vec<ReservationChannelConversion>,
+    pub by_day: Vec<ReservationDayConversion>,
     pub generated_at: DateTime<Utc>,
 }
 
@@ -535,6 +1223,8 @@ pub struct LowStockItemResponse {
     pub current_quantity: Decimal,
     pub available_quantity: Decimal,
     pub min_stock_level: Decimal,
+    /// Store's reorder point, or min_stock_level when it has none
+    pub reorder_point: Decimal,
     pub shortage: Decimal,
     pub reorder_suggestion: Decimal,
 }
@@ -546,3 +1236,77 @@ pub struct LowStockReportResponse {
     pub total_items: i64,
     pub generated_at: DateTime<Utc>,
 }
+
+/// Response for a single lot in the expiring stock report
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExpiringLotResponse {
+    pub lot_id: Uuid,
+    pub store_id: Uuid,
+    pub product_id: Uuid,
+    pub variant_id: Option<Uuid>,
+    pub product_name: Option<String>,
+    pub variant_name: Option<String>,
+    pub sku: Option<String>,
+    pub lot_number: Option<String>,
+    pub expiry_date: Option<NaiveDate>,
+    /// Negative once the lot has expired
+    pub days_until_expiry: i64,
+    pub is_expired: bool,
+    pub remaining_quantity: Decimal,
+}
+
+/// Response for expiring stock report
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExpiringStockReportResponse {
+    pub items: Vec<ExpiringLotResponse>,
+    pub total_items: i64,
+    pub total_quantity: Decimal,
+    /// Window the report looked ahead, in days
+    pub days: i64,
+    pub generated_at: DateTime<Utc>,
+}
+
+/// Quantity and value of on-hand stock falling in one age bucket
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct AgingBucketResponse {
+    pub quantity: Decimal,
+    pub value: Decimal,
+}
+
+/// On-hand stock of one stock record split by days since it was received
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct InventoryAgingItemResponse {
+    pub stock_id: Uuid,
+    pub store_id: Uuid,
+    pub product_id: Option<Uuid>,
+    pub variant_id: Option<Uuid>,
+    pub product_name: Option<String>,
+    pub variant_name: Option<String>,
+    pub sku: Option<String>,
+    pub quantity: Decimal,
+    pub total_value: Decimal,
+    pub days_0_30: AgingBucketResponse,
+    pub days_31_60: AgingBucketResponse,
+    pub days_61_90: AgingBucketResponse,
+    pub days_over_90: AgingBucketResponse,
+    /// Units no receipt on the kardex accounts for
+    pub unknown_age: AgingBucketResponse,
+    /// True when the kardex has no receipt at all for this stock record
+    pub age_unknown: bool,
+    pub currency: String,
+}
+
+/// Response for the inventory aging report, with bucket totals
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct InventoryAgingReportResponse {
+    pub items: Vec<InventoryAgingItemResponse>,
+    pub total_items: i64,
+    pub days_0_30: AgingBucketResponse,
+    pub days_31_60: AgingBucketResponse,
+    pub days_61_90: AgingBucketResponse,
+    pub days_over_90: AgingBucketResponse,
+    pub unknown_age: AgingBucketResponse,
+    pub total_value: Decimal,
+    pub currency: String,
+    pub generated_at: DateTime<Utc>,
+}
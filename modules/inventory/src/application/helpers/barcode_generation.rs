@@ -0,0 +1,198 @@
+use crate::InventoryError;
+use crate::domain::repositories::BarcodeSequenceRepository;
+use crate::domain::value_objects::{Barcode, CategoryId};
+
+/// Upper bound on sequence values tried before giving up on a free barcode.
+pub const MAX_BARCODE_ATTEMPTS: usize = 20;
+
+/// Generates an EAN-13 barcode from the prefix configured for `category_id`.
+///
+/// Returns None when there is no category or the category has no prefix, in
+/// which case the item is created without a barcode as before. Sequence
+/// values are handed out atomically by the repository, so concurrent callers
+/// never build the same barcode; values whose barcode was already entered by
+/// hand on another product or variant are skipped.
+pub async fn generate_category_barcode<B>(
+    barcode_repo: &B,
+    category_id: Option<CategoryId>,
+) -> Result<Option<Barcode>, InventoryError>
+where
+    B: BarcodeSequenceRepository + ?Sized,
+{
+    let Some(category_id) = category_id else {
+        return Ok(None);
+    };
+    let Some(prefix) = barcode_repo.find_prefix(category_id).await? else {
+        return Ok(None);
+    };
+
+    let mut last = None;
+    for _ in 0..MAX_BARCODE_ATTEMPTS {
+        let value = barcode_repo.next_value(&prefix).await?;
+        let barcode = Barcode::ean13(&prefix, value)?;
+        if !barcode_repo.barcode_exists(&barcode).await? {
+            return Ok(Some(barcode));
+        }
+        last = Some(barcode);
+    }
+
+    Err(InventoryError::DuplicateBarcode(
+        last.map(|b| b.as_str().to_string()).unwrap_or_default(),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::value_objects::{BarcodePrefix, ean13_check_digit};
+    use async_trait::async_trait;
+    use std::collections::{HashMap, HashSet};
+    use std::sync::{Arc, Mutex};
+
+    struct MockBarcodeSequenceRepository {
+        prefixes: HashMap<CategoryId, BarcodePrefix>,
+        sequences: Mutex<HashMap<BarcodePrefix, u64>>,
+        taken: HashSet<String>,
+    }
+
+    impl MockBarcodeSequenceRepository {
+        fn new(category_id: CategoryId, prefix: &str, taken: &[Barcode]) -> Self {
+            Self {
+                prefixes: HashMap::from([(category_id, BarcodePrefix::new(prefix).unwrap())]),
+                sequences: Mutex::new(HashMap::new()),
+                taken: taken.iter().map(|b| b.as_str().to_string()).collect(),
+            }
+        }
+    }
+
+    #[async_trait]
+    impl BarcodeSequenceRepository for MockBarcodeSequenceRepository {
+        async fn find_prefix(
+            &self,
+            category_id: CategoryId,
+        ) -> Result<Option<BarcodePrefix>, InventoryError> {
+            Ok(self.prefixes.get(&category_id).cloned())
+        }
+
+        async fn save_prefix(
+            &self,
+            _category_id: CategoryId,
+            _prefix: Option<&BarcodePrefix>,
+        ) -> Result<(), InventoryError> {
+            unimplemented!()
+        }
+
+        async fn next_value(&self, prefix: &BarcodePrefix) -> Result<u64, InventoryError> {
+            let mut sequences = self.sequences.lock().unwrap();
+            let value = sequences.entry(prefix.clone()).or_insert(0);
+            *value += 1;
+            Ok(*value)
+        }
+
+        async fn barcode_exists(&self, barcode: &Barcode) -> Result<bool, InventoryError> {
+            tokio::task::yield_now().await;
+            Ok(self.taken.contains(barcode.as_str()))
+        }
+    }
+
+    #[tokio::test]
+    async fn test_no_prefix_generates_nothing() {
+        let category_id = CategoryId::new();
+        let repo = MockBarcodeSequenceRepository::new(category_id, "740123", &[]);
+
+        assert!(
+            generate_category_barcode(&repo, None)
+                .await
+                .unwrap()
+                .is_none()
+        );
+        assert!(
+            generate_category_barcode(&repo, Some(CategoryId::new()))
+                .await
+                .unwrap()
+                .is_none()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_generated_barcode_has_valid_check_digit() {
+        let category_id = CategoryId::new();
+        let repo = MockBarcodeSequenceRepository::new(category_id, "740123", &[]);
+
+        let barcode = generate_category_barcode(&repo, Some(category_id))
+            .await
+            .unwrap()
+            .unwrap();
+
+        let value = barcode.as_str();
+        assert_eq!(&value[..12], "740123000001");
+        assert_eq!(value.as_bytes()[12] - b'0', ean13_check_digit(&value[..12]));
+    }
+
+    #[tokio::test]
+    async fn test_skips_manually_entered_barcodes() {
+        let category_id = CategoryId::new();
+        let prefix = BarcodePrefix::new("740123").unwrap();
+        let manual = vec![
+            Barcode::ean13(&prefix, 1).unwrap(),
+            Barcode::ean13(&prefix, 2).unwrap(),
+        ];
+        let repo = MockBarcodeSequenceRepository::new(category_id, "740123", &manual);
+
+        let barcode = generate_category_barcode(&repo, Some(category_id))
+            .await
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(barcode, Barcode::ean13(&prefix, 3).unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_gives_up_when_every_candidate_is_taken() {
+        let category_id = CategoryId::new();
+        let prefix = BarcodePrefix::new("740123").unwrap();
+        let manual: Vec<Barcode> = (1..=MAX_BARCODE_ATTEMPTS as u64)
+            .map(|v| Barcode::ean13(&prefix, v).unwrap())
+            .collect();
+        let repo = MockBarcodeSequenceRepository::new(category_id, "740123", &manual);
+
+        let result = generate_category_barcode(&repo, Some(category_id)).await;
+
+        assert!(matches!(result, Err(InventoryError::DuplicateBarcode(_))));
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+    async fn test_concurrent_generation_yields_unique_barcodes() {
+        let category_id = CategoryId::new();
+        let prefix = BarcodePrefix::new("740123").unwrap();
+        let manual: Vec<Barcode> = [3u64, 7, 50, 51]
+            .iter()
+            .map(|&v| Barcode::ean13(&prefix, v).unwrap())
+            .collect();
+        let repo = Arc::new(MockBarcodeSequenceRepository::new(
+            category_id,
+            "740123",
+            &manual,
+        ));
+
+        let handles: Vec<_> = (0..200)
+            .map(|_| {
+                let repo = Arc::clone(&repo);
+                tokio::spawn(async move {
+                    generate_category_barcode(repo.as_ref(), Some(category_id))
+                        .await
+                        .unwrap()
+                        .unwrap()
+                })
+            })
+            .collect();
+
+        let mut generated = HashSet::new();
+        for handle in handles {
+            let barcode = handle.await.unwrap();
+            assert!(!manual.contains(&barcode));
+            assert!(generated.insert(barcode), "barcode generated twice");
+        }
+        assert_eq!(generated.len(), 200);
+    }
+}
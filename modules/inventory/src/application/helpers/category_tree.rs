@@ -0,0 +1,94 @@
+use std::collections::HashSet;
+
+use crate::InventoryError;
+use crate::domain::repositories::CategoryRepository;
+use crate::domain::value_objects::CategoryId;
+
+/// Default limit on category levels, the root category being level 1.
+pub const DEFAULT_MAX_CATEGORY_DEPTH: usize = 5;
+
+/// Checks that a category can be placed under `parent_id`.
+///
+/// `category_id` is the category being moved, with its whole subtree, or
+/// None for a new category. Placing a category under itself or one of its
+/// descendants is a cycle, and the deepest level of the subtree may not
+/// exceed `max_depth`.
+///
+/// # Errors
+/// * `InventoryError::CategoryCycle` - If `parent_id` is `category_id` or
+///   one of its descendants
+/// * `InventoryError::CategoryDepthExceeded` - If the placement goes deeper
+///   than `max_depth`
+pub async fn validate_category_placement<C>(
+    category_repo: &C,
+    category_id: Option<CategoryId>,
+    parent_id: CategoryId,
+    max_depth: usize,
+) -> Result<(), InventoryError>
+where
+    C: CategoryRepository + ?Sized,
+{
+    let ancestry = category_ancestry(category_repo, parent_id).await?;
+    if category_id.is_some_and(|id| ancestry.contains(&id)) {
+        return Err(InventoryError::CategoryCycle);
+    }
+
+    let height = match category_id {
+        Some(id) => subtree_height(category_repo, id).await?,
+        None => 1,
+    };
+    if ancestry.len() + height > max_depth {
+        return Err(InventoryError::CategoryDepthExceeded(max_depth));
+    }
+    Ok(())
+}
+
+/// Ids from `category_id` up to its root, `category_id` first.
+pub async fn category_ancestry<C>(
+    category_repo: &C,
+    category_id: CategoryId,
+) -> Result<Vec<CategoryId>, InventoryError>
+where
+    C: CategoryRepository + ?Sized,
+{
+    let mut ancestry = Vec::new();
+    let mut next = Some(category_id);
+    while let Some(id) = next {
+        // Stop on cycles already in the data rather than looping forever
+        if ancestry.contains(&id) {
+            break;
+        }
+        ancestry.push(id);
+        next = category_repo
+            .find_by_id(id)
+            .await?
+            .and_then(|category| category.parent_id());
+    }
+    Ok(ancestry)
+}
+
+/// Number of levels in the subtree rooted at `category_id`, 1 for a leaf.
+async fn subtree_height<C>(
+    category_repo: &C,
+    category_id: CategoryId,
+) -> Result<usize, InventoryError>
+where
+    C: CategoryRepository + ?Sized,
+{
+    let mut visited = HashSet::from([category_id]);
+    let mut level = vec![category_id];
+    let mut height = 0;
+    while !level.is_empty() {
+        height += 1;
+        let mut next = Vec::new();
+        for id in level {
+            for child in category_repo.find_children(id).await? {
+                if visited.insert(child.id()) {
+                    next.push(child.id());
+                }
+            }
+        }
+        level = next;
+    }
+    Ok(height)
+}
@@ -1,3 +1,20 @@
+pub mod barcode_generation;
+pub mod category_tree;
+pub mod period_close;
 pub mod retry;
+pub mod stock_posting;
+pub mod tax_category;
 
-pub use retry::{DEFAULT_BASE_DELAY_MS, DEFAULT_MAX_ATTEMPTS, retry_on_conflict};
+pub use barcode_generation::{MAX_BARCODE_ATTEMPTS, generate_category_barcode};
+pub use category_tree::{
+    DEFAULT_MAX_CATEGORY_DEPTH, category_ancestry, validate_category_placement,
+};
+pub use period_close::ensure_period_open;
+pub use retry::{
+    DEFAULT_BASE_DELAY_MS, DEFAULT_MAX_ATTEMPTS, retry_on_conflict, retry_on_conflict_counted,
+};
+pub use stock_posting::{
+    DEFAULT_STOCK_POSTING_BATCH_SIZE, InboundPostingContext, InboundPostingPlan, InboundStockLine,
+    plan_inbound_posting,
+};
+pub use tax_category::resolve_effective_tax_category;
@@ -0,0 +1,127 @@
+use chrono::NaiveDate;
+use identity::StoreId;
+
+use crate::InventoryError;
+use crate::domain::repositories::InventoryPeriodRepository;
+
+/// Checks that an operation dated `date` can still be posted at a store.
+///
+/// `date` is the document date of the operation (receipt date, sale date,
+/// ...), never the time it is posted. Operations dated in a closed period
+/// are rejected unless `can_override` is set (e.g. users holding
+/// `inventory:reopen_period`).
+///
+/// # Errors
+/// * `InventoryError::PeriodClosed` - With the period's range, if `date`
+///   falls in a closed period and `can_override` is false
+pub async fn ensure_period_open<R>(
+    period_repo: &R,
+    store_id: StoreId,
+    date: NaiveDate,
+    can_override: bool,
+) -> Result<(), InventoryError>
+where
+    R: InventoryPeriodRepository + ?Sized,
+{
+    if can_override {
+        return Ok(());
+    }
+    match period_repo.find_closed_containing(store_id, date).await? {
+        Some(period) => Err(InventoryError::PeriodClosed(
+            period.period_start(),
+            period.period_end(),
+        )),
+        None => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use async_trait::async_trait;
+
+    use crate::domain::entities::InventoryPeriod;
+    use crate::domain::value_objects::InventoryPeriodId;
+    use identity::UserId;
+
+    struct Periods(Vec<InventoryPeriod>);
+
+    #[async_trait]
+    impl InventoryPeriodRepository for Periods {
+        async fn save(&self, _period: &InventoryPeriod) -> Result<(), InventoryError> {
+            unimplemented!()
+        }
+
+        async fn update(&self, _period: &InventoryPeriod) -> Result<(), InventoryError> {
+            unimplemented!()
+        }
+
+        async fn find_by_id(
+            &self,
+            _id: InventoryPeriodId,
+        ) -> Result<Option<InventoryPeriod>, InventoryError> {
+            unimplemented!()
+        }
+
+        async fn find_overlapping(
+            &self,
+            _store_id: StoreId,
+            _start: NaiveDate,
+            _end: NaiveDate,
+        ) -> Result<Vec<InventoryPeriod>, InventoryError> {
+            unimplemented!()
+        }
+
+        async fn find_closed_containing(
+            &self,
+            store_id: StoreId,
+            date: NaiveDate,
+        ) -> Result<Option<InventoryPeriod>, InventoryError> {
+            Ok(self
+                .0
+                .iter()
+                .find(|p| p.store_id() == store_id && p.is_closed() && p.contains(date))
+                .cloned())
+        }
+
+        async fn find_by_store(
+            &self,
+            _store_id: StoreId,
+        ) -> Result<Vec<InventoryPeriod>, InventoryError> {
+            unimplemented!()
+        }
+    }
+
+    fn date(day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2026, 9, day).unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_operations_dated_in_closed_period_are_rejected() {
+        let store_id = StoreId::new();
+        let mut closed = InventoryPeriod::create(store_id, date(1), date(15)).unwrap();
+        closed.close(UserId::new()).unwrap();
+        let open = InventoryPeriod::create(store_id, date(16), date(30)).unwrap();
+        let repo = Periods(vec![closed, open]);
+
+        assert!(matches!(
+            ensure_period_open(&repo, store_id, date(15), false).await,
+            Err(InventoryError::PeriodClosed(start, end)) if start == date(1) && end == date(15)
+        ));
+        assert!(
+            ensure_period_open(&repo, store_id, date(15), true)
+                .await
+                .is_ok()
+        );
+        assert!(
+            ensure_period_open(&repo, store_id, date(16), false)
+                .await
+                .is_ok()
+        );
+        assert!(
+            ensure_period_open(&repo, StoreId::new(), date(1), false)
+                .await
+                .is_ok()
+        );
+    }
+}
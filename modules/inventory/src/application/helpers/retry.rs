@@ -23,8 +23,24 @@ pub const DEFAULT_BASE_DELAY_MS: u64 = 10;
 pub async fn retry_on_conflict<F, Fut, T>(
     max_attempts: u32,
     base_delay_ms: u64,
-    mut operation: F,
+    operation: F,
 ) -> Result<T, InventoryError>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Result<T, InventoryError>>,
+{
+    retry_on_conflict_counted(max_attempts, base_delay_ms, operation)
+        .await
+        .map(|(value, _)| value)
+}
+
+/// Same as [`retry_on_conflict`], also returning the number of attempts the
+/// operation took, so callers can report contention.
+pub async fn retry_on_conflict_counted<F, Fut, T>(
+    max_attempts: u32,
+    base_delay_ms: u64,
+    mut operation: F,
+) -> Result<(T, u32), InventoryError>
 where
     F: FnMut() -> Fut,
     Fut: Future<Output = Result<T, InventoryError>>,
@@ -33,7 +49,7 @@ where
     loop {
         attempt += 1;
         match operation().await {
-            Ok(value) => return Ok(value),
+            Ok(value) => return Ok((value, attempt)),
             Err(InventoryError::OptimisticLockError) if attempt < max_attempts => {
                 let delay = base_delay_ms * (1 << (attempt - 1));
                 tokio::time::sleep(tokio::time::Duration::from_millis(delay)).await;
@@ -89,6 +105,26 @@ mod tests {
         assert_eq!(call_count.load(Ordering::SeqCst), 3);
     }
 
+    #[tokio::test]
+    async fn test_counts_attempts() {
+        let call_count = Arc::new(AtomicU32::new(0));
+        let cc = call_count.clone();
+
+        let result = retry_on_conflict_counted(5, 1, move || {
+            let cc = cc.clone();
+            async move {
+                if cc.fetch_add(1, Ordering::SeqCst) < 3 {
+                    Err(InventoryError::OptimisticLockError)
+                } else {
+                    Ok(7)
+                }
+            }
+        })
+        .await;
+
+        assert_eq!(result.unwrap(), (7, 4));
+    }
+
     #[tokio::test]
     async fn test_exhausts_all_attempts() {
         let call_count = Arc::new(AtomicU32::new(0));
@@ -0,0 +1,274 @@
+use std::collections::HashMap;
+
+use rust_decimal::Decimal;
+use uuid::Uuid;
+
+use crate::InventoryError;
+use crate::domain::entities::{InventoryMovement, InventoryStock};
+use crate::domain::value_objects::{Currency, MovementType, ProductId, VariantId};
+use identity::{StoreId, UserId};
+
+/// Default number of rows written per statement when persisting a plan.
+pub const DEFAULT_STOCK_POSTING_BATCH_SIZE: usize = 500;
+
+/// A line of stock entering a store, e.g. a goods receipt line
+#[derive(Debug, Clone)]
+pub struct InboundStockLine {
+    pub product_id: ProductId,
+    pub variant_id: Option<VariantId>,
+    pub quantity: Decimal,
+    pub unit_cost: Option<Decimal>,
+}
+
+/// What the inbound movements are posted against
+#[derive(Debug, Clone)]
+pub struct InboundPostingContext {
+    pub store_id: StoreId,
+    pub movement_reason: Option<String>,
+    pub reference_type: Option<String>,
+    pub reference_id: Option<Uuid>,
+    pub actor_id: UserId,
+    pub currency: Currency,
+}
+
+/// Stock writes and kardex entries needed to post a set of inbound lines
+#[derive(Debug, Default)]
+pub struct InboundPostingPlan {
+    /// Stock records that don't exist yet, already holding the posted quantity
+    pub new_stocks: Vec<InventoryStock>,
+    /// Existing stock records with the version they were read at
+    pub updated_stocks: Vec<(InventoryStock, i32)>,
+    /// One movement per line, grouped per stock record in line order
+    pub movements: Vec<InventoryMovement>,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+enum StockKey {
+    Product(ProductId),
+    Variant(VariantId),
+}
+
+impl StockKey {
+    fn of_line(line: &InboundStockLine) -> Self {
+        match line.variant_id {
+            Some(vid) => Self::Variant(vid),
+            None => Self::Product(line.product_id),
+        }
+    }
+
+    fn of_stock(stock: &InventoryStock) -> Option<Self> {
+        match (stock.variant_id(), stock.product_id()) {
+            (Some(vid), _) => Some(Self::Variant(vid)),
+            (None, Some(pid)) => Some(Self::Product(pid)),
+            (None, None) => None,
+        }
+    }
+}
+
+/// Plans the posting of inbound lines against a store's stock.
+///
+/// Lines are grouped by stock record (the variant's stock when the line has a
+/// variant, otherwise the product's) and folded in line order within each
+/// group, so every movement's `balance_after` follows on from the previous
+/// movement of the same stock. Each touched stock record is written once,
+/// which lets the caller persist the whole plan with batched statements.
+///
+/// `existing` should hold the store's stock records for the lines' products
+/// and variants; lines without one get a new stock record.
+pub fn plan_inbound_posting(
+    context: &InboundPostingContext,
+    existing: Vec<InventoryStock>,
+    lines: &[InboundStockLine],
+) -> Result<InboundPostingPlan, InventoryError> {
+    let mut existing: HashMap<StockKey, InventoryStock> = existing
+        .into_iter()
+        .filter_map(|s| StockKey::of_stock(&s).map(|key| (key, s)))
+        .collect();
+
+    let mut order: Vec<StockKey> = Vec::new();
+    let mut groups: HashMap<StockKey, Vec<&InboundStockLine>> = HashMap::new();
+    for line in lines {
+        let key = StockKey::of_line(line);
+        groups
+            .entry(key)
+            .or_insert_with(|| {
+                order.push(key);
+                Vec::new()
+            })
+            .push(line);
+    }
+
+    let mut plan = InboundPostingPlan {
+        movements: Vec::with_capacity(lines.len()),
+        ..Default::default()
+    };
+
+    for key in order {
+        let (mut stock, expected_version) = match existing.remove(&key) {
+            Some(stock) => {
+                let version = stock.version();
+                (stock, Some(version))
+            }
+            None => match key {
+                StockKey::Variant(vid) => (
+                    InventoryStock::create_for_variant(context.store_id, vid)?,
+                    None,
+                ),
+                StockKey::Product(pid) => (
+                    InventoryStock::create_for_product(context.store_id, pid)?,
+                    None,
+                ),
+            },
+        };
+
+        for line in &groups[&key] {
+            stock.adjust_quantity(line.quantity)?;
+            plan.movements.push(InventoryMovement::create(
+                stock.id(),
+                MovementType::In,
+                context.movement_reason.clone(),
+                line.quantity,
+                line.unit_cost,
+                context.currency.clone(),
+                stock.quantity(),
+                context.reference_type.clone(),
+                context.reference_id,
+                context.actor_id,
+                None,
+            ));
+        }
+
+        match expected_version {
+            Some(version) => {
+                stock.increment_version();
+                plan.updated_stocks.push((stock, version));
+            }
+            None => plan.new_stocks.push(stock),
+        }
+    }
+
+    Ok(plan)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+    use std::time::{Duration, Instant};
+
+    fn context() -> InboundPostingContext {
+        InboundPostingContext {
+            store_id: StoreId::new(),
+            movement_reason: Some("Goods receipt confirmed".to_string()),
+            reference_type: Some("goods_receipt".to_string()),
+            reference_id: Some(Uuid::now_v7()),
+            actor_id: UserId::new(),
+            currency: Currency::hnl(),
+        }
+    }
+
+    fn line(product_id: ProductId, quantity: Decimal, unit_cost: Decimal) -> InboundStockLine {
+        InboundStockLine {
+            product_id,
+            variant_id: None,
+            quantity,
+            unit_cost: Some(unit_cost),
+        }
+    }
+
+    #[test]
+    fn test_lines_fold_sequentially_within_a_stock() {
+        let ctx = context();
+        let product_id = ProductId::new();
+        let mut stock = InventoryStock::create_for_product(ctx.store_id, product_id).unwrap();
+        stock.adjust_quantity(dec!(5)).unwrap();
+        let stock_id = stock.id();
+
+        let lines = vec![
+            line(product_id, dec!(10), dec!(2)),
+            line(ProductId::new(), dec!(3), dec!(1)),
+            line(product_id, dec!(4), dec!(3)),
+        ];
+        let plan = plan_inbound_posting(&ctx, vec![stock], &lines).unwrap();
+
+        assert_eq!(plan.updated_stocks.len(), 1);
+        assert_eq!(plan.new_stocks.len(), 1);
+        let (updated, expected_version) = &plan.updated_stocks[0];
+        assert_eq!(*expected_version, 1);
+        assert_eq!(updated.version(), 2);
+        assert_eq!(updated.quantity(), dec!(19));
+
+        let kardex: Vec<(Decimal, Option<Decimal>)> = plan
+            .movements
+            .iter()
+            .filter(|m| m.stock_id() == stock_id)
+            .map(|m| (m.balance_after(), m.unit_cost()))
+            .collect();
+        assert_eq!(
+            kardex,
+            vec![(dec!(15), Some(dec!(2))), (dec!(19), Some(dec!(3)))]
+        );
+    }
+
+    #[test]
+    fn test_variant_lines_post_to_variant_stock() {
+        let ctx = context();
+        let product_id = ProductId::new();
+        let variant_id = VariantId::new();
+        let lines = vec![
+            InboundStockLine {
+                product_id,
+                variant_id: Some(variant_id),
+                quantity: dec!(2),
+                unit_cost: None,
+            },
+            line(product_id, dec!(1), dec!(1)),
+        ];
+
+        let plan = plan_inbound_posting(&ctx, Vec::new(), &lines).unwrap();
+
+        assert_eq!(plan.new_stocks.len(), 2);
+        assert_eq!(plan.new_stocks[0].variant_id(), Some(variant_id));
+        assert_eq!(plan.new_stocks[0].product_id(), None);
+        assert_eq!(plan.new_stocks[0].quantity(), dec!(2));
+        assert_eq!(plan.new_stocks[1].product_id(), Some(product_id));
+    }
+
+    #[test]
+    fn test_large_receipt_plans_quickly_with_balanced_kardex() {
+        let ctx = context();
+        let products: Vec<ProductId> = (0..50).map(|_| ProductId::new()).collect();
+        let existing: Vec<InventoryStock> = products[..25]
+            .iter()
+            .map(|&pid| {
+                let mut s = InventoryStock::create_for_product(ctx.store_id, pid).unwrap();
+                s.adjust_quantity(dec!(100)).unwrap();
+                s
+            })
+            .collect();
+        let lines: Vec<InboundStockLine> = (0..500)
+            .map(|i| line(products[i % products.len()], Decimal::from(i + 1), dec!(1)))
+            .collect();
+
+        let started = Instant::now();
+        let plan = plan_inbound_posting(&ctx, existing, &lines).unwrap();
+        assert!(started.elapsed() < Duration::from_secs(1));
+
+        assert_eq!(plan.movements.len(), 500);
+        assert_eq!(plan.updated_stocks.len() + plan.new_stocks.len(), 50);
+
+        let stocks = plan
+            .updated_stocks
+            .iter()
+            .map(|(s, _)| (s, dec!(100)))
+            .chain(plan.new_stocks.iter().map(|s| (s, Decimal::ZERO)));
+        for (stock, opening) in stocks {
+            let mut balance = opening;
+            for movement in plan.movements.iter().filter(|m| m.stock_id() == stock.id()) {
+                balance += movement.quantity();
+                assert_eq!(movement.balance_after(), balance);
+            }
+            assert_eq!(stock.quantity(), balance);
+        }
+    }
+}
@@ -0,0 +1,187 @@
+use std::collections::HashSet;
+
+use crate::InventoryError;
+use crate::domain::entities::{Product, ProductCategory};
+use crate::domain::repositories::CategoryRepository;
+use crate::domain::value_objects::EffectiveTaxCategory;
+
+/// Upper bound on the category levels walked when resolving a tax category.
+const MAX_CATEGORY_DEPTH: usize = 32;
+
+/// Resolves the tax category that applies to `product`.
+///
+/// The product's own tax category wins; otherwise the category tree is
+/// walked up from the product's category to the nearest category that
+/// defines one. Categories are read on every call, so changing a category's
+/// tax category applies to all inheriting products right away.
+///
+/// Returns None when neither the product nor any of its categories define
+/// a tax category.
+pub async fn resolve_effective_tax_category<C>(
+    category_repo: &C,
+    product: &Product,
+) -> Result<Option<EffectiveTaxCategory>, InventoryError>
+where
+    C: CategoryRepository + ?Sized,
+{
+    if product.tax_category().is_some() {
+        return Ok(EffectiveTaxCategory::resolve(product.tax_category(), &[]));
+    }
+
+    let mut chain: Vec<ProductCategory> = Vec::new();
+    let mut visited = HashSet::new();
+    let mut next = product.category_id();
+
+    while let Some(category_id) = next {
+        // Stop on cycles and runaway trees rather than looping forever
+        if chain.len() >= MAX_CATEGORY_DEPTH || !visited.insert(category_id) {
+            break;
+        }
+        let Some(category) = category_repo.find_by_id(category_id).await? else {
+            break;
+        };
+        next = category.parent_id();
+        let defines_tax_category = category.tax_category().is_some();
+        chain.push(category);
+        if defines_tax_category {
+            break;
+        }
+    }
+
+    Ok(EffectiveTaxCategory::resolve(None, &chain))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::repositories::CategoryStockValuation;
+    use crate::domain::value_objects::{CategoryId, TaxCategory, TaxCategorySource, UnitOfMeasure};
+    use async_trait::async_trait;
+    use identity::StoreId;
+    use std::collections::HashMap;
+
+    struct MockCategoryRepository {
+        categories: HashMap<CategoryId, ProductCategory>,
+    }
+
+    #[async_trait]
+    impl CategoryRepository for MockCategoryRepository {
+        async fn save(&self, _category: &ProductCategory) -> Result<(), InventoryError> {
+            unimplemented!()
+        }
+        async fn find_by_id(
+            &self,
+            id: CategoryId,
+        ) -> Result<Option<ProductCategory>, InventoryError> {
+            Ok(self.categories.get(&id).cloned())
+        }
+        async fn find_by_slug(
+            &self,
+            _slug: &str,
+        ) -> Result<Option<ProductCategory>, InventoryError> {
+            unimplemented!()
+        }
+        async fn find_root_categories(&self) -> Result<Vec<ProductCategory>, InventoryError> {
+            unimplemented!()
+        }
+        async fn find_children(
+            &self,
+            _parent_id: CategoryId,
+        ) -> Result<Vec<ProductCategory>, InventoryError> {
+            unimplemented!()
+        }
+        async fn find_all_active(&self) -> Result<Vec<ProductCategory>, InventoryError> {
+            unimplemented!()
+        }
+        async fn update(&self, _category: &ProductCategory) -> Result<(), InventoryError> {
+            unimplemented!()
+        }
+        async fn stock_valuation_by_category(
+            &self,
+            _store_id: Option<StoreId>,
+        ) -> Result<Vec<CategoryStockValuation>, InventoryError> {
+            unimplemented!()
+        }
+        async fn delete(&self, _id: CategoryId) -> Result<(), InventoryError> {
+            unimplemented!()
+        }
+    }
+
+    /// Beverages (isv15) -> Alcohol (isv18) -> Beer (none)
+    fn tree() -> (MockCategoryRepository, CategoryId, CategoryId) {
+        let mut beverages = ProductCategory::create("Beverages".into(), "beverages".into());
+        beverages.set_tax_category(Some(TaxCategory::Isv15));
+        let mut alcohol =
+            ProductCategory::create_subcategory(beverages.id(), "Alcohol".into(), "alcohol".into());
+        alcohol.set_tax_category(Some(TaxCategory::Isv18));
+        let beer = ProductCategory::create_subcategory(alcohol.id(), "Beer".into(), "beer".into());
+
+        let (alcohol_id, beer_id) = (alcohol.id(), beer.id());
+        let categories = [beverages, alcohol, beer]
+            .into_iter()
+            .map(|c| (c.id(), c))
+            .collect();
+        (MockCategoryRepository { categories }, alcohol_id, beer_id)
+    }
+
+    fn product(category_id: Option<CategoryId>) -> Product {
+        let mut p = Product::create("Lager".into(), UnitOfMeasure::Unit, None);
+        p.set_category_id(category_id);
+        p
+    }
+
+    #[tokio::test]
+    async fn test_inherits_from_nearest_ancestor() {
+        let (repo, alcohol_id, beer_id) = tree();
+
+        let effective = resolve_effective_tax_category(&repo, &product(Some(beer_id)))
+            .await
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(effective.tax_category, TaxCategory::Isv18);
+        assert_eq!(effective.source, TaxCategorySource::Category(alcohol_id));
+    }
+
+    #[tokio::test]
+    async fn test_category_change_applies_to_inheriting_products() {
+        let (mut repo, alcohol_id, beer_id) = tree();
+        repo.categories
+            .get_mut(&alcohol_id)
+            .unwrap()
+            .set_tax_category(None);
+
+        let effective = resolve_effective_tax_category(&repo, &product(Some(beer_id)))
+            .await
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(effective.tax_category, TaxCategory::Isv15);
+    }
+
+    #[tokio::test]
+    async fn test_product_override_takes_precedence() {
+        let (repo, _, beer_id) = tree();
+        let mut p = product(Some(beer_id));
+        p.set_tax_category(Some(TaxCategory::Exempt));
+
+        let effective = resolve_effective_tax_category(&repo, &p)
+            .await
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(effective.tax_category, TaxCategory::Exempt);
+        assert_eq!(effective.source, TaxCategorySource::Product);
+    }
+
+    #[tokio::test]
+    async fn test_uncategorized_product_has_no_tax_category() {
+        let (repo, _, _) = tree();
+
+        let effective = resolve_effective_tax_category(&repo, &product(None))
+            .await
+            .unwrap();
+
+        assert!(effective.is_none());
+    }
+}
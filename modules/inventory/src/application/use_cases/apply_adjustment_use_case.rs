@@ -5,17 +5,21 @@ use std::sync::Arc;
 use crate::InventoryError;
 use crate::application::dtos::commands::ApplyAdjustmentCommand;
 use crate::application::dtos::responses::{AdjustmentDetailResponse, AdjustmentItemResponse};
+use crate::application::helpers::ensure_period_open;
 use crate::domain::entities::{InventoryMovement, StockAdjustment};
 use crate::domain::repositories::{
-    AdjustmentRepository, InventoryMovementRepository, InventoryStockRepository,
+    AdjustmentRepository, InventoryMovementRepository, InventoryPeriodRepository,
+    InventoryStockRepository,
 };
 use crate::domain::value_objects::{AdjustmentId, Currency, MovementType};
-use identity::UserId;
+use identity::{StoreSettings, UserId};
 
 /// Use case for applying an approved stock adjustment to inventory.
 ///
 /// Validates status is approved, updates stock with optimistic locking for each item,
 /// records balance_before/after, creates movements, and marks adjustment as applied.
+/// With a period lock, adjustments dated (created) in a closed period of
+/// their store are rejected.
 pub struct ApplyAdjustmentUseCase<A, S, M>
 where
     A: AdjustmentRepository,
@@ -25,6 +29,8 @@ where
     adjustment_repo: Arc<A>,
     stock_repo: Arc<S>,
     movement_repo: Arc<M>,
+    period_repo: Option<Arc<dyn InventoryPeriodRepository>>,
+    can_post_to_closed_period: bool,
 }
 
 impl<A, S, M> ApplyAdjustmentUseCase<A, S, M>
@@ -39,14 +45,29 @@ where
             adjustment_repo,
             stock_repo,
             movement_repo,
+            period_repo: None,
+            can_post_to_closed_period: false,
         }
     }
 
+    /// Rejects adjustments dated in a closed period, unless `can_override`
+    /// is set
+    pub fn with_period_lock(
+        mut self,
+        period_repo: Arc<dyn InventoryPeriodRepository>,
+        can_override: bool,
+    ) -> Self {
+        self.period_repo = Some(period_repo);
+        self.can_post_to_closed_period = can_override;
+        self
+    }
+
     /// Executes the use case to apply an approved adjustment
     ///
     /// # Arguments
     /// * `command` - The apply adjustment command containing adjustment ID
     /// * `actor_id` - ID of the user applying the adjustment
+    /// * `settings` - Settings of the adjustment's store
     ///
     /// # Returns
     /// AdjustmentDetailResponse on success
@@ -57,10 +78,14 @@ where
     /// * `InventoryError::StockNotFound` - If a stock record doesn't exist
     /// * `InventoryError::OptimisticLockError` - If concurrent modification detected
     /// * `InventoryError::NegativeStock` - If adjustment would result in negative stock
+    ///   and the store doesn't allow negative stock
+    /// * `InventoryError::PeriodClosed` - If the adjustment is dated in a
+    ///   closed period and the actor can't override it
     pub async fn execute(
         &self,
         command: ApplyAdjustmentCommand,
         actor_id: UserId,
+        settings: &StoreSettings,
     ) -> Result<AdjustmentDetailResponse, InventoryError> {
         // 1. Find adjustment with items
         let adjustment_id = AdjustmentId::from_uuid(command.adjustment_id);
@@ -75,9 +100,22 @@ where
             return Err(InventoryError::InvalidStatusTransition);
         }
 
+        // The adjustment is dated when it was raised, not when it is applied
+        if let Some(period_repo) = &self.period_repo {
+            ensure_period_open(
+                period_repo.as_ref(),
+                adjustment.store_id(),
+                adjustment.created_at().date_naive(),
+                self.can_post_to_closed_period,
+            )
+            .await?;
+        }
+
         // Extract values needed for movements before mutable borrow
         let adjustment_reason = adjustment.adjustment_reason().to_string();
         let adjustment_uuid = adjustment.id().into_uuid();
+        let allow_negative =
+            settings.store_id() == adjustment.store_id() && settings.allow_negative_stock();
 
         // 3. Apply each item (Requirement 10.3)
         for item in adjustment.items_mut() {
@@ -93,7 +131,7 @@ where
             let expected_version = stock.version();
 
             // Apply quantity change
-            stock.adjust_quantity(item.quantity())?;
+            stock.adjust_quantity_with_policy(item.quantity(), allow_negative)?;
             stock.increment_version();
 
             // Record balance after
@@ -160,6 +198,7 @@ where
             created_by_id: adjustment.created_by_id().into_uuid(),
             approved_by_id: adjustment.approved_by_id().map(|id| id.into_uuid()),
             approved_at: adjustment.approved_at(),
+            auto_approved: adjustment.auto_approved(),
             applied_at: adjustment.applied_at(),
             notes: adjustment.notes().map(|s| s.to_string()),
             attachments: Some(adjustment.attachments().clone()),
@@ -180,10 +219,12 @@ mod tests {
     use std::sync::Mutex;
     use uuid::{NoContext, Timestamp, Uuid};
 
-    use crate::domain::entities::{AdjustmentItem, InventoryStock};
+    use crate::domain::entities::{AdjustmentItem, InventoryPeriod, InventoryStock};
     use crate::domain::value_objects::{
-        AdjustmentReason, AdjustmentStatus, AdjustmentType, ProductId, StockId, VariantId,
+        AdjustmentReason, AdjustmentStatus, AdjustmentType, InventoryPeriodId, ProductId, StockId,
+        VariantId,
     };
+    use chrono::NaiveDate;
     use identity::StoreId;
 
     fn new_uuid() -> Uuid {
@@ -252,8 +293,9 @@ mod tests {
 
         async fn find_paginated(
             &self,
-            _store_id: Option<StoreId>,
+            _store_ids: Option<&[StoreId]>,
             _status: Option<&str>,
+            _after_id: Option<Uuid>,
             _page: i64,
             _page_size: i64,
         ) -> Result<(Vec<StockAdjustment>, i64), InventoryError> {
@@ -340,9 +382,10 @@ mod tests {
 
         async fn find_paginated(
             &self,
-            _store_id: Option<StoreId>,
+            _store_ids: Option<&[StoreId]>,
             _product_id: Option<ProductId>,
             _low_stock_only: bool,
+            _after_id: Option<Uuid>,
             _page: i64,
             _page_size: i64,
         ) -> Result<(Vec<InventoryStock>, i64), InventoryError> {
@@ -477,6 +520,37 @@ mod tests {
         async fn save_batch(&self, _movements: &[InventoryMovement]) -> Result<(), InventoryError> {
             unimplemented!()
         }
+
+        async fn find_all_by_stock_id_chronological(
+            &self,
+            _stock_id: StockId,
+        ) -> Result<Vec<InventoryMovement>, InventoryError> {
+            unimplemented!()
+        }
+
+        async fn rewrite_average_costs(
+            &self,
+            _stock_id: StockId,
+            _expected_version: i32,
+            _movement_costs: &[(crate::domain::value_objects::MovementId, Option<Decimal>)],
+            _average_cost: Option<Decimal>,
+        ) -> Result<crate::domain::repositories::AverageCostRewrite, InventoryError> {
+            unimplemented!()
+        }
+
+        async fn find_costing_method(
+            &self,
+            _stock_id: StockId,
+        ) -> Result<crate::domain::value_objects::CostingMethod, InventoryError> {
+            unimplemented!()
+        }
+
+        async fn find_open_cost_layers(
+            &self,
+            _stock_id: StockId,
+        ) -> Result<Vec<crate::domain::repositories::CostLayer>, InventoryError> {
+            unimplemented!()
+        }
     }
 
     fn create_approved_adjustment(stock_id: StockId) -> StockAdjustment {
@@ -494,6 +568,10 @@ mod tests {
         adjustment
     }
 
+    fn default_settings() -> StoreSettings {
+        StoreSettings::defaults(StoreId::new())
+    }
+
     fn create_stock_with_quantity(quantity: Decimal) -> InventoryStock {
         let store_id = StoreId::new();
         let product_id = ProductId::new();
@@ -529,7 +607,9 @@ mod tests {
         };
 
         let actor_id = UserId::new();
-        let result = use_case.execute(command, actor_id).await;
+        let result = use_case
+            .execute(command, actor_id, &default_settings())
+            .await;
         assert!(result.is_ok());
 
         let response = result.unwrap();
@@ -596,7 +676,9 @@ mod tests {
             adjustment_id: adjustment_id.into_uuid(),
         };
 
-        let result = use_case.execute(command, UserId::new()).await;
+        let result = use_case
+            .execute(command, UserId::new(), &default_settings())
+            .await;
         assert!(result.is_ok());
 
         let response = result.unwrap();
@@ -620,7 +702,9 @@ mod tests {
             adjustment_id: new_uuid(),
         };
 
-        let result = use_case.execute(command, UserId::new()).await;
+        let result = use_case
+            .execute(command, UserId::new(), &default_settings())
+            .await;
         assert!(matches!(result, Err(InventoryError::AdjustmentNotFound(_))));
     }
 
@@ -655,7 +739,9 @@ mod tests {
             adjustment_id: adjustment_id.into_uuid(),
         };
 
-        let result = use_case.execute(command, UserId::new()).await;
+        let result = use_case
+            .execute(command, UserId::new(), &default_settings())
+            .await;
         assert!(matches!(
             result,
             Err(InventoryError::InvalidStatusTransition)
@@ -679,7 +765,9 @@ mod tests {
             adjustment_id: adjustment_id.into_uuid(),
         };
 
-        let result = use_case.execute(command, UserId::new()).await;
+        let result = use_case
+            .execute(command, UserId::new(), &default_settings())
+            .await;
         assert!(matches!(result, Err(InventoryError::StockNotFound(_))));
     }
 
@@ -705,10 +793,42 @@ mod tests {
             adjustment_id: adjustment_id.into_uuid(),
         };
 
-        let result = use_case.execute(command, UserId::new()).await;
+        let result = use_case
+            .execute(command, UserId::new(), &default_settings())
+            .await;
         assert!(matches!(result, Err(InventoryError::NegativeStock)));
     }
 
+    #[tokio::test]
+    async fn test_apply_adjustment_negative_stock_allowed_by_store() {
+        let adjustment_repo = Arc::new(MockAdjustmentRepository::new());
+        let stock_repo = Arc::new(MockStockRepository::new());
+        let movement_repo = Arc::new(MockMovementRepository::new());
+
+        let stock = create_stock_with_quantity(dec!(5));
+        let stock_id = stock.id();
+        stock_repo.add_stock(stock);
+
+        let adjustment = create_approved_adjustment(stock_id);
+        let adjustment_id = adjustment.id();
+        let mut settings = StoreSettings::defaults(adjustment.store_id());
+        settings.set_allow_negative_stock(true);
+        adjustment_repo.add_adjustment(adjustment);
+
+        let use_case =
+            ApplyAdjustmentUseCase::new(adjustment_repo, stock_repo.clone(), movement_repo);
+
+        let command = ApplyAdjustmentCommand {
+            adjustment_id: adjustment_id.into_uuid(),
+        };
+
+        let response = use_case
+            .execute(command, UserId::new(), &settings)
+            .await
+            .unwrap();
+        assert_eq!(response.items[0].balance_after, Some(dec!(-5)));
+    }
+
     #[tokio::test]
     async fn test_apply_adjustment_multiple_items() {
         let adjustment_repo = Arc::new(MockAdjustmentRepository::new());
@@ -751,7 +871,9 @@ mod tests {
             adjustment_id: adjustment_id.into_uuid(),
         };
 
-        let result = use_case.execute(command, UserId::new()).await;
+        let result = use_case
+            .execute(command, UserId::new(), &default_settings())
+            .await;
         assert!(result.is_ok());
 
         let response = result.unwrap();
@@ -768,4 +890,107 @@ mod tests {
         let movements = movement_repo.get_movements();
         assert_eq!(movements.len(), 2);
     }
+
+    struct MockPeriodRepository {
+        periods: Vec<InventoryPeriod>,
+    }
+
+    #[async_trait]
+    impl InventoryPeriodRepository for MockPeriodRepository {
+        async fn save(&self, _period: &InventoryPeriod) -> Result<(), InventoryError> {
+            unimplemented!()
+        }
+
+        async fn update(&self, _period: &InventoryPeriod) -> Result<(), InventoryError> {
+            unimplemented!()
+        }
+
+        async fn find_by_id(
+            &self,
+            _id: InventoryPeriodId,
+        ) -> Result<Option<InventoryPeriod>, InventoryError> {
+            unimplemented!()
+        }
+
+        async fn find_overlapping(
+            &self,
+            _store_id: StoreId,
+            _start: NaiveDate,
+            _end: NaiveDate,
+        ) -> Result<Vec<InventoryPeriod>, InventoryError> {
+            unimplemented!()
+        }
+
+        async fn find_closed_containing(
+            &self,
+            store_id: StoreId,
+            date: NaiveDate,
+        ) -> Result<Option<InventoryPeriod>, InventoryError> {
+            Ok(self
+                .periods
+                .iter()
+                .find(|p| p.store_id() == store_id && p.is_closed() && p.contains(date))
+                .cloned())
+        }
+
+        async fn find_by_store(
+            &self,
+            _store_id: StoreId,
+        ) -> Result<Vec<InventoryPeriod>, InventoryError> {
+            unimplemented!()
+        }
+    }
+
+    #[tokio::test]
+    async fn test_apply_adjustment_dated_in_closed_period() {
+        let adjustment_repo = Arc::new(MockAdjustmentRepository::new());
+        let stock_repo = Arc::new(MockStockRepository::new());
+        let stock = create_stock_with_quantity(dec!(100));
+        let stock_id = stock.id();
+        stock_repo.add_stock(stock);
+        let adjustment = create_approved_adjustment(stock_id);
+        let adjustment_id = adjustment.id();
+
+        // Close the period the adjustment was raised in
+        let raised_on = adjustment.created_at().date_naive();
+        let mut period =
+            InventoryPeriod::create(adjustment.store_id(), raised_on, raised_on).unwrap();
+        period.close(UserId::new()).unwrap();
+        adjustment_repo.add_adjustment(adjustment);
+        let period_repo = Arc::new(MockPeriodRepository {
+            periods: vec![period],
+        });
+
+        let use_case = |can_override| {
+            ApplyAdjustmentUseCase::new(
+                adjustment_repo.clone(),
+                stock_repo.clone(),
+                Arc::new(MockMovementRepository::new()),
+            )
+            .with_period_lock(period_repo.clone(), can_override)
+        };
+        let command = || ApplyAdjustmentCommand {
+            adjustment_id: adjustment_id.into_uuid(),
+        };
+
+        let result = use_case(false)
+            .execute(command(), UserId::new(), &default_settings())
+            .await;
+        assert!(matches!(result, Err(InventoryError::PeriodClosed(_, _))));
+        assert_eq!(
+            stock_repo
+                .find_by_id(stock_id)
+                .await
+                .unwrap()
+                .unwrap()
+                .quantity(),
+            dec!(100)
+        );
+
+        let response = use_case(true)
+            .execute(command(), UserId::new(), &default_settings())
+            .await
+            .unwrap();
+        assert_eq!(response.status, "applied");
+    }
 }
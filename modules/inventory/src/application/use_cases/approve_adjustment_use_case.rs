@@ -8,12 +8,19 @@ use crate::application::dtos::responses::{AdjustmentDetailResponse, AdjustmentIt
 use crate::domain::entities::StockAdjustment;
 use crate::domain::repositories::AdjustmentRepository;
 use crate::domain::value_objects::AdjustmentId;
-use identity::UserId;
+use identity::{PermissionCheckResult, UserContext};
+
+/// Permission required to approve adjustments
+const APPROVE_ADJUSTMENT_PERMISSION: &str = "adjustments:approve";
+
+/// Permission required to reject adjustments
+const REJECT_ADJUSTMENT_PERMISSION: &str = "adjustments:reject";
 
 /// Use case for approving or rejecting a stock adjustment.
 ///
-/// Validates status is pending_approval, records approver and timestamp,
-/// and changes status to approved or rejected.
+/// Checks the caller holds `adjustments:approve` or `adjustments:reject`
+/// for the decision, validates status is pending_approval, records approver
+/// and timestamp, and changes status to approved or rejected.
 pub struct ApproveAdjustmentUseCase<A>
 where
     A: AdjustmentRepository,
@@ -34,19 +41,30 @@ where
     ///
     /// # Arguments
     /// * `command` - The approve adjustment command containing adjustment ID and decision
-    /// * `approver_id` - ID of the user approving/rejecting the adjustment
+    /// * `ctx` - Context of the user approving/rejecting the adjustment
     ///
     /// # Returns
     /// AdjustmentDetailResponse on success
     ///
     /// # Errors
+    /// * `InventoryError::Forbidden` - If the user lacks the permission for the decision
     /// * `InventoryError::AdjustmentNotFound` - If adjustment doesn't exist
     /// * `InventoryError::InvalidStatusTransition` - If adjustment is not in pending_approval status
     pub async fn execute(
         &self,
         command: ApproveAdjustmentCommand,
-        approver_id: UserId,
+        ctx: &UserContext,
     ) -> Result<AdjustmentDetailResponse, InventoryError> {
+        let required = if command.approve {
+            APPROVE_ADJUSTMENT_PERMISSION
+        } else {
+            REJECT_ADJUSTMENT_PERMISSION
+        };
+        if let PermissionCheckResult::Denied { missing } = ctx.authorize(&[required]) {
+            return Err(InventoryError::Forbidden { missing });
+        }
+        let approver_id = *ctx.user_id();
+
         // 1. Find adjustment with items
         let adjustment_id = AdjustmentId::from_uuid(command.adjustment_id);
         let mut adjustment = self
@@ -101,6 +119,7 @@ where
             created_by_id: adjustment.created_by_id().into_uuid(),
             approved_by_id: adjustment.approved_by_id().map(|id| id.into_uuid()),
             approved_at: adjustment.approved_at(),
+            auto_approved: adjustment.auto_approved(),
             applied_at: adjustment.applied_at(),
             notes: adjustment.notes().map(|s| s.to_string()),
             attachments: Some(adjustment.attachments().clone()),
@@ -124,7 +143,7 @@ mod tests {
     use crate::domain::value_objects::{
         AdjustmentReason, AdjustmentStatus, AdjustmentType, StockId,
     };
-    use identity::StoreId;
+    use identity::{PermissionCode, StoreId, UserId};
 
     fn new_uuid() -> Uuid {
         Uuid::new_v7(Timestamp::now(NoContext))
@@ -192,8 +211,9 @@ mod tests {
 
         async fn find_paginated(
             &self,
-            _store_id: Option<StoreId>,
+            _store_ids: Option<&[StoreId]>,
             _status: Option<&str>,
+            _after_id: Option<Uuid>,
             _page: i64,
             _page_size: i64,
         ) -> Result<(Vec<StockAdjustment>, i64), InventoryError> {
@@ -201,6 +221,22 @@ mod tests {
         }
     }
 
+    fn approver(user_id: UserId) -> UserContext {
+        let permissions = ["adjustments:approve", "adjustments:reject"]
+            .iter()
+            .map(|code| PermissionCode::new(code).unwrap())
+            .collect();
+        UserContext::new(
+            user_id,
+            StoreId::new(),
+            permissions,
+            vec![],
+            false,
+            None,
+            None,
+        )
+    }
+
     fn create_pending_adjustment() -> StockAdjustment {
         let mut adjustment = StockAdjustment::create(
             StoreId::new(),
@@ -232,7 +268,7 @@ mod tests {
             notes: Some("Approved".to_string()),
         };
 
-        let result = use_case.execute(command, approver_id).await;
+        let result = use_case.execute(command, &approver(approver_id)).await;
         assert!(result.is_ok());
 
         let response = result.unwrap();
@@ -262,7 +298,7 @@ mod tests {
             notes: Some("Rejected - insufficient documentation".to_string()),
         };
 
-        let result = use_case.execute(command, approver_id).await;
+        let result = use_case.execute(command, &approver(approver_id)).await;
         assert!(result.is_ok());
 
         let response = result.unwrap();
@@ -286,7 +322,7 @@ mod tests {
             notes: None,
         };
 
-        let result = use_case.execute(command, UserId::new()).await;
+        let result = use_case.execute(command, &approver(UserId::new())).await;
         assert!(matches!(result, Err(InventoryError::AdjustmentNotFound(_))));
     }
 
@@ -314,7 +350,7 @@ mod tests {
             notes: None,
         };
 
-        let result = use_case.execute(command, UserId::new()).await;
+        let result = use_case.execute(command, &approver(UserId::new())).await;
         assert!(matches!(
             result,
             Err(InventoryError::InvalidStatusTransition)
@@ -337,10 +373,47 @@ mod tests {
             notes: None,
         };
 
-        let result = use_case.execute(command, UserId::new()).await;
+        let result = use_case.execute(command, &approver(UserId::new())).await;
         assert!(matches!(
             result,
             Err(InventoryError::InvalidStatusTransition)
         ));
     }
+
+    #[tokio::test]
+    async fn test_reject_requires_reject_permission() {
+        let repo = Arc::new(MockAdjustmentRepository::new());
+        let adjustment = create_pending_adjustment();
+        let adjustment_id = adjustment.id();
+        repo.add_adjustment(adjustment);
+
+        let use_case = ApproveAdjustmentUseCase::new(repo.clone());
+        // Holds only the approve permission
+        let ctx = UserContext::new(
+            UserId::new(),
+            StoreId::new(),
+            [PermissionCode::new("adjustments:approve").unwrap()].into(),
+            vec![],
+            false,
+            None,
+            None,
+        );
+
+        let command = ApproveAdjustmentCommand {
+            adjustment_id: adjustment_id.into_uuid(),
+            approve: false,
+            notes: None,
+        };
+
+        let result = use_case.execute(command, &ctx).await;
+        match result {
+            Err(InventoryError::Forbidden { missing }) => {
+                assert_eq!(missing, vec!["adjustments:reject".to_string()]);
+            }
+            other => panic!("unexpected result: {:?}", other.map(|r| r.status)),
+        }
+
+        let unchanged = repo.find_by_id(adjustment_id).await.unwrap().unwrap();
+        assert_eq!(unchanged.status(), AdjustmentStatus::PendingApproval);
+    }
 }
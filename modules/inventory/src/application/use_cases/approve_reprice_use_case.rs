@@ -0,0 +1,465 @@
+// ApproveRepriceUseCase - applies or rejects a previewed batch reprice
+
+use std::sync::Arc;
+
+use crate::InventoryError;
+use crate::application::dtos::commands::ApproveRepriceCommand;
+use crate::application::dtos::responses::RepriceBatchResponse;
+use crate::domain::entities::ProductPriceChange;
+use crate::domain::repositories::{
+    PriceHistoryRepository, ProductRepository, RepriceBatchRepository,
+};
+use crate::domain::value_objects::RepriceBatchId;
+use identity::UserId;
+
+/// Use case for approving or rejecting a batch reprice.
+///
+/// Approval writes each line's new cost to its product, and the new price
+/// to products with a markup rule; excluded (manually priced) products keep
+/// their price. Every product change is recorded in the price history,
+/// linked to the batch. Rejection discards the preview.
+pub struct ApproveRepriceUseCase<P, B, H>
+where
+    P: ProductRepository,
+    B: RepriceBatchRepository,
+    H: PriceHistoryRepository,
+{
+    product_repo: Arc<P>,
+    batch_repo: Arc<B>,
+    history_repo: Arc<H>,
+}
+
+impl<P, B, H> ApproveRepriceUseCase<P, B, H>
+where
+    P: ProductRepository,
+    B: RepriceBatchRepository,
+    H: PriceHistoryRepository,
+{
+    /// Creates a new instance of ApproveRepriceUseCase
+    pub fn new(product_repo: Arc<P>, batch_repo: Arc<B>, history_repo: Arc<H>) -> Self {
+        Self {
+            product_repo,
+            batch_repo,
+            history_repo,
+        }
+    }
+
+    /// Executes the use case to apply or reject a batch
+    ///
+    /// # Errors
+    /// * `InventoryError::RepriceBatchNotFound` - If the batch doesn't exist
+    /// * `InventoryError::InvalidStatusTransition` - If the batch is not pending
+    /// * `InventoryError::ProductNotFound` - If a product was deleted after the preview
+    pub async fn execute(
+        &self,
+        command: ApproveRepriceCommand,
+        approver_id: UserId,
+    ) -> Result<RepriceBatchResponse, InventoryError> {
+        let mut batch = self
+            .batch_repo
+            .find_by_id(RepriceBatchId::from_uuid(command.batch_id))
+            .await?
+            .ok_or(InventoryError::RepriceBatchNotFound(command.batch_id))?;
+
+        if !command.approve {
+            batch.reject(approver_id)?;
+            self.batch_repo.update(&batch).await?;
+            return Ok(RepriceBatchResponse::from(&batch));
+        }
+
+        if !batch.status().is_pending() {
+            return Err(InventoryError::InvalidStatusTransition);
+        }
+
+        let mut changes = Vec::with_capacity(batch.lines().len());
+        for line in batch.lines() {
+            let mut product = self
+                .product_repo
+                .find_by_id(line.product_id())
+                .await?
+                .ok_or(InventoryError::ProductNotFound(
+                    line.product_id().into_uuid(),
+                ))?;
+
+            let (old_cost, old_price) = (product.cost_price(), product.base_price());
+            product.set_cost_price(line.new_cost());
+            if let Some(new_price) = line.new_price() {
+                product.set_base_price(new_price);
+            }
+            self.product_repo.update(&product).await?;
+
+            changes.push(ProductPriceChange::record(
+                product.id(),
+                old_cost,
+                product.cost_price(),
+                old_price,
+                product.base_price(),
+                Some(batch.id()),
+                approver_id,
+            ));
+        }
+        self.history_repo.save_batch(&changes).await?;
+
+        batch.mark_applied(approver_id)?;
+        self.batch_repo.update(&batch).await?;
+
+        Ok(RepriceBatchResponse::from(&batch))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use async_trait::async_trait;
+    use rust_decimal_macros::dec;
+    use std::collections::HashMap;
+    use std::sync::Mutex;
+    use uuid::Uuid;
+
+    use crate::domain::entities::{
+        Product, ProductVariant, ProductVendor, RepriceBatch, RepriceLine,
+    };
+    use crate::domain::value_objects::{
+        Barcode, CategoryId, MarkupRule, ProductId, RepriceBatchStatus, Sku, UnitConversion,
+        UnitOfMeasure, VariantId,
+    };
+
+    struct MockProductRepository {
+        products: Mutex<HashMap<ProductId, Product>>,
+    }
+
+    #[async_trait]
+    impl ProductRepository for MockProductRepository {
+        async fn save(&self, _product: &Product) -> Result<(), InventoryError> {
+            unimplemented!()
+        }
+
+        async fn find_by_id(&self, id: ProductId) -> Result<Option<Product>, InventoryError> {
+            Ok(self.products.lock().unwrap().get(&id).cloned())
+        }
+
+        async fn find_by_sku(&self, _sku: &Sku) -> Result<Option<Product>, InventoryError> {
+            unimplemented!()
+        }
+
+        async fn find_by_barcode(
+            &self,
+            _barcode: &Barcode,
+        ) -> Result<Option<Product>, InventoryError> {
+            unimplemented!()
+        }
+
+        async fn update(&self, product: &Product) -> Result<(), InventoryError> {
+            self.products
+                .lock()
+                .unwrap()
+                .insert(product.id(), product.clone());
+            Ok(())
+        }
+
+        async fn delete(&self, _id: ProductId) -> Result<(), InventoryError> {
+            unimplemented!()
+        }
+
+        async fn find_active(&self) -> Result<Vec<Product>, InventoryError> {
+            unimplemented!()
+        }
+
+        async fn find_by_category(
+            &self,
+            _category_id: CategoryId,
+        ) -> Result<Vec<Product>, InventoryError> {
+            unimplemented!()
+        }
+
+        async fn find_paginated(
+            &self,
+            _category_id: Option<CategoryId>,
+            _is_active: Option<bool>,
+            _search: Option<&str>,
+            _after_id: Option<Uuid>,
+            _page: i64,
+            _page_size: i64,
+        ) -> Result<(Vec<Product>, i64), InventoryError> {
+            unimplemented!()
+        }
+
+        async fn count_filtered(
+            &self,
+            _category_id: Option<CategoryId>,
+            _is_active: Option<bool>,
+            _search: Option<&str>,
+        ) -> Result<i64, InventoryError> {
+            unimplemented!()
+        }
+
+        async fn search(
+            &self,
+            _term: &str,
+            _category_id: Option<CategoryId>,
+            _active_only: bool,
+            _page: i64,
+            _page_size: i64,
+        ) -> Result<(Vec<(Product, f64)>, i64), InventoryError> {
+            unimplemented!()
+        }
+
+        async fn save_variant(&self, _variant: &ProductVariant) -> Result<(), InventoryError> {
+            unimplemented!()
+        }
+
+        async fn find_variant_by_id(
+            &self,
+            _id: VariantId,
+        ) -> Result<Option<ProductVariant>, InventoryError> {
+            unimplemented!()
+        }
+
+        async fn find_variant_by_sku(
+            &self,
+            _sku: &Sku,
+        ) -> Result<Option<ProductVariant>, InventoryError> {
+            unimplemented!()
+        }
+
+        async fn find_variant_by_barcode(
+            &self,
+            _barcode: &Barcode,
+        ) -> Result<Option<ProductVariant>, InventoryError> {
+            unimplemented!()
+        }
+
+        async fn find_variants_by_product(
+            &self,
+            _product_id: ProductId,
+        ) -> Result<Vec<ProductVariant>, InventoryError> {
+            unimplemented!()
+        }
+
+        async fn update_variant(&self, _variant: &ProductVariant) -> Result<(), InventoryError> {
+            unimplemented!()
+        }
+
+        async fn delete_variant(&self, _id: VariantId) -> Result<(), InventoryError> {
+            unimplemented!()
+        }
+
+        async fn count_variants(&self, _product_id: ProductId) -> Result<u32, InventoryError> {
+            unimplemented!()
+        }
+
+        async fn save_product_vendors(
+            &self,
+            _vendors: &[ProductVendor],
+        ) -> Result<(), InventoryError> {
+            unimplemented!()
+        }
+
+        async fn find_product_vendors(
+            &self,
+            _product_id: ProductId,
+        ) -> Result<Vec<ProductVendor>, InventoryError> {
+            unimplemented!()
+        }
+
+        async fn find_preferred_vendor(
+            &self,
+            _product_id: ProductId,
+        ) -> Result<Option<ProductVendor>, InventoryError> {
+            unimplemented!()
+        }
+
+        async fn replace_unit_conversions(
+            &self,
+            _product_id: ProductId,
+            _conversions: &[UnitConversion],
+        ) -> Result<(), InventoryError> {
+            unimplemented!()
+        }
+
+        async fn find_unit_conversions(
+            &self,
+            _product_id: ProductId,
+        ) -> Result<Vec<UnitConversion>, InventoryError> {
+            unimplemented!()
+        }
+    }
+
+    struct MockRepriceBatchRepository {
+        batches: Mutex<HashMap<RepriceBatchId, RepriceBatch>>,
+    }
+
+    #[async_trait]
+    impl RepriceBatchRepository for MockRepriceBatchRepository {
+        async fn save(&self, _batch: &RepriceBatch) -> Result<(), InventoryError> {
+            unimplemented!()
+        }
+
+        async fn update(&self, batch: &RepriceBatch) -> Result<(), InventoryError> {
+            self.batches
+                .lock()
+                .unwrap()
+                .insert(batch.id(), batch.clone());
+            Ok(())
+        }
+
+        async fn find_by_id(
+            &self,
+            id: RepriceBatchId,
+        ) -> Result<Option<RepriceBatch>, InventoryError> {
+            Ok(self.batches.lock().unwrap().get(&id).cloned())
+        }
+
+        async fn find_all(
+            &self,
+            _status: Option<RepriceBatchStatus>,
+        ) -> Result<Vec<RepriceBatch>, InventoryError> {
+            unimplemented!()
+        }
+    }
+
+    struct MockPriceHistoryRepository {
+        changes: Mutex<Vec<ProductPriceChange>>,
+    }
+
+    #[async_trait]
+    impl PriceHistoryRepository for MockPriceHistoryRepository {
+        async fn save_batch(&self, changes: &[ProductPriceChange]) -> Result<(), InventoryError> {
+            self.changes.lock().unwrap().extend_from_slice(changes);
+            Ok(())
+        }
+
+        async fn find_by_product(
+            &self,
+            _product_id: ProductId,
+        ) -> Result<Vec<ProductPriceChange>, InventoryError> {
+            unimplemented!()
+        }
+    }
+
+    struct Fixture {
+        use_case: ApproveRepriceUseCase<
+            MockProductRepository,
+            MockRepriceBatchRepository,
+            MockPriceHistoryRepository,
+        >,
+        product_repo: Arc<MockProductRepository>,
+        history_repo: Arc<MockPriceHistoryRepository>,
+        batch_id: RepriceBatchId,
+        marked_up: ProductId,
+        manual: ProductId,
+    }
+
+    fn setup() -> Fixture {
+        let product = |name: &str, rule: Option<MarkupRule>| {
+            let mut product = Product::create(name.to_string(), UnitOfMeasure::Unit, None);
+            product.set_cost_price(dec!(10));
+            product.set_base_price(dec!(15));
+            product.set_markup_rule(rule);
+            product
+        };
+        let marked_up = product("Beans", Some(MarkupRule::Margin { percent: dec!(40) }));
+        let manual = product("Mug", None);
+
+        let lines = vec![
+            RepriceLine::preview(&marked_up, dec!(12)).unwrap(),
+            RepriceLine::preview(&manual, dec!(11)).unwrap(),
+        ];
+        let batch = RepriceBatch::create(None, None, lines, UserId::new()).unwrap();
+        let batch_id = batch.id();
+
+        let fixture_ids = (marked_up.id(), manual.id());
+        let product_repo = Arc::new(MockProductRepository {
+            products: Mutex::new(
+                [marked_up, manual]
+                    .into_iter()
+                    .map(|p| (p.id(), p))
+                    .collect(),
+            ),
+        });
+        let batch_repo = Arc::new(MockRepriceBatchRepository {
+            batches: Mutex::new(HashMap::from([(batch_id, batch)])),
+        });
+        let history_repo = Arc::new(MockPriceHistoryRepository {
+            changes: Mutex::new(Vec::new()),
+        });
+
+        Fixture {
+            use_case: ApproveRepriceUseCase::new(
+                product_repo.clone(),
+                batch_repo,
+                history_repo.clone(),
+            ),
+            product_repo,
+            history_repo,
+            batch_id,
+            marked_up: fixture_ids.0,
+            manual: fixture_ids.1,
+        }
+    }
+
+    fn command(fixture: &Fixture, approve: bool) -> ApproveRepriceCommand {
+        ApproveRepriceCommand {
+            batch_id: fixture.batch_id.into_uuid(),
+            approve,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_approve_applies_prices_and_records_history() {
+        let fixture = setup();
+
+        let response = fixture
+            .use_case
+            .execute(command(&fixture, true), UserId::new())
+            .await
+            .unwrap();
+        assert_eq!(response.status, "applied");
+
+        {
+            let products = fixture.product_repo.products.lock().unwrap();
+            let beans = &products[&fixture.marked_up];
+            assert_eq!(beans.cost_price(), dec!(12));
+            assert_eq!(beans.base_price(), dec!(20.00));
+            let mug = &products[&fixture.manual];
+            assert_eq!(mug.cost_price(), dec!(11));
+            assert_eq!(mug.base_price(), dec!(15));
+
+            let changes = fixture.history_repo.changes.lock().unwrap();
+            assert_eq!(changes.len(), 2);
+            assert!(
+                changes
+                    .iter()
+                    .all(|c| c.reprice_batch_id() == Some(fixture.batch_id))
+            );
+            assert_eq!(changes[0].old_price(), dec!(15));
+            assert_eq!(changes[0].new_price(), dec!(20.00));
+        }
+
+        // An applied batch cannot be applied again
+        let again = fixture
+            .use_case
+            .execute(command(&fixture, true), UserId::new())
+            .await;
+        assert!(matches!(
+            again,
+            Err(InventoryError::InvalidStatusTransition)
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_reject_leaves_products_unchanged() {
+        let fixture = setup();
+
+        let response = fixture
+            .use_case
+            .execute(command(&fixture, false), UserId::new())
+            .await
+            .unwrap();
+        assert_eq!(response.status, "rejected");
+
+        let products = fixture.product_repo.products.lock().unwrap();
+        assert_eq!(products[&fixture.marked_up].base_price(), dec!(15));
+        assert!(fixture.history_repo.changes.lock().unwrap().is_empty());
+    }
+}
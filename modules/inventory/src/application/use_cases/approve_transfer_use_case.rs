@@ -0,0 +1,107 @@
+// ApproveTransferUseCase - approves or rejects a transfer awaiting approval
+
+use std::sync::Arc;
+
+use crate::InventoryError;
+use crate::application::dtos::commands::ApproveTransferCommand;
+use crate::application::dtos::responses::{TransferDetailResponse, TransferItemResponse};
+use crate::domain::entities::StockTransfer;
+use crate::domain::repositories::TransferRepository;
+use crate::domain::value_objects::TransferId;
+use identity::UserId;
+
+/// Use case for approving or rejecting a stock transfer.
+///
+/// Validates status is pending_approval and records the approver, timestamp
+/// and notes. Approved transfers become shippable; rejected ones are
+/// cancelled.
+pub struct ApproveTransferUseCase<T>
+where
+    T: TransferRepository,
+{
+    transfer_repo: Arc<T>,
+}
+
+impl<T> ApproveTransferUseCase<T>
+where
+    T: TransferRepository,
+{
+    /// Creates a new instance of ApproveTransferUseCase
+    pub fn new(transfer_repo: Arc<T>) -> Self {
+        Self { transfer_repo }
+    }
+
+    /// Executes the use case to approve or reject a transfer
+    ///
+    /// # Errors
+    /// * `InventoryError::TransferNotFound` - If transfer doesn't exist
+    /// * `InventoryError::InvalidStatusTransition` - If transfer is not in pending_approval status
+    pub async fn execute(
+        &self,
+        command: ApproveTransferCommand,
+        approver_id: UserId,
+    ) -> Result<TransferDetailResponse, InventoryError> {
+        let transfer_id = TransferId::from_uuid(command.transfer_id);
+        let mut transfer = self
+            .transfer_repo
+            .find_by_id_with_items(transfer_id)
+            .await?
+            .ok_or(InventoryError::TransferNotFound(command.transfer_id))?;
+
+        if command.approve {
+            transfer.approve(approver_id, command.notes)?;
+        } else {
+            transfer.reject(approver_id, command.notes)?;
+        }
+        self.transfer_repo.update(&transfer).await?;
+
+        Ok(Self::to_response(&transfer))
+    }
+
+    fn to_response(transfer: &StockTransfer) -> TransferDetailResponse {
+        let items: Vec<TransferItemResponse> = transfer
+            .items()
+            .iter()
+            .map(|item| TransferItemResponse {
+                id: item.id(),
+                transfer_id: item.transfer_id().into_uuid(),
+                product_id: item.product_id().map(|id| id.into_uuid()),
+                variant_id: item.variant_id().map(|id| id.into_uuid()),
+                product: None,
+                variant: None,
+                quantity_requested: item.quantity_requested(),
+                quantity_shipped: item.quantity_shipped(),
+                quantity_received: item.quantity_received(),
+                quantity_discrepancy: item.shipping_discrepancy(),
+                unit_cost: item.unit_cost(),
+                notes: item.notes().map(|s| s.to_string()),
+                created_at: item.created_at(),
+            })
+            .collect();
+
+        TransferDetailResponse {
+            id: transfer.id().into_uuid(),
+            transfer_number: transfer.transfer_number().to_string(),
+            from_store_id: transfer.from_store_id().into_uuid(),
+            to_store_id: transfer.to_store_id().into_uuid(),
+            status: transfer.status().to_string(),
+            requested_date: transfer.requested_date(),
+            shipped_date: transfer.shipped_date(),
+            received_date: transfer.received_date(),
+            requested_by_id: transfer.requested_by_id().into_uuid(),
+            shipped_by_id: transfer.shipped_by_id().map(|id| id.into_uuid()),
+            received_by_id: transfer.received_by_id().map(|id| id.into_uuid()),
+            notes: transfer.notes().map(|s| s.to_string()),
+            shipping_method: transfer.shipping_method().map(|s| s.to_string()),
+            tracking_number: transfer.tracking_number().map(|s| s.to_string()),
+            requires_approval: transfer.requires_approval(),
+            approval_reason: transfer.approval_reason().map(|s| s.to_string()),
+            approved_by_id: transfer.approved_by_id().map(|id| id.into_uuid()),
+            approved_at: transfer.approved_at(),
+            approval_notes: transfer.approval_notes().map(|s| s.to_string()),
+            items,
+            created_at: transfer.created_at(),
+            updated_at: transfer.updated_at(),
+        }
+    }
+}
@@ -236,6 +236,11 @@ where
             version: stock.version(),
             min_stock_level: stock.min_stock_level(),
             max_stock_level: stock.max_stock_level(),
+            reorder_point: stock.reorder_point(),
+            reorder_quantity: stock.reorder_quantity(),
+            suggested_reorder_quantity: stock
+                .is_low_stock()
+                .then(|| stock.suggested_reorder_quantity()),
             is_low_stock: stock.is_low_stock(),
             created_at: stock.created_at(),
             updated_at: stock.updated_at(),
@@ -0,0 +1,540 @@
+// BulkUpdatePricesUseCase - adjusts the prices of every product in a category
+
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use rust_decimal::Decimal;
+
+use crate::InventoryError;
+use crate::application::dtos::commands::BulkUpdatePricesCommand;
+use crate::application::dtos::responses::BulkUpdatePricesResponse;
+use crate::domain::repositories::{
+    CategoryRepository, PriceUpdate, PriceUpdateRepository, ProductRepository,
+};
+use crate::domain::value_objects::{CategoryId, PriceAdjustment};
+use identity::domain::entities::AuditEntry;
+use identity::domain::value_objects::UserId;
+
+/// Use case for adjusting the base and/or cost price of all products in a
+/// category, optionally including its subcategories.
+///
+/// Products whose new price would be negative are skipped and reported by
+/// SKU. The remaining products and one audit entry per product are written
+/// in a single transaction.
+pub struct BulkUpdatePricesUseCase<P, C, U>
+where
+    P: ProductRepository,
+    C: CategoryRepository,
+    U: PriceUpdateRepository,
+{
+    product_repo: Arc<P>,
+    category_repo: Arc<C>,
+    price_update_repo: Arc<U>,
+}
+
+impl<P, C, U> BulkUpdatePricesUseCase<P, C, U>
+where
+    P: ProductRepository,
+    C: CategoryRepository,
+    U: PriceUpdateRepository,
+{
+    /// Creates a new instance of BulkUpdatePricesUseCase
+    pub fn new(product_repo: Arc<P>, category_repo: Arc<C>, price_update_repo: Arc<U>) -> Self {
+        Self {
+            product_repo,
+            category_repo,
+            price_update_repo,
+        }
+    }
+
+    /// Executes the use case to adjust prices across a category
+    ///
+    /// # Arguments
+    /// * `command` - Category, adjustment and the prices it applies to
+    /// * `actor_id` - ID of the user performing this action (for audit)
+    ///
+    /// # Errors
+    /// * `InventoryError::InvalidPriceAdjustment` - If the type is unknown or no price is selected
+    /// * `InventoryError::CategoryNotFound` - If the category doesn't exist
+    pub async fn execute(
+        &self,
+        command: BulkUpdatePricesCommand,
+        actor_id: UserId,
+    ) -> Result<BulkUpdatePricesResponse, InventoryError> {
+        let adjustment = PriceAdjustment::parse(
+            &command.adjustment_type,
+            command.value,
+            command.apply_to_base_price,
+            command.apply_to_cost_price,
+        )?;
+
+        let category_id = CategoryId::from_uuid(command.category_id);
+        if self.category_repo.find_by_id(category_id).await?.is_none() {
+            return Err(InventoryError::CategoryNotFound(command.category_id));
+        }
+
+        let category_ids = if command.include_subcategories {
+            self.collect_subtree(category_id).await?
+        } else {
+            vec![category_id]
+        };
+
+        let mut updates = Vec::new();
+        let mut skipped_skus = Vec::new();
+        for id in &category_ids {
+            for product in self.product_repo.find_by_category(*id).await? {
+                let new_base = adjustment
+                    .applies_to_base_price()
+                    .then(|| adjustment.apply(product.base_price()));
+                let new_cost = adjustment
+                    .applies_to_cost_price()
+                    .then(|| adjustment.apply(product.cost_price()));
+
+                if [new_base, new_cost]
+                    .iter()
+                    .flatten()
+                    .any(|price| *price < Decimal::ZERO)
+                {
+                    skipped_skus.push(product.sku().to_string());
+                    continue;
+                }
+
+                let mut updated = product.clone();
+                if let Some(price) = new_base {
+                    updated.set_base_price(price);
+                }
+                if let Some(cost) = new_cost {
+                    updated.set_cost_price(cost);
+                }
+
+                let audit_entry = AuditEntry::for_update(
+                    "product",
+                    updated.id().into_uuid(),
+                    &product,
+                    &updated,
+                    actor_id,
+                );
+                updates.push(PriceUpdate {
+                    product: updated,
+                    audit_entry,
+                });
+            }
+        }
+
+        self.price_update_repo.apply(&updates).await?;
+
+        Ok(BulkUpdatePricesResponse {
+            category_id: command.category_id,
+            categories_included: category_ids.len() as i64,
+            products_updated: updates.len() as i64,
+            skipped_skus,
+        })
+    }
+
+    /// Returns the category and all its descendants, parents first
+    async fn collect_subtree(&self, root: CategoryId) -> Result<Vec<CategoryId>, InventoryError> {
+        let mut seen = HashSet::from([root]);
+        let mut ids = vec![root];
+        let mut next = 0;
+        while next < ids.len() {
+            for child in self.category_repo.find_children(ids[next]).await? {
+                if seen.insert(child.id()) {
+                    ids.push(child.id());
+                }
+            }
+            next += 1;
+        }
+        Ok(ids)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use async_trait::async_trait;
+    use rust_decimal_macros::dec;
+    use std::sync::Mutex;
+    use uuid::Uuid;
+
+    use crate::domain::entities::{Product, ProductCategory, ProductVariant, ProductVendor};
+    use crate::domain::repositories::CategoryStockValuation;
+    use crate::domain::value_objects::{
+        Barcode, ProductId, Sku, UnitConversion, UnitOfMeasure, VariantId,
+    };
+    use identity::StoreId;
+
+    struct MockProductRepository {
+        products: Vec<Product>,
+    }
+
+    #[async_trait]
+    impl ProductRepository for MockProductRepository {
+        async fn save(&self, _product: &Product) -> Result<(), InventoryError> {
+            unimplemented!()
+        }
+
+        async fn find_by_id(&self, _id: ProductId) -> Result<Option<Product>, InventoryError> {
+            unimplemented!()
+        }
+
+        async fn find_by_sku(&self, _sku: &Sku) -> Result<Option<Product>, InventoryError> {
+            unimplemented!()
+        }
+
+        async fn find_by_barcode(
+            &self,
+            _barcode: &Barcode,
+        ) -> Result<Option<Product>, InventoryError> {
+            unimplemented!()
+        }
+
+        async fn update(&self, _product: &Product) -> Result<(), InventoryError> {
+            unimplemented!()
+        }
+
+        async fn delete(&self, _id: ProductId) -> Result<(), InventoryError> {
+            unimplemented!()
+        }
+
+        async fn find_active(&self) -> Result<Vec<Product>, InventoryError> {
+            unimplemented!()
+        }
+
+        async fn find_by_category(
+            &self,
+            category_id: CategoryId,
+        ) -> Result<Vec<Product>, InventoryError> {
+            Ok(self
+                .products
+                .iter()
+                .filter(|p| p.category_id() == Some(category_id))
+                .cloned()
+                .collect())
+        }
+
+        async fn find_paginated(
+            &self,
+            _category_id: Option<CategoryId>,
+            _is_active: Option<bool>,
+            _search: Option<&str>,
+            _after_id: Option<Uuid>,
+            _page: i64,
+            _page_size: i64,
+        ) -> Result<(Vec<Product>, i64), InventoryError> {
+            unimplemented!()
+        }
+
+        async fn count_filtered(
+            &self,
+            _category_id: Option<CategoryId>,
+            _is_active: Option<bool>,
+            _search: Option<&str>,
+        ) -> Result<i64, InventoryError> {
+            unimplemented!()
+        }
+
+        async fn search(
+            &self,
+            _term: &str,
+            _category_id: Option<CategoryId>,
+            _active_only: bool,
+            _page: i64,
+            _page_size: i64,
+        ) -> Result<(Vec<(Product, f64)>, i64), InventoryError> {
+            unimplemented!()
+        }
+
+        async fn save_variant(&self, _variant: &ProductVariant) -> Result<(), InventoryError> {
+            unimplemented!()
+        }
+
+        async fn find_variant_by_id(
+            &self,
+            _id: VariantId,
+        ) -> Result<Option<ProductVariant>, InventoryError> {
+            unimplemented!()
+        }
+
+        async fn find_variant_by_sku(
+            &self,
+            _sku: &Sku,
+        ) -> Result<Option<ProductVariant>, InventoryError> {
+            unimplemented!()
+        }
+
+        async fn find_variant_by_barcode(
+            &self,
+            _barcode: &Barcode,
+        ) -> Result<Option<ProductVariant>, InventoryError> {
+            unimplemented!()
+        }
+
+        async fn find_variants_by_product(
+            &self,
+            _product_id: ProductId,
+        ) -> Result<Vec<ProductVariant>, InventoryError> {
+            unimplemented!()
+        }
+
+        async fn update_variant(&self, _variant: &ProductVariant) -> Result<(), InventoryError> {
+            unimplemented!()
+        }
+
+        async fn delete_variant(&self, _id: VariantId) -> Result<(), InventoryError> {
+            unimplemented!()
+        }
+
+        async fn count_variants(&self, _product_id: ProductId) -> Result<u32, InventoryError> {
+            unimplemented!()
+        }
+
+        async fn save_product_vendors(
+            &self,
+            _vendors: &[ProductVendor],
+        ) -> Result<(), InventoryError> {
+            unimplemented!()
+        }
+
+        async fn find_product_vendors(
+            &self,
+            _product_id: ProductId,
+        ) -> Result<Vec<ProductVendor>, InventoryError> {
+            unimplemented!()
+        }
+
+        async fn find_preferred_vendor(
+            &self,
+            _product_id: ProductId,
+        ) -> Result<Option<ProductVendor>, InventoryError> {
+            unimplemented!()
+        }
+
+        async fn replace_unit_conversions(
+            &self,
+            _product_id: ProductId,
+            _conversions: &[UnitConversion],
+        ) -> Result<(), InventoryError> {
+            unimplemented!()
+        }
+
+        async fn find_unit_conversions(
+            &self,
+            _product_id: ProductId,
+        ) -> Result<Vec<UnitConversion>, InventoryError> {
+            unimplemented!()
+        }
+    }
+
+    struct MockCategoryRepository {
+        categories: Vec<ProductCategory>,
+    }
+
+    #[async_trait]
+    impl CategoryRepository for MockCategoryRepository {
+        async fn save(&self, _category: &ProductCategory) -> Result<(), InventoryError> {
+            unimplemented!()
+        }
+
+        async fn find_by_id(
+            &self,
+            id: CategoryId,
+        ) -> Result<Option<ProductCategory>, InventoryError> {
+            Ok(self.categories.iter().find(|c| c.id() == id).cloned())
+        }
+
+        async fn find_by_slug(
+            &self,
+            _slug: &str,
+        ) -> Result<Option<ProductCategory>, InventoryError> {
+            unimplemented!()
+        }
+
+        async fn find_root_categories(&self) -> Result<Vec<ProductCategory>, InventoryError> {
+            unimplemented!()
+        }
+
+        async fn find_children(
+            &self,
+            parent_id: CategoryId,
+        ) -> Result<Vec<ProductCategory>, InventoryError> {
+            Ok(self
+                .categories
+                .iter()
+                .filter(|c| c.parent_id() == Some(parent_id))
+                .cloned()
+                .collect())
+        }
+
+        async fn find_all_active(&self) -> Result<Vec<ProductCategory>, InventoryError> {
+            unimplemented!()
+        }
+
+        async fn update(&self, _category: &ProductCategory) -> Result<(), InventoryError> {
+            unimplemented!()
+        }
+
+        async fn stock_valuation_by_category(
+            &self,
+            _store_id: Option<StoreId>,
+        ) -> Result<Vec<CategoryStockValuation>, InventoryError> {
+            unimplemented!()
+        }
+
+        async fn delete(&self, _id: CategoryId) -> Result<(), InventoryError> {
+            unimplemented!()
+        }
+    }
+
+    struct MockPriceUpdateRepository {
+        applied: Mutex<Vec<PriceUpdate>>,
+    }
+
+    #[async_trait]
+    impl PriceUpdateRepository for MockPriceUpdateRepository {
+        async fn apply(&self, updates: &[PriceUpdate]) -> Result<(), InventoryError> {
+            self.applied.lock().unwrap().extend_from_slice(updates);
+            Ok(())
+        }
+    }
+
+    fn product(category: &ProductCategory, base_price: Decimal, cost_price: Decimal) -> Product {
+        let mut p = Product::create("Item".to_string(), UnitOfMeasure::Unit, None);
+        p.set_category_id(Some(category.id()));
+        p.set_base_price(base_price);
+        p.set_cost_price(cost_price);
+        p
+    }
+
+    fn command(
+        category: &ProductCategory,
+        adjustment_type: &str,
+        value: Decimal,
+    ) -> BulkUpdatePricesCommand {
+        BulkUpdatePricesCommand {
+            category_id: category.id().into_uuid(),
+            include_subcategories: false,
+            adjustment_type: adjustment_type.to_string(),
+            value,
+            apply_to_base_price: true,
+            apply_to_cost_price: false,
+        }
+    }
+
+    fn setup(
+        categories: Vec<ProductCategory>,
+        products: Vec<Product>,
+    ) -> (
+        BulkUpdatePricesUseCase<
+            MockProductRepository,
+            MockCategoryRepository,
+            MockPriceUpdateRepository,
+        >,
+        Arc<MockPriceUpdateRepository>,
+    ) {
+        let price_update_repo = Arc::new(MockPriceUpdateRepository {
+            applied: Mutex::new(Vec::new()),
+        });
+        let use_case = BulkUpdatePricesUseCase::new(
+            Arc::new(MockProductRepository { products }),
+            Arc::new(MockCategoryRepository { categories }),
+            price_update_repo.clone(),
+        );
+        (use_case, price_update_repo)
+    }
+
+    #[tokio::test]
+    async fn test_percentage_markup_with_audit_per_product() {
+        let drinks = ProductCategory::create("Drinks".to_string(), "drinks".to_string());
+        let products = vec![
+            product(&drinks, dec!(20), dec!(12)),
+            product(&drinks, dec!(35), dec!(20)),
+        ];
+        let (use_case, repo) = setup(vec![drinks.clone()], products);
+
+        let result = use_case
+            .execute(command(&drinks, "percentage", dec!(10)), UserId::new())
+            .await
+            .unwrap();
+
+        assert_eq!(result.products_updated, 2);
+        assert!(result.skipped_skus.is_empty());
+        let applied = repo.applied.lock().unwrap();
+        let prices: Vec<_> = applied.iter().map(|u| u.product.base_price()).collect();
+        assert_eq!(prices, vec![dec!(22), dec!(38.5)]);
+        // Cost prices are untouched unless selected
+        assert_eq!(applied[0].product.cost_price(), dec!(12));
+        assert!(
+            applied
+                .iter()
+                .all(|u| u.audit_entry.entity_id() == u.product.id().into_uuid())
+        );
+    }
+
+    #[tokio::test]
+    async fn test_skips_products_that_would_go_negative() {
+        let snacks = ProductCategory::create("Snacks".to_string(), "snacks".to_string());
+        let cheap = product(&snacks, dec!(3), dec!(1));
+        let cheap_sku = cheap.sku().to_string();
+        let (use_case, repo) = setup(
+            vec![snacks.clone()],
+            vec![cheap, product(&snacks, dec!(10), dec!(6))],
+        );
+
+        let mut cmd = command(&snacks, "fixed_amount", dec!(-5));
+        cmd.apply_to_cost_price = true;
+        let result = use_case.execute(cmd, UserId::new()).await.unwrap();
+
+        assert_eq!(result.products_updated, 1);
+        assert_eq!(result.skipped_skus, vec![cheap_sku]);
+        let applied = repo.applied.lock().unwrap();
+        assert_eq!(applied[0].product.base_price(), dec!(5));
+        assert_eq!(applied[0].product.cost_price(), dec!(1));
+    }
+
+    #[tokio::test]
+    async fn test_includes_subcategories_when_requested() {
+        let food = ProductCategory::create("Food".to_string(), "food".to_string());
+        let dairy = ProductCategory::create_subcategory(
+            food.id(),
+            "Dairy".to_string(),
+            "dairy".to_string(),
+        );
+        let cheese = ProductCategory::create_subcategory(
+            dairy.id(),
+            "Cheese".to_string(),
+            "cheese".to_string(),
+        );
+        let products = vec![
+            product(&food, dec!(10), dec!(5)),
+            product(&dairy, dec!(10), dec!(5)),
+            product(&cheese, dec!(10), dec!(5)),
+        ];
+        let categories = vec![food.clone(), dairy, cheese];
+
+        let (use_case, _) = setup(categories.clone(), products.clone());
+        let result = use_case
+            .execute(command(&food, "percentage", dec!(5)), UserId::new())
+            .await
+            .unwrap();
+        assert_eq!(result.products_updated, 1);
+
+        let (use_case, _) = setup(categories, products);
+        let mut cmd = command(&food, "percentage", dec!(5));
+        cmd.include_subcategories = true;
+        let result = use_case.execute(cmd, UserId::new()).await.unwrap();
+        assert_eq!(result.categories_included, 3);
+        assert_eq!(result.products_updated, 3);
+    }
+
+    #[tokio::test]
+    async fn test_category_not_found() {
+        let missing = ProductCategory::create("Gone".to_string(), "gone".to_string());
+        let (use_case, _) = setup(vec![], vec![]);
+
+        let result = use_case
+            .execute(command(&missing, "percentage", dec!(10)), UserId::new())
+            .await;
+
+        assert!(matches!(result, Err(InventoryError::CategoryNotFound(_))));
+    }
+}
@@ -1,6 +1,6 @@
 // CalculateRecipeCostUseCase - calculates the cost of a recipe from its ingredients
 //
-// - Sum ingredient costs with waste percentage
+// - Sum ingredient costs with waste percentage, converting units
 // - Divide by yield_quantity
 
 use std::sync::Arc;
@@ -8,20 +8,28 @@ use std::sync::Arc;
 use rust_decimal::Decimal;
 
 use crate::InventoryError;
-use crate::domain::repositories::RecipeRepository;
-use crate::domain::value_objects::RecipeId;
+use crate::domain::entities::RecipeIngredient;
+use crate::domain::repositories::{ProductRepository, RecipeRepository};
+use crate::domain::value_objects::{RecipeId, UnitOfMeasure};
 
 /// Response containing the calculated recipe cost
 #[derive(Debug, Clone)]
 pub struct RecipeCostResult {
     /// The recipe ID
     pub recipe_id: uuid::Uuid,
-    /// Total cost of all ingredients (including waste)
+    /// Total cost of all ingredients (including waste), i.e. of one batch
     pub total_ingredient_cost: Decimal,
-    /// Cost per unit produced (total_ingredient_cost / yield_quantity)
+    /// Cost per yield unit produced (total_ingredient_cost / yield_quantity)
     pub cost_per_unit: Decimal,
     /// The yield quantity used in calculation
     pub yield_quantity: Decimal,
+    /// Unit the yield is measured in
+    pub yield_unit: UnitOfMeasure,
+    /// Base unit of the yield unit (kg, liter or unit)
+    pub base_unit: UnitOfMeasure,
+    /// Cost per base unit produced, to compare recipes yielding in
+    /// different units of the same kind
+    pub cost_per_base_unit: Decimal,
     /// Number of ingredients included in calculation
     pub ingredient_count: usize,
     /// Number of ingredients with cost data
@@ -33,22 +41,32 @@ pub struct RecipeCostResult {
 /// Calculates the total cost of a recipe by summing all ingredient costs
 /// (including waste percentage) and dividing by the yield quantity.
 ///
+/// An ingredient's estimated cost is per unit of the product it draws on.
+/// Ingredients measured in another unit (ounces of a product stocked by the
+/// pound) are converted to the product's unit first.
+///
 /// Formula per ingredient: quantity * (1 + waste_percentage) * cost_per_unit
 /// Total cost per unit: sum(ingredient_costs) / yield_quantity
-pub struct CalculateRecipeCostUseCase<R>
+pub struct CalculateRecipeCostUseCase<R, P>
 where
     R: RecipeRepository,
+    P: ProductRepository,
 {
     recipe_repo: Arc<R>,
+    product_repo: Arc<P>,
 }
 
-impl<R> CalculateRecipeCostUseCase<R>
+impl<R, P> CalculateRecipeCostUseCase<R, P>
 where
     R: RecipeRepository,
+    P: ProductRepository,
 {
     /// Creates a new instance of CalculateRecipeCostUseCase
-    pub fn new(recipe_repo: Arc<R>) -> Self {
-        Self { recipe_repo }
+    pub fn new(recipe_repo: Arc<R>, product_repo: Arc<P>) -> Self {
+        Self {
+            recipe_repo,
+            product_repo,
+        }
     }
 
     /// Executes the use case to calculate recipe cost
@@ -61,6 +79,8 @@ where
     ///
     /// # Errors
     /// * `InventoryError::RecipeNotFound` - If the recipe doesn't exist
+    /// * `InventoryError::IncompatibleUnits` - If an ingredient's unit can't be
+    ///   converted to its product's unit, e.g. liters of a product sold by weight
     pub async fn execute(&self, recipe_id: uuid::Uuid) -> Result<RecipeCostResult, InventoryError> {
         let recipe_id_vo = RecipeId::from_uuid(recipe_id);
 
@@ -84,8 +104,9 @@ where
         // Calculate total ingredient cost (Requirement 7.4)
         // Formula: sum(quantity * (1 + waste_percentage) * cost_per_unit)
         for ingredient in &ingredients {
+            let factor = self.unit_factor(ingredient).await?;
             if let Some(effective_cost) = ingredient.calculate_effective_cost() {
-                total_ingredient_cost += effective_cost;
+                total_ingredient_cost += effective_cost * factor;
                 ingredients_with_cost += 1;
             }
         }
@@ -94,15 +115,48 @@ where
         // Formula: total_ingredient_cost / yield_quantity
         let cost_per_unit = recipe.calculate_cost(total_ingredient_cost);
 
+        let yield_unit = recipe.yield_unit();
+
         Ok(RecipeCostResult {
             recipe_id,
             total_ingredient_cost,
             cost_per_unit,
             yield_quantity: recipe.yield_quantity(),
+            yield_unit,
+            base_unit: yield_unit.base_unit(),
+            cost_per_base_unit: cost_per_unit / yield_unit.base_factor(),
             ingredient_count,
             ingredients_with_cost,
         })
     }
+
+    /// Product units in one unit of the ingredient's measure. Ingredients
+    /// whose product can't be found are costed in their own unit.
+    async fn unit_factor(&self, ingredient: &RecipeIngredient) -> Result<Decimal, InventoryError> {
+        let product_id = match (
+            ingredient.ingredient_product_id(),
+            ingredient.ingredient_variant_id(),
+        ) {
+            (Some(product_id), _) => Some(product_id),
+            (None, Some(variant_id)) => self
+                .product_repo
+                .find_variant_by_id(variant_id)
+                .await?
+                .map(|v| v.product_id()),
+            (None, None) => None,
+        };
+        let product = match product_id {
+            Some(product_id) => self.product_repo.find_by_id(product_id).await?,
+            None => None,
+        };
+
+        match product {
+            Some(product) => ingredient
+                .unit_of_measure()
+                .convert(Decimal::ONE, product.unit_of_measure()),
+            None => Ok(Decimal::ONE),
+        }
+    }
 }
 
 #[cfg(test)]
@@ -112,10 +166,13 @@ mod tests {
     use rust_decimal_macros::dec;
     use std::collections::HashMap;
     use std::sync::Mutex;
+    use uuid::Uuid;
 
-    use crate::domain::entities::{IngredientSubstitute, Recipe, RecipeIngredient};
+    use crate::domain::entities::{
+        IngredientSubstitute, Product, ProductVariant, ProductVendor, Recipe,
+    };
     use crate::domain::value_objects::{
-        IngredientId, ProductId, SubstituteId, UnitOfMeasure, VariantId,
+        Barcode, IngredientId, ProductId, Sku, SubstituteId, UnitConversion, VariantId,
     };
 
     // Mock repository for testing
@@ -188,6 +245,7 @@ mod tests {
             &self,
             _is_active: Option<bool>,
             _search: Option<&str>,
+            _after_id: Option<Uuid>,
             _page: i64,
             _page_size: i64,
         ) -> Result<(Vec<Recipe>, i64), InventoryError> {
@@ -265,6 +323,200 @@ mod tests {
         async fn delete_substitute(&self, _id: SubstituteId) -> Result<(), InventoryError> {
             unimplemented!()
         }
+
+        async fn find_composite_cost_sources(
+            &self,
+        ) -> Result<Vec<crate::domain::repositories::CompositeCostSource>, InventoryError> {
+            unimplemented!()
+        }
+
+        async fn find_weighted_average_costs(
+            &self,
+        ) -> Result<Vec<crate::domain::repositories::ItemUnitCost>, InventoryError> {
+            unimplemented!()
+        }
+
+        async fn update_composite_cost_price(
+            &self,
+            _recipe: &Recipe,
+            _cost_price: Decimal,
+        ) -> Result<(), InventoryError> {
+            unimplemented!()
+        }
+    }
+
+    struct MockProductRepository {
+        products: Mutex<HashMap<ProductId, Product>>,
+        variants: Mutex<HashMap<VariantId, ProductVariant>>,
+    }
+
+    impl MockProductRepository {
+        fn new() -> Self {
+            Self {
+                products: Mutex::new(HashMap::new()),
+                variants: Mutex::new(HashMap::new()),
+            }
+        }
+
+        fn add_product(&self, product: Product) {
+            let mut products = self.products.lock().unwrap();
+            products.insert(product.id(), product);
+        }
+    }
+
+    #[async_trait]
+    impl ProductRepository for MockProductRepository {
+        async fn save(&self, _product: &Product) -> Result<(), InventoryError> {
+            unimplemented!()
+        }
+
+        async fn find_by_id(&self, id: ProductId) -> Result<Option<Product>, InventoryError> {
+            let products = self.products.lock().unwrap();
+            Ok(products.get(&id).cloned())
+        }
+
+        async fn find_by_sku(&self, _sku: &Sku) -> Result<Option<Product>, InventoryError> {
+            unimplemented!()
+        }
+
+        async fn find_by_barcode(
+            &self,
+            _barcode: &Barcode,
+        ) -> Result<Option<Product>, InventoryError> {
+            unimplemented!()
+        }
+
+        async fn update(&self, _product: &Product) -> Result<(), InventoryError> {
+            unimplemented!()
+        }
+
+        async fn delete(&self, _id: ProductId) -> Result<(), InventoryError> {
+            unimplemented!()
+        }
+
+        async fn find_active(&self) -> Result<Vec<Product>, InventoryError> {
+            unimplemented!()
+        }
+
+        async fn find_by_category(
+            &self,
+            _category_id: crate::domain::value_objects::CategoryId,
+        ) -> Result<Vec<Product>, InventoryError> {
+            unimplemented!()
+        }
+
+        async fn save_variant(&self, _variant: &ProductVariant) -> Result<(), InventoryError> {
+            unimplemented!()
+        }
+
+        async fn find_variant_by_id(
+            &self,
+            id: VariantId,
+        ) -> Result<Option<ProductVariant>, InventoryError> {
+            let variants = self.variants.lock().unwrap();
+            Ok(variants.get(&id).cloned())
+        }
+
+        async fn find_variant_by_sku(
+            &self,
+            _sku: &Sku,
+        ) -> Result<Option<ProductVariant>, InventoryError> {
+            unimplemented!()
+        }
+
+        async fn find_variant_by_barcode(
+            &self,
+            _barcode: &Barcode,
+        ) -> Result<Option<ProductVariant>, InventoryError> {
+            unimplemented!()
+        }
+
+        async fn find_variants_by_product(
+            &self,
+            _product_id: ProductId,
+        ) -> Result<Vec<ProductVariant>, InventoryError> {
+            unimplemented!()
+        }
+
+        async fn update_variant(&self, _variant: &ProductVariant) -> Result<(), InventoryError> {
+            unimplemented!()
+        }
+
+        async fn delete_variant(&self, _id: VariantId) -> Result<(), InventoryError> {
+            unimplemented!()
+        }
+
+        async fn count_variants(&self, _product_id: ProductId) -> Result<u32, InventoryError> {
+            unimplemented!()
+        }
+
+        async fn save_product_vendors(
+            &self,
+            _vendors: &[ProductVendor],
+        ) -> Result<(), InventoryError> {
+            unimplemented!()
+        }
+
+        async fn find_product_vendors(
+            &self,
+            _product_id: ProductId,
+        ) -> Result<Vec<ProductVendor>, InventoryError> {
+            unimplemented!()
+        }
+
+        async fn find_preferred_vendor(
+            &self,
+            _product_id: ProductId,
+        ) -> Result<Option<ProductVendor>, InventoryError> {
+            unimplemented!()
+        }
+
+        async fn find_paginated(
+            &self,
+            _category_id: Option<crate::domain::value_objects::CategoryId>,
+            _is_active: Option<bool>,
+            _search: Option<&str>,
+            _after_id: Option<Uuid>,
+            _page: i64,
+            _page_size: i64,
+        ) -> Result<(Vec<Product>, i64), InventoryError> {
+            unimplemented!()
+        }
+
+        async fn count_filtered(
+            &self,
+            _category_id: Option<crate::domain::value_objects::CategoryId>,
+            _is_active: Option<bool>,
+            _search: Option<&str>,
+        ) -> Result<i64, InventoryError> {
+            unimplemented!()
+        }
+
+        async fn search(
+            &self,
+            _term: &str,
+            _category_id: Option<crate::domain::value_objects::CategoryId>,
+            _active_only: bool,
+            _page: i64,
+            _page_size: i64,
+        ) -> Result<(Vec<(Product, f64)>, i64), InventoryError> {
+            unimplemented!()
+        }
+
+        async fn replace_unit_conversions(
+            &self,
+            _product_id: ProductId,
+            _conversions: &[UnitConversion],
+        ) -> Result<(), InventoryError> {
+            unimplemented!()
+        }
+
+        async fn find_unit_conversions(
+            &self,
+            _product_id: ProductId,
+        ) -> Result<Vec<UnitConversion>, InventoryError> {
+            unimplemented!()
+        }
     }
 
     #[tokio::test]
@@ -300,7 +552,8 @@ mod tests {
         ing2.set_estimated_cost_per_unit(Some(dec!(5)));
         repo.add_ingredient(recipe_id, ing2);
 
-        let use_case = CalculateRecipeCostUseCase::new(repo);
+        let use_case =
+            CalculateRecipeCostUseCase::new(repo, Arc::new(MockProductRepository::new()));
         let result = use_case.execute(recipe_id.into_uuid()).await.unwrap();
 
         // Total: $20 + $5 = $25
@@ -336,7 +589,8 @@ mod tests {
         ing.set_estimated_waste_percentage(dec!(0.1)).unwrap();
         repo.add_ingredient(recipe_id, ing);
 
-        let use_case = CalculateRecipeCostUseCase::new(repo);
+        let use_case =
+            CalculateRecipeCostUseCase::new(repo, Arc::new(MockProductRepository::new()));
         let result = use_case.execute(recipe_id.into_uuid()).await.unwrap();
 
         // Total: $22
@@ -377,7 +631,8 @@ mod tests {
         .unwrap();
         repo.add_ingredient(recipe_id, ing2);
 
-        let use_case = CalculateRecipeCostUseCase::new(repo);
+        let use_case =
+            CalculateRecipeCostUseCase::new(repo, Arc::new(MockProductRepository::new()));
         let result = use_case.execute(recipe_id.into_uuid()).await.unwrap();
 
         // Only ingredient 1 has cost: $20
@@ -399,7 +654,8 @@ mod tests {
         let recipe_id = recipe.id();
         repo.add_recipe(recipe);
 
-        let use_case = CalculateRecipeCostUseCase::new(repo);
+        let use_case =
+            CalculateRecipeCostUseCase::new(repo, Arc::new(MockProductRepository::new()));
         let result = use_case.execute(recipe_id.into_uuid()).await.unwrap();
 
         assert_eq!(result.total_ingredient_cost, dec!(0));
@@ -411,11 +667,102 @@ mod tests {
     #[tokio::test]
     async fn test_calculate_recipe_cost_not_found() {
         let repo = Arc::new(MockRecipeRepository::new());
-        let use_case = CalculateRecipeCostUseCase::new(repo);
+        let use_case =
+            CalculateRecipeCostUseCase::new(repo, Arc::new(MockProductRepository::new()));
 
         let non_existent_id = RecipeId::new().into_uuid();
         let result = use_case.execute(non_existent_id).await;
 
         assert!(matches!(result, Err(InventoryError::RecipeNotFound(_))));
     }
+
+    #[tokio::test]
+    async fn test_calculate_recipe_cost_converts_to_product_unit() {
+        let repo = Arc::new(MockRecipeRepository::new());
+        let products = Arc::new(MockProductRepository::new());
+
+        let recipe =
+            Recipe::create_for_product(ProductId::new(), "Burger".to_string(), dec!(1)).unwrap();
+        let recipe_id = recipe.id();
+        repo.add_recipe(recipe);
+
+        // Cheese is stocked by the pound at $4/lb; the recipe uses 8 oz
+        let cheese = Product::create("Cheese".to_string(), UnitOfMeasure::Lb, None);
+        let mut ing = RecipeIngredient::create_for_product(
+            recipe_id,
+            cheese.id(),
+            dec!(8),
+            UnitOfMeasure::Oz,
+        )
+        .unwrap();
+        ing.set_estimated_cost_per_unit(Some(dec!(4)));
+        repo.add_ingredient(recipe_id, ing);
+        products.add_product(cheese);
+
+        let use_case = CalculateRecipeCostUseCase::new(repo, products);
+        let result = use_case.execute(recipe_id.into_uuid()).await.unwrap();
+
+        assert_eq!(result.total_ingredient_cost.round_dp(6), dec!(2));
+    }
+
+    #[tokio::test]
+    async fn test_calculate_recipe_cost_incompatible_units() {
+        let repo = Arc::new(MockRecipeRepository::new());
+        let products = Arc::new(MockProductRepository::new());
+
+        let recipe =
+            Recipe::create_for_product(ProductId::new(), "Sauce".to_string(), dec!(1)).unwrap();
+        let recipe_id = recipe.id();
+        repo.add_recipe(recipe);
+
+        let sugar = Product::create("Sugar".to_string(), UnitOfMeasure::Kg, None);
+        let ing = RecipeIngredient::create_for_product(
+            recipe_id,
+            sugar.id(),
+            dec!(1),
+            UnitOfMeasure::Liter,
+        )
+        .unwrap();
+        repo.add_ingredient(recipe_id, ing);
+        products.add_product(sugar);
+
+        let use_case = CalculateRecipeCostUseCase::new(repo, products);
+        let result = use_case.execute(recipe_id.into_uuid()).await;
+
+        assert!(matches!(
+            result,
+            Err(InventoryError::IncompatibleUnits { .. })
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_calculate_recipe_cost_per_yield_and_base_unit() {
+        let repo = Arc::new(MockRecipeRepository::new());
+
+        // A batch yields 2 lb of dough
+        let mut recipe =
+            Recipe::create_for_product(ProductId::new(), "Dough".to_string(), dec!(2)).unwrap();
+        recipe.set_yield_unit(UnitOfMeasure::Lb);
+        let recipe_id = recipe.id();
+        repo.add_recipe(recipe);
+
+        let mut ing = RecipeIngredient::create_for_product(
+            recipe_id,
+            ProductId::new(),
+            dec!(1),
+            UnitOfMeasure::Kg,
+        )
+        .unwrap();
+        ing.set_estimated_cost_per_unit(Some(dec!(9.0718474)));
+        repo.add_ingredient(recipe_id, ing);
+
+        let use_case =
+            CalculateRecipeCostUseCase::new(repo, Arc::new(MockProductRepository::new()));
+        let result = use_case.execute(recipe_id.into_uuid()).await.unwrap();
+
+        assert_eq!(result.cost_per_unit, dec!(4.5359237));
+        assert_eq!(result.yield_unit, UnitOfMeasure::Lb);
+        assert_eq!(result.base_unit, UnitOfMeasure::Kg);
+        assert_eq!(result.cost_per_base_unit, dec!(10));
+    }
 }
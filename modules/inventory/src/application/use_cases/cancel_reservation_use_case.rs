@@ -92,7 +92,11 @@ where
             reference_id: reservation.reference_id(),
             quantity: reservation.quantity(),
             status: reservation.status().to_string(),
+            priority: reservation.priority(),
             expires_at: reservation.expires_at(),
+            auto_extend_seconds: reservation.auto_extend_seconds(),
+            bumped_at: reservation.bumped_at(),
+            bump_reason: reservation.bump_reason().map(str::to_string),
             created_at: reservation.created_at(),
             updated_at: reservation.updated_at(),
         })
@@ -103,13 +107,14 @@ where
 mod tests {
     use super::*;
     use async_trait::async_trait;
-    use chrono::{Duration, Utc};
+    use chrono::{DateTime, Duration, Utc};
     use rust_decimal_macros::dec;
     use std::collections::HashMap;
     use std::sync::Mutex;
     use uuid::{NoContext, Timestamp, Uuid};
 
     use crate::domain::entities::{InventoryReservation, InventoryStock};
+    use crate::domain::repositories::ReservationStatusCount;
     use crate::domain::value_objects::{ProductId, StockId};
     use identity::StoreId;
 
@@ -182,14 +187,26 @@ mod tests {
 
         async fn find_paginated(
             &self,
+            _store_ids: Option<&[StoreId]>,
             _stock_id: Option<StockId>,
             _status: Option<&str>,
             _reference_type: Option<&str>,
+            _after_id: Option<Uuid>,
             _page: i64,
             _page_size: i64,
         ) -> Result<(Vec<InventoryReservation>, i64), InventoryError> {
             unimplemented!()
         }
+
+        async fn count_by_status(
+            &self,
+            _store_id: StoreId,
+            _from: DateTime<Utc>,
+            _to: DateTime<Utc>,
+            _by_day: bool,
+        ) -> Result<Vec<ReservationStatusCount>, InventoryError> {
+            unimplemented!()
+        }
     }
 
     struct MockStockRepository {
@@ -271,9 +288,10 @@ mod tests {
 
         async fn find_paginated(
             &self,
-            _store_id: Option<StoreId>,
+            _store_ids: Option<&[StoreId]>,
             _product_id: Option<crate::domain::value_objects::ProductId>,
             _low_stock_only: bool,
+            _after_id: Option<Uuid>,
             _page: i64,
             _page_size: i64,
         ) -> Result<(Vec<InventoryStock>, i64), InventoryError> {
@@ -344,6 +362,7 @@ mod tests {
             new_uuid(),
             dec!(30),
             future_time(),
+            0,
         )
         .unwrap();
         let reservation_id = reservation.id();
@@ -406,6 +425,7 @@ mod tests {
             new_uuid(),
             dec!(30),
             future_time(),
+            0,
         )
         .unwrap();
         reservation.cancel().unwrap();
@@ -445,6 +465,7 @@ mod tests {
             new_uuid(),
             dec!(30),
             future_time(),
+            0,
         )
         .unwrap();
         reservation.confirm().unwrap();
@@ -52,6 +52,7 @@ where
                 quantity_requested: item.quantity_requested(),
                 quantity_shipped: item.quantity_shipped(),
                 quantity_received: item.quantity_received(),
+                quantity_discrepancy: item.shipping_discrepancy(),
                 unit_cost: item.unit_cost(),
                 notes: item.notes().map(|s| s.to_string()),
                 created_at: item.created_at(),
@@ -73,6 +74,11 @@ where
             notes: transfer.notes().map(|s| s.to_string()),
             shipping_method: transfer.shipping_method().map(|s| s.to_string()),
             tracking_number: transfer.tracking_number().map(|s| s.to_string()),
+            requires_approval: transfer.requires_approval(),
+            approval_reason: transfer.approval_reason().map(|s| s.to_string()),
+            approved_by_id: transfer.approved_by_id().map(|id| id.into_uuid()),
+            approved_at: transfer.approved_at(),
+            approval_notes: transfer.approval_notes().map(|s| s.to_string()),
             items,
             created_at: transfer.created_at(),
             updated_at: transfer.updated_at(),
@@ -0,0 +1,244 @@
+// ClosePeriodUseCase - closes an inventory period of a store
+
+use std::sync::Arc;
+
+use chrono::Utc;
+use identity::{StoreId, UserId};
+
+use crate::InventoryError;
+use crate::application::dtos::commands::ClosePeriodCommand;
+use crate::application::dtos::responses::InventoryPeriodResponse;
+use crate::domain::entities::InventoryPeriod;
+use crate::domain::repositories::InventoryPeriodRepository;
+
+/// Use case for closing an inventory period
+///
+/// Once closed, stock updates, adjustments, goods receipts and sales dated
+/// in the period are rejected unless the actor may reopen periods.
+pub struct ClosePeriodUseCase<R>
+where
+    R: InventoryPeriodRepository,
+{
+    period_repo: Arc<R>,
+}
+
+impl<R> ClosePeriodUseCase<R>
+where
+    R: InventoryPeriodRepository,
+{
+    /// Creates a new instance of ClosePeriodUseCase
+    pub fn new(period_repo: Arc<R>) -> Self {
+        Self { period_repo }
+    }
+
+    /// Executes the use case to close a period
+    ///
+    /// # Errors
+    /// * `InventoryError::InvalidPeriodRange` - If the period starts after it ends
+    /// * `InventoryError::PeriodNotEnded` - If `period_end` is today or later
+    /// * `InventoryError::PeriodOverlap` - If the range overlaps another period
+    /// * `InventoryError::PeriodAlreadyClosed` - If the period is already closed
+    pub async fn execute(
+        &self,
+        command: ClosePeriodCommand,
+        actor_id: UserId,
+    ) -> Result<InventoryPeriodResponse, InventoryError> {
+        if command.period_start > command.period_end {
+            return Err(InventoryError::InvalidPeriodRange);
+        }
+        if command.period_end >= Utc::now().date_naive() {
+            return Err(InventoryError::PeriodNotEnded(command.period_end));
+        }
+
+        let store_id = StoreId::from_uuid(command.store_id);
+        let overlapping = self
+            .period_repo
+            .find_overlapping(store_id, command.period_start, command.period_end)
+            .await?;
+
+        let existing = match overlapping.as_slice() {
+            [] => None,
+            [period]
+                if period.period_start() == command.period_start
+                    && period.period_end() == command.period_end =>
+            {
+                Some(period.clone())
+            }
+            [period, ..] => {
+                return Err(InventoryError::PeriodOverlap(
+                    period.period_start(),
+                    period.period_end(),
+                ));
+            }
+        };
+
+        let period = match existing {
+            Some(mut period) => {
+                period.close(actor_id)?;
+                self.period_repo.update(&period).await?;
+                period
+            }
+            None => {
+                let mut period =
+                    InventoryPeriod::create(store_id, command.period_start, command.period_end)?;
+                period.close(actor_id)?;
+                self.period_repo.save(&period).await?;
+                period
+            }
+        };
+
+        Ok(InventoryPeriodResponse::from(&period))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use async_trait::async_trait;
+    use chrono::NaiveDate;
+    use std::sync::Mutex;
+
+    use crate::domain::value_objects::InventoryPeriodId;
+
+    struct MockInventoryPeriodRepository {
+        periods: Mutex<Vec<InventoryPeriod>>,
+    }
+
+    impl MockInventoryPeriodRepository {
+        fn new(periods: Vec<InventoryPeriod>) -> Self {
+            Self {
+                periods: Mutex::new(periods),
+            }
+        }
+    }
+
+    #[async_trait]
+    impl InventoryPeriodRepository for MockInventoryPeriodRepository {
+        async fn save(&self, period: &InventoryPeriod) -> Result<(), InventoryError> {
+            self.periods.lock().unwrap().push(period.clone());
+            Ok(())
+        }
+
+        async fn update(&self, period: &InventoryPeriod) -> Result<(), InventoryError> {
+            let mut periods = self.periods.lock().unwrap();
+            if let Some(existing) = periods.iter_mut().find(|p| p.id() == period.id()) {
+                *existing = period.clone();
+            }
+            Ok(())
+        }
+
+        async fn find_by_id(
+            &self,
+            _id: InventoryPeriodId,
+        ) -> Result<Option<InventoryPeriod>, InventoryError> {
+            unimplemented!()
+        }
+
+        async fn find_overlapping(
+            &self,
+            store_id: StoreId,
+            start: NaiveDate,
+            end: NaiveDate,
+        ) -> Result<Vec<InventoryPeriod>, InventoryError> {
+            Ok(self
+                .periods
+                .lock()
+                .unwrap()
+                .iter()
+                .filter(|p| p.store_id() == store_id && p.overlaps(start, end))
+                .cloned()
+                .collect())
+        }
+
+        async fn find_closed_containing(
+            &self,
+            _store_id: StoreId,
+            _date: NaiveDate,
+        ) -> Result<Option<InventoryPeriod>, InventoryError> {
+            unimplemented!()
+        }
+
+        async fn find_by_store(
+            &self,
+            _store_id: StoreId,
+        ) -> Result<Vec<InventoryPeriod>, InventoryError> {
+            unimplemented!()
+        }
+    }
+
+    fn date(month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2026, month, day).unwrap()
+    }
+
+    fn command(store_id: StoreId, start: NaiveDate, end: NaiveDate) -> ClosePeriodCommand {
+        ClosePeriodCommand {
+            store_id: store_id.into_uuid(),
+            period_start: start,
+            period_end: end,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_close_new_period() {
+        let repo = Arc::new(MockInventoryPeriodRepository::new(vec![]));
+        let use_case = ClosePeriodUseCase::new(repo.clone());
+        let store_id = StoreId::new();
+
+        let response = use_case
+            .execute(command(store_id, date(8, 1), date(8, 31)), UserId::new())
+            .await
+            .unwrap();
+
+        assert_eq!(response.status, "closed");
+        assert_eq!(repo.periods.lock().unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_close_reopened_period_again() {
+        let store_id = StoreId::new();
+        let user = UserId::new();
+        let mut period = InventoryPeriod::create(store_id, date(8, 1), date(8, 31)).unwrap();
+        period.close(user).unwrap();
+        period.reopen(user).unwrap();
+        let repo = Arc::new(MockInventoryPeriodRepository::new(vec![period.clone()]));
+        let use_case = ClosePeriodUseCase::new(repo.clone());
+
+        let response = use_case
+            .execute(command(store_id, date(8, 1), date(8, 31)), user)
+            .await
+            .unwrap();
+
+        assert_eq!(response.id, period.id().into_uuid());
+        assert_eq!(response.status, "closed");
+        assert_eq!(repo.periods.lock().unwrap().len(), 1);
+
+        let again = use_case
+            .execute(command(store_id, date(8, 1), date(8, 31)), user)
+            .await;
+        assert!(matches!(again, Err(InventoryError::PeriodAlreadyClosed(_))));
+    }
+
+    #[tokio::test]
+    async fn test_rejects_overlap_and_unended_periods() {
+        let store_id = StoreId::new();
+        let period = InventoryPeriod::create(store_id, date(8, 1), date(8, 31)).unwrap();
+        let repo = Arc::new(MockInventoryPeriodRepository::new(vec![period]));
+        let use_case = ClosePeriodUseCase::new(repo);
+
+        let overlap = use_case
+            .execute(command(store_id, date(8, 15), date(9, 15)), UserId::new())
+            .await;
+        assert!(matches!(overlap, Err(InventoryError::PeriodOverlap(_, _))));
+
+        let today = Utc::now().date_naive();
+        let unended = use_case
+            .execute(command(store_id, today, today), UserId::new())
+            .await;
+        assert!(matches!(unended, Err(InventoryError::PeriodNotEnded(_))));
+
+        let inverted = use_case
+            .execute(command(store_id, date(9, 30), date(9, 1)), UserId::new())
+            .await;
+        assert!(matches!(inverted, Err(InventoryError::InvalidPeriodRange)));
+    }
+}
@@ -0,0 +1,82 @@
+// ConfigureCategoryBarcodePrefixUseCase - reads and sets a category's barcode prefix
+
+use std::sync::Arc;
+
+use crate::InventoryError;
+use crate::application::dtos::commands::ConfigureCategoryBarcodePrefixCommand;
+use crate::application::dtos::responses::CategoryBarcodePrefixResponse;
+use crate::domain::repositories::{BarcodeSequenceRepository, CategoryRepository};
+use crate::domain::value_objects::{BarcodePrefix, CategoryId};
+
+/// Use case for reading and changing the prefix used to generate EAN-13
+/// barcodes for a category's products and variants.
+///
+/// Barcodes already assigned are kept. A prefix that was used before resumes
+/// its sequence where it left off.
+pub struct ConfigureCategoryBarcodePrefixUseCase<C, B>
+where
+    C: CategoryRepository,
+    B: BarcodeSequenceRepository,
+{
+    category_repo: Arc<C>,
+    barcode_repo: Arc<B>,
+}
+
+impl<C, B> ConfigureCategoryBarcodePrefixUseCase<C, B>
+where
+    C: CategoryRepository,
+    B: BarcodeSequenceRepository,
+{
+    pub fn new(category_repo: Arc<C>, barcode_repo: Arc<B>) -> Self {
+        Self {
+            category_repo,
+            barcode_repo,
+        }
+    }
+
+    /// Returns the prefix configured for the category, if any
+    pub async fn get(
+        &self,
+        category_id: uuid::Uuid,
+    ) -> Result<CategoryBarcodePrefixResponse, InventoryError> {
+        let id = self.existing_category(category_id).await?;
+        let prefix = self.barcode_repo.find_prefix(id).await?;
+
+        Ok(CategoryBarcodePrefixResponse {
+            category_id,
+            prefix: prefix.map(|p| p.as_str().to_string()),
+        })
+    }
+
+    /// Sets the category's prefix; a null prefix turns generation off
+    ///
+    /// # Errors
+    /// * `InventoryError::CategoryNotFound` - If the category doesn't exist
+    /// * `InventoryError::InvalidBarcodePrefix` - If the prefix is not 3 to 9 digits
+    pub async fn execute(
+        &self,
+        command: ConfigureCategoryBarcodePrefixCommand,
+    ) -> Result<CategoryBarcodePrefixResponse, InventoryError> {
+        let id = self.existing_category(command.category_id).await?;
+        let prefix = command
+            .prefix
+            .as_deref()
+            .map(BarcodePrefix::new)
+            .transpose()?;
+
+        self.barcode_repo.save_prefix(id, prefix.as_ref()).await?;
+
+        Ok(CategoryBarcodePrefixResponse {
+            category_id: command.category_id,
+            prefix: prefix.map(|p| p.as_str().to_string()),
+        })
+    }
+
+    async fn existing_category(&self, id: uuid::Uuid) -> Result<CategoryId, InventoryError> {
+        let category_id = CategoryId::from_uuid(id);
+        if self.category_repo.find_by_id(category_id).await?.is_none() {
+            return Err(InventoryError::CategoryNotFound(id));
+        }
+        Ok(category_id)
+    }
+}
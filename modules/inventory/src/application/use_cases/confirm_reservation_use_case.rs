@@ -124,7 +124,11 @@ where
             reference_id: reservation.reference_id(),
             quantity: reservation.quantity(),
             status: reservation.status().to_string(),
+            priority: reservation.priority(),
             expires_at: reservation.expires_at(),
+            auto_extend_seconds: reservation.auto_extend_seconds(),
+            bumped_at: reservation.bumped_at(),
+            bump_reason: reservation.bump_reason().map(str::to_string),
             created_at: reservation.created_at(),
             updated_at: reservation.updated_at(),
         })
@@ -135,7 +139,7 @@ where
 mod tests {
     use super::*;
     use async_trait::async_trait;
-    use chrono::{Duration, Utc};
+    use chrono::{DateTime, Duration, Utc};
     use rust_decimal::Decimal;
     use rust_decimal_macros::dec;
     use std::collections::HashMap;
@@ -143,6 +147,7 @@ mod tests {
     use uuid::{NoContext, Timestamp, Uuid};
 
     use crate::domain::entities::{InventoryReservation, InventoryStock};
+    use crate::domain::repositories::ReservationStatusCount;
     use crate::domain::value_objects::{ProductId, StockId};
     use identity::StoreId;
 
@@ -215,14 +220,26 @@ mod tests {
 
         async fn find_paginated(
             &self,
+            _store_ids: Option<&[StoreId]>,
             _stock_id: Option<StockId>,
             _status: Option<&str>,
             _reference_type: Option<&str>,
+            _after_id: Option<Uuid>,
             _page: i64,
             _page_size: i64,
         ) -> Result<(Vec<InventoryReservation>, i64), InventoryError> {
             unimplemented!()
         }
+
+        async fn count_by_status(
+            &self,
+            _store_id: StoreId,
+            _from: DateTime<Utc>,
+            _to: DateTime<Utc>,
+            _by_day: bool,
+        ) -> Result<Vec<ReservationStatusCount>, InventoryError> {
+            unimplemented!()
+        }
     }
 
     struct MockStockRepository {
@@ -304,9 +321,10 @@ mod tests {
 
         async fn find_paginated(
             &self,
-            _store_id: Option<StoreId>,
+            _store_ids: Option<&[StoreId]>,
             _product_id: Option<crate::domain::value_objects::ProductId>,
             _low_stock_only: bool,
+            _after_id: Option<Uuid>,
             _page: i64,
             _page_size: i64,
         ) -> Result<(Vec<InventoryStock>, i64), InventoryError> {
@@ -437,6 +455,37 @@ mod tests {
         async fn save_batch(&self, _movements: &[InventoryMovement]) -> Result<(), InventoryError> {
             unimplemented!()
         }
+
+        async fn find_all_by_stock_id_chronological(
+            &self,
+            _stock_id: StockId,
+        ) -> Result<Vec<InventoryMovement>, InventoryError> {
+            unimplemented!()
+        }
+
+        async fn rewrite_average_costs(
+            &self,
+            _stock_id: StockId,
+            _expected_version: i32,
+            _movement_costs: &[(crate::domain::value_objects::MovementId, Option<Decimal>)],
+            _average_cost: Option<Decimal>,
+        ) -> Result<crate::domain::repositories::AverageCostRewrite, InventoryError> {
+            unimplemented!()
+        }
+
+        async fn find_costing_method(
+            &self,
+            _stock_id: StockId,
+        ) -> Result<crate::domain::value_objects::CostingMethod, InventoryError> {
+            unimplemented!()
+        }
+
+        async fn find_open_cost_layers(
+            &self,
+            _stock_id: StockId,
+        ) -> Result<Vec<crate::domain::repositories::CostLayer>, InventoryError> {
+            unimplemented!()
+        }
     }
 
     fn future_time() -> chrono::DateTime<Utc> {
@@ -465,6 +514,7 @@ mod tests {
             new_uuid(),
             dec!(30),
             future_time(),
+            0,
         )
         .unwrap();
         let reservation_id = reservation.id();
@@ -540,6 +590,7 @@ mod tests {
             new_uuid(),
             dec!(30),
             future_time(),
+            0,
         )
         .unwrap();
         reservation.confirm().unwrap();
@@ -1,4 +1,5 @@
-// CreateAdjustmentUseCase - creates a new stock adjustment in draft status
+// CreateAdjustmentUseCase - creates a new stock adjustment in draft status, or
+// approved when the approval policy lets it skip review
 
 use std::str::FromStr;
 use std::sync::Arc;
@@ -8,17 +9,22 @@ use crate::application::dtos::commands::{AdjustmentItemCommand, CreateAdjustment
 use crate::application::dtos::responses::{AdjustmentDetailResponse, AdjustmentItemResponse};
 use crate::domain::entities::{AdjustmentItem, StockAdjustment};
 use crate::domain::repositories::AdjustmentRepository;
-use crate::domain::value_objects::{AdjustmentReason, AdjustmentType, StockId};
+use crate::domain::value_objects::{
+    AdjustmentApprovalPolicy, AdjustmentReason, AdjustmentType, StockId,
+};
 use identity::{StoreId, UserId};
 
 /// Use case for creating a new stock adjustment.
 ///
 /// Generates adjustment number, sets status to draft, and adds items.
+/// Negative adjustments within the approval policy's limits are approved on
+/// creation and flagged as auto-approved; they can be applied right away.
 pub struct CreateAdjustmentUseCase<A>
 where
     A: AdjustmentRepository,
 {
     adjustment_repo: Arc<A>,
+    approval_policy: AdjustmentApprovalPolicy,
 }
 
 impl<A> CreateAdjustmentUseCase<A>
@@ -26,8 +32,11 @@ where
     A: AdjustmentRepository,
 {
     /// Creates a new instance of CreateAdjustmentUseCase
-    pub fn new(adjustment_repo: Arc<A>) -> Self {
-        Self { adjustment_repo }
+    pub fn new(adjustment_repo: Arc<A>, approval_policy: AdjustmentApprovalPolicy) -> Self {
+        Self {
+            adjustment_repo,
+            approval_policy,
+        }
     }
 
     /// Executes the use case to create a new stock adjustment
@@ -87,10 +96,15 @@ where
             adjustment.add_item(item)?;
         }
 
-        // 7. Save adjustment
+        // 7. Skip review for small negative adjustments
+        if self.approval_policy.can_auto_approve(&adjustment) {
+            adjustment.auto_approve()?;
+        }
+
+        // 8. Save adjustment
         self.adjustment_repo.save(&adjustment).await?;
 
-        // 8. Convert to response
+        // 9. Convert to response
         Ok(self.to_response(&adjustment))
     }
 
@@ -137,6 +151,7 @@ where
             created_by_id: adjustment.created_by_id().into_uuid(),
             approved_by_id: adjustment.approved_by_id().map(|id| id.into_uuid()),
             approved_at: adjustment.approved_at(),
+            auto_approved: adjustment.auto_approved(),
             applied_at: adjustment.applied_at(),
             notes: adjustment.notes().map(|s| s.to_string()),
             attachments: Some(adjustment.attachments().clone()),
@@ -151,12 +166,13 @@ where
 mod tests {
     use super::*;
     use async_trait::async_trait;
+    use rust_decimal::Decimal;
     use rust_decimal_macros::dec;
     use std::collections::HashMap;
     use std::sync::Mutex;
     use uuid::{NoContext, Timestamp, Uuid};
 
-    use crate::domain::value_objects::AdjustmentId;
+    use crate::domain::value_objects::{AdjustmentApprovalLimit, AdjustmentId};
 
     fn new_uuid() -> Uuid {
         Uuid::new_v7(Timestamp::now(NoContext))
@@ -223,8 +239,9 @@ mod tests {
 
         async fn find_paginated(
             &self,
-            _store_id: Option<StoreId>,
+            _store_ids: Option<&[StoreId]>,
             _status: Option<&str>,
+            _after_id: Option<Uuid>,
             _page: i64,
             _page_size: i64,
         ) -> Result<(Vec<StockAdjustment>, i64), InventoryError> {
@@ -235,7 +252,8 @@ mod tests {
     #[tokio::test]
     async fn test_create_adjustment_success() {
         let repo = Arc::new(MockAdjustmentRepository::new());
-        let use_case = CreateAdjustmentUseCase::new(repo.clone());
+        let use_case =
+            CreateAdjustmentUseCase::new(repo.clone(), AdjustmentApprovalPolicy::disabled());
 
         let command = CreateAdjustmentCommand {
             store_id: new_uuid(),
@@ -260,15 +278,82 @@ mod tests {
         assert_eq!(response.adjustment_type, "decrease");
         assert_eq!(response.adjustment_reason, "damage");
         assert_eq!(response.status, "draft");
+        assert!(!response.auto_approved);
         assert_eq!(response.notes, Some("Damaged goods".to_string()));
         assert_eq!(response.items.len(), 1);
         assert_eq!(response.items[0].quantity, dec!(-10));
     }
 
+    fn decrease_command(reason: &str, quantity: Decimal) -> CreateAdjustmentCommand {
+        CreateAdjustmentCommand {
+            store_id: new_uuid(),
+            adjustment_type: "decrease".to_string(),
+            adjustment_reason: reason.to_string(),
+            notes: None,
+            attachments: None,
+            items: vec![AdjustmentItemCommand {
+                stock_id: new_uuid(),
+                quantity,
+                unit_cost: Some(dec!(10.00)),
+                notes: None,
+            }],
+        }
+    }
+
+    #[tokio::test]
+    async fn test_create_adjustment_auto_approves_up_to_threshold() {
+        let repo = Arc::new(MockAdjustmentRepository::new());
+        let policy =
+            AdjustmentApprovalPolicy::disabled().with_default_limit(AdjustmentApprovalLimit {
+                max_value: Some(dec!(100)),
+                max_quantity: None,
+            });
+        let use_case = CreateAdjustmentUseCase::new(repo.clone(), policy);
+
+        let at_threshold = use_case
+            .execute(decrease_command("damage", dec!(-10)), UserId::new())
+            .await
+            .unwrap();
+        assert_eq!(at_threshold.status, "approved");
+        assert!(at_threshold.auto_approved);
+        assert!(at_threshold.approved_by_id.is_none());
+        let saved = repo
+            .find_by_id(AdjustmentId::from_uuid(at_threshold.id))
+            .await
+            .unwrap()
+            .unwrap();
+        assert!(saved.auto_approved());
+
+        let above = use_case
+            .execute(decrease_command("damage", dec!(-10.01)), UserId::new())
+            .await
+            .unwrap();
+        assert_eq!(above.status, "draft");
+        assert!(!above.auto_approved);
+    }
+
+    #[tokio::test]
+    async fn test_create_adjustment_theft_always_needs_approval() {
+        let repo = Arc::new(MockAdjustmentRepository::new());
+        let policy =
+            AdjustmentApprovalPolicy::disabled().with_default_limit(AdjustmentApprovalLimit {
+                max_value: Some(dec!(1000)),
+                max_quantity: Some(dec!(100)),
+            });
+        let use_case = CreateAdjustmentUseCase::new(repo, policy);
+
+        let response = use_case
+            .execute(decrease_command("theft", dec!(-1)), UserId::new())
+            .await
+            .unwrap();
+        assert_eq!(response.status, "draft");
+        assert!(!response.auto_approved);
+    }
+
     #[tokio::test]
     async fn test_create_adjustment_multiple_items() {
         let repo = Arc::new(MockAdjustmentRepository::new());
-        let use_case = CreateAdjustmentUseCase::new(repo);
+        let use_case = CreateAdjustmentUseCase::new(repo, AdjustmentApprovalPolicy::disabled());
 
         let command = CreateAdjustmentCommand {
             store_id: new_uuid(),
@@ -303,7 +388,7 @@ mod tests {
     #[tokio::test]
     async fn test_create_adjustment_empty_items() {
         let repo = Arc::new(MockAdjustmentRepository::new());
-        let use_case = CreateAdjustmentUseCase::new(repo);
+        let use_case = CreateAdjustmentUseCase::new(repo, AdjustmentApprovalPolicy::disabled());
 
         let command = CreateAdjustmentCommand {
             store_id: new_uuid(),
@@ -322,7 +407,7 @@ mod tests {
     #[tokio::test]
     async fn test_create_adjustment_invalid_type() {
         let repo = Arc::new(MockAdjustmentRepository::new());
-        let use_case = CreateAdjustmentUseCase::new(repo);
+        let use_case = CreateAdjustmentUseCase::new(repo, AdjustmentApprovalPolicy::disabled());
 
         let command = CreateAdjustmentCommand {
             store_id: new_uuid(),
@@ -346,7 +431,7 @@ mod tests {
     #[tokio::test]
     async fn test_create_adjustment_invalid_reason() {
         let repo = Arc::new(MockAdjustmentRepository::new());
-        let use_case = CreateAdjustmentUseCase::new(repo);
+        let use_case = CreateAdjustmentUseCase::new(repo, AdjustmentApprovalPolicy::disabled());
 
         let command = CreateAdjustmentCommand {
             store_id: new_uuid(),
@@ -1,13 +1,15 @@
 // CreateCategoryUseCase - creates a new product category
 
+use std::str::FromStr;
 use std::sync::Arc;
 
 use crate::InventoryError;
 use crate::application::dtos::commands::CreateCategoryCommand;
 use crate::application::dtos::responses::CategoryResponse;
+use crate::application::helpers::{DEFAULT_MAX_CATEGORY_DEPTH, validate_category_placement};
 use crate::domain::entities::ProductCategory;
 use crate::domain::repositories::CategoryRepository;
-use crate::domain::value_objects::CategoryId;
+use crate::domain::value_objects::{CategoryId, CostingMethod, TaxCategory};
 
 /// Use case for creating a new product category
 ///
@@ -17,6 +19,7 @@ where
     C: CategoryRepository,
 {
     category_repo: Arc<C>,
+    max_depth: usize,
 }
 
 impl<C> CreateCategoryUseCase<C>
@@ -25,7 +28,16 @@ where
 {
     /// Creates a new instance of CreateCategoryUseCase
     pub fn new(category_repo: Arc<C>) -> Self {
-        Self { category_repo }
+        Self {
+            category_repo,
+            max_depth: DEFAULT_MAX_CATEGORY_DEPTH,
+        }
+    }
+
+    /// Sets the maximum number of levels in the category tree
+    pub fn with_max_depth(mut self, max_depth: usize) -> Self {
+        self.max_depth = max_depth;
+        self
     }
 
     /// Executes the use case to create a new category
@@ -39,10 +51,23 @@ where
     /// # Errors
     /// * `InventoryError::DuplicateCategorySlug` - If slug already exists
     /// * `InventoryError::ParentCategoryNotFound` - If parent_id is provided but doesn't exist
+    /// * `InventoryError::CategoryDepthExceeded` - If the parent is already at the maximum depth
     pub async fn execute(
         &self,
         command: CreateCategoryCommand,
     ) -> Result<CategoryResponse, InventoryError> {
+        let tax_category = command
+            .tax_category
+            .as_deref()
+            .map(TaxCategory::from_str)
+            .transpose()?;
+        let costing_method = command
+            .costing_method
+            .as_deref()
+            .map(CostingMethod::from_str)
+            .transpose()?
+            .unwrap_or_default();
+
         // Validate slug uniqueness (Requirement 1A.2)
         if self
             .category_repo
@@ -59,6 +84,13 @@ where
             if self.category_repo.find_by_id(parent_id).await?.is_none() {
                 return Err(InventoryError::ParentCategoryNotFound(parent_uuid));
             }
+            validate_category_placement(
+                self.category_repo.as_ref(),
+                None,
+                parent_id,
+                self.max_depth,
+            )
+            .await?;
         }
 
         // Create category entity
@@ -77,6 +109,8 @@ where
             category.set_icon(Some(icon));
         }
         category.set_sort_order(command.sort_order);
+        category.set_tax_category(tax_category);
+        category.set_costing_method(costing_method);
 
         // Save to repository
         self.category_repo.save(&category).await?;
@@ -91,6 +125,8 @@ where
             icon: category.icon().map(|s| s.to_string()),
             sort_order: category.sort_order(),
             is_active: category.is_active(),
+            tax_category: category.tax_category().map(|t| t.to_string()),
+            costing_method: category.costing_method().to_string(),
             created_at: category.created_at(),
             updated_at: category.updated_at(),
         })
@@ -100,7 +136,9 @@ where
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::domain::repositories::CategoryStockValuation;
     use async_trait::async_trait;
+    use identity::StoreId;
     use std::collections::HashMap;
     use std::sync::Mutex;
 
@@ -165,6 +203,12 @@ mod tests {
             unimplemented!()
         }
 
+        async fn stock_valuation_by_category(
+            &self,
+            _store_id: Option<StoreId>,
+        ) -> Result<Vec<CategoryStockValuation>, InventoryError> {
+            Ok(vec![])
+        }
         async fn delete(&self, _id: CategoryId) -> Result<(), InventoryError> {
             unimplemented!()
         }
@@ -182,6 +226,8 @@ mod tests {
             description: Some("Electronic devices".to_string()),
             icon: Some("icon-electronics".to_string()),
             sort_order: 1,
+            tax_category: None,
+            costing_method: None,
         };
 
         let result = use_case.execute(command).await;
@@ -214,6 +260,8 @@ mod tests {
             description: None,
             icon: None,
             sort_order: 0,
+            tax_category: None,
+            costing_method: None,
         };
 
         let result = use_case.execute(command).await;
@@ -224,6 +272,32 @@ mod tests {
         assert_eq!(response.parent_id, Some(parent.id().into_uuid()));
     }
 
+    #[tokio::test]
+    async fn test_create_subcategory_beyond_max_depth() {
+        let repo = Arc::new(MockCategoryRepository::new());
+        let use_case = CreateCategoryUseCase::new(repo.clone()).with_max_depth(1);
+
+        let parent = ProductCategory::create("Electronics".to_string(), "electronics".to_string());
+        repo.save(&parent).await.unwrap();
+
+        let command = CreateCategoryCommand {
+            name: "Smartphones".to_string(),
+            slug: "smartphones".to_string(),
+            parent_id: Some(parent.id().into_uuid()),
+            description: None,
+            icon: None,
+            sort_order: 0,
+            tax_category: None,
+            costing_method: None,
+        };
+
+        let result = use_case.execute(command).await;
+        assert!(matches!(
+            result,
+            Err(InventoryError::CategoryDepthExceeded(1))
+        ));
+    }
+
     #[tokio::test]
     async fn test_duplicate_slug_error() {
         let repo = Arc::new(MockCategoryRepository::new());
@@ -242,6 +316,8 @@ mod tests {
             description: None,
             icon: None,
             sort_order: 0,
+            tax_category: None,
+            costing_method: None,
         };
 
         let result = use_case.execute(command).await;
@@ -264,6 +340,8 @@ mod tests {
             description: None,
             icon: None,
             sort_order: 0,
+            tax_category: None,
+            costing_method: None,
         };
 
         let result = use_case.execute(command).await;
@@ -6,9 +6,14 @@ use std::sync::Arc;
 use crate::InventoryError;
 use crate::application::dtos::commands::CreateProductCommand;
 use crate::application::dtos::responses::ProductResponse;
+use crate::application::helpers::generate_category_barcode;
 use crate::domain::entities::Product;
-use crate::domain::repositories::{CategoryRepository, ProductRepository};
-use crate::domain::value_objects::{Barcode, CategoryId, Currency, UnitOfMeasure};
+use crate::domain::repositories::{
+    BarcodeSequenceRepository, CategoryRepository, ProductRepository,
+};
+use crate::domain::value_objects::{
+    Barcode, CategoryId, Currency, MarkupRule, TaxCategory, UnitOfMeasure,
+};
 use identity::domain::entities::AuditEntry;
 use identity::domain::repositories::AuditRepository;
 use identity::domain::value_objects::UserId;
@@ -16,29 +21,39 @@ use identity::domain::value_objects::UserId;
 /// Use case for creating a new product
 ///
 /// Auto-generates SKU, validates barcode uniqueness, validates category existence,
-/// and creates an audit entry.
-pub struct CreateProductUseCase<P, C, A>
+/// and creates an audit entry. Products created without a barcode get an EAN-13
+/// generated from their category's prefix, if one is configured.
+pub struct CreateProductUseCase<P, C, B, A>
 where
     P: ProductRepository,
     C: CategoryRepository,
+    B: BarcodeSequenceRepository,
     A: AuditRepository,
 {
     product_repo: Arc<P>,
     category_repo: Arc<C>,
+    barcode_repo: Arc<B>,
     audit_repo: Arc<A>,
 }
 
-impl<P, C, A> CreateProductUseCase<P, C, A>
+impl<P, C, B, A> CreateProductUseCase<P, C, B, A>
 where
     P: ProductRepository,
     C: CategoryRepository,
+    B: BarcodeSequenceRepository,
     A: AuditRepository,
 {
     /// Creates a new instance of CreateProductUseCase
-    pub fn new(product_repo: Arc<P>, category_repo: Arc<C>, audit_repo: Arc<A>) -> Self {
+    pub fn new(
+        product_repo: Arc<P>,
+        category_repo: Arc<C>,
+        barcode_repo: Arc<B>,
+        audit_repo: Arc<A>,
+    ) -> Self {
         Self {
             product_repo,
             category_repo,
+            barcode_repo,
             audit_repo,
         }
     }
@@ -58,6 +73,8 @@ where
     /// * `InventoryError::InvalidCurrency` - If currency code is invalid
     /// * `InventoryError::InvalidUnitOfMeasure` - If unit of measure is invalid
     /// * `InventoryError::InvalidBarcode` - If barcode format is invalid
+    /// * `InventoryError::InvalidEan13CheckDigit` - If a 13-digit barcode has a wrong check digit
+    /// * `InventoryError::BarcodeSequenceExhausted` - If the category's prefix has no values left
     pub async fn execute(
         &self,
         command: CreateProductCommand,
@@ -65,6 +82,16 @@ where
     ) -> Result<ProductResponse, InventoryError> {
         // Validate unit of measure (Requirement 1.2)
         let unit_of_measure = UnitOfMeasure::from_str(&command.unit_of_measure)?;
+        let tax_category = command
+            .tax_category
+            .as_deref()
+            .map(TaxCategory::from_str)
+            .transpose()?;
+        let markup_rule = command
+            .markup_type
+            .as_deref()
+            .map(|t| MarkupRule::parse(t, command.markup_percent))
+            .transpose()?;
 
         // Validate barcode uniqueness if provided (Requirement 1.2)
         let barcode = if let Some(barcode_str) = &command.barcode {
@@ -100,8 +127,15 @@ where
         let mut product = Product::create(command.name, unit_of_measure, category_code);
 
         // Apply optional and additional fields
-        if let Some(barcode) = barcode {
-            product.set_barcode(Some(barcode));
+        match barcode {
+            Some(barcode) => product.set_barcode(Some(barcode)),
+            None => {
+                if let Some(generated) =
+                    generate_category_barcode(self.barcode_repo.as_ref(), category_id).await?
+                {
+                    product.set_generated_barcode(generated);
+                }
+            }
         }
         if let Some(description) = command.description {
             product.set_description(Some(description));
@@ -126,6 +160,9 @@ where
         product.set_has_variants(command.has_variants);
         product.set_tax_rate(command.tax_rate);
         product.set_tax_included(command.tax_included);
+        product.set_tax_category(tax_category);
+        product.set_serialized(command.is_serialized);
+        product.set_markup_rule(markup_rule);
 
         if let Some(attributes) = command.attributes {
             product.set_attributes(attributes);
@@ -147,6 +184,7 @@ where
             id: product.id().into_uuid(),
             sku: product.sku().as_str().to_string(),
             barcode: product.barcode().map(|b| b.as_str().to_string()),
+            barcode_generated: product.barcode_generated(),
             name: product.name().to_string(),
             description: product.description().map(|s| s.to_string()),
             category_id: product.category_id().map(|id| id.into_uuid()),
@@ -160,6 +198,10 @@ where
             has_variants: product.has_variants(),
             tax_rate: product.tax_rate(),
             tax_included: product.tax_included(),
+            tax_category: product.tax_category().map(|t| t.to_string()),
+            is_serialized: product.is_serialized(),
+            markup_type: product.markup_rule().map(|r| r.markup_type().to_string()),
+            markup_percent: product.markup_rule().and_then(|r| r.percent()),
             is_active: product.is_active(),
             created_at: product.created_at(),
             updated_at: product.updated_at(),
@@ -170,15 +212,18 @@ where
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::domain::repositories::CategoryStockValuation;
     use async_trait::async_trait;
     use chrono::Utc;
+    use identity::StoreId;
     use identity::domain::entities::AuditEntry;
     use rust_decimal_macros::dec;
     use std::collections::HashMap;
     use std::sync::Mutex;
+    use uuid::Uuid;
 
-    use crate::domain::entities::ProductCategory;
-    use crate::domain::value_objects::{ProductId, Sku};
+    use crate::domain::entities::{ProductCategory, ProductVendor};
+    use crate::domain::value_objects::{BarcodePrefix, ProductId, Sku, UnitConversion};
 
     // Mock repositories for testing
     struct MockProductRepository {
@@ -299,11 +344,33 @@ mod tests {
             unimplemented!()
         }
 
+        async fn save_product_vendors(
+            &self,
+            _vendors: &[ProductVendor],
+        ) -> Result<(), InventoryError> {
+            unimplemented!()
+        }
+
+        async fn find_product_vendors(
+            &self,
+            _product_id: ProductId,
+        ) -> Result<Vec<ProductVendor>, InventoryError> {
+            unimplemented!()
+        }
+
+        async fn find_preferred_vendor(
+            &self,
+            _product_id: ProductId,
+        ) -> Result<Option<ProductVendor>, InventoryError> {
+            unimplemented!()
+        }
+
         async fn find_paginated(
             &self,
             _category_id: Option<CategoryId>,
             _is_active: Option<bool>,
             _search: Option<&str>,
+            _after_id: Option<Uuid>,
             _page: i64,
             _page_size: i64,
         ) -> Result<(Vec<Product>, i64), InventoryError> {
@@ -318,6 +385,32 @@ mod tests {
         ) -> Result<i64, InventoryError> {
             unimplemented!()
         }
+
+        async fn search(
+            &self,
+            _term: &str,
+            _category_id: Option<CategoryId>,
+            _active_only: bool,
+            _page: i64,
+            _page_size: i64,
+        ) -> Result<(Vec<(Product, f64)>, i64), InventoryError> {
+            unimplemented!()
+        }
+
+        async fn replace_unit_conversions(
+            &self,
+            _product_id: ProductId,
+            _conversions: &[UnitConversion],
+        ) -> Result<(), InventoryError> {
+            unimplemented!()
+        }
+
+        async fn find_unit_conversions(
+            &self,
+            _product_id: ProductId,
+        ) -> Result<Vec<UnitConversion>, InventoryError> {
+            unimplemented!()
+        }
     }
 
     struct MockCategoryRepository {
@@ -374,6 +467,12 @@ mod tests {
             unimplemented!()
         }
 
+        async fn stock_valuation_by_category(
+            &self,
+            _store_id: Option<StoreId>,
+        ) -> Result<Vec<CategoryStockValuation>, InventoryError> {
+            Ok(vec![])
+        }
         async fn delete(&self, _id: CategoryId) -> Result<(), InventoryError> {
             unimplemented!()
         }
@@ -414,6 +513,62 @@ mod tests {
         ) -> Result<Vec<AuditEntry>, identity::IdentityError> {
             unimplemented!()
         }
+
+        async fn find_paginated(
+            &self,
+            _filter: &identity::AuditEntryFilter,
+            _page: i64,
+            _page_size: i64,
+        ) -> Result<(Vec<AuditEntry>, i64), identity::IdentityError> {
+            unimplemented!()
+        }
+    }
+
+    struct MockBarcodeSequenceRepository {
+        prefixes: Mutex<HashMap<CategoryId, BarcodePrefix>>,
+        last_value: Mutex<u64>,
+    }
+
+    impl MockBarcodeSequenceRepository {
+        fn new() -> Self {
+            Self {
+                prefixes: Mutex::new(HashMap::new()),
+                last_value: Mutex::new(0),
+            }
+        }
+    }
+
+    #[async_trait]
+    impl BarcodeSequenceRepository for MockBarcodeSequenceRepository {
+        async fn find_prefix(
+            &self,
+            category_id: CategoryId,
+        ) -> Result<Option<BarcodePrefix>, InventoryError> {
+            Ok(self.prefixes.lock().unwrap().get(&category_id).cloned())
+        }
+
+        async fn save_prefix(
+            &self,
+            category_id: CategoryId,
+            prefix: Option<&BarcodePrefix>,
+        ) -> Result<(), InventoryError> {
+            let mut prefixes = self.prefixes.lock().unwrap();
+            match prefix {
+                Some(prefix) => prefixes.insert(category_id, prefix.clone()),
+                None => prefixes.remove(&category_id),
+            };
+            Ok(())
+        }
+
+        async fn next_value(&self, _prefix: &BarcodePrefix) -> Result<u64, InventoryError> {
+            let mut last_value = self.last_value.lock().unwrap();
+            *last_value += 1;
+            Ok(*last_value)
+        }
+
+        async fn barcode_exists(&self, _barcode: &Barcode) -> Result<bool, InventoryError> {
+            Ok(false)
+        }
     }
 
     #[tokio::test]
@@ -421,7 +576,12 @@ mod tests {
         let product_repo = Arc::new(MockProductRepository::new());
         let category_repo = Arc::new(MockCategoryRepository::new());
         let audit_repo = Arc::new(MockAuditRepository::new());
-        let use_case = CreateProductUseCase::new(product_repo, category_repo, audit_repo);
+        let use_case = CreateProductUseCase::new(
+            product_repo,
+            category_repo,
+            Arc::new(MockBarcodeSequenceRepository::new()),
+            audit_repo,
+        );
 
         let command = CreateProductCommand {
             name: "Test Product".to_string(),
@@ -438,6 +598,10 @@ mod tests {
             has_variants: false,
             tax_rate: dec!(0.15),
             tax_included: false,
+            tax_category: None,
+            is_serialized: false,
+            markup_type: None,
+            markup_percent: None,
             attributes: None,
         };
 
@@ -465,7 +629,12 @@ mod tests {
             ProductCategory::create("Electronics".to_string(), "electronics".to_string());
         category_repo.save(&category).await.unwrap();
 
-        let use_case = CreateProductUseCase::new(product_repo, category_repo, audit_repo);
+        let use_case = CreateProductUseCase::new(
+            product_repo,
+            category_repo,
+            Arc::new(MockBarcodeSequenceRepository::new()),
+            audit_repo,
+        );
 
         let command = CreateProductCommand {
             name: "Smartphone".to_string(),
@@ -482,6 +651,10 @@ mod tests {
             has_variants: false,
             tax_rate: dec!(0.0),
             tax_included: false,
+            tax_category: None,
+            is_serialized: false,
+            markup_type: None,
+            markup_percent: None,
             attributes: None,
         };
 
@@ -501,17 +674,22 @@ mod tests {
 
         // Create first product with barcode
         let mut product = Product::create("Product 1".to_string(), UnitOfMeasure::Unit, None);
-        let barcode = Barcode::new("1234567890123").unwrap();
+        let barcode = Barcode::new("1234567890128").unwrap();
         product.set_barcode(Some(barcode));
         product_repo.save(&product).await.unwrap();
 
-        let use_case = CreateProductUseCase::new(product_repo, category_repo, audit_repo);
+        let use_case = CreateProductUseCase::new(
+            product_repo,
+            category_repo,
+            Arc::new(MockBarcodeSequenceRepository::new()),
+            audit_repo,
+        );
 
         // Try to create another product with same barcode
         let command = CreateProductCommand {
             name: "Product 2".to_string(),
             unit_of_measure: "unit".to_string(),
-            barcode: Some("1234567890123".to_string()),
+            barcode: Some("1234567890128".to_string()),
             description: None,
             category_id: None,
             brand: None,
@@ -523,6 +701,10 @@ mod tests {
             has_variants: false,
             tax_rate: dec!(0.0),
             tax_included: false,
+            tax_category: None,
+            is_serialized: false,
+            markup_type: None,
+            markup_percent: None,
             attributes: None,
         };
 
@@ -536,7 +718,12 @@ mod tests {
         let product_repo = Arc::new(MockProductRepository::new());
         let category_repo = Arc::new(MockCategoryRepository::new());
         let audit_repo = Arc::new(MockAuditRepository::new());
-        let use_case = CreateProductUseCase::new(product_repo, category_repo, audit_repo);
+        let use_case = CreateProductUseCase::new(
+            product_repo,
+            category_repo,
+            Arc::new(MockBarcodeSequenceRepository::new()),
+            audit_repo,
+        );
 
         let non_existent_category = CategoryId::new().into_uuid();
         let command = CreateProductCommand {
@@ -554,6 +741,10 @@ mod tests {
             has_variants: false,
             tax_rate: dec!(0.0),
             tax_included: false,
+            tax_category: None,
+            is_serialized: false,
+            markup_type: None,
+            markup_percent: None,
             attributes: None,
         };
 
@@ -561,4 +752,60 @@ mod tests {
         let result = use_case.execute(command, actor_id).await;
         assert!(matches!(result, Err(InventoryError::CategoryNotFound(_))));
     }
+
+    #[tokio::test]
+    async fn test_barcode_generated_from_category_prefix() {
+        let product_repo = Arc::new(MockProductRepository::new());
+        let category_repo = Arc::new(MockCategoryRepository::new());
+        let barcode_repo = Arc::new(MockBarcodeSequenceRepository::new());
+        let audit_repo = Arc::new(MockAuditRepository::new());
+
+        let category = ProductCategory::create("Snacks".to_string(), "snacks".to_string());
+        category_repo.save(&category).await.unwrap();
+        let prefix = BarcodePrefix::new("7401234").unwrap();
+        barcode_repo
+            .save_prefix(category.id(), Some(&prefix))
+            .await
+            .unwrap();
+
+        let use_case =
+            CreateProductUseCase::new(product_repo, category_repo, barcode_repo, audit_repo);
+
+        let command = |barcode: Option<&str>| CreateProductCommand {
+            name: "Chips".to_string(),
+            unit_of_measure: "unit".to_string(),
+            barcode: barcode.map(|b| b.to_string()),
+            description: None,
+            category_id: Some(category.id().into_uuid()),
+            brand: None,
+            base_price: dec!(1.0),
+            cost_price: dec!(0.5),
+            currency: None,
+            is_perishable: false,
+            is_trackable: true,
+            has_variants: false,
+            tax_rate: dec!(0.0),
+            tax_included: false,
+            tax_category: None,
+            is_serialized: false,
+            markup_type: None,
+            markup_percent: None,
+            attributes: None,
+        };
+
+        let generated = use_case
+            .execute(command(None), UserId::new())
+            .await
+            .unwrap();
+        let expected = Barcode::ean13(&prefix, 1).unwrap();
+        assert_eq!(generated.barcode.as_deref(), Some(expected.as_str()));
+        assert!(generated.barcode_generated);
+
+        let manual = use_case
+            .execute(command(Some("4006381333931")), UserId::new())
+            .await
+            .unwrap();
+        assert_eq!(manual.barcode.as_deref(), Some("4006381333931"));
+        assert!(!manual.barcode_generated);
+    }
 }
@@ -96,7 +96,7 @@ where
         }
 
         // Create recipe entity (Requirement 6.1)
-        let recipe = if let Some(pid) = product_id {
+        let mut recipe = if let Some(pid) = product_id {
             Recipe::create_for_product(pid, command.name.clone(), command.yield_quantity)?
         } else {
             Recipe::create_for_variant(
@@ -105,6 +105,9 @@ where
                 command.yield_quantity,
             )?
         };
+        if let Some(yield_unit) = &command.yield_unit {
+            recipe.set_yield_unit(yield_unit.parse()?);
+        }
 
         // Save recipe first
         self.recipe_repo.save(&recipe).await?;
@@ -130,6 +133,7 @@ where
             description: recipe.description().map(|s| s.to_string()),
             version: recipe.version(),
             yield_quantity: recipe.yield_quantity(),
+            yield_unit: recipe.yield_unit().to_string(),
             is_active: recipe.is_active(),
             preparation_time_minutes: recipe.preparation_time_minutes(),
             calculate_cost_from_ingredients: recipe.calculate_cost_from_ingredients(),
@@ -3,18 +3,22 @@
 use std::sync::Arc;
 
 use chrono::Utc;
+use rust_decimal::Decimal;
 
 use crate::InventoryError;
 use crate::application::dtos::commands::CreateReservationCommand;
 use crate::application::dtos::responses::ReservationResponse;
 use crate::domain::entities::InventoryReservation;
 use crate::domain::repositories::{InventoryStockRepository, ReservationRepository};
-use crate::domain::value_objects::StockId;
+use crate::domain::value_objects::{ReservationPriorityPolicy, StockId};
 
 /// Use case for creating an inventory reservation.
 ///
 /// Validates stock has sufficient available quantity, validates expires_at is in future,
-/// and increases reserved_quantity on stock.
+/// and increases reserved_quantity on stock. When stock is short, pending
+/// reservations the priority policy lets the new one bump are expired to free
+/// their quantity, lowest priority and soonest to expire first; nothing is
+/// bumped unless that frees enough.
 pub struct CreateReservationUseCase<S, R>
 where
     S: InventoryStockRepository,
@@ -22,6 +26,7 @@ where
 {
     stock_repo: Arc<S>,
     reservation_repo: Arc<R>,
+    priority_policy: ReservationPriorityPolicy,
 }
 
 impl<S, R> CreateReservationUseCase<S, R>
@@ -30,10 +35,15 @@ where
     R: ReservationRepository,
 {
     /// Creates a new instance of CreateReservationUseCase
-    pub fn new(stock_repo: Arc<S>, reservation_repo: Arc<R>) -> Self {
+    pub fn new(
+        stock_repo: Arc<S>,
+        reservation_repo: Arc<R>,
+        priority_policy: ReservationPriorityPolicy,
+    ) -> Self {
         Self {
             stock_repo,
             reservation_repo,
+            priority_policy,
         }
     }
 
@@ -49,6 +59,7 @@ where
     /// * `InventoryError::StockNotFound` - If stock record doesn't exist
     /// * `InventoryError::InsufficientStock` - If not enough available quantity
     /// * `InventoryError::ReservationExpired` - If expires_at is not in the future
+    /// * `InventoryError::InvalidAutoExtend` - If auto_extend_seconds is out of range
     /// * `InventoryError::OptimisticLockError` - If concurrent modification detected
     pub async fn execute(
         &self,
@@ -67,22 +78,38 @@ where
             .await?
             .ok_or(InventoryError::StockNotFound(command.stock_id))?;
 
-        // 3. Validate sufficient available quantity (Requirement 4.1)
-        if command.quantity > stock.available_quantity() {
-            return Err(InventoryError::InsufficientStock);
-        }
+        // 3. Validate sufficient available quantity (Requirement 4.1),
+        // bumping lower-priority holds to cover a shortfall
+        let priority = self.priority_policy.priority_for(&command.reference_type);
+        let shortfall = command.quantity - stock.available_quantity();
+        let mut bumped = if shortfall > Decimal::ZERO {
+            self.find_bumpable(stock_id, priority, shortfall).await?
+        } else {
+            Vec::new()
+        };
 
         // 4. Create reservation entity
-        let reservation = InventoryReservation::create(
+        let mut reservation = InventoryReservation::create(
             stock_id,
             command.reference_type,
             command.reference_id,
             command.quantity,
             command.expires_at,
+            priority,
         )?;
+        reservation.set_auto_extend(command.auto_extend_seconds)?;
 
-        // 5. Increase reserved_quantity on stock (Requirement 4.1)
+        // 5. Release bumped holds and increase reserved_quantity on stock
+        // (Requirement 4.1)
         let expected_version = stock.version();
+        for holder in &mut bumped {
+            holder.bump(format!(
+                "Stock taken by higher-priority {} reservation {}",
+                reservation.reference_type(),
+                reservation.id().into_uuid()
+            ))?;
+            stock.release(holder.quantity())?;
+        }
         stock.reserve(command.quantity)?;
         stock.increment_version();
 
@@ -91,7 +118,10 @@ where
             .update_with_version(&stock, expected_version)
             .await?;
 
-        // 7. Save reservation
+        // 7. Save bumped holds and the reservation
+        for holder in &bumped {
+            self.reservation_repo.update(holder).await?;
+        }
         self.reservation_repo.save(&reservation).await?;
 
         // 8. Convert to response
@@ -102,25 +132,62 @@ where
             reference_id: reservation.reference_id(),
             quantity: reservation.quantity(),
             status: reservation.status().to_string(),
+            priority: reservation.priority(),
             expires_at: reservation.expires_at(),
+            auto_extend_seconds: reservation.auto_extend_seconds(),
+            bumped_at: reservation.bumped_at(),
+            bump_reason: reservation.bump_reason().map(str::to_string),
             created_at: reservation.created_at(),
             updated_at: reservation.updated_at(),
         })
     }
+
+    /// Pending holds on the stock that a reservation with `priority` may
+    /// bump, enough to cover `shortfall`
+    async fn find_bumpable(
+        &self,
+        stock_id: StockId,
+        priority: i32,
+        shortfall: Decimal,
+    ) -> Result<Vec<InventoryReservation>, InventoryError> {
+        let mut candidates: Vec<_> = self
+            .reservation_repo
+            .find_by_stock_id(stock_id)
+            .await?
+            .into_iter()
+            .filter(|r| self.priority_policy.can_bump(r, priority))
+            .collect();
+        candidates.sort_by_key(|r| (r.priority(), r.expires_at()));
+
+        let mut freed = Decimal::ZERO;
+        let mut bumped = Vec::new();
+        for candidate in candidates {
+            if freed >= shortfall {
+                break;
+            }
+            freed += candidate.quantity();
+            bumped.push(candidate);
+        }
+        if freed < shortfall {
+            return Err(InventoryError::InsufficientStock);
+        }
+        Ok(bumped)
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
     use async_trait::async_trait;
-    use chrono::Duration;
+    use chrono::{DateTime, Duration, Utc};
     use rust_decimal_macros::dec;
     use std::collections::HashMap;
     use std::sync::Mutex;
     use uuid::{NoContext, Timestamp, Uuid};
 
     use crate::domain::entities::InventoryStock;
-    use crate::domain::value_objects::{ProductId, ReservationId};
+    use crate::domain::repositories::ReservationStatusCount;
+    use crate::domain::value_objects::{ProductId, ReservationId, ReservationStatus};
     use identity::StoreId;
 
     fn new_uuid() -> Uuid {
@@ -207,9 +274,10 @@ mod tests {
 
         async fn find_paginated(
             &self,
-            _store_id: Option<StoreId>,
+            _store_ids: Option<&[StoreId]>,
             _product_id: Option<crate::domain::value_objects::ProductId>,
             _low_stock_only: bool,
+            _after_id: Option<Uuid>,
             _page: i64,
             _page_size: i64,
         ) -> Result<(Vec<InventoryStock>, i64), InventoryError> {
@@ -285,9 +353,14 @@ mod tests {
 
         async fn find_by_stock_id(
             &self,
-            _stock_id: StockId,
+            stock_id: StockId,
         ) -> Result<Vec<InventoryReservation>, InventoryError> {
-            unimplemented!()
+            let reservations = self.reservations.lock().unwrap();
+            Ok(reservations
+                .values()
+                .filter(|r| r.stock_id() == stock_id)
+                .cloned()
+                .collect())
         }
 
         async fn find_by_reference(
@@ -314,14 +387,26 @@ mod tests {
 
         async fn find_paginated(
             &self,
+            _store_ids: Option<&[StoreId]>,
             _stock_id: Option<StockId>,
             _status: Option<&str>,
             _reference_type: Option<&str>,
+            _after_id: Option<Uuid>,
             _page: i64,
             _page_size: i64,
         ) -> Result<(Vec<InventoryReservation>, i64), InventoryError> {
             unimplemented!()
         }
+
+        async fn count_by_status(
+            &self,
+            _store_id: StoreId,
+            _from: DateTime<Utc>,
+            _to: DateTime<Utc>,
+            _by_day: bool,
+        ) -> Result<Vec<ReservationStatusCount>, InventoryError> {
+            unimplemented!()
+        }
     }
 
     fn future_time() -> chrono::DateTime<Utc> {
@@ -341,7 +426,11 @@ mod tests {
         let stock_id = stock.id();
         stock_repo.add_stock(stock);
 
-        let use_case = CreateReservationUseCase::new(stock_repo.clone(), reservation_repo.clone());
+        let use_case = CreateReservationUseCase::new(
+            stock_repo.clone(),
+            reservation_repo.clone(),
+            ReservationPriorityPolicy::default(),
+        );
 
         let command = CreateReservationCommand {
             stock_id: stock_id.into_uuid(),
@@ -349,6 +438,7 @@ mod tests {
             reference_id: new_uuid(),
             quantity: dec!(30),
             expires_at: future_time(),
+            auto_extend_seconds: None,
         };
 
         let result = use_case.execute(command).await;
@@ -378,7 +468,11 @@ mod tests {
         let stock_id = stock.id();
         stock_repo.add_stock(stock);
 
-        let use_case = CreateReservationUseCase::new(stock_repo, reservation_repo);
+        let use_case = CreateReservationUseCase::new(
+            stock_repo,
+            reservation_repo,
+            ReservationPriorityPolicy::default(),
+        );
 
         // Try to reserve more than available
         let command = CreateReservationCommand {
@@ -387,6 +481,7 @@ mod tests {
             reference_id: new_uuid(),
             quantity: dec!(100),
             expires_at: future_time(),
+            auto_extend_seconds: None,
         };
 
         let result = use_case.execute(command).await;
@@ -406,7 +501,11 @@ mod tests {
         let stock_id = stock.id();
         stock_repo.add_stock(stock);
 
-        let use_case = CreateReservationUseCase::new(stock_repo, reservation_repo);
+        let use_case = CreateReservationUseCase::new(
+            stock_repo,
+            reservation_repo,
+            ReservationPriorityPolicy::default(),
+        );
 
         // Use past expiration time
         let command = CreateReservationCommand {
@@ -415,6 +514,7 @@ mod tests {
             reference_id: new_uuid(),
             quantity: dec!(30),
             expires_at: Utc::now() - Duration::hours(1),
+            auto_extend_seconds: None,
         };
 
         let result = use_case.execute(command).await;
@@ -426,7 +526,11 @@ mod tests {
         let stock_repo = Arc::new(MockStockRepository::new());
         let reservation_repo = Arc::new(MockReservationRepository::new());
 
-        let use_case = CreateReservationUseCase::new(stock_repo, reservation_repo);
+        let use_case = CreateReservationUseCase::new(
+            stock_repo,
+            reservation_repo,
+            ReservationPriorityPolicy::default(),
+        );
 
         let command = CreateReservationCommand {
             stock_id: new_uuid(),
@@ -434,9 +538,130 @@ mod tests {
             reference_id: new_uuid(),
             quantity: dec!(30),
             expires_at: future_time(),
+            auto_extend_seconds: None,
         };
 
         let result = use_case.execute(command).await;
         assert!(matches!(result, Err(InventoryError::StockNotFound(_))));
     }
+
+    fn reserve_command(
+        stock_id: StockId,
+        reference_type: &str,
+        quantity: Decimal,
+    ) -> CreateReservationCommand {
+        CreateReservationCommand {
+            stock_id: stock_id.into_uuid(),
+            reference_type: reference_type.to_string(),
+            reference_id: new_uuid(),
+            quantity,
+            expires_at: future_time(),
+            auto_extend_seconds: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_create_reservation_bumps_lower_priority_cart() {
+        let stock_repo = Arc::new(MockStockRepository::new());
+        let reservation_repo = Arc::new(MockReservationRepository::new());
+
+        let mut stock =
+            InventoryStock::create_for_product(StoreId::new(), ProductId::new()).unwrap();
+        stock.adjust_quantity(dec!(10)).unwrap();
+        let stock_id = stock.id();
+        stock_repo.add_stock(stock);
+
+        let use_case = CreateReservationUseCase::new(
+            stock_repo.clone(),
+            reservation_repo.clone(),
+            ReservationPriorityPolicy::default(),
+        );
+        let first_cart = use_case
+            .execute(reserve_command(stock_id, "cart", dec!(4)))
+            .await
+            .unwrap();
+        let second_cart = use_case
+            .execute(reserve_command(stock_id, "cart", dec!(4)))
+            .await
+            .unwrap();
+
+        // A cart can't bump another cart
+        let result = use_case
+            .execute(reserve_command(stock_id, "cart", dec!(5)))
+            .await;
+        assert!(matches!(result, Err(InventoryError::InsufficientStock)));
+
+        // 2 units free; an order needing 5 bumps one cart, the first to expire
+        let order = use_case
+            .execute(reserve_command(stock_id, "order", dec!(5)))
+            .await
+            .unwrap();
+        assert_eq!(order.priority, 100);
+
+        let bumped = reservation_repo
+            .find_by_id(ReservationId::from_uuid(first_cart.id))
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(bumped.status(), ReservationStatus::Expired);
+        assert!(bumped.bumped_at().is_some());
+        assert!(
+            bumped
+                .bump_reason()
+                .unwrap()
+                .contains(&order.id.to_string())
+        );
+
+        let kept = reservation_repo
+            .find_by_id(ReservationId::from_uuid(second_cart.id))
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(kept.status(), ReservationStatus::Pending);
+        assert!(kept.bumped_at().is_none());
+
+        let stock = stock_repo.find_by_id(stock_id).await.unwrap().unwrap();
+        assert_eq!(stock.reserved_quantity(), dec!(9));
+    }
+
+    #[tokio::test]
+    async fn test_create_reservation_never_bumps_confirmed_or_checkout_holds() {
+        let stock_repo = Arc::new(MockStockRepository::new());
+        let reservation_repo = Arc::new(MockReservationRepository::new());
+
+        let mut stock =
+            InventoryStock::create_for_product(StoreId::new(), ProductId::new()).unwrap();
+        stock.adjust_quantity(dec!(10)).unwrap();
+        let stock_id = stock.id();
+        stock_repo.add_stock(stock);
+
+        let policy = ReservationPriorityPolicy::default().with_priority("vip", 500);
+        let use_case =
+            CreateReservationUseCase::new(stock_repo.clone(), reservation_repo.clone(), policy);
+        let confirmed_cart = use_case
+            .execute(reserve_command(stock_id, "cart", dec!(4)))
+            .await
+            .unwrap();
+        let mut confirmed = reservation_repo
+            .find_by_id(ReservationId::from_uuid(confirmed_cart.id))
+            .await
+            .unwrap()
+            .unwrap();
+        confirmed.confirm().unwrap();
+        reservation_repo.update(&confirmed).await.unwrap();
+        use_case
+            .execute(reserve_command(stock_id, "checkout", dec!(6)))
+            .await
+            .unwrap();
+
+        let result = use_case
+            .execute(reserve_command(stock_id, "vip", dec!(1)))
+            .await;
+        assert!(matches!(result, Err(InventoryError::InsufficientStock)));
+
+        let holds = reservation_repo.find_by_stock_id(stock_id).await.unwrap();
+        assert!(holds.iter().all(|r| r.bumped_at().is_none()));
+        let stock = stock_repo.find_by_id(stock_id).await.unwrap().unwrap();
+        assert_eq!(stock.reserved_quantity(), dec!(10));
+    }
 }
@@ -135,6 +135,7 @@ where
                 quantity_requested: item.quantity_requested(),
                 quantity_shipped: item.quantity_shipped(),
                 quantity_received: item.quantity_received(),
+                quantity_discrepancy: item.shipping_discrepancy(),
                 unit_cost: item.unit_cost(),
                 notes: item.notes().map(|s| s.to_string()),
                 created_at: item.created_at(),
@@ -156,6 +157,11 @@ where
             notes: transfer.notes().map(|s| s.to_string()),
             shipping_method: transfer.shipping_method().map(|s| s.to_string()),
             tracking_number: transfer.tracking_number().map(|s| s.to_string()),
+            requires_approval: transfer.requires_approval(),
+            approval_reason: transfer.approval_reason().map(|s| s.to_string()),
+            approved_by_id: transfer.approved_by_id().map(|id| id.into_uuid()),
+            approved_at: transfer.approved_at(),
+            approval_notes: transfer.approval_notes().map(|s| s.to_string()),
             items,
             created_at: transfer.created_at(),
             updated_at: transfer.updated_at(),
@@ -234,6 +240,10 @@ mod tests {
             Ok(vec![])
         }
 
+        async fn find_in_transit(&self) -> Result<Vec<StockTransfer>, InventoryError> {
+            Ok(vec![])
+        }
+
         async fn update(&self, transfer: &StockTransfer) -> Result<(), InventoryError> {
             let mut transfers = self.transfers.lock().unwrap();
             transfers.insert(transfer.id(), transfer.clone());
@@ -0,0 +1,131 @@
+// CreateValuationSnapshotUseCase - records the period-end valuation of a store
+
+use std::sync::Arc;
+
+use chrono::Utc;
+use identity::{StoreId, UserId};
+
+use crate::InventoryError;
+use crate::application::dtos::commands::CreateValuationSnapshotCommand;
+use crate::application::dtos::responses::ValuationSnapshotResponse;
+use crate::application::use_cases::get_valuation_report_use_case::value_on_hand;
+use crate::domain::entities::{ValuationSnapshot, ValuationSnapshotLine};
+use crate::domain::repositories::{
+    InventoryMovementRepository, InventoryStockRepository, ProductRepository,
+    ValuationSnapshotRepository,
+};
+use crate::domain::value_objects::{ProductId, StockId, VariantId};
+
+/// Use case for closing a store's inventory period with a valuation snapshot
+///
+/// The snapshot freezes each stock record's quantity and value, valued like
+/// the valuation report's on-hand stock (stock in transit is left out). It
+/// values stock as it stands when recorded, so it should be taken as soon as
+/// the period ends. Recording is idempotent per store and period end: asking
+/// again returns the snapshot already recorded.
+pub struct CreateValuationSnapshotUseCase<S, M, P, V>
+where
+    S: InventoryStockRepository,
+    M: InventoryMovementRepository,
+    P: ProductRepository,
+    V: ValuationSnapshotRepository,
+{
+    stock_repo: Arc<S>,
+    movement_repo: Arc<M>,
+    product_repo: Arc<P>,
+    snapshot_repo: Arc<V>,
+}
+
+impl<S, M, P, V> CreateValuationSnapshotUseCase<S, M, P, V>
+where
+    S: InventoryStockRepository,
+    M: InventoryMovementRepository,
+    P: ProductRepository,
+    V: ValuationSnapshotRepository,
+{
+    /// Creates a new instance of CreateValuationSnapshotUseCase
+    pub fn new(
+        stock_repo: Arc<S>,
+        movement_repo: Arc<M>,
+        product_repo: Arc<P>,
+        snapshot_repo: Arc<V>,
+    ) -> Self {
+        Self {
+            stock_repo,
+            movement_repo,
+            product_repo,
+            snapshot_repo,
+        }
+    }
+
+    /// Executes the use case to record a snapshot
+    ///
+    /// # Returns
+    /// The snapshot, and whether this call recorded it (false when the period
+    /// already had one)
+    ///
+    /// # Errors
+    /// * `InventoryError::ValuationPeriodNotEnded` - If `period_end` is today
+    ///   or later
+    pub async fn execute(
+        &self,
+        command: CreateValuationSnapshotCommand,
+        actor_id: UserId,
+    ) -> Result<(ValuationSnapshotResponse, bool), InventoryError> {
+        if command.period_end >= Utc::now().date_naive() {
+            return Err(InventoryError::ValuationPeriodNotEnded(command.period_end));
+        }
+
+        let store_id = StoreId::from_uuid(command.store_id);
+        if let Some(existing) = self
+            .snapshot_repo
+            .find_by_store_and_period(store_id, command.period_end)
+            .await?
+        {
+            return Ok((ValuationSnapshotResponse::from(&existing), false));
+        }
+
+        let currency = command.currency.unwrap_or_else(|| "HNL".to_string());
+        let items = value_on_hand(
+            self.stock_repo.as_ref(),
+            self.movement_repo.as_ref(),
+            self.product_repo.as_ref(),
+            Some(command.store_id),
+            &currency,
+        )
+        .await?;
+
+        let lines = items
+            .into_iter()
+            .map(|item| ValuationSnapshotLine {
+                stock_id: StockId::from_uuid(item.stock_id),
+                product_id: item.product_id.map(ProductId::from_uuid),
+                variant_id: item.variant_id.map(VariantId::from_uuid),
+                product_name: item.product_name,
+                variant_name: item.variant_name,
+                sku: item.sku,
+                quantity: item.quantity,
+                costing_method: item.costing_method,
+                unit_cost: item.unit_cost,
+                total_value: item.total_value,
+            })
+            .collect();
+        let snapshot =
+            ValuationSnapshot::record(store_id, command.period_end, currency, lines, actor_id);
+
+        if self.snapshot_repo.save(&snapshot).await? {
+            return Ok((ValuationSnapshotResponse::from(&snapshot), true));
+        }
+
+        // Recorded concurrently by another request
+        let existing = self
+            .snapshot_repo
+            .find_by_store_and_period(store_id, command.period_end)
+            .await?
+            .ok_or(InventoryError::ValuationSnapshotNotFound(
+                command.store_id,
+                command.period_end,
+            ))?;
+        Ok((ValuationSnapshotResponse::from(&existing), false))
+    }
+}
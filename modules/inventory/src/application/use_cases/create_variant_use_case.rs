@@ -5,28 +5,36 @@ use std::sync::Arc;
 use crate::InventoryError;
 use crate::application::dtos::commands::CreateVariantCommand;
 use crate::application::dtos::responses::VariantResponse;
+use crate::application::helpers::generate_category_barcode;
 use crate::domain::entities::ProductVariant;
-use crate::domain::repositories::ProductRepository;
+use crate::domain::repositories::{BarcodeSequenceRepository, ProductRepository};
 use crate::domain::value_objects::{Barcode, ProductId};
 
 /// Use case for creating a new product variant
 ///
 /// Validates that the product has variants enabled, auto-generates variant SKU,
-/// and validates barcode uniqueness.
-pub struct CreateVariantUseCase<P>
+/// and validates barcode uniqueness. Variants created without a barcode get an
+/// EAN-13 generated from the product category's prefix, if one is configured.
+pub struct CreateVariantUseCase<P, B>
 where
     P: ProductRepository,
+    B: BarcodeSequenceRepository,
 {
     product_repo: Arc<P>,
+    barcode_repo: Arc<B>,
 }
 
-impl<P> CreateVariantUseCase<P>
+impl<P, B> CreateVariantUseCase<P, B>
 where
     P: ProductRepository,
+    B: BarcodeSequenceRepository,
 {
     /// Creates a new instance of CreateVariantUseCase
-    pub fn new(product_repo: Arc<P>) -> Self {
-        Self { product_repo }
+    pub fn new(product_repo: Arc<P>, barcode_repo: Arc<B>) -> Self {
+        Self {
+            product_repo,
+            barcode_repo,
+        }
     }
 
     /// Executes the use case to create a new product variant
@@ -42,6 +50,8 @@ where
     /// * `InventoryError::VariantsNotEnabled` - If product has_variants is false
     /// * `InventoryError::DuplicateBarcode` - If barcode already exists
     /// * `InventoryError::InvalidBarcode` - If barcode format is invalid
+    /// * `InventoryError::InvalidEan13CheckDigit` - If a 13-digit barcode has a wrong check digit
+    /// * `InventoryError::BarcodeSequenceExhausted` - If the category's prefix has no values left
     pub async fn execute(
         &self,
         command: CreateVariantCommand,
@@ -93,8 +103,16 @@ where
             ProductVariant::create(product_id, product.sku(), variant_index, command.name);
 
         // Apply optional fields
-        if let Some(barcode) = barcode {
-            variant.set_barcode(Some(barcode));
+        match barcode {
+            Some(barcode) => variant.set_barcode(Some(barcode)),
+            None => {
+                if let Some(generated) =
+                    generate_category_barcode(self.barcode_repo.as_ref(), product.category_id())
+                        .await?
+                {
+                    variant.set_generated_barcode(generated);
+                }
+            }
         }
         variant.set_variant_attributes(command.variant_attributes);
         if let Some(price) = command.price {
@@ -117,6 +135,7 @@ where
             product_id: variant.product_id().into_uuid(),
             sku: variant.sku().as_str().to_string(),
             barcode: variant.barcode().map(|b| b.as_str().to_string()),
+            barcode_generated: variant.barcode_generated(),
             name: variant.name().to_string(),
             variant_attributes: variant.variant_attributes().clone(),
             price: variant.price(),
@@ -137,9 +156,12 @@ mod tests {
     use rust_decimal_macros::dec;
     use std::collections::HashMap;
     use std::sync::Mutex;
+    use uuid::Uuid;
 
-    use crate::domain::entities::Product;
-    use crate::domain::value_objects::{CategoryId, Sku, UnitOfMeasure, VariantId};
+    use crate::domain::entities::{Product, ProductVendor};
+    use crate::domain::value_objects::{
+        BarcodePrefix, CategoryId, Sku, UnitConversion, UnitOfMeasure, VariantId,
+    };
 
     // Mock repository for testing
     struct MockProductRepository {
@@ -291,11 +313,33 @@ mod tests {
                 .unwrap_or(0))
         }
 
+        async fn save_product_vendors(
+            &self,
+            _vendors: &[ProductVendor],
+        ) -> Result<(), InventoryError> {
+            unimplemented!()
+        }
+
+        async fn find_product_vendors(
+            &self,
+            _product_id: ProductId,
+        ) -> Result<Vec<ProductVendor>, InventoryError> {
+            unimplemented!()
+        }
+
+        async fn find_preferred_vendor(
+            &self,
+            _product_id: ProductId,
+        ) -> Result<Option<ProductVendor>, InventoryError> {
+            unimplemented!()
+        }
+
         async fn find_paginated(
             &self,
             _category_id: Option<CategoryId>,
             _is_active: Option<bool>,
             _search: Option<&str>,
+            _after_id: Option<Uuid>,
             _page: i64,
             _page_size: i64,
         ) -> Result<(Vec<Product>, i64), InventoryError> {
@@ -310,12 +354,68 @@ mod tests {
         ) -> Result<i64, InventoryError> {
             unimplemented!()
         }
+
+        async fn search(
+            &self,
+            _term: &str,
+            _category_id: Option<CategoryId>,
+            _active_only: bool,
+            _page: i64,
+            _page_size: i64,
+        ) -> Result<(Vec<(Product, f64)>, i64), InventoryError> {
+            unimplemented!()
+        }
+
+        async fn replace_unit_conversions(
+            &self,
+            _product_id: ProductId,
+            _conversions: &[UnitConversion],
+        ) -> Result<(), InventoryError> {
+            unimplemented!()
+        }
+
+        async fn find_unit_conversions(
+            &self,
+            _product_id: ProductId,
+        ) -> Result<Vec<UnitConversion>, InventoryError> {
+            unimplemented!()
+        }
+    }
+
+    // No category prefixes are configured, so variants keep their entered barcode
+    struct MockBarcodeSequenceRepository;
+
+    #[async_trait]
+    impl BarcodeSequenceRepository for MockBarcodeSequenceRepository {
+        async fn find_prefix(
+            &self,
+            _category_id: CategoryId,
+        ) -> Result<Option<BarcodePrefix>, InventoryError> {
+            Ok(None)
+        }
+
+        async fn save_prefix(
+            &self,
+            _category_id: CategoryId,
+            _prefix: Option<&BarcodePrefix>,
+        ) -> Result<(), InventoryError> {
+            unimplemented!()
+        }
+
+        async fn next_value(&self, _prefix: &BarcodePrefix) -> Result<u64, InventoryError> {
+            unimplemented!()
+        }
+
+        async fn barcode_exists(&self, _barcode: &Barcode) -> Result<bool, InventoryError> {
+            unimplemented!()
+        }
     }
 
     #[tokio::test]
     async fn test_create_variant_basic() {
         let repo = Arc::new(MockProductRepository::new());
-        let use_case = CreateVariantUseCase::new(repo.clone());
+        let use_case =
+            CreateVariantUseCase::new(repo.clone(), Arc::new(MockBarcodeSequenceRepository));
 
         // Create a product with variants enabled
         let mut product = Product::create("T-Shirt".to_string(), UnitOfMeasure::Unit, None);
@@ -351,7 +451,8 @@ mod tests {
     #[tokio::test]
     async fn test_create_multiple_variants() {
         let repo = Arc::new(MockProductRepository::new());
-        let use_case = CreateVariantUseCase::new(repo.clone());
+        let use_case =
+            CreateVariantUseCase::new(repo.clone(), Arc::new(MockBarcodeSequenceRepository));
 
         // Create a product with variants enabled
         let mut product = Product::create("Shoes".to_string(), UnitOfMeasure::Unit, None);
@@ -388,7 +489,8 @@ mod tests {
     #[tokio::test]
     async fn test_variants_not_enabled_error() {
         let repo = Arc::new(MockProductRepository::new());
-        let use_case = CreateVariantUseCase::new(repo.clone());
+        let use_case =
+            CreateVariantUseCase::new(repo.clone(), Arc::new(MockBarcodeSequenceRepository));
 
         // Create a product WITHOUT variants enabled
         let product = Product::create("Simple Product".to_string(), UnitOfMeasure::Unit, None);
@@ -410,7 +512,7 @@ mod tests {
     #[tokio::test]
     async fn test_product_not_found_error() {
         let repo = Arc::new(MockProductRepository::new());
-        let use_case = CreateVariantUseCase::new(repo);
+        let use_case = CreateVariantUseCase::new(repo, Arc::new(MockBarcodeSequenceRepository));
 
         let non_existent_product = ProductId::new().into_uuid();
         let command = CreateVariantCommand {
@@ -429,7 +531,8 @@ mod tests {
     #[tokio::test]
     async fn test_duplicate_barcode_error() {
         let repo = Arc::new(MockProductRepository::new());
-        let use_case = CreateVariantUseCase::new(repo.clone());
+        let use_case =
+            CreateVariantUseCase::new(repo.clone(), Arc::new(MockBarcodeSequenceRepository));
 
         // Create a product with variants enabled
         let mut product = Product::create("Product".to_string(), UnitOfMeasure::Unit, None);
@@ -443,7 +546,7 @@ mod tests {
             variant_attributes: serde_json::json!({}),
             price: None,
             cost_price: None,
-            barcode: Some("1234567890123".to_string()),
+            barcode: Some("1234567890128".to_string()),
         };
         use_case.execute(command1).await.unwrap();
 
@@ -454,7 +557,7 @@ mod tests {
             variant_attributes: serde_json::json!({}),
             price: None,
             cost_price: None,
-            barcode: Some("1234567890123".to_string()),
+            barcode: Some("1234567890128".to_string()),
         };
 
         let result = use_case.execute(command2).await;
@@ -464,7 +567,8 @@ mod tests {
     #[tokio::test]
     async fn test_effective_prices() {
         let repo = Arc::new(MockProductRepository::new());
-        let use_case = CreateVariantUseCase::new(repo.clone());
+        let use_case =
+            CreateVariantUseCase::new(repo.clone(), Arc::new(MockBarcodeSequenceRepository));
 
         // Create a product with base prices
         let mut product = Product::create("Product".to_string(), UnitOfMeasure::Unit, None);
@@ -50,7 +50,9 @@ where
 mod tests {
     use super::*;
     use crate::domain::entities::ProductCategory;
+    use crate::domain::repositories::CategoryStockValuation;
     use async_trait::async_trait;
+    use identity::StoreId;
     use std::collections::HashMap;
     use std::sync::Mutex;
 
@@ -108,6 +110,12 @@ mod tests {
             categories.insert(category.id(), category.clone());
             Ok(())
         }
+        async fn stock_valuation_by_category(
+            &self,
+            _store_id: Option<StoreId>,
+        ) -> Result<Vec<CategoryStockValuation>, InventoryError> {
+            Ok(vec![])
+        }
         async fn delete(&self, _id: CategoryId) -> Result<(), InventoryError> {
             Ok(())
         }
@@ -137,7 +137,11 @@ where
             reference_id: reservation.reference_id(),
             quantity: reservation.quantity(),
             status: reservation.status().to_string(),
+            priority: reservation.priority(),
             expires_at: reservation.expires_at(),
+            auto_extend_seconds: reservation.auto_extend_seconds(),
+            bumped_at: reservation.bumped_at(),
+            bump_reason: reservation.bump_reason().map(str::to_string),
             created_at: reservation.created_at(),
             updated_at: reservation.updated_at(),
         })
@@ -148,7 +152,7 @@ where
 mod tests {
     use super::*;
     use async_trait::async_trait;
-    use chrono::{Duration, Utc};
+    use chrono::{DateTime, Duration, Utc};
     use rust_decimal::Decimal;
     use rust_decimal_macros::dec;
     use std::collections::HashMap;
@@ -156,6 +160,7 @@ mod tests {
     use uuid::{NoContext, Timestamp, Uuid};
 
     use crate::domain::entities::{InventoryReservation, InventoryStock};
+    use crate::domain::repositories::ReservationStatusCount;
     use crate::domain::value_objects::{ProductId, ReservationId, ReservationStatus, StockId};
     use identity::StoreId;
 
@@ -234,14 +239,26 @@ mod tests {
 
         async fn find_paginated(
             &self,
+            _store_ids: Option<&[StoreId]>,
             _stock_id: Option<StockId>,
             _status: Option<&str>,
             _reference_type: Option<&str>,
+            _after_id: Option<Uuid>,
             _page: i64,
             _page_size: i64,
         ) -> Result<(Vec<InventoryReservation>, i64), InventoryError> {
             unimplemented!()
         }
+
+        async fn count_by_status(
+            &self,
+            _store_id: StoreId,
+            _from: DateTime<Utc>,
+            _to: DateTime<Utc>,
+            _by_day: bool,
+        ) -> Result<Vec<ReservationStatusCount>, InventoryError> {
+            unimplemented!()
+        }
     }
 
     struct MockStockRepository {
@@ -323,9 +340,10 @@ mod tests {
 
         async fn find_paginated(
             &self,
-            _store_id: Option<StoreId>,
+            _store_ids: Option<&[StoreId]>,
             _product_id: Option<crate::domain::value_objects::ProductId>,
             _low_stock_only: bool,
+            _after_id: Option<Uuid>,
             _page: i64,
             _page_size: i64,
         ) -> Result<(Vec<InventoryStock>, i64), InventoryError> {
@@ -389,7 +407,11 @@ mod tests {
             new_uuid(),
             quantity,
             ReservationStatus::Pending,
+            0,
             past_time(),
+            None,
+            None,
+            None,
             Utc::now() - Duration::hours(2),
             Utc::now() - Duration::hours(2),
         )
@@ -452,6 +474,7 @@ mod tests {
             new_uuid(),
             dec!(30),
             future_time(),
+            0,
         )
         .unwrap();
         reservation_repo.add_reservation(reservation);
@@ -494,6 +517,7 @@ mod tests {
             new_uuid(),
             dec!(30),
             future_time(),
+            0,
         )
         .unwrap();
         reservation_repo.add_reservation(active_reservation);
@@ -0,0 +1,831 @@
+// ExportProductsUseCase - writes the product catalog out as rows of chosen columns
+
+use std::collections::HashMap;
+use std::fmt;
+use std::str::FromStr;
+use std::sync::Arc;
+
+use rust_decimal::Decimal;
+use uuid::Uuid;
+
+use super::ListProductsQuery;
+use super::import_products_use_case::CATEGORY_PATH_SEPARATOR;
+use crate::InventoryError;
+use crate::application::dtos::responses::ProductExportRow;
+use crate::domain::entities::{InventoryStock, Product};
+use crate::domain::repositories::{
+    CategoryRepository, InventoryStockRepository, ProductRepository,
+};
+use crate::domain::value_objects::{CategoryId, ProductId};
+use identity::StoreId;
+
+/// Products read from the repository per round trip
+const EXPORT_PAGE_SIZE: i64 = 100;
+
+/// A column of a product export
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ProductExportColumn {
+    Sku,
+    Name,
+    Description,
+    Barcode,
+    /// Category names from the root down, e.g. `Beverages/Sodas`
+    CategoryPath,
+    Brand,
+    UnitOfMeasure,
+    BasePrice,
+    CostPrice,
+    Currency,
+    TaxRate,
+    IsActive,
+    /// On-hand quantity at the export's store
+    Stock,
+    /// On-hand minus reserved quantity at the export's store
+    AvailableStock,
+}
+
+impl ProductExportColumn {
+    /// Columns exported when none are requested; they match the columns a
+    /// catalog import reads, so an export can be edited and re-imported.
+    pub const DEFAULT: [ProductExportColumn; 7] = [
+        ProductExportColumn::Name,
+        ProductExportColumn::Sku,
+        ProductExportColumn::Barcode,
+        ProductExportColumn::CategoryPath,
+        ProductExportColumn::BasePrice,
+        ProductExportColumn::CostPrice,
+        ProductExportColumn::UnitOfMeasure,
+    ];
+
+    /// Column name used as the CSV header and JSON key
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ProductExportColumn::Sku => "sku",
+            ProductExportColumn::Name => "name",
+            ProductExportColumn::Description => "description",
+            ProductExportColumn::Barcode => "barcode",
+            ProductExportColumn::CategoryPath => "category_path",
+            ProductExportColumn::Brand => "brand",
+            ProductExportColumn::UnitOfMeasure => "unit_of_measure",
+            ProductExportColumn::BasePrice => "base_price",
+            ProductExportColumn::CostPrice => "cost_price",
+            ProductExportColumn::Currency => "currency",
+            ProductExportColumn::TaxRate => "tax_rate",
+            ProductExportColumn::IsActive => "is_active",
+            ProductExportColumn::Stock => "stock",
+            ProductExportColumn::AvailableStock => "available_stock",
+        }
+    }
+
+    /// Whether the column reads stock, which needs a store
+    pub fn is_stock(&self) -> bool {
+        matches!(
+            self,
+            ProductExportColumn::Stock | ProductExportColumn::AvailableStock
+        )
+    }
+}
+
+impl FromStr for ProductExportColumn {
+    type Err = InventoryError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().replace('-', "_").as_str() {
+            "sku" => Ok(ProductExportColumn::Sku),
+            "name" => Ok(ProductExportColumn::Name),
+            "description" => Ok(ProductExportColumn::Description),
+            "barcode" => Ok(ProductExportColumn::Barcode),
+            "category_path" | "category" => Ok(ProductExportColumn::CategoryPath),
+            "brand" => Ok(ProductExportColumn::Brand),
+            "unit_of_measure" | "unit" => Ok(ProductExportColumn::UnitOfMeasure),
+            "base_price" => Ok(ProductExportColumn::BasePrice),
+            "cost_price" => Ok(ProductExportColumn::CostPrice),
+            "currency" => Ok(ProductExportColumn::Currency),
+            "tax_rate" => Ok(ProductExportColumn::TaxRate),
+            "is_active" => Ok(ProductExportColumn::IsActive),
+            "stock" | "quantity" => Ok(ProductExportColumn::Stock),
+            "available_stock" | "available_quantity" => Ok(ProductExportColumn::AvailableStock),
+            other => Err(InventoryError::InvalidExportColumn(other.to_string())),
+        }
+    }
+}
+
+impl fmt::Display for ProductExportColumn {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Query parameters for exporting products
+#[derive(Debug, Clone, Default)]
+pub struct ExportProductsQuery {
+    /// Which products to export; pagination fields are ignored
+    pub filter: ListProductsQuery,
+    /// Columns in output order; empty means [`ProductExportColumn::DEFAULT`]
+    pub columns: Vec<ProductExportColumn>,
+    /// Store whose stock fills the stock columns
+    pub store_id: Option<Uuid>,
+    /// Stop after this many rows
+    pub limit: Option<usize>,
+}
+
+/// Use case for exporting the product catalog, e.g. to send a vendor a
+/// price list in their template.
+///
+/// Products are read from the repository a page at a time and handed to the
+/// caller's sink as they are converted, so a large catalog is never held in
+/// memory at once.
+pub struct ExportProductsUseCase<P, C, S>
+where
+    P: ProductRepository,
+    C: CategoryRepository,
+    S: InventoryStockRepository,
+{
+    product_repo: Arc<P>,
+    category_repo: Arc<C>,
+    stock_repo: Arc<S>,
+}
+
+impl<P, C, S> ExportProductsUseCase<P, C, S>
+where
+    P: ProductRepository,
+    C: CategoryRepository,
+    S: InventoryStockRepository,
+{
+    /// Creates a new instance of ExportProductsUseCase
+    pub fn new(product_repo: Arc<P>, category_repo: Arc<C>, stock_repo: Arc<S>) -> Self {
+        Self {
+            product_repo,
+            category_repo,
+            stock_repo,
+        }
+    }
+
+    /// Executes the use case, calling `sink` with each exported row
+    ///
+    /// # Arguments
+    /// * `query` - Product filters, columns and optional store
+    /// * `sink` - Receives rows newest product first
+    ///
+    /// # Returns
+    /// The number of rows exported
+    ///
+    /// # Errors
+    /// * `InventoryError::InvalidExportColumn` - If stock columns are requested
+    ///   without a store
+    pub async fn execute<F>(
+        &self,
+        query: ExportProductsQuery,
+        mut sink: F,
+    ) -> Result<usize, InventoryError>
+    where
+        F: FnMut(ProductExportRow),
+    {
+        let columns = if query.columns.is_empty() {
+            ProductExportColumn::DEFAULT.to_vec()
+        } else {
+            query.columns
+        };
+        let store_id = query.store_id.map(StoreId::from_uuid);
+        let wants_stock = columns.iter().any(ProductExportColumn::is_stock);
+        if wants_stock && store_id.is_none() {
+            return Err(InventoryError::InvalidExportColumn(
+                "stock columns require a store".to_string(),
+            ));
+        }
+        let wants_category = columns.contains(&ProductExportColumn::CategoryPath);
+        let limit = query.limit.unwrap_or(usize::MAX);
+
+        let category_id = query.filter.category_id.map(CategoryId::from_uuid);
+        let mut category_paths = HashMap::new();
+        let mut exported = 0;
+        let mut after_id = None;
+
+        while exported < limit {
+            // Keyset pages, so products added during the export don't shift rows
+            let (products, _) = self
+                .product_repo
+                .find_paginated(
+                    category_id,
+                    query.filter.is_active,
+                    query.filter.search.as_deref(),
+                    after_id,
+                    1,
+                    EXPORT_PAGE_SIZE,
+                )
+                .await?;
+            let Some(last) = products.last() else {
+                break;
+            };
+            after_id = Some(last.id().into_uuid());
+
+            let stock: HashMap<ProductId, InventoryStock> = match store_id {
+                Some(store_id) if wants_stock => {
+                    let ids: Vec<ProductId> = products.iter().map(|p| p.id()).collect();
+                    self.stock_repo
+                        .find_by_store_and_products(store_id, &ids)
+                        .await?
+                        .into_iter()
+                        .filter_map(|s| s.product_id().map(|id| (id, s)))
+                        .collect()
+                }
+                _ => HashMap::new(),
+            };
+
+            let page_len = products.len();
+            for product in products.into_iter().take(limit - exported) {
+                let category_path = match product.category_id() {
+                    Some(id) if wants_category => {
+                        self.category_path(id, &mut category_paths).await?
+                    }
+                    _ => String::new(),
+                };
+                let cells = columns
+                    .iter()
+                    .map(|column| {
+                        let value =
+                            cell(*column, &product, &category_path, stock.get(&product.id()));
+                        (column.as_str(), value)
+                    })
+                    .collect();
+                sink(ProductExportRow { cells });
+                exported += 1;
+            }
+
+            if (page_len as i64) < EXPORT_PAGE_SIZE {
+                break;
+            }
+        }
+
+        Ok(exported)
+    }
+
+    /// Path of a category from the root, remembering every level resolved
+    async fn category_path(
+        &self,
+        id: CategoryId,
+        cache: &mut HashMap<CategoryId, String>,
+    ) -> Result<String, InventoryError> {
+        let mut chain = Vec::new();
+        let mut prefix: Option<String> = None;
+        let mut next = Some(id);
+        while let Some(current) = next {
+            if let Some(path) = cache.get(&current) {
+                prefix = Some(path.clone());
+                break;
+            }
+            match self.category_repo.find_by_id(current).await? {
+                Some(category) => {
+                    next = category.parent_id();
+                    chain.push(category);
+                }
+                None => break,
+            }
+        }
+
+        let mut path = prefix;
+        for category in chain.iter().rev() {
+            let full = match path {
+                Some(parent) => format!("{}{}{}", parent, CATEGORY_PATH_SEPARATOR, category.name()),
+                None => category.name().to_string(),
+            };
+            cache.insert(category.id(), full.clone());
+            path = Some(full);
+        }
+        Ok(path.unwrap_or_default())
+    }
+}
+
+/// Text of one column for a product
+fn cell(
+    column: ProductExportColumn,
+    product: &Product,
+    category_path: &str,
+    stock: Option<&InventoryStock>,
+) -> String {
+    let quantity = |f: fn(&InventoryStock) -> Decimal| {
+        stock
+            .map(f)
+            .unwrap_or(Decimal::ZERO)
+            .normalize()
+            .to_string()
+    };
+    match column {
+        ProductExportColumn::Sku => product.sku().to_string(),
+        ProductExportColumn::Name => product.name().to_string(),
+        ProductExportColumn::Description => product.description().unwrap_or_default().to_string(),
+        ProductExportColumn::Barcode => {
+            product.barcode().map(|b| b.to_string()).unwrap_or_default()
+        }
+        ProductExportColumn::CategoryPath => category_path.to_string(),
+        ProductExportColumn::Brand => product.brand().unwrap_or_default().to_string(),
+        ProductExportColumn::UnitOfMeasure => product.unit_of_measure().to_string(),
+        ProductExportColumn::BasePrice => product.base_price().to_string(),
+        ProductExportColumn::CostPrice => product.cost_price().to_string(),
+        ProductExportColumn::Currency => product.currency().to_string(),
+        ProductExportColumn::TaxRate => product.tax_rate().to_string(),
+        ProductExportColumn::IsActive => product.is_active().to_string(),
+        ProductExportColumn::Stock => quantity(InventoryStock::quantity),
+        ProductExportColumn::AvailableStock => quantity(InventoryStock::available_quantity),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use async_trait::async_trait;
+    use rust_decimal_macros::dec;
+
+    use crate::domain::entities::{ProductCategory, ProductVariant, ProductVendor};
+    use crate::domain::repositories::CategoryStockValuation;
+    use crate::domain::value_objects::{
+        Barcode, Sku, StockId, UnitConversion, UnitOfMeasure, VariantId,
+    };
+
+    struct MockProductRepository {
+        /// Newest first, like the repository's keyset order
+        products: Vec<Product>,
+    }
+
+    #[async_trait]
+    impl ProductRepository for MockProductRepository {
+        async fn save(&self, _product: &Product) -> Result<(), InventoryError> {
+            unimplemented!()
+        }
+
+        async fn find_by_id(&self, _id: ProductId) -> Result<Option<Product>, InventoryError> {
+            unimplemented!()
+        }
+
+        async fn find_by_sku(&self, _sku: &Sku) -> Result<Option<Product>, InventoryError> {
+            unimplemented!()
+        }
+
+        async fn find_by_barcode(
+            &self,
+            _barcode: &Barcode,
+        ) -> Result<Option<Product>, InventoryError> {
+            unimplemented!()
+        }
+
+        async fn update(&self, _product: &Product) -> Result<(), InventoryError> {
+            unimplemented!()
+        }
+
+        async fn delete(&self, _id: ProductId) -> Result<(), InventoryError> {
+            unimplemented!()
+        }
+
+        async fn find_active(&self) -> Result<Vec<Product>, InventoryError> {
+            unimplemented!()
+        }
+
+        async fn find_by_category(
+            &self,
+            _category_id: CategoryId,
+        ) -> Result<Vec<Product>, InventoryError> {
+            unimplemented!()
+        }
+
+        async fn find_paginated(
+            &self,
+            _category_id: Option<CategoryId>,
+            _is_active: Option<bool>,
+            _search: Option<&str>,
+            after_id: Option<Uuid>,
+            _page: i64,
+            page_size: i64,
+        ) -> Result<(Vec<Product>, i64), InventoryError> {
+            let page = self
+                .products
+                .iter()
+                .filter(|p| after_id.is_none_or(|after| p.id().into_uuid() < after))
+                .take(page_size as usize)
+                .cloned()
+                .collect();
+            Ok((page, self.products.len() as i64))
+        }
+
+        async fn count_filtered(
+            &self,
+            _category_id: Option<CategoryId>,
+            _is_active: Option<bool>,
+            _search: Option<&str>,
+        ) -> Result<i64, InventoryError> {
+            unimplemented!()
+        }
+
+        async fn search(
+            &self,
+            _term: &str,
+            _category_id: Option<CategoryId>,
+            _active_only: bool,
+            _page: i64,
+            _page_size: i64,
+        ) -> Result<(Vec<(Product, f64)>, i64), InventoryError> {
+            unimplemented!()
+        }
+
+        async fn save_variant(&self, _variant: &ProductVariant) -> Result<(), InventoryError> {
+            unimplemented!()
+        }
+
+        async fn find_variant_by_id(
+            &self,
+            _id: VariantId,
+        ) -> Result<Option<ProductVariant>, InventoryError> {
+            unimplemented!()
+        }
+
+        async fn find_variant_by_sku(
+            &self,
+            _sku: &Sku,
+        ) -> Result<Option<ProductVariant>, InventoryError> {
+            unimplemented!()
+        }
+
+        async fn find_variant_by_barcode(
+            &self,
+            _barcode: &Barcode,
+        ) -> Result<Option<ProductVariant>, InventoryError> {
+            unimplemented!()
+        }
+
+        async fn find_variants_by_product(
+            &self,
+            _product_id: ProductId,
+        ) -> Result<Vec<ProductVariant>, InventoryError> {
+            unimplemented!()
+        }
+
+        async fn update_variant(&self, _variant: &ProductVariant) -> Result<(), InventoryError> {
+            unimplemented!()
+        }
+
+        async fn delete_variant(&self, _id: VariantId) -> Result<(), InventoryError> {
+            unimplemented!()
+        }
+
+        async fn count_variants(&self, _product_id: ProductId) -> Result<u32, InventoryError> {
+            unimplemented!()
+        }
+
+        async fn save_product_vendors(
+            &self,
+            _vendors: &[ProductVendor],
+        ) -> Result<(), InventoryError> {
+            unimplemented!()
+        }
+
+        async fn find_product_vendors(
+            &self,
+            _product_id: ProductId,
+        ) -> Result<Vec<ProductVendor>, InventoryError> {
+            unimplemented!()
+        }
+
+        async fn find_preferred_vendor(
+            &self,
+            _product_id: ProductId,
+        ) -> Result<Option<ProductVendor>, InventoryError> {
+            unimplemented!()
+        }
+
+        async fn replace_unit_conversions(
+            &self,
+            _product_id: ProductId,
+            _conversions: &[UnitConversion],
+        ) -> Result<(), InventoryError> {
+            unimplemented!()
+        }
+
+        async fn find_unit_conversions(
+            &self,
+            _product_id: ProductId,
+        ) -> Result<Vec<UnitConversion>, InventoryError> {
+            unimplemented!()
+        }
+    }
+
+    struct MockCategoryRepository {
+        categories: Vec<ProductCategory>,
+    }
+
+    #[async_trait]
+    impl CategoryRepository for MockCategoryRepository {
+        async fn save(&self, _category: &ProductCategory) -> Result<(), InventoryError> {
+            unimplemented!()
+        }
+
+        async fn find_by_id(
+            &self,
+            id: CategoryId,
+        ) -> Result<Option<ProductCategory>, InventoryError> {
+            Ok(self.categories.iter().find(|c| c.id() == id).cloned())
+        }
+
+        async fn find_by_slug(
+            &self,
+            _slug: &str,
+        ) -> Result<Option<ProductCategory>, InventoryError> {
+            unimplemented!()
+        }
+
+        async fn find_root_categories(&self) -> Result<Vec<ProductCategory>, InventoryError> {
+            unimplemented!()
+        }
+
+        async fn find_children(
+            &self,
+            _parent_id: CategoryId,
+        ) -> Result<Vec<ProductCategory>, InventoryError> {
+            unimplemented!()
+        }
+
+        async fn find_all_active(&self) -> Result<Vec<ProductCategory>, InventoryError> {
+            unimplemented!()
+        }
+
+        async fn update(&self, _category: &ProductCategory) -> Result<(), InventoryError> {
+            unimplemented!()
+        }
+
+        async fn stock_valuation_by_category(
+            &self,
+            _store_id: Option<StoreId>,
+        ) -> Result<Vec<CategoryStockValuation>, InventoryError> {
+            unimplemented!()
+        }
+
+        async fn delete(&self, _id: CategoryId) -> Result<(), InventoryError> {
+            unimplemented!()
+        }
+    }
+
+    struct MockStockRepository {
+        stocks: Vec<InventoryStock>,
+    }
+
+    #[async_trait]
+    impl InventoryStockRepository for MockStockRepository {
+        async fn save(&self, _stock: &InventoryStock) -> Result<(), InventoryError> {
+            unimplemented!()
+        }
+
+        async fn find_by_id(&self, _id: StockId) -> Result<Option<InventoryStock>, InventoryError> {
+            unimplemented!()
+        }
+
+        async fn find_by_store_and_product(
+            &self,
+            _store_id: StoreId,
+            _product_id: ProductId,
+        ) -> Result<Option<InventoryStock>, InventoryError> {
+            unimplemented!()
+        }
+
+        async fn find_by_store_and_variant(
+            &self,
+            _store_id: StoreId,
+            _variant_id: VariantId,
+        ) -> Result<Option<InventoryStock>, InventoryError> {
+            unimplemented!()
+        }
+
+        async fn update_with_version(
+            &self,
+            _stock: &InventoryStock,
+            _expected_version: i32,
+        ) -> Result<(), InventoryError> {
+            unimplemented!()
+        }
+
+        async fn find_low_stock(
+            &self,
+            _store_id: StoreId,
+        ) -> Result<Vec<InventoryStock>, InventoryError> {
+            unimplemented!()
+        }
+
+        async fn find_by_store(
+            &self,
+            _store_id: StoreId,
+        ) -> Result<Vec<InventoryStock>, InventoryError> {
+            unimplemented!()
+        }
+
+        async fn find_paginated(
+            &self,
+            _store_ids: Option<&[StoreId]>,
+            _product_id: Option<ProductId>,
+            _low_stock_only: bool,
+            _after_id: Option<Uuid>,
+            _page: i64,
+            _page_size: i64,
+        ) -> Result<(Vec<InventoryStock>, i64), InventoryError> {
+            unimplemented!()
+        }
+
+        async fn find_by_product(
+            &self,
+            _product_id: ProductId,
+        ) -> Result<Vec<InventoryStock>, InventoryError> {
+            unimplemented!()
+        }
+
+        async fn find_all(&self) -> Result<Vec<InventoryStock>, InventoryError> {
+            unimplemented!()
+        }
+
+        async fn find_all_low_stock(&self) -> Result<Vec<InventoryStock>, InventoryError> {
+            unimplemented!()
+        }
+
+        async fn find_low_stock_by_store(
+            &self,
+            _store_id: StoreId,
+        ) -> Result<Vec<InventoryStock>, InventoryError> {
+            unimplemented!()
+        }
+
+        async fn find_by_store_and_products(
+            &self,
+            store_id: StoreId,
+            product_ids: &[ProductId],
+        ) -> Result<Vec<InventoryStock>, InventoryError> {
+            Ok(self
+                .stocks
+                .iter()
+                .filter(|s| {
+                    s.store_id() == store_id
+                        && s.product_id().is_some_and(|id| product_ids.contains(&id))
+                })
+                .cloned()
+                .collect())
+        }
+
+        async fn find_by_store_and_variants(
+            &self,
+            _store_id: StoreId,
+            _variant_ids: &[VariantId],
+        ) -> Result<Vec<InventoryStock>, InventoryError> {
+            unimplemented!()
+        }
+    }
+
+    fn product(sku: &str) -> Product {
+        let mut product = Product::create_with_sku(
+            format!("Product {}", sku),
+            Sku::from_string(sku.to_string()),
+            UnitOfMeasure::Unit,
+        );
+        product.set_base_price(dec!(10.50));
+        product.set_cost_price(dec!(6));
+        product
+    }
+
+    fn use_case(
+        products: Vec<Product>,
+        categories: Vec<ProductCategory>,
+        stocks: Vec<InventoryStock>,
+    ) -> ExportProductsUseCase<MockProductRepository, MockCategoryRepository, MockStockRepository>
+    {
+        let mut products = products;
+        products.sort_by_key(|p| std::cmp::Reverse(p.id().into_uuid()));
+        ExportProductsUseCase::new(
+            Arc::new(MockProductRepository { products }),
+            Arc::new(MockCategoryRepository { categories }),
+            Arc::new(MockStockRepository { stocks }),
+        )
+    }
+
+    #[tokio::test]
+    async fn test_exports_default_columns_with_category_path() {
+        let beverages = ProductCategory::create("Beverages".to_string(), "beverages".to_string());
+        let sodas = ProductCategory::create_subcategory(
+            beverages.id(),
+            "Sodas".to_string(),
+            "sodas".to_string(),
+        );
+        let mut cola = product("COLA-1");
+        cola.set_category_id(Some(sodas.id()));
+
+        let mut rows = Vec::new();
+        let count = use_case(vec![cola], vec![beverages, sodas], Vec::new())
+            .execute(ExportProductsQuery::default(), |row| rows.push(row))
+            .await
+            .unwrap();
+
+        assert_eq!(count, 1);
+        let names: Vec<_> = rows[0].cells.iter().map(|(name, _)| *name).collect();
+        assert_eq!(
+            names,
+            vec![
+                "name",
+                "sku",
+                "barcode",
+                "category_path",
+                "base_price",
+                "cost_price",
+                "unit_of_measure"
+            ]
+        );
+        assert_eq!(rows[0].get("category_path"), Some("Beverages/Sodas"));
+        assert_eq!(rows[0].get("base_price"), Some("10.50"));
+    }
+
+    #[tokio::test]
+    async fn test_walks_every_page_and_honours_limit() {
+        let products: Vec<Product> = (0..250).map(|i| product(&format!("SKU-{}", i))).collect();
+
+        let mut skus = Vec::new();
+        let query = ExportProductsQuery {
+            columns: vec![ProductExportColumn::Sku],
+            ..Default::default()
+        };
+        let count = use_case(products.clone(), Vec::new(), Vec::new())
+            .execute(query.clone(), |row| skus.extend(row.into_values()))
+            .await
+            .unwrap();
+        assert_eq!(count, 250);
+        skus.sort();
+        skus.dedup();
+        assert_eq!(skus.len(), 250);
+
+        let limited = use_case(products, Vec::new(), Vec::new())
+            .execute(
+                ExportProductsQuery {
+                    limit: Some(120),
+                    ..query
+                },
+                |_| {},
+            )
+            .await
+            .unwrap();
+        assert_eq!(limited, 120);
+    }
+
+    #[tokio::test]
+    async fn test_stock_columns_read_the_requested_store() {
+        let store_id = StoreId::new();
+        let cola = product("COLA-1");
+        let chips = product("CHIPS-1");
+        let mut stock = InventoryStock::create_for_product(store_id, cola.id()).unwrap();
+        stock.adjust_quantity(dec!(12)).unwrap();
+        stock.reserve(dec!(2)).unwrap();
+
+        let use_case = use_case(vec![cola, chips], Vec::new(), vec![stock]);
+        let columns = vec![
+            ProductExportColumn::Sku,
+            ProductExportColumn::Stock,
+            ProductExportColumn::AvailableStock,
+        ];
+
+        let mut rows = Vec::new();
+        use_case
+            .execute(
+                ExportProductsQuery {
+                    columns: columns.clone(),
+                    store_id: Some(store_id.into_uuid()),
+                    ..Default::default()
+                },
+                |row| rows.push(row),
+            )
+            .await
+            .unwrap();
+        let cola_row = rows
+            .iter()
+            .find(|r| r.get("sku") == Some("COLA-1"))
+            .unwrap();
+        assert_eq!(cola_row.get("stock"), Some("12"));
+        assert_eq!(cola_row.get("available_stock"), Some("10"));
+        let chips_row = rows
+            .iter()
+            .find(|r| r.get("sku") == Some("CHIPS-1"))
+            .unwrap();
+        assert_eq!(chips_row.get("stock"), Some("0"));
+
+        let without_store = use_case
+            .execute(
+                ExportProductsQuery {
+                    columns,
+                    ..Default::default()
+                },
+                |_| {},
+            )
+            .await;
+        assert!(matches!(
+            without_store,
+            Err(InventoryError::InvalidExportColumn(_))
+        ));
+    }
+
+    #[test]
+    fn test_parse_column() {
+        assert_eq!(
+            "Category-Path".parse::<ProductExportColumn>().unwrap(),
+            ProductExportColumn::CategoryPath
+        );
+        assert!("weight".parse::<ProductExportColumn>().is_err());
+    }
+}
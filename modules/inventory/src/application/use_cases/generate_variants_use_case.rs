@@ -0,0 +1,746 @@
+// GenerateVariantsUseCase - bulk-creates variants from attribute axes
+
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value as JsonValue};
+
+use crate::InventoryError;
+use crate::application::dtos::commands::{GenerateVariantsCommand, VariantAxis};
+use crate::application::dtos::responses::VariantResponse;
+use crate::domain::entities::ProductVariant;
+use crate::domain::repositories::ProductRepository;
+use crate::domain::value_objects::{MAX_SKU_LENGTH, ProductId, Sku};
+
+/// Upper bound on combinations generated by one request
+pub const MAX_GENERATED_VARIANTS: usize = 100;
+
+/// Longest attribute code used in a generated SKU
+pub const MAX_VARIANT_CODE_LENGTH: usize = 8;
+
+/// Result of bulk variant generation
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GenerateVariantsResult {
+    /// Variants created by this request
+    pub created: Vec<VariantResponse>,
+    /// Combinations skipped because a variant with the same attributes exists
+    pub skipped: Vec<SkippedVariantCombination>,
+}
+
+/// A combination that already existed on the product
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SkippedVariantCombination {
+    /// Name the variant would have been given
+    pub name: String,
+    /// Attribute values of the combination
+    pub variant_attributes: JsonValue,
+    /// ID of the existing variant with these attributes
+    pub existing_variant_id: uuid::Uuid,
+}
+
+/// Use case for generating the cartesian product of attribute axes as variants
+///
+/// Each combination gets a name joined from its values (e.g. "M / Red"), its
+/// axis values as `variant_attributes` and a SKU joining the product SKU with
+/// the values' codes (e.g. "{PRODUCT_SKU}-M-RED"). A value's code is its
+/// uppercased letters and digits unless the axis sets one; if the SKU is
+/// too long or already taken by another variant the indexed form ("-V007")
+/// is used.
+/// Combinations whose attributes match an existing variant are skipped, so
+/// the request can be re-run after adding a new value to an axis.
+pub struct GenerateVariantsUseCase<P>
+where
+    P: ProductRepository,
+{
+    product_repo: Arc<P>,
+}
+
+impl<P> GenerateVariantsUseCase<P>
+where
+    P: ProductRepository,
+{
+    /// Creates a new instance of GenerateVariantsUseCase
+    pub fn new(product_repo: Arc<P>) -> Self {
+        Self { product_repo }
+    }
+
+    /// Executes the use case to generate variants
+    ///
+    /// # Errors
+    /// * `InventoryError::ProductNotFound` - If product_id doesn't exist
+    /// * `InventoryError::VariantsNotEnabled` - If product has_variants is false
+    /// * `InventoryError::InvalidVariantAxes` - If axes are empty, duplicated,
+    ///   two values of an axis share a SKU code or an override doesn't match a
+    ///   combination
+    /// * `InventoryError::TooManyVariantCombinations` - If the cartesian product
+    ///   exceeds [`MAX_GENERATED_VARIANTS`]
+    pub async fn execute(
+        &self,
+        command: GenerateVariantsCommand,
+    ) -> Result<GenerateVariantsResult, InventoryError> {
+        let product_id = ProductId::from_uuid(command.product_id);
+
+        let product = self
+            .product_repo
+            .find_by_id(product_id)
+            .await?
+            .ok_or(InventoryError::ProductNotFound(command.product_id))?;
+
+        if !product.has_variants() {
+            return Err(InventoryError::VariantsNotEnabled);
+        }
+
+        let axes = normalize_axes(command.axes)?;
+        let combinations = cartesian_product(&axes);
+
+        // Resolve overrides up front so a typo fails the whole request
+        let mut overrides = HashMap::new();
+        for o in command.overrides {
+            let key = axes
+                .iter()
+                .map(|axis| o.attributes.get(&axis.name).map(|v| v.trim().to_string()))
+                .collect::<Option<Vec<String>>>()
+                .filter(|key| o.attributes.len() == axes.len() && combinations.contains(key))
+                .ok_or_else(|| {
+                    InventoryError::InvalidVariantAxes(
+                        "override attributes must match one generated combination".to_string(),
+                    )
+                })?;
+            overrides.insert(key, (o.price, o.cost_price));
+        }
+
+        let existing = self
+            .product_repo
+            .find_variants_by_product(product_id)
+            .await?;
+        let mut variant_index = self.product_repo.count_variants(product_id).await?;
+
+        let mut created = Vec::new();
+        let mut skipped = Vec::new();
+
+        for values in combinations {
+            let attributes = attributes_json(&axes, &values);
+            let name = values.join(" / ");
+
+            if let Some(dup) = existing
+                .iter()
+                .find(|v| same_attributes(v.variant_attributes(), &axes, &values))
+            {
+                skipped.push(SkippedVariantCombination {
+                    name,
+                    variant_attributes: attributes,
+                    existing_variant_id: dup.id().into_uuid(),
+                });
+                continue;
+            }
+
+            variant_index += 1;
+            let codes: Vec<&str> = axes
+                .iter()
+                .zip(&values)
+                .map(|(axis, value)| axis.codes[value].as_str())
+                .collect();
+            let mut sku = Sku::generate_variant_from_codes(product.sku(), &codes);
+            if sku.as_str().len() > MAX_SKU_LENGTH || existing.iter().any(|v| v.sku() == &sku) {
+                sku = Sku::generate_variant(product.sku(), variant_index);
+            }
+            let mut variant = ProductVariant::create_with_sku(product_id, sku, name);
+            variant.set_variant_attributes(attributes);
+            if let Some((price, cost_price)) = overrides.get(&values) {
+                variant.set_price(*price);
+                variant.set_cost_price(*cost_price);
+            }
+
+            self.product_repo.save_variant(&variant).await?;
+
+            created.push(VariantResponse {
+                id: variant.id().into_uuid(),
+                product_id: variant.product_id().into_uuid(),
+                sku: variant.sku().as_str().to_string(),
+                barcode: None,
+                barcode_generated: variant.barcode_generated(),
+                name: variant.name().to_string(),
+                variant_attributes: variant.variant_attributes().clone(),
+                price: variant.price(),
+                cost_price: variant.cost_price(),
+                effective_price: variant.effective_price(product.base_price()),
+                effective_cost: variant.effective_cost(product.cost_price()),
+                is_active: variant.is_active(),
+                created_at: variant.created_at(),
+                updated_at: variant.updated_at(),
+            });
+        }
+
+        Ok(GenerateVariantsResult { created, skipped })
+    }
+}
+
+/// Trims names/values, drops repeated values and rejects empty or duplicate
+/// axes. Checks the combination cap before anything is generated.
+fn normalize_axes(axes: Vec<VariantAxis>) -> Result<Vec<VariantAxis>, InventoryError> {
+    if axes.is_empty() {
+        return Err(InventoryError::InvalidVariantAxes(
+            "at least one axis is required".to_string(),
+        ));
+    }
+
+    let mut names = HashSet::new();
+    let mut normalized = Vec::with_capacity(axes.len());
+    let mut total: usize = 1;
+
+    for axis in axes {
+        let name = axis.name.trim().to_string();
+        if name.is_empty() {
+            return Err(InventoryError::InvalidVariantAxes(
+                "axis name cannot be empty".to_string(),
+            ));
+        }
+        if !names.insert(name.clone()) {
+            return Err(InventoryError::InvalidVariantAxes(format!(
+                "duplicate axis '{}'",
+                name
+            )));
+        }
+
+        let mut seen = HashSet::new();
+        let values: Vec<String> = axis
+            .values
+            .iter()
+            .map(|v| v.trim().to_string())
+            .filter(|v| !v.is_empty() && seen.insert(v.clone()))
+            .collect();
+        if values.is_empty() {
+            return Err(InventoryError::InvalidVariantAxes(format!(
+                "axis '{}' has no values",
+                name
+            )));
+        }
+
+        let codes = variant_codes(&name, &values, &axis.codes)?;
+
+        total = total.saturating_mul(values.len());
+        if total > MAX_GENERATED_VARIANTS {
+            return Err(InventoryError::TooManyVariantCombinations(
+                total,
+                MAX_GENERATED_VARIANTS,
+            ));
+        }
+
+        normalized.push(VariantAxis {
+            name,
+            values,
+            codes,
+        });
+    }
+
+    Ok(normalized)
+}
+
+/// SKU code of every value of an axis, keyed by value. Codes must be unique
+/// within the axis so generated SKUs don't collide.
+fn variant_codes(
+    axis: &str,
+    values: &[String],
+    custom: &HashMap<String, String>,
+) -> Result<HashMap<String, String>, InventoryError> {
+    let custom: HashMap<&str, &str> = custom
+        .iter()
+        .map(|(value, code)| (value.trim(), code.as_str()))
+        .collect();
+    if let Some(value) = custom.keys().find(|v| !values.iter().any(|x| x == *v)) {
+        return Err(InventoryError::InvalidVariantAxes(format!(
+            "code given for unknown value '{}' of axis '{}'",
+            value, axis
+        )));
+    }
+
+    let mut by_code: HashMap<String, &str> = HashMap::new();
+    let mut codes = HashMap::with_capacity(values.len());
+    for value in values {
+        let code: String = custom
+            .get(value.as_str())
+            .copied()
+            .unwrap_or(value)
+            .chars()
+            .filter(|c| c.is_ascii_alphanumeric())
+            .map(|c| c.to_ascii_uppercase())
+            .collect();
+        if code.is_empty() || code.len() > MAX_VARIANT_CODE_LENGTH {
+            return Err(InventoryError::InvalidVariantAxes(format!(
+                "value '{}' of axis '{}' needs a code of 1 to {} letters or digits",
+                value, axis, MAX_VARIANT_CODE_LENGTH
+            )));
+        }
+        if let Some(other) = by_code.insert(code.clone(), value) {
+            return Err(InventoryError::InvalidVariantAxes(format!(
+                "values '{}' and '{}' of axis '{}' share the code '{}'",
+                other, value, axis, code
+            )));
+        }
+        codes.insert(value.clone(), code);
+    }
+    Ok(codes)
+}
+
+/// All value combinations, first axis varying slowest
+fn cartesian_product(axes: &[VariantAxis]) -> Vec<Vec<String>> {
+    axes.iter().fold(vec![Vec::new()], |acc, axis| {
+        acc.iter()
+            .flat_map(|prefix| {
+                axis.values.iter().map(move |value| {
+                    let mut combo = prefix.clone();
+                    combo.push(value.clone());
+                    combo
+                })
+            })
+            .collect()
+    })
+}
+
+fn attributes_json(axes: &[VariantAxis], values: &[String]) -> JsonValue {
+    let map: Map<String, JsonValue> = axes
+        .iter()
+        .zip(values)
+        .map(|(axis, value)| (axis.name.clone(), JsonValue::String(value.clone())))
+        .collect();
+    JsonValue::Object(map)
+}
+
+/// True when an existing variant's attributes equal the combination. Values
+/// compare case-insensitively and non-string JSON (e.g. `{"size": 8}`) is
+/// compared by its text form.
+fn same_attributes(existing: &JsonValue, axes: &[VariantAxis], values: &[String]) -> bool {
+    let Some(map) = existing.as_object() else {
+        return false;
+    };
+    if map.len() != axes.len() {
+        return false;
+    }
+    axes.iter().zip(values).all(|(axis, value)| {
+        map.get(&axis.name).is_some_and(|v| {
+            let text = match v {
+                JsonValue::String(s) => s.trim().to_string(),
+                other => other.to_string(),
+            };
+            text.eq_ignore_ascii_case(value)
+        })
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use async_trait::async_trait;
+    use rust_decimal_macros::dec;
+    use std::sync::Mutex;
+    use uuid::Uuid;
+
+    use crate::application::dtos::commands::VariantCombinationOverride;
+    use crate::domain::entities::{Product, ProductVendor};
+    use crate::domain::value_objects::{
+        Barcode, CategoryId, Sku, UnitConversion, UnitOfMeasure, VariantId,
+    };
+
+    // Mock repository for testing
+    struct MockProductRepository {
+        products: Mutex<HashMap<ProductId, Product>>,
+        variants: Mutex<HashMap<VariantId, ProductVariant>>,
+        product_variants: Mutex<HashMap<ProductId, Vec<VariantId>>>,
+        barcodes: Mutex<HashMap<String, ProductId>>,
+        variant_barcodes: Mutex<HashMap<String, VariantId>>,
+    }
+
+    impl MockProductRepository {
+        fn new() -> Self {
+            Self {
+                products: Mutex::new(HashMap::new()),
+                variants: Mutex::new(HashMap::new()),
+                product_variants: Mutex::new(HashMap::new()),
+                barcodes: Mutex::new(HashMap::new()),
+                variant_barcodes: Mutex::new(HashMap::new()),
+            }
+        }
+    }
+
+    #[async_trait]
+    impl ProductRepository for MockProductRepository {
+        async fn save(&self, product: &Product) -> Result<(), InventoryError> {
+            let mut products = self.products.lock().unwrap();
+            products.insert(product.id(), product.clone());
+            if let Some(barcode) = product.barcode() {
+                let mut barcodes = self.barcodes.lock().unwrap();
+                barcodes.insert(barcode.as_str().to_string(), product.id());
+            }
+            Ok(())
+        }
+
+        async fn find_by_id(&self, id: ProductId) -> Result<Option<Product>, InventoryError> {
+            let products = self.products.lock().unwrap();
+            Ok(products.get(&id).cloned())
+        }
+
+        async fn find_by_sku(&self, _sku: &Sku) -> Result<Option<Product>, InventoryError> {
+            unimplemented!()
+        }
+
+        async fn find_by_barcode(
+            &self,
+            barcode: &Barcode,
+        ) -> Result<Option<Product>, InventoryError> {
+            let barcodes = self.barcodes.lock().unwrap();
+            let products = self.products.lock().unwrap();
+            Ok(barcodes
+                .get(barcode.as_str())
+                .and_then(|id| products.get(id).cloned()))
+        }
+
+        async fn update(&self, _product: &Product) -> Result<(), InventoryError> {
+            unimplemented!()
+        }
+
+        async fn delete(&self, _id: ProductId) -> Result<(), InventoryError> {
+            unimplemented!()
+        }
+
+        async fn find_active(&self) -> Result<Vec<Product>, InventoryError> {
+            unimplemented!()
+        }
+
+        async fn find_by_category(
+            &self,
+            _category_id: CategoryId,
+        ) -> Result<Vec<Product>, InventoryError> {
+            unimplemented!()
+        }
+
+        async fn save_variant(&self, variant: &ProductVariant) -> Result<(), InventoryError> {
+            let mut variants = self.variants.lock().unwrap();
+            let mut product_variants = self.product_variants.lock().unwrap();
+
+            variants.insert(variant.id(), variant.clone());
+            product_variants
+                .entry(variant.product_id())
+                .or_default()
+                .push(variant.id());
+
+            if let Some(barcode) = variant.barcode() {
+                let mut variant_barcodes = self.variant_barcodes.lock().unwrap();
+                variant_barcodes.insert(barcode.as_str().to_string(), variant.id());
+            }
+
+            Ok(())
+        }
+
+        async fn find_variant_by_id(
+            &self,
+            id: VariantId,
+        ) -> Result<Option<ProductVariant>, InventoryError> {
+            let variants = self.variants.lock().unwrap();
+            Ok(variants.get(&id).cloned())
+        }
+
+        async fn find_variant_by_sku(
+            &self,
+            _sku: &Sku,
+        ) -> Result<Option<ProductVariant>, InventoryError> {
+            unimplemented!()
+        }
+
+        async fn find_variant_by_barcode(
+            &self,
+            barcode: &Barcode,
+        ) -> Result<Option<ProductVariant>, InventoryError> {
+            let variant_barcodes = self.variant_barcodes.lock().unwrap();
+            let variants = self.variants.lock().unwrap();
+            Ok(variant_barcodes
+                .get(barcode.as_str())
+                .and_then(|id| variants.get(id).cloned()))
+        }
+
+        async fn find_variants_by_product(
+            &self,
+            product_id: ProductId,
+        ) -> Result<Vec<ProductVariant>, InventoryError> {
+            let product_variants = self.product_variants.lock().unwrap();
+            let variants = self.variants.lock().unwrap();
+
+            Ok(product_variants
+                .get(&product_id)
+                .map(|variant_ids| {
+                    variant_ids
+                        .iter()
+                        .filter_map(|id| variants.get(id).cloned())
+                        .collect()
+                })
+                .unwrap_or_default())
+        }
+
+        async fn update_variant(&self, _variant: &ProductVariant) -> Result<(), InventoryError> {
+            unimplemented!()
+        }
+
+        async fn delete_variant(&self, _id: VariantId) -> Result<(), InventoryError> {
+            unimplemented!()
+        }
+
+        async fn count_variants(&self, product_id: ProductId) -> Result<u32, InventoryError> {
+            let product_variants = self.product_variants.lock().unwrap();
+            Ok(product_variants
+                .get(&product_id)
+                .map(|v| v.len() as u32)
+                .unwrap_or(0))
+        }
+
+        async fn save_product_vendors(
+            &self,
+            _vendors: &[ProductVendor],
+        ) -> Result<(), InventoryError> {
+            unimplemented!()
+        }
+
+        async fn find_product_vendors(
+            &self,
+            _product_id: ProductId,
+        ) -> Result<Vec<ProductVendor>, InventoryError> {
+            unimplemented!()
+        }
+
+        async fn find_preferred_vendor(
+            &self,
+            _product_id: ProductId,
+        ) -> Result<Option<ProductVendor>, InventoryError> {
+            unimplemented!()
+        }
+
+        async fn find_paginated(
+            &self,
+            _category_id: Option<CategoryId>,
+            _is_active: Option<bool>,
+            _search: Option<&str>,
+            _after_id: Option<Uuid>,
+            _page: i64,
+            _page_size: i64,
+        ) -> Result<(Vec<Product>, i64), InventoryError> {
+            unimplemented!()
+        }
+
+        async fn count_filtered(
+            &self,
+            _category_id: Option<CategoryId>,
+            _is_active: Option<bool>,
+            _search: Option<&str>,
+        ) -> Result<i64, InventoryError> {
+            unimplemented!()
+        }
+
+        async fn search(
+            &self,
+            _term: &str,
+            _category_id: Option<CategoryId>,
+            _active_only: bool,
+            _page: i64,
+            _page_size: i64,
+        ) -> Result<(Vec<(Product, f64)>, i64), InventoryError> {
+            unimplemented!()
+        }
+
+        async fn replace_unit_conversions(
+            &self,
+            _product_id: ProductId,
+            _conversions: &[UnitConversion],
+        ) -> Result<(), InventoryError> {
+            unimplemented!()
+        }
+
+        async fn find_unit_conversions(
+            &self,
+            _product_id: ProductId,
+        ) -> Result<Vec<UnitConversion>, InventoryError> {
+            unimplemented!()
+        }
+    }
+
+    fn axis(name: &str, values: &[&str]) -> VariantAxis {
+        VariantAxis {
+            name: name.to_string(),
+            values: values.iter().map(|v| v.to_string()).collect(),
+            codes: HashMap::new(),
+        }
+    }
+
+    async fn setup() -> (Arc<MockProductRepository>, Product) {
+        let repo = Arc::new(MockProductRepository::new());
+        let mut product = Product::create("T-Shirt".to_string(), UnitOfMeasure::Unit, None);
+        product.set_has_variants(true);
+        product.set_base_price(dec!(20.00));
+        repo.save(&product).await.unwrap();
+        (repo, product)
+    }
+
+    #[tokio::test]
+    async fn test_generates_cartesian_product() {
+        let (repo, product) = setup().await;
+        let use_case = GenerateVariantsUseCase::new(repo.clone());
+
+        let result = use_case
+            .execute(GenerateVariantsCommand {
+                product_id: product.id().into_uuid(),
+                axes: vec![
+                    axis("size", &["S", "M", "L"]),
+                    axis("color", &["Red", "Blue"]),
+                ],
+                overrides: vec![VariantCombinationOverride {
+                    attributes: HashMap::from([
+                        ("size".to_string(), "L".to_string()),
+                        ("color".to_string(), "Blue".to_string()),
+                    ]),
+                    price: Some(dec!(24.00)),
+                    cost_price: None,
+                }],
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(result.created.len(), 6);
+        assert!(result.skipped.is_empty());
+        assert_eq!(result.created[0].name, "S / Red");
+        assert_eq!(
+            result.created[0].sku,
+            format!("{}-S-RED", product.sku().as_str())
+        );
+        assert_eq!(
+            result.created[5].sku,
+            format!("{}-L-BLUE", product.sku().as_str())
+        );
+        assert_eq!(result.created[5].name, "L / Blue");
+        assert_eq!(result.created[5].effective_price, dec!(24.00));
+        assert_eq!(result.created[0].effective_price, dec!(20.00));
+    }
+
+    #[tokio::test]
+    async fn test_skips_existing_combinations() {
+        let (repo, product) = setup().await;
+        let mut existing = ProductVariant::create(product.id(), product.sku(), 1, "M".to_string());
+        existing.set_variant_attributes(serde_json::json!({"size": "m"}));
+        repo.save_variant(&existing).await.unwrap();
+
+        let use_case = GenerateVariantsUseCase::new(repo.clone());
+        let result = use_case
+            .execute(GenerateVariantsCommand {
+                product_id: product.id().into_uuid(),
+                axes: vec![axis("size", &["S", "M", "L"])],
+                overrides: vec![],
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(result.created.len(), 2);
+        assert_eq!(result.skipped.len(), 1);
+        assert_eq!(
+            result.skipped[0].existing_variant_id,
+            existing.id().into_uuid()
+        );
+        assert!(result.created[0].sku.ends_with("-S"));
+    }
+
+    #[tokio::test]
+    async fn test_custom_codes_and_taken_skus() {
+        let (repo, product) = setup().await;
+        let mut existing = ProductVariant::create_with_sku(
+            product.id(),
+            Sku::generate_variant_from_codes(product.sku(), &["S"]),
+            "Small".to_string(),
+        );
+        existing.set_variant_attributes(serde_json::json!({"size": "Small"}));
+        repo.save_variant(&existing).await.unwrap();
+
+        let mut size = axis("size", &["S", "Extra Large"]);
+        size.codes = HashMap::from([("Extra Large".to_string(), "xl".to_string())]);
+
+        let use_case = GenerateVariantsUseCase::new(repo.clone());
+        let result = use_case
+            .execute(GenerateVariantsCommand {
+                product_id: product.id().into_uuid(),
+                axes: vec![size],
+                overrides: vec![],
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(result.created.len(), 2);
+        assert!(result.created[0].sku.ends_with("-V002"));
+        assert_eq!(
+            result.created[1].sku,
+            format!("{}-XL", product.sku().as_str())
+        );
+    }
+
+    #[tokio::test]
+    async fn test_falls_back_to_indexed_sku_when_too_long() {
+        let (repo, product) = setup().await;
+        let use_case = GenerateVariantsUseCase::new(repo);
+
+        let result = use_case
+            .execute(GenerateVariantsCommand {
+                product_id: product.id().into_uuid(),
+                axes: ["a", "b", "c", "d"]
+                    .iter()
+                    .map(|name| axis(name, &["ABCDEFGH"]))
+                    .collect(),
+                overrides: vec![],
+            })
+            .await
+            .unwrap();
+
+        assert!(result.created[0].sku.ends_with("-V001"));
+        assert!(result.created[0].sku.len() <= MAX_SKU_LENGTH);
+    }
+
+    #[tokio::test]
+    async fn test_rejects_too_many_combinations() {
+        let (repo, product) = setup().await;
+        let use_case = GenerateVariantsUseCase::new(repo);
+
+        let many: Vec<String> = (0..11).map(|i| i.to_string()).collect();
+        let many: Vec<&str> = many.iter().map(|s| s.as_str()).collect();
+        let result = use_case
+            .execute(GenerateVariantsCommand {
+                product_id: product.id().into_uuid(),
+                axes: vec![axis("a", &many), axis("b", &many)],
+                overrides: vec![],
+            })
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(InventoryError::TooManyVariantCombinations(
+                121,
+                MAX_GENERATED_VARIANTS
+            ))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_rejects_invalid_axes() {
+        let (repo, product) = setup().await;
+        let use_case = GenerateVariantsUseCase::new(repo);
+
+        for axes in [
+            vec![],
+            vec![axis("size", &[" "])],
+            vec![axis("size", &["S"]), axis("size", &["M"])],
+            vec![axis("color", &["Navy Blue", "navy-blue"])],
+            vec![axis("size", &["Extra Extra Large"])],
+        ] {
+            let result = use_case
+                .execute(GenerateVariantsCommand {
+                    product_id: product.id().into_uuid(),
+                    axes,
+                    overrides: vec![],
+                })
+                .await;
+            assert!(matches!(result, Err(InventoryError::InvalidVariantAxes(_))));
+        }
+    }
+}
@@ -2,6 +2,8 @@
 
 use std::sync::Arc;
 
+use identity::UserContext;
+
 use crate::InventoryError;
 use crate::application::dtos::responses::{AdjustmentDetailResponse, AdjustmentItemResponse};
 use crate::domain::repositories::AdjustmentRepository;
@@ -9,7 +11,8 @@ use crate::domain::value_objects::AdjustmentId;
 
 /// Use case for getting an adjustment by ID with full details
 ///
-/// Retrieves the adjustment and all its items.
+/// Retrieves the adjustment and all its items. Adjustments of stores the
+/// caller is not assigned to are scoped out and reported as not found.
 pub struct GetAdjustmentUseCase<A>
 where
     A: AdjustmentRepository,
@@ -30,15 +33,18 @@ where
     ///
     /// # Arguments
     /// * `adjustment_id` - The UUID of the adjustment to retrieve
+    /// * `ctx` - The caller, who must be assigned to the adjustment's store
     ///
     /// # Returns
     /// AdjustmentDetailResponse with full adjustment details including items
     ///
     /// # Errors
     /// * `InventoryError::AdjustmentNotFound` - If the adjustment doesn't exist
+    ///   or belongs to a store the caller is not assigned to
     pub async fn execute(
         &self,
         adjustment_id: uuid::Uuid,
+        ctx: &UserContext,
     ) -> Result<AdjustmentDetailResponse, InventoryError> {
         let adjustment_id_vo = AdjustmentId::from_uuid(adjustment_id);
 
@@ -47,6 +53,7 @@ where
             .adjustment_repo
             .find_by_id_with_items(adjustment_id_vo)
             .await?
+            .filter(|a| ctx.can_access_store(*a.store_id().as_uuid()))
             .ok_or(InventoryError::AdjustmentNotFound(adjustment_id))?;
 
         // Build item responses
@@ -77,6 +84,7 @@ where
             created_by_id: adjustment.created_by_id().into_uuid(),
             approved_by_id: adjustment.approved_by_id().map(|id| id.into_uuid()),
             approved_at: adjustment.approved_at(),
+            auto_approved: adjustment.auto_approved(),
             applied_at: adjustment.applied_at(),
             notes: adjustment.notes().map(|s| s.to_string()),
             attachments: Some(adjustment.attachments().clone()),
@@ -86,3 +94,137 @@ where
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use async_trait::async_trait;
+    use uuid::Uuid;
+
+    use crate::domain::entities::StockAdjustment;
+    use crate::domain::value_objects::{AdjustmentReason, AdjustmentType};
+    use identity::{StoreId, UserId};
+
+    struct MockAdjustmentRepository {
+        adjustment: StockAdjustment,
+    }
+
+    #[async_trait]
+    impl AdjustmentRepository for MockAdjustmentRepository {
+        async fn save(&self, _adjustment: &StockAdjustment) -> Result<(), InventoryError> {
+            unimplemented!()
+        }
+
+        async fn find_by_id(
+            &self,
+            _id: AdjustmentId,
+        ) -> Result<Option<StockAdjustment>, InventoryError> {
+            unimplemented!()
+        }
+
+        async fn find_by_id_with_items(
+            &self,
+            id: AdjustmentId,
+        ) -> Result<Option<StockAdjustment>, InventoryError> {
+            Ok(Some(self.adjustment.clone()).filter(|a| a.id() == id))
+        }
+
+        async fn find_by_store(
+            &self,
+            _store_id: StoreId,
+        ) -> Result<Vec<StockAdjustment>, InventoryError> {
+            unimplemented!()
+        }
+
+        async fn update(&self, _adjustment: &StockAdjustment) -> Result<(), InventoryError> {
+            unimplemented!()
+        }
+
+        async fn generate_adjustment_number(
+            &self,
+            _store_id: StoreId,
+        ) -> Result<String, InventoryError> {
+            unimplemented!()
+        }
+
+        async fn find_paginated(
+            &self,
+            _store_ids: Option<&[StoreId]>,
+            _status: Option<&str>,
+            _after_id: Option<Uuid>,
+            _page: i64,
+            _page_size: i64,
+        ) -> Result<(Vec<StockAdjustment>, i64), InventoryError> {
+            unimplemented!()
+        }
+    }
+
+    /// A use case holding one adjustment, with the adjustment's id and store
+    fn setup() -> (GetAdjustmentUseCase<MockAdjustmentRepository>, Uuid, Uuid) {
+        let adjustment = StockAdjustment::create(
+            StoreId::new(),
+            "ADJ-TEST-00001".to_string(),
+            AdjustmentType::Decrease,
+            AdjustmentReason::Damage,
+            UserId::new(),
+        );
+        let (id, store_id) = (
+            adjustment.id().into_uuid(),
+            *adjustment.store_id().as_uuid(),
+        );
+        let use_case = GetAdjustmentUseCase::new(Arc::new(MockAdjustmentRepository { adjustment }));
+        (use_case, id, store_id)
+    }
+
+    fn context(stores: Vec<Uuid>, is_super_admin: bool) -> UserContext {
+        UserContext::new(
+            UserId::new(),
+            StoreId::new(),
+            Default::default(),
+            stores,
+            is_super_admin,
+            None,
+            None,
+        )
+    }
+
+    #[tokio::test]
+    async fn test_assigned_user_gets_adjustment() {
+        let (use_case, id, store_id) = setup();
+
+        let response = use_case
+            .execute(id, &context(vec![store_id], false))
+            .await
+            .unwrap();
+        assert_eq!(response.id, id);
+    }
+
+    #[tokio::test]
+    async fn test_adjustment_of_another_store_is_not_found() {
+        let (use_case, id, _) = setup();
+
+        let result = use_case
+            .execute(id, &context(vec![*StoreId::new().as_uuid()], false))
+            .await;
+        assert!(matches!(
+            result,
+            Err(InventoryError::AdjustmentNotFound(not_found)) if not_found == id
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_user_without_stores_gets_not_found() {
+        let (use_case, id, _) = setup();
+
+        let result = use_case.execute(id, &context(vec![], false)).await;
+        assert!(matches!(result, Err(InventoryError::AdjustmentNotFound(_))));
+    }
+
+    #[tokio::test]
+    async fn test_super_admin_gets_adjustment_of_any_store() {
+        let (use_case, id, _) = setup();
+
+        let response = use_case.execute(id, &context(vec![], true)).await.unwrap();
+        assert_eq!(response.id, id);
+    }
+}
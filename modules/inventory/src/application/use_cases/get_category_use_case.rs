@@ -40,6 +40,8 @@ where
             icon: category.icon().map(|s| s.to_string()),
             sort_order: category.sort_order(),
             is_active: category.is_active(),
+            tax_category: category.tax_category().map(|t| t.to_string()),
+            costing_method: category.costing_method().to_string(),
             created_at: category.created_at(),
             updated_at: category.updated_at(),
         })
@@ -50,7 +52,9 @@ where
 mod tests {
     use super::*;
     use crate::domain::entities::ProductCategory;
+    use crate::domain::repositories::CategoryStockValuation;
     use async_trait::async_trait;
+    use identity::StoreId;
     use std::collections::HashMap;
     use std::sync::Mutex;
 
@@ -101,6 +105,12 @@ mod tests {
         async fn update(&self, _category: &ProductCategory) -> Result<(), InventoryError> {
             Ok(())
         }
+        async fn stock_valuation_by_category(
+            &self,
+            _store_id: Option<StoreId>,
+        ) -> Result<Vec<CategoryStockValuation>, InventoryError> {
+            Ok(vec![])
+        }
         async fn delete(&self, _id: CategoryId) -> Result<(), InventoryError> {
             Ok(())
         }
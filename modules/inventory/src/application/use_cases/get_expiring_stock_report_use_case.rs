@@ -0,0 +1,413 @@
+// GetExpiringStockReportUseCase - lists lots expiring within a number of days
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use chrono::Utc;
+use rust_decimal::Decimal;
+use uuid::Uuid;
+
+use crate::InventoryError;
+use crate::application::dtos::responses::{ExpiringLotResponse, ExpiringStockReportResponse};
+use crate::domain::repositories::{InventoryLotRepository, ProductRepository};
+use crate::domain::value_objects::{ProductId, VariantId};
+
+/// Days ahead the expiring stock report looks by default
+pub const DEFAULT_EXPIRY_WINDOW_DAYS: i64 = 30;
+
+/// Query parameters for expiring stock report
+#[derive(Debug, Clone)]
+pub struct ExpiringStockReportQuery {
+    /// Filter by store ID (optional - if None, includes all stores)
+    pub store_id: Option<Uuid>,
+    /// Include lots expiring within this many days from today; lots already
+    /// expired but still in stock are always included
+    pub days: i64,
+}
+
+impl Default for ExpiringStockReportQuery {
+    fn default() -> Self {
+        Self {
+            store_id: None,
+            days: DEFAULT_EXPIRY_WINDOW_DAYS,
+        }
+    }
+}
+
+/// Use case for listing lots that expire soon, companion of the low stock
+/// report for perishable goods. Depleted lots are left out.
+pub struct GetExpiringStockReportUseCase<L, P>
+where
+    L: InventoryLotRepository,
+    P: ProductRepository,
+{
+    lot_repo: Arc<L>,
+    product_repo: Arc<P>,
+}
+
+impl<L, P> GetExpiringStockReportUseCase<L, P>
+where
+    L: InventoryLotRepository,
+    P: ProductRepository,
+{
+    pub fn new(lot_repo: Arc<L>, product_repo: Arc<P>) -> Self {
+        Self {
+            lot_repo,
+            product_repo,
+        }
+    }
+
+    /// Executes the use case to generate the expiring stock report
+    ///
+    /// # Arguments
+    /// * `query` - Optional store filter and the window in days
+    ///
+    /// # Returns
+    /// ExpiringStockReportResponse with lots sorted by expiry date
+    ///
+    /// # Errors
+    /// * `InventoryError::InvalidOperation` - If `days` is negative
+    pub async fn execute(
+        &self,
+        query: ExpiringStockReportQuery,
+    ) -> Result<ExpiringStockReportResponse, InventoryError> {
+        if query.days < 0 {
+            return Err(InventoryError::InvalidOperation(
+                "days must be zero or positive".to_string(),
+            ));
+        }
+
+        let today = Utc::now().date_naive();
+        let until = today + chrono::Duration::days(query.days);
+        let lots = self
+            .lot_repo
+            .find_expiring(query.store_id.map(Into::into), until)
+            .await?;
+
+        let mut product_names: HashMap<ProductId, (Option<String>, Option<String>)> =
+            HashMap::new();
+        let mut variant_names: HashMap<VariantId, (Option<String>, Option<String>)> =
+            HashMap::new();
+
+        let mut items = Vec::with_capacity(lots.len());
+        for lot in lots.into_iter().filter(|l| !l.is_depleted()) {
+            let (product_name, mut sku) = match product_names.get(&lot.product_id()) {
+                Some(info) => info.clone(),
+                None => {
+                    let info = self
+                        .product_repo
+                        .find_by_id(lot.product_id())
+                        .await?
+                        .map(|p| (Some(p.name().to_string()), Some(p.sku().to_string())))
+                        .unwrap_or_default();
+                    product_names.insert(lot.product_id(), info.clone());
+                    info
+                }
+            };
+            let mut variant_name = None;
+            if let Some(variant_id) = lot.variant_id() {
+                let (name, variant_sku) = match variant_names.get(&variant_id) {
+                    Some(info) => info.clone(),
+                    None => {
+                        let info = self
+                            .product_repo
+                            .find_variant_by_id(variant_id)
+                            .await?
+                            .map(|v| (Some(v.name().to_string()), Some(v.sku().to_string())))
+                            .unwrap_or_default();
+                        variant_names.insert(variant_id, info.clone());
+                        info
+                    }
+                };
+                variant_name = name;
+                sku = variant_sku.or(sku);
+            }
+
+            let days_until_expiry = lot.days_until_expiry(today).unwrap_or_default();
+            items.push(ExpiringLotResponse {
+                lot_id: lot.id().into_uuid(),
+                store_id: lot.store_id().into_uuid(),
+                product_id: lot.product_id().into_uuid(),
+                variant_id: lot.variant_id().map(|v| v.into_uuid()),
+                product_name,
+                variant_name,
+                sku,
+                lot_number: lot.lot_number().map(|s| s.to_string()),
+                expiry_date: lot.expiry_date(),
+                days_until_expiry,
+                is_expired: days_until_expiry < 0,
+                remaining_quantity: lot.remaining_quantity(),
+            });
+        }
+
+        items.sort_by_key(|i| (i.expiry_date, i.store_id));
+        let total_quantity = items.iter().map(|i| i.remaining_quantity).sum::<Decimal>();
+
+        Ok(ExpiringStockReportResponse {
+            days: query.days,
+            total_items: items.len() as i64,
+            total_quantity,
+            items,
+            generated_at: Utc::now(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use async_trait::async_trait;
+    use chrono::NaiveDate;
+    use rust_decimal_macros::dec;
+    use std::sync::Mutex;
+
+    use crate::domain::entities::{InventoryLot, Product, ProductVariant, ProductVendor};
+    use crate::domain::value_objects::{Barcode, CategoryId, Sku, UnitConversion};
+    use identity::StoreId;
+
+    struct MockLotRepository {
+        lots: Mutex<Vec<InventoryLot>>,
+    }
+
+    #[async_trait]
+    impl InventoryLotRepository for MockLotRepository {
+        async fn receive_batch(&self, lots: &[InventoryLot]) -> Result<(), InventoryError> {
+            self.lots.lock().unwrap().extend(lots.iter().cloned());
+            Ok(())
+        }
+
+        async fn find_expiring(
+            &self,
+            store_id: Option<StoreId>,
+            until: NaiveDate,
+        ) -> Result<Vec<InventoryLot>, InventoryError> {
+            let lots = self.lots.lock().unwrap();
+            Ok(lots
+                .iter()
+                .filter(|l| store_id.is_none_or(|s| l.store_id() == s))
+                .filter(|l| l.expiry_date().is_some_and(|d| d <= until))
+                .cloned()
+                .collect())
+        }
+    }
+
+    // Mock Product Repository (no product info)
+    struct MockProductRepository;
+
+    #[async_trait]
+    impl ProductRepository for MockProductRepository {
+        async fn save(&self, _product: &Product) -> Result<(), InventoryError> {
+            unimplemented!()
+        }
+
+        async fn find_by_id(&self, _id: ProductId) -> Result<Option<Product>, InventoryError> {
+            Ok(None) // No product info for simplicity
+        }
+
+        async fn find_by_sku(&self, _sku: &Sku) -> Result<Option<Product>, InventoryError> {
+            unimplemented!()
+        }
+
+        async fn find_by_category(
+            &self,
+            _category_id: CategoryId,
+        ) -> Result<Vec<Product>, InventoryError> {
+            unimplemented!()
+        }
+
+        async fn update(&self, _product: &Product) -> Result<(), InventoryError> {
+            unimplemented!()
+        }
+
+        async fn delete(&self, _id: ProductId) -> Result<(), InventoryError> {
+            unimplemented!()
+        }
+
+        async fn find_paginated(
+            &self,
+            _category_id: Option<CategoryId>,
+            _is_active: Option<bool>,
+            _search: Option<&str>,
+            _after_id: Option<Uuid>,
+            _page: i64,
+            _page_size: i64,
+        ) -> Result<(Vec<Product>, i64), InventoryError> {
+            unimplemented!()
+        }
+
+        async fn save_variant(&self, _variant: &ProductVariant) -> Result<(), InventoryError> {
+            unimplemented!()
+        }
+
+        async fn find_variant_by_id(
+            &self,
+            _id: VariantId,
+        ) -> Result<Option<ProductVariant>, InventoryError> {
+            Ok(None) // No variant info for simplicity
+        }
+
+        async fn find_variant_by_sku(
+            &self,
+            _sku: &Sku,
+        ) -> Result<Option<ProductVariant>, InventoryError> {
+            unimplemented!()
+        }
+
+        async fn find_variants_by_product(
+            &self,
+            _product_id: ProductId,
+        ) -> Result<Vec<ProductVariant>, InventoryError> {
+            unimplemented!()
+        }
+
+        async fn update_variant(&self, _variant: &ProductVariant) -> Result<(), InventoryError> {
+            unimplemented!()
+        }
+
+        async fn delete_variant(&self, _id: VariantId) -> Result<(), InventoryError> {
+            unimplemented!()
+        }
+
+        async fn find_by_barcode(
+            &self,
+            _barcode: &Barcode,
+        ) -> Result<Option<Product>, InventoryError> {
+            unimplemented!()
+        }
+
+        async fn find_active(&self) -> Result<Vec<Product>, InventoryError> {
+            unimplemented!()
+        }
+
+        async fn count_filtered(
+            &self,
+            _category_id: Option<CategoryId>,
+            _is_active: Option<bool>,
+            _search: Option<&str>,
+        ) -> Result<i64, InventoryError> {
+            unimplemented!()
+        }
+
+        async fn search(
+            &self,
+            _term: &str,
+            _category_id: Option<CategoryId>,
+            _active_only: bool,
+            _page: i64,
+            _page_size: i64,
+        ) -> Result<(Vec<(Product, f64)>, i64), InventoryError> {
+            unimplemented!()
+        }
+
+        async fn find_variant_by_barcode(
+            &self,
+            _barcode: &Barcode,
+        ) -> Result<Option<ProductVariant>, InventoryError> {
+            unimplemented!()
+        }
+
+        async fn count_variants(&self, _product_id: ProductId) -> Result<u32, InventoryError> {
+            unimplemented!()
+        }
+
+        async fn save_product_vendors(
+            &self,
+            _vendors: &[ProductVendor],
+        ) -> Result<(), InventoryError> {
+            unimplemented!()
+        }
+
+        async fn find_product_vendors(
+            &self,
+            _product_id: ProductId,
+        ) -> Result<Vec<ProductVendor>, InventoryError> {
+            unimplemented!()
+        }
+
+        async fn find_preferred_vendor(
+            &self,
+            _product_id: ProductId,
+        ) -> Result<Option<ProductVendor>, InventoryError> {
+            unimplemented!()
+        }
+
+        async fn replace_unit_conversions(
+            &self,
+            _product_id: ProductId,
+            _conversions: &[UnitConversion],
+        ) -> Result<(), InventoryError> {
+            unimplemented!()
+        }
+
+        async fn find_unit_conversions(
+            &self,
+            _product_id: ProductId,
+        ) -> Result<Vec<UnitConversion>, InventoryError> {
+            unimplemented!()
+        }
+    }
+
+    fn lot(store_id: StoreId, expires_in_days: i64, quantity: Decimal) -> InventoryLot {
+        let expiry = Utc::now().date_naive() + chrono::Duration::days(expires_in_days);
+        InventoryLot::receive(
+            ProductId::new(),
+            None,
+            store_id,
+            Some("LOT-1"),
+            Some(expiry),
+            quantity,
+        )
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_lists_lots_within_window_by_expiry() {
+        let store_id = StoreId::new();
+        let mut depleted = lot(store_id, 1, dec!(2));
+        depleted.consume(dec!(2));
+        let lot_repo = Arc::new(MockLotRepository {
+            lots: Mutex::new(vec![
+                lot(store_id, 20, dec!(4)),
+                lot(store_id, 45, dec!(8)),
+                lot(store_id, -2, dec!(1)),
+                lot(StoreId::new(), 3, dec!(6)),
+                depleted,
+            ]),
+        });
+        let use_case =
+            GetExpiringStockReportUseCase::new(lot_repo, Arc::new(MockProductRepository));
+
+        let report = use_case
+            .execute(ExpiringStockReportQuery {
+                store_id: Some(store_id.into_uuid()),
+                days: 30,
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(report.total_items, 2);
+        assert_eq!(report.total_quantity, dec!(5));
+        assert!(report.items[0].is_expired);
+        assert_eq!(report.items[0].days_until_expiry, -2);
+        assert_eq!(report.items[1].days_until_expiry, 20);
+        assert!(!report.items[1].is_expired);
+    }
+
+    #[tokio::test]
+    async fn test_rejects_negative_days() {
+        let lot_repo = Arc::new(MockLotRepository {
+            lots: Mutex::new(Vec::new()),
+        });
+        let use_case =
+            GetExpiringStockReportUseCase::new(lot_repo, Arc::new(MockProductRepository));
+
+        let result = use_case
+            .execute(ExpiringStockReportQuery {
+                store_id: None,
+                days: -1,
+            })
+            .await;
+
+        assert!(matches!(result, Err(InventoryError::InvalidOperation(_))));
+    }
+}
@@ -0,0 +1,263 @@
+// GetInTransitReportUseCase - lists shipped transfers not yet received, by destination
+
+use std::cmp::Reverse;
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use uuid::Uuid;
+
+use super::get_in_transit_stock_use_case::in_transit_valuation;
+use crate::InventoryError;
+use crate::application::dtos::responses::{
+    InTransitDestinationResponse, InTransitReportResponse, InTransitTransferResponse,
+};
+use crate::domain::entities::StockTransfer;
+use crate::domain::repositories::TransferRepository;
+
+/// Days a transfer may stay in transit before the report flags it
+pub const DEFAULT_TRANSIT_OVERDUE_DAYS: i64 = 7;
+
+/// Query parameters for the in-transit transfers report
+#[derive(Debug, Clone)]
+pub struct InTransitReportQuery {
+    /// Only transfers from or to this store (all if None)
+    pub store_id: Option<Uuid>,
+    /// Transfers shipped more than this many days ago are flagged overdue
+    pub overdue_after_days: i64,
+}
+
+impl Default for InTransitReportQuery {
+    fn default() -> Self {
+        Self {
+            store_id: None,
+            overdue_after_days: DEFAULT_TRANSIT_OVERDUE_DAYS,
+        }
+    }
+}
+
+/// Summarizes one shipped transfer.
+///
+/// Its lines are valued the same way as in the item-level in-transit stock
+/// report, so both reports agree on the value of a transfer.
+pub(crate) fn in_transit_transfer(
+    transfer: &StockTransfer,
+    now: DateTime<Utc>,
+    overdue_after_days: i64,
+) -> InTransitTransferResponse {
+    let mut total_quantity = Decimal::ZERO;
+    let mut total_value = Decimal::ZERO;
+    for item in transfer.items() {
+        let (quantity, unit_cost) = in_transit_valuation(item);
+        total_quantity += quantity;
+        total_value += quantity * unit_cost;
+    }
+
+    let days_in_transit = transfer
+        .shipped_date()
+        .map(|shipped| (now - shipped).num_days().max(0))
+        .unwrap_or(0);
+
+    InTransitTransferResponse {
+        transfer_id: transfer.id().into_uuid(),
+        transfer_number: transfer.transfer_number().to_string(),
+        from_store_id: *transfer.from_store_id().as_uuid(),
+        to_store_id: *transfer.to_store_id().as_uuid(),
+        shipped_date: transfer.shipped_date(),
+        days_in_transit,
+        is_overdue: days_in_transit > overdue_after_days,
+        shipping_method: transfer.shipping_method().map(str::to_string),
+        tracking_number: transfer.tracking_number().map(str::to_string),
+        total_items: transfer.items().len() as i64,
+        total_quantity,
+        total_value,
+    }
+}
+
+/// Groups transfers by destination store, longest in transit first
+pub(crate) fn group_by_destination(
+    transfers: Vec<InTransitTransferResponse>,
+) -> Vec<InTransitDestinationResponse> {
+    let mut destinations: BTreeMap<Uuid, InTransitDestinationResponse> = BTreeMap::new();
+    for transfer in transfers {
+        let destination = destinations.entry(transfer.to_store_id).or_insert_with(|| {
+            InTransitDestinationResponse {
+                to_store_id: transfer.to_store_id,
+                transfers: Vec::new(),
+                total_transfers: 0,
+                overdue_transfers: 0,
+                total_value: Decimal::ZERO,
+            }
+        });
+        destination.total_transfers += 1;
+        if transfer.is_overdue {
+            destination.overdue_transfers += 1;
+        }
+        destination.total_value += transfer.total_value;
+        destination.transfers.push(transfer);
+    }
+
+    let mut destinations: Vec<InTransitDestinationResponse> = destinations.into_values().collect();
+    for destination in &mut destinations {
+        destination
+            .transfers
+            .sort_by_key(|t| Reverse(t.days_in_transit));
+    }
+    destinations
+}
+
+/// Use case for reporting the transfers currently on their way between
+/// stores, one row per transfer rather than per item (see
+/// GetInTransitStockUseCase for the item-level view).
+///
+/// Each transfer carries how many days it has been in transit, so the ones
+/// stuck too long can be chased.
+pub struct GetInTransitReportUseCase<T>
+where
+    T: TransferRepository,
+{
+    transfer_repo: Arc<T>,
+}
+
+impl<T> GetInTransitReportUseCase<T>
+where
+    T: TransferRepository,
+{
+    pub fn new(transfer_repo: Arc<T>) -> Self {
+        Self { transfer_repo }
+    }
+
+    /// Executes the use case
+    ///
+    /// # Errors
+    /// * `InventoryError::InvalidOperation` - If `overdue_after_days` is negative
+    pub async fn execute(
+        &self,
+        query: InTransitReportQuery,
+    ) -> Result<InTransitReportResponse, InventoryError> {
+        if query.overdue_after_days < 0 {
+            return Err(InventoryError::InvalidOperation(
+                "overdue_after_days must be zero or positive".to_string(),
+            ));
+        }
+
+        let now = Utc::now();
+        let mut transfers = Vec::new();
+        for transfer in self.transfer_repo.find_in_transit().await? {
+            if let Some(store_id) = query.store_id
+                && *transfer.from_store_id().as_uuid() != store_id
+                && *transfer.to_store_id().as_uuid() != store_id
+            {
+                continue;
+            }
+            transfers.push(in_transit_transfer(
+                &transfer,
+                now,
+                query.overdue_after_days,
+            ));
+        }
+
+        let destinations = group_by_destination(transfers);
+
+        Ok(InTransitReportResponse {
+            total_transfers: destinations.iter().map(|d| d.total_transfers).sum(),
+            overdue_transfers: destinations.iter().map(|d| d.overdue_transfers).sum(),
+            total_value: destinations.iter().map(|d| d.total_value).sum(),
+            overdue_after_days: query.overdue_after_days,
+            destinations,
+            generated_at: now,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Duration;
+    use rust_decimal_macros::dec;
+
+    use crate::application::use_cases::get_in_transit_stock_use_case::in_transit_items;
+    use crate::domain::entities::TransferItem;
+    use crate::domain::value_objects::ProductId;
+    use identity::{StoreId, UserId};
+
+    fn shipped_transfer(to: StoreId, quantity: Decimal) -> StockTransfer {
+        let mut transfer =
+            StockTransfer::create("TRF-1".to_string(), StoreId::new(), to, UserId::new()).unwrap();
+        let item = TransferItem::create_for_product(
+            transfer.id(),
+            ProductId::new(),
+            quantity,
+            Some(dec!(4)),
+        )
+        .unwrap();
+        transfer.add_item(item).unwrap();
+        transfer.submit().unwrap();
+        transfer.ship(UserId::new()).unwrap();
+        transfer
+    }
+
+    #[test]
+    fn test_values_lines_at_their_unit_cost() {
+        let transfer = shipped_transfer(StoreId::new(), dec!(10));
+
+        let line = in_transit_transfer(&transfer, Utc::now(), 7);
+
+        assert_eq!(line.total_quantity, dec!(10));
+        assert_eq!(line.total_value, dec!(40));
+    }
+
+    #[test]
+    fn test_agrees_with_item_level_report() {
+        let transfers = vec![
+            shipped_transfer(StoreId::new(), dec!(10)),
+            shipped_transfer(StoreId::new(), dec!(3)),
+        ];
+
+        let items = in_transit_items(&transfers, &[]);
+        let lines: Vec<InTransitTransferResponse> = transfers
+            .iter()
+            .map(|t| in_transit_transfer(t, Utc::now(), 7))
+            .collect();
+
+        let item_value: Decimal = items.iter().map(|i| i.total_value).sum();
+        let transfer_value: Decimal = lines.iter().map(|l| l.total_value).sum();
+        assert_eq!(item_value, dec!(52));
+        assert_eq!(transfer_value, item_value);
+    }
+
+    #[test]
+    fn test_flags_transfers_past_the_threshold() {
+        let transfer = shipped_transfer(StoreId::new(), dec!(1));
+        let now = transfer.shipped_date().unwrap() + Duration::days(8);
+
+        let line = in_transit_transfer(&transfer, now, 7);
+        assert_eq!(line.days_in_transit, 8);
+        assert!(line.is_overdue);
+
+        let line = in_transit_transfer(&transfer, now, 8);
+        assert!(!line.is_overdue);
+    }
+
+    #[test]
+    fn test_groups_by_destination() {
+        let (a, b) = (StoreId::new(), StoreId::new());
+        let now = Utc::now();
+        let lines = vec![
+            in_transit_transfer(&shipped_transfer(a, dec!(1)), now, 7),
+            in_transit_transfer(&shipped_transfer(b, dec!(2)), now, 7),
+            in_transit_transfer(&shipped_transfer(a, dec!(3)), now, 7),
+        ];
+
+        let destinations = group_by_destination(lines);
+
+        assert_eq!(destinations.len(), 2);
+        let to_a = destinations
+            .iter()
+            .find(|d| d.to_store_id == *a.as_uuid())
+            .unwrap();
+        assert_eq!(to_a.total_transfers, 2);
+        assert_eq!(to_a.total_value, dec!(16));
+    }
+}
@@ -0,0 +1,282 @@
+// GetInTransitStockUseCase - reports stock shipped on transfers but not yet received
+
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use chrono::Utc;
+use rust_decimal::Decimal;
+use uuid::Uuid;
+
+use crate::InventoryError;
+use crate::application::dtos::responses::{
+    InTransitItemResponse, InTransitStockResponse, InTransitStoreTotalResponse,
+};
+use crate::domain::entities::{StockTransfer, TransferItem};
+use crate::domain::repositories::TransferRepository;
+use identity::StoreSettings;
+
+/// Quantity of a transfer line in transit and the unit cost it is valued at.
+///
+/// The quantity is what was shipped (what was requested if the line has no
+/// shipped quantity). The unit cost is the line's, which is also the cost the
+/// transfer-out movement records when the transfer ships; lines without a
+/// cost are valued at zero.
+pub(crate) fn in_transit_valuation(item: &TransferItem) -> (Decimal, Decimal) {
+    let quantity = item.quantity_shipped().unwrap_or(item.quantity_requested());
+    (quantity, item.unit_cost().unwrap_or(Decimal::ZERO))
+}
+
+/// Builds the in-transit lines of shipped transfers.
+///
+/// Each line is attributed to the source store, or to the destination when
+/// the source store's settings say transit stock belongs to the receiver.
+/// Stores missing from `settings` use the defaults (source owns).
+pub(crate) fn in_transit_items(
+    transfers: &[StockTransfer],
+    settings: &[StoreSettings],
+) -> Vec<InTransitItemResponse> {
+    let mut items = Vec::new();
+    for transfer in transfers {
+        let by_destination = settings
+            .iter()
+            .find(|s| s.store_id() == transfer.from_store_id())
+            .is_some_and(|s| s.transit_owned_by_destination());
+        let owner = if by_destination {
+            transfer.to_store_id()
+        } else {
+            transfer.from_store_id()
+        };
+
+        for item in transfer.items() {
+            let (quantity, unit_cost) = in_transit_valuation(item);
+            if quantity <= Decimal::ZERO {
+                continue;
+            }
+            items.push(InTransitItemResponse {
+                transfer_id: transfer.id().into_uuid(),
+                transfer_number: transfer.transfer_number().to_string(),
+                from_store_id: *transfer.from_store_id().as_uuid(),
+                to_store_id: *transfer.to_store_id().as_uuid(),
+                owner_store_id: *owner.as_uuid(),
+                product_id: item.product_id().map(|id| id.into_uuid()),
+                variant_id: item.variant_id().map(|id| id.into_uuid()),
+                quantity,
+                unit_cost,
+                total_value: quantity * unit_cost,
+                shipped_date: transfer.shipped_date(),
+            });
+        }
+    }
+    items
+}
+
+/// Use case for reporting stock in transit between stores
+///
+/// Stock leaves the source's on-hand when a transfer ships and reaches the
+/// destination's on-hand when it is received. In between it is reported
+/// here, with per-store outbound/inbound quantities and the value each
+/// store owns.
+pub struct GetInTransitStockUseCase<T>
+where
+    T: TransferRepository,
+{
+    transfer_repo: Arc<T>,
+}
+
+impl<T> GetInTransitStockUseCase<T>
+where
+    T: TransferRepository,
+{
+    pub fn new(transfer_repo: Arc<T>) -> Self {
+        Self { transfer_repo }
+    }
+
+    /// Executes the use case
+    ///
+    /// # Arguments
+    /// * `store_id` - Only transfers from or to this store (all if None)
+    /// * `settings` - Settings of the source stores, deciding value ownership
+    pub async fn execute(
+        &self,
+        store_id: Option<Uuid>,
+        settings: &[StoreSettings],
+    ) -> Result<InTransitStockResponse, InventoryError> {
+        let transfers = self.transfer_repo.find_in_transit().await?;
+        let items: Vec<InTransitItemResponse> = in_transit_items(&transfers, settings)
+            .into_iter()
+            .filter(|i| store_id.is_none_or(|s| i.from_store_id == s || i.to_store_id == s))
+            .collect();
+
+        let mut stores: BTreeMap<Uuid, InTransitStoreTotalResponse> = BTreeMap::new();
+        for item in &items {
+            stores
+                .entry(item.from_store_id)
+                .or_insert_with(|| empty_total(item.from_store_id))
+                .outbound_quantity += item.quantity;
+            stores
+                .entry(item.to_store_id)
+                .or_insert_with(|| empty_total(item.to_store_id))
+                .inbound_quantity += item.quantity;
+            stores
+                .entry(item.owner_store_id)
+                .or_insert_with(|| empty_total(item.owner_store_id))
+                .owned_value += item.total_value;
+        }
+        if let Some(store_id) = store_id {
+            stores.retain(|id, _| *id == store_id);
+        }
+
+        Ok(InTransitStockResponse {
+            total_quantity: items.iter().map(|i| i.quantity).sum(),
+            total_value: items.iter().map(|i| i.total_value).sum(),
+            items,
+            stores: stores.into_values().collect(),
+            generated_at: Utc::now(),
+        })
+    }
+}
+
+fn empty_total(store_id: Uuid) -> InTransitStoreTotalResponse {
+    InTransitStoreTotalResponse {
+        store_id,
+        outbound_quantity: Decimal::ZERO,
+        inbound_quantity: Decimal::ZERO,
+        owned_value: Decimal::ZERO,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use async_trait::async_trait;
+    use rust_decimal_macros::dec;
+    use std::sync::Mutex;
+
+    use crate::domain::entities::TransferItem;
+    use crate::domain::value_objects::{ProductId, TransferId};
+    use identity::{StoreId, UserId};
+
+    struct MockTransferRepository {
+        transfers: Mutex<Vec<StockTransfer>>,
+    }
+
+    #[async_trait]
+    impl TransferRepository for MockTransferRepository {
+        async fn save(&self, _transfer: &StockTransfer) -> Result<(), InventoryError> {
+            unimplemented!()
+        }
+
+        async fn find_by_id(
+            &self,
+            _id: TransferId,
+        ) -> Result<Option<StockTransfer>, InventoryError> {
+            unimplemented!()
+        }
+
+        async fn find_by_id_with_items(
+            &self,
+            _id: TransferId,
+        ) -> Result<Option<StockTransfer>, InventoryError> {
+            unimplemented!()
+        }
+
+        async fn find_by_store(
+            &self,
+            _store_id: StoreId,
+        ) -> Result<Vec<StockTransfer>, InventoryError> {
+            unimplemented!()
+        }
+
+        async fn find_outgoing_by_store(
+            &self,
+            _store_id: StoreId,
+        ) -> Result<Vec<StockTransfer>, InventoryError> {
+            unimplemented!()
+        }
+
+        async fn find_incoming_by_store(
+            &self,
+            _store_id: StoreId,
+        ) -> Result<Vec<StockTransfer>, InventoryError> {
+            unimplemented!()
+        }
+
+        async fn find_in_transit(&self) -> Result<Vec<StockTransfer>, InventoryError> {
+            Ok(self.transfers.lock().unwrap().clone())
+        }
+
+        async fn update(&self, _transfer: &StockTransfer) -> Result<(), InventoryError> {
+            unimplemented!()
+        }
+
+        async fn generate_transfer_number(&self) -> Result<String, InventoryError> {
+            unimplemented!()
+        }
+    }
+
+    fn shipped_transfer(from: StoreId, to: StoreId, quantity: Decimal) -> StockTransfer {
+        let mut transfer =
+            StockTransfer::create("TRF-1".to_string(), from, to, UserId::new()).unwrap();
+        let item = TransferItem::create_for_product(
+            transfer.id(),
+            ProductId::new(),
+            quantity,
+            Some(dec!(4)),
+        )
+        .unwrap();
+        transfer.add_item(item).unwrap();
+        transfer.submit().unwrap();
+        transfer.ship(UserId::new()).unwrap();
+        transfer
+    }
+
+    fn use_case(transfers: Vec<StockTransfer>) -> GetInTransitStockUseCase<MockTransferRepository> {
+        GetInTransitStockUseCase::new(Arc::new(MockTransferRepository {
+            transfers: Mutex::new(transfers),
+        }))
+    }
+
+    #[test]
+    fn test_source_store_owns_transit_stock_by_default() {
+        let (from, to) = (StoreId::new(), StoreId::new());
+        let transfers = vec![shipped_transfer(from, to, dec!(10))];
+
+        let items = in_transit_items(&transfers, &[]);
+
+        assert_eq!(items.len(), 1);
+        assert_eq!(items[0].owner_store_id, *from.as_uuid());
+        assert_eq!(items[0].total_value, dec!(40));
+    }
+
+    #[test]
+    fn test_destination_owns_when_source_configures_it() {
+        let (from, to) = (StoreId::new(), StoreId::new());
+        let transfers = vec![shipped_transfer(from, to, dec!(10))];
+        let mut settings = StoreSettings::defaults(from);
+        settings.set_transit_owned_by_destination(true);
+
+        let items = in_transit_items(&transfers, &[settings]);
+
+        assert_eq!(items[0].owner_store_id, *to.as_uuid());
+    }
+
+    #[tokio::test]
+    async fn test_store_totals_split_outbound_and_inbound() {
+        let (a, b, c) = (StoreId::new(), StoreId::new(), StoreId::new());
+        let use_case = use_case(vec![
+            shipped_transfer(a, b, dec!(10)),
+            shipped_transfer(b, a, dec!(3)),
+            shipped_transfer(b, c, dec!(5)),
+        ]);
+
+        let response = use_case.execute(Some(*a.as_uuid()), &[]).await.unwrap();
+
+        assert_eq!(response.items.len(), 2);
+        assert_eq!(response.total_quantity, dec!(13));
+        assert_eq!(response.stores.len(), 1);
+        let totals = &response.stores[0];
+        assert_eq!(totals.outbound_quantity, dec!(10));
+        assert_eq!(totals.inbound_quantity, dec!(3));
+        assert_eq!(totals.owned_value, dec!(40));
+    }
+}
@@ -0,0 +1,313 @@
+// GetInventoryAgingReportUseCase - age of on-hand stock bucketed by days since receipt
+
+use std::sync::Arc;
+
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use uuid::Uuid;
+
+use crate::InventoryError;
+use crate::application::dtos::responses::{
+    AgingBucketResponse, InventoryAgingItemResponse, InventoryAgingReportResponse,
+};
+use crate::application::use_cases::get_valuation_report_use_case::product_info;
+use crate::domain::entities::InventoryMovement;
+use crate::domain::repositories::{
+    InventoryMovementRepository, InventoryStockRepository, ProductRepository,
+};
+use crate::domain::value_objects::MovementType;
+
+/// Upper bounds, in days, of the 0-30, 31-60 and 61-90 buckets; older stock
+/// falls in the 90+ bucket
+const BUCKET_LIMITS: [i64; 3] = [30, 60, 90];
+
+/// Query parameters for the inventory aging report
+#[derive(Debug, Clone, Default)]
+pub struct InventoryAgingReportQuery {
+    /// Filter by store ID (optional - if None, includes all stores)
+    pub store_id: Option<Uuid>,
+    /// Currency for the report (defaults to HNL)
+    pub currency: Option<String>,
+}
+
+/// Use case for the inventory aging report.
+///
+/// On-hand quantity is assumed to be what remains of the most recent
+/// receipts (FIFO): walking the kardex newest receipt first, each receipt
+/// covers on-hand units until the quantity is accounted for, and those units
+/// are as old as the receipt. Receipts are `in` and `transfer_in` movements
+/// and positive adjustments. Units no receipt covers have unknown age.
+pub struct GetInventoryAgingReportUseCase<S, M, P>
+where
+    S: InventoryStockRepository,
+    M: InventoryMovementRepository,
+    P: ProductRepository,
+{
+    stock_repo: Arc<S>,
+    movement_repo: Arc<M>,
+    product_repo: Arc<P>,
+}
+
+impl<S, M, P> GetInventoryAgingReportUseCase<S, M, P>
+where
+    S: InventoryStockRepository,
+    M: InventoryMovementRepository,
+    P: ProductRepository,
+{
+    pub fn new(stock_repo: Arc<S>, movement_repo: Arc<M>, product_repo: Arc<P>) -> Self {
+        Self {
+            stock_repo,
+            movement_repo,
+            product_repo,
+        }
+    }
+
+    /// Executes the use case to generate the aging report
+    ///
+    /// # Returns
+    /// InventoryAgingReportResponse with one item per stock record holding
+    /// stock, the most value over 90 days old first
+    pub async fn execute(
+        &self,
+        query: InventoryAgingReportQuery,
+    ) -> Result<InventoryAgingReportResponse, InventoryError> {
+        let currency = query.currency.unwrap_or_else(|| "HNL".to_string());
+        let now = Utc::now();
+
+        let stocks = if let Some(store_id) = query.store_id {
+            self.stock_repo.find_by_store(store_id.into()).await?
+        } else {
+            self.stock_repo.find_all().await?
+        };
+
+        let mut items = Vec::new();
+        for stock in stocks {
+            if stock.quantity() <= Decimal::ZERO {
+                continue;
+            }
+
+            let average_cost = self
+                .movement_repo
+                .calculate_weighted_average_cost(stock.id())
+                .await?
+                .unwrap_or(Decimal::ZERO);
+            let movements = self
+                .movement_repo
+                .find_all_by_stock_id_chronological(stock.id())
+                .await?;
+            let receipts: Vec<&InventoryMovement> =
+                movements.iter().rev().filter(|m| is_receipt(m)).collect();
+
+            let aged = age_on_hand(stock.quantity(), &receipts, average_cost, now);
+            let (product_name, variant_name, sku) =
+                product_info(self.product_repo.as_ref(), &stock).await?;
+
+            items.push(InventoryAgingItemResponse {
+                stock_id: stock.id().into_uuid(),
+                store_id: *stock.store_id().as_uuid(),
+                product_id: stock.product_id().map(|id| id.into_uuid()),
+                variant_id: stock.variant_id().map(|id| id.into_uuid()),
+                product_name,
+                variant_name,
+                sku,
+                quantity: stock.quantity(),
+                total_value: aged.total_value(),
+                days_0_30: aged.buckets[0].clone(),
+                days_31_60: aged.buckets[1].clone(),
+                days_61_90: aged.buckets[2].clone(),
+                days_over_90: aged.buckets[3].clone(),
+                unknown_age: aged.unknown.clone(),
+                age_unknown: receipts.is_empty(),
+                currency: currency.clone(),
+            });
+        }
+
+        items.sort_by(|a, b| {
+            b.days_over_90
+                .value
+                .cmp(&a.days_over_90.value)
+                .then_with(|| b.days_61_90.value.cmp(&a.days_61_90.value))
+                .then_with(|| a.sku.cmp(&b.sku))
+        });
+
+        let total = |bucket: fn(&InventoryAgingItemResponse) -> &AgingBucketResponse| {
+            items
+                .iter()
+                .map(bucket)
+                .fold(AgingBucketResponse::default(), |acc, b| {
+                    AgingBucketResponse {
+                        quantity: acc.quantity + b.quantity,
+                        value: acc.value + b.value,
+                    }
+                })
+        };
+        let days_0_30 = total(|i| &i.days_0_30);
+        let days_31_60 = total(|i| &i.days_31_60);
+        let days_61_90 = total(|i| &i.days_61_90);
+        let days_over_90 = total(|i| &i.days_over_90);
+        let unknown_age = total(|i| &i.unknown_age);
+
+        Ok(InventoryAgingReportResponse {
+            total_items: items.len() as i64,
+            total_value: items.iter().map(|i| i.total_value).sum(),
+            items,
+            days_0_30,
+            days_31_60,
+            days_61_90,
+            days_over_90,
+            unknown_age,
+            currency,
+            generated_at: now,
+        })
+    }
+}
+
+/// Whether a kardex movement brought units into the stock record
+fn is_receipt(movement: &InventoryMovement) -> bool {
+    match movement.movement_type() {
+        MovementType::In | MovementType::TransferIn => true,
+        MovementType::Adjustment => movement.quantity() > Decimal::ZERO,
+        _ => false,
+    }
+}
+
+/// On-hand stock split into the age buckets plus the units of unknown age
+#[derive(Debug, Default)]
+struct AgedStock {
+    buckets: [AgingBucketResponse; 4],
+    unknown: AgingBucketResponse,
+}
+
+impl AgedStock {
+    fn total_value(&self) -> Decimal {
+        self.buckets.iter().map(|b| b.value).sum::<Decimal>() + self.unknown.value
+    }
+}
+
+/// Ages `quantity` units on hand against `receipts`, newest first.
+///
+/// Units take the cost of the receipt covering them, or `fallback_cost` when
+/// the receipt carries none; units of unknown age are priced at
+/// `fallback_cost`.
+fn age_on_hand(
+    quantity: Decimal,
+    receipts: &[&InventoryMovement],
+    fallback_cost: Decimal,
+    now: DateTime<Utc>,
+) -> AgedStock {
+    let mut aged = AgedStock::default();
+    let mut uncovered = quantity;
+
+    for receipt in receipts {
+        if uncovered <= Decimal::ZERO {
+            break;
+        }
+        let taken = receipt.quantity().abs().min(uncovered);
+        let age_days = (now - receipt.created_at()).num_days();
+        let bucket = BUCKET_LIMITS
+            .iter()
+            .position(|limit| age_days <= *limit)
+            .unwrap_or(BUCKET_LIMITS.len());
+
+        aged.buckets[bucket].quantity += taken;
+        aged.buckets[bucket].value += taken * receipt.unit_cost().unwrap_or(fallback_cost);
+        uncovered -= taken;
+    }
+
+    if uncovered > Decimal::ZERO {
+        aged.unknown.quantity = uncovered;
+        aged.unknown.value = uncovered * fallback_cost;
+    }
+    aged
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::value_objects::{Currency, MovementId, StockId};
+    use chrono::Duration;
+    use identity::UserId;
+    use rust_decimal_macros::dec;
+
+    fn movement(
+        movement_type: MovementType,
+        quantity: Decimal,
+        unit_cost: Option<Decimal>,
+        days_ago: i64,
+    ) -> InventoryMovement {
+        InventoryMovement::reconstitute(
+            MovementId::new(),
+            StockId::new(),
+            movement_type,
+            None,
+            quantity,
+            unit_cost,
+            Currency::hnl(),
+            Decimal::ZERO,
+            None,
+            None,
+            UserId::new(),
+            None,
+            serde_json::json!({}),
+            Utc::now() - Duration::days(days_ago),
+        )
+    }
+
+    #[test]
+    fn test_age_on_hand_matches_newest_receipts_first() {
+        let now = Utc::now();
+        let newest = movement(MovementType::In, dec!(5), Some(dec!(12)), 10);
+        let middle = movement(MovementType::TransferIn, dec!(10), Some(dec!(11)), 45);
+        let oldest = movement(MovementType::In, dec!(20), Some(dec!(10)), 120);
+
+        let aged = age_on_hand(dec!(12), &[&newest, &middle, &oldest], dec!(9), now);
+
+        assert_eq!(aged.buckets[0].quantity, dec!(5));
+        assert_eq!(aged.buckets[0].value, dec!(60));
+        assert_eq!(aged.buckets[1].quantity, dec!(7));
+        assert_eq!(aged.buckets[1].value, dec!(77));
+        assert_eq!(aged.buckets[3].quantity, Decimal::ZERO);
+        assert_eq!(aged.unknown.quantity, Decimal::ZERO);
+        assert_eq!(aged.total_value(), dec!(137));
+    }
+
+    #[test]
+    fn test_age_on_hand_reports_uncovered_units_as_unknown() {
+        let now = Utc::now();
+        let receipt = movement(MovementType::In, dec!(3), None, 100);
+
+        let aged = age_on_hand(dec!(8), &[&receipt], dec!(4), now);
+
+        assert_eq!(aged.buckets[3].quantity, dec!(3));
+        assert_eq!(aged.buckets[3].value, dec!(12));
+        assert_eq!(aged.unknown.quantity, dec!(5));
+        assert_eq!(aged.unknown.value, dec!(20));
+
+        let no_history = age_on_hand(dec!(8), &[], dec!(4), now);
+        assert_eq!(no_history.unknown.quantity, dec!(8));
+    }
+
+    #[test]
+    fn test_is_receipt_counts_positive_adjustments_only() {
+        assert!(is_receipt(&movement(MovementType::In, dec!(1), None, 0)));
+        assert!(is_receipt(&movement(
+            MovementType::Adjustment,
+            dec!(2),
+            None,
+            0
+        )));
+        assert!(!is_receipt(&movement(
+            MovementType::Adjustment,
+            dec!(-2),
+            None,
+            0
+        )));
+        assert!(!is_receipt(&movement(
+            MovementType::Release,
+            dec!(1),
+            None,
+            0
+        )));
+        assert!(!is_receipt(&movement(MovementType::Out, dec!(-1), None, 0)));
+    }
+}
@@ -0,0 +1,363 @@
+// GetInventoryCategoryRollupUseCase - stock quantity and valuation rolled up the category tree
+
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+
+use chrono::Utc;
+use identity::StoreId;
+use rust_decimal::Decimal;
+use uuid::Uuid;
+
+use crate::InventoryError;
+use crate::application::dtos::responses::{CategoryRollupNode, CategoryRollupReportResponse};
+use crate::domain::repositories::{CategoryRepository, CategoryStockValuation};
+use crate::domain::value_objects::CategoryId;
+
+/// Query parameters for the category rollup report
+#[derive(Debug, Clone, Default)]
+pub struct CategoryRollupQuery {
+    /// Filter by store ID (optional - if None, includes all stores)
+    pub store_id: Option<Uuid>,
+    /// Drill down into a single category's subtree (optional)
+    pub category_id: Option<Uuid>,
+    /// Currency for the report (defaults to HNL)
+    pub currency: Option<String>,
+    /// Whether the caller may see valuation; quantities are always returned
+    pub include_costs: bool,
+}
+
+/// Use case for the inventory-by-category rollup report.
+///
+/// Every category carries the stock of products assigned directly to it
+/// (parents included, not only leaves) and the sum of its whole subtree.
+pub struct GetInventoryCategoryRollupUseCase<C>
+where
+    C: CategoryRepository,
+{
+    category_repo: Arc<C>,
+}
+
+impl<C> GetInventoryCategoryRollupUseCase<C>
+where
+    C: CategoryRepository,
+{
+    pub fn new(category_repo: Arc<C>) -> Self {
+        Self { category_repo }
+    }
+
+    /// Executes the use case to generate the rollup tree
+    ///
+    /// # Returns
+    /// CategoryRollupReportResponse with root nodes (or the drill-down node)
+    pub async fn execute(
+        &self,
+        query: CategoryRollupQuery,
+    ) -> Result<CategoryRollupReportResponse, InventoryError> {
+        let currency = query.currency.unwrap_or_else(|| "HNL".to_string());
+
+        let rows = self
+            .category_repo
+            .stock_valuation_by_category(query.store_id.map(StoreId::from_uuid))
+            .await?;
+
+        let tree = RollupTree::new(&rows);
+        let nodes = match query.category_id {
+            Some(id) => {
+                let node = tree
+                    .build(CategoryId::from_uuid(id), &mut HashSet::new())
+                    .ok_or(InventoryError::CategoryNotFound(id))?;
+                vec![node]
+            }
+            None => tree.roots(),
+        };
+
+        let total_quantity = nodes.iter().map(|n| n.rolled_up_quantity).sum();
+        let total_value: Decimal = nodes.iter().filter_map(|n| n.rolled_up_value).sum();
+
+        let mut nodes = nodes;
+        if !query.include_costs {
+            nodes.iter_mut().for_each(hide_costs);
+        }
+
+        Ok(CategoryRollupReportResponse {
+            store_id: query.store_id,
+            nodes,
+            total_quantity,
+            total_value: query.include_costs.then_some(total_value),
+            costs_visible: query.include_costs,
+            currency,
+            generated_at: Utc::now(),
+        })
+    }
+}
+
+fn hide_costs(node: &mut CategoryRollupNode) {
+    node.own_value = None;
+    node.rolled_up_value = None;
+    node.children.iter_mut().for_each(hide_costs);
+}
+
+/// Parent/child index over the flat per-category rows
+struct RollupTree<'a> {
+    by_id: HashMap<CategoryId, &'a CategoryStockValuation>,
+    children: HashMap<CategoryId, Vec<&'a CategoryStockValuation>>,
+    roots: Vec<&'a CategoryStockValuation>,
+    uncategorized: Option<&'a CategoryStockValuation>,
+}
+
+impl<'a> RollupTree<'a> {
+    fn new(rows: &'a [CategoryStockValuation]) -> Self {
+        let by_id: HashMap<CategoryId, &CategoryStockValuation> = rows
+            .iter()
+            .filter_map(|r| r.category_id.map(|id| (id, r)))
+            .collect();
+
+        let mut children: HashMap<CategoryId, Vec<&CategoryStockValuation>> = HashMap::new();
+        let mut roots = Vec::new();
+        let mut uncategorized = None;
+
+        for row in rows {
+            if row.category_id.is_none() {
+                uncategorized = Some(row);
+                continue;
+            }
+            match row.parent_id.filter(|p| by_id.contains_key(p)) {
+                Some(parent) => children.entry(parent).or_default().push(row),
+                None => roots.push(row),
+            }
+        }
+
+        let order = |a: &&CategoryStockValuation, b: &&CategoryStockValuation| {
+            a.sort_order.cmp(&b.sort_order).then(a.name.cmp(&b.name))
+        };
+        roots.sort_by(order);
+        children.values_mut().for_each(|c| c.sort_by(order));
+
+        Self {
+            by_id,
+            children,
+            roots,
+            uncategorized,
+        }
+    }
+
+    fn roots(&self) -> Vec<CategoryRollupNode> {
+        let mut visited = HashSet::new();
+        let mut nodes: Vec<CategoryRollupNode> = self
+            .roots
+            .iter()
+            .filter_map(|r| r.category_id)
+            .filter_map(|id| self.build(id, &mut visited))
+            .collect();
+
+        if let Some(row) = self.uncategorized {
+            nodes.push(leaf(row));
+        }
+        nodes
+    }
+
+    /// Builds the subtree under `id`; `visited` guards against parent cycles
+    fn build(
+        &self,
+        id: CategoryId,
+        visited: &mut HashSet<CategoryId>,
+    ) -> Option<CategoryRollupNode> {
+        let row = self.by_id.get(&id)?;
+        if !visited.insert(id) {
+            return None;
+        }
+
+        let mut node = leaf(row);
+        for child in self.children.get(&id).into_iter().flatten() {
+            if let Some(child_node) = child.category_id.and_then(|c| self.build(c, visited)) {
+                node.rolled_up_quantity += child_node.rolled_up_quantity;
+                node.rolled_up_value = node
+                    .rolled_up_value
+                    .zip(child_node.rolled_up_value)
+                    .map(|(a, b)| a + b);
+                node.children.push(child_node);
+            }
+        }
+        Some(node)
+    }
+}
+
+fn leaf(row: &CategoryStockValuation) -> CategoryRollupNode {
+    CategoryRollupNode {
+        category_id: row.category_id.map(|id| id.into_uuid()),
+        parent_id: row.parent_id.map(|id| id.into_uuid()),
+        name: row.name.clone(),
+        own_quantity: row.quantity,
+        own_value: Some(row.total_value),
+        rolled_up_quantity: row.quantity,
+        rolled_up_value: Some(row.total_value),
+        children: Vec::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::entities::ProductCategory;
+    use async_trait::async_trait;
+    use rust_decimal_macros::dec;
+
+    struct MockCategoryRepository {
+        rows: Vec<CategoryStockValuation>,
+    }
+
+    #[async_trait]
+    impl CategoryRepository for MockCategoryRepository {
+        async fn save(&self, _category: &ProductCategory) -> Result<(), InventoryError> {
+            Ok(())
+        }
+        async fn find_by_id(
+            &self,
+            _id: CategoryId,
+        ) -> Result<Option<ProductCategory>, InventoryError> {
+            Ok(None)
+        }
+        async fn find_by_slug(
+            &self,
+            _slug: &str,
+        ) -> Result<Option<ProductCategory>, InventoryError> {
+            Ok(None)
+        }
+        async fn find_root_categories(&self) -> Result<Vec<ProductCategory>, InventoryError> {
+            Ok(vec![])
+        }
+        async fn find_children(
+            &self,
+            _parent_id: CategoryId,
+        ) -> Result<Vec<ProductCategory>, InventoryError> {
+            Ok(vec![])
+        }
+        async fn find_all_active(&self) -> Result<Vec<ProductCategory>, InventoryError> {
+            Ok(vec![])
+        }
+        async fn update(&self, _category: &ProductCategory) -> Result<(), InventoryError> {
+            Ok(())
+        }
+        async fn stock_valuation_by_category(
+            &self,
+            _store_id: Option<StoreId>,
+        ) -> Result<Vec<CategoryStockValuation>, InventoryError> {
+            Ok(self.rows.clone())
+        }
+        async fn delete(&self, _id: CategoryId) -> Result<(), InventoryError> {
+            Ok(())
+        }
+    }
+
+    fn row(
+        id: Option<CategoryId>,
+        parent: Option<CategoryId>,
+        name: &str,
+        quantity: Decimal,
+        value: Decimal,
+    ) -> CategoryStockValuation {
+        CategoryStockValuation {
+            category_id: id,
+            parent_id: parent,
+            name: name.to_string(),
+            sort_order: 0,
+            quantity,
+            total_value: value,
+        }
+    }
+
+    /// Food (own 2 / 20) -> Dairy (own 5 / 50) -> Cheese (own 1 / 30);
+    /// Tools (empty); uncategorized (3 / 9)
+    fn sample() -> (Vec<CategoryStockValuation>, CategoryId, CategoryId) {
+        let food = CategoryId::new();
+        let dairy = CategoryId::new();
+        let cheese = CategoryId::new();
+        let tools = CategoryId::new();
+        let rows = vec![
+            row(Some(cheese), Some(dairy), "Cheese", dec!(1), dec!(30)),
+            row(Some(food), None, "Food", dec!(2), dec!(20)),
+            row(Some(tools), None, "Tools", dec!(0), dec!(0)),
+            row(Some(dairy), Some(food), "Dairy", dec!(5), dec!(50)),
+            row(None, None, "Uncategorized", dec!(3), dec!(9)),
+        ];
+        (rows, food, dairy)
+    }
+
+    fn use_case(
+        rows: Vec<CategoryStockValuation>,
+    ) -> GetInventoryCategoryRollupUseCase<MockCategoryRepository> {
+        GetInventoryCategoryRollupUseCase::new(Arc::new(MockCategoryRepository { rows }))
+    }
+
+    #[tokio::test]
+    async fn test_rolls_subtree_into_parents() {
+        let (rows, _, _) = sample();
+        let result = use_case(rows)
+            .execute(CategoryRollupQuery {
+                include_costs: true,
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+
+        let names: Vec<&str> = result.nodes.iter().map(|n| n.name.as_str()).collect();
+        assert_eq!(names, vec!["Food", "Tools", "Uncategorized"]);
+
+        let food = &result.nodes[0];
+        assert_eq!(food.own_quantity, dec!(2));
+        assert_eq!(food.own_value, Some(dec!(20)));
+        assert_eq!(food.rolled_up_quantity, dec!(8));
+        assert_eq!(food.rolled_up_value, Some(dec!(100)));
+        assert_eq!(food.children[0].rolled_up_value, Some(dec!(80)));
+        assert_eq!(food.children[0].children[0].name, "Cheese");
+
+        assert_eq!(result.total_quantity, dec!(11));
+        assert_eq!(result.total_value, Some(dec!(109)));
+    }
+
+    #[tokio::test]
+    async fn test_drill_down_returns_subtree() {
+        let (rows, _, dairy) = sample();
+        let result = use_case(rows)
+            .execute(CategoryRollupQuery {
+                category_id: Some(dairy.into_uuid()),
+                include_costs: true,
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(result.nodes.len(), 1);
+        assert_eq!(result.nodes[0].name, "Dairy");
+        assert_eq!(result.total_value, Some(dec!(80)));
+    }
+
+    #[tokio::test]
+    async fn test_hides_values_without_cost_visibility() {
+        let (rows, _, _) = sample();
+        let result = use_case(rows)
+            .execute(CategoryRollupQuery::default())
+            .await
+            .unwrap();
+
+        assert!(!result.costs_visible);
+        assert_eq!(result.total_value, None);
+        assert_eq!(result.total_quantity, dec!(11));
+        let food = &result.nodes[0];
+        assert_eq!(food.rolled_up_value, None);
+        assert_eq!(food.children[0].children[0].own_value, None);
+    }
+
+    #[tokio::test]
+    async fn test_unknown_drill_down_category() {
+        let (rows, _, _) = sample();
+        let result = use_case(rows)
+            .execute(CategoryRollupQuery {
+                category_id: Some(Uuid::now_v7()),
+                ..Default::default()
+            })
+            .await;
+
+        assert!(matches!(result, Err(InventoryError::CategoryNotFound(_))));
+    }
+}
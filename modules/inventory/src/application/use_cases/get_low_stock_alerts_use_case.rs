@@ -9,8 +9,9 @@ use identity::StoreId;
 
 /// Use case for retrieving low stock alerts for a store.
 ///
-/// Returns all stock records where available_quantity <= min_stock_level,
-/// useful for generating reorder alerts and notifications.
+/// Returns all stock records where available_quantity is at or below the
+/// store's reorder point (min_stock_level when the store has none), with the
+/// quantity to reorder, useful for generating reorder alerts and notifications.
 pub struct GetLowStockAlertsUseCase<S>
 where
     S: InventoryStockRepository,
@@ -57,6 +58,9 @@ where
                 version: s.version(),
                 min_stock_level: s.min_stock_level(),
                 max_stock_level: s.max_stock_level(),
+                reorder_point: s.reorder_point(),
+                reorder_quantity: s.reorder_quantity(),
+                suggested_reorder_quantity: Some(s.suggested_reorder_quantity()),
                 is_low_stock: true, // All items from find_low_stock are low stock
                 created_at: s.created_at(),
                 updated_at: s.updated_at(),
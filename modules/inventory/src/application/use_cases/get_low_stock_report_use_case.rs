@@ -80,22 +80,14 @@ where
                 continue;
             }
 
-            // Calculate shortage (how much below min level)
-            let shortage = if available < stock.min_stock_level() {
-                stock.min_stock_level() - available
+            // Calculate shortage (how much below the store's reorder point)
+            let reorder_point = stock.effective_reorder_point();
+            let shortage = if available < reorder_point {
+                reorder_point - available
             } else {
                 Decimal::ZERO
             };
 
-            // Calculate reorder suggestion
-            // Suggest ordering enough to reach max_stock_level if set,
-            // otherwise suggest 2x the min_stock_level
-            let reorder_suggestion = if let Some(max_level) = stock.max_stock_level() {
-                max_level - available
-            } else {
-                (stock.min_stock_level() * Decimal::from(2)) - available
-            };
-
             // Get product info
             let (product_name, variant_name, sku) = self.get_product_info(&stock).await?;
 
@@ -110,8 +102,9 @@ where
                 current_quantity: stock.quantity(),
                 available_quantity: available,
                 min_stock_level: stock.min_stock_level(),
+                reorder_point,
                 shortage,
-                reorder_suggestion: reorder_suggestion.max(Decimal::ZERO),
+                reorder_suggestion: stock.suggested_reorder_quantity(),
             });
         }
 
@@ -168,8 +161,10 @@ mod tests {
     use std::sync::Mutex;
     use uuid::{NoContext, Timestamp, Uuid};
 
-    use crate::domain::entities::{InventoryStock, Product, ProductVariant};
-    use crate::domain::value_objects::{Barcode, CategoryId, ProductId, Sku, StockId, VariantId};
+    use crate::domain::entities::{InventoryStock, Product, ProductVariant, ProductVendor};
+    use crate::domain::value_objects::{
+        Barcode, CategoryId, ProductId, Sku, StockId, UnitConversion, VariantId,
+    };
     use identity::StoreId;
 
     fn new_uuid() -> Uuid {
@@ -238,10 +233,7 @@ mod tests {
             let stocks = self.stocks.lock().unwrap();
             Ok(stocks
                 .values()
-                .filter(|s| {
-                    *s.store_id().as_uuid() == *store_id.as_uuid()
-                        && s.available_quantity() <= s.min_stock_level()
-                })
+                .filter(|s| *s.store_id().as_uuid() == *store_id.as_uuid() && s.is_low_stock())
                 .cloned()
                 .collect())
         }
@@ -255,9 +247,10 @@ mod tests {
 
         async fn find_paginated(
             &self,
-            _store_id: Option<StoreId>,
+            _store_ids: Option<&[StoreId]>,
             _product_id: Option<ProductId>,
             _low_stock_only: bool,
+            _after_id: Option<Uuid>,
             _page: i64,
             _page_size: i64,
         ) -> Result<(Vec<InventoryStock>, i64), InventoryError> {
@@ -280,7 +273,7 @@ mod tests {
             let stocks = self.stocks.lock().unwrap();
             Ok(stocks
                 .values()
-                .filter(|s| s.available_quantity() <= s.min_stock_level())
+                .filter(|s| s.is_low_stock())
                 .cloned()
                 .collect())
         }
@@ -308,10 +301,7 @@ mod tests {
             let stocks = self.stocks.lock().unwrap();
             Ok(stocks
                 .values()
-                .filter(|s| {
-                    *s.store_id().as_uuid() == *store_id.as_uuid()
-                        && s.available_quantity() <= s.min_stock_level()
-                })
+                .filter(|s| *s.store_id().as_uuid() == *store_id.as_uuid() && s.is_low_stock())
                 .cloned()
                 .collect())
         }
@@ -354,6 +344,7 @@ mod tests {
             _category_id: Option<CategoryId>,
             _is_active: Option<bool>,
             _search: Option<&str>,
+            _after_id: Option<Uuid>,
             _page: i64,
             _page_size: i64,
         ) -> Result<(Vec<Product>, i64), InventoryError> {
@@ -413,6 +404,17 @@ mod tests {
             unimplemented!()
         }
 
+        async fn search(
+            &self,
+            _term: &str,
+            _category_id: Option<CategoryId>,
+            _active_only: bool,
+            _page: i64,
+            _page_size: i64,
+        ) -> Result<(Vec<(Product, f64)>, i64), InventoryError> {
+            unimplemented!()
+        }
+
         async fn find_variant_by_barcode(
             &self,
             _barcode: &Barcode,
@@ -423,6 +425,42 @@ mod tests {
         async fn count_variants(&self, _product_id: ProductId) -> Result<u32, InventoryError> {
             unimplemented!()
         }
+
+        async fn save_product_vendors(
+            &self,
+            _vendors: &[ProductVendor],
+        ) -> Result<(), InventoryError> {
+            unimplemented!()
+        }
+
+        async fn find_product_vendors(
+            &self,
+            _product_id: ProductId,
+        ) -> Result<Vec<ProductVendor>, InventoryError> {
+            unimplemented!()
+        }
+
+        async fn find_preferred_vendor(
+            &self,
+            _product_id: ProductId,
+        ) -> Result<Option<ProductVendor>, InventoryError> {
+            unimplemented!()
+        }
+
+        async fn replace_unit_conversions(
+            &self,
+            _product_id: ProductId,
+            _conversions: &[UnitConversion],
+        ) -> Result<(), InventoryError> {
+            unimplemented!()
+        }
+
+        async fn find_unit_conversions(
+            &self,
+            _product_id: ProductId,
+        ) -> Result<Vec<UnitConversion>, InventoryError> {
+            unimplemented!()
+        }
     }
 
     fn create_test_stock(
@@ -442,6 +480,8 @@ mod tests {
             1,
             min_level,
             max_level,
+            None,
+            None,
             now,
             now,
         )
@@ -551,6 +591,29 @@ mod tests {
         assert_eq!(result.items[0].reorder_suggestion, dec!(15));
     }
 
+    #[tokio::test]
+    async fn test_low_stock_report_uses_store_reorder_point() {
+        let stock_repo = Arc::new(MockStockRepository::new());
+        let product_repo = Arc::new(MockProductRepository);
+
+        // Above min_stock_level but below this store's reorder point
+        let mut stock = create_test_stock(dec!(15), dec!(0), dec!(10), None);
+        stock.set_reorder_point(Some(dec!(20)));
+        stock.set_reorder_quantity(Some(dec!(40)));
+        stock_repo.add_stock(stock);
+
+        let use_case = GetLowStockReportUseCase::new(stock_repo, product_repo);
+        let result = use_case
+            .execute(LowStockReportQuery::default())
+            .await
+            .unwrap();
+
+        assert_eq!(result.total_items, 1);
+        assert_eq!(result.items[0].reorder_point, dec!(20));
+        assert_eq!(result.items[0].shortage, dec!(5));
+        assert_eq!(result.items[0].reorder_suggestion, dec!(40));
+    }
+
     // =========================================================================
     // Property-Based Tests for Low Stock Report Accuracy
     // =========================================================================
@@ -0,0 +1,51 @@
+// GetPriceHistoryUseCase - lists the cost and price changes of a product
+
+use std::sync::Arc;
+
+use uuid::Uuid;
+
+use crate::InventoryError;
+use crate::application::dtos::responses::PriceChangeResponse;
+use crate::domain::repositories::{PriceHistoryRepository, ProductRepository};
+use crate::domain::value_objects::ProductId;
+
+/// Use case for getting the price history of a product, newest first
+pub struct GetPriceHistoryUseCase<P, H>
+where
+    P: ProductRepository,
+    H: PriceHistoryRepository,
+{
+    product_repo: Arc<P>,
+    history_repo: Arc<H>,
+}
+
+impl<P, H> GetPriceHistoryUseCase<P, H>
+where
+    P: ProductRepository,
+    H: PriceHistoryRepository,
+{
+    /// Creates a new instance of GetPriceHistoryUseCase
+    pub fn new(product_repo: Arc<P>, history_repo: Arc<H>) -> Self {
+        Self {
+            product_repo,
+            history_repo,
+        }
+    }
+
+    /// Executes the use case
+    ///
+    /// # Errors
+    /// * `InventoryError::ProductNotFound` - If the product doesn't exist
+    pub async fn execute(
+        &self,
+        product_id: Uuid,
+    ) -> Result<Vec<PriceChangeResponse>, InventoryError> {
+        let product_id_vo = ProductId::from_uuid(product_id);
+        if self.product_repo.find_by_id(product_id_vo).await?.is_none() {
+            return Err(InventoryError::ProductNotFound(product_id));
+        }
+
+        let changes = self.history_repo.find_by_product(product_id_vo).await?;
+        Ok(changes.iter().map(PriceChangeResponse::from).collect())
+    }
+}
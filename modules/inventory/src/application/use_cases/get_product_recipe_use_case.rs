@@ -75,6 +75,7 @@ where
             id: product.id().into_uuid(),
             sku: product.sku().to_string(),
             barcode: product.barcode().map(|b| b.to_string()),
+            barcode_generated: product.barcode_generated(),
             name: product.name().to_string(),
             description: product.description().map(|s| s.to_string()),
             category_id: product.category_id().map(|id| id.into_uuid()),
@@ -88,6 +89,10 @@ where
             has_variants: product.has_variants(),
             tax_rate: product.tax_rate(),
             tax_included: product.tax_included(),
+            tax_category: product.tax_category().map(|t| t.to_string()),
+            is_serialized: product.is_serialized(),
+            markup_type: product.markup_rule().map(|r| r.markup_type().to_string()),
+            markup_percent: product.markup_rule().and_then(|r| r.percent()),
             is_active: product.is_active(),
             created_at: product.created_at(),
             updated_at: product.updated_at(),
@@ -156,6 +161,7 @@ where
             description: recipe.description().map(|s| s.to_string()),
             version: recipe.version(),
             yield_quantity: recipe.yield_quantity(),
+            yield_unit: recipe.yield_unit().to_string(),
             is_active: recipe.is_active(),
             preparation_time_minutes: recipe.preparation_time_minutes(),
             calculate_cost_from_ingredients: recipe.calculate_cost_from_ingredients(),
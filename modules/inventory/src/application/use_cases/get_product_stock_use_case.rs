@@ -59,7 +59,7 @@ where
             .into_iter()
             .map(|s| {
                 let available = s.available_quantity();
-                let is_low = available <= s.min_stock_level();
+                let is_low = s.is_low_stock();
                 StockResponse {
                     id: s.id().into_uuid(),
                     store_id: *s.store_id().as_uuid(),
@@ -71,6 +71,9 @@ where
                     version: s.version(),
                     min_stock_level: s.min_stock_level(),
                     max_stock_level: s.max_stock_level(),
+                    reorder_point: s.reorder_point(),
+                    reorder_quantity: s.reorder_quantity(),
+                    suggested_reorder_quantity: is_low.then(|| s.suggested_reorder_quantity()),
                     is_low_stock: is_low,
                     created_at: s.created_at(),
                     updated_at: s.updated_at(),
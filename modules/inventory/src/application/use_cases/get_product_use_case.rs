@@ -3,25 +3,34 @@
 use std::sync::Arc;
 
 use crate::InventoryError;
-use crate::application::dtos::responses::{ProductDetailResponse, VariantResponse};
-use crate::domain::repositories::ProductRepository;
+use crate::application::dtos::responses::{
+    ProductDetailResponse, ProductVendorResponse, VariantResponse,
+};
+use crate::application::helpers::resolve_effective_tax_category;
+use crate::domain::repositories::{CategoryRepository, ProductRepository};
 use crate::domain::value_objects::ProductId;
 
 /// Use case for retrieving a product with full details including variants
-pub struct GetProductUseCase<P>
+pub struct GetProductUseCase<P, C>
 where
     P: ProductRepository,
+    C: CategoryRepository,
 {
     product_repo: Arc<P>,
+    category_repo: Arc<C>,
 }
 
-impl<P> GetProductUseCase<P>
+impl<P, C> GetProductUseCase<P, C>
 where
     P: ProductRepository,
+    C: CategoryRepository,
 {
     /// Creates a new instance of GetProductUseCase
-    pub fn new(product_repo: Arc<P>) -> Self {
-        Self { product_repo }
+    pub fn new(product_repo: Arc<P>, category_repo: Arc<C>) -> Self {
+        Self {
+            product_repo,
+            category_repo,
+        }
     }
 
     /// Executes the use case to get a product by ID
@@ -61,6 +70,7 @@ where
                     product_id: v.product_id().into_uuid(),
                     sku: v.sku().as_str().to_string(),
                     barcode: v.barcode().map(|b| b.as_str().to_string()),
+                    barcode_generated: v.barcode_generated(),
                     name: v.name().to_string(),
                     variant_attributes: v.variant_attributes().clone(),
                     price: v.price(),
@@ -74,11 +84,23 @@ where
             })
             .collect();
 
+        // Resolve the tax category from the product or its category tree
+        let effective_tax_category =
+            resolve_effective_tax_category(self.category_repo.as_ref(), &product).await?;
+
+        let preferred_vendor = self
+            .product_repo
+            .find_preferred_vendor(product.id())
+            .await?
+            .as_ref()
+            .map(ProductVendorResponse::from);
+
         // Build response
         Ok(ProductDetailResponse {
             id: product.id().into_uuid(),
             sku: product.sku().as_str().to_string(),
             barcode: product.barcode().map(|b| b.as_str().to_string()),
+            barcode_generated: product.barcode_generated(),
             name: product.name().to_string(),
             description: product.description().map(|s| s.to_string()),
             category_id: product.category_id().map(|id| id.into_uuid()),
@@ -93,9 +115,15 @@ where
             has_variants: product.has_variants(),
             tax_rate: product.tax_rate(),
             tax_included: product.tax_included(),
+            tax_category: product.tax_category().map(|t| t.to_string()),
+            is_serialized: product.is_serialized(),
+            markup_type: product.markup_rule().map(|r| r.markup_type().to_string()),
+            markup_percent: product.markup_rule().and_then(|r| r.percent()),
+            effective_tax_category: effective_tax_category.map(Into::into),
             attributes: product.attributes().clone(),
             is_active: product.is_active(),
             variants: variant_responses,
+            preferred_vendor,
             created_at: product.created_at(),
             updated_at: product.updated_at(),
         })
@@ -71,6 +71,7 @@ where
                     id: p.id().into_uuid(),
                     sku: p.sku().to_string(),
                     barcode: p.barcode().map(|b| b.to_string()),
+                    barcode_generated: p.barcode_generated(),
                     name: p.name().to_string(),
                     description: p.description().map(|s| s.to_string()),
                     category_id: p.category_id().map(|id| id.into_uuid()),
@@ -84,6 +85,10 @@ where
                     has_variants: p.has_variants(),
                     tax_rate: p.tax_rate(),
                     tax_included: p.tax_included(),
+                    tax_category: p.tax_category().map(|t| t.to_string()),
+                    is_serialized: p.is_serialized(),
+                    markup_type: p.markup_rule().map(|r| r.markup_type().to_string()),
+                    markup_percent: p.markup_rule().and_then(|r| r.percent()),
                     is_active: p.is_active(),
                     created_at: p.created_at(),
                     updated_at: p.updated_at(),
@@ -101,6 +106,7 @@ where
                     product_id: v.product_id().into_uuid(),
                     sku: v.sku().to_string(),
                     barcode: v.barcode().map(|b| b.to_string()),
+                    barcode_generated: v.barcode_generated(),
                     name: v.name().to_string(),
                     variant_attributes: v.variant_attributes().clone(),
                     price: v.price(),
@@ -178,6 +184,7 @@ where
             description: recipe.description().map(|s| s.to_string()),
             version: recipe.version(),
             yield_quantity: recipe.yield_quantity(),
+            yield_unit: recipe.yield_unit().to_string(),
             is_active: recipe.is_active(),
             preparation_time_minutes: recipe.preparation_time_minutes(),
             calculate_cost_from_ingredients: recipe.calculate_cost_from_ingredients(),
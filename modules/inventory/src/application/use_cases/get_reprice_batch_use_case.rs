@@ -0,0 +1,42 @@
+// GetRepriceBatchUseCase - retrieves a batch reprice by ID with its lines
+
+use std::sync::Arc;
+
+use uuid::Uuid;
+
+use crate::InventoryError;
+use crate::application::dtos::responses::RepriceBatchResponse;
+use crate::domain::repositories::RepriceBatchRepository;
+use crate::domain::value_objects::RepriceBatchId;
+
+/// Use case for getting a batch reprice by ID
+pub struct GetRepriceBatchUseCase<B>
+where
+    B: RepriceBatchRepository,
+{
+    batch_repo: Arc<B>,
+}
+
+impl<B> GetRepriceBatchUseCase<B>
+where
+    B: RepriceBatchRepository,
+{
+    /// Creates a new instance of GetRepriceBatchUseCase
+    pub fn new(batch_repo: Arc<B>) -> Self {
+        Self { batch_repo }
+    }
+
+    /// Executes the use case to get a batch by ID
+    ///
+    /// # Errors
+    /// * `InventoryError::RepriceBatchNotFound` - If the batch doesn't exist
+    pub async fn execute(&self, batch_id: Uuid) -> Result<RepriceBatchResponse, InventoryError> {
+        let batch = self
+            .batch_repo
+            .find_by_id(RepriceBatchId::from_uuid(batch_id))
+            .await?
+            .ok_or(InventoryError::RepriceBatchNotFound(batch_id))?;
+
+        Ok(RepriceBatchResponse::from(&batch))
+    }
+}
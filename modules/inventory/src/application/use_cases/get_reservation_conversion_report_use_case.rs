@@ -0,0 +1,320 @@
+// GetReservationConversionReportUseCase - how many reservations convert to sales vs expire
+
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use chrono::{DateTime, NaiveDate, Utc};
+use identity::StoreId;
+use rust_decimal::Decimal;
+use uuid::Uuid;
+
+use crate::InventoryError;
+use crate::application::dtos::responses::{
+    ReservationChannelConversion, ReservationConversionReportResponse, ReservationConversionStats,
+    ReservationDayConversion,
+};
+use crate::domain::repositories::{ReservationRepository, ReservationStatusCount};
+use crate::domain::value_objects::ReservationStatus;
+
+/// Query parameters for the reservation conversion report
+#[derive(Debug, Clone)]
+pub struct ReservationConversionQuery {
+    /// Store whose reservations are reported
+    pub store_id: Uuid,
+    /// Include reservations created from this date (inclusive)
+    pub from_date: DateTime<Utc>,
+    /// Include reservations created up to this date (inclusive)
+    pub to_date: DateTime<Utc>,
+    /// Also bucket the counts by creation day (UTC)
+    pub group_by_day: bool,
+}
+
+/// Use case for the reservation conversion (hold-to-sale) report.
+///
+/// Reservations created in the period are counted by their current status:
+/// confirmed holds were converted into a sale, expired and cancelled ones
+/// released their stock. The breakdown by reference type separates the
+/// channels holding stock (e.g. e-commerce carts vs orders).
+pub struct GetReservationConversionReportUseCase<R>
+where
+    R: ReservationRepository,
+{
+    reservation_repo: Arc<R>,
+}
+
+impl<R> GetReservationConversionReportUseCase<R>
+where
+    R: ReservationRepository,
+{
+    pub fn new(reservation_repo: Arc<R>) -> Self {
+        Self { reservation_repo }
+    }
+
+    /// Executes the use case to generate the conversion report
+    ///
+    /// # Returns
+    /// ReservationConversionReportResponse with totals, per-channel and
+    /// (optionally) per-day conversion
+    pub async fn execute(
+        &self,
+        query: ReservationConversionQuery,
+    ) -> Result<ReservationConversionReportResponse, InventoryError> {
+        if query.from_date > query.to_date {
+            return Err(InventoryError::InvalidOperation(
+                "from_date must not be after to_date".to_string(),
+            ));
+        }
+
+        let counts = self
+            .reservation_repo
+            .count_by_status(
+                StoreId::from_uuid(query.store_id),
+                query.from_date,
+                query.to_date,
+                query.group_by_day,
+            )
+            .await?;
+
+        let mut totals = ReservationConversionStats::default();
+        let mut by_channel: BTreeMap<String, ReservationConversionStats> = BTreeMap::new();
+        let mut by_day: BTreeMap<NaiveDate, ReservationConversionStats> = BTreeMap::new();
+
+        for count in &counts {
+            add_count(&mut totals, count);
+            add_count(
+                by_channel.entry(count.reference_type.clone()).or_default(),
+                count,
+            );
+            if let Some(day) = count.day {
+                add_count(by_day.entry(day).or_default(), count);
+            }
+        }
+
+        Ok(ReservationConversionReportResponse {
+            store_id: query.store_id,
+            from_date: query.from_date,
+            to_date: query.to_date,
+            totals: with_rate(totals),
+            by_channel: by_channel
+                .into_iter()
+                .map(|(reference_type, stats)| ReservationChannelConversion {
+                    reference_type,
+                    stats: with_rate(stats),
+                })
+                .collect(),
+            by_day: by_day
+                .into_iter()
+                .map(|(day, stats)| ReservationDayConversion {
+                    day,
+                    stats: with_rate(stats),
+                })
+                .collect(),
+            generated_at: Utc::now(),
+        })
+    }
+}
+
+fn add_count(stats: &mut ReservationConversionStats, count: &ReservationStatusCount) {
+    stats.created += count.reservations;
+    stats.quantity_reserved += count.quantity;
+    match count.status {
+        ReservationStatus::Confirmed => {
+            stats.confirmed += count.reservations;
+            stats.quantity_converted += count.quantity;
+        }
+        ReservationStatus::Expired => stats.expired += count.reservations,
+        ReservationStatus::Cancelled => stats.cancelled += count.reservations,
+        ReservationStatus::Pending => stats.pending += count.reservations,
+    }
+}
+
+/// Fills in the conversion rate; None when no reservation is resolved yet
+fn with_rate(mut stats: ReservationConversionStats) -> ReservationConversionStats {
+    let resolved = stats.confirmed + stats.expired + stats.cancelled;
+    stats.conversion_rate = (resolved > 0).then(|| {
+        (Decimal::from(stats.confirmed) * Decimal::ONE_HUNDRED / Decimal::from(resolved))
+            .round_dp(2)
+    });
+    stats
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::entities::InventoryReservation;
+    use crate::domain::value_objects::{ReservationId, StockId};
+    use async_trait::async_trait;
+    use chrono::Duration;
+    use rust_decimal_macros::dec;
+
+    struct MockReservationRepository {
+        counts: Vec<ReservationStatusCount>,
+    }
+
+    #[async_trait]
+    impl ReservationRepository for MockReservationRepository {
+        async fn save(&self, _reservation: &InventoryReservation) -> Result<(), InventoryError> {
+            unimplemented!()
+        }
+
+        async fn find_by_id(
+            &self,
+            _id: ReservationId,
+        ) -> Result<Option<InventoryReservation>, InventoryError> {
+            unimplemented!()
+        }
+
+        async fn find_by_stock_id(
+            &self,
+            _stock_id: StockId,
+        ) -> Result<Vec<InventoryReservation>, InventoryError> {
+            unimplemented!()
+        }
+
+        async fn find_by_reference(
+            &self,
+            _reference_type: &str,
+            _reference_id: Uuid,
+        ) -> Result<Vec<InventoryReservation>, InventoryError> {
+            unimplemented!()
+        }
+
+        async fn find_expired(&self) -> Result<Vec<InventoryReservation>, InventoryError> {
+            unimplemented!()
+        }
+
+        async fn update(&self, _reservation: &InventoryReservation) -> Result<(), InventoryError> {
+            unimplemented!()
+        }
+
+        async fn delete(&self, _id: ReservationId) -> Result<(), InventoryError> {
+            unimplemented!()
+        }
+
+        async fn find_paginated(
+            &self,
+            _store_ids: Option<&[StoreId]>,
+            _stock_id: Option<StockId>,
+            _status: Option<&str>,
+            _reference_type: Option<&str>,
+            _after_id: Option<Uuid>,
+            _page: i64,
+            _page_size: i64,
+        ) -> Result<(Vec<InventoryReservation>, i64), InventoryError> {
+            unimplemented!()
+        }
+
+        async fn count_by_status(
+            &self,
+            _store_id: StoreId,
+            _from: DateTime<Utc>,
+            _to: DateTime<Utc>,
+            by_day: bool,
+        ) -> Result<Vec<ReservationStatusCount>, InventoryError> {
+            Ok(self
+                .counts
+                .iter()
+                .cloned()
+                .map(|mut c| {
+                    if !by_day {
+                        c.day = None;
+                    }
+                    c
+                })
+                .collect())
+        }
+    }
+
+    fn count(
+        day: u32,
+        reference_type: &str,
+        status: ReservationStatus,
+        reservations: i64,
+        quantity: Decimal,
+    ) -> ReservationStatusCount {
+        ReservationStatusCount {
+            day: NaiveDate::from_ymd_opt(2026, 10, day),
+            reference_type: reference_type.to_string(),
+            status,
+            reservations,
+            quantity,
+        }
+    }
+
+    fn query(group_by_day: bool) -> ReservationConversionQuery {
+        let to_date = Utc::now();
+        ReservationConversionQuery {
+            store_id: Uuid::now_v7(),
+            from_date: to_date - Duration::days(7),
+            to_date,
+            group_by_day,
+        }
+    }
+
+    fn use_case() -> GetReservationConversionReportUseCase<MockReservationRepository> {
+        GetReservationConversionReportUseCase::new(Arc::new(MockReservationRepository {
+            counts: vec![
+                count(1, "cart", ReservationStatus::Confirmed, 6, dec!(12)),
+                count(1, "cart", ReservationStatus::Expired, 3, dec!(5)),
+                count(1, "order", ReservationStatus::Confirmed, 2, dec!(2)),
+                count(2, "cart", ReservationStatus::Cancelled, 1, dec!(1)),
+                count(2, "cart", ReservationStatus::Pending, 4, dec!(4)),
+            ],
+        }))
+    }
+
+    #[tokio::test]
+    async fn test_totals_and_conversion_rate() {
+        let report = use_case().execute(query(false)).await.unwrap();
+
+        assert_eq!(report.totals.created, 16);
+        assert_eq!(report.totals.confirmed, 8);
+        assert_eq!(report.totals.expired, 3);
+        assert_eq!(report.totals.cancelled, 1);
+        assert_eq!(report.totals.pending, 4);
+        assert_eq!(report.totals.quantity_reserved, dec!(24));
+        assert_eq!(report.totals.quantity_converted, dec!(14));
+        // 8 of 12 resolved reservations were confirmed
+        assert_eq!(report.totals.conversion_rate, Some(dec!(66.67)));
+        assert!(report.by_day.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_breakdown_by_channel_and_day() {
+        let report = use_case().execute(query(true)).await.unwrap();
+
+        let channels: Vec<&str> = report
+            .by_channel
+            .iter()
+            .map(|c| c.reference_type.as_str())
+            .collect();
+        assert_eq!(channels, vec!["cart", "order"]);
+        assert_eq!(report.by_channel[1].stats.conversion_rate, Some(dec!(100)));
+
+        assert_eq!(report.by_day.len(), 2);
+        assert_eq!(report.by_day[0].stats.created, 11);
+        // Only pending and cancelled holds on day two
+        assert_eq!(report.by_day[1].stats.conversion_rate, Some(dec!(0)));
+    }
+
+    #[tokio::test]
+    async fn test_no_resolved_reservations_has_no_rate() {
+        let use_case =
+            GetReservationConversionReportUseCase::new(Arc::new(MockReservationRepository {
+                counts: vec![count(1, "cart", ReservationStatus::Pending, 2, dec!(2))],
+            }));
+        let report = use_case.execute(query(false)).await.unwrap();
+
+        assert_eq!(report.totals.created, 2);
+        assert_eq!(report.totals.conversion_rate, None);
+    }
+
+    #[tokio::test]
+    async fn test_invalid_date_range() {
+        let mut q = query(false);
+        std::mem::swap(&mut q.from_date, &mut q.to_date);
+
+        let result = use_case().execute(q).await;
+        assert!(matches!(result, Err(InventoryError::InvalidOperation(_))));
+    }
+}
@@ -62,6 +62,7 @@ where
                     id: p.id().into_uuid(),
                     sku: p.sku().to_string(),
                     barcode: p.barcode().map(|b| b.to_string()),
+                    barcode_generated: p.barcode_generated(),
                     name: p.name().to_string(),
                     description: p.description().map(|s| s.to_string()),
                     category_id: p.category_id().map(|id| id.into_uuid()),
@@ -75,6 +76,10 @@ where
                     has_variants: p.has_variants(),
                     tax_rate: p.tax_rate(),
                     tax_included: p.tax_included(),
+                    tax_category: p.tax_category().map(|t| t.to_string()),
+                    is_serialized: p.is_serialized(),
+                    markup_type: p.markup_rule().map(|r| r.markup_type().to_string()),
+                    markup_percent: p.markup_rule().and_then(|r| r.percent()),
                     is_active: p.is_active(),
                     created_at: p.created_at(),
                     updated_at: p.updated_at(),
@@ -93,6 +98,7 @@ where
                     product_id: v.product_id().into_uuid(),
                     sku: v.sku().to_string(),
                     barcode: v.barcode().map(|b| b.to_string()),
+                    barcode_generated: v.barcode_generated(),
                     name: v.name().to_string(),
                     variant_attributes: v.variant_attributes().clone(),
                     price: v.price(),
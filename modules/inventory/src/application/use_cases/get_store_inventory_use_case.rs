@@ -5,9 +5,11 @@ use std::sync::Arc;
 use crate::InventoryError;
 use crate::application::dtos::responses::{ListResponse, StockResponse};
 use crate::domain::repositories::InventoryStockRepository;
-use identity::StoreId;
+use identity::{StoreId, StoreScope, UserContext};
 
 /// Use case for getting all inventory for a specific store
+///
+/// A store the caller is not assigned to is rejected.
 pub struct GetStoreInventoryUseCase<S>
 where
     S: InventoryStockRepository,
@@ -28,13 +30,22 @@ where
     ///
     /// # Arguments
     /// * `store_id` - The UUID of the store
+    /// * `ctx` - The caller, who must be assigned to the store
     ///
     /// # Returns
     /// ListResponse containing stock records for the store
+    ///
+    /// # Errors
+    /// * `InventoryError::StoreAccessDenied` - If the store is not assigned to
+    ///   the caller
     pub async fn execute(
         &self,
         store_id: uuid::Uuid,
+        ctx: &UserContext,
     ) -> Result<ListResponse<StockResponse>, InventoryError> {
+        if let StoreScope::Denied(store_id) = ctx.store_scope(Some(store_id)) {
+            return Err(InventoryError::StoreAccessDenied(store_id));
+        }
         let store_id_vo = StoreId::from_uuid(store_id);
 
         // Find all stock for this store
@@ -45,7 +56,7 @@ where
             .into_iter()
             .map(|s| {
                 let available = s.available_quantity();
-                let is_low = available <= s.min_stock_level();
+                let is_low = s.is_low_stock();
                 StockResponse {
                     id: s.id().into_uuid(),
                     store_id: *s.store_id().as_uuid(),
@@ -57,6 +68,9 @@ where
                     version: s.version(),
                     min_stock_level: s.min_stock_level(),
                     max_stock_level: s.max_stock_level(),
+                    reorder_point: s.reorder_point(),
+                    reorder_quantity: s.reorder_quantity(),
+                    suggested_reorder_quantity: is_low.then(|| s.suggested_reorder_quantity()),
                     is_low_stock: is_low,
                     created_at: s.created_at(),
                     updated_at: s.updated_at(),
@@ -7,8 +7,12 @@ use crate::application::dtos::responses::{TransferDetailResponse, TransferItemRe
 use crate::domain::entities::StockTransfer;
 use crate::domain::repositories::TransferRepository;
 use crate::domain::value_objects::TransferId;
+use identity::UserContext;
 
 /// Use case for getting a single stock transfer by ID
+///
+/// A transfer is visible from either of its stores. Transfers between stores
+/// the caller is not assigned to are reported as not found.
 pub struct GetTransferUseCase<T>
 where
     T: TransferRepository,
@@ -24,12 +28,23 @@ where
         Self { transfer_repo }
     }
 
-    pub async fn execute(&self, id: uuid::Uuid) -> Result<TransferDetailResponse, InventoryError> {
+    /// # Errors
+    /// * `InventoryError::TransferNotFound` - If the transfer doesn't exist or
+    ///   neither of its stores is assigned to the caller
+    pub async fn execute(
+        &self,
+        id: uuid::Uuid,
+        ctx: &UserContext,
+    ) -> Result<TransferDetailResponse, InventoryError> {
         let transfer_id = TransferId::from_uuid(id);
         let transfer = self
             .transfer_repo
             .find_by_id_with_items(transfer_id)
             .await?
+            .filter(|t| {
+                ctx.can_access_store(t.from_store_id().into_uuid())
+                    || ctx.can_access_store(t.to_store_id().into_uuid())
+            })
             .ok_or(InventoryError::TransferNotFound(id))?;
 
         Ok(Self::to_response(&transfer))
@@ -49,6 +64,7 @@ where
                 quantity_requested: item.quantity_requested(),
                 quantity_shipped: item.quantity_shipped(),
                 quantity_received: item.quantity_received(),
+                quantity_discrepancy: item.shipping_discrepancy(),
                 unit_cost: item.unit_cost(),
                 notes: item.notes().map(|s| s.to_string()),
                 created_at: item.created_at(),
@@ -70,6 +86,11 @@ where
             notes: transfer.notes().map(|s| s.to_string()),
             shipping_method: transfer.shipping_method().map(|s| s.to_string()),
             tracking_number: transfer.tracking_number().map(|s| s.to_string()),
+            requires_approval: transfer.requires_approval(),
+            approval_reason: transfer.approval_reason().map(|s| s.to_string()),
+            approved_by_id: transfer.approved_by_id().map(|id| id.into_uuid()),
+            approved_at: transfer.approved_at(),
+            approval_notes: transfer.approval_notes().map(|s| s.to_string()),
             items,
             created_at: transfer.created_at(),
             updated_at: transfer.updated_at(),
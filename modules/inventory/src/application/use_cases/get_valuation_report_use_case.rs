@@ -8,9 +8,14 @@ use uuid::Uuid;
 
 use crate::InventoryError;
 use crate::application::dtos::responses::{ValuationItemResponse, ValuationReportResponse};
+use crate::application::use_cases::in_transit_items;
+use crate::domain::entities::InventoryStock;
 use crate::domain::repositories::{
-    InventoryMovementRepository, InventoryStockRepository, ProductRepository,
+    CostLayer, InventoryMovementRepository, InventoryStockRepository, ProductRepository,
+    TransferRepository,
 };
+use crate::domain::value_objects::CostingMethod;
+use identity::StoreSettings;
 
 /// Query parameters for valuation report
 #[derive(Debug, Clone)]
@@ -22,28 +27,44 @@ pub struct ValuationReportQuery {
 }
 
 /// Use case for generating inventory valuation report
-pub struct GetValuationReportUseCase<S, M, P>
+///
+/// On-hand stock is valued by the costing method of each product's category:
+/// weighted average from the kardex, or the open cost layers for FIFO/LIFO.
+///
+/// Besides on-hand stock, the report values stock shipped on transfers but
+/// not yet received, attributed to its owner store (see
+/// `StoreSettings::transit_owned_by_destination`).
+pub struct GetValuationReportUseCase<S, M, P, T>
 where
     S: InventoryStockRepository,
     M: InventoryMovementRepository,
     P: ProductRepository,
+    T: TransferRepository,
 {
     stock_repo: Arc<S>,
     movement_repo: Arc<M>,
     product_repo: Arc<P>,
+    transfer_repo: Arc<T>,
 }
 
-impl<S, M, P> GetValuationReportUseCase<S, M, P>
+impl<S, M, P, T> GetValuationReportUseCase<S, M, P, T>
 where
     S: InventoryStockRepository,
     M: InventoryMovementRepository,
     P: ProductRepository,
+    T: TransferRepository,
 {
-    pub fn new(stock_repo: Arc<S>, movement_repo: Arc<M>, product_repo: Arc<P>) -> Self {
+    pub fn new(
+        stock_repo: Arc<S>,
+        movement_repo: Arc<M>,
+        product_repo: Arc<P>,
+        transfer_repo: Arc<T>,
+    ) -> Self {
         Self {
             stock_repo,
             movement_repo,
             product_repo,
+            transfer_repo,
         }
     }
 
@@ -51,97 +72,225 @@ where
     ///
     /// # Arguments
     /// * `query` - Query parameters including optional store filter
+    /// * `settings` - Store settings deciding who owns in-transit stock
     ///
     /// # Returns
     /// ValuationReportResponse with stock values and totals
     pub async fn execute(
         &self,
         query: ValuationReportQuery,
+        settings: &[StoreSettings],
     ) -> Result<ValuationReportResponse, InventoryError> {
         let currency = query.currency.unwrap_or_else(|| "HNL".to_string());
 
-        // Get all stock records (optionally filtered by store)
-        let stocks = if let Some(store_id) = query.store_id {
-            self.stock_repo.find_by_store(store_id.into()).await?
-        } else {
-            self.stock_repo.find_all().await?
-        };
+        let items = value_on_hand(
+            self.stock_repo.as_ref(),
+            self.movement_repo.as_ref(),
+            self.product_repo.as_ref(),
+            query.store_id,
+            &currency,
+        )
+        .await?;
+        let on_hand_value: Decimal = items.iter().map(|i| i.total_value).sum();
 
-        let mut items = Vec::new();
-        let mut total_value = Decimal::ZERO;
-
-        for stock in stocks {
-            // Skip stocks with zero quantity
-            if stock.quantity() <= Decimal::ZERO {
-                continue;
-            }
-
-            // Get weighted average cost for this stock
-            let unit_cost = self
-                .movement_repo
-                .calculate_weighted_average_cost(stock.id())
-                .await?
-                .unwrap_or(Decimal::ZERO);
-
-            let item_total_value = stock.quantity() * unit_cost;
-            total_value += item_total_value;
-
-            // Get product name and SKU
-            let (product_name, variant_name, sku) = self.get_product_info(&stock).await?;
-
-            items.push(ValuationItemResponse {
-                stock_id: stock.id().into_uuid(),
-                store_id: *stock.store_id().as_uuid(),
-                product_id: stock.product_id().map(|id| id.into_uuid()),
-                variant_id: stock.variant_id().map(|id| id.into_uuid()),
-                product_name,
-                variant_name,
-                sku,
-                quantity: stock.quantity(),
-                unit_cost,
-                total_value: item_total_value,
-                currency: currency.clone(),
-            });
-        }
+        // Stock shipped but not yet received counts toward its owner store
+        let transfers = self.transfer_repo.find_in_transit().await?;
+        let in_transit_items: Vec<_> = in_transit_items(&transfers, settings)
+            .into_iter()
+            .filter(|i| query.store_id.is_none_or(|s| i.owner_store_id == s))
+            .collect();
+        let in_transit_value: Decimal = in_transit_items.iter().map(|i| i.total_value).sum();
 
         Ok(ValuationReportResponse {
             items: items.clone(),
+            in_transit_items,
             total_items: items.len() as i64,
-            total_value,
+            on_hand_value,
+            in_transit_value,
+            total_value: on_hand_value + in_transit_value,
             currency,
             generated_at: Utc::now(),
         })
     }
+}
 
-    async fn get_product_info(
-        &self,
-        stock: &crate::domain::entities::InventoryStock,
-    ) -> Result<(Option<String>, Option<String>, Option<String>), InventoryError> {
-        let mut product_name = None;
-        let mut variant_name = None;
-        let mut sku = None;
-
-        if let Some(product_id) = stock.product_id()
-            && let Some(product) = self.product_repo.find_by_id(product_id).await?
+/// Values the stock on hand, of one store or of all stores, skipping records
+/// without stock.
+///
+/// Each record is valued by the costing method of its product's category:
+/// weighted average from the kardex, or the open cost layers for FIFO/LIFO.
+pub(crate) async fn value_on_hand<S, M, P>(
+    stock_repo: &S,
+    movement_repo: &M,
+    product_repo: &P,
+    store_id: Option<Uuid>,
+    currency: &str,
+) -> Result<Vec<ValuationItemResponse>, InventoryError>
+where
+    S: InventoryStockRepository,
+    M: InventoryMovementRepository,
+    P: ProductRepository,
+{
+    let stocks = if let Some(store_id) = store_id {
+        stock_repo.find_by_store(store_id.into()).await?
+    } else {
+        stock_repo.find_all().await?
+    };
+
+    let mut items = Vec::new();
+
+    for stock in stocks {
+        // Skip stocks with zero quantity
+        if stock.quantity() <= Decimal::ZERO {
+            continue;
+        }
+
+        // Get weighted average cost for this stock
+        let average_cost = movement_repo
+            .calculate_weighted_average_cost(stock.id())
+            .await?
+            .unwrap_or(Decimal::ZERO);
+
+        let costing_method = movement_repo.find_costing_method(stock.id()).await?;
+        let (unit_cost, item_total_value) = if costing_method.uses_layers() {
+            let layers = movement_repo.find_open_cost_layers(stock.id()).await?;
+            let total = value_from_layers(costing_method, &layers, stock.quantity(), average_cost);
+            ((total / stock.quantity()).round_dp(4), total)
+        } else {
+            (average_cost, stock.quantity() * average_cost)
+        };
+
+        // Get product name and SKU
+        let (product_name, variant_name, sku) = product_info(product_repo, &stock).await?;
+
+        items.push(ValuationItemResponse {
+            stock_id: stock.id().into_uuid(),
+            store_id: *stock.store_id().as_uuid(),
+            product_id: stock.product_id().map(|id| id.into_uuid()),
+            variant_id: stock.variant_id().map(|id| id.into_uuid()),
+            product_name,
+            variant_name,
+            sku,
+            quantity: stock.quantity(),
+            costing_method: costing_method.to_string(),
+            unit_cost,
+            total_value: item_total_value,
+            currency: currency.to_string(),
+        });
+    }
+
+    Ok(items)
+}
+
+pub(crate) async fn product_info<P: ProductRepository>(
+    product_repo: &P,
+    stock: &InventoryStock,
+) -> Result<(Option<String>, Option<String>, Option<String>), InventoryError> {
+    let mut product_name = None;
+    let mut variant_name = None;
+    let mut sku = None;
+
+    if let Some(product_id) = stock.product_id()
+        && let Some(product) = product_repo.find_by_id(product_id).await?
+    {
+        product_name = Some(product.name().to_string());
+        sku = Some(product.sku().to_string());
+    }
+
+    if let Some(variant_id) = stock.variant_id()
+        && let Some(variant) = product_repo.find_variant_by_id(variant_id).await?
+    {
+        variant_name = Some(variant.name().to_string());
+        sku = Some(variant.sku().to_string());
+
+        // Also get parent product name if not already set
+        if product_name.is_none()
+            && let Some(product) = product_repo.find_by_id(variant.product_id()).await?
         {
             product_name = Some(product.name().to_string());
-            sku = Some(product.sku().to_string());
         }
+    }
 
-        if let Some(variant_id) = stock.variant_id()
-            && let Some(variant) = self.product_repo.find_variant_by_id(variant_id).await?
-        {
-            variant_name = Some(variant.name().to_string());
-            sku = Some(variant.sku().to_string());
-
-            // Also get parent product name if not already set
-            if product_name.is_none()
-                && let Some(product) = self.product_repo.find_by_id(variant.product_id()).await?
-            {
-                product_name = Some(product.name().to_string());
-            }
+    Ok((product_name, variant_name, sku))
+}
+/// Values `quantity` units on hand from the open cost layers of a stock
+/// record, given oldest first.
+///
+/// Sales leave FIFO stock holding the newest layers and LIFO stock holding
+/// the oldest, so if the layers hold more than is on hand the excess is
+/// dropped from the end that would have been consumed. Units no layer
+/// covers, e.g. stock received before the category switched method, are
+/// priced at `fallback_cost`.
+fn value_from_layers(
+    method: CostingMethod,
+    layers: &[CostLayer],
+    quantity: Decimal,
+    fallback_cost: Decimal,
+) -> Decimal {
+    let mut held: Vec<&CostLayer> = layers.iter().collect();
+    if method.consumes_oldest_first() {
+        held.reverse();
+    }
+
+    let mut uncovered = quantity;
+    let mut value = Decimal::ZERO;
+    for layer in held {
+        if uncovered <= Decimal::ZERO {
+            break;
+        }
+        let taken = layer.remaining_quantity.min(uncovered);
+        value += taken * layer.unit_cost;
+        uncovered -= taken;
+    }
+
+    value + uncovered.max(Decimal::ZERO) * fallback_cost
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::value_objects::MovementId;
+    use rust_decimal_macros::dec;
+
+    fn layer(unit_cost: Decimal, remaining_quantity: Decimal) -> CostLayer {
+        CostLayer {
+            movement_id: MovementId::new(),
+            unit_cost,
+            remaining_quantity,
+            received_at: Utc::now(),
         }
+    }
+
+    #[test]
+    fn test_value_from_layers_sums_open_layers() {
+        let layers = [layer(dec!(10), dec!(4)), layer(dec!(12), dec!(6))];
+
+        let value = value_from_layers(CostingMethod::Fifo, &layers, dec!(10), dec!(11));
+
+        assert_eq!(value, dec!(112));
+    }
+
+    #[test]
+    fn test_value_from_layers_drops_excess_by_method() {
+        let layers = [layer(dec!(10), dec!(5)), layer(dec!(12), dec!(5))];
+
+        // FIFO stock holds the newest layer, LIFO stock the oldest
+        assert_eq!(
+            value_from_layers(CostingMethod::Fifo, &layers, dec!(5), dec!(0)),
+            dec!(60)
+        );
+        assert_eq!(
+            value_from_layers(CostingMethod::Lifo, &layers, dec!(5), dec!(0)),
+            dec!(50)
+        );
+    }
+
+    #[test]
+    fn test_value_from_layers_prices_uncovered_units_at_fallback() {
+        let layers = [layer(dec!(10), dec!(2))];
+
+        let value = value_from_layers(CostingMethod::Lifo, &layers, dec!(5), dec!(8));
 
-        Ok((product_name, variant_name, sku))
+        assert_eq!(value, dec!(44));
     }
 }
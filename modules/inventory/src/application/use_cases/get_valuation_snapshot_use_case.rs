@@ -0,0 +1,139 @@
+// GetValuationSnapshotUseCase - retrieves a recorded period-end valuation
+
+use std::sync::Arc;
+
+use chrono::NaiveDate;
+use identity::StoreId;
+use uuid::Uuid;
+
+use crate::InventoryError;
+use crate::application::dtos::responses::ValuationSnapshotResponse;
+use crate::domain::repositories::ValuationSnapshotRepository;
+
+/// Use case for retrieving the valuation snapshot of a store for a period
+pub struct GetValuationSnapshotUseCase<V>
+where
+    V: ValuationSnapshotRepository,
+{
+    snapshot_repo: Arc<V>,
+}
+
+impl<V> GetValuationSnapshotUseCase<V>
+where
+    V: ValuationSnapshotRepository,
+{
+    /// Creates a new instance of GetValuationSnapshotUseCase
+    pub fn new(snapshot_repo: Arc<V>) -> Self {
+        Self { snapshot_repo }
+    }
+
+    /// Executes the use case to get a snapshot
+    ///
+    /// # Errors
+    /// * `InventoryError::ValuationSnapshotNotFound` - If the store has no
+    ///   snapshot for `period_end`
+    pub async fn execute(
+        &self,
+        store_id: Uuid,
+        period_end: NaiveDate,
+    ) -> Result<ValuationSnapshotResponse, InventoryError> {
+        let snapshot = self
+            .snapshot_repo
+            .find_by_store_and_period(StoreId::from_uuid(store_id), period_end)
+            .await?
+            .ok_or(InventoryError::ValuationSnapshotNotFound(
+                store_id, period_end,
+            ))?;
+
+        Ok(ValuationSnapshotResponse::from(&snapshot))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use async_trait::async_trait;
+    use rust_decimal::Decimal;
+    use rust_decimal_macros::dec;
+    use std::sync::Mutex;
+
+    use crate::domain::entities::{ValuationSnapshot, ValuationSnapshotLine};
+    use crate::domain::value_objects::{ProductId, StockId};
+    use identity::UserId;
+
+    struct MockValuationSnapshotRepository {
+        snapshots: Mutex<Vec<ValuationSnapshot>>,
+    }
+
+    #[async_trait]
+    impl ValuationSnapshotRepository for MockValuationSnapshotRepository {
+        async fn save(&self, snapshot: &ValuationSnapshot) -> Result<bool, InventoryError> {
+            self.snapshots.lock().unwrap().push(snapshot.clone());
+            Ok(true)
+        }
+
+        async fn find_by_store_and_period(
+            &self,
+            store_id: StoreId,
+            period_end: NaiveDate,
+        ) -> Result<Option<ValuationSnapshot>, InventoryError> {
+            Ok(self
+                .snapshots
+                .lock()
+                .unwrap()
+                .iter()
+                .find(|s| s.store_id() == store_id && s.period_end() == period_end)
+                .cloned())
+        }
+    }
+
+    fn line(quantity: Decimal, unit_cost: Decimal) -> ValuationSnapshotLine {
+        ValuationSnapshotLine {
+            stock_id: StockId::new(),
+            product_id: Some(ProductId::new()),
+            variant_id: None,
+            product_name: Some("Coffee".to_string()),
+            variant_name: None,
+            sku: Some("PRD-GEN-1-AAAA".to_string()),
+            quantity,
+            costing_method: "weighted_average".to_string(),
+            unit_cost,
+            total_value: quantity * unit_cost,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_get_snapshot_for_period() {
+        let store_id = StoreId::new();
+        let period_end = NaiveDate::from_ymd_opt(2026, 9, 30).unwrap();
+        let repo = Arc::new(MockValuationSnapshotRepository {
+            snapshots: Mutex::new(vec![ValuationSnapshot::record(
+                store_id,
+                period_end,
+                "HNL".to_string(),
+                vec![line(dec!(10), dec!(2.50)), line(dec!(4), dec!(10))],
+                UserId::new(),
+            )]),
+        });
+        let use_case = GetValuationSnapshotUseCase::new(repo);
+
+        let response = use_case
+            .execute(store_id.into_uuid(), period_end)
+            .await
+            .unwrap();
+        assert_eq!(response.total_items, 2);
+        assert_eq!(response.total_value, dec!(65));
+        assert_eq!(response.items[0].store_id, store_id.into_uuid());
+
+        let missing = use_case
+            .execute(
+                store_id.into_uuid(),
+                NaiveDate::from_ymd_opt(2026, 8, 31).unwrap(),
+            )
+            .await;
+        assert!(matches!(
+            missing,
+            Err(InventoryError::ValuationSnapshotNotFound(_, _))
+        ));
+    }
+}
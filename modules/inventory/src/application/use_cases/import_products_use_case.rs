@@ -0,0 +1,749 @@
+// ImportProductsUseCase - creates or updates products from an imported catalog
+
+use std::collections::{HashMap, HashSet};
+use std::str::FromStr;
+use std::sync::Arc;
+
+use rust_decimal::Decimal;
+use uuid::Uuid;
+
+use crate::InventoryError;
+use crate::application::dtos::commands::{ImportProductRow, ImportProductsCommand};
+use crate::application::dtos::responses::{
+    ImportProductRowResult, ImportProductsResponse, ImportRowOutcome,
+};
+use crate::application::helpers::DEFAULT_MAX_CATEGORY_DEPTH;
+use crate::domain::entities::{Product, ProductCategory};
+use crate::domain::repositories::{
+    CategoryRepository, ProductImportBatch, ProductImportRepository, ProductRepository,
+};
+use crate::domain::value_objects::{Barcode, CategoryId, Sku, UnitOfMeasure};
+use identity::domain::entities::AuditEntry;
+use identity::domain::value_objects::UserId;
+
+/// Separator between category names in a row's `category_path`
+pub const CATEGORY_PATH_SEPARATOR: char = '/';
+
+/// Longest SKU the products table accepts
+const MAX_SKU_LEN: usize = 50;
+
+/// Longest slug the product_categories table accepts
+const MAX_SLUG_LEN: usize = 100;
+
+/// Rows accepted so far, and what they will write
+#[derive(Default)]
+struct ImportPlan {
+    batch: ProductImportBatch,
+    /// Lower-cased category path to the category it names, existing or new
+    categories: HashMap<Vec<String>, CategoryId>,
+    /// Slugs taken by categories created in this import
+    slugs: HashSet<String>,
+    skus: HashSet<String>,
+    barcodes: HashSet<String>,
+}
+
+/// Use case for importing a product catalog, e.g. when onboarding a store.
+///
+/// Rows are matched to existing products by SKU and updated; the rest are
+/// created. Categories missing from a row's path are created under the
+/// categories that do exist. A row that fails validation is reported and
+/// skipped without affecting the others. The accepted rows are then written
+/// in one transaction, so a database error fails the whole import and
+/// leaves the catalog untouched.
+pub struct ImportProductsUseCase<P, C, I>
+where
+    P: ProductRepository,
+    C: CategoryRepository,
+    I: ProductImportRepository,
+{
+    product_repo: Arc<P>,
+    category_repo: Arc<C>,
+    import_repo: Arc<I>,
+    max_category_depth: usize,
+}
+
+impl<P, C, I> ImportProductsUseCase<P, C, I>
+where
+    P: ProductRepository,
+    C: CategoryRepository,
+    I: ProductImportRepository,
+{
+    /// Creates a new instance of ImportProductsUseCase
+    pub fn new(product_repo: Arc<P>, category_repo: Arc<C>, import_repo: Arc<I>) -> Self {
+        Self {
+            product_repo,
+            category_repo,
+            import_repo,
+            max_category_depth: DEFAULT_MAX_CATEGORY_DEPTH,
+        }
+    }
+
+    pub fn with_max_category_depth(mut self, max_category_depth: usize) -> Self {
+        self.max_category_depth = max_category_depth;
+        self
+    }
+
+    /// Executes the use case to import the rows of a catalog
+    ///
+    /// # Arguments
+    /// * `command` - Parsed catalog rows
+    /// * `actor_id` - ID of the user performing this action (for audit)
+    ///
+    /// # Returns
+    /// One result per row, in request order, plus totals
+    ///
+    /// # Errors
+    /// * `InventoryError::Database` - If reading or writing the catalog fails;
+    ///   nothing is imported
+    pub async fn execute(
+        &self,
+        command: ImportProductsCommand,
+        actor_id: UserId,
+    ) -> Result<ImportProductsResponse, InventoryError> {
+        let mut plan = ImportPlan::default();
+        let mut rows = Vec::with_capacity(command.rows.len());
+
+        for (row, input) in command.rows.into_iter().enumerate() {
+            let sku = input.sku.trim().to_string();
+            match self.import_row(input, &mut plan, actor_id).await {
+                Ok((outcome, product_id)) => rows.push(ImportProductRowResult {
+                    row,
+                    sku,
+                    outcome,
+                    product_id: Some(product_id),
+                    error: None,
+                }),
+                // Not the row's fault: abort before anything is written
+                Err(e @ InventoryError::Database(_)) => return Err(e),
+                Err(e) => rows.push(ImportProductRowResult {
+                    row,
+                    sku,
+                    outcome: ImportRowOutcome::Failed,
+                    product_id: None,
+                    error: Some(e.to_string()),
+                }),
+            }
+        }
+
+        self.import_repo.apply(&plan.batch).await?;
+
+        let count = |outcome| rows.iter().filter(|r| r.outcome == outcome).count() as i64;
+        Ok(ImportProductsResponse {
+            created: count(ImportRowOutcome::Created),
+            updated: count(ImportRowOutcome::Updated),
+            failed: count(ImportRowOutcome::Failed),
+            categories_created: plan.batch.categories.len() as i64,
+            rows,
+        })
+    }
+
+    /// Validates one row and adds its writes to the plan. Nothing is added
+    /// when the row is rejected.
+    async fn import_row(
+        &self,
+        row: ImportProductRow,
+        plan: &mut ImportPlan,
+        actor_id: UserId,
+    ) -> Result<(ImportRowOutcome, Uuid), InventoryError> {
+        let name = row.name.trim();
+        if name.is_empty() {
+            return Err(InventoryError::InvalidImportRow(
+                "name is required".to_string(),
+            ));
+        }
+        let sku = row.sku.trim();
+        if sku.is_empty() || sku.chars().count() > MAX_SKU_LEN {
+            return Err(InventoryError::InvalidImportRow(format!(
+                "sku must be 1 to {} characters",
+                MAX_SKU_LEN
+            )));
+        }
+        if row.base_price < Decimal::ZERO || row.cost_price < Decimal::ZERO {
+            return Err(InventoryError::InvalidImportRow(
+                "prices cannot be negative".to_string(),
+            ));
+        }
+        let unit_of_measure = UnitOfMeasure::from_str(row.unit_of_measure.trim())?;
+        let barcode = row
+            .barcode
+            .as_deref()
+            .map(str::trim)
+            .filter(|b| !b.is_empty())
+            .map(Barcode::new)
+            .transpose()?;
+        let path = parse_category_path(row.category_path.as_deref());
+        if path.len() > self.max_category_depth {
+            return Err(InventoryError::CategoryDepthExceeded(
+                self.max_category_depth,
+            ));
+        }
+
+        if plan.skus.contains(sku) {
+            return Err(InventoryError::InvalidImportRow(format!(
+                "SKU '{}' appears more than once in the import",
+                sku
+            )));
+        }
+        if let Some(barcode) = &barcode
+            && plan.barcodes.contains(barcode.as_str())
+        {
+            return Err(InventoryError::DuplicateBarcode(barcode.to_string()));
+        }
+
+        let sku = Sku::from_string(sku.to_string());
+        let existing = self.product_repo.find_by_sku(&sku).await?;
+        if let Some(barcode) = &barcode
+            && let Some(owner) = self.product_repo.find_by_barcode(barcode).await?
+            && existing.as_ref().is_none_or(|p| p.id() != owner.id())
+        {
+            return Err(InventoryError::DuplicateBarcode(barcode.to_string()));
+        }
+
+        let category_id = if path.is_empty() {
+            None
+        } else {
+            Some(self.resolve_category(&path, plan).await?)
+        };
+
+        plan.skus.insert(sku.to_string());
+        if let Some(barcode) = &barcode {
+            plan.barcodes.insert(barcode.to_string());
+        }
+
+        let apply = |product: &mut Product| {
+            product.set_name(name.to_string());
+            product.set_unit_of_measure(unit_of_measure);
+            product.set_base_price(row.base_price);
+            product.set_cost_price(row.cost_price);
+            if let Some(barcode) = barcode.clone() {
+                product.set_barcode(Some(barcode));
+            }
+            // Rows without a path keep the product's current category
+            if category_id.is_some() {
+                product.set_category_id(category_id);
+            }
+        };
+
+        match existing {
+            Some(before) => {
+                let mut product = before.clone();
+                apply(&mut product);
+                let id = product.id().into_uuid();
+                plan.batch.audit_entries.push(AuditEntry::for_update(
+                    "product", id, &before, &product, actor_id,
+                ));
+                plan.batch.updated.push(product);
+                Ok((ImportRowOutcome::Updated, id))
+            }
+            None => {
+                let mut product = Product::create_with_sku(name.to_string(), sku, unit_of_measure);
+                apply(&mut product);
+                let id = product.id().into_uuid();
+                plan.batch
+                    .audit_entries
+                    .push(AuditEntry::for_create("product", id, &product, actor_id));
+                plan.batch.created.push(product);
+                Ok((ImportRowOutcome::Created, id))
+            }
+        }
+    }
+
+    /// Finds the category a path names, planning any missing levels.
+    /// Names match existing categories case-insensitively.
+    async fn resolve_category(
+        &self,
+        path: &[String],
+        plan: &mut ImportPlan,
+    ) -> Result<CategoryId, InventoryError> {
+        let mut parent: Option<CategoryId> = None;
+        for depth in 0..path.len() {
+            let key: Vec<String> = path[..=depth].iter().map(|s| s.to_lowercase()).collect();
+            if let Some(id) = plan.categories.get(&key) {
+                parent = Some(*id);
+                continue;
+            }
+
+            let name = &path[depth];
+            let siblings = match parent {
+                Some(parent_id) => self.category_repo.find_children(parent_id).await?,
+                None => self.category_repo.find_root_categories().await?,
+            };
+            let id = match siblings
+                .iter()
+                .find(|c| c.name().trim().to_lowercase() == key[depth])
+            {
+                Some(existing) => existing.id(),
+                None => {
+                    let slug = self.unique_slug(&path[..=depth], plan).await?;
+                    let category = match parent {
+                        Some(parent_id) => ProductCategory::create_subcategory(
+                            parent_id,
+                            name.clone(),
+                            slug.clone(),
+                        ),
+                        None => ProductCategory::create(name.clone(), slug.clone()),
+                    };
+                    let id = category.id();
+                    plan.slugs.insert(slug);
+                    plan.batch.categories.push(category);
+                    id
+                }
+            };
+            plan.categories.insert(key, id);
+            parent = Some(id);
+        }
+
+        parent.ok_or_else(|| InventoryError::InvalidImportRow("empty category path".to_string()))
+    }
+
+    /// Slug from the category's full path, numbered when already taken
+    async fn unique_slug(
+        &self,
+        path: &[String],
+        plan: &ImportPlan,
+    ) -> Result<String, InventoryError> {
+        let base = slugify(&path.join(" "));
+        let mut candidate = base.clone();
+        let mut n = 2;
+        while plan.slugs.contains(&candidate)
+            || self.category_repo.find_by_slug(&candidate).await?.is_some()
+        {
+            candidate = format!("{}-{}", base, n);
+            n += 1;
+        }
+        Ok(candidate)
+    }
+}
+
+/// Splits a category path into trimmed names, dropping empty segments
+fn parse_category_path(path: Option<&str>) -> Vec<String> {
+    path.map(|p| {
+        p.split(CATEGORY_PATH_SEPARATOR)
+            .map(str::trim)
+            .filter(|s| !s.is_empty())
+            .map(String::from)
+            .collect()
+    })
+    .unwrap_or_default()
+}
+
+/// Lower-case words joined by dashes, short enough to leave room for a
+/// numeric suffix
+fn slugify(text: &str) -> String {
+    let mut slug = String::new();
+    for ch in text.chars().flat_map(char::to_lowercase) {
+        if ch.is_alphanumeric() {
+            slug.push(ch);
+        } else if !slug.is_empty() && !slug.ends_with('-') {
+            slug.push('-');
+        }
+    }
+    let slug: String = slug
+        .trim_end_matches('-')
+        .chars()
+        .take(MAX_SLUG_LEN - 4)
+        .collect();
+    if slug.is_empty() {
+        "category".to_string()
+    } else {
+        slug.trim_end_matches('-').to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use async_trait::async_trait;
+    use rust_decimal_macros::dec;
+    use std::sync::Mutex;
+
+    use crate::domain::entities::{ProductVariant, ProductVendor};
+    use crate::domain::repositories::CategoryStockValuation;
+    use crate::domain::value_objects::{ProductId, UnitConversion, VariantId};
+    use identity::StoreId;
+
+    struct MockProductRepository {
+        products: Vec<Product>,
+    }
+
+    #[async_trait]
+    impl ProductRepository for MockProductRepository {
+        async fn save(&self, _product: &Product) -> Result<(), InventoryError> {
+            unimplemented!()
+        }
+
+        async fn find_by_id(&self, _id: ProductId) -> Result<Option<Product>, InventoryError> {
+            unimplemented!()
+        }
+
+        async fn find_by_sku(&self, sku: &Sku) -> Result<Option<Product>, InventoryError> {
+            Ok(self.products.iter().find(|p| p.sku() == sku).cloned())
+        }
+
+        async fn find_by_barcode(
+            &self,
+            barcode: &Barcode,
+        ) -> Result<Option<Product>, InventoryError> {
+            Ok(self
+                .products
+                .iter()
+                .find(|p| p.barcode() == Some(barcode))
+                .cloned())
+        }
+
+        async fn update(&self, _product: &Product) -> Result<(), InventoryError> {
+            unimplemented!()
+        }
+
+        async fn delete(&self, _id: ProductId) -> Result<(), InventoryError> {
+            unimplemented!()
+        }
+
+        async fn find_active(&self) -> Result<Vec<Product>, InventoryError> {
+            unimplemented!()
+        }
+
+        async fn find_by_category(
+            &self,
+            _category_id: CategoryId,
+        ) -> Result<Vec<Product>, InventoryError> {
+            unimplemented!()
+        }
+
+        async fn find_paginated(
+            &self,
+            _category_id: Option<CategoryId>,
+            _is_active: Option<bool>,
+            _search: Option<&str>,
+            _after_id: Option<Uuid>,
+            _page: i64,
+            _page_size: i64,
+        ) -> Result<(Vec<Product>, i64), InventoryError> {
+            unimplemented!()
+        }
+
+        async fn count_filtered(
+            &self,
+            _category_id: Option<CategoryId>,
+            _is_active: Option<bool>,
+            _search: Option<&str>,
+        ) -> Result<i64, InventoryError> {
+            unimplemented!()
+        }
+
+        async fn search(
+            &self,
+            _term: &str,
+            _category_id: Option<CategoryId>,
+            _active_only: bool,
+            _page: i64,
+            _page_size: i64,
+        ) -> Result<(Vec<(Product, f64)>, i64), InventoryError> {
+            unimplemented!()
+        }
+
+        async fn save_variant(&self, _variant: &ProductVariant) -> Result<(), InventoryError> {
+            unimplemented!()
+        }
+
+        async fn find_variant_by_id(
+            &self,
+            _id: VariantId,
+        ) -> Result<Option<ProductVariant>, InventoryError> {
+            unimplemented!()
+        }
+
+        async fn find_variant_by_sku(
+            &self,
+            _sku: &Sku,
+        ) -> Result<Option<ProductVariant>, InventoryError> {
+            unimplemented!()
+        }
+
+        async fn find_variant_by_barcode(
+            &self,
+            _barcode: &Barcode,
+        ) -> Result<Option<ProductVariant>, InventoryError> {
+            unimplemented!()
+        }
+
+        async fn find_variants_by_product(
+            &self,
+            _product_id: ProductId,
+        ) -> Result<Vec<ProductVariant>, InventoryError> {
+            unimplemented!()
+        }
+
+        async fn update_variant(&self, _variant: &ProductVariant) -> Result<(), InventoryError> {
+            unimplemented!()
+        }
+
+        async fn delete_variant(&self, _id: VariantId) -> Result<(), InventoryError> {
+            unimplemented!()
+        }
+
+        async fn count_variants(&self, _product_id: ProductId) -> Result<u32, InventoryError> {
+            unimplemented!()
+        }
+
+        async fn save_product_vendors(
+            &self,
+            _vendors: &[ProductVendor],
+        ) -> Result<(), InventoryError> {
+            unimplemented!()
+        }
+
+        async fn find_product_vendors(
+            &self,
+            _product_id: ProductId,
+        ) -> Result<Vec<ProductVendor>, InventoryError> {
+            unimplemented!()
+        }
+
+        async fn find_preferred_vendor(
+            &self,
+            _product_id: ProductId,
+        ) -> Result<Option<ProductVendor>, InventoryError> {
+            unimplemented!()
+        }
+
+        async fn replace_unit_conversions(
+            &self,
+            _product_id: ProductId,
+            _conversions: &[UnitConversion],
+        ) -> Result<(), InventoryError> {
+            unimplemented!()
+        }
+
+        async fn find_unit_conversions(
+            &self,
+            _product_id: ProductId,
+        ) -> Result<Vec<UnitConversion>, InventoryError> {
+            unimplemented!()
+        }
+    }
+
+    struct MockCategoryRepository {
+        categories: Vec<ProductCategory>,
+    }
+
+    #[async_trait]
+    impl CategoryRepository for MockCategoryRepository {
+        async fn save(&self, _category: &ProductCategory) -> Result<(), InventoryError> {
+            unimplemented!()
+        }
+
+        async fn find_by_id(
+            &self,
+            _id: CategoryId,
+        ) -> Result<Option<ProductCategory>, InventoryError> {
+            unimplemented!()
+        }
+
+        async fn find_by_slug(
+            &self,
+            slug: &str,
+        ) -> Result<Option<ProductCategory>, InventoryError> {
+            Ok(self.categories.iter().find(|c| c.slug() == slug).cloned())
+        }
+
+        async fn find_root_categories(&self) -> Result<Vec<ProductCategory>, InventoryError> {
+            Ok(self
+                .categories
+                .iter()
+                .filter(|c| c.parent_id().is_none())
+                .cloned()
+                .collect())
+        }
+
+        async fn find_children(
+            &self,
+            parent_id: CategoryId,
+        ) -> Result<Vec<ProductCategory>, InventoryError> {
+            Ok(self
+                .categories
+                .iter()
+                .filter(|c| c.parent_id() == Some(parent_id))
+                .cloned()
+                .collect())
+        }
+
+        async fn find_all_active(&self) -> Result<Vec<ProductCategory>, InventoryError> {
+            unimplemented!()
+        }
+
+        async fn update(&self, _category: &ProductCategory) -> Result<(), InventoryError> {
+            unimplemented!()
+        }
+
+        async fn stock_valuation_by_category(
+            &self,
+            _store_id: Option<StoreId>,
+        ) -> Result<Vec<CategoryStockValuation>, InventoryError> {
+            unimplemented!()
+        }
+
+        async fn delete(&self, _id: CategoryId) -> Result<(), InventoryError> {
+            unimplemented!()
+        }
+    }
+
+    struct MockProductImportRepository {
+        applied: Mutex<Option<ProductImportBatch>>,
+        fail: bool,
+    }
+
+    #[async_trait]
+    impl ProductImportRepository for MockProductImportRepository {
+        async fn apply(&self, batch: &ProductImportBatch) -> Result<(), InventoryError> {
+            if self.fail {
+                return Err(InventoryError::Database(sqlx::Error::PoolTimedOut));
+            }
+            *self.applied.lock().unwrap() = Some(batch.clone());
+            Ok(())
+        }
+    }
+
+    fn row(sku: &str, category_path: Option<&str>) -> ImportProductRow {
+        ImportProductRow {
+            name: format!("Product {}", sku),
+            sku: sku.to_string(),
+            barcode: None,
+            category_path: category_path.map(String::from),
+            base_price: dec!(10),
+            cost_price: dec!(6),
+            unit_of_measure: "unit".to_string(),
+        }
+    }
+
+    fn setup(
+        categories: Vec<ProductCategory>,
+        products: Vec<Product>,
+        fail: bool,
+    ) -> (
+        ImportProductsUseCase<
+            MockProductRepository,
+            MockCategoryRepository,
+            MockProductImportRepository,
+        >,
+        Arc<MockProductImportRepository>,
+    ) {
+        let import_repo = Arc::new(MockProductImportRepository {
+            applied: Mutex::new(None),
+            fail,
+        });
+        let use_case = ImportProductsUseCase::new(
+            Arc::new(MockProductRepository { products }),
+            Arc::new(MockCategoryRepository { categories }),
+            import_repo.clone(),
+        );
+        (use_case, import_repo)
+    }
+
+    #[tokio::test]
+    async fn test_creates_updates_and_reuses_categories() {
+        let beverages = ProductCategory::create("Beverages".to_string(), "beverages".to_string());
+        let existing = Product::create_with_sku(
+            "Old name".to_string(),
+            Sku::from_string("COLA-1".to_string()),
+            UnitOfMeasure::Unit,
+        );
+        let existing_id = existing.id().into_uuid();
+        let (use_case, repo) = setup(vec![beverages.clone()], vec![existing], false);
+
+        let result = use_case
+            .execute(
+                ImportProductsCommand {
+                    rows: vec![
+                        row("COLA-1", Some("beverages / Sodas")),
+                        row("COLA-2", Some("Beverages/Sodas")),
+                        row("CHIPS-1", None),
+                    ],
+                },
+                UserId::new(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!((result.created, result.updated, result.failed), (2, 1, 0));
+        assert_eq!(result.rows[0].product_id, Some(existing_id));
+        assert_eq!(result.categories_created, 1);
+
+        let batch = repo.applied.lock().unwrap().clone().unwrap();
+        let sodas = &batch.categories[0];
+        assert_eq!(sodas.name(), "Sodas");
+        assert_eq!(sodas.slug(), "beverages-sodas");
+        assert_eq!(sodas.parent_id(), Some(beverages.id()));
+        assert_eq!(batch.updated[0].name(), "Product COLA-1");
+        assert_eq!(batch.updated[0].category_id(), Some(sodas.id()));
+        assert_eq!(batch.created[0].category_id(), Some(sodas.id()));
+        assert_eq!(batch.created[1].category_id(), None);
+        assert_eq!(batch.audit_entries.len(), 3);
+    }
+
+    #[tokio::test]
+    async fn test_invalid_rows_are_reported_and_skipped() {
+        let (use_case, repo) = setup(Vec::new(), Vec::new(), false);
+
+        let mut negative = row("NEG-1", Some("Snacks"));
+        negative.cost_price = dec!(-1);
+        let mut bad_unit = row("BAD-1", None);
+        bad_unit.unit_of_measure = "crate".to_string();
+
+        let result = use_case
+            .execute(
+                ImportProductsCommand {
+                    rows: vec![negative, row("OK-1", None), bad_unit, row("OK-1", None)],
+                },
+                UserId::new(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!((result.created, result.updated, result.failed), (1, 0, 3));
+        let outcomes: Vec<_> = result.rows.iter().map(|r| r.outcome).collect();
+        assert_eq!(
+            outcomes,
+            vec![
+                ImportRowOutcome::Failed,
+                ImportRowOutcome::Created,
+                ImportRowOutcome::Failed,
+                ImportRowOutcome::Failed,
+            ]
+        );
+        assert!(
+            result.rows[3]
+                .error
+                .as_deref()
+                .unwrap()
+                .contains("more than once")
+        );
+
+        // The rejected row's category is not created
+        let batch = repo.applied.lock().unwrap().clone().unwrap();
+        assert!(batch.categories.is_empty());
+        assert_eq!(batch.created.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_database_failure_fails_the_whole_import() {
+        let (use_case, _) = setup(Vec::new(), Vec::new(), true);
+
+        let result = use_case
+            .execute(
+                ImportProductsCommand {
+                    rows: vec![row("OK-1", Some("Snacks"))],
+                },
+                UserId::new(),
+            )
+            .await;
+
+        assert!(matches!(result, Err(InventoryError::Database(_))));
+    }
+
+    #[test]
+    fn test_slugify() {
+        assert_eq!(slugify("Beverages  Hot & Cold"), "beverages-hot-cold");
+        assert_eq!(slugify("¿?"), "category");
+    }
+}
@@ -186,6 +186,11 @@ where
             version: stock.version(),
             min_stock_level: stock.min_stock_level(),
             max_stock_level: stock.max_stock_level(),
+            reorder_point: stock.reorder_point(),
+            reorder_quantity: stock.reorder_quantity(),
+            suggested_reorder_quantity: stock
+                .is_low_stock()
+                .then(|| stock.suggested_reorder_quantity()),
             is_low_stock: stock.is_low_stock(),
             created_at: stock.created_at(),
             updated_at: stock.updated_at(),
@@ -204,8 +209,8 @@ mod tests {
     use std::sync::Mutex;
     use uuid::Uuid;
 
-    use crate::domain::entities::{InventoryMovement, Product, ProductVariant};
-    use crate::domain::value_objects::{Barcode, Sku, StockId, UnitOfMeasure};
+    use crate::domain::entities::{InventoryMovement, Product, ProductVariant, ProductVendor};
+    use crate::domain::value_objects::{Barcode, Sku, StockId, UnitConversion, UnitOfMeasure};
 
     // Mock repositories
     struct MockStockRepository {
@@ -281,9 +286,10 @@ mod tests {
 
         async fn find_paginated(
             &self,
-            _store_id: Option<StoreId>,
+            _store_ids: Option<&[StoreId]>,
             _product_id: Option<ProductId>,
             _low_stock_only: bool,
+            _after_id: Option<Uuid>,
             _page: i64,
             _page_size: i64,
         ) -> Result<(Vec<InventoryStock>, i64), InventoryError> {
@@ -440,11 +446,33 @@ mod tests {
             unimplemented!()
         }
 
+        async fn save_product_vendors(
+            &self,
+            _vendors: &[ProductVendor],
+        ) -> Result<(), InventoryError> {
+            unimplemented!()
+        }
+
+        async fn find_product_vendors(
+            &self,
+            _product_id: ProductId,
+        ) -> Result<Vec<ProductVendor>, InventoryError> {
+            unimplemented!()
+        }
+
+        async fn find_preferred_vendor(
+            &self,
+            _product_id: ProductId,
+        ) -> Result<Option<ProductVendor>, InventoryError> {
+            unimplemented!()
+        }
+
         async fn find_paginated(
             &self,
             _category_id: Option<crate::domain::value_objects::CategoryId>,
             _is_active: Option<bool>,
             _search: Option<&str>,
+            _after_id: Option<Uuid>,
             _page: i64,
             _page_size: i64,
         ) -> Result<(Vec<Product>, i64), InventoryError> {
@@ -459,6 +487,32 @@ mod tests {
         ) -> Result<i64, InventoryError> {
             unimplemented!()
         }
+
+        async fn search(
+            &self,
+            _term: &str,
+            _category_id: Option<crate::domain::value_objects::CategoryId>,
+            _active_only: bool,
+            _page: i64,
+            _page_size: i64,
+        ) -> Result<(Vec<(Product, f64)>, i64), InventoryError> {
+            unimplemented!()
+        }
+
+        async fn replace_unit_conversions(
+            &self,
+            _product_id: ProductId,
+            _conversions: &[UnitConversion],
+        ) -> Result<(), InventoryError> {
+            unimplemented!()
+        }
+
+        async fn find_unit_conversions(
+            &self,
+            _product_id: ProductId,
+        ) -> Result<Vec<UnitConversion>, InventoryError> {
+            unimplemented!()
+        }
     }
 
     struct MockMovementRepository {
@@ -546,6 +600,37 @@ mod tests {
         async fn save_batch(&self, _movements: &[InventoryMovement]) -> Result<(), InventoryError> {
             unimplemented!()
         }
+
+        async fn find_all_by_stock_id_chronological(
+            &self,
+            _stock_id: StockId,
+        ) -> Result<Vec<InventoryMovement>, InventoryError> {
+            unimplemented!()
+        }
+
+        async fn rewrite_average_costs(
+            &self,
+            _stock_id: StockId,
+            _expected_version: i32,
+            _movement_costs: &[(crate::domain::value_objects::MovementId, Option<Decimal>)],
+            _average_cost: Option<Decimal>,
+        ) -> Result<crate::domain::repositories::AverageCostRewrite, InventoryError> {
+            unimplemented!()
+        }
+
+        async fn find_costing_method(
+            &self,
+            _stock_id: StockId,
+        ) -> Result<crate::domain::value_objects::CostingMethod, InventoryError> {
+            unimplemented!()
+        }
+
+        async fn find_open_cost_layers(
+            &self,
+            _stock_id: StockId,
+        ) -> Result<Vec<crate::domain::repositories::CostLayer>, InventoryError> {
+            unimplemented!()
+        }
     }
 
     struct MockAuditRepository {
@@ -583,6 +668,15 @@ mod tests {
         ) -> Result<Vec<AuditEntry>, identity::IdentityError> {
             unimplemented!()
         }
+
+        async fn find_paginated(
+            &self,
+            _filter: &identity::AuditEntryFilter,
+            _page: i64,
+            _page_size: i64,
+        ) -> Result<(Vec<AuditEntry>, i64), identity::IdentityError> {
+            unimplemented!()
+        }
     }
 
     #[tokio::test]
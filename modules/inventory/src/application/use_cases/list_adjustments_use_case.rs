@@ -2,7 +2,9 @@
 
 use std::sync::Arc;
 
-use identity::StoreId;
+use common::PageCursor;
+
+use identity::{StoreId, StoreScope, UserContext};
 
 use crate::InventoryError;
 use crate::application::dtos::responses::{AdjustmentResponse, PaginatedResponse};
@@ -15,6 +17,9 @@ pub struct ListAdjustmentsQuery {
     pub store_id: Option<uuid::Uuid>,
     /// Filter by status (draft, pending_approval, approved, rejected, applied)
     pub status: Option<String>,
+    /// Keyset cursor from a previous page's `next_cursor`; when set,
+    /// `page` is ignored
+    pub after_cursor: Option<String>,
     /// Page number (1-indexed)
     pub page: i64,
     /// Number of items per page
@@ -26,6 +31,7 @@ impl Default for ListAdjustmentsQuery {
         Self {
             store_id: None,
             status: None,
+            after_cursor: None,
             page: 1,
             page_size: 20,
         }
@@ -33,6 +39,9 @@ impl Default for ListAdjustmentsQuery {
 }
 
 /// Use case for listing adjustments with pagination and filters
+///
+/// Adjustments are limited to the caller's assigned stores; asking for a
+/// store the caller is not assigned to is rejected.
 pub struct ListAdjustmentsUseCase<A>
 where
     A: AdjustmentRepository,
@@ -53,25 +62,44 @@ where
     ///
     /// # Arguments
     /// * `query` - Query parameters with filters and pagination
+    /// * `ctx` - The caller, whose assigned stores bound the listing
     ///
     /// # Returns
     /// Paginated response with adjustments
+    ///
+    /// # Errors
+    /// * `InventoryError::StoreAccessDenied` - If the requested store is not
+    ///   assigned to the caller
     pub async fn execute(
         &self,
         query: ListAdjustmentsQuery,
+        ctx: &UserContext,
     ) -> Result<PaginatedResponse<AdjustmentResponse>, InventoryError> {
         // Validate and clamp pagination
         let page = query.page.max(1);
         let page_size = query.page_size.clamp(1, 100);
+        let after = PageCursor::decode_opt(query.after_cursor.as_deref())?;
 
-        // Convert store_id to StoreId value object
-        let store_id_vo = query.store_id.map(StoreId::from_uuid);
+        let store_ids: Option<Vec<StoreId>> = match ctx.store_scope(query.store_id) {
+            StoreScope::All => None,
+            StoreScope::Stores(ids) => Some(ids.into_iter().map(StoreId::from_uuid).collect()),
+            StoreScope::Denied(store_id) => {
+                return Err(InventoryError::StoreAccessDenied(store_id));
+            }
+        };
 
         // Fetch adjustments with pagination
         let (adjustments, total_items) = self
             .adjustment_repo
-            .find_paginated(store_id_vo, query.status.as_deref(), page, page_size)
+            .find_paginated(
+                store_ids.as_deref(),
+                query.status.as_deref(),
+                after.map(|c| c.id()),
+                page,
+                page_size,
+            )
             .await?;
+        let last_id = adjustments.last().map(|e| e.id().into_uuid());
 
         // Convert to response DTOs
         let adjustment_responses: Vec<AdjustmentResponse> = adjustments
@@ -86,6 +114,7 @@ where
                 created_by_id: a.created_by_id().into_uuid(),
                 approved_by_id: a.approved_by_id().map(|id| id.into_uuid()),
                 approved_at: a.approved_at(),
+                auto_approved: a.auto_approved(),
                 applied_at: a.applied_at(),
                 notes: a.notes().map(|s| s.to_string()),
                 item_count: a.items().len() as i32,
@@ -94,11 +123,176 @@ where
             })
             .collect();
 
-        Ok(PaginatedResponse::new(
+        Ok(PaginatedResponse::keyset(
             adjustment_responses,
+            after,
             page,
             page_size,
             total_items,
+            last_id,
         ))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use async_trait::async_trait;
+    use uuid::Uuid;
+
+    use crate::domain::entities::StockAdjustment;
+    use crate::domain::value_objects::{AdjustmentId, AdjustmentReason, AdjustmentType};
+    use identity::UserId;
+
+    struct MockAdjustmentRepository {
+        adjustments: Vec<StockAdjustment>,
+    }
+
+    #[async_trait]
+    impl AdjustmentRepository for MockAdjustmentRepository {
+        async fn save(&self, _adjustment: &StockAdjustment) -> Result<(), InventoryError> {
+            unimplemented!()
+        }
+
+        async fn find_by_id(
+            &self,
+            _id: AdjustmentId,
+        ) -> Result<Option<StockAdjustment>, InventoryError> {
+            unimplemented!()
+        }
+
+        async fn find_by_id_with_items(
+            &self,
+            _id: AdjustmentId,
+        ) -> Result<Option<StockAdjustment>, InventoryError> {
+            unimplemented!()
+        }
+
+        async fn find_by_store(
+            &self,
+            _store_id: StoreId,
+        ) -> Result<Vec<StockAdjustment>, InventoryError> {
+            unimplemented!()
+        }
+
+        async fn update(&self, _adjustment: &StockAdjustment) -> Result<(), InventoryError> {
+            unimplemented!()
+        }
+
+        async fn generate_adjustment_number(
+            &self,
+            _store_id: StoreId,
+        ) -> Result<String, InventoryError> {
+            unimplemented!()
+        }
+
+        async fn find_paginated(
+            &self,
+            store_ids: Option<&[StoreId]>,
+            _status: Option<&str>,
+            _after_id: Option<Uuid>,
+            _page: i64,
+            _page_size: i64,
+        ) -> Result<(Vec<StockAdjustment>, i64), InventoryError> {
+            let adjustments: Vec<StockAdjustment> = self
+                .adjustments
+                .iter()
+                .filter(|a| store_ids.is_none_or(|ids| ids.contains(&a.store_id())))
+                .cloned()
+                .collect();
+            let total = adjustments.len() as i64;
+            Ok((adjustments, total))
+        }
+    }
+
+    fn adjustment(store_id: StoreId) -> StockAdjustment {
+        StockAdjustment::create(
+            store_id,
+            "ADJ-TEST-00001".to_string(),
+            AdjustmentType::Decrease,
+            AdjustmentReason::Damage,
+            UserId::new(),
+        )
+    }
+
+    /// One adjustment in each of two stores
+    fn setup() -> (ListAdjustmentsUseCase<MockAdjustmentRepository>, Uuid, Uuid) {
+        let (store_a, store_b) = (StoreId::new(), StoreId::new());
+        let repo = MockAdjustmentRepository {
+            adjustments: vec![adjustment(store_a), adjustment(store_b)],
+        };
+        let use_case = ListAdjustmentsUseCase::new(Arc::new(repo));
+        (use_case, *store_a.as_uuid(), *store_b.as_uuid())
+    }
+
+    fn context(stores: Vec<Uuid>, is_super_admin: bool) -> UserContext {
+        UserContext::new(
+            UserId::new(),
+            StoreId::new(),
+            Default::default(),
+            stores,
+            is_super_admin,
+            None,
+            None,
+        )
+    }
+
+    fn query(store_id: Option<Uuid>) -> ListAdjustmentsQuery {
+        ListAdjustmentsQuery {
+            store_id,
+            ..Default::default()
+        }
+    }
+
+    #[tokio::test]
+    async fn test_assigned_user_sees_only_their_store() {
+        let (use_case, store_a, _) = setup();
+        let ctx = context(vec![store_a], false);
+
+        let all = use_case.execute(query(None), &ctx).await.unwrap();
+        assert_eq!(all.items.len(), 1);
+        assert_eq!(all.items[0].store_id, store_a);
+
+        let requested = use_case.execute(query(Some(store_a)), &ctx).await.unwrap();
+        assert_eq!(requested.items.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_rejects_store_the_user_is_not_assigned_to() {
+        let (use_case, store_a, store_b) = setup();
+        let ctx = context(vec![store_b], false);
+
+        let result = use_case.execute(query(Some(store_a)), &ctx).await;
+        assert!(matches!(
+            result,
+            Err(InventoryError::StoreAccessDenied(id)) if id == store_a
+        ));
+
+        let all = use_case.execute(query(None), &ctx).await.unwrap();
+        assert!(all.items.iter().all(|a| a.store_id == store_b));
+    }
+
+    #[tokio::test]
+    async fn test_user_without_stores_sees_nothing() {
+        let (use_case, store_a, _) = setup();
+        let ctx = context(vec![], false);
+
+        let all = use_case.execute(query(None), &ctx).await.unwrap();
+        assert!(all.items.is_empty());
+
+        let result = use_case.execute(query(Some(store_a)), &ctx).await;
+        assert!(matches!(result, Err(InventoryError::StoreAccessDenied(_))));
+    }
+
+    #[tokio::test]
+    async fn test_super_admin_sees_every_store() {
+        let (use_case, store_a, _) = setup();
+        let ctx = context(vec![], true);
+
+        let all = use_case.execute(query(None), &ctx).await.unwrap();
+        assert_eq!(all.items.len(), 2);
+
+        let requested = use_case.execute(query(Some(store_a)), &ctx).await.unwrap();
+        assert_eq!(requested.items.len(), 1);
+    }
+}
@@ -35,7 +35,9 @@ where
         let all_categories = self.category_repo.find_all_active().await?;
 
         // Separate roots and children
-        let roots: Vec<&ProductCategory> = all_categories.iter().filter(|c| c.is_root()).collect();
+        let mut roots: Vec<&ProductCategory> =
+            all_categories.iter().filter(|c| c.is_root()).collect();
+        Self::sort_siblings(&mut roots);
 
         let tree: Vec<CategoryTreeResponse> = roots
             .iter()
@@ -60,9 +62,13 @@ where
         category: &ProductCategory,
         all: &[ProductCategory],
     ) -> CategoryTreeResponse {
-        let children: Vec<CategoryTreeResponse> = all
+        let mut children: Vec<&ProductCategory> = all
             .iter()
             .filter(|c| c.parent_id() == Some(category.id()))
+            .collect();
+        Self::sort_siblings(&mut children);
+        let children: Vec<CategoryTreeResponse> = children
+            .into_iter()
             .map(|child| self.build_tree(child, all))
             .collect();
 
@@ -81,6 +87,15 @@ where
         }
     }
 
+    /// Orders siblings by sort order, then name
+    fn sort_siblings(siblings: &mut [&ProductCategory]) {
+        siblings.sort_by(|a, b| {
+            a.sort_order()
+                .cmp(&b.sort_order())
+                .then_with(|| a.name().cmp(b.name()))
+        });
+    }
+
     fn to_response(category: &ProductCategory) -> CategoryResponse {
         CategoryResponse {
             id: category.id().into_uuid(),
@@ -91,6 +106,8 @@ where
             icon: category.icon().map(|s| s.to_string()),
             sort_order: category.sort_order(),
             is_active: category.is_active(),
+            tax_category: category.tax_category().map(|t| t.to_string()),
+            costing_method: category.costing_method().to_string(),
             created_at: category.created_at(),
             updated_at: category.updated_at(),
         }
@@ -100,7 +117,9 @@ where
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::domain::repositories::CategoryStockValuation;
     use async_trait::async_trait;
+    use identity::StoreId;
     use std::collections::HashMap;
     use std::sync::Mutex;
 
@@ -180,6 +199,12 @@ mod tests {
             Ok(())
         }
 
+        async fn stock_valuation_by_category(
+            &self,
+            _store_id: Option<StoreId>,
+        ) -> Result<Vec<CategoryStockValuation>, InventoryError> {
+            Ok(vec![])
+        }
         async fn delete(&self, _id: CategoryId) -> Result<(), InventoryError> {
             Ok(())
         }
@@ -221,6 +246,29 @@ mod tests {
         assert_eq!(result[0].children[0].name, "Phones");
     }
 
+    #[tokio::test]
+    async fn test_list_tree_honors_sort_order() {
+        let repo = Arc::new(MockCategoryRepository::new());
+
+        let parent = ProductCategory::create("Electronics".to_string(), "electronics".to_string());
+        let parent_id = parent.id();
+        repo.add_category(parent);
+        for (name, sort_order) in [("Phones", 2), ("Tablets", 0), ("Cases", 1), ("Audio", 1)] {
+            let mut child = ProductCategory::create_subcategory(
+                parent_id,
+                name.to_string(),
+                name.to_lowercase(),
+            );
+            child.set_sort_order(sort_order);
+            repo.add_category(child);
+        }
+
+        let use_case = ListCategoriesUseCase::new(repo);
+        let result = use_case.execute_tree().await.unwrap();
+        let names: Vec<&str> = result[0].children.iter().map(|c| c.name.as_str()).collect();
+        assert_eq!(names, vec!["Tablets", "Audio", "Cases", "Phones"]);
+    }
+
     #[tokio::test]
     async fn test_list_children() {
         let repo = Arc::new(MockCategoryRepository::new());
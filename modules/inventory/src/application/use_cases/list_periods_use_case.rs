@@ -0,0 +1,41 @@
+// ListPeriodsUseCase - lists the inventory periods of a store
+
+use std::sync::Arc;
+
+use identity::StoreId;
+use uuid::Uuid;
+
+use crate::InventoryError;
+use crate::application::dtos::responses::InventoryPeriodResponse;
+use crate::domain::repositories::InventoryPeriodRepository;
+
+/// Use case for listing the inventory periods of a store, latest first
+pub struct ListPeriodsUseCase<R>
+where
+    R: InventoryPeriodRepository,
+{
+    period_repo: Arc<R>,
+}
+
+impl<R> ListPeriodsUseCase<R>
+where
+    R: InventoryPeriodRepository,
+{
+    /// Creates a new instance of ListPeriodsUseCase
+    pub fn new(period_repo: Arc<R>) -> Self {
+        Self { period_repo }
+    }
+
+    /// Executes the use case to list a store's periods
+    pub async fn execute(
+        &self,
+        store_id: Uuid,
+    ) -> Result<Vec<InventoryPeriodResponse>, InventoryError> {
+        let periods = self
+            .period_repo
+            .find_by_store(StoreId::from_uuid(store_id))
+            .await?;
+
+        Ok(periods.iter().map(InventoryPeriodResponse::from).collect())
+    }
+}
@@ -2,6 +2,8 @@
 
 use std::sync::Arc;
 
+use common::PageCursor;
+
 use crate::InventoryError;
 use crate::application::dtos::responses::{PaginatedResponse, ProductResponse};
 use crate::domain::repositories::ProductRepository;
@@ -16,6 +18,9 @@ pub struct ListProductsQuery {
     pub is_active: Option<bool>,
     /// Search term for name/description
     pub search: Option<String>,
+    /// Keyset cursor from a previous page's `next_cursor`; when set,
+    /// `page` is ignored
+    pub after_cursor: Option<String>,
     /// Page number (1-indexed)
     pub page: i64,
     /// Number of items per page
@@ -28,6 +33,7 @@ impl Default for ListProductsQuery {
             category_id: None,
             is_active: None,
             search: None,
+            after_cursor: None,
             page: 1,
             page_size: 20,
         }
@@ -65,6 +71,7 @@ where
         // Validate and clamp pagination
         let page = query.page.max(1);
         let page_size = query.page_size.clamp(1, 100);
+        let after = PageCursor::decode_opt(query.after_cursor.as_deref())?;
 
         // Convert category_id to domain type
         let category_id = query.category_id.map(CategoryId::from_uuid);
@@ -76,10 +83,12 @@ where
                 category_id,
                 query.is_active,
                 query.search.as_deref(),
+                after.map(|c| c.id()),
                 page,
                 page_size,
             )
             .await?;
+        let last_id = products.last().map(|e| e.id().into_uuid());
 
         // Convert to response DTOs
         let product_responses: Vec<ProductResponse> = products
@@ -88,6 +97,7 @@ where
                 id: p.id().into_uuid(),
                 sku: p.sku().as_str().to_string(),
                 barcode: p.barcode().map(|b| b.as_str().to_string()),
+                barcode_generated: p.barcode_generated(),
                 name: p.name().to_string(),
                 description: p.description().map(|s| s.to_string()),
                 category_id: p.category_id().map(|id| id.into_uuid()),
@@ -101,17 +111,23 @@ where
                 has_variants: p.has_variants(),
                 tax_rate: p.tax_rate(),
                 tax_included: p.tax_included(),
+                tax_category: p.tax_category().map(|t| t.to_string()),
+                is_serialized: p.is_serialized(),
+                markup_type: p.markup_rule().map(|r| r.markup_type().to_string()),
+                markup_percent: p.markup_rule().and_then(|r| r.percent()),
                 is_active: p.is_active(),
                 created_at: p.created_at(),
                 updated_at: p.updated_at(),
             })
             .collect();
 
-        Ok(PaginatedResponse::new(
+        Ok(PaginatedResponse::keyset(
             product_responses,
+            after,
             page,
             page_size,
             total_items,
+            last_id,
         ))
     }
 }
@@ -2,6 +2,8 @@
 
 use std::sync::Arc;
 
+use common::PageCursor;
+
 use crate::InventoryError;
 use crate::application::dtos::responses::{PaginatedResponse, RecipeResponse};
 use crate::domain::repositories::RecipeRepository;
@@ -13,6 +15,9 @@ pub struct ListRecipesQuery {
     pub is_active: Option<bool>,
     /// Search term for name/description
     pub search: Option<String>,
+    /// Keyset cursor from a previous page's `next_cursor`; when set,
+    /// `page` is ignored
+    pub after_cursor: Option<String>,
     /// Page number (1-indexed)
     pub page: i64,
     /// Number of items per page
@@ -24,6 +29,7 @@ impl Default for ListRecipesQuery {
         Self {
             is_active: None,
             search: None,
+            after_cursor: None,
             page: 1,
             page_size: 20,
         }
@@ -61,12 +67,20 @@ where
         // Validate and clamp pagination
         let page = query.page.max(1);
         let page_size = query.page_size.clamp(1, 100);
+        let after = PageCursor::decode_opt(query.after_cursor.as_deref())?;
 
         // Fetch recipes with pagination
         let (recipes, total_items) = self
             .recipe_repo
-            .find_paginated(query.is_active, query.search.as_deref(), page, page_size)
+            .find_paginated(
+                query.is_active,
+                query.search.as_deref(),
+                after.map(|c| c.id()),
+                page,
+                page_size,
+            )
             .await?;
+        let last_id = recipes.last().map(|e| e.id().into_uuid());
 
         // Convert to response DTOs
         let recipe_responses: Vec<RecipeResponse> = recipes
@@ -79,6 +93,7 @@ where
                 description: r.description().map(|s| s.to_string()),
                 version: r.version(),
                 yield_quantity: r.yield_quantity(),
+                yield_unit: r.yield_unit().to_string(),
                 preparation_time_minutes: r.preparation_time_minutes(),
                 calculate_cost_from_ingredients: r.calculate_cost_from_ingredients(),
                 calculated_cost: None,
@@ -88,11 +103,13 @@ where
             })
             .collect();
 
-        Ok(PaginatedResponse::new(
+        Ok(PaginatedResponse::keyset(
             recipe_responses,
+            after,
             page,
             page_size,
             total_items,
+            last_id,
         ))
     }
 }
@@ -0,0 +1,44 @@
+// ListRepriceBatchesUseCase - lists batch reprices, optionally by status
+
+use std::str::FromStr;
+use std::sync::Arc;
+
+use crate::InventoryError;
+use crate::application::dtos::responses::RepriceBatchResponse;
+use crate::domain::repositories::RepriceBatchRepository;
+use crate::domain::value_objects::RepriceBatchStatus;
+
+/// Use case for listing batch reprices, newest first
+pub struct ListRepriceBatchesUseCase<B>
+where
+    B: RepriceBatchRepository,
+{
+    batch_repo: Arc<B>,
+}
+
+impl<B> ListRepriceBatchesUseCase<B>
+where
+    B: RepriceBatchRepository,
+{
+    /// Creates a new instance of ListRepriceBatchesUseCase
+    pub fn new(batch_repo: Arc<B>) -> Self {
+        Self { batch_repo }
+    }
+
+    /// Executes the use case
+    ///
+    /// # Arguments
+    /// * `status` - Only batches in this status ("pending", "applied", "rejected")
+    ///
+    /// # Errors
+    /// * `InventoryError::InvalidRepriceBatchStatus` - If the status is not recognized
+    pub async fn execute(
+        &self,
+        status: Option<&str>,
+    ) -> Result<Vec<RepriceBatchResponse>, InventoryError> {
+        let status = status.map(RepriceBatchStatus::from_str).transpose()?;
+        let batches = self.batch_repo.find_all(status).await?;
+
+        Ok(batches.iter().map(RepriceBatchResponse::from).collect())
+    }
+}
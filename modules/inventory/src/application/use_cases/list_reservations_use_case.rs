@@ -2,12 +2,15 @@
 
 use std::sync::Arc;
 
+use common::PageCursor;
+
 use uuid::Uuid;
 
 use crate::InventoryError;
 use crate::application::dtos::responses::{PaginatedResponse, ReservationResponse};
 use crate::domain::repositories::ReservationRepository;
 use crate::domain::value_objects::StockId;
+use identity::{StoreId, StoreScope, UserContext};
 
 /// Query parameters for listing reservations
 #[derive(Debug, Clone)]
@@ -18,6 +21,9 @@ pub struct ListReservationsQuery {
     pub status: Option<String>,
     /// Optional filter by reference type (cart, order, quote)
     pub reference_type: Option<String>,
+    /// Keyset cursor from a previous page's `next_cursor`; when set,
+    /// `page` is ignored
+    pub after_cursor: Option<String>,
     /// Page number (1-indexed)
     pub page: i64,
     /// Number of items per page
@@ -25,6 +31,8 @@ pub struct ListReservationsQuery {
 }
 
 /// Use case for listing inventory reservations with pagination and filters.
+///
+/// Reservations are limited to stock at the caller's assigned stores.
 pub struct ListReservationsUseCase<R>
 where
     R: ReservationRepository,
@@ -45,16 +53,29 @@ where
     ///
     /// # Arguments
     /// * `query` - The query parameters for filtering and pagination
+    /// * `ctx` - The caller, whose assigned stores bound the listing
     ///
     /// # Returns
     /// PaginatedResponse of ReservationResponse on success
     pub async fn execute(
         &self,
         query: ListReservationsQuery,
+        ctx: &UserContext,
     ) -> Result<PaginatedResponse<ReservationResponse>, InventoryError> {
+        // The query names no store, so this only ever narrows to the
+        // caller's stores
+        let store_ids: Option<Vec<StoreId>> = match ctx.store_scope(None) {
+            StoreScope::All => None,
+            StoreScope::Stores(ids) => Some(ids.into_iter().map(StoreId::from_uuid).collect()),
+            StoreScope::Denied(store_id) => {
+                return Err(InventoryError::StoreAccessDenied(store_id));
+            }
+        };
+
         // Validate pagination parameters
         let page = if query.page < 1 { 1 } else { query.page };
         let page_size = query.page_size.clamp(1, 100);
+        let after = PageCursor::decode_opt(query.after_cursor.as_deref())?;
 
         // Convert stock_id to domain type if present
         let stock_id = query.stock_id.map(StockId::from_uuid);
@@ -63,13 +84,16 @@ where
         let (reservations, total_count) = self
             .reservation_repo
             .find_paginated(
+                store_ids.as_deref(),
                 stock_id,
                 query.status.as_deref(),
                 query.reference_type.as_deref(),
+                after.map(|c| c.id()),
                 page,
                 page_size,
             )
             .await?;
+        let last_id = reservations.last().map(|e| e.id().into_uuid());
 
         // Convert to response DTOs
         let responses: Vec<ReservationResponse> = reservations
@@ -81,17 +105,23 @@ where
                 reference_id: r.reference_id(),
                 quantity: r.quantity(),
                 status: r.status().to_string(),
+                priority: r.priority(),
                 expires_at: r.expires_at(),
+                auto_extend_seconds: r.auto_extend_seconds(),
+                bumped_at: r.bumped_at(),
+                bump_reason: r.bump_reason().map(str::to_string),
                 created_at: r.created_at(),
                 updated_at: r.updated_at(),
             })
             .collect();
 
-        Ok(PaginatedResponse::new(
+        Ok(PaginatedResponse::keyset(
             responses,
+            after,
             page,
             page_size,
             total_count,
+            last_id,
         ))
     }
 }
@@ -100,14 +130,16 @@ where
 mod tests {
     use super::*;
     use async_trait::async_trait;
-    use chrono::{Duration, Utc};
+    use chrono::{DateTime, Duration, Utc};
     use rust_decimal_macros::dec;
     use std::collections::HashMap;
     use std::sync::Mutex;
     use uuid::{NoContext, Timestamp, Uuid};
 
     use crate::domain::entities::InventoryReservation;
+    use crate::domain::repositories::ReservationStatusCount;
     use crate::domain::value_objects::ReservationId;
+    use identity::StoreId;
 
     fn new_uuid() -> Uuid {
         Uuid::new_v7(Timestamp::now(NoContext))
@@ -177,9 +209,11 @@ mod tests {
 
         async fn find_paginated(
             &self,
+            _store_ids: Option<&[StoreId]>,
             stock_id: Option<StockId>,
             status: Option<&str>,
             reference_type: Option<&str>,
+            after_id: Option<Uuid>,
             page: i64,
             page_size: i64,
         ) -> Result<(Vec<InventoryReservation>, i64), InventoryError> {
@@ -206,9 +240,16 @@ mod tests {
                 })
                 .cloned()
                 .collect();
+            filtered.sort_by_key(|r| std::cmp::Reverse(r.id().into_uuid()));
 
             let total = filtered.len() as i64;
-            let offset = ((page - 1) * page_size) as usize;
+            let offset = match after_id {
+                Some(after) => {
+                    filtered.retain(|r| r.id().into_uuid() < after);
+                    0
+                }
+                None => ((page - 1) * page_size) as usize,
+            };
             let result: Vec<_> = filtered
                 .drain(..)
                 .skip(offset)
@@ -217,12 +258,34 @@ mod tests {
 
             Ok((result, total))
         }
+
+        async fn count_by_status(
+            &self,
+            _store_id: StoreId,
+            _from: DateTime<Utc>,
+            _to: DateTime<Utc>,
+            _by_day: bool,
+        ) -> Result<Vec<ReservationStatusCount>, InventoryError> {
+            unimplemented!()
+        }
     }
 
     fn future_time() -> chrono::DateTime<Utc> {
         Utc::now() + Duration::hours(1)
     }
 
+    fn super_admin() -> UserContext {
+        UserContext::new(
+            identity::UserId::new(),
+            StoreId::new(),
+            Default::default(),
+            vec![],
+            true,
+            None,
+            None,
+        )
+    }
+
     #[tokio::test]
     async fn test_list_reservations_success() {
         let reservation_repo = Arc::new(MockReservationRepository::new());
@@ -236,6 +299,7 @@ mod tests {
                 new_uuid(),
                 dec!(10),
                 future_time(),
+                0,
             )
             .unwrap();
             reservation_repo.add_reservation(reservation);
@@ -247,11 +311,12 @@ mod tests {
             stock_id: None,
             status: None,
             reference_type: None,
+            after_cursor: None,
             page: 1,
             page_size: 10,
         };
 
-        let result = use_case.execute(query).await;
+        let result = use_case.execute(query, &super_admin()).await;
         assert!(result.is_ok());
 
         let response = result.unwrap();
@@ -270,6 +335,7 @@ mod tests {
             new_uuid(),
             dec!(10),
             future_time(),
+            0,
         )
         .unwrap();
         reservation_repo.add_reservation(reservation);
@@ -280,15 +346,84 @@ mod tests {
             stock_id: None,
             status: Some("pending".to_string()),
             reference_type: None,
+            after_cursor: None,
             page: 1,
             page_size: 10,
         };
 
-        let result = use_case.execute(query).await;
+        let result = use_case.execute(query, &super_admin()).await;
         assert!(result.is_ok());
 
         let response = result.unwrap();
         assert_eq!(response.items.len(), 1);
         assert_eq!(response.items[0].status, "pending");
     }
+
+    #[tokio::test]
+    async fn test_list_reservations_follows_cursor_without_repeats() {
+        let reservation_repo = Arc::new(MockReservationRepository::new());
+        let stock_id = StockId::new();
+        for _ in 0..5 {
+            let reservation = InventoryReservation::create(
+                stock_id,
+                "cart".to_string(),
+                new_uuid(),
+                dec!(1),
+                future_time(),
+                0,
+            )
+            .unwrap();
+            reservation_repo.add_reservation(reservation);
+        }
+        let use_case = ListReservationsUseCase::new(reservation_repo);
+
+        let mut seen = Vec::new();
+        let mut after_cursor = None;
+        loop {
+            let page = use_case
+                .execute(
+                    ListReservationsQuery {
+                        stock_id: None,
+                        status: None,
+                        reference_type: None,
+                        after_cursor: after_cursor.clone(),
+                        page: 1,
+                        page_size: 2,
+                    },
+                    &super_admin(),
+                )
+                .await
+                .unwrap();
+            assert_eq!(page.total_items, 5);
+            seen.extend(page.items.iter().map(|r| r.id));
+            match page.next_cursor {
+                Some(next) => after_cursor = Some(next),
+                None => break,
+            }
+        }
+
+        assert_eq!(seen.len(), 5);
+        let mut newest_first = seen.clone();
+        newest_first.sort_by(|a, b| b.cmp(a));
+        assert_eq!(seen, newest_first);
+    }
+
+    #[tokio::test]
+    async fn test_list_reservations_rejects_unknown_cursor() {
+        let use_case = ListReservationsUseCase::new(Arc::new(MockReservationRepository::new()));
+        let result = use_case
+            .execute(
+                ListReservationsQuery {
+                    stock_id: None,
+                    status: None,
+                    reference_type: None,
+                    after_cursor: Some("page-2".to_string()),
+                    page: 1,
+                    page_size: 10,
+                },
+                &super_admin(),
+            )
+            .await;
+        assert!(matches!(result, Err(InventoryError::InvalidPageCursor(_))));
+    }
 }
@@ -2,11 +2,13 @@
 
 use std::sync::Arc;
 
+use common::PageCursor;
+
 use crate::InventoryError;
 use crate::application::dtos::responses::{PaginatedResponse, StockResponse};
 use crate::domain::repositories::InventoryStockRepository;
 use crate::domain::value_objects::ProductId;
-use identity::StoreId;
+use identity::{StoreId, StoreScope, UserContext};
 
 /// Query parameters for listing stock
 #[derive(Debug, Clone)]
@@ -17,6 +19,9 @@ pub struct ListStockQuery {
     pub product_id: Option<uuid::Uuid>,
     /// Filter to only show low stock items
     pub low_stock: bool,
+    /// Keyset cursor from a previous page's `next_cursor`; when set,
+    /// `page` is ignored
+    pub after_cursor: Option<String>,
     /// Page number (1-indexed)
     pub page: i64,
     /// Number of items per page
@@ -29,6 +34,7 @@ impl Default for ListStockQuery {
             store_id: None,
             product_id: None,
             low_stock: false,
+            after_cursor: None,
             page: 1,
             page_size: 20,
         }
@@ -36,6 +42,9 @@ impl Default for ListStockQuery {
 }
 
 /// Use case for listing stock records with pagination and filters
+///
+/// Stock is limited to the caller's assigned stores; asking for a store the
+/// caller is not assigned to is rejected.
 pub struct ListStockUseCase<S>
 where
     S: InventoryStockRepository,
@@ -56,33 +65,55 @@ where
     ///
     /// # Arguments
     /// * `query` - Query parameters with filters and pagination
+    /// * `ctx` - The caller, whose assigned stores bound the listing
     ///
     /// # Returns
     /// Paginated response with stock records
+    ///
+    /// # Errors
+    /// * `InventoryError::StoreAccessDenied` - If the requested store is not
+    ///   assigned to the caller
     pub async fn execute(
         &self,
         query: ListStockQuery,
+        ctx: &UserContext,
     ) -> Result<PaginatedResponse<StockResponse>, InventoryError> {
+        let store_ids: Option<Vec<StoreId>> = match ctx.store_scope(query.store_id) {
+            StoreScope::All => None,
+            StoreScope::Stores(ids) => Some(ids.into_iter().map(StoreId::from_uuid).collect()),
+            StoreScope::Denied(store_id) => {
+                return Err(InventoryError::StoreAccessDenied(store_id));
+            }
+        };
+
         // Validate and clamp pagination
         let page = query.page.max(1);
         let page_size = query.page_size.clamp(1, 100);
+        let after = PageCursor::decode_opt(query.after_cursor.as_deref())?;
 
         // Convert UUIDs to value objects
-        let store_id = query.store_id.map(StoreId::from_uuid);
         let product_id = query.product_id.map(ProductId::from_uuid);
 
         // Fetch stock records with pagination
         let (stocks, total_items) = self
             .stock_repo
-            .find_paginated(store_id, product_id, query.low_stock, page, page_size)
+            .find_paginated(
+                store_ids.as_deref(),
+                product_id,
+                query.low_stock,
+                after.map(|c| c.id()),
+                page,
+                page_size,
+            )
             .await?;
+        let last_id = stocks.last().map(|e| e.id().into_uuid());
 
         // Convert to response DTOs
         let stock_responses: Vec<StockResponse> = stocks
             .into_iter()
             .map(|s| {
                 let available = s.available_quantity();
-                let is_low = available <= s.min_stock_level();
+                let is_low = s.is_low_stock();
                 StockResponse {
                     id: s.id().into_uuid(),
                     store_id: *s.store_id().as_uuid(),
@@ -94,6 +125,9 @@ where
                     version: s.version(),
                     min_stock_level: s.min_stock_level(),
                     max_stock_level: s.max_stock_level(),
+                    reorder_point: s.reorder_point(),
+                    reorder_quantity: s.reorder_quantity(),
+                    suggested_reorder_quantity: is_low.then(|| s.suggested_reorder_quantity()),
                     is_low_stock: is_low,
                     created_at: s.created_at(),
                     updated_at: s.updated_at(),
@@ -101,11 +135,13 @@ where
             })
             .collect();
 
-        Ok(PaginatedResponse::new(
+        Ok(PaginatedResponse::keyset(
             stock_responses,
+            after,
             page,
             page_size,
             total_items,
+            last_id,
         ))
     }
 }
@@ -6,7 +6,7 @@ use crate::InventoryError;
 use crate::application::dtos::responses::TransferResponse;
 use crate::domain::entities::StockTransfer;
 use crate::domain::repositories::TransferRepository;
-use identity::StoreId;
+use identity::{StoreId, StoreScope, UserContext};
 use serde::Deserialize;
 
 /// Query parameters for listing transfers
@@ -21,6 +21,9 @@ pub struct ListTransfersQuery {
 }
 
 /// Use case for listing stock transfers
+///
+/// Transfers are listed per store; asking for a store the caller is not
+/// assigned to is rejected.
 pub struct ListTransfersUseCase<T>
 where
     T: TransferRepository,
@@ -36,11 +39,20 @@ where
         Self { transfer_repo }
     }
 
+    /// Lists the transfers of `query.store_id`
+    ///
+    /// # Errors
+    /// * `InventoryError::StoreAccessDenied` - If the store is not assigned to
+    ///   the caller
     pub async fn execute(
         &self,
         query: ListTransfersQuery,
+        ctx: &UserContext,
     ) -> Result<Vec<TransferResponse>, InventoryError> {
         let transfers = if let Some(store_uuid) = query.store_id {
+            if let StoreScope::Denied(store_id) = ctx.store_scope(Some(store_uuid)) {
+                return Err(InventoryError::StoreAccessDenied(store_id));
+            }
             let store_id = StoreId::from_uuid(store_uuid);
             let direction = query.direction.as_deref().unwrap_or("all");
 
@@ -84,6 +96,11 @@ where
             notes: transfer.notes().map(|s| s.to_string()),
             shipping_method: transfer.shipping_method().map(|s| s.to_string()),
             tracking_number: transfer.tracking_number().map(|s| s.to_string()),
+            requires_approval: transfer.requires_approval(),
+            approval_reason: transfer.approval_reason().map(|s| s.to_string()),
+            approved_by_id: transfer.approved_by_id().map(|id| id.into_uuid()),
+            approved_at: transfer.approved_at(),
+            approval_notes: transfer.approval_notes().map(|s| s.to_string()),
             item_count: transfer.items().len() as i32,
             created_at: transfer.created_at(),
             updated_at: transfer.updated_at(),
@@ -61,6 +61,7 @@ where
                     product_id: v.product_id().into_uuid(),
                     sku: v.sku().as_str().to_string(),
                     barcode: v.barcode().map(|b| b.as_str().to_string()),
+                    barcode_generated: v.barcode_generated(),
                     name: v.name().to_string(),
                     variant_attributes: v.variant_attributes().clone(),
                     price: v.price(),
@@ -8,6 +8,13 @@
 //! - [`CreateCategoryUseCase`]: Create hierarchical product categories
 //! - [`CreateProductUseCase`]: Create products with auto-generated SKUs
 //! - [`CreateVariantUseCase`]: Create product variants
+//! - [`GenerateVariantsUseCase`]: Bulk-create variants from attribute axes
+//! - [`ConfigureCategoryBarcodePrefixUseCase`]: Set the prefix for generated EAN-13 barcodes
+//! - [`BulkUpdatePricesUseCase`]: Adjust base/cost prices across a category tree
+//! - [`ImportProductsUseCase`]: Create or update products from a catalog file, adding missing categories
+//! - [`ExportProductsUseCase`]: Write filtered products out in a caller-chosen column layout
+//! - [`SetProductVendorUseCase`]: Link vendors to a product, keeping one preferred
+//! - [`SetProductUnitConversionsUseCase`]: Define how a product's packaging converts to other units
 //!
 //! ## Stock Management Use Cases
 //!
@@ -16,11 +23,17 @@
 //! - [`ConfirmReservationUseCase`]: Confirm and consume reserved stock
 //! - [`CancelReservationUseCase`]: Cancel and release reserved stock
 //! - [`ExpireReservationsUseCase`]: Batch expire old reservations
+//! - [`RevalidateReservationsUseCase`]: Re-check holds before checkout, with a grace window
+//! - [`TouchReservationUseCase`]: Extend an active hold while its holder is active
+//! - [`RegisterSerialsUseCase`]: Register serial numbers of units for serial-tracked products
+//! - [`GetExpiringStockReportUseCase`]: Lots expiring within a number of days
+//! - [`GetInventoryAgingReportUseCase`]: On-hand stock bucketed by days since receipt
 //!
 //! ## Recipe Use Cases
 //!
 //! - [`CreateRecipeUseCase`]: Create recipes/BOMs for composite products
 //! - [`CalculateRecipeCostUseCase`]: Calculate recipe cost from ingredients
+//! - [`ResolveRecipeConsumptionUseCase`]: Ingredient stock a composite sale uses, with substitutes
 //!
 //! ## Adjustment Use Cases
 //!
@@ -32,21 +45,39 @@
 //! ## Transfer Use Cases
 //!
 //! - [`CreateTransferUseCase`]: Create inter-store transfer documents
+//! - [`SubmitTransferUseCase`]: Submit transfers, routing them to approval when required
+//! - [`ApproveTransferUseCase`]: Approve or reject transfers awaiting approval
 //! - [`ShipTransferUseCase`]: Ship transfers and reduce source stock
 //! - [`ReceiveTransferUseCase`]: Receive transfers and increase destination stock
+//!
+//! ## Reprice Use Cases
+//!
+//! - [`PreviewRepriceUseCase`]: Preview prices from a vendor cost update using markup rules
+//! - [`ApproveRepriceUseCase`]: Apply or reject a previewed batch, recording price history
+//! - [`GetPriceHistoryUseCase`]: Cost and price history of a product
 
+mod bulk_update_prices_use_case;
+mod configure_category_barcode_prefix_use_case;
 mod create_category_use_case;
 mod create_product_use_case;
 mod create_variant_use_case;
 mod delete_category_use_case;
 mod delete_product_use_case;
 mod delete_variant_use_case;
+mod export_products_use_case;
+mod generate_variants_use_case;
 mod get_category_use_case;
 mod get_product_use_case;
 mod get_variant_use_case;
+mod import_products_use_case;
 mod list_categories_use_case;
 mod list_products_use_case;
 mod list_variants_use_case;
+mod move_category_use_case;
+mod reorder_categories_use_case;
+mod search_products_use_case;
+mod set_product_unit_conversions_use_case;
+mod set_product_vendor_use_case;
 mod update_category_use_case;
 mod update_product_use_case;
 mod update_variant_use_case;
@@ -64,21 +95,42 @@ mod get_store_inventory_use_case;
 mod initialize_stock_use_case;
 mod list_reservations_use_case;
 mod list_stock_use_case;
+mod recalculate_average_cost_use_case;
+mod register_serials_use_case;
+mod revalidate_reservations_use_case;
+mod touch_reservation_use_case;
 mod update_stock_levels_use_case;
 mod update_stock_use_case;
 
 // Stock history and report use cases
+mod get_expiring_stock_report_use_case;
+mod get_in_transit_report_use_case;
+mod get_in_transit_stock_use_case;
+mod get_inventory_aging_report_use_case;
+mod get_inventory_category_rollup_use_case;
 mod get_low_stock_report_use_case;
 mod get_movements_report_use_case;
+mod get_reservation_conversion_report_use_case;
 mod get_stock_history_use_case;
 mod get_valuation_report_use_case;
 
+// Valuation snapshot use cases
+mod create_valuation_snapshot_use_case;
+mod get_valuation_snapshot_use_case;
+
+// Period close use cases
+mod close_period_use_case;
+mod list_periods_use_case;
+mod reopen_period_use_case;
+
 // Recipe use cases
 mod calculate_recipe_cost_use_case;
 mod create_recipe_use_case;
 mod get_product_recipe_use_case;
 mod get_recipe_use_case;
 mod list_recipes_use_case;
+mod refresh_composite_costs_use_case;
+mod resolve_recipe_consumption_use_case;
 mod update_recipe_use_case;
 
 // Adjustment use cases
@@ -90,6 +142,7 @@ mod list_adjustments_use_case;
 mod submit_adjustment_use_case;
 
 // Transfer use cases
+mod approve_transfer_use_case;
 mod cancel_transfer_use_case;
 mod create_transfer_use_case;
 mod get_transfer_use_case;
@@ -98,18 +151,40 @@ mod receive_transfer_use_case;
 mod ship_transfer_use_case;
 mod submit_transfer_use_case;
 
+// Reprice use cases
+mod approve_reprice_use_case;
+mod get_price_history_use_case;
+mod get_reprice_batch_use_case;
+mod list_reprice_batches_use_case;
+mod preview_reprice_use_case;
+
+pub use bulk_update_prices_use_case::BulkUpdatePricesUseCase;
+pub use configure_category_barcode_prefix_use_case::ConfigureCategoryBarcodePrefixUseCase;
 pub use create_category_use_case::CreateCategoryUseCase;
 pub use create_product_use_case::CreateProductUseCase;
 pub use create_variant_use_case::CreateVariantUseCase;
 pub use delete_category_use_case::DeleteCategoryUseCase;
 pub use delete_product_use_case::DeleteProductUseCase;
 pub use delete_variant_use_case::DeleteVariantUseCase;
+pub use export_products_use_case::{
+    ExportProductsQuery, ExportProductsUseCase, ProductExportColumn,
+};
+pub use generate_variants_use_case::{
+    GenerateVariantsResult, GenerateVariantsUseCase, MAX_GENERATED_VARIANTS,
+    MAX_VARIANT_CODE_LENGTH, SkippedVariantCombination,
+};
 pub use get_category_use_case::GetCategoryUseCase;
 pub use get_product_use_case::GetProductUseCase;
 pub use get_variant_use_case::GetVariantUseCase;
+pub use import_products_use_case::ImportProductsUseCase;
 pub use list_categories_use_case::ListCategoriesUseCase;
 pub use list_products_use_case::{ListProductsQuery, ListProductsUseCase};
 pub use list_variants_use_case::ListVariantsUseCase;
+pub use move_category_use_case::MoveCategoryUseCase;
+pub use reorder_categories_use_case::ReorderCategoriesUseCase;
+pub use search_products_use_case::{SearchProductsQuery, SearchProductsUseCase};
+pub use set_product_unit_conversions_use_case::SetProductUnitConversionsUseCase;
+pub use set_product_vendor_use_case::{MAX_VENDOR_SKU_LENGTH, SetProductVendorUseCase};
 pub use update_category_use_case::UpdateCategoryUseCase;
 pub use update_product_use_case::UpdateProductUseCase;
 pub use update_variant_use_case::UpdateVariantUseCase;
@@ -129,21 +204,58 @@ pub use get_store_inventory_use_case::GetStoreInventoryUseCase;
 pub use initialize_stock_use_case::InitializeStockUseCase;
 pub use list_reservations_use_case::{ListReservationsQuery, ListReservationsUseCase};
 pub use list_stock_use_case::{ListStockQuery, ListStockUseCase};
+pub use recalculate_average_cost_use_case::RecalculateAverageCostUseCase;
+pub use register_serials_use_case::RegisterSerialsUseCase;
+pub use revalidate_reservations_use_case::{
+    FailedReservationLine, ReReservedLine, RevalidateReservationsResult,
+    RevalidateReservationsUseCase,
+};
+pub use touch_reservation_use_case::TouchReservationUseCase;
 pub use update_stock_levels_use_case::UpdateStockLevelsUseCase;
-pub use update_stock_use_case::UpdateStockUseCase;
+pub use update_stock_use_case::{UpdateStockResult, UpdateStockUseCase};
 
 // Stock history and report use cases exports
+pub use get_expiring_stock_report_use_case::{
+    DEFAULT_EXPIRY_WINDOW_DAYS, ExpiringStockReportQuery, GetExpiringStockReportUseCase,
+};
+pub use get_in_transit_report_use_case::{
+    DEFAULT_TRANSIT_OVERDUE_DAYS, GetInTransitReportUseCase, InTransitReportQuery,
+};
+pub use get_in_transit_stock_use_case::GetInTransitStockUseCase;
+pub(crate) use get_in_transit_stock_use_case::in_transit_items;
+pub use get_inventory_aging_report_use_case::{
+    GetInventoryAgingReportUseCase, InventoryAgingReportQuery,
+};
+pub use get_inventory_category_rollup_use_case::{
+    CategoryRollupQuery, GetInventoryCategoryRollupUseCase,
+};
 pub use get_low_stock_report_use_case::{GetLowStockReportUseCase, LowStockReportQuery};
 pub use get_movements_report_use_case::{GetMovementsReportUseCase, MovementsReportQuery};
+pub use get_reservation_conversion_report_use_case::{
+    GetReservationConversionReportUseCase, ReservationConversionQuery,
+};
 pub use get_stock_history_use_case::{GetStockHistoryUseCase, StockHistoryQuery};
 pub use get_valuation_report_use_case::{GetValuationReportUseCase, ValuationReportQuery};
 
+// Valuation snapshot use cases exports
+pub use create_valuation_snapshot_use_case::CreateValuationSnapshotUseCase;
+pub use get_valuation_snapshot_use_case::GetValuationSnapshotUseCase;
+
+// Period close use cases exports
+pub use close_period_use_case::ClosePeriodUseCase;
+pub use list_periods_use_case::ListPeriodsUseCase;
+pub use reopen_period_use_case::ReopenPeriodUseCase;
+
 // Recipe use cases exports
 pub use calculate_recipe_cost_use_case::{CalculateRecipeCostUseCase, RecipeCostResult};
 pub use create_recipe_use_case::CreateRecipeUseCase;
 pub use get_product_recipe_use_case::GetProductRecipeUseCase;
 pub use get_recipe_use_case::GetRecipeUseCase;
 pub use list_recipes_use_case::{ListRecipesQuery, ListRecipesUseCase};
+pub use refresh_composite_costs_use_case::RefreshCompositeCostsUseCase;
+pub use resolve_recipe_consumption_use_case::{
+    IngredientDraw, RecipeConsumption, ResolveRecipeConsumptionUseCase,
+};
 pub use update_recipe_use_case::UpdateRecipeUseCase;
 
 // Adjustment use cases exports
@@ -155,6 +267,7 @@ pub use list_adjustments_use_case::{ListAdjustmentsQuery, ListAdjustmentsUseCase
 pub use submit_adjustment_use_case::SubmitAdjustmentUseCase;
 
 // Transfer use cases exports
+pub use approve_transfer_use_case::ApproveTransferUseCase;
 pub use cancel_transfer_use_case::CancelTransferUseCase;
 pub use create_transfer_use_case::CreateTransferUseCase;
 pub use get_transfer_use_case::GetTransferUseCase;
@@ -162,3 +275,10 @@ pub use list_transfers_use_case::{ListTransfersQuery, ListTransfersUseCase};
 pub use receive_transfer_use_case::ReceiveTransferUseCase;
 pub use ship_transfer_use_case::ShipTransferUseCase;
 pub use submit_transfer_use_case::SubmitTransferUseCase;
+
+// Reprice use cases exports
+pub use approve_reprice_use_case::ApproveRepriceUseCase;
+pub use get_price_history_use_case::GetPriceHistoryUseCase;
+pub use get_reprice_batch_use_case::GetRepriceBatchUseCase;
+pub use list_reprice_batches_use_case::ListRepriceBatchesUseCase;
+pub use preview_reprice_use_case::PreviewRepriceUseCase;
@@ -0,0 +1,288 @@
+// MoveCategoryUseCase - reparents a product category with its subtree
+
+use std::sync::Arc;
+
+use crate::InventoryError;
+use crate::application::dtos::commands::MoveCategoryCommand;
+use crate::application::dtos::responses::CategoryResponse;
+use crate::application::helpers::{DEFAULT_MAX_CATEGORY_DEPTH, validate_category_placement};
+use crate::domain::repositories::CategoryRepository;
+use crate::domain::value_objects::CategoryId;
+
+/// Use case for moving a category under another parent
+///
+/// Subcategories move along, and products stay assigned to the moved
+/// categories.
+pub struct MoveCategoryUseCase<C>
+where
+    C: CategoryRepository,
+{
+    category_repo: Arc<C>,
+    max_depth: usize,
+}
+
+impl<C> MoveCategoryUseCase<C>
+where
+    C: CategoryRepository,
+{
+    /// Creates a new instance of MoveCategoryUseCase
+    pub fn new(category_repo: Arc<C>) -> Self {
+        Self {
+            category_repo,
+            max_depth: DEFAULT_MAX_CATEGORY_DEPTH,
+        }
+    }
+
+    /// Sets the maximum number of levels in the category tree
+    pub fn with_max_depth(mut self, max_depth: usize) -> Self {
+        self.max_depth = max_depth;
+        self
+    }
+
+    /// Executes the use case to move a category
+    ///
+    /// # Errors
+    /// * `InventoryError::CategoryNotFound` - If the category doesn't exist
+    /// * `InventoryError::ParentCategoryNotFound` - If the new parent doesn't exist
+    /// * `InventoryError::CategoryCycle` - If the new parent is the category
+    ///   or one of its descendants
+    /// * `InventoryError::CategoryDepthExceeded` - If the subtree would end up
+    ///   deeper than the maximum
+    pub async fn execute(
+        &self,
+        id: uuid::Uuid,
+        command: MoveCategoryCommand,
+    ) -> Result<CategoryResponse, InventoryError> {
+        let category_id = CategoryId::from_uuid(id);
+        let mut category = self
+            .category_repo
+            .find_by_id(category_id)
+            .await?
+            .ok_or(InventoryError::CategoryNotFound(id))?;
+
+        let parent_id = command.parent_id.map(CategoryId::from_uuid);
+        if let Some(parent_id) = parent_id {
+            if self.category_repo.find_by_id(parent_id).await?.is_none() {
+                return Err(InventoryError::ParentCategoryNotFound(
+                    parent_id.into_uuid(),
+                ));
+            }
+            validate_category_placement(
+                self.category_repo.as_ref(),
+                Some(category_id),
+                parent_id,
+                self.max_depth,
+            )
+            .await?;
+        }
+
+        // Without an explicit position, go after the last sibling
+        let sort_order = match command.sort_order {
+            Some(sort_order) => sort_order,
+            None => {
+                let siblings = match parent_id {
+                    Some(parent_id) => self.category_repo.find_children(parent_id).await?,
+                    None => self.category_repo.find_root_categories().await?,
+                };
+                siblings
+                    .iter()
+                    .filter(|sibling| sibling.id() != category_id)
+                    .map(|sibling| sibling.sort_order() + 1)
+                    .max()
+                    .unwrap_or(0)
+            }
+        };
+
+        category.set_parent_id(parent_id);
+        category.set_sort_order(sort_order);
+        self.category_repo.update(&category).await?;
+
+        Ok(CategoryResponse {
+            id: category.id().into_uuid(),
+            parent_id: category.parent_id().map(|id| id.into_uuid()),
+            name: category.name().to_string(),
+            description: category.description().map(|s| s.to_string()),
+            slug: category.slug().to_string(),
+            icon: category.icon().map(|s| s.to_string()),
+            sort_order: category.sort_order(),
+            is_active: category.is_active(),
+            tax_category: category.tax_category().map(|t| t.to_string()),
+            costing_method: category.costing_method().to_string(),
+            created_at: category.created_at(),
+            updated_at: category.updated_at(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::entities::ProductCategory;
+    use crate::domain::repositories::CategoryStockValuation;
+    use async_trait::async_trait;
+    use identity::StoreId;
+    use std::collections::HashMap;
+    use std::sync::Mutex;
+
+    struct MockCategoryRepository {
+        categories: Mutex<HashMap<CategoryId, ProductCategory>>,
+    }
+
+    impl MockCategoryRepository {
+        fn new() -> Self {
+            Self {
+                categories: Mutex::new(HashMap::new()),
+            }
+        }
+
+        fn add(&self, parent: Option<CategoryId>, name: &str) -> CategoryId {
+            let category = match parent {
+                Some(parent_id) => ProductCategory::create_subcategory(
+                    parent_id,
+                    name.to_string(),
+                    name.to_lowercase(),
+                ),
+                None => ProductCategory::create(name.to_string(), name.to_lowercase()),
+            };
+            let id = category.id();
+            self.categories.lock().unwrap().insert(id, category);
+            id
+        }
+    }
+
+    #[async_trait]
+    impl CategoryRepository for MockCategoryRepository {
+        async fn save(&self, _category: &ProductCategory) -> Result<(), InventoryError> {
+            unimplemented!()
+        }
+
+        async fn find_by_id(
+            &self,
+            id: CategoryId,
+        ) -> Result<Option<ProductCategory>, InventoryError> {
+            Ok(self.categories.lock().unwrap().get(&id).cloned())
+        }
+
+        async fn find_by_slug(
+            &self,
+            _slug: &str,
+        ) -> Result<Option<ProductCategory>, InventoryError> {
+            unimplemented!()
+        }
+
+        async fn find_root_categories(&self) -> Result<Vec<ProductCategory>, InventoryError> {
+            let categories = self.categories.lock().unwrap();
+            Ok(categories
+                .values()
+                .filter(|c| c.is_root())
+                .cloned()
+                .collect())
+        }
+
+        async fn find_children(
+            &self,
+            parent_id: CategoryId,
+        ) -> Result<Vec<ProductCategory>, InventoryError> {
+            let categories = self.categories.lock().unwrap();
+            Ok(categories
+                .values()
+                .filter(|c| c.parent_id() == Some(parent_id))
+                .cloned()
+                .collect())
+        }
+
+        async fn find_all_active(&self) -> Result<Vec<ProductCategory>, InventoryError> {
+            unimplemented!()
+        }
+
+        async fn update(&self, category: &ProductCategory) -> Result<(), InventoryError> {
+            let mut categories = self.categories.lock().unwrap();
+            categories.insert(category.id(), category.clone());
+            Ok(())
+        }
+
+        async fn stock_valuation_by_category(
+            &self,
+            _store_id: Option<StoreId>,
+        ) -> Result<Vec<CategoryStockValuation>, InventoryError> {
+            unimplemented!()
+        }
+
+        async fn delete(&self, _id: CategoryId) -> Result<(), InventoryError> {
+            unimplemented!()
+        }
+    }
+
+    fn move_to(parent_id: Option<CategoryId>) -> MoveCategoryCommand {
+        MoveCategoryCommand {
+            parent_id: parent_id.map(|id| id.into_uuid()),
+            sort_order: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_move_appends_after_last_sibling() {
+        let repo = Arc::new(MockCategoryRepository::new());
+        let electronics = repo.add(None, "Electronics");
+        let phones = repo.add(Some(electronics), "Phones");
+        let mut phones_category = repo.find_by_id(phones).await.unwrap().unwrap();
+        phones_category.set_sort_order(4);
+        repo.update(&phones_category).await.unwrap();
+        let tablets = repo.add(None, "Tablets");
+        repo.add(Some(tablets), "Cases");
+
+        let use_case = MoveCategoryUseCase::new(repo.clone());
+        let result = use_case
+            .execute(tablets.into_uuid(), move_to(Some(electronics)))
+            .await
+            .unwrap();
+
+        assert_eq!(result.parent_id, Some(electronics.into_uuid()));
+        assert_eq!(result.sort_order, 5);
+        // The subtree moves along
+        assert_eq!(repo.find_children(tablets).await.unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_move_under_descendant_is_rejected() {
+        let repo = Arc::new(MockCategoryRepository::new());
+        let electronics = repo.add(None, "Electronics");
+        let phones = repo.add(Some(electronics), "Phones");
+        let cases = repo.add(Some(phones), "Cases");
+
+        let use_case = MoveCategoryUseCase::new(repo);
+        for parent in [electronics, cases] {
+            let result = use_case
+                .execute(electronics.into_uuid(), move_to(Some(parent)))
+                .await;
+            assert!(matches!(result, Err(InventoryError::CategoryCycle)));
+        }
+    }
+
+    #[tokio::test]
+    async fn test_move_counts_subtree_depth() {
+        let repo = Arc::new(MockCategoryRepository::new());
+        let electronics = repo.add(None, "Electronics");
+        let phones = repo.add(Some(electronics), "Phones");
+        let tablets = repo.add(None, "Tablets");
+        repo.add(Some(tablets), "Cases");
+
+        // Electronics > Phones > Tablets > Cases is 4 levels
+        let use_case = MoveCategoryUseCase::new(repo.clone()).with_max_depth(3);
+        let result = use_case
+            .execute(tablets.into_uuid(), move_to(Some(phones)))
+            .await;
+        assert!(matches!(
+            result,
+            Err(InventoryError::CategoryDepthExceeded(3))
+        ));
+
+        let use_case = MoveCategoryUseCase::new(repo).with_max_depth(4);
+        assert!(
+            use_case
+                .execute(tablets.into_uuid(), move_to(Some(phones)))
+                .await
+                .is_ok()
+        );
+    }
+}
@@ -0,0 +1,373 @@
+// PreviewRepriceUseCase - previews selling prices after a vendor cost update
+
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use crate::InventoryError;
+use crate::application::dtos::commands::PreviewRepriceCommand;
+use crate::application::dtos::responses::RepriceBatchResponse;
+use crate::domain::entities::{RepriceBatch, RepriceLine};
+use crate::domain::repositories::{ProductRepository, RepriceBatchRepository};
+use crate::domain::value_objects::ProductId;
+use identity::UserId;
+
+/// Use case for previewing a batch reprice.
+///
+/// Recomputes the price of every product in the cost update with its markup
+/// rule and saves the result as a pending batch. No product changes until
+/// the batch is approved. Manually priced products are kept in the batch,
+/// flagged as excluded.
+pub struct PreviewRepriceUseCase<P, B>
+where
+    P: ProductRepository,
+    B: RepriceBatchRepository,
+{
+    product_repo: Arc<P>,
+    batch_repo: Arc<B>,
+}
+
+impl<P, B> PreviewRepriceUseCase<P, B>
+where
+    P: ProductRepository,
+    B: RepriceBatchRepository,
+{
+    /// Creates a new instance of PreviewRepriceUseCase
+    pub fn new(product_repo: Arc<P>, batch_repo: Arc<B>) -> Self {
+        Self {
+            product_repo,
+            batch_repo,
+        }
+    }
+
+    /// Executes the use case to preview new prices
+    ///
+    /// # Errors
+    /// * `InventoryError::EmptyRepriceBatch` - If the command has no items
+    /// * `InventoryError::ProductNotFound` - If a product doesn't exist
+    /// * `InventoryError::NegativeCost` - If a new cost is negative
+    /// * `InventoryError::InvalidOperation` - If a product is listed twice
+    pub async fn execute(
+        &self,
+        command: PreviewRepriceCommand,
+        actor_id: UserId,
+    ) -> Result<RepriceBatchResponse, InventoryError> {
+        let mut seen = HashSet::new();
+        let mut lines = Vec::with_capacity(command.items.len());
+        for item in command.items {
+            if !seen.insert(item.product_id) {
+                return Err(InventoryError::InvalidOperation(format!(
+                    "product {} is listed more than once",
+                    item.product_id
+                )));
+            }
+            let product = self
+                .product_repo
+                .find_by_id(ProductId::from_uuid(item.product_id))
+                .await?
+                .ok_or(InventoryError::ProductNotFound(item.product_id))?;
+            lines.push(RepriceLine::preview(&product, item.new_cost)?);
+        }
+
+        let batch = RepriceBatch::create(command.vendor_id, command.reason, lines, actor_id)?;
+        self.batch_repo.save(&batch).await?;
+
+        Ok(RepriceBatchResponse::from(&batch))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use async_trait::async_trait;
+    use rust_decimal_macros::dec;
+    use std::collections::HashMap;
+    use std::sync::Mutex;
+    use uuid::Uuid;
+
+    use crate::application::dtos::commands::RepriceCostItemCommand;
+    use crate::domain::entities::{Product, ProductVariant, ProductVendor};
+    use crate::domain::value_objects::{
+        Barcode, CategoryId, MarkupRule, RepriceBatchId, RepriceBatchStatus, Sku, UnitConversion,
+        UnitOfMeasure, VariantId,
+    };
+
+    struct MockProductRepository {
+        products: Mutex<HashMap<ProductId, Product>>,
+    }
+
+    #[async_trait]
+    impl ProductRepository for MockProductRepository {
+        async fn save(&self, _product: &Product) -> Result<(), InventoryError> {
+            unimplemented!()
+        }
+
+        async fn find_by_id(&self, id: ProductId) -> Result<Option<Product>, InventoryError> {
+            Ok(self.products.lock().unwrap().get(&id).cloned())
+        }
+
+        async fn find_by_sku(&self, _sku: &Sku) -> Result<Option<Product>, InventoryError> {
+            unimplemented!()
+        }
+
+        async fn find_by_barcode(
+            &self,
+            _barcode: &Barcode,
+        ) -> Result<Option<Product>, InventoryError> {
+            unimplemented!()
+        }
+
+        async fn update(&self, _product: &Product) -> Result<(), InventoryError> {
+            unimplemented!()
+        }
+
+        async fn delete(&self, _id: ProductId) -> Result<(), InventoryError> {
+            unimplemented!()
+        }
+
+        async fn find_active(&self) -> Result<Vec<Product>, InventoryError> {
+            unimplemented!()
+        }
+
+        async fn find_by_category(
+            &self,
+            _category_id: CategoryId,
+        ) -> Result<Vec<Product>, InventoryError> {
+            unimplemented!()
+        }
+
+        async fn find_paginated(
+            &self,
+            _category_id: Option<CategoryId>,
+            _is_active: Option<bool>,
+            _search: Option<&str>,
+            _after_id: Option<Uuid>,
+            _page: i64,
+            _page_size: i64,
+        ) -> Result<(Vec<Product>, i64), InventoryError> {
+            unimplemented!()
+        }
+
+        async fn count_filtered(
+            &self,
+            _category_id: Option<CategoryId>,
+            _is_active: Option<bool>,
+            _search: Option<&str>,
+        ) -> Result<i64, InventoryError> {
+            unimplemented!()
+        }
+
+        async fn search(
+            &self,
+            _term: &str,
+            _category_id: Option<CategoryId>,
+            _active_only: bool,
+            _page: i64,
+            _page_size: i64,
+        ) -> Result<(Vec<(Product, f64)>, i64), InventoryError> {
+            unimplemented!()
+        }
+
+        async fn save_variant(&self, _variant: &ProductVariant) -> Result<(), InventoryError> {
+            unimplemented!()
+        }
+
+        async fn find_variant_by_id(
+            &self,
+            _id: VariantId,
+        ) -> Result<Option<ProductVariant>, InventoryError> {
+            unimplemented!()
+        }
+
+        async fn find_variant_by_sku(
+            &self,
+            _sku: &Sku,
+        ) -> Result<Option<ProductVariant>, InventoryError> {
+            unimplemented!()
+        }
+
+        async fn find_variant_by_barcode(
+            &self,
+            _barcode: &Barcode,
+        ) -> Result<Option<ProductVariant>, InventoryError> {
+            unimplemented!()
+        }
+
+        async fn find_variants_by_product(
+            &self,
+            _product_id: ProductId,
+        ) -> Result<Vec<ProductVariant>, InventoryError> {
+            unimplemented!()
+        }
+
+        async fn update_variant(&self, _variant: &ProductVariant) -> Result<(), InventoryError> {
+            unimplemented!()
+        }
+
+        async fn delete_variant(&self, _id: VariantId) -> Result<(), InventoryError> {
+            unimplemented!()
+        }
+
+        async fn count_variants(&self, _product_id: ProductId) -> Result<u32, InventoryError> {
+            unimplemented!()
+        }
+
+        async fn save_product_vendors(
+            &self,
+            _vendors: &[ProductVendor],
+        ) -> Result<(), InventoryError> {
+            unimplemented!()
+        }
+
+        async fn find_product_vendors(
+            &self,
+            _product_id: ProductId,
+        ) -> Result<Vec<ProductVendor>, InventoryError> {
+            unimplemented!()
+        }
+
+        async fn find_preferred_vendor(
+            &self,
+            _product_id: ProductId,
+        ) -> Result<Option<ProductVendor>, InventoryError> {
+            unimplemented!()
+        }
+
+        async fn replace_unit_conversions(
+            &self,
+            _product_id: ProductId,
+            _conversions: &[UnitConversion],
+        ) -> Result<(), InventoryError> {
+            unimplemented!()
+        }
+
+        async fn find_unit_conversions(
+            &self,
+            _product_id: ProductId,
+        ) -> Result<Vec<UnitConversion>, InventoryError> {
+            unimplemented!()
+        }
+    }
+
+    struct MockRepriceBatchRepository {
+        batches: Mutex<Vec<RepriceBatch>>,
+    }
+
+    #[async_trait]
+    impl RepriceBatchRepository for MockRepriceBatchRepository {
+        async fn save(&self, batch: &RepriceBatch) -> Result<(), InventoryError> {
+            self.batches.lock().unwrap().push(batch.clone());
+            Ok(())
+        }
+
+        async fn update(&self, _batch: &RepriceBatch) -> Result<(), InventoryError> {
+            unimplemented!()
+        }
+
+        async fn find_by_id(
+            &self,
+            _id: RepriceBatchId,
+        ) -> Result<Option<RepriceBatch>, InventoryError> {
+            unimplemented!()
+        }
+
+        async fn find_all(
+            &self,
+            _status: Option<RepriceBatchStatus>,
+        ) -> Result<Vec<RepriceBatch>, InventoryError> {
+            unimplemented!()
+        }
+    }
+
+    fn product(name: &str, markup_rule: Option<MarkupRule>) -> Product {
+        let mut product = Product::create(name.to_string(), UnitOfMeasure::Unit, None);
+        product.set_cost_price(dec!(10));
+        product.set_base_price(dec!(15));
+        product.set_markup_rule(markup_rule);
+        product
+    }
+
+    fn setup(
+        products: Vec<Product>,
+    ) -> (
+        PreviewRepriceUseCase<MockProductRepository, MockRepriceBatchRepository>,
+        Arc<MockRepriceBatchRepository>,
+    ) {
+        let product_repo = Arc::new(MockProductRepository {
+            products: Mutex::new(products.into_iter().map(|p| (p.id(), p)).collect()),
+        });
+        let batch_repo = Arc::new(MockRepriceBatchRepository {
+            batches: Mutex::new(Vec::new()),
+        });
+        (
+            PreviewRepriceUseCase::new(product_repo, batch_repo.clone()),
+            batch_repo,
+        )
+    }
+
+    fn command(items: &[(&Product, rust_decimal::Decimal)]) -> PreviewRepriceCommand {
+        PreviewRepriceCommand {
+            vendor_id: None,
+            reason: Some("Vendor price list 2026".to_string()),
+            items: items
+                .iter()
+                .map(|(p, cost)| RepriceCostItemCommand {
+                    product_id: p.id().into_uuid(),
+                    new_cost: *cost,
+                })
+                .collect(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_preview_reprices_and_flags_manual_prices() {
+        let marked_up = product("Beans", Some(MarkupRule::Markup { percent: dec!(50) }));
+        let manual = product("Mug", None);
+        let (use_case, batch_repo) = setup(vec![marked_up.clone(), manual.clone()]);
+
+        let response = use_case
+            .execute(
+                command(&[(&marked_up, dec!(12)), (&manual, dec!(12))]),
+                UserId::new(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status, "pending");
+        assert_eq!(response.repriced_count, 1);
+        assert_eq!(response.excluded_count, 1);
+        let beans = &response.lines[0];
+        assert_eq!(beans.old_price, dec!(15));
+        assert_eq!(beans.new_price, Some(dec!(18.00)));
+        let mug = &response.lines[1];
+        assert!(mug.excluded);
+        assert_eq!(mug.new_price, None);
+        assert_eq!(batch_repo.batches.lock().unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_preview_rejects_unknown_and_duplicate_products() {
+        let beans = product("Beans", Some(MarkupRule::Keystone));
+        let (use_case, batch_repo) = setup(vec![beans.clone()]);
+
+        let duplicate = use_case
+            .execute(
+                command(&[(&beans, dec!(1)), (&beans, dec!(2))]),
+                UserId::new(),
+            )
+            .await;
+        assert!(matches!(
+            duplicate,
+            Err(InventoryError::InvalidOperation(_))
+        ));
+
+        let unknown = product("Unknown", None);
+        let missing = use_case
+            .execute(command(&[(&unknown, dec!(1))]), UserId::new())
+            .await;
+        assert!(matches!(missing, Err(InventoryError::ProductNotFound(_))));
+
+        let empty = use_case.execute(command(&[]), UserId::new()).await;
+        assert!(matches!(empty, Err(InventoryError::EmptyRepriceBatch)));
+        assert!(batch_repo.batches.lock().unwrap().is_empty());
+    }
+}
@@ -0,0 +1,277 @@
+// RecalculateAverageCostUseCase - replays the kardex to correct weighted-average cost
+
+use std::sync::Arc;
+
+use rust_decimal::Decimal;
+use serde_json::json;
+
+use crate::InventoryError;
+use crate::application::dtos::commands::RecalculateAverageCostCommand;
+use crate::application::dtos::responses::AverageCostRecalculationResponse;
+use crate::application::helpers::{DEFAULT_BASE_DELAY_MS, DEFAULT_MAX_ATTEMPTS, retry_on_conflict};
+use crate::domain::entities::InventoryMovement;
+use crate::domain::repositories::{InventoryMovementRepository, InventoryStockRepository};
+use crate::domain::value_objects::{MovementId, MovementType, ProductId};
+use identity::StoreId;
+use identity::domain::entities::AuditEntry;
+use identity::domain::repositories::AuditRepository;
+use identity::domain::value_objects::UserId;
+
+/// Use case for recomputing the weighted-average cost of a product's stock in
+/// a store after a bad movement has been corrected.
+///
+/// Every movement of the stock record is replayed oldest first and the running
+/// average after each one is written back, together with the final average on
+/// the stock record, in a single transaction. If movements are posted while
+/// replaying, the replay starts over.
+pub struct RecalculateAverageCostUseCase<S, M, A>
+where
+    S: InventoryStockRepository,
+    M: InventoryMovementRepository,
+    A: AuditRepository,
+{
+    stock_repo: Arc<S>,
+    movement_repo: Arc<M>,
+    audit_repo: Arc<A>,
+}
+
+impl<S, M, A> RecalculateAverageCostUseCase<S, M, A>
+where
+    S: InventoryStockRepository,
+    M: InventoryMovementRepository,
+    A: AuditRepository,
+{
+    /// Creates a new instance of RecalculateAverageCostUseCase
+    pub fn new(stock_repo: Arc<S>, movement_repo: Arc<M>, audit_repo: Arc<A>) -> Self {
+        Self {
+            stock_repo,
+            movement_repo,
+            audit_repo,
+        }
+    }
+
+    /// Executes the recalculation
+    ///
+    /// # Arguments
+    /// * `command` - Store and product whose stock is recalculated
+    /// * `actor_id` - ID of the user performing this action (for audit)
+    ///
+    /// # Errors
+    /// * `InventoryError::StockNotFound` - If the product has no stock in the store
+    /// * `InventoryError::OptimisticLockError` - If movements keep being posted
+    ///   during the replay
+    pub async fn execute(
+        &self,
+        command: RecalculateAverageCostCommand,
+        actor_id: UserId,
+    ) -> Result<AverageCostRecalculationResponse, InventoryError> {
+        let store_id = StoreId::from_uuid(command.store_id);
+        let product_id = ProductId::from_uuid(command.product_id);
+
+        let (stock, replay, rewrite) =
+            retry_on_conflict(DEFAULT_MAX_ATTEMPTS, DEFAULT_BASE_DELAY_MS, || async {
+                let stock = self
+                    .stock_repo
+                    .find_by_store_and_product(store_id, product_id)
+                    .await?
+                    .ok_or(InventoryError::StockNotFound(command.product_id))?;
+
+                let movements = self
+                    .movement_repo
+                    .find_all_by_stock_id_chronological(stock.id())
+                    .await?;
+                let replay = replay_average_cost(&movements);
+
+                let rewrite = self
+                    .movement_repo
+                    .rewrite_average_costs(
+                        stock.id(),
+                        stock.version(),
+                        &replay.movement_costs,
+                        replay.average_cost,
+                    )
+                    .await?;
+
+                Ok((stock, replay, rewrite))
+            })
+            .await?;
+
+        // Before the first recalculation nothing is stored; compare against
+        // the average the reports have been showing instead.
+        let previous_average_cost = match rewrite.previous_average_cost {
+            Some(cost) => Some(cost),
+            None => {
+                self.movement_repo
+                    .calculate_weighted_average_cost(stock.id())
+                    .await?
+            }
+        };
+        let cost_delta = replay.average_cost.unwrap_or(Decimal::ZERO)
+            - previous_average_cost.unwrap_or(Decimal::ZERO);
+
+        let audit_entry = AuditEntry::for_update(
+            "inventory_stock",
+            stock.id().into_uuid(),
+            &json!({ "average_cost": previous_average_cost }),
+            &json!({ "average_cost": replay.average_cost }),
+            actor_id,
+        );
+        self.audit_repo
+            .save(&audit_entry)
+            .await
+            .map_err(|e| InventoryError::AuditError(e.to_string()))?;
+
+        Ok(AverageCostRecalculationResponse {
+            stock_id: stock.id().into_uuid(),
+            store_id: command.store_id,
+            product_id: command.product_id,
+            movements_replayed: replay.movement_costs.len() as i64,
+            movements_rewritten: rewrite.movements_rewritten,
+            previous_average_cost,
+            average_cost: replay.average_cost,
+            cost_delta,
+        })
+    }
+}
+
+/// Running averages produced by replaying a kardex
+#[derive(Debug)]
+struct AverageCostReplay {
+    /// Average cost right after each movement, in replay order
+    movement_costs: Vec<(MovementId, Option<Decimal>)>,
+    /// Average cost after the last movement
+    average_cost: Option<Decimal>,
+}
+
+/// Replays movements (oldest first) into a perpetual weighted-average cost.
+///
+/// Only costed receipts (`in` / `transfer_in` with a positive quantity and a
+/// unit cost) move the average; everything else carries it forward, so
+/// zero-quantity movements never divide by zero. The first costed receipt, and
+/// any receipt into empty or negative stock, sets the average to its own unit
+/// cost. Averages are rounded to the 4 decimals the kardex stores.
+fn replay_average_cost(movements: &[InventoryMovement]) -> AverageCostReplay {
+    let mut average: Option<Decimal> = None;
+    let mut movement_costs = Vec::with_capacity(movements.len());
+
+    for movement in movements {
+        let receipt_cost = match movement.movement_type() {
+            MovementType::In | MovementType::TransferIn if movement.quantity() > Decimal::ZERO => {
+                movement.unit_cost()
+            }
+            _ => None,
+        };
+
+        if let Some(unit_cost) = receipt_cost {
+            let quantity = movement.quantity();
+            let quantity_before = movement.balance_after() - quantity;
+            average = Some(match average {
+                Some(current) if quantity_before > Decimal::ZERO => ((quantity_before * current
+                    + quantity * unit_cost)
+                    / (quantity_before + quantity))
+                    .round_dp(4),
+                _ => unit_cost.round_dp(4),
+            });
+        }
+
+        movement_costs.push((movement.id(), average));
+    }
+
+    AverageCostReplay {
+        movement_costs,
+        average_cost: average,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::value_objects::{Currency, StockId};
+    use rust_decimal_macros::dec;
+
+    fn movement(
+        movement_type: MovementType,
+        quantity: Decimal,
+        unit_cost: Option<Decimal>,
+        balance_after: Decimal,
+    ) -> InventoryMovement {
+        InventoryMovement::create(
+            StockId::new(),
+            movement_type,
+            None,
+            quantity,
+            unit_cost,
+            Currency::hnl(),
+            balance_after,
+            None,
+            None,
+            UserId::new(),
+            None,
+        )
+    }
+
+    fn costs(replay: &AverageCostReplay) -> Vec<Option<Decimal>> {
+        replay.movement_costs.iter().map(|(_, c)| *c).collect()
+    }
+
+    #[test]
+    fn test_first_receipt_sets_baseline_and_later_ones_weigh_in() {
+        let movements = vec![
+            movement(MovementType::Adjustment, dec!(5), None, dec!(5)),
+            movement(MovementType::In, dec!(10), Some(dec!(8)), dec!(15)),
+            movement(MovementType::Out, dec!(-5), None, dec!(10)),
+            movement(MovementType::In, dec!(10), Some(dec!(11)), dec!(20)),
+        ];
+
+        let replay = replay_average_cost(&movements);
+
+        // Uncosted stock before the first receipt does not dilute the baseline
+        assert_eq!(
+            costs(&replay),
+            vec![None, Some(dec!(8)), Some(dec!(8)), Some(dec!(9.5))]
+        );
+        assert_eq!(replay.average_cost, Some(dec!(9.5)));
+    }
+
+    #[test]
+    fn test_zero_quantity_movements_carry_the_average() {
+        let movements = vec![
+            movement(MovementType::In, dec!(0), Some(dec!(50)), dec!(0)),
+            movement(MovementType::In, dec!(3), Some(dec!(10)), dec!(3)),
+            movement(MovementType::In, dec!(0), Some(dec!(99)), dec!(3)),
+            movement(MovementType::Adjustment, dec!(0), None, dec!(3)),
+        ];
+
+        let replay = replay_average_cost(&movements);
+
+        assert_eq!(
+            costs(&replay),
+            vec![None, Some(dec!(10)), Some(dec!(10)), Some(dec!(10))]
+        );
+    }
+
+    #[test]
+    fn test_receipt_into_empty_stock_resets_and_rounds() {
+        let movements = vec![
+            movement(MovementType::In, dec!(3), Some(dec!(10)), dec!(3)),
+            movement(MovementType::Out, dec!(-3), None, dec!(0)),
+            movement(MovementType::TransferIn, dec!(2), Some(dec!(7)), dec!(2)),
+            movement(MovementType::In, dec!(1), Some(dec!(8)), dec!(3)),
+        ];
+
+        let replay = replay_average_cost(&movements);
+
+        assert_eq!(replay.movement_costs[2].1, Some(dec!(7)));
+        assert_eq!(replay.average_cost, Some(dec!(7.3333)));
+    }
+
+    #[test]
+    fn test_no_costed_receipts_leaves_average_empty() {
+        let movements = vec![movement(MovementType::Adjustment, dec!(4), None, dec!(4))];
+
+        let replay = replay_average_cost(&movements);
+
+        assert_eq!(costs(&replay), vec![None]);
+        assert_eq!(replay.average_cost, None);
+    }
+}
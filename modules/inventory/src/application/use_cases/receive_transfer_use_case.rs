@@ -1,16 +1,24 @@
 // ReceiveTransferUseCase - receives a transfer at destination, increasing destination stock
 
+use std::collections::HashMap;
 use std::sync::Arc;
 
+use rust_decimal::Decimal;
+use uuid::Uuid;
+
 use crate::InventoryError;
 use crate::application::dtos::commands::ReceiveTransferCommand;
 use crate::application::dtos::responses::{TransferDetailResponse, TransferItemResponse};
 use crate::application::helpers::{DEFAULT_BASE_DELAY_MS, DEFAULT_MAX_ATTEMPTS, retry_on_conflict};
-use crate::domain::entities::{InventoryMovement, InventoryStock, StockTransfer};
+use crate::domain::entities::{
+    AdjustmentItem, InventoryMovement, InventoryStock, StockAdjustment, StockTransfer,
+};
 use crate::domain::repositories::{
-    InventoryMovementRepository, InventoryStockRepository, TransferRepository,
+    AdjustmentRepository, InventoryMovementRepository, InventoryStockRepository, TransferRepository,
+};
+use crate::domain::value_objects::{
+    AdjustmentReason, AdjustmentType, Currency, MovementType, TransferId,
 };
-use crate::domain::value_objects::{Currency, MovementType, TransferId};
 use identity::UserId;
 
 /// Use case for receiving a stock transfer at destination.
@@ -18,29 +26,42 @@ use identity::UserId;
 /// Validates status is in_transit, records receiver and timestamp,
 /// increases destination stock for each item (creating stock records if needed),
 /// creates transfer_in movements, and changes status to completed.
-pub struct ReceiveTransferUseCase<T, S, M>
+///
+/// Items received short of what was shipped move the transfer to
+/// received_with_discrepancy instead, and the shortfall is written off at the
+/// source store with an in_transit_loss adjustment.
+pub struct ReceiveTransferUseCase<T, S, M, A>
 where
     T: TransferRepository,
     S: InventoryStockRepository,
     M: InventoryMovementRepository,
+    A: AdjustmentRepository,
 {
     transfer_repo: Arc<T>,
     stock_repo: Arc<S>,
     movement_repo: Arc<M>,
+    adjustment_repo: Arc<A>,
 }
 
-impl<T, S, M> ReceiveTransferUseCase<T, S, M>
+impl<T, S, M, A> ReceiveTransferUseCase<T, S, M, A>
 where
     T: TransferRepository,
     S: InventoryStockRepository,
     M: InventoryMovementRepository,
+    A: AdjustmentRepository,
 {
     /// Creates a new instance of ReceiveTransferUseCase
-    pub fn new(transfer_repo: Arc<T>, stock_repo: Arc<S>, movement_repo: Arc<M>) -> Self {
+    pub fn new(
+        transfer_repo: Arc<T>,
+        stock_repo: Arc<S>,
+        movement_repo: Arc<M>,
+        adjustment_repo: Arc<A>,
+    ) -> Self {
         Self {
             transfer_repo,
             stock_repo,
             movement_repo,
+            adjustment_repo,
         }
     }
 
@@ -56,6 +77,8 @@ where
     /// # Errors
     /// * `InventoryError::TransferNotFound` - If transfer doesn't exist
     /// * `InventoryError::InvalidStatusTransition` - If transfer is not in in_transit status
+    /// * `InventoryError::InvalidReceivedQuantity` - If a received quantity is negative or
+    ///   more than was shipped
     /// * `InventoryError::OptimisticLockError` - If concurrent modification detected
     pub async fn execute(
         &self,
@@ -70,30 +93,37 @@ where
             .await?
             .ok_or(InventoryError::TransferNotFound(command.transfer_id))?;
 
-        // 2. Receive the transfer (validates status is in_transit) (Requirement 11.6)
-        transfer.receive(actor_id)?;
-
-        // 3. Build a map of item_id -> received quantity from command
-        let received_quantities: std::collections::HashMap<uuid::Uuid, rust_decimal::Decimal> =
-            command
-                .items
-                .iter()
-                .map(|item| (item.item_id, item.quantity_received))
-                .collect();
-
-        // 4. Process each item: increase destination stock and create movements (Requirement 11.7)
-        let to_store_id = transfer.to_store_id();
-        let transfer_uuid = transfer.id().into_uuid();
+        // 2. Record the received quantities from the command, defaulting to shipped
+        let received_quantities: HashMap<Uuid, Decimal> = command
+            .items
+            .iter()
+            .map(|item| (item.item_id, item.quantity_received))
+            .collect();
 
         for item in transfer.items_mut() {
-            // Get received quantity from command, default to shipped if not specified
+            let quantity_shipped = item.quantity_shipped().unwrap_or(item.quantity_requested());
             let quantity_received = received_quantities
                 .get(&item.id())
                 .copied()
-                .unwrap_or_else(|| item.quantity_shipped().unwrap_or(item.quantity_requested()));
-
-            // Record received quantity on item
+                .unwrap_or(quantity_shipped);
+            if quantity_received < Decimal::ZERO || quantity_received > quantity_shipped {
+                return Err(InventoryError::InvalidReceivedQuantity(item.id()));
+            }
             item.record_received(quantity_received);
+        }
+
+        // 3. Receive the transfer (validates status is in_transit) (Requirement 11.6)
+        transfer.receive(actor_id)?;
+
+        // 4. Process each item: increase destination stock and create movements (Requirement 11.7)
+        let to_store_id = transfer.to_store_id();
+        let transfer_uuid = transfer.id().into_uuid();
+
+        for item in transfer.items() {
+            let quantity_received = item.quantity_received().unwrap_or(Decimal::ZERO);
+            if quantity_received.is_zero() {
+                continue;
+            }
 
             // Find or create stock record, then update with retry on optimistic lock conflict
             let item_product_id = item.product_id();
@@ -165,13 +195,82 @@ where
             self.movement_repo.save(&movement).await?;
         }
 
-        // 5. Update transfer
+        // 5. Write off what went missing at the source store
+        if transfer.has_discrepancy() {
+            self.record_in_transit_loss(&transfer, actor_id).await?;
+        }
+
+        // 6. Update transfer
         self.transfer_repo.update(&transfer).await?;
 
-        // 6. Convert to response
+        // 7. Convert to response
         Ok(self.to_response(&transfer))
     }
 
+    /// Records the shortfall of each item as an in_transit_loss adjustment at
+    /// the source store. The units left the source's stock when the transfer
+    /// shipped, so the adjustment is recorded as applied without moving stock.
+    async fn record_in_transit_loss(
+        &self,
+        transfer: &StockTransfer,
+        actor_id: UserId,
+    ) -> Result<(), InventoryError> {
+        let from_store_id = transfer.from_store_id();
+        let adjustment_number = self
+            .adjustment_repo
+            .generate_adjustment_number(from_store_id)
+            .await?;
+        let mut adjustment = StockAdjustment::create(
+            from_store_id,
+            adjustment_number,
+            AdjustmentType::Decrease,
+            AdjustmentReason::InTransitLoss,
+            actor_id,
+        );
+        adjustment.set_notes(Some(format!(
+            "Shortfall on receipt of transfer {}",
+            transfer.transfer_number()
+        )))?;
+
+        for item in transfer.items() {
+            let shortfall = item.shortfall();
+            if shortfall.is_zero() {
+                continue;
+            }
+
+            let found = if let Some(product_id) = item.product_id() {
+                self.stock_repo
+                    .find_by_store_and_product(from_store_id, product_id)
+                    .await?
+            } else if let Some(variant_id) = item.variant_id() {
+                self.stock_repo
+                    .find_by_store_and_variant(from_store_id, variant_id)
+                    .await?
+            } else {
+                return Err(InventoryError::InvalidProductVariantConstraint);
+            };
+            let stock = found.ok_or_else(|| {
+                InventoryError::StockNotFound(
+                    item.product_id()
+                        .map(|id| id.into_uuid())
+                        .or_else(|| item.variant_id().map(|id| id.into_uuid()))
+                        .unwrap_or_default(),
+                )
+            })?;
+
+            adjustment.add_item(AdjustmentItem::create(
+                adjustment.id(),
+                stock.id(),
+                -shortfall,
+                item.unit_cost(),
+            ))?;
+        }
+
+        adjustment.auto_approve()?;
+        adjustment.mark_applied()?;
+        self.adjustment_repo.save(&adjustment).await
+    }
+
     fn to_response(&self, transfer: &StockTransfer) -> TransferDetailResponse {
         let items: Vec<TransferItemResponse> = transfer
             .items()
@@ -186,6 +285,7 @@ where
                 quantity_requested: item.quantity_requested(),
                 quantity_shipped: item.quantity_shipped(),
                 quantity_received: item.quantity_received(),
+                quantity_discrepancy: item.shipping_discrepancy(),
                 unit_cost: item.unit_cost(),
                 notes: item.notes().map(|s| s.to_string()),
                 created_at: item.created_at(),
@@ -207,6 +307,11 @@ where
             notes: transfer.notes().map(|s| s.to_string()),
             shipping_method: transfer.shipping_method().map(|s| s.to_string()),
             tracking_number: transfer.tracking_number().map(|s| s.to_string()),
+            requires_approval: transfer.requires_approval(),
+            approval_reason: transfer.approval_reason().map(|s| s.to_string()),
+            approved_by_id: transfer.approved_by_id().map(|id| id.into_uuid()),
+            approved_at: transfer.approved_at(),
+            approval_notes: transfer.approval_notes().map(|s| s.to_string()),
             items,
             created_at: transfer.created_at(),
             updated_at: transfer.updated_at(),
@@ -226,7 +331,9 @@ mod tests {
 
     use crate::application::dtos::commands::ReceiveTransferItemCommand;
     use crate::domain::entities::TransferItem;
-    use crate::domain::value_objects::{ProductId, StockId, VariantId};
+    use crate::domain::value_objects::{
+        AdjustmentId, AdjustmentStatus, ProductId, StockId, VariantId,
+    };
     use identity::StoreId;
 
     fn new_uuid() -> Uuid {
@@ -294,6 +401,10 @@ mod tests {
             Ok(vec![])
         }
 
+        async fn find_in_transit(&self) -> Result<Vec<StockTransfer>, InventoryError> {
+            Ok(vec![])
+        }
+
         async fn update(&self, transfer: &StockTransfer) -> Result<(), InventoryError> {
             let mut transfers = self.transfers.lock().unwrap();
             transfers.insert(transfer.id(), transfer.clone());
@@ -436,9 +547,10 @@ mod tests {
 
         async fn find_paginated(
             &self,
-            _store_id: Option<StoreId>,
+            _store_ids: Option<&[StoreId]>,
             _product_id: Option<ProductId>,
             _low_stock_only: bool,
+            _after_id: Option<Uuid>,
             _page: i64,
             _page_size: i64,
         ) -> Result<(Vec<InventoryStock>, i64), InventoryError> {
@@ -573,6 +685,104 @@ mod tests {
         async fn save_batch(&self, _movements: &[InventoryMovement]) -> Result<(), InventoryError> {
             unimplemented!()
         }
+
+        async fn find_all_by_stock_id_chronological(
+            &self,
+            _stock_id: StockId,
+        ) -> Result<Vec<InventoryMovement>, InventoryError> {
+            unimplemented!()
+        }
+
+        async fn rewrite_average_costs(
+            &self,
+            _stock_id: StockId,
+            _expected_version: i32,
+            _movement_costs: &[(crate::domain::value_objects::MovementId, Option<Decimal>)],
+            _average_cost: Option<Decimal>,
+        ) -> Result<crate::domain::repositories::AverageCostRewrite, InventoryError> {
+            unimplemented!()
+        }
+
+        async fn find_costing_method(
+            &self,
+            _stock_id: StockId,
+        ) -> Result<crate::domain::value_objects::CostingMethod, InventoryError> {
+            unimplemented!()
+        }
+
+        async fn find_open_cost_layers(
+            &self,
+            _stock_id: StockId,
+        ) -> Result<Vec<crate::domain::repositories::CostLayer>, InventoryError> {
+            unimplemented!()
+        }
+    }
+
+    struct MockAdjustmentRepository {
+        adjustments: Mutex<Vec<StockAdjustment>>,
+    }
+
+    impl MockAdjustmentRepository {
+        fn new() -> Self {
+            Self {
+                adjustments: Mutex::new(Vec::new()),
+            }
+        }
+
+        fn get_adjustments(&self) -> Vec<StockAdjustment> {
+            self.adjustments.lock().unwrap().clone()
+        }
+    }
+
+    #[async_trait]
+    impl AdjustmentRepository for MockAdjustmentRepository {
+        async fn save(&self, adjustment: &StockAdjustment) -> Result<(), InventoryError> {
+            self.adjustments.lock().unwrap().push(adjustment.clone());
+            Ok(())
+        }
+
+        async fn find_by_id(
+            &self,
+            _id: AdjustmentId,
+        ) -> Result<Option<StockAdjustment>, InventoryError> {
+            unimplemented!()
+        }
+
+        async fn find_by_id_with_items(
+            &self,
+            _id: AdjustmentId,
+        ) -> Result<Option<StockAdjustment>, InventoryError> {
+            unimplemented!()
+        }
+
+        async fn find_by_store(
+            &self,
+            _store_id: StoreId,
+        ) -> Result<Vec<StockAdjustment>, InventoryError> {
+            unimplemented!()
+        }
+
+        async fn update(&self, _adjustment: &StockAdjustment) -> Result<(), InventoryError> {
+            unimplemented!()
+        }
+
+        async fn generate_adjustment_number(
+            &self,
+            _store_id: StoreId,
+        ) -> Result<String, InventoryError> {
+            Ok("ADJ-TEST-00001".to_string())
+        }
+
+        async fn find_paginated(
+            &self,
+            _store_ids: Option<&[StoreId]>,
+            _status: Option<&str>,
+            _after_id: Option<Uuid>,
+            _page: i64,
+            _page_size: i64,
+        ) -> Result<(Vec<StockAdjustment>, i64), InventoryError> {
+            unimplemented!()
+        }
     }
 
     fn create_in_transit_transfer(
@@ -613,6 +823,7 @@ mod tests {
         let transfer_repo = Arc::new(MockTransferRepository::new());
         let stock_repo = Arc::new(MockStockRepository::new());
         let movement_repo = Arc::new(MockMovementRepository::new());
+        let adjustment_repo = Arc::new(MockAdjustmentRepository::new());
 
         let from_store_id = StoreId::new();
         let to_store_id = StoreId::new();
@@ -632,6 +843,7 @@ mod tests {
             transfer_repo.clone(),
             stock_repo.clone(),
             movement_repo.clone(),
+            adjustment_repo.clone(),
         );
 
         let command = ReceiveTransferCommand {
@@ -648,6 +860,7 @@ mod tests {
 
         let response = result.unwrap();
         assert_eq!(response.status, "completed");
+        assert_eq!(response.items[0].quantity_discrepancy, Some(dec!(0)));
         assert!(response.received_date.is_some());
         assert!(response.received_by_id.is_some());
         assert_eq!(response.items[0].quantity_received, Some(dec!(10)));
@@ -670,6 +883,7 @@ mod tests {
         let transfer_repo = Arc::new(MockTransferRepository::new());
         let stock_repo = Arc::new(MockStockRepository::new());
         let movement_repo = Arc::new(MockMovementRepository::new());
+        let adjustment_repo = Arc::new(MockAdjustmentRepository::new());
 
         let from_store_id = StoreId::new();
         let to_store_id = StoreId::new();
@@ -687,6 +901,7 @@ mod tests {
             transfer_repo.clone(),
             stock_repo.clone(),
             movement_repo.clone(),
+            adjustment_repo.clone(),
         );
 
         let command = ReceiveTransferCommand {
@@ -712,11 +927,17 @@ mod tests {
         let transfer_repo = Arc::new(MockTransferRepository::new());
         let stock_repo = Arc::new(MockStockRepository::new());
         let movement_repo = Arc::new(MockMovementRepository::new());
+        let adjustment_repo = Arc::new(MockAdjustmentRepository::new());
 
         let from_store_id = StoreId::new();
         let to_store_id = StoreId::new();
         let product_id = ProductId::new();
 
+        // Source stock the 10 units were shipped from
+        let source_stock = create_stock_with_quantity(from_store_id, product_id, dec!(40));
+        let source_stock_id = source_stock.id();
+        stock_repo.add_stock(source_stock);
+
         // Create in_transit transfer (shipped 10)
         let transfer = create_in_transit_transfer(from_store_id, to_store_id, product_id);
         let transfer_id = transfer.id();
@@ -727,6 +948,7 @@ mod tests {
             transfer_repo.clone(),
             stock_repo.clone(),
             movement_repo.clone(),
+            adjustment_repo.clone(),
         );
 
         // Receive only 8 (2 damaged/lost)
@@ -744,12 +966,67 @@ mod tests {
         let response = result.unwrap();
         assert_eq!(response.items[0].quantity_shipped, Some(dec!(10)));
         assert_eq!(response.items[0].quantity_received, Some(dec!(8)));
+        assert_eq!(response.items[0].quantity_discrepancy, Some(dec!(2)));
+        assert_eq!(response.status, "received_with_discrepancy");
 
         // Verify only 8 was added to stock
         let created_stock = stock_repo
             .get_stock_by_store_and_product(to_store_id, product_id)
             .unwrap();
         assert_eq!(created_stock.quantity(), dec!(8));
+
+        // The 2 missing units are written off at the source, without
+        // reducing its stock a second time
+        let adjustments = adjustment_repo.get_adjustments();
+        assert_eq!(adjustments.len(), 1);
+        let adjustment = &adjustments[0];
+        assert_eq!(adjustment.store_id(), from_store_id);
+        assert_eq!(
+            adjustment.adjustment_reason(),
+            AdjustmentReason::InTransitLoss
+        );
+        assert_eq!(adjustment.status(), AdjustmentStatus::Applied);
+        assert_eq!(adjustment.items()[0].stock_id(), source_stock_id);
+        assert_eq!(adjustment.items()[0].quantity(), dec!(-2));
+        let source_stock = stock_repo
+            .get_stock_by_store_and_product(from_store_id, product_id)
+            .unwrap();
+        assert_eq!(source_stock.quantity(), dec!(40));
+    }
+
+    #[tokio::test]
+    async fn test_receive_transfer_more_than_shipped() {
+        let transfer_repo = Arc::new(MockTransferRepository::new());
+        let stock_repo = Arc::new(MockStockRepository::new());
+        let movement_repo = Arc::new(MockMovementRepository::new());
+        let adjustment_repo = Arc::new(MockAdjustmentRepository::new());
+
+        let transfer = create_in_transit_transfer(StoreId::new(), StoreId::new(), ProductId::new());
+        let transfer_id = transfer.id();
+        let item_id = transfer.items()[0].id();
+        transfer_repo.add_transfer(transfer);
+
+        let use_case = ReceiveTransferUseCase::new(
+            transfer_repo,
+            stock_repo,
+            movement_repo.clone(),
+            adjustment_repo,
+        );
+
+        let command = ReceiveTransferCommand {
+            transfer_id: transfer_id.into_uuid(),
+            items: vec![ReceiveTransferItemCommand {
+                item_id,
+                quantity_received: dec!(11),
+            }],
+        };
+
+        let result = use_case.execute(command, UserId::new()).await;
+        assert!(matches!(
+            result,
+            Err(InventoryError::InvalidReceivedQuantity(id)) if id == item_id
+        ));
+        assert!(movement_repo.get_movements().is_empty());
     }
 
     #[tokio::test]
@@ -757,8 +1034,10 @@ mod tests {
         let transfer_repo = Arc::new(MockTransferRepository::new());
         let stock_repo = Arc::new(MockStockRepository::new());
         let movement_repo = Arc::new(MockMovementRepository::new());
+        let adjustment_repo = Arc::new(MockAdjustmentRepository::new());
 
-        let use_case = ReceiveTransferUseCase::new(transfer_repo, stock_repo, movement_repo);
+        let use_case =
+            ReceiveTransferUseCase::new(transfer_repo, stock_repo, movement_repo, adjustment_repo);
 
         let command = ReceiveTransferCommand {
             transfer_id: new_uuid(),
@@ -774,6 +1053,7 @@ mod tests {
         let transfer_repo = Arc::new(MockTransferRepository::new());
         let stock_repo = Arc::new(MockStockRepository::new());
         let movement_repo = Arc::new(MockMovementRepository::new());
+        let adjustment_repo = Arc::new(MockAdjustmentRepository::new());
 
         let from_store_id = StoreId::new();
         let to_store_id = StoreId::new();
@@ -795,7 +1075,8 @@ mod tests {
         let transfer_id = transfer.id();
         transfer_repo.add_transfer(transfer);
 
-        let use_case = ReceiveTransferUseCase::new(transfer_repo, stock_repo, movement_repo);
+        let use_case =
+            ReceiveTransferUseCase::new(transfer_repo, stock_repo, movement_repo, adjustment_repo);
 
         let command = ReceiveTransferCommand {
             transfer_id: transfer_id.into_uuid(),
@@ -0,0 +1,574 @@
+// RefreshCompositeCostsUseCase - recomputes recipe/bundle costs from current ingredient costs
+//
+// - Ingredient unit cost: weighted-average cost, else the recipe's estimate
+// - Nested composites are costed after their sub-composites (dependency order)
+// - Composites in (or depending on) an ingredient cycle are reported and skipped
+// - Flags margin erosion and optionally writes the new cost to cost_price
+
+use std::collections::{HashMap, VecDeque};
+use std::sync::Arc;
+
+use rust_decimal::Decimal;
+
+use crate::InventoryError;
+use crate::application::dtos::responses::{
+    CompositeCostChangeResponse, RefreshCompositeCostsResponse,
+};
+use crate::domain::entities::RecipeIngredient;
+use crate::domain::repositories::{CompositeCostSource, RecipeRepository};
+use crate::domain::value_objects::{ProductId, VariantId};
+
+/// Decimal places of the cost_price columns
+const COST_SCALE: u32 = 4;
+
+/// A product or variant, as produced by a recipe or consumed as an ingredient
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum CostItem {
+    Product(ProductId),
+    Variant(VariantId),
+}
+
+impl CostItem {
+    fn from_ids(product_id: Option<ProductId>, variant_id: Option<VariantId>) -> Option<Self> {
+        match (product_id, variant_id) {
+            (_, Some(variant_id)) => Some(CostItem::Variant(variant_id)),
+            (Some(product_id), None) => Some(CostItem::Product(product_id)),
+            (None, None) => None,
+        }
+    }
+
+    fn of_ingredient(ingredient: &RecipeIngredient) -> Option<Self> {
+        Self::from_ids(
+            ingredient.ingredient_product_id(),
+            ingredient.ingredient_variant_id(),
+        )
+    }
+}
+
+/// Use case for refreshing the cost of composite products (recipes/bundles).
+///
+/// Meant to run on a schedule. Each active recipe with
+/// calculate_cost_from_ingredients is costed per unit produced:
+/// sum(quantity * (1 + waste) * ingredient unit cost) / yield_quantity,
+/// where an ingredient that is itself a composite uses its freshly
+/// recomputed cost.
+pub struct RefreshCompositeCostsUseCase<R>
+where
+    R: RecipeRepository,
+{
+    recipe_repo: Arc<R>,
+}
+
+impl<R> RefreshCompositeCostsUseCase<R>
+where
+    R: RecipeRepository,
+{
+    /// Creates a new instance of RefreshCompositeCostsUseCase
+    pub fn new(recipe_repo: Arc<R>) -> Self {
+        Self { recipe_repo }
+    }
+
+    /// Executes the use case
+    ///
+    /// # Arguments
+    /// * `auto_adjust` - Write the recomputed cost to each changed composite's cost_price
+    ///
+    /// # Returns
+    /// RefreshCompositeCostsResponse listing changed, margin-eroded and cyclic composites
+    pub async fn execute(
+        &self,
+        auto_adjust: bool,
+    ) -> Result<RefreshCompositeCostsResponse, InventoryError> {
+        let sources = self.recipe_repo.find_composite_cost_sources().await?;
+
+        let mut unit_costs: HashMap<CostItem, Decimal> = self
+            .recipe_repo
+            .find_weighted_average_costs()
+            .await?
+            .into_iter()
+            .filter_map(|c| {
+                CostItem::from_ids(c.product_id, c.variant_id).map(|k| (k, c.unit_cost))
+            })
+            .collect();
+
+        let (order, cyclic) = dependency_order(&sources);
+
+        let mut changed = Vec::new();
+        let mut margin_eroded = Vec::new();
+
+        for index in order {
+            let source = &sources[index];
+            let recipe = &source.recipe;
+
+            let mut total_cost = Decimal::ZERO;
+            let mut ingredients_without_cost = 0;
+            for ingredient in &source.ingredients {
+                let unit_cost = CostItem::of_ingredient(ingredient)
+                    .and_then(|item| unit_costs.get(&item).copied())
+                    .or(ingredient.estimated_cost_per_unit());
+                match unit_cost {
+                    Some(cost) => total_cost += ingredient.effective_cost_at(cost),
+                    None => ingredients_without_cost += 1,
+                }
+            }
+
+            let new_cost = recipe.calculate_cost(total_cost).round_dp(COST_SCALE);
+
+            // Parent composites cost this item at its recomputed value
+            if let Some(item) = CostItem::from_ids(recipe.product_id(), recipe.variant_id()) {
+                unit_costs.insert(item, new_cost);
+            }
+
+            let is_eroded = new_cost > source.price;
+            if is_eroded {
+                margin_eroded.push(recipe.id().into_uuid());
+            }
+
+            if new_cost == source.cost_price {
+                continue;
+            }
+
+            if auto_adjust {
+                self.recipe_repo
+                    .update_composite_cost_price(recipe, new_cost)
+                    .await?;
+            }
+
+            changed.push(CompositeCostChangeResponse {
+                recipe_id: recipe.id().into_uuid(),
+                recipe_name: recipe.name().to_string(),
+                product_id: recipe.product_id().map(|id| id.into_uuid()),
+                variant_id: recipe.variant_id().map(|id| id.into_uuid()),
+                previous_cost: source.cost_price,
+                new_cost,
+                change: new_cost - source.cost_price,
+                price: source.price,
+                margin_eroded: is_eroded,
+                ingredients_without_cost,
+                adjusted: auto_adjust,
+            });
+        }
+
+        Ok(RefreshCompositeCostsResponse {
+            composites_evaluated: sources.len(),
+            changed,
+            margin_eroded,
+            cycle_recipe_ids: cyclic
+                .into_iter()
+                .map(|i| sources[i].recipe.id().into_uuid())
+                .collect(),
+            auto_adjust,
+        })
+    }
+}
+
+/// Orders composites so every sub-composite comes before the composites that
+/// use it (Kahn's algorithm). Returns (ordered indices, indices left over
+/// because they are in a cycle or depend on one).
+fn dependency_order(sources: &[CompositeCostSource]) -> (Vec<usize>, Vec<usize>) {
+    let producers: HashMap<CostItem, usize> = sources
+        .iter()
+        .enumerate()
+        .filter_map(|(i, s)| {
+            CostItem::from_ids(s.recipe.product_id(), s.recipe.variant_id()).map(|k| (k, i))
+        })
+        .collect();
+
+    let mut pending = vec![0usize; sources.len()];
+    let mut dependents: Vec<Vec<usize>> = vec![Vec::new(); sources.len()];
+    for (i, source) in sources.iter().enumerate() {
+        for ingredient in &source.ingredients {
+            if let Some(&dep) = CostItem::of_ingredient(ingredient).and_then(|k| producers.get(&k))
+            {
+                pending[i] += 1;
+                dependents[dep].push(i);
+            }
+        }
+    }
+
+    let mut queue: VecDeque<usize> = (0..sources.len()).filter(|&i| pending[i] == 0).collect();
+    let mut order = Vec::with_capacity(sources.len());
+    while let Some(i) = queue.pop_front() {
+        order.push(i);
+        for &dependent in &dependents[i] {
+            pending[dependent] -= 1;
+            if pending[dependent] == 0 {
+                queue.push_back(dependent);
+            }
+        }
+    }
+
+    let cyclic = (0..sources.len()).filter(|&i| pending[i] > 0).collect();
+    (order, cyclic)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use async_trait::async_trait;
+    use rust_decimal_macros::dec;
+    use std::sync::Mutex;
+    use uuid::Uuid;
+
+    use crate::domain::entities::{IngredientSubstitute, Recipe};
+    use crate::domain::repositories::ItemUnitCost;
+    use crate::domain::value_objects::{IngredientId, RecipeId, SubstituteId, UnitOfMeasure};
+
+    struct MockRecipeRepository {
+        sources: Vec<CompositeCostSource>,
+        unit_costs: Vec<ItemUnitCost>,
+        updated: Mutex<Vec<(RecipeId, Decimal)>>,
+    }
+
+    impl MockRecipeRepository {
+        fn new(sources: Vec<CompositeCostSource>, unit_costs: Vec<ItemUnitCost>) -> Self {
+            Self {
+                sources,
+                unit_costs,
+                updated: Mutex::new(Vec::new()),
+            }
+        }
+    }
+
+    #[async_trait]
+    impl RecipeRepository for MockRecipeRepository {
+        async fn save(&self, _recipe: &Recipe) -> Result<(), InventoryError> {
+            unimplemented!()
+        }
+
+        async fn find_by_id(&self, _id: RecipeId) -> Result<Option<Recipe>, InventoryError> {
+            unimplemented!()
+        }
+
+        async fn find_by_product(
+            &self,
+            _product_id: ProductId,
+        ) -> Result<Vec<Recipe>, InventoryError> {
+            unimplemented!()
+        }
+
+        async fn find_by_variant(
+            &self,
+            _variant_id: VariantId,
+        ) -> Result<Vec<Recipe>, InventoryError> {
+            unimplemented!()
+        }
+
+        async fn find_active_by_product(
+            &self,
+            _product_id: ProductId,
+        ) -> Result<Option<Recipe>, InventoryError> {
+            unimplemented!()
+        }
+
+        async fn find_active_by_variant(
+            &self,
+            _variant_id: VariantId,
+        ) -> Result<Option<Recipe>, InventoryError> {
+            unimplemented!()
+        }
+
+        async fn find_paginated(
+            &self,
+            _is_active: Option<bool>,
+            _search: Option<&str>,
+            _after_id: Option<Uuid>,
+            _page: i64,
+            _page_size: i64,
+        ) -> Result<(Vec<Recipe>, i64), InventoryError> {
+            unimplemented!()
+        }
+
+        async fn count_filtered(
+            &self,
+            _is_active: Option<bool>,
+            _search: Option<&str>,
+        ) -> Result<i64, InventoryError> {
+            unimplemented!()
+        }
+
+        async fn update(&self, _recipe: &Recipe) -> Result<(), InventoryError> {
+            unimplemented!()
+        }
+
+        async fn delete(&self, _id: RecipeId) -> Result<(), InventoryError> {
+            unimplemented!()
+        }
+
+        async fn save_ingredient(
+            &self,
+            _ingredient: &RecipeIngredient,
+        ) -> Result<(), InventoryError> {
+            unimplemented!()
+        }
+
+        async fn find_ingredient_by_id(
+            &self,
+            _id: IngredientId,
+        ) -> Result<Option<RecipeIngredient>, InventoryError> {
+            unimplemented!()
+        }
+
+        async fn find_ingredients_by_recipe(
+            &self,
+            _recipe_id: RecipeId,
+        ) -> Result<Vec<RecipeIngredient>, InventoryError> {
+            unimplemented!()
+        }
+
+        async fn update_ingredient(
+            &self,
+            _ingredient: &RecipeIngredient,
+        ) -> Result<(), InventoryError> {
+            unimplemented!()
+        }
+
+        async fn delete_ingredient(&self, _id: IngredientId) -> Result<(), InventoryError> {
+            unimplemented!()
+        }
+
+        async fn save_substitute(
+            &self,
+            _substitute: &IngredientSubstitute,
+        ) -> Result<(), InventoryError> {
+            unimplemented!()
+        }
+
+        async fn find_substitutes_by_ingredient(
+            &self,
+            _ingredient_id: IngredientId,
+        ) -> Result<Vec<IngredientSubstitute>, InventoryError> {
+            unimplemented!()
+        }
+
+        async fn delete_substitute(&self, _id: SubstituteId) -> Result<(), InventoryError> {
+            unimplemented!()
+        }
+
+        async fn find_composite_cost_sources(
+            &self,
+        ) -> Result<Vec<CompositeCostSource>, InventoryError> {
+            Ok(self.sources.clone())
+        }
+
+        async fn find_weighted_average_costs(&self) -> Result<Vec<ItemUnitCost>, InventoryError> {
+            Ok(self.unit_costs.clone())
+        }
+
+        async fn update_composite_cost_price(
+            &self,
+            recipe: &Recipe,
+            cost_price: Decimal,
+        ) -> Result<(), InventoryError> {
+            self.updated.lock().unwrap().push((recipe.id(), cost_price));
+            Ok(())
+        }
+    }
+
+    fn composite(
+        product_id: ProductId,
+        yield_quantity: Decimal,
+        ingredients: &[(ProductId, Decimal)],
+        price: Decimal,
+        cost_price: Decimal,
+    ) -> CompositeCostSource {
+        let recipe =
+            Recipe::create_for_product(product_id, "Composite".to_string(), yield_quantity)
+                .unwrap();
+        let ingredients = ingredients
+            .iter()
+            .map(|(ingredient_id, quantity)| {
+                RecipeIngredient::create_for_product(
+                    recipe.id(),
+                    *ingredient_id,
+                    *quantity,
+                    UnitOfMeasure::Unit,
+                )
+                .unwrap()
+            })
+            .collect();
+        CompositeCostSource {
+            recipe,
+            ingredients,
+            price,
+            cost_price,
+        }
+    }
+
+    fn wac(product_id: ProductId, unit_cost: Decimal) -> ItemUnitCost {
+        ItemUnitCost {
+            product_id: Some(product_id),
+            variant_id: None,
+            unit_cost,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_reports_change_from_weighted_average_costs() {
+        let flour = ProductId::new();
+        let sugar = ProductId::new();
+        let cake = composite(
+            ProductId::new(),
+            dec!(2),
+            &[(flour, dec!(3)), (sugar, dec!(1))],
+            dec!(10),
+            dec!(4),
+        );
+        let repo = Arc::new(MockRecipeRepository::new(
+            vec![cake],
+            vec![wac(flour, dec!(2)), wac(sugar, dec!(4))],
+        ));
+
+        let result = RefreshCompositeCostsUseCase::new(repo.clone())
+            .execute(false)
+            .await
+            .unwrap();
+
+        // (3 * 2 + 1 * 4) / 2 = 5
+        assert_eq!(result.composites_evaluated, 1);
+        assert_eq!(result.changed.len(), 1);
+        assert_eq!(result.changed[0].previous_cost, dec!(4));
+        assert_eq!(result.changed[0].new_cost, dec!(5));
+        assert_eq!(result.changed[0].change, dec!(1));
+        assert!(!result.changed[0].margin_eroded);
+        assert!(!result.changed[0].adjusted);
+        assert!(repo.updated.lock().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_nested_composites_cost_in_dependency_order() {
+        let flour = ProductId::new();
+        let dough = ProductId::new();
+        // Parent listed first so ordering is not accidental
+        let pizza = composite(
+            ProductId::new(),
+            dec!(1),
+            &[(dough, dec!(2))],
+            dec!(5),
+            dec!(0),
+        );
+        let dough_recipe = composite(dough, dec!(1), &[(flour, dec!(2))], dec!(3), dec!(0));
+        let repo = Arc::new(MockRecipeRepository::new(
+            vec![pizza, dough_recipe],
+            // Stale stock cost for dough must be overridden by the recomputed cost
+            vec![wac(flour, dec!(1.5)), wac(dough, dec!(100))],
+        ));
+
+        let result = RefreshCompositeCostsUseCase::new(repo)
+            .execute(false)
+            .await
+            .unwrap();
+
+        let cost_of = |product_id: ProductId| {
+            result
+                .changed
+                .iter()
+                .find(|c| c.product_id == Some(product_id.into_uuid()))
+                .unwrap()
+                .new_cost
+        };
+        assert_eq!(cost_of(dough), dec!(3));
+        // 2 * 3 = 6 > price 5
+        let pizza_change = result.changed.iter().find(|c| c.price == dec!(5)).unwrap();
+        assert_eq!(pizza_change.new_cost, dec!(6));
+        assert!(pizza_change.margin_eroded);
+        assert_eq!(result.margin_eroded, vec![pizza_change.recipe_id]);
+    }
+
+    #[tokio::test]
+    async fn test_cycles_are_reported_and_skipped() {
+        let a = ProductId::new();
+        let b = ProductId::new();
+        let c = ProductId::new();
+        let ingredient = ProductId::new();
+        let recipe_a = composite(a, dec!(1), &[(b, dec!(1))], dec!(10), dec!(0));
+        let recipe_b = composite(b, dec!(1), &[(a, dec!(1))], dec!(10), dec!(0));
+        let recipe_c = composite(c, dec!(1), &[(a, dec!(1))], dec!(10), dec!(0));
+        let standalone = composite(
+            ProductId::new(),
+            dec!(1),
+            &[(ingredient, dec!(1))],
+            dec!(10),
+            dec!(0),
+        );
+        let cyclic_ids = [
+            recipe_a.recipe.id().into_uuid(),
+            recipe_b.recipe.id().into_uuid(),
+            recipe_c.recipe.id().into_uuid(),
+        ];
+        let repo = Arc::new(MockRecipeRepository::new(
+            vec![recipe_a, recipe_b, recipe_c, standalone],
+            vec![wac(ingredient, dec!(2))],
+        ));
+
+        let result = RefreshCompositeCostsUseCase::new(repo)
+            .execute(false)
+            .await
+            .unwrap();
+
+        assert_eq!(result.cycle_recipe_ids.len(), 3);
+        assert!(
+            cyclic_ids
+                .iter()
+                .all(|id| result.cycle_recipe_ids.contains(id))
+        );
+        assert_eq!(result.changed.len(), 1);
+        assert_eq!(result.changed[0].new_cost, dec!(2));
+    }
+
+    #[tokio::test]
+    async fn test_auto_adjust_updates_only_changed_composites() {
+        let ingredient = ProductId::new();
+        let changed = composite(
+            ProductId::new(),
+            dec!(1),
+            &[(ingredient, dec!(2))],
+            dec!(10),
+            dec!(3),
+        );
+        let unchanged = composite(
+            ProductId::new(),
+            dec!(1),
+            &[(ingredient, dec!(1))],
+            dec!(10),
+            dec!(2),
+        );
+        let changed_id = changed.recipe.id();
+        let repo = Arc::new(MockRecipeRepository::new(
+            vec![changed, unchanged],
+            vec![wac(ingredient, dec!(2))],
+        ));
+
+        let result = RefreshCompositeCostsUseCase::new(repo.clone())
+            .execute(true)
+            .await
+            .unwrap();
+
+        assert_eq!(result.changed.len(), 1);
+        assert!(result.changed[0].adjusted);
+        assert_eq!(*repo.updated.lock().unwrap(), vec![(changed_id, dec!(4))]);
+    }
+
+    #[tokio::test]
+    async fn test_falls_back_to_estimate_and_counts_missing_costs() {
+        let estimated = ProductId::new();
+        let unknown = ProductId::new();
+        let mut source = composite(
+            ProductId::new(),
+            dec!(1),
+            &[(estimated, dec!(2)), (unknown, dec!(1))],
+            dec!(10),
+            dec!(0),
+        );
+        source.ingredients[0].set_estimated_cost_per_unit(Some(dec!(1.25)));
+        let repo = Arc::new(MockRecipeRepository::new(vec![source], vec![]));
+
+        let result = RefreshCompositeCostsUseCase::new(repo)
+            .execute(false)
+            .await
+            .unwrap();
+
+        assert_eq!(result.changed[0].new_cost, dec!(2.5));
+        assert_eq!(result.changed[0].ingredients_without_cost, 1);
+    }
+}
@@ -0,0 +1,480 @@
+// RegisterSerialsUseCase - registers serialized units in stock at a store
+
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use crate::InventoryError;
+use crate::application::dtos::commands::RegisterSerialsCommand;
+use crate::application::dtos::responses::ProductSerialResponse;
+use crate::domain::entities::ProductSerial;
+use crate::domain::repositories::{ProductRepository, ProductSerialRepository};
+use crate::domain::value_objects::{ProductId, VariantId};
+use identity::StoreId;
+
+/// Use case for registering the serial numbers of units received at a store.
+///
+/// Validates:
+/// - Product exists and is serial-tracked
+/// - Variant, when given, belongs to the product
+/// - At least one serial is given and none repeats within the batch
+///
+/// Serials already registered for the product are rejected by the repository.
+pub struct RegisterSerialsUseCase<P, N>
+where
+    P: ProductRepository,
+    N: ProductSerialRepository,
+{
+    product_repo: Arc<P>,
+    serial_repo: Arc<N>,
+}
+
+impl<P, N> RegisterSerialsUseCase<P, N>
+where
+    P: ProductRepository,
+    N: ProductSerialRepository,
+{
+    /// Creates a new instance of RegisterSerialsUseCase
+    pub fn new(product_repo: Arc<P>, serial_repo: Arc<N>) -> Self {
+        Self {
+            product_repo,
+            serial_repo,
+        }
+    }
+
+    /// Executes the use case to register serials
+    ///
+    /// # Errors
+    /// * `InventoryError::ProductNotFound` - If product doesn't exist
+    /// * `InventoryError::ProductNotSerialized` - If product isn't serial-tracked
+    /// * `InventoryError::VariantNotFound` - If variant doesn't exist for the product
+    /// * `InventoryError::InvalidSerialNumber` - If the list is empty or a serial is blank/too long
+    /// * `InventoryError::DuplicateSerial` - If a serial repeats or is already registered
+    pub async fn execute(
+        &self,
+        command: RegisterSerialsCommand,
+    ) -> Result<Vec<ProductSerialResponse>, InventoryError> {
+        let product_id = ProductId::from_uuid(command.product_id);
+        let store_id = StoreId::from_uuid(command.store_id);
+
+        let product = self
+            .product_repo
+            .find_by_id(product_id)
+            .await?
+            .ok_or(InventoryError::ProductNotFound(command.product_id))?;
+        if !product.is_serialized() {
+            return Err(InventoryError::ProductNotSerialized(command.product_id));
+        }
+
+        let variant_id = match command.variant_id {
+            Some(vid) => {
+                let variant_id = VariantId::from_uuid(vid);
+                match self.product_repo.find_variant_by_id(variant_id).await? {
+                    Some(variant) if variant.product_id() == product_id => Some(variant_id),
+                    _ => return Err(InventoryError::VariantNotFound(vid)),
+                }
+            }
+            None => None,
+        };
+
+        if command.serial_numbers.is_empty() {
+            return Err(InventoryError::InvalidSerialNumber);
+        }
+
+        let mut seen = HashSet::new();
+        let mut serials = Vec::with_capacity(command.serial_numbers.len());
+        for raw in &command.serial_numbers {
+            let serial = ProductSerial::create(product_id, variant_id, store_id, raw)?;
+            if !seen.insert(serial.serial_number().to_string()) {
+                return Err(InventoryError::DuplicateSerial(
+                    serial.serial_number().to_string(),
+                ));
+            }
+            serials.push(serial);
+        }
+
+        self.serial_repo.save_batch(&serials).await?;
+
+        Ok(serials.iter().map(ProductSerialResponse::from).collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use async_trait::async_trait;
+    use std::collections::HashMap;
+    use std::sync::Mutex;
+    use uuid::Uuid;
+
+    use crate::domain::entities::{Product, ProductVariant, ProductVendor};
+    use crate::domain::value_objects::{Barcode, SerialStatus, Sku, UnitConversion, UnitOfMeasure};
+
+    struct MockProductRepository {
+        products: Mutex<HashMap<ProductId, Product>>,
+        variants: Mutex<HashMap<VariantId, ProductVariant>>,
+    }
+
+    impl MockProductRepository {
+        fn new() -> Self {
+            Self {
+                products: Mutex::new(HashMap::new()),
+                variants: Mutex::new(HashMap::new()),
+            }
+        }
+
+        fn add_product(&self, product: Product) {
+            let mut products = self.products.lock().unwrap();
+            products.insert(product.id(), product);
+        }
+
+        fn add_variant(&self, variant: ProductVariant) {
+            let mut variants = self.variants.lock().unwrap();
+            variants.insert(variant.id(), variant);
+        }
+    }
+
+    #[async_trait]
+    impl ProductRepository for MockProductRepository {
+        async fn save(&self, _product: &Product) -> Result<(), InventoryError> {
+            unimplemented!()
+        }
+
+        async fn find_by_id(&self, id: ProductId) -> Result<Option<Product>, InventoryError> {
+            let products = self.products.lock().unwrap();
+            Ok(products.get(&id).cloned())
+        }
+
+        async fn find_by_sku(&self, _sku: &Sku) -> Result<Option<Product>, InventoryError> {
+            unimplemented!()
+        }
+
+        async fn find_by_barcode(
+            &self,
+            _barcode: &Barcode,
+        ) -> Result<Option<Product>, InventoryError> {
+            unimplemented!()
+        }
+
+        async fn update(&self, _product: &Product) -> Result<(), InventoryError> {
+            unimplemented!()
+        }
+
+        async fn delete(&self, _id: ProductId) -> Result<(), InventoryError> {
+            unimplemented!()
+        }
+
+        async fn find_active(&self) -> Result<Vec<Product>, InventoryError> {
+            unimplemented!()
+        }
+
+        async fn find_by_category(
+            &self,
+            _category_id: crate::domain::value_objects::CategoryId,
+        ) -> Result<Vec<Product>, InventoryError> {
+            unimplemented!()
+        }
+
+        async fn save_variant(&self, _variant: &ProductVariant) -> Result<(), InventoryError> {
+            unimplemented!()
+        }
+
+        async fn find_variant_by_id(
+            &self,
+            id: VariantId,
+        ) -> Result<Option<ProductVariant>, InventoryError> {
+            let variants = self.variants.lock().unwrap();
+            Ok(variants.get(&id).cloned())
+        }
+
+        async fn find_variant_by_sku(
+            &self,
+            _sku: &Sku,
+        ) -> Result<Option<ProductVariant>, InventoryError> {
+            unimplemented!()
+        }
+
+        async fn find_variant_by_barcode(
+            &self,
+            _barcode: &Barcode,
+        ) -> Result<Option<ProductVariant>, InventoryError> {
+            unimplemented!()
+        }
+
+        async fn find_variants_by_product(
+            &self,
+            _product_id: ProductId,
+        ) -> Result<Vec<ProductVariant>, InventoryError> {
+            unimplemented!()
+        }
+
+        async fn update_variant(&self, _variant: &ProductVariant) -> Result<(), InventoryError> {
+            unimplemented!()
+        }
+
+        async fn delete_variant(&self, _id: VariantId) -> Result<(), InventoryError> {
+            unimplemented!()
+        }
+
+        async fn count_variants(&self, _product_id: ProductId) -> Result<u32, InventoryError> {
+            unimplemented!()
+        }
+
+        async fn save_product_vendors(
+            &self,
+            _vendors: &[ProductVendor],
+        ) -> Result<(), InventoryError> {
+            unimplemented!()
+        }
+
+        async fn find_product_vendors(
+            &self,
+            _product_id: ProductId,
+        ) -> Result<Vec<ProductVendor>, InventoryError> {
+            unimplemented!()
+        }
+
+        async fn find_preferred_vendor(
+            &self,
+            _product_id: ProductId,
+        ) -> Result<Option<ProductVendor>, InventoryError> {
+            unimplemented!()
+        }
+
+        async fn find_paginated(
+            &self,
+            _category_id: Option<crate::domain::value_objects::CategoryId>,
+            _is_active: Option<bool>,
+            _search: Option<&str>,
+            _after_id: Option<Uuid>,
+            _page: i64,
+            _page_size: i64,
+        ) -> Result<(Vec<Product>, i64), InventoryError> {
+            unimplemented!()
+        }
+
+        async fn count_filtered(
+            &self,
+            _category_id: Option<crate::domain::value_objects::CategoryId>,
+            _is_active: Option<bool>,
+            _search: Option<&str>,
+        ) -> Result<i64, InventoryError> {
+            unimplemented!()
+        }
+
+        async fn search(
+            &self,
+            _term: &str,
+            _category_id: Option<crate::domain::value_objects::CategoryId>,
+            _active_only: bool,
+            _page: i64,
+            _page_size: i64,
+        ) -> Result<(Vec<(Product, f64)>, i64), InventoryError> {
+            unimplemented!()
+        }
+
+        async fn replace_unit_conversions(
+            &self,
+            _product_id: ProductId,
+            _conversions: &[UnitConversion],
+        ) -> Result<(), InventoryError> {
+            unimplemented!()
+        }
+
+        async fn find_unit_conversions(
+            &self,
+            _product_id: ProductId,
+        ) -> Result<Vec<UnitConversion>, InventoryError> {
+            unimplemented!()
+        }
+    }
+
+    struct MockSerialRepository {
+        serials: Mutex<Vec<ProductSerial>>,
+    }
+
+    impl MockSerialRepository {
+        fn new() -> Self {
+            Self {
+                serials: Mutex::new(Vec::new()),
+            }
+        }
+    }
+
+    #[async_trait]
+    impl ProductSerialRepository for MockSerialRepository {
+        async fn save_batch(&self, serials: &[ProductSerial]) -> Result<(), InventoryError> {
+            let mut stored = self.serials.lock().unwrap();
+            for serial in serials {
+                if stored.iter().any(|s| {
+                    s.product_id() == serial.product_id()
+                        && s.serial_number() == serial.serial_number()
+                }) {
+                    return Err(InventoryError::DuplicateSerial(
+                        serial.serial_number().to_string(),
+                    ));
+                }
+            }
+            stored.extend_from_slice(serials);
+            Ok(())
+        }
+
+        async fn update(
+            &self,
+            _serial: &ProductSerial,
+            _expected_status: SerialStatus,
+        ) -> Result<(), InventoryError> {
+            unimplemented!()
+        }
+
+        async fn find_by_product_and_number(
+            &self,
+            _product_id: ProductId,
+            _serial_number: &str,
+        ) -> Result<Option<ProductSerial>, InventoryError> {
+            unimplemented!()
+        }
+
+        async fn find_by_serial_number(
+            &self,
+            _serial_number: &str,
+        ) -> Result<Vec<ProductSerial>, InventoryError> {
+            unimplemented!()
+        }
+
+        async fn find_by_product(
+            &self,
+            _product_id: ProductId,
+            _store_id: StoreId,
+            _status: Option<SerialStatus>,
+        ) -> Result<Vec<ProductSerial>, InventoryError> {
+            unimplemented!()
+        }
+    }
+
+    fn serialized_product() -> Product {
+        let mut product = Product::create("Laptop".to_string(), UnitOfMeasure::Unit, None);
+        product.set_serialized(true);
+        product
+    }
+
+    fn command(product_id: ProductId, serials: &[&str]) -> RegisterSerialsCommand {
+        RegisterSerialsCommand {
+            product_id: product_id.into_uuid(),
+            variant_id: None,
+            store_id: Uuid::now_v7(),
+            serial_numbers: serials.iter().map(|s| s.to_string()).collect(),
+        }
+    }
+
+    fn use_case(
+        products: Arc<MockProductRepository>,
+        serials: Arc<MockSerialRepository>,
+    ) -> RegisterSerialsUseCase<MockProductRepository, MockSerialRepository> {
+        RegisterSerialsUseCase::new(products, serials)
+    }
+
+    #[tokio::test]
+    async fn test_register_serials_success() {
+        let products = Arc::new(MockProductRepository::new());
+        let serials = Arc::new(MockSerialRepository::new());
+        let product = serialized_product();
+        let product_id = product.id();
+        products.add_product(product);
+
+        let result = use_case(products, serials.clone())
+            .execute(command(product_id, &[" SN-001 ", "SN-002"]))
+            .await
+            .unwrap();
+
+        assert_eq!(result.len(), 2);
+        assert_eq!(result[0].serial_number, "SN-001");
+        assert_eq!(result[0].status, "available");
+        assert_eq!(serials.serials.lock().unwrap().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_register_serials_product_not_serialized() {
+        let products = Arc::new(MockProductRepository::new());
+        let serials = Arc::new(MockSerialRepository::new());
+        let product = Product::create("Cable".to_string(), UnitOfMeasure::Unit, None);
+        let product_id = product.id();
+        products.add_product(product);
+
+        let result = use_case(products, serials)
+            .execute(command(product_id, &["SN-001"]))
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(InventoryError::ProductNotSerialized(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_register_serials_rejects_duplicates_in_batch() {
+        let products = Arc::new(MockProductRepository::new());
+        let serials = Arc::new(MockSerialRepository::new());
+        let product = serialized_product();
+        let product_id = product.id();
+        products.add_product(product);
+
+        let result = use_case(products, serials.clone())
+            .execute(command(product_id, &["SN-001", "SN-001 "]))
+            .await;
+
+        assert!(matches!(result, Err(InventoryError::DuplicateSerial(_))));
+        assert!(serials.serials.lock().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_register_serials_rejects_already_registered() {
+        let products = Arc::new(MockProductRepository::new());
+        let serials = Arc::new(MockSerialRepository::new());
+        let product = serialized_product();
+        let product_id = product.id();
+        products.add_product(product);
+        let use_case = use_case(products, serials);
+
+        use_case
+            .execute(command(product_id, &["SN-001"]))
+            .await
+            .unwrap();
+        let result = use_case.execute(command(product_id, &["SN-001"])).await;
+
+        assert!(matches!(result, Err(InventoryError::DuplicateSerial(_))));
+    }
+
+    #[tokio::test]
+    async fn test_register_serials_empty_list() {
+        let products = Arc::new(MockProductRepository::new());
+        let serials = Arc::new(MockSerialRepository::new());
+        let product = serialized_product();
+        let product_id = product.id();
+        products.add_product(product);
+
+        let result = use_case(products, serials)
+            .execute(command(product_id, &[]))
+            .await;
+
+        assert!(matches!(result, Err(InventoryError::InvalidSerialNumber)));
+    }
+
+    #[tokio::test]
+    async fn test_register_serials_variant_of_other_product() {
+        let products = Arc::new(MockProductRepository::new());
+        let serials = Arc::new(MockSerialRepository::new());
+        let product = serialized_product();
+        let product_id = product.id();
+        let other = Product::create("Other".to_string(), UnitOfMeasure::Unit, None);
+        let variant = ProductVariant::create(other.id(), other.sku(), 1, "Silver".to_string());
+        let variant_id = variant.id();
+        products.add_product(product);
+        products.add_variant(variant);
+
+        let mut cmd = command(product_id, &["SN-001"]);
+        cmd.variant_id = Some(variant_id.into_uuid());
+        let result = use_case(products, serials).execute(cmd).await;
+
+        assert!(matches!(result, Err(InventoryError::VariantNotFound(_))));
+    }
+}
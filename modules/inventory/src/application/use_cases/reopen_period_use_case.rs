@@ -0,0 +1,147 @@
+// ReopenPeriodUseCase - reopens a closed inventory period
+
+use std::sync::Arc;
+
+use identity::UserId;
+use uuid::Uuid;
+
+use crate::InventoryError;
+use crate::application::dtos::responses::InventoryPeriodResponse;
+use crate::domain::repositories::InventoryPeriodRepository;
+use crate::domain::value_objects::InventoryPeriodId;
+
+/// Use case for reopening a closed inventory period so operations dated in
+/// it are accepted again
+pub struct ReopenPeriodUseCase<R>
+where
+    R: InventoryPeriodRepository,
+{
+    period_repo: Arc<R>,
+}
+
+impl<R> ReopenPeriodUseCase<R>
+where
+    R: InventoryPeriodRepository,
+{
+    /// Creates a new instance of ReopenPeriodUseCase
+    pub fn new(period_repo: Arc<R>) -> Self {
+        Self { period_repo }
+    }
+
+    /// Executes the use case to reopen a period
+    ///
+    /// # Errors
+    /// * `InventoryError::PeriodNotFound` - If the period doesn't exist
+    /// * `InventoryError::PeriodNotClosed` - If the period is open
+    pub async fn execute(
+        &self,
+        period_id: Uuid,
+        actor_id: UserId,
+    ) -> Result<InventoryPeriodResponse, InventoryError> {
+        let mut period = self
+            .period_repo
+            .find_by_id(InventoryPeriodId::from_uuid(period_id))
+            .await?
+            .ok_or(InventoryError::PeriodNotFound(period_id))?;
+
+        period.reopen(actor_id)?;
+        self.period_repo.update(&period).await?;
+
+        Ok(InventoryPeriodResponse::from(&period))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use async_trait::async_trait;
+    use chrono::NaiveDate;
+    use identity::StoreId;
+    use std::sync::Mutex;
+
+    use crate::domain::entities::InventoryPeriod;
+
+    struct MockInventoryPeriodRepository {
+        periods: Mutex<Vec<InventoryPeriod>>,
+    }
+
+    #[async_trait]
+    impl InventoryPeriodRepository for MockInventoryPeriodRepository {
+        async fn save(&self, _period: &InventoryPeriod) -> Result<(), InventoryError> {
+            unimplemented!()
+        }
+
+        async fn update(&self, period: &InventoryPeriod) -> Result<(), InventoryError> {
+            let mut periods = self.periods.lock().unwrap();
+            if let Some(existing) = periods.iter_mut().find(|p| p.id() == period.id()) {
+                *existing = period.clone();
+            }
+            Ok(())
+        }
+
+        async fn find_by_id(
+            &self,
+            id: InventoryPeriodId,
+        ) -> Result<Option<InventoryPeriod>, InventoryError> {
+            Ok(self
+                .periods
+                .lock()
+                .unwrap()
+                .iter()
+                .find(|p| p.id() == id)
+                .cloned())
+        }
+
+        async fn find_overlapping(
+            &self,
+            _store_id: StoreId,
+            _start: NaiveDate,
+            _end: NaiveDate,
+        ) -> Result<Vec<InventoryPeriod>, InventoryError> {
+            unimplemented!()
+        }
+
+        async fn find_closed_containing(
+            &self,
+            _store_id: StoreId,
+            _date: NaiveDate,
+        ) -> Result<Option<InventoryPeriod>, InventoryError> {
+            unimplemented!()
+        }
+
+        async fn find_by_store(
+            &self,
+            _store_id: StoreId,
+        ) -> Result<Vec<InventoryPeriod>, InventoryError> {
+            unimplemented!()
+        }
+    }
+
+    #[tokio::test]
+    async fn test_reopen_closed_period() {
+        let user = UserId::new();
+        let mut period = InventoryPeriod::create(
+            StoreId::new(),
+            NaiveDate::from_ymd_opt(2026, 8, 1).unwrap(),
+            NaiveDate::from_ymd_opt(2026, 8, 31).unwrap(),
+        )
+        .unwrap();
+        period.close(user).unwrap();
+        let period_id = period.id().into_uuid();
+        let repo = Arc::new(MockInventoryPeriodRepository {
+            periods: Mutex::new(vec![period]),
+        });
+        let use_case = ReopenPeriodUseCase::new(repo.clone());
+
+        let response = use_case.execute(period_id, user).await.unwrap();
+        assert_eq!(response.status, "open");
+        assert_eq!(response.reopened_by_id, Some(user.into_uuid()));
+        assert!(!repo.periods.lock().unwrap()[0].is_closed());
+
+        let again = use_case.execute(period_id, user).await;
+        assert!(matches!(again, Err(InventoryError::PeriodNotClosed(_))));
+
+        let missing = use_case.execute(Uuid::nil(), user).await;
+        assert!(matches!(missing, Err(InventoryError::PeriodNotFound(_))));
+    }
+}
@@ -0,0 +1,240 @@
+// ReorderCategoriesUseCase - sets the order of sibling categories
+
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+
+use crate::InventoryError;
+use crate::application::dtos::commands::ReorderCategoriesCommand;
+use crate::application::dtos::responses::CategoryResponse;
+use crate::domain::repositories::CategoryRepository;
+use crate::domain::value_objects::CategoryId;
+
+/// Use case for reordering the categories that share a parent
+pub struct ReorderCategoriesUseCase<C>
+where
+    C: CategoryRepository,
+{
+    category_repo: Arc<C>,
+}
+
+impl<C> ReorderCategoriesUseCase<C>
+where
+    C: CategoryRepository,
+{
+    /// Creates a new instance of ReorderCategoriesUseCase
+    pub fn new(category_repo: Arc<C>) -> Self {
+        Self { category_repo }
+    }
+
+    /// Executes the use case, numbering the siblings 0, 1, 2... in the
+    /// listed order
+    ///
+    /// # Returns
+    /// The siblings in their new order
+    ///
+    /// # Errors
+    /// * `InventoryError::ParentCategoryNotFound` - If the parent doesn't exist
+    /// * `InventoryError::InvalidCategoryOrder` - If the list isn't exactly
+    ///   the parent's children
+    pub async fn execute(
+        &self,
+        command: ReorderCategoriesCommand,
+    ) -> Result<Vec<CategoryResponse>, InventoryError> {
+        let siblings = match command.parent_id {
+            Some(parent_uuid) => {
+                let parent_id = CategoryId::from_uuid(parent_uuid);
+                if self.category_repo.find_by_id(parent_id).await?.is_none() {
+                    return Err(InventoryError::ParentCategoryNotFound(parent_uuid));
+                }
+                self.category_repo.find_children(parent_id).await?
+            }
+            None => self.category_repo.find_root_categories().await?,
+        };
+
+        let listed: HashSet<uuid::Uuid> = command.category_ids.iter().copied().collect();
+        if listed.len() != command.category_ids.len()
+            || listed.len() != siblings.len()
+            || siblings
+                .iter()
+                .any(|sibling| !listed.contains(&sibling.id().into_uuid()))
+        {
+            return Err(InventoryError::InvalidCategoryOrder);
+        }
+
+        let mut by_id: HashMap<uuid::Uuid, _> = siblings
+            .into_iter()
+            .map(|sibling| (sibling.id().into_uuid(), sibling))
+            .collect();
+
+        let mut responses = Vec::with_capacity(command.category_ids.len());
+        for (position, id) in command.category_ids.iter().enumerate() {
+            let Some(mut category) = by_id.remove(id) else {
+                return Err(InventoryError::InvalidCategoryOrder);
+            };
+            let sort_order = position as i32;
+            if category.sort_order() != sort_order {
+                category.set_sort_order(sort_order);
+                self.category_repo.update(&category).await?;
+            }
+
+            responses.push(CategoryResponse {
+                id: category.id().into_uuid(),
+                parent_id: category.parent_id().map(|id| id.into_uuid()),
+                name: category.name().to_string(),
+                description: category.description().map(|s| s.to_string()),
+                slug: category.slug().to_string(),
+                icon: category.icon().map(|s| s.to_string()),
+                sort_order: category.sort_order(),
+                is_active: category.is_active(),
+                tax_category: category.tax_category().map(|t| t.to_string()),
+                costing_method: category.costing_method().to_string(),
+                created_at: category.created_at(),
+                updated_at: category.updated_at(),
+            });
+        }
+
+        Ok(responses)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::entities::ProductCategory;
+    use crate::domain::repositories::CategoryStockValuation;
+    use async_trait::async_trait;
+    use identity::StoreId;
+    use std::sync::Mutex;
+
+    struct MockCategoryRepository {
+        categories: Mutex<HashMap<CategoryId, ProductCategory>>,
+    }
+
+    impl MockCategoryRepository {
+        fn with_roots(names: &[&str]) -> (Self, Vec<uuid::Uuid>) {
+            let mut categories = HashMap::new();
+            let mut ids = Vec::new();
+            for name in names {
+                let category = ProductCategory::create(name.to_string(), name.to_lowercase());
+                ids.push(category.id().into_uuid());
+                categories.insert(category.id(), category);
+            }
+            (
+                Self {
+                    categories: Mutex::new(categories),
+                },
+                ids,
+            )
+        }
+    }
+
+    #[async_trait]
+    impl CategoryRepository for MockCategoryRepository {
+        async fn save(&self, _category: &ProductCategory) -> Result<(), InventoryError> {
+            unimplemented!()
+        }
+
+        async fn find_by_id(
+            &self,
+            id: CategoryId,
+        ) -> Result<Option<ProductCategory>, InventoryError> {
+            Ok(self.categories.lock().unwrap().get(&id).cloned())
+        }
+
+        async fn find_by_slug(
+            &self,
+            _slug: &str,
+        ) -> Result<Option<ProductCategory>, InventoryError> {
+            unimplemented!()
+        }
+
+        async fn find_root_categories(&self) -> Result<Vec<ProductCategory>, InventoryError> {
+            let categories = self.categories.lock().unwrap();
+            Ok(categories
+                .values()
+                .filter(|c| c.is_root())
+                .cloned()
+                .collect())
+        }
+
+        async fn find_children(
+            &self,
+            parent_id: CategoryId,
+        ) -> Result<Vec<ProductCategory>, InventoryError> {
+            let categories = self.categories.lock().unwrap();
+            Ok(categories
+                .values()
+                .filter(|c| c.parent_id() == Some(parent_id))
+                .cloned()
+                .collect())
+        }
+
+        async fn find_all_active(&self) -> Result<Vec<ProductCategory>, InventoryError> {
+            unimplemented!()
+        }
+
+        async fn update(&self, category: &ProductCategory) -> Result<(), InventoryError> {
+            let mut categories = self.categories.lock().unwrap();
+            categories.insert(category.id(), category.clone());
+            Ok(())
+        }
+
+        async fn stock_valuation_by_category(
+            &self,
+            _store_id: Option<StoreId>,
+        ) -> Result<Vec<CategoryStockValuation>, InventoryError> {
+            unimplemented!()
+        }
+
+        async fn delete(&self, _id: CategoryId) -> Result<(), InventoryError> {
+            unimplemented!()
+        }
+    }
+
+    #[tokio::test]
+    async fn test_reorder_roots() {
+        let (repo, ids) = MockCategoryRepository::with_roots(&["Food", "Drinks", "Snacks"]);
+        let repo = Arc::new(repo);
+
+        let use_case = ReorderCategoriesUseCase::new(repo.clone());
+        let result = use_case
+            .execute(ReorderCategoriesCommand {
+                parent_id: None,
+                category_ids: vec![ids[2], ids[0], ids[1]],
+            })
+            .await
+            .unwrap();
+
+        let order: Vec<(&str, i32)> = result
+            .iter()
+            .map(|c| (c.name.as_str(), c.sort_order))
+            .collect();
+        assert_eq!(order, vec![("Snacks", 0), ("Food", 1), ("Drinks", 2)]);
+        let drinks = repo
+            .find_by_id(CategoryId::from_uuid(ids[1]))
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(drinks.sort_order(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_reorder_must_list_every_sibling_once() {
+        let (repo, ids) = MockCategoryRepository::with_roots(&["Food", "Drinks"]);
+        let use_case = ReorderCategoriesUseCase::new(Arc::new(repo));
+
+        for category_ids in [
+            vec![ids[0]],
+            vec![ids[0], ids[0]],
+            vec![ids[0], ids[1], uuid::Uuid::now_v7()],
+        ] {
+            let result = use_case
+                .execute(ReorderCategoriesCommand {
+                    parent_id: None,
+                    category_ids,
+                })
+                .await;
+            assert!(matches!(result, Err(InventoryError::InvalidCategoryOrder)));
+        }
+    }
+}
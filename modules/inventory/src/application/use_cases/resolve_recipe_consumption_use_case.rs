@@ -0,0 +1,333 @@
+// ResolveRecipeConsumptionUseCase - works out the ingredient stock a composite sale uses
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use rust_decimal::Decimal;
+
+use crate::InventoryError;
+use crate::domain::entities::{Recipe, RecipeIngredient};
+use crate::domain::repositories::{InventoryStockRepository, ProductRepository, RecipeRepository};
+use crate::domain::value_objects::{
+    IngredientId, ProductId, RecipeId, StockId, SubstituteId, VariantId,
+};
+use identity::StoreId;
+
+/// Stock drawn for one recipe ingredient, either from the ingredient itself
+/// or from one of its substitutes
+#[derive(Debug, Clone)]
+pub struct IngredientDraw {
+    pub ingredient_id: IngredientId,
+    /// Set when a substitute stands in for the ingredient
+    pub substitute_id: Option<SubstituteId>,
+    pub stock_id: StockId,
+    pub quantity: Decimal,
+}
+
+/// Ingredient stock needed to make the quantity sold of a composite product
+#[derive(Debug, Clone)]
+pub struct RecipeConsumption {
+    pub recipe_id: RecipeId,
+    pub draws: Vec<IngredientDraw>,
+}
+
+/// One place an ingredient can be drawn from
+#[derive(Debug, Clone)]
+pub(crate) struct IngredientSource {
+    pub substitute_id: Option<SubstituteId>,
+    /// Quantity needed from this source, after any conversion ratio
+    pub quantity: Decimal,
+    /// Stock record at the store and its available quantity, if any
+    pub stock: Option<(StockId, Decimal)>,
+}
+
+/// Picks the first source with enough stock left once earlier draws from
+/// the same record are counted. Sources are the ingredient itself followed
+/// by its substitutes in priority order. When none has enough and negative
+/// stock is allowed, the ingredient itself is drawn if it is stocked.
+pub(crate) fn pick_source(
+    sources: &[IngredientSource],
+    drawn: &HashMap<StockId, Decimal>,
+    allow_negative_stock: bool,
+) -> Option<usize> {
+    let enough = sources.iter().position(|source| match source.stock {
+        Some((stock_id, available)) => {
+            let remaining = available - drawn.get(&stock_id).copied().unwrap_or(Decimal::ZERO);
+            remaining >= source.quantity
+        }
+        None => false,
+    });
+    match enough {
+        Some(index) => Some(index),
+        None if allow_negative_stock => sources
+            .first()
+            .filter(|primary| primary.stock.is_some())
+            .map(|_| 0),
+        None => None,
+    }
+}
+
+/// Use case for resolving which ingredient stock a sale of a composite
+/// product consumes.
+///
+/// Products (or variants) with an active recipe are made to order: selling
+/// them uses up their ingredients, scaled by the quantity sold over the
+/// recipe's yield and including the ingredient's estimated waste, instead of
+/// finished-goods stock. An ingredient short of stock at the store is
+/// replaced by its first substitute, by priority, that has enough. Optional
+/// ingredients are left out when nothing is available.
+pub struct ResolveRecipeConsumptionUseCase<R, S, P>
+where
+    R: RecipeRepository,
+    S: InventoryStockRepository,
+    P: ProductRepository,
+{
+    recipe_repo: Arc<R>,
+    stock_repo: Arc<S>,
+    product_repo: Arc<P>,
+    allow_negative_stock: bool,
+}
+
+impl<R, S, P> ResolveRecipeConsumptionUseCase<R, S, P>
+where
+    R: RecipeRepository,
+    S: InventoryStockRepository,
+    P: ProductRepository,
+{
+    /// Creates a new instance of ResolveRecipeConsumptionUseCase
+    pub fn new(recipe_repo: Arc<R>, stock_repo: Arc<S>, product_repo: Arc<P>) -> Self {
+        Self {
+            recipe_repo,
+            stock_repo,
+            product_repo,
+            allow_negative_stock: false,
+        }
+    }
+
+    /// Lets ingredients nothing can stand in for go below zero, as the
+    /// store's negative stock policy allows
+    pub fn with_allow_negative_stock(mut self, allow: bool) -> Self {
+        self.allow_negative_stock = allow;
+        self
+    }
+
+    /// Executes the use case
+    ///
+    /// # Returns
+    /// None if the product or variant has no active recipe
+    ///
+    /// # Errors
+    /// * `InventoryError::IngredientUnavailable` - If a required ingredient
+    ///   and all its substitutes are short of stock
+    pub async fn execute(
+        &self,
+        store_id: StoreId,
+        product_id: ProductId,
+        variant_id: Option<VariantId>,
+        quantity: Decimal,
+    ) -> Result<Option<RecipeConsumption>, InventoryError> {
+        let Some(recipe) = self.find_active_recipe(product_id, variant_id).await? else {
+            return Ok(None);
+        };
+
+        let ingredients = self
+            .recipe_repo
+            .find_ingredients_by_recipe(recipe.id())
+            .await?;
+
+        let mut drawn: HashMap<StockId, Decimal> = HashMap::new();
+        let mut draws = Vec::new();
+        for ingredient in &ingredients {
+            let required = ingredient.quantity()
+                * (Decimal::ONE + ingredient.estimated_waste_percentage())
+                * quantity
+                / recipe.yield_quantity();
+            let sources = self.sources(store_id, ingredient, required).await?;
+
+            match pick_source(&sources, &drawn, self.allow_negative_stock) {
+                Some(index) => {
+                    let source = &sources[index];
+                    let (stock_id, _) = source.stock.expect("picked sources are stocked");
+                    *drawn.entry(stock_id).or_insert(Decimal::ZERO) += source.quantity;
+                    draws.push(IngredientDraw {
+                        ingredient_id: ingredient.id(),
+                        substitute_id: source.substitute_id,
+                        stock_id,
+                        quantity: source.quantity,
+                    });
+                }
+                None if ingredient.is_optional() => {}
+                None => {
+                    return Err(InventoryError::IngredientUnavailable(
+                        self.ingredient_name(ingredient).await?,
+                    ));
+                }
+            }
+        }
+
+        Ok(Some(RecipeConsumption {
+            recipe_id: recipe.id(),
+            draws,
+        }))
+    }
+
+    /// The variant's own recipe wins over the product's
+    async fn find_active_recipe(
+        &self,
+        product_id: ProductId,
+        variant_id: Option<VariantId>,
+    ) -> Result<Option<Recipe>, InventoryError> {
+        if let Some(variant_id) = variant_id
+            && let Some(recipe) = self.recipe_repo.find_active_by_variant(variant_id).await?
+        {
+            return Ok(Some(recipe));
+        }
+        self.recipe_repo.find_active_by_product(product_id).await
+    }
+
+    /// The ingredient itself, then its substitutes by priority
+    async fn sources(
+        &self,
+        store_id: StoreId,
+        ingredient: &RecipeIngredient,
+        required: Decimal,
+    ) -> Result<Vec<IngredientSource>, InventoryError> {
+        let mut sources = vec![IngredientSource {
+            substitute_id: None,
+            quantity: required,
+            stock: self
+                .stock_at(
+                    store_id,
+                    ingredient.ingredient_product_id(),
+                    ingredient.ingredient_variant_id(),
+                )
+                .await?,
+        }];
+
+        if ingredient.can_substitute() {
+            let mut substitutes = self
+                .recipe_repo
+                .find_substitutes_by_ingredient(ingredient.id())
+                .await?;
+            substitutes.sort_by_key(|s| s.priority());
+            for substitute in substitutes {
+                sources.push(IngredientSource {
+                    substitute_id: Some(substitute.id()),
+                    quantity: substitute.calculate_substitute_quantity(required),
+                    stock: self
+                        .stock_at(
+                            store_id,
+                            substitute.substitute_product_id(),
+                            substitute.substitute_variant_id(),
+                        )
+                        .await?,
+                });
+            }
+        }
+
+        Ok(sources)
+    }
+
+    async fn stock_at(
+        &self,
+        store_id: StoreId,
+        product_id: Option<ProductId>,
+        variant_id: Option<VariantId>,
+    ) -> Result<Option<(StockId, Decimal)>, InventoryError> {
+        let stock = match (variant_id, product_id) {
+            (Some(variant_id), _) => {
+                self.stock_repo
+                    .find_by_store_and_variant(store_id, variant_id)
+                    .await?
+            }
+            (None, Some(product_id)) => {
+                self.stock_repo
+                    .find_by_store_and_product(store_id, product_id)
+                    .await?
+            }
+            (None, None) => None,
+        };
+        Ok(stock.map(|s| (s.id(), s.available_quantity())))
+    }
+
+    /// Name of the ingredient's product, with the variant's when it is one
+    async fn ingredient_name(
+        &self,
+        ingredient: &RecipeIngredient,
+    ) -> Result<String, InventoryError> {
+        if let Some(variant_id) = ingredient.ingredient_variant_id()
+            && let Some(variant) = self.product_repo.find_variant_by_id(variant_id).await?
+        {
+            let product = self.product_repo.find_by_id(variant.product_id()).await?;
+            return Ok(match product {
+                Some(product) => format!("{} ({})", product.name(), variant.name()),
+                None => variant.name().to_string(),
+            });
+        }
+        if let Some(product_id) = ingredient.ingredient_product_id()
+            && let Some(product) = self.product_repo.find_by_id(product_id).await?
+        {
+            return Ok(product.name().to_string());
+        }
+        Ok(ingredient.id().into_uuid().to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    fn source(
+        substitute: bool,
+        quantity: Decimal,
+        stock: Option<(StockId, Decimal)>,
+    ) -> IngredientSource {
+        IngredientSource {
+            substitute_id: substitute.then(SubstituteId::new),
+            quantity,
+            stock,
+        }
+    }
+
+    #[test]
+    fn test_picks_ingredient_when_in_stock() {
+        let sources = vec![
+            source(false, dec!(2), Some((StockId::new(), dec!(5)))),
+            source(true, dec!(2), Some((StockId::new(), dec!(5)))),
+        ];
+        assert_eq!(pick_source(&sources, &HashMap::new(), false), Some(0));
+    }
+
+    #[test]
+    fn test_falls_back_to_substitute() {
+        let sources = vec![
+            source(false, dec!(2), Some((StockId::new(), dec!(1)))),
+            source(true, dec!(4), Some((StockId::new(), dec!(3)))),
+            source(true, dec!(3), Some((StockId::new(), dec!(3)))),
+        ];
+        assert_eq!(pick_source(&sources, &HashMap::new(), false), Some(2));
+    }
+
+    #[test]
+    fn test_counts_earlier_draws_from_the_same_stock() {
+        let stock_id = StockId::new();
+        let sources = vec![source(false, dec!(2), Some((stock_id, dec!(3))))];
+        let drawn = HashMap::from([(stock_id, dec!(2))]);
+
+        assert_eq!(pick_source(&sources, &drawn, false), None);
+    }
+
+    #[test]
+    fn test_negative_stock_draws_the_ingredient() {
+        let sources = vec![
+            source(false, dec!(2), Some((StockId::new(), dec!(0)))),
+            source(true, dec!(2), None),
+        ];
+        assert_eq!(pick_source(&sources, &HashMap::new(), false), None);
+        assert_eq!(pick_source(&sources, &HashMap::new(), true), Some(0));
+
+        let unstocked = vec![source(false, dec!(2), None)];
+        assert_eq!(pick_source(&unstocked, &HashMap::new(), true), None);
+    }
+}
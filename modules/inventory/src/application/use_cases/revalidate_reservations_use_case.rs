@@ -0,0 +1,614 @@
+// RevalidateReservationsUseCase - re-checks a set of reservation lines before
+// they are consumed (e.g. at checkout), applying a grace policy to lines
+// that expired moments ago
+
+use std::sync::Arc;
+
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::InventoryError;
+use crate::application::dtos::responses::ReservationResponse;
+use crate::application::helpers::{DEFAULT_BASE_DELAY_MS, DEFAULT_MAX_ATTEMPTS, retry_on_conflict};
+use crate::domain::entities::InventoryReservation;
+use crate::domain::repositories::{InventoryStockRepository, ReservationRepository};
+use crate::domain::value_objects::{ReservationGracePolicy, ReservationId, ReservationStatus};
+
+/// Hold length used for a re-reservation when the original hold's length
+/// can't be derived from its timestamps
+const FALLBACK_HOLD_MINUTES: i64 = 15;
+
+/// Outcome for every line passed to [`RevalidateReservationsUseCase`]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct RevalidateReservationsResult {
+    /// Lines whose reservation is still active
+    pub valid: Vec<ReservationResponse>,
+    /// Lines that had expired within the grace window and were re-reserved
+    pub re_reserved: Vec<ReReservedLine>,
+    /// Lines that could not be honoured
+    pub failed: Vec<FailedReservationLine>,
+}
+
+impl RevalidateReservationsResult {
+    /// True when every line can proceed (valid or re-reserved)
+    pub fn all_reserved(&self) -> bool {
+        self.failed.is_empty()
+    }
+}
+
+/// A recently-expired line that now holds stock again
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReReservedLine {
+    /// Reservation ID passed in by the caller
+    pub original_reservation_id: Uuid,
+    /// Reservation now holding the stock; same ID if the original hold was
+    /// extended, a new one if the stock had already been released
+    pub reservation: ReservationResponse,
+}
+
+/// A line that could not be reserved
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FailedReservationLine {
+    pub reservation_id: Uuid,
+    pub reason: String,
+}
+
+/// Use case for re-checking reservations right before they are confirmed.
+///
+/// With the default (strict) [`ReservationGracePolicy`] any expired line
+/// fails. With a grace window, a line that expired within the window is
+/// re-reserved if stock still permits:
+/// - still pending (the expiry job hasn't released it yet): the hold is
+///   extended in place
+/// - already expired and released: a new reservation for the same reference
+///   is created, checking available stock under optimistic locking so a
+///   concurrent sale can't oversell
+///
+/// Lines whose stock is genuinely gone are reported as failed.
+pub struct RevalidateReservationsUseCase<R, S>
+where
+    R: ReservationRepository,
+    S: InventoryStockRepository,
+{
+    reservation_repo: Arc<R>,
+    stock_repo: Arc<S>,
+    grace_policy: ReservationGracePolicy,
+}
+
+impl<R, S> RevalidateReservationsUseCase<R, S>
+where
+    R: ReservationRepository,
+    S: InventoryStockRepository,
+{
+    /// Creates a new instance of RevalidateReservationsUseCase
+    pub fn new(
+        reservation_repo: Arc<R>,
+        stock_repo: Arc<S>,
+        grace_policy: ReservationGracePolicy,
+    ) -> Self {
+        Self {
+            reservation_repo,
+            stock_repo,
+            grace_policy,
+        }
+    }
+
+    /// Revalidates each reservation; individual failures don't stop the run
+    pub async fn execute(
+        &self,
+        reservation_ids: Vec<Uuid>,
+    ) -> Result<RevalidateReservationsResult, InventoryError> {
+        let mut result = RevalidateReservationsResult::default();
+        let now = Utc::now();
+
+        for id in reservation_ids {
+            let reservation = self
+                .reservation_repo
+                .find_by_id(ReservationId::from_uuid(id))
+                .await?;
+
+            let Some(reservation) = reservation else {
+                result.failed.push(FailedReservationLine {
+                    reservation_id: id,
+                    reason: InventoryError::ReservationNotFound(id).to_string(),
+                });
+                continue;
+            };
+
+            match self.revalidate_line(reservation, now).await {
+                Ok(LineOutcome::Valid(r)) => result.valid.push(to_response(&r)),
+                Ok(LineOutcome::ReReserved(r)) => result.re_reserved.push(ReReservedLine {
+                    original_reservation_id: id,
+                    reservation: to_response(&r),
+                }),
+                Err(e) => result.failed.push(FailedReservationLine {
+                    reservation_id: id,
+                    reason: e.to_string(),
+                }),
+            }
+        }
+
+        Ok(result)
+    }
+
+    async fn revalidate_line(
+        &self,
+        mut reservation: InventoryReservation,
+        now: DateTime<Utc>,
+    ) -> Result<LineOutcome, InventoryError> {
+        if reservation.is_active() {
+            return Ok(LineOutcome::Valid(reservation));
+        }
+
+        let expired =
+            reservation.status() == ReservationStatus::Expired || reservation.is_expired();
+        if !expired {
+            // Confirmed or cancelled
+            return Err(InventoryError::InvalidReservationStatus);
+        }
+        if !self.grace_policy.allows(reservation.expires_at(), now) {
+            return Err(InventoryError::ReservationExpired);
+        }
+
+        let new_expires_at = now + hold_length(&reservation);
+
+        if reservation.status() == ReservationStatus::Pending {
+            // Stock is still held for this line; just extend it
+            reservation.extend(new_expires_at)?;
+            self.reservation_repo.update(&reservation).await?;
+            return Ok(LineOutcome::ReReserved(reservation));
+        }
+
+        // Released already: take the stock again if it's still available
+        let stock_id = reservation.stock_id();
+        let quantity = reservation.quantity();
+        retry_on_conflict(DEFAULT_MAX_ATTEMPTS, DEFAULT_BASE_DELAY_MS, || async {
+            let mut stock = self
+                .stock_repo
+                .find_by_id(stock_id)
+                .await?
+                .ok_or(InventoryError::StockNotFound(stock_id.into_uuid()))?;
+
+            let expected_version = stock.version();
+            stock.reserve(quantity)?;
+            stock.increment_version();
+
+            self.stock_repo
+                .update_with_version(&stock, expected_version)
+                .await
+        })
+        .await?;
+
+        let renewed = InventoryReservation::create(
+            stock_id,
+            reservation.reference_type().to_string(),
+            reservation.reference_id(),
+            quantity,
+            new_expires_at,
+            reservation.priority(),
+        )?;
+        self.reservation_repo.save(&renewed).await?;
+
+        Ok(LineOutcome::ReReserved(renewed))
+    }
+}
+
+enum LineOutcome {
+    Valid(InventoryReservation),
+    ReReserved(InventoryReservation),
+}
+
+/// A re-reservation holds stock as long as the original did
+fn hold_length(reservation: &InventoryReservation) -> Duration {
+    let original = reservation.expires_at() - reservation.created_at();
+    if original > Duration::zero() {
+        original
+    } else {
+        Duration::minutes(FALLBACK_HOLD_MINUTES)
+    }
+}
+
+fn to_response(reservation: &InventoryReservation) -> ReservationResponse {
+    ReservationResponse {
+        id: reservation.id().into_uuid(),
+        stock_id: reservation.stock_id().into_uuid(),
+        reference_type: reservation.reference_type().to_string(),
+        reference_id: reservation.reference_id(),
+        quantity: reservation.quantity(),
+        status: reservation.status().to_string(),
+        priority: reservation.priority(),
+        expires_at: reservation.expires_at(),
+        auto_extend_seconds: reservation.auto_extend_seconds(),
+        bumped_at: reservation.bumped_at(),
+        bump_reason: reservation.bump_reason().map(str::to_string),
+        created_at: reservation.created_at(),
+        updated_at: reservation.updated_at(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use async_trait::async_trait;
+    use rust_decimal::Decimal;
+    use rust_decimal_macros::dec;
+    use std::collections::HashMap;
+    use std::sync::Mutex;
+    use uuid::{NoContext, Timestamp};
+
+    use crate::domain::entities::InventoryStock;
+    use crate::domain::repositories::ReservationStatusCount;
+    use crate::domain::value_objects::{ProductId, StockId};
+    use identity::StoreId;
+
+    fn new_uuid() -> Uuid {
+        Uuid::new_v7(Timestamp::now(NoContext))
+    }
+
+    // Mock repositories
+    struct MockReservationRepository {
+        reservations: Mutex<HashMap<ReservationId, InventoryReservation>>,
+    }
+
+    impl MockReservationRepository {
+        fn new() -> Self {
+            Self {
+                reservations: Mutex::new(HashMap::new()),
+            }
+        }
+
+        fn add_reservation(&self, reservation: InventoryReservation) {
+            let mut reservations = self.reservations.lock().unwrap();
+            reservations.insert(reservation.id(), reservation);
+        }
+    }
+
+    #[async_trait]
+    impl ReservationRepository for MockReservationRepository {
+        async fn save(&self, reservation: &InventoryReservation) -> Result<(), InventoryError> {
+            let mut reservations = self.reservations.lock().unwrap();
+            reservations.insert(reservation.id(), reservation.clone());
+            Ok(())
+        }
+
+        async fn find_by_id(
+            &self,
+            id: ReservationId,
+        ) -> Result<Option<InventoryReservation>, InventoryError> {
+            let reservations = self.reservations.lock().unwrap();
+            Ok(reservations.get(&id).cloned())
+        }
+
+        async fn find_by_stock_id(
+            &self,
+            _stock_id: StockId,
+        ) -> Result<Vec<InventoryReservation>, InventoryError> {
+            unimplemented!()
+        }
+
+        async fn find_by_reference(
+            &self,
+            _reference_type: &str,
+            _reference_id: Uuid,
+        ) -> Result<Vec<InventoryReservation>, InventoryError> {
+            unimplemented!()
+        }
+
+        async fn find_expired(&self) -> Result<Vec<InventoryReservation>, InventoryError> {
+            let reservations = self.reservations.lock().unwrap();
+            let now = Utc::now();
+            Ok(reservations
+                .values()
+                .filter(|r| r.status() == ReservationStatus::Pending && r.expires_at() < now)
+                .cloned()
+                .collect())
+        }
+
+        async fn update(&self, reservation: &InventoryReservation) -> Result<(), InventoryError> {
+            let mut reservations = self.reservations.lock().unwrap();
+            reservations.insert(reservation.id(), reservation.clone());
+            Ok(())
+        }
+
+        async fn delete(&self, _id: ReservationId) -> Result<(), InventoryError> {
+            unimplemented!()
+        }
+
+        async fn find_paginated(
+            &self,
+            _store_ids: Option<&[StoreId]>,
+            _stock_id: Option<StockId>,
+            _status: Option<&str>,
+            _reference_type: Option<&str>,
+            _after_id: Option<Uuid>,
+            _page: i64,
+            _page_size: i64,
+        ) -> Result<(Vec<InventoryReservation>, i64), InventoryError> {
+            unimplemented!()
+        }
+
+        async fn count_by_status(
+            &self,
+            _store_id: StoreId,
+            _from: DateTime<Utc>,
+            _to: DateTime<Utc>,
+            _by_day: bool,
+        ) -> Result<Vec<ReservationStatusCount>, InventoryError> {
+            unimplemented!()
+        }
+    }
+
+    struct MockStockRepository {
+        stocks: Mutex<HashMap<StockId, InventoryStock>>,
+    }
+
+    impl MockStockRepository {
+        fn new() -> Self {
+            Self {
+                stocks: Mutex::new(HashMap::new()),
+            }
+        }
+
+        fn add_stock(&self, stock: InventoryStock) {
+            let mut stocks = self.stocks.lock().unwrap();
+            stocks.insert(stock.id(), stock);
+        }
+    }
+
+    #[async_trait]
+    impl InventoryStockRepository for MockStockRepository {
+        async fn save(&self, stock: &InventoryStock) -> Result<(), InventoryError> {
+            let mut stocks = self.stocks.lock().unwrap();
+            stocks.insert(stock.id(), stock.clone());
+            Ok(())
+        }
+
+        async fn find_by_id(&self, id: StockId) -> Result<Option<InventoryStock>, InventoryError> {
+            let stocks = self.stocks.lock().unwrap();
+            Ok(stocks.get(&id).cloned())
+        }
+
+        async fn find_by_store_and_product(
+            &self,
+            _store_id: StoreId,
+            _product_id: ProductId,
+        ) -> Result<Option<InventoryStock>, InventoryError> {
+            unimplemented!()
+        }
+
+        async fn find_by_store_and_variant(
+            &self,
+            _store_id: StoreId,
+            _variant_id: crate::domain::value_objects::VariantId,
+        ) -> Result<Option<InventoryStock>, InventoryError> {
+            unimplemented!()
+        }
+
+        async fn update_with_version(
+            &self,
+            stock: &InventoryStock,
+            expected_version: i32,
+        ) -> Result<(), InventoryError> {
+            let mut stocks = self.stocks.lock().unwrap();
+            if let Some(existing) = stocks.get(&stock.id()) {
+                if existing.version() != expected_version {
+                    return Err(InventoryError::OptimisticLockError);
+                }
+                stocks.insert(stock.id(), stock.clone());
+                Ok(())
+            } else {
+                Err(InventoryError::StockNotFound(stock.id().into_uuid()))
+            }
+        }
+
+        async fn find_low_stock(
+            &self,
+            _store_id: StoreId,
+        ) -> Result<Vec<InventoryStock>, InventoryError> {
+            unimplemented!()
+        }
+
+        async fn find_by_store(
+            &self,
+            _store_id: StoreId,
+        ) -> Result<Vec<InventoryStock>, InventoryError> {
+            unimplemented!()
+        }
+
+        async fn find_paginated(
+            &self,
+            _store_ids: Option<&[StoreId]>,
+            _product_id: Option<crate::domain::value_objects::ProductId>,
+            _low_stock_only: bool,
+            _after_id: Option<Uuid>,
+            _page: i64,
+            _page_size: i64,
+        ) -> Result<(Vec<InventoryStock>, i64), InventoryError> {
+            unimplemented!()
+        }
+
+        async fn find_by_product(
+            &self,
+            _product_id: crate::domain::value_objects::ProductId,
+        ) -> Result<Vec<InventoryStock>, InventoryError> {
+            unimplemented!()
+        }
+
+        async fn find_all(&self) -> Result<Vec<InventoryStock>, InventoryError> {
+            unimplemented!()
+        }
+
+        async fn find_all_low_stock(&self) -> Result<Vec<InventoryStock>, InventoryError> {
+            unimplemented!()
+        }
+
+        async fn find_by_store_and_products(
+            &self,
+            _store_id: StoreId,
+            _product_ids: &[ProductId],
+        ) -> Result<Vec<InventoryStock>, InventoryError> {
+            unimplemented!()
+        }
+
+        async fn find_by_store_and_variants(
+            &self,
+            _store_id: StoreId,
+            _variant_ids: &[crate::domain::value_objects::VariantId],
+        ) -> Result<Vec<InventoryStock>, InventoryError> {
+            unimplemented!()
+        }
+
+        async fn find_low_stock_by_store(
+            &self,
+            _store_id: StoreId,
+        ) -> Result<Vec<InventoryStock>, InventoryError> {
+            unimplemented!()
+        }
+    }
+
+    /// Stock with 10 units on hand, `reserved` of them held
+    fn setup_stock(stock_repo: &MockStockRepository, reserved: Decimal) -> StockId {
+        let mut stock =
+            InventoryStock::create_for_product(StoreId::new(), ProductId::new()).unwrap();
+        stock.adjust_quantity(dec!(10)).unwrap();
+        if reserved > Decimal::ZERO {
+            stock.reserve(reserved).unwrap();
+        }
+        let stock_id = stock.id();
+        stock_repo.add_stock(stock);
+        stock_id
+    }
+
+    fn reservation(
+        stock_id: StockId,
+        quantity: Decimal,
+        status: ReservationStatus,
+        expired_secs_ago: i64,
+    ) -> InventoryReservation {
+        let expires_at = Utc::now() - Duration::seconds(expired_secs_ago);
+        InventoryReservation::reconstitute(
+            ReservationId::new(),
+            stock_id,
+            "cart".to_string(),
+            new_uuid(),
+            quantity,
+            status,
+            0,
+            expires_at,
+            None,
+            None,
+            None,
+            expires_at - Duration::minutes(15),
+            expires_at,
+        )
+    }
+
+    #[tokio::test]
+    async fn test_strict_policy_fails_expired_lines() {
+        let reservation_repo = Arc::new(MockReservationRepository::new());
+        let stock_repo = Arc::new(MockStockRepository::new());
+        let stock_id = setup_stock(&stock_repo, dec!(0));
+
+        let expired = reservation(stock_id, dec!(2), ReservationStatus::Expired, 5);
+        let expired_id = expired.id().into_uuid();
+        reservation_repo.add_reservation(expired);
+
+        let use_case = RevalidateReservationsUseCase::new(
+            reservation_repo,
+            stock_repo,
+            ReservationGracePolicy::strict(),
+        );
+        let result = use_case.execute(vec![expired_id]).await.unwrap();
+
+        assert!(!result.all_reserved());
+        assert_eq!(result.failed[0].reservation_id, expired_id);
+    }
+
+    #[tokio::test]
+    async fn test_grace_re_reserves_released_line() {
+        let reservation_repo = Arc::new(MockReservationRepository::new());
+        let stock_repo = Arc::new(MockStockRepository::new());
+        let stock_id = setup_stock(&stock_repo, dec!(0));
+
+        let expired = reservation(stock_id, dec!(4), ReservationStatus::Expired, 10);
+        let expired_id = expired.id().into_uuid();
+        reservation_repo.add_reservation(expired);
+
+        let use_case = RevalidateReservationsUseCase::new(
+            reservation_repo,
+            stock_repo.clone(),
+            ReservationGracePolicy::from_secs(60),
+        );
+        let result = use_case.execute(vec![expired_id]).await.unwrap();
+
+        assert!(result.all_reserved());
+        assert_eq!(result.re_reserved.len(), 1);
+        let line = &result.re_reserved[0];
+        assert_eq!(line.original_reservation_id, expired_id);
+        assert_ne!(line.reservation.id, expired_id);
+        assert!(line.reservation.expires_at > Utc::now());
+
+        let stock = stock_repo.find_by_id(stock_id).await.unwrap().unwrap();
+        assert_eq!(stock.reserved_quantity(), dec!(4));
+    }
+
+    #[tokio::test]
+    async fn test_grace_extends_unreleased_line() {
+        let reservation_repo = Arc::new(MockReservationRepository::new());
+        let stock_repo = Arc::new(MockStockRepository::new());
+        let stock_id = setup_stock(&stock_repo, dec!(3));
+
+        let lapsed = reservation(stock_id, dec!(3), ReservationStatus::Pending, 10);
+        let lapsed_id = lapsed.id().into_uuid();
+        reservation_repo.add_reservation(lapsed);
+
+        let use_case = RevalidateReservationsUseCase::new(
+            reservation_repo,
+            stock_repo.clone(),
+            ReservationGracePolicy::from_secs(60),
+        );
+        let result = use_case.execute(vec![lapsed_id]).await.unwrap();
+
+        assert_eq!(result.re_reserved[0].reservation.id, lapsed_id);
+        let stock = stock_repo.find_by_id(stock_id).await.unwrap().unwrap();
+        assert_eq!(stock.reserved_quantity(), dec!(3));
+    }
+
+    #[tokio::test]
+    async fn test_grace_fails_when_stock_is_gone() {
+        let reservation_repo = Arc::new(MockReservationRepository::new());
+        let stock_repo = Arc::new(MockStockRepository::new());
+        // Someone else took 8 of the 10 units after our hold was released
+        let stock_id = setup_stock(&stock_repo, dec!(8));
+
+        let active = InventoryReservation::create(
+            stock_id,
+            "cart".to_string(),
+            new_uuid(),
+            dec!(1),
+            Utc::now() + Duration::minutes(10),
+            0,
+        )
+        .unwrap();
+        let active_id = active.id().into_uuid();
+        reservation_repo.add_reservation(active);
+
+        let expired = reservation(stock_id, dec!(5), ReservationStatus::Expired, 10);
+        let expired_id = expired.id().into_uuid();
+        reservation_repo.add_reservation(expired);
+
+        let use_case = RevalidateReservationsUseCase::new(
+            reservation_repo,
+            stock_repo.clone(),
+            ReservationGracePolicy::from_secs(60),
+        );
+        let result = use_case.execute(vec![active_id, expired_id]).await.unwrap();
+
+        assert_eq!(result.valid.len(), 1);
+        assert_eq!(result.failed.len(), 1);
+        assert_eq!(result.failed[0].reservation_id, expired_id);
+        let stock = stock_repo.find_by_id(stock_id).await.unwrap().unwrap();
+        assert_eq!(stock.reserved_quantity(), dec!(8));
+    }
+}
@@ -0,0 +1,116 @@
+// SearchProductsUseCase - relevance-ranked product search for the storefront
+
+use std::sync::Arc;
+
+use crate::InventoryError;
+use crate::application::dtos::responses::{
+    PaginatedResponse, ProductResponse, ProductSearchResultResponse,
+};
+use crate::domain::repositories::ProductRepository;
+use crate::domain::value_objects::CategoryId;
+
+/// Query parameters for searching products
+#[derive(Debug, Clone)]
+pub struct SearchProductsQuery {
+    /// Search text, matched against name, SKU, barcode and description
+    pub q: String,
+    /// Filter by category ID
+    pub category_id: Option<uuid::Uuid>,
+    /// Only active products
+    pub active_only: bool,
+    /// Page number (1-indexed)
+    pub page: i64,
+    /// Number of items per page
+    pub page_size: i64,
+}
+
+/// Use case for searching products by relevance
+///
+/// Whole words match through full-text search; misspelled names and partial
+/// SKUs match through trigram similarity; barcodes match exactly.
+pub struct SearchProductsUseCase<P>
+where
+    P: ProductRepository,
+{
+    product_repo: Arc<P>,
+}
+
+impl<P> SearchProductsUseCase<P>
+where
+    P: ProductRepository,
+{
+    /// Creates a new instance of SearchProductsUseCase
+    pub fn new(product_repo: Arc<P>) -> Self {
+        Self { product_repo }
+    }
+
+    /// Executes the use case to search products
+    ///
+    /// # Errors
+    /// * `InventoryError::EmptySearchQuery` - If the query is blank
+    pub async fn execute(
+        &self,
+        query: SearchProductsQuery,
+    ) -> Result<PaginatedResponse<ProductSearchResultResponse>, InventoryError> {
+        let term = query.q.trim();
+        if term.is_empty() {
+            return Err(InventoryError::EmptySearchQuery);
+        }
+
+        // Validate and clamp pagination
+        let page = query.page.max(1);
+        let page_size = query.page_size.clamp(1, 100);
+
+        let (products, total_items) = self
+            .product_repo
+            .search(
+                term,
+                query.category_id.map(CategoryId::from_uuid),
+                query.active_only,
+                page,
+                page_size,
+            )
+            .await?;
+
+        // Convert to response DTOs, keeping the relevance order
+        let results: Vec<ProductSearchResultResponse> = products
+            .into_iter()
+            .map(|(p, relevance_score)| ProductSearchResultResponse {
+                product: ProductResponse {
+                    id: p.id().into_uuid(),
+                    sku: p.sku().as_str().to_string(),
+                    barcode: p.barcode().map(|b| b.as_str().to_string()),
+                    barcode_generated: p.barcode_generated(),
+                    name: p.name().to_string(),
+                    description: p.description().map(|s| s.to_string()),
+                    category_id: p.category_id().map(|id| id.into_uuid()),
+                    brand: p.brand().map(|s| s.to_string()),
+                    unit_of_measure: p.unit_of_measure().to_string(),
+                    base_price: p.base_price(),
+                    cost_price: p.cost_price(),
+                    currency: p.currency().as_str().to_string(),
+                    is_perishable: p.is_perishable(),
+                    is_trackable: p.is_trackable(),
+                    has_variants: p.has_variants(),
+                    tax_rate: p.tax_rate(),
+                    tax_included: p.tax_included(),
+                    tax_category: p.tax_category().map(|t| t.to_string()),
+                    is_serialized: p.is_serialized(),
+                    markup_type: p.markup_rule().map(|r| r.markup_type().to_string()),
+                    markup_percent: p.markup_rule().and_then(|r| r.percent()),
+                    is_active: p.is_active(),
+                    created_at: p.created_at(),
+                    updated_at: p.updated_at(),
+                },
+                relevance_score,
+            })
+            .collect();
+
+        Ok(PaginatedResponse::new(
+            results,
+            page,
+            page_size,
+            total_items,
+        ))
+    }
+}
@@ -0,0 +1,369 @@
+// SetProductUnitConversionsUseCase - defines how a product's packaging converts
+// to other units
+
+use std::sync::Arc;
+
+use crate::InventoryError;
+use crate::application::dtos::commands::SetProductUnitConversionsCommand;
+use crate::application::dtos::responses::UnitConversionResponse;
+use crate::domain::repositories::ProductRepository;
+use crate::domain::value_objects::{ProductId, UnitConversion, UnitOfMeasure};
+
+/// Use case for replacing the unit conversions defined for a product.
+///
+/// Conversions cover packaging that varies between products, such as a case
+/// holding 24 units of one product and 12 of another. Units that already
+/// convert with standard factors (kg and g, liter and ml) can't be redefined,
+/// and each pair of units may be defined only once, in either direction.
+pub struct SetProductUnitConversionsUseCase<P>
+where
+    P: ProductRepository,
+{
+    product_repo: Arc<P>,
+}
+
+impl<P> SetProductUnitConversionsUseCase<P>
+where
+    P: ProductRepository,
+{
+    /// Creates a new instance of SetProductUnitConversionsUseCase
+    pub fn new(product_repo: Arc<P>) -> Self {
+        Self { product_repo }
+    }
+
+    /// Executes the use case to replace a product's unit conversions
+    ///
+    /// # Arguments
+    /// * `command` - Product and its full list of conversions
+    ///
+    /// # Returns
+    /// The conversions now defined for the product
+    ///
+    /// # Errors
+    /// * `InventoryError::InvalidUnitOfMeasure` - If a unit is not recognized
+    /// * `InventoryError::InvalidUnitConversion` - If a factor is not
+    ///   positive, a pair repeats, or the units convert with standard factors
+    /// * `InventoryError::ProductNotFound` - If the product doesn't exist
+    pub async fn execute(
+        &self,
+        command: SetProductUnitConversionsCommand,
+    ) -> Result<Vec<UnitConversionResponse>, InventoryError> {
+        let mut conversions: Vec<UnitConversion> = Vec::new();
+        for input in &command.conversions {
+            let from_unit: UnitOfMeasure = input.from_unit.parse()?;
+            let to_unit: UnitOfMeasure = input.to_unit.parse()?;
+            if from_unit.base_unit() == to_unit.base_unit() {
+                return Err(InventoryError::InvalidUnitConversion(format!(
+                    "{} to {} uses the standard factor",
+                    from_unit, to_unit
+                )));
+            }
+            let conversion = UnitConversion::new(from_unit, to_unit, input.factor)?;
+            let repeated = conversions.iter().any(|c| {
+                (c.from_unit() == from_unit && c.to_unit() == to_unit)
+                    || (c.from_unit() == to_unit && c.to_unit() == from_unit)
+            });
+            if repeated {
+                return Err(InventoryError::InvalidUnitConversion(format!(
+                    "{} to {} is defined more than once",
+                    from_unit, to_unit
+                )));
+            }
+            conversions.push(conversion);
+        }
+
+        let product_id = ProductId::from_uuid(command.product_id);
+        if self.product_repo.find_by_id(product_id).await?.is_none() {
+            return Err(InventoryError::ProductNotFound(command.product_id));
+        }
+
+        self.product_repo
+            .replace_unit_conversions(product_id, &conversions)
+            .await?;
+
+        Ok(conversions
+            .iter()
+            .map(UnitConversionResponse::from)
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use async_trait::async_trait;
+    use rust_decimal::Decimal;
+    use rust_decimal_macros::dec;
+    use std::sync::Mutex;
+    use uuid::Uuid;
+
+    use crate::application::dtos::commands::UnitConversionInput;
+    use crate::domain::entities::{Product, ProductVariant, ProductVendor};
+    use crate::domain::value_objects::{Barcode, CategoryId, Sku, VariantId};
+
+    struct MockProductRepository {
+        products: Vec<Product>,
+        conversions: Mutex<Vec<UnitConversion>>,
+    }
+
+    #[async_trait]
+    impl ProductRepository for MockProductRepository {
+        async fn save(&self, _product: &Product) -> Result<(), InventoryError> {
+            unimplemented!()
+        }
+
+        async fn find_by_id(&self, id: ProductId) -> Result<Option<Product>, InventoryError> {
+            Ok(self.products.iter().find(|p| p.id() == id).cloned())
+        }
+
+        async fn find_by_sku(&self, _sku: &Sku) -> Result<Option<Product>, InventoryError> {
+            unimplemented!()
+        }
+
+        async fn find_by_barcode(
+            &self,
+            _barcode: &Barcode,
+        ) -> Result<Option<Product>, InventoryError> {
+            unimplemented!()
+        }
+
+        async fn update(&self, _product: &Product) -> Result<(), InventoryError> {
+            unimplemented!()
+        }
+
+        async fn delete(&self, _id: ProductId) -> Result<(), InventoryError> {
+            unimplemented!()
+        }
+
+        async fn find_active(&self) -> Result<Vec<Product>, InventoryError> {
+            unimplemented!()
+        }
+
+        async fn find_by_category(
+            &self,
+            _category_id: CategoryId,
+        ) -> Result<Vec<Product>, InventoryError> {
+            unimplemented!()
+        }
+
+        async fn find_paginated(
+            &self,
+            _category_id: Option<CategoryId>,
+            _is_active: Option<bool>,
+            _search: Option<&str>,
+            _after_id: Option<Uuid>,
+            _page: i64,
+            _page_size: i64,
+        ) -> Result<(Vec<Product>, i64), InventoryError> {
+            unimplemented!()
+        }
+
+        async fn count_filtered(
+            &self,
+            _category_id: Option<CategoryId>,
+            _is_active: Option<bool>,
+            _search: Option<&str>,
+        ) -> Result<i64, InventoryError> {
+            unimplemented!()
+        }
+
+        async fn search(
+            &self,
+            _term: &str,
+            _category_id: Option<CategoryId>,
+            _active_only: bool,
+            _page: i64,
+            _page_size: i64,
+        ) -> Result<(Vec<(Product, f64)>, i64), InventoryError> {
+            unimplemented!()
+        }
+
+        async fn save_variant(&self, _variant: &ProductVariant) -> Result<(), InventoryError> {
+            unimplemented!()
+        }
+
+        async fn find_variant_by_id(
+            &self,
+            _id: VariantId,
+        ) -> Result<Option<ProductVariant>, InventoryError> {
+            unimplemented!()
+        }
+
+        async fn find_variant_by_sku(
+            &self,
+            _sku: &Sku,
+        ) -> Result<Option<ProductVariant>, InventoryError> {
+            unimplemented!()
+        }
+
+        async fn find_variant_by_barcode(
+            &self,
+            _barcode: &Barcode,
+        ) -> Result<Option<ProductVariant>, InventoryError> {
+            unimplemented!()
+        }
+
+        async fn find_variants_by_product(
+            &self,
+            _product_id: ProductId,
+        ) -> Result<Vec<ProductVariant>, InventoryError> {
+            unimplemented!()
+        }
+
+        async fn update_variant(&self, _variant: &ProductVariant) -> Result<(), InventoryError> {
+            unimplemented!()
+        }
+
+        async fn delete_variant(&self, _id: VariantId) -> Result<(), InventoryError> {
+            unimplemented!()
+        }
+
+        async fn count_variants(&self, _product_id: ProductId) -> Result<u32, InventoryError> {
+            unimplemented!()
+        }
+
+        async fn save_product_vendors(
+            &self,
+            _vendors: &[ProductVendor],
+        ) -> Result<(), InventoryError> {
+            unimplemented!()
+        }
+
+        async fn find_product_vendors(
+            &self,
+            _product_id: ProductId,
+        ) -> Result<Vec<ProductVendor>, InventoryError> {
+            unimplemented!()
+        }
+
+        async fn find_preferred_vendor(
+            &self,
+            _product_id: ProductId,
+        ) -> Result<Option<ProductVendor>, InventoryError> {
+            unimplemented!()
+        }
+
+        async fn replace_unit_conversions(
+            &self,
+            _product_id: ProductId,
+            conversions: &[UnitConversion],
+        ) -> Result<(), InventoryError> {
+            *self.conversions.lock().unwrap() = conversions.to_vec();
+            Ok(())
+        }
+
+        async fn find_unit_conversions(
+            &self,
+            _product_id: ProductId,
+        ) -> Result<Vec<UnitConversion>, InventoryError> {
+            Ok(self.conversions.lock().unwrap().clone())
+        }
+    }
+
+    fn setup() -> (
+        SetProductUnitConversionsUseCase<MockProductRepository>,
+        Arc<MockProductRepository>,
+        Uuid,
+    ) {
+        let product = Product::create("Soda".to_string(), UnitOfMeasure::Unit, None);
+        let product_id = product.id().into_uuid();
+        let repo = Arc::new(MockProductRepository {
+            products: vec![product],
+            conversions: Mutex::new(Vec::new()),
+        });
+        (
+            SetProductUnitConversionsUseCase::new(repo.clone()),
+            repo,
+            product_id,
+        )
+    }
+
+    fn input(from_unit: &str, to_unit: &str, factor: Decimal) -> UnitConversionInput {
+        UnitConversionInput {
+            from_unit: from_unit.to_string(),
+            to_unit: to_unit.to_string(),
+            factor,
+        }
+    }
+
+    fn command(
+        product_id: Uuid,
+        conversions: Vec<UnitConversionInput>,
+    ) -> SetProductUnitConversionsCommand {
+        SetProductUnitConversionsCommand {
+            product_id,
+            conversions,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_replaces_conversions() {
+        let (use_case, repo, product_id) = setup();
+
+        let response = use_case
+            .execute(command(product_id, vec![input("case", "unit", dec!(24))]))
+            .await
+            .unwrap();
+
+        assert_eq!(response.len(), 1);
+        assert_eq!(response[0].from_unit, "case");
+        assert_eq!(response[0].factor, dec!(24));
+        let stored = repo
+            .find_unit_conversions(ProductId::from_uuid(product_id))
+            .await
+            .unwrap();
+        assert_eq!(stored[0].to_unit(), UnitOfMeasure::Unit);
+
+        use_case
+            .execute(command(product_id, Vec::new()))
+            .await
+            .unwrap();
+        assert!(repo.conversions.lock().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_rejects_standard_factor_units() {
+        let (use_case, _, product_id) = setup();
+
+        let result = use_case
+            .execute(command(product_id, vec![input("kg", "g", dec!(1000))]))
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(InventoryError::InvalidUnitConversion(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_rejects_pair_defined_twice() {
+        let (use_case, _, product_id) = setup();
+
+        let result = use_case
+            .execute(command(
+                product_id,
+                vec![
+                    input("case", "unit", dec!(24)),
+                    input("unit", "case", dec!(0.04)),
+                ],
+            ))
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(InventoryError::InvalidUnitConversion(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_product_not_found() {
+        let (use_case, _, _) = setup();
+        let missing = Uuid::now_v7();
+
+        let result = use_case
+            .execute(command(missing, vec![input("case", "unit", dec!(24))]))
+            .await;
+
+        assert!(matches!(result, Err(InventoryError::ProductNotFound(id)) if id == missing));
+    }
+}
@@ -0,0 +1,432 @@
+// SetProductVendorUseCase - links a vendor to a product and keeps one preferred
+
+use std::sync::Arc;
+
+use rust_decimal::Decimal;
+
+use crate::InventoryError;
+use crate::application::dtos::commands::SetProductVendorCommand;
+use crate::application::dtos::responses::ProductVendorResponse;
+use crate::domain::entities::ProductVendor;
+use crate::domain::repositories::ProductRepository;
+use crate::domain::value_objects::ProductId;
+
+/// Maximum length of a vendor's SKU for a product
+pub const MAX_VENDOR_SKU_LENGTH: usize = 100;
+
+/// Use case for adding or updating a vendor that supplies a product.
+///
+/// A product may have several vendors but exactly one of them is preferred:
+/// the first vendor linked becomes preferred, marking another vendor as
+/// preferred unmarks the previous one, and the preferred vendor can't be
+/// unmarked directly.
+pub struct SetProductVendorUseCase<P>
+where
+    P: ProductRepository,
+{
+    product_repo: Arc<P>,
+}
+
+impl<P> SetProductVendorUseCase<P>
+where
+    P: ProductRepository,
+{
+    /// Creates a new instance of SetProductVendorUseCase
+    pub fn new(product_repo: Arc<P>) -> Self {
+        Self { product_repo }
+    }
+
+    /// Executes the use case to link a vendor to a product
+    ///
+    /// # Arguments
+    /// * `command` - Product, vendor, vendor SKU, last price and preferred flag
+    ///
+    /// # Returns
+    /// The saved product-vendor link
+    ///
+    /// # Errors
+    /// * `InventoryError::NegativeCost` - If the last purchase price is negative
+    /// * `InventoryError::InvalidProductVendor` - If the vendor SKU is too long,
+    ///   the preferred vendor would be unmarked, or the vendor doesn't exist
+    /// * `InventoryError::ProductNotFound` - If the product doesn't exist
+    pub async fn execute(
+        &self,
+        command: SetProductVendorCommand,
+    ) -> Result<ProductVendorResponse, InventoryError> {
+        if command
+            .last_purchase_price
+            .is_some_and(|p| p < Decimal::ZERO)
+        {
+            return Err(InventoryError::NegativeCost);
+        }
+        let vendor_sku = command
+            .vendor_sku
+            .map(|s| s.trim().to_string())
+            .filter(|s| !s.is_empty());
+        if vendor_sku
+            .as_ref()
+            .is_some_and(|s| s.chars().count() > MAX_VENDOR_SKU_LENGTH)
+        {
+            return Err(InventoryError::InvalidProductVendor(format!(
+                "vendor_sku cannot exceed {} characters",
+                MAX_VENDOR_SKU_LENGTH
+            )));
+        }
+
+        let product_id = ProductId::from_uuid(command.product_id);
+        if self.product_repo.find_by_id(product_id).await?.is_none() {
+            return Err(InventoryError::ProductNotFound(command.product_id));
+        }
+
+        let mut others = self.product_repo.find_product_vendors(product_id).await?;
+        let mut vendor = match others
+            .iter()
+            .position(|v| v.vendor_id() == command.vendor_id)
+        {
+            Some(index) => others.remove(index),
+            None => ProductVendor::create(product_id, command.vendor_id),
+        };
+
+        // Exactly one vendor stays preferred
+        let is_preferred = if command.is_preferred {
+            true
+        } else if vendor.is_preferred() {
+            return Err(InventoryError::InvalidProductVendor(
+                "mark another vendor as preferred instead of unmarking the preferred one"
+                    .to_string(),
+            ));
+        } else {
+            !others.iter().any(|v| v.is_preferred())
+        };
+
+        vendor.set_vendor_sku(vendor_sku);
+        vendor.set_last_purchase_price(command.last_purchase_price);
+        vendor.set_preferred(is_preferred);
+
+        // Previously preferred vendors are saved first so the new one never
+        // overlaps with them
+        let mut changes = Vec::new();
+        if is_preferred {
+            for other in others.iter_mut().filter(|v| v.is_preferred()) {
+                other.set_preferred(false);
+                changes.push(other.clone());
+            }
+        }
+        changes.push(vendor.clone());
+        self.product_repo.save_product_vendors(&changes).await?;
+
+        Ok(ProductVendorResponse::from(&vendor))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use async_trait::async_trait;
+    use rust_decimal_macros::dec;
+    use std::sync::Mutex;
+    use uuid::Uuid;
+
+    use crate::domain::entities::{Product, ProductVariant};
+    use crate::domain::value_objects::{
+        Barcode, CategoryId, Sku, UnitConversion, UnitOfMeasure, VariantId,
+    };
+
+    struct MockProductRepository {
+        products: Vec<Product>,
+        vendors: Mutex<Vec<ProductVendor>>,
+    }
+
+    #[async_trait]
+    impl ProductRepository for MockProductRepository {
+        async fn save(&self, _product: &Product) -> Result<(), InventoryError> {
+            unimplemented!()
+        }
+
+        async fn find_by_id(&self, id: ProductId) -> Result<Option<Product>, InventoryError> {
+            Ok(self.products.iter().find(|p| p.id() == id).cloned())
+        }
+
+        async fn find_by_sku(&self, _sku: &Sku) -> Result<Option<Product>, InventoryError> {
+            unimplemented!()
+        }
+
+        async fn find_by_barcode(
+            &self,
+            _barcode: &Barcode,
+        ) -> Result<Option<Product>, InventoryError> {
+            unimplemented!()
+        }
+
+        async fn update(&self, _product: &Product) -> Result<(), InventoryError> {
+            unimplemented!()
+        }
+
+        async fn delete(&self, _id: ProductId) -> Result<(), InventoryError> {
+            unimplemented!()
+        }
+
+        async fn find_active(&self) -> Result<Vec<Product>, InventoryError> {
+            unimplemented!()
+        }
+
+        async fn find_by_category(
+            &self,
+            _category_id: CategoryId,
+        ) -> Result<Vec<Product>, InventoryError> {
+            unimplemented!()
+        }
+
+        async fn find_paginated(
+            &self,
+            _category_id: Option<CategoryId>,
+            _is_active: Option<bool>,
+            _search: Option<&str>,
+            _after_id: Option<Uuid>,
+            _page: i64,
+            _page_size: i64,
+        ) -> Result<(Vec<Product>, i64), InventoryError> {
+            unimplemented!()
+        }
+
+        async fn count_filtered(
+            &self,
+            _category_id: Option<CategoryId>,
+            _is_active: Option<bool>,
+            _search: Option<&str>,
+        ) -> Result<i64, InventoryError> {
+            unimplemented!()
+        }
+
+        async fn search(
+            &self,
+            _term: &str,
+            _category_id: Option<CategoryId>,
+            _active_only: bool,
+            _page: i64,
+            _page_size: i64,
+        ) -> Result<(Vec<(Product, f64)>, i64), InventoryError> {
+            unimplemented!()
+        }
+
+        async fn save_variant(&self, _variant: &ProductVariant) -> Result<(), InventoryError> {
+            unimplemented!()
+        }
+
+        async fn find_variant_by_id(
+            &self,
+            _id: VariantId,
+        ) -> Result<Option<ProductVariant>, InventoryError> {
+            unimplemented!()
+        }
+
+        async fn find_variant_by_sku(
+            &self,
+            _sku: &Sku,
+        ) -> Result<Option<ProductVariant>, InventoryError> {
+            unimplemented!()
+        }
+
+        async fn find_variant_by_barcode(
+            &self,
+            _barcode: &Barcode,
+        ) -> Result<Option<ProductVariant>, InventoryError> {
+            unimplemented!()
+        }
+
+        async fn find_variants_by_product(
+            &self,
+            _product_id: ProductId,
+        ) -> Result<Vec<ProductVariant>, InventoryError> {
+            unimplemented!()
+        }
+
+        async fn update_variant(&self, _variant: &ProductVariant) -> Result<(), InventoryError> {
+            unimplemented!()
+        }
+
+        async fn delete_variant(&self, _id: VariantId) -> Result<(), InventoryError> {
+            unimplemented!()
+        }
+
+        async fn count_variants(&self, _product_id: ProductId) -> Result<u32, InventoryError> {
+            unimplemented!()
+        }
+
+        async fn save_product_vendors(
+            &self,
+            vendors: &[ProductVendor],
+        ) -> Result<(), InventoryError> {
+            let mut stored = self.vendors.lock().unwrap();
+            for vendor in vendors {
+                stored.retain(|v| {
+                    !(v.product_id() == vendor.product_id() && v.vendor_id() == vendor.vendor_id())
+                });
+                stored.push(vendor.clone());
+            }
+            Ok(())
+        }
+
+        async fn find_product_vendors(
+            &self,
+            product_id: ProductId,
+        ) -> Result<Vec<ProductVendor>, InventoryError> {
+            let stored = self.vendors.lock().unwrap();
+            Ok(stored
+                .iter()
+                .filter(|v| v.product_id() == product_id)
+                .cloned()
+                .collect())
+        }
+
+        async fn find_preferred_vendor(
+            &self,
+            product_id: ProductId,
+        ) -> Result<Option<ProductVendor>, InventoryError> {
+            let stored = self.vendors.lock().unwrap();
+            Ok(stored
+                .iter()
+                .find(|v| v.product_id() == product_id && v.is_preferred())
+                .cloned())
+        }
+
+        async fn replace_unit_conversions(
+            &self,
+            _product_id: ProductId,
+            _conversions: &[UnitConversion],
+        ) -> Result<(), InventoryError> {
+            unimplemented!()
+        }
+
+        async fn find_unit_conversions(
+            &self,
+            _product_id: ProductId,
+        ) -> Result<Vec<UnitConversion>, InventoryError> {
+            unimplemented!()
+        }
+    }
+
+    fn setup() -> (
+        SetProductVendorUseCase<MockProductRepository>,
+        Arc<MockProductRepository>,
+        Uuid,
+    ) {
+        let product = Product::create("Coffee".to_string(), UnitOfMeasure::Kg, None);
+        let product_id = product.id().into_uuid();
+        let repo = Arc::new(MockProductRepository {
+            products: vec![product],
+            vendors: Mutex::new(Vec::new()),
+        });
+        (SetProductVendorUseCase::new(repo.clone()), repo, product_id)
+    }
+
+    fn command(product_id: Uuid, vendor_id: Uuid, is_preferred: bool) -> SetProductVendorCommand {
+        SetProductVendorCommand {
+            product_id,
+            vendor_id,
+            vendor_sku: Some(" ACM-001 ".to_string()),
+            last_purchase_price: Some(dec!(12.5)),
+            is_preferred,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_first_vendor_becomes_preferred() {
+        let (use_case, repo, product_id) = setup();
+        let vendor_id = Uuid::now_v7();
+
+        let response = use_case
+            .execute(command(product_id, vendor_id, false))
+            .await
+            .unwrap();
+
+        assert!(response.is_preferred);
+        assert_eq!(response.vendor_sku.as_deref(), Some("ACM-001"));
+        assert_eq!(response.last_purchase_price, Some(dec!(12.5)));
+        let preferred = repo
+            .find_preferred_vendor(ProductId::from_uuid(product_id))
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(preferred.vendor_id(), vendor_id);
+    }
+
+    #[tokio::test]
+    async fn test_marking_preferred_unmarks_previous() {
+        let (use_case, repo, product_id) = setup();
+        let first = Uuid::now_v7();
+        let second = Uuid::now_v7();
+        let third = Uuid::now_v7();
+
+        use_case
+            .execute(command(product_id, first, true))
+            .await
+            .unwrap();
+        let second_response = use_case
+            .execute(command(product_id, second, false))
+            .await
+            .unwrap();
+        assert!(!second_response.is_preferred);
+
+        use_case
+            .execute(command(product_id, third, true))
+            .await
+            .unwrap();
+
+        let vendors = repo
+            .find_product_vendors(ProductId::from_uuid(product_id))
+            .await
+            .unwrap();
+        assert_eq!(vendors.len(), 3);
+        let preferred: Vec<_> = vendors
+            .iter()
+            .filter(|v| v.is_preferred())
+            .map(|v| v.vendor_id())
+            .collect();
+        assert_eq!(preferred, vec![third]);
+    }
+
+    #[tokio::test]
+    async fn test_cannot_unmark_preferred_vendor() {
+        let (use_case, _repo, product_id) = setup();
+        let vendor_id = Uuid::now_v7();
+        use_case
+            .execute(command(product_id, vendor_id, true))
+            .await
+            .unwrap();
+
+        let result = use_case
+            .execute(command(product_id, vendor_id, false))
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(InventoryError::InvalidProductVendor(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_validation_errors() {
+        let (use_case, _repo, product_id) = setup();
+
+        let mut negative = command(product_id, Uuid::now_v7(), true);
+        negative.last_purchase_price = Some(dec!(-1));
+        assert!(matches!(
+            use_case.execute(negative).await,
+            Err(InventoryError::NegativeCost)
+        ));
+
+        let mut long_sku = command(product_id, Uuid::now_v7(), true);
+        long_sku.vendor_sku = Some("X".repeat(MAX_VENDOR_SKU_LENGTH + 1));
+        assert!(matches!(
+            use_case.execute(long_sku).await,
+            Err(InventoryError::InvalidProductVendor(_))
+        ));
+
+        let unknown = Uuid::now_v7();
+        assert!(matches!(
+            use_case.execute(command(unknown, Uuid::now_v7(), true)).await,
+            Err(InventoryError::ProductNotFound(id)) if id == unknown
+        ));
+    }
+}
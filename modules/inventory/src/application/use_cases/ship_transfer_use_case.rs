@@ -15,7 +15,8 @@ use identity::UserId;
 
 /// Use case for shipping a stock transfer.
 ///
-/// Validates status is pending, records shipper and timestamp,
+/// Validates status is pending (or approved, for transfers that required
+/// approval), records shipper and timestamp,
 /// reduces source stock for each item, creates transfer_out movements,
 /// and changes status to in_transit.
 pub struct ShipTransferUseCase<T, S, M>
@@ -55,7 +56,8 @@ where
     ///
     /// # Errors
     /// * `InventoryError::TransferNotFound` - If transfer doesn't exist
-    /// * `InventoryError::InvalidStatusTransition` - If transfer is not in pending status
+    /// * `InventoryError::InvalidStatusTransition` - If transfer is not in pending or approved status
+    /// * `InventoryError::TransferApprovalRequired` - If transfer required approval and was not approved
     /// * `InventoryError::StockNotFound` - If a stock record doesn't exist
     /// * `InventoryError::OptimisticLockError` - If concurrent modification detected
     /// * `InventoryError::InsufficientStock` - If not enough stock available
@@ -77,7 +79,7 @@ where
             transfer.set_tracking_number(Some(tracking))?;
         }
 
-        // 3. Ship the transfer (validates status is pending) (Requirement 11.4)
+        // 3. Ship the transfer (validates status and approval) (Requirement 11.4)
         transfer.ship(actor_id)?;
 
         // 4. Build a map of item_id -> shipped quantity from command
@@ -182,6 +184,7 @@ where
                 quantity_requested: item.quantity_requested(),
                 quantity_shipped: item.quantity_shipped(),
                 quantity_received: item.quantity_received(),
+                quantity_discrepancy: item.shipping_discrepancy(),
                 unit_cost: item.unit_cost(),
                 notes: item.notes().map(|s| s.to_string()),
                 created_at: item.created_at(),
@@ -203,6 +206,11 @@ where
             notes: transfer.notes().map(|s| s.to_string()),
             shipping_method: transfer.shipping_method().map(|s| s.to_string()),
             tracking_number: transfer.tracking_number().map(|s| s.to_string()),
+            requires_approval: transfer.requires_approval(),
+            approval_reason: transfer.approval_reason().map(|s| s.to_string()),
+            approved_by_id: transfer.approved_by_id().map(|id| id.into_uuid()),
+            approved_at: transfer.approved_at(),
+            approval_notes: transfer.approval_notes().map(|s| s.to_string()),
             items,
             created_at: transfer.created_at(),
             updated_at: transfer.updated_at(),
@@ -290,6 +298,10 @@ mod tests {
             Ok(vec![])
         }
 
+        async fn find_in_transit(&self) -> Result<Vec<StockTransfer>, InventoryError> {
+            Ok(vec![])
+        }
+
         async fn update(&self, transfer: &StockTransfer) -> Result<(), InventoryError> {
             let mut transfers = self.transfers.lock().unwrap();
             transfers.insert(transfer.id(), transfer.clone());
@@ -407,9 +419,10 @@ mod tests {
 
         async fn find_paginated(
             &self,
-            _store_id: Option<StoreId>,
+            _store_ids: Option<&[StoreId]>,
             _product_id: Option<ProductId>,
             _low_stock_only: bool,
+            _after_id: Option<Uuid>,
             _page: i64,
             _page_size: i64,
         ) -> Result<(Vec<InventoryStock>, i64), InventoryError> {
@@ -544,6 +557,37 @@ mod tests {
         async fn save_batch(&self, _movements: &[InventoryMovement]) -> Result<(), InventoryError> {
             unimplemented!()
         }
+
+        async fn find_all_by_stock_id_chronological(
+            &self,
+            _stock_id: StockId,
+        ) -> Result<Vec<InventoryMovement>, InventoryError> {
+            unimplemented!()
+        }
+
+        async fn rewrite_average_costs(
+            &self,
+            _stock_id: StockId,
+            _expected_version: i32,
+            _movement_costs: &[(crate::domain::value_objects::MovementId, Option<Decimal>)],
+            _average_cost: Option<Decimal>,
+        ) -> Result<crate::domain::repositories::AverageCostRewrite, InventoryError> {
+            unimplemented!()
+        }
+
+        async fn find_costing_method(
+            &self,
+            _stock_id: StockId,
+        ) -> Result<crate::domain::value_objects::CostingMethod, InventoryError> {
+            unimplemented!()
+        }
+
+        async fn find_open_cost_layers(
+            &self,
+            _stock_id: StockId,
+        ) -> Result<Vec<crate::domain::repositories::CostLayer>, InventoryError> {
+            unimplemented!()
+        }
     }
 
     fn create_pending_transfer(
@@ -696,6 +740,63 @@ mod tests {
         ));
     }
 
+    #[tokio::test]
+    async fn test_ship_transfer_awaiting_approval() {
+        let transfer_repo = Arc::new(MockTransferRepository::new());
+        let stock_repo = Arc::new(MockStockRepository::new());
+        let movement_repo = Arc::new(MockMovementRepository::new());
+
+        let from_store_id = StoreId::new();
+        let to_store_id = StoreId::new();
+        let product_id = ProductId::new();
+        stock_repo.add_stock(create_stock_with_quantity(
+            from_store_id,
+            product_id,
+            dec!(100),
+        ));
+
+        let mut transfer = StockTransfer::create(
+            "TRF-TEST-00001".to_string(),
+            from_store_id,
+            to_store_id,
+            UserId::new(),
+        )
+        .unwrap();
+        let item =
+            TransferItem::create_for_product(transfer.id(), product_id, dec!(10), Some(dec!(5.00)))
+                .unwrap();
+        transfer.add_item(item).unwrap();
+        transfer
+            .submit_for_approval("transfer value 50 exceeds threshold 10".to_string())
+            .unwrap();
+        let transfer_id = transfer.id();
+        transfer_repo.add_transfer(transfer);
+
+        let use_case =
+            ShipTransferUseCase::new(transfer_repo, stock_repo.clone(), movement_repo.clone());
+
+        let command = ShipTransferCommand {
+            transfer_id: transfer_id.into_uuid(),
+            tracking_number: None,
+            items: vec![],
+        };
+
+        let result = use_case.execute(command, UserId::new()).await;
+        assert!(matches!(
+            result,
+            Err(InventoryError::TransferApprovalRequired)
+        ));
+
+        // Stock untouched, no movements recorded
+        let stock = stock_repo
+            .find_by_store_and_product(from_store_id, product_id)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(stock.quantity(), dec!(100));
+        assert!(movement_repo.get_movements().is_empty());
+    }
+
     #[tokio::test]
     async fn test_ship_transfer_insufficient_stock() {
         let transfer_repo = Arc::new(MockTransferRepository::new());
@@ -90,6 +90,7 @@ where
             created_by_id: adjustment.created_by_id().into_uuid(),
             approved_by_id: adjustment.approved_by_id().map(|id| id.into_uuid()),
             approved_at: adjustment.approved_at(),
+            auto_approved: adjustment.auto_approved(),
             applied_at: adjustment.applied_at(),
             notes: adjustment.notes().map(|s| s.to_string()),
             attachments: Some(adjustment.attachments().clone()),
@@ -181,8 +182,9 @@ mod tests {
 
         async fn find_paginated(
             &self,
-            _store_id: Option<StoreId>,
+            _store_ids: Option<&[StoreId]>,
             _status: Option<&str>,
+            _after_id: Option<Uuid>,
             _page: i64,
             _page_size: i64,
         ) -> Result<(Vec<StockAdjustment>, i64), InventoryError> {
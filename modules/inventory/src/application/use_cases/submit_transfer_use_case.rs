@@ -1,4 +1,5 @@
-// SubmitTransferUseCase - submits a draft transfer for processing
+// SubmitTransferUseCase - submits a draft transfer for processing, routing it
+// to approval when the approval policy requires it
 
 use std::sync::Arc;
 
@@ -6,22 +7,30 @@ use crate::InventoryError;
 use crate::application::dtos::responses::{TransferDetailResponse, TransferItemResponse};
 use crate::domain::entities::StockTransfer;
 use crate::domain::repositories::TransferRepository;
-use crate::domain::value_objects::TransferId;
+use crate::domain::value_objects::{TransferApprovalPolicy, TransferId};
 
-/// Use case for submitting a draft transfer
+/// Use case for submitting a draft transfer.
+///
+/// Transfers that the approval policy flags (over the value threshold or
+/// cross-region) move to pending_approval; all others become shippable
+/// right away.
 pub struct SubmitTransferUseCase<T>
 where
     T: TransferRepository,
 {
     transfer_repo: Arc<T>,
+    approval_policy: TransferApprovalPolicy,
 }
 
 impl<T> SubmitTransferUseCase<T>
 where
     T: TransferRepository,
 {
-    pub fn new(transfer_repo: Arc<T>) -> Self {
-        Self { transfer_repo }
+    pub fn new(transfer_repo: Arc<T>, approval_policy: TransferApprovalPolicy) -> Self {
+        Self {
+            transfer_repo,
+            approval_policy,
+        }
     }
 
     pub async fn execute(&self, id: uuid::Uuid) -> Result<TransferDetailResponse, InventoryError> {
@@ -32,7 +41,10 @@ where
             .await?
             .ok_or(InventoryError::TransferNotFound(id))?;
 
-        transfer.submit()?;
+        match self.approval_policy.approval_reason(&transfer) {
+            Some(reason) => transfer.submit_for_approval(reason)?,
+            None => transfer.submit()?,
+        }
         self.transfer_repo.update(&transfer).await?;
 
         Ok(Self::to_response(&transfer))
@@ -52,6 +64,7 @@ where
                 quantity_requested: item.quantity_requested(),
                 quantity_shipped: item.quantity_shipped(),
                 quantity_received: item.quantity_received(),
+                quantity_discrepancy: item.shipping_discrepancy(),
                 unit_cost: item.unit_cost(),
                 notes: item.notes().map(|s| s.to_string()),
                 created_at: item.created_at(),
@@ -73,6 +86,11 @@ where
             notes: transfer.notes().map(|s| s.to_string()),
             shipping_method: transfer.shipping_method().map(|s| s.to_string()),
             tracking_number: transfer.tracking_number().map(|s| s.to_string()),
+            requires_approval: transfer.requires_approval(),
+            approval_reason: transfer.approval_reason().map(|s| s.to_string()),
+            approved_by_id: transfer.approved_by_id().map(|id| id.into_uuid()),
+            approved_at: transfer.approved_at(),
+            approval_notes: transfer.approval_notes().map(|s| s.to_string()),
             items,
             created_at: transfer.created_at(),
             updated_at: transfer.updated_at(),
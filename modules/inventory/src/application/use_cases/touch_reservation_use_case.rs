@@ -0,0 +1,275 @@
+// TouchReservationUseCase - keeps an active reservation alive
+
+use std::sync::Arc;
+
+use crate::InventoryError;
+use crate::application::dtos::commands::TouchReservationCommand;
+use crate::application::dtos::responses::ReservationResponse;
+use crate::domain::repositories::ReservationRepository;
+use crate::domain::value_objects::ReservationId;
+
+/// Use case for touching an inventory reservation.
+///
+/// Pushes the expiry of a pending reservation created with an auto-extend
+/// interval to at least that interval from now, so a storefront can keep a
+/// cart hold alive while the shopper is active. The hold on stock is
+/// unchanged.
+pub struct TouchReservationUseCase<R>
+where
+    R: ReservationRepository,
+{
+    reservation_repo: Arc<R>,
+}
+
+impl<R> TouchReservationUseCase<R>
+where
+    R: ReservationRepository,
+{
+    /// Creates a new instance of TouchReservationUseCase
+    pub fn new(reservation_repo: Arc<R>) -> Self {
+        Self { reservation_repo }
+    }
+
+    /// Executes the use case to touch a reservation
+    ///
+    /// # Arguments
+    /// * `command` - The touch reservation command
+    ///
+    /// # Returns
+    /// ReservationResponse with the new expiry on success
+    ///
+    /// # Errors
+    /// * `InventoryError::ReservationNotFound` - If reservation doesn't exist
+    /// * `InventoryError::ReservationExpired` - If the reservation already expired
+    /// * `InventoryError::InvalidReservationStatus` - If the reservation was
+    ///   confirmed or cancelled
+    /// * `InventoryError::ReservationNotAutoExtending` - If the reservation has
+    ///   no auto-extend interval
+    pub async fn execute(
+        &self,
+        command: TouchReservationCommand,
+    ) -> Result<ReservationResponse, InventoryError> {
+        // 1. Find reservation
+        let reservation_id = ReservationId::from_uuid(command.reservation_id);
+        let mut reservation = self
+            .reservation_repo
+            .find_by_id(reservation_id)
+            .await?
+            .ok_or(InventoryError::ReservationNotFound(command.reservation_id))?;
+
+        // 2. Extend the expiry (validates the reservation is still active)
+        reservation.touch()?;
+
+        // 3. Update reservation
+        self.reservation_repo.update(&reservation).await?;
+
+        // 4. Convert to response
+        Ok(ReservationResponse {
+            id: reservation.id().into_uuid(),
+            stock_id: reservation.stock_id().into_uuid(),
+            reference_type: reservation.reference_type().to_string(),
+            reference_id: reservation.reference_id(),
+            quantity: reservation.quantity(),
+            status: reservation.status().to_string(),
+            priority: reservation.priority(),
+            expires_at: reservation.expires_at(),
+            auto_extend_seconds: reservation.auto_extend_seconds(),
+            bumped_at: reservation.bumped_at(),
+            bump_reason: reservation.bump_reason().map(str::to_string),
+            created_at: reservation.created_at(),
+            updated_at: reservation.updated_at(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use async_trait::async_trait;
+    use chrono::{DateTime, Duration, Utc};
+    use rust_decimal_macros::dec;
+    use std::collections::HashMap;
+    use std::sync::Mutex;
+    use uuid::{NoContext, Timestamp, Uuid};
+
+    use crate::domain::entities::InventoryReservation;
+    use crate::domain::repositories::ReservationStatusCount;
+    use crate::domain::value_objects::{ReservationStatus, StockId};
+    use identity::StoreId;
+
+    fn new_uuid() -> Uuid {
+        Uuid::new_v7(Timestamp::now(NoContext))
+    }
+
+    // Mock repository
+    struct MockReservationRepository {
+        reservations: Mutex<HashMap<ReservationId, InventoryReservation>>,
+    }
+
+    impl MockReservationRepository {
+        fn new() -> Self {
+            Self {
+                reservations: Mutex::new(HashMap::new()),
+            }
+        }
+
+        fn add_reservation(&self, reservation: InventoryReservation) {
+            let mut reservations = self.reservations.lock().unwrap();
+            reservations.insert(reservation.id(), reservation);
+        }
+    }
+
+    #[async_trait]
+    impl ReservationRepository for MockReservationRepository {
+        async fn save(&self, reservation: &InventoryReservation) -> Result<(), InventoryError> {
+            let mut reservations = self.reservations.lock().unwrap();
+            reservations.insert(reservation.id(), reservation.clone());
+            Ok(())
+        }
+
+        async fn find_by_id(
+            &self,
+            id: ReservationId,
+        ) -> Result<Option<InventoryReservation>, InventoryError> {
+            let reservations = self.reservations.lock().unwrap();
+            Ok(reservations.get(&id).cloned())
+        }
+
+        async fn find_by_stock_id(
+            &self,
+            _stock_id: StockId,
+        ) -> Result<Vec<InventoryReservation>, InventoryError> {
+            unimplemented!()
+        }
+
+        async fn find_by_reference(
+            &self,
+            _reference_type: &str,
+            _reference_id: Uuid,
+        ) -> Result<Vec<InventoryReservation>, InventoryError> {
+            unimplemented!()
+        }
+
+        async fn find_expired(&self) -> Result<Vec<InventoryReservation>, InventoryError> {
+            unimplemented!()
+        }
+
+        async fn update(&self, reservation: &InventoryReservation) -> Result<(), InventoryError> {
+            let mut reservations = self.reservations.lock().unwrap();
+            reservations.insert(reservation.id(), reservation.clone());
+            Ok(())
+        }
+
+        async fn delete(&self, _id: ReservationId) -> Result<(), InventoryError> {
+            unimplemented!()
+        }
+
+        async fn find_paginated(
+            &self,
+            _store_ids: Option<&[StoreId]>,
+            _stock_id: Option<StockId>,
+            _status: Option<&str>,
+            _reference_type: Option<&str>,
+            _after_id: Option<Uuid>,
+            _page: i64,
+            _page_size: i64,
+        ) -> Result<(Vec<InventoryReservation>, i64), InventoryError> {
+            unimplemented!()
+        }
+
+        async fn count_by_status(
+            &self,
+            _store_id: StoreId,
+            _from: DateTime<Utc>,
+            _to: DateTime<Utc>,
+            _by_day: bool,
+        ) -> Result<Vec<ReservationStatusCount>, InventoryError> {
+            unimplemented!()
+        }
+    }
+
+    fn cart_reservation(auto_extend_seconds: Option<i64>) -> InventoryReservation {
+        let mut reservation = InventoryReservation::create(
+            StockId::new(),
+            "cart".to_string(),
+            new_uuid(),
+            dec!(2),
+            Utc::now() + Duration::minutes(5),
+            0,
+        )
+        .unwrap();
+        reservation.set_auto_extend(auto_extend_seconds).unwrap();
+        reservation
+    }
+
+    #[tokio::test]
+    async fn test_touch_extends_active_reservation() {
+        let reservation_repo = Arc::new(MockReservationRepository::new());
+        let reservation = cart_reservation(Some(1800));
+        let reservation_id = reservation.id();
+        reservation_repo.add_reservation(reservation);
+
+        let use_case = TouchReservationUseCase::new(reservation_repo.clone());
+        let response = use_case
+            .execute(TouchReservationCommand {
+                reservation_id: reservation_id.into_uuid(),
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(response.status, "pending");
+        assert!(response.expires_at > Utc::now() + Duration::minutes(29));
+        let stored = reservation_repo
+            .find_by_id(reservation_id)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(stored.expires_at(), response.expires_at);
+    }
+
+    #[tokio::test]
+    async fn test_touch_rejects_expired_and_confirmed() {
+        let reservation_repo = Arc::new(MockReservationRepository::new());
+
+        let lapsed = InventoryReservation::reconstitute(
+            ReservationId::new(),
+            StockId::new(),
+            "cart".to_string(),
+            new_uuid(),
+            dec!(2),
+            ReservationStatus::Pending,
+            0,
+            Utc::now() - Duration::minutes(1),
+            Some(1800),
+            None,
+            None,
+            Utc::now() - Duration::minutes(30),
+            Utc::now() - Duration::minutes(30),
+        );
+        let lapsed_id = lapsed.id().into_uuid();
+        reservation_repo.add_reservation(lapsed);
+
+        let mut confirmed = cart_reservation(Some(1800));
+        confirmed.confirm().unwrap();
+        let confirmed_id = confirmed.id().into_uuid();
+        reservation_repo.add_reservation(confirmed);
+
+        let use_case = TouchReservationUseCase::new(reservation_repo);
+        assert!(matches!(
+            use_case
+                .execute(TouchReservationCommand {
+                    reservation_id: lapsed_id,
+                })
+                .await,
+            Err(InventoryError::ReservationExpired)
+        ));
+        assert!(matches!(
+            use_case
+                .execute(TouchReservationCommand {
+                    reservation_id: confirmed_id,
+                })
+                .await,
+            Err(InventoryError::InvalidReservationStatus)
+        ));
+    }
+}
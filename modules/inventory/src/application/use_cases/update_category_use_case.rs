@@ -1,12 +1,14 @@
 // UpdateCategoryUseCase - updates an existing product category
 
+use std::str::FromStr;
 use std::sync::Arc;
 
 use crate::InventoryError;
 use crate::application::dtos::commands::UpdateCategoryCommand;
 use crate::application::dtos::responses::CategoryResponse;
+use crate::application::helpers::{DEFAULT_MAX_CATEGORY_DEPTH, validate_category_placement};
 use crate::domain::repositories::CategoryRepository;
-use crate::domain::value_objects::CategoryId;
+use crate::domain::value_objects::{CategoryId, CostingMethod, TaxCategory};
 
 /// Use case for updating an existing product category
 pub struct UpdateCategoryUseCase<C>
@@ -14,6 +16,7 @@ where
     C: CategoryRepository,
 {
     category_repo: Arc<C>,
+    max_depth: usize,
 }
 
 impl<C> UpdateCategoryUseCase<C>
@@ -21,7 +24,16 @@ where
     C: CategoryRepository,
 {
     pub fn new(category_repo: Arc<C>) -> Self {
-        Self { category_repo }
+        Self {
+            category_repo,
+            max_depth: DEFAULT_MAX_CATEGORY_DEPTH,
+        }
+    }
+
+    /// Sets the maximum number of levels in the category tree
+    pub fn with_max_depth(mut self, max_depth: usize) -> Self {
+        self.max_depth = max_depth;
+        self
     }
 
     pub async fn execute(
@@ -56,6 +68,15 @@ where
             if self.category_repo.find_by_id(parent_id).await?.is_none() {
                 return Err(InventoryError::ParentCategoryNotFound(parent_uuid));
             }
+            if category.parent_id() != Some(parent_id) {
+                validate_category_placement(
+                    self.category_repo.as_ref(),
+                    Some(category_id),
+                    parent_id,
+                    self.max_depth,
+                )
+                .await?;
+            }
         }
 
         // Apply updates
@@ -80,6 +101,16 @@ where
         if let Some(is_active) = command.is_active {
             category.set_active(is_active);
         }
+        if let Some(tax_category) = command.tax_category {
+            let tax_category = tax_category
+                .as_deref()
+                .map(TaxCategory::from_str)
+                .transpose()?;
+            category.set_tax_category(tax_category);
+        }
+        if let Some(costing_method) = command.costing_method {
+            category.set_costing_method(CostingMethod::from_str(&costing_method)?);
+        }
 
         self.category_repo.update(&category).await?;
 
@@ -92,6 +123,8 @@ where
             icon: category.icon().map(|s| s.to_string()),
             sort_order: category.sort_order(),
             is_active: category.is_active(),
+            tax_category: category.tax_category().map(|t| t.to_string()),
+            costing_method: category.costing_method().to_string(),
             created_at: category.created_at(),
             updated_at: category.updated_at(),
         })
@@ -102,7 +135,9 @@ where
 mod tests {
     use super::*;
     use crate::domain::entities::ProductCategory;
+    use crate::domain::repositories::CategoryStockValuation;
     use async_trait::async_trait;
+    use identity::StoreId;
     use std::collections::HashMap;
     use std::sync::Mutex;
 
@@ -163,6 +198,12 @@ mod tests {
             slugs.insert(category.slug().to_string(), category.id());
             Ok(())
         }
+        async fn stock_valuation_by_category(
+            &self,
+            _store_id: Option<StoreId>,
+        ) -> Result<Vec<CategoryStockValuation>, InventoryError> {
+            Ok(vec![])
+        }
         async fn delete(&self, _id: CategoryId) -> Result<(), InventoryError> {
             Ok(())
         }
@@ -185,6 +226,8 @@ mod tests {
             icon: None,
             sort_order: None,
             is_active: None,
+            tax_category: None,
+            costing_method: None,
         };
 
         let result = use_case.execute(category_id, command).await.unwrap();
@@ -192,6 +235,31 @@ mod tests {
         assert_eq!(result.slug, "electronics");
     }
 
+    #[tokio::test]
+    async fn test_update_category_costing_method() {
+        let repo = Arc::new(MockCategoryRepository::new());
+        let category =
+            ProductCategory::create("Electronics".to_string(), "electronics".to_string());
+        let category_id = category.id().into_uuid();
+        repo.save(&category).await.unwrap();
+
+        let use_case = UpdateCategoryUseCase::new(repo);
+        let command = UpdateCategoryCommand {
+            name: None,
+            slug: None,
+            parent_id: None,
+            description: None,
+            icon: None,
+            sort_order: None,
+            is_active: None,
+            tax_category: None,
+            costing_method: Some("fifo".to_string()),
+        };
+
+        let result = use_case.execute(category_id, command).await.unwrap();
+        assert_eq!(result.costing_method, "fifo");
+    }
+
     #[tokio::test]
     async fn test_update_category_duplicate_slug() {
         let repo = Arc::new(MockCategoryRepository::new());
@@ -211,6 +279,8 @@ mod tests {
             icon: None,
             sort_order: None,
             is_active: None,
+            tax_category: None,
+            costing_method: None,
         };
 
         let result = use_case.execute(cat2_id, command).await;
@@ -232,6 +302,8 @@ mod tests {
             icon: None,
             sort_order: None,
             is_active: None,
+            tax_category: None,
+            costing_method: None,
         };
 
         let result = use_case
@@ -1,12 +1,15 @@
 // UpdateProductUseCase - updates an existing product
 
+use std::str::FromStr;
 use std::sync::Arc;
 
 use crate::InventoryError;
 use crate::application::dtos::commands::UpdateProductCommand;
 use crate::application::dtos::responses::ProductResponse;
 use crate::domain::repositories::{CategoryRepository, ProductRepository};
-use crate::domain::value_objects::{Barcode, CategoryId, Currency, ProductId, UnitOfMeasure};
+use crate::domain::value_objects::{
+    Barcode, CategoryId, Currency, MarkupRule, ProductId, TaxCategory, UnitOfMeasure,
+};
 use identity::domain::entities::AuditEntry;
 use identity::domain::repositories::AuditRepository;
 use identity::domain::value_objects::UserId;
@@ -113,6 +116,26 @@ where
             product.set_tax_included(tax_included);
         }
 
+        if let Some(tax_category) = command.tax_category {
+            let tax_category = tax_category
+                .as_deref()
+                .map(TaxCategory::from_str)
+                .transpose()?;
+            product.set_tax_category(tax_category);
+        }
+
+        if let Some(is_serialized) = command.is_serialized {
+            product.set_serialized(is_serialized);
+        }
+
+        if let Some(markup_type) = command.markup_type {
+            let markup_rule = markup_type
+                .as_deref()
+                .map(|t| MarkupRule::parse(t, command.markup_percent))
+                .transpose()?;
+            product.set_markup_rule(markup_rule);
+        }
+
         if let Some(attributes) = command.attributes {
             product.set_attributes(attributes);
         }
@@ -180,6 +203,7 @@ where
             id: product.id().into_uuid(),
             sku: product.sku().as_str().to_string(),
             barcode: product.barcode().map(|b| b.as_str().to_string()),
+            barcode_generated: product.barcode_generated(),
             name: product.name().to_string(),
             description: product.description().map(|s| s.to_string()),
             category_id: product.category_id().map(|id| id.into_uuid()),
@@ -193,6 +217,10 @@ where
             has_variants: product.has_variants(),
             tax_rate: product.tax_rate(),
             tax_included: product.tax_included(),
+            tax_category: product.tax_category().map(|t| t.to_string()),
+            is_serialized: product.is_serialized(),
+            markup_type: product.markup_rule().map(|r| r.markup_type().to_string()),
+            markup_percent: product.markup_rule().and_then(|r| r.percent()),
             is_active: product.is_active(),
             created_at: product.created_at(),
             updated_at: product.updated_at(),
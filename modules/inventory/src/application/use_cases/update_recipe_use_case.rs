@@ -71,6 +71,10 @@ where
             recipe.set_yield_quantity(yield_quantity)?;
         }
 
+        if let Some(yield_unit) = command.yield_unit {
+            recipe.set_yield_unit(yield_unit.parse()?);
+        }
+
         if let Some(preparation_time_minutes) = command.preparation_time_minutes {
             recipe.set_preparation_time_minutes(Some(preparation_time_minutes));
         }
@@ -112,6 +116,7 @@ where
             description: recipe.description().map(|s| s.to_string()),
             version: recipe.version(),
             yield_quantity: recipe.yield_quantity(),
+            yield_unit: recipe.yield_unit().to_string(),
             is_active: recipe.is_active(),
             preparation_time_minutes: recipe.preparation_time_minutes(),
             calculate_cost_from_ingredients: recipe.calculate_cost_from_ingredients(),
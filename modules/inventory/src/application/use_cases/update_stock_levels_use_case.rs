@@ -2,6 +2,8 @@
 
 use std::sync::Arc;
 
+use rust_decimal::Decimal;
+
 use crate::InventoryError;
 use crate::application::dtos::commands::UpdateStockLevelsCommand;
 use crate::application::dtos::responses::StockResponse;
@@ -11,10 +13,11 @@ use identity::domain::entities::AuditEntry;
 use identity::domain::repositories::AuditRepository;
 use identity::domain::value_objects::UserId;
 
-/// Use case for updating stock level thresholds (min/max).
+/// Use case for updating stock level thresholds (min/max and reorder point).
 ///
-/// This allows updating the minimum and maximum stock levels for alerting
-/// and inventory management without changing the actual quantity.
+/// This allows updating the minimum and maximum stock levels and the store's
+/// reorder settings for alerting and inventory management without changing
+/// the actual quantity.
 pub struct UpdateStockLevelsUseCase<S, A>
 where
     S: InventoryStockRepository,
@@ -47,6 +50,8 @@ where
     /// StockResponse on success
     ///
     /// # Errors
+    /// * `InventoryError::InvalidReorderLevel` - If the reorder point is negative
+    ///   or the reorder quantity is not positive
     /// * `InventoryError::StockNotFound` - If stock record doesn't exist
     /// * `InventoryError::OptimisticLockError` - If version mismatch
     pub async fn execute(
@@ -54,6 +59,17 @@ where
         command: UpdateStockLevelsCommand,
         actor_id: UserId,
     ) -> Result<StockResponse, InventoryError> {
+        if command.reorder_point.is_some_and(|p| p < Decimal::ZERO) {
+            return Err(InventoryError::InvalidReorderLevel(
+                "reorder_point cannot be negative".to_string(),
+            ));
+        }
+        if command.reorder_quantity.is_some_and(|q| q <= Decimal::ZERO) {
+            return Err(InventoryError::InvalidReorderLevel(
+                "reorder_quantity must be positive".to_string(),
+            ));
+        }
+
         let stock_id = StockId::from_uuid(command.stock_id);
 
         // 1. Find existing stock record
@@ -68,6 +84,8 @@ where
         // 2. Update levels
         stock.set_min_stock_level(command.min_stock_level);
         stock.set_max_stock_level(command.max_stock_level);
+        stock.set_reorder_point(command.reorder_point);
+        stock.set_reorder_quantity(command.reorder_quantity);
 
         // 3. Save with optimistic locking
         self.stock_repo
@@ -99,6 +117,11 @@ where
             version: stock.version(),
             min_stock_level: stock.min_stock_level(),
             max_stock_level: stock.max_stock_level(),
+            reorder_point: stock.reorder_point(),
+            reorder_quantity: stock.reorder_quantity(),
+            suggested_reorder_quantity: stock
+                .is_low_stock()
+                .then(|| stock.suggested_reorder_quantity()),
             is_low_stock: stock.is_low_stock(),
             created_at: stock.created_at(),
             updated_at: stock.updated_at(),
@@ -3,21 +3,44 @@
 use std::str::FromStr;
 use std::sync::Arc;
 
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+
 use crate::InventoryError;
 use crate::application::dtos::commands::UpdateStockCommand;
 use crate::application::dtos::responses::StockResponse;
+use crate::application::helpers::{
+    DEFAULT_BASE_DELAY_MS, DEFAULT_MAX_ATTEMPTS, ensure_period_open, retry_on_conflict_counted,
+};
 use crate::domain::entities::InventoryMovement;
-use crate::domain::repositories::{InventoryMovementRepository, InventoryStockRepository};
+use crate::domain::repositories::{
+    InventoryMovementRepository, InventoryPeriodRepository, InventoryStockRepository,
+};
 use crate::domain::value_objects::{Currency, MovementType, StockId};
 use identity::domain::entities::AuditEntry;
 use identity::domain::repositories::AuditRepository;
 use identity::domain::value_objects::UserId;
 
+/// Updated stock, with how many attempts the update took
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UpdateStockResult {
+    pub stock: StockResponse,
+    /// 1 when the first write went through; anything higher means the
+    /// stock was contended and the update was retried
+    pub attempts: u32,
+}
+
 /// Use case for updating stock quantity with optimistic locking.
 ///
 /// Validates version matches, applies quantity change, increments version,
 /// creates inventory movement, and creates audit entry.
 ///
+/// Without an `expected_version`, version conflicts on the write are retried
+/// with a fresh read, up to `max_attempts` times, so callers need no retry
+/// loop of their own. With one, the write is pinned to that version and a
+/// conflict is returned to the caller instead of retried.
+/// With a period lock, updates whose effective date falls in a closed period
+/// of the stock's store are rejected.
 pub struct UpdateStockUseCase<S, M, A>
 where
     S: InventoryStockRepository,
@@ -27,6 +50,9 @@ where
     stock_repo: Arc<S>,
     movement_repo: Arc<M>,
     audit_repo: Arc<A>,
+    max_attempts: u32,
+    period_repo: Option<Arc<dyn InventoryPeriodRepository>>,
+    can_post_to_closed_period: bool,
 }
 
 impl<S, M, A> UpdateStockUseCase<S, M, A>
@@ -41,9 +67,29 @@ where
             stock_repo,
             movement_repo,
             audit_repo,
+            max_attempts: DEFAULT_MAX_ATTEMPTS,
+            period_repo: None,
+            can_post_to_closed_period: false,
         }
     }
 
+    /// Sets how many times a conflicting write is attempted (at least once)
+    pub fn with_max_attempts(mut self, max_attempts: u32) -> Self {
+        self.max_attempts = max_attempts.max(1);
+        self
+    }
+
+    /// Rejects updates dated in a closed period, unless `can_override` is set
+    pub fn with_period_lock(
+        mut self,
+        period_repo: Arc<dyn InventoryPeriodRepository>,
+        can_override: bool,
+    ) -> Self {
+        self.period_repo = Some(period_repo);
+        self.can_post_to_closed_period = can_override;
+        self
+    }
+
     /// Executes the use case to update stock quantity
     ///
     /// # Arguments
@@ -51,41 +97,66 @@ where
     /// * `actor_id` - ID of the user performing this action (for audit and movement)
     ///
     /// # Returns
-    /// StockResponse on success
+    /// The updated stock and the number of attempts it took
     ///
     /// # Errors
     /// * `InventoryError::StockNotFound` - If stock record doesn't exist
-    /// * `InventoryError::OptimisticLockError` - If version mismatch (concurrent modification)
+    /// * `InventoryError::OptimisticLockError` - If `expected_version` is set and
+    ///   stale, or the stock changes before the write
+    /// * `InventoryError::ConcurrencyExhausted` - If `expected_version` is not set
+    ///   and every attempt hit a version conflict
     /// * `InventoryError::NegativeStock` - If adjustment would result in negative stock
     /// * `InventoryError::ReservedExceedsQuantity` - If adjustment would make quantity < reserved
     /// * `InventoryError::InvalidMovementType` - If movement type is invalid
+    /// * `InventoryError::PeriodClosed` - If the effective date falls in a
+    ///   closed period and the actor can't override it
     pub async fn execute(
         &self,
         command: UpdateStockCommand,
         actor_id: UserId,
-    ) -> Result<StockResponse, InventoryError> {
-        use crate::application::helpers::{
-            DEFAULT_BASE_DELAY_MS, DEFAULT_MAX_ATTEMPTS, retry_on_conflict,
-        };
-
+    ) -> Result<UpdateStockResult, InventoryError> {
         // 1. Parse and validate movement type (Requirement 5.1)
         let movement_type = MovementType::from_str(&command.movement_type)?;
         let stock_id = StockId::from_uuid(command.stock_id);
 
-        // 2. Validate client-side expected version before attempting update
-        let initial_stock = self
-            .stock_repo
-            .find_by_id(stock_id)
-            .await?
-            .ok_or(InventoryError::StockNotFound(command.stock_id))?;
+        // 2. Validate client-side expected version and the period before
+        // attempting update
+        if command.expected_version.is_some() || self.period_repo.is_some() {
+            let initial_stock = self
+                .stock_repo
+                .find_by_id(stock_id)
+                .await?
+                .ok_or(InventoryError::StockNotFound(command.stock_id))?;
+
+            if command
+                .expected_version
+                .is_some_and(|expected_version| initial_stock.version() != expected_version)
+            {
+                return Err(InventoryError::OptimisticLockError);
+            }
 
-        if initial_stock.version() != command.expected_version {
-            return Err(InventoryError::OptimisticLockError);
+            if let Some(period_repo) = &self.period_repo {
+                let effective_date = command
+                    .effective_date
+                    .unwrap_or_else(|| Utc::now().date_naive());
+                ensure_period_open(
+                    period_repo.as_ref(),
+                    initial_stock.store_id(),
+                    effective_date,
+                    self.can_post_to_closed_period,
+                )
+                .await?;
+            }
         }
 
-        // 3. Retry stock update on DB-level optimistic lock conflict
-        let (stock, old_stock) =
-            retry_on_conflict(DEFAULT_MAX_ATTEMPTS, DEFAULT_BASE_DELAY_MS, || async {
+        // 3. Retry stock update on DB-level optimistic lock conflict, unless
+        // the caller pinned the version it expects
+        let max_attempts = match command.expected_version {
+            Some(_) => 1,
+            None => self.max_attempts,
+        };
+        let ((stock, old_stock), attempts) =
+            retry_on_conflict_counted(max_attempts, DEFAULT_BASE_DELAY_MS, || async {
                 // Re-fetch stock to get latest version
                 let mut stock = self
                     .stock_repo
@@ -94,7 +165,7 @@ where
                     .ok_or(InventoryError::StockNotFound(command.stock_id))?;
 
                 let old_stock = stock.clone();
-                let expected_version = stock.version();
+                let expected_version = command.expected_version.unwrap_or(stock.version());
 
                 // Apply quantity change (Requirement 3.4)
                 stock.adjust_quantity(command.quantity_delta)?;
@@ -107,7 +178,15 @@ where
 
                 Ok((stock, old_stock))
             })
-            .await?;
+            .await
+            .map_err(|e| match e {
+                InventoryError::OptimisticLockError if command.expected_version.is_none() => {
+                    InventoryError::ConcurrencyExhausted {
+                        attempts: max_attempts,
+                    }
+                }
+                e => e,
+            })?;
 
         // 3. Record movement (Requirement 5.1, 5.3)
         let movement = InventoryMovement::create(
@@ -139,7 +218,7 @@ where
             .map_err(|e| InventoryError::AuditError(e.to_string()))?;
 
         // 5. Convert to response
-        Ok(StockResponse {
+        let stock = StockResponse {
             id: stock.id().into_uuid(),
             store_id: stock.store_id().into_uuid(),
             product_id: stock.product_id().map(|id| id.into_uuid()),
@@ -150,10 +229,17 @@ where
             version: stock.version(),
             min_stock_level: stock.min_stock_level(),
             max_stock_level: stock.max_stock_level(),
+            reorder_point: stock.reorder_point(),
+            reorder_quantity: stock.reorder_quantity(),
+            suggested_reorder_quantity: stock
+                .is_low_stock()
+                .then(|| stock.suggested_reorder_quantity()),
             is_low_stock: stock.is_low_stock(),
             created_at: stock.created_at(),
             updated_at: stock.updated_at(),
-        })
+        };
+
+        Ok(UpdateStockResult { stock, attempts })
     }
 }
 
@@ -161,7 +247,7 @@ where
 mod tests {
     use super::*;
     use async_trait::async_trait;
-    use chrono::Utc;
+    use chrono::NaiveDate;
     use identity::domain::entities::AuditEntry;
     use rust_decimal::Decimal;
     use rust_decimal_macros::dec;
@@ -169,8 +255,8 @@ mod tests {
     use std::sync::Mutex;
     use uuid::{NoContext, Timestamp, Uuid};
 
-    use crate::domain::entities::InventoryStock;
-    use crate::domain::value_objects::ProductId;
+    use crate::domain::entities::{InventoryPeriod, InventoryStock};
+    use crate::domain::value_objects::{InventoryPeriodId, ProductId};
     use identity::StoreId;
 
     fn new_uuid() -> Uuid {
@@ -180,12 +266,22 @@ mod tests {
     // Mock repositories for testing
     struct MockStockRepository {
         stocks: Mutex<HashMap<StockId, InventoryStock>>,
+        /// Writes that fail with a version conflict before one goes through
+        conflicts: Mutex<u32>,
     }
 
     impl MockStockRepository {
         fn new() -> Self {
             Self {
                 stocks: Mutex::new(HashMap::new()),
+                conflicts: Mutex::new(0),
+            }
+        }
+
+        fn with_conflicts(conflicts: u32) -> Self {
+            Self {
+                stocks: Mutex::new(HashMap::new()),
+                conflicts: Mutex::new(conflicts),
             }
         }
 
@@ -229,6 +325,11 @@ mod tests {
             stock: &InventoryStock,
             expected_version: i32,
         ) -> Result<(), InventoryError> {
+            let mut conflicts = self.conflicts.lock().unwrap();
+            if *conflicts > 0 {
+                *conflicts -= 1;
+                return Err(InventoryError::OptimisticLockError);
+            }
             let mut stocks = self.stocks.lock().unwrap();
             if let Some(existing) = stocks.get(&stock.id()) {
                 if existing.version() != expected_version {
@@ -257,9 +358,10 @@ mod tests {
 
         async fn find_paginated(
             &self,
-            _store_id: Option<StoreId>,
+            _store_ids: Option<&[StoreId]>,
             _product_id: Option<ProductId>,
             _low_stock_only: bool,
+            _after_id: Option<Uuid>,
             _page: i64,
             _page_size: i64,
         ) -> Result<(Vec<InventoryStock>, i64), InventoryError> {
@@ -390,6 +492,37 @@ mod tests {
         async fn save_batch(&self, _movements: &[InventoryMovement]) -> Result<(), InventoryError> {
             unimplemented!()
         }
+
+        async fn find_all_by_stock_id_chronological(
+            &self,
+            _stock_id: StockId,
+        ) -> Result<Vec<InventoryMovement>, InventoryError> {
+            unimplemented!()
+        }
+
+        async fn rewrite_average_costs(
+            &self,
+            _stock_id: StockId,
+            _expected_version: i32,
+            _movement_costs: &[(crate::domain::value_objects::MovementId, Option<Decimal>)],
+            _average_cost: Option<Decimal>,
+        ) -> Result<crate::domain::repositories::AverageCostRewrite, InventoryError> {
+            unimplemented!()
+        }
+
+        async fn find_costing_method(
+            &self,
+            _stock_id: StockId,
+        ) -> Result<crate::domain::value_objects::CostingMethod, InventoryError> {
+            unimplemented!()
+        }
+
+        async fn find_open_cost_layers(
+            &self,
+            _stock_id: StockId,
+        ) -> Result<Vec<crate::domain::repositories::CostLayer>, InventoryError> {
+            unimplemented!()
+        }
     }
 
     struct MockAuditRepository {
@@ -427,6 +560,15 @@ mod tests {
         ) -> Result<Vec<AuditEntry>, identity::IdentityError> {
             unimplemented!()
         }
+
+        async fn find_paginated(
+            &self,
+            _filter: &identity::AuditEntryFilter,
+            _page: i64,
+            _page_size: i64,
+        ) -> Result<(Vec<AuditEntry>, i64), identity::IdentityError> {
+            unimplemented!()
+        }
     }
 
     #[tokio::test]
@@ -455,7 +597,8 @@ mod tests {
             reference_type: Some("purchase_order".to_string()),
             reference_id: Some(new_uuid()),
             notes: Some("Restocking".to_string()),
-            expected_version: initial_version,
+            expected_version: Some(initial_version),
+            effective_date: None,
         };
 
         let actor_id = UserId::new();
@@ -463,8 +606,9 @@ mod tests {
         assert!(result.is_ok());
 
         let response = result.unwrap();
-        assert_eq!(response.quantity, dec!(150));
-        assert_eq!(response.version, initial_version + 1);
+        assert_eq!(response.stock.quantity, dec!(150));
+        assert_eq!(response.stock.version, initial_version + 1);
+        assert_eq!(response.attempts, 1);
 
         // Verify movement was created
         let movements = movement_repo.movements.lock().unwrap();
@@ -499,7 +643,8 @@ mod tests {
             reference_type: Some("order".to_string()),
             reference_id: Some(new_uuid()),
             notes: None,
-            expected_version: initial_version,
+            expected_version: Some(initial_version),
+            effective_date: None,
         };
 
         let actor_id = UserId::new();
@@ -507,7 +652,7 @@ mod tests {
         assert!(result.is_ok());
 
         let response = result.unwrap();
-        assert_eq!(response.quantity, dec!(70));
+        assert_eq!(response.stock.quantity, dec!(70));
     }
 
     #[tokio::test]
@@ -536,7 +681,8 @@ mod tests {
             reference_type: None,
             reference_id: None,
             notes: None,
-            expected_version: 999, // Wrong version
+            expected_version: Some(999), // Wrong version
+            effective_date: None,
         };
 
         let actor_id = UserId::new();
@@ -561,7 +707,8 @@ mod tests {
             reference_type: None,
             reference_id: None,
             notes: None,
-            expected_version: 1,
+            expected_version: Some(1),
+            effective_date: None,
         };
 
         let actor_id = UserId::new();
@@ -596,11 +743,203 @@ mod tests {
             reference_type: None,
             reference_id: None,
             notes: None,
-            expected_version: initial_version,
+            expected_version: Some(initial_version),
+            effective_date: None,
         };
 
         let actor_id = UserId::new();
         let result = use_case.execute(command, actor_id).await;
         assert!(matches!(result, Err(InventoryError::NegativeStock)));
     }
+
+    fn restock_command(stock_id: StockId) -> UpdateStockCommand {
+        UpdateStockCommand {
+            stock_id: stock_id.into_uuid(),
+            quantity_delta: dec!(5),
+            movement_type: "in".to_string(),
+            movement_reason: None,
+            unit_cost: None,
+            reference_type: None,
+            reference_id: None,
+            notes: None,
+            expected_version: None,
+            effective_date: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_update_stock_retries_conflicts() {
+        let stock_repo = Arc::new(MockStockRepository::with_conflicts(2));
+        let stock = InventoryStock::create_for_product(StoreId::new(), ProductId::new()).unwrap();
+        let stock_id = stock.id();
+        stock_repo.add_stock(stock);
+
+        let use_case = UpdateStockUseCase::new(
+            stock_repo,
+            Arc::new(MockMovementRepository::new()),
+            Arc::new(MockAuditRepository::new()),
+        );
+
+        let response = use_case
+            .execute(restock_command(stock_id), UserId::new())
+            .await
+            .unwrap();
+        assert_eq!(response.stock.quantity, dec!(5));
+        assert_eq!(response.attempts, 3);
+    }
+
+    #[tokio::test]
+    async fn test_update_stock_concurrency_exhausted() {
+        let stock_repo = Arc::new(MockStockRepository::with_conflicts(5));
+        let stock = InventoryStock::create_for_product(StoreId::new(), ProductId::new()).unwrap();
+        let stock_id = stock.id();
+        stock_repo.add_stock(stock);
+        let movement_repo = Arc::new(MockMovementRepository::new());
+
+        let use_case = UpdateStockUseCase::new(
+            stock_repo,
+            movement_repo.clone(),
+            Arc::new(MockAuditRepository::new()),
+        )
+        .with_max_attempts(2);
+
+        let result = use_case
+            .execute(restock_command(stock_id), UserId::new())
+            .await;
+        assert!(matches!(
+            result,
+            Err(InventoryError::ConcurrencyExhausted { attempts: 2 })
+        ));
+        assert!(movement_repo.movements.lock().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_update_stock_with_expected_version_does_not_retry_conflicts() {
+        let stock_repo = Arc::new(MockStockRepository::with_conflicts(1));
+        let stock = InventoryStock::create_for_product(StoreId::new(), ProductId::new()).unwrap();
+        let stock_id = stock.id();
+        let version = stock.version();
+        stock_repo.add_stock(stock);
+        let movement_repo = Arc::new(MockMovementRepository::new());
+
+        let use_case = UpdateStockUseCase::new(
+            stock_repo.clone(),
+            movement_repo.clone(),
+            Arc::new(MockAuditRepository::new()),
+        );
+
+        let command = UpdateStockCommand {
+            expected_version: Some(version),
+            ..restock_command(stock_id)
+        };
+        let result = use_case.execute(command, UserId::new()).await;
+        assert!(matches!(result, Err(InventoryError::OptimisticLockError)));
+        assert!(movement_repo.movements.lock().unwrap().is_empty());
+
+        let stored = stock_repo.find_by_id(stock_id).await.unwrap().unwrap();
+        assert_eq!(stored.quantity(), Decimal::ZERO);
+        assert_eq!(stored.version(), version);
+    }
+
+    struct MockPeriodRepository {
+        periods: Vec<InventoryPeriod>,
+    }
+
+    #[async_trait]
+    impl InventoryPeriodRepository for MockPeriodRepository {
+        async fn save(&self, _period: &InventoryPeriod) -> Result<(), InventoryError> {
+            unimplemented!()
+        }
+
+        async fn update(&self, _period: &InventoryPeriod) -> Result<(), InventoryError> {
+            unimplemented!()
+        }
+
+        async fn find_by_id(
+            &self,
+            _id: InventoryPeriodId,
+        ) -> Result<Option<InventoryPeriod>, InventoryError> {
+            unimplemented!()
+        }
+
+        async fn find_overlapping(
+            &self,
+            _store_id: StoreId,
+            _start: NaiveDate,
+            _end: NaiveDate,
+        ) -> Result<Vec<InventoryPeriod>, InventoryError> {
+            unimplemented!()
+        }
+
+        async fn find_closed_containing(
+            &self,
+            store_id: StoreId,
+            date: NaiveDate,
+        ) -> Result<Option<InventoryPeriod>, InventoryError> {
+            Ok(self
+                .periods
+                .iter()
+                .find(|p| p.store_id() == store_id && p.is_closed() && p.contains(date))
+                .cloned())
+        }
+
+        async fn find_by_store(
+            &self,
+            _store_id: StoreId,
+        ) -> Result<Vec<InventoryPeriod>, InventoryError> {
+            unimplemented!()
+        }
+    }
+
+    #[tokio::test]
+    async fn test_update_stock_dated_in_closed_period() {
+        let store_id = StoreId::new();
+        let stock_repo = Arc::new(MockStockRepository::new());
+        let stock = InventoryStock::create_for_product(store_id, ProductId::new()).unwrap();
+        let stock_id = stock.id();
+        stock_repo.add_stock(stock);
+
+        let august_end = NaiveDate::from_ymd_opt(2026, 8, 31).unwrap();
+        let mut period = InventoryPeriod::create(
+            store_id,
+            NaiveDate::from_ymd_opt(2026, 8, 1).unwrap(),
+            august_end,
+        )
+        .unwrap();
+        period.close(UserId::new()).unwrap();
+        let period_repo = Arc::new(MockPeriodRepository {
+            periods: vec![period],
+        });
+
+        let use_case = |can_override| {
+            UpdateStockUseCase::new(
+                stock_repo.clone(),
+                Arc::new(MockMovementRepository::new()),
+                Arc::new(MockAuditRepository::new()),
+            )
+            .with_period_lock(period_repo.clone(), can_override)
+        };
+        let dated = |date| UpdateStockCommand {
+            effective_date: Some(date),
+            ..restock_command(stock_id)
+        };
+
+        let result = use_case(false)
+            .execute(dated(august_end), UserId::new())
+            .await;
+        assert!(matches!(result, Err(InventoryError::PeriodClosed(_, _))));
+
+        // The document date decides, not when the update is posted
+        let response = use_case(false)
+            .execute(dated(august_end.succ_opt().unwrap()), UserId::new())
+            .await
+            .unwrap();
+        assert_eq!(response.stock.quantity, dec!(5));
+
+        let response = use_case(true)
+            .execute(dated(august_end), UserId::new())
+            .await
+            .unwrap();
+        assert_eq!(response.stock.quantity, dec!(10));
+    }
 }
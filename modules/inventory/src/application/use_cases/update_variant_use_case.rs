@@ -124,6 +124,7 @@ where
             product_id: variant.product_id().into_uuid(),
             sku: variant.sku().as_str().to_string(),
             barcode: variant.barcode().map(|b| b.as_str().to_string()),
+            barcode_generated: variant.barcode_generated(),
             name: variant.name().to_string(),
             variant_attributes: variant.variant_attributes().clone(),
             price: variant.price(),
@@ -0,0 +1,231 @@
+// InventoryLot entity - received quantity of a lot/batch at a store
+
+use chrono::{DateTime, NaiveDate, Utc};
+use identity::StoreId;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+use crate::domain::value_objects::{LotId, ProductId, VariantId};
+
+/// InventoryLot entity tracking the units of one lot/batch at a store.
+///
+/// A lot is identified by product, variant, store, lot number and expiry
+/// date; receiving the same lot again adds to it. Stock leaving the store
+/// comes off its lots earliest-expiry first (FEFO), so `remaining_quantity`
+/// is what is left of the lot on the shelf.
+///
+/// Invariants:
+/// - A lot has a lot number, an expiry date or both
+/// - 0 <= remaining_quantity <= received_quantity
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct InventoryLot {
+    id: LotId,
+    product_id: ProductId,
+    variant_id: Option<VariantId>,
+    store_id: StoreId,
+    lot_number: Option<String>,
+    expiry_date: Option<NaiveDate>,
+    received_quantity: Decimal,
+    remaining_quantity: Decimal,
+    received_at: DateTime<Utc>,
+    updated_at: DateTime<Utc>,
+}
+
+impl InventoryLot {
+    /// Records units received for a lot. Returns None when the line has
+    /// neither a lot number nor an expiry date, or nothing was received.
+    pub fn receive(
+        product_id: ProductId,
+        variant_id: Option<VariantId>,
+        store_id: StoreId,
+        lot_number: Option<&str>,
+        expiry_date: Option<NaiveDate>,
+        quantity: Decimal,
+    ) -> Option<Self> {
+        let lot_number = lot_number
+            .map(str::trim)
+            .filter(|s| !s.is_empty())
+            .map(str::to_string);
+        if (lot_number.is_none() && expiry_date.is_none()) || quantity <= Decimal::ZERO {
+            return None;
+        }
+        let now = Utc::now();
+        Some(Self {
+            id: LotId::new(),
+            product_id,
+            variant_id,
+            store_id,
+            lot_number,
+            expiry_date,
+            received_quantity: quantity,
+            remaining_quantity: quantity,
+            received_at: now,
+            updated_at: now,
+        })
+    }
+
+    /// Reconstitutes an InventoryLot from persistence
+    #[allow(clippy::too_many_arguments)]
+    pub fn reconstitute(
+        id: LotId,
+        product_id: ProductId,
+        variant_id: Option<VariantId>,
+        store_id: StoreId,
+        lot_number: Option<String>,
+        expiry_date: Option<NaiveDate>,
+        received_quantity: Decimal,
+        remaining_quantity: Decimal,
+        received_at: DateTime<Utc>,
+        updated_at: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            id,
+            product_id,
+            variant_id,
+            store_id,
+            lot_number,
+            expiry_date,
+            received_quantity,
+            remaining_quantity,
+            received_at,
+            updated_at,
+        }
+    }
+
+    /// Takes up to `quantity` units off the lot and returns how many were
+    /// taken
+    pub fn consume(&mut self, quantity: Decimal) -> Decimal {
+        let taken = self.remaining_quantity.min(quantity.max(Decimal::ZERO));
+        if taken > Decimal::ZERO {
+            self.remaining_quantity -= taken;
+            self.updated_at = Utc::now();
+        }
+        taken
+    }
+
+    /// Returns true if no units of the lot are left
+    pub fn is_depleted(&self) -> bool {
+        self.remaining_quantity <= Decimal::ZERO
+    }
+
+    /// Days from `today` until the lot expires; negative once expired.
+    /// None for lots without an expiry date.
+    pub fn days_until_expiry(&self, today: NaiveDate) -> Option<i64> {
+        self.expiry_date.map(|d| (d - today).num_days())
+    }
+
+    // =========================================================================
+    // Getters
+    // =========================================================================
+
+    pub fn id(&self) -> LotId {
+        self.id
+    }
+
+    pub fn product_id(&self) -> ProductId {
+        self.product_id
+    }
+
+    pub fn variant_id(&self) -> Option<VariantId> {
+        self.variant_id
+    }
+
+    pub fn store_id(&self) -> StoreId {
+        self.store_id
+    }
+
+    pub fn lot_number(&self) -> Option<&str> {
+        self.lot_number.as_deref()
+    }
+
+    pub fn expiry_date(&self) -> Option<NaiveDate> {
+        self.expiry_date
+    }
+
+    pub fn received_quantity(&self) -> Decimal {
+        self.received_quantity
+    }
+
+    pub fn remaining_quantity(&self) -> Decimal {
+        self.remaining_quantity
+    }
+
+    pub fn received_at(&self) -> DateTime<Utc> {
+        self.received_at
+    }
+
+    pub fn updated_at(&self) -> DateTime<Utc> {
+        self.updated_at
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    #[test]
+    fn test_receive_needs_lot_or_expiry() {
+        let product_id = ProductId::new();
+        let store_id = StoreId::new();
+
+        assert!(InventoryLot::receive(product_id, None, store_id, None, None, dec!(5)).is_none());
+        assert!(
+            InventoryLot::receive(product_id, None, store_id, Some("  "), None, dec!(5)).is_none()
+        );
+        assert!(
+            InventoryLot::receive(product_id, None, store_id, Some("L1"), None, dec!(0)).is_none()
+        );
+
+        let lot = InventoryLot::receive(
+            product_id,
+            None,
+            store_id,
+            Some(" L1 "),
+            Some(date(2026, 11, 1)),
+            dec!(5),
+        )
+        .unwrap();
+        assert_eq!(lot.lot_number(), Some("L1"));
+        assert_eq!(lot.remaining_quantity(), dec!(5));
+    }
+
+    #[test]
+    fn test_consume_stops_at_remaining() {
+        let mut lot = InventoryLot::receive(
+            ProductId::new(),
+            None,
+            StoreId::new(),
+            Some("L1"),
+            None,
+            dec!(5),
+        )
+        .unwrap();
+
+        assert_eq!(lot.consume(dec!(3)), dec!(3));
+        assert_eq!(lot.consume(dec!(4)), dec!(2));
+        assert!(lot.is_depleted());
+        assert_eq!(lot.consume(dec!(1)), dec!(0));
+        assert_eq!(lot.received_quantity(), dec!(5));
+    }
+
+    #[test]
+    fn test_days_until_expiry() {
+        let lot = InventoryLot::receive(
+            ProductId::new(),
+            None,
+            StoreId::new(),
+            None,
+            Some(date(2026, 10, 25)),
+            dec!(1),
+        )
+        .unwrap();
+
+        assert_eq!(lot.days_until_expiry(date(2026, 10, 18)), Some(7));
+        assert_eq!(lot.days_until_expiry(date(2026, 10, 27)), Some(-2));
+    }
+}
@@ -0,0 +1,241 @@
+// InventoryPeriod entity - date range of a store whose stock can be closed
+
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::InventoryError;
+use crate::domain::value_objects::{InventoryPeriodId, PeriodStatus};
+use identity::{StoreId, UserId};
+
+/// Inventory period of a store, from `period_start` through `period_end`
+/// (both inclusive).
+///
+/// While the period is closed, stock operations whose document date falls
+/// in it are rejected (see `ensure_period_open`). Reopening the period lets
+/// them through again until it is closed anew. Periods of the same store
+/// never overlap.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct InventoryPeriod {
+    id: InventoryPeriodId,
+    store_id: StoreId,
+    period_start: NaiveDate,
+    period_end: NaiveDate,
+    status: PeriodStatus,
+    closed_by_id: Option<UserId>,
+    closed_at: Option<DateTime<Utc>>,
+    reopened_by_id: Option<UserId>,
+    reopened_at: Option<DateTime<Utc>>,
+    created_at: DateTime<Utc>,
+    updated_at: DateTime<Utc>,
+}
+
+impl InventoryPeriod {
+    /// Creates an open period
+    ///
+    /// # Errors
+    /// * `InventoryError::InvalidPeriodRange` - If `period_start` is after
+    ///   `period_end`
+    pub fn create(
+        store_id: StoreId,
+        period_start: NaiveDate,
+        period_end: NaiveDate,
+    ) -> Result<Self, InventoryError> {
+        if period_start > period_end {
+            return Err(InventoryError::InvalidPeriodRange);
+        }
+        let now = Utc::now();
+        Ok(Self {
+            id: InventoryPeriodId::new(),
+            store_id,
+            period_start,
+            period_end,
+            status: PeriodStatus::Open,
+            closed_by_id: None,
+            closed_at: None,
+            reopened_by_id: None,
+            reopened_at: None,
+            created_at: now,
+            updated_at: now,
+        })
+    }
+
+    /// Reconstitutes an InventoryPeriod from persistence
+    #[allow(clippy::too_many_arguments)]
+    pub fn reconstitute(
+        id: InventoryPeriodId,
+        store_id: StoreId,
+        period_start: NaiveDate,
+        period_end: NaiveDate,
+        status: PeriodStatus,
+        closed_by_id: Option<UserId>,
+        closed_at: Option<DateTime<Utc>>,
+        reopened_by_id: Option<UserId>,
+        reopened_at: Option<DateTime<Utc>>,
+        created_at: DateTime<Utc>,
+        updated_at: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            id,
+            store_id,
+            period_start,
+            period_end,
+            status,
+            closed_by_id,
+            closed_at,
+            reopened_by_id,
+            reopened_at,
+            created_at,
+            updated_at,
+        }
+    }
+
+    /// Closes the period
+    ///
+    /// # Errors
+    /// * `InventoryError::PeriodAlreadyClosed` - If the period is closed
+    pub fn close(&mut self, closed_by_id: UserId) -> Result<(), InventoryError> {
+        if self.status.is_closed() {
+            return Err(InventoryError::PeriodAlreadyClosed(self.id.into_uuid()));
+        }
+        let now = Utc::now();
+        self.status = PeriodStatus::Closed;
+        self.closed_by_id = Some(closed_by_id);
+        self.closed_at = Some(now);
+        self.updated_at = now;
+        Ok(())
+    }
+
+    /// Reopens the period; the last close stays recorded
+    ///
+    /// # Errors
+    /// * `InventoryError::PeriodNotClosed` - If the period is open
+    pub fn reopen(&mut self, reopened_by_id: UserId) -> Result<(), InventoryError> {
+        if !self.status.is_closed() {
+            return Err(InventoryError::PeriodNotClosed(self.id.into_uuid()));
+        }
+        let now = Utc::now();
+        self.status = PeriodStatus::Open;
+        self.reopened_by_id = Some(reopened_by_id);
+        self.reopened_at = Some(now);
+        self.updated_at = now;
+        Ok(())
+    }
+
+    /// Returns true if `date` falls in the period
+    pub fn contains(&self, date: NaiveDate) -> bool {
+        self.period_start <= date && date <= self.period_end
+    }
+
+    /// Returns true if the period shares a day with `start` through `end`
+    pub fn overlaps(&self, start: NaiveDate, end: NaiveDate) -> bool {
+        self.period_start <= end && start <= self.period_end
+    }
+
+    pub fn is_closed(&self) -> bool {
+        self.status.is_closed()
+    }
+
+    // Getters
+
+    pub fn id(&self) -> InventoryPeriodId {
+        self.id
+    }
+
+    pub fn store_id(&self) -> StoreId {
+        self.store_id
+    }
+
+    pub fn period_start(&self) -> NaiveDate {
+        self.period_start
+    }
+
+    pub fn period_end(&self) -> NaiveDate {
+        self.period_end
+    }
+
+    pub fn status(&self) -> PeriodStatus {
+        self.status
+    }
+
+    pub fn closed_by_id(&self) -> Option<UserId> {
+        self.closed_by_id
+    }
+
+    pub fn closed_at(&self) -> Option<DateTime<Utc>> {
+        self.closed_at
+    }
+
+    pub fn reopened_by_id(&self) -> Option<UserId> {
+        self.reopened_by_id
+    }
+
+    pub fn reopened_at(&self) -> Option<DateTime<Utc>> {
+        self.reopened_at
+    }
+
+    pub fn created_at(&self) -> DateTime<Utc> {
+        self.created_at
+    }
+
+    pub fn updated_at(&self) -> DateTime<Utc> {
+        self.updated_at
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2026, month, day).unwrap()
+    }
+
+    fn september() -> InventoryPeriod {
+        InventoryPeriod::create(StoreId::new(), date(9, 1), date(9, 30)).unwrap()
+    }
+
+    #[test]
+    fn test_create_starts_open() {
+        let period = september();
+        assert_eq!(period.status(), PeriodStatus::Open);
+        assert!(period.closed_at().is_none());
+        assert!(matches!(
+            InventoryPeriod::create(StoreId::new(), date(9, 30), date(9, 1)),
+            Err(InventoryError::InvalidPeriodRange)
+        ));
+    }
+
+    #[test]
+    fn test_close_and_reopen() {
+        let mut period = september();
+        let user = UserId::new();
+
+        period.close(user).unwrap();
+        assert!(period.is_closed());
+        assert_eq!(period.closed_by_id(), Some(user));
+        assert!(matches!(
+            period.close(user),
+            Err(InventoryError::PeriodAlreadyClosed(_))
+        ));
+
+        period.reopen(user).unwrap();
+        assert!(!period.is_closed());
+        assert_eq!(period.reopened_by_id(), Some(user));
+        assert!(matches!(
+            period.reopen(user),
+            Err(InventoryError::PeriodNotClosed(_))
+        ));
+    }
+
+    #[test]
+    fn test_contains_and_overlaps_are_inclusive() {
+        let period = september();
+        assert!(period.contains(date(9, 1)));
+        assert!(period.contains(date(9, 30)));
+        assert!(!period.contains(date(10, 1)));
+
+        assert!(period.overlaps(date(9, 30), date(10, 31)));
+        assert!(period.overlaps(date(8, 1), date(9, 1)));
+        assert!(!period.overlaps(date(10, 1), date(10, 31)));
+    }
+}
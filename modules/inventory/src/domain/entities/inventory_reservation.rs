@@ -1,6 +1,6 @@
 // InventoryReservation entity - temporary stock holds for shopping carts
 
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, Duration, Utc};
 use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
@@ -8,6 +8,9 @@ use uuid::Uuid;
 use crate::InventoryError;
 use crate::domain::value_objects::{ReservationId, ReservationStatus, StockId};
 
+/// Longest interval a touch may push a reservation's expiry out by (1 day)
+pub const MAX_AUTO_EXTEND_SECONDS: i64 = 86_400;
+
 /// InventoryReservation entity representing a temporary hold on stock.
 /// Used for shopping carts, orders, and quotes to prevent overselling.
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -18,19 +21,25 @@ pub struct InventoryReservation {
     reference_id: Uuid,
     quantity: Decimal,
     status: ReservationStatus,
+    priority: i32,
     expires_at: DateTime<Utc>,
+    auto_extend_seconds: Option<i64>,
+    bumped_at: Option<DateTime<Utc>>,
+    bump_reason: Option<String>,
     created_at: DateTime<Utc>,
     updated_at: DateTime<Utc>,
 }
 
 impl InventoryReservation {
-    /// Creates a new pending reservation
+    /// Creates a new pending reservation. A higher `priority` may bump
+    /// lower-priority pending holds when stock is short.
     pub fn create(
         stock_id: StockId,
         reference_type: String,
         reference_id: Uuid,
         quantity: Decimal,
         expires_at: DateTime<Utc>,
+        priority: i32,
     ) -> Result<Self, InventoryError> {
         if expires_at <= Utc::now() {
             return Err(InventoryError::ReservationExpired);
@@ -44,7 +53,11 @@ impl InventoryReservation {
             reference_id,
             quantity,
             status: ReservationStatus::Pending,
+            priority,
             expires_at,
+            auto_extend_seconds: None,
+            bumped_at: None,
+            bump_reason: None,
             created_at: now,
             updated_at: now,
         })
@@ -59,7 +72,11 @@ impl InventoryReservation {
         reference_id: Uuid,
         quantity: Decimal,
         status: ReservationStatus,
+        priority: i32,
         expires_at: DateTime<Utc>,
+        auto_extend_seconds: Option<i64>,
+        bumped_at: Option<DateTime<Utc>>,
+        bump_reason: Option<String>,
         created_at: DateTime<Utc>,
         updated_at: DateTime<Utc>,
     ) -> Self {
@@ -70,7 +87,11 @@ impl InventoryReservation {
             reference_id,
             quantity,
             status,
+            priority,
             expires_at,
+            auto_extend_seconds,
+            bumped_at,
+            bump_reason,
             created_at,
             updated_at,
         }
@@ -106,6 +127,59 @@ impl InventoryReservation {
         Ok(())
     }
 
+    /// Expires the reservation early so a higher-priority hold can take its
+    /// stock, recording when and why for the holder to be told
+    pub fn bump(&mut self, reason: String) -> Result<(), InventoryError> {
+        self.expire()?;
+        self.bumped_at = Some(self.updated_at);
+        self.bump_reason = Some(reason);
+        Ok(())
+    }
+
+    /// Pushes out the expiry of a pending reservation whose hold on stock is
+    /// still in place (used when re-reserving within a grace window)
+    pub fn extend(&mut self, expires_at: DateTime<Utc>) -> Result<(), InventoryError> {
+        if self.status != ReservationStatus::Pending {
+            return Err(InventoryError::InvalidReservationStatus);
+        }
+        if expires_at <= Utc::now() {
+            return Err(InventoryError::ReservationExpired);
+        }
+        self.expires_at = expires_at;
+        self.updated_at = Utc::now();
+        Ok(())
+    }
+
+    /// Sets how far each touch pushes the expiry out while the holder is
+    /// active (e.g. a shopper editing their cart); None disables touching
+    pub fn set_auto_extend(&mut self, seconds: Option<i64>) -> Result<(), InventoryError> {
+        if seconds.is_some_and(|s| s <= 0 || s > MAX_AUTO_EXTEND_SECONDS) {
+            return Err(InventoryError::InvalidAutoExtend);
+        }
+        self.auto_extend_seconds = seconds;
+        self.updated_at = Utc::now();
+        Ok(())
+    }
+
+    /// Keeps an active reservation alive: its expiry becomes at least the
+    /// auto-extend interval from now. Never shortens the hold.
+    pub fn touch(&mut self) -> Result<(), InventoryError> {
+        if self.status == ReservationStatus::Expired || self.is_expired() {
+            return Err(InventoryError::ReservationExpired);
+        }
+        if self.status != ReservationStatus::Pending {
+            return Err(InventoryError::InvalidReservationStatus);
+        }
+        let seconds = self
+            .auto_extend_seconds
+            .ok_or(InventoryError::ReservationNotAutoExtending)?;
+
+        let now = Utc::now();
+        self.expires_at = self.expires_at.max(now + Duration::seconds(seconds));
+        self.updated_at = now;
+        Ok(())
+    }
+
     /// Returns true if the reservation has expired (based on current time)
     pub fn is_expired(&self) -> bool {
         self.status == ReservationStatus::Pending && Utc::now() > self.expires_at
@@ -134,9 +208,23 @@ impl InventoryReservation {
     pub fn status(&self) -> ReservationStatus {
         self.status
     }
+    pub fn priority(&self) -> i32 {
+        self.priority
+    }
     pub fn expires_at(&self) -> DateTime<Utc> {
         self.expires_at
     }
+    /// Interval a touch extends the reservation by, if enabled
+    pub fn auto_extend_seconds(&self) -> Option<i64> {
+        self.auto_extend_seconds
+    }
+    /// When the reservation was bumped by a higher-priority one
+    pub fn bumped_at(&self) -> Option<DateTime<Utc>> {
+        self.bumped_at
+    }
+    pub fn bump_reason(&self) -> Option<&str> {
+        self.bump_reason.as_deref()
+    }
     pub fn created_at(&self) -> DateTime<Utc> {
         self.created_at
     }
@@ -172,6 +260,7 @@ mod tests {
             reference_id,
             dec!(5),
             expires_at,
+            0,
         )
         .unwrap();
 
@@ -190,6 +279,7 @@ mod tests {
             new_uuid(),
             dec!(5),
             future_time(),
+            0,
         )
         .unwrap();
 
@@ -206,6 +296,7 @@ mod tests {
             new_uuid(),
             dec!(5),
             future_time(),
+            0,
         )
         .unwrap();
 
@@ -222,10 +313,42 @@ mod tests {
             new_uuid(),
             dec!(5),
             future_time(),
+            0,
         )
         .unwrap();
 
         reservation.expire().unwrap();
         assert_eq!(reservation.status(), ReservationStatus::Expired);
     }
+
+    #[test]
+    fn test_touch_reservation() {
+        let mut reservation = InventoryReservation::create(
+            StockId::new(),
+            "cart".to_string(),
+            new_uuid(),
+            dec!(5),
+            Utc::now() + Duration::minutes(5),
+            0,
+        )
+        .unwrap();
+        assert!(matches!(
+            reservation.touch(),
+            Err(InventoryError::ReservationNotAutoExtending)
+        ));
+        assert!(matches!(
+            reservation.set_auto_extend(Some(0)),
+            Err(InventoryError::InvalidAutoExtend)
+        ));
+
+        reservation.set_auto_extend(Some(900)).unwrap();
+        reservation.touch().unwrap();
+        assert!(reservation.expires_at() > Utc::now() + Duration::minutes(14));
+
+        reservation.confirm().unwrap();
+        assert!(matches!(
+            reservation.touch(),
+            Err(InventoryError::InvalidReservationStatus)
+        ));
+    }
 }
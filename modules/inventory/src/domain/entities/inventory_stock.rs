@@ -26,6 +26,8 @@ pub struct InventoryStock {
     version: i32,
     min_stock_level: Decimal,
     max_stock_level: Option<Decimal>,
+    reorder_point: Option<Decimal>,
+    reorder_quantity: Option<Decimal>,
     created_at: DateTime<Utc>,
     updated_at: DateTime<Utc>,
 }
@@ -47,6 +49,8 @@ impl InventoryStock {
             version: 1,
             min_stock_level: Decimal::ZERO,
             max_stock_level: None,
+            reorder_point: None,
+            reorder_quantity: None,
             created_at: now,
             updated_at: now,
         })
@@ -68,6 +72,8 @@ impl InventoryStock {
             version: 1,
             min_stock_level: Decimal::ZERO,
             max_stock_level: None,
+            reorder_point: None,
+            reorder_quantity: None,
             created_at: now,
             updated_at: now,
         })
@@ -85,6 +91,8 @@ impl InventoryStock {
         version: i32,
         min_stock_level: Decimal,
         max_stock_level: Option<Decimal>,
+        reorder_point: Option<Decimal>,
+        reorder_quantity: Option<Decimal>,
         created_at: DateTime<Utc>,
         updated_at: DateTime<Utc>,
     ) -> Result<Self, InventoryError> {
@@ -101,6 +109,8 @@ impl InventoryStock {
             version,
             min_stock_level,
             max_stock_level,
+            reorder_point,
+            reorder_quantity,
             created_at,
             updated_at,
         })
@@ -122,9 +132,29 @@ impl InventoryStock {
         self.quantity - self.reserved_quantity
     }
 
-    /// Returns true if available quantity is at or below min_stock_level
+    /// Returns the level at which this store should reorder: the store's
+    /// own reorder point, or min_stock_level when none is set
+    pub fn effective_reorder_point(&self) -> Decimal {
+        self.reorder_point.unwrap_or(self.min_stock_level)
+    }
+
+    /// Returns true if available quantity is at or below the reorder point
     pub fn is_low_stock(&self) -> bool {
-        self.available_quantity() <= self.min_stock_level
+        self.available_quantity() <= self.effective_reorder_point()
+    }
+
+    /// Returns how much to order to replenish this stock record.
+    ///
+    /// Uses the store's reorder quantity when set; otherwise refills up to
+    /// max_stock_level, or to twice the reorder point when there is no max.
+    pub fn suggested_reorder_quantity(&self) -> Decimal {
+        if let Some(qty) = self.reorder_quantity {
+            return qty;
+        }
+        let target = self
+            .max_stock_level
+            .unwrap_or(self.effective_reorder_point() * Decimal::TWO);
+        (target - self.available_quantity()).max(Decimal::ZERO)
     }
 
     /// Attempts to reserve quantity, returns error if insufficient available stock
@@ -155,11 +185,21 @@ impl InventoryStock {
 
     /// Adjusts quantity by delta (positive for increase, negative for decrease)
     pub fn adjust_quantity(&mut self, delta: Decimal) -> Result<(), InventoryError> {
+        self.adjust_quantity_with_policy(delta, false)
+    }
+
+    /// Adjusts quantity by delta, letting it go below zero when the store
+    /// allows negative stock. Reserved quantity must still be on hand.
+    pub fn adjust_quantity_with_policy(
+        &mut self,
+        delta: Decimal,
+        allow_negative: bool,
+    ) -> Result<(), InventoryError> {
         let new_qty = self.quantity + delta;
-        if new_qty < Decimal::ZERO {
+        if new_qty < Decimal::ZERO && !allow_negative {
             return Err(InventoryError::NegativeStock);
         }
-        if new_qty < self.reserved_quantity {
+        if new_qty.max(Decimal::ZERO) < self.reserved_quantity {
             return Err(InventoryError::ReservedExceedsQuantity);
         }
         self.quantity = new_qty;
@@ -213,6 +253,14 @@ impl InventoryStock {
         self.max_stock_level
     }
 
+    pub fn reorder_point(&self) -> Option<Decimal> {
+        self.reorder_point
+    }
+
+    pub fn reorder_quantity(&self) -> Option<Decimal> {
+        self.reorder_quantity
+    }
+
     pub fn created_at(&self) -> DateTime<Utc> {
         self.created_at
     }
@@ -234,6 +282,16 @@ impl InventoryStock {
         self.max_stock_level = level;
         self.updated_at = Utc::now();
     }
+
+    pub fn set_reorder_point(&mut self, point: Option<Decimal>) {
+        self.reorder_point = point;
+        self.updated_at = Utc::now();
+    }
+
+    pub fn set_reorder_quantity(&mut self, quantity: Option<Decimal>) {
+        self.reorder_quantity = quantity;
+        self.updated_at = Utc::now();
+    }
 }
 
 #[cfg(test)]
@@ -387,6 +445,26 @@ mod tests {
         assert!(matches!(result, Err(InventoryError::NegativeStock)));
     }
 
+    #[test]
+    fn test_adjust_quantity_allowing_negative() {
+        let store_id = StoreId::new();
+        let product_id = ProductId::new();
+
+        let mut stock = InventoryStock::create_for_product(store_id, product_id).unwrap();
+        stock.adjust_quantity(dec!(50)).unwrap();
+        stock.adjust_quantity_with_policy(dec!(-60), true).unwrap();
+        assert_eq!(stock.quantity(), dec!(-10));
+
+        // Reserved stock can't be sold even when negative stock is allowed
+        stock.adjust_quantity(dec!(20)).unwrap();
+        stock.reserve(dec!(5)).unwrap();
+        let result = stock.adjust_quantity_with_policy(dec!(-20), true);
+        assert!(matches!(
+            result,
+            Err(InventoryError::ReservedExceedsQuantity)
+        ));
+    }
+
     #[test]
     fn test_adjust_quantity_below_reserved() {
         let store_id = StoreId::new();
@@ -436,6 +514,8 @@ mod tests {
             1,
             Decimal::ZERO,
             None,
+            None,
+            None,
             now,
             now,
         );
@@ -461,6 +541,8 @@ mod tests {
             1,
             Decimal::ZERO,
             None,
+            None,
+            None,
             now,
             now,
         );
@@ -484,4 +566,42 @@ mod tests {
         stock.set_max_stock_level(Some(dec!(100)));
         assert_eq!(stock.max_stock_level(), Some(dec!(100)));
     }
+
+    #[test]
+    fn test_reorder_point_overrides_min_stock_level() {
+        let store_id = StoreId::new();
+        let product_id = ProductId::new();
+
+        let mut stock = InventoryStock::create_for_product(store_id, product_id).unwrap();
+        stock.set_min_stock_level(dec!(5));
+        stock.adjust_quantity(dec!(15)).unwrap();
+        assert!(!stock.is_low_stock());
+
+        stock.set_reorder_point(Some(dec!(20)));
+        assert_eq!(stock.effective_reorder_point(), dec!(20));
+        assert!(stock.is_low_stock()); // 15 <= 20
+
+        stock.set_reorder_point(None);
+        assert_eq!(stock.effective_reorder_point(), dec!(5));
+        assert!(!stock.is_low_stock());
+    }
+
+    #[test]
+    fn test_suggested_reorder_quantity() {
+        let store_id = StoreId::new();
+        let product_id = ProductId::new();
+
+        let mut stock = InventoryStock::create_for_product(store_id, product_id).unwrap();
+        stock.set_reorder_point(Some(dec!(10)));
+        stock.adjust_quantity(dec!(4)).unwrap();
+
+        // No max: refill to twice the reorder point
+        assert_eq!(stock.suggested_reorder_quantity(), dec!(16));
+
+        stock.set_max_stock_level(Some(dec!(50)));
+        assert_eq!(stock.suggested_reorder_quantity(), dec!(46));
+
+        stock.set_reorder_quantity(Some(dec!(24)));
+        assert_eq!(stock.suggested_reorder_quantity(), dec!(24));
+    }
 }
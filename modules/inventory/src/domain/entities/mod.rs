@@ -8,12 +8,16 @@
 //! - [`ProductCategory`]: Hierarchical product categorization
 //! - [`Product`]: Main product with SKU, pricing, and attributes
 //! - [`ProductVariant`]: Product variations (size, color, etc.)
+//! - [`ProductPriceChange`]: Price history of a product
+//! - [`ProductVendor`]: Vendors that supply a product, one of them preferred
 //!
 //! ## Stock Management
 //!
 //! - [`InventoryStock`]: Stock levels per store with optimistic locking
 //! - [`InventoryReservation`]: Temporary stock holds for carts/orders
 //! - [`InventoryMovement`]: Kardex entries tracking all stock changes
+//! - [`ProductSerial`]: Serialized units of products tracked for warranty
+//! - [`InventoryLot`]: Lots/batches with expiry dates, consumed earliest-expiry first
 //!
 //! ## Recipe/BOM
 //!
@@ -27,16 +31,29 @@
 //! - [`AdjustmentItem`]: Line items within an adjustment
 //! - [`StockTransfer`]: Inter-store inventory movement
 //! - [`TransferItem`]: Line items within a transfer
+//!
+//! ## Repricing
+//!
+//! - [`RepriceBatch`]: Price changes cascaded from a vendor cost update
+//! - [`RepriceLine`]: Previewed cost and price change of one product
+//!
+//! ## Period Close
+//!
+//! - [`InventoryPeriod`]: Store date range whose stock operations can be closed
 
 // Product catalog
 mod product;
 mod product_category;
+mod product_price_change;
 mod product_variant;
+mod product_vendor;
 
 // Stock management
+mod inventory_lot;
 mod inventory_movement;
 mod inventory_reservation;
 mod inventory_stock;
+mod product_serial;
 
 // Recipe/BOM
 mod ingredient_substitute;
@@ -49,15 +66,26 @@ mod stock_adjustment;
 mod stock_transfer;
 mod transfer_item;
 
+// Repricing
+mod reprice_batch;
+mod valuation_snapshot;
+
+// Period close
+mod inventory_period;
+
 // Re-exports - Product catalog
 pub use product::Product;
 pub use product_category::ProductCategory;
+pub use product_price_change::ProductPriceChange;
 pub use product_variant::ProductVariant;
+pub use product_vendor::ProductVendor;
 
 // Re-exports - Stock management
+pub use inventory_lot::InventoryLot;
 pub use inventory_movement::InventoryMovement;
-pub use inventory_reservation::InventoryReservation;
+pub use inventory_reservation::{InventoryReservation, MAX_AUTO_EXTEND_SECONDS};
 pub use inventory_stock::InventoryStock;
+pub use product_serial::{MAX_SERIAL_NUMBER_LENGTH, ProductSerial};
 
 // Re-exports - Recipe/BOM
 pub use ingredient_substitute::IngredientSubstitute;
@@ -69,3 +97,10 @@ pub use adjustment_item::AdjustmentItem;
 pub use stock_adjustment::StockAdjustment;
 pub use stock_transfer::StockTransfer;
 pub use transfer_item::TransferItem;
+
+// Re-exports - Repricing
+pub use reprice_batch::{RepriceBatch, RepriceLine};
+pub use valuation_snapshot::{ValuationSnapshot, ValuationSnapshotLine};
+
+// Re-exports - Period close
+pub use inventory_period::InventoryPeriod;
@@ -5,7 +5,9 @@ use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 use serde_json::Value as JsonValue;
 
-use crate::domain::value_objects::{Barcode, CategoryId, Currency, ProductId, Sku, UnitOfMeasure};
+use crate::domain::value_objects::{
+    Barcode, CategoryId, Currency, MarkupRule, ProductId, Sku, TaxCategory, UnitOfMeasure,
+};
 
 /// Product entity representing a catalog item that can be sold.
 /// Supports optional variants, inventory tracking, and flexible attributes.
@@ -14,6 +16,7 @@ pub struct Product {
     id: ProductId,
     sku: Sku,
     barcode: Option<Barcode>,
+    barcode_generated: bool,
     name: String,
     description: Option<String>,
     category_id: Option<CategoryId>,
@@ -27,6 +30,9 @@ pub struct Product {
     has_variants: bool,
     tax_rate: Decimal,
     tax_included: bool,
+    tax_category: Option<TaxCategory>,
+    is_serialized: bool,
+    markup_rule: Option<MarkupRule>,
     attributes: JsonValue,
     is_active: bool,
     created_at: DateTime<Utc>,
@@ -45,6 +51,7 @@ impl Product {
             id: ProductId::new(),
             sku: Sku::generate(category_code),
             barcode: None,
+            barcode_generated: false,
             name,
             description: None,
             category_id: None,
@@ -58,6 +65,9 @@ impl Product {
             has_variants: false,
             tax_rate: Decimal::ZERO,
             tax_included: false,
+            tax_category: None,
+            is_serialized: false,
+            markup_rule: None,
             attributes: JsonValue::Object(Default::default()),
             is_active: true,
             created_at: now,
@@ -65,12 +75,21 @@ impl Product {
         }
     }
 
+    /// Creates a new Product with a SKU supplied by the caller, such as one
+    /// read from an imported catalog
+    pub fn create_with_sku(name: String, sku: Sku, unit_of_measure: UnitOfMeasure) -> Self {
+        let mut product = Self::create(name, unit_of_measure, None);
+        product.sku = sku;
+        product
+    }
+
     /// Reconstitutes a Product from persistence
     #[allow(clippy::too_many_arguments)]
     pub fn reconstitute(
         id: ProductId,
         sku: Sku,
         barcode: Option<Barcode>,
+        barcode_generated: bool,
         name: String,
         description: Option<String>,
         category_id: Option<CategoryId>,
@@ -84,6 +103,9 @@ impl Product {
         has_variants: bool,
         tax_rate: Decimal,
         tax_included: bool,
+        tax_category: Option<TaxCategory>,
+        is_serialized: bool,
+        markup_rule: Option<MarkupRule>,
         attributes: JsonValue,
         is_active: bool,
         created_at: DateTime<Utc>,
@@ -93,6 +115,7 @@ impl Product {
             id,
             sku,
             barcode,
+            barcode_generated,
             name,
             description,
             category_id,
@@ -106,6 +129,9 @@ impl Product {
             has_variants,
             tax_rate,
             tax_included,
+            tax_category,
+            is_serialized,
+            markup_rule,
             attributes,
             is_active,
             created_at,
@@ -141,6 +167,11 @@ impl Product {
         self.barcode.as_ref()
     }
 
+    /// Whether the barcode was generated from the category's prefix
+    pub fn barcode_generated(&self) -> bool {
+        self.barcode_generated
+    }
+
     pub fn name(&self) -> &str {
         &self.name
     }
@@ -193,6 +224,21 @@ impl Product {
         self.tax_included
     }
 
+    /// Tax category set on the product itself; None inherits the category's
+    pub fn tax_category(&self) -> Option<TaxCategory> {
+        self.tax_category
+    }
+
+    /// Serialized products record the serial number of every unit sold
+    pub fn is_serialized(&self) -> bool {
+        self.is_serialized
+    }
+
+    /// Rule deriving the price from cost; None means the price is set manually
+    pub fn markup_rule(&self) -> Option<MarkupRule> {
+        self.markup_rule
+    }
+
     pub fn attributes(&self) -> &JsonValue {
         &self.attributes
     }
@@ -215,6 +261,14 @@ impl Product {
 
     pub fn set_barcode(&mut self, barcode: Option<Barcode>) {
         self.barcode = barcode;
+        self.barcode_generated = false;
+        self.updated_at = Utc::now();
+    }
+
+    /// Assigns a barcode generated from the category's prefix
+    pub fn set_generated_barcode(&mut self, barcode: Barcode) {
+        self.barcode = Some(barcode);
+        self.barcode_generated = true;
         self.updated_at = Utc::now();
     }
 
@@ -283,6 +337,21 @@ impl Product {
         self.updated_at = Utc::now();
     }
 
+    pub fn set_tax_category(&mut self, tax_category: Option<TaxCategory>) {
+        self.tax_category = tax_category;
+        self.updated_at = Utc::now();
+    }
+
+    pub fn set_serialized(&mut self, is_serialized: bool) {
+        self.is_serialized = is_serialized;
+        self.updated_at = Utc::now();
+    }
+
+    pub fn set_markup_rule(&mut self, markup_rule: Option<MarkupRule>) {
+        self.markup_rule = markup_rule;
+        self.updated_at = Utc::now();
+    }
+
     pub fn set_attributes(&mut self, attributes: JsonValue) {
         self.attributes = attributes;
         self.updated_at = Utc::now();
@@ -382,9 +451,9 @@ mod tests {
         let mut product = Product::create("Test".to_string(), UnitOfMeasure::Unit, None);
         assert!(product.barcode().is_none());
 
-        let barcode = Barcode::new("1234567890123").unwrap();
+        let barcode = Barcode::new("1234567890128").unwrap();
         product.set_barcode(Some(barcode.clone()));
-        assert_eq!(product.barcode().map(|b| b.as_str()), Some("1234567890123"));
+        assert_eq!(product.barcode().map(|b| b.as_str()), Some("1234567890128"));
     }
 
     #[test]
@@ -3,7 +3,7 @@
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
-use crate::domain::value_objects::CategoryId;
+use crate::domain::value_objects::{CategoryId, CostingMethod, TaxCategory};
 
 /// ProductCategory entity for organizing products into a hierarchical structure.
 /// Supports parent-child relationships for nested categories.
@@ -17,6 +17,8 @@ pub struct ProductCategory {
     icon: Option<String>,
     sort_order: i32,
     is_active: bool,
+    tax_category: Option<TaxCategory>,
+    costing_method: CostingMethod,
     created_at: DateTime<Utc>,
     updated_at: DateTime<Utc>,
 }
@@ -34,6 +36,8 @@ impl ProductCategory {
             icon: None,
             sort_order: 0,
             is_active: true,
+            tax_category: None,
+            costing_method: CostingMethod::default(),
             created_at: now,
             updated_at: now,
         }
@@ -57,6 +61,8 @@ impl ProductCategory {
         icon: Option<String>,
         sort_order: i32,
         is_active: bool,
+        tax_category: Option<TaxCategory>,
+        costing_method: CostingMethod,
         created_at: DateTime<Utc>,
         updated_at: DateTime<Utc>,
     ) -> Self {
@@ -69,6 +75,8 @@ impl ProductCategory {
             icon,
             sort_order,
             is_active,
+            tax_category,
+            costing_method,
             created_at,
             updated_at,
         }
@@ -115,6 +123,17 @@ impl ProductCategory {
         self.is_active
     }
 
+    /// Tax category defined on this category, inherited by the products
+    /// below it that don't define their own
+    pub fn tax_category(&self) -> Option<TaxCategory> {
+        self.tax_category
+    }
+
+    /// How stock of the products in this category is costed
+    pub fn costing_method(&self) -> CostingMethod {
+        self.costing_method
+    }
+
     pub fn created_at(&self) -> DateTime<Utc> {
         self.created_at
     }
@@ -132,6 +151,16 @@ impl ProductCategory {
         self.updated_at = Utc::now();
     }
 
+    pub fn set_tax_category(&mut self, tax_category: Option<TaxCategory>) {
+        self.tax_category = tax_category;
+        self.updated_at = Utc::now();
+    }
+
+    pub fn set_costing_method(&mut self, costing_method: CostingMethod) {
+        self.costing_method = costing_method;
+        self.updated_at = Utc::now();
+    }
+
     pub fn set_name(&mut self, name: String) {
         self.name = name;
         self.updated_at = Utc::now();
@@ -189,6 +218,7 @@ mod tests {
         assert_eq!(category.sort_order(), 0);
         assert!(category.description().is_none());
         assert!(category.icon().is_none());
+        assert_eq!(category.costing_method(), CostingMethod::WeightedAverage);
     }
 
     #[test]
@@ -254,6 +284,8 @@ mod tests {
             Some("icon.svg".to_string()),
             5,
             true,
+            Some(TaxCategory::Isv18),
+            CostingMethod::Fifo,
             now,
             now,
         );
@@ -266,5 +298,6 @@ mod tests {
         assert_eq!(category.icon(), Some("icon.svg"));
         assert_eq!(category.sort_order(), 5);
         assert!(category.is_active());
+        assert_eq!(category.costing_method(), CostingMethod::Fifo);
     }
 }
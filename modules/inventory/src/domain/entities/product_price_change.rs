@@ -0,0 +1,116 @@
+// ProductPriceChange entity - price history record of a product
+
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+use crate::domain::value_objects::{PriceChangeId, ProductId, RepriceBatchId};
+use identity::UserId;
+
+/// Record of a change to a product's cost and selling price.
+///
+/// History records are append-only; `reprice_batch_id` links the records
+/// written when a batch reprice is approved.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProductPriceChange {
+    id: PriceChangeId,
+    product_id: ProductId,
+    old_cost: Decimal,
+    new_cost: Decimal,
+    old_price: Decimal,
+    new_price: Decimal,
+    reprice_batch_id: Option<RepriceBatchId>,
+    changed_by_id: UserId,
+    changed_at: DateTime<Utc>,
+}
+
+impl ProductPriceChange {
+    /// Records a change made now
+    pub fn record(
+        product_id: ProductId,
+        old_cost: Decimal,
+        new_cost: Decimal,
+        old_price: Decimal,
+        new_price: Decimal,
+        reprice_batch_id: Option<RepriceBatchId>,
+        changed_by_id: UserId,
+    ) -> Self {
+        Self {
+            id: PriceChangeId::new(),
+            product_id,
+            old_cost,
+            new_cost,
+            old_price,
+            new_price,
+            reprice_batch_id,
+            changed_by_id,
+            changed_at: Utc::now(),
+        }
+    }
+
+    /// Reconstitutes a ProductPriceChange from persistence
+    #[allow(clippy::too_many_arguments)]
+    pub fn reconstitute(
+        id: PriceChangeId,
+        product_id: ProductId,
+        old_cost: Decimal,
+        new_cost: Decimal,
+        old_price: Decimal,
+        new_price: Decimal,
+        reprice_batch_id: Option<RepriceBatchId>,
+        changed_by_id: UserId,
+        changed_at: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            id,
+            product_id,
+            old_cost,
+            new_cost,
+            old_price,
+            new_price,
+            reprice_batch_id,
+            changed_by_id,
+            changed_at,
+        }
+    }
+
+    // =========================================================================
+    // Getters
+    // =========================================================================
+
+    pub fn id(&self) -> PriceChangeId {
+        self.id
+    }
+
+    pub fn product_id(&self) -> ProductId {
+        self.product_id
+    }
+
+    pub fn old_cost(&self) -> Decimal {
+        self.old_cost
+    }
+
+    pub fn new_cost(&self) -> Decimal {
+        self.new_cost
+    }
+
+    pub fn old_price(&self) -> Decimal {
+        self.old_price
+    }
+
+    pub fn new_price(&self) -> Decimal {
+        self.new_price
+    }
+
+    pub fn reprice_batch_id(&self) -> Option<RepriceBatchId> {
+        self.reprice_batch_id
+    }
+
+    pub fn changed_by_id(&self) -> UserId {
+        self.changed_by_id
+    }
+
+    pub fn changed_at(&self) -> DateTime<Utc> {
+        self.changed_at
+    }
+}
@@ -0,0 +1,286 @@
+// ProductSerial entity - a single serialized unit of a product at a store
+
+use chrono::{DateTime, Utc};
+use identity::StoreId;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::InventoryError;
+use crate::domain::value_objects::{ProductId, SerialId, SerialStatus, VariantId};
+
+/// Maximum length of a serial number
+pub const MAX_SERIAL_NUMBER_LENGTH: usize = 100;
+
+/// ProductSerial entity tracking one physical unit of a serialized product.
+///
+/// A unit is registered as available at a store, moves to sold when a sale
+/// carrying its serial completes, and comes back on a credit note either
+/// restocked (available again) or returned (kept out of stock). The sale and
+/// credit note are stored as plain ids since they live in the sales module.
+///
+/// Invariants:
+/// - The serial number is trimmed, non-blank and unique per product
+/// - Only available units can be sold; only sold units can come back
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProductSerial {
+    id: SerialId,
+    product_id: ProductId,
+    variant_id: Option<VariantId>,
+    store_id: StoreId,
+    serial_number: String,
+    status: SerialStatus,
+    sale_id: Option<Uuid>,
+    sale_item_id: Option<Uuid>,
+    sold_at: Option<DateTime<Utc>>,
+    credit_note_id: Option<Uuid>,
+    created_at: DateTime<Utc>,
+    updated_at: DateTime<Utc>,
+}
+
+impl ProductSerial {
+    /// Registers a new available unit at a store
+    pub fn create(
+        product_id: ProductId,
+        variant_id: Option<VariantId>,
+        store_id: StoreId,
+        serial_number: &str,
+    ) -> Result<Self, InventoryError> {
+        let now = Utc::now();
+        Ok(Self {
+            id: SerialId::new(),
+            product_id,
+            variant_id,
+            store_id,
+            serial_number: Self::normalize(serial_number)?,
+            status: SerialStatus::Available,
+            sale_id: None,
+            sale_item_id: None,
+            sold_at: None,
+            credit_note_id: None,
+            created_at: now,
+            updated_at: now,
+        })
+    }
+
+    /// Reconstitutes a ProductSerial from persistence
+    #[allow(clippy::too_many_arguments)]
+    pub fn reconstitute(
+        id: SerialId,
+        product_id: ProductId,
+        variant_id: Option<VariantId>,
+        store_id: StoreId,
+        serial_number: String,
+        status: SerialStatus,
+        sale_id: Option<Uuid>,
+        sale_item_id: Option<Uuid>,
+        sold_at: Option<DateTime<Utc>>,
+        credit_note_id: Option<Uuid>,
+        created_at: DateTime<Utc>,
+        updated_at: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            id,
+            product_id,
+            variant_id,
+            store_id,
+            serial_number,
+            status,
+            sale_id,
+            sale_item_id,
+            sold_at,
+            credit_note_id,
+            created_at,
+            updated_at,
+        }
+    }
+
+    /// Trims a captured serial number and checks its length
+    pub fn normalize(serial_number: &str) -> Result<String, InventoryError> {
+        let trimmed = serial_number.trim();
+        if trimmed.is_empty() || trimmed.chars().count() > MAX_SERIAL_NUMBER_LENGTH {
+            return Err(InventoryError::InvalidSerialNumber);
+        }
+        Ok(trimmed.to_string())
+    }
+
+    // =========================================================================
+    // Lifecycle
+    // =========================================================================
+
+    /// Marks the unit as sold on a sale line
+    pub fn sell(&mut self, sale_id: Uuid, sale_item_id: Uuid) -> Result<(), InventoryError> {
+        if !self.status.is_available() {
+            return Err(InventoryError::SerialNotAvailable(
+                self.serial_number.clone(),
+            ));
+        }
+        let now = Utc::now();
+        self.status = SerialStatus::Sold;
+        self.sale_id = Some(sale_id);
+        self.sale_item_id = Some(sale_item_id);
+        self.sold_at = Some(now);
+        self.credit_note_id = None;
+        self.updated_at = now;
+        Ok(())
+    }
+
+    /// Takes a sold unit back on a credit note. Restocked units become
+    /// available again and drop their sale; the others are kept out of stock
+    /// and still point at the sale they came from.
+    pub fn take_back(&mut self, credit_note_id: Uuid, restock: bool) -> Result<(), InventoryError> {
+        if self.status != SerialStatus::Sold {
+            return Err(InventoryError::InvalidStatusTransition);
+        }
+        if restock {
+            self.status = SerialStatus::Available;
+            self.sale_id = None;
+            self.sale_item_id = None;
+            self.sold_at = None;
+        } else {
+            self.status = SerialStatus::Returned;
+        }
+        self.credit_note_id = Some(credit_note_id);
+        self.updated_at = Utc::now();
+        Ok(())
+    }
+
+    // =========================================================================
+    // Getters
+    // =========================================================================
+
+    pub fn id(&self) -> SerialId {
+        self.id
+    }
+
+    pub fn product_id(&self) -> ProductId {
+        self.product_id
+    }
+
+    pub fn variant_id(&self) -> Option<VariantId> {
+        self.variant_id
+    }
+
+    pub fn store_id(&self) -> StoreId {
+        self.store_id
+    }
+
+    pub fn serial_number(&self) -> &str {
+        &self.serial_number
+    }
+
+    pub fn status(&self) -> SerialStatus {
+        self.status
+    }
+
+    pub fn sale_id(&self) -> Option<Uuid> {
+        self.sale_id
+    }
+
+    pub fn sale_item_id(&self) -> Option<Uuid> {
+        self.sale_item_id
+    }
+
+    pub fn sold_at(&self) -> Option<DateTime<Utc>> {
+        self.sold_at
+    }
+
+    pub fn credit_note_id(&self) -> Option<Uuid> {
+        self.credit_note_id
+    }
+
+    pub fn created_at(&self) -> DateTime<Utc> {
+        self.created_at
+    }
+
+    pub fn updated_at(&self) -> DateTime<Utc> {
+        self.updated_at
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn serial(number: &str) -> ProductSerial {
+        ProductSerial::create(ProductId::new(), None, StoreId::new(), number).unwrap()
+    }
+
+    #[test]
+    fn test_create_trims_serial_number() {
+        let unit = serial("  SN-001 ");
+        assert_eq!(unit.serial_number(), "SN-001");
+        assert_eq!(unit.status(), SerialStatus::Available);
+    }
+
+    #[test]
+    fn test_create_rejects_blank_or_long_serial() {
+        let product_id = ProductId::new();
+        let store_id = StoreId::new();
+        assert!(matches!(
+            ProductSerial::create(product_id, None, store_id, "   "),
+            Err(InventoryError::InvalidSerialNumber)
+        ));
+        assert!(matches!(
+            ProductSerial::create(product_id, None, store_id, &"X".repeat(101)),
+            Err(InventoryError::InvalidSerialNumber)
+        ));
+    }
+
+    #[test]
+    fn test_sell_records_sale() {
+        let mut unit = serial("SN-001");
+        let sale_id = Uuid::now_v7();
+        let item_id = Uuid::now_v7();
+
+        unit.sell(sale_id, item_id).unwrap();
+
+        assert_eq!(unit.status(), SerialStatus::Sold);
+        assert_eq!(unit.sale_id(), Some(sale_id));
+        assert_eq!(unit.sale_item_id(), Some(item_id));
+        assert!(unit.sold_at().is_some());
+    }
+
+    #[test]
+    fn test_cannot_sell_twice() {
+        let mut unit = serial("SN-001");
+        unit.sell(Uuid::now_v7(), Uuid::now_v7()).unwrap();
+
+        let result = unit.sell(Uuid::now_v7(), Uuid::now_v7());
+        assert!(matches!(result, Err(InventoryError::SerialNotAvailable(_))));
+    }
+
+    #[test]
+    fn test_take_back_with_restock_makes_unit_available() {
+        let mut unit = serial("SN-001");
+        unit.sell(Uuid::now_v7(), Uuid::now_v7()).unwrap();
+        let credit_note_id = Uuid::now_v7();
+
+        unit.take_back(credit_note_id, true).unwrap();
+
+        assert_eq!(unit.status(), SerialStatus::Available);
+        assert_eq!(unit.sale_id(), None);
+        assert_eq!(unit.credit_note_id(), Some(credit_note_id));
+    }
+
+    #[test]
+    fn test_take_back_without_restock_keeps_sale() {
+        let mut unit = serial("SN-001");
+        let sale_id = Uuid::now_v7();
+        unit.sell(sale_id, Uuid::now_v7()).unwrap();
+
+        unit.take_back(Uuid::now_v7(), false).unwrap();
+
+        assert_eq!(unit.status(), SerialStatus::Returned);
+        assert_eq!(unit.sale_id(), Some(sale_id));
+    }
+
+    #[test]
+    fn test_take_back_requires_sold_unit() {
+        let mut unit = serial("SN-001");
+        let result = unit.take_back(Uuid::now_v7(), true);
+        assert!(matches!(
+            result,
+            Err(InventoryError::InvalidStatusTransition)
+        ));
+    }
+}
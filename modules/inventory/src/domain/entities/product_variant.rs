@@ -15,6 +15,7 @@ pub struct ProductVariant {
     product_id: ProductId,
     sku: Sku,
     barcode: Option<Barcode>,
+    barcode_generated: bool,
     name: String,
     variant_attributes: JsonValue,
     price: Option<Decimal>,
@@ -32,12 +33,22 @@ impl ProductVariant {
         variant_index: u32,
         name: String,
     ) -> Self {
+        Self::create_with_sku(
+            product_id,
+            Sku::generate_variant(parent_sku, variant_index),
+            name,
+        )
+    }
+
+    /// Creates a new variant with an already generated SKU
+    pub fn create_with_sku(product_id: ProductId, sku: Sku, name: String) -> Self {
         let now = Utc::now();
         Self {
             id: VariantId::new(),
             product_id,
-            sku: Sku::generate_variant(parent_sku, variant_index),
+            sku,
             barcode: None,
+            barcode_generated: false,
             name,
             variant_attributes: JsonValue::Object(Default::default()),
             price: None,
@@ -55,6 +66,7 @@ impl ProductVariant {
         product_id: ProductId,
         sku: Sku,
         barcode: Option<Barcode>,
+        barcode_generated: bool,
         name: String,
         variant_attributes: JsonValue,
         price: Option<Decimal>,
@@ -68,6 +80,7 @@ impl ProductVariant {
             product_id,
             sku,
             barcode,
+            barcode_generated,
             name,
             variant_attributes,
             price,
@@ -120,6 +133,11 @@ impl ProductVariant {
         self.barcode.as_ref()
     }
 
+    /// Whether the barcode was generated from the category's prefix
+    pub fn barcode_generated(&self) -> bool {
+        self.barcode_generated
+    }
+
     pub fn name(&self) -> &str {
         &self.name
     }
@@ -154,6 +172,14 @@ impl ProductVariant {
 
     pub fn set_barcode(&mut self, barcode: Option<Barcode>) {
         self.barcode = barcode;
+        self.barcode_generated = false;
+        self.updated_at = Utc::now();
+    }
+
+    /// Assigns a barcode generated from the category's prefix
+    pub fn set_generated_barcode(&mut self, barcode: Barcode) {
+        self.barcode = Some(barcode);
+        self.barcode_generated = true;
         self.updated_at = Utc::now();
     }
 
@@ -281,8 +307,8 @@ mod tests {
 
         assert!(variant.barcode().is_none());
 
-        let barcode = Barcode::new("9876543210123").unwrap();
+        let barcode = Barcode::new("9876543210128").unwrap();
         variant.set_barcode(Some(barcode));
-        assert_eq!(variant.barcode().map(|b| b.as_str()), Some("9876543210123"));
+        assert_eq!(variant.barcode().map(|b| b.as_str()), Some("9876543210128"));
     }
 }
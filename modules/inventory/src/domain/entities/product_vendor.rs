@@ -0,0 +1,112 @@
+// ProductVendor entity - a vendor that supplies a product
+
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::domain::value_objects::ProductId;
+
+/// Link between a product and a vendor that supplies it.
+///
+/// A product may have several vendors; exactly one of them is preferred and
+/// is the one automated replenishment orders from. The vendor lives in the
+/// purchasing module, so only its ID is kept here.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProductVendor {
+    product_id: ProductId,
+    vendor_id: Uuid,
+    vendor_sku: Option<String>,
+    last_purchase_price: Option<Decimal>,
+    is_preferred: bool,
+    created_at: DateTime<Utc>,
+    updated_at: DateTime<Utc>,
+}
+
+impl ProductVendor {
+    /// Creates a new, non-preferred link between a product and a vendor
+    pub fn create(product_id: ProductId, vendor_id: Uuid) -> Self {
+        let now = Utc::now();
+        Self {
+            product_id,
+            vendor_id,
+            vendor_sku: None,
+            last_purchase_price: None,
+            is_preferred: false,
+            created_at: now,
+            updated_at: now,
+        }
+    }
+
+    /// Reconstitutes a ProductVendor from persistence
+    pub fn reconstitute(
+        product_id: ProductId,
+        vendor_id: Uuid,
+        vendor_sku: Option<String>,
+        last_purchase_price: Option<Decimal>,
+        is_preferred: bool,
+        created_at: DateTime<Utc>,
+        updated_at: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            product_id,
+            vendor_id,
+            vendor_sku,
+            last_purchase_price,
+            is_preferred,
+            created_at,
+            updated_at,
+        }
+    }
+
+    // =========================================================================
+    // Getters
+    // =========================================================================
+
+    pub fn product_id(&self) -> ProductId {
+        self.product_id
+    }
+
+    pub fn vendor_id(&self) -> Uuid {
+        self.vendor_id
+    }
+
+    pub fn vendor_sku(&self) -> Option<&str> {
+        self.vendor_sku.as_deref()
+    }
+
+    pub fn last_purchase_price(&self) -> Option<Decimal> {
+        self.last_purchase_price
+    }
+
+    pub fn is_preferred(&self) -> bool {
+        self.is_preferred
+    }
+
+    pub fn created_at(&self) -> DateTime<Utc> {
+        self.created_at
+    }
+
+    pub fn updated_at(&self) -> DateTime<Utc> {
+        self.updated_at
+    }
+
+    // =========================================================================
+    // Setters
+    // =========================================================================
+
+    pub fn set_vendor_sku(&mut self, vendor_sku: Option<String>) {
+        self.vendor_sku = vendor_sku;
+        self.updated_at = Utc::now();
+    }
+
+    pub fn set_last_purchase_price(&mut self, price: Option<Decimal>) {
+        self.last_purchase_price = price;
+        self.updated_at = Utc::now();
+    }
+
+    pub fn set_preferred(&mut self, is_preferred: bool) {
+        self.is_preferred = is_preferred;
+        self.updated_at = Utc::now();
+    }
+}
@@ -6,7 +6,7 @@ use serde::{Deserialize, Serialize};
 use serde_json::Value as JsonValue;
 
 use crate::InventoryError;
-use crate::domain::value_objects::{ProductId, RecipeId, VariantId};
+use crate::domain::value_objects::{ProductId, RecipeId, UnitOfMeasure, VariantId};
 
 /// Recipe entity representing a Bill of Materials (BOM) for composite products.
 /// Defines ingredients needed to produce a product or variant.
@@ -15,6 +15,9 @@ use crate::domain::value_objects::{ProductId, RecipeId, VariantId};
 /// - Either product_id OR variant_id must be set, but not both (XOR constraint)
 /// - Only one active recipe per product/variant at any time
 /// - yield_quantity must be positive
+///
+/// A batch yields `yield_quantity` of `yield_unit`, e.g. 12 units of
+/// cookies or 2 liters of sauce.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Recipe {
     id: RecipeId,
@@ -24,6 +27,7 @@ pub struct Recipe {
     description: Option<String>,
     version: i32,
     yield_quantity: Decimal,
+    yield_unit: UnitOfMeasure,
     is_active: bool,
     preparation_time_minutes: Option<i32>,
     calculate_cost_from_ingredients: bool,
@@ -53,6 +57,7 @@ impl Recipe {
             description: None,
             version: 1,
             yield_quantity,
+            yield_unit: UnitOfMeasure::Unit,
             is_active: true,
             preparation_time_minutes: None,
             calculate_cost_from_ingredients: true,
@@ -82,6 +87,7 @@ impl Recipe {
             description: None,
             version: 1,
             yield_quantity,
+            yield_unit: UnitOfMeasure::Unit,
             is_active: true,
             preparation_time_minutes: None,
             calculate_cost_from_ingredients: true,
@@ -102,6 +108,7 @@ impl Recipe {
         description: Option<String>,
         version: i32,
         yield_quantity: Decimal,
+        yield_unit: UnitOfMeasure,
         is_active: bool,
         preparation_time_minutes: Option<i32>,
         calculate_cost_from_ingredients: bool,
@@ -121,6 +128,7 @@ impl Recipe {
             description,
             version,
             yield_quantity,
+            yield_unit,
             is_active,
             preparation_time_minutes,
             calculate_cost_from_ingredients,
@@ -203,6 +211,10 @@ impl Recipe {
         self.yield_quantity
     }
 
+    pub fn yield_unit(&self) -> UnitOfMeasure {
+        self.yield_unit
+    }
+
     pub fn is_active(&self) -> bool {
         self.is_active
     }
@@ -254,6 +266,11 @@ impl Recipe {
         Ok(())
     }
 
+    pub fn set_yield_unit(&mut self, yield_unit: UnitOfMeasure) {
+        self.yield_unit = yield_unit;
+        self.updated_at = Utc::now();
+    }
+
     pub fn set_preparation_time_minutes(&mut self, minutes: Option<i32>) {
         self.preparation_time_minutes = minutes;
         self.updated_at = Utc::now();
@@ -423,6 +440,7 @@ mod tests {
             None,
             1,
             dec!(1),
+            UnitOfMeasure::Unit,
             true,
             None,
             true,
@@ -450,6 +468,7 @@ mod tests {
             None,
             1,
             dec!(1),
+            UnitOfMeasure::Unit,
             true,
             None,
             true,
@@ -478,6 +497,7 @@ mod tests {
             Some("Description".to_string()),
             3,
             dec!(5),
+            UnitOfMeasure::Unit,
             true,
             Some(30),
             true,
@@ -152,7 +152,13 @@ impl RecipeIngredient {
     /// Formula: quantity * (1 + waste_percentage) * cost_per_unit
     pub fn calculate_effective_cost(&self) -> Option<Decimal> {
         self.estimated_cost_per_unit
-            .map(|cost| self.quantity * (Decimal::ONE + self.estimated_waste_percentage) * cost)
+            .map(|cost| self.effective_cost_at(cost))
+    }
+
+    /// Effective cost including waste at the given unit cost, e.g. a current
+    /// weighted-average cost instead of the stored estimate
+    pub fn effective_cost_at(&self, cost_per_unit: Decimal) -> Decimal {
+        self.quantity * (Decimal::ONE + self.estimated_waste_percentage) * cost_per_unit
     }
 
     // =========================================================================
@@ -0,0 +1,301 @@
+// RepriceBatch entity - previewed price changes from a vendor cost update
+
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use super::Product;
+use crate::InventoryError;
+use crate::domain::value_objects::{MarkupRule, ProductId, RepriceBatchId, RepriceBatchStatus};
+use identity::UserId;
+
+/// One product of a batch reprice.
+///
+/// `new_price` is the price the product's markup rule gives for the new
+/// cost. Products priced manually have no rule, so they keep their price
+/// and the line is flagged as excluded.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RepriceLine {
+    product_id: ProductId,
+    product_name: String,
+    markup_rule: Option<MarkupRule>,
+    old_cost: Decimal,
+    new_cost: Decimal,
+    old_price: Decimal,
+    new_price: Option<Decimal>,
+}
+
+impl RepriceLine {
+    /// Previews the cost change for a product
+    pub fn preview(product: &Product, new_cost: Decimal) -> Result<Self, InventoryError> {
+        if new_cost < Decimal::ZERO {
+            return Err(InventoryError::NegativeCost);
+        }
+        let markup_rule = product.markup_rule();
+        Ok(Self {
+            product_id: product.id(),
+            product_name: product.name().to_string(),
+            markup_rule,
+            old_cost: product.cost_price(),
+            new_cost,
+            old_price: product.base_price(),
+            new_price: markup_rule.map(|rule| rule.apply(new_cost)),
+        })
+    }
+
+    /// Reconstitutes a RepriceLine from persistence
+    #[allow(clippy::too_many_arguments)]
+    pub fn reconstitute(
+        product_id: ProductId,
+        product_name: String,
+        markup_rule: Option<MarkupRule>,
+        old_cost: Decimal,
+        new_cost: Decimal,
+        old_price: Decimal,
+        new_price: Option<Decimal>,
+    ) -> Self {
+        Self {
+            product_id,
+            product_name,
+            markup_rule,
+            old_cost,
+            new_cost,
+            old_price,
+            new_price,
+        }
+    }
+
+    /// Manually priced products keep their price when the batch is applied
+    pub fn is_excluded(&self) -> bool {
+        self.new_price.is_none()
+    }
+
+    pub fn product_id(&self) -> ProductId {
+        self.product_id
+    }
+
+    pub fn product_name(&self) -> &str {
+        &self.product_name
+    }
+
+    pub fn markup_rule(&self) -> Option<MarkupRule> {
+        self.markup_rule
+    }
+
+    pub fn old_cost(&self) -> Decimal {
+        self.old_cost
+    }
+
+    pub fn new_cost(&self) -> Decimal {
+        self.new_cost
+    }
+
+    pub fn old_price(&self) -> Decimal {
+        self.old_price
+    }
+
+    pub fn new_price(&self) -> Option<Decimal> {
+        self.new_price
+    }
+}
+
+/// Batch of selling-price changes cascaded from a vendor cost update.
+///
+/// The batch is created as a pending preview. Approving it writes the new
+/// costs and prices to the products; rejecting it discards the preview.
+/// Transitions: pending → applied | rejected
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RepriceBatch {
+    id: RepriceBatchId,
+    vendor_id: Option<Uuid>,
+    reason: Option<String>,
+    status: RepriceBatchStatus,
+    lines: Vec<RepriceLine>,
+    created_by_id: UserId,
+    decided_by_id: Option<UserId>,
+    decided_at: Option<DateTime<Utc>>,
+    created_at: DateTime<Utc>,
+    updated_at: DateTime<Utc>,
+}
+
+impl RepriceBatch {
+    /// Creates a pending batch from previewed lines
+    pub fn create(
+        vendor_id: Option<Uuid>,
+        reason: Option<String>,
+        lines: Vec<RepriceLine>,
+        created_by_id: UserId,
+    ) -> Result<Self, InventoryError> {
+        if lines.is_empty() {
+            return Err(InventoryError::EmptyRepriceBatch);
+        }
+        let now = Utc::now();
+        Ok(Self {
+            id: RepriceBatchId::new(),
+            vendor_id,
+            reason,
+            status: RepriceBatchStatus::Pending,
+            lines,
+            created_by_id,
+            decided_by_id: None,
+            decided_at: None,
+            created_at: now,
+            updated_at: now,
+        })
+    }
+
+    /// Reconstitutes a RepriceBatch from persistence
+    #[allow(clippy::too_many_arguments)]
+    pub fn reconstitute(
+        id: RepriceBatchId,
+        vendor_id: Option<Uuid>,
+        reason: Option<String>,
+        status: RepriceBatchStatus,
+        lines: Vec<RepriceLine>,
+        created_by_id: UserId,
+        decided_by_id: Option<UserId>,
+        decided_at: Option<DateTime<Utc>>,
+        created_at: DateTime<Utc>,
+        updated_at: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            id,
+            vendor_id,
+            reason,
+            status,
+            lines,
+            created_by_id,
+            decided_by_id,
+            decided_at,
+            created_at,
+            updated_at,
+        }
+    }
+
+    /// Marks the batch as applied to the products
+    /// Transitions: pending → applied
+    pub fn mark_applied(&mut self, approver_id: UserId) -> Result<(), InventoryError> {
+        self.decide(RepriceBatchStatus::Applied, approver_id)
+    }
+
+    /// Discards the batch without changing any product
+    /// Transitions: pending → rejected
+    pub fn reject(&mut self, approver_id: UserId) -> Result<(), InventoryError> {
+        self.decide(RepriceBatchStatus::Rejected, approver_id)
+    }
+
+    fn decide(
+        &mut self,
+        status: RepriceBatchStatus,
+        user_id: UserId,
+    ) -> Result<(), InventoryError> {
+        if !self.status.is_pending() {
+            return Err(InventoryError::InvalidStatusTransition);
+        }
+        self.status = status;
+        self.decided_by_id = Some(user_id);
+        self.decided_at = Some(Utc::now());
+        self.updated_at = Utc::now();
+        Ok(())
+    }
+
+    // =========================================================================
+    // Getters
+    // =========================================================================
+
+    pub fn id(&self) -> RepriceBatchId {
+        self.id
+    }
+
+    /// Vendor whose cost update triggered the batch
+    pub fn vendor_id(&self) -> Option<Uuid> {
+        self.vendor_id
+    }
+
+    pub fn reason(&self) -> Option<&str> {
+        self.reason.as_deref()
+    }
+
+    pub fn status(&self) -> RepriceBatchStatus {
+        self.status
+    }
+
+    pub fn lines(&self) -> &[RepriceLine] {
+        &self.lines
+    }
+
+    pub fn created_by_id(&self) -> UserId {
+        self.created_by_id
+    }
+
+    pub fn decided_by_id(&self) -> Option<UserId> {
+        self.decided_by_id
+    }
+
+    pub fn decided_at(&self) -> Option<DateTime<Utc>> {
+        self.decided_at
+    }
+
+    pub fn created_at(&self) -> DateTime<Utc> {
+        self.created_at
+    }
+
+    pub fn updated_at(&self) -> DateTime<Utc> {
+        self.updated_at
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::value_objects::UnitOfMeasure;
+    use rust_decimal_macros::dec;
+
+    fn product(markup_rule: Option<MarkupRule>) -> Product {
+        let mut product = Product::create("Coffee".to_string(), UnitOfMeasure::Unit, None);
+        product.set_cost_price(dec!(5));
+        product.set_base_price(dec!(10));
+        product.set_markup_rule(markup_rule);
+        product
+    }
+
+    #[test]
+    fn test_preview_applies_markup_rule() {
+        let line = RepriceLine::preview(&product(Some(MarkupRule::Keystone)), dec!(6)).unwrap();
+
+        assert_eq!(line.old_cost(), dec!(5));
+        assert_eq!(line.old_price(), dec!(10));
+        assert_eq!(line.new_price(), Some(dec!(12.00)));
+        assert!(!line.is_excluded());
+    }
+
+    #[test]
+    fn test_preview_excludes_manual_price() {
+        let line = RepriceLine::preview(&product(None), dec!(6)).unwrap();
+
+        assert!(line.is_excluded());
+        assert_eq!(line.new_price(), None);
+        assert!(RepriceLine::preview(&product(None), dec!(-1)).is_err());
+    }
+
+    #[test]
+    fn test_status_transitions() {
+        let line = RepriceLine::preview(&product(None), dec!(6)).unwrap();
+        assert!(matches!(
+            RepriceBatch::create(None, None, vec![], UserId::new()),
+            Err(InventoryError::EmptyRepriceBatch)
+        ));
+
+        let mut batch = RepriceBatch::create(None, None, vec![line], UserId::new()).unwrap();
+        assert_eq!(batch.status(), RepriceBatchStatus::Pending);
+
+        batch.mark_applied(UserId::new()).unwrap();
+        assert_eq!(batch.status(), RepriceBatchStatus::Applied);
+        assert!(batch.decided_at().is_some());
+        assert!(matches!(
+            batch.reject(UserId::new()),
+            Err(InventoryError::InvalidStatusTransition)
+        ));
+    }
+}
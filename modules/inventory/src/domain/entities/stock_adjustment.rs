@@ -13,6 +13,7 @@ use identity::{StoreId, UserId};
 
 /// StockAdjustment entity representing a document for correcting inventory.
 /// Implements an approval workflow: draft → pending_approval → approved/rejected → applied
+/// Small negative adjustments may be auto-approved: draft → approved → applied
 ///
 /// Invariants:
 /// - Status transitions must follow the defined workflow
@@ -29,6 +30,7 @@ pub struct StockAdjustment {
     created_by_id: UserId,
     approved_by_id: Option<UserId>,
     approved_at: Option<DateTime<Utc>>,
+    auto_approved: bool,
     applied_at: Option<DateTime<Utc>>,
     notes: Option<String>,
     attachments: JsonValue,
@@ -57,6 +59,7 @@ impl StockAdjustment {
             created_by_id,
             approved_by_id: None,
             approved_at: None,
+            auto_approved: false,
             applied_at: None,
             notes: None,
             attachments: JsonValue::Array(vec![]),
@@ -78,6 +81,7 @@ impl StockAdjustment {
         created_by_id: UserId,
         approved_by_id: Option<UserId>,
         approved_at: Option<DateTime<Utc>>,
+        auto_approved: bool,
         applied_at: Option<DateTime<Utc>>,
         notes: Option<String>,
         attachments: JsonValue,
@@ -95,6 +99,7 @@ impl StockAdjustment {
             created_by_id,
             approved_by_id,
             approved_at,
+            auto_approved,
             applied_at,
             notes,
             attachments,
@@ -131,6 +136,24 @@ impl StockAdjustment {
         Ok(())
     }
 
+    /// Approves the adjustment without review, for adjustments under the
+    /// auto-approval limits. No approver is recorded.
+    /// Transitions: draft → approved
+    pub fn auto_approve(&mut self) -> Result<(), InventoryError> {
+        if self.status != AdjustmentStatus::Draft {
+            return Err(InventoryError::InvalidStatusTransition);
+        }
+        if self.items.is_empty() {
+            return Err(InventoryError::EmptyAdjustment);
+        }
+        let now = Utc::now();
+        self.status = AdjustmentStatus::Approved;
+        self.auto_approved = true;
+        self.approved_at = Some(now);
+        self.updated_at = now;
+        Ok(())
+    }
+
     /// Rejects the adjustment
     /// Transitions: pending_approval → rejected
     pub fn reject(&mut self, approver_id: UserId) -> Result<(), InventoryError> {
@@ -219,6 +242,10 @@ impl StockAdjustment {
         self.approved_at
     }
 
+    pub fn auto_approved(&self) -> bool {
+        self.auto_approved
+    }
+
     pub fn applied_at(&self) -> Option<DateTime<Utc>> {
         self.applied_at
     }
@@ -417,6 +444,24 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn test_auto_approve_skips_submission() {
+        let mut adjustment = create_test_adjustment();
+        assert!(matches!(
+            adjustment.auto_approve(),
+            Err(InventoryError::EmptyAdjustment)
+        ));
+
+        adjustment.add_item(create_test_item()).unwrap();
+        adjustment.auto_approve().unwrap();
+
+        assert_eq!(adjustment.status(), AdjustmentStatus::Approved);
+        assert!(adjustment.auto_approved());
+        assert!(adjustment.approved_by_id().is_none());
+        assert!(adjustment.approved_at().is_some());
+        adjustment.mark_applied().unwrap();
+    }
+
     #[test]
     fn test_reject_success() {
         let mut adjustment = create_test_adjustment();
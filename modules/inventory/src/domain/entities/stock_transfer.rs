@@ -1,6 +1,7 @@
 // StockTransfer entity - document for moving inventory between stores
 
 use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 
 use crate::InventoryError;
@@ -9,7 +10,9 @@ use crate::domain::value_objects::{TransferId, TransferStatus};
 use identity::{StoreId, UserId};
 
 /// StockTransfer entity representing a document for transferring inventory between stores.
-/// Implements a shipping workflow: draft → pending → in_transit → completed
+/// Implements a shipping workflow: draft → pending → in_transit → completed.
+/// Transfers that need approval go draft → pending_approval → approved →
+/// in_transit instead.
 ///
 /// Invariants:
 /// - from_store_id must not equal to_store_id
@@ -31,6 +34,11 @@ pub struct StockTransfer {
     notes: Option<String>,
     shipping_method: Option<String>,
     tracking_number: Option<String>,
+    requires_approval: bool,
+    approval_reason: Option<String>,
+    approved_by_id: Option<UserId>,
+    approved_at: Option<DateTime<Utc>>,
+    approval_notes: Option<String>,
     items: Vec<TransferItem>,
     created_at: DateTime<Utc>,
     updated_at: DateTime<Utc>,
@@ -65,6 +73,11 @@ impl StockTransfer {
             notes: None,
             shipping_method: None,
             tracking_number: None,
+            requires_approval: false,
+            approval_reason: None,
+            approved_by_id: None,
+            approved_at: None,
+            approval_notes: None,
             items: Vec::new(),
             created_at: now,
             updated_at: now,
@@ -88,6 +101,11 @@ impl StockTransfer {
         notes: Option<String>,
         shipping_method: Option<String>,
         tracking_number: Option<String>,
+        requires_approval: bool,
+        approval_reason: Option<String>,
+        approved_by_id: Option<UserId>,
+        approved_at: Option<DateTime<Utc>>,
+        approval_notes: Option<String>,
         items: Vec<TransferItem>,
         created_at: DateTime<Utc>,
         updated_at: DateTime<Utc>,
@@ -107,6 +125,11 @@ impl StockTransfer {
             notes,
             shipping_method,
             tracking_number,
+            requires_approval,
+            approval_reason,
+            approved_by_id,
+            approved_at,
+            approval_notes,
             items,
             created_at,
             updated_at,
@@ -127,10 +150,70 @@ impl StockTransfer {
         Ok(())
     }
 
+    /// Submits a transfer that must be approved before it can ship
+    /// Transitions: draft → pending_approval
+    pub fn submit_for_approval(&mut self, reason: String) -> Result<(), InventoryError> {
+        if self.status != TransferStatus::Draft {
+            return Err(InventoryError::InvalidStatusTransition);
+        }
+        if self.items.is_empty() {
+            return Err(InventoryError::EmptyTransfer);
+        }
+        self.status = TransferStatus::PendingApproval;
+        self.requires_approval = true;
+        self.approval_reason = Some(reason);
+        self.updated_at = Utc::now();
+        Ok(())
+    }
+
+    /// Approves the transfer for shipping
+    /// Transitions: pending_approval → approved
+    pub fn approve(
+        &mut self,
+        approver_id: UserId,
+        notes: Option<String>,
+    ) -> Result<(), InventoryError> {
+        if self.status != TransferStatus::PendingApproval {
+            return Err(InventoryError::InvalidStatusTransition);
+        }
+        self.status = TransferStatus::Approved;
+        self.record_review(approver_id, notes);
+        Ok(())
+    }
+
+    /// Rejects the transfer
+    /// Transitions: pending_approval → cancelled
+    pub fn reject(
+        &mut self,
+        approver_id: UserId,
+        notes: Option<String>,
+    ) -> Result<(), InventoryError> {
+        if self.status != TransferStatus::PendingApproval {
+            return Err(InventoryError::InvalidStatusTransition);
+        }
+        self.status = TransferStatus::Cancelled;
+        self.record_review(approver_id, notes);
+        Ok(())
+    }
+
+    fn record_review(&mut self, approver_id: UserId, notes: Option<String>) {
+        let now = Utc::now();
+        self.approved_by_id = Some(approver_id);
+        self.approved_at = Some(now);
+        self.approval_notes = notes;
+        self.updated_at = now;
+    }
+
     /// Ships the transfer
-    /// Transitions: pending → in_transit
+    /// Transitions: pending → in_transit, or approved → in_transit when
+    /// the transfer required approval
     pub fn ship(&mut self, shipped_by_id: UserId) -> Result<(), InventoryError> {
-        if self.status != TransferStatus::Pending {
+        if self.status == TransferStatus::PendingApproval
+            || (self.requires_approval && self.status == TransferStatus::Pending)
+        {
+            return Err(InventoryError::TransferApprovalRequired);
+        }
+        if !self.status.can_ship() {
             return Err(InventoryError::InvalidStatusTransition);
         }
         self.status = TransferStatus::InTransit;
@@ -140,13 +223,19 @@ impl StockTransfer {
         Ok(())
     }
 
-    /// Receives the transfer at destination
-    /// Transitions: in_transit → completed
+    /// Receives the transfer at destination, once the received quantities
+    /// are recorded on the items
+    /// Transitions: in_transit → completed, or in_transit →
+    /// received_with_discrepancy when any item arrived short
     pub fn receive(&mut self, received_by_id: UserId) -> Result<(), InventoryError> {
         if self.status != TransferStatus::InTransit {
             return Err(InventoryError::InvalidStatusTransition);
         }
-        self.status = TransferStatus::Completed;
+        self.status = if self.has_discrepancy() {
+            TransferStatus::ReceivedWithDiscrepancy
+        } else {
+            TransferStatus::Completed
+        };
         self.received_by_id = Some(received_by_id);
         self.received_date = Some(Utc::now());
         self.updated_at = Utc::now();
@@ -159,9 +248,9 @@ impl StockTransfer {
     }
 
     /// Cancels the transfer
-    /// Transitions: draft/pending → cancelled
+    /// Transitions: draft/pending_approval/approved/pending → cancelled
     pub fn cancel(&mut self) -> Result<(), InventoryError> {
-        if !matches!(self.status, TransferStatus::Draft | TransferStatus::Pending) {
+        if !self.status.can_cancel() {
             return Err(InventoryError::InvalidStatusTransition);
         }
         self.status = TransferStatus::Cancelled;
@@ -186,10 +275,23 @@ impl StockTransfer {
 
     /// Returns true if the transfer is in a final state
     pub fn is_final(&self) -> bool {
-        matches!(
-            self.status,
-            TransferStatus::Completed | TransferStatus::Cancelled
-        )
+        self.status.is_final()
+    }
+
+    /// Returns true if any item was received short of what was shipped
+    pub fn has_discrepancy(&self) -> bool {
+        self.items
+            .iter()
+            .any(|item| item.shortfall() > Decimal::ZERO)
+    }
+
+    /// Value of the requested quantities at their unit cost.
+    /// Items without a unit cost count as zero.
+    pub fn total_value(&self) -> Decimal {
+        self.items
+            .iter()
+            .map(|item| item.quantity_requested() * item.unit_cost().unwrap_or(Decimal::ZERO))
+            .sum()
     }
 
     // =========================================================================
@@ -252,6 +354,26 @@ impl StockTransfer {
         self.tracking_number.as_deref()
     }
 
+    pub fn requires_approval(&self) -> bool {
+        self.requires_approval
+    }
+
+    pub fn approval_reason(&self) -> Option<&str> {
+        self.approval_reason.as_deref()
+    }
+
+    pub fn approved_by_id(&self) -> Option<UserId> {
+        self.approved_by_id
+    }
+
+    pub fn approved_at(&self) -> Option<DateTime<Utc>> {
+        self.approved_at
+    }
+
+    pub fn approval_notes(&self) -> Option<&str> {
+        self.approval_notes.as_deref()
+    }
+
     pub fn items(&self) -> &[TransferItem] {
         &self.items
     }
@@ -442,6 +564,24 @@ mod tests {
         assert!(transfer.is_final());
     }
 
+    #[test]
+    fn test_receive_short_flags_discrepancy() {
+        let mut transfer = create_test_transfer();
+        transfer.add_item(create_test_item()).unwrap();
+        transfer.submit().unwrap();
+        transfer.ship(UserId::new()).unwrap();
+        for item in transfer.items_mut() {
+            item.record_shipped(dec!(10));
+            item.record_received(dec!(7));
+        }
+
+        transfer.receive(UserId::new()).unwrap();
+
+        assert_eq!(transfer.status(), TransferStatus::ReceivedWithDiscrepancy);
+        assert_eq!(transfer.items()[0].shortfall(), dec!(3));
+        assert!(transfer.is_final());
+    }
+
     #[test]
     fn test_receive_wrong_status() {
         let mut transfer = create_test_transfer();
@@ -582,6 +722,76 @@ mod tests {
         assert!(transfer.is_final());
     }
 
+    #[test]
+    fn test_approval_workflow() {
+        let mut transfer = create_test_transfer();
+        transfer.add_item(create_test_item()).unwrap();
+
+        transfer
+            .submit_for_approval("cross-region transfer from north to south".to_string())
+            .unwrap();
+        assert_eq!(transfer.status(), TransferStatus::PendingApproval);
+        assert!(transfer.requires_approval());
+
+        let result = transfer.ship(UserId::new());
+        assert!(matches!(
+            result,
+            Err(InventoryError::TransferApprovalRequired)
+        ));
+
+        let approver = UserId::new();
+        transfer
+            .approve(approver, Some("OK for restock".to_string()))
+            .unwrap();
+        assert_eq!(transfer.status(), TransferStatus::Approved);
+        assert_eq!(transfer.approved_by_id(), Some(approver));
+        assert!(transfer.approved_at().is_some());
+        assert_eq!(transfer.approval_notes(), Some("OK for restock"));
+
+        transfer.ship(UserId::new()).unwrap();
+        assert_eq!(transfer.status(), TransferStatus::InTransit);
+    }
+
+    #[test]
+    fn test_reject_cancels_transfer() {
+        let mut transfer = create_test_transfer();
+        transfer.add_item(create_test_item()).unwrap();
+        transfer
+            .submit_for_approval("value over threshold".to_string())
+            .unwrap();
+
+        transfer
+            .reject(UserId::new(), Some("Not needed".to_string()))
+            .unwrap();
+
+        assert_eq!(transfer.status(), TransferStatus::Cancelled);
+        assert_eq!(transfer.approval_notes(), Some("Not needed"));
+    }
+
+    #[test]
+    fn test_approve_requires_pending_approval() {
+        let mut transfer = create_test_transfer();
+        transfer.add_item(create_test_item()).unwrap();
+        transfer.submit().unwrap();
+
+        let result = transfer.approve(UserId::new(), None);
+
+        assert!(matches!(
+            result,
+            Err(InventoryError::InvalidStatusTransition)
+        ));
+    }
+
+    #[test]
+    fn test_total_value() {
+        let mut transfer = create_test_transfer();
+        transfer.add_item(create_test_item()).unwrap();
+        transfer.add_item(create_test_item()).unwrap();
+
+        // 2 items x 10 units x 5.00
+        assert_eq!(transfer.total_value(), dec!(100));
+    }
+
     #[test]
     fn test_workflow_draft_to_cancelled() {
         let mut transfer = create_test_transfer();
@@ -131,6 +131,13 @@ impl TransferItem {
         }
     }
 
+    /// Quantity shipped but not received, zero when nothing went missing
+    pub fn shortfall(&self) -> Decimal {
+        self.shipping_discrepancy()
+            .unwrap_or(Decimal::ZERO)
+            .max(Decimal::ZERO)
+    }
+
     /// Calculates the total cost of this item based on requested quantity
     pub fn total_requested_cost(&self) -> Option<Decimal> {
         self.unit_cost.map(|cost| cost * self.quantity_requested)
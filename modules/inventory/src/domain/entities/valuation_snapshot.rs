@@ -0,0 +1,115 @@
+// ValuationSnapshot entity - immutable period-end stock valuation of a store
+
+use chrono::{DateTime, NaiveDate, Utc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+use crate::domain::value_objects::{ProductId, StockId, ValuationSnapshotId, VariantId};
+use identity::{StoreId, UserId};
+
+/// Valued stock of one stock record when the snapshot was recorded
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ValuationSnapshotLine {
+    pub stock_id: StockId,
+    pub product_id: Option<ProductId>,
+    pub variant_id: Option<VariantId>,
+    pub product_name: Option<String>,
+    pub variant_name: Option<String>,
+    pub sku: Option<String>,
+    pub quantity: Decimal,
+    /// Costing method the line was valued with ("weighted_average", "fifo", "lifo")
+    pub costing_method: String,
+    pub unit_cost: Decimal,
+    pub total_value: Decimal,
+}
+
+/// Stock valuation of a store frozen at the end of an accounting period.
+///
+/// There is at most one snapshot per store and period end. Once recorded the
+/// snapshot never changes; closing the period itself is left to
+/// `InventoryPeriod`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ValuationSnapshot {
+    id: ValuationSnapshotId,
+    store_id: StoreId,
+    period_end: NaiveDate,
+    currency: String,
+    lines: Vec<ValuationSnapshotLine>,
+    created_by_id: UserId,
+    created_at: DateTime<Utc>,
+}
+
+impl ValuationSnapshot {
+    /// Records a snapshot of the given lines
+    pub fn record(
+        store_id: StoreId,
+        period_end: NaiveDate,
+        currency: String,
+        lines: Vec<ValuationSnapshotLine>,
+        created_by_id: UserId,
+    ) -> Self {
+        Self {
+            id: ValuationSnapshotId::new(),
+            store_id,
+            period_end,
+            currency,
+            lines,
+            created_by_id,
+            created_at: Utc::now(),
+        }
+    }
+
+    /// Reconstitutes a ValuationSnapshot from persistence
+    pub fn reconstitute(
+        id: ValuationSnapshotId,
+        store_id: StoreId,
+        period_end: NaiveDate,
+        currency: String,
+        lines: Vec<ValuationSnapshotLine>,
+        created_by_id: UserId,
+        created_at: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            id,
+            store_id,
+            period_end,
+            currency,
+            lines,
+            created_by_id,
+            created_at,
+        }
+    }
+
+    /// Sum of the lines' values
+    pub fn total_value(&self) -> Decimal {
+        self.lines.iter().map(|line| line.total_value).sum()
+    }
+
+    pub fn id(&self) -> ValuationSnapshotId {
+        self.id
+    }
+
+    pub fn store_id(&self) -> StoreId {
+        self.store_id
+    }
+
+    pub fn period_end(&self) -> NaiveDate {
+        self.period_end
+    }
+
+    pub fn currency(&self) -> &str {
+        &self.currency
+    }
+
+    pub fn lines(&self) -> &[ValuationSnapshotLine] {
+        &self.lines
+    }
+
+    pub fn created_by_id(&self) -> UserId {
+        self.created_by_id
+    }
+
+    pub fn created_at(&self) -> DateTime<Utc> {
+        self.created_at
+    }
+}
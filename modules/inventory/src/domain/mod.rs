@@ -5,9 +5,11 @@
 //! - **Entities**: Domain objects with identity and lifecycle (Product, InventoryStock, Recipe, etc.)
 //! - **Value Objects**: Immutable objects defined by their attributes (ProductId, Sku, Currency, etc.)
 //! - **Repository Traits**: Abstractions for data persistence
+//! - **Services**: Domain logic spanning several types (unit conversion)
 //!
 //! The domain layer has no dependencies on external frameworks or infrastructure concerns.
 
 pub mod entities;
 pub mod repositories;
+pub mod services;
 pub mod value_objects;
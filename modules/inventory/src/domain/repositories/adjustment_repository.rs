@@ -2,6 +2,8 @@
 
 use async_trait::async_trait;
 
+use uuid::Uuid;
+
 use crate::InventoryError;
 use crate::domain::entities::StockAdjustment;
 use crate::domain::value_objects::AdjustmentId;
@@ -42,8 +44,10 @@ pub trait AdjustmentRepository: Send + Sync {
     /// Finds adjustments with pagination and optional filters
     ///
     /// # Arguments
-    /// * `store_id` - Optional filter by store ID
+    /// * `store_ids` - Optional filter by store - the requested store or the
+    ///   caller's assigned stores
     /// * `status` - Optional filter by status (draft, pending_approval, approved, rejected, applied)
+    /// * `after_id` - Keyset cursor: rows with a smaller id, newest id first (`page` is ignored)
     /// * `page` - Page number (1-indexed)
     /// * `page_size` - Number of items per page
     ///
@@ -51,8 +55,9 @@ pub trait AdjustmentRepository: Send + Sync {
     /// Tuple of (adjustments, total_count) for pagination
     async fn find_paginated(
         &self,
-        store_id: Option<StoreId>,
+        store_ids: Option<&[StoreId]>,
         status: Option<&str>,
+        after_id: Option<Uuid>,
         page: i64,
         page_size: i64,
     ) -> Result<(Vec<StockAdjustment>, i64), InventoryError>;
@@ -0,0 +1,33 @@
+// BarcodeSequenceRepository trait - prefixes and sequences for generated barcodes
+
+use async_trait::async_trait;
+
+use crate::InventoryError;
+use crate::domain::value_objects::{Barcode, BarcodePrefix, CategoryId};
+
+/// Repository trait for automatic EAN-13 barcode generation.
+///
+/// Each category may configure a prefix; every prefix has its own sequence,
+/// so categories sharing a prefix share the sequence too.
+#[async_trait]
+pub trait BarcodeSequenceRepository: Send + Sync {
+    /// Finds the prefix configured for a category
+    async fn find_prefix(
+        &self,
+        category_id: CategoryId,
+    ) -> Result<Option<BarcodePrefix>, InventoryError>;
+
+    /// Sets or clears the prefix configured for a category
+    async fn save_prefix(
+        &self,
+        category_id: CategoryId,
+        prefix: Option<&BarcodePrefix>,
+    ) -> Result<(), InventoryError>;
+
+    /// Atomically advances the prefix's sequence and returns the new value.
+    /// Concurrent callers never receive the same value.
+    async fn next_value(&self, prefix: &BarcodePrefix) -> Result<u64, InventoryError>;
+
+    /// Checks whether any product or variant already uses the barcode
+    async fn barcode_exists(&self, barcode: &Barcode) -> Result<bool, InventoryError>;
+}
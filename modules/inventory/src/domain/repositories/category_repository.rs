@@ -1,11 +1,27 @@
 // CategoryRepository trait - repository for product category operations
 
 use async_trait::async_trait;
+use identity::StoreId;
+use rust_decimal::Decimal;
 
 use crate::InventoryError;
 use crate::domain::entities::ProductCategory;
 use crate::domain::value_objects::CategoryId;
 
+/// On-hand stock held directly in one category (subcategories not included).
+///
+/// `category_id` is None for the bucket of products without a category.
+/// `total_value` uses each stock record's weighted average incoming cost.
+#[derive(Debug, Clone)]
+pub struct CategoryStockValuation {
+    pub category_id: Option<CategoryId>,
+    pub parent_id: Option<CategoryId>,
+    pub name: String,
+    pub sort_order: i32,
+    pub quantity: Decimal,
+    pub total_value: Decimal,
+}
+
 /// Repository trait for ProductCategory persistence operations.
 /// Supports hierarchical category management with parent-child relationships.
 #[async_trait]
@@ -37,6 +53,14 @@ pub trait CategoryRepository: Send + Sync {
     /// Updates an existing category
     async fn update(&self, category: &ProductCategory) -> Result<(), InventoryError>;
 
+    /// Returns every category with the stock quantity and valuation of the
+    /// products assigned directly to it, plus one row for uncategorized
+    /// products when there is any such stock.
+    async fn stock_valuation_by_category(
+        &self,
+        store_id: Option<StoreId>,
+    ) -> Result<Vec<CategoryStockValuation>, InventoryError>;
+
     /// Deletes a category by ID
     /// Child categories will have their parent_id set to NULL (orphaned)
     async fn delete(&self, id: CategoryId) -> Result<(), InventoryError>;
@@ -0,0 +1,27 @@
+// InventoryLotRepository trait - repository for lots/batches and their expiry
+
+use async_trait::async_trait;
+use chrono::NaiveDate;
+use identity::StoreId;
+
+use crate::InventoryError;
+use crate::domain::entities::InventoryLot;
+
+/// Repository trait for InventoryLot persistence operations.
+/// Lots are unique per product, variant, store, lot number and expiry date.
+/// Outgoing stock movements consume lots when they are saved (see
+/// `InventoryMovementRepository`).
+#[async_trait]
+pub trait InventoryLotRepository: Send + Sync {
+    /// Adds received units to their lots in one transaction, creating the
+    /// lots that don't exist yet
+    async fn receive_batch(&self, lots: &[InventoryLot]) -> Result<(), InventoryError>;
+
+    /// Finds lots with units left whose expiry date is on or before `until`,
+    /// optionally at one store, earliest expiry first
+    async fn find_expiring(
+        &self,
+        store_id: Option<StoreId>,
+        until: NaiveDate,
+    ) -> Result<Vec<InventoryLot>, InventoryError>;
+}
@@ -7,7 +7,7 @@ use uuid::Uuid;
 
 use crate::InventoryError;
 use crate::domain::entities::InventoryMovement;
-use crate::domain::value_objects::StockId;
+use crate::domain::value_objects::{CostingMethod, MovementId, StockId};
 
 /// Query parameters for listing movements with filters
 #[derive(Debug, Clone, Default)]
@@ -28,6 +28,26 @@ pub struct MovementQuery {
     pub page_size: i64,
 }
 
+/// Outcome of rewriting the stored average costs of a stock record
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AverageCostRewrite {
+    /// Average cost stored on the stock record before the rewrite
+    pub previous_average_cost: Option<Decimal>,
+    /// Movements whose stored average cost actually changed
+    pub movements_rewritten: i64,
+}
+
+/// Open FIFO/LIFO cost layer of a stock record
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CostLayer {
+    /// Receipt movement that opened the layer
+    pub movement_id: MovementId,
+    pub unit_cost: Decimal,
+    /// Units of the receipt not yet consumed by outgoing movements
+    pub remaining_quantity: Decimal,
+    pub received_at: DateTime<Utc>,
+}
+
 /// Repository trait for InventoryMovement (stock history) persistence operations.
 /// Records all stock changes for audit and cost tracking purposes.
 #[async_trait]
@@ -96,4 +116,35 @@ pub trait InventoryMovementRepository: Send + Sync {
 
     /// Saves multiple movements in a single batch insert
     async fn save_batch(&self, movements: &[InventoryMovement]) -> Result<(), InventoryError>;
+
+    /// Finds every movement of a stock record in chronological order (oldest first)
+    async fn find_all_by_stock_id_chronological(
+        &self,
+        stock_id: StockId,
+    ) -> Result<Vec<InventoryMovement>, InventoryError>;
+
+    /// Rewrites the stored running average cost of the given movements and the
+    /// stock record's current average cost in a single transaction.
+    ///
+    /// Fails with `InventoryError::OptimisticLockError` if the stock record is no
+    /// longer at `expected_version`, i.e. movements were posted meanwhile.
+    async fn rewrite_average_costs(
+        &self,
+        stock_id: StockId,
+        expected_version: i32,
+        movement_costs: &[(MovementId, Option<Decimal>)],
+        average_cost: Option<Decimal>,
+    ) -> Result<AverageCostRewrite, InventoryError>;
+
+    /// Returns the costing method of the category the stock's product belongs to.
+    /// Uncategorized products use `CostingMethod::WeightedAverage`.
+    async fn find_costing_method(&self, stock_id: StockId)
+    -> Result<CostingMethod, InventoryError>;
+
+    /// Finds the cost layers of a stock record that still hold units,
+    /// oldest first. Only stock under FIFO/LIFO categories has layers.
+    async fn find_open_cost_layers(
+        &self,
+        stock_id: StockId,
+    ) -> Result<Vec<CostLayer>, InventoryError>;
 }
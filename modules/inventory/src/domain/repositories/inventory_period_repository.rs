@@ -0,0 +1,46 @@
+// InventoryPeriodRepository trait - repository for store inventory periods
+
+use async_trait::async_trait;
+use chrono::NaiveDate;
+
+use crate::InventoryError;
+use crate::domain::entities::InventoryPeriod;
+use crate::domain::value_objects::InventoryPeriodId;
+use identity::StoreId;
+
+/// Repository trait for InventoryPeriod persistence operations
+#[async_trait]
+pub trait InventoryPeriodRepository: Send + Sync {
+    /// Saves a new period
+    async fn save(&self, period: &InventoryPeriod) -> Result<(), InventoryError>;
+
+    /// Updates the status of an existing period
+    async fn update(&self, period: &InventoryPeriod) -> Result<(), InventoryError>;
+
+    /// Finds a period by its ID
+    async fn find_by_id(
+        &self,
+        id: InventoryPeriodId,
+    ) -> Result<Option<InventoryPeriod>, InventoryError>;
+
+    /// Finds the periods of a store sharing a day with `start` through `end`
+    async fn find_overlapping(
+        &self,
+        store_id: StoreId,
+        start: NaiveDate,
+        end: NaiveDate,
+    ) -> Result<Vec<InventoryPeriod>, InventoryError>;
+
+    /// Finds the closed period of a store containing `date`, if any
+    async fn find_closed_containing(
+        &self,
+        store_id: StoreId,
+        date: NaiveDate,
+    ) -> Result<Option<InventoryPeriod>, InventoryError>;
+
+    /// Lists the periods of a store, latest first
+    async fn find_by_store(
+        &self,
+        store_id: StoreId,
+    ) -> Result<Vec<InventoryPeriod>, InventoryError>;
+}
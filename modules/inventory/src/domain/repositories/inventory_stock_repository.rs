@@ -2,6 +2,8 @@
 
 use async_trait::async_trait;
 
+use uuid::Uuid;
+
 use crate::InventoryError;
 use crate::domain::entities::InventoryStock;
 use crate::domain::value_objects::{ProductId, StockId, VariantId};
@@ -46,7 +48,8 @@ pub trait InventoryStockRepository: Send + Sync {
         expected_version: i32,
     ) -> Result<(), InventoryError>;
 
-    /// Finds all stock records with low stock (available_quantity <= min_stock_level)
+    /// Finds all stock records with low stock (available_quantity at or below
+    /// reorder_point, or min_stock_level when the store has none)
     async fn find_low_stock(
         &self,
         store_id: StoreId,
@@ -58,11 +61,15 @@ pub trait InventoryStockRepository: Send + Sync {
 
     /// Finds stock records with pagination and optional filters
     /// Returns (stocks, total_count)
+    /// When `after_id` is set the page is the rows with a smaller id, newest
+    /// id first, and `page` is ignored (keyset pagination). `store_ids`
+    /// restricts the rows to those stores; `None` leaves the store open.
     async fn find_paginated(
         &self,
-        store_id: Option<StoreId>,
+        store_ids: Option<&[StoreId]>,
         product_id: Option<ProductId>,
         low_stock_only: bool,
+        after_id: Option<Uuid>,
         page: i64,
         page_size: i64,
     ) -> Result<(Vec<InventoryStock>, i64), InventoryError>;
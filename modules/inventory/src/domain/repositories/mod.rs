@@ -7,12 +7,20 @@
 //!
 //! - [`CategoryRepository`]: CRUD operations for product categories
 //! - [`ProductRepository`]: Product catalog persistence
+//! - [`BarcodeSequenceRepository`]: Prefixes and sequences for generated barcodes
 //! - [`InventoryStockRepository`]: Stock records with optimistic locking support
 //! - [`InventoryMovementRepository`]: Stock history movement records
 //! - [`ReservationRepository`]: Stock reservation management
+//! - [`ProductSerialRepository`]: Serialized units and their sale
+//! - [`InventoryLotRepository`]: Lots/batches and their expiry dates
 //! - [`RecipeRepository`]: Recipe/BOM persistence
 //! - [`AdjustmentRepository`]: Stock adjustment documents
 //! - [`TransferRepository`]: Inter-store transfer documents
+//! - [`RepriceBatchRepository`]: Batch reprices from vendor cost updates
+//! - [`PriceHistoryRepository`]: Product cost and price history
+//! - [`PriceUpdateRepository`]: Transactional bulk price updates
+//! - [`ValuationSnapshotRepository`]: Period-end stock valuation snapshots
+//! - [`InventoryPeriodRepository`]: Open and closed inventory periods per store
 //!
 //! ## Optimistic Locking
 //!
@@ -20,20 +28,40 @@
 //! `update_with_version` method, which prevents concurrent update conflicts.
 
 mod adjustment_repository;
+mod barcode_sequence_repository;
 mod category_repository;
+mod inventory_lot_repository;
 mod inventory_movement_repository;
+mod inventory_period_repository;
 mod inventory_stock_repository;
+mod price_history_repository;
+mod price_update_repository;
+mod product_import_repository;
 mod product_repository;
+mod product_serial_repository;
 mod recipe_repository;
+mod reprice_batch_repository;
 mod reservation_repository;
 mod transfer_repository;
+mod valuation_snapshot_repository;
 
 // Re-exports
 pub use adjustment_repository::AdjustmentRepository;
-pub use category_repository::CategoryRepository;
-pub use inventory_movement_repository::{InventoryMovementRepository, MovementQuery};
+pub use barcode_sequence_repository::BarcodeSequenceRepository;
+pub use category_repository::{CategoryRepository, CategoryStockValuation};
+pub use inventory_lot_repository::InventoryLotRepository;
+pub use inventory_movement_repository::{
+    AverageCostRewrite, CostLayer, InventoryMovementRepository, MovementQuery,
+};
+pub use inventory_period_repository::InventoryPeriodRepository;
 pub use inventory_stock_repository::InventoryStockRepository;
+pub use price_history_repository::PriceHistoryRepository;
+pub use price_update_repository::{PriceUpdate, PriceUpdateRepository};
+pub use product_import_repository::{ProductImportBatch, ProductImportRepository};
 pub use product_repository::ProductRepository;
-pub use recipe_repository::RecipeRepository;
-pub use reservation_repository::ReservationRepository;
+pub use product_serial_repository::ProductSerialRepository;
+pub use recipe_repository::{CompositeCostSource, ItemUnitCost, RecipeRepository};
+pub use reprice_batch_repository::RepriceBatchRepository;
+pub use reservation_repository::{ReservationRepository, ReservationStatusCount};
 pub use transfer_repository::TransferRepository;
+pub use valuation_snapshot_repository::ValuationSnapshotRepository;
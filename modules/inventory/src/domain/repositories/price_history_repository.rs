@@ -0,0 +1,21 @@
+// PriceHistoryRepository trait - repository for product price history
+
+use async_trait::async_trait;
+
+use crate::InventoryError;
+use crate::domain::entities::ProductPriceChange;
+use crate::domain::value_objects::ProductId;
+
+/// Repository trait for ProductPriceChange persistence operations.
+/// History records are never updated or deleted.
+#[async_trait]
+pub trait PriceHistoryRepository: Send + Sync {
+    /// Saves history records in one transaction
+    async fn save_batch(&self, changes: &[ProductPriceChange]) -> Result<(), InventoryError>;
+
+    /// Lists the price changes of a product, newest first
+    async fn find_by_product(
+        &self,
+        product_id: ProductId,
+    ) -> Result<Vec<ProductPriceChange>, InventoryError>;
+}
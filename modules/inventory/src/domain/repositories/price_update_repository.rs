@@ -0,0 +1,26 @@
+// PriceUpdateRepository trait - atomic write of a bulk price update
+
+use async_trait::async_trait;
+
+use crate::InventoryError;
+use crate::domain::entities::Product;
+use identity::domain::entities::AuditEntry;
+
+/// One product repriced by a bulk update, with its audit trail
+#[derive(Debug, Clone)]
+pub struct PriceUpdate {
+    /// Product with its new prices applied
+    pub product: Product,
+    /// Audit entry recording the old and new product
+    pub audit_entry: AuditEntry,
+}
+
+/// Repository trait for writing bulk price updates.
+///
+/// Every product and its audit entry are written in a single transaction:
+/// either the whole update lands or none of it does.
+#[async_trait]
+pub trait PriceUpdateRepository: Send + Sync {
+    /// Saves the updated products and their audit entries
+    async fn apply(&self, updates: &[PriceUpdate]) -> Result<(), InventoryError>;
+}
@@ -0,0 +1,36 @@
+// ProductImportRepository trait - atomic write of a product catalog import
+
+use async_trait::async_trait;
+
+use crate::InventoryError;
+use crate::domain::entities::{Product, ProductCategory};
+use identity::domain::entities::AuditEntry;
+
+/// Everything a catalog import writes, collected before touching the database
+#[derive(Debug, Clone, Default)]
+pub struct ProductImportBatch {
+    /// Categories created from import paths, parents before children
+    pub categories: Vec<ProductCategory>,
+    /// Products that did not exist yet
+    pub created: Vec<Product>,
+    /// Existing products, matched by SKU, with the imported fields applied
+    pub updated: Vec<Product>,
+    /// One audit entry per created or updated product
+    pub audit_entries: Vec<AuditEntry>,
+}
+
+impl ProductImportBatch {
+    pub fn is_empty(&self) -> bool {
+        self.categories.is_empty() && self.created.is_empty() && self.updated.is_empty()
+    }
+}
+
+/// Repository trait for writing product imports.
+///
+/// The whole batch is written in a single transaction: a database failure
+/// part way through leaves the catalog as it was before the import.
+#[async_trait]
+pub trait ProductImportRepository: Send + Sync {
+    /// Saves the new categories, new and updated products and audit entries
+    async fn apply(&self, batch: &ProductImportBatch) -> Result<(), InventoryError>;
+}
@@ -2,9 +2,13 @@
 
 use async_trait::async_trait;
 
+use uuid::Uuid;
+
 use crate::InventoryError;
-use crate::domain::entities::{Product, ProductVariant};
-use crate::domain::value_objects::{Barcode, CategoryId, ProductId, Sku, VariantId};
+use crate::domain::entities::{Product, ProductVariant, ProductVendor};
+use crate::domain::value_objects::{
+    Barcode, CategoryId, ProductId, Sku, UnitConversion, VariantId,
+};
 
 /// Repository trait for Product persistence operations.
 /// Handles both products and their variants.
@@ -40,11 +44,14 @@ pub trait ProductRepository: Send + Sync {
 
     /// Finds products with pagination and filters
     /// Returns (products, total_count)
+    /// When `after_id` is set the page is the rows with a smaller id, newest
+    /// id first, and `page` is ignored (keyset pagination).
     async fn find_paginated(
         &self,
         category_id: Option<CategoryId>,
         is_active: Option<bool>,
         search: Option<&str>,
+        after_id: Option<Uuid>,
         page: i64,
         page_size: i64,
     ) -> Result<(Vec<Product>, i64), InventoryError>;
@@ -57,6 +64,18 @@ pub trait ProductRepository: Send + Sync {
         search: Option<&str>,
     ) -> Result<i64, InventoryError>;
 
+    /// Searches products by name, SKU, barcode and description, tolerating
+    /// typos, best matches first.
+    /// Returns (products with their relevance score, total_count)
+    async fn search(
+        &self,
+        term: &str,
+        category_id: Option<CategoryId>,
+        active_only: bool,
+        page: i64,
+        page_size: i64,
+    ) -> Result<(Vec<(Product, f64)>, i64), InventoryError>;
+
     // =========================================================================
     // Variant operations
     // =========================================================================
@@ -96,4 +115,42 @@ pub trait ProductRepository: Send + Sync {
 
     /// Counts the number of variants for a product (used for SKU generation)
     async fn count_variants(&self, product_id: ProductId) -> Result<u32, InventoryError>;
+
+    // =========================================================================
+    // Vendor operations
+    // =========================================================================
+
+    /// Inserts or updates product-vendor links in a single transaction.
+    /// Links losing the preferred flag must come before the one gaining it.
+    async fn save_product_vendors(&self, vendors: &[ProductVendor]) -> Result<(), InventoryError>;
+
+    /// Finds the vendors that supply a product, preferred first
+    async fn find_product_vendors(
+        &self,
+        product_id: ProductId,
+    ) -> Result<Vec<ProductVendor>, InventoryError>;
+
+    /// Finds the preferred vendor of a product
+    async fn find_preferred_vendor(
+        &self,
+        product_id: ProductId,
+    ) -> Result<Option<ProductVendor>, InventoryError>;
+
+    // =========================================================================
+    // Unit conversion operations
+    // =========================================================================
+
+    /// Replaces the unit conversions defined for a product in a single
+    /// transaction
+    async fn replace_unit_conversions(
+        &self,
+        product_id: ProductId,
+        conversions: &[UnitConversion],
+    ) -> Result<(), InventoryError>;
+
+    /// Finds the unit conversions defined for a product
+    async fn find_unit_conversions(
+        &self,
+        product_id: ProductId,
+    ) -> Result<Vec<UnitConversion>, InventoryError>;
 }
@@ -0,0 +1,50 @@
+// ProductSerialRepository trait - repository for serialized units
+
+use async_trait::async_trait;
+use identity::StoreId;
+
+use crate::InventoryError;
+use crate::domain::entities::ProductSerial;
+use crate::domain::value_objects::{ProductId, SerialStatus};
+
+/// Repository trait for ProductSerial persistence operations.
+/// Serial numbers are unique per product.
+#[async_trait]
+pub trait ProductSerialRepository: Send + Sync {
+    /// Saves new units in one transaction
+    ///
+    /// # Errors
+    /// - `InventoryError::DuplicateSerial` if a serial is already registered
+    async fn save_batch(&self, serials: &[ProductSerial]) -> Result<(), InventoryError>;
+
+    /// Updates a unit if it is still in `expected_status`
+    ///
+    /// Fails with `InventoryError::SerialNotAvailable` when another process
+    /// changed the unit first (e.g. two tills selling the same serial).
+    async fn update(
+        &self,
+        serial: &ProductSerial,
+        expected_status: SerialStatus,
+    ) -> Result<(), InventoryError>;
+
+    /// Finds a unit of a product by its serial number
+    async fn find_by_product_and_number(
+        &self,
+        product_id: ProductId,
+        serial_number: &str,
+    ) -> Result<Option<ProductSerial>, InventoryError>;
+
+    /// Finds every unit carrying a serial number, across products
+    async fn find_by_serial_number(
+        &self,
+        serial_number: &str,
+    ) -> Result<Vec<ProductSerial>, InventoryError>;
+
+    /// Lists units of a product at a store, optionally by status
+    async fn find_by_product(
+        &self,
+        product_id: ProductId,
+        store_id: StoreId,
+        status: Option<SerialStatus>,
+    ) -> Result<Vec<ProductSerial>, InventoryError>;
+}
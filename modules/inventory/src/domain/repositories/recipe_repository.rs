@@ -1,11 +1,35 @@
 // RecipeRepository trait - repository for recipe/BOM operations
 
 use async_trait::async_trait;
+use rust_decimal::Decimal;
+
+use uuid::Uuid;
 
 use crate::InventoryError;
 use crate::domain::entities::{IngredientSubstitute, Recipe, RecipeIngredient};
 use crate::domain::value_objects::{IngredientId, ProductId, RecipeId, VariantId};
 
+/// An active recipe that derives its cost from ingredients, together with the
+/// current price and stored cost of the composite product/variant it produces.
+#[derive(Debug, Clone)]
+pub struct CompositeCostSource {
+    pub recipe: Recipe,
+    pub ingredients: Vec<RecipeIngredient>,
+    /// Selling price (variant price falls back to the product base price)
+    pub price: Decimal,
+    /// Stored cost_price (variant cost falls back to the product cost)
+    pub cost_price: Decimal,
+}
+
+/// Weighted-average unit cost of a product or variant across all stores,
+/// from incoming movements that carry a unit cost
+#[derive(Debug, Clone)]
+pub struct ItemUnitCost {
+    pub product_id: Option<ProductId>,
+    pub variant_id: Option<VariantId>,
+    pub unit_cost: Decimal,
+}
+
 /// Repository trait for Recipe (BOM) persistence operations.
 /// Handles recipes, ingredients, and substitutes.
 #[async_trait]
@@ -40,10 +64,13 @@ pub trait RecipeRepository: Send + Sync {
 
     /// Finds recipes with pagination and filters
     /// Returns (recipes, total_count)
+    /// When `after_id` is set the page is the rows with a smaller id, newest
+    /// id first, and `page` is ignored (keyset pagination).
     async fn find_paginated(
         &self,
         is_active: Option<bool>,
         search: Option<&str>,
+        after_id: Option<Uuid>,
         page: i64,
         page_size: i64,
     ) -> Result<(Vec<Recipe>, i64), InventoryError>;
@@ -108,4 +135,23 @@ pub trait RecipeRepository: Send + Sync {
         &self,
         id: crate::domain::value_objects::SubstituteId,
     ) -> Result<(), InventoryError>;
+
+    // =========================================================================
+    // Cost rollup operations
+    // =========================================================================
+
+    /// Finds all active recipes with calculate_cost_from_ingredients set,
+    /// with their ingredients and the composite's price and stored cost
+    async fn find_composite_cost_sources(&self)
+    -> Result<Vec<CompositeCostSource>, InventoryError>;
+
+    /// Current weighted-average unit costs of every stocked item
+    async fn find_weighted_average_costs(&self) -> Result<Vec<ItemUnitCost>, InventoryError>;
+
+    /// Sets cost_price on the product or variant the recipe produces
+    async fn update_composite_cost_price(
+        &self,
+        recipe: &Recipe,
+        cost_price: Decimal,
+    ) -> Result<(), InventoryError>;
 }
@@ -0,0 +1,27 @@
+// RepriceBatchRepository trait - repository for batch reprices
+
+use async_trait::async_trait;
+
+use crate::InventoryError;
+use crate::domain::entities::RepriceBatch;
+use crate::domain::value_objects::{RepriceBatchId, RepriceBatchStatus};
+
+/// Repository trait for RepriceBatch persistence operations.
+/// Batches are saved together with their lines.
+#[async_trait]
+pub trait RepriceBatchRepository: Send + Sync {
+    /// Saves a new batch and its lines
+    async fn save(&self, batch: &RepriceBatch) -> Result<(), InventoryError>;
+
+    /// Updates the batch status and decision
+    async fn update(&self, batch: &RepriceBatch) -> Result<(), InventoryError>;
+
+    /// Finds a batch by ID, with its lines
+    async fn find_by_id(&self, id: RepriceBatchId) -> Result<Option<RepriceBatch>, InventoryError>;
+
+    /// Lists batches, newest first, optionally by status
+    async fn find_all(
+        &self,
+        status: Option<RepriceBatchStatus>,
+    ) -> Result<Vec<RepriceBatch>, InventoryError>;
+}
@@ -1,11 +1,26 @@
 // ReservationRepository trait - repository for inventory reservation operations
 
 use async_trait::async_trait;
+use chrono::{DateTime, NaiveDate, Utc};
+use identity::StoreId;
+use rust_decimal::Decimal;
 use uuid::Uuid;
 
 use crate::InventoryError;
 use crate::domain::entities::InventoryReservation;
-use crate::domain::value_objects::{ReservationId, StockId};
+use crate::domain::value_objects::{ReservationId, ReservationStatus, StockId};
+
+/// Count of reservations created in a period that are now in a given status.
+///
+/// `day` is the UTC creation date when the counts are bucketed by day.
+#[derive(Debug, Clone)]
+pub struct ReservationStatusCount {
+    pub day: Option<NaiveDate>,
+    pub reference_type: String,
+    pub status: ReservationStatus,
+    pub reservations: i64,
+    pub quantity: Decimal,
+}
 
 /// Repository trait for InventoryReservation persistence operations.
 /// Handles temporary stock holds for shopping carts, orders, and quotes.
@@ -46,20 +61,37 @@ pub trait ReservationRepository: Send + Sync {
     /// Finds reservations with pagination and optional filters
     ///
     /// # Arguments
+    /// * `store_ids` - Restricts to reservations of stock at these stores;
+    ///   `None` leaves the store open
     /// * `stock_id` - Optional filter by stock ID
     /// * `status` - Optional filter by status (pending, confirmed, cancelled, expired)
     /// * `reference_type` - Optional filter by reference type (cart, order, quote)
+    /// * `after_id` - Keyset cursor: rows with a smaller id, newest id first (`page` is ignored)
     /// * `page` - Page number (1-indexed)
     /// * `page_size` - Number of items per page
     ///
     /// # Returns
     /// Tuple of (reservations, total_count) for pagination
+    #[allow(clippy::too_many_arguments)]
     async fn find_paginated(
         &self,
+        store_ids: Option<&[StoreId]>,
         stock_id: Option<StockId>,
         status: Option<&str>,
         reference_type: Option<&str>,
+        after_id: Option<Uuid>,
         page: i64,
         page_size: i64,
     ) -> Result<(Vec<InventoryReservation>, i64), InventoryError>;
+
+    /// Counts the reservations of a store created within `[from, to]`,
+    /// grouped by reference type and current status, and by creation day
+    /// when `by_day` is set
+    async fn count_by_status(
+        &self,
+        store_id: StoreId,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+        by_day: bool,
+    ) -> Result<Vec<ReservationStatusCount>, InventoryError>;
 }
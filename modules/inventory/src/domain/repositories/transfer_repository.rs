@@ -39,6 +39,9 @@ pub trait TransferRepository: Send + Sync {
         store_id: StoreId,
     ) -> Result<Vec<StockTransfer>, InventoryError>;
 
+    /// Finds all shipped transfers not yet received, with items loaded
+    async fn find_in_transit(&self) -> Result<Vec<StockTransfer>, InventoryError>;
+
     /// Updates an existing transfer
     async fn update(&self, transfer: &StockTransfer) -> Result<(), InventoryError>;
 
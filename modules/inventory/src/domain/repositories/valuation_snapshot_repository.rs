@@ -0,0 +1,24 @@
+// ValuationSnapshotRepository trait - repository for period-end valuation snapshots
+
+use async_trait::async_trait;
+use chrono::NaiveDate;
+
+use crate::InventoryError;
+use crate::domain::entities::ValuationSnapshot;
+use identity::StoreId;
+
+/// Repository trait for ValuationSnapshot persistence operations.
+/// Snapshots are saved together with their lines and never updated.
+#[async_trait]
+pub trait ValuationSnapshotRepository: Send + Sync {
+    /// Saves a new snapshot and its lines. Returns false, saving nothing, if
+    /// the store already has a snapshot for the same period end.
+    async fn save(&self, snapshot: &ValuationSnapshot) -> Result<bool, InventoryError>;
+
+    /// Finds the snapshot of a store for a period end, with its lines
+    async fn find_by_store_and_period(
+        &self,
+        store_id: StoreId,
+        period_end: NaiveDate,
+    ) -> Result<Option<ValuationSnapshot>, InventoryError>;
+}
@@ -0,0 +1,9 @@
+//! Domain services for inventory management.
+//!
+//! Stateless logic that doesn't belong to a single entity or value object:
+//!
+//! - [`UnitConverter`]: Converts quantities between units of measure
+
+mod unit_converter;
+
+pub use unit_converter::UnitConverter;
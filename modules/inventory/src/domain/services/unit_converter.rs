@@ -0,0 +1,147 @@
+// UnitConverter domain service - converts quantities between units of measure
+
+use rust_decimal::Decimal;
+
+use crate::InventoryError;
+use crate::domain::value_objects::{UnitConversion, UnitOfMeasure};
+
+/// Converts quantities between units of measure.
+///
+/// Units sharing a base unit (kg, g, lb and oz; liter and ml) convert with
+/// their standard factors. Anything else, such as cases to units or a bag of
+/// flour to kilograms, needs a [`UnitConversion`], usually one defined for
+/// the product. A conversion also works in reverse and chains with the
+/// standard factors on either side, so `1 case = 24 kg` lets cases convert
+/// to grams. Units with no path between them, like a weight and a volume,
+/// fail with `IncompatibleUnits`.
+#[derive(Debug, Clone, Default)]
+pub struct UnitConverter {
+    conversions: Vec<UnitConversion>,
+}
+
+impl UnitConverter {
+    /// Converter that knows only the standard factors
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds conversions, e.g. the ones defined for a product
+    pub fn with_conversions(
+        mut self,
+        conversions: impl IntoIterator<Item = UnitConversion>,
+    ) -> Self {
+        self.conversions.extend(conversions);
+        self
+    }
+
+    pub fn conversions(&self) -> &[UnitConversion] {
+        &self.conversions
+    }
+
+    /// Converts `quantity` from `from` to `to`
+    ///
+    /// # Errors
+    /// * `InventoryError::IncompatibleUnits` - If no standard factor or
+    ///   conversion links the two units
+    pub fn convert(
+        &self,
+        quantity: Decimal,
+        from: UnitOfMeasure,
+        to: UnitOfMeasure,
+    ) -> Result<Decimal, InventoryError> {
+        if from.base_unit() == to.base_unit() {
+            return from.convert(quantity, to);
+        }
+
+        let conversion = self
+            .conversions
+            .iter()
+            .flat_map(|c| [*c, c.inverse()])
+            .find(|c| {
+                c.from_unit().base_unit() == from.base_unit()
+                    && c.to_unit().base_unit() == to.base_unit()
+            });
+        match conversion {
+            Some(c) => {
+                let in_from_unit = from.convert(quantity, c.from_unit())?;
+                c.to_unit().convert(c.apply(in_from_unit), to)
+            }
+            None => Err(InventoryError::IncompatibleUnits {
+                from: from.to_string(),
+                to: to.to_string(),
+            }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    fn case_of(factor: Decimal, unit: UnitOfMeasure) -> UnitConversion {
+        UnitConversion::new(UnitOfMeasure::Case, unit, factor).unwrap()
+    }
+
+    #[test]
+    fn test_standard_factors() {
+        let converter = UnitConverter::new();
+        assert_eq!(
+            converter
+                .convert(dec!(1.5), UnitOfMeasure::Kg, UnitOfMeasure::G)
+                .unwrap(),
+            dec!(1500)
+        );
+        assert_eq!(
+            converter
+                .convert(dec!(3), UnitOfMeasure::Unit, UnitOfMeasure::Unit)
+                .unwrap(),
+            dec!(3)
+        );
+    }
+
+    #[test]
+    fn test_conversion_both_ways() {
+        let converter =
+            UnitConverter::new().with_conversions([case_of(dec!(24), UnitOfMeasure::Unit)]);
+        assert_eq!(
+            converter
+                .convert(dec!(2), UnitOfMeasure::Case, UnitOfMeasure::Unit)
+                .unwrap(),
+            dec!(48)
+        );
+        assert_eq!(
+            converter
+                .convert(dec!(12), UnitOfMeasure::Unit, UnitOfMeasure::Case)
+                .unwrap()
+                .round_dp(6),
+            dec!(0.5)
+        );
+    }
+
+    #[test]
+    fn test_conversion_chains_with_standard_factors() {
+        let converter =
+            UnitConverter::new().with_conversions([case_of(dec!(10), UnitOfMeasure::Kg)]);
+        assert_eq!(
+            converter
+                .convert(dec!(1), UnitOfMeasure::Case, UnitOfMeasure::G)
+                .unwrap(),
+            dec!(10000)
+        );
+    }
+
+    #[test]
+    fn test_incompatible_units_fail() {
+        let converter =
+            UnitConverter::new().with_conversions([case_of(dec!(24), UnitOfMeasure::Unit)]);
+        assert!(matches!(
+            converter.convert(dec!(1), UnitOfMeasure::Kg, UnitOfMeasure::Liter),
+            Err(InventoryError::IncompatibleUnits { .. })
+        ));
+        assert!(matches!(
+            converter.convert(dec!(1), UnitOfMeasure::Case, UnitOfMeasure::Kg),
+            Err(InventoryError::IncompatibleUnits { .. })
+        ));
+    }
+}
@@ -0,0 +1,300 @@
+// AdjustmentApprovalPolicy value object - decides which negative stock
+// adjustments are approved automatically when they are created
+
+use std::collections::{HashMap, HashSet};
+
+use rust_decimal::Decimal;
+
+use crate::domain::entities::StockAdjustment;
+use crate::domain::value_objects::AdjustmentReason;
+use identity::StoreId;
+
+/// Size limit under which a negative adjustment needs no approval.
+///
+/// Value is Σ |quantity| × unit cost and quantity is Σ |quantity| over the
+/// adjustment's items; an adjustment at the limit is still within it. When a
+/// value limit is set, items without a unit cost can't be valued and the
+/// adjustment goes through approval.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct AdjustmentApprovalLimit {
+    pub max_value: Option<Decimal>,
+    pub max_quantity: Option<Decimal>,
+}
+
+impl AdjustmentApprovalLimit {
+    /// Returns true if `adjustment` is within both limits. A limit without
+    /// any maximum allows nothing.
+    pub fn allows(&self, adjustment: &StockAdjustment) -> bool {
+        if self.max_value.is_none() && self.max_quantity.is_none() {
+            return false;
+        }
+
+        if let Some(max_quantity) = self.max_quantity {
+            let quantity: Decimal = adjustment
+                .items()
+                .iter()
+                .map(|item| item.quantity().abs())
+                .sum();
+            if quantity > max_quantity {
+                return false;
+            }
+        }
+
+        if let Some(max_value) = self.max_value {
+            let value: Option<Decimal> = adjustment
+                .items()
+                .iter()
+                .map(|item| item.total_cost())
+                .sum();
+            match value {
+                Some(value) if value <= max_value => {}
+                _ => return false,
+            }
+        }
+
+        true
+    }
+}
+
+/// Auto-approval rules for negative stock adjustments.
+///
+/// A store's limit replaces the default limit; a reason's limit applies on
+/// top of it, so the adjustment must be within both. Reasons in the
+/// always-approve set (theft, plus any configured) never skip approval.
+/// Increases and adjustments that mix increases with decreases always go
+/// through approval. The default policy has no limits, so every adjustment
+/// needs approval.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AdjustmentApprovalPolicy {
+    default_limit: Option<AdjustmentApprovalLimit>,
+    store_limits: HashMap<StoreId, AdjustmentApprovalLimit>,
+    reason_limits: HashMap<AdjustmentReason, AdjustmentApprovalLimit>,
+    always_approve: HashSet<AdjustmentReason>,
+}
+
+impl AdjustmentApprovalPolicy {
+    /// Policy that never auto-approves
+    pub fn disabled() -> Self {
+        Self::default()
+    }
+
+    /// Auto-approves negative adjustments within `limit` in every store
+    /// without its own limit
+    pub fn with_default_limit(mut self, limit: AdjustmentApprovalLimit) -> Self {
+        self.default_limit = Some(limit);
+        self
+    }
+
+    /// Replaces the default limit for `store_id`
+    pub fn with_store_limit(mut self, store_id: StoreId, limit: AdjustmentApprovalLimit) -> Self {
+        self.store_limits.insert(store_id, limit);
+        self
+    }
+
+    /// Adds a limit for adjustments with `reason`
+    pub fn with_reason_limit(
+        mut self,
+        reason: AdjustmentReason,
+        limit: AdjustmentApprovalLimit,
+    ) -> Self {
+        self.reason_limits.insert(reason, limit);
+        self
+    }
+
+    /// Makes adjustments with `reason` always need approval
+    pub fn with_always_approve(mut self, reason: AdjustmentReason) -> Self {
+        self.always_approve.insert(reason);
+        self
+    }
+
+    pub fn always_requires_approval(&self, reason: AdjustmentReason) -> bool {
+        self.always_approve.contains(&reason)
+    }
+
+    /// Returns true if `adjustment` can skip the submit and approve steps
+    pub fn can_auto_approve(&self, adjustment: &StockAdjustment) -> bool {
+        if self.always_requires_approval(adjustment.adjustment_reason()) {
+            return false;
+        }
+        let is_negative = !adjustment.items().is_empty()
+            && adjustment
+                .items()
+                .iter()
+                .all(|item| item.quantity() < Decimal::ZERO);
+        if !is_negative {
+            return false;
+        }
+
+        let base = self
+            .store_limits
+            .get(&adjustment.store_id())
+            .or(self.default_limit.as_ref());
+        let reason = self.reason_limits.get(&adjustment.adjustment_reason());
+        let limits: Vec<&AdjustmentApprovalLimit> = base.into_iter().chain(reason).collect();
+
+        !limits.is_empty() && limits.iter().all(|limit| limit.allows(adjustment))
+    }
+}
+
+impl Default for AdjustmentApprovalPolicy {
+    fn default() -> Self {
+        Self {
+            default_limit: None,
+            store_limits: HashMap::new(),
+            reason_limits: HashMap::new(),
+            always_approve: HashSet::from([AdjustmentReason::Theft]),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::entities::AdjustmentItem;
+    use crate::domain::value_objects::{AdjustmentType, StockId};
+    use identity::UserId;
+    use rust_decimal_macros::dec;
+
+    fn adjustment(
+        store_id: StoreId,
+        reason: AdjustmentReason,
+        lines: &[(Decimal, Option<Decimal>)],
+    ) -> StockAdjustment {
+        let mut adjustment = StockAdjustment::create(
+            store_id,
+            "ADJ-001".to_string(),
+            AdjustmentType::Decrease,
+            reason,
+            UserId::new(),
+        );
+        for (quantity, unit_cost) in lines {
+            let item =
+                AdjustmentItem::create(adjustment.id(), StockId::new(), *quantity, *unit_cost);
+            adjustment.add_item(item).unwrap();
+        }
+        adjustment
+    }
+
+    fn value_limit(max_value: Decimal) -> AdjustmentApprovalLimit {
+        AdjustmentApprovalLimit {
+            max_value: Some(max_value),
+            max_quantity: None,
+        }
+    }
+
+    #[test]
+    fn test_disabled_policy_never_auto_approves() {
+        let a = adjustment(
+            StoreId::new(),
+            AdjustmentReason::Damage,
+            &[(dec!(-1), Some(dec!(1)))],
+        );
+        assert!(!AdjustmentApprovalPolicy::disabled().can_auto_approve(&a));
+    }
+
+    #[test]
+    fn test_value_and_quantity_threshold_boundary() {
+        let policy =
+            AdjustmentApprovalPolicy::disabled().with_default_limit(AdjustmentApprovalLimit {
+                max_value: Some(dec!(500)),
+                max_quantity: Some(dec!(10)),
+            });
+        let store = StoreId::new();
+
+        let at_limit = adjustment(
+            store,
+            AdjustmentReason::Damage,
+            &[(dec!(-6), Some(dec!(50))), (dec!(-4), Some(dec!(50)))],
+        );
+        assert!(policy.can_auto_approve(&at_limit));
+
+        let over_value = adjustment(
+            store,
+            AdjustmentReason::Damage,
+            &[(dec!(-10), Some(dec!(50.01)))],
+        );
+        assert!(!policy.can_auto_approve(&over_value));
+
+        let over_quantity = adjustment(
+            store,
+            AdjustmentReason::Damage,
+            &[(dec!(-11), Some(dec!(1)))],
+        );
+        assert!(!policy.can_auto_approve(&over_quantity));
+
+        let unvalued = adjustment(store, AdjustmentReason::Damage, &[(dec!(-1), None)]);
+        assert!(!policy.can_auto_approve(&unvalued));
+    }
+
+    #[test]
+    fn test_only_negative_adjustments_are_auto_approved() {
+        let policy =
+            AdjustmentApprovalPolicy::disabled().with_default_limit(value_limit(dec!(500)));
+
+        let found = adjustment(
+            StoreId::new(),
+            AdjustmentReason::Found,
+            &[(dec!(1), Some(dec!(1)))],
+        );
+        assert!(!policy.can_auto_approve(&found));
+
+        let mixed = adjustment(
+            StoreId::new(),
+            AdjustmentReason::Correction,
+            &[(dec!(-1), Some(dec!(1))), (dec!(1), Some(dec!(1)))],
+        );
+        assert!(!policy.can_auto_approve(&mixed));
+    }
+
+    #[test]
+    fn test_always_approve_reasons_ignore_size() {
+        let policy = AdjustmentApprovalPolicy::disabled()
+            .with_default_limit(value_limit(dec!(1000)))
+            .with_always_approve(AdjustmentReason::Loss);
+
+        for reason in [AdjustmentReason::Theft, AdjustmentReason::Loss] {
+            let small = adjustment(StoreId::new(), reason, &[(dec!(-1), Some(dec!(1)))]);
+            assert!(!policy.can_auto_approve(&small));
+        }
+    }
+
+    #[test]
+    fn test_store_limit_replaces_default_and_reason_limit_applies_on_top() {
+        let (strict_store, other_store) = (StoreId::new(), StoreId::new());
+        let policy = AdjustmentApprovalPolicy::disabled()
+            .with_default_limit(value_limit(dec!(500)))
+            .with_store_limit(strict_store, value_limit(dec!(100)))
+            .with_reason_limit(AdjustmentReason::Expiration, value_limit(dec!(50)));
+
+        let lines = [(dec!(-2), Some(dec!(100)))];
+        assert!(policy.can_auto_approve(&adjustment(
+            other_store,
+            AdjustmentReason::Damage,
+            &lines
+        )));
+        assert!(!policy.can_auto_approve(&adjustment(
+            strict_store,
+            AdjustmentReason::Damage,
+            &lines
+        )));
+        assert!(!policy.can_auto_approve(&adjustment(
+            other_store,
+            AdjustmentReason::Expiration,
+            &lines
+        )));
+
+        let reason_only = AdjustmentApprovalPolicy::disabled()
+            .with_reason_limit(AdjustmentReason::Expiration, value_limit(dec!(50)));
+        let small = [(dec!(-1), Some(dec!(50)))];
+        assert!(reason_only.can_auto_approve(&adjustment(
+            other_store,
+            AdjustmentReason::Expiration,
+            &small
+        )));
+        assert!(!reason_only.can_auto_approve(&adjustment(
+            other_store,
+            AdjustmentReason::Damage,
+            &small
+        )));
+    }
+}
@@ -21,6 +21,8 @@ pub enum AdjustmentReason {
     Correction,
     /// Product expired
     Expiration,
+    /// Shipped on a transfer but never received at the destination
+    InTransitLoss,
 }
 
 impl AdjustmentReason {
@@ -33,6 +35,7 @@ impl AdjustmentReason {
             AdjustmentReason::Found,
             AdjustmentReason::Correction,
             AdjustmentReason::Expiration,
+            AdjustmentReason::InTransitLoss,
         ]
     }
 
@@ -44,6 +47,7 @@ impl AdjustmentReason {
                 | AdjustmentReason::Theft
                 | AdjustmentReason::Loss
                 | AdjustmentReason::Expiration
+                | AdjustmentReason::InTransitLoss
         )
     }
 
@@ -64,6 +68,7 @@ impl FromStr for AdjustmentReason {
             "found" => Ok(AdjustmentReason::Found),
             "correction" | "count" | "recount" => Ok(AdjustmentReason::Correction),
             "expiration" | "expired" => Ok(AdjustmentReason::Expiration),
+            "in_transit_loss" => Ok(AdjustmentReason::InTransitLoss),
             _ => Err(InventoryError::InvalidAdjustmentReason),
         }
     }
@@ -78,6 +83,7 @@ impl fmt::Display for AdjustmentReason {
             AdjustmentReason::Found => write!(f, "found"),
             AdjustmentReason::Correction => write!(f, "correction"),
             AdjustmentReason::Expiration => write!(f, "expiration"),
+            AdjustmentReason::InTransitLoss => write!(f, "in_transit_loss"),
         }
     }
 }
@@ -151,6 +157,14 @@ mod tests {
     fn test_display() {
         assert_eq!(AdjustmentReason::Damage.to_string(), "damage");
         assert_eq!(AdjustmentReason::Correction.to_string(), "correction");
+        assert_eq!(
+            AdjustmentReason::InTransitLoss.to_string(),
+            "in_transit_loss"
+        );
+        assert_eq!(
+            AdjustmentReason::from_str("in_transit_loss").unwrap(),
+            AdjustmentReason::InTransitLoss
+        );
     }
 
     #[test]
@@ -159,6 +173,7 @@ mod tests {
         assert!(AdjustmentReason::Theft.is_typically_decrease());
         assert!(AdjustmentReason::Loss.is_typically_decrease());
         assert!(AdjustmentReason::Expiration.is_typically_decrease());
+        assert!(AdjustmentReason::InTransitLoss.is_typically_decrease());
         assert!(!AdjustmentReason::Found.is_typically_decrease());
         assert!(!AdjustmentReason::Correction.is_typically_decrease());
     }
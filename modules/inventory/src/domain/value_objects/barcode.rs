@@ -3,7 +3,13 @@
 use crate::InventoryError;
 use serde::{Deserialize, Serialize};
 
+/// Number of digits in an EAN-13 barcode, check digit included
+pub const EAN13_LENGTH: usize = 13;
+
 /// Product barcode - optional, max 100 characters
+///
+/// Any value up to 100 characters is accepted, except that a 13-digit value
+/// is taken to be an EAN-13 and must carry the correct check digit.
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct Barcode(String);
 
@@ -11,14 +17,41 @@ impl Barcode {
     /// Maximum allowed length for a barcode
     pub const MAX_LENGTH: usize = 100;
 
-    /// Creates a new Barcode, validating the length
+    /// Creates a new Barcode, validating the length and, for EAN-13 values,
+    /// the check digit
     pub fn new(value: &str) -> Result<Self, InventoryError> {
         if value.len() > Self::MAX_LENGTH {
             return Err(InventoryError::InvalidBarcode);
         }
+        if is_ean13_shaped(value) {
+            let expected = ean13_check_digit(&value[..EAN13_LENGTH - 1]);
+            if value.as_bytes()[EAN13_LENGTH - 1] - b'0' != expected {
+                return Err(InventoryError::InvalidEan13CheckDigit(value.to_string()));
+            }
+        }
         Ok(Self(value.to_string()))
     }
 
+    /// Builds the EAN-13 for `value` under `prefix`: the prefix, the value
+    /// zero-padded to fill the remaining 12 digits, and the check digit.
+    ///
+    /// Fails with `BarcodeSequenceExhausted` once `value` no longer fits.
+    pub fn ean13(prefix: &BarcodePrefix, value: u64) -> Result<Self, InventoryError> {
+        if value > prefix.max_value() {
+            return Err(InventoryError::BarcodeSequenceExhausted(
+                prefix.as_str().to_string(),
+            ));
+        }
+        let body = format!(
+            "{}{:0width$}",
+            prefix.as_str(),
+            value,
+            width = prefix.sequence_digits()
+        );
+        let check = ean13_check_digit(&body);
+        Ok(Self(format!("{}{}", body, check)))
+    }
+
     /// Reconstitutes a Barcode from database (no validation needed)
     pub fn from_string(value: String) -> Self {
         Self(value)
@@ -36,14 +69,78 @@ impl std::fmt::Display for Barcode {
     }
 }
 
+/// Leading digits of auto-generated EAN-13 barcodes, typically a GS1 company
+/// prefix. Configured per category; the rest of the 12-digit body comes from
+/// the prefix's sequence.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct BarcodePrefix(String);
+
+impl BarcodePrefix {
+    /// Shortest prefix accepted (a GS1 country prefix alone)
+    pub const MIN_LENGTH: usize = 3;
+
+    /// Longest prefix accepted, leaving at least 3 digits for the sequence
+    pub const MAX_LENGTH: usize = 9;
+
+    /// Creates a validated prefix of 3 to 9 digits
+    pub fn new(value: &str) -> Result<Self, InventoryError> {
+        let value = value.trim();
+        if value.len() < Self::MIN_LENGTH
+            || value.len() > Self::MAX_LENGTH
+            || !value.bytes().all(|b| b.is_ascii_digit())
+        {
+            return Err(InventoryError::InvalidBarcodePrefix(format!(
+                "prefix must be {} to {} digits",
+                Self::MIN_LENGTH,
+                Self::MAX_LENGTH
+            )));
+        }
+        Ok(Self(value.to_string()))
+    }
+
+    /// Number of digits left for the sequence value
+    pub fn sequence_digits(&self) -> usize {
+        EAN13_LENGTH - 1 - self.0.len()
+    }
+
+    /// Largest sequence value that still fits under this prefix
+    pub fn max_value(&self) -> u64 {
+        10u64.pow(self.sequence_digits() as u32) - 1
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+/// Computes the EAN-13 check digit for the first 12 digits of a barcode.
+///
+/// Digits are weighted 1 and 3 alternately from the left; the check digit
+/// brings the weighted sum up to the next multiple of 10.
+pub fn ean13_check_digit(body: &str) -> u8 {
+    let sum: u32 = body
+        .bytes()
+        .enumerate()
+        .map(|(i, b)| {
+            let digit = (b - b'0') as u32;
+            if i % 2 == 0 { digit } else { digit * 3 }
+        })
+        .sum();
+    ((10 - sum % 10) % 10) as u8
+}
+
+fn is_ean13_shaped(value: &str) -> bool {
+    value.len() == EAN13_LENGTH && value.bytes().all(|b| b.is_ascii_digit())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
     #[test]
     fn test_barcode_valid() {
-        let barcode = Barcode::new("1234567890123").unwrap();
-        assert_eq!(barcode.as_str(), "1234567890123");
+        let barcode = Barcode::new("1234567890128").unwrap();
+        assert_eq!(barcode.as_str(), "1234567890128");
     }
 
     #[test]
@@ -65,4 +162,59 @@ mod tests {
         let barcode = Barcode::new("").unwrap();
         assert_eq!(barcode.as_str(), "");
     }
+
+    #[test]
+    fn test_ean13_check_digit_known_codes() {
+        assert_eq!(ean13_check_digit("400638133393"), 1);
+        assert_eq!(ean13_check_digit("501234567890"), 0);
+        assert_eq!(ean13_check_digit("123456789012"), 8);
+        assert_eq!(ean13_check_digit("000000000000"), 0);
+    }
+
+    #[test]
+    fn test_barcode_rejects_wrong_ean13_check_digit() {
+        assert!(Barcode::new("4006381333931").is_ok());
+        let result = Barcode::new("4006381333932");
+        assert!(matches!(
+            result,
+            Err(InventoryError::InvalidEan13CheckDigit(_))
+        ));
+    }
+
+    #[test]
+    fn test_barcode_other_formats_skip_check_digit() {
+        // 12-digit UPC-A and alphanumeric codes are not EAN-13
+        assert!(Barcode::new("123456789012").is_ok());
+        assert!(Barcode::new("ABC1234567890").is_ok());
+    }
+
+    #[test]
+    fn test_ean13_from_prefix_and_sequence() {
+        let prefix = BarcodePrefix::new("7401234").unwrap();
+        let barcode = Barcode::ean13(&prefix, 42).unwrap();
+
+        assert_eq!(barcode.as_str().len(), EAN13_LENGTH);
+        assert!(barcode.as_str().starts_with("740123400042"));
+        // Generated codes pass the same validation as entered ones
+        assert_eq!(Barcode::new(barcode.as_str()).unwrap(), barcode);
+    }
+
+    #[test]
+    fn test_ean13_sequence_exhausted() {
+        let prefix = BarcodePrefix::new("740123456").unwrap();
+        assert_eq!(prefix.max_value(), 999);
+        assert!(Barcode::ean13(&prefix, 999).is_ok());
+        assert!(matches!(
+            Barcode::ean13(&prefix, 1000),
+            Err(InventoryError::BarcodeSequenceExhausted(_))
+        ));
+    }
+
+    #[test]
+    fn test_barcode_prefix_validation() {
+        assert!(BarcodePrefix::new("740").is_ok());
+        assert!(BarcodePrefix::new("74").is_err());
+        assert!(BarcodePrefix::new("7401234567").is_err());
+        assert!(BarcodePrefix::new("74A123").is_err());
+    }
 }
@@ -0,0 +1,119 @@
+// CostingMethod enum - how on-hand stock of a category is costed
+
+use crate::InventoryError;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
+
+/// Inventory costing method, configured per product category.
+///
+/// Weighted average needs nothing beyond the kardex; FIFO and LIFO keep cost
+/// layers that receipts open and outgoing movements consume.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CostingMethod {
+    /// Average cost of all received units
+    #[default]
+    WeightedAverage,
+    /// First in, first out: the oldest layers are consumed first
+    Fifo,
+    /// Last in, first out: the newest layers are consumed first
+    Lifo,
+}
+
+impl CostingMethod {
+    /// Returns all available costing methods
+    pub fn all() -> &'static [CostingMethod] {
+        &[
+            CostingMethod::WeightedAverage,
+            CostingMethod::Fifo,
+            CostingMethod::Lifo,
+        ]
+    }
+
+    /// Returns true if the method keeps cost layers
+    pub fn uses_layers(&self) -> bool {
+        matches!(self, CostingMethod::Fifo | CostingMethod::Lifo)
+    }
+
+    /// Returns true if outgoing stock is taken from the oldest layers first
+    pub fn consumes_oldest_first(&self) -> bool {
+        !matches!(self, CostingMethod::Lifo)
+    }
+}
+
+impl FromStr for CostingMethod {
+    type Err = InventoryError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().replace('-', "_").as_str() {
+            "weighted_average" | "average" | "wac" => Ok(CostingMethod::WeightedAverage),
+            "fifo" => Ok(CostingMethod::Fifo),
+            "lifo" => Ok(CostingMethod::Lifo),
+            _ => Err(InventoryError::InvalidCostingMethod),
+        }
+    }
+}
+
+impl fmt::Display for CostingMethod {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CostingMethod::WeightedAverage => write!(f, "weighted_average"),
+            CostingMethod::Fifo => write!(f, "fifo"),
+            CostingMethod::Lifo => write!(f, "lifo"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_str() {
+        assert_eq!(
+            CostingMethod::from_str("weighted_average").unwrap(),
+            CostingMethod::WeightedAverage
+        );
+        assert_eq!(
+            CostingMethod::from_str("weighted-average").unwrap(),
+            CostingMethod::WeightedAverage
+        );
+        assert_eq!(
+            CostingMethod::from_str("FIFO").unwrap(),
+            CostingMethod::Fifo
+        );
+        assert_eq!(
+            CostingMethod::from_str("lifo").unwrap(),
+            CostingMethod::Lifo
+        );
+        assert!(matches!(
+            CostingMethod::from_str("standard"),
+            Err(InventoryError::InvalidCostingMethod)
+        ));
+    }
+
+    #[test]
+    fn test_display_round_trips() {
+        for method in CostingMethod::all() {
+            assert_eq!(
+                CostingMethod::from_str(&method.to_string()).unwrap(),
+                *method
+            );
+        }
+    }
+
+    #[test]
+    fn test_default_is_weighted_average() {
+        assert_eq!(CostingMethod::default(), CostingMethod::WeightedAverage);
+        assert!(!CostingMethod::default().uses_layers());
+    }
+
+    #[test]
+    fn test_layer_order() {
+        assert!(CostingMethod::Fifo.uses_layers());
+        assert!(CostingMethod::Fifo.consumes_oldest_first());
+        assert!(CostingMethod::Lifo.uses_layers());
+        assert!(!CostingMethod::Lifo.consumes_oldest_first());
+    }
+}
@@ -0,0 +1,31 @@
+// InventoryPeriodId value object - unique identifier for inventory periods
+
+use serde::{Deserialize, Serialize};
+use uuid::{NoContext, Timestamp, Uuid};
+
+/// Unique identifier for an InventoryPeriod
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct InventoryPeriodId(Uuid);
+
+impl InventoryPeriodId {
+    /// Creates a new InventoryPeriodId with a UUID v7 (time-ordered)
+    pub fn new() -> Self {
+        Self(Uuid::new_v7(Timestamp::now(NoContext)))
+    }
+
+    /// Creates a InventoryPeriodId from an existing UUID
+    pub fn from_uuid(uuid: Uuid) -> Self {
+        Self(uuid)
+    }
+
+    /// Converts the InventoryPeriodId into its underlying UUID
+    pub fn into_uuid(self) -> Uuid {
+        self.0
+    }
+}
+
+impl Default for InventoryPeriodId {
+    fn default() -> Self {
+        Self::new()
+    }
+}
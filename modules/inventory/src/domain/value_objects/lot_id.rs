@@ -0,0 +1,31 @@
+// LotId value object - unique identifier for inventory lots
+
+use serde::{Deserialize, Serialize};
+use uuid::{NoContext, Timestamp, Uuid};
+
+/// Unique identifier for an InventoryLot
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct LotId(Uuid);
+
+impl LotId {
+    /// Creates a new LotId with a UUID v7 (time-ordered)
+    pub fn new() -> Self {
+        Self(Uuid::new_v7(Timestamp::now(NoContext)))
+    }
+
+    /// Creates a LotId from an existing UUID
+    pub fn from_uuid(uuid: Uuid) -> Self {
+        Self(uuid)
+    }
+
+    /// Converts the LotId into its underlying UUID
+    pub fn into_uuid(self) -> Uuid {
+        self.0
+    }
+}
+
+impl Default for LotId {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,136 @@
+// MarkupRule value object - how a product's selling price follows its cost
+
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+use crate::InventoryError;
+
+/// Rule that derives a selling price from a unit cost.
+///
+/// Products without a rule keep a manual price that cost changes never touch.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum MarkupRule {
+    /// Price is cost plus `percent` of cost (cost 10, 40% → 14.00)
+    Markup { percent: Decimal },
+    /// Price keeps a gross margin of `percent` of the price (cost 6, 40% → 10.00)
+    Margin { percent: Decimal },
+    /// Price is twice the cost
+    Keystone,
+}
+
+impl MarkupRule {
+    /// Builds a rule from its stored type and percent
+    ///
+    /// # Errors
+    /// `InventoryError::InvalidMarkupRule` for an unknown type, a missing or
+    /// negative percent, or a margin of 100% or more.
+    pub fn parse(markup_type: &str, percent: Option<Decimal>) -> Result<Self, InventoryError> {
+        let required = || {
+            percent.ok_or_else(|| {
+                InventoryError::InvalidMarkupRule(format!("{} requires a percent", markup_type))
+            })
+        };
+        let rule = match markup_type.to_lowercase().as_str() {
+            "markup" => MarkupRule::Markup {
+                percent: required()?,
+            },
+            "margin" => MarkupRule::Margin {
+                percent: required()?,
+            },
+            "keystone" => MarkupRule::Keystone,
+            other => {
+                return Err(InventoryError::InvalidMarkupRule(format!(
+                    "unknown markup type '{}'",
+                    other
+                )));
+            }
+        };
+
+        match rule {
+            MarkupRule::Markup { percent } if percent < Decimal::ZERO => Err(
+                InventoryError::InvalidMarkupRule("markup percent cannot be negative".to_string()),
+            ),
+            MarkupRule::Margin { percent }
+                if percent < Decimal::ZERO || percent >= Decimal::ONE_HUNDRED =>
+            {
+                Err(InventoryError::InvalidMarkupRule(
+                    "margin percent must be at least 0 and below 100".to_string(),
+                ))
+            }
+            rule => Ok(rule),
+        }
+    }
+
+    /// Stored name of the rule type
+    pub fn markup_type(&self) -> &'static str {
+        match self {
+            MarkupRule::Markup { .. } => "markup",
+            MarkupRule::Margin { .. } => "margin",
+            MarkupRule::Keystone => "keystone",
+        }
+    }
+
+    /// Percent of the rule; None for keystone
+    pub fn percent(&self) -> Option<Decimal> {
+        match self {
+            MarkupRule::Markup { percent } | MarkupRule::Margin { percent } => Some(*percent),
+            MarkupRule::Keystone => None,
+        }
+    }
+
+    /// Selling price for `cost`, rounded to 2 decimal places
+    pub fn apply(&self, cost: Decimal) -> Decimal {
+        let price = match self {
+            MarkupRule::Markup { percent } => {
+                cost * (Decimal::ONE_HUNDRED + percent) / Decimal::ONE_HUNDRED
+            }
+            MarkupRule::Margin { percent } => {
+                cost * Decimal::ONE_HUNDRED / (Decimal::ONE_HUNDRED - percent)
+            }
+            MarkupRule::Keystone => cost * Decimal::TWO,
+        };
+        price.round_dp(2)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    #[test]
+    fn test_apply() {
+        let markup = MarkupRule::parse("markup", Some(dec!(40))).unwrap();
+        assert_eq!(markup.apply(dec!(10)), dec!(14.00));
+
+        let margin = MarkupRule::parse("margin", Some(dec!(40))).unwrap();
+        assert_eq!(margin.apply(dec!(6)), dec!(10.00));
+        assert_eq!(margin.apply(dec!(7)), dec!(11.67));
+
+        let keystone = MarkupRule::parse("KEYSTONE", None).unwrap();
+        assert_eq!(keystone.apply(dec!(4.25)), dec!(8.50));
+    }
+
+    #[test]
+    fn test_round_trip() {
+        for rule in [
+            MarkupRule::Markup { percent: dec!(25) },
+            MarkupRule::Margin { percent: dec!(30) },
+            MarkupRule::Keystone,
+        ] {
+            assert_eq!(
+                MarkupRule::parse(rule.markup_type(), rule.percent()).unwrap(),
+                rule
+            );
+        }
+    }
+
+    #[test]
+    fn test_invalid() {
+        assert!(MarkupRule::parse("markup", None).is_err());
+        assert!(MarkupRule::parse("markup", Some(dec!(-5))).is_err());
+        assert!(MarkupRule::parse("margin", Some(dec!(100))).is_err());
+        assert!(MarkupRule::parse("cost_plus", Some(dec!(10))).is_err());
+    }
+}
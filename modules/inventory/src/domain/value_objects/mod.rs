@@ -8,76 +8,128 @@
 //! All IDs use UUID v7 for temporal ordering:
 //!
 //! - [`ProductId`], [`VariantId`], [`CategoryId`]: Product catalog identifiers
-//! - [`StockId`], [`MovementId`], [`ReservationId`]: Stock management identifiers
+//! - [`StockId`], [`MovementId`], [`ReservationId`], [`SerialId`], [`LotId`]: Stock management identifiers
 //! - [`RecipeId`], [`IngredientId`], [`SubstituteId`]: Recipe/BOM identifiers
-//! - [`AdjustmentId`], [`TransferId`]: Workflow document identifiers
+//! - [`AdjustmentId`], [`TransferId`], [`RepriceBatchId`]: Workflow document identifiers
+//! - [`PriceChangeId`]: Product price history identifiers
+//! - [`ValuationSnapshotId`]: Period-end valuation snapshot identifiers
+//! - [`InventoryPeriodId`]: Store inventory period identifiers
 //!
 //! ## Validated Value Objects
 //!
 //! - [`Sku`]: Auto-generated stock keeping unit codes
-//! - [`Barcode`]: Optional product barcodes (max 100 chars)
+//! - [`Barcode`]: Optional product barcodes (max 100 chars, EAN-13 check digit)
+//! - [`BarcodePrefix`]: Leading digits of auto-generated EAN-13 barcodes
 //! - [`Currency`]: ISO 4217 currency codes (3 uppercase letters)
-//! - [`UnitOfMeasure`]: Measurement units (Unit, Kg, Lb, Liter, Oz)
+//! - [`UnitOfMeasure`]: Measurement units (Unit, Kg, Lb, Liter, Oz, G, Ml, Case)
+//! - [`UnitConversion`]: Factor between two units, e.g. 1 case = 24 units
+//! - [`ReservationGracePolicy`]: Grace window for re-reserving expired holds
+//! - [`ReservationPriorityPolicy`]: Which reservations may bump others when stock is short
+//! - [`TransferApprovalPolicy`]: Value and cross-region rules for transfer approval
+//! - [`AdjustmentApprovalPolicy`]: Size limits under which negative adjustments are auto-approved
+//! - [`MarkupRule`]: Markup, margin or keystone pricing from cost
+//! - [`PriceAdjustment`]: Percentage or fixed change for bulk price updates
 //!
 //! ## Enum Value Objects
 //!
 //! - [`MovementType`]: Types of inventory movements (In, Out, Adjustment, etc.)
 //! - [`ReservationStatus`]: Reservation lifecycle states
+//! - [`SerialStatus`]: Serialized unit lifecycle states
 //! - [`AdjustmentStatus`]: Adjustment workflow states
 //! - [`AdjustmentType`]: Increase or decrease adjustments
 //! - [`AdjustmentReason`]: Reasons for adjustments (Damage, Theft, etc.)
 //! - [`TransferStatus`]: Transfer workflow states
+//! - [`RepriceBatchStatus`]: Batch reprice approval states
+//! - [`TaxCategory`]: Tax treatment inherited down the category tree
+//! - [`CostingMethod`]: Weighted average, FIFO or LIFO costing per category
+//! - [`PeriodStatus`]: Open or closed inventory periods
 
 // ID value objects
 mod adjustment_id;
 mod category_id;
 mod ingredient_id;
+mod inventory_period_id;
+mod lot_id;
 mod movement_id;
+mod price_change_id;
 mod product_id;
 mod recipe_id;
+mod reprice_batch_id;
 mod reservation_id;
+mod serial_id;
 mod stock_id;
 mod substitute_id;
 mod transfer_id;
+mod valuation_snapshot_id;
 mod variant_id;
 
 // Validated value objects
+mod adjustment_approval_policy;
 mod barcode;
 mod currency;
+mod markup_rule;
+mod price_adjustment;
+mod reservation_grace_policy;
+mod reservation_priority_policy;
 mod sku;
+mod transfer_approval_policy;
+mod unit_conversion;
 mod unit_of_measure;
 
 // Enum value objects
 mod adjustment_reason;
 mod adjustment_status;
 mod adjustment_type;
+mod costing_method;
 mod movement_type;
+mod period_status;
+mod reprice_batch_status;
 mod reservation_status;
+mod serial_status;
+mod tax_category;
 mod transfer_status;
 
 // Re-exports - ID value objects
 pub use adjustment_id::AdjustmentId;
 pub use category_id::CategoryId;
 pub use ingredient_id::IngredientId;
+pub use inventory_period_id::InventoryPeriodId;
+pub use lot_id::LotId;
 pub use movement_id::MovementId;
+pub use price_change_id::PriceChangeId;
 pub use product_id::ProductId;
 pub use recipe_id::RecipeId;
+pub use reprice_batch_id::RepriceBatchId;
 pub use reservation_id::ReservationId;
+pub use serial_id::SerialId;
 pub use stock_id::StockId;
 pub use substitute_id::SubstituteId;
 pub use transfer_id::TransferId;
+pub use valuation_snapshot_id::ValuationSnapshotId;
 pub use variant_id::VariantId;
 
 // Re-exports - Validated value objects
-pub use barcode::Barcode;
+pub use adjustment_approval_policy::{AdjustmentApprovalLimit, AdjustmentApprovalPolicy};
+pub use barcode::{Barcode, BarcodePrefix, EAN13_LENGTH, ean13_check_digit};
 pub use currency::Currency;
-pub use sku::Sku;
+pub use markup_rule::MarkupRule;
+pub use price_adjustment::{PriceAdjustment, PriceAdjustmentAmount};
+pub use reservation_grace_policy::ReservationGracePolicy;
+pub use reservation_priority_policy::ReservationPriorityPolicy;
+pub use sku::{MAX_SKU_LENGTH, Sku};
+pub use transfer_approval_policy::TransferApprovalPolicy;
+pub use unit_conversion::UnitConversion;
 pub use unit_of_measure::UnitOfMeasure;
 
 // Re-exports - Enum value objects
 pub use adjustment_reason::AdjustmentReason;
 pub use adjustment_status::AdjustmentStatus;
 pub use adjustment_type::AdjustmentType;
+pub use costing_method::CostingMethod;
 pub use movement_type::MovementType;
+pub use period_status::PeriodStatus;
+pub use reprice_batch_status::RepriceBatchStatus;
 pub use reservation_status::ReservationStatus;
+pub use serial_status::SerialStatus;
+pub use tax_category::{EffectiveTaxCategory, TaxCategory, TaxCategorySource};
 pub use transfer_status::TransferStatus;
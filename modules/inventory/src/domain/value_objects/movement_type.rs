@@ -1,6 +1,7 @@
 // MovementType enum - types of inventory movements (Kardex)
 
 use crate::InventoryError;
+use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 use std::fmt;
 use std::str::FromStr;
@@ -23,6 +24,8 @@ pub enum MovementType {
     Reservation,
     /// Released from reservation
     Release,
+    /// Stock sold beyond what was on hand, in a store allowing negative stock
+    Backorder,
 }
 
 impl MovementType {
@@ -36,9 +39,20 @@ impl MovementType {
             MovementType::TransferIn,
             MovementType::Reservation,
             MovementType::Release,
+            MovementType::Backorder,
         ]
     }
 
+    /// Movement type for a sale leaving `balance_after` on hand: a sale that
+    /// takes stock below zero is recorded as a backorder
+    pub fn for_sale(balance_after: Decimal) -> MovementType {
+        if balance_after < Decimal::ZERO {
+            MovementType::Backorder
+        } else {
+            MovementType::Out
+        }
+    }
+
     /// Returns true if this movement type increases stock
     pub fn is_increase(&self) -> bool {
         matches!(
@@ -51,7 +65,10 @@ impl MovementType {
     pub fn is_decrease(&self) -> bool {
         matches!(
             self,
-            MovementType::Out | MovementType::TransferOut | MovementType::Reservation
+            MovementType::Out
+                | MovementType::TransferOut
+                | MovementType::Reservation
+                | MovementType::Backorder
         )
     }
 }
@@ -68,6 +85,7 @@ impl FromStr for MovementType {
             "transfer_in" | "transferin" => Ok(MovementType::TransferIn),
             "reservation" | "reserve" | "reserved" => Ok(MovementType::Reservation),
             "release" | "released" => Ok(MovementType::Release),
+            "backorder" | "backordered" => Ok(MovementType::Backorder),
             _ => Err(InventoryError::InvalidMovementType),
         }
     }
@@ -83,6 +101,7 @@ impl fmt::Display for MovementType {
             MovementType::TransferIn => write!(f, "transfer_in"),
             MovementType::Reservation => write!(f, "reservation"),
             MovementType::Release => write!(f, "release"),
+            MovementType::Backorder => write!(f, "backorder"),
         }
     }
 }
@@ -152,6 +171,27 @@ mod tests {
         assert!(MovementType::Out.is_decrease());
         assert!(MovementType::TransferOut.is_decrease());
         assert!(MovementType::Reservation.is_decrease());
+        assert!(MovementType::Backorder.is_decrease());
         assert!(!MovementType::In.is_decrease());
     }
+
+    #[test]
+    fn test_display_round_trip() {
+        for movement_type in MovementType::all() {
+            assert_eq!(
+                MovementType::from_str(&movement_type.to_string()).unwrap(),
+                *movement_type
+            );
+        }
+    }
+
+    #[test]
+    fn test_for_sale() {
+        assert_eq!(MovementType::for_sale(Decimal::ONE), MovementType::Out);
+        assert_eq!(MovementType::for_sale(Decimal::ZERO), MovementType::Out);
+        assert_eq!(
+            MovementType::for_sale(Decimal::NEGATIVE_ONE),
+            MovementType::Backorder
+        );
+    }
 }
@@ -0,0 +1,72 @@
+// PeriodStatus enum - whether stock may still be posted to an inventory period
+
+use crate::InventoryError;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
+
+/// Status of an inventory period
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PeriodStatus {
+    /// Operations dated in the period are accepted
+    Open,
+    /// Operations dated in the period are rejected
+    Closed,
+}
+
+impl PeriodStatus {
+    /// Returns all period statuses
+    pub fn all() -> &'static [PeriodStatus] {
+        &[PeriodStatus::Open, PeriodStatus::Closed]
+    }
+
+    /// Returns true if the period is closed
+    pub fn is_closed(&self) -> bool {
+        matches!(self, PeriodStatus::Closed)
+    }
+}
+
+impl FromStr for PeriodStatus {
+    type Err = InventoryError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "open" => Ok(PeriodStatus::Open),
+            "closed" => Ok(PeriodStatus::Closed),
+            _ => Err(InventoryError::InvalidPeriodStatus),
+        }
+    }
+}
+
+impl fmt::Display for PeriodStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PeriodStatus::Open => write!(f, "open"),
+            PeriodStatus::Closed => write!(f, "closed"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_round_trip() {
+        for status in PeriodStatus::all() {
+            assert_eq!(
+                PeriodStatus::from_str(&status.to_string()).unwrap(),
+                *status
+            );
+        }
+    }
+
+    #[test]
+    fn test_invalid() {
+        assert!(matches!(
+            PeriodStatus::from_str("locked"),
+            Err(InventoryError::InvalidPeriodStatus)
+        ));
+    }
+}
@@ -0,0 +1,134 @@
+// PriceAdjustment value object - change applied to prices in a bulk update
+
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+use crate::InventoryError;
+
+/// How a bulk price update changes each price
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type", content = "value", rename_all = "snake_case")]
+pub enum PriceAdjustmentAmount {
+    /// Adds `value` percent of the price (10 → +10%, -5 → -5%)
+    Percentage(Decimal),
+    /// Adds `value` to the price; negative values lower it
+    FixedAmount(Decimal),
+}
+
+/// Change applied to the base price and/or cost price of many products
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PriceAdjustment {
+    amount: PriceAdjustmentAmount,
+    base_price: bool,
+    cost_price: bool,
+}
+
+impl PriceAdjustment {
+    /// Creates an adjustment of the selected prices
+    ///
+    /// # Errors
+    /// `InventoryError::InvalidPriceAdjustment` if neither price is selected
+    pub fn new(
+        amount: PriceAdjustmentAmount,
+        base_price: bool,
+        cost_price: bool,
+    ) -> Result<Self, InventoryError> {
+        if !base_price && !cost_price {
+            return Err(InventoryError::InvalidPriceAdjustment(
+                "select base_price, cost_price or both".to_string(),
+            ));
+        }
+        Ok(Self {
+            amount,
+            base_price,
+            cost_price,
+        })
+    }
+
+    /// Builds an adjustment from its type ("percentage" or "fixed_amount")
+    /// and value
+    ///
+    /// # Errors
+    /// `InventoryError::InvalidPriceAdjustment` for an unknown type or when
+    /// neither price is selected
+    pub fn parse(
+        adjustment_type: &str,
+        value: Decimal,
+        base_price: bool,
+        cost_price: bool,
+    ) -> Result<Self, InventoryError> {
+        let amount = match adjustment_type.to_lowercase().replace('-', "_").as_str() {
+            "percentage" | "percent" => PriceAdjustmentAmount::Percentage(value),
+            "fixed_amount" | "fixed" | "amount" => PriceAdjustmentAmount::FixedAmount(value),
+            other => {
+                return Err(InventoryError::InvalidPriceAdjustment(format!(
+                    "unknown adjustment type '{}'",
+                    other
+                )));
+            }
+        };
+        Self::new(amount, base_price, cost_price)
+    }
+
+    pub fn amount(&self) -> PriceAdjustmentAmount {
+        self.amount
+    }
+
+    /// Returns true if the adjustment changes base prices
+    pub fn applies_to_base_price(&self) -> bool {
+        self.base_price
+    }
+
+    /// Returns true if the adjustment changes cost prices
+    pub fn applies_to_cost_price(&self) -> bool {
+        self.cost_price
+    }
+
+    /// Adjusted `price`, rounded to 2 decimal places. May be negative;
+    /// callers decide what to do with such prices.
+    pub fn apply(&self, price: Decimal) -> Decimal {
+        let adjusted = match self.amount {
+            PriceAdjustmentAmount::Percentage(percent) => {
+                price * (Decimal::ONE_HUNDRED + percent) / Decimal::ONE_HUNDRED
+            }
+            PriceAdjustmentAmount::FixedAmount(amount) => price + amount,
+        };
+        adjusted.round_dp(2)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    #[test]
+    fn test_percentage() {
+        let adjustment = PriceAdjustment::parse("percentage", dec!(10), true, false).unwrap();
+        assert_eq!(adjustment.apply(dec!(19.99)), dec!(21.99));
+
+        let discount = PriceAdjustment::parse("percent", dec!(-25), true, false).unwrap();
+        assert_eq!(discount.apply(dec!(40)), dec!(30));
+    }
+
+    #[test]
+    fn test_fixed_amount() {
+        let adjustment = PriceAdjustment::parse("fixed_amount", dec!(-5), false, true).unwrap();
+        assert!(adjustment.applies_to_cost_price());
+        assert!(!adjustment.applies_to_base_price());
+        assert_eq!(adjustment.apply(dec!(12.5)), dec!(7.5));
+        assert_eq!(adjustment.apply(dec!(3)), dec!(-2));
+    }
+
+    #[test]
+    fn test_invalid() {
+        assert!(matches!(
+            PriceAdjustment::parse("double", dec!(1), true, true),
+            Err(InventoryError::InvalidPriceAdjustment(_))
+        ));
+        assert!(matches!(
+            PriceAdjustment::parse("percentage", dec!(1), false, false),
+            Err(InventoryError::InvalidPriceAdjustment(_))
+        ));
+    }
+}
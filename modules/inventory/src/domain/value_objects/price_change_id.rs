@@ -0,0 +1,31 @@
+// PriceChangeId value object - unique identifier for product price history records
+
+use serde::{Deserialize, Serialize};
+use uuid::{NoContext, Timestamp, Uuid};
+
+/// Unique identifier for a ProductPriceChange
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct PriceChangeId(Uuid);
+
+impl PriceChangeId {
+    /// Creates a new PriceChangeId with a UUID v7 (time-ordered)
+    pub fn new() -> Self {
+        Self(Uuid::new_v7(Timestamp::now(NoContext)))
+    }
+
+    /// Creates a PriceChangeId from an existing UUID
+    pub fn from_uuid(uuid: Uuid) -> Self {
+        Self(uuid)
+    }
+
+    /// Converts the PriceChangeId into its underlying UUID
+    pub fn into_uuid(self) -> Uuid {
+        self.0
+    }
+}
+
+impl Default for PriceChangeId {
+    fn default() -> Self {
+        Self::new()
+    }
+}
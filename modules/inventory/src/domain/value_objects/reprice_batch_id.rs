@@ -0,0 +1,31 @@
+// RepriceBatchId value object - unique identifier for reprice batches
+
+use serde::{Deserialize, Serialize};
+use uuid::{NoContext, Timestamp, Uuid};
+
+/// Unique identifier for a RepriceBatch
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct RepriceBatchId(Uuid);
+
+impl RepriceBatchId {
+    /// Creates a new RepriceBatchId with a UUID v7 (time-ordered)
+    pub fn new() -> Self {
+        Self(Uuid::new_v7(Timestamp::now(NoContext)))
+    }
+
+    /// Creates a RepriceBatchId from an existing UUID
+    pub fn from_uuid(uuid: Uuid) -> Self {
+        Self(uuid)
+    }
+
+    /// Converts the RepriceBatchId into its underlying UUID
+    pub fn into_uuid(self) -> Uuid {
+        self.0
+    }
+}
+
+impl Default for RepriceBatchId {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,80 @@
+// RepriceBatchStatus enum - approval state of a batch reprice
+
+use crate::InventoryError;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
+
+/// Status of a batch reprice
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RepriceBatchStatus {
+    /// Preview awaiting approval; no product has changed yet
+    Pending,
+    /// New costs and prices were written to the products
+    Applied,
+    /// Preview was discarded
+    Rejected,
+}
+
+impl RepriceBatchStatus {
+    /// Returns all batch statuses
+    pub fn all() -> &'static [RepriceBatchStatus] {
+        &[
+            RepriceBatchStatus::Pending,
+            RepriceBatchStatus::Applied,
+            RepriceBatchStatus::Rejected,
+        ]
+    }
+
+    /// Returns true if the batch can still be approved or rejected
+    pub fn is_pending(&self) -> bool {
+        matches!(self, RepriceBatchStatus::Pending)
+    }
+}
+
+impl FromStr for RepriceBatchStatus {
+    type Err = InventoryError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "pending" => Ok(RepriceBatchStatus::Pending),
+            "applied" => Ok(RepriceBatchStatus::Applied),
+            "rejected" => Ok(RepriceBatchStatus::Rejected),
+            _ => Err(InventoryError::InvalidRepriceBatchStatus),
+        }
+    }
+}
+
+impl fmt::Display for RepriceBatchStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RepriceBatchStatus::Pending => write!(f, "pending"),
+            RepriceBatchStatus::Applied => write!(f, "applied"),
+            RepriceBatchStatus::Rejected => write!(f, "rejected"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_round_trip() {
+        for status in RepriceBatchStatus::all() {
+            assert_eq!(
+                RepriceBatchStatus::from_str(&status.to_string()).unwrap(),
+                *status
+            );
+        }
+    }
+
+    #[test]
+    fn test_invalid() {
+        assert!(matches!(
+            RepriceBatchStatus::from_str("draft"),
+            Err(InventoryError::InvalidRepriceBatchStatus)
+        ));
+    }
+}
@@ -0,0 +1,65 @@
+// ReservationGracePolicy value object - how long after expiry a reservation
+// may still be re-reserved instead of failing
+
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Grace window applied to recently-expired reservations at checkout.
+///
+/// A reservation that expired no more than `grace_seconds` ago may be
+/// re-reserved if stock still permits. The default is zero (strict): an
+/// expired reservation always fails.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ReservationGracePolicy {
+    grace_seconds: u64,
+}
+
+impl ReservationGracePolicy {
+    /// Strict policy: no grace window
+    pub fn strict() -> Self {
+        Self::default()
+    }
+
+    /// Policy allowing re-reservation up to `grace_seconds` after expiry
+    pub fn from_secs(grace_seconds: u64) -> Self {
+        Self { grace_seconds }
+    }
+
+    pub fn grace_seconds(&self) -> u64 {
+        self.grace_seconds
+    }
+
+    pub fn is_strict(&self) -> bool {
+        self.grace_seconds == 0
+    }
+
+    /// Returns true if a reservation that expired at `expires_at` is still
+    /// within the grace window at `now`
+    pub fn allows(&self, expires_at: DateTime<Utc>, now: DateTime<Utc>) -> bool {
+        !self.is_strict()
+            && now > expires_at
+            && now - expires_at <= Duration::seconds(self.grace_seconds as i64)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_strict_never_allows() {
+        let now = Utc::now();
+        let policy = ReservationGracePolicy::strict();
+        assert!(policy.is_strict());
+        assert!(!policy.allows(now - Duration::seconds(1), now));
+    }
+
+    #[test]
+    fn test_allows_within_window() {
+        let now = Utc::now();
+        let policy = ReservationGracePolicy::from_secs(30);
+        assert!(policy.allows(now - Duration::seconds(5), now));
+        assert!(policy.allows(now - Duration::seconds(30), now));
+        assert!(!policy.allows(now - Duration::seconds(31), now));
+    }
+}
@@ -0,0 +1,119 @@
+// ReservationPriorityPolicy value object - decides which reservations win
+// when holds on the same stock overlap
+
+use std::collections::{HashMap, HashSet};
+
+use crate::domain::entities::InventoryReservation;
+use crate::domain::value_objects::ReservationStatus;
+
+/// Reservation priorities by reference type.
+///
+/// A new reservation takes the priority of its reference type (0 when the
+/// type has none). When stock is short it may bump pending reservations with
+/// a strictly lower priority. Confirmed holds are never bumped, and neither
+/// are holds whose reference type is protected: those are in checkout and
+/// about to convert. The default policy ranks orders and checkouts above
+/// carts and protects both.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReservationPriorityPolicy {
+    priorities: HashMap<String, i32>,
+    protected: HashSet<String>,
+}
+
+impl ReservationPriorityPolicy {
+    /// Default priority of reference types without one
+    pub const DEFAULT_PRIORITY: i32 = 0;
+
+    /// Policy where every reservation has the same priority, so nothing is
+    /// ever bumped
+    pub fn disabled() -> Self {
+        Self {
+            priorities: HashMap::new(),
+            protected: HashSet::new(),
+        }
+    }
+
+    /// Sets the priority of reservations with `reference_type`
+    pub fn with_priority(mut self, reference_type: &str, priority: i32) -> Self {
+        self.priorities.insert(reference_type.to_string(), priority);
+        self
+    }
+
+    /// Makes reservations with `reference_type` never be bumped
+    pub fn with_protected(mut self, reference_type: &str) -> Self {
+        self.protected.insert(reference_type.to_string());
+        self
+    }
+
+    pub fn priority_for(&self, reference_type: &str) -> i32 {
+        self.priorities
+            .get(reference_type)
+            .copied()
+            .unwrap_or(Self::DEFAULT_PRIORITY)
+    }
+
+    pub fn is_protected(&self, reference_type: &str) -> bool {
+        self.protected.contains(reference_type)
+    }
+
+    /// Returns true if a new reservation with `priority` may bump `holder`
+    pub fn can_bump(&self, holder: &InventoryReservation, priority: i32) -> bool {
+        holder.status() == ReservationStatus::Pending
+            && !self.is_protected(holder.reference_type())
+            && holder.priority() < priority
+    }
+}
+
+impl Default for ReservationPriorityPolicy {
+    fn default() -> Self {
+        Self::disabled()
+            .with_priority("order", 100)
+            .with_priority("checkout", 100)
+            .with_protected("order")
+            .with_protected("checkout")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::value_objects::StockId;
+    use chrono::{Duration, Utc};
+    use rust_decimal_macros::dec;
+    use uuid::Uuid;
+
+    fn reservation(reference_type: &str, priority: i32) -> InventoryReservation {
+        InventoryReservation::create(
+            StockId::new(),
+            reference_type.to_string(),
+            Uuid::nil(),
+            dec!(1),
+            Utc::now() + Duration::hours(1),
+            priority,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_default_policy_ranks_checkout_above_cart() {
+        let policy = ReservationPriorityPolicy::default();
+        assert_eq!(policy.priority_for("cart"), 0);
+        assert_eq!(policy.priority_for("order"), 100);
+
+        let cart = reservation("cart", 0);
+        assert!(policy.can_bump(&cart, policy.priority_for("order")));
+        assert!(!policy.can_bump(&cart, policy.priority_for("cart")));
+    }
+
+    #[test]
+    fn test_protected_and_confirmed_holds_are_never_bumped() {
+        let policy = ReservationPriorityPolicy::default().with_priority("vip", 500);
+
+        let checkout = reservation("checkout", 0);
+        assert!(!policy.can_bump(&checkout, 500));
+
+        let mut confirmed = reservation("cart", 0);
+        confirmed.confirm().unwrap();
+        assert!(!policy.can_bump(&confirmed, 500));
+    }
+}
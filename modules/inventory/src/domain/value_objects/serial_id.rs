@@ -0,0 +1,31 @@
+// SerialId value object - unique identifier for product serial numbers
+
+use serde::{Deserialize, Serialize};
+use uuid::{NoContext, Timestamp, Uuid};
+
+/// Unique identifier for a ProductSerial
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct SerialId(Uuid);
+
+impl SerialId {
+    /// Creates a new SerialId with a UUID v7 (time-ordered)
+    pub fn new() -> Self {
+        Self(Uuid::new_v7(Timestamp::now(NoContext)))
+    }
+
+    /// Creates a SerialId from an existing UUID
+    pub fn from_uuid(uuid: Uuid) -> Self {
+        Self(uuid)
+    }
+
+    /// Converts the SerialId into its underlying UUID
+    pub fn into_uuid(self) -> Uuid {
+        self.0
+    }
+}
+
+impl Default for SerialId {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,80 @@
+// SerialStatus enum - where a serialized unit is in its lifecycle
+
+use crate::InventoryError;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
+
+/// Status of a serialized unit
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SerialStatus {
+    /// Unit is in stock and can be sold
+    Available,
+    /// Unit was sold and is covered by the sale's warranty
+    Sold,
+    /// Unit came back on a credit note without being restocked
+    Returned,
+}
+
+impl SerialStatus {
+    /// Returns all serial statuses
+    pub fn all() -> &'static [SerialStatus] {
+        &[
+            SerialStatus::Available,
+            SerialStatus::Sold,
+            SerialStatus::Returned,
+        ]
+    }
+
+    /// Returns true if the unit can be put on a sale
+    pub fn is_available(&self) -> bool {
+        matches!(self, SerialStatus::Available)
+    }
+}
+
+impl FromStr for SerialStatus {
+    type Err = InventoryError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "available" => Ok(SerialStatus::Available),
+            "sold" => Ok(SerialStatus::Sold),
+            "returned" => Ok(SerialStatus::Returned),
+            _ => Err(InventoryError::InvalidSerialStatus),
+        }
+    }
+}
+
+impl fmt::Display for SerialStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SerialStatus::Available => write!(f, "available"),
+            SerialStatus::Sold => write!(f, "sold"),
+            SerialStatus::Returned => write!(f, "returned"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_round_trip() {
+        for status in SerialStatus::all() {
+            assert_eq!(
+                SerialStatus::from_str(&status.to_string()).unwrap(),
+                *status
+            );
+        }
+    }
+
+    #[test]
+    fn test_invalid() {
+        assert!(matches!(
+            SerialStatus::from_str("lost"),
+            Err(InventoryError::InvalidSerialStatus)
+        ));
+    }
+}
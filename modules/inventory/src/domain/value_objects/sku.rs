@@ -4,6 +4,9 @@ use chrono::Utc;
 use rand::Rng;
 use serde::{Deserialize, Serialize};
 
+/// Longest SKU the catalog tables store
+pub const MAX_SKU_LENGTH: usize = 50;
+
 /// Stock Keeping Unit - auto-generated unique product identifier
 /// Format: PRD-{CATEGORY_CODE}-{TIMESTAMP_BASE36}-{RANDOM}
 /// Example: PRD-ELC-1A2B3C-X7Y9
@@ -41,6 +44,18 @@ impl Sku {
         Self(format!("{}-V{:03}", parent_sku.0, variant_index))
     }
 
+    /// Generates a variant SKU from the codes of its attribute values
+    /// Format: {PARENT_SKU}-{CODE}-{CODE}...
+    /// Example: PRD-ELC-1A2B3C-X7Y9-M-RED
+    pub fn generate_variant_from_codes(parent_sku: &Sku, codes: &[&str]) -> Self {
+        let mut sku = parent_sku.0.clone();
+        for code in codes {
+            sku.push('-');
+            sku.push_str(code);
+        }
+        Self(sku)
+    }
+
     /// Reconstitutes a SKU from database (no validation needed, already validated on creation)
     pub fn from_string(value: String) -> Self {
         Self(value)
@@ -110,6 +125,13 @@ mod tests {
         assert_eq!(variant.as_str(), "PRD-ELE-ABC123-XY12-V001");
     }
 
+    #[test]
+    fn test_sku_generate_variant_from_codes() {
+        let parent = Sku::from_string("PRD-ELE-ABC123-XY12".to_string());
+        let variant = Sku::generate_variant_from_codes(&parent, &["M", "RED"]);
+        assert_eq!(variant.as_str(), "PRD-ELE-ABC123-XY12-M-RED");
+    }
+
     #[test]
     fn test_sku_uniqueness() {
         let sku1 = Sku::generate(Some("Test"));
@@ -0,0 +1,152 @@
+// TaxCategory enum - tax treatment assigned to categories and products
+
+use crate::InventoryError;
+use crate::domain::entities::ProductCategory;
+use crate::domain::value_objects::CategoryId;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
+
+/// Tax treatment of a product (Honduras ISV).
+///
+/// Set on a category it applies to every product below it in the tree;
+/// set on a product it overrides whatever the categories define. The string
+/// forms match the fiscal module's tax types.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TaxCategory {
+    /// General ISV rate (15%)
+    Isv15,
+    /// ISV rate for alcoholic beverages and tobacco (18%)
+    Isv18,
+    /// Exempt from ISV
+    Exempt,
+}
+
+impl TaxCategory {
+    /// Returns all available tax categories
+    pub fn all() -> &'static [TaxCategory] {
+        &[TaxCategory::Isv15, TaxCategory::Isv18, TaxCategory::Exempt]
+    }
+}
+
+impl FromStr for TaxCategory {
+    type Err = InventoryError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "isv15" | "isv_15" | "15" => Ok(TaxCategory::Isv15),
+            "isv18" | "isv_18" | "18" => Ok(TaxCategory::Isv18),
+            "exempt" | "exento" => Ok(TaxCategory::Exempt),
+            _ => Err(InventoryError::InvalidTaxCategory),
+        }
+    }
+}
+
+impl fmt::Display for TaxCategory {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TaxCategory::Isv15 => write!(f, "isv15"),
+            TaxCategory::Isv18 => write!(f, "isv18"),
+            TaxCategory::Exempt => write!(f, "exempt"),
+        }
+    }
+}
+
+/// Where a product's effective tax category comes from
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type", content = "category_id", rename_all = "snake_case")]
+pub enum TaxCategorySource {
+    /// Set on the product itself
+    Product,
+    /// Inherited from this category (the product's or an ancestor)
+    Category(CategoryId),
+}
+
+/// The tax category that applies to a product, and where it was defined
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EffectiveTaxCategory {
+    pub tax_category: TaxCategory,
+    pub source: TaxCategorySource,
+}
+
+impl EffectiveTaxCategory {
+    /// Resolves a product's tax category: its own override wins, otherwise
+    /// the nearest category in `category_chain` that defines one.
+    ///
+    /// `category_chain` starts at the product's category and walks up to
+    /// the root. Returns None when nothing in the chain defines a category.
+    pub fn resolve(
+        product_override: Option<TaxCategory>,
+        category_chain: &[ProductCategory],
+    ) -> Option<Self> {
+        if let Some(tax_category) = product_override {
+            return Some(Self {
+                tax_category,
+                source: TaxCategorySource::Product,
+            });
+        }
+
+        category_chain.iter().find_map(|category| {
+            category.tax_category().map(|tax_category| Self {
+                tax_category,
+                source: TaxCategorySource::Category(category.id()),
+            })
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn category(tax_category: Option<TaxCategory>) -> ProductCategory {
+        let mut c = ProductCategory::create("Category".to_string(), "category".to_string());
+        c.set_tax_category(tax_category);
+        c
+    }
+
+    #[test]
+    fn test_from_str_and_display() {
+        assert_eq!(TaxCategory::from_str("isv15").unwrap(), TaxCategory::Isv15);
+        assert_eq!(TaxCategory::from_str("ISV_18").unwrap(), TaxCategory::Isv18);
+        assert_eq!(
+            TaxCategory::from_str("exento").unwrap(),
+            TaxCategory::Exempt
+        );
+        assert!(matches!(
+            TaxCategory::from_str("vat"),
+            Err(InventoryError::InvalidTaxCategory)
+        ));
+        assert_eq!(TaxCategory::Isv18.to_string(), "isv18");
+    }
+
+    #[test]
+    fn test_inherits_from_nearest_category() {
+        let leaf = category(None);
+        let parent = category(Some(TaxCategory::Isv18));
+        let root = category(Some(TaxCategory::Isv15));
+        let parent_id = parent.id();
+
+        let effective = EffectiveTaxCategory::resolve(None, &[leaf, parent, root]).unwrap();
+
+        assert_eq!(effective.tax_category, TaxCategory::Isv18);
+        assert_eq!(effective.source, TaxCategorySource::Category(parent_id));
+    }
+
+    #[test]
+    fn test_product_override_wins() {
+        let chain = [category(Some(TaxCategory::Isv18))];
+
+        let effective = EffectiveTaxCategory::resolve(Some(TaxCategory::Exempt), &chain).unwrap();
+
+        assert_eq!(effective.tax_category, TaxCategory::Exempt);
+        assert_eq!(effective.source, TaxCategorySource::Product);
+    }
+
+    #[test]
+    fn test_nothing_defined() {
+        assert!(EffectiveTaxCategory::resolve(None, &[category(None), category(None)]).is_none());
+        assert!(EffectiveTaxCategory::resolve(None, &[]).is_none());
+    }
+}
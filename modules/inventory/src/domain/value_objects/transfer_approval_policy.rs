@@ -0,0 +1,152 @@
+// TransferApprovalPolicy value object - decides which stock transfers must be
+// approved before they can ship
+
+use std::collections::HashMap;
+
+use rust_decimal::Decimal;
+
+use crate::domain::entities::StockTransfer;
+use identity::StoreId;
+
+/// Approval rules for stock transfers.
+///
+/// A transfer needs approval when its value (requested quantity × unit cost)
+/// exceeds `value_threshold`, or when its source and destination stores are
+/// assigned to different regions. Stores without a region never count as
+/// cross-region. The default policy requires no approval.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TransferApprovalPolicy {
+    value_threshold: Option<Decimal>,
+    store_regions: HashMap<StoreId, String>,
+}
+
+impl TransferApprovalPolicy {
+    /// Policy that never requires approval
+    pub fn disabled() -> Self {
+        Self::default()
+    }
+
+    /// Requires approval for transfers worth more than `threshold`
+    pub fn with_value_threshold(mut self, threshold: Decimal) -> Self {
+        self.value_threshold = Some(threshold);
+        self
+    }
+
+    /// Assigns `store_id` to `region` for the cross-region rule
+    pub fn with_store_region(mut self, store_id: StoreId, region: impl Into<String>) -> Self {
+        self.store_regions.insert(store_id, region.into());
+        self
+    }
+
+    pub fn value_threshold(&self) -> Option<Decimal> {
+        self.value_threshold
+    }
+
+    pub fn region_of(&self, store_id: StoreId) -> Option<&str> {
+        self.store_regions.get(&store_id).map(String::as_str)
+    }
+
+    /// Returns why `transfer` needs approval, or `None` if it can ship
+    /// directly after submission
+    pub fn approval_reason(&self, transfer: &StockTransfer) -> Option<String> {
+        let mut reasons = Vec::new();
+
+        if let Some(threshold) = self.value_threshold {
+            let value = transfer.total_value();
+            if value > threshold {
+                reasons.push(format!(
+                    "transfer value {} exceeds threshold {}",
+                    value, threshold
+                ));
+            }
+        }
+
+        if let (Some(from), Some(to)) = (
+            self.region_of(transfer.from_store_id()),
+            self.region_of(transfer.to_store_id()),
+        ) && from != to
+        {
+            reasons.push(format!("cross-region transfer from {} to {}", from, to));
+        }
+
+        if reasons.is_empty() {
+            None
+        } else {
+            Some(reasons.join("; "))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::entities::TransferItem;
+    use crate::domain::value_objects::ProductId;
+    use identity::UserId;
+    use rust_decimal_macros::dec;
+
+    fn transfer(
+        from: StoreId,
+        to: StoreId,
+        quantity: Decimal,
+        unit_cost: Decimal,
+    ) -> StockTransfer {
+        let mut transfer =
+            StockTransfer::create("TRF-001".to_string(), from, to, UserId::new()).unwrap();
+        let item = TransferItem::create_for_product(
+            transfer.id(),
+            ProductId::new(),
+            quantity,
+            Some(unit_cost),
+        )
+        .unwrap();
+        transfer.add_item(item).unwrap();
+        transfer
+    }
+
+    #[test]
+    fn test_disabled_policy_never_requires_approval() {
+        let t = transfer(StoreId::new(), StoreId::new(), dec!(1000), dec!(1000));
+        assert!(
+            TransferApprovalPolicy::disabled()
+                .approval_reason(&t)
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn test_value_threshold() {
+        let policy = TransferApprovalPolicy::disabled().with_value_threshold(dec!(500));
+
+        let at_threshold = transfer(StoreId::new(), StoreId::new(), dec!(10), dec!(50));
+        assert!(policy.approval_reason(&at_threshold).is_none());
+
+        let above = transfer(StoreId::new(), StoreId::new(), dec!(11), dec!(50));
+        let reason = policy.approval_reason(&above).unwrap();
+        assert!(reason.contains("exceeds threshold"));
+    }
+
+    #[test]
+    fn test_cross_region() {
+        let (north, north2, south, unassigned) = (
+            StoreId::new(),
+            StoreId::new(),
+            StoreId::new(),
+            StoreId::new(),
+        );
+        let policy = TransferApprovalPolicy::disabled()
+            .with_store_region(north, "north")
+            .with_store_region(north2, "north")
+            .with_store_region(south, "south");
+
+        let same_region = transfer(north, north2, dec!(1), dec!(1));
+        assert!(policy.approval_reason(&same_region).is_none());
+
+        let no_region = transfer(north, unassigned, dec!(1), dec!(1));
+        assert!(policy.approval_reason(&no_region).is_none());
+
+        let cross = transfer(north, south, dec!(1), dec!(1));
+        let reason = policy.approval_reason(&cross).unwrap();
+        assert_eq!(reason, "cross-region transfer from north to south");
+    }
+}
@@ -11,12 +11,19 @@ use std::str::FromStr;
 pub enum TransferStatus {
     /// Initial state, can be edited
     Draft,
+    /// Submitted, but over the approval threshold or cross-region
+    PendingApproval,
+    /// Approved and waiting to be shipped
+    Approved,
     /// Submitted and waiting to be shipped
     Pending,
     /// Shipped, in transit to destination
     InTransit,
     /// Received and completed
     Completed,
+    /// Received, but short of what was shipped; the shortfall was written
+    /// off at the source store
+    ReceivedWithDiscrepancy,
     /// Cancelled
     Cancelled,
 }
@@ -26,9 +33,12 @@ impl TransferStatus {
     pub fn all() -> &'static [TransferStatus] {
         &[
             TransferStatus::Draft,
+            TransferStatus::PendingApproval,
+            TransferStatus::Approved,
             TransferStatus::Pending,
             TransferStatus::InTransit,
             TransferStatus::Completed,
+            TransferStatus::ReceivedWithDiscrepancy,
             TransferStatus::Cancelled,
         ]
     }
@@ -40,7 +50,12 @@ impl TransferStatus {
 
     /// Returns true if the transfer is in a final state
     pub fn is_final(&self) -> bool {
-        matches!(self, TransferStatus::Completed | TransferStatus::Cancelled)
+        matches!(
+            self,
+            TransferStatus::Completed
+                | TransferStatus::ReceivedWithDiscrepancy
+                | TransferStatus::Cancelled
+        )
     }
 
     /// Returns true if the transfer can be submitted
@@ -48,9 +63,14 @@ impl TransferStatus {
         matches!(self, TransferStatus::Draft)
     }
 
+    /// Returns true if the transfer can be approved or rejected
+    pub fn can_review(&self) -> bool {
+        matches!(self, TransferStatus::PendingApproval)
+    }
+
     /// Returns true if the transfer can be shipped
     pub fn can_ship(&self) -> bool {
-        matches!(self, TransferStatus::Pending)
+        matches!(self, TransferStatus::Pending | TransferStatus::Approved)
     }
 
     /// Returns true if the transfer can be received
@@ -60,7 +80,13 @@ impl TransferStatus {
 
     /// Returns true if the transfer can be cancelled
     pub fn can_cancel(&self) -> bool {
-        matches!(self, TransferStatus::Draft | TransferStatus::Pending)
+        matches!(
+            self,
+            TransferStatus::Draft
+                | TransferStatus::PendingApproval
+                | TransferStatus::Approved
+                | TransferStatus::Pending
+        )
     }
 }
 
@@ -70,9 +96,12 @@ impl FromStr for TransferStatus {
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         match s.to_lowercase().replace('-', "_").as_str() {
             "draft" => Ok(TransferStatus::Draft),
+            "pending_approval" | "pendingapproval" => Ok(TransferStatus::PendingApproval),
+            "approved" => Ok(TransferStatus::Approved),
             "pending" | "submitted" => Ok(TransferStatus::Pending),
             "in_transit" | "intransit" | "shipped" => Ok(TransferStatus::InTransit),
             "completed" | "received" => Ok(TransferStatus::Completed),
+            "received_with_discrepancy" => Ok(TransferStatus::ReceivedWithDiscrepancy),
             "cancelled" | "canceled" => Ok(TransferStatus::Cancelled),
             _ => Err(InventoryError::InvalidTransferStatus),
         }
@@ -83,9 +112,12 @@ impl fmt::Display for TransferStatus {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
             TransferStatus::Draft => write!(f, "draft"),
+            TransferStatus::PendingApproval => write!(f, "pending_approval"),
+            TransferStatus::Approved => write!(f, "approved"),
             TransferStatus::Pending => write!(f, "pending"),
             TransferStatus::InTransit => write!(f, "in_transit"),
             TransferStatus::Completed => write!(f, "completed"),
+            TransferStatus::ReceivedWithDiscrepancy => write!(f, "received_with_discrepancy"),
             TransferStatus::Cancelled => write!(f, "cancelled"),
         }
     }
@@ -153,6 +185,30 @@ mod tests {
     fn test_display() {
         assert_eq!(TransferStatus::Draft.to_string(), "draft");
         assert_eq!(TransferStatus::InTransit.to_string(), "in_transit");
+        assert_eq!(
+            TransferStatus::PendingApproval.to_string(),
+            "pending_approval"
+        );
+        assert_eq!(
+            TransferStatus::from_str("pending_approval").unwrap(),
+            TransferStatus::PendingApproval
+        );
+        assert_eq!(
+            TransferStatus::from_str("approved").unwrap(),
+            TransferStatus::Approved
+        );
+    }
+
+    #[test]
+    fn test_approval_states() {
+        assert!(TransferStatus::PendingApproval.can_review());
+        assert!(!TransferStatus::PendingApproval.can_ship());
+        assert!(TransferStatus::PendingApproval.can_cancel());
+
+        assert!(TransferStatus::Approved.can_ship());
+        assert!(TransferStatus::Approved.can_cancel());
+        assert!(!TransferStatus::Approved.can_review());
+        assert!(!TransferStatus::Pending.can_review());
     }
 
     #[test]
@@ -174,6 +230,17 @@ mod tests {
 
         // Final states
         assert!(TransferStatus::Completed.is_final());
+        assert!(TransferStatus::ReceivedWithDiscrepancy.is_final());
         assert!(TransferStatus::Cancelled.is_final());
     }
+
+    #[test]
+    fn test_display_round_trip() {
+        for status in TransferStatus::all() {
+            assert_eq!(
+                TransferStatus::from_str(&status.to_string()).unwrap(),
+                *status
+            );
+        }
+    }
 }
@@ -0,0 +1,112 @@
+// UnitConversion value object - how many of one unit make up another
+
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+use super::UnitOfMeasure;
+use crate::InventoryError;
+
+/// Conversion factor between two units of measure: one `from_unit` equals
+/// `factor` of `to_unit`, e.g. 1 case = 24 units.
+///
+/// Conversions fill the gaps the standard factors of [`UnitOfMeasure`] leave,
+/// typically packaging that varies per product.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct UnitConversion {
+    from_unit: UnitOfMeasure,
+    to_unit: UnitOfMeasure,
+    factor: Decimal,
+}
+
+impl UnitConversion {
+    /// Creates a conversion where one `from_unit` equals `factor` `to_unit`
+    ///
+    /// # Errors
+    /// * `InventoryError::InvalidUnitConversion` - If the factor is not
+    ///   positive or both units are the same
+    pub fn new(
+        from_unit: UnitOfMeasure,
+        to_unit: UnitOfMeasure,
+        factor: Decimal,
+    ) -> Result<Self, InventoryError> {
+        if factor <= Decimal::ZERO {
+            return Err(InventoryError::InvalidUnitConversion(
+                "factor must be positive".to_string(),
+            ));
+        }
+        if from_unit == to_unit {
+            return Err(InventoryError::InvalidUnitConversion(format!(
+                "cannot convert {} to itself",
+                from_unit
+            )));
+        }
+        Ok(Self {
+            from_unit,
+            to_unit,
+            factor,
+        })
+    }
+
+    pub fn from_unit(&self) -> UnitOfMeasure {
+        self.from_unit
+    }
+
+    pub fn to_unit(&self) -> UnitOfMeasure {
+        self.to_unit
+    }
+
+    pub fn factor(&self) -> Decimal {
+        self.factor
+    }
+
+    /// The same conversion read the other way round
+    pub fn inverse(&self) -> Self {
+        Self {
+            from_unit: self.to_unit,
+            to_unit: self.from_unit,
+            factor: Decimal::ONE / self.factor,
+        }
+    }
+
+    /// Converts a quantity in `from_unit` to `to_unit`
+    pub fn apply(&self, quantity: Decimal) -> Decimal {
+        quantity * self.factor
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    #[test]
+    fn test_apply_and_inverse() {
+        let case = UnitConversion::new(UnitOfMeasure::Case, UnitOfMeasure::Unit, dec!(24)).unwrap();
+        assert_eq!(case.apply(dec!(2)), dec!(48));
+
+        let inverse = case.inverse();
+        assert_eq!(inverse.from_unit(), UnitOfMeasure::Unit);
+        assert_eq!(inverse.to_unit(), UnitOfMeasure::Case);
+        assert_eq!(inverse.apply(dec!(48)).round_dp(6), dec!(2));
+    }
+
+    #[test]
+    fn test_rejects_non_positive_factor() {
+        for factor in [dec!(0), dec!(-1)] {
+            let result = UnitConversion::new(UnitOfMeasure::Case, UnitOfMeasure::Unit, factor);
+            assert!(matches!(
+                result,
+                Err(InventoryError::InvalidUnitConversion(_))
+            ));
+        }
+    }
+
+    #[test]
+    fn test_rejects_same_unit() {
+        let result = UnitConversion::new(UnitOfMeasure::Kg, UnitOfMeasure::Kg, dec!(1));
+        assert!(matches!(
+            result,
+            Err(InventoryError::InvalidUnitConversion(_))
+        ));
+    }
+}
@@ -1,6 +1,7 @@
 // UnitOfMeasure value object - enumeration of measurement units
 
 use crate::InventoryError;
+use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 use std::fmt;
 use std::str::FromStr;
@@ -20,6 +21,13 @@ pub enum UnitOfMeasure {
     Liter,
     /// Ounce
     Oz,
+    /// Gram
+    G,
+    /// Milliliter
+    Ml,
+    /// Case or pack; how many units it holds is set per product with a
+    /// [`UnitConversion`](super::UnitConversion)
+    Case,
 }
 
 impl UnitOfMeasure {
@@ -31,8 +39,56 @@ impl UnitOfMeasure {
             UnitOfMeasure::Lb,
             UnitOfMeasure::Liter,
             UnitOfMeasure::Oz,
+            UnitOfMeasure::G,
+            UnitOfMeasure::Ml,
+            UnitOfMeasure::Case,
         ]
     }
+
+    /// Unit this one converts through: kilograms for weights, liters for
+    /// volumes, and units for counted items. Cases only convert through a
+    /// product's own conversions.
+    pub fn base_unit(&self) -> UnitOfMeasure {
+        match self {
+            UnitOfMeasure::Kg | UnitOfMeasure::Lb | UnitOfMeasure::Oz | UnitOfMeasure::G => {
+                UnitOfMeasure::Kg
+            }
+            UnitOfMeasure::Liter | UnitOfMeasure::Ml => UnitOfMeasure::Liter,
+            UnitOfMeasure::Unit => UnitOfMeasure::Unit,
+            UnitOfMeasure::Case => UnitOfMeasure::Case,
+        }
+    }
+
+    /// How many base units one of this unit is
+    pub fn base_factor(&self) -> Decimal {
+        match self {
+            UnitOfMeasure::Lb => Decimal::new(45_359_237, 8),
+            UnitOfMeasure::Oz => Decimal::new(28_349_523_125, 12),
+            UnitOfMeasure::G | UnitOfMeasure::Ml => Decimal::new(1, 3),
+            UnitOfMeasure::Kg
+            | UnitOfMeasure::Liter
+            | UnitOfMeasure::Unit
+            | UnitOfMeasure::Case => Decimal::ONE,
+        }
+    }
+
+    /// Converts a quantity in this unit to `to`
+    ///
+    /// # Errors
+    /// * `InventoryError::IncompatibleUnits` - If the units don't share a
+    ///   base unit, e.g. a weight and a volume
+    pub fn convert(&self, quantity: Decimal, to: UnitOfMeasure) -> Result<Decimal, InventoryError> {
+        if *self == to {
+            return Ok(quantity);
+        }
+        if self.base_unit() != to.base_unit() {
+            return Err(InventoryError::IncompatibleUnits {
+                from: self.to_string(),
+                to: to.to_string(),
+            });
+        }
+        Ok(quantity * self.base_factor() / to.base_factor())
+    }
 }
 
 impl FromStr for UnitOfMeasure {
@@ -45,6 +101,11 @@ impl FromStr for UnitOfMeasure {
             "lb" | "lbs" | "pound" | "pounds" => Ok(UnitOfMeasure::Lb),
             "liter" | "liters" | "l" | "litre" | "litres" => Ok(UnitOfMeasure::Liter),
             "oz" | "ounce" | "ounces" => Ok(UnitOfMeasure::Oz),
+            "g" | "gram" | "grams" => Ok(UnitOfMeasure::G),
+            "ml" | "milliliter" | "milliliters" | "millilitre" | "millilitres" => {
+                Ok(UnitOfMeasure::Ml)
+            }
+            "case" | "cases" | "pack" | "packs" | "box" | "boxes" => Ok(UnitOfMeasure::Case),
             _ => Err(InventoryError::InvalidUnitOfMeasure),
         }
     }
@@ -58,6 +119,9 @@ impl fmt::Display for UnitOfMeasure {
             UnitOfMeasure::Lb => write!(f, "lb"),
             UnitOfMeasure::Liter => write!(f, "liter"),
             UnitOfMeasure::Oz => write!(f, "oz"),
+            UnitOfMeasure::G => write!(f, "g"),
+            UnitOfMeasure::Ml => write!(f, "ml"),
+            UnitOfMeasure::Case => write!(f, "case"),
         }
     }
 }
@@ -128,6 +192,70 @@ mod tests {
         assert_eq!(UnitOfMeasure::Lb.to_string(), "lb");
         assert_eq!(UnitOfMeasure::Liter.to_string(), "liter");
         assert_eq!(UnitOfMeasure::Oz.to_string(), "oz");
+        assert_eq!(UnitOfMeasure::G.to_string(), "g");
+        assert_eq!(UnitOfMeasure::Ml.to_string(), "ml");
+        assert_eq!(UnitOfMeasure::Case.to_string(), "case");
+    }
+
+    #[test]
+    fn test_display_round_trip() {
+        for unit in UnitOfMeasure::all() {
+            assert_eq!(UnitOfMeasure::from_str(&unit.to_string()).unwrap(), *unit);
+        }
+    }
+
+    #[test]
+    fn test_convert_within_base_unit() {
+        assert_eq!(
+            UnitOfMeasure::Lb
+                .convert(Decimal::ONE, UnitOfMeasure::Kg)
+                .unwrap(),
+            Decimal::new(45_359_237, 8)
+        );
+        assert_eq!(
+            UnitOfMeasure::Lb
+                .convert(Decimal::ONE, UnitOfMeasure::Oz)
+                .unwrap()
+                .round_dp(6),
+            Decimal::from(16)
+        );
+        assert_eq!(
+            UnitOfMeasure::Unit
+                .convert(Decimal::TWO, UnitOfMeasure::Unit)
+                .unwrap(),
+            Decimal::TWO
+        );
+        assert_eq!(
+            UnitOfMeasure::Kg
+                .convert(Decimal::ONE, UnitOfMeasure::G)
+                .unwrap(),
+            Decimal::from(1000)
+        );
+        assert_eq!(
+            UnitOfMeasure::Ml
+                .convert(Decimal::from(250), UnitOfMeasure::Liter)
+                .unwrap(),
+            Decimal::new(25, 2)
+        );
+    }
+
+    #[test]
+    fn test_convert_between_base_units_fails() {
+        let result = UnitOfMeasure::Kg.convert(Decimal::ONE, UnitOfMeasure::Liter);
+        assert!(matches!(
+            result,
+            Err(InventoryError::IncompatibleUnits { .. })
+        ));
+        assert!(
+            UnitOfMeasure::Unit
+                .convert(Decimal::ONE, UnitOfMeasure::Kg)
+                .is_err()
+        );
+        assert!(
+            UnitOfMeasure::Case
+                .convert(Decimal::ONE, UnitOfMeasure::Unit)
+                .is_err()
+        );
     }
 
     #[test]
@@ -0,0 +1,31 @@
+// ValuationSnapshotId value object - unique identifier for valuation snapshots
+
+use serde::{Deserialize, Serialize};
+use uuid::{NoContext, Timestamp, Uuid};
+
+/// Unique identifier for a ValuationSnapshot
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct ValuationSnapshotId(Uuid);
+
+impl ValuationSnapshotId {
+    /// Creates a new ValuationSnapshotId with a UUID v7 (time-ordered)
+    pub fn new() -> Self {
+        Self(Uuid::new_v7(Timestamp::now(NoContext)))
+    }
+
+    /// Creates a ValuationSnapshotId from an existing UUID
+    pub fn from_uuid(uuid: Uuid) -> Self {
+        Self(uuid)
+    }
+
+    /// Converts the ValuationSnapshotId into its underlying UUID
+    pub fn into_uuid(self) -> Uuid {
+        self.0
+    }
+}
+
+impl Default for ValuationSnapshotId {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -3,6 +3,7 @@
 //! This module defines all error types that can occur during inventory operations.
 //! Errors are categorized by domain area (products, stock, reservations, etc.).
 
+use chrono::NaiveDate;
 use thiserror::Error;
 use uuid::Uuid;
 
@@ -46,6 +47,18 @@ pub enum InventoryError {
     #[error("Parent category not found: {0}")]
     ParentCategoryNotFound(Uuid),
 
+    /// A category was placed under itself or one of its descendants.
+    #[error("A category cannot be placed under itself or one of its descendants")]
+    CategoryCycle,
+
+    /// Placing a category would make the tree deeper than allowed.
+    #[error("Category tree cannot be deeper than {0} levels")]
+    CategoryDepthExceeded(usize),
+
+    /// A reorder didn't list exactly the sibling categories.
+    #[error("Reorder must list every sibling category exactly once")]
+    InvalidCategoryOrder,
+
     // -------------------------------------------------------------------------
     // Product errors
     // -------------------------------------------------------------------------
@@ -61,6 +74,19 @@ pub enum InventoryError {
     #[error("Barcode '{0}' already exists")]
     DuplicateBarcode(String),
 
+    /// A row of a product import is missing or has an invalid field.
+    #[error("Invalid import row: {0}")]
+    InvalidImportRow(String),
+
+    /// A product export asked for an unknown column, or for stock columns
+    /// without a store.
+    #[error("Invalid export column: {0}")]
+    InvalidExportColumn(String),
+
+    /// A product search was given an empty query.
+    #[error("Search query cannot be empty")]
+    EmptySearchQuery,
+
     /// Attempted to create a variant for a product that doesn't have variants enabled.
     #[error("Product does not have variants enabled")]
     VariantsNotEnabled,
@@ -72,6 +98,14 @@ pub enum InventoryError {
     #[error("Variant not found: {0}")]
     VariantNotFound(Uuid),
 
+    /// The attribute axes for variant generation are invalid.
+    #[error("Invalid variant axes: {0}")]
+    InvalidVariantAxes(String),
+
+    /// Variant generation would create more combinations than allowed.
+    #[error("Too many variant combinations: {0} exceeds the limit of {1}")]
+    TooManyVariantCombinations(usize, usize),
+
     // -------------------------------------------------------------------------
     // Stock errors
     // -------------------------------------------------------------------------
@@ -100,6 +134,10 @@ pub enum InventoryError {
     #[error("Optimistic lock error: record was modified by another process")]
     OptimisticLockError,
 
+    /// Every attempt at a stock update hit a version conflict.
+    #[error("Stock update gave up after {attempts} conflicting attempts")]
+    ConcurrencyExhausted { attempts: u32 },
+
     /// A stock record already exists for this store and product/variant combination.
     #[error("Stock already exists for store {store_id} and product/variant")]
     StockAlreadyExists {
@@ -123,6 +161,37 @@ pub enum InventoryError {
     #[error("Invalid reservation status transition")]
     InvalidReservationStatus,
 
+    /// The auto-extend interval is not between 1 second and 1 day.
+    #[error("Auto-extend interval must be between 1 and 86400 seconds")]
+    InvalidAutoExtend,
+
+    /// The reservation was created without an auto-extend interval.
+    #[error("Reservation does not auto-extend")]
+    ReservationNotAutoExtending,
+
+    // -------------------------------------------------------------------------
+    // Serial number errors
+    // -------------------------------------------------------------------------
+    /// No unit of the product carries this serial number.
+    #[error("Serial number not found: {0}")]
+    SerialNotFound(String),
+
+    /// The serial number is already registered for the product.
+    #[error("Serial number '{0}' already exists")]
+    DuplicateSerial(String),
+
+    /// The unit is not in stock (already sold or returned).
+    #[error("Serial number '{0}' is not available")]
+    SerialNotAvailable(String),
+
+    /// Serial numbers must be non-blank and at most 100 characters.
+    #[error("Invalid serial number: must be 1-100 characters")]
+    InvalidSerialNumber,
+
+    /// Serial numbers were given for a product that is not serialized.
+    #[error("Product is not serialized: {0}")]
+    ProductNotSerialized(Uuid),
+
     // -------------------------------------------------------------------------
     // Recipe errors
     // -------------------------------------------------------------------------
@@ -171,6 +240,11 @@ pub enum InventoryError {
     #[error("Substitute not found: {0}")]
     SubstituteNotFound(Uuid),
 
+    /// Neither an ingredient nor any of its substitutes has enough stock to
+    /// make what is being sold. Carries the ingredient's name.
+    #[error("Ingredient unavailable: {0}")]
+    IngredientUnavailable(String),
+
     // -------------------------------------------------------------------------
     // Adjustment errors
     // -------------------------------------------------------------------------
@@ -201,6 +275,89 @@ pub enum InventoryError {
     #[error("Transfer has no items")]
     EmptyTransfer,
 
+    /// The transfer needs approval and has not been approved yet.
+    #[error("Transfer requires approval before shipping")]
+    TransferApprovalRequired,
+
+    /// A received quantity is negative or more than was shipped.
+    #[error(
+        "Received quantity for transfer item {0} must be between zero and the quantity shipped"
+    )]
+    InvalidReceivedQuantity(Uuid),
+
+    // -------------------------------------------------------------------------
+    // Reprice errors
+    // -------------------------------------------------------------------------
+    /// The requested reprice batch was not found.
+    #[error("Reprice batch not found: {0}")]
+    RepriceBatchNotFound(Uuid),
+
+    /// Cannot create a reprice batch with no items.
+    #[error("Reprice batch has no items")]
+    EmptyRepriceBatch,
+
+    /// A new unit cost below zero.
+    #[error("Cost cannot be negative")]
+    NegativeCost,
+
+    /// The markup rule type or percent is not valid.
+    #[error("Invalid markup rule: {0}")]
+    InvalidMarkupRule(String),
+
+    /// The bulk price adjustment type or target is not valid.
+    #[error("Invalid price adjustment: {0}")]
+    InvalidPriceAdjustment(String),
+
+    /// A store's reorder point or reorder quantity is out of range.
+    #[error("Invalid reorder level: {0}")]
+    InvalidReorderLevel(String),
+
+    /// A product-vendor link is not valid (unknown vendor, no preferred vendor).
+    #[error("Invalid product vendor: {0}")]
+    InvalidProductVendor(String),
+
+    // -------------------------------------------------------------------------
+    // Valuation snapshot errors
+    // -------------------------------------------------------------------------
+    /// No valuation snapshot was recorded for the store and period.
+    #[error("Valuation snapshot not found for store {0} and period ending {1}")]
+    ValuationSnapshotNotFound(Uuid, NaiveDate),
+
+    /// A snapshot was requested for a period that hasn't ended yet.
+    #[error("Cannot snapshot the period ending {0} before it has ended")]
+    ValuationPeriodNotEnded(NaiveDate),
+
+    // -------------------------------------------------------------------------
+    // Period close errors
+    // -------------------------------------------------------------------------
+    /// No inventory period exists with the given ID.
+    #[error("Inventory period not found: {0}")]
+    PeriodNotFound(Uuid),
+
+    /// A period's start date is after its end date.
+    #[error("Period start must not be after its end")]
+    InvalidPeriodRange,
+
+    /// A period shares days with another period of the same store.
+    #[error("Period overlaps the period from {0} to {1}")]
+    PeriodOverlap(NaiveDate, NaiveDate),
+
+    /// A period was closed before its last day had passed.
+    #[error("Cannot close the period ending {0} before it has ended")]
+    PeriodNotEnded(NaiveDate),
+
+    /// The period is already closed.
+    #[error("Inventory period {0} is already closed")]
+    PeriodAlreadyClosed(Uuid),
+
+    /// Only a closed period can be reopened.
+    #[error("Inventory period {0} is not closed")]
+    PeriodNotClosed(Uuid),
+
+    /// An operation is dated in a closed period.
+    #[error("The period from {0} to {1} is closed")]
+    PeriodClosed(NaiveDate, NaiveDate),
+
     // -------------------------------------------------------------------------
     // Workflow errors
     // -------------------------------------------------------------------------
@@ -219,14 +376,47 @@ pub enum InventoryError {
     #[error("Invalid barcode: maximum 100 characters")]
     InvalidBarcode,
 
+    /// A 13-digit barcode whose last digit is not its EAN-13 check digit.
+    #[error("Invalid EAN-13 barcode '{0}': wrong check digit")]
+    InvalidEan13CheckDigit(String),
+
+    /// The barcode prefix configured for generation is not valid.
+    #[error("Invalid barcode prefix: {0}")]
+    InvalidBarcodePrefix(String),
+
+    /// Every sequence value under the prefix has been used.
+    #[error("No barcodes left under prefix '{0}'")]
+    BarcodeSequenceExhausted(String),
+
     /// The provided unit of measure is not recognized.
     #[error("Invalid unit of measure")]
     InvalidUnitOfMeasure,
 
+    /// A quantity can't be converted between units with different base
+    /// units, such as a weight and a volume.
+    #[error("Cannot convert {from} to {to}")]
+    IncompatibleUnits { from: String, to: String },
+
+    /// A unit conversion factor is not positive or converts a unit to itself.
+    #[error("Invalid unit conversion: {0}")]
+    InvalidUnitConversion(String),
+
+    /// The `after_cursor` of a list query was not issued by this API.
+    #[error(transparent)]
+    InvalidPageCursor(#[from] common::InvalidPageCursor),
+
     /// The provided movement type is not recognized.
     #[error("Invalid movement type")]
     InvalidMovementType,
 
+    /// The provided tax category is not recognized.
+    #[error("Invalid tax category")]
+    InvalidTaxCategory,
+
+    /// The provided costing method is not recognized.
+    #[error("Invalid costing method")]
+    InvalidCostingMethod,
+
     /// The provided adjustment type is not recognized.
     #[error("Invalid adjustment type")]
     InvalidAdjustmentType,
@@ -243,6 +433,18 @@ pub enum InventoryError {
     #[error("Invalid reservation status")]
     InvalidReservationStatusValue,
 
+    /// Invalid serial status string.
+    #[error("Invalid serial status")]
+    InvalidSerialStatus,
+
+    /// The provided reprice batch status is not recognized.
+    #[error("Invalid reprice batch status")]
+    InvalidRepriceBatchStatus,
+
+    /// The provided period status is not recognized.
+    #[error("Invalid period status")]
+    InvalidPeriodStatus,
+
     /// The provided adjustment status is not recognized.
     #[error("Invalid adjustment status")]
     InvalidAdjustmentStatus,
@@ -265,6 +467,14 @@ pub enum InventoryError {
     // -------------------------------------------------------------------------
     // General errors
     // -------------------------------------------------------------------------
+    /// The caller lacks a permission the operation requires.
+    #[error("Missing required permissions: {}", missing.join(", "))]
+    Forbidden { missing: Vec<String> },
+
+    /// The caller is not assigned to the store whose data was requested.
+    #[error("Store not assigned to the user: {0}")]
+    StoreAccessDenied(Uuid),
+
     /// A general invalid operation that doesn't fit other error categories.
     #[error("Invalid operation: {0}")]
     InvalidOperation(String),
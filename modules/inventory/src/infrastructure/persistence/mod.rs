@@ -7,12 +7,19 @@
 //!
 //! - [`PgCategoryRepository`]: Product category persistence
 //! - [`PgProductRepository`]: Product catalog with cascade delete for variants
+//! - [`PgBarcodeSequenceRepository`]: Category barcode prefixes and their sequences
 //! - [`PgInventoryStockRepository`]: Stock records with optimistic locking
 //! - [`PgReservationRepository`]: Stock reservations with expiration queries
+//! - [`PgProductSerialRepository`]: Serialized units with status-guarded updates
+//! - [`PgInventoryLotRepository`]: Lots/batches consumed earliest-expiry first
 //! - [`PgInventoryMovementRepository`]: Kardex with weighted average cost calculation
 //! - [`PgRecipeRepository`]: Recipe/BOM persistence
 //! - [`PgAdjustmentRepository`]: Stock adjustments with number generation
 //! - [`PgTransferRepository`]: Inter-store transfers with number generation
+//! - [`PgRepriceBatchRepository`]: Batch reprices with their lines
+//! - [`PgPriceHistoryRepository`]: Append-only product price history
+//! - [`PgValuationSnapshotRepository`]: Period-end valuation snapshots with their lines
+//! - [`PgInventoryPeriodRepository`]: Open and closed inventory periods per store
 //!
 //! ## Usage
 //!
@@ -29,20 +36,38 @@
 //! ```
 
 mod pg_adjustment_repository;
+mod pg_barcode_sequence_repository;
 mod pg_category_repository;
+mod pg_inventory_lot_repository;
 mod pg_inventory_movement_repository;
+mod pg_inventory_period_repository;
 mod pg_inventory_stock_repository;
+mod pg_price_history_repository;
+mod pg_price_update_repository;
+mod pg_product_import_repository;
 mod pg_product_repository;
+mod pg_product_serial_repository;
 mod pg_recipe_repository;
+mod pg_reprice_batch_repository;
 mod pg_reservation_repository;
 mod pg_transfer_repository;
+mod pg_valuation_snapshot_repository;
 
 // Re-exports
 pub use pg_adjustment_repository::PgAdjustmentRepository;
+pub use pg_barcode_sequence_repository::PgBarcodeSequenceRepository;
 pub use pg_category_repository::PgCategoryRepository;
+pub use pg_inventory_lot_repository::PgInventoryLotRepository;
 pub use pg_inventory_movement_repository::PgInventoryMovementRepository;
+pub use pg_inventory_period_repository::PgInventoryPeriodRepository;
 pub use pg_inventory_stock_repository::PgInventoryStockRepository;
+pub use pg_price_history_repository::PgPriceHistoryRepository;
+pub use pg_price_update_repository::PgPriceUpdateRepository;
+pub use pg_product_import_repository::PgProductImportRepository;
 pub use pg_product_repository::PgProductRepository;
+pub use pg_product_serial_repository::PgProductSerialRepository;
 pub use pg_recipe_repository::PgRecipeRepository;
+pub use pg_reprice_batch_repository::PgRepriceBatchRepository;
 pub use pg_reservation_repository::PgReservationRepository;
 pub use pg_transfer_repository::PgTransferRepository;
+pub use pg_valuation_snapshot_repository::PgValuationSnapshotRepository;
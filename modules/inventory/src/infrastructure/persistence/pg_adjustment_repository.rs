@@ -1,8 +1,10 @@
 // PostgreSQL AdjustmentRepository implementation
 
 use async_trait::async_trait;
+use common::{DocumentNumberService, DocumentType};
 use rust_decimal::Decimal;
 use sqlx::PgPool;
+use uuid::Uuid;
 
 use crate::InventoryError;
 use crate::domain::entities::{AdjustmentItem, StockAdjustment};
@@ -15,12 +17,22 @@ use identity::{StoreId, UserId};
 /// PostgreSQL implementation of AdjustmentRepository
 pub struct PgAdjustmentRepository {
     pool: PgPool,
+    numbering: DocumentNumberService,
 }
 
 impl PgAdjustmentRepository {
     /// Creates a new PgAdjustmentRepository with the given connection pool
     pub fn new(pool: PgPool) -> Self {
-        Self { pool }
+        Self {
+            pool,
+            numbering: DocumentNumberService::default(),
+        }
+    }
+
+    /// Replaces the default document number formats
+    pub fn with_numbering(mut self, numbering: DocumentNumberService) -> Self {
+        self.numbering = numbering;
+        self
     }
 }
 
@@ -35,10 +47,10 @@ impl AdjustmentRepository for PgAdjustmentRepository {
             r#"
             INSERT INTO stock_adjustments (
                 id, store_id, adjustment_number, adjustment_type, adjustment_reason, status,
-                created_by_id, approved_by_id, approved_at, applied_at, notes, attachments,
-                created_at, updated_at
+                created_by_id, approved_by_id, approved_at, auto_approved, applied_at, notes,
+                attachments, created_at, updated_at
             )
-            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15)
             "#,
         )
         .bind(adjustment.id().into_uuid())
@@ -50,6 +62,7 @@ impl AdjustmentRepository for PgAdjustmentRepository {
         .bind(adjustment.created_by_id().into_uuid())
         .bind(adjustment.approved_by_id().map(|id| id.into_uuid()))
         .bind(adjustment.approved_at())
+        .bind(adjustment.auto_approved())
         .bind(adjustment.applied_at())
         .bind(adjustment.notes())
         .bind(adjustment.attachments())
@@ -92,8 +105,8 @@ impl AdjustmentRepository for PgAdjustmentRepository {
         let row = sqlx::query_as::<_, AdjustmentRow>(
             r#"
             SELECT id, store_id, adjustment_number, adjustment_type, adjustment_reason, status,
-                   created_by_id, approved_by_id, approved_at, applied_at, notes, attachments,
-                   created_at, updated_at
+                   created_by_id, approved_by_id, approved_at, auto_approved, applied_at, notes,
+                   attachments, created_at, updated_at
             FROM stock_adjustments
             WHERE id = $1
             "#,
@@ -113,8 +126,8 @@ impl AdjustmentRepository for PgAdjustmentRepository {
         let row = sqlx::query_as::<_, AdjustmentRow>(
             r#"
             SELECT id, store_id, adjustment_number, adjustment_type, adjustment_reason, status,
-                   created_by_id, approved_by_id, approved_at, applied_at, notes, attachments,
-                   created_at, updated_at
+                   created_by_id, approved_by_id, approved_at, auto_approved, applied_at, notes,
+                   attachments, created_at, updated_at
             FROM stock_adjustments
             WHERE id = $1
             "#,
@@ -152,8 +165,8 @@ impl AdjustmentRepository for PgAdjustmentRepository {
         let rows = sqlx::query_as::<_, AdjustmentRow>(
             r#"
             SELECT id, store_id, adjustment_number, adjustment_type, adjustment_reason, status,
-                   created_by_id, approved_by_id, approved_at, applied_at, notes, attachments,
-                   created_at, updated_at
+                   created_by_id, approved_by_id, approved_at, auto_approved, applied_at, notes,
+                   attachments, created_at, updated_at
             FROM stock_adjustments
             WHERE store_id = $1
             ORDER BY created_at DESC
@@ -240,42 +253,38 @@ impl AdjustmentRepository for PgAdjustmentRepository {
         &self,
         store_id: StoreId,
     ) -> Result<String, InventoryError> {
-        // Generate unique adjustment number per store: ADJ-{STORE_SHORT_ID}-{SEQUENCE}
-        // Use a sequence based on count of adjustments for this store
-        let count: (i64,) = sqlx::query_as(
-            r#"
-            SELECT COUNT(*) FROM stock_adjustments WHERE store_id = $1
-            "#,
-        )
-        .bind(store_id.as_uuid())
-        .fetch_one(&self.pool)
-        .await?;
-
-        let sequence = count.0 + 1;
-        let store_short = &store_id.as_uuid().to_string()[..8];
-        Ok(format!(
-            "ADJ-{}-{:06}",
-            store_short.to_uppercase(),
-            sequence
-        ))
+        let mut conn = self.pool.acquire().await?;
+        Ok(self
+            .numbering
+            .next_number(
+                &mut conn,
+                DocumentType::StockAdjustment,
+                Some(store_id.into_uuid()),
+            )
+            .await?)
     }
 
     async fn find_paginated(
         &self,
-        store_id: Option<StoreId>,
+        store_ids: Option<&[StoreId]>,
         status: Option<&str>,
+        after_id: Option<Uuid>,
         page: i64,
         page_size: i64,
     ) -> Result<(Vec<StockAdjustment>, i64), InventoryError> {
         // Build dynamic query based on filters
-        let offset = (page - 1) * page_size;
+        let offset = if after_id.is_some() {
+            0
+        } else {
+            (page - 1) * page_size
+        };
 
         // Count total matching records
         let mut count_query = String::from("SELECT COUNT(*) FROM stock_adjustments WHERE 1=1");
         let mut param_idx = 1;
 
-        if store_id.is_some() {
-            count_query.push_str(&format!(" AND store_id = ${}", param_idx));
+        if store_ids.is_some() {
+            count_query.push_str(&format!(" AND store_id = ANY(${})", param_idx));
             param_idx += 1;
         }
         if status.is_some() {
@@ -284,8 +293,9 @@ impl AdjustmentRepository for PgAdjustmentRepository {
 
         // Execute count query
         let mut count_builder = sqlx::query_scalar::<_, i64>(&count_query);
-        if let Some(sid) = &store_id {
-            count_builder = count_builder.bind(sid.as_uuid());
+        if let Some(ids) = store_ids {
+            let ids: Vec<Uuid> = ids.iter().map(|id| *id.as_uuid()).collect();
+            count_builder = count_builder.bind(ids);
         }
         if let Some(s) = status {
             count_builder = count_builder.bind(s);
@@ -295,35 +305,40 @@ impl AdjustmentRepository for PgAdjustmentRepository {
         // Build data query
         let mut data_query = String::from(
             r#"SELECT id, store_id, adjustment_number, adjustment_type, adjustment_reason, status,
-                   created_by_id, approved_by_id, approved_at, applied_at, notes, attachments,
-                   created_at, updated_at
+                   created_by_id, approved_by_id, approved_at, auto_approved, applied_at, notes,
+                   attachments, created_at, updated_at
             FROM stock_adjustments
             WHERE 1=1"#,
         );
 
         param_idx = 1;
-        if store_id.is_some() {
-            data_query.push_str(&format!(" AND store_id = ${}", param_idx));
+        if store_ids.is_some() {
+            data_query.push_str(&format!(" AND store_id = ANY(${})", param_idx));
             param_idx += 1;
         }
         if status.is_some() {
             data_query.push_str(&format!(" AND status = ${}", param_idx));
             param_idx += 1;
         }
-        data_query.push_str(&format!(
-            " ORDER BY created_at DESC LIMIT ${} OFFSET ${}",
-            param_idx,
-            param_idx + 1
-        ));
+        if after_id.is_some() {
+            data_query.push_str(&format!(" AND id < ${}", param_idx));
+            param_idx += 1;
+        }
+        data_query.push_str(" ORDER BY id DESC");
+        data_query.push_str(&format!(" LIMIT ${} OFFSET ${}", param_idx, param_idx + 1));
 
         // Execute data query
         let mut data_builder = sqlx::query_as::<_, AdjustmentRow>(&data_query);
-        if let Some(sid) = &store_id {
-            data_builder = data_builder.bind(sid.as_uuid());
+        if let Some(ids) = store_ids {
+            let ids: Vec<Uuid> = ids.iter().map(|id| *id.as_uuid()).collect();
+            data_builder = data_builder.bind(ids);
         }
         if let Some(s) = status {
             data_builder = data_builder.bind(s);
         }
+        if let Some(id) = after_id {
+            data_builder = data_builder.bind(id);
+        }
         data_builder = data_builder.bind(page_size).bind(offset);
 
         let rows = data_builder.fetch_all(&self.pool).await?;
@@ -353,6 +368,7 @@ struct AdjustmentRow {
     created_by_id: uuid::Uuid,
     approved_by_id: Option<uuid::Uuid>,
     approved_at: Option<chrono::DateTime<chrono::Utc>>,
+    auto_approved: bool,
     applied_at: Option<chrono::DateTime<chrono::Utc>>,
     notes: Option<String>,
     attachments: serde_json::Value,
@@ -383,6 +399,7 @@ impl AdjustmentRow {
             UserId::from_uuid(self.created_by_id),
             self.approved_by_id.map(UserId::from_uuid),
             self.approved_at,
+            self.auto_approved,
             self.applied_at,
             self.notes,
             self.attachments,
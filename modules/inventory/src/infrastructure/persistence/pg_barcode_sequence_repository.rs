@@ -0,0 +1,101 @@
+// PostgreSQL BarcodeSequenceRepository implementation
+
+use async_trait::async_trait;
+use sqlx::PgPool;
+
+use crate::InventoryError;
+use crate::domain::repositories::BarcodeSequenceRepository;
+use crate::domain::value_objects::{Barcode, BarcodePrefix, CategoryId};
+
+/// PostgreSQL implementation of BarcodeSequenceRepository
+pub struct PgBarcodeSequenceRepository {
+    pool: PgPool,
+}
+
+impl PgBarcodeSequenceRepository {
+    /// Creates a new PgBarcodeSequenceRepository with the given connection pool
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl BarcodeSequenceRepository for PgBarcodeSequenceRepository {
+    async fn find_prefix(
+        &self,
+        category_id: CategoryId,
+    ) -> Result<Option<BarcodePrefix>, InventoryError> {
+        let prefix: Option<String> = sqlx::query_scalar(
+            "SELECT prefix FROM category_barcode_prefixes WHERE category_id = $1",
+        )
+        .bind(category_id.into_uuid())
+        .fetch_optional(&self.pool)
+        .await?;
+
+        prefix.map(|p| BarcodePrefix::new(&p)).transpose()
+    }
+
+    async fn save_prefix(
+        &self,
+        category_id: CategoryId,
+        prefix: Option<&BarcodePrefix>,
+    ) -> Result<(), InventoryError> {
+        match prefix {
+            Some(prefix) => {
+                sqlx::query(
+                    r#"
+                    INSERT INTO category_barcode_prefixes (category_id, prefix)
+                    VALUES ($1, $2)
+                    ON CONFLICT (category_id) DO UPDATE
+                    SET prefix = EXCLUDED.prefix,
+                        updated_at = NOW()
+                    "#,
+                )
+                .bind(category_id.into_uuid())
+                .bind(prefix.as_str())
+                .execute(&self.pool)
+                .await?;
+            }
+            None => {
+                sqlx::query("DELETE FROM category_barcode_prefixes WHERE category_id = $1")
+                    .bind(category_id.into_uuid())
+                    .execute(&self.pool)
+                    .await?;
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn next_value(&self, prefix: &BarcodePrefix) -> Result<u64, InventoryError> {
+        let value: i64 = sqlx::query_scalar(
+            r#"
+            INSERT INTO barcode_sequences (prefix, last_value)
+            VALUES ($1, 1)
+            ON CONFLICT (prefix) DO UPDATE
+            SET last_value = barcode_sequences.last_value + 1,
+                updated_at = NOW()
+            RETURNING last_value
+            "#,
+        )
+        .bind(prefix.as_str())
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(value as u64)
+    }
+
+    async fn barcode_exists(&self, barcode: &Barcode) -> Result<bool, InventoryError> {
+        let exists: bool = sqlx::query_scalar(
+            r#"
+            SELECT EXISTS(SELECT 1 FROM products WHERE barcode = $1)
+                OR EXISTS(SELECT 1 FROM product_variants WHERE barcode = $1)
+            "#,
+        )
+        .bind(barcode.as_str())
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(exists)
+    }
+}
@@ -1,11 +1,13 @@
 // PostgreSQL CategoryRepository implementation
 
 use async_trait::async_trait;
+use identity::StoreId;
+use rust_decimal::Decimal;
 use sqlx::PgPool;
 
 use crate::InventoryError;
 use crate::domain::entities::ProductCategory;
-use crate::domain::repositories::CategoryRepository;
+use crate::domain::repositories::{CategoryRepository, CategoryStockValuation};
 use crate::domain::value_objects::CategoryId;
 
 /// PostgreSQL implementation of CategoryRepository
@@ -23,32 +25,13 @@ impl PgCategoryRepository {
 #[async_trait]
 impl CategoryRepository for PgCategoryRepository {
     async fn save(&self, category: &ProductCategory) -> Result<(), InventoryError> {
-        sqlx::query(
-            r#"
-            INSERT INTO product_categories (id, parent_id, name, description, slug, icon, sort_order, is_active, created_at, updated_at)
-            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10)
-            "#,
-        )
-        .bind(category.id().into_uuid())
-        .bind(category.parent_id().map(|id| id.into_uuid()))
-        .bind(category.name())
-        .bind(category.description())
-        .bind(category.slug())
-        .bind(category.icon())
-        .bind(category.sort_order())
-        .bind(category.is_active())
-        .bind(category.created_at())
-        .bind(category.updated_at())
-        .execute(&self.pool)
-        .await?;
-
-        Ok(())
+        Self::save_with(&self.pool, category).await
     }
 
     async fn find_by_id(&self, id: CategoryId) -> Result<Option<ProductCategory>, InventoryError> {
         let row = sqlx::query_as::<_, CategoryRow>(
             r#"
-            SELECT id, parent_id, name, description, slug, icon, sort_order, is_active, created_at, updated_at
+            SELECT id, parent_id, name, description, slug, icon, sort_order, is_active, tax_category, costing_method, created_at, updated_at
             FROM product_categories
             WHERE id = $1
             "#,
@@ -63,7 +46,7 @@ impl CategoryRepository for PgCategoryRepository {
     async fn find_by_slug(&self, slug: &str) -> Result<Option<ProductCategory>, InventoryError> {
         let row = sqlx::query_as::<_, CategoryRow>(
             r#"
-            SELECT id, parent_id, name, description, slug, icon, sort_order, is_active, created_at, updated_at
+            SELECT id, parent_id, name, description, slug, icon, sort_order, is_active, tax_category, costing_method, created_at, updated_at
             FROM product_categories
             WHERE slug = $1
             "#,
@@ -78,7 +61,7 @@ impl CategoryRepository for PgCategoryRepository {
     async fn find_root_categories(&self) -> Result<Vec<ProductCategory>, InventoryError> {
         let rows = sqlx::query_as::<_, CategoryRow>(
             r#"
-            SELECT id, parent_id, name, description, slug, icon, sort_order, is_active, created_at, updated_at
+            SELECT id, parent_id, name, description, slug, icon, sort_order, is_active, tax_category, costing_method, created_at, updated_at
             FROM product_categories
             WHERE parent_id IS NULL
             ORDER BY sort_order, name
@@ -96,7 +79,7 @@ impl CategoryRepository for PgCategoryRepository {
     ) -> Result<Vec<ProductCategory>, InventoryError> {
         let rows = sqlx::query_as::<_, CategoryRow>(
             r#"
-            SELECT id, parent_id, name, description, slug, icon, sort_order, is_active, created_at, updated_at
+            SELECT id, parent_id, name, description, slug, icon, sort_order, is_active, tax_category, costing_method, created_at, updated_at
             FROM product_categories
             WHERE parent_id = $1
             ORDER BY sort_order, name
@@ -112,7 +95,7 @@ impl CategoryRepository for PgCategoryRepository {
     async fn find_all_active(&self) -> Result<Vec<ProductCategory>, InventoryError> {
         let rows = sqlx::query_as::<_, CategoryRow>(
             r#"
-            SELECT id, parent_id, name, description, slug, icon, sort_order, is_active, created_at, updated_at
+            SELECT id, parent_id, name, description, slug, icon, sort_order, is_active, tax_category, costing_method, created_at, updated_at
             FROM product_categories
             WHERE is_active = TRUE
             ORDER BY COALESCE(parent_id, id), sort_order, name
@@ -128,7 +111,7 @@ impl CategoryRepository for PgCategoryRepository {
         let result = sqlx::query(
             r#"
             UPDATE product_categories
-            SET parent_id = $2, name = $3, description = $4, slug = $5, icon = $6, sort_order = $7, is_active = $8, updated_at = $9
+            SET parent_id = $2, name = $3, description = $4, slug = $5, icon = $6, sort_order = $7, is_active = $8, tax_category = $9, costing_method = $10, updated_at = $11
             WHERE id = $1
             "#,
         )
@@ -140,6 +123,8 @@ impl CategoryRepository for PgCategoryRepository {
         .bind(category.icon())
         .bind(category.sort_order())
         .bind(category.is_active())
+        .bind(category.tax_category().map(|t| t.to_string()))
+        .bind(category.costing_method().to_string())
         .bind(category.updated_at())
         .execute(&self.pool)
         .await?;
@@ -151,6 +136,57 @@ impl CategoryRepository for PgCategoryRepository {
         Ok(())
     }
 
+    async fn stock_valuation_by_category(
+        &self,
+        store_id: Option<StoreId>,
+    ) -> Result<Vec<CategoryStockValuation>, InventoryError> {
+        // Unit cost per stock record mirrors calculate_weighted_average_cost:
+        // weighted average of incoming movements that carry a unit cost.
+        let rows = sqlx::query_as::<_, CategoryStockValuationRow>(
+            r#"
+            WITH stock_cost AS (
+                SELECT stock_id,
+                       SUM(ABS(quantity) * COALESCE(unit_cost, 0)) / SUM(ABS(quantity)) AS unit_cost
+                FROM inventory_movements
+                WHERE movement_type = 'in'
+                  AND unit_cost IS NOT NULL
+                  AND quantity > 0
+                GROUP BY stock_id
+            ),
+            per_category AS (
+                SELECT COALESCE(p.category_id, vp.category_id)               AS category_id,
+                       SUM(s.quantity)                                       AS quantity,
+                       SUM(s.quantity * COALESCE(sc.unit_cost, 0))           AS total_value
+                FROM inventory_stock s
+                LEFT JOIN stock_cost sc ON sc.stock_id = s.id
+                LEFT JOIN products p ON p.id = s.product_id
+                LEFT JOIN product_variants v ON v.id = s.variant_id
+                LEFT JOIN products vp ON vp.id = v.product_id
+                WHERE s.quantity > 0
+                  AND ($1::UUID IS NULL OR s.store_id = $1)
+                GROUP BY 1
+            )
+            SELECT c.id                              AS category_id,
+                   c.parent_id,
+                   c.name,
+                   c.sort_order,
+                   COALESCE(pc.quantity, 0)          AS quantity,
+                   COALESCE(pc.total_value, 0)       AS total_value
+            FROM product_categories c
+            LEFT JOIN per_category pc ON pc.category_id = c.id
+            UNION ALL
+            SELECT NULL, NULL, 'Uncategorized', 0, quantity, total_value
+            FROM per_category
+            WHERE category_id IS NULL
+            "#,
+        )
+        .bind(store_id.map(|id| id.into_uuid()))
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows.into_iter().map(CategoryStockValuation::from).collect())
+    }
+
     async fn delete(&self, id: CategoryId) -> Result<(), InventoryError> {
         // First, orphan any child categories by setting their parent_id to NULL
         sqlx::query(
@@ -194,10 +230,51 @@ struct CategoryRow {
     icon: Option<String>,
     sort_order: i32,
     is_active: bool,
+    tax_category: Option<String>,
+    costing_method: String,
     created_at: chrono::DateTime<chrono::Utc>,
     updated_at: chrono::DateTime<chrono::Utc>,
 }
 
+// Transactional methods
+impl PgCategoryRepository {
+    /// Inserts a category within an existing transaction.
+    pub async fn save_in_tx(
+        tx: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+        category: &ProductCategory,
+    ) -> Result<(), InventoryError> {
+        Self::save_with(&mut **tx, category).await
+    }
+
+    async fn save_with<'e, E>(executor: E, category: &ProductCategory) -> Result<(), InventoryError>
+    where
+        E: sqlx::PgExecutor<'e>,
+    {
+        sqlx::query(
+            r#"
+            INSERT INTO product_categories (id, parent_id, name, description, slug, icon, sort_order, is_active, tax_category, costing_method, created_at, updated_at)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12)
+            "#,
+        )
+        .bind(category.id().into_uuid())
+        .bind(category.parent_id().map(|id| id.into_uuid()))
+        .bind(category.name())
+        .bind(category.description())
+        .bind(category.slug())
+        .bind(category.icon())
+        .bind(category.sort_order())
+        .bind(category.is_active())
+        .bind(category.tax_category().map(|t| t.to_string()))
+        .bind(category.costing_method().to_string())
+        .bind(category.created_at())
+        .bind(category.updated_at())
+        .execute(executor)
+        .await?;
+
+        Ok(())
+    }
+}
+
 impl From<CategoryRow> for ProductCategory {
     fn from(row: CategoryRow) -> Self {
         ProductCategory::reconstitute(
@@ -209,8 +286,35 @@ impl From<CategoryRow> for ProductCategory {
             row.icon,
             row.sort_order,
             row.is_active,
+            // Constrained by the column's CHECK, so parsing cannot fail
+            row.tax_category.and_then(|t| t.parse().ok()),
+            row.costing_method.parse().unwrap_or_default(),
             row.created_at,
             row.updated_at,
         )
     }
 }
+
+/// Internal row type for per-category stock valuation
+#[derive(sqlx::FromRow)]
+struct CategoryStockValuationRow {
+    category_id: Option<uuid::Uuid>,
+    parent_id: Option<uuid::Uuid>,
+    name: String,
+    sort_order: i32,
+    quantity: Decimal,
+    total_value: Decimal,
+}
+
+impl From<CategoryStockValuationRow> for CategoryStockValuation {
+    fn from(row: CategoryStockValuationRow) -> Self {
+        Self {
+            category_id: row.category_id.map(CategoryId::from_uuid),
+            parent_id: row.parent_id.map(CategoryId::from_uuid),
+            name: row.name,
+            sort_order: row.sort_order,
+            quantity: row.quantity,
+            total_value: row.total_value,
+        }
+    }
+}
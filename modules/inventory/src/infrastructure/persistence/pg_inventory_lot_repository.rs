@@ -0,0 +1,178 @@
+// PostgreSQL InventoryLotRepository implementation
+
+use async_trait::async_trait;
+use chrono::{DateTime, NaiveDate, Utc};
+use identity::StoreId;
+use rust_decimal::Decimal;
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::InventoryError;
+use crate::domain::entities::InventoryLot;
+use crate::domain::repositories::InventoryLotRepository;
+use crate::domain::value_objects::{LotId, ProductId, StockId, VariantId};
+
+/// PostgreSQL implementation of InventoryLotRepository
+pub struct PgInventoryLotRepository {
+    pool: PgPool,
+}
+
+impl PgInventoryLotRepository {
+    /// Creates a new PgInventoryLotRepository with the given connection pool
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    /// Adds received units to their lots within an existing transaction.
+    pub async fn receive_batch_in_tx(
+        tx: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+        lots: &[InventoryLot],
+    ) -> Result<(), InventoryError> {
+        for lot in lots {
+            sqlx::query(
+                r#"
+                INSERT INTO inventory_lots (
+                    id, product_id, variant_id, store_id, lot_number, expiry_date,
+                    received_quantity, remaining_quantity, received_at, updated_at
+                )
+                VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10)
+                ON CONFLICT ON CONSTRAINT uq_inventory_lots_key DO UPDATE
+                SET received_quantity = inventory_lots.received_quantity + EXCLUDED.received_quantity,
+                    remaining_quantity = inventory_lots.remaining_quantity + EXCLUDED.remaining_quantity,
+                    updated_at = EXCLUDED.updated_at
+                "#,
+            )
+            .bind(lot.id().into_uuid())
+            .bind(lot.product_id().into_uuid())
+            .bind(lot.variant_id().map(|v| v.into_uuid()))
+            .bind(lot.store_id().into_uuid())
+            .bind(lot.lot_number())
+            .bind(lot.expiry_date())
+            .bind(lot.received_quantity())
+            .bind(lot.remaining_quantity())
+            .bind(lot.received_at())
+            .bind(lot.updated_at())
+            .execute(&mut **tx)
+            .await?;
+        }
+
+        Ok(())
+    }
+
+    /// Takes `quantity` units of a stock record off its lots, earliest expiry
+    /// first (FEFO); lots without an expiry date go last. Units no lot covers,
+    /// e.g. stock received before lots were tracked, are ignored.
+    pub(crate) async fn consume_fefo_in_tx(
+        tx: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+        stock_id: StockId,
+        quantity: Decimal,
+    ) -> Result<(), InventoryError> {
+        let rows = sqlx::query_as::<_, InventoryLotRow>(
+            r#"
+            SELECT l.id, l.product_id, l.variant_id, l.store_id, l.lot_number, l.expiry_date,
+                   l.received_quantity, l.remaining_quantity, l.received_at, l.updated_at
+            FROM inventory_lots l
+            JOIN inventory_stock s ON s.store_id = l.store_id
+             AND (l.variant_id = s.variant_id
+                  OR (s.variant_id IS NULL AND l.variant_id IS NULL AND l.product_id = s.product_id))
+            WHERE s.id = $1 AND l.remaining_quantity > 0
+            ORDER BY l.expiry_date ASC NULLS LAST, l.received_at ASC, l.id ASC
+            FOR UPDATE OF l
+            "#,
+        )
+        .bind(stock_id.into_uuid())
+        .fetch_all(&mut **tx)
+        .await?;
+
+        let mut outstanding = quantity;
+        for row in rows {
+            if outstanding <= Decimal::ZERO {
+                break;
+            }
+            let mut lot = InventoryLot::from(row);
+            let taken = lot.consume(outstanding);
+            outstanding -= taken;
+
+            sqlx::query(
+                r#"
+                UPDATE inventory_lots
+                SET remaining_quantity = $2, updated_at = $3
+                WHERE id = $1
+                "#,
+            )
+            .bind(lot.id().into_uuid())
+            .bind(lot.remaining_quantity())
+            .bind(lot.updated_at())
+            .execute(&mut **tx)
+            .await?;
+        }
+
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl InventoryLotRepository for PgInventoryLotRepository {
+    async fn receive_batch(&self, lots: &[InventoryLot]) -> Result<(), InventoryError> {
+        let mut tx = self.pool.begin().await?;
+        Self::receive_batch_in_tx(&mut tx, lots).await?;
+        tx.commit().await?;
+        Ok(())
+    }
+
+    async fn find_expiring(
+        &self,
+        store_id: Option<StoreId>,
+        until: NaiveDate,
+    ) -> Result<Vec<InventoryLot>, InventoryError> {
+        let rows = sqlx::query_as::<_, InventoryLotRow>(
+            r#"
+            SELECT id, product_id, variant_id, store_id, lot_number, expiry_date,
+                   received_quantity, remaining_quantity, received_at, updated_at
+            FROM inventory_lots
+            WHERE remaining_quantity > 0
+              AND expiry_date <= $1
+              AND ($2::uuid IS NULL OR store_id = $2)
+            ORDER BY expiry_date ASC, received_at ASC
+            "#,
+        )
+        .bind(until)
+        .bind(store_id.map(|s| s.into_uuid()))
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows.into_iter().map(InventoryLot::from).collect())
+    }
+}
+
+/// Internal row type for mapping lot database results
+#[derive(sqlx::FromRow)]
+struct InventoryLotRow {
+    id: Uuid,
+    product_id: Uuid,
+    variant_id: Option<Uuid>,
+    store_id: Uuid,
+    lot_number: Option<String>,
+    expiry_date: Option<NaiveDate>,
+    received_quantity: Decimal,
+    remaining_quantity: Decimal,
+    received_at: DateTime<Utc>,
+    updated_at: DateTime<Utc>,
+}
+
+impl From<InventoryLotRow> for InventoryLot {
+    fn from(row: InventoryLotRow) -> Self {
+        InventoryLot::reconstitute(
+            LotId::from_uuid(row.id),
+            ProductId::from_uuid(row.product_id),
+            row.variant_id.map(VariantId::from_uuid),
+            StoreId::from_uuid(row.store_id),
+            row.lot_number,
+            row.expiry_date,
+            row.received_quantity,
+            row.remaining_quantity,
+            row.received_at,
+            row.updated_at,
+        )
+    }
+}
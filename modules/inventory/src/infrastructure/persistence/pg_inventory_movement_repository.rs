@@ -1,5 +1,7 @@
 // PostgreSQL InventoryMovementRepository implementation
 
+use std::collections::HashMap;
+
 use async_trait::async_trait;
 use chrono::{DateTime, Utc};
 use rust_decimal::Decimal;
@@ -8,8 +10,11 @@ use uuid::Uuid;
 
 use crate::InventoryError;
 use crate::domain::entities::InventoryMovement;
-use crate::domain::repositories::{InventoryMovementRepository, MovementQuery};
-use crate::domain::value_objects::{Currency, MovementId, MovementType, StockId};
+use crate::domain::repositories::{
+    AverageCostRewrite, CostLayer, InventoryMovementRepository, MovementQuery,
+};
+use crate::domain::value_objects::{CostingMethod, Currency, MovementId, MovementType, StockId};
+use crate::infrastructure::persistence::PgInventoryLotRepository;
 use identity::UserId;
 
 /// PostgreSQL implementation of InventoryMovementRepository
@@ -27,31 +32,9 @@ impl PgInventoryMovementRepository {
 #[async_trait]
 impl InventoryMovementRepository for PgInventoryMovementRepository {
     async fn save(&self, movement: &InventoryMovement) -> Result<(), InventoryError> {
-        sqlx::query(
-            r#"
-            INSERT INTO inventory_movements (
-                id, stock_id, movement_type, movement_reason, quantity, unit_cost, currency,
-                balance_after, reference_type, reference_id, actor_id, notes, metadata, created_at
-            )
-            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14)
-            "#,
-        )
-        .bind(movement.id().into_uuid())
-        .bind(movement.stock_id().into_uuid())
-        .bind(movement.movement_type().to_string())
-        .bind(movement.movement_reason())
-        .bind(movement.quantity())
-        .bind(movement.unit_cost())
-        .bind(movement.currency().as_str())
-        .bind(movement.balance_after())
-        .bind(movement.reference_type())
-        .bind(movement.reference_id())
-        .bind(movement.actor_id().into_uuid())
-        .bind(movement.notes())
-        .bind(movement.metadata())
-        .bind(movement.created_at())
-        .execute(&self.pool)
-        .await?;
+        let mut tx = self.pool.begin().await?;
+        Self::save_in_tx(&mut tx, movement).await?;
+        tx.commit().await?;
 
         Ok(())
     }
@@ -237,36 +220,9 @@ impl InventoryMovementRepository for PgInventoryMovementRepository {
             return Ok(());
         }
 
-        // 14 bind params per row; PG limit is 65,535. Chunk at 4,000 rows for safety.
-        const CHUNK_SIZE: usize = 4000;
-
-        for chunk in movements.chunks(CHUNK_SIZE) {
-            let mut query_builder = sqlx::QueryBuilder::new(
-                r#"INSERT INTO inventory_movements (
-                    id, stock_id, movement_type, movement_reason, quantity, unit_cost, currency,
-                    balance_after, reference_type, reference_id, actor_id, notes, metadata, created_at
-                ) "#,
-            );
-
-            query_builder.push_values(chunk, |mut b, movement| {
-                b.push_bind(movement.id().into_uuid())
-                    .push_bind(movement.stock_id().into_uuid())
-                    .push_bind(movement.movement_type().to_string())
-                    .push_bind(movement.movement_reason().map(|s| s.to_string()))
-                    .push_bind(movement.quantity())
-                    .push_bind(movement.unit_cost())
-                    .push_bind(movement.currency().as_str().to_string())
-                    .push_bind(movement.balance_after())
-                    .push_bind(movement.reference_type().map(|s| s.to_string()))
-                    .push_bind(movement.reference_id())
-                    .push_bind(movement.actor_id().into_uuid())
-                    .push_bind(movement.notes().map(|s| s.to_string()))
-                    .push_bind(movement.metadata().clone())
-                    .push_bind(movement.created_at());
-            });
-
-            query_builder.build().execute(&self.pool).await?;
-        }
+        let mut tx = self.pool.begin().await?;
+        Self::save_batch_in_tx(&mut tx, movements).await?;
+        tx.commit().await?;
 
         Ok(())
     }
@@ -298,6 +254,133 @@ impl InventoryMovementRepository for PgInventoryMovementRepository {
 
         Ok(result.and_then(|r| r.0))
     }
+
+    async fn find_all_by_stock_id_chronological(
+        &self,
+        stock_id: StockId,
+    ) -> Result<Vec<InventoryMovement>, InventoryError> {
+        let rows = sqlx::query_as::<_, MovementRow>(
+            r#"
+            SELECT id, stock_id, movement_type, movement_reason, quantity, unit_cost, currency,
+                   balance_after, reference_type, reference_id, actor_id, notes, metadata, created_at
+            FROM inventory_movements
+            WHERE stock_id = $1
+            ORDER BY created_at ASC, id ASC
+            "#,
+        )
+        .bind(stock_id.into_uuid())
+        .fetch_all(&self.pool)
+        .await?;
+
+        rows.into_iter().map(|r| r.try_into()).collect()
+    }
+
+    async fn rewrite_average_costs(
+        &self,
+        stock_id: StockId,
+        expected_version: i32,
+        movement_costs: &[(MovementId, Option<Decimal>)],
+        average_cost: Option<Decimal>,
+    ) -> Result<AverageCostRewrite, InventoryError> {
+        let mut tx = self.pool.begin().await?;
+
+        // Lock the stock record; a version change means movements were posted
+        // after the caller replayed the kardex.
+        let previous: Option<(Option<Decimal>,)> = sqlx::query_as(
+            r#"
+            SELECT average_cost
+            FROM inventory_stock
+            WHERE id = $1 AND version = $2
+            FOR UPDATE
+            "#,
+        )
+        .bind(stock_id.into_uuid())
+        .bind(expected_version)
+        .fetch_optional(&mut *tx)
+        .await?;
+
+        let Some((previous_average_cost,)) = previous else {
+            return Err(InventoryError::OptimisticLockError);
+        };
+
+        let ids: Vec<Uuid> = movement_costs
+            .iter()
+            .map(|(id, _)| id.into_uuid())
+            .collect();
+        let costs: Vec<Option<Decimal>> = movement_costs.iter().map(|(_, cost)| *cost).collect();
+
+        let result = sqlx::query(
+            r#"
+            UPDATE inventory_movements m
+            SET average_cost = v.average_cost
+            FROM UNNEST($2::uuid[], $3::numeric[]) AS v(id, average_cost)
+            WHERE m.id = v.id
+              AND m.stock_id = $1
+              AND m.average_cost IS DISTINCT FROM v.average_cost
+            "#,
+        )
+        .bind(stock_id.into_uuid())
+        .bind(&ids)
+        .bind(&costs)
+        .execute(&mut *tx)
+        .await?;
+
+        sqlx::query(
+            r#"
+            UPDATE inventory_stock
+            SET average_cost = $2
+            WHERE id = $1
+            "#,
+        )
+        .bind(stock_id.into_uuid())
+        .bind(average_cost)
+        .execute(&mut *tx)
+        .await?;
+
+        tx.commit().await?;
+
+        Ok(AverageCostRewrite {
+            previous_average_cost,
+            movements_rewritten: result.rows_affected() as i64,
+        })
+    }
+
+    async fn find_costing_method(
+        &self,
+        stock_id: StockId,
+    ) -> Result<CostingMethod, InventoryError> {
+        let mut methods = Self::find_costing_methods(&self.pool, &[stock_id.into_uuid()]).await?;
+        Ok(methods.remove(&stock_id.into_uuid()).unwrap_or_default())
+    }
+
+    async fn find_open_cost_layers(
+        &self,
+        stock_id: StockId,
+    ) -> Result<Vec<CostLayer>, InventoryError> {
+        let rows: Vec<(Uuid, Decimal, Decimal, DateTime<Utc>)> = sqlx::query_as(
+            r#"
+            SELECT movement_id, unit_cost, remaining_quantity, received_at
+            FROM inventory_cost_layers
+            WHERE stock_id = $1 AND remaining_quantity > 0
+            ORDER BY received_at ASC, id ASC
+            "#,
+        )
+        .bind(stock_id.into_uuid())
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows
+            .into_iter()
+            .map(
+                |(movement_id, unit_cost, remaining_quantity, received_at)| CostLayer {
+                    movement_id: MovementId::from_uuid(movement_id),
+                    unit_cost,
+                    remaining_quantity,
+                    received_at,
+                },
+            )
+            .collect())
+    }
 }
 
 // Transactional methods
@@ -333,7 +416,8 @@ impl PgInventoryMovementRepository {
         .execute(&mut **tx)
         .await?;
 
-        Ok(())
+        Self::apply_cost_layers_in_tx(tx, std::slice::from_ref(movement)).await?;
+        Self::consume_lots_in_tx(tx, std::slice::from_ref(movement)).await
     }
 
     /// Saves a batch of movements within an existing transaction.
@@ -375,8 +459,197 @@ impl PgInventoryMovementRepository {
             query_builder.build().execute(&mut **tx).await?;
         }
 
+        Self::apply_cost_layers_in_tx(tx, movements).await?;
+        Self::consume_lots_in_tx(tx, movements).await
+    }
+
+    /// Opens and consumes FIFO/LIFO cost layers for movements that were just
+    /// inserted. Stock under weighted-average categories has no layers.
+    ///
+    /// A costed receipt (in, transfer in or a positive adjustment) opens a
+    /// layer; an outgoing movement (out, transfer out or a negative
+    /// adjustment) consumes layers in the category's order. Units that no
+    /// layer covers, e.g. stock received before the category switched
+    /// method, are left for valuation to price at weighted average.
+    async fn apply_cost_layers_in_tx(
+        tx: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+        movements: &[InventoryMovement],
+    ) -> Result<(), InventoryError> {
+        let mut stock_ids: Vec<Uuid> = movements
+            .iter()
+            .filter(|m| opens_cost_layer(m) || is_stock_leaving(m))
+            .map(|m| m.stock_id().into_uuid())
+            .collect();
+        stock_ids.sort();
+        stock_ids.dedup();
+
+        if stock_ids.is_empty() {
+            return Ok(());
+        }
+
+        let methods = Self::find_costing_methods(&mut **tx, &stock_ids).await?;
+
+        for movement in movements {
+            let method = methods
+                .get(&movement.stock_id().into_uuid())
+                .copied()
+                .unwrap_or_default();
+            if !method.uses_layers() {
+                continue;
+            }
+
+            if opens_cost_layer(movement) {
+                sqlx::query(
+                    r#"
+                    INSERT INTO inventory_cost_layers (
+                        id, stock_id, movement_id, unit_cost, original_quantity,
+                        remaining_quantity, received_at
+                    )
+                    VALUES ($1, $2, $3, $4, $5, $5, $6)
+                    "#,
+                )
+                .bind(Uuid::now_v7())
+                .bind(movement.stock_id().into_uuid())
+                .bind(movement.id().into_uuid())
+                .bind(movement.unit_cost())
+                .bind(movement.quantity())
+                .bind(movement.created_at())
+                .execute(&mut **tx)
+                .await?;
+            } else if is_stock_leaving(movement) {
+                Self::consume_cost_layers_in_tx(
+                    tx,
+                    movement.stock_id(),
+                    movement.quantity().abs(),
+                    method,
+                )
+                .await?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Takes the units of outgoing movements off their lots, earliest
+    /// expiry first (FEFO)
+    async fn consume_lots_in_tx(
+        tx: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+        movements: &[InventoryMovement],
+    ) -> Result<(), InventoryError> {
+        for movement in movements.iter().filter(|m| is_stock_leaving(m)) {
+            PgInventoryLotRepository::consume_fefo_in_tx(
+                tx,
+                movement.stock_id(),
+                movement.quantity().abs(),
+            )
+            .await?;
+        }
         Ok(())
     }
+
+    /// Takes `quantity` units out of the open layers of a stock record,
+    /// oldest first for FIFO and newest first for LIFO.
+    async fn consume_cost_layers_in_tx(
+        tx: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+        stock_id: StockId,
+        quantity: Decimal,
+        method: CostingMethod,
+    ) -> Result<(), InventoryError> {
+        let order = if method.consumes_oldest_first() {
+            "ASC"
+        } else {
+            "DESC"
+        };
+        let layers: Vec<(Uuid, Decimal)> = sqlx::query_as(&format!(
+            r#"
+            SELECT id, remaining_quantity
+            FROM inventory_cost_layers
+            WHERE stock_id = $1 AND remaining_quantity > 0
+            ORDER BY received_at {order}, id {order}
+            FOR UPDATE
+            "#
+        ))
+        .bind(stock_id.into_uuid())
+        .fetch_all(&mut **tx)
+        .await?;
+
+        let mut outstanding = quantity;
+        for (layer_id, remaining) in layers {
+            if outstanding <= Decimal::ZERO {
+                break;
+            }
+            let taken = remaining.min(outstanding);
+            outstanding -= taken;
+
+            sqlx::query(
+                r#"
+                UPDATE inventory_cost_layers
+                SET remaining_quantity = remaining_quantity - $2
+                WHERE id = $1
+                "#,
+            )
+            .bind(layer_id)
+            .bind(taken)
+            .execute(&mut **tx)
+            .await?;
+        }
+
+        Ok(())
+    }
+
+    /// Looks up the costing method of each stock record through its product
+    /// (or its variant's product) and that product's category.
+    async fn find_costing_methods<'e, E>(
+        executor: E,
+        stock_ids: &[Uuid],
+    ) -> Result<HashMap<Uuid, CostingMethod>, InventoryError>
+    where
+        E: sqlx::PgExecutor<'e>,
+    {
+        let rows: Vec<(Uuid, Option<String>)> = sqlx::query_as(
+            r#"
+            SELECT s.id, c.costing_method
+            FROM inventory_stock s
+            LEFT JOIN product_variants v ON v.id = s.variant_id
+            LEFT JOIN products p ON p.id = COALESCE(s.product_id, v.product_id)
+            LEFT JOIN product_categories c ON c.id = p.category_id
+            WHERE s.id = ANY($1)
+            "#,
+        )
+        .bind(stock_ids)
+        .fetch_all(executor)
+        .await?;
+
+        rows.into_iter()
+            .map(|(stock_id, method)| {
+                let method = method.map(|m| m.parse()).transpose()?.unwrap_or_default();
+                Ok((stock_id, method))
+            })
+            .collect()
+    }
+}
+
+/// Costed receipts open a FIFO/LIFO layer
+fn opens_cost_layer(movement: &InventoryMovement) -> bool {
+    movement.quantity() > Decimal::ZERO
+        && movement.unit_cost().is_some()
+        && matches!(
+            movement.movement_type(),
+            MovementType::In | MovementType::TransferIn | MovementType::Adjustment
+        )
+}
+
+/// Stock leaving the store consumes FIFO/LIFO layers and lots; reservations
+/// only hold it
+fn is_stock_leaving(movement: &InventoryMovement) -> bool {
+    movement.quantity() < Decimal::ZERO
+        && matches!(
+            movement.movement_type(),
+            MovementType::Out
+                | MovementType::TransferOut
+                | MovementType::Adjustment
+                | MovementType::Backorder
+        )
 }
 
 /// Internal row type for mapping movement database results
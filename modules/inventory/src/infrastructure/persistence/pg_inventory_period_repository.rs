@@ -0,0 +1,198 @@
+// PostgreSQL InventoryPeriodRepository implementation
+
+use std::str::FromStr;
+
+use async_trait::async_trait;
+use chrono::{DateTime, NaiveDate, Utc};
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::InventoryError;
+use crate::domain::entities::InventoryPeriod;
+use crate::domain::repositories::InventoryPeriodRepository;
+use crate::domain::value_objects::{InventoryPeriodId, PeriodStatus};
+use identity::{StoreId, UserId};
+
+const PERIOD_COLUMNS: &str = "id, store_id, period_start, period_end, status, closed_by_id, \
+     closed_at, reopened_by_id, reopened_at, created_at, updated_at";
+
+/// PostgreSQL implementation of InventoryPeriodRepository
+pub struct PgInventoryPeriodRepository {
+    pool: PgPool,
+}
+
+impl PgInventoryPeriodRepository {
+    /// Creates a new PgInventoryPeriodRepository with the given connection pool
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl InventoryPeriodRepository for PgInventoryPeriodRepository {
+    async fn save(&self, period: &InventoryPeriod) -> Result<(), InventoryError> {
+        sqlx::query(
+            r#"
+            INSERT INTO inventory_periods (
+                id, store_id, period_start, period_end, status, closed_by_id,
+                closed_at, reopened_by_id, reopened_at, created_at, updated_at
+            )
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11)
+            "#,
+        )
+        .bind(period.id().into_uuid())
+        .bind(period.store_id().into_uuid())
+        .bind(period.period_start())
+        .bind(period.period_end())
+        .bind(period.status().to_string())
+        .bind(period.closed_by_id().map(|id| id.into_uuid()))
+        .bind(period.closed_at())
+        .bind(period.reopened_by_id().map(|id| id.into_uuid()))
+        .bind(period.reopened_at())
+        .bind(period.created_at())
+        .bind(period.updated_at())
+        .execute(&self.pool)
+        .await
+        .map_err(|e| match e {
+            sqlx::Error::Database(ref db) if db.constraint().is_some() => {
+                InventoryError::PeriodOverlap(period.period_start(), period.period_end())
+            }
+            e => InventoryError::Database(e),
+        })?;
+
+        Ok(())
+    }
+
+    async fn update(&self, period: &InventoryPeriod) -> Result<(), InventoryError> {
+        let result = sqlx::query(
+            r#"
+            UPDATE inventory_periods
+            SET status = $2,
+                closed_by_id = $3,
+                closed_at = $4,
+                reopened_by_id = $5,
+                reopened_at = $6,
+                updated_at = $7
+            WHERE id = $1
+            "#,
+        )
+        .bind(period.id().into_uuid())
+        .bind(period.status().to_string())
+        .bind(period.closed_by_id().map(|id| id.into_uuid()))
+        .bind(period.closed_at())
+        .bind(period.reopened_by_id().map(|id| id.into_uuid()))
+        .bind(period.reopened_at())
+        .bind(period.updated_at())
+        .execute(&self.pool)
+        .await?;
+
+        if result.rows_affected() == 0 {
+            return Err(InventoryError::PeriodNotFound(period.id().into_uuid()));
+        }
+
+        Ok(())
+    }
+
+    async fn find_by_id(
+        &self,
+        id: InventoryPeriodId,
+    ) -> Result<Option<InventoryPeriod>, InventoryError> {
+        let row = sqlx::query_as::<_, InventoryPeriodRow>(&format!(
+            "SELECT {PERIOD_COLUMNS} FROM inventory_periods WHERE id = $1"
+        ))
+        .bind(id.into_uuid())
+        .fetch_optional(&self.pool)
+        .await?;
+
+        row.map(InventoryPeriod::try_from).transpose()
+    }
+
+    async fn find_overlapping(
+        &self,
+        store_id: StoreId,
+        start: NaiveDate,
+        end: NaiveDate,
+    ) -> Result<Vec<InventoryPeriod>, InventoryError> {
+        let rows = sqlx::query_as::<_, InventoryPeriodRow>(&format!(
+            "SELECT {PERIOD_COLUMNS} FROM inventory_periods \
+             WHERE store_id = $1 AND period_start <= $3 AND period_end >= $2 \
+             ORDER BY period_start"
+        ))
+        .bind(store_id.into_uuid())
+        .bind(start)
+        .bind(end)
+        .fetch_all(&self.pool)
+        .await?;
+
+        rows.into_iter().map(InventoryPeriod::try_from).collect()
+    }
+
+    async fn find_closed_containing(
+        &self,
+        store_id: StoreId,
+        date: NaiveDate,
+    ) -> Result<Option<InventoryPeriod>, InventoryError> {
+        let row = sqlx::query_as::<_, InventoryPeriodRow>(&format!(
+            "SELECT {PERIOD_COLUMNS} FROM inventory_periods \
+             WHERE store_id = $1 AND status = 'closed' \
+               AND period_start <= $2 AND period_end >= $2"
+        ))
+        .bind(store_id.into_uuid())
+        .bind(date)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        row.map(InventoryPeriod::try_from).transpose()
+    }
+
+    async fn find_by_store(
+        &self,
+        store_id: StoreId,
+    ) -> Result<Vec<InventoryPeriod>, InventoryError> {
+        let rows = sqlx::query_as::<_, InventoryPeriodRow>(&format!(
+            "SELECT {PERIOD_COLUMNS} FROM inventory_periods \
+             WHERE store_id = $1 ORDER BY period_start DESC"
+        ))
+        .bind(store_id.into_uuid())
+        .fetch_all(&self.pool)
+        .await?;
+
+        rows.into_iter().map(InventoryPeriod::try_from).collect()
+    }
+}
+
+/// Internal row type for mapping period database results
+#[derive(sqlx::FromRow)]
+struct InventoryPeriodRow {
+    id: Uuid,
+    store_id: Uuid,
+    period_start: NaiveDate,
+    period_end: NaiveDate,
+    status: String,
+    closed_by_id: Option<Uuid>,
+    closed_at: Option<DateTime<Utc>>,
+    reopened_by_id: Option<Uuid>,
+    reopened_at: Option<DateTime<Utc>>,
+    created_at: DateTime<Utc>,
+    updated_at: DateTime<Utc>,
+}
+
+impl TryFrom<InventoryPeriodRow> for InventoryPeriod {
+    type Error = InventoryError;
+
+    fn try_from(row: InventoryPeriodRow) -> Result<Self, Self::Error> {
+        Ok(InventoryPeriod::reconstitute(
+            InventoryPeriodId::from_uuid(row.id),
+            StoreId::from_uuid(row.store_id),
+            row.period_start,
+            row.period_end,
+            PeriodStatus::from_str(&row.status)?,
+            row.closed_by_id.map(UserId::from_uuid),
+            row.closed_at,
+            row.reopened_by_id.map(UserId::from_uuid),
+            row.reopened_at,
+            row.created_at,
+            row.updated_at,
+        ))
+    }
+}
@@ -1,8 +1,11 @@
 // PostgreSQL InventoryStockRepository implementation with optimistic locking
 
 use async_trait::async_trait;
+use events::{OutboxEvent, enqueue_outbox_event_in_tx};
 use rust_decimal::Decimal;
+use serde_json::json;
 use sqlx::PgPool;
+use uuid::Uuid;
 
 use crate::InventoryError;
 use crate::domain::entities::InventoryStock;
@@ -29,9 +32,10 @@ impl InventoryStockRepository for PgInventoryStockRepository {
             r#"
             INSERT INTO inventory_stock (
                 id, store_id, product_id, variant_id, quantity, reserved_quantity,
-                version, min_stock_level, max_stock_level, created_at, updated_at
+                version, min_stock_level, max_stock_level, reorder_point, reorder_quantity,
+                created_at, updated_at
             )
-            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13)
             "#,
         )
         .bind(stock.id().into_uuid())
@@ -43,6 +47,8 @@ impl InventoryStockRepository for PgInventoryStockRepository {
         .bind(stock.version())
         .bind(stock.min_stock_level())
         .bind(stock.max_stock_level())
+        .bind(stock.reorder_point())
+        .bind(stock.reorder_quantity())
         .bind(stock.created_at())
         .bind(stock.updated_at())
         .execute(&self.pool)
@@ -55,7 +61,8 @@ impl InventoryStockRepository for PgInventoryStockRepository {
         let row = sqlx::query_as::<_, StockRow>(
             r#"
             SELECT id, store_id, product_id, variant_id, quantity, reserved_quantity,
-                   version, min_stock_level, max_stock_level, created_at, updated_at
+                   version, min_stock_level, max_stock_level, reorder_point, reorder_quantity,
+                created_at, updated_at
             FROM inventory_stock
             WHERE id = $1
             "#,
@@ -75,7 +82,8 @@ impl InventoryStockRepository for PgInventoryStockRepository {
         let row = sqlx::query_as::<_, StockRow>(
             r#"
             SELECT id, store_id, product_id, variant_id, quantity, reserved_quantity,
-                   version, min_stock_level, max_stock_level, created_at, updated_at
+                   version, min_stock_level, max_stock_level, reorder_point, reorder_quantity,
+                created_at, updated_at
             FROM inventory_stock
             WHERE store_id = $1 AND product_id = $2 AND variant_id IS NULL
             "#,
@@ -96,7 +104,8 @@ impl InventoryStockRepository for PgInventoryStockRepository {
         let row = sqlx::query_as::<_, StockRow>(
             r#"
             SELECT id, store_id, product_id, variant_id, quantity, reserved_quantity,
-                   version, min_stock_level, max_stock_level, created_at, updated_at
+                   version, min_stock_level, max_stock_level, reorder_point, reorder_quantity,
+                created_at, updated_at
             FROM inventory_stock
             WHERE store_id = $1 AND variant_id = $2 AND product_id IS NULL
             "#,
@@ -114,34 +123,9 @@ impl InventoryStockRepository for PgInventoryStockRepository {
         stock: &InventoryStock,
         expected_version: i32,
     ) -> Result<(), InventoryError> {
-        // Use optimistic locking: only update if version matches
-        let result = sqlx::query(
-            r#"
-            UPDATE inventory_stock
-            SET quantity = $2,
-                reserved_quantity = $3,
-                version = $4,
-                min_stock_level = $5,
-                max_stock_level = $6,
-                updated_at = $7
-            WHERE id = $1 AND version = $8
-            "#,
-        )
-        .bind(stock.id().into_uuid())
-        .bind(stock.quantity())
-        .bind(stock.reserved_quantity())
-        .bind(stock.version())
-        .bind(stock.min_stock_level())
-        .bind(stock.max_stock_level())
-        .bind(stock.updated_at())
-        .bind(expected_version)
-        .execute(&self.pool)
-        .await?;
-
-        // If no rows were affected, the version didn't match (concurrent modification)
-        if result.rows_affected() == 0 {
-            return Err(InventoryError::OptimisticLockError);
-        }
+        let mut tx = self.pool.begin().await?;
+        Self::update_with_version_in_tx(&mut tx, stock, expected_version).await?;
+        tx.commit().await?;
 
         Ok(())
     }
@@ -153,9 +137,10 @@ impl InventoryStockRepository for PgInventoryStockRepository {
         let rows = sqlx::query_as::<_, StockRow>(
             r#"
             SELECT id, store_id, product_id, variant_id, quantity, reserved_quantity,
-                   version, min_stock_level, max_stock_level, created_at, updated_at
+                   version, min_stock_level, max_stock_level, reorder_point, reorder_quantity,
+                created_at, updated_at
             FROM inventory_stock
-            WHERE store_id = $1 AND (quantity - reserved_quantity) <= min_stock_level
+            WHERE store_id = $1 AND (quantity - reserved_quantity) <= COALESCE(reorder_point, min_stock_level)
             ORDER BY (quantity - reserved_quantity) ASC
             "#,
         )
@@ -173,7 +158,8 @@ impl InventoryStockRepository for PgInventoryStockRepository {
         let rows = sqlx::query_as::<_, StockRow>(
             r#"
             SELECT id, store_id, product_id, variant_id, quantity, reserved_quantity,
-                   version, min_stock_level, max_stock_level, created_at, updated_at
+                   version, min_stock_level, max_stock_level, reorder_point, reorder_quantity,
+                created_at, updated_at
             FROM inventory_stock
             WHERE store_id = $1
             ORDER BY created_at DESC
@@ -189,25 +175,32 @@ impl InventoryStockRepository for PgInventoryStockRepository {
 
     async fn find_paginated(
         &self,
-        store_id: Option<StoreId>,
+        store_ids: Option<&[StoreId]>,
         product_id: Option<ProductId>,
         low_stock_only: bool,
+        after_id: Option<Uuid>,
         page: i64,
         page_size: i64,
     ) -> Result<(Vec<InventoryStock>, i64), InventoryError> {
-        let offset = (page - 1) * page_size;
+        let offset = if after_id.is_some() {
+            0
+        } else {
+            (page - 1) * page_size
+        };
 
         // Helper: appends WHERE/AND conditions using QueryBuilder
         fn push_filters(
             qb: &mut sqlx::QueryBuilder<'_, sqlx::Postgres>,
-            store_id: Option<StoreId>,
+            store_ids: Option<&[StoreId]>,
             product_id: Option<ProductId>,
             low_stock_only: bool,
         ) {
             let mut has_condition = false;
-            if let Some(sid) = store_id {
-                qb.push(" WHERE store_id = ");
-                qb.push_bind(sid.into_uuid());
+            if let Some(ids) = store_ids {
+                let ids: Vec<Uuid> = ids.iter().map(|id| *id.as_uuid()).collect();
+                qb.push(" WHERE store_id = ANY(");
+                qb.push_bind(ids);
+                qb.push(")");
                 has_condition = true;
             }
             if let Some(pid) = product_id {
@@ -218,23 +211,32 @@ impl InventoryStockRepository for PgInventoryStockRepository {
             }
             if low_stock_only {
                 qb.push(if has_condition { " AND " } else { " WHERE " });
-                qb.push("(quantity - reserved_quantity) <= min_stock_level");
+                qb.push(
+                    "(quantity - reserved_quantity) <= COALESCE(reorder_point, min_stock_level)",
+                );
             }
         }
 
         // Count query
         let mut count_qb = sqlx::QueryBuilder::new("SELECT COUNT(*) as count FROM inventory_stock");
-        push_filters(&mut count_qb, store_id, product_id, low_stock_only);
+        push_filters(&mut count_qb, store_ids, product_id, low_stock_only);
         let (total,): (i64,) = count_qb.build_query_as().fetch_one(&self.pool).await?;
 
         // Data query
         let mut data_qb = sqlx::QueryBuilder::new(
             r#"SELECT id, store_id, product_id, variant_id, quantity, reserved_quantity,
-                   version, min_stock_level, max_stock_level, created_at, updated_at
+                   version, min_stock_level, max_stock_level, reorder_point, reorder_quantity,
+                created_at, updated_at
             FROM inventory_stock"#,
         );
-        push_filters(&mut data_qb, store_id, product_id, low_stock_only);
-        data_qb.push(" ORDER BY created_at DESC LIMIT ");
+        push_filters(&mut data_qb, store_ids, product_id, low_stock_only);
+        if let Some(id) = after_id {
+            let filtered = store_ids.is_some() || product_id.is_some() || low_stock_only;
+            data_qb.push(if filtered { " AND " } else { " WHERE " });
+            data_qb.push("id < ");
+            data_qb.push_bind(id);
+        }
+        data_qb.push(" ORDER BY id DESC LIMIT ");
         data_qb.push_bind(page_size);
         data_qb.push(" OFFSET ");
         data_qb.push_bind(offset);
@@ -254,7 +256,8 @@ impl InventoryStockRepository for PgInventoryStockRepository {
         let rows = sqlx::query_as::<_, StockRow>(
             r#"
             SELECT id, store_id, product_id, variant_id, quantity, reserved_quantity,
-                   version, min_stock_level, max_stock_level, created_at, updated_at
+                   version, min_stock_level, max_stock_level, reorder_point, reorder_quantity,
+                created_at, updated_at
             FROM inventory_stock
             WHERE product_id = $1
             ORDER BY store_id, created_at DESC
@@ -271,7 +274,8 @@ impl InventoryStockRepository for PgInventoryStockRepository {
         let rows = sqlx::query_as::<_, StockRow>(
             r#"
             SELECT id, store_id, product_id, variant_id, quantity, reserved_quantity,
-                   version, min_stock_level, max_stock_level, created_at, updated_at
+                   version, min_stock_level, max_stock_level, reorder_point, reorder_quantity,
+                created_at, updated_at
             FROM inventory_stock
             ORDER BY store_id, created_at DESC
             LIMIT 1000
@@ -287,9 +291,10 @@ impl InventoryStockRepository for PgInventoryStockRepository {
         let rows = sqlx::query_as::<_, StockRow>(
             r#"
             SELECT id, store_id, product_id, variant_id, quantity, reserved_quantity,
-                   version, min_stock_level, max_stock_level, created_at, updated_at
+                   version, min_stock_level, max_stock_level, reorder_point, reorder_quantity,
+                created_at, updated_at
             FROM inventory_stock
-            WHERE (quantity - reserved_quantity) <= min_stock_level
+            WHERE (quantity - reserved_quantity) <= COALESCE(reorder_point, min_stock_level)
             ORDER BY (quantity - reserved_quantity) ASC
             LIMIT 1000
             "#,
@@ -307,9 +312,10 @@ impl InventoryStockRepository for PgInventoryStockRepository {
         let rows = sqlx::query_as::<_, StockRow>(
             r#"
             SELECT id, store_id, product_id, variant_id, quantity, reserved_quantity,
-                   version, min_stock_level, max_stock_level, created_at, updated_at
+                   version, min_stock_level, max_stock_level, reorder_point, reorder_quantity,
+                created_at, updated_at
             FROM inventory_stock
-            WHERE store_id = $1 AND (quantity - reserved_quantity) <= min_stock_level
+            WHERE store_id = $1 AND (quantity - reserved_quantity) <= COALESCE(reorder_point, min_stock_level)
             ORDER BY (quantity - reserved_quantity) ASC
             "#,
         )
@@ -329,7 +335,8 @@ impl InventoryStockRepository for PgInventoryStockRepository {
         let rows = sqlx::query_as::<_, StockRow>(
             r#"
             SELECT id, store_id, product_id, variant_id, quantity, reserved_quantity,
-                   version, min_stock_level, max_stock_level, created_at, updated_at
+                   version, min_stock_level, max_stock_level, reorder_point, reorder_quantity,
+                created_at, updated_at
             FROM inventory_stock
             WHERE store_id = $1 AND product_id = ANY($2) AND variant_id IS NULL
             "#,
@@ -351,7 +358,8 @@ impl InventoryStockRepository for PgInventoryStockRepository {
         let rows = sqlx::query_as::<_, StockRow>(
             r#"
             SELECT id, store_id, product_id, variant_id, quantity, reserved_quantity,
-                   version, min_stock_level, max_stock_level, created_at, updated_at
+                   version, min_stock_level, max_stock_level, reorder_point, reorder_quantity,
+                created_at, updated_at
             FROM inventory_stock
             WHERE store_id = $1 AND variant_id = ANY($2) AND product_id IS NULL
             "#,
@@ -367,7 +375,8 @@ impl InventoryStockRepository for PgInventoryStockRepository {
 
 // Transactional methods
 impl PgInventoryStockRepository {
-    /// Updates stock with optimistic locking within an existing transaction.
+    /// Updates stock with optimistic locking within an existing transaction,
+    /// writing a `stock.updated` outbox event in the same transaction.
     pub async fn update_with_version_in_tx(
         tx: &mut sqlx::Transaction<'_, sqlx::Postgres>,
         stock: &InventoryStock,
@@ -381,8 +390,10 @@ impl PgInventoryStockRepository {
                 version = $4,
                 min_stock_level = $5,
                 max_stock_level = $6,
-                updated_at = $7
-            WHERE id = $1 AND version = $8
+                reorder_point = $7,
+                reorder_quantity = $8,
+                updated_at = $9
+            WHERE id = $1 AND version = $10
             "#,
         )
         .bind(stock.id().into_uuid())
@@ -391,6 +402,8 @@ impl PgInventoryStockRepository {
         .bind(stock.version())
         .bind(stock.min_stock_level())
         .bind(stock.max_stock_level())
+        .bind(stock.reorder_point())
+        .bind(stock.reorder_quantity())
         .bind(stock.updated_at())
         .bind(expected_version)
         .execute(&mut **tx)
@@ -400,10 +413,123 @@ impl PgInventoryStockRepository {
             return Err(InventoryError::OptimisticLockError);
         }
 
+        enqueue_outbox_event_in_tx(tx, &stock_updated_event(stock)).await?;
+
+        Ok(())
+    }
+
+    /// Inserts new stock records within an existing transaction.
+    pub async fn save_batch_in_tx(
+        tx: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+        stocks: &[InventoryStock],
+    ) -> Result<(), InventoryError> {
+        if stocks.is_empty() {
+            return Ok(());
+        }
+
+        const CHUNK_SIZE: usize = 4000;
+
+        for chunk in stocks.chunks(CHUNK_SIZE) {
+            let mut query_builder = sqlx::QueryBuilder::new(
+                r#"INSERT INTO inventory_stock (
+                    id, store_id, product_id, variant_id, quantity, reserved_quantity,
+                    version, min_stock_level, max_stock_level, reorder_point, reorder_quantity,
+                created_at, updated_at
+                ) "#,
+            );
+
+            query_builder.push_values(chunk, |mut b, stock| {
+                b.push_bind(stock.id().into_uuid())
+                    .push_bind(stock.store_id().into_uuid())
+                    .push_bind(stock.product_id().map(|id| id.into_uuid()))
+                    .push_bind(stock.variant_id().map(|id| id.into_uuid()))
+                    .push_bind(stock.quantity())
+                    .push_bind(stock.reserved_quantity())
+                    .push_bind(stock.version())
+                    .push_bind(stock.min_stock_level())
+                    .push_bind(stock.max_stock_level())
+                    .push_bind(stock.reorder_point())
+                    .push_bind(stock.reorder_quantity())
+                    .push_bind(stock.created_at())
+                    .push_bind(stock.updated_at());
+            });
+
+            query_builder.build().execute(&mut **tx).await?;
+        }
+
+        Ok(())
+    }
+
+    /// Updates the quantity of several stock records with optimistic locking
+    /// within an existing transaction.
+    ///
+    /// Each record is paired with the version it was read at. Fails with
+    /// `OptimisticLockError` if any of them changed since. A `stock.updated`
+    /// outbox event is written for every record.
+    pub async fn update_quantities_with_version_in_tx(
+        tx: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+        stocks: &[(InventoryStock, i32)],
+    ) -> Result<(), InventoryError> {
+        if stocks.is_empty() {
+            return Ok(());
+        }
+
+        const CHUNK_SIZE: usize = 8000;
+
+        for chunk in stocks.chunks(CHUNK_SIZE) {
+            let mut query_builder = sqlx::QueryBuilder::new(
+                r#"UPDATE inventory_stock AS s
+                SET quantity = v.quantity,
+                    version = v.version,
+                    updated_at = v.updated_at
+                FROM ("#,
+            );
+
+            query_builder.push_values(chunk, |mut b, (stock, expected_version)| {
+                b.push_bind(stock.id().into_uuid())
+                    .push_bind(stock.quantity())
+                    .push_bind(stock.version())
+                    .push_bind(stock.updated_at())
+                    .push_bind(*expected_version);
+            });
+            query_builder.push(
+                r#") AS v(id, quantity, version, updated_at, expected_version)
+                WHERE s.id = v.id AND s.version = v.expected_version"#,
+            );
+
+            let result = query_builder.build().execute(&mut **tx).await?;
+            if result.rows_affected() != chunk.len() as u64 {
+                return Err(InventoryError::OptimisticLockError);
+            }
+        }
+
+        for (stock, _) in stocks {
+            enqueue_outbox_event_in_tx(tx, &stock_updated_event(stock)).await?;
+        }
+
         Ok(())
     }
 }
 
+/// Builds the `stock.updated` outbox event carrying the stock's new levels
+fn stock_updated_event(stock: &InventoryStock) -> OutboxEvent {
+    OutboxEvent::create(
+        "inventory_stock",
+        stock.id().into_uuid().to_string(),
+        "stock.updated",
+        json!({
+            "stock_id": stock.id().into_uuid(),
+            "store_id": stock.store_id().into_uuid(),
+            "product_id": stock.product_id().map(|id| id.into_uuid()),
+            "variant_id": stock.variant_id().map(|id| id.into_uuid()),
+            "quantity": stock.quantity(),
+            "reserved_quantity": stock.reserved_quantity(),
+            "available_quantity": stock.available_quantity(),
+            "version": stock.version(),
+        }),
+    )
+}
+
 /// Internal row type for mapping stock database results
 #[derive(sqlx::FromRow)]
 struct StockRow {
@@ -416,6 +542,8 @@ struct StockRow {
     version: i32,
     min_stock_level: Decimal,
     max_stock_level: Option<Decimal>,
+    reorder_point: Option<Decimal>,
+    reorder_quantity: Option<Decimal>,
     created_at: chrono::DateTime<chrono::Utc>,
     updated_at: chrono::DateTime<chrono::Utc>,
 }
@@ -434,6 +562,8 @@ impl TryFrom<StockRow> for InventoryStock {
             row.version,
             row.min_stock_level,
             row.max_stock_level,
+            row.reorder_point,
+            row.reorder_quantity,
             row.created_at,
             row.updated_at,
         )
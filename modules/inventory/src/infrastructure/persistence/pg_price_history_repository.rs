@@ -0,0 +1,108 @@
+// PostgreSQL PriceHistoryRepository implementation
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::InventoryError;
+use crate::domain::entities::ProductPriceChange;
+use crate::domain::repositories::PriceHistoryRepository;
+use crate::domain::value_objects::{PriceChangeId, ProductId, RepriceBatchId};
+use identity::UserId;
+
+/// PostgreSQL implementation of PriceHistoryRepository
+pub struct PgPriceHistoryRepository {
+    pool: PgPool,
+}
+
+impl PgPriceHistoryRepository {
+    /// Creates a new PgPriceHistoryRepository with the given connection pool
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl PriceHistoryRepository for PgPriceHistoryRepository {
+    async fn save_batch(&self, changes: &[ProductPriceChange]) -> Result<(), InventoryError> {
+        let mut tx = self.pool.begin().await?;
+
+        for change in changes {
+            sqlx::query(
+                r#"
+                INSERT INTO product_price_history (
+                    id, product_id, old_cost, new_cost, old_price, new_price,
+                    reprice_batch_id, changed_by_id, changed_at
+                )
+                VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
+                "#,
+            )
+            .bind(change.id().into_uuid())
+            .bind(change.product_id().into_uuid())
+            .bind(change.old_cost())
+            .bind(change.new_cost())
+            .bind(change.old_price())
+            .bind(change.new_price())
+            .bind(change.reprice_batch_id().map(|id| id.into_uuid()))
+            .bind(change.changed_by_id().into_uuid())
+            .bind(change.changed_at())
+            .execute(&mut *tx)
+            .await?;
+        }
+
+        tx.commit().await?;
+        Ok(())
+    }
+
+    async fn find_by_product(
+        &self,
+        product_id: ProductId,
+    ) -> Result<Vec<ProductPriceChange>, InventoryError> {
+        let rows = sqlx::query_as::<_, PriceChangeRow>(
+            r#"
+            SELECT id, product_id, old_cost, new_cost, old_price, new_price,
+                   reprice_batch_id, changed_by_id, changed_at
+            FROM product_price_history
+            WHERE product_id = $1
+            ORDER BY changed_at DESC
+            "#,
+        )
+        .bind(product_id.into_uuid())
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows.into_iter().map(ProductPriceChange::from).collect())
+    }
+}
+
+/// Internal row type for mapping price history database results
+#[derive(sqlx::FromRow)]
+struct PriceChangeRow {
+    id: Uuid,
+    product_id: Uuid,
+    old_cost: Decimal,
+    new_cost: Decimal,
+    old_price: Decimal,
+    new_price: Decimal,
+    reprice_batch_id: Option<Uuid>,
+    changed_by_id: Uuid,
+    changed_at: DateTime<Utc>,
+}
+
+impl From<PriceChangeRow> for ProductPriceChange {
+    fn from(row: PriceChangeRow) -> Self {
+        ProductPriceChange::reconstitute(
+            PriceChangeId::from_uuid(row.id),
+            ProductId::from_uuid(row.product_id),
+            row.old_cost,
+            row.new_cost,
+            row.old_price,
+            row.new_price,
+            row.reprice_batch_id.map(RepriceBatchId::from_uuid),
+            UserId::from_uuid(row.changed_by_id),
+            row.changed_at,
+        )
+    }
+}
@@ -0,0 +1,43 @@
+// PostgreSQL PriceUpdateRepository implementation
+
+use async_trait::async_trait;
+use sqlx::PgPool;
+
+use crate::InventoryError;
+use crate::domain::repositories::{PriceUpdate, PriceUpdateRepository};
+use identity::PgAuditRepository;
+
+use super::PgProductRepository;
+
+/// PostgreSQL implementation of PriceUpdateRepository
+pub struct PgPriceUpdateRepository {
+    pool: PgPool,
+}
+
+impl PgPriceUpdateRepository {
+    /// Creates a new PgPriceUpdateRepository with the given connection pool
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl PriceUpdateRepository for PgPriceUpdateRepository {
+    async fn apply(&self, updates: &[PriceUpdate]) -> Result<(), InventoryError> {
+        if updates.is_empty() {
+            return Ok(());
+        }
+
+        let mut tx = self.pool.begin().await?;
+
+        for update in updates {
+            PgProductRepository::update_in_tx(&mut tx, &update.product).await?;
+            PgAuditRepository::save_in_tx(&mut tx, &update.audit_entry)
+                .await
+                .map_err(|e| InventoryError::AuditError(e.to_string()))?;
+        }
+
+        tx.commit().await?;
+        Ok(())
+    }
+}
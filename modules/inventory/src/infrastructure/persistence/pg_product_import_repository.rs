@@ -0,0 +1,51 @@
+// PostgreSQL ProductImportRepository implementation
+
+use async_trait::async_trait;
+use sqlx::PgPool;
+
+use crate::InventoryError;
+use crate::domain::repositories::{ProductImportBatch, ProductImportRepository};
+use identity::PgAuditRepository;
+
+use super::{PgCategoryRepository, PgProductRepository};
+
+/// PostgreSQL implementation of ProductImportRepository
+pub struct PgProductImportRepository {
+    pool: PgPool,
+}
+
+impl PgProductImportRepository {
+    /// Creates a new PgProductImportRepository with the given connection pool
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl ProductImportRepository for PgProductImportRepository {
+    async fn apply(&self, batch: &ProductImportBatch) -> Result<(), InventoryError> {
+        if batch.is_empty() {
+            return Ok(());
+        }
+
+        let mut tx = self.pool.begin().await?;
+
+        for category in &batch.categories {
+            PgCategoryRepository::save_in_tx(&mut tx, category).await?;
+        }
+        for product in &batch.created {
+            PgProductRepository::save_in_tx(&mut tx, product).await?;
+        }
+        for product in &batch.updated {
+            PgProductRepository::update_in_tx(&mut tx, product).await?;
+        }
+        for entry in &batch.audit_entries {
+            PgAuditRepository::save_in_tx(&mut tx, entry)
+                .await
+                .map_err(|e| InventoryError::AuditError(e.to_string()))?;
+        }
+
+        tx.commit().await?;
+        Ok(())
+    }
+}
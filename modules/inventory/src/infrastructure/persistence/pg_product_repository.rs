@@ -3,12 +3,14 @@
 use async_trait::async_trait;
 use rust_decimal::Decimal;
 use sqlx::PgPool;
+use uuid::Uuid;
 
 use crate::InventoryError;
-use crate::domain::entities::{Product, ProductVariant};
+use crate::domain::entities::{Product, ProductVariant, ProductVendor};
 use crate::domain::repositories::ProductRepository;
 use crate::domain::value_objects::{
-    Barcode, CategoryId, Currency, ProductId, Sku, UnitOfMeasure, VariantId,
+    Barcode, CategoryId, Currency, MarkupRule, ProductId, Sku, UnitConversion, UnitOfMeasure,
+    VariantId,
 };
 
 /// PostgreSQL implementation of ProductRepository
@@ -26,48 +28,16 @@ impl PgProductRepository {
 #[async_trait]
 impl ProductRepository for PgProductRepository {
     async fn save(&self, product: &Product) -> Result<(), InventoryError> {
-        sqlx::query(
-            r#"
-            INSERT INTO products (
-                id, sku, barcode, name, description, category_id, brand, unit_of_measure,
-                base_price, cost_price, currency, is_perishable, is_trackable, has_variants,
-                tax_rate, tax_included, attributes, is_active, created_at, updated_at
-            )
-            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15, $16, $17, $18, $19, $20)
-            "#,
-        )
-        .bind(product.id().into_uuid())
-        .bind(product.sku().as_str())
-        .bind(product.barcode().map(|b| b.as_str()))
-        .bind(product.name())
-        .bind(product.description())
-        .bind(product.category_id().map(|id| id.into_uuid()))
-        .bind(product.brand())
-        .bind(product.unit_of_measure().to_string())
-        .bind(product.base_price())
-        .bind(product.cost_price())
-        .bind(product.currency().as_str())
-        .bind(product.is_perishable())
-        .bind(product.is_trackable())
-        .bind(product.has_variants())
-        .bind(product.tax_rate())
-        .bind(product.tax_included())
-        .bind(product.attributes())
-        .bind(product.is_active())
-        .bind(product.created_at())
-        .bind(product.updated_at())
-        .execute(&self.pool)
-        .await?;
-
-        Ok(())
+        Self::save_with(&self.pool, product).await
     }
 
     async fn find_by_id(&self, id: ProductId) -> Result<Option<Product>, InventoryError> {
         let row = sqlx::query_as::<_, ProductRow>(
             r#"
-            SELECT id, sku, barcode, name, description, category_id, brand, unit_of_measure,
+            SELECT id, sku, barcode, barcode_generated, name, description, category_id, brand, unit_of_measure,
                    base_price, cost_price, currency, is_perishable, is_trackable, has_variants,
-                   tax_rate, tax_included, attributes, is_active, created_at, updated_at
+                   tax_rate, tax_included, tax_category, attributes, is_active, created_at, updated_at,
+                   is_serialized, markup_type, markup_percent
             FROM products
             WHERE id = $1
             "#,
@@ -82,9 +52,10 @@ impl ProductRepository for PgProductRepository {
     async fn find_by_sku(&self, sku: &Sku) -> Result<Option<Product>, InventoryError> {
         let row = sqlx::query_as::<_, ProductRow>(
             r#"
-            SELECT id, sku, barcode, name, description, category_id, brand, unit_of_measure,
+            SELECT id, sku, barcode, barcode_generated, name, description, category_id, brand, unit_of_measure,
                    base_price, cost_price, currency, is_perishable, is_trackable, has_variants,
-                   tax_rate, tax_included, attributes, is_active, created_at, updated_at
+                   tax_rate, tax_included, tax_category, attributes, is_active, created_at, updated_at,
+                   is_serialized, markup_type, markup_percent
             FROM products
             WHERE sku = $1
             "#,
@@ -99,9 +70,10 @@ impl ProductRepository for PgProductRepository {
     async fn find_by_barcode(&self, barcode: &Barcode) -> Result<Option<Product>, InventoryError> {
         let row = sqlx::query_as::<_, ProductRow>(
             r#"
-            SELECT id, sku, barcode, name, description, category_id, brand, unit_of_measure,
+            SELECT id, sku, barcode, barcode_generated, name, description, category_id, brand, unit_of_measure,
                    base_price, cost_price, currency, is_perishable, is_trackable, has_variants,
-                   tax_rate, tax_included, attributes, is_active, created_at, updated_at
+                   tax_rate, tax_included, tax_category, attributes, is_active, created_at, updated_at,
+                   is_serialized, markup_type, markup_percent
             FROM products
             WHERE barcode = $1
             "#,
@@ -114,43 +86,7 @@ impl ProductRepository for PgProductRepository {
     }
 
     async fn update(&self, product: &Product) -> Result<(), InventoryError> {
-        let result = sqlx::query(
-            r#"
-            UPDATE products
-            SET sku = $2, barcode = $3, name = $4, description = $5, category_id = $6, brand = $7,
-                unit_of_measure = $8, base_price = $9, cost_price = $10, currency = $11,
-                is_perishable = $12, is_trackable = $13, has_variants = $14, tax_rate = $15,
-                tax_included = $16, attributes = $17, is_active = $18, updated_at = $19
-            WHERE id = $1
-            "#,
-        )
-        .bind(product.id().into_uuid())
-        .bind(product.sku().as_str())
-        .bind(product.barcode().map(|b| b.as_str()))
-        .bind(product.name())
-        .bind(product.description())
-        .bind(product.category_id().map(|id| id.into_uuid()))
-        .bind(product.brand())
-        .bind(product.unit_of_measure().to_string())
-        .bind(product.base_price())
-        .bind(product.cost_price())
-        .bind(product.currency().as_str())
-        .bind(product.is_perishable())
-        .bind(product.is_trackable())
-        .bind(product.has_variants())
-        .bind(product.tax_rate())
-        .bind(product.tax_included())
-        .bind(product.attributes())
-        .bind(product.is_active())
-        .bind(product.updated_at())
-        .execute(&self.pool)
-        .await?;
-
-        if result.rows_affected() == 0 {
-            return Err(InventoryError::ProductNotFound(product.id().into_uuid()));
-        }
-
-        Ok(())
+        Self::update_with(&self.pool, product).await
     }
 
     async fn delete(&self, id: ProductId) -> Result<(), InventoryError> {
@@ -175,9 +111,10 @@ impl ProductRepository for PgProductRepository {
     async fn find_active(&self) -> Result<Vec<Product>, InventoryError> {
         let rows = sqlx::query_as::<_, ProductRow>(
             r#"
-            SELECT id, sku, barcode, name, description, category_id, brand, unit_of_measure,
+            SELECT id, sku, barcode, barcode_generated, name, description, category_id, brand, unit_of_measure,
                    base_price, cost_price, currency, is_perishable, is_trackable, has_variants,
-                   tax_rate, tax_included, attributes, is_active, created_at, updated_at
+                   tax_rate, tax_included, tax_category, attributes, is_active, created_at, updated_at,
+                   is_serialized, markup_type, markup_percent
             FROM products
             WHERE is_active = TRUE
             ORDER BY name
@@ -195,9 +132,10 @@ impl ProductRepository for PgProductRepository {
     ) -> Result<Vec<Product>, InventoryError> {
         let rows = sqlx::query_as::<_, ProductRow>(
             r#"
-            SELECT id, sku, barcode, name, description, category_id, brand, unit_of_measure,
+            SELECT id, sku, barcode, barcode_generated, name, description, category_id, brand, unit_of_measure,
                    base_price, cost_price, currency, is_perishable, is_trackable, has_variants,
-                   tax_rate, tax_included, attributes, is_active, created_at, updated_at
+                   tax_rate, tax_included, tax_category, attributes, is_active, created_at, updated_at,
+                   is_serialized, markup_type, markup_percent
             FROM products
             WHERE category_id = $1
             ORDER BY name
@@ -215,22 +153,29 @@ impl ProductRepository for PgProductRepository {
         category_id: Option<CategoryId>,
         is_active: Option<bool>,
         search: Option<&str>,
+        after_id: Option<Uuid>,
         page: i64,
         page_size: i64,
     ) -> Result<(Vec<Product>, i64), InventoryError> {
-        let offset = (page - 1) * page_size;
+        let offset = if after_id.is_some() {
+            0
+        } else {
+            (page - 1) * page_size
+        };
 
         // Build dynamic query based on filters
         let rows = sqlx::query_as::<_, ProductRow>(
             r#"
-            SELECT id, sku, barcode, name, description, category_id, brand, unit_of_measure,
+            SELECT id, sku, barcode, barcode_generated, name, description, category_id, brand, unit_of_measure,
                    base_price, cost_price, currency, is_perishable, is_trackable, has_variants,
-                   tax_rate, tax_included, attributes, is_active, created_at, updated_at
+                   tax_rate, tax_included, tax_category, attributes, is_active, created_at, updated_at,
+                   is_serialized, markup_type, markup_percent
             FROM products
             WHERE ($1::uuid IS NULL OR category_id = $1)
               AND ($2::bool IS NULL OR is_active = $2)
               AND ($3::text IS NULL OR name ILIKE '%' || $3 || '%' OR description ILIKE '%' || $3 || '%')
-            ORDER BY created_at DESC
+              AND ($6::uuid IS NULL OR id < $6)
+            ORDER BY id DESC
             LIMIT $4 OFFSET $5
             "#,
         )
@@ -239,6 +184,7 @@ impl ProductRepository for PgProductRepository {
         .bind(search)
         .bind(page_size)
         .bind(offset)
+        .bind(after_id)
         .fetch_all(&self.pool)
         .await?;
 
@@ -275,6 +221,85 @@ impl ProductRepository for PgProductRepository {
         Ok(count.0)
     }
 
+    async fn search(
+        &self,
+        term: &str,
+        category_id: Option<CategoryId>,
+        active_only: bool,
+        page: i64,
+        page_size: i64,
+    ) -> Result<(Vec<(Product, f64)>, i64), InventoryError> {
+        let offset = (page - 1) * page_size;
+
+        // Full-text matches on whole words, trigram matches on misspelled
+        // names and partial SKUs, exact matches on barcodes
+        let rows = sqlx::query_as::<_, ScoredProductRow>(
+            r#"
+            WITH params AS (
+                SELECT plainto_tsquery('simple', $1) AS query, $1::text AS term
+            )
+            SELECT p.id, p.sku, p.barcode, p.barcode_generated, p.name, p.description,
+                   p.category_id, p.brand, p.unit_of_measure, p.base_price, p.cost_price,
+                   p.currency, p.is_perishable, p.is_trackable, p.has_variants, p.tax_rate,
+                   p.tax_included, p.tax_category, p.attributes, p.is_active, p.created_at,
+                   p.updated_at, p.is_serialized, p.markup_type, p.markup_percent,
+                   (ts_rank(p.search_vector, params.query)
+                    + GREATEST(word_similarity(params.term, p.name),
+                               similarity(params.term, p.sku),
+                               CASE WHEN p.barcode = params.term THEN 1 ELSE 0 END)
+                   )::float8 AS relevance_score
+            FROM products p
+            CROSS JOIN params
+            WHERE (p.search_vector @@ params.query
+                   OR params.term <% p.name
+                   OR p.sku % params.term
+                   OR p.sku ILIKE '%' || params.term || '%'
+                   OR p.barcode = params.term)
+              AND ($2::uuid IS NULL OR p.category_id = $2)
+              AND (NOT $3 OR p.is_active)
+            ORDER BY relevance_score DESC, p.name
+            LIMIT $4 OFFSET $5
+            "#,
+        )
+        .bind(term)
+        .bind(category_id.map(|c| c.into_uuid()))
+        .bind(active_only)
+        .bind(page_size)
+        .bind(offset)
+        .fetch_all(&self.pool)
+        .await?;
+
+        let total: (i64,) = sqlx::query_as(
+            r#"
+            WITH params AS (
+                SELECT plainto_tsquery('simple', $1) AS query, $1::text AS term
+            )
+            SELECT COUNT(*)
+            FROM products p
+            CROSS JOIN params
+            WHERE (p.search_vector @@ params.query
+                   OR params.term <% p.name
+                   OR p.sku % params.term
+                   OR p.sku ILIKE '%' || params.term || '%'
+                   OR p.barcode = params.term)
+              AND ($2::uuid IS NULL OR p.category_id = $2)
+              AND (NOT $3 OR p.is_active)
+            "#,
+        )
+        .bind(term)
+        .bind(category_id.map(|c| c.into_uuid()))
+        .bind(active_only)
+        .fetch_one(&self.pool)
+        .await?;
+
+        let products = rows
+            .into_iter()
+            .map(|r| Ok((r.product.try_into()?, r.relevance_score)))
+            .collect::<Result<Vec<_>, InventoryError>>()?;
+
+        Ok((products, total.0))
+    }
+
     // =========================================================================
     // Variant operations
     // =========================================================================
@@ -283,9 +308,10 @@ impl ProductRepository for PgProductRepository {
         sqlx::query(
             r#"
             INSERT INTO product_variants (
-                id, product_id, sku, barcode, name, variant_attributes, price, cost_price, is_active, created_at, updated_at
+                id, product_id, sku, barcode, name, variant_attributes, price, cost_price, is_active, created_at, updated_at,
+                barcode_generated
             )
-            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12)
             "#,
         )
         .bind(variant.id().into_uuid())
@@ -299,6 +325,7 @@ impl ProductRepository for PgProductRepository {
         .bind(variant.is_active())
         .bind(variant.created_at())
         .bind(variant.updated_at())
+        .bind(variant.barcode_generated())
         .execute(&self.pool)
         .await?;
 
@@ -311,7 +338,7 @@ impl ProductRepository for PgProductRepository {
     ) -> Result<Option<ProductVariant>, InventoryError> {
         let row = sqlx::query_as::<_, VariantRow>(
             r#"
-            SELECT id, product_id, sku, barcode, name, variant_attributes, price, cost_price, is_active, created_at, updated_at
+            SELECT id, product_id, sku, barcode, barcode_generated, name, variant_attributes, price, cost_price, is_active, created_at, updated_at
             FROM product_variants
             WHERE id = $1
             "#,
@@ -329,7 +356,7 @@ impl ProductRepository for PgProductRepository {
     ) -> Result<Option<ProductVariant>, InventoryError> {
         let row = sqlx::query_as::<_, VariantRow>(
             r#"
-            SELECT id, product_id, sku, barcode, name, variant_attributes, price, cost_price, is_active, created_at, updated_at
+            SELECT id, product_id, sku, barcode, barcode_generated, name, variant_attributes, price, cost_price, is_active, created_at, updated_at
             FROM product_variants
             WHERE sku = $1
             "#,
@@ -347,7 +374,7 @@ impl ProductRepository for PgProductRepository {
     ) -> Result<Option<ProductVariant>, InventoryError> {
         let row = sqlx::query_as::<_, VariantRow>(
             r#"
-            SELECT id, product_id, sku, barcode, name, variant_attributes, price, cost_price, is_active, created_at, updated_at
+            SELECT id, product_id, sku, barcode, barcode_generated, name, variant_attributes, price, cost_price, is_active, created_at, updated_at
             FROM product_variants
             WHERE barcode = $1
             "#,
@@ -365,7 +392,7 @@ impl ProductRepository for PgProductRepository {
     ) -> Result<Vec<ProductVariant>, InventoryError> {
         let rows = sqlx::query_as::<_, VariantRow>(
             r#"
-            SELECT id, product_id, sku, barcode, name, variant_attributes, price, cost_price, is_active, created_at, updated_at
+            SELECT id, product_id, sku, barcode, barcode_generated, name, variant_attributes, price, cost_price, is_active, created_at, updated_at
             FROM product_variants
             WHERE product_id = $1
             ORDER BY name
@@ -382,7 +409,8 @@ impl ProductRepository for PgProductRepository {
         let result = sqlx::query(
             r#"
             UPDATE product_variants
-            SET sku = $2, barcode = $3, name = $4, variant_attributes = $5, price = $6, cost_price = $7, is_active = $8, updated_at = $9
+            SET sku = $2, barcode = $3, name = $4, variant_attributes = $5, price = $6, cost_price = $7, is_active = $8, updated_at = $9,
+                barcode_generated = $10
             WHERE id = $1
             "#,
         )
@@ -395,6 +423,7 @@ impl ProductRepository for PgProductRepository {
         .bind(variant.cost_price())
         .bind(variant.is_active())
         .bind(variant.updated_at())
+        .bind(variant.barcode_generated())
         .execute(&self.pool)
         .await?;
 
@@ -435,6 +464,257 @@ impl ProductRepository for PgProductRepository {
 
         Ok(count.0 as u32)
     }
+
+    async fn save_product_vendors(&self, vendors: &[ProductVendor]) -> Result<(), InventoryError> {
+        let mut tx = self.pool.begin().await?;
+
+        for vendor in vendors {
+            sqlx::query(
+                r#"
+                INSERT INTO product_vendors (
+                    product_id, vendor_id, vendor_sku, last_purchase_price, is_preferred,
+                    created_at, updated_at
+                )
+                VALUES ($1, $2, $3, $4, $5, $6, $7)
+                ON CONFLICT (product_id, vendor_id) DO UPDATE
+                SET vendor_sku = EXCLUDED.vendor_sku,
+                    last_purchase_price = EXCLUDED.last_purchase_price,
+                    is_preferred = EXCLUDED.is_preferred,
+                    updated_at = EXCLUDED.updated_at
+                "#,
+            )
+            .bind(vendor.product_id().into_uuid())
+            .bind(vendor.vendor_id())
+            .bind(vendor.vendor_sku())
+            .bind(vendor.last_purchase_price())
+            .bind(vendor.is_preferred())
+            .bind(vendor.created_at())
+            .bind(vendor.updated_at())
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| {
+                let is_unknown_vendor = e
+                    .as_database_error()
+                    .and_then(|d| d.constraint())
+                    .is_some_and(|c| c == "product_vendors_vendor_id_fkey");
+                if is_unknown_vendor {
+                    InventoryError::InvalidProductVendor(format!(
+                        "vendor {} not found",
+                        vendor.vendor_id()
+                    ))
+                } else {
+                    e.into()
+                }
+            })?;
+        }
+
+        tx.commit().await?;
+        Ok(())
+    }
+
+    async fn find_product_vendors(
+        &self,
+        product_id: ProductId,
+    ) -> Result<Vec<ProductVendor>, InventoryError> {
+        let rows = sqlx::query_as::<_, ProductVendorRow>(
+            r#"
+            SELECT product_id, vendor_id, vendor_sku, last_purchase_price, is_preferred,
+                   created_at, updated_at
+            FROM product_vendors
+            WHERE product_id = $1
+            ORDER BY is_preferred DESC, created_at
+            "#,
+        )
+        .bind(product_id.into_uuid())
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows.into_iter().map(|r| r.into()).collect())
+    }
+
+    async fn find_preferred_vendor(
+        &self,
+        product_id: ProductId,
+    ) -> Result<Option<ProductVendor>, InventoryError> {
+        let row = sqlx::query_as::<_, ProductVendorRow>(
+            r#"
+            SELECT product_id, vendor_id, vendor_sku, last_purchase_price, is_preferred,
+                   created_at, updated_at
+            FROM product_vendors
+            WHERE product_id = $1 AND is_preferred
+            "#,
+        )
+        .bind(product_id.into_uuid())
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(row.map(|r| r.into()))
+    }
+
+    async fn replace_unit_conversions(
+        &self,
+        product_id: ProductId,
+        conversions: &[UnitConversion],
+    ) -> Result<(), InventoryError> {
+        let mut tx = self.pool.begin().await?;
+
+        sqlx::query("DELETE FROM product_unit_conversions WHERE product_id = $1")
+            .bind(product_id.into_uuid())
+            .execute(&mut *tx)
+            .await?;
+
+        for conversion in conversions {
+            sqlx::query(
+                r#"
+                INSERT INTO product_unit_conversions (product_id, from_unit, to_unit, factor)
+                VALUES ($1, $2, $3, $4)
+                "#,
+            )
+            .bind(product_id.into_uuid())
+            .bind(conversion.from_unit().to_string())
+            .bind(conversion.to_unit().to_string())
+            .bind(conversion.factor())
+            .execute(&mut *tx)
+            .await?;
+        }
+
+        tx.commit().await?;
+        Ok(())
+    }
+
+    async fn find_unit_conversions(
+        &self,
+        product_id: ProductId,
+    ) -> Result<Vec<UnitConversion>, InventoryError> {
+        let rows = sqlx::query_as::<_, UnitConversionRow>(
+            r#"
+            SELECT from_unit, to_unit, factor
+            FROM product_unit_conversions
+            WHERE product_id = $1
+            ORDER BY from_unit, to_unit
+            "#,
+        )
+        .bind(product_id.into_uuid())
+        .fetch_all(&self.pool)
+        .await?;
+
+        rows.into_iter().map(|r| r.try_into()).collect()
+    }
+}
+
+// Transactional methods
+impl PgProductRepository {
+    /// Inserts a product within an existing transaction.
+    pub async fn save_in_tx(
+        tx: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+        product: &Product,
+    ) -> Result<(), InventoryError> {
+        Self::save_with(&mut **tx, product).await
+    }
+
+    async fn save_with<'e, E>(executor: E, product: &Product) -> Result<(), InventoryError>
+    where
+        E: sqlx::PgExecutor<'e>,
+    {
+        sqlx::query(
+            r#"
+            INSERT INTO products (
+                id, sku, barcode, name, description, category_id, brand, unit_of_measure,
+                base_price, cost_price, currency, is_perishable, is_trackable, has_variants,
+                tax_rate, tax_included, tax_category, attributes, is_active, created_at, updated_at,
+                barcode_generated, is_serialized, markup_type, markup_percent
+            )
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15, $16, $17, $18, $19, $20, $21, $22, $23, $24, $25)
+            "#,
+        )
+        .bind(product.id().into_uuid())
+        .bind(product.sku().as_str())
+        .bind(product.barcode().map(|b| b.as_str()))
+        .bind(product.name())
+        .bind(product.description())
+        .bind(product.category_id().map(|id| id.into_uuid()))
+        .bind(product.brand())
+        .bind(product.unit_of_measure().to_string())
+        .bind(product.base_price())
+        .bind(product.cost_price())
+        .bind(product.currency().as_str())
+        .bind(product.is_perishable())
+        .bind(product.is_trackable())
+        .bind(product.has_variants())
+        .bind(product.tax_rate())
+        .bind(product.tax_included())
+        .bind(product.tax_category().map(|t| t.to_string()))
+        .bind(product.attributes())
+        .bind(product.is_active())
+        .bind(product.created_at())
+        .bind(product.updated_at())
+        .bind(product.barcode_generated())
+        .bind(product.is_serialized())
+        .bind(product.markup_rule().map(|r| r.markup_type()))
+        .bind(product.markup_rule().and_then(|r| r.percent()))
+        .execute(executor)
+        .await?;
+
+        Ok(())
+    }
+
+    /// Updates a product within an existing transaction.
+    pub async fn update_in_tx(
+        tx: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+        product: &Product,
+    ) -> Result<(), InventoryError> {
+        Self::update_with(&mut **tx, product).await
+    }
+
+    async fn update_with<'e, E>(executor: E, product: &Product) -> Result<(), InventoryError>
+    where
+        E: sqlx::PgExecutor<'e>,
+    {
+        let result = sqlx::query(
+            r#"
+            UPDATE products
+            SET sku = $2, barcode = $3, name = $4, description = $5, category_id = $6, brand = $7,
+                unit_of_measure = $8, base_price = $9, cost_price = $10, currency = $11,
+                is_perishable = $12, is_trackable = $13, has_variants = $14, tax_rate = $15,
+                tax_included = $16, tax_category = $17, attributes = $18, is_active = $19,
+                updated_at = $20, barcode_generated = $21, is_serialized = $22,
+                markup_type = $23, markup_percent = $24
+            WHERE id = $1
+            "#,
+        )
+        .bind(product.id().into_uuid())
+        .bind(product.sku().as_str())
+        .bind(product.barcode().map(|b| b.as_str()))
+        .bind(product.name())
+        .bind(product.description())
+        .bind(product.category_id().map(|id| id.into_uuid()))
+        .bind(product.brand())
+        .bind(product.unit_of_measure().to_string())
+        .bind(product.base_price())
+        .bind(product.cost_price())
+        .bind(product.currency().as_str())
+        .bind(product.is_perishable())
+        .bind(product.is_trackable())
+        .bind(product.has_variants())
+        .bind(product.tax_rate())
+        .bind(product.tax_included())
+        .bind(product.tax_category().map(|t| t.to_string()))
+        .bind(product.attributes())
+        .bind(product.is_active())
+        .bind(product.updated_at())
+        .bind(product.barcode_generated())
+        .bind(product.is_serialized())
+        .bind(product.markup_rule().map(|r| r.markup_type()))
+        .bind(product.markup_rule().and_then(|r| r.percent()))
+        .execute(executor)
+        .await?;
+
+        if result.rows_affected() == 0 {
+            return Err(InventoryError::ProductNotFound(product.id().into_uuid()));
+        }
+
+        Ok(())
+    }
 }
 
 /// Internal row type for mapping product database results
@@ -443,6 +723,7 @@ struct ProductRow {
     id: uuid::Uuid,
     sku: String,
     barcode: Option<String>,
+    barcode_generated: bool,
     name: String,
     description: Option<String>,
     category_id: Option<uuid::Uuid>,
@@ -456,6 +737,10 @@ struct ProductRow {
     has_variants: bool,
     tax_rate: Decimal,
     tax_included: bool,
+    tax_category: Option<String>,
+    is_serialized: bool,
+    markup_type: Option<String>,
+    markup_percent: Option<Decimal>,
     attributes: serde_json::Value,
     is_active: bool,
     created_at: chrono::DateTime<chrono::Utc>,
@@ -467,11 +752,16 @@ impl TryFrom<ProductRow> for Product {
 
     fn try_from(row: ProductRow) -> Result<Self, Self::Error> {
         let unit_of_measure: UnitOfMeasure = row.unit_of_measure.parse()?;
+        let markup_rule = row
+            .markup_type
+            .map(|t| MarkupRule::parse(&t, row.markup_percent))
+            .transpose()?;
 
         Ok(Product::reconstitute(
             ProductId::from_uuid(row.id),
             Sku::from_string(row.sku),
             row.barcode.map(Barcode::from_string),
+            row.barcode_generated,
             row.name,
             row.description,
             row.category_id.map(CategoryId::from_uuid),
@@ -485,6 +775,9 @@ impl TryFrom<ProductRow> for Product {
             row.has_variants,
             row.tax_rate,
             row.tax_included,
+            row.tax_category.map(|t| t.parse()).transpose()?,
+            row.is_serialized,
+            markup_rule,
             row.attributes,
             row.is_active,
             row.created_at,
@@ -493,6 +786,14 @@ impl TryFrom<ProductRow> for Product {
     }
 }
 
+/// Product row with its search relevance
+#[derive(sqlx::FromRow)]
+struct ScoredProductRow {
+    #[sqlx(flatten)]
+    product: ProductRow,
+    relevance_score: f64,
+}
+
 /// Internal row type for mapping variant database results
 #[derive(sqlx::FromRow)]
 struct VariantRow {
@@ -500,6 +801,7 @@ struct VariantRow {
     product_id: uuid::Uuid,
     sku: String,
     barcode: Option<String>,
+    barcode_generated: bool,
     name: String,
     variant_attributes: serde_json::Value,
     price: Option<Decimal>,
@@ -516,6 +818,7 @@ impl From<VariantRow> for ProductVariant {
             ProductId::from_uuid(row.product_id),
             Sku::from_string(row.sku),
             row.barcode.map(Barcode::from_string),
+            row.barcode_generated,
             row.name,
             row.variant_attributes,
             row.price,
@@ -526,3 +829,45 @@ impl From<VariantRow> for ProductVariant {
         )
     }
 }
+
+/// Internal row type for mapping product vendor database results
+#[derive(sqlx::FromRow)]
+struct ProductVendorRow {
+    product_id: uuid::Uuid,
+    vendor_id: uuid::Uuid,
+    vendor_sku: Option<String>,
+    last_purchase_price: Option<Decimal>,
+    is_preferred: bool,
+    created_at: chrono::DateTime<chrono::Utc>,
+    updated_at: chrono::DateTime<chrono::Utc>,
+}
+
+impl From<ProductVendorRow> for ProductVendor {
+    fn from(row: ProductVendorRow) -> Self {
+        ProductVendor::reconstitute(
+            ProductId::from_uuid(row.product_id),
+            row.vendor_id,
+            row.vendor_sku,
+            row.last_purchase_price,
+            row.is_preferred,
+            row.created_at,
+            row.updated_at,
+        )
+    }
+}
+
+/// Internal row type for mapping product unit conversion database results
+#[derive(sqlx::FromRow)]
+struct UnitConversionRow {
+    from_unit: String,
+    to_unit: String,
+    factor: Decimal,
+}
+
+impl TryFrom<UnitConversionRow> for UnitConversion {
+    type Error = InventoryError;
+
+    fn try_from(row: UnitConversionRow) -> Result<Self, Self::Error> {
+        UnitConversion::new(row.from_unit.parse()?, row.to_unit.parse()?, row.factor)
+    }
+}
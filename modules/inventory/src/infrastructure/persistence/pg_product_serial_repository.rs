@@ -0,0 +1,216 @@
+// PostgreSQL ProductSerialRepository implementation
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use identity::StoreId;
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::InventoryError;
+use crate::domain::entities::ProductSerial;
+use crate::domain::repositories::ProductSerialRepository;
+use crate::domain::value_objects::{ProductId, SerialId, SerialStatus, VariantId};
+
+/// PostgreSQL implementation of ProductSerialRepository
+pub struct PgProductSerialRepository {
+    pool: PgPool,
+}
+
+impl PgProductSerialRepository {
+    /// Creates a new PgProductSerialRepository with the given connection pool
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    /// Inserts new units within an existing transaction.
+    ///
+    /// # Errors
+    /// - `InventoryError::DuplicateSerial` if a serial is already registered
+    pub async fn save_batch_in_tx(
+        tx: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+        serials: &[ProductSerial],
+    ) -> Result<(), InventoryError> {
+        for serial in serials {
+            let result = sqlx::query(
+                r#"
+                INSERT INTO product_serials (
+                    id, product_id, variant_id, store_id, serial_number, status,
+                    sale_id, sale_item_id, sold_at, credit_note_id, created_at, updated_at
+                )
+                VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12)
+                ON CONFLICT (product_id, serial_number) DO NOTHING
+                "#,
+            )
+            .bind(serial.id().into_uuid())
+            .bind(serial.product_id().into_uuid())
+            .bind(serial.variant_id().map(|v| v.into_uuid()))
+            .bind(serial.store_id().into_uuid())
+            .bind(serial.serial_number())
+            .bind(serial.status().to_string())
+            .bind(serial.sale_id())
+            .bind(serial.sale_item_id())
+            .bind(serial.sold_at())
+            .bind(serial.credit_note_id())
+            .bind(serial.created_at())
+            .bind(serial.updated_at())
+            .execute(&mut **tx)
+            .await?;
+
+            if result.rows_affected() == 0 {
+                return Err(InventoryError::DuplicateSerial(
+                    serial.serial_number().to_string(),
+                ));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl ProductSerialRepository for PgProductSerialRepository {
+    async fn save_batch(&self, serials: &[ProductSerial]) -> Result<(), InventoryError> {
+        let mut tx = self.pool.begin().await?;
+        Self::save_batch_in_tx(&mut tx, serials).await?;
+        tx.commit().await?;
+        Ok(())
+    }
+
+    async fn update(
+        &self,
+        serial: &ProductSerial,
+        expected_status: SerialStatus,
+    ) -> Result<(), InventoryError> {
+        let result = sqlx::query(
+            r#"
+            UPDATE product_serials
+            SET store_id = $2, status = $3, sale_id = $4, sale_item_id = $5, sold_at = $6,
+                credit_note_id = $7, updated_at = $8
+            WHERE id = $1 AND status = $9
+            "#,
+        )
+        .bind(serial.id().into_uuid())
+        .bind(serial.store_id().into_uuid())
+        .bind(serial.status().to_string())
+        .bind(serial.sale_id())
+        .bind(serial.sale_item_id())
+        .bind(serial.sold_at())
+        .bind(serial.credit_note_id())
+        .bind(serial.updated_at())
+        .bind(expected_status.to_string())
+        .execute(&self.pool)
+        .await?;
+
+        if result.rows_affected() == 0 {
+            return Err(InventoryError::SerialNotAvailable(
+                serial.serial_number().to_string(),
+            ));
+        }
+
+        Ok(())
+    }
+
+    async fn find_by_product_and_number(
+        &self,
+        product_id: ProductId,
+        serial_number: &str,
+    ) -> Result<Option<ProductSerial>, InventoryError> {
+        let row = sqlx::query_as::<_, ProductSerialRow>(
+            r#"
+            SELECT id, product_id, variant_id, store_id, serial_number, status,
+                   sale_id, sale_item_id, sold_at, credit_note_id, created_at, updated_at
+            FROM product_serials
+            WHERE product_id = $1 AND serial_number = $2
+            "#,
+        )
+        .bind(product_id.into_uuid())
+        .bind(serial_number)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        row.map(ProductSerial::try_from).transpose()
+    }
+
+    async fn find_by_serial_number(
+        &self,
+        serial_number: &str,
+    ) -> Result<Vec<ProductSerial>, InventoryError> {
+        let rows = sqlx::query_as::<_, ProductSerialRow>(
+            r#"
+            SELECT id, product_id, variant_id, store_id, serial_number, status,
+                   sale_id, sale_item_id, sold_at, credit_note_id, created_at, updated_at
+            FROM product_serials
+            WHERE serial_number = $1
+            ORDER BY created_at
+            "#,
+        )
+        .bind(serial_number)
+        .fetch_all(&self.pool)
+        .await?;
+
+        rows.into_iter().map(ProductSerial::try_from).collect()
+    }
+
+    async fn find_by_product(
+        &self,
+        product_id: ProductId,
+        store_id: StoreId,
+        status: Option<SerialStatus>,
+    ) -> Result<Vec<ProductSerial>, InventoryError> {
+        let rows = sqlx::query_as::<_, ProductSerialRow>(
+            r#"
+            SELECT id, product_id, variant_id, store_id, serial_number, status,
+                   sale_id, sale_item_id, sold_at, credit_note_id, created_at, updated_at
+            FROM product_serials
+            WHERE product_id = $1 AND store_id = $2
+              AND ($3::text IS NULL OR status = $3)
+            ORDER BY serial_number
+            "#,
+        )
+        .bind(product_id.into_uuid())
+        .bind(store_id.into_uuid())
+        .bind(status.map(|s| s.to_string()))
+        .fetch_all(&self.pool)
+        .await?;
+
+        rows.into_iter().map(ProductSerial::try_from).collect()
+    }
+}
+
+/// Internal row type for mapping serial database results
+#[derive(sqlx::FromRow)]
+struct ProductSerialRow {
+    id: Uuid,
+    product_id: Uuid,
+    variant_id: Option<Uuid>,
+    store_id: Uuid,
+    serial_number: String,
+    status: String,
+    sale_id: Option<Uuid>,
+    sale_item_id: Option<Uuid>,
+    sold_at: Option<DateTime<Utc>>,
+    credit_note_id: Option<Uuid>,
+    created_at: DateTime<Utc>,
+    updated_at: DateTime<Utc>,
+}
+
+impl TryFrom<ProductSerialRow> for ProductSerial {
+    type Error = InventoryError;
+
+    fn try_from(row: ProductSerialRow) -> Result<Self, Self::Error> {
+        Ok(ProductSerial::reconstitute(
+            SerialId::from_uuid(row.id),
+            ProductId::from_uuid(row.product_id),
+            row.variant_id.map(VariantId::from_uuid),
+            StoreId::from_uuid(row.store_id),
+            row.serial_number,
+            row.status.parse()?,
+            row.sale_id,
+            row.sale_item_id,
+            row.sold_at,
+            row.credit_note_id,
+            row.created_at,
+            row.updated_at,
+        ))
+    }
+}
@@ -1,12 +1,15 @@
 // PostgreSQL RecipeRepository implementation
 
+use std::collections::HashMap;
+
 use async_trait::async_trait;
 use rust_decimal::Decimal;
 use sqlx::PgPool;
+use uuid::Uuid;
 
 use crate::InventoryError;
 use crate::domain::entities::{IngredientSubstitute, Recipe, RecipeIngredient};
-use crate::domain::repositories::RecipeRepository;
+use crate::domain::repositories::{CompositeCostSource, ItemUnitCost, RecipeRepository};
 use crate::domain::value_objects::{
     IngredientId, ProductId, RecipeId, SubstituteId, UnitOfMeasure, VariantId,
 };
@@ -35,9 +38,9 @@ impl RecipeRepository for PgRecipeRepository {
             INSERT INTO product_recipes (
                 id, product_id, variant_id, name, description, version, yield_quantity,
                 is_active, preparation_time_minutes, calculate_cost_from_ingredients,
-                notes, metadata, created_at, updated_at
+                notes, metadata, created_at, updated_at, yield_unit
             )
-            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15)
             "#,
         )
         .bind(recipe.id().into_uuid())
@@ -54,6 +57,7 @@ impl RecipeRepository for PgRecipeRepository {
         .bind(recipe.metadata())
         .bind(recipe.created_at())
         .bind(recipe.updated_at())
+        .bind(recipe.yield_unit().to_string())
         .execute(&self.pool)
         .await?;
 
@@ -63,7 +67,7 @@ impl RecipeRepository for PgRecipeRepository {
     async fn find_by_id(&self, id: RecipeId) -> Result<Option<Recipe>, InventoryError> {
         let row = sqlx::query_as::<_, RecipeRow>(
             r#"
-            SELECT id, product_id, variant_id, name, description, version, yield_quantity,
+            SELECT id, product_id, variant_id, name, description, version, yield_quantity, yield_unit,
                    is_active, preparation_time_minutes, calculate_cost_from_ingredients,
                    notes, metadata, created_at, updated_at
             FROM product_recipes
@@ -80,7 +84,7 @@ impl RecipeRepository for PgRecipeRepository {
     async fn find_by_product(&self, product_id: ProductId) -> Result<Vec<Recipe>, InventoryError> {
         let rows = sqlx::query_as::<_, RecipeRow>(
             r#"
-            SELECT id, product_id, variant_id, name, description, version, yield_quantity,
+            SELECT id, product_id, variant_id, name, description, version, yield_quantity, yield_unit,
                    is_active, preparation_time_minutes, calculate_cost_from_ingredients,
                    notes, metadata, created_at, updated_at
             FROM product_recipes
@@ -98,7 +102,7 @@ impl RecipeRepository for PgRecipeRepository {
     async fn find_by_variant(&self, variant_id: VariantId) -> Result<Vec<Recipe>, InventoryError> {
         let rows = sqlx::query_as::<_, RecipeRow>(
             r#"
-            SELECT id, product_id, variant_id, name, description, version, yield_quantity,
+            SELECT id, product_id, variant_id, name, description, version, yield_quantity, yield_unit,
                    is_active, preparation_time_minutes, calculate_cost_from_ingredients,
                    notes, metadata, created_at, updated_at
             FROM product_recipes
@@ -120,7 +124,7 @@ impl RecipeRepository for PgRecipeRepository {
         // Only one active recipe per product (enforced by business logic)
         let row = sqlx::query_as::<_, RecipeRow>(
             r#"
-            SELECT id, product_id, variant_id, name, description, version, yield_quantity,
+            SELECT id, product_id, variant_id, name, description, version, yield_quantity, yield_unit,
                    is_active, preparation_time_minutes, calculate_cost_from_ingredients,
                    notes, metadata, created_at, updated_at
             FROM product_recipes
@@ -142,7 +146,7 @@ impl RecipeRepository for PgRecipeRepository {
         // Only one active recipe per variant (enforced by business logic)
         let row = sqlx::query_as::<_, RecipeRow>(
             r#"
-            SELECT id, product_id, variant_id, name, description, version, yield_quantity,
+            SELECT id, product_id, variant_id, name, description, version, yield_quantity, yield_unit,
                    is_active, preparation_time_minutes, calculate_cost_from_ingredients,
                    notes, metadata, created_at, updated_at
             FROM product_recipes
@@ -161,20 +165,26 @@ impl RecipeRepository for PgRecipeRepository {
         &self,
         is_active: Option<bool>,
         search: Option<&str>,
+        after_id: Option<Uuid>,
         page: i64,
         page_size: i64,
     ) -> Result<(Vec<Recipe>, i64), InventoryError> {
-        let offset = (page - 1) * page_size;
+        let offset = if after_id.is_some() {
+            0
+        } else {
+            (page - 1) * page_size
+        };
 
         // Build dynamic query based on filters
         let rows = sqlx::query_as::<_, RecipeRow>(
             r#"
-            SELECT id, product_id, variant_id, name, description, version, yield_quantity, preparation_time_minutes,
+            SELECT id, product_id, variant_id, name, description, version, yield_quantity, yield_unit, preparation_time_minutes,
                    calculate_cost_from_ingredients, notes, metadata, is_active, created_at, updated_at
             FROM product_recipes
             WHERE ($1::bool IS NULL OR is_active = $1)
               AND ($2::text IS NULL OR name ILIKE '%' || $2 || '%' OR description ILIKE '%' || $2 || '%')
-            ORDER BY created_at DESC
+              AND ($5::uuid IS NULL OR id < $5)
+            ORDER BY id DESC
             LIMIT $3 OFFSET $4
             "#,
         )
@@ -182,6 +192,7 @@ impl RecipeRepository for PgRecipeRepository {
         .bind(search)
         .bind(page_size)
         .bind(offset)
+        .bind(after_id)
         .fetch_all(&self.pool)
         .await?;
 
@@ -221,7 +232,7 @@ impl RecipeRepository for PgRecipeRepository {
             UPDATE product_recipes
             SET name = $2, description = $3, version = $4, yield_quantity = $5,
                 is_active = $6, preparation_time_minutes = $7, calculate_cost_from_ingredients = $8,
-                notes = $9, metadata = $10, updated_at = $11
+                notes = $9, metadata = $10, updated_at = $11, yield_unit = $12
             WHERE id = $1
             "#,
         )
@@ -236,6 +247,7 @@ impl RecipeRepository for PgRecipeRepository {
         .bind(recipe.notes())
         .bind(recipe.metadata())
         .bind(recipe.updated_at())
+        .bind(recipe.yield_unit().to_string())
         .execute(&self.pool)
         .await?;
 
@@ -463,6 +475,129 @@ impl RecipeRepository for PgRecipeRepository {
 
         Ok(())
     }
+
+    // =========================================================================
+    // Cost rollup operations
+    // =========================================================================
+
+    async fn find_composite_cost_sources(
+        &self,
+    ) -> Result<Vec<CompositeCostSource>, InventoryError> {
+        let rows = sqlx::query_as::<_, CompositeCostRow>(
+            r#"
+            SELECT r.id, r.product_id, r.variant_id, r.name, r.description, r.version,
+                   r.yield_quantity, r.yield_unit, r.is_active, r.preparation_time_minutes,
+                   r.calculate_cost_from_ingredients, r.notes, r.metadata, r.created_at,
+                   r.updated_at,
+                   COALESCE(v.price, vp.base_price, p.base_price, 0)    AS price,
+                   COALESCE(v.cost_price, vp.cost_price, p.cost_price, 0) AS cost_price
+            FROM product_recipes r
+            LEFT JOIN products p ON p.id = r.product_id
+            LEFT JOIN product_variants v ON v.id = r.variant_id
+            LEFT JOIN products vp ON vp.id = v.product_id
+            WHERE r.is_active = TRUE
+              AND r.calculate_cost_from_ingredients = TRUE
+            "#,
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        let recipe_ids: Vec<uuid::Uuid> = rows.iter().map(|r| r.recipe.id).collect();
+        let ingredient_rows = sqlx::query_as::<_, IngredientRow>(
+            r#"
+            SELECT id, recipe_id, ingredient_product_id, ingredient_variant_id, quantity,
+                   unit_of_measure, is_optional, can_substitute, sort_order, preparation_step,
+                   estimated_cost_per_unit, estimated_waste_percentage, notes, created_at, updated_at
+            FROM recipe_ingredients
+            WHERE recipe_id = ANY($1)
+            ORDER BY recipe_id, sort_order ASC
+            "#,
+        )
+        .bind(&recipe_ids)
+        .fetch_all(&self.pool)
+        .await?;
+
+        let mut ingredients: HashMap<RecipeId, Vec<RecipeIngredient>> = HashMap::new();
+        for row in ingredient_rows {
+            let ingredient: RecipeIngredient = row.try_into()?;
+            ingredients
+                .entry(ingredient.recipe_id())
+                .or_default()
+                .push(ingredient);
+        }
+
+        rows.into_iter()
+            .map(|row| {
+                let recipe: Recipe = row.recipe.try_into()?;
+                Ok(CompositeCostSource {
+                    ingredients: ingredients.remove(&recipe.id()).unwrap_or_default(),
+                    recipe,
+                    price: row.price,
+                    cost_price: row.cost_price,
+                })
+            })
+            .collect()
+    }
+
+    async fn find_weighted_average_costs(&self) -> Result<Vec<ItemUnitCost>, InventoryError> {
+        // Same weighting as calculate_weighted_average_cost, pooled across stores
+        let rows = sqlx::query_as::<_, ItemUnitCostRow>(
+            r#"
+            SELECT s.product_id, s.variant_id,
+                   SUM(m.quantity * m.unit_cost) / SUM(m.quantity) AS unit_cost
+            FROM inventory_movements m
+            JOIN inventory_stock s ON s.id = m.stock_id
+            WHERE m.movement_type = 'in'
+              AND m.unit_cost IS NOT NULL
+              AND m.quantity > 0
+            GROUP BY s.product_id, s.variant_id
+            "#,
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows
+            .into_iter()
+            .map(|row| ItemUnitCost {
+                product_id: row.product_id.map(ProductId::from_uuid),
+                variant_id: row.variant_id.map(VariantId::from_uuid),
+                unit_cost: row.unit_cost,
+            })
+            .collect())
+    }
+
+    async fn update_composite_cost_price(
+        &self,
+        recipe: &Recipe,
+        cost_price: Decimal,
+    ) -> Result<(), InventoryError> {
+        let result = match (recipe.product_id(), recipe.variant_id()) {
+            (Some(product_id), _) => {
+                sqlx::query("UPDATE products SET cost_price = $2, updated_at = NOW() WHERE id = $1")
+                    .bind(product_id.into_uuid())
+                    .bind(cost_price)
+                    .execute(&self.pool)
+                    .await?
+            }
+            (None, Some(variant_id)) => {
+                sqlx::query(
+                    "UPDATE product_variants SET cost_price = $2, updated_at = NOW() WHERE id = $1",
+                )
+                .bind(variant_id.into_uuid())
+                .bind(cost_price)
+                .execute(&self.pool)
+                .await?
+            }
+            // Ruled out by the product/variant XOR constraint
+            (None, None) => return Err(InventoryError::RecipeNotFound(recipe.id().into_uuid())),
+        };
+
+        if result.rows_affected() == 0 {
+            return Err(InventoryError::RecipeNotFound(recipe.id().into_uuid()));
+        }
+
+        Ok(())
+    }
 }
 
 // =============================================================================
@@ -479,6 +614,7 @@ struct RecipeRow {
     description: Option<String>,
     version: i32,
     yield_quantity: Decimal,
+    yield_unit: String,
     is_active: bool,
     preparation_time_minutes: Option<i32>,
     calculate_cost_from_ingredients: bool,
@@ -492,6 +628,7 @@ impl TryFrom<RecipeRow> for Recipe {
     type Error = InventoryError;
 
     fn try_from(row: RecipeRow) -> Result<Self, Self::Error> {
+        let yield_unit: UnitOfMeasure = row.yield_unit.parse()?;
         Recipe::reconstitute(
             RecipeId::from_uuid(row.id),
             row.product_id.map(ProductId::from_uuid),
@@ -500,6 +637,7 @@ impl TryFrom<RecipeRow> for Recipe {
             row.description,
             row.version,
             row.yield_quantity,
+            yield_unit,
             row.is_active,
             row.preparation_time_minutes,
             row.calculate_cost_from_ingredients,
@@ -511,6 +649,24 @@ impl TryFrom<RecipeRow> for Recipe {
     }
 }
 
+/// Internal row type for composite cost sources: a recipe plus the price and
+/// stored cost of the item it produces
+#[derive(sqlx::FromRow)]
+struct CompositeCostRow {
+    #[sqlx(flatten)]
+    recipe: RecipeRow,
+    price: Decimal,
+    cost_price: Decimal,
+}
+
+/// Internal row type for weighted-average item costs
+#[derive(sqlx::FromRow)]
+struct ItemUnitCostRow {
+    product_id: Option<uuid::Uuid>,
+    variant_id: Option<uuid::Uuid>,
+    unit_cost: Decimal,
+}
+
 /// Internal row type for mapping ingredient database results
 #[derive(sqlx::FromRow)]
 struct IngredientRow {
@@ -0,0 +1,246 @@
+// PostgreSQL RepriceBatchRepository implementation
+
+use std::collections::HashMap;
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::InventoryError;
+use crate::domain::entities::{RepriceBatch, RepriceLine};
+use crate::domain::repositories::RepriceBatchRepository;
+use crate::domain::value_objects::{MarkupRule, ProductId, RepriceBatchId, RepriceBatchStatus};
+use identity::UserId;
+
+/// PostgreSQL implementation of RepriceBatchRepository
+pub struct PgRepriceBatchRepository {
+    pool: PgPool,
+}
+
+impl PgRepriceBatchRepository {
+    /// Creates a new PgRepriceBatchRepository with the given connection pool
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    async fn load_lines(
+        &self,
+        batch_ids: &[Uuid],
+    ) -> Result<HashMap<Uuid, Vec<RepriceLine>>, InventoryError> {
+        let rows = sqlx::query_as::<_, RepriceLineRow>(
+            r#"
+            SELECT batch_id, product_id, product_name, markup_type, markup_percent,
+                   old_cost, new_cost, old_price, new_price
+            FROM reprice_batch_lines
+            WHERE batch_id = ANY($1)
+            ORDER BY product_name
+            "#,
+        )
+        .bind(batch_ids)
+        .fetch_all(&self.pool)
+        .await?;
+
+        let mut lines: HashMap<Uuid, Vec<RepriceLine>> = HashMap::new();
+        for row in rows {
+            let batch_id = row.batch_id;
+            lines
+                .entry(batch_id)
+                .or_default()
+                .push(RepriceLine::try_from(row)?);
+        }
+        Ok(lines)
+    }
+}
+
+#[async_trait]
+impl RepriceBatchRepository for PgRepriceBatchRepository {
+    async fn save(&self, batch: &RepriceBatch) -> Result<(), InventoryError> {
+        let mut tx = self.pool.begin().await?;
+
+        sqlx::query(
+            r#"
+            INSERT INTO reprice_batches (
+                id, vendor_id, reason, status, created_by_id, decided_by_id, decided_at,
+                created_at, updated_at
+            )
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
+            "#,
+        )
+        .bind(batch.id().into_uuid())
+        .bind(batch.vendor_id())
+        .bind(batch.reason())
+        .bind(batch.status().to_string())
+        .bind(batch.created_by_id().into_uuid())
+        .bind(batch.decided_by_id().map(|id| id.into_uuid()))
+        .bind(batch.decided_at())
+        .bind(batch.created_at())
+        .bind(batch.updated_at())
+        .execute(&mut *tx)
+        .await?;
+
+        for line in batch.lines() {
+            sqlx::query(
+                r#"
+                INSERT INTO reprice_batch_lines (
+                    batch_id, product_id, product_name, markup_type, markup_percent,
+                    old_cost, new_cost, old_price, new_price
+                )
+                VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
+                "#,
+            )
+            .bind(batch.id().into_uuid())
+            .bind(line.product_id().into_uuid())
+            .bind(line.product_name())
+            .bind(line.markup_rule().map(|r| r.markup_type()))
+            .bind(line.markup_rule().and_then(|r| r.percent()))
+            .bind(line.old_cost())
+            .bind(line.new_cost())
+            .bind(line.old_price())
+            .bind(line.new_price())
+            .execute(&mut *tx)
+            .await?;
+        }
+
+        tx.commit().await?;
+        Ok(())
+    }
+
+    async fn update(&self, batch: &RepriceBatch) -> Result<(), InventoryError> {
+        let result = sqlx::query(
+            r#"
+            UPDATE reprice_batches
+            SET status = $2, decided_by_id = $3, decided_at = $4, updated_at = $5
+            WHERE id = $1
+            "#,
+        )
+        .bind(batch.id().into_uuid())
+        .bind(batch.status().to_string())
+        .bind(batch.decided_by_id().map(|id| id.into_uuid()))
+        .bind(batch.decided_at())
+        .bind(batch.updated_at())
+        .execute(&self.pool)
+        .await?;
+
+        if result.rows_affected() == 0 {
+            return Err(InventoryError::RepriceBatchNotFound(batch.id().into_uuid()));
+        }
+
+        Ok(())
+    }
+
+    async fn find_by_id(&self, id: RepriceBatchId) -> Result<Option<RepriceBatch>, InventoryError> {
+        let row = sqlx::query_as::<_, RepriceBatchRow>(
+            r#"
+            SELECT id, vendor_id, reason, status, created_by_id, decided_by_id, decided_at,
+                   created_at, updated_at
+            FROM reprice_batches
+            WHERE id = $1
+            "#,
+        )
+        .bind(id.into_uuid())
+        .fetch_optional(&self.pool)
+        .await?;
+
+        let Some(row) = row else {
+            return Ok(None);
+        };
+        let mut lines = self.load_lines(&[row.id]).await?;
+        let batch_lines = lines.remove(&row.id).unwrap_or_default();
+        row.into_batch(batch_lines).map(Some)
+    }
+
+    async fn find_all(
+        &self,
+        status: Option<RepriceBatchStatus>,
+    ) -> Result<Vec<RepriceBatch>, InventoryError> {
+        let rows = sqlx::query_as::<_, RepriceBatchRow>(
+            r#"
+            SELECT id, vendor_id, reason, status, created_by_id, decided_by_id, decided_at,
+                   created_at, updated_at
+            FROM reprice_batches
+            WHERE ($1::text IS NULL OR status = $1)
+            ORDER BY created_at DESC
+            "#,
+        )
+        .bind(status.map(|s| s.to_string()))
+        .fetch_all(&self.pool)
+        .await?;
+
+        let ids: Vec<Uuid> = rows.iter().map(|r| r.id).collect();
+        let mut lines = self.load_lines(&ids).await?;
+        rows.into_iter()
+            .map(|row| {
+                let batch_lines = lines.remove(&row.id).unwrap_or_default();
+                row.into_batch(batch_lines)
+            })
+            .collect()
+    }
+}
+
+/// Internal row type for mapping batch database results
+#[derive(sqlx::FromRow)]
+struct RepriceBatchRow {
+    id: Uuid,
+    vendor_id: Option<Uuid>,
+    reason: Option<String>,
+    status: String,
+    created_by_id: Uuid,
+    decided_by_id: Option<Uuid>,
+    decided_at: Option<DateTime<Utc>>,
+    created_at: DateTime<Utc>,
+    updated_at: DateTime<Utc>,
+}
+
+impl RepriceBatchRow {
+    fn into_batch(self, lines: Vec<RepriceLine>) -> Result<RepriceBatch, InventoryError> {
+        Ok(RepriceBatch::reconstitute(
+            RepriceBatchId::from_uuid(self.id),
+            self.vendor_id,
+            self.reason,
+            self.status.parse()?,
+            lines,
+            UserId::from_uuid(self.created_by_id),
+            self.decided_by_id.map(UserId::from_uuid),
+            self.decided_at,
+            self.created_at,
+            self.updated_at,
+        ))
+    }
+}
+
+/// Internal row type for mapping batch line database results
+#[derive(sqlx::FromRow)]
+struct RepriceLineRow {
+    batch_id: Uuid,
+    product_id: Uuid,
+    product_name: String,
+    markup_type: Option<String>,
+    markup_percent: Option<Decimal>,
+    old_cost: Decimal,
+    new_cost: Decimal,
+    old_price: Decimal,
+    new_price: Option<Decimal>,
+}
+
+impl TryFrom<RepriceLineRow> for RepriceLine {
+    type Error = InventoryError;
+
+    fn try_from(row: RepriceLineRow) -> Result<Self, Self::Error> {
+        let markup_rule = row
+            .markup_type
+            .map(|t| MarkupRule::parse(&t, row.markup_percent))
+            .transpose()?;
+
+        Ok(RepriceLine::reconstitute(
+            ProductId::from_uuid(row.product_id),
+            row.product_name,
+            markup_rule,
+            row.old_cost,
+            row.new_cost,
+            row.old_price,
+            row.new_price,
+        ))
+    }
+}
@@ -1,13 +1,15 @@
 // PostgreSQL ReservationRepository implementation
 
 use async_trait::async_trait;
+use chrono::{DateTime, NaiveDate, Utc};
+use identity::StoreId;
 use rust_decimal::Decimal;
 use sqlx::PgPool;
 use uuid::Uuid;
 
 use crate::InventoryError;
 use crate::domain::entities::InventoryReservation;
-use crate::domain::repositories::ReservationRepository;
+use crate::domain::repositories::{ReservationRepository, ReservationStatusCount};
 use crate::domain::value_objects::{ReservationId, ReservationStatus, StockId};
 
 /// PostgreSQL implementation of ReservationRepository
@@ -28,9 +30,10 @@ impl ReservationRepository for PgReservationRepository {
         sqlx::query(
             r#"
             INSERT INTO inventory_reservations (
-                id, stock_id, reference_type, reference_id, quantity, status, expires_at, created_at, updated_at
+                id, stock_id, reference_type, reference_id, quantity, status, priority, expires_at,
+                auto_extend_seconds, bumped_at, bump_reason, created_at, updated_at
             )
-            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13)
             "#,
         )
         .bind(reservation.id().into_uuid())
@@ -39,7 +42,11 @@ impl ReservationRepository for PgReservationRepository {
         .bind(reservation.reference_id())
         .bind(reservation.quantity())
         .bind(reservation.status().to_string())
+        .bind(reservation.priority())
         .bind(reservation.expires_at())
+        .bind(reservation.auto_extend_seconds())
+        .bind(reservation.bumped_at())
+        .bind(reservation.bump_reason())
         .bind(reservation.created_at())
         .bind(reservation.updated_at())
         .execute(&self.pool)
@@ -54,7 +61,8 @@ impl ReservationRepository for PgReservationRepository {
     ) -> Result<Option<InventoryReservation>, InventoryError> {
         let row = sqlx::query_as::<_, ReservationRow>(
             r#"
-            SELECT id, stock_id, reference_type, reference_id, quantity, status, expires_at, created_at, updated_at
+            SELECT id, stock_id, reference_type, reference_id, quantity, status, priority, expires_at,
+                   auto_extend_seconds, bumped_at, bump_reason, created_at, updated_at
             FROM inventory_reservations
             WHERE id = $1
             "#,
@@ -72,7 +80,8 @@ impl ReservationRepository for PgReservationRepository {
     ) -> Result<Vec<InventoryReservation>, InventoryError> {
         let rows = sqlx::query_as::<_, ReservationRow>(
             r#"
-            SELECT id, stock_id, reference_type, reference_id, quantity, status, expires_at, created_at, updated_at
+            SELECT id, stock_id, reference_type, reference_id, quantity, status, priority, expires_at,
+                   auto_extend_seconds, bumped_at, bump_reason, created_at, updated_at
             FROM inventory_reservations
             WHERE stock_id = $1
             ORDER BY created_at DESC
@@ -92,7 +101,8 @@ impl ReservationRepository for PgReservationRepository {
     ) -> Result<Vec<InventoryReservation>, InventoryError> {
         let rows = sqlx::query_as::<_, ReservationRow>(
             r#"
-            SELECT id, stock_id, reference_type, reference_id, quantity, status, expires_at, created_at, updated_at
+            SELECT id, stock_id, reference_type, reference_id, quantity, status, priority, expires_at,
+                   auto_extend_seconds, bumped_at, bump_reason, created_at, updated_at
             FROM inventory_reservations
             WHERE reference_type = $1 AND reference_id = $2
             ORDER BY created_at DESC
@@ -109,7 +119,8 @@ impl ReservationRepository for PgReservationRepository {
     async fn find_expired(&self) -> Result<Vec<InventoryReservation>, InventoryError> {
         let rows = sqlx::query_as::<_, ReservationRow>(
             r#"
-            SELECT id, stock_id, reference_type, reference_id, quantity, status, expires_at, created_at, updated_at
+            SELECT id, stock_id, reference_type, reference_id, quantity, status, priority, expires_at,
+                   auto_extend_seconds, bumped_at, bump_reason, created_at, updated_at
             FROM inventory_reservations
             WHERE status = 'pending' AND expires_at < NOW()
             ORDER BY expires_at ASC
@@ -125,12 +136,15 @@ impl ReservationRepository for PgReservationRepository {
         let result = sqlx::query(
             r#"
             UPDATE inventory_reservations
-            SET status = $2, updated_at = $3
+            SET status = $2, expires_at = $3, bumped_at = $4, bump_reason = $5, updated_at = $6
             WHERE id = $1
             "#,
         )
         .bind(reservation.id().into_uuid())
         .bind(reservation.status().to_string())
+        .bind(reservation.expires_at())
+        .bind(reservation.bumped_at())
+        .bind(reservation.bump_reason())
         .bind(reservation.updated_at())
         .execute(&self.pool)
         .await?;
@@ -164,18 +178,31 @@ impl ReservationRepository for PgReservationRepository {
 
     async fn find_paginated(
         &self,
+        store_ids: Option<&[StoreId]>,
         stock_id: Option<StockId>,
         status: Option<&str>,
         reference_type: Option<&str>,
+        after_id: Option<Uuid>,
         page: i64,
         page_size: i64,
     ) -> Result<(Vec<InventoryReservation>, i64), InventoryError> {
-        let offset = (page - 1) * page_size;
+        let offset = if after_id.is_some() {
+            0
+        } else {
+            (page - 1) * page_size
+        };
 
         // Build dynamic query for filtering
         let mut conditions: Vec<String> = Vec::new();
         let mut param_count = 0;
 
+        if store_ids.is_some() {
+            param_count += 1;
+            conditions.push(format!(
+                "stock_id IN (SELECT id FROM inventory_stock WHERE store_id = ANY(${}))",
+                param_count
+            ));
+        }
         if stock_id.is_some() {
             param_count += 1;
             conditions.push(format!("stock_id = ${}", param_count));
@@ -201,22 +228,38 @@ impl ReservationRepository for PgReservationRepository {
             where_clause
         );
 
-        // Data query
+        // Data query; the keyset condition is only applied here so the count
+        // still reports the whole filtered set
+        let mut data_conditions = conditions.clone();
+        if after_id.is_some() {
+            data_conditions.push(format!("id < ${}", param_count + 3));
+        }
+        let data_where = if data_conditions.is_empty() {
+            String::new()
+        } else {
+            format!("WHERE {}", data_conditions.join(" AND "))
+        };
         let data_query = format!(
             r#"
-            SELECT id, stock_id, reference_type, reference_id, quantity, status, expires_at, created_at, updated_at
+            SELECT id, stock_id, reference_type, reference_id, quantity, status, priority, expires_at,
+                   auto_extend_seconds, bumped_at, bump_reason, created_at, updated_at
             FROM inventory_reservations
             {}
-            ORDER BY created_at DESC
+            ORDER BY id DESC
             LIMIT ${} OFFSET ${}
             "#,
-            where_clause,
+            data_where,
             param_count + 1,
             param_count + 2
         );
 
         // Execute count query
+        let store_ids: Option<Vec<Uuid>> =
+            store_ids.map(|ids| ids.iter().map(|id| *id.as_uuid()).collect());
         let mut count_q = sqlx::query_scalar::<_, i64>(&count_query);
+        if let Some(ids) = &store_ids {
+            count_q = count_q.bind(ids.clone());
+        }
         if let Some(sid) = stock_id {
             count_q = count_q.bind(sid.into_uuid());
         }
@@ -230,6 +273,9 @@ impl ReservationRepository for PgReservationRepository {
 
         // Execute data query
         let mut data_q = sqlx::query_as::<_, ReservationRow>(&data_query);
+        if let Some(ids) = store_ids {
+            data_q = data_q.bind(ids);
+        }
         if let Some(sid) = stock_id {
             data_q = data_q.bind(sid.into_uuid());
         }
@@ -240,12 +286,48 @@ impl ReservationRepository for PgReservationRepository {
             data_q = data_q.bind(rt);
         }
         data_q = data_q.bind(page_size).bind(offset);
+        if let Some(id) = after_id {
+            data_q = data_q.bind(id);
+        }
 
         let rows = data_q.fetch_all(&self.pool).await?;
         let reservations: Result<Vec<_>, _> = rows.into_iter().map(|r| r.try_into()).collect();
 
         Ok((reservations?, total_count))
     }
+
+    async fn count_by_status(
+        &self,
+        store_id: StoreId,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+        by_day: bool,
+    ) -> Result<Vec<ReservationStatusCount>, InventoryError> {
+        let rows = sqlx::query_as::<_, ReservationStatusCountRow>(
+            r#"
+            SELECT CASE WHEN $4 THEN (r.created_at AT TIME ZONE 'UTC')::DATE END AS day,
+                   r.reference_type,
+                   r.status,
+                   COUNT(*)        AS reservations,
+                   SUM(r.quantity) AS quantity
+            FROM inventory_reservations r
+            JOIN inventory_stock s ON s.id = r.stock_id
+            WHERE s.store_id = $1
+              AND r.created_at >= $2
+              AND r.created_at <= $3
+            GROUP BY 1, 2, 3
+            ORDER BY 1, 2, 3
+            "#,
+        )
+        .bind(store_id.into_uuid())
+        .bind(from)
+        .bind(to)
+        .bind(by_day)
+        .fetch_all(&self.pool)
+        .await?;
+
+        rows.into_iter().map(|r| r.try_into()).collect()
+    }
 }
 
 /// Internal row type for mapping reservation database results
@@ -257,7 +339,11 @@ struct ReservationRow {
     reference_id: uuid::Uuid,
     quantity: Decimal,
     status: String,
+    priority: i32,
     expires_at: chrono::DateTime<chrono::Utc>,
+    auto_extend_seconds: Option<i64>,
+    bumped_at: Option<chrono::DateTime<chrono::Utc>>,
+    bump_reason: Option<String>,
     created_at: chrono::DateTime<chrono::Utc>,
     updated_at: chrono::DateTime<chrono::Utc>,
 }
@@ -275,9 +361,37 @@ impl TryFrom<ReservationRow> for InventoryReservation {
             row.reference_id,
             row.quantity,
             status,
+            row.priority,
             row.expires_at,
+            row.auto_extend_seconds,
+            row.bumped_at,
+            row.bump_reason,
             row.created_at,
             row.updated_at,
         ))
     }
 }
+
+/// Internal row type for grouped reservation status counts
+#[derive(sqlx::FromRow)]
+struct ReservationStatusCountRow {
+    day: Option<NaiveDate>,
+    reference_type: String,
+    status: String,
+    reservations: i64,
+    quantity: Decimal,
+}
+
+impl TryFrom<ReservationStatusCountRow> for ReservationStatusCount {
+    type Error = InventoryError;
+
+    fn try_from(row: ReservationStatusCountRow) -> Result<Self, Self::Error> {
+        Ok(ReservationStatusCount {
+            day: row.day,
+            reference_type: row.reference_type,
+            status: row.status.parse()?,
+            reservations: row.reservations,
+            quantity: row.quantity,
+        })
+    }
+}
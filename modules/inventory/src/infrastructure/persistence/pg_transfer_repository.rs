@@ -1,7 +1,7 @@
 // PostgreSQL TransferRepository implementation
 
 use async_trait::async_trait;
-use chrono::Utc;
+use common::{DocumentNumberService, DocumentType};
 use rust_decimal::Decimal;
 use sqlx::PgPool;
 
@@ -14,12 +14,22 @@ use identity::{StoreId, UserId};
 /// PostgreSQL implementation of TransferRepository
 pub struct PgTransferRepository {
     pool: PgPool,
+    numbering: DocumentNumberService,
 }
 
 impl PgTransferRepository {
     /// Creates a new PgTransferRepository with the given connection pool
     pub fn new(pool: PgPool) -> Self {
-        Self { pool }
+        Self {
+            pool,
+            numbering: DocumentNumberService::default(),
+        }
+    }
+
+    /// Replaces the default document number formats
+    pub fn with_numbering(mut self, numbering: DocumentNumberService) -> Self {
+        self.numbering = numbering;
+        self
     }
 }
 
@@ -35,9 +45,11 @@ impl TransferRepository for PgTransferRepository {
             INSERT INTO stock_transfers (
                 id, transfer_number, from_store_id, to_store_id, status, requested_date,
                 shipped_date, received_date, requested_by_id, shipped_by_id, received_by_id,
-                notes, shipping_method, tracking_number, created_at, updated_at
+                notes, shipping_method, tracking_number, requires_approval, approval_reason,
+                approved_by_id, approved_at, approval_notes, created_at, updated_at
             )
-            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15, $16)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15, $16, $17, $18,
+                    $19, $20, $21)
             "#,
         )
         .bind(transfer.id().into_uuid())
@@ -54,6 +66,11 @@ impl TransferRepository for PgTransferRepository {
         .bind(transfer.notes())
         .bind(transfer.shipping_method())
         .bind(transfer.tracking_number())
+        .bind(transfer.requires_approval())
+        .bind(transfer.approval_reason())
+        .bind(transfer.approved_by_id().map(|id| id.into_uuid()))
+        .bind(transfer.approved_at())
+        .bind(transfer.approval_notes())
         .bind(transfer.created_at())
         .bind(transfer.updated_at())
         .execute(&mut *tx)
@@ -93,7 +110,8 @@ impl TransferRepository for PgTransferRepository {
             r#"
             SELECT id, transfer_number, from_store_id, to_store_id, status, requested_date,
                    shipped_date, received_date, requested_by_id, shipped_by_id, received_by_id,
-                   notes, shipping_method, tracking_number, created_at, updated_at
+                   notes, shipping_method, tracking_number, requires_approval, approval_reason,
+                   approved_by_id, approved_at, approval_notes, created_at, updated_at
             FROM stock_transfers
             WHERE id = $1
             "#,
@@ -114,7 +132,8 @@ impl TransferRepository for PgTransferRepository {
             r#"
             SELECT id, transfer_number, from_store_id, to_store_id, status, requested_date,
                    shipped_date, received_date, requested_by_id, shipped_by_id, received_by_id,
-                   notes, shipping_method, tracking_number, created_at, updated_at
+                   notes, shipping_method, tracking_number, requires_approval, approval_reason,
+                   approved_by_id, approved_at, approval_notes, created_at, updated_at
             FROM stock_transfers
             WHERE id = $1
             "#,
@@ -152,7 +171,8 @@ impl TransferRepository for PgTransferRepository {
             r#"
             SELECT id, transfer_number, from_store_id, to_store_id, status, requested_date,
                    shipped_date, received_date, requested_by_id, shipped_by_id, received_by_id,
-                   notes, shipping_method, tracking_number, created_at, updated_at
+                   notes, shipping_method, tracking_number, requires_approval, approval_reason,
+                   approved_by_id, approved_at, approval_notes, created_at, updated_at
             FROM stock_transfers
             WHERE from_store_id = $1 OR to_store_id = $1
             ORDER BY created_at DESC
@@ -175,7 +195,8 @@ impl TransferRepository for PgTransferRepository {
             r#"
             SELECT id, transfer_number, from_store_id, to_store_id, status, requested_date,
                    shipped_date, received_date, requested_by_id, shipped_by_id, received_by_id,
-                   notes, shipping_method, tracking_number, created_at, updated_at
+                   notes, shipping_method, tracking_number, requires_approval, approval_reason,
+                   approved_by_id, approved_at, approval_notes, created_at, updated_at
             FROM stock_transfers
             WHERE from_store_id = $1
             ORDER BY created_at DESC
@@ -198,7 +219,8 @@ impl TransferRepository for PgTransferRepository {
             r#"
             SELECT id, transfer_number, from_store_id, to_store_id, status, requested_date,
                    shipped_date, received_date, requested_by_id, shipped_by_id, received_by_id,
-                   notes, shipping_method, tracking_number, created_at, updated_at
+                   notes, shipping_method, tracking_number, requires_approval, approval_reason,
+                   approved_by_id, approved_at, approval_notes, created_at, updated_at
             FROM stock_transfers
             WHERE to_store_id = $1
             ORDER BY created_at DESC
@@ -213,6 +235,53 @@ impl TransferRepository for PgTransferRepository {
             .collect()
     }
 
+    async fn find_in_transit(&self) -> Result<Vec<StockTransfer>, InventoryError> {
+        let rows = sqlx::query_as::<_, TransferRow>(
+            r#"
+            SELECT id, transfer_number, from_store_id, to_store_id, status, requested_date,
+                   shipped_date, received_date, requested_by_id, shipped_by_id, received_by_id,
+                   notes, shipping_method, tracking_number, requires_approval, approval_reason,
+                   approved_by_id, approved_at, approval_notes, created_at, updated_at
+            FROM stock_transfers
+            WHERE status = 'in_transit'
+            ORDER BY shipped_date
+            "#,
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        let ids: Vec<uuid::Uuid> = rows.iter().map(|r| r.id).collect();
+        let item_rows = sqlx::query_as::<_, TransferItemRow>(
+            r#"
+            SELECT id, transfer_id, product_id, variant_id, quantity_requested,
+                   quantity_shipped, quantity_received, unit_cost, notes, created_at
+            FROM stock_transfer_items
+            WHERE transfer_id = ANY($1)
+            ORDER BY created_at
+            "#,
+        )
+        .bind(&ids)
+        .fetch_all(&self.pool)
+        .await?;
+
+        let mut items_by_transfer: std::collections::HashMap<uuid::Uuid, Vec<TransferItem>> =
+            std::collections::HashMap::new();
+        for row in item_rows {
+            let transfer_id = row.transfer_id;
+            items_by_transfer
+                .entry(transfer_id)
+                .or_default()
+                .push(row.try_into()?);
+        }
+
+        rows.into_iter()
+            .map(|r| {
+                let items = items_by_transfer.remove(&r.id).unwrap_or_default();
+                r.try_into_with_items(items)
+            })
+            .collect()
+    }
+
     async fn update(&self, transfer: &StockTransfer) -> Result<(), InventoryError> {
         let mut tx = self.pool.begin().await?;
 
@@ -222,7 +291,9 @@ impl TransferRepository for PgTransferRepository {
             UPDATE stock_transfers
             SET status = $2, shipped_date = $3, received_date = $4,
                 shipped_by_id = $5, received_by_id = $6, notes = $7,
-                shipping_method = $8, tracking_number = $9, updated_at = $10
+                shipping_method = $8, tracking_number = $9, updated_at = $10,
+                requires_approval = $11, approval_reason = $12, approved_by_id = $13,
+                approved_at = $14, approval_notes = $15
             WHERE id = $1
             "#,
         )
@@ -236,6 +307,11 @@ impl TransferRepository for PgTransferRepository {
         .bind(transfer.shipping_method())
         .bind(transfer.tracking_number())
         .bind(transfer.updated_at())
+        .bind(transfer.requires_approval())
+        .bind(transfer.approval_reason())
+        .bind(transfer.approved_by_id().map(|id| id.into_uuid()))
+        .bind(transfer.approved_at())
+        .bind(transfer.approval_notes())
         .execute(&mut *tx)
         .await?;
 
@@ -282,22 +358,12 @@ impl TransferRepository for PgTransferRepository {
     }
 
     async fn generate_transfer_number(&self) -> Result<String, InventoryError> {
-        // Generate globally unique transfer number: TRF-{YYYYMMDD}-{SEQUENCE}
-        let today = Utc::now().format("%Y%m%d").to_string();
-
-        // Count transfers created today
-        let count: (i64,) = sqlx::query_as(
-            r#"
-            SELECT COUNT(*) FROM stock_transfers 
-            WHERE transfer_number LIKE $1
-            "#,
-        )
-        .bind(format!("TRF-{}-%%", today))
-        .fetch_one(&self.pool)
-        .await?;
-
-        let sequence = count.0 + 1;
-        Ok(format!("TRF-{}-{:06}", today, sequence))
+        // Transfer numbers are unique across stores
+        let mut conn = self.pool.acquire().await?;
+        Ok(self
+            .numbering
+            .next_number(&mut conn, DocumentType::StockTransfer, None)
+            .await?)
     }
 }
 
@@ -313,7 +379,9 @@ impl PgTransferRepository {
             UPDATE stock_transfers
             SET status = $2, shipped_date = $3, received_date = $4,
                 shipped_by_id = $5, received_by_id = $6, notes = $7,
-                shipping_method = $8, tracking_number = $9, updated_at = $10
+                shipping_method = $8, tracking_number = $9, updated_at = $10,
+                requires_approval = $11, approval_reason = $12, approved_by_id = $13,
+                approved_at = $14, approval_notes = $15
             WHERE id = $1
             "#,
         )
@@ -327,6 +395,11 @@ impl PgTransferRepository {
         .bind(transfer.shipping_method())
         .bind(transfer.tracking_number())
         .bind(transfer.updated_at())
+        .bind(transfer.requires_approval())
+        .bind(transfer.approval_reason())
+        .bind(transfer.approved_by_id().map(|id| id.into_uuid()))
+        .bind(transfer.approved_at())
+        .bind(transfer.approval_notes())
         .execute(&mut **tx)
         .await?;
 
@@ -389,6 +462,11 @@ struct TransferRow {
     notes: Option<String>,
     shipping_method: Option<String>,
     tracking_number: Option<String>,
+    requires_approval: bool,
+    approval_reason: Option<String>,
+    approved_by_id: Option<uuid::Uuid>,
+    approved_at: Option<chrono::DateTime<chrono::Utc>>,
+    approval_notes: Option<String>,
     created_at: chrono::DateTime<chrono::Utc>,
     updated_at: chrono::DateTime<chrono::Utc>,
 }
@@ -419,6 +497,11 @@ impl TransferRow {
             self.notes,
             self.shipping_method,
             self.tracking_number,
+            self.requires_approval,
+            self.approval_reason,
+            self.approved_by_id.map(UserId::from_uuid),
+            self.approved_at,
+            self.approval_notes,
             items,
             self.created_at,
             self.updated_at,
@@ -0,0 +1,181 @@
+// PostgreSQL ValuationSnapshotRepository implementation
+
+use async_trait::async_trait;
+use chrono::{DateTime, NaiveDate, Utc};
+use rust_decimal::Decimal;
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::InventoryError;
+use crate::domain::entities::{ValuationSnapshot, ValuationSnapshotLine};
+use crate::domain::repositories::ValuationSnapshotRepository;
+use crate::domain::value_objects::{ProductId, StockId, ValuationSnapshotId, VariantId};
+use identity::{StoreId, UserId};
+
+/// PostgreSQL implementation of ValuationSnapshotRepository
+pub struct PgValuationSnapshotRepository {
+    pool: PgPool,
+}
+
+impl PgValuationSnapshotRepository {
+    /// Creates a new PgValuationSnapshotRepository with the given connection pool
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl ValuationSnapshotRepository for PgValuationSnapshotRepository {
+    async fn save(&self, snapshot: &ValuationSnapshot) -> Result<bool, InventoryError> {
+        let mut tx = self.pool.begin().await?;
+
+        let result = sqlx::query(
+            r#"
+            INSERT INTO inventory_valuation_snapshots (
+                id, store_id, period_end, currency, total_value, created_by_id, created_at
+            )
+            VALUES ($1, $2, $3, $4, $5, $6, $7)
+            ON CONFLICT (store_id, period_end) DO NOTHING
+            "#,
+        )
+        .bind(snapshot.id().into_uuid())
+        .bind(snapshot.store_id().into_uuid())
+        .bind(snapshot.period_end())
+        .bind(snapshot.currency())
+        .bind(snapshot.total_value())
+        .bind(snapshot.created_by_id().into_uuid())
+        .bind(snapshot.created_at())
+        .execute(&mut *tx)
+        .await?;
+
+        if result.rows_affected() == 0 {
+            return Ok(false);
+        }
+
+        const CHUNK_SIZE: usize = 4000;
+
+        for chunk in snapshot.lines().chunks(CHUNK_SIZE) {
+            let mut query_builder = sqlx::QueryBuilder::new(
+                r#"INSERT INTO inventory_valuation_snapshot_lines (
+                    snapshot_id, stock_id, product_id, variant_id, product_name, variant_name,
+                    sku, quantity, costing_method, unit_cost, total_value
+                ) "#,
+            );
+
+            query_builder.push_values(chunk, |mut b, line| {
+                b.push_bind(snapshot.id().into_uuid())
+                    .push_bind(line.stock_id.into_uuid())
+                    .push_bind(line.product_id.map(|id| id.into_uuid()))
+                    .push_bind(line.variant_id.map(|id| id.into_uuid()))
+                    .push_bind(line.product_name.clone())
+                    .push_bind(line.variant_name.clone())
+                    .push_bind(line.sku.clone())
+                    .push_bind(line.quantity)
+                    .push_bind(line.costing_method.clone())
+                    .push_bind(line.unit_cost)
+                    .push_bind(line.total_value);
+            });
+
+            query_builder.build().execute(&mut *tx).await?;
+        }
+
+        tx.commit().await?;
+        Ok(true)
+    }
+
+    async fn find_by_store_and_period(
+        &self,
+        store_id: StoreId,
+        period_end: NaiveDate,
+    ) -> Result<Option<ValuationSnapshot>, InventoryError> {
+        let row = sqlx::query_as::<_, ValuationSnapshotRow>(
+            r#"
+            SELECT id, store_id, period_end, currency, created_by_id, created_at
+            FROM inventory_valuation_snapshots
+            WHERE store_id = $1 AND period_end = $2
+            "#,
+        )
+        .bind(store_id.into_uuid())
+        .bind(period_end)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        let Some(row) = row else {
+            return Ok(None);
+        };
+
+        let lines = sqlx::query_as::<_, ValuationSnapshotLineRow>(
+            r#"
+            SELECT stock_id, product_id, variant_id, product_name, variant_name, sku,
+                   quantity, costing_method, unit_cost, total_value
+            FROM inventory_valuation_snapshot_lines
+            WHERE snapshot_id = $1
+            ORDER BY product_name NULLS LAST, variant_name NULLS FIRST, stock_id
+            "#,
+        )
+        .bind(row.id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(Some(row.into_snapshot(
+            lines.into_iter().map(ValuationSnapshotLine::from).collect(),
+        )))
+    }
+}
+
+/// Internal row type for mapping snapshot database results
+#[derive(sqlx::FromRow)]
+struct ValuationSnapshotRow {
+    id: Uuid,
+    store_id: Uuid,
+    period_end: NaiveDate,
+    currency: String,
+    created_by_id: Uuid,
+    created_at: DateTime<Utc>,
+}
+
+impl ValuationSnapshotRow {
+    fn into_snapshot(self, lines: Vec<ValuationSnapshotLine>) -> ValuationSnapshot {
+        ValuationSnapshot::reconstitute(
+            ValuationSnapshotId::from_uuid(self.id),
+            StoreId::from_uuid(self.store_id),
+            self.period_end,
+            self.currency,
+            lines,
+            UserId::from_uuid(self.created_by_id),
+            self.created_at,
+        )
+    }
+}
+
+/// Internal row type for mapping snapshot line database results
+#[derive(sqlx::FromRow)]
+struct ValuationSnapshotLineRow {
+    stock_id: Uuid,
+    product_id: Option<Uuid>,
+    variant_id: Option<Uuid>,
+    product_name: Option<String>,
+    variant_name: Option<String>,
+    sku: Option<String>,
+    quantity: Decimal,
+    costing_method: String,
+    unit_cost: Decimal,
+    total_value: Decimal,
+}
+
+impl From<ValuationSnapshotLineRow> for ValuationSnapshotLine {
+    fn from(row: ValuationSnapshotLineRow) -> Self {
+        ValuationSnapshotLine {
+            stock_id: StockId::from_uuid(row.stock_id),
+            product_id: row.product_id.map(ProductId::from_uuid),
+            variant_id: row.variant_id.map(VariantId::from_uuid),
+            product_name: row.product_name,
+            variant_name: row.variant_name,
+            sku: row.sku,
+            quantity: row.quantity,
+            costing_method: row.costing_method,
+            unit_cost: row.unit_cost,
+            total_value: row.total_value,
+        }
+    }
+}
@@ -11,6 +11,7 @@
 //! - **Recipe/BOM Management**: Bill of materials for composite products
 //! - **Stock Adjustments**: Inventory corrections with approval workflow
 //! - **Inter-store Transfers**: Stock movement between locations with shipping workflow
+//! - **Batch Repricing**: Markup-rule price previews from vendor cost updates, with price history
 //!
 //! ## Architecture
 //!
@@ -46,7 +47,8 @@
 //!     ..Default::default()
 //! };
 //!
-//! let use_case = CreateProductUseCase::new(product_repo, category_repo, audit_repo);
+//! let use_case =
+//!     CreateProductUseCase::new(product_repo, category_repo, barcode_repo, audit_repo);
 //! let product = use_case.execute(command, actor_id).await?;
 //! ```
 
@@ -71,37 +73,71 @@ pub use error::InventoryError;
 pub use domain::value_objects::AdjustmentId;
 pub use domain::value_objects::CategoryId;
 pub use domain::value_objects::IngredientId;
+pub use domain::value_objects::InventoryPeriodId;
+pub use domain::value_objects::LotId;
 pub use domain::value_objects::MovementId;
+pub use domain::value_objects::PriceChangeId;
 pub use domain::value_objects::ProductId;
 pub use domain::value_objects::RecipeId;
+pub use domain::value_objects::RepriceBatchId;
 pub use domain::value_objects::ReservationId;
+pub use domain::value_objects::SerialId;
 pub use domain::value_objects::StockId;
 pub use domain::value_objects::SubstituteId;
 pub use domain::value_objects::TransferId;
+pub use domain::value_objects::ValuationSnapshotId;
 pub use domain::value_objects::VariantId;
 
 // Validated value objects
+pub use domain::value_objects::AdjustmentApprovalLimit;
+pub use domain::value_objects::AdjustmentApprovalPolicy;
 pub use domain::value_objects::Barcode;
+pub use domain::value_objects::BarcodePrefix;
 pub use domain::value_objects::Currency;
+pub use domain::value_objects::EAN13_LENGTH;
+pub use domain::value_objects::MAX_SKU_LENGTH;
+pub use domain::value_objects::MarkupRule;
+pub use domain::value_objects::PriceAdjustment;
+pub use domain::value_objects::PriceAdjustmentAmount;
 pub use domain::value_objects::Sku;
+pub use domain::value_objects::TransferApprovalPolicy;
+pub use domain::value_objects::UnitConversion;
 pub use domain::value_objects::UnitOfMeasure;
+pub use domain::value_objects::ean13_check_digit;
 
 // Enum value objects
 pub use domain::value_objects::AdjustmentReason;
 pub use domain::value_objects::AdjustmentStatus;
 pub use domain::value_objects::AdjustmentType;
+pub use domain::value_objects::CostingMethod;
 pub use domain::value_objects::MovementType;
+pub use domain::value_objects::PeriodStatus;
+pub use domain::value_objects::RepriceBatchStatus;
+pub use domain::value_objects::ReservationGracePolicy;
+pub use domain::value_objects::ReservationPriorityPolicy;
 pub use domain::value_objects::ReservationStatus;
+pub use domain::value_objects::SerialStatus;
+pub use domain::value_objects::TaxCategory;
 pub use domain::value_objects::TransferStatus;
 
+// Tax category resolution
+pub use domain::value_objects::EffectiveTaxCategory;
+pub use domain::value_objects::TaxCategorySource;
+
 // -----------------------------------------------------------------------------
 // Domain Layer - Entities
 // -----------------------------------------------------------------------------
 
 // Product catalog entities
+pub use domain::entities::InventoryLot;
+pub use domain::entities::MAX_AUTO_EXTEND_SECONDS;
+pub use domain::entities::MAX_SERIAL_NUMBER_LENGTH;
 pub use domain::entities::Product;
 pub use domain::entities::ProductCategory;
+pub use domain::entities::ProductPriceChange;
+pub use domain::entities::ProductSerial;
 pub use domain::entities::ProductVariant;
+pub use domain::entities::ProductVendor;
 
 // Stock management entities
 pub use domain::entities::InventoryMovement;
@@ -119,38 +155,90 @@ pub use domain::entities::StockAdjustment;
 pub use domain::entities::StockTransfer;
 pub use domain::entities::TransferItem;
 
+// Repricing entities
+pub use domain::entities::RepriceBatch;
+pub use domain::entities::RepriceLine;
+
+// Valuation snapshot entities
+pub use domain::entities::ValuationSnapshot;
+pub use domain::entities::ValuationSnapshotLine;
+
+// Period close entities
+pub use domain::entities::InventoryPeriod;
+
+// -----------------------------------------------------------------------------
+// Domain Layer - Services
+// -----------------------------------------------------------------------------
+
+pub use domain::services::UnitConverter;
+
 // -----------------------------------------------------------------------------
 // Domain Layer - Repository Traits
 // -----------------------------------------------------------------------------
 
 pub use domain::repositories::AdjustmentRepository;
+pub use domain::repositories::AverageCostRewrite;
+pub use domain::repositories::BarcodeSequenceRepository;
 pub use domain::repositories::CategoryRepository;
+pub use domain::repositories::CategoryStockValuation;
+pub use domain::repositories::CompositeCostSource;
+pub use domain::repositories::CostLayer;
+pub use domain::repositories::InventoryLotRepository;
 pub use domain::repositories::InventoryMovementRepository;
+pub use domain::repositories::InventoryPeriodRepository;
 pub use domain::repositories::InventoryStockRepository;
+pub use domain::repositories::ItemUnitCost;
 pub use domain::repositories::MovementQuery;
+pub use domain::repositories::PriceHistoryRepository;
+pub use domain::repositories::PriceUpdate;
+pub use domain::repositories::PriceUpdateRepository;
+pub use domain::repositories::ProductImportBatch;
+pub use domain::repositories::ProductImportRepository;
 pub use domain::repositories::ProductRepository;
+pub use domain::repositories::ProductSerialRepository;
 pub use domain::repositories::RecipeRepository;
+pub use domain::repositories::RepriceBatchRepository;
 pub use domain::repositories::ReservationRepository;
+pub use domain::repositories::ReservationStatusCount;
 pub use domain::repositories::TransferRepository;
+pub use domain::repositories::ValuationSnapshotRepository;
 
 // -----------------------------------------------------------------------------
 // Application Layer - Use Cases
 // -----------------------------------------------------------------------------
 
 // Product and category use cases
+pub use application::use_cases::BulkUpdatePricesUseCase;
+pub use application::use_cases::ConfigureCategoryBarcodePrefixUseCase;
 pub use application::use_cases::CreateCategoryUseCase;
 pub use application::use_cases::CreateProductUseCase;
 pub use application::use_cases::CreateVariantUseCase;
 pub use application::use_cases::DeleteCategoryUseCase;
 pub use application::use_cases::DeleteProductUseCase;
 pub use application::use_cases::DeleteVariantUseCase;
+pub use application::use_cases::ExportProductsQuery;
+pub use application::use_cases::ExportProductsUseCase;
+pub use application::use_cases::GenerateVariantsResult;
+pub use application::use_cases::GenerateVariantsUseCase;
 pub use application::use_cases::GetCategoryUseCase;
 pub use application::use_cases::GetProductUseCase;
 pub use application::use_cases::GetVariantUseCase;
+pub use application::use_cases::ImportProductsUseCase;
 pub use application::use_cases::ListCategoriesUseCase;
 pub use application::use_cases::ListProductsQuery;
 pub use application::use_cases::ListProductsUseCase;
 pub use application::use_cases::ListVariantsUseCase;
+pub use application::use_cases::MAX_GENERATED_VARIANTS;
+pub use application::use_cases::MAX_VARIANT_CODE_LENGTH;
+pub use application::use_cases::MAX_VENDOR_SKU_LENGTH;
+pub use application::use_cases::MoveCategoryUseCase;
+pub use application::use_cases::ProductExportColumn;
+pub use application::use_cases::ReorderCategoriesUseCase;
+pub use application::use_cases::SearchProductsQuery;
+pub use application::use_cases::SearchProductsUseCase;
+pub use application::use_cases::SetProductUnitConversionsUseCase;
+pub use application::use_cases::SetProductVendorUseCase;
+pub use application::use_cases::SkippedVariantCombination;
 pub use application::use_cases::UpdateCategoryUseCase;
 pub use application::use_cases::UpdateProductUseCase;
 pub use application::use_cases::UpdateVariantUseCase;
@@ -164,6 +252,7 @@ pub use application::use_cases::ConfirmReservationUseCase;
 pub use application::use_cases::CreateReservationUseCase;
 pub use application::use_cases::ExpireReservationsResult;
 pub use application::use_cases::ExpireReservationsUseCase;
+pub use application::use_cases::FailedReservationLine;
 pub use application::use_cases::GetLowStockAlertsUseCase;
 pub use application::use_cases::GetProductStockUseCase;
 pub use application::use_cases::GetStockUseCase;
@@ -173,18 +262,46 @@ pub use application::use_cases::ListReservationsQuery;
 pub use application::use_cases::ListReservationsUseCase;
 pub use application::use_cases::ListStockQuery;
 pub use application::use_cases::ListStockUseCase;
+pub use application::use_cases::ReReservedLine;
+pub use application::use_cases::RecalculateAverageCostUseCase;
+pub use application::use_cases::RegisterSerialsUseCase;
+pub use application::use_cases::RevalidateReservationsResult;
+pub use application::use_cases::RevalidateReservationsUseCase;
+pub use application::use_cases::TouchReservationUseCase;
 pub use application::use_cases::UpdateStockLevelsUseCase;
+pub use application::use_cases::UpdateStockResult;
 pub use application::use_cases::UpdateStockUseCase;
 
 // Stock history and report use cases
+pub use application::use_cases::CategoryRollupQuery;
+pub use application::use_cases::GetInTransitStockUseCase;
+pub use application::use_cases::GetInventoryCategoryRollupUseCase;
 pub use application::use_cases::GetLowStockReportUseCase;
 pub use application::use_cases::GetMovementsReportUseCase;
+pub use application::use_cases::GetReservationConversionReportUseCase;
 pub use application::use_cases::GetStockHistoryUseCase;
 pub use application::use_cases::GetValuationReportUseCase;
 pub use application::use_cases::LowStockReportQuery;
 pub use application::use_cases::MovementsReportQuery;
+pub use application::use_cases::ReservationConversionQuery;
 pub use application::use_cases::StockHistoryQuery;
 pub use application::use_cases::ValuationReportQuery;
+pub use application::use_cases::{
+    DEFAULT_EXPIRY_WINDOW_DAYS, ExpiringStockReportQuery, GetExpiringStockReportUseCase,
+};
+pub use application::use_cases::{
+    DEFAULT_TRANSIT_OVERDUE_DAYS, GetInTransitReportUseCase, InTransitReportQuery,
+};
+pub use application::use_cases::{GetInventoryAgingReportUseCase, InventoryAgingReportQuery};
+
+// Valuation snapshot use cases
+pub use application::use_cases::CreateValuationSnapshotUseCase;
+pub use application::use_cases::GetValuationSnapshotUseCase;
+
+// Period close use cases
+pub use application::use_cases::ClosePeriodUseCase;
+pub use application::use_cases::ListPeriodsUseCase;
+pub use application::use_cases::ReopenPeriodUseCase;
 
 // Recipe use cases
 pub use application::use_cases::CalculateRecipeCostUseCase;
@@ -194,7 +311,11 @@ pub use application::use_cases::GetRecipeUseCase;
 pub use application::use_cases::ListRecipesQuery;
 pub use application::use_cases::ListRecipesUseCase;
 pub use application::use_cases::RecipeCostResult;
+pub use application::use_cases::RefreshCompositeCostsUseCase;
 pub use application::use_cases::UpdateRecipeUseCase;
+pub use application::use_cases::{
+    IngredientDraw, RecipeConsumption, ResolveRecipeConsumptionUseCase,
+};
 
 // Adjustment use cases
 pub use application::use_cases::ApplyAdjustmentUseCase;
@@ -206,6 +327,7 @@ pub use application::use_cases::ListAdjustmentsUseCase;
 pub use application::use_cases::SubmitAdjustmentUseCase;
 
 // Transfer use cases
+pub use application::use_cases::ApproveTransferUseCase;
 pub use application::use_cases::CancelTransferUseCase;
 pub use application::use_cases::CreateTransferUseCase;
 pub use application::use_cases::GetTransferUseCase;
@@ -215,31 +337,55 @@ pub use application::use_cases::ReceiveTransferUseCase;
 pub use application::use_cases::ShipTransferUseCase;
 pub use application::use_cases::SubmitTransferUseCase;
 
+// Reprice use cases
+pub use application::use_cases::ApproveRepriceUseCase;
+pub use application::use_cases::GetPriceHistoryUseCase;
+pub use application::use_cases::GetRepriceBatchUseCase;
+pub use application::use_cases::ListRepriceBatchesUseCase;
+pub use application::use_cases::PreviewRepriceUseCase;
+
 // -----------------------------------------------------------------------------
 // Application Layer - Command DTOs
 // -----------------------------------------------------------------------------
 
 // Category commands
+pub use application::dtos::ConfigureCategoryBarcodePrefixCommand;
 pub use application::dtos::CreateCategoryCommand;
+pub use application::dtos::MoveCategoryCommand;
+pub use application::dtos::ReorderCategoriesCommand;
 pub use application::dtos::UpdateCategoryCommand;
 
 // Product commands
+pub use application::dtos::BulkUpdatePricesCommand;
 pub use application::dtos::CreateProductCommand;
 pub use application::dtos::CreateVariantCommand;
+pub use application::dtos::GenerateVariantsCommand;
+pub use application::dtos::ImportProductRow;
+pub use application::dtos::ImportProductsCommand;
+pub use application::dtos::SetProductUnitConversionsCommand;
+pub use application::dtos::SetProductVendorCommand;
+pub use application::dtos::UnitConversionInput;
 pub use application::dtos::UpdateProductCommand;
 pub use application::dtos::UpdateVariantCommand;
+pub use application::dtos::VariantAxis;
+pub use application::dtos::VariantCombinationOverride;
 
 // Stock commands
 pub use application::dtos::BulkInitializeStockCommand;
 pub use application::dtos::BulkInitializeStockItem;
 pub use application::dtos::InitializeStockCommand;
+pub use application::dtos::RecalculateAverageCostCommand;
 pub use application::dtos::UpdateStockCommand;
 pub use application::dtos::UpdateStockLevelsCommand;
 
+// Serial number commands
+pub use application::dtos::RegisterSerialsCommand;
+
 // Reservation commands
 pub use application::dtos::CancelReservationCommand;
 pub use application::dtos::ConfirmReservationCommand;
 pub use application::dtos::CreateReservationCommand;
+pub use application::dtos::TouchReservationCommand;
 
 // Recipe commands
 pub use application::dtos::CreateRecipeCommand;
@@ -255,6 +401,7 @@ pub use application::dtos::CreateAdjustmentCommand;
 pub use application::dtos::SubmitAdjustmentCommand;
 
 // Transfer commands
+pub use application::dtos::ApproveTransferCommand;
 pub use application::dtos::CreateTransferCommand;
 pub use application::dtos::ReceiveTransferCommand;
 pub use application::dtos::ReceiveTransferItemCommand;
@@ -262,23 +409,47 @@ pub use application::dtos::ShipTransferCommand;
 pub use application::dtos::ShipTransferItemCommand;
 pub use application::dtos::TransferItemCommand;
 
+// Valuation snapshot commands
+pub use application::dtos::CreateValuationSnapshotCommand;
+
+// Period close commands
+pub use application::dtos::ClosePeriodCommand;
+
+// Reprice commands
+pub use application::dtos::ApproveRepriceCommand;
+pub use application::dtos::PreviewRepriceCommand;
+pub use application::dtos::RepriceCostItemCommand;
+
 // -----------------------------------------------------------------------------
 // Application Layer - Response DTOs
 // -----------------------------------------------------------------------------
 
 // Category responses
+pub use application::dtos::CategoryBarcodePrefixResponse;
 pub use application::dtos::CategoryResponse;
 pub use application::dtos::CategoryTreeResponse;
 
 // Product responses
+pub use application::dtos::BulkUpdatePricesResponse;
+pub use application::dtos::ImportProductRowResult;
+pub use application::dtos::ImportProductsResponse;
+pub use application::dtos::ImportRowOutcome;
 pub use application::dtos::ProductDetailResponse;
+pub use application::dtos::ProductExportRow;
 pub use application::dtos::ProductResponse;
+pub use application::dtos::ProductSearchResultResponse;
+pub use application::dtos::ProductVendorResponse;
+pub use application::dtos::UnitConversionResponse;
 pub use application::dtos::VariantResponse;
 
 // Stock responses
+pub use application::dtos::AverageCostRecalculationResponse;
 pub use application::dtos::StockDetailResponse;
 pub use application::dtos::StockResponse;
 
+// Serial number responses
+pub use application::dtos::ProductSerialResponse;
+
 // Reservation responses
 pub use application::dtos::ReservationResponse;
 
@@ -287,16 +458,38 @@ pub use application::dtos::MovementResponse;
 pub use application::dtos::StockHistoryResponse;
 
 // Report responses
+pub use application::dtos::CategoryRollupNode;
+pub use application::dtos::CategoryRollupReportResponse;
+pub use application::dtos::InTransitDestinationResponse;
+pub use application::dtos::InTransitItemResponse;
+pub use application::dtos::InTransitReportResponse;
+pub use application::dtos::InTransitStockResponse;
+pub use application::dtos::InTransitStoreTotalResponse;
+pub use application::dtos::InTransitTransferResponse;
 pub use application::dtos::LowStockItemResponse;
 pub use application::dtos::LowStockReportResponse;
+pub use application::dtos::ReservationChannelConversion;
+pub use application::dtos::ReservationConversionReportResponse;
+pub use application::dtos::ReservationConversionStats;
+pub use application::dtos::ReservationDayConversion;
 pub use application::dtos::ValuationItemResponse;
 pub use application::dtos::ValuationReportResponse;
+pub use application::dtos::ValuationSnapshotResponse;
+
+// Period close responses
+pub use application::dtos::InventoryPeriodResponse;
+pub use application::dtos::{
+    AgingBucketResponse, InventoryAgingItemResponse, InventoryAgingReportResponse,
+};
+pub use application::dtos::{ExpiringLotResponse, ExpiringStockReportResponse};
 
 // Recipe responses
+pub use application::dtos::CompositeCostChangeResponse;
 pub use application::dtos::IngredientSubstituteResponse;
 pub use application::dtos::RecipeDetailResponse;
 pub use application::dtos::RecipeIngredientResponse;
 pub use application::dtos::RecipeResponse;
+pub use application::dtos::RefreshCompositeCostsResponse;
 
 // Adjustment responses
 pub use application::dtos::AdjustmentDetailResponse;
@@ -308,6 +501,11 @@ pub use application::dtos::TransferDetailResponse;
 pub use application::dtos::TransferItemResponse;
 pub use application::dtos::TransferResponse;
 
+// Reprice responses
+pub use application::dtos::PriceChangeResponse;
+pub use application::dtos::RepriceBatchResponse;
+pub use application::dtos::RepriceLineResponse;
+
 // List and Pagination
 pub use application::dtos::ListResponse;
 pub use application::dtos::PaginatedResponse;
@@ -317,10 +515,19 @@ pub use application::dtos::PaginatedResponse;
 // -----------------------------------------------------------------------------
 
 pub use infrastructure::persistence::PgAdjustmentRepository;
+pub use infrastructure::persistence::PgBarcodeSequenceRepository;
 pub use infrastructure::persistence::PgCategoryRepository;
+pub use infrastructure::persistence::PgInventoryLotRepository;
 pub use infrastructure::persistence::PgInventoryMovementRepository;
+pub use infrastructure::persistence::PgInventoryPeriodRepository;
 pub use infrastructure::persistence::PgInventoryStockRepository;
+pub use infrastructure::persistence::PgPriceHistoryRepository;
+pub use infrastructure::persistence::PgPriceUpdateRepository;
+pub use infrastructure::persistence::PgProductImportRepository;
 pub use infrastructure::persistence::PgProductRepository;
+pub use infrastructure::persistence::PgProductSerialRepository;
 pub use infrastructure::persistence::PgRecipeRepository;
+pub use infrastructure::persistence::PgRepriceBatchRepository;
 pub use infrastructure::persistence::PgReservationRepository;
 pub use infrastructure::persistence::PgTransferRepository;
+pub use infrastructure::persistence::PgValuationSnapshotRepository;
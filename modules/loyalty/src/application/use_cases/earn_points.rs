@@ -1,11 +1,13 @@
 //! EarnPointsUseCase — admin-driven (or v1.1 subscriber-driven) earn flow.
 //! Uses the ledger's atomic post operation so concurrent earns from multiple
 //! requests don't lose updates. After the post, re-evaluates the member's
-//! tier in case the new lifetime crossed a threshold.
+//! tier in case the new lifetime crossed a threshold. Refused when the
+//! program's store has loyalty turned off in its settings.
 
 use std::sync::Arc;
 
 use chrono::{Duration, Utc};
+use identity::StoreSettings;
 use uuid::Uuid;
 
 use super::tier_advancement::maybe_advance_tier;
@@ -44,7 +46,13 @@ impl EarnPointsUseCase {
         member_id: LoyaltyMemberId,
         actor_id: Option<Uuid>,
         cmd: EarnPointsCommand,
+        settings: &StoreSettings,
     ) -> Result<PostPointsResult, LoyaltyError> {
+        if !settings.loyalty_enabled() {
+            return Err(LoyaltyError::LoyaltyDisabled(
+                settings.store_id().into_uuid(),
+            ));
+        }
         if cmd.points <= 0 {
             return Err(LoyaltyError::NegativeAmount(cmd.points));
         }
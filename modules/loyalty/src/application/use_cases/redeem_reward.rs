@@ -1,8 +1,10 @@
 //! RedeemRewardUseCase — checks the member has enough balance, that the
 //! reward belongs to the same program, and that the per-member cap (if any)
 //! hasn't been hit; then posts a `Redeem` ledger entry and writes a voucher
-//! `RewardRedemption` row.
+//! `RewardRedemption` row. Refused when the program's store has loyalty
+//! turned off in its settings.
 
+use identity::StoreSettings;
 use std::sync::Arc;
 use uuid::Uuid;
 
@@ -41,7 +43,13 @@ impl RedeemRewardUseCase {
         member_id: LoyaltyMemberId,
         actor_id: Option<Uuid>,
         cmd: RedeemRewardCommand,
+        settings: &StoreSettings,
     ) -> Result<RewardRedemption, LoyaltyError> {
+        if !settings.loyalty_enabled() {
+            return Err(LoyaltyError::LoyaltyDisabled(
+                settings.store_id().into_uuid(),
+            ));
+        }
         let member = self
             .members
             .find_by_id(member_id)
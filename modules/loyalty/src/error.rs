@@ -24,6 +24,9 @@ pub enum LoyaltyError {
     #[error("Reward {reward_id} belongs to a different program than member {member_id}")]
     RewardProgramMismatch { reward_id: Uuid, member_id: Uuid },
 
+    #[error("Loyalty is disabled for store {0}")]
+    LoyaltyDisabled(Uuid),
+
     #[error("Insufficient points: balance={balance} required={required}")]
     InsufficientPoints { balance: i64, required: i64 },
 
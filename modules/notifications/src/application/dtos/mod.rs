@@ -1,5 +1,9 @@
 //! DTOs for the notifications module.
 
+mod register_webhook_command;
 mod send_notification_command;
+mod webhook_responses;
 
+pub use register_webhook_command::RegisterWebhookCommand;
 pub use send_notification_command::SendNotificationCommand;
+pub use webhook_responses::{WebhookDeliveryResponse, WebhookResponse};
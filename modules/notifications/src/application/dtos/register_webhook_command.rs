@@ -0,0 +1,14 @@
+//! DTO for `RegisterWebhookUseCase`.
+
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RegisterWebhookCommand {
+    pub store_id: Uuid,
+    pub url: String,
+    /// Shared secret the deliveries are signed with (HMAC-SHA256).
+    pub secret: String,
+    /// Outbox event types to receive, e.g. `"stock.updated"`.
+    pub event_types: Vec<String>,
+}
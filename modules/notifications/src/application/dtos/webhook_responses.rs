@@ -0,0 +1,72 @@
+//! Response DTOs for webhook registrations and deliveries.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::domain::entities::{Webhook, WebhookDelivery};
+
+/// A webhook registration. The secret is never returned.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WebhookResponse {
+    pub id: Uuid,
+    pub store_id: Uuid,
+    pub url: String,
+    pub event_types: Vec<String>,
+    pub is_active: bool,
+    pub created_by_id: Uuid,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl From<&Webhook> for WebhookResponse {
+    fn from(w: &Webhook) -> Self {
+        Self {
+            id: w.id().into_uuid(),
+            store_id: w.store_id().into_uuid(),
+            url: w.url().to_string(),
+            event_types: w.event_types().to_vec(),
+            is_active: w.is_active(),
+            created_by_id: w.created_by_id().into_uuid(),
+            created_at: w.created_at(),
+            updated_at: w.updated_at(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WebhookDeliveryResponse {
+    pub id: Uuid,
+    pub webhook_id: Uuid,
+    pub outbox_event_id: Uuid,
+    pub event_type: String,
+    pub payload: serde_json::Value,
+    pub status: String,
+    pub attempts: i32,
+    pub next_attempt_at: DateTime<Utc>,
+    pub last_response_status: Option<i32>,
+    pub last_error: Option<String>,
+    pub delivered_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl From<&WebhookDelivery> for WebhookDeliveryResponse {
+    fn from(d: &WebhookDelivery) -> Self {
+        Self {
+            id: d.id().into_uuid(),
+            webhook_id: d.webhook_id().into_uuid(),
+            outbox_event_id: d.outbox_event_id(),
+            event_type: d.event_type().to_string(),
+            payload: d.payload().clone(),
+            status: d.status().to_string(),
+            attempts: d.attempts(),
+            next_attempt_at: d.next_attempt_at(),
+            last_response_status: d.last_response_status(),
+            last_error: d.last_error().map(str::to_string),
+            delivered_at: d.delivered_at(),
+            created_at: d.created_at(),
+            updated_at: d.updated_at(),
+        }
+    }
+}
@@ -2,3 +2,4 @@
 
 pub mod dtos;
 pub mod use_cases;
+pub mod webhook_event_publisher;
@@ -0,0 +1,366 @@
+//! DispatchWebhooksUseCase — POSTs due event webhook deliveries.
+//!
+//! Run periodically by `api-gateway::jobs::webhook_dispatcher`. Deliveries
+//! are created from outbox events by [`WebhookEventPublisher`]; each
+//! invocation attempts up to `batch_size` of those that are due. Every
+//! attempt is signed with the webhook's secret (HMAC-SHA256) and a fresh
+//! timestamp. A 2xx response delivers it; anything else is retried with
+//! exponential backoff until the [`WebhookRetryPolicy`] gives up and the
+//! delivery lands in the dead-letter queue.
+//!
+//! [`WebhookEventPublisher`]: crate::WebhookEventPublisher
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use chrono::Utc;
+use serde_json::json;
+
+use crate::NotificationsError;
+use crate::domain::entities::{Webhook, WebhookDelivery};
+use crate::domain::repositories::{WebhookDeliveryRepository, WebhookRepository};
+use crate::domain::value_objects::{
+    DEFAULT_SIGNATURE_TOLERANCE_SECS, SignatureAlgorithm, WebhookId, WebhookRetryPolicy,
+    WebhookSignature,
+};
+use crate::infrastructure::adapters::{WebhookClient, WebhookRequest};
+
+pub struct DispatchWebhooksUseCase {
+    webhook_repo: Arc<dyn WebhookRepository>,
+    delivery_repo: Arc<dyn WebhookDeliveryRepository>,
+    client: Arc<dyn WebhookClient>,
+    policy: WebhookRetryPolicy,
+}
+
+impl DispatchWebhooksUseCase {
+    pub fn new(
+        webhook_repo: Arc<dyn WebhookRepository>,
+        delivery_repo: Arc<dyn WebhookDeliveryRepository>,
+        client: Arc<dyn WebhookClient>,
+    ) -> Self {
+        Self {
+            webhook_repo,
+            delivery_repo,
+            client,
+            policy: WebhookRetryPolicy::default(),
+        }
+    }
+
+    pub fn with_retry_policy(mut self, policy: WebhookRetryPolicy) -> Self {
+        self.policy = policy;
+        self
+    }
+
+    /// Attempt up to `batch_size` due deliveries. Returns how many were
+    /// attempted (regardless of success/failure).
+    pub async fn execute(&self, batch_size: i64) -> Result<usize, NotificationsError> {
+        let mut batch = self.delivery_repo.find_due(Utc::now(), batch_size).await?;
+        let count = batch.len();
+        let mut webhooks: HashMap<WebhookId, Option<Webhook>> = HashMap::new();
+
+        for delivery in batch.iter_mut() {
+            let webhook = match webhooks.get(&delivery.webhook_id()) {
+                Some(webhook) => webhook.clone(),
+                None => {
+                    let webhook = self.webhook_repo.find_by_id(delivery.webhook_id()).await?;
+                    webhooks.insert(delivery.webhook_id(), webhook.clone());
+                    webhook
+                }
+            };
+
+            match webhook.filter(|w| w.is_active()) {
+                Some(webhook) => self.attempt(&webhook, delivery).await,
+                None => delivery.dead_letter("webhook unregistered"),
+            }
+
+            if let Err(err) = self.delivery_repo.update(delivery).await {
+                tracing::error!(
+                    id = %delivery.id().into_uuid(),
+                    error = %err,
+                    "failed to persist webhook delivery update"
+                );
+            }
+        }
+
+        Ok(count)
+    }
+
+    async fn attempt(&self, webhook: &Webhook, delivery: &mut WebhookDelivery) {
+        let delivery_id = delivery.id().into_uuid();
+        let timestamp = Utc::now().timestamp();
+        let body = json!({
+            "id": delivery_id,
+            "event": delivery.event_type(),
+            "timestamp": timestamp,
+            "data": delivery.payload(),
+        })
+        .to_string()
+        .into_bytes();
+        let signature = WebhookSignature::compute(
+            SignatureAlgorithm::HmacSha256,
+            webhook.secret().as_bytes(),
+            delivery_id,
+            timestamp,
+            &body,
+        );
+
+        let request = WebhookRequest {
+            url: webhook.url().to_string(),
+            delivery_id,
+            timestamp,
+            signature: signature.to_header(),
+            tolerance_secs: DEFAULT_SIGNATURE_TOLERANCE_SECS,
+            body,
+        };
+
+        match self.client.post(&request).await {
+            Ok(status) if (200..300).contains(&status) => delivery.mark_delivered(status as i32),
+            Ok(status) => delivery.mark_attempt_failed(
+                Some(status as i32),
+                format!("receiver responded {status}"),
+                &self.policy,
+            ),
+            Err(error) => delivery.mark_attempt_failed(None, error, &self.policy),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use async_trait::async_trait;
+    use chrono::{DateTime, Utc};
+    use identity::{StoreId, UserId};
+    use std::sync::Mutex;
+
+    use crate::domain::value_objects::WebhookDeliveryStatus;
+
+    const SECRET: &str = "0123456789abcdef";
+
+    struct MockWebhookRepository {
+        webhooks: Vec<Webhook>,
+    }
+
+    #[async_trait]
+    impl WebhookRepository for MockWebhookRepository {
+        async fn save(&self, _webhook: &Webhook) -> Result<(), NotificationsError> {
+            unimplemented!()
+        }
+
+        async fn update(&self, _webhook: &Webhook) -> Result<(), NotificationsError> {
+            unimplemented!()
+        }
+
+        async fn find_by_id(&self, id: WebhookId) -> Result<Option<Webhook>, NotificationsError> {
+            Ok(self.webhooks.iter().find(|w| w.id() == id).cloned())
+        }
+
+        async fn find_active_by_store(
+            &self,
+            _store_id: StoreId,
+        ) -> Result<Vec<Webhook>, NotificationsError> {
+            unimplemented!()
+        }
+
+        async fn find_active_for_event(
+            &self,
+            _store_id: StoreId,
+            _event_type: &str,
+        ) -> Result<Vec<Webhook>, NotificationsError> {
+            unimplemented!()
+        }
+    }
+
+    struct MockWebhookDeliveryRepository {
+        deliveries: Mutex<Vec<WebhookDelivery>>,
+    }
+
+    #[async_trait]
+    impl WebhookDeliveryRepository for MockWebhookDeliveryRepository {
+        async fn enqueue(&self, _deliveries: &[WebhookDelivery]) -> Result<(), NotificationsError> {
+            unimplemented!()
+        }
+
+        async fn update(&self, delivery: &WebhookDelivery) -> Result<(), NotificationsError> {
+            let mut deliveries = self.deliveries.lock().unwrap();
+            if let Some(existing) = deliveries.iter_mut().find(|d| d.id() == delivery.id()) {
+                *existing = delivery.clone();
+            }
+            Ok(())
+        }
+
+        async fn find_due(
+            &self,
+            now: DateTime<Utc>,
+            limit: i64,
+        ) -> Result<Vec<WebhookDelivery>, NotificationsError> {
+            Ok(self
+                .deliveries
+                .lock()
+                .unwrap()
+                .iter()
+                .filter(|d| {
+                    d.status() == WebhookDeliveryStatus::Pending && d.next_attempt_at() <= now
+                })
+                .take(limit as usize)
+                .cloned()
+                .collect())
+        }
+
+        async fn find_dead_letters(
+            &self,
+            _store_id: StoreId,
+            _webhook_id: Option<WebhookId>,
+            _limit: i64,
+        ) -> Result<Vec<WebhookDelivery>, NotificationsError> {
+            unimplemented!()
+        }
+    }
+
+    /// Answers every request with `status` and keeps the requests.
+    struct MockWebhookClient {
+        status: Result<u16, String>,
+        requests: Mutex<Vec<WebhookRequest>>,
+    }
+
+    #[async_trait]
+    impl WebhookClient for MockWebhookClient {
+        async fn post(&self, request: &WebhookRequest) -> Result<u16, String> {
+            self.requests.lock().unwrap().push(request.clone());
+            self.status.clone()
+        }
+    }
+
+    fn webhook() -> Webhook {
+        Webhook::register(
+            StoreId::new(),
+            "https://example.test/hooks",
+            SECRET,
+            vec!["stock.updated".into()],
+            UserId::new(),
+        )
+        .unwrap()
+    }
+
+    fn setup(
+        webhooks: Vec<Webhook>,
+        deliveries: Vec<WebhookDelivery>,
+        status: Result<u16, String>,
+    ) -> (
+        Arc<MockWebhookDeliveryRepository>,
+        Arc<MockWebhookClient>,
+        DispatchWebhooksUseCase,
+    ) {
+        let delivery_repo = Arc::new(MockWebhookDeliveryRepository {
+            deliveries: Mutex::new(deliveries),
+        });
+        let client = Arc::new(MockWebhookClient {
+            status,
+            requests: Mutex::new(vec![]),
+        });
+        let use_case = DispatchWebhooksUseCase::new(
+            Arc::new(MockWebhookRepository { webhooks }),
+            delivery_repo.clone(),
+            client.clone(),
+        )
+        .with_retry_policy(WebhookRetryPolicy::new(2, 60, 3600));
+        (delivery_repo, client, use_case)
+    }
+
+    fn delivery_for(webhook: &Webhook) -> WebhookDelivery {
+        WebhookDelivery::create(
+            webhook.id(),
+            uuid::Uuid::nil(),
+            "stock.updated",
+            json!({ "stock_id": "42" }),
+        )
+    }
+
+    #[tokio::test]
+    async fn delivers_signed_request() {
+        let webhook = webhook();
+        let delivery = delivery_for(&webhook);
+        let (repo, client, use_case) = setup(vec![webhook], vec![delivery.clone()], Ok(204));
+
+        assert_eq!(use_case.execute(10).await.unwrap(), 1);
+
+        let stored = &repo.deliveries.lock().unwrap()[0];
+        assert_eq!(stored.status(), WebhookDeliveryStatus::Delivered);
+        assert_eq!(stored.last_response_status(), Some(204));
+
+        let request = &client.requests.lock().unwrap()[0];
+        assert_eq!(request.delivery_id, delivery.id().into_uuid());
+        let body: serde_json::Value = serde_json::from_slice(&request.body).unwrap();
+        assert_eq!(body["event"], json!("stock.updated"));
+        assert_eq!(body["data"]["stock_id"], json!("42"));
+        let signature = WebhookSignature::parse(&request.signature).unwrap();
+        assert!(
+            signature
+                .verify(
+                    SECRET.as_bytes(),
+                    request.delivery_id,
+                    &request.body,
+                    request.timestamp,
+                    300
+                )
+                .is_ok()
+        );
+    }
+
+    #[tokio::test]
+    async fn failures_back_off_then_dead_letter() {
+        let webhook = webhook();
+        let delivery = delivery_for(&webhook);
+        let (repo, _client, use_case) = setup(vec![webhook], vec![delivery], Ok(500));
+
+        assert_eq!(use_case.execute(10).await.unwrap(), 1);
+        {
+            let stored = &repo.deliveries.lock().unwrap()[0];
+            assert_eq!(stored.status(), WebhookDeliveryStatus::Pending);
+            assert_eq!(stored.attempts(), 1);
+            assert!(stored.next_attempt_at() > Utc::now());
+        }
+        // Not due again until the backoff elapsed
+        assert_eq!(use_case.execute(10).await.unwrap(), 0);
+
+        {
+            let mut deliveries = repo.deliveries.lock().unwrap();
+            let d = deliveries[0].clone();
+            deliveries[0] = WebhookDelivery::reconstitute(
+                d.id(),
+                d.webhook_id(),
+                d.outbox_event_id(),
+                d.event_type().to_string(),
+                d.payload().clone(),
+                d.status(),
+                d.attempts(),
+                Utc::now(),
+                d.last_response_status(),
+                d.last_error().map(str::to_string),
+                d.delivered_at(),
+                d.created_at(),
+                d.updated_at(),
+            );
+        }
+        assert_eq!(use_case.execute(10).await.unwrap(), 1);
+
+        let stored = &repo.deliveries.lock().unwrap()[0];
+        assert_eq!(stored.status(), WebhookDeliveryStatus::DeadLetter);
+        assert_eq!(stored.attempts(), 2);
+        assert_eq!(stored.last_error(), Some("receiver responded 500"));
+    }
+
+    #[tokio::test]
+    async fn unregistered_webhook_dead_letters_delivery() {
+        let mut webhook = webhook();
+        let delivery = delivery_for(&webhook);
+        webhook.unregister();
+        let (repo, client, use_case) = setup(vec![webhook], vec![delivery], Ok(200));
+
+        use_case.execute(10).await.unwrap();
+
+        let stored = &repo.deliveries.lock().unwrap()[0];
+        assert_eq!(stored.status(), WebhookDeliveryStatus::DeadLetter);
+        assert!(client.requests.lock().unwrap().is_empty());
+    }
+}
@@ -0,0 +1,45 @@
+//! ListFailedWebhookDeliveriesUseCase — the dead-letter queue of a store's
+//! webhooks, most recently failed first.
+
+use std::sync::Arc;
+
+use identity::StoreId;
+use uuid::Uuid;
+
+use crate::NotificationsError;
+use crate::application::dtos::WebhookDeliveryResponse;
+use crate::domain::repositories::WebhookDeliveryRepository;
+use crate::domain::value_objects::WebhookId;
+
+/// Most dead letters returned in one call.
+pub const MAX_FAILED_DELIVERIES: i64 = 500;
+
+pub struct ListFailedWebhookDeliveriesUseCase {
+    repo: Arc<dyn WebhookDeliveryRepository>,
+}
+
+impl ListFailedWebhookDeliveriesUseCase {
+    pub fn new(repo: Arc<dyn WebhookDeliveryRepository>) -> Self {
+        Self { repo }
+    }
+
+    pub async fn execute(
+        &self,
+        store_id: Uuid,
+        webhook_id: Option<Uuid>,
+        limit: i64,
+    ) -> Result<Vec<WebhookDeliveryResponse>, NotificationsError> {
+        let deliveries = self
+            .repo
+            .find_dead_letters(
+                StoreId::from_uuid(store_id),
+                webhook_id.map(WebhookId::from_uuid),
+                limit.clamp(1, MAX_FAILED_DELIVERIES),
+            )
+            .await?;
+        Ok(deliveries
+            .iter()
+            .map(WebhookDeliveryResponse::from)
+            .collect())
+    }
+}
@@ -0,0 +1,31 @@
+//! ListWebhooksUseCase — the active webhooks of a store.
+
+use std::sync::Arc;
+
+use identity::StoreId;
+use uuid::Uuid;
+
+use crate::NotificationsError;
+use crate::application::dtos::WebhookResponse;
+use crate::domain::repositories::WebhookRepository;
+
+pub struct ListWebhooksUseCase {
+    repo: Arc<dyn WebhookRepository>,
+}
+
+impl ListWebhooksUseCase {
+    pub fn new(repo: Arc<dyn WebhookRepository>) -> Self {
+        Self { repo }
+    }
+
+    pub async fn execute(
+        &self,
+        store_id: Uuid,
+    ) -> Result<Vec<WebhookResponse>, NotificationsError> {
+        let webhooks = self
+            .repo
+            .find_active_by_store(StoreId::from_uuid(store_id))
+            .await?;
+        Ok(webhooks.iter().map(WebhookResponse::from).collect())
+    }
+}
@@ -1,7 +1,19 @@
 //! Use cases for the notifications module.
 
+mod dispatch_webhooks;
+mod list_failed_webhook_deliveries;
+mod list_webhooks;
+mod register_webhook;
 mod retry_failed_notifications;
 mod send_notification;
+mod unregister_webhook;
 
+pub use dispatch_webhooks::DispatchWebhooksUseCase;
+pub use list_failed_webhook_deliveries::{
+    ListFailedWebhookDeliveriesUseCase, MAX_FAILED_DELIVERIES,
+};
+pub use list_webhooks::ListWebhooksUseCase;
+pub use register_webhook::RegisterWebhookUseCase;
 pub use retry_failed_notifications::{DEFAULT_MAX_ATTEMPTS, RetryFailedNotificationsUseCase};
 pub use send_notification::SendNotificationUseCase;
+pub use unregister_webhook::UnregisterWebhookUseCase;
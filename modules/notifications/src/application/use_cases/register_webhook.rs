@@ -0,0 +1,37 @@
+//! RegisterWebhookUseCase — registers a store webhook for outbox events.
+
+use std::sync::Arc;
+
+use identity::{StoreId, UserId};
+
+use crate::NotificationsError;
+use crate::application::dtos::{RegisterWebhookCommand, WebhookResponse};
+use crate::domain::entities::Webhook;
+use crate::domain::repositories::WebhookRepository;
+
+pub struct RegisterWebhookUseCase {
+    repo: Arc<dyn WebhookRepository>,
+}
+
+impl RegisterWebhookUseCase {
+    pub fn new(repo: Arc<dyn WebhookRepository>) -> Self {
+        Self { repo }
+    }
+
+    pub async fn execute(
+        &self,
+        cmd: RegisterWebhookCommand,
+        actor_id: UserId,
+    ) -> Result<WebhookResponse, NotificationsError> {
+        let webhook = Webhook::register(
+            StoreId::from_uuid(cmd.store_id),
+            cmd.url,
+            cmd.secret,
+            cmd.event_types,
+            actor_id,
+        )?;
+        self.repo.save(&webhook).await?;
+
+        Ok(WebhookResponse::from(&webhook))
+    }
+}
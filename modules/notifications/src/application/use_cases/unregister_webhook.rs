@@ -0,0 +1,37 @@
+//! UnregisterWebhookUseCase — stops delivering events to a webhook.
+//!
+//! Pending deliveries are dead-lettered by the dispatcher on their next
+//! attempt; past deliveries stay queryable.
+
+use std::sync::Arc;
+
+use uuid::Uuid;
+
+use crate::NotificationsError;
+use crate::application::dtos::WebhookResponse;
+use crate::domain::repositories::WebhookRepository;
+use crate::domain::value_objects::WebhookId;
+
+pub struct UnregisterWebhookUseCase {
+    repo: Arc<dyn WebhookRepository>,
+}
+
+impl UnregisterWebhookUseCase {
+    pub fn new(repo: Arc<dyn WebhookRepository>) -> Self {
+        Self { repo }
+    }
+
+    pub async fn execute(&self, webhook_id: Uuid) -> Result<WebhookResponse, NotificationsError> {
+        let mut webhook = self
+            .repo
+            .find_by_id(WebhookId::from_uuid(webhook_id))
+            .await?
+            .filter(|w| w.is_active())
+            .ok_or(NotificationsError::WebhookNotFound(webhook_id))?;
+
+        webhook.unregister();
+        self.repo.update(&webhook).await?;
+
+        Ok(WebhookResponse::from(&webhook))
+    }
+}
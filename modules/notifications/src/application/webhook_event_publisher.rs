@@ -0,0 +1,76 @@
+//! WebhookEventPublisher — turns outbox events into webhook deliveries.
+//!
+//! Plugged into the outbox drain (`events::DrainOutboxUseCase`) as its
+//! [`EventPublisher`]. An event is routed to the active webhooks of the store
+//! named by its payload's `store_id` that subscribed to its type; events
+//! without a store are not delivered. Enqueueing is idempotent per webhook
+//! and event, so the drain's at-least-once redelivery never duplicates a
+//! webhook delivery. `DispatchWebhooksUseCase` performs the HTTP calls.
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use events::{EventPublisher, EventsError, OutboxEvent};
+use identity::StoreId;
+use uuid::Uuid;
+
+use crate::domain::entities::WebhookDelivery;
+use crate::domain::repositories::{WebhookDeliveryRepository, WebhookRepository};
+
+pub struct WebhookEventPublisher {
+    webhook_repo: Arc<dyn WebhookRepository>,
+    delivery_repo: Arc<dyn WebhookDeliveryRepository>,
+}
+
+impl WebhookEventPublisher {
+    pub fn new(
+        webhook_repo: Arc<dyn WebhookRepository>,
+        delivery_repo: Arc<dyn WebhookDeliveryRepository>,
+    ) -> Self {
+        Self {
+            webhook_repo,
+            delivery_repo,
+        }
+    }
+}
+
+#[async_trait]
+impl EventPublisher for WebhookEventPublisher {
+    fn name(&self) -> &'static str {
+        "webhooks"
+    }
+
+    async fn publish(&self, event: &OutboxEvent) -> Result<(), EventsError> {
+        let Some(store_id) = event
+            .payload()
+            .get("store_id")
+            .and_then(|v| v.as_str())
+            .and_then(|s| Uuid::parse_str(s).ok())
+        else {
+            return Ok(());
+        };
+
+        let webhooks = self
+            .webhook_repo
+            .find_active_for_event(StoreId::from_uuid(store_id), event.event_type())
+            .await
+            .map_err(|e| EventsError::SubscriberFailed(e.to_string()))?;
+
+        let deliveries: Vec<WebhookDelivery> = webhooks
+            .iter()
+            .map(|w| {
+                WebhookDelivery::create(
+                    w.id(),
+                    event.id().into_uuid(),
+                    event.event_type(),
+                    event.payload().clone(),
+                )
+            })
+            .collect();
+
+        self.delivery_repo
+            .enqueue(&deliveries)
+            .await
+            .map_err(|e| EventsError::SubscriberFailed(e.to_string()))
+    }
+}
@@ -1,5 +1,11 @@
 //! Domain entities for the notifications module.
 
 mod notification;
+mod webhook;
+mod webhook_delivery;
+mod webhook_delivery_attempt;
 
 pub use notification::Notification;
+pub use webhook::{MIN_WEBHOOK_SECRET_LEN, Webhook};
+pub use webhook_delivery::WebhookDelivery;
+pub use webhook_delivery_attempt::WebhookDeliveryAttempt;
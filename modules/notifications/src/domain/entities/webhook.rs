@@ -0,0 +1,198 @@
+//! Webhook — a store's registration to receive outbox events over HTTP.
+//!
+//! Every delivery is signed with the webhook's own secret so receivers can
+//! tell it came from us. Unregistering deactivates the webhook; its
+//! deliveries, including dead letters, stay queryable.
+
+use chrono::{DateTime, Utc};
+use identity::{StoreId, UserId};
+use serde::{Deserialize, Serialize};
+
+use crate::NotificationsError;
+use crate::domain::value_objects::WebhookId;
+
+/// Shortest accepted signing secret.
+pub const MIN_WEBHOOK_SECRET_LEN: usize = 16;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Webhook {
+    id: WebhookId,
+    store_id: StoreId,
+    url: String,
+    secret: String,
+    event_types: Vec<String>,
+    is_active: bool,
+    created_by_id: UserId,
+    created_at: DateTime<Utc>,
+    updated_at: DateTime<Utc>,
+}
+
+impl Webhook {
+    /// Registers a webhook. Event types are trimmed and deduplicated.
+    pub fn register(
+        store_id: StoreId,
+        url: impl Into<String>,
+        secret: impl Into<String>,
+        event_types: Vec<String>,
+        created_by_id: UserId,
+    ) -> Result<Self, NotificationsError> {
+        let url = url.into().trim().to_string();
+        if !(url.starts_with("https://") || url.starts_with("http://")) {
+            return Err(NotificationsError::InvalidWebhookUrl(url));
+        }
+
+        let secret = secret.into();
+        if secret.chars().count() < MIN_WEBHOOK_SECRET_LEN {
+            return Err(NotificationsError::WebhookSecretTooShort(
+                MIN_WEBHOOK_SECRET_LEN,
+            ));
+        }
+
+        let mut types: Vec<String> = Vec::with_capacity(event_types.len());
+        for event_type in event_types {
+            let event_type = event_type.trim();
+            if !event_type.is_empty() && !types.iter().any(|t| t == event_type) {
+                types.push(event_type.to_string());
+            }
+        }
+        if types.is_empty() {
+            return Err(NotificationsError::NoWebhookEventTypes);
+        }
+
+        let now = Utc::now();
+        Ok(Self {
+            id: WebhookId::new(),
+            store_id,
+            url,
+            secret,
+            event_types: types,
+            is_active: true,
+            created_by_id,
+            created_at: now,
+            updated_at: now,
+        })
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn reconstitute(
+        id: WebhookId,
+        store_id: StoreId,
+        url: String,
+        secret: String,
+        event_types: Vec<String>,
+        is_active: bool,
+        created_by_id: UserId,
+        created_at: DateTime<Utc>,
+        updated_at: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            id,
+            store_id,
+            url,
+            secret,
+            event_types,
+            is_active,
+            created_by_id,
+            created_at,
+            updated_at,
+        }
+    }
+
+    pub fn unregister(&mut self) {
+        self.is_active = false;
+        self.updated_at = Utc::now();
+    }
+
+    /// True if the webhook is active and listens to `event_type`.
+    pub fn subscribes_to(&self, event_type: &str) -> bool {
+        self.is_active && self.event_types.iter().any(|t| t == event_type)
+    }
+
+    // Getters
+    pub fn id(&self) -> WebhookId {
+        self.id
+    }
+    pub fn store_id(&self) -> StoreId {
+        self.store_id
+    }
+    pub fn url(&self) -> &str {
+        &self.url
+    }
+    pub fn secret(&self) -> &str {
+        &self.secret
+    }
+    pub fn event_types(&self) -> &[String] {
+        &self.event_types
+    }
+    pub fn is_active(&self) -> bool {
+        self.is_active
+    }
+    pub fn created_by_id(&self) -> UserId {
+        self.created_by_id
+    }
+    pub fn created_at(&self) -> DateTime<Utc> {
+        self.created_at
+    }
+    pub fn updated_at(&self) -> DateTime<Utc> {
+        self.updated_at
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SECRET: &str = "0123456789abcdef";
+
+    fn register(
+        url: &str,
+        secret: &str,
+        event_types: &[&str],
+    ) -> Result<Webhook, NotificationsError> {
+        Webhook::register(
+            StoreId::new(),
+            url,
+            secret,
+            event_types.iter().map(|t| t.to_string()).collect(),
+            UserId::new(),
+        )
+    }
+
+    #[test]
+    fn register_normalizes_event_types() {
+        let webhook = register(
+            "https://example.test/hooks",
+            SECRET,
+            &[" stock.updated ", "sale.completed", "stock.updated", ""],
+        )
+        .unwrap();
+
+        assert_eq!(webhook.event_types(), ["stock.updated", "sale.completed"]);
+        assert!(webhook.subscribes_to("sale.completed"));
+        assert!(!webhook.subscribes_to("purchase_order.status_changed"));
+    }
+
+    #[test]
+    fn register_validates_url_secret_and_event_types() {
+        assert!(matches!(
+            register("ftp://example.test", SECRET, &["stock.updated"]),
+            Err(NotificationsError::InvalidWebhookUrl(_))
+        ));
+        assert!(matches!(
+            register("https://example.test", "short", &["stock.updated"]),
+            Err(NotificationsError::WebhookSecretTooShort(_))
+        ));
+        assert!(matches!(
+            register("https://example.test", SECRET, &[" "]),
+            Err(NotificationsError::NoWebhookEventTypes)
+        ));
+    }
+
+    #[test]
+    fn unregistered_webhook_receives_nothing() {
+        let mut webhook = register("https://example.test", SECRET, &["stock.updated"]).unwrap();
+        webhook.unregister();
+        assert!(!webhook.is_active());
+        assert!(!webhook.subscribes_to("stock.updated"));
+    }
+}
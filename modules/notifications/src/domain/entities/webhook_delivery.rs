@@ -0,0 +1,211 @@
+//! WebhookDelivery — one outbox event to be delivered to one webhook.
+//!
+//! The delivery id stays the same across retries, so receivers can dedupe;
+//! each attempt is signed with a fresh timestamp. Failed attempts are retried
+//! with exponential backoff per [`WebhookRetryPolicy`] until the delivery is
+//! moved to the dead-letter state.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::Value as JsonValue;
+use uuid::Uuid;
+
+use crate::domain::value_objects::{
+    WebhookDeliveryId, WebhookDeliveryStatus, WebhookId, WebhookRetryPolicy,
+};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WebhookDelivery {
+    id: WebhookDeliveryId,
+    webhook_id: WebhookId,
+    outbox_event_id: Uuid,
+    event_type: String,
+    payload: JsonValue,
+    status: WebhookDeliveryStatus,
+    attempts: i32,
+    next_attempt_at: DateTime<Utc>,
+    last_response_status: Option<i32>,
+    last_error: Option<String>,
+    delivered_at: Option<DateTime<Utc>>,
+    created_at: DateTime<Utc>,
+    updated_at: DateTime<Utc>,
+}
+
+impl WebhookDelivery {
+    /// A delivery due immediately.
+    pub fn create(
+        webhook_id: WebhookId,
+        outbox_event_id: Uuid,
+        event_type: impl Into<String>,
+        payload: JsonValue,
+    ) -> Self {
+        let now = Utc::now();
+        Self {
+            id: WebhookDeliveryId::new(),
+            webhook_id,
+            outbox_event_id,
+            event_type: event_type.into(),
+            payload,
+            status: WebhookDeliveryStatus::Pending,
+            attempts: 0,
+            next_attempt_at: now,
+            last_response_status: None,
+            last_error: None,
+            delivered_at: None,
+            created_at: now,
+            updated_at: now,
+        }
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn reconstitute(
+        id: WebhookDeliveryId,
+        webhook_id: WebhookId,
+        outbox_event_id: Uuid,
+        event_type: String,
+        payload: JsonValue,
+        status: WebhookDeliveryStatus,
+        attempts: i32,
+        next_attempt_at: DateTime<Utc>,
+        last_response_status: Option<i32>,
+        last_error: Option<String>,
+        delivered_at: Option<DateTime<Utc>>,
+        created_at: DateTime<Utc>,
+        updated_at: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            id,
+            webhook_id,
+            outbox_event_id,
+            event_type,
+            payload,
+            status,
+            attempts,
+            next_attempt_at,
+            last_response_status,
+            last_error,
+            delivered_at,
+            created_at,
+            updated_at,
+        }
+    }
+
+    /// The receiver answered with `response_status` (2xx).
+    pub fn mark_delivered(&mut self, response_status: i32) {
+        let now = Utc::now();
+        self.attempts += 1;
+        self.status = WebhookDeliveryStatus::Delivered;
+        self.last_response_status = Some(response_status);
+        self.last_error = None;
+        self.delivered_at = Some(now);
+        self.updated_at = now;
+    }
+
+    /// Records a failed attempt and schedules the next one, or dead-letters
+    /// the delivery once `policy.max_attempts` is reached.
+    pub fn mark_attempt_failed(
+        &mut self,
+        response_status: Option<i32>,
+        error: impl Into<String>,
+        policy: &WebhookRetryPolicy,
+    ) {
+        let now = Utc::now();
+        self.attempts += 1;
+        self.last_response_status = response_status;
+        self.last_error = Some(error.into());
+        if self.attempts >= policy.max_attempts {
+            self.status = WebhookDeliveryStatus::DeadLetter;
+        } else {
+            self.next_attempt_at = now + policy.delay_after(self.attempts);
+        }
+        self.updated_at = now;
+    }
+
+    /// Gives up without another attempt (e.g. the webhook was unregistered).
+    pub fn dead_letter(&mut self, error: impl Into<String>) {
+        self.status = WebhookDeliveryStatus::DeadLetter;
+        self.last_error = Some(error.into());
+        self.updated_at = Utc::now();
+    }
+
+    // Getters
+    pub fn id(&self) -> WebhookDeliveryId {
+        self.id
+    }
+    pub fn webhook_id(&self) -> WebhookId {
+        self.webhook_id
+    }
+    pub fn outbox_event_id(&self) -> Uuid {
+        self.outbox_event_id
+    }
+    pub fn event_type(&self) -> &str {
+        &self.event_type
+    }
+    pub fn payload(&self) -> &JsonValue {
+        &self.payload
+    }
+    pub fn status(&self) -> WebhookDeliveryStatus {
+        self.status
+    }
+    pub fn attempts(&self) -> i32 {
+        self.attempts
+    }
+    pub fn next_attempt_at(&self) -> DateTime<Utc> {
+        self.next_attempt_at
+    }
+    pub fn last_response_status(&self) -> Option<i32> {
+        self.last_response_status
+    }
+    pub fn last_error(&self) -> Option<&str> {
+        self.last_error.as_deref()
+    }
+    pub fn delivered_at(&self) -> Option<DateTime<Utc>> {
+        self.delivered_at
+    }
+    pub fn created_at(&self) -> DateTime<Utc> {
+        self.created_at
+    }
+    pub fn updated_at(&self) -> DateTime<Utc> {
+        self.updated_at
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Duration;
+    use serde_json::json;
+
+    fn delivery() -> WebhookDelivery {
+        WebhookDelivery::create(WebhookId::new(), Uuid::nil(), "stock.updated", json!({}))
+    }
+
+    #[test]
+    fn failed_attempts_back_off_then_dead_letter() {
+        let policy = WebhookRetryPolicy::new(3, 10, 3600);
+        let mut d = delivery();
+
+        d.mark_attempt_failed(Some(500), "receiver responded 500", &policy);
+        assert_eq!(d.status(), WebhookDeliveryStatus::Pending);
+        assert!(d.next_attempt_at() >= d.updated_at() + Duration::seconds(10));
+
+        d.mark_attempt_failed(None, "timeout", &policy);
+        assert!(d.next_attempt_at() >= d.updated_at() + Duration::seconds(20));
+
+        d.mark_attempt_failed(Some(502), "receiver responded 502", &policy);
+        assert_eq!(d.status(), WebhookDeliveryStatus::DeadLetter);
+        assert_eq!(d.attempts(), 3);
+        assert_eq!(d.last_response_status(), Some(502));
+    }
+
+    #[test]
+    fn delivered_clears_error() {
+        let mut d = delivery();
+        d.mark_attempt_failed(None, "timeout", &WebhookRetryPolicy::default());
+        d.mark_delivered(204);
+        assert_eq!(d.status(), WebhookDeliveryStatus::Delivered);
+        assert_eq!(d.attempts(), 2);
+        assert!(d.last_error().is_none());
+        assert!(d.delivered_at().is_some());
+    }
+}
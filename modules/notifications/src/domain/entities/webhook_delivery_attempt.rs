@@ -0,0 +1,116 @@
+//! WebhookDeliveryAttempt — one HTTP POST of a webhook notification.
+//!
+//! The delivery id is the notification id and stays the same across retries,
+//! so receivers can dedupe; each attempt gets a fresh signed timestamp.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::{NoContext, Timestamp, Uuid};
+
+use crate::domain::value_objects::{NotificationId, SignatureAlgorithm};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WebhookDeliveryAttempt {
+    id: Uuid,
+    notification_id: NotificationId,
+    attempt_number: i32,
+    url: String,
+    signature_algorithm: SignatureAlgorithm,
+    signed_timestamp: i64,
+    response_status: Option<i32>,
+    error: Option<String>,
+    duration_ms: i64,
+    attempted_at: DateTime<Utc>,
+}
+
+impl WebhookDeliveryAttempt {
+    #[allow(clippy::too_many_arguments)]
+    pub fn record(
+        notification_id: NotificationId,
+        attempt_number: i32,
+        url: impl Into<String>,
+        signature_algorithm: SignatureAlgorithm,
+        signed_timestamp: i64,
+        response_status: Option<i32>,
+        error: Option<String>,
+        duration_ms: i64,
+    ) -> Self {
+        Self {
+            id: Uuid::new_v7(Timestamp::now(NoContext)),
+            notification_id,
+            attempt_number,
+            url: url.into(),
+            signature_algorithm,
+            signed_timestamp,
+            response_status,
+            error,
+            duration_ms,
+            attempted_at: Utc::now(),
+        }
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn reconstitute(
+        id: Uuid,
+        notification_id: NotificationId,
+        attempt_number: i32,
+        url: String,
+        signature_algorithm: SignatureAlgorithm,
+        signed_timestamp: i64,
+        response_status: Option<i32>,
+        error: Option<String>,
+        duration_ms: i64,
+        attempted_at: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            id,
+            notification_id,
+            attempt_number,
+            url,
+            signature_algorithm,
+            signed_timestamp,
+            response_status,
+            error,
+            duration_ms,
+            attempted_at,
+        }
+    }
+
+    /// True when the receiver answered with a 2xx status.
+    pub fn is_success(&self) -> bool {
+        self.response_status
+            .is_some_and(|status| (200..300).contains(&status))
+    }
+
+    // Getters
+    pub fn id(&self) -> Uuid {
+        self.id
+    }
+    pub fn notification_id(&self) -> NotificationId {
+        self.notification_id
+    }
+    pub fn attempt_number(&self) -> i32 {
+        self.attempt_number
+    }
+    pub fn url(&self) -> &str {
+        &self.url
+    }
+    pub fn signature_algorithm(&self) -> SignatureAlgorithm {
+        self.signature_algorithm
+    }
+    pub fn signed_timestamp(&self) -> i64 {
+        self.signed_timestamp
+    }
+    pub fn response_status(&self) -> Option<i32> {
+        self.response_status
+    }
+    pub fn error(&self) -> Option<&str> {
+        self.error.as_deref()
+    }
+    pub fn duration_ms(&self) -> i64 {
+        self.duration_ms
+    }
+    pub fn attempted_at(&self) -> DateTime<Utc> {
+        self.attempted_at
+    }
+}
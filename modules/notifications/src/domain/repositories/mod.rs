@@ -1,5 +1,11 @@
 //! Repository traits for the notifications domain.
 
 mod notification_repository;
+mod webhook_delivery_attempt_repository;
+mod webhook_delivery_repository;
+mod webhook_repository;
 
 pub use notification_repository::NotificationRepository;
+pub use webhook_delivery_attempt_repository::WebhookDeliveryAttemptRepository;
+pub use webhook_delivery_repository::WebhookDeliveryRepository;
+pub use webhook_repository::WebhookRepository;
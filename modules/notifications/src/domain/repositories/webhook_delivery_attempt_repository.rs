@@ -0,0 +1,18 @@
+//! Webhook delivery attempt repository trait.
+
+use async_trait::async_trait;
+
+use crate::NotificationsError;
+use crate::domain::entities::WebhookDeliveryAttempt;
+use crate::domain::value_objects::NotificationId;
+
+#[async_trait]
+pub trait WebhookDeliveryAttemptRepository: Send + Sync {
+    async fn save(&self, attempt: &WebhookDeliveryAttempt) -> Result<(), NotificationsError>;
+
+    /// Attempts for one delivery, oldest first.
+    async fn list_for_notification(
+        &self,
+        notification_id: NotificationId,
+    ) -> Result<Vec<WebhookDeliveryAttempt>, NotificationsError>;
+}
@@ -0,0 +1,36 @@
+//! Webhook delivery repository trait.
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use identity::StoreId;
+
+use crate::NotificationsError;
+use crate::domain::entities::WebhookDelivery;
+use crate::domain::value_objects::WebhookId;
+
+#[async_trait]
+pub trait WebhookDeliveryRepository: Send + Sync {
+    /// Persists new deliveries. A delivery of an outbox event that was
+    /// already enqueued for the same webhook is skipped, so re-publishing an
+    /// event never delivers it twice.
+    async fn enqueue(&self, deliveries: &[WebhookDelivery]) -> Result<(), NotificationsError>;
+
+    /// Persist an updated delivery (status / attempts / backoff / last error).
+    async fn update(&self, delivery: &WebhookDelivery) -> Result<(), NotificationsError>;
+
+    /// Returns at most `limit` pending deliveries due at `now`, oldest first.
+    async fn find_due(
+        &self,
+        now: DateTime<Utc>,
+        limit: i64,
+    ) -> Result<Vec<WebhookDelivery>, NotificationsError>;
+
+    /// Dead-lettered deliveries of a store's webhooks (optionally one
+    /// webhook), most recently failed first.
+    async fn find_dead_letters(
+        &self,
+        store_id: StoreId,
+        webhook_id: Option<WebhookId>,
+        limit: i64,
+    ) -> Result<Vec<WebhookDelivery>, NotificationsError>;
+}
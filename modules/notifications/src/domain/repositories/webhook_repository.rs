@@ -0,0 +1,30 @@
+//! Webhook registration repository trait.
+
+use async_trait::async_trait;
+use identity::StoreId;
+
+use crate::NotificationsError;
+use crate::domain::entities::Webhook;
+use crate::domain::value_objects::WebhookId;
+
+#[async_trait]
+pub trait WebhookRepository: Send + Sync {
+    async fn save(&self, webhook: &Webhook) -> Result<(), NotificationsError>;
+
+    async fn update(&self, webhook: &Webhook) -> Result<(), NotificationsError>;
+
+    async fn find_by_id(&self, id: WebhookId) -> Result<Option<Webhook>, NotificationsError>;
+
+    /// Active webhooks of a store, oldest first.
+    async fn find_active_by_store(
+        &self,
+        store_id: StoreId,
+    ) -> Result<Vec<Webhook>, NotificationsError>;
+
+    /// Active webhooks of a store subscribed to `event_type`.
+    async fn find_active_for_event(
+        &self,
+        store_id: StoreId,
+        event_type: &str,
+    ) -> Result<Vec<Webhook>, NotificationsError>;
+}
@@ -3,7 +3,19 @@
 mod notification_channel;
 mod notification_id;
 mod notification_status;
+mod signature_algorithm;
+mod webhook_delivery_id;
+mod webhook_delivery_status;
+mod webhook_id;
+mod webhook_retry_policy;
+mod webhook_signature;
 
 pub use notification_channel::NotificationChannel;
 pub use notification_id::NotificationId;
 pub use notification_status::NotificationStatus;
+pub use signature_algorithm::SignatureAlgorithm;
+pub use webhook_delivery_id::WebhookDeliveryId;
+pub use webhook_delivery_status::WebhookDeliveryStatus;
+pub use webhook_id::WebhookId;
+pub use webhook_retry_policy::WebhookRetryPolicy;
+pub use webhook_signature::{DEFAULT_SIGNATURE_TOLERANCE_SECS, WebhookSignature};
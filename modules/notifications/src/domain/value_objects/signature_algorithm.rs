@@ -0,0 +1,37 @@
+//! SignatureAlgorithm — how outbound webhook payloads are signed.
+
+use std::fmt;
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+
+use crate::NotificationsError;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum SignatureAlgorithm {
+    #[default]
+    HmacSha256,
+    HmacSha512,
+}
+
+impl fmt::Display for SignatureAlgorithm {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SignatureAlgorithm::HmacSha256 => write!(f, "hmac-sha256"),
+            SignatureAlgorithm::HmacSha512 => write!(f, "hmac-sha512"),
+        }
+    }
+}
+
+impl FromStr for SignatureAlgorithm {
+    type Err = NotificationsError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "hmac-sha256" => Ok(Self::HmacSha256),
+            "hmac-sha512" => Ok(Self::HmacSha512),
+            other => Err(NotificationsError::InvalidSignatureAlgorithm(other.into())),
+        }
+    }
+}
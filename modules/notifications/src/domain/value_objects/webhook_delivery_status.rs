@@ -0,0 +1,43 @@
+//! WebhookDeliveryStatus — lifecycle states for one event delivery to one
+//! webhook.
+
+use std::fmt;
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+
+use crate::NotificationsError;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum WebhookDeliveryStatus {
+    /// Waiting for its first attempt or for the next retry
+    Pending,
+    /// The receiver answered with a 2xx status
+    Delivered,
+    /// Every attempt failed; left for manual review
+    DeadLetter,
+}
+
+impl fmt::Display for WebhookDeliveryStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            WebhookDeliveryStatus::Pending => write!(f, "pending"),
+            WebhookDeliveryStatus::Delivered => write!(f, "delivered"),
+            WebhookDeliveryStatus::DeadLetter => write!(f, "dead_letter"),
+        }
+    }
+}
+
+impl FromStr for WebhookDeliveryStatus {
+    type Err = NotificationsError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "pending" => Ok(Self::Pending),
+            "delivered" => Ok(Self::Delivered),
+            "dead_letter" => Ok(Self::DeadLetter),
+            other => Err(NotificationsError::InvalidStatus(other.into())),
+        }
+    }
+}
@@ -0,0 +1,62 @@
+//! WebhookRetryPolicy — how often a failed webhook delivery is retried.
+//!
+//! The wait before attempt `n + 1` is `base_delay_secs * 2^(n - 1)`, capped
+//! at `max_delay_secs`. After `max_attempts` failures the delivery is moved
+//! to the dead-letter state.
+
+use chrono::Duration;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WebhookRetryPolicy {
+    pub max_attempts: i32,
+    pub base_delay_secs: i64,
+    pub max_delay_secs: i64,
+}
+
+impl WebhookRetryPolicy {
+    pub const DEFAULT_MAX_ATTEMPTS: i32 = 8;
+    pub const DEFAULT_BASE_DELAY_SECS: i64 = 30;
+    pub const DEFAULT_MAX_DELAY_SECS: i64 = 6 * 3600;
+
+    pub fn new(max_attempts: i32, base_delay_secs: i64, max_delay_secs: i64) -> Self {
+        Self {
+            max_attempts: max_attempts.max(1),
+            base_delay_secs: base_delay_secs.max(1),
+            max_delay_secs: max_delay_secs.max(base_delay_secs.max(1)),
+        }
+    }
+
+    /// Wait before the next attempt, after `attempts` failed ones.
+    pub fn delay_after(&self, attempts: i32) -> Duration {
+        let exponent = (attempts - 1).clamp(0, 30) as u32;
+        let secs = self
+            .base_delay_secs
+            .saturating_mul(1_i64 << exponent)
+            .min(self.max_delay_secs);
+        Duration::seconds(secs)
+    }
+}
+
+impl Default for WebhookRetryPolicy {
+    fn default() -> Self {
+        Self::new(
+            Self::DEFAULT_MAX_ATTEMPTS,
+            Self::DEFAULT_BASE_DELAY_SECS,
+            Self::DEFAULT_MAX_DELAY_SECS,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn delay_doubles_and_is_capped() {
+        let policy = WebhookRetryPolicy::new(10, 30, 100);
+        assert_eq!(policy.delay_after(1), Duration::seconds(30));
+        assert_eq!(policy.delay_after(2), Duration::seconds(60));
+        assert_eq!(policy.delay_after(3), Duration::seconds(100));
+        assert_eq!(policy.delay_after(40), Duration::seconds(100));
+    }
+}
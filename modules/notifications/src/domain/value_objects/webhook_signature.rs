@@ -0,0 +1,221 @@
+//! WebhookSignature — signature carried by every outbound webhook delivery.
+//!
+//! The signed content is `"{delivery_id}.{timestamp}.{body}"`, so the delivery
+//! id and send time cannot be altered without breaking the signature. The
+//! header form is `t=<unix seconds>,<algorithm>=<hex digest>`, e.g.
+//! `t=1760659200,hmac-sha256=5f0c...`.
+//!
+//! Receivers verify with [`WebhookSignature::verify`]: recompute the digest
+//! with the shared secret, compare in constant time, and reject timestamps
+//! outside the clock-skew tolerance (replays of old deliveries). Deduplicating
+//! on the delivery id handles retries of the same delivery.
+
+use hmac::{Hmac, Mac};
+use sha2::{Sha256, Sha512};
+use uuid::Uuid;
+
+use crate::NotificationsError;
+use crate::domain::value_objects::SignatureAlgorithm;
+
+/// Default clock-skew tolerance advertised to receivers (5 minutes).
+pub const DEFAULT_SIGNATURE_TOLERANCE_SECS: i64 = 300;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WebhookSignature {
+    timestamp: i64,
+    algorithm: SignatureAlgorithm,
+    digest: Vec<u8>,
+}
+
+impl WebhookSignature {
+    /// Signs a delivery.
+    pub fn compute(
+        algorithm: SignatureAlgorithm,
+        secret: &[u8],
+        delivery_id: Uuid,
+        timestamp: i64,
+        body: &[u8],
+    ) -> Self {
+        let digest = digest(algorithm, secret, delivery_id, timestamp, body);
+        Self {
+            timestamp,
+            algorithm,
+            digest,
+        }
+    }
+
+    /// Parses a `t=...,<algorithm>=...` header value.
+    pub fn parse(header: &str) -> Result<Self, NotificationsError> {
+        let mut timestamp = None;
+        let mut signed = None;
+
+        for part in header.split(',').map(str::trim) {
+            let (key, value) = part.split_once('=').ok_or_else(|| {
+                NotificationsError::InvalidWebhookSignature(format!("malformed part '{part}'"))
+            })?;
+            if key == "t" {
+                timestamp = Some(value.parse::<i64>().map_err(|_| {
+                    NotificationsError::InvalidWebhookSignature("invalid timestamp".into())
+                })?);
+            } else {
+                let algorithm: SignatureAlgorithm = key.parse()?;
+                let digest = hex::decode(value).map_err(|_| {
+                    NotificationsError::InvalidWebhookSignature("digest is not hex".into())
+                })?;
+                signed = Some((algorithm, digest));
+            }
+        }
+
+        match (timestamp, signed) {
+            (Some(timestamp), Some((algorithm, digest))) => Ok(Self {
+                timestamp,
+                algorithm,
+                digest,
+            }),
+            _ => Err(NotificationsError::InvalidWebhookSignature(
+                "missing timestamp or digest".into(),
+            )),
+        }
+    }
+
+    /// Header value sent with the delivery.
+    pub fn to_header(&self) -> String {
+        format!(
+            "t={},{}={}",
+            self.timestamp,
+            self.algorithm,
+            hex::encode(&self.digest)
+        )
+    }
+
+    /// Checks the digest for `delivery_id` + `body` and that the timestamp is
+    /// within `tolerance_secs` of `now` (unix seconds).
+    pub fn verify(
+        &self,
+        secret: &[u8],
+        delivery_id: Uuid,
+        body: &[u8],
+        now: i64,
+        tolerance_secs: i64,
+    ) -> Result<(), NotificationsError> {
+        if (now - self.timestamp).abs() > tolerance_secs {
+            return Err(NotificationsError::InvalidWebhookSignature(format!(
+                "timestamp {} outside tolerance of {}s",
+                self.timestamp, tolerance_secs
+            )));
+        }
+
+        let ok = match self.algorithm {
+            SignatureAlgorithm::HmacSha256 => {
+                let mut mac = Hmac::<Sha256>::new_from_slice(secret).expect("any key length");
+                feed(&mut mac, delivery_id, self.timestamp, body);
+                mac.verify_slice(&self.digest).is_ok()
+            }
+            SignatureAlgorithm::HmacSha512 => {
+                let mut mac = Hmac::<Sha512>::new_from_slice(secret).expect("any key length");
+                feed(&mut mac, delivery_id, self.timestamp, body);
+                mac.verify_slice(&self.digest).is_ok()
+            }
+        };
+
+        if ok {
+            Ok(())
+        } else {
+            Err(NotificationsError::InvalidWebhookSignature(
+                "digest mismatch".into(),
+            ))
+        }
+    }
+
+    pub fn timestamp(&self) -> i64 {
+        self.timestamp
+    }
+
+    pub fn algorithm(&self) -> SignatureAlgorithm {
+        self.algorithm
+    }
+}
+
+fn feed<M: Mac>(mac: &mut M, delivery_id: Uuid, timestamp: i64, body: &[u8]) {
+    mac.update(delivery_id.to_string().as_bytes());
+    mac.update(b".");
+    mac.update(timestamp.to_string().as_bytes());
+    mac.update(b".");
+    mac.update(body);
+}
+
+fn digest(
+    algorithm: SignatureAlgorithm,
+    secret: &[u8],
+    delivery_id: Uuid,
+    timestamp: i64,
+    body: &[u8],
+) -> Vec<u8> {
+    match algorithm {
+        SignatureAlgorithm::HmacSha256 => {
+            let mut mac = Hmac::<Sha256>::new_from_slice(secret).expect("any key length");
+            feed(&mut mac, delivery_id, timestamp, body);
+            mac.finalize().into_bytes().to_vec()
+        }
+        SignatureAlgorithm::HmacSha512 => {
+            let mut mac = Hmac::<Sha512>::new_from_slice(secret).expect("any key length");
+            feed(&mut mac, delivery_id, timestamp, body);
+            mac.finalize().into_bytes().to_vec()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SECRET: &[u8] = b"whsec_test";
+    const NOW: i64 = 1_760_659_200;
+
+    #[test]
+    fn header_round_trip_verifies() {
+        let id = Uuid::now_v7();
+        let body = br#"{"event":"order.paid"}"#;
+        let sig = WebhookSignature::compute(SignatureAlgorithm::HmacSha256, SECRET, id, NOW, body);
+
+        let header = sig.to_header();
+        assert!(header.starts_with(&format!("t={NOW},hmac-sha256=")));
+
+        let parsed = WebhookSignature::parse(&header).unwrap();
+        assert_eq!(parsed, sig);
+        assert!(parsed.verify(SECRET, id, body, NOW + 10, 300).is_ok());
+    }
+
+    #[test]
+    fn sha512_is_supported() {
+        let id = Uuid::now_v7();
+        let sig = WebhookSignature::compute(SignatureAlgorithm::HmacSha512, SECRET, id, NOW, b"x");
+        let parsed = WebhookSignature::parse(&sig.to_header()).unwrap();
+        assert_eq!(parsed.algorithm(), SignatureAlgorithm::HmacSha512);
+        assert!(parsed.verify(SECRET, id, b"x", NOW, 300).is_ok());
+    }
+
+    #[test]
+    fn rejects_tampered_body_or_delivery_id() {
+        let id = Uuid::now_v7();
+        let sig = WebhookSignature::compute(SignatureAlgorithm::HmacSha256, SECRET, id, NOW, b"a");
+        assert!(sig.verify(SECRET, id, b"b", NOW, 300).is_err());
+        assert!(sig.verify(SECRET, Uuid::now_v7(), b"a", NOW, 300).is_err());
+        assert!(sig.verify(b"other", id, b"a", NOW, 300).is_err());
+    }
+
+    #[test]
+    fn rejects_stale_timestamp() {
+        let id = Uuid::now_v7();
+        let sig = WebhookSignature::compute(SignatureAlgorithm::HmacSha256, SECRET, id, NOW, b"a");
+        assert!(sig.verify(SECRET, id, b"a", NOW + 301, 300).is_err());
+        assert!(sig.verify(SECRET, id, b"a", NOW - 301, 300).is_err());
+    }
+
+    #[test]
+    fn parse_rejects_malformed_headers() {
+        assert!(WebhookSignature::parse("hmac-sha256=abcd").is_err());
+        assert!(WebhookSignature::parse("t=1,md5=abcd").is_err());
+        assert!(WebhookSignature::parse("t=1,hmac-sha256=zz").is_err());
+    }
+}
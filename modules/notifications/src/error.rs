@@ -20,6 +20,24 @@ pub enum NotificationsError {
     #[error("Adapter not configured for channel: {0}")]
     AdapterNotConfigured(String),
 
+    #[error("Invalid signature algorithm: {0}")]
+    InvalidSignatureAlgorithm(String),
+
+    #[error("Invalid webhook signature: {0}")]
+    InvalidWebhookSignature(String),
+
+    #[error("Webhook not found: {0}")]
+    WebhookNotFound(Uuid),
+
+    #[error("Invalid webhook URL: {0}")]
+    InvalidWebhookUrl(String),
+
+    #[error("Webhook secret must be at least {0} characters")]
+    WebhookSecretTooShort(usize),
+
+    #[error("A webhook must subscribe to at least one event type")]
+    NoWebhookEventTypes,
+
     #[error("Adapter error: {0}")]
     Adapter(String),
 
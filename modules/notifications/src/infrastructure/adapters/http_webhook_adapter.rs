@@ -0,0 +1,237 @@
+//! HttpWebhookAdapter — signed HTTP POST transport for the `webhook` channel.
+//!
+//! The notification recipient is the target URL. Every delivery is wrapped in
+//! an envelope carrying the delivery id (the notification id, stable across
+//! retries) and the send timestamp, and signed with [`WebhookSignature`]:
+//!
+//! ```text
+//! X-Webhook-Id:        <delivery id>
+//! X-Webhook-Timestamp: <unix seconds>
+//! X-Webhook-Signature: t=<unix seconds>,hmac-sha256=<hex>
+//! X-Webhook-Tolerance: <seconds>
+//!
+//! {"id":"<delivery id>","event":"order.paid","timestamp":1760659200,"data":{...}}
+//! ```
+//!
+//! Receivers should verify the signature, reject timestamps outside the
+//! advertised tolerance, and ignore delivery ids they already processed.
+//! Each attempt (status code, error, latency) is persisted.
+
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use async_trait::async_trait;
+use chrono::Utc;
+use serde_json::{Value as JsonValue, json};
+
+use crate::NotificationsError;
+use crate::domain::entities::{Notification, WebhookDeliveryAttempt};
+use crate::domain::repositories::WebhookDeliveryAttemptRepository;
+use crate::domain::value_objects::{
+    DEFAULT_SIGNATURE_TOLERANCE_SECS, NotificationChannel, SignatureAlgorithm, WebhookSignature,
+};
+
+use super::notification_adapter::{DeliveryResult, NotificationAdapter};
+
+pub const WEBHOOK_ID_HEADER: &str = "X-Webhook-Id";
+pub const WEBHOOK_TIMESTAMP_HEADER: &str = "X-Webhook-Timestamp";
+pub const WEBHOOK_SIGNATURE_HEADER: &str = "X-Webhook-Signature";
+pub const WEBHOOK_TOLERANCE_HEADER: &str = "X-Webhook-Tolerance";
+
+/// Signing and transport settings for outbound webhooks.
+#[derive(Debug, Clone)]
+pub struct WebhookSigningConfig {
+    pub secret: String,
+    pub algorithm: SignatureAlgorithm,
+    /// Clock-skew tolerance advertised to receivers, in seconds.
+    pub tolerance_secs: i64,
+    pub timeout_secs: u64,
+}
+
+impl WebhookSigningConfig {
+    pub fn new(secret: impl Into<String>) -> Self {
+        Self {
+            secret: secret.into(),
+            algorithm: SignatureAlgorithm::default(),
+            tolerance_secs: DEFAULT_SIGNATURE_TOLERANCE_SECS,
+            timeout_secs: 10,
+        }
+    }
+}
+
+pub struct HttpWebhookAdapter {
+    config: WebhookSigningConfig,
+    client: reqwest::Client,
+    attempts: Arc<dyn WebhookDeliveryAttemptRepository>,
+}
+
+impl HttpWebhookAdapter {
+    pub fn new(
+        config: WebhookSigningConfig,
+        attempts: Arc<dyn WebhookDeliveryAttemptRepository>,
+    ) -> Self {
+        let client = reqwest::Client::builder()
+            .timeout(Duration::from_secs(config.timeout_secs))
+            .build()
+            .unwrap_or_default();
+        Self {
+            config,
+            client,
+            attempts,
+        }
+    }
+}
+
+/// Serialized envelope for one attempt. `data` is the notification body,
+/// embedded as JSON when it parses and as a string otherwise.
+pub fn webhook_envelope(notification: &Notification, timestamp: i64) -> Vec<u8> {
+    let event = notification
+        .metadata()
+        .get("event_type")
+        .and_then(JsonValue::as_str)
+        .or(notification.subject())
+        .unwrap_or("notification");
+    let data = serde_json::from_str::<JsonValue>(notification.body())
+        .unwrap_or_else(|_| JsonValue::String(notification.body().to_string()));
+
+    json!({
+        "id": notification.id().into_uuid(),
+        "event": event,
+        "timestamp": timestamp,
+        "data": data,
+    })
+    .to_string()
+    .into_bytes()
+}
+
+#[async_trait]
+impl NotificationAdapter for HttpWebhookAdapter {
+    fn channel(&self) -> NotificationChannel {
+        NotificationChannel::Webhook
+    }
+
+    async fn send(
+        &self,
+        notification: &Notification,
+    ) -> Result<DeliveryResult, NotificationsError> {
+        let delivery_id = notification.id().into_uuid();
+        let timestamp = Utc::now().timestamp();
+        let body = webhook_envelope(notification, timestamp);
+        let signature = WebhookSignature::compute(
+            self.config.algorithm,
+            self.config.secret.as_bytes(),
+            delivery_id,
+            timestamp,
+            &body,
+        );
+
+        let started = Instant::now();
+        let response = self
+            .client
+            .post(notification.recipient())
+            .header(reqwest::header::CONTENT_TYPE, "application/json")
+            .header(WEBHOOK_ID_HEADER, delivery_id.to_string())
+            .header(WEBHOOK_TIMESTAMP_HEADER, timestamp.to_string())
+            .header(WEBHOOK_SIGNATURE_HEADER, signature.to_header())
+            .header(
+                WEBHOOK_TOLERANCE_HEADER,
+                self.config.tolerance_secs.to_string(),
+            )
+            .body(body)
+            .send()
+            .await;
+        let duration_ms = started.elapsed().as_millis() as i64;
+
+        let (status, error) = match &response {
+            Ok(r) if r.status().is_success() => (Some(r.status().as_u16() as i32), None),
+            Ok(r) => (
+                Some(r.status().as_u16() as i32),
+                Some(format!("receiver responded {}", r.status())),
+            ),
+            Err(e) => (None, Some(e.to_string())),
+        };
+
+        let attempt = WebhookDeliveryAttempt::record(
+            notification.id(),
+            notification.attempts() + 1,
+            notification.recipient(),
+            self.config.algorithm,
+            timestamp,
+            status,
+            error.clone(),
+            duration_ms,
+        );
+        // A failed audit write must not turn a delivered webhook into a retry
+        // (the receiver would see it twice), so it is only logged.
+        if let Err(e) = self.attempts.save(&attempt).await {
+            tracing::warn!(
+                delivery_id = %delivery_id,
+                error = %e,
+                "failed to record webhook delivery attempt"
+            );
+        }
+
+        match error {
+            None => Ok(DeliveryResult {
+                provider_message_id: Some(delivery_id.to_string()),
+            }),
+            Some(e) => Err(NotificationsError::Adapter(e)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn notification(body: &str, metadata: JsonValue) -> Notification {
+        Notification::create(
+            NotificationChannel::Webhook,
+            "https://example.test/hooks",
+            Some("order.status_changed".into()),
+            body,
+            metadata,
+        )
+    }
+
+    #[test]
+    fn envelope_carries_delivery_id_and_timestamp() {
+        let n = notification(r#"{"order_id":"42"}"#, json!({}));
+        let body = webhook_envelope(&n, 1_760_659_200);
+        let value: JsonValue = serde_json::from_slice(&body).unwrap();
+
+        assert_eq!(value["id"], json!(n.id().into_uuid()));
+        assert_eq!(value["timestamp"], json!(1_760_659_200));
+        assert_eq!(value["event"], json!("order.status_changed"));
+        assert_eq!(value["data"]["order_id"], json!("42"));
+    }
+
+    #[test]
+    fn envelope_prefers_metadata_event_type_and_wraps_plain_text() {
+        let n = notification("low stock", json!({ "event_type": "inventory.low_stock" }));
+        let value: JsonValue = serde_json::from_slice(&webhook_envelope(&n, 1)).unwrap();
+
+        assert_eq!(value["event"], json!("inventory.low_stock"));
+        assert_eq!(value["data"], json!("low stock"));
+    }
+
+    #[test]
+    fn envelope_signature_verifies_for_receiver() {
+        let n = notification("{}", json!({}));
+        let body = webhook_envelope(&n, 1_000);
+        let sig = WebhookSignature::compute(
+            SignatureAlgorithm::HmacSha256,
+            b"secret",
+            n.id().into_uuid(),
+            1_000,
+            &body,
+        );
+
+        let parsed = WebhookSignature::parse(&sig.to_header()).unwrap();
+        assert!(
+            parsed
+                .verify(b"secret", n.id().into_uuid(), &body, 1_100, 300)
+                .is_ok()
+        );
+    }
+}
@@ -4,14 +4,24 @@
 //! [`NotificationAdapterRegistry`] dispatches a `NotificationChannel` to the
 //! right adapter so use cases can stay channel-agnostic.
 //!
-//! [`LogOnlyAdapter`] is the only adapter wired today — it is the dev
-//! workhorse and the default for every channel. SendGrid / SES / Twilio /
-//! WhatsApp Cloud / OneSignal go here as they are implemented.
+//! [`LogOnlyAdapter`] is the dev workhorse and the default for every channel.
+//! [`HttpWebhookAdapter`] delivers signed webhooks and replaces it for the
+//! `webhook` channel when a signing secret is configured. SendGrid / SES /
+//! Twilio / WhatsApp Cloud / OneSignal go here as they are implemented.
+//!
+//! [`HttpWebhookClient`] is the transport for per-store event webhooks.
 
+mod http_webhook_adapter;
 mod log_only_adapter;
 mod notification_adapter;
 mod registry;
+mod webhook_client;
 
+pub use http_webhook_adapter::{
+    HttpWebhookAdapter, WEBHOOK_ID_HEADER, WEBHOOK_SIGNATURE_HEADER, WEBHOOK_TIMESTAMP_HEADER,
+    WEBHOOK_TOLERANCE_HEADER, WebhookSigningConfig, webhook_envelope,
+};
 pub use log_only_adapter::LogOnlyAdapter;
 pub use notification_adapter::{DeliveryResult, NotificationAdapter};
 pub use registry::{DefaultNotificationAdapterRegistry, NotificationAdapterRegistry};
+pub use webhook_client::{HttpWebhookClient, WebhookClient, WebhookRequest};
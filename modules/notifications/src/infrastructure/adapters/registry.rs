@@ -58,6 +58,14 @@ impl DefaultNotificationAdapterRegistry {
     }
 }
 
+impl DefaultNotificationAdapterRegistry {
+    /// Replaces only the webhook adapter, keeping the rest.
+    pub fn with_webhook(mut self, webhook: Arc<dyn NotificationAdapter>) -> Self {
+        self.webhook = webhook;
+        self
+    }
+}
+
 impl Default for DefaultNotificationAdapterRegistry {
     fn default() -> Self {
         Self::new()
@@ -0,0 +1,69 @@
+//! WebhookClient — HTTP transport for event webhook deliveries.
+//!
+//! [`DispatchWebhooksUseCase`](crate::DispatchWebhooksUseCase) builds and
+//! signs the request; the client only POSTs it. Deliveries use the same
+//! headers and envelope shape as [`HttpWebhookAdapter`](super::HttpWebhookAdapter):
+//!
+//! ```text
+//! {"id":"<delivery id>","event":"stock.updated","timestamp":1760659200,"data":{...}}
+//! ```
+
+use std::time::Duration;
+
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use super::http_webhook_adapter::{
+    WEBHOOK_ID_HEADER, WEBHOOK_SIGNATURE_HEADER, WEBHOOK_TIMESTAMP_HEADER, WEBHOOK_TOLERANCE_HEADER,
+};
+
+/// A signed webhook POST.
+#[derive(Debug, Clone)]
+pub struct WebhookRequest {
+    pub url: String,
+    pub delivery_id: Uuid,
+    pub timestamp: i64,
+    /// `X-Webhook-Signature` header value.
+    pub signature: String,
+    pub tolerance_secs: i64,
+    pub body: Vec<u8>,
+}
+
+#[async_trait]
+pub trait WebhookClient: Send + Sync {
+    /// POSTs the request. `Ok` carries the response status, whatever it is;
+    /// `Err` means no response was received (timeout, DNS, TLS).
+    async fn post(&self, request: &WebhookRequest) -> Result<u16, String>;
+}
+
+pub struct HttpWebhookClient {
+    client: reqwest::Client,
+}
+
+impl HttpWebhookClient {
+    pub fn new(timeout_secs: u64) -> Self {
+        let client = reqwest::Client::builder()
+            .timeout(Duration::from_secs(timeout_secs))
+            .build()
+            .unwrap_or_default();
+        Self { client }
+    }
+}
+
+#[async_trait]
+impl WebhookClient for HttpWebhookClient {
+    async fn post(&self, request: &WebhookRequest) -> Result<u16, String> {
+        self.client
+            .post(&request.url)
+            .header(reqwest::header::CONTENT_TYPE, "application/json")
+            .header(WEBHOOK_ID_HEADER, request.delivery_id.to_string())
+            .header(WEBHOOK_TIMESTAMP_HEADER, request.timestamp.to_string())
+            .header(WEBHOOK_SIGNATURE_HEADER, &request.signature)
+            .header(WEBHOOK_TOLERANCE_HEADER, request.tolerance_secs.to_string())
+            .body(request.body.clone())
+            .send()
+            .await
+            .map(|response| response.status().as_u16())
+            .map_err(|e| e.to_string())
+    }
+}
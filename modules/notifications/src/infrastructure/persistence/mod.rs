@@ -1,5 +1,11 @@
 //! PostgreSQL repository implementations.
 
 mod pg_notification_repository;
+mod pg_webhook_delivery_attempt_repository;
+mod pg_webhook_delivery_repository;
+mod pg_webhook_repository;
 
 pub use pg_notification_repository::PgNotificationRepository;
+pub use pg_webhook_delivery_attempt_repository::PgWebhookDeliveryAttemptRepository;
+pub use pg_webhook_delivery_repository::PgWebhookDeliveryRepository;
+pub use pg_webhook_repository::PgWebhookRepository;
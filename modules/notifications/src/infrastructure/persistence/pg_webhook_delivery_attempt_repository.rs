@@ -0,0 +1,100 @@
+//! PostgreSQL implementation of WebhookDeliveryAttemptRepository.
+
+use async_trait::async_trait;
+use sqlx::PgPool;
+
+use crate::NotificationsError;
+use crate::domain::entities::WebhookDeliveryAttempt;
+use crate::domain::repositories::WebhookDeliveryAttemptRepository;
+use crate::domain::value_objects::{NotificationId, SignatureAlgorithm};
+
+pub struct PgWebhookDeliveryAttemptRepository {
+    pool: PgPool,
+}
+
+impl PgWebhookDeliveryAttemptRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl WebhookDeliveryAttemptRepository for PgWebhookDeliveryAttemptRepository {
+    async fn save(&self, a: &WebhookDeliveryAttempt) -> Result<(), NotificationsError> {
+        sqlx::query(
+            r#"
+            INSERT INTO webhook_delivery_attempts (
+                id, notification_id, attempt_number, url, signature_algorithm,
+                signed_timestamp, response_status, error, duration_ms, attempted_at
+            )
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10)
+            "#,
+        )
+        .bind(a.id())
+        .bind(a.notification_id().into_uuid())
+        .bind(a.attempt_number())
+        .bind(a.url())
+        .bind(a.signature_algorithm().to_string())
+        .bind(a.signed_timestamp())
+        .bind(a.response_status())
+        .bind(a.error())
+        .bind(a.duration_ms())
+        .bind(a.attempted_at())
+        .execute(&self.pool)
+        .await?;
+        Ok(())
+    }
+
+    async fn list_for_notification(
+        &self,
+        notification_id: NotificationId,
+    ) -> Result<Vec<WebhookDeliveryAttempt>, NotificationsError> {
+        let rows = sqlx::query_as::<_, WebhookDeliveryAttemptRow>(
+            r#"
+            SELECT id, notification_id, attempt_number, url, signature_algorithm,
+                   signed_timestamp, response_status, error, duration_ms, attempted_at
+            FROM webhook_delivery_attempts
+            WHERE notification_id = $1
+            ORDER BY attempted_at, attempt_number
+            "#,
+        )
+        .bind(notification_id.into_uuid())
+        .fetch_all(&self.pool)
+        .await?;
+        rows.into_iter().map(TryInto::try_into).collect()
+    }
+}
+
+#[derive(sqlx::FromRow)]
+struct WebhookDeliveryAttemptRow {
+    id: uuid::Uuid,
+    notification_id: uuid::Uuid,
+    attempt_number: i32,
+    url: String,
+    signature_algorithm: String,
+    signed_timestamp: i64,
+    response_status: Option<i32>,
+    error: Option<String>,
+    duration_ms: i64,
+    attempted_at: chrono::DateTime<chrono::Utc>,
+}
+
+impl TryFrom<WebhookDeliveryAttemptRow> for WebhookDeliveryAttempt {
+    type Error = NotificationsError;
+
+    fn try_from(row: WebhookDeliveryAttemptRow) -> Result<Self, Self::Error> {
+        let algorithm: SignatureAlgorithm = row.signature_algorithm.parse()?;
+        Ok(WebhookDeliveryAttempt::reconstitute(
+            row.id,
+            NotificationId::from_uuid(row.notification_id),
+            row.attempt_number,
+            row.url,
+            algorithm,
+            row.signed_timestamp,
+            row.response_status,
+            row.error,
+            row.duration_ms,
+            row.attempted_at,
+        ))
+    }
+}
@@ -0,0 +1,169 @@
+//! PostgreSQL implementation of WebhookDeliveryRepository.
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use identity::StoreId;
+use sqlx::PgPool;
+
+use crate::NotificationsError;
+use crate::domain::entities::WebhookDelivery;
+use crate::domain::repositories::WebhookDeliveryRepository;
+use crate::domain::value_objects::{WebhookDeliveryId, WebhookDeliveryStatus, WebhookId};
+
+const DELIVERY_COLUMNS: &str = "d.id, d.webhook_id, d.outbox_event_id, d.event_type, d.payload, \
+     d.status, d.attempts, d.next_attempt_at, d.last_response_status, d.last_error, \
+     d.delivered_at, d.created_at, d.updated_at";
+
+pub struct PgWebhookDeliveryRepository {
+    pool: PgPool,
+}
+
+impl PgWebhookDeliveryRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl WebhookDeliveryRepository for PgWebhookDeliveryRepository {
+    async fn enqueue(&self, deliveries: &[WebhookDelivery]) -> Result<(), NotificationsError> {
+        if deliveries.is_empty() {
+            return Ok(());
+        }
+
+        let mut query_builder = sqlx::QueryBuilder::new(
+            r#"INSERT INTO webhook_deliveries (
+                id, webhook_id, outbox_event_id, event_type, payload, status, attempts,
+                next_attempt_at, last_response_status, last_error, delivered_at,
+                created_at, updated_at
+            ) "#,
+        );
+        query_builder.push_values(deliveries, |mut b, d| {
+            b.push_bind(d.id().into_uuid())
+                .push_bind(d.webhook_id().into_uuid())
+                .push_bind(d.outbox_event_id())
+                .push_bind(d.event_type())
+                .push_bind(d.payload())
+                .push_bind(d.status().to_string())
+                .push_bind(d.attempts())
+                .push_bind(d.next_attempt_at())
+                .push_bind(d.last_response_status())
+                .push_bind(d.last_error())
+                .push_bind(d.delivered_at())
+                .push_bind(d.created_at())
+                .push_bind(d.updated_at());
+        });
+        query_builder.push(" ON CONFLICT (webhook_id, outbox_event_id) DO NOTHING");
+        query_builder.build().execute(&self.pool).await?;
+        Ok(())
+    }
+
+    async fn update(&self, d: &WebhookDelivery) -> Result<(), NotificationsError> {
+        sqlx::query(
+            r#"
+            UPDATE webhook_deliveries
+            SET status = $2,
+                attempts = $3,
+                next_attempt_at = $4,
+                last_response_status = $5,
+                last_error = $6,
+                delivered_at = $7,
+                updated_at = $8
+            WHERE id = $1
+            "#,
+        )
+        .bind(d.id().into_uuid())
+        .bind(d.status().to_string())
+        .bind(d.attempts())
+        .bind(d.next_attempt_at())
+        .bind(d.last_response_status())
+        .bind(d.last_error())
+        .bind(d.delivered_at())
+        .bind(d.updated_at())
+        .execute(&self.pool)
+        .await?;
+        Ok(())
+    }
+
+    async fn find_due(
+        &self,
+        now: DateTime<Utc>,
+        limit: i64,
+    ) -> Result<Vec<WebhookDelivery>, NotificationsError> {
+        let rows = sqlx::query_as::<_, WebhookDeliveryRow>(&format!(
+            "SELECT {DELIVERY_COLUMNS} FROM webhook_deliveries d \
+             WHERE d.status = 'pending' AND d.next_attempt_at <= $1 \
+             ORDER BY d.next_attempt_at, d.created_at \
+             LIMIT $2"
+        ))
+        .bind(now)
+        .bind(limit)
+        .fetch_all(&self.pool)
+        .await?;
+
+        rows.into_iter().map(WebhookDelivery::try_from).collect()
+    }
+
+    async fn find_dead_letters(
+        &self,
+        store_id: StoreId,
+        webhook_id: Option<WebhookId>,
+        limit: i64,
+    ) -> Result<Vec<WebhookDelivery>, NotificationsError> {
+        let rows = sqlx::query_as::<_, WebhookDeliveryRow>(&format!(
+            "SELECT {DELIVERY_COLUMNS} FROM webhook_deliveries d \
+             JOIN webhooks w ON w.id = d.webhook_id \
+             WHERE w.store_id = $1 AND d.status = 'dead_letter' \
+               AND ($2::uuid IS NULL OR d.webhook_id = $2) \
+             ORDER BY d.updated_at DESC \
+             LIMIT $3"
+        ))
+        .bind(store_id.into_uuid())
+        .bind(webhook_id.map(|id| id.into_uuid()))
+        .bind(limit)
+        .fetch_all(&self.pool)
+        .await?;
+
+        rows.into_iter().map(WebhookDelivery::try_from).collect()
+    }
+}
+
+#[derive(sqlx::FromRow)]
+struct WebhookDeliveryRow {
+    id: uuid::Uuid,
+    webhook_id: uuid::Uuid,
+    outbox_event_id: uuid::Uuid,
+    event_type: String,
+    payload: serde_json::Value,
+    status: String,
+    attempts: i32,
+    next_attempt_at: DateTime<Utc>,
+    last_response_status: Option<i32>,
+    last_error: Option<String>,
+    delivered_at: Option<DateTime<Utc>>,
+    created_at: DateTime<Utc>,
+    updated_at: DateTime<Utc>,
+}
+
+impl TryFrom<WebhookDeliveryRow> for WebhookDelivery {
+    type Error = NotificationsError;
+
+    fn try_from(row: WebhookDeliveryRow) -> Result<Self, Self::Error> {
+        let status: WebhookDeliveryStatus = row.status.parse()?;
+        Ok(WebhookDelivery::reconstitute(
+            WebhookDeliveryId::from_uuid(row.id),
+            WebhookId::from_uuid(row.webhook_id),
+            row.outbox_event_id,
+            row.event_type,
+            row.payload,
+            status,
+            row.attempts,
+            row.next_attempt_at,
+            row.last_response_status,
+            row.last_error,
+            row.delivered_at,
+            row.created_at,
+            row.updated_at,
+        ))
+    }
+}
@@ -0,0 +1,144 @@
+//! PostgreSQL implementation of WebhookRepository.
+
+use async_trait::async_trait;
+use identity::{StoreId, UserId};
+use sqlx::PgPool;
+
+use crate::NotificationsError;
+use crate::domain::entities::Webhook;
+use crate::domain::repositories::WebhookRepository;
+use crate::domain::value_objects::WebhookId;
+
+const WEBHOOK_COLUMNS: &str = "id, store_id, url, secret, event_types, is_active, \
+     created_by_id, created_at, updated_at";
+
+pub struct PgWebhookRepository {
+    pool: PgPool,
+}
+
+impl PgWebhookRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl WebhookRepository for PgWebhookRepository {
+    async fn save(&self, w: &Webhook) -> Result<(), NotificationsError> {
+        sqlx::query(
+            r#"
+            INSERT INTO webhooks (
+                id, store_id, url, secret, event_types, is_active,
+                created_by_id, created_at, updated_at
+            )
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
+            "#,
+        )
+        .bind(w.id().into_uuid())
+        .bind(w.store_id().into_uuid())
+        .bind(w.url())
+        .bind(w.secret())
+        .bind(w.event_types())
+        .bind(w.is_active())
+        .bind(w.created_by_id().into_uuid())
+        .bind(w.created_at())
+        .bind(w.updated_at())
+        .execute(&self.pool)
+        .await?;
+        Ok(())
+    }
+
+    async fn update(&self, w: &Webhook) -> Result<(), NotificationsError> {
+        let result = sqlx::query(
+            r#"
+            UPDATE webhooks
+            SET url = $2, secret = $3, event_types = $4, is_active = $5, updated_at = $6
+            WHERE id = $1
+            "#,
+        )
+        .bind(w.id().into_uuid())
+        .bind(w.url())
+        .bind(w.secret())
+        .bind(w.event_types())
+        .bind(w.is_active())
+        .bind(w.updated_at())
+        .execute(&self.pool)
+        .await?;
+
+        if result.rows_affected() == 0 {
+            return Err(NotificationsError::WebhookNotFound(w.id().into_uuid()));
+        }
+        Ok(())
+    }
+
+    async fn find_by_id(&self, id: WebhookId) -> Result<Option<Webhook>, NotificationsError> {
+        let row = sqlx::query_as::<_, WebhookRow>(&format!(
+            "SELECT {WEBHOOK_COLUMNS} FROM webhooks WHERE id = $1"
+        ))
+        .bind(id.into_uuid())
+        .fetch_optional(&self.pool)
+        .await?;
+        Ok(row.map(Webhook::from))
+    }
+
+    async fn find_active_by_store(
+        &self,
+        store_id: StoreId,
+    ) -> Result<Vec<Webhook>, NotificationsError> {
+        let rows = sqlx::query_as::<_, WebhookRow>(&format!(
+            "SELECT {WEBHOOK_COLUMNS} FROM webhooks \
+             WHERE store_id = $1 AND is_active \
+             ORDER BY created_at"
+        ))
+        .bind(store_id.into_uuid())
+        .fetch_all(&self.pool)
+        .await?;
+        Ok(rows.into_iter().map(Webhook::from).collect())
+    }
+
+    async fn find_active_for_event(
+        &self,
+        store_id: StoreId,
+        event_type: &str,
+    ) -> Result<Vec<Webhook>, NotificationsError> {
+        let rows = sqlx::query_as::<_, WebhookRow>(&format!(
+            "SELECT {WEBHOOK_COLUMNS} FROM webhooks \
+             WHERE store_id = $1 AND is_active AND $2 = ANY(event_types) \
+             ORDER BY created_at"
+        ))
+        .bind(store_id.into_uuid())
+        .bind(event_type)
+        .fetch_all(&self.pool)
+        .await?;
+        Ok(rows.into_iter().map(Webhook::from).collect())
+    }
+}
+
+#[derive(sqlx::FromRow)]
+struct WebhookRow {
+    id: uuid::Uuid,
+    store_id: uuid::Uuid,
+    url: String,
+    secret: String,
+    event_types: Vec<String>,
+    is_active: bool,
+    created_by_id: uuid::Uuid,
+    created_at: chrono::DateTime<chrono::Utc>,
+    updated_at: chrono::DateTime<chrono::Utc>,
+}
+
+impl From<WebhookRow> for Webhook {
+    fn from(row: WebhookRow) -> Self {
+        Webhook::reconstitute(
+            WebhookId::from_uuid(row.id),
+            StoreId::from_uuid(row.store_id),
+            row.url,
+            row.secret,
+            row.event_types,
+            row.is_active,
+            UserId::from_uuid(row.created_by_id),
+            row.created_at,
+            row.updated_at,
+        )
+    }
+}
@@ -8,16 +8,27 @@
 //!   the [`NotificationAdapterRegistry`] dispatches a channel to its adapter.
 //!   The default registry wires [`LogOnlyAdapter`] for every channel so a
 //!   fresh dev environment runs without provider credentials.
+//! - **Webhooks**: [`HttpWebhookAdapter`] signs each delivery (delivery id +
+//!   timestamp, HMAC-SHA256 by default) so receivers can reject tampered or
+//!   replayed payloads; attempts are persisted as [`WebhookDeliveryAttempt`].
 //! - **Use cases**: [`SendNotificationUseCase`] enqueues + delivers in one
 //!   step; [`RetryFailedNotificationsUseCase`] is the periodic retry job.
+//! - **Event webhooks**: stores register a [`Webhook`] (url, secret, event
+//!   types). [`WebhookEventPublisher`] turns drained outbox events into
+//!   [`WebhookDelivery`] rows; [`DispatchWebhooksUseCase`] POSTs them signed
+//!   with the webhook's secret, retries with exponential backoff and
+//!   dead-letters them per [`WebhookRetryPolicy`].
 //!
 //! ## Architecture
 //!
 //! Hexagonal/clean architecture with three layers:
 //!
-//! - **Domain**: `Notification` entity, `NotificationRepository` trait
-//! - **Application**: `SendNotificationUseCase`, `RetryFailedNotificationsUseCase`
-//! - **Infrastructure**: `PgNotificationRepository`, adapter trait + registry
+//! - **Domain**: `Notification`, `Webhook`, `WebhookDelivery` entities and
+//!   their repository traits
+//! - **Application**: `SendNotificationUseCase`, `RetryFailedNotificationsUseCase`,
+//!   webhook use cases, `WebhookEventPublisher`
+//! - **Infrastructure**: `PgNotificationRepository`, `PgWebhookRepository`,
+//!   `PgWebhookDeliveryRepository`, adapter trait + registry, `HttpWebhookClient`
 
 pub mod application;
 pub mod domain;
@@ -28,19 +39,38 @@ mod error;
 pub use error::NotificationsError;
 
 // Domain
-pub use domain::entities::Notification;
-pub use domain::repositories::NotificationRepository;
-pub use domain::value_objects::{NotificationChannel, NotificationId, NotificationStatus};
+pub use domain::entities::{
+    MIN_WEBHOOK_SECRET_LEN, Notification, Webhook, WebhookDelivery, WebhookDeliveryAttempt,
+};
+pub use domain::repositories::{
+    NotificationRepository, WebhookDeliveryAttemptRepository, WebhookDeliveryRepository,
+    WebhookRepository,
+};
+pub use domain::value_objects::{
+    DEFAULT_SIGNATURE_TOLERANCE_SECS, NotificationChannel, NotificationId, NotificationStatus,
+    SignatureAlgorithm, WebhookDeliveryId, WebhookDeliveryStatus, WebhookId, WebhookRetryPolicy,
+    WebhookSignature,
+};
 
 // Application
-pub use application::dtos::SendNotificationCommand;
+pub use application::dtos::{
+    RegisterWebhookCommand, SendNotificationCommand, WebhookDeliveryResponse, WebhookResponse,
+};
 pub use application::use_cases::{
-    DEFAULT_MAX_ATTEMPTS, RetryFailedNotificationsUseCase, SendNotificationUseCase,
+    DEFAULT_MAX_ATTEMPTS, DispatchWebhooksUseCase, ListFailedWebhookDeliveriesUseCase,
+    ListWebhooksUseCase, MAX_FAILED_DELIVERIES, RegisterWebhookUseCase,
+    RetryFailedNotificationsUseCase, SendNotificationUseCase, UnregisterWebhookUseCase,
 };
+pub use application::webhook_event_publisher::WebhookEventPublisher;
 
 // Infrastructure
 pub use infrastructure::adapters::{
-    DefaultNotificationAdapterRegistry, DeliveryResult, LogOnlyAdapter, NotificationAdapter,
-    NotificationAdapterRegistry,
+    DefaultNotificationAdapterRegistry, DeliveryResult, HttpWebhookAdapter, HttpWebhookClient,
+    LogOnlyAdapter, NotificationAdapter, NotificationAdapterRegistry, WEBHOOK_ID_HEADER,
+    WEBHOOK_SIGNATURE_HEADER, WEBHOOK_TIMESTAMP_HEADER, WEBHOOK_TOLERANCE_HEADER, WebhookClient,
+    WebhookRequest, WebhookSigningConfig, webhook_envelope,
+};
+pub use infrastructure::persistence::{
+    PgNotificationRepository, PgWebhookDeliveryAttemptRepository, PgWebhookDeliveryRepository,
+    PgWebhookRepository,
 };
-pub use infrastructure::persistence::PgNotificationRepository;
@@ -56,4 +56,6 @@ pub struct PayoutListResponse {
     pub total: i64,
     pub page: i64,
     pub page_size: i64,
+    /// Pass back as `after_cursor` for the next page; `None` on the last page
+    pub next_cursor: Option<String>,
 }
@@ -52,6 +52,8 @@ pub struct ListTransactionsQuery {
     pub search: Option<String>,
     pub page: Option<i64>,
     pub page_size: Option<i64>,
+    /// `next_cursor` of a previous page; when set, `page` is ignored
+    pub after_cursor: Option<String>,
 }
 
 /// Manually confirms a pending transaction (e.g. after verifying the deposit
@@ -2,11 +2,13 @@
 
 use std::sync::Arc;
 
+use common::PageCursor;
 use uuid::Uuid;
 
 use crate::PaymentsError;
 use crate::application::dtos::{PayoutListResponse, PayoutResponse};
 use crate::domain::repositories::PayoutRepository;
+use crate::domain::value_objects::PayoutId;
 use identity::StoreId;
 
 pub struct ListPayoutsUseCase {
@@ -23,21 +25,36 @@ impl ListPayoutsUseCase {
         store_id: Uuid,
         page: Option<i64>,
         page_size: Option<i64>,
+        after_cursor: Option<&str>,
     ) -> Result<PayoutListResponse, PaymentsError> {
         let store_id = StoreId::from_uuid(store_id);
         let page = page.unwrap_or(1).max(1);
         let page_size = page_size.unwrap_or(50).clamp(1, 200);
+        let after = PageCursor::decode_opt(after_cursor)?;
+        // A cursor page has no meaningful number
+        let page = if after.is_some() { 1 } else { page };
 
         let (rows, total) = self
             .payout_repo
-            .find_by_store(store_id, page, page_size)
+            .find_by_store(
+                store_id,
+                page,
+                page_size,
+                after.map(|c| PayoutId::from_uuid(c.id())),
+            )
             .await?;
+        let next_cursor = PageCursor::next(
+            rows.last().map(|p| p.id().into_uuid()),
+            rows.len(),
+            page_size,
+        );
 
         Ok(PayoutListResponse {
             items: rows.into_iter().map(PayoutResponse::from).collect(),
             total,
             page,
             page_size,
+            next_cursor,
         })
     }
 }
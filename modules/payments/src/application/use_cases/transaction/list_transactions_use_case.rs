@@ -3,12 +3,16 @@
 use std::str::FromStr;
 use std::sync::Arc;
 
+use common::PageCursor;
+
 use crate::PaymentsError;
 use crate::application::dtos::{
     ListTransactionsQuery, TransactionListResponse, TransactionResponse,
 };
 use crate::domain::repositories::{TransactionFilter, TransactionRepository};
-use crate::domain::value_objects::{PaymentGatewayId, TransactionStatus, TransactionType};
+use crate::domain::value_objects::{
+    PaymentGatewayId, TransactionId, TransactionStatus, TransactionType,
+};
 use identity::StoreId;
 use sales::SaleId;
 
@@ -27,6 +31,9 @@ impl ListTransactionsUseCase {
     ) -> Result<TransactionListResponse, PaymentsError> {
         let page = query.page.unwrap_or(1).max(1);
         let page_size = query.page_size.unwrap_or(50).clamp(1, 200);
+        let after = PageCursor::decode_opt(query.after_cursor.as_deref())?;
+        // A cursor page has no meaningful number
+        let page = if after.is_some() { 1 } else { page };
 
         let filter = TransactionFilter {
             store_id: query.store_id.map(StoreId::from_uuid),
@@ -45,18 +52,25 @@ impl ListTransactionsUseCase {
             date_from: query.date_from,
             date_to: query.date_to,
             search: query.search,
+            after_id: after.map(|c| TransactionId::from_uuid(c.id())),
         };
 
         let (rows, total) = self
             .transaction_repo
             .find_paginated(filter, page, page_size)
             .await?;
+        let next_cursor = PageCursor::next(
+            rows.last().map(|t| t.id().into_uuid()),
+            rows.len(),
+            page_size,
+        );
 
         Ok(TransactionListResponse {
             items: rows.into_iter().map(TransactionResponse::from).collect(),
             total,
             page,
             page_size,
+            next_cursor,
         })
     }
 }
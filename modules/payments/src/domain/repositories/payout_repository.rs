@@ -13,11 +13,14 @@ pub trait PayoutRepository: Send + Sync {
 
     async fn find_by_id(&self, id: PayoutId) -> Result<Option<Payout>, PaymentsError>;
 
+    /// With `after_id`, only payouts with a smaller id are returned, newest
+    /// id first, and the page offset is ignored
     async fn find_by_store(
         &self,
         store_id: StoreId,
         page: i64,
         page_size: i64,
+        after_id: Option<PayoutId>,
     ) -> Result<(Vec<Payout>, i64), PaymentsError>;
 
     async fn update(&self, payout: &Payout) -> Result<(), PaymentsError>;
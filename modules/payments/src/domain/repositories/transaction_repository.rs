@@ -21,6 +21,9 @@ pub struct TransactionFilter {
     pub date_from: Option<DateTime<Utc>>,
     pub date_to: Option<DateTime<Utc>>,
     pub search: Option<String>,
+    /// Keyset cursor: only transactions with a smaller id, newest id first.
+    /// The page offset is ignored when set.
+    pub after_id: Option<TransactionId>,
 }
 
 #[async_trait]
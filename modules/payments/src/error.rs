@@ -90,6 +90,12 @@ pub enum PaymentsError {
     #[error("Invalid payout status")]
     InvalidPayoutStatus,
 
+    // -------------------------------------------------------------------------
+    // Listing errors
+    // -------------------------------------------------------------------------
+    #[error(transparent)]
+    InvalidPageCursor(#[from] common::InvalidPageCursor),
+
     // -------------------------------------------------------------------------
     // Cross-module references
     // -------------------------------------------------------------------------
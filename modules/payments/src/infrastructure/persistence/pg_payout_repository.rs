@@ -67,8 +67,13 @@ impl PayoutRepository for PgPayoutRepository {
         store_id: StoreId,
         page: i64,
         page_size: i64,
+        after_id: Option<PayoutId>,
     ) -> Result<(Vec<Payout>, i64), PaymentsError> {
-        let offset = (page - 1) * page_size;
+        let offset = if after_id.is_some() {
+            0
+        } else {
+            (page - 1) * page_size
+        };
         let rows = sqlx::query_as::<_, PayoutRow>(
             r#"
             SELECT id, store_id, gateway_id, status, amount, currency,
@@ -77,13 +82,15 @@ impl PayoutRepository for PgPayoutRepository {
                    created_at, updated_at
             FROM payouts
             WHERE store_id = $1
-            ORDER BY created_at DESC
+              AND ($4::uuid IS NULL OR id < $4)
+            ORDER BY id DESC
             LIMIT $2 OFFSET $3
             "#,
         )
         .bind(store_id.into_uuid())
         .bind(page_size)
         .bind(offset)
+        .bind(after_id.map(|id| id.into_uuid()))
         .fetch_all(&self.pool)
         .await?;
 
@@ -204,7 +204,11 @@ impl TransactionRepository for PgTransactionRepository {
         page: i64,
         page_size: i64,
     ) -> Result<(Vec<Transaction>, i64), PaymentsError> {
-        let offset = (page - 1) * page_size;
+        let offset = if filter.after_id.is_some() {
+            0
+        } else {
+            (page - 1) * page_size
+        };
 
         let mut data_qb: QueryBuilder<Postgres> = QueryBuilder::new(format!(
             "SELECT {SELECT_COLUMNS} FROM payment_transactions WHERE 1 = 1"
@@ -215,7 +219,12 @@ impl TransactionRepository for PgTransactionRepository {
         push_filters(&mut data_qb, &filter);
         push_filters(&mut count_qb, &filter);
 
-        data_qb.push(" ORDER BY created_at DESC LIMIT ");
+        if let Some(after_id) = filter.after_id {
+            data_qb.push(" AND id < ");
+            data_qb.push_bind(after_id.into_uuid());
+        }
+        data_qb.push(" ORDER BY id DESC");
+        data_qb.push(" LIMIT ");
         data_qb.push_bind(page_size);
         data_qb.push(" OFFSET ");
         data_qb.push_bind(offset);
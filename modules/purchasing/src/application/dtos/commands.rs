@@ -21,6 +21,9 @@ pub struct CreateVendorCommand {
     pub legal_name: String,
     /// Tax identification number (must be unique)
     pub tax_id: String,
+    /// Country whose format the tax ID follows (ISO 3166-1 alpha-2,
+    /// default: the configured country)
+    pub tax_id_country: Option<String>,
     /// Optional contact email
     pub email: Option<String>,
     /// Optional contact phone
@@ -44,6 +47,8 @@ pub struct UpdateVendorCommand {
     pub legal_name: Option<String>,
     /// New tax ID (if changing)
     pub tax_id: Option<String>,
+    /// New tax ID country (if changing)
+    pub tax_id_country: Option<String>,
     /// New email (if changing)
     pub email: Option<String>,
     /// New phone (if changing)
@@ -96,8 +101,14 @@ pub struct CreatePurchaseOrderItemCommand {
     pub quantity_ordered: Decimal,
     /// Unit of measure: "unit", "kg", "lb", "liter", "oz"
     pub unit_of_measure: String,
-    /// Cost per unit
+    /// Cost per unit; ignored when the line is priced in packs
+    #[serde(default)]
     pub unit_cost: Decimal,
+    /// Units of measure in one vendor pack (e.g., 24 for a case of 24).
+    /// When set, `quantity_ordered` counts packs and `pack_price` is required.
+    pub pack_size: Option<Decimal>,
+    /// Price of one vendor pack; the unit cost is derived from it
+    pub pack_price: Option<Decimal>,
     /// Discount percentage (e.g., 10 for 10%, default: 0)
     #[serde(default)]
     pub discount_percent: Decimal,
@@ -165,6 +176,13 @@ pub struct UpdateOrderItemCommand {
     pub notes: Option<String>,
 }
 
+/// Command to submit a draft purchase order for approval
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct SubmitOrderCommand {
+    /// Expected delivery date (YYYY-MM-DD); replaces the order's current one
+    pub expected_delivery_date: Option<String>,
+}
+
 /// Command to reject a submitted purchase order (returns to draft)
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct RejectOrderCommand {
@@ -179,6 +197,24 @@ pub struct CancelOrderCommand {
     pub reason: String,
 }
 
+/// Command to cancel every stale purchase order matching a filter
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BulkCancelPurchaseOrdersCommand {
+    /// Store whose purchase orders are cancelled
+    pub store_id: Uuid,
+    /// Only this status (draft or submitted); both when omitted
+    pub status: Option<String>,
+    /// Only orders not updated in the last N days
+    pub older_than_days: u32,
+    /// Only orders with this vendor
+    pub vendor_id: Option<Uuid>,
+    /// Reason recorded on each cancelled order (required)
+    pub reason: String,
+    /// Return what would be cancelled without cancelling anything
+    #[serde(default)]
+    pub dry_run: bool,
+}
+
 // =============================================================================
 // Goods Receipt Commands
 // =============================================================================
@@ -194,6 +230,10 @@ pub struct CreateGoodsReceiptCommand {
     pub receipt_date: String,
     /// Optional notes
     pub notes: Option<String>,
+    /// On confirmation, move quantities still pending on the order into a
+    /// new draft backorder instead of leaving the order partially received
+    #[serde(default)]
+    pub split_backorder: bool,
     /// Receipt line items
     pub items: Vec<CreateGoodsReceiptItemCommand>,
 }
@@ -207,9 +247,10 @@ pub struct CreateGoodsReceiptItemCommand {
     pub product_id: Uuid,
     /// Optional variant ID (if receiving a specific variant)
     pub variant_id: Option<Uuid>,
-    /// Quantity actually received
+    /// Quantity actually received, in packs for lines priced in packs
     pub quantity_received: Decimal,
-    /// Actual unit cost at time of receipt
+    /// Actual cost per ordered unit at time of receipt (per pack for lines
+    /// priced in packs)
     pub unit_cost: Decimal,
     /// Optional lot/batch number for traceability
     pub lot_number: Option<String>,
@@ -217,6 +258,10 @@ pub struct CreateGoodsReceiptItemCommand {
     pub expiry_date: Option<String>,
     /// Optional notes for this line item
     pub notes: Option<String>,
+    /// Serial numbers of the units received, one per unit; required for
+    /// serial-tracked products
+    #[serde(default)]
+    pub serial_numbers: Vec<String>,
 }
 
 /// Command to update an existing goods receipt (only in draft status)
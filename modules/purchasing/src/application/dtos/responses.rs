@@ -20,6 +20,7 @@ pub struct VendorResponse {
     pub name: String,
     pub legal_name: String,
     pub tax_id: String,
+    pub tax_id_country: Option<String>,
     pub email: Option<String>,
     pub phone: Option<String>,
     pub address: Option<String>,
@@ -40,11 +41,19 @@ pub struct VendorResponse {
 pub struct PurchaseOrderResponse {
     pub id: Uuid,
     pub order_number: String,
+    /// Order this one is a backorder of
+    pub parent_order_id: Option<Uuid>,
     pub store_id: Uuid,
     pub vendor_id: Uuid,
     pub status: String,
     pub order_date: NaiveDate,
     pub expected_delivery_date: Option<NaiveDate>,
+    /// Date the order was fully received
+    pub received_date: Option<NaiveDate>,
+    /// Date the first goods receipt was confirmed
+    pub first_received_date: Option<NaiveDate>,
+    /// Days past the expected delivery date (see `PurchaseOrder::days_late`)
+    pub days_late: Option<i64>,
     pub subtotal: Decimal,
     pub tax_amount: Decimal,
     pub discount_amount: Decimal,
@@ -62,6 +71,8 @@ pub struct PurchaseOrderResponse {
 pub struct PurchaseOrderDetailResponse {
     pub id: Uuid,
     pub order_number: String,
+    /// Order this one is a backorder of
+    pub parent_order_id: Option<Uuid>,
     pub store_id: Uuid,
     pub vendor_id: Uuid,
     pub status: String,
@@ -78,10 +89,17 @@ pub struct PurchaseOrderDetailResponse {
     pub created_by_id: Uuid,
     pub submitted_by_id: Option<Uuid>,
     pub submitted_at: Option<DateTime<Utc>>,
+    /// First approver of an order that needs two approvals
+    pub first_approved_by_id: Option<Uuid>,
+    pub first_approved_at: Option<DateTime<Utc>>,
     pub approved_by_id: Option<Uuid>,
     pub approved_at: Option<DateTime<Utc>>,
     pub received_by_id: Option<Uuid>,
     pub received_date: Option<NaiveDate>,
+    /// Date the first goods receipt was confirmed
+    pub first_received_date: Option<NaiveDate>,
+    /// Days past the expected delivery date (see `PurchaseOrder::days_late`)
+    pub days_late: Option<i64>,
     pub cancelled_by_id: Option<Uuid>,
     pub cancelled_at: Option<DateTime<Utc>>,
     pub cancellation_reason: Option<String>,
@@ -102,13 +120,48 @@ pub struct PurchaseOrderItemResponse {
     pub quantity_ordered: Decimal,
     pub quantity_received: Decimal,
     pub unit_of_measure: String,
+    /// Cost of one stocking unit; derived from the pack price for lines
+    /// priced in packs
     pub unit_cost: Decimal,
+    /// Stocking units per vendor pack, for lines priced in packs
+    pub pack_size: Option<Decimal>,
+    /// Price of one vendor pack, for lines priced in packs
+    pub pack_price: Option<Decimal>,
     pub discount_percent: Decimal,
     pub tax_percent: Decimal,
     pub line_total: Decimal,
     pub notes: Option<String>,
 }
 
+/// Outcome of one purchase order in a bulk cancel
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BulkCancelOrderResult {
+    pub purchase_order_id: Uuid,
+    pub order_number: String,
+    pub vendor_id: Uuid,
+    /// Status and last update before the bulk cancel
+    pub status: String,
+    pub updated_at: DateTime<Utc>,
+    /// `cancelled`, `would_cancel` (dry run) or `skipped`
+    pub outcome: String,
+    /// Why the order was skipped
+    pub skip_reason: Option<String>,
+}
+
+/// Response for a bulk cancel of purchase orders
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BulkCancelPurchaseOrdersResponse {
+    pub dry_run: bool,
+    /// Orders matching the filter
+    pub matched: usize,
+    /// Orders cancelled; always 0 on a dry run
+    pub cancelled: usize,
+    /// Orders a dry run would cancel; always 0 otherwise
+    pub would_cancel: usize,
+    pub skipped: usize,
+    pub results: Vec<BulkCancelOrderResult>,
+}
+
 // =============================================================================
 // Goods Receipt Responses
 // =============================================================================
@@ -140,9 +193,15 @@ pub struct GoodsReceiptDetailResponse {
     pub receipt_date: NaiveDate,
     pub status: String,
     pub notes: Option<String>,
+    pub split_backorder: bool,
+    /// Backorder created when this receipt was confirmed
+    pub backorder_id: Option<Uuid>,
     pub received_by_id: Uuid,
     pub confirmed_by_id: Option<Uuid>,
     pub confirmed_at: Option<DateTime<Utc>>,
+    /// `matched` or `overridden`; `None` if confirmed without matching
+    pub match_status: Option<String>,
+    pub match_overridden_by_id: Option<Uuid>,
     pub items: Vec<GoodsReceiptItemResponse>,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
@@ -161,4 +220,86 @@ pub struct GoodsReceiptItemResponse {
     pub lot_number: Option<String>,
     pub expiry_date: Option<NaiveDate>,
     pub notes: Option<String>,
+    pub serial_numbers: Vec<String>,
+    /// Quantity received past the ordered quantity, once matched
+    pub quantity_variance: Option<Decimal>,
+    /// Unit cost minus the ordered unit cost, once matched
+    pub cost_variance: Option<Decimal>,
+}
+
+// =============================================================================
+// Purchase Cost History Responses
+// =============================================================================
+
+/// One recent receipt cost for a product
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RecentUnitCostResponse {
+    pub goods_receipt_id: Uuid,
+    pub receipt_number: String,
+    pub receipt_date: NaiveDate,
+    pub confirmed_at: Option<DateTime<Utc>>,
+    pub store_id: Uuid,
+    pub purchase_order_id: Uuid,
+    pub order_number: String,
+    pub vendor_id: Uuid,
+    pub vendor_name: String,
+    pub currency: String,
+    pub quantity_received: Decimal,
+    pub unit_cost: Decimal,
+}
+
+/// Last N prices paid for a product, with summary statistics.
+///
+/// Statistics only cover receipts in `currency` (the currency of the most
+/// recent receipt); receipts in other currencies are listed but not averaged.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RecentPurchaseCostsResponse {
+    pub product_id: Uuid,
+    pub variant_id: Option<Uuid>,
+    pub limit: i64,
+    pub currency: Option<String>,
+    pub receipts: Vec<RecentUnitCostResponse>,
+    pub total_quantity: Decimal,
+    /// Average unit cost weighted by quantity received
+    pub weighted_average_cost: Option<Decimal>,
+    pub min_unit_cost: Option<Decimal>,
+    pub max_unit_cost: Option<Decimal>,
+    pub last_unit_cost: Option<Decimal>,
+    /// Unit cost the buyer was quoted, echoed back from the query
+    pub quoted_unit_cost: Option<Decimal>,
+    /// `(quoted - weighted average) / weighted average * 100`
+    pub quoted_variance_percent: Option<Decimal>,
+}
+
+// =============================================================================
+// Vendor Performance Responses
+// =============================================================================
+
+/// Delivery performance of one vendor over the report's date range
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct VendorPerformanceEntry {
+    /// 1 for the best vendor
+    pub rank: usize,
+    pub vendor_id: Uuid,
+    pub vendor_name: String,
+    /// Approved orders placed in the range
+    pub order_count: usize,
+    /// Orders with at least one confirmed receipt
+    pub delivered_order_count: usize,
+    /// % of delivered orders with an expected date whose last receipt
+    /// arrived on or before it; `None` if no such order
+    pub on_time_rate: Option<Decimal>,
+    /// Σ received / Σ ordered quantity × 100; `None` if nothing was ordered
+    pub fill_rate: Option<Decimal>,
+    /// Average days from order date to first receipt over delivered orders
+    pub average_lead_time_days: Option<Decimal>,
+}
+
+/// Vendors ranked by on-time rate, then fill rate, then lead time
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct VendorPerformanceResponse {
+    pub store_id: Option<Uuid>,
+    pub from_date: NaiveDate,
+    pub to_date: NaiveDate,
+    pub vendors: Vec<VendorPerformanceEntry>,
 }
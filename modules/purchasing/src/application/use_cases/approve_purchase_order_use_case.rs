@@ -1,5 +1,6 @@
 // ApprovePurchaseOrderUseCase - approves a submitted purchase order
 
+use chrono::Utc;
 use std::sync::Arc;
 use uuid::Uuid;
 
@@ -7,15 +8,24 @@ use crate::PurchasingError;
 use crate::application::dtos::responses::{PurchaseOrderDetailResponse, PurchaseOrderItemResponse};
 use crate::domain::entities::PurchaseOrder;
 use crate::domain::repositories::PurchaseOrderRepository;
-use crate::domain::value_objects::PurchaseOrderId;
-use identity::UserId;
+use crate::domain::value_objects::{PurchaseApprovalPolicy, PurchaseOrderId};
+use identity::{PermissionCheckResult, UserContext};
+
+/// Permission required to approve purchase orders
+const APPROVE_PURCHASE_ORDER_PERMISSION: &str = "purchase_orders:approve";
 
 /// Use case for approving a submitted purchase order
+///
+/// The caller must hold `purchase_orders:approve`; the check is made here
+/// and not only by the HTTP layer. The approval policy caps the total each approver may approve and, above
+/// its dual approval threshold, makes the order wait for a second, different
+/// approver before it moves to approved.
 pub struct ApprovePurchaseOrderUseCase<P>
 where
     P: PurchaseOrderRepository,
 {
     order_repo: Arc<P>,
+    approval_policy: PurchaseApprovalPolicy,
 }
 
 impl<P> ApprovePurchaseOrderUseCase<P>
@@ -24,25 +34,45 @@ where
 {
     /// Creates a new instance of ApprovePurchaseOrderUseCase
     pub fn new(order_repo: Arc<P>) -> Self {
-        Self { order_repo }
+        Self {
+            order_repo,
+            approval_policy: PurchaseApprovalPolicy::disabled(),
+        }
+    }
+
+    /// Applies spend limits and dual approval
+    pub fn with_approval_policy(mut self, policy: PurchaseApprovalPolicy) -> Self {
+        self.approval_policy = policy;
+        self
     }
 
     /// Executes the use case to approve a purchase order
     ///
     /// # Arguments
     /// * `order_id` - The ID of the purchase order to approve
-    /// * `actor_id` - ID of the user approving the order
+    /// * `ctx` - Context of the approving user; its permissions are also
+    ///   matched against the policy's tiers
     ///
     /// # Returns
-    /// PurchaseOrderDetailResponse on success
+    /// PurchaseOrderDetailResponse on success; an order needing two approvals
+    /// stays submitted after the first one
     ///
     /// # Errors
+    /// * `PurchasingError::Forbidden` - If the user lacks `purchase_orders:approve`
     /// * `PurchasingError::CannotApproveSelfCreatedOrder` - If approver is the order creator
+    /// * `PurchasingError::ApprovalLimitExceeded` - If the total exceeds the approver's limit
+    /// * `PurchasingError::DuplicateApprover` - If the first approver approves again
     pub async fn execute(
         &self,
         order_id: Uuid,
-        actor_id: UserId,
+        ctx: &UserContext,
     ) -> Result<PurchaseOrderDetailResponse, PurchasingError> {
+        if let PermissionCheckResult::Denied { missing } =
+            ctx.authorize(&[APPROVE_PURCHASE_ORDER_PERMISSION])
+        {
+            return Err(PurchasingError::Forbidden { missing });
+        }
+        let actor_id = *ctx.user_id();
         let id = PurchaseOrderId::from_uuid(order_id);
 
         // Find order with items
@@ -52,8 +82,17 @@ where
             .await?
             .ok_or(PurchasingError::PurchaseOrderNotFound(order_id))?;
 
+        self.approval_policy
+            .check(order.total(), ctx.permissions())?;
+
         // Approve order (will check self-approval rule)
-        order.approve(actor_id)?;
+        if self.approval_policy.requires_second_approval(order.total())
+            && order.first_approved_by_id().is_none()
+        {
+            order.approve_first(actor_id)?;
+        } else {
+            order.approve(actor_id)?;
+        }
 
         // Update order
         self.order_repo.update(&order).await?;
@@ -76,6 +115,8 @@ where
                 quantity_received: item.quantity_received(),
                 unit_of_measure: item.unit_of_measure().to_string(),
                 unit_cost: item.unit_cost(),
+                pack_size: item.pack_size(),
+                pack_price: item.pack_price(),
                 discount_percent: item.discount_percent(),
                 tax_percent: item.tax_percent(),
                 line_total: item.line_total(),
@@ -86,6 +127,7 @@ where
         PurchaseOrderDetailResponse {
             id: order.id().into_uuid(),
             order_number: order.order_number().to_string(),
+            parent_order_id: order.parent_order_id().map(|id| id.into_uuid()),
             store_id: order.store_id().into_uuid(),
             vendor_id: order.vendor_id().into_uuid(),
             status: order.status().to_string(),
@@ -102,10 +144,14 @@ where
             created_by_id: order.created_by_id().into_uuid(),
             submitted_by_id: order.submitted_by_id().map(|id| id.into_uuid()),
             submitted_at: order.submitted_at(),
+            first_approved_by_id: order.first_approved_by_id().map(|id| id.into_uuid()),
+            first_approved_at: order.first_approved_at(),
             approved_by_id: order.approved_by_id().map(|id| id.into_uuid()),
             approved_at: order.approved_at(),
             received_by_id: order.received_by_id().map(|id| id.into_uuid()),
             received_date: order.received_date(),
+            first_received_date: order.first_received_date(),
+            days_late: order.days_late(Utc::now().date_naive()),
             cancelled_by_id: order.cancelled_by_id().map(|id| id.into_uuid()),
             cancelled_at: order.cancelled_at(),
             cancellation_reason: order.cancellation_reason().map(|s| s.to_string()),
@@ -115,3 +161,242 @@ where
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use async_trait::async_trait;
+    use chrono::NaiveDate;
+    use rust_decimal_macros::dec;
+    use std::collections::{HashMap, HashSet};
+    use std::sync::Mutex;
+
+    use crate::domain::entities::PurchaseOrderItem;
+    use crate::domain::repositories::{PurchaseOrderFilter, StalePurchaseOrderFilter};
+    use crate::domain::value_objects::{PurchaseOrderItemId, PurchaseOrderStatus, VendorId};
+    use identity::{PermissionCode, StoreId, UserId};
+    use inventory::{Currency, ProductId, UnitOfMeasure};
+
+    struct MockOrderRepository {
+        orders: Mutex<HashMap<PurchaseOrderId, PurchaseOrder>>,
+    }
+
+    #[async_trait]
+    impl PurchaseOrderRepository for MockOrderRepository {
+        async fn save(&self, _order: &PurchaseOrder) -> Result<(), PurchasingError> {
+            unimplemented!()
+        }
+        async fn save_many(&self, _orders: &[PurchaseOrder]) -> Result<(), PurchasingError> {
+            unimplemented!()
+        }
+        async fn find_by_id(
+            &self,
+            _id: PurchaseOrderId,
+        ) -> Result<Option<PurchaseOrder>, PurchasingError> {
+            unimplemented!()
+        }
+        async fn find_by_id_with_items(
+            &self,
+            id: PurchaseOrderId,
+        ) -> Result<Option<PurchaseOrder>, PurchasingError> {
+            Ok(self.orders.lock().unwrap().get(&id).cloned())
+        }
+        async fn find_by_order_number(
+            &self,
+            _store_id: StoreId,
+            _order_number: &str,
+        ) -> Result<Option<PurchaseOrder>, PurchasingError> {
+            unimplemented!()
+        }
+        async fn update(&self, order: &PurchaseOrder) -> Result<(), PurchasingError> {
+            self.orders
+                .lock()
+                .unwrap()
+                .insert(order.id(), order.clone());
+            Ok(())
+        }
+        async fn find_paginated(
+            &self,
+            _filter: PurchaseOrderFilter,
+            _page: i64,
+            _page_size: i64,
+        ) -> Result<(Vec<PurchaseOrder>, i64), PurchasingError> {
+            unimplemented!()
+        }
+        async fn find_stale(
+            &self,
+            _filter: &StalePurchaseOrderFilter,
+        ) -> Result<Vec<PurchaseOrder>, PurchasingError> {
+            unimplemented!()
+        }
+        async fn cancel_many(&self, _orders: &[PurchaseOrder]) -> Result<(), PurchasingError> {
+            unimplemented!()
+        }
+        async fn find_vendor_deliveries(
+            &self,
+            _store_id: Option<identity::StoreId>,
+            _from: chrono::NaiveDate,
+            _to: chrono::NaiveDate,
+        ) -> Result<Vec<crate::domain::repositories::VendorOrderDelivery>, PurchasingError>
+        {
+            unimplemented!()
+        }
+        async fn generate_order_number(
+            &self,
+            _store_id: StoreId,
+        ) -> Result<String, PurchasingError> {
+            unimplemented!()
+        }
+        async fn save_item(&self, _item: &PurchaseOrderItem) -> Result<(), PurchasingError> {
+            unimplemented!()
+        }
+        async fn update_item(&self, _item: &PurchaseOrderItem) -> Result<(), PurchasingError> {
+            unimplemented!()
+        }
+        async fn delete_item(&self, _item_id: PurchaseOrderItemId) -> Result<(), PurchasingError> {
+            unimplemented!()
+        }
+        async fn find_items_by_order(
+            &self,
+            _order_id: PurchaseOrderId,
+        ) -> Result<Vec<PurchaseOrderItem>, PurchasingError> {
+            unimplemented!()
+        }
+        async fn find_item_by_id(
+            &self,
+            _item_id: PurchaseOrderItemId,
+        ) -> Result<Option<PurchaseOrderItem>, PurchasingError> {
+            unimplemented!()
+        }
+    }
+
+    /// Submitted order totalling 1000
+    fn setup() -> (Arc<MockOrderRepository>, Uuid) {
+        let mut order = PurchaseOrder::create(
+            "PO-2024-00001".to_string(),
+            StoreId::new(),
+            VendorId::new(),
+            NaiveDate::from_ymd_opt(2024, 1, 20).unwrap(),
+            Currency::new("HNL").unwrap(),
+            30,
+            UserId::new(),
+        );
+        let item = PurchaseOrderItem::create(
+            order.id(),
+            1,
+            ProductId::new(),
+            None,
+            "Test Product".to_string(),
+            dec!(10),
+            UnitOfMeasure::Unit,
+            dec!(100),
+            dec!(0),
+            dec!(0),
+        );
+        order.add_item(item).unwrap();
+        order.submit(UserId::new()).unwrap();
+
+        let id = order.id().into_uuid();
+        let repo = MockOrderRepository {
+            orders: Mutex::new(HashMap::from([(order.id(), order)])),
+        };
+        (Arc::new(repo), id)
+    }
+
+    fn context(user_id: UserId, codes: &[&str]) -> UserContext {
+        let permissions: HashSet<PermissionCode> = codes
+            .iter()
+            .map(|code| PermissionCode::new(code).unwrap())
+            .collect();
+        UserContext::new(
+            user_id,
+            StoreId::new(),
+            permissions,
+            vec![],
+            false,
+            None,
+            None,
+        )
+    }
+
+    fn policy() -> PurchaseApprovalPolicy {
+        PurchaseApprovalPolicy::disabled()
+            .with_tier(
+                PermissionCode::new("purchase_orders:approve").unwrap(),
+                Some(dec!(500)),
+            )
+            .with_tier(
+                PermissionCode::new("purchase_orders:approve_tier2").unwrap(),
+                None,
+            )
+    }
+
+    #[tokio::test]
+    async fn test_rejects_total_above_approver_limit() {
+        let (repo, id) = setup();
+        let use_case = ApprovePurchaseOrderUseCase::new(repo).with_approval_policy(policy());
+
+        let result = use_case
+            .execute(id, &context(UserId::new(), &["purchase_orders:approve"]))
+            .await;
+
+        match result {
+            Err(PurchasingError::ApprovalLimitExceeded {
+                total,
+                required_tier,
+            }) => {
+                assert_eq!(total, dec!(1000));
+                assert_eq!(required_tier, "purchase_orders:approve_tier2");
+            }
+            other => panic!("unexpected result: {:?}", other.map(|r| r.status)),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_large_order_needs_two_distinct_approvers() {
+        let (repo, id) = setup();
+        let use_case = ApprovePurchaseOrderUseCase::new(repo)
+            .with_approval_policy(policy().with_dual_approval_threshold(dec!(800)));
+        let tier2 = ["purchase_orders:approve", "purchase_orders:approve_tier2"];
+        let first = context(UserId::new(), &tier2);
+
+        let response = use_case.execute(id, &first).await.unwrap();
+        assert_eq!(response.status, PurchaseOrderStatus::Submitted.to_string());
+        assert_eq!(
+            response.first_approved_by_id,
+            Some(first.user_id().into_uuid())
+        );
+
+        let result = use_case.execute(id, &first).await;
+        assert!(matches!(result, Err(PurchasingError::DuplicateApprover)));
+
+        let response = use_case
+            .execute(id, &context(UserId::new(), &tier2))
+            .await
+            .unwrap();
+        assert_eq!(response.status, PurchaseOrderStatus::Approved.to_string());
+    }
+
+    #[tokio::test]
+    async fn test_rejects_caller_without_approve_permission() {
+        let (repo, id) = setup();
+        let use_case = ApprovePurchaseOrderUseCase::new(repo.clone());
+
+        let result = use_case
+            .execute(id, &context(UserId::new(), &["purchase_orders:read"]))
+            .await;
+
+        match result {
+            Err(PurchasingError::Forbidden { missing }) => {
+                assert_eq!(missing, vec!["purchase_orders:approve".to_string()]);
+            }
+            other => panic!("unexpected result: {:?}", other.map(|r| r.status)),
+        }
+        let order = repo
+            .find_by_id_with_items(PurchaseOrderId::from_uuid(id))
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(order.status(), PurchaseOrderStatus::Submitted);
+    }
+}
@@ -0,0 +1,516 @@
+// BulkCancelPurchaseOrdersUseCase - cancels stale draft/submitted purchase orders
+
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use chrono::{Duration, Utc};
+
+use crate::PurchasingError;
+use crate::application::dtos::commands::BulkCancelPurchaseOrdersCommand;
+use crate::application::dtos::responses::{
+    BulkCancelOrderResult, BulkCancelPurchaseOrdersResponse,
+};
+use crate::domain::entities::PurchaseOrder;
+use crate::domain::repositories::{
+    GoodsReceiptRepository, PurchaseOrderRepository, StalePurchaseOrderFilter,
+};
+use crate::domain::value_objects::{PurchaseOrderStatus, VendorId};
+use identity::{StoreId, UserId};
+
+/// Use case for cancelling, in one go, the purchase orders of a store left in
+/// draft or submitted status.
+///
+/// Every order matching the filter is cancelled with the same reason and
+/// actor in a single transaction. Orders with a goods receipt against them
+/// are skipped. A dry run returns the same per-order results without
+/// cancelling anything, counting them as `would_cancel` rather than
+/// `cancelled`.
+pub struct BulkCancelPurchaseOrdersUseCase<P, G>
+where
+    P: PurchaseOrderRepository,
+    G: GoodsReceiptRepository,
+{
+    order_repo: Arc<P>,
+    receipt_repo: Arc<G>,
+}
+
+impl<P, G> BulkCancelPurchaseOrdersUseCase<P, G>
+where
+    P: PurchaseOrderRepository,
+    G: GoodsReceiptRepository,
+{
+    /// Creates a new instance of BulkCancelPurchaseOrdersUseCase
+    pub fn new(order_repo: Arc<P>, receipt_repo: Arc<G>) -> Self {
+        Self {
+            order_repo,
+            receipt_repo,
+        }
+    }
+
+    /// Executes the use case to cancel the matching purchase orders
+    ///
+    /// # Arguments
+    /// * `command` - The filter, reason and dry-run flag
+    /// * `actor_id` - ID of the user cancelling the orders
+    ///
+    /// # Returns
+    /// BulkCancelPurchaseOrdersResponse with one result per matching order
+    pub async fn execute(
+        &self,
+        command: BulkCancelPurchaseOrdersCommand,
+        actor_id: UserId,
+    ) -> Result<BulkCancelPurchaseOrdersResponse, PurchasingError> {
+        let reason = command.reason.trim().to_string();
+        if reason.is_empty() {
+            return Err(PurchasingError::CancellationReasonRequired);
+        }
+
+        let statuses = match command.status {
+            Some(ref status) => {
+                let status: PurchaseOrderStatus = status.parse()?;
+                if !status.can_cancel() {
+                    return Err(PurchasingError::InvalidStatusTransition);
+                }
+                vec![status]
+            }
+            None => vec![PurchaseOrderStatus::Draft, PurchaseOrderStatus::Submitted],
+        };
+
+        let filter = StalePurchaseOrderFilter {
+            store_id: StoreId::from_uuid(command.store_id),
+            statuses,
+            updated_before: Utc::now() - Duration::days(i64::from(command.older_than_days)),
+            vendor_id: command.vendor_id.map(VendorId::from_uuid),
+        };
+        let orders = self.order_repo.find_stale(&filter).await?;
+
+        let order_ids: Vec<_> = orders.iter().map(|o| o.id()).collect();
+        let with_receipts: HashSet<_> = if order_ids.is_empty() {
+            HashSet::new()
+        } else {
+            self.receipt_repo
+                .find_orders_with_receipts(&order_ids)
+                .await?
+                .into_iter()
+                .collect()
+        };
+
+        let matched = orders.len();
+        let mut results = Vec::with_capacity(matched);
+        let mut to_cancel = Vec::new();
+        for mut order in orders {
+            let result = to_result(&order);
+            let skip_reason = if with_receipts.contains(&order.id()) {
+                Some(PurchasingError::OrderHasReceivedGoods.to_string())
+            } else {
+                order
+                    .cancel(actor_id, reason.clone())
+                    .err()
+                    .map(|e| e.to_string())
+            };
+
+            let outcome = match (&skip_reason, command.dry_run) {
+                (Some(_), _) => "skipped",
+                (None, true) => "would_cancel",
+                (None, false) => "cancelled",
+            };
+            results.push(BulkCancelOrderResult {
+                outcome: outcome.to_string(),
+                skip_reason,
+                ..result
+            });
+            if outcome == "cancelled" {
+                to_cancel.push(order);
+            }
+        }
+
+        if !command.dry_run && !to_cancel.is_empty() {
+            self.order_repo.cancel_many(&to_cancel).await?;
+        }
+
+        let count = |outcome: &str| results.iter().filter(|r| r.outcome == outcome).count();
+        Ok(BulkCancelPurchaseOrdersResponse {
+            dry_run: command.dry_run,
+            matched,
+            cancelled: count("cancelled"),
+            would_cancel: count("would_cancel"),
+            skipped: count("skipped"),
+            results,
+        })
+    }
+}
+
+/// Result for `order` as matched, before any cancellation
+fn to_result(order: &PurchaseOrder) -> BulkCancelOrderResult {
+    BulkCancelOrderResult {
+        purchase_order_id: order.id().into_uuid(),
+        order_number: order.order_number().to_string(),
+        vendor_id: order.vendor_id().into_uuid(),
+        status: order.status().to_string(),
+        updated_at: order.updated_at(),
+        outcome: String::new(),
+        skip_reason: None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Mutex;
+
+    use async_trait::async_trait;
+    use chrono::NaiveDate;
+
+    use crate::domain::entities::{GoodsReceipt, PurchaseOrderItem};
+    use crate::domain::repositories::{
+        GoodsReceiptFilter, PurchaseOrderFilter, ReceivedUnitCost, VendorOrderDelivery,
+    };
+    use crate::domain::value_objects::{GoodsReceiptId, PurchaseOrderId, PurchaseOrderItemId};
+    use inventory::{Currency, ProductId, VariantId};
+
+    struct MockOrderRepository {
+        orders: Vec<PurchaseOrder>,
+        cancelled: Mutex<Vec<PurchaseOrderId>>,
+    }
+
+    #[async_trait]
+    impl PurchaseOrderRepository for MockOrderRepository {
+        async fn save(&self, _order: &PurchaseOrder) -> Result<(), PurchasingError> {
+            unimplemented!()
+        }
+        async fn save_many(&self, _orders: &[PurchaseOrder]) -> Result<(), PurchasingError> {
+            unimplemented!()
+        }
+        async fn find_by_id(
+            &self,
+            _id: PurchaseOrderId,
+        ) -> Result<Option<PurchaseOrder>, PurchasingError> {
+            unimplemented!()
+        }
+        async fn find_by_id_with_items(
+            &self,
+            _id: PurchaseOrderId,
+        ) -> Result<Option<PurchaseOrder>, PurchasingError> {
+            unimplemented!()
+        }
+        async fn find_by_order_number(
+            &self,
+            _store_id: StoreId,
+            _order_number: &str,
+        ) -> Result<Option<PurchaseOrder>, PurchasingError> {
+            unimplemented!()
+        }
+        async fn update(&self, _order: &PurchaseOrder) -> Result<(), PurchasingError> {
+            unimplemented!()
+        }
+        async fn find_paginated(
+            &self,
+            _filter: PurchaseOrderFilter,
+            _page: i64,
+            _page_size: i64,
+        ) -> Result<(Vec<PurchaseOrder>, i64), PurchasingError> {
+            unimplemented!()
+        }
+        async fn find_stale(
+            &self,
+            filter: &StalePurchaseOrderFilter,
+        ) -> Result<Vec<PurchaseOrder>, PurchasingError> {
+            Ok(self
+                .orders
+                .iter()
+                .filter(|o| o.store_id() == filter.store_id)
+                .filter(|o| filter.statuses.contains(&o.status()))
+                .filter(|o| o.updated_at() < filter.updated_before)
+                .filter(|o| filter.vendor_id.is_none_or(|id| o.vendor_id() == id))
+                .cloned()
+                .collect())
+        }
+        async fn cancel_many(&self, orders: &[PurchaseOrder]) -> Result<(), PurchasingError> {
+            self.cancelled
+                .lock()
+                .unwrap()
+                .extend(orders.iter().map(|o| o.id()));
+            Ok(())
+        }
+        async fn find_vendor_deliveries(
+            &self,
+            _store_id: Option<StoreId>,
+            _from: NaiveDate,
+            _to: NaiveDate,
+        ) -> Result<Vec<VendorOrderDelivery>, PurchasingError> {
+            unimplemented!()
+        }
+        async fn generate_order_number(
+            &self,
+            _store_id: StoreId,
+        ) -> Result<String, PurchasingError> {
+            unimplemented!()
+        }
+        async fn save_item(&self, _item: &PurchaseOrderItem) -> Result<(), PurchasingError> {
+            unimplemented!()
+        }
+        async fn update_item(&self, _item: &PurchaseOrderItem) -> Result<(), PurchasingError> {
+            unimplemented!()
+        }
+        async fn delete_item(&self, _item_id: PurchaseOrderItemId) -> Result<(), PurchasingError> {
+            unimplemented!()
+        }
+        async fn find_items_by_order(
+            &self,
+            _order_id: PurchaseOrderId,
+        ) -> Result<Vec<PurchaseOrderItem>, PurchasingError> {
+            unimplemented!()
+        }
+        async fn find_item_by_id(
+            &self,
+            _item_id: PurchaseOrderItemId,
+        ) -> Result<Option<PurchaseOrderItem>, PurchasingError> {
+            unimplemented!()
+        }
+    }
+
+    /// Goods receipts exist only against `received`
+    struct MockReceiptRepository {
+        received: Vec<PurchaseOrderId>,
+    }
+
+    #[async_trait]
+    impl GoodsReceiptRepository for MockReceiptRepository {
+        async fn save(&self, _receipt: &GoodsReceipt) -> Result<(), PurchasingError> {
+            unimplemented!()
+        }
+        async fn find_by_id(
+            &self,
+            _id: GoodsReceiptId,
+        ) -> Result<Option<GoodsReceipt>, PurchasingError> {
+            unimplemented!()
+        }
+        async fn find_by_id_with_items(
+            &self,
+            _id: GoodsReceiptId,
+        ) -> Result<Option<GoodsReceipt>, PurchasingError> {
+            unimplemented!()
+        }
+        async fn update(&self, _receipt: &GoodsReceipt) -> Result<(), PurchasingError> {
+            unimplemented!()
+        }
+        async fn find_by_purchase_order(
+            &self,
+            _order_id: PurchaseOrderId,
+        ) -> Result<Vec<GoodsReceipt>, PurchasingError> {
+            unimplemented!()
+        }
+        async fn find_orders_with_receipts(
+            &self,
+            order_ids: &[PurchaseOrderId],
+        ) -> Result<Vec<PurchaseOrderId>, PurchasingError> {
+            Ok(order_ids
+                .iter()
+                .filter(|id| self.received.contains(id))
+                .copied()
+                .collect())
+        }
+        async fn find_paginated(
+            &self,
+            _filter: GoodsReceiptFilter,
+            _page: i64,
+            _page_size: i64,
+        ) -> Result<(Vec<GoodsReceipt>, i64), PurchasingError> {
+            unimplemented!()
+        }
+        async fn find_recent_unit_costs(
+            &self,
+            _product_id: ProductId,
+            _variant_id: Option<VariantId>,
+            _store_id: StoreId,
+            _limit: i64,
+        ) -> Result<Vec<ReceivedUnitCost>, PurchasingError> {
+            unimplemented!()
+        }
+        async fn generate_receipt_number(
+            &self,
+            _store_id: StoreId,
+        ) -> Result<String, PurchasingError> {
+            unimplemented!()
+        }
+    }
+
+    type UseCase = BulkCancelPurchaseOrdersUseCase<MockOrderRepository, MockReceiptRepository>;
+
+    fn order(store_id: StoreId, vendor_id: VendorId) -> PurchaseOrder {
+        PurchaseOrder::create(
+            "PO-2024-00001".to_string(),
+            store_id,
+            vendor_id,
+            NaiveDate::from_ymd_opt(2024, 1, 20).unwrap(),
+            Currency::new("HNL").unwrap(),
+            30,
+            UserId::new(),
+        )
+    }
+
+    fn setup(
+        orders: Vec<PurchaseOrder>,
+        received: Vec<PurchaseOrderId>,
+    ) -> (UseCase, Arc<MockOrderRepository>) {
+        let order_repo = Arc::new(MockOrderRepository {
+            orders,
+            cancelled: Mutex::new(Vec::new()),
+        });
+        let receipt_repo = Arc::new(MockReceiptRepository { received });
+        (
+            BulkCancelPurchaseOrdersUseCase::new(order_repo.clone(), receipt_repo),
+            order_repo,
+        )
+    }
+
+    /// Cancels every draft or submitted order of `store_id`, however recent
+    fn command(store_id: StoreId) -> BulkCancelPurchaseOrdersCommand {
+        BulkCancelPurchaseOrdersCommand {
+            store_id: store_id.into_uuid(),
+            status: None,
+            older_than_days: 0,
+            vendor_id: None,
+            reason: "Stale order".to_string(),
+            dry_run: false,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_cancels_matching_orders() {
+        let store_id = StoreId::new();
+        let orders = vec![
+            order(store_id, VendorId::new()),
+            order(store_id, VendorId::new()),
+        ];
+        let (use_case, repo) = setup(orders, vec![]);
+
+        let response = use_case
+            .execute(command(store_id), UserId::new())
+            .await
+            .unwrap();
+
+        assert_eq!(response.matched, 2);
+        assert_eq!(response.cancelled, 2);
+        assert_eq!(response.would_cancel, 0);
+        assert_eq!(repo.cancelled.lock().unwrap().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_dry_run_cancels_nothing() {
+        let store_id = StoreId::new();
+        let orders = vec![
+            order(store_id, VendorId::new()),
+            order(store_id, VendorId::new()),
+        ];
+        let (use_case, repo) = setup(orders, vec![]);
+
+        let response = use_case
+            .execute(
+                BulkCancelPurchaseOrdersCommand {
+                    dry_run: true,
+                    ..command(store_id)
+                },
+                UserId::new(),
+            )
+            .await
+            .unwrap();
+
+        assert!(response.dry_run);
+        assert_eq!(response.matched, 2);
+        assert_eq!(response.cancelled, 0);
+        assert_eq!(response.would_cancel, 2);
+        assert!(response.results.iter().all(|r| r.outcome == "would_cancel"));
+        assert!(repo.cancelled.lock().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_skips_orders_with_receipts() {
+        let store_id = StoreId::new();
+        let received = order(store_id, VendorId::new());
+        let received_id = received.id();
+        let orders = vec![received, order(store_id, VendorId::new())];
+        let (use_case, repo) = setup(orders, vec![received_id]);
+
+        let response = use_case
+            .execute(command(store_id), UserId::new())
+            .await
+            .unwrap();
+
+        assert_eq!(response.cancelled, 1);
+        assert_eq!(response.skipped, 1);
+        let skipped = response
+            .results
+            .iter()
+            .find(|r| r.purchase_order_id == received_id.into_uuid())
+            .unwrap();
+        assert_eq!(skipped.outcome, "skipped");
+        assert!(skipped.skip_reason.is_some());
+        assert!(!repo.cancelled.lock().unwrap().contains(&received_id));
+    }
+
+    #[tokio::test]
+    async fn test_vendor_filter_limits_cancellation() {
+        let store_id = StoreId::new();
+        let vendor_id = VendorId::new();
+        let orders = vec![order(store_id, vendor_id), order(store_id, VendorId::new())];
+        let (use_case, _) = setup(orders, vec![]);
+
+        let response = use_case
+            .execute(
+                BulkCancelPurchaseOrdersCommand {
+                    vendor_id: Some(vendor_id.into_uuid()),
+                    ..command(store_id)
+                },
+                UserId::new(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.matched, 1);
+        assert_eq!(response.results[0].vendor_id, vendor_id.into_uuid());
+    }
+
+    #[tokio::test]
+    async fn test_age_filter_excludes_recent_orders() {
+        let store_id = StoreId::new();
+        let (use_case, repo) = setup(vec![order(store_id, VendorId::new())], vec![]);
+
+        let response = use_case
+            .execute(
+                BulkCancelPurchaseOrdersCommand {
+                    older_than_days: 30,
+                    ..command(store_id)
+                },
+                UserId::new(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.matched, 0);
+        assert_eq!(response.cancelled, 0);
+        assert!(repo.cancelled.lock().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_rejects_empty_reason() {
+        let store_id = StoreId::new();
+        let (use_case, repo) = setup(vec![order(store_id, VendorId::new())], vec![]);
+
+        let result = use_case
+            .execute(
+                BulkCancelPurchaseOrdersCommand {
+                    reason: "   ".to_string(),
+                    ..command(store_id)
+                },
+                UserId::new(),
+            )
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(PurchasingError::CancellationReasonRequired)
+        ));
+        assert!(repo.cancelled.lock().unwrap().is_empty());
+    }
+}
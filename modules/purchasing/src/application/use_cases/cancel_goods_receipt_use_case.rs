@@ -70,6 +70,9 @@ where
                 lot_number: item.lot_number().map(|s| s.to_string()),
                 expiry_date: item.expiry_date(),
                 notes: item.notes().map(|s| s.to_string()),
+                serial_numbers: item.serial_numbers().to_vec(),
+                quantity_variance: item.quantity_variance(),
+                cost_variance: item.cost_variance(),
             })
             .collect();
 
@@ -81,9 +84,13 @@ where
             receipt_date: receipt.receipt_date(),
             status: receipt.status().to_string(),
             notes: receipt.notes().map(|s| s.to_string()),
+            split_backorder: receipt.split_backorder(),
+            backorder_id: None,
             received_by_id: receipt.received_by_id().into_uuid(),
             confirmed_by_id: receipt.confirmed_by_id().map(|id| id.into_uuid()),
             confirmed_at: receipt.confirmed_at(),
+            match_status: receipt.match_status().map(|s| s.to_string()),
+            match_overridden_by_id: receipt.match_overridden_by_id().map(|id| id.into_uuid()),
             items,
             created_at: receipt.created_at(),
             updated_at: receipt.updated_at(),
@@ -1,5 +1,6 @@
 // ClosePurchaseOrderUseCase - closes a fully received purchase order
 
+use chrono::Utc;
 use std::sync::Arc;
 use uuid::Uuid;
 
@@ -70,6 +71,8 @@ where
                 quantity_received: item.quantity_received(),
                 unit_of_measure: item.unit_of_measure().to_string(),
                 unit_cost: item.unit_cost(),
+                pack_size: item.pack_size(),
+                pack_price: item.pack_price(),
                 discount_percent: item.discount_percent(),
                 tax_percent: item.tax_percent(),
                 line_total: item.line_total(),
@@ -80,6 +83,7 @@ where
         PurchaseOrderDetailResponse {
             id: order.id().into_uuid(),
             order_number: order.order_number().to_string(),
+            parent_order_id: order.parent_order_id().map(|id| id.into_uuid()),
             store_id: order.store_id().into_uuid(),
             vendor_id: order.vendor_id().into_uuid(),
             status: order.status().to_string(),
@@ -96,10 +100,14 @@ where
             created_by_id: order.created_by_id().into_uuid(),
             submitted_by_id: order.submitted_by_id().map(|id| id.into_uuid()),
             submitted_at: order.submitted_at(),
+            first_approved_by_id: order.first_approved_by_id().map(|id| id.into_uuid()),
+            first_approved_at: order.first_approved_at(),
             approved_by_id: order.approved_by_id().map(|id| id.into_uuid()),
             approved_at: order.approved_at(),
             received_by_id: order.received_by_id().map(|id| id.into_uuid()),
             received_date: order.received_date(),
+            first_received_date: order.first_received_date(),
+            days_late: order.days_late(Utc::now().date_naive()),
             cancelled_by_id: order.cancelled_by_id().map(|id| id.into_uuid()),
             cancelled_at: order.cancelled_at(),
             cancellation_reason: order.cancellation_reason().map(|s| s.to_string()),
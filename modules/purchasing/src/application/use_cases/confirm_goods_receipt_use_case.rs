@@ -5,46 +5,122 @@ use uuid::Uuid;
 
 use crate::PurchasingError;
 use crate::application::dtos::responses::{GoodsReceiptDetailResponse, GoodsReceiptItemResponse};
+use crate::application::utils::{
+    inventory_error, match_receipt, plan_receipt_lots, plan_receipt_serials, plan_stocking_lines,
+};
 use crate::domain::entities::GoodsReceipt;
 use crate::domain::repositories::{GoodsReceiptRepository, PurchaseOrderRepository};
-use crate::domain::value_objects::GoodsReceiptId;
+use crate::domain::value_objects::{GoodsReceiptId, ReceiptMatchPolicy};
 use identity::UserId;
+use inventory::application::helpers::ensure_period_open;
+use inventory::{
+    InventoryLotRepository, InventoryPeriodRepository, ProductRepository, ProductSerialRepository,
+};
 
 /// Use case for confirming a goods receipt
-pub struct ConfirmGoodsReceiptUseCase<G, P>
+///
+/// Lines of serial-tracked products must carry one serial per received unit;
+/// confirming registers those units as available at the receipt's store.
+/// Lines with a lot number or expiry date add their quantity to that lot,
+/// converted from the ordered unit to the product's stocking unit.
+/// Under a match policy, quantities and costs are matched against the
+/// purchase order first and the variances are recorded on the receipt.
+/// With a period lock, receipts dated in a closed inventory period of their
+/// store are rejected.
+pub struct ConfirmGoodsReceiptUseCase<G, P, R, N, L>
 where
     G: GoodsReceiptRepository,
     P: PurchaseOrderRepository,
+    R: ProductRepository,
+    N: ProductSerialRepository,
+    L: InventoryLotRepository,
 {
     receipt_repo: Arc<G>,
     order_repo: Arc<P>,
+    product_repo: Arc<R>,
+    serial_repo: Arc<N>,
+    lot_repo: Arc<L>,
+    match_policy: ReceiptMatchPolicy,
+    period_repo: Option<Arc<dyn InventoryPeriodRepository>>,
+    can_post_to_closed_period: bool,
 }
 
-impl<G, P> ConfirmGoodsReceiptUseCase<G, P>
+impl<G, P, R, N, L> ConfirmGoodsReceiptUseCase<G, P, R, N, L>
 where
     G: GoodsReceiptRepository,
     P: PurchaseOrderRepository,
+    R: ProductRepository,
+    N: ProductSerialRepository,
+    L: InventoryLotRepository,
 {
     /// Creates a new instance of ConfirmGoodsReceiptUseCase
-    pub fn new(receipt_repo: Arc<G>, order_repo: Arc<P>) -> Self {
+    pub fn new(
+        receipt_repo: Arc<G>,
+        order_repo: Arc<P>,
+        product_repo: Arc<R>,
+        serial_repo: Arc<N>,
+        lot_repo: Arc<L>,
+    ) -> Self {
         Self {
             receipt_repo,
             order_repo,
+            product_repo,
+            serial_repo,
+            lot_repo,
+            match_policy: ReceiptMatchPolicy::disabled(),
+            period_repo: None,
+            can_post_to_closed_period: false,
         }
     }
 
+    /// Matches receipts against their purchase orders before confirming
+    pub fn with_match_policy(mut self, policy: ReceiptMatchPolicy) -> Self {
+        self.match_policy = policy;
+        self
+    }
+
+    /// Rejects receipts dated in a closed period, unless `can_override` is set
+    pub fn with_period_lock(
+        mut self,
+        period_repo: Arc<dyn InventoryPeriodRepository>,
+        can_override: bool,
+    ) -> Self {
+        self.period_repo = Some(period_repo);
+        self.can_post_to_closed_period = can_override;
+        self
+    }
+
     /// Executes the use case to confirm a goods receipt
     ///
+    /// When the receipt was created with `split_backorder` and the order still
+    /// has pending quantities, those move into a new draft backorder and the
+    /// order is marked as received.
+    ///
     /// # Arguments
     /// * `receipt_id` - The ID of the goods receipt to confirm
     /// * `actor_id` - ID of the user confirming the receipt
+    /// * `can_override_match` - Whether the user may confirm a receipt that
+    ///   doesn't match its purchase order
     ///
     /// # Returns
     /// GoodsReceiptDetailResponse on success
+    ///
+    /// # Errors
+    /// * `PurchasingError::SerialNumbersRequired` - A serial-tracked line has no serials
+    /// * `PurchasingError::SerialCountMismatch` - Serials don't match the quantity received
+    /// * `PurchasingError::ProductNotSerialized` - Serials given for an untracked product
+    /// * `PurchasingError::SerialAlreadyRegistered` - A serial is already in the system
+    /// * `PurchasingError::IncompatibleUnits` - A line's ordered unit doesn't
+    ///   convert to its product's stocking unit
+    /// * `PurchasingError::ReceiptMatchFailed` - Quantities or costs are
+    ///   outside the match tolerance and can't be overridden
+    /// * `PurchasingError::PeriodClosed` - The receipt date falls in a closed
+    ///   period and the actor can't override it
     pub async fn execute(
         &self,
         receipt_id: Uuid,
         actor_id: UserId,
+        can_override_match: bool,
     ) -> Result<GoodsReceiptDetailResponse, PurchasingError> {
         let id = GoodsReceiptId::from_uuid(receipt_id);
 
@@ -55,13 +131,21 @@ where
             .await?
             .ok_or(PurchasingError::GoodsReceiptNotFound(receipt_id))?;
 
+        // Stock is received as of the receipt date
+        if let Some(period_repo) = &self.period_repo {
+            ensure_period_open(
+                period_repo.as_ref(),
+                receipt.store_id(),
+                receipt.receipt_date(),
+                self.can_post_to_closed_period,
+            )
+            .await
+            .map_err(inventory_error)?;
+        }
+
         // Confirm receipt
         receipt.confirm(actor_id)?;
 
-        // Update receipt
-        self.receipt_repo.update(&receipt).await?;
-
-        // Update purchase order received quantities
         let mut order = self
             .order_repo
             .find_by_id_with_items(receipt.purchase_order_id())
@@ -70,6 +154,37 @@ where
                 receipt.purchase_order_id().into_uuid(),
             ))?;
 
+        match_receipt(
+            &self.match_policy,
+            &mut receipt,
+            &order,
+            actor_id,
+            can_override_match,
+        )?;
+
+        // Register the received units of serial-tracked products; serials
+        // already in the system abort the confirmation
+        let serials = plan_receipt_serials(self.product_repo.as_ref(), &receipt).await?;
+        if !serials.is_empty() {
+            self.serial_repo
+                .save_batch(&serials)
+                .await
+                .map_err(inventory_error)?;
+        }
+
+        // Add the received quantities to their lots, in stocking units
+        let lines = plan_stocking_lines(self.product_repo.as_ref(), &receipt, &order).await?;
+        let lots = plan_receipt_lots(&receipt, &lines);
+        if !lots.is_empty() {
+            self.lot_repo
+                .receive_batch(&lots)
+                .await
+                .map_err(inventory_error)?;
+        }
+
+        // Update receipt
+        self.receipt_repo.update(&receipt).await?;
+
         // Update received quantities for matching order items
         for receipt_item in receipt.items() {
             for order_item in order.items_mut() {
@@ -80,23 +195,44 @@ where
             }
         }
 
-        // Update order status based on received quantities
-        if order.all_items_received() {
-            order.receive_complete(actor_id, receipt.receipt_date())?;
-        } else if order.has_received_items() {
-            order.receive_partial(actor_id)?;
+        // Split pending quantities into a backorder when requested
+        let backorder = if receipt.split_backorder() && !order.all_items_received() {
+            let order_number = self
+                .order_repo
+                .generate_order_number(order.store_id())
+                .await?;
+            order.split_backorder(order_number, actor_id, receipt.receipt_date())?
+        } else {
+            None
+        };
+
+        // Update order status based on received quantities (a split has
+        // already marked the order as received)
+        if backorder.is_none() {
+            if order.all_items_received() {
+                order.receive_complete(actor_id, receipt.receipt_date())?;
+            } else if order.has_received_items() {
+                order.receive_partial(actor_id, receipt.receipt_date())?;
+            }
         }
 
         // Update order
         self.order_repo.update(&order).await?;
+        if let Some(backorder) = &backorder {
+            self.order_repo.save(backorder).await?;
+        }
 
         // Note: In a real implementation, this would also update inventory stock
         // using an InventoryRepository or event-driven approach
 
-        Ok(self.to_detail_response(&receipt))
+        Ok(self.to_detail_response(&receipt, backorder.map(|b| b.id().into_uuid())))
     }
 
-    fn to_detail_response(&self, receipt: &GoodsReceipt) -> GoodsReceiptDetailResponse {
+    fn to_detail_response(
+        &self,
+        receipt: &GoodsReceipt,
+        backorder_id: Option<Uuid>,
+    ) -> GoodsReceiptDetailResponse {
         let items: Vec<GoodsReceiptItemResponse> = receipt
             .items()
             .iter()
@@ -111,6 +247,9 @@ where
                 lot_number: item.lot_number().map(|s| s.to_string()),
                 expiry_date: item.expiry_date(),
                 notes: item.notes().map(|s| s.to_string()),
+                serial_numbers: item.serial_numbers().to_vec(),
+                quantity_variance: item.quantity_variance(),
+                cost_variance: item.cost_variance(),
             })
             .collect();
 
@@ -122,9 +261,13 @@ where
             receipt_date: receipt.receipt_date(),
             status: receipt.status().to_string(),
             notes: receipt.notes().map(|s| s.to_string()),
+            split_backorder: receipt.split_backorder(),
+            backorder_id,
             received_by_id: receipt.received_by_id().into_uuid(),
             confirmed_by_id: receipt.confirmed_by_id().map(|id| id.into_uuid()),
             confirmed_at: receipt.confirmed_at(),
+            match_status: receipt.match_status().map(|s| s.to_string()),
+            match_overridden_by_id: receipt.match_overridden_by_id().map(|id| id.into_uuid()),
             items,
             created_at: receipt.created_at(),
             updated_at: receipt.updated_at(),
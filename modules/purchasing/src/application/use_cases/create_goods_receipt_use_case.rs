@@ -1,5 +1,6 @@
 // CreateGoodsReceiptUseCase - creates a new goods receipt for a purchase order
 
+use std::collections::HashSet;
 use std::sync::Arc;
 
 use chrono::NaiveDate;
@@ -7,6 +8,7 @@ use chrono::NaiveDate;
 use crate::PurchasingError;
 use crate::application::dtos::commands::CreateGoodsReceiptCommand;
 use crate::application::dtos::responses::{GoodsReceiptDetailResponse, GoodsReceiptItemResponse};
+use crate::application::utils::normalize_serials;
 use crate::domain::entities::{GoodsReceipt, GoodsReceiptItem};
 use crate::domain::repositories::{GoodsReceiptRepository, PurchaseOrderRepository};
 use crate::domain::value_objects::{PurchaseOrderId, PurchaseOrderItemId};
@@ -76,8 +78,10 @@ where
         if let Some(notes) = command.notes {
             receipt.set_notes(Some(notes))?;
         }
+        receipt.set_split_backorder(command.split_backorder)?;
 
         // Add items
+        let mut seen_serials = HashSet::new();
         for item_cmd in command.items {
             // Parse expiry date if provided
             let expiry_date = item_cmd
@@ -108,6 +112,15 @@ where
                 item.set_notes(Some(notes));
             }
 
+            // Serials are checked against the product's serial tracking on
+            // confirmation
+            let serial_numbers = normalize_serials(
+                item_cmd.product_id,
+                &item_cmd.serial_numbers,
+                &mut seen_serials,
+            )?;
+            item.set_serial_numbers(serial_numbers)?;
+
             receipt.add_item(item)?;
         }
 
@@ -133,6 +146,9 @@ where
                 lot_number: item.lot_number().map(|s| s.to_string()),
                 expiry_date: item.expiry_date(),
                 notes: item.notes().map(|s| s.to_string()),
+                serial_numbers: item.serial_numbers().to_vec(),
+                quantity_variance: item.quantity_variance(),
+                cost_variance: item.cost_variance(),
             })
             .collect();
 
@@ -144,9 +160,13 @@ where
             receipt_date: receipt.receipt_date(),
             status: receipt.status().to_string(),
             notes: receipt.notes().map(|s| s.to_string()),
+            split_backorder: receipt.split_backorder(),
+            backorder_id: None,
             received_by_id: receipt.received_by_id().into_uuid(),
             confirmed_by_id: receipt.confirmed_by_id().map(|id| id.into_uuid()),
             confirmed_at: receipt.confirmed_at(),
+            match_status: receipt.match_status().map(|s| s.to_string()),
+            match_overridden_by_id: receipt.match_overridden_by_id().map(|id| id.into_uuid()),
             items,
             created_at: receipt.created_at(),
             updated_at: receipt.updated_at(),
@@ -3,7 +3,7 @@
 use std::str::FromStr;
 use std::sync::Arc;
 
-use chrono::NaiveDate;
+use chrono::{NaiveDate, Utc};
 
 use crate::PurchasingError;
 use crate::application::dtos::commands::CreatePurchaseOrderCommand;
@@ -50,6 +50,52 @@ where
         command: CreatePurchaseOrderCommand,
         actor_id: UserId,
     ) -> Result<PurchaseOrderDetailResponse, PurchasingError> {
+        let order = self.build_order(command, actor_id).await?;
+
+        // Save order with items
+        self.order_repo.save(&order).await?;
+
+        // Convert to response
+        Ok(self.to_detail_response(&order))
+    }
+
+    /// Creates several purchase orders at once: every command is validated
+    /// before any order is saved, and the orders are saved in a single
+    /// transaction so either all of them are created or none is.
+    ///
+    /// # Arguments
+    /// * `commands` - One create purchase order command per order
+    /// * `actor_id` - ID of the user creating the orders
+    ///
+    /// # Returns
+    /// One PurchaseOrderDetailResponse per command, in the same order
+    pub async fn execute_many(
+        &self,
+        commands: Vec<CreatePurchaseOrderCommand>,
+        actor_id: UserId,
+    ) -> Result<Vec<PurchaseOrderDetailResponse>, PurchasingError> {
+        let mut orders = Vec::with_capacity(commands.len());
+        for command in commands {
+            orders.push(self.build_order(command, actor_id).await?);
+        }
+
+        if !orders.is_empty() {
+            self.order_repo.save_many(&orders).await?;
+        }
+
+        Ok(orders
+            .iter()
+            .map(|order| self.to_detail_response(order))
+            .collect())
+    }
+
+    /// Validates a command and builds the draft order it describes, without
+    /// saving it
+    async fn build_order(
+        &self,
+        command: CreatePurchaseOrderCommand,
+        actor_id: UserId,
+    ) -> Result<PurchaseOrder, PurchasingError> {
         // Validate vendor exists and is active
         let vendor_id = VendorId::from_uuid(command.vendor_id);
         let vendor = self
@@ -112,7 +158,7 @@ where
             let unit_of_measure = UnitOfMeasure::from_str(&item_cmd.unit_of_measure)
                 .map_err(|_| PurchasingError::InvalidUnitOfMeasure)?;
 
-            let item = PurchaseOrderItem::create(
+            let mut item = PurchaseOrderItem::create(
                 order.id(),
                 (index + 1) as i32,
                 ProductId::from_uuid(item_cmd.product_id),
@@ -124,15 +170,22 @@ where
                 item_cmd.discount_percent,
                 item_cmd.tax_percent,
             );
+            match (item_cmd.pack_size, item_cmd.pack_price) {
+                (Some(pack_size), Some(pack_price)) => {
+                    item.set_pack_pricing(pack_size, pack_price)?;
+                }
+                (None, None) => {}
+                _ => {
+                    return Err(PurchasingError::InvalidPackPricing(
+                        "pack_size and pack_price must be given together".to_string(),
+                    ));
+                }
+            }
 
             order.add_item(item)?;
         }
 
-        // Save order with items
-        self.order_repo.save(&order).await?;
-
-        // Convert to response
-        Ok(self.to_detail_response(&order))
+        Ok(order)
     }
 
     fn to_detail_response(&self, order: &PurchaseOrder) -> PurchaseOrderDetailResponse {
@@ -150,6 +203,8 @@ where
                 quantity_received: item.quantity_received(),
                 unit_of_measure: item.unit_of_measure().to_string(),
                 unit_cost: item.unit_cost(),
+                pack_size: item.pack_size(),
+                pack_price: item.pack_price(),
                 discount_percent: item.discount_percent(),
                 tax_percent: item.tax_percent(),
                 line_total: item.line_total(),
@@ -160,6 +215,7 @@ where
         PurchaseOrderDetailResponse {
             id: order.id().into_uuid(),
             order_number: order.order_number().to_string(),
+            parent_order_id: order.parent_order_id().map(|id| id.into_uuid()),
             store_id: order.store_id().into_uuid(),
             vendor_id: order.vendor_id().into_uuid(),
             status: order.status().to_string(),
@@ -176,10 +232,14 @@ where
             created_by_id: order.created_by_id().into_uuid(),
             submitted_by_id: order.submitted_by_id().map(|id| id.into_uuid()),
             submitted_at: order.submitted_at(),
+            first_approved_by_id: order.first_approved_by_id().map(|id| id.into_uuid()),
+            first_approved_at: order.first_approved_at(),
             approved_by_id: order.approved_by_id().map(|id| id.into_uuid()),
             approved_at: order.approved_at(),
             received_by_id: order.received_by_id().map(|id| id.into_uuid()),
             received_date: order.received_date(),
+            first_received_date: order.first_received_date(),
+            days_late: order.days_late(Utc::now().date_naive()),
             cancelled_by_id: order.cancelled_by_id().map(|id| id.into_uuid()),
             cancelled_at: order.cancelled_at(),
             cancellation_reason: order.cancellation_reason().map(|s| s.to_string()),
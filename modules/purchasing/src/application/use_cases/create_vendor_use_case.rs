@@ -8,6 +8,7 @@ use crate::application::dtos::responses::VendorResponse;
 use crate::application::utils::generate_vendor_code_prefix;
 use crate::domain::entities::Vendor;
 use crate::domain::repositories::VendorRepository;
+use common::TaxIdPolicy;
 use inventory::Currency;
 
 /// Use case for creating a new vendor
@@ -16,6 +17,7 @@ where
     V: VendorRepository,
 {
     vendor_repo: Arc<V>,
+    tax_id_policy: TaxIdPolicy,
 }
 
 impl<V> CreateVendorUseCase<V>
@@ -23,8 +25,11 @@ where
     V: VendorRepository,
 {
     /// Creates a new instance of CreateVendorUseCase
-    pub fn new(vendor_repo: Arc<V>) -> Self {
-        Self { vendor_repo }
+    pub fn new(vendor_repo: Arc<V>, tax_id_policy: TaxIdPolicy) -> Self {
+        Self {
+            vendor_repo,
+            tax_id_policy,
+        }
     }
 
     /// Executes the use case to create a new vendor
@@ -37,15 +42,24 @@ where
     ///
     /// # Errors
     /// * `PurchasingError::DuplicateVendorCode` - If vendor code already exists
+    /// * `PurchasingError::InvalidTaxId` - If tax ID doesn't match its country's format
     /// * `PurchasingError::DuplicateVendorTaxId` - If vendor tax ID already exists
     /// * `PurchasingError::InvalidCurrency` - If currency code is invalid
     pub async fn execute(
         &self,
         command: CreateVendorCommand,
     ) -> Result<VendorResponse, PurchasingError> {
+        // Validate tax ID against its country's format
+        let tax_id = self
+            .tax_id_policy
+            .parse(&command.tax_id, command.tax_id_country.as_deref())
+            .map_err(|e| PurchasingError::InvalidTaxId(e.to_string()))?;
+
         // Check for duplicate tax ID
-        if self.vendor_repo.exists_by_tax_id(&command.tax_id).await? {
-            return Err(PurchasingError::DuplicateVendorTaxId(command.tax_id));
+        if self.vendor_repo.exists_by_tax_id(tax_id.value()).await? {
+            return Err(PurchasingError::DuplicateVendorTaxId(
+                tax_id.value().to_string(),
+            ));
         }
 
         // Generate vendor code from legal name
@@ -63,9 +77,10 @@ where
             vendor_code,
             command.name,
             command.legal_name,
-            command.tax_id,
+            tax_id.value().to_string(),
             currency,
         );
+        vendor.set_tax_id(tax_id);
 
         // Apply optional fields
         if let Some(email) = command.email {
@@ -94,6 +109,7 @@ where
             name: vendor.name().to_string(),
             legal_name: vendor.legal_name().to_string(),
             tax_id: vendor.tax_id().to_string(),
+            tax_id_country: vendor.tax_id_country().map(|s| s.to_string()),
             email: vendor.email().map(|s| s.to_string()),
             phone: vendor.phone().map(|s| s.to_string()),
             address: vendor.address().map(|s| s.to_string()),
@@ -8,8 +8,12 @@ use crate::application::dtos::responses::{GoodsReceiptDetailResponse, GoodsRecei
 use crate::domain::entities::GoodsReceipt;
 use crate::domain::repositories::GoodsReceiptRepository;
 use crate::domain::value_objects::GoodsReceiptId;
+use identity::UserContext;
 
 /// Use case for retrieving a goods receipt by ID
+///
+/// Receipts of stores the caller is not assigned to are reported as not
+/// found rather than revealing that they exist.
 pub struct GetGoodsReceiptUseCase<G>
 where
     G: GoodsReceiptRepository,
@@ -30,12 +34,18 @@ where
     ///
     /// # Arguments
     /// * `receipt_id` - The ID of the goods receipt to retrieve
+    /// * `ctx` - The caller, who must be assigned to the receipt's store
     ///
     /// # Returns
     /// GoodsReceiptDetailResponse on success
+    ///
+    /// # Errors
+    /// * `PurchasingError::GoodsReceiptNotFound` - If the receipt doesn't
+    ///   exist or belongs to a store the caller is not assigned to
     pub async fn execute(
         &self,
         receipt_id: Uuid,
+        ctx: &UserContext,
     ) -> Result<GoodsReceiptDetailResponse, PurchasingError> {
         let id = GoodsReceiptId::from_uuid(receipt_id);
 
@@ -43,6 +53,7 @@ where
             .receipt_repo
             .find_by_id_with_items(id)
             .await?
+            .filter(|r| ctx.can_access_store(*r.store_id().as_uuid()))
             .ok_or(PurchasingError::GoodsReceiptNotFound(receipt_id))?;
 
         Ok(self.to_detail_response(&receipt))
@@ -63,6 +74,9 @@ where
                 lot_number: item.lot_number().map(|s| s.to_string()),
                 expiry_date: item.expiry_date(),
                 notes: item.notes().map(|s| s.to_string()),
+                serial_numbers: item.serial_numbers().to_vec(),
+                quantity_variance: item.quantity_variance(),
+                cost_variance: item.cost_variance(),
             })
             .collect();
 
@@ -74,9 +88,13 @@ where
             receipt_date: receipt.receipt_date(),
             status: receipt.status().to_string(),
             notes: receipt.notes().map(|s| s.to_string()),
+            split_backorder: receipt.split_backorder(),
+            backorder_id: None,
             received_by_id: receipt.received_by_id().into_uuid(),
             confirmed_by_id: receipt.confirmed_by_id().map(|id| id.into_uuid()),
             confirmed_at: receipt.confirmed_at(),
+            match_status: receipt.match_status().map(|s| s.to_string()),
+            match_overridden_by_id: receipt.match_overridden_by_id().map(|id| id.into_uuid()),
             items,
             created_at: receipt.created_at(),
             updated_at: receipt.updated_at(),
@@ -1,5 +1,6 @@
 // GetPurchaseOrderUseCase - retrieves a purchase order by ID
 
+use chrono::Utc;
 use std::sync::Arc;
 use uuid::Uuid;
 
@@ -8,8 +9,12 @@ use crate::application::dtos::responses::{PurchaseOrderDetailResponse, PurchaseO
 use crate::domain::entities::PurchaseOrder;
 use crate::domain::repositories::PurchaseOrderRepository;
 use crate::domain::value_objects::PurchaseOrderId;
+use identity::UserContext;
 
 /// Use case for retrieving a purchase order by ID
+///
+/// Orders of stores the caller is not assigned to are reported as not found
+/// rather than revealing that they exist.
 pub struct GetPurchaseOrderUseCase<P>
 where
     P: PurchaseOrderRepository,
@@ -30,12 +35,18 @@ where
     ///
     /// # Arguments
     /// * `order_id` - The ID of the purchase order to retrieve
+    /// * `ctx` - The caller, who must be assigned to the order's store
     ///
     /// # Returns
     /// PurchaseOrderDetailResponse on success
+    ///
+    /// # Errors
+    /// * `PurchasingError::PurchaseOrderNotFound` - If the order doesn't exist
+    ///   or belongs to a store the caller is not assigned to
     pub async fn execute(
         &self,
         order_id: Uuid,
+        ctx: &UserContext,
     ) -> Result<PurchaseOrderDetailResponse, PurchasingError> {
         let id = PurchaseOrderId::from_uuid(order_id);
 
@@ -43,6 +54,7 @@ where
             .order_repo
             .find_by_id_with_items(id)
             .await?
+            .filter(|o| ctx.can_access_store(*o.store_id().as_uuid()))
             .ok_or(PurchasingError::PurchaseOrderNotFound(order_id))?;
 
         Ok(self.to_detail_response(&order))
@@ -63,6 +75,8 @@ where
                 quantity_received: item.quantity_received(),
                 unit_of_measure: item.unit_of_measure().to_string(),
                 unit_cost: item.unit_cost(),
+                pack_size: item.pack_size(),
+                pack_price: item.pack_price(),
                 discount_percent: item.discount_percent(),
                 tax_percent: item.tax_percent(),
                 line_total: item.line_total(),
@@ -73,6 +87,7 @@ where
         PurchaseOrderDetailResponse {
             id: order.id().into_uuid(),
             order_number: order.order_number().to_string(),
+            parent_order_id: order.parent_order_id().map(|id| id.into_uuid()),
             store_id: order.store_id().into_uuid(),
             vendor_id: order.vendor_id().into_uuid(),
             status: order.status().to_string(),
@@ -89,10 +104,14 @@ where
             created_by_id: order.created_by_id().into_uuid(),
             submitted_by_id: order.submitted_by_id().map(|id| id.into_uuid()),
             submitted_at: order.submitted_at(),
+            first_approved_by_id: order.first_approved_by_id().map(|id| id.into_uuid()),
+            first_approved_at: order.first_approved_at(),
             approved_by_id: order.approved_by_id().map(|id| id.into_uuid()),
             approved_at: order.approved_at(),
             received_by_id: order.received_by_id().map(|id| id.into_uuid()),
             received_date: order.received_date(),
+            first_received_date: order.first_received_date(),
+            days_late: order.days_late(Utc::now().date_naive()),
             cancelled_by_id: order.cancelled_by_id().map(|id| id.into_uuid()),
             cancelled_at: order.cancelled_at(),
             cancellation_reason: order.cancellation_reason().map(|s| s.to_string()),
@@ -102,3 +121,177 @@ where
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use async_trait::async_trait;
+    use chrono::NaiveDate;
+
+    use crate::domain::entities::PurchaseOrderItem;
+    use crate::domain::repositories::{
+        PurchaseOrderFilter, StalePurchaseOrderFilter, VendorOrderDelivery,
+    };
+    use crate::domain::value_objects::{PurchaseOrderItemId, VendorId};
+    use identity::{StoreId, UserId};
+    use inventory::Currency;
+
+    struct MockOrderRepository {
+        order: PurchaseOrder,
+    }
+
+    #[async_trait]
+    impl PurchaseOrderRepository for MockOrderRepository {
+        async fn save(&self, _order: &PurchaseOrder) -> Result<(), PurchasingError> {
+            unimplemented!()
+        }
+        async fn save_many(&self, _orders: &[PurchaseOrder]) -> Result<(), PurchasingError> {
+            unimplemented!()
+        }
+        async fn find_by_id(
+            &self,
+            _id: PurchaseOrderId,
+        ) -> Result<Option<PurchaseOrder>, PurchasingError> {
+            unimplemented!()
+        }
+        async fn find_by_id_with_items(
+            &self,
+            id: PurchaseOrderId,
+        ) -> Result<Option<PurchaseOrder>, PurchasingError> {
+            Ok(Some(self.order.clone()).filter(|o| o.id() == id))
+        }
+        async fn find_by_order_number(
+            &self,
+            _store_id: StoreId,
+            _order_number: &str,
+        ) -> Result<Option<PurchaseOrder>, PurchasingError> {
+            unimplemented!()
+        }
+        async fn update(&self, _order: &PurchaseOrder) -> Result<(), PurchasingError> {
+            unimplemented!()
+        }
+        async fn find_paginated(
+            &self,
+            _filter: PurchaseOrderFilter,
+            _page: i64,
+            _page_size: i64,
+        ) -> Result<(Vec<PurchaseOrder>, i64), PurchasingError> {
+            unimplemented!()
+        }
+        async fn find_stale(
+            &self,
+            _filter: &StalePurchaseOrderFilter,
+        ) -> Result<Vec<PurchaseOrder>, PurchasingError> {
+            unimplemented!()
+        }
+        async fn cancel_many(&self, _orders: &[PurchaseOrder]) -> Result<(), PurchasingError> {
+            unimplemented!()
+        }
+        async fn find_vendor_deliveries(
+            &self,
+            _store_id: Option<StoreId>,
+            _from: NaiveDate,
+            _to: NaiveDate,
+        ) -> Result<Vec<VendorOrderDelivery>, PurchasingError> {
+            unimplemented!()
+        }
+        async fn generate_order_number(
+            &self,
+            _store_id: StoreId,
+        ) -> Result<String, PurchasingError> {
+            unimplemented!()
+        }
+        async fn save_item(&self, _item: &PurchaseOrderItem) -> Result<(), PurchasingError> {
+            unimplemented!()
+        }
+        async fn update_item(&self, _item: &PurchaseOrderItem) -> Result<(), PurchasingError> {
+            unimplemented!()
+        }
+        async fn delete_item(&self, _item_id: PurchaseOrderItemId) -> Result<(), PurchasingError> {
+            unimplemented!()
+        }
+        async fn find_items_by_order(
+            &self,
+            _order_id: PurchaseOrderId,
+        ) -> Result<Vec<PurchaseOrderItem>, PurchasingError> {
+            unimplemented!()
+        }
+        async fn find_item_by_id(
+            &self,
+            _item_id: PurchaseOrderItemId,
+        ) -> Result<Option<PurchaseOrderItem>, PurchasingError> {
+            unimplemented!()
+        }
+    }
+
+    /// A use case holding one order, with the order's id and store
+    fn setup() -> (GetPurchaseOrderUseCase<MockOrderRepository>, Uuid, Uuid) {
+        let order = PurchaseOrder::create(
+            "PO-2024-00001".to_string(),
+            StoreId::new(),
+            VendorId::new(),
+            NaiveDate::from_ymd_opt(2024, 1, 20).unwrap(),
+            Currency::new("HNL").unwrap(),
+            30,
+            UserId::new(),
+        );
+        let (id, store_id) = (order.id().into_uuid(), order.store_id().into_uuid());
+        let use_case = GetPurchaseOrderUseCase::new(Arc::new(MockOrderRepository { order }));
+        (use_case, id, store_id)
+    }
+
+    fn context(stores: Vec<Uuid>, is_super_admin: bool) -> UserContext {
+        UserContext::new(
+            UserId::new(),
+            StoreId::new(),
+            Default::default(),
+            stores,
+            is_super_admin,
+            None,
+            None,
+        )
+    }
+
+    #[tokio::test]
+    async fn test_assigned_user_gets_order() {
+        let (use_case, id, store_id) = setup();
+
+        let response = use_case
+            .execute(id, &context(vec![store_id], false))
+            .await
+            .unwrap();
+        assert_eq!(response.id, id);
+    }
+
+    #[tokio::test]
+    async fn test_order_of_another_store_is_not_found() {
+        let (use_case, id, _) = setup();
+
+        let result = use_case
+            .execute(id, &context(vec![StoreId::new().into_uuid()], false))
+            .await;
+        assert!(matches!(
+            result,
+            Err(PurchasingError::PurchaseOrderNotFound(not_found)) if not_found == id
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_user_without_stores_gets_not_found() {
+        let (use_case, id, _) = setup();
+
+        let result = use_case.execute(id, &context(vec![], false)).await;
+        assert!(matches!(
+            result,
+            Err(PurchasingError::PurchaseOrderNotFound(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_super_admin_gets_order_of_any_store() {
+        let (use_case, id, _) = setup();
+
+        let response = use_case.execute(id, &context(vec![], true)).await.unwrap();
+        assert_eq!(response.id, id);
+    }
+}
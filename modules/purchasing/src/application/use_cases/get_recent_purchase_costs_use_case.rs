@@ -0,0 +1,228 @@
+// GetRecentPurchaseCostsUseCase - last N prices paid for a product, so buyers
+// can sanity-check a quoted cost when adding a purchase order line
+
+use std::sync::Arc;
+
+use rust_decimal::Decimal;
+use uuid::Uuid;
+
+use crate::PurchasingError;
+use crate::application::dtos::responses::{RecentPurchaseCostsResponse, RecentUnitCostResponse};
+use crate::domain::repositories::{GoodsReceiptRepository, ReceivedUnitCost};
+use identity::StoreId;
+use inventory::{ProductId, VariantId};
+
+/// Number of receipts returned when the query does not set a limit
+pub const DEFAULT_RECENT_COSTS_LIMIT: i64 = 5;
+
+/// Query parameters for the recent purchase costs lookup
+#[derive(Debug, Clone)]
+pub struct RecentPurchaseCostsQuery {
+    pub product_id: Uuid,
+    /// Restrict to one variant; otherwise all receipts of the product count
+    pub variant_id: Option<Uuid>,
+    /// Store the purchase order is for; receipts from every store of its
+    /// organization are considered
+    pub store_id: Uuid,
+    /// Number of receipts to return (default 5, max 50)
+    pub limit: Option<i64>,
+    /// Quoted unit cost to compare against the weighted average
+    pub quoted_unit_cost: Option<Decimal>,
+}
+
+/// Use case for looking up the last N goods-receipt unit costs of a product
+pub struct GetRecentPurchaseCostsUseCase<G>
+where
+    G: GoodsReceiptRepository,
+{
+    receipt_repo: Arc<G>,
+}
+
+impl<G> GetRecentPurchaseCostsUseCase<G>
+where
+    G: GoodsReceiptRepository,
+{
+    /// Creates a new instance of GetRecentPurchaseCostsUseCase
+    pub fn new(receipt_repo: Arc<G>) -> Self {
+        Self { receipt_repo }
+    }
+
+    /// Executes the use case
+    ///
+    /// # Arguments
+    /// * `query` - Product, optional variant, store and window size
+    ///
+    /// # Returns
+    /// Recent receipt costs, newest first, with weighted average and min/max
+    ///
+    /// # Errors
+    /// * `PurchasingError::InvalidUnitCost` - If the quoted unit cost is negative
+    pub async fn execute(
+        &self,
+        query: RecentPurchaseCostsQuery,
+    ) -> Result<RecentPurchaseCostsResponse, PurchasingError> {
+        if query.quoted_unit_cost.is_some_and(|c| c < Decimal::ZERO) {
+            return Err(PurchasingError::InvalidUnitCost);
+        }
+
+        let limit = query
+            .limit
+            .unwrap_or(DEFAULT_RECENT_COSTS_LIMIT)
+            .clamp(1, 50);
+
+        let entries = self
+            .receipt_repo
+            .find_recent_unit_costs(
+                ProductId::from_uuid(query.product_id),
+                query.variant_id.map(VariantId::from_uuid),
+                StoreId::from_uuid(query.store_id),
+                limit,
+            )
+            .await?;
+
+        Ok(summarize(&query, limit, entries))
+    }
+}
+
+/// Builds the response; statistics only cover receipts in the currency of the
+/// most recent one.
+fn summarize(
+    query: &RecentPurchaseCostsQuery,
+    limit: i64,
+    entries: Vec<ReceivedUnitCost>,
+) -> RecentPurchaseCostsResponse {
+    let currency = entries.first().map(|e| e.currency.clone());
+    let comparable: Vec<&ReceivedUnitCost> = entries
+        .iter()
+        .filter(|e| Some(&e.currency) == currency.as_ref())
+        .collect();
+
+    let total_quantity: Decimal = comparable.iter().map(|e| e.quantity_received).sum();
+    let total_cost: Decimal = comparable
+        .iter()
+        .map(|e| e.quantity_received * e.unit_cost)
+        .sum();
+    let weighted_average_cost =
+        (total_quantity > Decimal::ZERO).then(|| (total_cost / total_quantity).round_dp(4));
+
+    let quoted_variance_percent = match (query.quoted_unit_cost, weighted_average_cost) {
+        (Some(quoted), Some(avg)) if avg > Decimal::ZERO => {
+            Some(((quoted - avg) / avg * Decimal::ONE_HUNDRED).round_dp(2))
+        }
+        _ => None,
+    };
+
+    RecentPurchaseCostsResponse {
+        product_id: query.product_id,
+        variant_id: query.variant_id,
+        limit,
+        currency,
+        total_quantity,
+        weighted_average_cost,
+        min_unit_cost: comparable.iter().map(|e| e.unit_cost).min(),
+        max_unit_cost: comparable.iter().map(|e| e.unit_cost).max(),
+        last_unit_cost: comparable.first().map(|e| e.unit_cost),
+        quoted_unit_cost: query.quoted_unit_cost,
+        quoted_variance_percent,
+        receipts: entries
+            .into_iter()
+            .map(|e| RecentUnitCostResponse {
+                goods_receipt_id: e.goods_receipt_id.into_uuid(),
+                receipt_number: e.receipt_number,
+                receipt_date: e.receipt_date,
+                confirmed_at: e.confirmed_at,
+                store_id: e.store_id.into_uuid(),
+                purchase_order_id: e.purchase_order_id.into_uuid(),
+                order_number: e.order_number,
+                vendor_id: e.vendor_id.into_uuid(),
+                vendor_name: e.vendor_name,
+                currency: e.currency,
+                quantity_received: e.quantity_received,
+                unit_cost: e.unit_cost,
+            })
+            .collect(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::NaiveDate;
+    use rust_decimal_macros::dec;
+
+    use crate::domain::value_objects::{GoodsReceiptId, PurchaseOrderId, VendorId};
+
+    fn entry(currency: &str, quantity: Decimal, unit_cost: Decimal) -> ReceivedUnitCost {
+        ReceivedUnitCost {
+            goods_receipt_id: GoodsReceiptId::new(),
+            receipt_number: "GR-2026-00001".to_string(),
+            receipt_date: NaiveDate::from_ymd_opt(2026, 10, 1).unwrap(),
+            confirmed_at: None,
+            store_id: StoreId::new(),
+            purchase_order_id: PurchaseOrderId::new(),
+            order_number: "PO-2026-00001".to_string(),
+            vendor_id: VendorId::new(),
+            vendor_name: "Acme".to_string(),
+            currency: currency.to_string(),
+            quantity_received: quantity,
+            unit_cost,
+        }
+    }
+
+    fn query(quoted_unit_cost: Option<Decimal>) -> RecentPurchaseCostsQuery {
+        RecentPurchaseCostsQuery {
+            product_id: Uuid::nil(),
+            variant_id: None,
+            store_id: Uuid::nil(),
+            limit: None,
+            quoted_unit_cost,
+        }
+    }
+
+    #[test]
+    fn test_summarize_weighted_average_and_range() {
+        let entries = vec![
+            entry("HNL", dec!(10), dec!(12)),
+            entry("HNL", dec!(30), dec!(8)),
+        ];
+
+        let response = summarize(&query(Some(dec!(13.5))), 5, entries);
+
+        assert_eq!(response.currency.as_deref(), Some("HNL"));
+        assert_eq!(response.total_quantity, dec!(40));
+        // (10 * 12 + 30 * 8) / 40 = 9
+        assert_eq!(response.weighted_average_cost, Some(dec!(9)));
+        assert_eq!(response.min_unit_cost, Some(dec!(8)));
+        assert_eq!(response.max_unit_cost, Some(dec!(12)));
+        assert_eq!(response.last_unit_cost, Some(dec!(12)));
+        assert_eq!(response.quoted_variance_percent, Some(dec!(50)));
+        assert_eq!(response.receipts.len(), 2);
+    }
+
+    #[test]
+    fn test_summarize_ignores_other_currencies_in_stats() {
+        let entries = vec![
+            entry("USD", dec!(5), dec!(2)),
+            entry("HNL", dec!(5), dec!(50)),
+            entry("USD", dec!(5), dec!(4)),
+        ];
+
+        let response = summarize(&query(None), 5, entries);
+
+        assert_eq!(response.currency.as_deref(), Some("USD"));
+        assert_eq!(response.weighted_average_cost, Some(dec!(3)));
+        assert_eq!(response.max_unit_cost, Some(dec!(4)));
+        assert_eq!(response.receipts.len(), 3);
+    }
+
+    #[test]
+    fn test_summarize_no_history() {
+        let response = summarize(&query(Some(dec!(10))), 5, vec![]);
+
+        assert!(response.currency.is_none());
+        assert!(response.weighted_average_cost.is_none());
+        assert!(response.min_unit_cost.is_none());
+        assert!(response.quoted_variance_percent.is_none());
+        assert_eq!(response.total_quantity, Decimal::ZERO);
+    }
+}
@@ -0,0 +1,235 @@
+// GetVendorPerformanceUseCase - on-time delivery, fill rate and lead time per
+// vendor, so purchasing can compare vendors
+
+use std::cmp::Ordering;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use chrono::NaiveDate;
+use rust_decimal::Decimal;
+use uuid::Uuid;
+
+use crate::PurchasingError;
+use crate::application::dtos::responses::{VendorPerformanceEntry, VendorPerformanceResponse};
+use crate::domain::repositories::{PurchaseOrderRepository, VendorOrderDelivery};
+use identity::StoreId;
+
+/// Query parameters for the vendor performance report
+#[derive(Debug, Clone)]
+pub struct VendorPerformanceQuery {
+    /// Restrict to orders of one store; otherwise every store counts
+    pub store_id: Option<Uuid>,
+    /// First order date included
+    pub from_date: NaiveDate,
+    /// Last order date included
+    pub to_date: NaiveDate,
+}
+
+/// Use case for ranking vendors by their delivery history
+///
+/// Covers the approved orders placed in the date range. An order is on time
+/// when its last confirmed receipt is on or before its expected delivery
+/// date; orders without one don't count towards the on-time rate.
+pub struct GetVendorPerformanceUseCase<P>
+where
+    P: PurchaseOrderRepository,
+{
+    order_repo: Arc<P>,
+}
+
+impl<P> GetVendorPerformanceUseCase<P>
+where
+    P: PurchaseOrderRepository,
+{
+    /// Creates a new instance of GetVendorPerformanceUseCase
+    pub fn new(order_repo: Arc<P>) -> Self {
+        Self { order_repo }
+    }
+
+    /// Executes the use case
+    ///
+    /// # Errors
+    /// * `PurchasingError::InvalidDateRange` - If `from_date` is after `to_date`
+    pub async fn execute(
+        &self,
+        query: VendorPerformanceQuery,
+    ) -> Result<VendorPerformanceResponse, PurchasingError> {
+        if query.from_date > query.to_date {
+            return Err(PurchasingError::InvalidDateRange);
+        }
+
+        let deliveries = self
+            .order_repo
+            .find_vendor_deliveries(
+                query.store_id.map(StoreId::from_uuid),
+                query.from_date,
+                query.to_date,
+            )
+            .await?;
+
+        Ok(VendorPerformanceResponse {
+            store_id: query.store_id,
+            from_date: query.from_date,
+            to_date: query.to_date,
+            vendors: rank_vendors(deliveries),
+        })
+    }
+}
+
+/// Aggregates the deliveries per vendor and ranks the vendors
+fn rank_vendors(deliveries: Vec<VendorOrderDelivery>) -> Vec<VendorPerformanceEntry> {
+    let mut by_vendor: HashMap<Uuid, Vec<VendorOrderDelivery>> = HashMap::new();
+    for delivery in deliveries {
+        by_vendor
+            .entry(delivery.vendor_id.into_uuid())
+            .or_default()
+            .push(delivery);
+    }
+
+    let mut entries: Vec<VendorPerformanceEntry> = by_vendor
+        .into_iter()
+        .map(|(vendor_id, orders)| summarize(vendor_id, &orders))
+        .collect();
+
+    // Unknown rates rank below known ones
+    let desc = |a: Option<Decimal>, b: Option<Decimal>| b.cmp(&a);
+    entries.sort_by(|a, b| {
+        desc(a.on_time_rate, b.on_time_rate)
+            .then_with(|| desc(a.fill_rate, b.fill_rate))
+            .then_with(
+                || match (a.average_lead_time_days, b.average_lead_time_days) {
+                    (Some(a), Some(b)) => a.cmp(&b),
+                    (Some(_), None) => Ordering::Less,
+                    (None, Some(_)) => Ordering::Greater,
+                    (None, None) => Ordering::Equal,
+                },
+            )
+            .then_with(|| a.vendor_name.cmp(&b.vendor_name))
+    });
+    for (index, entry) in entries.iter_mut().enumerate() {
+        entry.rank = index + 1;
+    }
+    entries
+}
+
+fn summarize(vendor_id: Uuid, orders: &[VendorOrderDelivery]) -> VendorPerformanceEntry {
+    let delivered: Vec<&VendorOrderDelivery> = orders
+        .iter()
+        .filter(|o| o.first_receipt_date.is_some())
+        .collect();
+
+    let scheduled: Vec<bool> = delivered
+        .iter()
+        .filter_map(|o| Some(o.last_receipt_date? <= o.expected_delivery_date?))
+        .collect();
+    let on_time_rate = (!scheduled.is_empty()).then(|| {
+        percent(
+            Decimal::from(scheduled.iter().filter(|on_time| **on_time).count()),
+            Decimal::from(scheduled.len()),
+        )
+    });
+
+    let ordered: Decimal = orders.iter().map(|o| o.quantity_ordered).sum();
+    let received: Decimal = orders.iter().map(|o| o.quantity_received).sum();
+    let fill_rate = (ordered > Decimal::ZERO).then(|| percent(received, ordered));
+
+    let lead_days: Vec<i64> = delivered
+        .iter()
+        .filter_map(|o| Some((o.first_receipt_date? - o.order_date).num_days()))
+        .collect();
+    let average_lead_time_days = (!lead_days.is_empty()).then(|| {
+        (Decimal::from(lead_days.iter().sum::<i64>()) / Decimal::from(lead_days.len())).round_dp(2)
+    });
+
+    VendorPerformanceEntry {
+        rank: 0,
+        vendor_id,
+        vendor_name: orders[0].vendor_name.clone(),
+        order_count: orders.len(),
+        delivered_order_count: delivered.len(),
+        on_time_rate,
+        fill_rate,
+        average_lead_time_days,
+    }
+}
+
+fn percent(part: Decimal, whole: Decimal) -> Decimal {
+    (part / whole * Decimal::ONE_HUNDRED).round_dp(2)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::value_objects::{PurchaseOrderId, VendorId};
+    use rust_decimal_macros::dec;
+
+    fn date(day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2024, 3, day).unwrap()
+    }
+
+    fn delivery(
+        vendor: VendorId,
+        name: &str,
+        expected: Option<u32>,
+        received: Option<(u32, u32)>,
+        quantities: (Decimal, Decimal),
+    ) -> VendorOrderDelivery {
+        VendorOrderDelivery {
+            purchase_order_id: PurchaseOrderId::new(),
+            vendor_id: vendor,
+            vendor_name: name.to_string(),
+            order_date: date(1),
+            expected_delivery_date: expected.map(date),
+            quantity_ordered: quantities.0,
+            quantity_received: quantities.1,
+            first_receipt_date: received.map(|(first, _)| date(first)),
+            last_receipt_date: received.map(|(_, last)| date(last)),
+        }
+    }
+
+    #[test]
+    fn test_metrics_per_vendor() {
+        let vendor = VendorId::new();
+        let entries = rank_vendors(vec![
+            // On time, complete, 4 days lead time
+            delivery(vendor, "Acme", Some(10), Some((5, 8)), (dec!(10), dec!(10))),
+            // Late: last receipt after the expected date
+            delivery(vendor, "Acme", Some(10), Some((7, 12)), (dec!(10), dec!(5))),
+            // No expected date: counts for fill rate and lead time only
+            delivery(vendor, "Acme", None, Some((9, 9)), (dec!(10), dec!(10))),
+            // Nothing received yet
+            delivery(vendor, "Acme", Some(20), None, (dec!(10), dec!(0))),
+        ]);
+
+        assert_eq!(entries.len(), 1);
+        let acme = &entries[0];
+        assert_eq!(acme.order_count, 4);
+        assert_eq!(acme.delivered_order_count, 3);
+        assert_eq!(acme.on_time_rate, Some(dec!(50)));
+        assert_eq!(acme.fill_rate, Some(dec!(62.5)));
+        // (4 + 6 + 8) / 3
+        assert_eq!(acme.average_lead_time_days, Some(dec!(6)));
+    }
+
+    #[test]
+    fn test_ranks_by_on_time_then_fill_rate() {
+        let (late, partial, best) = (VendorId::new(), VendorId::new(), VendorId::new());
+        let entries = rank_vendors(vec![
+            delivery(late, "Late", Some(5), Some((8, 8)), (dec!(10), dec!(10))),
+            delivery(
+                partial,
+                "Partial",
+                Some(10),
+                Some((5, 5)),
+                (dec!(10), dec!(8)),
+            ),
+            delivery(best, "Best", Some(10), Some((5, 5)), (dec!(10), dec!(10))),
+        ]);
+
+        let ranked: Vec<(&str, usize)> = entries
+            .iter()
+            .map(|e| (e.vendor_name.as_str(), e.rank))
+            .collect();
+        assert_eq!(ranked, vec![("Best", 1), ("Partial", 2), ("Late", 3)]);
+    }
+}
@@ -50,6 +50,7 @@ where
             name: vendor.name().to_string(),
             legal_name: vendor.legal_name().to_string(),
             tax_id: vendor.tax_id().to_string(),
+            tax_id_country: vendor.tax_id_country().map(|s| s.to_string()),
             email: vendor.email().map(|s| s.to_string()),
             phone: vendor.phone().map(|s| s.to_string()),
             address: vendor.address().map(|s| s.to_string()),
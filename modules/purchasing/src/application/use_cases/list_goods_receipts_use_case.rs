@@ -1,13 +1,14 @@
 // ListGoodsReceiptsUseCase - lists goods receipts with pagination and filters
 
+use common::PageCursor;
 use std::sync::Arc;
 use uuid::Uuid;
 
 use crate::PurchasingError;
 use crate::application::dtos::responses::GoodsReceiptResponse;
 use crate::domain::repositories::{GoodsReceiptFilter, GoodsReceiptRepository};
-use crate::domain::value_objects::{GoodsReceiptStatus, PurchaseOrderId};
-use identity::StoreId;
+use crate::domain::value_objects::{GoodsReceiptId, GoodsReceiptStatus, PurchaseOrderId};
+use identity::{StoreId, StoreScope, UserContext};
 use inventory::PaginatedResponse;
 
 /// Query parameters for listing goods receipts
@@ -19,6 +20,9 @@ pub struct ListGoodsReceiptsQuery {
     pub purchase_order_id: Option<Uuid>,
     /// Filter by status
     pub status: Option<String>,
+    /// Keyset cursor from a previous page's `next_cursor`; when set,
+    /// `page` is ignored
+    pub after_cursor: Option<String>,
     /// Page number (1-indexed)
     pub page: i64,
     /// Number of items per page
@@ -26,6 +30,9 @@ pub struct ListGoodsReceiptsQuery {
 }
 
 /// Use case for listing goods receipts with pagination and filters
+///
+/// Receipts are limited to the caller's assigned stores; asking for a store
+/// the caller is not assigned to is rejected.
 pub struct ListGoodsReceiptsUseCase<G>
 where
     G: GoodsReceiptRepository,
@@ -46,16 +53,31 @@ where
     ///
     /// # Arguments
     /// * `query` - Query parameters with filters and pagination
+    /// * `ctx` - The caller, whose assigned stores bound the listing
     ///
     /// # Returns
     /// Paginated response with goods receipts
+    ///
+    /// # Errors
+    /// * `PurchasingError::StoreAccessDenied` - If the requested store is not
+    ///   assigned to the caller
     pub async fn execute(
         &self,
         query: ListGoodsReceiptsQuery,
+        ctx: &UserContext,
     ) -> Result<PaginatedResponse<GoodsReceiptResponse>, PurchasingError> {
+        let store_ids = match ctx.store_scope(query.store_id) {
+            StoreScope::All => None,
+            StoreScope::Stores(ids) => Some(ids.into_iter().map(StoreId::from_uuid).collect()),
+            StoreScope::Denied(store_id) => {
+                return Err(PurchasingError::StoreAccessDenied(store_id));
+            }
+        };
+
         // Validate and clamp pagination
         let page = query.page.max(1);
         let page_size = query.page_size.clamp(1, 100);
+        let after = PageCursor::decode_opt(query.after_cursor.as_deref())?;
 
         // Parse status if provided
         let status = query
@@ -69,8 +91,10 @@ where
         // Build filter
         let filter = GoodsReceiptFilter {
             store_id: query.store_id.map(StoreId::from_uuid),
+            store_ids,
             purchase_order_id: query.purchase_order_id.map(PurchaseOrderId::from_uuid),
             status,
+            after_id: after.map(|c| GoodsReceiptId::from_uuid(c.id())),
         };
 
         // Fetch receipts with pagination
@@ -78,6 +102,7 @@ where
             .receipt_repo
             .find_paginated(filter, page, page_size)
             .await?;
+        let last_id = receipts.last().map(|r| r.id().into_uuid());
 
         // Convert to response DTOs
         let receipt_responses: Vec<GoodsReceiptResponse> = receipts
@@ -98,11 +123,13 @@ where
             })
             .collect();
 
-        Ok(PaginatedResponse::new(
+        Ok(PaginatedResponse::keyset(
             receipt_responses,
+            after,
             page,
             page_size,
             total_items,
+            last_id,
         ))
     }
 }
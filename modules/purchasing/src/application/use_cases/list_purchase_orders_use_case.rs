@@ -1,13 +1,15 @@
 // ListPurchaseOrdersUseCase - lists purchase orders with pagination and filters
 
+use chrono::Utc;
+use common::PageCursor;
 use std::sync::Arc;
 use uuid::Uuid;
 
 use crate::PurchasingError;
 use crate::application::dtos::responses::PurchaseOrderResponse;
 use crate::domain::repositories::{PurchaseOrderFilter, PurchaseOrderRepository};
-use crate::domain::value_objects::{PurchaseOrderStatus, VendorId};
-use identity::StoreId;
+use crate::domain::value_objects::{PurchaseOrderId, PurchaseOrderStatus, VendorId};
+use identity::{StoreId, StoreScope, UserContext};
 use inventory::PaginatedResponse;
 
 /// Query parameters for listing purchase orders
@@ -21,6 +23,11 @@ pub struct ListPurchaseOrdersQuery {
     pub status: Option<String>,
     /// Search by order number
     pub search: Option<String>,
+    /// Only orders past their expected delivery date and not fully received
+    pub overdue: bool,
+    /// Keyset cursor from a previous page's `next_cursor`; when set,
+    /// `page` is ignored
+    pub after_cursor: Option<String>,
     /// Page number (1-indexed)
     pub page: i64,
     /// Number of items per page
@@ -28,6 +35,9 @@ pub struct ListPurchaseOrdersQuery {
 }
 
 /// Use case for listing purchase orders with pagination and filters
+///
+/// Orders are limited to the caller's assigned stores; asking for a store the
+/// caller is not assigned to is rejected.
 pub struct ListPurchaseOrdersUseCase<P>
 where
     P: PurchaseOrderRepository,
@@ -48,16 +58,27 @@ where
     ///
     /// # Arguments
     /// * `query` - Query parameters with filters and pagination
+    /// * `ctx` - The caller, whose assigned stores bound the listing
     ///
     /// # Returns
     /// Paginated response with purchase orders
     pub async fn execute(
         &self,
         query: ListPurchaseOrdersQuery,
+        ctx: &UserContext,
     ) -> Result<PaginatedResponse<PurchaseOrderResponse>, PurchasingError> {
+        let store_ids = match ctx.store_scope(query.store_id) {
+            StoreScope::All => None,
+            StoreScope::Stores(ids) => Some(ids.into_iter().map(StoreId::from_uuid).collect()),
+            StoreScope::Denied(store_id) => {
+                return Err(PurchasingError::StoreAccessDenied(store_id));
+            }
+        };
+
         // Validate and clamp pagination
         let page = query.page.max(1);
         let page_size = query.page_size.clamp(1, 100);
+        let after = PageCursor::decode_opt(query.after_cursor.as_deref())?;
 
         // Parse status if provided
         let status = query
@@ -68,12 +89,17 @@ where
             })
             .transpose()?;
 
+        let today = Utc::now().date_naive();
+
         // Build filter
         let filter = PurchaseOrderFilter {
             store_id: query.store_id.map(StoreId::from_uuid),
+            store_ids,
             vendor_id: query.vendor_id.map(VendorId::from_uuid),
             status,
             search: query.search,
+            overdue_as_of: query.overdue.then_some(today),
+            after_id: after.map(|c| PurchaseOrderId::from_uuid(c.id())),
         };
 
         // Fetch orders with pagination
@@ -81,6 +107,7 @@ where
             .order_repo
             .find_paginated(filter, page, page_size)
             .await?;
+        let last_id = orders.last().map(|o| o.id().into_uuid());
 
         // Convert to response DTOs
         let order_responses: Vec<PurchaseOrderResponse> = orders
@@ -88,11 +115,15 @@ where
             .map(|o| PurchaseOrderResponse {
                 id: o.id().into_uuid(),
                 order_number: o.order_number().to_string(),
+                parent_order_id: o.parent_order_id().map(|id| id.into_uuid()),
                 store_id: o.store_id().into_uuid(),
                 vendor_id: o.vendor_id().into_uuid(),
                 status: o.status().to_string(),
                 order_date: o.order_date(),
                 expected_delivery_date: o.expected_delivery_date(),
+                received_date: o.received_date(),
+                first_received_date: o.first_received_date(),
+                days_late: o.days_late(today),
                 subtotal: o.subtotal(),
                 tax_amount: o.tax_amount(),
                 discount_amount: o.discount_amount(),
@@ -106,11 +137,222 @@ where
             })
             .collect();
 
-        Ok(PaginatedResponse::new(
+        Ok(PaginatedResponse::keyset(
             order_responses,
+            after,
             page,
             page_size,
             total_items,
+            last_id,
         ))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use async_trait::async_trait;
+    use chrono::NaiveDate;
+
+    use crate::domain::entities::{PurchaseOrder, PurchaseOrderItem};
+    use crate::domain::repositories::{StalePurchaseOrderFilter, VendorOrderDelivery};
+    use crate::domain::value_objects::PurchaseOrderItemId;
+    use identity::UserId;
+    use inventory::Currency;
+
+    struct MockOrderRepository {
+        orders: Vec<PurchaseOrder>,
+    }
+
+    #[async_trait]
+    impl PurchaseOrderRepository for MockOrderRepository {
+        async fn save(&self, _order: &PurchaseOrder) -> Result<(), PurchasingError> {
+            unimplemented!()
+        }
+        async fn save_many(&self, _orders: &[PurchaseOrder]) -> Result<(), PurchasingError> {
+            unimplemented!()
+        }
+        async fn find_by_id(
+            &self,
+            _id: PurchaseOrderId,
+        ) -> Result<Option<PurchaseOrder>, PurchasingError> {
+            unimplemented!()
+        }
+        async fn find_by_id_with_items(
+            &self,
+            _id: PurchaseOrderId,
+        ) -> Result<Option<PurchaseOrder>, PurchasingError> {
+            unimplemented!()
+        }
+        async fn find_by_order_number(
+            &self,
+            _store_id: StoreId,
+            _order_number: &str,
+        ) -> Result<Option<PurchaseOrder>, PurchasingError> {
+            unimplemented!()
+        }
+        async fn update(&self, _order: &PurchaseOrder) -> Result<(), PurchasingError> {
+            unimplemented!()
+        }
+        async fn find_paginated(
+            &self,
+            filter: PurchaseOrderFilter,
+            _page: i64,
+            _page_size: i64,
+        ) -> Result<(Vec<PurchaseOrder>, i64), PurchasingError> {
+            let orders: Vec<PurchaseOrder> = self
+                .orders
+                .iter()
+                .filter(|o| filter.store_id.is_none_or(|id| o.store_id() == id))
+                .filter(|o| {
+                    filter
+                        .store_ids
+                        .as_ref()
+                        .is_none_or(|ids| ids.contains(&o.store_id()))
+                })
+                .cloned()
+                .collect();
+            let total = orders.len() as i64;
+            Ok((orders, total))
+        }
+        async fn find_stale(
+            &self,
+            _filter: &StalePurchaseOrderFilter,
+        ) -> Result<Vec<PurchaseOrder>, PurchasingError> {
+            unimplemented!()
+        }
+        async fn cancel_many(&self, _orders: &[PurchaseOrder]) -> Result<(), PurchasingError> {
+            unimplemented!()
+        }
+        async fn find_vendor_deliveries(
+            &self,
+            _store_id: Option<StoreId>,
+            _from: NaiveDate,
+            _to: NaiveDate,
+        ) -> Result<Vec<VendorOrderDelivery>, PurchasingError> {
+            unimplemented!()
+        }
+        async fn generate_order_number(
+            &self,
+            _store_id: StoreId,
+        ) -> Result<String, PurchasingError> {
+            unimplemented!()
+        }
+        async fn save_item(&self, _item: &PurchaseOrderItem) -> Result<(), PurchasingError> {
+            unimplemented!()
+        }
+        async fn update_item(&self, _item: &PurchaseOrderItem) -> Result<(), PurchasingError> {
+            unimplemented!()
+        }
+        async fn delete_item(&self, _item_id: PurchaseOrderItemId) -> Result<(), PurchasingError> {
+            unimplemented!()
+        }
+        async fn find_items_by_order(
+            &self,
+            _order_id: PurchaseOrderId,
+        ) -> Result<Vec<PurchaseOrderItem>, PurchasingError> {
+            unimplemented!()
+        }
+        async fn find_item_by_id(
+            &self,
+            _item_id: PurchaseOrderItemId,
+        ) -> Result<Option<PurchaseOrderItem>, PurchasingError> {
+            unimplemented!()
+        }
+    }
+
+    fn order(store_id: StoreId) -> PurchaseOrder {
+        PurchaseOrder::create(
+            "PO-2024-00001".to_string(),
+            store_id,
+            VendorId::new(),
+            NaiveDate::from_ymd_opt(2024, 1, 20).unwrap(),
+            Currency::new("HNL").unwrap(),
+            30,
+            UserId::new(),
+        )
+    }
+
+    /// One order in each of two stores
+    fn setup() -> (ListPurchaseOrdersUseCase<MockOrderRepository>, Uuid, Uuid) {
+        let (store_a, store_b) = (StoreId::new(), StoreId::new());
+        let repo = MockOrderRepository {
+            orders: vec![order(store_a), order(store_b)],
+        };
+        let use_case = ListPurchaseOrdersUseCase::new(Arc::new(repo));
+        (use_case, store_a.into_uuid(), store_b.into_uuid())
+    }
+
+    fn context(stores: Vec<Uuid>, is_super_admin: bool) -> UserContext {
+        UserContext::new(
+            UserId::new(),
+            StoreId::new(),
+            Default::default(),
+            stores,
+            is_super_admin,
+            None,
+            None,
+        )
+    }
+
+    fn query(store_id: Option<Uuid>) -> ListPurchaseOrdersQuery {
+        ListPurchaseOrdersQuery {
+            store_id,
+            page: 1,
+            page_size: 20,
+            ..Default::default()
+        }
+    }
+
+    #[tokio::test]
+    async fn test_assigned_user_sees_only_their_store() {
+        let (use_case, store_a, _) = setup();
+        let ctx = context(vec![store_a], false);
+
+        let all = use_case.execute(query(None), &ctx).await.unwrap();
+        assert_eq!(all.items.len(), 1);
+        assert_eq!(all.items[0].store_id, store_a);
+
+        let requested = use_case.execute(query(Some(store_a)), &ctx).await.unwrap();
+        assert_eq!(requested.items.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_rejects_store_the_user_is_not_assigned_to() {
+        let (use_case, store_a, store_b) = setup();
+        let ctx = context(vec![store_b], false);
+
+        let result = use_case.execute(query(Some(store_a)), &ctx).await;
+        assert!(matches!(
+            result,
+            Err(PurchasingError::StoreAccessDenied(id)) if id == store_a
+        ));
+
+        let all = use_case.execute(query(None), &ctx).await.unwrap();
+        assert!(all.items.iter().all(|o| o.store_id == store_b));
+    }
+
+    #[tokio::test]
+    async fn test_user_without_stores_sees_nothing() {
+        let (use_case, store_a, _) = setup();
+        let ctx = context(vec![], false);
+
+        let all = use_case.execute(query(None), &ctx).await.unwrap();
+        assert!(all.items.is_empty());
+
+        let result = use_case.execute(query(Some(store_a)), &ctx).await;
+        assert!(matches!(result, Err(PurchasingError::StoreAccessDenied(_))));
+    }
+
+    #[tokio::test]
+    async fn test_super_admin_sees_every_store() {
+        let (use_case, store_a, _) = setup();
+        let ctx = context(vec![], true);
+
+        let all = use_case.execute(query(None), &ctx).await.unwrap();
+        assert_eq!(all.items.len(), 2);
+
+        let requested = use_case.execute(query(Some(store_a)), &ctx).await.unwrap();
+        assert_eq!(requested.items.len(), 1);
+    }
+}
@@ -1,10 +1,12 @@
 // ListVendorsUseCase - lists vendors with pagination and filters
 
+use common::PageCursor;
 use std::sync::Arc;
 
 use crate::PurchasingError;
 use crate::application::dtos::responses::VendorResponse;
 use crate::domain::repositories::{VendorFilter, VendorRepository};
+use crate::domain::value_objects::VendorId;
 use inventory::PaginatedResponse;
 
 /// Query parameters for listing vendors
@@ -14,6 +16,9 @@ pub struct ListVendorsQuery {
     pub is_active: Option<bool>,
     /// Search by name or code
     pub search: Option<String>,
+    /// Keyset cursor from a previous page's `next_cursor`; when set,
+    /// `page` is ignored
+    pub after_cursor: Option<String>,
     /// Page number (1-indexed)
     pub page: i64,
     /// Number of items per page
@@ -51,11 +56,13 @@ where
         // Validate and clamp pagination
         let page = query.page.max(1);
         let page_size = query.page_size.clamp(1, 100);
+        let after = PageCursor::decode_opt(query.after_cursor.as_deref())?;
 
         // Build filter
         let filter = VendorFilter {
             is_active: query.is_active,
             search: query.search,
+            after_id: after.map(|c| VendorId::from_uuid(c.id())),
         };
 
         // Fetch vendors with pagination
@@ -63,6 +70,7 @@ where
             .vendor_repo
             .find_paginated(filter, page, page_size)
             .await?;
+        let last_id = vendors.last().map(|v| v.id().into_uuid());
 
         // Convert to response DTOs
         let vendor_responses: Vec<VendorResponse> = vendors
@@ -73,6 +81,7 @@ where
                 name: v.name().to_string(),
                 legal_name: v.legal_name().to_string(),
                 tax_id: v.tax_id().to_string(),
+                tax_id_country: v.tax_id_country().map(|s| s.to_string()),
                 email: v.email().map(|s| s.to_string()),
                 phone: v.phone().map(|s| s.to_string()),
                 address: v.address().map(|s| s.to_string()),
@@ -85,11 +94,13 @@ where
             })
             .collect();
 
-        Ok(PaginatedResponse::new(
+        Ok(PaginatedResponse::keyset(
             vendor_responses,
+            after,
             page,
             page_size,
             total_items,
+            last_id,
         ))
     }
 }
@@ -8,12 +8,14 @@
 // -----------------------------------------------------------------------------
 
 mod create_vendor_use_case;
+mod get_vendor_performance_use_case;
 mod get_vendor_use_case;
 mod list_vendors_use_case;
 mod toggle_vendor_status_use_case;
 mod update_vendor_use_case;
 
 pub use create_vendor_use_case::CreateVendorUseCase;
+pub use get_vendor_performance_use_case::{GetVendorPerformanceUseCase, VendorPerformanceQuery};
 pub use get_vendor_use_case::GetVendorUseCase;
 pub use list_vendors_use_case::{ListVendorsQuery, ListVendorsUseCase};
 pub use toggle_vendor_status_use_case::ToggleVendorStatusUseCase;
@@ -24,6 +26,7 @@ pub use update_vendor_use_case::UpdateVendorUseCase;
 // -----------------------------------------------------------------------------
 
 mod approve_purchase_order_use_case;
+mod bulk_cancel_purchase_orders_use_case;
 mod cancel_purchase_order_use_case;
 mod close_purchase_order_use_case;
 mod create_purchase_order_use_case;
@@ -34,6 +37,7 @@ mod submit_purchase_order_use_case;
 mod update_purchase_order_use_case;
 
 pub use approve_purchase_order_use_case::ApprovePurchaseOrderUseCase;
+pub use bulk_cancel_purchase_orders_use_case::BulkCancelPurchaseOrdersUseCase;
 pub use cancel_purchase_order_use_case::CancelPurchaseOrderUseCase;
 pub use close_purchase_order_use_case::ClosePurchaseOrderUseCase;
 pub use create_purchase_order_use_case::CreatePurchaseOrderUseCase;
@@ -51,10 +55,14 @@ mod cancel_goods_receipt_use_case;
 mod confirm_goods_receipt_use_case;
 mod create_goods_receipt_use_case;
 mod get_goods_receipt_use_case;
+mod get_recent_purchase_costs_use_case;
 mod list_goods_receipts_use_case;
 
 pub use cancel_goods_receipt_use_case::CancelGoodsReceiptUseCase;
 pub use confirm_goods_receipt_use_case::ConfirmGoodsReceiptUseCase;
 pub use create_goods_receipt_use_case::CreateGoodsReceiptUseCase;
 pub use get_goods_receipt_use_case::GetGoodsReceiptUseCase;
+pub use get_recent_purchase_costs_use_case::{
+    DEFAULT_RECENT_COSTS_LIMIT, GetRecentPurchaseCostsUseCase, RecentPurchaseCostsQuery,
+};
 pub use list_goods_receipts_use_case::{ListGoodsReceiptsQuery, ListGoodsReceiptsUseCase};
@@ -1,9 +1,11 @@
 // SubmitPurchaseOrderUseCase - submits a purchase order for approval
 
+use chrono::{NaiveDate, Utc};
 use std::sync::Arc;
 use uuid::Uuid;
 
 use crate::PurchasingError;
+use crate::application::dtos::commands::SubmitOrderCommand;
 use crate::application::dtos::responses::{PurchaseOrderDetailResponse, PurchaseOrderItemResponse};
 use crate::domain::entities::PurchaseOrder;
 use crate::domain::repositories::PurchaseOrderRepository;
@@ -32,13 +34,19 @@ where
     /// # Arguments
     /// * `order_id` - The ID of the purchase order to submit
     /// * `actor_id` - ID of the user submitting the order
+    /// * `command` - Optional expected delivery date to set on submission
     ///
     /// # Returns
     /// PurchaseOrderDetailResponse on success
+    ///
+    /// # Errors
+    /// * `PurchasingError::ExpectedDeliveryDateInPast` - If the expected
+    ///   delivery date is before today
     pub async fn execute(
         &self,
         order_id: Uuid,
         actor_id: UserId,
+        command: SubmitOrderCommand,
     ) -> Result<PurchaseOrderDetailResponse, PurchasingError> {
         let id = PurchaseOrderId::from_uuid(order_id);
 
@@ -49,6 +57,12 @@ where
             .await?
             .ok_or(PurchasingError::PurchaseOrderNotFound(order_id))?;
 
+        if let Some(date) = command.expected_delivery_date {
+            let date = NaiveDate::parse_from_str(&date, "%Y-%m-%d")
+                .map_err(|_| PurchasingError::InvalidPurchaseOrderStatus)?;
+            order.set_expected_delivery_date(Some(date))?;
+        }
+
         // Submit for approval
         order.submit(actor_id)?;
 
@@ -73,6 +87,8 @@ where
                 quantity_received: item.quantity_received(),
                 unit_of_measure: item.unit_of_measure().to_string(),
                 unit_cost: item.unit_cost(),
+                pack_size: item.pack_size(),
+                pack_price: item.pack_price(),
                 discount_percent: item.discount_percent(),
                 tax_percent: item.tax_percent(),
                 line_total: item.line_total(),
@@ -83,6 +99,7 @@ where
         PurchaseOrderDetailResponse {
             id: order.id().into_uuid(),
             order_number: order.order_number().to_string(),
+            parent_order_id: order.parent_order_id().map(|id| id.into_uuid()),
             store_id: order.store_id().into_uuid(),
             vendor_id: order.vendor_id().into_uuid(),
             status: order.status().to_string(),
@@ -99,10 +116,14 @@ where
             created_by_id: order.created_by_id().into_uuid(),
             submitted_by_id: order.submitted_by_id().map(|id| id.into_uuid()),
             submitted_at: order.submitted_at(),
+            first_approved_by_id: order.first_approved_by_id().map(|id| id.into_uuid()),
+            first_approved_at: order.first_approved_at(),
             approved_by_id: order.approved_by_id().map(|id| id.into_uuid()),
             approved_at: order.approved_at(),
             received_by_id: order.received_by_id().map(|id| id.into_uuid()),
             received_date: order.received_date(),
+            first_received_date: order.first_received_date(),
+            days_late: order.days_late(Utc::now().date_naive()),
             cancelled_by_id: order.cancelled_by_id().map(|id| id.into_uuid()),
             cancelled_at: order.cancelled_at(),
             cancellation_reason: order.cancellation_reason().map(|s| s.to_string()),
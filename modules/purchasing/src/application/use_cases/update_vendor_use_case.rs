@@ -8,23 +8,35 @@ use crate::application::dtos::commands::UpdateVendorCommand;
 use crate::application::dtos::responses::VendorResponse;
 use crate::domain::repositories::VendorRepository;
 use crate::domain::value_objects::VendorId;
+use common::TaxIdPolicy;
+use identity::domain::entities::AuditEntry;
+use identity::domain::repositories::AuditRepository;
+use identity::domain::value_objects::UserId;
 use inventory::Currency;
 
 /// Use case for updating an existing vendor
-pub struct UpdateVendorUseCase<V>
+pub struct UpdateVendorUseCase<V, A>
 where
     V: VendorRepository,
+    A: AuditRepository,
 {
     vendor_repo: Arc<V>,
+    audit_repo: Arc<A>,
+    tax_id_policy: TaxIdPolicy,
 }
 
-impl<V> UpdateVendorUseCase<V>
+impl<V, A> UpdateVendorUseCase<V, A>
 where
     V: VendorRepository,
+    A: AuditRepository,
 {
     /// Creates a new instance of UpdateVendorUseCase
-    pub fn new(vendor_repo: Arc<V>) -> Self {
-        Self { vendor_repo }
+    pub fn new(vendor_repo: Arc<V>, audit_repo: Arc<A>, tax_id_policy: TaxIdPolicy) -> Self {
+        Self {
+            vendor_repo,
+            audit_repo,
+            tax_id_policy,
+        }
     }
 
     /// Executes the use case to update an existing vendor
@@ -32,18 +44,21 @@ where
     /// # Arguments
     /// * `vendor_id` - The ID of the vendor to update
     /// * `command` - The update vendor command containing new data
+    /// * `actor_id` - ID of the user performing this action (for audit)
     ///
     /// # Returns
     /// VendorResponse on success
     ///
     /// # Errors
     /// * `PurchasingError::VendorNotFound` - If vendor doesn't exist
+    /// * `PurchasingError::InvalidTaxId` - If tax ID doesn't match its country's format
     /// * `PurchasingError::DuplicateVendorTaxId` - If new tax ID already exists for another vendor
     /// * `PurchasingError::InvalidCurrency` - If currency code is invalid
     pub async fn execute(
         &self,
         vendor_id: Uuid,
         command: UpdateVendorCommand,
+        actor_id: UserId,
     ) -> Result<VendorResponse, PurchasingError> {
         let id = VendorId::from_uuid(vendor_id);
 
@@ -54,15 +69,36 @@ where
             .await?
             .ok_or(PurchasingError::VendorNotFound(vendor_id))?;
 
+        // Clone for audit trail (old value)
+        let old_vendor = vendor.clone();
+
+        // Revalidate tax ID if it or its country is changing
+        let tax_id = if command.tax_id.is_some() || command.tax_id_country.is_some() {
+            let raw = command.tax_id.as_deref().unwrap_or(vendor.tax_id());
+            let country = command
+                .tax_id_country
+                .as_deref()
+                .or(vendor.tax_id_country());
+            Some(
+                self.tax_id_policy
+                    .parse(raw, country)
+                    .map_err(|e| PurchasingError::InvalidTaxId(e.to_string()))?,
+            )
+        } else {
+            None
+        };
+
         // Check for duplicate tax ID if changing
-        if let Some(ref new_tax_id) = command.tax_id
-            && new_tax_id != vendor.tax_id()
+        if let Some(ref new_tax_id) = tax_id
+            && new_tax_id.value() != vendor.tax_id()
             && self
                 .vendor_repo
-                .exists_by_tax_id_excluding(new_tax_id, id)
+                .exists_by_tax_id_excluding(new_tax_id.value(), id)
                 .await?
         {
-            return Err(PurchasingError::DuplicateVendorTaxId(new_tax_id.clone()));
+            return Err(PurchasingError::DuplicateVendorTaxId(
+                new_tax_id.value().to_string(),
+            ));
         }
 
         // Apply updates
@@ -72,7 +108,7 @@ where
         if let Some(legal_name) = command.legal_name {
             vendor.set_legal_name(legal_name);
         }
-        if let Some(tax_id) = command.tax_id {
+        if let Some(tax_id) = tax_id {
             vendor.set_tax_id(tax_id);
         }
         if let Some(email) = command.email {
@@ -99,6 +135,13 @@ where
         // Update in repository
         self.vendor_repo.update(&vendor).await?;
 
+        let audit_entry =
+            AuditEntry::for_update("vendor", vendor_id, &old_vendor, &vendor, actor_id);
+        self.audit_repo
+            .save(&audit_entry)
+            .await
+            .map_err(|e| PurchasingError::AuditError(e.to_string()))?;
+
         // Convert to response
         Ok(VendorResponse {
             id: vendor.id().into_uuid(),
@@ -106,6 +149,7 @@ where
             name: vendor.name().to_string(),
             legal_name: vendor.legal_name().to_string(),
             tax_id: vendor.tax_id().to_string(),
+            tax_id_country: vendor.tax_id_country().map(|s| s.to_string()),
             email: vendor.email().map(|s| s.to_string()),
             phone: vendor.phone().map(|s| s.to_string()),
             address: vendor.address().map(|s| s.to_string()),
@@ -2,6 +2,14 @@
 //!
 //! This module contains helper functions used by use cases.
 
+mod receipt_lots;
+mod receipt_match;
+mod receipt_serials;
+mod receipt_units;
 mod vendor_code_generator;
 
+pub use receipt_lots::plan_receipt_lots;
+pub use receipt_match::match_receipt;
+pub use receipt_serials::{inventory_error, normalize_serials, plan_receipt_serials};
+pub use receipt_units::{StockingLine, plan_stocking_lines};
 pub use vendor_code_generator::generate_vendor_code_prefix;
@@ -0,0 +1,26 @@
+//! Lots opened by the lines of a goods receipt
+
+use super::StockingLine;
+use crate::domain::entities::GoodsReceipt;
+use inventory::InventoryLot;
+
+/// Builds the lot quantities a receipt brings into its store: one per line
+/// with a lot number or expiry date, in the stocking units of `lines`.
+/// Lines for the same lot add up when saved.
+pub fn plan_receipt_lots(receipt: &GoodsReceipt, lines: &[StockingLine]) -> Vec<InventoryLot> {
+    receipt
+        .items()
+        .iter()
+        .zip(lines)
+        .filter_map(|(item, line)| {
+            InventoryLot::receive(
+                item.product_id(),
+                item.variant_id(),
+                receipt.store_id(),
+                item.lot_number(),
+                item.expiry_date(),
+                line.quantity,
+            )
+        })
+        .collect()
+}
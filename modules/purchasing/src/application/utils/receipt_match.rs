@@ -0,0 +1,152 @@
+//! Matching goods receipts against their purchase orders
+
+use crate::PurchasingError;
+use crate::domain::entities::{GoodsReceipt, PurchaseOrder};
+use crate::domain::value_objects::ReceiptMatchPolicy;
+use identity::UserId;
+
+/// Matches `receipt` against `order` under `policy` and records the
+/// variances on the receipt. `order` must not include the receipt's
+/// quantities yet. A mismatch is recorded as overridden by `actor_id` when
+/// the policy allows overrides and `can_override` is set.
+///
+/// # Errors
+/// * `PurchasingError::ReceiptMatchFailed` - Some line is outside tolerance
+///   and can't be overridden
+pub fn match_receipt(
+    policy: &ReceiptMatchPolicy,
+    receipt: &mut GoodsReceipt,
+    order: &PurchaseOrder,
+    actor_id: UserId,
+    can_override: bool,
+) -> Result<(), PurchasingError> {
+    if !policy.is_enabled() {
+        return Ok(());
+    }
+
+    let result = policy.evaluate(receipt, order);
+    if result.is_matched() {
+        receipt.record_match(&result, None);
+        return Ok(());
+    }
+    if policy.allows_override() && can_override {
+        receipt.record_match(&result, Some(actor_id));
+        return Ok(());
+    }
+
+    let mismatches: Vec<String> = result
+        .mismatches()
+        .map(|line| {
+            let product = receipt
+                .items()
+                .iter()
+                .find(|item| item.id() == line.goods_receipt_item_id)
+                .map(|item| item.product_id().into_uuid().to_string())
+                .unwrap_or_default();
+            format!(
+                "product {}: {} over ordered quantity, unit cost variance {}",
+                product, line.quantity_variance, line.cost_variance
+            )
+        })
+        .collect();
+    Err(PurchasingError::ReceiptMatchFailed(mismatches.join("; ")))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::entities::{GoodsReceiptItem, PurchaseOrderItem};
+    use crate::domain::value_objects::{ReceiptMatchStatus, VendorId};
+    use chrono::NaiveDate;
+    use identity::StoreId;
+    use inventory::{Currency, ProductId, UnitOfMeasure};
+    use rust_decimal_macros::dec;
+
+    /// Order for 10 units at 5.00 and a receipt for 12 of them
+    fn over_received() -> (GoodsReceipt, PurchaseOrder) {
+        let mut order = PurchaseOrder::create(
+            "PO-2024-00001".to_string(),
+            StoreId::new(),
+            VendorId::new(),
+            NaiveDate::from_ymd_opt(2024, 1, 20).unwrap(),
+            Currency::new("HNL").unwrap(),
+            30,
+            UserId::new(),
+        );
+        let ordered = PurchaseOrderItem::create(
+            order.id(),
+            1,
+            ProductId::new(),
+            None,
+            "Test Product".to_string(),
+            dec!(10),
+            UnitOfMeasure::Unit,
+            dec!(5),
+            dec!(0),
+            dec!(0),
+        );
+        let mut receipt = GoodsReceipt::create(
+            "GR-2024-00001".to_string(),
+            order.id(),
+            order.store_id(),
+            NaiveDate::from_ymd_opt(2024, 1, 25).unwrap(),
+            UserId::new(),
+        );
+        let line = GoodsReceiptItem::create(
+            receipt.id(),
+            ordered.id(),
+            ordered.product_id(),
+            None,
+            dec!(12),
+            dec!(5),
+        );
+        receipt.add_item(line).unwrap();
+        order.add_item(ordered).unwrap();
+        (receipt, order)
+    }
+
+    #[test]
+    fn test_mismatch_blocks_without_override() {
+        let (mut receipt, order) = over_received();
+        let policy = ReceiptMatchPolicy::disabled()
+            .with_quantity_tolerance(dec!(10))
+            .with_override_allowed(true);
+
+        let result = match_receipt(&policy, &mut receipt, &order, UserId::new(), false);
+
+        assert!(matches!(
+            result,
+            Err(PurchasingError::ReceiptMatchFailed(_))
+        ));
+        assert!(receipt.match_status().is_none());
+    }
+
+    #[test]
+    fn test_override_records_variance() {
+        let (mut receipt, order) = over_received();
+        let policy = ReceiptMatchPolicy::disabled()
+            .with_quantity_tolerance(dec!(10))
+            .with_override_allowed(true);
+        let actor = UserId::new();
+
+        match_receipt(&policy, &mut receipt, &order, actor, true).unwrap();
+
+        assert_eq!(receipt.match_status(), Some(ReceiptMatchStatus::Overridden));
+        assert_eq!(receipt.match_overridden_by_id(), Some(actor));
+        assert_eq!(receipt.items()[0].quantity_variance(), Some(dec!(2)));
+        assert_eq!(receipt.items()[0].cost_variance(), Some(dec!(0)));
+    }
+
+    #[test]
+    fn test_override_not_allowed_by_policy() {
+        let (mut receipt, order) = over_received();
+        let policy = ReceiptMatchPolicy::disabled().with_quantity_tolerance(dec!(10));
+
+        let result = match_receipt(&policy, &mut receipt, &order, UserId::new(), true);
+
+        assert!(matches!(
+            result,
+            Err(PurchasingError::ReceiptMatchFailed(_))
+        ));
+    }
+}
@@ -0,0 +1,91 @@
+//! Validation of the serial numbers captured on goods receipt lines
+
+use std::collections::HashSet;
+
+use crate::PurchasingError;
+use crate::domain::entities::GoodsReceipt;
+use inventory::{InventoryError, ProductRepository, ProductSerial};
+use uuid::Uuid;
+
+/// Maps inventory failures while confirming a receipt onto purchasing errors
+pub fn inventory_error(error: InventoryError) -> PurchasingError {
+    match error {
+        InventoryError::DuplicateSerial(serial) => PurchasingError::SerialAlreadyRegistered(serial),
+        InventoryError::InvalidSerialNumber => PurchasingError::InvalidSerialNumber,
+        InventoryError::ProductNotFound(id) => PurchasingError::ProductNotFound(id),
+        InventoryError::IncompatibleUnits { from, to } => {
+            PurchasingError::IncompatibleUnits { from, to }
+        }
+        InventoryError::PeriodClosed(start, end) => PurchasingError::PeriodClosed(start, end),
+        InventoryError::Database(e) => PurchasingError::Database(e),
+        other => PurchasingError::Inventory(other.to_string()),
+    }
+}
+
+/// Normalizes the serials scanned on a receipt line of `product_id`,
+/// rejecting blanks and serials of the product already listed on the
+/// receipt (tracked in `seen`)
+pub fn normalize_serials(
+    product_id: Uuid,
+    serial_numbers: &[String],
+    seen: &mut HashSet<(Uuid, String)>,
+) -> Result<Vec<String>, PurchasingError> {
+    let mut normalized = Vec::with_capacity(serial_numbers.len());
+    for raw in serial_numbers {
+        let serial = ProductSerial::normalize(raw).map_err(inventory_error)?;
+        if !seen.insert((product_id, serial.clone())) {
+            return Err(PurchasingError::DuplicateSerialNumber(serial));
+        }
+        normalized.push(serial);
+    }
+    Ok(normalized)
+}
+
+/// Builds the units a receipt brings into stock at its store.
+///
+/// Lines of serial-tracked products must carry one serial per received
+/// unit; lines of other products must carry none. Serials already
+/// registered for the product are rejected when the units are saved.
+pub async fn plan_receipt_serials<R>(
+    product_repo: &R,
+    receipt: &GoodsReceipt,
+) -> Result<Vec<ProductSerial>, PurchasingError>
+where
+    R: ProductRepository + ?Sized,
+{
+    let mut units = Vec::new();
+    for item in receipt.items() {
+        let product_id = item.product_id().into_uuid();
+        let product = product_repo
+            .find_by_id(item.product_id())
+            .await
+            .map_err(inventory_error)?
+            .ok_or(PurchasingError::ProductNotFound(product_id))?;
+
+        if !product.is_serialized() {
+            if !item.serial_numbers().is_empty() {
+                return Err(PurchasingError::ProductNotSerialized(product_id));
+            }
+            continue;
+        }
+        if item.serial_numbers().is_empty() {
+            return Err(PurchasingError::SerialNumbersRequired(product_id));
+        }
+        if !item.has_complete_serials() {
+            return Err(PurchasingError::SerialCountMismatch);
+        }
+
+        for serial_number in item.serial_numbers() {
+            units.push(
+                ProductSerial::create(
+                    item.product_id(),
+                    item.variant_id(),
+                    receipt.store_id(),
+                    serial_number,
+                )
+                .map_err(inventory_error)?,
+            );
+        }
+    }
+    Ok(units)
+}
@@ -0,0 +1,139 @@
+//! Receipt quantities in the units products are stocked in
+
+use std::collections::HashMap;
+
+use rust_decimal::Decimal;
+
+use super::inventory_error;
+use crate::PurchasingError;
+use crate::domain::entities::{GoodsReceipt, PurchaseOrder};
+use inventory::{ProductRepository, UnitConverter};
+
+/// A receipt line converted to its product's stocking unit
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StockingLine {
+    pub quantity: Decimal,
+    /// Cost of one stocking unit
+    pub unit_cost: Decimal,
+}
+
+impl StockingLine {
+    /// A received quantity and its cost per ordered unit, where one ordered
+    /// unit holds `factor` stocking units
+    fn convert(quantity: Decimal, unit_cost: Decimal, factor: Decimal) -> Self {
+        Self {
+            quantity: quantity * factor,
+            unit_cost: unit_cost / factor,
+        }
+    }
+}
+
+/// Converts each line of a receipt, in order, from the unit it was ordered
+/// in to the unit its product is stocked in, using the product's own unit
+/// conversions where standard factors don't apply (e.g. 1 case = 24
+/// units). Lines ordered in vendor packs count packs, so they are first
+/// multiplied by the pack size. The unit cost is spread over the converted
+/// quantity.
+///
+/// # Errors
+/// * `PurchasingError::IncompatibleUnits` - If a line's unit can't be
+///   converted to its product's unit
+/// * `PurchasingError::ProductNotFound` - If a line's product doesn't exist
+pub async fn plan_stocking_lines<R>(
+    product_repo: &R,
+    receipt: &GoodsReceipt,
+    order: &PurchaseOrder,
+) -> Result<Vec<StockingLine>, PurchasingError>
+where
+    R: ProductRepository + ?Sized,
+{
+    let ordered_units: HashMap<_, _> = order
+        .items()
+        .iter()
+        .map(|item| {
+            (
+                item.id(),
+                (*item.unit_of_measure(), item.units_per_ordered_unit()),
+            )
+        })
+        .collect();
+
+    let mut lines = Vec::with_capacity(receipt.items().len());
+    for item in receipt.items() {
+        let product = product_repo
+            .find_by_id(item.product_id())
+            .await
+            .map_err(inventory_error)?
+            .ok_or(PurchasingError::ProductNotFound(
+                item.product_id().into_uuid(),
+            ))?;
+        let stocking_unit = product.unit_of_measure();
+        let (ordered_unit, pack_size) = ordered_units
+            .get(&item.purchase_order_item_id())
+            .copied()
+            .unwrap_or((stocking_unit, Decimal::ONE));
+
+        let unit_factor = if ordered_unit == stocking_unit {
+            Decimal::ONE
+        } else {
+            let conversions = product_repo
+                .find_unit_conversions(product.id())
+                .await
+                .map_err(inventory_error)?;
+            UnitConverter::new()
+                .with_conversions(conversions)
+                .convert(Decimal::ONE, ordered_unit, stocking_unit)
+                .map_err(inventory_error)?
+        };
+        lines.push(StockingLine::convert(
+            item.quantity_received(),
+            item.unit_cost(),
+            pack_size * unit_factor,
+        ));
+    }
+    Ok(lines)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use identity::{StoreId, UserId};
+    use inventory::application::helpers::{
+        InboundPostingContext, InboundStockLine, plan_inbound_posting,
+    };
+    use inventory::{Currency, ProductId};
+    use rust_decimal_macros::dec;
+
+    #[test]
+    fn test_packs_convert_to_stocking_units() {
+        // 3 cases of 24 at 60 per case
+        let line = StockingLine::convert(dec!(3), dec!(60), dec!(24));
+        assert_eq!(line.quantity, dec!(72));
+        assert_eq!(line.unit_cost, dec!(2.5));
+    }
+
+    #[test]
+    fn test_movement_records_derived_unit_cost() {
+        let line = StockingLine::convert(dec!(2), dec!(60), dec!(24));
+        let context = InboundPostingContext {
+            store_id: StoreId::new(),
+            movement_reason: None,
+            reference_type: None,
+            reference_id: None,
+            actor_id: UserId::new(),
+            currency: Currency::hnl(),
+        };
+        let inbound = [InboundStockLine {
+            product_id: ProductId::new(),
+            variant_id: None,
+            quantity: line.quantity,
+            unit_cost: Some(line.unit_cost),
+        }];
+
+        let plan = plan_inbound_posting(&context, Vec::new(), &inbound).unwrap();
+
+        assert_eq!(plan.movements.len(), 1);
+        assert_eq!(plan.movements[0].quantity(), dec!(48));
+        assert_eq!(plan.movements[0].unit_cost(), Some(dec!(2.5)));
+    }
+}
@@ -5,7 +5,9 @@ use serde::{Deserialize, Serialize};
 
 use crate::PurchasingError;
 use crate::domain::entities::GoodsReceiptItem;
-use crate::domain::value_objects::{GoodsReceiptId, GoodsReceiptStatus, PurchaseOrderId};
+use crate::domain::value_objects::{
+    GoodsReceiptId, GoodsReceiptStatus, PurchaseOrderId, ReceiptMatch, ReceiptMatchStatus,
+};
 use identity::{StoreId, UserId};
 
 /// GoodsReceipt entity representing a document for receiving goods from a purchase order.
@@ -24,9 +26,12 @@ pub struct GoodsReceipt {
     receipt_date: NaiveDate,
     status: GoodsReceiptStatus,
     notes: Option<String>,
+    split_backorder: bool,
     received_by_id: UserId,
     confirmed_by_id: Option<UserId>,
     confirmed_at: Option<DateTime<Utc>>,
+    match_status: Option<ReceiptMatchStatus>,
+    match_overridden_by_id: Option<UserId>,
     items: Vec<GoodsReceiptItem>,
     created_at: DateTime<Utc>,
     updated_at: DateTime<Utc>,
@@ -50,9 +55,12 @@ impl GoodsReceipt {
             receipt_date,
             status: GoodsReceiptStatus::Draft,
             notes: None,
+            split_backorder: false,
             received_by_id,
             confirmed_by_id: None,
             confirmed_at: None,
+            match_status: None,
+            match_overridden_by_id: None,
             items: Vec::new(),
             created_at: now,
             updated_at: now,
@@ -69,9 +77,12 @@ impl GoodsReceipt {
         receipt_date: NaiveDate,
         status: GoodsReceiptStatus,
         notes: Option<String>,
+        split_backorder: bool,
         received_by_id: UserId,
         confirmed_by_id: Option<UserId>,
         confirmed_at: Option<DateTime<Utc>>,
+        match_status: Option<ReceiptMatchStatus>,
+        match_overridden_by_id: Option<UserId>,
         items: Vec<GoodsReceiptItem>,
         created_at: DateTime<Utc>,
         updated_at: DateTime<Utc>,
@@ -84,9 +95,12 @@ impl GoodsReceipt {
             receipt_date,
             status,
             notes,
+            split_backorder,
             received_by_id,
             confirmed_by_id,
             confirmed_at,
+            match_status,
+            match_overridden_by_id,
             items,
             created_at,
             updated_at,
@@ -114,6 +128,27 @@ impl GoodsReceipt {
         Ok(())
     }
 
+    /// Records the variances of a match against the purchase order.
+    /// `overridden_by` is the user who accepted a mismatch, if any.
+    pub fn record_match(&mut self, result: &ReceiptMatch, overridden_by: Option<UserId>) {
+        for line in &result.lines {
+            if let Some(item) = self
+                .items
+                .iter_mut()
+                .find(|item| item.id() == line.goods_receipt_item_id)
+            {
+                item.set_variances(line.quantity_variance, line.cost_variance);
+            }
+        }
+        self.match_status = Some(if overridden_by.is_some() {
+            ReceiptMatchStatus::Overridden
+        } else {
+            ReceiptMatchStatus::Matched
+        });
+        self.match_overridden_by_id = overridden_by;
+        self.updated_at = Utc::now();
+    }
+
     /// Cancels the receipt
     /// Transitions: draft → cancelled
     pub fn cancel(&mut self) -> Result<(), PurchasingError> {
@@ -199,6 +234,12 @@ impl GoodsReceipt {
         self.notes.as_deref()
     }
 
+    /// Whether confirming moves quantities still pending on the order into a
+    /// backorder instead of leaving the order partially received
+    pub fn split_backorder(&self) -> bool {
+        self.split_backorder
+    }
+
     pub fn received_by_id(&self) -> UserId {
         self.received_by_id
     }
@@ -211,6 +252,15 @@ impl GoodsReceipt {
         self.confirmed_at
     }
 
+    /// Outcome of the purchase order match; `None` if it wasn't matched
+    pub fn match_status(&self) -> Option<ReceiptMatchStatus> {
+        self.match_status
+    }
+
+    pub fn match_overridden_by_id(&self) -> Option<UserId> {
+        self.match_overridden_by_id
+    }
+
     pub fn items(&self) -> &[GoodsReceiptItem] {
         &self.items
     }
@@ -248,6 +298,15 @@ impl GoodsReceipt {
         self.updated_at = Utc::now();
         Ok(())
     }
+
+    pub fn set_split_backorder(&mut self, split: bool) -> Result<(), PurchasingError> {
+        if !self.is_editable() {
+            return Err(PurchasingError::ReceiptNotEditable);
+        }
+        self.split_backorder = split;
+        self.updated_at = Utc::now();
+        Ok(())
+    }
 }
 
 #[cfg(test)]
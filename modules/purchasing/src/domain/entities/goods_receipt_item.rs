@@ -1,9 +1,12 @@
 // GoodsReceiptItem entity - represents a line item in a goods receipt
 
+use std::collections::HashSet;
+
 use chrono::NaiveDate;
 use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 
+use crate::PurchasingError;
 use crate::domain::value_objects::{GoodsReceiptId, GoodsReceiptItemId, PurchaseOrderItemId};
 use inventory::{ProductId, VariantId};
 
@@ -12,6 +15,7 @@ use inventory::{ProductId, VariantId};
 /// Invariants:
 /// - Quantity received must be positive
 /// - Unit cost must be non-negative
+/// - Serial numbers, when given, are distinct and one per received unit
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct GoodsReceiptItem {
     id: GoodsReceiptItemId,
@@ -24,6 +28,9 @@ pub struct GoodsReceiptItem {
     lot_number: Option<String>,
     expiry_date: Option<NaiveDate>,
     notes: Option<String>,
+    serial_numbers: Vec<String>,
+    quantity_variance: Option<Decimal>,
+    cost_variance: Option<Decimal>,
 }
 
 impl GoodsReceiptItem {
@@ -48,6 +55,9 @@ impl GoodsReceiptItem {
             lot_number: None,
             expiry_date: None,
             notes: None,
+            serial_numbers: Vec::new(),
+            quantity_variance: None,
+            cost_variance: None,
         }
     }
 
@@ -64,6 +74,9 @@ impl GoodsReceiptItem {
         lot_number: Option<String>,
         expiry_date: Option<NaiveDate>,
         notes: Option<String>,
+        serial_numbers: Vec<String>,
+        quantity_variance: Option<Decimal>,
+        cost_variance: Option<Decimal>,
     ) -> Self {
         Self {
             id,
@@ -76,6 +89,9 @@ impl GoodsReceiptItem {
             lot_number,
             expiry_date,
             notes,
+            serial_numbers,
+            quantity_variance,
+            cost_variance,
         }
     }
 
@@ -128,6 +144,26 @@ impl GoodsReceiptItem {
         self.notes.as_deref()
     }
 
+    /// Quantity received past the ordered quantity, once matched
+    pub fn quantity_variance(&self) -> Option<Decimal> {
+        self.quantity_variance
+    }
+
+    /// Unit cost minus the ordered unit cost, once matched
+    pub fn cost_variance(&self) -> Option<Decimal> {
+        self.cost_variance
+    }
+
+    pub fn serial_numbers(&self) -> &[String] {
+        &self.serial_numbers
+    }
+
+    /// Returns true if every received unit has a serial recorded
+    pub fn has_complete_serials(&self) -> bool {
+        self.quantity_received.fract() == Decimal::ZERO
+            && Decimal::from(self.serial_numbers.len()) == self.quantity_received
+    }
+
     // =========================================================================
     // Setters
     // =========================================================================
@@ -148,9 +184,37 @@ impl GoodsReceiptItem {
         self.expiry_date = expiry_date;
     }
 
+    pub fn set_variances(&mut self, quantity_variance: Decimal, cost_variance: Decimal) {
+        self.quantity_variance = Some(quantity_variance);
+        self.cost_variance = Some(cost_variance);
+    }
+
     pub fn set_notes(&mut self, notes: Option<String>) {
         self.notes = notes;
     }
+
+    /// Records the serial numbers of the units received on this line.
+    ///
+    /// The quantity must be a whole number of units and each unit needs
+    /// exactly one distinct serial. Passing an empty list clears them.
+    pub fn set_serial_numbers(
+        &mut self,
+        serial_numbers: Vec<String>,
+    ) -> Result<(), PurchasingError> {
+        if !serial_numbers.is_empty() {
+            if self.quantity_received.fract() != Decimal::ZERO
+                || Decimal::from(serial_numbers.len()) != self.quantity_received
+            {
+                return Err(PurchasingError::SerialCountMismatch);
+            }
+            let mut seen = HashSet::new();
+            if let Some(dup) = serial_numbers.iter().find(|s| !seen.insert(s.as_str())) {
+                return Err(PurchasingError::DuplicateSerialNumber(dup.clone()));
+            }
+        }
+        self.serial_numbers = serial_numbers;
+        Ok(())
+    }
 }
 
 #[cfg(test)]
@@ -210,4 +274,31 @@ mod tests {
         );
         assert_eq!(item.notes(), Some("Test notes"));
     }
+
+    #[test]
+    fn test_set_serial_numbers() {
+        let mut item = GoodsReceiptItem::create(
+            GoodsReceiptId::new(),
+            PurchaseOrderItemId::new(),
+            ProductId::new(),
+            None,
+            dec!(2),
+            dec!(350.00),
+        );
+        assert!(!item.has_complete_serials());
+
+        assert!(matches!(
+            item.set_serial_numbers(vec!["SN-1".to_string()]),
+            Err(PurchasingError::SerialCountMismatch)
+        ));
+        assert!(matches!(
+            item.set_serial_numbers(vec!["SN-1".to_string(), "SN-1".to_string()]),
+            Err(PurchasingError::DuplicateSerialNumber(s)) if s == "SN-1"
+        ));
+
+        item.set_serial_numbers(vec!["SN-1".to_string(), "SN-2".to_string()])
+            .unwrap();
+        assert!(item.has_complete_serials());
+        assert_eq!(item.serial_numbers(), ["SN-1", "SN-2"]);
+    }
 }
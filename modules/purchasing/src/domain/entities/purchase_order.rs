@@ -28,6 +28,7 @@ pub struct PurchaseOrder {
     order_date: NaiveDate,
     expected_delivery_date: Option<NaiveDate>,
     received_date: Option<NaiveDate>,
+    first_received_date: Option<NaiveDate>,
     subtotal: Decimal,
     tax_amount: Decimal,
     discount_amount: Decimal,
@@ -39,12 +40,15 @@ pub struct PurchaseOrder {
     created_by_id: UserId,
     submitted_by_id: Option<UserId>,
     submitted_at: Option<DateTime<Utc>>,
+    first_approved_by_id: Option<UserId>,
+    first_approved_at: Option<DateTime<Utc>>,
     approved_by_id: Option<UserId>,
     approved_at: Option<DateTime<Utc>>,
     received_by_id: Option<UserId>,
     cancelled_by_id: Option<UserId>,
     cancelled_at: Option<DateTime<Utc>>,
     cancellation_reason: Option<String>,
+    parent_order_id: Option<PurchaseOrderId>,
     items: Vec<PurchaseOrderItem>,
     created_at: DateTime<Utc>,
     updated_at: DateTime<Utc>,
@@ -71,6 +75,7 @@ impl PurchaseOrder {
             order_date,
             expected_delivery_date: None,
             received_date: None,
+            first_received_date: None,
             subtotal: Decimal::ZERO,
             tax_amount: Decimal::ZERO,
             discount_amount: Decimal::ZERO,
@@ -82,12 +87,15 @@ impl PurchaseOrder {
             created_by_id,
             submitted_by_id: None,
             submitted_at: None,
+            first_approved_by_id: None,
+            first_approved_at: None,
             approved_by_id: None,
             approved_at: None,
             received_by_id: None,
             cancelled_by_id: None,
             cancelled_at: None,
             cancellation_reason: None,
+            parent_order_id: None,
             items: Vec::new(),
             created_at: now,
             updated_at: now,
@@ -105,6 +113,7 @@ impl PurchaseOrder {
         order_date: NaiveDate,
         expected_delivery_date: Option<NaiveDate>,
         received_date: Option<NaiveDate>,
+        first_received_date: Option<NaiveDate>,
         subtotal: Decimal,
         tax_amount: Decimal,
         discount_amount: Decimal,
@@ -116,12 +125,15 @@ impl PurchaseOrder {
         created_by_id: UserId,
         submitted_by_id: Option<UserId>,
         submitted_at: Option<DateTime<Utc>>,
+        first_approved_by_id: Option<UserId>,
+        first_approved_at: Option<DateTime<Utc>>,
         approved_by_id: Option<UserId>,
         approved_at: Option<DateTime<Utc>>,
         received_by_id: Option<UserId>,
         cancelled_by_id: Option<UserId>,
         cancelled_at: Option<DateTime<Utc>>,
         cancellation_reason: Option<String>,
+        parent_order_id: Option<PurchaseOrderId>,
         items: Vec<PurchaseOrderItem>,
         created_at: DateTime<Utc>,
         updated_at: DateTime<Utc>,
@@ -135,6 +147,7 @@ impl PurchaseOrder {
             order_date,
             expected_delivery_date,
             received_date,
+            first_received_date,
             subtotal,
             tax_amount,
             discount_amount,
@@ -146,12 +159,15 @@ impl PurchaseOrder {
             created_by_id,
             submitted_by_id,
             submitted_at,
+            first_approved_by_id,
+            first_approved_at,
             approved_by_id,
             approved_at,
             received_by_id,
             cancelled_by_id,
             cancelled_at,
             cancellation_reason,
+            parent_order_id,
             items,
             created_at,
             updated_at,
@@ -171,6 +187,12 @@ impl PurchaseOrder {
         if self.items.is_empty() {
             return Err(PurchasingError::EmptyPurchaseOrder);
         }
+        if self
+            .expected_delivery_date
+            .is_some_and(|date| date < Utc::now().date_naive())
+        {
+            return Err(PurchasingError::ExpectedDeliveryDateInPast);
+        }
 
         self.status = PurchaseOrderStatus::Submitted;
         self.submitted_by_id = Some(submitted_by_id);
@@ -179,6 +201,22 @@ impl PurchaseOrder {
         Ok(())
     }
 
+    /// Records the first of two approvals; the order stays submitted until a
+    /// different user approves it
+    pub fn approve_first(&mut self, approver_id: UserId) -> Result<(), PurchasingError> {
+        if !self.status.can_review() || self.first_approved_by_id.is_some() {
+            return Err(PurchasingError::InvalidStatusTransition);
+        }
+        if approver_id == self.created_by_id {
+            return Err(PurchasingError::CannotApproveSelfCreatedOrder);
+        }
+
+        self.first_approved_by_id = Some(approver_id);
+        self.first_approved_at = Some(Utc::now());
+        self.updated_at = Utc::now();
+        Ok(())
+    }
+
     /// Approves the order
     /// Transitions: submitted → approved
     pub fn approve(&mut self, approver_id: UserId) -> Result<(), PurchasingError> {
@@ -189,6 +227,9 @@ impl PurchaseOrder {
         if approver_id == self.created_by_id {
             return Err(PurchasingError::CannotApproveSelfCreatedOrder);
         }
+        if self.first_approved_by_id == Some(approver_id) {
+            return Err(PurchasingError::DuplicateApprover);
+        }
 
         self.status = PurchaseOrderStatus::Approved;
         self.approved_by_id = Some(approver_id);
@@ -207,20 +248,27 @@ impl PurchaseOrder {
         self.status = PurchaseOrderStatus::Draft;
         self.submitted_by_id = None;
         self.submitted_at = None;
+        self.first_approved_by_id = None;
+        self.first_approved_at = None;
         self.internal_notes = reason;
         self.updated_at = Utc::now();
         Ok(())
     }
 
-    /// Marks the order as partially received
+    /// Marks the order as partially received on `received_date`
     /// Transitions: approved → partially_received
-    pub fn receive_partial(&mut self, received_by_id: UserId) -> Result<(), PurchasingError> {
+    pub fn receive_partial(
+        &mut self,
+        received_by_id: UserId,
+        received_date: NaiveDate,
+    ) -> Result<(), PurchasingError> {
         if !self.status.can_receive() {
             return Err(PurchasingError::InvalidStatusTransition);
         }
 
         self.status = PurchaseOrderStatus::PartiallyReceived;
         self.received_by_id = Some(received_by_id);
+        self.first_received_date.get_or_insert(received_date);
         self.updated_at = Utc::now();
         Ok(())
     }
@@ -239,10 +287,75 @@ impl PurchaseOrder {
         self.status = PurchaseOrderStatus::Received;
         self.received_by_id = Some(received_by_id);
         self.received_date = Some(received_date);
+        self.first_received_date.get_or_insert(received_date);
         self.updated_at = Utc::now();
         Ok(())
     }
 
+    /// Moves the quantities still pending into a new draft backorder.
+    ///
+    /// The backorder copies vendor, store, currency, payment terms, notes and
+    /// line prices, and links back to this order through `parent_order_id`.
+    /// This order is then marked as received, since what is still owed lives
+    /// on the backorder. Returns `None` and leaves this order untouched when
+    /// nothing is pending.
+    ///
+    /// Transitions: approved/partially_received → received
+    pub fn split_backorder(
+        &mut self,
+        order_number: String,
+        created_by_id: UserId,
+        received_date: NaiveDate,
+    ) -> Result<Option<PurchaseOrder>, PurchasingError> {
+        if !self.status.can_receive() {
+            return Err(PurchasingError::InvalidStatusTransition);
+        }
+
+        let pending: Vec<&PurchaseOrderItem> = self
+            .items
+            .iter()
+            .filter(|item| item.quantity_pending() > Decimal::ZERO)
+            .collect();
+        if pending.is_empty() {
+            return Ok(None);
+        }
+
+        let mut backorder = PurchaseOrder::create(
+            order_number,
+            self.store_id,
+            self.vendor_id,
+            received_date,
+            self.currency.clone(),
+            self.payment_terms_days,
+            created_by_id,
+        );
+        backorder.parent_order_id = Some(self.id);
+        backorder.notes = self.notes.clone();
+
+        for (index, item) in pending.into_iter().enumerate() {
+            let mut line = PurchaseOrderItem::create(
+                backorder.id,
+                (index + 1) as i32,
+                item.product_id(),
+                item.variant_id(),
+                item.description().to_string(),
+                item.quantity_pending(),
+                *item.unit_of_measure(),
+                item.unit_cost(),
+                item.discount_percent(),
+                item.tax_percent(),
+            );
+            if let (Some(pack_size), Some(pack_price)) = (item.pack_size(), item.pack_price()) {
+                line.set_pack_pricing(pack_size, pack_price)?;
+            }
+            line.set_notes(item.notes().map(|n| n.to_string()));
+            backorder.add_item(line)?;
+        }
+
+        self.receive_complete(created_by_id, received_date)?;
+        Ok(Some(backorder))
+    }
+
     /// Closes the order
     /// Transitions: received → closed
     pub fn close(&mut self) -> Result<(), PurchasingError> {
@@ -310,7 +423,7 @@ impl PurchaseOrder {
         let mut discount_amount = Decimal::ZERO;
 
         for item in &self.items {
-            let item_subtotal = item.quantity_ordered() * item.unit_cost();
+            let item_subtotal = item.quantity_ordered() * item.ordered_unit_cost();
             let item_discount = item_subtotal * (item.discount_percent() / Decimal::from(100));
             let after_discount = item_subtotal - item_discount;
             let item_tax = after_discount * (item.tax_percent() / Decimal::from(100));
@@ -388,6 +501,23 @@ impl PurchaseOrder {
         self.received_date
     }
 
+    /// Date the first goods receipt against the order was confirmed
+    pub fn first_received_date(&self) -> Option<NaiveDate> {
+        self.first_received_date
+    }
+
+    /// Days the order is past its expected delivery date: up to the date it
+    /// was fully received, or up to `today` while still pending. Zero when on
+    /// time; `None` without an expected date or once cancelled.
+    pub fn days_late(&self, today: NaiveDate) -> Option<i64> {
+        let expected = self.expected_delivery_date?;
+        if self.status == PurchaseOrderStatus::Cancelled {
+            return None;
+        }
+        let delivered = self.received_date.unwrap_or(today);
+        Some((delivered - expected).num_days().max(0))
+    }
+
     pub fn subtotal(&self) -> Decimal {
         self.subtotal
     }
@@ -432,6 +562,14 @@ impl PurchaseOrder {
         self.submitted_at
     }
 
+    pub fn first_approved_by_id(&self) -> Option<UserId> {
+        self.first_approved_by_id
+    }
+
+    pub fn first_approved_at(&self) -> Option<DateTime<Utc>> {
+        self.first_approved_at
+    }
+
     pub fn approved_by_id(&self) -> Option<UserId> {
         self.approved_by_id
     }
@@ -456,6 +594,10 @@ impl PurchaseOrder {
         self.cancellation_reason.as_deref()
     }
 
+    pub fn parent_order_id(&self) -> Option<PurchaseOrderId> {
+        self.parent_order_id
+    }
+
     pub fn items(&self) -> &[PurchaseOrderItem] {
         &self.items
     }
@@ -656,6 +798,43 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn test_dual_approval_needs_distinct_approvers() {
+        let mut order = create_test_order();
+        let item = create_test_item(order.id());
+        order.add_item(item).unwrap();
+        order.submit(UserId::new()).unwrap();
+
+        let first = UserId::new();
+        order.approve_first(first).unwrap();
+        assert_eq!(order.status(), PurchaseOrderStatus::Submitted);
+        assert_eq!(order.first_approved_by_id(), Some(first));
+
+        assert!(matches!(
+            order.approve(first),
+            Err(PurchasingError::DuplicateApprover)
+        ));
+
+        let second = UserId::new();
+        order.approve(second).unwrap();
+        assert_eq!(order.status(), PurchaseOrderStatus::Approved);
+        assert_eq!(order.approved_by_id(), Some(second));
+    }
+
+    #[test]
+    fn test_reject_clears_first_approval() {
+        let mut order = create_test_order();
+        let item = create_test_item(order.id());
+        order.add_item(item).unwrap();
+        order.submit(UserId::new()).unwrap();
+        order.approve_first(UserId::new()).unwrap();
+
+        order.reject(None).unwrap();
+
+        assert!(order.first_approved_by_id().is_none());
+        assert!(order.first_approved_at().is_none());
+    }
+
     #[test]
     fn test_reject_workflow() {
         let mut order = create_test_order();
@@ -698,7 +877,8 @@ mod tests {
         order.approve(UserId::new()).unwrap();
 
         let receiver = UserId::new();
-        order.receive_partial(receiver).unwrap();
+        let first = NaiveDate::from_ymd_opt(2024, 1, 22).unwrap();
+        order.receive_partial(receiver, first).unwrap();
         assert_eq!(order.status(), PurchaseOrderStatus::PartiallyReceived);
 
         order
@@ -706,6 +886,101 @@ mod tests {
             .unwrap();
         assert_eq!(order.status(), PurchaseOrderStatus::Received);
         assert!(order.received_date().is_some());
+        assert_eq!(order.first_received_date(), Some(first));
+    }
+
+    #[test]
+    fn test_days_late() {
+        let date = |day| NaiveDate::from_ymd_opt(2024, 1, day).unwrap();
+        let mut order = create_test_order();
+        assert_eq!(order.days_late(date(31)), None);
+
+        order.set_expected_delivery_date(Some(date(25))).unwrap();
+        assert_eq!(order.days_late(date(24)), Some(0));
+        assert_eq!(order.days_late(date(28)), Some(3));
+
+        order.add_item(create_test_item(order.id())).unwrap();
+        order.expected_delivery_date = None;
+        order.submit(UserId::new()).unwrap();
+        order.approve(UserId::new()).unwrap();
+        order.expected_delivery_date = Some(date(25));
+        order.receive_complete(UserId::new(), date(27)).unwrap();
+        // Counted up to the receipt, not up to today
+        assert_eq!(order.days_late(date(31)), Some(2));
+    }
+
+    #[test]
+    fn test_submit_rejects_past_expected_delivery_date() {
+        let mut order = create_test_order();
+        order.add_item(create_test_item(order.id())).unwrap();
+        let yesterday = Utc::now().date_naive().pred_opt().unwrap();
+        order.set_expected_delivery_date(Some(yesterday)).unwrap();
+
+        let result = order.submit(UserId::new());
+        assert!(matches!(
+            result,
+            Err(PurchasingError::ExpectedDeliveryDateInPast)
+        ));
+
+        order
+            .set_expected_delivery_date(Some(Utc::now().date_naive()))
+            .unwrap();
+        assert!(order.submit(UserId::new()).is_ok());
+    }
+
+    #[test]
+    fn test_split_backorder_moves_pending_quantities() {
+        let mut order = create_test_order();
+        order.set_notes(Some("Dock B".to_string())).unwrap();
+        let item = create_test_item(order.id());
+        order.add_item(item).unwrap();
+        order.submit(UserId::new()).unwrap();
+        order.approve(UserId::new()).unwrap();
+        order.items_mut()[0].add_received_quantity(dec!(4));
+
+        let buyer = UserId::new();
+        let received = NaiveDate::from_ymd_opt(2024, 1, 25).unwrap();
+        let backorder = order
+            .split_backorder("PO-2024-00002".to_string(), buyer, received)
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(order.status(), PurchaseOrderStatus::Received);
+        assert_eq!(backorder.status(), PurchaseOrderStatus::Draft);
+        assert_eq!(backorder.parent_order_id(), Some(order.id()));
+        assert_eq!(backorder.vendor_id(), order.vendor_id());
+        assert_eq!(backorder.store_id(), order.store_id());
+        assert_eq!(backorder.notes(), Some("Dock B"));
+        assert_eq!(backorder.created_by_id(), buyer);
+        assert_eq!(backorder.order_date(), received);
+        assert_eq!(backorder.items().len(), 1);
+        let line = &backorder.items()[0];
+        assert_eq!(line.purchase_order_id(), backorder.id());
+        assert_eq!(line.quantity_ordered(), dec!(6));
+        assert_eq!(line.quantity_received(), Decimal::ZERO);
+        assert_eq!(line.unit_cost(), dec!(100.00));
+        assert_eq!(line.tax_percent(), dec!(15));
+    }
+
+    #[test]
+    fn test_split_backorder_without_pending_quantities() {
+        let mut order = create_test_order();
+        let item = create_test_item(order.id());
+        order.add_item(item).unwrap();
+        order.submit(UserId::new()).unwrap();
+        order.approve(UserId::new()).unwrap();
+        order.items_mut()[0].add_received_quantity(dec!(10));
+
+        let backorder = order
+            .split_backorder(
+                "PO-2024-00002".to_string(),
+                UserId::new(),
+                NaiveDate::from_ymd_opt(2024, 1, 25).unwrap(),
+            )
+            .unwrap();
+
+        assert!(backorder.is_none());
+        assert_eq!(order.status(), PurchaseOrderStatus::Approved);
     }
 
     #[test]
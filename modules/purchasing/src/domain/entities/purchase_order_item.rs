@@ -3,6 +3,7 @@
 use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 
+use crate::PurchasingError;
 use crate::domain::value_objects::{PurchaseOrderId, PurchaseOrderItemId};
 use inventory::{ProductId, UnitOfMeasure, VariantId};
 
@@ -12,6 +13,10 @@ use inventory::{ProductId, UnitOfMeasure, VariantId};
 /// - Quantity ordered must be positive
 /// - Unit cost must be non-negative
 /// - Quantity received cannot exceed quantity ordered
+///
+/// Lines priced in vendor packs order and receive whole packs: the quantities
+/// count packs, `pack_price` is what a pack costs and `unit_cost` is the
+/// derived cost of one stocking unit inside it, used for inventory.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PurchaseOrderItem {
     id: PurchaseOrderItemId,
@@ -28,6 +33,8 @@ pub struct PurchaseOrderItem {
     tax_percent: Decimal,
     line_total: Decimal,
     notes: Option<String>,
+    pack_size: Option<Decimal>,
+    pack_price: Option<Decimal>,
 }
 
 impl PurchaseOrderItem {
@@ -63,6 +70,8 @@ impl PurchaseOrderItem {
             tax_percent,
             line_total,
             notes: None,
+            pack_size: None,
+            pack_price: None,
         }
     }
 
@@ -83,6 +92,8 @@ impl PurchaseOrderItem {
         tax_percent: Decimal,
         line_total: Decimal,
         notes: Option<String>,
+        pack_size: Option<Decimal>,
+        pack_price: Option<Decimal>,
     ) -> Self {
         Self {
             id,
@@ -99,6 +110,8 @@ impl PurchaseOrderItem {
             tax_percent,
             line_total,
             notes,
+            pack_size,
+            pack_price,
         }
     }
 
@@ -120,12 +133,47 @@ impl PurchaseOrderItem {
     fn recalculate_line_total(&mut self) {
         self.line_total = Self::calculate_line_total(
             self.quantity_ordered,
-            self.unit_cost,
+            self.ordered_unit_cost(),
             self.discount_percent,
             self.tax_percent,
         );
     }
 
+    /// Prices the line in vendor packs of `pack_size` stocking units at
+    /// `pack_price` each; the unit cost becomes the price of one stocking
+    /// unit, rounded to 4 decimal places
+    pub fn set_pack_pricing(
+        &mut self,
+        pack_size: Decimal,
+        pack_price: Decimal,
+    ) -> Result<(), PurchasingError> {
+        if pack_size <= Decimal::ZERO {
+            return Err(PurchasingError::InvalidPackPricing(
+                "pack_size must be positive".to_string(),
+            ));
+        }
+        if pack_price < Decimal::ZERO {
+            return Err(PurchasingError::InvalidUnitCost);
+        }
+        self.pack_size = Some(pack_size);
+        self.pack_price = Some(pack_price);
+        self.unit_cost = (pack_price / pack_size).round_dp(4);
+        self.recalculate_line_total();
+        Ok(())
+    }
+
+    /// Cost of one ordered unit: the pack price for lines priced in packs,
+    /// otherwise the unit cost
+    pub fn ordered_unit_cost(&self) -> Decimal {
+        self.pack_price.unwrap_or(self.unit_cost)
+    }
+
+    /// Stocking units in one ordered unit: the pack size for lines priced in
+    /// packs, otherwise one
+    pub fn units_per_ordered_unit(&self) -> Decimal {
+        self.pack_size.unwrap_or(Decimal::ONE)
+    }
+
     /// Returns the pending quantity (ordered - received)
     pub fn quantity_pending(&self) -> Decimal {
         self.quantity_ordered - self.quantity_received
@@ -201,6 +249,14 @@ impl PurchaseOrderItem {
         self.notes.as_deref()
     }
 
+    pub fn pack_size(&self) -> Option<Decimal> {
+        self.pack_size
+    }
+
+    pub fn pack_price(&self) -> Option<Decimal> {
+        self.pack_price
+    }
+
     // =========================================================================
     // Setters
     // =========================================================================
@@ -218,8 +274,11 @@ impl PurchaseOrderItem {
         self.recalculate_line_total();
     }
 
+    /// Sets the cost per unit, dropping any pack pricing
     pub fn set_unit_cost(&mut self, cost: Decimal) {
         self.unit_cost = cost;
+        self.pack_size = None;
+        self.pack_price = None;
         self.recalculate_line_total();
     }
 
@@ -317,4 +376,47 @@ mod tests {
         // 20 * 100 * 1.15 = 2300
         assert_eq!(item.line_total(), dec!(2300.00));
     }
+
+    #[test]
+    fn test_pack_pricing() {
+        let mut item = create_test_item();
+        item.set_pack_pricing(dec!(24), dec!(60)).unwrap();
+
+        assert_eq!(item.pack_size(), Some(dec!(24)));
+        assert_eq!(item.pack_price(), Some(dec!(60)));
+        assert_eq!(item.unit_cost(), dec!(2.5));
+        assert_eq!(item.ordered_unit_cost(), dec!(60));
+        assert_eq!(item.units_per_ordered_unit(), dec!(24));
+        // 10 packs * 60 = 600, +15% tax = 690
+        assert_eq!(item.line_total(), dec!(690.00));
+
+        item.set_unit_cost(dec!(3));
+        assert_eq!(item.pack_size(), None);
+        assert_eq!(item.units_per_ordered_unit(), dec!(1));
+        assert_eq!(item.line_total(), dec!(34.50));
+    }
+
+    #[test]
+    fn test_pack_pricing_rounds_unit_cost() {
+        let mut item = create_test_item();
+        item.set_pack_pricing(dec!(3), dec!(10)).unwrap();
+
+        assert_eq!(item.unit_cost(), dec!(3.3333));
+        // The line is priced per pack, not from the rounded unit cost
+        assert_eq!(item.line_total(), dec!(115.00));
+    }
+
+    #[test]
+    fn test_pack_pricing_validation() {
+        let mut item = create_test_item();
+        assert!(matches!(
+            item.set_pack_pricing(dec!(0), dec!(60)),
+            Err(PurchasingError::InvalidPackPricing(_))
+        ));
+        assert!(matches!(
+            item.set_pack_pricing(dec!(24), dec!(-1)),
+            Err(PurchasingError::InvalidUnitCost)
+        ));
+        assert_eq!(item.pack_size(), None);
+    }
 }
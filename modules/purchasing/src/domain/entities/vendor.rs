@@ -5,6 +5,7 @@ use serde::{Deserialize, Serialize};
 
 use crate::PurchasingError;
 use crate::domain::value_objects::VendorId;
+use common::TaxId;
 use inventory::Currency;
 
 /// Vendor entity representing a supplier in the purchasing system.
@@ -20,6 +21,7 @@ pub struct Vendor {
     name: String,
     legal_name: String,
     tax_id: String,
+    tax_id_country: Option<String>,
     email: Option<String>,
     phone: Option<String>,
     address: Option<String>,
@@ -47,6 +49,7 @@ impl Vendor {
             name,
             legal_name,
             tax_id,
+            tax_id_country: None,
             email: None,
             phone: None,
             address: None,
@@ -67,6 +70,7 @@ impl Vendor {
         name: String,
         legal_name: String,
         tax_id: String,
+        tax_id_country: Option<String>,
         email: Option<String>,
         phone: Option<String>,
         address: Option<String>,
@@ -83,6 +87,7 @@ impl Vendor {
             name,
             legal_name,
             tax_id,
+            tax_id_country,
             email,
             phone,
             address,
@@ -139,6 +144,11 @@ impl Vendor {
         &self.tax_id
     }
 
+    /// Country the tax id was validated for
+    pub fn tax_id_country(&self) -> Option<&str> {
+        self.tax_id_country.as_deref()
+    }
+
     pub fn email(&self) -> Option<&str> {
         self.email.as_deref()
     }
@@ -194,8 +204,10 @@ impl Vendor {
         self.updated_at = Utc::now();
     }
 
-    pub fn set_tax_id(&mut self, tax_id: String) {
-        self.tax_id = tax_id;
+    /// Sets a validated tax id and the country it was validated for
+    pub fn set_tax_id(&mut self, tax_id: TaxId) {
+        self.tax_id = tax_id.value().to_string();
+        self.tax_id_country = Some(tax_id.country().to_string());
         self.updated_at = Utc::now();
     }
 
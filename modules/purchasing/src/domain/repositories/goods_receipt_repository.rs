@@ -1,21 +1,48 @@
 // GoodsReceiptRepository trait - repository for goods receipt operations
 
 use async_trait::async_trait;
+use chrono::{DateTime, NaiveDate, Utc};
+use rust_decimal::Decimal;
 
 use crate::PurchasingError;
 use crate::domain::entities::GoodsReceipt;
-use crate::domain::value_objects::{GoodsReceiptId, GoodsReceiptStatus, PurchaseOrderId};
+use crate::domain::value_objects::{GoodsReceiptId, GoodsReceiptStatus, PurchaseOrderId, VendorId};
 use identity::StoreId;
+use inventory::{ProductId, VariantId};
 
 /// Filter options for listing goods receipts
 #[derive(Debug, Clone, Default)]
 pub struct GoodsReceiptFilter {
     /// Filter by store ID
     pub store_id: Option<StoreId>,
+    /// Restrict to these stores (the caller's assigned stores); `None`
+    /// leaves the store unrestricted
+    pub store_ids: Option<Vec<StoreId>>,
     /// Filter by purchase order ID
     pub purchase_order_id: Option<PurchaseOrderId>,
     /// Filter by status
     pub status: Option<GoodsReceiptStatus>,
+    /// Keyset cursor: only receipts with a smaller id, newest id first. The
+    /// page offset is ignored when set.
+    pub after_id: Option<GoodsReceiptId>,
+}
+
+/// A confirmed receipt line, as used for purchase price history
+#[derive(Debug, Clone)]
+pub struct ReceivedUnitCost {
+    pub goods_receipt_id: GoodsReceiptId,
+    pub receipt_number: String,
+    pub receipt_date: NaiveDate,
+    pub confirmed_at: Option<DateTime<Utc>>,
+    pub store_id: StoreId,
+    pub purchase_order_id: PurchaseOrderId,
+    pub order_number: String,
+    pub vendor_id: VendorId,
+    pub vendor_name: String,
+    /// Currency of the purchase order the receipt belongs to
+    pub currency: String,
+    pub quantity_received: Decimal,
+    pub unit_cost: Decimal,
 }
 
 /// Repository trait for GoodsReceipt persistence operations.
@@ -43,6 +70,13 @@ pub trait GoodsReceiptRepository: Send + Sync {
         order_id: PurchaseOrderId,
     ) -> Result<Vec<GoodsReceipt>, PurchasingError>;
 
+    /// Returns which of `order_ids` have at least one goods receipt, in any
+    /// status
+    async fn find_orders_with_receipts(
+        &self,
+        order_ids: &[PurchaseOrderId],
+    ) -> Result<Vec<PurchaseOrderId>, PurchasingError>;
+
     /// Finds goods receipts with pagination and filters
     ///
     /// # Arguments
@@ -59,6 +93,19 @@ pub trait GoodsReceiptRepository: Send + Sync {
         page_size: i64,
     ) -> Result<(Vec<GoodsReceipt>, i64), PurchasingError>;
 
+    /// Finds the last `limit` confirmed receipt lines for a product, newest
+    /// first. When `variant_id` is set only that variant's lines are returned.
+    ///
+    /// Receipts from every store in the organization that owns `store_id`
+    /// are considered, so a buyer sees chain-wide costs.
+    async fn find_recent_unit_costs(
+        &self,
+        product_id: ProductId,
+        variant_id: Option<VariantId>,
+        store_id: StoreId,
+        limit: i64,
+    ) -> Result<Vec<ReceivedUnitCost>, PurchasingError>;
+
     /// Generates a unique receipt number for a store
     /// Format: GR-{YEAR}-{SEQUENCE}
     async fn generate_receipt_number(&self, store_id: StoreId) -> Result<String, PurchasingError>;
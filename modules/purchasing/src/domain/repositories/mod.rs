@@ -13,6 +13,8 @@ mod goods_receipt_repository;
 mod purchase_order_repository;
 mod vendor_repository;
 
-pub use goods_receipt_repository::{GoodsReceiptFilter, GoodsReceiptRepository};
-pub use purchase_order_repository::{PurchaseOrderFilter, PurchaseOrderRepository};
+pub use goods_receipt_repository::{GoodsReceiptFilter, GoodsReceiptRepository, ReceivedUnitCost};
+pub use purchase_order_repository::{
+    PurchaseOrderFilter, PurchaseOrderRepository, StalePurchaseOrderFilter, VendorOrderDelivery,
+};
 pub use vendor_repository::{VendorFilter, VendorRepository};
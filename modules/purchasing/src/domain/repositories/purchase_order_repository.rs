@@ -1,6 +1,8 @@
 // PurchaseOrderRepository trait - repository for purchase order operations
 
 use async_trait::async_trait;
+use chrono::{DateTime, NaiveDate, Utc};
+use rust_decimal::Decimal;
 
 use crate::PurchasingError;
 use crate::domain::entities::{PurchaseOrder, PurchaseOrderItem};
@@ -14,12 +16,49 @@ use identity::StoreId;
 pub struct PurchaseOrderFilter {
     /// Filter by store ID
     pub store_id: Option<StoreId>,
+    /// Restrict to these stores (the caller's assigned stores); `None`
+    /// leaves the store unrestricted
+    pub store_ids: Option<Vec<StoreId>>,
     /// Filter by vendor ID
     pub vendor_id: Option<VendorId>,
     /// Filter by status
     pub status: Option<PurchaseOrderStatus>,
     /// Search by order number
     pub search: Option<String>,
+    /// Only orders still awaiting goods (approved or partially received)
+    /// whose expected delivery date is before this date
+    pub overdue_as_of: Option<NaiveDate>,
+    /// Keyset cursor: only orders with a smaller id, newest id first. The
+    /// page offset is ignored when set.
+    pub after_id: Option<PurchaseOrderId>,
+}
+
+/// Selects the purchase orders a bulk cancel applies to
+#[derive(Debug, Clone)]
+pub struct StalePurchaseOrderFilter {
+    pub store_id: StoreId,
+    /// Statuses to match; each must be cancellable
+    pub statuses: Vec<PurchaseOrderStatus>,
+    /// Only orders not updated since this instant
+    pub updated_before: DateTime<Utc>,
+    pub vendor_id: Option<VendorId>,
+}
+
+/// Delivery history of one purchase order, as used for vendor performance
+#[derive(Debug, Clone)]
+pub struct VendorOrderDelivery {
+    pub purchase_order_id: PurchaseOrderId,
+    pub vendor_id: VendorId,
+    pub vendor_name: String,
+    pub order_date: NaiveDate,
+    pub expected_delivery_date: Option<NaiveDate>,
+    /// Σ ordered quantity over the order's lines
+    pub quantity_ordered: Decimal,
+    /// Σ received quantity over the order's lines
+    pub quantity_received: Decimal,
+    /// Earliest and latest confirmed receipt dates; `None` if nothing arrived
+    pub first_receipt_date: Option<NaiveDate>,
+    pub last_receipt_date: Option<NaiveDate>,
 }
 
 /// Repository trait for PurchaseOrder persistence operations.
@@ -28,6 +67,10 @@ pub trait PurchaseOrderRepository: Send + Sync {
     /// Saves a new purchase order to the repository
     async fn save(&self, order: &PurchaseOrder) -> Result<(), PurchasingError>;
 
+    /// Saves new purchase orders with their items in a single transaction;
+    /// if any of them fails, none is saved
+    async fn save_many(&self, orders: &[PurchaseOrder]) -> Result<(), PurchasingError>;
+
     /// Finds a purchase order by its unique ID (without items)
     async fn find_by_id(
         &self,
@@ -66,6 +109,27 @@ pub trait PurchaseOrderRepository: Send + Sync {
         page_size: i64,
     ) -> Result<(Vec<PurchaseOrder>, i64), PurchasingError>;
 
+    /// Finds the orders matching a bulk cancel filter (without items),
+    /// oldest first
+    async fn find_stale(
+        &self,
+        filter: &StalePurchaseOrderFilter,
+    ) -> Result<Vec<PurchaseOrder>, PurchasingError>;
+
+    /// Records the cancellation of every order in one transaction. Fails
+    /// without cancelling any if one of them is no longer cancellable or
+    /// has a goods receipt.
+    async fn cancel_many(&self, orders: &[PurchaseOrder]) -> Result<(), PurchasingError>;
+
+    /// Finds the delivery history of the orders placed between `from` and
+    /// `to` (inclusive) that were approved, optionally in one store
+    async fn find_vendor_deliveries(
+        &self,
+        store_id: Option<StoreId>,
+        from: NaiveDate,
+        to: NaiveDate,
+    ) -> Result<Vec<VendorOrderDelivery>, PurchasingError>;
+
     /// Generates a unique order number for a store
     /// Format: PO-{YEAR}-{SEQUENCE}
     async fn generate_order_number(&self, store_id: StoreId) -> Result<String, PurchasingError>;
@@ -13,6 +13,9 @@ pub struct VendorFilter {
     pub is_active: Option<bool>,
     /// Search by name or code
     pub search: Option<String>,
+    /// Keyset cursor: only vendors sorted after this one by name. The page
+    /// offset is ignored when set.
+    pub after_id: Option<VendorId>,
 }
 
 /// Repository trait for Vendor persistence operations.
@@ -17,6 +17,12 @@
 //!
 //! - [`PurchaseOrderStatus`]: Purchase order workflow states
 //! - [`GoodsReceiptStatus`]: Goods receipt workflow states
+//! - [`ReceiptMatchStatus`]: Outcome of matching a receipt against its order
+//!
+//! ## Policy Value Objects
+//!
+//! - [`PurchaseApprovalPolicy`]: Spend limits and dual approval for purchase orders
+//! - [`ReceiptMatchPolicy`]: Tolerances for matching receipts against orders
 
 // ID value objects
 mod goods_receipt_id;
@@ -28,6 +34,11 @@ mod vendor_id;
 // Enum value objects
 mod goods_receipt_status;
 mod purchase_order_status;
+mod receipt_match_status;
+
+// Policy value objects
+mod purchase_approval_policy;
+mod receipt_match_policy;
 
 // Re-exports - ID value objects
 pub use goods_receipt_id::GoodsReceiptId;
@@ -39,3 +50,8 @@ pub use vendor_id::VendorId;
 // Re-exports - Enum value objects
 pub use goods_receipt_status::GoodsReceiptStatus;
 pub use purchase_order_status::PurchaseOrderStatus;
+pub use receipt_match_status::ReceiptMatchStatus;
+
+// Re-exports - Policy value objects
+pub use purchase_approval_policy::{PurchaseApprovalPolicy, PurchaseApprovalTier};
+pub use receipt_match_policy::{ReceiptLineVariance, ReceiptMatch, ReceiptMatchPolicy};
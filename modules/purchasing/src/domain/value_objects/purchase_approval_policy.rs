@@ -0,0 +1,173 @@
+// PurchaseApprovalPolicy value object - spend limits per approval tier and
+// the total above which a purchase order needs two approvers
+
+use std::collections::HashSet;
+
+use rust_decimal::Decimal;
+
+use crate::PurchasingError;
+use identity::PermissionCode;
+
+/// Approval tier: holders of `permission` may approve purchase orders whose
+/// total is at most `limit`. A tier without a limit approves any total.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PurchaseApprovalTier {
+    pub permission: PermissionCode,
+    pub limit: Option<Decimal>,
+}
+
+/// Spend limits for approving purchase orders.
+///
+/// An approver's limit is the highest limit among the tiers whose permission
+/// they hold; an approver holding none of them can't approve any order. A
+/// total at the limit is still within it. Orders whose total exceeds the dual
+/// approval threshold need two distinct approvers, each within their own
+/// limit. The disabled policy has no tiers and no threshold, so any approver
+/// can approve any order alone.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PurchaseApprovalPolicy {
+    tiers: Vec<PurchaseApprovalTier>,
+    dual_approval_threshold: Option<Decimal>,
+}
+
+impl PurchaseApprovalPolicy {
+    /// Policy without spend limits or dual approval
+    pub fn disabled() -> Self {
+        Self::default()
+    }
+
+    /// Adds a tier; `None` means no limit
+    pub fn with_tier(mut self, permission: PermissionCode, limit: Option<Decimal>) -> Self {
+        self.tiers.push(PurchaseApprovalTier { permission, limit });
+        self
+    }
+
+    /// Orders with a total above `threshold` need a second approver
+    pub fn with_dual_approval_threshold(mut self, threshold: Decimal) -> Self {
+        self.dual_approval_threshold = Some(threshold);
+        self
+    }
+
+    pub fn tiers(&self) -> &[PurchaseApprovalTier] {
+        &self.tiers
+    }
+
+    pub fn dual_approval_threshold(&self) -> Option<Decimal> {
+        self.dual_approval_threshold
+    }
+
+    /// Returns true if an approver holding `permissions` may approve `total`
+    pub fn allows(&self, total: Decimal, permissions: &HashSet<PermissionCode>) -> bool {
+        if self.tiers.is_empty() {
+            return true;
+        }
+        self.tiers
+            .iter()
+            .filter(|tier| permissions.contains(&tier.permission))
+            .any(|tier| tier.limit.is_none_or(|limit| total <= limit))
+    }
+
+    /// Checks that an approver holding `permissions` may approve `total`
+    ///
+    /// # Errors
+    /// * `PurchasingError::ApprovalLimitExceeded` - With the lowest tier
+    ///   whose limit covers `total`, or the highest tier if none does
+    pub fn check(
+        &self,
+        total: Decimal,
+        permissions: &HashSet<PermissionCode>,
+    ) -> Result<(), PurchasingError> {
+        if self.allows(total, permissions) {
+            return Ok(());
+        }
+        let covering = self
+            .tiers
+            .iter()
+            .filter(|tier| tier.limit.is_none_or(|limit| total <= limit))
+            .min_by_key(|tier| tier.limit.unwrap_or(Decimal::MAX));
+        let required = covering.or_else(|| {
+            self.tiers
+                .iter()
+                .max_by_key(|tier| tier.limit.unwrap_or(Decimal::MAX))
+        });
+        Err(PurchasingError::ApprovalLimitExceeded {
+            total,
+            required_tier: required
+                .map(|tier| tier.permission.as_str().to_string())
+                .unwrap_or_default(),
+        })
+    }
+
+    /// Returns true if an order with `total` needs two distinct approvers
+    pub fn requires_second_approval(&self, total: Decimal) -> bool {
+        self.dual_approval_threshold
+            .is_some_and(|threshold| total > threshold)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    fn code(code: &str) -> PermissionCode {
+        PermissionCode::new(code).unwrap()
+    }
+
+    fn tiered() -> PurchaseApprovalPolicy {
+        PurchaseApprovalPolicy::disabled()
+            .with_tier(code("purchase_orders:approve"), Some(dec!(1000)))
+            .with_tier(code("purchase_orders:approve_tier2"), Some(dec!(10000)))
+            .with_tier(code("purchase_orders:approve_tier3"), None)
+    }
+
+    #[test]
+    fn test_disabled_policy_allows_any_total() {
+        let policy = PurchaseApprovalPolicy::disabled();
+        assert!(policy.allows(dec!(1000000), &HashSet::new()));
+        assert!(!policy.requires_second_approval(dec!(1000000)));
+    }
+
+    #[test]
+    fn test_limit_is_highest_held_tier() {
+        let policy = tiered();
+        let base = HashSet::from([code("purchase_orders:approve")]);
+        let tier2 = HashSet::from([
+            code("purchase_orders:approve"),
+            code("purchase_orders:approve_tier2"),
+        ]);
+        let tier3 = HashSet::from([code("purchase_orders:approve_tier3")]);
+
+        assert!(policy.allows(dec!(1000), &base));
+        assert!(!policy.allows(dec!(1000.01), &base));
+        assert!(policy.allows(dec!(10000), &tier2));
+        assert!(policy.allows(dec!(1000000), &tier3));
+        assert!(!policy.allows(dec!(1), &HashSet::new()));
+    }
+
+    #[test]
+    fn test_check_names_required_tier() {
+        let policy = tiered();
+        let base = HashSet::from([code("purchase_orders:approve")]);
+
+        match policy.check(dec!(5000), &base) {
+            Err(PurchasingError::ApprovalLimitExceeded { required_tier, .. }) => {
+                assert_eq!(required_tier, "purchase_orders:approve_tier2");
+            }
+            other => panic!("unexpected result: {:?}", other),
+        }
+        match policy.check(dec!(50000), &base) {
+            Err(PurchasingError::ApprovalLimitExceeded { required_tier, .. }) => {
+                assert_eq!(required_tier, "purchase_orders:approve_tier3");
+            }
+            other => panic!("unexpected result: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_dual_approval_threshold_boundary() {
+        let policy = PurchaseApprovalPolicy::disabled().with_dual_approval_threshold(dec!(5000));
+        assert!(!policy.requires_second_approval(dec!(5000)));
+        assert!(policy.requires_second_approval(dec!(5000.01)));
+    }
+}
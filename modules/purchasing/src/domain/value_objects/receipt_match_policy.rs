@@ -0,0 +1,254 @@
+// ReceiptMatchPolicy value object - tolerances a goods receipt must meet
+// against its purchase order before it is confirmed
+
+use std::collections::HashMap;
+
+use rust_decimal::Decimal;
+
+use crate::domain::entities::{GoodsReceipt, PurchaseOrder};
+use crate::domain::value_objects::GoodsReceiptItemId;
+
+/// Variance of one receipt line against its purchase order line.
+///
+/// `quantity_variance` is how much the line takes the order line past its
+/// ordered quantity (never negative); `cost_variance` is the receipt's unit
+/// cost minus the ordered unit cost, both per ordered unit.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ReceiptLineVariance {
+    pub goods_receipt_item_id: GoodsReceiptItemId,
+    pub quantity_variance: Decimal,
+    pub cost_variance: Decimal,
+    pub within_tolerance: bool,
+}
+
+/// Result of matching a receipt against its purchase order
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReceiptMatch {
+    pub lines: Vec<ReceiptLineVariance>,
+}
+
+impl ReceiptMatch {
+    /// Returns true if every line is within tolerance
+    pub fn is_matched(&self) -> bool {
+        self.lines.iter().all(|line| line.within_tolerance)
+    }
+
+    pub fn mismatches(&self) -> impl Iterator<Item = &ReceiptLineVariance> {
+        self.lines.iter().filter(|line| !line.within_tolerance)
+    }
+}
+
+/// Tolerances for matching goods receipts against purchase orders, the
+/// first two legs of a three-way match (the invoice leg comes later).
+///
+/// An order line may be received up to its ordered quantity plus the
+/// quantity tolerance (a percentage of the ordered quantity), counting
+/// earlier receipts; receiving less is never a mismatch. A receipt unit
+/// cost may differ from the ordered unit cost by at most the cost tolerance
+/// (a percentage of the ordered cost). A tolerance left unset skips that
+/// check, and the disabled policy checks nothing. Mismatches block the
+/// confirmation unless overrides are allowed and the user may override.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ReceiptMatchPolicy {
+    quantity_tolerance_percent: Option<Decimal>,
+    cost_tolerance_percent: Option<Decimal>,
+    allow_override: bool,
+}
+
+impl ReceiptMatchPolicy {
+    /// Policy that doesn't match receipts
+    pub fn disabled() -> Self {
+        Self::default()
+    }
+
+    /// Allows receiving up to `percent` over the ordered quantity
+    pub fn with_quantity_tolerance(mut self, percent: Decimal) -> Self {
+        self.quantity_tolerance_percent = Some(percent);
+        self
+    }
+
+    /// Allows unit costs up to `percent` away from the ordered cost
+    pub fn with_cost_tolerance(mut self, percent: Decimal) -> Self {
+        self.cost_tolerance_percent = Some(percent);
+        self
+    }
+
+    /// Lets users with the override permission confirm mismatched receipts
+    pub fn with_override_allowed(mut self, allow: bool) -> Self {
+        self.allow_override = allow;
+        self
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.quantity_tolerance_percent.is_some() || self.cost_tolerance_percent.is_some()
+    }
+
+    pub fn allows_override(&self) -> bool {
+        self.allow_override
+    }
+
+    /// Matches `receipt` against `order`, whose received quantities don't
+    /// include the receipt yet. Lines whose order line is missing are
+    /// reported as mismatches.
+    pub fn evaluate(&self, receipt: &GoodsReceipt, order: &PurchaseOrder) -> ReceiptMatch {
+        let mut received: HashMap<_, Decimal> = order
+            .items()
+            .iter()
+            .map(|item| (item.id(), item.quantity_received()))
+            .collect();
+
+        let lines = receipt
+            .items()
+            .iter()
+            .map(|line| {
+                let Some(ordered) = order
+                    .items()
+                    .iter()
+                    .find(|item| item.id() == line.purchase_order_item_id())
+                else {
+                    return ReceiptLineVariance {
+                        goods_receipt_item_id: line.id(),
+                        quantity_variance: line.quantity_received(),
+                        cost_variance: line.unit_cost(),
+                        within_tolerance: false,
+                    };
+                };
+
+                let before = received.entry(ordered.id()).or_default();
+                let after = *before + line.quantity_received();
+                let quantity_variance =
+                    (after - ordered.quantity_ordered().max(*before)).max(Decimal::ZERO);
+                *before = after;
+
+                let ordered_cost = ordered.ordered_unit_cost();
+                let cost_variance = line.unit_cost() - ordered_cost;
+
+                let quantity_ok = self.quantity_tolerance_percent.is_none_or(|percent| {
+                    after
+                        <= ordered.quantity_ordered() * (Decimal::ONE_HUNDRED + percent)
+                            / Decimal::ONE_HUNDRED
+                });
+                let cost_ok = self.cost_tolerance_percent.is_none_or(|percent| {
+                    cost_variance.abs() <= ordered_cost * percent / Decimal::ONE_HUNDRED
+                });
+
+                ReceiptLineVariance {
+                    goods_receipt_item_id: line.id(),
+                    quantity_variance,
+                    cost_variance,
+                    within_tolerance: quantity_ok && cost_ok,
+                }
+            })
+            .collect();
+
+        ReceiptMatch { lines }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::entities::{GoodsReceiptItem, PurchaseOrderItem};
+    use crate::domain::value_objects::VendorId;
+    use chrono::NaiveDate;
+    use identity::{StoreId, UserId};
+    use inventory::{Currency, ProductId, UnitOfMeasure};
+    use rust_decimal_macros::dec;
+
+    /// Order for 10 units at 5.00, 4 of them already received
+    fn order() -> PurchaseOrder {
+        let mut order = PurchaseOrder::create(
+            "PO-2024-00001".to_string(),
+            StoreId::new(),
+            VendorId::new(),
+            NaiveDate::from_ymd_opt(2024, 1, 20).unwrap(),
+            Currency::new("HNL").unwrap(),
+            30,
+            UserId::new(),
+        );
+        let mut item = PurchaseOrderItem::create(
+            order.id(),
+            1,
+            ProductId::new(),
+            None,
+            "Test Product".to_string(),
+            dec!(10),
+            UnitOfMeasure::Unit,
+            dec!(5),
+            dec!(0),
+            dec!(0),
+        );
+        item.add_received_quantity(dec!(4));
+        order.add_item(item).unwrap();
+        order
+    }
+
+    fn receipt(order: &PurchaseOrder, lines: &[(Decimal, Decimal)]) -> GoodsReceipt {
+        let mut receipt = GoodsReceipt::create(
+            "GR-2024-00001".to_string(),
+            order.id(),
+            order.store_id(),
+            NaiveDate::from_ymd_opt(2024, 1, 25).unwrap(),
+            UserId::new(),
+        );
+        let ordered = &order.items()[0];
+        for (quantity, unit_cost) in lines {
+            let item = GoodsReceiptItem::create(
+                receipt.id(),
+                ordered.id(),
+                ordered.product_id(),
+                None,
+                *quantity,
+                *unit_cost,
+            );
+            receipt.add_item(item).unwrap();
+        }
+        receipt
+    }
+
+    #[test]
+    fn test_disabled_policy_checks_nothing() {
+        let order = order();
+        let policy = ReceiptMatchPolicy::disabled();
+        assert!(!policy.is_enabled());
+        assert!(
+            policy
+                .evaluate(&receipt(&order, &[(dec!(100), dec!(50))]), &order)
+                .is_matched()
+        );
+    }
+
+    #[test]
+    fn test_over_receipt_tolerance_counts_earlier_receipts() {
+        let order = order();
+        let policy = ReceiptMatchPolicy::disabled().with_quantity_tolerance(dec!(10));
+
+        // 4 received + 7 = 11, within 10% of 10
+        let result = policy.evaluate(&receipt(&order, &[(dec!(7), dec!(5))]), &order);
+        assert!(result.is_matched());
+        assert_eq!(result.lines[0].quantity_variance, dec!(1));
+
+        // Two lines adding up to 12
+        let result = policy.evaluate(
+            &receipt(&order, &[(dec!(5), dec!(5)), (dec!(3), dec!(5))]),
+            &order,
+        );
+        assert!(result.lines[0].within_tolerance);
+        assert!(!result.lines[1].within_tolerance);
+        assert_eq!(result.lines[1].quantity_variance, dec!(2));
+    }
+
+    #[test]
+    fn test_cost_tolerance() {
+        let order = order();
+        let policy = ReceiptMatchPolicy::disabled().with_cost_tolerance(dec!(2));
+
+        let result = policy.evaluate(&receipt(&order, &[(dec!(1), dec!(5.10))]), &order);
+        assert!(result.is_matched());
+        assert_eq!(result.lines[0].cost_variance, dec!(0.10));
+
+        let result = policy.evaluate(&receipt(&order, &[(dec!(1), dec!(4.85))]), &order);
+        assert!(!result.is_matched());
+        assert_eq!(result.mismatches().count(), 1);
+    }
+}
@@ -0,0 +1,57 @@
+// ReceiptMatchStatus enum - outcome of matching a goods receipt against its
+// purchase order
+
+use crate::PurchasingError;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
+
+/// Outcome of matching a confirmed goods receipt against its purchase order
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ReceiptMatchStatus {
+    /// Quantities and costs within tolerance
+    Matched,
+    /// Outside tolerance, confirmed by a user allowed to override
+    Overridden,
+}
+
+impl FromStr for ReceiptMatchStatus {
+    type Err = PurchasingError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "matched" => Ok(ReceiptMatchStatus::Matched),
+            "overridden" => Ok(ReceiptMatchStatus::Overridden),
+            _ => Err(PurchasingError::InvalidReceiptMatchStatus),
+        }
+    }
+}
+
+impl fmt::Display for ReceiptMatchStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ReceiptMatchStatus::Matched => write!(f, "matched"),
+            ReceiptMatchStatus::Overridden => write!(f, "overridden"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_display_round_trip() {
+        for status in [ReceiptMatchStatus::Matched, ReceiptMatchStatus::Overridden] {
+            assert_eq!(
+                ReceiptMatchStatus::from_str(&status.to_string()).unwrap(),
+                status
+            );
+        }
+        assert!(matches!(
+            ReceiptMatchStatus::from_str("pending"),
+            Err(PurchasingError::InvalidReceiptMatchStatus)
+        ));
+    }
+}
@@ -3,6 +3,8 @@
 //! This module defines all error types that can occur during purchasing operations.
 //! Errors are categorized by domain area (vendors, purchase orders, goods receipts).
 
+use chrono::NaiveDate;
+use rust_decimal::Decimal;
 use thiserror::Error;
 use uuid::Uuid;
 
@@ -55,6 +57,17 @@ pub enum PurchasingError {
     #[error("User cannot approve their own purchase order")]
     CannotApproveSelfCreatedOrder,
 
+    /// The order total exceeds the approver's spend limit.
+    #[error("Purchase order total {total} exceeds approval limit; requires {required_tier}")]
+    ApprovalLimitExceeded {
+        total: Decimal,
+        required_tier: String,
+    },
+
+    /// The second approval must come from a different user than the first.
+    #[error("Purchase order needs a second, different approver")]
+    DuplicateApprover,
+
     /// The purchase order has already been cancelled.
     #[error("Purchase order has already been cancelled")]
     OrderAlreadyCancelled,
@@ -71,6 +84,10 @@ pub enum PurchasingError {
     #[error("Cannot cancel: purchase order has received goods")]
     OrderHasReceivedGoods,
 
+    /// A bulk cancel needs a reason to record on each order.
+    #[error("Cancellation reason is required")]
+    CancellationReasonRequired,
+
     // -------------------------------------------------------------------------
     // Purchase Order Item errors
     // -------------------------------------------------------------------------
@@ -86,6 +103,10 @@ pub enum PurchasingError {
     #[error("Unit cost must be non-negative")]
     InvalidUnitCost,
 
+    /// Pack size is not positive, or pack size and price aren't given together.
+    #[error("Invalid pack pricing: {0}")]
+    InvalidPackPricing(String),
+
     /// Cannot receive more than ordered quantity.
     #[error("Cannot receive more than ordered quantity")]
     ExceedsOrderedQuantity,
@@ -117,6 +138,15 @@ pub enum PurchasingError {
     #[error("Goods receipt has already been cancelled")]
     ReceiptAlreadyCancelled,
 
+    /// Received quantities or costs are outside the match tolerance and the
+    /// user can't override it.
+    #[error("Goods receipt doesn't match the purchase order: {0}")]
+    ReceiptMatchFailed(String),
+
+    /// The receipt is dated in a closed inventory period of its store.
+    #[error("The period from {0} to {1} is closed")]
+    PeriodClosed(NaiveDate, NaiveDate),
+
     // -------------------------------------------------------------------------
     // Goods Receipt Item errors
     // -------------------------------------------------------------------------
@@ -128,6 +158,33 @@ pub enum PurchasingError {
     #[error("Quantity received must be positive")]
     InvalidQuantityReceived,
 
+    // -------------------------------------------------------------------------
+    // Serial number errors
+    // -------------------------------------------------------------------------
+    /// A line of a serial-tracked product is missing the serials of its units.
+    #[error("Serial numbers required for serial-tracked product: {0}")]
+    SerialNumbersRequired(Uuid),
+
+    /// A serial number is blank or too long.
+    #[error("Invalid serial number")]
+    InvalidSerialNumber,
+
+    /// The number of serials does not match the (whole) quantity received.
+    #[error("Number of serial numbers must equal the quantity received")]
+    SerialCountMismatch,
+
+    /// The same serial appears more than once on the receipt.
+    #[error("Serial number '{0}' is listed more than once")]
+    DuplicateSerialNumber(String),
+
+    /// The serial is already registered for the product.
+    #[error("Serial number '{0}' is already registered")]
+    SerialAlreadyRegistered(String),
+
+    /// Serials were given for a product that is not serial-tracked.
+    #[error("Product is not serial-tracked: {0}")]
+    ProductNotSerialized(Uuid),
+
     // -------------------------------------------------------------------------
     // Workflow errors
     // -------------------------------------------------------------------------
@@ -142,10 +199,31 @@ pub enum PurchasingError {
     #[error("Invalid currency code: must be 3 uppercase letters (ISO 4217)")]
     InvalidCurrency,
 
+    /// The vendor tax ID does not match the format of its country.
+    #[error("{0}")]
+    InvalidTaxId(String),
+
+    /// The `after_cursor` of a list query was not issued by this API.
+    #[error(transparent)]
+    InvalidPageCursor(#[from] common::InvalidPageCursor),
+
+    /// A purchase order was submitted with an expected delivery date in the past.
+    #[error("Expected delivery date cannot be in the past")]
+    ExpectedDeliveryDateInPast,
+
+    /// A report's start date is after its end date.
+    #[error("from_date must not be after to_date")]
+    InvalidDateRange,
+
     /// The provided unit of measure is not recognized.
     #[error("Invalid unit of measure")]
     InvalidUnitOfMeasure,
 
+    /// A received quantity can't be converted to the product's stocking
+    /// unit, e.g. cases without a conversion defined for the product.
+    #[error("Cannot convert {from} to {to}")]
+    IncompatibleUnits { from: String, to: String },
+
     /// The provided purchase order status is not recognized.
     #[error("Invalid purchase order status")]
     InvalidPurchaseOrderStatus,
@@ -154,6 +232,10 @@ pub enum PurchasingError {
     #[error("Invalid goods receipt status")]
     InvalidGoodsReceiptStatus,
 
+    /// The provided receipt match status is not recognized.
+    #[error("Invalid receipt match status")]
+    InvalidReceiptMatchStatus,
+
     /// Product not found.
     #[error("Product not found: {0}")]
     ProductNotFound(Uuid),
@@ -169,6 +251,10 @@ pub enum PurchasingError {
     #[error("Audit error: {0}")]
     AuditError(String),
 
+    /// An inventory operation failed while confirming a receipt.
+    #[error("Inventory error: {0}")]
+    Inventory(String),
+
     /// A database error occurred during the operation.
     #[error("Database error: {0}")]
     Database(#[from] sqlx::Error),
@@ -176,6 +262,14 @@ pub enum PurchasingError {
     // -------------------------------------------------------------------------
     // General errors
     // -------------------------------------------------------------------------
+    /// The caller lacks a permission the operation requires.
+    #[error("Missing required permissions: {}", missing.join(", "))]
+    Forbidden { missing: Vec<String> },
+
+    /// The caller is not assigned to the store whose data was requested.
+    #[error("Store not assigned to the user: {0}")]
+    StoreAccessDenied(Uuid),
+
     /// The requested functionality is not yet implemented.
     #[error("Not implemented")]
     NotImplemented,
@@ -1,15 +1,17 @@
 // PostgreSQL GoodsReceiptRepository implementation
 
 use async_trait::async_trait;
-use chrono::NaiveDate;
+use chrono::{DateTime, NaiveDate, Utc};
+use common::{DocumentNumberService, DocumentType};
 use rust_decimal::Decimal;
 use sqlx::PgPool;
 
 use crate::PurchasingError;
 use crate::domain::entities::{GoodsReceipt, GoodsReceiptItem};
-use crate::domain::repositories::{GoodsReceiptFilter, GoodsReceiptRepository};
+use crate::domain::repositories::{GoodsReceiptFilter, GoodsReceiptRepository, ReceivedUnitCost};
 use crate::domain::value_objects::{
     GoodsReceiptId, GoodsReceiptItemId, GoodsReceiptStatus, PurchaseOrderId, PurchaseOrderItemId,
+    ReceiptMatchStatus, VendorId,
 };
 use identity::{StoreId, UserId};
 use inventory::{ProductId, VariantId};
@@ -17,12 +19,22 @@ use inventory::{ProductId, VariantId};
 /// PostgreSQL implementation of GoodsReceiptRepository
 pub struct PgGoodsReceiptRepository {
     pool: PgPool,
+    numbering: DocumentNumberService,
 }
 
 impl PgGoodsReceiptRepository {
     /// Creates a new PgGoodsReceiptRepository with the given connection pool
     pub fn new(pool: PgPool) -> Self {
-        Self { pool }
+        Self {
+            pool,
+            numbering: DocumentNumberService::default(),
+        }
+    }
+
+    /// Replaces the default document number formats
+    pub fn with_numbering(mut self, numbering: DocumentNumberService) -> Self {
+        self.numbering = numbering;
+        self
     }
 }
 
@@ -36,10 +48,10 @@ impl GoodsReceiptRepository for PgGoodsReceiptRepository {
             r#"
             INSERT INTO goods_receipts (
                 id, receipt_number, purchase_order_id, store_id, receipt_date,
-                status, notes, received_by_id, confirmed_by_id, confirmed_at,
-                created_at, updated_at
+                status, notes, split_backorder, received_by_id, confirmed_by_id,
+                confirmed_at, created_at, updated_at, match_status, match_overridden_by_id
             )
-            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15)
             "#,
         )
         .bind(receipt.id().into_uuid())
@@ -49,11 +61,14 @@ impl GoodsReceiptRepository for PgGoodsReceiptRepository {
         .bind(receipt.receipt_date())
         .bind(receipt.status().to_string())
         .bind(receipt.notes())
+        .bind(receipt.split_backorder())
         .bind(receipt.received_by_id().into_uuid())
         .bind(receipt.confirmed_by_id().map(|id| id.into_uuid()))
         .bind(receipt.confirmed_at())
         .bind(receipt.created_at())
         .bind(receipt.updated_at())
+        .bind(receipt.match_status().map(|s| s.to_string()))
+        .bind(receipt.match_overridden_by_id().map(|id| id.into_uuid()))
         .execute(&mut *tx)
         .await?;
 
@@ -73,8 +88,8 @@ impl GoodsReceiptRepository for PgGoodsReceiptRepository {
         let row = sqlx::query_as::<_, GoodsReceiptRow>(
             r#"
             SELECT id, receipt_number, purchase_order_id, store_id, receipt_date,
-                   status, notes, received_by_id, confirmed_by_id, confirmed_at,
-                   created_at, updated_at
+                   status, notes, split_backorder, received_by_id, confirmed_by_id, confirmed_at,
+                   match_status, match_overridden_by_id, created_at, updated_at
             FROM goods_receipts
             WHERE id = $1
             "#,
@@ -93,8 +108,8 @@ impl GoodsReceiptRepository for PgGoodsReceiptRepository {
         let row = sqlx::query_as::<_, GoodsReceiptRow>(
             r#"
             SELECT id, receipt_number, purchase_order_id, store_id, receipt_date,
-                   status, notes, received_by_id, confirmed_by_id, confirmed_at,
-                   created_at, updated_at
+                   status, notes, split_backorder, received_by_id, confirmed_by_id, confirmed_at,
+                   match_status, match_overridden_by_id, created_at, updated_at
             FROM goods_receipts
             WHERE id = $1
             "#,
@@ -109,7 +124,8 @@ impl GoodsReceiptRepository for PgGoodsReceiptRepository {
                     r#"
                     SELECT id, goods_receipt_id, purchase_order_item_id, product_id,
                            variant_id, quantity_received, unit_cost, lot_number,
-                           expiry_date, notes
+                           expiry_date, notes, serial_numbers, quantity_variance,
+                           cost_variance
                     FROM goods_receipt_items
                     WHERE goods_receipt_id = $1
                     "#,
@@ -134,7 +150,8 @@ impl GoodsReceiptRepository for PgGoodsReceiptRepository {
             r#"
             UPDATE goods_receipts
             SET receipt_date = $2, status = $3, notes = $4, confirmed_by_id = $5,
-                confirmed_at = $6, updated_at = $7
+                confirmed_at = $6, updated_at = $7, match_status = $8,
+                match_overridden_by_id = $9
             WHERE id = $1
             "#,
         )
@@ -145,6 +162,8 @@ impl GoodsReceiptRepository for PgGoodsReceiptRepository {
         .bind(receipt.confirmed_by_id().map(|id| id.into_uuid()))
         .bind(receipt.confirmed_at())
         .bind(receipt.updated_at())
+        .bind(receipt.match_status().map(|s| s.to_string()))
+        .bind(receipt.match_overridden_by_id().map(|id| id.into_uuid()))
         .execute(&mut *tx)
         .await?;
 
@@ -175,8 +194,8 @@ impl GoodsReceiptRepository for PgGoodsReceiptRepository {
         let rows = sqlx::query_as::<_, GoodsReceiptRow>(
             r#"
             SELECT id, receipt_number, purchase_order_id, store_id, receipt_date,
-                   status, notes, received_by_id, confirmed_by_id, confirmed_at,
-                   created_at, updated_at
+                   status, notes, split_backorder, received_by_id, confirmed_by_id, confirmed_at,
+                   match_status, match_overridden_by_id, created_at, updated_at
             FROM goods_receipts
             WHERE purchase_order_id = $1
             ORDER BY created_at DESC
@@ -191,13 +210,36 @@ impl GoodsReceiptRepository for PgGoodsReceiptRepository {
             .collect()
     }
 
+    async fn find_orders_with_receipts(
+        &self,
+        order_ids: &[PurchaseOrderId],
+    ) -> Result<Vec<PurchaseOrderId>, PurchasingError> {
+        let ids: Vec<uuid::Uuid> = order_ids.iter().map(|id| id.into_uuid()).collect();
+        let rows: Vec<uuid::Uuid> = sqlx::query_scalar(
+            r#"
+            SELECT DISTINCT purchase_order_id
+            FROM goods_receipts
+            WHERE purchase_order_id = ANY($1)
+            "#,
+        )
+        .bind(&ids)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows.into_iter().map(PurchaseOrderId::from_uuid).collect())
+    }
+
     async fn find_paginated(
         &self,
         filter: GoodsReceiptFilter,
         page: i64,
         page_size: i64,
     ) -> Result<(Vec<GoodsReceipt>, i64), PurchasingError> {
-        let offset = (page - 1) * page_size;
+        let offset = if filter.after_id.is_some() {
+            0
+        } else {
+            (page - 1) * page_size
+        };
 
         // Build count query
         let mut count_query = String::from("SELECT COUNT(*) FROM goods_receipts WHERE 1=1");
@@ -207,6 +249,10 @@ impl GoodsReceiptRepository for PgGoodsReceiptRepository {
             count_query.push_str(&format!(" AND store_id = ${}", param_idx));
             param_idx += 1;
         }
+        if filter.store_ids.is_some() {
+            count_query.push_str(&format!(" AND store_id = ANY(${})", param_idx));
+            param_idx += 1;
+        }
         if filter.purchase_order_id.is_some() {
             count_query.push_str(&format!(" AND purchase_order_id = ${}", param_idx));
             param_idx += 1;
@@ -220,6 +266,10 @@ impl GoodsReceiptRepository for PgGoodsReceiptRepository {
         if let Some(store_id) = &filter.store_id {
             count_builder = count_builder.bind(store_id.as_uuid());
         }
+        if let Some(store_ids) = &filter.store_ids {
+            let store_ids: Vec<uuid::Uuid> = store_ids.iter().map(|id| *id.as_uuid()).collect();
+            count_builder = count_builder.bind(store_ids);
+        }
         if let Some(po_id) = &filter.purchase_order_id {
             count_builder = count_builder.bind(po_id.into_uuid());
         }
@@ -231,8 +281,8 @@ impl GoodsReceiptRepository for PgGoodsReceiptRepository {
         // Build data query
         let mut data_query = String::from(
             r#"SELECT id, receipt_number, purchase_order_id, store_id, receipt_date,
-                   status, notes, received_by_id, confirmed_by_id, confirmed_at,
-                   created_at, updated_at
+                   status, notes, split_backorder, received_by_id, confirmed_by_id, confirmed_at,
+                   match_status, match_overridden_by_id, created_at, updated_at
             FROM goods_receipts
             WHERE 1=1"#,
         );
@@ -242,6 +292,10 @@ impl GoodsReceiptRepository for PgGoodsReceiptRepository {
             data_query.push_str(&format!(" AND store_id = ${}", param_idx));
             param_idx += 1;
         }
+        if filter.store_ids.is_some() {
+            data_query.push_str(&format!(" AND store_id = ANY(${})", param_idx));
+            param_idx += 1;
+        }
         if filter.purchase_order_id.is_some() {
             data_query.push_str(&format!(" AND purchase_order_id = ${}", param_idx));
             param_idx += 1;
@@ -250,23 +304,31 @@ impl GoodsReceiptRepository for PgGoodsReceiptRepository {
             data_query.push_str(&format!(" AND status = ${}", param_idx));
             param_idx += 1;
         }
-        data_query.push_str(&format!(
-            " ORDER BY created_at DESC LIMIT ${} OFFSET ${}",
-            param_idx,
-            param_idx + 1
-        ));
+        if filter.after_id.is_some() {
+            data_query.push_str(&format!(" AND id < ${}", param_idx));
+            param_idx += 1;
+        }
+        data_query.push_str(" ORDER BY id DESC");
+        data_query.push_str(&format!(" LIMIT ${} OFFSET ${}", param_idx, param_idx + 1));
 
         // Execute data query
         let mut data_builder = sqlx::query_as::<_, GoodsReceiptRow>(&data_query);
         if let Some(store_id) = &filter.store_id {
             data_builder = data_builder.bind(store_id.as_uuid());
         }
+        if let Some(store_ids) = &filter.store_ids {
+            let store_ids: Vec<uuid::Uuid> = store_ids.iter().map(|id| *id.as_uuid()).collect();
+            data_builder = data_builder.bind(store_ids);
+        }
         if let Some(po_id) = &filter.purchase_order_id {
             data_builder = data_builder.bind(po_id.into_uuid());
         }
         if let Some(status) = &filter.status {
             data_builder = data_builder.bind(status.to_string());
         }
+        if let Some(after_id) = filter.after_id {
+            data_builder = data_builder.bind(after_id.into_uuid());
+        }
         data_builder = data_builder.bind(page_size).bind(offset);
 
         let rows = data_builder.fetch_all(&self.pool).await?;
@@ -279,20 +341,53 @@ impl GoodsReceiptRepository for PgGoodsReceiptRepository {
         Ok((receipts?, total_count))
     }
 
-    async fn generate_receipt_number(&self, store_id: StoreId) -> Result<String, PurchasingError> {
-        let year = chrono::Utc::now().format("%Y");
-        let count: (i64,) = sqlx::query_as(
+    async fn find_recent_unit_costs(
+        &self,
+        product_id: ProductId,
+        variant_id: Option<VariantId>,
+        store_id: StoreId,
+        limit: i64,
+    ) -> Result<Vec<ReceivedUnitCost>, PurchasingError> {
+        let rows = sqlx::query_as::<_, ReceivedUnitCostRow>(
             r#"
-            SELECT COUNT(*) FROM goods_receipts
-            WHERE store_id = $1 AND EXTRACT(YEAR FROM created_at) = EXTRACT(YEAR FROM NOW())
+            SELECT gr.id AS goods_receipt_id, gr.receipt_number, gr.receipt_date,
+                   gr.confirmed_at, gr.store_id, po.id AS purchase_order_id,
+                   po.order_number, v.id AS vendor_id, v.name AS vendor_name,
+                   po.currency, gri.quantity_received, gri.unit_cost
+            FROM goods_receipt_items gri
+            JOIN goods_receipts gr ON gr.id = gri.goods_receipt_id
+            JOIN purchase_orders po ON po.id = gr.purchase_order_id
+            JOIN vendors v ON v.id = po.vendor_id
+            JOIN stores s ON s.id = gr.store_id
+            WHERE gri.product_id = $1
+              AND ($2::uuid IS NULL OR gri.variant_id = $2)
+              AND gr.status = 'confirmed'
+              AND s.organization_id IS NOT DISTINCT FROM
+                  (SELECT organization_id FROM stores WHERE id = $3)
+            ORDER BY gr.confirmed_at DESC NULLS LAST, gr.receipt_date DESC, gri.id DESC
+            LIMIT $4
             "#,
         )
+        .bind(product_id.into_uuid())
+        .bind(variant_id.map(|v| v.into_uuid()))
         .bind(store_id.as_uuid())
-        .fetch_one(&self.pool)
+        .bind(limit)
+        .fetch_all(&self.pool)
         .await?;
 
-        let sequence = count.0 + 1;
-        Ok(format!("GR-{}-{:05}", year, sequence))
+        Ok(rows.into_iter().map(Into::into).collect())
+    }
+
+    async fn generate_receipt_number(&self, store_id: StoreId) -> Result<String, PurchasingError> {
+        let mut conn = self.pool.acquire().await?;
+        Ok(self
+            .numbering
+            .next_number(
+                &mut conn,
+                DocumentType::GoodsReceipt,
+                Some(store_id.into_uuid()),
+            )
+            .await?)
     }
 }
 
@@ -307,7 +402,8 @@ impl PgGoodsReceiptRepository {
             r#"
             UPDATE goods_receipts
             SET receipt_date = $2, status = $3, notes = $4, confirmed_by_id = $5,
-                confirmed_at = $6, updated_at = $7
+                confirmed_at = $6, updated_at = $7, match_status = $8,
+                match_overridden_by_id = $9
             WHERE id = $1
             "#,
         )
@@ -318,6 +414,8 @@ impl PgGoodsReceiptRepository {
         .bind(receipt.confirmed_by_id().map(|id| id.into_uuid()))
         .bind(receipt.confirmed_at())
         .bind(receipt.updated_at())
+        .bind(receipt.match_status().map(|s| s.to_string()))
+        .bind(receipt.match_overridden_by_id().map(|id| id.into_uuid()))
         .execute(&mut **tx)
         .await?;
 
@@ -327,6 +425,34 @@ impl PgGoodsReceiptRepository {
             ));
         }
 
+        // Variances recorded by the purchase order match
+        let items: Vec<_> = receipt
+            .items()
+            .iter()
+            .filter(|item| item.quantity_variance().is_some() || item.cost_variance().is_some())
+            .collect();
+        if !items.is_empty() {
+            let ids: Vec<uuid::Uuid> = items.iter().map(|i| i.id().into_uuid()).collect();
+            let quantity_variances: Vec<Option<Decimal>> =
+                items.iter().map(|i| i.quantity_variance()).collect();
+            let cost_variances: Vec<Option<Decimal>> =
+                items.iter().map(|i| i.cost_variance()).collect();
+            sqlx::query(
+                r#"
+                UPDATE goods_receipt_items gri
+                SET quantity_variance = v.quantity_variance, cost_variance = v.cost_variance
+                FROM UNNEST($1::uuid[], $2::numeric[], $3::numeric[])
+                    AS v(id, quantity_variance, cost_variance)
+                WHERE gri.id = v.id
+                "#,
+            )
+            .bind(&ids)
+            .bind(&quantity_variances)
+            .bind(&cost_variances)
+            .execute(&mut **tx)
+            .await?;
+        }
+
         Ok(())
     }
 }
@@ -342,9 +468,9 @@ impl PgGoodsReceiptRepository {
             INSERT INTO goods_receipt_items (
                 id, goods_receipt_id, purchase_order_item_id, product_id,
                 variant_id, quantity_received, unit_cost, lot_number,
-                expiry_date, notes
+                expiry_date, notes, serial_numbers, quantity_variance, cost_variance
             )
-            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13)
             "#,
         )
         .bind(item.id().into_uuid())
@@ -357,6 +483,9 @@ impl PgGoodsReceiptRepository {
         .bind(item.lot_number())
         .bind(item.expiry_date())
         .bind(item.notes())
+        .bind(item.serial_numbers())
+        .bind(item.quantity_variance())
+        .bind(item.cost_variance())
         .execute(&mut **tx)
         .await?;
 
@@ -377,9 +506,12 @@ struct GoodsReceiptRow {
     receipt_date: NaiveDate,
     status: String,
     notes: Option<String>,
+    split_backorder: bool,
     received_by_id: uuid::Uuid,
     confirmed_by_id: Option<uuid::Uuid>,
     confirmed_at: Option<chrono::DateTime<chrono::Utc>>,
+    match_status: Option<String>,
+    match_overridden_by_id: Option<uuid::Uuid>,
     created_at: chrono::DateTime<chrono::Utc>,
     updated_at: chrono::DateTime<chrono::Utc>,
 }
@@ -394,6 +526,10 @@ impl GoodsReceiptRow {
         items: Vec<GoodsReceiptItem>,
     ) -> Result<GoodsReceipt, PurchasingError> {
         let status: GoodsReceiptStatus = self.status.parse()?;
+        let match_status = self
+            .match_status
+            .map(|s| s.parse::<ReceiptMatchStatus>())
+            .transpose()?;
 
         Ok(GoodsReceipt::reconstitute(
             GoodsReceiptId::from_uuid(self.id),
@@ -403,9 +539,12 @@ impl GoodsReceiptRow {
             self.receipt_date,
             status,
             self.notes,
+            self.split_backorder,
             UserId::from_uuid(self.received_by_id),
             self.confirmed_by_id.map(UserId::from_uuid),
             self.confirmed_at,
+            match_status,
+            self.match_overridden_by_id.map(UserId::from_uuid),
             items,
             self.created_at,
             self.updated_at,
@@ -425,6 +564,9 @@ struct GoodsReceiptItemRow {
     lot_number: Option<String>,
     expiry_date: Option<NaiveDate>,
     notes: Option<String>,
+    serial_numbers: Vec<String>,
+    quantity_variance: Option<Decimal>,
+    cost_variance: Option<Decimal>,
 }
 
 impl From<GoodsReceiptItemRow> for GoodsReceiptItem {
@@ -440,6 +582,44 @@ impl From<GoodsReceiptItemRow> for GoodsReceiptItem {
             row.lot_number,
             row.expiry_date,
             row.notes,
+            row.serial_numbers,
+            row.quantity_variance,
+            row.cost_variance,
         )
     }
 }
+
+#[derive(sqlx::FromRow)]
+struct ReceivedUnitCostRow {
+    goods_receipt_id: uuid::Uuid,
+    receipt_number: String,
+    receipt_date: NaiveDate,
+    confirmed_at: Option<DateTime<Utc>>,
+    store_id: uuid::Uuid,
+    purchase_order_id: uuid::Uuid,
+    order_number: String,
+    vendor_id: uuid::Uuid,
+    vendor_name: String,
+    currency: String,
+    quantity_received: Decimal,
+    unit_cost: Decimal,
+}
+
+impl From<ReceivedUnitCostRow> for ReceivedUnitCost {
+    fn from(row: ReceivedUnitCostRow) -> Self {
+        ReceivedUnitCost {
+            goods_receipt_id: GoodsReceiptId::from_uuid(row.goods_receipt_id),
+            receipt_number: row.receipt_number,
+            receipt_date: row.receipt_date,
+            confirmed_at: row.confirmed_at,
+            store_id: StoreId::from_uuid(row.store_id),
+            purchase_order_id: PurchaseOrderId::from_uuid(row.purchase_order_id),
+            order_number: row.order_number,
+            vendor_id: VendorId::from_uuid(row.vendor_id),
+            vendor_name: row.vendor_name,
+            currency: row.currency,
+            quantity_received: row.quantity_received,
+            unit_cost: row.unit_cost,
+        }
+    }
+}
@@ -2,12 +2,17 @@
 
 use async_trait::async_trait;
 use chrono::NaiveDate;
+use common::{DocumentNumberService, DocumentType};
+use events::{OutboxEvent, enqueue_outbox_event_in_tx};
 use rust_decimal::Decimal;
-use sqlx::PgPool;
+use serde_json::json;
+use sqlx::{PgPool, Postgres, Transaction};
 
 use crate::PurchasingError;
 use crate::domain::entities::{PurchaseOrder, PurchaseOrderItem};
-use crate::domain::repositories::{PurchaseOrderFilter, PurchaseOrderRepository};
+use crate::domain::repositories::{
+    PurchaseOrderFilter, PurchaseOrderRepository, StalePurchaseOrderFilter, VendorOrderDelivery,
+};
 use crate::domain::value_objects::{
     PurchaseOrderId, PurchaseOrderItemId, PurchaseOrderStatus, VendorId,
 };
@@ -17,12 +22,22 @@ use inventory::{Currency, ProductId, UnitOfMeasure, VariantId};
 /// PostgreSQL implementation of PurchaseOrderRepository
 pub struct PgPurchaseOrderRepository {
     pool: PgPool,
+    numbering: DocumentNumberService,
 }
 
 impl PgPurchaseOrderRepository {
     /// Creates a new PgPurchaseOrderRepository with the given connection pool
     pub fn new(pool: PgPool) -> Self {
-        Self { pool }
+        Self {
+            pool,
+            numbering: DocumentNumberService::default(),
+        }
+    }
+
+    /// Replaces the default document number formats
+    pub fn with_numbering(mut self, numbering: DocumentNumberService) -> Self {
+        self.numbering = numbering;
+        self
     }
 }
 
@@ -30,57 +45,16 @@ impl PgPurchaseOrderRepository {
 impl PurchaseOrderRepository for PgPurchaseOrderRepository {
     async fn save(&self, order: &PurchaseOrder) -> Result<(), PurchasingError> {
         let mut tx = self.pool.begin().await?;
+        Self::save_in_tx(&mut tx, order).await?;
+        tx.commit().await?;
+        Ok(())
+    }
 
-        // Save the order header
-        sqlx::query(
-            r#"
-            INSERT INTO purchase_orders (
-                id, order_number, store_id, vendor_id, status, order_date,
-                expected_delivery_date, received_date, subtotal, tax_amount,
-                discount_amount, total, currency, payment_terms_days, notes,
-                internal_notes, created_by_id, submitted_by_id, submitted_at,
-                approved_by_id, approved_at, received_by_id, cancelled_by_id,
-                cancelled_at, cancellation_reason, created_at, updated_at
-            )
-            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14,
-                    $15, $16, $17, $18, $19, $20, $21, $22, $23, $24, $25, $26, $27)
-            "#,
-        )
-        .bind(order.id().into_uuid())
-        .bind(order.order_number())
-        .bind(order.store_id().as_uuid())
-        .bind(order.vendor_id().into_uuid())
-        .bind(order.status().to_string())
-        .bind(order.order_date())
-        .bind(order.expected_delivery_date())
-        .bind(order.received_date())
-        .bind(order.subtotal())
-        .bind(order.tax_amount())
-        .bind(order.discount_amount())
-        .bind(order.total())
-        .bind(order.currency().as_str())
-        .bind(order.payment_terms_days())
-        .bind(order.notes())
-        .bind(order.internal_notes())
-        .bind(order.created_by_id().into_uuid())
-        .bind(order.submitted_by_id().map(|id| id.into_uuid()))
-        .bind(order.submitted_at())
-        .bind(order.approved_by_id().map(|id| id.into_uuid()))
-        .bind(order.approved_at())
-        .bind(order.received_by_id().map(|id| id.into_uuid()))
-        .bind(order.cancelled_by_id().map(|id| id.into_uuid()))
-        .bind(order.cancelled_at())
-        .bind(order.cancellation_reason())
-        .bind(order.created_at())
-        .bind(order.updated_at())
-        .execute(&mut *tx)
-        .await?;
-
-        // Save all items
-        for item in order.items() {
-            self.save_item_internal(&mut tx, item).await?;
+    async fn save_many(&self, orders: &[PurchaseOrder]) -> Result<(), PurchasingError> {
+        let mut tx = self.pool.begin().await?;
+        for order in orders {
+            Self::save_in_tx(&mut tx, order).await?;
         }
-
         tx.commit().await?;
         Ok(())
     }
@@ -92,11 +66,12 @@ impl PurchaseOrderRepository for PgPurchaseOrderRepository {
         let row = sqlx::query_as::<_, PurchaseOrderRow>(
             r#"
             SELECT id, order_number, store_id, vendor_id, status, order_date,
-                   expected_delivery_date, received_date, subtotal, tax_amount,
+                   expected_delivery_date, received_date, first_received_date, subtotal, tax_amount,
                    discount_amount, total, currency, payment_terms_days, notes,
                    internal_notes, created_by_id, submitted_by_id, submitted_at,
+                   first_approved_by_id, first_approved_at,
                    approved_by_id, approved_at, received_by_id, cancelled_by_id,
-                   cancelled_at, cancellation_reason, created_at, updated_at
+                   cancelled_at, cancellation_reason, parent_order_id, created_at, updated_at
             FROM purchase_orders
             WHERE id = $1
             "#,
@@ -115,11 +90,12 @@ impl PurchaseOrderRepository for PgPurchaseOrderRepository {
         let row = sqlx::query_as::<_, PurchaseOrderRow>(
             r#"
             SELECT id, order_number, store_id, vendor_id, status, order_date,
-                   expected_delivery_date, received_date, subtotal, tax_amount,
+                   expected_delivery_date, received_date, first_received_date, subtotal, tax_amount,
                    discount_amount, total, currency, payment_terms_days, notes,
                    internal_notes, created_by_id, submitted_by_id, submitted_at,
+                   first_approved_by_id, first_approved_at,
                    approved_by_id, approved_at, received_by_id, cancelled_by_id,
-                   cancelled_at, cancellation_reason, created_at, updated_at
+                   cancelled_at, cancellation_reason, parent_order_id, created_at, updated_at
             FROM purchase_orders
             WHERE id = $1
             "#,
@@ -134,7 +110,8 @@ impl PurchaseOrderRepository for PgPurchaseOrderRepository {
                     r#"
                     SELECT id, purchase_order_id, line_number, product_id, variant_id,
                            description, quantity_ordered, quantity_received, unit_of_measure,
-                           unit_cost, discount_percent, tax_percent, line_total, notes
+                           unit_cost, discount_percent, tax_percent, line_total, notes,
+                           pack_size, pack_price
                     FROM purchase_order_items
                     WHERE purchase_order_id = $1
                     ORDER BY line_number
@@ -161,11 +138,12 @@ impl PurchaseOrderRepository for PgPurchaseOrderRepository {
         let row = sqlx::query_as::<_, PurchaseOrderRow>(
             r#"
             SELECT id, order_number, store_id, vendor_id, status, order_date,
-                   expected_delivery_date, received_date, subtotal, tax_amount,
+                   expected_delivery_date, received_date, first_received_date, subtotal, tax_amount,
                    discount_amount, total, currency, payment_terms_days, notes,
                    internal_notes, created_by_id, submitted_by_id, submitted_at,
+                   first_approved_by_id, first_approved_at,
                    approved_by_id, approved_at, received_by_id, cancelled_by_id,
-                   cancelled_at, cancellation_reason, created_at, updated_at
+                   cancelled_at, cancellation_reason, parent_order_id, created_at, updated_at
             FROM purchase_orders
             WHERE store_id = $1 AND order_number = $2
             "#,
@@ -180,6 +158,7 @@ impl PurchaseOrderRepository for PgPurchaseOrderRepository {
 
     async fn update(&self, order: &PurchaseOrder) -> Result<(), PurchasingError> {
         let mut tx = self.pool.begin().await?;
+        let previous_status = lock_status(&mut tx, order).await?;
 
         let result = sqlx::query(
             r#"
@@ -190,7 +169,8 @@ impl PurchaseOrderRepository for PgPurchaseOrderRepository {
                 internal_notes = $14, submitted_by_id = $15, submitted_at = $16,
                 approved_by_id = $17, approved_at = $18, received_by_id = $19,
                 cancelled_by_id = $20, cancelled_at = $21, cancellation_reason = $22,
-                updated_at = $23
+                updated_at = $23, first_approved_by_id = $24, first_approved_at = $25,
+                first_received_date = $26
             WHERE id = $1
             "#,
         )
@@ -217,6 +197,9 @@ impl PurchaseOrderRepository for PgPurchaseOrderRepository {
         .bind(order.cancelled_at())
         .bind(order.cancellation_reason())
         .bind(order.updated_at())
+        .bind(order.first_approved_by_id().map(|id| id.into_uuid()))
+        .bind(order.first_approved_at())
+        .bind(order.first_received_date())
         .execute(&mut *tx)
         .await?;
 
@@ -236,6 +219,8 @@ impl PurchaseOrderRepository for PgPurchaseOrderRepository {
             self.save_item_internal(&mut tx, item).await?;
         }
 
+        enqueue_status_change(&mut tx, order, &previous_status).await?;
+
         tx.commit().await?;
         Ok(())
     }
@@ -246,7 +231,11 @@ impl PurchaseOrderRepository for PgPurchaseOrderRepository {
         page: i64,
         page_size: i64,
     ) -> Result<(Vec<PurchaseOrder>, i64), PurchasingError> {
-        let offset = (page - 1) * page_size;
+        let offset = if filter.after_id.is_some() {
+            0
+        } else {
+            (page - 1) * page_size
+        };
 
         // Build count query
         let mut count_query = String::from("SELECT COUNT(*) FROM purchase_orders WHERE 1=1");
@@ -256,6 +245,10 @@ impl PurchaseOrderRepository for PgPurchaseOrderRepository {
             count_query.push_str(&format!(" AND store_id = ${}", param_idx));
             param_idx += 1;
         }
+        if filter.store_ids.is_some() {
+            count_query.push_str(&format!(" AND store_id = ANY(${})", param_idx));
+            param_idx += 1;
+        }
         if filter.vendor_id.is_some() {
             count_query.push_str(&format!(" AND vendor_id = ${}", param_idx));
             param_idx += 1;
@@ -266,6 +259,13 @@ impl PurchaseOrderRepository for PgPurchaseOrderRepository {
         }
         if filter.search.is_some() {
             count_query.push_str(&format!(" AND order_number ILIKE ${}", param_idx));
+            param_idx += 1;
+        }
+        if filter.overdue_as_of.is_some() {
+            count_query.push_str(&format!(
+                " AND status IN ('approved', 'partially_received') AND expected_delivery_date < ${}",
+                param_idx
+            ));
         }
 
         // Execute count
@@ -273,6 +273,10 @@ impl PurchaseOrderRepository for PgPurchaseOrderRepository {
         if let Some(store_id) = &filter.store_id {
             count_builder = count_builder.bind(store_id.as_uuid());
         }
+        if let Some(store_ids) = &filter.store_ids {
+            let store_ids: Vec<uuid::Uuid> = store_ids.iter().map(|id| *id.as_uuid()).collect();
+            count_builder = count_builder.bind(store_ids);
+        }
         if let Some(vendor_id) = &filter.vendor_id {
             count_builder = count_builder.bind(vendor_id.into_uuid());
         }
@@ -282,16 +286,20 @@ impl PurchaseOrderRepository for PgPurchaseOrderRepository {
         if let Some(ref search) = filter.search {
             count_builder = count_builder.bind(format!("%{}%", search));
         }
+        if let Some(as_of) = filter.overdue_as_of {
+            count_builder = count_builder.bind(as_of);
+        }
         let total_count = count_builder.fetch_one(&self.pool).await?;
 
         // Build data query
         let mut data_query = String::from(
             r#"SELECT id, order_number, store_id, vendor_id, status, order_date,
-                   expected_delivery_date, received_date, subtotal, tax_amount,
+                   expected_delivery_date, received_date, first_received_date, subtotal, tax_amount,
                    discount_amount, total, currency, payment_terms_days, notes,
                    internal_notes, created_by_id, submitted_by_id, submitted_at,
+                   first_approved_by_id, first_approved_at,
                    approved_by_id, approved_at, received_by_id, cancelled_by_id,
-                   cancelled_at, cancellation_reason, created_at, updated_at
+                   cancelled_at, cancellation_reason, parent_order_id, created_at, updated_at
             FROM purchase_orders
             WHERE 1=1"#,
         );
@@ -301,6 +309,10 @@ impl PurchaseOrderRepository for PgPurchaseOrderRepository {
             data_query.push_str(&format!(" AND store_id = ${}", param_idx));
             param_idx += 1;
         }
+        if filter.store_ids.is_some() {
+            data_query.push_str(&format!(" AND store_id = ANY(${})", param_idx));
+            param_idx += 1;
+        }
         if filter.vendor_id.is_some() {
             data_query.push_str(&format!(" AND vendor_id = ${}", param_idx));
             param_idx += 1;
@@ -313,17 +325,29 @@ impl PurchaseOrderRepository for PgPurchaseOrderRepository {
             data_query.push_str(&format!(" AND order_number ILIKE ${}", param_idx));
             param_idx += 1;
         }
-        data_query.push_str(&format!(
-            " ORDER BY created_at DESC LIMIT ${} OFFSET ${}",
-            param_idx,
-            param_idx + 1
-        ));
+        if filter.overdue_as_of.is_some() {
+            data_query.push_str(&format!(
+                " AND status IN ('approved', 'partially_received') AND expected_delivery_date < ${}",
+                param_idx
+            ));
+            param_idx += 1;
+        }
+        if filter.after_id.is_some() {
+            data_query.push_str(&format!(" AND id < ${}", param_idx));
+            param_idx += 1;
+        }
+        data_query.push_str(" ORDER BY id DESC");
+        data_query.push_str(&format!(" LIMIT ${} OFFSET ${}", param_idx, param_idx + 1));
 
         // Execute data query
         let mut data_builder = sqlx::query_as::<_, PurchaseOrderRow>(&data_query);
         if let Some(store_id) = &filter.store_id {
             data_builder = data_builder.bind(store_id.as_uuid());
         }
+        if let Some(store_ids) = &filter.store_ids {
+            let store_ids: Vec<uuid::Uuid> = store_ids.iter().map(|id| *id.as_uuid()).collect();
+            data_builder = data_builder.bind(store_ids);
+        }
         if let Some(vendor_id) = &filter.vendor_id {
             data_builder = data_builder.bind(vendor_id.into_uuid());
         }
@@ -333,6 +357,12 @@ impl PurchaseOrderRepository for PgPurchaseOrderRepository {
         if let Some(ref search) = filter.search {
             data_builder = data_builder.bind(format!("%{}%", search));
         }
+        if let Some(as_of) = filter.overdue_as_of {
+            data_builder = data_builder.bind(as_of);
+        }
+        if let Some(after_id) = filter.after_id {
+            data_builder = data_builder.bind(after_id.into_uuid());
+        }
         data_builder = data_builder.bind(page_size).bind(offset);
 
         let rows = data_builder.fetch_all(&self.pool).await?;
@@ -345,20 +375,131 @@ impl PurchaseOrderRepository for PgPurchaseOrderRepository {
         Ok((orders?, total_count))
     }
 
-    async fn generate_order_number(&self, store_id: StoreId) -> Result<String, PurchasingError> {
-        let year = chrono::Utc::now().format("%Y");
-        let count: (i64,) = sqlx::query_as(
+    async fn find_stale(
+        &self,
+        filter: &StalePurchaseOrderFilter,
+    ) -> Result<Vec<PurchaseOrder>, PurchasingError> {
+        let statuses: Vec<String> = filter.statuses.iter().map(|s| s.to_string()).collect();
+        let rows = sqlx::query_as::<_, PurchaseOrderRow>(
             r#"
-            SELECT COUNT(*) FROM purchase_orders
-            WHERE store_id = $1 AND EXTRACT(YEAR FROM created_at) = EXTRACT(YEAR FROM NOW())
+            SELECT id, order_number, store_id, vendor_id, status, order_date,
+                   expected_delivery_date, received_date, first_received_date, subtotal, tax_amount,
+                   discount_amount, total, currency, payment_terms_days, notes,
+                   internal_notes, created_by_id, submitted_by_id, submitted_at,
+                   first_approved_by_id, first_approved_at,
+                   approved_by_id, approved_at, received_by_id, cancelled_by_id,
+                   cancelled_at, cancellation_reason, parent_order_id, created_at, updated_at
+            FROM purchase_orders
+            WHERE store_id = $1
+              AND status = ANY($2)
+              AND updated_at < $3
+              AND ($4::UUID IS NULL OR vendor_id = $4)
+            ORDER BY updated_at
             "#,
         )
-        .bind(store_id.as_uuid())
-        .fetch_one(&self.pool)
+        .bind(filter.store_id.as_uuid())
+        .bind(&statuses)
+        .bind(filter.updated_before)
+        .bind(filter.vendor_id.map(|id| id.into_uuid()))
+        .fetch_all(&self.pool)
+        .await?;
+
+        rows.into_iter()
+            .map(|r| r.try_into_without_items())
+            .collect()
+    }
+
+    async fn cancel_many(&self, orders: &[PurchaseOrder]) -> Result<(), PurchasingError> {
+        let mut tx = self.pool.begin().await?;
+
+        for order in orders {
+            let previous_status = lock_status(&mut tx, order).await?;
+
+            // Re-checked here so a PO approved or received since it was
+            // selected fails the batch instead of being cancelled
+            let result = sqlx::query(
+                r#"
+                UPDATE purchase_orders
+                SET status = $2, cancelled_by_id = $3, cancelled_at = $4,
+                    cancellation_reason = $5, updated_at = $6
+                WHERE id = $1
+                  AND status IN ('draft', 'submitted')
+                  AND NOT EXISTS (
+                      SELECT 1 FROM goods_receipts gr WHERE gr.purchase_order_id = $1
+                  )
+                "#,
+            )
+            .bind(order.id().into_uuid())
+            .bind(order.status().to_string())
+            .bind(order.cancelled_by_id().map(|id| id.into_uuid()))
+            .bind(order.cancelled_at())
+            .bind(order.cancellation_reason())
+            .bind(order.updated_at())
+            .execute(&mut *tx)
+            .await?;
+
+            if result.rows_affected() == 0 {
+                return Err(PurchasingError::InvalidStatusTransition);
+            }
+
+            enqueue_status_change(&mut tx, order, &previous_status).await?;
+        }
+
+        tx.commit().await?;
+        Ok(())
+    }
+
+    async fn find_vendor_deliveries(
+        &self,
+        store_id: Option<StoreId>,
+        from: NaiveDate,
+        to: NaiveDate,
+    ) -> Result<Vec<VendorOrderDelivery>, PurchasingError> {
+        let rows = sqlx::query_as::<_, VendorOrderDeliveryRow>(
+            r#"
+            SELECT po.id AS purchase_order_id, po.vendor_id, v.name AS vendor_name,
+                   po.order_date, po.expected_delivery_date,
+                   COALESCE(items.quantity_ordered, 0) AS quantity_ordered,
+                   COALESCE(items.quantity_received, 0) AS quantity_received,
+                   receipts.first_receipt_date, receipts.last_receipt_date
+            FROM purchase_orders po
+            JOIN vendors v ON v.id = po.vendor_id
+            LEFT JOIN LATERAL (
+                SELECT SUM(quantity_ordered) AS quantity_ordered,
+                       SUM(quantity_received) AS quantity_received
+                FROM purchase_order_items
+                WHERE purchase_order_id = po.id
+            ) items ON TRUE
+            LEFT JOIN LATERAL (
+                SELECT MIN(receipt_date) AS first_receipt_date,
+                       MAX(receipt_date) AS last_receipt_date
+                FROM goods_receipts
+                WHERE purchase_order_id = po.id AND status = 'confirmed'
+            ) receipts ON TRUE
+            WHERE po.order_date BETWEEN $1 AND $2
+              AND po.status IN ('approved', 'partially_received', 'received', 'closed')
+              AND ($3::uuid IS NULL OR po.store_id = $3)
+            "#,
+        )
+        .bind(from)
+        .bind(to)
+        .bind(store_id.map(|id| id.into_uuid()))
+        .fetch_all(&self.pool)
         .await?;
 
-        let sequence = count.0 + 1;
-        Ok(format!("PO-{}-{:05}", year, sequence))
+        Ok(rows.into_iter().map(Into::into).collect())
+    }
+
+    async fn generate_order_number(&self, store_id: StoreId) -> Result<String, PurchasingError> {
+        let mut conn = self.pool.acquire().await?;
+        Ok(self
+            .numbering
+            .next_number(
+                &mut conn,
+                DocumentType::PurchaseOrder,
+                Some(store_id.into_uuid()),
+            )
+            .await?)
     }
 
     // -------------------------------------------------------------------------
@@ -371,9 +512,10 @@ impl PurchaseOrderRepository for PgPurchaseOrderRepository {
             INSERT INTO purchase_order_items (
                 id, purchase_order_id, line_number, product_id, variant_id,
                 description, quantity_ordered, quantity_received, unit_of_measure,
-                unit_cost, discount_percent, tax_percent, line_total, notes
+                unit_cost, discount_percent, tax_percent, line_total, notes,
+                pack_size, pack_price
             )
-            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15, $16)
             "#,
         )
         .bind(item.id().into_uuid())
@@ -390,6 +532,8 @@ impl PurchaseOrderRepository for PgPurchaseOrderRepository {
         .bind(item.tax_percent())
         .bind(item.line_total())
         .bind(item.notes())
+        .bind(item.pack_size())
+        .bind(item.pack_price())
         .execute(&self.pool)
         .await?;
 
@@ -403,7 +547,7 @@ impl PurchaseOrderRepository for PgPurchaseOrderRepository {
             SET line_number = $2, product_id = $3, variant_id = $4, description = $5,
                 quantity_ordered = $6, quantity_received = $7, unit_of_measure = $8,
                 unit_cost = $9, discount_percent = $10, tax_percent = $11,
-                line_total = $12, notes = $13
+                line_total = $12, notes = $13, pack_size = $14, pack_price = $15
             WHERE id = $1
             "#,
         )
@@ -420,6 +564,8 @@ impl PurchaseOrderRepository for PgPurchaseOrderRepository {
         .bind(item.tax_percent())
         .bind(item.line_total())
         .bind(item.notes())
+        .bind(item.pack_size())
+        .bind(item.pack_price())
         .execute(&self.pool)
         .await?;
 
@@ -455,7 +601,8 @@ impl PurchaseOrderRepository for PgPurchaseOrderRepository {
             r#"
             SELECT id, purchase_order_id, line_number, product_id, variant_id,
                    description, quantity_ordered, quantity_received, unit_of_measure,
-                   unit_cost, discount_percent, tax_percent, line_total, notes
+                   unit_cost, discount_percent, tax_percent, line_total, notes,
+                   pack_size, pack_price
             FROM purchase_order_items
             WHERE purchase_order_id = $1
             ORDER BY line_number
@@ -476,7 +623,8 @@ impl PurchaseOrderRepository for PgPurchaseOrderRepository {
             r#"
             SELECT id, purchase_order_id, line_number, product_id, variant_id,
                    description, quantity_ordered, quantity_received, unit_of_measure,
-                   unit_cost, discount_percent, tax_percent, line_total, notes
+                   unit_cost, discount_percent, tax_percent, line_total, notes,
+                   pack_size, pack_price
             FROM purchase_order_items
             WHERE id = $1
             "#,
@@ -491,6 +639,83 @@ impl PurchaseOrderRepository for PgPurchaseOrderRepository {
 
 // Transactional methods
 impl PgPurchaseOrderRepository {
+    /// Draws the next order number of a store within an existing
+    /// transaction, so it is given back if the transaction rolls back.
+    pub async fn generate_order_number_in_tx(
+        &self,
+        tx: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+        store_id: StoreId,
+    ) -> Result<String, PurchasingError> {
+        Ok(self
+            .numbering
+            .next_number(tx, DocumentType::PurchaseOrder, Some(store_id.into_uuid()))
+            .await?)
+    }
+
+    /// Inserts a purchase order and its items within an existing transaction.
+    pub async fn save_in_tx(
+        tx: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+        order: &PurchaseOrder,
+    ) -> Result<(), PurchasingError> {
+        // Save the order header
+        sqlx::query(
+            r#"
+            INSERT INTO purchase_orders (
+                id, order_number, store_id, vendor_id, status, order_date,
+                expected_delivery_date, received_date, first_received_date, subtotal, tax_amount,
+                discount_amount, total, currency, payment_terms_days, notes,
+                internal_notes, created_by_id, submitted_by_id, submitted_at,
+                first_approved_by_id, first_approved_at, approved_by_id, approved_at,
+                received_by_id, cancelled_by_id, cancelled_at, cancellation_reason,
+                parent_order_id, created_at, updated_at
+            )
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14,
+                    $15, $16, $17, $18, $19, $20, $21, $22, $23, $24, $25, $26, $27, $28,
+                    $29, $30, $31)
+            "#,
+        )
+        .bind(order.id().into_uuid())
+        .bind(order.order_number())
+        .bind(order.store_id().as_uuid())
+        .bind(order.vendor_id().into_uuid())
+        .bind(order.status().to_string())
+        .bind(order.order_date())
+        .bind(order.expected_delivery_date())
+        .bind(order.received_date())
+        .bind(order.first_received_date())
+        .bind(order.subtotal())
+        .bind(order.tax_amount())
+        .bind(order.discount_amount())
+        .bind(order.total())
+        .bind(order.currency().as_str())
+        .bind(order.payment_terms_days())
+        .bind(order.notes())
+        .bind(order.internal_notes())
+        .bind(order.created_by_id().into_uuid())
+        .bind(order.submitted_by_id().map(|id| id.into_uuid()))
+        .bind(order.submitted_at())
+        .bind(order.first_approved_by_id().map(|id| id.into_uuid()))
+        .bind(order.first_approved_at())
+        .bind(order.approved_by_id().map(|id| id.into_uuid()))
+        .bind(order.approved_at())
+        .bind(order.received_by_id().map(|id| id.into_uuid()))
+        .bind(order.cancelled_by_id().map(|id| id.into_uuid()))
+        .bind(order.cancelled_at())
+        .bind(order.cancellation_reason())
+        .bind(order.parent_order_id().map(|id| id.into_uuid()))
+        .bind(order.created_at())
+        .bind(order.updated_at())
+        .execute(&mut **tx)
+        .await?;
+
+        // Save all items
+        for item in order.items() {
+            Self::save_item_to_tx(tx, item).await?;
+        }
+
+        Ok(())
+    }
+
     /// Updates a purchase order within an existing transaction.
     pub async fn update_in_tx(
         tx: &mut sqlx::Transaction<'_, sqlx::Postgres>,
@@ -505,7 +730,8 @@ impl PgPurchaseOrderRepository {
                 internal_notes = $14, submitted_by_id = $15, submitted_at = $16,
                 approved_by_id = $17, approved_at = $18, received_by_id = $19,
                 cancelled_by_id = $20, cancelled_at = $21, cancellation_reason = $22,
-                updated_at = $23
+                updated_at = $23, first_approved_by_id = $24, first_approved_at = $25,
+                first_received_date = $26
             WHERE id = $1
             "#,
         )
@@ -532,6 +758,9 @@ impl PgPurchaseOrderRepository {
         .bind(order.cancelled_at())
         .bind(order.cancellation_reason())
         .bind(order.updated_at())
+        .bind(order.first_approved_by_id().map(|id| id.into_uuid()))
+        .bind(order.first_approved_at())
+        .bind(order.first_received_date())
         .execute(&mut **tx)
         .await?;
 
@@ -563,9 +792,10 @@ impl PgPurchaseOrderRepository {
             INSERT INTO purchase_order_items (
                 id, purchase_order_id, line_number, product_id, variant_id,
                 description, quantity_ordered, quantity_received, unit_of_measure,
-                unit_cost, discount_percent, tax_percent, line_total, notes
+                unit_cost, discount_percent, tax_percent, line_total, notes,
+                pack_size, pack_price
             )
-            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15, $16)
             "#,
         )
         .bind(item.id().into_uuid())
@@ -582,6 +812,8 @@ impl PgPurchaseOrderRepository {
         .bind(item.tax_percent())
         .bind(item.line_total())
         .bind(item.notes())
+        .bind(item.pack_size())
+        .bind(item.pack_price())
         .execute(&mut **tx)
         .await?;
 
@@ -603,6 +835,51 @@ impl PgPurchaseOrderRepository {
 // Row types for database mapping
 // =============================================================================
 
+/// Locks the order row and returns its stored status
+async fn lock_status(
+    tx: &mut Transaction<'_, Postgres>,
+    order: &PurchaseOrder,
+) -> Result<String, PurchasingError> {
+    let row: Option<(String,)> =
+        sqlx::query_as("SELECT status FROM purchase_orders WHERE id = $1 FOR UPDATE")
+            .bind(order.id().into_uuid())
+            .fetch_optional(&mut **tx)
+            .await?;
+
+    row.map(|(status,)| status)
+        .ok_or_else(|| PurchasingError::PurchaseOrderNotFound(order.id().into_uuid()))
+}
+
+/// Writes a `purchase_order.status_changed` outbox event when the stored
+/// status differs from the order's
+async fn enqueue_status_change(
+    tx: &mut Transaction<'_, Postgres>,
+    order: &PurchaseOrder,
+    previous_status: &str,
+) -> Result<(), PurchasingError> {
+    let status = order.status().to_string();
+    if status == previous_status {
+        return Ok(());
+    }
+
+    let event = OutboxEvent::create(
+        "purchase_order",
+        order.id().into_uuid().to_string(),
+        "purchase_order.status_changed",
+        json!({
+            "purchase_order_id": order.id().into_uuid(),
+            "order_number": order.order_number(),
+            "store_id": order.store_id().into_uuid(),
+            "vendor_id": order.vendor_id().into_uuid(),
+            "previous_status": previous_status,
+            "status": status,
+            "total": order.total(),
+        }),
+    );
+    enqueue_outbox_event_in_tx(tx, &event).await?;
+    Ok(())
+}
+
 #[derive(sqlx::FromRow)]
 struct PurchaseOrderRow {
     id: uuid::Uuid,
@@ -613,6 +890,7 @@ struct PurchaseOrderRow {
     order_date: NaiveDate,
     expected_delivery_date: Option<NaiveDate>,
     received_date: Option<NaiveDate>,
+    first_received_date: Option<NaiveDate>,
     subtotal: Decimal,
     tax_amount: Decimal,
     discount_amount: Decimal,
@@ -624,12 +902,15 @@ struct PurchaseOrderRow {
     created_by_id: uuid::Uuid,
     submitted_by_id: Option<uuid::Uuid>,
     submitted_at: Option<chrono::DateTime<chrono::Utc>>,
+    first_approved_by_id: Option<uuid::Uuid>,
+    first_approved_at: Option<chrono::DateTime<chrono::Utc>>,
     approved_by_id: Option<uuid::Uuid>,
     approved_at: Option<chrono::DateTime<chrono::Utc>>,
     received_by_id: Option<uuid::Uuid>,
     cancelled_by_id: Option<uuid::Uuid>,
     cancelled_at: Option<chrono::DateTime<chrono::Utc>>,
     cancellation_reason: Option<String>,
+    parent_order_id: Option<uuid::Uuid>,
     created_at: chrono::DateTime<chrono::Utc>,
     updated_at: chrono::DateTime<chrono::Utc>,
 }
@@ -655,6 +936,7 @@ impl PurchaseOrderRow {
             self.order_date,
             self.expected_delivery_date,
             self.received_date,
+            self.first_received_date,
             self.subtotal,
             self.tax_amount,
             self.discount_amount,
@@ -666,12 +948,15 @@ impl PurchaseOrderRow {
             UserId::from_uuid(self.created_by_id),
             self.submitted_by_id.map(UserId::from_uuid),
             self.submitted_at,
+            self.first_approved_by_id.map(UserId::from_uuid),
+            self.first_approved_at,
             self.approved_by_id.map(UserId::from_uuid),
             self.approved_at,
             self.received_by_id.map(UserId::from_uuid),
             self.cancelled_by_id.map(UserId::from_uuid),
             self.cancelled_at,
             self.cancellation_reason,
+            self.parent_order_id.map(PurchaseOrderId::from_uuid),
             items,
             self.created_at,
             self.updated_at,
@@ -695,6 +980,8 @@ struct PurchaseOrderItemRow {
     tax_percent: Decimal,
     line_total: Decimal,
     notes: Option<String>,
+    pack_size: Option<Decimal>,
+    pack_price: Option<Decimal>,
 }
 
 impl TryFrom<PurchaseOrderItemRow> for PurchaseOrderItem {
@@ -721,6 +1008,37 @@ impl TryFrom<PurchaseOrderItemRow> for PurchaseOrderItem {
             row.tax_percent,
             row.line_total,
             row.notes,
+            row.pack_size,
+            row.pack_price,
         ))
     }
 }
+
+#[derive(sqlx::FromRow)]
+struct VendorOrderDeliveryRow {
+    purchase_order_id: uuid::Uuid,
+    vendor_id: uuid::Uuid,
+    vendor_name: String,
+    order_date: NaiveDate,
+    expected_delivery_date: Option<NaiveDate>,
+    quantity_ordered: Decimal,
+    quantity_received: Decimal,
+    first_receipt_date: Option<NaiveDate>,
+    last_receipt_date: Option<NaiveDate>,
+}
+
+impl From<VendorOrderDeliveryRow> for VendorOrderDelivery {
+    fn from(row: VendorOrderDeliveryRow) -> Self {
+        VendorOrderDelivery {
+            purchase_order_id: PurchaseOrderId::from_uuid(row.purchase_order_id),
+            vendor_id: VendorId::from_uuid(row.vendor_id),
+            vendor_name: row.vendor_name,
+            order_date: row.order_date,
+            expected_delivery_date: row.expected_delivery_date,
+            quantity_ordered: row.quantity_ordered,
+            quantity_received: row.quantity_received,
+            first_receipt_date: row.first_receipt_date,
+            last_receipt_date: row.last_receipt_date,
+        }
+    }
+}
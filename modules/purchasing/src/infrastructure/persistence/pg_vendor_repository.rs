@@ -27,10 +27,10 @@ impl VendorRepository for PgVendorRepository {
         sqlx::query(
             r#"
             INSERT INTO vendors (
-                id, code, name, legal_name, tax_id, email, phone, address,
+                id, code, name, legal_name, tax_id, tax_id_country, email, phone, address,
                 payment_terms_days, currency, is_active, notes, created_at, updated_at
             )
-            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15)
             "#,
         )
         .bind(vendor.id().into_uuid())
@@ -38,6 +38,7 @@ impl VendorRepository for PgVendorRepository {
         .bind(vendor.name())
         .bind(vendor.legal_name())
         .bind(vendor.tax_id())
+        .bind(vendor.tax_id_country())
         .bind(vendor.email())
         .bind(vendor.phone())
         .bind(vendor.address())
@@ -56,7 +57,7 @@ impl VendorRepository for PgVendorRepository {
     async fn find_by_id(&self, id: VendorId) -> Result<Option<Vendor>, PurchasingError> {
         let row = sqlx::query_as::<_, VendorRow>(
             r#"
-            SELECT id, code, name, legal_name, tax_id, email, phone, address,
+            SELECT id, code, name, legal_name, tax_id, tax_id_country, email, phone, address,
                    payment_terms_days, currency, is_active, notes, created_at, updated_at
             FROM vendors
             WHERE id = $1
@@ -72,7 +73,7 @@ impl VendorRepository for PgVendorRepository {
     async fn find_by_code(&self, code: &str) -> Result<Option<Vendor>, PurchasingError> {
         let row = sqlx::query_as::<_, VendorRow>(
             r#"
-            SELECT id, code, name, legal_name, tax_id, email, phone, address,
+            SELECT id, code, name, legal_name, tax_id, tax_id_country, email, phone, address,
                    payment_terms_days, currency, is_active, notes, created_at, updated_at
             FROM vendors
             WHERE code = $1
@@ -91,7 +92,7 @@ impl VendorRepository for PgVendorRepository {
             UPDATE vendors
             SET code = $2, name = $3, legal_name = $4, tax_id = $5, email = $6,
                 phone = $7, address = $8, payment_terms_days = $9, currency = $10,
-                is_active = $11, notes = $12, updated_at = $13
+                is_active = $11, notes = $12, updated_at = $13, tax_id_country = $14
             WHERE id = $1
             "#,
         )
@@ -108,6 +109,7 @@ impl VendorRepository for PgVendorRepository {
         .bind(vendor.is_active())
         .bind(vendor.notes())
         .bind(vendor.updated_at())
+        .bind(vendor.tax_id_country())
         .execute(&self.pool)
         .await?;
 
@@ -124,7 +126,11 @@ impl VendorRepository for PgVendorRepository {
         page: i64,
         page_size: i64,
     ) -> Result<(Vec<Vendor>, i64), PurchasingError> {
-        let offset = (page - 1) * page_size;
+        let offset = if filter.after_id.is_some() {
+            0
+        } else {
+            (page - 1) * page_size
+        };
 
         // Build dynamic query for counting
         let mut count_query = String::from("SELECT COUNT(*) FROM vendors WHERE 1=1");
@@ -153,7 +159,7 @@ impl VendorRepository for PgVendorRepository {
 
         // Build data query
         let mut data_query = String::from(
-            r#"SELECT id, code, name, legal_name, tax_id, email, phone, address,
+            r#"SELECT id, code, name, legal_name, tax_id, tax_id_country, email, phone, address,
                    payment_terms_days, currency, is_active, notes, created_at, updated_at
             FROM vendors
             WHERE 1=1"#,
@@ -171,8 +177,16 @@ impl VendorRepository for PgVendorRepository {
             ));
             param_idx += 1;
         }
+        // The cursor row's place in the name order, ids breaking ties
+        if filter.after_id.is_some() {
+            data_query.push_str(&format!(
+                " AND (name, id) > (SELECT name, id FROM vendors WHERE id = ${})",
+                param_idx
+            ));
+            param_idx += 1;
+        }
         data_query.push_str(&format!(
-            " ORDER BY name LIMIT ${} OFFSET ${}",
+            " ORDER BY name, id LIMIT ${} OFFSET ${}",
             param_idx,
             param_idx + 1
         ));
@@ -185,6 +199,9 @@ impl VendorRepository for PgVendorRepository {
         if let Some(ref search) = filter.search {
             data_builder = data_builder.bind(format!("%{}%", search));
         }
+        if let Some(after_id) = filter.after_id {
+            data_builder = data_builder.bind(after_id.into_uuid());
+        }
         data_builder = data_builder.bind(page_size).bind(offset);
 
         let rows = data_builder.fetch_all(&self.pool).await?;
@@ -287,6 +304,7 @@ struct VendorRow {
     name: String,
     legal_name: String,
     tax_id: String,
+    tax_id_country: Option<String>,
     email: Option<String>,
     phone: Option<String>,
     address: Option<String>,
@@ -310,6 +328,7 @@ impl TryFrom<VendorRow> for Vendor {
             row.name,
             row.legal_name,
             row.tax_id,
+            row.tax_id_country,
             row.email,
             row.phone,
             row.address,
@@ -6,6 +6,7 @@
 //! - **Vendor Management**: CRUD operations for suppliers/vendors
 //! - **Purchase Orders**: Order creation with approval workflow
 //! - **Goods Receipts**: Receiving merchandise and updating inventory
+//! - **Purchase Cost History**: Last N prices paid for a product
 //!
 //! ## Architecture
 //!
@@ -57,6 +58,14 @@ pub use domain::value_objects::VendorId;
 // Enum value objects
 pub use domain::value_objects::GoodsReceiptStatus;
 pub use domain::value_objects::PurchaseOrderStatus;
+pub use domain::value_objects::ReceiptMatchStatus;
+
+// Policy value objects
+pub use domain::value_objects::PurchaseApprovalPolicy;
+pub use domain::value_objects::PurchaseApprovalTier;
+pub use domain::value_objects::ReceiptLineVariance;
+pub use domain::value_objects::ReceiptMatch;
+pub use domain::value_objects::ReceiptMatchPolicy;
 
 // -----------------------------------------------------------------------------
 // Domain Layer - Entities
@@ -76,7 +85,10 @@ pub use domain::repositories::GoodsReceiptFilter;
 pub use domain::repositories::GoodsReceiptRepository;
 pub use domain::repositories::PurchaseOrderFilter;
 pub use domain::repositories::PurchaseOrderRepository;
+pub use domain::repositories::ReceivedUnitCost;
+pub use domain::repositories::StalePurchaseOrderFilter;
 pub use domain::repositories::VendorFilter;
+pub use domain::repositories::VendorOrderDelivery;
 pub use domain::repositories::VendorRepository;
 
 // -----------------------------------------------------------------------------
@@ -93,6 +105,7 @@ pub use infrastructure::persistence::PgVendorRepository;
 
 // Command DTOs
 pub use application::dtos::commands::AddOrderItemCommand;
+pub use application::dtos::commands::BulkCancelPurchaseOrdersCommand;
 pub use application::dtos::commands::CancelOrderCommand;
 pub use application::dtos::commands::CreateGoodsReceiptCommand;
 pub use application::dtos::commands::CreateGoodsReceiptItemCommand;
@@ -100,18 +113,25 @@ pub use application::dtos::commands::CreatePurchaseOrderCommand;
 pub use application::dtos::commands::CreatePurchaseOrderItemCommand;
 pub use application::dtos::commands::CreateVendorCommand;
 pub use application::dtos::commands::RejectOrderCommand;
+pub use application::dtos::commands::SubmitOrderCommand;
 pub use application::dtos::commands::UpdateGoodsReceiptCommand;
 pub use application::dtos::commands::UpdateOrderItemCommand;
 pub use application::dtos::commands::UpdatePurchaseOrderCommand;
 pub use application::dtos::commands::UpdateVendorCommand;
 
 // Response DTOs
+pub use application::dtos::responses::BulkCancelOrderResult;
+pub use application::dtos::responses::BulkCancelPurchaseOrdersResponse;
 pub use application::dtos::responses::GoodsReceiptDetailResponse;
 pub use application::dtos::responses::GoodsReceiptItemResponse;
 pub use application::dtos::responses::GoodsReceiptResponse;
 pub use application::dtos::responses::PurchaseOrderDetailResponse;
 pub use application::dtos::responses::PurchaseOrderItemResponse;
 pub use application::dtos::responses::PurchaseOrderResponse;
+pub use application::dtos::responses::RecentPurchaseCostsResponse;
+pub use application::dtos::responses::RecentUnitCostResponse;
+pub use application::dtos::responses::VendorPerformanceEntry;
+pub use application::dtos::responses::VendorPerformanceResponse;
 pub use application::dtos::responses::VendorResponse;
 
 // -----------------------------------------------------------------------------
@@ -120,14 +140,17 @@ pub use application::dtos::responses::VendorResponse;
 
 // Vendor Use Cases
 pub use application::use_cases::CreateVendorUseCase;
+pub use application::use_cases::GetVendorPerformanceUseCase;
 pub use application::use_cases::GetVendorUseCase;
 pub use application::use_cases::ListVendorsQuery;
 pub use application::use_cases::ListVendorsUseCase;
 pub use application::use_cases::ToggleVendorStatusUseCase;
 pub use application::use_cases::UpdateVendorUseCase;
+pub use application::use_cases::VendorPerformanceQuery;
 
 // Purchase Order Use Cases
 pub use application::use_cases::ApprovePurchaseOrderUseCase;
+pub use application::use_cases::BulkCancelPurchaseOrdersUseCase;
 pub use application::use_cases::CancelPurchaseOrderUseCase;
 pub use application::use_cases::ClosePurchaseOrderUseCase;
 pub use application::use_cases::CreatePurchaseOrderUseCase;
@@ -145,3 +168,8 @@ pub use application::use_cases::CreateGoodsReceiptUseCase;
 pub use application::use_cases::GetGoodsReceiptUseCase;
 pub use application::use_cases::ListGoodsReceiptsQuery;
 pub use application::use_cases::ListGoodsReceiptsUseCase;
+
+// Purchase Cost History Use Cases
+pub use application::use_cases::DEFAULT_RECENT_COSTS_LIMIT;
+pub use application::use_cases::GetRecentPurchaseCostsUseCase;
+pub use application::use_cases::RecentPurchaseCostsQuery;
@@ -28,6 +28,7 @@ pub struct CartResponse {
     pub notes: Option<String>,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
+    pub last_activity_at: DateTime<Utc>,
 }
 
 impl From<Cart> for CartResponse {
@@ -51,6 +52,7 @@ impl From<Cart> for CartResponse {
             expires_at: c.expires_at(),
             created_at: c.created_at(),
             updated_at: c.updated_at(),
+            last_activity_at: c.last_activity_at(),
             items,
         }
     }
@@ -97,3 +99,20 @@ impl From<&CartItem> for CartItemResponse {
         }
     }
 }
+
+/// Result of one abandoned-cart cleanup run
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct CleanupCartsResult {
+    /// Carts deleted because they were expired or inactive beyond their TTL
+    pub carts_deleted: usize,
+    /// Stale carts left alone because an order placed from them holds a
+    /// confirmed reservation (checkout in progress)
+    pub skipped_in_checkout: usize,
+    /// Pending reservations cancelled while deleting carts
+    pub reservations_released: usize,
+    /// Total reserved quantity returned to available stock
+    pub quantity_released: Decimal,
+    /// Carts that could not be cleaned up
+    pub failed: usize,
+    pub errors: Vec<String>,
+}
@@ -7,28 +7,54 @@ use uuid::Uuid;
 /// Command to create a new credit note
 #[derive(Debug, Deserialize)]
 pub struct CreateCreditNoteCommand {
-    pub original_sale_id: Uuid,
+    /// Sale being returned; omitted for returns without a receipt
+    pub original_sale_id: Option<Uuid>,
     pub store_id: Uuid,
+    #[serde(default)]
     pub original_invoice_number: String,
     pub return_type: String,
     pub return_reason: String,
     pub reason_details: Option<String>,
     pub notes: Option<String>,
+    /// Customer whose store credit a receiptless return is refunded to
+    pub customer_id: Option<Uuid>,
+    /// Lines returned; more can be added later while the note is a draft
+    #[serde(default)]
+    pub items: Vec<CreditNoteLineCommand>,
+    /// Whether the actor may approve returns without a receipt (set by
+    /// access control, not by the client)
+    #[serde(skip)]
+    pub manager_override: bool,
+}
+
+/// A returned quantity of an original sale line. Price, discount and tax are
+/// taken from the sale, with sale-level discounts allocated to the line.
+///
+/// Receiptless returns name the product (and variant) instead, priced at its
+/// current catalog price and tax rate.
+#[derive(Debug, Deserialize)]
+pub struct CreditNoteLineCommand {
+    pub original_sale_item_id: Option<Uuid>,
+    pub product_id: Option<Uuid>,
+    pub variant_id: Option<Uuid>,
+    pub return_quantity: Decimal,
+    /// Serials of the returned units, required for lines sold with serials
+    #[serde(default)]
+    pub serial_numbers: Vec<String>,
 }
 
-/// Command to add an item to a credit note
+/// Command to add an item to a credit note. Price, discount and tax are taken
+/// from the original sale line, or from the catalog on receiptless returns.
 #[derive(Debug, Deserialize)]
 pub struct AddCreditNoteItemCommand {
     pub credit_note_id: Uuid,
-    pub original_sale_item_id: Uuid,
-    pub product_id: Uuid,
+    pub original_sale_item_id: Option<Uuid>,
+    pub product_id: Option<Uuid>,
     pub variant_id: Option<Uuid>,
-    pub sku: String,
-    pub description: String,
     pub return_quantity: Decimal,
-    pub unit_of_measure: String,
-    pub unit_price: Decimal,
-    pub tax_rate: Decimal,
+    /// Serials of the returned units, required for lines sold with serials
+    #[serde(default)]
+    pub serial_numbers: Vec<String>,
 }
 
 /// Command to submit a credit note for approval
@@ -47,7 +73,12 @@ pub struct ApproveCreditNoteCommand {
 #[derive(Debug, Deserialize)]
 pub struct ApplyCreditNoteCommand {
     pub credit_note_id: Uuid,
-    pub refund_method: String,
+    /// Defaults to store credit on receiptless returns and cash otherwise
+    pub refund_method: Option<String>,
+    /// Credits the refund to the customer's store credit instead of paying
+    /// it out
+    #[serde(default)]
+    pub refund_to_store_credit: bool,
 }
 
 /// Command to cancel a credit note
@@ -62,12 +93,16 @@ pub struct CancelCreditNoteCommand {
 pub struct ListCreditNotesQuery {
     pub store_id: Option<Uuid>,
     pub original_sale_id: Option<Uuid>,
+    /// Only returns without (`true`) or with (`false`) a receipt
+    pub receiptless: Option<bool>,
     pub status: Option<String>,
     pub search: Option<String>,
     #[serde(default = "default_page")]
     pub page: i64,
     #[serde(default = "default_page_size")]
     pub page_size: i64,
+    /// `next_cursor` of a previous page; when set, `page` is ignored
+    pub after_cursor: Option<String>,
 }
 
 fn default_page() -> i64 {
@@ -6,13 +6,16 @@ use serde::Serialize;
 use uuid::Uuid;
 
 use crate::domain::entities::{CreditNote, CreditNoteItem};
+use crate::domain::value_objects::{
+    CreditNoteDocument, CreditNoteDocumentLine, CreditNoteDocumentSale,
+};
 
 /// Response DTO for a credit note item
 #[derive(Debug, Serialize, Clone)]
 pub struct CreditNoteItemResponse {
     pub id: Uuid,
     pub credit_note_id: Uuid,
-    pub original_sale_item_id: Uuid,
+    pub original_sale_item_id: Option<Uuid>,
     pub product_id: Uuid,
     pub variant_id: Option<Uuid>,
     pub sku: String,
@@ -21,10 +24,12 @@ pub struct CreditNoteItemResponse {
     pub unit_of_measure: String,
     pub unit_price: Decimal,
     pub tax_rate: Decimal,
+    pub discount_amount: Decimal,
     pub tax_amount: Decimal,
     pub subtotal: Decimal,
     pub total: Decimal,
     pub restock: bool,
+    pub serial_numbers: Vec<String>,
     pub condition: Option<String>,
     pub notes: Option<String>,
     pub created_at: DateTime<Utc>,
@@ -36,7 +41,7 @@ impl From<&CreditNoteItem> for CreditNoteItemResponse {
         Self {
             id: item.id().into_uuid(),
             credit_note_id: item.credit_note_id().into_uuid(),
-            original_sale_item_id: item.original_sale_item_id().into_uuid(),
+            original_sale_item_id: item.original_sale_item_id().map(|id| id.into_uuid()),
             product_id: item.product_id().into_uuid(),
             variant_id: item.variant_id().map(|id| id.into_uuid()),
             sku: item.sku().to_string(),
@@ -45,10 +50,12 @@ impl From<&CreditNoteItem> for CreditNoteItemResponse {
             unit_of_measure: item.unit_of_measure().to_string(),
             unit_price: item.unit_price(),
             tax_rate: item.tax_rate(),
+            discount_amount: item.discount_amount(),
             tax_amount: item.tax_amount(),
             subtotal: item.subtotal(),
             total: item.total(),
             restock: item.restock(),
+            serial_numbers: item.serial_numbers().to_vec(),
             condition: item.condition().map(|s| s.to_string()),
             notes: item.notes().map(|s| s.to_string()),
             created_at: item.created_at(),
@@ -63,14 +70,18 @@ pub struct CreditNoteResponse {
     pub id: Uuid,
     pub credit_note_number: String,
     pub store_id: Uuid,
-    pub original_sale_id: Uuid,
+    pub original_sale_id: Option<Uuid>,
     pub original_invoice_number: String,
+    pub customer_id: Option<Uuid>,
+    /// Return without a receipt, approved by a manager override
+    pub receiptless: bool,
     pub status: String,
     pub return_type: String,
     pub return_reason: String,
     pub reason_details: Option<String>,
     pub currency: String,
     pub subtotal: Decimal,
+    pub discount_amount: Decimal,
     pub tax_amount: Decimal,
     pub total: Decimal,
     pub refund_method: Option<String>,
@@ -102,14 +113,17 @@ impl From<CreditNote> for CreditNoteResponse {
             id: cn.id().into_uuid(),
             credit_note_number: cn.credit_note_number().to_string(),
             store_id: cn.store_id().into_uuid(),
-            original_sale_id: cn.original_sale_id().into_uuid(),
+            original_sale_id: cn.original_sale_id().map(|id| id.into_uuid()),
             original_invoice_number: cn.original_invoice_number().to_string(),
+            customer_id: cn.customer_id().map(|id| id.into_uuid()),
+            receiptless: cn.is_receiptless(),
             status: cn.status().to_string(),
             return_type: cn.return_type().to_string(),
             return_reason: cn.return_reason().to_string(),
             reason_details: cn.reason_details().map(|s| s.to_string()),
             currency: cn.currency().as_str().to_string(),
             subtotal: cn.subtotal(),
+            discount_amount: cn.discount_amount(),
             tax_amount: cn.tax_amount(),
             total: cn.total(),
             refund_method: cn.refund_method().map(|s| s.to_string()),
@@ -139,4 +153,121 @@ pub struct CreditNoteListResponse {
     pub total: i64,
     pub page: i64,
     pub page_size: i64,
+    /// Pass back as `after_cursor` for the next page; `None` on the last page
+    pub next_cursor: Option<String>,
+}
+
+/// Response DTO for the original sale a credit note document references
+#[derive(Debug, Serialize)]
+pub struct CreditNoteDocumentSaleResponse {
+    pub sale_id: Uuid,
+    pub sale_number: String,
+    pub invoice_number: Option<String>,
+    pub cai_number: Option<String>,
+    pub invoice_date: Option<DateTime<Utc>>,
+}
+
+impl From<&CreditNoteDocumentSale> for CreditNoteDocumentSaleResponse {
+    fn from(sale: &CreditNoteDocumentSale) -> Self {
+        Self {
+            sale_id: sale.sale_id,
+            sale_number: sale.sale_number.clone(),
+            invoice_number: sale.invoice_number.clone(),
+            cai_number: sale.cai_number.clone(),
+            invoice_date: sale.invoice_date,
+        }
+    }
+}
+
+/// Response DTO for a line of a credit note document
+#[derive(Debug, Serialize)]
+pub struct CreditNoteDocumentLineResponse {
+    pub line_number: i32,
+    pub sku: String,
+    pub description: String,
+    pub quantity: Decimal,
+    pub unit_of_measure: String,
+    pub unit_price: Decimal,
+    pub discount_amount: Decimal,
+    pub tax_rate: Decimal,
+    pub tax_amount: Decimal,
+    pub subtotal: Decimal,
+    pub total: Decimal,
+    pub serial_numbers: Vec<String>,
+}
+
+impl From<&CreditNoteDocumentLine> for CreditNoteDocumentLineResponse {
+    fn from(line: &CreditNoteDocumentLine) -> Self {
+        Self {
+            line_number: line.line_number,
+            sku: line.sku.clone(),
+            description: line.description.clone(),
+            quantity: line.quantity,
+            unit_of_measure: line.unit_of_measure.clone(),
+            unit_price: line.unit_price,
+            discount_amount: line.discount_amount,
+            tax_rate: line.tax_rate,
+            tax_amount: line.tax_amount,
+            subtotal: line.subtotal,
+            total: line.total,
+            serial_numbers: line.serial_numbers.clone(),
+        }
+    }
+}
+
+/// Response DTO for a printable credit note document
+#[derive(Debug, Serialize)]
+pub struct CreditNoteDocumentResponse {
+    pub document_number: String,
+    pub issued_at: DateTime<Utc>,
+    pub credit_note_id: Uuid,
+    pub credit_note_number: String,
+    pub store_id: Uuid,
+    pub customer_id: Option<Uuid>,
+    pub original_sale: Option<CreditNoteDocumentSaleResponse>,
+    pub return_type: String,
+    pub return_reason: String,
+    pub reason_details: Option<String>,
+    pub refund_method: String,
+    pub currency: String,
+    pub lines: Vec<CreditNoteDocumentLineResponse>,
+    pub subtotal: Decimal,
+    pub discount_amount: Decimal,
+    pub tax_amount: Decimal,
+    pub total: Decimal,
+    pub approved_by_id: Option<Uuid>,
+    pub approved_at: Option<DateTime<Utc>>,
+}
+
+impl From<&CreditNoteDocument> for CreditNoteDocumentResponse {
+    fn from(doc: &CreditNoteDocument) -> Self {
+        Self {
+            document_number: doc.document_number.clone(),
+            issued_at: doc.issued_at,
+            credit_note_id: doc.credit_note_id,
+            credit_note_number: doc.credit_note_number.clone(),
+            store_id: doc.store_id,
+            customer_id: doc.customer_id,
+            original_sale: doc
+                .original_sale
+                .as_ref()
+                .map(CreditNoteDocumentSaleResponse::from),
+            return_type: doc.return_type.clone(),
+            return_reason: doc.return_reason.clone(),
+            reason_details: doc.reason_details.clone(),
+            refund_method: doc.refund_method.clone(),
+            currency: doc.currency.clone(),
+            lines: doc
+                .lines
+                .iter()
+                .map(CreditNoteDocumentLineResponse::from)
+                .collect(),
+            subtotal: doc.subtotal,
+            discount_amount: doc.discount_amount,
+            tax_amount: doc.tax_amount,
+            total: doc.total,
+            approved_by_id: doc.approved_by_id,
+            approved_at: doc.approved_at,
+        }
+    }
 }
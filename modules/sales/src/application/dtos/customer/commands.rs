@@ -1,5 +1,7 @@
 //! Customer command DTOs
 
+use chrono::NaiveDate;
+use rust_decimal::Decimal;
 use serde::Deserialize;
 use uuid::Uuid;
 
@@ -14,7 +16,12 @@ pub struct CreateCustomerCommand {
     pub email: Option<String>,
     pub phone: Option<String>,
     pub tax_id: Option<String>,
+    /// Country whose format the tax id follows; defaults to the configured
+    /// tax id country
+    pub tax_id_country: Option<String>,
     pub billing_address: Option<AddressInput>,
+    /// Amount the customer may owe on account; omitted or 0 disables it
+    pub credit_limit: Option<Decimal>,
     pub notes: Option<String>,
 }
 
@@ -28,7 +35,9 @@ pub struct UpdateCustomerCommand {
     pub email: Option<String>,
     pub phone: Option<String>,
     pub tax_id: Option<String>,
+    pub tax_id_country: Option<String>,
     pub billing_address: Option<AddressInput>,
+    pub credit_limit: Option<Decimal>,
     pub notes: Option<String>,
 }
 
@@ -52,4 +61,41 @@ pub struct ListCustomersQuery {
     pub is_active: Option<bool>,
     pub page: Option<i64>,
     pub page_size: Option<i64>,
+    /// `next_cursor` of a previous page; when set, `page` is ignored
+    pub after_cursor: Option<String>,
+}
+
+/// Lookup of a customer by its human-friendly code
+#[derive(Debug, Deserialize)]
+pub struct GetCustomerByCodeQuery {
+    pub store_id: Uuid,
+    pub code: String,
+}
+
+/// Command to configure the customer code format of a store
+#[derive(Debug, Deserialize)]
+pub struct ConfigureCustomerCodeFormatCommand {
+    #[serde(default)]
+    pub store_id: Uuid,
+    pub prefix: String,
+    pub padding: u8,
+}
+
+/// Command to record a payment made by a customer against their account
+#[derive(Debug, Deserialize)]
+pub struct RecordAccountPaymentCommand {
+    #[serde(default)]
+    pub customer_id: Uuid,
+    pub amount: Decimal,
+    /// How the customer paid (cash, bank_transfer, ...); can't be on_account
+    pub payment_method: String,
+    pub reference: Option<String>,
+    pub notes: Option<String>,
+}
+
+/// Period of a customer account statement; both dates are inclusive
+#[derive(Debug, Deserialize)]
+pub struct CustomerStatementQuery {
+    pub from: NaiveDate,
+    pub to: NaiveDate,
 }
@@ -1,11 +1,12 @@
 //! Customer response DTOs
 
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, NaiveDate, Utc};
 use rust_decimal::Decimal;
 use serde::Serialize;
 use uuid::Uuid;
 
-use crate::domain::entities::{Address, Customer};
+use crate::domain::entities::{Address, Customer, CustomerAccountEntry};
+use crate::domain::value_objects::{CustomerCodeFormat, CustomerStatement};
 
 /// Response for a single customer
 #[derive(Debug, Serialize)]
@@ -21,11 +22,16 @@ pub struct CustomerResponse {
     pub email: Option<String>,
     pub phone: Option<String>,
     pub tax_id: Option<String>,
+    pub tax_id_country: Option<String>,
     pub billing_address: Option<AddressResponse>,
     pub is_active: bool,
     pub total_purchases: Decimal,
     pub purchase_count: i32,
     pub last_purchase_at: Option<DateTime<Utc>>,
+    pub credit_limit: Decimal,
+    pub account_balance: Decimal,
+    pub available_credit: Decimal,
+    pub store_credit_balance: Decimal,
     pub notes: Option<String>,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
@@ -45,6 +51,7 @@ impl From<Customer> for CustomerResponse {
             email: c.email().map(String::from),
             phone: c.phone().map(String::from),
             tax_id: c.tax_id().map(String::from),
+            tax_id_country: c.tax_id_country().map(String::from),
             billing_address: if c.billing_address().is_empty() {
                 None
             } else {
@@ -54,6 +61,10 @@ impl From<Customer> for CustomerResponse {
             total_purchases: c.total_purchases(),
             purchase_count: c.purchase_count(),
             last_purchase_at: c.last_purchase_at(),
+            credit_limit: c.credit_limit(),
+            account_balance: c.account_balance(),
+            available_credit: c.available_credit(),
+            store_credit_balance: c.store_credit_balance(),
             notes: c.notes().map(String::from),
             created_at: c.created_at(),
             updated_at: c.updated_at(),
@@ -93,4 +104,136 @@ pub struct CustomerListResponse {
     pub page: i64,
     pub page_size: i64,
     pub total_pages: i64,
+    /// Pass back as `after_cursor` for the next page; `None` on the last page
+    pub next_cursor: Option<String>,
+}
+
+/// A customer's store credit balance
+#[derive(Debug, Serialize)]
+pub struct CustomerCreditBalanceResponse {
+    pub customer_id: Uuid,
+    pub balance: Decimal,
+}
+
+/// Customer code format configured for a store
+#[derive(Debug, Serialize)]
+pub struct CustomerCodeFormatResponse {
+    pub store_id: Uuid,
+    pub prefix: String,
+    pub padding: u8,
+    /// Sample code rendered with this format
+    pub example: String,
+}
+
+impl CustomerCodeFormatResponse {
+    pub fn new(store_id: Uuid, format: &CustomerCodeFormat) -> Self {
+        Self {
+            store_id,
+            prefix: format.prefix().to_string(),
+            padding: format.padding(),
+            example: format.format(1),
+        }
+    }
+}
+
+/// A posted entry on a customer's account
+#[derive(Debug, Serialize)]
+pub struct CustomerAccountEntryResponse {
+    pub id: Uuid,
+    pub customer_id: Uuid,
+    pub entry_type: String,
+    /// Positive for charges, negative for credits
+    pub amount: Decimal,
+    pub balance_after: Decimal,
+    pub reference_id: Option<Uuid>,
+    pub reference_number: Option<String>,
+    pub notes: Option<String>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl From<CustomerAccountEntry> for CustomerAccountEntryResponse {
+    fn from(e: CustomerAccountEntry) -> Self {
+        Self {
+            id: e.id(),
+            customer_id: e.customer_id().into_uuid(),
+            entry_type: e.entry_type().to_string(),
+            amount: e.amount(),
+            balance_after: e.balance_after(),
+            reference_id: e.reference_id(),
+            reference_number: e.reference_number().map(String::from),
+            notes: e.notes().map(String::from),
+            created_at: e.created_at(),
+        }
+    }
+}
+
+/// A line of a customer account statement
+#[derive(Debug, Serialize)]
+pub struct CustomerStatementLineResponse {
+    pub date: DateTime<Utc>,
+    pub entry_type: String,
+    pub reference_id: Option<Uuid>,
+    pub reference_number: Option<String>,
+    pub notes: Option<String>,
+    pub debit: Decimal,
+    pub credit: Decimal,
+    pub balance: Decimal,
+}
+
+/// Customer account statement for a period.
+///
+/// `opening_balance + total_debits - total_credits == closing_balance`;
+/// `is_reconciled` is false if a recorded balance disagrees with the
+/// running balance.
+#[derive(Debug, Serialize)]
+pub struct CustomerStatementResponse {
+    pub customer_id: Uuid,
+    pub customer_code: String,
+    pub customer_name: String,
+    pub from: NaiveDate,
+    pub to: NaiveDate,
+    pub credit_limit: Decimal,
+    pub opening_balance: Decimal,
+    pub total_debits: Decimal,
+    pub total_credits: Decimal,
+    pub closing_balance: Decimal,
+    pub is_reconciled: bool,
+    pub lines: Vec<CustomerStatementLineResponse>,
+}
+
+impl CustomerStatementResponse {
+    pub fn new(
+        customer: &Customer,
+        from: NaiveDate,
+        to: NaiveDate,
+        statement: CustomerStatement,
+    ) -> Self {
+        Self {
+            customer_id: customer.id().into_uuid(),
+            customer_code: customer.code().to_string(),
+            customer_name: customer.display_name(),
+            from,
+            to,
+            credit_limit: customer.credit_limit(),
+            opening_balance: statement.opening_balance(),
+            total_debits: statement.total_debits(),
+            total_credits: statement.total_credits(),
+            closing_balance: statement.closing_balance(),
+            is_reconciled: statement.is_reconciled(),
+            lines: statement
+                .into_lines()
+                .into_iter()
+                .map(|line| CustomerStatementLineResponse {
+                    date: line.entry.created_at(),
+                    entry_type: line.entry.entry_type().to_string(),
+                    reference_id: line.entry.reference_id(),
+                    reference_number: line.entry.reference_number().map(String::from),
+                    notes: line.entry.notes().map(String::from),
+                    debit: line.debit,
+                    credit: line.credit,
+                    balance: line.running_balance,
+                })
+                .collect(),
+        }
+    }
 }
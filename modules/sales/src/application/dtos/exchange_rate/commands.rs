@@ -0,0 +1,16 @@
+// Command DTOs for exchange rate operations
+
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// Command to record the rate a store takes a foreign currency at, from now
+/// on
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RecordExchangeRateCommand {
+    pub store_id: Uuid,
+    /// ISO 4217 code of the foreign currency
+    pub currency: String,
+    /// Units of the base currency one unit of `currency` is worth
+    pub rate: Decimal,
+}
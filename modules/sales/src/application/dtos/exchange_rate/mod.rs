@@ -0,0 +1,5 @@
+pub mod commands;
+pub mod responses;
+
+pub use commands::*;
+pub use responses::*;
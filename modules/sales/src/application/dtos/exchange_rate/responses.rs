@@ -0,0 +1,34 @@
+// Response DTOs for exchange rate operations
+
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::domain::entities::ExchangeRate;
+
+/// Response for an exchange rate
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExchangeRateResponse {
+    pub id: Uuid,
+    pub store_id: Uuid,
+    pub currency: String,
+    pub rate: Decimal,
+    pub effective_at: DateTime<Utc>,
+    pub created_by_id: Uuid,
+    pub created_at: DateTime<Utc>,
+}
+
+impl From<&ExchangeRate> for ExchangeRateResponse {
+    fn from(r: &ExchangeRate) -> Self {
+        Self {
+            id: r.id(),
+            store_id: r.store_id(),
+            currency: r.currency().to_string(),
+            rate: r.rate(),
+            effective_at: r.effective_at(),
+            created_by_id: r.created_by_id().into_uuid(),
+            created_at: r.created_at(),
+        }
+    }
+}
@@ -0,0 +1,23 @@
+// Command DTOs for gift card operations
+
+use rust_decimal::Decimal;
+use serde::Deserialize;
+use uuid::Uuid;
+
+/// Command to issue a gift card.
+///
+/// With a `sale_id` the card is sold on that sale: a line for `product_id`
+/// (the catalog product that represents gift cards) is added at the card's
+/// amount, and the card becomes usable once the sale is completed. Without
+/// one the card is active at once, e.g. for promotions or replacements.
+#[derive(Debug, Deserialize)]
+pub struct IssueGiftCardCommand {
+    pub store_id: Uuid,
+    pub amount: Decimal,
+    /// Defaults to the sale's currency, or the store default when not sold
+    pub currency: Option<String>,
+    /// Code printed on a pre-made card; generated when omitted
+    pub code: Option<String>,
+    pub sale_id: Option<Uuid>,
+    pub product_id: Option<Uuid>,
+}
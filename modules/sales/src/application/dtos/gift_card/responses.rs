@@ -0,0 +1,60 @@
+// Response DTOs for gift card operations
+
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::domain::entities::GiftCard;
+
+/// Response for a gift card
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GiftCardResponse {
+    pub id: Uuid,
+    pub store_id: Uuid,
+    pub code: String,
+    pub currency: String,
+    pub initial_balance: Decimal,
+    pub current_balance: Decimal,
+    pub status: String,
+    pub sale_id: Option<Uuid>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl From<&GiftCard> for GiftCardResponse {
+    fn from(c: &GiftCard) -> Self {
+        Self {
+            id: c.id().into_uuid(),
+            store_id: c.store_id().into_uuid(),
+            code: c.code().to_string(),
+            currency: c.currency().to_string(),
+            initial_balance: c.initial_balance(),
+            current_balance: c.current_balance(),
+            status: c.status().to_string(),
+            sale_id: c.sale_id().map(|id| id.into_uuid()),
+            created_at: c.created_at(),
+        }
+    }
+}
+
+/// Balance of a gift card, as shown to the customer at the counter
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GiftCardBalanceResponse {
+    pub store_id: Uuid,
+    pub code: String,
+    pub currency: String,
+    pub current_balance: Decimal,
+    pub status: String,
+}
+
+impl From<&GiftCard> for GiftCardBalanceResponse {
+    fn from(c: &GiftCard) -> Self {
+        Self {
+            store_id: c.store_id().into_uuid(),
+            code: c.code().to_string(),
+            currency: c.currency().to_string(),
+            current_balance: c.current_balance(),
+            status: c.status().to_string(),
+        }
+    }
+}
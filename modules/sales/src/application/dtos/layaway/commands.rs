@@ -0,0 +1,20 @@
+// Command DTOs for layaway operations
+
+use rust_decimal::Decimal;
+use serde::Deserialize;
+
+/// Terms of a layaway sale: whatever the deposit leaves due is split into
+/// `installments` payments, one every `interval_days`
+#[derive(Debug, Clone, Deserialize)]
+pub struct LayawayTermsCommand {
+    pub installments: i32,
+    pub interval_days: i32,
+}
+
+/// Command to cancel a layaway. The restocking fee, if any, is kept from
+/// what the customer paid; the rest is owed back to them.
+#[derive(Debug, Deserialize)]
+pub struct CancelLayawayCommand {
+    pub reason: Option<String>,
+    pub restocking_fee: Option<Decimal>,
+}
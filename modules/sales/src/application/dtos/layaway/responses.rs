@@ -0,0 +1,110 @@
+// Response DTOs for layaway operations
+
+use chrono::{DateTime, NaiveDate, Utc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::domain::entities::{Layaway, LayawayInstallment, Sale};
+
+/// Response for one scheduled installment of a layaway
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LayawayInstallmentResponse {
+    pub number: i32,
+    pub due_date: NaiveDate,
+    pub amount: Decimal,
+    pub amount_paid: Decimal,
+    pub outstanding: Decimal,
+}
+
+impl From<&LayawayInstallment> for LayawayInstallmentResponse {
+    fn from(i: &LayawayInstallment) -> Self {
+        Self {
+            number: i.number(),
+            due_date: i.due_date(),
+            amount: i.amount(),
+            amount_paid: i.amount_paid(),
+            outstanding: i.outstanding(),
+        }
+    }
+}
+
+/// Response for a layaway
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LayawayResponse {
+    pub id: Uuid,
+    pub sale_id: Uuid,
+    pub store_id: Uuid,
+    pub status: String,
+    pub installment_count: i32,
+    pub interval_days: i32,
+    pub installments: Vec<LayawayInstallmentResponse>,
+    pub restocking_fee: Decimal,
+    pub refund_amount: Decimal,
+    pub cancel_reason: Option<String>,
+    pub activated_at: Option<DateTime<Utc>>,
+    pub completed_at: Option<DateTime<Utc>>,
+    pub cancelled_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl From<&Layaway> for LayawayResponse {
+    fn from(l: &Layaway) -> Self {
+        Self {
+            id: l.id().into_uuid(),
+            sale_id: l.sale_id().into_uuid(),
+            store_id: l.store_id().into_uuid(),
+            status: l.status().to_string(),
+            installment_count: l.installment_count(),
+            interval_days: l.interval_days(),
+            installments: l.installments().iter().map(Into::into).collect(),
+            restocking_fee: l.restocking_fee(),
+            refund_amount: l.refund_amount(),
+            cancel_reason: l.cancel_reason().map(str::to_string),
+            activated_at: l.activated_at(),
+            completed_at: l.completed_at(),
+            cancelled_at: l.cancelled_at(),
+            created_at: l.created_at(),
+        }
+    }
+}
+
+/// Balance of a layaway sale: what is paid, what is left and when the next
+/// installment is due
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LayawayBalanceResponse {
+    pub sale_id: Uuid,
+    pub sale_number: String,
+    pub store_id: Uuid,
+    pub status: String,
+    pub currency: String,
+    pub total: Decimal,
+    pub amount_paid: Decimal,
+    pub amount_due: Decimal,
+    pub next_due_date: Option<NaiveDate>,
+    pub next_due_amount: Option<Decimal>,
+    /// Owed on installments whose due date has passed
+    pub overdue_amount: Decimal,
+    pub installments: Vec<LayawayInstallmentResponse>,
+}
+
+impl LayawayBalanceResponse {
+    /// Builds the balance of `layaway` as of `today`
+    pub fn new(sale: &Sale, layaway: &Layaway, today: NaiveDate) -> Self {
+        let next = layaway.next_installment();
+        Self {
+            sale_id: sale.id().into_uuid(),
+            sale_number: sale.sale_number().to_string(),
+            store_id: sale.store_id().into_uuid(),
+            status: layaway.status().to_string(),
+            currency: sale.currency().to_string(),
+            total: sale.total(),
+            amount_paid: sale.amount_paid(),
+            amount_due: sale.amount_due(),
+            next_due_date: next.map(|i| i.due_date()),
+            next_due_amount: next.map(|i| i.outstanding()),
+            overdue_amount: layaway.overdue_amount(today),
+            installments: layaway.installments().iter().map(Into::into).collect(),
+        }
+    }
+}
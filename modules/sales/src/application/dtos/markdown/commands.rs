@@ -0,0 +1,30 @@
+// Command DTOs for markdown operations
+
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// Command to create or replace the markdown rule for a store/category pair
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UpsertMarkdownRuleCommand {
+    pub store_id: Uuid,
+    pub category_id: Uuid,
+    /// Mark down products with a lot expiring within this many days
+    pub expiry_window_days: Option<i32>,
+    /// Mark down products with no sales for this many days
+    pub idle_days: Option<i32>,
+    pub discount_percent: Decimal,
+    pub duration_days: i32,
+    #[serde(default = "default_is_active")]
+    pub is_active: bool,
+}
+
+fn default_is_active() -> bool {
+    true
+}
+
+/// Command to reverse an active markdown
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ReverseMarkdownCommand {
+    pub reason: Option<String>,
+}
@@ -0,0 +1,95 @@
+// Response DTOs for markdown operations
+
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::domain::entities::{Markdown, MarkdownRule};
+
+/// Response for a markdown rule
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MarkdownRuleResponse {
+    pub id: Uuid,
+    pub store_id: Uuid,
+    pub category_id: Uuid,
+    pub expiry_window_days: Option<i32>,
+    pub idle_days: Option<i32>,
+    pub discount_percent: Decimal,
+    pub duration_days: i32,
+    pub is_active: bool,
+    pub created_by_id: Uuid,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl From<&MarkdownRule> for MarkdownRuleResponse {
+    fn from(r: &MarkdownRule) -> Self {
+        Self {
+            id: r.id().into_uuid(),
+            store_id: r.store_id(),
+            category_id: r.category_id(),
+            expiry_window_days: r.expiry_window_days(),
+            idle_days: r.idle_days(),
+            discount_percent: r.discount_percent(),
+            duration_days: r.duration_days(),
+            is_active: r.is_active(),
+            created_by_id: r.created_by_id().into_uuid(),
+            created_at: r.created_at(),
+            updated_at: r.updated_at(),
+        }
+    }
+}
+
+/// Response for a markdown log entry
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MarkdownResponse {
+    pub id: Uuid,
+    pub rule_id: Uuid,
+    pub store_id: Uuid,
+    pub product_id: Uuid,
+    pub promotion_id: Uuid,
+    pub reason: String,
+    pub reason_detail: String,
+    pub discount_percent: Decimal,
+    pub starts_at: DateTime<Utc>,
+    pub ends_at: DateTime<Utc>,
+    pub status: String,
+    pub reversed_by_id: Option<Uuid>,
+    pub reversed_at: Option<DateTime<Utc>>,
+    pub reversal_reason: Option<String>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl From<&Markdown> for MarkdownResponse {
+    fn from(m: &Markdown) -> Self {
+        Self {
+            id: m.id().into_uuid(),
+            rule_id: m.rule_id().into_uuid(),
+            store_id: m.store_id(),
+            product_id: m.product_id(),
+            promotion_id: m.promotion_id().into_uuid(),
+            reason: m.reason().to_string(),
+            reason_detail: m.reason_detail().to_string(),
+            discount_percent: m.discount_percent(),
+            starts_at: m.starts_at(),
+            ends_at: m.ends_at(),
+            status: m.status().to_string(),
+            reversed_by_id: m.reversed_by_id().map(|id| id.into_uuid()),
+            reversed_at: m.reversed_at(),
+            reversal_reason: m.reversal_reason().map(|s| s.to_string()),
+            created_at: m.created_at(),
+        }
+    }
+}
+
+/// Result of one automatic markdown run
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ApplyAutoMarkdownsResult {
+    pub rules_evaluated: usize,
+    pub expired: u64,
+    pub skipped_already_marked_down: usize,
+    /// Rules not evaluated because their store turned auto markdowns off
+    pub skipped_store_disabled: usize,
+    pub created: Vec<MarkdownResponse>,
+}
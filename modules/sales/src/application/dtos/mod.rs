@@ -5,16 +5,48 @@
 pub mod cart;
 pub mod credit_note;
 pub mod customer;
+pub mod exchange_rate;
+pub mod gift_card;
+pub mod layaway;
+pub mod markdown;
+pub mod pickup;
+pub mod price_list;
+pub mod price_tier;
 pub mod promotion;
 pub mod sale;
 pub mod shift;
+pub mod tax_rule;
+pub mod weight_barcode;
 
 pub use cart::*;
 pub use credit_note::*;
 pub use customer::*;
+pub use exchange_rate::commands::RecordExchangeRateCommand;
+pub use exchange_rate::responses::ExchangeRateResponse;
+pub use gift_card::commands::IssueGiftCardCommand;
+pub use gift_card::responses::{GiftCardBalanceResponse, GiftCardResponse};
+pub use layaway::commands::{CancelLayawayCommand, LayawayTermsCommand};
+pub use layaway::responses::{LayawayBalanceResponse, LayawayInstallmentResponse, LayawayResponse};
+pub use markdown::commands::{ReverseMarkdownCommand, UpsertMarkdownRuleCommand};
+pub use markdown::responses::{ApplyAutoMarkdownsResult, MarkdownResponse, MarkdownRuleResponse};
+pub use pickup::commands::CompletePickupCommand;
+pub use pickup::responses::{
+    ExpirePickupsResult, OrderPickupResponse, PickupCompletedResponse, ReadyForPickupResponse,
+};
+pub use price_list::commands::{CreatePriceListCommand, SetPriceListEntryCommand};
+pub use price_list::responses::{PriceListEntryResponse, PriceListResponse};
+pub use price_tier::commands::UpsertPriceTierCommand;
+pub use price_tier::responses::PriceTierResponse;
 pub use promotion::commands::{
-    ApplyPromotionCommand, CreatePromotionCommand, UpdatePromotionCommand,
+    ApplyPromotionCommand, CreatePromotionCommand, EvaluatePromotionsCommand,
+    UpdatePromotionCommand,
+};
+pub use promotion::responses::{
+    PromotionDiscountResponse, PromotionEvaluationResponse, PromotionResponse,
 };
-pub use promotion::responses::PromotionResponse;
 pub use sale::*;
 pub use shift::*;
+pub use tax_rule::commands::UpsertTaxRuleCommand;
+pub use tax_rule::responses::TaxRuleResponse;
+pub use weight_barcode::commands::CreateWeightBarcodeFormatCommand;
+pub use weight_barcode::responses::WeightBarcodeFormatResponse;
@@ -0,0 +1,10 @@
+// Command DTOs for order pickup operations
+
+use serde::{Deserialize, Serialize};
+
+/// Command to hand an order over at the counter
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CompletePickupCommand {
+    /// Pickup code sent to the customer when the order was marked ready
+    pub code: String,
+}
@@ -0,0 +1,73 @@
+// Response DTOs for order pickup operations
+
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::application::dtos::SaleDetailResponse;
+use crate::domain::entities::OrderPickup;
+
+/// Response for an order held at the store
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OrderPickupResponse {
+    pub sale_id: Uuid,
+    pub store_id: Uuid,
+    pub pickup_code: String,
+    pub status: String,
+    pub ready_at: DateTime<Utc>,
+    pub hold_until: DateTime<Utc>,
+    pub picked_up_at: Option<DateTime<Utc>>,
+    pub picked_up_by_id: Option<Uuid>,
+    pub closed_at: Option<DateTime<Utc>>,
+}
+
+impl From<&OrderPickup> for OrderPickupResponse {
+    fn from(p: &OrderPickup) -> Self {
+        Self {
+            sale_id: p.sale_id().into_uuid(),
+            store_id: p.store_id().into_uuid(),
+            pickup_code: p.pickup_code().to_string(),
+            status: p.status().to_string(),
+            ready_at: p.ready_at(),
+            hold_until: p.hold_until(),
+            picked_up_at: p.picked_up_at(),
+            picked_up_by_id: p.picked_up_by_id().map(|id| id.into_uuid()),
+            closed_at: p.closed_at(),
+        }
+    }
+}
+
+/// Response after an order was put on hold for pickup
+#[derive(Debug, Serialize)]
+pub struct ReadyForPickupResponse {
+    pub order: SaleDetailResponse,
+    pub pickup: OrderPickupResponse,
+    /// False when the order has no customer contact or sending failed; staff
+    /// can then share the pickup code another way
+    pub customer_notified: bool,
+}
+
+/// Response after the customer collected the order
+#[derive(Debug, Serialize)]
+pub struct PickupCompletedResponse {
+    pub order: SaleDetailResponse,
+    pub pickup: OrderPickupResponse,
+    pub invoice_id: Uuid,
+    /// Reservations turned into stock deductions at hand-over
+    pub reservations_committed: usize,
+}
+
+/// Result of one no-show expiry run
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct ExpirePickupsResult {
+    /// Orders cancelled because nobody collected them in time
+    pub orders_cancelled: usize,
+    /// Pending reservations cancelled for those orders
+    pub reservations_released: usize,
+    /// Total reserved quantity returned to available stock
+    pub quantity_released: Decimal,
+    /// Pickups that could not be expired
+    pub failed: usize,
+    pub errors: Vec<String>,
+}
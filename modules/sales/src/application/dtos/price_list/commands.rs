@@ -0,0 +1,30 @@
+// Command DTOs for price list operations
+
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// Command to create a price list for a store
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreatePriceListCommand {
+    pub store_id: Uuid,
+    pub name: String,
+    /// Customer types the list prices (individual, business)
+    #[serde(default)]
+    pub customer_types: Vec<String>,
+    /// Makes this the store's default (retail) list, used for walk-in and
+    /// guest sales and customer types without a list of their own
+    #[serde(default)]
+    pub is_default: bool,
+}
+
+/// Command to set the price of a product, or of one of its variants, on a
+/// price list
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SetPriceListEntryCommand {
+    #[serde(default)]
+    pub price_list_id: Uuid,
+    pub product_id: Uuid,
+    pub variant_id: Option<Uuid>,
+    pub price: Decimal,
+}
@@ -0,0 +1,64 @@
+// Response DTOs for price list operations
+
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::domain::entities::{PriceList, PriceListEntry};
+
+/// Response for a price list
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PriceListResponse {
+    pub id: Uuid,
+    pub store_id: Uuid,
+    pub name: String,
+    pub customer_types: Vec<String>,
+    pub is_default: bool,
+    pub is_active: bool,
+    pub created_by_id: Uuid,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl From<&PriceList> for PriceListResponse {
+    fn from(l: &PriceList) -> Self {
+        Self {
+            id: l.id().into_uuid(),
+            store_id: l.store_id(),
+            name: l.name().to_string(),
+            customer_types: l.customer_types().iter().map(|t| t.to_string()).collect(),
+            is_default: l.is_default(),
+            is_active: l.is_active(),
+            created_by_id: l.created_by_id().into_uuid(),
+            created_at: l.created_at(),
+            updated_at: l.updated_at(),
+        }
+    }
+}
+
+/// Response for a price list entry
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PriceListEntryResponse {
+    pub id: Uuid,
+    pub price_list_id: Uuid,
+    pub product_id: Uuid,
+    pub variant_id: Option<Uuid>,
+    pub price: Decimal,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl From<&PriceListEntry> for PriceListEntryResponse {
+    fn from(e: &PriceListEntry) -> Self {
+        Self {
+            id: e.id(),
+            price_list_id: e.price_list_id().into_uuid(),
+            product_id: e.product_id(),
+            variant_id: e.variant_id(),
+            price: e.price(),
+            created_at: e.created_at(),
+            updated_at: e.updated_at(),
+        }
+    }
+}
@@ -0,0 +1,27 @@
+// Command DTOs for price tier operations
+
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// Command to create or replace the price tier a customer type gets at a
+/// store for a product or a category
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UpsertPriceTierCommand {
+    pub store_id: Uuid,
+    pub customer_type: String,
+    /// Product the tier prices; mutually exclusive with `category_id`
+    pub product_id: Option<Uuid>,
+    /// Category the tier prices; mutually exclusive with `product_id`
+    pub category_id: Option<Uuid>,
+    /// Fixed unit price (product tiers only)
+    pub price: Option<Decimal>,
+    /// Percentage off the base price
+    pub discount_percent: Option<Decimal>,
+    #[serde(default = "default_is_active")]
+    pub is_active: bool,
+}
+
+fn default_is_active() -> bool {
+    true
+}
@@ -0,0 +1,42 @@
+// Response DTOs for price tier operations
+
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::domain::entities::PriceTier;
+
+/// Response for a price tier
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PriceTierResponse {
+    pub id: Uuid,
+    pub store_id: Uuid,
+    pub customer_type: String,
+    pub product_id: Option<Uuid>,
+    pub category_id: Option<Uuid>,
+    pub price: Option<Decimal>,
+    pub discount_percent: Option<Decimal>,
+    pub is_active: bool,
+    pub created_by_id: Uuid,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl From<&PriceTier> for PriceTierResponse {
+    fn from(t: &PriceTier) -> Self {
+        Self {
+            id: t.id().into_uuid(),
+            store_id: t.store_id(),
+            customer_type: t.customer_type().to_string(),
+            product_id: t.product_id(),
+            category_id: t.category_id(),
+            price: t.price(),
+            discount_percent: t.discount_percent(),
+            is_active: t.is_active(),
+            created_by_id: t.created_by_id().into_uuid(),
+            created_at: t.created_at(),
+            updated_at: t.updated_at(),
+        }
+    }
+}
@@ -62,3 +62,13 @@ pub struct ApplyPromotionCommand {
     pub sale_id: Uuid,
     pub promotion_code: String,
 }
+
+/// Command to evaluate the store's active promotions against a sale
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct EvaluatePromotionsCommand {
+    #[serde(default)]
+    pub sale_id: Uuid,
+    /// Applies the best combination of discounts to the sale
+    #[serde(default)]
+    pub auto_apply: bool,
+}
@@ -5,7 +5,9 @@ use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
+use crate::application::dtos::SaleDetailResponse;
 use crate::domain::entities::Promotion;
+use crate::domain::value_objects::PromotionDiscount;
 
 /// Response for a promotion
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -68,3 +70,39 @@ impl From<Promotion> for PromotionResponse {
         Self::from(&p)
     }
 }
+
+/// A discount a promotion grants on a sale line, or on the whole sale when
+/// `sale_item_id` is empty
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PromotionDiscountResponse {
+    pub promotion_id: Uuid,
+    pub promotion_code: String,
+    pub promotion_name: String,
+    pub sale_item_id: Option<Uuid>,
+    pub amount: Decimal,
+}
+
+impl From<&PromotionDiscount> for PromotionDiscountResponse {
+    fn from(d: &PromotionDiscount) -> Self {
+        Self {
+            promotion_id: d.promotion_id.into_uuid(),
+            promotion_code: d.promotion_code.clone(),
+            promotion_name: d.promotion_name.clone(),
+            sale_item_id: d.sale_item_id.map(|id| id.into_uuid()),
+            amount: d.amount,
+        }
+    }
+}
+
+/// Result of evaluating promotions against a sale
+#[derive(Debug, Serialize)]
+pub struct PromotionEvaluationResponse {
+    /// Every discount an available promotion grants on its own
+    pub applicable: Vec<PromotionDiscountResponse>,
+    /// The best combination of those discounts
+    pub best: Vec<PromotionDiscountResponse>,
+    pub total_discount: Decimal,
+    /// Whether the best combination was applied to the sale
+    pub applied: bool,
+    pub sale: SaleDetailResponse,
+}
@@ -4,6 +4,8 @@ use rust_decimal::Decimal;
 use serde::Deserialize;
 use uuid::Uuid;
 
+use crate::application::dtos::LayawayTermsCommand;
+
 /// Command to create a new POS sale
 #[derive(Debug, Deserialize)]
 pub struct CreatePosSaleCommand {
@@ -12,6 +14,8 @@ pub struct CreatePosSaleCommand {
     pub shift_id: Uuid,
     pub customer_id: Option<Uuid>,
     pub notes: Option<String>,
+    /// Puts the sale on layaway with these terms once its deposit is paid
+    pub layaway: Option<LayawayTermsCommand>,
 }
 
 /// Command to add an item to a sale
@@ -22,6 +26,9 @@ pub struct AddSaleItemCommand {
     pub variant_id: Option<Uuid>,
     pub quantity: Decimal,
     pub unit_price: Option<Decimal>,
+    /// Serials of the units sold, required for serial-tracked products
+    #[serde(default)]
+    pub serial_numbers: Vec<String>,
     pub notes: Option<String>,
 }
 
@@ -43,18 +50,63 @@ pub struct ApplyDiscountCommand {
     pub discount_value: Decimal,
 }
 
-/// Command to process a payment
+/// One tender of a payment: the method used and the amount it covers
 #[derive(Debug, Deserialize)]
-pub struct ProcessPaymentCommand {
-    pub sale_id: Uuid,
+pub struct PaymentAllocation {
     pub payment_method: String,
     pub amount: Decimal,
+    pub amount_tendered: Option<Decimal>,
     pub reference: Option<String>,
     pub card_last_four: Option<String>,
     pub card_brand: Option<String>,
-    pub amount_tendered: Option<Decimal>,
+    /// Tender currency; defaults to the sale's base currency
+    pub currency: Option<String>,
+    /// Code of the card paying a gift card tender
+    pub gift_card_code: Option<String>,
+}
+
+/// Command to process a payment, split across one or more tenders
+#[derive(Debug, Deserialize)]
+pub struct ProcessPaymentCommand {
+    pub sale_id: Uuid,
+    pub allocations: Vec<PaymentAllocation>,
     pub notes: Option<String>,
     pub idempotency_key: Option<String>,
+    /// Invoice number for a layaway this payment pays off; defaults to the
+    /// sale number
+    pub invoice_number: Option<String>,
+}
+
+/// Command for a single-item cash sale rung up in one call.
+///
+/// The item is looked up by `barcode`, or by `product_id` and an optional
+/// `variant_id`. The invoice number is drawn from the terminal's CAI range;
+/// a command carrying its own `invoice_number` is rejected.
+#[derive(Debug, Deserialize)]
+pub struct QuickSaleCommand {
+    pub store_id: Uuid,
+    pub shift_id: Uuid,
+    pub barcode: Option<String>,
+    pub product_id: Option<Uuid>,
+    pub variant_id: Option<Uuid>,
+    #[serde(default = "default_quick_sale_quantity")]
+    pub quantity: Decimal,
+    pub amount_tendered: Decimal,
+    pub invoice_number: Option<String>,
+    pub idempotency_key: Option<String>,
+}
+
+fn default_quick_sale_quantity() -> Decimal {
+    Decimal::ONE
+}
+
+/// Command to quote a sale in another currency, at the store's current rate
+#[derive(Debug, Deserialize)]
+pub struct SetSaleCurrencyCommand {
+    #[serde(default)]
+    pub sale_id: Uuid,
+    /// ISO 4217 code; the base currency quotes the sale without conversion
+    pub currency: String,
 }
 
 /// Command to void a sale
@@ -64,6 +116,13 @@ pub struct VoidSaleCommand {
     pub reason: String,
 }
 
+/// Query for looking up a scanned barcode; stock is read at the
+/// terminal's store
+#[derive(Debug, Deserialize)]
+pub struct BarcodeLookupQuery {
+    pub terminal_id: Uuid,
+}
+
 /// Filter for listing sales
 #[derive(Debug, Default, Deserialize)]
 pub struct ListSalesQuery {
@@ -81,4 +140,6 @@ pub struct ListSalesQuery {
     pub search: Option<String>,
     pub page: Option<i64>,
     pub page_size: Option<i64>,
+    /// `next_cursor` of a previous page; when set, `page` is ignored
+    pub after_cursor: Option<String>,
 }
@@ -6,6 +6,7 @@ use serde::Serialize;
 use uuid::Uuid;
 
 use crate::domain::entities::{Payment, Sale, SaleItem};
+use crate::domain::value_objects::SaleTaxBreakdown;
 
 /// Response for a sale (list item)
 #[derive(Debug, Serialize)]
@@ -25,6 +26,7 @@ pub struct SaleResponse {
     pub total: Decimal,
     pub currency: String,
     pub invoice_number: Option<String>,
+    pub cai_number: Option<String>,
     pub item_count: usize,
     pub is_fully_paid: bool,
     pub created_at: DateTime<Utc>,
@@ -49,6 +51,7 @@ impl From<&Sale> for SaleResponse {
             total: s.total(),
             currency: s.currency().as_str().to_string(),
             invoice_number: s.invoice_number().map(String::from),
+            cai_number: s.cai_number().map(String::from),
             item_count: s.items().len(),
             is_fully_paid: s.is_fully_paid(),
             created_at: s.created_at(),
@@ -74,13 +77,24 @@ pub struct SaleDetailResponse {
     pub discount_type: Option<String>,
     pub discount_value: Decimal,
     pub discount_amount: Decimal,
+    /// Promotion that granted the sale discount, if any
+    pub promotion_id: Option<Uuid>,
     pub tax_amount: Decimal,
     pub total: Decimal,
     pub amount_paid: Decimal,
     pub amount_due: Decimal,
     pub change_given: Decimal,
+    /// Base currency the totals are in
     pub currency: String,
+    /// Currency the sale is quoted in
+    pub transaction_currency: String,
+    /// Units of the base currency per unit of the transaction currency,
+    /// as captured when the sale was completed
+    pub exchange_rate: Decimal,
+    pub transaction_total: Decimal,
+    pub transaction_amount_due: Decimal,
     pub invoice_number: Option<String>,
+    pub cai_number: Option<String>,
     pub invoice_date: Option<DateTime<Utc>>,
     pub notes: Option<String>,
     pub internal_notes: Option<String>,
@@ -90,6 +104,8 @@ pub struct SaleDetailResponse {
     pub completed_at: Option<DateTime<Utc>>,
     pub items: Vec<SaleItemResponse>,
     pub payments: Vec<PaymentResponse>,
+    /// The sale's tax by rule, jurisdiction and rate
+    pub tax_breakdown: Vec<SaleTaxBreakdownResponse>,
     pub is_fully_paid: bool,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
@@ -100,6 +116,11 @@ impl From<Sale> for SaleDetailResponse {
         let items: Vec<SaleItemResponse> = s.items().iter().map(SaleItemResponse::from).collect();
         let payments: Vec<PaymentResponse> =
             s.payments().iter().map(PaymentResponse::from).collect();
+        let tax_breakdown: Vec<SaleTaxBreakdownResponse> = s
+            .tax_breakdown()
+            .iter()
+            .map(SaleTaxBreakdownResponse::from)
+            .collect();
 
         Self {
             id: s.id().into_uuid(),
@@ -116,13 +137,19 @@ impl From<Sale> for SaleDetailResponse {
             discount_type: s.discount_type().map(|d| d.to_string()),
             discount_value: s.discount_value(),
             discount_amount: s.discount_amount(),
+            promotion_id: s.promotion_id().map(|p| p.into_uuid()),
             tax_amount: s.tax_amount(),
             total: s.total(),
             amount_paid: s.amount_paid(),
             amount_due: s.amount_due(),
             change_given: s.change_given(),
             currency: s.currency().as_str().to_string(),
+            transaction_currency: s.transaction_currency().as_str().to_string(),
+            exchange_rate: s.exchange_rate(),
+            transaction_total: s.transaction_total(),
+            transaction_amount_due: s.transaction_amount_due(),
             invoice_number: s.invoice_number().map(String::from),
+            cai_number: s.cai_number().map(String::from),
             invoice_date: s.invoice_date(),
             notes: s.notes().map(String::from),
             internal_notes: s.internal_notes().map(String::from),
@@ -133,6 +160,7 @@ impl From<Sale> for SaleDetailResponse {
             is_fully_paid: s.is_fully_paid(),
             items,
             payments,
+            tax_breakdown,
             created_at: s.created_at(),
             updated_at: s.updated_at(),
         }
@@ -156,10 +184,26 @@ pub struct SaleItemResponse {
     pub discount_type: Option<String>,
     pub discount_value: Decimal,
     pub discount_amount: Decimal,
+    /// The line's share of the sale-level discount
+    pub order_discount_amount: Decimal,
     pub tax_rate: Decimal,
     pub tax_amount: Decimal,
+    /// Whether the unit price already includes the tax
+    pub tax_inclusive: bool,
+    /// Whether the customer is exempt from the line's tax rule
+    pub tax_exempt: bool,
+    /// Tax rule the rate was taken from, if any
+    pub tax_rule_id: Option<Uuid>,
+    pub tax_jurisdiction: Option<String>,
     pub subtotal: Decimal,
     pub total: Decimal,
+    pub serial_numbers: Vec<String>,
+    /// Price tier the unit price was resolved from, if any
+    pub price_tier_id: Option<Uuid>,
+    /// Price list the unit price was resolved from, if any
+    pub price_list_id: Option<Uuid>,
+    /// Promotion that granted the line discount, if any
+    pub promotion_id: Option<Uuid>,
     pub notes: Option<String>,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
@@ -182,10 +226,19 @@ impl From<&SaleItem> for SaleItemResponse {
             discount_type: i.discount_type().map(|d| d.to_string()),
             discount_value: i.discount_value(),
             discount_amount: i.discount_amount(),
+            order_discount_amount: i.order_discount_amount(),
             tax_rate: i.tax_rate(),
             tax_amount: i.tax_amount(),
+            tax_inclusive: i.tax_inclusive(),
+            tax_exempt: i.tax_exempt(),
+            tax_rule_id: i.tax_rule_id().map(|r| r.into_uuid()),
+            tax_jurisdiction: i.tax_jurisdiction().map(String::from),
             subtotal: i.subtotal(),
             total: i.total(),
+            serial_numbers: i.serial_numbers().to_vec(),
+            price_tier_id: i.price_tier_id().map(|t| t.into_uuid()),
+            price_list_id: i.price_list_id().map(|l| l.into_uuid()),
+            promotion_id: i.promotion_id().map(|p| p.into_uuid()),
             notes: i.notes().map(String::from),
             created_at: i.created_at(),
             updated_at: i.updated_at(),
@@ -193,6 +246,33 @@ impl From<&SaleItem> for SaleItemResponse {
     }
 }
 
+/// Tax a sale charged under one rule and rate
+#[derive(Debug, Serialize)]
+pub struct SaleTaxBreakdownResponse {
+    pub tax_rule_id: Option<Uuid>,
+    pub jurisdiction: Option<String>,
+    pub tax_rate: Decimal,
+    pub tax_inclusive: bool,
+    pub tax_exempt: bool,
+    /// Value taxed, net of discounts and without the tax
+    pub taxable_amount: Decimal,
+    pub tax_amount: Decimal,
+}
+
+impl From<&SaleTaxBreakdown> for SaleTaxBreakdownResponse {
+    fn from(b: &SaleTaxBreakdown) -> Self {
+        Self {
+            tax_rule_id: b.tax_rule_id.map(|r| r.into_uuid()),
+            jurisdiction: b.jurisdiction.clone(),
+            tax_rate: b.tax_rate,
+            tax_inclusive: b.tax_inclusive,
+            tax_exempt: b.tax_exempt,
+            taxable_amount: b.taxable_amount.round_dp(2),
+            tax_amount: b.tax_amount.round_dp(2),
+        }
+    }
+}
+
 /// Response for a payment
 #[derive(Debug, Serialize)]
 pub struct PaymentResponse {
@@ -200,8 +280,12 @@ pub struct PaymentResponse {
     pub sale_id: Uuid,
     pub payment_method: String,
     pub status: String,
+    /// Amount in the tender currency
     pub amount: Decimal,
     pub currency: String,
+    /// Rate converting the tender currency to the sale's base currency
+    pub exchange_rate: Decimal,
+    pub base_amount: Decimal,
     pub reference_number: Option<String>,
     pub authorization_code: Option<String>,
     pub card_last_four: Option<String>,
@@ -225,6 +309,8 @@ impl From<&Payment> for PaymentResponse {
             status: p.status().to_string(),
             amount: p.amount(),
             currency: p.currency().as_str().to_string(),
+            exchange_rate: p.exchange_rate(),
+            base_amount: p.base_amount(),
             reference_number: p.reference_number().map(String::from),
             authorization_code: p.authorization_code().map(String::from),
             card_last_four: p.card_last_four().map(String::from),
@@ -249,4 +335,61 @@ pub struct SaleListResponse {
     pub page: i64,
     pub page_size: i64,
     pub total_pages: i64,
+    /// Pass back as `after_cursor` for the next page; `None` on the last page
+    pub next_cursor: Option<String>,
+}
+
+/// Sale a serialized unit was sold on, for warranty claims
+#[derive(Debug, Serialize)]
+pub struct SerialSaleResponse {
+    pub serial_number: String,
+    pub serial_status: String,
+    pub product_id: Uuid,
+    pub variant_id: Option<Uuid>,
+    pub sale_id: Uuid,
+    pub sale_item_id: Option<Uuid>,
+    pub sale_number: String,
+    pub invoice_number: Option<String>,
+    pub store_id: Uuid,
+    pub customer_id: Option<Uuid>,
+    pub sku: Option<String>,
+    pub description: Option<String>,
+    pub sold_at: Option<DateTime<Utc>>,
+    /// Credit note the unit was returned on, if any
+    pub credit_note_id: Option<Uuid>,
+}
+
+/// Item a scanned barcode resolves to, with what the terminal needs to add
+/// the line. `unit_price` is the catalog price; the sale's price list or
+/// tier may still override it when the line is added.
+#[derive(Debug, Serialize)]
+pub struct BarcodeLookupResponse {
+    pub barcode: String,
+    pub product_id: Uuid,
+    pub variant_id: Option<Uuid>,
+    pub sku: String,
+    pub description: String,
+    pub unit_of_measure: String,
+    pub unit_price: Decimal,
+    pub currency: String,
+    pub tax_rate: Decimal,
+    pub tax_included: bool,
+    pub is_serialized: bool,
+    pub store_id: Uuid,
+    /// Quantity available at the store, `None` when the item has no stock
+    /// record there
+    pub available_quantity: Option<Decimal>,
+    /// Set when the barcode is a scale-printed label
+    pub scale: Option<ScaleLabelResponse>,
+}
+
+/// Line a scale label rings up: the embedded weight or price and the
+/// quantity and total derived from it
+#[derive(Debug, Serialize)]
+pub struct ScaleLabelResponse {
+    pub format_id: Uuid,
+    pub value_kind: String,
+    pub embedded_value: Decimal,
+    pub quantity: Decimal,
+    pub total: Decimal,
 }
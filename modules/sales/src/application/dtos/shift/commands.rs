@@ -17,8 +17,23 @@ pub struct OpenShiftCommand {
 #[derive(Debug, Deserialize)]
 pub struct CloseShiftCommand {
     pub shift_id: Uuid,
+    /// Cash counted in the drawer
     pub closing_balance: Decimal,
+    /// Card total counted (settlement batch); cards aren't reconciled if omitted
+    pub card_count: Option<Decimal>,
+    /// Other-tender total counted; not reconciled if omitted
+    pub other_count: Option<Decimal>,
     pub closing_notes: Option<String>,
+    /// Required when a counted amount is off by more than the store's
+    /// variance threshold
+    pub variance_reason: Option<String>,
+}
+
+/// Command for a manager to reopen a prematurely closed shift
+#[derive(Debug, Deserialize)]
+pub struct ReopenShiftCommand {
+    pub shift_id: Uuid,
+    pub reason: String,
 }
 
 /// Command for cash movement (cash in or cash out)
@@ -40,4 +55,6 @@ pub struct ListShiftsQuery {
     pub date_to: Option<String>,
     pub page: Option<i64>,
     pub page_size: Option<i64>,
+    /// `next_cursor` of a previous page; when set, `page` is ignored
+    pub after_cursor: Option<String>,
 }
@@ -31,6 +31,11 @@ pub struct ShiftResponse {
     pub transaction_count: i32,
     pub notes: Option<String>,
     pub closing_notes: Option<String>,
+    pub card_count: Option<Decimal>,
+    pub other_count: Option<Decimal>,
+    /// Counted cash minus expected cash, set on close
+    pub variance: Option<Decimal>,
+    pub variance_reason: Option<String>,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
 }
@@ -59,6 +64,10 @@ impl From<CashierShift> for ShiftResponse {
             transaction_count: s.transaction_count(),
             notes: s.notes().map(String::from),
             closing_notes: s.closing_notes().map(String::from),
+            card_count: s.card_count(),
+            other_count: s.other_count(),
+            variance: s.variance(),
+            variance_reason: s.variance_reason().map(String::from),
             created_at: s.created_at(),
             updated_at: s.updated_at(),
         }
@@ -66,8 +75,13 @@ impl From<CashierShift> for ShiftResponse {
 }
 
 /// Shift report response with detailed breakdown
+///
+/// A `snapshot` (X-report) shows the running totals of a shift that is
+/// still open; the figures will change as the shift goes on.
 #[derive(Debug, Serialize)]
 pub struct ShiftReportResponse {
+    pub snapshot: bool,
+    pub generated_at: DateTime<Utc>,
     pub shift: ShiftResponse,
     pub sales_breakdown: SalesBreakdown,
     pub payment_breakdown: Vec<PaymentBreakdownItem>,
@@ -84,11 +98,18 @@ pub struct SalesBreakdown {
 }
 
 /// Payment method breakdown item
+///
+/// `expected` is what the closing count is reconciled against: the drawer's
+/// expected cash for cash, the method's sales otherwise. `counted` and
+/// `variance` are empty until the method is counted on close.
 #[derive(Debug, Serialize)]
 pub struct PaymentBreakdownItem {
     pub payment_method: String,
     pub amount: Decimal,
     pub count: i32,
+    pub expected: Decimal,
+    pub counted: Option<Decimal>,
+    pub variance: Option<Decimal>,
 }
 
 /// Paginated response for shift list
@@ -99,4 +120,6 @@ pub struct ShiftListResponse {
     pub page: i64,
     pub page_size: i64,
     pub total_pages: i64,
+    /// Pass back as `after_cursor` for the next page; `None` on the last page
+    pub next_cursor: Option<String>,
 }
@@ -0,0 +1,33 @@
+// Command DTOs for tax rule operations
+
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// Command to create or replace the tax rule a store applies to a product,
+/// a category, or everything it sells
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UpsertTaxRuleCommand {
+    pub store_id: Uuid,
+    /// Tax authority the rate is owed to, e.g. "HN-ISV"
+    pub jurisdiction: String,
+    /// Product the rule taxes; mutually exclusive with `category_id`
+    pub product_id: Option<Uuid>,
+    /// Category the rule taxes; mutually exclusive with `product_id`.
+    /// Leave both empty for the store-wide rule.
+    pub category_id: Option<Uuid>,
+    /// Tax rate as a percentage
+    pub rate: Decimal,
+    /// Whether shelf prices already include the tax
+    #[serde(default)]
+    pub tax_inclusive: bool,
+    /// Customer types that pay no tax under the rule
+    #[serde(default)]
+    pub exempt_customer_types: Vec<String>,
+    #[serde(default = "default_is_active")]
+    pub is_active: bool,
+}
+
+fn default_is_active() -> bool {
+    true
+}
@@ -0,0 +1,48 @@
+// Response DTOs for tax rule operations
+
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::domain::entities::TaxRule;
+
+/// Response for a tax rule
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TaxRuleResponse {
+    pub id: Uuid,
+    pub store_id: Uuid,
+    pub jurisdiction: String,
+    pub product_id: Option<Uuid>,
+    pub category_id: Option<Uuid>,
+    pub rate: Decimal,
+    pub tax_inclusive: bool,
+    pub exempt_customer_types: Vec<String>,
+    pub is_active: bool,
+    pub created_by_id: Uuid,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl From<&TaxRule> for TaxRuleResponse {
+    fn from(r: &TaxRule) -> Self {
+        Self {
+            id: r.id().into_uuid(),
+            store_id: r.store_id(),
+            jurisdiction: r.jurisdiction().to_string(),
+            product_id: r.product_id(),
+            category_id: r.category_id(),
+            rate: r.rate(),
+            tax_inclusive: r.tax_inclusive(),
+            exempt_customer_types: r
+                .exempt_customer_types()
+                .iter()
+                .map(|t| t.to_string())
+                .collect(),
+            is_active: r.is_active(),
+            created_by_id: r.created_by_id().into_uuid(),
+            created_at: r.created_at(),
+            updated_at: r.updated_at(),
+        }
+    }
+}
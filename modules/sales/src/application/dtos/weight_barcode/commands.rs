@@ -0,0 +1,20 @@
+// Command DTOs for weight barcode format operations
+
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// Command to register the layout of a store's scale-printed labels
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreateWeightBarcodeFormatCommand {
+    pub store_id: Uuid,
+    /// Leading digits that mark a scale label, e.g. "20"
+    pub prefix: String,
+    /// Zero-based position of the first digit of the embedded value
+    pub value_start: u8,
+    /// Digits holding the embedded value
+    pub value_length: u8,
+    /// "weight" or "price"
+    pub value_kind: String,
+    /// The embedded value is divided by this, e.g. 1000 for grams
+    pub divisor: u32,
+}
@@ -0,0 +1,37 @@
+// Response DTOs for weight barcode format operations
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::domain::entities::WeightBarcodeFormat;
+
+/// Response for a weight barcode format
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WeightBarcodeFormatResponse {
+    pub id: Uuid,
+    pub store_id: Uuid,
+    pub prefix: String,
+    pub value_start: u8,
+    pub value_length: u8,
+    pub value_kind: String,
+    pub divisor: u32,
+    pub created_by_id: Uuid,
+    pub created_at: DateTime<Utc>,
+}
+
+impl From<&WeightBarcodeFormat> for WeightBarcodeFormatResponse {
+    fn from(f: &WeightBarcodeFormat) -> Self {
+        Self {
+            id: f.id(),
+            store_id: f.store_id(),
+            prefix: f.prefix().to_string(),
+            value_start: f.value_start(),
+            value_length: f.value_length(),
+            value_kind: f.value_kind().to_string(),
+            divisor: f.divisor(),
+            created_by_id: f.created_by_id().into_uuid(),
+            created_at: f.created_at(),
+        }
+    }
+}
@@ -4,19 +4,55 @@ use std::sync::Arc;
 
 use crate::SalesError;
 use crate::application::dtos::{AddCartItemCommand, CartResponse};
+use crate::application::use_cases::price_list::resolve_list_price;
+use crate::application::use_cases::price_tier::{pricing_customer_type, resolve_tier_price};
 use crate::domain::entities::CartItem;
-use crate::domain::repositories::CartRepository;
+use crate::domain::repositories::{
+    CartRepository, CustomerRepository, PriceListRepository, PriceTierRepository,
+};
 use crate::domain::value_objects::CartId;
-use inventory::{ProductId, UnitOfMeasure, VariantId};
+use inventory::{InventoryError, ProductId, ProductRepository, UnitOfMeasure, VariantId};
 
 /// Use case for adding an item to a cart
-pub struct AddCartItemUseCase {
+///
+/// The line is priced from the store's price list for the cart customer's
+/// type, then the price tier for that type, so wholesale customers see
+/// wholesale prices online. Guest carts are priced from the store's default
+/// (retail) list. Without a list entry or tier the catalog price sent by
+/// the storefront stands.
+pub struct AddCartItemUseCase<P, T, L>
+where
+    P: ProductRepository,
+    T: PriceTierRepository,
+    L: PriceListRepository,
+{
     cart_repo: Arc<dyn CartRepository>,
+    customer_repo: Arc<dyn CustomerRepository>,
+    product_repo: Arc<P>,
+    price_tier_repo: Arc<T>,
+    price_list_repo: Arc<L>,
 }
 
-impl AddCartItemUseCase {
-    pub fn new(cart_repo: Arc<dyn CartRepository>) -> Self {
-        Self { cart_repo }
+impl<P, T, L> AddCartItemUseCase<P, T, L>
+where
+    P: ProductRepository,
+    T: PriceTierRepository,
+    L: PriceListRepository,
+{
+    pub fn new(
+        cart_repo: Arc<dyn CartRepository>,
+        customer_repo: Arc<dyn CustomerRepository>,
+        product_repo: Arc<P>,
+        price_tier_repo: Arc<T>,
+        price_list_repo: Arc<L>,
+    ) -> Self {
+        Self {
+            cart_repo,
+            customer_repo,
+            product_repo,
+            price_tier_repo,
+            price_list_repo,
+        }
     }
 
     pub async fn execute(&self, cmd: AddCartItemCommand) -> Result<CartResponse, SalesError> {
@@ -33,6 +69,39 @@ impl AddCartItemUseCase {
             .parse()
             .map_err(|_| SalesError::InvalidUnitOfMeasure)?;
 
+        let product = self
+            .product_repo
+            .find_by_id(ProductId::from_uuid(cmd.product_id))
+            .await
+            .map_err(|e| match e {
+                InventoryError::Database(e) => SalesError::Database(e),
+                _ => SalesError::ProductNotFound(cmd.product_id),
+            })?
+            .ok_or(SalesError::ProductNotFound(cmd.product_id))?;
+        let customer_type =
+            pricing_customer_type(self.customer_repo.as_ref(), cart.customer_id()).await?;
+        let list_price = resolve_list_price(
+            self.price_list_repo.as_ref(),
+            cart.store_id().into_uuid(),
+            cart.customer_id().map(|_| customer_type),
+            cmd.product_id,
+            cmd.variant_id,
+        )
+        .await?;
+        let unit_price = match list_price {
+            Some((price, _)) => price,
+            None => resolve_tier_price(
+                self.price_tier_repo.as_ref(),
+                cart.store_id().into_uuid(),
+                customer_type,
+                cmd.product_id,
+                product.category_id().map(|c| c.into_uuid()),
+                cmd.unit_price,
+            )
+            .await?
+            .map_or(cmd.unit_price, |(price, _)| price),
+        };
+
         let item = CartItem::create(
             cart_id,
             ProductId::from_uuid(cmd.product_id),
@@ -41,7 +110,7 @@ impl AddCartItemUseCase {
             cmd.name,
             cmd.quantity,
             uom,
-            cmd.unit_price,
+            unit_price,
             cmd.tax_rate,
         )?;
 
@@ -0,0 +1,198 @@
+//! Cleanup carts use case - deletes abandoned carts and releases their stock
+
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use chrono::Utc;
+use rust_decimal::Decimal;
+
+use crate::SalesError;
+use crate::application::dtos::CleanupCartsResult;
+use crate::domain::entities::Cart;
+use crate::domain::repositories::CartRepository;
+use crate::domain::value_objects::CartTtlPolicy;
+use inventory::application::helpers::{
+    DEFAULT_BASE_DELAY_MS, DEFAULT_MAX_ATTEMPTS, retry_on_conflict,
+};
+use inventory::{
+    InventoryError, InventoryReservation, InventoryStockRepository, ReservationRepository,
+    ReservationStatus,
+};
+
+/// Reference type used for reservations held by a cart
+const CART_RESERVATION_REFERENCE: &str = "cart";
+
+/// What happened to a single stale cart
+enum CartCleanupOutcome {
+    Deleted {
+        reservations_released: usize,
+        quantity_released: Decimal,
+    },
+    InCheckout,
+}
+
+/// Deletes carts that expired or stayed untouched beyond the TTL of the
+/// [`CartTtlPolicy`], cancelling the pending reservations they hold so the
+/// stock becomes available again.
+///
+/// A cart with a confirmed reservation belongs to an order that was placed
+/// but not yet converted; it is skipped so the checkout can finish. The use
+/// case is scheduled as a background job and processes at most `batch_size`
+/// carts per run; failures on one cart do not stop the run.
+pub struct CleanupCartsUseCase<C, R, S>
+where
+    C: CartRepository,
+    R: ReservationRepository,
+    S: InventoryStockRepository,
+{
+    cart_repo: Arc<C>,
+    reservation_repo: Arc<R>,
+    stock_repo: Arc<S>,
+    policy: CartTtlPolicy,
+}
+
+impl<C, R, S> CleanupCartsUseCase<C, R, S>
+where
+    C: CartRepository,
+    R: ReservationRepository,
+    S: InventoryStockRepository,
+{
+    pub fn new(
+        cart_repo: Arc<C>,
+        reservation_repo: Arc<R>,
+        stock_repo: Arc<S>,
+        policy: CartTtlPolicy,
+    ) -> Self {
+        Self {
+            cart_repo,
+            reservation_repo,
+            stock_repo,
+            policy,
+        }
+    }
+
+    /// Cleans up to `batch_size` stale carts, oldest activity first
+    pub async fn execute(&self, batch_size: i64) -> Result<CleanupCartsResult, SalesError> {
+        let now = Utc::now();
+        let (guest_before, customer_before) = self.policy.inactive_cutoffs(now);
+
+        let carts = self
+            .cart_repo
+            .find_stale(guest_before, customer_before, now, batch_size.max(1))
+            .await?;
+
+        let mut result = CleanupCartsResult::default();
+
+        for cart in carts.iter().filter(|c| self.policy.is_stale(c, now)) {
+            match self.cleanup_cart(cart).await {
+                Ok(CartCleanupOutcome::Deleted {
+                    reservations_released,
+                    quantity_released,
+                }) => {
+                    result.carts_deleted += 1;
+                    result.reservations_released += reservations_released;
+                    result.quantity_released += quantity_released;
+                }
+                Ok(CartCleanupOutcome::InCheckout) => result.skipped_in_checkout += 1,
+                Err(e) => {
+                    result.failed += 1;
+                    result.errors.push(format!(
+                        "Failed to clean up cart {}: {}",
+                        cart.id().into_uuid(),
+                        e
+                    ));
+                }
+            }
+        }
+
+        Ok(result)
+    }
+
+    async fn cleanup_cart(&self, cart: &Cart) -> Result<CartCleanupOutcome, String> {
+        let reservations = self
+            .cart_reservations(cart)
+            .await
+            .map_err(|e| e.to_string())?;
+
+        if reservations
+            .iter()
+            .any(|r| r.status() == ReservationStatus::Confirmed)
+        {
+            return Ok(CartCleanupOutcome::InCheckout);
+        }
+
+        let mut reservations_released = 0;
+        let mut quantity_released = Decimal::ZERO;
+        for mut reservation in reservations
+            .into_iter()
+            .filter(|r| r.status() == ReservationStatus::Pending)
+        {
+            self.release_reservation(&mut reservation)
+                .await
+                .map_err(|e| e.to_string())?;
+            reservations_released += 1;
+            quantity_released += reservation.quantity();
+        }
+
+        self.cart_repo
+            .delete(cart.id())
+            .await
+            .map_err(|e| e.to_string())?;
+
+        Ok(CartCleanupOutcome::Deleted {
+            reservations_released,
+            quantity_released,
+        })
+    }
+
+    /// Reservations referencing the cart plus those linked from its items
+    async fn cart_reservations(
+        &self,
+        cart: &Cart,
+    ) -> Result<Vec<InventoryReservation>, InventoryError> {
+        let mut reservations = self
+            .reservation_repo
+            .find_by_reference(CART_RESERVATION_REFERENCE, cart.id().into_uuid())
+            .await?;
+
+        let mut seen: HashSet<_> = reservations.iter().map(|r| r.id()).collect();
+        for reservation_id in cart.items().iter().filter_map(|i| i.reservation_id()) {
+            if seen.insert(reservation_id)
+                && let Some(reservation) = self.reservation_repo.find_by_id(reservation_id).await?
+            {
+                reservations.push(reservation);
+            }
+        }
+
+        Ok(reservations)
+    }
+
+    async fn release_reservation(
+        &self,
+        reservation: &mut InventoryReservation,
+    ) -> Result<(), InventoryError> {
+        reservation.cancel()?;
+
+        let stock_id = reservation.stock_id();
+        let quantity = reservation.quantity();
+
+        retry_on_conflict(DEFAULT_MAX_ATTEMPTS, DEFAULT_BASE_DELAY_MS, || async {
+            let mut stock = self
+                .stock_repo
+                .find_by_id(stock_id)
+                .await?
+                .ok_or(InventoryError::StockNotFound(stock_id.into_uuid()))?;
+
+            let expected_version = stock.version();
+            stock.release(quantity)?;
+            stock.increment_version();
+
+            self.stock_repo
+                .update_with_version(&stock, expected_version)
+                .await
+        })
+        .await?;
+
+        self.reservation_repo.update(reservation).await
+    }
+}
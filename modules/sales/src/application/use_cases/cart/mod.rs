@@ -1,6 +1,7 @@
 //! Cart use cases
 
 mod add_cart_item_use_case;
+mod cleanup_carts_use_case;
 mod clear_cart_use_case;
 mod create_cart_use_case;
 mod get_cart_use_case;
@@ -8,6 +9,7 @@ mod remove_cart_item_use_case;
 mod update_cart_item_use_case;
 
 pub use add_cart_item_use_case::AddCartItemUseCase;
+pub use cleanup_carts_use_case::CleanupCartsUseCase;
 pub use clear_cart_use_case::ClearCartUseCase;
 pub use create_cart_use_case::CreateCartUseCase;
 pub use get_cart_use_case::GetCartUseCase;
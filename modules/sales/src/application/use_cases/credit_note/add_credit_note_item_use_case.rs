@@ -2,21 +2,39 @@
 
 use std::sync::Arc;
 
+use super::create_credit_note_use_case::{
+    credit_note_item_from_catalog, credit_note_item_from_sale, returned_quantities,
+};
 use crate::SalesError;
 use crate::application::dtos::{AddCreditNoteItemCommand, CreditNoteResponse};
-use crate::domain::entities::CreditNoteItem;
-use crate::domain::repositories::CreditNoteRepository;
+use crate::domain::repositories::{CreditNoteRepository, SaleRepository};
 use crate::domain::value_objects::{CreditNoteId, SaleItemId};
-use inventory::{ProductId, VariantId};
+use inventory::ProductRepository;
 
 /// Use case for adding an item to a credit note
-pub struct AddCreditNoteItemUseCase {
+pub struct AddCreditNoteItemUseCase<P>
+where
+    P: ProductRepository,
+{
     credit_note_repo: Arc<dyn CreditNoteRepository>,
+    sale_repo: Arc<dyn SaleRepository>,
+    product_repo: Arc<P>,
 }
 
-impl AddCreditNoteItemUseCase {
-    pub fn new(credit_note_repo: Arc<dyn CreditNoteRepository>) -> Self {
-        Self { credit_note_repo }
+impl<P> AddCreditNoteItemUseCase<P>
+where
+    P: ProductRepository,
+{
+    pub fn new(
+        credit_note_repo: Arc<dyn CreditNoteRepository>,
+        sale_repo: Arc<dyn SaleRepository>,
+        product_repo: Arc<P>,
+    ) -> Self {
+        Self {
+            credit_note_repo,
+            sale_repo,
+            product_repo,
+        }
     }
 
     pub async fn execute(
@@ -31,23 +49,45 @@ impl AddCreditNoteItemUseCase {
             .await?
             .ok_or(SalesError::CreditNoteNotFound(cmd.credit_note_id))?;
 
-        let uom: inventory::UnitOfMeasure = cmd
-            .unit_of_measure
-            .parse()
-            .map_err(|_| SalesError::InvalidUnitOfMeasure)?;
-
-        let item = CreditNoteItem::create(
-            credit_note_id,
-            SaleItemId::from_uuid(cmd.original_sale_item_id),
-            ProductId::from_uuid(cmd.product_id),
-            cmd.variant_id.map(VariantId::from_uuid),
-            cmd.sku,
-            cmd.description,
-            cmd.return_quantity,
-            uom,
-            cmd.unit_price,
-            cmd.tax_rate,
-        )?;
+        if !credit_note.is_editable() {
+            return Err(SalesError::CreditNoteNotEditable);
+        }
+
+        let item = match (
+            credit_note.original_sale_id(),
+            cmd.original_sale_item_id,
+            cmd.product_id,
+        ) {
+            (Some(sale_id), Some(sale_item_id), None) => {
+                let sale = self
+                    .sale_repo
+                    .find_by_id_with_details(sale_id)
+                    .await?
+                    .ok_or(SalesError::SaleNotFound(sale_id.into_uuid()))?;
+
+                let mut returned = returned_quantities(&*self.credit_note_repo, sale_id).await?;
+                credit_note_item_from_sale(
+                    credit_note_id,
+                    &sale,
+                    SaleItemId::from_uuid(sale_item_id),
+                    cmd.return_quantity,
+                    cmd.serial_numbers,
+                    &mut returned,
+                )?
+            }
+            (None, None, Some(product_id)) => {
+                credit_note_item_from_catalog(
+                    self.product_repo.as_ref(),
+                    credit_note_id,
+                    product_id,
+                    cmd.variant_id,
+                    cmd.return_quantity,
+                    cmd.serial_numbers,
+                )
+                .await?
+            }
+            _ => return Err(SalesError::InvalidReturnLine),
+        };
 
         self.credit_note_repo.save_item(&item).await?;
         credit_note.add_item(item)?;
@@ -1,20 +1,36 @@
 //! Apply credit note use case
 
+use std::str::FromStr;
 use std::sync::Arc;
 
 use crate::SalesError;
 use crate::application::dtos::{ApplyCreditNoteCommand, CreditNoteResponse};
-use crate::domain::repositories::CreditNoteRepository;
-use crate::domain::value_objects::CreditNoteId;
+use crate::domain::entities::CustomerAccountEntry;
+use crate::domain::repositories::{CreditNoteRepository, CustomerRepository, SaleRepository};
+use crate::domain::value_objects::{AccountEntryType, CreditNoteId, PaymentMethod};
 
 /// Use case for applying (processing the refund of) a credit note
+///
+/// Refunds on account are credited against the customer's balance; refunds
+/// to store credit add to the customer's store credit balance. Without a
+/// refund method, receiptless returns go to store credit and others to cash.
 pub struct ApplyCreditNoteUseCase {
     credit_note_repo: Arc<dyn CreditNoteRepository>,
+    sale_repo: Arc<dyn SaleRepository>,
+    customer_repo: Arc<dyn CustomerRepository>,
 }
 
 impl ApplyCreditNoteUseCase {
-    pub fn new(credit_note_repo: Arc<dyn CreditNoteRepository>) -> Self {
-        Self { credit_note_repo }
+    pub fn new(
+        credit_note_repo: Arc<dyn CreditNoteRepository>,
+        sale_repo: Arc<dyn SaleRepository>,
+        customer_repo: Arc<dyn CustomerRepository>,
+    ) -> Self {
+        Self {
+            credit_note_repo,
+            sale_repo,
+            customer_repo,
+        }
     }
 
     pub async fn execute(
@@ -30,10 +46,64 @@ impl ApplyCreditNoteUseCase {
             .await?
             .ok_or(SalesError::CreditNoteNotFound(cmd.credit_note_id))?;
 
-        credit_note.apply(applied_by_id, cmd.refund_method)?;
+        let refund_method = if cmd.refund_to_store_credit {
+            PaymentMethod::StoreCredit.to_string()
+        } else {
+            cmd.refund_method
+                .unwrap_or_else(|| credit_note.default_refund_method().to_string())
+        };
+        let method = PaymentMethod::from_str(&refund_method).ok();
+        let to_account = method.is_some_and(|method| method.is_on_account());
+        let to_store_credit = method == Some(PaymentMethod::StoreCredit);
+
+        let customer_id = if to_account || to_store_credit {
+            // Receiptless returns name their customer, others refund the
+            // original sale's
+            let customer_id = match credit_note.original_sale_id() {
+                Some(sale_id) => self
+                    .sale_repo
+                    .find_by_id(sale_id)
+                    .await?
+                    .ok_or(SalesError::SaleNotFound(sale_id.into_uuid()))?
+                    .customer_id(),
+                None => credit_note.customer_id(),
+            };
+            Some(customer_id.ok_or(if to_account {
+                SalesError::OnAccountRequiresCustomer
+            } else {
+                SalesError::StoreCreditRequiresCustomer
+            })?)
+        } else {
+            None
+        };
+
+        credit_note.apply(applied_by_id, refund_method)?;
 
         self.credit_note_repo.update(&credit_note).await?;
 
+        match customer_id {
+            // Refunds to the account are credited against the customer's balance
+            Some(customer_id) if to_account => {
+                let mut entry = CustomerAccountEntry::credit(
+                    customer_id,
+                    credit_note.store_id(),
+                    AccountEntryType::CreditNote,
+                    credit_note.total(),
+                    Some(credit_note.id().into_uuid()),
+                    Some(credit_note.credit_note_number().to_string()),
+                    Some(applied_by_id),
+                )?;
+                self.customer_repo.post_account_entry(&mut entry).await?;
+            }
+            // Refunds to store credit can be spent on later sales
+            Some(customer_id) => {
+                self.customer_repo
+                    .adjust_store_credit(customer_id, credit_note.total())
+                    .await?;
+            }
+            None => {}
+        }
+
         Ok(CreditNoteResponse::from(credit_note))
     }
 }
@@ -7,8 +7,15 @@ use crate::SalesError;
 use crate::application::dtos::CreditNoteResponse;
 use crate::domain::repositories::CreditNoteRepository;
 use crate::domain::value_objects::CreditNoteId;
+use identity::{PermissionCheckResult, UserContext};
+
+/// Permission required to approve credit notes
+const APPROVE_CREDIT_NOTE_PERMISSION: &str = "sales:approve_credit_note";
 
 /// Use case for approving a credit note
+///
+/// The caller must hold `sales:approve_credit_note`; the check is made here
+/// and not only by the HTTP layer.
 pub struct ApproveCreditNoteUseCase {
     credit_note_repo: Arc<dyn CreditNoteRepository>,
 }
@@ -21,8 +28,13 @@ impl ApproveCreditNoteUseCase {
     pub async fn execute(
         &self,
         credit_note_id: Uuid,
-        approver_id: identity::UserId,
+        ctx: &UserContext,
     ) -> Result<CreditNoteResponse, SalesError> {
+        if let PermissionCheckResult::Denied { missing } =
+            ctx.authorize(&[APPROVE_CREDIT_NOTE_PERMISSION])
+        {
+            return Err(SalesError::Forbidden { missing });
+        }
         let cn_id = CreditNoteId::from_uuid(credit_note_id);
 
         let mut credit_note = self
@@ -31,10 +43,226 @@ impl ApproveCreditNoteUseCase {
             .await?
             .ok_or(SalesError::CreditNoteNotFound(credit_note_id))?;
 
-        credit_note.approve(approver_id)?;
+        credit_note.approve(*ctx.user_id())?;
 
         self.credit_note_repo.update(&credit_note).await?;
 
         Ok(CreditNoteResponse::from(credit_note))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::{HashMap, HashSet};
+    use std::sync::Mutex;
+
+    use async_trait::async_trait;
+    use rust_decimal::Decimal;
+    use rust_decimal_macros::dec;
+
+    use crate::domain::entities::{CreditNote, CreditNoteItem};
+    use crate::domain::repositories::CreditNoteFilter;
+    use crate::domain::value_objects::{
+        CreditNoteDocument, CreditNoteItemId, CreditNoteStatus, ReturnReason, ReturnType, SaleId,
+        SaleItemId,
+    };
+    use identity::{PermissionCode, StoreId, UserId};
+    use inventory::{Currency, ProductId, UnitOfMeasure};
+
+    #[derive(Default)]
+    struct MockCreditNoteRepository {
+        credit_notes: Mutex<HashMap<CreditNoteId, CreditNote>>,
+    }
+
+    impl MockCreditNoteRepository {
+        fn get(&self, id: CreditNoteId) -> Option<CreditNote> {
+            self.credit_notes.lock().unwrap().get(&id).cloned()
+        }
+    }
+
+    #[async_trait]
+    impl CreditNoteRepository for MockCreditNoteRepository {
+        async fn save(&self, credit_note: &CreditNote) -> Result<(), SalesError> {
+            self.credit_notes
+                .lock()
+                .unwrap()
+                .insert(credit_note.id(), credit_note.clone());
+            Ok(())
+        }
+
+        async fn find_by_id(&self, id: CreditNoteId) -> Result<Option<CreditNote>, SalesError> {
+            Ok(self.get(id))
+        }
+
+        async fn find_by_id_with_items(
+            &self,
+            id: CreditNoteId,
+        ) -> Result<Option<CreditNote>, SalesError> {
+            Ok(self.get(id))
+        }
+
+        async fn find_by_number(
+            &self,
+            _store_id: StoreId,
+            _number: &str,
+        ) -> Result<Option<CreditNote>, SalesError> {
+            unimplemented!()
+        }
+
+        async fn find_by_sale(&self, _sale_id: SaleId) -> Result<Vec<CreditNote>, SalesError> {
+            unimplemented!()
+        }
+
+        async fn update(&self, credit_note: &CreditNote) -> Result<(), SalesError> {
+            self.save(credit_note).await
+        }
+
+        async fn find_paginated(
+            &self,
+            _filter: CreditNoteFilter,
+            _page: i64,
+            _page_size: i64,
+        ) -> Result<(Vec<CreditNote>, i64), SalesError> {
+            unimplemented!()
+        }
+
+        async fn generate_credit_note_number(
+            &self,
+            _store_id: StoreId,
+        ) -> Result<String, SalesError> {
+            unimplemented!()
+        }
+
+        async fn save_item(&self, _item: &CreditNoteItem) -> Result<(), SalesError> {
+            unimplemented!()
+        }
+
+        async fn update_item(&self, _item: &CreditNoteItem) -> Result<(), SalesError> {
+            unimplemented!()
+        }
+
+        async fn delete_item(&self, _item_id: CreditNoteItemId) -> Result<(), SalesError> {
+            unimplemented!()
+        }
+
+        async fn find_items_by_credit_note(
+            &self,
+            _credit_note_id: CreditNoteId,
+        ) -> Result<Vec<CreditNoteItem>, SalesError> {
+            unimplemented!()
+        }
+
+        async fn find_item_by_id(
+            &self,
+            _item_id: CreditNoteItemId,
+        ) -> Result<Option<CreditNoteItem>, SalesError> {
+            unimplemented!()
+        }
+
+        async fn find_returned_quantities(
+            &self,
+            _sale_id: SaleId,
+        ) -> Result<Vec<(SaleItemId, Decimal)>, SalesError> {
+            unimplemented!()
+        }
+
+        async fn find_document(
+            &self,
+            _credit_note_id: CreditNoteId,
+        ) -> Result<Option<CreditNoteDocument>, SalesError> {
+            unimplemented!()
+        }
+
+        async fn issue_document(
+            &self,
+            _document: CreditNoteDocument,
+        ) -> Result<CreditNoteDocument, SalesError> {
+            unimplemented!()
+        }
+    }
+
+    /// A repository holding one submitted credit note, with the note's id
+    fn setup() -> (Arc<MockCreditNoteRepository>, CreditNoteId) {
+        let created_by = UserId::new();
+        let mut credit_note = CreditNote::create(
+            "NC-0001".to_string(),
+            StoreId::new(),
+            SaleId::new(),
+            "000-001-01-00000001".to_string(),
+            ReturnType::Partial,
+            ReturnReason::Defective,
+            Currency::new("HNL").unwrap(),
+            created_by,
+        );
+        let item = CreditNoteItem::create(
+            credit_note.id(),
+            None,
+            ProductId::new(),
+            None,
+            "SKU-001".to_string(),
+            "Widget".to_string(),
+            dec!(1),
+            UnitOfMeasure::Unit,
+            dec!(100),
+            dec!(0.15),
+        )
+        .unwrap();
+        credit_note.add_item(item).unwrap();
+        credit_note.submit(created_by).unwrap();
+
+        let id = credit_note.id();
+        let repo = MockCreditNoteRepository::default();
+        repo.credit_notes.lock().unwrap().insert(id, credit_note);
+        (Arc::new(repo), id)
+    }
+
+    fn context(permissions: &[&str]) -> UserContext {
+        let permissions: HashSet<PermissionCode> = permissions
+            .iter()
+            .map(|code| PermissionCode::new(code).unwrap())
+            .collect();
+        UserContext::new(
+            UserId::new(),
+            StoreId::new(),
+            permissions,
+            vec![],
+            false,
+            None,
+            None,
+        )
+    }
+
+    #[tokio::test]
+    async fn test_approver_with_permission_approves_credit_note() {
+        let (repo, id) = setup();
+        let use_case = ApproveCreditNoteUseCase::new(repo.clone());
+
+        let response = use_case
+            .execute(id.into_uuid(), &context(&[APPROVE_CREDIT_NOTE_PERMISSION]))
+            .await
+            .unwrap();
+
+        assert_eq!(response.status, "approved");
+        assert_eq!(repo.get(id).unwrap().status(), CreditNoteStatus::Approved);
+    }
+
+    #[tokio::test]
+    async fn test_caller_without_permission_is_forbidden() {
+        let (repo, id) = setup();
+        let use_case = ApproveCreditNoteUseCase::new(repo.clone());
+
+        let result = use_case
+            .execute(id.into_uuid(), &context(&["sales:read_credit_note"]))
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(SalesError::Forbidden { ref missing })
+                if missing == &[APPROVE_CREDIT_NOTE_PERMISSION.to_string()]
+        ));
+        let credit_note = repo.get(id).unwrap();
+        assert_eq!(credit_note.status(), CreditNoteStatus::Pending);
+        assert!(credit_note.approved_by_id().is_none());
+    }
+}
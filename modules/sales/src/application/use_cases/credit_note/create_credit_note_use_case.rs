@@ -1,21 +1,52 @@
 //! Create credit note use case
 
+use std::collections::HashMap;
 use std::sync::Arc;
 
+use rust_decimal::Decimal;
+use uuid::Uuid;
+
 use crate::SalesError;
 use crate::application::dtos::{CreateCreditNoteCommand, CreditNoteResponse};
-use crate::domain::repositories::CreditNoteRepository;
-use crate::domain::value_objects::{ReturnReason, ReturnType, SaleId};
+use crate::domain::entities::{CreditNote, CreditNoteItem, Sale};
+use crate::domain::repositories::{CreditNoteRepository, CustomerRepository, SaleRepository};
+use crate::domain::value_objects::{
+    CreditNoteId, CustomerId, ReturnReason, ReturnType, SaleId, SaleItemId,
+};
 use identity::StoreId;
+use inventory::{Currency, InventoryError, ProductId, ProductRepository, VariantId};
 
 /// Use case for creating a new credit note
-pub struct CreateCreditNoteUseCase {
+///
+/// Returns without a receipt reference no sale and need a manager override.
+/// They name the customer whose store credit is refunded, and their lines
+/// are priced from the catalog.
+pub struct CreateCreditNoteUseCase<P>
+where
+    P: ProductRepository,
+{
     credit_note_repo: Arc<dyn CreditNoteRepository>,
+    sale_repo: Arc<dyn SaleRepository>,
+    customer_repo: Arc<dyn CustomerRepository>,
+    product_repo: Arc<P>,
 }
 
-impl CreateCreditNoteUseCase {
-    pub fn new(credit_note_repo: Arc<dyn CreditNoteRepository>) -> Self {
-        Self { credit_note_repo }
+impl<P> CreateCreditNoteUseCase<P>
+where
+    P: ProductRepository,
+{
+    pub fn new(
+        credit_note_repo: Arc<dyn CreditNoteRepository>,
+        sale_repo: Arc<dyn SaleRepository>,
+        customer_repo: Arc<dyn CustomerRepository>,
+        product_repo: Arc<P>,
+    ) -> Self {
+        Self {
+            credit_note_repo,
+            sale_repo,
+            customer_repo,
+            product_repo,
+        }
     }
 
     pub async fn execute(
@@ -24,33 +55,211 @@ impl CreateCreditNoteUseCase {
         created_by_id: identity::UserId,
     ) -> Result<CreditNoteResponse, SalesError> {
         let store_id = StoreId::from_uuid(cmd.store_id);
-        let original_sale_id = SaleId::from_uuid(cmd.original_sale_id);
 
         let return_type: ReturnType = cmd.return_type.parse()?;
         let return_reason: ReturnReason = cmd.return_reason.parse()?;
 
+        let sale = match cmd.original_sale_id {
+            Some(sale_id) => Some(
+                self.sale_repo
+                    .find_by_id_with_details(SaleId::from_uuid(sale_id))
+                    .await?
+                    .filter(|s| s.store_id() == store_id)
+                    .ok_or(SalesError::SaleNotFound(sale_id))?,
+            ),
+            None => None,
+        };
+
         // Generate a unique credit note number
         let credit_note_number = self
             .credit_note_repo
             .generate_credit_note_number(store_id)
             .await?;
 
-        let mut credit_note = crate::domain::entities::CreditNote::create(
-            credit_note_number,
-            store_id,
-            original_sale_id,
-            cmd.original_invoice_number,
-            return_type,
-            return_reason,
-            inventory::Currency::new("HNL").map_err(|_| SalesError::InvalidCurrency)?,
-            created_by_id,
-        );
+        let mut credit_note = match &sale {
+            Some(sale) => CreditNote::create(
+                credit_note_number,
+                store_id,
+                sale.id(),
+                cmd.original_invoice_number,
+                return_type,
+                return_reason,
+                sale.currency().clone(),
+                created_by_id,
+            ),
+            None => {
+                if !cmd.manager_override {
+                    return Err(SalesError::ReceiptlessReturnNotAuthorized);
+                }
+                let customer_id = cmd
+                    .customer_id
+                    .ok_or(SalesError::StoreCreditRequiresCustomer)?;
+                let customer = self
+                    .customer_repo
+                    .find_by_id(CustomerId::from_uuid(customer_id))
+                    .await?
+                    .ok_or(SalesError::CustomerNotFound(customer_id))?;
+                CreditNote::create_receiptless(
+                    credit_note_number,
+                    store_id,
+                    customer.id(),
+                    return_type,
+                    return_reason,
+                    Currency::default(),
+                    created_by_id,
+                )
+            }
+        };
 
         credit_note.set_reason_details(cmd.reason_details)?;
         credit_note.set_notes(cmd.notes)?;
 
+        if !cmd.items.is_empty() {
+            let mut returned = match &sale {
+                Some(sale) => returned_quantities(&*self.credit_note_repo, sale.id()).await?,
+                None => HashMap::new(),
+            };
+            for line in cmd.items {
+                let item = match (&sale, line.original_sale_item_id, line.product_id) {
+                    (Some(sale), Some(sale_item_id), None) => credit_note_item_from_sale(
+                        credit_note.id(),
+                        sale,
+                        SaleItemId::from_uuid(sale_item_id),
+                        line.return_quantity,
+                        line.serial_numbers,
+                        &mut returned,
+                    )?,
+                    (None, None, Some(product_id)) => {
+                        credit_note_item_from_catalog(
+                            self.product_repo.as_ref(),
+                            credit_note.id(),
+                            product_id,
+                            line.variant_id,
+                            line.return_quantity,
+                            line.serial_numbers,
+                        )
+                        .await?
+                    }
+                    _ => return Err(SalesError::InvalidReturnLine),
+                };
+                credit_note.add_item(item)?;
+            }
+        }
+
         self.credit_note_repo.save(&credit_note).await?;
+        for item in credit_note.items() {
+            self.credit_note_repo.save_item(item).await?;
+        }
 
         Ok(CreditNoteResponse::from(credit_note))
     }
 }
+
+/// Quantities of each sale line already on non-cancelled credit notes
+pub(super) async fn returned_quantities(
+    credit_note_repo: &dyn CreditNoteRepository,
+    sale_id: SaleId,
+) -> Result<HashMap<SaleItemId, Decimal>, SalesError> {
+    Ok(credit_note_repo
+        .find_returned_quantities(sale_id)
+        .await?
+        .into_iter()
+        .collect())
+}
+
+/// Builds a credit note item for `return_quantity` units of a sale line with
+/// the sale discount allocated to it, and records the quantity in `returned`.
+/// Returns of serial-tracked lines must name the serials coming back.
+pub(super) fn credit_note_item_from_sale(
+    credit_note_id: CreditNoteId,
+    sale: &Sale,
+    sale_item_id: SaleItemId,
+    return_quantity: Decimal,
+    serial_numbers: Vec<String>,
+    returned: &mut HashMap<SaleItemId, Decimal>,
+) -> Result<CreditNoteItem, SalesError> {
+    let sale_item = sale
+        .items()
+        .iter()
+        .find(|i| i.id() == sale_item_id)
+        .ok_or(SalesError::SaleItemNotFound(sale_item_id.into_uuid()))?;
+    let allocation = sale
+        .line_allocations()
+        .into_iter()
+        .find(|a| a.sale_item_id == sale_item_id)
+        .ok_or(SalesError::SaleItemNotFound(sale_item_id.into_uuid()))?;
+
+    let already_returned = returned.entry(sale_item_id).or_insert(Decimal::ZERO);
+    let mut item = CreditNoteItem::from_sale_line(
+        credit_note_id,
+        sale_item,
+        &allocation,
+        *already_returned,
+        return_quantity,
+    )?;
+    item.set_returned_serials(sale_item, serial_numbers)?;
+    *already_returned += return_quantity;
+
+    Ok(item)
+}
+
+/// Builds a receiptless credit note item for `return_quantity` units of a
+/// product or one of its variants, at the current catalog price and tax rate
+pub(super) async fn credit_note_item_from_catalog<P: ProductRepository>(
+    product_repo: &P,
+    credit_note_id: CreditNoteId,
+    product_id: Uuid,
+    variant_id: Option<Uuid>,
+    return_quantity: Decimal,
+    serial_numbers: Vec<String>,
+) -> Result<CreditNoteItem, SalesError> {
+    let product = product_repo
+        .find_by_id(ProductId::from_uuid(product_id))
+        .await
+        .map_err(|e| lookup_error(e, product_id))?
+        .ok_or(SalesError::ProductNotFound(product_id))?;
+
+    let (sku, description, unit_price) = match variant_id {
+        Some(variant_id) => {
+            let variant = product_repo
+                .find_variant_by_id(VariantId::from_uuid(variant_id))
+                .await
+                .map_err(|e| lookup_error(e, product_id))?
+                .filter(|v| v.product_id() == product.id())
+                .ok_or(SalesError::ProductNotFound(product_id))?;
+            (
+                variant.sku().to_string(),
+                format!("{} - {}", product.name(), variant.name()),
+                variant.price().unwrap_or(product.base_price()),
+            )
+        }
+        None => (
+            product.sku().to_string(),
+            product.name().to_string(),
+            product.base_price(),
+        ),
+    };
+
+    let mut item = CreditNoteItem::create(
+        credit_note_id,
+        None,
+        product.id(),
+        variant_id.map(VariantId::from_uuid),
+        sku,
+        description,
+        return_quantity,
+        product.unit_of_measure(),
+        unit_price,
+        product.tax_rate(),
+    )?;
+    item.set_receiptless_serials(product.is_serialized(), serial_numbers)?;
+
+    Ok(item)
+}
+
+fn lookup_error(error: InventoryError, id: Uuid) -> SalesError {
+    match error {
+        InventoryError::Database(e) => SalesError::Database(e),
+        _ => SalesError::ProductNotFound(id),
+    }
+}
@@ -0,0 +1,65 @@
+//! Generate credit note document use case
+
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::SalesError;
+use crate::application::dtos::CreditNoteDocumentResponse;
+use crate::domain::repositories::{CreditNoteRepository, SaleRepository};
+use crate::domain::value_objects::{CreditNoteDocument, CreditNoteId};
+
+/// Use case for generating the printable document of a credit note
+///
+/// The first request issues the document of an approved or applied credit
+/// note: it is built from the note and its original sale's invoice
+/// reference, numbered from the store's credit-note sequence and stored.
+/// Later requests return the stored document, so reprints match the
+/// original.
+pub struct GenerateCreditNoteDocumentUseCase {
+    credit_note_repo: Arc<dyn CreditNoteRepository>,
+    sale_repo: Arc<dyn SaleRepository>,
+}
+
+impl GenerateCreditNoteDocumentUseCase {
+    pub fn new(
+        credit_note_repo: Arc<dyn CreditNoteRepository>,
+        sale_repo: Arc<dyn SaleRepository>,
+    ) -> Self {
+        Self {
+            credit_note_repo,
+            sale_repo,
+        }
+    }
+
+    pub async fn execute(
+        &self,
+        credit_note_id: Uuid,
+    ) -> Result<CreditNoteDocumentResponse, SalesError> {
+        let cn_id = CreditNoteId::from_uuid(credit_note_id);
+
+        if let Some(document) = self.credit_note_repo.find_document(cn_id).await? {
+            return Ok(CreditNoteDocumentResponse::from(&document));
+        }
+
+        let credit_note = self
+            .credit_note_repo
+            .find_by_id_with_items(cn_id)
+            .await?
+            .ok_or(SalesError::CreditNoteNotFound(credit_note_id))?;
+
+        let original_sale = match credit_note.original_sale_id() {
+            Some(sale_id) => Some(
+                self.sale_repo
+                    .find_by_id(sale_id)
+                    .await?
+                    .ok_or(SalesError::SaleNotFound(sale_id.into_uuid()))?,
+            ),
+            None => None,
+        };
+
+        let document = CreditNoteDocument::build(&credit_note, original_sale.as_ref())?;
+        let document = self.credit_note_repo.issue_document(document).await?;
+
+        Ok(CreditNoteDocumentResponse::from(&document))
+    }
+}
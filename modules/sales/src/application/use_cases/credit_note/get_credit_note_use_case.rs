@@ -7,8 +7,12 @@ use crate::SalesError;
 use crate::application::dtos::CreditNoteResponse;
 use crate::domain::repositories::CreditNoteRepository;
 use crate::domain::value_objects::CreditNoteId;
+use identity::UserContext;
 
 /// Use case for getting a credit note by ID
+///
+/// Credit notes of stores the caller is not assigned to are reported as not
+/// found rather than revealing that they exist.
 pub struct GetCreditNoteUseCase {
     credit_note_repo: Arc<dyn CreditNoteRepository>,
 }
@@ -18,13 +22,18 @@ impl GetCreditNoteUseCase {
         Self { credit_note_repo }
     }
 
-    pub async fn execute(&self, credit_note_id: Uuid) -> Result<CreditNoteResponse, SalesError> {
+    pub async fn execute(
+        &self,
+        credit_note_id: Uuid,
+        ctx: &UserContext,
+    ) -> Result<CreditNoteResponse, SalesError> {
         let cn_id = CreditNoteId::from_uuid(credit_note_id);
 
         let credit_note = self
             .credit_note_repo
             .find_by_id_with_items(cn_id)
             .await?
+            .filter(|cn| ctx.can_access_store(cn.store_id().into_uuid()))
             .ok_or(SalesError::CreditNoteNotFound(credit_note_id))?;
 
         Ok(CreditNoteResponse::from(credit_note))
@@ -2,13 +2,18 @@
 
 use std::sync::Arc;
 
+use common::PageCursor;
+
 use crate::SalesError;
 use crate::application::dtos::{CreditNoteListResponse, CreditNoteResponse, ListCreditNotesQuery};
 use crate::domain::repositories::{CreditNoteFilter, CreditNoteRepository};
-use crate::domain::value_objects::SaleId;
-use identity::StoreId;
+use crate::domain::value_objects::{CreditNoteId, SaleId};
+use identity::{StoreId, StoreScope, UserContext};
 
 /// Use case for listing credit notes with pagination
+///
+/// Credit notes are limited to the caller's assigned stores; asking for a
+/// store the caller is not assigned to is rejected.
 pub struct ListCreditNotesUseCase {
     credit_note_repo: Arc<dyn CreditNoteRepository>,
 }
@@ -21,23 +26,42 @@ impl ListCreditNotesUseCase {
     pub async fn execute(
         &self,
         query: ListCreditNotesQuery,
+        ctx: &UserContext,
     ) -> Result<CreditNoteListResponse, SalesError> {
+        let store_ids = match ctx.store_scope(query.store_id) {
+            StoreScope::All => None,
+            StoreScope::Stores(ids) => Some(ids.into_iter().map(StoreId::from_uuid).collect()),
+            StoreScope::Denied(store_id) => return Err(SalesError::StoreAccessDenied(store_id)),
+        };
+
         let status = match &query.status {
             Some(s) => Some(s.parse()?),
             None => None,
         };
 
+        let after = PageCursor::decode_opt(query.after_cursor.as_deref())?;
+        // A cursor page has no meaningful number
+        let page = if after.is_some() { 1 } else { query.page };
+
         let filter = CreditNoteFilter {
             store_id: query.store_id.map(StoreId::from_uuid),
+            store_ids,
             original_sale_id: query.original_sale_id.map(SaleId::from_uuid),
+            receiptless: query.receiptless,
             status,
             search: query.search,
+            after_id: after.map(|c| CreditNoteId::from_uuid(c.id())),
         };
 
         let (credit_notes, total) = self
             .credit_note_repo
-            .find_paginated(filter, query.page, query.page_size)
+            .find_paginated(filter, page, query.page_size)
             .await?;
+        let next_cursor = PageCursor::next(
+            credit_notes.last().map(|c| c.id().into_uuid()),
+            credit_notes.len(),
+            query.page_size,
+        );
 
         let items: Vec<CreditNoteResponse> = credit_notes
             .into_iter()
@@ -47,8 +71,9 @@ impl ListCreditNotesUseCase {
         Ok(CreditNoteListResponse {
             items,
             total,
-            page: query.page,
+            page,
             page_size: query.page_size,
+            next_cursor,
         })
     }
 }
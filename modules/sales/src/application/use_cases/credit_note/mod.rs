@@ -5,6 +5,7 @@ pub mod apply_credit_note_use_case;
 pub mod approve_credit_note_use_case;
 pub mod cancel_credit_note_use_case;
 pub mod create_credit_note_use_case;
+pub mod generate_credit_note_document_use_case;
 pub mod get_credit_note_use_case;
 pub mod list_credit_notes_use_case;
 pub mod remove_credit_note_item_use_case;
@@ -15,6 +16,7 @@ pub use apply_credit_note_use_case::ApplyCreditNoteUseCase;
 pub use approve_credit_note_use_case::ApproveCreditNoteUseCase;
 pub use cancel_credit_note_use_case::CancelCreditNoteUseCase;
 pub use create_credit_note_use_case::CreateCreditNoteUseCase;
+pub use generate_credit_note_document_use_case::GenerateCreditNoteDocumentUseCase;
 pub use get_credit_note_use_case::GetCreditNoteUseCase;
 pub use list_credit_notes_use_case::ListCreditNotesUseCase;
 pub use remove_credit_note_item_use_case::RemoveCreditNoteItemUseCase;
@@ -0,0 +1,45 @@
+//! Configure customer code format use case
+
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::SalesError;
+use crate::application::dtos::{ConfigureCustomerCodeFormatCommand, CustomerCodeFormatResponse};
+use crate::domain::repositories::CustomerRepository;
+use crate::domain::value_objects::CustomerCodeFormat;
+use identity::StoreId;
+
+/// Use case for reading and changing the prefix/padding of a store's
+/// customer codes. Codes already issued are not renumbered.
+pub struct ConfigureCustomerCodeFormatUseCase {
+    customer_repo: Arc<dyn CustomerRepository>,
+}
+
+impl ConfigureCustomerCodeFormatUseCase {
+    pub fn new(customer_repo: Arc<dyn CustomerRepository>) -> Self {
+        Self { customer_repo }
+    }
+
+    /// Returns the current format for the store
+    pub async fn get(&self, store_id: Uuid) -> Result<CustomerCodeFormatResponse, SalesError> {
+        let format = self
+            .customer_repo
+            .find_customer_code_format(StoreId::from_uuid(store_id))
+            .await?;
+
+        Ok(CustomerCodeFormatResponse::new(store_id, &format))
+    }
+
+    pub async fn execute(
+        &self,
+        cmd: ConfigureCustomerCodeFormatCommand,
+    ) -> Result<CustomerCodeFormatResponse, SalesError> {
+        let format = CustomerCodeFormat::new(cmd.prefix, cmd.padding)?;
+
+        self.customer_repo
+            .save_customer_code_format(StoreId::from_uuid(cmd.store_id), &format)
+            .await?;
+
+        Ok(CustomerCodeFormatResponse::new(cmd.store_id, &format))
+    }
+}
@@ -8,16 +8,21 @@ use crate::application::dtos::{CreateCustomerCommand, CustomerResponse};
 use crate::domain::entities::Customer;
 use crate::domain::repositories::CustomerRepository;
 use crate::domain::value_objects::CustomerType;
+use common::TaxIdPolicy;
 use identity::StoreId;
 
 /// Use case for creating a new customer
 pub struct CreateCustomerUseCase {
     customer_repo: Arc<dyn CustomerRepository>,
+    tax_id_policy: TaxIdPolicy,
 }
 
 impl CreateCustomerUseCase {
-    pub fn new(customer_repo: Arc<dyn CustomerRepository>) -> Self {
-        Self { customer_repo }
+    pub fn new(customer_repo: Arc<dyn CustomerRepository>, tax_id_policy: TaxIdPolicy) -> Self {
+        Self {
+            customer_repo,
+            tax_id_policy,
+        }
     }
 
     pub async fn execute(
@@ -28,6 +33,13 @@ impl CreateCustomerUseCase {
         let customer_type = CustomerType::from_str(&cmd.customer_type)
             .map_err(|_| SalesError::InvalidCustomerType)?;
 
+        let tax_id = cmd
+            .tax_id
+            .as_deref()
+            .map(|raw| self.tax_id_policy.parse(raw, cmd.tax_id_country.as_deref()))
+            .transpose()
+            .map_err(|e| SalesError::InvalidTaxId(e.to_string()))?;
+
         // Check if email is already in use
         if let Some(ref email) = cmd.email
             && self
@@ -39,12 +51,16 @@ impl CreateCustomerUseCase {
             return Err(SalesError::DuplicateCustomerEmail(email.clone()));
         }
 
-        // Generate unique customer code
+        // Issue the next code from the store's sequence
         let code = self.customer_repo.generate_customer_code(store_id).await?;
 
         // Create the customer with basic info
-        let customer =
+        let mut customer =
             Customer::create(store_id, code, cmd.first_name, cmd.last_name, customer_type);
+        customer.set_tax_id(tax_id);
+        if let Some(credit_limit) = cmd.credit_limit {
+            customer.set_credit_limit(credit_limit)?;
+        }
 
         // Save the customer
         self.customer_repo.save(&customer).await?;
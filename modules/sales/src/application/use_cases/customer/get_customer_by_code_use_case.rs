@@ -0,0 +1,34 @@
+//! Get customer by code use case
+
+use std::sync::Arc;
+
+use crate::SalesError;
+use crate::application::dtos::{CustomerResponse, GetCustomerByCodeQuery};
+use crate::domain::repositories::CustomerRepository;
+use identity::StoreId;
+
+/// Use case for retrieving a customer by its human-friendly code
+pub struct GetCustomerByCodeUseCase {
+    customer_repo: Arc<dyn CustomerRepository>,
+}
+
+impl GetCustomerByCodeUseCase {
+    pub fn new(customer_repo: Arc<dyn CustomerRepository>) -> Self {
+        Self { customer_repo }
+    }
+
+    pub async fn execute(
+        &self,
+        query: GetCustomerByCodeQuery,
+    ) -> Result<CustomerResponse, SalesError> {
+        let code = query.code.trim().to_uppercase();
+
+        let customer = self
+            .customer_repo
+            .find_by_code(StoreId::from_uuid(query.store_id), &code)
+            .await?
+            .ok_or(SalesError::CustomerCodeNotFound(code))?;
+
+        Ok(CustomerResponse::from(customer))
+    }
+}
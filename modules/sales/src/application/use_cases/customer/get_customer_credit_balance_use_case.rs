@@ -0,0 +1,39 @@
+//! Get customer credit balance use case
+
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::SalesError;
+use crate::application::dtos::CustomerCreditBalanceResponse;
+use crate::domain::repositories::CustomerRepository;
+use crate::domain::value_objects::CustomerId;
+
+/// Use case for retrieving a customer's store credit balance
+pub struct GetCustomerCreditBalanceUseCase {
+    customer_repo: Arc<dyn CustomerRepository>,
+}
+
+impl GetCustomerCreditBalanceUseCase {
+    pub fn new(customer_repo: Arc<dyn CustomerRepository>) -> Self {
+        Self { customer_repo }
+    }
+
+    pub async fn execute(
+        &self,
+        customer_id: Uuid,
+    ) -> Result<CustomerCreditBalanceResponse, SalesError> {
+        let id = CustomerId::from_uuid(customer_id);
+
+        self.customer_repo
+            .find_by_id(id)
+            .await?
+            .ok_or(SalesError::CustomerNotFound(customer_id))?;
+
+        let balance = self.customer_repo.find_store_credit_balance(id).await?;
+
+        Ok(CustomerCreditBalanceResponse {
+            customer_id,
+            balance,
+        })
+    }
+}
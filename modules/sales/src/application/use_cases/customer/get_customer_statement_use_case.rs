@@ -0,0 +1,63 @@
+//! Get customer statement use case
+
+use std::sync::Arc;
+
+use chrono::{Days, NaiveTime};
+use uuid::Uuid;
+
+use crate::SalesError;
+use crate::application::dtos::{CustomerStatementQuery, CustomerStatementResponse};
+use crate::domain::repositories::CustomerRepository;
+use crate::domain::value_objects::{CustomerId, CustomerStatement};
+
+/// Use case for rendering a customer's account ledger over a period
+pub struct GetCustomerStatementUseCase {
+    customer_repo: Arc<dyn CustomerRepository>,
+}
+
+impl GetCustomerStatementUseCase {
+    pub fn new(customer_repo: Arc<dyn CustomerRepository>) -> Self {
+        Self { customer_repo }
+    }
+
+    /// Builds the statement from the balance at the start of `from` and the
+    /// entries posted through the end of `to` (dates in UTC).
+    pub async fn execute(
+        &self,
+        customer_id: Uuid,
+        query: CustomerStatementQuery,
+    ) -> Result<CustomerStatementResponse, SalesError> {
+        if query.from > query.to {
+            return Err(SalesError::InvalidStatementPeriod);
+        }
+
+        let customer = self
+            .customer_repo
+            .find_by_id(CustomerId::from_uuid(customer_id))
+            .await?
+            .ok_or(SalesError::CustomerNotFound(customer_id))?;
+
+        let start = query.from.and_time(NaiveTime::MIN).and_utc();
+        let end = query
+            .to
+            .checked_add_days(Days::new(1))
+            .ok_or(SalesError::InvalidStatementPeriod)?
+            .and_time(NaiveTime::MIN)
+            .and_utc();
+
+        let opening_balance = self
+            .customer_repo
+            .find_account_balance_at(customer.id(), start)
+            .await?;
+        let entries = self
+            .customer_repo
+            .find_account_entries(customer.id(), start, end)
+            .await?;
+
+        let statement = CustomerStatement::build(opening_balance, entries);
+
+        Ok(CustomerStatementResponse::new(
+            &customer, query.from, query.to, statement,
+        ))
+    }
+}
@@ -2,9 +2,12 @@
 
 use std::sync::Arc;
 
+use common::PageCursor;
+
 use crate::SalesError;
 use crate::application::dtos::{CustomerListResponse, CustomerResponse, ListCustomersQuery};
 use crate::domain::repositories::{CustomerFilter, CustomerRepository};
+use crate::domain::value_objects::CustomerId;
 use identity::StoreId;
 
 /// Use case for listing customers with filters and pagination
@@ -23,11 +26,15 @@ impl ListCustomersUseCase {
     ) -> Result<CustomerListResponse, SalesError> {
         let page = query.page.unwrap_or(1).max(1);
         let page_size = query.page_size.unwrap_or(20).clamp(1, 100);
+        let after = PageCursor::decode_opt(query.after_cursor.as_deref())?;
+        // A cursor page has no meaningful number
+        let page = if after.is_some() { 1 } else { page };
 
         let filter = CustomerFilter {
             store_id: query.store_id.map(StoreId::from_uuid),
             search: query.search,
             is_active: query.is_active,
+            after_id: after.map(|c| CustomerId::from_uuid(c.id())),
         };
 
         let (customers, total) = self
@@ -36,6 +43,11 @@ impl ListCustomersUseCase {
             .await?;
 
         let total_pages = (total as f64 / page_size as f64).ceil() as i64;
+        let next_cursor = PageCursor::next(
+            customers.last().map(|c| c.id().into_uuid()),
+            customers.len(),
+            page_size,
+        );
 
         Ok(CustomerListResponse {
             items: customers.into_iter().map(CustomerResponse::from).collect(),
@@ -43,6 +55,7 @@ impl ListCustomersUseCase {
             page,
             page_size,
             total_pages,
+            next_cursor,
         })
     }
 }
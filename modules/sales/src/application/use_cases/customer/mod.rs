@@ -1,13 +1,23 @@
 //! Customer use cases
 
+mod configure_customer_code_format_use_case;
 mod create_customer_use_case;
+mod get_customer_by_code_use_case;
+mod get_customer_credit_balance_use_case;
+mod get_customer_statement_use_case;
 mod get_customer_use_case;
 mod list_customers_use_case;
+mod record_account_payment_use_case;
 mod toggle_customer_status_use_case;
 mod update_customer_use_case;
 
+pub use configure_customer_code_format_use_case::ConfigureCustomerCodeFormatUseCase;
 pub use create_customer_use_case::CreateCustomerUseCase;
+pub use get_customer_by_code_use_case::GetCustomerByCodeUseCase;
+pub use get_customer_credit_balance_use_case::GetCustomerCreditBalanceUseCase;
+pub use get_customer_statement_use_case::GetCustomerStatementUseCase;
 pub use get_customer_use_case::GetCustomerUseCase;
 pub use list_customers_use_case::ListCustomersUseCase;
+pub use record_account_payment_use_case::RecordAccountPaymentUseCase;
 pub use toggle_customer_status_use_case::ToggleCustomerStatusUseCase;
 pub use update_customer_use_case::UpdateCustomerUseCase;
@@ -0,0 +1,68 @@
+//! Record account payment use case
+
+use std::str::FromStr;
+use std::sync::Arc;
+
+use rust_decimal::Decimal;
+
+use crate::SalesError;
+use crate::application::dtos::{CustomerAccountEntryResponse, RecordAccountPaymentCommand};
+use crate::domain::entities::CustomerAccountEntry;
+use crate::domain::repositories::CustomerRepository;
+use crate::domain::value_objects::{AccountEntryType, CustomerId, PaymentMethod};
+use identity::UserId;
+
+/// Use case for recording a customer's payment against their account balance
+pub struct RecordAccountPaymentUseCase {
+    customer_repo: Arc<dyn CustomerRepository>,
+}
+
+impl RecordAccountPaymentUseCase {
+    pub fn new(customer_repo: Arc<dyn CustomerRepository>) -> Self {
+        Self { customer_repo }
+    }
+
+    pub async fn execute(
+        &self,
+        cmd: RecordAccountPaymentCommand,
+        actor_id: UserId,
+    ) -> Result<CustomerAccountEntryResponse, SalesError> {
+        let payment_method = PaymentMethod::from_str(&cmd.payment_method)
+            .map_err(|_| SalesError::InvalidPaymentMethod)?;
+        if payment_method.is_on_account() {
+            return Err(SalesError::InvalidPaymentMethod);
+        }
+        if cmd.amount <= Decimal::ZERO {
+            return Err(SalesError::InvalidPaymentAmount);
+        }
+
+        let customer = self
+            .customer_repo
+            .find_by_id(CustomerId::from_uuid(cmd.customer_id))
+            .await?
+            .ok_or(SalesError::CustomerNotFound(cmd.customer_id))?;
+
+        if cmd.amount > customer.account_balance() {
+            return Err(SalesError::PaymentExceedsBalance);
+        }
+
+        let mut entry = CustomerAccountEntry::credit(
+            customer.id(),
+            customer.store_id(),
+            AccountEntryType::Payment,
+            cmd.amount,
+            None,
+            cmd.reference,
+            Some(actor_id),
+        )?;
+        let notes = match cmd.notes {
+            Some(notes) => format!("{}: {}", payment_method, notes),
+            None => payment_method.to_string(),
+        };
+        entry.set_notes(Some(notes));
+
+        self.customer_repo.post_account_entry(&mut entry).await?;
+
+        Ok(CustomerAccountEntryResponse::from(entry))
+    }
+}
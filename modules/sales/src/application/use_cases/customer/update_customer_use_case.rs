@@ -6,28 +6,42 @@ use crate::SalesError;
 use crate::application::dtos::{CustomerResponse, UpdateCustomerCommand};
 use crate::domain::repositories::CustomerRepository;
 use crate::domain::value_objects::CustomerId;
+use common::TaxIdPolicy;
+use identity::{AuditEntry, AuditRepository, UserId};
 
 /// Use case for updating an existing customer
 pub struct UpdateCustomerUseCase {
     customer_repo: Arc<dyn CustomerRepository>,
+    audit_repo: Arc<dyn AuditRepository>,
+    tax_id_policy: TaxIdPolicy,
 }
 
 impl UpdateCustomerUseCase {
-    pub fn new(customer_repo: Arc<dyn CustomerRepository>) -> Self {
-        Self { customer_repo }
+    pub fn new(
+        customer_repo: Arc<dyn CustomerRepository>,
+        audit_repo: Arc<dyn AuditRepository>,
+        tax_id_policy: TaxIdPolicy,
+    ) -> Self {
+        Self {
+            customer_repo,
+            audit_repo,
+            tax_id_policy,
+        }
     }
 
     pub async fn execute(
         &self,
         cmd: UpdateCustomerCommand,
+        actor_id: UserId,
     ) -> Result<CustomerResponse, SalesError> {
         let customer_id = CustomerId::from_uuid(cmd.customer_id);
 
-        let customer = self
+        let mut customer = self
             .customer_repo
             .find_by_id(customer_id)
             .await?
             .ok_or(SalesError::CustomerNotFound(cmd.customer_id))?;
+        let old_customer = customer.clone();
 
         // Check email uniqueness if changing
         if let Some(ref new_email) = cmd.email
@@ -41,10 +55,40 @@ impl UpdateCustomerUseCase {
             return Err(SalesError::DuplicateCustomerEmail(new_email.clone()));
         }
 
+        // A new id or a new country revalidates the tax id; the record's
+        // country applies when only the id changes
+        if cmd.tax_id.is_some() || cmd.tax_id_country.is_some() {
+            let raw = cmd.tax_id.as_deref().or(customer.tax_id());
+            let country = cmd.tax_id_country.as_deref().or(customer.tax_id_country());
+            if let Some(raw) = raw {
+                let tax_id = self
+                    .tax_id_policy
+                    .parse(raw, country)
+                    .map_err(|e| SalesError::InvalidTaxId(e.to_string()))?;
+                customer.set_tax_id(Some(tax_id));
+            }
+        }
+
+        if let Some(credit_limit) = cmd.credit_limit {
+            customer.set_credit_limit(credit_limit)?;
+        }
+
         // Note: Customer entity needs update methods for full implementation.
         // For now we return the customer as-is since the entity doesn't have setters.
         self.customer_repo.update(&customer).await?;
 
+        let audit_entry = AuditEntry::for_update(
+            "customer",
+            cmd.customer_id,
+            &old_customer,
+            &customer,
+            actor_id,
+        );
+        self.audit_repo
+            .save(&audit_entry)
+            .await
+            .map_err(|e| SalesError::AuditError(e.to_string()))?;
+
         Ok(CustomerResponse::from(customer))
     }
 }
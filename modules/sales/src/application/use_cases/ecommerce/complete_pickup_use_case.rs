@@ -0,0 +1,164 @@
+//! Complete pickup use case - hands an order over at the store counter
+
+use std::sync::Arc;
+
+use identity::UserId;
+use inventory::application::helpers::{
+    DEFAULT_BASE_DELAY_MS, DEFAULT_MAX_ATTEMPTS, retry_on_conflict,
+};
+use inventory::{
+    InventoryError, InventoryMovement, InventoryMovementRepository, InventoryReservation,
+    InventoryStockRepository, MovementType, ReservationRepository,
+};
+
+use crate::SalesError;
+use crate::application::dtos::{
+    CompletePickupCommand, OrderPickupResponse, PickupCompletedResponse, SaleDetailResponse,
+};
+use crate::domain::entities::Sale;
+use crate::domain::repositories::{OrderPickupRepository, SaleRepository};
+use crate::domain::value_objects::SaleId;
+use crate::infrastructure::OrderInvoiceIssuer;
+
+use super::order_reservations::pending_order_reservations;
+
+/// Use case for handing a pickup order to the customer.
+/// Transitions: ReadyForPickup → PickedUp
+///
+/// The pickup code is checked first. The invoice is issued next, so a fiscal
+/// problem stops the hand-over before any stock moves; then the order's
+/// reservations are confirmed, turning the held quantity into a stock
+/// deduction. Issuing is idempotent, so a hand-over that failed part way can
+/// simply be retried.
+pub struct CompletePickupUseCase<R, S, M>
+where
+    R: ReservationRepository,
+    S: InventoryStockRepository,
+    M: InventoryMovementRepository,
+{
+    sale_repo: Arc<dyn SaleRepository>,
+    pickup_repo: Arc<dyn OrderPickupRepository>,
+    reservation_repo: Arc<R>,
+    stock_repo: Arc<S>,
+    movement_repo: Arc<M>,
+    invoice_issuer: Arc<dyn OrderInvoiceIssuer>,
+}
+
+impl<R, S, M> CompletePickupUseCase<R, S, M>
+where
+    R: ReservationRepository,
+    S: InventoryStockRepository,
+    M: InventoryMovementRepository,
+{
+    pub fn new(
+        sale_repo: Arc<dyn SaleRepository>,
+        pickup_repo: Arc<dyn OrderPickupRepository>,
+        reservation_repo: Arc<R>,
+        stock_repo: Arc<S>,
+        movement_repo: Arc<M>,
+        invoice_issuer: Arc<dyn OrderInvoiceIssuer>,
+    ) -> Self {
+        Self {
+            sale_repo,
+            pickup_repo,
+            reservation_repo,
+            stock_repo,
+            movement_repo,
+            invoice_issuer,
+        }
+    }
+
+    pub async fn execute(
+        &self,
+        sale_id: uuid::Uuid,
+        command: CompletePickupCommand,
+        staff_id: UserId,
+    ) -> Result<PickupCompletedResponse, SalesError> {
+        let id = SaleId::from_uuid(sale_id);
+        let mut sale = self
+            .sale_repo
+            .find_by_id_with_details(id)
+            .await?
+            .ok_or(SalesError::SaleNotFound(sale_id))?;
+        let mut pickup = self
+            .pickup_repo
+            .find_by_sale(id)
+            .await?
+            .ok_or(SalesError::PickupNotFound(sale_id))?;
+
+        pickup.complete(&command.code, staff_id)?;
+        sale.complete_pickup()?;
+
+        let invoice_id = self.invoice_issuer.issue_invoice(&sale).await?;
+
+        let reservations = pending_order_reservations(self.reservation_repo.as_ref(), &sale)
+            .await
+            .map_err(|_| SalesError::ReservationConfirmFailed)?;
+        let reservations_committed = reservations.len();
+        for mut reservation in reservations {
+            self.commit_reservation(&mut reservation, &sale, staff_id)
+                .await
+                .map_err(|_| SalesError::ReservationConfirmFailed)?;
+        }
+
+        self.sale_repo.update(&sale).await?;
+        self.pickup_repo.update(&pickup).await?;
+
+        Ok(PickupCompletedResponse {
+            pickup: OrderPickupResponse::from(&pickup),
+            order: SaleDetailResponse::from(sale),
+            invoice_id,
+            reservations_committed,
+        })
+    }
+
+    /// Confirms the reservation and deducts its quantity from stock
+    async fn commit_reservation(
+        &self,
+        reservation: &mut InventoryReservation,
+        sale: &Sale,
+        staff_id: UserId,
+    ) -> Result<(), InventoryError> {
+        reservation.confirm()?;
+
+        let stock_id = reservation.stock_id();
+        let quantity = reservation.quantity();
+
+        let balance_after =
+            retry_on_conflict(DEFAULT_MAX_ATTEMPTS, DEFAULT_BASE_DELAY_MS, || async {
+                let mut stock = self
+                    .stock_repo
+                    .find_by_id(stock_id)
+                    .await?
+                    .ok_or(InventoryError::StockNotFound(stock_id.into_uuid()))?;
+
+                let expected_version = stock.version();
+                stock.release(quantity)?;
+                stock.adjust_quantity(-quantity)?;
+                stock.increment_version();
+
+                self.stock_repo
+                    .update_with_version(&stock, expected_version)
+                    .await?;
+                Ok(stock.quantity())
+            })
+            .await?;
+
+        let movement = InventoryMovement::create(
+            stock_id,
+            MovementType::Out,
+            Some("reservation_confirmed".to_string()),
+            -quantity,
+            None,
+            sale.currency().clone(),
+            balance_after,
+            Some(reservation.reference_type().to_string()),
+            Some(reservation.reference_id()),
+            staff_id,
+            Some(format!("Picked up order {}", sale.sale_number())),
+        );
+        self.movement_repo.save(&movement).await?;
+
+        self.reservation_repo.update(reservation).await
+    }
+}
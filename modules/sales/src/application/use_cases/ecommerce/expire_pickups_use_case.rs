@@ -0,0 +1,180 @@
+//! Expire pickups use case - cancels pickup orders nobody came to collect
+
+use std::sync::Arc;
+
+use chrono::Utc;
+use rust_decimal::Decimal;
+
+use inventory::application::helpers::{
+    DEFAULT_BASE_DELAY_MS, DEFAULT_MAX_ATTEMPTS, retry_on_conflict,
+};
+use inventory::{
+    InventoryError, InventoryReservation, InventoryStockRepository, ReservationRepository,
+};
+
+use crate::SalesError;
+use crate::application::dtos::ExpirePickupsResult;
+use crate::domain::entities::OrderPickup;
+use crate::domain::repositories::{OrderPickupRepository, SaleRepository};
+use crate::domain::value_objects::OrderStatus;
+
+use super::order_reservations::pending_order_reservations;
+
+/// What happened to a single overdue pickup
+enum PickupExpiryOutcome {
+    Cancelled {
+        reservations_released: usize,
+        quantity_released: Decimal,
+    },
+    Closed,
+}
+
+/// Cancels orders still on the pickup shelf after their hold ended and puts
+/// the reserved stock back on sale.
+///
+/// A pickup whose order already left `ReadyForPickup` (e.g. cancelled by
+/// staff) is just closed. The use case is scheduled as a background job and
+/// processes at most `batch_size` pickups per run; failures on one pickup do
+/// not stop the run.
+pub struct ExpirePickupsUseCase<R, S>
+where
+    R: ReservationRepository,
+    S: InventoryStockRepository,
+{
+    sale_repo: Arc<dyn SaleRepository>,
+    pickup_repo: Arc<dyn OrderPickupRepository>,
+    reservation_repo: Arc<R>,
+    stock_repo: Arc<S>,
+}
+
+impl<R, S> ExpirePickupsUseCase<R, S>
+where
+    R: ReservationRepository,
+    S: InventoryStockRepository,
+{
+    pub fn new(
+        sale_repo: Arc<dyn SaleRepository>,
+        pickup_repo: Arc<dyn OrderPickupRepository>,
+        reservation_repo: Arc<R>,
+        stock_repo: Arc<S>,
+    ) -> Self {
+        Self {
+            sale_repo,
+            pickup_repo,
+            reservation_repo,
+            stock_repo,
+        }
+    }
+
+    /// Expires up to `batch_size` overdue pickups, oldest hold first
+    pub async fn execute(&self, batch_size: i64) -> Result<ExpirePickupsResult, SalesError> {
+        let now = Utc::now();
+        let pickups = self
+            .pickup_repo
+            .find_overdue(now, batch_size.max(1))
+            .await?;
+
+        let mut result = ExpirePickupsResult::default();
+
+        for mut pickup in pickups.into_iter().filter(|p| p.is_overdue(now)) {
+            match self.expire_pickup(&mut pickup).await {
+                Ok(PickupExpiryOutcome::Cancelled {
+                    reservations_released,
+                    quantity_released,
+                }) => {
+                    result.orders_cancelled += 1;
+                    result.reservations_released += reservations_released;
+                    result.quantity_released += quantity_released;
+                }
+                Ok(PickupExpiryOutcome::Closed) => {}
+                Err(e) => {
+                    result.failed += 1;
+                    result.errors.push(format!(
+                        "Failed to expire pickup for order {}: {}",
+                        pickup.sale_id().into_uuid(),
+                        e
+                    ));
+                }
+            }
+        }
+
+        Ok(result)
+    }
+
+    async fn expire_pickup(&self, pickup: &mut OrderPickup) -> Result<PickupExpiryOutcome, String> {
+        let sale = self
+            .sale_repo
+            .find_by_id_with_details(pickup.sale_id())
+            .await
+            .map_err(|e| e.to_string())?;
+
+        let Some(mut sale) = sale.filter(|s| s.order_status() == Some(OrderStatus::ReadyForPickup))
+        else {
+            pickup.cancel().map_err(|e| e.to_string())?;
+            self.pickup_repo
+                .update(pickup)
+                .await
+                .map_err(|e| e.to_string())?;
+            return Ok(PickupExpiryOutcome::Closed);
+        };
+
+        sale.cancel_order().map_err(|e| e.to_string())?;
+
+        let reservations = pending_order_reservations(self.reservation_repo.as_ref(), &sale)
+            .await
+            .map_err(|e| e.to_string())?;
+        let mut reservations_released = 0;
+        let mut quantity_released = Decimal::ZERO;
+        for mut reservation in reservations {
+            self.release_reservation(&mut reservation)
+                .await
+                .map_err(|e| e.to_string())?;
+            reservations_released += 1;
+            quantity_released += reservation.quantity();
+        }
+
+        self.sale_repo
+            .update(&sale)
+            .await
+            .map_err(|e| e.to_string())?;
+        pickup.expire().map_err(|e| e.to_string())?;
+        self.pickup_repo
+            .update(pickup)
+            .await
+            .map_err(|e| e.to_string())?;
+
+        Ok(PickupExpiryOutcome::Cancelled {
+            reservations_released,
+            quantity_released,
+        })
+    }
+
+    async fn release_reservation(
+        &self,
+        reservation: &mut InventoryReservation,
+    ) -> Result<(), InventoryError> {
+        reservation.cancel()?;
+
+        let stock_id = reservation.stock_id();
+        let quantity = reservation.quantity();
+
+        retry_on_conflict(DEFAULT_MAX_ATTEMPTS, DEFAULT_BASE_DELAY_MS, || async {
+            let mut stock = self
+                .stock_repo
+                .find_by_id(stock_id)
+                .await?
+                .ok_or(InventoryError::StockNotFound(stock_id.into_uuid()))?;
+
+            let expected_version = stock.version();
+            stock.release(quantity)?;
+            stock.increment_version();
+
+            self.stock_repo
+                .update_with_version(&stock, expected_version)
+                .await
+        })
+        .await?;
+
+        self.reservation_repo.update(reservation).await
+    }
+}
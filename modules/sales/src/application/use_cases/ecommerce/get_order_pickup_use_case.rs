@@ -0,0 +1,29 @@
+//! Get order pickup use case - pickup code and hold for an order
+
+use std::sync::Arc;
+
+use crate::SalesError;
+use crate::application::dtos::OrderPickupResponse;
+use crate::domain::repositories::OrderPickupRepository;
+use crate::domain::value_objects::SaleId;
+
+/// Use case for looking up the pickup of an order
+pub struct GetOrderPickupUseCase {
+    pickup_repo: Arc<dyn OrderPickupRepository>,
+}
+
+impl GetOrderPickupUseCase {
+    pub fn new(pickup_repo: Arc<dyn OrderPickupRepository>) -> Self {
+        Self { pickup_repo }
+    }
+
+    pub async fn execute(&self, sale_id: uuid::Uuid) -> Result<OrderPickupResponse, SalesError> {
+        let pickup = self
+            .pickup_repo
+            .find_by_sale(SaleId::from_uuid(sale_id))
+            .await?
+            .ok_or(SalesError::PickupNotFound(sale_id))?;
+
+        Ok(OrderPickupResponse::from(&pickup))
+    }
+}
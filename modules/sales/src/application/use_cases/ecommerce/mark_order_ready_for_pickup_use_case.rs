@@ -0,0 +1,106 @@
+//! Mark order ready for pickup use case - holds an online order at the store
+
+use std::sync::Arc;
+
+use crate::SalesError;
+use crate::application::dtos::{OrderPickupResponse, ReadyForPickupResponse, SaleDetailResponse};
+use crate::domain::entities::{OrderPickup, Sale};
+use crate::domain::repositories::{CustomerRepository, OrderPickupRepository, SaleRepository};
+use crate::domain::value_objects::SaleId;
+use crate::infrastructure::OrderNotifier;
+use inventory::ReservationRepository;
+
+use super::order_reservations::pending_order_reservations;
+
+/// Use case for putting an order on the pickup shelf.
+/// Transitions: Paid/Processing → ReadyForPickup
+///
+/// Stock stays reserved, not deducted, until the customer collects the
+/// order: the order's pending reservations are extended to the end of the
+/// hold so the reservation expiry job leaves them alone. The customer is
+/// then sent the pickup code; a failed notification does not undo the hold.
+pub struct MarkOrderReadyForPickupUseCase<R>
+where
+    R: ReservationRepository,
+{
+    sale_repo: Arc<dyn SaleRepository>,
+    pickup_repo: Arc<dyn OrderPickupRepository>,
+    customer_repo: Arc<dyn CustomerRepository>,
+    reservation_repo: Arc<R>,
+    notifier: Arc<dyn OrderNotifier>,
+}
+
+impl<R> MarkOrderReadyForPickupUseCase<R>
+where
+    R: ReservationRepository,
+{
+    pub fn new(
+        sale_repo: Arc<dyn SaleRepository>,
+        pickup_repo: Arc<dyn OrderPickupRepository>,
+        customer_repo: Arc<dyn CustomerRepository>,
+        reservation_repo: Arc<R>,
+        notifier: Arc<dyn OrderNotifier>,
+    ) -> Self {
+        Self {
+            sale_repo,
+            pickup_repo,
+            customer_repo,
+            reservation_repo,
+            notifier,
+        }
+    }
+
+    /// Holds the order for `hold_days` days (the store's pickup hold)
+    pub async fn execute(
+        &self,
+        sale_id: uuid::Uuid,
+        hold_days: i32,
+    ) -> Result<ReadyForPickupResponse, SalesError> {
+        let id = SaleId::from_uuid(sale_id);
+        let mut sale = self
+            .sale_repo
+            .find_by_id_with_details(id)
+            .await?
+            .ok_or(SalesError::SaleNotFound(sale_id))?;
+
+        sale.mark_ready_for_pickup()?;
+        let pickup = OrderPickup::create(sale.id(), sale.store_id(), hold_days);
+
+        let reservations = pending_order_reservations(self.reservation_repo.as_ref(), &sale)
+            .await
+            .map_err(|_| SalesError::ReservationFailed)?;
+        for mut reservation in reservations {
+            reservation
+                .extend(pickup.hold_until())
+                .map_err(|_| SalesError::ReservationFailed)?;
+            self.reservation_repo
+                .update(&reservation)
+                .await
+                .map_err(|_| SalesError::ReservationFailed)?;
+        }
+
+        self.sale_repo.update(&sale).await?;
+        self.pickup_repo.save(&pickup).await?;
+
+        let customer_notified = self.notify_customer(&sale, &pickup).await;
+
+        Ok(ReadyForPickupResponse {
+            pickup: OrderPickupResponse::from(&pickup),
+            order: SaleDetailResponse::from(sale),
+            customer_notified,
+        })
+    }
+
+    async fn notify_customer(&self, sale: &Sale, pickup: &OrderPickup) -> bool {
+        let Some(customer_id) = sale.customer_id() else {
+            return false;
+        };
+        let Ok(Some(customer)) = self.customer_repo.find_by_id(customer_id).await else {
+            return false;
+        };
+        self.notifier
+            .notify_ready_for_pickup(&customer, sale, pickup)
+            .await
+            .is_ok()
+    }
+}
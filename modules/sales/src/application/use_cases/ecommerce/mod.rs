@@ -1,13 +1,23 @@
 //! E-commerce order workflow use cases
 
 mod cancel_order_use_case;
+mod complete_pickup_use_case;
 mod deliver_order_use_case;
+mod expire_pickups_use_case;
+mod get_order_pickup_use_case;
 mod mark_order_paid_use_case;
+mod mark_order_ready_for_pickup_use_case;
+mod order_reservations;
 mod process_order_use_case;
 mod ship_order_use_case;
 
 pub use cancel_order_use_case::CancelOrderUseCase;
+pub use complete_pickup_use_case::CompletePickupUseCase;
 pub use deliver_order_use_case::DeliverOrderUseCase;
+pub use expire_pickups_use_case::ExpirePickupsUseCase;
+pub use get_order_pickup_use_case::GetOrderPickupUseCase;
 pub use mark_order_paid_use_case::MarkOrderPaidUseCase;
+pub use mark_order_ready_for_pickup_use_case::MarkOrderReadyForPickupUseCase;
+pub use order_reservations::ORDER_RESERVATION_REFERENCE;
 pub use process_order_use_case::ProcessOrderUseCase;
 pub use ship_order_use_case::ShipOrderUseCase;
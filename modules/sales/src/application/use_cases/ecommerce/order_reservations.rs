@@ -0,0 +1,35 @@
+//! Lookup of the inventory reservations held by an online order
+
+use std::collections::HashSet;
+
+use crate::domain::entities::Sale;
+use inventory::{InventoryError, InventoryReservation, ReservationRepository, ReservationStatus};
+
+/// Reference type used for reservations held by an order
+pub const ORDER_RESERVATION_REFERENCE: &str = "order";
+
+/// Pending reservations referencing the order plus those linked from its
+/// items
+pub(super) async fn pending_order_reservations<R>(
+    reservation_repo: &R,
+    sale: &Sale,
+) -> Result<Vec<InventoryReservation>, InventoryError>
+where
+    R: ReservationRepository,
+{
+    let mut reservations = reservation_repo
+        .find_by_reference(ORDER_RESERVATION_REFERENCE, sale.id().into_uuid())
+        .await?;
+
+    let mut seen: HashSet<_> = reservations.iter().map(|r| r.id()).collect();
+    for reservation_id in sale.items().iter().filter_map(|i| i.reservation_id()) {
+        if seen.insert(reservation_id)
+            && let Some(reservation) = reservation_repo.find_by_id(reservation_id).await?
+        {
+            reservations.push(reservation);
+        }
+    }
+
+    reservations.retain(|r| r.status() == ReservationStatus::Pending);
+    Ok(reservations)
+}
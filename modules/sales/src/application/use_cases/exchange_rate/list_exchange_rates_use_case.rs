@@ -0,0 +1,34 @@
+use std::sync::Arc;
+
+use uuid::Uuid;
+
+use crate::SalesError;
+use crate::application::dtos::exchange_rate::ExchangeRateResponse;
+use crate::domain::repositories::ExchangeRateRepository;
+use inventory::Currency;
+
+/// Lists a store's rate history, newest first
+pub struct ListExchangeRatesUseCase<X: ExchangeRateRepository> {
+    exchange_rate_repo: Arc<X>,
+}
+
+impl<X: ExchangeRateRepository> ListExchangeRatesUseCase<X> {
+    pub fn new(exchange_rate_repo: Arc<X>) -> Self {
+        Self { exchange_rate_repo }
+    }
+
+    pub async fn execute(
+        &self,
+        store_id: Uuid,
+        currency: Option<String>,
+    ) -> Result<Vec<ExchangeRateResponse>, SalesError> {
+        let currency = currency
+            .map(|c| Currency::new(&c).map_err(|_| SalesError::InvalidCurrency))
+            .transpose()?;
+        let rates = self
+            .exchange_rate_repo
+            .list(store_id, currency.as_ref())
+            .await?;
+        Ok(rates.iter().map(ExchangeRateResponse::from).collect())
+    }
+}
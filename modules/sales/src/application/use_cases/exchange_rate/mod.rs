@@ -0,0 +1,5 @@
+mod list_exchange_rates_use_case;
+mod record_exchange_rate_use_case;
+
+pub use list_exchange_rates_use_case::ListExchangeRatesUseCase;
+pub use record_exchange_rate_use_case::RecordExchangeRateUseCase;
@@ -0,0 +1,37 @@
+use std::sync::Arc;
+
+use crate::SalesError;
+use crate::application::dtos::exchange_rate::{ExchangeRateResponse, RecordExchangeRateCommand};
+use crate::domain::entities::ExchangeRate;
+use crate::domain::repositories::ExchangeRateRepository;
+use identity::UserId;
+use inventory::Currency;
+
+/// Records a store's rate for a foreign currency. Earlier rates are kept,
+/// so sales completed at them still show the rate they were rung up at.
+pub struct RecordExchangeRateUseCase<X: ExchangeRateRepository> {
+    exchange_rate_repo: Arc<X>,
+}
+
+impl<X: ExchangeRateRepository> RecordExchangeRateUseCase<X> {
+    pub fn new(exchange_rate_repo: Arc<X>) -> Self {
+        Self { exchange_rate_repo }
+    }
+
+    pub async fn execute(
+        &self,
+        command: RecordExchangeRateCommand,
+        actor_id: UserId,
+    ) -> Result<ExchangeRateResponse, SalesError> {
+        let currency = Currency::new(&command.currency).map_err(|_| SalesError::InvalidCurrency)?;
+        // Sales are rung up in the default currency, which needs no rate
+        if currency == Currency::default() {
+            return Err(SalesError::InvalidCurrency);
+        }
+
+        let rate = ExchangeRate::create(command.store_id, currency, command.rate, actor_id)?;
+        self.exchange_rate_repo.save(&rate).await?;
+
+        Ok(ExchangeRateResponse::from(&rate))
+    }
+}
@@ -0,0 +1,29 @@
+//! Get gift card balance use case
+
+use std::sync::Arc;
+
+use crate::SalesError;
+use crate::application::dtos::GiftCardBalanceResponse;
+use crate::domain::entities::normalize_gift_card_code;
+use crate::domain::repositories::GiftCardRepository;
+
+/// Use case for checking a gift card's balance by its code
+pub struct GetGiftCardBalanceUseCase {
+    gift_card_repo: Arc<dyn GiftCardRepository>,
+}
+
+impl GetGiftCardBalanceUseCase {
+    pub fn new(gift_card_repo: Arc<dyn GiftCardRepository>) -> Self {
+        Self { gift_card_repo }
+    }
+
+    pub async fn execute(&self, code: &str) -> Result<GiftCardBalanceResponse, SalesError> {
+        let card = self
+            .gift_card_repo
+            .find_by_code(code)
+            .await?
+            .ok_or_else(|| SalesError::GiftCardNotFound(normalize_gift_card_code(code)))?;
+
+        Ok(GiftCardBalanceResponse::from(&card))
+    }
+}
@@ -0,0 +1,127 @@
+//! Issue gift card use case
+
+use std::sync::Arc;
+
+use rust_decimal::Decimal;
+
+use crate::SalesError;
+use crate::application::dtos::{GiftCardResponse, IssueGiftCardCommand};
+use crate::domain::entities::{GiftCard, SaleItem};
+use crate::domain::repositories::{GiftCardRepository, SaleRepository};
+use crate::domain::value_objects::SaleId;
+use identity::{StoreId, UserId};
+use inventory::{Currency, InventoryError, ProductId, ProductRepository};
+
+/// Use case for issuing a gift card
+///
+/// A card sold on a sale adds a line for the gift card product at the
+/// card's amount, untaxed and without cost, in the sale's currency. The card
+/// stays pending until the sale is completed. A card issued on its own is
+/// active at once.
+pub struct IssueGiftCardUseCase<P>
+where
+    P: ProductRepository,
+{
+    gift_card_repo: Arc<dyn GiftCardRepository>,
+    sale_repo: Arc<dyn SaleRepository>,
+    product_repo: Arc<P>,
+}
+
+impl<P> IssueGiftCardUseCase<P>
+where
+    P: ProductRepository,
+{
+    pub fn new(
+        gift_card_repo: Arc<dyn GiftCardRepository>,
+        sale_repo: Arc<dyn SaleRepository>,
+        product_repo: Arc<P>,
+    ) -> Self {
+        Self {
+            gift_card_repo,
+            sale_repo,
+            product_repo,
+        }
+    }
+
+    pub async fn execute(
+        &self,
+        cmd: IssueGiftCardCommand,
+        actor_id: UserId,
+    ) -> Result<GiftCardResponse, SalesError> {
+        let currency = cmd
+            .currency
+            .as_deref()
+            .map(|code| Currency::new(code).map_err(|_| SalesError::InvalidCurrency))
+            .transpose()?;
+
+        let Some(sale_uuid) = cmd.sale_id else {
+            let card = GiftCard::issue(
+                StoreId::from_uuid(cmd.store_id),
+                cmd.code.as_deref(),
+                cmd.amount,
+                currency.unwrap_or_default(),
+                actor_id,
+            )?;
+            self.gift_card_repo.save(&card).await?;
+            return Ok(GiftCardResponse::from(&card));
+        };
+
+        let mut sale = self
+            .sale_repo
+            .find_by_id_with_details(SaleId::from_uuid(sale_uuid))
+            .await?
+            .ok_or(SalesError::SaleNotFound(sale_uuid))?;
+        if sale.store_id().into_uuid() != cmd.store_id {
+            return Err(SalesError::SaleNotFound(sale_uuid));
+        }
+        if !sale.is_editable() {
+            return Err(SalesError::SaleNotEditable);
+        }
+        if currency.is_some_and(|c| c != *sale.currency()) {
+            return Err(SalesError::GiftCardCurrencyMismatch);
+        }
+
+        let product_uuid = cmd.product_id.ok_or(SalesError::GiftCardProductRequired)?;
+        let product = self
+            .product_repo
+            .find_by_id(ProductId::from_uuid(product_uuid))
+            .await
+            .map_err(|e| match e {
+                InventoryError::Database(e) => SalesError::Database(e),
+                _ => SalesError::ProductNotFound(product_uuid),
+            })?
+            .ok_or(SalesError::ProductNotFound(product_uuid))?;
+
+        let item = SaleItem::create(
+            sale.id(),
+            sale.item_count() as i32 + 1,
+            product.id(),
+            None,
+            product.sku().as_str().to_string(),
+            product.name().to_string(),
+            Decimal::ONE,
+            product.unit_of_measure(),
+            cmd.amount,
+            Decimal::ZERO,
+            Decimal::ZERO,
+        )?;
+        let card = GiftCard::issue_on_sale(
+            sale.store_id(),
+            cmd.code.as_deref(),
+            cmd.amount,
+            sale.currency().clone(),
+            actor_id,
+            sale.id(),
+            item.id(),
+        )?;
+
+        // Save the card first so a taken code leaves the sale untouched
+        self.gift_card_repo.save(&card).await?;
+
+        sale.add_item(item.clone())?;
+        self.sale_repo.save_item(&item).await?;
+        self.sale_repo.update(&sale).await?;
+
+        Ok(GiftCardResponse::from(&card))
+    }
+}
@@ -0,0 +1,5 @@
+mod get_gift_card_balance_use_case;
+mod issue_gift_card_use_case;
+
+pub use get_gift_card_balance_use_case::GetGiftCardBalanceUseCase;
+pub use issue_gift_card_use_case::IssueGiftCardUseCase;
@@ -0,0 +1,102 @@
+//! Cancel layaway use case
+
+use std::sync::Arc;
+
+use rust_decimal::Decimal;
+use uuid::Uuid;
+
+use inventory::{InventoryStockRepository, ReservationRepository};
+
+use crate::SalesError;
+use crate::application::dtos::{CancelLayawayCommand, LayawayResponse};
+use crate::application::use_cases::pos::{release_sale_reservations, reverse_customer_charges};
+use crate::domain::repositories::{CustomerRepository, LayawayRepository, SaleRepository};
+use crate::domain::value_objects::SaleId;
+use identity::UserId;
+
+/// Reason recorded on the voided sale when none is given
+const DEFAULT_CANCEL_REASON: &str = "Layaway cancelled";
+
+/// Use case for cancelling a layaway before it is paid off
+///
+/// The sale is voided and the stock held for it is put back on sale. Of
+/// what the customer paid, an optional restocking fee is kept and the rest
+/// is recorded as owed back; on-account and store-credit tenders are
+/// reversed as for any voided sale.
+pub struct CancelLayawayUseCase<R, S>
+where
+    R: ReservationRepository,
+    S: InventoryStockRepository,
+{
+    sale_repo: Arc<dyn SaleRepository>,
+    customer_repo: Arc<dyn CustomerRepository>,
+    layaway_repo: Arc<dyn LayawayRepository>,
+    reservation_repo: Arc<R>,
+    stock_repo: Arc<S>,
+}
+
+impl<R, S> CancelLayawayUseCase<R, S>
+where
+    R: ReservationRepository,
+    S: InventoryStockRepository,
+{
+    pub fn new(
+        sale_repo: Arc<dyn SaleRepository>,
+        customer_repo: Arc<dyn CustomerRepository>,
+        layaway_repo: Arc<dyn LayawayRepository>,
+        reservation_repo: Arc<R>,
+        stock_repo: Arc<S>,
+    ) -> Self {
+        Self {
+            sale_repo,
+            customer_repo,
+            layaway_repo,
+            reservation_repo,
+            stock_repo,
+        }
+    }
+
+    pub async fn execute(
+        &self,
+        sale_id: Uuid,
+        cmd: CancelLayawayCommand,
+        cancelled_by: UserId,
+    ) -> Result<LayawayResponse, SalesError> {
+        let mut sale = self
+            .sale_repo
+            .find_by_id_with_details(SaleId::from_uuid(sale_id))
+            .await?
+            .ok_or(SalesError::SaleNotFound(sale_id))?;
+        let mut layaway = self
+            .layaway_repo
+            .find_by_sale(sale.id())
+            .await?
+            .ok_or(SalesError::LayawayNotFound(sale_id))?;
+
+        layaway.cancel(
+            cmd.restocking_fee.unwrap_or(Decimal::ZERO),
+            sale.amount_paid(),
+            cmd.reason.clone(),
+        )?;
+        let reason = cmd
+            .reason
+            .unwrap_or_else(|| DEFAULT_CANCEL_REASON.to_string());
+        sale.void(cancelled_by, reason)?;
+
+        self.sale_repo.update(&sale).await?;
+        self.layaway_repo.update(&layaway).await?;
+
+        reverse_customer_charges(self.customer_repo.as_ref(), &sale, cancelled_by).await?;
+
+        // Put the held items back on sale
+        release_sale_reservations(
+            self.reservation_repo.as_ref(),
+            self.stock_repo.as_ref(),
+            &sale,
+        )
+        .await
+        .map_err(|_| SalesError::ReservationCancelFailed)?;
+
+        Ok(LayawayResponse::from(&layaway))
+    }
+}
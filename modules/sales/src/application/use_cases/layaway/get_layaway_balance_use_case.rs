@@ -0,0 +1,48 @@
+//! Get layaway balance use case
+
+use std::sync::Arc;
+
+use chrono::Utc;
+use uuid::Uuid;
+
+use crate::SalesError;
+use crate::application::dtos::LayawayBalanceResponse;
+use crate::domain::repositories::{LayawayRepository, SaleRepository};
+use crate::domain::value_objects::SaleId;
+
+/// Use case for checking what is paid and still due on a layaway sale
+pub struct GetLayawayBalanceUseCase {
+    sale_repo: Arc<dyn SaleRepository>,
+    layaway_repo: Arc<dyn LayawayRepository>,
+}
+
+impl GetLayawayBalanceUseCase {
+    pub fn new(
+        sale_repo: Arc<dyn SaleRepository>,
+        layaway_repo: Arc<dyn LayawayRepository>,
+    ) -> Self {
+        Self {
+            sale_repo,
+            layaway_repo,
+        }
+    }
+
+    pub async fn execute(&self, sale_id: Uuid) -> Result<LayawayBalanceResponse, SalesError> {
+        let sale = self
+            .sale_repo
+            .find_by_id(SaleId::from_uuid(sale_id))
+            .await?
+            .ok_or(SalesError::SaleNotFound(sale_id))?;
+        let layaway = self
+            .layaway_repo
+            .find_by_sale(sale.id())
+            .await?
+            .ok_or(SalesError::LayawayNotFound(sale_id))?;
+
+        Ok(LayawayBalanceResponse::new(
+            &sale,
+            &layaway,
+            Utc::now().date_naive(),
+        ))
+    }
+}
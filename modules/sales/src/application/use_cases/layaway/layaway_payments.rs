@@ -0,0 +1,63 @@
+//! Payments taken against layaway sales
+
+use chrono::{DateTime, Duration, NaiveDate, NaiveTime, Utc};
+use rust_decimal::Decimal;
+
+use crate::SalesError;
+use crate::domain::entities::{Layaway, Sale};
+use crate::domain::repositories::LayawayRepository;
+use crate::domain::value_objects::LayawayStatus;
+
+/// Days a layaway's stock stays reserved past its last installment's due
+/// date, so a late final payment still finds the items held
+pub const LAYAWAY_HOLD_GRACE_DAYS: i64 = 7;
+
+/// Looks up the layaway of a sale about to take a payment. A layaway still
+/// waiting for its deposit puts the sale on layaway, so the deposit is
+/// taken against a sale whose items can no longer change.
+///
+/// Returns the layaway while it is open or active.
+pub async fn prepare_layaway_payment(
+    layaway_repo: &dyn LayawayRepository,
+    sale: &mut Sale,
+) -> Result<Option<Layaway>, SalesError> {
+    let Some(layaway) = layaway_repo.find_by_sale(sale.id()).await? else {
+        return Ok(None);
+    };
+    if !layaway.status().is_open() {
+        return Ok(None);
+    }
+    if layaway.status() == LayawayStatus::Open {
+        sale.start_layaway()?;
+    }
+    Ok(Some(layaway))
+}
+
+/// Applies `paid`, the amount a payment just added to the sale paid of its
+/// balance, to the layaway's schedule.
+///
+/// The deposit activates the layaway, scheduling whatever it leaves due,
+/// and returns when the sale's stock should be held until. A deposit that
+/// pays the whole sale leaves nothing to schedule or hold.
+pub fn apply_layaway_payment(
+    layaway: &mut Layaway,
+    sale: &Sale,
+    paid: Decimal,
+    today: NaiveDate,
+) -> Result<Option<DateTime<Utc>>, SalesError> {
+    match layaway.status() {
+        LayawayStatus::Open if sale.amount_due() > Decimal::ZERO => {
+            layaway.activate(sale.amount_due(), today)?;
+            let hold_until = layaway
+                .final_due_date()
+                .map(|date| date + Duration::days(LAYAWAY_HOLD_GRACE_DAYS))
+                .map(|date| date.and_time(NaiveTime::MIN).and_utc());
+            Ok(hold_until)
+        }
+        LayawayStatus::Active if paid > Decimal::ZERO => {
+            layaway.apply_payment(paid)?;
+            Ok(None)
+        }
+        _ => Ok(None),
+    }
+}
@@ -0,0 +1,9 @@
+mod cancel_layaway_use_case;
+mod get_layaway_balance_use_case;
+mod layaway_payments;
+
+pub use cancel_layaway_use_case::CancelLayawayUseCase;
+pub use get_layaway_balance_use_case::GetLayawayBalanceUseCase;
+pub use layaway_payments::{
+    LAYAWAY_HOLD_GRACE_DAYS, apply_layaway_payment, prepare_layaway_payment,
+};
@@ -0,0 +1,169 @@
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+
+use chrono::{Duration, Utc};
+use identity::StoreSettings;
+use uuid::Uuid;
+
+use crate::SalesError;
+use crate::application::dtos::markdown::{ApplyAutoMarkdownsResult, MarkdownResponse};
+use crate::domain::entities::{Markdown, MarkdownRule, Promotion};
+use crate::domain::repositories::{MarkdownRepository, PromotionRepository};
+use crate::domain::value_objects::{MarkdownId, MarkdownReason, PromotionType};
+
+/// Evaluates every active markdown rule and marks down matching products.
+///
+/// Each markdown publishes a product-scoped percentage promotion that ends
+/// after the rule's `duration_days`, and logs the reason in the markdown
+/// table. A product with an active markdown at the store is skipped, so the
+/// use case is safe to run repeatedly (it is scheduled as a background job).
+/// Markdowns whose window has ended are marked expired first. Rules of
+/// stores whose settings turn auto markdowns off are not evaluated.
+pub struct ApplyAutoMarkdownsUseCase<M: MarkdownRepository, P: PromotionRepository> {
+    markdown_repo: Arc<M>,
+    promotion_repo: Arc<P>,
+}
+
+impl<M: MarkdownRepository, P: PromotionRepository> ApplyAutoMarkdownsUseCase<M, P> {
+    pub fn new(markdown_repo: Arc<M>, promotion_repo: Arc<P>) -> Self {
+        Self {
+            markdown_repo,
+            promotion_repo,
+        }
+    }
+
+    /// Runs the rules for one store, or for every store when `store_id` is None.
+    ///
+    /// `settings` holds the saved settings of the stores involved; stores
+    /// missing from it use the defaults.
+    pub async fn execute(
+        &self,
+        store_id: Option<Uuid>,
+        settings: &[StoreSettings],
+    ) -> Result<ApplyAutoMarkdownsResult, SalesError> {
+        let now = Utc::now();
+        let expired = self.markdown_repo.expire_ended(now).await?;
+
+        let rules: Vec<MarkdownRule> = self
+            .markdown_repo
+            .find_active_rules()
+            .await?
+            .into_iter()
+            .filter(|r| store_id.is_none_or(|sid| r.store_id() == sid))
+            .collect();
+
+        let enabled: HashMap<Uuid, bool> = settings
+            .iter()
+            .map(|s| (s.store_id().into_uuid(), s.auto_markdowns_enabled()))
+            .collect();
+        let (rules, disabled): (Vec<MarkdownRule>, Vec<MarkdownRule>) = rules
+            .into_iter()
+            .partition(|r| enabled.get(&r.store_id()).copied().unwrap_or(true));
+
+        let mut created = Vec::new();
+        let mut skipped = 0;
+
+        for rule in &rules {
+            let candidates = self.collect_candidates(rule).await?;
+
+            for (product_id, reason, reason_detail) in candidates {
+                if self
+                    .markdown_repo
+                    .has_active_for_product(rule.store_id(), product_id)
+                    .await?
+                {
+                    skipped += 1;
+                    continue;
+                }
+
+                let markdown_id = MarkdownId::new();
+                let mut promotion = Promotion::create(
+                    format!("MD-{}", markdown_id.into_uuid().simple()),
+                    format!("Markdown {}% ({})", rule.discount_percent(), reason),
+                    PromotionType::Percentage,
+                    rule.discount_percent(),
+                    now,
+                    rule.created_by_id(),
+                );
+                promotion.set_description(Some(reason_detail.clone()));
+                promotion.set_applies_to("product".to_string());
+                promotion.set_product_ids(vec![product_id]);
+                promotion.set_store_id(Some(rule.store_id()));
+
+                let markdown = Markdown::create(
+                    markdown_id,
+                    rule,
+                    product_id,
+                    promotion.id(),
+                    reason,
+                    reason_detail,
+                );
+                promotion.set_end_date(Some(markdown.ends_at()));
+
+                self.promotion_repo.save(&promotion).await?;
+                self.markdown_repo.save(&markdown).await?;
+
+                created.push(MarkdownResponse::from(&markdown));
+            }
+        }
+
+        Ok(ApplyAutoMarkdownsResult {
+            rules_evaluated: rules.len(),
+            expired,
+            skipped_already_marked_down: skipped,
+            skipped_store_disabled: disabled.len(),
+            created,
+        })
+    }
+
+    /// Products the rule matches, with reason. Near-expiry wins when a
+    /// product matches both triggers.
+    async fn collect_candidates(
+        &self,
+        rule: &MarkdownRule,
+    ) -> Result<Vec<(Uuid, MarkdownReason, String)>, SalesError> {
+        let mut seen = HashSet::new();
+        let mut candidates = Vec::new();
+
+        if let Some(window) = rule.expiry_window_days() {
+            let today = Utc::now().date_naive();
+            let until = today + Duration::days(window as i64);
+            let expiring = self
+                .markdown_repo
+                .find_expiring_products(rule.store_id(), rule.category_id(), today, until)
+                .await?;
+            for (product_id, expiry_date) in expiring {
+                if seen.insert(product_id) {
+                    candidates.push((
+                        product_id,
+                        MarkdownReason::NearExpiry,
+                        format!("Lot expires on {}", expiry_date),
+                    ));
+                }
+            }
+        }
+
+        if let Some(idle_days) = rule.idle_days() {
+            let idle_since = Utc::now() - Duration::days(idle_days as i64);
+            let idle = self
+                .markdown_repo
+                .find_idle_products(rule.store_id(), rule.category_id(), idle_since)
+                .await?;
+            for (product_id, last_sold_at) in idle {
+                if seen.insert(product_id) {
+                    let detail = match last_sold_at {
+                        Some(at) => format!(
+                            "No sales for {} days (last sold {})",
+                            idle_days,
+                            at.date_naive()
+                        ),
+                        None => format!("No sales for {} days (never sold)", idle_days),
+                    };
+                    candidates.push((product_id, MarkdownReason::IdleStock, detail));
+                }
+            }
+        }
+
+        Ok(candidates)
+    }
+}
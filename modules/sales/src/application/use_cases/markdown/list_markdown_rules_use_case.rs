@@ -0,0 +1,25 @@
+use std::sync::Arc;
+
+use uuid::Uuid;
+
+use crate::SalesError;
+use crate::application::dtos::markdown::MarkdownRuleResponse;
+use crate::domain::repositories::MarkdownRepository;
+
+pub struct ListMarkdownRulesUseCase<M: MarkdownRepository> {
+    markdown_repo: Arc<M>,
+}
+
+impl<M: MarkdownRepository> ListMarkdownRulesUseCase<M> {
+    pub fn new(markdown_repo: Arc<M>) -> Self {
+        Self { markdown_repo }
+    }
+
+    pub async fn execute(
+        &self,
+        store_id: Option<Uuid>,
+    ) -> Result<Vec<MarkdownRuleResponse>, SalesError> {
+        let rules = self.markdown_repo.list_rules(store_id).await?;
+        Ok(rules.iter().map(MarkdownRuleResponse::from).collect())
+    }
+}
@@ -0,0 +1,57 @@
+use std::sync::Arc;
+
+use common::PageCursor;
+use serde::Deserialize;
+
+use crate::SalesError;
+use crate::application::dtos::markdown::MarkdownResponse;
+use crate::domain::repositories::{MarkdownFilter, MarkdownRepository};
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct ListMarkdownsQuery {
+    pub store_id: Option<uuid::Uuid>,
+    pub product_id: Option<uuid::Uuid>,
+    pub status: Option<String>,
+    pub page: i64,
+    pub page_size: i64,
+    /// `next_cursor` of a previous page; when set, `page` is ignored
+    pub after_cursor: Option<String>,
+}
+
+pub struct ListMarkdownsUseCase<M: MarkdownRepository> {
+    markdown_repo: Arc<M>,
+}
+
+impl<M: MarkdownRepository> ListMarkdownsUseCase<M> {
+    pub fn new(markdown_repo: Arc<M>) -> Self {
+        Self { markdown_repo }
+    }
+
+    pub async fn execute(
+        &self,
+        query: ListMarkdownsQuery,
+    ) -> Result<(Vec<MarkdownResponse>, i64, Option<String>), SalesError> {
+        let after = PageCursor::decode_opt(query.after_cursor.as_deref())?;
+        let filter = MarkdownFilter {
+            store_id: query.store_id,
+            product_id: query.product_id,
+            status: query.status,
+            after_id: after.map(|c| c.id()),
+        };
+
+        let (markdowns, total) = self
+            .markdown_repo
+            .find_paginated(filter, query.page, query.page_size)
+            .await?;
+        let next_cursor = PageCursor::next(
+            markdowns.last().map(|m| m.id().into_uuid()),
+            markdowns.len(),
+            query.page_size,
+        );
+
+        let responses: Vec<MarkdownResponse> =
+            markdowns.iter().map(MarkdownResponse::from).collect();
+
+        Ok((responses, total, next_cursor))
+    }
+}
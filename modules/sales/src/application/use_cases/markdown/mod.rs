@@ -0,0 +1,11 @@
+mod apply_auto_markdowns_use_case;
+mod list_markdown_rules_use_case;
+mod list_markdowns_use_case;
+mod reverse_markdown_use_case;
+mod upsert_markdown_rule_use_case;
+
+pub use apply_auto_markdowns_use_case::ApplyAutoMarkdownsUseCase;
+pub use list_markdown_rules_use_case::ListMarkdownRulesUseCase;
+pub use list_markdowns_use_case::{ListMarkdownsQuery, ListMarkdownsUseCase};
+pub use reverse_markdown_use_case::ReverseMarkdownUseCase;
+pub use upsert_markdown_rule_use_case::UpsertMarkdownRuleUseCase;
@@ -0,0 +1,53 @@
+use std::sync::Arc;
+
+use uuid::Uuid;
+
+use crate::SalesError;
+use crate::application::dtos::markdown::{MarkdownResponse, ReverseMarkdownCommand};
+use crate::domain::repositories::{MarkdownRepository, PromotionRepository};
+use crate::domain::value_objects::MarkdownId;
+use identity::UserId;
+
+/// Undoes an active markdown: the log entry is marked reversed and the
+/// promotion carrying the discount is deactivated.
+pub struct ReverseMarkdownUseCase<M: MarkdownRepository, P: PromotionRepository> {
+    markdown_repo: Arc<M>,
+    promotion_repo: Arc<P>,
+}
+
+impl<M: MarkdownRepository, P: PromotionRepository> ReverseMarkdownUseCase<M, P> {
+    pub fn new(markdown_repo: Arc<M>, promotion_repo: Arc<P>) -> Self {
+        Self {
+            markdown_repo,
+            promotion_repo,
+        }
+    }
+
+    pub async fn execute(
+        &self,
+        id: Uuid,
+        command: ReverseMarkdownCommand,
+        actor_id: UserId,
+    ) -> Result<MarkdownResponse, SalesError> {
+        let mut markdown = self
+            .markdown_repo
+            .find_by_id(MarkdownId::from_uuid(id))
+            .await?
+            .ok_or(SalesError::MarkdownNotFound(id))?;
+
+        markdown.reverse(actor_id, command.reason)?;
+
+        if let Some(mut promotion) = self
+            .promotion_repo
+            .find_by_id(markdown.promotion_id())
+            .await?
+        {
+            promotion.deactivate();
+            self.promotion_repo.update(&promotion).await?;
+        }
+
+        self.markdown_repo.update(&markdown).await?;
+
+        Ok(MarkdownResponse::from(&markdown))
+    }
+}
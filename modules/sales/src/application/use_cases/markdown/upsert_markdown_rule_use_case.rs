@@ -0,0 +1,68 @@
+use std::sync::Arc;
+
+use crate::SalesError;
+use crate::application::dtos::markdown::{MarkdownRuleResponse, UpsertMarkdownRuleCommand};
+use crate::domain::entities::MarkdownRule;
+use crate::domain::repositories::MarkdownRepository;
+use identity::UserId;
+
+/// Creates the markdown rule for a store/category pair, or reconfigures the
+/// existing one. Setting `is_active = false` opts the category back out.
+pub struct UpsertMarkdownRuleUseCase<M: MarkdownRepository> {
+    markdown_repo: Arc<M>,
+}
+
+impl<M: MarkdownRepository> UpsertMarkdownRuleUseCase<M> {
+    pub fn new(markdown_repo: Arc<M>) -> Self {
+        Self { markdown_repo }
+    }
+
+    pub async fn execute(
+        &self,
+        command: UpsertMarkdownRuleCommand,
+        actor_id: UserId,
+    ) -> Result<MarkdownRuleResponse, SalesError> {
+        let existing = self
+            .markdown_repo
+            .find_rule(command.store_id, command.category_id)
+            .await?;
+
+        let rule = match existing {
+            Some(mut rule) => {
+                rule.reconfigure(
+                    command.expiry_window_days,
+                    command.idle_days,
+                    command.discount_percent,
+                    command.duration_days,
+                    command.is_active,
+                )?;
+                self.markdown_repo.update_rule(&rule).await?;
+                rule
+            }
+            None => {
+                let mut rule = MarkdownRule::create(
+                    command.store_id,
+                    command.category_id,
+                    command.expiry_window_days,
+                    command.idle_days,
+                    command.discount_percent,
+                    command.duration_days,
+                    actor_id,
+                )?;
+                if !command.is_active {
+                    rule.reconfigure(
+                        command.expiry_window_days,
+                        command.idle_days,
+                        command.discount_percent,
+                        command.duration_days,
+                        false,
+                    )?;
+                }
+                self.markdown_repo.save_rule(&rule).await?;
+                rule
+            }
+        };
+
+        Ok(MarkdownRuleResponse::from(&rule))
+    }
+}
@@ -2,21 +2,47 @@
 //!
 //! Organized by domain area:
 //! - customer: Customer management operations
+//! - exchange_rate: Store rates for foreign currency tenders
+//! - gift_card: Gift cards sold and redeemed at the POS
+//! - layaway: Sales paid off in scheduled installments
 //! - shift: Cashier shift operations
 //! - pos: Point of Sale operations
+//! - markdown: Automatic markdowns for aging/expiring stock
+//! - price_list: Price lists assigned to customer types
+//! - price_tier: Customer-type pricing for products and categories
+//! - tax_rule: Tax rates per product, category and store
+//! - weight_barcode: Layouts of scale-printed weight and price labels
 
 pub mod cart;
 pub mod credit_note;
 pub mod customer;
 pub mod ecommerce;
+pub mod exchange_rate;
+pub mod gift_card;
+pub mod layaway;
+pub mod markdown;
 pub mod pos;
+pub mod price_list;
+pub mod price_tier;
 pub mod promotion;
 pub mod shift;
+pub mod tax_rule;
+#[cfg(test)]
+mod test_support;
+pub mod weight_barcode;
 
 pub use cart::*;
 pub use credit_note::*;
 pub use customer::*;
 pub use ecommerce::*;
+pub use exchange_rate::*;
+pub use gift_card::*;
+pub use layaway::*;
+pub use markdown::*;
 pub use pos::*;
+pub use price_list::*;
+pub use price_tier::*;
 pub use promotion::*;
 pub use shift::*;
+pub use tax_rule::*;
+pub use weight_barcode::*;
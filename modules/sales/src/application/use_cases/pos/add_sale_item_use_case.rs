@@ -6,28 +6,100 @@ use rust_decimal::Decimal;
 
 use crate::SalesError;
 use crate::application::dtos::{AddSaleItemCommand, SaleDetailResponse};
+use crate::application::use_cases::price_list::resolve_list_price;
+use crate::application::use_cases::price_tier::{pricing_customer_type, resolve_tier_price};
 use crate::domain::entities::SaleItem;
-use crate::domain::repositories::SaleRepository;
+use crate::domain::repositories::{
+    CustomerRepository, PriceListRepository, PriceTierRepository, SaleRepository,
+};
 use crate::domain::value_objects::SaleId;
-use inventory::{ProductId, UnitOfMeasure, VariantId};
+use inventory::{
+    InventoryStockRepository, ProductId, ProductRepository, ProductSerialRepository,
+    RecipeRepository, ResolveRecipeConsumptionUseCase, UnitOfMeasure, VariantId,
+};
+
+use super::line_serials::{
+    available_line_serials, ensure_not_on_sale, inventory_error, normalize_serials,
+};
 
 /// Use case for adding an item to a sale
-pub struct AddSaleItemUseCase {
+///
+/// Lines without a manual price are priced from the store's price list for
+/// the sale customer's type (the default list for walk-ins), then the price
+/// tier for that type, falling back to the product's base price, and
+/// record the list or tier used.
+///
+/// Serial-tracked products need one serial per unit, each in stock at the
+/// sale's store and not already captured on the sale.
+///
+/// Products with an active recipe are made from their ingredients: the
+/// ingredients (or their substitutes) must be in stock at the sale's store
+/// for everything of that item on the sale, and are what completing the
+/// sale deducts.
+pub struct AddSaleItemUseCase<P, N, T, L, R, S>
+where
+    P: ProductRepository,
+    N: ProductSerialRepository,
+    T: PriceTierRepository,
+    L: PriceListRepository,
+    R: RecipeRepository,
+    S: InventoryStockRepository,
+{
     sale_repo: Arc<dyn SaleRepository>,
+    customer_repo: Arc<dyn CustomerRepository>,
+    product_repo: Arc<P>,
+    serial_repo: Arc<N>,
+    price_tier_repo: Arc<T>,
+    price_list_repo: Arc<L>,
+    recipe_repo: Arc<R>,
+    stock_repo: Arc<S>,
+    allow_negative_stock: bool,
 }
 
-impl AddSaleItemUseCase {
-    pub fn new(sale_repo: Arc<dyn SaleRepository>) -> Self {
-        Self { sale_repo }
+impl<P, N, T, L, R, S> AddSaleItemUseCase<P, N, T, L, R, S>
+where
+    P: ProductRepository,
+    N: ProductSerialRepository,
+    T: PriceTierRepository,
+    L: PriceListRepository,
+    R: RecipeRepository,
+    S: InventoryStockRepository,
+{
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        sale_repo: Arc<dyn SaleRepository>,
+        customer_repo: Arc<dyn CustomerRepository>,
+        product_repo: Arc<P>,
+        serial_repo: Arc<N>,
+        price_tier_repo: Arc<T>,
+        price_list_repo: Arc<L>,
+        recipe_repo: Arc<R>,
+        stock_repo: Arc<S>,
+    ) -> Self {
+        Self {
+            sale_repo,
+            customer_repo,
+            product_repo,
+            serial_repo,
+            price_tier_repo,
+            price_list_repo,
+            recipe_repo,
+            stock_repo,
+            allow_negative_stock: false,
+        }
+    }
+
+    /// Applies the store's negative stock policy to recipe ingredients
+    pub fn with_allow_negative_stock(mut self, allow: bool) -> Self {
+        self.allow_negative_stock = allow;
+        self
     }
 
-    #[allow(clippy::too_many_arguments)]
     pub async fn execute(
         &self,
         cmd: AddSaleItemCommand,
         sku: String,
         description: String,
-        unit_price: Decimal,
         unit_cost: Decimal,
         tax_rate: Decimal,
         unit_of_measure: UnitOfMeasure,
@@ -45,15 +117,67 @@ impl AddSaleItemUseCase {
             return Err(SalesError::SaleNotEditable);
         }
 
+        let product_id = ProductId::from_uuid(cmd.product_id);
+        let product = self
+            .product_repo
+            .find_by_id(product_id)
+            .await
+            .map_err(inventory_error)?
+            .ok_or(SalesError::ProductNotFound(cmd.product_id))?;
+
         let line_number = sale.item_count() as i32 + 1;
-        let final_price = cmd.unit_price.unwrap_or(unit_price);
+        let variant_id = cmd.variant_id.map(VariantId::from_uuid);
+
+        // Manual prices win; otherwise price from the customer's price list,
+        // their tier or the variant/product base price
+        let (final_price, price_list_id, price_tier_id) = match cmd.unit_price {
+            Some(price) => (price, None, None),
+            None => {
+                let variant_price = match variant_id {
+                    Some(variant_id) => self
+                        .product_repo
+                        .find_variant_by_id(variant_id)
+                        .await
+                        .map_err(inventory_error)?
+                        .and_then(|v| v.price()),
+                    None => None,
+                };
+                let base_price = variant_price.unwrap_or(product.base_price());
+                let customer_type =
+                    pricing_customer_type(self.customer_repo.as_ref(), sale.customer_id()).await?;
+                let list_price = resolve_list_price(
+                    self.price_list_repo.as_ref(),
+                    sale.store_id().into_uuid(),
+                    sale.customer_id().map(|_| customer_type),
+                    cmd.product_id,
+                    cmd.variant_id,
+                )
+                .await?;
+                match list_price {
+                    Some((price, list_id)) => (price, Some(list_id), None),
+                    None => match resolve_tier_price(
+                        self.price_tier_repo.as_ref(),
+                        sale.store_id().into_uuid(),
+                        customer_type,
+                        cmd.product_id,
+                        product.category_id().map(|c| c.into_uuid()),
+                        base_price,
+                    )
+                    .await?
+                    {
+                        Some((price, tier_id)) => (price, None, Some(tier_id)),
+                        None => (base_price, None, None),
+                    },
+                }
+            }
+        };
 
         // Create the sale item
-        let item = SaleItem::create(
+        let mut item = SaleItem::create(
             sale_id,
             line_number,
-            ProductId::from_uuid(cmd.product_id),
-            cmd.variant_id.map(VariantId::from_uuid),
+            product_id,
+            variant_id,
             sku,
             description,
             cmd.quantity,
@@ -62,6 +186,43 @@ impl AddSaleItemUseCase {
             unit_cost,
             tax_rate,
         )?;
+        item.set_price_tier_id(price_tier_id);
+        item.set_price_list_id(price_list_id);
+
+        // Capture serials for serial-tracked products
+        if product.is_serialized() {
+            if cmd.serial_numbers.is_empty() {
+                return Err(SalesError::SerialNumbersRequired(cmd.product_id));
+            }
+            let serial_numbers = normalize_serials(&cmd.serial_numbers)?;
+            item.set_serial_numbers(serial_numbers)?;
+            ensure_not_on_sale(&sale, &item, item.serial_numbers())?;
+            available_line_serials(self.serial_repo.as_ref(), sale.store_id(), &item).await?;
+        } else if !cmd.serial_numbers.is_empty() {
+            return Err(SalesError::ProductNotSerialized(cmd.product_id));
+        }
+
+        // Composite products need their ingredients for every unit on the sale
+        let on_sale: Decimal = sale
+            .items()
+            .iter()
+            .filter(|i| i.product_id() == product_id && i.variant_id() == variant_id)
+            .map(|i| i.quantity())
+            .sum();
+        ResolveRecipeConsumptionUseCase::new(
+            self.recipe_repo.clone(),
+            self.stock_repo.clone(),
+            self.product_repo.clone(),
+        )
+        .with_allow_negative_stock(self.allow_negative_stock)
+        .execute(
+            sale.store_id(),
+            product_id,
+            variant_id,
+            on_sale + cmd.quantity,
+        )
+        .await
+        .map_err(inventory_error)?;
 
         // Add item to sale and recalculate totals
         sale.add_item(item.clone())?;
@@ -1,27 +1,140 @@
 //! Complete sale use case
 
+use chrono::Utc;
 use std::sync::Arc;
 use uuid::Uuid;
 
 use crate::SalesError;
 use crate::application::dtos::SaleDetailResponse;
-use crate::domain::repositories::SaleRepository;
-use crate::domain::value_objects::SaleId;
+use crate::application::use_cases::tax_rule::CalculateTaxUseCase;
+use crate::domain::repositories::{
+    CustomerRepository, ExchangeRateRepository, GiftCardRepository, LayawayRepository,
+    SaleIdempotencyRepository, SaleRepository, TaxRuleRepository,
+};
+use crate::domain::value_objects::{GiftCardStatus, IdempotentOperation, SaleId};
+use inventory::application::helpers::ensure_period_open;
+use inventory::{
+    CategoryRepository, InventoryPeriodRepository, ProductRepository, ProductSerialRepository,
+    SerialStatus,
+};
+
+use super::idempotency::{idempotency_key_for, is_idempotent_replay};
+use super::line_serials::{available_line_serials, inventory_error};
 
 /// Use case for completing a POS sale
-pub struct CompleteSaleUseCase {
+///
+/// Every line of a serial-tracked product must carry one valid, available
+/// serial per unit; those units are then marked sold against the line.
+/// A sale quoted in a foreign currency captures the store's current rate,
+/// which its receipt keeps from then on.
+/// A retry carrying an idempotency key the terminal already completed the
+/// sale with returns the sale as it stands.
+/// With a period lock, sales dated (opened) in a closed inventory period of
+/// their store are rejected.
+/// With gift cards, cards sold on the sale become usable once it completes.
+/// With layaways, a paid-off layaway is closed along with its sale.
+/// With tax rules, the lines of a sale still open are taxed under the
+/// store's rules first; a sale whose total rises past what was paid is
+/// then rejected as unpaid.
+/// With CAI numbering, the sale's invoice number is drawn from the active
+/// CAI range of its terminal instead of the one given, in the same
+/// transaction that completes the sale.
+pub struct CompleteSaleUseCase<P, N>
+where
+    P: ProductRepository,
+    N: ProductSerialRepository,
+{
     sale_repo: Arc<dyn SaleRepository>,
+    idempotency_repo: Arc<dyn SaleIdempotencyRepository>,
+    exchange_rate_repo: Arc<dyn ExchangeRateRepository>,
+    product_repo: Arc<P>,
+    serial_repo: Arc<N>,
+    period_repo: Option<Arc<dyn InventoryPeriodRepository>>,
+    can_post_to_closed_period: bool,
+    gift_card_repo: Option<Arc<dyn GiftCardRepository>>,
+    layaway_repo: Option<Arc<dyn LayawayRepository>>,
+    tax_calculator: Option<CalculateTaxUseCase<P>>,
+    cai_numbering: bool,
 }
 
-impl CompleteSaleUseCase {
-    pub fn new(sale_repo: Arc<dyn SaleRepository>) -> Self {
-        Self { sale_repo }
+impl<P, N> CompleteSaleUseCase<P, N>
+where
+    P: ProductRepository,
+    N: ProductSerialRepository,
+{
+    pub fn new(
+        sale_repo: Arc<dyn SaleRepository>,
+        idempotency_repo: Arc<dyn SaleIdempotencyRepository>,
+        exchange_rate_repo: Arc<dyn ExchangeRateRepository>,
+        product_repo: Arc<P>,
+        serial_repo: Arc<N>,
+    ) -> Self {
+        Self {
+            sale_repo,
+            idempotency_repo,
+            exchange_rate_repo,
+            product_repo,
+            serial_repo,
+            period_repo: None,
+            can_post_to_closed_period: false,
+            gift_card_repo: None,
+            layaway_repo: None,
+            tax_calculator: None,
+            cai_numbering: false,
+        }
+    }
+
+    /// Rejects sales dated in a closed period, unless `can_override` is set
+    pub fn with_period_lock(
+        mut self,
+        period_repo: Arc<dyn InventoryPeriodRepository>,
+        can_override: bool,
+    ) -> Self {
+        self.period_repo = Some(period_repo);
+        self.can_post_to_closed_period = can_override;
+        self
+    }
+
+    /// Activates the gift cards sold on the sale
+    pub fn with_gift_cards(mut self, gift_card_repo: Arc<dyn GiftCardRepository>) -> Self {
+        self.gift_card_repo = Some(gift_card_repo);
+        self
+    }
+
+    /// Closes the sale's layaway
+    pub fn with_layaways(mut self, layaway_repo: Arc<dyn LayawayRepository>) -> Self {
+        self.layaway_repo = Some(layaway_repo);
+        self
+    }
+
+    /// Taxes the sale's lines under the store's tax rules
+    pub fn with_tax_rules(
+        mut self,
+        customer_repo: Arc<dyn CustomerRepository>,
+        tax_rule_repo: Arc<dyn TaxRuleRepository>,
+        category_repo: Arc<dyn CategoryRepository>,
+    ) -> Self {
+        self.tax_calculator = Some(CalculateTaxUseCase::new(
+            self.sale_repo.clone(),
+            customer_repo,
+            tax_rule_repo,
+            self.product_repo.clone(),
+            category_repo,
+        ));
+        self
+    }
+
+    /// Numbers the sale from its terminal's CAI range
+    pub fn with_cai_numbering(mut self) -> Self {
+        self.cai_numbering = true;
+        self
     }
 
     pub async fn execute(
         &self,
         sale_id: Uuid,
         invoice_number: String,
+        idempotency_key: Option<String>,
     ) -> Result<SaleDetailResponse, SalesError> {
         let sale_id_vo = SaleId::from_uuid(sale_id);
 
@@ -31,12 +144,234 @@ impl CompleteSaleUseCase {
             .await?
             .ok_or(SalesError::SaleNotFound(sale_id))?;
 
+        // Idempotent replay: the terminal already completed the sale
+        let idempotency = idempotency_key
+            .map(|key| idempotency_key_for(&sale, &key, IdempotentOperation::Complete))
+            .transpose()?;
+        if let Some(record) = &idempotency
+            && is_idempotent_replay(self.idempotency_repo.as_ref(), record).await?
+        {
+            return Ok(SaleDetailResponse::from(sale));
+        }
+
+        // The sale is dated when it was rung up, not when it is completed
+        if let Some(period_repo) = &self.period_repo {
+            ensure_period_open(
+                period_repo.as_ref(),
+                sale.store_id(),
+                sale.created_at().date_naive(),
+                self.can_post_to_closed_period,
+            )
+            .await
+            .map_err(inventory_error)?;
+        }
+
+        // Check the serials of serial-tracked lines before completing
+        let mut units = Vec::new();
+        for item in sale.items() {
+            let product = self
+                .product_repo
+                .find_by_id(item.product_id())
+                .await
+                .map_err(inventory_error)?;
+            if !product.is_some_and(|p| p.is_serialized()) {
+                continue;
+            }
+            if !item.has_complete_serials() {
+                return Err(SalesError::SerialNumbersRequired(
+                    item.product_id().into_uuid(),
+                ));
+            }
+            for unit in
+                available_line_serials(self.serial_repo.as_ref(), sale.store_id(), item).await?
+            {
+                units.push((unit, item.id().into_uuid()));
+            }
+        }
+
+        // Capture the rate the sale is completed at
+        if sale.is_foreign_currency() {
+            let currency = sale.transaction_currency().clone();
+            let rate = self
+                .exchange_rate_repo
+                .find_effective(sale.store_id().into_uuid(), &currency, Utc::now())
+                .await?
+                .ok_or_else(|| SalesError::ExchangeRateNotFound(currency.to_string()))?;
+            sale.set_transaction_currency(currency, rate.rate())?;
+        }
+
+        // A layaway sale's totals were settled when the layaway started
+        if let Some(tax_calculator) = &self.tax_calculator
+            && sale.is_editable()
+        {
+            tax_calculator.apply(&mut sale).await?;
+        }
+
         // Complete the sale (validates status and payment)
         sale.complete(invoice_number)?;
 
-        // Update sale
-        self.sale_repo.update(&sale).await?;
+        // Update sale, drawing its fiscal number along the way
+        if self.cai_numbering {
+            self.sale_repo
+                .update_with_cai_invoice_number(&mut sale)
+                .await?;
+        } else {
+            self.sale_repo.update(&sale).await?;
+        }
+
+        // Mark the serialized units as sold only once the sale is saved, so
+        // a sale that fails to complete (no CAI number left) keeps them
+        // available
+        for (mut unit, sale_item_id) in units {
+            unit.sell(sale_id, sale_item_id).map_err(inventory_error)?;
+            self.serial_repo
+                .update(&unit, SerialStatus::Available)
+                .await
+                .map_err(inventory_error)?;
+        }
+
+        // Activate the cards whose lines are still on the sale
+        if let Some(gift_card_repo) = &self.gift_card_repo {
+            for mut card in gift_card_repo.find_by_sale(sale.id()).await? {
+                let on_sale = card
+                    .sale_item_id()
+                    .is_some_and(|item_id| sale.items().iter().any(|i| i.id() == item_id));
+                if card.status() == GiftCardStatus::Pending && on_sale {
+                    card.activate()?;
+                    gift_card_repo.update(&card).await?;
+                }
+            }
+        }
+
+        if let Some(layaway_repo) = &self.layaway_repo
+            && let Some(mut layaway) = layaway_repo.find_by_sale(sale.id()).await?
+            && layaway.status().is_open()
+        {
+            layaway.complete()?;
+            layaway_repo.update(&layaway).await?;
+        }
+
+        if let Some(record) = &idempotency {
+            self.idempotency_repo.save(record).await?;
+        }
 
         Ok(SaleDetailResponse::from(sale))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::use_cases::test_support::{
+        CaiRanges, MockExchangeRateRepository, MockIdempotencyRepository, MockProductRepository,
+        MockSaleRepository, MockSerialRepository,
+    };
+    use crate::domain::entities::{Payment, Sale, SaleItem};
+    use crate::domain::value_objects::ShiftId;
+    use identity::{StoreId, UserId};
+    use inventory::{Currency, Product, ProductSerial, UnitOfMeasure};
+    use pos_core::TerminalId;
+    use rust_decimal_macros::dec;
+
+    struct Fixture {
+        sale_id: Uuid,
+        sale_repo: Arc<MockSaleRepository>,
+        product_repo: Arc<MockProductRepository>,
+        serial_repo: Arc<MockSerialRepository>,
+    }
+
+    /// A paid sale of one serial-tracked phone, ready to complete
+    fn paid_serialized_sale(cai: CaiRanges) -> Fixture {
+        let store_id = StoreId::new();
+        let mut phone = Product::create("Phone".to_string(), UnitOfMeasure::Unit, None);
+        phone.set_serialized(true);
+        let product_repo = Arc::new(MockProductRepository::new());
+        product_repo.add_product(phone.clone());
+        let serial = ProductSerial::create(phone.id(), None, store_id, "SN-001").unwrap();
+        let serial_repo = Arc::new(MockSerialRepository::new(vec![serial]));
+
+        let mut sale = Sale::create_pos(
+            "S-000001".to_string(),
+            store_id,
+            TerminalId::new(),
+            ShiftId::new(),
+            UserId::new(),
+            Currency::default(),
+        );
+        let mut item = SaleItem::create(
+            sale.id(),
+            1,
+            phone.id(),
+            None,
+            "PHONE".to_string(),
+            "Phone".to_string(),
+            dec!(1),
+            UnitOfMeasure::Unit,
+            dec!(500),
+            dec!(300),
+            dec!(0),
+        )
+        .unwrap();
+        item.set_serial_numbers(vec!["SN-001".to_string()]).unwrap();
+        sale.add_item(item).unwrap();
+        let payment =
+            Payment::create_cash(sale.id(), dec!(500), sale.currency().clone(), dec!(500)).unwrap();
+        sale.add_payment(payment).unwrap();
+
+        let sale_repo = Arc::new(MockSaleRepository::with_cai(cai));
+        let sale_id = sale.id().into_uuid();
+        sale_repo.add_sale(sale);
+        Fixture {
+            sale_id,
+            sale_repo,
+            product_repo,
+            serial_repo,
+        }
+    }
+
+    fn use_case(
+        fixture: &Fixture,
+    ) -> CompleteSaleUseCase<MockProductRepository, MockSerialRepository> {
+        CompleteSaleUseCase::new(
+            fixture.sale_repo.clone(),
+            Arc::new(MockIdempotencyRepository::default()),
+            Arc::new(MockExchangeRateRepository),
+            fixture.product_repo.clone(),
+            fixture.serial_repo.clone(),
+        )
+        .with_cai_numbering()
+    }
+
+    #[tokio::test]
+    async fn test_complete_sale_marks_serials_sold() {
+        let fixture = paid_serialized_sale(CaiRanges::active("CAI-TEST", 1, 10));
+
+        let response = use_case(&fixture)
+            .execute(fixture.sale_id, "S-000001".to_string(), None)
+            .await
+            .unwrap();
+
+        assert_eq!(response.invoice_number.as_deref(), Some("00000001"));
+        assert_eq!(
+            fixture.serial_repo.status_of("SN-001"),
+            Some(SerialStatus::Sold)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_exhausted_cai_range_keeps_serials_available() {
+        let fixture = paid_serialized_sale(CaiRanges::active("CAI-TEST", 11, 10));
+
+        let result = use_case(&fixture)
+            .execute(fixture.sale_id, "S-000001".to_string(), None)
+            .await;
+
+        assert!(matches!(result, Err(SalesError::CaiRangeExhausted(_))));
+        assert_eq!(
+            fixture.serial_repo.status_of("SN-001"),
+            Some(SerialStatus::Available)
+        );
+        let sale = fixture.sale_repo.get(SaleId::from_uuid(fixture.sale_id));
+        assert!(sale.unwrap().cai_number().is_none());
+    }
+}
@@ -4,24 +4,33 @@ use std::sync::Arc;
 
 use crate::SalesError;
 use crate::application::dtos::{CreatePosSaleCommand, SaleDetailResponse};
-use crate::domain::entities::Sale;
-use crate::domain::repositories::{SaleRepository, ShiftRepository};
+use crate::domain::entities::{Layaway, Sale};
+use crate::domain::repositories::{LayawayRepository, SaleRepository, ShiftRepository};
 use crate::domain::value_objects::ShiftId;
 use identity::{StoreId, UserId};
 use inventory::Currency;
 use pos_core::TerminalId;
 
 /// Use case for creating a new POS sale
+///
+/// A sale created with layaway terms is rung up as usual; its deposit then
+/// puts it on layaway instead of paying for it outright.
 pub struct CreatePosSaleUseCase {
     sale_repo: Arc<dyn SaleRepository>,
     shift_repo: Arc<dyn ShiftRepository>,
+    layaway_repo: Arc<dyn LayawayRepository>,
 }
 
 impl CreatePosSaleUseCase {
-    pub fn new(sale_repo: Arc<dyn SaleRepository>, shift_repo: Arc<dyn ShiftRepository>) -> Self {
+    pub fn new(
+        sale_repo: Arc<dyn SaleRepository>,
+        shift_repo: Arc<dyn ShiftRepository>,
+        layaway_repo: Arc<dyn LayawayRepository>,
+    ) -> Self {
         Self {
             sale_repo,
             shift_repo,
+            layaway_repo,
         }
     }
 
@@ -63,8 +72,25 @@ impl CreatePosSaleUseCase {
             Currency::default(),
         );
 
+        // Check the layaway terms before anything is saved
+        let layaway = cmd
+            .layaway
+            .map(|terms| {
+                Layaway::create(
+                    sale.id(),
+                    store_id,
+                    terms.installments,
+                    terms.interval_days,
+                    cashier_id,
+                )
+            })
+            .transpose()?;
+
         // Save the sale
         self.sale_repo.save(&sale).await?;
+        if let Some(layaway) = &layaway {
+            self.layaway_repo.save(layaway).await?;
+        }
 
         Ok(SaleDetailResponse::from(sale))
     }
@@ -0,0 +1,234 @@
+//! Find product by barcode use case - resolves a POS scan to a sellable item
+
+use std::sync::Arc;
+
+use rust_decimal::Decimal;
+use uuid::Uuid;
+
+use crate::SalesError;
+use crate::application::dtos::{BarcodeLookupResponse, ScaleLabelResponse};
+use crate::domain::entities::ScaleLabel;
+use crate::domain::repositories::WeightBarcodeFormatRepository;
+use crate::domain::value_objects::ScaleValueKind;
+use inventory::{Barcode, InventoryStockRepository, Product, ProductRepository, ProductVariant};
+use pos_core::{CoreError, TerminalId, TerminalRepository};
+
+use super::line_serials::inventory_error;
+
+/// Use case for resolving a scanned barcode to a product or to one of its
+/// variants, with the price and the stock available at the terminal's store.
+///
+/// Variant barcodes win over product barcodes. A barcode carried by
+/// different products, or the barcode of a product whose variants each
+/// carry their own, is ambiguous: the candidates' SKUs are returned in the
+/// error so the cashier can pick one.
+///
+/// Labels printed by the store's scales are recognised by the store's
+/// weight barcode formats: the product is found by the label's item barcode
+/// and the embedded weight or price gives the line's quantity and total.
+pub struct FindProductByBarcodeUseCase<P, S, R, W>
+where
+    P: ProductRepository,
+    S: InventoryStockRepository,
+    R: TerminalRepository,
+    W: WeightBarcodeFormatRepository,
+{
+    product_repo: Arc<P>,
+    stock_repo: Arc<S>,
+    terminal_repo: Arc<R>,
+    format_repo: Arc<W>,
+}
+
+impl<P, S, R, W> FindProductByBarcodeUseCase<P, S, R, W>
+where
+    P: ProductRepository,
+    S: InventoryStockRepository,
+    R: TerminalRepository,
+    W: WeightBarcodeFormatRepository,
+{
+    pub fn new(
+        product_repo: Arc<P>,
+        stock_repo: Arc<S>,
+        terminal_repo: Arc<R>,
+        format_repo: Arc<W>,
+    ) -> Self {
+        Self {
+            product_repo,
+            stock_repo,
+            terminal_repo,
+            format_repo,
+        }
+    }
+
+    pub async fn execute(
+        &self,
+        raw_barcode: &str,
+        terminal_id: Uuid,
+    ) -> Result<BarcodeLookupResponse, SalesError> {
+        let terminal = self
+            .terminal_repo
+            .find_by_id(TerminalId::from_uuid(terminal_id))
+            .await
+            .map_err(|e| match e {
+                CoreError::Database(e) => SalesError::Database(e),
+                _ => SalesError::TerminalNotFound(terminal_id),
+            })?
+            .ok_or(SalesError::TerminalNotFound(terminal_id))?;
+        if !terminal.is_active() {
+            return Err(SalesError::TerminalNotActive(terminal_id));
+        }
+        let store_id = terminal.store_id();
+
+        let label = self
+            .format_repo
+            .find_by_store(store_id.into_uuid())
+            .await?
+            .iter()
+            .find_map(|f| f.parse(raw_barcode).map(|label| (f.id(), label)));
+        let lookup_code = match &label {
+            Some((_, label)) => label.item_barcode.as_str(),
+            None => raw_barcode,
+        };
+
+        let barcode = Barcode::new(lookup_code)
+            .map_err(|_| SalesError::BarcodeNotFound(raw_barcode.to_string()))?;
+        let (product, variant) = self.resolve(&barcode).await?;
+
+        let stock = match &variant {
+            Some(v) => {
+                self.stock_repo
+                    .find_by_store_and_variant(store_id, v.id())
+                    .await
+            }
+            None => {
+                self.stock_repo
+                    .find_by_store_and_product(store_id, product.id())
+                    .await
+            }
+        }
+        .map_err(inventory_error)?;
+
+        let (sku, description, unit_price) = match &variant {
+            Some(v) => (
+                v.sku().to_string(),
+                format!("{} ({})", product.name(), v.name()),
+                v.price().unwrap_or(product.base_price()),
+            ),
+            None => (
+                product.sku().to_string(),
+                product.name().to_string(),
+                product.base_price(),
+            ),
+        };
+
+        let scale = label
+            .map(|(format_id, label)| {
+                scale_line(format_id, label, unit_price, product.id().into_uuid())
+            })
+            .transpose()?;
+
+        Ok(BarcodeLookupResponse {
+            barcode: raw_barcode.to_string(),
+            product_id: product.id().into_uuid(),
+            variant_id: variant.as_ref().map(|v| v.id().into_uuid()),
+            sku,
+            description,
+            unit_of_measure: product.unit_of_measure().to_string(),
+            unit_price,
+            currency: product.currency().as_str().to_string(),
+            tax_rate: product.tax_rate(),
+            tax_included: product.tax_included(),
+            is_serialized: product.is_serialized(),
+            store_id: store_id.into_uuid(),
+            available_quantity: stock.map(|s| s.available_quantity()),
+            scale,
+        })
+    }
+
+    /// Finds the active product, and variant if any, the barcode names
+    async fn resolve(
+        &self,
+        barcode: &Barcode,
+    ) -> Result<(Product, Option<ProductVariant>), SalesError> {
+        let not_found = || SalesError::BarcodeNotFound(barcode.as_str().to_string());
+
+        let variant = self
+            .product_repo
+            .find_variant_by_barcode(barcode)
+            .await
+            .map_err(inventory_error)?
+            .filter(|v| v.is_active());
+        let product = self
+            .product_repo
+            .find_by_barcode(barcode)
+            .await
+            .map_err(inventory_error)?
+            .filter(|p| p.is_active());
+
+        match (product, variant) {
+            (Some(product), Some(variant)) if variant.product_id() != product.id() => {
+                Err(SalesError::AmbiguousBarcode {
+                    barcode: barcode.as_str().to_string(),
+                    candidates: vec![product.sku().to_string(), variant.sku().to_string()],
+                })
+            }
+            (_, Some(variant)) => {
+                let product = self
+                    .product_repo
+                    .find_by_id(variant.product_id())
+                    .await
+                    .map_err(inventory_error)?
+                    .filter(|p| p.is_active())
+                    .ok_or_else(not_found)?;
+                Ok((product, Some(variant)))
+            }
+            (Some(product), None) if product.has_variants() => {
+                let mut variants: Vec<ProductVariant> = self
+                    .product_repo
+                    .find_variants_by_product(product.id())
+                    .await
+                    .map_err(inventory_error)?
+                    .into_iter()
+                    .filter(|v| v.is_active())
+                    .collect();
+                match variants.len() {
+                    0 => Ok((product, None)),
+                    1 => Ok((product, variants.pop())),
+                    _ => Err(SalesError::AmbiguousBarcode {
+                        barcode: barcode.as_str().to_string(),
+                        candidates: variants.iter().map(|v| v.sku().to_string()).collect(),
+                    }),
+                }
+            }
+            (Some(product), None) => Ok((product, None)),
+            (None, None) => Err(not_found()),
+        }
+    }
+}
+
+/// Quantity and total of a weighed item: a weight label is priced at the
+/// unit price, and a price label's quantity is the price over the unit price
+fn scale_line(
+    format_id: Uuid,
+    label: ScaleLabel,
+    unit_price: Decimal,
+    product_id: Uuid,
+) -> Result<ScaleLabelResponse, SalesError> {
+    let (quantity, total) = match label.kind {
+        ScaleValueKind::Weight => (label.value, (label.value * unit_price).round_dp(2)),
+        ScaleValueKind::Price => {
+            if unit_price <= Decimal::ZERO {
+                return Err(SalesError::ScaleLabelUnpriced(product_id));
+            }
+            ((label.value / unit_price).round_dp(3), label.value)
+        }
+    };
+
+    Ok(ScaleLabelResponse {
+        format_id,
+        value_kind: label.kind.to_string(),
+        embedded_value: label.value,
+        quantity,
+        total,
+    })
+}
@@ -7,8 +7,12 @@ use crate::SalesError;
 use crate::application::dtos::SaleDetailResponse;
 use crate::domain::repositories::SaleRepository;
 use crate::domain::value_objects::SaleId;
+use identity::UserContext;
 
 /// Use case for retrieving a sale by ID
+///
+/// Sales of stores the caller is not assigned to are scoped out: they are
+/// reported as not found rather than revealing that they exist.
 pub struct GetSaleUseCase {
     sale_repo: Arc<dyn SaleRepository>,
 }
@@ -18,15 +22,104 @@ impl GetSaleUseCase {
         Self { sale_repo }
     }
 
-    pub async fn execute(&self, sale_id: Uuid) -> Result<SaleDetailResponse, SalesError> {
+    pub async fn execute(
+        &self,
+        sale_id: Uuid,
+        ctx: &UserContext,
+    ) -> Result<SaleDetailResponse, SalesError> {
         let id = SaleId::from_uuid(sale_id);
 
         let sale = self
             .sale_repo
             .find_by_id_with_details(id)
             .await?
+            .filter(|sale| ctx.can_access_store(sale.store_id().into_uuid()))
             .ok_or(SalesError::SaleNotFound(sale_id))?;
 
         Ok(SaleDetailResponse::from(sale))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::use_cases::test_support::MockSaleRepository;
+    use crate::domain::entities::Sale;
+    use crate::domain::value_objects::ShiftId;
+    use identity::{StoreId, UserId};
+    use inventory::Currency;
+    use pos_core::TerminalId;
+
+    /// A use case holding one sale, with the sale's id and store
+    fn setup() -> (GetSaleUseCase, Uuid, Uuid) {
+        let store_id = StoreId::new();
+        let sale = Sale::create_pos(
+            "S-000001".to_string(),
+            store_id,
+            TerminalId::new(),
+            ShiftId::new(),
+            UserId::new(),
+            Currency::default(),
+        );
+        let id = sale.id().into_uuid();
+        let repo = MockSaleRepository::new();
+        repo.add_sale(sale);
+        (
+            GetSaleUseCase::new(Arc::new(repo)),
+            id,
+            store_id.into_uuid(),
+        )
+    }
+
+    fn context(stores: Vec<Uuid>, is_super_admin: bool) -> UserContext {
+        UserContext::new(
+            UserId::new(),
+            StoreId::new(),
+            Default::default(),
+            stores,
+            is_super_admin,
+            None,
+            None,
+        )
+    }
+
+    #[tokio::test]
+    async fn test_assigned_user_gets_sale() {
+        let (use_case, id, store_id) = setup();
+
+        let response = use_case
+            .execute(id, &context(vec![store_id], false))
+            .await
+            .unwrap();
+        assert_eq!(response.id, id);
+    }
+
+    #[tokio::test]
+    async fn test_sale_of_another_store_is_not_found() {
+        let (use_case, id, _) = setup();
+
+        let result = use_case
+            .execute(id, &context(vec![StoreId::new().into_uuid()], false))
+            .await;
+        assert!(matches!(
+            result,
+            Err(SalesError::SaleNotFound(not_found)) if not_found == id
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_user_without_stores_gets_not_found() {
+        let (use_case, id, _) = setup();
+
+        let result = use_case.execute(id, &context(vec![], false)).await;
+        assert!(matches!(result, Err(SalesError::SaleNotFound(_))));
+    }
+
+    #[tokio::test]
+    async fn test_super_admin_gets_sale_of_any_store() {
+        let (use_case, id, _) = setup();
+
+        let response = use_case.execute(id, &context(vec![], true)).await.unwrap();
+        assert_eq!(response.id, id);
+    }
+}
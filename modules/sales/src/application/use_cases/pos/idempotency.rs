@@ -0,0 +1,114 @@
+//! Terminal-scoped idempotency keys for retried sale requests
+
+use crate::SalesError;
+use crate::domain::entities::{Sale, SaleIdempotencyKey};
+use crate::domain::repositories::SaleIdempotencyRepository;
+use crate::domain::value_objects::{IdempotentOperation, SaleId};
+use pos_core::TerminalId;
+
+/// Builds the key record for a request on the sale, scoped to the sale's
+/// terminal
+pub fn idempotency_key_for(
+    sale: &Sale,
+    key: &str,
+    operation: IdempotentOperation,
+) -> Result<SaleIdempotencyKey, SalesError> {
+    let terminal_id = sale
+        .terminal_id()
+        .ok_or(SalesError::IdempotencyKeyRequiresTerminal)?;
+    SaleIdempotencyKey::record(terminal_id, key, operation, sale.id())
+}
+
+/// Returns true when the terminal already sent the key for this request, in
+/// which case the sale should be returned as it stands instead of repeating
+/// the request. Fails if the key was used for another sale or operation.
+pub async fn is_idempotent_replay<R>(
+    repo: &R,
+    record: &SaleIdempotencyKey,
+) -> Result<bool, SalesError>
+where
+    R: SaleIdempotencyRepository + ?Sized,
+{
+    match repo.find_active(record.terminal_id(), record.key()).await? {
+        Some(existing) => {
+            existing.ensure_replay_of(record.sale_id(), record.operation())?;
+            Ok(true)
+        }
+        None => Ok(false),
+    }
+}
+
+/// Returns the sale an earlier request from the terminal created with the
+/// key, for requests that create their sale and so have no sale to check the
+/// key against. Fails if the key was used for another operation.
+pub async fn replayed_sale<R>(
+    repo: &R,
+    terminal_id: TerminalId,
+    key: &str,
+    operation: IdempotentOperation,
+) -> Result<Option<SaleId>, SalesError>
+where
+    R: SaleIdempotencyRepository + ?Sized,
+{
+    match repo.find_active(terminal_id, key.trim()).await? {
+        Some(existing) if existing.operation() == operation => Ok(Some(existing.sale_id())),
+        Some(existing) => Err(SalesError::IdempotencyKeyReused(existing.key().to_string())),
+        None => Ok(None),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::use_cases::test_support::MockIdempotencyRepository;
+    use chrono::{Duration, Utc};
+
+    #[tokio::test]
+    async fn test_same_key_on_another_terminal_is_not_a_replay() {
+        let repo = MockIdempotencyRepository::default();
+        let sale_id = SaleId::new();
+        let first = SaleIdempotencyKey::record(
+            TerminalId::new(),
+            "pay-1",
+            IdempotentOperation::Payment,
+            sale_id,
+        )
+        .unwrap();
+        repo.save(&first).await.unwrap();
+
+        let other_terminal = SaleIdempotencyKey::record(
+            TerminalId::new(),
+            "pay-1",
+            IdempotentOperation::Payment,
+            sale_id,
+        )
+        .unwrap();
+
+        assert!(is_idempotent_replay(&repo, &first).await.unwrap());
+        assert!(!is_idempotent_replay(&repo, &other_terminal).await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_key_older_than_ttl_is_not_a_replay() {
+        let repo = MockIdempotencyRepository::default();
+        let terminal_id = TerminalId::new();
+        let sale_id = SaleId::new();
+        let created_at = Utc::now() - Duration::hours(25);
+        repo.save(&SaleIdempotencyKey::reconstitute(
+            terminal_id,
+            "pay-1".to_string(),
+            IdempotentOperation::Payment,
+            sale_id,
+            created_at,
+            created_at + Duration::hours(24),
+        ))
+        .await
+        .unwrap();
+
+        let retry =
+            SaleIdempotencyKey::record(terminal_id, "pay-1", IdempotentOperation::Payment, sale_id)
+                .unwrap();
+
+        assert!(!is_idempotent_replay(&repo, &retry).await.unwrap());
+    }
+}
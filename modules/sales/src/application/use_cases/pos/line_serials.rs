@@ -0,0 +1,83 @@
+//! Validation of the serial numbers captured on serial-tracked sale lines
+
+use std::collections::HashSet;
+
+use crate::SalesError;
+use crate::domain::entities::{Sale, SaleItem};
+use identity::StoreId;
+use inventory::{InventoryError, ProductSerial, ProductSerialRepository};
+
+/// Maps inventory failures while checking serials onto sales errors
+pub(super) fn inventory_error(error: InventoryError) -> SalesError {
+    match error {
+        InventoryError::SerialNotAvailable(serial) => SalesError::SerialNotAvailable(serial),
+        InventoryError::InvalidSerialNumber => SalesError::InvalidSerialNumber,
+        InventoryError::ProductNotFound(id) => SalesError::ProductNotFound(id),
+        InventoryError::IngredientUnavailable(name) => SalesError::IngredientUnavailable(name),
+        InventoryError::PeriodClosed(start, end) => SalesError::PeriodClosed(start, end),
+        InventoryError::Database(e) => SalesError::Database(e),
+        other => SalesError::SerialNotAvailable(other.to_string()),
+    }
+}
+
+/// Normalizes the scanned serials, rejecting blanks and repeats
+pub(super) fn normalize_serials(serial_numbers: &[String]) -> Result<Vec<String>, SalesError> {
+    let mut seen = HashSet::new();
+    let mut normalized = Vec::with_capacity(serial_numbers.len());
+    for raw in serial_numbers {
+        let serial = ProductSerial::normalize(raw).map_err(inventory_error)?;
+        if !seen.insert(serial.clone()) {
+            return Err(SalesError::DuplicateSerialNumber(serial));
+        }
+        normalized.push(serial);
+    }
+    Ok(normalized)
+}
+
+/// Rejects serials already captured on another line of the sale
+pub(super) fn ensure_not_on_sale(
+    sale: &Sale,
+    item: &SaleItem,
+    serial_numbers: &[String],
+) -> Result<(), SalesError> {
+    for other in sale
+        .items()
+        .iter()
+        .filter(|i| i.id() != item.id() && i.product_id() == item.product_id())
+    {
+        if let Some(dup) = serial_numbers
+            .iter()
+            .find(|s| other.serial_numbers().contains(s))
+        {
+            return Err(SalesError::DuplicateSerialNumber(dup.clone()));
+        }
+    }
+    Ok(())
+}
+
+/// Loads the units captured on a line, checking each one is in stock at
+/// the sale's store for the line's product and variant
+pub(super) async fn available_line_serials<N>(
+    serial_repo: &N,
+    store_id: StoreId,
+    item: &SaleItem,
+) -> Result<Vec<ProductSerial>, SalesError>
+where
+    N: ProductSerialRepository,
+{
+    let mut units = Vec::with_capacity(item.serial_numbers().len());
+    for serial_number in item.serial_numbers() {
+        let unit = serial_repo
+            .find_by_product_and_number(item.product_id(), serial_number)
+            .await
+            .map_err(inventory_error)?
+            .filter(|u| {
+                u.store_id() == store_id
+                    && u.variant_id() == item.variant_id()
+                    && u.status().is_available()
+            })
+            .ok_or_else(|| SalesError::SerialNotAvailable(serial_number.clone()))?;
+        units.push(unit);
+    }
+    Ok(units)
+}
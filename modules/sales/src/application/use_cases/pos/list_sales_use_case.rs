@@ -3,14 +3,19 @@
 use std::str::FromStr;
 use std::sync::Arc;
 
+use common::PageCursor;
+
 use crate::SalesError;
 use crate::application::dtos::{ListSalesQuery, SaleListResponse, SaleResponse};
 use crate::domain::repositories::{SaleFilter, SaleRepository};
-use crate::domain::value_objects::{CustomerId, SaleStatus, SaleType, ShiftId};
-use identity::StoreId;
+use crate::domain::value_objects::{CustomerId, SaleId, SaleStatus, SaleType, ShiftId};
+use identity::{StoreId, StoreScope, UserContext};
 use pos_core::TerminalId;
 
 /// Use case for listing sales with filters and pagination
+///
+/// Sales are limited to the caller's assigned stores; asking for a store the
+/// caller is not assigned to is rejected.
 pub struct ListSalesUseCase {
     sale_repo: Arc<dyn SaleRepository>,
 }
@@ -20,12 +25,26 @@ impl ListSalesUseCase {
         Self { sale_repo }
     }
 
-    pub async fn execute(&self, query: ListSalesQuery) -> Result<SaleListResponse, SalesError> {
+    pub async fn execute(
+        &self,
+        query: ListSalesQuery,
+        ctx: &UserContext,
+    ) -> Result<SaleListResponse, SalesError> {
+        let store_ids = match ctx.store_scope(query.store_id) {
+            StoreScope::All => None,
+            StoreScope::Stores(ids) => Some(ids.into_iter().map(StoreId::from_uuid).collect()),
+            StoreScope::Denied(store_id) => return Err(SalesError::StoreAccessDenied(store_id)),
+        };
+
         let page = query.page.unwrap_or(1).max(1);
         let page_size = query.page_size.unwrap_or(20).clamp(1, 100);
+        let after = PageCursor::decode_opt(query.after_cursor.as_deref())?;
+        // A cursor page has no meaningful number
+        let page = if after.is_some() { 1 } else { page };
 
         let filter = SaleFilter {
             store_id: query.store_id.map(StoreId::from_uuid),
+            store_ids,
             terminal_id: query.terminal_id.map(TerminalId::from_uuid),
             customer_id: query.customer_id.map(CustomerId::from_uuid),
             shift_id: query.shift_id.map(ShiftId::from_uuid),
@@ -38,6 +57,7 @@ impl ListSalesUseCase {
                 .as_ref()
                 .and_then(|s| SaleStatus::from_str(s).ok()),
             search: query.search,
+            after_id: after.map(|c| SaleId::from_uuid(c.id())),
         };
 
         let (sales, total) = self
@@ -46,6 +66,11 @@ impl ListSalesUseCase {
             .await?;
 
         let total_pages = (total as f64 / page_size as f64).ceil() as i64;
+        let next_cursor = PageCursor::next(
+            sales.last().map(|s| s.id().into_uuid()),
+            sales.len(),
+            page_size,
+        );
 
         Ok(SaleListResponse {
             items: sales.iter().map(SaleResponse::from).collect(),
@@ -53,6 +78,109 @@ impl ListSalesUseCase {
             page,
             page_size,
             total_pages,
+            next_cursor,
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::use_cases::test_support::MockSaleRepository;
+    use crate::domain::entities::Sale;
+    use identity::UserId;
+    use inventory::Currency;
+    use uuid::Uuid;
+
+    fn sale(store_id: StoreId) -> Sale {
+        Sale::create_pos(
+            "S-000001".to_string(),
+            store_id,
+            TerminalId::new(),
+            ShiftId::new(),
+            UserId::new(),
+            Currency::default(),
+        )
+    }
+
+    /// One sale in each of two stores
+    fn setup() -> (ListSalesUseCase, Uuid, Uuid) {
+        let (store_a, store_b) = (StoreId::new(), StoreId::new());
+        let repo = MockSaleRepository::new();
+        repo.add_sale(sale(store_a));
+        repo.add_sale(sale(store_b));
+        let use_case = ListSalesUseCase::new(Arc::new(repo));
+        (use_case, store_a.into_uuid(), store_b.into_uuid())
+    }
+
+    fn context(stores: Vec<Uuid>, is_super_admin: bool) -> UserContext {
+        UserContext::new(
+            UserId::new(),
+            StoreId::new(),
+            Default::default(),
+            stores,
+            is_super_admin,
+            None,
+            None,
+        )
+    }
+
+    fn query(store_id: Option<Uuid>) -> ListSalesQuery {
+        ListSalesQuery {
+            store_id,
+            ..Default::default()
+        }
+    }
+
+    #[tokio::test]
+    async fn test_assigned_user_sees_only_their_store() {
+        let (use_case, store_a, _) = setup();
+        let ctx = context(vec![store_a], false);
+
+        let all = use_case.execute(query(None), &ctx).await.unwrap();
+        assert_eq!(all.items.len(), 1);
+        assert_eq!(all.items[0].store_id, store_a);
+
+        let requested = use_case.execute(query(Some(store_a)), &ctx).await.unwrap();
+        assert_eq!(requested.items.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_rejects_store_the_user_is_not_assigned_to() {
+        let (use_case, store_a, store_b) = setup();
+        let ctx = context(vec![store_b], false);
+
+        let result = use_case.execute(query(Some(store_a)), &ctx).await;
+        assert!(matches!(
+            result,
+            Err(SalesError::StoreAccessDenied(id)) if id == store_a
+        ));
+
+        let all = use_case.execute(query(None), &ctx).await.unwrap();
+        assert!(all.items.iter().all(|s| s.store_id == store_b));
+    }
+
+    #[tokio::test]
+    async fn test_user_without_stores_sees_nothing() {
+        let (use_case, store_a, _) = setup();
+        let ctx = context(vec![], false);
+
+        let all = use_case.execute(query(None), &ctx).await.unwrap();
+        assert!(all.items.is_empty());
+
+        let result = use_case.execute(query(Some(store_a)), &ctx).await;
+        assert!(matches!(result, Err(SalesError::StoreAccessDenied(_))));
+    }
+
+    #[tokio::test]
+    async fn test_super_admin_sees_every_store() {
+        let (use_case, store_a, _) = setup();
+        let ctx = context(vec![], true);
+
+        let all = use_case.execute(query(None), &ctx).await.unwrap();
+        assert_eq!(all.items.len(), 2);
+
+        let requested = use_case.execute(query(Some(store_a)), &ctx).await.unwrap();
+        assert_eq!(requested.items.len(), 1);
+    }
+}
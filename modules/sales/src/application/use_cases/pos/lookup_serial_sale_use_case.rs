@@ -0,0 +1,87 @@
+//! Lookup serial sale use case - finds the sale a serialized unit was sold on
+
+use std::sync::Arc;
+
+use crate::SalesError;
+use crate::application::dtos::SerialSaleResponse;
+use crate::domain::repositories::SaleRepository;
+use crate::domain::value_objects::SaleId;
+use inventory::{ProductSerial, ProductSerialRepository};
+
+use super::line_serials::inventory_error;
+
+/// Use case for warranty claims: resolves a serial number to the sale(s)
+/// of the units carrying it. Serials are unique per product, so the same
+/// number may match units of different products.
+pub struct LookupSerialSaleUseCase<N>
+where
+    N: ProductSerialRepository,
+{
+    sale_repo: Arc<dyn SaleRepository>,
+    serial_repo: Arc<N>,
+}
+
+impl<N> LookupSerialSaleUseCase<N>
+where
+    N: ProductSerialRepository,
+{
+    pub fn new(sale_repo: Arc<dyn SaleRepository>, serial_repo: Arc<N>) -> Self {
+        Self {
+            sale_repo,
+            serial_repo,
+        }
+    }
+
+    pub async fn execute(
+        &self,
+        serial_number: &str,
+    ) -> Result<Vec<SerialSaleResponse>, SalesError> {
+        let serial_number = ProductSerial::normalize(serial_number).map_err(inventory_error)?;
+
+        let units = self
+            .serial_repo
+            .find_by_serial_number(&serial_number)
+            .await
+            .map_err(inventory_error)?;
+
+        let mut results = Vec::new();
+        for unit in units {
+            let Some(sale_id) = unit.sale_id() else {
+                continue;
+            };
+            let Some(sale) = self
+                .sale_repo
+                .find_by_id_with_details(SaleId::from_uuid(sale_id))
+                .await?
+            else {
+                continue;
+            };
+            let item = sale
+                .items()
+                .iter()
+                .find(|i| Some(i.id().into_uuid()) == unit.sale_item_id());
+
+            results.push(SerialSaleResponse {
+                serial_number: unit.serial_number().to_string(),
+                serial_status: unit.status().to_string(),
+                product_id: unit.product_id().into_uuid(),
+                variant_id: unit.variant_id().map(|v| v.into_uuid()),
+                sale_id,
+                sale_item_id: unit.sale_item_id(),
+                sale_number: sale.sale_number().to_string(),
+                invoice_number: sale.invoice_number().map(String::from),
+                store_id: sale.store_id().into_uuid(),
+                customer_id: sale.customer_id().map(|c| c.into_uuid()),
+                sku: item.map(|i| i.sku().to_string()),
+                description: item.map(|i| i.description().to_string()),
+                sold_at: unit.sold_at(),
+                credit_note_id: unit.credit_note_id(),
+            });
+        }
+
+        if results.is_empty() {
+            return Err(SalesError::SerialSaleNotFound(serial_number));
+        }
+        Ok(results)
+    }
+}
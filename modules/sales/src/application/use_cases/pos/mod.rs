@@ -4,10 +4,20 @@ mod add_sale_item_use_case;
 mod apply_discount_use_case;
 mod complete_sale_use_case;
 mod create_pos_sale_use_case;
+mod find_product_by_barcode_use_case;
 mod get_sale_use_case;
+mod idempotency;
+mod line_serials;
 mod list_sales_use_case;
+mod lookup_serial_sale_use_case;
+mod park_sale_use_case;
 mod process_payment_use_case;
+mod quick_sale_use_case;
 mod remove_sale_item_use_case;
+mod resume_sale_use_case;
+mod sale_reservations;
+mod set_sale_currency_use_case;
+mod tenders;
 mod update_sale_item_use_case;
 mod void_sale_use_case;
 
@@ -15,9 +25,23 @@ pub use add_sale_item_use_case::AddSaleItemUseCase;
 pub use apply_discount_use_case::ApplyDiscountUseCase;
 pub use complete_sale_use_case::CompleteSaleUseCase;
 pub use create_pos_sale_use_case::CreatePosSaleUseCase;
+pub use find_product_by_barcode_use_case::FindProductByBarcodeUseCase;
 pub use get_sale_use_case::GetSaleUseCase;
+pub use idempotency::{idempotency_key_for, is_idempotent_replay};
 pub use list_sales_use_case::ListSalesUseCase;
+pub use lookup_serial_sale_use_case::LookupSerialSaleUseCase;
+pub use park_sale_use_case::ParkSaleUseCase;
 pub use process_payment_use_case::ProcessPaymentUseCase;
+pub use quick_sale_use_case::QuickSaleUseCase;
 pub use remove_sale_item_use_case::RemoveSaleItemUseCase;
+pub use resume_sale_use_case::{RESUMED_SALE_HOLD_MINUTES, ResumeSaleUseCase};
+pub use sale_reservations::{
+    SALE_RESERVATION_REFERENCE, release_sale_reservations, reserve_sale_stock,
+};
+pub use set_sale_currency_use_case::SetSaleCurrencyUseCase;
+pub use tenders::{
+    GiftCardRedemption, TenderRates, gift_card_tenders, tender_payments, tender_rates,
+};
 pub use update_sale_item_use_case::UpdateSaleItemUseCase;
 pub use void_sale_use_case::VoidSaleUseCase;
+pub(crate) use void_sale_use_case::reverse_customer_charges;
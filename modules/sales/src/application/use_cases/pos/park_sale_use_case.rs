@@ -0,0 +1,76 @@
+//! Park sale use case
+
+use std::sync::Arc;
+
+use uuid::Uuid;
+
+use inventory::{InventoryStockRepository, ReservationRepository};
+
+use crate::SalesError;
+use crate::application::dtos::SaleDetailResponse;
+use crate::domain::repositories::SaleRepository;
+use crate::domain::value_objects::SaleId;
+
+use super::sale_reservations::release_sale_reservations;
+
+/// Use case for parking a POS sale so the cashier can start another one
+///
+/// The sale keeps its items and payments, but the stock reserved for it is
+/// put back on sale until the sale is resumed.
+pub struct ParkSaleUseCase<R, S>
+where
+    R: ReservationRepository,
+    S: InventoryStockRepository,
+{
+    sale_repo: Arc<dyn SaleRepository>,
+    reservation_repo: Arc<R>,
+    stock_repo: Arc<S>,
+}
+
+impl<R, S> ParkSaleUseCase<R, S>
+where
+    R: ReservationRepository,
+    S: InventoryStockRepository,
+{
+    pub fn new(
+        sale_repo: Arc<dyn SaleRepository>,
+        reservation_repo: Arc<R>,
+        stock_repo: Arc<S>,
+    ) -> Self {
+        Self {
+            sale_repo,
+            reservation_repo,
+            stock_repo,
+        }
+    }
+
+    pub async fn execute(&self, sale_id: Uuid) -> Result<SaleDetailResponse, SalesError> {
+        let mut sale = self
+            .sale_repo
+            .find_by_id_with_details(SaleId::from_uuid(sale_id))
+            .await?
+            .ok_or(SalesError::SaleNotFound(sale_id))?;
+
+        sale.park()?;
+
+        // Release the stock held for the sale
+        release_sale_reservations(
+            self.reservation_repo.as_ref(),
+            self.stock_repo.as_ref(),
+            &sale,
+        )
+        .await
+        .map_err(|_| SalesError::ReservationCancelFailed)?;
+
+        for item in sale.items_mut() {
+            if item.reservation_id().is_some() {
+                item.set_reservation_id(None);
+                self.sale_repo.update_item(item).await?;
+            }
+        }
+
+        self.sale_repo.update(&sale).await?;
+
+        Ok(SaleDetailResponse::from(sale))
+    }
+}
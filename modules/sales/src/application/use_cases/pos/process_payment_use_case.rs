@@ -1,51 +1,115 @@
 //! Process payment use case
 
-use std::str::FromStr;
 use std::sync::Arc;
 
+use chrono::Utc;
+
+use inventory::{InventoryStockRepository, ReservationPriorityPolicy, ReservationRepository};
+
 use crate::SalesError;
 use crate::application::dtos::{ProcessPaymentCommand, SaleDetailResponse};
-use crate::domain::entities::Payment;
-use crate::domain::repositories::{SaleRepository, ShiftRepository};
-use crate::domain::value_objects::{PaymentMethod, SaleId};
+use crate::domain::entities::CustomerAccountEntry;
+use crate::domain::repositories::{
+    CustomerRepository, ExchangeRateRepository, GiftCardRepository, LayawayRepository,
+    SaleIdempotencyRepository, SaleRepository, ShiftRepository,
+};
+use crate::domain::value_objects::{IdempotentOperation, PaymentMethod, SaleId};
+
+use crate::application::use_cases::layaway::{apply_layaway_payment, prepare_layaway_payment};
+
+use super::idempotency::{idempotency_key_for, is_idempotent_replay};
+use super::sale_reservations::reserve_sale_stock;
+use super::tenders::{gift_card_tenders, tender_payments, tender_rates};
 
 /// Use case for processing a payment
-pub struct ProcessPaymentUseCase {
+///
+/// A payment may be split across several tenders, one `Payment` each. Until
+/// the tenders cover the sale total the sale stays partially paid; cash
+/// tendered beyond the total is recorded as change due.
+///
+/// Tenders in a foreign currency are converted to the sale's base currency
+/// at the store's current rate; coverage, customer balances and shift
+/// totals are all in the base currency.
+///
+/// A gift card tender larger than the card's balance only spends the
+/// balance, leaving the rest due.
+///
+/// Idempotency keys are scoped to the sale's terminal and kept for 24
+/// hours; a key on a sale without a terminal is rejected.
+///
+/// With layaways, the deposit on a layaway sale puts it on layaway: what it
+/// leaves due is scheduled in installments and the sale's stock is reserved
+/// until the last one is due. Later payments are applied to the schedule. A
+/// layaway paid in full is left for `CompleteSaleUseCase` to complete.
+pub struct ProcessPaymentUseCase<R, S>
+where
+    R: ReservationRepository,
+    S: InventoryStockRepository,
+{
     sale_repo: Arc<dyn SaleRepository>,
     shift_repo: Arc<dyn ShiftRepository>,
+    customer_repo: Arc<dyn CustomerRepository>,
+    idempotency_repo: Arc<dyn SaleIdempotencyRepository>,
+    exchange_rate_repo: Arc<dyn ExchangeRateRepository>,
+    gift_card_repo: Arc<dyn GiftCardRepository>,
+    layaways: Option<LayawayPayments<R, S>>,
 }
 
-impl ProcessPaymentUseCase {
-    pub fn new(sale_repo: Arc<dyn SaleRepository>, shift_repo: Arc<dyn ShiftRepository>) -> Self {
+/// What taking layaway payments needs: the layaways and the stock their
+/// deposits reserve
+struct LayawayPayments<R, S> {
+    layaway_repo: Arc<dyn LayawayRepository>,
+    reservation_repo: Arc<R>,
+    stock_repo: Arc<S>,
+    priority_policy: ReservationPriorityPolicy,
+}
+
+impl<R, S> ProcessPaymentUseCase<R, S>
+where
+    R: ReservationRepository,
+    S: InventoryStockRepository,
+{
+    pub fn new(
+        sale_repo: Arc<dyn SaleRepository>,
+        shift_repo: Arc<dyn ShiftRepository>,
+        customer_repo: Arc<dyn CustomerRepository>,
+        idempotency_repo: Arc<dyn SaleIdempotencyRepository>,
+        exchange_rate_repo: Arc<dyn ExchangeRateRepository>,
+        gift_card_repo: Arc<dyn GiftCardRepository>,
+    ) -> Self {
         Self {
             sale_repo,
             shift_repo,
+            customer_repo,
+            idempotency_repo,
+            exchange_rate_repo,
+            gift_card_repo,
+            layaways: None,
         }
     }
 
+    /// Takes deposits and installments on layaway sales
+    pub fn with_layaways(
+        mut self,
+        layaway_repo: Arc<dyn LayawayRepository>,
+        reservation_repo: Arc<R>,
+        stock_repo: Arc<S>,
+        priority_policy: ReservationPriorityPolicy,
+    ) -> Self {
+        self.layaways = Some(LayawayPayments {
+            layaway_repo,
+            reservation_repo,
+            stock_repo,
+            priority_policy,
+        });
+        self
+    }
+
     pub async fn execute(
         &self,
         cmd: ProcessPaymentCommand,
     ) -> Result<SaleDetailResponse, SalesError> {
-        // Idempotency check: if key provided, look for existing payment
-        if let Some(ref key) = cmd.idempotency_key
-            && let Some(existing_payment) =
-                self.sale_repo.find_payment_by_idempotency_key(key).await?
-        {
-            // Idempotent replay: return the sale associated with the existing payment
-            let sale = self
-                .sale_repo
-                .find_by_id_with_details(existing_payment.sale_id())
-                .await?
-                .ok_or(SalesError::SaleNotFound(
-                    existing_payment.sale_id().into_uuid(),
-                ))?;
-            return Ok(SaleDetailResponse::from(sale));
-        }
-
         let sale_id = SaleId::from_uuid(cmd.sale_id);
-        let payment_method = PaymentMethod::from_str(&cmd.payment_method)
-            .map_err(|_| SalesError::InvalidPaymentMethod)?;
 
         let mut sale = self
             .sale_repo
@@ -53,47 +117,147 @@ impl ProcessPaymentUseCase {
             .await?
             .ok_or(SalesError::SaleNotFound(cmd.sale_id))?;
 
-        // Verify sale is editable
-        if !sale.is_editable() {
-            return Err(SalesError::SaleNotEditable);
+        // Idempotent replay: the terminal already sent this payment
+        let idempotency = cmd
+            .idempotency_key
+            .as_deref()
+            .map(|key| idempotency_key_for(&sale, key, IdempotentOperation::Payment))
+            .transpose()?;
+        if let Some(record) = &idempotency
+            && is_idempotent_replay(self.idempotency_repo.as_ref(), record).await?
+        {
+            return Ok(SaleDetailResponse::from(sale));
         }
 
-        // Create the payment based on method
-        let mut payment = if payment_method == PaymentMethod::Cash {
-            let tendered = cmd.amount_tendered.unwrap_or(cmd.amount);
-            Payment::create_cash(sale_id, cmd.amount, sale.currency().clone(), tendered)?
-        } else {
-            Payment::create(sale_id, payment_method, cmd.amount, sale.currency().clone())?
+        // Verify sale takes payments
+        if !sale.accepts_payments() {
+            return Err(SalesError::SaleNotEditable);
+        }
+        let mut layaway = match &self.layaways {
+            Some(layaways) => {
+                prepare_layaway_payment(layaways.layaway_repo.as_ref(), &mut sale).await?
+            }
+            None => None,
         };
 
-        // Set optional fields
-        payment.set_reference_number(cmd.reference.clone());
-        payment.set_notes(cmd.notes.clone());
-        payment.set_idempotency_key(cmd.idempotency_key.clone());
+        // Create one payment per tender
+        let rates = tender_rates(self.exchange_rate_repo.as_ref(), &sale, &cmd.allocations).await?;
+        let mut payments = tender_payments(&sale, &cmd.allocations, &rates)?;
+        let gift_cards = gift_card_tenders(
+            self.gift_card_repo.as_ref(),
+            &sale,
+            &cmd.allocations,
+            &mut payments,
+        )
+        .await?;
+        for payment in &mut payments {
+            payment.set_notes(cmd.notes.clone());
+        }
+
+        // Charge on-account tenders to the customer's balance and spend
+        // store credit tenders from the customer's store credit. Both
+        // re-check the balance atomically, so concurrent sales can't push
+        // the account past its limit or overdraw the store credit.
+        for payment in &payments {
+            let method = payment.payment_method();
+            if !method.is_on_account() && method != PaymentMethod::StoreCredit {
+                continue;
+            }
+            let customer_id = sale.customer_id().ok_or(if method.is_on_account() {
+                SalesError::OnAccountRequiresCustomer
+            } else {
+                SalesError::StoreCreditRequiresCustomer
+            })?;
+            let customer = self
+                .customer_repo
+                .find_by_id(customer_id)
+                .await?
+                .ok_or(SalesError::CustomerNotFound(customer_id.into_uuid()))?;
+
+            if method.is_on_account() {
+                customer.ensure_can_charge(payment.base_amount())?;
+                let mut entry = CustomerAccountEntry::charge(
+                    customer.id(),
+                    sale.store_id(),
+                    payment.base_amount(),
+                    sale.id().into_uuid(),
+                    sale.sale_number().to_string(),
+                    None,
+                )?;
+                self.customer_repo.post_account_entry(&mut entry).await?;
+            } else {
+                customer.ensure_can_spend_store_credit(payment.base_amount())?;
+                self.customer_repo
+                    .adjust_store_credit(customer.id(), -payment.base_amount())
+                    .await?;
+            }
+        }
+
+        // Add the tenders to sale; partial tenders leave it partially paid
+        let paid_before = sale.amount_paid();
+        sale.add_payments(payments.clone())?;
 
-        // Add payment to sale
-        sale.add_payment(payment.clone())?;
+        // Schedule what a layaway deposit leaves due, holding the stock
+        // until the layaway is due, or apply an installment
+        if let (Some(layaways), Some(layaway)) = (&self.layaways, &mut layaway) {
+            let paid = sale.amount_paid() - paid_before;
+            if let Some(hold_until) =
+                apply_layaway_payment(layaway, &sale, paid, Utc::now().date_naive())?
+            {
+                let reserved = reserve_sale_stock(
+                    &layaways.reservation_repo,
+                    &layaways.stock_repo,
+                    &layaways.priority_policy,
+                    &sale,
+                    hold_until,
+                )
+                .await?;
+                for (item_id, reservation_id) in reserved {
+                    if let Some(item) = sale.items_mut().iter_mut().find(|i| i.id() == item_id) {
+                        item.set_reservation_id(Some(reservation_id));
+                        self.sale_repo.update_item(item).await?;
+                    }
+                }
+            }
+        }
 
-        // Save the payment
-        self.sale_repo.save_payment(&payment).await?;
+        // Spend gift card tenders; the balance is re-checked under a row
+        // lock, so the same card can't be spent twice concurrently
+        for (card_id, amount) in &gift_cards {
+            self.gift_card_repo.redeem(*card_id, *amount).await?;
+        }
+
+        // Save the payments
+        for payment in &payments {
+            self.sale_repo.save_payment(payment).await?;
+        }
 
         // Update sale
         self.sale_repo.update(&sale).await?;
+        if let (Some(layaways), Some(layaway)) = (&self.layaways, &layaway) {
+            layaways.layaway_repo.update(layaway).await?;
+        }
 
         // Update shift sales totals if this is a POS sale
         if let Some(shift_id) = sale.shift_id()
             && let Some(mut shift) = self.shift_repo.find_by_id(shift_id).await?
         {
-            match payment_method {
-                PaymentMethod::Cash => shift.record_cash_sale(cmd.amount)?,
-                PaymentMethod::CreditCard | PaymentMethod::DebitCard => {
-                    shift.record_card_sale(cmd.amount)?
+            for payment in &payments {
+                match payment.payment_method() {
+                    PaymentMethod::Cash => shift.record_cash_sale(payment.base_amount())?,
+                    PaymentMethod::CreditCard | PaymentMethod::DebitCard => {
+                        shift.record_card_sale(payment.base_amount())?
+                    }
+                    _ => shift.record_other_sale(payment.base_amount())?,
                 }
-                _ => shift.record_other_sale(cmd.amount)?,
             }
             self.shift_repo.update(&shift).await?;
         }
 
+        if let Some(record) = &idempotency {
+            self.idempotency_repo.save(record).await?;
+        }
+
         Ok(SaleDetailResponse::from(sale))
     }
 }
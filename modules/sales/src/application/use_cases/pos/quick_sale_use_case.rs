@@ -0,0 +1,1027 @@
+//! Quick sale use case - single-item cash checkout in one call
+
+use std::sync::Arc;
+
+use chrono::Utc;
+use rust_decimal::Decimal;
+
+use crate::SalesError;
+use crate::application::dtos::{QuickSaleCommand, SaleDetailResponse};
+use crate::application::use_cases::price_list::resolve_list_price;
+use crate::application::use_cases::price_tier::resolve_tier_price;
+use crate::application::use_cases::tax_rule::CalculateTaxUseCase;
+use crate::domain::entities::{Payment, Sale, SaleItem};
+use crate::domain::repositories::{
+    CustomerRepository, PriceListRepository, PriceTierRepository, QuickSale, QuickSaleRepository,
+    SaleIdempotencyRepository, SaleRepository, ShiftRepository, StockDeduction, TaxRuleRepository,
+};
+use crate::domain::value_objects::{CustomerType, IdempotentOperation, ShiftId};
+use identity::{StoreId, UserId};
+use inventory::application::helpers::ensure_period_open;
+use inventory::{
+    Barcode, CategoryRepository, Currency, InventoryError, InventoryMovement,
+    InventoryPeriodRepository, InventoryStock, InventoryStockRepository, MovementType, Product,
+    ProductId, ProductRepository, ProductVariant, RecipeRepository,
+    ResolveRecipeConsumptionUseCase, VariantId,
+};
+
+use super::idempotency::{idempotency_key_for, replayed_sale};
+use super::line_serials::inventory_error;
+
+/// Use case for the fast lane: one scanned item paid in cash.
+///
+/// Creates, pays and completes the sale in one transaction, with the same
+/// checks as the regular checkout. Items that need more than a scan are
+/// refused with `QuickSaleNotSupported` so the terminal falls back to the
+/// regular checkout steps.
+pub struct QuickSaleUseCase<P, S, T, L, R>
+where
+    P: ProductRepository,
+    S: InventoryStockRepository,
+    T: PriceTierRepository,
+    L: PriceListRepository,
+    R: RecipeRepository,
+{
+    sale_repo: Arc<dyn SaleRepository>,
+    shift_repo: Arc<dyn ShiftRepository>,
+    quick_sale_repo: Arc<dyn QuickSaleRepository>,
+    product_repo: Arc<P>,
+    stock_repo: Arc<S>,
+    price_tier_repo: Arc<T>,
+    price_list_repo: Arc<L>,
+    recipe_repo: Arc<R>,
+    idempotency_repo: Arc<dyn SaleIdempotencyRepository>,
+    period_repo: Option<Arc<dyn InventoryPeriodRepository>>,
+    can_post_to_closed_period: bool,
+    tax_calculator: Option<CalculateTaxUseCase<P>>,
+}
+
+impl<P, S, T, L, R> QuickSaleUseCase<P, S, T, L, R>
+where
+    P: ProductRepository,
+    S: InventoryStockRepository,
+    T: PriceTierRepository,
+    L: PriceListRepository,
+    R: RecipeRepository,
+{
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        sale_repo: Arc<dyn SaleRepository>,
+        shift_repo: Arc<dyn ShiftRepository>,
+        quick_sale_repo: Arc<dyn QuickSaleRepository>,
+        product_repo: Arc<P>,
+        stock_repo: Arc<S>,
+        price_tier_repo: Arc<T>,
+        price_list_repo: Arc<L>,
+        recipe_repo: Arc<R>,
+        idempotency_repo: Arc<dyn SaleIdempotencyRepository>,
+    ) -> Self {
+        Self {
+            sale_repo,
+            shift_repo,
+            quick_sale_repo,
+            product_repo,
+            stock_repo,
+            price_tier_repo,
+            price_list_repo,
+            recipe_repo,
+            idempotency_repo,
+            period_repo: None,
+            can_post_to_closed_period: false,
+            tax_calculator: None,
+        }
+    }
+
+    /// Rejects sales dated in a closed period, unless `can_override` is set
+    pub fn with_period_lock(
+        mut self,
+        period_repo: Arc<dyn InventoryPeriodRepository>,
+        can_override: bool,
+    ) -> Self {
+        self.period_repo = Some(period_repo);
+        self.can_post_to_closed_period = can_override;
+        self
+    }
+
+    /// Taxes the item under the store's tax rules
+    pub fn with_tax_rules(
+        mut self,
+        customer_repo: Arc<dyn CustomerRepository>,
+        tax_rule_repo: Arc<dyn TaxRuleRepository>,
+        category_repo: Arc<dyn CategoryRepository>,
+    ) -> Self {
+        self.tax_calculator = Some(CalculateTaxUseCase::new(
+            self.sale_repo.clone(),
+            customer_repo,
+            tax_rule_repo,
+            self.product_repo.clone(),
+            category_repo,
+        ));
+        self
+    }
+
+    pub async fn execute(
+        &self,
+        cmd: QuickSaleCommand,
+        cashier_id: UserId,
+        allow_negative_stock: bool,
+    ) -> Result<SaleDetailResponse, SalesError> {
+        if cmd.quantity <= Decimal::ZERO {
+            return Err(SalesError::InvalidQuantity);
+        }
+        if cmd.invoice_number.is_some() {
+            return Err(SalesError::InvoiceNumberNotAllowed);
+        }
+
+        // Verify the shift is open, belongs to the cashier and the store
+        let store_id = StoreId::from_uuid(cmd.store_id);
+        let mut shift = self
+            .shift_repo
+            .find_by_id(ShiftId::from_uuid(cmd.shift_id))
+            .await?
+            .ok_or(SalesError::ShiftNotFound(cmd.shift_id))?;
+        if !shift.is_open() {
+            return Err(SalesError::NoOpenShift);
+        }
+        if shift.cashier_id() != cashier_id || shift.store_id() != store_id {
+            return Err(SalesError::ShiftNotFound(cmd.shift_id));
+        }
+
+        // Idempotent replay: a retried scan from the shift's terminal returns
+        // the sale already rung up
+        if let Some(key) = &cmd.idempotency_key
+            && let Some(sale_id) = replayed_sale(
+                self.idempotency_repo.as_ref(),
+                shift.terminal_id(),
+                key,
+                IdempotentOperation::QuickSale,
+            )
+            .await?
+        {
+            let sale = self
+                .sale_repo
+                .find_by_id_with_details(sale_id)
+                .await?
+                .ok_or(SalesError::SaleNotFound(sale_id.into_uuid()))?;
+            return Ok(SaleDetailResponse::from(sale));
+        }
+
+        // The sale is created and completed now, so it is dated today
+        if let Some(period_repo) = &self.period_repo {
+            ensure_period_open(
+                period_repo.as_ref(),
+                store_id,
+                Utc::now().date_naive(),
+                self.can_post_to_closed_period,
+            )
+            .await
+            .map_err(inventory_error)?;
+        }
+
+        let (product, variant) = self.find_item(&cmd).await?;
+        if product.is_serialized() {
+            return Err(SalesError::QuickSaleNotSupported(
+                "product is serial-tracked".to_string(),
+            ));
+        }
+        if product.tax_included() {
+            return Err(SalesError::QuickSaleNotSupported(
+                "product price includes tax".to_string(),
+            ));
+        }
+
+        // Price from the store's default list, the walk-in customer's tier
+        // or the base price
+        let base_price = variant
+            .as_ref()
+            .and_then(|v| v.price())
+            .unwrap_or(product.base_price());
+        let list_price = resolve_list_price(
+            self.price_list_repo.as_ref(),
+            cmd.store_id,
+            None,
+            product.id().into_uuid(),
+            variant.as_ref().map(|v| v.id().into_uuid()),
+        )
+        .await?;
+        let (unit_price, price_list_id, price_tier_id) = match list_price {
+            Some((price, list_id)) => (price, Some(list_id), None),
+            None => match resolve_tier_price(
+                self.price_tier_repo.as_ref(),
+                cmd.store_id,
+                CustomerType::default(),
+                product.id().into_uuid(),
+                product.category_id().map(|c| c.into_uuid()),
+                base_price,
+            )
+            .await?
+            {
+                Some((price, tier_id)) => (price, None, Some(tier_id)),
+                None => (base_price, None, None),
+            },
+        };
+
+        let sale_number = self.sale_repo.generate_sale_number(store_id).await?;
+        let mut sale = Sale::create_pos(
+            sale_number.clone(),
+            store_id,
+            shift.terminal_id(),
+            shift.id(),
+            cashier_id,
+            Currency::default(),
+        );
+
+        let (sku, description, unit_cost) = match &variant {
+            Some(v) => (
+                v.sku().to_string(),
+                format!("{} ({})", product.name(), v.name()),
+                v.cost_price().unwrap_or(product.cost_price()),
+            ),
+            None => (
+                product.sku().to_string(),
+                product.name().to_string(),
+                product.cost_price(),
+            ),
+        };
+        let mut item = SaleItem::create(
+            sale.id(),
+            1,
+            product.id(),
+            variant.as_ref().map(|v| v.id()),
+            sku,
+            description,
+            cmd.quantity,
+            product.unit_of_measure(),
+            unit_price,
+            unit_cost,
+            product.tax_rate(),
+        )?;
+        item.set_price_tier_id(price_tier_id);
+        item.set_price_list_id(price_list_id);
+        sale.add_item(item)?;
+        if let Some(tax_calculator) = &self.tax_calculator {
+            tax_calculator.apply(&mut sale).await?;
+        }
+
+        // Cash tender for the full total; change comes back on the payment
+        let total = sale.total();
+        let payment = Payment::create_cash(
+            sale.id(),
+            total,
+            sale.currency().clone(),
+            cmd.amount_tendered,
+        )?;
+        sale.add_payment(payment)?;
+        // The sale number stands in until the commit draws the CAI number
+        sale.complete(sale_number)?;
+        shift.record_cash_sale(total)?;
+
+        let stock = self
+            .deduct_stock(
+                &sale,
+                &product,
+                variant.as_ref(),
+                cmd.quantity,
+                cashier_id,
+                allow_negative_stock,
+            )
+            .await?;
+
+        let idempotency = cmd
+            .idempotency_key
+            .as_deref()
+            .map(|key| idempotency_key_for(&sale, key, IdempotentOperation::QuickSale))
+            .transpose()?;
+
+        let mut quick_sale = QuickSale { sale, shift, stock };
+        self.quick_sale_repo.commit(&mut quick_sale).await?;
+        if let Some(record) = &idempotency {
+            self.idempotency_repo.save(record).await?;
+        }
+
+        Ok(SaleDetailResponse::from(quick_sale.sale))
+    }
+
+    /// Resolves the scanned barcode or the given product and variant
+    async fn find_item(
+        &self,
+        cmd: &QuickSaleCommand,
+    ) -> Result<(Product, Option<ProductVariant>), SalesError> {
+        let (product_id, variant) = if let Some(raw) = &cmd.barcode {
+            let barcode =
+                Barcode::new(raw).map_err(|_| SalesError::BarcodeNotFound(raw.clone()))?;
+            let variant = self
+                .product_repo
+                .find_variant_by_barcode(&barcode)
+                .await
+                .map_err(inventory_error)?;
+            match variant {
+                Some(variant) => (variant.product_id(), Some(variant)),
+                None => {
+                    let product = self
+                        .product_repo
+                        .find_by_barcode(&barcode)
+                        .await
+                        .map_err(inventory_error)?
+                        .filter(|p| p.is_active())
+                        .ok_or_else(|| SalesError::BarcodeNotFound(raw.clone()))?;
+                    (product.id(), None)
+                }
+            }
+        } else if let Some(product_id) = cmd.product_id {
+            let variant = match cmd.variant_id {
+                Some(variant_id) => Some(
+                    self.product_repo
+                        .find_variant_by_id(VariantId::from_uuid(variant_id))
+                        .await
+                        .map_err(inventory_error)?
+                        .filter(|v| v.product_id().into_uuid() == product_id)
+                        .ok_or(SalesError::ProductNotFound(product_id))?,
+                ),
+                None => None,
+            };
+            (ProductId::from_uuid(product_id), variant)
+        } else {
+            return Err(SalesError::QuickSaleProductRequired);
+        };
+
+        let product = self
+            .product_repo
+            .find_by_id(product_id)
+            .await
+            .map_err(inventory_error)?
+            .filter(|p| p.is_active())
+            .ok_or(SalesError::ProductNotFound(product_id.into_uuid()))?;
+        if variant.as_ref().is_some_and(|v| !v.is_active()) {
+            return Err(SalesError::ProductNotFound(product_id.into_uuid()));
+        }
+        if variant.is_none() && product.has_variants() {
+            return Err(SalesError::QuickSaleNotSupported(
+                "product has variants, scan the variant".to_string(),
+            ));
+        }
+
+        Ok((product, variant))
+    }
+
+    /// Takes the sold quantity off the store's stock record, or a
+    /// composite product's recipe ingredients off theirs. Products without
+    /// a stock record at the store are not tracked there, as in the regular
+    /// completion.
+    async fn deduct_stock(
+        &self,
+        sale: &Sale,
+        product: &Product,
+        variant: Option<&ProductVariant>,
+        quantity: Decimal,
+        actor_id: UserId,
+        allow_negative_stock: bool,
+    ) -> Result<Vec<StockDeduction>, SalesError> {
+        let insufficient = |e: InventoryError| match e {
+            InventoryError::NegativeStock | InventoryError::ReservedExceedsQuantity => {
+                SalesError::InsufficientStock(product.id().into_uuid())
+            }
+            other => inventory_error(other),
+        };
+
+        let consumption = ResolveRecipeConsumptionUseCase::new(
+            self.recipe_repo.clone(),
+            self.stock_repo.clone(),
+            self.product_repo.clone(),
+        )
+        .with_allow_negative_stock(allow_negative_stock)
+        .execute(
+            sale.store_id(),
+            product.id(),
+            variant.map(|v| v.id()),
+            quantity,
+        )
+        .await
+        .map_err(inventory_error)?;
+
+        if let Some(consumption) = consumption {
+            let mut deductions: Vec<StockDeduction> = Vec::new();
+            for draw in &consumption.draws {
+                // A record drawn twice continues from the earlier deduction
+                let drawn = deductions
+                    .iter()
+                    .rev()
+                    .find(|d| d.stock.id() == draw.stock_id)
+                    .map(|d| d.stock.clone());
+                let stock = match drawn {
+                    Some(stock) => stock,
+                    None => self
+                        .stock_repo
+                        .find_by_id(draw.stock_id)
+                        .await
+                        .map_err(inventory_error)?
+                        .ok_or(SalesError::StockConflict)?,
+                };
+                let reason = match draw.substitute_id {
+                    Some(_) => "Recipe ingredient substitute",
+                    None => "Recipe ingredient",
+                };
+                deductions.push(
+                    deduction(
+                        stock,
+                        draw.quantity,
+                        None,
+                        reason,
+                        sale,
+                        actor_id,
+                        allow_negative_stock,
+                    )
+                    .map_err(insufficient)?,
+                );
+            }
+            return Ok(deductions);
+        }
+
+        let existing = match variant {
+            Some(v) => {
+                self.stock_repo
+                    .find_by_store_and_variant(sale.store_id(), v.id())
+                    .await
+            }
+            None => {
+                self.stock_repo
+                    .find_by_store_and_product(sale.store_id(), product.id())
+                    .await
+            }
+        }
+        .map_err(inventory_error)?;
+        let Some(stock) = existing else {
+            return Ok(Vec::new());
+        };
+
+        let unit_price = sale.items().first().map(|i| i.unit_price());
+        let deduction = deduction(
+            stock,
+            quantity,
+            unit_price,
+            "Sale completed",
+            sale,
+            actor_id,
+            allow_negative_stock,
+        )
+        .map_err(insufficient)?;
+        Ok(vec![deduction])
+    }
+}
+
+/// Takes a quantity off a stock record and records the sale movement
+fn deduction(
+    mut stock: InventoryStock,
+    quantity: Decimal,
+    unit_price: Option<Decimal>,
+    reason: &str,
+    sale: &Sale,
+    actor_id: UserId,
+    allow_negative_stock: bool,
+) -> Result<StockDeduction, InventoryError> {
+    let expected_version = stock.version();
+    stock.adjust_quantity_with_policy(-quantity, allow_negative_stock)?;
+    stock.increment_version();
+
+    let movement = InventoryMovement::create(
+        stock.id(),
+        MovementType::for_sale(stock.quantity()),
+        Some(reason.to_string()),
+        -quantity,
+        unit_price,
+        sale.currency().clone(),
+        stock.quantity(),
+        Some("sale".to_string()),
+        Some(sale.id().into_uuid()),
+        actor_id,
+        None,
+    );
+
+    Ok(StockDeduction {
+        stock,
+        expected_version,
+        movement,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::use_cases::test_support::{
+        CaiRanges, MockCategoryRepository, MockIdempotencyRepository, MockTaxRuleRepository,
+        NoCustomers,
+    };
+    use crate::application::use_cases::test_support::{
+        MockPeriodRepository, MockProductRepository, MockQuickSaleRepository, MockRecipeRepository,
+        MockSaleRepository, MockShiftRepository, MockStockRepository, NoPriceLists, NoPriceTiers,
+    };
+    use crate::domain::entities::{CashierShift, SaleIdempotencyKey, TaxRule};
+    use crate::domain::value_objects::SaleId;
+    use chrono::Duration;
+    use inventory::{InventoryPeriod, ProductCategory, Recipe, RecipeIngredient, UnitOfMeasure};
+    use pos_core::TerminalId;
+    use rust_decimal_macros::dec;
+
+    struct Fixture {
+        store_id: StoreId,
+        cashier_id: UserId,
+        shift_id: ShiftId,
+        terminal_id: TerminalId,
+        sale_repo: Arc<MockSaleRepository>,
+        shift_repo: Arc<MockShiftRepository>,
+        quick_sale_repo: Arc<MockQuickSaleRepository>,
+        product_repo: Arc<MockProductRepository>,
+        stock_repo: Arc<MockStockRepository>,
+        recipe_repo: Arc<MockRecipeRepository>,
+        idempotency_repo: Arc<MockIdempotencyRepository>,
+    }
+
+    impl Fixture {
+        fn new() -> Self {
+            Self::with_cai(CaiRanges::active("CAI-TEST", 1, 100))
+        }
+
+        fn with_cai(cai: CaiRanges) -> Self {
+            let store_id = StoreId::new();
+            let cashier_id = UserId::new();
+            let terminal_id = TerminalId::new();
+            let shift = CashierShift::create(store_id, terminal_id, cashier_id, dec!(100)).unwrap();
+            let shift_id = shift.id();
+            let shift_repo = Arc::new(MockShiftRepository::new());
+            shift_repo.add_shift(shift);
+            Self {
+                store_id,
+                cashier_id,
+                shift_id,
+                terminal_id,
+                sale_repo: Arc::new(MockSaleRepository::new()),
+                shift_repo,
+                quick_sale_repo: Arc::new(MockQuickSaleRepository::new(cai)),
+                product_repo: Arc::new(MockProductRepository::new()),
+                stock_repo: Arc::new(MockStockRepository::new()),
+                recipe_repo: Arc::new(MockRecipeRepository::new()),
+                idempotency_repo: Arc::new(MockIdempotencyRepository::default()),
+            }
+        }
+
+        /// Opens a shift for the cashier on another terminal of the store
+        fn open_shift_on_other_terminal(&self) -> ShiftId {
+            let shift =
+                CashierShift::create(self.store_id, TerminalId::new(), self.cashier_id, dec!(100))
+                    .unwrap();
+            let shift_id = shift.id();
+            self.shift_repo.add_shift(shift);
+            shift_id
+        }
+
+        fn add_product(&self, name: &str, price: Decimal) -> Product {
+            let mut product = Product::create(name.to_string(), UnitOfMeasure::Unit, None);
+            product.set_base_price(price);
+            self.product_repo.add_product(product.clone());
+            product
+        }
+
+        fn add_stock(&self, product: &Product, quantity: Decimal) -> InventoryStock {
+            let mut stock =
+                InventoryStock::create_for_product(self.store_id, product.id()).unwrap();
+            stock.adjust_quantity(quantity).unwrap();
+            self.stock_repo.add_stock(stock.clone());
+            stock
+        }
+
+        fn use_case(
+            &self,
+        ) -> QuickSaleUseCase<
+            MockProductRepository,
+            MockStockRepository,
+            NoPriceTiers,
+            NoPriceLists,
+            MockRecipeRepository,
+        > {
+            QuickSaleUseCase::new(
+                self.sale_repo.clone(),
+                self.shift_repo.clone(),
+                self.quick_sale_repo.clone(),
+                self.product_repo.clone(),
+                self.stock_repo.clone(),
+                Arc::new(NoPriceTiers),
+                Arc::new(NoPriceLists),
+                self.recipe_repo.clone(),
+                self.idempotency_repo.clone(),
+            )
+        }
+
+        fn command(&self, product: &Product, quantity: Decimal) -> QuickSaleCommand {
+            QuickSaleCommand {
+                store_id: self.store_id.into_uuid(),
+                shift_id: self.shift_id.into_uuid(),
+                barcode: None,
+                product_id: Some(product.id().into_uuid()),
+                variant_id: None,
+                quantity,
+                amount_tendered: dec!(1000),
+                invoice_number: None,
+                idempotency_key: None,
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn test_quick_sale_deducts_product_stock() {
+        let fixture = Fixture::new();
+        let soda = fixture.add_product("Soda", dec!(25));
+        let stock = fixture.add_stock(&soda, dec!(10));
+
+        fixture
+            .use_case()
+            .execute(fixture.command(&soda, dec!(2)), fixture.cashier_id, false)
+            .await
+            .unwrap();
+
+        let committed = fixture.quick_sale_repo.committed();
+        let deductions = &committed[0].stock;
+        assert_eq!(deductions.len(), 1);
+        assert_eq!(deductions[0].stock.id(), stock.id());
+        assert_eq!(deductions[0].stock.quantity(), dec!(8));
+        assert_eq!(
+            deductions[0].movement.movement_reason(),
+            Some("Sale completed")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_quick_sale_consumes_recipe_ingredients() {
+        let fixture = Fixture::new();
+        let latte = fixture.add_product("Latte", dec!(60));
+        let latte_stock = fixture.add_stock(&latte, dec!(5));
+        let coffee = fixture.add_product("Coffee", dec!(0));
+        let coffee_stock = fixture.add_stock(&coffee, dec!(10));
+        let milk = fixture.add_product("Milk", dec!(0));
+        let milk_stock = fixture.add_stock(&milk, dec!(4));
+
+        let recipe = Recipe::create_for_product(latte.id(), "Latte".to_string(), dec!(1)).unwrap();
+        let ingredients = vec![
+            RecipeIngredient::create_for_product(
+                recipe.id(),
+                coffee.id(),
+                dec!(0.2),
+                UnitOfMeasure::Kg,
+            )
+            .unwrap(),
+            RecipeIngredient::create_for_product(
+                recipe.id(),
+                milk.id(),
+                dec!(0.3),
+                UnitOfMeasure::Liter,
+            )
+            .unwrap(),
+        ];
+        fixture.recipe_repo.add_recipe(recipe, ingredients);
+
+        fixture
+            .use_case()
+            .execute(fixture.command(&latte, dec!(2)), fixture.cashier_id, false)
+            .await
+            .unwrap();
+
+        let committed = fixture.quick_sale_repo.committed();
+        let deductions = &committed[0].stock;
+        assert_eq!(deductions.len(), 2);
+        assert!(deductions.iter().all(|d| d.stock.id() != latte_stock.id()));
+        let coffee_draw = deductions
+            .iter()
+            .find(|d| d.stock.id() == coffee_stock.id())
+            .unwrap();
+        assert_eq!(coffee_draw.stock.quantity(), dec!(9.6));
+        assert_eq!(coffee_draw.movement.quantity(), dec!(-0.4));
+        assert_eq!(
+            coffee_draw.movement.movement_reason(),
+            Some("Recipe ingredient")
+        );
+        let milk_draw = deductions
+            .iter()
+            .find(|d| d.stock.id() == milk_stock.id())
+            .unwrap();
+        assert_eq!(milk_draw.stock.quantity(), dec!(3.4));
+    }
+
+    #[tokio::test]
+    async fn test_quick_sale_rejects_short_recipe_ingredient() {
+        let fixture = Fixture::new();
+        let latte = fixture.add_product("Latte", dec!(60));
+        let coffee = fixture.add_product("Coffee", dec!(0));
+        fixture.add_stock(&coffee, dec!(0.1));
+
+        let recipe = Recipe::create_for_product(latte.id(), "Latte".to_string(), dec!(1)).unwrap();
+        let ingredient = RecipeIngredient::create_for_product(
+            recipe.id(),
+            coffee.id(),
+            dec!(0.2),
+            UnitOfMeasure::Kg,
+        )
+        .unwrap();
+        fixture.recipe_repo.add_recipe(recipe, vec![ingredient]);
+
+        let result = fixture
+            .use_case()
+            .execute(fixture.command(&latte, dec!(1)), fixture.cashier_id, false)
+            .await;
+
+        assert!(matches!(result, Err(SalesError::IngredientUnavailable(_))));
+        assert!(fixture.quick_sale_repo.committed().is_empty());
+    }
+
+    fn closed_period_today(store_id: StoreId) -> Arc<MockPeriodRepository> {
+        let today = Utc::now().date_naive();
+        let mut period = InventoryPeriod::create(store_id, today, today).unwrap();
+        period.close(UserId::new()).unwrap();
+        Arc::new(MockPeriodRepository(vec![period]))
+    }
+
+    #[tokio::test]
+    async fn test_quick_sale_rejected_in_closed_period() {
+        let fixture = Fixture::new();
+        let soda = fixture.add_product("Soda", dec!(25));
+        let stock = fixture.add_stock(&soda, dec!(10));
+
+        let result = fixture
+            .use_case()
+            .with_period_lock(closed_period_today(fixture.store_id), false)
+            .execute(fixture.command(&soda, dec!(1)), fixture.cashier_id, false)
+            .await;
+
+        assert!(matches!(result, Err(SalesError::PeriodClosed(_, _))));
+        assert!(fixture.quick_sale_repo.committed().is_empty());
+        let stored = fixture.stock_repo.find_by_id(stock.id()).await.unwrap();
+        assert_eq!(stored.unwrap().quantity(), dec!(10));
+    }
+
+    #[tokio::test]
+    async fn test_quick_sale_in_closed_period_with_override() {
+        let fixture = Fixture::new();
+        let soda = fixture.add_product("Soda", dec!(25));
+
+        let result = fixture
+            .use_case()
+            .with_period_lock(closed_period_today(fixture.store_id), true)
+            .execute(fixture.command(&soda, dec!(1)), fixture.cashier_id, false)
+            .await;
+
+        assert!(result.is_ok());
+        assert_eq!(fixture.quick_sale_repo.committed().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_quick_sale_taxes_under_store_rule() {
+        let fixture = Fixture::new();
+        let mut soda = Product::create("Soda".to_string(), UnitOfMeasure::Unit, None);
+        soda.set_base_price(dec!(100));
+        soda.set_tax_rate(dec!(15));
+        fixture.product_repo.add_product(soda.clone());
+        let rule = TaxRule::create(
+            fixture.store_id.into_uuid(),
+            "HN".to_string(),
+            Some(soda.id().into_uuid()),
+            None,
+            dec!(18),
+            false,
+            Vec::new(),
+            UserId::new(),
+        )
+        .unwrap();
+
+        let response = fixture
+            .use_case()
+            .with_tax_rules(
+                Arc::new(NoCustomers),
+                Arc::new(MockTaxRuleRepository(vec![rule])),
+                Arc::new(MockCategoryRepository::default()),
+            )
+            .execute(fixture.command(&soda, dec!(1)), fixture.cashier_id, false)
+            .await
+            .unwrap();
+
+        let sale = &fixture.quick_sale_repo.committed()[0].sale;
+        assert_eq!(sale.items()[0].tax_rate(), dec!(18));
+        assert_eq!(sale.tax_amount(), dec!(18));
+        assert_eq!(sale.total(), dec!(118));
+        assert_eq!(sale.payments()[0].amount(), dec!(118));
+        assert_eq!(response.total, dec!(118));
+    }
+
+    #[tokio::test]
+    async fn test_quick_sale_taxes_subcategory_under_parent_category_rule() {
+        let fixture = Fixture::new();
+        let drinks = ProductCategory::create("Drinks".to_string(), "drinks".to_string());
+        let sodas = ProductCategory::create_subcategory(
+            drinks.id(),
+            "Sodas".to_string(),
+            "sodas".to_string(),
+        );
+        let mut soda = Product::create("Soda".to_string(), UnitOfMeasure::Unit, None);
+        soda.set_base_price(dec!(100));
+        soda.set_tax_rate(dec!(15));
+        soda.set_category_id(Some(sodas.id()));
+        fixture.product_repo.add_product(soda.clone());
+        let rule = TaxRule::create(
+            fixture.store_id.into_uuid(),
+            "HN".to_string(),
+            None,
+            Some(drinks.id().into_uuid()),
+            dec!(18),
+            false,
+            Vec::new(),
+            UserId::new(),
+        )
+        .unwrap();
+
+        fixture
+            .use_case()
+            .with_tax_rules(
+                Arc::new(NoCustomers),
+                Arc::new(MockTaxRuleRepository(vec![rule])),
+                Arc::new(MockCategoryRepository(vec![drinks, sodas])),
+            )
+            .execute(fixture.command(&soda, dec!(1)), fixture.cashier_id, false)
+            .await
+            .unwrap();
+
+        let sale = &fixture.quick_sale_repo.committed()[0].sale;
+        assert_eq!(sale.items()[0].tax_rate(), dec!(18));
+        assert_eq!(sale.total(), dec!(118));
+    }
+
+    #[tokio::test]
+    async fn test_quick_sale_keeps_product_rate_without_rule() {
+        let fixture = Fixture::new();
+        let mut soda = Product::create("Soda".to_string(), UnitOfMeasure::Unit, None);
+        soda.set_base_price(dec!(100));
+        soda.set_tax_rate(dec!(15));
+        fixture.product_repo.add_product(soda.clone());
+
+        fixture
+            .use_case()
+            .with_tax_rules(
+                Arc::new(NoCustomers),
+                Arc::new(MockTaxRuleRepository(vec![])),
+                Arc::new(MockCategoryRepository::default()),
+            )
+            .execute(fixture.command(&soda, dec!(1)), fixture.cashier_id, false)
+            .await
+            .unwrap();
+
+        let sale = &fixture.quick_sale_repo.committed()[0].sale;
+        assert_eq!(sale.total(), dec!(115));
+    }
+
+    #[tokio::test]
+    async fn test_quick_sale_draws_next_cai_number() {
+        let fixture = Fixture::with_cai(CaiRanges::active("CAI-TEST", 41, 50));
+        let soda = fixture.add_product("Soda", dec!(25));
+
+        for _ in 0..2 {
+            fixture
+                .use_case()
+                .execute(fixture.command(&soda, dec!(1)), fixture.cashier_id, false)
+                .await
+                .unwrap();
+        }
+
+        let committed = fixture.quick_sale_repo.committed();
+        assert_eq!(committed[0].sale.cai_number(), Some("CAI-TEST"));
+        assert_eq!(committed[0].sale.invoice_number(), Some("00000041"));
+        assert_eq!(committed[1].sale.invoice_number(), Some("00000042"));
+    }
+
+    #[tokio::test]
+    async fn test_quick_sale_rejects_client_invoice_number() {
+        let fixture = Fixture::new();
+        let soda = fixture.add_product("Soda", dec!(25));
+        let mut command = fixture.command(&soda, dec!(1));
+        command.invoice_number = Some("000-001-01-00000007".to_string());
+
+        let result = fixture
+            .use_case()
+            .execute(command, fixture.cashier_id, false)
+            .await;
+
+        assert!(matches!(result, Err(SalesError::InvoiceNumberNotAllowed)));
+        assert!(fixture.quick_sale_repo.committed().is_empty());
+    }
+
+    /// Runs a quick sale against the CAI range and returns its error
+    async fn quick_sale_error(cai: CaiRanges) -> SalesError {
+        let fixture = Fixture::with_cai(cai);
+        let soda = fixture.add_product("Soda", dec!(25));
+
+        let error = fixture
+            .use_case()
+            .execute(fixture.command(&soda, dec!(1)), fixture.cashier_id, false)
+            .await
+            .unwrap_err();
+        assert!(fixture.quick_sale_repo.committed().is_empty());
+        error
+    }
+
+    #[tokio::test]
+    async fn test_quick_sale_fails_without_cai_range() {
+        let error = quick_sale_error(CaiRanges::missing()).await;
+        assert!(matches!(error, SalesError::NoValidCai(_)));
+    }
+
+    #[tokio::test]
+    async fn test_quick_sale_fails_with_expired_cai() {
+        let error = quick_sale_error(CaiRanges::expired()).await;
+        assert!(matches!(error, SalesError::CaiExpired(_)));
+    }
+
+    #[tokio::test]
+    async fn test_quick_sale_fails_with_exhausted_cai_range() {
+        let error = quick_sale_error(CaiRanges::active("CAI-TEST", 51, 50)).await;
+        assert!(matches!(error, SalesError::CaiRangeExhausted(_)));
+    }
+
+    #[tokio::test]
+    async fn test_quick_sale_replays_key_from_same_terminal() {
+        let fixture = Fixture::new();
+        let soda = fixture.add_product("Soda", dec!(25));
+        let command = || QuickSaleCommand {
+            idempotency_key: Some("scan-1".to_string()),
+            ..fixture.command(&soda, dec!(1))
+        };
+
+        let first = fixture
+            .use_case()
+            .execute(command(), fixture.cashier_id, false)
+            .await
+            .unwrap();
+        let committed = fixture.quick_sale_repo.committed();
+        fixture.sale_repo.add_sale(committed[0].sale.clone());
+
+        let retry = fixture
+            .use_case()
+            .execute(command(), fixture.cashier_id, false)
+            .await
+            .unwrap();
+
+        assert_eq!(retry.id, first.id);
+        assert_eq!(fixture.quick_sale_repo.committed().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_quick_sale_same_key_on_another_terminal_is_new_sale() {
+        let fixture = Fixture::new();
+        let soda = fixture.add_product("Soda", dec!(25));
+        let command = || QuickSaleCommand {
+            idempotency_key: Some("scan-1".to_string()),
+            ..fixture.command(&soda, dec!(1))
+        };
+        let other_terminal = QuickSaleCommand {
+            shift_id: fixture.open_shift_on_other_terminal().into_uuid(),
+            ..command()
+        };
+
+        let first = fixture
+            .use_case()
+            .execute(command(), fixture.cashier_id, false)
+            .await
+            .unwrap();
+        let second = fixture
+            .use_case()
+            .execute(other_terminal, fixture.cashier_id, false)
+            .await
+            .unwrap();
+
+        assert_ne!(second.id, first.id);
+        assert_eq!(fixture.quick_sale_repo.committed().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_quick_sale_does_not_replay_expired_key() {
+        let fixture = Fixture::new();
+        let soda = fixture.add_product("Soda", dec!(25));
+        let created_at = Utc::now() - Duration::hours(25);
+        fixture
+            .idempotency_repo
+            .save(&SaleIdempotencyKey::reconstitute(
+                fixture.terminal_id,
+                "scan-1".to_string(),
+                IdempotentOperation::QuickSale,
+                SaleId::new(),
+                created_at,
+                created_at + Duration::hours(24),
+            ))
+            .await
+            .unwrap();
+        let mut command = fixture.command(&soda, dec!(1));
+        command.idempotency_key = Some("scan-1".to_string());
+
+        fixture
+            .use_case()
+            .execute(command, fixture.cashier_id, false)
+            .await
+            .unwrap();
+
+        assert_eq!(fixture.quick_sale_repo.committed().len(), 1);
+    }
+}
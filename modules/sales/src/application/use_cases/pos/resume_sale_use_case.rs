@@ -0,0 +1,85 @@
+//! Resume sale use case
+
+use std::sync::Arc;
+
+use chrono::{Duration, Utc};
+use uuid::Uuid;
+
+use inventory::{InventoryStockRepository, ReservationPriorityPolicy, ReservationRepository};
+
+use crate::SalesError;
+use crate::application::dtos::SaleDetailResponse;
+use crate::domain::repositories::SaleRepository;
+use crate::domain::value_objects::SaleId;
+
+use super::sale_reservations::reserve_sale_stock;
+
+/// How long stock re-reserved for a resumed sale is held
+pub const RESUMED_SALE_HOLD_MINUTES: i64 = 30;
+
+/// Use case for resuming a parked POS sale
+///
+/// Stock for every stock-tracked line is reserved again for the sale. When
+/// a line is no longer available the sale stays parked and nothing is
+/// reserved, so the cashier can adjust it after resolving the shortage.
+pub struct ResumeSaleUseCase<R, S>
+where
+    R: ReservationRepository,
+    S: InventoryStockRepository,
+{
+    sale_repo: Arc<dyn SaleRepository>,
+    reservation_repo: Arc<R>,
+    stock_repo: Arc<S>,
+    priority_policy: ReservationPriorityPolicy,
+}
+
+impl<R, S> ResumeSaleUseCase<R, S>
+where
+    R: ReservationRepository,
+    S: InventoryStockRepository,
+{
+    pub fn new(
+        sale_repo: Arc<dyn SaleRepository>,
+        reservation_repo: Arc<R>,
+        stock_repo: Arc<S>,
+        priority_policy: ReservationPriorityPolicy,
+    ) -> Self {
+        Self {
+            sale_repo,
+            reservation_repo,
+            stock_repo,
+            priority_policy,
+        }
+    }
+
+    pub async fn execute(&self, sale_id: Uuid) -> Result<SaleDetailResponse, SalesError> {
+        let mut sale = self
+            .sale_repo
+            .find_by_id_with_details(SaleId::from_uuid(sale_id))
+            .await?
+            .ok_or(SalesError::SaleNotFound(sale_id))?;
+
+        sale.resume()?;
+
+        let expires_at = Utc::now() + Duration::minutes(RESUMED_SALE_HOLD_MINUTES);
+        let reserved = reserve_sale_stock(
+            &self.reservation_repo,
+            &self.stock_repo,
+            &self.priority_policy,
+            &sale,
+            expires_at,
+        )
+        .await?;
+
+        for (item_id, reservation_id) in reserved {
+            if let Some(item) = sale.items_mut().iter_mut().find(|i| i.id() == item_id) {
+                item.set_reservation_id(Some(reservation_id));
+                self.sale_repo.update_item(item).await?;
+            }
+        }
+
+        self.sale_repo.update(&sale).await?;
+
+        Ok(SaleDetailResponse::from(sale))
+    }
+}
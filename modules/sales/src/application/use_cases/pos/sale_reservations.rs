@@ -0,0 +1,227 @@
+//! Inventory reservations held by a POS sale
+
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use uuid::Uuid;
+
+use inventory::application::helpers::{
+    DEFAULT_BASE_DELAY_MS, DEFAULT_MAX_ATTEMPTS, retry_on_conflict,
+};
+use inventory::{
+    CreateReservationCommand, CreateReservationUseCase, InventoryError, InventoryReservation,
+    InventoryStock, InventoryStockRepository, ReservationId, ReservationPriorityPolicy,
+    ReservationRepository, ReservationStatus,
+};
+
+use crate::SalesError;
+use crate::domain::entities::Sale;
+use crate::domain::value_objects::SaleItemId;
+
+/// Reference type used for reservations held by a POS sale
+pub const SALE_RESERVATION_REFERENCE: &str = "sale";
+
+/// Pending reservations referencing the sale plus those linked from its
+/// items
+async fn pending_sale_reservations<R>(
+    reservation_repo: &R,
+    sale: &Sale,
+) -> Result<Vec<InventoryReservation>, InventoryError>
+where
+    R: ReservationRepository,
+{
+    let mut reservations = reservation_repo
+        .find_by_reference(SALE_RESERVATION_REFERENCE, sale.id().into_uuid())
+        .await?;
+
+    let mut seen: HashSet<_> = reservations.iter().map(|r| r.id()).collect();
+    for reservation_id in sale.items().iter().filter_map(|i| i.reservation_id()) {
+        if seen.insert(reservation_id)
+            && let Some(reservation) = reservation_repo.find_by_id(reservation_id).await?
+        {
+            reservations.push(reservation);
+        }
+    }
+
+    reservations.retain(|r| r.status() == ReservationStatus::Pending);
+    Ok(reservations)
+}
+
+/// Releases every pending reservation held by the sale, returning how many
+/// were released. Item links to the reservations are left for the caller
+/// to clear.
+pub async fn release_sale_reservations<R, S>(
+    reservation_repo: &R,
+    stock_repo: &S,
+    sale: &Sale,
+) -> Result<usize, InventoryError>
+where
+    R: ReservationRepository,
+    S: InventoryStockRepository,
+{
+    let reservations = pending_sale_reservations(reservation_repo, sale).await?;
+    let released = reservations.len();
+    for mut reservation in reservations {
+        release_reservation(reservation_repo, stock_repo, &mut reservation).await?;
+    }
+    Ok(released)
+}
+
+/// Cancels a reservation and puts its quantity back on sale
+pub(super) async fn release_reservation<R, S>(
+    reservation_repo: &R,
+    stock_repo: &S,
+    reservation: &mut InventoryReservation,
+) -> Result<(), InventoryError>
+where
+    R: ReservationRepository,
+    S: InventoryStockRepository,
+{
+    reservation.cancel()?;
+
+    let stock_id = reservation.stock_id();
+    let quantity = reservation.quantity();
+
+    retry_on_conflict(DEFAULT_MAX_ATTEMPTS, DEFAULT_BASE_DELAY_MS, || async {
+        let mut stock = stock_repo
+            .find_by_id(stock_id)
+            .await?
+            .ok_or(InventoryError::StockNotFound(stock_id.into_uuid()))?;
+
+        let expected_version = stock.version();
+        stock.release(quantity)?;
+        stock.increment_version();
+
+        stock_repo
+            .update_with_version(&stock, expected_version)
+            .await
+    })
+    .await?;
+
+    reservation_repo.update(reservation).await
+}
+
+/// A sale line with a stock record at the sale's store
+struct StockedLine {
+    item_id: SaleItemId,
+    product_id: Uuid,
+    stock: InventoryStock,
+    quantity: Decimal,
+}
+
+/// Reserves the stock of every stock-tracked line of the sale until
+/// `expires_at`, returning each reserved line with its reservation.
+///
+/// Every line is checked before anything is reserved. When a line is short
+/// nothing is reserved; when stock runs out part way, the reservations made
+/// so far are released again.
+pub async fn reserve_sale_stock<R, S>(
+    reservation_repo: &Arc<R>,
+    stock_repo: &Arc<S>,
+    priority_policy: &ReservationPriorityPolicy,
+    sale: &Sale,
+    expires_at: DateTime<Utc>,
+) -> Result<Vec<(SaleItemId, ReservationId)>, SalesError>
+where
+    R: ReservationRepository,
+    S: InventoryStockRepository,
+{
+    let lines = stocked_lines(stock_repo.as_ref(), sale).await?;
+    let mut required: HashMap<_, Decimal> = HashMap::new();
+    for line in &lines {
+        *required.entry(line.stock.id()).or_default() += line.quantity;
+    }
+    if let Some(short) = lines
+        .iter()
+        .find(|l| l.stock.available_quantity() < required[&l.stock.id()])
+    {
+        return Err(SalesError::InsufficientStock(short.product_id));
+    }
+
+    let create = CreateReservationUseCase::new(
+        stock_repo.clone(),
+        reservation_repo.clone(),
+        priority_policy.clone(),
+    );
+    let mut reserved: Vec<(SaleItemId, ReservationId)> = Vec::with_capacity(lines.len());
+    for line in &lines {
+        let result = create
+            .execute(CreateReservationCommand {
+                stock_id: line.stock.id().into_uuid(),
+                reference_type: SALE_RESERVATION_REFERENCE.to_string(),
+                reference_id: sale.id().into_uuid(),
+                quantity: line.quantity,
+                expires_at,
+                auto_extend_seconds: None,
+            })
+            .await;
+        match result {
+            Ok(reservation) => {
+                reserved.push((line.item_id, ReservationId::from_uuid(reservation.id)));
+            }
+            Err(e) => {
+                release_all(reservation_repo.as_ref(), stock_repo.as_ref(), &reserved).await;
+                return Err(match e {
+                    InventoryError::InsufficientStock => {
+                        SalesError::InsufficientStock(line.product_id)
+                    }
+                    _ => SalesError::ReservationFailed,
+                });
+            }
+        }
+    }
+    Ok(reserved)
+}
+
+/// The sale's lines with a stock record at its store
+async fn stocked_lines<S>(stock_repo: &S, sale: &Sale) -> Result<Vec<StockedLine>, SalesError>
+where
+    S: InventoryStockRepository,
+{
+    let mut lines = Vec::new();
+    for item in sale.items() {
+        let stock = match item.variant_id() {
+            Some(variant_id) => {
+                stock_repo
+                    .find_by_store_and_variant(sale.store_id(), variant_id)
+                    .await
+            }
+            None => {
+                stock_repo
+                    .find_by_store_and_product(sale.store_id(), item.product_id())
+                    .await
+            }
+        }
+        .map_err(|_| SalesError::ReservationFailed)?;
+
+        // Lines without a stock record aren't inventory-tracked
+        if let Some(stock) = stock {
+            lines.push(StockedLine {
+                item_id: item.id(),
+                product_id: item.product_id().into_uuid(),
+                stock,
+                quantity: item.quantity(),
+            });
+        }
+    }
+    Ok(lines)
+}
+
+/// Best-effort release of reservations made before a failed reservation
+/// run
+async fn release_all<R, S>(
+    reservation_repo: &R,
+    stock_repo: &S,
+    reserved: &[(SaleItemId, ReservationId)],
+) where
+    R: ReservationRepository,
+    S: InventoryStockRepository,
+{
+    for (_, reservation_id) in reserved {
+        if let Ok(Some(mut reservation)) = reservation_repo.find_by_id(*reservation_id).await {
+            let _ = release_reservation(reservation_repo, stock_repo, &mut reservation).await;
+        }
+    }
+}
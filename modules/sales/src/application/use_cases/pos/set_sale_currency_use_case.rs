@@ -0,0 +1,62 @@
+//! Set sale currency use case
+
+use std::sync::Arc;
+
+use chrono::Utc;
+use rust_decimal::Decimal;
+
+use crate::SalesError;
+use crate::application::dtos::{SaleDetailResponse, SetSaleCurrencyCommand};
+use crate::domain::repositories::{ExchangeRateRepository, SaleRepository};
+use crate::domain::value_objects::SaleId;
+use inventory::Currency;
+
+/// Use case for quoting an open sale in another currency
+///
+/// The sale keeps its totals in the base currency and shows them converted
+/// at the store's current rate; the rate is captured again when the sale is
+/// completed.
+pub struct SetSaleCurrencyUseCase {
+    sale_repo: Arc<dyn SaleRepository>,
+    exchange_rate_repo: Arc<dyn ExchangeRateRepository>,
+}
+
+impl SetSaleCurrencyUseCase {
+    pub fn new(
+        sale_repo: Arc<dyn SaleRepository>,
+        exchange_rate_repo: Arc<dyn ExchangeRateRepository>,
+    ) -> Self {
+        Self {
+            sale_repo,
+            exchange_rate_repo,
+        }
+    }
+
+    pub async fn execute(
+        &self,
+        cmd: SetSaleCurrencyCommand,
+    ) -> Result<SaleDetailResponse, SalesError> {
+        let currency = Currency::new(&cmd.currency).map_err(|_| SalesError::InvalidCurrency)?;
+
+        let mut sale = self
+            .sale_repo
+            .find_by_id_with_details(SaleId::from_uuid(cmd.sale_id))
+            .await?
+            .ok_or(SalesError::SaleNotFound(cmd.sale_id))?;
+
+        let rate = if currency == *sale.currency() {
+            Decimal::ONE
+        } else {
+            self.exchange_rate_repo
+                .find_effective(sale.store_id().into_uuid(), &currency, Utc::now())
+                .await?
+                .ok_or_else(|| SalesError::ExchangeRateNotFound(currency.to_string()))?
+                .rate()
+        };
+
+        sale.set_transaction_currency(currency, rate)?;
+        self.sale_repo.update(&sale).await?;
+
+        Ok(SaleDetailResponse::from(sale))
+    }
+}
@@ -0,0 +1,159 @@
+//! Split-tender payments for POS sales
+
+use std::collections::HashMap;
+use std::str::FromStr;
+
+use chrono::Utc;
+use rust_decimal::Decimal;
+
+use crate::SalesError;
+use crate::application::dtos::PaymentAllocation;
+use crate::domain::entities::{Payment, Sale, normalize_gift_card_code};
+use crate::domain::repositories::{ExchangeRateRepository, GiftCardRepository};
+use crate::domain::value_objects::{GiftCardId, PaymentMethod};
+use inventory::Currency;
+
+/// Rates to the sale's base currency of the currencies an allocation
+/// tenders
+pub type TenderRates = HashMap<Currency, Decimal>;
+
+/// A gift card tender: the card and the amount to spend from it
+pub type GiftCardRedemption = (GiftCardId, Decimal);
+
+/// Looks up the store's current rate of every foreign currency tendered
+pub async fn tender_rates(
+    exchange_rate_repo: &dyn ExchangeRateRepository,
+    sale: &Sale,
+    allocations: &[PaymentAllocation],
+) -> Result<TenderRates, SalesError> {
+    let now = Utc::now();
+    let mut rates = TenderRates::new();
+    for allocation in allocations {
+        let currency = tender_currency(sale, allocation)?;
+        if currency == *sale.currency() || rates.contains_key(&currency) {
+            continue;
+        }
+        let rate = exchange_rate_repo
+            .find_effective(sale.store_id().into_uuid(), &currency, now)
+            .await?
+            .ok_or_else(|| SalesError::ExchangeRateNotFound(currency.to_string()))?;
+        rates.insert(currency, rate.rate());
+    }
+    Ok(rates)
+}
+
+fn tender_currency(sale: &Sale, allocation: &PaymentAllocation) -> Result<Currency, SalesError> {
+    match &allocation.currency {
+        Some(code) => Currency::new(code).map_err(|_| SalesError::InvalidCurrency),
+        None => Ok(sale.currency().clone()),
+    }
+}
+
+/// Builds one payment per allocation of a split-tender payment.
+///
+/// Tenders are taken at the counter, so each payment is recorded as
+/// completed. Allocations must be positive and cash may not be tendered
+/// short of its allocated amount. Amounts are in the tender currency, which
+/// is converted at its rate in `rates`.
+pub fn tender_payments(
+    sale: &Sale,
+    allocations: &[PaymentAllocation],
+    rates: &TenderRates,
+) -> Result<Vec<Payment>, SalesError> {
+    if allocations.is_empty() {
+        return Err(SalesError::NoPaymentAllocations);
+    }
+
+    allocations
+        .iter()
+        .map(|allocation| {
+            let payment_method = PaymentMethod::from_str(&allocation.payment_method)
+                .map_err(|_| SalesError::InvalidPaymentMethod)?;
+            let currency = tender_currency(sale, allocation)?;
+            let rate = if currency == *sale.currency() {
+                Decimal::ONE
+            } else {
+                *rates
+                    .get(&currency)
+                    .ok_or_else(|| SalesError::ExchangeRateNotFound(currency.to_string()))?
+            };
+
+            let mut payment = if payment_method.is_cash() {
+                let tendered = allocation.amount_tendered.unwrap_or(allocation.amount);
+                Payment::create_cash(sale.id(), allocation.amount, currency, tendered)?
+            } else {
+                let mut payment =
+                    Payment::create(sale.id(), payment_method, allocation.amount, currency)?;
+                payment.complete(None)?;
+                payment
+            };
+
+            payment.set_exchange_rate(rate)?;
+            payment.set_reference_number(allocation.reference.clone());
+            if let (Some(last_four), Some(brand)) =
+                (&allocation.card_last_four, &allocation.card_brand)
+            {
+                payment.set_card_details(last_four.clone(), brand.clone());
+            }
+            Ok(payment)
+        })
+        .collect()
+}
+
+/// Resolves the cards of the gift card tenders among `payments`.
+///
+/// `payments` must be the result of `tender_payments` for the same
+/// allocations. Cards are only accepted at the store that issued them. A tender larger than its card's balance is cut down to the
+/// balance, leaving the rest of the sale due. Cards are only read here; the
+/// returned redemptions are spent when the payment is saved, where the
+/// balance is checked again under a row lock.
+pub async fn gift_card_tenders(
+    gift_card_repo: &dyn GiftCardRepository,
+    sale: &Sale,
+    allocations: &[PaymentAllocation],
+    payments: &mut [Payment],
+) -> Result<Vec<GiftCardRedemption>, SalesError> {
+    // Balance left on each card once earlier tenders of this payment are spent
+    let mut balances: HashMap<GiftCardId, Decimal> = HashMap::new();
+    let mut redemptions = Vec::new();
+
+    for (allocation, payment) in allocations.iter().zip(payments.iter_mut()) {
+        if payment.payment_method() != PaymentMethod::GiftCard {
+            continue;
+        }
+        let code = allocation
+            .gift_card_code
+            .as_deref()
+            .ok_or(SalesError::GiftCardCodeRequired)?;
+        let card = gift_card_repo
+            .find_by_code(code)
+            .await?
+            .filter(|card| card.store_id() == sale.store_id())
+            .ok_or_else(|| SalesError::GiftCardNotFound(normalize_gift_card_code(code)))?;
+        if card.currency() != sale.currency() || payment.currency() != sale.currency() {
+            return Err(SalesError::GiftCardCurrencyMismatch);
+        }
+
+        let balance = balances.entry(card.id()).or_insert(card.current_balance());
+        let amount = card.redeemable_amount(payment.amount())?.min(*balance);
+        if amount.is_zero() {
+            return Err(SalesError::GiftCardDepleted);
+        }
+        *balance -= amount;
+
+        if amount < payment.amount() {
+            let mut capped = Payment::create(
+                sale.id(),
+                PaymentMethod::GiftCard,
+                amount,
+                card.currency().clone(),
+            )?;
+            capped.complete(None)?;
+            *payment = capped;
+        }
+        payment.set_reference_number(Some(card.code().to_string()));
+        redemptions.push((card.id(), amount));
+    }
+
+    Ok(redemptions)
+}
@@ -2,20 +2,30 @@
 
 use std::sync::Arc;
 
+use rust_decimal::Decimal;
+
 use crate::SalesError;
 use crate::application::dtos::{SaleDetailResponse, VoidSaleCommand};
-use crate::domain::repositories::SaleRepository;
-use crate::domain::value_objects::SaleId;
+use crate::domain::entities::{CustomerAccountEntry, Sale};
+use crate::domain::repositories::{CustomerRepository, SaleRepository};
+use crate::domain::value_objects::{AccountEntryType, SaleId, SaleStatus};
 use identity::UserId;
 
 /// Use case for voiding a sale
 pub struct VoidSaleUseCase {
     sale_repo: Arc<dyn SaleRepository>,
+    customer_repo: Arc<dyn CustomerRepository>,
 }
 
 impl VoidSaleUseCase {
-    pub fn new(sale_repo: Arc<dyn SaleRepository>) -> Self {
-        Self { sale_repo }
+    pub fn new(
+        sale_repo: Arc<dyn SaleRepository>,
+        customer_repo: Arc<dyn CustomerRepository>,
+    ) -> Self {
+        Self {
+            sale_repo,
+            customer_repo,
+        }
     }
 
     pub async fn execute(
@@ -31,12 +41,57 @@ impl VoidSaleUseCase {
             .await?
             .ok_or(SalesError::SaleNotFound(cmd.sale_id))?;
 
+        // A layaway is closed through its own cancellation, which settles
+        // the restocking fee and releases the stock it holds
+        if sale.status() == SaleStatus::Layaway {
+            return Err(SalesError::SaleOnLayaway);
+        }
+
         // Void the sale
         sale.void(voided_by, cmd.reason)?;
 
         // Update sale
         self.sale_repo.update(&sale).await?;
 
+        reverse_customer_charges(self.customer_repo.as_ref(), &sale, voided_by).await?;
+
         Ok(SaleDetailResponse::from(sale))
     }
 }
+
+/// Reverses what a voided sale charged to its customer: on-account charges
+/// are credited back and spent store credit is returned
+pub(crate) async fn reverse_customer_charges(
+    customer_repo: &dyn CustomerRepository,
+    sale: &Sale,
+    voided_by: UserId,
+) -> Result<(), SalesError> {
+    // Reverse whatever was charged to the customer's account
+    let on_account = sale.on_account_amount();
+    if let Some(customer_id) = sale.customer_id()
+        && on_account > Decimal::ZERO
+    {
+        let mut entry = CustomerAccountEntry::credit(
+            customer_id,
+            sale.store_id(),
+            AccountEntryType::SaleVoid,
+            on_account,
+            Some(sale.id().into_uuid()),
+            Some(sale.sale_number().to_string()),
+            Some(voided_by),
+        )?;
+        customer_repo.post_account_entry(&mut entry).await?;
+    }
+
+    // Give back whatever was spent from the customer's store credit
+    let store_credit = sale.store_credit_amount();
+    if let Some(customer_id) = sale.customer_id()
+        && store_credit > Decimal::ZERO
+    {
+        customer_repo
+            .adjust_store_credit(customer_id, store_credit)
+            .await?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,59 @@
+use std::sync::Arc;
+
+use crate::SalesError;
+use crate::application::dtos::price_list::{CreatePriceListCommand, PriceListResponse};
+use crate::domain::entities::PriceList;
+use crate::domain::repositories::PriceListRepository;
+use crate::domain::value_objects::CustomerType;
+use identity::UserId;
+
+/// Creates a price list for a store. Each customer type can be covered by
+/// one active list per store, and a store has at most one default list.
+pub struct CreatePriceListUseCase<L: PriceListRepository> {
+    price_list_repo: Arc<L>,
+}
+
+impl<L: PriceListRepository> CreatePriceListUseCase<L> {
+    pub fn new(price_list_repo: Arc<L>) -> Self {
+        Self { price_list_repo }
+    }
+
+    pub async fn execute(
+        &self,
+        command: CreatePriceListCommand,
+        actor_id: UserId,
+    ) -> Result<PriceListResponse, SalesError> {
+        let customer_types = command
+            .customer_types
+            .iter()
+            .map(|t| t.parse::<CustomerType>())
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let list = PriceList::create(
+            command.store_id,
+            command.name,
+            customer_types,
+            command.is_default,
+            actor_id,
+        )?;
+
+        let existing = self
+            .price_list_repo
+            .find_active_by_store(command.store_id)
+            .await?;
+        if list.is_default() && existing.iter().any(|l| l.is_default()) {
+            return Err(SalesError::PriceListAlreadyAssigned("default".to_string()));
+        }
+        if let Some(taken) = list
+            .customer_types()
+            .iter()
+            .find(|t| existing.iter().any(|l| l.is_assigned_to(**t)))
+        {
+            return Err(SalesError::PriceListAlreadyAssigned(taken.to_string()));
+        }
+
+        self.price_list_repo.save(&list).await?;
+
+        Ok(PriceListResponse::from(&list))
+    }
+}
@@ -0,0 +1,25 @@
+use std::sync::Arc;
+
+use uuid::Uuid;
+
+use crate::SalesError;
+use crate::application::dtos::price_list::PriceListResponse;
+use crate::domain::repositories::PriceListRepository;
+
+pub struct ListPriceListsUseCase<L: PriceListRepository> {
+    price_list_repo: Arc<L>,
+}
+
+impl<L: PriceListRepository> ListPriceListsUseCase<L> {
+    pub fn new(price_list_repo: Arc<L>) -> Self {
+        Self { price_list_repo }
+    }
+
+    pub async fn execute(
+        &self,
+        store_id: Option<Uuid>,
+    ) -> Result<Vec<PriceListResponse>, SalesError> {
+        let lists = self.price_list_repo.list(store_id).await?;
+        Ok(lists.iter().map(PriceListResponse::from).collect())
+    }
+}
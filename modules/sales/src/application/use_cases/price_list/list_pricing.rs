@@ -0,0 +1,34 @@
+//! Price list resolution shared by the POS and cart line use cases
+
+use rust_decimal::Decimal;
+use uuid::Uuid;
+
+use crate::SalesError;
+use crate::domain::entities::{PriceList, PriceListEntry};
+use crate::domain::repositories::PriceListRepository;
+use crate::domain::value_objects::{CustomerType, PriceListId};
+
+/// Resolves a product line's price from the store's price list for the
+/// customer type, or `None` when no list applies or the list has no entry
+/// for the product. Sales without a customer (`customer_type = None`) are
+/// priced from the store's default list.
+pub(crate) async fn resolve_list_price<L>(
+    price_list_repo: &L,
+    store_id: Uuid,
+    customer_type: Option<CustomerType>,
+    product_id: Uuid,
+    variant_id: Option<Uuid>,
+) -> Result<Option<(Decimal, PriceListId)>, SalesError>
+where
+    L: PriceListRepository,
+{
+    let lists = price_list_repo.find_active_by_store(store_id).await?;
+    let Some(list) = PriceList::select(&lists, customer_type) else {
+        return Ok(None);
+    };
+
+    let entries = price_list_repo
+        .find_applicable_entries(list.id(), product_id, variant_id)
+        .await?;
+    Ok(PriceListEntry::select(&entries, product_id, variant_id).map(|e| (e.price(), list.id())))
+}
@@ -0,0 +1,9 @@
+mod create_price_list_use_case;
+mod list_price_lists_use_case;
+mod list_pricing;
+mod set_price_list_entry_use_case;
+
+pub use create_price_list_use_case::CreatePriceListUseCase;
+pub use list_price_lists_use_case::ListPriceListsUseCase;
+pub(crate) use list_pricing::resolve_list_price;
+pub use set_price_list_entry_use_case::SetPriceListEntryUseCase;
@@ -0,0 +1,98 @@
+use std::sync::Arc;
+
+use uuid::Uuid;
+
+use crate::SalesError;
+use crate::application::dtos::price_list::{PriceListEntryResponse, SetPriceListEntryCommand};
+use crate::domain::entities::PriceListEntry;
+use crate::domain::repositories::PriceListRepository;
+use crate::domain::value_objects::PriceListId;
+use inventory::{InventoryError, ProductId, ProductRepository, VariantId};
+
+/// Sets the price of a product, or of one of its variants, on a price
+/// list, replacing the existing entry if there is one
+pub struct SetPriceListEntryUseCase<L, P>
+where
+    L: PriceListRepository,
+    P: ProductRepository,
+{
+    price_list_repo: Arc<L>,
+    product_repo: Arc<P>,
+}
+
+impl<L, P> SetPriceListEntryUseCase<L, P>
+where
+    L: PriceListRepository,
+    P: ProductRepository,
+{
+    pub fn new(price_list_repo: Arc<L>, product_repo: Arc<P>) -> Self {
+        Self {
+            price_list_repo,
+            product_repo,
+        }
+    }
+
+    pub async fn execute(
+        &self,
+        command: SetPriceListEntryCommand,
+    ) -> Result<PriceListEntryResponse, SalesError> {
+        let price_list_id = PriceListId::from_uuid(command.price_list_id);
+        self.price_list_repo
+            .find_by_id(price_list_id)
+            .await?
+            .ok_or(SalesError::PriceListNotFound(command.price_list_id))?;
+
+        self.product_repo
+            .find_by_id(ProductId::from_uuid(command.product_id))
+            .await
+            .map_err(|e| lookup_error(e, command.product_id))?
+            .ok_or(SalesError::ProductNotFound(command.product_id))?;
+
+        // A variant entry must belong to the product it prices
+        if let Some(variant_id) = command.variant_id {
+            let variant = self
+                .product_repo
+                .find_variant_by_id(VariantId::from_uuid(variant_id))
+                .await
+                .map_err(|e| lookup_error(e, variant_id))?
+                .ok_or(SalesError::ProductNotFound(variant_id))?;
+            if variant.product_id().into_uuid() != command.product_id {
+                return Err(SalesError::InvalidPriceList(
+                    "variant does not belong to the product".to_string(),
+                ));
+            }
+        }
+
+        let existing = self
+            .price_list_repo
+            .find_entry(price_list_id, command.product_id, command.variant_id)
+            .await?;
+
+        let entry = match existing {
+            Some(mut entry) => {
+                entry.set_price(command.price)?;
+                self.price_list_repo.update_entry(&entry).await?;
+                entry
+            }
+            None => {
+                let entry = PriceListEntry::create(
+                    price_list_id,
+                    command.product_id,
+                    command.variant_id,
+                    command.price,
+                )?;
+                self.price_list_repo.save_entry(&entry).await?;
+                entry
+            }
+        };
+
+        Ok(PriceListEntryResponse::from(&entry))
+    }
+}
+
+fn lookup_error(error: InventoryError, id: Uuid) -> SalesError {
+    match error {
+        InventoryError::Database(e) => SalesError::Database(e),
+        _ => SalesError::ProductNotFound(id),
+    }
+}
@@ -0,0 +1,25 @@
+use std::sync::Arc;
+
+use uuid::Uuid;
+
+use crate::SalesError;
+use crate::application::dtos::price_tier::PriceTierResponse;
+use crate::domain::repositories::PriceTierRepository;
+
+pub struct ListPriceTiersUseCase<T: PriceTierRepository> {
+    price_tier_repo: Arc<T>,
+}
+
+impl<T: PriceTierRepository> ListPriceTiersUseCase<T> {
+    pub fn new(price_tier_repo: Arc<T>) -> Self {
+        Self { price_tier_repo }
+    }
+
+    pub async fn execute(
+        &self,
+        store_id: Option<Uuid>,
+    ) -> Result<Vec<PriceTierResponse>, SalesError> {
+        let tiers = self.price_tier_repo.list(store_id).await?;
+        Ok(tiers.iter().map(PriceTierResponse::from).collect())
+    }
+}
@@ -0,0 +1,7 @@
+mod list_price_tiers_use_case;
+mod tier_pricing;
+mod upsert_price_tier_use_case;
+
+pub use list_price_tiers_use_case::ListPriceTiersUseCase;
+pub(crate) use tier_pricing::{pricing_customer_type, resolve_tier_price};
+pub use upsert_price_tier_use_case::UpsertPriceTierUseCase;
@@ -0,0 +1,47 @@
+//! Price tier resolution shared by the POS and cart line use cases
+
+use rust_decimal::Decimal;
+use uuid::Uuid;
+
+use crate::SalesError;
+use crate::domain::entities::PriceTier;
+use crate::domain::repositories::{CustomerRepository, PriceTierRepository};
+use crate::domain::value_objects::{CustomerId, CustomerType, PriceTierId};
+
+/// Returns the customer type pricing applies to. Walk-in sales without a
+/// customer are priced as individuals.
+pub(crate) async fn pricing_customer_type(
+    customer_repo: &dyn CustomerRepository,
+    customer_id: Option<CustomerId>,
+) -> Result<CustomerType, SalesError> {
+    let Some(customer_id) = customer_id else {
+        return Ok(CustomerType::default());
+    };
+    let customer = customer_repo
+        .find_by_id(customer_id)
+        .await?
+        .ok_or(SalesError::CustomerNotFound(customer_id.into_uuid()))?;
+    Ok(customer.customer_type())
+}
+
+/// Resolves a product's tier price at a store for a customer type, or
+/// `None` when no active tier applies and the base price stands
+pub(crate) async fn resolve_tier_price<T>(
+    price_tier_repo: &T,
+    store_id: Uuid,
+    customer_type: CustomerType,
+    product_id: Uuid,
+    category_id: Option<Uuid>,
+    base_price: Decimal,
+) -> Result<Option<(Decimal, PriceTierId)>, SalesError>
+where
+    T: PriceTierRepository,
+{
+    let tiers = price_tier_repo
+        .find_applicable(store_id, customer_type, product_id, category_id)
+        .await?;
+    Ok(
+        PriceTier::select(&tiers, customer_type, product_id, category_id)
+            .map(|tier| (tier.price_for(base_price), tier.id())),
+    )
+}
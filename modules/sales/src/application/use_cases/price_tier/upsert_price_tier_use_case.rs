@@ -0,0 +1,65 @@
+use std::sync::Arc;
+
+use crate::SalesError;
+use crate::application::dtos::price_tier::{PriceTierResponse, UpsertPriceTierCommand};
+use crate::domain::entities::PriceTier;
+use crate::domain::repositories::PriceTierRepository;
+use crate::domain::value_objects::CustomerType;
+use identity::UserId;
+
+/// Creates the price tier for a store, customer type and product/category,
+/// or reconfigures the existing one. Setting `is_active = false` returns
+/// the target to base pricing.
+pub struct UpsertPriceTierUseCase<T: PriceTierRepository> {
+    price_tier_repo: Arc<T>,
+}
+
+impl<T: PriceTierRepository> UpsertPriceTierUseCase<T> {
+    pub fn new(price_tier_repo: Arc<T>) -> Self {
+        Self { price_tier_repo }
+    }
+
+    pub async fn execute(
+        &self,
+        command: UpsertPriceTierCommand,
+        actor_id: UserId,
+    ) -> Result<PriceTierResponse, SalesError> {
+        let customer_type: CustomerType = command.customer_type.parse()?;
+
+        let existing = self
+            .price_tier_repo
+            .find_by_target(
+                command.store_id,
+                customer_type,
+                command.product_id,
+                command.category_id,
+            )
+            .await?;
+
+        let tier = match existing {
+            Some(mut tier) => {
+                tier.reconfigure(command.price, command.discount_percent, command.is_active)?;
+                self.price_tier_repo.update(&tier).await?;
+                tier
+            }
+            None => {
+                let mut tier = PriceTier::create(
+                    command.store_id,
+                    customer_type,
+                    command.product_id,
+                    command.category_id,
+                    command.price,
+                    command.discount_percent,
+                    actor_id,
+                )?;
+                if !command.is_active {
+                    tier.reconfigure(command.price, command.discount_percent, false)?;
+                }
+                self.price_tier_repo.save(&tier).await?;
+                tier
+            }
+        };
+
+        Ok(PriceTierResponse::from(&tier))
+    }
+}
@@ -45,7 +45,7 @@ impl<P: PromotionRepository, S: SaleRepository> ApplyPromotionUseCase<P, S> {
         let discount_amount = promotion.calculate_discount(sale.subtotal());
 
         if discount_amount > rust_decimal::Decimal::ZERO {
-            sale.apply_fixed_discount(discount_amount)?;
+            sale.apply_promotion_discount(promotion.id(), discount_amount)?;
         }
 
         // Increment usage
@@ -0,0 +1,189 @@
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+
+use chrono::Utc;
+use rust_decimal::Decimal;
+use uuid::Uuid;
+
+use crate::SalesError;
+use crate::application::dtos::{
+    EvaluatePromotionsCommand, PromotionDiscountResponse, PromotionEvaluationResponse,
+    SaleDetailResponse,
+};
+use crate::domain::entities::Promotion;
+use crate::domain::repositories::{PromotionRepository, SaleRepository};
+use crate::domain::value_objects::{PromotionId, PromotionLine, SaleId};
+use inventory::{InventoryError, ProductRepository};
+
+/// Evaluates the store's active promotions against a draft sale and, when
+/// asked to, applies the best combination of their discounts
+pub struct EvaluatePromotionsUseCase<P, S, R>
+where
+    P: PromotionRepository,
+    S: SaleRepository,
+    R: ProductRepository,
+{
+    promotion_repo: Arc<P>,
+    sale_repo: Arc<S>,
+    product_repo: Arc<R>,
+}
+
+impl<P, S, R> EvaluatePromotionsUseCase<P, S, R>
+where
+    P: PromotionRepository,
+    S: SaleRepository,
+    R: ProductRepository,
+{
+    pub fn new(promotion_repo: Arc<P>, sale_repo: Arc<S>, product_repo: Arc<R>) -> Self {
+        Self {
+            promotion_repo,
+            sale_repo,
+            product_repo,
+        }
+    }
+
+    pub async fn execute(
+        &self,
+        command: EvaluatePromotionsCommand,
+    ) -> Result<PromotionEvaluationResponse, SalesError> {
+        let mut sale = self
+            .sale_repo
+            .find_by_id_with_details(SaleId::from_uuid(command.sale_id))
+            .await?
+            .ok_or(SalesError::SaleNotFound(command.sale_id))?;
+
+        if !sale.is_editable() {
+            return Err(SalesError::SaleNotEditable);
+        }
+
+        let store_id = sale.store_id().into_uuid();
+        let now = Utc::now();
+        let promotions: Vec<Promotion> = self
+            .promotion_repo
+            .find_active_by_store(Some(store_id))
+            .await?
+            .into_iter()
+            .filter(|p| p.is_available_at(store_id, now))
+            .collect();
+
+        // Category lookups are cached, a sale often repeats a product
+        let mut categories: HashMap<Uuid, Option<Uuid>> = HashMap::new();
+        let mut lines = Vec::with_capacity(sale.items().len());
+        for item in sale.items() {
+            let product_id = item.product_id().into_uuid();
+            let category_id = match categories.get(&product_id) {
+                Some(category_id) => *category_id,
+                None => {
+                    let category_id = self
+                        .product_repo
+                        .find_by_id(item.product_id())
+                        .await
+                        .map_err(|e| lookup_error(e, product_id))?
+                        .and_then(|p| p.category_id())
+                        .map(|c| c.into_uuid());
+                    categories.insert(product_id, category_id);
+                    category_id
+                }
+            };
+
+            // Discounts granted by an earlier evaluation are up for grabs again
+            let manual_discount = if item.promotion_id().is_none() {
+                item.discount_amount()
+            } else {
+                Decimal::ZERO
+            };
+
+            lines.push(PromotionLine {
+                sale_item_id: item.id(),
+                product_id,
+                category_id,
+                quantity: item.quantity(),
+                unit_price: item.unit_price(),
+                subtotal: item.subtotal(),
+                manual_discount,
+            });
+        }
+
+        // A discount given by hand on the whole sale takes precedence over
+        // order-level promotions
+        let manual_order_discount = sale.discount_type().is_some() && sale.promotion_id().is_none();
+
+        let mut applicable: Vec<_> = promotions.iter().flat_map(|p| p.evaluate(&lines)).collect();
+        let mut best = Promotion::best_combination(&promotions, &lines);
+        if manual_order_discount {
+            applicable.retain(|d| !d.is_order_level());
+            best.retain(|d| !d.is_order_level());
+        }
+
+        if command.auto_apply {
+            let mut previously_applied: HashSet<PromotionId> = sale
+                .items()
+                .iter()
+                .filter_map(|i| i.promotion_id())
+                .collect();
+            previously_applied.extend(sale.promotion_id());
+
+            let mut changed_items = Vec::new();
+            for item in sale.items_mut() {
+                match best.iter().find(|d| d.sale_item_id == Some(item.id())) {
+                    Some(discount) => {
+                        item.apply_promotion_discount(discount.promotion_id, discount.amount)?;
+                        changed_items.push(item.id());
+                    }
+                    None if item.promotion_id().is_some() => {
+                        item.remove_discount();
+                        changed_items.push(item.id());
+                    }
+                    None => {}
+                }
+            }
+
+            match best.iter().find(|d| d.is_order_level()) {
+                Some(discount) => {
+                    sale.apply_promotion_discount(discount.promotion_id, discount.amount)?
+                }
+                None => sale.remove_promotion_discount()?,
+            }
+            sale.recalculate_totals();
+
+            for item in sale
+                .items()
+                .iter()
+                .filter(|i| changed_items.contains(&i.id()))
+            {
+                self.sale_repo.update_item(item).await?;
+            }
+            self.sale_repo.update(&sale).await?;
+
+            // Count one use per promotion newly applied to the sale
+            let used: HashSet<PromotionId> = best
+                .iter()
+                .map(|d| d.promotion_id)
+                .filter(|id| !previously_applied.contains(id))
+                .collect();
+            for mut promotion in promotions.into_iter().filter(|p| used.contains(&p.id())) {
+                promotion.increment_usage();
+                self.promotion_repo.update(&promotion).await?;
+            }
+        }
+
+        let total_discount = best.iter().map(|d| d.amount).sum();
+        Ok(PromotionEvaluationResponse {
+            applicable: applicable
+                .iter()
+                .map(PromotionDiscountResponse::from)
+                .collect(),
+            best: best.iter().map(PromotionDiscountResponse::from).collect(),
+            total_discount,
+            applied: command.auto_apply,
+            sale: SaleDetailResponse::from(sale),
+        })
+    }
+}
+
+fn lookup_error(error: InventoryError, id: Uuid) -> SalesError {
+    match error {
+        InventoryError::Database(e) => SalesError::Database(e),
+        _ => SalesError::ProductNotFound(id),
+    }
+}
@@ -1,5 +1,6 @@
 use std::sync::Arc;
 
+use common::PageCursor;
 use serde::Deserialize;
 
 use crate::SalesError;
@@ -13,6 +14,8 @@ pub struct ListPromotionsQuery {
     pub search: Option<String>,
     pub page: i64,
     pub page_size: i64,
+    /// `next_cursor` of a previous page; when set, `page` is ignored
+    pub after_cursor: Option<String>,
 }
 
 pub struct ListPromotionsUseCase<P: PromotionRepository> {
@@ -27,21 +30,28 @@ impl<P: PromotionRepository> ListPromotionsUseCase<P> {
     pub async fn execute(
         &self,
         query: ListPromotionsQuery,
-    ) -> Result<(Vec<PromotionResponse>, i64), SalesError> {
+    ) -> Result<(Vec<PromotionResponse>, i64, Option<String>), SalesError> {
+        let after = PageCursor::decode_opt(query.after_cursor.as_deref())?;
         let filter = PromotionFilter {
             status: query.status,
             store_id: query.store_id,
             search: query.search,
+            after_id: after.map(|c| c.id()),
         };
 
         let (promotions, total) = self
             .promotion_repo
             .find_paginated(filter, query.page, query.page_size)
             .await?;
+        let next_cursor = PageCursor::next(
+            promotions.last().map(|p| p.id().into_uuid()),
+            promotions.len(),
+            query.page_size,
+        );
 
         let responses: Vec<PromotionResponse> =
             promotions.iter().map(PromotionResponse::from).collect();
 
-        Ok((responses, total))
+        Ok((responses, total, next_cursor))
     }
 }
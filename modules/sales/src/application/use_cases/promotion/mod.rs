@@ -1,6 +1,7 @@
 mod apply_promotion_use_case;
 mod create_promotion_use_case;
 mod deactivate_promotion_use_case;
+mod evaluate_promotions_use_case;
 mod get_promotion_use_case;
 mod list_promotions_use_case;
 mod update_promotion_use_case;
@@ -8,6 +9,7 @@ mod update_promotion_use_case;
 pub use apply_promotion_use_case::ApplyPromotionUseCase;
 pub use create_promotion_use_case::CreatePromotionUseCase;
 pub use deactivate_promotion_use_case::DeactivatePromotionUseCase;
+pub use evaluate_promotions_use_case::EvaluatePromotionsUseCase;
 pub use get_promotion_use_case::GetPromotionUseCase;
 pub use list_promotions_use_case::{ListPromotionsQuery, ListPromotionsUseCase};
 pub use update_promotion_use_case::UpdatePromotionUseCase;
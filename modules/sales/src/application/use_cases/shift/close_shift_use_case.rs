@@ -2,20 +2,33 @@
 
 use std::sync::Arc;
 
+use rust_decimal::Decimal;
+
 use crate::SalesError;
 use crate::application::dtos::{CloseShiftCommand, ShiftResponse};
 use crate::domain::repositories::ShiftRepository;
-use crate::domain::value_objects::ShiftId;
+use crate::domain::value_objects::{ShiftCount, ShiftId};
 use identity::UserId;
 
+/// Default variance a closing count may show without a reason
+pub const DEFAULT_SHIFT_VARIANCE_THRESHOLD: Decimal = Decimal::ONE_HUNDRED;
+
 /// Use case for closing a cashier shift
+///
+/// The counted cash is reconciled against the expected drawer, and card and
+/// other totals against the shift's sales when they are counted. A count off
+/// by more than the variance threshold needs a variance reason.
 pub struct CloseShiftUseCase {
     shift_repo: Arc<dyn ShiftRepository>,
+    variance_threshold: Decimal,
 }
 
 impl CloseShiftUseCase {
-    pub fn new(shift_repo: Arc<dyn ShiftRepository>) -> Self {
-        Self { shift_repo }
+    pub fn new(shift_repo: Arc<dyn ShiftRepository>, variance_threshold: Decimal) -> Self {
+        Self {
+            shift_repo,
+            variance_threshold,
+        }
     }
 
     pub async fn execute(
@@ -37,7 +50,17 @@ impl CloseShiftUseCase {
         }
 
         // Close the shift
-        shift.close(cmd.closing_balance, cmd.closing_notes)?;
+        let count = ShiftCount {
+            cash: cmd.closing_balance,
+            card: cmd.card_count,
+            other: cmd.other_count,
+        };
+        shift.close(
+            count,
+            cmd.closing_notes,
+            cmd.variance_reason,
+            self.variance_threshold,
+        )?;
 
         // Save changes
         self.shift_repo.update(&shift).await?;
@@ -6,9 +6,12 @@ use uuid::Uuid;
 use crate::SalesError;
 use crate::application::dtos::ShiftResponse;
 use crate::domain::repositories::ShiftRepository;
+use identity::UserContext;
 use pos_core::TerminalId;
 
 /// Use case for getting the current open shift for a terminal
+///
+/// A terminal of a store the caller is not assigned to reports no open shift.
 pub struct GetCurrentShiftUseCase {
     shift_repo: Arc<dyn ShiftRepository>,
 }
@@ -18,15 +21,76 @@ impl GetCurrentShiftUseCase {
         Self { shift_repo }
     }
 
-    pub async fn execute(&self, terminal_id: Uuid) -> Result<ShiftResponse, SalesError> {
+    pub async fn execute(
+        &self,
+        terminal_id: Uuid,
+        ctx: &UserContext,
+    ) -> Result<ShiftResponse, SalesError> {
         let terminal_id = TerminalId::from_uuid(terminal_id);
 
         let shift = self
             .shift_repo
             .find_open_by_terminal(terminal_id)
             .await?
+            .filter(|s| ctx.can_access_store(s.store_id().into_uuid()))
             .ok_or(SalesError::NoOpenShift)?;
 
         Ok(ShiftResponse::from(shift))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::use_cases::test_support::MockShiftRepository;
+    use crate::domain::entities::CashierShift;
+    use identity::{StoreId, UserId};
+    use rust_decimal_macros::dec;
+
+    /// A use case holding one open shift, with its terminal and store
+    fn setup() -> (GetCurrentShiftUseCase, Uuid, Uuid) {
+        let store_id = StoreId::new();
+        let terminal_id = TerminalId::new();
+        let shift = CashierShift::create(store_id, terminal_id, UserId::new(), dec!(100)).unwrap();
+        let repo = MockShiftRepository::new();
+        repo.add_shift(shift);
+        (
+            GetCurrentShiftUseCase::new(Arc::new(repo)),
+            terminal_id.into_uuid(),
+            store_id.into_uuid(),
+        )
+    }
+
+    fn context(stores: Vec<Uuid>) -> UserContext {
+        UserContext::new(
+            UserId::new(),
+            StoreId::new(),
+            Default::default(),
+            stores,
+            false,
+            None,
+            None,
+        )
+    }
+
+    #[tokio::test]
+    async fn test_assigned_user_gets_current_shift() {
+        let (use_case, terminal_id, store_id) = setup();
+
+        let shift = use_case
+            .execute(terminal_id, &context(vec![store_id]))
+            .await
+            .unwrap();
+        assert_eq!(shift.terminal_id, terminal_id);
+    }
+
+    #[tokio::test]
+    async fn test_terminal_of_another_store_has_no_open_shift() {
+        let (use_case, terminal_id, _) = setup();
+
+        let result = use_case
+            .execute(terminal_id, &context(vec![StoreId::new().into_uuid()]))
+            .await;
+        assert!(matches!(result, Err(SalesError::NoOpenShift)));
+    }
+}
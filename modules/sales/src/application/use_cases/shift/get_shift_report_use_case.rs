@@ -3,14 +3,22 @@
 use std::sync::Arc;
 use uuid::Uuid;
 
+use chrono::Utc;
 use rust_decimal::Decimal;
 
 use crate::SalesError;
-use crate::application::dtos::{SalesBreakdown, ShiftReportResponse, ShiftResponse};
+use crate::application::dtos::{
+    PaymentBreakdownItem, SalesBreakdown, ShiftReportResponse, ShiftResponse,
+};
+use crate::domain::entities::CashierShift;
 use crate::domain::repositories::ShiftRepository;
 use crate::domain::value_objects::ShiftId;
+use identity::UserContext;
 
-/// Use case for getting a detailed shift report
+/// Use case for getting a detailed shift report (the Z-report once the shift
+/// is closed)
+///
+/// Shifts of stores the caller is not assigned to are reported as not found.
 pub struct GetShiftReportUseCase {
     shift_repo: Arc<dyn ShiftRepository>,
 }
@@ -20,54 +28,241 @@ impl GetShiftReportUseCase {
         Self { shift_repo }
     }
 
-    pub async fn execute(&self, shift_id: Uuid) -> Result<ShiftReportResponse, SalesError> {
+    pub async fn execute(
+        &self,
+        shift_id: Uuid,
+        ctx: &UserContext,
+    ) -> Result<ShiftReportResponse, SalesError> {
         let id = ShiftId::from_uuid(shift_id);
 
         let shift = self
             .shift_repo
             .find_by_id(id)
             .await?
+            .filter(|s| ctx.can_access_store(s.store_id().into_uuid()))
             .ok_or(SalesError::ShiftNotFound(shift_id))?;
 
-        let net_sales = shift.total_sales() - shift.refunds();
-        let average_transaction = if shift.transaction_count() > 0 {
-            net_sales / Decimal::from(shift.transaction_count())
-        } else {
-            Decimal::ZERO
-        };
-
-        let sales_breakdown = SalesBreakdown {
-            total_sales: shift.total_sales(),
-            total_refunds: shift.refunds(),
-            net_sales,
-            transaction_count: shift.transaction_count(),
-            average_transaction,
-        };
-
-        // Payment breakdown would normally come from analyzing sales data
-        // For now, we return the aggregate data from the shift
-        let payment_breakdown = vec![
-            crate::application::dtos::PaymentBreakdownItem {
-                payment_method: "cash".to_string(),
-                amount: shift.cash_sales(),
-                count: 0, // Would need to aggregate from sales
-            },
-            crate::application::dtos::PaymentBreakdownItem {
-                payment_method: "card".to_string(),
-                amount: shift.card_sales(),
-                count: 0,
-            },
-            crate::application::dtos::PaymentBreakdownItem {
-                payment_method: "other".to_string(),
-                amount: shift.other_sales(),
-                count: 0,
-            },
-        ];
-
-        Ok(ShiftReportResponse {
-            shift: ShiftResponse::from(shift),
-            sales_breakdown,
-            payment_breakdown,
-        })
+        Ok(shift_report(shift))
+    }
+}
+
+/// Builds the report of a shift; open shifts are reported as snapshots
+pub(super) fn shift_report(shift: CashierShift) -> ShiftReportResponse {
+    let net_sales = shift.total_sales() - shift.refunds();
+    let average_transaction = if shift.transaction_count() > 0 {
+        net_sales / Decimal::from(shift.transaction_count())
+    } else {
+        Decimal::ZERO
+    };
+
+    let sales_breakdown = SalesBreakdown {
+        total_sales: shift.total_sales(),
+        total_refunds: shift.refunds(),
+        net_sales,
+        transaction_count: shift.transaction_count(),
+        average_transaction,
+    };
+
+    // Payment breakdown would normally come from analyzing sales data
+    // For now, we return the aggregate data from the shift, reconciled
+    // against the closing count
+    let payment_breakdown = vec![
+        PaymentBreakdownItem {
+            payment_method: "cash".to_string(),
+            amount: shift.cash_sales(),
+            count: 0, // Would need to aggregate from sales
+            expected: shift.expected_cash(),
+            counted: shift.closing_balance(),
+            variance: shift.variance(),
+        },
+        PaymentBreakdownItem {
+            payment_method: "card".to_string(),
+            amount: shift.card_sales(),
+            count: 0,
+            expected: shift.card_sales(),
+            counted: shift.card_count(),
+            variance: shift.card_variance(),
+        },
+        PaymentBreakdownItem {
+            payment_method: "other".to_string(),
+            amount: shift.other_sales(),
+            count: 0,
+            expected: shift.other_sales(),
+            counted: shift.other_count(),
+            variance: shift.other_variance(),
+        },
+    ];
+
+    ShiftReportResponse {
+        snapshot: shift.is_open(),
+        generated_at: Utc::now(),
+        shift: ShiftResponse::from(shift),
+        sales_breakdown,
+        payment_breakdown,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::use_cases::test_support::MockShiftRepository;
+    use crate::domain::value_objects::ShiftCount;
+    use identity::{StoreId, UserId};
+    use pos_core::TerminalId;
+    use rust_decimal_macros::dec;
+
+    /// A use case holding one open shift, with the shift's id and store
+    fn setup() -> (GetShiftReportUseCase, Uuid, Uuid) {
+        setup_with(
+            CashierShift::create(StoreId::new(), TerminalId::new(), UserId::new(), dec!(100))
+                .unwrap(),
+        )
+    }
+
+    /// A use case holding the given shift, with the shift's id and store
+    fn setup_with(shift: CashierShift) -> (GetShiftReportUseCase, Uuid, Uuid) {
+        let id = shift.id().into_uuid();
+        let store_id = shift.store_id().into_uuid();
+        let repo = MockShiftRepository::new();
+        repo.add_shift(shift);
+        (GetShiftReportUseCase::new(Arc::new(repo)), id, store_id)
+    }
+
+    /// An open shift with a 100 float, three sales (cash 50, card 30,
+    /// other 20) and a 10 cash refund
+    fn shift_with_sales() -> CashierShift {
+        let mut shift =
+            CashierShift::create(StoreId::new(), TerminalId::new(), UserId::new(), dec!(100))
+                .unwrap();
+        shift.record_cash_sale(dec!(50)).unwrap();
+        shift.record_card_sale(dec!(30)).unwrap();
+        shift.record_other_sale(dec!(20)).unwrap();
+        shift.record_cash_refund(dec!(10)).unwrap();
+        shift
+    }
+
+    fn context(stores: Vec<Uuid>, is_super_admin: bool) -> UserContext {
+        UserContext::new(
+            UserId::new(),
+            StoreId::new(),
+            Default::default(),
+            stores,
+            is_super_admin,
+            None,
+            None,
+        )
+    }
+
+    #[tokio::test]
+    async fn test_assigned_user_gets_shift_report() {
+        let (use_case, id, store_id) = setup();
+
+        let report = use_case
+            .execute(id, &context(vec![store_id], false))
+            .await
+            .unwrap();
+        assert_eq!(report.shift.id, id);
+    }
+
+    #[tokio::test]
+    async fn test_shift_report_of_another_store_is_not_found() {
+        let (use_case, id, _) = setup();
+
+        let result = use_case
+            .execute(id, &context(vec![StoreId::new().into_uuid()], false))
+            .await;
+        assert!(matches!(
+            result,
+            Err(SalesError::ShiftNotFound(not_found)) if not_found == id
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_super_admin_gets_shift_report_of_any_store() {
+        let (use_case, id, _) = setup();
+
+        let report = use_case.execute(id, &context(vec![], true)).await.unwrap();
+        assert_eq!(report.shift.id, id);
+    }
+
+    #[test]
+    fn test_open_shift_is_reported_as_snapshot_with_running_totals() {
+        let report = shift_report(shift_with_sales());
+
+        assert!(report.snapshot);
+        assert_eq!(report.shift.status, "open");
+        assert_eq!(report.sales_breakdown.total_sales, dec!(100));
+        assert_eq!(report.sales_breakdown.total_refunds, dec!(10));
+        assert_eq!(report.sales_breakdown.net_sales, dec!(90));
+        assert_eq!(report.sales_breakdown.transaction_count, 3);
+        assert_eq!(report.sales_breakdown.average_transaction, dec!(30));
+
+        let cash = &report.payment_breakdown[0];
+        assert_eq!(cash.payment_method, "cash");
+        assert_eq!(cash.amount, dec!(50));
+        assert_eq!(cash.expected, dec!(140));
+        assert_eq!(cash.counted, None);
+        assert_eq!(cash.variance, None);
+    }
+
+    #[test]
+    fn test_closed_shift_is_reported_as_final_report() {
+        let mut shift = shift_with_sales();
+        shift
+            .close(
+                ShiftCount {
+                    cash: dec!(135),
+                    card: Some(dec!(30)),
+                    other: Some(dec!(20)),
+                },
+                None,
+                Some("Short change given".to_string()),
+                Decimal::ZERO,
+            )
+            .unwrap();
+
+        let report = shift_report(shift);
+
+        assert!(!report.snapshot);
+        assert_eq!(report.shift.status, "closed");
+        assert_eq!(report.sales_breakdown.net_sales, dec!(90));
+
+        let cash = &report.payment_breakdown[0];
+        assert_eq!(cash.expected, dec!(140));
+        assert_eq!(cash.counted, Some(dec!(135)));
+        assert_eq!(cash.variance, Some(dec!(-5)));
+
+        let card = &report.payment_breakdown[1];
+        assert_eq!(card.counted, Some(dec!(30)));
+        assert_eq!(card.variance, Some(Decimal::ZERO));
+    }
+
+    #[test]
+    fn test_shift_without_transactions_has_zero_average() {
+        let shift =
+            CashierShift::create(StoreId::new(), TerminalId::new(), UserId::new(), dec!(100))
+                .unwrap();
+
+        let report = shift_report(shift);
+
+        assert_eq!(report.sales_breakdown.transaction_count, 0);
+        assert_eq!(report.sales_breakdown.average_transaction, Decimal::ZERO);
+    }
+
+    #[tokio::test]
+    async fn test_closed_shift_report_is_not_a_snapshot() {
+        let mut shift = shift_with_sales();
+        shift
+            .close(ShiftCount::cash(dec!(140)), None, None, Decimal::ZERO)
+            .unwrap();
+        let (use_case, id, store_id) = setup_with(shift);
+
+        let report = use_case
+            .execute(id, &context(vec![store_id], false))
+            .await
+            .unwrap();
+        assert!(!report.snapshot);
+        assert_eq!(report.shift.closing_balance, Some(dec!(140)));
+        assert_eq!(report.payment_breakdown[0].variance, Some(Decimal::ZERO));
     }
 }
@@ -0,0 +1,141 @@
+//! Get shift snapshot use case
+
+use std::sync::Arc;
+use uuid::Uuid;
+
+use super::get_shift_report_use_case::shift_report;
+use crate::SalesError;
+use crate::application::dtos::ShiftReportResponse;
+use crate::domain::repositories::ShiftRepository;
+use crate::domain::value_objects::ShiftId;
+use identity::UserContext;
+
+/// Use case for the X-report: a snapshot of an open shift's running totals.
+///
+/// Read-only, so it can be run any number of times during the shift; the
+/// shift's status and counters are left untouched. Shifts of stores the
+/// caller is not assigned to are reported as not found.
+pub struct GetShiftSnapshotUseCase {
+    shift_repo: Arc<dyn ShiftRepository>,
+}
+
+impl GetShiftSnapshotUseCase {
+    pub fn new(shift_repo: Arc<dyn ShiftRepository>) -> Self {
+        Self { shift_repo }
+    }
+
+    pub async fn execute(
+        &self,
+        shift_id: Uuid,
+        ctx: &UserContext,
+    ) -> Result<ShiftReportResponse, SalesError> {
+        let shift = self
+            .shift_repo
+            .find_by_id(ShiftId::from_uuid(shift_id))
+            .await?
+            .filter(|s| ctx.can_access_store(s.store_id().into_uuid()))
+            .ok_or(SalesError::ShiftNotFound(shift_id))?;
+
+        shift.validate_open()?;
+
+        Ok(shift_report(shift))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::use_cases::test_support::MockShiftRepository;
+    use crate::domain::entities::CashierShift;
+    use crate::domain::value_objects::ShiftCount;
+    use identity::{StoreId, UserId};
+    use pos_core::TerminalId;
+    use rust_decimal::Decimal;
+    use rust_decimal_macros::dec;
+
+    /// A use case holding one open shift, with the shift's id and store
+    fn setup() -> (GetShiftSnapshotUseCase, Uuid, Uuid) {
+        setup_with(open_shift())
+    }
+
+    /// A use case holding the given shift, with the shift's id and store
+    fn setup_with(shift: CashierShift) -> (GetShiftSnapshotUseCase, Uuid, Uuid) {
+        let id = shift.id().into_uuid();
+        let store_id = shift.store_id().into_uuid();
+        let repo = MockShiftRepository::new();
+        repo.add_shift(shift);
+        (GetShiftSnapshotUseCase::new(Arc::new(repo)), id, store_id)
+    }
+
+    fn open_shift() -> CashierShift {
+        CashierShift::create(StoreId::new(), TerminalId::new(), UserId::new(), dec!(100)).unwrap()
+    }
+
+    fn context(stores: Vec<Uuid>, is_super_admin: bool) -> UserContext {
+        UserContext::new(
+            UserId::new(),
+            StoreId::new(),
+            Default::default(),
+            stores,
+            is_super_admin,
+            None,
+            None,
+        )
+    }
+
+    #[tokio::test]
+    async fn test_assigned_user_gets_snapshot() {
+        let (use_case, id, store_id) = setup();
+
+        let report = use_case
+            .execute(id, &context(vec![store_id], false))
+            .await
+            .unwrap();
+        assert_eq!(report.shift.id, id);
+    }
+
+    #[tokio::test]
+    async fn test_snapshot_of_another_store_is_not_found() {
+        let (use_case, id, _) = setup();
+
+        let result = use_case
+            .execute(id, &context(vec![StoreId::new().into_uuid()], false))
+            .await;
+        assert!(matches!(
+            result,
+            Err(SalesError::ShiftNotFound(not_found)) if not_found == id
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_snapshot_of_open_shift_has_running_totals() {
+        let mut shift = open_shift();
+        shift.record_cash_sale(dec!(50)).unwrap();
+        shift.record_card_sale(dec!(30)).unwrap();
+        shift.record_cash_in(dec!(20)).unwrap();
+        let (use_case, id, store_id) = setup_with(shift);
+
+        let report = use_case
+            .execute(id, &context(vec![store_id], false))
+            .await
+            .unwrap();
+        assert!(report.snapshot);
+        assert_eq!(report.shift.status, "open");
+        assert_eq!(report.sales_breakdown.total_sales, dec!(80));
+        assert_eq!(report.sales_breakdown.transaction_count, 2);
+        assert_eq!(report.payment_breakdown[0].expected, dec!(170));
+        assert_eq!(report.payment_breakdown[0].counted, None);
+    }
+
+    #[tokio::test]
+    async fn test_snapshot_of_closed_shift_is_rejected() {
+        let mut shift = open_shift();
+        shift
+            .close(ShiftCount::cash(dec!(100)), None, None, Decimal::ZERO)
+            .unwrap();
+        let (use_case, id, store_id) = setup_with(shift);
+
+        let result = use_case.execute(id, &context(vec![store_id], false)).await;
+        assert!(matches!(result, Err(SalesError::ShiftAlreadyClosed)));
+    }
+}
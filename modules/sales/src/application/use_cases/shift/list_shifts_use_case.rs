@@ -3,14 +3,19 @@
 use std::str::FromStr;
 use std::sync::Arc;
 
+use common::PageCursor;
+
 use crate::SalesError;
 use crate::application::dtos::{ListShiftsQuery, ShiftListResponse, ShiftResponse};
 use crate::domain::repositories::{ShiftFilter, ShiftRepository};
-use crate::domain::value_objects::ShiftStatus;
-use identity::{StoreId, UserId};
+use crate::domain::value_objects::{ShiftId, ShiftStatus};
+use identity::{StoreId, StoreScope, UserContext, UserId};
 use pos_core::TerminalId;
 
 /// Use case for listing shifts with filters and pagination
+///
+/// Shifts are limited to the caller's assigned stores; asking for a store the
+/// caller is not assigned to is rejected.
 pub struct ListShiftsUseCase {
     shift_repo: Arc<dyn ShiftRepository>,
 }
@@ -20,18 +25,33 @@ impl ListShiftsUseCase {
         Self { shift_repo }
     }
 
-    pub async fn execute(&self, query: ListShiftsQuery) -> Result<ShiftListResponse, SalesError> {
+    pub async fn execute(
+        &self,
+        query: ListShiftsQuery,
+        ctx: &UserContext,
+    ) -> Result<ShiftListResponse, SalesError> {
+        let store_ids = match ctx.store_scope(query.store_id) {
+            StoreScope::All => None,
+            StoreScope::Stores(ids) => Some(ids.into_iter().map(StoreId::from_uuid).collect()),
+            StoreScope::Denied(store_id) => return Err(SalesError::StoreAccessDenied(store_id)),
+        };
+
         let page = query.page.unwrap_or(1).max(1);
         let page_size = query.page_size.unwrap_or(20).clamp(1, 100);
+        let after = PageCursor::decode_opt(query.after_cursor.as_deref())?;
+        // A cursor page has no meaningful number
+        let page = if after.is_some() { 1 } else { page };
 
         let filter = ShiftFilter {
             store_id: query.store_id.map(StoreId::from_uuid),
+            store_ids,
             terminal_id: query.terminal_id.map(TerminalId::from_uuid),
             cashier_id: query.cashier_id.map(UserId::from_uuid),
             status: query
                 .status
                 .as_ref()
                 .and_then(|s| ShiftStatus::from_str(s).ok()),
+            after_id: after.map(|c| ShiftId::from_uuid(c.id())),
         };
 
         let (shifts, total) = self
@@ -40,6 +60,11 @@ impl ListShiftsUseCase {
             .await?;
 
         let total_pages = (total as f64 / page_size as f64).ceil() as i64;
+        let next_cursor = PageCursor::next(
+            shifts.last().map(|s| s.id().into_uuid()),
+            shifts.len(),
+            page_size,
+        );
 
         Ok(ShiftListResponse {
             items: shifts.into_iter().map(ShiftResponse::from).collect(),
@@ -47,6 +72,7 @@ impl ListShiftsUseCase {
             page,
             page_size,
             total_pages,
+            next_cursor,
         })
     }
 }
@@ -3,13 +3,17 @@
 mod close_shift_use_case;
 mod get_current_shift_use_case;
 mod get_shift_report_use_case;
+mod get_shift_snapshot_use_case;
 mod list_shifts_use_case;
 mod open_shift_use_case;
 mod record_cash_movement_use_case;
+mod reopen_shift_use_case;
 
-pub use close_shift_use_case::CloseShiftUseCase;
+pub use close_shift_use_case::{CloseShiftUseCase, DEFAULT_SHIFT_VARIANCE_THRESHOLD};
 pub use get_current_shift_use_case::GetCurrentShiftUseCase;
 pub use get_shift_report_use_case::GetShiftReportUseCase;
+pub use get_shift_snapshot_use_case::GetShiftSnapshotUseCase;
 pub use list_shifts_use_case::ListShiftsUseCase;
 pub use open_shift_use_case::OpenShiftUseCase;
 pub use record_cash_movement_use_case::RecordCashMovementUseCase;
+pub use reopen_shift_use_case::{DEFAULT_SHIFT_REOPEN_WINDOW_MINUTES, ReopenShiftUseCase};
@@ -0,0 +1,78 @@
+//! Reopen shift use case
+
+use std::sync::Arc;
+
+use chrono::{Duration, Utc};
+
+use crate::SalesError;
+use crate::application::dtos::{ReopenShiftCommand, ShiftResponse};
+use crate::domain::repositories::ShiftRepository;
+use crate::domain::value_objects::ShiftId;
+use identity::UserId;
+
+/// Default time after a close during which a shift may still be reopened
+pub const DEFAULT_SHIFT_REOPEN_WINDOW_MINUTES: i64 = 120;
+
+/// Use case for a manager reopening a shift that was closed prematurely.
+///
+/// The shift must still be within the reopen window, must not have been
+/// finalized by a cash deposit, and neither its terminal nor its cashier may
+/// have opened another shift since. The cleared closing snapshot is kept in
+/// an audit record with the manager and reason.
+pub struct ReopenShiftUseCase {
+    shift_repo: Arc<dyn ShiftRepository>,
+    window: Duration,
+}
+
+impl ReopenShiftUseCase {
+    pub fn new(shift_repo: Arc<dyn ShiftRepository>, window: Duration) -> Self {
+        Self { shift_repo, window }
+    }
+
+    pub async fn execute(
+        &self,
+        cmd: ReopenShiftCommand,
+        manager_id: UserId,
+    ) -> Result<ShiftResponse, SalesError> {
+        let shift_id = ShiftId::from_uuid(cmd.shift_id);
+
+        let mut shift = self
+            .shift_repo
+            .find_by_id(shift_id)
+            .await?
+            .ok_or(SalesError::ShiftNotFound(cmd.shift_id))?;
+
+        if !shift.status().can_reopen() {
+            return Err(SalesError::ShiftNotClosed);
+        }
+
+        if self.shift_repo.is_finalized(shift_id).await? {
+            return Err(SalesError::ShiftFinalized);
+        }
+
+        // Reopening must not create a second open shift on the terminal
+        if self
+            .shift_repo
+            .find_open_by_terminal(shift.terminal_id())
+            .await?
+            .is_some()
+        {
+            return Err(SalesError::TerminalHasOpenShift);
+        }
+
+        if self
+            .shift_repo
+            .find_open_by_cashier(shift.cashier_id())
+            .await?
+            .is_some()
+        {
+            return Err(SalesError::CashierHasOpenShift);
+        }
+
+        let reopen = shift.reopen(manager_id, cmd.reason, Utc::now(), self.window)?;
+
+        self.shift_repo.save_reopen(&shift, &reopen).await?;
+
+        Ok(ShiftResponse::from(shift))
+    }
+}
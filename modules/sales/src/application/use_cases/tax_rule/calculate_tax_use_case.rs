@@ -0,0 +1,133 @@
+use std::collections::HashMap;
+use std::collections::hash_map::Entry;
+use std::sync::Arc;
+
+use uuid::Uuid;
+
+use crate::SalesError;
+use crate::application::dtos::SaleDetailResponse;
+use crate::domain::entities::{Sale, TaxRule};
+use crate::domain::repositories::{CustomerRepository, SaleRepository, TaxRuleRepository};
+use crate::domain::value_objects::SaleId;
+use inventory::application::helpers::category_ancestry;
+use inventory::{CategoryRepository, InventoryError, ProductId, ProductRepository};
+
+/// Taxes a sale's lines under its store's active tax rules.
+///
+/// Each line takes the rate of the most specific rule covering its product
+/// (product, then its category or the nearest ancestor category with a
+/// rule, then store-wide); lines no rule covers keep their rate. Customers whose type a rule exempts pay no tax on its lines;
+/// walk-in sales are never exempt.
+pub struct CalculateTaxUseCase<P>
+where
+    P: ProductRepository,
+{
+    sale_repo: Arc<dyn SaleRepository>,
+    customer_repo: Arc<dyn CustomerRepository>,
+    tax_rule_repo: Arc<dyn TaxRuleRepository>,
+    product_repo: Arc<P>,
+    category_repo: Arc<dyn CategoryRepository>,
+}
+
+impl<P> CalculateTaxUseCase<P>
+where
+    P: ProductRepository,
+{
+    pub fn new(
+        sale_repo: Arc<dyn SaleRepository>,
+        customer_repo: Arc<dyn CustomerRepository>,
+        tax_rule_repo: Arc<dyn TaxRuleRepository>,
+        product_repo: Arc<P>,
+        category_repo: Arc<dyn CategoryRepository>,
+    ) -> Self {
+        Self {
+            sale_repo,
+            customer_repo,
+            tax_rule_repo,
+            product_repo,
+            category_repo,
+        }
+    }
+
+    /// Taxes the sale and saves it, so the terminal can show the total due
+    /// before taking payment
+    pub async fn execute(&self, sale_id: Uuid) -> Result<SaleDetailResponse, SalesError> {
+        let mut sale = self
+            .sale_repo
+            .find_by_id_with_details(SaleId::from_uuid(sale_id))
+            .await?
+            .ok_or(SalesError::SaleNotFound(sale_id))?;
+
+        self.apply(&mut sale).await?;
+        self.sale_repo.update(&sale).await?;
+
+        Ok(SaleDetailResponse::from(sale))
+    }
+
+    /// Taxes the lines of an editable sale without saving it
+    pub async fn apply(&self, sale: &mut Sale) -> Result<(), SalesError> {
+        if !sale.is_editable() {
+            return Err(SalesError::SaleNotEditable);
+        }
+
+        let rules = self
+            .tax_rule_repo
+            .find_active_by_store(sale.store_id().into_uuid())
+            .await?;
+        if rules.is_empty() {
+            return Ok(());
+        }
+
+        let customer_type = match sale.customer_id() {
+            Some(customer_id) => Some(
+                self.customer_repo
+                    .find_by_id(customer_id)
+                    .await?
+                    .ok_or(SalesError::CustomerNotFound(customer_id.into_uuid()))?
+                    .customer_type(),
+            ),
+            None => None,
+        };
+
+        // Category lookups are cached, a sale often repeats a product
+        let mut categories: HashMap<Uuid, Vec<Uuid>> = HashMap::new();
+        let mut lines = Vec::with_capacity(sale.items().len());
+        for item in sale.items() {
+            let product_id = item.product_id().into_uuid();
+            if let Entry::Vacant(entry) = categories.entry(product_id) {
+                entry.insert(
+                    self.product_categories(item.product_id())
+                        .await
+                        .map_err(|e| lookup_error(e, product_id))?,
+                );
+            }
+
+            if let Some(rule) = TaxRule::select(&rules, product_id, &categories[&product_id]) {
+                lines.push((item.id(), rule, rule.exempts(customer_type)));
+            }
+        }
+
+        sale.apply_tax_rules(lines)
+    }
+
+    /// The product's category and its ancestors, nearest first
+    async fn product_categories(&self, product_id: ProductId) -> Result<Vec<Uuid>, InventoryError> {
+        let category_id = self
+            .product_repo
+            .find_by_id(product_id)
+            .await?
+            .and_then(|p| p.category_id());
+        let Some(category_id) = category_id else {
+            return Ok(Vec::new());
+        };
+        let ancestry = category_ancestry(self.category_repo.as_ref(), category_id).await?;
+        Ok(ancestry.into_iter().map(|c| c.into_uuid()).collect())
+    }
+}
+
+fn lookup_error(error: InventoryError, id: Uuid) -> SalesError {
+    match error {
+        InventoryError::Database(e) => SalesError::Database(e),
+        _ => SalesError::ProductNotFound(id),
+    }
+}
@@ -0,0 +1,25 @@
+use std::sync::Arc;
+
+use uuid::Uuid;
+
+use crate::SalesError;
+use crate::application::dtos::tax_rule::TaxRuleResponse;
+use crate::domain::repositories::TaxRuleRepository;
+
+pub struct ListTaxRulesUseCase<T: TaxRuleRepository> {
+    tax_rule_repo: Arc<T>,
+}
+
+impl<T: TaxRuleRepository> ListTaxRulesUseCase<T> {
+    pub fn new(tax_rule_repo: Arc<T>) -> Self {
+        Self { tax_rule_repo }
+    }
+
+    pub async fn execute(
+        &self,
+        store_id: Option<Uuid>,
+    ) -> Result<Vec<TaxRuleResponse>, SalesError> {
+        let rules = self.tax_rule_repo.list(store_id).await?;
+        Ok(rules.iter().map(TaxRuleResponse::from).collect())
+    }
+}
@@ -0,0 +1,7 @@
+mod calculate_tax_use_case;
+mod list_tax_rules_use_case;
+mod upsert_tax_rule_use_case;
+
+pub use calculate_tax_use_case::CalculateTaxUseCase;
+pub use list_tax_rules_use_case::ListTaxRulesUseCase;
+pub use upsert_tax_rule_use_case::UpsertTaxRuleUseCase;
@@ -0,0 +1,77 @@
+use std::sync::Arc;
+
+use crate::SalesError;
+use crate::application::dtos::tax_rule::{TaxRuleResponse, UpsertTaxRuleCommand};
+use crate::domain::entities::TaxRule;
+use crate::domain::repositories::TaxRuleRepository;
+use crate::domain::value_objects::CustomerType;
+use identity::UserId;
+
+/// Creates the tax rule for a store and product/category (or the store-wide
+/// rule), or reconfigures the existing one. Setting `is_active = false`
+/// leaves the target to the next less specific rule.
+pub struct UpsertTaxRuleUseCase<T: TaxRuleRepository> {
+    tax_rule_repo: Arc<T>,
+}
+
+impl<T: TaxRuleRepository> UpsertTaxRuleUseCase<T> {
+    pub fn new(tax_rule_repo: Arc<T>) -> Self {
+        Self { tax_rule_repo }
+    }
+
+    pub async fn execute(
+        &self,
+        command: UpsertTaxRuleCommand,
+        actor_id: UserId,
+    ) -> Result<TaxRuleResponse, SalesError> {
+        let exempt_customer_types = command
+            .exempt_customer_types
+            .iter()
+            .map(|t| t.parse::<CustomerType>())
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let existing = self
+            .tax_rule_repo
+            .find_by_target(command.store_id, command.product_id, command.category_id)
+            .await?;
+
+        let rule = match existing {
+            Some(mut rule) => {
+                rule.reconfigure(
+                    command.jurisdiction,
+                    command.rate,
+                    command.tax_inclusive,
+                    exempt_customer_types,
+                    command.is_active,
+                )?;
+                self.tax_rule_repo.update(&rule).await?;
+                rule
+            }
+            None => {
+                let mut rule = TaxRule::create(
+                    command.store_id,
+                    command.jurisdiction,
+                    command.product_id,
+                    command.category_id,
+                    command.rate,
+                    command.tax_inclusive,
+                    exempt_customer_types,
+                    actor_id,
+                )?;
+                if !command.is_active {
+                    rule.reconfigure(
+                        rule.jurisdiction().to_string(),
+                        rule.rate(),
+                        rule.tax_inclusive(),
+                        rule.exempt_customer_types().to_vec(),
+                        false,
+                    )?;
+                }
+                self.tax_rule_repo.save(&rule).await?;
+                rule
+            }
+        };
+
+        Ok(TaxRuleResponse::from(&rule))
+    }
+}
@@ -0,0 +1,1291 @@
+//! In-memory repositories shared by the sales use case tests
+
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+use async_trait::async_trait;
+use chrono::{DateTime, NaiveDate, Utc};
+use rust_decimal::Decimal;
+use uuid::Uuid;
+
+use crate::SalesError;
+use crate::domain::entities::{
+    CashierShift, Customer, CustomerAccountEntry, ExchangeRate, Payment, PriceList, PriceListEntry,
+    PriceTier, Sale, SaleIdempotencyKey, SaleItem, ShiftReopen, TaxRule,
+};
+use crate::domain::repositories::{
+    CustomerFilter, CustomerRepository, ExchangeRateRepository, PriceListRepository,
+    PriceTierRepository, QuickSale, QuickSaleRepository, SaleFilter, SaleIdempotencyRepository,
+    SaleRepository, ShiftFilter, ShiftRepository, TaxRuleRepository,
+};
+use crate::domain::value_objects::{
+    CustomerCodeFormat, CustomerId, CustomerType, PaymentId, PriceListId, PriceTierId, SaleId,
+    SaleItemId, ShiftId, TaxRuleId,
+};
+use identity::{StoreId, UserId};
+use inventory::{
+    Barcode, CategoryId, CategoryRepository, CategoryStockValuation, CompositeCostSource, Currency,
+    IngredientId, IngredientSubstitute, InventoryError, InventoryPeriod, InventoryPeriodId,
+    InventoryPeriodRepository, InventoryStock, InventoryStockRepository, ItemUnitCost, Product,
+    ProductCategory, ProductId, ProductRepository, ProductSerial, ProductSerialRepository,
+    ProductVariant, ProductVendor, Recipe, RecipeId, RecipeIngredient, RecipeRepository,
+    SerialStatus, Sku, StockId, SubstituteId, UnitConversion, VariantId,
+};
+use pos_core::TerminalId;
+
+// -----------------------------------------------------------------------------
+// CAI ranges
+// -----------------------------------------------------------------------------
+
+/// The CAI range invoice numbers are drawn from, as the locked draw in
+/// the sale repositories sees it
+pub(crate) enum CaiRange {
+    Active {
+        cai_number: String,
+        next: i64,
+        end: i64,
+    },
+    Missing,
+    Expired,
+}
+
+pub(crate) struct CaiRanges(Mutex<CaiRange>);
+
+impl CaiRanges {
+    pub(crate) fn active(cai_number: &str, next: i64, end: i64) -> Self {
+        Self(Mutex::new(CaiRange::Active {
+            cai_number: cai_number.to_string(),
+            next,
+            end,
+        }))
+    }
+
+    pub(crate) fn missing() -> Self {
+        Self(Mutex::new(CaiRange::Missing))
+    }
+
+    pub(crate) fn expired() -> Self {
+        Self(Mutex::new(CaiRange::Expired))
+    }
+
+    /// Stamps a completed sale with the next number of the range
+    fn assign(&self, sale: &mut Sale) -> Result<(), SalesError> {
+        let terminal_id = sale
+            .terminal_id()
+            .ok_or(SalesError::NoValidCai(sale.id().into_uuid()))?
+            .into_uuid();
+        let mut range = self.0.lock().unwrap();
+        let (cai_number, number) = match &mut *range {
+            CaiRange::Missing => return Err(SalesError::NoValidCai(terminal_id)),
+            CaiRange::Expired => return Err(SalesError::CaiExpired(terminal_id)),
+            CaiRange::Active { next, end, .. } if *next > *end => {
+                return Err(SalesError::CaiRangeExhausted(terminal_id));
+            }
+            CaiRange::Active {
+                cai_number, next, ..
+            } => {
+                *next += 1;
+                (cai_number.clone(), *next - 1)
+            }
+        };
+        sale.assign_cai_invoice_number(cai_number, format!("{:08}", number))
+    }
+}
+
+// -----------------------------------------------------------------------------
+// Sales
+// -----------------------------------------------------------------------------
+
+pub(crate) struct MockSaleRepository {
+    sales: Mutex<HashMap<SaleId, Sale>>,
+    cai: CaiRanges,
+}
+
+impl MockSaleRepository {
+    pub(crate) fn new() -> Self {
+        Self::with_cai(CaiRanges::missing())
+    }
+
+    pub(crate) fn with_cai(cai: CaiRanges) -> Self {
+        Self {
+            sales: Mutex::new(HashMap::new()),
+            cai,
+        }
+    }
+
+    pub(crate) fn add_sale(&self, sale: Sale) {
+        self.sales.lock().unwrap().insert(sale.id(), sale);
+    }
+
+    pub(crate) fn get(&self, id: SaleId) -> Option<Sale> {
+        self.sales.lock().unwrap().get(&id).cloned()
+    }
+}
+
+#[async_trait]
+impl SaleRepository for MockSaleRepository {
+    async fn save(&self, sale: &Sale) -> Result<(), SalesError> {
+        self.add_sale(sale.clone());
+        Ok(())
+    }
+
+    async fn find_by_id(&self, id: SaleId) -> Result<Option<Sale>, SalesError> {
+        Ok(self.get(id))
+    }
+
+    async fn find_by_id_with_details(&self, id: SaleId) -> Result<Option<Sale>, SalesError> {
+        Ok(self.get(id))
+    }
+
+    async fn find_by_sale_number(
+        &self,
+        _store_id: StoreId,
+        _sale_number: &str,
+    ) -> Result<Option<Sale>, SalesError> {
+        unimplemented!()
+    }
+
+    async fn find_by_invoice_number(
+        &self,
+        _store_id: StoreId,
+        _invoice_number: &str,
+    ) -> Result<Option<Sale>, SalesError> {
+        unimplemented!()
+    }
+
+    async fn update(&self, sale: &Sale) -> Result<(), SalesError> {
+        self.add_sale(sale.clone());
+        Ok(())
+    }
+
+    async fn update_with_cai_invoice_number(&self, sale: &mut Sale) -> Result<(), SalesError> {
+        self.cai.assign(sale)?;
+        self.add_sale(sale.clone());
+        Ok(())
+    }
+
+    async fn find_paginated(
+        &self,
+        filter: SaleFilter,
+        _page: i64,
+        _page_size: i64,
+    ) -> Result<(Vec<Sale>, i64), SalesError> {
+        let sales: Vec<Sale> = self
+            .sales
+            .lock()
+            .unwrap()
+            .values()
+            .filter(|s| filter.store_id.is_none_or(|id| s.store_id() == id))
+            .filter(|s| {
+                filter
+                    .store_ids
+                    .as_ref()
+                    .is_none_or(|ids| ids.contains(&s.store_id()))
+            })
+            .cloned()
+            .collect();
+        let total = sales.len() as i64;
+        Ok((sales, total))
+    }
+
+    async fn generate_sale_number(&self, _store_id: StoreId) -> Result<String, SalesError> {
+        Ok(format!("S-{:06}", self.sales.lock().unwrap().len() + 1))
+    }
+
+    async fn save_item(&self, _item: &SaleItem) -> Result<(), SalesError> {
+        unimplemented!()
+    }
+
+    async fn update_item(&self, _item: &SaleItem) -> Result<(), SalesError> {
+        unimplemented!()
+    }
+
+    async fn delete_item(&self, _item_id: SaleItemId) -> Result<(), SalesError> {
+        unimplemented!()
+    }
+
+    async fn find_items_by_sale(&self, _sale_id: SaleId) -> Result<Vec<SaleItem>, SalesError> {
+        unimplemented!()
+    }
+
+    async fn find_item_by_id(&self, _item_id: SaleItemId) -> Result<Option<SaleItem>, SalesError> {
+        unimplemented!()
+    }
+
+    async fn save_payment(&self, _payment: &Payment) -> Result<(), SalesError> {
+        unimplemented!()
+    }
+
+    async fn update_payment(&self, _payment: &Payment) -> Result<(), SalesError> {
+        unimplemented!()
+    }
+
+    async fn find_payments_by_sale(&self, _sale_id: SaleId) -> Result<Vec<Payment>, SalesError> {
+        unimplemented!()
+    }
+
+    async fn find_payment_by_id(
+        &self,
+        _payment_id: PaymentId,
+    ) -> Result<Option<Payment>, SalesError> {
+        unimplemented!()
+    }
+}
+
+/// Keeps the quick sales committed, numbering them from a CAI range the
+/// way the Postgres transaction does
+pub(crate) struct MockQuickSaleRepository {
+    committed: Mutex<Vec<QuickSale>>,
+    cai: CaiRanges,
+}
+
+impl MockQuickSaleRepository {
+    pub(crate) fn new(cai: CaiRanges) -> Self {
+        Self {
+            committed: Mutex::new(Vec::new()),
+            cai,
+        }
+    }
+
+    pub(crate) fn committed(&self) -> Vec<QuickSale> {
+        self.committed.lock().unwrap().clone()
+    }
+}
+
+#[async_trait]
+impl QuickSaleRepository for MockQuickSaleRepository {
+    async fn commit(&self, quick_sale: &mut QuickSale) -> Result<(), SalesError> {
+        self.cai.assign(&mut quick_sale.sale)?;
+        self.committed.lock().unwrap().push(quick_sale.clone());
+        Ok(())
+    }
+}
+
+/// Remembers keys per terminal until they expire, as the Postgres table does
+#[derive(Default)]
+pub(crate) struct MockIdempotencyRepository {
+    records: Mutex<Vec<SaleIdempotencyKey>>,
+}
+
+#[async_trait]
+impl SaleIdempotencyRepository for MockIdempotencyRepository {
+    async fn save(&self, record: &SaleIdempotencyKey) -> Result<(), SalesError> {
+        let mut records = self.records.lock().unwrap();
+        records.retain(|r| r.terminal_id() != record.terminal_id() || r.key() != record.key());
+        records.push(record.clone());
+        Ok(())
+    }
+
+    async fn find_active(
+        &self,
+        terminal_id: TerminalId,
+        key: &str,
+    ) -> Result<Option<SaleIdempotencyKey>, SalesError> {
+        let now = Utc::now();
+        Ok(self
+            .records
+            .lock()
+            .unwrap()
+            .iter()
+            .find(|r| r.terminal_id() == terminal_id && r.key() == key && !r.is_expired(now))
+            .cloned())
+    }
+}
+
+#[derive(Default)]
+pub(crate) struct MockExchangeRateRepository;
+
+#[async_trait]
+impl ExchangeRateRepository for MockExchangeRateRepository {
+    async fn save(&self, _rate: &ExchangeRate) -> Result<(), SalesError> {
+        unimplemented!()
+    }
+
+    async fn find_effective(
+        &self,
+        _store_id: Uuid,
+        _currency: &Currency,
+        _at: DateTime<Utc>,
+    ) -> Result<Option<ExchangeRate>, SalesError> {
+        Ok(None)
+    }
+
+    async fn list(
+        &self,
+        _store_id: Uuid,
+        _currency: Option<&Currency>,
+    ) -> Result<Vec<ExchangeRate>, SalesError> {
+        unimplemented!()
+    }
+}
+
+// -----------------------------------------------------------------------------
+// Shifts
+// -----------------------------------------------------------------------------
+
+pub(crate) struct MockShiftRepository {
+    shifts: Mutex<HashMap<ShiftId, CashierShift>>,
+}
+
+impl MockShiftRepository {
+    pub(crate) fn new() -> Self {
+        Self {
+            shifts: Mutex::new(HashMap::new()),
+        }
+    }
+
+    pub(crate) fn add_shift(&self, shift: CashierShift) {
+        self.shifts.lock().unwrap().insert(shift.id(), shift);
+    }
+}
+
+#[async_trait]
+impl ShiftRepository for MockShiftRepository {
+    async fn save(&self, shift: &CashierShift) -> Result<(), SalesError> {
+        self.add_shift(shift.clone());
+        Ok(())
+    }
+
+    async fn find_by_id(&self, id: ShiftId) -> Result<Option<CashierShift>, SalesError> {
+        Ok(self.shifts.lock().unwrap().get(&id).cloned())
+    }
+
+    async fn find_open_by_terminal(
+        &self,
+        terminal_id: TerminalId,
+    ) -> Result<Option<CashierShift>, SalesError> {
+        Ok(self
+            .shifts
+            .lock()
+            .unwrap()
+            .values()
+            .find(|s| s.terminal_id() == terminal_id && s.is_open())
+            .cloned())
+    }
+
+    async fn find_open_by_cashier(
+        &self,
+        _cashier_id: UserId,
+    ) -> Result<Option<CashierShift>, SalesError> {
+        unimplemented!()
+    }
+
+    async fn update(&self, shift: &CashierShift) -> Result<(), SalesError> {
+        self.add_shift(shift.clone());
+        Ok(())
+    }
+
+    async fn is_finalized(&self, _id: ShiftId) -> Result<bool, SalesError> {
+        unimplemented!()
+    }
+
+    async fn save_reopen(
+        &self,
+        _shift: &CashierShift,
+        _reopen: &ShiftReopen,
+    ) -> Result<(), SalesError> {
+        unimplemented!()
+    }
+
+    async fn find_paginated(
+        &self,
+        filter: ShiftFilter,
+        _page: i64,
+        _page_size: i64,
+    ) -> Result<(Vec<CashierShift>, i64), SalesError> {
+        let shifts: Vec<CashierShift> = self
+            .shifts
+            .lock()
+            .unwrap()
+            .values()
+            .filter(|s| filter.store_id.is_none_or(|id| s.store_id() == id))
+            .filter(|s| {
+                filter
+                    .store_ids
+                    .as_ref()
+                    .is_none_or(|ids| ids.contains(&s.store_id()))
+            })
+            .cloned()
+            .collect();
+        let total = shifts.len() as i64;
+        Ok((shifts, total))
+    }
+}
+
+// -----------------------------------------------------------------------------
+// Pricing, tax and customers
+// -----------------------------------------------------------------------------
+
+/// No store has price tiers
+#[derive(Default)]
+pub(crate) struct NoPriceTiers;
+
+#[async_trait]
+impl PriceTierRepository for NoPriceTiers {
+    async fn save(&self, _tier: &PriceTier) -> Result<(), SalesError> {
+        unimplemented!()
+    }
+
+    async fn update(&self, _tier: &PriceTier) -> Result<(), SalesError> {
+        unimplemented!()
+    }
+
+    async fn find_by_id(&self, _id: PriceTierId) -> Result<Option<PriceTier>, SalesError> {
+        Ok(None)
+    }
+
+    async fn find_by_target(
+        &self,
+        _store_id: Uuid,
+        _customer_type: CustomerType,
+        _product_id: Option<Uuid>,
+        _category_id: Option<Uuid>,
+    ) -> Result<Option<PriceTier>, SalesError> {
+        Ok(None)
+    }
+
+    async fn list(&self, _store_id: Option<Uuid>) -> Result<Vec<PriceTier>, SalesError> {
+        Ok(Vec::new())
+    }
+
+    async fn find_applicable(
+        &self,
+        _store_id: Uuid,
+        _customer_type: CustomerType,
+        _product_id: Uuid,
+        _category_id: Option<Uuid>,
+    ) -> Result<Vec<PriceTier>, SalesError> {
+        Ok(Vec::new())
+    }
+}
+
+/// No store has price lists
+#[derive(Default)]
+pub(crate) struct NoPriceLists;
+
+#[async_trait]
+impl PriceListRepository for NoPriceLists {
+    async fn save(&self, _list: &PriceList) -> Result<(), SalesError> {
+        unimplemented!()
+    }
+
+    async fn find_by_id(&self, _id: PriceListId) -> Result<Option<PriceList>, SalesError> {
+        Ok(None)
+    }
+
+    async fn list(&self, _store_id: Option<Uuid>) -> Result<Vec<PriceList>, SalesError> {
+        Ok(Vec::new())
+    }
+
+    async fn find_active_by_store(&self, _store_id: Uuid) -> Result<Vec<PriceList>, SalesError> {
+        Ok(Vec::new())
+    }
+
+    async fn save_entry(&self, _entry: &PriceListEntry) -> Result<(), SalesError> {
+        unimplemented!()
+    }
+
+    async fn update_entry(&self, _entry: &PriceListEntry) -> Result<(), SalesError> {
+        unimplemented!()
+    }
+
+    async fn find_entry(
+        &self,
+        _price_list_id: PriceListId,
+        _product_id: Uuid,
+        _variant_id: Option<Uuid>,
+    ) -> Result<Option<PriceListEntry>, SalesError> {
+        Ok(None)
+    }
+
+    async fn list_entries(
+        &self,
+        _price_list_id: PriceListId,
+    ) -> Result<Vec<PriceListEntry>, SalesError> {
+        Ok(Vec::new())
+    }
+
+    async fn find_applicable_entries(
+        &self,
+        _price_list_id: PriceListId,
+        _product_id: Uuid,
+        _variant_id: Option<Uuid>,
+    ) -> Result<Vec<PriceListEntry>, SalesError> {
+        Ok(Vec::new())
+    }
+}
+
+pub(crate) struct MockTaxRuleRepository(pub(crate) Vec<TaxRule>);
+
+#[async_trait]
+impl TaxRuleRepository for MockTaxRuleRepository {
+    async fn save(&self, _rule: &TaxRule) -> Result<(), SalesError> {
+        unimplemented!()
+    }
+
+    async fn update(&self, _rule: &TaxRule) -> Result<(), SalesError> {
+        unimplemented!()
+    }
+
+    async fn find_by_id(&self, _id: TaxRuleId) -> Result<Option<TaxRule>, SalesError> {
+        unimplemented!()
+    }
+
+    async fn find_by_target(
+        &self,
+        _store_id: Uuid,
+        _product_id: Option<Uuid>,
+        _category_id: Option<Uuid>,
+    ) -> Result<Option<TaxRule>, SalesError> {
+        unimplemented!()
+    }
+
+    async fn list(&self, _store_id: Option<Uuid>) -> Result<Vec<TaxRule>, SalesError> {
+        unimplemented!()
+    }
+
+    async fn find_active_by_store(&self, store_id: Uuid) -> Result<Vec<TaxRule>, SalesError> {
+        Ok(self
+            .0
+            .iter()
+            .filter(|r| r.store_id() == store_id && r.is_active())
+            .cloned()
+            .collect())
+    }
+}
+
+/// Walk-in sales only; no customer is ever looked up
+#[derive(Default)]
+pub(crate) struct NoCustomers;
+
+#[async_trait]
+impl CustomerRepository for NoCustomers {
+    async fn save(&self, _customer: &Customer) -> Result<(), SalesError> {
+        unimplemented!()
+    }
+
+    async fn find_by_id(&self, _id: CustomerId) -> Result<Option<Customer>, SalesError> {
+        Ok(None)
+    }
+
+    async fn find_by_code(
+        &self,
+        _store_id: StoreId,
+        _code: &str,
+    ) -> Result<Option<Customer>, SalesError> {
+        unimplemented!()
+    }
+
+    async fn find_by_email(
+        &self,
+        _store_id: StoreId,
+        _email: &str,
+    ) -> Result<Option<Customer>, SalesError> {
+        unimplemented!()
+    }
+
+    async fn update(&self, _customer: &Customer) -> Result<(), SalesError> {
+        unimplemented!()
+    }
+
+    async fn find_paginated(
+        &self,
+        _filter: CustomerFilter,
+        _page: i64,
+        _page_size: i64,
+    ) -> Result<(Vec<Customer>, i64), SalesError> {
+        unimplemented!()
+    }
+
+    async fn generate_customer_code(&self, _store_id: StoreId) -> Result<String, SalesError> {
+        unimplemented!()
+    }
+
+    async fn find_customer_code_format(
+        &self,
+        _store_id: StoreId,
+    ) -> Result<CustomerCodeFormat, SalesError> {
+        unimplemented!()
+    }
+
+    async fn save_customer_code_format(
+        &self,
+        _store_id: StoreId,
+        _format: &CustomerCodeFormat,
+    ) -> Result<(), SalesError> {
+        unimplemented!()
+    }
+
+    async fn post_account_entry(
+        &self,
+        _entry: &mut CustomerAccountEntry,
+    ) -> Result<(), SalesError> {
+        unimplemented!()
+    }
+
+    async fn find_account_entries(
+        &self,
+        _customer_id: CustomerId,
+        _from: DateTime<Utc>,
+        _to: DateTime<Utc>,
+    ) -> Result<Vec<CustomerAccountEntry>, SalesError> {
+        unimplemented!()
+    }
+
+    async fn find_account_balance_at(
+        &self,
+        _customer_id: CustomerId,
+        _at: DateTime<Utc>,
+    ) -> Result<Decimal, SalesError> {
+        unimplemented!()
+    }
+
+    async fn find_store_credit_balance(
+        &self,
+        _customer_id: CustomerId,
+    ) -> Result<Decimal, SalesError> {
+        unimplemented!()
+    }
+
+    async fn adjust_store_credit(
+        &self,
+        _customer_id: CustomerId,
+        _amount: Decimal,
+    ) -> Result<Decimal, SalesError> {
+        unimplemented!()
+    }
+}
+
+// -----------------------------------------------------------------------------
+// Inventory
+// -----------------------------------------------------------------------------
+
+/// Category tree the tax rules walk up
+#[derive(Default)]
+pub(crate) struct MockCategoryRepository(pub(crate) Vec<ProductCategory>);
+
+#[async_trait]
+impl CategoryRepository for MockCategoryRepository {
+    async fn save(&self, _category: &ProductCategory) -> Result<(), InventoryError> {
+        unimplemented!()
+    }
+
+    async fn find_by_id(&self, id: CategoryId) -> Result<Option<ProductCategory>, InventoryError> {
+        Ok(self.0.iter().find(|c| c.id() == id).cloned())
+    }
+
+    async fn find_by_slug(&self, _slug: &str) -> Result<Option<ProductCategory>, InventoryError> {
+        unimplemented!()
+    }
+
+    async fn find_root_categories(&self) -> Result<Vec<ProductCategory>, InventoryError> {
+        unimplemented!()
+    }
+
+    async fn find_children(
+        &self,
+        _parent_id: CategoryId,
+    ) -> Result<Vec<ProductCategory>, InventoryError> {
+        unimplemented!()
+    }
+
+    async fn find_all_active(&self) -> Result<Vec<ProductCategory>, InventoryError> {
+        unimplemented!()
+    }
+
+    async fn update(&self, _category: &ProductCategory) -> Result<(), InventoryError> {
+        unimplemented!()
+    }
+
+    async fn stock_valuation_by_category(
+        &self,
+        _store_id: Option<StoreId>,
+    ) -> Result<Vec<CategoryStockValuation>, InventoryError> {
+        unimplemented!()
+    }
+
+    async fn delete(&self, _id: CategoryId) -> Result<(), InventoryError> {
+        unimplemented!()
+    }
+}
+
+pub(crate) struct MockProductRepository {
+    products: Mutex<HashMap<ProductId, Product>>,
+    variants: Mutex<HashMap<VariantId, ProductVariant>>,
+}
+
+impl MockProductRepository {
+    pub(crate) fn new() -> Self {
+        Self {
+            products: Mutex::new(HashMap::new()),
+            variants: Mutex::new(HashMap::new()),
+        }
+    }
+
+    pub(crate) fn add_product(&self, product: Product) {
+        self.products.lock().unwrap().insert(product.id(), product);
+    }
+}
+
+#[async_trait]
+impl ProductRepository for MockProductRepository {
+    async fn save(&self, _product: &Product) -> Result<(), InventoryError> {
+        unimplemented!()
+    }
+
+    async fn find_by_id(&self, id: ProductId) -> Result<Option<Product>, InventoryError> {
+        Ok(self.products.lock().unwrap().get(&id).cloned())
+    }
+
+    async fn find_by_sku(&self, _sku: &Sku) -> Result<Option<Product>, InventoryError> {
+        unimplemented!()
+    }
+
+    async fn find_by_barcode(&self, _barcode: &Barcode) -> Result<Option<Product>, InventoryError> {
+        unimplemented!()
+    }
+
+    async fn update(&self, _product: &Product) -> Result<(), InventoryError> {
+        unimplemented!()
+    }
+
+    async fn delete(&self, _id: ProductId) -> Result<(), InventoryError> {
+        unimplemented!()
+    }
+
+    async fn find_active(&self) -> Result<Vec<Product>, InventoryError> {
+        unimplemented!()
+    }
+
+    async fn find_by_category(
+        &self,
+        _category_id: CategoryId,
+    ) -> Result<Vec<Product>, InventoryError> {
+        unimplemented!()
+    }
+
+    async fn save_variant(&self, _variant: &ProductVariant) -> Result<(), InventoryError> {
+        unimplemented!()
+    }
+
+    async fn find_variant_by_id(
+        &self,
+        id: VariantId,
+    ) -> Result<Option<ProductVariant>, InventoryError> {
+        Ok(self.variants.lock().unwrap().get(&id).cloned())
+    }
+
+    async fn find_variant_by_sku(
+        &self,
+        _sku: &Sku,
+    ) -> Result<Option<ProductVariant>, InventoryError> {
+        unimplemented!()
+    }
+
+    async fn find_variant_by_barcode(
+        &self,
+        _barcode: &Barcode,
+    ) -> Result<Option<ProductVariant>, InventoryError> {
+        unimplemented!()
+    }
+
+    async fn find_variants_by_product(
+        &self,
+        _product_id: ProductId,
+    ) -> Result<Vec<ProductVariant>, InventoryError> {
+        unimplemented!()
+    }
+
+    async fn update_variant(&self, _variant: &ProductVariant) -> Result<(), InventoryError> {
+        unimplemented!()
+    }
+
+    async fn delete_variant(&self, _id: VariantId) -> Result<(), InventoryError> {
+        unimplemented!()
+    }
+
+    async fn count_variants(&self, _product_id: ProductId) -> Result<u32, InventoryError> {
+        unimplemented!()
+    }
+
+    async fn save_product_vendors(&self, _vendors: &[ProductVendor]) -> Result<(), InventoryError> {
+        unimplemented!()
+    }
+
+    async fn find_product_vendors(
+        &self,
+        _product_id: ProductId,
+    ) -> Result<Vec<ProductVendor>, InventoryError> {
+        unimplemented!()
+    }
+
+    async fn find_preferred_vendor(
+        &self,
+        _product_id: ProductId,
+    ) -> Result<Option<ProductVendor>, InventoryError> {
+        unimplemented!()
+    }
+
+    async fn find_paginated(
+        &self,
+        _category_id: Option<CategoryId>,
+        _is_active: Option<bool>,
+        _search: Option<&str>,
+        _after_id: Option<Uuid>,
+        _page: i64,
+        _page_size: i64,
+    ) -> Result<(Vec<Product>, i64), InventoryError> {
+        unimplemented!()
+    }
+
+    async fn count_filtered(
+        &self,
+        _category_id: Option<CategoryId>,
+        _is_active: Option<bool>,
+        _search: Option<&str>,
+    ) -> Result<i64, InventoryError> {
+        unimplemented!()
+    }
+
+    async fn search(
+        &self,
+        _term: &str,
+        _category_id: Option<CategoryId>,
+        _active_only: bool,
+        _page: i64,
+        _page_size: i64,
+    ) -> Result<(Vec<(Product, f64)>, i64), InventoryError> {
+        unimplemented!()
+    }
+
+    async fn replace_unit_conversions(
+        &self,
+        _product_id: ProductId,
+        _conversions: &[UnitConversion],
+    ) -> Result<(), InventoryError> {
+        unimplemented!()
+    }
+
+    async fn find_unit_conversions(
+        &self,
+        _product_id: ProductId,
+    ) -> Result<Vec<UnitConversion>, InventoryError> {
+        unimplemented!()
+    }
+}
+
+pub(crate) struct MockStockRepository {
+    stocks: Mutex<HashMap<StockId, InventoryStock>>,
+}
+
+impl MockStockRepository {
+    pub(crate) fn new() -> Self {
+        Self {
+            stocks: Mutex::new(HashMap::new()),
+        }
+    }
+
+    pub(crate) fn add_stock(&self, stock: InventoryStock) {
+        self.stocks.lock().unwrap().insert(stock.id(), stock);
+    }
+}
+
+#[async_trait]
+impl InventoryStockRepository for MockStockRepository {
+    async fn save(&self, stock: &InventoryStock) -> Result<(), InventoryError> {
+        self.add_stock(stock.clone());
+        Ok(())
+    }
+
+    async fn find_by_id(&self, id: StockId) -> Result<Option<InventoryStock>, InventoryError> {
+        Ok(self.stocks.lock().unwrap().get(&id).cloned())
+    }
+
+    async fn find_by_store_and_product(
+        &self,
+        store_id: StoreId,
+        product_id: ProductId,
+    ) -> Result<Option<InventoryStock>, InventoryError> {
+        Ok(self
+            .stocks
+            .lock()
+            .unwrap()
+            .values()
+            .find(|s| s.store_id() == store_id && s.product_id() == Some(product_id))
+            .cloned())
+    }
+
+    async fn find_by_store_and_variant(
+        &self,
+        store_id: StoreId,
+        variant_id: VariantId,
+    ) -> Result<Option<InventoryStock>, InventoryError> {
+        Ok(self
+            .stocks
+            .lock()
+            .unwrap()
+            .values()
+            .find(|s| s.store_id() == store_id && s.variant_id() == Some(variant_id))
+            .cloned())
+    }
+
+    async fn update_with_version(
+        &self,
+        stock: &InventoryStock,
+        _expected_version: i32,
+    ) -> Result<(), InventoryError> {
+        self.add_stock(stock.clone());
+        Ok(())
+    }
+
+    async fn find_low_stock(
+        &self,
+        _store_id: StoreId,
+    ) -> Result<Vec<InventoryStock>, InventoryError> {
+        unimplemented!()
+    }
+
+    async fn find_by_store(
+        &self,
+        _store_id: StoreId,
+    ) -> Result<Vec<InventoryStock>, InventoryError> {
+        unimplemented!()
+    }
+
+    async fn find_paginated(
+        &self,
+        _store_ids: Option<&[StoreId]>,
+        _product_id: Option<ProductId>,
+        _low_stock_only: bool,
+        _after_id: Option<Uuid>,
+        _page: i64,
+        _page_size: i64,
+    ) -> Result<(Vec<InventoryStock>, i64), InventoryError> {
+        unimplemented!()
+    }
+
+    async fn find_by_product(
+        &self,
+        _product_id: ProductId,
+    ) -> Result<Vec<InventoryStock>, InventoryError> {
+        unimplemented!()
+    }
+
+    async fn find_all(&self) -> Result<Vec<InventoryStock>, InventoryError> {
+        unimplemented!()
+    }
+
+    async fn find_all_low_stock(&self) -> Result<Vec<InventoryStock>, InventoryError> {
+        unimplemented!()
+    }
+
+    async fn find_low_stock_by_store(
+        &self,
+        _store_id: StoreId,
+    ) -> Result<Vec<InventoryStock>, InventoryError> {
+        unimplemented!()
+    }
+
+    async fn find_by_store_and_products(
+        &self,
+        _store_id: StoreId,
+        _product_ids: &[ProductId],
+    ) -> Result<Vec<InventoryStock>, InventoryError> {
+        unimplemented!()
+    }
+
+    async fn find_by_store_and_variants(
+        &self,
+        _store_id: StoreId,
+        _variant_ids: &[VariantId],
+    ) -> Result<Vec<InventoryStock>, InventoryError> {
+        unimplemented!()
+    }
+}
+
+pub(crate) struct MockRecipeRepository {
+    recipes: Mutex<Vec<Recipe>>,
+    ingredients: Mutex<HashMap<RecipeId, Vec<RecipeIngredient>>>,
+}
+
+impl MockRecipeRepository {
+    pub(crate) fn new() -> Self {
+        Self {
+            recipes: Mutex::new(Vec::new()),
+            ingredients: Mutex::new(HashMap::new()),
+        }
+    }
+
+    pub(crate) fn add_recipe(&self, recipe: Recipe, ingredients: Vec<RecipeIngredient>) {
+        self.ingredients
+            .lock()
+            .unwrap()
+            .insert(recipe.id(), ingredients);
+        self.recipes.lock().unwrap().push(recipe);
+    }
+}
+
+#[async_trait]
+impl RecipeRepository for MockRecipeRepository {
+    async fn save(&self, _recipe: &Recipe) -> Result<(), InventoryError> {
+        unimplemented!()
+    }
+
+    async fn find_by_id(&self, _id: RecipeId) -> Result<Option<Recipe>, InventoryError> {
+        unimplemented!()
+    }
+
+    async fn find_by_product(&self, _product_id: ProductId) -> Result<Vec<Recipe>, InventoryError> {
+        unimplemented!()
+    }
+
+    async fn find_by_variant(&self, _variant_id: VariantId) -> Result<Vec<Recipe>, InventoryError> {
+        unimplemented!()
+    }
+
+    async fn find_active_by_product(
+        &self,
+        product_id: ProductId,
+    ) -> Result<Option<Recipe>, InventoryError> {
+        Ok(self
+            .recipes
+            .lock()
+            .unwrap()
+            .iter()
+            .find(|r| r.is_active() && r.product_id() == Some(product_id))
+            .cloned())
+    }
+
+    async fn find_active_by_variant(
+        &self,
+        variant_id: VariantId,
+    ) -> Result<Option<Recipe>, InventoryError> {
+        Ok(self
+            .recipes
+            .lock()
+            .unwrap()
+            .iter()
+            .find(|r| r.is_active() && r.variant_id() == Some(variant_id))
+            .cloned())
+    }
+
+    async fn find_paginated(
+        &self,
+        _is_active: Option<bool>,
+        _search: Option<&str>,
+        _after_id: Option<Uuid>,
+        _page: i64,
+        _page_size: i64,
+    ) -> Result<(Vec<Recipe>, i64), InventoryError> {
+        unimplemented!()
+    }
+
+    async fn count_filtered(
+        &self,
+        _is_active: Option<bool>,
+        _search: Option<&str>,
+    ) -> Result<i64, InventoryError> {
+        unimplemented!()
+    }
+
+    async fn update(&self, _recipe: &Recipe) -> Result<(), InventoryError> {
+        unimplemented!()
+    }
+
+    async fn delete(&self, _id: RecipeId) -> Result<(), InventoryError> {
+        unimplemented!()
+    }
+
+    async fn save_ingredient(&self, _ingredient: &RecipeIngredient) -> Result<(), InventoryError> {
+        unimplemented!()
+    }
+
+    async fn find_ingredient_by_id(
+        &self,
+        _id: IngredientId,
+    ) -> Result<Option<RecipeIngredient>, InventoryError> {
+        unimplemented!()
+    }
+
+    async fn find_ingredients_by_recipe(
+        &self,
+        recipe_id: RecipeId,
+    ) -> Result<Vec<RecipeIngredient>, InventoryError> {
+        Ok(self
+            .ingredients
+            .lock()
+            .unwrap()
+            .get(&recipe_id)
+            .cloned()
+            .unwrap_or_default())
+    }
+
+    async fn update_ingredient(
+        &self,
+        _ingredient: &RecipeIngredient,
+    ) -> Result<(), InventoryError> {
+        unimplemented!()
+    }
+
+    async fn delete_ingredient(&self, _id: IngredientId) -> Result<(), InventoryError> {
+        unimplemented!()
+    }
+
+    async fn save_substitute(
+        &self,
+        _substitute: &IngredientSubstitute,
+    ) -> Result<(), InventoryError> {
+        unimplemented!()
+    }
+
+    async fn find_substitutes_by_ingredient(
+        &self,
+        _ingredient_id: IngredientId,
+    ) -> Result<Vec<IngredientSubstitute>, InventoryError> {
+        Ok(Vec::new())
+    }
+
+    async fn delete_substitute(&self, _id: SubstituteId) -> Result<(), InventoryError> {
+        unimplemented!()
+    }
+
+    async fn find_composite_cost_sources(
+        &self,
+    ) -> Result<Vec<CompositeCostSource>, InventoryError> {
+        unimplemented!()
+    }
+
+    async fn find_weighted_average_costs(&self) -> Result<Vec<ItemUnitCost>, InventoryError> {
+        unimplemented!()
+    }
+
+    async fn update_composite_cost_price(
+        &self,
+        _recipe: &Recipe,
+        _cost_price: Decimal,
+    ) -> Result<(), InventoryError> {
+        unimplemented!()
+    }
+}
+
+pub(crate) struct MockPeriodRepository(pub(crate) Vec<InventoryPeriod>);
+
+#[async_trait]
+impl InventoryPeriodRepository for MockPeriodRepository {
+    async fn save(&self, _period: &InventoryPeriod) -> Result<(), InventoryError> {
+        unimplemented!()
+    }
+
+    async fn update(&self, _period: &InventoryPeriod) -> Result<(), InventoryError> {
+        unimplemented!()
+    }
+
+    async fn find_by_id(
+        &self,
+        _id: InventoryPeriodId,
+    ) -> Result<Option<InventoryPeriod>, InventoryError> {
+        unimplemented!()
+    }
+
+    async fn find_overlapping(
+        &self,
+        _store_id: StoreId,
+        _start: NaiveDate,
+        _end: NaiveDate,
+    ) -> Result<Vec<InventoryPeriod>, InventoryError> {
+        unimplemented!()
+    }
+
+    async fn find_closed_containing(
+        &self,
+        store_id: StoreId,
+        date: NaiveDate,
+    ) -> Result<Option<InventoryPeriod>, InventoryError> {
+        Ok(self
+            .0
+            .iter()
+            .find(|p| p.store_id() == store_id && p.is_closed() && p.contains(date))
+            .cloned())
+    }
+
+    async fn find_by_store(
+        &self,
+        _store_id: StoreId,
+    ) -> Result<Vec<InventoryPeriod>, InventoryError> {
+        unimplemented!()
+    }
+}
+
+pub(crate) struct MockSerialRepository {
+    serials: Mutex<Vec<ProductSerial>>,
+}
+
+impl MockSerialRepository {
+    pub(crate) fn new(serials: Vec<ProductSerial>) -> Self {
+        Self {
+            serials: Mutex::new(serials),
+        }
+    }
+
+    pub(crate) fn status_of(&self, serial_number: &str) -> Option<SerialStatus> {
+        self.serials
+            .lock()
+            .unwrap()
+            .iter()
+            .find(|s| s.serial_number() == serial_number)
+            .map(|s| s.status())
+    }
+}
+
+#[async_trait]
+impl ProductSerialRepository for MockSerialRepository {
+    async fn save_batch(&self, _serials: &[ProductSerial]) -> Result<(), InventoryError> {
+        unimplemented!()
+    }
+
+    async fn update(
+        &self,
+        serial: &ProductSerial,
+        expected_status: SerialStatus,
+    ) -> Result<(), InventoryError> {
+        let mut serials = self.serials.lock().unwrap();
+        let stored = serials
+            .iter_mut()
+            .find(|s| s.id() == serial.id() && s.status() == expected_status)
+            .ok_or_else(|| {
+                InventoryError::SerialNotAvailable(serial.serial_number().to_string())
+            })?;
+        *stored = serial.clone();
+        Ok(())
+    }
+
+    async fn find_by_product_and_number(
+        &self,
+        product_id: ProductId,
+        serial_number: &str,
+    ) -> Result<Option<ProductSerial>, InventoryError> {
+        Ok(self
+            .serials
+            .lock()
+            .unwrap()
+            .iter()
+            .find(|s| s.product_id() == product_id && s.serial_number() == serial_number)
+            .cloned())
+    }
+
+    async fn find_by_serial_number(
+        &self,
+        _serial_number: &str,
+    ) -> Result<Vec<ProductSerial>, InventoryError> {
+        unimplemented!()
+    }
+
+    async fn find_by_product(
+        &self,
+        _product_id: ProductId,
+        _store_id: StoreId,
+        _status: Option<SerialStatus>,
+    ) -> Result<Vec<ProductSerial>, InventoryError> {
+        unimplemented!()
+    }
+}
@@ -0,0 +1,50 @@
+use std::str::FromStr;
+use std::sync::Arc;
+
+use crate::SalesError;
+use crate::application::dtos::weight_barcode::{
+    CreateWeightBarcodeFormatCommand, WeightBarcodeFormatResponse,
+};
+use crate::domain::entities::WeightBarcodeFormat;
+use crate::domain::repositories::WeightBarcodeFormatRepository;
+use crate::domain::value_objects::ScaleValueKind;
+use identity::UserId;
+
+/// Registers a scale label layout for a store. Prefixes may not overlap
+/// within a store, so a scanned label matches at most one format.
+pub struct CreateWeightBarcodeFormatUseCase<W: WeightBarcodeFormatRepository> {
+    format_repo: Arc<W>,
+}
+
+impl<W: WeightBarcodeFormatRepository> CreateWeightBarcodeFormatUseCase<W> {
+    pub fn new(format_repo: Arc<W>) -> Self {
+        Self { format_repo }
+    }
+
+    pub async fn execute(
+        &self,
+        command: CreateWeightBarcodeFormatCommand,
+        actor_id: UserId,
+    ) -> Result<WeightBarcodeFormatResponse, SalesError> {
+        let format = WeightBarcodeFormat::create(
+            command.store_id,
+            command.prefix,
+            command.value_start,
+            command.value_length,
+            ScaleValueKind::from_str(&command.value_kind)?,
+            command.divisor,
+            actor_id,
+        )?;
+
+        let existing = self.format_repo.find_by_store(command.store_id).await?;
+        if existing.iter().any(|f| f.overlaps(format.prefix())) {
+            return Err(SalesError::WeightBarcodePrefixConflict(
+                format.prefix().to_string(),
+            ));
+        }
+
+        self.format_repo.save(&format).await?;
+
+        Ok(WeightBarcodeFormatResponse::from(&format))
+    }
+}
@@ -0,0 +1,28 @@
+use std::sync::Arc;
+
+use uuid::Uuid;
+
+use crate::SalesError;
+use crate::domain::repositories::WeightBarcodeFormatRepository;
+
+/// Removes a scale label layout from a store. Labels already printed in it
+/// stop resolving, so scales should be reprogrammed first.
+pub struct DeleteWeightBarcodeFormatUseCase<W: WeightBarcodeFormatRepository> {
+    format_repo: Arc<W>,
+}
+
+impl<W: WeightBarcodeFormatRepository> DeleteWeightBarcodeFormatUseCase<W> {
+    pub fn new(format_repo: Arc<W>) -> Self {
+        Self { format_repo }
+    }
+
+    pub async fn execute(&self, store_id: Uuid, id: Uuid) -> Result<(), SalesError> {
+        let format = self
+            .format_repo
+            .find_by_id(id)
+            .await?
+            .filter(|f| f.store_id() == store_id)
+            .ok_or(SalesError::WeightBarcodeFormatNotFound(id))?;
+        self.format_repo.delete(format.id()).await
+    }
+}
@@ -0,0 +1,29 @@
+use std::sync::Arc;
+
+use uuid::Uuid;
+
+use crate::SalesError;
+use crate::application::dtos::weight_barcode::WeightBarcodeFormatResponse;
+use crate::domain::repositories::WeightBarcodeFormatRepository;
+
+/// Lists a store's scale label layouts
+pub struct ListWeightBarcodeFormatsUseCase<W: WeightBarcodeFormatRepository> {
+    format_repo: Arc<W>,
+}
+
+impl<W: WeightBarcodeFormatRepository> ListWeightBarcodeFormatsUseCase<W> {
+    pub fn new(format_repo: Arc<W>) -> Self {
+        Self { format_repo }
+    }
+
+    pub async fn execute(
+        &self,
+        store_id: Uuid,
+    ) -> Result<Vec<WeightBarcodeFormatResponse>, SalesError> {
+        let formats = self.format_repo.find_by_store(store_id).await?;
+        Ok(formats
+            .iter()
+            .map(WeightBarcodeFormatResponse::from)
+            .collect())
+    }
+}
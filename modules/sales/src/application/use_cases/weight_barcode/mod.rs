@@ -0,0 +1,7 @@
+mod create_weight_barcode_format_use_case;
+mod delete_weight_barcode_format_use_case;
+mod list_weight_barcode_formats_use_case;
+
+pub use create_weight_barcode_format_use_case::CreateWeightBarcodeFormatUseCase;
+pub use delete_weight_barcode_format_use_case::DeleteWeightBarcodeFormatUseCase;
+pub use list_weight_barcode_formats_use_case::ListWeightBarcodeFormatsUseCase;
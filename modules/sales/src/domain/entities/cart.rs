@@ -34,6 +34,8 @@ pub struct Cart {
     notes: Option<String>,
     created_at: DateTime<Utc>,
     updated_at: DateTime<Utc>,
+    /// Last time the cart was mutated; drives the abandoned-cart cleanup
+    last_activity_at: DateTime<Utc>,
 }
 
 impl Cart {
@@ -67,6 +69,7 @@ impl Cart {
             notes: None,
             created_at: now,
             updated_at: now,
+            last_activity_at: now,
         }
     }
 
@@ -98,6 +101,7 @@ impl Cart {
             notes: None,
             created_at: now,
             updated_at: now,
+            last_activity_at: now,
         }
     }
 
@@ -120,6 +124,7 @@ impl Cart {
         notes: Option<String>,
         created_at: DateTime<Utc>,
         updated_at: DateTime<Utc>,
+        last_activity_at: DateTime<Utc>,
     ) -> Self {
         Self {
             id,
@@ -138,6 +143,7 @@ impl Cart {
             notes,
             created_at,
             updated_at,
+            last_activity_at,
         }
     }
 
@@ -212,19 +218,19 @@ impl Cart {
     /// Marks the cart as converted to a sale
     pub fn mark_converted(&mut self) {
         self.converted_to_sale = true;
-        self.updated_at = Utc::now();
+        self.touch();
     }
 
     /// Extends the cart expiration
     pub fn extend_expiration(&mut self, hours: i64) {
         self.expires_at = Utc::now() + Duration::hours(hours);
-        self.updated_at = Utc::now();
+        self.touch();
     }
 
     /// Associates a customer with the cart
     pub fn set_customer(&mut self, customer_id: Option<CustomerId>) {
         self.customer_id = customer_id;
-        self.updated_at = Utc::now();
+        self.touch();
     }
 
     /// Recalculates all cart totals
@@ -236,9 +242,11 @@ impl Cart {
         self.item_count = self.items.len() as i32;
     }
 
-    /// Updates timestamps
+    /// Updates timestamps and records cart activity
     fn touch(&mut self) {
-        self.updated_at = Utc::now();
+        let now = Utc::now();
+        self.updated_at = now;
+        self.last_activity_at = now;
     }
 
     // =========================================================================
@@ -348,13 +356,17 @@ impl Cart {
         self.updated_at
     }
 
+    pub fn last_activity_at(&self) -> DateTime<Utc> {
+        self.last_activity_at
+    }
+
     // =========================================================================
     // Setters
     // =========================================================================
 
     pub fn set_notes(&mut self, notes: Option<String>) {
         self.notes = notes;
-        self.updated_at = Utc::now();
+        self.touch();
     }
 }
 
@@ -458,4 +470,20 @@ mod tests {
         assert!(cart.converted_to_sale());
         assert!(!cart.is_active());
     }
+
+    #[test]
+    fn test_mutations_record_activity() {
+        let mut cart = create_test_cart();
+        let created = cart.last_activity_at();
+        assert_eq!(created, cart.created_at());
+
+        cart.add_item(create_test_item(cart.id())).unwrap();
+        let after_add = cart.last_activity_at();
+        assert!(after_add >= created);
+        assert_eq!(after_add, cart.updated_at());
+
+        cart.set_customer(Some(CustomerId::new()));
+        assert!(cart.last_activity_at() >= after_add);
+        assert_eq!(cart.last_activity_at(), cart.updated_at());
+    }
 }
@@ -1,11 +1,12 @@
 //! CashierShift entity - represents a cashier's work shift at a terminal
 
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, Duration, Utc};
 use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 
 use crate::SalesError;
-use crate::domain::value_objects::{ShiftId, ShiftStatus};
+use crate::domain::entities::ShiftReopen;
+use crate::domain::value_objects::{ShiftCount, ShiftId, ShiftStatus};
 use identity::{StoreId, UserId};
 use pos_core::TerminalId;
 
@@ -16,6 +17,12 @@ use pos_core::TerminalId;
 /// - Only one open shift per cashier at a time
 /// - Shift must be open to record sales
 /// - Opening balance must be non-negative
+/// - A close whose variance on any counted payment method exceeds the
+///   threshold needs a variance reason
+///
+/// On close the expected cash is recomputed as the opening float plus cash
+/// sales and cash added, minus refunds and cash removed. The difference
+/// between the counted drawer and that figure is kept as the variance.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CashierShift {
     id: ShiftId,
@@ -37,6 +44,13 @@ pub struct CashierShift {
     transaction_count: i32,
     notes: Option<String>,
     closing_notes: Option<String>,
+    /// Card total counted at close (settlement batch), if reconciled
+    card_count: Option<Decimal>,
+    /// Other-tender total counted at close, if reconciled
+    other_count: Option<Decimal>,
+    /// Counted cash minus expected cash, set on close
+    variance: Option<Decimal>,
+    variance_reason: Option<String>,
     created_at: DateTime<Utc>,
     updated_at: DateTime<Utc>,
 }
@@ -74,6 +88,10 @@ impl CashierShift {
             transaction_count: 0,
             notes: None,
             closing_notes: None,
+            card_count: None,
+            other_count: None,
+            variance: None,
+            variance_reason: None,
             created_at: now,
             updated_at: now,
         })
@@ -101,6 +119,10 @@ impl CashierShift {
         transaction_count: i32,
         notes: Option<String>,
         closing_notes: Option<String>,
+        card_count: Option<Decimal>,
+        other_count: Option<Decimal>,
+        variance: Option<Decimal>,
+        variance_reason: Option<String>,
         created_at: DateTime<Utc>,
         updated_at: DateTime<Utc>,
     ) -> Self {
@@ -124,6 +146,10 @@ impl CashierShift {
             transaction_count,
             notes,
             closing_notes,
+            card_count,
+            other_count,
+            variance,
+            variance_reason,
             created_at,
             updated_at,
         }
@@ -133,24 +159,99 @@ impl CashierShift {
     // Workflow Methods
     // =========================================================================
 
-    /// Closes the shift
+    /// Closes the shift with the amounts counted by the cashier.
+    ///
+    /// When the variance of any counted payment method exceeds
+    /// `variance_threshold` (in either direction) a `variance_reason` is
+    /// required.
     pub fn close(
         &mut self,
-        closing_balance: Decimal,
+        count: ShiftCount,
         notes: Option<String>,
+        variance_reason: Option<String>,
+        variance_threshold: Decimal,
     ) -> Result<(), SalesError> {
         if !self.status.can_close() {
             return Err(SalesError::ShiftAlreadyClosed);
         }
 
+        let expected_cash = self.expected_cash();
+        let variances = [
+            Some(count.cash - expected_cash),
+            count.card.map(|card| card - self.card_sales),
+            count.other.map(|other| other - self.other_sales),
+        ];
+        let variance_reason = variance_reason
+            .map(|r| r.trim().to_string())
+            .filter(|r| !r.is_empty());
+        if let Some(variance) = variances
+            .into_iter()
+            .flatten()
+            .find(|v| v.abs() > variance_threshold)
+            && variance_reason.is_none()
+        {
+            return Err(SalesError::ShiftVarianceReasonRequired(variance));
+        }
+
+        let now = Utc::now();
         self.status = ShiftStatus::Closed;
-        self.closed_at = Some(Utc::now());
-        self.closing_balance = Some(closing_balance);
+        self.closed_at = Some(now);
+        self.closing_balance = Some(count.cash);
+        self.expected_balance = expected_cash;
+        self.card_count = count.card;
+        self.other_count = count.other;
+        self.variance = Some(count.cash - expected_cash);
+        self.variance_reason = variance_reason;
         self.closing_notes = notes;
-        self.updated_at = Utc::now();
+        self.updated_at = now;
         Ok(())
     }
 
+    /// Reopens a closed shift to correct a premature close.
+    ///
+    /// Only allowed while `now` is within `window` of the close. The closing
+    /// snapshot is cleared and returned as a [`ShiftReopen`] audit record.
+    pub fn reopen(
+        &mut self,
+        reopened_by_id: UserId,
+        reason: String,
+        now: DateTime<Utc>,
+        window: Duration,
+    ) -> Result<ShiftReopen, SalesError> {
+        if !self.status.can_reopen() {
+            return Err(SalesError::ShiftNotClosed);
+        }
+        let closed_at = self.closed_at.ok_or(SalesError::ShiftNotClosed)?;
+
+        let reason = reason.trim().to_string();
+        if reason.is_empty() {
+            return Err(SalesError::ShiftReopenReasonRequired);
+        }
+
+        if now - closed_at > window {
+            return Err(SalesError::ShiftReopenWindowExpired(window.num_minutes()));
+        }
+
+        let record = ShiftReopen::create(
+            self.id,
+            reopened_by_id,
+            reason,
+            closed_at,
+            self.closing_balance.take(),
+            self.closing_notes.take(),
+            now,
+        );
+        self.card_count = None;
+        self.other_count = None;
+        self.variance = None;
+        self.variance_reason = None;
+
+        self.status = ShiftStatus::Open;
+        self.closed_at = None;
+        self.updated_at = now;
+        Ok(record)
+    }
+
     /// Validates that the shift is open for operations
     pub fn validate_open(&self) -> Result<(), SalesError> {
         if !self.status.is_open() {
@@ -232,6 +333,22 @@ impl CashierShift {
         self.total_sales() - self.refunds
     }
 
+    /// Cash expected in the drawer: the opening float plus cash sales and
+    /// cash added, minus refunds and cash removed
+    pub fn expected_cash(&self) -> Decimal {
+        self.opening_balance + self.cash_sales + self.cash_in - self.refunds - self.cash_out
+    }
+
+    /// Counted card total minus card sales, if cards were reconciled
+    pub fn card_variance(&self) -> Option<Decimal> {
+        self.card_count.map(|count| count - self.card_sales)
+    }
+
+    /// Counted other-tender total minus other sales, if reconciled
+    pub fn other_variance(&self) -> Option<Decimal> {
+        self.other_count.map(|count| count - self.other_sales)
+    }
+
     /// Returns the cash difference (closing - expected)
     pub fn cash_difference(&self) -> Option<Decimal> {
         self.closing_balance
@@ -323,6 +440,22 @@ impl CashierShift {
         self.closing_notes.as_deref()
     }
 
+    pub fn card_count(&self) -> Option<Decimal> {
+        self.card_count
+    }
+
+    pub fn other_count(&self) -> Option<Decimal> {
+        self.other_count
+    }
+
+    pub fn variance(&self) -> Option<Decimal> {
+        self.variance
+    }
+
+    pub fn variance_reason(&self) -> Option<&str> {
+        self.variance_reason.as_deref()
+    }
+
     pub fn created_at(&self) -> DateTime<Utc> {
         self.created_at
     }
@@ -406,24 +539,187 @@ mod tests {
         shift.record_cash_sale(dec!(50.00)).unwrap();
 
         shift
-            .close(dec!(148.00), Some("Slight shortage".to_string()))
+            .close(
+                ShiftCount::cash(dec!(148.00)),
+                Some("Slight shortage".to_string()),
+                None,
+                dec!(5),
+            )
             .unwrap();
 
         assert!(!shift.is_open());
         assert_eq!(shift.closing_balance(), Some(dec!(148.00)));
         assert_eq!(shift.cash_difference(), Some(dec!(-2.00)));
+        assert_eq!(shift.variance(), Some(dec!(-2.00)));
+    }
+
+    #[test]
+    fn test_close_recomputes_expected_cash() {
+        let mut shift = create_test_shift();
+        shift.record_cash_sale(dec!(80.00)).unwrap();
+        shift.record_card_sale(dec!(40.00)).unwrap();
+        shift.record_cash_refund(dec!(15.00)).unwrap();
+        shift.record_cash_in(dec!(20.00)).unwrap();
+        shift.record_cash_out(dec!(50.00)).unwrap();
+
+        shift
+            .close(
+                ShiftCount {
+                    cash: dec!(135.00),
+                    card: Some(dec!(40.00)),
+                    other: None,
+                },
+                None,
+                None,
+                dec!(5),
+            )
+            .unwrap();
+
+        // 100 float + 80 sales + 20 in - 15 refunds - 50 out
+        assert_eq!(shift.expected_balance(), dec!(135.00));
+        assert_eq!(shift.variance(), Some(Decimal::ZERO));
+        assert_eq!(shift.card_variance(), Some(Decimal::ZERO));
+        assert_eq!(shift.other_variance(), None);
+    }
+
+    #[test]
+    fn test_variance_over_threshold_requires_reason() {
+        let mut shift = create_test_shift();
+        shift.record_card_sale(dec!(60.00)).unwrap();
+        let count = ShiftCount {
+            cash: dec!(100.00),
+            card: Some(dec!(50.00)),
+            other: None,
+        };
+
+        // The card batch is 10 short
+        let result = shift.close(count, None, Some("  ".to_string()), dec!(5));
+        assert!(matches!(
+            result,
+            Err(SalesError::ShiftVarianceReasonRequired(v)) if v == dec!(-10.00)
+        ));
+        assert!(shift.is_open());
+
+        shift
+            .close(count, None, Some("Voided card slip".to_string()), dec!(5))
+            .unwrap();
+        assert_eq!(shift.variance_reason(), Some("Voided card slip"));
+        assert_eq!(shift.card_variance(), Some(dec!(-10.00)));
     }
 
     #[test]
     fn test_cannot_record_on_closed_shift() {
         let mut shift = create_test_shift();
-        shift.close(dec!(100.00), None).unwrap();
+        shift
+            .close(ShiftCount::cash(dec!(100.00)), None, None, dec!(5))
+            .unwrap();
 
         let result = shift.record_cash_sale(dec!(50.00));
 
         assert!(matches!(result, Err(SalesError::ShiftAlreadyClosed)));
     }
 
+    #[test]
+    fn test_reopen_clears_closing_snapshot() {
+        let mut shift = create_test_shift();
+        shift
+            .close(
+                ShiftCount::cash(dec!(95.00)),
+                Some("Closed by mistake".to_string()),
+                Some("Short".to_string()),
+                dec!(1),
+            )
+            .unwrap();
+        let closed_at = shift.closed_at().unwrap();
+        let manager = UserId::new();
+
+        let record = shift
+            .reopen(
+                manager,
+                "  Cashier closed early  ".to_string(),
+                closed_at + Duration::minutes(5),
+                Duration::minutes(30),
+            )
+            .unwrap();
+
+        assert!(shift.is_open());
+        assert_eq!(shift.closed_at(), None);
+        assert_eq!(shift.closing_balance(), None);
+        assert_eq!(shift.closing_notes(), None);
+        assert_eq!(shift.variance(), None);
+        assert_eq!(record.shift_id(), shift.id());
+        assert_eq!(record.reopened_by_id(), manager);
+        assert_eq!(record.reason(), "Cashier closed early");
+        assert_eq!(record.previous_closed_at(), closed_at);
+        assert_eq!(record.previous_closing_balance(), Some(dec!(95.00)));
+        assert_eq!(record.previous_closing_notes(), Some("Closed by mistake"));
+    }
+
+    #[test]
+    fn test_reopen_allowed_at_window_boundary() {
+        let mut shift = create_test_shift();
+        shift
+            .close(ShiftCount::cash(dec!(100.00)), None, None, dec!(5))
+            .unwrap();
+        let closed_at = shift.closed_at().unwrap();
+
+        let result = shift.reopen(
+            UserId::new(),
+            "Recount".to_string(),
+            closed_at + Duration::minutes(30),
+            Duration::minutes(30),
+        );
+
+        assert!(result.is_ok());
+        assert!(shift.is_open());
+    }
+
+    #[test]
+    fn test_reopen_rejected_past_window() {
+        let mut shift = create_test_shift();
+        shift
+            .close(ShiftCount::cash(dec!(100.00)), None, None, dec!(5))
+            .unwrap();
+        let closed_at = shift.closed_at().unwrap();
+
+        let result = shift.reopen(
+            UserId::new(),
+            "Recount".to_string(),
+            closed_at + Duration::minutes(30) + Duration::seconds(1),
+            Duration::minutes(30),
+        );
+
+        assert!(matches!(
+            result,
+            Err(SalesError::ShiftReopenWindowExpired(30))
+        ));
+        assert!(!shift.is_open());
+        assert_eq!(shift.closing_balance(), Some(dec!(100.00)));
+    }
+
+    #[test]
+    fn test_reopen_requires_closed_shift_and_reason() {
+        let mut shift = create_test_shift();
+        let result = shift.reopen(
+            UserId::new(),
+            "Recount".to_string(),
+            Utc::now(),
+            Duration::minutes(30),
+        );
+        assert!(matches!(result, Err(SalesError::ShiftNotClosed)));
+
+        shift
+            .close(ShiftCount::cash(dec!(100.00)), None, None, dec!(5))
+            .unwrap();
+        let result = shift.reopen(
+            UserId::new(),
+            "   ".to_string(),
+            Utc::now(),
+            Duration::minutes(30),
+        );
+        assert!(matches!(result, Err(SalesError::ShiftReopenReasonRequired)));
+    }
+
     #[test]
     fn test_total_and_net_sales() {
         let mut shift = create_test_shift();
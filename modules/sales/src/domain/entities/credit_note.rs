@@ -7,15 +7,22 @@ use serde::{Deserialize, Serialize};
 use crate::SalesError;
 use crate::domain::entities::CreditNoteItem;
 use crate::domain::value_objects::{
-    CreditNoteId, CreditNoteItemId, CreditNoteStatus, ReturnReason, ReturnType, SaleId,
+    CreditNoteId, CreditNoteItemId, CreditNoteStatus, CustomerId, PaymentMethod, ReturnReason,
+    ReturnType, SaleId,
 };
 use identity::{StoreId, UserId};
 use inventory::Currency;
 
 /// CreditNote entity representing a return/refund document.
 ///
+/// Receiptless returns, approved by a manager when the customer has no
+/// receipt, reference no sale. Their lines are priced from the catalog and
+/// the refund goes to the customer's store credit unless another method is
+/// chosen when the note is applied.
+///
 /// Invariants:
-/// - Must reference an original completed sale
+/// - Must reference an original completed sale, unless receiptless
+/// - Receiptless returns name the customer whose store credit is refunded
 /// - Return quantities cannot exceed original sale quantities
 /// - User cannot approve their own credit note
 /// - Must have items before submitting
@@ -24,14 +31,19 @@ pub struct CreditNote {
     id: CreditNoteId,
     credit_note_number: String,
     store_id: StoreId,
-    original_sale_id: SaleId,
+    original_sale_id: Option<SaleId>,
     original_invoice_number: String,
+    /// Customer refunded on a receiptless return; receipted returns refund
+    /// the original sale's customer
+    customer_id: Option<CustomerId>,
     status: CreditNoteStatus,
     return_type: ReturnType,
     return_reason: ReturnReason,
     reason_details: Option<String>,
     currency: Currency,
     subtotal: Decimal,
+    /// Original-sale discounts allocated to the returned lines
+    discount_amount: Decimal,
     tax_amount: Decimal,
     total: Decimal,
     refund_method: Option<String>,
@@ -64,6 +76,56 @@ impl CreditNote {
         return_reason: ReturnReason,
         currency: Currency,
         created_by_id: UserId,
+    ) -> Self {
+        Self::draft(
+            credit_note_number,
+            store_id,
+            Some(original_sale_id),
+            original_invoice_number,
+            None,
+            return_type,
+            return_reason,
+            currency,
+            created_by_id,
+        )
+    }
+
+    /// Creates a CreditNote for a return without a receipt, refunded to
+    /// `customer_id`
+    #[allow(clippy::too_many_arguments)]
+    pub fn create_receiptless(
+        credit_note_number: String,
+        store_id: StoreId,
+        customer_id: CustomerId,
+        return_type: ReturnType,
+        return_reason: ReturnReason,
+        currency: Currency,
+        created_by_id: UserId,
+    ) -> Self {
+        Self::draft(
+            credit_note_number,
+            store_id,
+            None,
+            String::new(),
+            Some(customer_id),
+            return_type,
+            return_reason,
+            currency,
+            created_by_id,
+        )
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn draft(
+        credit_note_number: String,
+        store_id: StoreId,
+        original_sale_id: Option<SaleId>,
+        original_invoice_number: String,
+        customer_id: Option<CustomerId>,
+        return_type: ReturnType,
+        return_reason: ReturnReason,
+        currency: Currency,
+        created_by_id: UserId,
     ) -> Self {
         let now = Utc::now();
         Self {
@@ -72,12 +134,14 @@ impl CreditNote {
             store_id,
             original_sale_id,
             original_invoice_number,
+            customer_id,
             status: CreditNoteStatus::Draft,
             return_type,
             return_reason,
             reason_details: None,
             currency,
             subtotal: Decimal::ZERO,
+            discount_amount: Decimal::ZERO,
             tax_amount: Decimal::ZERO,
             total: Decimal::ZERO,
             refund_method: None,
@@ -105,14 +169,16 @@ impl CreditNote {
         id: CreditNoteId,
         credit_note_number: String,
         store_id: StoreId,
-        original_sale_id: SaleId,
+        original_sale_id: Option<SaleId>,
         original_invoice_number: String,
+        customer_id: Option<CustomerId>,
         status: CreditNoteStatus,
         return_type: ReturnType,
         return_reason: ReturnReason,
         reason_details: Option<String>,
         currency: Currency,
         subtotal: Decimal,
+        discount_amount: Decimal,
         tax_amount: Decimal,
         total: Decimal,
         refund_method: Option<String>,
@@ -138,12 +204,14 @@ impl CreditNote {
             store_id,
             original_sale_id,
             original_invoice_number,
+            customer_id,
             status,
             return_type,
             return_reason,
             reason_details,
             currency,
             subtotal,
+            discount_amount,
             tax_amount,
             total,
             refund_method,
@@ -264,8 +332,9 @@ impl CreditNote {
     /// Recalculates the credit note totals
     pub fn recalculate_totals(&mut self) {
         self.subtotal = self.items.iter().map(|i| i.subtotal()).sum();
+        self.discount_amount = self.items.iter().map(|i| i.discount_amount()).sum();
         self.tax_amount = self.items.iter().map(|i| i.tax_amount()).sum();
-        self.total = self.subtotal + self.tax_amount;
+        self.total = self.subtotal - self.discount_amount + self.tax_amount;
     }
 
     // =========================================================================
@@ -303,10 +372,29 @@ impl CreditNote {
         self.store_id
     }
 
-    pub fn original_sale_id(&self) -> SaleId {
+    pub fn original_sale_id(&self) -> Option<SaleId> {
         self.original_sale_id
     }
 
+    pub fn customer_id(&self) -> Option<CustomerId> {
+        self.customer_id
+    }
+
+    /// Whether this is a return without a receipt
+    pub fn is_receiptless(&self) -> bool {
+        self.original_sale_id.is_none()
+    }
+
+    /// Refund method used when none is chosen on apply: store credit for
+    /// receiptless returns, cash otherwise
+    pub fn default_refund_method(&self) -> PaymentMethod {
+        if self.is_receiptless() {
+            PaymentMethod::StoreCredit
+        } else {
+            PaymentMethod::Cash
+        }
+    }
+
     pub fn original_invoice_number(&self) -> &str {
         &self.original_invoice_number
     }
@@ -335,6 +423,10 @@ impl CreditNote {
         self.subtotal
     }
 
+    pub fn discount_amount(&self) -> Decimal {
+        self.discount_amount
+    }
+
     pub fn tax_amount(&self) -> Decimal {
         self.tax_amount
     }
@@ -458,7 +550,7 @@ mod tests {
 
         CreditNoteItem::create(
             credit_note_id,
-            SaleItemId::new(),
+            Some(SaleItemId::new()),
             ProductId::new(),
             None,
             "SKU-001".to_string(),
@@ -482,6 +574,29 @@ mod tests {
         assert!(!cn.is_final());
     }
 
+    #[test]
+    fn test_receiptless_defaults_refund_to_store_credit() {
+        let customer_id = CustomerId::new();
+        let cn = CreditNote::create_receiptless(
+            "CN-002".to_string(),
+            StoreId::new(),
+            customer_id,
+            ReturnType::Partial,
+            ReturnReason::Defective,
+            Currency::new("USD").unwrap(),
+            UserId::new(),
+        );
+
+        assert!(cn.is_receiptless());
+        assert_eq!(cn.original_sale_id(), None);
+        assert_eq!(cn.customer_id(), Some(customer_id));
+        assert_eq!(cn.default_refund_method(), PaymentMethod::StoreCredit);
+        assert_eq!(
+            create_test_credit_note().default_refund_method(),
+            PaymentMethod::Cash
+        );
+    }
+
     #[test]
     fn test_submit_workflow() {
         let mut cn = create_test_credit_note();
@@ -566,4 +681,100 @@ mod tests {
         assert_eq!(cn.status(), CreditNoteStatus::Cancelled);
         assert!(cn.is_final());
     }
+
+    #[test]
+    fn test_partial_return_of_discounted_multi_line_sale() {
+        use crate::domain::entities::{Sale, SaleItem};
+        use inventory::{ProductId, UnitOfMeasure};
+        use pos_core::TerminalId;
+        use rust_decimal_macros::dec;
+        use std::str::FromStr;
+
+        use crate::domain::value_objects::ShiftId;
+
+        let mut sale = Sale::create_pos(
+            "SALE-001".to_string(),
+            StoreId::new(),
+            TerminalId::new(),
+            ShiftId::new(),
+            UserId::new(),
+            Currency::new("USD").unwrap(),
+        );
+        // 2 x 50 and 1 x 100, 15% tax, then 10% off the whole sale
+        for (line, qty, price) in [(1, dec!(2), dec!(50)), (2, dec!(1), dec!(100))] {
+            let item = SaleItem::create(
+                sale.id(),
+                line,
+                ProductId::new(),
+                None,
+                format!("SKU-{}", line),
+                "Test Product".to_string(),
+                qty,
+                UnitOfMeasure::from_str("unit").unwrap(),
+                price,
+                dec!(30),
+                dec!(15),
+            )
+            .unwrap();
+            sale.add_item(item).unwrap();
+        }
+        sale.apply_percentage_discount(dec!(10)).unwrap();
+        // Tax is charged on the discounted 180
+        assert_eq!(sale.total(), dec!(207));
+
+        let allocations = sale.line_allocations();
+        let first_line = &sale.items()[0];
+
+        // Return one unit of the first line
+        let mut partial = create_test_credit_note();
+        let item = CreditNoteItem::from_sale_line(
+            partial.id(),
+            first_line,
+            &allocations[0],
+            dec!(0),
+            dec!(1),
+        )
+        .unwrap();
+        partial.add_item(item).unwrap();
+
+        assert_eq!(partial.subtotal(), dec!(50));
+        assert_eq!(partial.discount_amount(), dec!(5));
+        assert_eq!(partial.tax_amount(), dec!(6.75));
+        assert_eq!(partial.total(), dec!(51.75));
+
+        // Return everything else; both notes together refund the sale total
+        let mut rest = create_test_credit_note();
+        let item = CreditNoteItem::from_sale_line(
+            rest.id(),
+            first_line,
+            &allocations[0],
+            dec!(1),
+            dec!(1),
+        )
+        .unwrap();
+        rest.add_item(item).unwrap();
+        let item = CreditNoteItem::from_sale_line(
+            rest.id(),
+            &sale.items()[1],
+            &allocations[1],
+            dec!(0),
+            dec!(1),
+        )
+        .unwrap();
+        rest.add_item(item).unwrap();
+
+        assert_eq!(partial.total() + rest.total(), sale.total());
+
+        // Nothing left to return on the first line
+        assert!(matches!(
+            CreditNoteItem::from_sale_line(
+                rest.id(),
+                first_line,
+                &allocations[0],
+                dec!(2),
+                dec!(1),
+            ),
+            Err(SalesError::ReturnQuantityExceedsSaleQuantity)
+        ));
+    }
 }
@@ -5,7 +5,10 @@ use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 
 use crate::SalesError;
-use crate::domain::value_objects::{CreditNoteId, CreditNoteItemId, SaleItemId};
+use crate::domain::entities::SaleItem;
+use crate::domain::value_objects::{
+    CreditNoteId, CreditNoteItemId, SaleItemId, SaleLineAllocation,
+};
 use inventory::{ProductId, UnitOfMeasure, VariantId};
 
 /// CreditNoteItem entity representing a line item in a credit note.
@@ -13,11 +16,13 @@ use inventory::{ProductId, UnitOfMeasure, VariantId};
 /// Invariants:
 /// - Quantity must be positive
 /// - Return quantity cannot exceed original sale item quantity
+/// - Returns of serial-tracked lines name one serial sold on the line per unit
+/// - Items of receiptless returns have no original sale item
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CreditNoteItem {
     id: CreditNoteItemId,
     credit_note_id: CreditNoteId,
-    original_sale_item_id: SaleItemId,
+    original_sale_item_id: Option<SaleItemId>,
     product_id: ProductId,
     variant_id: Option<VariantId>,
     sku: String,
@@ -26,10 +31,13 @@ pub struct CreditNoteItem {
     unit_of_measure: UnitOfMeasure,
     unit_price: Decimal,
     tax_rate: Decimal,
+    /// Share of the original sale's discounts allocated to the returned units
+    discount_amount: Decimal,
     tax_amount: Decimal,
     subtotal: Decimal,
     total: Decimal,
     restock: bool,
+    serial_numbers: Vec<String>,
     condition: Option<String>,
     notes: Option<String>,
     created_at: DateTime<Utc>,
@@ -41,7 +49,7 @@ impl CreditNoteItem {
     #[allow(clippy::too_many_arguments)]
     pub fn create(
         credit_note_id: CreditNoteId,
-        original_sale_item_id: SaleItemId,
+        original_sale_item_id: Option<SaleItemId>,
         product_id: ProductId,
         variant_id: Option<VariantId>,
         sku: String,
@@ -72,10 +80,12 @@ impl CreditNoteItem {
             unit_of_measure,
             unit_price,
             tax_rate,
+            discount_amount: Decimal::ZERO,
             tax_amount,
             subtotal,
             total,
             restock: true,
+            serial_numbers: Vec::new(),
             condition: None,
             notes: None,
             created_at: now,
@@ -83,12 +93,41 @@ impl CreditNoteItem {
         })
     }
 
+    /// Creates an item returning `return_quantity` units of an original sale
+    /// line, refunding the line's allocated discount and tax rather than the
+    /// pre-discount price. `already_returned` is the quantity of the line on
+    /// other credit notes (and earlier lines of this one).
+    pub fn from_sale_line(
+        credit_note_id: CreditNoteId,
+        sale_item: &SaleItem,
+        allocation: &SaleLineAllocation,
+        already_returned: Decimal,
+        return_quantity: Decimal,
+    ) -> Result<Self, SalesError> {
+        let refund = allocation.for_return(already_returned, return_quantity)?;
+
+        let mut item = Self::create(
+            credit_note_id,
+            Some(sale_item.id()),
+            sale_item.product_id(),
+            sale_item.variant_id(),
+            sale_item.sku().to_string(),
+            sale_item.description().to_string(),
+            return_quantity,
+            *sale_item.unit_of_measure(),
+            allocation.unit_price,
+            sale_item.tax_rate(),
+        )?;
+        item.apply_sale_allocation(refund.discount_amount, refund.tax_amount)?;
+        Ok(item)
+    }
+
     /// Reconstitutes a CreditNoteItem from persistence
     #[allow(clippy::too_many_arguments)]
     pub fn reconstitute(
         id: CreditNoteItemId,
         credit_note_id: CreditNoteId,
-        original_sale_item_id: SaleItemId,
+        original_sale_item_id: Option<SaleItemId>,
         product_id: ProductId,
         variant_id: Option<VariantId>,
         sku: String,
@@ -97,10 +136,12 @@ impl CreditNoteItem {
         unit_of_measure: UnitOfMeasure,
         unit_price: Decimal,
         tax_rate: Decimal,
+        discount_amount: Decimal,
         tax_amount: Decimal,
         subtotal: Decimal,
         total: Decimal,
         restock: bool,
+        serial_numbers: Vec<String>,
         condition: Option<String>,
         notes: Option<String>,
         created_at: DateTime<Utc>,
@@ -118,10 +159,12 @@ impl CreditNoteItem {
             unit_of_measure,
             unit_price,
             tax_rate,
+            discount_amount,
             tax_amount,
             subtotal,
             total,
             restock,
+            serial_numbers,
             condition,
             notes,
             created_at,
@@ -139,21 +182,133 @@ impl CreditNoteItem {
         self.updated_at = Utc::now();
     }
 
-    /// Updates the return quantity
+    /// Updates the return quantity.
+    ///
+    /// Any discount allocated from the original sale is scaled to the new
+    /// quantity; callers that need an exact reconciliation should re-apply
+    /// the sale allocation afterwards.
     pub fn set_return_quantity(&mut self, quantity: Decimal) -> Result<(), SalesError> {
         if quantity <= Decimal::ZERO {
             return Err(SalesError::InvalidQuantity);
         }
+        self.discount_amount = (self.discount_amount * quantity / self.return_quantity).round_dp(2);
         self.return_quantity = quantity;
         self.recalculate_totals();
         Ok(())
     }
 
+    /// Applies the discount and tax allocated from the original sale line to
+    /// the returned units, so the refund is net of any sale-level discount
+    pub fn apply_sale_allocation(
+        &mut self,
+        discount_amount: Decimal,
+        tax_amount: Decimal,
+    ) -> Result<(), SalesError> {
+        if discount_amount < Decimal::ZERO
+            || discount_amount > self.subtotal
+            || tax_amount < Decimal::ZERO
+        {
+            return Err(SalesError::InvalidDiscountAmount);
+        }
+        self.discount_amount = discount_amount;
+        self.tax_amount = tax_amount;
+        self.total = self.subtotal - self.discount_amount + self.tax_amount;
+        self.updated_at = Utc::now();
+        Ok(())
+    }
+
+    /// Records which serials of the original sale line are being returned.
+    ///
+    /// Lines sold without serials take none; lines sold with serials need
+    /// one distinct serial from the line per returned unit.
+    pub fn set_returned_serials(
+        &mut self,
+        sale_item: &SaleItem,
+        serial_numbers: Vec<String>,
+    ) -> Result<(), SalesError> {
+        let serial_numbers: Vec<String> = serial_numbers
+            .into_iter()
+            .map(|s| s.trim().to_string())
+            .collect();
+
+        if sale_item.serial_numbers().is_empty() {
+            if let Some(serial) = serial_numbers.into_iter().next() {
+                return Err(SalesError::SerialNotOnSaleLine(serial));
+            }
+            return Ok(());
+        }
+
+        if serial_numbers.is_empty() {
+            return Err(SalesError::SerialNumbersRequired(
+                sale_item.product_id().into_uuid(),
+            ));
+        }
+        if Decimal::from(serial_numbers.len()) != self.return_quantity {
+            return Err(SalesError::SerialCountMismatch);
+        }
+        let mut seen = std::collections::HashSet::new();
+        for serial in &serial_numbers {
+            if !seen.insert(serial.as_str()) {
+                return Err(SalesError::DuplicateSerialNumber(serial.clone()));
+            }
+            if !sale_item.serial_numbers().contains(serial) {
+                return Err(SalesError::SerialNotOnSaleLine(serial.clone()));
+            }
+        }
+
+        self.serial_numbers = serial_numbers;
+        self.updated_at = Utc::now();
+        Ok(())
+    }
+
+    /// Records the serials coming back on a receiptless return, where there
+    /// is no sale line to check them against. Serial-tracked products need
+    /// one distinct serial per returned unit.
+    pub fn set_receiptless_serials(
+        &mut self,
+        serialized: bool,
+        serial_numbers: Vec<String>,
+    ) -> Result<(), SalesError> {
+        let serial_numbers: Vec<String> = serial_numbers
+            .into_iter()
+            .map(|s| s.trim().to_string())
+            .collect();
+
+        if !serialized {
+            if !serial_numbers.is_empty() {
+                return Err(SalesError::ProductNotSerialized(
+                    self.product_id.into_uuid(),
+                ));
+            }
+            return Ok(());
+        }
+
+        if serial_numbers.is_empty() {
+            return Err(SalesError::SerialNumbersRequired(
+                self.product_id.into_uuid(),
+            ));
+        }
+        if Decimal::from(serial_numbers.len()) != self.return_quantity {
+            return Err(SalesError::SerialCountMismatch);
+        }
+        let mut seen = std::collections::HashSet::new();
+        for serial in &serial_numbers {
+            if !seen.insert(serial.as_str()) {
+                return Err(SalesError::DuplicateSerialNumber(serial.clone()));
+            }
+        }
+
+        self.serial_numbers = serial_numbers;
+        self.updated_at = Utc::now();
+        Ok(())
+    }
+
     /// Recalculates totals
     fn recalculate_totals(&mut self) {
         self.subtotal = self.return_quantity * self.unit_price;
-        self.tax_amount = self.subtotal * (self.tax_rate / Decimal::from(100));
-        self.total = self.subtotal + self.tax_amount;
+        let taxable = self.subtotal - self.discount_amount;
+        self.tax_amount = taxable * (self.tax_rate / Decimal::from(100));
+        self.total = taxable + self.tax_amount;
         self.updated_at = Utc::now();
     }
 
@@ -169,7 +324,7 @@ impl CreditNoteItem {
         self.credit_note_id
     }
 
-    pub fn original_sale_item_id(&self) -> SaleItemId {
+    pub fn original_sale_item_id(&self) -> Option<SaleItemId> {
         self.original_sale_item_id
     }
 
@@ -205,6 +360,10 @@ impl CreditNoteItem {
         self.tax_rate
     }
 
+    pub fn discount_amount(&self) -> Decimal {
+        self.discount_amount
+    }
+
     pub fn tax_amount(&self) -> Decimal {
         self.tax_amount
     }
@@ -221,6 +380,10 @@ impl CreditNoteItem {
         self.restock
     }
 
+    pub fn serial_numbers(&self) -> &[String] {
+        &self.serial_numbers
+    }
+
     pub fn condition(&self) -> Option<&str> {
         self.condition.as_deref()
     }
@@ -261,7 +424,7 @@ mod tests {
     fn create_test_item() -> CreditNoteItem {
         CreditNoteItem::create(
             CreditNoteId::new(),
-            SaleItemId::new(),
+            Some(SaleItemId::new()),
             ProductId::new(),
             None,
             "SKU-001".to_string(),
@@ -290,7 +453,7 @@ mod tests {
     fn test_invalid_quantity() {
         let result = CreditNoteItem::create(
             CreditNoteId::new(),
-            SaleItemId::new(),
+            Some(SaleItemId::new()),
             ProductId::new(),
             None,
             "SKU-001".to_string(),
@@ -322,4 +485,89 @@ mod tests {
         assert_eq!(item.return_quantity(), dec!(5));
         assert_eq!(item.subtotal(), dec!(250.00));
     }
+
+    fn serialized_sale_item() -> SaleItem {
+        let mut sale_item = SaleItem::create(
+            crate::domain::value_objects::SaleId::new(),
+            1,
+            ProductId::new(),
+            None,
+            "SKU-001".to_string(),
+            "Laptop".to_string(),
+            dec!(3),
+            UnitOfMeasure::from_str("unit").unwrap(),
+            dec!(50.00),
+            dec!(30.00),
+            dec!(15),
+        )
+        .unwrap();
+        sale_item
+            .set_serial_numbers(vec![
+                "SN-1".to_string(),
+                "SN-2".to_string(),
+                "SN-3".to_string(),
+            ])
+            .unwrap();
+        sale_item
+    }
+
+    #[test]
+    fn test_returned_serials_from_sale_line() {
+        let sale_item = serialized_sale_item();
+        let mut item = create_test_item();
+
+        item.set_returned_serials(&sale_item, vec!["SN-3".to_string(), " SN-1".to_string()])
+            .unwrap();
+
+        assert_eq!(item.serial_numbers(), ["SN-3", "SN-1"]);
+    }
+
+    #[test]
+    fn test_returned_serials_required_for_serialized_line() {
+        let sale_item = serialized_sale_item();
+        let mut item = create_test_item();
+
+        let result = item.set_returned_serials(&sale_item, Vec::new());
+
+        assert!(matches!(result, Err(SalesError::SerialNumbersRequired(_))));
+    }
+
+    #[test]
+    fn test_returned_serial_must_be_on_sale_line() {
+        let sale_item = serialized_sale_item();
+        let mut item = create_test_item();
+
+        let result =
+            item.set_returned_serials(&sale_item, vec!["SN-1".to_string(), "SN-9".to_string()]);
+
+        assert!(matches!(result, Err(SalesError::SerialNotOnSaleLine(s)) if s == "SN-9"));
+    }
+
+    #[test]
+    fn test_returned_serial_count_must_match_quantity() {
+        let sale_item = serialized_sale_item();
+        let mut item = create_test_item();
+
+        let result = item.set_returned_serials(&sale_item, vec!["SN-1".to_string()]);
+
+        assert!(matches!(result, Err(SalesError::SerialCountMismatch)));
+    }
+
+    #[test]
+    fn test_receiptless_serials() {
+        let mut item = create_test_item();
+
+        assert!(matches!(
+            item.set_receiptless_serials(true, vec!["SN-1".to_string(), "SN-1".to_string()]),
+            Err(SalesError::DuplicateSerialNumber(_))
+        ));
+        assert!(matches!(
+            item.set_receiptless_serials(false, vec!["SN-1".to_string()]),
+            Err(SalesError::ProductNotSerialized(_))
+        ));
+        item.set_receiptless_serials(true, vec!["SN-1".to_string(), "SN-2".to_string()])
+            .unwrap();
+
+        assert_eq!(item.serial_numbers(), ["SN-1", "SN-2"]);
+    }
 }
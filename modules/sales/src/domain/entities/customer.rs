@@ -6,6 +6,7 @@ use serde::{Deserialize, Serialize};
 
 use crate::SalesError;
 use crate::domain::value_objects::{CustomerId, CustomerType};
+use common::TaxId;
 use identity::{StoreId, UserId};
 
 /// Address embedded value object
@@ -55,6 +56,10 @@ impl Address {
 /// - Email must be unique per store (if provided)
 /// - First name must not be empty
 /// - Last name must not be empty
+/// - Credit limit is non-negative; zero means the customer can't buy on
+///   account
+/// - Account balance only changes through posted account entries
+/// - Store credit balance is never negative
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Customer {
     id: CustomerId,
@@ -67,12 +72,16 @@ pub struct Customer {
     email: Option<String>,
     phone: Option<String>,
     tax_id: Option<String>,
+    tax_id_country: Option<String>,
     billing_address: Address,
     user_id: Option<UserId>,
     is_active: bool,
     total_purchases: Decimal,
     purchase_count: i32,
     last_purchase_at: Option<DateTime<Utc>>,
+    credit_limit: Decimal,
+    account_balance: Decimal,
+    store_credit_balance: Decimal,
     notes: Option<String>,
     created_at: DateTime<Utc>,
     updated_at: DateTime<Utc>,
@@ -99,12 +108,16 @@ impl Customer {
             email: None,
             phone: None,
             tax_id: None,
+            tax_id_country: None,
             billing_address: Address::default(),
             user_id: None,
             is_active: true,
             total_purchases: Decimal::ZERO,
             purchase_count: 0,
             last_purchase_at: None,
+            credit_limit: Decimal::ZERO,
+            account_balance: Decimal::ZERO,
+            store_credit_balance: Decimal::ZERO,
             notes: None,
             created_at: now,
             updated_at: now,
@@ -124,12 +137,16 @@ impl Customer {
         email: Option<String>,
         phone: Option<String>,
         tax_id: Option<String>,
+        tax_id_country: Option<String>,
         billing_address: Address,
         user_id: Option<UserId>,
         is_active: bool,
         total_purchases: Decimal,
         purchase_count: i32,
         last_purchase_at: Option<DateTime<Utc>>,
+        credit_limit: Decimal,
+        account_balance: Decimal,
+        store_credit_balance: Decimal,
         notes: Option<String>,
         created_at: DateTime<Utc>,
         updated_at: DateTime<Utc>,
@@ -145,12 +162,16 @@ impl Customer {
             email,
             phone,
             tax_id,
+            tax_id_country,
             billing_address,
             user_id,
             is_active,
             total_purchases,
             purchase_count,
             last_purchase_at,
+            credit_limit,
+            account_balance,
+            store_credit_balance,
             notes,
             created_at,
             updated_at,
@@ -185,6 +206,45 @@ impl Customer {
         self.updated_at = Utc::now();
     }
 
+    /// Returns true if the customer may buy on account
+    pub fn allows_credit(&self) -> bool {
+        self.credit_limit > Decimal::ZERO
+    }
+
+    /// Credit still available before reaching the limit
+    pub fn available_credit(&self) -> Decimal {
+        (self.credit_limit - self.account_balance).max(Decimal::ZERO)
+    }
+
+    /// Checks that `amount` can be charged to the account.
+    ///
+    /// This is a check against the loaded balance; the repository enforces
+    /// the limit again atomically when the charge is posted.
+    pub fn ensure_can_charge(&self, amount: Decimal) -> Result<(), SalesError> {
+        self.validate_active()?;
+        if !self.allows_credit() {
+            return Err(SalesError::CustomerCreditNotAllowed(self.id.into_uuid()));
+        }
+        if self.account_balance + amount > self.credit_limit {
+            return Err(SalesError::CreditLimitExceeded(self.available_credit()));
+        }
+        Ok(())
+    }
+
+    /// Checks that `amount` of store credit can be spent.
+    ///
+    /// This is a check against the loaded balance; the repository enforces
+    /// it again atomically when the credit is spent.
+    pub fn ensure_can_spend_store_credit(&self, amount: Decimal) -> Result<(), SalesError> {
+        self.validate_active()?;
+        if amount > self.store_credit_balance {
+            return Err(SalesError::InsufficientStoreCredit(
+                self.store_credit_balance,
+            ));
+        }
+        Ok(())
+    }
+
     /// Returns the customer's full name
     pub fn full_name(&self) -> String {
         format!("{} {}", self.first_name, self.last_name)
@@ -245,6 +305,11 @@ impl Customer {
         self.tax_id.as_deref()
     }
 
+    /// Country the tax id was validated for
+    pub fn tax_id_country(&self) -> Option<&str> {
+        self.tax_id_country.as_deref()
+    }
+
     pub fn billing_address(&self) -> &Address {
         &self.billing_address
     }
@@ -269,6 +334,19 @@ impl Customer {
         self.last_purchase_at
     }
 
+    pub fn credit_limit(&self) -> Decimal {
+        self.credit_limit
+    }
+
+    /// Amount currently owed on account
+    pub fn account_balance(&self) -> Decimal {
+        self.account_balance
+    }
+
+    pub fn store_credit_balance(&self) -> Decimal {
+        self.store_credit_balance
+    }
+
     pub fn notes(&self) -> Option<&str> {
         self.notes.as_deref()
     }
@@ -315,8 +393,9 @@ impl Customer {
         self.updated_at = Utc::now();
     }
 
-    pub fn set_tax_id(&mut self, tax_id: Option<String>) {
-        self.tax_id = tax_id;
+    pub fn set_tax_id(&mut self, tax_id: Option<TaxId>) {
+        self.tax_id_country = tax_id.as_ref().map(|t| t.country().to_string());
+        self.tax_id = tax_id.map(|t| t.value().to_string());
         self.updated_at = Utc::now();
     }
 
@@ -334,6 +413,15 @@ impl Customer {
         self.notes = notes;
         self.updated_at = Utc::now();
     }
+
+    pub fn set_credit_limit(&mut self, credit_limit: Decimal) -> Result<(), SalesError> {
+        if credit_limit < Decimal::ZERO {
+            return Err(SalesError::InvalidCreditLimit);
+        }
+        self.credit_limit = credit_limit;
+        self.updated_at = Utc::now();
+        Ok(())
+    }
 }
 
 #[cfg(test)]
@@ -416,6 +504,56 @@ mod tests {
         assert_eq!(customer.purchase_count(), 2);
     }
 
+    #[test]
+    fn test_credit_limit_enforcement() {
+        let mut customer = Customer::create(
+            StoreId::new(),
+            "CUS-001".to_string(),
+            "Acme".to_string(),
+            "Buyer".to_string(),
+            CustomerType::Business,
+        );
+        assert!(matches!(
+            customer.ensure_can_charge(dec!(1)),
+            Err(SalesError::CustomerCreditNotAllowed(_))
+        ));
+        assert!(matches!(
+            customer.set_credit_limit(dec!(-1)),
+            Err(SalesError::InvalidCreditLimit)
+        ));
+
+        customer.set_credit_limit(dec!(500)).unwrap();
+        assert!(customer.allows_credit());
+        assert_eq!(customer.available_credit(), dec!(500));
+        assert!(customer.ensure_can_charge(dec!(500)).is_ok());
+        assert!(matches!(
+            customer.ensure_can_charge(dec!(500.01)),
+            Err(SalesError::CreditLimitExceeded(available)) if available == dec!(500)
+        ));
+    }
+
+    #[test]
+    fn test_store_credit_spending() {
+        let customer = Customer::create(
+            StoreId::new(),
+            "CUS-001".to_string(),
+            "John".to_string(),
+            "Doe".to_string(),
+            CustomerType::Individual,
+        );
+
+        assert_eq!(customer.store_credit_balance(), Decimal::ZERO);
+        assert!(
+            customer
+                .ensure_can_spend_store_credit(Decimal::ZERO)
+                .is_ok()
+        );
+        assert!(matches!(
+            customer.ensure_can_spend_store_credit(dec!(0.01)),
+            Err(SalesError::InsufficientStoreCredit(available)) if available == Decimal::ZERO
+        ));
+    }
+
     #[test]
     fn test_display_name() {
         let individual = Customer::create(
@@ -0,0 +1,263 @@
+//! CustomerAccountEntry entity - a line on a customer's account ledger
+
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use uuid::{NoContext, Timestamp, Uuid};
+
+use crate::SalesError;
+use crate::domain::value_objects::{AccountEntryType, CustomerId};
+use identity::{StoreId, UserId};
+
+/// A charge or credit on a customer's account.
+///
+/// `amount` is signed: charges (on-account sales) are positive and credits
+/// (payments, credit notes, voids) are negative. `balance_after` is the
+/// account balance once the entry was posted; it and `created_at` are set
+/// by the repository, which posts entries atomically with the balance
+/// update so entries are timestamped in the order they hit the balance.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CustomerAccountEntry {
+    id: Uuid,
+    customer_id: CustomerId,
+    store_id: StoreId,
+    entry_type: AccountEntryType,
+    amount: Decimal,
+    balance_after: Decimal,
+    reference_id: Option<Uuid>,
+    reference_number: Option<String>,
+    notes: Option<String>,
+    created_by_id: Option<UserId>,
+    created_at: DateTime<Utc>,
+}
+
+impl CustomerAccountEntry {
+    /// Creates a charge for an on-account sale
+    pub fn charge(
+        customer_id: CustomerId,
+        store_id: StoreId,
+        amount: Decimal,
+        sale_id: Uuid,
+        sale_number: String,
+        created_by_id: Option<UserId>,
+    ) -> Result<Self, SalesError> {
+        Self::create(
+            customer_id,
+            store_id,
+            AccountEntryType::Sale,
+            amount,
+            Some(sale_id),
+            Some(sale_number),
+            created_by_id,
+        )
+    }
+
+    /// Creates a credit of `amount` (given as a positive value)
+    pub fn credit(
+        customer_id: CustomerId,
+        store_id: StoreId,
+        entry_type: AccountEntryType,
+        amount: Decimal,
+        reference_id: Option<Uuid>,
+        reference_number: Option<String>,
+        created_by_id: Option<UserId>,
+    ) -> Result<Self, SalesError> {
+        if entry_type.is_charge() {
+            return Err(SalesError::InvalidAccountEntryType);
+        }
+        Self::create(
+            customer_id,
+            store_id,
+            entry_type,
+            -amount,
+            reference_id,
+            reference_number,
+            created_by_id,
+        )
+    }
+
+    fn create(
+        customer_id: CustomerId,
+        store_id: StoreId,
+        entry_type: AccountEntryType,
+        amount: Decimal,
+        reference_id: Option<Uuid>,
+        reference_number: Option<String>,
+        created_by_id: Option<UserId>,
+    ) -> Result<Self, SalesError> {
+        if amount.is_zero() || (amount > Decimal::ZERO) != entry_type.is_charge() {
+            return Err(SalesError::InvalidPaymentAmount);
+        }
+        Ok(Self {
+            id: Uuid::new_v7(Timestamp::now(NoContext)),
+            customer_id,
+            store_id,
+            entry_type,
+            amount,
+            balance_after: Decimal::ZERO,
+            reference_id,
+            reference_number,
+            notes: None,
+            created_by_id,
+            created_at: Utc::now(),
+        })
+    }
+
+    /// Reconstitutes an entry from persistence
+    #[allow(clippy::too_many_arguments)]
+    pub fn reconstitute(
+        id: Uuid,
+        customer_id: CustomerId,
+        store_id: StoreId,
+        entry_type: AccountEntryType,
+        amount: Decimal,
+        balance_after: Decimal,
+        reference_id: Option<Uuid>,
+        reference_number: Option<String>,
+        notes: Option<String>,
+        created_by_id: Option<UserId>,
+        created_at: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            id,
+            customer_id,
+            store_id,
+            entry_type,
+            amount,
+            balance_after,
+            reference_id,
+            reference_number,
+            notes,
+            created_by_id,
+            created_at,
+        }
+    }
+
+    pub fn id(&self) -> Uuid {
+        self.id
+    }
+
+    pub fn customer_id(&self) -> CustomerId {
+        self.customer_id
+    }
+
+    pub fn store_id(&self) -> StoreId {
+        self.store_id
+    }
+
+    pub fn entry_type(&self) -> AccountEntryType {
+        self.entry_type
+    }
+
+    pub fn amount(&self) -> Decimal {
+        self.amount
+    }
+
+    pub fn balance_after(&self) -> Decimal {
+        self.balance_after
+    }
+
+    pub fn reference_id(&self) -> Option<Uuid> {
+        self.reference_id
+    }
+
+    pub fn reference_number(&self) -> Option<&str> {
+        self.reference_number.as_deref()
+    }
+
+    pub fn notes(&self) -> Option<&str> {
+        self.notes.as_deref()
+    }
+
+    pub fn created_by_id(&self) -> Option<UserId> {
+        self.created_by_id
+    }
+
+    pub fn created_at(&self) -> DateTime<Utc> {
+        self.created_at
+    }
+
+    pub fn set_notes(&mut self, notes: Option<String>) {
+        self.notes = notes;
+    }
+
+    /// Records the balance resulting from posting the entry and when it was
+    /// posted
+    pub fn mark_posted(&mut self, balance_after: Decimal, posted_at: DateTime<Utc>) {
+        self.balance_after = balance_after;
+        self.created_at = posted_at;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    #[test]
+    fn test_charge_and_credit_signs() {
+        let (customer_id, store_id) = (CustomerId::new(), StoreId::new());
+        let charge = CustomerAccountEntry::charge(
+            customer_id,
+            store_id,
+            dec!(100),
+            Uuid::nil(),
+            "S-1".to_string(),
+            None,
+        )
+        .unwrap();
+        assert_eq!(charge.amount(), dec!(100));
+
+        let payment = CustomerAccountEntry::credit(
+            customer_id,
+            store_id,
+            AccountEntryType::Payment,
+            dec!(40),
+            None,
+            None,
+            None,
+        )
+        .unwrap();
+        assert_eq!(payment.amount(), dec!(-40));
+    }
+
+    #[test]
+    fn test_rejects_non_positive_amounts_and_charge_credits() {
+        let (customer_id, store_id) = (CustomerId::new(), StoreId::new());
+        assert!(
+            CustomerAccountEntry::charge(
+                customer_id,
+                store_id,
+                dec!(0),
+                Uuid::nil(),
+                "S-1".to_string(),
+                None
+            )
+            .is_err()
+        );
+        assert!(
+            CustomerAccountEntry::credit(
+                customer_id,
+                store_id,
+                AccountEntryType::Payment,
+                dec!(-5),
+                None,
+                None,
+                None
+            )
+            .is_err()
+        );
+        assert!(
+            CustomerAccountEntry::credit(
+                customer_id,
+                store_id,
+                AccountEntryType::Sale,
+                dec!(5),
+                None,
+                None,
+                None
+            )
+            .is_err()
+        );
+    }
+}
@@ -0,0 +1,140 @@
+// ExchangeRate entity - a store's rate for a foreign currency
+
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::SalesError;
+use identity::UserId;
+use inventory::Currency;
+
+/// Rate a store takes a foreign currency at: how many units of the store's
+/// base currency one unit of `currency` is worth.
+///
+/// Rates are never edited. A new rate is recorded from the moment it takes
+/// effect, so the history of a currency stays available for reprinting old
+/// sales.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExchangeRate {
+    id: Uuid,
+    store_id: Uuid,
+    currency: Currency,
+    rate: Decimal,
+    effective_at: DateTime<Utc>,
+    created_by_id: UserId,
+    created_at: DateTime<Utc>,
+}
+
+impl ExchangeRate {
+    /// Records a rate taking effect now
+    pub fn create(
+        store_id: Uuid,
+        currency: Currency,
+        rate: Decimal,
+        created_by_id: UserId,
+    ) -> Result<Self, SalesError> {
+        validate_rate(rate)?;
+        let now = Utc::now();
+        Ok(Self {
+            id: Uuid::now_v7(),
+            store_id,
+            currency,
+            rate,
+            effective_at: now,
+            created_by_id,
+            created_at: now,
+        })
+    }
+
+    /// Reconstitutes an ExchangeRate from persistence
+    pub fn reconstitute(
+        id: Uuid,
+        store_id: Uuid,
+        currency: Currency,
+        rate: Decimal,
+        effective_at: DateTime<Utc>,
+        created_by_id: UserId,
+        created_at: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            id,
+            store_id,
+            currency,
+            rate,
+            effective_at,
+            created_by_id,
+            created_at,
+        }
+    }
+
+    /// Converts an amount in the rate's currency to the base currency
+    pub fn to_base(&self, amount: Decimal) -> Decimal {
+        to_base(amount, self.rate)
+    }
+
+    // =========================================================================
+    // Getters
+    // =========================================================================
+    pub fn id(&self) -> Uuid {
+        self.id
+    }
+    pub fn store_id(&self) -> Uuid {
+        self.store_id
+    }
+    pub fn currency(&self) -> &Currency {
+        &self.currency
+    }
+    pub fn rate(&self) -> Decimal {
+        self.rate
+    }
+    pub fn effective_at(&self) -> DateTime<Utc> {
+        self.effective_at
+    }
+    pub fn created_by_id(&self) -> UserId {
+        self.created_by_id
+    }
+    pub fn created_at(&self) -> DateTime<Utc> {
+        self.created_at
+    }
+}
+
+/// Rejects rates that aren't positive
+pub(crate) fn validate_rate(rate: Decimal) -> Result<(), SalesError> {
+    if rate <= Decimal::ZERO {
+        return Err(SalesError::InvalidExchangeRate);
+    }
+    Ok(())
+}
+
+/// Converts an amount to the base currency at `rate`, rounded to cents
+pub(crate) fn to_base(amount: Decimal, rate: Decimal) -> Decimal {
+    (amount * rate).round_dp(2)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    #[test]
+    fn test_rate_must_be_positive() {
+        let create =
+            |rate| ExchangeRate::create(Uuid::now_v7(), Currency::usd(), rate, UserId::new());
+        assert!(create(Decimal::ZERO).is_err());
+        assert!(create(dec!(-1)).is_err());
+        assert!(create(dec!(24.65)).is_ok());
+    }
+
+    #[test]
+    fn test_to_base_rounds_to_cents() {
+        let rate = ExchangeRate::create(
+            Uuid::now_v7(),
+            Currency::usd(),
+            dec!(24.6517),
+            UserId::new(),
+        )
+        .unwrap();
+        assert_eq!(rate.to_base(dec!(20)), dec!(493.03));
+    }
+}
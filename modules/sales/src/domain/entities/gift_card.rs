@@ -0,0 +1,346 @@
+//! GiftCard entity - a stored-value card sold and redeemed at the POS
+
+use chrono::{DateTime, Utc};
+use rand::Rng;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+use crate::SalesError;
+use crate::domain::value_objects::{GiftCardId, GiftCardStatus, SaleId, SaleItemId};
+use identity::{StoreId, UserId};
+use inventory::Currency;
+
+/// Characters used for generated gift card codes, leaving out the ones that
+/// are easy to confuse when read aloud or typed (0/O, 1/I/L)
+const GIFT_CARD_CODE_ALPHABET: &[u8] = b"23456789ABCDEFGHJKMNPQRSTUVWXYZ";
+
+/// Length of a generated gift card code
+pub const GIFT_CARD_CODE_LENGTH: usize = 16;
+
+/// Shortest and longest code accepted when the code is chosen by the store,
+/// e.g. one printed on a pre-made card
+const MIN_GIFT_CARD_CODE_LENGTH: usize = 6;
+const MAX_GIFT_CARD_CODE_LENGTH: usize = 32;
+
+/// A gift card holding a balance that pays for sales.
+///
+/// A card issued directly is active at once. A card sold as a line of a sale
+/// stays pending until that sale is completed, so an abandoned or voided
+/// sale never leaves a spendable card behind. Redemptions draw the balance
+/// down; a card whose balance reaches zero is depleted.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GiftCard {
+    id: GiftCardId,
+    store_id: StoreId,
+    code: String,
+    currency: Currency,
+    initial_balance: Decimal,
+    current_balance: Decimal,
+    status: GiftCardStatus,
+    sale_id: Option<SaleId>,
+    sale_item_id: Option<SaleItemId>,
+    issued_by_id: UserId,
+    created_at: DateTime<Utc>,
+    updated_at: DateTime<Utc>,
+}
+
+impl GiftCard {
+    /// Issues an active card for `amount`, with a fresh code unless one is
+    /// given
+    pub fn issue(
+        store_id: StoreId,
+        code: Option<&str>,
+        amount: Decimal,
+        currency: Currency,
+        issued_by_id: UserId,
+    ) -> Result<Self, SalesError> {
+        if amount <= Decimal::ZERO {
+            return Err(SalesError::InvalidGiftCardAmount);
+        }
+        let code = match code {
+            Some(code) => validate_code(code)?,
+            None => generate_gift_card_code(),
+        };
+        let now = Utc::now();
+        Ok(Self {
+            id: GiftCardId::new(),
+            store_id,
+            code,
+            currency,
+            initial_balance: amount,
+            current_balance: amount,
+            status: GiftCardStatus::Active,
+            sale_id: None,
+            sale_item_id: None,
+            issued_by_id,
+            created_at: now,
+            updated_at: now,
+        })
+    }
+
+    /// Issues a card sold on `sale_item_id` of a sale; it stays pending until
+    /// the sale is completed
+    pub fn issue_on_sale(
+        store_id: StoreId,
+        code: Option<&str>,
+        amount: Decimal,
+        currency: Currency,
+        issued_by_id: UserId,
+        sale_id: SaleId,
+        sale_item_id: SaleItemId,
+    ) -> Result<Self, SalesError> {
+        let mut card = Self::issue(store_id, code, amount, currency, issued_by_id)?;
+        card.status = GiftCardStatus::Pending;
+        card.sale_id = Some(sale_id);
+        card.sale_item_id = Some(sale_item_id);
+        Ok(card)
+    }
+
+    /// Reconstitutes a GiftCard from persistence
+    #[allow(clippy::too_many_arguments)]
+    pub fn reconstitute(
+        id: GiftCardId,
+        store_id: StoreId,
+        code: String,
+        currency: Currency,
+        initial_balance: Decimal,
+        current_balance: Decimal,
+        status: GiftCardStatus,
+        sale_id: Option<SaleId>,
+        sale_item_id: Option<SaleItemId>,
+        issued_by_id: UserId,
+        created_at: DateTime<Utc>,
+        updated_at: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            id,
+            store_id,
+            code,
+            currency,
+            initial_balance,
+            current_balance,
+            status,
+            sale_id,
+            sale_item_id,
+            issued_by_id,
+            created_at,
+            updated_at,
+        }
+    }
+
+    /// Activates a card sold on a sale once the sale is completed
+    pub fn activate(&mut self) -> Result<(), SalesError> {
+        if self.status != GiftCardStatus::Pending {
+            return Err(SalesError::InvalidStatusTransition);
+        }
+        self.status = GiftCardStatus::Active;
+        self.updated_at = Utc::now();
+        Ok(())
+    }
+
+    /// How much of `requested` the card can pay: all of it, or its whole
+    /// balance when that is less
+    pub fn redeemable_amount(&self, requested: Decimal) -> Result<Decimal, SalesError> {
+        self.ensure_redeemable()?;
+        Ok(requested.min(self.current_balance))
+    }
+
+    /// Spends `amount` from the balance
+    pub fn redeem(&mut self, amount: Decimal) -> Result<(), SalesError> {
+        if amount <= Decimal::ZERO {
+            return Err(SalesError::InvalidPaymentAmount);
+        }
+        self.ensure_redeemable()?;
+        if amount > self.current_balance {
+            return Err(SalesError::InsufficientGiftCardBalance);
+        }
+        self.current_balance -= amount;
+        if self.current_balance.is_zero() {
+            self.status = GiftCardStatus::Depleted;
+        }
+        self.updated_at = Utc::now();
+        Ok(())
+    }
+
+    fn ensure_redeemable(&self) -> Result<(), SalesError> {
+        match self.status {
+            GiftCardStatus::Active => Ok(()),
+            GiftCardStatus::Depleted => Err(SalesError::GiftCardDepleted),
+            GiftCardStatus::Pending => Err(SalesError::GiftCardNotActive),
+        }
+    }
+
+    // =========================================================================
+    // Getters
+    // =========================================================================
+
+    pub fn id(&self) -> GiftCardId {
+        self.id
+    }
+
+    pub fn store_id(&self) -> StoreId {
+        self.store_id
+    }
+
+    pub fn code(&self) -> &str {
+        &self.code
+    }
+
+    pub fn currency(&self) -> &Currency {
+        &self.currency
+    }
+
+    pub fn initial_balance(&self) -> Decimal {
+        self.initial_balance
+    }
+
+    pub fn current_balance(&self) -> Decimal {
+        self.current_balance
+    }
+
+    pub fn status(&self) -> GiftCardStatus {
+        self.status
+    }
+
+    pub fn sale_id(&self) -> Option<SaleId> {
+        self.sale_id
+    }
+
+    pub fn sale_item_id(&self) -> Option<SaleItemId> {
+        self.sale_item_id
+    }
+
+    pub fn issued_by_id(&self) -> UserId {
+        self.issued_by_id
+    }
+
+    pub fn created_at(&self) -> DateTime<Utc> {
+        self.created_at
+    }
+
+    pub fn updated_at(&self) -> DateTime<Utc> {
+        self.updated_at
+    }
+}
+
+/// Normalizes a code as entered at the counter: surrounding whitespace is
+/// dropped and letters are upper-cased
+pub fn normalize_gift_card_code(code: &str) -> String {
+    code.trim().to_ascii_uppercase()
+}
+
+fn validate_code(code: &str) -> Result<String, SalesError> {
+    let code = normalize_gift_card_code(code);
+    let valid_len = (MIN_GIFT_CARD_CODE_LENGTH..=MAX_GIFT_CARD_CODE_LENGTH).contains(&code.len());
+    if !valid_len || !code.chars().all(|c| c.is_ascii_alphanumeric() || c == '-') {
+        return Err(SalesError::InvalidGiftCardCode);
+    }
+    Ok(code)
+}
+
+fn generate_gift_card_code() -> String {
+    let mut rng = rand::thread_rng();
+    (0..GIFT_CARD_CODE_LENGTH)
+        .map(|_| GIFT_CARD_CODE_ALPHABET[rng.gen_range(0..GIFT_CARD_CODE_ALPHABET.len())] as char)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    fn issue(amount: Decimal) -> GiftCard {
+        GiftCard::issue(StoreId::new(), None, amount, Currency::hnl(), UserId::new()).unwrap()
+    }
+
+    #[test]
+    fn test_issue_generates_code_and_is_active() {
+        let card = issue(dec!(500));
+
+        assert_eq!(card.code().len(), GIFT_CARD_CODE_LENGTH);
+        assert_eq!(card.status(), GiftCardStatus::Active);
+        assert_eq!(card.current_balance(), dec!(500));
+    }
+
+    #[test]
+    fn test_issue_normalizes_given_code_and_rejects_bad_ones() {
+        let card = GiftCard::issue(
+            StoreId::new(),
+            Some(" gc-2024-0001 "),
+            dec!(100),
+            Currency::hnl(),
+            UserId::new(),
+        )
+        .unwrap();
+        assert_eq!(card.code(), "GC-2024-0001");
+
+        for code in ["abc", "GC 2024 0001"] {
+            let result = GiftCard::issue(
+                StoreId::new(),
+                Some(code),
+                dec!(100),
+                Currency::hnl(),
+                UserId::new(),
+            );
+            assert!(matches!(result, Err(SalesError::InvalidGiftCardCode)));
+        }
+        let result = GiftCard::issue(
+            StoreId::new(),
+            None,
+            dec!(0),
+            Currency::hnl(),
+            UserId::new(),
+        );
+        assert!(matches!(result, Err(SalesError::InvalidGiftCardAmount)));
+    }
+
+    #[test]
+    fn test_redeemable_amount_caps_at_balance() {
+        let card = issue(dec!(50));
+
+        assert_eq!(card.redeemable_amount(dec!(20)).unwrap(), dec!(20));
+        assert_eq!(card.redeemable_amount(dec!(80)).unwrap(), dec!(50));
+    }
+
+    #[test]
+    fn test_redeem_depletes_card() {
+        let mut card = issue(dec!(50));
+
+        card.redeem(dec!(30)).unwrap();
+        assert_eq!(card.current_balance(), dec!(20));
+        assert!(matches!(
+            card.redeem(dec!(25)),
+            Err(SalesError::InsufficientGiftCardBalance)
+        ));
+
+        card.redeem(dec!(20)).unwrap();
+        assert_eq!(card.status(), GiftCardStatus::Depleted);
+        assert!(matches!(
+            card.redeemable_amount(dec!(1)),
+            Err(SalesError::GiftCardDepleted)
+        ));
+    }
+
+    #[test]
+    fn test_card_sold_on_sale_is_pending_until_activated() {
+        let mut card = GiftCard::issue_on_sale(
+            StoreId::new(),
+            None,
+            dec!(50),
+            Currency::hnl(),
+            UserId::new(),
+            SaleId::new(),
+            SaleItemId::new(),
+        )
+        .unwrap();
+
+        assert!(matches!(
+            card.redeem(dec!(10)),
+            Err(SalesError::GiftCardNotActive)
+        ));
+        card.activate().unwrap();
+        card.redeem(dec!(10)).unwrap();
+        assert!(card.activate().is_err());
+    }
+}
@@ -0,0 +1,443 @@
+//! Layaway entity - a POS sale paid off in installments while its items are
+//! held for the customer
+
+use chrono::{DateTime, Duration, NaiveDate, Utc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+use crate::SalesError;
+use crate::domain::value_objects::{LayawayId, LayawayStatus, SaleId};
+use identity::{StoreId, UserId};
+
+/// Most installments a layaway can be split into
+pub const MAX_LAYAWAY_INSTALLMENTS: i32 = 52;
+
+/// Longest time allowed between two installments
+pub const MAX_LAYAWAY_INTERVAL_DAYS: i32 = 90;
+
+/// One scheduled payment of a layaway
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LayawayInstallment {
+    number: i32,
+    due_date: NaiveDate,
+    amount: Decimal,
+    amount_paid: Decimal,
+}
+
+impl LayawayInstallment {
+    /// Reconstitutes an installment from persistence
+    pub fn reconstitute(
+        number: i32,
+        due_date: NaiveDate,
+        amount: Decimal,
+        amount_paid: Decimal,
+    ) -> Self {
+        Self {
+            number,
+            due_date,
+            amount,
+            amount_paid,
+        }
+    }
+
+    /// What is still owed on this installment
+    pub fn outstanding(&self) -> Decimal {
+        self.amount - self.amount_paid
+    }
+
+    pub fn is_paid(&self) -> bool {
+        self.outstanding() <= Decimal::ZERO
+    }
+
+    pub fn number(&self) -> i32 {
+        self.number
+    }
+
+    pub fn due_date(&self) -> NaiveDate {
+        self.due_date
+    }
+
+    pub fn amount(&self) -> Decimal {
+        self.amount
+    }
+
+    pub fn amount_paid(&self) -> Decimal {
+        self.amount_paid
+    }
+}
+
+/// A layaway: the terms and payment schedule of a sale put on layaway.
+///
+/// The layaway is opened with the sale and holds only its terms. The deposit
+/// (the sale's first payment) activates it: whatever the deposit leaves due
+/// is split into `installment_count` installments, one every
+/// `interval_days`. Later payments pay the installments off oldest first.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Layaway {
+    id: LayawayId,
+    sale_id: SaleId,
+    store_id: StoreId,
+    status: LayawayStatus,
+    installment_count: i32,
+    interval_days: i32,
+    installments: Vec<LayawayInstallment>,
+    restocking_fee: Decimal,
+    refund_amount: Decimal,
+    cancel_reason: Option<String>,
+    created_by_id: UserId,
+    activated_at: Option<DateTime<Utc>>,
+    completed_at: Option<DateTime<Utc>>,
+    cancelled_at: Option<DateTime<Utc>>,
+    created_at: DateTime<Utc>,
+    updated_at: DateTime<Utc>,
+}
+
+impl Layaway {
+    /// Opens a layaway for a sale with the agreed terms
+    pub fn create(
+        sale_id: SaleId,
+        store_id: StoreId,
+        installment_count: i32,
+        interval_days: i32,
+        created_by_id: UserId,
+    ) -> Result<Self, SalesError> {
+        if !(1..=MAX_LAYAWAY_INSTALLMENTS).contains(&installment_count) {
+            return Err(SalesError::InvalidLayawayTerms(format!(
+                "installments must be between 1 and {}",
+                MAX_LAYAWAY_INSTALLMENTS
+            )));
+        }
+        if !(1..=MAX_LAYAWAY_INTERVAL_DAYS).contains(&interval_days) {
+            return Err(SalesError::InvalidLayawayTerms(format!(
+                "interval must be between 1 and {} days",
+                MAX_LAYAWAY_INTERVAL_DAYS
+            )));
+        }
+
+        let now = Utc::now();
+        Ok(Self {
+            id: LayawayId::new(),
+            sale_id,
+            store_id,
+            status: LayawayStatus::Open,
+            installment_count,
+            interval_days,
+            installments: Vec::new(),
+            restocking_fee: Decimal::ZERO,
+            refund_amount: Decimal::ZERO,
+            cancel_reason: None,
+            created_by_id,
+            activated_at: None,
+            completed_at: None,
+            cancelled_at: None,
+            created_at: now,
+            updated_at: now,
+        })
+    }
+
+    /// Reconstitutes a Layaway from persistence
+    #[allow(clippy::too_many_arguments)]
+    pub fn reconstitute(
+        id: LayawayId,
+        sale_id: SaleId,
+        store_id: StoreId,
+        status: LayawayStatus,
+        installment_count: i32,
+        interval_days: i32,
+        installments: Vec<LayawayInstallment>,
+        restocking_fee: Decimal,
+        refund_amount: Decimal,
+        cancel_reason: Option<String>,
+        created_by_id: UserId,
+        activated_at: Option<DateTime<Utc>>,
+        completed_at: Option<DateTime<Utc>>,
+        cancelled_at: Option<DateTime<Utc>>,
+        created_at: DateTime<Utc>,
+        updated_at: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            id,
+            sale_id,
+            store_id,
+            status,
+            installment_count,
+            interval_days,
+            installments,
+            restocking_fee,
+            refund_amount,
+            cancel_reason,
+            created_by_id,
+            activated_at,
+            completed_at,
+            cancelled_at,
+            created_at,
+            updated_at,
+        }
+    }
+
+    /// Activates the layaway once its deposit is paid, scheduling the
+    /// `balance` left. Installments are equal, rounded to cents, with the
+    /// last one absorbing the rounding; the first is due `interval_days`
+    /// after `start`.
+    pub fn activate(&mut self, balance: Decimal, start: NaiveDate) -> Result<(), SalesError> {
+        if self.status != LayawayStatus::Open {
+            return Err(SalesError::InvalidStatusTransition);
+        }
+        if balance <= Decimal::ZERO {
+            return Err(SalesError::InvalidPaymentAmount);
+        }
+
+        let count = self.installment_count;
+        let amount = (balance / Decimal::from(count)).round_dp(2);
+        self.installments = (1..=count)
+            .map(|number| LayawayInstallment {
+                number,
+                due_date: start + Duration::days(i64::from(self.interval_days * number)),
+                amount: if number == count {
+                    balance - amount * Decimal::from(count - 1)
+                } else {
+                    amount
+                },
+                amount_paid: Decimal::ZERO,
+            })
+            .collect();
+
+        let now = Utc::now();
+        self.status = LayawayStatus::Active;
+        self.activated_at = Some(now);
+        self.updated_at = now;
+        Ok(())
+    }
+
+    /// Applies an installment payment to the schedule, oldest installment
+    /// first. Anything beyond the schedule is ignored; the sale's own
+    /// balance is what decides when it is paid off.
+    pub fn apply_payment(&mut self, amount: Decimal) -> Result<(), SalesError> {
+        if self.status != LayawayStatus::Active {
+            return Err(SalesError::InvalidStatusTransition);
+        }
+        if amount <= Decimal::ZERO {
+            return Err(SalesError::InvalidPaymentAmount);
+        }
+
+        let mut remaining = amount;
+        for installment in self.installments.iter_mut().filter(|i| !i.is_paid()) {
+            let applied = remaining.min(installment.outstanding());
+            installment.amount_paid += applied;
+            remaining -= applied;
+            if remaining.is_zero() {
+                break;
+            }
+        }
+        self.updated_at = Utc::now();
+        Ok(())
+    }
+
+    /// Closes the layaway when its sale is completed
+    pub fn complete(&mut self) -> Result<(), SalesError> {
+        if !self.status.is_open() {
+            return Err(SalesError::InvalidStatusTransition);
+        }
+
+        let now = Utc::now();
+        self.status = LayawayStatus::Completed;
+        self.completed_at = Some(now);
+        self.updated_at = now;
+        Ok(())
+    }
+
+    /// Cancels the layaway. Of the `amount_paid` on its sale, the
+    /// restocking fee is kept and the rest is owed back to the customer.
+    pub fn cancel(
+        &mut self,
+        restocking_fee: Decimal,
+        amount_paid: Decimal,
+        reason: Option<String>,
+    ) -> Result<(), SalesError> {
+        if !self.status.is_open() {
+            return Err(SalesError::InvalidStatusTransition);
+        }
+        if restocking_fee < Decimal::ZERO || restocking_fee > amount_paid {
+            return Err(SalesError::InvalidRestockingFee);
+        }
+
+        let now = Utc::now();
+        self.status = LayawayStatus::Cancelled;
+        self.restocking_fee = restocking_fee;
+        self.refund_amount = amount_paid - restocking_fee;
+        self.cancel_reason = reason;
+        self.cancelled_at = Some(now);
+        self.updated_at = now;
+        Ok(())
+    }
+
+    /// The earliest installment not yet paid off
+    pub fn next_installment(&self) -> Option<&LayawayInstallment> {
+        self.installments.iter().find(|i| !i.is_paid())
+    }
+
+    /// What is owed on installments due before `today`
+    pub fn overdue_amount(&self, today: NaiveDate) -> Decimal {
+        self.installments
+            .iter()
+            .filter(|i| i.due_date < today)
+            .map(|i| i.outstanding())
+            .sum()
+    }
+
+    /// Due date of the last installment, once scheduled
+    pub fn final_due_date(&self) -> Option<NaiveDate> {
+        self.installments.last().map(|i| i.due_date)
+    }
+
+    // =========================================================================
+    // Getters
+    // =========================================================================
+
+    pub fn id(&self) -> LayawayId {
+        self.id
+    }
+
+    pub fn sale_id(&self) -> SaleId {
+        self.sale_id
+    }
+
+    pub fn store_id(&self) -> StoreId {
+        self.store_id
+    }
+
+    pub fn status(&self) -> LayawayStatus {
+        self.status
+    }
+
+    pub fn installment_count(&self) -> i32 {
+        self.installment_count
+    }
+
+    pub fn interval_days(&self) -> i32 {
+        self.interval_days
+    }
+
+    pub fn installments(&self) -> &[LayawayInstallment] {
+        &self.installments
+    }
+
+    pub fn restocking_fee(&self) -> Decimal {
+        self.restocking_fee
+    }
+
+    pub fn refund_amount(&self) -> Decimal {
+        self.refund_amount
+    }
+
+    pub fn cancel_reason(&self) -> Option<&str> {
+        self.cancel_reason.as_deref()
+    }
+
+    pub fn created_by_id(&self) -> UserId {
+        self.created_by_id
+    }
+
+    pub fn activated_at(&self) -> Option<DateTime<Utc>> {
+        self.activated_at
+    }
+
+    pub fn completed_at(&self) -> Option<DateTime<Utc>> {
+        self.completed_at
+    }
+
+    pub fn cancelled_at(&self) -> Option<DateTime<Utc>> {
+        self.cancelled_at
+    }
+
+    pub fn created_at(&self) -> DateTime<Utc> {
+        self.created_at
+    }
+
+    pub fn updated_at(&self) -> DateTime<Utc> {
+        self.updated_at
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    fn open(installments: i32, interval_days: i32) -> Layaway {
+        Layaway::create(
+            SaleId::new(),
+            StoreId::new(),
+            installments,
+            interval_days,
+            UserId::new(),
+        )
+        .unwrap()
+    }
+
+    fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    #[test]
+    fn test_create_validates_terms() {
+        for (installments, interval) in [(0, 30), (53, 30), (3, 0), (3, 91)] {
+            let result = Layaway::create(
+                SaleId::new(),
+                StoreId::new(),
+                installments,
+                interval,
+                UserId::new(),
+            );
+            assert!(matches!(result, Err(SalesError::InvalidLayawayTerms(_))));
+        }
+    }
+
+    #[test]
+    fn test_activate_splits_balance_into_installments() {
+        let mut layaway = open(3, 14);
+        layaway.activate(dec!(100), date(2026, 3, 1)).unwrap();
+
+        assert_eq!(layaway.status(), LayawayStatus::Active);
+        let amounts: Vec<_> = layaway.installments().iter().map(|i| i.amount()).collect();
+        assert_eq!(amounts, vec![dec!(33.33), dec!(33.33), dec!(33.34)]);
+        assert_eq!(layaway.installments()[0].due_date(), date(2026, 3, 15));
+        assert_eq!(layaway.final_due_date(), Some(date(2026, 4, 12)));
+        assert!(layaway.activate(dec!(100), date(2026, 3, 1)).is_err());
+    }
+
+    #[test]
+    fn test_apply_payment_pays_oldest_installment_first() {
+        let mut layaway = open(3, 30);
+        assert!(layaway.apply_payment(dec!(10)).is_err());
+        layaway.activate(dec!(90), date(2026, 1, 1)).unwrap();
+
+        layaway.apply_payment(dec!(40)).unwrap();
+
+        assert!(layaway.installments()[0].is_paid());
+        assert_eq!(layaway.installments()[1].amount_paid(), dec!(10));
+        let next = layaway.next_installment().unwrap();
+        assert_eq!(next.number(), 2);
+        assert_eq!(next.outstanding(), dec!(20));
+        assert_eq!(layaway.overdue_amount(date(2026, 3, 15)), dec!(20));
+        assert_eq!(layaway.overdue_amount(date(2026, 1, 15)), dec!(0));
+    }
+
+    #[test]
+    fn test_cancel_keeps_restocking_fee_and_refunds_the_rest() {
+        let mut layaway = open(2, 30);
+        assert!(matches!(
+            layaway.cancel(dec!(60), dec!(50), None),
+            Err(SalesError::InvalidRestockingFee)
+        ));
+
+        layaway
+            .cancel(dec!(5), dec!(50), Some("Changed mind".to_string()))
+            .unwrap();
+
+        assert_eq!(layaway.status(), LayawayStatus::Cancelled);
+        assert_eq!(layaway.restocking_fee(), dec!(5));
+        assert_eq!(layaway.refund_amount(), dec!(45));
+        assert!(layaway.complete().is_err());
+    }
+}
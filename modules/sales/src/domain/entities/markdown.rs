@@ -0,0 +1,217 @@
+// Markdown entity - log entry for an automatic discount on one product
+
+use chrono::{DateTime, Duration, Utc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::SalesError;
+use crate::domain::entities::MarkdownRule;
+use crate::domain::value_objects::{
+    MarkdownId, MarkdownReason, MarkdownRuleId, MarkdownStatus, PromotionId,
+};
+use identity::UserId;
+
+/// A discount applied automatically by a [`MarkdownRule`].
+///
+/// The discount itself is carried by a time-bounded, product-scoped
+/// promotion; this record keeps the reason and makes it reversible. Reversing
+/// a markdown deactivates its promotion.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Markdown {
+    id: MarkdownId,
+    rule_id: MarkdownRuleId,
+    store_id: Uuid,
+    product_id: Uuid,
+    promotion_id: PromotionId,
+    reason: MarkdownReason,
+    reason_detail: String,
+    discount_percent: Decimal,
+    starts_at: DateTime<Utc>,
+    ends_at: DateTime<Utc>,
+    status: MarkdownStatus,
+    reversed_by_id: Option<UserId>,
+    reversed_at: Option<DateTime<Utc>>,
+    reversal_reason: Option<String>,
+    created_at: DateTime<Utc>,
+}
+
+impl Markdown {
+    /// Creates an active markdown from a rule, starting now
+    pub fn create(
+        id: MarkdownId,
+        rule: &MarkdownRule,
+        product_id: Uuid,
+        promotion_id: PromotionId,
+        reason: MarkdownReason,
+        reason_detail: String,
+    ) -> Self {
+        let now = Utc::now();
+        Self {
+            id,
+            rule_id: rule.id(),
+            store_id: rule.store_id(),
+            product_id,
+            promotion_id,
+            reason,
+            reason_detail,
+            discount_percent: rule.discount_percent(),
+            starts_at: now,
+            ends_at: now + Duration::days(rule.duration_days() as i64),
+            status: MarkdownStatus::Active,
+            reversed_by_id: None,
+            reversed_at: None,
+            reversal_reason: None,
+            created_at: now,
+        }
+    }
+
+    /// Reconstitutes a Markdown from persistence
+    #[allow(clippy::too_many_arguments)]
+    pub fn reconstitute(
+        id: MarkdownId,
+        rule_id: MarkdownRuleId,
+        store_id: Uuid,
+        product_id: Uuid,
+        promotion_id: PromotionId,
+        reason: MarkdownReason,
+        reason_detail: String,
+        discount_percent: Decimal,
+        starts_at: DateTime<Utc>,
+        ends_at: DateTime<Utc>,
+        status: MarkdownStatus,
+        reversed_by_id: Option<UserId>,
+        reversed_at: Option<DateTime<Utc>>,
+        reversal_reason: Option<String>,
+        created_at: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            id,
+            rule_id,
+            store_id,
+            product_id,
+            promotion_id,
+            reason,
+            reason_detail,
+            discount_percent,
+            starts_at,
+            ends_at,
+            status,
+            reversed_by_id,
+            reversed_at,
+            reversal_reason,
+            created_at,
+        }
+    }
+
+    /// Undoes an active markdown. The caller deactivates the promotion.
+    pub fn reverse(&mut self, actor_id: UserId, reason: Option<String>) -> Result<(), SalesError> {
+        if !self.status.can_reverse() {
+            return Err(SalesError::MarkdownNotReversible);
+        }
+        self.status = MarkdownStatus::Reversed;
+        self.reversed_by_id = Some(actor_id);
+        self.reversed_at = Some(Utc::now());
+        self.reversal_reason = reason;
+        Ok(())
+    }
+
+    // =========================================================================
+    // Getters
+    // =========================================================================
+    pub fn id(&self) -> MarkdownId {
+        self.id
+    }
+    pub fn rule_id(&self) -> MarkdownRuleId {
+        self.rule_id
+    }
+    pub fn store_id(&self) -> Uuid {
+        self.store_id
+    }
+    pub fn product_id(&self) -> Uuid {
+        self.product_id
+    }
+    pub fn promotion_id(&self) -> PromotionId {
+        self.promotion_id
+    }
+    pub fn reason(&self) -> MarkdownReason {
+        self.reason
+    }
+    pub fn reason_detail(&self) -> &str {
+        &self.reason_detail
+    }
+    pub fn discount_percent(&self) -> Decimal {
+        self.discount_percent
+    }
+    pub fn starts_at(&self) -> DateTime<Utc> {
+        self.starts_at
+    }
+    pub fn ends_at(&self) -> DateTime<Utc> {
+        self.ends_at
+    }
+    pub fn status(&self) -> MarkdownStatus {
+        self.status
+    }
+    pub fn reversed_by_id(&self) -> Option<UserId> {
+        self.reversed_by_id
+    }
+    pub fn reversed_at(&self) -> Option<DateTime<Utc>> {
+        self.reversed_at
+    }
+    pub fn reversal_reason(&self) -> Option<&str> {
+        self.reversal_reason.as_deref()
+    }
+    pub fn created_at(&self) -> DateTime<Utc> {
+        self.created_at
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    fn create_test_markdown() -> Markdown {
+        let rule = MarkdownRule::create(
+            Uuid::now_v7(),
+            Uuid::now_v7(),
+            Some(5),
+            None,
+            dec!(30),
+            4,
+            UserId::new(),
+        )
+        .unwrap();
+        Markdown::create(
+            MarkdownId::new(),
+            &rule,
+            Uuid::now_v7(),
+            PromotionId::new(),
+            MarkdownReason::NearExpiry,
+            "Lot expires 2026-10-20".to_string(),
+        )
+    }
+
+    #[test]
+    fn test_create_copies_rule_terms() {
+        let markdown = create_test_markdown();
+        assert_eq!(markdown.status(), MarkdownStatus::Active);
+        assert_eq!(markdown.discount_percent(), dec!(30));
+        assert_eq!(markdown.ends_at() - markdown.starts_at(), Duration::days(4));
+    }
+
+    #[test]
+    fn test_reverse_once() {
+        let mut markdown = create_test_markdown();
+        markdown
+            .reverse(UserId::new(), Some("price error".to_string()))
+            .unwrap();
+        assert_eq!(markdown.status(), MarkdownStatus::Reversed);
+        assert!(markdown.reversed_at().is_some());
+        assert_eq!(markdown.reversal_reason(), Some("price error"));
+        assert!(matches!(
+            markdown.reverse(UserId::new(), None),
+            Err(SalesError::MarkdownNotReversible)
+        ));
+    }
+}
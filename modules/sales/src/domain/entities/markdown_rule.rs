@@ -0,0 +1,240 @@
+// MarkdownRule entity - per-store, per-category opt-in for automatic markdowns
+
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::SalesError;
+use crate::domain::value_objects::MarkdownRuleId;
+use identity::UserId;
+
+/// Automatic markdown configuration for one category at one store.
+///
+/// A store only gets automatic markdowns for categories that have an active
+/// rule. Each rule triggers on:
+/// - proximity to expiry: a received lot expires within `expiry_window_days`
+/// - idle stock: on-hand stock with no sales for `idle_days`
+///
+/// At least one trigger must be set. Matching products get
+/// `discount_percent` off for `duration_days`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MarkdownRule {
+    id: MarkdownRuleId,
+    store_id: Uuid,
+    category_id: Uuid,
+    expiry_window_days: Option<i32>,
+    idle_days: Option<i32>,
+    discount_percent: Decimal,
+    duration_days: i32,
+    is_active: bool,
+    created_by_id: UserId,
+    created_at: DateTime<Utc>,
+    updated_at: DateTime<Utc>,
+}
+
+impl MarkdownRule {
+    /// Creates a new active markdown rule
+    pub fn create(
+        store_id: Uuid,
+        category_id: Uuid,
+        expiry_window_days: Option<i32>,
+        idle_days: Option<i32>,
+        discount_percent: Decimal,
+        duration_days: i32,
+        created_by_id: UserId,
+    ) -> Result<Self, SalesError> {
+        Self::validate(
+            expiry_window_days,
+            idle_days,
+            discount_percent,
+            duration_days,
+        )?;
+        let now = Utc::now();
+        Ok(Self {
+            id: MarkdownRuleId::new(),
+            store_id,
+            category_id,
+            expiry_window_days,
+            idle_days,
+            discount_percent,
+            duration_days,
+            is_active: true,
+            created_by_id,
+            created_at: now,
+            updated_at: now,
+        })
+    }
+
+    /// Reconstitutes a MarkdownRule from persistence
+    #[allow(clippy::too_many_arguments)]
+    pub fn reconstitute(
+        id: MarkdownRuleId,
+        store_id: Uuid,
+        category_id: Uuid,
+        expiry_window_days: Option<i32>,
+        idle_days: Option<i32>,
+        discount_percent: Decimal,
+        duration_days: i32,
+        is_active: bool,
+        created_by_id: UserId,
+        created_at: DateTime<Utc>,
+        updated_at: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            id,
+            store_id,
+            category_id,
+            expiry_window_days,
+            idle_days,
+            discount_percent,
+            duration_days,
+            is_active,
+            created_by_id,
+            created_at,
+            updated_at,
+        }
+    }
+
+    /// Replaces the rule's triggers and discount settings
+    pub fn reconfigure(
+        &mut self,
+        expiry_window_days: Option<i32>,
+        idle_days: Option<i32>,
+        discount_percent: Decimal,
+        duration_days: i32,
+        is_active: bool,
+    ) -> Result<(), SalesError> {
+        Self::validate(
+            expiry_window_days,
+            idle_days,
+            discount_percent,
+            duration_days,
+        )?;
+        self.expiry_window_days = expiry_window_days;
+        self.idle_days = idle_days;
+        self.discount_percent = discount_percent;
+        self.duration_days = duration_days;
+        self.is_active = is_active;
+        self.updated_at = Utc::now();
+        Ok(())
+    }
+
+    fn validate(
+        expiry_window_days: Option<i32>,
+        idle_days: Option<i32>,
+        discount_percent: Decimal,
+        duration_days: i32,
+    ) -> Result<(), SalesError> {
+        if expiry_window_days.is_none() && idle_days.is_none() {
+            return Err(SalesError::InvalidMarkdownRule(
+                "at least one of expiry_window_days or idle_days is required".to_string(),
+            ));
+        }
+        if expiry_window_days.is_some_and(|d| d <= 0) || idle_days.is_some_and(|d| d <= 0) {
+            return Err(SalesError::InvalidMarkdownRule(
+                "trigger thresholds must be positive".to_string(),
+            ));
+        }
+        if discount_percent <= Decimal::ZERO || discount_percent >= Decimal::ONE_HUNDRED {
+            return Err(SalesError::InvalidMarkdownRule(
+                "discount_percent must be between 0 and 100 (exclusive)".to_string(),
+            ));
+        }
+        if duration_days <= 0 {
+            return Err(SalesError::InvalidMarkdownRule(
+                "duration_days must be positive".to_string(),
+            ));
+        }
+        Ok(())
+    }
+
+    // =========================================================================
+    // Getters
+    // =========================================================================
+    pub fn id(&self) -> MarkdownRuleId {
+        self.id
+    }
+    pub fn store_id(&self) -> Uuid {
+        self.store_id
+    }
+    pub fn category_id(&self) -> Uuid {
+        self.category_id
+    }
+    pub fn expiry_window_days(&self) -> Option<i32> {
+        self.expiry_window_days
+    }
+    pub fn idle_days(&self) -> Option<i32> {
+        self.idle_days
+    }
+    pub fn discount_percent(&self) -> Decimal {
+        self.discount_percent
+    }
+    pub fn duration_days(&self) -> i32 {
+        self.duration_days
+    }
+    pub fn is_active(&self) -> bool {
+        self.is_active
+    }
+    pub fn created_by_id(&self) -> UserId {
+        self.created_by_id
+    }
+    pub fn created_at(&self) -> DateTime<Utc> {
+        self.created_at
+    }
+    pub fn updated_at(&self) -> DateTime<Utc> {
+        self.updated_at
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    fn create_rule(expiry: Option<i32>, idle: Option<i32>) -> Result<MarkdownRule, SalesError> {
+        MarkdownRule::create(
+            Uuid::now_v7(),
+            Uuid::now_v7(),
+            expiry,
+            idle,
+            dec!(25),
+            7,
+            UserId::new(),
+        )
+    }
+
+    #[test]
+    fn test_create_rule() {
+        let rule = create_rule(Some(5), Some(60)).unwrap();
+        assert!(rule.is_active());
+        assert_eq!(rule.expiry_window_days(), Some(5));
+        assert_eq!(rule.idle_days(), Some(60));
+        assert_eq!(rule.discount_percent(), dec!(25));
+    }
+
+    #[test]
+    fn test_requires_a_trigger() {
+        assert!(create_rule(None, None).is_err());
+        assert!(create_rule(Some(3), None).is_ok());
+        assert!(create_rule(None, Some(30)).is_ok());
+    }
+
+    #[test]
+    fn test_rejects_non_positive_thresholds() {
+        assert!(create_rule(Some(0), None).is_err());
+        assert!(create_rule(None, Some(-1)).is_err());
+    }
+
+    #[test]
+    fn test_reconfigure_validates_discount() {
+        let mut rule = create_rule(Some(5), None).unwrap();
+        assert!(rule.reconfigure(Some(5), None, dec!(0), 7, true).is_err());
+        assert!(rule.reconfigure(Some(5), None, dec!(100), 7, true).is_err());
+        assert!(rule.reconfigure(Some(5), None, dec!(30), 0, true).is_err());
+        rule.reconfigure(Some(10), None, dec!(40), 3, false)
+            .unwrap();
+        assert_eq!(rule.discount_percent(), dec!(40));
+        assert!(!rule.is_active());
+    }
+}
@@ -9,10 +9,24 @@ mod cashier_shift;
 mod credit_note;
 mod credit_note_item;
 mod customer;
+mod customer_account_entry;
+mod exchange_rate;
+mod gift_card;
+mod layaway;
+mod markdown;
+mod markdown_rule;
+mod order_pickup;
 mod payment;
+mod price_list;
+mod price_list_entry;
+mod price_tier;
 mod promotion;
 mod sale;
+mod sale_idempotency_key;
 mod sale_item;
+mod shift_reopen;
+mod tax_rule;
+mod weight_barcode_format;
 
 pub use cart::Cart;
 pub use cart_item::CartItem;
@@ -20,7 +34,29 @@ pub use cashier_shift::CashierShift;
 pub use credit_note::CreditNote;
 pub use credit_note_item::CreditNoteItem;
 pub use customer::{Address, Customer};
+pub use customer_account_entry::CustomerAccountEntry;
+pub use exchange_rate::ExchangeRate;
+pub(crate) use exchange_rate::{to_base, validate_rate};
+pub use gift_card::{GIFT_CARD_CODE_LENGTH, GiftCard, normalize_gift_card_code};
+pub use layaway::{
+    Layaway, LayawayInstallment, MAX_LAYAWAY_INSTALLMENTS, MAX_LAYAWAY_INTERVAL_DAYS,
+};
+pub use markdown::Markdown;
+pub use markdown_rule::MarkdownRule;
+pub use order_pickup::{OrderPickup, PICKUP_CODE_LENGTH};
 pub use payment::Payment;
+pub use price_list::PriceList;
+pub use price_list_entry::PriceListEntry;
+pub use price_tier::PriceTier;
 pub use promotion::Promotion;
 pub use sale::Sale;
+pub use sale_idempotency_key::{
+    IDEMPOTENCY_KEY_TTL_HOURS, MAX_IDEMPOTENCY_KEY_LENGTH, SaleIdempotencyKey,
+};
 pub use sale_item::SaleItem;
+pub use shift_reopen::ShiftReopen;
+pub use tax_rule::TaxRule;
+pub use weight_barcode_format::{
+    MAX_WEIGHT_BARCODE_DIVISOR, MAX_WEIGHT_BARCODE_PREFIX_LEN, MAX_WEIGHT_BARCODE_VALUE_LEN,
+    ScaleLabel, WeightBarcodeFormat,
+};
@@ -0,0 +1,263 @@
+//! OrderPickup entity - an online order held at the store for collection
+
+use chrono::{DateTime, Duration, Utc};
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+
+use crate::SalesError;
+use crate::domain::value_objects::{PickupStatus, SaleId};
+use identity::{StoreId, UserId};
+
+/// Characters used for pickup codes. Digits and letters that are easy to
+/// confuse when read aloud or handwritten (0/O, 1/I/L) are left out.
+const PICKUP_CODE_ALPHABET: &[u8] = b"23456789ABCDEFGHJKMNPQRSTUVWXYZ";
+
+/// Length of the pickup code handed to the customer
+pub const PICKUP_CODE_LENGTH: usize = 6;
+
+/// An order waiting at the store for the customer to collect it.
+///
+/// Created when the order is marked ready for pickup. The customer presents
+/// the pickup code at the counter; if nobody comes before `hold_until` the
+/// order is cancelled and its stock goes back on sale.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OrderPickup {
+    sale_id: SaleId,
+    store_id: StoreId,
+    pickup_code: String,
+    status: PickupStatus,
+    ready_at: DateTime<Utc>,
+    hold_until: DateTime<Utc>,
+    picked_up_at: Option<DateTime<Utc>>,
+    picked_up_by_id: Option<UserId>,
+    closed_at: Option<DateTime<Utc>>,
+    updated_at: DateTime<Utc>,
+}
+
+impl OrderPickup {
+    /// Creates a waiting pickup held for `hold_days` from now, with a fresh
+    /// pickup code
+    pub fn create(sale_id: SaleId, store_id: StoreId, hold_days: i32) -> Self {
+        let now = Utc::now();
+        Self {
+            sale_id,
+            store_id,
+            pickup_code: generate_pickup_code(),
+            status: PickupStatus::Waiting,
+            ready_at: now,
+            hold_until: now + Duration::days(hold_days as i64),
+            picked_up_at: None,
+            picked_up_by_id: None,
+            closed_at: None,
+            updated_at: now,
+        }
+    }
+
+    /// Reconstitutes an OrderPickup from persistence
+    #[allow(clippy::too_many_arguments)]
+    pub fn reconstitute(
+        sale_id: SaleId,
+        store_id: StoreId,
+        pickup_code: String,
+        status: PickupStatus,
+        ready_at: DateTime<Utc>,
+        hold_until: DateTime<Utc>,
+        picked_up_at: Option<DateTime<Utc>>,
+        picked_up_by_id: Option<UserId>,
+        closed_at: Option<DateTime<Utc>>,
+        updated_at: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            sale_id,
+            store_id,
+            pickup_code,
+            status,
+            ready_at,
+            hold_until,
+            picked_up_at,
+            picked_up_by_id,
+            closed_at,
+            updated_at,
+        }
+    }
+
+    /// Checks the code presented at the counter. Case and surrounding
+    /// whitespace are ignored.
+    pub fn verify_code(&self, code: &str) -> Result<(), SalesError> {
+        if !code.trim().eq_ignore_ascii_case(&self.pickup_code) {
+            return Err(SalesError::InvalidPickupCode);
+        }
+        Ok(())
+    }
+
+    /// Records the hand-over to the customer after checking the code
+    pub fn complete(&mut self, code: &str, staff_id: UserId) -> Result<(), SalesError> {
+        self.ensure_open()?;
+        self.verify_code(code)?;
+        let now = Utc::now();
+        self.status = PickupStatus::PickedUp;
+        self.picked_up_at = Some(now);
+        self.picked_up_by_id = Some(staff_id);
+        self.updated_at = now;
+        Ok(())
+    }
+
+    /// Closes the pickup after the customer failed to show up
+    pub fn expire(&mut self) -> Result<(), SalesError> {
+        self.close(PickupStatus::Expired)
+    }
+
+    /// Closes the pickup because the order was cancelled
+    pub fn cancel(&mut self) -> Result<(), SalesError> {
+        self.close(PickupStatus::Cancelled)
+    }
+
+    /// Returns true if the hold ended at `now` and the order was not collected
+    pub fn is_overdue(&self, now: DateTime<Utc>) -> bool {
+        self.status.is_open() && now > self.hold_until
+    }
+
+    fn close(&mut self, status: PickupStatus) -> Result<(), SalesError> {
+        self.ensure_open()?;
+        let now = Utc::now();
+        self.status = status;
+        self.closed_at = Some(now);
+        self.updated_at = now;
+        Ok(())
+    }
+
+    fn ensure_open(&self) -> Result<(), SalesError> {
+        if !self.status.is_open() {
+            return Err(SalesError::PickupNotOpen);
+        }
+        Ok(())
+    }
+
+    // =========================================================================
+    // Getters
+    // =========================================================================
+
+    pub fn sale_id(&self) -> SaleId {
+        self.sale_id
+    }
+
+    pub fn store_id(&self) -> StoreId {
+        self.store_id
+    }
+
+    pub fn pickup_code(&self) -> &str {
+        &self.pickup_code
+    }
+
+    pub fn status(&self) -> PickupStatus {
+        self.status
+    }
+
+    pub fn ready_at(&self) -> DateTime<Utc> {
+        self.ready_at
+    }
+
+    pub fn hold_until(&self) -> DateTime<Utc> {
+        self.hold_until
+    }
+
+    pub fn picked_up_at(&self) -> Option<DateTime<Utc>> {
+        self.picked_up_at
+    }
+
+    pub fn picked_up_by_id(&self) -> Option<UserId> {
+        self.picked_up_by_id
+    }
+
+    pub fn closed_at(&self) -> Option<DateTime<Utc>> {
+        self.closed_at
+    }
+
+    pub fn updated_at(&self) -> DateTime<Utc> {
+        self.updated_at
+    }
+}
+
+fn generate_pickup_code() -> String {
+    let mut rng = rand::thread_rng();
+    (0..PICKUP_CODE_LENGTH)
+        .map(|_| PICKUP_CODE_ALPHABET[rng.gen_range(0..PICKUP_CODE_ALPHABET.len())] as char)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_pickup() -> OrderPickup {
+        OrderPickup::create(SaleId::new(), StoreId::new(), 7)
+    }
+
+    #[test]
+    fn test_create_generates_readable_code() {
+        let pickup = create_pickup();
+
+        assert_eq!(pickup.status(), PickupStatus::Waiting);
+        assert_eq!(pickup.pickup_code().len(), PICKUP_CODE_LENGTH);
+        assert!(
+            pickup
+                .pickup_code()
+                .bytes()
+                .all(|b| PICKUP_CODE_ALPHABET.contains(&b))
+        );
+        assert_eq!(pickup.hold_until() - pickup.ready_at(), Duration::days(7));
+    }
+
+    #[test]
+    fn test_verify_code_ignores_case_and_whitespace() {
+        let pickup = create_pickup();
+        let code = format!("  {} ", pickup.pickup_code().to_lowercase());
+
+        assert!(pickup.verify_code(&code).is_ok());
+        assert!(matches!(
+            pickup.verify_code("WRONG1"),
+            Err(SalesError::InvalidPickupCode)
+        ));
+    }
+
+    #[test]
+    fn test_complete_records_hand_over() {
+        let mut pickup = create_pickup();
+        let staff_id = UserId::new();
+        let code = pickup.pickup_code().to_string();
+
+        pickup.complete(&code, staff_id).unwrap();
+
+        assert_eq!(pickup.status(), PickupStatus::PickedUp);
+        assert_eq!(pickup.picked_up_by_id(), Some(staff_id));
+        assert!(pickup.picked_up_at().is_some());
+        assert!(matches!(
+            pickup.complete(&code, staff_id),
+            Err(SalesError::PickupNotOpen)
+        ));
+    }
+
+    #[test]
+    fn test_complete_rejects_wrong_code() {
+        let mut pickup = create_pickup();
+
+        let result = pickup.complete("WRONG1", UserId::new());
+
+        assert!(matches!(result, Err(SalesError::InvalidPickupCode)));
+        assert_eq!(pickup.status(), PickupStatus::Waiting);
+    }
+
+    #[test]
+    fn test_overdue_after_hold_ends() {
+        let mut pickup = create_pickup();
+        let after_hold = pickup.hold_until() + Duration::seconds(1);
+
+        assert!(!pickup.is_overdue(pickup.ready_at()));
+        assert!(pickup.is_overdue(after_hold));
+
+        pickup.expire().unwrap();
+        assert_eq!(pickup.status(), PickupStatus::Expired);
+        assert!(pickup.closed_at().is_some());
+        assert!(!pickup.is_overdue(after_hold));
+    }
+}
@@ -5,6 +5,7 @@ use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 
 use crate::SalesError;
+use crate::domain::entities::{to_base, validate_rate};
 use crate::domain::value_objects::{PaymentId, PaymentMethod, PaymentStatus, SaleId};
 use inventory::Currency;
 
@@ -14,6 +15,9 @@ use inventory::Currency;
 /// - Amount must be positive
 /// - Cash payments must have amount_tendered >= amount
 /// - Only completed payments can be refunded
+///
+/// Amounts are in the tender currency; `exchange_rate` converts them to the
+/// sale's base currency (1 for tenders in the base currency).
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Payment {
     id: PaymentId,
@@ -22,6 +26,7 @@ pub struct Payment {
     status: PaymentStatus,
     amount: Decimal,
     currency: Currency,
+    exchange_rate: Decimal,
     amount_tendered: Option<Decimal>,
     change_given: Option<Decimal>,
     reference_number: Option<String>,
@@ -57,6 +62,7 @@ impl Payment {
             status: PaymentStatus::Pending,
             amount,
             currency,
+            exchange_rate: Decimal::ONE,
             amount_tendered: None,
             change_given: None,
             reference_number: None,
@@ -97,6 +103,7 @@ impl Payment {
             status: PaymentStatus::Completed,
             amount,
             currency,
+            exchange_rate: Decimal::ONE,
             amount_tendered: Some(amount_tendered),
             change_given: Some(change),
             reference_number: None,
@@ -122,6 +129,7 @@ impl Payment {
         status: PaymentStatus,
         amount: Decimal,
         currency: Currency,
+        exchange_rate: Decimal,
         amount_tendered: Option<Decimal>,
         change_given: Option<Decimal>,
         reference_number: Option<String>,
@@ -143,6 +151,7 @@ impl Payment {
             status,
             amount,
             currency,
+            exchange_rate,
             amount_tendered,
             change_given,
             reference_number,
@@ -234,6 +243,16 @@ impl Payment {
         self.amount - self.refunded_amount
     }
 
+    /// Returns the amount converted to the sale's base currency
+    pub fn base_amount(&self) -> Decimal {
+        to_base(self.amount, self.exchange_rate)
+    }
+
+    /// Returns the net amount converted to the sale's base currency
+    pub fn net_base_amount(&self) -> Decimal {
+        to_base(self.net_amount(), self.exchange_rate)
+    }
+
     /// Returns true if the payment is successful
     pub fn is_successful(&self) -> bool {
         self.status.is_successful()
@@ -274,6 +293,10 @@ impl Payment {
         &self.currency
     }
 
+    pub fn exchange_rate(&self) -> Decimal {
+        self.exchange_rate
+    }
+
     pub fn amount_tendered(&self) -> Option<Decimal> {
         self.amount_tendered
     }
@@ -326,6 +349,15 @@ impl Payment {
     // Setters
     // =========================================================================
 
+    /// Sets the rate converting the tender currency to the sale's base
+    /// currency
+    pub fn set_exchange_rate(&mut self, rate: Decimal) -> Result<(), SalesError> {
+        validate_rate(rate)?;
+        self.exchange_rate = rate;
+        self.updated_at = Utc::now();
+        Ok(())
+    }
+
     pub fn set_reference_number(&mut self, reference: Option<String>) {
         self.reference_number = reference;
         self.updated_at = Utc::now();
@@ -339,11 +371,6 @@ impl Payment {
     pub fn idempotency_key(&self) -> Option<&str> {
         self.idempotency_key.as_deref()
     }
-
-    pub fn set_idempotency_key(&mut self, key: Option<String>) {
-        self.idempotency_key = key;
-        self.updated_at = Utc::now();
-    }
 }
 
 #[cfg(test)]
@@ -446,4 +473,18 @@ mod tests {
         assert_eq!(payment.refunded_amount(), dec!(30.00));
         assert_eq!(payment.net_amount(), dec!(70.00));
     }
+
+    #[test]
+    fn test_base_amount_uses_exchange_rate() {
+        let mut payment =
+            Payment::create_cash(SaleId::new(), dec!(20.00), Currency::usd(), dec!(20.00)).unwrap();
+        assert_eq!(payment.base_amount(), dec!(20.00));
+
+        payment.set_exchange_rate(dec!(24.6517)).unwrap();
+        assert_eq!(payment.base_amount(), dec!(493.03));
+        assert!(payment.set_exchange_rate(Decimal::ZERO).is_err());
+
+        payment.refund_partial(dec!(5.00)).unwrap();
+        assert_eq!(payment.net_base_amount(), dec!(369.78));
+    }
 }
@@ -0,0 +1,212 @@
+// PriceList entity - a named set of product prices assigned to customer types
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::SalesError;
+use crate::domain::value_objects::{CustomerType, PriceListId};
+use identity::UserId;
+
+/// Set of product and variant prices a store charges the customer types
+/// assigned to it.
+///
+/// Invariants:
+/// - A list is assigned to at least one customer type or is the store's
+///   default (retail) list
+/// - The default list prices walk-in and guest sales, and customers whose
+///   type has no list of its own
+///
+/// Products without an entry on the applicable list fall through to the
+/// price tiers and then to their base price.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PriceList {
+    id: PriceListId,
+    store_id: Uuid,
+    name: String,
+    customer_types: Vec<CustomerType>,
+    is_default: bool,
+    is_active: bool,
+    created_by_id: UserId,
+    created_at: DateTime<Utc>,
+    updated_at: DateTime<Utc>,
+}
+
+impl PriceList {
+    /// Creates a new active price list
+    pub fn create(
+        store_id: Uuid,
+        name: String,
+        customer_types: Vec<CustomerType>,
+        is_default: bool,
+        created_by_id: UserId,
+    ) -> Result<Self, SalesError> {
+        let name = name.trim().to_string();
+        if name.is_empty() {
+            return Err(SalesError::InvalidPriceList("name is required".to_string()));
+        }
+
+        let mut assigned: Vec<CustomerType> = Vec::new();
+        for customer_type in customer_types {
+            if !assigned.contains(&customer_type) {
+                assigned.push(customer_type);
+            }
+        }
+        if assigned.is_empty() && !is_default {
+            return Err(SalesError::InvalidPriceList(
+                "assign at least one customer type or mark the list as default".to_string(),
+            ));
+        }
+
+        let now = Utc::now();
+        Ok(Self {
+            id: PriceListId::new(),
+            store_id,
+            name,
+            customer_types: assigned,
+            is_default,
+            is_active: true,
+            created_by_id,
+            created_at: now,
+            updated_at: now,
+        })
+    }
+
+    /// Reconstitutes a PriceList from persistence
+    #[allow(clippy::too_many_arguments)]
+    pub fn reconstitute(
+        id: PriceListId,
+        store_id: Uuid,
+        name: String,
+        customer_types: Vec<CustomerType>,
+        is_default: bool,
+        is_active: bool,
+        created_by_id: UserId,
+        created_at: DateTime<Utc>,
+        updated_at: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            id,
+            store_id,
+            name,
+            customer_types,
+            is_default,
+            is_active,
+            created_by_id,
+            created_at,
+            updated_at,
+        }
+    }
+
+    /// Returns true if the list is assigned to the customer type
+    pub fn is_assigned_to(&self, customer_type: CustomerType) -> bool {
+        self.customer_types.contains(&customer_type)
+    }
+
+    /// Picks the active list that prices a sale: the list assigned to the
+    /// customer's type, else the default list. Sales without a customer
+    /// (walk-ins and guest checkouts) use the default list.
+    pub fn select(lists: &[PriceList], customer_type: Option<CustomerType>) -> Option<&PriceList> {
+        let active = || lists.iter().filter(|l| l.is_active);
+        customer_type
+            .and_then(|t| active().find(|l| l.is_assigned_to(t)))
+            .or_else(|| active().find(|l| l.is_default))
+    }
+
+    // =========================================================================
+    // Getters
+    // =========================================================================
+    pub fn id(&self) -> PriceListId {
+        self.id
+    }
+    pub fn store_id(&self) -> Uuid {
+        self.store_id
+    }
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+    pub fn customer_types(&self) -> &[CustomerType] {
+        &self.customer_types
+    }
+    pub fn is_default(&self) -> bool {
+        self.is_default
+    }
+    pub fn is_active(&self) -> bool {
+        self.is_active
+    }
+    pub fn created_by_id(&self) -> UserId {
+        self.created_by_id
+    }
+    pub fn created_at(&self) -> DateTime<Utc> {
+        self.created_at
+    }
+    pub fn updated_at(&self) -> DateTime<Utc> {
+        self.updated_at
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn list(customer_types: Vec<CustomerType>, is_default: bool) -> PriceList {
+        PriceList::create(
+            Uuid::now_v7(),
+            "Wholesale".to_string(),
+            customer_types,
+            is_default,
+            UserId::new(),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_requires_name_and_assignment() {
+        let create = |name: &str, types: Vec<CustomerType>, is_default| {
+            PriceList::create(
+                Uuid::now_v7(),
+                name.to_string(),
+                types,
+                is_default,
+                UserId::new(),
+            )
+        };
+        assert!(create("  ", vec![CustomerType::Business], false).is_err());
+        assert!(create("Wholesale", vec![], false).is_err());
+        assert!(create("Retail", vec![], true).is_ok());
+
+        let list = create(
+            "Wholesale",
+            vec![CustomerType::Business, CustomerType::Business],
+            false,
+        )
+        .unwrap();
+        assert_eq!(list.customer_types(), &[CustomerType::Business]);
+    }
+
+    #[test]
+    fn test_select_prefers_assigned_list() {
+        let lists = vec![
+            list(vec![], true),
+            list(vec![CustomerType::Business], false),
+        ];
+
+        let business = PriceList::select(&lists, Some(CustomerType::Business)).unwrap();
+        assert!(business.is_assigned_to(CustomerType::Business));
+
+        let individual = PriceList::select(&lists, Some(CustomerType::Individual)).unwrap();
+        assert!(individual.is_default());
+    }
+
+    #[test]
+    fn test_select_guest_uses_default_list() {
+        let lists = vec![
+            list(vec![CustomerType::Individual], false),
+            list(vec![], true),
+        ];
+        assert!(PriceList::select(&lists, None).unwrap().is_default());
+
+        let without_default = vec![list(vec![CustomerType::Individual], false)];
+        assert!(PriceList::select(&without_default, None).is_none());
+    }
+}
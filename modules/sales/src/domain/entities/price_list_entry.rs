@@ -0,0 +1,162 @@
+// PriceListEntry entity - the price of a product or variant on a price list
+
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::SalesError;
+use crate::domain::value_objects::PriceListId;
+
+/// Unit price of a product on a price list, optionally for one of its
+/// variants.
+///
+/// A variant entry wins over the product entry for that variant; the
+/// product entry prices the product and any variant without its own entry.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PriceListEntry {
+    id: Uuid,
+    price_list_id: PriceListId,
+    product_id: Uuid,
+    variant_id: Option<Uuid>,
+    price: Decimal,
+    created_at: DateTime<Utc>,
+    updated_at: DateTime<Utc>,
+}
+
+impl PriceListEntry {
+    /// Creates a new price list entry
+    pub fn create(
+        price_list_id: PriceListId,
+        product_id: Uuid,
+        variant_id: Option<Uuid>,
+        price: Decimal,
+    ) -> Result<Self, SalesError> {
+        Self::validate_price(price)?;
+        let now = Utc::now();
+        Ok(Self {
+            id: Uuid::now_v7(),
+            price_list_id,
+            product_id,
+            variant_id,
+            price,
+            created_at: now,
+            updated_at: now,
+        })
+    }
+
+    /// Reconstitutes a PriceListEntry from persistence
+    pub fn reconstitute(
+        id: Uuid,
+        price_list_id: PriceListId,
+        product_id: Uuid,
+        variant_id: Option<Uuid>,
+        price: Decimal,
+        created_at: DateTime<Utc>,
+        updated_at: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            id,
+            price_list_id,
+            product_id,
+            variant_id,
+            price,
+            created_at,
+            updated_at,
+        }
+    }
+
+    /// Replaces the entry's price
+    pub fn set_price(&mut self, price: Decimal) -> Result<(), SalesError> {
+        Self::validate_price(price)?;
+        self.price = price;
+        self.updated_at = Utc::now();
+        Ok(())
+    }
+
+    fn validate_price(price: Decimal) -> Result<(), SalesError> {
+        if price < Decimal::ZERO {
+            return Err(SalesError::InvalidPriceList(
+                "price cannot be negative".to_string(),
+            ));
+        }
+        Ok(())
+    }
+
+    /// Picks the entry that prices a product line, preferring the entry
+    /// for its variant over the product entry
+    pub fn select(
+        entries: &[PriceListEntry],
+        product_id: Uuid,
+        variant_id: Option<Uuid>,
+    ) -> Option<&PriceListEntry> {
+        let for_product = || entries.iter().filter(move |e| e.product_id == product_id);
+        variant_id
+            .and_then(|v| for_product().find(|e| e.variant_id == Some(v)))
+            .or_else(|| for_product().find(|e| e.variant_id.is_none()))
+    }
+
+    // =========================================================================
+    // Getters
+    // =========================================================================
+    pub fn id(&self) -> Uuid {
+        self.id
+    }
+    pub fn price_list_id(&self) -> PriceListId {
+        self.price_list_id
+    }
+    pub fn product_id(&self) -> Uuid {
+        self.product_id
+    }
+    pub fn variant_id(&self) -> Option<Uuid> {
+        self.variant_id
+    }
+    pub fn price(&self) -> Decimal {
+        self.price
+    }
+    pub fn created_at(&self) -> DateTime<Utc> {
+        self.created_at
+    }
+    pub fn updated_at(&self) -> DateTime<Utc> {
+        self.updated_at
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    #[test]
+    fn test_rejects_negative_price() {
+        let list_id = PriceListId::new();
+        assert!(PriceListEntry::create(list_id, Uuid::now_v7(), None, dec!(-1)).is_err());
+
+        let mut entry = PriceListEntry::create(list_id, Uuid::now_v7(), None, dec!(5)).unwrap();
+        assert!(entry.set_price(dec!(-0.01)).is_err());
+        entry.set_price(dec!(4.50)).unwrap();
+        assert_eq!(entry.price(), dec!(4.50));
+    }
+
+    #[test]
+    fn test_select_prefers_variant_entry() {
+        let list_id = PriceListId::new();
+        let product_id = Uuid::now_v7();
+        let variant_id = Uuid::now_v7();
+        let entries = vec![
+            PriceListEntry::create(list_id, product_id, None, dec!(10)).unwrap(),
+            PriceListEntry::create(list_id, product_id, Some(variant_id), dec!(12)).unwrap(),
+        ];
+
+        let variant = PriceListEntry::select(&entries, product_id, Some(variant_id)).unwrap();
+        assert_eq!(variant.price(), dec!(12));
+
+        // Other variants and the bare product use the product entry
+        let other = PriceListEntry::select(&entries, product_id, Some(Uuid::now_v7())).unwrap();
+        assert_eq!(other.price(), dec!(10));
+        let product = PriceListEntry::select(&entries, product_id, None).unwrap();
+        assert_eq!(product.price(), dec!(10));
+
+        assert!(PriceListEntry::select(&entries, Uuid::now_v7(), None).is_none());
+    }
+}
@@ -0,0 +1,318 @@
+// PriceTier entity - customer-type pricing for a product or a whole category
+
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::SalesError;
+use crate::domain::value_objects::{CustomerType, PriceTierId};
+use identity::UserId;
+
+/// Price a customer type pays at one store, for one product or for every
+/// product of one category.
+///
+/// A tier targets exactly one of:
+/// - a product: a fixed `price` or a `discount_percent` off the base price
+/// - a category: a `discount_percent` off each product's base price
+///
+/// When both apply to a line, the product tier wins over the category tier.
+/// Products with no matching tier sell at their base price.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PriceTier {
+    id: PriceTierId,
+    store_id: Uuid,
+    customer_type: CustomerType,
+    product_id: Option<Uuid>,
+    category_id: Option<Uuid>,
+    price: Option<Decimal>,
+    discount_percent: Option<Decimal>,
+    is_active: bool,
+    created_by_id: UserId,
+    created_at: DateTime<Utc>,
+    updated_at: DateTime<Utc>,
+}
+
+impl PriceTier {
+    /// Creates a new active price tier
+    #[allow(clippy::too_many_arguments)]
+    pub fn create(
+        store_id: Uuid,
+        customer_type: CustomerType,
+        product_id: Option<Uuid>,
+        category_id: Option<Uuid>,
+        price: Option<Decimal>,
+        discount_percent: Option<Decimal>,
+        created_by_id: UserId,
+    ) -> Result<Self, SalesError> {
+        Self::validate(product_id, category_id, price, discount_percent)?;
+        let now = Utc::now();
+        Ok(Self {
+            id: PriceTierId::new(),
+            store_id,
+            customer_type,
+            product_id,
+            category_id,
+            price,
+            discount_percent,
+            is_active: true,
+            created_by_id,
+            created_at: now,
+            updated_at: now,
+        })
+    }
+
+    /// Reconstitutes a PriceTier from persistence
+    #[allow(clippy::too_many_arguments)]
+    pub fn reconstitute(
+        id: PriceTierId,
+        store_id: Uuid,
+        customer_type: CustomerType,
+        product_id: Option<Uuid>,
+        category_id: Option<Uuid>,
+        price: Option<Decimal>,
+        discount_percent: Option<Decimal>,
+        is_active: bool,
+        created_by_id: UserId,
+        created_at: DateTime<Utc>,
+        updated_at: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            id,
+            store_id,
+            customer_type,
+            product_id,
+            category_id,
+            price,
+            discount_percent,
+            is_active,
+            created_by_id,
+            created_at,
+            updated_at,
+        }
+    }
+
+    /// Replaces the tier's price settings
+    pub fn reconfigure(
+        &mut self,
+        price: Option<Decimal>,
+        discount_percent: Option<Decimal>,
+        is_active: bool,
+    ) -> Result<(), SalesError> {
+        Self::validate(self.product_id, self.category_id, price, discount_percent)?;
+        self.price = price;
+        self.discount_percent = discount_percent;
+        self.is_active = is_active;
+        self.updated_at = Utc::now();
+        Ok(())
+    }
+
+    fn validate(
+        product_id: Option<Uuid>,
+        category_id: Option<Uuid>,
+        price: Option<Decimal>,
+        discount_percent: Option<Decimal>,
+    ) -> Result<(), SalesError> {
+        if product_id.is_some() == category_id.is_some() {
+            return Err(SalesError::InvalidPriceTier(
+                "exactly one of product_id or category_id is required".to_string(),
+            ));
+        }
+        if price.is_some() == discount_percent.is_some() {
+            return Err(SalesError::InvalidPriceTier(
+                "exactly one of price or discount_percent is required".to_string(),
+            ));
+        }
+        if category_id.is_some() && price.is_some() {
+            return Err(SalesError::InvalidPriceTier(
+                "category tiers must use discount_percent".to_string(),
+            ));
+        }
+        if price.is_some_and(|p| p < Decimal::ZERO) {
+            return Err(SalesError::InvalidPriceTier(
+                "price cannot be negative".to_string(),
+            ));
+        }
+        if discount_percent.is_some_and(|d| d <= Decimal::ZERO || d >= Decimal::ONE_HUNDRED) {
+            return Err(SalesError::InvalidPriceTier(
+                "discount_percent must be between 0 and 100 (exclusive)".to_string(),
+            ));
+        }
+        Ok(())
+    }
+
+    /// Returns the tier price for a product sold at `base_price`
+    pub fn price_for(&self, base_price: Decimal) -> Decimal {
+        match (self.price, self.discount_percent) {
+            (Some(price), _) => price,
+            (None, Some(percent)) => {
+                (base_price * (Decimal::ONE_HUNDRED - percent) / Decimal::ONE_HUNDRED).round_dp(2)
+            }
+            (None, None) => base_price,
+        }
+    }
+
+    /// Picks the active tier that prices a product for a customer type,
+    /// preferring a product tier over a category tier
+    pub fn select(
+        tiers: &[PriceTier],
+        customer_type: CustomerType,
+        product_id: Uuid,
+        category_id: Option<Uuid>,
+    ) -> Option<&PriceTier> {
+        let applicable = || {
+            tiers
+                .iter()
+                .filter(move |t| t.is_active && t.customer_type == customer_type)
+        };
+        applicable()
+            .find(|t| t.product_id == Some(product_id))
+            .or_else(|| category_id.and_then(|c| applicable().find(|t| t.category_id == Some(c))))
+    }
+
+    // =========================================================================
+    // Getters
+    // =========================================================================
+    pub fn id(&self) -> PriceTierId {
+        self.id
+    }
+    pub fn store_id(&self) -> Uuid {
+        self.store_id
+    }
+    pub fn customer_type(&self) -> CustomerType {
+        self.customer_type
+    }
+    pub fn product_id(&self) -> Option<Uuid> {
+        self.product_id
+    }
+    pub fn category_id(&self) -> Option<Uuid> {
+        self.category_id
+    }
+    pub fn price(&self) -> Option<Decimal> {
+        self.price
+    }
+    pub fn discount_percent(&self) -> Option<Decimal> {
+        self.discount_percent
+    }
+    pub fn is_active(&self) -> bool {
+        self.is_active
+    }
+    pub fn created_by_id(&self) -> UserId {
+        self.created_by_id
+    }
+    pub fn created_at(&self) -> DateTime<Utc> {
+        self.created_at
+    }
+    pub fn updated_at(&self) -> DateTime<Utc> {
+        self.updated_at
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    fn product_tier(product_id: Uuid, price: Decimal) -> PriceTier {
+        PriceTier::create(
+            Uuid::now_v7(),
+            CustomerType::Business,
+            Some(product_id),
+            None,
+            Some(price),
+            None,
+            UserId::new(),
+        )
+        .unwrap()
+    }
+
+    fn category_tier(category_id: Uuid, percent: Decimal) -> PriceTier {
+        PriceTier::create(
+            Uuid::now_v7(),
+            CustomerType::Business,
+            None,
+            Some(category_id),
+            None,
+            Some(percent),
+            UserId::new(),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_requires_one_target_and_one_price() {
+        let store = Uuid::now_v7();
+        let id = Some(Uuid::now_v7());
+        let create = |product, category, price, percent| {
+            PriceTier::create(
+                store,
+                CustomerType::Business,
+                product,
+                category,
+                price,
+                percent,
+                UserId::new(),
+            )
+        };
+        assert!(create(None, None, Some(dec!(5)), None).is_err());
+        assert!(create(id, id, Some(dec!(5)), None).is_err());
+        assert!(create(id, None, None, None).is_err());
+        assert!(create(id, None, Some(dec!(5)), Some(dec!(10))).is_err());
+        assert!(create(None, id, Some(dec!(5)), None).is_err());
+        assert!(create(id, None, Some(dec!(-1)), None).is_err());
+        assert!(create(None, id, None, Some(dec!(100))).is_err());
+        assert!(create(id, None, Some(dec!(5)), None).is_ok());
+        assert!(create(None, id, None, Some(dec!(15))).is_ok());
+    }
+
+    #[test]
+    fn test_price_for_fixed_and_percent() {
+        let fixed = product_tier(Uuid::now_v7(), dec!(8.50));
+        assert_eq!(fixed.price_for(dec!(10)), dec!(8.50));
+
+        let percent = category_tier(Uuid::now_v7(), dec!(15));
+        assert_eq!(percent.price_for(dec!(9.99)), dec!(8.49));
+    }
+
+    #[test]
+    fn test_select_prefers_product_tier() {
+        let product_id = Uuid::now_v7();
+        let category_id = Uuid::now_v7();
+        let tiers = vec![
+            category_tier(category_id, dec!(10)),
+            product_tier(product_id, dec!(7)),
+        ];
+
+        let tier = PriceTier::select(
+            &tiers,
+            CustomerType::Business,
+            product_id,
+            Some(category_id),
+        )
+        .unwrap();
+        assert_eq!(tier.product_id(), Some(product_id));
+
+        let other = PriceTier::select(
+            &tiers,
+            CustomerType::Business,
+            Uuid::now_v7(),
+            Some(category_id),
+        )
+        .unwrap();
+        assert_eq!(other.category_id(), Some(category_id));
+    }
+
+    #[test]
+    fn test_select_falls_back_to_base_price() {
+        let product_id = Uuid::now_v7();
+        let mut tiers = vec![product_tier(product_id, dec!(7))];
+
+        // Other customer types and unrelated products have no tier
+        assert!(PriceTier::select(&tiers, CustomerType::Individual, product_id, None).is_none());
+        assert!(PriceTier::select(&tiers, CustomerType::Business, Uuid::now_v7(), None).is_none());
+
+        // Inactive tiers are ignored
+        tiers[0].reconfigure(Some(dec!(7)), None, false).unwrap();
+        assert!(PriceTier::select(&tiers, CustomerType::Business, product_id, None).is_none());
+    }
+}
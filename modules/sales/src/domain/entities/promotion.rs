@@ -6,7 +6,9 @@ use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
 use crate::SalesError;
-use crate::domain::value_objects::{PromotionId, PromotionStatus, PromotionType};
+use crate::domain::value_objects::{
+    PromotionDiscount, PromotionId, PromotionLine, PromotionStatus, PromotionType, SaleItemId,
+};
 use identity::UserId;
 
 /// Promotion entity representing a discount code or campaign.
@@ -20,6 +22,11 @@ use identity::UserId;
 /// - Date-based validity
 /// - Store-specific or global promotions
 /// - Product/category targeting
+///
+/// As a rule, a promotion discounts either the sale total (`applies_to =
+/// "order"`: percentage or fixed amount off, e.g. a spend-threshold deal
+/// via `minimum_purchase`) or individual lines (`applies_to = "product"`
+/// or `"category"`, and every buy-X-get-Y promotion).
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Promotion {
     id: PromotionId,
@@ -183,6 +190,133 @@ impl Promotion {
         }
     }
 
+    /// Returns true if the promotion can be used at a store at `now`:
+    /// active, within its validity dates, for that store (or every store)
+    /// and under its usage limit
+    pub fn is_available_at(&self, store_id: Uuid, now: DateTime<Utc>) -> bool {
+        self.status.is_active()
+            && self.start_date <= now
+            && self.end_date.is_none_or(|end| now <= end)
+            && self.store_id.is_none_or(|s| s == store_id)
+            && self
+                .usage_limit
+                .is_none_or(|limit| self.usage_count < limit)
+    }
+
+    /// Returns true if the promotion discounts individual lines rather than
+    /// the sale total
+    pub fn is_line_level(&self) -> bool {
+        self.promotion_type == PromotionType::BuyXGetY || self.applies_to != "order"
+    }
+
+    /// Returns true if the promotion's line rule covers the line. Lines
+    /// discounted by hand are never targeted.
+    fn targets(&self, line: &PromotionLine) -> bool {
+        if line.manual_discount > Decimal::ZERO {
+            return false;
+        }
+        match self.applies_to.as_str() {
+            "product" => self.product_ids.contains(&line.product_id),
+            "category" => line
+                .category_id
+                .is_some_and(|c| self.category_ids.contains(&c)),
+            _ => true,
+        }
+    }
+
+    /// Evaluates the promotion against a sale's lines, returning the line
+    /// discounts or the order discount it grants. Nothing applies while the
+    /// lines' subtotal is below the minimum purchase.
+    pub fn evaluate(&self, lines: &[PromotionLine]) -> Vec<PromotionDiscount> {
+        let subtotal: Decimal = lines.iter().map(|l| l.subtotal).sum();
+        if lines.is_empty() || subtotal < self.minimum_purchase {
+            return Vec::new();
+        }
+        if self.is_line_level() {
+            self.line_discounts(lines)
+        } else {
+            let base = subtotal - lines.iter().map(|l| l.manual_discount).sum::<Decimal>();
+            self.order_discount(base).into_iter().collect()
+        }
+    }
+
+    /// Picks the best combination of discounts from a set of promotions:
+    /// each line takes its largest line discount, then the sale takes the
+    /// largest order discount on what the lines come to after them.
+    pub fn best_combination(
+        promotions: &[Promotion],
+        lines: &[PromotionLine],
+    ) -> Vec<PromotionDiscount> {
+        let subtotal: Decimal = lines.iter().map(|l| l.subtotal).sum();
+        let line_discounts: Vec<PromotionDiscount> = promotions
+            .iter()
+            .filter(|p| p.is_line_level())
+            .flat_map(|p| p.evaluate(lines))
+            .collect();
+        let mut best = PromotionDiscount::best_per_line(&line_discounts);
+
+        let base = subtotal
+            - lines.iter().map(|l| l.manual_discount).sum::<Decimal>()
+            - best.iter().map(|d| d.amount).sum::<Decimal>();
+        let order_discount = promotions
+            .iter()
+            .filter(|p| !p.is_line_level() && subtotal >= p.minimum_purchase)
+            .filter_map(|p| p.order_discount(base))
+            .max_by(|a, b| a.amount.cmp(&b.amount));
+
+        best.extend(order_discount);
+        best
+    }
+
+    fn line_discounts(&self, lines: &[PromotionLine]) -> Vec<PromotionDiscount> {
+        let mut remaining = self.maximum_discount;
+        lines
+            .iter()
+            .filter(|line| self.targets(line))
+            .filter_map(|line| {
+                let raw = match self.promotion_type {
+                    PromotionType::Percentage => {
+                        line.subtotal * self.discount_value / Decimal::from(100)
+                    }
+                    PromotionType::FixedAmount => self.discount_value,
+                    PromotionType::BuyXGetY => self.free_units(line.quantity) * line.unit_price,
+                };
+                let mut amount = raw.min(line.subtotal).round_dp(2);
+                if let Some(cap) = remaining.as_mut() {
+                    amount = amount.min(*cap);
+                    *cap -= amount;
+                }
+                (amount > Decimal::ZERO).then(|| self.discount(Some(line.sale_item_id), amount))
+            })
+            .collect()
+    }
+
+    fn order_discount(&self, base: Decimal) -> Option<PromotionDiscount> {
+        let amount = self.calculate_discount(base).min(base).round_dp(2);
+        (amount > Decimal::ZERO).then(|| self.discount(None, amount))
+    }
+
+    /// Units given free on a line of `quantity` units: `get` for every full
+    /// group of `buy + get`
+    fn free_units(&self, quantity: Decimal) -> Decimal {
+        match (self.buy_quantity, self.get_quantity) {
+            (Some(buy), Some(get)) if buy > 0 && get > 0 => {
+                (quantity / Decimal::from(buy + get)).floor() * Decimal::from(get)
+            }
+            _ => Decimal::ZERO,
+        }
+    }
+
+    fn discount(&self, sale_item_id: Option<SaleItemId>, amount: Decimal) -> PromotionDiscount {
+        PromotionDiscount {
+            promotion_id: self.id,
+            promotion_code: self.code.clone(),
+            promotion_name: self.name.clone(),
+            sale_item_id,
+            amount,
+        }
+    }
+
     /// Increments the usage counter
     pub fn increment_usage(&mut self) {
         self.usage_count += 1;
@@ -445,4 +579,132 @@ mod tests {
         promo.activate();
         assert!(promo.status().is_active());
     }
+
+    fn line(
+        product_id: Uuid,
+        category_id: Option<Uuid>,
+        qty: Decimal,
+        price: Decimal,
+    ) -> PromotionLine {
+        PromotionLine {
+            sale_item_id: SaleItemId::new(),
+            product_id,
+            category_id,
+            quantity: qty,
+            unit_price: price,
+            subtotal: qty * price,
+            manual_discount: Decimal::ZERO,
+        }
+    }
+
+    #[test]
+    fn test_is_available_at_store_and_dates() {
+        let mut promo = create_test_promotion();
+        let store = Uuid::now_v7();
+        let now = Utc::now();
+        assert!(promo.is_available_at(store, now));
+
+        promo.set_store_id(Some(Uuid::now_v7()));
+        assert!(!promo.is_available_at(store, now));
+
+        promo.set_store_id(Some(store));
+        promo.set_end_date(Some(now - chrono::Duration::minutes(1)));
+        assert!(!promo.is_available_at(store, now));
+
+        promo.set_end_date(None);
+        promo.set_usage_limit(Some(1));
+        promo.increment_usage();
+        assert!(!promo.is_available_at(store, now));
+    }
+
+    #[test]
+    fn test_evaluate_buy_x_get_y() {
+        let mut promo = create_test_promotion();
+        promo.promotion_type = PromotionType::BuyXGetY;
+        promo.set_buy_quantity(Some(2));
+        promo.set_get_quantity(Some(1));
+
+        // 7 units: two full groups of 3, so 2 free units
+        let lines = vec![line(Uuid::now_v7(), None, dec!(7), dec!(5))];
+        let discounts = promo.evaluate(&lines);
+
+        assert_eq!(discounts.len(), 1);
+        assert_eq!(discounts[0].sale_item_id, Some(lines[0].sale_item_id));
+        assert_eq!(discounts[0].amount, dec!(10));
+    }
+
+    #[test]
+    fn test_evaluate_percentage_off_category() {
+        let category = Uuid::now_v7();
+        let mut promo = create_test_promotion();
+        promo.set_applies_to("category".to_string());
+        promo.set_category_ids(vec![category]);
+
+        let lines = vec![
+            line(Uuid::now_v7(), Some(category), dec!(2), dec!(25)),
+            line(Uuid::now_v7(), None, dec!(1), dec!(100)),
+        ];
+        let discounts = promo.evaluate(&lines);
+
+        assert_eq!(discounts.len(), 1);
+        assert_eq!(discounts[0].sale_item_id, Some(lines[0].sale_item_id));
+        assert_eq!(discounts[0].amount, dec!(5));
+    }
+
+    #[test]
+    fn test_evaluate_spend_threshold() {
+        let mut promo = create_test_promotion();
+        promo.promotion_type = PromotionType::FixedAmount;
+        promo.set_discount_value(dec!(15));
+        promo.set_minimum_purchase(dec!(100));
+
+        let below = vec![line(Uuid::now_v7(), None, dec!(1), dec!(99))];
+        assert!(promo.evaluate(&below).is_empty());
+
+        let above = vec![line(Uuid::now_v7(), None, dec!(2), dec!(60))];
+        let discounts = promo.evaluate(&above);
+        assert_eq!(discounts.len(), 1);
+        assert!(discounts[0].is_order_level());
+        assert_eq!(discounts[0].amount, dec!(15));
+    }
+
+    #[test]
+    fn test_manually_discounted_lines_are_skipped() {
+        let mut promo = create_test_promotion();
+        promo.set_applies_to("product".to_string());
+        let product_id = Uuid::now_v7();
+        promo.set_product_ids(vec![product_id]);
+
+        let mut discounted = line(product_id, None, dec!(1), dec!(50));
+        discounted.manual_discount = dec!(5);
+        assert!(promo.evaluate(&[discounted]).is_empty());
+    }
+
+    #[test]
+    fn test_best_combination() {
+        let category = Uuid::now_v7();
+        let lines = vec![
+            line(Uuid::now_v7(), Some(category), dec!(3), dec!(10)),
+            line(Uuid::now_v7(), None, dec!(1), dec!(70)),
+        ];
+
+        // 10% and 20% off the category: the line takes the larger one
+        let mut ten = create_test_promotion();
+        ten.set_applies_to("category".to_string());
+        ten.set_category_ids(vec![category]);
+        let mut twenty = ten.clone();
+        twenty.id = PromotionId::new();
+        twenty.set_discount_value(dec!(20));
+
+        // Order-level 10% applies to what remains after line discounts
+        let order = create_test_promotion();
+
+        let best = Promotion::best_combination(&[ten, twenty.clone(), order.clone()], &lines);
+
+        assert_eq!(best.len(), 2);
+        assert_eq!(best[0].promotion_id, twenty.id());
+        assert_eq!(best[0].amount, dec!(6));
+        assert_eq!(best[1].promotion_id, order.id());
+        assert_eq!(best[1].amount, dec!(9.40));
+    }
 }
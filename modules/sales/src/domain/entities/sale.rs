@@ -5,10 +5,10 @@ use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 
 use crate::SalesError;
-use crate::domain::entities::{Payment, SaleItem};
+use crate::domain::entities::{Payment, SaleItem, TaxRule, validate_rate};
 use crate::domain::value_objects::{
-    CustomerId, DiscountType, OrderStatus, PaymentMethod, SaleId, SaleItemId, SaleStatus, SaleType,
-    ShiftId,
+    CustomerId, DiscountType, OrderStatus, PaymentMethod, PromotionId, SaleId, SaleItemId,
+    SaleLineAllocation, SaleStatus, SaleTaxBreakdown, SaleType, ShiftId, allocate_order_discount,
 };
 use identity::{StoreId, UserId};
 use inventory::Currency;
@@ -21,6 +21,11 @@ use pos_core::TerminalId;
 /// - Sale must have items before completing
 /// - Sale must be fully paid before completing
 /// - Only draft sales can be modified
+///
+/// Totals, payments and reports are in the sale's base `currency`. A sale
+/// quoted in another `transaction_currency` carries the `exchange_rate` to
+/// the base currency, captured again when the sale is completed so the
+/// receipt keeps the rate it was rung up at.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Sale {
     id: SaleId,
@@ -34,6 +39,8 @@ pub struct Sale {
     cashier_id: Option<UserId>,
     customer_id: Option<CustomerId>,
     currency: Currency,
+    transaction_currency: Currency,
+    exchange_rate: Decimal,
     subtotal: Decimal,
     discount_type: Option<DiscountType>,
     discount_value: Decimal,
@@ -45,12 +52,14 @@ pub struct Sale {
     change_given: Decimal,
     invoice_number: Option<String>,
     invoice_date: Option<DateTime<Utc>>,
+    cai_number: Option<String>,
     notes: Option<String>,
     internal_notes: Option<String>,
     voided_by_id: Option<UserId>,
     voided_at: Option<DateTime<Utc>>,
     void_reason: Option<String>,
     completed_at: Option<DateTime<Utc>>,
+    promotion_id: Option<PromotionId>,
     items: Vec<SaleItem>,
     payments: Vec<Payment>,
     created_at: DateTime<Utc>,
@@ -79,7 +88,9 @@ impl Sale {
             shift_id: Some(shift_id),
             cashier_id: Some(cashier_id),
             customer_id: None,
+            transaction_currency: currency.clone(),
             currency,
+            exchange_rate: Decimal::ONE,
             subtotal: Decimal::ZERO,
             discount_type: None,
             discount_value: Decimal::ZERO,
@@ -91,12 +102,14 @@ impl Sale {
             change_given: Decimal::ZERO,
             invoice_number: None,
             invoice_date: None,
+            cai_number: None,
             notes: None,
             internal_notes: None,
             voided_by_id: None,
             voided_at: None,
             void_reason: None,
             completed_at: None,
+            promotion_id: None,
             items: Vec::new(),
             payments: Vec::new(),
             created_at: now,
@@ -118,7 +131,9 @@ impl Sale {
             shift_id: None,
             cashier_id: None,
             customer_id: None,
+            transaction_currency: currency.clone(),
             currency,
+            exchange_rate: Decimal::ONE,
             subtotal: Decimal::ZERO,
             discount_type: None,
             discount_value: Decimal::ZERO,
@@ -130,12 +145,14 @@ impl Sale {
             change_given: Decimal::ZERO,
             invoice_number: None,
             invoice_date: None,
+            cai_number: None,
             notes: None,
             internal_notes: None,
             voided_by_id: None,
             voided_at: None,
             void_reason: None,
             completed_at: None,
+            promotion_id: None,
             items: Vec::new(),
             payments: Vec::new(),
             created_at: now,
@@ -157,6 +174,8 @@ impl Sale {
         cashier_id: Option<UserId>,
         customer_id: Option<CustomerId>,
         currency: Currency,
+        transaction_currency: Currency,
+        exchange_rate: Decimal,
         subtotal: Decimal,
         discount_type: Option<DiscountType>,
         discount_value: Decimal,
@@ -168,12 +187,14 @@ impl Sale {
         change_given: Decimal,
         invoice_number: Option<String>,
         invoice_date: Option<DateTime<Utc>>,
+        cai_number: Option<String>,
         notes: Option<String>,
         internal_notes: Option<String>,
         voided_by_id: Option<UserId>,
         voided_at: Option<DateTime<Utc>>,
         void_reason: Option<String>,
         completed_at: Option<DateTime<Utc>>,
+        promotion_id: Option<PromotionId>,
         items: Vec<SaleItem>,
         payments: Vec<Payment>,
         created_at: DateTime<Utc>,
@@ -191,6 +212,8 @@ impl Sale {
             cashier_id,
             customer_id,
             currency,
+            transaction_currency,
+            exchange_rate,
             subtotal,
             discount_type,
             discount_value,
@@ -202,12 +225,14 @@ impl Sale {
             change_given,
             invoice_number,
             invoice_date,
+            cai_number,
             notes,
             internal_notes,
             voided_by_id,
             voided_at,
             void_reason,
             completed_at,
+            promotion_id,
             items,
             payments,
             created_at,
@@ -240,6 +265,23 @@ impl Sale {
         Ok(())
     }
 
+    /// Stamps a completed sale with the invoice number drawn from a CAI
+    /// range, replacing the number it was completed with
+    pub fn assign_cai_invoice_number(
+        &mut self,
+        cai_number: String,
+        invoice_number: String,
+    ) -> Result<(), SalesError> {
+        if self.status != SaleStatus::Completed || self.cai_number.is_some() {
+            return Err(SalesError::InvalidStatusTransition);
+        }
+
+        self.cai_number = Some(cai_number);
+        self.invoice_number = Some(invoice_number);
+        self.updated_at = Utc::now();
+        Ok(())
+    }
+
     /// Voids the sale
     pub fn void(&mut self, voided_by_id: UserId, reason: String) -> Result<(), SalesError> {
         if !self.status.can_void() {
@@ -255,6 +297,51 @@ impl Sale {
         Ok(())
     }
 
+    /// Parks the sale so the cashier can ring up another one; its items and
+    /// payments are kept
+    pub fn park(&mut self) -> Result<(), SalesError> {
+        if !self.status.can_park() {
+            return Err(SalesError::SaleNotEditable);
+        }
+        if self.items.is_empty() {
+            return Err(SalesError::EmptySale);
+        }
+
+        self.status = SaleStatus::Parked;
+        self.updated_at = Utc::now();
+        Ok(())
+    }
+
+    /// Resumes a parked sale, back to draft or partially paid
+    pub fn resume(&mut self) -> Result<(), SalesError> {
+        if self.status != SaleStatus::Parked {
+            return Err(SalesError::SaleNotParked);
+        }
+
+        self.status = SaleStatus::Draft;
+        self.recalculate_payment_totals();
+        self.updated_at = Utc::now();
+        Ok(())
+    }
+
+    /// Puts the sale on layaway: its items are held for its customer, who
+    /// pays it off in installments. The items can no longer be changed.
+    pub fn start_layaway(&mut self) -> Result<(), SalesError> {
+        if !self.status.can_transition_to(SaleStatus::Layaway) {
+            return Err(SalesError::SaleNotEditable);
+        }
+        if self.items.is_empty() {
+            return Err(SalesError::EmptySale);
+        }
+        if self.customer_id.is_none() {
+            return Err(SalesError::LayawayRequiresCustomer);
+        }
+
+        self.status = SaleStatus::Layaway;
+        self.updated_at = Utc::now();
+        Ok(())
+    }
+
     /// Marks the sale as returned (after credit note)
     pub fn mark_returned(&mut self) -> Result<(), SalesError> {
         if !self.status.can_return() {
@@ -328,6 +415,36 @@ impl Sale {
         }
     }
 
+    /// Puts the order on hold at the store for the customer to collect
+    pub fn mark_ready_for_pickup(&mut self) -> Result<(), SalesError> {
+        if let Some(order_status) = self.order_status {
+            if !order_status.can_mark_ready_for_pickup() {
+                return Err(SalesError::OrderNotPaid);
+            }
+            self.order_status = Some(OrderStatus::ReadyForPickup);
+            self.updated_at = Utc::now();
+            Ok(())
+        } else {
+            Err(SalesError::InvalidStatusTransition)
+        }
+    }
+
+    /// Marks the order as collected by the customer at the store
+    pub fn complete_pickup(&mut self) -> Result<(), SalesError> {
+        if let Some(order_status) = self.order_status {
+            if !order_status.can_complete_pickup() {
+                return Err(SalesError::OrderNotReadyForPickup);
+            }
+            self.order_status = Some(OrderStatus::PickedUp);
+            self.status = SaleStatus::Completed;
+            self.completed_at = Some(Utc::now());
+            self.updated_at = Utc::now();
+            Ok(())
+        } else {
+            Err(SalesError::InvalidStatusTransition)
+        }
+    }
+
     /// Cancels the order
     pub fn cancel_order(&mut self) -> Result<(), SalesError> {
         if let Some(order_status) = self.order_status {
@@ -371,11 +488,11 @@ impl Sale {
 
     /// Adds a payment to the sale
     pub fn add_payment(&mut self, payment: Payment) -> Result<(), SalesError> {
-        if !self.is_editable() {
+        if !self.accepts_payments() {
             return Err(SalesError::SaleNotEditable);
         }
 
-        let payment_amount = payment.amount();
+        let payment_amount = payment.base_amount();
         if payment_amount > self.amount_due {
             // For cash, this is okay (we give change)
             if payment.payment_method().is_cash() {
@@ -389,6 +506,43 @@ impl Sale {
         Ok(())
     }
 
+    /// Adds the tenders of a split payment to the sale.
+    ///
+    /// Non-cash tenders can't exceed the amount due; cash may overpay it, and
+    /// the excess is recorded as change due to the customer.
+    pub fn add_payments(&mut self, payments: Vec<Payment>) -> Result<(), SalesError> {
+        if !self.accepts_payments() {
+            return Err(SalesError::SaleNotEditable);
+        }
+        if payments.is_empty() {
+            return Err(SalesError::NoPaymentAllocations);
+        }
+
+        let (cash, non_cash) =
+            payments
+                .iter()
+                .fold((Decimal::ZERO, Decimal::ZERO), |(cash, non_cash), p| {
+                    if p.payment_method().is_cash() {
+                        (cash + p.base_amount(), non_cash)
+                    } else {
+                        (cash, non_cash + p.base_amount())
+                    }
+                });
+        if non_cash > self.amount_due {
+            return Err(SalesError::PaymentExceedsBalance);
+        }
+
+        let overpaid = cash + non_cash - self.amount_due;
+        if overpaid > Decimal::ZERO {
+            self.change_given = overpaid;
+        }
+
+        self.payments.extend(payments);
+        self.recalculate_payment_totals();
+        self.updated_at = Utc::now();
+        Ok(())
+    }
+
     /// Applies a percentage discount to the entire sale
     pub fn apply_percentage_discount(&mut self, percent: Decimal) -> Result<(), SalesError> {
         if !self.is_editable() {
@@ -400,6 +554,7 @@ impl Sale {
 
         self.discount_type = Some(DiscountType::Percentage);
         self.discount_value = percent;
+        self.promotion_id = None;
         self.recalculate_totals();
         Ok(())
     }
@@ -412,40 +567,185 @@ impl Sale {
 
         self.discount_type = Some(DiscountType::Fixed);
         self.discount_value = amount;
+        self.promotion_id = None;
         self.recalculate_totals();
         Ok(())
     }
 
-    /// Recalculates all sale totals
+    /// Applies a fixed sale discount granted by a promotion, recording the
+    /// promotion so the receipt shows where the discount came from
+    pub fn apply_promotion_discount(
+        &mut self,
+        promotion_id: PromotionId,
+        amount: Decimal,
+    ) -> Result<(), SalesError> {
+        self.apply_fixed_discount(amount)?;
+        self.promotion_id = Some(promotion_id);
+        Ok(())
+    }
+
+    /// Removes the sale discount if a promotion granted it; discounts given
+    /// by hand are kept
+    pub fn remove_promotion_discount(&mut self) -> Result<(), SalesError> {
+        if !self.is_editable() {
+            return Err(SalesError::SaleNotEditable);
+        }
+        if self.promotion_id.take().is_some() {
+            self.discount_type = None;
+            self.discount_value = Decimal::ZERO;
+            self.recalculate_totals();
+        }
+        Ok(())
+    }
+
+    /// Recalculates all sale totals.
+    ///
+    /// A sale-level discount applies to the lines' value after their own
+    /// discounts and is split across lines by that value; each line's tax is
+    /// then charged on what remains, so exempt lines absorb part of the
+    /// discount without lowering the tax on taxed lines.
     pub fn recalculate_totals(&mut self) {
-        // Sum up item totals
         self.subtotal = self.items.iter().map(|i| i.subtotal()).sum();
-        self.tax_amount = self.items.iter().map(|i| i.tax_amount()).sum();
+        let line_discounts: Decimal = self.items.iter().map(|i| i.discount_amount()).sum();
+        let discounted = self.subtotal - line_discounts;
 
-        // Calculate sale-level discount
-        self.discount_amount = match self.discount_type {
+        let order_discount = match self.discount_type {
             Some(DiscountType::Percentage) => {
-                self.subtotal * (self.discount_value / Decimal::from(100))
+                (discounted * (self.discount_value / Decimal::from(100))).round_dp(2)
             }
-            Some(DiscountType::Fixed) => self.discount_value.min(self.subtotal),
-            None => self.items.iter().map(|i| i.discount_amount()).sum(),
+            Some(DiscountType::Fixed) => self.discount_value.min(discounted),
+            None => Decimal::ZERO,
         };
 
-        // Calculate total
-        self.total = self.subtotal - self.discount_amount + self.tax_amount;
+        if self.discount_type.is_some() {
+            let lines: Vec<(Decimal, Decimal, bool)> = self
+                .items
+                .iter()
+                .map(|i| (i.discounted_subtotal(), i.tax_rate(), i.tax_inclusive()))
+                .collect();
+            let shares = allocate_order_discount(order_discount, &lines);
+            for (item, share) in self.items.iter_mut().zip(shares) {
+                item.apply_order_discount(share.discount_amount, share.tax_amount);
+            }
+        } else {
+            for item in &mut self.items {
+                item.clear_order_discount();
+            }
+        }
+
+        self.discount_amount = line_discounts + order_discount;
+        self.tax_amount = self.items.iter().map(|i| i.tax_amount()).sum();
+        // Tax-inclusive lines already carry their tax in the subtotal
+        let added_tax: Decimal = self
+            .items
+            .iter()
+            .filter(|i| !i.tax_inclusive())
+            .map(|i| i.tax_amount())
+            .sum();
+        self.total = self.subtotal - self.discount_amount + added_tax;
         self.recalculate_payment_totals();
     }
 
+    /// Taxes lines under the tax rules selected for them, given as (line,
+    /// rule, whether the customer is exempt from the rule)
+    pub fn apply_tax_rules<'a>(
+        &mut self,
+        lines: impl IntoIterator<Item = (SaleItemId, &'a TaxRule, bool)>,
+    ) -> Result<(), SalesError> {
+        if !self.is_editable() {
+            return Err(SalesError::SaleNotEditable);
+        }
+        for (item_id, rule, exempt) in lines {
+            let item = self
+                .items
+                .iter_mut()
+                .find(|i| i.id() == item_id)
+                .ok_or(SalesError::SaleItemNotFound(item_id.into_uuid()))?;
+            item.apply_tax_rule(rule, exempt);
+        }
+        self.recalculate_totals();
+        self.updated_at = Utc::now();
+        Ok(())
+    }
+
+    /// The sale's tax grouped by rule, jurisdiction and rate, in line order
+    /// of first appearance
+    pub fn tax_breakdown(&self) -> Vec<SaleTaxBreakdown> {
+        let mut groups: Vec<SaleTaxBreakdown> = Vec::new();
+        for item in &self.items {
+            let group = groups.iter_mut().find(|g| {
+                g.tax_rule_id == item.tax_rule_id()
+                    && g.jurisdiction.as_deref() == item.tax_jurisdiction()
+                    && g.tax_rate == item.tax_rate()
+                    && g.tax_inclusive == item.tax_inclusive()
+                    && g.tax_exempt == item.tax_exempt()
+            });
+            match group {
+                Some(group) => {
+                    group.taxable_amount += item.taxable_amount();
+                    group.tax_amount += item.tax_amount();
+                }
+                None => groups.push(SaleTaxBreakdown {
+                    tax_rule_id: item.tax_rule_id(),
+                    jurisdiction: item.tax_jurisdiction().map(str::to_string),
+                    tax_rate: item.tax_rate(),
+                    tax_inclusive: item.tax_inclusive(),
+                    tax_exempt: item.tax_exempt(),
+                    taxable_amount: item.taxable_amount(),
+                    tax_amount: item.tax_amount(),
+                }),
+            }
+        }
+        groups
+    }
+
     /// Recalculates payment totals
     fn recalculate_payment_totals(&mut self) {
         self.amount_paid = self
             .payments
             .iter()
             .filter(|p| p.is_successful())
-            .map(|p| p.net_amount())
+            .map(|p| p.net_base_amount())
             .sum();
 
         self.amount_due = (self.total - self.amount_paid).max(Decimal::ZERO);
+
+        // An open sale is partially paid while its tenders don't cover it
+        if self.status.is_editable() {
+            self.status = if self.amount_paid > Decimal::ZERO && self.amount_due > Decimal::ZERO {
+                SaleStatus::PartiallyPaid
+            } else {
+                SaleStatus::Draft
+            };
+        }
+    }
+
+    /// Each line's share of the sale totals.
+    ///
+    /// Discounts and tax come from the per-line allocation made by
+    /// `recalculate_totals`, so the allocations add up to the sale totals
+    /// exactly. Tax-inclusive lines are stated without their tax, so every
+    /// allocation adds its tax on top of the subtotal.
+    pub fn line_allocations(&self) -> Vec<SaleLineAllocation> {
+        self.items
+            .iter()
+            .map(|item| {
+                let (unit_price, subtotal) = if item.tax_inclusive() {
+                    let subtotal = item.subtotal() - item.tax_amount();
+                    (subtotal / item.quantity(), subtotal)
+                } else {
+                    (item.unit_price(), item.subtotal())
+                };
+                SaleLineAllocation {
+                    sale_item_id: item.id(),
+                    quantity: item.quantity(),
+                    unit_price,
+                    subtotal,
+                    discount_amount: item.discount_amount() + item.order_discount_amount(),
+                    tax_amount: item.tax_amount(),
+                }
+            })
+            .collect()
     }
 
     // =========================================================================
@@ -457,6 +757,11 @@ impl Sale {
         self.status.is_editable()
     }
 
+    /// Returns true if payments can be taken against the sale
+    pub fn accepts_payments(&self) -> bool {
+        self.status.accepts_payments()
+    }
+
     /// Returns true if the sale is fully paid
     pub fn is_fully_paid(&self) -> bool {
         self.amount_paid >= self.total
@@ -472,6 +777,50 @@ impl Sale {
         self.items.len()
     }
 
+    /// Returns the amount of successful payments charged to the customer's
+    /// account
+    pub fn on_account_amount(&self) -> Decimal {
+        self.payments
+            .iter()
+            .filter(|p| p.payment_method().is_on_account() && p.is_successful())
+            .map(|p| p.net_base_amount())
+            .sum()
+    }
+
+    /// Returns the amount of successful payments spent from the customer's
+    /// store credit
+    pub fn store_credit_amount(&self) -> Decimal {
+        self.payments
+            .iter()
+            .filter(|p| p.payment_method() == PaymentMethod::StoreCredit && p.is_successful())
+            .map(|p| p.net_base_amount())
+            .sum()
+    }
+
+    /// Returns true if the sale is quoted in a currency other than its base
+    /// currency
+    pub fn is_foreign_currency(&self) -> bool {
+        self.transaction_currency != self.currency
+    }
+
+    /// Returns the total in the transaction currency
+    pub fn transaction_total(&self) -> Decimal {
+        self.to_transaction_currency(self.total)
+    }
+
+    /// Returns the amount due in the transaction currency
+    pub fn transaction_amount_due(&self) -> Decimal {
+        self.to_transaction_currency(self.amount_due)
+    }
+
+    fn to_transaction_currency(&self, amount: Decimal) -> Decimal {
+        if self.is_foreign_currency() {
+            (amount / self.exchange_rate).round_dp(2)
+        } else {
+            amount
+        }
+    }
+
     /// Returns the total quantity of all items
     pub fn total_quantity(&self) -> Decimal {
         self.items.iter().map(|i| i.quantity()).sum()
@@ -482,7 +831,7 @@ impl Sale {
         self.payments
             .iter()
             .filter(|p| p.is_successful())
-            .max_by(|a, b| a.amount().cmp(&b.amount()))
+            .max_by(|a, b| a.base_amount().cmp(&b.base_amount()))
             .map(|p| p.payment_method())
     }
 
@@ -534,6 +883,14 @@ impl Sale {
         &self.currency
     }
 
+    pub fn transaction_currency(&self) -> &Currency {
+        &self.transaction_currency
+    }
+
+    pub fn exchange_rate(&self) -> Decimal {
+        self.exchange_rate
+    }
+
     pub fn subtotal(&self) -> Decimal {
         self.subtotal
     }
@@ -546,6 +903,10 @@ impl Sale {
         self.discount_value
     }
 
+    pub fn promotion_id(&self) -> Option<PromotionId> {
+        self.promotion_id
+    }
+
     pub fn discount_amount(&self) -> Decimal {
         self.discount_amount
     }
@@ -578,6 +939,10 @@ impl Sale {
         self.invoice_date
     }
 
+    pub fn cai_number(&self) -> Option<&str> {
+        self.cai_number.as_deref()
+    }
+
     pub fn notes(&self) -> Option<&str> {
         self.notes.as_deref()
     }
@@ -639,6 +1004,27 @@ impl Sale {
         Ok(())
     }
 
+    /// Sets the currency the sale is quoted in and its rate to the base
+    /// currency; the base currency always has a rate of 1
+    pub fn set_transaction_currency(
+        &mut self,
+        currency: Currency,
+        exchange_rate: Decimal,
+    ) -> Result<(), SalesError> {
+        if !self.is_editable() {
+            return Err(SalesError::SaleNotEditable);
+        }
+        if currency == self.currency {
+            self.exchange_rate = Decimal::ONE;
+        } else {
+            validate_rate(exchange_rate)?;
+            self.exchange_rate = exchange_rate;
+        }
+        self.transaction_currency = currency;
+        self.updated_at = Utc::now();
+        Ok(())
+    }
+
     pub fn set_notes(&mut self, notes: Option<String>) -> Result<(), SalesError> {
         if !self.is_editable() {
             return Err(SalesError::SaleNotEditable);
@@ -718,6 +1104,50 @@ mod tests {
         assert_eq!(sale.order_status(), Some(OrderStatus::PendingPayment));
     }
 
+    #[test]
+    fn test_pickup_workflow() {
+        let mut sale = Sale::create_online(
+            "ORDER-002".to_string(),
+            StoreId::new(),
+            Currency::new("USD").unwrap(),
+        );
+
+        assert!(matches!(
+            sale.mark_ready_for_pickup(),
+            Err(SalesError::OrderNotPaid)
+        ));
+        assert!(matches!(
+            sale.complete_pickup(),
+            Err(SalesError::OrderNotReadyForPickup)
+        ));
+
+        sale.mark_paid().unwrap();
+        sale.mark_ready_for_pickup().unwrap();
+        assert_eq!(sale.order_status(), Some(OrderStatus::ReadyForPickup));
+        assert!(matches!(sale.ship(), Err(SalesError::OrderNotProcessing)));
+
+        sale.complete_pickup().unwrap();
+        assert_eq!(sale.order_status(), Some(OrderStatus::PickedUp));
+        assert_eq!(sale.status(), SaleStatus::Completed);
+        assert!(sale.completed_at().is_some());
+    }
+
+    #[test]
+    fn test_ready_for_pickup_order_can_be_cancelled() {
+        let mut sale = Sale::create_online(
+            "ORDER-003".to_string(),
+            StoreId::new(),
+            Currency::new("USD").unwrap(),
+        );
+        sale.mark_paid().unwrap();
+        sale.mark_ready_for_pickup().unwrap();
+
+        sale.cancel_order().unwrap();
+
+        assert_eq!(sale.order_status(), Some(OrderStatus::Cancelled));
+        assert_eq!(sale.status(), SaleStatus::Voided);
+    }
+
     #[test]
     fn test_add_item() {
         let mut sale = create_test_pos_sale();
@@ -751,6 +1181,35 @@ mod tests {
         assert_eq!(sale.invoice_number(), Some("INV-001"));
     }
 
+    #[test]
+    fn test_assign_cai_invoice_number() {
+        let mut sale = create_test_pos_sale();
+        let item = create_test_item(sale.id());
+        sale.add_item(item).unwrap();
+
+        let result = sale.assign_cai_invoice_number("CAI-001".to_string(), "00000001".to_string());
+        assert!(matches!(result, Err(SalesError::InvalidStatusTransition)));
+
+        let payment = Payment::create_cash(
+            sale.id(),
+            sale.total(),
+            Currency::new("USD").unwrap(),
+            sale.total(),
+        )
+        .unwrap();
+        sale.add_payment(payment).unwrap();
+        sale.complete(String::new()).unwrap();
+
+        sale.assign_cai_invoice_number("CAI-001".to_string(), "00000001".to_string())
+            .unwrap();
+        assert_eq!(sale.cai_number(), Some("CAI-001"));
+        assert_eq!(sale.invoice_number(), Some("00000001"));
+
+        // A sale keeps the first number it was given
+        let result = sale.assign_cai_invoice_number("CAI-001".to_string(), "00000002".to_string());
+        assert!(matches!(result, Err(SalesError::InvalidStatusTransition)));
+    }
+
     #[test]
     fn test_cannot_complete_unpaid() {
         let mut sale = create_test_pos_sale();
@@ -773,6 +1232,180 @@ mod tests {
         assert!(sale.void_reason().is_some());
     }
 
+    #[test]
+    fn test_park_and_resume_sale() {
+        let mut sale = create_test_pos_sale();
+        assert!(matches!(sale.park(), Err(SalesError::EmptySale)));
+
+        let item = create_test_item(sale.id());
+        sale.add_item(item).unwrap();
+        sale.add_payment(
+            Payment::create_cash(sale.id(), dec!(15), Currency::new("USD").unwrap(), dec!(15))
+                .unwrap(),
+        )
+        .unwrap();
+
+        sale.park().unwrap();
+        assert_eq!(sale.status(), SaleStatus::Parked);
+        assert!(!sale.is_editable());
+        assert_eq!(sale.item_count(), 1);
+
+        sale.resume().unwrap();
+        assert_eq!(sale.status(), SaleStatus::PartiallyPaid);
+        assert!(matches!(sale.resume(), Err(SalesError::SaleNotParked)));
+    }
+
+    #[test]
+    fn test_layaway_takes_payments_but_not_items() {
+        let mut sale = create_test_pos_sale();
+        sale.add_item(create_test_item(sale.id())).unwrap();
+        assert!(matches!(
+            sale.start_layaway(),
+            Err(SalesError::LayawayRequiresCustomer)
+        ));
+
+        sale.set_customer_id(Some(CustomerId::new())).unwrap();
+        sale.start_layaway().unwrap();
+        assert_eq!(sale.status(), SaleStatus::Layaway);
+        assert!(!sale.is_editable());
+        assert!(matches!(
+            sale.add_item(create_test_item(sale.id())),
+            Err(SalesError::SaleNotEditable)
+        ));
+
+        let currency = Currency::new("USD").unwrap();
+        sale.add_payment(Payment::create_cash(sale.id(), dec!(15), currency, dec!(15)).unwrap())
+            .unwrap();
+        assert_eq!(sale.status(), SaleStatus::Layaway);
+        assert!(sale.amount_paid() > Decimal::ZERO);
+    }
+
+    #[test]
+    fn test_on_account_amount() {
+        let mut sale = create_test_pos_sale();
+        let item = create_test_item(sale.id());
+        sale.add_item(item).unwrap();
+        let currency = Currency::new("USD").unwrap();
+
+        sale.add_payment(
+            Payment::create_cash(sale.id(), dec!(30), currency.clone(), dec!(30)).unwrap(),
+        )
+        .unwrap();
+        let mut on_account =
+            Payment::create(sale.id(), PaymentMethod::OnAccount, dec!(50), currency).unwrap();
+        on_account.complete(None).unwrap();
+        sale.add_payment(on_account).unwrap();
+
+        assert_eq!(sale.on_account_amount(), dec!(50));
+    }
+
+    #[test]
+    fn test_store_credit_amount() {
+        let mut sale = create_test_pos_sale();
+        let item = create_test_item(sale.id());
+        sale.add_item(item).unwrap();
+        let currency = Currency::new("USD").unwrap();
+
+        let mut store_credit =
+            Payment::create(sale.id(), PaymentMethod::StoreCredit, dec!(40), currency).unwrap();
+        store_credit.complete(None).unwrap();
+        sale.add_payment(store_credit).unwrap();
+
+        assert_eq!(sale.store_credit_amount(), dec!(40));
+        assert_eq!(sale.on_account_amount(), Decimal::ZERO);
+    }
+
+    #[test]
+    fn test_foreign_currency_tender_covers_base_total() {
+        let mut sale = create_test_pos_sale();
+        let item = create_test_item(sale.id());
+        sale.add_item(item).unwrap();
+        assert_eq!(sale.total(), dec!(115.00));
+
+        sale.set_transaction_currency(Currency::eur(), dec!(1.10))
+            .unwrap();
+        assert!(sale.is_foreign_currency());
+        assert_eq!(sale.transaction_total(), dec!(104.55));
+        assert!(
+            sale.set_transaction_currency(Currency::eur(), Decimal::ZERO)
+                .is_err()
+        );
+
+        let mut cash =
+            Payment::create_cash(sale.id(), dec!(100), Currency::eur(), dec!(105)).unwrap();
+        cash.set_exchange_rate(dec!(1.10)).unwrap();
+        sale.add_payment(cash).unwrap();
+        assert_eq!(sale.amount_paid(), dec!(110.00));
+        assert_eq!(sale.amount_due(), dec!(5.00));
+        assert_eq!(sale.transaction_amount_due(), dec!(4.55));
+
+        // Back to the base currency, the rate resets to 1
+        sale.set_transaction_currency(Currency::usd(), dec!(1.10))
+            .unwrap();
+        assert_eq!(sale.exchange_rate(), Decimal::ONE);
+        assert_eq!(sale.transaction_total(), dec!(115.00));
+    }
+
+    #[test]
+    fn test_split_tender_payment() {
+        let mut sale = create_test_pos_sale();
+        let item = create_test_item(sale.id());
+        sale.add_item(item).unwrap();
+        let currency = Currency::new("USD").unwrap();
+
+        let mut card = Payment::create(
+            sale.id(),
+            PaymentMethod::CreditCard,
+            dec!(100),
+            currency.clone(),
+        )
+        .unwrap();
+        card.complete(None).unwrap();
+        sale.add_payments(vec![card]).unwrap();
+
+        assert_eq!(sale.status(), SaleStatus::PartiallyPaid);
+        assert_eq!(sale.amount_due(), dec!(15));
+        assert!(matches!(
+            sale.complete("INV-001".to_string()),
+            Err(SalesError::SaleNotFullyPaid)
+        ));
+
+        sale.add_payments(vec![
+            Payment::create_cash(sale.id(), dec!(20), currency, dec!(20)).unwrap(),
+        ])
+        .unwrap();
+
+        assert_eq!(sale.status(), SaleStatus::Draft);
+        assert_eq!(sale.change_given(), dec!(5));
+        sale.complete("INV-001".to_string()).unwrap();
+        assert_eq!(sale.status(), SaleStatus::Completed);
+    }
+
+    #[test]
+    fn test_split_tender_rejects_non_cash_overpayment() {
+        let mut sale = create_test_pos_sale();
+        let item = create_test_item(sale.id());
+        sale.add_item(item).unwrap();
+        let currency = Currency::new("USD").unwrap();
+
+        let mut card = Payment::create(
+            sale.id(),
+            PaymentMethod::DebitCard,
+            dec!(100),
+            currency.clone(),
+        )
+        .unwrap();
+        card.complete(None).unwrap();
+        let mut other =
+            Payment::create(sale.id(), PaymentMethod::CreditCard, dec!(20), currency).unwrap();
+        other.complete(None).unwrap();
+
+        let result = sale.add_payments(vec![card, other]);
+
+        assert!(matches!(result, Err(SalesError::PaymentExceedsBalance)));
+        assert!(sale.payments().is_empty());
+    }
+
     #[test]
     fn test_sale_discount() {
         let mut sale = create_test_pos_sale();
@@ -785,4 +1418,144 @@ mod tests {
         assert!(sale.total() < original_total);
         assert_eq!(sale.discount_type(), Some(DiscountType::Percentage));
     }
+
+    #[test]
+    fn test_order_discount_split_across_taxed_and_exempt_lines() {
+        use inventory::ProductId;
+
+        let mut sale = create_test_pos_sale();
+        for (line, price, tax_rate) in [(1, dec!(100), dec!(15)), (2, dec!(50), dec!(0))] {
+            let item = SaleItem::create(
+                sale.id(),
+                line,
+                ProductId::new(),
+                None,
+                format!("SKU-{}", line),
+                "Test Product".to_string(),
+                dec!(1),
+                UnitOfMeasure::from_str("unit").unwrap(),
+                price,
+                dec!(10),
+                tax_rate,
+            )
+            .unwrap();
+            sale.add_item(item).unwrap();
+        }
+        sale.apply_percentage_discount(dec!(10)).unwrap();
+
+        // 15.00 off splits 10.00 / 5.00; tax is only on the taxed line's 90.00
+        let taxed = &sale.items()[0];
+        let exempt = &sale.items()[1];
+        assert_eq!(taxed.order_discount_amount(), dec!(10.00));
+        assert_eq!(taxed.tax_amount(), dec!(13.50));
+        assert_eq!(taxed.total(), dec!(103.50));
+        assert_eq!(exempt.order_discount_amount(), dec!(5.00));
+        assert_eq!(exempt.tax_amount(), dec!(0));
+        assert_eq!(exempt.total(), dec!(45.00));
+
+        assert_eq!(sale.discount_amount(), dec!(15.00));
+        assert_eq!(sale.tax_amount(), dec!(13.50));
+        assert_eq!(sale.total(), dec!(148.50));
+    }
+
+    #[test]
+    fn test_line_allocations_reconcile_with_total() {
+        use inventory::ProductId;
+
+        let mut sale = create_test_pos_sale();
+        // Three equal lines; a fixed 10.00 discount does not split evenly
+        for line in 1..=3 {
+            let item = SaleItem::create(
+                sale.id(),
+                line,
+                ProductId::new(),
+                None,
+                format!("SKU-{}", line),
+                "Test Product".to_string(),
+                dec!(1),
+                UnitOfMeasure::from_str("unit").unwrap(),
+                dec!(10),
+                dec!(5),
+                dec!(15),
+            )
+            .unwrap();
+            sale.add_item(item).unwrap();
+        }
+        sale.apply_fixed_discount(dec!(10)).unwrap();
+
+        let allocations = sale.line_allocations();
+
+        let discounts: Decimal = allocations.iter().map(|a| a.discount_amount).sum();
+        assert_eq!(discounts, dec!(10));
+        assert_eq!(allocations[0].discount_amount, dec!(3.33));
+        let net: Decimal = allocations.iter().map(|a| a.net_total()).sum();
+        assert_eq!(net, sale.total());
+    }
+
+    #[test]
+    fn test_tax_rules_with_inclusive_and_exempt_lines() {
+        use crate::domain::value_objects::CustomerType;
+        use inventory::ProductId;
+
+        let mut sale = create_test_pos_sale();
+        for line in 1..=3 {
+            let item = SaleItem::create(
+                sale.id(),
+                line,
+                ProductId::new(),
+                None,
+                format!("SKU-{}", line),
+                "Test Product".to_string(),
+                dec!(1),
+                UnitOfMeasure::from_str("unit").unwrap(),
+                dec!(115),
+                dec!(50),
+                dec!(0),
+            )
+            .unwrap();
+            sale.add_item(item).unwrap();
+        }
+        let rule = |rate, tax_inclusive, exempt_customer_types| {
+            TaxRule::create(
+                uuid::Uuid::now_v7(),
+                "HN-ISV".to_string(),
+                None,
+                None,
+                rate,
+                tax_inclusive,
+                exempt_customer_types,
+                UserId::new(),
+            )
+            .unwrap()
+        };
+        let inclusive = rule(dec!(15), true, vec![]);
+        let exclusive = rule(dec!(18), false, vec![CustomerType::Business]);
+        let ids: Vec<SaleItemId> = sale.items().iter().map(|i| i.id()).collect();
+
+        sale.apply_tax_rules([
+            (ids[0], &inclusive, false),
+            (ids[1], &exclusive, false),
+            (ids[2], &exclusive, true),
+        ])
+        .unwrap();
+        sale.apply_fixed_discount(dec!(10)).unwrap();
+
+        assert_eq!(sale.items()[0].total(), sale.items()[0].net_amount());
+        assert_eq!(
+            sale.total(),
+            dec!(345) - dec!(10) + sale.items()[1].tax_amount()
+        );
+
+        let breakdown = sale.tax_breakdown();
+        assert_eq!(breakdown.len(), 3);
+        assert!(breakdown[0].tax_inclusive);
+        assert_eq!(breakdown[1].tax_rate, dec!(18));
+        assert!(breakdown[2].tax_exempt);
+        assert_eq!(breakdown[2].tax_amount, dec!(0));
+        let tax: Decimal = breakdown.iter().map(|b| b.tax_amount).sum();
+        assert_eq!(tax, sale.tax_amount());
+
+        let net: Decimal = sale.line_allocations().iter().map(|a| a.net_total()).sum();
+        assert_eq!(net, sale.total());
+    }
 }
@@ -0,0 +1,186 @@
+//! SaleIdempotencyKey entity - a terminal's key for a sale request it may retry
+
+use chrono::{DateTime, Duration, Utc};
+use pos_core::TerminalId;
+use serde::{Deserialize, Serialize};
+
+use crate::SalesError;
+use crate::domain::value_objects::{IdempotentOperation, SaleId};
+
+/// Hours a terminal's idempotency key is remembered
+pub const IDEMPOTENCY_KEY_TTL_HOURS: i64 = 24;
+
+/// Maximum length of an idempotency key
+pub const MAX_IDEMPOTENCY_KEY_LENGTH: usize = 255;
+
+/// An idempotency key a terminal sent with a sale request.
+///
+/// Terminals on flaky connections retry requests whose response was lost.
+/// A retry carrying the same key returns the sale the first request
+/// produced instead of completing or charging it again. Keys are scoped per
+/// terminal and forgotten after `IDEMPOTENCY_KEY_TTL_HOURS`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SaleIdempotencyKey {
+    terminal_id: TerminalId,
+    key: String,
+    operation: IdempotentOperation,
+    sale_id: SaleId,
+    created_at: DateTime<Utc>,
+    expires_at: DateTime<Utc>,
+}
+
+impl SaleIdempotencyKey {
+    /// Records that the terminal's key produced `operation` on the sale
+    pub fn record(
+        terminal_id: TerminalId,
+        key: &str,
+        operation: IdempotentOperation,
+        sale_id: SaleId,
+    ) -> Result<Self, SalesError> {
+        let key = normalize_key(key)?;
+        let now = Utc::now();
+        Ok(Self {
+            terminal_id,
+            key,
+            operation,
+            sale_id,
+            created_at: now,
+            expires_at: now + Duration::hours(IDEMPOTENCY_KEY_TTL_HOURS),
+        })
+    }
+
+    /// Reconstitutes a SaleIdempotencyKey from persistence
+    pub fn reconstitute(
+        terminal_id: TerminalId,
+        key: String,
+        operation: IdempotentOperation,
+        sale_id: SaleId,
+        created_at: DateTime<Utc>,
+        expires_at: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            terminal_id,
+            key,
+            operation,
+            sale_id,
+            created_at,
+            expires_at,
+        }
+    }
+
+    /// Checks that a retry carrying this key is the same request: the same
+    /// operation on the same sale
+    pub fn ensure_replay_of(
+        &self,
+        sale_id: SaleId,
+        operation: IdempotentOperation,
+    ) -> Result<(), SalesError> {
+        if self.sale_id != sale_id || self.operation != operation {
+            return Err(SalesError::IdempotencyKeyReused(self.key.clone()));
+        }
+        Ok(())
+    }
+
+    /// Returns true once the key is no longer remembered at `now`
+    pub fn is_expired(&self, now: DateTime<Utc>) -> bool {
+        now >= self.expires_at
+    }
+
+    // =========================================================================
+    // Getters
+    // =========================================================================
+
+    pub fn terminal_id(&self) -> TerminalId {
+        self.terminal_id
+    }
+
+    pub fn key(&self) -> &str {
+        &self.key
+    }
+
+    pub fn operation(&self) -> IdempotentOperation {
+        self.operation
+    }
+
+    pub fn sale_id(&self) -> SaleId {
+        self.sale_id
+    }
+
+    pub fn created_at(&self) -> DateTime<Utc> {
+        self.created_at
+    }
+
+    pub fn expires_at(&self) -> DateTime<Utc> {
+        self.expires_at
+    }
+}
+
+/// Trims a client-supplied key, rejecting blank or overlong keys
+fn normalize_key(key: &str) -> Result<String, SalesError> {
+    let key = key.trim();
+    if key.is_empty() || key.chars().count() > MAX_IDEMPOTENCY_KEY_LENGTH {
+        return Err(SalesError::InvalidIdempotencyKey);
+    }
+    Ok(key.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_record_expires_after_ttl() {
+        let record = SaleIdempotencyKey::record(
+            TerminalId::new(),
+            " retry-1 ",
+            IdempotentOperation::Complete,
+            SaleId::new(),
+        )
+        .unwrap();
+
+        assert_eq!(record.key(), "retry-1");
+        assert!(!record.is_expired(Utc::now()));
+        assert!(record.is_expired(Utc::now() + Duration::hours(IDEMPOTENCY_KEY_TTL_HOURS)));
+    }
+
+    #[test]
+    fn test_record_rejects_invalid_keys() {
+        for key in ["  ", &"k".repeat(MAX_IDEMPOTENCY_KEY_LENGTH + 1)] {
+            assert!(matches!(
+                SaleIdempotencyKey::record(
+                    TerminalId::new(),
+                    key,
+                    IdempotentOperation::Payment,
+                    SaleId::new(),
+                ),
+                Err(SalesError::InvalidIdempotencyKey)
+            ));
+        }
+    }
+
+    #[test]
+    fn test_replay_must_match_sale_and_operation() {
+        let sale_id = SaleId::new();
+        let record = SaleIdempotencyKey::record(
+            TerminalId::new(),
+            "retry-1",
+            IdempotentOperation::Payment,
+            sale_id,
+        )
+        .unwrap();
+
+        assert!(
+            record
+                .ensure_replay_of(sale_id, IdempotentOperation::Payment)
+                .is_ok()
+        );
+        assert!(matches!(
+            record.ensure_replay_of(sale_id, IdempotentOperation::Complete),
+            Err(SalesError::IdempotencyKeyReused(_))
+        ));
+        assert!(matches!(
+            record.ensure_replay_of(SaleId::new(), IdempotentOperation::Payment),
+            Err(SalesError::IdempotencyKeyReused(_))
+        ));
+    }
+}
@@ -5,7 +5,10 @@ use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 
 use crate::SalesError;
-use crate::domain::value_objects::{DiscountType, SaleId, SaleItemId};
+use crate::domain::entities::TaxRule;
+use crate::domain::value_objects::{
+    DiscountType, PriceListId, PriceTierId, PromotionId, SaleId, SaleItemId, TaxRuleId, line_tax,
+};
 use inventory::{ProductId, ReservationId, UnitOfMeasure, VariantId};
 
 /// SaleItem entity representing a line item in a sale.
@@ -14,6 +17,8 @@ use inventory::{ProductId, ReservationId, UnitOfMeasure, VariantId};
 /// - Quantity must be positive
 /// - Unit price must be non-negative
 /// - Line number must be positive
+/// - Serial numbers, when captured, match the quantity one-to-one
+/// - A tax-inclusive line's total is its net amount; the tax is part of it
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SaleItem {
     id: SaleItemId,
@@ -30,11 +35,20 @@ pub struct SaleItem {
     discount_type: Option<DiscountType>,
     discount_value: Decimal,
     discount_amount: Decimal,
+    order_discount_amount: Decimal,
     tax_rate: Decimal,
     tax_amount: Decimal,
+    tax_inclusive: bool,
+    tax_exempt: bool,
+    tax_rule_id: Option<TaxRuleId>,
+    tax_jurisdiction: Option<String>,
     subtotal: Decimal,
     total: Decimal,
     reservation_id: Option<ReservationId>,
+    serial_numbers: Vec<String>,
+    price_tier_id: Option<PriceTierId>,
+    price_list_id: Option<PriceListId>,
+    promotion_id: Option<PromotionId>,
     notes: Option<String>,
     created_at: DateTime<Utc>,
     updated_at: DateTime<Utc>,
@@ -83,11 +97,20 @@ impl SaleItem {
             discount_type: None,
             discount_value: Decimal::ZERO,
             discount_amount: Decimal::ZERO,
+            order_discount_amount: Decimal::ZERO,
             tax_rate,
             tax_amount,
+            tax_inclusive: false,
+            tax_exempt: false,
+            tax_rule_id: None,
+            tax_jurisdiction: None,
             subtotal,
             total,
             reservation_id: None,
+            serial_numbers: Vec::new(),
+            price_tier_id: None,
+            price_list_id: None,
+            promotion_id: None,
             notes: None,
             created_at: now,
             updated_at: now,
@@ -111,11 +134,20 @@ impl SaleItem {
         discount_type: Option<DiscountType>,
         discount_value: Decimal,
         discount_amount: Decimal,
+        order_discount_amount: Decimal,
         tax_rate: Decimal,
         tax_amount: Decimal,
+        tax_inclusive: bool,
+        tax_exempt: bool,
+        tax_rule_id: Option<TaxRuleId>,
+        tax_jurisdiction: Option<String>,
         subtotal: Decimal,
         total: Decimal,
         reservation_id: Option<ReservationId>,
+        serial_numbers: Vec<String>,
+        price_tier_id: Option<PriceTierId>,
+        price_list_id: Option<PriceListId>,
+        promotion_id: Option<PromotionId>,
         notes: Option<String>,
         created_at: DateTime<Utc>,
         updated_at: DateTime<Utc>,
@@ -135,11 +167,20 @@ impl SaleItem {
             discount_type,
             discount_value,
             discount_amount,
+            order_discount_amount,
             tax_rate,
             tax_amount,
+            tax_inclusive,
+            tax_exempt,
+            tax_rule_id,
+            tax_jurisdiction,
             subtotal,
             total,
             reservation_id,
+            serial_numbers,
+            price_tier_id,
+            price_list_id,
+            promotion_id,
             notes,
             created_at,
             updated_at,
@@ -158,6 +199,7 @@ impl SaleItem {
 
         self.discount_type = Some(DiscountType::Percentage);
         self.discount_value = percent;
+        self.promotion_id = None;
         self.recalculate_totals();
         Ok(())
     }
@@ -170,14 +212,28 @@ impl SaleItem {
 
         self.discount_type = Some(DiscountType::Fixed);
         self.discount_value = amount;
+        self.promotion_id = None;
         self.recalculate_totals();
         Ok(())
     }
 
+    /// Applies a fixed discount granted by a promotion, recording the
+    /// promotion so the receipt shows where the discount came from
+    pub fn apply_promotion_discount(
+        &mut self,
+        promotion_id: PromotionId,
+        amount: Decimal,
+    ) -> Result<(), SalesError> {
+        self.apply_fixed_discount(amount)?;
+        self.promotion_id = Some(promotion_id);
+        Ok(())
+    }
+
     /// Removes any discount from the item
     pub fn remove_discount(&mut self) {
         self.discount_type = None;
         self.discount_value = Decimal::ZERO;
+        self.promotion_id = None;
         self.recalculate_totals();
     }
 
@@ -191,12 +247,15 @@ impl SaleItem {
         Ok(())
     }
 
-    /// Updates the unit price
+    /// Updates the unit price. A manually set price no longer comes from
+    /// a price tier or price list, so the recorded source is cleared.
     pub fn set_unit_price(&mut self, unit_price: Decimal) -> Result<(), SalesError> {
         if unit_price < Decimal::ZERO {
             return Err(SalesError::InvalidUnitPrice);
         }
         self.unit_price = unit_price;
+        self.price_tier_id = None;
+        self.price_list_id = None;
         self.recalculate_totals();
         Ok(())
     }
@@ -214,15 +273,74 @@ impl SaleItem {
             None => Decimal::ZERO,
         };
 
-        let after_discount = self.subtotal - self.discount_amount;
-        self.tax_amount = after_discount * (self.tax_rate / Decimal::from(100));
-        self.total = after_discount + self.tax_amount;
+        self.tax_amount = line_tax(self.net_amount(), self.tax_rate, self.tax_inclusive);
+        self.total = self.total_with_tax();
+        self.updated_at = Utc::now();
+    }
+
+    /// What the customer pays for the line: the net amount, plus the tax
+    /// unless the price already includes it
+    fn total_with_tax(&self) -> Decimal {
+        if self.tax_inclusive {
+            self.net_amount()
+        } else {
+            self.net_amount() + self.tax_amount
+        }
+    }
+
+    /// Line value after the line discount, before any sale-level discount
+    pub fn discounted_subtotal(&self) -> Decimal {
+        self.subtotal - self.discount_amount
+    }
+
+    /// Line value after both the line discount and its share of the
+    /// sale-level discount; the base tax is charged on
+    pub fn net_amount(&self) -> Decimal {
+        self.discounted_subtotal() - self.order_discount_amount
+    }
+
+    /// Records the line's share of a sale-level discount and the tax on the
+    /// remaining value, as allocated by the sale
+    pub(crate) fn apply_order_discount(&mut self, discount_amount: Decimal, tax_amount: Decimal) {
+        self.order_discount_amount = discount_amount;
+        self.tax_amount = tax_amount;
+        self.total = self.total_with_tax();
         self.updated_at = Utc::now();
     }
 
+    /// Line value the tax is charged on: the net amount, less the tax when
+    /// the price includes it
+    pub fn taxable_amount(&self) -> Decimal {
+        if self.tax_inclusive {
+            self.net_amount() - self.tax_amount
+        } else {
+            self.net_amount()
+        }
+    }
+
+    /// Taxes the line under `rule`, taking its rate, jurisdiction and
+    /// whether prices include the tax. An `exempt` line carries no tax; a
+    /// tax-inclusive price is still charged as marked.
+    pub(crate) fn apply_tax_rule(&mut self, rule: &TaxRule, exempt: bool) {
+        self.tax_rule_id = Some(rule.id());
+        self.tax_jurisdiction = Some(rule.jurisdiction().to_string());
+        self.tax_rate = if exempt { Decimal::ZERO } else { rule.rate() };
+        self.tax_inclusive = rule.tax_inclusive();
+        self.tax_exempt = exempt;
+        self.recalculate_totals();
+    }
+
+    /// Drops the line's share of a sale-level discount that no longer applies
+    pub(crate) fn clear_order_discount(&mut self) {
+        if self.order_discount_amount != Decimal::ZERO {
+            self.order_discount_amount = Decimal::ZERO;
+            self.recalculate_totals();
+        }
+    }
+
     /// Returns the gross profit for this item
     pub fn gross_profit(&self) -> Decimal {
-        let revenue = self.subtotal - self.discount_amount;
+        let revenue = self.net_amount();
         let cost = self.quantity * self.unit_cost;
         revenue - cost
     }
@@ -233,6 +351,45 @@ impl SaleItem {
         self.updated_at = Utc::now();
     }
 
+    /// Records the price tier the line's unit price was resolved from
+    pub fn set_price_tier_id(&mut self, price_tier_id: Option<PriceTierId>) {
+        self.price_tier_id = price_tier_id;
+        self.updated_at = Utc::now();
+    }
+
+    /// Records the price list the line's unit price was resolved from
+    pub fn set_price_list_id(&mut self, price_list_id: Option<PriceListId>) {
+        self.price_list_id = price_list_id;
+        self.updated_at = Utc::now();
+    }
+
+    /// Records the serial numbers of the units sold on this line.
+    ///
+    /// The quantity must be a whole number of units and each unit needs
+    /// exactly one distinct serial. Passing an empty list clears them.
+    pub fn set_serial_numbers(&mut self, serial_numbers: Vec<String>) -> Result<(), SalesError> {
+        if !serial_numbers.is_empty() {
+            if self.quantity.fract() != Decimal::ZERO
+                || Decimal::from(serial_numbers.len()) != self.quantity
+            {
+                return Err(SalesError::SerialCountMismatch);
+            }
+            let mut seen = std::collections::HashSet::new();
+            if let Some(dup) = serial_numbers.iter().find(|s| !seen.insert(s.as_str())) {
+                return Err(SalesError::DuplicateSerialNumber(dup.clone()));
+            }
+        }
+        self.serial_numbers = serial_numbers;
+        self.updated_at = Utc::now();
+        Ok(())
+    }
+
+    /// Returns true if every unit on the line has a serial recorded
+    pub fn has_complete_serials(&self) -> bool {
+        self.quantity.fract() == Decimal::ZERO
+            && Decimal::from(self.serial_numbers.len()) == self.quantity
+    }
+
     // =========================================================================
     // Getters
     // =========================================================================
@@ -293,6 +450,11 @@ impl SaleItem {
         self.discount_amount
     }
 
+    /// The line's share of the sale-level discount
+    pub fn order_discount_amount(&self) -> Decimal {
+        self.order_discount_amount
+    }
+
     pub fn tax_rate(&self) -> Decimal {
         self.tax_rate
     }
@@ -301,6 +463,25 @@ impl SaleItem {
         self.tax_amount
     }
 
+    /// Whether the unit price already includes the tax
+    pub fn tax_inclusive(&self) -> bool {
+        self.tax_inclusive
+    }
+
+    /// Whether the customer is exempt from the tax rule covering the line
+    pub fn tax_exempt(&self) -> bool {
+        self.tax_exempt
+    }
+
+    /// The tax rule that set the line's rate, if any
+    pub fn tax_rule_id(&self) -> Option<TaxRuleId> {
+        self.tax_rule_id
+    }
+
+    pub fn tax_jurisdiction(&self) -> Option<&str> {
+        self.tax_jurisdiction.as_deref()
+    }
+
     pub fn subtotal(&self) -> Decimal {
         self.subtotal
     }
@@ -313,6 +494,22 @@ impl SaleItem {
         self.reservation_id
     }
 
+    pub fn serial_numbers(&self) -> &[String] {
+        &self.serial_numbers
+    }
+
+    pub fn price_tier_id(&self) -> Option<PriceTierId> {
+        self.price_tier_id
+    }
+
+    pub fn price_list_id(&self) -> Option<PriceListId> {
+        self.price_list_id
+    }
+
+    pub fn promotion_id(&self) -> Option<PromotionId> {
+        self.promotion_id
+    }
+
     pub fn notes(&self) -> Option<&str> {
         self.notes.as_deref()
     }
@@ -419,4 +616,105 @@ mod tests {
         // Revenue: 200, Cost: 2 * 60 = 120
         assert_eq!(item.gross_profit(), dec!(80.00));
     }
+
+    #[test]
+    fn test_set_serial_numbers() {
+        let mut item = create_test_item();
+        assert!(!item.has_complete_serials());
+
+        item.set_serial_numbers(vec!["SN-1".to_string(), "SN-2".to_string()])
+            .unwrap();
+
+        assert_eq!(item.serial_numbers(), ["SN-1", "SN-2"]);
+        assert!(item.has_complete_serials());
+    }
+
+    #[test]
+    fn test_serial_count_must_match_quantity() {
+        let mut item = create_test_item();
+
+        let result = item.set_serial_numbers(vec!["SN-1".to_string()]);
+
+        assert!(matches!(result, Err(SalesError::SerialCountMismatch)));
+        assert!(item.serial_numbers().is_empty());
+    }
+
+    #[test]
+    fn test_duplicate_serial_rejected() {
+        let mut item = create_test_item();
+
+        let result = item.set_serial_numbers(vec!["SN-1".to_string(), "SN-1".to_string()]);
+
+        assert!(matches!(result, Err(SalesError::DuplicateSerialNumber(_))));
+    }
+
+    #[test]
+    fn test_quantity_change_invalidates_serials() {
+        let mut item = create_test_item();
+        item.set_serial_numbers(vec!["SN-1".to_string(), "SN-2".to_string()])
+            .unwrap();
+
+        item.set_quantity(dec!(3)).unwrap();
+
+        assert!(!item.has_complete_serials());
+    }
+
+    #[test]
+    fn test_manual_price_clears_price_tier() {
+        let mut item = create_test_item();
+        item.set_price_tier_id(Some(PriceTierId::new()));
+
+        item.set_unit_price(dec!(90.00)).unwrap();
+
+        assert!(item.price_tier_id().is_none());
+    }
+
+    #[test]
+    fn test_manual_price_clears_price_list() {
+        let mut item = create_test_item();
+        item.set_price_list_id(Some(PriceListId::new()));
+
+        item.set_unit_price(dec!(90.00)).unwrap();
+
+        assert!(item.price_list_id().is_none());
+    }
+
+    fn tax_rule(rate: Decimal, tax_inclusive: bool) -> TaxRule {
+        TaxRule::create(
+            uuid::Uuid::now_v7(),
+            "HN-ISV".to_string(),
+            None,
+            None,
+            rate,
+            tax_inclusive,
+            vec![],
+            identity::UserId::new(),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_tax_inclusive_rule_backs_tax_out_of_price() {
+        let mut item = create_test_item();
+
+        item.apply_tax_rule(&tax_rule(dec!(15), true), false);
+
+        // 200.00 already includes 15%: 173.91 + 26.09
+        assert_eq!(item.tax_amount().round_dp(2), dec!(26.09));
+        assert_eq!(item.taxable_amount().round_dp(2), dec!(173.91));
+        assert_eq!(item.total(), dec!(200.00));
+        assert_eq!(item.tax_jurisdiction(), Some("HN-ISV"));
+    }
+
+    #[test]
+    fn test_exempt_line_carries_no_tax() {
+        let mut item = create_test_item();
+
+        item.apply_tax_rule(&tax_rule(dec!(18), false), true);
+
+        assert!(item.tax_exempt());
+        assert_eq!(item.tax_rate(), dec!(0));
+        assert_eq!(item.tax_amount(), dec!(0));
+        assert_eq!(item.total(), dec!(200.00));
+    }
 }
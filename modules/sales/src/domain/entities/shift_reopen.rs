@@ -0,0 +1,79 @@
+//! ShiftReopen entity - audit record for a manager reopening a closed shift
+
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use uuid::{NoContext, Timestamp, Uuid};
+
+use crate::domain::value_objects::ShiftId;
+use identity::UserId;
+
+/// Records who reopened a shift, why, and the closing snapshot that was
+/// cleared so the original count is never lost.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ShiftReopen {
+    id: Uuid,
+    shift_id: ShiftId,
+    reopened_by_id: UserId,
+    reason: String,
+    previous_closed_at: DateTime<Utc>,
+    previous_closing_balance: Option<Decimal>,
+    previous_closing_notes: Option<String>,
+    reopened_at: DateTime<Utc>,
+}
+
+impl ShiftReopen {
+    /// Creates a new audit record for a reopen happening at `reopened_at`
+    pub fn create(
+        shift_id: ShiftId,
+        reopened_by_id: UserId,
+        reason: String,
+        previous_closed_at: DateTime<Utc>,
+        previous_closing_balance: Option<Decimal>,
+        previous_closing_notes: Option<String>,
+        reopened_at: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            id: Uuid::new_v7(Timestamp::now(NoContext)),
+            shift_id,
+            reopened_by_id,
+            reason,
+            previous_closed_at,
+            previous_closing_balance,
+            previous_closing_notes,
+            reopened_at,
+        }
+    }
+
+    pub fn id(&self) -> Uuid {
+        self.id
+    }
+
+    pub fn shift_id(&self) -> ShiftId {
+        self.shift_id
+    }
+
+    pub fn reopened_by_id(&self) -> UserId {
+        self.reopened_by_id
+    }
+
+    pub fn reason(&self) -> &str {
+        &self.reason
+    }
+
+    pub fn previous_closed_at(&self) -> DateTime<Utc> {
+        self.previous_closed_at
+    }
+
+    pub fn previous_closing_balance(&self) -> Option<Decimal> {
+        self.previous_closing_balance
+    }
+
+    pub fn previous_closing_notes(&self) -> Option<&str> {
+        self.previous_closing_notes.as_deref()
+    }
+
+    pub fn reopened_at(&self) -> DateTime<Utc> {
+        self.reopened_at
+    }
+}
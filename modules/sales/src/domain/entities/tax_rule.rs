@@ -0,0 +1,361 @@
+// TaxRule entity - the tax a store charges on a product, a category or
+// everything it sells
+
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::SalesError;
+use crate::domain::value_objects::{CustomerType, TaxRuleId};
+use identity::UserId;
+
+/// Longest jurisdiction label accepted, e.g. "HN-ISV" or "US-TX-AUSTIN"
+const MAX_JURISDICTION_LENGTH: usize = 50;
+
+/// Tax rate a store charges under a jurisdiction.
+///
+/// A rule targets at most one of:
+/// - a product
+/// - a category: every product of the category
+/// - neither: everything the store sells
+///
+/// When several apply to a line, the product rule wins over the category
+/// rule, which wins over the store-wide rule. Lines no rule applies to keep
+/// the rate they were rung up with.
+///
+/// With `tax_inclusive` the shelf price already contains the tax, which is
+/// backed out of the line rather than added on top. Customers whose type is
+/// listed in `exempt_customer_types` pay no tax on the lines the rule covers.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TaxRule {
+    id: TaxRuleId,
+    store_id: Uuid,
+    jurisdiction: String,
+    product_id: Option<Uuid>,
+    category_id: Option<Uuid>,
+    rate: Decimal,
+    tax_inclusive: bool,
+    exempt_customer_types: Vec<CustomerType>,
+    is_active: bool,
+    created_by_id: UserId,
+    created_at: DateTime<Utc>,
+    updated_at: DateTime<Utc>,
+}
+
+impl TaxRule {
+    /// Creates a new active tax rule
+    #[allow(clippy::too_many_arguments)]
+    pub fn create(
+        store_id: Uuid,
+        jurisdiction: String,
+        product_id: Option<Uuid>,
+        category_id: Option<Uuid>,
+        rate: Decimal,
+        tax_inclusive: bool,
+        exempt_customer_types: Vec<CustomerType>,
+        created_by_id: UserId,
+    ) -> Result<Self, SalesError> {
+        if product_id.is_some() && category_id.is_some() {
+            return Err(SalesError::InvalidTaxRule(
+                "at most one of product_id or category_id is allowed".to_string(),
+            ));
+        }
+        let jurisdiction = Self::validate(&jurisdiction, rate)?;
+        let now = Utc::now();
+        Ok(Self {
+            id: TaxRuleId::new(),
+            store_id,
+            jurisdiction,
+            product_id,
+            category_id,
+            rate,
+            tax_inclusive,
+            exempt_customer_types: dedup(exempt_customer_types),
+            is_active: true,
+            created_by_id,
+            created_at: now,
+            updated_at: now,
+        })
+    }
+
+    /// Reconstitutes a TaxRule from persistence
+    #[allow(clippy::too_many_arguments)]
+    pub fn reconstitute(
+        id: TaxRuleId,
+        store_id: Uuid,
+        jurisdiction: String,
+        product_id: Option<Uuid>,
+        category_id: Option<Uuid>,
+        rate: Decimal,
+        tax_inclusive: bool,
+        exempt_customer_types: Vec<CustomerType>,
+        is_active: bool,
+        created_by_id: UserId,
+        created_at: DateTime<Utc>,
+        updated_at: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            id,
+            store_id,
+            jurisdiction,
+            product_id,
+            category_id,
+            rate,
+            tax_inclusive,
+            exempt_customer_types,
+            is_active,
+            created_by_id,
+            created_at,
+            updated_at,
+        }
+    }
+
+    /// Replaces the rule's tax settings
+    pub fn reconfigure(
+        &mut self,
+        jurisdiction: String,
+        rate: Decimal,
+        tax_inclusive: bool,
+        exempt_customer_types: Vec<CustomerType>,
+        is_active: bool,
+    ) -> Result<(), SalesError> {
+        self.jurisdiction = Self::validate(&jurisdiction, rate)?;
+        self.rate = rate;
+        self.tax_inclusive = tax_inclusive;
+        self.exempt_customer_types = dedup(exempt_customer_types);
+        self.is_active = is_active;
+        self.updated_at = Utc::now();
+        Ok(())
+    }
+
+    fn validate(jurisdiction: &str, rate: Decimal) -> Result<String, SalesError> {
+        let jurisdiction = jurisdiction.trim();
+        if jurisdiction.is_empty() || jurisdiction.len() > MAX_JURISDICTION_LENGTH {
+            return Err(SalesError::InvalidTaxRule(format!(
+                "jurisdiction must be 1 to {} characters",
+                MAX_JURISDICTION_LENGTH
+            )));
+        }
+        if rate < Decimal::ZERO || rate > Decimal::ONE_HUNDRED {
+            return Err(SalesError::InvalidTaxRule(
+                "rate must be between 0 and 100".to_string(),
+            ));
+        }
+        Ok(jurisdiction.to_string())
+    }
+
+    /// Returns true if customers of `customer_type` pay no tax under this rule
+    pub fn exempts(&self, customer_type: Option<CustomerType>) -> bool {
+        customer_type.is_some_and(|t| self.exempt_customer_types.contains(&t))
+    }
+
+    /// Picks the active rule that taxes a product, preferring a product rule
+    /// over a category rule over a store-wide rule.
+    ///
+    /// `categories` are the product's category and its ancestors, nearest
+    /// first, so a subcategory's rule wins over its parent's. When several
+    /// rules cover the same target the oldest wins.
+    pub fn select<'a>(
+        rules: &'a [TaxRule],
+        product_id: Uuid,
+        categories: &[Uuid],
+    ) -> Option<&'a TaxRule> {
+        let oldest = |covers: &dyn Fn(&TaxRule) -> bool| {
+            rules
+                .iter()
+                .filter(|r| r.is_active && covers(r))
+                .min_by_key(|r| (r.created_at, r.id.into_uuid()))
+        };
+        oldest(&|r| r.product_id == Some(product_id))
+            .or_else(|| {
+                categories
+                    .iter()
+                    .find_map(|c| oldest(&|r| r.category_id == Some(*c)))
+            })
+            .or_else(|| oldest(&|r| r.is_store_wide()))
+    }
+
+    /// Returns true if the rule covers everything the store sells
+    pub fn is_store_wide(&self) -> bool {
+        self.product_id.is_none() && self.category_id.is_none()
+    }
+
+    // =========================================================================
+    // Getters
+    // =========================================================================
+    pub fn id(&self) -> TaxRuleId {
+        self.id
+    }
+    pub fn store_id(&self) -> Uuid {
+        self.store_id
+    }
+    pub fn jurisdiction(&self) -> &str {
+        &self.jurisdiction
+    }
+    pub fn product_id(&self) -> Option<Uuid> {
+        self.product_id
+    }
+    pub fn category_id(&self) -> Option<Uuid> {
+        self.category_id
+    }
+    pub fn rate(&self) -> Decimal {
+        self.rate
+    }
+    pub fn tax_inclusive(&self) -> bool {
+        self.tax_inclusive
+    }
+    pub fn exempt_customer_types(&self) -> &[CustomerType] {
+        &self.exempt_customer_types
+    }
+    pub fn is_active(&self) -> bool {
+        self.is_active
+    }
+    pub fn created_by_id(&self) -> UserId {
+        self.created_by_id
+    }
+    pub fn created_at(&self) -> DateTime<Utc> {
+        self.created_at
+    }
+    pub fn updated_at(&self) -> DateTime<Utc> {
+        self.updated_at
+    }
+}
+
+fn dedup(mut customer_types: Vec<CustomerType>) -> Vec<CustomerType> {
+    let mut seen = Vec::with_capacity(customer_types.len());
+    customer_types.retain(|t| {
+        let first = !seen.contains(t);
+        seen.push(*t);
+        first
+    });
+    customer_types
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    fn rule(product_id: Option<Uuid>, category_id: Option<Uuid>, rate: Decimal) -> TaxRule {
+        TaxRule::create(
+            Uuid::now_v7(),
+            "HN-ISV".to_string(),
+            product_id,
+            category_id,
+            rate,
+            false,
+            vec![],
+            UserId::new(),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_validation() {
+        let id = Some(Uuid::now_v7());
+        let create = |jurisdiction: &str, product, category, rate| {
+            TaxRule::create(
+                Uuid::now_v7(),
+                jurisdiction.to_string(),
+                product,
+                category,
+                rate,
+                false,
+                vec![],
+                UserId::new(),
+            )
+        };
+        assert!(create("HN-ISV", id, id, dec!(15)).is_err());
+        assert!(create("  ", None, None, dec!(15)).is_err());
+        assert!(create("HN-ISV", None, None, dec!(-1)).is_err());
+        assert!(create("HN-ISV", None, None, dec!(100.5)).is_err());
+        assert!(create("HN-ISV", None, None, dec!(0)).is_ok());
+
+        let rule = create(" HN-ISV ", None, id, dec!(18)).unwrap();
+        assert_eq!(rule.jurisdiction(), "HN-ISV");
+    }
+
+    #[test]
+    fn test_select_prefers_product_then_category_then_store() {
+        let product_id = Uuid::now_v7();
+        let category_id = Uuid::now_v7();
+        let rules = vec![
+            rule(None, None, dec!(15)),
+            rule(None, Some(category_id), dec!(18)),
+            rule(Some(product_id), None, dec!(0)),
+        ];
+
+        let selected = TaxRule::select(&rules, product_id, &[category_id]).unwrap();
+        assert_eq!(selected.rate(), dec!(0));
+
+        let selected = TaxRule::select(&rules, Uuid::now_v7(), &[category_id]).unwrap();
+        assert_eq!(selected.rate(), dec!(18));
+
+        let selected = TaxRule::select(&rules, Uuid::now_v7(), &[]).unwrap();
+        assert_eq!(selected.rate(), dec!(15));
+    }
+
+    #[test]
+    fn test_select_inherits_nearest_category_rule() {
+        let (child, parent, root) = (Uuid::now_v7(), Uuid::now_v7(), Uuid::now_v7());
+        let rules = vec![
+            rule(None, None, dec!(15)),
+            rule(None, Some(root), dec!(12)),
+            rule(None, Some(parent), dec!(18)),
+        ];
+
+        let selected = TaxRule::select(&rules, Uuid::now_v7(), &[child, parent, root]).unwrap();
+        assert_eq!(selected.rate(), dec!(18));
+
+        let selected = TaxRule::select(&rules, Uuid::now_v7(), &[parent, root]).unwrap();
+        assert_eq!(selected.rate(), dec!(18));
+    }
+
+    #[test]
+    fn test_select_picks_oldest_of_matching_store_rules() {
+        let older = rule(None, None, dec!(15));
+        let mut newer = rule(None, None, dec!(18));
+        newer.created_at = older.created_at + chrono::Duration::seconds(1);
+
+        for rules in [
+            vec![older.clone(), newer.clone()],
+            vec![newer.clone(), older.clone()],
+        ] {
+            let selected = TaxRule::select(&rules, Uuid::now_v7(), &[]).unwrap();
+            assert_eq!(selected.id(), older.id());
+        }
+    }
+
+    #[test]
+    fn test_select_ignores_inactive_rules() {
+        let product_id = Uuid::now_v7();
+        let mut rules = vec![rule(Some(product_id), None, dec!(15))];
+
+        rules[0]
+            .reconfigure("HN-ISV".to_string(), dec!(15), false, vec![], false)
+            .unwrap();
+
+        assert!(TaxRule::select(&rules, product_id, &[]).is_none());
+    }
+
+    #[test]
+    fn test_exempts_listed_customer_types() {
+        let mut rule = rule(None, None, dec!(15));
+        assert!(!rule.exempts(Some(CustomerType::Business)));
+
+        rule.reconfigure(
+            "HN-ISV".to_string(),
+            dec!(15),
+            false,
+            vec![CustomerType::Business, CustomerType::Business],
+            true,
+        )
+        .unwrap();
+
+        assert_eq!(rule.exempt_customer_types(), [CustomerType::Business]);
+        assert!(rule.exempts(Some(CustomerType::Business)));
+        assert!(!rule.exempts(Some(CustomerType::Individual)));
+        assert!(!rule.exempts(None));
+    }
+}
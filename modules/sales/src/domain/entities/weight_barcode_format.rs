@@ -0,0 +1,287 @@
+// WeightBarcodeFormat entity - a store's layout for scale-printed EAN-13 labels
+
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::SalesError;
+use crate::domain::value_objects::ScaleValueKind;
+use identity::UserId;
+use inventory::{EAN13_LENGTH, ean13_check_digit};
+
+/// Longest prefix a scale label may be recognised by
+pub const MAX_WEIGHT_BARCODE_PREFIX_LEN: usize = 3;
+
+/// Most digits a scale may embed for the weight or price
+pub const MAX_WEIGHT_BARCODE_VALUE_LEN: u8 = 6;
+
+/// Largest divisor accepted for the embedded value
+pub const MAX_WEIGHT_BARCODE_DIVISOR: u32 = 1_000_000;
+
+/// Layout of the EAN-13 labels a store's deli or produce scales print.
+///
+/// A label starts with `prefix`, followed by the item code up to
+/// `value_start` (a zero-based digit position), then `value_length` digits
+/// holding the weight or price, and ends with the EAN-13 check digit. The
+/// embedded value is divided by `divisor`, so grams print as kilograms with
+/// a divisor of 1000 and cents as currency units with 100.
+///
+/// The weighed product is found by its item barcode: the label with every
+/// digit from `value_start` on zeroed and the check digit recomputed.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WeightBarcodeFormat {
+    id: Uuid,
+    store_id: Uuid,
+    prefix: String,
+    value_start: u8,
+    value_length: u8,
+    value_kind: ScaleValueKind,
+    divisor: u32,
+    created_by_id: UserId,
+    created_at: DateTime<Utc>,
+}
+
+/// What a scale label decodes to
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ScaleLabel {
+    /// Barcode of the weighed product, with the embedded value zeroed
+    pub item_barcode: String,
+    /// Embedded weight or price, already divided by the format's divisor
+    pub value: Decimal,
+    pub kind: ScaleValueKind,
+}
+
+impl WeightBarcodeFormat {
+    /// Creates a validated format
+    pub fn create(
+        store_id: Uuid,
+        prefix: impl Into<String>,
+        value_start: u8,
+        value_length: u8,
+        value_kind: ScaleValueKind,
+        divisor: u32,
+        created_by_id: UserId,
+    ) -> Result<Self, SalesError> {
+        let prefix = prefix.into().trim().to_string();
+        if prefix.is_empty()
+            || prefix.len() > MAX_WEIGHT_BARCODE_PREFIX_LEN
+            || !prefix.bytes().all(|b| b.is_ascii_digit())
+        {
+            return Err(SalesError::InvalidWeightBarcodeFormat(format!(
+                "prefix must be 1 to {} digits",
+                MAX_WEIGHT_BARCODE_PREFIX_LEN
+            )));
+        }
+        if (value_start as usize) <= prefix.len() {
+            return Err(SalesError::InvalidWeightBarcodeFormat(
+                "value_start must leave at least one item code digit after the prefix".to_string(),
+            ));
+        }
+        if value_length == 0 || value_length > MAX_WEIGHT_BARCODE_VALUE_LEN {
+            return Err(SalesError::InvalidWeightBarcodeFormat(format!(
+                "value_length must be between 1 and {}",
+                MAX_WEIGHT_BARCODE_VALUE_LEN
+            )));
+        }
+        if value_start as usize + value_length as usize > EAN13_LENGTH - 1 {
+            return Err(SalesError::InvalidWeightBarcodeFormat(
+                "embedded value must end before the check digit".to_string(),
+            ));
+        }
+        if divisor == 0 || divisor > MAX_WEIGHT_BARCODE_DIVISOR {
+            return Err(SalesError::InvalidWeightBarcodeFormat(format!(
+                "divisor must be between 1 and {}",
+                MAX_WEIGHT_BARCODE_DIVISOR
+            )));
+        }
+
+        Ok(Self {
+            id: Uuid::now_v7(),
+            store_id,
+            prefix,
+            value_start,
+            value_length,
+            value_kind,
+            divisor,
+            created_by_id,
+            created_at: Utc::now(),
+        })
+    }
+
+    /// Reconstitutes a WeightBarcodeFormat from persistence
+    #[allow(clippy::too_many_arguments)]
+    pub fn reconstitute(
+        id: Uuid,
+        store_id: Uuid,
+        prefix: String,
+        value_start: u8,
+        value_length: u8,
+        value_kind: ScaleValueKind,
+        divisor: u32,
+        created_by_id: UserId,
+        created_at: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            id,
+            store_id,
+            prefix,
+            value_start,
+            value_length,
+            value_kind,
+            divisor,
+            created_by_id,
+            created_at,
+        }
+    }
+
+    /// Decodes a scanned code printed in this format.
+    ///
+    /// Returns `None` for codes that aren't 13 digits, don't start with the
+    /// prefix or carry a wrong check digit.
+    pub fn parse(&self, code: &str) -> Option<ScaleLabel> {
+        if code.len() != EAN13_LENGTH
+            || !code.bytes().all(|b| b.is_ascii_digit())
+            || !code.starts_with(&self.prefix)
+        {
+            return None;
+        }
+        let check = code.as_bytes()[EAN13_LENGTH - 1] - b'0';
+        if ean13_check_digit(&code[..EAN13_LENGTH - 1]) != check {
+            return None;
+        }
+
+        let start = self.value_start as usize;
+        let end = start + self.value_length as usize;
+        let raw: i64 = code[start..end].parse().ok()?;
+        let value = Decimal::from(raw) / Decimal::from(self.divisor);
+
+        let body = format!("{}{}", &code[..start], "0".repeat(EAN13_LENGTH - 1 - start));
+        let item_barcode = format!("{}{}", body, ean13_check_digit(&body));
+
+        Some(ScaleLabel {
+            item_barcode,
+            value,
+            kind: self.value_kind,
+        })
+    }
+
+    /// Whether labels of this format could be mistaken for ones under
+    /// `prefix`, because one prefix starts the other
+    pub fn overlaps(&self, prefix: &str) -> bool {
+        self.prefix.starts_with(prefix) || prefix.starts_with(&self.prefix)
+    }
+
+    // =========================================================================
+    // Getters
+    // =========================================================================
+    pub fn id(&self) -> Uuid {
+        self.id
+    }
+    pub fn store_id(&self) -> Uuid {
+        self.store_id
+    }
+    pub fn prefix(&self) -> &str {
+        &self.prefix
+    }
+    pub fn value_start(&self) -> u8 {
+        self.value_start
+    }
+    pub fn value_length(&self) -> u8 {
+        self.value_length
+    }
+    pub fn value_kind(&self) -> ScaleValueKind {
+        self.value_kind
+    }
+    pub fn divisor(&self) -> u32 {
+        self.divisor
+    }
+    pub fn created_by_id(&self) -> UserId {
+        self.created_by_id
+    }
+    pub fn created_at(&self) -> DateTime<Utc> {
+        self.created_at
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    fn format(prefix: &str, kind: ScaleValueKind, divisor: u32) -> WeightBarcodeFormat {
+        WeightBarcodeFormat::create(Uuid::now_v7(), prefix, 7, 5, kind, divisor, UserId::new())
+            .unwrap()
+    }
+
+    fn with_check_digit(body: &str) -> String {
+        format!("{}{}", body, ean13_check_digit(body))
+    }
+
+    #[test]
+    fn test_parses_weight_label() {
+        let format = format("20", ScaleValueKind::Weight, 1000);
+        // Item 12345, 1.250 kg
+        let label = format.parse(&with_check_digit("201234501250")).unwrap();
+
+        assert_eq!(label.value, dec!(1.25));
+        assert_eq!(label.kind, ScaleValueKind::Weight);
+        assert_eq!(label.item_barcode, with_check_digit("201234500000"));
+    }
+
+    #[test]
+    fn test_parses_price_label() {
+        let format = format("21", ScaleValueKind::Price, 100);
+        let label = format.parse(&with_check_digit("210004200899")).unwrap();
+
+        assert_eq!(label.value, dec!(8.99));
+        assert_eq!(label.kind, ScaleValueKind::Price);
+    }
+
+    #[test]
+    fn test_rejects_other_codes() {
+        let format = format("20", ScaleValueKind::Weight, 1000);
+        let label = with_check_digit("201234501250");
+        let mut bad_check = label.clone();
+        bad_check.replace_range(12.., if label.ends_with('0') { "1" } else { "0" });
+
+        assert!(format.parse(&bad_check).is_none());
+        assert!(format.parse(&with_check_digit("211234501250")).is_none());
+        assert!(format.parse("2012345").is_none());
+    }
+
+    #[test]
+    fn test_validates_layout() {
+        let create = |prefix: &str, start, length, divisor| {
+            WeightBarcodeFormat::create(
+                Uuid::now_v7(),
+                prefix,
+                start,
+                length,
+                ScaleValueKind::Weight,
+                divisor,
+                UserId::new(),
+            )
+        };
+        assert!(create("2", 7, 5, 1000).is_ok());
+        assert!(create("", 7, 5, 1000).is_err());
+        assert!(create("2a", 7, 5, 1000).is_err());
+        assert!(create("2000", 7, 5, 1000).is_err());
+        // No item code digits left
+        assert!(create("20", 2, 5, 1000).is_err());
+        // Runs into the check digit
+        assert!(create("20", 8, 5, 1000).is_err());
+        assert!(create("20", 7, 0, 1000).is_err());
+        assert!(create("20", 7, 5, 0).is_err());
+        assert!(create("20", 255, 6, 1000).is_err());
+    }
+
+    #[test]
+    fn test_overlapping_prefixes() {
+        let format = format("20", ScaleValueKind::Weight, 1000);
+        assert!(format.overlaps("2"));
+        assert!(format.overlaps("20"));
+        assert!(format.overlaps("201"));
+        assert!(!format.overlaps("21"));
+    }
+}
@@ -55,6 +55,18 @@ pub trait CartRepository: Send + Sync {
     /// Deletes expired carts
     async fn delete_expired(&self, before: DateTime<Utc>) -> Result<i64, SalesError>;
 
+    /// Finds unconverted carts (with items) that have expired or whose last
+    /// activity is older than the cutoff for their kind: guest carts (no
+    /// customer) use `guest_inactive_before`, customer carts use
+    /// `customer_inactive_before`. Oldest activity first.
+    async fn find_stale(
+        &self,
+        guest_inactive_before: DateTime<Utc>,
+        customer_inactive_before: DateTime<Utc>,
+        now: DateTime<Utc>,
+        limit: i64,
+    ) -> Result<Vec<Cart>, SalesError>;
+
     // -------------------------------------------------------------------------
     // Cart Item operations
     // -------------------------------------------------------------------------
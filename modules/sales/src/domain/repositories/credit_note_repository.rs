@@ -4,16 +4,28 @@ use async_trait::async_trait;
 
 use crate::SalesError;
 use crate::domain::entities::{CreditNote, CreditNoteItem};
-use crate::domain::value_objects::{CreditNoteId, CreditNoteItemId, CreditNoteStatus, SaleId};
+use rust_decimal::Decimal;
+
+use crate::domain::value_objects::{
+    CreditNoteDocument, CreditNoteId, CreditNoteItemId, CreditNoteStatus, SaleId, SaleItemId,
+};
 use identity::StoreId;
 
 /// Filter for querying credit notes
 #[derive(Debug, Clone, Default)]
 pub struct CreditNoteFilter {
     pub store_id: Option<StoreId>,
+    /// Restrict to these stores (the caller's assigned stores); `None`
+    /// leaves the store unrestricted
+    pub store_ids: Option<Vec<StoreId>>,
     pub original_sale_id: Option<SaleId>,
+    /// Only returns without (`true`) or with (`false`) a receipt
+    pub receiptless: Option<bool>,
     pub status: Option<CreditNoteStatus>,
     pub search: Option<String>,
+    /// Keyset cursor: only credit notes with a smaller id, newest id first.
+    /// The page offset is ignored when set.
+    pub after_id: Option<CreditNoteId>,
 }
 
 /// Repository trait for CreditNote persistence
@@ -79,4 +91,32 @@ pub trait CreditNoteRepository: Send + Sync {
         &self,
         item_id: CreditNoteItemId,
     ) -> Result<Option<CreditNoteItem>, SalesError>;
+
+    /// Quantities already returned per original sale item across the sale's
+    /// credit notes, excluding cancelled ones
+    async fn find_returned_quantities(
+        &self,
+        sale_id: SaleId,
+    ) -> Result<Vec<(SaleItemId, Decimal)>, SalesError>;
+
+    // -------------------------------------------------------------------------
+    // Document operations
+    // -------------------------------------------------------------------------
+
+    /// Finds the document issued for a credit note
+    async fn find_document(
+        &self,
+        credit_note_id: CreditNoteId,
+    ) -> Result<Option<CreditNoteDocument>, SalesError>;
+
+    /// Issues a credit note's document under the next number of its store's
+    /// credit-note sequence and returns it.
+    ///
+    /// The sequence stays locked until the document is saved, so numbers are
+    /// unique and gapless per store. If the credit note already has a
+    /// document, that one is returned and no number is drawn.
+    async fn issue_document(
+        &self,
+        document: CreditNoteDocument,
+    ) -> Result<CreditNoteDocument, SalesError>;
 }
@@ -1,10 +1,12 @@
 //! Customer repository trait
 
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
 
 use crate::SalesError;
-use crate::domain::entities::Customer;
-use crate::domain::value_objects::CustomerId;
+use crate::domain::entities::{Customer, CustomerAccountEntry};
+use crate::domain::value_objects::{CustomerCodeFormat, CustomerId};
 use identity::StoreId;
 
 /// Filter for querying customers
@@ -13,6 +15,9 @@ pub struct CustomerFilter {
     pub store_id: Option<StoreId>,
     pub is_active: Option<bool>,
     pub search: Option<String>,
+    /// Keyset cursor: only customers sorted after this one by name. The
+    /// page offset is ignored when set.
+    pub after_id: Option<CustomerId>,
 }
 
 /// Repository trait for Customer persistence
@@ -49,6 +54,70 @@ pub trait CustomerRepository: Send + Sync {
         page_size: i64,
     ) -> Result<(Vec<Customer>, i64), SalesError>;
 
-    /// Generates a unique customer code for a store
+    /// Issues the next customer code for a store.
+    ///
+    /// The store's sequence is incremented atomically, so concurrent creates
+    /// never receive the same code.
     async fn generate_customer_code(&self, store_id: StoreId) -> Result<String, SalesError>;
+
+    /// Returns the customer code format configured for a store (or the default)
+    async fn find_customer_code_format(
+        &self,
+        store_id: StoreId,
+    ) -> Result<CustomerCodeFormat, SalesError>;
+
+    /// Sets the customer code format for a store; the sequence value is kept
+    async fn save_customer_code_format(
+        &self,
+        store_id: StoreId,
+        format: &CustomerCodeFormat,
+    ) -> Result<(), SalesError>;
+
+    /// Posts an entry to the customer's account and updates the balance.
+    ///
+    /// The balance update and the entry insert happen atomically, and
+    /// charges only go through if the new balance stays within the credit
+    /// limit, so concurrent charges can't exceed it. On success the entry
+    /// is marked posted with the resulting balance.
+    ///
+    /// # Errors
+    /// * `SalesError::CreditLimitExceeded` - The charge would exceed the limit
+    /// * `SalesError::CustomerNotFound` - The customer doesn't exist
+    async fn post_account_entry(&self, entry: &mut CustomerAccountEntry) -> Result<(), SalesError>;
+
+    /// Finds a customer's account entries posted in `[from, to)`, in posting
+    /// order
+    async fn find_account_entries(
+        &self,
+        customer_id: CustomerId,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+    ) -> Result<Vec<CustomerAccountEntry>, SalesError>;
+
+    /// Returns the customer's account balance just before `at`
+    async fn find_account_balance_at(
+        &self,
+        customer_id: CustomerId,
+        at: DateTime<Utc>,
+    ) -> Result<Decimal, SalesError>;
+
+    /// Returns the customer's store credit balance
+    async fn find_store_credit_balance(
+        &self,
+        customer_id: CustomerId,
+    ) -> Result<Decimal, SalesError>;
+
+    /// Adds `amount` to the customer's store credit (negative to spend it)
+    /// and returns the new balance.
+    ///
+    /// Spending only goes through if the balance covers it, checked
+    /// atomically, so concurrent payments can't overdraw it.
+    ///
+    /// # Errors
+    /// * `SalesError::InsufficientStoreCredit` - The balance doesn't cover the spend
+    async fn adjust_store_credit(
+        &self,
+        customer_id: CustomerId,
+        amount: Decimal,
+    ) -> Result<Decimal, SalesError>;
 }
@@ -0,0 +1,31 @@
+//! ExchangeRate repository trait
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use crate::SalesError;
+use crate::domain::entities::ExchangeRate;
+use inventory::Currency;
+
+/// Repository trait for ExchangeRate persistence
+#[async_trait]
+pub trait ExchangeRateRepository: Send + Sync {
+    /// Saves a new exchange rate
+    async fn save(&self, rate: &ExchangeRate) -> Result<(), SalesError>;
+
+    /// Finds the store's rate for the currency in effect at the given time
+    async fn find_effective(
+        &self,
+        store_id: Uuid,
+        currency: &Currency,
+        at: DateTime<Utc>,
+    ) -> Result<Option<ExchangeRate>, SalesError>;
+
+    /// Lists the store's rates, newest first, optionally for one currency
+    async fn list(
+        &self,
+        store_id: Uuid,
+        currency: Option<&Currency>,
+    ) -> Result<Vec<ExchangeRate>, SalesError>;
+}
@@ -0,0 +1,31 @@
+//! GiftCard repository trait
+
+use async_trait::async_trait;
+use rust_decimal::Decimal;
+
+use crate::SalesError;
+use crate::domain::entities::GiftCard;
+use crate::domain::value_objects::{GiftCardId, SaleId};
+
+/// Repository trait for GiftCard persistence
+#[async_trait]
+pub trait GiftCardRepository: Send + Sync {
+    /// Saves a new gift card
+    async fn save(&self, card: &GiftCard) -> Result<(), SalesError>;
+
+    /// Updates an existing gift card
+    async fn update(&self, card: &GiftCard) -> Result<(), SalesError>;
+
+    /// Finds a gift card by its (normalized) code
+    async fn find_by_code(&self, code: &str) -> Result<Option<GiftCard>, SalesError>;
+
+    /// Finds the gift cards sold on a sale
+    async fn find_by_sale(&self, sale_id: SaleId) -> Result<Vec<GiftCard>, SalesError>;
+
+    /// Spends `amount` from a card and returns the card as left.
+    ///
+    /// The card's row is locked while its balance is checked and updated, so
+    /// concurrent redemptions of the same card are applied one after the
+    /// other and can't spend more than the balance between them.
+    async fn redeem(&self, id: GiftCardId, amount: Decimal) -> Result<GiftCard, SalesError>;
+}
@@ -0,0 +1,20 @@
+//! Layaway repository trait
+
+use async_trait::async_trait;
+
+use crate::SalesError;
+use crate::domain::entities::Layaway;
+use crate::domain::value_objects::SaleId;
+
+/// Repository trait for Layaway persistence
+#[async_trait]
+pub trait LayawayRepository: Send + Sync {
+    /// Saves a new layaway with its schedule
+    async fn save(&self, layaway: &Layaway) -> Result<(), SalesError>;
+
+    /// Updates an existing layaway, replacing its schedule
+    async fn update(&self, layaway: &Layaway) -> Result<(), SalesError>;
+
+    /// Finds the layaway of a sale
+    async fn find_by_sale(&self, sale_id: SaleId) -> Result<Option<Layaway>, SalesError>;
+}
@@ -0,0 +1,112 @@
+// MarkdownRepository trait - repository for markdown rules, the markdown log,
+// and the candidate lookups that drive automatic markdowns
+
+use async_trait::async_trait;
+use chrono::{DateTime, NaiveDate, Utc};
+use uuid::Uuid;
+
+use crate::SalesError;
+use crate::domain::entities::{Markdown, MarkdownRule};
+use crate::domain::value_objects::{MarkdownId, MarkdownRuleId};
+
+/// Filter options for listing markdowns
+#[derive(Debug, Clone, Default)]
+pub struct MarkdownFilter {
+    /// Filter by store ID
+    pub store_id: Option<Uuid>,
+    /// Filter by product ID
+    pub product_id: Option<Uuid>,
+    /// Filter by status (active, expired, reversed)
+    pub status: Option<String>,
+    /// Keyset cursor: only markdowns with a smaller id, newest id first.
+    /// The page offset is ignored when set.
+    pub after_id: Option<Uuid>,
+}
+
+/// Repository trait for markdown persistence operations.
+#[async_trait]
+pub trait MarkdownRepository: Send + Sync {
+    // -------------------------------------------------------------------------
+    // Rules
+    // -------------------------------------------------------------------------
+
+    /// Saves a new markdown rule
+    async fn save_rule(&self, rule: &MarkdownRule) -> Result<(), SalesError>;
+
+    /// Updates an existing markdown rule
+    async fn update_rule(&self, rule: &MarkdownRule) -> Result<(), SalesError>;
+
+    /// Finds the rule for a store/category pair
+    async fn find_rule(
+        &self,
+        store_id: Uuid,
+        category_id: Uuid,
+    ) -> Result<Option<MarkdownRule>, SalesError>;
+
+    /// Finds a rule by ID
+    async fn find_rule_by_id(&self, id: MarkdownRuleId)
+    -> Result<Option<MarkdownRule>, SalesError>;
+
+    /// Lists rules, optionally for a single store
+    async fn list_rules(&self, store_id: Option<Uuid>) -> Result<Vec<MarkdownRule>, SalesError>;
+
+    /// Lists every active rule across stores
+    async fn find_active_rules(&self) -> Result<Vec<MarkdownRule>, SalesError>;
+
+    // -------------------------------------------------------------------------
+    // Markdown log
+    // -------------------------------------------------------------------------
+
+    /// Saves a new markdown
+    async fn save(&self, markdown: &Markdown) -> Result<(), SalesError>;
+
+    /// Updates an existing markdown (reversal)
+    async fn update(&self, markdown: &Markdown) -> Result<(), SalesError>;
+
+    /// Finds a markdown by ID
+    async fn find_by_id(&self, id: MarkdownId) -> Result<Option<Markdown>, SalesError>;
+
+    /// Returns true if the product already has an active markdown at the store
+    async fn has_active_for_product(
+        &self,
+        store_id: Uuid,
+        product_id: Uuid,
+    ) -> Result<bool, SalesError>;
+
+    /// Marks active markdowns whose window ended before `now` as expired.
+    /// Returns the number of rows updated.
+    async fn expire_ended(&self, now: DateTime<Utc>) -> Result<u64, SalesError>;
+
+    /// Finds markdowns with pagination
+    async fn find_paginated(
+        &self,
+        filter: MarkdownFilter,
+        page: i64,
+        page_size: i64,
+    ) -> Result<(Vec<Markdown>, i64), SalesError>;
+
+    // -------------------------------------------------------------------------
+    // Candidate lookups
+    // -------------------------------------------------------------------------
+
+    /// Products in the category with stock on hand at the store and a
+    /// confirmed received lot expiring between `from` and `to` (inclusive).
+    /// Returns (product_id, earliest expiry date).
+    async fn find_expiring_products(
+        &self,
+        store_id: Uuid,
+        category_id: Uuid,
+        from: NaiveDate,
+        to: NaiveDate,
+    ) -> Result<Vec<(Uuid, NaiveDate)>, SalesError>;
+
+    /// Products in the category with stock on hand at the store since before
+    /// `idle_since` and no sale at the store after it.
+    /// Returns (product_id, last sale timestamp if any).
+    async fn find_idle_products(
+        &self,
+        store_id: Uuid,
+        category_id: Uuid,
+        idle_since: DateTime<Utc>,
+    ) -> Result<Vec<(Uuid, Option<DateTime<Utc>>)>, SalesError>;
+}
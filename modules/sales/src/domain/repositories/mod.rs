@@ -6,13 +6,35 @@
 mod cart_repository;
 mod credit_note_repository;
 mod customer_repository;
+mod exchange_rate_repository;
+mod gift_card_repository;
+mod layaway_repository;
+mod markdown_repository;
+mod order_pickup_repository;
+mod price_list_repository;
+mod price_tier_repository;
 mod promotion_repository;
+mod quick_sale_repository;
+mod sale_idempotency_repository;
 mod sale_repository;
 mod shift_repository;
+mod tax_rule_repository;
+mod weight_barcode_format_repository;
 
 pub use cart_repository::{CartFilter, CartRepository};
 pub use credit_note_repository::{CreditNoteFilter, CreditNoteRepository};
 pub use customer_repository::{CustomerFilter, CustomerRepository};
+pub use exchange_rate_repository::ExchangeRateRepository;
+pub use gift_card_repository::GiftCardRepository;
+pub use layaway_repository::LayawayRepository;
+pub use markdown_repository::{MarkdownFilter, MarkdownRepository};
+pub use order_pickup_repository::OrderPickupRepository;
+pub use price_list_repository::PriceListRepository;
+pub use price_tier_repository::PriceTierRepository;
 pub use promotion_repository::{PromotionFilter, PromotionRepository};
+pub use quick_sale_repository::{QuickSale, QuickSaleRepository, StockDeduction};
+pub use sale_idempotency_repository::SaleIdempotencyRepository;
 pub use sale_repository::{SaleFilter, SaleRepository};
 pub use shift_repository::{ShiftFilter, ShiftRepository};
+pub use tax_rule_repository::TaxRuleRepository;
+pub use weight_barcode_format_repository::WeightBarcodeFormatRepository;
@@ -0,0 +1,28 @@
+//! OrderPickup repository trait
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+
+use crate::SalesError;
+use crate::domain::entities::OrderPickup;
+use crate::domain::value_objects::SaleId;
+
+/// Repository trait for OrderPickup persistence
+#[async_trait]
+pub trait OrderPickupRepository: Send + Sync {
+    /// Saves a new pickup
+    async fn save(&self, pickup: &OrderPickup) -> Result<(), SalesError>;
+
+    /// Updates an existing pickup
+    async fn update(&self, pickup: &OrderPickup) -> Result<(), SalesError>;
+
+    /// Finds the pickup for an order
+    async fn find_by_sale(&self, sale_id: SaleId) -> Result<Option<OrderPickup>, SalesError>;
+
+    /// Finds waiting pickups whose hold ended before `now`, oldest first
+    async fn find_overdue(
+        &self,
+        now: DateTime<Utc>,
+        limit: i64,
+    ) -> Result<Vec<OrderPickup>, SalesError>;
+}
@@ -0,0 +1,53 @@
+//! PriceList repository trait
+
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::SalesError;
+use crate::domain::entities::{PriceList, PriceListEntry};
+use crate::domain::value_objects::PriceListId;
+
+/// Repository trait for PriceList and PriceListEntry persistence
+#[async_trait]
+pub trait PriceListRepository: Send + Sync {
+    /// Saves a new price list
+    async fn save(&self, list: &PriceList) -> Result<(), SalesError>;
+
+    /// Finds a price list by ID
+    async fn find_by_id(&self, id: PriceListId) -> Result<Option<PriceList>, SalesError>;
+
+    /// Lists price lists, optionally for a single store
+    async fn list(&self, store_id: Option<Uuid>) -> Result<Vec<PriceList>, SalesError>;
+
+    /// Finds the active price lists of a store
+    async fn find_active_by_store(&self, store_id: Uuid) -> Result<Vec<PriceList>, SalesError>;
+
+    /// Saves a new price list entry
+    async fn save_entry(&self, entry: &PriceListEntry) -> Result<(), SalesError>;
+
+    /// Updates an existing price list entry
+    async fn update_entry(&self, entry: &PriceListEntry) -> Result<(), SalesError>;
+
+    /// Finds the entry of a list for a product, or for one of its variants
+    async fn find_entry(
+        &self,
+        price_list_id: PriceListId,
+        product_id: Uuid,
+        variant_id: Option<Uuid>,
+    ) -> Result<Option<PriceListEntry>, SalesError>;
+
+    /// Lists the entries of a price list
+    async fn list_entries(
+        &self,
+        price_list_id: PriceListId,
+    ) -> Result<Vec<PriceListEntry>, SalesError>;
+
+    /// Finds the entries that could price a product line on a list: its
+    /// product entry and the entry for its variant
+    async fn find_applicable_entries(
+        &self,
+        price_list_id: PriceListId,
+        product_id: Uuid,
+        variant_id: Option<Uuid>,
+    ) -> Result<Vec<PriceListEntry>, SalesError>;
+}
@@ -0,0 +1,44 @@
+//! PriceTier repository trait
+
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::SalesError;
+use crate::domain::entities::PriceTier;
+use crate::domain::value_objects::{CustomerType, PriceTierId};
+
+/// Repository trait for PriceTier persistence
+#[async_trait]
+pub trait PriceTierRepository: Send + Sync {
+    /// Saves a new price tier
+    async fn save(&self, tier: &PriceTier) -> Result<(), SalesError>;
+
+    /// Updates an existing price tier
+    async fn update(&self, tier: &PriceTier) -> Result<(), SalesError>;
+
+    /// Finds a price tier by ID
+    async fn find_by_id(&self, id: PriceTierId) -> Result<Option<PriceTier>, SalesError>;
+
+    /// Finds the tier for a store and customer type targeting the given
+    /// product or category
+    async fn find_by_target(
+        &self,
+        store_id: Uuid,
+        customer_type: CustomerType,
+        product_id: Option<Uuid>,
+        category_id: Option<Uuid>,
+    ) -> Result<Option<PriceTier>, SalesError>;
+
+    /// Lists tiers, optionally for a single store
+    async fn list(&self, store_id: Option<Uuid>) -> Result<Vec<PriceTier>, SalesError>;
+
+    /// Finds the active tiers that could price a product for a customer
+    /// type at a store: its product tier and its category's tier
+    async fn find_applicable(
+        &self,
+        store_id: Uuid,
+        customer_type: CustomerType,
+        product_id: Uuid,
+        category_id: Option<Uuid>,
+    ) -> Result<Vec<PriceTier>, SalesError>;
+}
@@ -15,6 +15,9 @@ pub struct PromotionFilter {
     pub store_id: Option<uuid::Uuid>,
     /// Search by code or name
     pub search: Option<String>,
+    /// Keyset cursor: only promotions with a smaller id, newest id first.
+    /// The page offset is ignored when set.
+    pub after_id: Option<uuid::Uuid>,
 }
 
 /// Repository trait for Promotion persistence operations.
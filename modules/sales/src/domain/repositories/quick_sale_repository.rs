@@ -0,0 +1,37 @@
+//! QuickSale repository trait
+
+use async_trait::async_trait;
+
+use crate::SalesError;
+use crate::domain::entities::{CashierShift, Sale};
+use inventory::{InventoryMovement, InventoryStock};
+
+/// Stock deducted by a quick sale and the movement recording it
+#[derive(Debug, Clone)]
+pub struct StockDeduction {
+    pub stock: InventoryStock,
+    /// Version the stock was read at; the update fails if it moved since
+    pub expected_version: i32,
+    pub movement: InventoryMovement,
+}
+
+/// Everything a single-item quick sale writes
+#[derive(Debug, Clone)]
+pub struct QuickSale {
+    /// The completed sale with its item and cash payment
+    pub sale: Sale,
+    pub shift: CashierShift,
+    /// In the order they apply; empty when the product has no stock
+    /// record at the store. A composite product draws one per ingredient.
+    pub stock: Vec<StockDeduction>,
+}
+
+/// Repository trait for persisting a quick sale
+#[async_trait]
+pub trait QuickSaleRepository: Send + Sync {
+    /// Numbers the sale from its terminal's active CAI range, saves the
+    /// sale, item and payment, updates the shift totals and deducts the
+    /// stock in a single transaction. The CAI and invoice number drawn are
+    /// set on `quick_sale.sale`.
+    async fn commit(&self, quick_sale: &mut QuickSale) -> Result<(), SalesError>;
+}
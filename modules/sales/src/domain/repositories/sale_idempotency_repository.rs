@@ -0,0 +1,23 @@
+//! SaleIdempotencyKey repository trait
+
+use async_trait::async_trait;
+use pos_core::TerminalId;
+
+use crate::SalesError;
+use crate::domain::entities::SaleIdempotencyKey;
+
+/// Repository trait for the idempotency keys terminals send with sale
+/// requests
+#[async_trait]
+pub trait SaleIdempotencyRepository: Send + Sync {
+    /// Saves a key, replacing an expired one the terminal sent before.
+    /// Fails with `IdempotencyKeyReused` if the key is still remembered.
+    async fn save(&self, record: &SaleIdempotencyKey) -> Result<(), SalesError>;
+
+    /// Finds the terminal's key unless it has expired
+    async fn find_active(
+        &self,
+        terminal_id: TerminalId,
+        key: &str,
+    ) -> Result<Option<SaleIdempotencyKey>, SalesError>;
+}
@@ -14,12 +14,18 @@ use pos_core::TerminalId;
 #[derive(Debug, Clone, Default)]
 pub struct SaleFilter {
     pub store_id: Option<StoreId>,
+    /// Restrict to these stores (the caller's assigned stores); `None`
+    /// leaves the store unrestricted
+    pub store_ids: Option<Vec<StoreId>>,
     pub terminal_id: Option<TerminalId>,
     pub shift_id: Option<ShiftId>,
     pub customer_id: Option<CustomerId>,
     pub sale_type: Option<SaleType>,
     pub status: Option<SaleStatus>,
     pub search: Option<String>,
+    /// Keyset cursor: only sales with a smaller id, newest id first. The
+    /// page offset is ignored when set.
+    pub after_id: Option<SaleId>,
 }
 
 /// Repository trait for Sale persistence
@@ -51,6 +57,13 @@ pub trait SaleRepository: Send + Sync {
     /// Updates an existing sale
     async fn update(&self, sale: &Sale) -> Result<(), SalesError>;
 
+    /// Updates a sale being completed, stamping it with the next invoice
+    /// number of its terminal's active CAI range.
+    ///
+    /// The range stays locked until the sale is written, so the numbers of a
+    /// range are unique and gapless under concurrent completions.
+    async fn update_with_cai_invoice_number(&self, sale: &mut Sale) -> Result<(), SalesError>;
+
     /// Finds sales with pagination
     async fn find_paginated(
         &self,
@@ -99,10 +112,4 @@ pub trait SaleRepository: Send + Sync {
         &self,
         payment_id: PaymentId,
     ) -> Result<Option<Payment>, SalesError>;
-
-    /// Finds a payment by idempotency key
-    async fn find_payment_by_idempotency_key(
-        &self,
-        key: &str,
-    ) -> Result<Option<Payment>, SalesError>;
 }
@@ -3,7 +3,7 @@
 use async_trait::async_trait;
 
 use crate::SalesError;
-use crate::domain::entities::CashierShift;
+use crate::domain::entities::{CashierShift, ShiftReopen};
 use crate::domain::value_objects::{ShiftId, ShiftStatus};
 use identity::{StoreId, UserId};
 use pos_core::TerminalId;
@@ -12,9 +12,15 @@ use pos_core::TerminalId;
 #[derive(Debug, Clone, Default)]
 pub struct ShiftFilter {
     pub store_id: Option<StoreId>,
+    /// Restrict to these stores (the caller's assigned stores); `None`
+    /// leaves the store unrestricted
+    pub store_ids: Option<Vec<StoreId>>,
     pub terminal_id: Option<TerminalId>,
     pub cashier_id: Option<UserId>,
     pub status: Option<ShiftStatus>,
+    /// Keyset cursor: only shifts with a smaller id, newest id first. The
+    /// page offset is ignored when set.
+    pub after_id: Option<ShiftId>,
 }
 
 /// Repository trait for CashierShift persistence
@@ -41,6 +47,17 @@ pub trait ShiftRepository: Send + Sync {
     /// Updates an existing shift
     async fn update(&self, shift: &CashierShift) -> Result<(), SalesError>;
 
+    /// Returns true if the shift has been finalized by a cash deposit and
+    /// can no longer be reopened
+    async fn is_finalized(&self, id: ShiftId) -> Result<bool, SalesError>;
+
+    /// Persists a reopened shift together with its audit record, atomically
+    async fn save_reopen(
+        &self,
+        shift: &CashierShift,
+        reopen: &ShiftReopen,
+    ) -> Result<(), SalesError>;
+
     /// Finds shifts with pagination
     async fn find_paginated(
         &self,
@@ -0,0 +1,36 @@
+//! TaxRule repository trait
+
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::SalesError;
+use crate::domain::entities::TaxRule;
+use crate::domain::value_objects::TaxRuleId;
+
+/// Repository trait for TaxRule persistence
+#[async_trait]
+pub trait TaxRuleRepository: Send + Sync {
+    /// Saves a new tax rule
+    async fn save(&self, rule: &TaxRule) -> Result<(), SalesError>;
+
+    /// Updates an existing tax rule
+    async fn update(&self, rule: &TaxRule) -> Result<(), SalesError>;
+
+    /// Finds a tax rule by ID
+    async fn find_by_id(&self, id: TaxRuleId) -> Result<Option<TaxRule>, SalesError>;
+
+    /// Finds the rule for a store targeting the given product or category,
+    /// or the store-wide rule when both are `None`
+    async fn find_by_target(
+        &self,
+        store_id: Uuid,
+        product_id: Option<Uuid>,
+        category_id: Option<Uuid>,
+    ) -> Result<Option<TaxRule>, SalesError>;
+
+    /// Lists rules, optionally for a single store
+    async fn list(&self, store_id: Option<Uuid>) -> Result<Vec<TaxRule>, SalesError>;
+
+    /// Finds a store's active rules
+    async fn find_active_by_store(&self, store_id: Uuid) -> Result<Vec<TaxRule>, SalesError>;
+}
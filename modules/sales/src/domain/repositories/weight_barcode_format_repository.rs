@@ -0,0 +1,23 @@
+//! WeightBarcodeFormat repository trait
+
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::SalesError;
+use crate::domain::entities::WeightBarcodeFormat;
+
+/// Repository trait for WeightBarcodeFormat persistence
+#[async_trait]
+pub trait WeightBarcodeFormatRepository: Send + Sync {
+    /// Saves a new format
+    async fn save(&self, format: &WeightBarcodeFormat) -> Result<(), SalesError>;
+
+    /// Finds a format by its ID
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<WeightBarcodeFormat>, SalesError>;
+
+    /// Lists the store's formats, ordered by prefix
+    async fn find_by_store(&self, store_id: Uuid) -> Result<Vec<WeightBarcodeFormat>, SalesError>;
+
+    /// Deletes a format
+    async fn delete(&self, id: Uuid) -> Result<(), SalesError>;
+}
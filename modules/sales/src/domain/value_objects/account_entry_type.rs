@@ -0,0 +1,73 @@
+//! AccountEntryType enum - kinds of customer account ledger entries
+
+use crate::SalesError;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
+
+/// Kinds of entries on a customer's account
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AccountEntryType {
+    /// A sale paid on account (charge)
+    Sale,
+    /// Reversal of an on-account sale that was voided (credit)
+    SaleVoid,
+    /// A payment made by the customer against the account (credit)
+    Payment,
+    /// A credit note refunded to the account (credit)
+    CreditNote,
+}
+
+impl AccountEntryType {
+    /// Returns true if entries of this type increase the balance owed
+    pub fn is_charge(&self) -> bool {
+        matches!(self, AccountEntryType::Sale)
+    }
+}
+
+impl FromStr for AccountEntryType {
+    type Err = SalesError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().replace('-', "_").as_str() {
+            "sale" => Ok(AccountEntryType::Sale),
+            "sale_void" => Ok(AccountEntryType::SaleVoid),
+            "payment" => Ok(AccountEntryType::Payment),
+            "credit_note" => Ok(AccountEntryType::CreditNote),
+            _ => Err(SalesError::InvalidAccountEntryType),
+        }
+    }
+}
+
+impl fmt::Display for AccountEntryType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AccountEntryType::Sale => write!(f, "sale"),
+            AccountEntryType::SaleVoid => write!(f, "sale_void"),
+            AccountEntryType::Payment => write!(f, "payment"),
+            AccountEntryType::CreditNote => write!(f, "credit_note"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_round_trip() {
+        for entry_type in [
+            AccountEntryType::Sale,
+            AccountEntryType::SaleVoid,
+            AccountEntryType::Payment,
+            AccountEntryType::CreditNote,
+        ] {
+            assert_eq!(
+                AccountEntryType::from_str(&entry_type.to_string()).unwrap(),
+                entry_type
+            );
+        }
+        assert!(AccountEntryType::from_str("refund").is_err());
+    }
+}
@@ -0,0 +1,124 @@
+//! CartTtlPolicy value object - how long an untouched cart is kept
+
+use chrono::{DateTime, Duration, Utc};
+
+use crate::domain::entities::Cart;
+
+/// Inactivity TTLs for abandoned-cart cleanup.
+///
+/// A cart is stale once its last activity is older than the TTL for its kind
+/// (guest carts have no customer and usually a shorter TTL), or once it is
+/// past its `expires_at`. Converted carts are never stale.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CartTtlPolicy {
+    guest_ttl: Duration,
+    customer_ttl: Duration,
+}
+
+impl CartTtlPolicy {
+    /// Default TTL for guest carts in hours
+    pub const DEFAULT_GUEST_TTL_HOURS: i64 = 24;
+    /// Default TTL for carts of an authenticated customer in hours
+    pub const DEFAULT_CUSTOMER_TTL_HOURS: i64 = 168;
+
+    /// Creates a policy; TTLs below one hour are raised to one hour
+    pub fn from_hours(guest_ttl_hours: i64, customer_ttl_hours: i64) -> Self {
+        Self {
+            guest_ttl: Duration::hours(guest_ttl_hours.max(1)),
+            customer_ttl: Duration::hours(customer_ttl_hours.max(1)),
+        }
+    }
+
+    pub fn guest_ttl(&self) -> Duration {
+        self.guest_ttl
+    }
+
+    pub fn customer_ttl(&self) -> Duration {
+        self.customer_ttl
+    }
+
+    /// TTL that applies to `cart`
+    pub fn ttl_for(&self, cart: &Cart) -> Duration {
+        if cart.customer_id().is_some() {
+            self.customer_ttl
+        } else {
+            self.guest_ttl
+        }
+    }
+
+    /// Last-activity cutoffs `(guest, customer)`: carts inactive since before
+    /// the cutoff for their kind are stale
+    pub fn inactive_cutoffs(&self, now: DateTime<Utc>) -> (DateTime<Utc>, DateTime<Utc>) {
+        (now - self.guest_ttl, now - self.customer_ttl)
+    }
+
+    /// Returns true if `cart` should be cleaned up at `now`
+    pub fn is_stale(&self, cart: &Cart, now: DateTime<Utc>) -> bool {
+        if cart.converted_to_sale() {
+            return false;
+        }
+        cart.expires_at() < now || cart.last_activity_at() < now - self.ttl_for(cart)
+    }
+}
+
+impl Default for CartTtlPolicy {
+    fn default() -> Self {
+        Self::from_hours(
+            Self::DEFAULT_GUEST_TTL_HOURS,
+            Self::DEFAULT_CUSTOMER_TTL_HOURS,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::value_objects::CustomerId;
+    use identity::StoreId;
+    use inventory::Currency;
+
+    fn cart(customer: Option<CustomerId>) -> Cart {
+        Cart::create_with_expiration(StoreId::new(), customer, None, Currency::hnl(), 24 * 30)
+    }
+
+    #[test]
+    fn test_guest_and_customer_ttls() {
+        let policy = CartTtlPolicy::from_hours(24, 168);
+        let guest = cart(None);
+        let customer = cart(Some(CustomerId::new()));
+
+        let now = guest.last_activity_at();
+        assert!(!policy.is_stale(&guest, now + Duration::hours(23)));
+        assert!(policy.is_stale(&guest, now + Duration::hours(25)));
+
+        let now = customer.last_activity_at();
+        assert!(!policy.is_stale(&customer, now + Duration::hours(25)));
+        assert!(policy.is_stale(&customer, now + Duration::hours(169)));
+    }
+
+    #[test]
+    fn test_expired_cart_is_stale() {
+        let policy = CartTtlPolicy::from_hours(24 * 60, 24 * 60);
+        let c = cart(None);
+        assert!(policy.is_stale(&c, c.expires_at() + Duration::seconds(1)));
+    }
+
+    #[test]
+    fn test_converted_cart_is_never_stale() {
+        let policy = CartTtlPolicy::default();
+        let mut c = cart(None);
+        c.mark_converted();
+        assert!(!policy.is_stale(&c, c.expires_at() + Duration::days(365)));
+    }
+
+    #[test]
+    fn test_inactive_cutoffs_and_minimum_ttl() {
+        let policy = CartTtlPolicy::from_hours(0, 48);
+        assert_eq!(policy.guest_ttl(), Duration::hours(1));
+
+        let now = Utc::now();
+        let (guest, customer) = policy.inactive_cutoffs(now);
+        assert_eq!(guest, now - Duration::hours(1));
+        assert_eq!(customer, now - Duration::hours(48));
+    }
+}
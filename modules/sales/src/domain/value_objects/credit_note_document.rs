@@ -0,0 +1,239 @@
+//! CreditNoteDocument value object - the printable content of a credit note
+
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::SalesError;
+use crate::domain::entities::{CreditNote, Sale};
+use crate::domain::value_objects::CreditNoteStatus;
+
+/// Fiscal reference of the sale a credit note returns goods from
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CreditNoteDocumentSale {
+    pub sale_id: Uuid,
+    pub sale_number: String,
+    pub invoice_number: Option<String>,
+    /// CAI the original invoice number was drawn under
+    pub cai_number: Option<String>,
+    pub invoice_date: Option<DateTime<Utc>>,
+}
+
+/// A returned line as printed, numbered from 1
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CreditNoteDocumentLine {
+    pub line_number: i32,
+    pub sku: String,
+    pub description: String,
+    pub quantity: Decimal,
+    pub unit_of_measure: String,
+    pub unit_price: Decimal,
+    pub discount_amount: Decimal,
+    pub tax_rate: Decimal,
+    pub tax_amount: Decimal,
+    pub subtotal: Decimal,
+    pub total: Decimal,
+    pub serial_numbers: Vec<String>,
+}
+
+/// Everything a printed credit note shows, ready for rendering.
+///
+/// Built once from an approved or applied credit note and kept as issued:
+/// reprints render the stored document, so they match the original even
+/// after the credit note or its sale change. `document_number` is drawn
+/// from the store's credit-note sequence when the document is issued.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CreditNoteDocument {
+    pub document_number: String,
+    pub issued_at: DateTime<Utc>,
+    pub credit_note_id: Uuid,
+    pub credit_note_number: String,
+    pub store_id: Uuid,
+    pub customer_id: Option<Uuid>,
+    /// `None` for receiptless returns
+    pub original_sale: Option<CreditNoteDocumentSale>,
+    pub return_type: String,
+    pub return_reason: String,
+    pub reason_details: Option<String>,
+    /// Method the refund was applied with, or will be by default
+    pub refund_method: String,
+    pub currency: String,
+    pub lines: Vec<CreditNoteDocumentLine>,
+    pub subtotal: Decimal,
+    pub discount_amount: Decimal,
+    pub tax_amount: Decimal,
+    pub total: Decimal,
+    pub approved_by_id: Option<Uuid>,
+    pub approved_at: Option<DateTime<Utc>>,
+}
+
+impl CreditNoteDocument {
+    /// Builds the document of an approved or applied credit note, not yet
+    /// numbered
+    pub fn build(
+        credit_note: &CreditNote,
+        original_sale: Option<&Sale>,
+    ) -> Result<Self, SalesError> {
+        if !matches!(
+            credit_note.status(),
+            CreditNoteStatus::Approved | CreditNoteStatus::Applied
+        ) {
+            return Err(SalesError::CreditNoteNotApproved);
+        }
+
+        let lines = credit_note
+            .items()
+            .iter()
+            .enumerate()
+            .map(|(idx, item)| CreditNoteDocumentLine {
+                line_number: idx as i32 + 1,
+                sku: item.sku().to_string(),
+                description: item.description().to_string(),
+                quantity: item.return_quantity(),
+                unit_of_measure: item.unit_of_measure().to_string(),
+                unit_price: item.unit_price(),
+                discount_amount: item.discount_amount(),
+                tax_rate: item.tax_rate(),
+                tax_amount: item.tax_amount(),
+                subtotal: item.subtotal(),
+                total: item.total(),
+                serial_numbers: item.serial_numbers().to_vec(),
+            })
+            .collect();
+
+        let refund_method = credit_note
+            .refund_method()
+            .map(String::from)
+            .unwrap_or_else(|| credit_note.default_refund_method().to_string());
+
+        Ok(Self {
+            document_number: String::new(),
+            issued_at: Utc::now(),
+            credit_note_id: credit_note.id().into_uuid(),
+            credit_note_number: credit_note.credit_note_number().to_string(),
+            store_id: credit_note.store_id().into_uuid(),
+            customer_id: credit_note
+                .customer_id()
+                .or_else(|| original_sale.and_then(|s| s.customer_id()))
+                .map(|c| c.into_uuid()),
+            original_sale: original_sale.map(|sale| CreditNoteDocumentSale {
+                sale_id: sale.id().into_uuid(),
+                sale_number: sale.sale_number().to_string(),
+                invoice_number: sale.invoice_number().map(String::from),
+                cai_number: sale.cai_number().map(String::from),
+                invoice_date: sale.invoice_date(),
+            }),
+            return_type: credit_note.return_type().to_string(),
+            return_reason: credit_note.return_reason().to_string(),
+            reason_details: credit_note.reason_details().map(String::from),
+            refund_method,
+            currency: credit_note.currency().as_str().to_string(),
+            lines,
+            subtotal: credit_note.subtotal(),
+            discount_amount: credit_note.discount_amount(),
+            tax_amount: credit_note.tax_amount(),
+            total: credit_note.total(),
+            approved_by_id: credit_note.approved_by_id().map(|u| u.into_uuid()),
+            approved_at: credit_note.approved_at(),
+        })
+    }
+
+    /// Numbers the document as it is issued
+    pub fn issue(&mut self, document_number: String) {
+        self.document_number = document_number;
+        self.issued_at = Utc::now();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::entities::CreditNoteItem;
+    use crate::domain::value_objects::{CustomerId, ReturnReason, ReturnType};
+    use identity::{StoreId, UserId};
+    use inventory::{Currency, ProductId, UnitOfMeasure};
+    use rust_decimal_macros::dec;
+    use std::str::FromStr;
+
+    fn submitted_receiptless_note() -> CreditNote {
+        let mut cn = CreditNote::create_receiptless(
+            "CN-001".to_string(),
+            StoreId::new(),
+            CustomerId::new(),
+            ReturnType::Partial,
+            ReturnReason::Defective,
+            Currency::new("HNL").unwrap(),
+            UserId::new(),
+        );
+        for sku in ["SKU-001", "SKU-002"] {
+            let item = CreditNoteItem::create(
+                cn.id(),
+                None,
+                ProductId::new(),
+                None,
+                sku.to_string(),
+                "Test Product".to_string(),
+                dec!(2),
+                UnitOfMeasure::from_str("unit").unwrap(),
+                dec!(50.00),
+                dec!(15),
+            )
+            .unwrap();
+            cn.add_item(item).unwrap();
+        }
+        cn.submit(UserId::new()).unwrap();
+        cn
+    }
+
+    #[test]
+    fn test_build_requires_approval() {
+        let mut cn = submitted_receiptless_note();
+        assert!(matches!(
+            CreditNoteDocument::build(&cn, None),
+            Err(SalesError::CreditNoteNotApproved)
+        ));
+
+        cn.approve(UserId::new()).unwrap();
+        assert!(CreditNoteDocument::build(&cn, None).is_ok());
+    }
+
+    #[test]
+    fn test_build_numbers_lines_and_keeps_totals() {
+        let mut cn = submitted_receiptless_note();
+        cn.approve(UserId::new()).unwrap();
+
+        let mut document = CreditNoteDocument::build(&cn, None).unwrap();
+        document.issue("NC-00000001".to_string());
+
+        assert_eq!(document.document_number, "NC-00000001");
+        assert!(document.original_sale.is_none());
+        assert_eq!(document.refund_method, "store_credit");
+        assert_eq!(
+            document
+                .lines
+                .iter()
+                .map(|l| (l.line_number, l.sku.as_str()))
+                .collect::<Vec<_>>(),
+            [(1, "SKU-001"), (2, "SKU-002")]
+        );
+        assert_eq!(document.subtotal, cn.subtotal());
+        assert_eq!(document.total, cn.total());
+        assert_eq!(
+            document.lines.iter().map(|l| l.total).sum::<Decimal>(),
+            cn.total()
+        );
+    }
+
+    #[test]
+    fn test_document_survives_serialization_unchanged() {
+        let mut cn = submitted_receiptless_note();
+        cn.approve(UserId::new()).unwrap();
+        let document = CreditNoteDocument::build(&cn, None).unwrap();
+
+        let json = serde_json::to_value(&document).unwrap();
+        let reprint: CreditNoteDocument = serde_json::from_value(json).unwrap();
+
+        assert_eq!(reprint, document);
+    }
+}
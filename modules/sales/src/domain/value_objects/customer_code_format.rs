@@ -0,0 +1,109 @@
+//! CustomerCodeFormat - prefix and zero padding for auto-assigned customer codes
+
+use serde::{Deserialize, Serialize};
+
+use crate::SalesError;
+
+/// Maximum prefix length (the `customers.code` column is VARCHAR(50))
+pub const MAX_CUSTOMER_CODE_PREFIX_LEN: usize = 20;
+
+/// Maximum zero padding for the numeric part of the code
+pub const MAX_CUSTOMER_CODE_PADDING: u8 = 12;
+
+/// Format used to render customer codes such as `CUS-00042`.
+///
+/// Configured per store; the numeric part comes from the store's customer
+/// code sequence.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CustomerCodeFormat {
+    prefix: String,
+    padding: u8,
+}
+
+impl CustomerCodeFormat {
+    /// Creates a validated format. The prefix is stored uppercase so codes
+    /// can be looked up case-insensitively.
+    pub fn new(prefix: impl Into<String>, padding: u8) -> Result<Self, SalesError> {
+        let prefix = prefix.into().trim().to_uppercase();
+        if prefix.is_empty() || prefix.len() > MAX_CUSTOMER_CODE_PREFIX_LEN {
+            return Err(SalesError::InvalidCustomerCodeFormat(format!(
+                "prefix must be 1 to {} characters",
+                MAX_CUSTOMER_CODE_PREFIX_LEN
+            )));
+        }
+        if !prefix
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+        {
+            return Err(SalesError::InvalidCustomerCodeFormat(
+                "prefix may only contain letters, digits, '-' and '_'".to_string(),
+            ));
+        }
+        if padding == 0 || padding > MAX_CUSTOMER_CODE_PADDING {
+            return Err(SalesError::InvalidCustomerCodeFormat(format!(
+                "padding must be between 1 and {}",
+                MAX_CUSTOMER_CODE_PADDING
+            )));
+        }
+        Ok(Self { prefix, padding })
+    }
+
+    /// Renders the code for a sequence value
+    pub fn format(&self, value: i64) -> String {
+        format!(
+            "{}{:0width$}",
+            self.prefix,
+            value,
+            width = self.padding as usize
+        )
+    }
+
+    pub fn prefix(&self) -> &str {
+        &self.prefix
+    }
+
+    pub fn padding(&self) -> u8 {
+        self.padding
+    }
+}
+
+impl Default for CustomerCodeFormat {
+    fn default() -> Self {
+        Self {
+            prefix: "CUS-".to_string(),
+            padding: 5,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_format() {
+        let format = CustomerCodeFormat::default();
+        assert_eq!(format.format(42), "CUS-00042");
+    }
+
+    #[test]
+    fn test_custom_format() {
+        let format = CustomerCodeFormat::new("cust_", 3).unwrap();
+        assert_eq!(format.format(7), "CUST_007");
+        // Values wider than the padding are not truncated
+        assert_eq!(format.format(12345), "CUST_12345");
+    }
+
+    #[test]
+    fn test_rejects_invalid_prefix() {
+        assert!(CustomerCodeFormat::new("", 5).is_err());
+        assert!(CustomerCodeFormat::new("CUS 1", 5).is_err());
+        assert!(CustomerCodeFormat::new("X".repeat(21), 5).is_err());
+    }
+
+    #[test]
+    fn test_rejects_invalid_padding() {
+        assert!(CustomerCodeFormat::new("CUS-", 0).is_err());
+        assert!(CustomerCodeFormat::new("CUS-", 13).is_err());
+    }
+}
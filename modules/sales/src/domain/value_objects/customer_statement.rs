@@ -0,0 +1,184 @@
+//! CustomerStatement value object - a customer's account ledger over a period
+
+use rust_decimal::Decimal;
+
+use crate::domain::entities::CustomerAccountEntry;
+
+/// A statement line: an account entry with the running balance after it
+#[derive(Debug, Clone)]
+pub struct CustomerStatementLine {
+    pub entry: CustomerAccountEntry,
+    /// Amount added to the balance (charges)
+    pub debit: Decimal,
+    /// Amount taken off the balance (payments, credit notes, voids)
+    pub credit: Decimal,
+    pub running_balance: Decimal,
+}
+
+/// Account activity between an opening and a closing balance.
+///
+/// The running balance is recomputed from the opening balance and the entry
+/// amounts, so `opening_balance + total_debits - total_credits ==
+/// closing_balance` always holds. `is_reconciled` additionally checks that
+/// every running balance matches the balance recorded when the entry was
+/// posted.
+#[derive(Debug, Clone)]
+pub struct CustomerStatement {
+    opening_balance: Decimal,
+    lines: Vec<CustomerStatementLine>,
+    total_debits: Decimal,
+    total_credits: Decimal,
+    closing_balance: Decimal,
+    is_reconciled: bool,
+}
+
+impl CustomerStatement {
+    /// Builds a statement from the balance before the period and the
+    /// period's entries in posting order
+    pub fn build(opening_balance: Decimal, entries: Vec<CustomerAccountEntry>) -> Self {
+        let mut running_balance = opening_balance;
+        let mut total_debits = Decimal::ZERO;
+        let mut total_credits = Decimal::ZERO;
+        let mut is_reconciled = true;
+
+        let lines = entries
+            .into_iter()
+            .map(|entry| {
+                let amount = entry.amount();
+                let (debit, credit) = if amount >= Decimal::ZERO {
+                    (amount, Decimal::ZERO)
+                } else {
+                    (Decimal::ZERO, -amount)
+                };
+                total_debits += debit;
+                total_credits += credit;
+                running_balance += amount;
+                is_reconciled &= running_balance == entry.balance_after();
+                CustomerStatementLine {
+                    entry,
+                    debit,
+                    credit,
+                    running_balance,
+                }
+            })
+            .collect();
+
+        Self {
+            opening_balance,
+            lines,
+            total_debits,
+            total_credits,
+            closing_balance: running_balance,
+            is_reconciled,
+        }
+    }
+
+    pub fn opening_balance(&self) -> Decimal {
+        self.opening_balance
+    }
+
+    pub fn lines(&self) -> &[CustomerStatementLine] {
+        &self.lines
+    }
+
+    pub fn into_lines(self) -> Vec<CustomerStatementLine> {
+        self.lines
+    }
+
+    pub fn total_debits(&self) -> Decimal {
+        self.total_debits
+    }
+
+    pub fn total_credits(&self) -> Decimal {
+        self.total_credits
+    }
+
+    pub fn closing_balance(&self) -> Decimal {
+        self.closing_balance
+    }
+
+    /// Returns true if the recorded balances agree with the recomputed ones
+    pub fn is_reconciled(&self) -> bool {
+        self.is_reconciled
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::value_objects::{AccountEntryType, CustomerId};
+    use chrono::Utc;
+    use identity::StoreId;
+    use rust_decimal_macros::dec;
+    use uuid::Uuid;
+
+    fn entry(
+        entry_type: AccountEntryType,
+        amount: Decimal,
+        balance_after: Decimal,
+    ) -> CustomerAccountEntry {
+        CustomerAccountEntry::reconstitute(
+            Uuid::nil(),
+            CustomerId::new(),
+            StoreId::new(),
+            entry_type,
+            amount,
+            balance_after,
+            None,
+            None,
+            None,
+            None,
+            Utc::now(),
+        )
+    }
+
+    #[test]
+    fn test_statement_running_balance() {
+        let statement = CustomerStatement::build(
+            dec!(50),
+            vec![
+                entry(AccountEntryType::Sale, dec!(200), dec!(250)),
+                entry(AccountEntryType::Payment, dec!(-120), dec!(130)),
+                entry(AccountEntryType::CreditNote, dec!(-30), dec!(100)),
+            ],
+        );
+
+        let balances: Vec<Decimal> = statement
+            .lines()
+            .iter()
+            .map(|l| l.running_balance)
+            .collect();
+        assert_eq!(balances, vec![dec!(250), dec!(130), dec!(100)]);
+        assert_eq!(statement.total_debits(), dec!(200));
+        assert_eq!(statement.total_credits(), dec!(150));
+        assert_eq!(statement.closing_balance(), dec!(100));
+        assert_eq!(
+            statement.opening_balance() + statement.total_debits() - statement.total_credits(),
+            statement.closing_balance()
+        );
+        assert!(statement.is_reconciled());
+    }
+
+    #[test]
+    fn test_statement_detects_unreconciled_entries() {
+        let statement = CustomerStatement::build(
+            Decimal::ZERO,
+            vec![
+                entry(AccountEntryType::Sale, dec!(100), dec!(100)),
+                entry(AccountEntryType::Sale, dec!(40), dec!(150)),
+            ],
+        );
+
+        assert_eq!(statement.closing_balance(), dec!(140));
+        assert!(!statement.is_reconciled());
+    }
+
+    #[test]
+    fn test_empty_statement() {
+        let statement = CustomerStatement::build(dec!(75), Vec::new());
+
+        assert!(statement.lines().is_empty());
+        assert_eq!(statement.closing_balance(), dec!(75));
+        assert!(statement.is_reconciled());
+    }
+}
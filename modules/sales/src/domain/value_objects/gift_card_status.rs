@@ -0,0 +1,68 @@
+//! GiftCardStatus enum - lifecycle of a gift card
+
+use crate::SalesError;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
+
+/// Lifecycle of a gift card
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum GiftCardStatus {
+    /// Sold on a sale that has not been completed yet
+    Pending,
+    /// Can be redeemed
+    Active,
+    /// The whole balance has been spent
+    Depleted,
+}
+
+impl GiftCardStatus {
+    /// Returns true if the card can pay for a sale
+    pub fn is_redeemable(&self) -> bool {
+        matches!(self, GiftCardStatus::Active)
+    }
+}
+
+impl FromStr for GiftCardStatus {
+    type Err = SalesError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "pending" => Ok(GiftCardStatus::Pending),
+            "active" => Ok(GiftCardStatus::Active),
+            "depleted" => Ok(GiftCardStatus::Depleted),
+            _ => Err(SalesError::InvalidGiftCardStatus),
+        }
+    }
+}
+
+impl fmt::Display for GiftCardStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GiftCardStatus::Pending => write!(f, "pending"),
+            GiftCardStatus::Active => write!(f, "active"),
+            GiftCardStatus::Depleted => write!(f, "depleted"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_str_round_trip() {
+        for status in [
+            GiftCardStatus::Pending,
+            GiftCardStatus::Active,
+            GiftCardStatus::Depleted,
+        ] {
+            assert_eq!(
+                GiftCardStatus::from_str(&status.to_string()).unwrap(),
+                status
+            );
+        }
+        assert!(GiftCardStatus::from_str("bogus").is_err());
+    }
+}
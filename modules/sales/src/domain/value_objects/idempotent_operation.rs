@@ -0,0 +1,41 @@
+//! IdempotentOperation enum - sale request an idempotency key was used for
+
+use crate::SalesError;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
+
+/// Sale request a terminal's idempotency key was used for
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum IdempotentOperation {
+    /// Completing a POS sale
+    Complete,
+    /// Processing a payment on a sale
+    Payment,
+    /// Ringing up a quick sale
+    QuickSale,
+}
+
+impl FromStr for IdempotentOperation {
+    type Err = SalesError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "complete" => Ok(IdempotentOperation::Complete),
+            "payment" => Ok(IdempotentOperation::Payment),
+            "quick_sale" => Ok(IdempotentOperation::QuickSale),
+            _ => Err(SalesError::InvalidIdempotentOperation),
+        }
+    }
+}
+
+impl fmt::Display for IdempotentOperation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            IdempotentOperation::Complete => write!(f, "complete"),
+            IdempotentOperation::Payment => write!(f, "payment"),
+            IdempotentOperation::QuickSale => write!(f, "quick_sale"),
+        }
+    }
+}
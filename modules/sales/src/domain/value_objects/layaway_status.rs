@@ -0,0 +1,73 @@
+//! LayawayStatus enum - lifecycle of a layaway
+
+use crate::SalesError;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
+
+/// Lifecycle of a layaway
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LayawayStatus {
+    /// Terms agreed; waiting for the deposit
+    Open,
+    /// Deposit paid and stock held; installments are being paid
+    Active,
+    /// Paid in full and the sale completed
+    Completed,
+    /// Cancelled before it was paid off
+    Cancelled,
+}
+
+impl LayawayStatus {
+    /// Returns true if the layaway can still be paid or cancelled
+    pub fn is_open(&self) -> bool {
+        matches!(self, LayawayStatus::Open | LayawayStatus::Active)
+    }
+}
+
+impl FromStr for LayawayStatus {
+    type Err = SalesError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "open" => Ok(LayawayStatus::Open),
+            "active" => Ok(LayawayStatus::Active),
+            "completed" => Ok(LayawayStatus::Completed),
+            "cancelled" => Ok(LayawayStatus::Cancelled),
+            _ => Err(SalesError::InvalidLayawayStatus),
+        }
+    }
+}
+
+impl fmt::Display for LayawayStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LayawayStatus::Open => write!(f, "open"),
+            LayawayStatus::Active => write!(f, "active"),
+            LayawayStatus::Completed => write!(f, "completed"),
+            LayawayStatus::Cancelled => write!(f, "cancelled"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_str_round_trip() {
+        for status in [
+            LayawayStatus::Open,
+            LayawayStatus::Active,
+            LayawayStatus::Completed,
+            LayawayStatus::Cancelled,
+        ] {
+            assert_eq!(
+                LayawayStatus::from_str(&status.to_string()).unwrap(),
+                status
+            );
+        }
+        assert!(LayawayStatus::from_str("bogus").is_err());
+    }
+}
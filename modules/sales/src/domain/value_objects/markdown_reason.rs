@@ -0,0 +1,37 @@
+//! MarkdownReason enum - why a product was automatically marked down
+
+use crate::SalesError;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
+
+/// Trigger that produced an automatic markdown
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MarkdownReason {
+    /// A received lot is within the rule's expiry window
+    NearExpiry,
+    /// Stock has not sold for longer than the rule's idle threshold
+    IdleStock,
+}
+
+impl FromStr for MarkdownReason {
+    type Err = SalesError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "near_expiry" => Ok(MarkdownReason::NearExpiry),
+            "idle_stock" => Ok(MarkdownReason::IdleStock),
+            _ => Err(SalesError::InvalidMarkdownReason),
+        }
+    }
+}
+
+impl fmt::Display for MarkdownReason {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MarkdownReason::NearExpiry => write!(f, "near_expiry"),
+            MarkdownReason::IdleStock => write!(f, "idle_stock"),
+        }
+    }
+}
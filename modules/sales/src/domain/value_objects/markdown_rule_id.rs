@@ -0,0 +1,28 @@
+//! MarkdownRuleId value object - unique identifier for markdown rules
+
+use serde::{Deserialize, Serialize};
+use uuid::{NoContext, Timestamp, Uuid};
+
+/// Unique identifier for a MarkdownRule
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct MarkdownRuleId(Uuid);
+
+impl MarkdownRuleId {
+    pub fn new() -> Self {
+        Self(Uuid::new_v7(Timestamp::now(NoContext)))
+    }
+
+    pub fn from_uuid(uuid: Uuid) -> Self {
+        Self(uuid)
+    }
+
+    pub fn into_uuid(self) -> Uuid {
+        self.0
+    }
+}
+
+impl Default for MarkdownRuleId {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,75 @@
+//! MarkdownStatus enum - lifecycle of an automatic markdown
+
+use crate::SalesError;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
+
+/// Lifecycle of an automatic markdown
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MarkdownStatus {
+    /// Discount promotion is live
+    Active,
+    /// Discount window ended on its own
+    Expired,
+    /// Manually undone before it ended
+    Reversed,
+}
+
+impl MarkdownStatus {
+    /// Returns true if the markdown can still be reversed
+    pub fn can_reverse(&self) -> bool {
+        matches!(self, MarkdownStatus::Active)
+    }
+}
+
+impl FromStr for MarkdownStatus {
+    type Err = SalesError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "active" => Ok(MarkdownStatus::Active),
+            "expired" => Ok(MarkdownStatus::Expired),
+            "reversed" => Ok(MarkdownStatus::Reversed),
+            _ => Err(SalesError::InvalidMarkdownStatus),
+        }
+    }
+}
+
+impl fmt::Display for MarkdownStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MarkdownStatus::Active => write!(f, "active"),
+            MarkdownStatus::Expired => write!(f, "expired"),
+            MarkdownStatus::Reversed => write!(f, "reversed"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_str_round_trip() {
+        for status in [
+            MarkdownStatus::Active,
+            MarkdownStatus::Expired,
+            MarkdownStatus::Reversed,
+        ] {
+            assert_eq!(
+                MarkdownStatus::from_str(&status.to_string()).unwrap(),
+                status
+            );
+        }
+        assert!(MarkdownStatus::from_str("bogus").is_err());
+    }
+
+    #[test]
+    fn test_only_active_can_reverse() {
+        assert!(MarkdownStatus::Active.can_reverse());
+        assert!(!MarkdownStatus::Expired.can_reverse());
+        assert!(!MarkdownStatus::Reversed.can_reverse());
+    }
+}
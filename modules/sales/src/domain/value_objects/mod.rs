@@ -9,26 +9,52 @@ mod cart_item_id;
 mod credit_note_id;
 mod credit_note_item_id;
 mod customer_id;
+mod gift_card_id;
+mod layaway_id;
 mod payment_id;
 mod sale_id;
 mod sale_item_id;
 mod shift_id;
+mod tax_rule_id;
 
+mod markdown_id;
+mod markdown_rule_id;
+mod price_list_id;
+mod price_tier_id;
 mod promotion_id;
 
+// Other value objects
+mod cart_ttl_policy;
+mod credit_note_document;
+mod customer_code_format;
+mod customer_statement;
+mod promotion_discount;
+mod sale_line_allocation;
+mod sale_tax_breakdown;
+
 // Enum value objects
+mod account_entry_type;
 mod credit_note_status;
 mod customer_type;
 mod discount_type;
+mod gift_card_status;
+mod idempotent_operation;
+mod layaway_status;
+mod markdown_reason;
+mod markdown_status;
 mod order_status;
 mod payment_method;
 mod payment_status;
+mod pickup_status;
 mod promotion_status;
 mod promotion_type;
+mod restock_cost_basis;
 mod return_reason;
 mod return_type;
 mod sale_status;
 mod sale_type;
+mod scale_value_kind;
+mod shift_count;
 mod shift_status;
 
 // Re-exports - IDs
@@ -37,23 +63,54 @@ pub use cart_item_id::CartItemId;
 pub use credit_note_id::CreditNoteId;
 pub use credit_note_item_id::CreditNoteItemId;
 pub use customer_id::CustomerId;
+pub use gift_card_id::GiftCardId;
+pub use layaway_id::LayawayId;
+pub use markdown_id::MarkdownId;
+pub use markdown_rule_id::MarkdownRuleId;
 pub use payment_id::PaymentId;
+pub use price_list_id::PriceListId;
+pub use price_tier_id::PriceTierId;
 pub use promotion_id::PromotionId;
 pub use sale_id::SaleId;
 pub use sale_item_id::SaleItemId;
 pub use shift_id::ShiftId;
+pub use tax_rule_id::TaxRuleId;
+
+// Re-exports - Other value objects
+pub use cart_ttl_policy::CartTtlPolicy;
+pub use credit_note_document::{
+    CreditNoteDocument, CreditNoteDocumentLine, CreditNoteDocumentSale,
+};
+pub use customer_code_format::{
+    CustomerCodeFormat, MAX_CUSTOMER_CODE_PADDING, MAX_CUSTOMER_CODE_PREFIX_LEN,
+};
+pub use customer_statement::{CustomerStatement, CustomerStatementLine};
+pub use promotion_discount::{PromotionDiscount, PromotionLine};
+pub use sale_line_allocation::{ReturnAllocation, SaleLineAllocation};
+pub(crate) use sale_line_allocation::{allocate_order_discount, line_tax};
+pub use sale_tax_breakdown::SaleTaxBreakdown;
 
 // Re-exports - Enums
+pub use account_entry_type::AccountEntryType;
 pub use credit_note_status::CreditNoteStatus;
 pub use customer_type::CustomerType;
 pub use discount_type::DiscountType;
+pub use gift_card_status::GiftCardStatus;
+pub use idempotent_operation::IdempotentOperation;
+pub use layaway_status::LayawayStatus;
+pub use markdown_reason::MarkdownReason;
+pub use markdown_status::MarkdownStatus;
 pub use order_status::OrderStatus;
 pub use payment_method::PaymentMethod;
 pub use payment_status::PaymentStatus;
+pub use pickup_status::PickupStatus;
 pub use promotion_status::PromotionStatus;
 pub use promotion_type::PromotionType;
+pub use restock_cost_basis::RestockCostBasis;
 pub use return_reason::ReturnReason;
 pub use return_type::ReturnType;
 pub use sale_status::SaleStatus;
 pub use sale_type::SaleType;
+pub use scale_value_kind::ScaleValueKind;
+pub use shift_count::ShiftCount;
 pub use shift_status::ShiftStatus;
@@ -21,6 +21,10 @@ pub enum OrderStatus {
     Shipped,
     /// Order delivered
     Delivered,
+    /// Order held at the store waiting for the customer to collect it
+    ReadyForPickup,
+    /// Order collected by the customer at the store
+    PickedUp,
     /// Order cancelled
     Cancelled,
     /// Order returned
@@ -37,6 +41,8 @@ impl OrderStatus {
             OrderStatus::Processing,
             OrderStatus::Shipped,
             OrderStatus::Delivered,
+            OrderStatus::ReadyForPickup,
+            OrderStatus::PickedUp,
             OrderStatus::Cancelled,
             OrderStatus::Returned,
         ]
@@ -46,7 +52,10 @@ impl OrderStatus {
     pub fn is_final(&self) -> bool {
         matches!(
             self,
-            OrderStatus::Delivered | OrderStatus::Cancelled | OrderStatus::Returned
+            OrderStatus::Delivered
+                | OrderStatus::PickedUp
+                | OrderStatus::Cancelled
+                | OrderStatus::Returned
         )
     }
 
@@ -54,7 +63,10 @@ impl OrderStatus {
     pub fn can_cancel(&self) -> bool {
         matches!(
             self,
-            OrderStatus::PendingPayment | OrderStatus::Paid | OrderStatus::Processing
+            OrderStatus::PendingPayment
+                | OrderStatus::Paid
+                | OrderStatus::Processing
+                | OrderStatus::ReadyForPickup
         )
     }
 
@@ -73,9 +85,19 @@ impl OrderStatus {
         matches!(self, OrderStatus::Shipped)
     }
 
+    /// Returns true if the order can be put on hold at the store for pickup
+    pub fn can_mark_ready_for_pickup(&self) -> bool {
+        matches!(self, OrderStatus::Paid | OrderStatus::Processing)
+    }
+
+    /// Returns true if the customer can collect the order
+    pub fn can_complete_pickup(&self) -> bool {
+        matches!(self, OrderStatus::ReadyForPickup)
+    }
+
     /// Returns true if a return can be created for this order
     pub fn can_return(&self) -> bool {
-        matches!(self, OrderStatus::Delivered)
+        matches!(self, OrderStatus::Delivered | OrderStatus::PickedUp)
     }
 
     /// Returns true if payment can be retried
@@ -95,14 +117,21 @@ impl OrderStatus {
             (OrderStatus::PaymentFailed, OrderStatus::Cancelled) => true,
             // From Paid
             (OrderStatus::Paid, OrderStatus::Processing) => true,
+            (OrderStatus::Paid, OrderStatus::ReadyForPickup) => true,
             (OrderStatus::Paid, OrderStatus::Cancelled) => true,
             // From Processing
             (OrderStatus::Processing, OrderStatus::Shipped) => true,
+            (OrderStatus::Processing, OrderStatus::ReadyForPickup) => true,
             (OrderStatus::Processing, OrderStatus::Cancelled) => true,
+            // From ReadyForPickup
+            (OrderStatus::ReadyForPickup, OrderStatus::PickedUp) => true,
+            (OrderStatus::ReadyForPickup, OrderStatus::Cancelled) => true,
             // From Shipped
             (OrderStatus::Shipped, OrderStatus::Delivered) => true,
             // From Delivered
             (OrderStatus::Delivered, OrderStatus::Returned) => true,
+            // From PickedUp
+            (OrderStatus::PickedUp, OrderStatus::Returned) => true,
             // All other transitions are invalid
             _ => false,
         }
@@ -120,6 +149,8 @@ impl FromStr for OrderStatus {
             "processing" => Ok(OrderStatus::Processing),
             "shipped" => Ok(OrderStatus::Shipped),
             "delivered" => Ok(OrderStatus::Delivered),
+            "ready_for_pickup" | "readyforpickup" => Ok(OrderStatus::ReadyForPickup),
+            "picked_up" | "pickedup" => Ok(OrderStatus::PickedUp),
             "cancelled" | "canceled" => Ok(OrderStatus::Cancelled),
             "returned" => Ok(OrderStatus::Returned),
             _ => Err(SalesError::InvalidOrderStatus),
@@ -136,6 +167,8 @@ impl fmt::Display for OrderStatus {
             OrderStatus::Processing => write!(f, "processing"),
             OrderStatus::Shipped => write!(f, "shipped"),
             OrderStatus::Delivered => write!(f, "delivered"),
+            OrderStatus::ReadyForPickup => write!(f, "ready_for_pickup"),
+            OrderStatus::PickedUp => write!(f, "picked_up"),
             OrderStatus::Cancelled => write!(f, "cancelled"),
             OrderStatus::Returned => write!(f, "returned"),
         }
@@ -215,4 +248,26 @@ mod tests {
         assert!(OrderStatus::Shipped.can_transition_to(OrderStatus::Delivered));
         assert!(!OrderStatus::Shipped.can_transition_to(OrderStatus::Cancelled));
     }
+
+    #[test]
+    fn test_pickup_workflow() {
+        assert!(OrderStatus::Paid.can_transition_to(OrderStatus::ReadyForPickup));
+        assert!(OrderStatus::Processing.can_transition_to(OrderStatus::ReadyForPickup));
+        assert!(!OrderStatus::Shipped.can_transition_to(OrderStatus::ReadyForPickup));
+
+        assert!(OrderStatus::ReadyForPickup.can_complete_pickup());
+        assert!(OrderStatus::ReadyForPickup.can_cancel());
+        assert!(OrderStatus::ReadyForPickup.can_transition_to(OrderStatus::PickedUp));
+        assert!(!OrderStatus::ReadyForPickup.can_transition_to(OrderStatus::Shipped));
+
+        assert!(OrderStatus::PickedUp.is_final());
+        assert!(OrderStatus::PickedUp.can_return());
+        assert!(!OrderStatus::PickedUp.can_cancel());
+
+        assert_eq!(
+            OrderStatus::from_str("ready_for_pickup").unwrap(),
+            OrderStatus::ReadyForPickup
+        );
+        assert_eq!(OrderStatus::PickedUp.to_string(), "picked_up");
+    }
 }
@@ -23,6 +23,8 @@ pub enum PaymentMethod {
     StoreCredit,
     /// Gift card
     GiftCard,
+    /// Charged to the customer's account, to be paid later
+    OnAccount,
     /// Other payment method
     Other,
 }
@@ -38,6 +40,7 @@ impl PaymentMethod {
             PaymentMethod::PayPal,
             PaymentMethod::StoreCredit,
             PaymentMethod::GiftCard,
+            PaymentMethod::OnAccount,
             PaymentMethod::Other,
         ]
     }
@@ -63,6 +66,11 @@ impl PaymentMethod {
         )
     }
 
+    /// Returns true if the amount is charged to the customer's account
+    pub fn is_on_account(&self) -> bool {
+        matches!(self, PaymentMethod::OnAccount)
+    }
+
     /// Returns true if change can be given for this payment method
     pub fn can_give_change(&self) -> bool {
         matches!(self, PaymentMethod::Cash)
@@ -81,6 +89,7 @@ impl FromStr for PaymentMethod {
             "paypal" => Ok(PaymentMethod::PayPal),
             "store_credit" | "storecredit" => Ok(PaymentMethod::StoreCredit),
             "gift_card" | "giftcard" | "gift" => Ok(PaymentMethod::GiftCard),
+            "on_account" | "onaccount" | "account" => Ok(PaymentMethod::OnAccount),
             "other" => Ok(PaymentMethod::Other),
             _ => Err(SalesError::InvalidPaymentMethod),
         }
@@ -97,6 +106,7 @@ impl fmt::Display for PaymentMethod {
             PaymentMethod::PayPal => write!(f, "paypal"),
             PaymentMethod::StoreCredit => write!(f, "store_credit"),
             PaymentMethod::GiftCard => write!(f, "gift_card"),
+            PaymentMethod::OnAccount => write!(f, "on_account"),
             PaymentMethod::Other => write!(f, "other"),
         }
     }
@@ -130,6 +140,7 @@ mod tests {
     fn test_display() {
         assert_eq!(PaymentMethod::Cash.to_string(), "cash");
         assert_eq!(PaymentMethod::CreditCard.to_string(), "credit_card");
+        assert_eq!(PaymentMethod::OnAccount.to_string(), "on_account");
     }
 
     #[test]
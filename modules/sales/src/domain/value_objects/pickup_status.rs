@@ -0,0 +1,78 @@
+//! PickupStatus enum - lifecycle of an order held at the store for pickup
+
+use crate::SalesError;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
+
+/// Lifecycle of an order held at the store for pickup
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PickupStatus {
+    /// Order is on the shelf waiting for the customer
+    Waiting,
+    /// Customer collected the order
+    PickedUp,
+    /// Customer did not show up before the hold ended
+    Expired,
+    /// Order was cancelled while waiting
+    Cancelled,
+}
+
+impl PickupStatus {
+    /// Returns true while the order can still be collected
+    pub fn is_open(&self) -> bool {
+        matches!(self, PickupStatus::Waiting)
+    }
+}
+
+impl FromStr for PickupStatus {
+    type Err = SalesError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().replace('-', "_").as_str() {
+            "waiting" => Ok(PickupStatus::Waiting),
+            "picked_up" => Ok(PickupStatus::PickedUp),
+            "expired" => Ok(PickupStatus::Expired),
+            "cancelled" | "canceled" => Ok(PickupStatus::Cancelled),
+            _ => Err(SalesError::InvalidPickupStatus),
+        }
+    }
+}
+
+impl fmt::Display for PickupStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PickupStatus::Waiting => write!(f, "waiting"),
+            PickupStatus::PickedUp => write!(f, "picked_up"),
+            PickupStatus::Expired => write!(f, "expired"),
+            PickupStatus::Cancelled => write!(f, "cancelled"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_str_round_trip() {
+        for status in [
+            PickupStatus::Waiting,
+            PickupStatus::PickedUp,
+            PickupStatus::Expired,
+            PickupStatus::Cancelled,
+        ] {
+            assert_eq!(PickupStatus::from_str(&status.to_string()).unwrap(), status);
+        }
+        assert!(PickupStatus::from_str("bogus").is_err());
+    }
+
+    #[test]
+    fn test_only_waiting_is_open() {
+        assert!(PickupStatus::Waiting.is_open());
+        assert!(!PickupStatus::PickedUp.is_open());
+        assert!(!PickupStatus::Expired.is_open());
+        assert!(!PickupStatus::Cancelled.is_open());
+    }
+}
@@ -0,0 +1,66 @@
+//! PromotionDiscount value object - a discount a promotion grants on a sale
+
+use std::collections::HashMap;
+
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::domain::value_objects::{PromotionId, SaleItemId};
+
+/// A sale line as promotion rules see it
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PromotionLine {
+    pub sale_item_id: SaleItemId,
+    pub product_id: Uuid,
+    pub category_id: Option<Uuid>,
+    pub quantity: Decimal,
+    pub unit_price: Decimal,
+    /// Line amount before discounts (quantity × unit price)
+    pub subtotal: Decimal,
+    /// Discount already given on the line by hand; promotions leave such
+    /// lines alone
+    pub manual_discount: Decimal,
+}
+
+/// Discount a promotion grants on one sale line (`sale_item_id` set) or on
+/// the whole sale
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PromotionDiscount {
+    pub promotion_id: PromotionId,
+    pub promotion_code: String,
+    pub promotion_name: String,
+    pub sale_item_id: Option<SaleItemId>,
+    pub amount: Decimal,
+}
+
+impl PromotionDiscount {
+    /// Returns true if the discount applies to the whole sale
+    pub fn is_order_level(&self) -> bool {
+        self.sale_item_id.is_none()
+    }
+
+    /// Keeps the largest line-level discount of each line, in line order of
+    /// first appearance. A line carries a single discount, so promotions
+    /// targeting the same line don't stack.
+    pub fn best_per_line(discounts: &[PromotionDiscount]) -> Vec<PromotionDiscount> {
+        let mut order: Vec<SaleItemId> = Vec::new();
+        let mut best: HashMap<SaleItemId, &PromotionDiscount> = HashMap::new();
+        for discount in discounts {
+            let Some(item_id) = discount.sale_item_id else {
+                continue;
+            };
+            match best.get(&item_id) {
+                Some(current) if current.amount >= discount.amount => {}
+                Some(_) => {
+                    best.insert(item_id, discount);
+                }
+                None => {
+                    order.push(item_id);
+                    best.insert(item_id, discount);
+                }
+            }
+        }
+        order.iter().map(|id| best[id].clone()).collect()
+    }
+}
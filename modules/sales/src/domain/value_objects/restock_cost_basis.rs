@@ -0,0 +1,125 @@
+//! RestockCostBasis enum - unit cost recorded when returned items go back to stock
+
+use crate::SalesError;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
+
+/// Cost basis for the inbound movement created when a credit note restocks
+/// returned items.
+///
+/// Valuation impact: the weighted-average cost is computed from inbound
+/// movements that carry a unit cost, so a return is averaged in like a
+/// purchase at the recorded cost.
+/// - `OriginalSaleCost` puts the units back at what they cost when sold,
+///   so a return reverses the sale without moving the average, even if
+///   purchase costs changed in between.
+/// - `WeightedAverage` records the current average; the average itself stays
+///   put, but units sold at an older cost come back at today's cost.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RestockCostBasis {
+    /// Unit cost captured on the original sale line (preferred)
+    #[default]
+    OriginalSaleCost,
+    /// Current weighted-average cost of the stock record
+    WeightedAverage,
+}
+
+impl RestockCostBasis {
+    /// Returns all available cost bases
+    pub fn all() -> &'static [RestockCostBasis] {
+        &[
+            RestockCostBasis::OriginalSaleCost,
+            RestockCostBasis::WeightedAverage,
+        ]
+    }
+
+    /// Picks the unit cost for the restock movement.
+    ///
+    /// `sale_cost` is the cost captured on the sale line; a zero cost means it
+    /// was never captured, and the weighted average is used instead. `None`
+    /// means no cost is known, and the movement is recorded without one so it
+    /// does not count toward the average.
+    pub fn resolve(
+        &self,
+        sale_cost: Decimal,
+        weighted_average: Option<Decimal>,
+    ) -> Option<Decimal> {
+        match self {
+            RestockCostBasis::OriginalSaleCost if sale_cost > Decimal::ZERO => Some(sale_cost),
+            RestockCostBasis::OriginalSaleCost | RestockCostBasis::WeightedAverage => {
+                weighted_average
+            }
+        }
+    }
+}
+
+impl FromStr for RestockCostBasis {
+    type Err = SalesError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "original_sale_cost" | "sale_cost" | "original" => {
+                Ok(RestockCostBasis::OriginalSaleCost)
+            }
+            "weighted_average" | "average" => Ok(RestockCostBasis::WeightedAverage),
+            _ => Err(SalesError::InvalidRestockCostBasis),
+        }
+    }
+}
+
+impl fmt::Display for RestockCostBasis {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RestockCostBasis::OriginalSaleCost => write!(f, "original_sale_cost"),
+            RestockCostBasis::WeightedAverage => write!(f, "weighted_average"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    #[test]
+    fn test_from_str() {
+        assert_eq!(
+            RestockCostBasis::from_str("original_sale_cost").unwrap(),
+            RestockCostBasis::OriginalSaleCost
+        );
+        assert_eq!(
+            RestockCostBasis::from_str("WEIGHTED_AVERAGE").unwrap(),
+            RestockCostBasis::WeightedAverage
+        );
+        assert!(RestockCostBasis::from_str("fifo").is_err());
+    }
+
+    #[test]
+    fn test_display_round_trip() {
+        for basis in RestockCostBasis::all() {
+            assert_eq!(
+                RestockCostBasis::from_str(&basis.to_string()).unwrap(),
+                *basis
+            );
+        }
+    }
+
+    #[test]
+    fn test_original_sale_cost_basis() {
+        let basis = RestockCostBasis::OriginalSaleCost;
+        assert_eq!(basis.resolve(dec!(8), Some(dec!(10))), Some(dec!(8)));
+        // Sale line without a captured cost falls back to the average
+        assert_eq!(basis.resolve(Decimal::ZERO, Some(dec!(10))), Some(dec!(10)));
+        assert_eq!(basis.resolve(Decimal::ZERO, None), None);
+    }
+
+    #[test]
+    fn test_weighted_average_basis() {
+        let basis = RestockCostBasis::WeightedAverage;
+        assert_eq!(basis.resolve(dec!(8), Some(dec!(10))), Some(dec!(10)));
+        assert_eq!(basis.resolve(dec!(8), None), None);
+    }
+}
@@ -0,0 +1,249 @@
+//! SaleLineAllocation value object - a sale line's share of the sale totals
+
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+use crate::SalesError;
+use crate::domain::value_objects::SaleItemId;
+
+/// A sale line with the sale's discount distributed onto it.
+///
+/// Produced by `Sale::line_allocations`: the allocations of a sale add up to
+/// the sale totals exactly, so `Σ net_total == sale.total`. Returns are
+/// refunded from these figures, never from the pre-discount line price.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SaleLineAllocation {
+    pub sale_item_id: SaleItemId,
+    pub quantity: Decimal,
+    pub unit_price: Decimal,
+    /// Line amount before discounts (quantity × unit price)
+    pub subtotal: Decimal,
+    /// Line discount plus the line's share of any sale-level discount
+    pub discount_amount: Decimal,
+    pub tax_amount: Decimal,
+}
+
+/// Discount and tax to refund for a number of returned units
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ReturnAllocation {
+    pub discount_amount: Decimal,
+    pub tax_amount: Decimal,
+}
+
+impl SaleLineAllocation {
+    /// Amount the customer paid for the line
+    pub fn net_total(&self) -> Decimal {
+        self.subtotal - self.discount_amount + self.tax_amount
+    }
+
+    /// Discount and tax for returning `quantity` units when
+    /// `already_returned` units of the line were returned before.
+    ///
+    /// Amounts are prorated cumulatively and rounded to cents, so any
+    /// sequence of partial returns of the whole line adds up to exactly the
+    /// line's discount and tax.
+    pub fn for_return(
+        &self,
+        already_returned: Decimal,
+        quantity: Decimal,
+    ) -> Result<ReturnAllocation, SalesError> {
+        if quantity <= Decimal::ZERO {
+            return Err(SalesError::InvalidQuantity);
+        }
+        let returned_after = already_returned + quantity;
+        if returned_after > self.quantity {
+            return Err(SalesError::ReturnQuantityExceedsSaleQuantity);
+        }
+
+        Ok(ReturnAllocation {
+            discount_amount: self.cumulative(self.discount_amount, returned_after)
+                - self.cumulative(self.discount_amount, already_returned),
+            tax_amount: self.cumulative(self.tax_amount, returned_after)
+                - self.cumulative(self.tax_amount, already_returned),
+        })
+    }
+
+    /// Portion of `amount` attributable to the first `returned` units
+    fn cumulative(&self, amount: Decimal, returned: Decimal) -> Decimal {
+        if returned >= self.quantity {
+            amount
+        } else if returned <= Decimal::ZERO {
+            Decimal::ZERO
+        } else {
+            (amount * returned / self.quantity).round_dp(2)
+        }
+    }
+}
+
+/// Splits `total` across `weights` proportionally, rounding each share to
+/// cents. The rounding remainder goes to the heaviest weight so the shares
+/// add up to `total` exactly.
+pub(crate) fn allocate_proportionally(total: Decimal, weights: &[Decimal]) -> Vec<Decimal> {
+    let weight_sum: Decimal = weights.iter().sum();
+    if weights.is_empty() || weight_sum <= Decimal::ZERO {
+        return vec![Decimal::ZERO; weights.len()];
+    }
+
+    let mut shares: Vec<Decimal> = weights
+        .iter()
+        .map(|w| (total * w / weight_sum).round_dp(2))
+        .collect();
+
+    let heaviest = weights
+        .iter()
+        .enumerate()
+        .max_by(|a, b| a.1.cmp(b.1))
+        .map(|(i, _)| i)
+        .unwrap_or(0);
+    let others: Decimal = shares
+        .iter()
+        .enumerate()
+        .filter(|(i, _)| *i != heaviest)
+        .map(|(_, s)| *s)
+        .sum();
+    shares[heaviest] = total - others;
+
+    shares
+}
+
+/// A line's share of a sale-level discount and the tax on what remains
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct OrderDiscountShare {
+    pub discount_amount: Decimal,
+    pub tax_amount: Decimal,
+}
+
+/// Tax on a line worth `value` at `rate` percent. When the price is
+/// `inclusive` the tax is already part of `value` and is backed out of it.
+pub(crate) fn line_tax(value: Decimal, rate: Decimal, inclusive: bool) -> Decimal {
+    if inclusive {
+        value * rate / (Decimal::ONE_HUNDRED + rate)
+    } else {
+        value * rate / Decimal::ONE_HUNDRED
+    }
+}
+
+/// Splits a sale-level `discount` across lines given as (value, tax rate %,
+/// tax-inclusive) in proportion to their value, then computes each line's tax
+/// on its value less its share.
+///
+/// Tax is rounded to cents per line; the difference to the rounded total tax
+/// goes to the largest taxed line, so exempt lines never carry tax.
+pub(crate) fn allocate_order_discount(
+    discount: Decimal,
+    lines: &[(Decimal, Decimal, bool)],
+) -> Vec<OrderDiscountShare> {
+    let values: Vec<Decimal> = lines.iter().map(|(value, _, _)| *value).collect();
+    let discounts = allocate_proportionally(discount, &values);
+
+    let exact_taxes: Vec<Decimal> = lines
+        .iter()
+        .zip(&discounts)
+        .map(|((value, rate, inclusive), share)| line_tax(value - share, *rate, *inclusive))
+        .collect();
+    let mut taxes: Vec<Decimal> = exact_taxes.iter().map(|t| t.round_dp(2)).collect();
+
+    let remainder = exact_taxes.iter().sum::<Decimal>().round_dp(2) - taxes.iter().sum::<Decimal>();
+    let largest_taxed = lines
+        .iter()
+        .enumerate()
+        .filter(|(_, (_, rate, _))| *rate > Decimal::ZERO)
+        .max_by(|a, b| a.1.0.cmp(&b.1.0))
+        .map(|(i, _)| i);
+    if let Some(i) = largest_taxed {
+        taxes[i] += remainder;
+    }
+
+    discounts
+        .into_iter()
+        .zip(taxes)
+        .map(|(discount_amount, tax_amount)| OrderDiscountShare {
+            discount_amount,
+            tax_amount,
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    fn line(quantity: Decimal, discount: Decimal, tax: Decimal) -> SaleLineAllocation {
+        SaleLineAllocation {
+            sale_item_id: SaleItemId::new(),
+            quantity,
+            unit_price: dec!(10),
+            subtotal: quantity * dec!(10),
+            discount_amount: discount,
+            tax_amount: tax,
+        }
+    }
+
+    #[test]
+    fn test_allocate_proportionally_sums_exactly() {
+        let shares = allocate_proportionally(dec!(10), &[dec!(1), dec!(1), dec!(1)]);
+        assert_eq!(shares.iter().copied().sum::<Decimal>(), dec!(10));
+        assert_eq!(shares[1], dec!(3.33));
+
+        let shares = allocate_proportionally(dec!(5), &[dec!(0), dec!(0)]);
+        assert_eq!(shares, vec![Decimal::ZERO, Decimal::ZERO]);
+    }
+
+    #[test]
+    fn test_order_discount_tax_remainder_goes_to_largest_line() {
+        // Discounted bases 0.10, 0.10, 0.90 at 15%: per-line tax rounds to
+        // 0.02 + 0.02 + 0.14 = 0.18 but the exact 0.165 rounds to 0.16
+        let shares = allocate_order_discount(
+            dec!(0.11),
+            &[
+                (dec!(0.11), dec!(15), false),
+                (dec!(0.11), dec!(15), false),
+                (dec!(0.99), dec!(15), false),
+            ],
+        );
+        let discounts: Vec<Decimal> = shares.iter().map(|s| s.discount_amount).collect();
+        assert_eq!(discounts, vec![dec!(0.01), dec!(0.01), dec!(0.09)]);
+        let taxes: Vec<Decimal> = shares.iter().map(|s| s.tax_amount).collect();
+        assert_eq!(taxes, vec![dec!(0.02), dec!(0.02), dec!(0.12)]);
+    }
+
+    #[test]
+    fn test_line_tax_inclusive_backs_tax_out_of_value() {
+        assert_eq!(line_tax(dec!(100), dec!(15), false), dec!(15));
+        assert_eq!(line_tax(dec!(115), dec!(15), true), dec!(15));
+        assert_eq!(line_tax(dec!(115), dec!(0), true), dec!(0));
+    }
+
+    #[test]
+    fn test_partial_returns_add_up_to_line() {
+        let l = line(dec!(3), dec!(10), dec!(4.50));
+
+        let first = l.for_return(dec!(0), dec!(1)).unwrap();
+        let second = l.for_return(dec!(1), dec!(1)).unwrap();
+        let third = l.for_return(dec!(2), dec!(1)).unwrap();
+
+        assert_eq!(first.discount_amount, dec!(3.33));
+        assert_eq!(
+            first.discount_amount + second.discount_amount + third.discount_amount,
+            dec!(10)
+        );
+        assert_eq!(
+            first.tax_amount + second.tax_amount + third.tax_amount,
+            dec!(4.50)
+        );
+    }
+
+    #[test]
+    fn test_return_beyond_sold_quantity() {
+        let l = line(dec!(2), dec!(0), dec!(0));
+        assert!(matches!(
+            l.for_return(dec!(1), dec!(2)),
+            Err(SalesError::ReturnQuantityExceedsSaleQuantity)
+        ));
+        assert!(matches!(
+            l.for_return(dec!(0), dec!(0)),
+            Err(SalesError::InvalidQuantity)
+        ));
+    }
+}
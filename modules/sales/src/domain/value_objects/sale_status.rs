@@ -11,6 +11,12 @@ use std::str::FromStr;
 pub enum SaleStatus {
     /// Initial state, sale in progress
     Draft,
+    /// Sale in progress whose tenders don't cover its total yet
+    PartiallyPaid,
+    /// Sale suspended by the cashier, to be resumed later
+    Parked,
+    /// Items held for the customer while installments are paid
+    Layaway,
     /// Sale completed and invoiced
     Completed,
     /// Sale voided before completion
@@ -24,6 +30,9 @@ impl SaleStatus {
     pub fn all() -> &'static [SaleStatus] {
         &[
             SaleStatus::Draft,
+            SaleStatus::PartiallyPaid,
+            SaleStatus::Parked,
+            SaleStatus::Layaway,
             SaleStatus::Completed,
             SaleStatus::Voided,
             SaleStatus::Returned,
@@ -32,7 +41,7 @@ impl SaleStatus {
 
     /// Returns true if the sale can be edited
     pub fn is_editable(&self) -> bool {
-        matches!(self, SaleStatus::Draft)
+        matches!(self, SaleStatus::Draft | SaleStatus::PartiallyPaid)
     }
 
     /// Returns true if the sale is in a final state
@@ -43,14 +52,33 @@ impl SaleStatus {
         )
     }
 
+    /// Returns true if payments can be taken against the sale
+    pub fn accepts_payments(&self) -> bool {
+        self.is_editable() || matches!(self, SaleStatus::Layaway)
+    }
+
     /// Returns true if the sale can be completed
     pub fn can_complete(&self) -> bool {
-        matches!(self, SaleStatus::Draft)
+        matches!(
+            self,
+            SaleStatus::Draft | SaleStatus::PartiallyPaid | SaleStatus::Layaway
+        )
     }
 
     /// Returns true if the sale can be voided
     pub fn can_void(&self) -> bool {
-        matches!(self, SaleStatus::Draft)
+        matches!(
+            self,
+            SaleStatus::Draft
+                | SaleStatus::PartiallyPaid
+                | SaleStatus::Parked
+                | SaleStatus::Layaway
+        )
+    }
+
+    /// Returns true if the sale can be parked
+    pub fn can_park(&self) -> bool {
+        matches!(self, SaleStatus::Draft | SaleStatus::PartiallyPaid)
     }
 
     /// Returns true if a return can be created for this sale
@@ -64,6 +92,22 @@ impl SaleStatus {
             // From Draft
             (SaleStatus::Draft, SaleStatus::Completed) => true,
             (SaleStatus::Draft, SaleStatus::Voided) => true,
+            (SaleStatus::Draft, SaleStatus::PartiallyPaid) => true,
+            (SaleStatus::Draft, SaleStatus::Parked) => true,
+            (SaleStatus::Draft, SaleStatus::Layaway) => true,
+            // From PartiallyPaid
+            (SaleStatus::PartiallyPaid, SaleStatus::Draft) => true,
+            (SaleStatus::PartiallyPaid, SaleStatus::Completed) => true,
+            (SaleStatus::PartiallyPaid, SaleStatus::Voided) => true,
+            (SaleStatus::PartiallyPaid, SaleStatus::Parked) => true,
+            (SaleStatus::PartiallyPaid, SaleStatus::Layaway) => true,
+            // From Parked
+            (SaleStatus::Parked, SaleStatus::Draft) => true,
+            (SaleStatus::Parked, SaleStatus::PartiallyPaid) => true,
+            (SaleStatus::Parked, SaleStatus::Voided) => true,
+            // From Layaway
+            (SaleStatus::Layaway, SaleStatus::Completed) => true,
+            (SaleStatus::Layaway, SaleStatus::Voided) => true,
             // From Completed
             (SaleStatus::Completed, SaleStatus::Returned) => true,
             // All other transitions are invalid
@@ -78,6 +122,9 @@ impl FromStr for SaleStatus {
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         match s.to_lowercase().as_str() {
             "draft" => Ok(SaleStatus::Draft),
+            "partially_paid" => Ok(SaleStatus::PartiallyPaid),
+            "parked" | "held" => Ok(SaleStatus::Parked),
+            "layaway" => Ok(SaleStatus::Layaway),
             "completed" => Ok(SaleStatus::Completed),
             "voided" | "void" => Ok(SaleStatus::Voided),
             "returned" => Ok(SaleStatus::Returned),
@@ -90,6 +137,9 @@ impl fmt::Display for SaleStatus {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
             SaleStatus::Draft => write!(f, "draft"),
+            SaleStatus::PartiallyPaid => write!(f, "partially_paid"),
+            SaleStatus::Parked => write!(f, "parked"),
+            SaleStatus::Layaway => write!(f, "layaway"),
             SaleStatus::Completed => write!(f, "completed"),
             SaleStatus::Voided => write!(f, "voided"),
             SaleStatus::Returned => write!(f, "returned"),
@@ -104,6 +154,11 @@ mod tests {
     #[test]
     fn test_from_str() {
         assert_eq!(SaleStatus::from_str("draft").unwrap(), SaleStatus::Draft);
+        assert_eq!(
+            SaleStatus::from_str("partially_paid").unwrap(),
+            SaleStatus::PartiallyPaid
+        );
+        assert_eq!(SaleStatus::from_str("parked").unwrap(), SaleStatus::Parked);
         assert_eq!(
             SaleStatus::from_str("completed").unwrap(),
             SaleStatus::Completed
@@ -130,6 +185,24 @@ mod tests {
         assert!(SaleStatus::Draft.can_void());
         assert!(!SaleStatus::Draft.is_final());
 
+        // PartiallyPaid state
+        assert!(SaleStatus::PartiallyPaid.is_editable());
+        assert!(SaleStatus::PartiallyPaid.can_void());
+        assert!(!SaleStatus::PartiallyPaid.is_final());
+
+        // Parked state
+        assert!(!SaleStatus::Parked.is_editable());
+        assert!(!SaleStatus::Parked.can_complete());
+        assert!(SaleStatus::Parked.can_void());
+        assert!(!SaleStatus::Parked.can_park());
+
+        // Layaway state
+        assert!(!SaleStatus::Layaway.is_editable());
+        assert!(SaleStatus::Layaway.accepts_payments());
+        assert!(SaleStatus::Layaway.can_complete());
+        assert!(SaleStatus::Layaway.can_void());
+        assert!(!SaleStatus::Layaway.can_park());
+
         // Completed state
         assert!(!SaleStatus::Completed.is_editable());
         assert!(SaleStatus::Completed.can_return());
@@ -146,6 +219,16 @@ mod tests {
         assert!(SaleStatus::Draft.can_transition_to(SaleStatus::Completed));
         assert!(SaleStatus::Draft.can_transition_to(SaleStatus::Voided));
         assert!(!SaleStatus::Draft.can_transition_to(SaleStatus::Returned));
+        assert!(SaleStatus::Draft.can_transition_to(SaleStatus::PartiallyPaid));
+
+        // From PartiallyPaid
+        assert!(SaleStatus::PartiallyPaid.can_transition_to(SaleStatus::Completed));
+        assert!(!SaleStatus::PartiallyPaid.can_transition_to(SaleStatus::Returned));
+        assert!(SaleStatus::PartiallyPaid.can_transition_to(SaleStatus::Parked));
+
+        // From Parked
+        assert!(SaleStatus::Parked.can_transition_to(SaleStatus::Draft));
+        assert!(!SaleStatus::Parked.can_transition_to(SaleStatus::Completed));
 
         // From Completed
         assert!(SaleStatus::Completed.can_transition_to(SaleStatus::Returned));
@@ -0,0 +1,24 @@
+//! SaleTaxBreakdown value object - the tax a sale charged under one rate
+
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+use crate::domain::value_objects::TaxRuleId;
+
+/// The sale's lines taxed alike, grouped by the rule and rate applied.
+///
+/// Produced by `Sale::tax_breakdown`: the groups' `tax_amount` add up to the
+/// sale's tax. `taxable_amount` is the lines' value net of discounts and
+/// without the tax, also for tax-inclusive lines.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SaleTaxBreakdown {
+    /// Rule that set the rate, `None` for lines taxed at their own rate
+    pub tax_rule_id: Option<TaxRuleId>,
+    pub jurisdiction: Option<String>,
+    pub tax_rate: Decimal,
+    pub tax_inclusive: bool,
+    /// The customer is exempt from the rule's tax on these lines
+    pub tax_exempt: bool,
+    pub taxable_amount: Decimal,
+    pub tax_amount: Decimal,
+}
@@ -0,0 +1,59 @@
+//! ScaleValueKind enum - what the value embedded in a scale label measures
+
+use crate::SalesError;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
+
+/// Value a scale prints into its labels next to the item code
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ScaleValueKind {
+    /// Weight in the product's unit of measure; the price is derived from it
+    Weight,
+    /// Price of the weighed item; the quantity is derived from it
+    Price,
+}
+
+impl ScaleValueKind {
+    /// Returns all available kinds
+    pub fn all() -> &'static [ScaleValueKind] {
+        &[ScaleValueKind::Weight, ScaleValueKind::Price]
+    }
+}
+
+impl FromStr for ScaleValueKind {
+    type Err = SalesError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "weight" => Ok(ScaleValueKind::Weight),
+            "price" => Ok(ScaleValueKind::Price),
+            _ => Err(SalesError::InvalidWeightBarcodeFormat(
+                "value_kind must be 'weight' or 'price'".to_string(),
+            )),
+        }
+    }
+}
+
+impl fmt::Display for ScaleValueKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ScaleValueKind::Weight => write!(f, "weight"),
+            ScaleValueKind::Price => write!(f, "price"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_display_round_trip() {
+        for kind in ScaleValueKind::all() {
+            assert_eq!(ScaleValueKind::from_str(&kind.to_string()).unwrap(), *kind);
+        }
+        assert!(ScaleValueKind::from_str("count").is_err());
+    }
+}
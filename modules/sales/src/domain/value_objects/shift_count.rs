@@ -0,0 +1,27 @@
+//! ShiftCount value object - what a cashier counted when closing a shift
+
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+/// Amounts counted per payment method when closing a shift.
+///
+/// Cash is the drawer count. Card and other tenders are optional, typically
+/// the terminal's settlement batch and the voucher/transfer slips; methods
+/// left uncounted are not reconciled.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ShiftCount {
+    pub cash: Decimal,
+    pub card: Option<Decimal>,
+    pub other: Option<Decimal>,
+}
+
+impl ShiftCount {
+    /// A count of the cash drawer only
+    pub fn cash(cash: Decimal) -> Self {
+        Self {
+            cash,
+            card: None,
+            other: None,
+        }
+    }
+}
@@ -36,6 +36,12 @@ impl ShiftStatus {
         matches!(self, ShiftStatus::Open)
     }
 
+    /// Returns true if the shift can be reopened (manager correction only,
+    /// outside the normal lifecycle checked by `can_transition_to`)
+    pub fn can_reopen(&self) -> bool {
+        matches!(self, ShiftStatus::Closed)
+    }
+
     /// Validates transition from current status to new status
     pub fn can_transition_to(&self, new_status: ShiftStatus) -> bool {
         matches!((self, new_status), (ShiftStatus::Open, ShiftStatus::Closed))
@@ -92,6 +98,8 @@ mod tests {
         assert!(ShiftStatus::Closed.is_closed());
         assert!(!ShiftStatus::Closed.is_open());
         assert!(!ShiftStatus::Closed.can_close());
+        assert!(ShiftStatus::Closed.can_reopen());
+        assert!(!ShiftStatus::Open.can_reopen());
     }
 
     #[test]
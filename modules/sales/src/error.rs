@@ -3,6 +3,7 @@
 //! This module defines all error types that can occur during sales operations.
 //! Errors are categorized by domain area (customers, sales, payments, carts, shifts, returns).
 
+use chrono::NaiveDate;
 use thiserror::Error;
 use uuid::Uuid;
 
@@ -32,6 +33,43 @@ pub enum SalesError {
     #[error("Customer is not active: {0}")]
     CustomerNotActive(Uuid),
 
+    /// No customer with the given code exists in the store.
+    #[error("Customer not found with code '{0}'")]
+    CustomerCodeNotFound(String),
+
+    /// The customer code prefix or padding is invalid.
+    #[error("Invalid customer code format: {0}")]
+    InvalidCustomerCodeFormat(String),
+
+    /// The credit limit is negative.
+    #[error("Credit limit must be non-negative")]
+    InvalidCreditLimit,
+
+    /// The customer has no credit limit and cannot buy on account.
+    #[error("Customer is not allowed to buy on account: {0}")]
+    CustomerCreditNotAllowed(Uuid),
+
+    /// Charging the amount would take the account over its credit limit.
+    #[error("Credit limit exceeded: {0} available")]
+    CreditLimitExceeded(rust_decimal::Decimal),
+
+    /// On-account payments need a sale with a customer.
+    #[error("On-account payments require a customer")]
+    OnAccountRequiresCustomer,
+
+    /// Store credit can only be spent or refunded to on a sale with a
+    /// customer.
+    #[error("Store credit requires a customer")]
+    StoreCreditRequiresCustomer,
+
+    /// Spending store credit beyond the customer's balance.
+    #[error("Insufficient store credit: {0} available")]
+    InsufficientStoreCredit(rust_decimal::Decimal),
+
+    /// The statement period ends before it starts.
+    #[error("Statement period start must not be after its end")]
+    InvalidStatementPeriod,
+
     // -------------------------------------------------------------------------
     // Cashier Shift errors
     // -------------------------------------------------------------------------
@@ -59,6 +97,27 @@ pub enum SalesError {
     #[error("Opening balance must be non-negative")]
     InvalidOpeningBalance,
 
+    /// Only a closed shift can be reopened.
+    #[error("Shift is not closed")]
+    ShiftNotClosed,
+
+    /// The shift was closed too long ago to be reopened.
+    #[error("Shift was closed more than {0} minutes ago and can no longer be reopened")]
+    ShiftReopenWindowExpired(i64),
+
+    /// The shift has been finalized (cash deposited) and cannot be reopened.
+    #[error("Shift has been finalized and cannot be reopened")]
+    ShiftFinalized,
+
+    /// Reopening a shift requires a reason.
+    #[error("A reason is required to reopen a shift")]
+    ShiftReopenReasonRequired,
+
+    /// The shift's closing count is off by more than the allowed variance
+    /// and no variance reason was given.
+    #[error("Closing count is off by {0}; a variance reason is required")]
+    ShiftVarianceReasonRequired(rust_decimal::Decimal),
+
     // -------------------------------------------------------------------------
     // Sale errors
     // -------------------------------------------------------------------------
@@ -82,6 +141,10 @@ pub enum SalesError {
     #[error("Sale is not fully paid")]
     SaleNotFullyPaid,
 
+    /// The sale is dated in a closed inventory period of its store.
+    #[error("The period from {0} to {1} is closed")]
+    PeriodClosed(NaiveDate, NaiveDate),
+
     /// The sale has already been completed.
     #[error("Sale has already been completed")]
     SaleAlreadyCompleted,
@@ -90,6 +153,10 @@ pub enum SalesError {
     #[error("Sale has already been voided")]
     SaleAlreadyVoided,
 
+    /// Only a parked sale can be resumed.
+    #[error("Sale is not parked")]
+    SaleNotParked,
+
     /// POS sale requires an open shift.
     #[error("POS sale requires an open shift")]
     PosRequiresOpenShift,
@@ -102,6 +169,20 @@ pub enum SalesError {
     #[error("POS sale requires a cashier")]
     PosRequiresCashier,
 
+    /// A quick sale names neither a barcode nor a product.
+    #[error("Quick sale requires a barcode or a product id")]
+    QuickSaleProductRequired,
+
+    /// The sale is too complex for a quick sale and must go through the
+    /// regular checkout steps.
+    #[error("Quick sale not available: {0}")]
+    QuickSaleNotSupported(String),
+
+    /// A quick sale is numbered from its terminal's CAI range, so the
+    /// client cannot pick its invoice number.
+    #[error("Quick sale invoice numbers are drawn from the terminal's CAI range")]
+    InvoiceNumberNotAllowed,
+
     // -------------------------------------------------------------------------
     // Sale Item errors
     // -------------------------------------------------------------------------
@@ -121,6 +202,50 @@ pub enum SalesError {
     #[error("Insufficient stock for product: {0}")]
     InsufficientStock(Uuid),
 
+    /// The stock record changed while the sale was being recorded.
+    #[error("Stock was modified by another process, retry the sale")]
+    StockConflict,
+
+    /// A recipe ingredient of the sold product is out of stock, as are all
+    /// its substitutes. Carries the ingredient's name.
+    #[error("Ingredient unavailable: {0}")]
+    IngredientUnavailable(String),
+
+    // -------------------------------------------------------------------------
+    // Serial number errors
+    // -------------------------------------------------------------------------
+    /// A serial-tracked line is missing the serials of its units.
+    #[error("Serial numbers required for serial-tracked product: {0}")]
+    SerialNumbersRequired(Uuid),
+
+    /// A serial number is blank or too long.
+    #[error("Invalid serial number")]
+    InvalidSerialNumber,
+
+    /// The number of serials does not match the (whole) line quantity.
+    #[error("Number of serial numbers must equal the quantity")]
+    SerialCountMismatch,
+
+    /// The same serial appears more than once on the sale or return.
+    #[error("Serial number '{0}' is listed more than once")]
+    DuplicateSerialNumber(String),
+
+    /// The serial is not in stock for the product at the store.
+    #[error("Serial number '{0}' is not available for sale")]
+    SerialNotAvailable(String),
+
+    /// Serials were given for a product that is not serial-tracked.
+    #[error("Product is not serial-tracked: {0}")]
+    ProductNotSerialized(Uuid),
+
+    /// The returned serial was not sold on the original sale line.
+    #[error("Serial number '{0}' was not sold on this sale line")]
+    SerialNotOnSaleLine(String),
+
+    /// No sold unit carries the serial number.
+    #[error("No sale found for serial number '{0}'")]
+    SerialSaleNotFound(String),
+
     // -------------------------------------------------------------------------
     // Payment errors
     // -------------------------------------------------------------------------
@@ -136,6 +261,10 @@ pub enum SalesError {
     #[error("Payment exceeds remaining balance")]
     PaymentExceedsBalance,
 
+    /// A payment must have at least one tender.
+    #[error("Payment requires at least one allocation")]
+    NoPaymentAllocations,
+
     /// The payment has already been refunded.
     #[error("Payment has already been refunded")]
     PaymentAlreadyRefunded,
@@ -202,6 +331,10 @@ pub enum SalesError {
     #[error("Credit note has already been applied")]
     CreditNoteAlreadyApplied,
 
+    /// Only approved or applied credit notes have a printable document.
+    #[error("Credit note has not been approved")]
+    CreditNoteNotApproved,
+
     /// Return quantity exceeds original sale quantity.
     #[error("Return quantity exceeds original sale quantity")]
     ReturnQuantityExceedsSaleQuantity,
@@ -210,6 +343,17 @@ pub enum SalesError {
     #[error("Cannot create return for incomplete sale")]
     SaleNotCompleted,
 
+    /// A return without a receipt was attempted without a manager override.
+    #[error("Returns without a receipt require a manager override")]
+    ReceiptlessReturnNotAuthorized,
+
+    /// A return line named the wrong source: receipted returns take a line
+    /// of the original sale, receiptless returns take a product.
+    #[error(
+        "Return lines must name an original sale line, or a product on returns without a receipt"
+    )]
+    InvalidReturnLine,
+
     // -------------------------------------------------------------------------
     // Order (E-commerce) errors
     // -------------------------------------------------------------------------
@@ -229,6 +373,52 @@ pub enum SalesError {
     #[error("Order has not been shipped")]
     OrderNotShipped,
 
+    /// Order is not waiting at the store for pickup.
+    #[error("Order is not ready for pickup")]
+    OrderNotReadyForPickup,
+
+    // -------------------------------------------------------------------------
+    // Pickup errors
+    // -------------------------------------------------------------------------
+    /// No pickup has been set up for the order.
+    #[error("Pickup not found for order: {0}")]
+    PickupNotFound(Uuid),
+
+    /// The code presented at the counter does not match the order.
+    #[error("Invalid pickup code")]
+    InvalidPickupCode,
+
+    /// The pickup was already collected, expired or cancelled.
+    #[error("Pickup is no longer open")]
+    PickupNotOpen,
+
+    /// The fiscal document for the order could not be issued.
+    #[error("Failed to issue invoice: {0}")]
+    InvoiceIssueFailed(String),
+
+    /// The customer could not be notified.
+    #[error("Failed to notify customer: {0}")]
+    NotificationFailed(String),
+
+    // -------------------------------------------------------------------------
+    // Idempotency errors
+    // -------------------------------------------------------------------------
+    /// An idempotency key is blank or too long.
+    #[error("Invalid idempotency key")]
+    InvalidIdempotencyKey,
+
+    /// The terminal already used the key for another sale or operation.
+    #[error("Idempotency key '{0}' was already used for a different request")]
+    IdempotencyKeyReused(String),
+
+    /// Idempotency keys are scoped per terminal; the sale has none.
+    #[error("Idempotency keys require a sale rung up at a terminal")]
+    IdempotencyKeyRequiresTerminal,
+
+    /// The provided idempotent operation is not recognized.
+    #[error("Invalid idempotent operation")]
+    InvalidIdempotentOperation,
+
     // -------------------------------------------------------------------------
     // Workflow errors
     // -------------------------------------------------------------------------
@@ -247,6 +437,10 @@ pub enum SalesError {
     #[error("Invalid unit of measure")]
     InvalidUnitOfMeasure,
 
+    /// The `after_cursor` of a list query was not issued by this API.
+    #[error(transparent)]
+    InvalidPageCursor(#[from] common::InvalidPageCursor),
+
     /// The provided sale status is not recognized.
     #[error("Invalid sale status")]
     InvalidSaleStatus,
@@ -255,10 +449,26 @@ pub enum SalesError {
     #[error("Invalid order status")]
     InvalidOrderStatus,
 
+    /// The provided pickup status is not recognized.
+    #[error("Invalid pickup status")]
+    InvalidPickupStatus,
+
+    /// The provided gift card status is not recognized.
+    #[error("Invalid gift card status")]
+    InvalidGiftCardStatus,
+
+    /// The provided layaway status is not recognized.
+    #[error("Invalid layaway status")]
+    InvalidLayawayStatus,
+
     /// The provided payment method is not recognized.
     #[error("Invalid payment method")]
     InvalidPaymentMethod,
 
+    /// Invalid customer account entry type string.
+    #[error("Invalid account entry type")]
+    InvalidAccountEntryType,
+
     /// The provided payment status is not recognized.
     #[error("Invalid payment status")]
     InvalidPaymentStatus,
@@ -271,10 +481,18 @@ pub enum SalesError {
     #[error("Invalid customer type")]
     InvalidCustomerType,
 
+    /// The tax id does not match its country's format.
+    #[error("{0}")]
+    InvalidTaxId(String),
+
     /// The provided return reason is not recognized.
     #[error("Invalid return reason")]
     InvalidReturnReason,
 
+    /// The provided restock cost basis is not recognized.
+    #[error("Invalid restock cost basis")]
+    InvalidRestockCostBasis,
+
     /// The provided credit note status is not recognized.
     #[error("Invalid credit note status")]
     InvalidCreditNoteStatus,
@@ -283,6 +501,10 @@ pub enum SalesError {
     #[error("Discount percentage must be between 0 and 100")]
     InvalidDiscountPercentage,
 
+    /// Allocated discount must be between zero and the line subtotal.
+    #[error("Allocated discount must be between zero and the line subtotal")]
+    InvalidDiscountAmount,
+
     /// Tax rate must be non-negative.
     #[error("Tax rate must be non-negative")]
     InvalidTaxRate,
@@ -291,6 +513,18 @@ pub enum SalesError {
     #[error("Product not found: {0}")]
     ProductNotFound(Uuid),
 
+    /// No active product or variant has the scanned barcode.
+    #[error("No product found for barcode '{0}'")]
+    BarcodeNotFound(String),
+
+    /// The scanned barcode matches more than one sellable item; the cashier
+    /// has to pick one of the listed SKUs.
+    #[error("Barcode '{barcode}' matches several items: {}", candidates.join(", "))]
+    AmbiguousBarcode {
+        barcode: String,
+        candidates: Vec<String>,
+    },
+
     /// Store not found.
     #[error("Store not found: {0}")]
     StoreNotFound(Uuid),
@@ -307,6 +541,14 @@ pub enum SalesError {
     #[error("No valid CAI available for terminal: {0}")]
     NoValidCai(Uuid),
 
+    /// The CAI of the terminal has expired.
+    #[error("CAI has expired for terminal: {0}")]
+    CaiExpired(Uuid),
+
+    /// The CAI range of the terminal has no invoice numbers left.
+    #[error("CAI range exhausted for terminal: {0}")]
+    CaiRangeExhausted(Uuid),
+
     // -------------------------------------------------------------------------
     // Reservation errors
     // -------------------------------------------------------------------------
@@ -353,6 +595,157 @@ pub enum SalesError {
     #[error("Invalid promotion status")]
     InvalidPromotionStatus,
 
+    // -------------------------------------------------------------------------
+    // Markdown errors
+    // -------------------------------------------------------------------------
+    /// The requested markdown was not found.
+    #[error("Markdown not found: {0}")]
+    MarkdownNotFound(Uuid),
+
+    /// The markdown rule configuration is invalid.
+    #[error("Invalid markdown rule: {0}")]
+    InvalidMarkdownRule(String),
+
+    /// Only active markdowns can be reversed.
+    #[error("Markdown is not active and cannot be reversed")]
+    MarkdownNotReversible,
+
+    /// Invalid markdown reason string.
+    #[error("Invalid markdown reason")]
+    InvalidMarkdownReason,
+
+    /// Invalid markdown status string.
+    #[error("Invalid markdown status")]
+    InvalidMarkdownStatus,
+
+    // -------------------------------------------------------------------------
+    // Price tier errors
+    // -------------------------------------------------------------------------
+    /// The price tier configuration is invalid.
+    #[error("Invalid price tier: {0}")]
+    InvalidPriceTier(String),
+
+    // -------------------------------------------------------------------------
+    // Tax rule errors
+    // -------------------------------------------------------------------------
+    /// The tax rule configuration is invalid.
+    #[error("Invalid tax rule: {0}")]
+    InvalidTaxRule(String),
+
+    // -------------------------------------------------------------------------
+    // Price list errors
+    // -------------------------------------------------------------------------
+    /// The requested price list was not found.
+    #[error("Price list not found: {0}")]
+    PriceListNotFound(Uuid),
+
+    /// The price list configuration is invalid.
+    #[error("Invalid price list: {0}")]
+    InvalidPriceList(String),
+
+    /// Another active price list of the store already covers the customer
+    /// type, or is already the store's default list.
+    #[error("A price list is already assigned to '{0}' at this store")]
+    PriceListAlreadyAssigned(String),
+
+    // -------------------------------------------------------------------------
+    // Gift card errors
+    // -------------------------------------------------------------------------
+    /// No gift card has the given code.
+    #[error("Gift card not found: {0}")]
+    GiftCardNotFound(String),
+
+    /// Another gift card already has the code.
+    #[error("Gift card code '{0}' already exists")]
+    DuplicateGiftCardCode(String),
+
+    /// Gift card codes are 6 to 32 letters, digits or dashes.
+    #[error("Invalid gift card code: must be 6-32 letters, digits or dashes")]
+    InvalidGiftCardCode,
+
+    /// A gift card must be issued for a positive amount.
+    #[error("Gift card amount must be positive")]
+    InvalidGiftCardAmount,
+
+    /// The card was sold on a sale that is not completed yet.
+    #[error("Gift card is not active")]
+    GiftCardNotActive,
+
+    /// The card has no balance left.
+    #[error("Gift card has no balance left")]
+    GiftCardDepleted,
+
+    /// The card's balance is less than the amount being spent.
+    #[error("Insufficient gift card balance")]
+    InsufficientGiftCardBalance,
+
+    /// A gift card tender did not say which card pays.
+    #[error("Gift card payment requires a gift card code")]
+    GiftCardCodeRequired,
+
+    /// Gift cards pay in their own currency, which must be the sale's.
+    #[error("Gift card currency does not match the sale currency")]
+    GiftCardCurrencyMismatch,
+
+    /// Selling a gift card on a sale needs the catalog product that
+    /// represents gift cards.
+    #[error("Selling a gift card on a sale requires a gift card product")]
+    GiftCardProductRequired,
+
+    // -------------------------------------------------------------------------
+    // Layaway errors
+    // -------------------------------------------------------------------------
+    /// The sale is not a layaway sale.
+    #[error("Layaway not found for sale: {0}")]
+    LayawayNotFound(Uuid),
+
+    /// Layaway terms are out of range.
+    #[error("Invalid layaway terms: {0}")]
+    InvalidLayawayTerms(String),
+
+    /// Items are held for a customer, so a layaway needs one.
+    #[error("Layaway requires a customer")]
+    LayawayRequiresCustomer,
+
+    /// A restocking fee can't be negative or more than was paid.
+    #[error("Restocking fee must be between zero and the amount paid")]
+    InvalidRestockingFee,
+
+    /// Layaway sales are cancelled through their layaway, not voided.
+    #[error("Sale is on layaway; cancel the layaway instead")]
+    SaleOnLayaway,
+
+    // -------------------------------------------------------------------------
+    // Exchange rate errors
+    // -------------------------------------------------------------------------
+    /// Exchange rates must be greater than zero.
+    #[error("Exchange rate must be greater than zero")]
+    InvalidExchangeRate,
+
+    /// The store has no exchange rate recorded for the currency.
+    #[error("No exchange rate recorded for {0}")]
+    ExchangeRateNotFound(String),
+
+    // -------------------------------------------------------------------------
+    // Weight barcode errors
+    // -------------------------------------------------------------------------
+    /// The scale label layout is invalid.
+    #[error("Invalid weight barcode format: {0}")]
+    InvalidWeightBarcodeFormat(String),
+
+    /// Another of the store's scale label formats uses an overlapping prefix.
+    #[error("A weight barcode format already uses a prefix overlapping '{0}'")]
+    WeightBarcodePrefixConflict(String),
+
+    /// Weight barcode format not found.
+    #[error("Weight barcode format not found: {0}")]
+    WeightBarcodeFormatNotFound(Uuid),
+
+    /// A price-embedded label names a product without a unit price, so the
+    /// weight can't be derived.
+    #[error("Product {0} has no unit price to derive the weighed quantity from")]
+    ScaleLabelUnpriced(Uuid),
+
     // -------------------------------------------------------------------------
     // Database errors
     // -------------------------------------------------------------------------
@@ -367,6 +760,14 @@ pub enum SalesError {
     // -------------------------------------------------------------------------
     // General errors
     // -------------------------------------------------------------------------
+    /// The caller lacks a permission the operation requires.
+    #[error("Missing required permissions: {}", missing.join(", "))]
+    Forbidden { missing: Vec<String> },
+
+    /// The caller is not assigned to the store whose data was requested.
+    #[error("Store not assigned to the user: {0}")]
+    StoreAccessDenied(Uuid),
+
     /// The requested functionality is not yet implemented.
     #[error("Not implemented")]
     NotImplemented,
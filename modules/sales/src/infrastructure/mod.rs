@@ -1,6 +1,10 @@
 //! Infrastructure layer for the sales module.
 //!
 //! This module contains implementations of repository traits
-//! using PostgreSQL as the persistence layer.
+//! using PostgreSQL as the persistence layer, plus the outbound gateways
+//! the order workflow calls into.
 
+pub mod order_gateways;
 pub mod persistence;
+
+pub use order_gateways::{OrderInvoiceIssuer, OrderNotifier};
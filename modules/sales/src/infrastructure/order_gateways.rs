@@ -0,0 +1,33 @@
+//! Outbound gateways used by the pickup workflow.
+//!
+//! - `OrderNotifier` — tells the customer their order is waiting at the
+//!   store (delegates to `notifications::SendNotificationUseCase`).
+//! - `OrderInvoiceIssuer` — issues the fiscal document when the order is
+//!   collected (delegates to `fiscal::GenerateInvoiceUseCase`).
+//!
+//! Both modules depend on this one, so the concrete implementations are
+//! wired up in the api-gateway crate.
+
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::SalesError;
+use crate::domain::entities::{Customer, OrderPickup, Sale};
+
+#[async_trait]
+pub trait OrderNotifier: Send + Sync {
+    /// Sends the customer the pickup code and how long the order is held.
+    async fn notify_ready_for_pickup(
+        &self,
+        customer: &Customer,
+        sale: &Sale,
+        pickup: &OrderPickup,
+    ) -> Result<(), SalesError>;
+}
+
+#[async_trait]
+pub trait OrderInvoiceIssuer: Send + Sync {
+    /// Issues the invoice for `sale`, or returns the existing one if the
+    /// order was already invoiced. Returns the `invoice_id`.
+    async fn issue_invoice(&self, sale: &Sale) -> Result<Uuid, SalesError>;
+}
@@ -3,13 +3,35 @@
 mod pg_cart_repository;
 mod pg_credit_note_repository;
 mod pg_customer_repository;
+mod pg_exchange_rate_repository;
+mod pg_gift_card_repository;
+mod pg_layaway_repository;
+mod pg_markdown_repository;
+mod pg_order_pickup_repository;
+mod pg_price_list_repository;
+mod pg_price_tier_repository;
 mod pg_promotion_repository;
+mod pg_quick_sale_repository;
+mod pg_sale_idempotency_repository;
 mod pg_sale_repository;
 mod pg_shift_repository;
+mod pg_tax_rule_repository;
+mod pg_weight_barcode_format_repository;
 
 pub use pg_cart_repository::PgCartRepository;
 pub use pg_credit_note_repository::PgCreditNoteRepository;
 pub use pg_customer_repository::PgCustomerRepository;
+pub use pg_exchange_rate_repository::PgExchangeRateRepository;
+pub use pg_gift_card_repository::PgGiftCardRepository;
+pub use pg_layaway_repository::PgLayawayRepository;
+pub use pg_markdown_repository::PgMarkdownRepository;
+pub use pg_order_pickup_repository::PgOrderPickupRepository;
+pub use pg_price_list_repository::PgPriceListRepository;
+pub use pg_price_tier_repository::PgPriceTierRepository;
 pub use pg_promotion_repository::PgPromotionRepository;
+pub use pg_quick_sale_repository::PgQuickSaleRepository;
+pub use pg_sale_idempotency_repository::PgSaleIdempotencyRepository;
 pub use pg_sale_repository::PgSaleRepository;
 pub use pg_shift_repository::PgShiftRepository;
+pub use pg_tax_rule_repository::PgTaxRuleRepository;
+pub use pg_weight_barcode_format_repository::PgWeightBarcodeFormatRepository;
@@ -49,9 +49,9 @@ impl CartRepository for PgCartRepository {
             INSERT INTO carts (
                 id, store_id, customer_id, session_id, currency, subtotal, discount_amount,
                 tax_amount, total, item_count, expires_at, converted_to_sale, notes,
-                created_at, updated_at
+                created_at, updated_at, last_activity_at
             )
-            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15, $16)
             "#,
         )
         .bind(cart.id().into_uuid())
@@ -69,6 +69,7 @@ impl CartRepository for PgCartRepository {
         .bind(cart.notes())
         .bind(cart.created_at())
         .bind(cart.updated_at())
+        .bind(cart.last_activity_at())
         .execute(&self.pool)
         .await?;
 
@@ -80,7 +81,7 @@ impl CartRepository for PgCartRepository {
             r#"
             SELECT id, store_id, customer_id, session_id, currency, subtotal, discount_amount,
                    tax_amount, total, item_count, expires_at, converted_to_sale, notes,
-                   created_at, updated_at
+                   created_at, updated_at, last_activity_at
             FROM carts
             WHERE id = $1
             "#,
@@ -100,7 +101,7 @@ impl CartRepository for PgCartRepository {
             r#"
             SELECT id, store_id, customer_id, session_id, currency, subtotal, discount_amount,
                    tax_amount, total, item_count, expires_at, converted_to_sale, notes,
-                   created_at, updated_at
+                   created_at, updated_at, last_activity_at
             FROM carts
             WHERE id = $1
             "#,
@@ -127,7 +128,7 @@ impl CartRepository for PgCartRepository {
             r#"
             SELECT id, store_id, customer_id, session_id, currency, subtotal, discount_amount,
                    tax_amount, total, item_count, expires_at, converted_to_sale, notes,
-                   created_at, updated_at
+                   created_at, updated_at, last_activity_at
             FROM carts
             WHERE store_id = $1 AND session_id = $2 AND converted_to_sale = FALSE AND expires_at > NOW()
             "#,
@@ -155,7 +156,7 @@ impl CartRepository for PgCartRepository {
             r#"
             SELECT id, store_id, customer_id, session_id, currency, subtotal, discount_amount,
                    tax_amount, total, item_count, expires_at, converted_to_sale, notes,
-                   created_at, updated_at
+                   created_at, updated_at, last_activity_at
             FROM carts
             WHERE store_id = $1 AND customer_id = $2 AND converted_to_sale = FALSE AND expires_at > NOW()
             "#,
@@ -180,7 +181,7 @@ impl CartRepository for PgCartRepository {
             UPDATE carts
             SET customer_id = $2, subtotal = $3, discount_amount = $4, tax_amount = $5,
                 total = $6, item_count = $7, expires_at = $8, converted_to_sale = $9,
-                notes = $10, updated_at = $11
+                notes = $10, updated_at = $11, last_activity_at = $12
             WHERE id = $1
             "#,
         )
@@ -195,6 +196,7 @@ impl CartRepository for PgCartRepository {
         .bind(cart.converted_to_sale())
         .bind(cart.notes())
         .bind(cart.updated_at())
+        .bind(cart.last_activity_at())
         .execute(&self.pool)
         .await?;
 
@@ -214,7 +216,7 @@ impl CartRepository for PgCartRepository {
             r#"
             SELECT id, store_id, customer_id, session_id, currency, subtotal, discount_amount,
                    tax_amount, total, item_count, expires_at, converted_to_sale, notes,
-                   created_at, updated_at
+                   created_at, updated_at, last_activity_at
             FROM carts
             WHERE expires_at < $1 AND converted_to_sale = FALSE
             "#,
@@ -235,6 +237,44 @@ impl CartRepository for PgCartRepository {
         Ok(result.rows_affected() as i64)
     }
 
+    async fn find_stale(
+        &self,
+        guest_inactive_before: DateTime<Utc>,
+        customer_inactive_before: DateTime<Utc>,
+        now: DateTime<Utc>,
+        limit: i64,
+    ) -> Result<Vec<Cart>, SalesError> {
+        let rows = sqlx::query_as::<_, CartRow>(
+            r#"
+            SELECT id, store_id, customer_id, session_id, currency, subtotal, discount_amount,
+                   tax_amount, total, item_count, expires_at, converted_to_sale, notes,
+                   created_at, updated_at, last_activity_at
+            FROM carts
+            WHERE converted_to_sale = FALSE
+              AND (
+                  expires_at < $3
+                  OR (customer_id IS NULL AND last_activity_at < $1)
+                  OR (customer_id IS NOT NULL AND last_activity_at < $2)
+              )
+            ORDER BY last_activity_at
+            LIMIT $4
+            "#,
+        )
+        .bind(guest_inactive_before)
+        .bind(customer_inactive_before)
+        .bind(now)
+        .bind(limit)
+        .fetch_all(&self.pool)
+        .await?;
+
+        let mut carts = Vec::with_capacity(rows.len());
+        for r in rows {
+            let items = self.load_items(CartId::from_uuid(r.id)).await?;
+            carts.push(r.into_cart(items)?);
+        }
+        Ok(carts)
+    }
+
     async fn save_item(&self, item: &CartItem) -> Result<(), SalesError> {
         sqlx::query(
             r#"
@@ -355,6 +395,7 @@ struct CartRow {
     notes: Option<String>,
     created_at: chrono::DateTime<chrono::Utc>,
     updated_at: chrono::DateTime<chrono::Utc>,
+    last_activity_at: chrono::DateTime<chrono::Utc>,
 }
 
 impl CartRow {
@@ -376,6 +417,7 @@ impl CartRow {
             self.notes,
             self.created_at,
             self.updated_at,
+            self.last_activity_at,
         ))
     }
 }
@@ -1,6 +1,7 @@
 //! PostgreSQL CreditNoteRepository implementation
 
 use async_trait::async_trait;
+use common::{DocumentNumberService, DocumentType};
 use sqlx::PgPool;
 use std::str::FromStr;
 
@@ -8,7 +9,8 @@ use crate::SalesError;
 use crate::domain::entities::{CreditNote, CreditNoteItem};
 use crate::domain::repositories::{CreditNoteFilter, CreditNoteRepository};
 use crate::domain::value_objects::{
-    CreditNoteId, CreditNoteItemId, CreditNoteStatus, ReturnReason, ReturnType, SaleId, SaleItemId,
+    CreditNoteDocument, CreditNoteId, CreditNoteItemId, CreditNoteStatus, CustomerId, ReturnReason,
+    ReturnType, SaleId, SaleItemId,
 };
 use identity::{StoreId, UserId};
 use inventory::{Currency, ProductId, UnitOfMeasure, VariantId};
@@ -16,11 +18,21 @@ use inventory::{Currency, ProductId, UnitOfMeasure, VariantId};
 /// PostgreSQL implementation of CreditNoteRepository
 pub struct PgCreditNoteRepository {
     pool: PgPool,
+    numbering: DocumentNumberService,
 }
 
 impl PgCreditNoteRepository {
     pub fn new(pool: PgPool) -> Self {
-        Self { pool }
+        Self {
+            pool,
+            numbering: DocumentNumberService::default(),
+        }
+    }
+
+    /// Replaces the default document number formats
+    pub fn with_numbering(mut self, numbering: DocumentNumberService) -> Self {
+        self.numbering = numbering;
+        self
     }
 
     async fn load_items(
@@ -31,10 +43,11 @@ impl PgCreditNoteRepository {
             r#"
             SELECT id, credit_note_id, original_sale_item_id, product_id, variant_id, sku,
                    description, return_quantity, unit_of_measure, unit_price, tax_rate,
-                   tax_amount, subtotal, total, restock, condition, notes, created_at, updated_at
+                   discount_amount, tax_amount, subtotal, total, restock, serial_numbers, condition, notes,
+                   created_at, updated_at
             FROM credit_note_items
             WHERE credit_note_id = $1
-            ORDER BY created_at
+            ORDER BY created_at, id
             "#,
         )
         .bind(credit_note_id.into_uuid())
@@ -56,15 +69,15 @@ impl CreditNoteRepository for PgCreditNoteRepository {
                 tax_amount, total, refund_method, refunded_amount, created_by_id,
                 submitted_by_id, submitted_at, approved_by_id, approved_at, applied_by_id,
                 applied_at, cancelled_by_id, cancelled_at, cancellation_reason, notes,
-                created_at, updated_at
+                created_at, updated_at, discount_amount, customer_id
             )
-            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15, $16, $17, $18, $19, $20, $21, $22, $23, $24, $25, $26, $27, $28)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15, $16, $17, $18, $19, $20, $21, $22, $23, $24, $25, $26, $27, $28, $29, $30)
             "#,
         )
         .bind(credit_note.id().into_uuid())
         .bind(credit_note.credit_note_number())
         .bind(credit_note.store_id().into_uuid())
-        .bind(credit_note.original_sale_id().into_uuid())
+        .bind(credit_note.original_sale_id().map(|s| s.into_uuid()))
         .bind(credit_note.original_invoice_number())
         .bind(credit_note.status().to_string())
         .bind(credit_note.return_type().to_string())
@@ -89,6 +102,8 @@ impl CreditNoteRepository for PgCreditNoteRepository {
         .bind(credit_note.notes())
         .bind(credit_note.created_at())
         .bind(credit_note.updated_at())
+        .bind(credit_note.discount_amount())
+        .bind(credit_note.customer_id().map(|c| c.into_uuid()))
         .execute(&self.pool)
         .await?;
 
@@ -99,8 +114,8 @@ impl CreditNoteRepository for PgCreditNoteRepository {
         let row = sqlx::query_as::<_, CreditNoteRow>(
             r#"
             SELECT id, credit_note_number, store_id, original_sale_id, original_invoice_number,
-                   status, return_type, return_reason, reason_details, currency, subtotal,
-                   tax_amount, total, refund_method, refunded_amount, created_by_id,
+                   customer_id, status, return_type, return_reason, reason_details, currency, subtotal,
+                   discount_amount, tax_amount, total, refund_method, refunded_amount, created_by_id,
                    submitted_by_id, submitted_at, approved_by_id, approved_at, applied_by_id,
                    applied_at, cancelled_by_id, cancelled_at, cancellation_reason, notes,
                    created_at, updated_at
@@ -125,8 +140,8 @@ impl CreditNoteRepository for PgCreditNoteRepository {
         let row = sqlx::query_as::<_, CreditNoteRow>(
             r#"
             SELECT id, credit_note_number, store_id, original_sale_id, original_invoice_number,
-                   status, return_type, return_reason, reason_details, currency, subtotal,
-                   tax_amount, total, refund_method, refunded_amount, created_by_id,
+                   customer_id, status, return_type, return_reason, reason_details, currency, subtotal,
+                   discount_amount, tax_amount, total, refund_method, refunded_amount, created_by_id,
                    submitted_by_id, submitted_at, approved_by_id, approved_at, applied_by_id,
                    applied_at, cancelled_by_id, cancelled_at, cancellation_reason, notes,
                    created_at, updated_at
@@ -155,8 +170,8 @@ impl CreditNoteRepository for PgCreditNoteRepository {
         let row = sqlx::query_as::<_, CreditNoteRow>(
             r#"
             SELECT id, credit_note_number, store_id, original_sale_id, original_invoice_number,
-                   status, return_type, return_reason, reason_details, currency, subtotal,
-                   tax_amount, total, refund_method, refunded_amount, created_by_id,
+                   customer_id, status, return_type, return_reason, reason_details, currency, subtotal,
+                   discount_amount, tax_amount, total, refund_method, refunded_amount, created_by_id,
                    submitted_by_id, submitted_at, approved_by_id, approved_at, applied_by_id,
                    applied_at, cancelled_by_id, cancelled_at, cancellation_reason, notes,
                    created_at, updated_at
@@ -179,8 +194,8 @@ impl CreditNoteRepository for PgCreditNoteRepository {
         let rows = sqlx::query_as::<_, CreditNoteRow>(
             r#"
             SELECT id, credit_note_number, store_id, original_sale_id, original_invoice_number,
-                   status, return_type, return_reason, reason_details, currency, subtotal,
-                   tax_amount, total, refund_method, refunded_amount, created_by_id,
+                   customer_id, status, return_type, return_reason, reason_details, currency, subtotal,
+                   discount_amount, tax_amount, total, refund_method, refunded_amount, created_by_id,
                    submitted_by_id, submitted_at, approved_by_id, approved_at, applied_by_id,
                    applied_at, cancelled_by_id, cancelled_at, cancellation_reason, notes,
                    created_at, updated_at
@@ -206,7 +221,7 @@ impl CreditNoteRepository for PgCreditNoteRepository {
                 refund_method = $7, refunded_amount = $8, submitted_by_id = $9, submitted_at = $10,
                 approved_by_id = $11, approved_at = $12, applied_by_id = $13, applied_at = $14,
                 cancelled_by_id = $15, cancelled_at = $16, cancellation_reason = $17, notes = $18,
-                updated_at = $19
+                updated_at = $19, discount_amount = $20
             WHERE id = $1
             "#,
         )
@@ -229,6 +244,7 @@ impl CreditNoteRepository for PgCreditNoteRepository {
         .bind(credit_note.cancellation_reason())
         .bind(credit_note.notes())
         .bind(credit_note.updated_at())
+        .bind(credit_note.discount_amount())
         .execute(&self.pool)
         .await?;
 
@@ -245,7 +261,11 @@ impl CreditNoteRepository for PgCreditNoteRepository {
         page: i64,
         page_size: i64,
     ) -> Result<(Vec<CreditNote>, i64), SalesError> {
-        let offset = (page - 1) * page_size;
+        let offset = if filter.after_id.is_some() {
+            0
+        } else {
+            (page - 1) * page_size
+        };
 
         let mut count_query = String::from("SELECT COUNT(*) FROM credit_notes WHERE 1=1");
         let mut param_idx = 1;
@@ -254,6 +274,15 @@ impl CreditNoteRepository for PgCreditNoteRepository {
             count_query.push_str(&format!(" AND store_id = ${}", param_idx));
             param_idx += 1;
         }
+        if filter.store_ids.is_some() {
+            count_query.push_str(&format!(" AND store_id = ANY(${})", param_idx));
+            param_idx += 1;
+        }
+        match filter.receiptless {
+            Some(true) => count_query.push_str(" AND original_sale_id IS NULL"),
+            Some(false) => count_query.push_str(" AND original_sale_id IS NOT NULL"),
+            None => {}
+        }
         if filter.status.is_some() {
             count_query.push_str(&format!(" AND status = ${}", param_idx));
             param_idx += 1;
@@ -269,6 +298,10 @@ impl CreditNoteRepository for PgCreditNoteRepository {
         if let Some(store_id) = filter.store_id {
             count_builder = count_builder.bind(store_id.into_uuid());
         }
+        if let Some(store_ids) = &filter.store_ids {
+            let store_ids: Vec<uuid::Uuid> = store_ids.iter().map(|id| *id.as_uuid()).collect();
+            count_builder = count_builder.bind(store_ids);
+        }
         if let Some(status) = filter.status {
             count_builder = count_builder.bind(status.to_string());
         }
@@ -279,8 +312,8 @@ impl CreditNoteRepository for PgCreditNoteRepository {
 
         let mut data_query = String::from(
             r#"SELECT id, credit_note_number, store_id, original_sale_id, original_invoice_number,
-                   status, return_type, return_reason, reason_details, currency, subtotal,
-                   tax_amount, total, refund_method, refunded_amount, created_by_id,
+                   customer_id, status, return_type, return_reason, reason_details, currency, subtotal,
+                   discount_amount, tax_amount, total, refund_method, refunded_amount, created_by_id,
                    submitted_by_id, submitted_at, approved_by_id, approved_at, applied_by_id,
                    applied_at, cancelled_by_id, cancelled_at, cancellation_reason, notes,
                    created_at, updated_at
@@ -292,6 +325,15 @@ impl CreditNoteRepository for PgCreditNoteRepository {
             data_query.push_str(&format!(" AND store_id = ${}", param_idx));
             param_idx += 1;
         }
+        if filter.store_ids.is_some() {
+            data_query.push_str(&format!(" AND store_id = ANY(${})", param_idx));
+            param_idx += 1;
+        }
+        match filter.receiptless {
+            Some(true) => data_query.push_str(" AND original_sale_id IS NULL"),
+            Some(false) => data_query.push_str(" AND original_sale_id IS NOT NULL"),
+            None => {}
+        }
         if filter.status.is_some() {
             data_query.push_str(&format!(" AND status = ${}", param_idx));
             param_idx += 1;
@@ -303,22 +345,30 @@ impl CreditNoteRepository for PgCreditNoteRepository {
             ));
             param_idx += 1;
         }
-        data_query.push_str(&format!(
-            " ORDER BY created_at DESC LIMIT ${} OFFSET ${}",
-            param_idx,
-            param_idx + 1
-        ));
+        if filter.after_id.is_some() {
+            data_query.push_str(&format!(" AND id < ${}", param_idx));
+            param_idx += 1;
+        }
+        data_query.push_str(" ORDER BY id DESC");
+        data_query.push_str(&format!(" LIMIT ${} OFFSET ${}", param_idx, param_idx + 1));
 
         let mut data_builder = sqlx::query_as::<_, CreditNoteRow>(&data_query);
         if let Some(store_id) = filter.store_id {
             data_builder = data_builder.bind(store_id.into_uuid());
         }
+        if let Some(store_ids) = &filter.store_ids {
+            let store_ids: Vec<uuid::Uuid> = store_ids.iter().map(|id| *id.as_uuid()).collect();
+            data_builder = data_builder.bind(store_ids);
+        }
         if let Some(status) = filter.status {
             data_builder = data_builder.bind(status.to_string());
         }
         if let Some(ref search) = filter.search {
             data_builder = data_builder.bind(format!("%{}%", search));
         }
+        if let Some(after_id) = filter.after_id {
+            data_builder = data_builder.bind(after_id.into_uuid());
+        }
         data_builder = data_builder.bind(page_size).bind(offset);
 
         let rows = data_builder.fetch_all(&self.pool).await?;
@@ -331,18 +381,15 @@ impl CreditNoteRepository for PgCreditNoteRepository {
     }
 
     async fn generate_credit_note_number(&self, store_id: StoreId) -> Result<String, SalesError> {
-        let today = chrono::Utc::now().format("%Y%m%d");
-        let prefix = format!("CN-{}", today);
-
-        let count: (i64,) = sqlx::query_as(
-            "SELECT COUNT(*) FROM credit_notes WHERE store_id = $1 AND credit_note_number LIKE $2",
-        )
-        .bind(store_id.into_uuid())
-        .bind(format!("{}%", prefix))
-        .fetch_one(&self.pool)
-        .await?;
-
-        Ok(format!("{}-{:04}", prefix, count.0 + 1))
+        let mut conn = self.pool.acquire().await?;
+        Ok(self
+            .numbering
+            .next_number(
+                &mut conn,
+                DocumentType::CreditNote,
+                Some(store_id.into_uuid()),
+            )
+            .await?)
     }
 
     async fn save_item(&self, item: &CreditNoteItem) -> Result<(), SalesError> {
@@ -351,14 +398,15 @@ impl CreditNoteRepository for PgCreditNoteRepository {
             INSERT INTO credit_note_items (
                 id, credit_note_id, original_sale_item_id, product_id, variant_id, sku,
                 description, return_quantity, unit_of_measure, unit_price, tax_rate,
-                tax_amount, subtotal, total, restock, condition, notes, created_at, updated_at
+                tax_amount, subtotal, total, restock, condition, notes, created_at, updated_at,
+                discount_amount, serial_numbers
             )
-            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15, $16, $17, $18, $19)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15, $16, $17, $18, $19, $20, $21)
             "#,
         )
         .bind(item.id().into_uuid())
         .bind(item.credit_note_id().into_uuid())
-        .bind(item.original_sale_item_id().into_uuid())
+        .bind(item.original_sale_item_id().map(|i| i.into_uuid()))
         .bind(item.product_id().into_uuid())
         .bind(item.variant_id().map(|v| v.into_uuid()))
         .bind(item.sku())
@@ -375,6 +423,8 @@ impl CreditNoteRepository for PgCreditNoteRepository {
         .bind(item.notes())
         .bind(item.created_at())
         .bind(item.updated_at())
+        .bind(item.discount_amount())
+        .bind(item.serial_numbers())
         .execute(&self.pool)
         .await?;
 
@@ -386,7 +436,8 @@ impl CreditNoteRepository for PgCreditNoteRepository {
             r#"
             UPDATE credit_note_items
             SET return_quantity = $2, tax_amount = $3, subtotal = $4, total = $5,
-                restock = $6, condition = $7, notes = $8, updated_at = $9
+                restock = $6, condition = $7, notes = $8, updated_at = $9, discount_amount = $10,
+                serial_numbers = $11
             WHERE id = $1
             "#,
         )
@@ -399,6 +450,8 @@ impl CreditNoteRepository for PgCreditNoteRepository {
         .bind(item.condition())
         .bind(item.notes())
         .bind(item.updated_at())
+        .bind(item.discount_amount())
+        .bind(item.serial_numbers())
         .execute(&self.pool)
         .await?;
 
@@ -428,7 +481,8 @@ impl CreditNoteRepository for PgCreditNoteRepository {
             r#"
             SELECT id, credit_note_id, original_sale_item_id, product_id, variant_id, sku,
                    description, return_quantity, unit_of_measure, unit_price, tax_rate,
-                   tax_amount, subtotal, total, restock, condition, notes, created_at, updated_at
+                   discount_amount, tax_amount, subtotal, total, restock, serial_numbers, condition, notes,
+                   created_at, updated_at
             FROM credit_note_items
             WHERE id = $1
             "#,
@@ -439,6 +493,95 @@ impl CreditNoteRepository for PgCreditNoteRepository {
 
         row.map(|r| r.try_into()).transpose()
     }
+
+    async fn find_returned_quantities(
+        &self,
+        sale_id: SaleId,
+    ) -> Result<Vec<(SaleItemId, rust_decimal::Decimal)>, SalesError> {
+        let rows = sqlx::query_as::<_, (uuid::Uuid, rust_decimal::Decimal)>(
+            r#"
+            SELECT i.original_sale_item_id, SUM(i.return_quantity)
+            FROM credit_note_items i
+            JOIN credit_notes c ON c.id = i.credit_note_id
+            WHERE c.original_sale_id = $1 AND c.status <> 'cancelled'
+            GROUP BY i.original_sale_item_id
+            "#,
+        )
+        .bind(sale_id.into_uuid())
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows
+            .into_iter()
+            .map(|(id, qty)| (SaleItemId::from_uuid(id), qty))
+            .collect())
+    }
+
+    async fn find_document(
+        &self,
+        credit_note_id: CreditNoteId,
+    ) -> Result<Option<CreditNoteDocument>, SalesError> {
+        let row: Option<(sqlx::types::Json<CreditNoteDocument>,)> =
+            sqlx::query_as("SELECT document FROM credit_note_documents WHERE credit_note_id = $1")
+                .bind(credit_note_id.into_uuid())
+                .fetch_optional(&self.pool)
+                .await?;
+
+        Ok(row.map(|(document,)| document.0))
+    }
+
+    async fn issue_document(
+        &self,
+        mut document: CreditNoteDocument,
+    ) -> Result<CreditNoteDocument, SalesError> {
+        let mut tx = self.pool.begin().await?;
+
+        // The upsert locks the store's sequence row until commit; a rollback
+        // gives the number back
+        let (number,): (i64,) = sqlx::query_as(
+            r#"
+            INSERT INTO credit_note_document_sequences (store_id, last_number)
+            VALUES ($1, 1)
+            ON CONFLICT (store_id)
+            DO UPDATE SET last_number = credit_note_document_sequences.last_number + 1
+            RETURNING last_number
+            "#,
+        )
+        .bind(document.store_id)
+        .fetch_one(&mut *tx)
+        .await?;
+        document.issue(format!("NC-{:08}", number));
+
+        let inserted = sqlx::query(
+            r#"
+            INSERT INTO credit_note_documents (
+                credit_note_id, store_id, document_number, document, issued_at
+            )
+            VALUES ($1, $2, $3, $4, $5)
+            ON CONFLICT (credit_note_id) DO NOTHING
+            "#,
+        )
+        .bind(document.credit_note_id)
+        .bind(document.store_id)
+        .bind(&document.document_number)
+        .bind(sqlx::types::Json(&document))
+        .bind(document.issued_at)
+        .execute(&mut *tx)
+        .await?;
+
+        if inserted.rows_affected() == 0 {
+            // Issued by a concurrent request; keep its number instead
+            tx.rollback().await?;
+            let credit_note_id = CreditNoteId::from_uuid(document.credit_note_id);
+            return self
+                .find_document(credit_note_id)
+                .await?
+                .ok_or(SalesError::CreditNoteNotFound(document.credit_note_id));
+        }
+
+        tx.commit().await?;
+        Ok(document)
+    }
 }
 
 // =============================================================================
@@ -450,14 +593,16 @@ struct CreditNoteRow {
     id: uuid::Uuid,
     credit_note_number: String,
     store_id: uuid::Uuid,
-    original_sale_id: uuid::Uuid,
+    original_sale_id: Option<uuid::Uuid>,
     original_invoice_number: String,
+    customer_id: Option<uuid::Uuid>,
     status: String,
     return_type: String,
     return_reason: String,
     reason_details: Option<String>,
     currency: String,
     subtotal: rust_decimal::Decimal,
+    discount_amount: rust_decimal::Decimal,
     tax_amount: rust_decimal::Decimal,
     total: rust_decimal::Decimal,
     refund_method: Option<String>,
@@ -487,14 +632,16 @@ impl CreditNoteRow {
             CreditNoteId::from_uuid(self.id),
             self.credit_note_number,
             StoreId::from_uuid(self.store_id),
-            SaleId::from_uuid(self.original_sale_id),
+            self.original_sale_id.map(SaleId::from_uuid),
             self.original_invoice_number,
+            self.customer_id.map(CustomerId::from_uuid),
             status,
             return_type,
             return_reason,
             self.reason_details,
             Currency::from_string(self.currency),
             self.subtotal,
+            self.discount_amount,
             self.tax_amount,
             self.total,
             self.refund_method,
@@ -521,7 +668,7 @@ impl CreditNoteRow {
 struct CreditNoteItemRow {
     id: uuid::Uuid,
     credit_note_id: uuid::Uuid,
-    original_sale_item_id: uuid::Uuid,
+    original_sale_item_id: Option<uuid::Uuid>,
     product_id: uuid::Uuid,
     variant_id: Option<uuid::Uuid>,
     sku: String,
@@ -530,10 +677,12 @@ struct CreditNoteItemRow {
     unit_of_measure: String,
     unit_price: rust_decimal::Decimal,
     tax_rate: rust_decimal::Decimal,
+    discount_amount: rust_decimal::Decimal,
     tax_amount: rust_decimal::Decimal,
     subtotal: rust_decimal::Decimal,
     total: rust_decimal::Decimal,
     restock: bool,
+    serial_numbers: Vec<String>,
     condition: Option<String>,
     notes: Option<String>,
     created_at: chrono::DateTime<chrono::Utc>,
@@ -550,7 +699,7 @@ impl TryFrom<CreditNoteItemRow> for CreditNoteItem {
         Ok(CreditNoteItem::reconstitute(
             CreditNoteItemId::from_uuid(row.id),
             CreditNoteId::from_uuid(row.credit_note_id),
-            SaleItemId::from_uuid(row.original_sale_item_id),
+            row.original_sale_item_id.map(SaleItemId::from_uuid),
             ProductId::from_uuid(row.product_id),
             row.variant_id.map(VariantId::from_uuid),
             row.sku,
@@ -559,10 +708,12 @@ impl TryFrom<CreditNoteItemRow> for CreditNoteItem {
             uom,
             row.unit_price,
             row.tax_rate,
+            row.discount_amount,
             row.tax_amount,
             row.subtotal,
             row.total,
             row.restock,
+            row.serial_numbers,
             row.condition,
             row.notes,
             row.created_at,
@@ -1,14 +1,21 @@
 //! PostgreSQL CustomerRepository implementation
 
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
 use sqlx::PgPool;
 
 use crate::SalesError;
-use crate::domain::entities::{Address, Customer};
+use crate::domain::entities::{Address, Customer, CustomerAccountEntry};
 use crate::domain::repositories::{CustomerFilter, CustomerRepository};
-use crate::domain::value_objects::{CustomerId, CustomerType};
+use crate::domain::value_objects::{
+    AccountEntryType, CustomerCodeFormat, CustomerId, CustomerType,
+};
 use identity::{StoreId, UserId};
 
+/// Upper bound on sequence bumps when skipping codes that are already taken
+const MAX_CODE_ATTEMPTS: usize = 20;
+
 /// PostgreSQL implementation of CustomerRepository
 pub struct PgCustomerRepository {
     pool: PgPool,
@@ -28,11 +35,12 @@ impl CustomerRepository for PgCustomerRepository {
             r#"
             INSERT INTO customers (
                 id, store_id, customer_type, code, first_name, last_name, company_name,
-                email, phone, tax_id, address_line1, address_line2, address_city,
+                email, phone, tax_id, tax_id_country, address_line1, address_line2, address_city,
                 address_state, address_postal_code, address_country, user_id, is_active,
-                total_purchases, purchase_count, last_purchase_at, notes, created_at, updated_at
+                total_purchases, purchase_count, last_purchase_at, credit_limit, account_balance,
+                notes, created_at, updated_at
             )
-            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15, $16, $17, $18, $19, $20, $21, $22, $23, $24)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15, $16, $17, $18, $19, $20, $21, $22, $23, $24, $25, $26, $27)
             "#,
         )
         .bind(customer.id().into_uuid())
@@ -45,6 +53,7 @@ impl CustomerRepository for PgCustomerRepository {
         .bind(customer.email())
         .bind(customer.phone())
         .bind(customer.tax_id())
+        .bind(customer.tax_id_country())
         .bind(customer.billing_address().line1.as_deref())
         .bind(customer.billing_address().line2.as_deref())
         .bind(customer.billing_address().city.as_deref())
@@ -56,6 +65,8 @@ impl CustomerRepository for PgCustomerRepository {
         .bind(customer.total_purchases())
         .bind(customer.purchase_count())
         .bind(customer.last_purchase_at())
+        .bind(customer.credit_limit())
+        .bind(customer.account_balance())
         .bind(customer.notes())
         .bind(customer.created_at())
         .bind(customer.updated_at())
@@ -69,9 +80,12 @@ impl CustomerRepository for PgCustomerRepository {
         let row = sqlx::query_as::<_, CustomerRow>(
             r#"
             SELECT id, store_id, customer_type, code, first_name, last_name, company_name,
-                   email, phone, tax_id, address_line1, address_line2, address_city,
+                   email, phone, tax_id, tax_id_country, address_line1, address_line2, address_city,
                    address_state, address_postal_code, address_country, user_id, is_active,
-                   total_purchases, purchase_count, last_purchase_at, notes, created_at, updated_at
+                   total_purchases, purchase_count, last_purchase_at, credit_limit, account_balance,
+                   COALESCE((SELECT b.balance FROM customer_credit_balances b
+                             WHERE b.customer_id = customers.id), 0) AS store_credit_balance,
+                   notes, created_at, updated_at
             FROM customers
             WHERE id = $1
             "#,
@@ -91,9 +105,12 @@ impl CustomerRepository for PgCustomerRepository {
         let row = sqlx::query_as::<_, CustomerRow>(
             r#"
             SELECT id, store_id, customer_type, code, first_name, last_name, company_name,
-                   email, phone, tax_id, address_line1, address_line2, address_city,
+                   email, phone, tax_id, tax_id_country, address_line1, address_line2, address_city,
                    address_state, address_postal_code, address_country, user_id, is_active,
-                   total_purchases, purchase_count, last_purchase_at, notes, created_at, updated_at
+                   total_purchases, purchase_count, last_purchase_at, credit_limit, account_balance,
+                   COALESCE((SELECT b.balance FROM customer_credit_balances b
+                             WHERE b.customer_id = customers.id), 0) AS store_credit_balance,
+                   notes, created_at, updated_at
             FROM customers
             WHERE store_id = $1 AND code = $2
             "#,
@@ -114,9 +131,12 @@ impl CustomerRepository for PgCustomerRepository {
         let row = sqlx::query_as::<_, CustomerRow>(
             r#"
             SELECT id, store_id, customer_type, code, first_name, last_name, company_name,
-                   email, phone, tax_id, address_line1, address_line2, address_city,
+                   email, phone, tax_id, tax_id_country, address_line1, address_line2, address_city,
                    address_state, address_postal_code, address_country, user_id, is_active,
-                   total_purchases, purchase_count, last_purchase_at, notes, created_at, updated_at
+                   total_purchases, purchase_count, last_purchase_at, credit_limit, account_balance,
+                   COALESCE((SELECT b.balance FROM customer_credit_balances b
+                             WHERE b.customer_id = customers.id), 0) AS store_credit_balance,
+                   notes, created_at, updated_at
             FROM customers
             WHERE store_id = $1 AND email = $2
             "#,
@@ -138,7 +158,8 @@ impl CustomerRepository for PgCustomerRepository {
                 address_line1 = $10, address_line2 = $11, address_city = $12,
                 address_state = $13, address_postal_code = $14, address_country = $15,
                 user_id = $16, is_active = $17, total_purchases = $18, purchase_count = $19,
-                last_purchase_at = $20, notes = $21, updated_at = $22
+                last_purchase_at = $20, credit_limit = $21, notes = $22, updated_at = $23,
+                tax_id_country = $24
             WHERE id = $1
             "#,
         )
@@ -162,8 +183,10 @@ impl CustomerRepository for PgCustomerRepository {
         .bind(customer.total_purchases())
         .bind(customer.purchase_count())
         .bind(customer.last_purchase_at())
+        .bind(customer.credit_limit())
         .bind(customer.notes())
         .bind(customer.updated_at())
+        .bind(customer.tax_id_country())
         .execute(&self.pool)
         .await?;
 
@@ -180,7 +203,11 @@ impl CustomerRepository for PgCustomerRepository {
         page: i64,
         page_size: i64,
     ) -> Result<(Vec<Customer>, i64), SalesError> {
-        let offset = (page - 1) * page_size;
+        let offset = if filter.after_id.is_some() {
+            0
+        } else {
+            (page - 1) * page_size
+        };
 
         // Build count query
         let mut count_query = String::from("SELECT COUNT(*) FROM customers WHERE 1=1");
@@ -217,9 +244,12 @@ impl CustomerRepository for PgCustomerRepository {
         // Build data query
         let mut data_query = String::from(
             r#"SELECT id, store_id, customer_type, code, first_name, last_name, company_name,
-                   email, phone, tax_id, address_line1, address_line2, address_city,
+                   email, phone, tax_id, tax_id_country, address_line1, address_line2, address_city,
                    address_state, address_postal_code, address_country, user_id, is_active,
-                   total_purchases, purchase_count, last_purchase_at, notes, created_at, updated_at
+                   total_purchases, purchase_count, last_purchase_at, credit_limit, account_balance,
+                   COALESCE((SELECT b.balance FROM customer_credit_balances b
+                             WHERE b.customer_id = customers.id), 0) AS store_credit_balance,
+                   notes, created_at, updated_at
             FROM customers WHERE 1=1"#,
         );
 
@@ -239,8 +269,16 @@ impl CustomerRepository for PgCustomerRepository {
             ));
             param_idx += 1;
         }
+        // The cursor row's place in the name order, ids breaking ties
+        if filter.after_id.is_some() {
+            data_query.push_str(&format!(
+                " AND (last_name, first_name, id) > (SELECT last_name, first_name, id FROM customers WHERE id = ${})",
+                param_idx
+            ));
+            param_idx += 1;
+        }
         data_query.push_str(&format!(
-            " ORDER BY last_name, first_name LIMIT ${} OFFSET ${}",
+            " ORDER BY last_name, first_name, id LIMIT ${} OFFSET ${}",
             param_idx,
             param_idx + 1
         ));
@@ -255,6 +293,9 @@ impl CustomerRepository for PgCustomerRepository {
         if let Some(ref search) = filter.search {
             data_builder = data_builder.bind(format!("%{}%", search));
         }
+        if let Some(after_id) = filter.after_id {
+            data_builder = data_builder.bind(after_id.into_uuid());
+        }
         data_builder = data_builder.bind(page_size).bind(offset);
 
         let rows = data_builder.fetch_all(&self.pool).await?;
@@ -265,14 +306,279 @@ impl CustomerRepository for PgCustomerRepository {
     }
 
     async fn generate_customer_code(&self, store_id: StoreId) -> Result<String, SalesError> {
-        let count: (i64,) = sqlx::query_as(
-            "SELECT COUNT(*) FROM customers WHERE store_id = $1 AND code LIKE 'CUS-%'",
+        // Codes inserted outside the sequence (seed data, imports) may already
+        // occupy a number, so skip ahead until a free code is found.
+        for _ in 0..MAX_CODE_ATTEMPTS {
+            let (prefix, padding, value): (String, i16, i64) = sqlx::query_as(
+                r#"
+                INSERT INTO customer_code_sequences (store_id, last_value)
+                VALUES ($1, 1)
+                ON CONFLICT (store_id) DO UPDATE
+                SET last_value = customer_code_sequences.last_value + 1,
+                    updated_at = NOW()
+                RETURNING prefix, padding, last_value
+                "#,
+            )
+            .bind(store_id.into_uuid())
+            .fetch_one(&self.pool)
+            .await?;
+
+            let format = CustomerCodeFormat::new(prefix, padding as u8)?;
+            let code = format.format(value);
+
+            let taken: bool = sqlx::query_scalar(
+                "SELECT EXISTS(SELECT 1 FROM customers WHERE store_id = $1 AND code = $2)",
+            )
+            .bind(store_id.into_uuid())
+            .bind(&code)
+            .fetch_one(&self.pool)
+            .await?;
+
+            if !taken {
+                return Ok(code);
+            }
+        }
+
+        Err(SalesError::DuplicateCustomerCode(format!(
+            "no free code after {} attempts",
+            MAX_CODE_ATTEMPTS
+        )))
+    }
+
+    async fn find_customer_code_format(
+        &self,
+        store_id: StoreId,
+    ) -> Result<CustomerCodeFormat, SalesError> {
+        let row: Option<(String, i16)> = sqlx::query_as(
+            "SELECT prefix, padding FROM customer_code_sequences WHERE store_id = $1",
         )
         .bind(store_id.into_uuid())
-        .fetch_one(&self.pool)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        match row {
+            Some((prefix, padding)) => CustomerCodeFormat::new(prefix, padding as u8),
+            None => Ok(CustomerCodeFormat::default()),
+        }
+    }
+
+    async fn save_customer_code_format(
+        &self,
+        store_id: StoreId,
+        format: &CustomerCodeFormat,
+    ) -> Result<(), SalesError> {
+        sqlx::query(
+            r#"
+            INSERT INTO customer_code_sequences (store_id, prefix, padding)
+            VALUES ($1, $2, $3)
+            ON CONFLICT (store_id) DO UPDATE
+            SET prefix = EXCLUDED.prefix,
+                padding = EXCLUDED.padding,
+                updated_at = NOW()
+            "#,
+        )
+        .bind(store_id.into_uuid())
+        .bind(format.prefix())
+        .bind(format.padding() as i16)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    async fn post_account_entry(&self, entry: &mut CustomerAccountEntry) -> Result<(), SalesError> {
+        let mut tx = self.pool.begin().await?;
+        Self::post_account_entry_in_tx(&mut tx, entry).await?;
+        tx.commit().await?;
+        Ok(())
+    }
+
+    async fn find_account_entries(
+        &self,
+        customer_id: CustomerId,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+    ) -> Result<Vec<CustomerAccountEntry>, SalesError> {
+        let rows = sqlx::query_as::<_, AccountEntryRow>(
+            r#"
+            SELECT id, customer_id, store_id, entry_type, amount, balance_after,
+                   reference_id, reference_number, notes, created_by_id, created_at
+            FROM customer_account_entries
+            WHERE customer_id = $1 AND created_at >= $2 AND created_at < $3
+            ORDER BY created_at, id
+            "#,
+        )
+        .bind(customer_id.into_uuid())
+        .bind(from)
+        .bind(to)
+        .fetch_all(&self.pool)
+        .await?;
+
+        rows.into_iter().map(|r| r.try_into()).collect()
+    }
+
+    async fn find_account_balance_at(
+        &self,
+        customer_id: CustomerId,
+        at: DateTime<Utc>,
+    ) -> Result<Decimal, SalesError> {
+        let balance: Option<Decimal> = sqlx::query_scalar(
+            r#"
+            SELECT balance_after
+            FROM customer_account_entries
+            WHERE customer_id = $1 AND created_at < $2
+            ORDER BY created_at DESC, id DESC
+            LIMIT 1
+            "#,
+        )
+        .bind(customer_id.into_uuid())
+        .bind(at)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(balance.unwrap_or(Decimal::ZERO))
+    }
+
+    async fn find_store_credit_balance(
+        &self,
+        customer_id: CustomerId,
+    ) -> Result<Decimal, SalesError> {
+        let balance: Option<Decimal> = sqlx::query_scalar(
+            "SELECT balance FROM customer_credit_balances WHERE customer_id = $1",
+        )
+        .bind(customer_id.into_uuid())
+        .fetch_optional(&self.pool)
         .await?;
 
-        Ok(format!("CUS-{:05}", count.0 + 1))
+        Ok(balance.unwrap_or(Decimal::ZERO))
+    }
+
+    async fn adjust_store_credit(
+        &self,
+        customer_id: CustomerId,
+        amount: Decimal,
+    ) -> Result<Decimal, SalesError> {
+        let mut tx = self.pool.begin().await?;
+        let balance = Self::adjust_store_credit_in_tx(&mut tx, customer_id, amount).await?;
+        tx.commit().await?;
+        Ok(balance)
+    }
+}
+
+// Transactional methods
+impl PgCustomerRepository {
+    /// Posts an account entry within an existing transaction.
+    ///
+    /// The balance update takes the customer row lock, and the entry is
+    /// timestamped while holding it, so entries are ordered exactly as they
+    /// were applied to the balance.
+    pub async fn post_account_entry_in_tx(
+        tx: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+        entry: &mut CustomerAccountEntry,
+    ) -> Result<(), SalesError> {
+        let posted: Option<(Decimal, DateTime<Utc>)> = sqlx::query_as(
+            r#"
+            WITH updated AS (
+                UPDATE customers
+                SET account_balance = account_balance + $4, updated_at = NOW()
+                WHERE id = $2 AND ($4 <= 0 OR account_balance + $4 <= credit_limit)
+                RETURNING account_balance
+            )
+            INSERT INTO customer_account_entries (
+                id, customer_id, store_id, entry_type, amount, balance_after,
+                reference_id, reference_number, notes, created_by_id, created_at
+            )
+            SELECT $1, $2, $3, $5, $4, updated.account_balance, $6, $7, $8, $9, clock_timestamp()
+            FROM updated
+            RETURNING balance_after, created_at
+            "#,
+        )
+        .bind(entry.id())
+        .bind(entry.customer_id().into_uuid())
+        .bind(entry.store_id().into_uuid())
+        .bind(entry.amount())
+        .bind(entry.entry_type().to_string())
+        .bind(entry.reference_id())
+        .bind(entry.reference_number())
+        .bind(entry.notes())
+        .bind(entry.created_by_id().map(|u| u.into_uuid()))
+        .fetch_optional(&mut **tx)
+        .await?;
+
+        if let Some((balance_after, posted_at)) = posted {
+            entry.mark_posted(balance_after, posted_at);
+            return Ok(());
+        }
+
+        let account: Option<(Decimal, Decimal)> =
+            sqlx::query_as("SELECT credit_limit, account_balance FROM customers WHERE id = $1")
+                .bind(entry.customer_id().into_uuid())
+                .fetch_optional(&mut **tx)
+                .await?;
+
+        match account {
+            Some((credit_limit, balance)) => Err(SalesError::CreditLimitExceeded(
+                (credit_limit - balance).max(Decimal::ZERO),
+            )),
+            None => Err(SalesError::CustomerNotFound(
+                entry.customer_id().into_uuid(),
+            )),
+        }
+    }
+
+    /// Adjusts the customer's store credit within an existing transaction.
+    ///
+    /// Credits create the balance row on first use; spends update it only
+    /// while the balance covers them.
+    pub async fn adjust_store_credit_in_tx(
+        tx: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+        customer_id: CustomerId,
+        amount: Decimal,
+    ) -> Result<Decimal, SalesError> {
+        if amount >= Decimal::ZERO {
+            let balance: Decimal = sqlx::query_scalar(
+                r#"
+                INSERT INTO customer_credit_balances (customer_id, balance, created_at, updated_at)
+                VALUES ($1, $2, NOW(), NOW())
+                ON CONFLICT (customer_id) DO UPDATE
+                SET balance = customer_credit_balances.balance + EXCLUDED.balance,
+                    updated_at = NOW()
+                RETURNING balance
+                "#,
+            )
+            .bind(customer_id.into_uuid())
+            .bind(amount)
+            .fetch_one(&mut **tx)
+            .await?;
+            return Ok(balance);
+        }
+
+        let spent: Option<Decimal> = sqlx::query_scalar(
+            r#"
+            UPDATE customer_credit_balances
+            SET balance = balance + $2, updated_at = NOW()
+            WHERE customer_id = $1 AND balance + $2 >= 0
+            RETURNING balance
+            "#,
+        )
+        .bind(customer_id.into_uuid())
+        .bind(amount)
+        .fetch_optional(&mut **tx)
+        .await?;
+
+        if let Some(balance) = spent {
+            return Ok(balance);
+        }
+
+        let available: Option<Decimal> = sqlx::query_scalar(
+            "SELECT balance FROM customer_credit_balances WHERE customer_id = $1",
+        )
+        .bind(customer_id.into_uuid())
+        .fetch_optional(&mut **tx)
+        .await?;
+        Err(SalesError::InsufficientStoreCredit(
+            available.unwrap_or(Decimal::ZERO),
+        ))
     }
 }
 
@@ -292,6 +598,7 @@ struct CustomerRow {
     email: Option<String>,
     phone: Option<String>,
     tax_id: Option<String>,
+    tax_id_country: Option<String>,
     address_line1: Option<String>,
     address_line2: Option<String>,
     address_city: Option<String>,
@@ -303,6 +610,9 @@ struct CustomerRow {
     total_purchases: rust_decimal::Decimal,
     purchase_count: i32,
     last_purchase_at: Option<chrono::DateTime<chrono::Utc>>,
+    credit_limit: rust_decimal::Decimal,
+    account_balance: rust_decimal::Decimal,
+    store_credit_balance: rust_decimal::Decimal,
     notes: Option<String>,
     created_at: chrono::DateTime<chrono::Utc>,
     updated_at: chrono::DateTime<chrono::Utc>,
@@ -337,15 +647,56 @@ impl TryFrom<CustomerRow> for Customer {
             row.email,
             row.phone,
             row.tax_id,
+            row.tax_id_country,
             address,
             row.user_id.map(UserId::from_uuid),
             row.is_active,
             row.total_purchases,
             row.purchase_count,
             row.last_purchase_at,
+            row.credit_limit,
+            row.account_balance,
+            row.store_credit_balance,
             row.notes,
             row.created_at,
             row.updated_at,
         ))
     }
 }
+
+#[derive(sqlx::FromRow)]
+struct AccountEntryRow {
+    id: uuid::Uuid,
+    customer_id: uuid::Uuid,
+    store_id: uuid::Uuid,
+    entry_type: String,
+    amount: Decimal,
+    balance_after: Decimal,
+    reference_id: Option<uuid::Uuid>,
+    reference_number: Option<String>,
+    notes: Option<String>,
+    created_by_id: Option<uuid::Uuid>,
+    created_at: DateTime<Utc>,
+}
+
+impl TryFrom<AccountEntryRow> for CustomerAccountEntry {
+    type Error = SalesError;
+
+    fn try_from(row: AccountEntryRow) -> Result<Self, Self::Error> {
+        let entry_type: AccountEntryType = row.entry_type.parse()?;
+
+        Ok(CustomerAccountEntry::reconstitute(
+            row.id,
+            CustomerId::from_uuid(row.customer_id),
+            StoreId::from_uuid(row.store_id),
+            entry_type,
+            row.amount,
+            row.balance_after,
+            row.reference_id,
+            row.reference_number,
+            row.notes,
+            row.created_by_id.map(UserId::from_uuid),
+            row.created_at,
+        ))
+    }
+}
@@ -0,0 +1,117 @@
+//! PostgreSQL ExchangeRateRepository implementation
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::SalesError;
+use crate::domain::entities::ExchangeRate;
+use crate::domain::repositories::ExchangeRateRepository;
+use identity::UserId;
+use inventory::Currency;
+
+/// Row type for reading exchange rates from the database
+#[derive(Debug, sqlx::FromRow)]
+struct ExchangeRateRow {
+    id: Uuid,
+    store_id: Uuid,
+    currency: String,
+    rate: Decimal,
+    effective_at: DateTime<Utc>,
+    created_by_id: Uuid,
+    created_at: DateTime<Utc>,
+}
+
+impl From<ExchangeRateRow> for ExchangeRate {
+    fn from(row: ExchangeRateRow) -> Self {
+        ExchangeRate::reconstitute(
+            row.id,
+            row.store_id,
+            Currency::from_string(row.currency),
+            row.rate,
+            row.effective_at,
+            UserId::from_uuid(row.created_by_id),
+            row.created_at,
+        )
+    }
+}
+
+pub struct PgExchangeRateRepository {
+    pool: PgPool,
+}
+
+impl PgExchangeRateRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl ExchangeRateRepository for PgExchangeRateRepository {
+    async fn save(&self, rate: &ExchangeRate) -> Result<(), SalesError> {
+        sqlx::query(
+            r#"
+            INSERT INTO exchange_rates (
+                id, store_id, currency, rate, effective_at, created_by_id, created_at
+            )
+            VALUES ($1, $2, $3, $4, $5, $6, $7)
+            "#,
+        )
+        .bind(rate.id())
+        .bind(rate.store_id())
+        .bind(rate.currency().as_str())
+        .bind(rate.rate())
+        .bind(rate.effective_at())
+        .bind(rate.created_by_id().into_uuid())
+        .bind(rate.created_at())
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    async fn find_effective(
+        &self,
+        store_id: Uuid,
+        currency: &Currency,
+        at: DateTime<Utc>,
+    ) -> Result<Option<ExchangeRate>, SalesError> {
+        let row = sqlx::query_as::<_, ExchangeRateRow>(
+            r#"
+            SELECT * FROM exchange_rates
+            WHERE store_id = $1 AND currency = $2 AND effective_at <= $3
+            ORDER BY effective_at DESC
+            LIMIT 1
+            "#,
+        )
+        .bind(store_id)
+        .bind(currency.as_str())
+        .bind(at)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(row.map(ExchangeRate::from))
+    }
+
+    async fn list(
+        &self,
+        store_id: Uuid,
+        currency: Option<&Currency>,
+    ) -> Result<Vec<ExchangeRate>, SalesError> {
+        let rows = sqlx::query_as::<_, ExchangeRateRow>(
+            r#"
+            SELECT * FROM exchange_rates
+            WHERE store_id = $1 AND ($2::TEXT IS NULL OR currency = $2)
+            ORDER BY effective_at DESC
+            "#,
+        )
+        .bind(store_id)
+        .bind(currency.map(|c| c.as_str()))
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows.into_iter().map(ExchangeRate::from).collect())
+    }
+}
@@ -0,0 +1,195 @@
+//! PostgreSQL GiftCardRepository implementation
+
+use std::str::FromStr;
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::SalesError;
+use crate::domain::entities::{GiftCard, normalize_gift_card_code};
+use crate::domain::repositories::GiftCardRepository;
+use crate::domain::value_objects::{GiftCardId, GiftCardStatus, SaleId, SaleItemId};
+use identity::{StoreId, UserId};
+use inventory::Currency;
+
+const GIFT_CARD_COLUMNS: &str = r#"
+    id, store_id, code, currency, initial_balance, current_balance, status,
+    sale_id, sale_item_id, issued_by_id, created_at, updated_at
+"#;
+
+/// Row type for reading gift cards from the database
+#[derive(Debug, sqlx::FromRow)]
+struct GiftCardRow {
+    id: Uuid,
+    store_id: Uuid,
+    code: String,
+    currency: String,
+    initial_balance: Decimal,
+    current_balance: Decimal,
+    status: String,
+    sale_id: Option<Uuid>,
+    sale_item_id: Option<Uuid>,
+    issued_by_id: Uuid,
+    created_at: DateTime<Utc>,
+    updated_at: DateTime<Utc>,
+}
+
+impl TryFrom<GiftCardRow> for GiftCard {
+    type Error = SalesError;
+
+    fn try_from(row: GiftCardRow) -> Result<Self, Self::Error> {
+        Ok(GiftCard::reconstitute(
+            GiftCardId::from_uuid(row.id),
+            StoreId::from_uuid(row.store_id),
+            row.code,
+            Currency::from_string(row.currency),
+            row.initial_balance,
+            row.current_balance,
+            GiftCardStatus::from_str(&row.status)?,
+            row.sale_id.map(SaleId::from_uuid),
+            row.sale_item_id.map(SaleItemId::from_uuid),
+            UserId::from_uuid(row.issued_by_id),
+            row.created_at,
+            row.updated_at,
+        ))
+    }
+}
+
+/// PostgreSQL implementation of GiftCardRepository
+pub struct PgGiftCardRepository {
+    pool: PgPool,
+}
+
+impl PgGiftCardRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl GiftCardRepository for PgGiftCardRepository {
+    async fn save(&self, card: &GiftCard) -> Result<(), SalesError> {
+        sqlx::query(
+            r#"
+            INSERT INTO gift_cards (
+                id, store_id, code, currency, initial_balance, current_balance, status,
+                sale_id, sale_item_id, issued_by_id, created_at, updated_at
+            )
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12)
+            "#,
+        )
+        .bind(card.id().into_uuid())
+        .bind(card.store_id().into_uuid())
+        .bind(card.code())
+        .bind(card.currency().as_str())
+        .bind(card.initial_balance())
+        .bind(card.current_balance())
+        .bind(card.status().to_string())
+        .bind(card.sale_id().map(|id| id.into_uuid()))
+        .bind(card.sale_item_id().map(|id| id.into_uuid()))
+        .bind(card.issued_by_id().into_uuid())
+        .bind(card.created_at())
+        .bind(card.updated_at())
+        .execute(&self.pool)
+        .await
+        .map_err(|e| match &e {
+            sqlx::Error::Database(db_err) if db_err.is_unique_violation() => {
+                SalesError::DuplicateGiftCardCode(card.code().to_string())
+            }
+            _ => SalesError::Database(e),
+        })?;
+
+        Ok(())
+    }
+
+    async fn update(&self, card: &GiftCard) -> Result<(), SalesError> {
+        Self::update_with(&self.pool, card).await
+    }
+
+    async fn find_by_code(&self, code: &str) -> Result<Option<GiftCard>, SalesError> {
+        let row = sqlx::query_as::<_, GiftCardRow>(&format!(
+            "SELECT {} FROM gift_cards WHERE code = $1",
+            GIFT_CARD_COLUMNS
+        ))
+        .bind(normalize_gift_card_code(code))
+        .fetch_optional(&self.pool)
+        .await?;
+
+        row.map(GiftCard::try_from).transpose()
+    }
+
+    async fn find_by_sale(&self, sale_id: SaleId) -> Result<Vec<GiftCard>, SalesError> {
+        let rows = sqlx::query_as::<_, GiftCardRow>(&format!(
+            "SELECT {} FROM gift_cards WHERE sale_id = $1 ORDER BY created_at",
+            GIFT_CARD_COLUMNS
+        ))
+        .bind(sale_id.into_uuid())
+        .fetch_all(&self.pool)
+        .await?;
+
+        rows.into_iter().map(GiftCard::try_from).collect()
+    }
+
+    async fn redeem(&self, id: GiftCardId, amount: Decimal) -> Result<GiftCard, SalesError> {
+        let mut tx = self.pool.begin().await?;
+        let card = Self::redeem_in_tx(&mut tx, id, amount).await?;
+        tx.commit().await?;
+        Ok(card)
+    }
+}
+
+// Transactional methods
+impl PgGiftCardRepository {
+    /// Spends `amount` from a card within an existing transaction.
+    ///
+    /// `SELECT ... FOR UPDATE` holds the card's row until the transaction
+    /// ends, so a concurrent redemption waits and then sees the reduced
+    /// balance instead of spending the same money twice.
+    pub async fn redeem_in_tx(
+        tx: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+        id: GiftCardId,
+        amount: Decimal,
+    ) -> Result<GiftCard, SalesError> {
+        let row = sqlx::query_as::<_, GiftCardRow>(&format!(
+            "SELECT {} FROM gift_cards WHERE id = $1 FOR UPDATE",
+            GIFT_CARD_COLUMNS
+        ))
+        .bind(id.into_uuid())
+        .fetch_optional(&mut **tx)
+        .await?
+        .ok_or_else(|| SalesError::GiftCardNotFound(id.into_uuid().to_string()))?;
+
+        let mut card = GiftCard::try_from(row)?;
+        card.redeem(amount)?;
+        Self::update_with(&mut **tx, &card).await?;
+        Ok(card)
+    }
+
+    async fn update_with<'e, E>(executor: E, card: &GiftCard) -> Result<(), SalesError>
+    where
+        E: sqlx::PgExecutor<'e>,
+    {
+        let result = sqlx::query(
+            r#"
+            UPDATE gift_cards SET
+                current_balance = $2, status = $3, updated_at = $4
+            WHERE id = $1
+            "#,
+        )
+        .bind(card.id().into_uuid())
+        .bind(card.current_balance())
+        .bind(card.status().to_string())
+        .bind(card.updated_at())
+        .execute(executor)
+        .await?;
+
+        if result.rows_affected() == 0 {
+            return Err(SalesError::GiftCardNotFound(card.code().to_string()));
+        }
+
+        Ok(())
+    }
+}
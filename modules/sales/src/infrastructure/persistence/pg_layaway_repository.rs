@@ -0,0 +1,232 @@
+//! PostgreSQL LayawayRepository implementation
+
+use std::str::FromStr;
+
+use async_trait::async_trait;
+use chrono::{DateTime, NaiveDate, Utc};
+use rust_decimal::Decimal;
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::SalesError;
+use crate::domain::entities::{Layaway, LayawayInstallment};
+use crate::domain::repositories::LayawayRepository;
+use crate::domain::value_objects::{LayawayId, LayawayStatus, SaleId};
+use identity::{StoreId, UserId};
+
+/// Row type for reading layaways from the database
+#[derive(Debug, sqlx::FromRow)]
+struct LayawayRow {
+    id: Uuid,
+    sale_id: Uuid,
+    store_id: Uuid,
+    status: String,
+    installment_count: i32,
+    interval_days: i32,
+    restocking_fee: Decimal,
+    refund_amount: Decimal,
+    cancel_reason: Option<String>,
+    created_by_id: Uuid,
+    activated_at: Option<DateTime<Utc>>,
+    completed_at: Option<DateTime<Utc>>,
+    cancelled_at: Option<DateTime<Utc>>,
+    created_at: DateTime<Utc>,
+    updated_at: DateTime<Utc>,
+}
+
+/// Row type for reading layaway installments from the database
+#[derive(Debug, sqlx::FromRow)]
+struct LayawayInstallmentRow {
+    number: i32,
+    due_date: NaiveDate,
+    amount: Decimal,
+    amount_paid: Decimal,
+}
+
+impl LayawayRow {
+    fn into_layaway(self, installments: Vec<LayawayInstallmentRow>) -> Result<Layaway, SalesError> {
+        Ok(Layaway::reconstitute(
+            LayawayId::from_uuid(self.id),
+            SaleId::from_uuid(self.sale_id),
+            StoreId::from_uuid(self.store_id),
+            LayawayStatus::from_str(&self.status)?,
+            self.installment_count,
+            self.interval_days,
+            installments
+                .into_iter()
+                .map(|i| {
+                    LayawayInstallment::reconstitute(i.number, i.due_date, i.amount, i.amount_paid)
+                })
+                .collect(),
+            self.restocking_fee,
+            self.refund_amount,
+            self.cancel_reason,
+            UserId::from_uuid(self.created_by_id),
+            self.activated_at,
+            self.completed_at,
+            self.cancelled_at,
+            self.created_at,
+            self.updated_at,
+        ))
+    }
+}
+
+/// PostgreSQL implementation of LayawayRepository
+pub struct PgLayawayRepository {
+    pool: PgPool,
+}
+
+impl PgLayawayRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl LayawayRepository for PgLayawayRepository {
+    async fn save(&self, layaway: &Layaway) -> Result<(), SalesError> {
+        let mut tx = self.pool.begin().await?;
+        Self::save_in_tx(&mut tx, layaway).await?;
+        tx.commit().await?;
+        Ok(())
+    }
+
+    async fn update(&self, layaway: &Layaway) -> Result<(), SalesError> {
+        let mut tx = self.pool.begin().await?;
+        Self::update_in_tx(&mut tx, layaway).await?;
+        tx.commit().await?;
+        Ok(())
+    }
+
+    async fn find_by_sale(&self, sale_id: SaleId) -> Result<Option<Layaway>, SalesError> {
+        let row = sqlx::query_as::<_, LayawayRow>(
+            r#"
+            SELECT id, sale_id, store_id, status, installment_count, interval_days,
+                   restocking_fee, refund_amount, cancel_reason, created_by_id,
+                   activated_at, completed_at, cancelled_at, created_at, updated_at
+            FROM layaways
+            WHERE sale_id = $1
+            "#,
+        )
+        .bind(sale_id.into_uuid())
+        .fetch_optional(&self.pool)
+        .await?;
+
+        let Some(row) = row else {
+            return Ok(None);
+        };
+
+        let installments = sqlx::query_as::<_, LayawayInstallmentRow>(
+            r#"
+            SELECT number, due_date, amount, amount_paid
+            FROM layaway_installments
+            WHERE layaway_id = $1
+            ORDER BY number
+            "#,
+        )
+        .bind(row.id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        row.into_layaway(installments).map(Some)
+    }
+}
+
+// Transactional methods
+impl PgLayawayRepository {
+    /// Saves a new layaway with its schedule within an existing transaction
+    pub async fn save_in_tx(
+        tx: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+        layaway: &Layaway,
+    ) -> Result<(), SalesError> {
+        sqlx::query(
+            r#"
+            INSERT INTO layaways (
+                id, sale_id, store_id, status, installment_count, interval_days,
+                restocking_fee, refund_amount, cancel_reason, created_by_id,
+                activated_at, completed_at, cancelled_at, created_at, updated_at
+            )
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15)
+            "#,
+        )
+        .bind(layaway.id().into_uuid())
+        .bind(layaway.sale_id().into_uuid())
+        .bind(layaway.store_id().into_uuid())
+        .bind(layaway.status().to_string())
+        .bind(layaway.installment_count())
+        .bind(layaway.interval_days())
+        .bind(layaway.restocking_fee())
+        .bind(layaway.refund_amount())
+        .bind(layaway.cancel_reason())
+        .bind(layaway.created_by_id().into_uuid())
+        .bind(layaway.activated_at())
+        .bind(layaway.completed_at())
+        .bind(layaway.cancelled_at())
+        .bind(layaway.created_at())
+        .bind(layaway.updated_at())
+        .execute(&mut **tx)
+        .await?;
+
+        Self::insert_installments(tx, layaway).await
+    }
+
+    /// Updates a layaway within an existing transaction, replacing its
+    /// schedule
+    pub async fn update_in_tx(
+        tx: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+        layaway: &Layaway,
+    ) -> Result<(), SalesError> {
+        let result = sqlx::query(
+            r#"
+            UPDATE layaways
+            SET status = $2, restocking_fee = $3, refund_amount = $4, cancel_reason = $5,
+                activated_at = $6, completed_at = $7, cancelled_at = $8, updated_at = $9
+            WHERE id = $1
+            "#,
+        )
+        .bind(layaway.id().into_uuid())
+        .bind(layaway.status().to_string())
+        .bind(layaway.restocking_fee())
+        .bind(layaway.refund_amount())
+        .bind(layaway.cancel_reason())
+        .bind(layaway.activated_at())
+        .bind(layaway.completed_at())
+        .bind(layaway.cancelled_at())
+        .bind(layaway.updated_at())
+        .execute(&mut **tx)
+        .await?;
+
+        if result.rows_affected() == 0 {
+            return Err(SalesError::LayawayNotFound(layaway.sale_id().into_uuid()));
+        }
+
+        sqlx::query("DELETE FROM layaway_installments WHERE layaway_id = $1")
+            .bind(layaway.id().into_uuid())
+            .execute(&mut **tx)
+            .await?;
+
+        Self::insert_installments(tx, layaway).await
+    }
+
+    async fn insert_installments(
+        tx: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+        layaway: &Layaway,
+    ) -> Result<(), SalesError> {
+        for installment in layaway.installments() {
+            sqlx::query(
+                r#"
+                INSERT INTO layaway_installments (layaway_id, number, due_date, amount, amount_paid)
+                VALUES ($1, $2, $3, $4, $5)
+                "#,
+            )
+            .bind(layaway.id().into_uuid())
+            .bind(installment.number())
+            .bind(installment.due_date())
+            .bind(installment.amount())
+            .bind(installment.amount_paid())
+            .execute(&mut **tx)
+            .await?;
+        }
+        Ok(())
+    }
+}
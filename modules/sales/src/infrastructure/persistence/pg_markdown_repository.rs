@@ -0,0 +1,442 @@
+//! PostgreSQL MarkdownRepository implementation
+
+use std::str::FromStr;
+
+use async_trait::async_trait;
+use chrono::{DateTime, NaiveDate, Utc};
+use rust_decimal::Decimal;
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::SalesError;
+use crate::domain::entities::{Markdown, MarkdownRule};
+use crate::domain::repositories::{MarkdownFilter, MarkdownRepository};
+use crate::domain::value_objects::{
+    MarkdownId, MarkdownReason, MarkdownRuleId, MarkdownStatus, PromotionId,
+};
+use identity::UserId;
+
+/// Row type for reading markdown rules from the database
+#[derive(Debug, sqlx::FromRow)]
+struct MarkdownRuleRow {
+    id: Uuid,
+    store_id: Uuid,
+    category_id: Uuid,
+    expiry_window_days: Option<i32>,
+    idle_days: Option<i32>,
+    discount_percent: Decimal,
+    duration_days: i32,
+    is_active: bool,
+    created_by_id: Uuid,
+    created_at: DateTime<Utc>,
+    updated_at: DateTime<Utc>,
+}
+
+impl From<MarkdownRuleRow> for MarkdownRule {
+    fn from(row: MarkdownRuleRow) -> Self {
+        MarkdownRule::reconstitute(
+            MarkdownRuleId::from_uuid(row.id),
+            row.store_id,
+            row.category_id,
+            row.expiry_window_days,
+            row.idle_days,
+            row.discount_percent,
+            row.duration_days,
+            row.is_active,
+            UserId::from_uuid(row.created_by_id),
+            row.created_at,
+            row.updated_at,
+        )
+    }
+}
+
+/// Row type for reading markdowns from the database
+#[derive(Debug, sqlx::FromRow)]
+struct MarkdownRow {
+    id: Uuid,
+    rule_id: Uuid,
+    store_id: Uuid,
+    product_id: Uuid,
+    promotion_id: Uuid,
+    reason: String,
+    reason_detail: String,
+    discount_percent: Decimal,
+    starts_at: DateTime<Utc>,
+    ends_at: DateTime<Utc>,
+    status: String,
+    reversed_by_id: Option<Uuid>,
+    reversed_at: Option<DateTime<Utc>>,
+    reversal_reason: Option<String>,
+    created_at: DateTime<Utc>,
+}
+
+impl TryFrom<MarkdownRow> for Markdown {
+    type Error = SalesError;
+
+    fn try_from(row: MarkdownRow) -> Result<Self, Self::Error> {
+        Ok(Markdown::reconstitute(
+            MarkdownId::from_uuid(row.id),
+            MarkdownRuleId::from_uuid(row.rule_id),
+            row.store_id,
+            row.product_id,
+            PromotionId::from_uuid(row.promotion_id),
+            MarkdownReason::from_str(&row.reason)?,
+            row.reason_detail,
+            row.discount_percent,
+            row.starts_at,
+            row.ends_at,
+            MarkdownStatus::from_str(&row.status)?,
+            row.reversed_by_id.map(UserId::from_uuid),
+            row.reversed_at,
+            row.reversal_reason,
+            row.created_at,
+        ))
+    }
+}
+
+pub struct PgMarkdownRepository {
+    pool: PgPool,
+}
+
+impl PgMarkdownRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl MarkdownRepository for PgMarkdownRepository {
+    async fn save_rule(&self, rule: &MarkdownRule) -> Result<(), SalesError> {
+        sqlx::query(
+            r#"
+            INSERT INTO markdown_rules (
+                id, store_id, category_id, expiry_window_days, idle_days,
+                discount_percent, duration_days, is_active, created_by_id,
+                created_at, updated_at
+            )
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11)
+            "#,
+        )
+        .bind(rule.id().into_uuid())
+        .bind(rule.store_id())
+        .bind(rule.category_id())
+        .bind(rule.expiry_window_days())
+        .bind(rule.idle_days())
+        .bind(rule.discount_percent())
+        .bind(rule.duration_days())
+        .bind(rule.is_active())
+        .bind(rule.created_by_id().into_uuid())
+        .bind(rule.created_at())
+        .bind(rule.updated_at())
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    async fn update_rule(&self, rule: &MarkdownRule) -> Result<(), SalesError> {
+        sqlx::query(
+            r#"
+            UPDATE markdown_rules SET
+                expiry_window_days = $2, idle_days = $3, discount_percent = $4,
+                duration_days = $5, is_active = $6, updated_at = $7
+            WHERE id = $1
+            "#,
+        )
+        .bind(rule.id().into_uuid())
+        .bind(rule.expiry_window_days())
+        .bind(rule.idle_days())
+        .bind(rule.discount_percent())
+        .bind(rule.duration_days())
+        .bind(rule.is_active())
+        .bind(rule.updated_at())
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    async fn find_rule(
+        &self,
+        store_id: Uuid,
+        category_id: Uuid,
+    ) -> Result<Option<MarkdownRule>, SalesError> {
+        let row = sqlx::query_as::<_, MarkdownRuleRow>(
+            r#"SELECT * FROM markdown_rules WHERE store_id = $1 AND category_id = $2"#,
+        )
+        .bind(store_id)
+        .bind(category_id)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(row.map(MarkdownRule::from))
+    }
+
+    async fn find_rule_by_id(
+        &self,
+        id: MarkdownRuleId,
+    ) -> Result<Option<MarkdownRule>, SalesError> {
+        let row =
+            sqlx::query_as::<_, MarkdownRuleRow>(r#"SELECT * FROM markdown_rules WHERE id = $1"#)
+                .bind(id.into_uuid())
+                .fetch_optional(&self.pool)
+                .await?;
+
+        Ok(row.map(MarkdownRule::from))
+    }
+
+    async fn list_rules(&self, store_id: Option<Uuid>) -> Result<Vec<MarkdownRule>, SalesError> {
+        let rows = sqlx::query_as::<_, MarkdownRuleRow>(
+            r#"
+            SELECT * FROM markdown_rules
+            WHERE ($1::UUID IS NULL OR store_id = $1)
+            ORDER BY created_at DESC
+            "#,
+        )
+        .bind(store_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows.into_iter().map(MarkdownRule::from).collect())
+    }
+
+    async fn find_active_rules(&self) -> Result<Vec<MarkdownRule>, SalesError> {
+        let rows = sqlx::query_as::<_, MarkdownRuleRow>(
+            r#"SELECT * FROM markdown_rules WHERE is_active = TRUE ORDER BY store_id, created_at"#,
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows.into_iter().map(MarkdownRule::from).collect())
+    }
+
+    async fn save(&self, markdown: &Markdown) -> Result<(), SalesError> {
+        sqlx::query(
+            r#"
+            INSERT INTO markdowns (
+                id, rule_id, store_id, product_id, promotion_id, reason,
+                reason_detail, discount_percent, starts_at, ends_at, status,
+                reversed_by_id, reversed_at, reversal_reason, created_at
+            )
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15)
+            "#,
+        )
+        .bind(markdown.id().into_uuid())
+        .bind(markdown.rule_id().into_uuid())
+        .bind(markdown.store_id())
+        .bind(markdown.product_id())
+        .bind(markdown.promotion_id().into_uuid())
+        .bind(markdown.reason().to_string())
+        .bind(markdown.reason_detail())
+        .bind(markdown.discount_percent())
+        .bind(markdown.starts_at())
+        .bind(markdown.ends_at())
+        .bind(markdown.status().to_string())
+        .bind(markdown.reversed_by_id().map(|id| id.into_uuid()))
+        .bind(markdown.reversed_at())
+        .bind(markdown.reversal_reason())
+        .bind(markdown.created_at())
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    async fn update(&self, markdown: &Markdown) -> Result<(), SalesError> {
+        sqlx::query(
+            r#"
+            UPDATE markdowns SET
+                status = $2, reversed_by_id = $3, reversed_at = $4, reversal_reason = $5
+            WHERE id = $1
+            "#,
+        )
+        .bind(markdown.id().into_uuid())
+        .bind(markdown.status().to_string())
+        .bind(markdown.reversed_by_id().map(|id| id.into_uuid()))
+        .bind(markdown.reversed_at())
+        .bind(markdown.reversal_reason())
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    async fn find_by_id(&self, id: MarkdownId) -> Result<Option<Markdown>, SalesError> {
+        let row = sqlx::query_as::<_, MarkdownRow>(r#"SELECT * FROM markdowns WHERE id = $1"#)
+            .bind(id.into_uuid())
+            .fetch_optional(&self.pool)
+            .await?;
+
+        row.map(Markdown::try_from).transpose()
+    }
+
+    async fn has_active_for_product(
+        &self,
+        store_id: Uuid,
+        product_id: Uuid,
+    ) -> Result<bool, SalesError> {
+        let exists: (bool,) = sqlx::query_as(
+            r#"
+            SELECT EXISTS (
+                SELECT 1 FROM markdowns
+                WHERE store_id = $1 AND product_id = $2 AND status = 'active'
+            )
+            "#,
+        )
+        .bind(store_id)
+        .bind(product_id)
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(exists.0)
+    }
+
+    async fn expire_ended(&self, now: DateTime<Utc>) -> Result<u64, SalesError> {
+        let result = sqlx::query(
+            r#"UPDATE markdowns SET status = 'expired' WHERE status = 'active' AND ends_at <= $1"#,
+        )
+        .bind(now)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(result.rows_affected())
+    }
+
+    async fn find_paginated(
+        &self,
+        filter: MarkdownFilter,
+        page: i64,
+        page_size: i64,
+    ) -> Result<(Vec<Markdown>, i64), SalesError> {
+        let offset = if filter.after_id.is_some() {
+            0
+        } else {
+            (page - 1) * page_size
+        };
+
+        let count: (i64,) = sqlx::query_as(
+            r#"
+            SELECT COUNT(*) FROM markdowns
+            WHERE ($1::UUID IS NULL OR store_id = $1)
+              AND ($2::UUID IS NULL OR product_id = $2)
+              AND ($3::TEXT IS NULL OR status = $3)
+            "#,
+        )
+        .bind(filter.store_id)
+        .bind(filter.product_id)
+        .bind(filter.status.as_deref())
+        .fetch_one(&self.pool)
+        .await?;
+
+        let rows = sqlx::query_as::<_, MarkdownRow>(
+            r#"
+            SELECT * FROM markdowns
+            WHERE ($1::UUID IS NULL OR store_id = $1)
+              AND ($2::UUID IS NULL OR product_id = $2)
+              AND ($3::TEXT IS NULL OR status = $3)
+              AND ($6::UUID IS NULL OR id < $6)
+            ORDER BY id DESC
+            LIMIT $4 OFFSET $5
+            "#,
+        )
+        .bind(filter.store_id)
+        .bind(filter.product_id)
+        .bind(filter.status.as_deref())
+        .bind(page_size)
+        .bind(offset)
+        .bind(filter.after_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        let markdowns: Result<Vec<Markdown>, _> =
+            rows.into_iter().map(Markdown::try_from).collect();
+
+        Ok((markdowns?, count.0))
+    }
+
+    async fn find_expiring_products(
+        &self,
+        store_id: Uuid,
+        category_id: Uuid,
+        from: NaiveDate,
+        to: NaiveDate,
+    ) -> Result<Vec<(Uuid, NaiveDate)>, SalesError> {
+        let rows: Vec<(Uuid, NaiveDate)> = sqlx::query_as(
+            r#"
+            SELECT p.id, MIN(gri.expiry_date) AS earliest_expiry
+            FROM products p
+            JOIN goods_receipt_items gri ON gri.product_id = p.id
+            JOIN goods_receipts gr ON gr.id = gri.goods_receipt_id
+            WHERE gr.store_id = $1
+              AND gr.status = 'confirmed'
+              AND p.category_id = $2
+              AND p.is_active = TRUE
+              AND gri.expiry_date IS NOT NULL
+              AND gri.expiry_date BETWEEN $3 AND $4
+              AND EXISTS (
+                  SELECT 1
+                  FROM inventory_stock st
+                  LEFT JOIN product_variants pv ON pv.id = st.variant_id
+                  WHERE st.store_id = $1
+                    AND st.quantity > 0
+                    AND COALESCE(st.product_id, pv.product_id) = p.id
+              )
+            GROUP BY p.id
+            ORDER BY earliest_expiry
+            "#,
+        )
+        .bind(store_id)
+        .bind(category_id)
+        .bind(from)
+        .bind(to)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows)
+    }
+
+    async fn find_idle_products(
+        &self,
+        store_id: Uuid,
+        category_id: Uuid,
+        idle_since: DateTime<Utc>,
+    ) -> Result<Vec<(Uuid, Option<DateTime<Utc>>)>, SalesError> {
+        let rows: Vec<(Uuid, Option<DateTime<Utc>>)> = sqlx::query_as(
+            r#"
+            WITH stocked AS (
+                SELECT COALESCE(st.product_id, pv.product_id) AS product_id,
+                       MIN(st.created_at) AS stocked_since
+                FROM inventory_stock st
+                LEFT JOIN product_variants pv ON pv.id = st.variant_id
+                WHERE st.store_id = $1 AND st.quantity > 0
+                GROUP BY COALESCE(st.product_id, pv.product_id)
+            ),
+            last_sale AS (
+                SELECT si.product_id, MAX(s.completed_at) AS last_sold_at
+                FROM sales s
+                JOIN sale_items si ON si.sale_id = s.id
+                WHERE s.store_id = $1
+                  AND s.status IN ('completed', 'returned')
+                  AND s.completed_at IS NOT NULL
+                GROUP BY si.product_id
+            )
+            SELECT p.id, ls.last_sold_at
+            FROM products p
+            JOIN stocked sk ON sk.product_id = p.id
+            LEFT JOIN last_sale ls ON ls.product_id = p.id
+            WHERE p.category_id = $2
+              AND p.is_active = TRUE
+              AND sk.stocked_since < $3
+              AND (ls.last_sold_at IS NULL OR ls.last_sold_at < $3)
+            ORDER BY ls.last_sold_at NULLS FIRST
+            "#,
+        )
+        .bind(store_id)
+        .bind(category_id)
+        .bind(idle_since)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows)
+    }
+}
@@ -0,0 +1,152 @@
+//! PostgreSQL OrderPickupRepository implementation
+
+use std::str::FromStr;
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::SalesError;
+use crate::domain::entities::OrderPickup;
+use crate::domain::repositories::OrderPickupRepository;
+use crate::domain::value_objects::{PickupStatus, SaleId};
+use identity::{StoreId, UserId};
+
+/// Row type for reading pickups from the database
+#[derive(Debug, sqlx::FromRow)]
+struct OrderPickupRow {
+    sale_id: Uuid,
+    store_id: Uuid,
+    pickup_code: String,
+    status: String,
+    ready_at: DateTime<Utc>,
+    hold_until: DateTime<Utc>,
+    picked_up_at: Option<DateTime<Utc>>,
+    picked_up_by_id: Option<Uuid>,
+    closed_at: Option<DateTime<Utc>>,
+    updated_at: DateTime<Utc>,
+}
+
+impl TryFrom<OrderPickupRow> for OrderPickup {
+    type Error = SalesError;
+
+    fn try_from(row: OrderPickupRow) -> Result<Self, Self::Error> {
+        Ok(OrderPickup::reconstitute(
+            SaleId::from_uuid(row.sale_id),
+            StoreId::from_uuid(row.store_id),
+            row.pickup_code,
+            PickupStatus::from_str(&row.status)?,
+            row.ready_at,
+            row.hold_until,
+            row.picked_up_at,
+            row.picked_up_by_id.map(UserId::from_uuid),
+            row.closed_at,
+            row.updated_at,
+        ))
+    }
+}
+
+/// PostgreSQL implementation of OrderPickupRepository
+pub struct PgOrderPickupRepository {
+    pool: PgPool,
+}
+
+impl PgOrderPickupRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl OrderPickupRepository for PgOrderPickupRepository {
+    async fn save(&self, pickup: &OrderPickup) -> Result<(), SalesError> {
+        sqlx::query(
+            r#"
+            INSERT INTO order_pickups (
+                sale_id, store_id, pickup_code, status, ready_at, hold_until,
+                picked_up_at, picked_up_by_id, closed_at, updated_at
+            )
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10)
+            "#,
+        )
+        .bind(pickup.sale_id().into_uuid())
+        .bind(pickup.store_id().into_uuid())
+        .bind(pickup.pickup_code())
+        .bind(pickup.status().to_string())
+        .bind(pickup.ready_at())
+        .bind(pickup.hold_until())
+        .bind(pickup.picked_up_at())
+        .bind(pickup.picked_up_by_id().map(|id| id.into_uuid()))
+        .bind(pickup.closed_at())
+        .bind(pickup.updated_at())
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    async fn update(&self, pickup: &OrderPickup) -> Result<(), SalesError> {
+        let result = sqlx::query(
+            r#"
+            UPDATE order_pickups SET
+                status = $2, picked_up_at = $3, picked_up_by_id = $4,
+                closed_at = $5, updated_at = $6
+            WHERE sale_id = $1
+            "#,
+        )
+        .bind(pickup.sale_id().into_uuid())
+        .bind(pickup.status().to_string())
+        .bind(pickup.picked_up_at())
+        .bind(pickup.picked_up_by_id().map(|id| id.into_uuid()))
+        .bind(pickup.closed_at())
+        .bind(pickup.updated_at())
+        .execute(&self.pool)
+        .await?;
+
+        if result.rows_affected() == 0 {
+            return Err(SalesError::PickupNotFound(pickup.sale_id().into_uuid()));
+        }
+
+        Ok(())
+    }
+
+    async fn find_by_sale(&self, sale_id: SaleId) -> Result<Option<OrderPickup>, SalesError> {
+        let row = sqlx::query_as::<_, OrderPickupRow>(
+            r#"
+            SELECT sale_id, store_id, pickup_code, status, ready_at, hold_until,
+                   picked_up_at, picked_up_by_id, closed_at, updated_at
+            FROM order_pickups
+            WHERE sale_id = $1
+            "#,
+        )
+        .bind(sale_id.into_uuid())
+        .fetch_optional(&self.pool)
+        .await?;
+
+        row.map(OrderPickup::try_from).transpose()
+    }
+
+    async fn find_overdue(
+        &self,
+        now: DateTime<Utc>,
+        limit: i64,
+    ) -> Result<Vec<OrderPickup>, SalesError> {
+        let rows = sqlx::query_as::<_, OrderPickupRow>(
+            r#"
+            SELECT sale_id, store_id, pickup_code, status, ready_at, hold_until,
+                   picked_up_at, picked_up_by_id, closed_at, updated_at
+            FROM order_pickups
+            WHERE status = 'waiting' AND hold_until < $1
+            ORDER BY hold_until
+            LIMIT $2
+            "#,
+        )
+        .bind(now)
+        .bind(limit)
+        .fetch_all(&self.pool)
+        .await?;
+
+        rows.into_iter().map(OrderPickup::try_from).collect()
+    }
+}
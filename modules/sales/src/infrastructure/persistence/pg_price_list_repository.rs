@@ -0,0 +1,261 @@
+//! PostgreSQL PriceListRepository implementation
+
+use std::str::FromStr;
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::SalesError;
+use crate::domain::entities::{PriceList, PriceListEntry};
+use crate::domain::repositories::PriceListRepository;
+use crate::domain::value_objects::{CustomerType, PriceListId};
+use identity::UserId;
+
+/// Row type for reading price lists from the database
+#[derive(Debug, sqlx::FromRow)]
+struct PriceListRow {
+    id: Uuid,
+    store_id: Uuid,
+    name: String,
+    customer_types: Vec<String>,
+    is_default: bool,
+    is_active: bool,
+    created_by_id: Uuid,
+    created_at: DateTime<Utc>,
+    updated_at: DateTime<Utc>,
+}
+
+impl TryFrom<PriceListRow> for PriceList {
+    type Error = SalesError;
+
+    fn try_from(row: PriceListRow) -> Result<Self, Self::Error> {
+        let customer_types = row
+            .customer_types
+            .iter()
+            .map(|t| CustomerType::from_str(t))
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(PriceList::reconstitute(
+            PriceListId::from_uuid(row.id),
+            row.store_id,
+            row.name,
+            customer_types,
+            row.is_default,
+            row.is_active,
+            UserId::from_uuid(row.created_by_id),
+            row.created_at,
+            row.updated_at,
+        ))
+    }
+}
+
+/// Row type for reading price list entries from the database
+#[derive(Debug, sqlx::FromRow)]
+struct PriceListEntryRow {
+    id: Uuid,
+    price_list_id: Uuid,
+    product_id: Uuid,
+    variant_id: Option<Uuid>,
+    price: Decimal,
+    created_at: DateTime<Utc>,
+    updated_at: DateTime<Utc>,
+}
+
+impl From<PriceListEntryRow> for PriceListEntry {
+    fn from(row: PriceListEntryRow) -> Self {
+        PriceListEntry::reconstitute(
+            row.id,
+            PriceListId::from_uuid(row.price_list_id),
+            row.product_id,
+            row.variant_id,
+            row.price,
+            row.created_at,
+            row.updated_at,
+        )
+    }
+}
+
+pub struct PgPriceListRepository {
+    pool: PgPool,
+}
+
+impl PgPriceListRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl PriceListRepository for PgPriceListRepository {
+    async fn save(&self, list: &PriceList) -> Result<(), SalesError> {
+        let customer_types: Vec<String> = list
+            .customer_types()
+            .iter()
+            .map(|t| t.to_string())
+            .collect();
+
+        sqlx::query(
+            r#"
+            INSERT INTO price_lists (
+                id, store_id, name, customer_types, is_default, is_active,
+                created_by_id, created_at, updated_at
+            )
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
+            "#,
+        )
+        .bind(list.id().into_uuid())
+        .bind(list.store_id())
+        .bind(list.name())
+        .bind(customer_types)
+        .bind(list.is_default())
+        .bind(list.is_active())
+        .bind(list.created_by_id().into_uuid())
+        .bind(list.created_at())
+        .bind(list.updated_at())
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    async fn find_by_id(&self, id: PriceListId) -> Result<Option<PriceList>, SalesError> {
+        let row = sqlx::query_as::<_, PriceListRow>(r#"SELECT * FROM price_lists WHERE id = $1"#)
+            .bind(id.into_uuid())
+            .fetch_optional(&self.pool)
+            .await?;
+
+        row.map(PriceList::try_from).transpose()
+    }
+
+    async fn list(&self, store_id: Option<Uuid>) -> Result<Vec<PriceList>, SalesError> {
+        let rows = sqlx::query_as::<_, PriceListRow>(
+            r#"
+            SELECT * FROM price_lists
+            WHERE ($1::UUID IS NULL OR store_id = $1)
+            ORDER BY created_at DESC
+            "#,
+        )
+        .bind(store_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        rows.into_iter().map(PriceList::try_from).collect()
+    }
+
+    async fn find_active_by_store(&self, store_id: Uuid) -> Result<Vec<PriceList>, SalesError> {
+        let rows = sqlx::query_as::<_, PriceListRow>(
+            r#"
+            SELECT * FROM price_lists
+            WHERE store_id = $1 AND is_active = TRUE
+            ORDER BY created_at
+            "#,
+        )
+        .bind(store_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        rows.into_iter().map(PriceList::try_from).collect()
+    }
+
+    async fn save_entry(&self, entry: &PriceListEntry) -> Result<(), SalesError> {
+        sqlx::query(
+            r#"
+            INSERT INTO price_list_entries (
+                id, price_list_id, product_id, variant_id, price, created_at, updated_at
+            )
+            VALUES ($1, $2, $3, $4, $5, $6, $7)
+            "#,
+        )
+        .bind(entry.id())
+        .bind(entry.price_list_id().into_uuid())
+        .bind(entry.product_id())
+        .bind(entry.variant_id())
+        .bind(entry.price())
+        .bind(entry.created_at())
+        .bind(entry.updated_at())
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    async fn update_entry(&self, entry: &PriceListEntry) -> Result<(), SalesError> {
+        sqlx::query(
+            r#"
+            UPDATE price_list_entries SET price = $2, updated_at = $3
+            WHERE id = $1
+            "#,
+        )
+        .bind(entry.id())
+        .bind(entry.price())
+        .bind(entry.updated_at())
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    async fn find_entry(
+        &self,
+        price_list_id: PriceListId,
+        product_id: Uuid,
+        variant_id: Option<Uuid>,
+    ) -> Result<Option<PriceListEntry>, SalesError> {
+        let row = sqlx::query_as::<_, PriceListEntryRow>(
+            r#"
+            SELECT * FROM price_list_entries
+            WHERE price_list_id = $1 AND product_id = $2
+              AND variant_id IS NOT DISTINCT FROM $3
+            "#,
+        )
+        .bind(price_list_id.into_uuid())
+        .bind(product_id)
+        .bind(variant_id)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(row.map(PriceListEntry::from))
+    }
+
+    async fn list_entries(
+        &self,
+        price_list_id: PriceListId,
+    ) -> Result<Vec<PriceListEntry>, SalesError> {
+        let rows = sqlx::query_as::<_, PriceListEntryRow>(
+            r#"
+            SELECT * FROM price_list_entries
+            WHERE price_list_id = $1
+            ORDER BY created_at
+            "#,
+        )
+        .bind(price_list_id.into_uuid())
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows.into_iter().map(PriceListEntry::from).collect())
+    }
+
+    async fn find_applicable_entries(
+        &self,
+        price_list_id: PriceListId,
+        product_id: Uuid,
+        variant_id: Option<Uuid>,
+    ) -> Result<Vec<PriceListEntry>, SalesError> {
+        let rows = sqlx::query_as::<_, PriceListEntryRow>(
+            r#"
+            SELECT * FROM price_list_entries
+            WHERE price_list_id = $1 AND product_id = $2
+              AND (variant_id IS NULL OR variant_id = $3)
+            "#,
+        )
+        .bind(price_list_id.into_uuid())
+        .bind(product_id)
+        .bind(variant_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows.into_iter().map(PriceListEntry::from).collect())
+    }
+}
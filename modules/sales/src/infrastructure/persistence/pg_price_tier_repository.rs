@@ -0,0 +1,183 @@
+//! PostgreSQL PriceTierRepository implementation
+
+use std::str::FromStr;
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::SalesError;
+use crate::domain::entities::PriceTier;
+use crate::domain::repositories::PriceTierRepository;
+use crate::domain::value_objects::{CustomerType, PriceTierId};
+use identity::UserId;
+
+/// Row type for reading price tiers from the database
+#[derive(Debug, sqlx::FromRow)]
+struct PriceTierRow {
+    id: Uuid,
+    store_id: Uuid,
+    customer_type: String,
+    product_id: Option<Uuid>,
+    category_id: Option<Uuid>,
+    price: Option<Decimal>,
+    discount_percent: Option<Decimal>,
+    is_active: bool,
+    created_by_id: Uuid,
+    created_at: DateTime<Utc>,
+    updated_at: DateTime<Utc>,
+}
+
+impl TryFrom<PriceTierRow> for PriceTier {
+    type Error = SalesError;
+
+    fn try_from(row: PriceTierRow) -> Result<Self, Self::Error> {
+        Ok(PriceTier::reconstitute(
+            PriceTierId::from_uuid(row.id),
+            row.store_id,
+            CustomerType::from_str(&row.customer_type)?,
+            row.product_id,
+            row.category_id,
+            row.price,
+            row.discount_percent,
+            row.is_active,
+            UserId::from_uuid(row.created_by_id),
+            row.created_at,
+            row.updated_at,
+        ))
+    }
+}
+
+pub struct PgPriceTierRepository {
+    pool: PgPool,
+}
+
+impl PgPriceTierRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl PriceTierRepository for PgPriceTierRepository {
+    async fn save(&self, tier: &PriceTier) -> Result<(), SalesError> {
+        sqlx::query(
+            r#"
+            INSERT INTO price_tiers (
+                id, store_id, customer_type, product_id, category_id, price,
+                discount_percent, is_active, created_by_id, created_at, updated_at
+            )
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11)
+            "#,
+        )
+        .bind(tier.id().into_uuid())
+        .bind(tier.store_id())
+        .bind(tier.customer_type().to_string())
+        .bind(tier.product_id())
+        .bind(tier.category_id())
+        .bind(tier.price())
+        .bind(tier.discount_percent())
+        .bind(tier.is_active())
+        .bind(tier.created_by_id().into_uuid())
+        .bind(tier.created_at())
+        .bind(tier.updated_at())
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    async fn update(&self, tier: &PriceTier) -> Result<(), SalesError> {
+        sqlx::query(
+            r#"
+            UPDATE price_tiers SET
+                price = $2, discount_percent = $3, is_active = $4, updated_at = $5
+            WHERE id = $1
+            "#,
+        )
+        .bind(tier.id().into_uuid())
+        .bind(tier.price())
+        .bind(tier.discount_percent())
+        .bind(tier.is_active())
+        .bind(tier.updated_at())
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    async fn find_by_id(&self, id: PriceTierId) -> Result<Option<PriceTier>, SalesError> {
+        let row = sqlx::query_as::<_, PriceTierRow>(r#"SELECT * FROM price_tiers WHERE id = $1"#)
+            .bind(id.into_uuid())
+            .fetch_optional(&self.pool)
+            .await?;
+
+        row.map(PriceTier::try_from).transpose()
+    }
+
+    async fn find_by_target(
+        &self,
+        store_id: Uuid,
+        customer_type: CustomerType,
+        product_id: Option<Uuid>,
+        category_id: Option<Uuid>,
+    ) -> Result<Option<PriceTier>, SalesError> {
+        let row = sqlx::query_as::<_, PriceTierRow>(
+            r#"
+            SELECT * FROM price_tiers
+            WHERE store_id = $1 AND customer_type = $2
+              AND product_id IS NOT DISTINCT FROM $3
+              AND category_id IS NOT DISTINCT FROM $4
+            "#,
+        )
+        .bind(store_id)
+        .bind(customer_type.to_string())
+        .bind(product_id)
+        .bind(category_id)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        row.map(PriceTier::try_from).transpose()
+    }
+
+    async fn list(&self, store_id: Option<Uuid>) -> Result<Vec<PriceTier>, SalesError> {
+        let rows = sqlx::query_as::<_, PriceTierRow>(
+            r#"
+            SELECT * FROM price_tiers
+            WHERE ($1::UUID IS NULL OR store_id = $1)
+            ORDER BY created_at DESC
+            "#,
+        )
+        .bind(store_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        rows.into_iter().map(PriceTier::try_from).collect()
+    }
+
+    async fn find_applicable(
+        &self,
+        store_id: Uuid,
+        customer_type: CustomerType,
+        product_id: Uuid,
+        category_id: Option<Uuid>,
+    ) -> Result<Vec<PriceTier>, SalesError> {
+        let rows = sqlx::query_as::<_, PriceTierRow>(
+            r#"
+            SELECT * FROM price_tiers
+            WHERE store_id = $1 AND customer_type = $2 AND is_active = TRUE
+              AND (product_id = $3 OR ($4::UUID IS NOT NULL AND category_id = $4))
+            "#,
+        )
+        .bind(store_id)
+        .bind(customer_type.to_string())
+        .bind(product_id)
+        .bind(category_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        rows.into_iter().map(PriceTier::try_from).collect()
+    }
+}
@@ -194,7 +194,11 @@ impl PromotionRepository for PgPromotionRepository {
         page: i64,
         page_size: i64,
     ) -> Result<(Vec<Promotion>, i64), SalesError> {
-        let offset = (page - 1) * page_size;
+        let offset = if filter.after_id.is_some() {
+            0
+        } else {
+            (page - 1) * page_size
+        };
 
         let mut conditions = vec!["1=1".to_string()];
         if let Some(ref status) = filter.status {
@@ -218,10 +222,16 @@ impl PromotionRepository for PgPromotionRepository {
         );
         let count: (i64,) = sqlx::query_as(&count_query).fetch_one(&self.pool).await?;
 
-        let data_query = format!(
-            "SELECT * FROM promotions WHERE {} ORDER BY created_at DESC LIMIT {} OFFSET {}",
-            where_clause, page_size, offset
-        );
+        let data_query = match filter.after_id {
+            Some(after_id) => format!(
+                "SELECT * FROM promotions WHERE {} AND id < '{}' ORDER BY id DESC LIMIT {} OFFSET {}",
+                where_clause, after_id, page_size, offset
+            ),
+            None => format!(
+                "SELECT * FROM promotions WHERE {} ORDER BY id DESC LIMIT {} OFFSET {}",
+                where_clause, page_size, offset
+            ),
+        };
         let rows: Vec<PromotionRow> = sqlx::query_as(&data_query).fetch_all(&self.pool).await?;
 
         let promotions: Result<Vec<Promotion>, _> =
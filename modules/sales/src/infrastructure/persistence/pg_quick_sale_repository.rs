@@ -0,0 +1,77 @@
+//! PostgreSQL QuickSaleRepository implementation
+
+use async_trait::async_trait;
+use events::enqueue_outbox_event_in_tx;
+use sqlx::PgPool;
+
+use crate::SalesError;
+use crate::domain::repositories::{QuickSale, QuickSaleRepository};
+use inventory::{InventoryError, PgInventoryMovementRepository, PgInventoryStockRepository};
+
+use super::pg_sale_repository::{draw_cai_invoice_number, sale_completed_event};
+use super::{PgSaleRepository, PgShiftRepository};
+
+/// PostgreSQL implementation of QuickSaleRepository
+pub struct PgQuickSaleRepository {
+    pool: PgPool,
+}
+
+impl PgQuickSaleRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+/// Maps failures of the stock writes onto sales errors
+fn stock_error(error: InventoryError) -> SalesError {
+    match error {
+        InventoryError::Database(e) => SalesError::Database(e),
+        _ => SalesError::StockConflict,
+    }
+}
+
+#[async_trait]
+impl QuickSaleRepository for PgQuickSaleRepository {
+    async fn commit(&self, quick_sale: &mut QuickSale) -> Result<(), SalesError> {
+        let terminal_id = quick_sale
+            .sale
+            .terminal_id()
+            .ok_or(SalesError::NoValidCai(quick_sale.sale.id().into_uuid()))?
+            .into_uuid();
+        let mut tx = self.pool.begin().await?;
+
+        // The range stays locked until commit; a rollback gives the number back
+        let (cai_number, invoice_number) = draw_cai_invoice_number(&mut tx, terminal_id).await?;
+        quick_sale
+            .sale
+            .assign_cai_invoice_number(cai_number, format!("{:08}", invoice_number))?;
+        let sale = &quick_sale.sale;
+
+        PgSaleRepository::save_in_tx(&mut tx, sale).await?;
+        for item in sale.items() {
+            PgSaleRepository::save_item_in_tx(&mut tx, item).await?;
+        }
+        for payment in sale.payments() {
+            PgSaleRepository::save_payment_in_tx(&mut tx, payment).await?;
+        }
+        PgShiftRepository::update_in_tx(&mut tx, &quick_sale.shift).await?;
+
+        for deduction in &quick_sale.stock {
+            PgInventoryStockRepository::update_with_version_in_tx(
+                &mut tx,
+                &deduction.stock,
+                deduction.expected_version,
+            )
+            .await
+            .map_err(stock_error)?;
+            PgInventoryMovementRepository::save_in_tx(&mut tx, &deduction.movement)
+                .await
+                .map_err(stock_error)?;
+        }
+
+        enqueue_outbox_event_in_tx(&mut tx, &sale_completed_event(sale)).await?;
+
+        tx.commit().await?;
+        Ok(())
+    }
+}
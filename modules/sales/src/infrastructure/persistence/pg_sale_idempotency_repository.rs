@@ -0,0 +1,118 @@
+//! PostgreSQL SaleIdempotencyRepository implementation
+
+use std::str::FromStr;
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use pos_core::TerminalId;
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::SalesError;
+use crate::domain::entities::SaleIdempotencyKey;
+use crate::domain::repositories::SaleIdempotencyRepository;
+use crate::domain::value_objects::{IdempotentOperation, SaleId};
+
+/// Row type for reading idempotency keys from the database
+#[derive(Debug, sqlx::FromRow)]
+struct SaleIdempotencyKeyRow {
+    terminal_id: Uuid,
+    idempotency_key: String,
+    operation: String,
+    sale_id: Uuid,
+    created_at: DateTime<Utc>,
+    expires_at: DateTime<Utc>,
+}
+
+impl TryFrom<SaleIdempotencyKeyRow> for SaleIdempotencyKey {
+    type Error = SalesError;
+
+    fn try_from(row: SaleIdempotencyKeyRow) -> Result<Self, Self::Error> {
+        Ok(SaleIdempotencyKey::reconstitute(
+            TerminalId::from_uuid(row.terminal_id),
+            row.idempotency_key,
+            IdempotentOperation::from_str(&row.operation)?,
+            SaleId::from_uuid(row.sale_id),
+            row.created_at,
+            row.expires_at,
+        ))
+    }
+}
+
+/// PostgreSQL implementation of SaleIdempotencyRepository
+pub struct PgSaleIdempotencyRepository {
+    pool: PgPool,
+}
+
+impl PgSaleIdempotencyRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    /// Saves a key within an existing transaction, replacing an expired one.
+    /// A concurrent request that recorded the key first makes this fail with
+    /// `IdempotencyKeyReused`, rolling back the duplicate.
+    pub async fn save_in_tx(
+        tx: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+        record: &SaleIdempotencyKey,
+    ) -> Result<(), SalesError> {
+        let result = sqlx::query(
+            r#"
+            INSERT INTO sale_idempotency_keys (
+                terminal_id, idempotency_key, operation, sale_id, created_at, expires_at
+            )
+            VALUES ($1, $2, $3, $4, $5, $6)
+            ON CONFLICT (terminal_id, idempotency_key) DO UPDATE
+            SET operation = EXCLUDED.operation,
+                sale_id = EXCLUDED.sale_id,
+                created_at = EXCLUDED.created_at,
+                expires_at = EXCLUDED.expires_at
+            WHERE sale_idempotency_keys.expires_at <= EXCLUDED.created_at
+            "#,
+        )
+        .bind(record.terminal_id().into_uuid())
+        .bind(record.key())
+        .bind(record.operation().to_string())
+        .bind(record.sale_id().into_uuid())
+        .bind(record.created_at())
+        .bind(record.expires_at())
+        .execute(&mut **tx)
+        .await?;
+
+        if result.rows_affected() == 0 {
+            return Err(SalesError::IdempotencyKeyReused(record.key().to_string()));
+        }
+
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl SaleIdempotencyRepository for PgSaleIdempotencyRepository {
+    async fn save(&self, record: &SaleIdempotencyKey) -> Result<(), SalesError> {
+        let mut tx = self.pool.begin().await?;
+        Self::save_in_tx(&mut tx, record).await?;
+        tx.commit().await?;
+        Ok(())
+    }
+
+    async fn find_active(
+        &self,
+        terminal_id: TerminalId,
+        key: &str,
+    ) -> Result<Option<SaleIdempotencyKey>, SalesError> {
+        let row = sqlx::query_as::<_, SaleIdempotencyKeyRow>(
+            r#"
+            SELECT terminal_id, idempotency_key, operation, sale_id, created_at, expires_at
+            FROM sale_idempotency_keys
+            WHERE terminal_id = $1 AND idempotency_key = $2 AND expires_at > NOW()
+            "#,
+        )
+        .bind(terminal_id.into_uuid())
+        .bind(key.trim())
+        .fetch_optional(&self.pool)
+        .await?;
+
+        row.map(SaleIdempotencyKey::try_from).transpose()
+    }
+}
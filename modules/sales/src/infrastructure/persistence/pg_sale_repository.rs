@@ -1,15 +1,17 @@
 //! PostgreSQL SaleRepository implementation
 
 use async_trait::async_trait;
-use sqlx::PgPool;
+use events::{OutboxEvent, enqueue_outbox_event_in_tx};
+use serde_json::json;
+use sqlx::{PgExecutor, PgPool};
 use std::str::FromStr;
 
 use crate::SalesError;
 use crate::domain::entities::{Payment, Sale, SaleItem};
 use crate::domain::repositories::{SaleFilter, SaleRepository};
 use crate::domain::value_objects::{
-    CustomerId, DiscountType, OrderStatus, PaymentId, PaymentMethod, PaymentStatus, SaleId,
-    SaleItemId, SaleStatus, SaleType, ShiftId,
+    CustomerId, DiscountType, OrderStatus, PaymentId, PaymentMethod, PaymentStatus, PriceListId,
+    PriceTierId, PromotionId, SaleId, SaleItemId, SaleStatus, SaleType, ShiftId, TaxRuleId,
 };
 use identity::{StoreId, UserId};
 use inventory::{Currency, ProductId, ReservationId, UnitOfMeasure, VariantId};
@@ -30,8 +32,9 @@ impl PgSaleRepository {
             r#"
             SELECT id, sale_id, line_number, product_id, variant_id, sku, description,
                    quantity, unit_of_measure, unit_price, unit_cost, discount_type,
-                   discount_value, discount_amount, tax_rate, tax_amount, subtotal,
-                   total, reservation_id, notes, created_at, updated_at
+                   discount_value, discount_amount, order_discount_amount, tax_rate, tax_amount,
+                   tax_inclusive, tax_exempt, tax_rule_id, tax_jurisdiction, subtotal,
+                   total, reservation_id, serial_numbers, price_tier_id, price_list_id, promotion_id, notes, created_at, updated_at
             FROM sale_items
             WHERE sale_id = $1
             ORDER BY line_number
@@ -47,7 +50,7 @@ impl PgSaleRepository {
     async fn load_payments(&self, sale_id: SaleId) -> Result<Vec<Payment>, SalesError> {
         let rows = sqlx::query_as::<_, PaymentRow>(
             r#"
-            SELECT id, sale_id, payment_method, status, amount, currency, amount_tendered,
+            SELECT id, sale_id, payment_method, status, amount, currency, exchange_rate, amount_tendered,
                    change_given, reference_number, authorization_code, card_last_four,
                    card_brand, refunded_amount, refunded_at, notes, idempotency_key,
                    processed_at, created_at, updated_at
@@ -67,62 +70,17 @@ impl PgSaleRepository {
 #[async_trait]
 impl SaleRepository for PgSaleRepository {
     async fn save(&self, sale: &Sale) -> Result<(), SalesError> {
-        sqlx::query(
-            r#"
-            INSERT INTO sales (
-                id, sale_number, store_id, sale_type, status, order_status, terminal_id,
-                shift_id, cashier_id, customer_id, currency, subtotal, discount_type,
-                discount_value, discount_amount, tax_amount, total, amount_paid, amount_due,
-                change_given, invoice_number, invoice_date, notes, internal_notes,
-                voided_by_id, voided_at, void_reason, completed_at, created_at, updated_at
-            )
-            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15, $16, $17, $18, $19, $20, $21, $22, $23, $24, $25, $26, $27, $28, $29, $30)
-            "#,
-        )
-        .bind(sale.id().into_uuid())
-        .bind(sale.sale_number())
-        .bind(sale.store_id().into_uuid())
-        .bind(sale.sale_type().to_string())
-        .bind(sale.status().to_string())
-        .bind(sale.order_status().map(|s| s.to_string()))
-        .bind(sale.terminal_id().map(|t| t.into_uuid()))
-        .bind(sale.shift_id().map(|s| s.into_uuid()))
-        .bind(sale.cashier_id().map(|c| c.into_uuid()))
-        .bind(sale.customer_id().map(|c| c.into_uuid()))
-        .bind(sale.currency().as_str())
-        .bind(sale.subtotal())
-        .bind(sale.discount_type().map(|d| d.to_string()))
-        .bind(sale.discount_value())
-        .bind(sale.discount_amount())
-        .bind(sale.tax_amount())
-        .bind(sale.total())
-        .bind(sale.amount_paid())
-        .bind(sale.amount_due())
-        .bind(sale.change_given())
-        .bind(sale.invoice_number())
-        .bind(sale.invoice_date())
-        .bind(sale.notes())
-        .bind(sale.internal_notes())
-        .bind(sale.voided_by_id().map(|u| u.into_uuid()))
-        .bind(sale.voided_at())
-        .bind(sale.void_reason())
-        .bind(sale.completed_at())
-        .bind(sale.created_at())
-        .bind(sale.updated_at())
-        .execute(&self.pool)
-        .await?;
-
-        Ok(())
+        save_q(&self.pool, sale).await
     }
 
     async fn find_by_id(&self, id: SaleId) -> Result<Option<Sale>, SalesError> {
         let row = sqlx::query_as::<_, SaleRow>(
             r#"
             SELECT id, sale_number, store_id, sale_type, status, order_status, terminal_id,
-                   shift_id, cashier_id, customer_id, currency, subtotal, discount_type,
+                   shift_id, cashier_id, customer_id, currency, transaction_currency, exchange_rate, subtotal, discount_type,
                    discount_value, discount_amount, tax_amount, total, amount_paid, amount_due,
-                   change_given, invoice_number, invoice_date, notes, internal_notes,
-                   voided_by_id, voided_at, void_reason, completed_at, created_at, updated_at
+                   change_given, invoice_number, invoice_date, cai_number, notes, internal_notes,
+                   voided_by_id, voided_at, void_reason, completed_at, promotion_id, created_at, updated_at
             FROM sales
             WHERE id = $1
             "#,
@@ -141,10 +99,10 @@ impl SaleRepository for PgSaleRepository {
         let row = sqlx::query_as::<_, SaleRow>(
             r#"
             SELECT id, sale_number, store_id, sale_type, status, order_status, terminal_id,
-                   shift_id, cashier_id, customer_id, currency, subtotal, discount_type,
+                   shift_id, cashier_id, customer_id, currency, transaction_currency, exchange_rate, subtotal, discount_type,
                    discount_value, discount_amount, tax_amount, total, amount_paid, amount_due,
-                   change_given, invoice_number, invoice_date, notes, internal_notes,
-                   voided_by_id, voided_at, void_reason, completed_at, created_at, updated_at
+                   change_given, invoice_number, invoice_date, cai_number, notes, internal_notes,
+                   voided_by_id, voided_at, void_reason, completed_at, promotion_id, created_at, updated_at
             FROM sales
             WHERE id = $1
             "#,
@@ -171,10 +129,10 @@ impl SaleRepository for PgSaleRepository {
         let row = sqlx::query_as::<_, SaleRow>(
             r#"
             SELECT id, sale_number, store_id, sale_type, status, order_status, terminal_id,
-                   shift_id, cashier_id, customer_id, currency, subtotal, discount_type,
+                   shift_id, cashier_id, customer_id, currency, transaction_currency, exchange_rate, subtotal, discount_type,
                    discount_value, discount_amount, tax_amount, total, amount_paid, amount_due,
-                   change_given, invoice_number, invoice_date, notes, internal_notes,
-                   voided_by_id, voided_at, void_reason, completed_at, created_at, updated_at
+                   change_given, invoice_number, invoice_date, cai_number, notes, internal_notes,
+                   voided_by_id, voided_at, void_reason, completed_at, promotion_id, created_at, updated_at
             FROM sales
             WHERE store_id = $1 AND sale_number = $2
             "#,
@@ -198,10 +156,10 @@ impl SaleRepository for PgSaleRepository {
         let row = sqlx::query_as::<_, SaleRow>(
             r#"
             SELECT id, sale_number, store_id, sale_type, status, order_status, terminal_id,
-                   shift_id, cashier_id, customer_id, currency, subtotal, discount_type,
+                   shift_id, cashier_id, customer_id, currency, transaction_currency, exchange_rate, subtotal, discount_type,
                    discount_value, discount_amount, tax_amount, total, amount_paid, amount_due,
-                   change_given, invoice_number, invoice_date, notes, internal_notes,
-                   voided_by_id, voided_at, void_reason, completed_at, created_at, updated_at
+                   change_given, invoice_number, invoice_date, cai_number, notes, internal_notes,
+                   voided_by_id, voided_at, void_reason, completed_at, promotion_id, created_at, updated_at
             FROM sales
             WHERE store_id = $1 AND invoice_number = $2
             "#,
@@ -218,47 +176,42 @@ impl SaleRepository for PgSaleRepository {
     }
 
     async fn update(&self, sale: &Sale) -> Result<(), SalesError> {
-        let result = sqlx::query(
-            r#"
-            UPDATE sales
-            SET status = $2, order_status = $3, customer_id = $4, subtotal = $5,
-                discount_type = $6, discount_value = $7, discount_amount = $8, tax_amount = $9,
-                total = $10, amount_paid = $11, amount_due = $12, change_given = $13,
-                invoice_number = $14, invoice_date = $15, notes = $16, internal_notes = $17,
-                voided_by_id = $18, voided_at = $19, void_reason = $20, completed_at = $21,
-                updated_at = $22
-            WHERE id = $1
-            "#,
-        )
-        .bind(sale.id().into_uuid())
-        .bind(sale.status().to_string())
-        .bind(sale.order_status().map(|s| s.to_string()))
-        .bind(sale.customer_id().map(|c| c.into_uuid()))
-        .bind(sale.subtotal())
-        .bind(sale.discount_type().map(|d| d.to_string()))
-        .bind(sale.discount_value())
-        .bind(sale.discount_amount())
-        .bind(sale.tax_amount())
-        .bind(sale.total())
-        .bind(sale.amount_paid())
-        .bind(sale.amount_due())
-        .bind(sale.change_given())
-        .bind(sale.invoice_number())
-        .bind(sale.invoice_date())
-        .bind(sale.notes())
-        .bind(sale.internal_notes())
-        .bind(sale.voided_by_id().map(|u| u.into_uuid()))
-        .bind(sale.voided_at())
-        .bind(sale.void_reason())
-        .bind(sale.completed_at())
-        .bind(sale.updated_at())
-        .execute(&self.pool)
-        .await?;
+        let mut tx = self.pool.begin().await?;
 
-        if result.rows_affected() == 0 {
-            return Err(SalesError::SaleNotFound(sale.id().into_uuid()));
+        let previous_status = lock_sale_status(&mut tx, sale).await?;
+        Self::update_in_tx(&mut tx, sale).await?;
+
+        if sale.status() == SaleStatus::Completed
+            && previous_status != SaleStatus::Completed.to_string()
+        {
+            enqueue_outbox_event_in_tx(&mut tx, &sale_completed_event(sale)).await?;
+        }
+
+        tx.commit().await?;
+        Ok(())
+    }
+
+    async fn update_with_cai_invoice_number(&self, sale: &mut Sale) -> Result<(), SalesError> {
+        let terminal_id = sale
+            .terminal_id()
+            .ok_or(SalesError::NoValidCai(sale.id().into_uuid()))?
+            .into_uuid();
+
+        let mut tx = self.pool.begin().await?;
+
+        // A sale completed by a concurrent request already holds its number
+        let previous_status = lock_sale_status(&mut tx, sale).await?;
+        if previous_status == SaleStatus::Completed.to_string() {
+            return Err(SalesError::SaleAlreadyCompleted);
         }
 
+        let (cai_number, invoice_number) = draw_cai_invoice_number(&mut tx, terminal_id).await?;
+        sale.assign_cai_invoice_number(cai_number, format!("{:08}", invoice_number))?;
+
+        Self::update_in_tx(&mut tx, sale).await?;
+        enqueue_outbox_event_in_tx(&mut tx, &sale_completed_event(sale)).await?;
+
+        tx.commit().await?;
         Ok(())
     }
 
@@ -268,7 +221,11 @@ impl SaleRepository for PgSaleRepository {
         page: i64,
         page_size: i64,
     ) -> Result<(Vec<Sale>, i64), SalesError> {
-        let offset = (page - 1) * page_size;
+        let offset = if filter.after_id.is_some() {
+            0
+        } else {
+            (page - 1) * page_size
+        };
 
         let mut count_query = String::from("SELECT COUNT(*) FROM sales WHERE 1=1");
         let mut param_idx = 1;
@@ -277,6 +234,10 @@ impl SaleRepository for PgSaleRepository {
             count_query.push_str(&format!(" AND store_id = ${}", param_idx));
             param_idx += 1;
         }
+        if filter.store_ids.is_some() {
+            count_query.push_str(&format!(" AND store_id = ANY(${})", param_idx));
+            param_idx += 1;
+        }
         if filter.status.is_some() {
             count_query.push_str(&format!(" AND status = ${}", param_idx));
             param_idx += 1;
@@ -296,6 +257,10 @@ impl SaleRepository for PgSaleRepository {
         if let Some(store_id) = filter.store_id {
             count_builder = count_builder.bind(store_id.into_uuid());
         }
+        if let Some(store_ids) = &filter.store_ids {
+            let store_ids: Vec<uuid::Uuid> = store_ids.iter().map(|id| *id.as_uuid()).collect();
+            count_builder = count_builder.bind(store_ids);
+        }
         if let Some(status) = filter.status {
             count_builder = count_builder.bind(status.to_string());
         }
@@ -309,10 +274,10 @@ impl SaleRepository for PgSaleRepository {
 
         let mut data_query = String::from(
             r#"SELECT id, sale_number, store_id, sale_type, status, order_status, terminal_id,
-                   shift_id, cashier_id, customer_id, currency, subtotal, discount_type,
+                   shift_id, cashier_id, customer_id, currency, transaction_currency, exchange_rate, subtotal, discount_type,
                    discount_value, discount_amount, tax_amount, total, amount_paid, amount_due,
-                   change_given, invoice_number, invoice_date, notes, internal_notes,
-                   voided_by_id, voided_at, void_reason, completed_at, created_at, updated_at
+                   change_given, invoice_number, invoice_date, cai_number, notes, internal_notes,
+                   voided_by_id, voided_at, void_reason, completed_at, promotion_id, created_at, updated_at
             FROM sales WHERE 1=1"#,
         );
 
@@ -321,6 +286,10 @@ impl SaleRepository for PgSaleRepository {
             data_query.push_str(&format!(" AND store_id = ${}", param_idx));
             param_idx += 1;
         }
+        if filter.store_ids.is_some() {
+            data_query.push_str(&format!(" AND store_id = ANY(${})", param_idx));
+            param_idx += 1;
+        }
         if filter.status.is_some() {
             data_query.push_str(&format!(" AND status = ${}", param_idx));
             param_idx += 1;
@@ -336,16 +305,21 @@ impl SaleRepository for PgSaleRepository {
             ));
             param_idx += 1;
         }
-        data_query.push_str(&format!(
-            " ORDER BY created_at DESC LIMIT ${} OFFSET ${}",
-            param_idx,
-            param_idx + 1
-        ));
+        if filter.after_id.is_some() {
+            data_query.push_str(&format!(" AND id < ${}", param_idx));
+            param_idx += 1;
+        }
+        data_query.push_str(" ORDER BY id DESC");
+        data_query.push_str(&format!(" LIMIT ${} OFFSET ${}", param_idx, param_idx + 1));
 
         let mut data_builder = sqlx::query_as::<_, SaleRow>(&data_query);
         if let Some(store_id) = filter.store_id {
             data_builder = data_builder.bind(store_id.into_uuid());
         }
+        if let Some(store_ids) = &filter.store_ids {
+            let store_ids: Vec<uuid::Uuid> = store_ids.iter().map(|id| *id.as_uuid()).collect();
+            data_builder = data_builder.bind(store_ids);
+        }
         if let Some(status) = filter.status {
             data_builder = data_builder.bind(status.to_string());
         }
@@ -355,6 +329,9 @@ impl SaleRepository for PgSaleRepository {
         if let Some(ref search) = filter.search {
             data_builder = data_builder.bind(format!("%{}%", search));
         }
+        if let Some(after_id) = filter.after_id {
+            data_builder = data_builder.bind(after_id.into_uuid());
+        }
         data_builder = data_builder.bind(page_size).bind(offset);
 
         let rows = data_builder.fetch_all(&self.pool).await?;
@@ -382,43 +359,7 @@ impl SaleRepository for PgSaleRepository {
     }
 
     async fn save_item(&self, item: &SaleItem) -> Result<(), SalesError> {
-        sqlx::query(
-            r#"
-            INSERT INTO sale_items (
-                id, sale_id, line_number, product_id, variant_id, sku, description,
-                quantity, unit_of_measure, unit_price, unit_cost, discount_type,
-                discount_value, discount_amount, tax_rate, tax_amount, subtotal,
-                total, reservation_id, notes, created_at, updated_at
-            )
-            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15, $16, $17, $18, $19, $20, $21, $22)
-            "#,
-        )
-        .bind(item.id().into_uuid())
-        .bind(item.sale_id().into_uuid())
-        .bind(item.line_number())
-        .bind(item.product_id().into_uuid())
-        .bind(item.variant_id().map(|v| v.into_uuid()))
-        .bind(item.sku())
-        .bind(item.description())
-        .bind(item.quantity())
-        .bind(item.unit_of_measure().to_string())
-        .bind(item.unit_price())
-        .bind(item.unit_cost())
-        .bind(item.discount_type().map(|d| d.to_string()))
-        .bind(item.discount_value())
-        .bind(item.discount_amount())
-        .bind(item.tax_rate())
-        .bind(item.tax_amount())
-        .bind(item.subtotal())
-        .bind(item.total())
-        .bind(item.reservation_id().map(|r| r.into_uuid()))
-        .bind(item.notes())
-        .bind(item.created_at())
-        .bind(item.updated_at())
-        .execute(&self.pool)
-        .await?;
-
-        Ok(())
+        save_item_q(&self.pool, item).await
     }
 
     async fn update_item(&self, item: &SaleItem) -> Result<(), SalesError> {
@@ -427,7 +368,10 @@ impl SaleRepository for PgSaleRepository {
             UPDATE sale_items
             SET quantity = $2, unit_price = $3, discount_type = $4, discount_value = $5,
                 discount_amount = $6, tax_amount = $7, subtotal = $8, total = $9,
-                reservation_id = $10, notes = $11, updated_at = $12
+                reservation_id = $10, notes = $11, updated_at = $12, serial_numbers = $13,
+                price_tier_id = $14, order_discount_amount = $15, price_list_id = $16,
+                promotion_id = $17, tax_rate = $18, tax_inclusive = $19, tax_exempt = $20,
+                tax_rule_id = $21, tax_jurisdiction = $22
             WHERE id = $1
             "#,
         )
@@ -443,6 +387,16 @@ impl SaleRepository for PgSaleRepository {
         .bind(item.reservation_id().map(|r| r.into_uuid()))
         .bind(item.notes())
         .bind(item.updated_at())
+        .bind(item.serial_numbers())
+        .bind(item.price_tier_id().map(|t| t.into_uuid()))
+        .bind(item.order_discount_amount())
+        .bind(item.price_list_id().map(|l| l.into_uuid()))
+        .bind(item.promotion_id().map(|p| p.into_uuid()))
+        .bind(item.tax_rate())
+        .bind(item.tax_inclusive())
+        .bind(item.tax_exempt())
+        .bind(item.tax_rule_id().map(|r| r.into_uuid()))
+        .bind(item.tax_jurisdiction())
         .execute(&self.pool)
         .await?;
 
@@ -466,8 +420,9 @@ impl SaleRepository for PgSaleRepository {
             r#"
             SELECT id, sale_id, line_number, product_id, variant_id, sku, description,
                    quantity, unit_of_measure, unit_price, unit_cost, discount_type,
-                   discount_value, discount_amount, tax_rate, tax_amount, subtotal,
-                   total, reservation_id, notes, created_at, updated_at
+                   discount_value, discount_amount, order_discount_amount, tax_rate, tax_amount,
+                   tax_inclusive, tax_exempt, tax_rule_id, tax_jurisdiction, subtotal,
+                   total, reservation_id, serial_numbers, price_tier_id, price_list_id, promotion_id, notes, created_at, updated_at
             FROM sale_items
             WHERE id = $1
             "#,
@@ -483,12 +438,12 @@ impl SaleRepository for PgSaleRepository {
         sqlx::query(
             r#"
             INSERT INTO payments (
-                id, sale_id, payment_method, status, amount, currency, amount_tendered,
+                id, sale_id, payment_method, status, amount, currency, exchange_rate, amount_tendered,
                 change_given, reference_number, authorization_code, card_last_four,
                 card_brand, refunded_amount, refunded_at, notes, idempotency_key,
                 processed_at, created_at, updated_at
             )
-            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15, $16, $17, $18, $19)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15, $16, $17, $18, $19, $20)
             "#,
         )
         .bind(payment.id().into_uuid())
@@ -497,6 +452,7 @@ impl SaleRepository for PgSaleRepository {
         .bind(payment.status().to_string())
         .bind(payment.amount())
         .bind(payment.currency().as_str())
+        .bind(payment.exchange_rate())
         .bind(payment.amount_tendered())
         .bind(payment.change_given())
         .bind(payment.reference_number())
@@ -546,7 +502,7 @@ impl SaleRepository for PgSaleRepository {
     ) -> Result<Option<Payment>, SalesError> {
         let row = sqlx::query_as::<_, PaymentRow>(
             r#"
-            SELECT id, sale_id, payment_method, status, amount, currency, amount_tendered,
+            SELECT id, sale_id, payment_method, status, amount, currency, exchange_rate, amount_tendered,
                    change_given, reference_number, authorization_code, card_last_four,
                    card_brand, refunded_amount, refunded_at, notes, idempotency_key,
                    processed_at, created_at, updated_at
@@ -560,27 +516,6 @@ impl SaleRepository for PgSaleRepository {
 
         row.map(|r| r.try_into()).transpose()
     }
-
-    async fn find_payment_by_idempotency_key(
-        &self,
-        key: &str,
-    ) -> Result<Option<Payment>, SalesError> {
-        let row = sqlx::query_as::<_, PaymentRow>(
-            r#"
-            SELECT id, sale_id, payment_method, status, amount, currency, amount_tendered,
-                   change_given, reference_number, authorization_code, card_last_four,
-                   card_brand, refunded_amount, refunded_at, notes, idempotency_key,
-                   processed_at, created_at, updated_at
-            FROM payments
-            WHERE idempotency_key = $1
-            "#,
-        )
-        .bind(key)
-        .fetch_optional(&self.pool)
-        .await?;
-
-        row.map(|r| r.try_into()).transpose()
-    }
 }
 
 // =============================================================================
@@ -601,7 +536,8 @@ impl PgSaleRepository {
                 total = $10, amount_paid = $11, amount_due = $12, change_given = $13,
                 invoice_number = $14, invoice_date = $15, notes = $16, internal_notes = $17,
                 voided_by_id = $18, voided_at = $19, void_reason = $20, completed_at = $21,
-                updated_at = $22
+                updated_at = $22, promotion_id = $23,
+                transaction_currency = $24, exchange_rate = $25, cai_number = $26
             WHERE id = $1
             "#,
         )
@@ -627,6 +563,10 @@ impl PgSaleRepository {
         .bind(sale.void_reason())
         .bind(sale.completed_at())
         .bind(sale.updated_at())
+        .bind(sale.promotion_id().map(|p| p.into_uuid()))
+        .bind(sale.transaction_currency().as_str())
+        .bind(sale.exchange_rate())
+        .bind(sale.cai_number())
         .execute(&mut **tx)
         .await?;
 
@@ -634,9 +574,27 @@ impl PgSaleRepository {
             return Err(SalesError::SaleNotFound(sale.id().into_uuid()));
         }
 
+        update_item_allocations_q(&mut **tx, sale).await?;
+
         Ok(())
     }
 
+    /// Saves a new sale header within an existing transaction.
+    pub async fn save_in_tx(
+        tx: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+        sale: &Sale,
+    ) -> Result<(), SalesError> {
+        save_q(&mut **tx, sale).await
+    }
+
+    /// Saves a sale item within an existing transaction.
+    pub async fn save_item_in_tx(
+        tx: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+        item: &SaleItem,
+    ) -> Result<(), SalesError> {
+        save_item_q(&mut **tx, item).await
+    }
+
     /// Saves a payment within an existing transaction.
     pub async fn save_payment_in_tx(
         tx: &mut sqlx::Transaction<'_, sqlx::Postgres>,
@@ -645,12 +603,12 @@ impl PgSaleRepository {
         sqlx::query(
             r#"
             INSERT INTO payments (
-                id, sale_id, payment_method, status, amount, currency, amount_tendered,
+                id, sale_id, payment_method, status, amount, currency, exchange_rate, amount_tendered,
                 change_given, reference_number, authorization_code, card_last_four,
                 card_brand, refunded_amount, refunded_at, notes, idempotency_key,
                 processed_at, created_at, updated_at
             )
-            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15, $16, $17, $18, $19)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15, $16, $17, $18, $19, $20)
             "#,
         )
         .bind(payment.id().into_uuid())
@@ -659,6 +617,7 @@ impl PgSaleRepository {
         .bind(payment.status().to_string())
         .bind(payment.amount())
         .bind(payment.currency().as_str())
+        .bind(payment.exchange_rate())
         .bind(payment.amount_tendered())
         .bind(payment.change_given())
         .bind(payment.reference_number())
@@ -696,6 +655,8 @@ struct SaleRow {
     cashier_id: Option<uuid::Uuid>,
     customer_id: Option<uuid::Uuid>,
     currency: String,
+    transaction_currency: String,
+    exchange_rate: rust_decimal::Decimal,
     subtotal: rust_decimal::Decimal,
     discount_type: Option<String>,
     discount_value: rust_decimal::Decimal,
@@ -707,12 +668,14 @@ struct SaleRow {
     change_given: rust_decimal::Decimal,
     invoice_number: Option<String>,
     invoice_date: Option<chrono::DateTime<chrono::Utc>>,
+    cai_number: Option<String>,
     notes: Option<String>,
     internal_notes: Option<String>,
     voided_by_id: Option<uuid::Uuid>,
     voided_at: Option<chrono::DateTime<chrono::Utc>>,
     void_reason: Option<String>,
     completed_at: Option<chrono::DateTime<chrono::Utc>>,
+    promotion_id: Option<uuid::Uuid>,
     created_at: chrono::DateTime<chrono::Utc>,
     updated_at: chrono::DateTime<chrono::Utc>,
 }
@@ -736,6 +699,8 @@ impl SaleRow {
             self.cashier_id.map(UserId::from_uuid),
             self.customer_id.map(CustomerId::from_uuid),
             Currency::from_string(self.currency),
+            Currency::from_string(self.transaction_currency),
+            self.exchange_rate,
             self.subtotal,
             discount_type,
             self.discount_value,
@@ -747,12 +712,14 @@ impl SaleRow {
             self.change_given,
             self.invoice_number,
             self.invoice_date,
+            self.cai_number,
             self.notes,
             self.internal_notes,
             self.voided_by_id.map(UserId::from_uuid),
             self.voided_at,
             self.void_reason,
             self.completed_at,
+            self.promotion_id.map(PromotionId::from_uuid),
             items,
             payments,
             self.created_at,
@@ -761,6 +728,266 @@ impl SaleRow {
     }
 }
 
+/// Locks the sale row, so exactly one writer sees the transition to
+/// completed, and returns the status it had
+async fn lock_sale_status(
+    tx: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+    sale: &Sale,
+) -> Result<String, SalesError> {
+    let previous_status: Option<(String,)> =
+        sqlx::query_as("SELECT status FROM sales WHERE id = $1 FOR UPDATE")
+            .bind(sale.id().into_uuid())
+            .fetch_optional(&mut **tx)
+            .await?;
+    previous_status
+        .map(|(status,)| status)
+        .ok_or(SalesError::SaleNotFound(sale.id().into_uuid()))
+}
+
+/// Draws the next number of the terminal's active CAI range, returning the
+/// CAI and the number.
+///
+/// The range row is locked `FOR UPDATE` until the transaction ends, so a
+/// rolled-back completion gives its number back instead of leaving a gap.
+pub(super) async fn draw_cai_invoice_number(
+    tx: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+    terminal_id: uuid::Uuid,
+) -> Result<(String, i64), SalesError> {
+    let range: Option<(uuid::Uuid, String, i64, i64)> = sqlx::query_as(
+        r#"
+        SELECT id, cai_number, current_number, range_end
+        FROM cai_ranges
+        WHERE terminal_id = $1
+          AND is_exhausted = FALSE
+          AND expiration_date >= CURRENT_DATE
+        ORDER BY created_at DESC
+        LIMIT 1
+        FOR UPDATE
+        "#,
+    )
+    .bind(terminal_id)
+    .fetch_optional(&mut **tx)
+    .await?;
+
+    let Some((range_id, cai_number, current_number, range_end)) = range else {
+        // Tell an expired CAI apart from a used-up or missing one
+        let (any, expired): (i64, i64) = sqlx::query_as(
+            r#"
+            SELECT COUNT(*),
+                   COUNT(*) FILTER (WHERE is_exhausted = FALSE AND expiration_date < CURRENT_DATE)
+            FROM cai_ranges
+            WHERE terminal_id = $1
+            "#,
+        )
+        .bind(terminal_id)
+        .fetch_one(&mut **tx)
+        .await?;
+
+        return Err(if any == 0 {
+            SalesError::NoValidCai(terminal_id)
+        } else if expired > 0 {
+            SalesError::CaiExpired(terminal_id)
+        } else {
+            SalesError::CaiRangeExhausted(terminal_id)
+        });
+    };
+
+    if current_number > range_end {
+        return Err(SalesError::CaiRangeExhausted(terminal_id));
+    }
+
+    sqlx::query("UPDATE cai_ranges SET current_number = $2, is_exhausted = $3 WHERE id = $1")
+        .bind(range_id)
+        .bind(current_number + 1)
+        .bind(current_number + 1 > range_end)
+        .execute(&mut **tx)
+        .await?;
+
+    Ok((cai_number, current_number))
+}
+
+/// Builds the `sale.completed` outbox event
+pub(crate) fn sale_completed_event(sale: &Sale) -> OutboxEvent {
+    OutboxEvent::create(
+        "sale",
+        sale.id().into_uuid().to_string(),
+        "sale.completed",
+        json!({
+            "sale_id": sale.id().into_uuid(),
+            "sale_number": sale.sale_number(),
+            "store_id": sale.store_id().into_uuid(),
+            "customer_id": sale.customer_id().map(|id| id.into_uuid()),
+            "currency": sale.currency().as_str(),
+            "total": sale.total(),
+            "completed_at": sale.completed_at(),
+        }),
+    )
+}
+
+async fn save_q<'e, E: PgExecutor<'e>>(exec: E, sale: &Sale) -> Result<(), SalesError> {
+    sqlx::query(
+        r#"
+        INSERT INTO sales (
+            id, sale_number, store_id, sale_type, status, order_status, terminal_id,
+            shift_id, cashier_id, customer_id, currency, transaction_currency, exchange_rate, subtotal, discount_type,
+            discount_value, discount_amount, tax_amount, total, amount_paid, amount_due,
+            change_given, invoice_number, invoice_date, cai_number, notes, internal_notes,
+            voided_by_id, voided_at, void_reason, completed_at, promotion_id, created_at, updated_at
+        )
+        VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15, $16, $17, $18, $19, $20, $21, $22, $23, $24, $25, $26, $27, $28, $29, $30, $31, $32, $33, $34)
+        "#,
+    )
+    .bind(sale.id().into_uuid())
+    .bind(sale.sale_number())
+    .bind(sale.store_id().into_uuid())
+    .bind(sale.sale_type().to_string())
+    .bind(sale.status().to_string())
+    .bind(sale.order_status().map(|s| s.to_string()))
+    .bind(sale.terminal_id().map(|t| t.into_uuid()))
+    .bind(sale.shift_id().map(|s| s.into_uuid()))
+    .bind(sale.cashier_id().map(|c| c.into_uuid()))
+    .bind(sale.customer_id().map(|c| c.into_uuid()))
+    .bind(sale.currency().as_str())
+    .bind(sale.transaction_currency().as_str())
+    .bind(sale.exchange_rate())
+    .bind(sale.subtotal())
+    .bind(sale.discount_type().map(|d| d.to_string()))
+    .bind(sale.discount_value())
+    .bind(sale.discount_amount())
+    .bind(sale.tax_amount())
+    .bind(sale.total())
+    .bind(sale.amount_paid())
+    .bind(sale.amount_due())
+    .bind(sale.change_given())
+    .bind(sale.invoice_number())
+    .bind(sale.invoice_date())
+    .bind(sale.cai_number())
+    .bind(sale.notes())
+    .bind(sale.internal_notes())
+    .bind(sale.voided_by_id().map(|u| u.into_uuid()))
+    .bind(sale.voided_at())
+    .bind(sale.void_reason())
+    .bind(sale.completed_at())
+    .bind(sale.promotion_id().map(|p| p.into_uuid()))
+    .bind(sale.created_at())
+    .bind(sale.updated_at())
+    .execute(exec)
+    .await?;
+
+    Ok(())
+}
+
+async fn save_item_q<'e, E: PgExecutor<'e>>(exec: E, item: &SaleItem) -> Result<(), SalesError> {
+    sqlx::query(
+        r#"
+        INSERT INTO sale_items (
+            id, sale_id, line_number, product_id, variant_id, sku, description,
+            quantity, unit_of_measure, unit_price, unit_cost, discount_type,
+            discount_value, discount_amount, order_discount_amount, tax_rate, tax_amount,
+            tax_inclusive, tax_exempt, tax_rule_id, tax_jurisdiction, subtotal,
+            total, reservation_id, serial_numbers, price_tier_id, price_list_id, promotion_id, notes, created_at, updated_at
+        )
+        VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15, $16, $17, $18, $19, $20, $21, $22, $23, $24, $25, $26, $27, $28, $29, $30, $31)
+        "#,
+    )
+    .bind(item.id().into_uuid())
+    .bind(item.sale_id().into_uuid())
+    .bind(item.line_number())
+    .bind(item.product_id().into_uuid())
+    .bind(item.variant_id().map(|v| v.into_uuid()))
+    .bind(item.sku())
+    .bind(item.description())
+    .bind(item.quantity())
+    .bind(item.unit_of_measure().to_string())
+    .bind(item.unit_price())
+    .bind(item.unit_cost())
+    .bind(item.discount_type().map(|d| d.to_string()))
+    .bind(item.discount_value())
+    .bind(item.discount_amount())
+    .bind(item.order_discount_amount())
+    .bind(item.tax_rate())
+    .bind(item.tax_amount())
+    .bind(item.tax_inclusive())
+    .bind(item.tax_exempt())
+    .bind(item.tax_rule_id().map(|r| r.into_uuid()))
+    .bind(item.tax_jurisdiction())
+    .bind(item.subtotal())
+    .bind(item.total())
+    .bind(item.reservation_id().map(|r| r.into_uuid()))
+    .bind(item.serial_numbers())
+    .bind(item.price_tier_id().map(|t| t.into_uuid()))
+    .bind(item.price_list_id().map(|l| l.into_uuid()))
+    .bind(item.promotion_id().map(|p| p.into_uuid()))
+    .bind(item.notes())
+    .bind(item.created_at())
+    .bind(item.updated_at())
+    .execute(exec)
+    .await?;
+
+    Ok(())
+}
+
+/// Writes the sale-level discount allocation and the tax of each loaded
+/// item; changing the sale discount or its tax rules moves every line's
+/// share and tax.
+async fn update_item_allocations_q<'e, E: PgExecutor<'e>>(
+    exec: E,
+    sale: &Sale,
+) -> Result<(), SalesError> {
+    if sale.items().is_empty() {
+        return Ok(());
+    }
+    let ids: Vec<uuid::Uuid> = sale.items().iter().map(|i| i.id().into_uuid()).collect();
+    let discounts: Vec<rust_decimal::Decimal> = sale
+        .items()
+        .iter()
+        .map(|i| i.order_discount_amount())
+        .collect();
+    let taxes: Vec<rust_decimal::Decimal> = sale.items().iter().map(|i| i.tax_amount()).collect();
+    let totals: Vec<rust_decimal::Decimal> = sale.items().iter().map(|i| i.total()).collect();
+    let rates: Vec<rust_decimal::Decimal> = sale.items().iter().map(|i| i.tax_rate()).collect();
+    let inclusive: Vec<bool> = sale.items().iter().map(|i| i.tax_inclusive()).collect();
+    let exempt: Vec<bool> = sale.items().iter().map(|i| i.tax_exempt()).collect();
+    let rule_ids: Vec<Option<uuid::Uuid>> = sale
+        .items()
+        .iter()
+        .map(|i| i.tax_rule_id().map(|r| r.into_uuid()))
+        .collect();
+    let jurisdictions: Vec<Option<String>> = sale
+        .items()
+        .iter()
+        .map(|i| i.tax_jurisdiction().map(str::to_string))
+        .collect();
+
+    sqlx::query(
+        r#"
+        UPDATE sale_items si
+        SET order_discount_amount = a.order_discount_amount, tax_amount = a.tax_amount,
+            total = a.total, tax_rate = a.tax_rate, tax_inclusive = a.tax_inclusive,
+            tax_exempt = a.tax_exempt, tax_rule_id = a.tax_rule_id,
+            tax_jurisdiction = a.tax_jurisdiction
+        FROM UNNEST($1::uuid[], $2::numeric[], $3::numeric[], $4::numeric[], $5::numeric[],
+                    $6::boolean[], $7::boolean[], $8::uuid[], $9::text[])
+            AS a(id, order_discount_amount, tax_amount, total, tax_rate, tax_inclusive,
+                 tax_exempt, tax_rule_id, tax_jurisdiction)
+        WHERE si.id = a.id
+        "#,
+    )
+    .bind(ids)
+    .bind(discounts)
+    .bind(taxes)
+    .bind(totals)
+    .bind(rates)
+    .bind(inclusive)
+    .bind(exempt)
+    .bind(rule_ids)
+    .bind(jurisdictions)
+    .execute(exec)
+    .await?;
+
+    Ok(())
+}
+
 #[derive(sqlx::FromRow)]
 struct SaleItemRow {
     id: uuid::Uuid,
@@ -777,11 +1004,20 @@ struct SaleItemRow {
     discount_type: Option<String>,
     discount_value: rust_decimal::Decimal,
     discount_amount: rust_decimal::Decimal,
+    order_discount_amount: rust_decimal::Decimal,
     tax_rate: rust_decimal::Decimal,
     tax_amount: rust_decimal::Decimal,
+    tax_inclusive: bool,
+    tax_exempt: bool,
+    tax_rule_id: Option<uuid::Uuid>,
+    tax_jurisdiction: Option<String>,
     subtotal: rust_decimal::Decimal,
     total: rust_decimal::Decimal,
     reservation_id: Option<uuid::Uuid>,
+    serial_numbers: Vec<String>,
+    price_tier_id: Option<uuid::Uuid>,
+    price_list_id: Option<uuid::Uuid>,
+    promotion_id: Option<uuid::Uuid>,
     notes: Option<String>,
     created_at: chrono::DateTime<chrono::Utc>,
     updated_at: chrono::DateTime<chrono::Utc>,
@@ -810,11 +1046,20 @@ impl TryFrom<SaleItemRow> for SaleItem {
             discount_type,
             row.discount_value,
             row.discount_amount,
+            row.order_discount_amount,
             row.tax_rate,
             row.tax_amount,
+            row.tax_inclusive,
+            row.tax_exempt,
+            row.tax_rule_id.map(TaxRuleId::from_uuid),
+            row.tax_jurisdiction,
             row.subtotal,
             row.total,
             row.reservation_id.map(ReservationId::from_uuid),
+            row.serial_numbers,
+            row.price_tier_id.map(PriceTierId::from_uuid),
+            row.price_list_id.map(PriceListId::from_uuid),
+            row.promotion_id.map(PromotionId::from_uuid),
             row.notes,
             row.created_at,
             row.updated_at,
@@ -830,6 +1075,7 @@ struct PaymentRow {
     status: String,
     amount: rust_decimal::Decimal,
     currency: String,
+    exchange_rate: rust_decimal::Decimal,
     amount_tendered: Option<rust_decimal::Decimal>,
     change_given: Option<rust_decimal::Decimal>,
     reference_number: Option<String>,
@@ -859,6 +1105,7 @@ impl TryFrom<PaymentRow> for Payment {
             status,
             row.amount,
             Currency::from_string(row.currency),
+            row.exchange_rate,
             row.amount_tendered,
             row.change_given,
             row.reference_number,
@@ -4,7 +4,7 @@ use async_trait::async_trait;
 use sqlx::PgPool;
 
 use crate::SalesError;
-use crate::domain::entities::CashierShift;
+use crate::domain::entities::{CashierShift, ShiftReopen};
 use crate::domain::repositories::{ShiftFilter, ShiftRepository};
 use crate::domain::value_objects::{ShiftId, ShiftStatus};
 use identity::{StoreId, UserId};
@@ -30,9 +30,10 @@ impl ShiftRepository for PgShiftRepository {
                 id, store_id, terminal_id, cashier_id, status, opened_at, closed_at,
                 opening_balance, closing_balance, expected_balance, cash_sales, card_sales,
                 other_sales, refunds, cash_in, cash_out, transaction_count, notes,
-                closing_notes, created_at, updated_at
+                closing_notes, created_at, updated_at, card_count, other_count, variance,
+                variance_reason
             )
-            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15, $16, $17, $18, $19, $20, $21)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15, $16, $17, $18, $19, $20, $21, $22, $23, $24, $25)
             "#,
         )
         .bind(shift.id().into_uuid())
@@ -56,6 +57,10 @@ impl ShiftRepository for PgShiftRepository {
         .bind(shift.closing_notes())
         .bind(shift.created_at())
         .bind(shift.updated_at())
+        .bind(shift.card_count())
+        .bind(shift.other_count())
+        .bind(shift.variance())
+        .bind(shift.variance_reason())
         .execute(&self.pool)
         .await?;
 
@@ -68,7 +73,8 @@ impl ShiftRepository for PgShiftRepository {
             SELECT id, store_id, terminal_id, cashier_id, status, opened_at, closed_at,
                    opening_balance, closing_balance, expected_balance, cash_sales, card_sales,
                    other_sales, refunds, cash_in, cash_out, transaction_count, notes,
-                   closing_notes, created_at, updated_at
+                   closing_notes, card_count, other_count, variance, variance_reason,
+                   created_at, updated_at
             FROM cashier_shifts
             WHERE id = $1
             "#,
@@ -89,7 +95,8 @@ impl ShiftRepository for PgShiftRepository {
             SELECT id, store_id, terminal_id, cashier_id, status, opened_at, closed_at,
                    opening_balance, closing_balance, expected_balance, cash_sales, card_sales,
                    other_sales, refunds, cash_in, cash_out, transaction_count, notes,
-                   closing_notes, created_at, updated_at
+                   closing_notes, card_count, other_count, variance, variance_reason,
+                   created_at, updated_at
             FROM cashier_shifts
             WHERE terminal_id = $1 AND status = 'open'
             "#,
@@ -110,7 +117,8 @@ impl ShiftRepository for PgShiftRepository {
             SELECT id, store_id, terminal_id, cashier_id, status, opened_at, closed_at,
                    opening_balance, closing_balance, expected_balance, cash_sales, card_sales,
                    other_sales, refunds, cash_in, cash_out, transaction_count, notes,
-                   closing_notes, created_at, updated_at
+                   closing_notes, card_count, other_count, variance, variance_reason,
+                   created_at, updated_at
             FROM cashier_shifts
             WHERE cashier_id = $1 AND status = 'open'
             "#,
@@ -129,7 +137,8 @@ impl ShiftRepository for PgShiftRepository {
             SET status = $2, closed_at = $3, closing_balance = $4, expected_balance = $5,
                 cash_sales = $6, card_sales = $7, other_sales = $8, refunds = $9,
                 cash_in = $10, cash_out = $11, transaction_count = $12, notes = $13,
-                closing_notes = $14, updated_at = $15
+                closing_notes = $14, updated_at = $15, card_count = $16, other_count = $17,
+                variance = $18, variance_reason = $19
             WHERE id = $1
             "#,
         )
@@ -148,6 +157,10 @@ impl ShiftRepository for PgShiftRepository {
         .bind(shift.notes())
         .bind(shift.closing_notes())
         .bind(shift.updated_at())
+        .bind(shift.card_count())
+        .bind(shift.other_count())
+        .bind(shift.variance())
+        .bind(shift.variance_reason())
         .execute(&self.pool)
         .await?;
 
@@ -158,13 +171,61 @@ impl ShiftRepository for PgShiftRepository {
         Ok(())
     }
 
+    async fn is_finalized(&self, id: ShiftId) -> Result<bool, SalesError> {
+        let finalized = sqlx::query_scalar::<_, bool>(
+            "SELECT EXISTS(SELECT 1 FROM cash_deposits WHERE cashier_shift_id = $1)",
+        )
+        .bind(id.into_uuid())
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(finalized)
+    }
+
+    async fn save_reopen(
+        &self,
+        shift: &CashierShift,
+        reopen: &ShiftReopen,
+    ) -> Result<(), SalesError> {
+        let mut tx = self.pool.begin().await?;
+
+        Self::update_in_tx(&mut tx, shift).await?;
+
+        sqlx::query(
+            r#"
+            INSERT INTO cashier_shift_reopens (
+                id, shift_id, reopened_by_id, reason, previous_closed_at,
+                previous_closing_balance, previous_closing_notes, reopened_at
+            )
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
+            "#,
+        )
+        .bind(reopen.id())
+        .bind(reopen.shift_id().into_uuid())
+        .bind(reopen.reopened_by_id().into_uuid())
+        .bind(reopen.reason())
+        .bind(reopen.previous_closed_at())
+        .bind(reopen.previous_closing_balance())
+        .bind(reopen.previous_closing_notes())
+        .bind(reopen.reopened_at())
+        .execute(&mut *tx)
+        .await?;
+
+        tx.commit().await?;
+        Ok(())
+    }
+
     async fn find_paginated(
         &self,
         filter: ShiftFilter,
         page: i64,
         page_size: i64,
     ) -> Result<(Vec<CashierShift>, i64), SalesError> {
-        let offset = (page - 1) * page_size;
+        let offset = if filter.after_id.is_some() {
+            0
+        } else {
+            (page - 1) * page_size
+        };
 
         let mut count_query = String::from("SELECT COUNT(*) FROM cashier_shifts WHERE 1=1");
         let mut param_idx = 1;
@@ -173,6 +234,10 @@ impl ShiftRepository for PgShiftRepository {
             count_query.push_str(&format!(" AND store_id = ${}", param_idx));
             param_idx += 1;
         }
+        if filter.store_ids.is_some() {
+            count_query.push_str(&format!(" AND store_id = ANY(${})", param_idx));
+            param_idx += 1;
+        }
         if filter.terminal_id.is_some() {
             count_query.push_str(&format!(" AND terminal_id = ${}", param_idx));
             param_idx += 1;
@@ -189,6 +254,10 @@ impl ShiftRepository for PgShiftRepository {
         if let Some(store_id) = filter.store_id {
             count_builder = count_builder.bind(store_id.into_uuid());
         }
+        if let Some(store_ids) = &filter.store_ids {
+            let store_ids: Vec<uuid::Uuid> = store_ids.iter().map(|id| *id.as_uuid()).collect();
+            count_builder = count_builder.bind(store_ids);
+        }
         if let Some(terminal_id) = filter.terminal_id {
             count_builder = count_builder.bind(terminal_id.into_uuid());
         }
@@ -204,7 +273,8 @@ impl ShiftRepository for PgShiftRepository {
             r#"SELECT id, store_id, terminal_id, cashier_id, status, opened_at, closed_at,
                    opening_balance, closing_balance, expected_balance, cash_sales, card_sales,
                    other_sales, refunds, cash_in, cash_out, transaction_count, notes,
-                   closing_notes, created_at, updated_at
+                   closing_notes, card_count, other_count, variance, variance_reason,
+                   created_at, updated_at
             FROM cashier_shifts WHERE 1=1"#,
         );
 
@@ -213,6 +283,10 @@ impl ShiftRepository for PgShiftRepository {
             data_query.push_str(&format!(" AND store_id = ${}", param_idx));
             param_idx += 1;
         }
+        if filter.store_ids.is_some() {
+            data_query.push_str(&format!(" AND store_id = ANY(${})", param_idx));
+            param_idx += 1;
+        }
         if filter.terminal_id.is_some() {
             data_query.push_str(&format!(" AND terminal_id = ${}", param_idx));
             param_idx += 1;
@@ -225,16 +299,21 @@ impl ShiftRepository for PgShiftRepository {
             data_query.push_str(&format!(" AND status = ${}", param_idx));
             param_idx += 1;
         }
-        data_query.push_str(&format!(
-            " ORDER BY opened_at DESC LIMIT ${} OFFSET ${}",
-            param_idx,
-            param_idx + 1
-        ));
+        if filter.after_id.is_some() {
+            data_query.push_str(&format!(" AND id < ${}", param_idx));
+            param_idx += 1;
+        }
+        data_query.push_str(" ORDER BY id DESC");
+        data_query.push_str(&format!(" LIMIT ${} OFFSET ${}", param_idx, param_idx + 1));
 
         let mut data_builder = sqlx::query_as::<_, ShiftRow>(&data_query);
         if let Some(store_id) = filter.store_id {
             data_builder = data_builder.bind(store_id.into_uuid());
         }
+        if let Some(store_ids) = &filter.store_ids {
+            let store_ids: Vec<uuid::Uuid> = store_ids.iter().map(|id| *id.as_uuid()).collect();
+            data_builder = data_builder.bind(store_ids);
+        }
         if let Some(terminal_id) = filter.terminal_id {
             data_builder = data_builder.bind(terminal_id.into_uuid());
         }
@@ -244,6 +323,9 @@ impl ShiftRepository for PgShiftRepository {
         if let Some(status) = filter.status {
             data_builder = data_builder.bind(status.to_string());
         }
+        if let Some(after_id) = filter.after_id {
+            data_builder = data_builder.bind(after_id.into_uuid());
+        }
         data_builder = data_builder.bind(page_size).bind(offset);
 
         let rows = data_builder.fetch_all(&self.pool).await?;
@@ -267,7 +349,8 @@ impl PgShiftRepository {
             SET status = $2, closed_at = $3, closing_balance = $4, expected_balance = $5,
                 cash_sales = $6, card_sales = $7, other_sales = $8, refunds = $9,
                 cash_in = $10, cash_out = $11, transaction_count = $12, notes = $13,
-                closing_notes = $14, updated_at = $15
+                closing_notes = $14, updated_at = $15, card_count = $16, other_count = $17,
+                variance = $18, variance_reason = $19
             WHERE id = $1
             "#,
         )
@@ -286,6 +369,10 @@ impl PgShiftRepository {
         .bind(shift.notes())
         .bind(shift.closing_notes())
         .bind(shift.updated_at())
+        .bind(shift.card_count())
+        .bind(shift.other_count())
+        .bind(shift.variance())
+        .bind(shift.variance_reason())
         .execute(&mut **tx)
         .await?;
 
@@ -318,6 +405,10 @@ struct ShiftRow {
     transaction_count: i32,
     notes: Option<String>,
     closing_notes: Option<String>,
+    card_count: Option<rust_decimal::Decimal>,
+    other_count: Option<rust_decimal::Decimal>,
+    variance: Option<rust_decimal::Decimal>,
+    variance_reason: Option<String>,
     created_at: chrono::DateTime<chrono::Utc>,
     updated_at: chrono::DateTime<chrono::Utc>,
 }
@@ -348,6 +439,10 @@ impl TryFrom<ShiftRow> for CashierShift {
             row.transaction_count,
             row.notes,
             row.closing_notes,
+            row.card_count,
+            row.other_count,
+            row.variance,
+            row.variance_reason,
             row.created_at,
             row.updated_at,
         ))
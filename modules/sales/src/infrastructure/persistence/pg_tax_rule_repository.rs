@@ -0,0 +1,186 @@
+//! PostgreSQL TaxRuleRepository implementation
+
+use std::str::FromStr;
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::SalesError;
+use crate::domain::entities::TaxRule;
+use crate::domain::repositories::TaxRuleRepository;
+use crate::domain::value_objects::{CustomerType, TaxRuleId};
+use identity::UserId;
+
+/// Row type for reading tax rules from the database
+#[derive(Debug, sqlx::FromRow)]
+struct TaxRuleRow {
+    id: Uuid,
+    store_id: Uuid,
+    jurisdiction: String,
+    product_id: Option<Uuid>,
+    category_id: Option<Uuid>,
+    rate: Decimal,
+    tax_inclusive: bool,
+    exempt_customer_types: Vec<String>,
+    is_active: bool,
+    created_by_id: Uuid,
+    created_at: DateTime<Utc>,
+    updated_at: DateTime<Utc>,
+}
+
+impl TryFrom<TaxRuleRow> for TaxRule {
+    type Error = SalesError;
+
+    fn try_from(row: TaxRuleRow) -> Result<Self, Self::Error> {
+        let exempt_customer_types = row
+            .exempt_customer_types
+            .iter()
+            .map(|t| CustomerType::from_str(t))
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(TaxRule::reconstitute(
+            TaxRuleId::from_uuid(row.id),
+            row.store_id,
+            row.jurisdiction,
+            row.product_id,
+            row.category_id,
+            row.rate,
+            row.tax_inclusive,
+            exempt_customer_types,
+            row.is_active,
+            UserId::from_uuid(row.created_by_id),
+            row.created_at,
+            row.updated_at,
+        ))
+    }
+}
+
+fn exempt_customer_types(rule: &TaxRule) -> Vec<String> {
+    rule.exempt_customer_types()
+        .iter()
+        .map(|t| t.to_string())
+        .collect()
+}
+
+pub struct PgTaxRuleRepository {
+    pool: PgPool,
+}
+
+impl PgTaxRuleRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl TaxRuleRepository for PgTaxRuleRepository {
+    async fn save(&self, rule: &TaxRule) -> Result<(), SalesError> {
+        sqlx::query(
+            r#"
+            INSERT INTO tax_rules (
+                id, store_id, jurisdiction, product_id, category_id, rate, tax_inclusive,
+                exempt_customer_types, is_active, created_by_id, created_at, updated_at
+            )
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12)
+            "#,
+        )
+        .bind(rule.id().into_uuid())
+        .bind(rule.store_id())
+        .bind(rule.jurisdiction())
+        .bind(rule.product_id())
+        .bind(rule.category_id())
+        .bind(rule.rate())
+        .bind(rule.tax_inclusive())
+        .bind(exempt_customer_types(rule))
+        .bind(rule.is_active())
+        .bind(rule.created_by_id().into_uuid())
+        .bind(rule.created_at())
+        .bind(rule.updated_at())
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    async fn update(&self, rule: &TaxRule) -> Result<(), SalesError> {
+        sqlx::query(
+            r#"
+            UPDATE tax_rules SET
+                jurisdiction = $2, rate = $3, tax_inclusive = $4, exempt_customer_types = $5,
+                is_active = $6, updated_at = $7
+            WHERE id = $1
+            "#,
+        )
+        .bind(rule.id().into_uuid())
+        .bind(rule.jurisdiction())
+        .bind(rule.rate())
+        .bind(rule.tax_inclusive())
+        .bind(exempt_customer_types(rule))
+        .bind(rule.is_active())
+        .bind(rule.updated_at())
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    async fn find_by_id(&self, id: TaxRuleId) -> Result<Option<TaxRule>, SalesError> {
+        let row = sqlx::query_as::<_, TaxRuleRow>(r#"SELECT * FROM tax_rules WHERE id = $1"#)
+            .bind(id.into_uuid())
+            .fetch_optional(&self.pool)
+            .await?;
+
+        row.map(TaxRule::try_from).transpose()
+    }
+
+    async fn find_by_target(
+        &self,
+        store_id: Uuid,
+        product_id: Option<Uuid>,
+        category_id: Option<Uuid>,
+    ) -> Result<Option<TaxRule>, SalesError> {
+        let row = sqlx::query_as::<_, TaxRuleRow>(
+            r#"
+            SELECT * FROM tax_rules
+            WHERE store_id = $1
+              AND product_id IS NOT DISTINCT FROM $2
+              AND category_id IS NOT DISTINCT FROM $3
+            "#,
+        )
+        .bind(store_id)
+        .bind(product_id)
+        .bind(category_id)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        row.map(TaxRule::try_from).transpose()
+    }
+
+    async fn list(&self, store_id: Option<Uuid>) -> Result<Vec<TaxRule>, SalesError> {
+        let rows = sqlx::query_as::<_, TaxRuleRow>(
+            r#"
+            SELECT * FROM tax_rules
+            WHERE ($1::UUID IS NULL OR store_id = $1)
+            ORDER BY created_at DESC
+            "#,
+        )
+        .bind(store_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        rows.into_iter().map(TaxRule::try_from).collect()
+    }
+
+    async fn find_active_by_store(&self, store_id: Uuid) -> Result<Vec<TaxRule>, SalesError> {
+        let rows = sqlx::query_as::<_, TaxRuleRow>(
+            r#"SELECT * FROM tax_rules WHERE store_id = $1 AND is_active = TRUE"#,
+        )
+        .bind(store_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        rows.into_iter().map(TaxRule::try_from).collect()
+    }
+}
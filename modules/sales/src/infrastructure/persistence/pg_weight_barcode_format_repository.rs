@@ -0,0 +1,121 @@
+//! PostgreSQL WeightBarcodeFormatRepository implementation
+
+use std::str::FromStr;
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::SalesError;
+use crate::domain::entities::WeightBarcodeFormat;
+use crate::domain::repositories::WeightBarcodeFormatRepository;
+use crate::domain::value_objects::ScaleValueKind;
+use identity::UserId;
+
+/// Row type for reading weight barcode formats from the database
+#[derive(Debug, sqlx::FromRow)]
+struct WeightBarcodeFormatRow {
+    id: Uuid,
+    store_id: Uuid,
+    prefix: String,
+    value_start: i16,
+    value_length: i16,
+    value_kind: String,
+    divisor: i32,
+    created_by_id: Uuid,
+    created_at: DateTime<Utc>,
+}
+
+impl TryFrom<WeightBarcodeFormatRow> for WeightBarcodeFormat {
+    type Error = SalesError;
+
+    fn try_from(row: WeightBarcodeFormatRow) -> Result<Self, Self::Error> {
+        Ok(WeightBarcodeFormat::reconstitute(
+            row.id,
+            row.store_id,
+            row.prefix,
+            row.value_start as u8,
+            row.value_length as u8,
+            ScaleValueKind::from_str(&row.value_kind)?,
+            row.divisor as u32,
+            UserId::from_uuid(row.created_by_id),
+            row.created_at,
+        ))
+    }
+}
+
+pub struct PgWeightBarcodeFormatRepository {
+    pool: PgPool,
+}
+
+impl PgWeightBarcodeFormatRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl WeightBarcodeFormatRepository for PgWeightBarcodeFormatRepository {
+    async fn save(&self, format: &WeightBarcodeFormat) -> Result<(), SalesError> {
+        sqlx::query(
+            r#"
+            INSERT INTO weight_barcode_formats (
+                id, store_id, prefix, value_start, value_length, value_kind, divisor,
+                created_by_id, created_at
+            )
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
+            "#,
+        )
+        .bind(format.id())
+        .bind(format.store_id())
+        .bind(format.prefix())
+        .bind(format.value_start() as i16)
+        .bind(format.value_length() as i16)
+        .bind(format.value_kind().to_string())
+        .bind(format.divisor() as i32)
+        .bind(format.created_by_id().into_uuid())
+        .bind(format.created_at())
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<WeightBarcodeFormat>, SalesError> {
+        let row = sqlx::query_as::<_, WeightBarcodeFormatRow>(
+            "SELECT * FROM weight_barcode_formats WHERE id = $1",
+        )
+        .bind(id)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        row.map(WeightBarcodeFormat::try_from).transpose()
+    }
+
+    async fn find_by_store(&self, store_id: Uuid) -> Result<Vec<WeightBarcodeFormat>, SalesError> {
+        let rows = sqlx::query_as::<_, WeightBarcodeFormatRow>(
+            r#"
+            SELECT * FROM weight_barcode_formats
+            WHERE store_id = $1
+            ORDER BY prefix
+            "#,
+        )
+        .bind(store_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        rows.into_iter()
+            .map(WeightBarcodeFormat::try_from)
+            .collect()
+    }
+
+    async fn delete(&self, id: Uuid) -> Result<(), SalesError> {
+        sqlx::query("DELETE FROM weight_barcode_formats WHERE id = $1")
+            .bind(id)
+            .execute(&self.pool)
+            .await?;
+
+        Ok(())
+    }
+}
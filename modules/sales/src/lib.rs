@@ -33,6 +33,13 @@
 //! Delivered → Returned (via Credit Note)
 //! ```
 //!
+//! ### Pickup Order Workflow
+//! ```text
+//! Paid/Processing → ReadyForPickup → PickedUp
+//!                                 ↘ Cancelled (no-show after the store's hold)
+//! PickedUp → Returned (via Credit Note)
+//! ```
+//!
 //! ### Credit Note Workflow
 //! ```text
 //! Draft → Pending → Approved → Applied
@@ -62,26 +69,57 @@ pub use domain::value_objects::CartItemId;
 pub use domain::value_objects::CreditNoteId;
 pub use domain::value_objects::CreditNoteItemId;
 pub use domain::value_objects::CustomerId;
+pub use domain::value_objects::GiftCardId;
+pub use domain::value_objects::LayawayId;
+pub use domain::value_objects::MarkdownId;
+pub use domain::value_objects::MarkdownRuleId;
 pub use domain::value_objects::PaymentId;
+pub use domain::value_objects::PriceListId;
+pub use domain::value_objects::PriceTierId;
 pub use domain::value_objects::SaleId;
 pub use domain::value_objects::SaleItemId;
 pub use domain::value_objects::ShiftId;
+pub use domain::value_objects::TaxRuleId;
 
+pub use domain::value_objects::PromotionDiscount;
 pub use domain::value_objects::PromotionId;
+pub use domain::value_objects::PromotionLine;
+
+// Other value objects
+pub use domain::value_objects::CartTtlPolicy;
+pub use domain::value_objects::CreditNoteDocument;
+pub use domain::value_objects::CreditNoteDocumentLine;
+pub use domain::value_objects::CreditNoteDocumentSale;
+pub use domain::value_objects::CustomerCodeFormat;
+pub use domain::value_objects::CustomerStatement;
+pub use domain::value_objects::CustomerStatementLine;
+pub use domain::value_objects::ReturnAllocation;
+pub use domain::value_objects::SaleLineAllocation;
+pub use domain::value_objects::SaleTaxBreakdown;
 
 // Enum value objects
+pub use domain::value_objects::AccountEntryType;
 pub use domain::value_objects::CreditNoteStatus;
 pub use domain::value_objects::CustomerType;
 pub use domain::value_objects::DiscountType;
+pub use domain::value_objects::GiftCardStatus;
+pub use domain::value_objects::IdempotentOperation;
+pub use domain::value_objects::LayawayStatus;
+pub use domain::value_objects::MarkdownReason;
+pub use domain::value_objects::MarkdownStatus;
 pub use domain::value_objects::OrderStatus;
 pub use domain::value_objects::PaymentMethod;
 pub use domain::value_objects::PaymentStatus;
+pub use domain::value_objects::PickupStatus;
 pub use domain::value_objects::PromotionStatus;
 pub use domain::value_objects::PromotionType;
+pub use domain::value_objects::RestockCostBasis;
 pub use domain::value_objects::ReturnReason;
 pub use domain::value_objects::ReturnType;
 pub use domain::value_objects::SaleStatus;
 pub use domain::value_objects::SaleType;
+pub use domain::value_objects::ScaleValueKind;
+pub use domain::value_objects::ShiftCount;
 pub use domain::value_objects::ShiftStatus;
 
 // -----------------------------------------------------------------------------
@@ -95,10 +133,35 @@ pub use domain::entities::CashierShift;
 pub use domain::entities::CreditNote;
 pub use domain::entities::CreditNoteItem;
 pub use domain::entities::Customer;
+pub use domain::entities::CustomerAccountEntry;
+pub use domain::entities::ExchangeRate;
+pub use domain::entities::GIFT_CARD_CODE_LENGTH;
+pub use domain::entities::GiftCard;
+pub use domain::entities::IDEMPOTENCY_KEY_TTL_HOURS;
+pub use domain::entities::Layaway;
+pub use domain::entities::LayawayInstallment;
+pub use domain::entities::MAX_IDEMPOTENCY_KEY_LENGTH;
+pub use domain::entities::MAX_LAYAWAY_INSTALLMENTS;
+pub use domain::entities::MAX_LAYAWAY_INTERVAL_DAYS;
+pub use domain::entities::MAX_WEIGHT_BARCODE_DIVISOR;
+pub use domain::entities::MAX_WEIGHT_BARCODE_PREFIX_LEN;
+pub use domain::entities::MAX_WEIGHT_BARCODE_VALUE_LEN;
+pub use domain::entities::Markdown;
+pub use domain::entities::MarkdownRule;
+pub use domain::entities::OrderPickup;
+pub use domain::entities::PICKUP_CODE_LENGTH;
 pub use domain::entities::Payment;
+pub use domain::entities::PriceList;
+pub use domain::entities::PriceListEntry;
+pub use domain::entities::PriceTier;
 pub use domain::entities::Promotion;
 pub use domain::entities::Sale;
+pub use domain::entities::SaleIdempotencyKey;
 pub use domain::entities::SaleItem;
+pub use domain::entities::ScaleLabel;
+pub use domain::entities::ShiftReopen;
+pub use domain::entities::TaxRule;
+pub use domain::entities::WeightBarcodeFormat;
 
 // -----------------------------------------------------------------------------
 // Domain Layer - Repository Traits
@@ -110,12 +173,26 @@ pub use domain::repositories::CreditNoteFilter;
 pub use domain::repositories::CreditNoteRepository;
 pub use domain::repositories::CustomerFilter;
 pub use domain::repositories::CustomerRepository;
+pub use domain::repositories::ExchangeRateRepository;
+pub use domain::repositories::GiftCardRepository;
+pub use domain::repositories::LayawayRepository;
+pub use domain::repositories::MarkdownFilter;
+pub use domain::repositories::MarkdownRepository;
+pub use domain::repositories::OrderPickupRepository;
+pub use domain::repositories::PriceListRepository;
+pub use domain::repositories::PriceTierRepository;
 pub use domain::repositories::PromotionFilter;
 pub use domain::repositories::PromotionRepository;
+pub use domain::repositories::QuickSale;
+pub use domain::repositories::QuickSaleRepository;
 pub use domain::repositories::SaleFilter;
+pub use domain::repositories::SaleIdempotencyRepository;
 pub use domain::repositories::SaleRepository;
 pub use domain::repositories::ShiftFilter;
 pub use domain::repositories::ShiftRepository;
+pub use domain::repositories::StockDeduction;
+pub use domain::repositories::TaxRuleRepository;
+pub use domain::repositories::WeightBarcodeFormatRepository;
 
 // -----------------------------------------------------------------------------
 // Infrastructure Layer - PostgreSQL Repository Implementations
@@ -124,9 +201,27 @@ pub use domain::repositories::ShiftRepository;
 pub use infrastructure::persistence::PgCartRepository;
 pub use infrastructure::persistence::PgCreditNoteRepository;
 pub use infrastructure::persistence::PgCustomerRepository;
+pub use infrastructure::persistence::PgExchangeRateRepository;
+pub use infrastructure::persistence::PgGiftCardRepository;
+pub use infrastructure::persistence::PgLayawayRepository;
+pub use infrastructure::persistence::PgMarkdownRepository;
+pub use infrastructure::persistence::PgOrderPickupRepository;
+pub use infrastructure::persistence::PgPriceListRepository;
+pub use infrastructure::persistence::PgPriceTierRepository;
 pub use infrastructure::persistence::PgPromotionRepository;
+pub use infrastructure::persistence::PgQuickSaleRepository;
+pub use infrastructure::persistence::PgSaleIdempotencyRepository;
 pub use infrastructure::persistence::PgSaleRepository;
 pub use infrastructure::persistence::PgShiftRepository;
+pub use infrastructure::persistence::PgTaxRuleRepository;
+pub use infrastructure::persistence::PgWeightBarcodeFormatRepository;
+
+// -----------------------------------------------------------------------------
+// Infrastructure Layer - Outbound Gateways
+// -----------------------------------------------------------------------------
+
+pub use infrastructure::OrderInvoiceIssuer;
+pub use infrastructure::OrderNotifier;
 
 // -----------------------------------------------------------------------------
 // Application Layer - DTOs
@@ -135,10 +230,19 @@ pub use infrastructure::persistence::PgShiftRepository;
 // Customer DTOs
 pub use application::dtos::AddressInput;
 pub use application::dtos::AddressResponse;
+pub use application::dtos::ConfigureCustomerCodeFormatCommand;
 pub use application::dtos::CreateCustomerCommand;
+pub use application::dtos::CustomerAccountEntryResponse;
+pub use application::dtos::CustomerCodeFormatResponse;
+pub use application::dtos::CustomerCreditBalanceResponse;
 pub use application::dtos::CustomerListResponse;
 pub use application::dtos::CustomerResponse;
+pub use application::dtos::CustomerStatementLineResponse;
+pub use application::dtos::CustomerStatementQuery;
+pub use application::dtos::CustomerStatementResponse;
+pub use application::dtos::GetCustomerByCodeQuery;
 pub use application::dtos::ListCustomersQuery;
+pub use application::dtos::RecordAccountPaymentCommand;
 pub use application::dtos::UpdateCustomerCommand;
 
 // Shift DTOs
@@ -147,6 +251,7 @@ pub use application::dtos::CloseShiftCommand;
 pub use application::dtos::ListShiftsQuery;
 pub use application::dtos::OpenShiftCommand;
 pub use application::dtos::PaymentBreakdownItem;
+pub use application::dtos::ReopenShiftCommand;
 pub use application::dtos::SalesBreakdown;
 pub use application::dtos::ShiftListResponse;
 pub use application::dtos::ShiftReportResponse;
@@ -156,6 +261,7 @@ pub use application::dtos::ShiftResponse;
 pub use application::dtos::AddCartItemCommand;
 pub use application::dtos::CartItemResponse;
 pub use application::dtos::CartResponse;
+pub use application::dtos::CleanupCartsResult;
 pub use application::dtos::CreateCartCommand;
 pub use application::dtos::UpdateCartItemCommand;
 
@@ -164,7 +270,11 @@ pub use application::dtos::AddCreditNoteItemCommand;
 pub use application::dtos::ApplyCreditNoteCommand;
 pub use application::dtos::CancelCreditNoteCommand;
 pub use application::dtos::CreateCreditNoteCommand;
+pub use application::dtos::CreditNoteDocumentLineResponse;
+pub use application::dtos::CreditNoteDocumentResponse;
+pub use application::dtos::CreditNoteDocumentSaleResponse;
 pub use application::dtos::CreditNoteItemResponse;
+pub use application::dtos::CreditNoteLineCommand;
 pub use application::dtos::CreditNoteListResponse;
 pub use application::dtos::CreditNoteResponse;
 pub use application::dtos::ListCreditNotesQuery;
@@ -173,20 +283,75 @@ pub use application::dtos::SubmitCreditNoteCommand;
 // Promotion DTOs
 pub use application::dtos::ApplyPromotionCommand;
 pub use application::dtos::CreatePromotionCommand;
+pub use application::dtos::EvaluatePromotionsCommand;
+pub use application::dtos::PromotionDiscountResponse;
+pub use application::dtos::PromotionEvaluationResponse;
 pub use application::dtos::PromotionResponse;
 pub use application::dtos::UpdatePromotionCommand;
 
+// Markdown DTOs
+pub use application::dtos::ApplyAutoMarkdownsResult;
+pub use application::dtos::MarkdownResponse;
+pub use application::dtos::MarkdownRuleResponse;
+pub use application::dtos::ReverseMarkdownCommand;
+pub use application::dtos::UpsertMarkdownRuleCommand;
+
+// Price Tier DTOs
+pub use application::dtos::CreatePriceListCommand;
+pub use application::dtos::ExchangeRateResponse;
+pub use application::dtos::PriceListEntryResponse;
+pub use application::dtos::PriceListResponse;
+pub use application::dtos::PriceTierResponse;
+pub use application::dtos::RecordExchangeRateCommand;
+pub use application::dtos::SetPriceListEntryCommand;
+pub use application::dtos::UpsertPriceTierCommand;
+
+// Tax Rule DTOs
+pub use application::dtos::TaxRuleResponse;
+pub use application::dtos::UpsertTaxRuleCommand;
+
+// Gift Card DTOs
+pub use application::dtos::GiftCardBalanceResponse;
+pub use application::dtos::GiftCardResponse;
+pub use application::dtos::IssueGiftCardCommand;
+
+// Layaway DTOs
+pub use application::dtos::CancelLayawayCommand;
+pub use application::dtos::LayawayBalanceResponse;
+pub use application::dtos::LayawayInstallmentResponse;
+pub use application::dtos::LayawayResponse;
+pub use application::dtos::LayawayTermsCommand;
+
+// Weight Barcode DTOs
+pub use application::dtos::CreateWeightBarcodeFormatCommand;
+pub use application::dtos::WeightBarcodeFormatResponse;
+
+// Pickup DTOs
+pub use application::dtos::CompletePickupCommand;
+pub use application::dtos::ExpirePickupsResult;
+pub use application::dtos::OrderPickupResponse;
+pub use application::dtos::PickupCompletedResponse;
+pub use application::dtos::ReadyForPickupResponse;
+
 // Sale DTOs
 pub use application::dtos::AddSaleItemCommand;
 pub use application::dtos::ApplyDiscountCommand;
+pub use application::dtos::BarcodeLookupQuery;
+pub use application::dtos::BarcodeLookupResponse;
 pub use application::dtos::CreatePosSaleCommand;
 pub use application::dtos::ListSalesQuery;
+pub use application::dtos::PaymentAllocation;
 pub use application::dtos::PaymentResponse;
 pub use application::dtos::ProcessPaymentCommand;
+pub use application::dtos::QuickSaleCommand;
 pub use application::dtos::SaleDetailResponse;
 pub use application::dtos::SaleItemResponse;
 pub use application::dtos::SaleListResponse;
 pub use application::dtos::SaleResponse;
+pub use application::dtos::SaleTaxBreakdownResponse;
+pub use application::dtos::ScaleLabelResponse;
+pub use application::dtos::SerialSaleResponse;
+pub use application::dtos::SetSaleCurrencyCommand;
 pub use application::dtos::UpdateSaleItemCommand;
 pub use application::dtos::VoidSaleCommand;
 
@@ -195,22 +360,32 @@ pub use application::dtos::VoidSaleCommand;
 // -----------------------------------------------------------------------------
 
 // Customer Use Cases
+pub use application::use_cases::ConfigureCustomerCodeFormatUseCase;
 pub use application::use_cases::CreateCustomerUseCase;
+pub use application::use_cases::GetCustomerByCodeUseCase;
+pub use application::use_cases::GetCustomerCreditBalanceUseCase;
+pub use application::use_cases::GetCustomerStatementUseCase;
 pub use application::use_cases::GetCustomerUseCase;
 pub use application::use_cases::ListCustomersUseCase;
+pub use application::use_cases::RecordAccountPaymentUseCase;
 pub use application::use_cases::ToggleCustomerStatusUseCase;
 pub use application::use_cases::UpdateCustomerUseCase;
 
 // Shift Use Cases
 pub use application::use_cases::CloseShiftUseCase;
+pub use application::use_cases::DEFAULT_SHIFT_REOPEN_WINDOW_MINUTES;
+pub use application::use_cases::DEFAULT_SHIFT_VARIANCE_THRESHOLD;
 pub use application::use_cases::GetCurrentShiftUseCase;
 pub use application::use_cases::GetShiftReportUseCase;
+pub use application::use_cases::GetShiftSnapshotUseCase;
 pub use application::use_cases::ListShiftsUseCase;
 pub use application::use_cases::OpenShiftUseCase;
 pub use application::use_cases::RecordCashMovementUseCase;
+pub use application::use_cases::ReopenShiftUseCase;
 
 // Cart Use Cases
 pub use application::use_cases::AddCartItemUseCase;
+pub use application::use_cases::CleanupCartsUseCase;
 pub use application::use_cases::ClearCartUseCase;
 pub use application::use_cases::CreateCartUseCase;
 pub use application::use_cases::GetCartUseCase;
@@ -223,6 +398,7 @@ pub use application::use_cases::ApplyCreditNoteUseCase;
 pub use application::use_cases::ApproveCreditNoteUseCase;
 pub use application::use_cases::CancelCreditNoteUseCase;
 pub use application::use_cases::CreateCreditNoteUseCase;
+pub use application::use_cases::GenerateCreditNoteDocumentUseCase;
 pub use application::use_cases::GetCreditNoteUseCase;
 pub use application::use_cases::ListCreditNotesUseCase;
 pub use application::use_cases::RemoveCreditNoteItemUseCase;
@@ -232,15 +408,59 @@ pub use application::use_cases::SubmitCreditNoteUseCase;
 pub use application::use_cases::ApplyPromotionUseCase;
 pub use application::use_cases::CreatePromotionUseCase;
 pub use application::use_cases::DeactivatePromotionUseCase;
+pub use application::use_cases::EvaluatePromotionsUseCase;
 pub use application::use_cases::GetPromotionUseCase;
 pub use application::use_cases::ListPromotionsQuery;
 pub use application::use_cases::ListPromotionsUseCase;
 pub use application::use_cases::UpdatePromotionUseCase;
 
+// Markdown Use Cases
+pub use application::use_cases::ApplyAutoMarkdownsUseCase;
+pub use application::use_cases::ListMarkdownRulesUseCase;
+pub use application::use_cases::ListMarkdownsQuery;
+pub use application::use_cases::ListMarkdownsUseCase;
+pub use application::use_cases::ReverseMarkdownUseCase;
+pub use application::use_cases::UpsertMarkdownRuleUseCase;
+
+// Price Tier Use Cases
+pub use application::use_cases::CreatePriceListUseCase;
+pub use application::use_cases::ListExchangeRatesUseCase;
+pub use application::use_cases::ListPriceListsUseCase;
+pub use application::use_cases::ListPriceTiersUseCase;
+pub use application::use_cases::RecordExchangeRateUseCase;
+pub use application::use_cases::SetPriceListEntryUseCase;
+pub use application::use_cases::UpsertPriceTierUseCase;
+
+// Tax Rule Use Cases
+pub use application::use_cases::CalculateTaxUseCase;
+pub use application::use_cases::ListTaxRulesUseCase;
+pub use application::use_cases::UpsertTaxRuleUseCase;
+
+// Gift Card Use Cases
+pub use application::use_cases::GetGiftCardBalanceUseCase;
+pub use application::use_cases::IssueGiftCardUseCase;
+
+// Layaway Use Cases
+pub use application::use_cases::CancelLayawayUseCase;
+pub use application::use_cases::GetLayawayBalanceUseCase;
+pub use application::use_cases::LAYAWAY_HOLD_GRACE_DAYS;
+pub use application::use_cases::apply_layaway_payment;
+pub use application::use_cases::prepare_layaway_payment;
+
+// Weight Barcode Use Cases
+pub use application::use_cases::CreateWeightBarcodeFormatUseCase;
+pub use application::use_cases::DeleteWeightBarcodeFormatUseCase;
+pub use application::use_cases::ListWeightBarcodeFormatsUseCase;
+
 // E-commerce Order Use Cases
 pub use application::use_cases::CancelOrderUseCase;
+pub use application::use_cases::CompletePickupUseCase;
 pub use application::use_cases::DeliverOrderUseCase;
+pub use application::use_cases::ExpirePickupsUseCase;
+pub use application::use_cases::GetOrderPickupUseCase;
 pub use application::use_cases::MarkOrderPaidUseCase;
+pub use application::use_cases::MarkOrderReadyForPickupUseCase;
+pub use application::use_cases::ORDER_RESERVATION_REFERENCE;
 pub use application::use_cases::ProcessOrderUseCase;
 pub use application::use_cases::ShipOrderUseCase;
 
@@ -249,9 +469,26 @@ pub use application::use_cases::AddSaleItemUseCase;
 pub use application::use_cases::ApplyDiscountUseCase;
 pub use application::use_cases::CompleteSaleUseCase;
 pub use application::use_cases::CreatePosSaleUseCase;
+pub use application::use_cases::FindProductByBarcodeUseCase;
 pub use application::use_cases::GetSaleUseCase;
+pub use application::use_cases::GiftCardRedemption;
 pub use application::use_cases::ListSalesUseCase;
+pub use application::use_cases::LookupSerialSaleUseCase;
+pub use application::use_cases::ParkSaleUseCase;
 pub use application::use_cases::ProcessPaymentUseCase;
+pub use application::use_cases::QuickSaleUseCase;
+pub use application::use_cases::RESUMED_SALE_HOLD_MINUTES;
 pub use application::use_cases::RemoveSaleItemUseCase;
+pub use application::use_cases::ResumeSaleUseCase;
+pub use application::use_cases::SALE_RESERVATION_REFERENCE;
+pub use application::use_cases::SetSaleCurrencyUseCase;
+pub use application::use_cases::TenderRates;
 pub use application::use_cases::UpdateSaleItemUseCase;
 pub use application::use_cases::VoidSaleUseCase;
+pub use application::use_cases::gift_card_tenders;
+pub use application::use_cases::idempotency_key_for;
+pub use application::use_cases::is_idempotent_replay;
+pub use application::use_cases::release_sale_reservations;
+pub use application::use_cases::reserve_sale_stock;
+pub use application::use_cases::tender_payments;
+pub use application::use_cases::tender_rates;
@@ -0,0 +1,234 @@
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::application::dtos::TrackingEventResponse;
+use crate::domain::entities::OrderPick;
+use crate::domain::repositories::{PickLineSnapshot, PickQueueOrderSnapshot};
+
+/// Payment picture of an order, derived from its totals and refunds.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum OrderPaymentStatus {
+    Unpaid,
+    PartiallyPaid,
+    Paid,
+    PartiallyRefunded,
+    Refunded,
+}
+
+/// Where a single order line stands.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LineFulfillmentStatus {
+    /// Not reserved yet, but the allocation store has enough stock.
+    Pending,
+    /// Held by a live reservation, waiting for the shipment to leave.
+    Allocated,
+    /// Not reserved and the allocation store cannot cover the quantity.
+    Backordered,
+    /// Handed to the carrier (or picked up) but not yet delivered.
+    Shipped,
+    Delivered,
+    Cancelled,
+}
+
+/// Roll-up of the line statuses for the whole order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum OrderFulfillmentState {
+    /// Nothing has left the store yet.
+    Unfulfilled,
+    /// Some lines left with the shipment, others are still owed.
+    PartiallyShipped,
+    Shipped,
+    Delivered,
+    Cancelled,
+}
+
+#[derive(Debug, Serialize)]
+pub struct OrderPaymentResponse {
+    pub id: Uuid,
+    pub payment_method: String,
+    pub status: String,
+    pub amount: Decimal,
+    pub refunded_amount: Decimal,
+    pub processed_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct OrderLineFulfillmentResponse {
+    pub sale_item_id: Uuid,
+    pub line_number: i32,
+    pub product_id: Uuid,
+    pub variant_id: Option<Uuid>,
+    pub sku: String,
+    pub description: String,
+    pub allocation_store_id: Uuid,
+    pub reservation_id: Option<Uuid>,
+    pub reservation_status: Option<String>,
+    pub reservation_expires_at: Option<DateTime<Utc>>,
+    pub status: LineFulfillmentStatus,
+    pub ordered_quantity: Decimal,
+    pub shipped_quantity: Decimal,
+    pub remaining_quantity: Decimal,
+}
+
+/// Shipment view for the order page: carrier/tracking and the event log,
+/// without the recipient details.
+#[derive(Debug, Serialize)]
+pub struct OrderShipmentResponse {
+    pub id: Uuid,
+    pub status: String,
+    pub method_type: String,
+    pub tracking_number: Option<String>,
+    pub carrier_name: Option<String>,
+    pub estimated_delivery: Option<DateTime<Utc>>,
+    pub shipped_at: Option<DateTime<Utc>>,
+    pub picked_up_at: Option<DateTime<Utc>>,
+    pub delivered_at: Option<DateTime<Utc>>,
+    pub events: Vec<TrackingEventResponse>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct OrderFulfillmentResponse {
+    pub sale_id: Uuid,
+    pub sale_number: String,
+    pub store_id: Uuid,
+    pub order_status: Option<String>,
+    pub currency: String,
+    pub total: Decimal,
+    pub amount_paid: Decimal,
+    pub amount_due: Decimal,
+    pub payment_status: OrderPaymentStatus,
+    pub fulfillment_status: OrderFulfillmentState,
+    pub backordered_lines: usize,
+    pub lines: Vec<OrderLineFulfillmentResponse>,
+    pub payments: Vec<OrderPaymentResponse>,
+    /// Sales carry at most one shipment.
+    pub shipment: Option<OrderShipmentResponse>,
+    pub created_at: DateTime<Utc>,
+}
+
+// =============================================================================
+// Pick queue
+// =============================================================================
+
+#[derive(Debug, Deserialize)]
+pub struct PickQueueQuery {
+    pub store_id: Uuid,
+    pub page: Option<i64>,
+    pub page_size: Option<i64>,
+}
+
+/// Claim, release, hold or resume an order in the pick queue. The caller
+/// sends the `version` it read from the queue; the change is refused with a
+/// conflict if the order's pick state moved on since.
+#[derive(Debug, Deserialize)]
+pub struct OrderPickCommand {
+    #[serde(default)]
+    pub sale_id: Uuid,
+    pub expected_version: i32,
+    /// Why the order is put on hold; ignored by the other actions.
+    #[serde(default)]
+    pub reason: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct PickLineResponse {
+    pub sale_item_id: Uuid,
+    pub line_number: i32,
+    pub product_id: Uuid,
+    pub variant_id: Option<Uuid>,
+    pub sku: String,
+    pub description: String,
+    pub quantity: Decimal,
+    pub allocation_store_id: Uuid,
+    pub bin_location: Option<String>,
+}
+
+impl From<PickLineSnapshot> for PickLineResponse {
+    fn from(l: PickLineSnapshot) -> Self {
+        Self {
+            sale_item_id: l.sale_item_id,
+            line_number: l.line_number,
+            product_id: l.product_id,
+            variant_id: l.variant_id,
+            sku: l.sku,
+            description: l.description,
+            quantity: l.quantity,
+            allocation_store_id: l.allocation_store_id,
+            bin_location: l.bin_location,
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct PickQueueOrderResponse {
+    pub sale_id: Uuid,
+    pub sale_number: String,
+    pub store_id: Uuid,
+    pub order_status: String,
+    pub paid_at: DateTime<Utc>,
+    pub shipping_method_name: Option<String>,
+    pub shipping_method_type: Option<String>,
+    pub picker_id: Option<Uuid>,
+    pub claimed_at: Option<DateTime<Utc>>,
+    /// Send back as `expected_version` to claim the order.
+    pub version: i32,
+    pub lines: Vec<PickLineResponse>,
+}
+
+impl From<PickQueueOrderSnapshot> for PickQueueOrderResponse {
+    fn from(o: PickQueueOrderSnapshot) -> Self {
+        Self {
+            sale_id: o.sale_id,
+            sale_number: o.sale_number,
+            store_id: o.store_id,
+            order_status: o.order_status,
+            paid_at: o.paid_at,
+            shipping_method_name: o.shipping_method_name,
+            shipping_method_type: o.shipping_method_type,
+            picker_id: o.picker_id,
+            claimed_at: o.claimed_at,
+            version: o.version,
+            lines: o.lines.into_iter().map(PickLineResponse::from).collect(),
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct PickQueueResponse {
+    pub items: Vec<PickQueueOrderResponse>,
+    pub total: i64,
+    pub page: i64,
+    pub page_size: i64,
+}
+
+#[derive(Debug, Serialize)]
+pub struct OrderPickResponse {
+    pub sale_id: Uuid,
+    pub store_id: Uuid,
+    pub picker_id: Option<Uuid>,
+    pub claimed_at: Option<DateTime<Utc>>,
+    pub on_hold: bool,
+    pub hold_reason: Option<String>,
+    pub version: i32,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl From<OrderPick> for OrderPickResponse {
+    fn from(p: OrderPick) -> Self {
+        Self {
+            sale_id: p.sale_id().into_uuid(),
+            store_id: p.store_id().into_uuid(),
+            picker_id: p.picker_id().map(|u| u.into_uuid()),
+            claimed_at: p.claimed_at(),
+            on_hold: p.on_hold(),
+            hold_reason: p.hold_reason().map(str::to_string),
+            version: p.version(),
+            updated_at: p.updated_at(),
+        }
+    }
+}
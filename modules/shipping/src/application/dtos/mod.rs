@@ -2,6 +2,7 @@
 
 pub mod delivery_provider;
 pub mod driver;
+pub mod fulfillment;
 pub mod method;
 pub mod rate;
 pub mod shipment;
@@ -11,6 +12,7 @@ pub mod zone;
 
 pub use delivery_provider::*;
 pub use driver::*;
+pub use fulfillment::*;
 pub use method::*;
 pub use rate::*;
 pub use shipment::*;
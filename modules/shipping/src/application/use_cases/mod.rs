@@ -4,6 +4,8 @@ pub mod calculate_shipping_use_case;
 pub mod delivery_provider_use_cases;
 pub mod driver_use_cases;
 pub mod method_use_cases;
+pub mod order_fulfillment_use_case;
+pub mod pick_queue_use_cases;
 pub mod public_tracking_use_case;
 pub mod rate_use_cases;
 pub mod shipment_use_cases;
@@ -14,6 +16,8 @@ pub use calculate_shipping_use_case::CalculateShippingUseCase;
 pub use delivery_provider_use_cases::*;
 pub use driver_use_cases::*;
 pub use method_use_cases::*;
+pub use order_fulfillment_use_case::GetOrderFulfillmentStatusUseCase;
+pub use pick_queue_use_cases::*;
 pub use public_tracking_use_case::PublicTrackingUseCase;
 pub use rate_use_cases::*;
 pub use shipment_use_cases::*;
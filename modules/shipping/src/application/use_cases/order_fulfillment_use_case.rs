@@ -0,0 +1,429 @@
+//! "Where's my order" — one view of an order's payment, per-line allocation
+//! and reservation state, shipment tracking and remaining-to-ship quantities.
+//!
+//! Sales carry a single shipment, so a line ships when that shipment leaves
+//! the store. A line that is backordered at that point (no live reservation
+//! and not enough stock at its allocation store) is still owed, which is how
+//! an order ends up `partially_shipped`.
+
+use std::sync::Arc;
+
+use rust_decimal::Decimal;
+use uuid::Uuid;
+
+use crate::ShippingError;
+use crate::application::dtos::{
+    LineFulfillmentStatus, OrderFulfillmentResponse, OrderFulfillmentState,
+    OrderLineFulfillmentResponse, OrderPaymentResponse, OrderPaymentStatus, OrderShipmentResponse,
+    TrackingEventResponse,
+};
+use crate::domain::entities::Shipment;
+use crate::domain::repositories::{
+    OrderFulfillmentRepository, OrderHeaderSnapshot, OrderLineSnapshot, OrderPaymentSnapshot,
+    ShipmentRepository, ShipmentTrackingEventRepository,
+};
+use crate::domain::value_objects::ShipmentStatus;
+use identity::UserId;
+use sales::SaleId;
+
+/// How far the order's shipment has progressed, as seen by its lines.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ShipmentProgress {
+    /// No shipment, or it has not left the store (or came back).
+    NotShipped,
+    Shipped,
+    Delivered,
+}
+
+impl ShipmentProgress {
+    fn of(shipment: Option<&Shipment>) -> Self {
+        let Some(s) = shipment else {
+            return Self::NotShipped;
+        };
+        match s.status() {
+            ShipmentStatus::Delivered | ShipmentStatus::PickedUp => Self::Delivered,
+            ShipmentStatus::Cancelled | ShipmentStatus::Returned | ShipmentStatus::Expired => {
+                Self::NotShipped
+            }
+            _ if s.shipped_at().is_some() => Self::Shipped,
+            _ => Self::NotShipped,
+        }
+    }
+}
+
+pub struct GetOrderFulfillmentStatusUseCase {
+    fulfillment_repo: Arc<dyn OrderFulfillmentRepository>,
+    shipment_repo: Arc<dyn ShipmentRepository>,
+    event_repo: Arc<dyn ShipmentTrackingEventRepository>,
+}
+
+impl GetOrderFulfillmentStatusUseCase {
+    pub fn new(
+        fulfillment_repo: Arc<dyn OrderFulfillmentRepository>,
+        shipment_repo: Arc<dyn ShipmentRepository>,
+        event_repo: Arc<dyn ShipmentTrackingEventRepository>,
+    ) -> Self {
+        Self {
+            fulfillment_repo,
+            shipment_repo,
+            event_repo,
+        }
+    }
+
+    /// Builds the fulfillment view for `sale_id`. When `customer_user` is set
+    /// the order must belong to that user's customer record; other orders are
+    /// reported as not found.
+    pub async fn execute(
+        &self,
+        sale_id: Uuid,
+        customer_user: Option<UserId>,
+    ) -> Result<OrderFulfillmentResponse, ShippingError> {
+        let sale = SaleId::from_uuid(sale_id);
+        let snapshot = self
+            .fulfillment_repo
+            .find_snapshot(sale)
+            .await?
+            .ok_or(ShippingError::SaleNotFound(sale_id))?;
+
+        if let Some(user) = customer_user
+            && snapshot.header.customer_user_id != Some(user.into_uuid())
+        {
+            return Err(ShippingError::SaleNotFound(sale_id));
+        }
+
+        let shipment = self.shipment_repo.find_by_sale_id(sale).await?;
+        let events = match &shipment {
+            Some(s) => self.event_repo.find_by_shipment(s.id()).await?,
+            None => Vec::new(),
+        };
+
+        let cancelled = is_cancelled(&snapshot.header);
+        let progress = ShipmentProgress::of(shipment.as_ref());
+        let lines: Vec<OrderLineFulfillmentResponse> = snapshot
+            .lines
+            .into_iter()
+            .map(|line| line_fulfillment(line, progress, cancelled))
+            .collect();
+        let fulfillment_status = if cancelled {
+            OrderFulfillmentState::Cancelled
+        } else {
+            order_state(&lines)
+        };
+        let backordered_lines = lines
+            .iter()
+            .filter(|l| l.status == LineFulfillmentStatus::Backordered)
+            .count();
+
+        let payment_status = payment_status(&snapshot.header, &snapshot.payments);
+
+        let header = snapshot.header;
+        Ok(OrderFulfillmentResponse {
+            sale_id: header.sale_id,
+            sale_number: header.sale_number,
+            store_id: header.store_id,
+            order_status: header.order_status,
+            currency: header.currency,
+            total: header.total,
+            amount_paid: header.amount_paid,
+            amount_due: header.amount_due,
+            payment_status,
+            fulfillment_status,
+            backordered_lines,
+            lines,
+            payments: snapshot
+                .payments
+                .into_iter()
+                .map(|p| OrderPaymentResponse {
+                    id: p.id,
+                    payment_method: p.payment_method,
+                    status: p.status,
+                    amount: p.amount,
+                    refunded_amount: p.refunded_amount,
+                    processed_at: p.processed_at,
+                })
+                .collect(),
+            shipment: shipment.map(|s| OrderShipmentResponse {
+                id: s.id().into_uuid(),
+                status: s.status().to_string(),
+                method_type: s.method_type().to_string(),
+                tracking_number: s.tracking_number().map(str::to_string),
+                carrier_name: s.carrier_name().map(str::to_string),
+                estimated_delivery: s.estimated_delivery(),
+                shipped_at: s.shipped_at(),
+                picked_up_at: s.picked_up_at(),
+                delivered_at: s.delivered_at(),
+                events: events
+                    .into_iter()
+                    .map(TrackingEventResponse::from)
+                    .collect(),
+            }),
+            created_at: header.created_at,
+        })
+    }
+}
+
+fn is_cancelled(header: &OrderHeaderSnapshot) -> bool {
+    header.status == "voided" || header.order_status.as_deref() == Some("cancelled")
+}
+
+fn payment_status(
+    header: &OrderHeaderSnapshot,
+    payments: &[OrderPaymentSnapshot],
+) -> OrderPaymentStatus {
+    let refunded: Decimal = payments.iter().map(|p| p.refunded_amount).sum();
+    if refunded > Decimal::ZERO {
+        if refunded >= header.amount_paid {
+            OrderPaymentStatus::Refunded
+        } else {
+            OrderPaymentStatus::PartiallyRefunded
+        }
+    } else if header.amount_paid > Decimal::ZERO && header.amount_paid >= header.total {
+        OrderPaymentStatus::Paid
+    } else if header.amount_paid > Decimal::ZERO {
+        OrderPaymentStatus::PartiallyPaid
+    } else {
+        OrderPaymentStatus::Unpaid
+    }
+}
+
+fn line_fulfillment(
+    line: OrderLineSnapshot,
+    progress: ShipmentProgress,
+    cancelled: bool,
+) -> OrderLineFulfillmentResponse {
+    let reserved = matches!(
+        line.reservation_status.as_deref(),
+        Some("pending") | Some("confirmed")
+    );
+    let backordered = !reserved && line.available_quantity < line.quantity;
+
+    let status = if cancelled {
+        LineFulfillmentStatus::Cancelled
+    } else if backordered {
+        LineFulfillmentStatus::Backordered
+    } else {
+        match progress {
+            ShipmentProgress::Delivered => LineFulfillmentStatus::Delivered,
+            ShipmentProgress::Shipped => LineFulfillmentStatus::Shipped,
+            ShipmentProgress::NotShipped if reserved => LineFulfillmentStatus::Allocated,
+            ShipmentProgress::NotShipped => LineFulfillmentStatus::Pending,
+        }
+    };
+    let shipped_quantity = match status {
+        LineFulfillmentStatus::Shipped | LineFulfillmentStatus::Delivered => line.quantity,
+        _ => Decimal::ZERO,
+    };
+    let remaining_quantity = if cancelled {
+        Decimal::ZERO
+    } else {
+        line.quantity - shipped_quantity
+    };
+
+    OrderLineFulfillmentResponse {
+        sale_item_id: line.sale_item_id,
+        line_number: line.line_number,
+        product_id: line.product_id,
+        variant_id: line.variant_id,
+        sku: line.sku,
+        description: line.description,
+        allocation_store_id: line.allocation_store_id,
+        reservation_id: line.reservation_id,
+        reservation_status: line.reservation_status,
+        reservation_expires_at: line.reservation_expires_at,
+        status,
+        ordered_quantity: line.quantity,
+        shipped_quantity,
+        remaining_quantity,
+    }
+}
+
+fn order_state(lines: &[OrderLineFulfillmentResponse]) -> OrderFulfillmentState {
+    let shipped = |l: &&OrderLineFulfillmentResponse| {
+        matches!(
+            l.status,
+            LineFulfillmentStatus::Shipped | LineFulfillmentStatus::Delivered
+        )
+    };
+    let shipped_count = lines.iter().filter(shipped).count();
+
+    if shipped_count == 0 {
+        OrderFulfillmentState::Unfulfilled
+    } else if shipped_count < lines.len() {
+        OrderFulfillmentState::PartiallyShipped
+    } else if lines
+        .iter()
+        .all(|l| l.status == LineFulfillmentStatus::Delivered)
+    {
+        OrderFulfillmentState::Delivered
+    } else {
+        OrderFulfillmentState::Shipped
+    }
+}
+
+// =============================================================================
+// Tests
+// =============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Utc;
+    use rust_decimal_macros::dec;
+
+    fn header(total: Decimal, paid: Decimal) -> OrderHeaderSnapshot {
+        OrderHeaderSnapshot {
+            sale_id: Uuid::nil(),
+            sale_number: "WEB-0001".to_string(),
+            store_id: Uuid::nil(),
+            sale_type: "online".to_string(),
+            status: "completed".to_string(),
+            order_status: Some("paid".to_string()),
+            currency: "HNL".to_string(),
+            total,
+            amount_paid: paid,
+            amount_due: total - paid,
+            customer_user_id: None,
+            created_at: Utc::now(),
+        }
+    }
+
+    fn line(quantity: Decimal, reservation: Option<&str>, available: Decimal) -> OrderLineSnapshot {
+        OrderLineSnapshot {
+            sale_item_id: Uuid::nil(),
+            line_number: 1,
+            product_id: Uuid::nil(),
+            variant_id: None,
+            sku: "SKU-1".to_string(),
+            description: "Item".to_string(),
+            quantity,
+            reservation_id: reservation.map(|_| Uuid::nil()),
+            reservation_status: reservation.map(str::to_string),
+            reservation_expires_at: None,
+            allocation_store_id: Uuid::nil(),
+            available_quantity: available,
+        }
+    }
+
+    fn payment(amount: Decimal, refunded: Decimal) -> OrderPaymentSnapshot {
+        OrderPaymentSnapshot {
+            id: Uuid::nil(),
+            payment_method: "credit_card".to_string(),
+            status: "completed".to_string(),
+            amount,
+            refunded_amount: refunded,
+            processed_at: Utc::now(),
+        }
+    }
+
+    #[test]
+    fn unshipped_lines_are_allocated_pending_or_backordered() {
+        let allocated = line_fulfillment(
+            line(dec!(2), Some("pending"), dec!(0)),
+            ShipmentProgress::NotShipped,
+            false,
+        );
+        assert_eq!(allocated.status, LineFulfillmentStatus::Allocated);
+        assert_eq!(allocated.remaining_quantity, dec!(2));
+
+        let pending = line_fulfillment(
+            line(dec!(2), None, dec!(5)),
+            ShipmentProgress::NotShipped,
+            false,
+        );
+        assert_eq!(pending.status, LineFulfillmentStatus::Pending);
+
+        // An expired reservation no longer holds stock.
+        let backordered = line_fulfillment(
+            line(dec!(2), Some("expired"), dec!(1)),
+            ShipmentProgress::NotShipped,
+            false,
+        );
+        assert_eq!(backordered.status, LineFulfillmentStatus::Backordered);
+    }
+
+    #[test]
+    fn backordered_line_is_still_owed_after_shipment_leaves() {
+        let lines = vec![
+            line_fulfillment(
+                line(dec!(1), Some("confirmed"), dec!(0)),
+                ShipmentProgress::Shipped,
+                false,
+            ),
+            line_fulfillment(
+                line(dec!(3), None, dec!(1)),
+                ShipmentProgress::Shipped,
+                false,
+            ),
+        ];
+
+        assert_eq!(lines[0].status, LineFulfillmentStatus::Shipped);
+        assert_eq!(lines[0].remaining_quantity, dec!(0));
+        assert_eq!(lines[1].status, LineFulfillmentStatus::Backordered);
+        assert_eq!(lines[1].shipped_quantity, dec!(0));
+        assert_eq!(lines[1].remaining_quantity, dec!(3));
+        assert_eq!(order_state(&lines), OrderFulfillmentState::PartiallyShipped);
+    }
+
+    #[test]
+    fn order_state_rolls_up_lines() {
+        let delivered = vec![line_fulfillment(
+            line(dec!(1), Some("confirmed"), dec!(0)),
+            ShipmentProgress::Delivered,
+            false,
+        )];
+        assert_eq!(order_state(&delivered), OrderFulfillmentState::Delivered);
+
+        let shipped = vec![line_fulfillment(
+            line(dec!(1), Some("confirmed"), dec!(0)),
+            ShipmentProgress::Shipped,
+            false,
+        )];
+        assert_eq!(order_state(&shipped), OrderFulfillmentState::Shipped);
+
+        assert_eq!(order_state(&[]), OrderFulfillmentState::Unfulfilled);
+    }
+
+    #[test]
+    fn cancelled_lines_owe_nothing() {
+        let l = line_fulfillment(
+            line(dec!(2), None, dec!(0)),
+            ShipmentProgress::NotShipped,
+            true,
+        );
+        assert_eq!(l.status, LineFulfillmentStatus::Cancelled);
+        assert_eq!(l.remaining_quantity, dec!(0));
+    }
+
+    #[test]
+    fn payment_status_reflects_totals_and_refunds() {
+        assert_eq!(
+            payment_status(&header(dec!(100), dec!(0)), &[]),
+            OrderPaymentStatus::Unpaid
+        );
+        assert_eq!(
+            payment_status(&header(dec!(100), dec!(40)), &[payment(dec!(40), dec!(0))]),
+            OrderPaymentStatus::PartiallyPaid
+        );
+        assert_eq!(
+            payment_status(
+                &header(dec!(100), dec!(100)),
+                &[payment(dec!(100), dec!(0))]
+            ),
+            OrderPaymentStatus::Paid
+        );
+        assert_eq!(
+            payment_status(
+                &header(dec!(100), dec!(100)),
+                &[payment(dec!(100), dec!(30))]
+            ),
+            OrderPaymentStatus::PartiallyRefunded
+        );
+        assert_eq!(
+            payment_status(
+                &header(dec!(100), dec!(100)),
+                &[payment(dec!(100), dec!(100))]
+            ),
+            OrderPaymentStatus::Refunded
+        );
+    }
+}
@@ -0,0 +1,218 @@
+//! Warehouse pick queue — the operational side of order allocation.
+//!
+//! Lists the paid online orders a store still has to pick, with the lines,
+//! their allocation stores and bins, and lets pickers claim orders. Claims
+//! are guarded by the `version` the picker read from the queue, so when two
+//! pickers grab the same order only the first one wins.
+
+use std::sync::Arc;
+
+use crate::ShippingError;
+use crate::application::dtos::{
+    OrderPickCommand, OrderPickResponse, PickQueueOrderResponse, PickQueueQuery, PickQueueResponse,
+};
+use crate::domain::entities::OrderPick;
+use crate::domain::repositories::{
+    OrderFulfillmentRepository, OrderHeaderSnapshot, OrderPickRepository,
+};
+use identity::{StoreId, UserId};
+use sales::SaleId;
+
+// =============================================================================
+// ListOrdersAwaitingFulfillmentUseCase
+// =============================================================================
+
+pub struct ListOrdersAwaitingFulfillmentUseCase {
+    fulfillment_repo: Arc<dyn OrderFulfillmentRepository>,
+}
+
+impl ListOrdersAwaitingFulfillmentUseCase {
+    pub fn new(fulfillment_repo: Arc<dyn OrderFulfillmentRepository>) -> Self {
+        Self { fulfillment_repo }
+    }
+
+    pub async fn execute(&self, query: PickQueueQuery) -> Result<PickQueueResponse, ShippingError> {
+        let page = query.page.unwrap_or(1).max(1);
+        let page_size = query.page_size.unwrap_or(50).clamp(1, 200);
+        let (orders, total) = self
+            .fulfillment_repo
+            .find_pick_queue(StoreId::from_uuid(query.store_id), page, page_size)
+            .await?;
+        Ok(PickQueueResponse {
+            items: orders
+                .into_iter()
+                .map(PickQueueOrderResponse::from)
+                .collect(),
+            total,
+            page,
+            page_size,
+        })
+    }
+}
+
+// =============================================================================
+// Claim / release / hold / resume
+// =============================================================================
+
+/// Dependencies shared by the use cases that change an order's pick state.
+pub struct OrderPickDeps {
+    pub fulfillment_repo: Arc<dyn OrderFulfillmentRepository>,
+    pub pick_repo: Arc<dyn OrderPickRepository>,
+}
+
+impl OrderPickDeps {
+    /// Loads the pick state of an order still in the queue and checks it is
+    /// the version the caller read.
+    async fn load(&self, cmd: &OrderPickCommand) -> Result<OrderPick, ShippingError> {
+        let sale_id = SaleId::from_uuid(cmd.sale_id);
+        let snapshot = self
+            .fulfillment_repo
+            .find_snapshot(sale_id)
+            .await?
+            .ok_or(ShippingError::SaleNotFound(cmd.sale_id))?;
+        if !is_awaiting_fulfillment(&snapshot.header) {
+            return Err(ShippingError::OrderNotAwaitingFulfillment(cmd.sale_id));
+        }
+
+        let pick = self
+            .pick_repo
+            .find_by_sale(sale_id)
+            .await?
+            .unwrap_or_else(|| {
+                OrderPick::new(sale_id, StoreId::from_uuid(snapshot.header.store_id))
+            });
+        if pick.version() != cmd.expected_version {
+            return Err(ShippingError::PickVersionConflict(cmd.sale_id));
+        }
+        Ok(pick)
+    }
+
+    async fn persist(&self, mut pick: OrderPick) -> Result<OrderPickResponse, ShippingError> {
+        self.pick_repo.save(&pick).await?;
+        pick.increment_version();
+        Ok(OrderPickResponse::from(pick))
+    }
+}
+
+pub struct ClaimOrderPickUseCase {
+    deps: Arc<OrderPickDeps>,
+}
+
+impl ClaimOrderPickUseCase {
+    pub fn new(deps: Arc<OrderPickDeps>) -> Self {
+        Self { deps }
+    }
+
+    pub async fn execute(
+        &self,
+        cmd: OrderPickCommand,
+        picker_id: UserId,
+    ) -> Result<OrderPickResponse, ShippingError> {
+        let mut pick = self.deps.load(&cmd).await?;
+        pick.claim(picker_id)?;
+        self.deps.persist(pick).await
+    }
+}
+
+pub struct ReleaseOrderPickUseCase {
+    deps: Arc<OrderPickDeps>,
+}
+
+impl ReleaseOrderPickUseCase {
+    pub fn new(deps: Arc<OrderPickDeps>) -> Self {
+        Self { deps }
+    }
+
+    pub async fn execute(&self, cmd: OrderPickCommand) -> Result<OrderPickResponse, ShippingError> {
+        let mut pick = self.deps.load(&cmd).await?;
+        pick.release();
+        self.deps.persist(pick).await
+    }
+}
+
+pub struct HoldOrderPickUseCase {
+    deps: Arc<OrderPickDeps>,
+}
+
+impl HoldOrderPickUseCase {
+    pub fn new(deps: Arc<OrderPickDeps>) -> Self {
+        Self { deps }
+    }
+
+    pub async fn execute(&self, cmd: OrderPickCommand) -> Result<OrderPickResponse, ShippingError> {
+        let mut pick = self.deps.load(&cmd).await?;
+        pick.hold(cmd.reason);
+        self.deps.persist(pick).await
+    }
+}
+
+pub struct ResumeOrderPickUseCase {
+    deps: Arc<OrderPickDeps>,
+}
+
+impl ResumeOrderPickUseCase {
+    pub fn new(deps: Arc<OrderPickDeps>) -> Self {
+        Self { deps }
+    }
+
+    pub async fn execute(&self, cmd: OrderPickCommand) -> Result<OrderPickResponse, ShippingError> {
+        let mut pick = self.deps.load(&cmd).await?;
+        pick.resume();
+        self.deps.persist(pick).await
+    }
+}
+
+/// Paid online orders that have not left the store yet.
+fn is_awaiting_fulfillment(header: &OrderHeaderSnapshot) -> bool {
+    header.sale_type == "online"
+        && header.status != "voided"
+        && matches!(
+            header.order_status.as_deref(),
+            Some("paid") | Some("processing")
+        )
+}
+
+// =============================================================================
+// Tests
+// =============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Utc;
+    use rust_decimal::Decimal;
+    use uuid::Uuid;
+
+    fn header(sale_type: &str, order_status: Option<&str>) -> OrderHeaderSnapshot {
+        OrderHeaderSnapshot {
+            sale_id: Uuid::nil(),
+            sale_number: "WEB-0001".to_string(),
+            store_id: Uuid::nil(),
+            sale_type: sale_type.to_string(),
+            status: "completed".to_string(),
+            order_status: order_status.map(str::to_string),
+            currency: "HNL".to_string(),
+            total: Decimal::ZERO,
+            amount_paid: Decimal::ZERO,
+            amount_due: Decimal::ZERO,
+            customer_user_id: None,
+            created_at: Utc::now(),
+        }
+    }
+
+    #[test]
+    fn only_paid_unshipped_online_orders_await_fulfillment() {
+        assert!(is_awaiting_fulfillment(&header("online", Some("paid"))));
+        assert!(is_awaiting_fulfillment(&header(
+            "online",
+            Some("processing")
+        )));
+
+        assert!(!is_awaiting_fulfillment(&header(
+            "online",
+            Some("pending_payment")
+        )));
+        assert!(!is_awaiting_fulfillment(&header("online", Some("shipped"))));
+        assert!(!is_awaiting_fulfillment(&header("pos", None)));
+    }
+}
@@ -1,5 +1,6 @@
 mod delivery_provider;
 mod driver;
+mod order_pick;
 mod shipment;
 mod shipment_tracking_event;
 mod shipping_method;
@@ -8,6 +9,7 @@ mod shipping_zone;
 
 pub use delivery_provider::DeliveryProvider;
 pub use driver::Driver;
+pub use order_pick::OrderPick;
 pub use shipment::Shipment;
 pub use shipment_tracking_event::ShipmentTrackingEvent;
 pub use shipping_method::ShippingMethod;
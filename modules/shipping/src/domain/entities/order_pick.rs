@@ -0,0 +1,178 @@
+//! OrderPick - a warehouse picker's claim on a paid order, plus its hold flag.
+//!
+//! Orders enter the pick queue without a row; the row is written the first
+//! time an order is claimed or put on hold. `version` is the optimistic lock:
+//! it starts at 0 for an order nobody touched yet and is bumped on every
+//! persist, so two pickers claiming the same version cannot both win.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::ShippingError;
+use identity::{StoreId, UserId};
+use sales::SaleId;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OrderPick {
+    sale_id: SaleId,
+    store_id: StoreId,
+    picker_id: Option<UserId>,
+    claimed_at: Option<DateTime<Utc>>,
+    on_hold: bool,
+    hold_reason: Option<String>,
+    version: i32,
+    updated_at: DateTime<Utc>,
+}
+
+impl OrderPick {
+    /// Pick state of an order that has never been claimed or held
+    pub fn new(sale_id: SaleId, store_id: StoreId) -> Self {
+        Self {
+            sale_id,
+            store_id,
+            picker_id: None,
+            claimed_at: None,
+            on_hold: false,
+            hold_reason: None,
+            version: 0,
+            updated_at: Utc::now(),
+        }
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn reconstitute(
+        sale_id: SaleId,
+        store_id: StoreId,
+        picker_id: Option<UserId>,
+        claimed_at: Option<DateTime<Utc>>,
+        on_hold: bool,
+        hold_reason: Option<String>,
+        version: i32,
+        updated_at: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            sale_id,
+            store_id,
+            picker_id,
+            claimed_at,
+            on_hold,
+            hold_reason,
+            version,
+            updated_at,
+        }
+    }
+
+    /// Assigns the order to `picker_id`. Claiming an order the picker already
+    /// holds is a no-op; an order claimed by someone else or on hold is refused.
+    pub fn claim(&mut self, picker_id: UserId) -> Result<(), ShippingError> {
+        if self.on_hold {
+            return Err(ShippingError::OrderOnHold(self.sale_id.into_uuid()));
+        }
+        match self.picker_id {
+            Some(current) if current == picker_id => Ok(()),
+            Some(_) => Err(ShippingError::OrderAlreadyClaimed(self.sale_id.into_uuid())),
+            None => {
+                let now = Utc::now();
+                self.picker_id = Some(picker_id);
+                self.claimed_at = Some(now);
+                self.updated_at = now;
+                Ok(())
+            }
+        }
+    }
+
+    /// Puts the order back in the queue for any picker
+    pub fn release(&mut self) {
+        self.picker_id = None;
+        self.claimed_at = None;
+        self.updated_at = Utc::now();
+    }
+
+    /// Takes the order out of the queue; any claim is dropped
+    pub fn hold(&mut self, reason: Option<String>) {
+        self.release();
+        self.on_hold = true;
+        self.hold_reason = reason;
+    }
+
+    /// Returns a held order to the queue
+    pub fn resume(&mut self) {
+        self.on_hold = false;
+        self.hold_reason = None;
+        self.updated_at = Utc::now();
+    }
+
+    pub fn increment_version(&mut self) {
+        self.version += 1;
+    }
+
+    pub fn sale_id(&self) -> SaleId {
+        self.sale_id
+    }
+    pub fn store_id(&self) -> StoreId {
+        self.store_id
+    }
+    pub fn picker_id(&self) -> Option<UserId> {
+        self.picker_id
+    }
+    pub fn claimed_at(&self) -> Option<DateTime<Utc>> {
+        self.claimed_at
+    }
+    pub fn on_hold(&self) -> bool {
+        self.on_hold
+    }
+    pub fn hold_reason(&self) -> Option<&str> {
+        self.hold_reason.as_deref()
+    }
+    pub fn version(&self) -> i32 {
+        self.version
+    }
+    pub fn updated_at(&self) -> DateTime<Utc> {
+        self.updated_at
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pick() -> OrderPick {
+        OrderPick::new(SaleId::new(), StoreId::new())
+    }
+
+    #[test]
+    fn claim_is_exclusive_but_idempotent() {
+        let mut p = pick();
+        let picker = UserId::new();
+
+        p.claim(picker).unwrap();
+        assert_eq!(p.picker_id(), Some(picker));
+        assert!(p.claimed_at().is_some());
+        p.claim(picker).unwrap();
+
+        assert!(matches!(
+            p.claim(UserId::new()),
+            Err(ShippingError::OrderAlreadyClaimed(_))
+        ));
+
+        p.release();
+        p.claim(UserId::new()).unwrap();
+    }
+
+    #[test]
+    fn held_orders_cannot_be_claimed() {
+        let mut p = pick();
+        p.claim(UserId::new()).unwrap();
+
+        p.hold(Some("address check".to_string()));
+        assert_eq!(p.picker_id(), None);
+        assert!(matches!(
+            p.claim(UserId::new()),
+            Err(ShippingError::OrderOnHold(_))
+        ));
+
+        p.resume();
+        assert_eq!(p.hold_reason(), None);
+        p.claim(UserId::new()).unwrap();
+    }
+}
@@ -1,5 +1,7 @@
 mod delivery_provider_repository;
 mod driver_repository;
+mod order_fulfillment_repository;
+mod order_pick_repository;
 mod shipment_repository;
 mod shipment_tracking_event_repository;
 mod shipping_method_repository;
@@ -8,6 +10,11 @@ mod shipping_zone_repository;
 
 pub use delivery_provider_repository::DeliveryProviderRepository;
 pub use driver_repository::DriverRepository;
+pub use order_fulfillment_repository::{
+    OrderFulfillmentRepository, OrderFulfillmentSnapshot, OrderHeaderSnapshot, OrderLineSnapshot,
+    OrderPaymentSnapshot, PickLineSnapshot, PickQueueOrderSnapshot,
+};
+pub use order_pick_repository::OrderPickRepository;
 pub use shipment_repository::{ShipmentFilter, ShipmentRepository};
 pub use shipment_tracking_event_repository::ShipmentTrackingEventRepository;
 pub use shipping_method_repository::ShippingMethodRepository;
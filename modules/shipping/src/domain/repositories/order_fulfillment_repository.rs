@@ -0,0 +1,123 @@
+//! Read-side snapshot of an order used to assemble its fulfillment status.
+//!
+//! The snapshot joins data owned by other modules (sales, payments,
+//! inventory reservations and stock) and is only ever read, never saved.
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use uuid::Uuid;
+
+use crate::ShippingError;
+use identity::StoreId;
+use sales::SaleId;
+
+/// Order header: sale totals plus the user linked to the order's customer.
+#[derive(Debug, Clone)]
+pub struct OrderHeaderSnapshot {
+    pub sale_id: Uuid,
+    pub sale_number: String,
+    pub store_id: Uuid,
+    pub sale_type: String,
+    pub status: String,
+    pub order_status: Option<String>,
+    pub currency: String,
+    pub total: Decimal,
+    pub amount_paid: Decimal,
+    pub amount_due: Decimal,
+    /// `customers.user_id` of the order's customer, when it has an account.
+    pub customer_user_id: Option<Uuid>,
+    pub created_at: DateTime<Utc>,
+}
+
+/// One payment recorded against the order.
+#[derive(Debug, Clone)]
+pub struct OrderPaymentSnapshot {
+    pub id: Uuid,
+    pub payment_method: String,
+    pub status: String,
+    pub amount: Decimal,
+    pub refunded_amount: Decimal,
+    pub processed_at: DateTime<Utc>,
+}
+
+/// One order line with its reservation and the stock at its allocation store.
+#[derive(Debug, Clone)]
+pub struct OrderLineSnapshot {
+    pub sale_item_id: Uuid,
+    pub line_number: i32,
+    pub product_id: Uuid,
+    pub variant_id: Option<Uuid>,
+    pub sku: String,
+    pub description: String,
+    pub quantity: Decimal,
+    pub reservation_id: Option<Uuid>,
+    pub reservation_status: Option<String>,
+    pub reservation_expires_at: Option<DateTime<Utc>>,
+    /// Store of the reserved stock row, or the order's store when unreserved.
+    pub allocation_store_id: Uuid,
+    /// `quantity - reserved_quantity` at the allocation store (0 if no stock row).
+    pub available_quantity: Decimal,
+}
+
+#[derive(Debug, Clone)]
+pub struct OrderFulfillmentSnapshot {
+    pub header: OrderHeaderSnapshot,
+    pub payments: Vec<OrderPaymentSnapshot>,
+    pub lines: Vec<OrderLineSnapshot>,
+}
+
+/// A paid order waiting in a store's pick queue.
+#[derive(Debug, Clone)]
+pub struct PickQueueOrderSnapshot {
+    pub sale_id: Uuid,
+    pub sale_number: String,
+    pub store_id: Uuid,
+    pub order_status: String,
+    /// Time of the order's last completed payment.
+    pub paid_at: DateTime<Utc>,
+    /// Method of the order's shipment, when one was created at checkout.
+    pub shipping_method_name: Option<String>,
+    pub shipping_method_type: Option<String>,
+    pub picker_id: Option<Uuid>,
+    pub claimed_at: Option<DateTime<Utc>>,
+    /// `order_picks.version`, or 0 when the order was never claimed.
+    pub version: i32,
+    pub lines: Vec<PickLineSnapshot>,
+}
+
+/// One line to pick, with the store its stock is allocated from.
+#[derive(Debug, Clone)]
+pub struct PickLineSnapshot {
+    pub sale_item_id: Uuid,
+    pub line_number: i32,
+    pub product_id: Uuid,
+    pub variant_id: Option<Uuid>,
+    pub sku: String,
+    pub description: String,
+    pub quantity: Decimal,
+    pub allocation_store_id: Uuid,
+    /// Bin of the stock row at the allocation store, when one is recorded.
+    pub bin_location: Option<String>,
+}
+
+#[async_trait]
+pub trait OrderFulfillmentRepository: Send + Sync {
+    /// Loads the order header, payments and lines, or `None` if the sale
+    /// does not exist.
+    async fn find_snapshot(
+        &self,
+        sale_id: SaleId,
+    ) -> Result<Option<OrderFulfillmentSnapshot>, ShippingError>;
+
+    /// Pages through the paid online orders of `store_id` that still have to
+    /// be picked, skipping orders on hold, oldest payment first and faster
+    /// shipping methods first among orders paid at the same time. Returns
+    /// the page and the total number of queued orders.
+    async fn find_pick_queue(
+        &self,
+        store_id: StoreId,
+        page: i64,
+        page_size: i64,
+    ) -> Result<(Vec<PickQueueOrderSnapshot>, i64), ShippingError>;
+}
@@ -0,0 +1,15 @@
+use async_trait::async_trait;
+
+use crate::ShippingError;
+use crate::domain::entities::OrderPick;
+use sales::SaleId;
+
+#[async_trait]
+pub trait OrderPickRepository: Send + Sync {
+    async fn find_by_sale(&self, sale_id: SaleId) -> Result<Option<OrderPick>, ShippingError>;
+
+    /// Persists the pick if its version still matches the stored one (a
+    /// missing row counts as version 0) and bumps the stored version. Fails
+    /// with `PickVersionConflict` when another writer got there first.
+    async fn save(&self, pick: &OrderPick) -> Result<(), ShippingError>;
+}
@@ -86,6 +86,21 @@ pub enum ShippingError {
     #[error("Pickup window has expired")]
     PickupExpired,
 
+    // -------------------------------------------------------------------------
+    // Pick queue
+    // -------------------------------------------------------------------------
+    #[error("Order is not awaiting fulfillment: {0}")]
+    OrderNotAwaitingFulfillment(Uuid),
+
+    #[error("Order already claimed by another picker: {0}")]
+    OrderAlreadyClaimed(Uuid),
+
+    #[error("Order is on hold: {0}")]
+    OrderOnHold(Uuid),
+
+    #[error("Order pick was changed by someone else: {0}")]
+    PickVersionConflict(Uuid),
+
     // -------------------------------------------------------------------------
     // External provider errors
     // -------------------------------------------------------------------------
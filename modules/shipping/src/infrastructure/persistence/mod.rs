@@ -2,6 +2,8 @@
 
 mod pg_delivery_provider_repository;
 mod pg_driver_repository;
+mod pg_order_fulfillment_repository;
+mod pg_order_pick_repository;
 mod pg_shipment_repository;
 mod pg_shipment_tracking_event_repository;
 mod pg_shipping_method_repository;
@@ -10,6 +12,8 @@ mod pg_shipping_zone_repository;
 
 pub use pg_delivery_provider_repository::PgDeliveryProviderRepository;
 pub use pg_driver_repository::PgDriverRepository;
+pub use pg_order_fulfillment_repository::PgOrderFulfillmentRepository;
+pub use pg_order_pick_repository::PgOrderPickRepository;
 pub use pg_shipment_repository::PgShipmentRepository;
 pub use pg_shipment_tracking_event_repository::PgShipmentTrackingEventRepository;
 pub use pg_shipping_method_repository::PgShippingMethodRepository;
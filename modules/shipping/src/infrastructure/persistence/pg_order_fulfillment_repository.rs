@@ -0,0 +1,332 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::ShippingError;
+use crate::domain::repositories::{
+    OrderFulfillmentRepository, OrderFulfillmentSnapshot, OrderHeaderSnapshot, OrderLineSnapshot,
+    OrderPaymentSnapshot, PickLineSnapshot, PickQueueOrderSnapshot,
+};
+use identity::StoreId;
+use sales::SaleId;
+
+pub struct PgOrderFulfillmentRepository {
+    pool: PgPool,
+}
+
+impl PgOrderFulfillmentRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+/// Paid online orders of store `$1` that have not left the store and are
+/// not on hold. The paid time is the last completed payment, falling back to
+/// the sale's last update for orders marked paid without a payment row.
+const PICK_QUEUE_FROM: &str = r#"
+    FROM sales s
+    LEFT JOIN shipments sh ON sh.sale_id = s.id
+    LEFT JOIN shipping_methods m ON m.id = sh.shipping_method_id
+    LEFT JOIN order_picks op ON op.sale_id = s.id
+    LEFT JOIN LATERAL (
+        SELECT MAX(py.processed_at) AS paid_at
+        FROM payments py
+        WHERE py.sale_id = s.id AND py.status = 'completed'
+    ) pay ON true
+    WHERE s.store_id = $1
+      AND s.sale_type = 'online'
+      AND s.status <> 'voided'
+      AND s.order_status IN ('paid', 'processing')
+      AND sh.shipped_at IS NULL
+      AND COALESCE(op.on_hold, false) = false"#;
+
+#[async_trait]
+impl OrderFulfillmentRepository for PgOrderFulfillmentRepository {
+    async fn find_snapshot(
+        &self,
+        sale_id: SaleId,
+    ) -> Result<Option<OrderFulfillmentSnapshot>, ShippingError> {
+        let header = sqlx::query_as::<_, HeaderRow>(
+            r#"SELECT s.id, s.sale_number, s.store_id, s.sale_type, s.status, s.order_status,
+                      s.currency, s.total, s.amount_paid, s.amount_due,
+                      c.user_id AS customer_user_id, s.created_at
+               FROM sales s
+               LEFT JOIN customers c ON c.id = s.customer_id
+               WHERE s.id = $1"#,
+        )
+        .bind(sale_id.into_uuid())
+        .fetch_optional(&self.pool)
+        .await?;
+
+        let Some(header) = header else {
+            return Ok(None);
+        };
+
+        let payments = sqlx::query_as::<_, PaymentRow>(
+            r#"SELECT id, payment_method, status, amount, refunded_amount, processed_at
+               FROM payments
+               WHERE sale_id = $1
+               ORDER BY processed_at"#,
+        )
+        .bind(sale_id.into_uuid())
+        .fetch_all(&self.pool)
+        .await?;
+
+        // The allocation store is the store of the reserved stock row; lines
+        // without a reservation fall back to the order's store. Availability
+        // is read from the matching product/variant stock row at that store.
+        let lines = sqlx::query_as::<_, LineRow>(
+            r#"SELECT si.id, si.line_number, si.product_id, si.variant_id, si.sku,
+                      si.description, si.quantity,
+                      r.id AS reservation_id, r.status AS reservation_status,
+                      r.expires_at AS reservation_expires_at,
+                      COALESCE(rs.store_id, s.store_id) AS allocation_store_id,
+                      COALESCE(st.quantity - st.reserved_quantity, 0) AS available_quantity
+               FROM sale_items si
+               JOIN sales s ON s.id = si.sale_id
+               LEFT JOIN inventory_reservations r ON r.id = si.reservation_id
+               LEFT JOIN inventory_stock rs ON rs.id = r.stock_id
+               LEFT JOIN inventory_stock st
+                      ON st.store_id = COALESCE(rs.store_id, s.store_id)
+                     AND ((si.variant_id IS NOT NULL AND st.variant_id = si.variant_id)
+                          OR (si.variant_id IS NULL AND st.product_id = si.product_id))
+               WHERE si.sale_id = $1
+               ORDER BY si.line_number"#,
+        )
+        .bind(sale_id.into_uuid())
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(Some(OrderFulfillmentSnapshot {
+            header: header.into(),
+            payments: payments.into_iter().map(Into::into).collect(),
+            lines: lines.into_iter().map(Into::into).collect(),
+        }))
+    }
+
+    async fn find_pick_queue(
+        &self,
+        store_id: StoreId,
+        page: i64,
+        page_size: i64,
+    ) -> Result<(Vec<PickQueueOrderSnapshot>, i64), ShippingError> {
+        let offset = (page - 1) * page_size;
+
+        let orders = sqlx::query_as::<_, PickQueueRow>(&format!(
+            r#"SELECT s.id, s.sale_number, s.store_id, s.order_status,
+                      COALESCE(pay.paid_at, s.updated_at) AS paid_at,
+                      m.name AS shipping_method_name,
+                      m.method_type AS shipping_method_type,
+                      op.picker_id, op.claimed_at,
+                      COALESCE(op.version, 0) AS version
+               {PICK_QUEUE_FROM}
+               ORDER BY COALESCE(pay.paid_at, s.updated_at),
+                        CASE m.method_type
+                            WHEN 'same_day' THEN 0
+                            WHEN 'express' THEN 1
+                            ELSE 2
+                        END,
+                        s.sale_number
+               LIMIT $2 OFFSET $3"#
+        ))
+        .bind(store_id.into_uuid())
+        .bind(page_size)
+        .bind(offset)
+        .fetch_all(&self.pool)
+        .await?;
+
+        let total: (i64,) = sqlx::query_as(&format!("SELECT COUNT(*) {PICK_QUEUE_FROM}"))
+            .bind(store_id.into_uuid())
+            .fetch_one(&self.pool)
+            .await?;
+
+        // Same allocation store as `find_snapshot`; the bin comes from the
+        // stock row the line is picked from.
+        let sale_ids: Vec<Uuid> = orders.iter().map(|o| o.id).collect();
+        let lines = sqlx::query_as::<_, PickLineRow>(
+            r#"SELECT si.sale_id, si.id, si.line_number, si.product_id, si.variant_id,
+                      si.sku, si.description, si.quantity,
+                      COALESCE(rs.store_id, s.store_id) AS allocation_store_id,
+                      st.bin_location
+               FROM sale_items si
+               JOIN sales s ON s.id = si.sale_id
+               LEFT JOIN inventory_reservations r ON r.id = si.reservation_id
+               LEFT JOIN inventory_stock rs ON rs.id = r.stock_id
+               LEFT JOIN inventory_stock st
+                      ON st.store_id = COALESCE(rs.store_id, s.store_id)
+                     AND ((si.variant_id IS NOT NULL AND st.variant_id = si.variant_id)
+                          OR (si.variant_id IS NULL AND st.product_id = si.product_id))
+               WHERE si.sale_id = ANY($1)
+               ORDER BY si.sale_id, si.line_number"#,
+        )
+        .bind(&sale_ids)
+        .fetch_all(&self.pool)
+        .await?;
+
+        let mut queue: Vec<PickQueueOrderSnapshot> = orders.into_iter().map(Into::into).collect();
+        for line in lines {
+            if let Some(order) = queue.iter_mut().find(|o| o.sale_id == line.sale_id) {
+                order.lines.push(line.into());
+            }
+        }
+
+        Ok((queue, total.0))
+    }
+}
+
+#[derive(sqlx::FromRow)]
+struct HeaderRow {
+    id: Uuid,
+    sale_number: String,
+    store_id: Uuid,
+    sale_type: String,
+    status: String,
+    order_status: Option<String>,
+    currency: String,
+    total: Decimal,
+    amount_paid: Decimal,
+    amount_due: Decimal,
+    customer_user_id: Option<Uuid>,
+    created_at: DateTime<Utc>,
+}
+
+impl From<HeaderRow> for OrderHeaderSnapshot {
+    fn from(r: HeaderRow) -> Self {
+        Self {
+            sale_id: r.id,
+            sale_number: r.sale_number,
+            store_id: r.store_id,
+            sale_type: r.sale_type,
+            status: r.status,
+            order_status: r.order_status,
+            currency: r.currency,
+            total: r.total,
+            amount_paid: r.amount_paid,
+            amount_due: r.amount_due,
+            customer_user_id: r.customer_user_id,
+            created_at: r.created_at,
+        }
+    }
+}
+
+#[derive(sqlx::FromRow)]
+struct PaymentRow {
+    id: Uuid,
+    payment_method: String,
+    status: String,
+    amount: Decimal,
+    refunded_amount: Decimal,
+    processed_at: DateTime<Utc>,
+}
+
+impl From<PaymentRow> for OrderPaymentSnapshot {
+    fn from(r: PaymentRow) -> Self {
+        Self {
+            id: r.id,
+            payment_method: r.payment_method,
+            status: r.status,
+            amount: r.amount,
+            refunded_amount: r.refunded_amount,
+            processed_at: r.processed_at,
+        }
+    }
+}
+
+#[derive(sqlx::FromRow)]
+struct LineRow {
+    id: Uuid,
+    line_number: i32,
+    product_id: Uuid,
+    variant_id: Option<Uuid>,
+    sku: String,
+    description: String,
+    quantity: Decimal,
+    reservation_id: Option<Uuid>,
+    reservation_status: Option<String>,
+    reservation_expires_at: Option<DateTime<Utc>>,
+    allocation_store_id: Uuid,
+    available_quantity: Decimal,
+}
+
+impl From<LineRow> for OrderLineSnapshot {
+    fn from(r: LineRow) -> Self {
+        Self {
+            sale_item_id: r.id,
+            line_number: r.line_number,
+            product_id: r.product_id,
+            variant_id: r.variant_id,
+            sku: r.sku,
+            description: r.description,
+            quantity: r.quantity,
+            reservation_id: r.reservation_id,
+            reservation_status: r.reservation_status,
+            reservation_expires_at: r.reservation_expires_at,
+            allocation_store_id: r.allocation_store_id,
+            available_quantity: r.available_quantity,
+        }
+    }
+}
+
+#[derive(sqlx::FromRow)]
+struct PickQueueRow {
+    id: Uuid,
+    sale_number: String,
+    store_id: Uuid,
+    order_status: String,
+    paid_at: DateTime<Utc>,
+    shipping_method_name: Option<String>,
+    shipping_method_type: Option<String>,
+    picker_id: Option<Uuid>,
+    claimed_at: Option<DateTime<Utc>>,
+    version: i32,
+}
+
+impl From<PickQueueRow> for PickQueueOrderSnapshot {
+    fn from(r: PickQueueRow) -> Self {
+        Self {
+            sale_id: r.id,
+            sale_number: r.sale_number,
+            store_id: r.store_id,
+            order_status: r.order_status,
+            paid_at: r.paid_at,
+            shipping_method_name: r.shipping_method_name,
+            shipping_method_type: r.shipping_method_type,
+            picker_id: r.picker_id,
+            claimed_at: r.claimed_at,
+            version: r.version,
+            lines: Vec::new(),
+        }
+    }
+}
+
+#[derive(sqlx::FromRow)]
+struct PickLineRow {
+    sale_id: Uuid,
+    id: Uuid,
+    line_number: i32,
+    product_id: Uuid,
+    variant_id: Option<Uuid>,
+    sku: String,
+    description: String,
+    quantity: Decimal,
+    allocation_store_id: Uuid,
+    bin_location: Option<String>,
+}
+
+impl From<PickLineRow> for PickLineSnapshot {
+    fn from(r: PickLineRow) -> Self {
+        Self {
+            sale_item_id: r.id,
+            line_number: r.line_number,
+            product_id: r.product_id,
+            variant_id: r.variant_id,
+            sku: r.sku,
+            description: r.description,
+            quantity: r.quantity,
+            allocation_store_id: r.allocation_store_id,
+            bin_location: r.bin_location,
+        }
+    }
+}
@@ -0,0 +1,111 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::ShippingError;
+use crate::domain::entities::OrderPick;
+use crate::domain::repositories::OrderPickRepository;
+use identity::{StoreId, UserId};
+use sales::SaleId;
+
+pub struct PgOrderPickRepository {
+    pool: PgPool,
+}
+
+impl PgOrderPickRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl OrderPickRepository for PgOrderPickRepository {
+    async fn find_by_sale(&self, sale_id: SaleId) -> Result<Option<OrderPick>, ShippingError> {
+        let row = sqlx::query_as::<_, OrderPickRow>(
+            r#"SELECT sale_id, store_id, picker_id, claimed_at, on_hold, hold_reason,
+                      version, updated_at
+               FROM order_picks WHERE sale_id = $1"#,
+        )
+        .bind(sale_id.into_uuid())
+        .fetch_optional(&self.pool)
+        .await?;
+        Ok(row.map(Into::into))
+    }
+
+    async fn save(&self, p: &OrderPick) -> Result<(), ShippingError> {
+        // Optimistic lock: the first writer inserts the row at version 1, a
+        // concurrent insert hits the primary key and matches nothing. Later
+        // writes only match the version they read, and bump it.
+        let result = if p.version() == 0 {
+            sqlx::query(
+                r#"INSERT INTO order_picks
+                  (sale_id, store_id, picker_id, claimed_at, on_hold, hold_reason,
+                   version, updated_at)
+                  VALUES ($1, $2, $3, $4, $5, $6, 1, $7)
+                  ON CONFLICT (sale_id) DO NOTHING"#,
+            )
+            .bind(p.sale_id().into_uuid())
+            .bind(p.store_id().into_uuid())
+            .bind(p.picker_id().map(|u| u.into_uuid()))
+            .bind(p.claimed_at())
+            .bind(p.on_hold())
+            .bind(p.hold_reason())
+            .bind(p.updated_at())
+            .execute(&self.pool)
+            .await?
+        } else {
+            sqlx::query(
+                r#"UPDATE order_picks
+                   SET picker_id = $2,
+                       claimed_at = $3,
+                       on_hold = $4,
+                       hold_reason = $5,
+                       version = version + 1,
+                       updated_at = $6
+                   WHERE sale_id = $1 AND version = $7"#,
+            )
+            .bind(p.sale_id().into_uuid())
+            .bind(p.picker_id().map(|u| u.into_uuid()))
+            .bind(p.claimed_at())
+            .bind(p.on_hold())
+            .bind(p.hold_reason())
+            .bind(p.updated_at())
+            .bind(p.version())
+            .execute(&self.pool)
+            .await?
+        };
+
+        if result.rows_affected() == 0 {
+            return Err(ShippingError::PickVersionConflict(p.sale_id().into_uuid()));
+        }
+        Ok(())
+    }
+}
+
+#[derive(sqlx::FromRow)]
+struct OrderPickRow {
+    sale_id: Uuid,
+    store_id: Uuid,
+    picker_id: Option<Uuid>,
+    claimed_at: Option<DateTime<Utc>>,
+    on_hold: bool,
+    hold_reason: Option<String>,
+    version: i32,
+    updated_at: DateTime<Utc>,
+}
+
+impl From<OrderPickRow> for OrderPick {
+    fn from(r: OrderPickRow) -> Self {
+        OrderPick::reconstitute(
+            SaleId::from_uuid(r.sale_id),
+            StoreId::from_uuid(r.store_id),
+            r.picker_id.map(UserId::from_uuid),
+            r.claimed_at,
+            r.on_hold,
+            r.hold_reason,
+            r.version,
+            r.updated_at,
+        )
+    }
+}
@@ -38,17 +38,19 @@ pub use domain::value_objects::{
 // Domain - Entities
 // -----------------------------------------------------------------------------
 pub use domain::entities::{
-    DeliveryProvider, Driver, Shipment, ShipmentTrackingEvent, ShippingMethod, ShippingRate,
-    ShippingZone,
+    DeliveryProvider, Driver, OrderPick, Shipment, ShipmentTrackingEvent, ShippingMethod,
+    ShippingRate, ShippingZone,
 };
 
 // -----------------------------------------------------------------------------
 // Domain - Repository traits
 // -----------------------------------------------------------------------------
 pub use domain::repositories::{
-    DeliveryProviderRepository, DriverRepository, ShipmentFilter, ShipmentRepository,
-    ShipmentTrackingEventRepository, ShippingMethodRepository, ShippingRateRepository,
-    ShippingZoneRepository,
+    DeliveryProviderRepository, DriverRepository, OrderFulfillmentRepository,
+    OrderFulfillmentSnapshot, OrderHeaderSnapshot, OrderLineSnapshot, OrderPaymentSnapshot,
+    OrderPickRepository, PickLineSnapshot, PickQueueOrderSnapshot, ShipmentFilter,
+    ShipmentRepository, ShipmentTrackingEventRepository, ShippingMethodRepository,
+    ShippingRateRepository, ShippingZoneRepository,
 };
 
 // -----------------------------------------------------------------------------
@@ -67,7 +69,7 @@ pub use infrastructure::adapters::{
 };
 
 pub use infrastructure::persistence::{
-    PgDeliveryProviderRepository, PgDriverRepository, PgShipmentRepository,
-    PgShipmentTrackingEventRepository, PgShippingMethodRepository, PgShippingRateRepository,
-    PgShippingZoneRepository,
+    PgDeliveryProviderRepository, PgDriverRepository, PgOrderFulfillmentRepository,
+    PgOrderPickRepository, PgShipmentRepository, PgShipmentTrackingEventRepository,
+    PgShippingMethodRepository, PgShippingRateRepository, PgShippingZoneRepository,
 };
@@ -28,6 +28,11 @@ pub const PERMISSIONS: &[(&str, &str)] = &[
     ("products:update", "Update product information"),
     ("products:delete", "Delete products"),
     ("products:list", "List all products"),
+    ("products:export", "Export the product catalog"),
+    (
+        "products:reprice",
+        "Approve batch reprices from vendor cost changes",
+    ),
     ("variants:create", "Create product variants"),
     ("variants:read", "View product variants"),
     ("variants:update", "Update product variants"),
@@ -50,6 +55,14 @@ pub const PERMISSIONS: &[(&str, &str)] = &[
         "inventory:approve_adjustments",
         "Approve inventory adjustments",
     ),
+    (
+        "inventory:close_period",
+        "Close inventory periods and record valuation snapshots",
+    ),
+    (
+        "inventory:reopen_period",
+        "Reopen closed inventory periods and post into them",
+    ),
     ("inventory:reserve", "Create inventory reservations"),
     ("reservations:create", "Create inventory reservations"),
     ("reservations:read", "View reservations"),
@@ -70,6 +83,10 @@ pub const PERMISSIONS: &[(&str, &str)] = &[
     ("transfers:ship", "Ship stock transfers"),
     ("transfers:receive", "Receive stock transfers"),
     ("transfers:cancel", "Cancel stock transfers"),
+    (
+        "transfers:approve",
+        "Approve or reject high-value and cross-region transfers",
+    ),
     ("categories:create", "Create product categories"),
     ("categories:read", "View product categories"),
     ("categories:update", "Update product categories"),
@@ -79,8 +96,11 @@ pub const PERMISSIONS: &[(&str, &str)] = &[
     ("sales:create", "Create sales transactions"),
     ("sales:update", "Update sales transactions"),
     ("sales:process_payment", "Process payments for sales"),
+    ("gift_cards:issue", "Issue and sell gift cards"),
+    ("gift_cards:read", "Check gift card balances"),
     ("sales:apply_discount", "Apply discounts to sales"),
     ("sales:read", "View sales transactions"),
+    ("sales:export", "Export sales transactions"),
     ("sales:void", "Void sales transactions"),
     ("sales:list", "List sales transactions"),
     ("sales:complete", "Complete sales transactions"),
@@ -88,11 +108,32 @@ pub const PERMISSIONS: &[(&str, &str)] = &[
     ("sales:manage_cart", "Manage shopping carts"),
     ("sales:manage_credit_note", "Manage credit notes"),
     ("sales:manage_shift", "Manage cashier shifts"),
+    (
+        "sales:reopen_shift",
+        "Reopen a recently closed cashier shift",
+    ),
     ("sales:read_shift", "View cashier shift details"),
+    (
+        "sales:manage_exchange_rates",
+        "Record currency exchange rates",
+    ),
+    (
+        "sales:manage_barcode_formats",
+        "Configure scale-printed barcode formats",
+    ),
     ("sales:approve_credit_note", "Approve credit notes"),
+    (
+        "sales:return_without_receipt",
+        "Approve returns without a receipt",
+    ),
     ("sales:read_credit_note", "View credit note details"),
     ("sales:create_customer", "Create customers"),
     ("sales:read_customer", "View customer information"),
+    ("sales:export_customer", "Export customer lists"),
+    (
+        "sales:read_customer_pii",
+        "View unmasked customer email and phone in exports",
+    ),
     ("sales:update_customer", "Update customer information"),
     // Promotions module permissions
     ("promotions:create", "Create promotions"),
@@ -258,6 +299,11 @@ pub const PERMISSIONS: &[(&str, &str)] = &[
         "accounting:write",
         "Create accounts, open/close periods, post journal entries",
     ),
+    // Notifications module
+    (
+        "webhooks:manage",
+        "Register and unregister event webhooks and review failed deliveries",
+    ),
     // Demand planning module
     ("demand_planning:read_forecast", "Read demand forecasts"),
     ("demand_planning:read_policy", "Read reorder policies"),
@@ -575,6 +621,8 @@ pub const ROLE_PERMISSIONS: &[(&str, &[&str])] = &[
             "products:update",
             "products:delete",
             "products:list",
+            "products:export",
+            "products:reprice",
             "variants:create",
             "variants:read",
             "variants:update",
@@ -591,6 +639,8 @@ pub const ROLE_PERMISSIONS: &[(&str, &[&str])] = &[
             "inventory:transfer",
             "inventory:adjust",
             "inventory:approve_adjustments",
+            "inventory:close_period",
+            "inventory:reopen_period",
             "inventory:reserve",
             "reservations:create",
             "reservations:read",
@@ -608,6 +658,7 @@ pub const ROLE_PERMISSIONS: &[(&str, &[&str])] = &[
             "transfers:ship",
             "transfers:receive",
             "transfers:cancel",
+            "transfers:approve",
             "categories:create",
             "categories:read",
             "categories:update",
@@ -622,16 +673,25 @@ pub const ROLE_PERMISSIONS: &[(&str, &[&str])] = &[
             "sales:complete",
             "sales:reports",
             "sales:process_payment",
+            "gift_cards:issue",
+            "gift_cards:read",
             "sales:apply_discount",
             "sales:create_customer",
             "sales:read_customer",
+            "sales:export_customer",
+            "sales:read_customer_pii",
+            "sales:export",
             "sales:update_customer",
             "sales:manage_shift",
+            "sales:reopen_shift",
             "sales:read_shift",
+            "sales:manage_exchange_rates",
+            "sales:manage_barcode_formats",
             "sales:manage_cart",
             "sales:manage_credit_note",
             "sales:read_credit_note",
             "sales:approve_credit_note",
+            "sales:return_without_receipt",
             // Promotions
             "promotions:create",
             "promotions:read",
@@ -721,6 +781,8 @@ pub const ROLE_PERMISSIONS: &[(&str, &[&str])] = &[
             // Accounting
             "accounting:read",
             "accounting:write",
+            // Webhooks
+            "webhooks:manage",
             // Demand planning
             "demand_planning:read_forecast",
             "demand_planning:read_policy",
@@ -831,6 +893,8 @@ pub const ROLE_PERMISSIONS: &[(&str, &[&str])] = &[
             "products:update",
             "products:delete",
             "products:list",
+            "products:export",
+            "products:reprice",
             "variants:create",
             "variants:read",
             "variants:update",
@@ -847,6 +911,8 @@ pub const ROLE_PERMISSIONS: &[(&str, &[&str])] = &[
             "inventory:transfer",
             "inventory:adjust",
             "inventory:approve_adjustments",
+            "inventory:close_period",
+            "inventory:reopen_period",
             "inventory:reserve",
             "reservations:create",
             "reservations:read",
@@ -863,6 +929,7 @@ pub const ROLE_PERMISSIONS: &[(&str, &[&str])] = &[
             "transfers:ship",
             "transfers:receive",
             "transfers:cancel",
+            "transfers:approve",
             "categories:create",
             "categories:read",
             "categories:update",
@@ -877,16 +944,25 @@ pub const ROLE_PERMISSIONS: &[(&str, &[&str])] = &[
             "sales:complete",
             "sales:reports",
             "sales:process_payment",
+            "gift_cards:issue",
+            "gift_cards:read",
             "sales:apply_discount",
             "sales:create_customer",
             "sales:read_customer",
+            "sales:export_customer",
+            "sales:read_customer_pii",
+            "sales:export",
             "sales:update_customer",
             "sales:manage_shift",
+            "sales:reopen_shift",
             "sales:read_shift",
+            "sales:manage_exchange_rates",
+            "sales:manage_barcode_formats",
             "sales:manage_cart",
             "sales:manage_credit_note",
             "sales:read_credit_note",
             "sales:approve_credit_note",
+            "sales:return_without_receipt",
             // Promotions
             "promotions:create",
             "promotions:read",
@@ -969,6 +1045,8 @@ pub const ROLE_PERMISSIONS: &[(&str, &[&str])] = &[
             // Accounting — chart of accounts, periods, JEs, P&L
             "accounting:read",
             "accounting:write",
+            // Webhooks — event feeds of the store
+            "webhooks:manage",
             // Demand planning — forecasts, reorder policies, replenishment, ABC
             "demand_planning:read_forecast",
             "demand_planning:read_policy",
@@ -1049,6 +1127,7 @@ pub const ROLE_PERMISSIONS: &[(&str, &[&str])] = &[
             "products:read",
             "products:update",
             "products:list",
+            "products:export",
             "variants:create",
             "variants:read",
             "variants:update",
@@ -1077,6 +1156,7 @@ pub const ROLE_PERMISSIONS: &[(&str, &[&str])] = &[
             "transfers:ship",
             "transfers:receive",
             "transfers:cancel",
+            "transfers:approve",
             "categories:read",
             "categories:list",
             // Sales
@@ -1088,16 +1168,24 @@ pub const ROLE_PERMISSIONS: &[(&str, &[&str])] = &[
             "sales:complete",
             "sales:reports",
             "sales:process_payment",
+            "gift_cards:issue",
+            "gift_cards:read",
             "sales:apply_discount",
             "sales:create_customer",
             "sales:read_customer",
+            "sales:export_customer",
+            "sales:export",
             "sales:update_customer",
             "sales:manage_shift",
+            "sales:reopen_shift",
             "sales:read_shift",
+            "sales:manage_exchange_rates",
+            "sales:manage_barcode_formats",
             "sales:manage_cart",
             "sales:manage_credit_note",
             "sales:read_credit_note",
             "sales:approve_credit_note",
+            "sales:return_without_receipt",
             // Promotions
             "promotions:create",
             "promotions:read",
@@ -1179,6 +1267,8 @@ pub const ROLE_PERMISSIONS: &[(&str, &[&str])] = &[
             "sales:list",
             "sales:complete",
             "sales:process_payment",
+            "gift_cards:issue",
+            "gift_cards:read",
             "sales:apply_discount",
             "sales:create_customer",
             "sales:read_customer",